@@ -0,0 +1,486 @@
+#ifndef APRILTAG_CAPI_H
+#define APRILTAG_CAPI_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/*
+ The result of a fallible call.
+
+ On failure, a message is available from `apriltag_rs_last_error()`.
+ */
+typedef enum ApriltagRsStatus {
+  /*
+   The call succeeded.
+   */
+  APRILTAG_RS_STATUS_OK = 0,
+  /*
+   A required pointer argument is NULL.
+   */
+  APRILTAG_RS_STATUS_NULL_POINTER,
+  /*
+   An argument is out of range or not valid UTF-8.
+   */
+  APRILTAG_RS_STATUS_INVALID_ARGUMENT,
+  /*
+   The configuration cannot be loaded or parsed.
+   */
+  APRILTAG_RS_STATUS_INVALID_CONFIG,
+  /*
+   The requested item does not exist.
+   */
+  APRILTAG_RS_STATUS_NOT_FOUND,
+  /*
+   The detector cannot be created from the configuration.
+   */
+  APRILTAG_RS_STATUS_DETECTOR_ERROR,
+  /*
+   The library panicked. The handles passed to the call are left in
+   a valid state, but should be freed.
+   */
+  APRILTAG_RS_STATUS_PANIC,
+} ApriltagRsStatus;
+
+/*
+ A rigid arrangement of tags whose pose is estimated as a whole.
+ */
+typedef struct ApriltagRsBundle ApriltagRsBundle;
+
+/*
+ An owned array of detections returned by
+ `apriltag_rs_detector_detect()`.
+ */
+typedef struct ApriltagRsDetections ApriltagRsDetections;
+
+/*
+ A detector along with its configuration.
+
+ The detector is rebuilt on the next detection after the families
+ are changed.
+ */
+typedef struct ApriltagRsDetector ApriltagRsDetector;
+
+/*
+ A tracker along with the C view of its tracks.
+ */
+typedef struct ApriltagRsTracker ApriltagRsTracker;
+
+/*
+ Tag size and camera parameters for pose estimation.
+ */
+typedef struct ApriltagRsTagParams {
+  /*
+   The width of the tag border.
+   */
+  double tagsize;
+  /*
+   The focal length along x in pixels.
+   */
+  double fx;
+  /*
+   The focal length along y in pixels.
+   */
+  double fy;
+  /*
+   The principal point x in pixels.
+   */
+  double cx;
+  /*
+   The principal point y in pixels.
+   */
+  double cy;
+} ApriltagRsTagParams;
+
+/*
+ A tag pose in camera coordinates.
+ */
+typedef struct ApriltagRsPose {
+  /*
+   The row-major rotation matrix.
+   */
+  double rotation[3][3];
+  /*
+   The translation in the same unit as `tagsize`.
+   */
+  double translation[3];
+  /*
+   The object-space error of the estimation.
+   */
+  double error;
+} ApriltagRsPose;
+
+/*
+ A detected tag.
+ */
+typedef struct ApriltagRsDetection {
+  /*
+   The family name. It is valid until the detections are freed.
+   */
+  const char *family;
+  /*
+   The marker ID.
+   */
+  size_t id;
+  /*
+   The Hamming distance to the target tag.
+   */
+  size_t hamming;
+  /*
+   Indicate the _goodness_ of the detection.
+   */
+  float decision_margin;
+  /*
+   The center `[x, y]` in pixels.
+   */
+  double center[2];
+  /*
+   The corners `[[x, y]; 4]` in pixels.
+   */
+  double corners[4][2];
+  /*
+   The row-major homography that maps tag coordinates to pixels.
+   */
+  double homography[3][3];
+  /*
+   Whether `pose` is set. Poses are estimated if the detector has
+   tag parameters.
+   */
+  bool has_pose;
+  /*
+   The estimated pose. It is zeroed if `has_pose` is false.
+   */
+  struct ApriltagRsPose pose;
+} ApriltagRsDetection;
+
+/*
+ A tag followed across frames.
+ */
+typedef struct ApriltagRsTrack {
+  /*
+   The id of the track, unique within the tracker.
+   */
+  uint64_t track_id;
+  /*
+   The family name. It is valid until the next update or the
+   tracker is freed.
+   */
+  const char *family;
+  /*
+   The marker ID.
+   */
+  size_t id;
+  /*
+   The center `[x, y]` of the last detection in pixels.
+   */
+  double center[2];
+  /*
+   The corners `[[x, y]; 4]` of the last detection in pixels.
+   */
+  double corners[4][2];
+  /*
+   The number of frames where the tag is detected.
+   */
+  size_t hits;
+  /*
+   The number of consecutive frames where the tag is not detected.
+   */
+  size_t missed;
+  /*
+   The center velocity `[x, y]` in pixels per frame.
+   */
+  double velocity[2];
+} ApriltagRsTrack;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Create a tag bundle from a JSON string.
+
+ The format is the same as the serialized `TagBundle` of the
+ apriltag crate. It returns NULL on failure.
+
+ # Safety
+ The string must be NULL or NUL-terminated.
+ */
+struct ApriltagRsBundle *apriltag_rs_bundle_from_json(const char *json);
+
+/*
+ Create a tag bundle from a JSON file.
+
+ It returns NULL on failure.
+
+ # Safety
+ The path must be NULL or NUL-terminated.
+ */
+struct ApriltagRsBundle *apriltag_rs_bundle_from_json_file(const char *path);
+
+/*
+ Gets the number of member tags.
+
+ # Safety
+ The pointer must be NULL or a valid bundle handle.
+ */
+size_t apriltag_rs_bundle_tag_count(const struct ApriltagRsBundle *bundle);
+
+/*
+ Estimate the pose of the bundle from the corners of all detected
+ member tags.
+
+ The `tagsize` of `params` is used for member tags without a size. On
+ success, the pose is stored in `out`. It fails with
+ `APRILTAG_RS_STATUS_NOT_FOUND` if no member tag is detected.
+
+ # Safety
+ The bundle and the detections must be valid handles, `params` must
+ point to valid parameters and `out` must be writable.
+ */
+enum ApriltagRsStatus apriltag_rs_bundle_estimate_pose(const struct ApriltagRsBundle *bundle,
+                                                       const struct ApriltagRsDetections *detections,
+                                                       const struct ApriltagRsTagParams *params,
+                                                       struct ApriltagRsPose *out);
+
+/*
+ Free the tag bundle.
+
+ # Safety
+ The pointer must be NULL or a valid bundle handle, which is not used
+ afterwards.
+ */
+void apriltag_rs_bundle_free(struct ApriltagRsBundle *bundle);
+
+/*
+ Gets the number of detections.
+
+ # Safety
+ The pointer must be NULL or a valid detections handle.
+ */
+size_t apriltag_rs_detections_len(const struct ApriltagRsDetections *detections);
+
+/*
+ Gets the detection at the index.
+
+ It returns NULL if the index is out of range. The pointer is valid
+ until the detections are freed.
+
+ # Safety
+ The pointer must be NULL or a valid detections handle.
+ */
+const struct ApriltagRsDetection *apriltag_rs_detections_get(const struct ApriltagRsDetections *detections,
+                                                             size_t index);
+
+/*
+ Serialize the detections to a JSON array.
+
+ The returned string must be freed by `apriltag_rs_string_free()`.
+ It returns NULL if the pointer is NULL.
+
+ # Safety
+ The pointer must be NULL or a valid detections handle.
+ */
+char *apriltag_rs_detections_to_json(const struct ApriltagRsDetections *detections);
+
+/*
+ Free the detections.
+
+ # Safety
+ The pointer must be NULL or a valid detections handle, which is not
+ used afterwards.
+ */
+void apriltag_rs_detections_free(struct ApriltagRsDetections *detections);
+
+/*
+ Create a detector with the default configuration, which detects
+ `tag36h11` with 2 corrected bits.
+ */
+struct ApriltagRsDetector *apriltag_rs_detector_new(void);
+
+/*
+ Create a detector from a JSON configuration string.
+
+ The format is the same as the serialized `DetectorConfig` of the
+ apriltag crate. It returns NULL on failure.
+
+ # Safety
+ The string must be NULL or NUL-terminated.
+ */
+struct ApriltagRsDetector *apriltag_rs_detector_from_json(const char *json);
+
+/*
+ Create a detector from a JSON configuration file.
+
+ It returns NULL on failure.
+
+ # Safety
+ The path must be NULL or NUL-terminated.
+ */
+struct ApriltagRsDetector *apriltag_rs_detector_from_json_file(const char *path);
+
+/*
+ Free the detector.
+
+ # Safety
+ The pointer must be NULL or a valid detector handle, which is not
+ used afterwards.
+ */
+void apriltag_rs_detector_free(struct ApriltagRsDetector *detector);
+
+/*
+ Add a tag family by name, such as `tag36h11`, or update the number
+ of corrected bits if the family is present.
+
+ # Safety
+ The detector must be a valid handle, and the name must be NULL or
+ NUL-terminated.
+ */
+enum ApriltagRsStatus apriltag_rs_detector_add_family(struct ApriltagRsDetector *detector,
+                                                      const char *name,
+                                                      size_t bits_corrected);
+
+/*
+ Remove a tag family by name.
+
+ At least one family must remain before the next detection.
+
+ # Safety
+ The detector must be a valid handle, and the name must be NULL or
+ NUL-terminated.
+ */
+enum ApriltagRsStatus apriltag_rs_detector_remove_family(struct ApriltagRsDetector *detector,
+                                                         const char *name);
+
+/*
+ Gets the number of tag families.
+
+ # Safety
+ The pointer must be NULL or a valid detector handle.
+ */
+size_t apriltag_rs_detector_family_count(const struct ApriltagRsDetector *detector);
+
+/*
+ Set the tag parameters to estimate poses, or disable pose
+ estimation if `params` is NULL.
+
+ # Safety
+ The detector must be a valid handle, and `params` must be NULL or
+ point to valid parameters.
+ */
+enum ApriltagRsStatus apriltag_rs_detector_set_tag_params(struct ApriltagRsDetector *detector,
+                                                          const struct ApriltagRsTagParams *params);
+
+/*
+ Serialize the current configuration to JSON.
+
+ The returned string must be freed by `apriltag_rs_string_free()`.
+ It returns NULL if the pointer is NULL.
+
+ # Safety
+ The pointer must be NULL or a valid detector handle.
+ */
+char *apriltag_rs_detector_config_json(const struct ApriltagRsDetector *detector);
+
+/*
+ Detect tags on an 8-bit grayscale image.
+
+ Row `y` of the image starts at `data + y * stride`. On success, the
+ detections are stored in `out`, which must be freed by
+ `apriltag_rs_detections_free()`.
+
+ # Safety
+ The detector must be a valid handle, `data` must point to at least
+ `stride * (height - 1) + width` readable bytes and `out` must be
+ writable.
+ */
+enum ApriltagRsStatus apriltag_rs_detector_detect(struct ApriltagRsDetector *detector,
+                                                  const uint8_t *data,
+                                                  size_t width,
+                                                  size_t height,
+                                                  size_t stride,
+                                                  struct ApriltagRsDetections **out);
+
+/*
+ Gets the message of the last failed call on the calling thread.
+
+ It returns NULL if no call has failed. The string is valid until the
+ next failed call on the same thread.
+ */
+const char *apriltag_rs_last_error(void);
+
+/*
+ Free a string returned by this library.
+
+ # Safety
+ The pointer must be NULL or returned by a function of this library
+ that transfers the ownership of a string, and not freed before.
+ */
+void apriltag_rs_string_free(char *string);
+
+/*
+ Create a tracker.
+
+ A track is kept for `max_missed` frames without detections, and a
+ detection continues a track if its center is within `max_distance`
+ pixels from the predicted one. It returns NULL if `max_distance` is
+ not positive.
+ */
+struct ApriltagRsTracker *apriltag_rs_tracker_new(size_t max_missed, double max_distance);
+
+/*
+ Update the tracks with the detections of the next frame.
+
+ The tracks previously returned by `apriltag_rs_tracker_get()` are
+ invalidated.
+
+ # Safety
+ The tracker and the detections must be valid handles.
+ */
+enum ApriltagRsStatus apriltag_rs_tracker_update(struct ApriltagRsTracker *tracker,
+                                                 const struct ApriltagRsDetections *detections);
+
+/*
+ Gets the number of live tracks, including those not detected in
+ the last frame.
+
+ # Safety
+ The pointer must be NULL or a valid tracker handle.
+ */
+size_t apriltag_rs_tracker_len(const struct ApriltagRsTracker *tracker);
+
+/*
+ Gets the track at the index. Tracks are ordered by track id.
+
+ It returns NULL if the index is out of range. The pointer is valid
+ until the next update or the tracker is freed.
+
+ # Safety
+ The pointer must be NULL or a valid tracker handle.
+ */
+const struct ApriltagRsTrack *apriltag_rs_tracker_get(const struct ApriltagRsTracker *tracker,
+                                                      size_t index);
+
+/*
+ Drop all tracks. Track ids are not reused.
+
+ # Safety
+ The pointer must be NULL or a valid tracker handle.
+ */
+void apriltag_rs_tracker_clear(struct ApriltagRsTracker *tracker);
+
+/*
+ Free the tracker.
+
+ # Safety
+ The pointer must be NULL or a valid tracker handle, which is not
+ used afterwards.
+ */
+void apriltag_rs_tracker_free(struct ApriltagRsTracker *tracker);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* APRILTAG_CAPI_H */
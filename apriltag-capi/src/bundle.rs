@@ -0,0 +1,140 @@
+//! The tag bundle handle.
+
+use crate::{
+    detections::{ApriltagRsDetections, ApriltagRsPose},
+    detector::{to_str, ApriltagRsTagParams},
+    status::{catch_panic, fail, ApriltagRsStatus},
+};
+use apriltag::{DetectionRecord, TagBundle, TagParams};
+use std::{
+    os::raw::c_char,
+    ptr::{self, NonNull},
+};
+
+/// A rigid arrangement of tags whose pose is estimated as a whole.
+pub struct ApriltagRsBundle {
+    bundle: TagBundle,
+}
+
+impl ApriltagRsBundle {
+    fn from_result(bundle: Result<TagBundle, ApriltagRsStatus>) -> *mut Self {
+        match bundle {
+            Ok(bundle) => Box::into_raw(Box::new(Self { bundle })),
+            Err(_) => ptr::null_mut(),
+        }
+    }
+}
+
+/// Create a tag bundle from a JSON string.
+///
+/// The format is the same as the serialized `TagBundle` of the
+/// apriltag crate. It returns NULL on failure.
+///
+/// # Safety
+/// The string must be NULL or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_bundle_from_json(
+    json: *const c_char,
+) -> *mut ApriltagRsBundle {
+    catch_panic(ptr::null_mut(), || {
+        ApriltagRsBundle::from_result(to_str(json, "json").and_then(|json| {
+            TagBundle::from_json_str(json)
+                .map_err(|err| fail(ApriltagRsStatus::InvalidConfig, err.to_string()))
+        }))
+    })
+}
+
+/// Create a tag bundle from a JSON file.
+///
+/// It returns NULL on failure.
+///
+/// # Safety
+/// The path must be NULL or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_bundle_from_json_file(
+    path: *const c_char,
+) -> *mut ApriltagRsBundle {
+    catch_panic(ptr::null_mut(), || {
+        ApriltagRsBundle::from_result(to_str(path, "path").and_then(|path| {
+            TagBundle::from_json_file(path)
+                .map_err(|err| fail(ApriltagRsStatus::InvalidConfig, err.to_string()))
+        }))
+    })
+}
+
+/// Gets the number of member tags.
+///
+/// # Safety
+/// The pointer must be NULL or a valid bundle handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_bundle_tag_count(bundle: *const ApriltagRsBundle) -> usize {
+    catch_panic(0, || match bundle.as_ref() {
+        Some(bundle) => bundle.bundle.tags.len(),
+        None => 0,
+    })
+}
+
+/// Estimate the pose of the bundle from the corners of all detected
+/// member tags.
+///
+/// The `tagsize` of `params` is used for member tags without a size. On
+/// success, the pose is stored in `out`. It fails with
+/// `APRILTAG_RS_STATUS_NOT_FOUND` if no member tag is detected.
+///
+/// # Safety
+/// The bundle and the detections must be valid handles, `params` must
+/// point to valid parameters and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_bundle_estimate_pose(
+    bundle: *const ApriltagRsBundle,
+    detections: *const ApriltagRsDetections,
+    params: *const ApriltagRsTagParams,
+    out: *mut ApriltagRsPose,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let (Some(bundle), Some(detections), Some(params)) =
+            (bundle.as_ref(), detections.as_ref(), params.as_ref())
+        else {
+            return fail(
+                ApriltagRsStatus::NullPointer,
+                "bundle, detections or params is NULL",
+            );
+        };
+        if out.is_null() {
+            return fail(ApriltagRsStatus::NullPointer, "out is NULL");
+        }
+
+        let records: Vec<DetectionRecord> = detections.records().cloned().collect();
+        match bundle
+            .bundle
+            .estimate_pose(&records, &TagParams::from(params))
+        {
+            Some(estimation) => {
+                *out = ApriltagRsPose {
+                    rotation: estimation.pose.rotation,
+                    translation: estimation.pose.translation,
+                    error: estimation.error,
+                };
+                ApriltagRsStatus::Ok
+            }
+            None => fail(
+                ApriltagRsStatus::NotFound,
+                "no tag of the bundle is detected",
+            ),
+        }
+    })
+}
+
+/// Free the tag bundle.
+///
+/// # Safety
+/// The pointer must be NULL or a valid bundle handle, which is not used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_bundle_free(bundle: *mut ApriltagRsBundle) {
+    catch_panic((), || {
+        if let Some(bundle) = NonNull::new(bundle) {
+            drop(Box::from_raw(bundle.as_ptr()));
+        }
+    })
+}
@@ -0,0 +1,198 @@
+//! Owned arrays of detections.
+
+use crate::status::{catch_panic, fail, ApriltagRsStatus};
+use apriltag::{Detection, DetectionRecord, PoseEstimationRecord, PoseRecord, TagParams};
+use serde::Serialize;
+use std::{
+    ffi::CString,
+    os::raw::c_char,
+    ptr::{self, NonNull},
+};
+
+/// A tag pose in camera coordinates.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApriltagRsPose {
+    /// The row-major rotation matrix.
+    pub rotation: [[f64; 3]; 3],
+    /// The translation in the same unit as `tagsize`.
+    pub translation: [f64; 3],
+    /// The object-space error of the estimation.
+    pub error: f64,
+}
+
+/// A detected tag.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ApriltagRsDetection {
+    /// The family name. It is valid until the detections are freed.
+    pub family: *const c_char,
+    /// The marker ID.
+    pub id: usize,
+    /// The Hamming distance to the target tag.
+    pub hamming: usize,
+    /// Indicate the _goodness_ of the detection.
+    pub decision_margin: f32,
+    /// The center `[x, y]` in pixels.
+    pub center: [f64; 2],
+    /// The corners `[[x, y]; 4]` in pixels.
+    pub corners: [[f64; 2]; 4],
+    /// The row-major homography that maps tag coordinates to pixels.
+    pub homography: [[f64; 3]; 3],
+    /// Whether `pose` is set. Poses are estimated if the detector has
+    /// tag parameters.
+    pub has_pose: bool,
+    /// The estimated pose. It is zeroed if `has_pose` is false.
+    pub pose: ApriltagRsPose,
+}
+
+/// An owned array of detections returned by
+/// `apriltag_rs_detector_detect()`.
+pub struct ApriltagRsDetections {
+    items: Vec<ApriltagRsDetection>,
+    records: Vec<DetectionJson>,
+    // Keeps the strings referred by the items.
+    _families: Vec<CString>,
+}
+
+/// The JSON form of a detection, the same as the output of the
+/// `apriltag detect` command.
+#[derive(Serialize)]
+struct DetectionJson {
+    #[serde(flatten)]
+    detection: DetectionRecord,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pose: Option<PoseRecord>,
+}
+
+impl ApriltagRsDetections {
+    pub(crate) fn new(detections: &[Detection], tag_params: Option<&TagParams>) -> Self {
+        let mut families = vec![];
+        let mut items = vec![];
+        let mut records = vec![];
+
+        for detection in detections {
+            let record = detection.to_record();
+            let pose = tag_params.and_then(|params| estimate_pose(detection, params));
+
+            let family = CString::new(record.family.as_str()).unwrap();
+            items.push(ApriltagRsDetection {
+                family: family.as_ptr(),
+                id: record.id,
+                hamming: record.hamming,
+                decision_margin: record.decision_margin,
+                center: record.center,
+                corners: record.corners,
+                homography: record.homography,
+                has_pose: pose.is_some(),
+                pose: pose
+                    .as_ref()
+                    .map(|estimation| ApriltagRsPose {
+                        rotation: estimation.pose.rotation,
+                        translation: estimation.pose.translation,
+                        error: estimation.error,
+                    })
+                    .unwrap_or(ApriltagRsPose {
+                        rotation: [[0.0; 3]; 3],
+                        translation: [0.0; 3],
+                        error: 0.0,
+                    }),
+            });
+            families.push(family);
+            records.push(DetectionJson {
+                detection: record,
+                pose: pose.map(|estimation| estimation.pose),
+            });
+        }
+
+        Self {
+            items,
+            records,
+            _families: families,
+        }
+    }
+
+    /// Gets the owned copies of the detections.
+    pub(crate) fn records(&self) -> impl Iterator<Item = &DetectionRecord> {
+        self.records.iter().map(|record| &record.detection)
+    }
+}
+
+/// Estimate the pose with the least error, the same as
+/// [Detection::estimate_tag_pose], along with the error.
+fn estimate_pose(detection: &Detection, params: &TagParams) -> Option<PoseEstimationRecord> {
+    detection
+        .estimate_tag_pose_orthogonal_iteration(params, 50)
+        .iter()
+        .min_by(|lhs, rhs| lhs.error.total_cmp(&rhs.error))
+        .map(|estimation| estimation.to_record())
+}
+
+/// Gets the number of detections.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detections handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detections_len(
+    detections: *const ApriltagRsDetections,
+) -> usize {
+    catch_panic(0, || match detections.as_ref() {
+        Some(detections) => detections.items.len(),
+        None => 0,
+    })
+}
+
+/// Gets the detection at the index.
+///
+/// It returns NULL if the index is out of range. The pointer is valid
+/// until the detections are freed.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detections handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detections_get(
+    detections: *const ApriltagRsDetections,
+    index: usize,
+) -> *const ApriltagRsDetection {
+    catch_panic(ptr::null(), || {
+        match detections.as_ref().and_then(|d| d.items.get(index)) {
+            Some(item) => item,
+            None => ptr::null(),
+        }
+    })
+}
+
+/// Serialize the detections to a JSON array.
+///
+/// The returned string must be freed by `apriltag_rs_string_free()`.
+/// It returns NULL if the pointer is NULL.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detections handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detections_to_json(
+    detections: *const ApriltagRsDetections,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        let Some(detections) = detections.as_ref() else {
+            fail(ApriltagRsStatus::NullPointer, "detections is NULL");
+            return ptr::null_mut();
+        };
+        let json = serde_json::to_string(&detections.records).unwrap();
+        CString::new(json).unwrap().into_raw()
+    })
+}
+
+/// Free the detections.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detections handle, which is not
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detections_free(detections: *mut ApriltagRsDetections) {
+    catch_panic((), || {
+        if let Some(detections) = NonNull::new(detections) {
+            drop(Box::from_raw(detections.as_ptr()));
+        }
+    })
+}
@@ -0,0 +1,360 @@
+//! The detector handle.
+
+use crate::{
+    detections::ApriltagRsDetections,
+    status::{catch_panic, fail, ApriltagRsStatus},
+};
+use apriltag::{
+    image_buf::DEFAULT_ALIGNMENT_U8, Detector, DetectorConfig, FamilyConfig, FamilyKind, Image,
+    TagParams,
+};
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    ptr::{self, NonNull},
+    slice,
+};
+
+/// Tag size and camera parameters for pose estimation.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApriltagRsTagParams {
+    /// The width of the tag border.
+    pub tagsize: f64,
+    /// The focal length along x in pixels.
+    pub fx: f64,
+    /// The focal length along y in pixels.
+    pub fy: f64,
+    /// The principal point x in pixels.
+    pub cx: f64,
+    /// The principal point y in pixels.
+    pub cy: f64,
+}
+
+impl From<&ApriltagRsTagParams> for TagParams {
+    fn from(params: &ApriltagRsTagParams) -> Self {
+        Self {
+            tagsize: params.tagsize,
+            fx: params.fx,
+            fy: params.fy,
+            cx: params.cx,
+            cy: params.cy,
+        }
+    }
+}
+
+/// A detector along with its configuration.
+///
+/// The detector is rebuilt on the next detection after the families
+/// are changed.
+pub struct ApriltagRsDetector {
+    config: DetectorConfig,
+    detector: Option<Detector>,
+}
+
+impl ApriltagRsDetector {
+    fn from_config(config: DetectorConfig) -> *mut Self {
+        match config.build() {
+            Ok(detector) => Box::into_raw(Box::new(Self {
+                config,
+                detector: Some(detector),
+            })),
+            Err(err) => {
+                fail(ApriltagRsStatus::DetectorError, err.to_string());
+                ptr::null_mut()
+            }
+        }
+    }
+
+    fn detector(&mut self) -> Result<&mut Detector, ApriltagRsStatus> {
+        if self.detector.is_none() {
+            let detector = self
+                .config
+                .build()
+                .map_err(|err| fail(ApriltagRsStatus::DetectorError, err.to_string()))?;
+            self.detector = Some(detector);
+        }
+        Ok(self.detector.as_mut().unwrap())
+    }
+}
+
+/// Create a detector with the default configuration, which detects
+/// `tag36h11` with 2 corrected bits.
+#[no_mangle]
+pub extern "C" fn apriltag_rs_detector_new() -> *mut ApriltagRsDetector {
+    catch_panic(ptr::null_mut(), || {
+        ApriltagRsDetector::from_config(DetectorConfig::default())
+    })
+}
+
+/// Create a detector from a JSON configuration string.
+///
+/// The format is the same as the serialized `DetectorConfig` of the
+/// apriltag crate. It returns NULL on failure.
+///
+/// # Safety
+/// The string must be NULL or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_from_json(
+    json: *const c_char,
+) -> *mut ApriltagRsDetector {
+    catch_panic(ptr::null_mut(), || {
+        let config = to_str(json, "json").and_then(|json| {
+            DetectorConfig::from_json_str(json)
+                .map_err(|err| fail(ApriltagRsStatus::InvalidConfig, err.to_string()))
+        });
+        match config {
+            Ok(config) => ApriltagRsDetector::from_config(config),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Create a detector from a JSON configuration file.
+///
+/// It returns NULL on failure.
+///
+/// # Safety
+/// The path must be NULL or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_from_json_file(
+    path: *const c_char,
+) -> *mut ApriltagRsDetector {
+    catch_panic(ptr::null_mut(), || {
+        let config = to_str(path, "path").and_then(|path| {
+            DetectorConfig::from_json_file(path)
+                .map_err(|err| fail(ApriltagRsStatus::InvalidConfig, err.to_string()))
+        });
+        match config {
+            Ok(config) => ApriltagRsDetector::from_config(config),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Free the detector.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detector handle, which is not
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_free(detector: *mut ApriltagRsDetector) {
+    catch_panic((), || {
+        if let Some(detector) = NonNull::new(detector) {
+            drop(Box::from_raw(detector.as_ptr()));
+        }
+    })
+}
+
+/// Add a tag family by name, such as `tag36h11`, or update the number
+/// of corrected bits if the family is present.
+///
+/// # Safety
+/// The detector must be a valid handle, and the name must be NULL or
+/// NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_add_family(
+    detector: *mut ApriltagRsDetector,
+    name: *const c_char,
+    bits_corrected: usize,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let Some(detector) = detector.as_mut() else {
+            return fail(ApriltagRsStatus::NullPointer, "detector is NULL");
+        };
+        let kind = match parse_family(name) {
+            Ok(kind) => kind,
+            Err(status) => return status,
+        };
+
+        let families = &mut detector.config.families;
+        match families.iter_mut().find(|family| family.family == kind) {
+            Some(family) => family.bits_corrected = bits_corrected,
+            None => families.push(FamilyConfig {
+                family: kind,
+                bits_corrected,
+            }),
+        }
+        detector.detector = None;
+        ApriltagRsStatus::Ok
+    })
+}
+
+/// Remove a tag family by name.
+///
+/// At least one family must remain before the next detection.
+///
+/// # Safety
+/// The detector must be a valid handle, and the name must be NULL or
+/// NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_remove_family(
+    detector: *mut ApriltagRsDetector,
+    name: *const c_char,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let Some(detector) = detector.as_mut() else {
+            return fail(ApriltagRsStatus::NullPointer, "detector is NULL");
+        };
+        let kind = match parse_family(name) {
+            Ok(kind) => kind,
+            Err(status) => return status,
+        };
+
+        let families = &mut detector.config.families;
+        let len = families.len();
+        families.retain(|family| family.family != kind);
+        if families.len() == len {
+            return fail(
+                ApriltagRsStatus::NotFound,
+                format!("family '{}' is not added", kind.name()),
+            );
+        }
+        detector.detector = None;
+        ApriltagRsStatus::Ok
+    })
+}
+
+/// Gets the number of tag families.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detector handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_family_count(
+    detector: *const ApriltagRsDetector,
+) -> usize {
+    catch_panic(0, || match detector.as_ref() {
+        Some(detector) => detector.config.families.len(),
+        None => 0,
+    })
+}
+
+/// Set the tag parameters to estimate poses, or disable pose
+/// estimation if `params` is NULL.
+///
+/// # Safety
+/// The detector must be a valid handle, and `params` must be NULL or
+/// point to valid parameters.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_set_tag_params(
+    detector: *mut ApriltagRsDetector,
+    params: *const ApriltagRsTagParams,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let Some(detector) = detector.as_mut() else {
+            return fail(ApriltagRsStatus::NullPointer, "detector is NULL");
+        };
+        detector.config.tag_params = params.as_ref().map(TagParams::from);
+        ApriltagRsStatus::Ok
+    })
+}
+
+/// Serialize the current configuration to JSON.
+///
+/// The returned string must be freed by `apriltag_rs_string_free()`.
+/// It returns NULL if the pointer is NULL.
+///
+/// # Safety
+/// The pointer must be NULL or a valid detector handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_config_json(
+    detector: *const ApriltagRsDetector,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        let Some(detector) = detector.as_ref() else {
+            fail(ApriltagRsStatus::NullPointer, "detector is NULL");
+            return ptr::null_mut();
+        };
+        CString::new(detector.config.to_json_string())
+            .unwrap()
+            .into_raw()
+    })
+}
+
+/// Detect tags on an 8-bit grayscale image.
+///
+/// Row `y` of the image starts at `data + y * stride`. On success, the
+/// detections are stored in `out`, which must be freed by
+/// `apriltag_rs_detections_free()`.
+///
+/// # Safety
+/// The detector must be a valid handle, `data` must point to at least
+/// `stride * (height - 1) + width` readable bytes and `out` must be
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_detector_detect(
+    detector: *mut ApriltagRsDetector,
+    data: *const u8,
+    width: usize,
+    height: usize,
+    stride: usize,
+    out: *mut *mut ApriltagRsDetections,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let Some(detector) = detector.as_mut() else {
+            return fail(ApriltagRsStatus::NullPointer, "detector is NULL");
+        };
+        if data.is_null() || out.is_null() {
+            return fail(ApriltagRsStatus::NullPointer, "data or out is NULL");
+        }
+        if width == 0 || height == 0 || stride < width {
+            return fail(
+                ApriltagRsStatus::InvalidArgument,
+                format!("invalid image size {width}x{height} with stride {stride}"),
+            );
+        }
+        let Some(len) = stride
+            .checked_mul(height - 1)
+            .and_then(|len| len.checked_add(width))
+        else {
+            return fail(ApriltagRsStatus::InvalidArgument, "image is too large");
+        };
+
+        let data = slice::from_raw_parts(data, len);
+        let mut image = match Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8) {
+            Ok(image) => image,
+            Err(err) => return fail(ApriltagRsStatus::InvalidArgument, err.to_string()),
+        };
+        let image_stride = image.stride();
+        data.chunks(stride)
+            .zip(image.as_slice_mut().chunks_mut(image_stride))
+            .for_each(|(src, dst)| dst[..width].copy_from_slice(&src[..width]));
+
+        let tag_params = detector.config.tag_params.clone();
+        let detections = match detector.detector() {
+            Ok(inner) => inner.detect(&image),
+            Err(status) => return status,
+        };
+        let detections = ApriltagRsDetections::new(&detections, tag_params.as_ref());
+        *out = Box::into_raw(Box::new(detections));
+        ApriltagRsStatus::Ok
+    })
+}
+
+pub(crate) unsafe fn to_str<'a>(
+    string: *const c_char,
+    name: &str,
+) -> Result<&'a str, ApriltagRsStatus> {
+    if string.is_null() {
+        return Err(fail(
+            ApriltagRsStatus::NullPointer,
+            format!("{name} is NULL"),
+        ));
+    }
+    CStr::from_ptr(string).to_str().map_err(|_| {
+        fail(
+            ApriltagRsStatus::InvalidArgument,
+            format!("{name} is not valid UTF-8"),
+        )
+    })
+}
+
+unsafe fn parse_family(name: *const c_char) -> Result<FamilyKind, ApriltagRsStatus> {
+    let name = to_str(name, "name")?;
+    name.parse().map_err(|_| {
+        fail(
+            ApriltagRsStatus::InvalidArgument,
+            format!("unknown family '{name}'"),
+        )
+    })
+}
@@ -0,0 +1,53 @@
+//! C ABI of the [apriltag] crate for embedding in C and C++ applications.
+//!
+//! The crate builds a shared and a static library along with the
+//! `include/apriltag_capi.h` header generated by cbindgen. Unlike the
+//! raw AprilTag C library, it provides
+//!
+//! - detectors configured by the JSON format of
+//!   [DetectorConfig](apriltag::DetectorConfig), the same as the Rust
+//!   services,
+//! - adding and removing tag families on a live detector,
+//! - owned detection arrays with estimated poses, which can also be
+//!   serialized to JSON,
+//! - tag tracking across frames, which assigns stable track ids,
+//! - pose estimation of tag bundles loaded from JSON, using the
+//!   corners of all visible member tags.
+//!
+//! Every object is accessed through an opaque handle and released by
+//! the matching `*_free()` function. Fallible calls return an
+//! [ApriltagRsStatus] or NULL, and the error message is available from
+//! [apriltag_rs_last_error].
+//! A panic inside the library is caught at every entry point and
+//! reported as [ApriltagRsStatus::Panic] or NULL.
+//!
+//! The exported symbols are prefixed by `apriltag_rs_` to avoid
+//! conflicts with the AprilTag C library linked in the same binary.
+//!
+//! ```c
+//! ApriltagRsDetector *detector =
+//!     apriltag_rs_detector_from_json_file("detector.json");
+//! if (detector == NULL) {
+//!     fprintf(stderr, "%s\n", apriltag_rs_last_error());
+//!     return 1;
+//! }
+//!
+//! ApriltagRsDetections *detections = NULL;
+//! if (apriltag_rs_detector_detect(detector, pixels, width, height, stride,
+//!                                 &detections) == APRILTAG_RS_STATUS_OK) {
+//!     for (size_t i = 0; i < apriltag_rs_detections_len(detections); i++) {
+//!         const ApriltagRsDetection *det = apriltag_rs_detections_get(detections, i);
+//!         printf("%s %zu\n", det->family, det->id);
+//!     }
+//!     apriltag_rs_detections_free(detections);
+//! }
+//! apriltag_rs_detector_free(detector);
+//! ```
+
+mod bundle;
+mod detections;
+mod detector;
+mod status;
+mod tracker;
+
+pub use crate::{bundle::*, detections::*, detector::*, status::*, tracker::*};
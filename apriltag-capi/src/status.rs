@@ -0,0 +1,105 @@
+//! Status codes and the last error message.
+
+use std::{
+    cell::RefCell,
+    ffi::CString,
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+/// The result of a fallible call.
+///
+/// On failure, a message is available from `apriltag_rs_last_error()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApriltagRsStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument is NULL.
+    NullPointer,
+    /// An argument is out of range or not valid UTF-8.
+    InvalidArgument,
+    /// The configuration cannot be loaded or parsed.
+    InvalidConfig,
+    /// The requested item does not exist.
+    NotFound,
+    /// The detector cannot be created from the configuration.
+    DetectorError,
+    /// The library panicked. The handles passed to the call are left in
+    /// a valid state, but should be freed.
+    Panic,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record the error message of the calling thread and return the status.
+pub(crate) fn fail(status: ApriltagRsStatus, message: impl Into<String>) -> ApriltagRsStatus {
+    let message = CString::new(message.into().replace('\0', " ")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+    status
+}
+
+/// Run the body of an entry point, and record a panic as a failure
+/// instead of unwinding into the caller, which is undefined behavior.
+/// It returns `on_panic` if the body panics.
+pub(crate) fn catch_panic<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown reason");
+            fail(ApriltagRsStatus::Panic, format!("panicked: {message}"));
+            on_panic
+        }
+    }
+}
+
+/// Gets the message of the last failed call on the calling thread.
+///
+/// It returns NULL if no call has failed. The string is valid until the
+/// next failed call on the same thread.
+#[no_mangle]
+pub extern "C" fn apriltag_rs_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| match &*last.borrow() {
+        Some(message) => message.as_ptr(),
+        None => ptr::null(),
+    })
+}
+
+/// Free a string returned by this library.
+///
+/// # Safety
+/// The pointer must be NULL or returned by a function of this library
+/// that transfers the ownership of a string, and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_string_free(string: *mut c_char) {
+    catch_panic((), || {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn record_panic_as_failure() {
+        let status = catch_panic(ApriltagRsStatus::Panic, || {
+            panic!("index {} out of range", 3);
+        });
+        assert_eq!(status, ApriltagRsStatus::Panic);
+        let message = unsafe { CStr::from_ptr(apriltag_rs_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "panicked: index 3 out of range");
+
+        assert_eq!(catch_panic(0, || 1), 1);
+    }
+}
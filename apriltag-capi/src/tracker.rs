@@ -0,0 +1,194 @@
+//! The tracker handle.
+
+use crate::{
+    detections::ApriltagRsDetections,
+    status::{catch_panic, fail, ApriltagRsStatus},
+};
+use apriltag::{DetectionRecord, Track, Tracker, TrackerParams};
+use std::{
+    ffi::CString,
+    os::raw::c_char,
+    ptr::{self, NonNull},
+};
+
+/// A tag followed across frames.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ApriltagRsTrack {
+    /// The id of the track, unique within the tracker.
+    pub track_id: u64,
+    /// The family name. It is valid until the next update or the
+    /// tracker is freed.
+    pub family: *const c_char,
+    /// The marker ID.
+    pub id: usize,
+    /// The center `[x, y]` of the last detection in pixels.
+    pub center: [f64; 2],
+    /// The corners `[[x, y]; 4]` of the last detection in pixels.
+    pub corners: [[f64; 2]; 4],
+    /// The number of frames where the tag is detected.
+    pub hits: usize,
+    /// The number of consecutive frames where the tag is not detected.
+    pub missed: usize,
+    /// The center velocity `[x, y]` in pixels per frame.
+    pub velocity: [f64; 2],
+}
+
+/// A tracker along with the C view of its tracks.
+pub struct ApriltagRsTracker {
+    tracker: Tracker,
+    items: Vec<ApriltagRsTrack>,
+    // Keeps the strings referred by the items.
+    _families: Vec<CString>,
+}
+
+impl ApriltagRsTracker {
+    fn update(&mut self, detections: &[DetectionRecord]) {
+        let tracks = self.tracker.update(detections);
+        let (items, families) = to_items(tracks);
+        self.items = items;
+        self._families = families;
+    }
+
+    fn clear(&mut self) {
+        self.tracker.clear();
+        self.items.clear();
+        self._families.clear();
+    }
+}
+
+fn to_items(tracks: &[Track]) -> (Vec<ApriltagRsTrack>, Vec<CString>) {
+    tracks
+        .iter()
+        .map(|track| {
+            let detection = &track.detection;
+            let family = CString::new(detection.family.as_str()).unwrap();
+            let item = ApriltagRsTrack {
+                track_id: track.track_id,
+                family: family.as_ptr(),
+                id: detection.id,
+                center: detection.center,
+                corners: detection.corners,
+                hits: track.hits,
+                missed: track.missed,
+                velocity: track.velocity,
+            };
+            (item, family)
+        })
+        .unzip()
+}
+
+/// Create a tracker.
+///
+/// A track is kept for `max_missed` frames without detections, and a
+/// detection continues a track if its center is within `max_distance`
+/// pixels from the predicted one. It returns NULL if `max_distance` is
+/// not positive.
+#[no_mangle]
+pub extern "C" fn apriltag_rs_tracker_new(
+    max_missed: usize,
+    max_distance: f64,
+) -> *mut ApriltagRsTracker {
+    catch_panic(ptr::null_mut(), || {
+        let params = TrackerParams {
+            max_missed,
+            max_distance,
+        };
+        match Tracker::new(params) {
+            Ok(tracker) => Box::into_raw(Box::new(ApriltagRsTracker {
+                tracker,
+                items: vec![],
+                _families: vec![],
+            })),
+            Err(err) => {
+                fail(ApriltagRsStatus::InvalidArgument, err.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Update the tracks with the detections of the next frame.
+///
+/// The tracks previously returned by `apriltag_rs_tracker_get()` are
+/// invalidated.
+///
+/// # Safety
+/// The tracker and the detections must be valid handles.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_tracker_update(
+    tracker: *mut ApriltagRsTracker,
+    detections: *const ApriltagRsDetections,
+) -> ApriltagRsStatus {
+    catch_panic(ApriltagRsStatus::Panic, || {
+        let (Some(tracker), Some(detections)) = (tracker.as_mut(), detections.as_ref()) else {
+            return fail(
+                ApriltagRsStatus::NullPointer,
+                "tracker or detections is NULL",
+            );
+        };
+        let records: Vec<_> = detections.records().cloned().collect();
+        tracker.update(&records);
+        ApriltagRsStatus::Ok
+    })
+}
+
+/// Gets the number of live tracks, including those not detected in
+/// the last frame.
+///
+/// # Safety
+/// The pointer must be NULL or a valid tracker handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_tracker_len(tracker: *const ApriltagRsTracker) -> usize {
+    catch_panic(0, || match tracker.as_ref() {
+        Some(tracker) => tracker.items.len(),
+        None => 0,
+    })
+}
+
+/// Gets the track at the index. Tracks are ordered by track id.
+///
+/// It returns NULL if the index is out of range. The pointer is valid
+/// until the next update or the tracker is freed.
+///
+/// # Safety
+/// The pointer must be NULL or a valid tracker handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_tracker_get(
+    tracker: *const ApriltagRsTracker,
+    index: usize,
+) -> *const ApriltagRsTrack {
+    catch_panic(ptr::null(), || {
+        match tracker.as_ref().and_then(|t| t.items.get(index)) {
+            Some(item) => item,
+            None => ptr::null(),
+        }
+    })
+}
+
+/// Drop all tracks. Track ids are not reused.
+///
+/// # Safety
+/// The pointer must be NULL or a valid tracker handle.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_tracker_clear(tracker: *mut ApriltagRsTracker) {
+    catch_panic((), || {
+        if let Some(tracker) = tracker.as_mut() {
+            tracker.clear();
+        }
+    })
+}
+
+/// Free the tracker.
+///
+/// # Safety
+/// The pointer must be NULL or a valid tracker handle, which is not
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn apriltag_rs_tracker_free(tracker: *mut ApriltagRsTracker) {
+    catch_panic((), || {
+        if let Some(tracker) = NonNull::new(tracker) {
+            drop(Box::from_raw(tracker.as_ptr()));
+        }
+    })
+}
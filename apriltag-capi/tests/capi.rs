@@ -0,0 +1,305 @@
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    Family, PoseRecord,
+};
+use apriltag_capi::*;
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+};
+
+fn last_error() -> String {
+    let message = apriltag_rs_last_error();
+    assert!(!message.is_null());
+    unsafe { CStr::from_ptr(message) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn detect_with_json_config() {
+    let family = Family::tag_16h5();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let tag = SyntheticTag {
+        family: &family,
+        id: 3,
+        size: 0.1,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.2, 0.1, 0.4),
+            translation: [0.0, 0.01, 0.4],
+        },
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+    let image = &scene.image;
+
+    let config = CString::new(
+        r#"{
+            "families": [{ "family": "tag36h11", "bits_corrected": 1 }],
+            "tag_params": { "tagsize": 0.1, "fx": 300, "fy": 300, "cx": 160, "cy": 120 }
+        }"#,
+    )
+    .unwrap();
+
+    unsafe {
+        let detector = apriltag_rs_detector_from_json(config.as_ptr());
+        assert!(!detector.is_null());
+
+        // The tag is not found before its family is added.
+        let mut detections = ptr::null_mut();
+        let status = apriltag_rs_detector_detect(
+            detector,
+            image.as_slice().as_ptr(),
+            image.width(),
+            image.height(),
+            image.stride(),
+            &mut detections,
+        );
+        assert_eq!(status, ApriltagRsStatus::Ok);
+        assert_eq!(apriltag_rs_detections_len(detections), 0);
+        apriltag_rs_detections_free(detections);
+
+        let name = CString::new("tag16h5").unwrap();
+        let status = apriltag_rs_detector_add_family(detector, name.as_ptr(), 0);
+        assert_eq!(status, ApriltagRsStatus::Ok);
+        assert_eq!(apriltag_rs_detector_family_count(detector), 2);
+
+        let mut detections = ptr::null_mut();
+        let status = apriltag_rs_detector_detect(
+            detector,
+            image.as_slice().as_ptr(),
+            image.width(),
+            image.height(),
+            image.stride(),
+            &mut detections,
+        );
+        assert_eq!(status, ApriltagRsStatus::Ok);
+        assert_eq!(apriltag_rs_detections_len(detections), 1);
+        assert!(apriltag_rs_detections_get(detections, 1).is_null());
+
+        let detection = &*apriltag_rs_detections_get(detections, 0);
+        assert_eq!(
+            CStr::from_ptr(detection.family).to_str().unwrap(),
+            "tag16h5"
+        );
+        assert_eq!(detection.id, 3);
+        assert_eq!(detection.hamming, 0);
+        assert!(detection.has_pose);
+        assert!((detection.pose.translation[2] - 0.4).abs() < 0.01);
+
+        let json = apriltag_rs_detections_to_json(detections);
+        let value: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+        apriltag_rs_string_free(json);
+        assert_eq!(value[0]["family"], "tag16h5");
+        assert_eq!(value[0]["id"], 3);
+        assert!(value[0]["pose"]["translation"].is_array());
+
+        apriltag_rs_detections_free(detections);
+        apriltag_rs_detector_free(detector);
+    }
+}
+
+#[test]
+fn manage_families() {
+    unsafe {
+        let detector = apriltag_rs_detector_new();
+        assert_eq!(apriltag_rs_detector_family_count(detector), 1);
+
+        let name = CString::new("tag25h9").unwrap();
+        assert_eq!(
+            apriltag_rs_detector_add_family(detector, name.as_ptr(), 1),
+            ApriltagRsStatus::Ok
+        );
+        // Adding a present family updates its bits.
+        assert_eq!(
+            apriltag_rs_detector_add_family(detector, name.as_ptr(), 0),
+            ApriltagRsStatus::Ok
+        );
+        assert_eq!(apriltag_rs_detector_family_count(detector), 2);
+
+        let json = apriltag_rs_detector_config_json(detector);
+        let value: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+        apriltag_rs_string_free(json);
+        assert_eq!(value["families"][1]["family"], "tag25h9");
+        assert_eq!(value["families"][1]["bits_corrected"], 0);
+
+        let unknown = CString::new("tag99h1").unwrap();
+        assert_eq!(
+            apriltag_rs_detector_add_family(detector, unknown.as_ptr(), 0),
+            ApriltagRsStatus::InvalidArgument
+        );
+        assert!(last_error().contains("tag99h1"));
+
+        let absent = CString::new("tag16h5").unwrap();
+        assert_eq!(
+            apriltag_rs_detector_remove_family(detector, absent.as_ptr()),
+            ApriltagRsStatus::NotFound
+        );
+
+        // The detector cannot be rebuilt without families.
+        let tag36h11 = CString::new("tag36h11").unwrap();
+        apriltag_rs_detector_remove_family(detector, name.as_ptr());
+        apriltag_rs_detector_remove_family(detector, tag36h11.as_ptr());
+        assert_eq!(apriltag_rs_detector_family_count(detector), 0);
+        let pixels = [0u8; 16];
+        let mut detections = ptr::null_mut();
+        let status =
+            apriltag_rs_detector_detect(detector, pixels.as_ptr(), 4, 4, 4, &mut detections);
+        assert_eq!(status, ApriltagRsStatus::DetectorError);
+        assert!(detections.is_null());
+
+        apriltag_rs_detector_free(detector);
+    }
+}
+
+#[test]
+fn reject_invalid_arguments() {
+    unsafe {
+        let config = CString::new(r#"{ "decimation": "fast" }"#).unwrap();
+        assert!(apriltag_rs_detector_from_json(config.as_ptr()).is_null());
+        assert!(last_error().contains("configuration"));
+
+        let path = CString::new("/nonexistent/detector.json").unwrap();
+        assert!(apriltag_rs_detector_from_json_file(path.as_ptr()).is_null());
+        assert!(last_error().contains("/nonexistent/detector.json"));
+
+        let detector = apriltag_rs_detector_new();
+        let pixels = [0u8; 16];
+        let mut detections = ptr::null_mut();
+        let status =
+            apriltag_rs_detector_detect(detector, pixels.as_ptr(), 4, 4, 2, &mut detections);
+        assert_eq!(status, ApriltagRsStatus::InvalidArgument);
+        let status = apriltag_rs_detector_detect(detector, ptr::null(), 4, 4, 4, &mut detections);
+        assert_eq!(status, ApriltagRsStatus::NullPointer);
+        apriltag_rs_detector_free(detector);
+
+        assert_eq!(apriltag_rs_detections_len(ptr::null()), 0);
+        apriltag_rs_detector_free(ptr::null_mut());
+        apriltag_rs_detections_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn track_tags_and_estimate_bundle_pose() {
+    let family = Family::tag_16h5();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let tags: Vec<_> = [(1, -0.06), (2, 0.06)]
+        .into_iter()
+        .map(|(id, x)| SyntheticTag {
+            family: &family,
+            id,
+            size: 0.05,
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(0.0, 0.0, 0.0),
+                translation: [x, 0.0, 0.5],
+            },
+        })
+        .collect();
+    let scene = synthetic::render(&camera, &tags, &Effects::default()).unwrap();
+    let image = &scene.image;
+
+    let config =
+        CString::new(r#"{ "families": [{ "family": "tag16h5", "bits_corrected": 0 }] }"#).unwrap();
+    let bundle_json = CString::new(
+        r#"{
+            "name": "pair",
+            "tags": [
+                { "family": "tag16h5", "id": 1,
+                  "pose": { "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]], "translation": [-0.06, 0, 0] } },
+                { "family": "tag16h5", "id": 2,
+                  "pose": { "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]], "translation": [0.06, 0, 0] } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let params = ApriltagRsTagParams {
+        tagsize: 0.05,
+        fx: 300.0,
+        fy: 300.0,
+        cx: 160.0,
+        cy: 120.0,
+    };
+
+    unsafe {
+        let detector = apriltag_rs_detector_from_json(config.as_ptr());
+        let tracker = apriltag_rs_tracker_new(2, 20.0);
+        let bundle = apriltag_rs_bundle_from_json(bundle_json.as_ptr());
+        assert!(!detector.is_null() && !tracker.is_null() && !bundle.is_null());
+        assert_eq!(apriltag_rs_bundle_tag_count(bundle), 2);
+
+        let detect = || {
+            let mut detections = ptr::null_mut();
+            let status = apriltag_rs_detector_detect(
+                detector,
+                image.as_slice().as_ptr(),
+                image.width(),
+                image.height(),
+                image.stride(),
+                &mut detections,
+            );
+            assert_eq!(status, ApriltagRsStatus::Ok);
+            detections
+        };
+
+        // The tracks keep their ids across frames.
+        for frame in 1..=2 {
+            let detections = detect();
+            assert_eq!(
+                apriltag_rs_tracker_update(tracker, detections),
+                ApriltagRsStatus::Ok
+            );
+            apriltag_rs_detections_free(detections);
+
+            assert_eq!(apriltag_rs_tracker_len(tracker), 2);
+            let mut ids: Vec<_> = (0..2)
+                .map(|index| {
+                    let track = &*apriltag_rs_tracker_get(tracker, index);
+                    assert_eq!(CStr::from_ptr(track.family).to_str().unwrap(), "tag16h5");
+                    assert_eq!((track.hits, track.missed), (frame, 0));
+                    (track.track_id, track.id)
+                })
+                .collect();
+            ids.sort();
+            assert_eq!(ids, [(0, 1), (1, 2)]);
+        }
+
+        let detections = detect();
+        let mut pose = std::mem::zeroed::<ApriltagRsPose>();
+        assert_eq!(
+            apriltag_rs_bundle_estimate_pose(bundle, detections, &params, &mut pose),
+            ApriltagRsStatus::Ok
+        );
+        for (lhs, rhs) in pose.translation.iter().zip([0.0, 0.0, 0.5]) {
+            assert!((lhs - rhs).abs() < 0.01);
+        }
+        apriltag_rs_detections_free(detections);
+
+        // Without detections, the tracks are missed and the bundle is
+        // not found.
+        let pixels = vec![0u8; 64 * 64];
+        let mut detections = ptr::null_mut();
+        let status =
+            apriltag_rs_detector_detect(detector, pixels.as_ptr(), 64, 64, 64, &mut detections);
+        assert_eq!(status, ApriltagRsStatus::Ok);
+        apriltag_rs_tracker_update(tracker, detections);
+        assert_eq!((*apriltag_rs_tracker_get(tracker, 0)).missed, 1);
+        assert_eq!(
+            apriltag_rs_bundle_estimate_pose(bundle, detections, &params, &mut pose),
+            ApriltagRsStatus::NotFound
+        );
+        apriltag_rs_detections_free(detections);
+
+        apriltag_rs_tracker_clear(tracker);
+        assert_eq!(apriltag_rs_tracker_len(tracker), 0);
+
+        apriltag_rs_bundle_free(bundle);
+        apriltag_rs_tracker_free(tracker);
+        apriltag_rs_detector_free(detector);
+
+        let invalid = CString::new(r#"{ "tags": [] }"#).unwrap();
+        assert!(apriltag_rs_bundle_from_json(invalid.as_ptr()).is_null());
+        assert!(last_error().contains("no tags"));
+        assert!(apriltag_rs_tracker_new(2, -1.0).is_null());
+    }
+}
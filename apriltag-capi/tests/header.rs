@@ -0,0 +1,30 @@
+use std::{env, fs, path::Path};
+
+/// Check that the committed header matches the exported items.
+///
+/// Run with `APRILTAG_CAPI_UPDATE_HEADER=1` to regenerate the header.
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let header_path = crate_dir.join("include/apriltag_capi.h");
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+
+    let mut generated = vec![];
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    if env::var_os("APRILTAG_CAPI_UPDATE_HEADER").is_some() {
+        fs::write(&header_path, &generated).unwrap();
+    }
+    let committed = fs::read_to_string(&header_path).unwrap_or_default();
+    assert!(
+        committed == generated,
+        "{} is outdated. Run the test with APRILTAG_CAPI_UPDATE_HEADER=1 to regenerate it.",
+        header_path.display()
+    );
+}
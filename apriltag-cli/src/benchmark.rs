@@ -0,0 +1,529 @@
+//! The `benchmark` subcommand.
+
+use crate::{
+    detector_opts::{DetectorOpts, FamilySpec, ThresholdOpts},
+    input::{self, InputOpts},
+};
+use anyhow::{ensure, Context, Result};
+use apriltag::{
+    eval::{Dataset, EvalParams, EvalReport, Evaluation, LabelledTag, Sample},
+    DetectionRecord,
+};
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Options of the `benchmark` subcommand.
+///
+/// Each setting option takes a comma-separated list of values, and
+/// every combination of the values is benchmarked. Unset options use
+/// the values of the `--config` file, or the library defaults.
+#[derive(Debug, Clone, Args)]
+pub struct BenchmarkOpts {
+    #[clap(flatten)]
+    pub input: InputOpts,
+
+    /// Detector configuration in the JSON format of
+    /// `apriltag::DetectorConfig`, on which the settings are applied.
+    #[clap(long)]
+    pub config: Option<PathBuf>,
+
+    /// Tag family in format "name[:bits]". It can be specified multiple
+    /// times. It defaults to the families of the configuration, or
+    /// tag36h11.
+    #[clap(long = "family")]
+    pub families: Vec<FamilySpec>,
+
+    /// Decimation factors.
+    #[clap(long, value_delimiter = ',')]
+    pub decimation: Vec<f32>,
+
+    /// Standard deviations of Gaussian blur.
+    #[clap(long, value_delimiter = ',')]
+    pub sigma: Vec<f32>,
+
+    /// Whether to refine edges.
+    #[clap(long, value_delimiter = ',')]
+    pub refine_edges: Vec<bool>,
+
+    /// Sharpening applied to decoded images.
+    #[clap(long, value_delimiter = ',')]
+    pub sharpening: Vec<f64>,
+
+    /// Numbers of detector threads.
+    #[clap(long, value_delimiter = ',')]
+    pub threads: Vec<u8>,
+
+    /// Minimal numbers of pixels per candidate.
+    #[clap(long, value_delimiter = ',')]
+    pub min_cluster_pixels: Vec<u32>,
+
+    /// Numbers of local maxima considered when segmenting a cluster.
+    #[clap(long, value_delimiter = ',')]
+    pub max_maxima_number: Vec<u32>,
+
+    /// Minimal angles between edges in degrees.
+    #[clap(long, value_delimiter = ',')]
+    pub min_angle: Vec<f64>,
+
+    /// Minimal deviations from straight angles in degrees.
+    #[clap(long, value_delimiter = ',')]
+    pub min_opposite_angle: Vec<f64>,
+
+    /// Maximal mean squared errors of line fitting.
+    #[clap(long, value_delimiter = ',')]
+    pub max_mse: Vec<f32>,
+
+    /// Minimal differences between white and black intensities.
+    #[clap(long, value_delimiter = ',')]
+    pub min_white_black_diff: Vec<u8>,
+
+    /// Whether to deglitch images.
+    #[clap(long, value_delimiter = ',')]
+    pub deglitch: Vec<bool>,
+
+    /// Number of times each image is processed per setting.
+    #[clap(long, default_value = "1")]
+    pub repeat: usize,
+
+    /// Ground truth in the JSON Lines format written by the `detect`
+    /// subcommand, or a CSV dataset of `apriltag::eval` if the file
+    /// name ends with `.csv`. Relative image paths are resolved against
+    /// the directory of the file, or the current directory if the image
+    /// is not found there. Images without ground truth are excluded from
+    /// the accuracy.
+    #[clap(long)]
+    pub ground_truth: Option<PathBuf>,
+
+    /// Maximal distance in pixels between the centers of a detection
+    /// and its ground truth to count as a match.
+    #[clap(long, default_value = "5")]
+    pub match_distance: f64,
+
+    /// Report format.
+    #[clap(long, value_enum, default_value = "table")]
+    pub format: ReportFormat,
+}
+
+/// The report format of the `benchmark` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// A human-readable table.
+    Table,
+    /// A JSON array with an entry per setting.
+    Json,
+}
+
+/// A detector setting in the grid.
+#[derive(Debug, Clone)]
+struct Setting {
+    opts: DetectorOpts,
+    params: Vec<(&'static str, String)>,
+}
+
+/// The benchmark result of a setting.
+#[derive(Debug, Clone, Serialize)]
+struct Report {
+    settings: BTreeMap<&'static str, String>,
+    images: usize,
+    detections: usize,
+    detections_per_image: f64,
+    latency_ms: LatencyStats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accuracy: Option<EvalReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LatencyStats {
+    mean: f64,
+    p50: f64,
+    p90: f64,
+    p99: f64,
+    max: f64,
+}
+
+/// A line of the JSON output of the `detect` subcommand.
+#[derive(Debug, Clone, Deserialize)]
+struct DetectedImage {
+    path: PathBuf,
+    detections: Vec<DetectionRecord>,
+}
+
+pub fn run(opts: BenchmarkOpts) -> Result<()> {
+    ensure!(opts.repeat > 0, "--repeat must be positive");
+
+    let files = opts.input.files()?;
+    ensure!(!files.is_empty(), "no input files");
+
+    let ground_truth = opts
+        .ground_truth
+        .as_deref()
+        .map(load_ground_truth)
+        .transpose()?;
+    let labels: Option<HashMap<PathBuf, &Sample>> = ground_truth.as_ref().map(|dataset| {
+        dataset
+            .samples
+            .iter()
+            .map(|sample| (canonicalize(&sample.image), sample))
+            .collect()
+    });
+    if let Some(labels) = &labels {
+        let unlabelled = files
+            .iter()
+            .filter(|path| !labels.contains_key(&canonicalize(path)))
+            .count();
+        if unlabelled > 0 {
+            eprintln!(
+                "warning: {unlabelled} images without ground truth are excluded from the accuracy"
+            );
+        }
+    }
+
+    let reports: Vec<Report> = settings_grid(&opts)
+        .into_iter()
+        .map(|setting| benchmark(&setting, &files, labels.as_ref(), &opts))
+        .collect::<Result<_>>()?;
+
+    match opts.format {
+        ReportFormat::Table => print_table(&reports),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+    }
+
+    Ok(())
+}
+
+/// Enumerate every combination of the setting values.
+fn settings_grid(opts: &BenchmarkOpts) -> Vec<Setting> {
+    let base = Setting {
+        opts: DetectorOpts {
+            config: opts.config.clone(),
+            families: opts.families.clone(),
+            decimation: None,
+            sigma: None,
+            refine_edges: None,
+            sharpening: None,
+            threads: None,
+            thresholds: ThresholdOpts::default(),
+        },
+        params: vec![],
+    };
+
+    let mut grid = vec![base];
+    expand(&mut grid, "decimation", &opts.decimation, |o, v| {
+        o.decimation = Some(v)
+    });
+    expand(&mut grid, "sigma", &opts.sigma, |o, v| o.sigma = Some(v));
+    expand(&mut grid, "refine_edges", &opts.refine_edges, |o, v| {
+        o.refine_edges = Some(v)
+    });
+    expand(&mut grid, "sharpening", &opts.sharpening, |o, v| {
+        o.sharpening = Some(v)
+    });
+    expand(&mut grid, "threads", &opts.threads, |o, v| {
+        o.threads = Some(v)
+    });
+    expand(
+        &mut grid,
+        "min_cluster_pixels",
+        &opts.min_cluster_pixels,
+        |o, v| o.thresholds.min_cluster_pixels = Some(v),
+    );
+    expand(
+        &mut grid,
+        "max_maxima_number",
+        &opts.max_maxima_number,
+        |o, v| o.thresholds.max_maxima_number = Some(v),
+    );
+    expand(&mut grid, "min_angle", &opts.min_angle, |o, v| {
+        o.thresholds.min_angle = Some(v)
+    });
+    expand(
+        &mut grid,
+        "min_opposite_angle",
+        &opts.min_opposite_angle,
+        |o, v| o.thresholds.min_opposite_angle = Some(v),
+    );
+    expand(&mut grid, "max_mse", &opts.max_mse, |o, v| {
+        o.thresholds.max_mse = Some(v)
+    });
+    expand(
+        &mut grid,
+        "min_white_black_diff",
+        &opts.min_white_black_diff,
+        |o, v| o.thresholds.min_white_black_diff = Some(v),
+    );
+    expand(&mut grid, "deglitch", &opts.deglitch, |o, v| {
+        o.thresholds.deglitch = Some(v)
+    });
+
+    grid
+}
+
+/// Replace each setting with a copy per value. Empty values leave the
+/// settings unchanged.
+fn expand<T, F>(grid: &mut Vec<Setting>, name: &'static str, values: &[T], set: F)
+where
+    T: Clone + ToString,
+    F: Fn(&mut DetectorOpts, T),
+{
+    if values.is_empty() {
+        return;
+    }
+
+    *grid = grid
+        .iter()
+        .flat_map(|setting| {
+            values.iter().map(|value| {
+                let mut setting = setting.clone();
+                set(&mut setting.opts, value.clone());
+                setting.params.push((name, value.to_string()));
+                setting
+            })
+        })
+        .collect();
+}
+
+fn benchmark(
+    setting: &Setting,
+    files: &[PathBuf],
+    labels: Option<&HashMap<PathBuf, &Sample>>,
+    opts: &BenchmarkOpts,
+) -> Result<Report> {
+    let mut detector = setting.opts.build()?;
+    let mut latencies = vec![];
+    let mut ndetections = 0;
+    let mut evaluation = labels.map(|_| {
+        Evaluation::new(EvalParams {
+            match_distance: opts.match_distance,
+        })
+    });
+
+    // Load one image at a time to keep the memory bounded on large
+    // datasets. Only the detection is timed.
+    for path in files {
+        let image = input::load_image(path)?;
+        let mut detections = vec![];
+        for _ in 0..opts.repeat {
+            let since = Instant::now();
+            detections = detector.detect(&image);
+            latencies.push(since.elapsed());
+        }
+        ndetections += detections.len();
+
+        if let (Some(evaluation), Some(labels)) = (&mut evaluation, labels) {
+            if let Some(sample) = labels.get(&canonicalize(path)) {
+                evaluation.add_sample(sample, &detections);
+            }
+        }
+    }
+
+    Ok(Report {
+        settings: setting.params.iter().cloned().collect(),
+        images: files.len(),
+        detections: ndetections,
+        detections_per_image: ratio(ndetections, files.len()),
+        latency_ms: latency_stats(&mut latencies),
+        accuracy: evaluation.map(Evaluation::finish),
+    })
+}
+
+fn latency_stats(latencies: &mut [Duration]) -> LatencyStats {
+    latencies.sort_unstable();
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+
+    // Nearest-rank percentile.
+    let percentile = |p: f64| {
+        let rank = ((p / 100.0 * latencies.len() as f64).ceil() as usize).max(1);
+        ms(latencies[rank - 1])
+    };
+    let total: Duration = latencies.iter().sum();
+
+    LatencyStats {
+        mean: ms(total) / latencies.len() as f64,
+        p50: percentile(50.0),
+        p90: percentile(90.0),
+        p99: percentile(99.0),
+        max: ms(*latencies.last().unwrap()),
+    }
+}
+
+fn ratio(num: usize, den: usize) -> f64 {
+    if den == 0 {
+        0.0
+    } else {
+        num as f64 / den as f64
+    }
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Load the ground truth as a dataset.
+fn load_ground_truth(path: &Path) -> Result<Dataset> {
+    if path.extension().is_some_and(|ext| ext == "csv") {
+        return Ok(Dataset::from_csv_file(path)?);
+    }
+
+    let text =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    parse_detect_output(&text, dir).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// Convert the JSON output of the `detect` subcommand to a dataset.
+/// Each non-empty line is an image.
+fn parse_detect_output(text: &str, dir: &Path) -> Result<Dataset> {
+    let samples = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let entry: DetectedImage = serde_json::from_str(line)
+                .with_context(|| format!("invalid entry at line {}", index + 1))?;
+            let image = match dir.join(&entry.path) {
+                path if path.exists() => path,
+                _ => entry.path,
+            };
+            let tags = entry
+                .detections
+                .into_iter()
+                .map(|detection| LabelledTag {
+                    family: Some(detection.family),
+                    id: detection.id,
+                    corners: detection.corners,
+                    pose: None,
+                })
+                .collect();
+            Ok(Sample {
+                image,
+                tag_params: None,
+                tags,
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Dataset { samples })
+}
+
+fn print_table(reports: &[Report]) {
+    let header = [
+        "settings",
+        "images",
+        "dets/img",
+        "mean ms",
+        "p50 ms",
+        "p90 ms",
+        "p99 ms",
+        "max ms",
+        "precision",
+        "recall",
+    ];
+    let rows: Vec<[String; 10]> = reports
+        .iter()
+        .map(|report| {
+            let settings: Vec<_> = report
+                .settings
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect();
+            let settings = if settings.is_empty() {
+                "default".to_string()
+            } else {
+                settings.join(" ")
+            };
+            let latency = &report.latency_ms;
+            let format_ratio = |ratio: Option<f64>| match ratio {
+                Some(ratio) => format!("{ratio:.3}"),
+                None => "-".to_string(),
+            };
+            let accuracy = report.accuracy.as_ref();
+            let precision = format_ratio(accuracy.and_then(EvalReport::precision));
+            let recall = format_ratio(accuracy.and_then(EvalReport::recall));
+
+            [
+                settings,
+                report.images.to_string(),
+                format!("{:.2}", report.detections_per_image),
+                format!("{:.2}", latency.mean),
+                format!("{:.2}", latency.p50),
+                format!("{:.2}", latency.p90),
+                format!("{:.2}", latency.p99),
+                format!("{:.2}", latency.max),
+                precision,
+                recall,
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].len())
+                .chain([header[col].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let print_row = |cells: Vec<&str>| {
+        let line: Vec<_> = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, &width))| {
+                if col == 0 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        println!("{}", line.join("  "));
+    };
+
+    print_row(header.to_vec());
+    for row in &rows {
+        print_row(row.iter().map(String::as_str).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_detect_output_as_dataset() {
+        let text = r#"
+{"path":"a.png","width":64,"height":64,"detections":[{"family":"tag36h11","id":3,"hamming":0,"decision_margin":100.0,"center":[5.0,5.0],"corners":[[0.0,10.0],[10.0,10.0],[10.0,0.0],[0.0,0.0]],"homography":[[0.0,0.0,0.0],[0.0,0.0,0.0],[0.0,0.0,0.0]]}]}
+
+{"path":"b.png","width":64,"height":64,"detections":[]}
+"#;
+
+        let dataset = parse_detect_output(text, Path::new("missing-dir")).unwrap();
+        assert_eq!(dataset.samples.len(), 2);
+        assert_eq!(dataset.samples[0].image, Path::new("a.png"));
+        let tag = &dataset.samples[0].tags[0];
+        assert_eq!(tag.family.as_deref(), Some("tag36h11"));
+        assert_eq!(tag.id, 3);
+        assert_eq!(tag.center(), [5.0, 5.0]);
+        assert!(dataset.samples[1].tags.is_empty());
+
+        let err = parse_detect_output("{}\n[", Path::new("")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid entry at line 1");
+    }
+
+    #[test]
+    fn latency_percentiles() {
+        let mut latencies: Vec<_> = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = latency_stats(&mut latencies);
+        assert_eq!(stats.p50, 50.0);
+        assert_eq!(stats.p90, 90.0);
+        assert_eq!(stats.p99, 99.0);
+        assert_eq!(stats.max, 100.0);
+        assert_eq!(stats.mean, 50.5);
+    }
+}
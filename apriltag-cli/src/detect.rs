@@ -0,0 +1,257 @@
+//! The `detect` subcommand.
+
+use crate::{
+    detector_opts::{DetectorOpts, TagParamsArg},
+    input::{self, InputOpts},
+};
+use anyhow::{bail, ensure, Context, Result};
+use apriltag::{DetectionRecord, PoseRecord};
+use apriltag_image::annotate;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// Options of the `detect` subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct DetectOpts {
+    #[clap(flatten)]
+    pub input: InputOpts,
+
+    #[clap(flatten)]
+    pub detector: DetectorOpts,
+
+    /// Tag parameters in format "tagsize,fx,fy,cx,cy". It enables pose
+    /// estimation, and overrides the tag parameters of the
+    /// configuration.
+    #[clap(long)]
+    pub tag_params: Option<TagParamsArg>,
+
+    /// Output format.
+    #[clap(long, value_enum, default_value = "json")]
+    pub format: OutputFormat,
+
+    /// Output path. It is a file for JSON and CSV formats, and is
+    /// printed to stdout if not set. Each image is written as soon as it
+    /// is processed. It is a directory for annotated
+    /// images and is required.
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Continue processing the rest of images if an image fails to load.
+    #[clap(long)]
+    pub keep_going: bool,
+}
+
+/// The output format of the `detect` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// JSON Lines with an object per image.
+    Json,
+    /// A CSV table with a row per detection.
+    Csv,
+    /// Input images annotated with detections.
+    Image,
+}
+
+/// The detections on an image.
+#[derive(Debug, Clone, Serialize)]
+struct ImageOutput {
+    path: PathBuf,
+    width: usize,
+    height: usize,
+    detections: Vec<DetectionOutput>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DetectionOutput {
+    #[serde(flatten)]
+    detection: DetectionRecord,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pose: Option<PoseRecord>,
+}
+
+/// The destination of the outputs.
+enum Sink {
+    Json(Box<dyn Write>),
+    Csv(Box<csv::Writer<Box<dyn Write>>>),
+    Image(PathBuf),
+}
+
+/// A row of the CSV output.
+#[derive(Debug, Clone, Serialize)]
+struct CsvRow<'a> {
+    path: &'a Path,
+    family: &'a str,
+    id: usize,
+    hamming: usize,
+    decision_margin: f32,
+    center_x: f64,
+    center_y: f64,
+    corner0_x: f64,
+    corner0_y: f64,
+    corner1_x: f64,
+    corner1_y: f64,
+    corner2_x: f64,
+    corner2_y: f64,
+    corner3_x: f64,
+    corner3_y: f64,
+}
+
+pub fn run(opts: DetectOpts) -> Result<()> {
+    let DetectOpts {
+        input,
+        detector,
+        tag_params,
+        format,
+        output,
+        keep_going,
+    } = opts;
+
+    let files = input.files()?;
+    ensure!(!files.is_empty(), "no input files");
+
+    let config = detector.to_config()?;
+    let tag_params = match tag_params {
+        Some(TagParamsArg(params)) => Some(params),
+        None => config.tag_params.clone(),
+    };
+    let mut detector = config.build()?;
+
+    let mut sink = match format {
+        OutputFormat::Json => Sink::Json(open_output(output.as_deref())?),
+        OutputFormat::Csv => {
+            let writer = csv::Writer::from_writer(open_output(output.as_deref())?);
+            Sink::Csv(Box::new(writer))
+        }
+        OutputFormat::Image => {
+            let Some(dir) = output else {
+                bail!("--output directory is required for image output");
+            };
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("failed to create directory {}", dir.display()))?;
+            Sink::Image(dir)
+        }
+    };
+
+    for path in files {
+        let image = match input::load_image(&path) {
+            Ok(image) => image,
+            Err(err) if keep_going => {
+                eprintln!("warning: {err:#}");
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let detections = detector
+            .detect(&image)
+            .iter()
+            .map(|detection| DetectionOutput {
+                detection: detection.to_record(),
+                pose: tag_params
+                    .as_ref()
+                    .and_then(|params| Some(detection.estimate_tag_pose(params)?.to_record())),
+            })
+            .collect();
+        let image_output = ImageOutput {
+            width: image.width(),
+            height: image.height(),
+            path,
+            detections,
+        };
+        sink.write(&image_output)?;
+    }
+
+    Ok(())
+}
+
+impl Sink {
+    /// Write the detections on an image and flush them, so that the
+    /// output can be consumed while the rest of images are processed.
+    fn write(&mut self, output: &ImageOutput) -> Result<()> {
+        match self {
+            Sink::Json(writer) => {
+                serde_json::to_writer(&mut *writer, output)?;
+                writeln!(writer)?;
+                writer.flush()?;
+            }
+            Sink::Csv(writer) => {
+                for detection in &output.detections {
+                    writer.serialize(CsvRow::new(&output.path, &detection.detection))?;
+                }
+                writer.flush()?;
+            }
+            Sink::Image(dir) => write_annotated(output, dir)?,
+        }
+        Ok(())
+    }
+}
+
+impl<'a> CsvRow<'a> {
+    fn new(path: &'a Path, detection: &'a DetectionRecord) -> Self {
+        let [center_x, center_y] = detection.center;
+        let [[corner0_x, corner0_y], [corner1_x, corner1_y], [corner2_x, corner2_y], [corner3_x, corner3_y]] =
+            detection.corners;
+
+        Self {
+            path,
+            family: &detection.family,
+            id: detection.id,
+            hamming: detection.hamming,
+            decision_margin: detection.decision_margin,
+            center_x,
+            center_y,
+            corner0_x,
+            corner0_y,
+            corner1_x,
+            corner1_y,
+            corner2_x,
+            corner2_y,
+            corner3_x,
+            corner3_y,
+        }
+    }
+}
+
+fn open_output(path: Option<&Path>) -> Result<Box<dyn Write>> {
+    let writer: Box<dyn Write> = match path {
+        Some(path) => {
+            let file = File::create(path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            Box::new(BufWriter::new(file))
+        }
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    Ok(writer)
+}
+
+fn write_annotated(output: &ImageOutput, dir: &Path) -> Result<()> {
+    let mut image = input::open_image(&output.path)?.to_rgb8();
+    let detections: Vec<_> = output
+        .detections
+        .iter()
+        .map(|detection| detection.detection.clone())
+        .collect();
+    annotate::draw_detections(&mut image, &detections);
+
+    // Mirror the input path in the output directory to avoid name
+    // collisions among files from different directories.
+    let relative: PathBuf = output
+        .path
+        .components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect();
+    let path = dir.join(relative).with_extension("png");
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create directory {}", parent.display()))?;
+    }
+    image
+        .save(&path)
+        .with_context(|| format!("failed to save {}", path.display()))?;
+    Ok(())
+}
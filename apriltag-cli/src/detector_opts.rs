@@ -0,0 +1,296 @@
+//! Detector options shared by subcommands.
+
+use anyhow::{ensure, Context, Error, Result};
+use apriltag::{
+    detector::QuadThresholds, Detector, DetectorConfig, FamilyConfig, FamilyKind, TagParams,
+};
+use clap::Args;
+use measurements::angle::Angle;
+use noisy_float::prelude::R32;
+use std::{path::PathBuf, str::FromStr};
+
+/// Tag families and detector tunables.
+///
+/// The options override the values of the `--config` file, and the
+/// library defaults are used for values set by neither.
+#[derive(Debug, Clone, Args)]
+pub struct DetectorOpts {
+    /// Detector configuration in the JSON format of
+    /// `apriltag::DetectorConfig`. It also sets the preprocessing,
+    /// multi-scale detection and filtering of detections.
+    #[clap(long)]
+    pub config: Option<PathBuf>,
+
+    /// Tag family in format "name[:bits]", where bits is the number of
+    /// corrected bits. It can be specified multiple times. It defaults
+    /// to the families of the configuration, or tag36h11.
+    #[clap(long = "family")]
+    pub families: Vec<FamilySpec>,
+
+    /// Decimation factor applied to the input image for quad detection.
+    #[clap(long)]
+    pub decimation: Option<f32>,
+
+    /// Standard deviation of Gaussian blur. Negative values sharpen the
+    /// image.
+    #[clap(long)]
+    pub sigma: Option<f32>,
+
+    /// Whether to refine the quad edges on the full-resolution image.
+    #[clap(long)]
+    pub refine_edges: Option<bool>,
+
+    /// Sharpening applied to decoded images.
+    #[clap(long)]
+    pub sharpening: Option<f64>,
+
+    /// Number of threads used by the detector.
+    #[clap(long)]
+    pub threads: Option<u8>,
+
+    #[clap(flatten)]
+    pub thresholds: ThresholdOpts,
+}
+
+/// Quad thresholds. Unset values are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Args)]
+pub struct ThresholdOpts {
+    /// Minimal number of pixels per candidate.
+    #[clap(long)]
+    pub min_cluster_pixels: Option<u32>,
+
+    /// Number of local maxima considered when segmenting a cluster into
+    /// a quad.
+    #[clap(long)]
+    pub max_maxima_number: Option<u32>,
+
+    /// Minimal angle between edges in degrees.
+    #[clap(long)]
+    pub min_angle: Option<f64>,
+
+    /// Minimal deviation from straight angles between edges in degrees.
+    #[clap(long)]
+    pub min_opposite_angle: Option<f64>,
+
+    /// Maximal mean squared error of line fitting.
+    #[clap(long)]
+    pub max_mse: Option<f32>,
+
+    /// Minimal difference between white and black intensities.
+    #[clap(long)]
+    pub min_white_black_diff: Option<u8>,
+
+    /// Enable deglitching for very noisy images.
+    #[clap(long)]
+    pub deglitch: Option<bool>,
+}
+
+impl DetectorOpts {
+    /// Load the configuration file and override it with the options.
+    pub fn to_config(&self) -> Result<DetectorConfig> {
+        let Self {
+            config,
+            families,
+            decimation,
+            sigma,
+            refine_edges,
+            sharpening,
+            threads,
+            thresholds,
+        } = self;
+
+        let mut config = match config {
+            Some(path) => DetectorConfig::from_json_file(path)?,
+            None => DetectorConfig::default(),
+        };
+
+        if !families.is_empty() {
+            config.families = families
+                .iter()
+                .map(|family| FamilyConfig {
+                    family: family.kind,
+                    bits_corrected: family.bits,
+                })
+                .collect();
+        }
+        if decimation.is_some() {
+            config.decimation = *decimation;
+        }
+        if sigma.is_some() {
+            config.sigma = *sigma;
+        }
+        if refine_edges.is_some() {
+            config.refine_edges = *refine_edges;
+        }
+        if sharpening.is_some() {
+            config.sharpening = *sharpening;
+        }
+        if threads.is_some() {
+            config.threads = *threads;
+        }
+        if *thresholds != ThresholdOpts::default() {
+            let base = config.thresholds.unwrap_or_default();
+            config.thresholds = Some(thresholds.apply(base)?);
+        }
+
+        Ok(config)
+    }
+
+    /// Create a detector with the options.
+    pub fn build(&self) -> Result<Detector> {
+        Ok(self.to_config()?.build()?)
+    }
+}
+
+impl ThresholdOpts {
+    /// Override the thresholds with the set values.
+    pub fn apply(&self, thresholds: QuadThresholds) -> Result<QuadThresholds> {
+        let Self {
+            min_cluster_pixels,
+            max_maxima_number,
+            min_angle,
+            min_opposite_angle,
+            max_mse,
+            min_white_black_diff,
+            deglitch,
+        } = *self;
+
+        let max_mse = match max_mse {
+            Some(max_mse) => R32::try_new(max_mse)
+                .with_context(|| format!("max_mse must be finite, but get {max_mse}"))?,
+            None => thresholds.max_mse,
+        };
+
+        Ok(QuadThresholds {
+            min_cluster_pixels: min_cluster_pixels.unwrap_or(thresholds.min_cluster_pixels),
+            max_maxima_number: max_maxima_number.unwrap_or(thresholds.max_maxima_number),
+            min_angle: min_angle
+                .map(Angle::from_degrees)
+                .unwrap_or(thresholds.min_angle),
+            min_opposite_angle: min_opposite_angle
+                .map(Angle::from_degrees)
+                .unwrap_or(thresholds.min_opposite_angle),
+            max_mse,
+            min_white_black_diff: min_white_black_diff.unwrap_or(thresholds.min_white_black_diff),
+            deglitch: deglitch.unwrap_or(thresholds.deglitch),
+        })
+    }
+}
+
+/// A tag family along with the number of corrected bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FamilySpec {
+    pub kind: FamilyKind,
+    pub bits: usize,
+}
+
+impl FromStr for FamilySpec {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (name, bits) = match text.split_once(':') {
+            Some((name, bits)) => {
+                let bits = bits
+                    .parse()
+                    .with_context(|| format!("invalid number of bits in '{text}'"))?;
+                (name, bits)
+            }
+            None => (text, 2),
+        };
+        let kind: FamilyKind = name.parse()?;
+        Ok(Self { kind, bits })
+    }
+}
+
+/// Tag size and camera parameters in format "tagsize,fx,fy,cx,cy".
+#[derive(Debug, Clone)]
+pub struct TagParamsArg(pub TagParams);
+
+impl FromStr for TagParamsArg {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let values = text
+            .split(',')
+            .map(|token| token.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("failed to parse tag parameters {text}"))?;
+        ensure!(
+            values.len() == 5,
+            r#"tag parameters must be in format "tagsize,fx,fy,cx,cy""#
+        );
+
+        Ok(Self(TagParams {
+            tagsize: values[0],
+            fx: values[1],
+            fy: values[2],
+            cx: values[3],
+            cy: values[4],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_family_spec() {
+        let spec: FamilySpec = "tagStandard41h12:1".parse().unwrap();
+        assert_eq!(
+            spec,
+            FamilySpec {
+                kind: FamilyKind::TagStandard41h12,
+                bits: 1
+            }
+        );
+
+        let spec: FamilySpec = "tag16h5".parse().unwrap();
+        assert_eq!(spec.bits, 2);
+
+        assert!("tag16h5:x".parse::<FamilySpec>().is_err());
+        assert!("tag99h9".parse::<FamilySpec>().is_err());
+    }
+
+    #[test]
+    fn override_config() {
+        let opts = DetectorOpts {
+            config: None,
+            families: vec!["tag16h5:0".parse().unwrap()],
+            decimation: Some(1.0),
+            sigma: None,
+            refine_edges: None,
+            sharpening: None,
+            threads: None,
+            thresholds: ThresholdOpts::default(),
+        };
+        let config = opts.to_config().unwrap();
+        assert_eq!(
+            config.families,
+            [FamilyConfig {
+                family: FamilyKind::Tag16h5,
+                bits_corrected: 0
+            }]
+        );
+        assert_eq!(config.decimation, Some(1.0));
+        assert_eq!(config.sigma, None);
+        assert_eq!(config.thresholds, None);
+
+        let opts = DetectorOpts {
+            families: vec![],
+            thresholds: ThresholdOpts {
+                min_angle: Some(30.0),
+                ..ThresholdOpts::default()
+            },
+            ..opts
+        };
+        let config = opts.to_config().unwrap();
+        assert_eq!(config.families, DetectorConfig::default().families);
+        let thresholds = config.thresholds.unwrap();
+        assert_eq!(thresholds.min_angle, Angle::from_degrees(30.0));
+        assert_eq!(
+            thresholds.min_cluster_pixels,
+            QuadThresholds::default().min_cluster_pixels
+        );
+    }
+}
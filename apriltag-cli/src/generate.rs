@@ -0,0 +1,263 @@
+//! The `generate` subcommand.
+
+use crate::sheet::{self, PlacedTag, Sheet};
+use anyhow::{bail, ensure, Context, Error, Result};
+use apriltag::FamilyKind;
+use clap::{Args, ValueEnum};
+use std::{ops::RangeInclusive, path::PathBuf, str::FromStr};
+
+/// The label font size in millimeters.
+const LABEL_SIZE: f64 = 3.0;
+
+/// Options of the `generate` subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct GenerateOpts {
+    /// Tag family name.
+    #[clap(long)]
+    pub family: FamilyKind,
+
+    /// Tag IDs, e.g. "0-19" or "1,5,7-9". All tags of the family are
+    /// generated by default.
+    #[clap(long)]
+    pub ids: Option<IdList>,
+
+    /// Physical size of the tag border in millimeters. It is the tag
+    /// size used by pose estimation.
+    #[clap(long)]
+    pub tag_size: f64,
+
+    /// Page size, either "a4", "a3", "letter", "legal" or "WIDTHxHEIGHT"
+    /// in millimeters.
+    #[clap(long, default_value = "a4")]
+    pub page: PageSize,
+
+    /// Use the landscape orientation.
+    #[clap(long)]
+    pub landscape: bool,
+
+    /// Page margin in millimeters.
+    #[clap(long, default_value = "10")]
+    pub margin: f64,
+
+    /// Spacing between tags in millimeters.
+    #[clap(long, default_value = "5")]
+    pub spacing: f64,
+
+    /// Do not print labels below tags.
+    #[clap(long)]
+    pub no_labels: bool,
+
+    /// Output format. It is inferred from the output file extension if
+    /// not set.
+    #[clap(long, value_enum)]
+    pub format: Option<SheetFormat>,
+
+    /// Resolution of PNG output in dots per inch.
+    #[clap(long, default_value = "300")]
+    pub dpi: f64,
+
+    /// Output file. Multi-page PNG and SVG outputs are written to
+    /// numbered files, e.g. "tags-001.png".
+    #[clap(short, long)]
+    pub output: PathBuf,
+}
+
+/// The output format of the `generate` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SheetFormat {
+    Png,
+    Svg,
+    Pdf,
+}
+
+/// A page size in millimeters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl FromStr for PageSize {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (width, height) = match text.to_ascii_lowercase().as_str() {
+            "a4" => (210.0, 297.0),
+            "a3" => (297.0, 420.0),
+            "letter" => (215.9, 279.4),
+            "legal" => (215.9, 355.6),
+            size => {
+                let (width, height) = size
+                    .split_once('x')
+                    .with_context(|| format!("invalid page size '{text}'"))?;
+                let width: f64 = width.trim().parse()?;
+                let height: f64 = height.trim().parse()?;
+                (width, height)
+            }
+        };
+        ensure!(
+            width > 0.0 && height > 0.0,
+            "page size must be positive, but get '{text}'"
+        );
+        Ok(Self { width, height })
+    }
+}
+
+/// A list of tag ID ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdList(pub Vec<RangeInclusive<usize>>);
+
+impl IdList {
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().cloned().flatten()
+    }
+}
+
+impl FromStr for IdList {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let ranges = text
+            .split(',')
+            .map(|token| -> Result<_> {
+                let token = token.trim();
+                let range = match token.split_once('-') {
+                    Some((first, last)) => first.trim().parse()?..=last.trim().parse()?,
+                    None => {
+                        let id = token.parse()?;
+                        id..=id
+                    }
+                };
+                ensure!(!range.is_empty(), "invalid ID range '{token}'");
+                Ok(range)
+            })
+            .collect::<Result<_>>()
+            .with_context(|| format!("failed to parse tag IDs '{text}'"))?;
+        Ok(Self(ranges))
+    }
+}
+
+pub fn run(opts: GenerateOpts) -> Result<()> {
+    let GenerateOpts {
+        family: kind,
+        ids,
+        tag_size,
+        page,
+        landscape,
+        margin,
+        spacing,
+        no_labels,
+        format,
+        dpi,
+        output,
+    } = opts;
+
+    let format = match format {
+        Some(format) => format,
+        None => {
+            let ext = output
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+            match ext.as_deref() {
+                Some("png") => SheetFormat::Png,
+                Some("svg") => SheetFormat::Svg,
+                Some("pdf") => SheetFormat::Pdf,
+                _ => bail!("unable to infer the output format. Please set --format."),
+            }
+        }
+    };
+    ensure!(tag_size > 0.0, "--tag-size must be positive");
+    ensure!(
+        margin >= 0.0 && spacing >= 0.0,
+        "--margin and --spacing must not be negative"
+    );
+    ensure!(dpi > 0.0, "--dpi must be positive");
+
+    let family = kind.create();
+    let ids: Vec<usize> = match &ids {
+        Some(ids) => ids.iter().collect(),
+        None => (0..family.len()).collect(),
+    };
+    ensure!(!ids.is_empty(), "no tags to generate");
+
+    let (page_width, page_height) = if landscape {
+        (page.height, page.width)
+    } else {
+        (page.width, page.height)
+    };
+
+    // Lay out tags in a grid.
+    let cell_size = tag_size / family.width_at_border() as f64;
+    let tag_width = cell_size * family.total_width() as f64;
+    let label_height = if no_labels { 0.0 } else { LABEL_SIZE * 1.5 };
+    let slot_width = tag_width + spacing;
+    let slot_height = tag_width + label_height + spacing;
+    let ncols = ((page_width - 2.0 * margin + spacing) / slot_width).floor() as usize;
+    let nrows = ((page_height - 2.0 * margin + spacing) / slot_height).floor() as usize;
+    ensure!(
+        ncols > 0 && nrows > 0,
+        "a tag of {tag_width:.1}mm does not fit in a {page_width}x{page_height}mm page with {margin}mm margins"
+    );
+
+    let tags = ids
+        .iter()
+        .map(|&id| -> Result<_> {
+            let image = family.to_image(id)?;
+            let cells = (0..image.height())
+                .map(|y| (0..image.width()).map(|x| image[(x, y)] < 128).collect())
+                .collect();
+            Ok((id, cells))
+        })
+        .collect::<Result<Vec<(usize, Vec<Vec<bool>>)>>>()?;
+
+    let pages = tags
+        .chunks(ncols * nrows)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .map(|(index, (id, cells))| PlacedTag {
+                    left: margin + (index % ncols) as f64 * slot_width,
+                    top: margin + (index / ncols) as f64 * slot_height,
+                    cell_size,
+                    cells: cells.clone(),
+                    label: (!no_labels).then(|| format!("{kind} #{id}")),
+                    label_size: LABEL_SIZE,
+                })
+                .collect()
+        })
+        .collect();
+    let sheet = Sheet {
+        page_width,
+        page_height,
+        pages,
+    };
+
+    match format {
+        SheetFormat::Png => sheet::write_png(&sheet, &output, dpi),
+        SheetFormat::Svg => sheet::write_svg(&sheet, &output),
+        SheetFormat::Pdf => sheet::write_pdf(&sheet, &output),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id_list() {
+        let ids: IdList = "1,5, 7-9".parse().unwrap();
+        assert_eq!(ids.iter().collect::<Vec<_>>(), [1, 5, 7, 8, 9]);
+        assert!("9-7".parse::<IdList>().is_err());
+        assert!("a".parse::<IdList>().is_err());
+    }
+
+    #[test]
+    fn parse_page_size() {
+        let page: PageSize = "A4".parse().unwrap();
+        assert_eq!(page.width, 210.0);
+        let page: PageSize = "100x150".parse().unwrap();
+        assert_eq!((page.width, page.height), (100.0, 150.0));
+        assert!("100".parse::<PageSize>().is_err());
+    }
+}
@@ -0,0 +1,92 @@
+//! Input file discovery and image loading.
+
+use anyhow::{Context, Result};
+use apriltag::Image;
+use apriltag_image::prelude::*;
+use clap::Args;
+use glob::Pattern;
+use image::{io::Reader, DynamicImage, ImageFormat};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Input files and directories.
+#[derive(Debug, Clone, Args)]
+pub struct InputOpts {
+    /// Image files or directories. Directories are searched
+    /// recursively for image files.
+    #[clap(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Only process files in directories whose paths relative to the
+    /// directory match the glob pattern, e.g. "**/*.png". It can be
+    /// specified multiple times.
+    #[clap(long = "glob")]
+    pub globs: Vec<Pattern>,
+}
+
+impl InputOpts {
+    /// List the input files in a deterministic order.
+    pub fn files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = vec![];
+
+        for input in &self.inputs {
+            if !input.is_dir() {
+                files.push(input.clone());
+                continue;
+            }
+
+            let mut entries = vec![];
+            for entry in WalkDir::new(input).follow_links(true) {
+                let entry = entry.with_context(|| format!("failed to walk {}", input.display()))?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                let path = entry.path();
+                if self.matches(path.strip_prefix(input).unwrap_or(path)) {
+                    entries.push(path.to_path_buf());
+                }
+            }
+            entries.sort();
+            files.extend(entries);
+        }
+
+        Ok(files)
+    }
+
+    fn matches(&self, relative: &Path) -> bool {
+        if self.globs.is_empty() {
+            return is_image_file(relative);
+        }
+        self.globs
+            .iter()
+            .any(|pattern| pattern.matches_path(relative))
+    }
+}
+
+/// Check if the file extension is a known image format.
+fn is_image_file(path: &Path) -> bool {
+    let is_pnm = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("pnm"));
+    is_pnm || ImageFormat::from_path(path).is_ok()
+}
+
+/// Open an image file of any format supported by the image crate.
+///
+/// The format is guessed from the file content, so that files with
+/// unregistered extensions like `.pnm` are supported.
+pub fn open_image(path: &Path) -> Result<DynamicImage> {
+    let image = Reader::open(path)
+        .and_then(|reader| reader.with_guessed_format())
+        .map_err(anyhow::Error::from)
+        .and_then(|reader| Ok(reader.decode()?))
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    Ok(image)
+}
+
+/// Load an image file as a grayscale image for detection.
+pub fn load_image(path: &Path) -> Result<Image> {
+    let image = Image::from_dynamic_image(&open_image(path)?)
+        .with_context(|| format!("failed to convert {}", path.display()))?;
+    Ok(image)
+}
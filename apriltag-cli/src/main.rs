@@ -0,0 +1,41 @@
+//! Command-line tools for AprilTag detection.
+
+mod benchmark;
+mod detect;
+mod detector_opts;
+mod generate;
+mod input;
+mod sheet;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+/// AprilTag command-line tools.
+#[derive(Debug, Parser)]
+#[clap(version, about)]
+struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Detect tags on images.
+    Detect(detect::DetectOpts),
+
+    /// Generate printable sheets of tags.
+    Generate(generate::GenerateOpts),
+
+    /// Benchmark detector settings on images.
+    Benchmark(benchmark::BenchmarkOpts),
+}
+
+fn main() -> Result<()> {
+    let Opts { command } = Opts::parse();
+
+    match command {
+        Command::Detect(opts) => detect::run(opts),
+        Command::Generate(opts) => generate::run(opts),
+        Command::Benchmark(opts) => benchmark::run(opts),
+    }
+}
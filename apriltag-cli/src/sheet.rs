@@ -0,0 +1,271 @@
+//! Rendering of printable tag sheets.
+//!
+//! The sheet geometry is in millimeters with the origin at the top-left
+//! corner of the page.
+
+use anyhow::{Context, Result};
+use apriltag_image::font;
+use image::{Rgb, RgbImage};
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const MM_PER_INCH: f64 = 25.4;
+const PT_PER_MM: f64 = 72.0 / MM_PER_INCH;
+
+/// Pages of tags to be printed.
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub page_width: f64,
+    pub page_height: f64,
+    pub pages: Vec<Vec<PlacedTag>>,
+}
+
+/// A tag placed on a page.
+#[derive(Debug, Clone)]
+pub struct PlacedTag {
+    /// The left edge of the tag image.
+    pub left: f64,
+
+    /// The top edge of the tag image.
+    pub top: f64,
+
+    /// The size of a tag cell.
+    pub cell_size: f64,
+
+    /// The cells in row-major order, where black cells are `true`.
+    pub cells: Vec<Vec<bool>>,
+
+    /// The label printed below the tag.
+    pub label: Option<String>,
+
+    /// The label font size.
+    pub label_size: f64,
+}
+
+impl PlacedTag {
+    fn width(&self) -> f64 {
+        self.cell_size * self.cells.len() as f64
+    }
+
+    /// Iterate over the black cells in form of `(left, top)`.
+    fn black_cells(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.cells.iter().enumerate().flat_map(move |(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|(_, &black)| black)
+                .map(move |(col, _)| {
+                    (
+                        self.left + col as f64 * self.cell_size,
+                        self.top + row as f64 * self.cell_size,
+                    )
+                })
+        })
+    }
+
+    /// Gets the baseline of the label.
+    fn label_baseline(&self) -> f64 {
+        self.top + self.width() + self.label_size * 1.2
+    }
+}
+
+/// Gets the output paths, numbering the pages if there are many.
+fn page_paths(path: &Path, npages: usize) -> Vec<PathBuf> {
+    if npages == 1 {
+        return vec![path.to_path_buf()];
+    }
+
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    (1..=npages)
+        .map(|page| path.with_file_name(format!("{stem}-{page:03}{ext}")))
+        .collect()
+}
+
+/// Write each page to a PNG file.
+pub fn write_png(sheet: &Sheet, path: &Path, dpi: f64) -> Result<()> {
+    let px = |mm: f64| (mm / MM_PER_INCH * dpi).round() as i64;
+    let width = px(sheet.page_width) as u32;
+    let height = px(sheet.page_height) as u32;
+    let black = Rgb([0, 0, 0]);
+
+    for (tags, path) in sheet.pages.iter().zip(page_paths(path, sheet.pages.len())) {
+        let mut image = RgbImage::from_pixel(width, height, Rgb([255, 255, 255]));
+
+        for tag in tags {
+            // Round the cell boundaries rather than cell sizes so that
+            // adjacent cells leave no gaps.
+            for (left, top) in tag.black_cells() {
+                let x = px(left);
+                let y = px(top);
+                let w = px(left + tag.cell_size) - x;
+                let h = px(top + tag.cell_size) - y;
+                font::fill_rect(&mut image, x, y, w, h, black);
+            }
+
+            if let Some(label) = &tag.label {
+                let scale = (px(tag.label_size) / font::GLYPH_HEIGHT).max(1);
+                let top = px(tag.label_baseline()) - font::GLYPH_HEIGHT * scale;
+                font::draw_text(&mut image, label, px(tag.left), top, scale, black);
+            }
+        }
+
+        image
+            .save(&path)
+            .with_context(|| format!("failed to save {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Write each page to an SVG file.
+pub fn write_svg(sheet: &Sheet, path: &Path) -> Result<()> {
+    for (tags, path) in sheet.pages.iter().zip(page_paths(path, sheet.pages.len())) {
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">"#,
+            w = sheet.page_width,
+            h = sheet.page_height,
+        )?;
+        writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+
+        for tag in tags {
+            writeln!(svg, r#"<g fill="black" shape-rendering="crispEdges">"#)?;
+            for (left, top) in tag.black_cells() {
+                writeln!(
+                    svg,
+                    r#"<rect x="{left:.4}" y="{top:.4}" width="{s:.4}" height="{s:.4}"/>"#,
+                    s = tag.cell_size,
+                )?;
+            }
+            writeln!(svg, "</g>")?;
+
+            if let Some(label) = &tag.label {
+                writeln!(
+                    svg,
+                    r#"<text x="{x:.4}" y="{y:.4}" font-family="sans-serif" font-size="{size}">{label}</text>"#,
+                    x = tag.left,
+                    y = tag.label_baseline(),
+                    size = tag.label_size,
+                    label = escape_xml(label),
+                )?;
+            }
+        }
+        writeln!(svg, "</svg>")?;
+
+        fs::write(&path, svg).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Write all pages to a PDF file.
+pub fn write_pdf(sheet: &Sheet, path: &Path) -> Result<()> {
+    let page_width = sheet.page_width * PT_PER_MM;
+    let page_height = sheet.page_height * PT_PER_MM;
+
+    // Objects 1, 2 and 3 are the catalog, the page tree and the font.
+    // Each page takes a page object followed by a content stream.
+    let npages = sheet.pages.len();
+    let page_ids: Vec<usize> = (0..npages).map(|index| 4 + 2 * index).collect();
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {npages} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{id} 0 R"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+        .into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_vec(),
+    ];
+
+    for (tags, page_id) in sheet.pages.iter().zip(&page_ids) {
+        // PDF coordinates are in points with the origin at the bottom-left.
+        let mut content = String::new();
+        for tag in tags {
+            for (left, top) in tag.black_cells() {
+                writeln!(
+                    content,
+                    "{:.3} {:.3} {s:.3} {s:.3} re",
+                    left * PT_PER_MM,
+                    page_height - (top + tag.cell_size) * PT_PER_MM,
+                    s = tag.cell_size * PT_PER_MM,
+                )?;
+            }
+            writeln!(content, "f")?;
+
+            if let Some(label) = &tag.label {
+                writeln!(
+                    content,
+                    "BT /F1 {:.3} Tf {:.3} {:.3} Td ({}) Tj ET",
+                    tag.label_size * PT_PER_MM,
+                    tag.left * PT_PER_MM,
+                    page_height - tag.label_baseline() * PT_PER_MM,
+                    escape_pdf(label),
+                )?;
+            }
+        }
+
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_width:.3} {page_height:.3}] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                page_id + 1
+            )
+            .into_bytes(),
+        );
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content.as_bytes());
+        stream.extend_from_slice(b"endstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = vec![];
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        writeln!(xref, "{offset:010} 00000 n ")?;
+    }
+    write!(
+        xref,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    )?;
+    pdf.extend_from_slice(xref.as_bytes());
+
+    fs::write(path, pdf).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_pdf(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
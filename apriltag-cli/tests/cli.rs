@@ -0,0 +1,132 @@
+use std::process::Command;
+
+const TEST_IMAGE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../apriltag/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+);
+
+#[test]
+fn detect_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["detect", "--family", "tag16h5:1", TEST_IMAGE])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // A JSON object per line.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    let json: serde_json::Value = serde_json::from_str(stdout.trim()).unwrap();
+    let mut ids: Vec<_> = json["detections"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|detection| detection["id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [2, 12, 22, 29]);
+}
+
+#[test]
+fn detect_with_config() {
+    let dir = std::env::temp_dir().join(format!("apriltag-cli-config-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("config.json");
+    std::fs::write(
+        &config,
+        r#"{
+            "families": [{ "family": "tag16h5", "bits_corrected": 1 }],
+            "filter": { "allowed_ids": { "tag16h5": [12, 22] } },
+            "tag_params": { "tagsize": 0.1, "fx": 500, "fy": 500, "cx": 250, "cy": 250 }
+        }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["detect", "--decimation", "1", "--config"])
+        .arg(&config)
+        .arg(TEST_IMAGE)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let detections = json["detections"].as_array().unwrap();
+    let mut ids: Vec<_> = detections
+        .iter()
+        .map(|detection| detection["id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [12, 22]);
+    assert!(detections
+        .iter()
+        .all(|detection| detection["pose"].is_object()));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn detect_directory_with_glob() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../apriltag");
+    let output = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["detect", "--family", "tag16h5", "--format", "csv"])
+        .args(["--glob", "test_data/*.pnm", dir])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // A header and a row per detection.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 5);
+}
+
+#[test]
+fn generate_and_detect() {
+    let dir = std::env::temp_dir().join(format!("apriltag-cli-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let sheet = dir.join("tags.png");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["generate", "--family", "tag36h11", "--ids", "3,7-8"])
+        .args(["--tag-size", "30", "--dpi", "100", "-o"])
+        .arg(&sheet)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["detect", "--family", "tag36h11", "--format", "csv"])
+        .arg(&sheet)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut ids: Vec<u64> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.rsplit(',').nth(12).unwrap().parse().unwrap())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [3, 7, 8]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn benchmark_grid() {
+    let output = Command::new(env!("CARGO_BIN_EXE_apriltag"))
+        .args(["benchmark", "--family", "tag16h5:1", "--format", "json"])
+        .args(["--decimation", "1,2", "--refine-edges", "true,false"])
+        .arg(TEST_IMAGE)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let reports = json.as_array().unwrap();
+    assert_eq!(reports.len(), 4);
+    assert!(reports
+        .iter()
+        .all(|report| report["detections"].as_u64() == Some(4)));
+}
@@ -0,0 +1,77 @@
+//! Drawing of detections on images.
+
+use crate::font;
+use apriltag::DetectionRecord;
+use image::{Rgb, RgbImage};
+
+const EDGE_COLOR: Rgb<u8> = Rgb([0, 255, 0]);
+const FIRST_CORNER_COLOR: Rgb<u8> = Rgb([255, 0, 0]);
+const LABEL_COLOR: Rgb<u8> = Rgb([255, 0, 255]);
+
+/// Draw the tag outlines and IDs on the image.
+///
+/// The first corner is marked to show the tag orientation.
+pub fn draw_detections(image: &mut RgbImage, detections: &[DetectionRecord]) {
+    let scale = (image.width().min(image.height()) / 100).max(1) as i64;
+
+    for detection in detections {
+        let corners = detection.corners;
+        for index in 0..4 {
+            draw_line(image, corners[index], corners[(index + 1) % 4], EDGE_COLOR);
+        }
+
+        let [x, y] = corners[0];
+        font::fill_rect(
+            image,
+            x as i64 - scale,
+            y as i64 - scale,
+            2 * scale + 1,
+            2 * scale + 1,
+            FIRST_CORNER_COLOR,
+        );
+
+        let [cx, cy] = detection.center;
+        let label = detection.id.to_string();
+        font::draw_text(
+            image,
+            &label,
+            cx as i64 - font::text_width(&label, scale) / 2,
+            cy as i64 - font::GLYPH_HEIGHT * scale / 2,
+            scale,
+            LABEL_COLOR,
+        );
+    }
+}
+
+/// Draw a line segment using Bresenham's algorithm.
+fn draw_line(image: &mut RgbImage, from: [f64; 2], to: [f64; 2], color: Rgb<u8>) {
+    let [mut x, mut y] = from.map(|value| value.round() as i64);
+    let [x1, y1] = to.map(|value| value.round() as i64);
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        put_pixel(image, x, y, color);
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+fn put_pixel(image: &mut RgbImage, x: i64, y: i64, color: Rgb<u8>) {
+    if x >= 0 && y >= 0 && x < image.width() as i64 && y < image.height() as i64 {
+        image.put_pixel(x as u32, y as u32, color);
+    }
+}
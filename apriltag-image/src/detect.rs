@@ -0,0 +1,17 @@
+use crate::error::Error;
+use apriltag::{Detection, Detector, Image};
+use image::DynamicImage;
+
+/// Detect tags on a [DynamicImage] of any color type.
+///
+/// The image is converted to luma as [ImageExt::from_dynamic_image]
+/// does.
+///
+/// [ImageExt::from_dynamic_image]: crate::ImageExt::from_dynamic_image
+pub fn detect_dynamic_image(
+    detector: &mut Detector,
+    image: &DynamicImage,
+) -> Result<Vec<Detection>, Error> {
+    let image = Image::try_from(image)?;
+    Ok(detector.detect(&image))
+}
@@ -0,0 +1,26 @@
+//! Defines the error type for the crate.
+
+/// The error type for the crate.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Unsupported color type '{color_type}'")]
+    UnsupportedColorType { color_type: String },
+
+    #[error("Unable to convert the image: {reason}")]
+    ConvertImageError { reason: String },
+
+    #[error(transparent)]
+    Apriltag(apriltag::Error),
+}
+
+impl From<apriltag::Error> for Error {
+    fn from(error: apriltag::Error) -> Self {
+        match error {
+            apriltag::Error::UnsupportedColorType { color_type } => {
+                Self::UnsupportedColorType { color_type }
+            }
+            apriltag::Error::ConvertImageError { reason } => Self::ConvertImageError { reason },
+            error => Self::Apriltag(error),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! A tiny bitmap font for labels on raster images.
+
+use image::{Rgb, RgbImage};
+
+/// The glyph width in font pixels.
+pub const GLYPH_WIDTH: i64 = 3;
+
+/// The glyph height in font pixels.
+pub const GLYPH_HEIGHT: i64 = 5;
+
+/// Gets the 3x5 glyph of a character. Each row is 3 bits wide.
+///
+/// Lowercase letters are drawn as uppercase, and unsupported
+/// characters are drawn as blanks.
+fn glyph(ch: char) -> [u8; 5] {
+    match ch.to_ascii_uppercase() {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        _ => [0; 5],
+    }
+}
+
+/// Gets the width of the text in image pixels.
+pub fn text_width(text: &str, scale: i64) -> i64 {
+    let len = text.chars().count() as i64;
+    (len * (GLYPH_WIDTH + 1) - 1).max(0) * scale
+}
+
+/// Draw the text with its top-left corner at the position.
+///
+/// Each font pixel is drawn as a `scale` by `scale` square.
+pub fn draw_text(
+    image: &mut RgbImage,
+    text: &str,
+    left: i64,
+    top: i64,
+    scale: i64,
+    color: Rgb<u8>,
+) {
+    for (nth, ch) in text.chars().enumerate() {
+        let x0 = left + nth as i64 * (GLYPH_WIDTH + 1) * scale;
+        for (row, bits) in glyph(ch).iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (0b100 >> col) != 0 {
+                    fill_rect(
+                        image,
+                        x0 + col * scale,
+                        top + row as i64 * scale,
+                        scale,
+                        scale,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Fill a rectangle, clipping the parts outside the image.
+pub fn fill_rect(image: &mut RgbImage, x: i64, y: i64, width: i64, height: i64, color: Rgb<u8>) {
+    let x_range = x.max(0)..(x + width).min(image.width() as i64);
+    let y_range = y.max(0)..(y + height).min(image.height() as i64);
+    for py in y_range {
+        for px in x_range.clone() {
+            image.put_pixel(px as u32, py as u32, color);
+        }
+    }
+}
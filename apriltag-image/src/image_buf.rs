@@ -1,13 +1,20 @@
+use crate::error::Error;
 use apriltag::{image_buf::DEFAULT_ALIGNMENT_U8, Image};
 use image::{
     flat::{FlatSamples, SampleLayout},
-    ColorType, ImageBuffer, Luma, Pixel,
+    ColorType, DynamicImage, ImageBuffer, Luma, Pixel,
 };
 use std::ops::Deref;
 
 pub trait ImageExt {
-    fn from_flat_samples<Buffer>(from: &FlatSamples<Buffer>) -> Self
+    /// Convert flat samples to an image.
+    ///
+    /// `L8`, `La8`, `Rgb8` and `Rgba8` samples are accepted, and color
+    /// pixels are converted to luma. Samples without a color hint are
+    /// treated as `L8` if they have a single channel.
+    fn from_flat_samples<Buffer>(from: &FlatSamples<Buffer>) -> Result<Self, Error>
     where
+        Self: Sized,
         Buffer: AsRef<[u8]>;
 
     fn to_flat_samples(&self) -> FlatSamples<Vec<u8>>;
@@ -17,38 +24,22 @@ pub trait ImageExt {
         Container: Deref<Target = [u8]>;
 
     fn to_image_buffer(&self) -> ImageBuffer<Luma<u8>, Vec<u8>>;
+
+    /// Convert a [DynamicImage] of any color type to an image.
+    ///
+    /// The result is identical to that of `from.to_luma8()`, without
+    /// allocating the intermediate buffer.
+    fn from_dynamic_image(from: &DynamicImage) -> Result<Self, Error>
+    where
+        Self: Sized;
 }
 
 impl ImageExt for Image {
-    fn from_flat_samples<Buffer>(from: &FlatSamples<Buffer>) -> Self
+    fn from_flat_samples<Buffer>(from: &FlatSamples<Buffer>) -> Result<Self, Error>
     where
         Buffer: AsRef<[u8]>,
     {
-        match from.color_hint {
-            Some(ColorType::L8) => (),
-            _ => panic!("color type {:?} is not supported", from.color_hint),
-        }
-
-        let SampleLayout { width, height, .. } = from.layout;
-        let mut image =
-            Image::zeros_with_alignment(width as usize, height as usize, DEFAULT_ALIGNMENT_U8)
-                .unwrap();
-        let stride = image.stride();
-
-        let sample_iter = (0..height)
-            .flat_map(|y| (0..width).map(move |x| (x, y)))
-            .map(|(x, y)| *from.get_sample(0, x, y).unwrap());
-        let buffer_index_iter = (0..height)
-            .flat_map(|y| (0..width).map(move |x| (x as usize, y as usize)))
-            .map(|(x, y)| y * stride + x);
-
-        buffer_index_iter
-            .zip(sample_iter)
-            .for_each(|(buffer_index, sample)| {
-                image.as_mut()[buffer_index] = sample;
-            });
-
-        image
+        Ok(Image::try_from(from)?)
     }
 
     fn to_flat_samples(&self) -> FlatSamples<Vec<u8>> {
@@ -95,14 +86,19 @@ impl ImageExt for Image {
             Luma::from([self[(x as usize, y as usize)]])
         })
     }
+
+    fn from_dynamic_image(from: &DynamicImage) -> Result<Self, Error> {
+        Ok(Image::try_from(from)?)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion, clippy::slow_vector_initialization)]
 mod tests {
     use super::*;
     use image::{
         flat::{FlatSamples, SampleLayout},
-        ColorType, ImageBuffer, Luma,
+        ColorType, DynamicImage, ImageBuffer, Luma, Rgb, Rgba,
     };
 
     #[test]
@@ -158,7 +154,7 @@ mod tests {
             }
         };
 
-        let image = Image::from_flat_samples(&flat_from);
+        let image = Image::from_flat_samples(&flat_from).unwrap();
         (0..height)
             .into_iter()
             .flat_map(|y| (0..width).into_iter().map(move |x| (x, y)))
@@ -218,6 +214,121 @@ mod tests {
         });
     }
 
+    #[test]
+    fn convert_flat_samples_of_color_types() {
+        let width = 5;
+        let height = 3;
+        let rgb = ImageBuffer::from_fn(width, height, |x, y| {
+            Rgb([(x * 50) as u8, (y * 100) as u8, ((x + y) * 30) as u8])
+        });
+        let expect = DynamicImage::ImageRgb8(rgb.clone()).to_luma8();
+
+        // Pad each row with two pixels.
+        let stride = (width as usize + 2) * 3;
+        let mut samples = vec![0; stride * height as usize];
+        for (y, row) in rgb.rows().enumerate() {
+            for (x, pixel) in row.enumerate() {
+                samples[y * stride + x * 3..][..3].copy_from_slice(&pixel.0);
+            }
+        }
+        let padded = FlatSamples {
+            samples,
+            layout: SampleLayout {
+                height_stride: stride,
+                ..SampleLayout::row_major_packed(3, width, height)
+            },
+            color_hint: Some(ColorType::Rgb8),
+        };
+        let image = Image::from_flat_samples(&padded).unwrap();
+        assert_eq!(image.to_image_buffer(), expect);
+
+        // Samples in planar layout are gathered pixel by pixel.
+        let plane = (width * height) as usize;
+        let mut samples = vec![0; plane * 3];
+        for (index, pixel) in rgb.pixels().enumerate() {
+            for (channel, &sample) in pixel.0.iter().enumerate() {
+                samples[channel * plane + index] = sample;
+            }
+        }
+        let planar = FlatSamples {
+            samples,
+            layout: SampleLayout {
+                channels: 3,
+                channel_stride: plane,
+                width,
+                width_stride: 1,
+                height,
+                height_stride: width as usize,
+            },
+            color_hint: Some(ColorType::Rgb8),
+        };
+        let image = Image::from_flat_samples(&planar).unwrap();
+        assert_eq!(image.to_image_buffer(), expect);
+    }
+
+    #[test]
+    fn reject_invalid_flat_samples() {
+        let rgb16 = FlatSamples {
+            samples: vec![0u8; 16],
+            layout: SampleLayout::row_major_packed(1, 4, 4),
+            color_hint: Some(ColorType::Rgb16),
+        };
+        assert!(matches!(
+            Image::from_flat_samples(&rgb16),
+            Err(Error::UnsupportedColorType { .. })
+        ));
+
+        let short = FlatSamples {
+            samples: vec![0u8; 15],
+            layout: SampleLayout::row_major_packed(1, 4, 4),
+            color_hint: None,
+        };
+        assert!(matches!(
+            Image::from_flat_samples(&short),
+            Err(Error::ConvertImageError { .. })
+        ));
+
+        let missing_channels = FlatSamples {
+            samples: vec![0u8; 16],
+            layout: SampleLayout::row_major_packed(1, 4, 4),
+            color_hint: Some(ColorType::Rgba8),
+        };
+        assert!(matches!(
+            Image::from_flat_samples(&missing_channels),
+            Err(Error::ConvertImageError { .. })
+        ));
+    }
+
+    #[test]
+    fn convert_dynamic_image_vs_to_luma8() {
+        let rgba = ImageBuffer::from_fn(37, 21, |x, y| {
+            Rgba([(x * 7) as u8, (y * 11) as u8, ((x * y) % 256) as u8, 128])
+        });
+        let rgba = DynamicImage::ImageRgba8(rgba);
+        let variants = [
+            DynamicImage::ImageLuma8(rgba.to_luma8()),
+            DynamicImage::ImageLumaA8(rgba.to_luma_alpha8()),
+            DynamicImage::ImageRgb8(rgba.to_rgb8()),
+            rgba.clone(),
+            DynamicImage::ImageLuma16(rgba.to_luma16()),
+            DynamicImage::ImageLumaA16(rgba.to_luma_alpha16()),
+            DynamicImage::ImageRgb16(rgba.to_rgb16()),
+            DynamicImage::ImageRgba16(rgba.to_rgba16()),
+            DynamicImage::ImageRgb32F(rgba.to_rgb32f()),
+            DynamicImage::ImageRgba32F(rgba.to_rgba32f()),
+        ];
+
+        for variant in &variants {
+            let image = Image::from_dynamic_image(variant).unwrap();
+            assert_eq!(
+                image.to_image_buffer(),
+                variant.to_luma8(),
+                "{:?}",
+                variant.color()
+            );
+        }
+    }
+
     fn diagonal_image(width: usize, height: usize) -> Image {
         let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8).unwrap();
         (0..width.min(height)).into_iter().for_each(|index| {
@@ -1,27 +1,48 @@
 //! Adds image conversion from/to [image] crate for [apriltag] crate.
+//! Images of any color type are converted to luma by
+//! [ImageExt::from_dynamic_image] or detected directly by
+//! [detect_dynamic_image]. High bit-depth grayscale images are mapped
+//! to 8 bits by [tone_map_luma16] or detected by [detect_luma16]. It
+//! also draws detections on RGB images by [draw_detections]. The same
+//! conversions are available as `Image::try_from()` on a [DynamicImage],
+//! [FlatSamples] or 16-bit luma [ImageBuffer].
+//!
+//! [DynamicImage]: image::DynamicImage
+//! [FlatSamples]: image::flat::FlatSamples
+//! [ImageBuffer]: image::ImageBuffer
 //!
 //! # Example
 //!
 //! ```rust
-//! use apriltag::{Detector, Family, Image};
-//! use apriltag_image::prelude::*;
+//! use apriltag::{Detector, Family};
+//! use apriltag_image::detect_dynamic_image;
 //!
 //! fn main() -> anyhow::Result<()> {
 //!     let path = "test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.jpg";
 //!     let reader = image::io::Reader::open(path)?;
-//!     let image_buf = reader.decode()?.to_luma8();
-//!     let image = Image::from_image_buffer(&image_buf);
+//!     let image = reader.decode()?;
 //!     let mut detector = Detector::builder()
 //!         .add_family_bits(Family::tag_16h5(), 1)
 //!         .build()?;
-//!     let detections = detector.detect(&image);
+//!     let detections = detect_dynamic_image(&mut detector, &image)?;
 //!     Ok(())
 //! }
 //! ```
 
+pub mod annotate;
+mod detect;
+pub mod error;
+pub mod font;
 mod image_buf;
+mod tone_map;
 
-pub use crate::image_buf::ImageExt;
+pub use crate::{
+    annotate::draw_detections,
+    detect::detect_dynamic_image,
+    error::Error,
+    image_buf::ImageExt,
+    tone_map::{detect_luma16, tone_map_luma16, Luma16Detections},
+};
 pub use image;
 
 pub mod prelude {
@@ -0,0 +1,48 @@
+use crate::error::Error;
+use apriltag::{
+    tone_map::{self, AppliedToneMap, ToneMapParams, ToneMapped},
+    Detection, Detector,
+};
+use image::{ImageBuffer, Luma};
+use std::ops::Deref;
+
+/// The detections on a high bit-depth frame along with the applied
+/// tone mapping.
+#[derive(Debug)]
+pub struct Luma16Detections {
+    pub detections: Vec<Detection>,
+    pub tone_map: AppliedToneMap,
+}
+
+/// Map a 16-bit grayscale image to an 8-bit image.
+///
+/// Frames of 10 or 12 bits are stored in 16-bit samples as well, and
+/// their depth is given by [ToneMapParams::bit_depth].
+pub fn tone_map_luma16<Container>(
+    from: &ImageBuffer<Luma<u16>, Container>,
+    params: &ToneMapParams,
+) -> Result<ToneMapped, Error>
+where
+    Container: Deref<Target = [u16]>,
+{
+    let width = from.width() as usize;
+    let height = from.height() as usize;
+    let mapped = tone_map::tone_map(from.as_raw(), width, height, width, params)?;
+    Ok(mapped)
+}
+
+/// Detect tags on a 16-bit grayscale image after tone mapping.
+pub fn detect_luma16<Container>(
+    detector: &mut Detector,
+    from: &ImageBuffer<Luma<u16>, Container>,
+    params: &ToneMapParams,
+) -> Result<Luma16Detections, Error>
+where
+    Container: Deref<Target = [u16]>,
+{
+    let ToneMapped { image, applied } = tone_map_luma16(from, params)?;
+    Ok(Luma16Detections {
+        detections: detector.detect(&image),
+        tone_map: applied,
+    })
+}
@@ -1,5 +1,6 @@
 use apriltag::{Detector, Family, Image};
-use apriltag_image::ImageExt;
+use apriltag_image::{detect_dynamic_image, ImageExt};
+use image::DynamicImage;
 
 #[test]
 fn jpg_file_detection() {
@@ -31,3 +32,31 @@ fn jpg_file_detection() {
     ids_found.sort_unstable();
     assert_eq!(ids_found, [2, 12, 22, 29]);
 }
+
+#[test]
+fn detect_color_and_16bit_images() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.jpg"
+    );
+    let image = image::open(path).unwrap();
+
+    let mut detector = Detector::builder()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .unwrap();
+
+    for image in [
+        DynamicImage::ImageRgb8(image.to_rgb8()),
+        DynamicImage::ImageLumaA16(image.to_luma_alpha16()),
+        DynamicImage::ImageRgba32F(image.to_rgba32f()),
+    ] {
+        let mut ids_found: Vec<_> = detect_dynamic_image(&mut detector, &image)
+            .unwrap()
+            .into_iter()
+            .map(|detection| detection.id())
+            .collect();
+        ids_found.sort_unstable();
+        assert_eq!(ids_found, [2, 12, 22, 29], "{:?}", image.color());
+    }
+}
@@ -0,0 +1,80 @@
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    Detector, Family, PoseRecord, ToneMapParams, ToneMapping,
+};
+use apriltag_image::{detect_luma16, tone_map_luma16};
+use image::{ImageBuffer, Luma};
+
+/// Render a tag and scale it to a dim and noisy range of 12 bits with
+/// a hot spot, as seen by a thermal camera.
+fn dim_12bit_frame() -> ImageBuffer<Luma<u16>, Vec<u16>> {
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let tag = SyntheticTag {
+        family: &family,
+        id: 4,
+        size: 0.1,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.1, 0.2, 0.3),
+            translation: [0.0, 0.0, 0.4],
+        },
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+
+    ImageBuffer::from_fn(camera.width as u32, camera.height as u32, |x, y| {
+        if x < 8 && y < 8 {
+            return Luma([4095]);
+        }
+        let value = scene.image[(x as usize, y as usize)] as u16;
+        Luma([300 + value / 4])
+    })
+}
+
+#[test]
+fn detect_dim_frame_with_tone_mapping() {
+    let frame = dim_12bit_frame();
+    let mut detector = Detector::builder()
+        .add_family_bits(Family::tag_36h11(), 1)
+        .build()
+        .unwrap();
+
+    let mappings = [
+        ToneMapping::Linear {
+            low: 300,
+            high: 364,
+        },
+        ToneMapping::Percentile {
+            low: 1.0,
+            high: 99.0,
+        },
+        ToneMapping::Gamma { gamma: 2.2 },
+        ToneMapping::Clahe {
+            tiles: [8, 8],
+            clip_limit: 4.0,
+        },
+    ];
+
+    for mapping in mappings {
+        let params = ToneMapParams {
+            bit_depth: 12,
+            mapping,
+        };
+        let result = detect_luma16(&mut detector, &frame, &params).unwrap();
+        let ids: Vec<_> = result.detections.iter().map(|d| d.id()).collect();
+        assert_eq!(ids, [4], "{mapping:?}");
+        assert_eq!(result.tone_map.params, params);
+    }
+
+    // The percentile window ignores the hot spot.
+    let mapped = tone_map_luma16(
+        &frame,
+        &ToneMapParams {
+            bit_depth: 12,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let [low, high] = mapped.applied.window;
+    assert!(low >= 300 && high <= 364, "{:?}", mapped.applied.window);
+    assert_eq!(mapped.image[(0, 0)], 255);
+}
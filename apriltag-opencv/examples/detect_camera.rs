@@ -0,0 +1,69 @@
+use anyhow::{ensure, Result};
+use apriltag::{Detector, FamilyKind, Image};
+use apriltag_opencv::prelude::*;
+use clap::Parser;
+use opencv::{
+    core::{Mat, Point, Scalar, Vector},
+    highgui, imgproc,
+    prelude::*,
+    videoio::{self, VideoCapture},
+};
+
+/// Capture frames with OpenCV and show the detected tags in a window.
+#[derive(Debug, Clone, Parser)]
+struct Opts {
+    /// The camera index.
+    #[clap(long, default_value = "0")]
+    pub camera: i32,
+
+    /// The tag family.
+    #[clap(long, default_value = "tag36h11")]
+    pub family: FamilyKind,
+}
+
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    let mut capture = VideoCapture::new(opts.camera, videoio::CAP_ANY)?;
+    ensure!(
+        capture.is_opened()?,
+        "unable to open camera {}",
+        opts.camera
+    );
+
+    let mut detector = Detector::builder()
+        .add_family_bits(opts.family, 1)
+        .build()?;
+    let mut frame = Mat::default();
+    let color = Scalar::new(0.0, 255.0, 0.0, 0.0);
+
+    // Stop on the end of stream or any key press.
+    while capture.read(&mut frame)? && highgui::wait_key(1)? < 0 {
+        let image = Image::from_mat(&frame)?;
+
+        for detection in detector.detect(&image) {
+            let corners: Vector<Point> = detection
+                .corner_points()
+                .into_iter()
+                .map(|point| Point::new(point.x.round() as i32, point.y.round() as i32))
+                .collect();
+            imgproc::polylines(&mut frame, &corners, true, color, 2, imgproc::LINE_8, 0)?;
+
+            let center = detection.center_point();
+            imgproc::put_text(
+                &mut frame,
+                &detection.id().to_string(),
+                Point::new(center.x as i32, center.y as i32),
+                imgproc::FONT_HERSHEY_SIMPLEX,
+                0.8,
+                color,
+                2,
+                imgproc::LINE_8,
+                false,
+            )?;
+        }
+
+        highgui::imshow("apriltag", &frame)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use apriltag::{Detection, DetectionRecord};
+use opencv::core::{Point2f, Point3f};
+
+/// The corners in the unit of the tag size, in the order of
+/// [Detection::corners].
+const TAG_CORNERS: [[f32; 2]; 4] = [[-0.5, 0.5], [0.5, 0.5], [0.5, -0.5], [-0.5, -0.5]];
+
+pub trait DetectionExt {
+    /// The center in pixels.
+    fn center_point(&self) -> Point2f;
+
+    /// The four corners in pixels, in the order of
+    /// [tag_object_points].
+    fn corner_points(&self) -> Vec<Point2f>;
+}
+
+impl DetectionExt for Detection {
+    fn center_point(&self) -> Point2f {
+        to_point(self.center())
+    }
+
+    fn corner_points(&self) -> Vec<Point2f> {
+        self.corners().into_iter().map(to_point).collect()
+    }
+}
+
+impl DetectionExt for DetectionRecord {
+    fn center_point(&self) -> Point2f {
+        to_point(self.center)
+    }
+
+    fn corner_points(&self) -> Vec<Point2f> {
+        self.corners.into_iter().map(to_point).collect()
+    }
+}
+
+/// The corners of a tag with the given size in the tag frame.
+///
+/// Together with [DetectionExt::corner_points], they are the object
+/// and image points for `cv::solvePnP`, which yields the same pose as
+/// [Detection::estimate_tag_pose].
+pub fn tag_object_points(tagsize: f64) -> Vec<Point3f> {
+    let tagsize = tagsize as f32;
+    TAG_CORNERS
+        .iter()
+        .map(|&[x, y]| Point3f::new(x * tagsize, y * tagsize, 0.0))
+        .collect()
+}
+
+fn to_point([x, y]: [f64; 2]) -> Point2f {
+    Point2f::new(x as f32, y as f32)
+}
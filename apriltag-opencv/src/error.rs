@@ -0,0 +1,22 @@
+//! Defines the error type for the crate.
+
+/// The error type for the crate.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Unsupported Mat type '{typ}', expect CV_8UC1 or CV_8UC3")]
+    UnsupportedMatType { typ: String },
+
+    #[error("Unable to convert the Mat: {reason}")]
+    ConvertMatError { reason: String },
+
+    #[error(transparent)]
+    Apriltag(#[from] apriltag::Error),
+}
+
+impl From<opencv::Error> for Error {
+    fn from(err: opencv::Error) -> Self {
+        Self::ConvertMatError {
+            reason: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::Error;
+use apriltag::{image_buf::DEFAULT_ALIGNMENT_U8, Image};
+use opencv::{
+    boxed_ref::{BoxedRef, BoxedRefMut},
+    core::{self, Mat, Scalar, Vec3b, VecN, CV_8UC1, CV_8UC3},
+    prelude::*,
+};
+use std::ffi::c_void;
+
+pub trait ImageExt {
+    /// Copy a `CV_8UC1` or a BGR `CV_8UC3` [Mat] to an image.
+    ///
+    /// The rows are read according to the step of the Mat, so ROIs and
+    /// padded Mats are accepted. Color pixels are converted with the
+    /// same weights as `cv::COLOR_BGR2GRAY`.
+    fn from_mat<M>(mat: &M) -> Result<Self, Error>
+    where
+        Self: Sized,
+        M: MatTraitConst + ?Sized;
+
+    /// Borrow the image as a `CV_8UC1` [Mat] without copying.
+    fn as_mat(&self) -> Result<BoxedRef<'_, Mat>, Error>;
+
+    /// Borrow the image as a mutable `CV_8UC1` [Mat] without copying.
+    fn as_mat_mut(&mut self) -> Result<BoxedRefMut<'_, Mat>, Error>;
+
+    /// Copy the image to an owned `CV_8UC1` [Mat].
+    fn to_mat(&self) -> Result<Mat, Error>;
+
+    /// Copy the image to an owned BGR `CV_8UC3` [Mat], which is
+    /// convenient to draw colored annotations on.
+    fn to_bgr_mat(&self) -> Result<Mat, Error>;
+}
+
+impl ImageExt for Image {
+    fn from_mat<M>(mat: &M) -> Result<Self, Error>
+    where
+        M: MatTraitConst + ?Sized,
+    {
+        if mat.empty() {
+            return Err(Error::ConvertMatError {
+                reason: "the Mat is empty".to_string(),
+            });
+        }
+        if mat.dims() != 2 {
+            return Err(Error::ConvertMatError {
+                reason: format!(
+                    "expect a 2-dimensional Mat, but get {} dimensions",
+                    mat.dims()
+                ),
+            });
+        }
+
+        let width = mat.cols() as usize;
+        let height = mat.rows() as usize;
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+        let stride = image.stride();
+        let typ = mat.typ();
+
+        for (y, dst) in image
+            .as_slice_mut()
+            .chunks_mut(stride)
+            .take(height)
+            .enumerate()
+        {
+            let dst = &mut dst[..width];
+
+            match typ {
+                CV_8UC1 => {
+                    dst.copy_from_slice(mat.at_row::<u8>(y as i32)?);
+                }
+                CV_8UC3 => {
+                    let src = mat.at_row::<Vec3b>(y as i32)?;
+                    dst.iter_mut().zip(src).for_each(|(dst, pixel)| {
+                        let [b, g, r] = pixel.0;
+                        *dst = bgr_to_luma(b, g, r);
+                    });
+                }
+                _ => {
+                    return Err(Error::UnsupportedMatType {
+                        typ: core::type_to_string(typ)?,
+                    })
+                }
+            }
+        }
+
+        Ok(image)
+    }
+
+    fn as_mat(&self) -> Result<BoxedRef<'_, Mat>, Error> {
+        let data = self.as_slice().as_ptr().cast_mut().cast::<c_void>();
+
+        // SAFETY: The Mat refers to the rows of the image, which
+        // outlive the returned reference and are not mutated through
+        // it.
+        let mat = unsafe {
+            Mat::new_rows_cols_with_data_unsafe(
+                self.height() as i32,
+                self.width() as i32,
+                CV_8UC1,
+                data,
+                self.stride(),
+            )?
+        };
+        Ok(mat.into())
+    }
+
+    fn as_mat_mut(&mut self) -> Result<BoxedRefMut<'_, Mat>, Error> {
+        let height = self.height() as i32;
+        let width = self.width() as i32;
+        let stride = self.stride();
+        let data = self.as_slice_mut().as_mut_ptr().cast::<c_void>();
+
+        // SAFETY: The Mat refers to the rows of the image, which are
+        // exclusively borrowed for the lifetime of the reference.
+        let mat =
+            unsafe { Mat::new_rows_cols_with_data_unsafe(height, width, CV_8UC1, data, stride)? };
+        Ok(mat.into())
+    }
+
+    fn to_mat(&self) -> Result<Mat, Error> {
+        Ok(self.as_mat()?.try_clone()?)
+    }
+
+    fn to_bgr_mat(&self) -> Result<Mat, Error> {
+        let mut mat = Mat::new_rows_cols_with_default(
+            self.height() as i32,
+            self.width() as i32,
+            CV_8UC3,
+            Scalar::all(0.0),
+        )?;
+
+        for (y, src) in self
+            .as_slice()
+            .chunks(self.stride())
+            .take(self.height())
+            .enumerate()
+        {
+            let dst = mat.at_row_mut::<Vec3b>(y as i32)?;
+            dst.iter_mut()
+                .zip(src)
+                .for_each(|(dst, &value)| *dst = VecN([value; 3]));
+        }
+
+        Ok(mat)
+    }
+}
+
+/// Convert a BGR pixel to luma by the fixed-point weights of OpenCV,
+/// 0.114, 0.587 and 0.299 in 14 bits.
+fn bgr_to_luma(b: u8, g: u8, r: u8) -> u8 {
+    let sum = 1868 * b as u32 + 9617 * g as u32 + 4899 * r as u32;
+    ((sum + (1 << 13)) >> 14) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bgr_to_luma_weights() {
+        assert_eq!(bgr_to_luma(0, 0, 0), 0);
+        assert_eq!(bgr_to_luma(255, 255, 255), 255);
+        assert_eq!(bgr_to_luma(255, 0, 0), 29);
+        assert_eq!(bgr_to_luma(0, 255, 0), 150);
+        assert_eq!(bgr_to_luma(0, 0, 255), 76);
+    }
+}
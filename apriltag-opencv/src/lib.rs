@@ -0,0 +1,57 @@
+//! Adds type conversions from/to [opencv] crate for [apriltag] crate.
+//!
+//! - [ImageExt] converts `CV_8UC1` and BGR `CV_8UC3` [Mat]s to [Image]s,
+//!   and borrows [Image]s as [Mat]s without copying.
+//! - [DetectionExt] converts detected corners to [Point2f]s, which are
+//!   paired with [tag_object_points] for `cv::solvePnP`.
+//! - [PoseExt] converts poses to `rvec` and `tvec`, and
+//!   [pose_from_rvec_tvec] converts them back.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use apriltag::{DetectorConfig, Image, TagParams};
+//! use apriltag_opencv::prelude::*;
+//! use opencv::imgcodecs;
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let mat = imgcodecs::imread("frame.png", imgcodecs::IMREAD_COLOR)?;
+//!     let image = Image::from_mat(&mat)?;
+//!     let mut detector = DetectorConfig::default().build()?;
+//!     let tag_params = TagParams {
+//!         tagsize: 0.1,
+//!         fx: 600.0,
+//!         fy: 600.0,
+//!         cx: 320.0,
+//!         cy: 240.0,
+//!     };
+//!
+//!     for detection in detector.detect(&image) {
+//!         let corners = detection.corner_points();
+//!         if let Some(pose) = detection.estimate_tag_pose(&tag_params) {
+//!             let (rvec, tvec) = pose.to_rvec_tvec();
+//!             println!("{corners:?} {rvec:?} {tvec:?}");
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [Image]: apriltag::Image
+//! [Mat]: opencv::core::Mat
+//! [Point2f]: opencv::core::Point2f
+
+mod detection;
+pub mod error;
+mod image;
+mod pose;
+
+pub use detection::{tag_object_points, DetectionExt};
+pub use error::Error;
+pub use image::ImageExt;
+pub use opencv;
+pub use pose::{pose_from_rvec_tvec, PoseExt};
+
+pub mod prelude {
+    pub use crate::{DetectionExt as _, ImageExt as _, PoseExt as _};
+}
@@ -0,0 +1,122 @@
+use apriltag::{Pose, PoseRecord};
+use opencv::core::{Vec3d, VecN};
+use std::f64::consts::PI;
+
+pub trait PoseExt {
+    /// Convert to the rotation vector and the translation vector, as
+    /// returned by `cv::solvePnP` and accepted by `cv::projectPoints`
+    /// and `cv::drawFrameAxes`.
+    fn to_rvec_tvec(&self) -> (Vec3d, Vec3d);
+}
+
+impl PoseExt for Pose {
+    fn to_rvec_tvec(&self) -> (Vec3d, Vec3d) {
+        self.to_record().to_rvec_tvec()
+    }
+}
+
+impl PoseExt for PoseRecord {
+    fn to_rvec_tvec(&self) -> (Vec3d, Vec3d) {
+        let rvec = VecN(rotation_to_rvec(&self.rotation));
+        let tvec = VecN(self.translation);
+        (rvec, tvec)
+    }
+}
+
+/// Create a pose from the rotation vector and the translation vector
+/// of OpenCV.
+pub fn pose_from_rvec_tvec(rvec: &Vec3d, tvec: &Vec3d) -> PoseRecord {
+    PoseRecord {
+        rotation: rvec_to_rotation(rvec.0),
+        translation: tvec.0,
+    }
+}
+
+/// Convert a rotation matrix to the axis-angle vector in the same way
+/// as `cv::Rodrigues`.
+fn rotation_to_rvec(rotation: &[[f64; 3]; 3]) -> [f64; 3] {
+    let r = rotation;
+    let axis = [r[2][1] - r[1][2], r[0][2] - r[2][0], r[1][0] - r[0][1]];
+    let sin = norm(axis) / 2.0;
+    let cos = ((r[0][0] + r[1][1] + r[2][2] - 1.0) / 2.0).clamp(-1.0, 1.0);
+
+    if sin >= 1e-5 {
+        let scale = sin.atan2(cos) / (2.0 * sin);
+        return axis.map(|value| value * scale);
+    }
+
+    if cos > 0.0 {
+        return [0.0; 3];
+    }
+
+    // The rotation is close to 180 degrees, where the axis is recovered
+    // from the diagonal of (R + I) / 2.
+    let x = ((r[0][0] + 1.0) / 2.0).max(0.0).sqrt();
+    let y = ((r[1][1] + 1.0) / 2.0).max(0.0).sqrt() * if r[0][1] < 0.0 { -1.0 } else { 1.0 };
+    let mut z = ((r[2][2] + 1.0) / 2.0).max(0.0).sqrt() * if r[0][2] < 0.0 { -1.0 } else { 1.0 };
+    if x.abs() < y.abs() && x.abs() < z.abs() && (r[1][2] > 0.0) != (y * z > 0.0) {
+        z = -z;
+    }
+    let axis = [x, y, z];
+    let scale = PI / norm(axis);
+    axis.map(|value| value * scale)
+}
+
+/// Convert an axis-angle vector to the rotation matrix.
+fn rvec_to_rotation(rvec: [f64; 3]) -> [[f64; 3]; 3] {
+    let theta = norm(rvec);
+    if theta < f64::EPSILON {
+        return [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    }
+
+    let [x, y, z] = rvec.map(|value| value / theta);
+    let (sin, cos) = theta.sin_cos();
+    let t = 1.0 - cos;
+    [
+        [cos + t * x * x, t * x * y - sin * z, t * x * z + sin * y],
+        [t * x * y + sin * z, cos + t * y * y, t * y * z - sin * x],
+        [t * x * z - sin * y, t * y * z + sin * x, cos + t * z * z],
+    ]
+}
+
+fn norm([x, y, z]: [f64; 3]) -> f64 {
+    (x * x + y * y + z * z).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apriltag::synthetic::euler_rotation;
+
+    fn assert_rotation_eq(lhs: &[[f64; 3]; 3], rhs: &[[f64; 3]; 3]) {
+        lhs.iter()
+            .flatten()
+            .zip(rhs.iter().flatten())
+            .for_each(|(lhs, rhs)| assert!((lhs - rhs).abs() < 1e-9, "{lhs:?} != {rhs:?}"));
+    }
+
+    #[test]
+    fn rvec_round_trip() {
+        for (rx, ry, rz) in [
+            (0.0, 0.0, 0.0),
+            (0.3, -0.2, 1.1),
+            (2.5, 0.4, -0.7),
+            (PI, 0.0, 0.0),
+            (0.0, PI, 0.0),
+            (PI / 2.0, 0.0, PI),
+        ] {
+            let rotation = euler_rotation(rx, ry, rz);
+            let rvec = rotation_to_rvec(&rotation);
+            assert!(norm(rvec) <= PI + 1e-9);
+            assert_rotation_eq(&rvec_to_rotation(rvec), &rotation);
+        }
+    }
+
+    #[test]
+    fn rvec_of_axis_rotation() {
+        let rvec = rotation_to_rvec(&euler_rotation(0.0, 0.0, 0.5));
+        assert!((rvec[0]).abs() < 1e-12);
+        assert!((rvec[1]).abs() < 1e-12);
+        assert!((rvec[2] - 0.5).abs() < 1e-12);
+    }
+}
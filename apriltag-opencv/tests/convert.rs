@@ -0,0 +1,145 @@
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    DetectorConfig, Family, Image, PoseRecord, TagParams,
+};
+use apriltag_opencv::{pose_from_rvec_tvec, prelude::*, tag_object_points, Error};
+use opencv::{
+    core::{Mat, Rect, Scalar, Vec3b, VecN, CV_32FC1, CV_8UC3},
+    prelude::*,
+};
+
+fn render_scene() -> (Camera, PoseRecord, Image) {
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let truth = PoseRecord {
+        rotation: synthetic::euler_rotation(0.3, -0.2, 0.1),
+        translation: [0.01, 0.02, 0.5],
+    };
+    let tag = SyntheticTag {
+        family: &family,
+        id: 11,
+        size: 0.1,
+        pose: truth.clone(),
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+    (camera, truth, scene.image)
+}
+
+#[test]
+fn borrow_image_as_mat() {
+    let (_, _, mut image) = render_scene();
+
+    let mat = image.as_mat().unwrap();
+    assert_eq!(mat.rows() as usize, image.height());
+    assert_eq!(mat.cols() as usize, image.width());
+    assert_eq!(mat.mat_step()[0], image.stride());
+    assert_eq!(mat.data(), image.as_slice().as_ptr());
+    assert_eq!(*mat.at_2d::<u8>(50, 60).unwrap(), image[(60, 50)]);
+    drop(mat);
+
+    let mut mat = image.as_mat_mut().unwrap();
+    *mat.at_2d_mut::<u8>(7, 9).unwrap() = 123;
+    drop(mat);
+    assert_eq!(image[(9, 7)], 123);
+
+    let owned = image.to_mat().unwrap();
+    assert_ne!(owned.data(), image.as_slice().as_ptr());
+    let converted = Image::from_mat(&owned).unwrap();
+    assert!(image
+        .indexed_samples_iter()
+        .all(|(x, y, value)| converted[(x, y)] == value));
+}
+
+#[test]
+fn convert_bgr_mat_with_roi() {
+    let (_, _, image) = render_scene();
+    let width = image.width() as i32;
+    let height = image.height() as i32;
+
+    // Embed the colored image in a larger Mat so that the ROI is not
+    // continuous.
+    let mut canvas =
+        Mat::new_rows_cols_with_default(height + 20, width + 30, CV_8UC3, Scalar::all(255.0))
+            .unwrap();
+    for y in 0..height {
+        for x in 0..width {
+            let value = image[(x as usize, y as usize)];
+            *canvas.at_2d_mut::<Vec3b>(y + 10, x + 20).unwrap() = VecN([value; 3]);
+        }
+    }
+    let roi = Mat::roi(&canvas, Rect::new(20, 10, width, height)).unwrap();
+    assert!(!roi.is_continuous());
+
+    let converted = Image::from_mat(&roi).unwrap();
+    assert_eq!(converted.width(), image.width());
+    assert_eq!(converted.height(), image.height());
+    assert!(image
+        .indexed_samples_iter()
+        .all(|(x, y, value)| converted[(x, y)] == value));
+
+    let bgr = image.to_bgr_mat().unwrap();
+    assert_eq!(bgr.typ(), CV_8UC3);
+    assert_eq!(bgr.at_2d::<Vec3b>(40, 30).unwrap().0, [image[(30, 40)]; 3]);
+}
+
+#[test]
+fn reject_unsupported_mat() {
+    let mat = Mat::new_rows_cols_with_default(4, 4, CV_32FC1, Scalar::all(0.0)).unwrap();
+    let err = Image::from_mat(&mat).unwrap_err();
+    assert!(matches!(err, Error::UnsupportedMatType { typ } if typ == "CV_32FC1"));
+}
+
+#[test]
+fn convert_detection_and_pose() {
+    let (camera, truth, image) = render_scene();
+    let mut detector = DetectorConfig::default().build().unwrap();
+    let detections = detector.detect(&image);
+    assert_eq!(detections.len(), 1);
+    let detection = &detections[0];
+
+    let corners = detection.corner_points();
+    assert_eq!(corners.len(), 4);
+    for (point, [x, y]) in corners.iter().zip(detection.corners()) {
+        assert_eq!(point.x, x as f32);
+        assert_eq!(point.y, y as f32);
+    }
+    assert_eq!(detection.to_record().corner_points(), corners);
+    assert_eq!(
+        detection.center_point().x,
+        detection.to_record().center[0] as f32
+    );
+
+    let object_points = tag_object_points(0.1);
+    assert_eq!(object_points[1].x, 0.05);
+    assert_eq!(object_points[1].y, 0.05);
+
+    let tag_params = TagParams {
+        tagsize: 0.1,
+        fx: camera.fx,
+        fy: camera.fy,
+        cx: camera.cx,
+        cy: camera.cy,
+    };
+    let pose = detection.estimate_tag_pose(&tag_params).unwrap();
+    let (rvec, tvec) = pose.to_rvec_tvec();
+    for (lhs, rhs) in tvec.0.iter().zip(&truth.translation) {
+        assert!((lhs - rhs).abs() < 0.01, "{tvec:?}");
+    }
+
+    let (expect, _) = truth.to_rvec_tvec();
+    for (lhs, rhs) in rvec.0.iter().zip(&expect.0) {
+        assert!((lhs - rhs).abs() < 0.05, "{rvec:?} != {expect:?}");
+    }
+
+    let record = pose.to_record();
+    let restored = pose_from_rvec_tvec(&rvec, &tvec);
+    assert_eq!(restored.translation, record.translation);
+    for (lhs, rhs) in restored
+        .rotation
+        .iter()
+        .flatten()
+        .zip(record.rotation.iter().flatten())
+    {
+        assert!((lhs - rhs).abs() < 1e-9);
+    }
+}
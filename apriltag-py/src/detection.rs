@@ -0,0 +1,199 @@
+//! The `Detection`, `Pose`, `PoseEstimation` and `TagParams` classes.
+
+use apriltag::{Detection, PoseRecord, TagParams};
+use numpy::{ndarray::arr2, PyArray1, PyArray2};
+use pyo3::prelude::*;
+
+/// Tag size and camera parameters for pose estimation.
+#[pyclass(name = "TagParams", module = "apriltag_py", get_all, set_all)]
+#[derive(Debug, Clone)]
+pub struct PyTagParams {
+    /// The width of the tag border.
+    pub tagsize: f64,
+    /// The focal length along x in pixels.
+    pub fx: f64,
+    /// The focal length along y in pixels.
+    pub fy: f64,
+    /// The principal point x in pixels.
+    pub cx: f64,
+    /// The principal point y in pixels.
+    pub cy: f64,
+}
+
+#[pymethods]
+impl PyTagParams {
+    #[new]
+    fn new(tagsize: f64, fx: f64, fy: f64, cx: f64, cy: f64) -> Self {
+        Self {
+            tagsize,
+            fx,
+            fy,
+            cx,
+            cy,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let Self {
+            tagsize,
+            fx,
+            fy,
+            cx,
+            cy,
+        } = self;
+        format!("TagParams(tagsize={tagsize}, fx={fx}, fy={fy}, cx={cx}, cy={cy})")
+    }
+}
+
+impl From<&PyTagParams> for TagParams {
+    fn from(from: &PyTagParams) -> Self {
+        Self {
+            tagsize: from.tagsize,
+            fx: from.fx,
+            fy: from.fy,
+            cx: from.cx,
+            cy: from.cy,
+        }
+    }
+}
+
+/// A detected tag.
+#[pyclass(name = "Detection", module = "apriltag_py", frozen)]
+pub struct PyDetection {
+    pub detection: Detection,
+}
+
+#[pymethods]
+impl PyDetection {
+    /// The marker ID.
+    #[getter]
+    fn id(&self) -> usize {
+        self.detection.id()
+    }
+
+    /// The name of the tag family.
+    #[getter]
+    fn family(&self) -> String {
+        self.detection.family_name()
+    }
+
+    /// The Hamming distance to the target tag.
+    #[getter]
+    fn hamming(&self) -> usize {
+        self.detection.hamming()
+    }
+
+    /// Indicate the _goodness_ of the detection.
+    #[getter]
+    fn decision_margin(&self) -> f32 {
+        self.detection.decision_margin()
+    }
+
+    /// The center `[x, y]` in pixels.
+    #[getter]
+    fn center<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice(py, &self.detection.center())
+    }
+
+    /// The corners of shape `(4, 2)` in pixels.
+    #[getter]
+    fn corners<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        PyArray2::from_owned_array(py, arr2(&self.detection.corners()))
+    }
+
+    /// The homography of shape `(3, 3)` that maps tag coordinates to
+    /// pixels.
+    #[getter]
+    fn homography<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        let record = self.detection.to_record();
+        PyArray2::from_owned_array(py, arr2(&record.homography))
+    }
+
+    /// Estimate the pose of the tag.
+    fn estimate_tag_pose(&self, params: &PyTagParams) -> Option<PyPose> {
+        let pose = self.detection.estimate_tag_pose(&params.into())?;
+        Some(PyPose {
+            pose: pose.to_record(),
+        })
+    }
+
+    /// Estimate up to two candidate poses by orthogonal iteration.
+    #[pyo3(signature = (params, n_iters = 50))]
+    fn estimate_tag_pose_orthogonal_iteration(
+        &self,
+        params: &PyTagParams,
+        n_iters: usize,
+    ) -> Vec<PyPoseEstimation> {
+        self.detection
+            .estimate_tag_pose_orthogonal_iteration(&params.into(), n_iters)
+            .iter()
+            .map(|estimation| PyPoseEstimation {
+                pose: estimation.pose.to_record(),
+                error: estimation.error,
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        let [x, y] = self.detection.center();
+        format!(
+            "Detection(family='{}', id={}, hamming={}, center=[{x:.2}, {y:.2}])",
+            self.detection.family_name(),
+            self.detection.id(),
+            self.detection.hamming(),
+        )
+    }
+}
+
+/// An estimated tag pose in camera coordinates.
+#[pyclass(name = "Pose", module = "apriltag_py", frozen)]
+pub struct PyPose {
+    pub pose: PoseRecord,
+}
+
+#[pymethods]
+impl PyPose {
+    /// The rotation matrix of shape `(3, 3)`.
+    #[getter]
+    fn rotation<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        PyArray2::from_owned_array(py, arr2(&self.pose.rotation))
+    }
+
+    /// The translation vector in the same unit as `tagsize`.
+    #[getter]
+    fn translation<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice(py, &self.pose.translation)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Pose(translation={:?})", self.pose.translation)
+    }
+}
+
+/// A candidate pose with its object-space error.
+#[pyclass(name = "PoseEstimation", module = "apriltag_py", frozen)]
+pub struct PyPoseEstimation {
+    pose: PoseRecord,
+
+    /// The object-space error of the estimation.
+    #[pyo3(get)]
+    error: f64,
+}
+
+#[pymethods]
+impl PyPoseEstimation {
+    /// The estimated pose.
+    #[getter]
+    fn pose(&self) -> PyPose {
+        PyPose {
+            pose: self.pose.clone(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PoseEstimation(translation={:?}, error={})",
+            self.pose.translation, self.error
+        )
+    }
+}
@@ -0,0 +1,165 @@
+//! The `Detector` and `DetectorBuilder` classes.
+
+use crate::{detection::PyDetection, family::PyFamily, image::ImageInput};
+use apriltag::{Detector, DetectorBuilder, FamilyKind};
+use numpy::PyReadonlyArray2;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::sync::Mutex;
+
+/// The builder that creates a `Detector` with multiple families.
+#[pyclass(name = "DetectorBuilder", module = "apriltag_py")]
+#[derive(Debug, Clone, Default)]
+pub struct PyDetectorBuilder {
+    families: Vec<(FamilyKind, usize)>,
+}
+
+#[pymethods]
+impl PyDetectorBuilder {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a tag family. It returns the builder for chaining.
+    #[pyo3(signature = (family, bits_corrected = 2))]
+    fn add_family_bits(
+        mut slf: PyRefMut<'_, Self>,
+        family: PyFamily,
+        bits_corrected: usize,
+    ) -> PyRefMut<'_, Self> {
+        slf.families.push((family.kind, bits_corrected));
+        slf
+    }
+
+    /// Create a `Detector`.
+    fn build(&self) -> PyResult<PyDetector> {
+        let builder = self
+            .families
+            .iter()
+            .fold(DetectorBuilder::new(), |builder, &(kind, bits)| {
+                builder.add_family_bits(kind, bits)
+            });
+        let detector = builder
+            .build()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(PyDetector {
+            detector: Mutex::new(detector),
+        })
+    }
+}
+
+/// The tag detector.
+///
+/// `families` is a space-separated list of family names, which are
+/// detected with `bits_corrected` corrected bits. Unset tunables use
+/// the library defaults.
+#[pyclass(name = "Detector", module = "apriltag_py")]
+pub struct PyDetector {
+    detector: Mutex<Detector>,
+}
+
+#[pymethods]
+impl PyDetector {
+    #[new]
+    #[pyo3(signature = (
+        families = "tag36h11",
+        bits_corrected = 2,
+        decimation = None,
+        sigma = None,
+        refine_edges = None,
+        sharpening = None,
+        threads = None,
+    ))]
+    fn new(
+        families: &str,
+        bits_corrected: usize,
+        decimation: Option<f32>,
+        sigma: Option<f32>,
+        refine_edges: Option<bool>,
+        sharpening: Option<f64>,
+        threads: Option<u8>,
+    ) -> PyResult<Self> {
+        let builder = families.split_whitespace().map(PyFamily::new).try_fold(
+            PyDetectorBuilder::default(),
+            |mut builder, family| {
+                builder.families.push((family?.kind, bits_corrected));
+                PyResult::Ok(builder)
+            },
+        )?;
+        let detector = builder.build()?;
+
+        {
+            let mut inner = detector.lock();
+            if let Some(decimation) = decimation {
+                inner.set_decimation(decimation);
+            }
+            if let Some(sigma) = sigma {
+                inner.set_sigma(sigma);
+            }
+            if let Some(refine_edges) = refine_edges {
+                inner.set_refine_edges(refine_edges);
+            }
+            if let Some(sharpening) = sharpening {
+                inner.set_shapening(sharpening);
+            }
+            if let Some(threads) = threads {
+                inner.set_thread_number(threads);
+            }
+        }
+
+        Ok(detector)
+    }
+
+    /// Create a `DetectorBuilder`.
+    #[staticmethod]
+    fn builder() -> PyDetectorBuilder {
+        PyDetectorBuilder::default()
+    }
+
+    /// Detect tags on a `uint8` array of shape `(height, width)`.
+    ///
+    /// C-contiguous arrays are passed to the detector without copy.
+    fn detect(&self, image: PyReadonlyArray2<'_, u8>) -> PyResult<Vec<PyDetection>> {
+        let image = ImageInput::from_array(image.as_array())
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let detections = self.lock().detect(&image);
+        Ok(detections
+            .into_iter()
+            .map(|detection| PyDetection { detection })
+            .collect())
+    }
+
+    /// Set the number of threads used for detection.
+    fn set_thread_number(&self, num_threads: u8) {
+        self.lock().set_thread_number(num_threads);
+    }
+
+    /// Decimate the input image for quad detection.
+    fn set_decimation(&self, decimation: f32) {
+        self.lock().set_decimation(decimation);
+    }
+
+    /// Set the standard deviation of Gaussian blur for quad detection.
+    fn set_sigma(&self, sigma: f32) {
+        self.lock().set_sigma(sigma);
+    }
+
+    /// Enable refinement of edges.
+    fn set_refine_edges(&self, refine_edges: bool) {
+        self.lock().set_refine_edges(refine_edges);
+    }
+
+    /// Set the amount of sharpening applied to the decoded images.
+    fn set_sharpening(&self, sharpening: f64) {
+        self.lock().set_shapening(sharpening);
+    }
+}
+
+impl PyDetector {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Detector> {
+        // A panic during detection leaves the detector usable.
+        self.detector
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
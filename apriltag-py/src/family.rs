@@ -0,0 +1,57 @@
+//! The `Family` class.
+
+use apriltag::{Family, FamilyKind};
+use numpy::{ndarray::Array2, PyArray2};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// A pre-generated tag family.
+///
+/// It is constructed from the family name, such as `tag36h11`.
+#[pyclass(name = "Family", module = "apriltag_py", frozen, eq, hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyFamily {
+    pub kind: FamilyKind,
+}
+
+#[pymethods]
+impl PyFamily {
+    #[new]
+    pub fn new(name: &str) -> PyResult<Self> {
+        let kind = name
+            .parse()
+            .map_err(|err: apriltag::Error| PyValueError::new_err(err.to_string()))?;
+        Ok(Self { kind })
+    }
+
+    /// The names of all pre-generated families.
+    #[staticmethod]
+    fn names() -> Vec<&'static str> {
+        FamilyKind::ALL.iter().map(|kind| kind.name()).collect()
+    }
+
+    /// The family name.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// The number of tags in the family.
+    fn __len__(&self) -> usize {
+        self.kind.create().len()
+    }
+
+    /// Render the tag of the ID as a `uint8` array of shape
+    /// `(total_width, total_width)`.
+    fn to_image<'py>(&self, py: Python<'py>, id: usize) -> PyResult<Bound<'py, PyArray2<u8>>> {
+        let family: Family = self.kind.create();
+        let image = family
+            .to_image(id)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let array = Array2::from_shape_fn((image.height(), image.width()), |(y, x)| image[(x, y)]);
+        Ok(PyArray2::from_owned_array(py, array))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Family('{}')", self.kind.name())
+    }
+}
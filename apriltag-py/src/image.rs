@@ -0,0 +1,125 @@
+//! Conversion from numpy arrays to images.
+
+use apriltag::{error::Error, image_buf::DEFAULT_ALIGNMENT_U8, Image};
+use apriltag_sys as sys;
+use numpy::ndarray::ArrayView2;
+use std::{marker::PhantomData, mem::ManuallyDrop, ops::Deref, os::raw::c_int};
+
+/// A grayscale image backed by a two dimensional `uint8` array.
+///
+/// The array is borrowed without copy if it is C-contiguous, including
+/// row slices of C-contiguous arrays. Otherwise the pixels are copied
+/// into an owned [Image].
+pub enum ImageInput<'a> {
+    Borrowed(BorrowedImage<'a>),
+    Owned(Image),
+}
+
+/// An [Image] whose header points into a borrowed buffer.
+pub struct BorrowedImage<'a> {
+    // The image is never dropped since the buffer is not owned by the
+    // AprilTag library.
+    image: ManuallyDrop<Image>,
+    _header: Box<sys::image_u8_t>,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> ImageInput<'a> {
+    /// Wrap an array of shape `(height, width)`.
+    pub fn from_array(array: ArrayView2<'a, u8>) -> Result<Self, Error> {
+        let (height, width) = array.dim();
+        let strides = array.strides();
+
+        let too_large = |size: usize| c_int::try_from(size).is_err();
+        if too_large(width) || too_large(height) {
+            return Err(Error::CreateImageError {
+                reason: format!("image size {width}x{height} is too large"),
+            });
+        }
+
+        // Padded rows are not borrowed, because the image would cover
+        // the padding after the last row, which may be out of bounds.
+        let contiguous = width > 0 && height > 0 && strides == [width as isize, 1];
+        if contiguous {
+            let mut header = Box::new(sys::image_u8_t {
+                width: width as c_int,
+                height: height as c_int,
+                stride: width as c_int,
+                buf: array.as_ptr() as *mut u8,
+            });
+            // SAFETY: The header outlives the image, and the buffer is
+            // borrowed for 'a. The detector only reads from the buffer.
+            let image = unsafe { Image::from_raw(&mut *header) };
+            return Ok(Self::Borrowed(BorrowedImage {
+                image: ManuallyDrop::new(image),
+                _header: header,
+                _marker: PhantomData,
+            }));
+        }
+
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+        array
+            .indexed_iter()
+            .for_each(|((y, x), &value)| image[(x, y)] = value);
+        Ok(Self::Owned(image))
+    }
+
+    /// Check if the array is borrowed without copy.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+}
+
+impl Deref for ImageInput<'_> {
+    type Target = Image;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(borrowed) => &borrowed.image,
+            Self::Owned(image) => image,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numpy::ndarray::{s, Array2};
+
+    #[test]
+    fn borrow_contiguous_rows() {
+        let array = Array2::from_shape_fn((20, 30), |(y, x)| (x + y) as u8);
+
+        let input = ImageInput::from_array(array.view()).unwrap();
+        assert!(input.is_borrowed());
+        assert_eq!(input.as_slice().as_ptr(), array.as_ptr());
+        assert_eq!(
+            (input.width(), input.height(), input.stride()),
+            (30, 20, 30)
+        );
+
+        let input = ImageInput::from_array(array.slice(s![2..10, ..])).unwrap();
+        assert!(input.is_borrowed());
+        assert_eq!((input.width(), input.height()), (30, 8));
+        assert_eq!(input[(29, 7)], 38);
+    }
+
+    #[test]
+    fn copy_non_contiguous_arrays() {
+        let array = Array2::from_shape_fn((20, 30), |(y, x)| (x * 2 + y) as u8);
+
+        let input = ImageInput::from_array(array.t()).unwrap();
+        assert!(!input.is_borrowed());
+        assert_eq!((input.width(), input.height()), (20, 30));
+        assert_eq!(input[(3, 4)], 11);
+
+        let input = ImageInput::from_array(array.slice(s![.., ..;2])).unwrap();
+        assert!(!input.is_borrowed());
+        assert_eq!(input[(5, 1)], 21);
+
+        let input = ImageInput::from_array(array.slice(s![2..10, 5..25])).unwrap();
+        assert!(!input.is_borrowed());
+        assert_eq!((input.width(), input.height()), (20, 8));
+        assert_eq!(input[(19, 7)], 57);
+    }
+}
@@ -0,0 +1,43 @@
+//! Python bindings for the [apriltag] crate.
+//!
+//! The `apriltag_py` extension module is built by
+//! [maturin](https://www.maturin.rs/). It exposes `Detector`,
+//! `DetectorBuilder`, `Family`, `Detection` and pose estimation, with
+//! images passed as numpy `uint8` arrays.
+//!
+//! ```python
+//! import numpy as np
+//! from apriltag_py import Detector, TagParams
+//!
+//! detector = Detector(families="tag36h11 tag16h5", decimation=2.0)
+//! image = np.zeros((480, 640), dtype=np.uint8)
+//! for detection in detector.detect(image):
+//!     pose = detection.estimate_tag_pose(TagParams(0.16, 600, 600, 320, 240))
+//!     print(detection.id, detection.corners, pose.translation)
+//! ```
+
+mod detection;
+mod detector;
+mod family;
+mod image;
+
+pub use crate::{
+    detection::{PyDetection, PyPose, PyPoseEstimation, PyTagParams},
+    detector::{PyDetector, PyDetectorBuilder},
+    family::PyFamily,
+    image::ImageInput,
+};
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn apriltag_py(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDetector>()?;
+    module.add_class::<PyDetectorBuilder>()?;
+    module.add_class::<PyFamily>()?;
+    module.add_class::<PyDetection>()?;
+    module.add_class::<PyPose>()?;
+    module.add_class::<PyPoseEstimation>()?;
+    module.add_class::<PyTagParams>()?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Conversion from `sensor_msgs/CameraInfo` to tag parameters.
+
+use crate::{error::Error, msg::sensor_msgs::CameraInfo};
+use apriltag::TagParams;
+
+/// Build the [TagParams] for pose estimation from the camera info and
+/// the tag size in meters.
+///
+/// Tags are expected to be detected on rectified images, so the
+/// intrinsics are taken from the projection matrix `p`. If `p` is unset,
+/// which is the case for some uncalibrated drivers, the raw intrinsic
+/// matrix `k` is used instead.
+pub fn tag_params_from_camera_info(info: &CameraInfo, tagsize: f64) -> Result<TagParams, Error> {
+    if !(tagsize.is_finite() && tagsize > 0.0) {
+        return Err(Error::ConvertCameraInfoError {
+            reason: format!("tag size must be positive, but get {tagsize}"),
+        });
+    }
+
+    let (fx, fy, cx, cy) = if info.p[0] != 0.0 {
+        (info.p[0], info.p[5], info.p[2], info.p[6])
+    } else {
+        (info.k[0], info.k[4], info.k[2], info.k[5])
+    };
+    if !(fx.is_finite() && fy.is_finite() && fx > 0.0 && fy > 0.0) {
+        return Err(Error::ConvertCameraInfoError {
+            reason: format!("invalid focal lengths fx={fx}, fy={fy}; is the camera calibrated?"),
+        });
+    }
+
+    Ok(TagParams {
+        tagsize,
+        fx,
+        fy,
+        cx,
+        cy,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msg::{sensor_msgs::RegionOfInterest, std_msgs::Header};
+    use ros2_client::builtin_interfaces::Time;
+
+    fn camera_info(k: [f64; 9], p: [f64; 12]) -> CameraInfo {
+        CameraInfo {
+            header: Header::new(Time::ZERO, "camera"),
+            height: 480,
+            width: 640,
+            distortion_model: "plumb_bob".to_string(),
+            d: vec![0.0; 5],
+            k,
+            r: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            p,
+            binning_x: 0,
+            binning_y: 0,
+            roi: RegionOfInterest::default(),
+        }
+    }
+
+    #[test]
+    fn prefer_projection_matrix() {
+        let k = [600.0, 0.0, 320.0, 0.0, 610.0, 240.0, 0.0, 0.0, 1.0];
+        let p = [
+            590.0, 0.0, 318.0, 0.0, 0.0, 595.0, 242.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+
+        let params = tag_params_from_camera_info(&camera_info(k, p), 0.16).unwrap();
+        assert_eq!(
+            params,
+            TagParams {
+                tagsize: 0.16,
+                fx: 590.0,
+                fy: 595.0,
+                cx: 318.0,
+                cy: 242.0,
+            }
+        );
+
+        let params = tag_params_from_camera_info(&camera_info(k, [0.0; 12]), 0.16).unwrap();
+        assert_eq!(
+            (params.fx, params.fy, params.cx, params.cy),
+            (600.0, 610.0, 320.0, 240.0)
+        );
+    }
+
+    #[test]
+    fn reject_uncalibrated_camera() {
+        let info = camera_info([0.0; 9], [0.0; 12]);
+        assert!(tag_params_from_camera_info(&info, 0.16).is_err());
+
+        let k = [600.0, 0.0, 320.0, 0.0, 600.0, 240.0, 0.0, 0.0, 1.0];
+        assert!(tag_params_from_camera_info(&camera_info(k, [0.0; 12]), 0.0).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Conversion from detections to `apriltag_msgs` messages.
+
+use crate::msg::{
+    apriltag_msgs::{AprilTagDetection, AprilTagDetectionArray, Point},
+    std_msgs::Header,
+};
+use apriltag::DetectionRecord;
+
+impl From<&DetectionRecord> for AprilTagDetection {
+    fn from(record: &DetectionRecord) -> Self {
+        let [cx, cy] = record.center;
+        let [[r0c0, r0c1, r0c2], [r1c0, r1c1, r1c2], [r2c0, r2c1, r2c2]] = record.homography;
+        Self {
+            family: record.family.clone(),
+            id: record.id as i32,
+            hamming: record.hamming as i32,
+            goodness: 0.0,
+            decision_margin: record.decision_margin,
+            centre: Point { x: cx, y: cy },
+            corners: record.corners.map(|[x, y]| Point { x, y }),
+            homography: [r0c0, r0c1, r0c2, r1c0, r1c1, r1c2, r2c0, r2c1, r2c2],
+        }
+    }
+}
+
+/// Build an `apriltag_msgs/AprilTagDetectionArray` from the detections
+/// of an image.
+///
+/// The header is usually copied from the source image.
+pub fn to_detection_array<'a, I>(header: Header, records: I) -> AprilTagDetectionArray
+where
+    I: IntoIterator<Item = &'a DetectionRecord>,
+{
+    AprilTagDetectionArray {
+        header,
+        detections: records.into_iter().map(AprilTagDetection::from).collect(),
+    }
+}
+
+/// The TF frame ID of a tag, in form of `family:id` such as
+/// `tag36h11:7`, the same as the apriltag_ros node.
+pub fn tag_frame_id(family: &str, id: usize) -> String {
+    format!("{family}:{id}")
+}
@@ -0,0 +1,17 @@
+//! Defines the error type for the crate.
+
+/// The error type for the crate.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Unsupported image encoding '{encoding}'")]
+    UnsupportedEncoding { encoding: String },
+
+    #[error("Unable to convert the image: {reason}")]
+    ConvertImageError { reason: String },
+
+    #[error("Unable to convert the camera info: {reason}")]
+    ConvertCameraInfoError { reason: String },
+
+    #[error(transparent)]
+    Apriltag(#[from] apriltag::Error),
+}
@@ -0,0 +1,198 @@
+//! Conversion from `sensor_msgs/Image` to grayscale images.
+
+use crate::{error::Error, msg::sensor_msgs};
+use apriltag::{image_buf::DEFAULT_ALIGNMENT_U8, Image};
+
+/// The pixel encodings accepted by [image_from_msg].
+pub const SUPPORTED_ENCODINGS: &[&str] = &["mono8", "bgr8", "rgb8", "yuv422", "yuv422_yuy2"];
+
+/// Convert a `sensor_msgs/Image` to a grayscale image.
+///
+/// The `mono8`, `bgr8`, `rgb8`, `yuv422` (UYVY) and `yuv422_yuy2`
+/// (YUYV) encodings are supported. The luma of color images is computed
+/// with the Rec. 709 coefficients, while the Y channel of YUV images is
+/// taken as is.
+pub fn image_from_msg(msg: &sensor_msgs::Image) -> Result<Image, Error> {
+    let width = msg.width as usize;
+    let height = msg.height as usize;
+    let step = msg.step as usize;
+
+    let encoding = Encoding::parse(&msg.encoding)?;
+    let bytes_per_pixel = encoding.bytes_per_pixel();
+
+    let row_len = width
+        .checked_mul(bytes_per_pixel)
+        .ok_or_else(|| Error::ConvertImageError {
+            reason: format!("image size {width}x{height} is too large"),
+        })?;
+    if step < row_len {
+        return Err(Error::ConvertImageError {
+            reason: format!(
+                "step {step} is less than {row_len} bytes of a {width}-pixel {} row",
+                msg.encoding
+            ),
+        });
+    }
+    let expect_len = step
+        .checked_mul(height)
+        .ok_or_else(|| Error::ConvertImageError {
+            reason: format!("image size {width}x{height} is too large"),
+        })?;
+    if msg.data.len() < expect_len {
+        return Err(Error::ConvertImageError {
+            reason: format!(
+                "expect {expect_len} bytes for {height} rows of {step} bytes, but get {}",
+                msg.data.len()
+            ),
+        });
+    }
+
+    let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+    let stride = image.stride();
+    if width == 0 || height == 0 {
+        return Ok(image);
+    }
+
+    msg.data
+        .chunks(step)
+        .take(height)
+        .zip(image.as_slice_mut().chunks_mut(stride))
+        .for_each(|(src, dst)| encoding.convert_row(&src[..row_len], &mut dst[..width]));
+
+    Ok(image)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Encoding {
+    Mono8,
+    Bgr8,
+    Rgb8,
+    /// Two pixels are packed in U Y0 V Y1.
+    Uyvy,
+    /// Two pixels are packed in Y0 U Y1 V.
+    Yuyv,
+}
+
+impl Encoding {
+    fn parse(encoding: &str) -> Result<Self, Error> {
+        Ok(match encoding {
+            "mono8" => Self::Mono8,
+            "bgr8" => Self::Bgr8,
+            "rgb8" => Self::Rgb8,
+            "yuv422" => Self::Uyvy,
+            "yuv422_yuy2" => Self::Yuyv,
+            _ => {
+                return Err(Error::UnsupportedEncoding {
+                    encoding: encoding.to_string(),
+                })
+            }
+        })
+    }
+
+    fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Mono8 => 1,
+            Self::Bgr8 | Self::Rgb8 => 3,
+            Self::Uyvy | Self::Yuyv => 2,
+        }
+    }
+
+    fn convert_row(self, src: &[u8], dst: &mut [u8]) {
+        match self {
+            Self::Mono8 => dst.copy_from_slice(src),
+            Self::Bgr8 => src
+                .chunks_exact(3)
+                .zip(dst)
+                .for_each(|(pixel, luma)| *luma = rgb_to_luma(pixel[2], pixel[1], pixel[0])),
+            Self::Rgb8 => src
+                .chunks_exact(3)
+                .zip(dst)
+                .for_each(|(pixel, luma)| *luma = rgb_to_luma(pixel[0], pixel[1], pixel[2])),
+            Self::Uyvy => src
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .zip(dst)
+                .for_each(|(y, luma)| *luma = *y),
+            Self::Yuyv => src
+                .iter()
+                .step_by(2)
+                .zip(dst)
+                .for_each(|(y, luma)| *luma = *y),
+        }
+    }
+}
+
+fn rgb_to_luma(r: u8, g: u8, b: u8) -> u8 {
+    let luma = 2126 * r as u32 + 7152 * g as u32 + 722 * b as u32;
+    ((luma + 5000) / 10000) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msg::std_msgs::Header;
+    use ros2_client::builtin_interfaces::Time;
+
+    fn msg(
+        encoding: &str,
+        width: u32,
+        height: u32,
+        step: u32,
+        data: Vec<u8>,
+    ) -> sensor_msgs::Image {
+        sensor_msgs::Image {
+            header: Header::new(Time::ZERO, "camera"),
+            height,
+            width,
+            encoding: encoding.to_string(),
+            is_bigendian: 0,
+            step,
+            data,
+        }
+    }
+
+    fn pixels(image: &Image) -> Vec<u8> {
+        (0..image.height())
+            .flat_map(|y| (0..image.width()).map(move |x| (x, y)))
+            .map(|index| image[index])
+            .collect()
+    }
+
+    #[test]
+    fn convert_supported_encodings() {
+        // The padding byte at the end of each row is skipped.
+        let image = image_from_msg(&msg("mono8", 2, 2, 3, vec![1, 2, 0, 3, 4, 0])).unwrap();
+        assert_eq!(pixels(&image), [1, 2, 3, 4]);
+
+        #[rustfmt::skip]
+        let bgr = vec![
+            255, 0, 0,    0, 0, 255,
+            0, 255, 0,    128, 128, 128,
+        ];
+        let image = image_from_msg(&msg("bgr8", 2, 2, 6, bgr.clone())).unwrap();
+        assert_eq!(pixels(&image), [18, 54, 182, 128]);
+        let image = image_from_msg(&msg("rgb8", 2, 2, 6, bgr)).unwrap();
+        assert_eq!(pixels(&image), [54, 18, 182, 128]);
+
+        let uyvy = vec![128, 10, 128, 20, 128, 30, 128, 40];
+        let image = image_from_msg(&msg("yuv422", 4, 1, 8, uyvy)).unwrap();
+        assert_eq!(pixels(&image), [10, 20, 30, 40]);
+
+        let yuyv = vec![10, 128, 20, 128, 30, 128, 40, 128];
+        let image = image_from_msg(&msg("yuv422_yuy2", 4, 1, 8, yuyv)).unwrap();
+        assert_eq!(pixels(&image), [10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn reject_invalid_images() {
+        let err = image_from_msg(&msg("mono16", 2, 2, 4, vec![0; 8])).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedEncoding { .. }));
+
+        let err = image_from_msg(&msg("rgb8", 2, 2, 4, vec![0; 8])).unwrap_err();
+        assert!(matches!(err, Error::ConvertImageError { .. }));
+
+        let err = image_from_msg(&msg("mono8", 2, 2, 2, vec![0; 3])).unwrap_err();
+        assert!(matches!(err, Error::ConvertImageError { .. }));
+    }
+}
@@ -0,0 +1,72 @@
+//! Conversion between ROS 2 messages and [apriltag] types.
+//!
+//! The message types in [msg] implement [ros2_client::Message], so they
+//! can be published and subscribed by [ros2_client] nodes without a ROS
+//! installation. This crate provides
+//!
+//! - [image_from_msg] to build an [Image](apriltag::Image) from a
+//!   `sensor_msgs/Image`,
+//! - [tag_params_from_camera_info] to build [TagParams](apriltag::TagParams)
+//!   from a `sensor_msgs/CameraInfo`,
+//! - [to_detection_array] to build an `apriltag_msgs/AprilTagDetectionArray`,
+//! - [to_pose_with_covariance_stamped], [to_transform_stamped] and
+//!   [to_tf_message] to publish tag poses.
+//!
+//! # Example
+//!
+//! ```rust
+//! use apriltag::{Detector, Family};
+//! use apriltag_ros2::{
+//!     msg::{builtin_interfaces::Time, sensor_msgs, std_msgs::Header},
+//!     prelude::*,
+//! };
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let msg = sensor_msgs::Image {
+//!         header: Header::new(Time::ZERO, "camera_optical_frame"),
+//!         height: 240,
+//!         width: 320,
+//!         encoding: "mono8".to_string(),
+//!         is_bigendian: 0,
+//!         step: 320,
+//!         data: vec![255; 320 * 240],
+//!     };
+//!
+//!     let image = image_from_msg(&msg)?;
+//!     let mut detector = Detector::builder()
+//!         .add_family_bits(Family::tag_36h11(), 1)
+//!         .build()?;
+//!     let records: Vec<_> = detector
+//!         .detect(&image)
+//!         .iter()
+//!         .map(|detection| detection.to_record())
+//!         .collect();
+//!     let detections = to_detection_array(msg.header.clone(), &records);
+//!     assert!(detections.detections.is_empty());
+//!     Ok(())
+//! }
+//! ```
+
+pub mod camera_info;
+pub mod detection;
+pub mod error;
+pub mod image;
+pub mod msg;
+pub mod pose;
+
+pub use camera_info::tag_params_from_camera_info;
+pub use detection::{tag_frame_id, to_detection_array};
+pub use error::Error;
+pub use image::image_from_msg;
+pub use pose::{
+    diagonal_covariance, rotation_to_quaternion, to_pose_with_covariance_stamped, to_tf_message,
+    to_transform_stamped,
+};
+pub use ros2_client;
+
+pub mod prelude {
+    pub use crate::{
+        image_from_msg, tag_frame_id, tag_params_from_camera_info, to_detection_array,
+        to_pose_with_covariance_stamped, to_tf_message, to_transform_stamped,
+    };
+}
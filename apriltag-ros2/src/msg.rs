@@ -0,0 +1,293 @@
+//! ROS 2 message types used by the conversions.
+//!
+//! The types mirror the `.msg` definitions field by field, so that they
+//! are serialized to the same CDR layout and can be published and
+//! subscribed by [ros2_client] nodes. The modules are named after the
+//! ROS packages.
+
+pub use ros2_client::builtin_interfaces;
+
+/// Messages of the `std_msgs` package.
+pub mod std_msgs {
+    use ros2_client::{builtin_interfaces::Time, Message};
+    use serde::{Deserialize, Serialize};
+
+    /// `std_msgs/Header`
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Header {
+        pub stamp: Time,
+        pub frame_id: String,
+    }
+
+    impl Message for Header {}
+
+    impl Header {
+        /// Create a header with the time stamp and the frame ID.
+        pub fn new(stamp: Time, frame_id: impl Into<String>) -> Self {
+            Self {
+                stamp,
+                frame_id: frame_id.into(),
+            }
+        }
+    }
+}
+
+/// Messages of the `geometry_msgs` package.
+pub mod geometry_msgs {
+    use super::std_msgs::Header;
+    use ros2_client::Message;
+    use serde::{Deserialize, Serialize};
+
+    /// `geometry_msgs/Point`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct Point {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    /// `geometry_msgs/Vector3`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct Vector3 {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    /// `geometry_msgs/Quaternion`
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct Quaternion {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+        pub w: f64,
+    }
+
+    impl Default for Quaternion {
+        fn default() -> Self {
+            Self {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            }
+        }
+    }
+
+    /// `geometry_msgs/Pose`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct Pose {
+        pub position: Point,
+        pub orientation: Quaternion,
+    }
+
+    /// `geometry_msgs/PoseWithCovariance`
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovariance {
+        pub pose: Pose,
+        /// The row-major 6x6 covariance of `(x, y, z)` and the rotation
+        /// about the fixed x, y and z axes.
+        #[serde(with = "covariance")]
+        pub covariance: [f64; 36],
+    }
+
+    impl Default for PoseWithCovariance {
+        fn default() -> Self {
+            Self {
+                pose: Pose::default(),
+                covariance: [0.0; 36],
+            }
+        }
+    }
+
+    /// `geometry_msgs/PoseWithCovarianceStamped`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovarianceStamped {
+        pub header: Header,
+        pub pose: PoseWithCovariance,
+    }
+
+    impl Message for PoseWithCovarianceStamped {}
+
+    /// `geometry_msgs/Transform`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct Transform {
+        pub translation: Vector3,
+        pub rotation: Quaternion,
+    }
+
+    /// `geometry_msgs/TransformStamped`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct TransformStamped {
+        pub header: Header,
+        pub child_frame_id: String,
+        pub transform: Transform,
+    }
+
+    impl Message for TransformStamped {}
+
+    /// Serde implements arrays up to 32 elements, so the 36 elements of
+    /// a covariance are (de)serialized as a tuple here, which is also
+    /// the CDR layout of a fixed-size array.
+    mod covariance {
+        use serde::{
+            de::{self, SeqAccess, Visitor},
+            ser::SerializeTuple,
+            Deserializer, Serializer,
+        };
+        use std::fmt;
+
+        const LEN: usize = 36;
+
+        pub fn serialize<S>(values: &[f64; LEN], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut tuple = serializer.serialize_tuple(LEN)?;
+            for value in values {
+                tuple.serialize_element(value)?;
+            }
+            tuple.end()
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<[f64; LEN], D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct ArrayVisitor;
+
+            impl<'de> Visitor<'de> for ArrayVisitor {
+                type Value = [f64; LEN];
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "an array of {LEN} floats")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut values = [0.0; LEN];
+                    for (index, value) in values.iter_mut().enumerate() {
+                        *value = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                    }
+                    Ok(values)
+                }
+            }
+
+            deserializer.deserialize_tuple(LEN, ArrayVisitor)
+        }
+    }
+}
+
+/// Messages of the `tf2_msgs` package.
+pub mod tf2_msgs {
+    use super::geometry_msgs::TransformStamped;
+    use ros2_client::Message;
+    use serde::{Deserialize, Serialize};
+
+    /// `tf2_msgs/TFMessage`, published on `/tf` and `/tf_static`.
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct TFMessage {
+        pub transforms: Vec<TransformStamped>,
+    }
+
+    impl Message for TFMessage {}
+}
+
+/// Messages of the `sensor_msgs` package.
+pub mod sensor_msgs {
+    use super::std_msgs::Header;
+    use ros2_client::Message;
+    use serde::{Deserialize, Serialize};
+
+    /// `sensor_msgs/Image`
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Image {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        /// The pixel encoding, such as `mono8` or `bgr8`.
+        pub encoding: String,
+        pub is_bigendian: u8,
+        /// The length of a row in bytes.
+        pub step: u32,
+        pub data: Vec<u8>,
+    }
+
+    impl Message for Image {}
+
+    /// `sensor_msgs/RegionOfInterest`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+    pub struct RegionOfInterest {
+        pub x_offset: u32,
+        pub y_offset: u32,
+        pub height: u32,
+        pub width: u32,
+        pub do_rectify: bool,
+    }
+
+    /// `sensor_msgs/CameraInfo`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct CameraInfo {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        pub distortion_model: String,
+        /// The distortion parameters.
+        pub d: Vec<f64>,
+        /// The row-major intrinsic matrix of the raw image.
+        pub k: [f64; 9],
+        /// The row-major rectification matrix.
+        pub r: [f64; 9],
+        /// The row-major projection matrix of the rectified image.
+        pub p: [f64; 12],
+        pub binning_x: u32,
+        pub binning_y: u32,
+        pub roi: RegionOfInterest,
+    }
+
+    impl Message for CameraInfo {}
+}
+
+/// Messages of the `apriltag_msgs` package.
+pub mod apriltag_msgs {
+    use super::std_msgs::Header;
+    use ros2_client::Message;
+    use serde::{Deserialize, Serialize};
+
+    /// `apriltag_msgs/Point`, a point in pixels.
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct Point {
+        pub x: f64,
+        pub y: f64,
+    }
+
+    /// `apriltag_msgs/AprilTagDetection`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct AprilTagDetection {
+        pub family: String,
+        pub id: i32,
+        pub hamming: i32,
+        /// Unused since AprilTag 3 and always zero.
+        pub goodness: f32,
+        pub decision_margin: f32,
+        pub centre: Point,
+        pub corners: [Point; 4],
+        /// The row-major homography that maps tag coordinates to pixels.
+        pub homography: [f64; 9],
+    }
+
+    impl Message for AprilTagDetection {}
+
+    /// `apriltag_msgs/AprilTagDetectionArray`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct AprilTagDetectionArray {
+        pub header: Header,
+        pub detections: Vec<AprilTagDetection>,
+    }
+
+    impl Message for AprilTagDetectionArray {}
+}
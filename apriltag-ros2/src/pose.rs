@@ -0,0 +1,192 @@
+//! Conversion from tag poses to `geometry_msgs` and TF messages.
+//!
+//! A [PoseRecord] is expressed in the camera frame, where x points
+//! right, y points down and z points forward. It is the ROS optical
+//! frame convention, so the header should refer to the optical frame
+//! of the camera. The translation is in the same unit as `tagsize`,
+//! which should be given in meters.
+
+use crate::{
+    detection::tag_frame_id,
+    msg::{
+        geometry_msgs::{
+            Point, Pose, PoseWithCovariance, PoseWithCovarianceStamped, Quaternion, Transform,
+            TransformStamped, Vector3,
+        },
+        std_msgs::Header,
+        tf2_msgs::TFMessage,
+    },
+};
+use apriltag::{DetectionRecord, PoseRecord};
+
+impl From<&PoseRecord> for Pose {
+    fn from(pose: &PoseRecord) -> Self {
+        let [x, y, z] = pose.translation;
+        Self {
+            position: Point { x, y, z },
+            orientation: rotation_to_quaternion(&pose.rotation),
+        }
+    }
+}
+
+impl From<&PoseRecord> for Transform {
+    fn from(pose: &PoseRecord) -> Self {
+        let [x, y, z] = pose.translation;
+        Self {
+            translation: Vector3 { x, y, z },
+            rotation: rotation_to_quaternion(&pose.rotation),
+        }
+    }
+}
+
+/// Convert a row-major rotation matrix to a unit quaternion.
+pub fn rotation_to_quaternion(rotation: &[[f64; 3]; 3]) -> Quaternion {
+    let [[r00, r01, r02], [r10, r11, r12], [r20, r21, r22]] = *rotation;
+    let trace = r00 + r11 + r22;
+
+    // Pick the largest component to divide by for numerical stability.
+    let (x, y, z, w) = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        ((r21 - r12) / s, (r02 - r20) / s, (r10 - r01) / s, s / 4.0)
+    } else if r00 > r11 && r00 > r22 {
+        let s = (1.0 + r00 - r11 - r22).sqrt() * 2.0;
+        (s / 4.0, (r01 + r10) / s, (r02 + r20) / s, (r21 - r12) / s)
+    } else if r11 > r22 {
+        let s = (1.0 + r11 - r00 - r22).sqrt() * 2.0;
+        ((r01 + r10) / s, s / 4.0, (r12 + r21) / s, (r02 - r20) / s)
+    } else {
+        let s = (1.0 + r22 - r00 - r11).sqrt() * 2.0;
+        ((r02 + r20) / s, (r12 + r21) / s, s / 4.0, (r10 - r01) / s)
+    };
+
+    let norm = (x * x + y * y + z * z + w * w).sqrt();
+    Quaternion {
+        x: x / norm,
+        y: y / norm,
+        z: z / norm,
+        w: w / norm,
+    }
+}
+
+/// Build a 6x6 covariance with the position variance on the first three
+/// diagonal entries and the rotation variance on the last three.
+pub fn diagonal_covariance(position_variance: f64, rotation_variance: f64) -> [f64; 36] {
+    let mut covariance = [0.0; 36];
+    for index in 0..6 {
+        covariance[index * 7] = if index < 3 {
+            position_variance
+        } else {
+            rotation_variance
+        };
+    }
+    covariance
+}
+
+/// Build a `geometry_msgs/PoseWithCovarianceStamped` from a tag pose.
+pub fn to_pose_with_covariance_stamped(
+    header: Header,
+    pose: &PoseRecord,
+    covariance: [f64; 36],
+) -> PoseWithCovarianceStamped {
+    PoseWithCovarianceStamped {
+        header,
+        pose: PoseWithCovariance {
+            pose: pose.into(),
+            covariance,
+        },
+    }
+}
+
+/// Build a `geometry_msgs/TransformStamped` from the camera frame in the
+/// header to the child frame of the tag.
+pub fn to_transform_stamped(
+    header: Header,
+    child_frame_id: impl Into<String>,
+    pose: &PoseRecord,
+) -> TransformStamped {
+    TransformStamped {
+        header,
+        child_frame_id: child_frame_id.into(),
+        transform: pose.into(),
+    }
+}
+
+/// Build a `tf2_msgs/TFMessage` with a transform per tag.
+///
+/// The child frames are named by [tag_frame_id].
+pub fn to_tf_message<'a, I>(header: &Header, tags: I) -> TFMessage
+where
+    I: IntoIterator<Item = (&'a DetectionRecord, &'a PoseRecord)>,
+{
+    let transforms = tags
+        .into_iter()
+        .map(|(detection, pose)| {
+            to_transform_stamped(
+                header.clone(),
+                tag_frame_id(&detection.family, detection.id),
+                pose,
+            )
+        })
+        .collect();
+    TFMessage { transforms }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apriltag::synthetic::euler_rotation;
+
+    fn quaternion_to_rotation(q: &Quaternion) -> [[f64; 3]; 3] {
+        let Quaternion { x, y, z, w } = *q;
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+            ],
+            [
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+            ],
+            [
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        // Cover every branch, including rotations by nearly 180 degrees.
+        let angles = [
+            (0.0, 0.0, 0.0),
+            (0.3, -0.2, 1.1),
+            (3.1, 0.1, 0.0),
+            (0.0, 3.1, 0.2),
+            (0.1, 0.0, 3.1),
+        ];
+        for (roll, pitch, yaw) in angles {
+            let rotation = euler_rotation(roll, pitch, yaw);
+            let q = rotation_to_quaternion(&rotation);
+            let norm = q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w;
+            assert!((norm - 1.0).abs() < 1e-9);
+
+            let recovered = quaternion_to_rotation(&q);
+            for (lhs, rhs) in recovered.iter().flatten().zip(rotation.iter().flatten()) {
+                assert!((lhs - rhs).abs() < 1e-9, "{recovered:?} != {rotation:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn build_diagonal_covariance() {
+        let covariance = diagonal_covariance(0.01, 0.1);
+        assert_eq!(covariance[0], 0.01);
+        assert_eq!(covariance[14], 0.01);
+        assert_eq!(covariance[21], 0.1);
+        assert_eq!(covariance[35], 0.1);
+        assert_eq!(covariance.iter().filter(|&&value| value != 0.0).count(), 6);
+    }
+}
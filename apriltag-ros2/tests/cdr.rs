@@ -0,0 +1,61 @@
+use apriltag::{synthetic, PoseRecord};
+use apriltag_ros2::{
+    diagonal_covariance,
+    msg::{
+        apriltag_msgs::{AprilTagDetection, AprilTagDetectionArray, Point},
+        builtin_interfaces::Time,
+        geometry_msgs::PoseWithCovarianceStamped,
+        std_msgs::Header,
+    },
+    to_pose_with_covariance_stamped,
+};
+use byteorder::LittleEndian;
+
+#[test]
+fn pose_with_covariance_layout() {
+    let pose = PoseRecord {
+        rotation: synthetic::euler_rotation(0.1, 0.2, 0.3),
+        translation: [1.0, 2.0, 3.0],
+    };
+    let msg = to_pose_with_covariance_stamped(
+        Header::new(Time::new(1, 2), "map"),
+        &pose,
+        diagonal_covariance(0.01, 0.1),
+    );
+
+    // The stamp takes 8 bytes, "map" with the length and NUL takes 8
+    // bytes, followed by 7 and 36 doubles without length prefixes.
+    let bytes = cdr_encoding::to_vec::<_, LittleEndian>(&msg).unwrap();
+    assert_eq!(bytes.len(), 8 + 8 + 7 * 8 + 36 * 8);
+
+    let (decoded, _) =
+        cdr_encoding::from_bytes::<PoseWithCovarianceStamped, LittleEndian>(&bytes).unwrap();
+    assert_eq!(decoded, msg);
+}
+
+#[test]
+fn detection_array_round_trip() {
+    let msg = AprilTagDetectionArray {
+        header: Header::new(Time::new(3, 4), "camera"),
+        detections: vec![AprilTagDetection {
+            family: "tag36h11".to_string(),
+            id: 5,
+            hamming: 1,
+            goodness: 0.0,
+            decision_margin: 42.5,
+            centre: Point { x: 10.0, y: 20.0 },
+            corners: [
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 1.0, y: 0.0 },
+                Point { x: 1.0, y: 1.0 },
+                Point { x: 0.0, y: 1.0 },
+            ],
+            homography: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        }],
+    };
+
+    let bytes = cdr_encoding::to_vec::<_, LittleEndian>(&msg).unwrap();
+    let (decoded, _) =
+        cdr_encoding::from_bytes::<AprilTagDetectionArray, LittleEndian>(&bytes).unwrap();
+    assert_eq!(decoded, msg);
+}
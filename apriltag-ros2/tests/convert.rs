@@ -0,0 +1,105 @@
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    DetectorConfig, Family, PoseRecord,
+};
+use apriltag_ros2::{
+    msg::{
+        builtin_interfaces::Time,
+        sensor_msgs::{self, CameraInfo, RegionOfInterest},
+        std_msgs::Header,
+    },
+    prelude::*,
+    rotation_to_quaternion,
+};
+
+#[test]
+fn detect_bgr8_image_and_publish_poses() {
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let truth = PoseRecord {
+        rotation: synthetic::euler_rotation(0.2, -0.1, 0.3),
+        translation: [0.02, -0.01, 0.5],
+    };
+    let tag = SyntheticTag {
+        family: &family,
+        id: 7,
+        size: 0.1,
+        pose: truth.clone(),
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+
+    // Pack the gray image into a bgr8 message with row padding.
+    let step = camera.width * 3 + 4;
+    let mut data = vec![0; step * camera.height];
+    for (y, row) in data.chunks_mut(step).enumerate() {
+        for (x, pixel) in row.chunks_mut(3).take(camera.width).enumerate() {
+            pixel.fill(scene.image[(x, y)]);
+        }
+    }
+    let header = Header::new(Time::new(12, 345), "camera_optical_frame");
+    let image_msg = sensor_msgs::Image {
+        header: header.clone(),
+        height: camera.height as u32,
+        width: camera.width as u32,
+        encoding: "bgr8".to_string(),
+        is_bigendian: 0,
+        step: step as u32,
+        data,
+    };
+    let info = CameraInfo {
+        header: header.clone(),
+        height: camera.height as u32,
+        width: camera.width as u32,
+        distortion_model: "plumb_bob".to_string(),
+        d: vec![0.0; 5],
+        k: [
+            camera.fx, 0.0, camera.cx, 0.0, camera.fy, camera.cy, 0.0, 0.0, 1.0,
+        ],
+        r: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        p: [
+            camera.fx, 0.0, camera.cx, 0.0, 0.0, camera.fy, camera.cy, 0.0, 0.0, 0.0, 1.0, 0.0,
+        ],
+        binning_x: 0,
+        binning_y: 0,
+        roi: RegionOfInterest::default(),
+    };
+
+    let image = image_from_msg(&image_msg).unwrap();
+    let tag_params = tag_params_from_camera_info(&info, 0.1).unwrap();
+    let mut detector = DetectorConfig::default().build().unwrap();
+    let detections = detector.detect(&image);
+    assert_eq!(detections.len(), 1);
+
+    let records: Vec<_> = detections.iter().map(|d| d.to_record()).collect();
+    let array = to_detection_array(image_msg.header.clone(), &records);
+    assert_eq!(array.header, header);
+    assert_eq!(array.detections.len(), 1);
+    let detection = &array.detections[0];
+    assert_eq!(detection.family, "tag36h11");
+    assert_eq!(detection.id, 7);
+    assert_eq!(detection.centre.x, records[0].center[0]);
+    assert_eq!(detection.corners[2].y, records[0].corners[2][1]);
+    assert_eq!(detection.homography[5], records[0].homography[1][2]);
+
+    let pose = detections[0]
+        .estimate_tag_pose(&tag_params)
+        .unwrap()
+        .to_record();
+    for (lhs, rhs) in pose.translation.iter().zip(&truth.translation) {
+        assert!((lhs - rhs).abs() < 0.01, "{pose:?}");
+    }
+
+    let stamped = to_pose_with_covariance_stamped(header.clone(), &pose, [0.0; 36]);
+    assert_eq!(stamped.pose.pose.position.z, pose.translation[2]);
+
+    let expect = rotation_to_quaternion(&truth.rotation);
+    let actual = stamped.pose.pose.orientation;
+    let dot = expect.x * actual.x + expect.y * actual.y + expect.z * actual.z + expect.w * actual.w;
+    assert!(dot.abs() > 0.999, "{expect:?} != {actual:?}");
+
+    let tf = to_tf_message(&header, records.iter().zip([&pose]));
+    assert_eq!(tf.transforms.len(), 1);
+    assert_eq!(tf.transforms[0].child_frame_id, "tag36h11:7");
+    assert_eq!(tf.transforms[0].header.frame_id, "camera_optical_frame");
+    assert_eq!(tf.transforms[0].transform.rotation, actual);
+}
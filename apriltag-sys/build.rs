@@ -1,4 +1,8 @@
-use anyhow::{anyhow, bail, ensure, Context, Result};
+#[cfg(target_os = "windows")]
+use anyhow::anyhow;
+#[cfg(feature = "buildtime-bindgen")]
+use anyhow::Context;
+use anyhow::{bail, ensure, Result};
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use std::{
@@ -7,6 +11,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
+#[cfg(feature = "buildtime-bindgen")]
 static MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
 static OUT_DIR: Lazy<PathBuf> = Lazy::new(|| {
     let path = env::var_os("OUT_DIR").expect("The OUT_DIR environment variable is not set");
@@ -0,0 +1,132 @@
+use anyhow::{ensure, Context, Result};
+use apriltag::{DetectionRecord, Detector, FamilyKind, PoseRecord, TagParams};
+use apriltag_v4l::{Capture, CaptureParams, PixelFormat};
+use clap::Parser;
+use serde::Serialize;
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// Detect tags on a Video4Linux camera and print a JSON line per frame.
+#[derive(Debug, Clone, Parser)]
+struct Opts {
+    /// The capture device.
+    #[clap(long, default_value = "/dev/video0")]
+    pub device: PathBuf,
+
+    /// The requested frame width.
+    #[clap(long, default_value = "640")]
+    pub width: u32,
+
+    /// The requested frame height.
+    #[clap(long, default_value = "480")]
+    pub height: u32,
+
+    /// The requested pixel format, one of GREY, YUYV, UYVY and MJPG.
+    #[clap(long, default_value = "YUYV")]
+    pub format: PixelFormat,
+
+    /// The tag family.
+    #[clap(long, default_value = "tag36h11")]
+    pub family: FamilyKind,
+
+    /// Optional tag parameters in format "tagsize,fx,fy,cx,cy". It
+    /// enables pose estimation.
+    #[clap(long)]
+    pub tag_params: Option<String>,
+
+    /// Stop after the number of frames. It runs forever if not set.
+    #[clap(long)]
+    pub frames: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct FrameOutput {
+    sequence: u32,
+    /// The time stamp in seconds.
+    timestamp: f64,
+    detections: Vec<DetectionOutput>,
+}
+
+#[derive(Serialize)]
+struct DetectionOutput {
+    #[serde(flatten)]
+    detection: DetectionRecord,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pose: Option<PoseRecord>,
+}
+
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    let tag_params = opts
+        .tag_params
+        .as_deref()
+        .map(parse_tag_params)
+        .transpose()?;
+
+    let mut capture = Capture::open(&CaptureParams {
+        device: opts.device.clone(),
+        width: opts.width,
+        height: opts.height,
+        format: opts.format,
+        ..Default::default()
+    })?;
+    eprintln!(
+        "capturing {}x{} {} frames from {}",
+        capture.width(),
+        capture.height(),
+        capture.format(),
+        opts.device.display()
+    );
+
+    let mut detector = Detector::builder()
+        .add_family_bits(opts.family, 1)
+        .build()?;
+    let mut stdout = io::stdout().lock();
+
+    for _ in 0..opts.frames.unwrap_or(usize::MAX) {
+        let frame = capture.next_frame()?;
+        let detections = detector
+            .detect(frame.image)
+            .iter()
+            .map(|detection| DetectionOutput {
+                detection: detection.to_record(),
+                pose: tag_params
+                    .as_ref()
+                    .and_then(|params| detection.estimate_tag_pose(params))
+                    .map(|pose| pose.to_record()),
+            })
+            .collect();
+
+        let output = FrameOutput {
+            sequence: frame.sequence,
+            timestamp: frame.timestamp.as_secs_f64(),
+            detections,
+        };
+        serde_json::to_writer(&mut stdout, &output)?;
+        writeln!(stdout)?;
+    }
+
+    Ok(())
+}
+
+fn parse_tag_params(text: &str) -> Result<TagParams> {
+    let values = text
+        .split(',')
+        .map(|token| token.trim().parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("failed to parse tag parameters {text}"))?;
+    ensure!(
+        values.len() == 5,
+        r#"tag parameters must be in format "tagsize,fx,fy,cx,cy""#
+    );
+
+    Ok(TagParams {
+        tagsize: values[0],
+        fx: values[1],
+        fy: values[2],
+        cx: values[3],
+        cy: values[4],
+    })
+}
@@ -0,0 +1,154 @@
+//! Frame capture from Video4Linux devices.
+
+use crate::{
+    error::Error,
+    frame::{FrameConverter, PixelFormat},
+};
+use apriltag::Image;
+use std::{path::PathBuf, time::Duration};
+use v4l::{
+    buffer::Type,
+    io::{mmap::Stream, traits::CaptureStream},
+    video::Capture as _,
+    Device, Format, FourCC,
+};
+
+/// The parameters to open a capture device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureParams {
+    /// The device path, such as `/dev/video0`.
+    pub device: PathBuf,
+    /// The requested frame width. The driver may choose the closest
+    /// supported size.
+    pub width: u32,
+    /// The requested frame height.
+    pub height: u32,
+    /// The requested pixel format.
+    pub format: PixelFormat,
+    /// The number of memory-mapped buffers shared with the driver.
+    pub buffers: u32,
+}
+
+impl Default for CaptureParams {
+    fn default() -> Self {
+        Self {
+            device: PathBuf::from("/dev/video0"),
+            width: 640,
+            height: 480,
+            format: PixelFormat::Yuyv,
+            buffers: 4,
+        }
+    }
+}
+
+/// A frame converted to a grayscale image.
+#[derive(Debug)]
+pub struct Frame<'a> {
+    /// The grayscale image, which is overwritten by the next frame.
+    pub image: &'a Image,
+    /// The frame sequence number counted by the driver.
+    pub sequence: u32,
+    /// The capture time stamp reported by the driver, usually on the
+    /// monotonic clock.
+    pub timestamp: Duration,
+}
+
+/// A streaming capture device.
+///
+/// Frames are captured into memory-mapped driver buffers and converted
+/// into a single reused [Image], so no memory is allocated per frame
+/// except for the JPEG decoder state.
+pub struct Capture {
+    device: Device,
+    stream: Stream<'static>,
+    converter: FrameConverter,
+}
+
+impl Capture {
+    /// Open the device, negotiate the format and start streaming.
+    pub fn open(params: &CaptureParams) -> Result<Self, Error> {
+        let path = params.device.display();
+        let device = Device::with_path(&params.device).map_err(|err| Error::CaptureError {
+            reason: format!("failed to open '{path}': {err}"),
+        })?;
+
+        let request = Format::new(
+            params.width,
+            params.height,
+            FourCC::new(&params.format.fourcc()),
+        );
+        let format = device
+            .set_format(&request)
+            .map_err(|err| Error::CaptureError {
+                reason: format!("failed to set the format of '{path}': {err}"),
+            })?;
+
+        // The driver may fall back to another pixel format.
+        let converter = FrameConverter::new(
+            PixelFormat::from_fourcc(format.fourcc.repr)?,
+            format.width as usize,
+            format.height as usize,
+            format.stride as usize,
+        )?;
+
+        let stream =
+            Stream::with_buffers(&device, Type::VideoCapture, params.buffers).map_err(|err| {
+                Error::CaptureError {
+                    reason: format!("failed to allocate buffers on '{path}': {err}"),
+                }
+            })?;
+
+        Ok(Self {
+            device,
+            stream,
+            converter,
+        })
+    }
+
+    /// Gets the underlying device, for example, to set controls.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Gets the negotiated pixel format.
+    pub fn format(&self) -> PixelFormat {
+        self.converter.format()
+    }
+
+    /// Gets the negotiated frame width.
+    pub fn width(&self) -> usize {
+        self.converter.width()
+    }
+
+    /// Gets the negotiated frame height.
+    pub fn height(&self) -> usize {
+        self.converter.height()
+    }
+
+    /// Set the timeout to wait for a frame. It waits forever by default.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.stream.set_timeout(timeout);
+    }
+
+    /// Wait for the next frame and convert it.
+    pub fn next_frame(&mut self) -> Result<Frame<'_>, Error> {
+        let (data, meta) = self.stream.next().map_err(|err| Error::CaptureError {
+            reason: format!("failed to dequeue a frame: {err}"),
+        })?;
+
+        // Compressed frames only fill a part of the buffer.
+        let len = match meta.bytesused as usize {
+            0 => data.len(),
+            len => len.min(data.len()),
+        };
+        let sequence = meta.sequence;
+        let timestamp: Duration = meta.timestamp.into();
+        let image = self.converter.convert(&data[..len])?;
+
+        Ok(Frame {
+            image,
+            sequence,
+            timestamp,
+        })
+    }
+}
@@ -0,0 +1,20 @@
+//! Defines the error type for the crate.
+
+/// The error type for the crate.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Unsupported pixel format '{fourcc}'")]
+    UnsupportedFormat { fourcc: String },
+
+    #[error("Unable to convert the frame: {reason}")]
+    ConvertFrameError { reason: String },
+
+    #[error("Unable to decode the JPEG frame: {reason}")]
+    DecodeJpegError { reason: String },
+
+    #[error("Unable to capture from the device: {reason}")]
+    CaptureError { reason: String },
+
+    #[error(transparent)]
+    Apriltag(#[from] apriltag::Error),
+}
@@ -0,0 +1,328 @@
+//! Conversion from captured frames to grayscale images.
+
+use crate::error::Error;
+use apriltag::{image_buf::DEFAULT_ALIGNMENT_U8, Image};
+use std::{fmt, str::FromStr};
+use zune_jpeg::{
+    zune_core::{bytestream::ZCursor, colorspace::ColorSpace, options::DecoderOptions},
+    JpegDecoder,
+};
+
+/// The pixel formats of captured frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// 8-bit grayscale, `GREY`.
+    Grey,
+    /// Packed YUV 4:2:2 in Y0 U Y1 V order, `YUYV`.
+    Yuyv,
+    /// Packed YUV 4:2:2 in U Y0 V Y1 order, `UYVY`.
+    Uyvy,
+    /// Motion JPEG, `MJPG`, where each frame is a JPEG image.
+    Mjpeg,
+}
+
+impl PixelFormat {
+    /// Gets the format from a V4L2 four character code.
+    pub fn from_fourcc(fourcc: [u8; 4]) -> Result<Self, Error> {
+        Ok(match &fourcc {
+            b"GREY" => Self::Grey,
+            b"YUYV" => Self::Yuyv,
+            b"UYVY" => Self::Uyvy,
+            b"MJPG" | b"JPEG" => Self::Mjpeg,
+            _ => {
+                return Err(Error::UnsupportedFormat {
+                    fourcc: String::from_utf8_lossy(&fourcc).into_owned(),
+                })
+            }
+        })
+    }
+
+    /// Gets the V4L2 four character code.
+    pub fn fourcc(self) -> [u8; 4] {
+        match self {
+            Self::Grey => *b"GREY",
+            Self::Yuyv => *b"YUYV",
+            Self::Uyvy => *b"UYVY",
+            Self::Mjpeg => *b"MJPG",
+        }
+    }
+
+    /// The number of bytes per pixel of uncompressed formats.
+    fn bytes_per_pixel(self) -> Option<usize> {
+        match self {
+            Self::Grey => Some(1),
+            Self::Yuyv | Self::Uyvy => Some(2),
+            Self::Mjpeg => None,
+        }
+    }
+}
+
+impl FromStr for PixelFormat {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let fourcc: [u8; 4] = text
+            .to_ascii_uppercase()
+            .into_bytes()
+            .try_into()
+            .map_err(|_| Error::UnsupportedFormat {
+                fourcc: text.to_string(),
+            })?;
+        Self::from_fourcc(fourcc)
+    }
+}
+
+impl fmt::Display for PixelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(std::str::from_utf8(&self.fourcc()).unwrap())
+    }
+}
+
+/// Converts frames of a fixed format and size to grayscale images.
+///
+/// The output image and the JPEG decoding buffer are allocated once and
+/// reused for every frame, so that a live pipeline does not allocate
+/// per frame.
+pub struct FrameConverter {
+    format: PixelFormat,
+    width: usize,
+    height: usize,
+    stride: usize,
+    image: Image,
+    scratch: Vec<u8>,
+}
+
+impl FrameConverter {
+    /// Create a converter for frames of the format and size.
+    ///
+    /// The stride is the number of bytes per row of uncompressed
+    /// formats. Zero means tightly packed rows. It is ignored for Motion
+    /// JPEG.
+    pub fn new(
+        format: PixelFormat,
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Result<Self, Error> {
+        let stride = match format.bytes_per_pixel() {
+            Some(bytes_per_pixel) => {
+                let row_len =
+                    width
+                        .checked_mul(bytes_per_pixel)
+                        .ok_or_else(|| Error::ConvertFrameError {
+                            reason: format!("frame size {width}x{height} is too large"),
+                        })?;
+                if stride == 0 {
+                    row_len
+                } else if stride < row_len {
+                    return Err(Error::ConvertFrameError {
+                        reason: format!(
+                            "stride {stride} is less than {row_len} bytes of a {width}-pixel {format} row"
+                        ),
+                    });
+                } else {
+                    stride
+                }
+            }
+            None => 0,
+        };
+        let image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+
+        Ok(Self {
+            format,
+            width,
+            height,
+            stride,
+            image,
+            scratch: vec![],
+        })
+    }
+
+    /// Gets the pixel format.
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Gets the frame width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the frame height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Convert a frame. The returned image is overwritten by the next
+    /// conversion.
+    pub fn convert(&mut self, data: &[u8]) -> Result<&Image, Error> {
+        if self.width == 0 || self.height == 0 {
+            return Ok(&self.image);
+        }
+
+        match self.format {
+            PixelFormat::Grey => {
+                self.convert_packed(data, 1, |src, dst| dst.copy_from_slice(src))?
+            }
+            PixelFormat::Yuyv => self.convert_packed(data, 2, |src, dst| {
+                src.iter()
+                    .step_by(2)
+                    .zip(dst)
+                    .for_each(|(y, luma)| *luma = *y)
+            })?,
+            PixelFormat::Uyvy => self.convert_packed(data, 2, |src, dst| {
+                src.iter()
+                    .skip(1)
+                    .step_by(2)
+                    .zip(dst)
+                    .for_each(|(y, luma)| *luma = *y)
+            })?,
+            PixelFormat::Mjpeg => self.convert_jpeg(data)?,
+        }
+
+        Ok(&self.image)
+    }
+
+    fn convert_packed<F>(
+        &mut self,
+        data: &[u8],
+        bytes_per_pixel: usize,
+        convert_row: F,
+    ) -> Result<(), Error>
+    where
+        F: Fn(&[u8], &mut [u8]),
+    {
+        let Self {
+            width,
+            height,
+            stride,
+            ..
+        } = *self;
+        let row_len = width * bytes_per_pixel;
+
+        // The last row may be not padded.
+        let expect_len = stride * (height - 1) + row_len;
+        if data.len() < expect_len {
+            return Err(Error::ConvertFrameError {
+                reason: format!(
+                    "expect {expect_len} bytes for a {width}x{height} {} frame, but get {}",
+                    self.format,
+                    data.len()
+                ),
+            });
+        }
+
+        let image_stride = self.image.stride();
+        data.chunks(stride)
+            .take(height)
+            .zip(self.image.as_slice_mut().chunks_mut(image_stride))
+            .for_each(|(src, dst)| convert_row(&src[..row_len], &mut dst[..width]));
+        Ok(())
+    }
+
+    fn convert_jpeg(&mut self, data: &[u8]) -> Result<(), Error> {
+        let options = DecoderOptions::default().jpeg_set_out_colorspace(ColorSpace::Luma);
+        let mut decoder = JpegDecoder::new_with_options(ZCursor::new(data), options);
+        decoder
+            .decode_headers()
+            .map_err(|err| Error::DecodeJpegError {
+                reason: err.to_string(),
+            })?;
+
+        let info = decoder.info().unwrap();
+        let (width, height) = (info.width as usize, info.height as usize);
+        if (width, height) != (self.width, self.height) {
+            return Err(Error::DecodeJpegError {
+                reason: format!(
+                    "expect a {}x{} frame, but get {width}x{height}",
+                    self.width, self.height
+                ),
+            });
+        }
+
+        self.scratch
+            .resize(decoder.output_buffer_size().unwrap(), 0);
+        decoder
+            .decode_into(&mut self.scratch)
+            .map_err(|err| Error::DecodeJpegError {
+                reason: err.to_string(),
+            })?;
+
+        let image_stride = self.image.stride();
+        self.scratch
+            .chunks_exact(width)
+            .zip(self.image.as_slice_mut().chunks_mut(image_stride))
+            .for_each(|(src, dst)| dst[..width].copy_from_slice(src));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jpeg_encoder::{ColorType, Encoder};
+
+    fn pixels(image: &Image) -> Vec<u8> {
+        (0..image.height())
+            .flat_map(|y| (0..image.width()).map(move |x| (x, y)))
+            .map(|index| image[index])
+            .collect()
+    }
+
+    #[test]
+    fn parse_pixel_formats() {
+        assert_eq!("yuyv".parse::<PixelFormat>().unwrap(), PixelFormat::Yuyv);
+        assert_eq!("MJPG".parse::<PixelFormat>().unwrap(), PixelFormat::Mjpeg);
+        assert_eq!(PixelFormat::Grey.to_string(), "GREY");
+        assert!("NV12".parse::<PixelFormat>().is_err());
+        assert!("RGB".parse::<PixelFormat>().is_err());
+    }
+
+    #[test]
+    fn convert_packed_frames() {
+        // The padding bytes at the end of each row are skipped.
+        let mut converter = FrameConverter::new(PixelFormat::Yuyv, 2, 2, 6).unwrap();
+        let data = [10, 128, 20, 128, 0, 0, 30, 128, 40, 128];
+        let image = converter.convert(&data).unwrap();
+        assert_eq!(pixels(image), [10, 20, 30, 40]);
+
+        // The buffer is reused for the next frame.
+        let data = [50, 128, 60, 128, 0, 0, 70, 128, 80, 128, 0, 0];
+        let image = converter.convert(&data).unwrap();
+        assert_eq!(pixels(image), [50, 60, 70, 80]);
+
+        let mut converter = FrameConverter::new(PixelFormat::Uyvy, 2, 1, 0).unwrap();
+        let image = converter.convert(&[128, 10, 128, 20]).unwrap();
+        assert_eq!(pixels(image), [10, 20]);
+
+        let mut converter = FrameConverter::new(PixelFormat::Grey, 2, 2, 0).unwrap();
+        assert!(converter.convert(&[1, 2, 3]).is_err());
+        assert!(FrameConverter::new(PixelFormat::Yuyv, 4, 4, 6).is_err());
+    }
+
+    #[test]
+    fn decode_mjpeg_frames() {
+        let (width, height) = (16, 8);
+        let luma: Vec<u8> = (0..width * height)
+            .map(|index| if index % width < width / 2 { 32 } else { 224 })
+            .collect();
+        let mut jpeg = vec![];
+        Encoder::new(&mut jpeg, 100)
+            .encode(&luma, width as u16, height as u16, ColorType::Luma)
+            .unwrap();
+
+        let mut converter = FrameConverter::new(PixelFormat::Mjpeg, width, height, 0).unwrap();
+        let image = converter.convert(&jpeg).unwrap();
+        pixels(image)
+            .iter()
+            .zip(&luma)
+            .for_each(|(actual, expect)| assert!(actual.abs_diff(*expect) <= 4));
+
+        let mut converter = FrameConverter::new(PixelFormat::Mjpeg, 8, 8, 0).unwrap();
+        assert!(matches!(
+            converter.convert(&jpeg),
+            Err(Error::DecodeJpegError { .. })
+        ));
+        assert!(converter.convert(&[0xff, 0xd8, 0x00]).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! Live tag detection on Video4Linux cameras.
+//!
+//! [Capture] streams frames from a `/dev/video*` device through
+//! memory-mapped buffers and converts them to grayscale [Image]s, which
+//! are fed to [Detector::detect]. `GREY`, `YUYV`, `UYVY` and `MJPG`
+//! frames are supported. The conversion is available in
+//! [FrameConverter] without the `capture` feature, for frames obtained
+//! elsewhere.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use apriltag::DetectorConfig;
+//! use apriltag_v4l::{Capture, CaptureParams};
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let mut capture = Capture::open(&CaptureParams::default())?;
+//!     let mut detector = DetectorConfig::default().build()?;
+//!
+//!     loop {
+//!         let frame = capture.next_frame()?;
+//!         for detection in detector.detect(frame.image) {
+//!             println!("{} {}", frame.sequence, detection.id());
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [Image]: apriltag::Image
+//! [Detector::detect]: apriltag::Detector::detect
+
+#[cfg(feature = "capture")]
+pub mod capture;
+pub mod error;
+pub mod frame;
+
+#[cfg(feature = "capture")]
+pub use capture::{Capture, CaptureParams, Frame};
+pub use error::Error;
+pub use frame::{FrameConverter, PixelFormat};
+#[cfg(feature = "capture")]
+pub use v4l;
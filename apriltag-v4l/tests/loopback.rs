@@ -0,0 +1,37 @@
+//! Capture from a v4l2loopback device fed with tag36h11 tags. Read the
+//! README to set up the device.
+
+#![cfg(feature = "capture")]
+
+use apriltag::DetectorConfig;
+use apriltag_v4l::{Capture, CaptureParams};
+use std::{env, path::PathBuf, time::Duration};
+
+#[test]
+#[ignore = "requires a v4l2loopback device fed with tag36h11 tags"]
+fn detect_on_loopback_device() {
+    let device = env::var_os("APRILTAG_V4L_DEVICE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/dev/video10"));
+    let mut capture = Capture::open(&CaptureParams {
+        device,
+        ..Default::default()
+    })
+    .unwrap();
+    capture.set_timeout(Duration::from_secs(5));
+    let size = (capture.width(), capture.height());
+    let mut detector = DetectorConfig::default().build().unwrap();
+
+    let mut sequence = None;
+    let mut detected = 0;
+    for _ in 0..10 {
+        let frame = capture.next_frame().unwrap();
+        assert_eq!((frame.image.width(), frame.image.height()), size);
+        if let Some(previous) = sequence {
+            assert!(frame.sequence > previous);
+        }
+        sequence = Some(frame.sequence);
+        detected += detector.detect(frame.image).len();
+    }
+    assert!(detected > 0);
+}
@@ -0,0 +1,69 @@
+use anyhow::{ensure, Result};
+use apriltag::{Detector, FamilyKind};
+use apriltag_video::{AnnotatedWriter, FrameResult, VideoReader};
+use clap::Parser;
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// Detect tags on a video file and print a JSON line per frame.
+#[derive(Debug, Clone, Parser)]
+struct Opts {
+    /// The tag family.
+    #[clap(long, default_value = "tag36h11")]
+    pub family: FamilyKind,
+
+    /// Write the annotated video to the path. The container is chosen
+    /// by the mp4, mkv or webm extension.
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// The input video.
+    pub input: PathBuf,
+}
+
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    let mut reader = VideoReader::open(&opts.input)?;
+    let mut detector = Detector::builder()
+        .add_family_bits(opts.family, 1)
+        .build()?;
+    let mut writer: Option<AnnotatedWriter> = None;
+    let mut stdout = io::stdout().lock();
+    let mut frames = 0;
+
+    while let Some(frame) = reader.next_frame()? {
+        frames += 1;
+        let result = FrameResult::detect(&mut detector, &frame);
+        serde_json::to_writer(&mut stdout, &result)?;
+        writeln!(stdout)?;
+
+        let Some(path) = &opts.output else {
+            continue;
+        };
+        let writer = match &mut writer {
+            Some(writer) => writer,
+            None => {
+                // Variable frame rate streams are written at 30 fps.
+                let framerate = match frame.framerate {
+                    (numer, denom) if numer > 0 && denom > 0 => (numer, denom),
+                    _ => (30, 1),
+                };
+                writer.insert(AnnotatedWriter::create(
+                    path,
+                    frame.image.width(),
+                    frame.image.height(),
+                    framerate,
+                )?)
+            }
+        };
+        writer.write(frame.image, &result.detections, frame.timestamp)?;
+    }
+
+    if let Some(writer) = writer {
+        writer.finish()?;
+    }
+    ensure!(frames > 0, "no video frames are decoded");
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Defines the error type for the crate.
+
+/// The error type for the crate.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to initialize GStreamer: {reason}")]
+    InitError { reason: String },
+
+    #[error("Unable to open the video: {reason}")]
+    OpenVideoError { reason: String },
+
+    #[error("Unable to decode the video: {reason}")]
+    DecodeVideoError { reason: String },
+
+    #[error("Unable to encode the video: {reason}")]
+    EncodeVideoError { reason: String },
+
+    #[error(transparent)]
+    Apriltag(#[from] apriltag::Error),
+}
@@ -0,0 +1,81 @@
+//! Tag detection on video files decoded by GStreamer.
+//!
+//! [VideoReader] decodes a video file, such as an MP4 or Matroska
+//! recording, frame by frame into grayscale [Image]s. The detections of
+//! each frame are collected into a [FrameResult] with the frame time
+//! stamp, and [AnnotatedWriter] writes the frames with the detections
+//! drawn on them to another video.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use apriltag::DetectorConfig;
+//! use apriltag_video::{AnnotatedWriter, FrameResult, VideoReader};
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let mut reader = VideoReader::open("flight.mp4")?;
+//!     let mut detector = DetectorConfig::default().build()?;
+//!     let mut writer = None;
+//!
+//!     while let Some(frame) = reader.next_frame()? {
+//!         let result = FrameResult::detect(&mut detector, &frame);
+//!         println!("{}", serde_json::to_string(&result)?);
+//!
+//!         let writer = match &mut writer {
+//!             Some(writer) => writer,
+//!             None => writer.insert(AnnotatedWriter::create(
+//!                 "annotated.mp4",
+//!                 frame.image.width(),
+//!                 frame.image.height(),
+//!                 frame.framerate,
+//!             )?),
+//!         };
+//!         writer.write(frame.image, &result.detections, frame.timestamp)?;
+//!     }
+//!
+//!     if let Some(writer) = writer {
+//!         writer.finish()?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [Image]: apriltag::Image
+
+pub mod error;
+mod pipeline;
+pub mod reader;
+pub mod writer;
+
+pub use error::Error;
+pub use reader::{VideoFrame, VideoReader};
+pub use writer::AnnotatedWriter;
+
+use apriltag::{DetectionRecord, Detector};
+use serde::{Deserialize, Serialize};
+
+/// The detections on a video frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameResult {
+    /// The zero-based frame index.
+    pub index: usize,
+    /// The presentation time stamp in seconds.
+    pub timestamp: Option<f64>,
+    /// The detections on the frame.
+    pub detections: Vec<DetectionRecord>,
+}
+
+impl FrameResult {
+    /// Run the detector on the frame.
+    pub fn detect(detector: &mut Detector, frame: &VideoFrame) -> Self {
+        Self {
+            index: frame.index,
+            timestamp: frame.timestamp.map(|timestamp| timestamp.as_secs_f64()),
+            detections: detector
+                .detect(frame.image)
+                .iter()
+                .map(|detection| detection.to_record())
+                .collect(),
+        }
+    }
+}
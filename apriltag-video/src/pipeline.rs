@@ -0,0 +1,31 @@
+//! Helpers shared by the GStreamer pipelines.
+
+use crate::error::Error;
+use gstreamer as gst;
+use gstreamer::prelude::*;
+
+/// Initialize GStreamer. It is cheap to call repeatedly.
+pub(crate) fn init() -> Result<(), Error> {
+    gst::init().map_err(|err| Error::InitError {
+        reason: err.to_string(),
+    })
+}
+
+/// Create an element, which fails if its plugin is not installed.
+pub(crate) fn make_element(factory: &str) -> Result<gst::Element, String> {
+    gst::ElementFactory::make(factory)
+        .build()
+        .map_err(|_| format!("the '{factory}' element is not available"))
+}
+
+/// Take the first error message posted on the bus of the pipeline.
+pub(crate) fn take_error(pipeline: &gst::Pipeline) -> Option<String> {
+    let message = pipeline.bus()?.pop_filtered(&[gst::MessageType::Error])?;
+    match message.view() {
+        gst::MessageView::Error(err) => Some(match err.debug() {
+            Some(debug) => format!("{} ({debug})", err.error()),
+            None => err.error().to_string(),
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,188 @@
+//! Decoding of video files into grayscale images.
+
+use crate::{error::Error, pipeline};
+use apriltag::{image_buf::DEFAULT_ALIGNMENT_U8, Image};
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+use std::{path::Path, time::Duration};
+
+/// A decoded frame.
+#[derive(Debug)]
+pub struct VideoFrame<'a> {
+    /// The zero-based frame index.
+    pub index: usize,
+    /// The presentation time stamp from the start of the video.
+    pub timestamp: Option<Duration>,
+    /// The nominal frame rate as a fraction. It is zero for variable
+    /// frame rate streams.
+    pub framerate: (i32, i32),
+    /// The grayscale image, which is overwritten by the next frame.
+    pub image: &'a Image,
+}
+
+/// Decodes the first video stream of a file frame by frame.
+///
+/// Any container and codec supported by the installed GStreamer plugins
+/// can be read, such as MP4 and Matroska files with H.264 streams. The
+/// frames are converted to grayscale by GStreamer and copied into a
+/// reused [Image].
+pub struct VideoReader {
+    pipeline: gst::Pipeline,
+    sink: gst_app::AppSink,
+    image: Option<Image>,
+    index: usize,
+}
+
+impl VideoReader {
+    /// Open a video file and start decoding.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        pipeline::init()?;
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Err(Error::OpenVideoError {
+                reason: format!("'{}' is not a file", path.display()),
+            });
+        }
+        let location = path.to_str().ok_or_else(|| Error::OpenVideoError {
+            reason: format!("'{}' is not a valid UTF-8 path", path.display()),
+        })?;
+
+        let open_error = |reason: String| Error::OpenVideoError { reason };
+        let src = pipeline::make_element("filesrc").map_err(open_error)?;
+        src.set_property("location", location);
+        let decode = pipeline::make_element("decodebin").map_err(open_error)?;
+        let convert = pipeline::make_element("videoconvert").map_err(open_error)?;
+        let sink = gst_app::AppSink::builder()
+            .caps(
+                &gst_video::VideoCapsBuilder::new()
+                    .format(gst_video::VideoFormat::Gray8)
+                    .build(),
+            )
+            // Decode as fast as possible instead of in real time.
+            .sync(false)
+            .max_buffers(4)
+            .build();
+
+        let pipeline = gst::Pipeline::new();
+        pipeline
+            .add_many([&src, &decode, &convert, sink.upcast_ref()])
+            .and_then(|_| src.link(&decode))
+            .and_then(|_| convert.link(&sink))
+            .map_err(|err| Error::OpenVideoError {
+                reason: err.to_string(),
+            })?;
+
+        // The decoder creates a pad per stream once the container is
+        // parsed. Only the first video stream is linked.
+        let convert = convert.downgrade();
+        decode.connect_pad_added(move |_, pad| {
+            let Some(convert) = convert.upgrade() else {
+                return;
+            };
+            let sink_pad = convert.static_pad("sink").unwrap();
+            let is_video = pad
+                .current_caps()
+                .and_then(|caps| {
+                    caps.structure(0)
+                        .map(|structure| structure.name().starts_with("video/"))
+                })
+                .unwrap_or(false);
+            if is_video && !sink_pad.is_linked() {
+                let _ = pad.link(&sink_pad);
+            }
+        });
+
+        pipeline
+            .set_state(gst::State::Playing)
+            .map_err(|_| Error::OpenVideoError {
+                reason: pipeline::take_error(&pipeline)
+                    .unwrap_or_else(|| format!("failed to start decoding '{}'", path.display())),
+            })?;
+
+        Ok(Self {
+            pipeline,
+            sink,
+            image: None,
+            index: 0,
+        })
+    }
+
+    /// Decode the next frame. It returns `None` at the end of the video.
+    pub fn next_frame(&mut self) -> Result<Option<VideoFrame<'_>>, Error> {
+        let sample = match self.sink.pull_sample() {
+            Ok(sample) => sample,
+            Err(_) if self.sink.is_eos() => return Ok(None),
+            Err(_) => {
+                return Err(Error::DecodeVideoError {
+                    reason: pipeline::take_error(&self.pipeline)
+                        .unwrap_or_else(|| "the pipeline stopped unexpectedly".to_string()),
+                })
+            }
+        };
+        let decode_error = |reason: &str| Error::DecodeVideoError {
+            reason: reason.to_string(),
+        };
+
+        let info = sample
+            .caps()
+            .and_then(|caps| gst_video::VideoInfo::from_caps(caps).ok())
+            .ok_or_else(|| decode_error("the frame has no valid caps"))?;
+        let buffer = sample
+            .buffer()
+            .ok_or_else(|| decode_error("the sample has no buffer"))?;
+        let map = buffer
+            .map_readable()
+            .map_err(|_| decode_error("failed to map the frame buffer"))?;
+
+        let width = info.width() as usize;
+        let height = info.height() as usize;
+        let stride = info.stride()[0] as usize;
+        let offset = info.offset()[0];
+        if map.len() < offset + stride * height.saturating_sub(1) + width {
+            return Err(decode_error("the frame buffer is too small"));
+        }
+
+        let reuse = self
+            .image
+            .as_ref()
+            .is_some_and(|image| (image.width(), image.height()) == (width, height));
+        if !reuse {
+            self.image = Some(Image::zeros_with_alignment(
+                width,
+                height,
+                DEFAULT_ALIGNMENT_U8,
+            )?);
+        }
+        let image = self.image.as_mut().unwrap();
+        let image_stride = image.stride();
+        if width > 0 {
+            map[offset..]
+                .chunks(stride)
+                .take(height)
+                .zip(image.as_slice_mut().chunks_mut(image_stride))
+                .for_each(|(src, dst)| dst[..width].copy_from_slice(&src[..width]));
+        }
+
+        let fps = info.fps();
+        let index = self.index;
+        self.index += 1;
+
+        Ok(Some(VideoFrame {
+            index,
+            timestamp: buffer.pts().map(|pts| Duration::from_nanos(pts.nseconds())),
+            framerate: (fps.numer(), fps.denom()),
+            image,
+        }))
+    }
+}
+
+impl Drop for VideoReader {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
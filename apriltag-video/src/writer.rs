@@ -0,0 +1,206 @@
+//! Encoding of annotated videos.
+
+use crate::{error::Error, pipeline};
+use apriltag::{DetectionRecord, Image};
+use apriltag_image::{
+    draw_detections,
+    image::{Rgb, RgbImage},
+};
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+use std::{path::Path, time::Duration};
+
+/// Writes frames annotated with detections to a video file.
+///
+/// The container is chosen by the file extension. `.mp4` and `.mkv`
+/// files are encoded in H.264 by `x264enc`, and `.webm` files in VP8 by
+/// `vp8enc`.
+pub struct AnnotatedWriter {
+    pipeline: gst::Pipeline,
+    src: gst_app::AppSrc,
+    info: gst_video::VideoInfo,
+    canvas: RgbImage,
+    frame_duration: Option<gst::ClockTime>,
+    frames: u64,
+}
+
+impl AnnotatedWriter {
+    /// Create a video file for frames of the size and frame rate.
+    ///
+    /// The frame rate is a fraction such as `(30, 1)` or `(30000, 1001)`.
+    pub fn create<P>(
+        path: P,
+        width: usize,
+        height: usize,
+        framerate: (i32, i32),
+    ) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        pipeline::init()?;
+        let path = path.as_ref();
+        let location = path.to_str().ok_or_else(|| Error::EncodeVideoError {
+            reason: format!("'{}' is not a valid UTF-8 path", path.display()),
+        })?;
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        let (encoder, muxer) = match extension.as_deref() {
+            Some("mp4") => ("x264enc", "mp4mux"),
+            Some("mkv") => ("x264enc", "matroskamux"),
+            Some("webm") => ("vp8enc", "webmmux"),
+            _ => {
+                return Err(Error::EncodeVideoError {
+                    reason: format!(
+                        "'{}' must have the mp4, mkv or webm extension",
+                        path.display()
+                    ),
+                })
+            }
+        };
+
+        let (numer, denom) = framerate;
+        let info =
+            gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgb, width as u32, height as u32)
+                .fps(gst::Fraction::new(numer, denom))
+                .build()
+                .map_err(|err| Error::EncodeVideoError {
+                    reason: err.to_string(),
+                })?;
+        let caps = info.to_caps().map_err(|err| Error::EncodeVideoError {
+            reason: err.to_string(),
+        })?;
+
+        let encode_error = |reason: String| Error::EncodeVideoError { reason };
+        let src = gst_app::AppSrc::builder()
+            .caps(&caps)
+            .format(gst::Format::Time)
+            .build();
+        let convert = pipeline::make_element("videoconvert").map_err(encode_error)?;
+        let encoder = pipeline::make_element(encoder).map_err(encode_error)?;
+        let muxer = pipeline::make_element(muxer).map_err(encode_error)?;
+        let sink = pipeline::make_element("filesink").map_err(encode_error)?;
+        sink.set_property("location", location);
+
+        let pipeline = gst::Pipeline::new();
+        let elements = [src.upcast_ref(), &convert, &encoder, &muxer, &sink];
+        pipeline
+            .add_many(elements)
+            .and_then(|_| gst::Element::link_many(elements))
+            .map_err(|err| Error::EncodeVideoError {
+                reason: err.to_string(),
+            })?;
+        pipeline
+            .set_state(gst::State::Playing)
+            .map_err(|_| Error::EncodeVideoError {
+                reason: pipeline::take_error(&pipeline)
+                    .unwrap_or_else(|| format!("failed to start encoding '{}'", path.display())),
+            })?;
+
+        let frame_duration = (numer > 0 && denom > 0).then(|| {
+            gst::ClockTime::SECOND
+                .mul_div_floor(denom as u64, numer as u64)
+                .unwrap()
+        });
+
+        Ok(Self {
+            pipeline,
+            src,
+            info,
+            canvas: RgbImage::new(width as u32, height as u32),
+            frame_duration,
+            frames: 0,
+        })
+    }
+
+    /// Write a frame with the detections drawn on it.
+    ///
+    /// Frames are placed at the time stamp if given, or else after the
+    /// previous frame by the frame rate.
+    pub fn write(
+        &mut self,
+        image: &Image,
+        detections: &[DetectionRecord],
+        timestamp: Option<Duration>,
+    ) -> Result<(), Error> {
+        let (width, height) = self.canvas.dimensions();
+        if (image.width(), image.height()) != (width as usize, height as usize) {
+            return Err(Error::EncodeVideoError {
+                reason: format!(
+                    "expect a {width}x{height} frame, but get {}x{}",
+                    image.width(),
+                    image.height()
+                ),
+            });
+        }
+
+        for (x, y, pixel) in self.canvas.enumerate_pixels_mut() {
+            let luma = image[(x as usize, y as usize)];
+            *pixel = Rgb([luma, luma, luma]);
+        }
+        draw_detections(&mut self.canvas, detections);
+
+        let mut buffer =
+            gst::Buffer::with_size(self.info.size()).map_err(|err| Error::EncodeVideoError {
+                reason: err.to_string(),
+            })?;
+        {
+            let buffer = buffer.get_mut().unwrap();
+            let pts = match timestamp {
+                Some(timestamp) => Some(gst::ClockTime::from_nseconds(timestamp.as_nanos() as u64)),
+                None => self.frame_duration.map(|duration| duration * self.frames),
+            };
+            buffer.set_pts(pts);
+            buffer.set_duration(self.frame_duration);
+
+            let stride = self.info.stride()[0] as usize;
+            let offset = self.info.offset()[0];
+            let row_len = width as usize * 3;
+            let mut map = buffer.map_writable().map_err(|_| Error::EncodeVideoError {
+                reason: "failed to map the frame buffer".to_string(),
+            })?;
+            map[offset..]
+                .chunks_mut(stride)
+                .zip(self.canvas.as_raw().chunks_exact(row_len))
+                .for_each(|(dst, src)| dst[..row_len].copy_from_slice(src));
+        }
+
+        self.src
+            .push_buffer(buffer)
+            .map_err(|err| Error::EncodeVideoError {
+                reason: pipeline::take_error(&self.pipeline).unwrap_or_else(|| err.to_string()),
+            })?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Flush the remaining frames and finalize the file.
+    pub fn finish(self) -> Result<(), Error> {
+        self.src
+            .end_of_stream()
+            .map_err(|err| Error::EncodeVideoError {
+                reason: err.to_string(),
+            })?;
+
+        let bus = self.pipeline.bus().unwrap();
+        let message = bus.timed_pop_filtered(
+            gst::ClockTime::NONE,
+            &[gst::MessageType::Eos, gst::MessageType::Error],
+        );
+        if let Some(gst::MessageView::Error(err)) = message.as_ref().map(|message| message.view()) {
+            return Err(Error::EncodeVideoError {
+                reason: err.error().to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AnnotatedWriter {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
@@ -0,0 +1,53 @@
+//! Writes a synthetic video and detects tags on it. It requires the
+//! GStreamer base, good and ugly plugins.
+
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    DetectorConfig, Family, PoseRecord,
+};
+use apriltag_video::{AnnotatedWriter, FrameResult, VideoReader};
+use std::{env, fs, time::Duration};
+
+#[test]
+fn write_and_detect_video() {
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let path = env::temp_dir().join(format!("apriltag-video-{}.mkv", std::process::id()));
+
+    let mut writer = AnnotatedWriter::create(&path, camera.width, camera.height, (10, 1)).unwrap();
+    for index in 0..10 {
+        let tag = SyntheticTag {
+            family: &family,
+            id: 5,
+            size: 0.1,
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(0.1, 0.0, index as f64 * 0.05),
+                translation: [index as f64 * 0.005, 0.0, 0.4],
+            },
+        };
+        let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+        writer.write(&scene.image, &[], None).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let mut reader = VideoReader::open(&path).unwrap();
+    let mut detector = DetectorConfig::default().build().unwrap();
+    let mut results = vec![];
+    while let Some(frame) = reader.next_frame().unwrap() {
+        assert_eq!(frame.image.width(), camera.width);
+        assert_eq!(frame.image.height(), camera.height);
+        assert_eq!(frame.framerate, (10, 1));
+        results.push(FrameResult::detect(&mut detector, &frame));
+    }
+    drop(reader);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(results.len(), 10);
+    for (index, result) in results.iter().enumerate() {
+        assert_eq!(result.index, index);
+        let expect = Duration::from_millis(index as u64 * 100).as_secs_f64();
+        assert!((result.timestamp.unwrap() - expect).abs() < 1e-3);
+        assert_eq!(result.detections.len(), 1);
+        assert_eq!(result.detections[0].id, 5);
+    }
+}
@@ -0,0 +1,117 @@
+//! WebAssembly bindings for in-browser AprilTag detection.
+//!
+//! The crate builds the [apriltag] crate with the `pure-rust` backend,
+//! so that no C toolchain is needed for the `wasm32-unknown-unknown`
+//! target. [TagDetector] is exported to JavaScript by wasm-bindgen.
+//!
+//! # Example
+//!
+//! ```js
+//! import init, { TagDetector } from "./pkg/apriltag_wasm.js";
+//!
+//! await init();
+//! const detector = new TagDetector({
+//!   families: [{ family: "tag36h11", bits_corrected: 1 }],
+//!   tag_params: { tagsize: 0.16, fx: 600, fy: 600, cx: 320, cy: 240 },
+//! });
+//!
+//! const context = canvas.getContext("2d");
+//! const imageData = context.getImageData(0, 0, canvas.width, canvas.height);
+//! for (const tag of detector.detect(imageData)) {
+//!   console.log(tag.family, tag.id, tag.corners, tag.pose);
+//! }
+//! ```
+
+mod rgba;
+
+pub use crate::rgba::rgba_to_image;
+pub use apriltag::{self, DetectorConfig, FamilyConfig};
+
+use apriltag::{error::Error, DetectionRecord, Detector, Image, PoseRecord, TagParams};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use web_sys::ImageData;
+
+/// A detected tag in the form returned to JavaScript.
+///
+/// The fields of [DetectionRecord] are flattened into the object. The
+/// `pose` field is present if the detector is configured with
+/// `tag_params`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TagDetection {
+    #[serde(flatten)]
+    pub detection: DetectionRecord,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pose: Option<PoseRecord>,
+}
+
+/// The tag detector exported to JavaScript.
+#[wasm_bindgen]
+pub struct TagDetector {
+    detector: Detector,
+    tag_params: Option<TagParams>,
+}
+
+#[wasm_bindgen]
+impl TagDetector {
+    /// Create a detector from a configuration object, or with the
+    /// default configuration if it is `undefined` or `null`.
+    ///
+    /// The object follows the fields of [DetectorConfig].
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: JsValue) -> Result<TagDetector, JsError> {
+        let config: DetectorConfig = if config.is_undefined() || config.is_null() {
+            DetectorConfig::default()
+        } else {
+            serde_wasm_bindgen::from_value(config)?
+        };
+        Ok(Self::with_config(&config)?)
+    }
+
+    /// Detect tags on an `ImageData`, such as the one obtained from a
+    /// canvas, and return an array of detection objects.
+    pub fn detect(&mut self, image: &ImageData) -> Result<JsValue, JsError> {
+        self.detect_rgba(&image.data(), image.width(), image.height())
+    }
+
+    /// Detect tags on a tightly packed RGBA buffer and return an array
+    /// of detection objects.
+    #[wasm_bindgen(js_name = detectRgba)]
+    pub fn detect_rgba(
+        &mut self,
+        data: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Result<JsValue, JsError> {
+        let image = rgba_to_image(data, width as usize, height as usize)?;
+        let detections = self.detect_image(&image);
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        Ok(detections.serialize(&serializer)?)
+    }
+}
+
+impl TagDetector {
+    /// Create a detector with the configuration.
+    pub fn with_config(config: &DetectorConfig) -> Result<Self, Error> {
+        Ok(Self {
+            detector: config.build()?,
+            tag_params: config.tag_params.clone(),
+        })
+    }
+
+    /// Detect tags on a grayscale image.
+    pub fn detect_image(&mut self, image: &Image) -> Vec<TagDetection> {
+        self.detector
+            .detect(image)
+            .into_iter()
+            .map(|detection| TagDetection {
+                detection: detection.to_record(),
+                pose: self
+                    .tag_params
+                    .as_ref()
+                    .and_then(|params| Some(detection.estimate_tag_pose(params)?.to_record())),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+//! Conversion from RGBA pixel buffers to grayscale images.
+
+use apriltag::{error::Error, image_buf::DEFAULT_ALIGNMENT_U8, Image};
+
+/// Convert a tightly packed RGBA buffer, as stored in `ImageData`, to a
+/// grayscale image.
+///
+/// The luma is computed with the Rec. 709 coefficients, the same as
+/// `to_luma8()` in the image crate. The alpha channel is ignored.
+pub fn rgba_to_image(data: &[u8], width: usize, height: usize) -> Result<Image, Error> {
+    let expect_len = width
+        .checked_mul(height)
+        .and_then(|len| len.checked_mul(4))
+        .ok_or_else(|| Error::CreateImageError {
+            reason: format!("image size {width}x{height} is too large"),
+        })?;
+    if data.len() != expect_len {
+        return Err(Error::CreateImageError {
+            reason: format!(
+                "expect {expect_len} bytes for a {width}x{height} RGBA image, but get {}",
+                data.len()
+            ),
+        });
+    }
+
+    let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+    let stride = image.stride();
+    if width == 0 {
+        return Ok(image);
+    }
+
+    data.chunks_exact(width * 4)
+        .zip(image.as_slice_mut().chunks_mut(stride))
+        .for_each(|(src, dst)| {
+            src.chunks_exact(4).zip(dst).for_each(|(pixel, luma)| {
+                *luma = rgb_to_luma(pixel[0], pixel[1], pixel[2]);
+            });
+        });
+
+    Ok(image)
+}
+
+fn rgb_to_luma(r: u8, g: u8, b: u8) -> u8 {
+    let luma = 2126 * r as u32 + 7152 * g as u32 + 722 * b as u32;
+    ((luma + 5000) / 10000) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_rgba_to_luma() {
+        let width = 3;
+        let height = 2;
+        #[rustfmt::skip]
+        let data = [
+            0, 0, 0, 255,       255, 255, 255, 0,   255, 0, 0, 255,
+            0, 255, 0, 255,     0, 0, 255, 255,     128, 128, 128, 255,
+        ];
+
+        let image = rgba_to_image(&data, width, height).unwrap();
+        assert_eq!(image.width(), width);
+        assert_eq!(image.height(), height);
+        assert_eq!(image[(0, 0)], 0);
+        assert_eq!(image[(1, 0)], 255);
+        assert_eq!(image[(2, 0)], 54);
+        assert_eq!(image[(0, 1)], 182);
+        assert_eq!(image[(1, 1)], 18);
+        assert_eq!(image[(2, 1)], 128);
+    }
+
+    #[test]
+    fn reject_mismatched_buffer_size() {
+        assert!(rgba_to_image(&[0; 15], 2, 2).is_err());
+        assert!(rgba_to_image(&[0; 16], 2, 2).is_ok());
+    }
+}
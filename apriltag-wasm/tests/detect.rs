@@ -0,0 +1,53 @@
+use apriltag::{
+    synthetic::{self, Camera, Effects, SyntheticTag},
+    Family, FamilyKind, PoseRecord,
+};
+use apriltag_wasm::{rgba_to_image, DetectorConfig, FamilyConfig, TagDetector};
+
+#[test]
+fn detect_rgba_scene() {
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let tag = SyntheticTag {
+        family: &family,
+        id: 7,
+        size: 0.1,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.2, -0.3, 0.5),
+            translation: [0.01, 0.0, 0.4],
+        },
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+
+    // Expand the gray scene to RGBA as a canvas would provide it.
+    let rgba: Vec<u8> = scene
+        .image
+        .samples_iter()
+        .flat_map(|value| [value, value, value, 255])
+        .collect();
+    let image = rgba_to_image(&rgba, camera.width, camera.height).unwrap();
+
+    let config = DetectorConfig {
+        families: vec![FamilyConfig {
+            family: FamilyKind::Tag36h11,
+            bits_corrected: 1,
+        }],
+        tag_params: Some(camera.tag_params(0.1)),
+        ..DetectorConfig::default()
+    };
+    let mut detector = TagDetector::with_config(&config).unwrap();
+    let detections = detector.detect_image(&image);
+
+    assert_eq!(detections.len(), 1);
+    let detection = &detections[0];
+    assert_eq!(detection.detection.id, 7);
+    assert_eq!(detection.detection.family, "tag36h11");
+    let pose = detection.pose.as_ref().unwrap();
+    assert!((pose.translation[2] - 0.4).abs() < 0.01);
+
+    // The pose is flattened next to the detection fields.
+    let value = serde_json::to_value(&detections).unwrap();
+    assert_eq!(value[0]["id"], 7);
+    assert!(value[0]["decision_margin"].is_number());
+    assert!(value[0]["pose"]["rotation"].is_array());
+}
@@ -0,0 +1,188 @@
+//! Parallel detection over many images.
+//!
+//! [BatchDetector] distributes the images over a [rayon] thread pool.
+//! Each worker borrows a [Detector] from a shared pool, so that frames
+//! are processed in parallel rather than splitting a single frame
+//! across threads. The results are returned in input order.
+//!
+//! ```rust,no_run
+//! use apriltag::{batch::BatchDetector, Detector, Family, Image};
+//!
+//! let paths = vec!["frame0.pnm".to_string(), "frame1.pnm".to_string()];
+//! let batch = BatchDetector::new(|| {
+//!     Detector::builder()
+//!         .add_family_bits(Family::tag_36h11(), 1)
+//!         .build()
+//! })
+//! .on_progress(|progress| {
+//!     eprintln!("{}/{}", progress.completed, progress.total);
+//! });
+//!
+//! let results = batch.detect_with(&paths, |path| Image::from_pnm_file(path));
+//! for (path, result) in paths.iter().zip(results) {
+//!     match result {
+//!         Ok(detections) => println!("{path}: {} tags", detections.len()),
+//!         Err(err) => eprintln!("{path}: {err}"),
+//!     }
+//! }
+//! ```
+
+use crate::{detection::Detection, detector::Detector, error::Error, image_buf::Image};
+use rayon::prelude::*;
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+type DetectorFactory = dyn Fn() -> Result<Detector, Error> + Send + Sync;
+type ProgressCallback = dyn Fn(BatchProgress) + Send + Sync;
+
+/// The progress reported after each image is processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// The index of the processed image in the input.
+    pub index: usize,
+
+    /// The number of images processed so far.
+    pub completed: usize,
+
+    /// The total number of images.
+    pub total: usize,
+}
+
+/// Runs detection on many images in parallel.
+///
+/// See the [module](self) documentation for an example.
+pub struct BatchDetector {
+    make_detector: Box<DetectorFactory>,
+    num_threads: Option<usize>,
+    thread_pool: Option<Result<rayon::ThreadPool, Error>>,
+    progress: Option<Box<ProgressCallback>>,
+    pool: Mutex<Vec<Detector>>,
+}
+
+impl BatchDetector {
+    /// Create a batch detector.
+    ///
+    /// The `make_detector` is called whenever a worker needs a new
+    /// detector. The detectors are kept and reused in later batches.
+    pub fn new<F>(make_detector: F) -> Self
+    where
+        F: Fn() -> Result<Detector, Error> + Send + Sync + 'static,
+    {
+        Self {
+            make_detector: Box::new(make_detector),
+            num_threads: None,
+            thread_pool: None,
+            progress: None,
+            pool: Mutex::new(vec![]),
+        }
+    }
+
+    /// Set the number of worker threads.
+    ///
+    /// By default, it runs on the global rayon thread pool. Otherwise,
+    /// a dedicated thread pool is created here and reused by every
+    /// batch. If the pool cannot be created, each input of a batch
+    /// fails with the error.
+    pub fn num_threads(mut self, num_threads: usize) -> Self {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|err| Error::CreateDetectorError {
+                reason: format!("unable to create the thread pool: {err}"),
+            });
+        self.num_threads = Some(num_threads);
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    /// Set the callback invoked after each image is processed.
+    ///
+    /// The callback is called from worker threads, possibly out of
+    /// input order.
+    pub fn on_progress<P>(mut self, progress: P) -> Self
+    where
+        P: Fn(BatchProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Run detection on the images.
+    ///
+    /// The results are in the same order as the input images.
+    pub fn detect(&self, images: &[Image]) -> Vec<Result<Vec<Detection>, Error>> {
+        self.run(images, |image| self.detect_one(image))
+    }
+
+    /// Load each input using `load` and run detection on it.
+    ///
+    /// The loading and detection errors are captured per input, and
+    /// the results are in the same order as the inputs.
+    pub fn detect_with<T, L>(&self, inputs: &[T], load: L) -> Vec<Result<Vec<Detection>, Error>>
+    where
+        T: Sync,
+        L: Fn(&T) -> Result<Image, Error> + Sync,
+    {
+        self.run(inputs, |input| self.detect_one(&load(input)?))
+    }
+
+    fn run<T, F>(&self, inputs: &[T], f: F) -> Vec<Result<Vec<Detection>, Error>>
+    where
+        T: Sync,
+        F: Fn(&T) -> Result<Vec<Detection>, Error> + Sync,
+    {
+        let total = inputs.len();
+        let completed = AtomicUsize::new(0);
+
+        let job = || {
+            inputs
+                .par_iter()
+                .enumerate()
+                .map(|(index, input)| {
+                    let result = f(input);
+                    if let Some(progress) = &self.progress {
+                        let completed = completed.fetch_add(1, Ordering::SeqCst) + 1;
+                        progress(BatchProgress {
+                            index,
+                            completed,
+                            total,
+                        });
+                    }
+                    result
+                })
+                .collect()
+        };
+
+        match &self.thread_pool {
+            Some(Ok(pool)) => pool.install(job),
+            Some(Err(err)) => inputs.iter().map(|_| Err(err.clone())).collect(),
+            None => job(),
+        }
+    }
+
+    fn detect_one(&self, image: &Image) -> Result<Vec<Detection>, Error> {
+        let detector = self.pool.lock().unwrap().pop();
+        let mut detector = match detector {
+            Some(detector) => detector,
+            None => (self.make_detector)()?,
+        };
+        let detections = detector.detect(image);
+        self.pool.lock().unwrap().push(detector);
+        Ok(detections)
+    }
+}
+
+impl Debug for BatchDetector {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("BatchDetector")
+            .field("num_threads", &self.num_threads)
+            .field("pool_size", &self.pool.lock().unwrap().len())
+            .finish()
+    }
+}
@@ -0,0 +1,247 @@
+//! Pose estimation of tag bundles.
+//!
+//! A [TagBundle] is a rigid arrangement of tags, such as several tags
+//! printed on a board or attached to a robot. Its pose is estimated
+//! from the corners of all visible tags at once, which is more accurate
+//! than the pose of any single tag and works as long as one of the tags
+//! is detected. With the `serde` feature, bundles are loaded from JSON,
+//! where the pose of each tag is given in bundle coordinates.
+//!
+//! ```json
+//! {
+//!   "name": "board",
+//!   "tags": [
+//!     {
+//!       "family": "tag36h11", "id": 0, "size": 0.05,
+//!       "pose": {
+//!         "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+//!         "translation": [-0.04, 0, 0]
+//!       }
+//!     },
+//!     {
+//!       "family": "tag36h11", "id": 1, "size": 0.05,
+//!       "pose": {
+//!         "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+//!         "translation": [0.04, 0, 0]
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+
+use crate::{
+    error::Error,
+    families::FamilyKind,
+    native::pose::{self, PoseInput, RawPose, Vec3},
+    pose::TagParams,
+    record::{DetectionRecord, PoseEstimationRecord, PoseRecord},
+};
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
+
+/// A rigid arrangement of tags.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct TagBundle {
+    /// The name of the bundle.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: String,
+
+    /// The member tags.
+    pub tags: Vec<BundleTag>,
+}
+
+/// A member tag of a [TagBundle].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct BundleTag {
+    /// The tag family.
+    pub family: FamilyKind,
+
+    /// The marker ID.
+    pub id: usize,
+
+    /// The width of the tag border. It defaults to the `tagsize` of
+    /// the tag parameters.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub size: Option<f64>,
+
+    /// The pose of the tag in bundle coordinates, in the same unit as
+    /// `size`.
+    pub pose: PoseRecord,
+}
+
+impl TagBundle {
+    /// Check that the bundle has tags of valid sizes, and that no tag
+    /// is listed twice.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: String| Err(Error::BundleError { reason });
+
+        if self.tags.is_empty() {
+            return invalid("the bundle has no tags".to_string());
+        }
+        for (index, tag) in self.tags.iter().enumerate() {
+            if let Some(size) = tag.size {
+                if !(size.is_finite() && size > 0.0) {
+                    return invalid(format!(
+                        "the size ({size}) of {} {} must be positive",
+                        tag.family, tag.id
+                    ));
+                }
+            }
+            if self.tags[..index]
+                .iter()
+                .any(|other| other.family == tag.family && other.id == tag.id)
+            {
+                return invalid(format!("{} {} is listed twice", tag.family, tag.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse a bundle from a JSON string.
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(text: &str) -> Result<Self, Error> {
+        let bundle: Self = serde_json::from_str(text).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to parse the bundle: {err}"),
+        })?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    /// Load a bundle from a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn from_json_file<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to read '{}': {err}", path.display()),
+        })?;
+        let bundle: Self = serde_json::from_str(&text).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to parse '{}': {err}", path.display()),
+        })?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    /// Estimate the pose of the bundle in camera coordinates.
+    ///
+    /// The corners of every detected member tag are fitted together by
+    /// orthogonal iteration, starting from the pose implied by each
+    /// member tag, and the pose with the least object-space error is
+    /// returned. If a member tag is detected more than once, the
+    /// detection with the greatest decision margin is used. It returns
+    /// `None` if no member tag is detected.
+    pub fn estimate_pose(
+        &self,
+        detections: &[DetectionRecord],
+        params: &TagParams,
+    ) -> Option<PoseEstimationRecord> {
+        let mut rays: Vec<Vec3> = vec![];
+        let mut points: Vec<Vec3> = vec![];
+        let mut initial_poses = vec![];
+
+        for tag in &self.tags {
+            let Some(detection) = detections
+                .iter()
+                .filter(|det| det.id == tag.id && det.family == tag.family.name())
+                .max_by(|lhs, rhs| lhs.decision_margin.total_cmp(&rhs.decision_margin))
+            else {
+                continue;
+            };
+            let size = tag.size.unwrap_or(params.tagsize);
+            let scale = size / 2.0;
+
+            let corners = [
+                [-scale, scale, 0.0],
+                [scale, scale, 0.0],
+                [scale, -scale, 0.0],
+                [-scale, -scale, 0.0],
+            ];
+            for (corner, [x, y]) in corners.iter().zip(detection.corners) {
+                let point = pose::mul_vec(&tag.pose.rotation, corner);
+                points.push(pose::add_vec(&point, &tag.pose.translation));
+                rays.push([
+                    (x - params.cx) / params.fx,
+                    (y - params.cy) / params.fy,
+                    1.0,
+                ]);
+            }
+
+            // Compose the camera-from-tag pose with the tag-from-bundle
+            // transform.
+            let input = PoseInput {
+                homography: &detection.homography,
+                corners: &detection.corners,
+                tagsize: size,
+                fx: params.fx,
+                fy: params.fy,
+                cx: params.cx,
+                cy: params.cy,
+            };
+            let (tag_pose, _) = pose::estimate_tag_pose(&input);
+            let rotation = pose::mul(&tag_pose.rotation, &pose::transpose(&tag.pose.rotation));
+            let offset = pose::mul_vec(&rotation, &tag.pose.translation);
+            initial_poses.push(RawPose {
+                rotation,
+                translation: pose::sub_vec(&tag_pose.translation, &offset),
+            });
+        }
+
+        initial_poses
+            .into_iter()
+            .map(|mut pose| {
+                let error = pose::orthogonal_iteration(&rays, &points, &mut pose, 50);
+                (pose, error)
+            })
+            .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+            .map(|(pose, error)| PoseEstimationRecord {
+                pose: PoseRecord {
+                    rotation: pose.rotation,
+                    translation: pose.translation,
+                },
+                error,
+            })
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_validate_bundles() {
+        let bundle = TagBundle::from_json_str(
+            r#"{
+                "tags": [{
+                    "family": "tag16h5", "id": 3,
+                    "pose": { "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]], "translation": [0, 0, 0] }
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(bundle.name, "");
+        assert_eq!(bundle.tags[0].family, FamilyKind::Tag16h5);
+        assert_eq!(bundle.tags[0].size, None);
+
+        let mut invalid = bundle.clone();
+        invalid.tags.push(invalid.tags[0].clone());
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = bundle.clone();
+        invalid.tags[0].size = Some(0.0);
+        assert!(invalid.validate().is_err());
+
+        assert!(TagBundle::from_json_str(r#"{ "tags": [] }"#).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! Contrast limited adaptive histogram equalization (CLAHE).
+//!
+//! The image is divided into a grid of tiles. Each tile is equalized
+//! by its own histogram, whose bins are clipped at a limit to avoid
+//! amplifying noise in flat regions, and the mappings of the four
+//! nearest tiles are bilinearly interpolated for every pixel.
+
+use crate::image_buf::Image;
+
+/// Equalize samples quantized to `bins` levels and write them to `out`.
+///
+/// The `bin_of` function gives the bin of the sample at `(x, y)`, which
+/// must be less than `bins`. The `clip_limit` is relative to the
+/// average bin count of a tile, as the `clipLimit` of OpenCV.
+pub(crate) fn equalize<F>(
+    bins: usize,
+    tiles: [usize; 2],
+    clip_limit: f64,
+    bin_of: F,
+    out: &mut Image,
+) where
+    F: Fn(usize, usize) -> usize,
+{
+    let width = out.width();
+    let height = out.height();
+    if width == 0 || height == 0 || bins == 0 {
+        return;
+    }
+
+    // Recompute the tile count from the tile size so that no tile is
+    // empty.
+    let tile_width = width.div_ceil(tiles[0].clamp(1, width));
+    let tile_height = height.div_ceil(tiles[1].clamp(1, height));
+    let tiles_x = width.div_ceil(tile_width);
+    let tiles_y = height.div_ceil(tile_height);
+
+    let mut luts = vec![0u8; tiles_x * tiles_y * bins];
+    let mut hist = vec![0u32; bins];
+
+    for (tile_index, lut) in luts.chunks_mut(bins).enumerate() {
+        let x0 = (tile_index % tiles_x) * tile_width;
+        let y0 = (tile_index / tiles_x) * tile_height;
+        let x1 = (x0 + tile_width).min(width);
+        let y1 = (y0 + tile_height).min(height);
+        let count = ((x1 - x0) * (y1 - y0)) as u32;
+
+        hist.fill(0);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                hist[bin_of(x, y)] += 1;
+            }
+        }
+
+        clip_histogram(&mut hist, clip_limit, count);
+
+        let scale = u8::MAX as f64 / count as f64;
+        let mut sum = 0;
+        for (entry, &bin_count) in lut.iter_mut().zip(&hist) {
+            sum += bin_count;
+            *entry = (sum as f64 * scale).round().min(u8::MAX as f64) as u8;
+        }
+    }
+
+    let lut_at = |tx: usize, ty: usize, bin: usize| luts[(ty * tiles_x + tx) * bins + bin] as f64;
+    let neighbors = |pos: usize, size: usize, count: usize| {
+        let pos = (pos as f64 + 0.5) / size as f64 - 0.5;
+        let lower = (pos.floor().max(0.0) as usize).min(count - 1);
+        let upper = (lower + 1).min(count - 1);
+        let weight = (pos - lower as f64).clamp(0.0, 1.0);
+        (lower, upper, weight)
+    };
+
+    for y in 0..height {
+        let (ty0, ty1, wy) = neighbors(y, tile_height, tiles_y);
+        for x in 0..width {
+            let (tx0, tx1, wx) = neighbors(x, tile_width, tiles_x);
+            let bin = bin_of(x, y);
+            let top = lut_at(tx0, ty0, bin) * (1.0 - wx) + lut_at(tx1, ty0, bin) * wx;
+            let bottom = lut_at(tx0, ty1, bin) * (1.0 - wx) + lut_at(tx1, ty1, bin) * wx;
+            out[(x, y)] = (top * (1.0 - wy) + bottom * wy).round() as u8;
+        }
+    }
+}
+
+/// Clip the bins at the limit and redistribute the excess evenly.
+fn clip_histogram(hist: &mut [u32], clip_limit: f64, count: u32) {
+    let bins = hist.len();
+    let limit = ((clip_limit * count as f64 / bins as f64) as u32).max(1);
+
+    let mut excess = 0;
+    for bin_count in hist.iter_mut() {
+        if *bin_count > limit {
+            excess += *bin_count - limit;
+            *bin_count = limit;
+        }
+    }
+
+    let increment = excess / bins as u32;
+    let remainder = (excess % bins as u32) as usize;
+    hist.iter_mut()
+        .for_each(|bin_count| *bin_count += increment);
+    if let Some(step) = bins.checked_div(remainder) {
+        hist.iter_mut()
+            .step_by(step)
+            .take(remainder)
+            .for_each(|bin_count| *bin_count += 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image_buf::DEFAULT_ALIGNMENT_U8;
+
+    #[test]
+    fn clip_keeps_total_count() {
+        let mut hist = vec![0, 100, 3, 0, 0, 1, 0, 0];
+        clip_histogram(&mut hist, 2.0, 104);
+        assert_eq!(hist.iter().sum::<u32>(), 104);
+        assert!(hist.iter().all(|&count| count <= 26 + 12));
+    }
+
+    #[test]
+    fn equalize_stretches_dim_tiles() {
+        let width = 64;
+        let height = 32;
+        let mut out = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8).unwrap();
+
+        // A dim left half and a bright right half, both with a faint
+        // pattern.
+        let bin_of = |x: usize, y: usize| {
+            let base = if x < width / 2 { 10 } else { 200 };
+            base + (x + y) % 4
+        };
+        equalize(256, [2, 1], 40.0, bin_of, &mut out);
+
+        let (min, max) = (0..height)
+            .flat_map(|y| (0..width / 4).map(move |x| (x, y)))
+            .map(|(x, y)| out[(x, y)])
+            .fold((u8::MAX, u8::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        assert!(max - min > 60, "{min} {max}");
+    }
+}
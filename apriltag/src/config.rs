@@ -0,0 +1,337 @@
+//! Detector configuration shared by services and bindings.
+//!
+//! A [DetectorConfig] gathers the tag families and the tunables of a
+//! [Detector], so that the same settings can be stored in files and
+//! loaded by the Rust services, the WebAssembly and C bindings alike.
+//! With the `serde` feature, it is loaded from JSON by
+//! [from_json_str](DetectorConfig::from_json_str) or
+//! [from_json_file](DetectorConfig::from_json_file).
+//!
+//! ```json
+//! {
+//!   "families": [
+//!     { "family": "tag36h11", "bits_corrected": 1 },
+//!     { "family": "tag16h5", "bits_corrected": 0 }
+//!   ],
+//!   "decimation": 2.0,
+//!   "tag_params": { "tagsize": 0.16, "fx": 600, "fy": 600, "cx": 320, "cy": 240 }
+//! }
+//! ```
+
+use crate::{
+    detector::{Detector, QuadThresholds},
+    error::Error,
+    families::FamilyKind,
+    filter::FilterParams,
+    multiscale::MultiScaleParams,
+    pose::TagParams,
+    preprocess::PreprocessParams,
+};
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
+
+/// The families and tunables of a [Detector].
+///
+/// The library defaults are used for unset tunables. With the `serde`
+/// feature, every field is optional, and the `tag36h11` family is used
+/// if no family is given.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct DetectorConfig {
+    /// The tag families to detect.
+    #[cfg_attr(feature = "serde", serde(default = "default_families"))]
+    pub families: Vec<FamilyConfig>,
+
+    /// Decimation factor applied to the input image for quad detection.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decimation: Option<f32>,
+
+    /// Standard deviation of Gaussian blur. Negative values sharpen the
+    /// image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sigma: Option<f32>,
+
+    /// Whether to refine the quad edges on the full-resolution image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub refine_edges: Option<bool>,
+
+    /// Sharpening applied to decoded images.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sharpening: Option<f64>,
+
+    /// Number of threads used by the detector.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub threads: Option<u8>,
+
+    /// Quad thresholds.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thresholds: Option<QuadThresholds>,
+
+    /// Preprocessing of the input image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub preprocess: Option<PreprocessParams>,
+
+    /// Multi-scale detection. The decimation levels override
+    /// `decimation`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub multi_scale: Option<MultiScaleParams>,
+
+    /// Filtering of the detections.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub filter: Option<FilterParams>,
+
+    /// Tag size and camera parameters. Consumers estimate poses if it
+    /// is set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tag_params: Option<TagParams>,
+}
+
+/// A tag family with the number of corrected bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct FamilyConfig {
+    /// The tag family.
+    pub family: FamilyKind,
+
+    /// The maximal number of corrected bits. It defaults to 2.
+    #[cfg_attr(feature = "serde", serde(default = "default_bits_corrected"))]
+    pub bits_corrected: usize,
+}
+
+impl DetectorConfig {
+    /// Create a [Detector] with the configuration.
+    pub fn build(&self) -> Result<Detector, Error> {
+        let mut builder = self
+            .families
+            .iter()
+            .fold(Detector::builder(), |builder, family| {
+                builder.add_family_bits(family.family, family.bits_corrected)
+            });
+        if let Some(preprocess) = &self.preprocess {
+            builder = builder.preprocess(preprocess.clone());
+        }
+        if let Some(multi_scale) = &self.multi_scale {
+            builder = builder.multi_scale(multi_scale.clone());
+        }
+        if let Some(filter) = &self.filter {
+            builder = builder.filter(filter.clone());
+        }
+        let mut detector = builder.build()?;
+
+        if let Some(decimation) = self.decimation {
+            detector.set_decimation(decimation);
+        }
+        if let Some(sigma) = self.sigma {
+            detector.set_sigma(sigma);
+        }
+        if let Some(refine_edges) = self.refine_edges {
+            detector.set_refine_edges(refine_edges);
+        }
+        if let Some(sharpening) = self.sharpening {
+            detector.set_shapening(sharpening);
+        }
+        if let Some(threads) = self.threads {
+            detector.set_thread_number(threads);
+        }
+        if let Some(thresholds) = self.thresholds {
+            detector.set_thresholds(thresholds);
+        }
+
+        Ok(detector)
+    }
+
+    /// Parse a configuration from a JSON string.
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(text: &str) -> Result<Self, Error> {
+        serde_json::from_str(text).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to parse the configuration: {err}"),
+        })
+    }
+
+    /// Load a configuration from a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn from_json_file<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to read '{}': {err}", path.display()),
+        })?;
+        serde_json::from_str(&text).map_err(|err| Error::LoadConfigError {
+            reason: format!("failed to parse '{}': {err}", path.display()),
+        })
+    }
+
+    /// Serialize the configuration to a JSON string.
+    #[cfg(feature = "serde")]
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl Default for DetectorConfig {
+    fn default() -> Self {
+        Self {
+            families: default_families(),
+            decimation: None,
+            sigma: None,
+            refine_edges: None,
+            sharpening: None,
+            threads: None,
+            thresholds: None,
+            preprocess: None,
+            multi_scale: None,
+            filter: None,
+            tag_params: None,
+        }
+    }
+}
+
+fn default_families() -> Vec<FamilyConfig> {
+    vec![FamilyConfig {
+        family: FamilyKind::Tag36h11,
+        bits_corrected: default_bits_corrected(),
+    }]
+}
+
+fn default_bits_corrected() -> usize {
+    2
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::preprocess::Preprocess;
+
+    #[test]
+    fn empty_config_uses_defaults() {
+        let config = DetectorConfig::from_json_str("{}").unwrap();
+        assert_eq!(config, DetectorConfig::default());
+        assert!(config.build().is_ok());
+    }
+
+    #[test]
+    fn parse_families_and_tag_params() {
+        let config = DetectorConfig::from_json_str(
+            r#"{
+                "families": [
+                    { "family": "tag16h5", "bits_corrected": 0 },
+                    { "family": "tagStandard41h12" }
+                ],
+                "decimation": 1.5,
+                "tag_params": { "tagsize": 0.1, "fx": 500, "fy": 500, "cx": 320, "cy": 240 }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.families,
+            [
+                FamilyConfig {
+                    family: FamilyKind::Tag16h5,
+                    bits_corrected: 0,
+                },
+                FamilyConfig {
+                    family: FamilyKind::TagStandard41h12,
+                    bits_corrected: 2,
+                },
+            ]
+        );
+        assert_eq!(config.decimation, Some(1.5));
+        assert_eq!(config.tag_params.as_ref().unwrap().tagsize, 0.1);
+
+        let json = config.to_json_string();
+        assert_eq!(DetectorConfig::from_json_str(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn parse_preprocess() {
+        let config = DetectorConfig::from_json_str(
+            r#"{
+                "preprocess": {
+                    "steps": [{ "method": "gamma", "gamma": 2.0 }],
+                    "retries": [
+                        [{ "method": "clahe", "tiles": [8, 8], "clip_limit": 4.0 }],
+                        [{ "method": "equalize_histogram" }]
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let preprocess = config.preprocess.as_ref().unwrap();
+        assert_eq!(preprocess.steps, [Preprocess::Gamma { gamma: 2.0 }]);
+        assert_eq!(preprocess.retries.len(), 2);
+        assert!(config.build().is_ok());
+
+        let invalid = DetectorConfig::from_json_str(
+            r#"{ "preprocess": { "steps": [{ "method": "gamma", "gamma": 0 }] } }"#,
+        )
+        .unwrap();
+        assert!(invalid.build().is_err());
+    }
+
+    #[test]
+    fn parse_multi_scale() {
+        let config = DetectorConfig::from_json_str(
+            r#"{ "multi_scale": { "decimations": [1, 3], "min_overlap": 0.4 } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.multi_scale,
+            Some(MultiScaleParams {
+                decimations: vec![1.0, 3.0],
+                min_overlap: 0.4,
+            })
+        );
+        assert!(config.build().is_ok());
+
+        let invalid = DetectorConfig::from_json_str(
+            r#"{ "multi_scale": { "decimations": [0.5], "min_overlap": 0.4 } }"#,
+        )
+        .unwrap();
+        assert!(invalid.build().is_err());
+    }
+
+    #[test]
+    fn parse_filter() {
+        let config = DetectorConfig::from_json_str(
+            r#"{
+                "filter": {
+                    "min_decision_margin": 30,
+                    "allowed_ids": { "tag16h5": [0, 1, 2] },
+                    "unique_ids": true
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let filter = config.filter.as_ref().unwrap();
+        assert_eq!(filter.min_decision_margin, Some(30.0));
+        assert_eq!(filter.max_hamming, None);
+        assert_eq!(filter.allowed_ids[&FamilyKind::Tag16h5], [0, 1, 2]);
+        assert_eq!(filter.duplicate_overlap, Some(0.5));
+        assert!(filter.unique_ids);
+        assert!(config.build().is_ok());
+
+        let json = config.to_json_string();
+        assert_eq!(DetectorConfig::from_json_str(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn reject_unknown_fields() {
+        assert!(DetectorConfig::from_json_str(r#"{ "famlies": [] }"#).is_err());
+        assert!(
+            DetectorConfig::from_json_str(r#"{ "families": [{ "family": "tag99h1" }] }"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! In-memory capture of intermediate detection results.
+//!
+//! [Detector::set_debug](crate::Detector::set_debug) lets the AprilTag
+//! library write `debug_*.pnm` files into the working directory. It
+//! has no effect with the pure-rust backend.
+//! [Detector::detect_debug](crate::Detector::detect_debug) instead
+//! gathers the intermediate images and quad geometry into
+//! [DebugArtifacts] without touching the file system.
+//!
+//! [Detector::detect_with_rejected](crate::Detector::detect_with_rejected)
+//! reports the quads that were fitted but failed to decode as
+//! [RejectedQuad]s, which helps to triage missed tags.
+
+#[cfg(not(feature = "pure-rust"))]
+use crate::native::quad::{self, QuadConstraints, QuadParams};
+use crate::{
+    decode::{self, FamilyData},
+    detection::Detection,
+    error::Error,
+    image_buf::Image,
+    unionfind,
+};
+#[cfg(not(feature = "pure-rust"))]
+use apriltag_sys as sys;
+#[cfg(not(feature = "pure-rust"))]
+use std::{ptr::NonNull, slice};
+
+/// Intermediate results of a detection.
+#[derive(Debug, Clone)]
+pub struct DebugArtifacts {
+    /// The decimated and blurred image used for quad detection.
+    pub preprocessed: Image,
+
+    /// The thresholded image, where black, white and unknown pixels
+    /// are 0, 255 and 127 respectively.
+    ///
+    /// It has the same size as [preprocessed](Self::preprocessed).
+    pub threshold: Image,
+
+    /// The connected components of the thresholded image, each
+    /// painted with a distinct gray level.
+    ///
+    /// It has the same size as [preprocessed](Self::preprocessed).
+    pub segmentation: Image,
+
+    /// All quad candidates on the input image.
+    pub quads: Vec<Quad>,
+
+    /// The quad candidates that do not result in any detection.
+    pub rejected_quads: Vec<Quad>,
+}
+
+/// A quad candidate fitted to a cluster of boundary pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quad {
+    /// The corner coordinates in form of `[[x, y]; 4]` on the input
+    /// image, before edge refinement.
+    pub corners: [[f64; 2]; 4],
+
+    /// Whether the border is white inside and black outside.
+    pub reversed_border: bool,
+}
+
+impl Quad {
+    /// Gets the average of corner coordinates in form of `[x, y]`.
+    pub fn center(&self) -> [f64; 2] {
+        let [sx, sy] = self
+            .corners
+            .iter()
+            .fold([0.0, 0.0], |[sx, sy], [x, y]| [sx + x, sy + y]);
+        [sx / 4.0, sy / 4.0]
+    }
+
+    /// Check if the quad is located at the same place as the detection.
+    pub(crate) fn matches(&self, corners: &[[f64; 2]; 4]) -> bool {
+        let perimeter: f64 = (0..4)
+            .map(|index| {
+                let [x1, y1] = self.corners[index];
+                let [x2, y2] = self.corners[(index + 1) % 4];
+                (x1 - x2).hypot(y1 - y2)
+            })
+            .sum();
+        let tolerance = (perimeter / 4.0 * 0.15).max(2.0);
+
+        self.corners.iter().all(|&[x1, y1]| {
+            corners
+                .iter()
+                .any(|&[x2, y2]| (x1 - x2).hypot(y1 - y2) <= tolerance)
+        })
+    }
+}
+
+/// A quad candidate that does not result in any detection, along
+/// with its closest codeword.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedQuad {
+    /// The corner coordinates in form of `[[x, y]; 4]` on the input
+    /// image, after edge refinement if enabled.
+    pub corners: [[f64; 2]; 4],
+
+    /// Whether the border is white inside and black outside.
+    pub reversed_border: bool,
+
+    /// The name of the family of the closest codeword.
+    pub family: String,
+
+    /// The tag ID of the closest codeword.
+    pub best_id: usize,
+
+    /// The Hamming distance to the closest codeword.
+    pub best_hamming: usize,
+
+    /// Indicate the _goodness_ of the sampled bits.
+    ///
+    /// It is negative if the border contrast does not match the family.
+    pub decision_margin: f32,
+}
+
+/// Find the quads that fail to decode and decode them again to report
+/// their closest codewords.
+#[cfg(not(feature = "pure-rust"))]
+pub(crate) unsafe fn rejected_quads(
+    td: NonNull<sys::apriltag_detector_t>,
+    image: &Image,
+    detections: &[Detection],
+) -> Result<Vec<RejectedQuad>, Error> {
+    let td = td.as_ref();
+    let preprocessed = preprocess(td, image)?;
+    let (_, quads) = detect_quads(td, &preprocessed)?;
+
+    let families: Vec<FamilyData> = families(td)
+        .map(|family| FamilyData::from_raw(family))
+        .collect();
+    let settings = DecodeSettings {
+        refine_edges: td.refine_edges != 0,
+        quad_decimate: td.quad_decimate as f64,
+        decode_sharpening: td.decode_sharpening,
+    };
+
+    Ok(decode_rejected(
+        image,
+        quads,
+        detections,
+        families.iter(),
+        &settings,
+    ))
+}
+
+/// The detector settings that affect decoding.
+#[derive(Debug, Clone)]
+pub(crate) struct DecodeSettings {
+    pub refine_edges: bool,
+    pub quad_decimate: f64,
+    pub decode_sharpening: f64,
+}
+
+/// Decode the quads that do not match any detection against all
+/// families to report their closest codewords.
+pub(crate) fn decode_rejected<'a, I>(
+    image: &Image,
+    quads: Vec<Quad>,
+    detections: &[Detection],
+    families: I,
+    settings: &DecodeSettings,
+) -> Vec<RejectedQuad>
+where
+    I: Iterator<Item = &'a FamilyData> + Clone,
+{
+    quads
+        .into_iter()
+        .filter(|quad| !matches_any(quad, detections))
+        .filter_map(|quad| {
+            let Quad {
+                mut corners,
+                reversed_border,
+            } = quad;
+            if settings.refine_edges {
+                decode::refine_edges(image, &mut corners, reversed_border, settings.quad_decimate);
+            }
+
+            let (family, decode) = families
+                .clone()
+                .filter(|family| family.reversed_border == reversed_border)
+                .filter_map(|family| {
+                    let decode =
+                        decode::decode_quad(image, family, &corners, settings.decode_sharpening)?;
+                    Some((family, decode))
+                })
+                .min_by(|(_, lhs), (_, rhs)| {
+                    lhs.hamming
+                        .cmp(&rhs.hamming)
+                        .then(rhs.decision_margin.total_cmp(&lhs.decision_margin))
+                })?;
+
+            Some(RejectedQuad {
+                corners,
+                reversed_border,
+                family: family.name.clone(),
+                best_id: decode.id,
+                best_hamming: decode.hamming as usize,
+                decision_margin: decode.decision_margin,
+            })
+        })
+        .collect()
+}
+
+/// Assemble the artifacts from the preprocessed and thresholded images.
+///
+/// It fails if the segmentation image can not be allocated.
+pub(crate) fn artifacts(
+    preprocessed: Image,
+    threshold: Image,
+    min_cluster_pixels: u32,
+    quads: Vec<Quad>,
+    detections: &[Detection],
+) -> Result<DebugArtifacts, Error> {
+    let segmentation = unionfind::segmentation_image(&threshold, min_cluster_pixels)?;
+    let rejected_quads = quads
+        .iter()
+        .filter(|quad| !matches_any(quad, detections))
+        .cloned()
+        .collect();
+
+    Ok(DebugArtifacts {
+        preprocessed,
+        threshold,
+        segmentation,
+        quads,
+        rejected_quads,
+    })
+}
+
+fn matches_any(quad: &Quad, detections: &[Detection]) -> bool {
+    detections
+        .iter()
+        .any(|detection| quad.matches(&detection.corners()))
+}
+
+/// Capture the intermediate results of a detection.
+#[cfg(not(feature = "pure-rust"))]
+pub(crate) unsafe fn capture(
+    td: NonNull<sys::apriltag_detector_t>,
+    image: &Image,
+    detections: &[Detection],
+) -> Result<DebugArtifacts, Error> {
+    let td = td.as_ref();
+    let preprocessed = preprocess(td, image)?;
+    let (threshold, quads) = detect_quads(td, &preprocessed)?;
+
+    artifacts(
+        preprocessed,
+        threshold,
+        td.qtp.min_cluster_pixels.max(0) as u32,
+        quads,
+        detections,
+    )
+}
+
+/// Decimate and blur the image in the same way as `apriltag_detector_detect()`.
+#[cfg(not(feature = "pure-rust"))]
+pub(crate) unsafe fn preprocess(
+    td: &sys::apriltag_detector_t,
+    image: &Image,
+) -> Result<Image, Error> {
+    let ptr = if td.quad_decimate > 1.0 {
+        sys::image_u8_decimate(image.ptr.as_ptr(), td.quad_decimate)
+    } else {
+        sys::image_u8_copy(image.ptr.as_ptr())
+    };
+    let ptr = NonNull::new(ptr).ok_or_else(|| Error::CreateImageError {
+        reason: "image_u8_decimate() failed".to_string(),
+    })?;
+    let mut quad_im = Image { ptr };
+
+    if td.quad_sigma != 0.0 {
+        let sigma = td.quad_sigma.abs();
+        let mut ksz = (4.0 * sigma) as i32;
+        if ksz & 1 == 0 {
+            ksz += 1;
+        }
+
+        if ksz > 1 {
+            if td.quad_sigma > 0.0 {
+                sys::image_u8_gaussian_blur(quad_im.ptr.as_ptr(), sigma as f64, ksz);
+            } else {
+                // Sharpen the image by subtracting the low frequency components.
+                let orig = quad_im.clone();
+                sys::image_u8_gaussian_blur(quad_im.ptr.as_ptr(), sigma as f64, ksz);
+                let stride = quad_im.stride();
+                quad_im
+                    .as_slice_mut()
+                    .iter_mut()
+                    .zip(orig.as_slice())
+                    .enumerate()
+                    .filter(|(index, _)| index % stride < orig.width())
+                    .for_each(|(_, (blur, &orig))| {
+                        *blur = (2 * orig as i32 - *blur as i32).clamp(0, 255) as u8;
+                    });
+            }
+        }
+    }
+
+    Ok(quad_im)
+}
+
+/// Threshold the preprocessed image and fit quads on it, returning the
+/// thresholded image and the quads on the input image.
+///
+/// The quad detection stage of the C library is not part of its public
+/// headers, so it is run by the Rust port in
+/// [native::quad](crate::native::quad). The port follows the C library
+/// bundled with apriltag-sys, but it is not the code that produced the
+/// detections. The quads may differ from those of the C library in rare
+/// cases due to floating point rounding, or if the library is linked
+/// from another version.
+#[cfg(not(feature = "pure-rust"))]
+unsafe fn detect_quads(
+    td: &sys::apriltag_detector_t,
+    preprocessed: &Image,
+) -> Result<(Image, Vec<Quad>), Error> {
+    let families = families(td).map(|family| (family.width_at_border, family.reversed_border));
+    let params = QuadParams::from_raw(&td.qtp);
+    let constraints = QuadConstraints::new(families, td.quad_decimate);
+
+    let threshold = quad::threshold(preprocessed, &params)?;
+    let mut uf = unionfind::connected_components(&threshold);
+    let clusters = quad::gradient_clusters(&threshold, &mut uf);
+    let mut quads = quad::fit_quads(&params, preprocessed, clusters, &constraints);
+    quad::map_to_input(&mut quads, td.quad_decimate);
+
+    Ok((threshold, quads))
+}
+
+/// Iterate over the tag families of the detector.
+#[cfg(not(feature = "pure-rust"))]
+unsafe fn families(
+    td: &sys::apriltag_detector_t,
+) -> impl Iterator<Item = &sys::apriltag_family_t> + '_ {
+    let families = &*td.tag_families;
+    slice::from_raw_parts(
+        families.data as *const *mut sys::apriltag_family_t,
+        families.size as usize,
+    )
+    .iter()
+    .filter_map(|&family| family.as_ref())
+}
@@ -0,0 +1,512 @@
+//! Tag payload decoding, ported from `apriltag.c`.
+//!
+//! The AprilTag library only reports quads that decode successfully.
+//! The routines here decode arbitrary quads in the same way, so that
+//! the closest codeword can be reported even when it is beyond the
+//! number of correctable bits.
+
+use crate::{homography, image_buf::Image};
+#[cfg(not(feature = "pure-rust"))]
+use apriltag_sys as sys;
+#[cfg(not(feature = "pure-rust"))]
+use std::{ffi::CStr, slice};
+
+/// The code table and bit layout of a tag family.
+#[derive(Debug, Clone)]
+pub(crate) struct FamilyData {
+    pub name: String,
+    pub codes: Vec<u64>,
+    pub nbits: u32,
+    /// The bit coordinates relative to the border, which are negative
+    /// for bits outside the border.
+    pub bit_x: Vec<i32>,
+    pub bit_y: Vec<i32>,
+    pub width_at_border: i32,
+    pub total_width: i32,
+    pub reversed_border: bool,
+}
+
+impl FamilyData {
+    #[cfg(not(feature = "pure-rust"))]
+    pub unsafe fn from_raw(family: &sys::apriltag_family_t) -> Self {
+        let name = if family.name.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(family.name).to_string_lossy().into_owned()
+        };
+        let nbits = family.nbits as usize;
+
+        Self {
+            name,
+            codes: slice::from_raw_parts(family.codes, family.ncodes as usize).to_vec(),
+            nbits: family.nbits,
+            bit_x: slice::from_raw_parts(family.bit_x, nbits)
+                .iter()
+                .map(|&x| x as i32)
+                .collect(),
+            bit_y: slice::from_raw_parts(family.bit_y, nbits)
+                .iter()
+                .map(|&y| y as i32)
+                .collect(),
+            width_at_border: family.width_at_border,
+            total_width: family.total_width,
+            reversed_border: family.reversed_border,
+        }
+    }
+
+    #[cfg(feature = "pure-rust")]
+    pub fn from_table(family: &crate::native::families::FamilyTable) -> Self {
+        Self {
+            name: family.name.to_string(),
+            codes: family.codes.to_vec(),
+            nbits: family.nbits,
+            bit_x: family.bit_x.to_vec(),
+            bit_y: family.bit_y.to_vec(),
+            width_at_border: family.width_at_border,
+            total_width: family.total_width,
+            reversed_border: family.reversed_border,
+        }
+    }
+
+    /// Find the closest codeword among all rotations.
+    ///
+    /// It returns the tag ID, the Hamming distance and the number of
+    /// 90 degree rotations.
+    pub fn closest_code(&self, rcode: u64) -> Option<(usize, u32, u32)> {
+        let mut rcode = rcode;
+        let mut best: Option<(usize, u32, u32)> = None;
+
+        for rotation in 0..4 {
+            for (id, &code) in self.codes.iter().enumerate() {
+                let hamming = (code ^ rcode).count_ones();
+                if best.is_none_or(|(_, best_hamming, _)| hamming < best_hamming) {
+                    best = Some((id, hamming, rotation));
+                }
+            }
+            rcode = rotate90(rcode, self.nbits);
+        }
+
+        best
+    }
+}
+
+/// The outcome of [decode_quad].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct QuadDecode {
+    pub id: usize,
+    pub hamming: u32,
+    pub rotation: u32,
+    pub decision_margin: f32,
+}
+
+/// Decode the payload of a quad.
+///
+/// The corners wind in the same order as the quads fitted by the
+/// AprilTag library. The decision margin is negative if the contrast
+/// of the border does not match the family.
+pub(crate) fn decode_quad(
+    image: &Image,
+    family: &FamilyData,
+    corners: &[[f64; 2]; 4],
+    decode_sharpening: f64,
+) -> Option<QuadDecode> {
+    let h = quad_homography(corners)?;
+    let (rcode, decision_margin) = sample_code(image, family, &h, decode_sharpening);
+    let (id, hamming, rotation) = family.closest_code(rcode)?;
+
+    Some(QuadDecode {
+        id,
+        hamming,
+        rotation,
+        decision_margin,
+    })
+}
+
+/// Sample the bits of a quad with the homography from the quad corners.
+///
+/// It returns the code and the decision margin, which is negative if
+/// the contrast of the border does not match the family.
+pub(crate) fn sample_code(
+    image: &Image,
+    family: &FamilyData,
+    h: &[[f64; 3]; 3],
+    decode_sharpening: f64,
+) -> (u64, f32) {
+    let width_at_border = family.width_at_border as f64;
+    let to_tag = |x: f64, y: f64| {
+        (
+            2.0 * (x / width_at_border - 0.5),
+            2.0 * (y / width_at_border - 0.5),
+        )
+    };
+
+    // Sample known white and black cells around the border to build
+    // the gray level models.
+    let wb = width_at_border;
+    let patterns: [(f64, f64, f64, f64, bool); 8] = [
+        (-0.5, 0.5, 0.0, 1.0, true),
+        (0.5, 0.5, 0.0, 1.0, false),
+        (wb + 0.5, 0.5, 0.0, 1.0, true),
+        (wb - 0.5, 0.5, 0.0, 1.0, false),
+        (0.5, -0.5, 1.0, 0.0, true),
+        (0.5, 0.5, 1.0, 0.0, false),
+        (0.5, wb + 0.5, 1.0, 0.0, true),
+        (0.5, wb - 0.5, 1.0, 0.0, false),
+    ];
+
+    let mut white_model = GrayModel::default();
+    let mut black_model = GrayModel::default();
+    let stride = image.stride();
+    let buf = image.as_slice();
+
+    for (x0, y0, dx, dy, is_white) in patterns {
+        for index in 0..family.width_at_border {
+            let (tagx, tagy) = to_tag(x0 + index as f64 * dx, y0 + index as f64 * dy);
+            let [px, py] = homography::project(h, tagx, tagy);
+
+            // Truncate instead of rounding, the same as the C library.
+            let (ix, iy) = (px as i64, py as i64);
+            if ix < 0 || iy < 0 || ix >= image.width() as i64 || iy >= image.height() as i64 {
+                continue;
+            }
+            let value = buf[iy as usize * stride + ix as usize] as f64;
+
+            if is_white {
+                white_model.add(tagx, tagy, value);
+            } else {
+                black_model.add(tagx, tagy, value);
+            }
+        }
+    }
+
+    let white = white_model.solve();
+    let black = if family.width_at_border > 1 {
+        black_model.solve()
+    } else {
+        [0.0, 0.0, black_model.b[2] / 4.0]
+    };
+    let interpolate = |c: &[f64; 3], x: f64, y: f64| c[0] * x + c[1] * y + c[2];
+
+    let polarity_ok = (interpolate(&white, 0.0, 0.0) - interpolate(&black, 0.0, 0.0) < 0.0)
+        == family.reversed_border;
+
+    // Sample the bit cells.
+    let total_width = family.total_width as usize;
+    let min_coord = (family.width_at_border - family.total_width) / 2;
+    let mut values = vec![0f64; total_width * total_width];
+    let cell_index = |bitx: i32, bity: i32| {
+        (bity - min_coord) as usize * total_width + (bitx - min_coord) as usize
+    };
+
+    for (&bitx, &bity) in family.bit_x.iter().zip(&family.bit_y) {
+        let (tagx, tagy) = to_tag(bitx as f64 + 0.5, bity as f64 + 0.5);
+        let [px, py] = homography::project(h, tagx, tagy);
+        let Some(value) = value_for_pixel(image, px, py) else {
+            continue;
+        };
+        let thresh = (interpolate(&black, tagx, tagy) + interpolate(&white, tagx, tagy)) / 2.0;
+        values[cell_index(bitx, bity)] = value - thresh;
+    }
+
+    sharpen(&mut values, total_width, decode_sharpening);
+
+    let mut rcode = 0u64;
+    let (mut white_score, mut white_count) = (0f32, 1f32);
+    let (mut black_score, mut black_count) = (0f32, 1f32);
+
+    for (&bitx, &bity) in family.bit_x.iter().zip(&family.bit_y) {
+        let value = values[cell_index(bitx, bity)];
+        rcode <<= 1;
+        if value > 0.0 {
+            white_score += value as f32;
+            white_count += 1.0;
+            rcode |= 1;
+        } else {
+            black_score -= value as f32;
+            black_count += 1.0;
+        }
+    }
+
+    let decision_margin = if polarity_ok {
+        (white_score / white_count).min(black_score / black_count)
+    } else {
+        -1.0
+    };
+
+    (rcode, decision_margin)
+}
+
+/// Snap the edges of a quad to strong gradients nearby, the same as
+/// `refine_edges()` in the C library.
+pub(crate) fn refine_edges(
+    image: &Image,
+    corners: &mut [[f64; 2]; 4],
+    reversed_border: bool,
+    quad_decimate: f64,
+) {
+    let width = image.width() as i64;
+    let height = image.height() as i64;
+    let stride = image.stride();
+    let buf = image.as_slice();
+    let mut lines = [[0f64; 4]; 4];
+
+    for (edge, line) in lines.iter_mut().enumerate() {
+        let [ax, ay] = corners[edge];
+        let [bx, by] = corners[(edge + 1) % 4];
+
+        let mag = (by - ay).hypot(bx - ax);
+        let (mut nx, mut ny) = ((by - ay) / mag, -(bx - ax) / mag);
+        if reversed_border {
+            nx = -nx;
+            ny = -ny;
+        }
+
+        let nsamples = 16.max((mag / 8.0) as usize);
+        let (mut mx, mut my, mut mxx, mut mxy, mut myy, mut count) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+        for sample in 0..nsamples {
+            let alpha = (1.0 + sample as f64) / (nsamples as f64 + 1.0);
+            let x0 = alpha * ax + (1.0 - alpha) * bx;
+            let y0 = alpha * ay + (1.0 - alpha) * by;
+
+            let range = quad_decimate + 1.0;
+            let (mut mn, mut mcount) = (0.0, 0.0);
+            let mut n = -range;
+
+            while n <= range {
+                let pixel = |offset: f64| {
+                    let x = (x0 + (n + offset) * nx) as i64;
+                    let y = (y0 + (n + offset) * ny) as i64;
+                    (x >= 0 && x < width && y >= 0 && y < height)
+                        .then(|| buf[y as usize * stride + x as usize] as f64)
+                };
+
+                if let (Some(g1), Some(g2)) = (pixel(1.0), pixel(-1.0)) {
+                    // Reject points whose gradient is backwards.
+                    if g1 >= g2 {
+                        let weight = (g2 - g1) * (g2 - g1);
+                        mn += weight * n;
+                        mcount += weight;
+                    }
+                }
+                n += 0.25;
+            }
+
+            if mcount == 0.0 {
+                continue;
+            }
+            let n0 = mn / mcount;
+            let bestx = x0 + n0 * nx;
+            let besty = y0 + n0 * ny;
+
+            mx += bestx;
+            my += besty;
+            mxx += bestx * bestx;
+            mxy += bestx * besty;
+            myy += besty * besty;
+            count += 1.0;
+        }
+
+        let ex = mx / count;
+        let ey = my / count;
+        let cxx = mxx / count - ex * ex;
+        let cxy = mxy / count - ex * ey;
+        let cyy = myy / count - ey * ey;
+
+        let normal_theta = 0.5 * (-2.0 * cxy).atan2(cyy - cxx);
+        *line = [ex, ey, normal_theta.cos(), normal_theta.sin()];
+    }
+
+    for (index, corner) in corners.iter_mut().enumerate() {
+        let this = &lines[index];
+        let next = &lines[(index + 1) % 4];
+
+        let a00 = this[3];
+        let a01 = -next[3];
+        let a10 = -this[2];
+        let a11 = next[2];
+        let b0 = -this[0] + next[0];
+        let b1 = -this[1] + next[1];
+
+        let det = a00 * a11 - a10 * a01;
+        if det.abs() > 0.001 {
+            let l0 = (a11 * b0 - a01 * b1) / det;
+            *corner = [this[0] + l0 * a00, this[1] + l0 * a10];
+        }
+    }
+
+    // The C library assigns the intersection of lines (i, i+1) to the
+    // corner i. Rotate so that the corners keep their winding.
+    corners.rotate_right(1);
+}
+
+/// Compute the homography of a quad fitted by the C library.
+///
+/// Unlike the corners of detections, quad corners correspond to the
+/// tag coordinates `[-1, -1]`, `[1, -1]`, `[1, 1]` and `[-1, 1]`.
+pub(crate) fn quad_homography(corners: &[[f64; 2]; 4]) -> Option<[[f64; 3]; 3]> {
+    let tag = [[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]];
+    let mut corr = [[0f64; 4]; 4];
+    for (index, (&[x, y], &[u, v])) in tag.iter().zip(corners).enumerate() {
+        corr[index] = [x, y, u, v];
+    }
+    homography::compute(&corr)
+}
+
+#[derive(Debug, Default)]
+struct GrayModel {
+    a: [[f64; 3]; 3],
+    b: [f64; 3],
+}
+
+impl GrayModel {
+    fn add(&mut self, x: f64, y: f64, gray: f64) {
+        let row = [x, y, 1.0];
+        for i in 0..3 {
+            for j in 0..3 {
+                self.a[i][j] += row[i] * row[j];
+            }
+            self.b[i] += row[i] * gray;
+        }
+    }
+
+    /// Solve the least squares fit of `gray = c0 * x + c1 * y + c2`.
+    fn solve(&self) -> [f64; 3] {
+        let a = &self.a;
+        let det = |m: [[f64; 3]; 3]| {
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+        };
+
+        let d = det(*a);
+        if d.abs() < 1e-12 {
+            return [0.0; 3];
+        }
+
+        // Cramer's rule.
+        let mut c = [0f64; 3];
+        for (col, c) in c.iter_mut().enumerate() {
+            let mut m = *a;
+            m.iter_mut().zip(&self.b).for_each(|(row, &b)| row[col] = b);
+            *c = det(m) / d;
+        }
+        c
+    }
+}
+
+/// Bilinear interpolation at pixel centers, the same as `value_for_pixel()`.
+fn value_for_pixel(image: &Image, px: f64, py: f64) -> Option<f64> {
+    let x1 = (px - 0.5).floor();
+    let x2 = (px - 0.5).ceil();
+    let y1 = (py - 0.5).floor();
+    let y2 = (py - 0.5).ceil();
+    if x1 < 0.0 || y1 < 0.0 || x2 >= image.width() as f64 || y2 >= image.height() as f64 {
+        return None;
+    }
+
+    let x = px - 0.5 - x1;
+    let y = py - 0.5 - y1;
+    let stride = image.stride();
+    let buf = image.as_slice();
+    let at = |x: f64, y: f64| buf[y as usize * stride + x as usize] as f64;
+
+    Some(
+        at(x1, y1) * (1.0 - x) * (1.0 - y)
+            + at(x2, y1) * x * (1.0 - y)
+            + at(x1, y2) * (1.0 - x) * y
+            + at(x2, y2) * x * y,
+    )
+}
+
+/// Apply the Laplacian sharpening to the sampled bit values.
+fn sharpen(values: &mut [f64], size: usize, decode_sharpening: f64) {
+    let kernel = [[0.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 0.0]];
+    let mut sharpened = vec![0f64; size * size];
+
+    for y in 0..size {
+        for x in 0..size {
+            for (i, row) in kernel.iter().enumerate() {
+                for (j, k) in row.iter().enumerate() {
+                    let (yy, xx) = ((y + i).checked_sub(1), (x + j).checked_sub(1));
+                    if let (Some(yy), Some(xx)) = (yy, xx) {
+                        if yy < size && xx < size {
+                            sharpened[y * size + x] += values[yy * size + xx] * k;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    values
+        .iter_mut()
+        .zip(sharpened)
+        .for_each(|(value, sharp)| *value += decode_sharpening * sharp);
+}
+
+/// Rotate the code by 90 degrees, the same as `rotate90()`.
+pub(crate) fn rotate90(w: u64, nbits: u32) -> u64 {
+    let (p, l) = if nbits % 4 == 1 {
+        (nbits - 1, 1)
+    } else {
+        (nbits, 0)
+    };
+    let w = ((w >> l) << (p / 4 + l)) | (w >> (3 * p / 4 + l) << l) | (w & l as u64);
+    w & 1u64.checked_shl(nbits).map_or(u64::MAX, |bit| bit - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "pure-rust"))]
+    use crate::families::Tag16h5;
+    use crate::{DetectorBuilder, Family};
+
+    #[test]
+    fn decode_matches_detections() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+        );
+        let image = Image::from_pnm_file(path).unwrap();
+        let mut detector = DetectorBuilder::new()
+            .add_family_bits(Family::tag_16h5(), 1)
+            .build()
+            .unwrap();
+        let detections = detector.detect(&image);
+        assert_eq!(detections.len(), 4);
+
+        #[cfg(not(feature = "pure-rust"))]
+        let family = unsafe { FamilyData::from_raw(&*Tag16h5::default().ptr) };
+        #[cfg(feature = "pure-rust")]
+        let family = FamilyData::from_table(Family::tag_16h5().table());
+        assert_eq!(family.name, "tag16h5");
+
+        for detection in detections {
+            // Quad corners wind in the reverse order of detection corners.
+            let [p0, p1, p2, p3] = detection.corners();
+            let decode = decode_quad(&image, &family, &[p3, p2, p1, p0], 0.25).unwrap();
+            assert_eq!(decode.id, detection.id());
+            assert_eq!(decode.hamming as usize, detection.hamming());
+            assert!((decode.decision_margin - detection.decision_margin()).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn rotate_code() {
+        let family = FamilyData {
+            name: String::new(),
+            codes: vec![0x27c8],
+            nbits: 16,
+            bit_x: vec![],
+            bit_y: vec![],
+            width_at_border: 6,
+            total_width: 8,
+            reversed_border: false,
+        };
+        let rotated = (0..3).fold(0x27c8, |code, _| rotate90(code, 16));
+        assert_eq!(rotate90(rotated, 16), 0x27c8);
+        assert_eq!(family.closest_code(rotated).unwrap().1, 0);
+    }
+}
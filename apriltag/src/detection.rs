@@ -1,29 +1,169 @@
 //! Tag detection types.
 
+#[cfg(feature = "pure-rust")]
+use crate::{families::FamilyKind, native::pose::PoseInput};
 use crate::{
+    image_buf::Image,
     matd::MatdRef,
     pose::{Pose, PoseEstimation, TagParams},
+    record::DetectionRecord,
+    refine::{self, RefineParams, RefinedCorners},
 };
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
+use std::fmt::{self, Debug, Formatter};
+#[cfg(not(feature = "pure-rust"))]
 use std::{
-    ffi::c_int,
-    fmt::{self, Debug, Formatter},
+    ffi::{c_int, CStr},
     mem::{ManuallyDrop, MaybeUninit},
     ptr::NonNull,
 };
 
 /// Represent a marker detection outcome.
+#[cfg(not(feature = "pure-rust"))]
 #[repr(transparent)]
 pub struct Detection {
     ptr: NonNull<sys::apriltag_detection_t>,
 }
 
+/// Represent a marker detection outcome.
+#[cfg(feature = "pure-rust")]
+pub struct Detection {
+    pub(crate) id: usize,
+    pub(crate) family: FamilyKind,
+    pub(crate) hamming: usize,
+    pub(crate) decision_margin: f32,
+    pub(crate) center: [f64; 2],
+    pub(crate) corners: [[f64; 2]; 4],
+    /// The homography in row-major order.
+    pub(crate) homography: [f64; 9],
+}
+
+// The detection owns its data and the family it refers to is never
+// modified after the detector is built.
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Send for Detection {}
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Sync for Detection {}
+
+impl Detection {
+    /// Copy the detection into an owned [DetectionRecord].
+    pub fn to_record(&self) -> DetectionRecord {
+        self.into()
+    }
+
+    /// Refine the corners to sub-pixel accuracy on the full-resolution image.
+    ///
+    /// The image must be the one passed to [Detector::detect](crate::Detector::detect).
+    /// It returns `None` if the tag edges cannot be located. See [refine] for details.
+    pub fn refine_corners(&self, image: &Image, params: &RefineParams) -> Option<RefinedCorners> {
+        refine::refine_corners(image, &self.corners(), params)
+    }
+}
+
+#[cfg(feature = "pure-rust")]
+impl Detection {
+    /// Get the marker ID.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Get the name of the tag family.
+    pub fn family_name(&self) -> String {
+        self.family.name().to_string()
+    }
+
+    /// Get the Hamming distance to the target tag.
+    pub fn hamming(&self) -> usize {
+        self.hamming
+    }
+
+    /// Indicate the _goodness_ of the detection.
+    pub fn decision_margin(&self) -> f32 {
+        self.decision_margin
+    }
+
+    /// Get the center coordinates in form of `[x, y]`.
+    pub fn center(&self) -> [f64; 2] {
+        self.center
+    }
+
+    /// Get the corner coordinates in form of `[[x, y]; 4]`.
+    pub fn corners(&self) -> [[f64; 2]; 4] {
+        self.corners
+    }
+
+    /// Get the homography matrix.
+    pub fn homography(&self) -> MatdRef<'_> {
+        MatdRef::from_slice(3, 3, &self.homography)
+    }
+
+    /// Estimates the pose of tag with specified number of iterations.
+    pub fn estimate_tag_pose_orthogonal_iteration(
+        &self,
+        params: &TagParams,
+        n_iters: usize,
+    ) -> Vec<PoseEstimation> {
+        let homography = self.homography_matrix();
+        let input = self.pose_input(&homography, params);
+        let (first, second) =
+            crate::native::pose::estimate_tag_pose_orthogonal_iteration(&input, n_iters);
+
+        [Some(first), second]
+            .into_iter()
+            .flatten()
+            .map(|(pose, error)| PoseEstimation {
+                pose: pose.into(),
+                error,
+            })
+            .collect()
+    }
+
+    /// Estimates the pose of tag.
+    pub fn estimate_tag_pose(&self, params: &TagParams) -> Option<Pose> {
+        let homography = self.homography_matrix();
+        let input = self.pose_input(&homography, params);
+        let (pose, _) = crate::native::pose::estimate_tag_pose(&input);
+        Some(pose.into())
+    }
+
+    fn homography_matrix(&self) -> [[f64; 3]; 3] {
+        let h = &self.homography;
+        [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], h[8]]]
+    }
+
+    fn pose_input<'a>(
+        &'a self,
+        homography: &'a [[f64; 3]; 3],
+        params: &TagParams,
+    ) -> PoseInput<'a> {
+        PoseInput {
+            homography,
+            corners: &self.corners,
+            tagsize: params.tagsize,
+            fx: params.fx,
+            fy: params.fy,
+            cx: params.cx,
+            cy: params.cy,
+        }
+    }
+}
+
+#[cfg(not(feature = "pure-rust"))]
 impl Detection {
     /// Get the marker ID.
     pub fn id(&self) -> usize {
         unsafe { self.ptr.as_ref().id as usize }
     }
 
+    /// Get the name of the tag family.
+    pub fn family_name(&self) -> String {
+        unsafe {
+            let family = &*self.ptr.as_ref().family;
+            CStr::from_ptr(family.name).to_string_lossy().into_owned()
+        }
+    }
+
     /// Get the Hamming distance to the target tag.
     pub fn hamming(&self) -> usize {
         unsafe { self.ptr.as_ref().hamming as usize }
@@ -154,6 +294,7 @@ impl Debug for Detection {
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl Drop for Detection {
     fn drop(&mut self) {
         unsafe {
@@ -1,32 +1,61 @@
 //! AprilTag detector type and its builder.
 
+#[cfg(feature = "pure-rust")]
+use crate::native::detector::{NativeDetector, NativeFamily};
 use crate::{
+    debug::{self, DebugArtifacts, RejectedQuad},
     detection::Detection,
     error::Error,
-    families::{ApriltagFamily, Family},
+    families::Family,
+    filter::FilterParams,
     image_buf::Image,
-    zarray::ZArray,
+    multiscale::{self, MultiScaleParams},
+    preprocess::{self, Preprocess, PreprocessParams},
+    stats::DetectorStats,
 };
+#[cfg(not(feature = "pure-rust"))]
+use crate::{families::ApriltagFamily, zarray::ZArray};
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
 use measurements::angle::Angle;
 use noisy_float::prelude::R32;
-use std::{ffi::c_int, mem::ManuallyDrop, ptr::NonNull};
+use std::ops::{Deref, DerefMut};
+#[cfg(not(feature = "pure-rust"))]
+use std::{ffi::c_int, mem, mem::ManuallyDrop, ptr::NonNull};
 
 /// The detector builder that creates [Detector].
 #[derive(Debug)]
 pub struct DetectorBuilder {
     families: Vec<(Family, usize)>,
+    preprocess: PreprocessParams,
+    multi_scale: Option<MultiScaleParams>,
+    filter: Option<FilterParams>,
 }
 
 impl DetectorBuilder {
     /// Create a builder instance.
     pub fn new() -> Self {
-        Self { families: vec![] }
+        Self {
+            families: vec![],
+            preprocess: PreprocessParams::default(),
+            multi_scale: None,
+            filter: None,
+        }
     }
 
     /// Append a tag family.
     ///
-    /// The method must be called at least once.
+    /// The method must be called at least once. Every family supports
+    /// correcting up to 3 bits, and larger values of `bits_corrected`
+    /// are treated as 3, the same as the AprilTag library. Each bit
+    /// raises the chance of false positives, especially on the small
+    /// `tag16h5` and `tag25h9` families.
+    ///
+    /// The C backend builds a lookup table of every correctable code,
+    /// which takes a lot of memory and time for the large
+    /// `tagCircle49h12`, `tagCustom48h12` and `tagStandard52h13`
+    /// families with 2 or more bits. The pure-rust backend scans the
+    /// codes instead when the table would be too large.
     pub fn add_family_bits<F>(mut self, family: F, bits_corrected: usize) -> Self
     where
         F: Into<Family>,
@@ -35,6 +64,45 @@ impl DetectorBuilder {
         self
     }
 
+    /// Append a [Preprocess] step applied to the input image before
+    /// detection.
+    pub fn add_preprocess(mut self, step: Preprocess) -> Self {
+        self.preprocess.steps.push(step);
+        self
+    }
+
+    /// Append an alternate chain of [Preprocess] steps.
+    ///
+    /// If the preprocessed image yields no detections, the chains are
+    /// applied to the original image in order until tags are found.
+    /// An empty chain retries on the original image.
+    pub fn add_preprocess_retry<I>(mut self, steps: I) -> Self
+    where
+        I: IntoIterator<Item = Preprocess>,
+    {
+        self.preprocess.retries.push(steps.into_iter().collect());
+        self
+    }
+
+    /// Replace the preprocessing steps and retries.
+    pub fn preprocess(mut self, params: PreprocessParams) -> Self {
+        self.preprocess = params;
+        self
+    }
+
+    /// Run detection at several decimation levels and merge the
+    /// results. See [multiscale](crate::multiscale).
+    pub fn multi_scale(mut self, params: MultiScaleParams) -> Self {
+        self.multi_scale = Some(params);
+        self
+    }
+
+    /// Filter the detections by [FilterParams].
+    pub fn filter(mut self, params: FilterParams) -> Self {
+        self.filter = Some(params);
+        self
+    }
+
     /// Create a [Detector] instance.
     ///
     /// If [add_family_bits](DetectorBuilder::add_family_bits) is never called.
@@ -47,7 +115,49 @@ impl DetectorBuilder {
                     .to_string(),
             });
         }
+        self.preprocess
+            .validate()
+            .map_err(|err| Error::CreateDetectorError {
+                reason: err.to_string(),
+            })?;
+        if let Some(multi_scale) = &self.multi_scale {
+            multi_scale
+                .validate()
+                .map_err(|err| Error::CreateDetectorError {
+                    reason: err.to_string(),
+                })?;
+        }
+        if let Some(filter) = &self.filter {
+            filter
+                .validate()
+                .map_err(|err| Error::CreateDetectorError {
+                    reason: err.to_string(),
+                })?;
+        }
+
+        #[cfg(feature = "pure-rust")]
+        {
+            let families = self
+                .families
+                .into_iter()
+                .map(|(family, bits_corrected)| NativeFamily::new(family.kind(), bits_corrected))
+                .collect();
 
+            Ok(Detector {
+                native: NativeDetector::new(families),
+                preprocess: self.preprocess,
+                multi_scale: self.multi_scale,
+                filter: self.filter,
+                multi_scale_stats: None,
+            })
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
+        self.build_c()
+    }
+
+    #[cfg(not(feature = "pure-rust"))]
+    fn build_c(self) -> Result<Detector, Error> {
         let detector_ptr = unsafe { sys::apriltag_detector_create() };
         let detector_ptr =
             NonNull::new(detector_ptr).ok_or_else(|| Error::CreateDetectorError {
@@ -64,7 +174,13 @@ impl DetectorBuilder {
             }
         }
 
-        Ok(Detector { ptr: detector_ptr })
+        Ok(Detector {
+            ptr: detector_ptr,
+            preprocess: self.preprocess,
+            multi_scale: self.multi_scale,
+            filter: self.filter,
+            multi_scale_stats: None,
+        })
     }
 }
 
@@ -75,19 +191,141 @@ impl Default for DetectorBuilder {
 }
 
 /// The marker detector.
+#[cfg(not(feature = "pure-rust"))]
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct Detector {
     pub(crate) ptr: NonNull<sys::apriltag_detector_t>,
+    preprocess: PreprocessParams,
+    multi_scale: Option<MultiScaleParams>,
+    filter: Option<FilterParams>,
+    multi_scale_stats: Option<DetectorStats>,
 }
 
+/// The marker detector.
+#[cfg(feature = "pure-rust")]
+#[derive(Debug)]
+pub struct Detector {
+    native: NativeDetector,
+    preprocess: PreprocessParams,
+    multi_scale: Option<MultiScaleParams>,
+    filter: Option<FilterParams>,
+    multi_scale_stats: Option<DetectorStats>,
+}
+
+// The detector owns its state and tag families. It can be moved to
+// another thread, but detection requires exclusive access.
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Send for Detector {}
+
 impl Detector {
     pub fn builder() -> DetectorBuilder {
         DetectorBuilder::new()
     }
 
     /// Run detection on the input image.
+    ///
+    /// If preprocessing is configured, the detection runs on the
+    /// preprocessed image, and the retries are tried in order when no
+    /// tags are found. The [last_stats](Detector::last_stats) refer to
+    /// the last attempt, summed over its decimation passes if
+    /// multi-scale detection is enabled. The stages of each pass are
+    /// traced in its own span.
     pub fn detect(&mut self, image: &Image) -> Vec<Detection> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "apriltag_detect",
+            width = image.width(),
+            height = image.height()
+        )
+        .entered();
+
+        if self.preprocess.is_empty() {
+            return self.detect_once(image);
+        }
+
+        let mut detections = self.detect_preprocessed(image);
+        for index in 0..self.preprocess.retries.len() {
+            if !detections.is_empty() {
+                break;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                retry = index,
+                "no tags found, retry with alternate preprocessing"
+            );
+
+            let retried = preprocess::apply_steps(image, &self.preprocess.retries[index]);
+            detections = self.detect_once(&retried);
+        }
+
+        detections
+    }
+
+    /// Run detection on the image with the preprocessing steps applied.
+    fn detect_preprocessed(&mut self, image: &Image) -> Vec<Detection> {
+        if self.preprocess.steps.is_empty() {
+            self.detect_once(image)
+        } else {
+            let image = preprocess::apply_steps(image, &self.preprocess.steps);
+            self.detect_once(&image)
+        }
+    }
+
+    /// Run detection on the image as is and filter the detections.
+    fn detect_once(&mut self, image: &Image) -> Vec<Detection> {
+        let detections = self.detect_scales(image);
+        match &self.filter {
+            Some(filter) => filter.apply(detections),
+            None => detections,
+        }
+    }
+
+    /// Run detection at every decimation level if multi-scale detection
+    /// is enabled.
+    fn detect_scales(&mut self, image: &Image) -> Vec<Detection> {
+        let Some(multi_scale) = &self.multi_scale else {
+            return self.detect_single(image);
+        };
+        let MultiScaleParams {
+            decimations,
+            min_overlap,
+        } = multi_scale.clone();
+
+        let mut merged = vec![];
+        let mut stats = DetectorStats::default();
+        {
+            // The decimation is restored even if a pass panics.
+            let mut detector = RestoreDecimation::new(self);
+            for decimation in decimations {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!("apriltag_pass", decimation).entered();
+
+                detector.set_decimation(decimation);
+                let detections = detector.detect_single(image);
+                stats.accumulate(&detector.pass_stats());
+                multiscale::merge(&mut merged, detections, min_overlap);
+            }
+        }
+        self.multi_scale_stats = Some(stats);
+
+        merged
+    }
+
+    /// Run a single detection on the image as is.
+    fn detect_single(&mut self, image: &Image) -> Vec<Detection> {
+        self.multi_scale_stats = None;
+
+        // The intermediate images of the pure-rust backend only fail to
+        // allocate with invalid sizes, in which case no tag is found.
+        #[cfg(feature = "pure-rust")]
+        let detections = self
+            .native
+            .detect(image)
+            .map(|(detections, _)| detections)
+            .unwrap_or_default();
+
+        #[cfg(not(feature = "pure-rust"))]
         let detections = unsafe {
             let ptr = sys::apriltag_detector_detect(self.ptr.as_ptr(), image.ptr.as_ptr());
             let zarray = ZArray::<*mut sys::apriltag_detection_t>::from_raw(ptr);
@@ -98,21 +336,169 @@ impl Detector {
                 .collect::<Vec<_>>();
             detections
         };
+
+        #[cfg(feature = "tracing")]
+        self.pass_stats().trace();
+
         detections
     }
 
+    /// Run detection and capture the intermediate results in memory.
+    ///
+    /// Unlike [set_debug](Detector::set_debug), it does not write
+    /// files to the working directory. With the C backend, the image
+    /// preprocessing and quad fitting are run a second time to collect
+    /// the [DebugArtifacts], so it is slower than [detect](Detector::detect).
+    /// The quad fitting is not exposed by the C library and is run by
+    /// a Rust port of the stage, which may differ from the quads behind
+    /// the detections in rare cases.
+    ///
+    /// The preprocessing steps are applied, but the retries, the
+    /// multi-scale detection and the filter are not.
+    ///
+    /// It returns an error if an intermediate image can not be allocated.
+    pub fn detect_debug(
+        &mut self,
+        image: &Image,
+    ) -> Result<(Vec<Detection>, DebugArtifacts), Error> {
+        let preprocessed;
+        let image = if self.preprocess.steps.is_empty() {
+            image
+        } else {
+            preprocessed = preprocess::apply_steps(image, &self.preprocess.steps);
+            &preprocessed
+        };
+
+        #[cfg(feature = "pure-rust")]
+        {
+            let (detections, intermediates) = self.native.detect(image)?;
+            let artifacts = debug::artifacts(
+                intermediates.preprocessed.into_owned(),
+                intermediates.threshold,
+                self.native.thresholds.min_cluster_pixels,
+                intermediates.quads,
+                &detections,
+            )?;
+            Ok((detections, artifacts))
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
+        {
+            let detections = self.detect_single(image);
+            let artifacts = unsafe { debug::capture(self.ptr, image, &detections)? };
+            Ok((detections, artifacts))
+        }
+    }
+
+    /// Run detection and report the quads that fail to decode.
+    ///
+    /// Each [RejectedQuad] carries the closest codeword among the
+    /// families of the detector regardless of the number of corrected
+    /// bits. With the C backend, the quads are fitted a second time by
+    /// the Rust port of the stage, so it is slower than
+    /// [detect](Detector::detect).
+    ///
+    /// The preprocessing steps are applied, but the retries, the
+    /// multi-scale detection and the filter are not.
+    ///
+    /// It returns an error if an intermediate image can not be allocated.
+    pub fn detect_with_rejected(
+        &mut self,
+        image: &Image,
+    ) -> Result<(Vec<Detection>, Vec<RejectedQuad>), Error> {
+        let preprocessed;
+        let image = if self.preprocess.steps.is_empty() {
+            image
+        } else {
+            preprocessed = preprocess::apply_steps(image, &self.preprocess.steps);
+            &preprocessed
+        };
+
+        #[cfg(feature = "pure-rust")]
+        {
+            let (detections, intermediates) = self.native.detect(image)?;
+            let quads = intermediates.quads;
+            let settings = debug::DecodeSettings {
+                refine_edges: self.native.refine_edges,
+                quad_decimate: self.native.quad_decimate as f64,
+                decode_sharpening: self.native.decode_sharpening,
+            };
+            let families = self.native.families.iter().map(|family| &family.data);
+            let rejected = debug::decode_rejected(image, quads, &detections, families, &settings);
+            Ok((detections, rejected))
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
+        {
+            let detections = self.detect_single(image);
+            let rejected = unsafe { debug::rejected_quads(self.ptr, image, &detections)? };
+            Ok((detections, rejected))
+        }
+    }
+
+    /// Get the timing and counting statistics of the last detection.
+    ///
+    /// The values are zero if [detect](Detector::detect) was never called.
+    /// With multi-scale detection, they are summed over the decimation
+    /// passes.
+    pub fn last_stats(&self) -> DetectorStats {
+        match &self.multi_scale_stats {
+            Some(stats) => stats.clone(),
+            None => self.pass_stats(),
+        }
+    }
+
+    /// Get the statistics of the last pass of the detection pipeline.
+    fn pass_stats(&self) -> DetectorStats {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.stats.clone()
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
+        unsafe {
+            DetectorStats::from_raw(self.ptr.as_ref())
+        }
+    }
+
     /// Set the number of threads used for detection.
+    ///
+    /// The pure-rust backend always runs on the calling thread and
+    /// ignores the setting.
     pub fn set_thread_number(&mut self, num_threads: u8) {
+        #[cfg(feature = "pure-rust")]
+        let _ = num_threads;
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().nthreads = num_threads as c_int;
         }
     }
 
+    /// Get the decimation factor.
+    fn decimation(&self) -> f32 {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.quad_decimate
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
+        unsafe {
+            self.ptr.as_ref().quad_decimate
+        }
+    }
+
     /// Decimate the input image.
     ///
     /// The detection of quads can be done on a lower-resolution image, improving speed at a cost of pose accuracy and a slight decrease in detection rate.
     /// Decoding the binary payload is still done at full resolution.
     pub fn set_decimation(&mut self, decimation: f32) {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.quad_decimate = decimation;
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().quad_decimate = decimation;
         }
@@ -121,6 +507,12 @@ impl Detector {
     /// Set the standard deviation in pixels for Gaussian blur applied to the segmented image for quad detection.
     /// Very noisy images benefit from non-zero values (e.g. 0.8).
     pub fn set_sigma(&mut self, sigma: f32) {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.quad_sigma = sigma;
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().quad_sigma = sigma;
         }
@@ -132,6 +524,12 @@ impl Detector {
     /// This is useful when decimation is employed, as it can increase the quality of the initial quad estimate substantially.
     /// Both very computationally inexpensive and generally recommended to be enabled. The option is ignored if decimation is disabled.
     pub fn set_refine_edges(&mut self, refine_edges: bool) {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.refine_edges = refine_edges;
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().refine_edges = refine_edges as c_int;
         }
@@ -142,6 +540,12 @@ impl Detector {
     /// This can help decode small tags but may or may not help in odd lighting conditions or low light conditions.
     /// The default value is 0.25.
     pub fn set_shapening(&mut self, shapening: f64) {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.decode_sharpening = shapening;
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().decode_sharpening = shapening;
         }
@@ -149,15 +553,80 @@ impl Detector {
 
     /// Enable or disable the debugging message.
     ///
-    /// It is disabled by default.
+    /// It is disabled by default. The pure-rust backend ignores the
+    /// setting. Use [detect_debug](Detector::detect_debug) instead.
     pub fn set_debug(&mut self, debug: bool) {
+        #[cfg(feature = "pure-rust")]
+        let _ = debug;
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().debug = debug as c_int;
         }
     }
 
+    /// Set the preprocessing steps and retries applied by
+    /// [detect](Detector::detect).
+    ///
+    /// It returns an error and keeps the current setting if the
+    /// parameters are invalid.
+    pub fn set_preprocess(&mut self, params: PreprocessParams) -> Result<(), Error> {
+        params.validate()?;
+        self.preprocess = params;
+        Ok(())
+    }
+
+    /// Get the preprocessing steps and retries.
+    pub fn preprocess(&self) -> &PreprocessParams {
+        &self.preprocess
+    }
+
+    /// Enable multi-scale detection, or disable it with `None`.
+    ///
+    /// The decimation levels override
+    /// [set_decimation](Detector::set_decimation) in
+    /// [detect](Detector::detect). It returns an error and keeps the
+    /// current setting if the parameters are invalid.
+    pub fn set_multi_scale(&mut self, params: Option<MultiScaleParams>) -> Result<(), Error> {
+        if let Some(params) = &params {
+            params.validate()?;
+        }
+        self.multi_scale = params;
+        Ok(())
+    }
+
+    /// Get the multi-scale parameters if enabled.
+    pub fn multi_scale(&self) -> Option<&MultiScaleParams> {
+        self.multi_scale.as_ref()
+    }
+
+    /// Filter the detections of [detect](Detector::detect), or disable
+    /// filtering with `None`.
+    ///
+    /// The retries of preprocessing are tried if all detections are
+    /// filtered out. It returns an error and keeps the current setting
+    /// if the parameters are invalid.
+    pub fn set_filter(&mut self, params: Option<FilterParams>) -> Result<(), Error> {
+        if let Some(params) = &params {
+            params.validate()?;
+        }
+        self.filter = params;
+        Ok(())
+    }
+
+    /// Get the filter parameters if enabled.
+    pub fn filter(&self) -> Option<&FilterParams> {
+        self.filter.as_ref()
+    }
+
     /// Set various thresholds for detecting quads as candidates for further processing.
     pub fn set_thresholds(&mut self, thresholds: QuadThresholds) {
+        #[cfg(feature = "pure-rust")]
+        {
+            self.native.thresholds = thresholds;
+        }
+
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             self.ptr.as_mut().qtp = thresholds.to_c_params();
         }
@@ -166,22 +635,70 @@ impl Detector {
     /// Creates an instance from pointer.
     ///
     /// The pointer will be managed by the type. Do not run manual deallocation on the pointer.
-    /// Panics if the pointer is null.
+    /// Panics if the pointer is null. The preprocessing, multi-scale
+    /// detection and filter are disabled.
     ///
     /// # Safety
     /// The method is safe when the pointer was created by [apriltag_detector_create](sys::apriltag_detector_create).
+    #[cfg(not(feature = "pure-rust"))]
     pub unsafe fn from_raw(ptr: *mut sys::apriltag_detector_t) -> Self {
         Self {
             ptr: NonNull::new(ptr).unwrap(),
+            preprocess: PreprocessParams::default(),
+            multi_scale: None,
+            filter: None,
+            multi_scale_stats: None,
         }
     }
 
     /// Returns the underlying pointer.
+    ///
+    /// The preprocessing, multi-scale and filter settings are discarded.
+    #[cfg(not(feature = "pure-rust"))]
     pub fn into_raw(self) -> NonNull<sys::apriltag_detector_t> {
-        ManuallyDrop::new(self).ptr
+        let mut detector = ManuallyDrop::new(self);
+        drop(mem::take(&mut detector.preprocess));
+        drop(detector.multi_scale.take());
+        drop(detector.filter.take());
+        drop(detector.multi_scale_stats.take());
+        detector.ptr
     }
 }
 
+/// Restores the decimation of the detector when dropped.
+struct RestoreDecimation<'a> {
+    detector: &'a mut Detector,
+    original: f32,
+}
+
+impl<'a> RestoreDecimation<'a> {
+    fn new(detector: &'a mut Detector) -> Self {
+        let original = detector.decimation();
+        Self { detector, original }
+    }
+}
+
+impl Deref for RestoreDecimation<'_> {
+    type Target = Detector;
+
+    fn deref(&self) -> &Self::Target {
+        self.detector
+    }
+}
+
+impl DerefMut for RestoreDecimation<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.detector
+    }
+}
+
+impl Drop for RestoreDecimation<'_> {
+    fn drop(&mut self) {
+        self.detector.set_decimation(self.original);
+    }
+}
+
+#[cfg(not(feature = "pure-rust"))]
 impl Drop for Detector {
     fn drop(&mut self) {
         unsafe { sys::apriltag_detector_destroy(self.ptr.as_ptr()) };
@@ -189,7 +706,15 @@ impl Drop for Detector {
 }
 
 /// The adjustable theshold for detecting candidates.
+///
+/// With the `serde` feature, the angles are serialized in degrees as
+/// `min_angle_degrees` and `min_opposite_angle_degrees`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "QuadThresholdsRepr", try_from = "QuadThresholdsRepr")
+)]
 pub struct QuadThresholds {
     /// Enforce a minimal number of pixels per candidate.
     pub min_cluster_pixels: u32,
@@ -218,8 +743,70 @@ pub struct QuadThresholds {
     pub deglitch: bool,
 }
 
+/// The serialized form of [QuadThresholds] with explicit angle units.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QuadThresholdsRepr {
+    min_cluster_pixels: u32,
+    max_maxima_number: u32,
+    min_angle_degrees: f64,
+    min_opposite_angle_degrees: f64,
+    max_mse: f32,
+    min_white_black_diff: u8,
+    deglitch: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<QuadThresholds> for QuadThresholdsRepr {
+    fn from(from: QuadThresholds) -> Self {
+        Self {
+            min_cluster_pixels: from.min_cluster_pixels,
+            max_maxima_number: from.max_maxima_number,
+            min_angle_degrees: from.min_angle.as_degrees(),
+            min_opposite_angle_degrees: from.min_opposite_angle.as_degrees(),
+            max_mse: from.max_mse.raw(),
+            min_white_black_diff: from.min_white_black_diff,
+            deglitch: from.deglitch,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<QuadThresholdsRepr> for QuadThresholds {
+    type Error = String;
+
+    fn try_from(from: QuadThresholdsRepr) -> Result<Self, Self::Error> {
+        Ok(Self {
+            min_cluster_pixels: from.min_cluster_pixels,
+            max_maxima_number: from.max_maxima_number,
+            min_angle: Angle::from_degrees(from.min_angle_degrees),
+            min_opposite_angle: Angle::from_degrees(from.min_opposite_angle_degrees),
+            max_mse: R32::try_new(from.max_mse)
+                .ok_or_else(|| format!("max_mse must be finite, but get {}", from.max_mse))?,
+            min_white_black_diff: from.min_white_black_diff,
+            deglitch: from.deglitch,
+        })
+    }
+}
+
+impl Default for QuadThresholds {
+    /// The defaults of the AprilTag library.
+    fn default() -> Self {
+        Self {
+            min_cluster_pixels: 5,
+            max_maxima_number: 10,
+            min_angle: Angle::from_degrees(10.0),
+            min_opposite_angle: Angle::from_degrees(10.0),
+            max_mse: R32::new(10.0),
+            min_white_black_diff: 5,
+            deglitch: false,
+        }
+    }
+}
+
+#[cfg(not(feature = "pure-rust"))]
 impl QuadThresholds {
-    fn to_c_params(&self) -> sys::apriltag_quad_thresh_params {
+    fn to_c_params(self) -> sys::apriltag_quad_thresh_params {
         let Self {
             min_cluster_pixels,
             max_maxima_number,
@@ -228,16 +815,42 @@ impl QuadThresholds {
             max_mse,
             min_white_black_diff,
             deglitch,
-        } = *self;
+        } = self;
 
         sys::apriltag_quad_thresh_params {
             min_cluster_pixels: min_cluster_pixels as c_int,
             max_nmaxima: max_maxima_number as c_int,
             critical_rad: min_angle.as_radians() as f32,
-            cos_critical_rad: min_opposite_angle.as_radians() as f32,
+            cos_critical_rad: min_opposite_angle.as_radians().cos() as f32,
             max_line_fit_mse: max_mse.raw(),
             min_white_black_diff: min_white_black_diff as c_int,
             deglitch: deglitch as c_int,
         }
     }
 }
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_thresholds_passes_cosine_of_opposite_angle() {
+        let mut detector = DetectorBuilder::new()
+            .add_family_bits(Family::tag_16h5(), 1)
+            .build()
+            .unwrap();
+        detector.set_thresholds(QuadThresholds {
+            min_cluster_pixels: 5,
+            max_maxima_number: 10,
+            min_angle: Angle::from_degrees(10.0),
+            min_opposite_angle: Angle::from_degrees(30.0),
+            max_mse: R32::new(10.0),
+            min_white_black_diff: 5,
+            deglitch: false,
+        });
+
+        let qtp = unsafe { &detector.ptr.as_ref().qtp };
+        assert_eq!(qtp.critical_rad, 10f64.to_radians() as f32);
+        assert_eq!(qtp.cos_critical_rad, 30f64.to_radians().cos() as f32);
+    }
+}
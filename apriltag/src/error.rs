@@ -9,6 +9,36 @@ pub enum Error {
     #[error("Unable to create an image: {reason}")]
     CreateImageError { reason: String },
 
+    #[error("Unsupported color type '{color_type}'")]
+    UnsupportedColorType { color_type: String },
+
+    #[error("Unable to convert the image: {reason}")]
+    ConvertImageError { reason: String },
+
     #[error("Unable to create a detector: {reason}")]
     CreateDetectorError { reason: String },
+
+    #[error("Unable to load the dataset: {reason}")]
+    LoadDatasetError { reason: String },
+
+    #[error("Unable to load the configuration: {reason}")]
+    LoadConfigError { reason: String },
+
+    #[error("Unable to map the tones of the image: {reason}")]
+    ToneMapError { reason: String },
+
+    #[error("Unable to preprocess the image: {reason}")]
+    PreprocessError { reason: String },
+
+    #[error("Unable to configure multi-scale detection: {reason}")]
+    MultiScaleError { reason: String },
+
+    #[error("Unable to configure the detection filter: {reason}")]
+    FilterError { reason: String },
+
+    #[error("Invalid tag bundle: {reason}")]
+    BundleError { reason: String },
+
+    #[error("Unable to configure the tracker: {reason}")]
+    TrackerError { reason: String },
 }
@@ -0,0 +1,592 @@
+//! Evaluation of detection accuracy on labelled datasets.
+//!
+//! A [Dataset] lists images along with the expected tags on each
+//! image. [Dataset::evaluate] runs a configured [Detector] on every
+//! image, matches the detections to the labels and summarizes the
+//! accuracy in an [EvalReport], including the recall, the precision,
+//! the corner RMSE and, for labels with known poses, the pose error.
+//! An [Evaluation] computes the same summary from detections produced
+//! by the caller.
+//!
+//! Datasets are loaded from CSV files with
+//! [from_csv_file](Dataset::from_csv_file) if the `csv` feature is
+//! enabled, or from JSON files with
+//! [from_json_file](Dataset::from_json_file) if the `serde` feature
+//! is enabled. They can also be built in code.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "csv")]
+//! # fn main() -> Result<(), apriltag::Error> {
+//! use apriltag::{
+//!     eval::{Dataset, EvalParams},
+//!     Detector, Family,
+//! };
+//!
+//! let dataset = Dataset::from_csv_file("dataset/labels.csv")?;
+//! let mut detector = Detector::builder()
+//!     .add_family_bits(Family::tag_36h11(), 1)
+//!     .build()?;
+//!
+//! let report = dataset.evaluate(&mut detector, &EvalParams::default())?;
+//! println!("recall: {:?}", report.recall());
+//! println!("precision: {:?}", report.precision());
+//! println!("corner RMSE: {:?}", report.corner_rmse);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "csv"))]
+//! # fn main() {}
+//! ```
+
+use crate::{
+    detection::Detection, detector::Detector, error::Error, image_buf::Image, pose::TagParams,
+    record::PoseRecord,
+};
+use std::path::{Path, PathBuf};
+
+/// A labelled dataset.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dataset {
+    pub samples: Vec<Sample>,
+}
+
+/// An image along with the tags expected on it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sample {
+    /// The image path.
+    pub image: PathBuf,
+
+    /// The camera parameters and tag size used to estimate poses. Pose
+    /// errors are computed only if it is set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tag_params: Option<TagParams>,
+
+    /// The expected tags. An empty list marks a negative sample.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tags: Vec<LabelledTag>,
+}
+
+/// An expected tag on an image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabelledTag {
+    /// The family name, e.g. "tag36h11". Any family matches if it is
+    /// not set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub family: Option<String>,
+
+    /// The marker ID.
+    pub id: usize,
+
+    /// The corner coordinates in form of `[[x, y]; 4]` in pixels, in
+    /// the same order as [Detection::corners].
+    pub corners: [[f64; 2]; 4],
+
+    /// The known pose of the tag.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pose: Option<PoseRecord>,
+}
+
+impl LabelledTag {
+    /// Gets the center of the corners.
+    pub fn center(&self) -> [f64; 2] {
+        let [sx, sy] = self
+            .corners
+            .iter()
+            .fold([0.0, 0.0], |[sx, sy], [x, y]| [sx + x, sy + y]);
+        [sx / 4.0, sy / 4.0]
+    }
+}
+
+/// Parameters for [Dataset::evaluate].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalParams {
+    /// The maximum distance in pixels between the centers of a
+    /// detection and a label to be matched.
+    pub match_distance: f64,
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        Self {
+            match_distance: 5.0,
+        }
+    }
+}
+
+/// The accuracy summary of an evaluation.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvalReport {
+    /// The number of evaluated images.
+    pub num_images: usize,
+
+    /// The number of detections matched to labels.
+    pub true_positives: usize,
+
+    /// The number of detections not matched to any label.
+    pub false_positives: usize,
+
+    /// The number of labels not matched to any detection.
+    pub false_negatives: usize,
+
+    /// The root mean square distance in pixels between the corners of
+    /// matched detections and labels. It is `None` if nothing is
+    /// matched.
+    pub corner_rmse: Option<f64>,
+
+    /// The pose error over matched labels with known poses. It is
+    /// `None` if no pose is compared.
+    pub pose_error: Option<PoseError>,
+}
+
+impl EvalReport {
+    /// Gets the fraction of labels that are detected.
+    ///
+    /// It is `None` if the dataset has no labels.
+    pub fn recall(&self) -> Option<f64> {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        )
+    }
+
+    /// Gets the fraction of detections that match labels.
+    ///
+    /// It is `None` if nothing is detected.
+    pub fn precision(&self) -> Option<f64> {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        )
+    }
+}
+
+/// The error of estimated poses against known poses.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseError {
+    /// The number of compared poses.
+    pub count: usize,
+
+    /// The number of matched labels whose pose cannot be estimated.
+    pub failures: usize,
+
+    /// The mean translation error in the same unit as `tagsize`.
+    pub mean_translation: f64,
+
+    /// The maximum translation error in the same unit as `tagsize`.
+    pub max_translation: f64,
+
+    /// The mean rotation error in degrees.
+    pub mean_rotation_degrees: f64,
+
+    /// The maximum rotation error in degrees.
+    pub max_rotation_degrees: f64,
+}
+
+impl Dataset {
+    /// Load a dataset from a CSV file.
+    ///
+    /// The file starts with a header line naming the columns `image`,
+    /// `id` and `x0`, `y0`, ..., `x3`, `y3` for the corners, in any
+    /// order. An optional `family` column restricts the matched family.
+    /// Each following line describes a tag, and lines with the same
+    /// image are grouped into one sample. A line with an empty `id`
+    /// marks an image without tags. Fields are trimmed and can be
+    /// quoted with double quotes.
+    ///
+    /// Relative image paths are resolved against the directory of the
+    /// CSV file. Poses cannot be labelled in CSV files.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_file<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = read_to_string(path)?;
+        let mut dataset = Self::from_csv_str(&text)?;
+        dataset.resolve_paths(path);
+        Ok(dataset)
+    }
+
+    /// Parse a dataset from CSV text.
+    ///
+    /// See [from_csv_file](Dataset::from_csv_file) for the format.
+    /// Image paths are kept as they are.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_str(text: &str) -> Result<Self, Error> {
+        let csv_error = |err: csv::Error| Error::LoadDatasetError {
+            reason: format!("failed to parse CSV: {err}"),
+        };
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .flexible(true)
+            .from_reader(text.as_bytes());
+
+        let header = reader.headers().map_err(csv_error)?.clone();
+        if header.is_empty() {
+            return Err(Error::LoadDatasetError {
+                reason: "the CSV header is missing".to_string(),
+            });
+        }
+        let column = |name: &str| header.iter().position(|field| field == name);
+        let required = |name: &str| {
+            column(name).ok_or_else(|| Error::LoadDatasetError {
+                reason: format!("the CSV column '{name}' is missing"),
+            })
+        };
+
+        let image_col = required("image")?;
+        let id_col = required("id")?;
+        let family_col = column("family");
+        let corner_cols = (0..4)
+            .map(|index| {
+                Ok([
+                    required(&format!("x{index}"))?,
+                    required(&format!("y{index}"))?,
+                ])
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut samples: Vec<Sample> = vec![];
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let field = |col: usize| record.get(col).unwrap_or("");
+            let lineno = record.position().map_or(0, |pos| pos.line());
+            let parse_error = |name: &str| Error::LoadDatasetError {
+                reason: format!("invalid {name} at line {lineno}"),
+            };
+
+            let image = PathBuf::from(field(image_col));
+            if image.as_os_str().is_empty() {
+                return Err(parse_error("image"));
+            }
+            let sample = match samples.iter_mut().position(|sample| sample.image == image) {
+                Some(index) => &mut samples[index],
+                None => {
+                    samples.push(Sample {
+                        image,
+                        tag_params: None,
+                        tags: vec![],
+                    });
+                    samples.last_mut().unwrap()
+                }
+            };
+
+            if field(id_col).is_empty() {
+                continue;
+            }
+            let id = field(id_col).parse().map_err(|_| parse_error("id"))?;
+            let family = family_col
+                .map(field)
+                .filter(|family| !family.is_empty())
+                .map(str::to_string);
+            let mut corners = [[0.0; 2]; 4];
+            for (corner, cols) in corners.iter_mut().zip(&corner_cols) {
+                for (value, &col) in corner.iter_mut().zip(cols) {
+                    *value = field(col).parse().map_err(|_| parse_error("corner"))?;
+                }
+            }
+
+            sample.tags.push(LabelledTag {
+                family,
+                id,
+                corners,
+                pose: None,
+            });
+        }
+
+        Ok(Self { samples })
+    }
+
+    /// Load a dataset from a JSON file.
+    ///
+    /// The file contains the serialized [Dataset]. Relative image
+    /// paths are resolved against the directory of the JSON file.
+    #[cfg(feature = "serde")]
+    pub fn from_json_file<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = read_to_string(path)?;
+        let mut dataset: Self =
+            serde_json::from_str(&text).map_err(|err| Error::LoadDatasetError {
+                reason: format!("failed to parse '{}': {err}", path.display()),
+            })?;
+        dataset.resolve_paths(path);
+        Ok(dataset)
+    }
+
+    /// Gets the total number of labelled tags.
+    pub fn num_tags(&self) -> usize {
+        self.samples.iter().map(|sample| sample.tags.len()).sum()
+    }
+
+    /// Run the detector on each image loaded from a PNM file and
+    /// evaluate the detections.
+    ///
+    /// Only PNM images can be loaded, because the crate does not decode
+    /// other formats. Call [evaluate_with](Dataset::evaluate_with) with
+    /// a loader for other formats, such as the conversions in the
+    /// `apriltag-image` crate.
+    pub fn evaluate(
+        &self,
+        detector: &mut Detector,
+        params: &EvalParams,
+    ) -> Result<EvalReport, Error> {
+        self.evaluate_with(detector, params, |path| {
+            let path = path.to_str().ok_or_else(|| Error::CreateImageError {
+                reason: format!("the path '{}' is not valid UTF-8", path.display()),
+            })?;
+            Image::from_pnm_file(path)
+        })
+    }
+
+    /// Run the detector on each image loaded by the `load` function and
+    /// evaluate the detections.
+    ///
+    /// It stops at the first image that fails to load.
+    pub fn evaluate_with<F>(
+        &self,
+        detector: &mut Detector,
+        params: &EvalParams,
+        mut load: F,
+    ) -> Result<EvalReport, Error>
+    where
+        F: FnMut(&Path) -> Result<Image, Error>,
+    {
+        let mut evaluation = Evaluation::new(params.clone());
+
+        for sample in &self.samples {
+            let image = load(&sample.image)?;
+            let detections = detector.detect(&image);
+            evaluation.add_sample(sample, &detections);
+        }
+
+        Ok(evaluation.finish())
+    }
+
+    /// Resolve relative image paths against the directory of the
+    /// dataset file.
+    #[cfg(any(feature = "csv", feature = "serde"))]
+    fn resolve_paths(&mut self, dataset_path: &Path) {
+        let dir = match dataset_path.parent() {
+            Some(dir) => dir,
+            None => return,
+        };
+        for sample in &mut self.samples {
+            if sample.image.is_relative() {
+                sample.image = dir.join(&sample.image);
+            }
+        }
+    }
+}
+
+/// An evaluation on detections produced by the caller.
+///
+/// It is used by [Dataset::evaluate_with], and is useful when the
+/// caller runs the detector itself, e.g. to measure the latency.
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    params: EvalParams,
+    num_images: usize,
+    true_positives: usize,
+    false_positives: usize,
+    false_negatives: usize,
+    corner_sq_sum: f64,
+    num_corners: usize,
+    translation_errors: Vec<f64>,
+    rotation_errors: Vec<f64>,
+    pose_failures: usize,
+}
+
+impl Evaluation {
+    /// Start an evaluation with no samples.
+    pub fn new(params: EvalParams) -> Self {
+        Self {
+            params,
+            num_images: 0,
+            true_positives: 0,
+            false_positives: 0,
+            false_negatives: 0,
+            corner_sq_sum: 0.0,
+            num_corners: 0,
+            translation_errors: vec![],
+            rotation_errors: vec![],
+            pose_failures: 0,
+        }
+    }
+
+    /// Match the detections on the image of a sample to its labels.
+    ///
+    /// Each label is matched to the closest unmatched detection with the
+    /// same ID and family. The other detections count as false
+    /// positives.
+    pub fn add_sample(&mut self, sample: &Sample, detections: &[Detection]) {
+        self.num_images += 1;
+        let mut matched = vec![false; detections.len()];
+
+        for tag in &sample.tags {
+            let [lx, ly] = tag.center();
+
+            // Pick the closest unmatched detection with the same ID.
+            let best = detections
+                .iter()
+                .enumerate()
+                .filter(|(index, detection)| {
+                    !matched[*index]
+                        && detection.id() == tag.id
+                        && tag
+                            .family
+                            .as_ref()
+                            .is_none_or(|family| *family == detection.family_name())
+                })
+                .map(|(index, detection)| {
+                    let [dx, dy] = detection.center();
+                    (index, (dx - lx).hypot(dy - ly))
+                })
+                .filter(|&(_, distance)| distance <= self.params.match_distance)
+                .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs));
+
+            let Some((index, _)) = best else {
+                self.false_negatives += 1;
+                continue;
+            };
+            matched[index] = true;
+            self.true_positives += 1;
+
+            let detection = &detections[index];
+            for ([dx, dy], [lx, ly]) in detection.corners().iter().zip(&tag.corners) {
+                self.corner_sq_sum += (dx - lx).powi(2) + (dy - ly).powi(2);
+                self.num_corners += 1;
+            }
+
+            if let (Some(expect), Some(tag_params)) = (&tag.pose, &sample.tag_params) {
+                match detection.estimate_tag_pose(tag_params) {
+                    Some(pose) => {
+                        let pose = pose.to_record();
+                        self.translation_errors
+                            .push(translation_error(&pose, expect));
+                        self.rotation_errors.push(rotation_error(&pose, expect));
+                    }
+                    None => self.pose_failures += 1,
+                }
+            }
+        }
+
+        self.false_positives += matched.iter().filter(|&&matched| !matched).count();
+    }
+
+    /// Summarize the added samples.
+    pub fn finish(self) -> EvalReport {
+        let corner_rmse =
+            (self.num_corners > 0).then(|| (self.corner_sq_sum / self.num_corners as f64).sqrt());
+
+        let count = self.translation_errors.len();
+        let pose_error = (count > 0 || self.pose_failures > 0).then(|| {
+            let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len().max(1) as f64;
+            let max = |values: &[f64]| values.iter().copied().fold(0.0, f64::max);
+            PoseError {
+                count,
+                failures: self.pose_failures,
+                mean_translation: mean(&self.translation_errors),
+                max_translation: max(&self.translation_errors),
+                mean_rotation_degrees: mean(&self.rotation_errors),
+                max_rotation_degrees: max(&self.rotation_errors),
+            }
+        });
+
+        EvalReport {
+            num_images: self.num_images,
+            true_positives: self.true_positives,
+            false_positives: self.false_positives,
+            false_negatives: self.false_negatives,
+            corner_rmse,
+            pose_error,
+        }
+    }
+}
+
+/// Compute the distance between translations.
+fn translation_error(pose: &PoseRecord, expect: &PoseRecord) -> f64 {
+    pose.translation
+        .iter()
+        .zip(&expect.translation)
+        .map(|(lhs, rhs)| (lhs - rhs).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Compute the angle in degrees of the relative rotation.
+fn rotation_error(pose: &PoseRecord, expect: &PoseRecord) -> f64 {
+    // trace(R1^T R2) = 1 + 2 cos(angle)
+    let trace: f64 = (0..3)
+        .flat_map(|row| (0..3).map(move |col| (row, col)))
+        .map(|(row, col)| pose.rotation[row][col] * expect.rotation[row][col])
+        .sum();
+    ((trace - 1.0) / 2.0).clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+fn ratio(numerator: usize, denominator: usize) -> Option<f64> {
+    (denominator > 0).then(|| numerator as f64 / denominator as f64)
+}
+
+#[cfg(any(feature = "csv", feature = "serde"))]
+fn read_to_string(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|err| Error::LoadDatasetError {
+        reason: format!("failed to read '{}': {err}", path.display()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn parse_csv() {
+        let text = "\
+image,id,family,x0,y0,x1,y1,x2,y2,x3,y3
+a.pnm,3,tag16h5,0,10,10,10,10,0,0,0
+b.pnm,,,,,,,,,,
+
+\"a,\"\"b\"\".pnm\",5,,0,0,0,0,0,0,0,0
+a.pnm,4,,20,30,30,30,30,20,20,20
+";
+        let dataset = Dataset::from_csv_str(text).unwrap();
+        assert_eq!(dataset.samples.len(), 3);
+        assert_eq!(dataset.num_tags(), 3);
+
+        let tags = &dataset.samples[0].tags;
+        assert_eq!(tags[0].family.as_deref(), Some("tag16h5"));
+        assert_eq!(tags[0].center(), [5.0, 5.0]);
+        assert_eq!(tags[1].family, None);
+        assert_eq!(tags[1].corners[3], [20.0, 20.0]);
+        assert!(dataset.samples[1].tags.is_empty());
+        assert_eq!(dataset.samples[2].image, Path::new("a,\"b\".pnm"));
+
+        assert!(Dataset::from_csv_str("").is_err());
+        assert!(Dataset::from_csv_str("image,id\n").is_err());
+        let err = Dataset::from_csv_str(&text.replace(",4,", ",x,")).unwrap_err();
+        assert!(err.to_string().contains("invalid id at line 6"), "{err}");
+    }
+
+    #[test]
+    fn pose_errors() {
+        let identity = PoseRecord {
+            rotation: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            translation: [0.0, 0.0, 1.0],
+        };
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let rotated = PoseRecord {
+            rotation: [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]],
+            translation: [0.3, 0.4, 1.0],
+        };
+        assert!((translation_error(&rotated, &identity) - 0.5).abs() < 1e-9);
+        assert!((rotation_error(&rotated, &identity) - 30.0).abs() < 1e-9);
+        assert!(rotation_error(&identity, &identity).abs() < 1e-6);
+    }
+}
@@ -4,10 +4,17 @@
 //! The images of pre-generated tags can be found at the official repositoy
 //! [https://github.com/AprilRobotics/apriltag-imgs](https://github.com/AprilRobotics/apriltag-imgs).
 
-use crate::error::Error;
+use crate::{error::Error, image_buf::Image};
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
-use std::{fmt::Debug, mem::ManuallyDrop, str::FromStr};
+#[cfg(not(feature = "pure-rust"))]
+use std::{ffi::c_int, mem::ManuallyDrop, ptr::NonNull, slice};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
 
+#[cfg(not(feature = "pure-rust"))]
 pub trait ApriltagFamily
 where
     Self: Debug,
@@ -15,6 +22,7 @@ where
     fn into_raw(self) -> *mut sys::apriltag_family_t;
 }
 
+#[cfg(not(feature = "pure-rust"))]
 macro_rules! declare_family {
     ($name:ident, $init_fn:ident, $fini_fn:ident) => {
         #[derive(Debug)]
@@ -49,6 +57,17 @@ macro_rules! declare_family {
     };
 }
 
+/// Declares a family type that refers to a built-in code table.
+#[cfg(feature = "pure-rust")]
+macro_rules! declare_family {
+    ($name:ident, $init_fn:ident, $fini_fn:ident) => {
+        #[derive(Debug, Default)]
+        pub struct $name {
+            _private: (),
+        }
+    };
+}
+
 declare_family!(Tag16h5, tag16h5_create, tag16h5_destroy);
 declare_family!(Tag25h9, tag25h9_create, tag25h9_destroy);
 declare_family!(Tag36h11, tag36h11_create, tag36h11_destroy);
@@ -142,6 +161,7 @@ impl From<Tag16h5> for Family {
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl ApriltagFamily for Family {
     fn into_raw(self) -> *mut sys::apriltag_family_t {
         match self {
@@ -199,6 +219,220 @@ impl Family {
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
+impl Family {
+    /// Gets the number of tags in the family.
+    pub fn len(&self) -> usize {
+        self.as_raw().ncodes as usize
+    }
+
+    /// Check if the family has no tags.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the width of the tag border in cells.
+    ///
+    /// It is the width that corresponds to the tag size in
+    /// [TagParams](crate::TagParams).
+    pub fn width_at_border(&self) -> usize {
+        self.as_raw().width_at_border as usize
+    }
+
+    /// Gets the width of the tag image in cells.
+    pub fn total_width(&self) -> usize {
+        self.as_raw().total_width as usize
+    }
+
+    /// Render the tag with the ID to an image with a pixel per cell.
+    ///
+    /// It follows `apriltag_to_image()` in the AprilTag library,
+    /// except that cells outside the outer black border that carry no
+    /// data are white, so that the tag can be printed on white paper.
+    pub fn to_image(&self, id: usize) -> Result<Image, Error> {
+        let family = self.as_raw();
+        if id >= self.len() {
+            return Err(Error::CreateImageError {
+                reason: format!(
+                    "tag ID {id} is out of range for a family of {} tags",
+                    self.len()
+                ),
+            });
+        }
+
+        let mut image = unsafe {
+            let ptr = sys::apriltag_to_image(self.as_raw_mut(), id as c_int);
+            let ptr = NonNull::new(ptr).ok_or_else(|| Error::CreateImageError {
+                reason: "apriltag_to_image() failed".to_string(),
+            })?;
+            Image { ptr }
+        };
+
+        let (bit_x, bit_y) = unsafe {
+            let nbits = family.nbits as usize;
+            (
+                slice::from_raw_parts(family.bit_x, nbits),
+                slice::from_raw_parts(family.bit_y, nbits),
+            )
+        };
+        // The bit coordinates are stored as unsigned integers, but can
+        // be negative for bits outside the border.
+        let bits: Vec<(i32, i32)> = bit_x
+            .iter()
+            .zip(bit_y)
+            .map(|(&x, &y)| (x as i32, y as i32))
+            .collect();
+        whiten_outer_cells(
+            &mut image,
+            family.total_width as usize,
+            family.width_at_border as usize,
+            family.reversed_border,
+            &bits,
+        );
+
+        Ok(image)
+    }
+
+    fn as_raw(&self) -> &sys::apriltag_family_t {
+        unsafe { &*self.as_raw_mut() }
+    }
+
+    fn as_raw_mut(&self) -> *mut sys::apriltag_family_t {
+        match self {
+            Family::Tag16h5(family) => family.ptr,
+            Family::Tag25h9(family) => family.ptr,
+            Family::Tag36h11(family) => family.ptr,
+            Family::TagCircle21h7(family) => family.ptr,
+            Family::TagCircle49h12(family) => family.ptr,
+            Family::TagStandard41h12(family) => family.ptr,
+            Family::TagStandard52h13(family) => family.ptr,
+            Family::TagCustom48h12(family) => family.ptr,
+        }
+    }
+}
+
+#[cfg(feature = "pure-rust")]
+impl Family {
+    /// Gets the number of tags in the family.
+    pub fn len(&self) -> usize {
+        self.table().codes.len()
+    }
+
+    /// Check if the family has no tags.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the width of the tag border in cells.
+    ///
+    /// It is the width that corresponds to the tag size in
+    /// [TagParams](crate::TagParams).
+    pub fn width_at_border(&self) -> usize {
+        self.table().width_at_border as usize
+    }
+
+    /// Gets the width of the tag image in cells.
+    pub fn total_width(&self) -> usize {
+        self.table().total_width as usize
+    }
+
+    /// Render the tag with the ID to an image with a pixel per cell.
+    ///
+    /// It follows `apriltag_to_image()` in the AprilTag library,
+    /// except that cells outside the outer black border that carry no
+    /// data are white, so that the tag can be printed on white paper.
+    pub fn to_image(&self, id: usize) -> Result<Image, Error> {
+        let family = self.table();
+        if id >= self.len() {
+            return Err(Error::CreateImageError {
+                reason: format!(
+                    "tag ID {id} is out of range for a family of {} tags",
+                    self.len()
+                ),
+            });
+        }
+
+        let total_width = family.total_width as usize;
+        let width_at_border = family.width_at_border as usize;
+        let mut image = Image::zeros_with_alignment(
+            total_width,
+            total_width,
+            crate::image_buf::DEFAULT_ALIGNMENT_U8,
+        )?;
+
+        // Draw the one cell wide white border, the same as apriltag_to_image().
+        let white_border_width = width_at_border + if family.reversed_border { 0 } else { 2 };
+        let white_border_start = (total_width - white_border_width) / 2;
+        let white_border_end = total_width - 1 - white_border_start;
+        for i in 0..white_border_width - 1 {
+            image[(white_border_start + i, white_border_start)] = 255;
+            image[(white_border_end, white_border_start + i)] = 255;
+            image[(white_border_start + i + 1, white_border_end)] = 255;
+            image[(white_border_start, white_border_start + 1 + i)] = 255;
+        }
+
+        let code = family.codes[id];
+        let border_start = ((total_width - width_at_border) / 2) as i32;
+        let bits: Vec<(i32, i32)> = family
+            .bit_x
+            .iter()
+            .zip(family.bit_y)
+            .map(|(&x, &y)| (x, y))
+            .collect();
+        for (index, &(x, y)) in bits.iter().enumerate() {
+            if code & (1 << (family.nbits as usize - index - 1)) != 0 {
+                image[((x + border_start) as usize, (y + border_start) as usize)] = 255;
+            }
+        }
+
+        whiten_outer_cells(
+            &mut image,
+            total_width,
+            width_at_border,
+            family.reversed_border,
+            &bits,
+        );
+
+        Ok(image)
+    }
+
+    pub(crate) fn table(&self) -> &'static crate::native::families::FamilyTable {
+        crate::native::families::table(self.kind())
+    }
+}
+
+/// Paint the cells outside the outer black border white, except for
+/// data bits.
+///
+/// The bit coordinates are relative to the border.
+fn whiten_outer_cells(
+    image: &mut Image,
+    total_width: usize,
+    width_at_border: usize,
+    reversed_border: bool,
+    bits: &[(i32, i32)],
+) {
+    let black_border_offset = if reversed_border {
+        (total_width - width_at_border) / 2 - 1
+    } else {
+        (total_width - width_at_border) / 2
+    };
+    let border_start = ((total_width - width_at_border) / 2) as i32;
+    let data_cells: Vec<(usize, usize)> = bits
+        .iter()
+        .map(|&(x, y)| ((x + border_start) as usize, (y + border_start) as usize))
+        .collect();
+
+    for y in 0..total_width {
+        for x in 0..total_width {
+            let ring = x.min(y).min(total_width - 1 - x).min(total_width - 1 - y);
+            if ring < black_border_offset && !data_cells.contains(&(x, y)) {
+                image[(x, y)] = 255;
+            }
+        }
+    }
+}
+
 impl FromStr for Family {
     type Err = Error;
 
@@ -228,3 +462,117 @@ impl FromStr for Family {
         Ok(family)
     }
 }
+
+/// The identifier of a pre-generated tag family.
+///
+/// Unlike [Family], it does not hold the tag codes and can be copied,
+/// compared and stored in configurations.
+///
+/// ```rust
+/// use apriltag::families::FamilyKind;
+/// let kind: FamilyKind = "tag36h11".parse().unwrap();
+/// assert_eq!(kind.name(), "tag36h11");
+/// let family = kind.create();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FamilyKind {
+    #[cfg_attr(feature = "serde", serde(rename = "tag16h5"))]
+    Tag16h5,
+    #[cfg_attr(feature = "serde", serde(rename = "tag25h9"))]
+    Tag25h9,
+    #[cfg_attr(feature = "serde", serde(rename = "tag36h11"))]
+    Tag36h11,
+    #[cfg_attr(feature = "serde", serde(rename = "tagCircle21h7"))]
+    TagCircle21h7,
+    #[cfg_attr(feature = "serde", serde(rename = "tagCircle49h12"))]
+    TagCircle49h12,
+    #[cfg_attr(feature = "serde", serde(rename = "tagStandard41h12"))]
+    TagStandard41h12,
+    #[cfg_attr(feature = "serde", serde(rename = "tagStandard52h13"))]
+    TagStandard52h13,
+    #[cfg_attr(feature = "serde", serde(rename = "tagCustom48h12"))]
+    TagCustom48h12,
+}
+
+impl FamilyKind {
+    /// All pre-generated tag families.
+    pub const ALL: [FamilyKind; 8] = [
+        Self::Tag16h5,
+        Self::Tag25h9,
+        Self::Tag36h11,
+        Self::TagCircle21h7,
+        Self::TagCircle49h12,
+        Self::TagStandard41h12,
+        Self::TagStandard52h13,
+        Self::TagCustom48h12,
+    ];
+
+    /// Gets the family name used by the AprilTag library.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Tag16h5 => "tag16h5",
+            Self::Tag25h9 => "tag25h9",
+            Self::Tag36h11 => "tag36h11",
+            Self::TagCircle21h7 => "tagCircle21h7",
+            Self::TagCircle49h12 => "tagCircle49h12",
+            Self::TagStandard41h12 => "tagStandard41h12",
+            Self::TagStandard52h13 => "tagStandard52h13",
+            Self::TagCustom48h12 => "tagCustom48h12",
+        }
+    }
+
+    /// Create the [Family] instance.
+    pub fn create(&self) -> Family {
+        match self {
+            Self::Tag16h5 => Family::tag_16h5(),
+            Self::Tag25h9 => Family::tag_25h9(),
+            Self::Tag36h11 => Family::tag_36h11(),
+            Self::TagCircle21h7 => Family::tag_circle_21h7(),
+            Self::TagCircle49h12 => Family::tag_circle_49h12(),
+            Self::TagStandard41h12 => Family::tag_standard_41h12(),
+            Self::TagStandard52h13 => Family::tag_standard_52h13(),
+            Self::TagCustom48h12 => Family::tag_custom_48h12(),
+        }
+    }
+}
+
+impl Family {
+    /// Gets the identifier of the family.
+    pub fn kind(&self) -> FamilyKind {
+        match self {
+            Family::Tag16h5(_) => FamilyKind::Tag16h5,
+            Family::Tag25h9(_) => FamilyKind::Tag25h9,
+            Family::Tag36h11(_) => FamilyKind::Tag36h11,
+            Family::TagCircle21h7(_) => FamilyKind::TagCircle21h7,
+            Family::TagCircle49h12(_) => FamilyKind::TagCircle49h12,
+            Family::TagStandard41h12(_) => FamilyKind::TagStandard41h12,
+            Family::TagStandard52h13(_) => FamilyKind::TagStandard52h13,
+            Family::TagCustom48h12(_) => FamilyKind::TagCustom48h12,
+        }
+    }
+}
+
+impl From<FamilyKind> for Family {
+    fn from(kind: FamilyKind) -> Self {
+        kind.create()
+    }
+}
+
+impl FromStr for FamilyKind {
+    type Err = Error;
+
+    /// Parse the family name. See [Family::from_str] for supported names.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name() == text)
+            .ok_or_else(|| Error::ParseFamilyStringError(text.to_owned()))
+    }
+}
+
+impl Display for FamilyKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
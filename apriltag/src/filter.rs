@@ -0,0 +1,357 @@
+//! Post-filtering of detections.
+//!
+//! Mirrored, repeated or overlapping candidates can make the detector
+//! report the same tag several times, and small families such as
+//! `tag16h5` produce false positives with low decision margins. A
+//! [FilterParams] set by [filter](crate::DetectorBuilder::filter)
+//! removes them from the results of [detect](crate::Detector::detect).
+//! It can also be applied on detections and records directly.
+//!
+//! ```no_run
+//! use apriltag::{filter::FilterParams, Detector, Family, FamilyKind};
+//!
+//! let detector = Detector::builder()
+//!     .add_family_bits(Family::tag_16h5(), 0)
+//!     .filter(FilterParams {
+//!         min_decision_margin: Some(40.0),
+//!         max_hamming: Some(0),
+//!         allowed_ids: [(FamilyKind::Tag16h5, (0..8).collect())].into(),
+//!         unique_ids: true,
+//!         ..FilterParams::default()
+//!     })
+//!     .build()?;
+//! # Ok::<(), apriltag::Error>(())
+//! ```
+
+use crate::{
+    detection::Detection, error::Error, families::FamilyKind, overlap, record::DetectionRecord,
+};
+use std::{cmp::Ordering, collections::BTreeMap};
+
+/// Parameters of the detection filter.
+///
+/// Among the detections of the same family and id, the best one has
+/// the greatest decision margin, and the one with the fewer corrected
+/// bits on ties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct FilterParams {
+    /// Drop detections with smaller decision margins.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min_decision_margin: Option<f32>,
+
+    /// Drop detections with more corrected bits.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_hamming: Option<usize>,
+
+    /// The allowed ids of each family. Detections of a listed family
+    /// with other ids are dropped. Families not listed are not
+    /// restricted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allowed_ids: BTreeMap<FamilyKind, Vec<usize>>,
+
+    /// Suppress the detections of the same family and id whose corners
+    /// overlap a better one by at least this intersection over union,
+    /// in range 0 to 1. It is disabled if unset.
+    #[cfg_attr(feature = "serde", serde(default = "default_duplicate_overlap"))]
+    pub duplicate_overlap: Option<f64>,
+
+    /// Keep only the best detection of each family and id, wherever
+    /// the others are.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub unique_ids: bool,
+}
+
+impl FilterParams {
+    /// Check that the parameters are in range.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: String| Err(Error::FilterError { reason });
+
+        if let Some(margin) = self.min_decision_margin {
+            if !margin.is_finite() {
+                return invalid(format!("min decision margin ({margin}) must be finite"));
+            }
+        }
+        if let Some(overlap) = self.duplicate_overlap {
+            if !(0.0..=1.0).contains(&overlap) {
+                return invalid(format!(
+                    "duplicate overlap ({overlap}) must be in range 0 to 1"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Filter the detections. The order of the kept detections is
+    /// preserved.
+    pub fn apply(&self, detections: Vec<Detection>) -> Vec<Detection> {
+        self.apply_candidates(detections)
+    }
+
+    /// Filter the detection records. The order of the kept records is
+    /// preserved.
+    pub fn apply_records(&self, records: Vec<DetectionRecord>) -> Vec<DetectionRecord> {
+        self.apply_candidates(records)
+    }
+
+    fn apply_candidates<T>(&self, candidates: Vec<T>) -> Vec<T>
+    where
+        T: Candidate,
+    {
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .filter(|candidate| self.accepts(candidate))
+            .collect();
+
+        // Visit the candidates from the best and keep those not
+        // suppressed by a kept one.
+        let mut order: Vec<usize> = (0..candidates.len()).collect();
+        order.sort_by(|&lhs, &rhs| compare_quality(&candidates[rhs], &candidates[lhs]));
+
+        let mut keep = vec![false; candidates.len()];
+        let mut kept: Vec<usize> = vec![];
+        for index in order {
+            let candidate = &candidates[index];
+            let suppressed = kept.iter().any(|&other| {
+                let other = &candidates[other];
+                other.id() == candidate.id()
+                    && other.family_name() == candidate.family_name()
+                    && self.is_duplicate(other, candidate)
+            });
+            if !suppressed {
+                keep[index] = true;
+                kept.push(index);
+            }
+        }
+
+        candidates
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(candidate, keep)| keep.then_some(candidate))
+            .collect()
+    }
+
+    /// Check the decision margin, the hamming distance and the id.
+    fn accepts<T>(&self, candidate: &T) -> bool
+    where
+        T: Candidate,
+    {
+        if let Some(margin) = self.min_decision_margin {
+            if candidate.decision_margin() < margin {
+                return false;
+            }
+        }
+        if let Some(hamming) = self.max_hamming {
+            if candidate.hamming() > hamming {
+                return false;
+            }
+        }
+        if !self.allowed_ids.is_empty() {
+            let allowed = candidate
+                .family_name()
+                .parse::<FamilyKind>()
+                .ok()
+                .and_then(|kind| self.allowed_ids.get(&kind));
+            if let Some(ids) = allowed {
+                if !ids.contains(&candidate.id()) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Check whether a detection of the same family and id as a better
+    /// one is suppressed.
+    fn is_duplicate<T>(&self, better: &T, candidate: &T) -> bool
+    where
+        T: Candidate,
+    {
+        if self.unique_ids {
+            return true;
+        }
+        match self.duplicate_overlap {
+            Some(min_overlap) => {
+                overlap::quad_iou(&better.corners(), &candidate.corners()) >= min_overlap
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for FilterParams {
+    /// Suppress overlapping duplicates only.
+    fn default() -> Self {
+        Self {
+            min_decision_margin: None,
+            max_hamming: None,
+            allowed_ids: BTreeMap::new(),
+            duplicate_overlap: default_duplicate_overlap(),
+            unique_ids: false,
+        }
+    }
+}
+
+fn default_duplicate_overlap() -> Option<f64> {
+    Some(0.5)
+}
+
+/// Order by the decision margin, then by the reverse of the hamming
+/// distance.
+fn compare_quality<T>(lhs: &T, rhs: &T) -> Ordering
+where
+    T: Candidate,
+{
+    lhs.decision_margin()
+        .total_cmp(&rhs.decision_margin())
+        .then_with(|| rhs.hamming().cmp(&lhs.hamming()))
+}
+
+/// The properties of a detection used by the filter.
+trait Candidate {
+    fn family_name(&self) -> String;
+    fn id(&self) -> usize;
+    fn hamming(&self) -> usize;
+    fn decision_margin(&self) -> f32;
+    fn corners(&self) -> [[f64; 2]; 4];
+}
+
+impl Candidate for Detection {
+    fn family_name(&self) -> String {
+        Detection::family_name(self)
+    }
+
+    fn id(&self) -> usize {
+        Detection::id(self)
+    }
+
+    fn hamming(&self) -> usize {
+        Detection::hamming(self)
+    }
+
+    fn decision_margin(&self) -> f32 {
+        Detection::decision_margin(self)
+    }
+
+    fn corners(&self) -> [[f64; 2]; 4] {
+        Detection::corners(self)
+    }
+}
+
+impl Candidate for DetectionRecord {
+    fn family_name(&self) -> String {
+        self.family.clone()
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn hamming(&self) -> usize {
+        self.hamming
+    }
+
+    fn decision_margin(&self) -> f32 {
+        self.decision_margin
+    }
+
+    fn corners(&self) -> [[f64; 2]; 4] {
+        self.corners
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(family: &str, id: usize, hamming: usize, margin: f32, x: f64) -> DetectionRecord {
+        DetectionRecord {
+            family: family.to_string(),
+            id,
+            hamming,
+            decision_margin: margin,
+            center: [x + 5.0, 5.0],
+            corners: [[x, 0.0], [x + 10.0, 0.0], [x + 10.0, 10.0], [x, 10.0]],
+            homography: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    fn summary(records: &[DetectionRecord]) -> Vec<(usize, f32)> {
+        records
+            .iter()
+            .map(|record| (record.id, record.decision_margin))
+            .collect()
+    }
+
+    #[test]
+    fn suppress_overlapping_duplicates() {
+        let records = vec![
+            record("tag36h11", 1, 0, 50.0, 0.0),
+            record("tag36h11", 1, 0, 80.0, 2.0),
+            record("tag36h11", 1, 0, 60.0, 100.0),
+            record("tag36h11", 2, 0, 30.0, 1.0),
+            record("tag16h5", 1, 0, 40.0, 0.0),
+        ];
+        let filtered = FilterParams::default().apply_records(records);
+        assert_eq!(
+            summary(&filtered),
+            [(1, 80.0), (1, 60.0), (2, 30.0), (1, 40.0)]
+        );
+    }
+
+    #[test]
+    fn keep_best_of_unique_ids() {
+        let records = vec![
+            record("tag36h11", 1, 1, 50.0, 0.0),
+            record("tag36h11", 1, 0, 50.0, 100.0),
+            record("tag36h11", 1, 0, 20.0, 200.0),
+        ];
+        let params = FilterParams {
+            unique_ids: true,
+            ..FilterParams::default()
+        };
+        let filtered = params.apply_records(records);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].hamming, 0);
+        assert_eq!(filtered[0].corners[0], [100.0, 0.0]);
+    }
+
+    #[test]
+    fn drop_by_margin_hamming_and_id() {
+        let records = vec![
+            record("tag16h5", 0, 0, 80.0, 0.0),
+            record("tag16h5", 9, 0, 80.0, 20.0),
+            record("tag16h5", 3, 1, 80.0, 40.0),
+            record("tag16h5", 4, 0, 10.0, 60.0),
+            record("tag36h11", 9, 0, 80.0, 80.0),
+        ];
+        let params = FilterParams {
+            min_decision_margin: Some(30.0),
+            max_hamming: Some(0),
+            allowed_ids: [(FamilyKind::Tag16h5, vec![0, 3, 4])].into(),
+            ..FilterParams::default()
+        };
+        let filtered = params.apply_records(records);
+        assert_eq!(summary(&filtered), [(0, 80.0), (9, 80.0)]);
+        assert_eq!(filtered[1].family, "tag36h11");
+    }
+
+    #[test]
+    fn reject_invalid_params() {
+        let params = FilterParams {
+            duplicate_overlap: Some(1.5),
+            ..FilterParams::default()
+        };
+        assert!(matches!(params.validate(), Err(Error::FilterError { .. })));
+
+        let params = FilterParams {
+            min_decision_margin: Some(f32::NAN),
+            ..FilterParams::default()
+        };
+        assert!(params.validate().is_err());
+    }
+}
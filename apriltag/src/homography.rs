@@ -0,0 +1,71 @@
+//! Homography estimation between tag coordinates and image pixels.
+
+/// The tag coordinates of the detection corners.
+///
+/// The order follows the corners of [Detection](crate::Detection),
+/// which wind counter-clockwise around the tag starting at `[-1, 1]`.
+pub(crate) const TAG_CORNERS: [[f64; 2]; 4] = [[-1.0, 1.0], [1.0, 1.0], [1.0, -1.0], [-1.0, -1.0]];
+
+/// Compute the homography that maps tag coordinates to pixels.
+///
+/// Each correspondence is in form of `[tag_x, tag_y, pixel_x, pixel_y]`.
+/// It returns `None` if the correspondences are degenerate.
+pub(crate) fn compute(corr: &[[f64; 4]; 4]) -> Option<[[f64; 3]; 3]> {
+    let mut a = [[0f64; 9]; 8];
+
+    for (index, &[x, y, u, v]) in corr.iter().enumerate() {
+        a[index * 2] = [x, y, 1.0, 0.0, 0.0, 0.0, -x * u, -y * u, u];
+        a[index * 2 + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -x * v, -y * v, v];
+    }
+
+    // Gaussian elimination with partial pivoting.
+    for col in 0..8 {
+        let pivot = (col..8)
+            .max_by(|&lhs, &rhs| a[lhs][col].abs().total_cmp(&a[rhs][col].abs()))
+            .unwrap();
+
+        if a[pivot][col].abs() < 1e-10 {
+            return None;
+        }
+        a.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            row[col] = 0.0;
+            row.iter_mut()
+                .zip(pivot_row)
+                .skip(col + 1)
+                .for_each(|(value, pivot)| *value -= factor * pivot);
+        }
+    }
+
+    // Back substitution.
+    let mut h = [0f64; 8];
+    for col in (0..8).rev() {
+        let sum: f64 = ((col + 1)..8).map(|index| a[col][index] * h[index]).sum();
+        h[col] = (a[col][8] - sum) / a[col][col];
+    }
+
+    Some([[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], 1.0]])
+}
+
+/// Compute the homography that maps tag coordinates to the given corners.
+///
+/// The corners are ordered in the same way as [TAG_CORNERS].
+pub(crate) fn from_corners(corners: &[[f64; 2]; 4]) -> Option<[[f64; 3]; 3]> {
+    let mut corr = [[0f64; 4]; 4];
+    for (index, (&[x, y], &[u, v])) in TAG_CORNERS.iter().zip(corners).enumerate() {
+        corr[index] = [x, y, u, v];
+    }
+    compute(&corr)
+}
+
+/// Project a point in tag coordinates to pixels.
+pub(crate) fn project(h: &[[f64; 3]; 3], x: f64, y: f64) -> [f64; 2] {
+    let xx = h[0][0] * x + h[0][1] * y + h[0][2];
+    let yy = h[1][0] * x + h[1][1] * y + h[1][2];
+    let zz = h[2][0] * x + h[2][1] * y + h[2][2];
+    [xx / zz, yy / zz]
+}
@@ -7,25 +7,47 @@
 //! from third-party types using extension crates.
 
 use crate::Error;
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
+#[cfg(not(feature = "pure-rust"))]
 use std::{
     ffi::{c_int, c_uint, CString},
-    iter,
     mem::ManuallyDrop,
-    ops::{Index, IndexMut},
     ptr::NonNull,
     slice,
 };
+use std::{
+    iter,
+    ops::{Index, IndexMut},
+};
 
 pub const DEFAULT_ALIGNMENT_U8: usize = 96;
 
 /// The single-channel image with pixels in bytes.
+#[cfg(not(feature = "pure-rust"))]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Image {
     pub(crate) ptr: NonNull<sys::image_u8_t>,
 }
 
+/// The single-channel image with pixels in bytes.
+#[cfg(feature = "pure-rust")]
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    stride: usize,
+    buf: Vec<u8>,
+}
+
+// The image owns its buffer, and mutation requires exclusive access.
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Send for Image {}
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Sync for Image {}
+
+#[cfg(not(feature = "pure-rust"))]
 impl Image {
     /// Give width and height and create an uninitialized image.
     ///
@@ -128,42 +150,6 @@ impl Image {
         Ok(Self { ptr })
     }
 
-    /// Create an iterator traversing pixels in row-major order.
-    pub fn samples_iter(&self) -> impl Iterator<Item = u8> + '_ {
-        let height = self.height();
-        let width = self.width();
-        let stride = self.stride();
-        let buffer = self.as_slice();
-
-        let row_offsets =
-            iter::successors(Some(0), move |&offset| Some(offset + stride)).take(height);
-        let pixel_offsets =
-            row_offsets.flat_map(move |row_offset| row_offset..(row_offset + width));
-        pixel_offsets.map(move |offset| buffer[offset])
-    }
-
-    /// Create an iterator that traverses pixels with pixel positions
-    /// in row-major order.
-    ///
-    /// The iterator item is in (x, y, pixel_value) format.
-    pub fn indexed_samples_iter(&self) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
-        let height = self.height();
-        let width = self.width();
-        let stride = self.stride();
-        let buffer = self.as_slice();
-
-        let row_offsets = iter::successors(Some(0), move |&offset| Some(offset + stride))
-            .take(height)
-            .enumerate();
-        let pixel_offsets = row_offsets.flat_map(move |(row, row_offset)| {
-            let offsets = row_offset..(row_offset + width);
-            offsets
-                .enumerate()
-                .map(move |(col, offset)| (row, col, offset))
-        });
-        pixel_offsets.map(move |(row, col, offset)| (col, row, buffer[offset]))
-    }
-
     /// Gets the image width.
     pub fn width(&self) -> usize {
         unsafe { self.ptr.as_ref().width as usize }
@@ -216,6 +202,142 @@ impl Image {
     }
 }
 
+#[cfg(feature = "pure-rust")]
+impl Image {
+    /// Give width and height and create an uninitialized image.
+    ///
+    /// # Safety
+    ///
+    /// After the image is returned, the caller must explicitly
+    /// initialize the image buffer.
+    pub unsafe fn new_uinit(width: usize, height: usize) -> Result<Self, Error> {
+        Self::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)
+    }
+
+    /// Give width, height and stride and create an uninitialized image.
+    ///
+    /// # Safety
+    ///
+    /// After the image is returned, the caller must explicitly
+    /// initialize the image buffer.
+    pub unsafe fn new_uinit_with_stride(
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Result<Self, Error> {
+        Self::zeros_with_stride(width, height, stride)
+    }
+
+    /// Create an image from a PNM file.
+    pub fn from_pnm_file(path: &str) -> Result<Self, Error> {
+        Self::from_pnm_file_with_alignment(path, DEFAULT_ALIGNMENT_U8)
+    }
+
+    /// Create an image from a PNM file with a specified alignment.
+    pub fn from_pnm_file_with_alignment(path: &str, alignment: usize) -> Result<Self, Error> {
+        crate::native::pnm::read_file(path.as_ref(), alignment)
+    }
+
+    /// Create a zerod image.
+    ///
+    /// The `stride` must be more than or equal to `width`. Otherwise it returns `None`.
+    pub fn zeros_with_stride(width: usize, height: usize, stride: usize) -> Result<Self, Error> {
+        if width > stride {
+            return Err(Error::CreateImageError {
+                reason: format!("width ({width}) must be less than or equal to stride ({stride})"),
+            });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            stride,
+            buf: vec![0; stride * height],
+        })
+    }
+
+    /// Create a zerod image.
+    ///
+    /// The `alignment` must be non-zero. Otherwise it returns `None`.
+    pub fn zeros_with_alignment(
+        width: usize,
+        height: usize,
+        alignment: usize,
+    ) -> Result<Self, Error> {
+        if alignment == 0 {
+            return Err(Error::CreateImageError {
+                reason: format!("alignment ({alignment}) must positive"),
+            });
+        }
+
+        let stride = width.div_ceil(alignment) * alignment;
+        Self::zeros_with_stride(width, height, stride)
+    }
+
+    /// Gets the image width.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the image height.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the per-row stride in bytes.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the pixel buffer with size height*stride.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Get the mutable pixel buffer with size height*stride.
+    pub fn as_slice_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+}
+
+impl Image {
+    /// Create an iterator traversing pixels in row-major order.
+    pub fn samples_iter(&self) -> impl Iterator<Item = u8> + '_ {
+        let height = self.height();
+        let width = self.width();
+        let stride = self.stride();
+        let buffer = self.as_slice();
+
+        let row_offsets =
+            iter::successors(Some(0), move |&offset| Some(offset + stride)).take(height);
+        let pixel_offsets =
+            row_offsets.flat_map(move |row_offset| row_offset..(row_offset + width));
+        pixel_offsets.map(move |offset| buffer[offset])
+    }
+
+    /// Create an iterator that traverses pixels with pixel positions
+    /// in row-major order.
+    ///
+    /// The iterator item is in (x, y, pixel_value) format.
+    pub fn indexed_samples_iter(&self) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
+        let height = self.height();
+        let width = self.width();
+        let stride = self.stride();
+        let buffer = self.as_slice();
+
+        let row_offsets = iter::successors(Some(0), move |&offset| Some(offset + stride))
+            .take(height)
+            .enumerate();
+        let pixel_offsets = row_offsets.flat_map(move |(row, row_offset)| {
+            let offsets = row_offset..(row_offset + width);
+            offsets
+                .enumerate()
+                .map(move |(col, offset)| (row, col, offset))
+        });
+        pixel_offsets.map(move |(row, col, offset)| (col, row, buffer[offset]))
+    }
+}
+
 impl Index<(usize, usize)> for Image {
     type Output = u8;
 
@@ -234,16 +356,17 @@ impl IndexMut<(usize, usize)> for Image {
 
 impl AsRef<[u8]> for Image {
     fn as_ref(&self) -> &[u8] {
-        unsafe { slice::from_raw_parts(self.ptr.as_ref().buf, self.stride() * self.height()) }
+        self.as_slice()
     }
 }
 
 impl AsMut<[u8]> for Image {
     fn as_mut(&mut self) -> &mut [u8] {
-        unsafe { slice::from_raw_parts_mut(self.ptr.as_ref().buf, self.stride() * self.height()) }
+        self.as_slice_mut()
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl Clone for Image {
     fn clone(&self) -> Self {
         let cloned_ptr = unsafe { sys::image_u8_copy(self.ptr.as_ptr()) };
@@ -253,6 +376,7 @@ impl Clone for Image {
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl Drop for Image {
     fn drop(&mut self) {
         unsafe {
@@ -262,6 +386,7 @@ impl Drop for Image {
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     use super::*;
 
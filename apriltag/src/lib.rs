@@ -1,28 +1,95 @@
 //! The Rusty AprilTag detector.
 //!
-//! The crate is built on top of [apriltag-sys](apriltag_sys).
+//! The crate is built on top of
+//! [apriltag-sys](https://docs.rs/apriltag-sys) by default.
 //! It provides high level type wrappers on images, detections, and so on.
 //!
+//! The detection backend is selected by cargo features.
+//!
+//! - **c-backend** (default): Run the AprilTag C library through apriltag-sys.
+//! - **pure-rust**: Run a Rust port of the detection pipeline, which
+//!   needs no C compiler and builds for `wasm32` targets. The
+//!   C-specific items, such as `from_raw()`, `into_raw()` and the
+//!   `zarray` module, are not available.
+//!
+//! Exactly one backend must be enabled. Enabling both is a compile
+//! error, because the pure-rust backend does not provide the C API.
+//!
 //! Third-party type conversions are supported by extension crates,
 //! including
 //!
 //! - **apriltag-nalgebra**: Add conversions from/to two dimensional byte matrix in nalgebra crate.
 //! - **apriltag-image**: Add conversions from/to image types in image crate.
+//!
+//! The `TryFrom` conversions from image crate types into [Image] are
+//! implemented in this crate behind the **image** feature, because the
+//! orphan rule forbids them in apriltag-image, which enables the feature.
+
+#[cfg(not(any(feature = "c-backend", feature = "pure-rust")))]
+compile_error!("either the \"c-backend\" or the \"pure-rust\" feature must be enabled");
+
+#[cfg(all(feature = "c-backend", feature = "pure-rust"))]
+compile_error!(
+    "the \"c-backend\" and \"pure-rust\" features are mutually exclusive. \
+     Disable the default features to use the pure-rust backend"
+);
 
+#[cfg(feature = "rayon")]
+pub mod batch;
+pub mod bundle;
+mod clahe;
+pub mod config;
+pub mod debug;
+mod decode;
 pub mod detection;
 pub mod detector;
 pub mod error;
+pub mod eval;
 pub mod families;
+pub mod filter;
+mod homography;
 pub mod image_buf;
+#[cfg(feature = "image")]
+mod luma;
 pub mod matd;
+pub mod multiscale;
+mod native;
+mod overlap;
 pub mod pose;
+pub mod preprocess;
+pub mod record;
+pub mod refine;
+pub mod stats;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod synthetic;
+pub mod tone_map;
+pub mod track;
+mod unionfind;
+#[cfg(not(feature = "pure-rust"))]
 pub mod zarray;
 
+#[cfg(feature = "rayon")]
+pub use batch::BatchDetector;
+pub use bundle::{BundleTag, TagBundle};
+pub use config::{DetectorConfig, FamilyConfig};
+pub use debug::{DebugArtifacts, RejectedQuad};
 pub use detection::Detection;
 pub use detector::{Detector, DetectorBuilder};
 pub use error::Error;
-pub use families::Family;
+pub use families::{Family, FamilyKind};
+pub use filter::FilterParams;
 pub use image_buf::Image;
 pub use matd::MatdRef;
+pub use multiscale::MultiScaleParams;
 pub use pose::{Pose, PoseEstimation, TagParams};
+pub use preprocess::{Preprocess, PreprocessParams};
+pub use record::{DetectionRecord, PoseEstimationRecord, PoseRecord};
+pub use refine::{RefineParams, RefinedCorners};
+pub use stats::DetectorStats;
+#[cfg(feature = "async")]
+pub use stream::DetectorStream;
+pub use tone_map::{AppliedToneMap, ToneMapParams, ToneMapped, ToneMapping};
+pub use track::{Track, Tracker, TrackerParams};
+#[cfg(not(feature = "pure-rust"))]
 pub use zarray::ZArray;
@@ -0,0 +1,317 @@
+//! Conversion from [image] crate types, enabled by the `image` feature.
+//!
+//! Color and high bit-depth samples are converted to 8-bit luma. The
+//! results are identical to `DynamicImage::to_luma8()`, but the samples
+//! are written to the [Image] directly without an intermediate buffer.
+
+use crate::{
+    error::Error,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+};
+use image::{
+    flat::{FlatSamples, SampleLayout},
+    ColorType, DynamicImage, ImageBuffer, Luma, Pixel,
+};
+use std::ops::Deref;
+
+impl TryFrom<&DynamicImage> for Image {
+    type Error = Error;
+
+    /// Convert an image of any color type to luma.
+    fn try_from(from: &DynamicImage) -> Result<Self, Self::Error> {
+        match from {
+            DynamicImage::ImageLuma8(buf) => image_from_buffer(buf, Channels::Luma),
+            DynamicImage::ImageLumaA8(buf) => image_from_buffer(buf, Channels::LumaAlpha),
+            DynamicImage::ImageRgb8(buf) => image_from_buffer(buf, Channels::Rgb),
+            DynamicImage::ImageRgba8(buf) => image_from_buffer(buf, Channels::Rgba),
+            DynamicImage::ImageLuma16(buf) => image_from_buffer(buf, Channels::Luma),
+            DynamicImage::ImageLumaA16(buf) => image_from_buffer(buf, Channels::LumaAlpha),
+            DynamicImage::ImageRgb16(buf) => image_from_buffer(buf, Channels::Rgb),
+            DynamicImage::ImageRgba16(buf) => image_from_buffer(buf, Channels::Rgba),
+            DynamicImage::ImageRgb32F(buf) => image_from_buffer(buf, Channels::Rgb),
+            DynamicImage::ImageRgba32F(buf) => image_from_buffer(buf, Channels::Rgba),
+            _ => Err(Error::UnsupportedColorType {
+                color_type: format!("{:?}", from.color()),
+            }),
+        }
+    }
+}
+
+impl<Buffer> TryFrom<&FlatSamples<Buffer>> for Image
+where
+    Buffer: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    /// Convert flat samples to luma.
+    ///
+    /// `L8`, `La8`, `Rgb8` and `Rgba8` samples are accepted. Samples
+    /// without a color hint are treated as `L8` if they have a single
+    /// channel.
+    fn try_from(from: &FlatSamples<Buffer>) -> Result<Self, Self::Error> {
+        let layout = from.layout;
+        let channels = match from.color_hint {
+            Some(color_type) => Channels::from_color_type_u8(color_type).ok_or_else(|| {
+                Error::UnsupportedColorType {
+                    color_type: format!("{color_type:?}"),
+                }
+            })?,
+            None if layout.channels == 1 => Channels::Luma,
+            None => {
+                return Err(Error::UnsupportedColorType {
+                    color_type: format!("unknown with {} channels", layout.channels),
+                })
+            }
+        };
+
+        let samples = from.samples.as_ref();
+        if (layout.channels as usize) < channels.count() {
+            return Err(Error::ConvertImageError {
+                reason: format!(
+                    "the layout has {} channels, but {} are expected",
+                    layout.channels,
+                    channels.count()
+                ),
+            });
+        }
+        if !layout.fits(samples.len()) {
+            return Err(Error::ConvertImageError {
+                reason: format!("the layout does not fit in {} samples", samples.len()),
+            });
+        }
+
+        let SampleLayout {
+            width,
+            height,
+            width_stride,
+            height_stride,
+            channel_stride,
+            ..
+        } = layout;
+        let width = width as usize;
+        let height = height as usize;
+
+        // Copy row by row if pixels are packed, or gather the samples
+        // of each pixel otherwise.
+        if channel_stride == 1 && width_stride == channels.count() {
+            let row_len = width * channels.count();
+            let rows = (0..height).map(|y| &samples[y * height_stride..][..row_len]);
+            return image_from_rows(width, height, channels, rows);
+        }
+
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+        for y in 0..height {
+            for x in 0..width {
+                let mut pixel = [0; 4];
+                for (channel, sample) in pixel.iter_mut().take(channels.count()).enumerate() {
+                    let index = layout.in_bounds_index(channel as u8, x as u32, y as u32);
+                    *sample = samples[index];
+                }
+                image[(x, y)] = channels.luma(&pixel);
+            }
+        }
+
+        Ok(image)
+    }
+}
+
+impl<Container> TryFrom<&ImageBuffer<Luma<u16>, Container>> for Image
+where
+    Container: Deref<Target = [u16]>,
+{
+    type Error = Error;
+
+    /// Scale 16-bit luma to 8 bits in the same way as `to_luma8()`.
+    ///
+    /// Frames of 10 or 12 bits are better mapped by
+    /// [tone_map](crate::tone_map::tone_map).
+    fn try_from(from: &ImageBuffer<Luma<u16>, Container>) -> Result<Self, Self::Error> {
+        let width = from.width() as usize;
+        let height = from.height() as usize;
+        let rows = from.as_raw().chunks_exact(width.max(1));
+        image_from_rows(width, height, Channels::Luma, rows)
+    }
+}
+
+/// The channels of a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Channels {
+    Luma,
+    LumaAlpha,
+    Rgb,
+    Rgba,
+}
+
+impl Channels {
+    /// Get the channels of an 8-bit color type.
+    fn from_color_type_u8(color_type: ColorType) -> Option<Self> {
+        let channels = match color_type {
+            ColorType::L8 => Self::Luma,
+            ColorType::La8 => Self::LumaAlpha,
+            ColorType::Rgb8 => Self::Rgb,
+            ColorType::Rgba8 => Self::Rgba,
+            _ => return None,
+        };
+        Some(channels)
+    }
+
+    fn count(self) -> usize {
+        match self {
+            Self::Luma => 1,
+            Self::LumaAlpha => 2,
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+
+    /// Compute the luma of a pixel. The alpha channel is ignored.
+    fn luma<T>(self, pixel: &[T]) -> u8
+    where
+        T: Sample,
+    {
+        match self {
+            Self::Luma | Self::LumaAlpha => pixel[0].to_u8(),
+            Self::Rgb | Self::Rgba => T::rgb_luma(pixel[0], pixel[1], pixel[2]).to_u8(),
+        }
+    }
+}
+
+/// A sample type that is converted to 8-bit luma.
+trait Sample: Copy {
+    /// Compute the luma by Rec. 709 weights in the same type, as the
+    /// image crate does.
+    fn rgb_luma(r: Self, g: Self, b: Self) -> Self;
+
+    /// Scale the sample to 8 bits with rounding.
+    fn to_u8(self) -> u8;
+}
+
+impl Sample for u8 {
+    fn rgb_luma(r: Self, g: Self, b: Self) -> Self {
+        ((2126 * r as u32 + 7152 * g as u32 + 722 * b as u32) / 10000) as u8
+    }
+
+    fn to_u8(self) -> u8 {
+        self
+    }
+}
+
+impl Sample for u16 {
+    fn rgb_luma(r: Self, g: Self, b: Self) -> Self {
+        ((2126 * r as u32 + 7152 * g as u32 + 722 * b as u32) / 10000) as u16
+    }
+
+    fn to_u8(self) -> u8 {
+        ((self as u32 + 128) / 257) as u8
+    }
+}
+
+impl Sample for f32 {
+    fn rgb_luma(r: Self, g: Self, b: Self) -> Self {
+        ((2126.0 * r as f64 + 7152.0 * g as f64 + 722.0 * b as f64) / 10000.0) as f32
+    }
+
+    fn to_u8(self) -> u8 {
+        (self.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8
+    }
+}
+
+/// Create an image from rows of packed pixels.
+fn image_from_rows<'a, T, I>(
+    width: usize,
+    height: usize,
+    channels: Channels,
+    rows: I,
+) -> Result<Image, Error>
+where
+    T: Sample + 'a,
+    I: IntoIterator<Item = &'a [T]>,
+{
+    let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+    if width == 0 || height == 0 {
+        return Ok(image);
+    }
+
+    let stride = image.stride();
+    for (dst, src) in image.as_slice_mut().chunks_mut(stride).zip(rows) {
+        let dst = &mut dst[..width];
+
+        match channels {
+            Channels::Luma => dst
+                .iter_mut()
+                .zip(src)
+                .for_each(|(dst, &sample)| *dst = sample.to_u8()),
+            _ => dst
+                .iter_mut()
+                .zip(src.chunks_exact(channels.count()))
+                .for_each(|(dst, pixel)| *dst = channels.luma(pixel)),
+        }
+    }
+
+    Ok(image)
+}
+
+fn image_from_buffer<P>(
+    from: &ImageBuffer<P, Vec<P::Subpixel>>,
+    channels: Channels,
+) -> Result<Image, Error>
+where
+    P: Pixel,
+    P::Subpixel: Sample,
+{
+    let width = from.width() as usize;
+    let height = from.height() as usize;
+    let row_len = (width * channels.count()).max(1);
+    image_from_rows(width, height, channels, from.as_raw().chunks_exact(row_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    #[test]
+    fn sample_to_u8() {
+        assert_eq!(0u16.to_u8(), 0);
+        assert_eq!(257u16.to_u8(), 1);
+        assert_eq!(u16::MAX.to_u8(), 255);
+        assert_eq!((-0.5f32).to_u8(), 0);
+        assert_eq!(0.5f32.to_u8(), 128);
+        assert_eq!(2.0f32.to_u8(), 255);
+    }
+
+    #[test]
+    fn rgb_luma() {
+        assert_eq!(u8::rgb_luma(255, 255, 255), 255);
+        assert_eq!(u8::rgb_luma(255, 0, 0), 54);
+        assert_eq!(u16::rgb_luma(0, u16::MAX, 0), 46870);
+        assert!((f32::rgb_luma(0.0, 0.0, 1.0) - 0.0722).abs() < 1e-6);
+    }
+
+    #[test]
+    fn convert_luma16_vs_to_luma8() {
+        let luma16 = ImageBuffer::from_fn(13, 7, |x, y| Luma([(x * 5000 + y * 300) as u16]));
+        let image = Image::try_from(&luma16).unwrap();
+        let expect = DynamicImage::ImageLuma16(luma16).to_luma8();
+
+        for (x, y, pixel) in expect.enumerate_pixels() {
+            assert_eq!(image[(x as usize, y as usize)], pixel[0]);
+        }
+    }
+
+    #[test]
+    fn reject_unsupported_color_types() {
+        let rgb16 = FlatSamples {
+            samples: vec![0u8; 16],
+            layout: SampleLayout::row_major_packed(1, 4, 4),
+            color_hint: Some(ColorType::Rgb16),
+        };
+        assert!(matches!(
+            Image::try_from(&rgb16),
+            Err(Error::UnsupportedColorType { .. })
+        ));
+
+        let rgb = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(4, 3, Rgb([0, 255, 0])));
+        assert_eq!(Image::try_from(&rgb).unwrap()[(3, 2)], 182);
+    }
+}
@@ -1,36 +1,50 @@
 //! The matrix type.
 
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
 use std::fmt::{self, Debug, Formatter};
 
 /// The wrapper type of a matrix reference.
 pub struct MatdRef<'a> {
-    pub(crate) ref_: &'a sys::matd_t,
+    nrows: usize,
+    ncols: usize,
+    data: &'a [f64],
 }
 
 impl<'a> MatdRef<'a> {
     /// Get number of rows.
     pub fn nrows(&self) -> usize {
-        self.ref_.nrows as usize
+        self.nrows
     }
 
     /// Get number of columns.
     pub fn ncols(&self) -> usize {
-        self.ref_.ncols as usize
+        self.ncols
     }
 
     /// Get the reference to the matrix data.
     ///
     /// The values are in row-major order.
     pub fn data(&self) -> &'a [f64] {
-        let len = self.nrows() * self.ncols();
-        let data = unsafe { self.ref_.data.as_slice(len) };
-        data
+        self.data
     }
 
+    /// Wrap a row-major slice with `nrows * ncols` values.
+    #[cfg(feature = "pure-rust")]
+    pub(crate) fn from_slice(nrows: usize, ncols: usize, data: &'a [f64]) -> Self {
+        debug_assert_eq!(data.len(), nrows * ncols);
+        Self { nrows, ncols, data }
+    }
+
+    #[cfg(not(feature = "pure-rust"))]
     pub(crate) unsafe fn from_ptr(ptr: *const sys::matd_t) -> Self {
+        let ref_ = ptr.as_ref().expect("please report bug");
+        let nrows = ref_.nrows as usize;
+        let ncols = ref_.ncols as usize;
         Self {
-            ref_: ptr.as_ref().expect("please report bug"),
+            nrows,
+            ncols,
+            data: ref_.data.as_slice(nrows * ncols),
         }
     }
 }
@@ -0,0 +1,116 @@
+//! Detection at several decimation levels.
+//!
+//! A single decimation factor trades the recall of small tags for
+//! speed. With [MultiScaleParams] set by
+//! [multi_scale](crate::DetectorBuilder::multi_scale), the
+//! [Detector](crate::Detector) runs one pass per decimation level. The
+//! detections of the same family and id whose corners overlap are
+//! merged, keeping the one with the best decision margin. Decoding
+//! always runs at full resolution, so the corners of all passes are in
+//! the pixels of the input image.
+//!
+//! Duplicates within a single pass are kept. Set
+//! [duplicate_overlap](crate::filter::FilterParams::duplicate_overlap)
+//! on the filter to remove them. The
+//! [last_stats](crate::Detector::last_stats) are summed over the
+//! passes.
+//!
+//! ```no_run
+//! use apriltag::{multiscale::MultiScaleParams, Detector, Family};
+//!
+//! let detector = Detector::builder()
+//!     .add_family_bits(Family::tag_36h11(), 1)
+//!     .multi_scale(MultiScaleParams {
+//!         decimations: vec![1.0, 4.0],
+//!         ..MultiScaleParams::default()
+//!     })
+//!     .build()?;
+//! # Ok::<(), apriltag::Error>(())
+//! ```
+
+use crate::{detection::Detection, error::Error, overlap};
+
+/// Parameters of multi-scale detection.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct MultiScaleParams {
+    /// The decimation factors, each at least 1, run in order. A factor
+    /// of 1 detects quads at full resolution.
+    pub decimations: Vec<f32>,
+
+    /// The minimal intersection over union of the corners, in range 0
+    /// to 1, for two detections of the same family and id to be merged.
+    pub min_overlap: f64,
+}
+
+impl MultiScaleParams {
+    /// Check that the parameters are in range.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: String| Err(Error::MultiScaleError { reason });
+
+        if self.decimations.is_empty() {
+            return invalid("at least one decimation factor is required".to_string());
+        }
+        if let Some(decimation) = self
+            .decimations
+            .iter()
+            .find(|decimation| !(decimation.is_finite() && **decimation >= 1.0))
+        {
+            return invalid(format!(
+                "decimation ({decimation}) must be finite and at least 1"
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.min_overlap) {
+            return invalid(format!(
+                "min overlap ({}) must be in range 0 to 1",
+                self.min_overlap
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for MultiScaleParams {
+    fn default() -> Self {
+        Self {
+            decimations: vec![1.0, 2.0, 4.0],
+            min_overlap: 0.5,
+        }
+    }
+}
+
+/// Merge the detections of a pass into the merged detections.
+///
+/// A detection replaces the merged one of the same family and id whose
+/// corners overlap if it has a greater decision margin, and is dropped
+/// otherwise. Detections without overlapping counterparts are appended.
+///
+/// Only detections from different passes are merged. Duplicates within
+/// a pass are kept, and are removed by
+/// [duplicate_overlap](crate::filter::FilterParams::duplicate_overlap)
+/// if a filter is set.
+pub(crate) fn merge(merged: &mut Vec<Detection>, detections: Vec<Detection>, min_overlap: f64) {
+    let count = merged.len();
+
+    for detection in detections {
+        let corners = detection.corners();
+        let duplicate = merged[..count].iter().position(|other| {
+            other.id() == detection.id()
+                && other.family_name() == detection.family_name()
+                && overlap::quad_iou(&other.corners(), &corners) >= min_overlap
+        });
+
+        match duplicate {
+            Some(index) => {
+                if detection.decision_margin() > merged[index].decision_margin() {
+                    merged[index] = detection;
+                }
+            }
+            None => merged.push(detection),
+        }
+    }
+}
@@ -0,0 +1,496 @@
+//! The detection pipeline, ported from `apriltag_detector_detect()`.
+
+use super::{
+    preprocess,
+    quad::{self, QuadConstraints, QuadParams},
+};
+use crate::{
+    debug::Quad,
+    decode::{self, FamilyData},
+    detection::Detection,
+    detector::QuadThresholds,
+    error::Error,
+    families::FamilyKind,
+    homography,
+    image_buf::Image,
+    stats::{DetectorStats, TimeStamp},
+    unionfind,
+};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    f64::consts::FRAC_PI_2,
+    time::{Duration, Instant},
+};
+
+/// The detector state, the counterpart of `apriltag_detector_t`.
+#[derive(Debug)]
+pub(crate) struct NativeDetector {
+    pub families: Vec<NativeFamily>,
+    pub quad_decimate: f32,
+    pub quad_sigma: f32,
+    pub refine_edges: bool,
+    pub decode_sharpening: f64,
+    pub thresholds: QuadThresholds,
+    pub stats: DetectorStats,
+}
+
+/// The maximal number of entries of a quick decoding table. Larger
+/// tables take hundreds of megabytes, so the codes are scanned instead.
+const MAX_QUICK_DECODE_ENTRIES: usize = 1 << 20;
+
+/// A tag family along with its quick decoding table.
+#[derive(Debug)]
+pub(crate) struct NativeFamily {
+    pub kind: FamilyKind,
+    pub data: FamilyData,
+    maxhamming: u32,
+    /// The codewords within `maxhamming` bits, or `None` if the table
+    /// would exceed [MAX_QUICK_DECODE_ENTRIES].
+    quick_decode: Option<HashMap<u64, (u16, u8)>>,
+}
+
+/// The intermediate results kept for [DebugArtifacts](crate::DebugArtifacts).
+///
+/// The preprocessed image borrows the input image if it is neither
+/// decimated nor blurred.
+#[derive(Debug)]
+pub(crate) struct Intermediates<'a> {
+    pub preprocessed: Cow<'a, Image>,
+    pub threshold: Image,
+    /// The quads on the input image before edge refinement.
+    pub quads: Vec<Quad>,
+}
+
+impl NativeFamily {
+    /// Build the lookup table of codewords within `maxhamming` bits,
+    /// the same as `quick_decode_init()`.
+    ///
+    /// Like the C library, at most 3 bits are corrected. The table is
+    /// not built if it would have more than [MAX_QUICK_DECODE_ENTRIES]
+    /// entries, such as `tag36h11` with 3 bits, `tagStandard41h12` with
+    /// 2 bits, and `tagCircle49h12`, `tagCustom48h12` and
+    /// `tagStandard52h13` with any bits. The codes are then decoded by
+    /// a linear scan with the same results.
+    pub fn new(kind: FamilyKind, maxhamming: usize) -> Self {
+        let data = FamilyData::from_table(super::families::table(kind));
+        let maxhamming = maxhamming.min(3);
+        let quick_decode = (quick_decode_len(data.codes.len(), data.nbits, maxhamming)
+            <= MAX_QUICK_DECODE_ENTRIES)
+            .then(|| quick_decode_table(&data, maxhamming));
+
+        Self {
+            kind,
+            data,
+            maxhamming: maxhamming as u32,
+            quick_decode,
+        }
+    }
+
+    /// Look up the code among all rotations, the same as
+    /// `quick_decode_codeword()`.
+    ///
+    /// It returns the tag ID, the Hamming distance and the number of
+    /// 90 degree rotations.
+    fn decode(&self, rcode: u64) -> Option<(usize, u32, u32)> {
+        let mut rcode = rcode;
+        for rotation in 0..4 {
+            let entry = match &self.quick_decode {
+                Some(table) => table.get(&rcode).copied(),
+                None => self.scan(rcode),
+            };
+            if let Some((id, hamming)) = entry {
+                return Some((id as usize, hamming as u32, rotation));
+            }
+            rcode = decode::rotate90(rcode, self.data.nbits);
+        }
+        None
+    }
+
+    /// Find the first codeword within `maxhamming` bits, which is the
+    /// entry of the quick decoding table.
+    fn scan(&self, rcode: u64) -> Option<(u16, u8)> {
+        self.data.codes.iter().enumerate().find_map(|(id, &code)| {
+            let hamming = (code ^ rcode).count_ones();
+            (hamming <= self.maxhamming).then_some((id as u16, hamming as u8))
+        })
+    }
+}
+
+/// The number of entries of the quick decoding table.
+fn quick_decode_len(ncodes: usize, nbits: u32, maxhamming: usize) -> usize {
+    let n = nbits as usize;
+    let variants = [1, n, n * (n - 1) / 2, n * (n - 1) * (n - 2) / 6];
+    ncodes * variants[..=maxhamming].iter().sum::<usize>()
+}
+
+/// Build the lookup table of codewords within `maxhamming` bits.
+fn quick_decode_table(data: &FamilyData, maxhamming: usize) -> HashMap<u64, (u16, u8)> {
+    let nbits = data.nbits;
+    let mut quick_decode = HashMap::new();
+
+    for (id, &code) in data.codes.iter().enumerate() {
+        let mut add = |rcode: u64, hamming: u8| {
+            // The first insertion wins, the same as the linear probing
+            // in the C library.
+            quick_decode.entry(rcode).or_insert((id as u16, hamming));
+        };
+
+        add(code, 0);
+        if maxhamming >= 1 {
+            for j in 0..nbits {
+                add(code ^ (1 << j), 1);
+            }
+        }
+        if maxhamming >= 2 {
+            for j in 0..nbits {
+                for k in 0..j {
+                    add(code ^ (1 << j) ^ (1 << k), 2);
+                }
+            }
+        }
+        if maxhamming >= 3 {
+            for j in 0..nbits {
+                for k in 0..j {
+                    for m in 0..k {
+                        add(code ^ (1 << j) ^ (1 << k) ^ (1 << m), 3);
+                    }
+                }
+            }
+        }
+    }
+
+    quick_decode
+}
+
+impl NativeDetector {
+    pub fn new(families: Vec<NativeFamily>) -> Self {
+        Self {
+            families,
+            quad_decimate: 2.0,
+            quad_sigma: 0.0,
+            refine_edges: true,
+            decode_sharpening: 0.25,
+            thresholds: QuadThresholds::default(),
+            stats: DetectorStats::default(),
+        }
+    }
+
+    /// Run detection on the input image along with the intermediate
+    /// results.
+    ///
+    /// It fails if an intermediate image can not be allocated.
+    pub fn detect<'a>(
+        &mut self,
+        image: &'a Image,
+    ) -> Result<(Vec<Detection>, Intermediates<'a>), Error> {
+        let mut tp = TimeProfile::new();
+        let params = QuadParams::from(self.thresholds);
+        let constraints = QuadConstraints::new(
+            self.families
+                .iter()
+                .map(|family| (family.data.width_at_border, family.data.reversed_border)),
+            self.quad_decimate,
+        );
+        tp.stamp("init");
+
+        // Step 1. Detect quads on the decimated and blurred image.
+        let mut quad_im = if self.quad_decimate > 1.0 {
+            Cow::Owned(preprocess::decimate(image, self.quad_decimate)?)
+        } else {
+            Cow::Borrowed(image)
+        };
+        tp.stamp("decimate");
+
+        preprocess::blur_or_sharpen(&mut quad_im, self.quad_sigma);
+        tp.stamp("blur/sharp");
+
+        let threshim = quad::threshold(&quad_im, &params)?;
+        tp.stamp("threshold");
+
+        let mut uf = unionfind::connected_components(&threshim);
+        tp.stamp("unionfind");
+
+        let clusters = quad::gradient_clusters(&threshim, &mut uf);
+        drop(uf);
+        tp.stamp("make clusters");
+
+        let mut quads = quad::fit_quads(&params, &quad_im, clusters, &constraints);
+        tp.stamp("fit quads to clusters");
+
+        quad::map_to_input(&mut quads, self.quad_decimate);
+        let nquads = quads.len();
+
+        // Step 2. Decode tags from each quad.
+        let mut detections: Vec<Detection> = quads
+            .iter()
+            .flat_map(|quad| self.decode_quad(image, quad))
+            .collect();
+        tp.stamp("decode+refinement");
+
+        // Step 3. Reconcile detections so that the same tag is not
+        // reported more than once.
+        reconcile(&mut detections);
+        tp.stamp("reconcile");
+
+        detections.sort_by_key(|detection| detection.id);
+
+        let intermediates = Intermediates {
+            preprocessed: quad_im,
+            threshold: threshim,
+            quads,
+        };
+        tp.stamp("cleanup");
+
+        self.stats = DetectorStats::from_stamps(tp.stamps, nquads);
+        Ok((detections, intermediates))
+    }
+
+    /// Refine and decode a quad for each family, the same as
+    /// `quad_decode_task()`.
+    fn decode_quad(&self, image: &Image, quad: &Quad) -> Vec<Detection> {
+        let mut corners = quad.corners;
+        if self.refine_edges {
+            decode::refine_edges(
+                image,
+                &mut corners,
+                quad.reversed_border,
+                self.quad_decimate as f64,
+            );
+            corners = corners.map(|[x, y]| [x as f32 as f64, y as f32 as f64]);
+        }
+
+        let Some(h) = decode::quad_homography(&corners) else {
+            return vec![];
+        };
+
+        self.families
+            .iter()
+            .filter(|family| family.data.reversed_border == quad.reversed_border)
+            .filter_map(|family| {
+                let (rcode, decision_margin) =
+                    decode::sample_code(image, &family.data, &h, self.decode_sharpening);
+                if decision_margin < 0.0 {
+                    return None;
+                }
+                let (id, hamming, rotation) = family.decode(rcode)?;
+
+                // Fix the rotation of the homography to orient the tag.
+                let theta = rotation as f64 * FRAC_PI_2;
+                let (s, c) = theta.sin_cos();
+                let r = [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]];
+                let h: [[f64; 3]; 3] = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| (0..3).map(|k| h[i][k] * r[k][j]).sum())
+                });
+
+                // Order the corners counter-clockwise around the quad,
+                // starting at [-1, 1] in the flipped tag frame.
+                let corners = [[-1.0, 1.0], [1.0, 1.0], [1.0, -1.0], [-1.0, -1.0]]
+                    .map(|[x, y]| homography::project(&h, x, y));
+
+                Some(Detection {
+                    id,
+                    family: family.kind,
+                    hamming: hamming as usize,
+                    decision_margin,
+                    center: homography::project(&h, 0.0, 0.0),
+                    corners,
+                    homography: h.concat().try_into().unwrap(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Remove overlapping detections of the same tag, the same as the
+/// reconciliation step in `apriltag_detector_detect()`.
+fn reconcile(detections: &mut Vec<Detection>) {
+    let mut i0 = 0;
+
+    'outer: while i0 < detections.len() {
+        let mut i1 = i0 + 1;
+
+        while i1 < detections.len() {
+            let (det0, det1) = (&detections[i0], &detections[i1]);
+            if det0.id != det1.id
+                || det0.family != det1.family
+                || !polygons_overlap(&det0.corners, &det1.corners)
+            {
+                i1 += 1;
+                continue;
+            }
+
+            // Prefer the smaller Hamming distance, then the larger
+            // margin, then the smaller corner coordinates.
+            let keys0 = [det0.hamming as f64, -det0.decision_margin as f64];
+            let keys1 = [det1.hamming as f64, -det1.decision_margin as f64];
+            let corners0 = det0.corners.iter().flatten();
+            let corners1 = det1.corners.iter().flatten();
+            let prefer_first = keys0
+                .iter()
+                .chain(corners0)
+                .zip(keys1.iter().chain(corners1))
+                .find_map(|(lhs, rhs)| lhs.partial_cmp(rhs).filter(|ord| ord.is_ne()))
+                .is_some_and(|ord| ord.is_lt());
+
+            if prefer_first {
+                detections.remove(i1);
+            } else {
+                detections.remove(i0);
+                continue 'outer;
+            }
+        }
+
+        i0 += 1;
+    }
+}
+
+/// Check if two quads overlap, the same as `g2d_polygon_overlaps_polygon()`.
+fn polygons_overlap(lhs: &[[f64; 2]; 4], rhs: &[[f64; 2]; 4]) -> bool {
+    let edges = |poly: &[[f64; 2]; 4]| {
+        (0..4)
+            .map(|i| (poly[i], poly[(i + 1) % 4]))
+            .collect::<Vec<_>>()
+    };
+    let (lhs_edges, rhs_edges) = (edges(lhs), edges(rhs));
+
+    // Do any edges cross?
+    let crossed = lhs_edges.iter().any(|&(a0, a1)| {
+        rhs_edges
+            .iter()
+            .any(|&(b0, b1)| segments_intersect(a0, a1, b0, b1))
+    });
+    if crossed {
+        return true;
+    }
+
+    // Otherwise, either one contains the other or they do not overlap.
+    polygon_contains(rhs, interior_point(lhs)) || polygon_contains(lhs, interior_point(rhs))
+}
+
+/// Pick a point inside a convex polygon.
+fn interior_point(poly: &[[f64; 2]; 4]) -> [f64; 2] {
+    let [sx, sy] = poly[..3]
+        .iter()
+        .fold([0.0, 0.0], |[sx, sy], [x, y]| [sx + x, sy + y]);
+    [sx / 3.0, sy / 3.0]
+}
+
+fn segments_intersect(a0: [f64; 2], a1: [f64; 2], b0: [f64; 2], b1: [f64; 2]) -> bool {
+    let orient = |p: [f64; 2], q: [f64; 2], r: [f64; 2]| {
+        (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+    };
+    let d0 = orient(b0, b1, a0);
+    let d1 = orient(b0, b1, a1);
+    let d2 = orient(a0, a1, b0);
+    let d3 = orient(a0, a1, b1);
+    d0 * d1 < 0.0 && d2 * d3 < 0.0
+}
+
+/// The even-odd test of a point against a polygon.
+fn polygon_contains(poly: &[[f64; 2]; 4], [px, py]: [f64; 2]) -> bool {
+    let mut inside = false;
+    for i in 0..4 {
+        let [x0, y0] = poly[i];
+        let [x1, y1] = poly[(i + 1) % 4];
+        if (y0 > py) != (y1 > py) && px < (x1 - x0) * (py - y0) / (y1 - y0) + x0 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Record the elapsed time of each stage, the same as `timeprofile_t`.
+struct TimeProfile {
+    last: Option<Instant>,
+    stamps: Vec<TimeStamp>,
+}
+
+impl TimeProfile {
+    fn new() -> Self {
+        Self {
+            last: now(),
+            stamps: vec![],
+        }
+    }
+
+    fn stamp(&mut self, name: &str) {
+        let now = now();
+        let elapsed = match (self.last, now) {
+            (Some(last), Some(now)) => now - last,
+            _ => Duration::ZERO,
+        };
+        self.last = now;
+        self.stamps.push(TimeStamp {
+            name: name.to_string(),
+            elapsed,
+        });
+    }
+}
+
+/// Gets the current time if the platform has a clock.
+///
+/// `Instant::now()` panics on `wasm32-unknown-unknown`, so the stage
+/// timings are zero there.
+fn now() -> Option<Instant> {
+    if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        None
+    } else {
+        Some(Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quick_decode_finds_rotated_codes() {
+        let family = NativeFamily::new(FamilyKind::Tag16h5, 1);
+        let code = family.data.codes[7];
+
+        assert_eq!(family.decode(code), Some((7, 0, 0)));
+        assert_eq!(family.decode(code ^ 0b100), Some((7, 1, 0)));
+
+        let rotated = decode::rotate90(decode::rotate90(decode::rotate90(code, 16), 16), 16);
+        assert_eq!(family.decode(rotated), Some((7, 0, 1)));
+    }
+
+    #[test]
+    fn scan_matches_quick_decode_table() {
+        let family = NativeFamily::new(FamilyKind::Tag16h5, 2);
+        assert!(family.quick_decode.is_some());
+        let table = family.quick_decode.as_ref().unwrap();
+
+        // Walk codes near and far from the codewords.
+        let mut rcode = 0x2f5a_u64;
+        for _ in 0..4096 {
+            rcode = (rcode * 25173 + 13849) & 0xffff;
+            assert_eq!(family.scan(rcode), table.get(&rcode).copied());
+        }
+    }
+
+    #[test]
+    fn scan_large_families() {
+        let family = NativeFamily::new(FamilyKind::TagStandard52h13, 5);
+        assert!(family.quick_decode.is_none());
+        assert_eq!(family.maxhamming, 3);
+
+        let code = family.data.codes[1234];
+        assert_eq!(family.decode(code ^ 0b1011), Some((1234, 3, 0)));
+        assert_eq!(family.decode(code ^ 0b11011), None);
+    }
+
+    #[test]
+    fn overlapping_polygons() {
+        let square = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+        let shifted = square.map(|[x, y]| [x + 1.0, y + 1.0]);
+        let inner = square.map(|[x, y]| [x * 0.25 + 0.5, y * 0.25 + 0.5]);
+        let apart = square.map(|[x, y]| [x + 5.0, y]);
+
+        assert!(polygons_overlap(&square, &shifted));
+        assert!(polygons_overlap(&square, &inner));
+        assert!(polygons_overlap(&inner, &square));
+        assert!(!polygons_overlap(&square, &apart));
+    }
+}
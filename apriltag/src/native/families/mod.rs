@@ -0,0 +1,58 @@
+//! Code tables of the pre-generated tag families.
+//!
+//! The tables are generated from the `tag*.c` sources of the AprilTag
+//! library, so that the pure-Rust backend decodes the same codewords.
+
+mod tag16h5;
+mod tag25h9;
+mod tag36h11;
+mod tagcircle21h7;
+mod tagcircle49h12;
+mod tagcustom48h12;
+mod tagstandard41h12;
+mod tagstandard52h13;
+
+use crate::families::FamilyKind;
+
+/// The static counterpart of `apriltag_family_t`.
+#[derive(Debug)]
+pub(crate) struct FamilyTable {
+    pub name: &'static str,
+    pub nbits: u32,
+    pub width_at_border: i32,
+    pub total_width: i32,
+    pub reversed_border: bool,
+    pub bit_x: &'static [i32],
+    pub bit_y: &'static [i32],
+    pub codes: &'static [u64],
+}
+
+/// Gets the code table of the family.
+pub(crate) fn table(kind: FamilyKind) -> &'static FamilyTable {
+    match kind {
+        FamilyKind::Tag16h5 => &tag16h5::TAG16H5,
+        FamilyKind::Tag25h9 => &tag25h9::TAG25H9,
+        FamilyKind::Tag36h11 => &tag36h11::TAG36H11,
+        FamilyKind::TagCircle21h7 => &tagcircle21h7::TAG_CIRCLE21H7,
+        FamilyKind::TagCircle49h12 => &tagcircle49h12::TAG_CIRCLE49H12,
+        FamilyKind::TagStandard41h12 => &tagstandard41h12::TAG_STANDARD41H12,
+        FamilyKind::TagStandard52h13 => &tagstandard52h13::TAG_STANDARD52H13,
+        FamilyKind::TagCustom48h12 => &tagcustom48h12::TAG_CUSTOM48H12,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_match_family_names() {
+        for kind in FamilyKind::ALL {
+            let table = table(kind);
+            assert_eq!(table.name, kind.name());
+            assert_eq!(table.bit_x.len(), table.nbits as usize);
+            assert_eq!(table.bit_y.len(), table.nbits as usize);
+            assert!(table.codes.iter().all(|&code| code >> table.nbits == 0));
+        }
+    }
+}
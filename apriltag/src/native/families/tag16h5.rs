@@ -0,0 +1,34 @@
+//! The tag16h5 family, generated from `tag16h5.c` of the AprilTag library.
+//!
+//! Copyright (C) 2013-2016, The Regents of The University of Michigan.
+
+use super::FamilyTable;
+
+pub(crate) static TAG16H5: FamilyTable = FamilyTable {
+    name: "tag16h5",
+    nbits: 16,
+    width_at_border: 6,
+    total_width: 8,
+    reversed_border: false,
+    bit_x: &BIT_X,
+    bit_y: &BIT_Y,
+    codes: &CODES,
+};
+
+#[rustfmt::skip]
+static BIT_X: [i32; 16] = [1, 2, 3, 2, 4, 4, 4, 3, 4, 3, 2, 3, 1, 1, 1, 2];
+
+#[rustfmt::skip]
+static BIT_Y: [i32; 16] = [1, 1, 1, 2, 1, 2, 3, 2, 4, 4, 4, 3, 4, 3, 2, 3];
+
+#[rustfmt::skip]
+static CODES: [u64; 30] = [
+    0x27c8, 0x31b6, 0x3859, 0x569c,
+    0x6c76, 0x7ddb, 0xaf09, 0xf5a1,
+    0xfb8b, 0x1cb9, 0x28ca, 0xe8dc,
+    0x1426, 0x5770, 0x9253, 0xb702,
+    0x063a, 0x8f34, 0xb4c0, 0x51ec,
+    0xe6f0, 0x5fa4, 0xdd43, 0x1aaa,
+    0xe62f, 0x6dbc, 0xb6eb, 0xde10,
+    0x154d, 0xb57a,
+];
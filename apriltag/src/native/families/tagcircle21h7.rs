@@ -0,0 +1,36 @@
+//! The tagCircle21h7 family, generated from `tagCircle21h7.c` of the AprilTag library.
+//!
+//! Copyright (C) 2013-2016, The Regents of The University of Michigan.
+
+use super::FamilyTable;
+
+pub(crate) static TAG_CIRCLE21H7: FamilyTable = FamilyTable {
+    name: "tagCircle21h7",
+    nbits: 21,
+    width_at_border: 5,
+    total_width: 9,
+    reversed_border: true,
+    bit_x: &BIT_X,
+    bit_y: &BIT_Y,
+    codes: &CODES,
+};
+
+#[rustfmt::skip]
+static BIT_X: [i32; 21] = [1, 2, 3, 1, 2, 6, 6, 6, 3, 3, 3, 2, 1, 3, 2, -2, -2, -2, 1, 1, 2];
+
+#[rustfmt::skip]
+static BIT_Y: [i32; 21] = [-2, -2, -2, 1, 1, 1, 2, 3, 1, 2, 6, 6, 6, 3, 3, 3, 2, 1, 3, 2, 2];
+
+#[rustfmt::skip]
+static CODES: [u64; 38] = [
+    0x157863, 0x047e28, 0x1383ed, 0x00953c,
+    0x0da68b, 0x1cac50, 0x0bb215, 0x16ceee,
+    0x05d4b3, 0x1ff751, 0x0efd16, 0x072b3e,
+    0x163103, 0x106e56, 0x1996b9, 0x0c0234,
+    0x0624d2, 0x1fa985, 0x0344a5, 0x0762fb,
+    0x19e92b, 0x043755, 0x01a4f4, 0x10fad8,
+    0x001b52, 0x17e59f, 0x0e6f70, 0x0ed47a,
+    0x0c9931, 0x014df2, 0x0a06f1, 0x0e5041,
+    0x12ec03, 0x16724e, 0x0af1a5, 0x08a8ac,
+    0x015b39, 0x1ec1e3,
+];
@@ -0,0 +1,10579 @@
+//! The tagCustom48h12 family, generated from `tagCustom48h12.c` of the AprilTag library.
+//!
+//! Copyright (C) 2013-2016, The Regents of The University of Michigan.
+
+use super::FamilyTable;
+
+pub(crate) static TAG_CUSTOM48H12: FamilyTable = FamilyTable {
+    name: "tagCustom48h12",
+    nbits: 48,
+    width_at_border: 6,
+    total_width: 10,
+    reversed_border: true,
+    bit_x: &BIT_X,
+    bit_y: &BIT_Y,
+    codes: &CODES,
+};
+
+#[rustfmt::skip]
+static BIT_X: [i32; 48] = [-2, -1, 0, 1, 2, 3, 4, 5, 6, 1, 2, 3, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 4, 4, 7, 6, 5, 4, 3, 2, 1, 0, -1, 4, 3, 2, -2, -2, -2, -2, -2, -2, -2, -2, -2, 1, 1, 1];
+
+#[rustfmt::skip]
+static BIT_Y: [i32; 48] = [-2, -2, -2, -2, -2, -2, -2, -2, -2, 1, 1, 1, -2, -1, 0, 1, 2, 3, 4, 5, 6, 1, 2, 3, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 4, 4, 7, 6, 5, 4, 3, 2, 1, 0, -1, 4, 3, 2];
+
+#[rustfmt::skip]
+static CODES: [u64; 42211] = [
+    0xd6c8ae76dff0, 0xd6c8e905e5b5, 0xd6c92394eb7a, 0xd6c95e23f13f,
+    0xd6c998b2f704, 0xd6c9d341fcc9, 0xd6ca0dd1028e, 0xd6ca48600853,
+    0xd6ca82ef0e18, 0xd6cabd7e13dd, 0xd6caf80d19a2, 0xd6cb329c1f67,
+    0xd6cb6d2b252c, 0xd6cba7ba2af1, 0xd6cc1cd8367b, 0xd6cc57673c40,
+    0xd6cc91f64205, 0xd6cccc8547ca, 0xd6cd41a35354, 0xd6cdb6c15ede,
+    0xd6cdf15064a3, 0xd6ce2bdf6a68, 0xd6ce666e702d, 0xd6cea0fd75f2,
+    0xd6cedb8c7bb7, 0xd6cf161b817c, 0xd6cf50aa8741, 0xd6cf8b398d06,
+    0xd6cfc5c892cb, 0xd6d000579890, 0xd6d03ae69e55, 0xd6d07575a41a,
+    0xd6d0b004a9df, 0xd6d0ea93afa4, 0xd6d12522b569, 0xd6d19a40c0f3,
+    0xd6d1d4cfc6b8, 0xd6d249edd242, 0xd6d2f99ae391, 0xd6d33429e956,
+    0xd6d36eb8ef1b, 0xd6d3a947f4e0, 0xd6d3e3d6faa5, 0xd6d41e66006a,
+    0xd6d458f5062f, 0xd6d493840bf4, 0xd6d4ce1311b9, 0xd6d543311d43,
+    0xd6d57dc02308, 0xd6d5f2de2e92, 0xd6d62d6d3457, 0xd6d667fc3a1c,
+    0xd6d6dd1a45a6, 0xd6d717a94b6b, 0xd6d752385130, 0xd6d7c7565cba,
+    0xd6d801e5627f, 0xd6d83c746844, 0xd6d8b19273ce, 0xd6d8ec217993,
+    0xd6d9d65d90a7, 0xd6da10ec966c, 0xd6da4b7b9c31, 0xd6dac099a7bb,
+    0xd6dafb28ad80, 0xd6db35b7b345, 0xd6db7046b90a, 0xd6dbaad5becf,
+    0xd6dbe564c494, 0xd6dc1ff3ca59, 0xd6dc9511d5e3, 0xd6dd7f4decf7,
+    0xd6ddb9dcf2bc, 0xd6de2efafe46, 0xd6de698a040b, 0xd6dea41909d0,
+    0xd6df1937155a, 0xd6df8e5520e4, 0xd6e003732c6e, 0xd6e0789137f8,
+    0xd6e0b3203dbd, 0xd6e1283e4947, 0xd6e162cd4f0c, 0xd6e19d5c54d1,
+    0xd6e1d7eb5a96, 0xd6e2127a605b, 0xd6e2c22771aa, 0xd6e2fcb6776f,
+    0xd6e337457d34, 0xd6e3ac6388be, 0xd6e3e6f28e83, 0xd6e421819448,
+    0xd6e45c109a0d, 0xd6e4969f9fd2, 0xd6e4d12ea597, 0xd6e50bbdab5c,
+    0xd6e5464cb121, 0xd6e580dbb6e6, 0xd6e5f5f9c270, 0xd6e63088c835,
+    0xd6e66b17cdfa, 0xd6e6e035d984, 0xd6e71ac4df49, 0xd6e75553e50e,
+    0xd6e7ca71f098, 0xd6e8b4ae07ac, 0xd6e8ef3d0d71, 0xd6e929cc1336,
+    0xd6e9645b18fb, 0xd6ea14082a4a, 0xd6ea892635d4, 0xd6eac3b53b99,
+    0xd6eafe44415e, 0xd6eb73624ce8, 0xd6ec5d9e63fc, 0xd6ec982d69c1,
+    0xd6ed0d4b754b, 0xd6ed47da7b10, 0xd6ed826980d5, 0xd6edf7878c5f,
+    0xd6ee32169224, 0xd6eee1c3a373, 0xd6ef56e1aefd, 0xd6efcbffba87,
+    0xd6f0411dc611, 0xd6f07baccbd6, 0xd6f0b63bd19b, 0xd6f0f0cad760,
+    0xd6f12b59dd25, 0xd6f1db06ee74, 0xd6f28ab3ffc3, 0xd6f2c5430588,
+    0xd6f2ffd20b4d, 0xd6f33a611112, 0xd6f3ea0e2261, 0xd6f4249d2826,
+    0xd6f45f2c2deb, 0xd6f499bb33b0, 0xd6f5496844ff, 0xd6f5be865089,
+    0xd6f5f915564e, 0xd6f633a45c13, 0xd6f6a8c2679d, 0xd6f7586f78ec,
+    0xd6f7cd8d8476, 0xd6f842ab9000, 0xd6f87d3a95c5, 0xd6f8b7c99b8a,
+    0xd6f92ce7a714, 0xd6f96776acd9, 0xd6f9dc94b863, 0xd6fa1723be28,
+    0xd6fa51b2c3ed, 0xd6fac6d0cf77, 0xd6fbb10ce68b, 0xd6fbeb9bec50,
+    0xd6fc9b48fd9f, 0xd6fd10670929, 0xd6fdc0141a78, 0xd6fdfaa3203d,
+    0xd6ff1f6e3d16, 0xd6ff948c48a0, 0xd70009aa542a, 0xd700443959ef,
+    0xd7007ec85fb4, 0xd700b9576579, 0xd700f3e66b3e, 0xd7012e757103,
+    0xd701690476c8, 0xd701a3937c8d, 0xd701de228252, 0xd70218b18817,
+    0xd70253408ddc, 0xd7028dcf93a1, 0xd702c85e9966, 0xd70302ed9f2b,
+    0xd7033d7ca4f0, 0xd703780baab5, 0xd703b29ab07a, 0xd703ed29b63f,
+    0xd70427b8bc04, 0xd7046247c1c9, 0xd704d765cd53, 0xd70511f4d318,
+    0xd7054c83d8dd, 0xd7058712dea2, 0xd705c1a1e467, 0xd705fc30ea2c,
+    0xd70636bfeff1, 0xd706714ef5b6, 0xd706abddfb7b, 0xd70720fc0705,
+    0xd7075b8b0cca, 0xd707961a128f, 0xd707d0a91854, 0xd7080b381e19,
+    0xd70845c723de, 0xd708805629a3, 0xd708bae52f68, 0xd708f574352d,
+    0xd7096a9240b7, 0xd709a521467c, 0xd709dfb04c41, 0xd70a1a3f5206,
+    0xd70a54ce57cb, 0xd70a8f5d5d90, 0xd70ac9ec6355, 0xd70b047b691a,
+    0xd70b3f0a6edf, 0xd70b799974a4, 0xd70bb4287a69, 0xd70beeb7802e,
+    0xd70c294685f3, 0xd70c63d58bb8, 0xd70cd8f39742, 0xd70d13829d07,
+    0xd70d4e11a2cc, 0xd70d88a0a891, 0xd70dc32fae56, 0xd70dfdbeb41b,
+    0xd70e72dcbfa5, 0xd70ead6bc56a, 0xd70ee7facb2f, 0xd70f2289d0f4,
+    0xd70f5d18d6b9, 0xd70f97a7dc7e, 0xd71081e3f392, 0xd710bc72f957,
+    0xd711319104e1, 0xd711a6af106b, 0xd711e13e1630, 0xd712565c21ba,
+    0xd71290eb277f, 0xd71306093309, 0xd713409838ce, 0xd7137b273e93,
+    0xd713b5b64458, 0xd713f0454a1d, 0xd7142ad44fe2, 0xd714656355a7,
+    0xd7149ff25b6c, 0xd714da816131, 0xd715151066f6, 0xd7154f9f6cbb,
+    0xd715ff4c7e0a, 0xd71639db83cf, 0xd716746a8994, 0xd716e988951e,
+    0xd71724179ae3, 0xd7175ea6a0a8, 0xd7179935a66d, 0xd717d3c4ac32,
+    0xd71848e2b7bc, 0xd7188371bd81, 0xd718f88fc90b, 0xd719331eced0,
+    0xd7196dadd495, 0xd719a83cda5a, 0xd71a57e9eba9, 0xd71a9278f16e,
+    0xd71acd07f733, 0xd71b422602bd, 0xd71bb7440e47, 0xd71c66f11f96,
+    0xd71cdc0f2b20, 0xd71d169e30e5, 0xd71d512d36aa, 0xd71d8bbc3c6f,
+    0xd71dc64b4234, 0xd71e3b694dbe, 0xd71eeb165f0d, 0xd71f9ac3705c,
+    0xd7204a7081ab, 0xd72084ff8770, 0xd720bf8e8d35, 0xd720fa1d92fa,
+    0xd72134ac98bf, 0xd721a9caa449, 0xd7221ee8afd3, 0xd7225977b598,
+    0xd7229406bb5d, 0xd7230924c6e7, 0xd7242defe3c0, 0xd725c7d90c23,
+    0xd7263cf717ad, 0xd72677861d72, 0xd726b2152337, 0xd726eca428fc,
+    0xd72727332ec1, 0xd7279c513a4b, 0xd728116f45d5, 0xd7284bfe4b9a,
+    0xd728868d515f, 0xd728fbab5ce9, 0xd729363a62ae, 0xd72a9594854c,
+    0xd72c2f7dadaf, 0xd72ca49bb939, 0xd72d19b9c4c3, 0xd72d5448ca88,
+    0xd72dc966d612, 0xd72e03f5dbd7, 0xd72e3e84e19c, 0xd72e7913e761,
+    0xd72fd86e09ff, 0xd73012fd0fc4, 0xd7304d8c1589, 0xd730fd3926d8,
+    0xd73137c82c9d, 0xd7325c934976, 0xd73297224f3b, 0xd732d1b15500,
+    0xd7330c405ac5, 0xd733815e664f, 0xd7346b9a7d63, 0xd734a6298328,
+    0xd73555d69477, 0xd7360583a5c6, 0xd7364012ab8b, 0xd736b530b715,
+    0xd736efbfbcda, 0xd7372a4ec29f, 0xd73764ddc864, 0xd73889a8e53d,
+    0xd739ae740216, 0xd739e90307db, 0xd73a23920da0, 0xd73a5e211365,
+    0xd73b0dce24b4, 0xd73b485d2a79, 0xd73bbd7b3603, 0xd73bf80a3bc8,
+    0xd73c3299418d, 0xd73c6d284752, 0xd73ca7b74d17, 0xd73d1cd558a1,
+    0xd73d91f3642b, 0xd73e07116fb5, 0xd73e7c2f7b3f, 0xd73ef14d86c9,
+    0xd73f666b9253, 0xd73fdb899ddd, 0xd7408b36af2c, 0xd740c5c5b4f1,
+    0xd7417572c640, 0xd741ea90d1ca, 0xd742251fd78f, 0xd7425faedd54,
+    0xd7429a3de319, 0xd742d4cce8de, 0xd74434270b7c, 0xd7446eb61141,
+    0xd744a9451706, 0xd744e3d41ccb, 0xd746089f39a4, 0xd746432e3f69,
+    0xd7467dbd452e, 0xd746b84c4af3, 0xd746f2db50b8, 0xd7472d6a567d,
+    0xd747a2886207, 0xd74817a66d91, 0xd74901e284a5, 0xd7493c718a6a,
+    0xd749ec1e9bb9, 0xd74a26ada17e, 0xd74a613ca743, 0xd74a9bcbad08,
+    0xd74b10e9b892, 0xd74d950ef809, 0xd74e44bc0958, 0xd74e7f4b0f1d,
+    0xd74eb9da14e2, 0xd74ef4691aa7, 0xd74f69872631, 0xd74fa4162bf6,
+    0xd74fdea531bb, 0xd75019343780, 0xd7508e52430a, 0xd750c8e148cf,
+    0xd75103704e94, 0xd751788e5a1e, 0xd751b31d5fe3, 0xd752283b6b6d,
+    0xd7534d068846, 0xd753c22493d0, 0xd753fcb39995, 0xd75437429f5a,
+    0xd755217eb66e, 0xd7555c0dbc33, 0xd755969cc1f8, 0xd7560bbacd82,
+    0xd75680d8d90c, 0xd7576b14f020, 0xd75855510734, 0xd7588fe00cf9,
+    0xd75904fe1883, 0xd7593f8d1e48, 0xd7597a1c240d, 0xd759ef3a2f97,
+    0xd75a64583b21, 0xd75a9ee740e6, 0xd75ad97646ab, 0xd75bc3b25dbf,
+    0xd75bfe416384, 0xd75d5d9b8622, 0xd75f6ca2ba0f, 0xd760916dd6e8,
+    0xd760cbfcdcad, 0xd761068be272, 0xd761411ae837, 0xd7617ba9edfc,
+    0xd761b638f3c1, 0xd761f0c7f986, 0xd762a0750ad5, 0xd7631593165f,
+    0xd76350221c24, 0xd7638ab121e9, 0xd7643a5e3338, 0xd764ea0b4487,
+    0xd765249a4a4c, 0xd7655f295011, 0xd76649656725, 0xd766be8372af,
+    0xd766f9127874, 0xd767e34e8f88, 0xd768586c9b12, 0xd768cd8aa69c,
+    0xd7697d37b7eb, 0xd76a6773ceff, 0xd76aa202d4c4, 0xd76cb10a08b1,
+    0xd76ceb990e76, 0xd76d2628143b, 0xd76d60b71a00, 0xd76d9b461fc5,
+    0xd76dd5d5258a, 0xd76e4af33114, 0xd76ec0113c9e, 0xd76efaa04263,
+    0xd77109a77650, 0xd7717ec581da, 0xd771b954879f, 0xd772de1fa478,
+    0xd7738dccb5c7, 0xd77402eac151, 0xd7747808ccdb, 0xd77527b5de2a,
+    0xd7759cd3e9b4, 0xd77611f1f53e, 0xd77736bd1217, 0xd777e66a2366,
+    0xd7790b35403f, 0xd77980534bc9, 0xd779bae2518e, 0xd77a30005d18,
+    0xd77a6a8f62dd, 0xd77aa51e68a2, 0xd77adfad6e67, 0xd77cb4259c8f,
+    0xd77d2943a819, 0xd77e889dcab7, 0xd77ec32cd07c, 0xd77f384adc06,
+    0xd78097a4fea4, 0xd780d2340469, 0xd78147520ff3, 0xd78181e115b8,
+    0xd781f6ff2142, 0xd7826c1d2ccc, 0xd782a6ac3291, 0xd783565943e0,
+    0xd78390e849a5, 0xd783cb774f6a, 0xd7840606552f, 0xd78440955af4,
+    0xd7847b2460b9, 0xd7859fef7d92, 0xd786150d891c, 0xd7864f9c8ee1,
+    0xd7868a2b94a6, 0xd786ff49a030, 0xd7877467abba, 0xd7890e50d41d,
+    0xd789836edfa7, 0xd789bdfde56c, 0xd78aa839fc80, 0xd78ae2c90245,
+    0xd78b57e70dcf, 0xd78b92761394, 0xd78bcd051959, 0xd78c422324e3,
+    0xd78ddc0c4d46, 0xd78ec648645a, 0xd79060318cbd, 0xd790d54f9847,
+    0xd7910fde9e0c, 0xd79184fca996, 0xd791bf8baf5b, 0xd791fa1ab520,
+    0xd79234a9bae5, 0xd7926f38c0aa, 0xd792a9c7c66f, 0xd7931ee5d1f9,
+    0xd7935974d7be, 0xd7939403dd83, 0xd7940921e90d, 0xd79443b0eed2,
+    0xd7947e3ff497, 0xd7952ded05e6, 0xd795687c0bab, 0xd795dd9a1735,
+    0xd7970265340e, 0xd7973cf439d3, 0xd797eca14b22, 0xd7994bfb6dc0,
+    0xd79a363784d4, 0xd79aab55905e, 0xd79ae5e49623, 0xd79b9591a772,
+    0xd79c453eb8c1, 0xd79cba5cc44b, 0xd79e5445ecae, 0xd79ec963f838,
+    0xd79f3e8203c2, 0xd79f79110987, 0xd79fee2f1511, 0xd7a0d86b2c25,
+    0xd7a1c2a74339, 0xd7a1fd3648fe, 0xd7a272545488, 0xd7a2e7726012,
+    0xd7a3220165d7, 0xd7a35c906b9c, 0xd7a3971f7161, 0xd7a3d1ae7726,
+    0xd7a4815b8875, 0xd7a4bbea8e3a, 0xd7a5310899c4, 0xd7a5e0b5ab13,
+    0xd7a69062bc62, 0xd7a7400fcdb1, 0xd7a7efbcdf00, 0xd7a89f69f04f,
+    0xd7a9fec412ed, 0xd7aa395318b2, 0xd7aaae71243c, 0xd7aae9002a01,
+    0xd7ab5e1e358b, 0xd7ac485a4c9f, 0xd7acbd785829, 0xd7ade2437502,
+    0xd7ae91f08651, 0xd7b06668b479, 0xd7b0a0f7ba3e, 0xd7b18b33d152,
+    0xd7b23ae0e2a1, 0xd7b40f5910c9, 0xd7b4bf062218, 0xd7b534242da2,
+    0xd7b56eb33367, 0xd7b5a942392c, 0xd7b5e3d13ef1, 0xd7b7432b618f,
+    0xd7b7b8496d19, 0xd7b7f2d872de, 0xd7b867f67e68, 0xd7b8a285842d,
+    0xd7b917a38fb7, 0xd7b9c750a106, 0xd7bab18cb81a, 0xd7bb6139c969,
+    0xd7bb9bc8cf2e, 0xd7bcfb22f1cc, 0xd7be5a7d146a, 0xd7bf7f483143,
+    0xd7c1193159a6, 0xd7c1c8de6af5, 0xd7c2b31a8209, 0xd7c2eda987ce,
+    0xd7c41274a4a7, 0xd7c48792b031, 0xd7c4fcb0bbbb, 0xd7c5373fc180,
+    0xd7c5ac5dcd0a, 0xd7c6d128e9e3, 0xd7c7f5f406bc, 0xd7c86b121246,
+    0xd7c8a5a1180b, 0xd7c98fdd2f1f, 0xd7c9ca6c34e4, 0xd7ca3f8a406e,
+    0xd7ca7a194633, 0xd7cb64555d47, 0xd7cbd97368d1, 0xd7ccfe3e85aa,
+    0xd7cf0d45b997, 0xd7cf8263c521, 0xd7d03210d670, 0xd7d0a72ee1fa,
+    0xd7d156dbf349, 0xd7d1916af90e, 0xd7d206890498, 0xd7d241180a5d,
+    0xd7d27ba71022, 0xd7d4501f3e4a, 0xd7d53a5b555e, 0xd7d6d4447dc1,
+    0xd7d7be8094d5, 0xd7d8339ea05f, 0xd7d86e2da624, 0xd7d8a8bcabe9,
+    0xd7d8e34bb1ae, 0xd7da7d34da11, 0xd7daf252e59b, 0xd7dbdc8efcaf,
+    0xd7dfc00e5ec4, 0xd7e0aa4a75d8, 0xd7e0e4d97b9d, 0xd7e1cf1592b1,
+    0xd7e209a49876, 0xd7e27ec2a400, 0xd7e32e6fb54f, 0xd7e368febb14,
+    0xd7e3a38dc0d9, 0xd7e3de1cc69e, 0xd7e5b294f4c6, 0xd7e5ed23fa8b,
+    0xd7e6d760119f, 0xd7e74c7e1d29, 0xd7e7870d22ee, 0xd7e7fc2b2e78,
+    0xd7ea45c1682a, 0xd7eb6a8c8503, 0xd7ebdfaa908d, 0xd7ec54c89c17,
+    0xd7ec8f57a1dc, 0xd7edb422beb5, 0xd7edeeb1c47a, 0xd7ef4e0be718,
+    0xd7f197a220ca, 0xd7f1d231268f, 0xd7f2f6fc4368, 0xd7f36c1a4ef2,
+    0xd7f456566606, 0xd7f540927d1a, 0xd7f5b5b088a4, 0xd7f6655d99f3,
+    0xd7f78a28b6cc, 0xd7f8e982d96a, 0xd7f9992feab9, 0xd7fa48dcfc08,
+    0xd7fa836c01cd, 0xd7faf88a0d57, 0xd7fb3319131c, 0xd7fb6da818e1,
+    0xd7fba8371ea6, 0xd7fc927335ba, 0xd7fd42204709, 0xd7ffc6458680,
+    0xd80000d48c45, 0xd8003b63920a, 0xd80075f297cf, 0xd800b0819d94,
+    0xd800eb10a359, 0xd801259fa91e, 0xd801602eaee3, 0xd8019abdb4a8,
+    0xd801d54cba6d, 0xd8024a6ac5f7, 0xd80284f9cbbc, 0xd802bf88d181,
+    0xd802fa17d746, 0xd80334a6dd0b, 0xd8036f35e2d0, 0xd803a9c4e895,
+    0xd803e453ee5a, 0xd8045971f9e4, 0xd8049400ffa9, 0xd805091f0b33,
+    0xd80543ae10f8, 0xd8057e3d16bd, 0xd805b8cc1c82, 0xd8062dea280c,
+    0xd806a3083396, 0xd80718263f20, 0xd80752b544e5, 0xd8078d444aaa,
+    0xd80802625634, 0xd8083cf15bf9, 0xd808b20f6783, 0xd808ec9e6d48,
+    0xd80961bc78d2, 0xd8099c4b7e97, 0xd809d6da845c, 0xd80a11698a21,
+    0xd80a868795ab, 0xd80ac1169b70, 0xd80afba5a135, 0xd80b70c3acbf,
+    0xd80bab52b284, 0xd80be5e1b849, 0xd80c5affc3d3, 0xd80c958ec998,
+    0xd80cd01dcf5d, 0xd80d453bdae7, 0xd80d7fcae0ac, 0xd80e2f77f1fb,
+    0xd80edf25034a, 0xd80f54430ed4, 0xd80f8ed21499, 0xd810790e2bad,
+    0xd810b39d3172, 0xd810ee2c3737, 0xd81128bb3cfc, 0xd811634a42c1,
+    0xd811d8684e4b, 0xd8124d8659d5, 0xd81288155f9a, 0xd812c2a4655f,
+    0xd812fd336b24, 0xd813725176ae, 0xd813ace07c73, 0xd813e76f8238,
+    0xd814d1ab994c, 0xd815bbe7b060, 0xd815f676b625, 0xd8163105bbea,
+    0xd8166b94c1af, 0xd816a623c774, 0xd81755d0d8c3, 0xd818057dea12,
+    0xd8187a9bf59c, 0xd818b52afb61, 0xd818efba0126, 0xd81964d80cb0,
+    0xd819d9f6183a, 0xd81a89a32989, 0xd81ac4322f4e, 0xd81afec13513,
+    0xd81b73df409d, 0xd81bae6e4662, 0xd81be8fd4c27, 0xd81d82e6748a,
+    0xd81df8048014, 0xd81ea7b19163, 0xd81ee2409728, 0xd81f1ccf9ced,
+    0xd81f91eda877, 0xd820419ab9c6, 0xd8207c29bf8b, 0xd820b6b8c550,
+    0xd820f147cb15, 0xd8212bd6d0da, 0xd821db83e229, 0xd8221612e7ee,
+    0xd824251a1bdb, 0xd8245fa921a0, 0xd8249a382765, 0xd824d4c72d2a,
+    0xd8250f5632ef, 0xd825bf03443e, 0xd8271e5d66dc, 0xd827937b7266,
+    0xd827ce0a782b, 0xd828432883b5, 0xd829a282a653, 0xd829dd11ac18,
+    0xd82a17a0b1dd, 0xd82bb189da40, 0xd82bec18e005, 0xd82c26a7e5ca,
+    0xd82dfb2013f2, 0xd82e35af19b7, 0xd82eaacd2541, 0xd82f1feb30cb,
+    0xd82f5a7a3690, 0xd82f95093c55, 0xd82fcf98421a, 0xd8307f455369,
+    0xd8328e4c8756, 0xd832c8db8d1b, 0xd8333df998a5, 0xd83378889e6a,
+    0xd833b317a42f, 0xd8342835afb9, 0xd83462c4b57e, 0xd8349d53bb43,
+    0xd8354d00cc92, 0xd835fcaddde1, 0xd83671cbe96b, 0xd836e6e9f4f5,
+    0xd8372178faba, 0xd837d1260c09, 0xd8380bb511ce, 0xd83846441793,
+    0xd838bb62231d, 0xd838f5f128e2, 0xd839a59e3a31, 0xd83a1abc45bb,
+    0xd83a554b4b80, 0xd83b7a166859, 0xd83bb4a56e1e, 0xd83c64527f6d,
+    0xd83cd9708af7, 0xd83d891d9c46, 0xd83dc3aca20b, 0xd83e7359b35a,
+    0xd83ee877bee4, 0xd8400d42dbbd, 0xd840f77ef2d1, 0xd841320df896,
+    0xd8416c9cfe5b, 0xd842cbf720f9, 0xd843068626be, 0xd84341152c83,
+    0xd8437ba43248, 0xd8442b514397, 0xd844dafe54e6, 0xd8458aab6635,
+    0xd845c53a6bfa, 0xd846af76830e, 0xd846ea0588d3, 0xd8475f23945d,
+    0xd8480ed0a5ac, 0xd84883eeb136, 0xd8496e2ac84a, 0xd84a5866df5e,
+    0xd84a92f5e523, 0xd84b0813f0ad, 0xd84cdc8c1ed5, 0xd84d171b249a,
+    0xd84d8c393024, 0xd84dc6c835e9, 0xd84e76754738, 0xd84eb1044cfd,
+    0xd84fd5cf69d6, 0xd850105e6f9b, 0xd850c00b80ea, 0xd850fa9a86af,
+    0xd85135298c74, 0xd8516fb89239, 0xd851aa4797fe, 0xd851e4d69dc3,
+    0xd8521f65a388, 0xd85259f4a94d, 0xd8529483af12, 0xd852cf12b4d7,
+    0xd85309a1ba9c, 0xd853b94ecbeb, 0xd854de19e8c4, 0xd8558dc6fa13,
+    0xd856ed211cb1, 0xd85727b02276, 0xd857623f283b, 0xd857d75d33c5,
+    0xd85811ec398a, 0xd858c1994ad9, 0xd858fc28509e, 0xd85936b75663,
+    0xd859abd561ed, 0xd85a5b82733c, 0xd85a96117901, 0xd85b0b2f848b,
+    0xd85b804d9015, 0xd85bf56b9b9f, 0xd85cdfa7b2b3, 0xd85d8f54c402,
+    0xd85dc9e3c9c7, 0xd85eb41fe0db, 0xd85f9e5bf7ef, 0xd85fd8eafdb4,
+    0xd860137a0379, 0xd8604e09093e, 0xd860fdb61a8d, 0xd86138452052,
+    0xd861ad632bdc, 0xd861e7f231a1, 0xd86222813766, 0xd8625d103d2b,
+    0xd862d22e48b5, 0xd863f6f9658e, 0xd8646c177118, 0xd866408f9f40,
+    0xd8667b1ea505, 0xd867da78c7a3, 0xd868c4b4deb7, 0xd869e97ffb90,
+    0xd86a240f0155, 0xd86b836923f3, 0xd86bf8872f7d, 0xd86ca83440cc,
+    0xd86d1d524c56, 0xd86fdc069192, 0xd87051249d1c, 0xd87100d1ae6b,
+    0xd872259ccb44, 0xd872602bd109, 0xd872d549dc93, 0xd8746f3304f6,
+    0xd874a9c20abb, 0xd874e4511080, 0xd87593fe21cf, 0xd875ce8d2794,
+    0xd8767e3a38e3, 0xd87768764ff7, 0xd877a30555bc, 0xd87852b2670b,
+    0xd8788d416cd0, 0xd879025f785a, 0xd879777d83e4, 0xd87a272a9533,
+    0xd87a9c48a0bd, 0xd87bfba2c35b, 0xd87cab4fd4aa, 0xd87e0aa9f748,
+    0xd87f2f751421, 0xd87f6a0419e6, 0xd87fa4931fab, 0xd87fdf222570,
+    0xd88019b12b35, 0xd880544030fa, 0xd880c95e3c84, 0xd881790b4dd3,
+    0xd881b39a5398, 0xd881ee29595d, 0xd882d8657071, 0xd883881281c0,
+    0xd883c2a18785, 0xd883fd308d4a, 0xd884724e98d4, 0xd884acdd9e99,
+    0xd884e76ca45e, 0xd8860c37c137, 0xd886f673d84b, 0xd8873102de10,
+    0xd887a620e99a, 0xd887e0afef5f, 0xd8881b3ef524, 0xd888905d00ae,
+    0xd888caec0673, 0xd8897a9917c2, 0xd889efb7234c, 0xd88a2a462911,
+    0xd88a9f64349b, 0xd88b14824025, 0xd88bfebe5739, 0xd88d98a77f9c,
+    0xd88dd3368561, 0xd88e0dc58b26, 0xd88e485490eb, 0xd88ef801a23a,
+    0xd88f3290a7ff, 0xd8901cccbf13, 0xd89091eaca9d, 0xd890cc79d062,
+    0xd8914197dbec, 0xd8917c26e1b1, 0xd89316100a14, 0xd893509f0fd9,
+    0xd893c5bd1b63, 0xd894004c2128, 0xd894ea88383c, 0xd8955fa643c6,
+    0xd8959a35498b, 0xd89649e25ada, 0xd8968471609f, 0xd897341e71ee,
+    0xd8981e5a8902, 0xd89a2d61bcef, 0xd89a67f0c2b4, 0xd89aa27fc879,
+    0xd89add0ece3e, 0xd89b179dd403, 0xd89b8cbbdf8d, 0xd89cb186fc66,
+    0xd89d26a507f0, 0xd89d61340db5, 0xd89e10e11f04, 0xd89ec08e3053,
+    0xd89f35ac3bdd, 0xd89fe5594d2c, 0xd8a01fe852f1, 0xd8a095065e7b,
+    0xd8a0cf956440, 0xd8a10a246a05, 0xd8a144b36fca, 0xd8a17f42758f,
+    0xd8a1f4608119, 0xd8a2a40d9268, 0xd8a40367b506, 0xd8a4b314c655,
+    0xd8a6fcab0007, 0xd8a7373a05cc, 0xd8a771c90b91, 0xd8a7e6e7171b,
+    0xd8a821761ce0, 0xd8a85c0522a5, 0xd8a89694286a, 0xd8a8d1232e2f,
+    0xd8a90bb233f4, 0xd8a9f5ee4b08, 0xd8aae02a621c, 0xd8ac3f8484ba,
+    0xd8acef319609, 0xd8ae13fcb2e2, 0xd8ae891abe6c, 0xd8aec3a9c431,
+    0xd8aefe38c9f6, 0xd8afe874e10a, 0xd8b0d2b0f81e, 0xd8b10d3ffde3,
+    0xd8b1f77c14f7, 0xd8b2320b1abc, 0xd8b2a7292646, 0xd8b441124ea9,
+    0xd8b4b6305a33, 0xd8b5a06c7147, 0xd8b5dafb770c, 0xd8b650198296,
+    0xd8b68aa8885b, 0xd8b6ffc693e5, 0xd8b7ea02aaf9, 0xd8b983ebd35c,
+    0xd8bb58640184, 0xd8bb92f30749, 0xd8bc7d2f1e5d, 0xd8bcf24d29e7,
+    0xd8bddc8940fb, 0xd8bfb1016f23, 0xd8c0261f7aad, 0xd8c09b3d8637,
+    0xd8c1c008a310, 0xd8c23526ae9a, 0xd8c4099edcc2, 0xd8c4442de287,
+    0xd8c568f8ff60, 0xd8c5a3880525, 0xd8c5de170aea, 0xd8c618a610af,
+    0xd8c702e227c3, 0xd8c827ad449c, 0xd8c8623c4a61, 0xd8c911e95bb0,
+    0xd8caabd28413, 0xd8cb960e9b27, 0xd8cc45bbac76, 0xd8cc804ab23b,
+    0xd8cda515cf14, 0xd8ce1a33da9e, 0xd8cf046ff1b2, 0xd8cf798dfd3c,
+    0xd8d14e062b64, 0xd8d1c32436ee, 0xd8d238424278, 0xd8d2ad604e02,
+    0xd8d2e7ef53c7, 0xd8d3227e598c, 0xd8d3d22b6adb, 0xd8d4bc6781ef,
+    0xd8d5e1329ec8, 0xd8d77b1bc72b, 0xd8d86557de3f, 0xd8d98a22fb18,
+    0xd8d9c4b200dd, 0xd8d9ff4106a2, 0xd8db240c237b, 0xd8dc83664619,
+    0xd8dcbdf54bde, 0xd8dcf88451a3, 0xd8dda83162f2, 0xd8dde2c068b7,
+    0xd8deccfc7fcb, 0xd8df421a8b55, 0xd8dfb73896df, 0xd8e02c56a269,
+    0xd8e066e5a82e, 0xd8e0dc03b3b8, 0xd8e11692b97d, 0xd8e15121bf42,
+    0xd8e200ced091, 0xd8e2b07be1e0, 0xd8e32599ed6a, 0xd8e39ab7f8f4,
+    0xd8e4bf8315cd, 0xd8e56f30271c, 0xd8e5a9bf2ce1, 0xd8e5e44e32a6,
+    0xd8e7b8c660ce, 0xd8e7f3556693, 0xd8e91820836c, 0xd8e952af8931,
+    0xd8e98d3e8ef6, 0xd8eb2727b759, 0xd8eb61b6bd1e, 0xd8eb9c45c2e3,
+    0xd8edab4cf6d0, 0xd8ee5afa081f, 0xd8efba542abd, 0xd8f1543d5320,
+    0xd8f23e796a34, 0xd8f328b58148, 0xd8f36344870d, 0xd8f39dd38cd2,
+    0xd8f3d8629297, 0xd8f4fd2daf70, 0xd8f5e769c684, 0xd8f65c87d20e,
+    0xd8f746c3e922, 0xd8f78152eee7, 0xd8f86b8f05fb, 0xd8f8a61e0bc0,
+    0xd8f955cb1d0f, 0xd8f9cae92899, 0xd8fab5253fad, 0xd8faefb44572,
+    0xd8fb2a434b37, 0xd8fbd9f05c86, 0xd8fcfebb795f, 0xd8fe5e159bfd,
+    0xd8fe98a4a1c2, 0xd8fed333a787, 0xd900328dca25, 0xd9011cc9e139,
+    0xd901cc76f288, 0xd9024194fe12, 0xd9027c2403d7, 0xd90366601aeb,
+    0xd903a0ef20b0, 0xd903db7e2675, 0xd904509c31ff, 0xd9069a326bb1,
+    0xd9070f50773b, 0xd907846e82c5, 0xd908a9399f9e, 0xd9091e57ab28,
+    0xd90958e6b0ed, 0xd909ce04bc77, 0xd90af2cfd950, 0xd90ba27cea9f,
+    0xd90c179af629, 0xd90c8cb901b3, 0xd90d76f518c7, 0xd90e61312fdb,
+    0xd90e9bc035a0, 0xd90f4b6d46ef, 0xd90ffb1a583e, 0xd91035a95e03,
+    0xd910703863c8, 0xd911950380a1, 0xd91244b091f0, 0xd912f45da33f,
+    0xd913697baec9, 0xd913a40ab48e, 0xd9141928c018, 0xd915b311e87b,
+    0xd915eda0ee40, 0xd916282ff405, 0xd91662bef9ca, 0xd917126c0b19,
+    0xd9174cfb10de, 0xd917878a16a3, 0xd917fca8222d, 0xd91921733f06,
+    0xd91abb5c6769, 0xd91b307a72f3, 0xd91be0278442, 0xd91c1ab68a07,
+    0xd91cca639b56, 0xd91f4e88dacd, 0xd920e8720330, 0xd921230108f5,
+    0xd921d2ae1a44, 0xd9220d3d2009, 0xd92247cc25ce, 0xd922bcea3158,
+    0xd9236c9742a7, 0xd923a726486c, 0xd923e1b54e31, 0xd92456d359bb,
+    0xd92491625f80, 0xd92506806b0a, 0xd925410f70cf, 0xd925f0bc821e,
+    0xd9262b4b87e3, 0xd9275016a4bc, 0xd927c534b046, 0xd9283a52bbd0,
+    0xd929248ed2e4, 0xd9295f1dd8a9, 0xd92999acde6e, 0xd929d43be433,
+    0xd92a0ecae9f8, 0xd92af907010c, 0xd92b6e250c96, 0xd92ba8b4125b,
+    0xd92be3431820, 0xd92ccd7f2f34, 0xd92db7bb4648, 0xd92df24a4c0d,
+    0xd92edc866321, 0xd92f171568e6, 0xd92f51a46eab, 0xd931261c9cd3,
+    0xd9319b3aa85d, 0xd931d5c9ae22, 0xd9324ae7b9ac, 0xd932fa94cafb,
+    0xd933aa41dc4a, 0xd93459eeed99, 0xd935442b04ad, 0xd9362e671bc1,
+    0xd936de142d10, 0xd93718a332d5, 0xd9375332389a, 0xd937c8504424,
+    0xd9383d6e4fae, 0xd938b28c5b38, 0xd9399cc8724c, 0xd939d7577811,
+    0xd93a11e67dd6, 0xd93ac1938f25, 0xd93b7140a074, 0xd93ea512f13a,
+    0xd93f1a30fcc4, 0xd940046d13d8, 0xd9403efc199d, 0xd940798b1f62,
+    0xd940eea92aec, 0xd941d8e54200, 0xd942137447c5, 0xd9428892534f,
+    0xd942c3215914, 0xd943383f649e, 0xd94372ce6a63, 0xd944227b7bb2,
+    0xd9449799873c, 0xd944d2288d01, 0xd9454746988b, 0xd945f6f3a9da,
+    0xd9466c11b564, 0xd947564dcc78, 0xd94790dcd23d, 0xd9484089e38c,
+    0xd9492ac5faa0, 0xd94a150211b4, 0xd94aff3e28c8, 0xd94b745c3452,
+    0xd94c9927512b, 0xd94df88173c9, 0xd94ea82e8518, 0xd94f57db9667,
+    0xd950f1c4beca, 0xd9512c53c48f, 0xd95166e2ca54, 0xd9528bade72d,
+    0xd952c63cecf2, 0xd95460261555, 0xd954d54420df, 0xd9550fd326a4,
+    0xd955fa0f3db8, 0xd9566f2d4942, 0xd9571eda5a91, 0xd95759696056,
+    0xd958f35288b9, 0xd959a2ff9a08, 0xd95a52acab57, 0xd95a8d3bb11c,
+    0xd95b3ce8c26b, 0xd95bec95d3ba, 0xd95d867efc1d, 0xd95e70bb1331,
+    0xd95f20682480, 0xd95f9586300a, 0xd96219ab6f81, 0xd962543a7546,
+    0xd96303e78695, 0xd9633e768c5a, 0xd9637905921f, 0xd963b39497e4,
+    0xd963ee239da9, 0xd96428b2a36e, 0xd9646341a933, 0xd9649dd0aef8,
+    0xd965880cc60c, 0xd965c29bcbd1, 0xd96637b9d75b, 0xd9696b8c2821,
+    0xd969a61b2de6, 0xd96b05755084, 0xd96b40045649, 0xd96bb52261d3,
+    0xd96c2a406d5d, 0xd96d147c8471, 0xd96dc42995c0, 0xd96f2383b85e,
+    0xd96fd330c9ad, 0xd97082dddafc, 0xd9721cc7035f, 0xd97257560924,
+    0xd97291e50ee9, 0xd9737c2125fd, 0xd973b6b02bc2, 0xd9742bce374c,
+    0xd974a0ec42d6, 0xd974db7b489b, 0xd975160a4e60, 0xd9758b2859ea,
+    0xd9772511824d, 0xd9779a2f8dd7, 0xd9780f4d9961, 0xd97849dc9f26,
+    0xd9796ea7bbff, 0xd97bb83df5b1, 0xd97c2d5c013b, 0xd97c67eb0700,
+    0xd97e3c633528, 0xd97eb18140b2, 0xd97f9bbd57c6, 0xd98010db6350,
+    0xd981aac48bb3, 0xd981e5539178, 0xd9821fe2973d, 0xd9829500a2c7,
+    0xd982cf8fa88c, 0xd98344adb416, 0xd983b9cbbfa0, 0xd9842ee9cb2a,
+    0xd984de96dc79, 0xd98553b4e803, 0xd986788004dc, 0xd986ed9e1066,
+    0xd987282d162b, 0xd987d7da277a, 0xd98812692d3f, 0xd989ac5255a2,
+    0xd98a968e6cb6, 0xd98b463b7e05, 0xd98bf5e88f54, 0xd98eb49cd490,
+    0xd98f29bae01a, 0xd990fe330e42, 0xd99138c21407, 0xd991735119cc,
+    0xd9925d8d30e0, 0xd992981c36a5, 0xd9930d3a422f, 0xd99382584db9,
+    0xd996067d8d30, 0xd996410c92f5, 0xd996f0b9a444, 0xd99765d7afce,
+    0xd997a066b593, 0xd997daf5bb58, 0xd9985013c6e2, 0xd999af6de980,
+    0xd99ad4390659, 0xd99c6e222ebc, 0xd99ca8b13481, 0xd99d1dcf400b,
+    0xd99e080b571f, 0xd99e429a5ce4, 0xd99f676579bd, 0xd99fdc838547,
+    0xd9a051a190d1, 0xd9a1b0fbb36f, 0xd9a29b37ca83, 0xd9a2d5c6d048,
+    0xd9a3fa91ed21, 0xd9a4e4ce0435, 0xd9a51f5d09fa, 0xd9a6442826d3,
+    0xd9a6b946325d, 0xd9a8c84d664a, 0xd9a902dc6c0f, 0xd9a93d6b71d4,
+    0xd9a9b2897d5e, 0xd9aa62368ead, 0xd9aad7549a37, 0xd9ae8044f687,
+    0xd9afa5101360, 0xd9b01a2e1eea, 0xd9b1b417474d, 0xd9b2293552d7,
+    0xd9b3137169eb, 0xd9b3888f7575, 0xd9b3c31e7b3a, 0xd9b4e7e99813,
+    0xd9b60cb4b4ec, 0xd9b64743bab1, 0xd9b6bc61c63b, 0xd9b7317fd1c5,
+    0xd9b76c0ed78a, 0xd9b8564aee9e, 0xd9b9b5a5113c, 0xd9ba6552228b,
+    0xd9bb14ff33da, 0xd9bb4f8e399f, 0xd9bbc4ac4529, 0xd9bbff3b4aee,
+    0xd9bd99247351, 0xd9c09267be52, 0xd9c1b732db2b, 0xd9c3511c038e,
+    0xd9c38bab0953, 0xd9c3c63a0f18, 0xd9c4b076262c, 0xd9c5259431b6,
+    0xd9c59ab23d40, 0xd9c6bf7d5a19, 0xd9c6fa0c5fde, 0xd9c7a9b9712d,
+    0xd9c893f58841, 0xd9c9091393cb, 0xd9c9f34faadf, 0xd9cb181ac7b8,
+    0xd9cc0256decc, 0xd9cd61b1016a, 0xd9cf36292f92, 0xd9cfe5d640e1,
+    0xd9d05af44c6b, 0xd9d2df198be2, 0xd9d3c955a2f6, 0xd9d403e4a8bb,
+    0xd9d612ebdca8, 0xd9d68809e832, 0xd9d77245ff46, 0xd9d7e7640ad0,
+    0xd9d85c82165a, 0xd9d8d1a021e4, 0xd9d90c2f27a9, 0xd9dbcae36ce5,
+    0xd9de4f08ac5c, 0xd9de8997b221, 0xd9dec426b7e6, 0xd9defeb5bdab,
+    0xd9e05e0fe049, 0xd9e10dbcf198, 0xd9e1484bf75d, 0xd9e182dafd22,
+    0xd9e1bd6a02e7, 0xd9e232880e71, 0xd9e2a7a619fb, 0xd9e2e2351fc0,
+    0xd9e47c1e4823, 0xd9e4f13c53ad, 0xd9e5665a5f37, 0xd9e70043879a,
+    0xd9e7ea7f9eae, 0xd9e89a2caffd, 0xd9eae3c2e9af, 0xd9ec431d0c4d,
+    0xd9ecb83b17d7, 0xd9ee5224403a, 0xd9eec7424bc4, 0xd9ef3c60574e,
+    0xd9efb17e62d8, 0xd9efec0d689d, 0xd9f14b678b3b, 0xd9f2aac1add9,
+    0xd9f444aad63c, 0xd9f56975f315, 0xd9f619230464, 0xd9f68e410fee,
+    0xd9f6c8d015b3, 0xd9f7787d2702, 0xd9f7ed9b328c, 0xd9f8282a3851,
+    0xd9f862b93e16, 0xd9f89d4843db, 0xd9f9c21360b4, 0xd9fb216d8352,
+    0xd9fb968b8edc, 0xd9fbd11a94a1, 0xd9fc80c7a5f0, 0xda0029b80240,
+    0xda00d965138f, 0xda0113f41954, 0xda01c3a12aa3, 0xda02734e3bf2,
+    0xda035d8a5306, 0xda03981958cb, 0xda03d2a85e90, 0xda04bce475a4,
+    0xda053202812e, 0xda061c3e9842, 0xda0656cd9e07, 0xda06915ca3cc,
+    0xda07067aaf56, 0xda07f0b6c66a, 0xda082b45cc2f, 0xda09ffbdfa57,
+    0xda0a3a4d001c, 0xda0b5f181cf5, 0xda0b99a722ba, 0xda0cbe723f93,
+    0xda0da8ae56a7, 0xda0de33d5c6c, 0xda0e585b67f6, 0xda0e92ea6dbb,
+    0xda0f08087945, 0xda0f42977f0a, 0xda0fb7b58a94, 0xda10a1f1a1a8,
+    0xda10dc80a76d, 0xda12014bc446, 0xda13d5c3f26e, 0xda141052f833,
+    0xda14c0000982, 0xda14fa8f0f47, 0xda161f5a2c20, 0xda16947837aa,
+    0xda17442548f9, 0xda17b9435483, 0xda17f3d25a48, 0xda1868f065d2,
+    0xda18de0e715c, 0xda19189d7721, 0xda19c84a8870, 0xda1a3d6893fa,
+    0xda1b27a4ab0e, 0xda1b6233b0d3, 0xda1c86fecdac, 0xda1cc18dd371,
+    0xda1d713ae4c0, 0xda1de658f04a, 0xda1f0b240d23, 0xda1f45b312e8,
+    0xda1ff5602437, 0xda20df9c3b4b, 0xda211a2b4110, 0xda2154ba46d5,
+    0xda22798563ae, 0xda22b4146973, 0xda239e508087, 0xda253839a8ea,
+    0xda25ad57b474, 0xda25e7e6ba39, 0xda26d222d14d, 0xda270cb1d712,
+    0xda28e12a053a, 0xda291bb90aff, 0xda29cb661c4e, 0xda2a408427d8,
+    0xda2b2ac03eec, 0xda2b654f44b1, 0xda2b9fde4a76, 0xda2c14fc5600,
+    0xda2d39c772d9, 0xda2e5e928fb2, 0xda2f48cea6c6, 0xda2ff87bb815,
+    0xda306d99c39f, 0xda30a828c964, 0xda319264e078, 0xda31ccf3e63d,
+    0xda32f1bf0316, 0xda332c4e08db, 0xda34c637313e, 0xda3500c63703,
+    0xda353b553cc8, 0xda3575e4428d, 0xda374a5c70b5, 0xda37bf7a7c3f,
+    0xda38e4459918, 0xda391ed49edd, 0xda395963a4a2, 0xda3a0910b5f1,
+    0xda3c8d35f568, 0xda3d3ce306b7, 0xda40362651b8, 0xda4070b5577d,
+    0xda42452d85a5, 0xda43a487a843, 0xda448ec3bf57, 0xda44c952c51c,
+    0xda4628ace7ba, 0xda4712e8fece, 0xda474d780493, 0xda4837b41ba7,
+    0xda4921f032bb, 0xda4a46bb4f94, 0xda4af66860e3, 0xda4ba6157232,
+    0xda4c55c28381, 0xda4f1476c8bd, 0xda4fc423da0c, 0xda50ae5ff120,
+    0xda515e0d026f, 0xda53e23241e6, 0xda54cc6e58fa, 0xda55418c6484,
+    0xda562bc87b98, 0xda57160492ac, 0xda578b229e36, 0xda599a29d223,
+    0xda59d4b8d7e8, 0xda5abef4eefc, 0xda5b3412fa86, 0xda5ba9310610,
+    0xda5be3c00bd5, 0xda5c1e4f119a, 0xda5e2d564587, 0xda5ea2745111,
+    0xda5edd0356d6, 0xda5f52216260, 0xda60b17b84fe, 0xda60ec0a8ac3,
+    0xda616128964d, 0xda62fb11beb0, 0xda63702fca3a, 0xda6494fae713,
+    0xda662ee40f76, 0xda666973153b, 0xda66a4021b00, 0xda671920268a,
+    0xda6753af2c4f, 0xda683deb4363, 0xda68ed9854b2, 0xda6afc9f889f,
+    0xda6d80c4c816, 0xda6e3071d965, 0xda6ee01eeab4, 0xda6f553cf63e,
+    0xda6fca5b01c8, 0xda7129b52466, 0xda74d2a580b6, 0xda750d34867b,
+    0xda7582529205, 0xda76a71daede, 0xda76e1acb4a3, 0xda7756cac02d,
+    0xda78f0b3e890, 0xda7a8a9d10f3, 0xda7ac52c16b8, 0xda7c24863956,
+    0xda7d0ec2506a, 0xda7df8fe677e, 0xda7ea8ab78cd, 0xda8008059b6b,
+    0xda807d23a6f5, 0xda81675fbe09, 0xda81a1eec3ce, 0xda82519bd51d,
+    0xda849b320ecf, 0xda854adf201e, 0xda85bffd2ba8, 0xda86351b3732,
+    0xda866faa3cf7, 0xda86aa3942bc, 0xda86e4c84881, 0xda87947559d0,
+    0xda887eb170e4, 0xda892e5e8233, 0xda8ac847aa96, 0xda8b3d65b620,
+    0xda8e36a90121, 0xda8e713806e6, 0xda8f20e51835, 0xda9045b0350e,
+    0xda90803f3ad3, 0xda9304647a4a, 0xda95136bae37, 0xda954dfab3fc,
+    0xda972272e224, 0xda98473dfefd, 0xda9bf02e5b4d, 0xda9c654c66d7,
+    0xda9dff358f3a, 0xda9eaee2a089, 0xdaa048cbc8ec, 0xdaa16d96e5c5,
+    0xdaa21d43f714, 0xdaa257d2fcd9, 0xdaa307800e28, 0xdaa3420f13ed,
+    0xdaa37c9e19b2, 0xdaa4dbf83c50, 0xdaa5511647da, 0xdaa600c35929,
+    0xdaa79aac818c, 0xdaa7d53b8751, 0xdaa884e898a0, 0xdaa8bf779e65,
+    0xdaa9a9b3b579, 0xdaaa5960c6c8, 0xdaaace7ed252, 0xdaab090dd817,
+    0xdaae02512318, 0xdaae776f2ea2, 0xdaaf271c3ff1, 0xdab0fb946e19,
+    0xdab1362373de, 0xdab2d00c9c41, 0xdab3ba48b355, 0xdab42f66bedf,
+    0xdab519a2d5f3, 0xdab8fd223808, 0xdab937b13dcd, 0xdaba5c7c5aa6,
+    0xdaba970b606b, 0xdabad19a6630, 0xdabb0c296bf5, 0xdabb46b871ba,
+    0xdabc30f488ce, 0xdabc6b838e93, 0xdabeb519c845, 0xdabf2a37d3cf,
+    0xdac01473eae3, 0xdac08991f66d, 0xdac1ae5d1346, 0xdac2237b1ed0,
+    0xdac4a7a05e47, 0xdac606fa80e5, 0xdac6418986aa, 0xdac67c188c6f,
+    0xdac76654a383, 0xdac85090ba97, 0xdac88b1fc05c, 0xdac8c5aec621,
+    0xdac9003dcbe6, 0xdaca9a26f449, 0xdacdcdf9450f, 0xdace43175099,
+    0xdaceb8355c23, 0xdacf67e26d72, 0xdacfdd0078fc, 0xdad0178f7ec1,
+    0xdad0c73c9010, 0xdad26125b873, 0xdad2d643c3fd, 0xdad3c07fdb11,
+    0xdad3fb0ee0d6, 0xdad4e54af7ea, 0xdad8191d48b0, 0xdad88e3b543a,
+    0xdada28247c9d, 0xdada9d428827, 0xdadb126093b1, 0xdadbfc9caac5,
+    0xdadcac49bc14, 0xdadf6afe0150, 0xdae0553a1864, 0xdae104e729b3,
+    0xdae17a05353d, 0xdae229b2468c, 0xdae3890c692a, 0xdae3fe2a74b4,
+    0xdae522f5918d, 0xdae5d2a2a2dc, 0xdae60d31a8a1, 0xdae8cbe5eddd,
+    0xdae97b92ff2c, 0xdaea2b40107b, 0xdaeaa05e1c05, 0xdaeb8a9a3319,
+    0xdaebffb83ea3, 0xdaec3a474468, 0xdaee0ebf7290, 0xdaefa8a89af3,
+    0xdaf560a02b30, 0xdaf59b2f30f5, 0xdaf6104d3c7f, 0xdaf909908780,
+    0xdaf97eae930a, 0xdaf9f3cc9e94, 0xdafa2e5ba459, 0xdafdd74c00a9,
+    0xdaffe6533496, 0xdb00960045e5, 0xdb010b1e516f, 0xdb01bacb62be,
+    0xdb01f55a6883, 0xdb026a78740d, 0xdb0354b48b21, 0xdb043ef0a235,
+    0xdb04ee9db384, 0xdb059e4ac4d3, 0xdb07ad51f8c0, 0xdb082270044a,
+    0xdb08d21d1599, 0xdb090cac1b5e, 0xdb0a6c063dfc, 0xdb0aa69543c1,
+    0xdb0b1bb34f4b, 0xdb0b56425510, 0xdb0e8a14a5d6, 0xdb11f875fc61,
+    0xdb131d41193a, 0xdb13925f24c4, 0xdb147c9b3bd8, 0xdb14b72a419d,
+    0xdb14f1b94762, 0xdb15dbf55e76, 0xdb161684643b, 0xdb17eafc9263,
+    0xdb18258b9828, 0xdb18d538a977, 0xdb194a56b501, 0xdb1984e5bac6,
+    0xdb1a3492cc15, 0xdb1b595de8ee, 0xdb1c090afa3d, 0xdb20d6c67366,
+    0xdb2236209604, 0xdb22e5cda753, 0xdb24ba45d57b, 0xdb252f63e105,
+    0xdb26199ff819, 0xdb27b389207c, 0xdb28633631cb, 0xdb28d8543d55,
+    0xdb2988014ea4, 0xdb2bd1978856, 0xdb2cf662a52f, 0xdb2f0569d91c,
+    0xdb2fefa5f030, 0xdb30d9e20744, 0xdb31898f1893, 0xdb3273cb2fa7,
+    0xdb340db4580a, 0xdb377c15ae95, 0xdb386651c5a9, 0xdb38db6fd133,
+    0xdb3da92b4a5c, 0xdb3e1e4955e6, 0xdb3f08856cfa, 0xdb3f7da37884,
+    0xdb3ff2c1840e, 0xdb41178ca0e7, 0xdb42b175c94a, 0xdb42ec04cf0f,
+    0xdb436122da99, 0xdb439bb1e05e, 0xdb45aab9144b, 0xdb465a66259a,
+    0xdb477f314273, 0xdb4a035681ea, 0xdb4cc20ac726, 0xdb4d3728d2b0,
+    0xdb4de6d5e3ff, 0xdb4e9682f54e, 0xdb4fbb4e1227, 0xdb506afb2376,
+    0xdb539ecd743c, 0xdb54fe2796da, 0xdb55add4a829, 0xdb55e863adee,
+    0xdb569810bf3d, 0xdb5747bdd08c, 0xdb58e1a6f8ef, 0xdb5a41011b8d,
+    0xdb5bdaea43f0, 0xdb5d3a44668e, 0xdb5fbe69a605, 0xdb5ff8f8abca,
+    0xdb60e334c2de, 0xdb6192e1d42d, 0xdb61cd70d9f2, 0xdb62428ee57c,
+    0xdb62b7acf106, 0xdb62f23bf6cb, 0xdb632ccafc90, 0xdb66260e4791,
+    0xdb6785686a2f, 0xdb67bff76ff4, 0xdb6835157b7e, 0xdb691f519292,
+    0xdb6959e09857, 0xdb69946f9e1c, 0xdb6ab93abaf5, 0xdb6b68e7cc44,
+    0xdb6c5323e358, 0xdb6c8db2e91d, 0xdb6e9cba1d0a, 0xdb6f4c672e59,
+    0xdb720b1b7395, 0xdb72bac884e4, 0xdb73a5049bf8, 0xdb73df93a1bd,
+    0xdb7454b1ad47, 0xdb75045ebe96, 0xdb771365f283, 0xdb7838310f5c,
+    0xdb78ad4f1ae6, 0xdb79d21a37bf, 0xdb7af6e55498, 0xdb7b31745a5d,
+    0xdb80744ddf10, 0xdb80aedce4d5, 0xdb815e89f624, 0xdb82bde418c2,
+    0xdb83a8202fd6, 0xdb85b72763c3, 0xdb86a1637ad7, 0xdb86dbf2809c,
+    0xdb88b06aaec4, 0xdb89d535cb9d, 0xdb8a4a53d727, 0xdb8b348fee3b,
+    0xdb8be43cff8a, 0xdb8cce79169e, 0xdb8db8b52db2, 0xdb8e2dd3393c,
+    0xdb8f180f5050, 0xdb90ec877e78, 0xdb912716843d, 0xdb9161a58a02,
+    0xdb9211529b51, 0xdb924be1a116, 0xdb93361db82a, 0xdb9370acbdef,
+    0xdb945ae8d503, 0xdb94d006e08d, 0xdb97199d1a3f, 0xdb978ebb25c9,
+    0xdb97c94a2b8e, 0xdb983e683718, 0xdb9878f73cdd, 0xdb98b38642a2,
+    0xdb98ee154867, 0xdb99d8515f7b, 0xdb9a12e06540, 0xdb9a4d6f6b05,
+    0xdb9a87fe70ca, 0xdb9ac28d768f, 0xdb9afd1c7c54, 0xdb9b723a87de,
+    0xdb9c5c769ef2, 0xdb9cd194aa7c, 0xdb9dbbd0c190, 0xdb9e30eecd1a,
+    0xdba0efa31256, 0xdba12a32181b, 0xdba164c11de0, 0xdba2c41b407e,
+    0xdba339394c08, 0xdba5f7ed9144, 0xdba66d0b9cce, 0xdba87c12d0bb,
+    0xdbaa8b1a04a8, 0xdbaac5a90a6d, 0xdbae340a60f8, 0xdbaf1e46780c,
+    0xdbb21789c30d, 0xdbb42690f6fa, 0xdbb4611ffcbf, 0xdbb54b5c13d3,
+    0xdbb5c07a1f5d, 0xdbb6aab63671, 0xdbb6e5453c36, 0xdbb75a6347c0,
+    0xdbb7cf81534a, 0xdbb8f44c7023, 0xdbbbed8fbb24, 0xdbc00b9e22fe,
+    0xdbc080bc2e88, 0xdbc255345cb0, 0xdbc2ca52683a, 0xdbc4643b909d,
+    0xdbc638b3bec5, 0xdbc6add1ca4f, 0xdbc6e860d014, 0xdbca1c3320da,
+    0xdbca91512c64, 0xdbcacbe03229, 0xdbcb7b8d4378, 0xdbcbb61c493d,
+    0xdbcbf0ab4f02, 0xdbcc65c95a8c, 0xdbcfd42ab117, 0xdbd1e331e504,
+    0xdbd3b7aa132c, 0xdbd3f23918f1, 0xdbd4dc753005, 0xdbd5170435ca,
+    0xdbd6765e5868, 0xdbd7260b69b7, 0xdbd7609a6f7c, 0xdbd8bff4921a,
+    0xdbd96fa1a369, 0xdbd9e4bfaef3, 0xdbda1f4eb4b8, 0xdbda946cc042,
+    0xdbdeb27b281c, 0xdbe0fc1161ce, 0xdbe295fa8a31, 0xdbe2d0898ff6,
+    0xdbe3bac5a70a, 0xdbe3f554accf, 0xdbe6b408f20b, 0xdbe79e45091f,
+    0xdbea226a4896, 0xdbead21759e5, 0xdbeb0ca65faa, 0xdbeb4735656f,
+    0xdbed1bad9397, 0xdbedcb5aa4e6, 0xdbee4078b070, 0xdbef6543cd49,
+    0xdbf014f0de98, 0xdbf139bbfb71, 0xdbf1744b0136, 0xdbf1e9690cc0,
+    0xdbf299161e0f, 0xdbf30e342999, 0xdbf642067a5f, 0xdbf6b72485e9,
+    0xdbf6f1b38bae, 0xdbf766d19738, 0xdbf8c62bb9d6, 0xdbfa2585dc74,
+    0xdbfa6014e239, 0xdbfa9aa3e7fe, 0xdbfb4a50f94d, 0xdc008d2a7e00,
+    0xdc010248898a, 0xdc022713a663, 0xdc029c31b1ed, 0xdc03c0fccec6,
+    0xdc052056f164, 0xdc055ae5f729, 0xdc05d00402b3, 0xdc0645220e3d,
+    0xdc067fb11402, 0xdc06ba4019c7, 0xdc072f5e2551, 0xdc0769ed2b16,
+    0xdc07a47c30db, 0xdc085429422a, 0xdc088eb847ef, 0xdc0903d65379,
+    0xdc093e65593e, 0xdc0a28a17052, 0xdc0ad84e81a1, 0xdc0b87fb92f0,
+    0xdc0bc28a98b5, 0xdc0d5c73c118, 0xdc0e0c20d267, 0xdc0f30ebef40,
+    0xdc0fe099008f, 0xdc10904611de, 0xdc117a8228f2, 0xdc129f4d45cb,
+    0xdc13146b5155, 0xdc134efa571a, 0xdc1439366e2e, 0xdc155e018b07,
+    0xdc15989090cc, 0xdc16f7eab36a, 0xdc173279b92f, 0xdc176d08bef4,
+    0xdc185744d608, 0xdc194180ed1c, 0xdc1b8b1726ce, 0xdc1c00353258,
+    0xdc1cea71496c, 0xdc1f3407831e, 0xdc1fa9258ea8, 0xdc20cdf0ab81,
+    0xdc21b82cc295, 0xdc222d4ace1f, 0xdc231786e533, 0xdc23c733f682,
+    0xdc2476e107d1, 0xdc25268e1920, 0xdc25611d1ee5, 0xdc26c0774183,
+    0xdc27aab35897, 0xdc29449c80fa, 0xdc2a69679dd3, 0xdc2aa3f6a398,
+    0xdc2b53a3b4e7, 0xdc2bc8c1c071, 0xdc2e4ce6ffe8, 0xdc2fac412286,
+    0xdc305bee33d5, 0xdc30967d399a, 0xdc310b9b4524, 0xdc31bb485673,
+    0xdc32e013734c, 0xdc3479fc9baf, 0xdc34b48ba174, 0xdc37733fe6b0,
+    0xdc390d290f13, 0xdc40247ac1ee, 0xdc405f09c7b3, 0xdc414945dec7,
+    0xdc41be63ea51, 0xdc426e10fba0, 0xdc42a8a00165, 0xdc431dbe0cef,
+    0xdc43584d12b4, 0xdc44f2363b17, 0xdc45675446a1, 0xdc45a1e34c66,
+    0xdc45dc72522b, 0xdc4826088bdd, 0xdc48609791a2, 0xdc4ae4bcd119,
+    0xdc4cb934ff41, 0xdc4cf3c40506, 0xdc4dde001c1a, 0xdc4e188f21df,
+    0xdc4f3d5a3eb8, 0xdc4fb2784a42, 0xdc5062255b91, 0xdc509cb46156,
+    0xdc51fc0e83f4, 0xdc535b68a692, 0xdc53d086b21c, 0xdc540b15b7e1,
+    0xdc54bac2c930, 0xdc552fe0d4ba, 0xdc556a6fda7f, 0xdc5654abf193,
+    0xdc57045902e2, 0xdc57b4061431, 0xdc59c30d481e, 0xdc59fd9c4de3,
+    0xdc5a72ba596d, 0xdc5b22676abc, 0xdc5c0ca381d0, 0xdc5e90c8c147,
+    0xdc5f4075d296, 0xdc5ff022e3e5, 0xdc61c49b120d, 0xdc6399134035,
+    0xdc6532fc6898, 0xdc65a81a7422, 0xdc66cce590fb, 0xdc68a15dbf23,
+    0xdc69510ad072, 0xdc6a3b46e786, 0xdc6b6012045f, 0xdc6c4a4e1b73,
+    0xdc6e59554f60, 0xdc6f43916674, 0xdc6ff33e77c3, 0xdc72ec81c2c4,
+    0xdc74866aeb27, 0xdc75ab360800, 0xdc76d00124d9, 0xdc7b289e9278,
+    0xdc7cfd16c0a0, 0xdc7e5c70e33e, 0xdc7ed18eeec8, 0xdc811b25287a,
+    0xdc81cad239c9, 0xdc827a7f4b18, 0xdc86236fa768, 0xdc886d05e11a,
+    0xdc891cb2f269, 0xdc8a417e0f42, 0xdc8ab69c1acc, 0xdc8bdb6737a5,
+    0xdc8e9a1b7ce1, 0xdc8f49c88e30, 0xdc903404a544, 0xdc90a922b0ce,
+    0xdc911e40bc58, 0xdc9417840759, 0xdc9452130d1e, 0xdc9501c01e6d,
+    0xdc953c4f2432, 0xdc95ebfc3581, 0xdc96268b3b46, 0xdc96d6384c95,
+    0xdc9835926f33, 0xdc98e53f8082, 0xdc991fce8647, 0xdc995a5d8c0c,
+    0xdc9af446b46f, 0xdc9b2ed5ba34, 0xdc9c8e2fdcd2, 0xdc9e62a80afa,
+    0xdc9f4ce4220e, 0xdca0e6cd4a71, 0xdca1967a5bc0, 0xdca1d1096185,
+    0xdca2f5d47e5e, 0xdca330638423, 0xdca3e0109572, 0xdca4552ea0fc,
+    0xdca579f9bdd5, 0xdca66435d4e9, 0xdca7fe1efd4c, 0xdca8733d08d6,
+    0xdca95d791fea, 0xdcaa0d263139, 0xdcaa47b536fe, 0xdcab6c8053d7,
+    0xdcaba70f599c, 0xdcac914b70b0, 0xdcb19995ef9e, 0xdcb20eb3fb28,
+    0xdcb2f8f0123c, 0xdcb3a89d238b, 0xdcb3e32c2950, 0xdcb6675168c7,
+    0xdcb716fe7a16, 0xdcb7c6ab8b65, 0xdcb8013a912a, 0xdcb8b0e7a279,
+    0xdcb8eb76a83e, 0xdcb96094b3c8, 0xdcbb350ce1f0, 0xdcbd7ea31ba2,
+    0xdcbdf3c1272c, 0xdcbe68df32b6, 0xdcc1279377f2, 0xdcc162227db7,
+    0xdcc2c17ca055, 0xdcc420d6c2f3, 0xdcc495f4ce7d, 0xdcc4d083d442,
+    0xdcc5f54ef11b, 0xdcc83ee52acd, 0xdcc8b4033657, 0xdcca4dec5eba,
+    0xdcca887b647f, 0xdccbad468158, 0xdccc9782986c, 0xdcccd2119e31,
+    0xdcce316bc0cf, 0xdccee118d21e, 0xdcd04072f4bc, 0xdcd24f7a28a9,
+    0xdcd2ff2739f8, 0xdcd339b63fbd, 0xdcd3e963510c, 0xdcd49910625b,
+    0xdcd4d39f6820, 0xdcd5f86a84f9, 0xdcd6e2a69c0d, 0xdcd7cce2b321,
+    0xdcd8b71eca35, 0xdcd8f1adcffa, 0xdcd92c3cd5bf, 0xdcdac625fe22,
+    0xdcddbf694923, 0xdcdee43465fc, 0xdce0b8ac9424, 0xdce12dca9fae,
+    0xdce3b1efdf25, 0xdce3ec7ee4ea, 0xdce4619cf074, 0xdce54bd90788,
+    0xdce586680d4d, 0xdce75ae03b75, 0xdce92f58699d, 0xdcea199480b1,
+    0xdceac9419200, 0xdceb78eea34f, 0xdcefd18c10ee, 0xdcf00c1b16b3,
+    0xdcf08139223d, 0xdcf0bbc82802, 0xdcf21b224aa0, 0xdcf33fed6779,
+    0xdcf42a297e8d, 0xdcf464b88452, 0xdcf49f478a17, 0xdcf58983a12b,
+    0xdcf5c412a6f0, 0xdcf7988ad518, 0xdcf80da8e0a2, 0xdcf882c6ec2c,
+    0xdcf8f7e4f7b6, 0xdcfbf12842b7, 0xdcfc2bb7487c, 0xdcfca0d55406,
+    0xdd008454b61b, 0xdd021e3dde7e, 0xdd034308fb57, 0xdd03f2b60ca6,
+    0xdd084b537a45, 0xdd0885e2800a, 0xdd09358f9159, 0xdd0ca3f0e7e4,
+    0xdd0e034b0a82, 0xdd104ce14434, 0xdd10c1ff4fbe, 0xdd125be87821,
+    0xdd143060a649, 0xdd15ca49ceac, 0xdd17d9510299, 0xdd184e6f0e23,
+    0xdd19adc930c1, 0xdd19e8583686, 0xdd1a22e73c4b, 0xdd1a980547d5,
+    0xdd1c31ee7038, 0xdd1ca70c7bc2, 0xdd1d1c2a874c, 0xdd1e40f5a425,
+    0xdd213a38ef26, 0xdd2174c7f4eb, 0xdd251db8513b, 0xdd26b7a1799e,
+    0xdd272cbf8528, 0xdd27dc6c9677, 0xdd28518aa201, 0xdd2ad5afe178,
+    0xdd2c350a0416, 0xdd2e0982323e, 0xdd301889662b, 0xdd30c836777a,
+    0xdd32621f9fdd, 0xdd329caea5a2, 0xdd33c179c27b, 0xdd36459f01f2,
+    0xdd388f353ba4, 0xdd39ee8f5e42, 0xdd3a63ad69cc, 0xdd3bfd96922f,
+    0xdd3ce7d2a943, 0xdd3dd20ec057, 0xdd3e0c9dc61c, 0xdd3e472ccbe1,
+    0xdd41b58e226c, 0xdd41f01d2831, 0xdd42653b33bb, 0xdd438a065094,
+    0xdd4439b361e3, 0xdd460e2b900b, 0xdd47e2a3be33, 0xdd49076edb0c,
+    0xdd4b8b941a83, 0xdd4bc6232048, 0xdd4ceaee3d21, 0xdd4dd52a5435,
+    0xdd5108fca4fb, 0xdd51438baac0, 0xdd535292dead, 0xdd54023feffc,
+    0xdd543ccef5c1, 0xdd559c29185f, 0xdd55d6b81e24, 0xdd5770a14687,
+    0xdd59451974af, 0xdd5a69e49188, 0xdd5aa473974d, 0xdd5b5420a89c,
+    0xdd5bc93eb426, 0xdd610c1838d9, 0xdd6181364463, 0xdd6230e355b2,
+    0xdd62e0906701, 0xdd63cacc7e15, 0xdd652a26a0b3, 0xdd65d9d3b202,
+    0xdd66c40fc916, 0xdd6773bcda65, 0xdd690da602c8, 0xdd694835088d,
+    0xdd6aa78f2b2b, 0xdd6ae21e30f0, 0xdd6f002c98ca, 0xdd709a15c12d,
+    0xdd7149c2d27c, 0xdd731e3b00a4, 0xdd7393590c2e, 0xdd747d952342,
+    0xdd74f2b32ecc, 0xdd75a260401b, 0xdd7701ba62b9, 0xdd77ebf679cd,
+    0xdd794b509c6b, 0xdd7ae539c4ce, 0xdd7ec8b926e3, 0xdd7fb2f53df7,
+    0xdd8062a24f46, 0xdd8271a98333, 0xdd839674a00c, 0xdd865528e548,
+    0xdd8779f40221, 0xdd88d94e24bf, 0xdd89fe194198, 0xdd8b5d736436,
+    0xdd8b980269fb, 0xdd8e56b6af37, 0xdd9065bde324, 0xdd91156af473,
+    0xdd918a88fffd, 0xdd923a36114c, 0xdd9324722860, 0xdd93999033ea,
+    0xdd94493d4539, 0xdd94f8ea5688, 0xdd9658447926, 0xdd96cd6284b0,
+    0xdd977d0f95ff, 0xdd97f22da189, 0xdd9a0134d576, 0xdd9b608ef814,
+    0xdd9c103c0963, 0xdd9cfa782077, 0xdd9f440e5a29, 0xdd9ff3bb6b78,
+    0xdda118868851, 0xdda23d51a52a, 0xdda39cabc7c8, 0xdda4fc05ea66,
+    0xdda57123f5f0, 0xdda6d07e188e, 0xdda86a6740f1, 0xdda91a145240,
+    0xddab291b862d, 0xddab9e3991b7, 0xddac8875a8cb, 0xddacc304ae90,
+    0xddacfd93b455, 0xddaf81b8f3cc, 0xddb03166051b, 0xddb190c027b9,
+    0xddb32aa9501c, 0xddb48a0372ba, 0xddb4c492787f, 0xddb70e28b231,
+    0xddb7f864c945, 0xddb86d82d4cf, 0xddb9924df1a8, 0xddbab7190e81,
+    0xddbcc620426e, 0xddbe257a650c, 0xddbed527765b, 0xddbf0fb67c20,
+    0xddc06f109ebe, 0xddc208f9c721, 0xddc4529000d3, 0xddc48d1f0698,
+    0xddc5ec792936, 0xddc7c0f1575e, 0xddc8ab2d6e72, 0xddc9cff88b4b,
+    0xddcaba34a25f, 0xddcaf4c3a824, 0xddcdee06f325, 0xddcf4d6115c3,
+    0xddcffd0e2712, 0xddd0722c329c, 0xddd0e74a3e26, 0xddd1d186553a,
+    0xddd246a460c4, 0xddd3e08d8927, 0xddd4903a9a76, 0xddd57a76b18a,
+    0xddd62a23c2d9, 0xddd8ae490250, 0xddd95df6139f, 0xddd998851964,
+    0xdddd06e66fef, 0xddde6640928d, 0xdde00029baf0, 0xdde124f4d7c9,
+    0xdde20f30eedd, 0xdde41e3822ca, 0xdde458c7288f, 0xdde57d924568,
+    0xdde5b8214b2d, 0xdde6a25d6241, 0xdde7520a7390, 0xdde801b784df,
+    0xdde92682a1b8, 0xddeb7018db6a, 0xddedf43e1ae1, 0xddee695c266b,
+    0xddeea3eb2c30, 0xddef190937ba, 0xddf2124c82bb, 0xddf3ac35ab1e,
+    0xddf3e6c4b0e3, 0xddf42153b6a8, 0xddf45be2bc6d, 0xddf4d100c7f7,
+    0xddf879f12447, 0xddfafe1663be, 0xddff56b3d15d, 0xddff9142d722,
+    0xde000660e2ac, 0xde0040efe871, 0xde028a862223, 0xde03e9e044c1,
+    0xde04246f4a86, 0xde05493a675f, 0xde05be5872e9, 0xde066e058438,
+    0xde06e3238fc2, 0xde096748cf39, 0xde09dc66dac3, 0xde0b0131f79c,
+    0xde0b76500326, 0xde0beb6e0eb0, 0xde0cd5aa25c4, 0xde0e6f934e27,
+    0xde0f1f405f76, 0xde0f59cf653b, 0xde0f945e6b00, 0xde107e9a8214,
+    0xde154c55fb3d, 0xde15fc030c8c, 0xde16abb01ddb, 0xde16e63f23a0,
+    0xde19a4f368dc, 0xde1b796b9704, 0xde1bb3fa9cc9, 0xde1c2918a853,
+    0xde1e72aee205, 0xde216bf22d06, 0xde21a68132cb, 0xde21e1103890,
+    0xde22562e441a, 0xde23b58866b8, 0xde24da538391, 0xde25ff1ea06a,
+    0xde2723e9bd43, 0xde279907c8cd, 0xde2848b4da1c, 0xde28f861eb6b,
+    0xde2932f0f130, 0xde2a1d2d0844, 0xde2bf1a5366c, 0xde2c2c343c31,
+    0xde30f9efb55a, 0xde31347ebb1f, 0xde342dc20620, 0xde358d1c28be,
+    0xde36ec764b5c, 0xde3811416835, 0xde39ab2a9098, 0xde3bf4c0ca4a,
+    0xde3eee04154b, 0xde404d5e37e9, 0xde40fd0b4938, 0xde41722954c2,
+    0xde43813088af, 0xde4555a8b6d7, 0xde49392818ec, 0xde4a98823b8a,
+    0xde4ad311414f, 0xde4ce218753c, 0xde4d1ca77b01, 0xde4d573680c6,
+    0xde4e06e39215, 0xde4e417297da, 0xde4e7c019d9f, 0xde4f2baeaeee,
+    0xde513ab5e2db, 0xde551e3544f0, 0xde559353507a, 0xde5608715c04,
+    0xde56f2ad7318, 0xde5767cb7ea2, 0xde5817788ff1, 0xde5a9b9dcf68,
+    0xde5bfaf7f206, 0xde5ef43b3d07, 0xde608e24656a, 0xde61b2ef8243,
+    0xde631249a4e1, 0xde63c1f6b630, 0xde64e6c1d309, 0xde652150d8ce,
+    0xde6c38a28ba9, 0xde6d22dea2bd, 0xde6e8238c55b, 0xde6f31e5d6aa,
+    0xde70913ff948, 0xde71f09a1be6, 0xde722b2921ab, 0xde738a834449,
+    0xde755efb7271, 0xde75d4197dfb, 0xde76f8e49ad4, 0xde776e02a65e,
+    0xde77e320b1e8, 0xde781dafb7ad, 0xde79427ad486, 0xde7a2cb6eb9a,
+    0xde7adc63fce9, 0xde7e4ac55374, 0xde8059cc8761, 0xde80ceea92eb,
+    0xde81f3b5afc4, 0xde822e44b589, 0xde8268d3bb4e, 0xde831880cc9d,
+    0xde83c82dddec, 0xde843d4be976, 0xde87711e3a3c, 0xde885b5a5150,
+    0xde88d0785cda, 0xde8945966864, 0xde8980256e29, 0xde8aa4f08b02,
+    0xde8b1a0e968c, 0xde8b8f2ca216, 0xde8c7968b92a, 0xde8efd8df8a1,
+    0xde9231604967, 0xde92e10d5ab6, 0xde9689fdb706, 0xde98d393f0b8,
+    0xde9948b1fc42, 0xde99bdd007cc, 0xde9a32ee1356, 0xde9cf1a25892,
+    0xde9e8b8b80f5, 0xdea06003af1d, 0xdea10fb0c06c, 0xdea393d5ffe3,
+    0xdea408f40b6d, 0xdea443831132, 0xdea47e1216f7, 0xdea5684e2e0b,
+    0xdea86191790c, 0xdea89c207ed1, 0xdeaa3609a734, 0xdeaa7098acf9,
+    0xdeacba2ee6ab, 0xdeafb37231ac, 0xdeb028903d36, 0xdeb112cc544a,
+    0xdeb14d5b5a0f, 0xdeb1fd086b5e, 0xdeb2e7448272, 0xdeb61b16d338,
+    0xdeb73fe1f011, 0xdeb77a70f5d6, 0xdeb82a1e0725, 0xdeb9145a1e39,
+    0xdeb9897829c3, 0xdebb23615226, 0xdebfb68dc58a, 0xdec115e7e828,
+    0xdec275420ac6, 0xdec61e326716, 0xdec7f2aa953e, 0xdec98c93bda1,
+    0xdecc10b8fd18, 0xdecd358419f1, 0xdecf09fc4819, 0xded830552ee1,
+    0xdedaef09741d, 0xdedf47a6e1bc, 0xdee031e2f8d0, 0xdee11c1f0fe4,
+    0xdee27b793282, 0xdee32b2643d1, 0xdee3a0444f5b, 0xdee3dad35520,
+    0xdee6d416a021, 0xdee70ea5a5e6, 0xdee83370c2bf, 0xdee86dffc884,
+    0xdeeaf22507fb, 0xdeeb67431385, 0xdeebdc611f0f, 0xdeec16f024d4,
+    0xdeeed5a46a10, 0xdeef85517b5f, 0xdef3a35fe339, 0xdef502ba05d7,
+    0xdef5b2671726, 0xdef6278522b0, 0xdefba4edad28, 0xdefbdf7cb2ed,
+    0xdefc1a0bb8b2, 0xdf00381a208c, 0xdf0247215479, 0xdf0456288866,
+    0xdf074f6bd367, 0xdf0a48af1e68, 0xdf0c57b65255, 0xdf119a8fd708,
+    0xdf1543803358, 0xdf16684b5031, 0xdf178d166d0a, 0xdf1a4bcab246,
+    0xdf1afb77c395, 0xdf1f19862b6f, 0xdf2078e04e0d, 0xdf23e741a498,
+    0xdf2421d0aa5d, 0xdf250c0cc171, 0xdf29d9c83a9a, 0xdf2ac40451ae,
+    0xdf2afe935773, 0xdf2f1ca1bf4d, 0xdf312ba8f33a, 0xdf3250741013,
+    0xdf3300212162, 0xdf39a254c8b3, 0xdf3a1772d43d, 0xdf3a5201da02,
+    0xdf3b01aeeb51, 0xdf47d0f82e69, 0xdf4bef069643, 0xdf55c50c8e5a,
+    0xdf575ef5b6bd, 0xdf57d413c247, 0xdf596dfceaaa, 0xdf5a583901be,
+    0xdf5cdc5e4135, 0xdf5f608380ac, 0xdf5f9b128671, 0xdf60fa6ca90f,
+    0xdf6134fbaed4, 0xdf621f37c5e8, 0xdf637e91e886, 0xdf64ddec0b24,
+    0xdf65187b10e9, 0xdf663d462dc2, 0xdf67278244d6, 0xdf6762114a9b,
+    0xdf6b4590acb0, 0xdf6eb3f2033b, 0xdf70c2f93728, 0xdf7172a64877,
+    0xdf73bc3c8229, 0xdf77da4aea03, 0xdf7974341266, 0xdf7ad38e3504,
+    0xdf7bf85951dd, 0xdf7e41ef8b8f, 0xdf7f66baa868, 0xdf82d51bfef3,
+    0xdf846f052756, 0xdf86f32a66cd, 0xdf8902319aba, 0xdf893cc0a07f,
+    0xdf8a618bbd58, 0xdf8b4bc7d46c, 0xdf8cab21f70a, 0xdf8f69d63c46,
+    0xdf90c9305ee4, 0xdf913e4e6a6e, 0xdf955c5cd248, 0xdf98902f230e,
+    0xdf99054d2e98, 0xdf9a64a75136, 0xdf9b14546285, 0xdf9e4826b34b,
+    0xdf9f3262ca5f, 0xdfa524e96061, 0xdfa90868c276, 0xdfaadce0f09e,
+    0xdfab8c8e01ed, 0xdfacb1591ec6, 0xdfacebe8248b, 0xdfad61063015,
+    0xdfae10b34164, 0xdfb01fba7551, 0xdfb3c8aad1a1, 0xdfb56293fa04,
+    0xdfbcb474b2a4, 0xdfbec37be691, 0xdfbf3899f21b, 0xdfbfe847036a,
+    0xdfc0d2831a7e, 0xdfc10d122043, 0xdfc2e18a4e6b, 0xdfc440e47109,
+    0xdfcb583623e4, 0xdfcd673d57d1, 0xdfcddc5b635b, 0xdfd40970ff22,
+    0xdfd47e8f0aac, 0xdfd4b91e1071, 0xdfd568cb21c0, 0xdfd61878330f,
+    0xdfd702b44a23, 0xdfde8f240888, 0xdfdec9b30e4d, 0xdfdf79601f9c,
+    0xdfe0d8ba423a, 0xdfe1c2f6594e, 0xdfe5e104c128, 0xdfe82a9afada,
+    0xdfec48a962b4, 0xdfecbdc76e3e, 0xdfee57b096a1, 0xdfeff199bf04,
+    0xdff484c63268, 0xdff61eaf5acb, 0xdff6593e6090, 0xdffaec6ad3f4,
+    0xdffb26f9d9b9, 0xe001c92d810a, 0xe00203bc86cf, 0xe0023e4b8c94,
+    0xe002edf89de3, 0xe0032887a3a8, 0xe0039da5af32, 0xe003d834b4f7,
+    0xe00412c3babc, 0xe00696e8fa33, 0xe006d177fff8, 0xe00746960b82,
+    0xe007bbb4170c, 0xe008e07f33e5, 0xe009559d3f6f, 0xe00a7a685c48,
+    0xe00aef8667d2, 0xe00b64a4735c, 0xe00e5de7be5d, 0xe00e9876c422,
+    0xe00f82b2db36, 0xe0106ceef24a, 0xe011cc4914e8, 0xe01241672072,
+    0xe0127bf62637, 0xe012b6852bfc, 0xe012f11431c1, 0xe0132ba33786,
+    0xe01366323d4b, 0xe013a0c14310, 0xe013db5048d5, 0xe014506e545f,
+    0xe015001b65ae, 0xe0153aaa6b73, 0xe01624e68287, 0xe0165f75884c,
+    0xe0169a048e11, 0xe016d49393d6, 0xe0170f22999b, 0xe0178440a525,
+    0xe017f95eb0af, 0xe01833edb674, 0xe0186e7cbc39, 0xe018a90bc1fe,
+    0xe01a0865e49c, 0xe01b2d310175, 0xe01c176d1889, 0xe020357b8063,
+    0xe020700a8628, 0xe020aa998bed, 0xe0211fb79777, 0xe021cf64a8c6,
+    0xe0224482b450, 0xe0227f11ba15, 0xe022f42fc59f, 0xe023de6bdcb3,
+    0xe0253dc5ff51, 0xe02578550516, 0xe02628021665, 0xe0274ccd333e,
+    0xe027c1eb3ec8, 0xe02837094a52, 0xe028e6b65ba1, 0xe02e641ee619,
+    0xe02e9eadebde, 0xe02f88ea02f2, 0xe02fc37908b7, 0xe02ffe080e7c,
+    0xe03122d32b55, 0xe0315d62311a, 0xe032822d4df3, 0xe032f74b597d,
+    0xe0336c696507, 0xe033a6f86acc, 0xe03456a57c1b, 0xe036a03bb5cd,
+    0xe036dacabb92, 0xe037ff95d86b, 0xe039997f00ce, 0xe039d40e0693,
+    0xe03a492c121d, 0xe03a83bb17e2, 0xe03ccd515194, 0xe040b0d0b3a9,
+    0xe040eb5fb96e, 0xe041607dc4f8, 0xe041d59bd082, 0xe0428548e1d1,
+    0xe042fa66ed5b, 0xe043aa13feaa, 0xe043e4a3046f, 0xe0441f320a34,
+    0xe04753045afa, 0xe047c8226684, 0xe04a4c47a5fb, 0xe04b7112c2d4,
+    0xe04baba1c899, 0xe04be630ce5e, 0xe04c20bfd423, 0xe04c5b4ed9e8,
+    0xe04dbaa8fc86, 0xe04ea4e5139a, 0xe04f8f212aae, 0xe051290a5311,
+    0xe051639958d6, 0xe0519e285e9b, 0xe051d8b76460, 0xe052c2f37b74,
+    0xe053381186fe, 0xe053ad2f9288, 0xe0554718baeb, 0xe05581a7c0b0,
+    0xe055f6c5cc3a, 0xe0563154d1ff, 0xe05790aef49d, 0xe0587aeb0bb1,
+    0xe058b57a1176, 0xe058f009173b, 0xe059652722c5, 0xe059da452e4f,
+    0xe05a89f23f9e, 0xe05aff104b28, 0xe05b399f50ed, 0xe05d48a684da,
+    0xe05e32e29bee, 0xe0612c25e6ef, 0xe0633b2d1adc, 0xe063b04b2666,
+    0xe063eada2c2b, 0xe064256931f0, 0xe066347065dd, 0xe066e41d772c,
+    0xe0671eac7cf1, 0xe067593b82b6, 0xe0692db3b0de, 0xe0696842b6a3,
+    0xe06a8d0dd37c, 0xe06ac79cd941, 0xe06bec67f61a, 0xe06f9558526a,
+    0xe0700a765df4, 0xe072197d91e1, 0xe072c92aa330, 0xe073b366ba44,
+    0xe0749da2d158, 0xe074d831d71d, 0xe07512c0dce2, 0xe0754d4fe2a7,
+    0xe078bbb13932, 0xe07a559a6195, 0xe07bb4f48433, 0xe07c2a128fbd,
+    0xe07c64a19582, 0xe07e3919c3aa, 0xe07ee8c6d4f9, 0xe07f9873e648,
+    0xe08082affd5c, 0xe080f7ce08e6, 0xe081a77b1a35, 0xe08257282b84,
+    0xe082cc46370e, 0xe084662f5f71, 0xe084db4d6afb, 0xe088becccd10,
+    0xe08933ead89a, 0xe08a1e26efae, 0xe08a9344fb38, 0xe08c2d2e239b,
+    0xe08c67bd2960, 0xe08dc7174bfe, 0xe08e3c355788, 0xe08eebe268d7,
+    0xe09010ad85b0, 0xe090fae99cc4, 0xe093447fd676, 0xe0937f0edc3b,
+    0xe093f42ce7c5, 0xe094a3d9f914, 0xe09518f8049e, 0xe09553870a63,
+    0xe096ed7032c6, 0xe0979d1d4415, 0xe097d7ac49da, 0xe098fc7766b3,
+    0xe0997195723d, 0xe09a2142838c, 0xe09b460da065, 0xe09b809ca62a,
+    0xe09bf5bab1b4, 0xe09fd93a13c9, 0xe0a013c9198e, 0xe0a04e581f53,
+    0xe0a0c3762add, 0xe0a0fe0530a2, 0xe0a173233c2c, 0xe0a222d04d7b,
+    0xe0a25d5f5340, 0xe0a2d27d5eca, 0xe0a3822a7019, 0xe0a556a29e41,
+    0xe0a67b6dbb1a, 0xe0a6b5fcc0df, 0xe0a765a9d22e, 0xe0a7a038d7f3,
+    0xe0a93a220056, 0xe0aad40b28b9, 0xe0ab49293443, 0xe0ae7cfb8509,
+    0xe0af67379c1d, 0xe0b05173b331, 0xe0b0c691bebb, 0xe0b13bafca45,
+    0xe0b2607ae71e, 0xe0b31027f86d, 0xe0b34ab6fe32, 0xe0b3854603f7,
+    0xe0b3fa640f81, 0xe0b46f821b0b, 0xe0b4aa1120d0, 0xe0b6096b436e,
+    0xe0b6f3a75a82, 0xe0b7a3546bd1, 0xe0b81872775b, 0xe0b93d3d9434,
+    0xe0b977cc99f9, 0xe0bbc162d3ab, 0xe0be0af90d5d, 0xe0bef5352471,
+    0xe0bf6a532ffb, 0xe0c29e2580c1, 0xe0c3fd7fa35f, 0xe0c4380ea924,
+    0xe0c4729daee9, 0xe0c4ad2cb4ae, 0xe0c681a4e2d6, 0xe0c6f6c2ee60,
+    0xe0c73151f425, 0xe0c890ac16c3, 0xe0c8cb3b1c88, 0xe0c9f0063961,
+    0xe0ca652444eb, 0xe0cada425075, 0xe0cb89ef61c4, 0xe0cbc47e6789,
+    0xe0ccaeba7e9d, 0xe0cce9498462, 0xe0d057aadaed, 0xe0d0ccc8e677,
+    0xe0d141e6f201, 0xe0d4b048488c, 0xe0d64a3170ef, 0xe0d7e41a9952,
+    0xe0d893c7aaa1, 0xe0d94374bbf0, 0xe0da2db0d304, 0xe0daa2cede8e,
+    0xe0db527befdd, 0xe0dcec651840, 0xe0de11303519, 0xe0e05ac66ecb,
+    0xe0e403b6cb1b, 0xe0e478d4d6a5, 0xe0e4b363dc6a, 0xe0e612bdff08,
+    0xe0e8d1724444, 0xe0eaa5ea726c, 0xe0eae0797831, 0xe0eb559783bb,
+    0xe0eb90268980, 0xe0ec0544950a, 0xe0ecb4f1a659, 0xe0ee144bc8f7,
+    0xe0ee8969d481, 0xe0f10d8f13f8, 0xe0f1bd3c2547, 0xe0f1f7cb2b0c,
+    0xe0f2325a30d1, 0xe0f31c9647e5, 0xe0f3cc435934, 0xe0f6506898ab,
+    0xe0f6c586a435, 0xe0f70015a9fa, 0xe0f73aa4afbf, 0xe0f899fed25d,
+    0xe0f8d48dd822, 0xe0f949abe3ac, 0xe0f9843ae971, 0xe0fa6e770085,
+    0xe0faa906064a, 0xe0fbcdd12323, 0xe0fc42ef2ead, 0xe1009b8c9c4c,
+    0xe10110aaa7d6, 0xe101fae6beea, 0xe1023575c4af, 0xe102aa93d039,
+    0xe1031fb1dbc3, 0xe1035a40e188, 0xe10394cfe74d, 0xe104b99b0426,
+    0xe10618f526c4, 0xe1068e13324e, 0xe108628b6076, 0xe108d7a96c00,
+    0xe10aac219a28, 0xe10b213fa5b2, 0xe10b5bceab77, 0xe10b965db13c,
+    0xe11064192a65, 0xe111c3734d03, 0xe111fe0252c8, 0xe112adaf6417,
+    0xe112e83e69dc, 0xe11322cd6fa1, 0xe113d27a80f0, 0xe1140d0986b5,
+    0xe1156c63a953, 0xe115a6f2af18, 0xe1161c10baa2, 0xe116912ec62c,
+    0xe116cbbdcbf1, 0xe1194fe30b68, 0xe11a3a1f227c, 0xe11a74ae2841,
+    0xe11b9979451a, 0xe11c83b55c2e, 0xe11d6df17342, 0xe11e582d8a56,
+    0xe1206734be43, 0xe120a1c3c408, 0xe120dc52c9cd, 0xe1215170d557,
+    0xe1218bffdb1c, 0xe12325e9037f, 0xe12360780944, 0xe1239b070f09,
+    0xe123d59614ce, 0xe1244ab42058, 0xe1261f2c4e80, 0xe12659bb5445,
+    0xe12743f76b59, 0xe127b91576e3, 0xe12a3d3ab65a, 0xe12aece7c7a9,
+    0xe12b6205d333, 0xe12c4c41ea47, 0xe12ff5324697, 0xe1306a505221,
+    0xe13119fd6370, 0xe1318f1b6efa, 0xe131c9aa74bf, 0xe1327957860e,
+    0xe132b3e68bd3, 0xe1339e22a2e7, 0xe133d8b1a8ac, 0xe134fd7cc585,
+    0xe135380bcb4a, 0xe135729ad10f, 0xe136d1f4f3ad, 0xe137bc310ac1,
+    0xe138a66d21d5, 0xe138e0fc279a, 0xe13ab57455c2, 0xe13b2a92614c,
+    0xe13b9fb06cd6, 0xe13bda3f729b, 0xe13f0e11c361, 0xe1411d18f74e,
+    0xe141923702d8, 0xe141ccc6089d, 0xe142b7021fb1, 0xe1432c202b3b,
+    0xe143a13e36c5, 0xe144c609539e, 0xe14575b664ed, 0xe146d510878b,
+    0xe1470f9f8d50, 0xe1474a2e9315, 0xe14784bd98da, 0xe147bf4c9e9f,
+    0xe148a988b5b3, 0xe148e417bb78, 0xe1491ea6c13d, 0xe14993c4ccc7,
+    0xe14a08e2d851, 0xe14b683cfaef, 0xe14e618045f0, 0xe14e9c0f4bb5,
+    0xe14f112d573f, 0xe14f4bbc5d04, 0xe14ffb696e53, 0xe150708779dd,
+    0xe15120348b2c, 0xe151955296b6, 0xe152ba1db38f, 0xe153a459caa3,
+    0xe15578d1f8cb, 0xe155b360fe90, 0xe156287f0a1a, 0xe156630e0fdf,
+    0xe156d82c1b69, 0xe15af63a8343, 0xe15c1b05a01c, 0xe15ccab2b16b,
+    0xe15d3fd0bcf5, 0xe162481b3be3, 0xe16282aa41a8, 0xe162bd39476d,
+    0xe163a7755e81, 0xe1666629a3bd, 0xe1675065bad1, 0xe1678af4c096,
+    0xe167c583c65b, 0xe168afbfdd6f, 0xe168ea4ee334, 0xe1695f6ceebe,
+    0xe169d48afa48, 0xe16a84380b97, 0xe16abec7115c, 0xe16af9561721,
+    0xe16ba9032870, 0xe16c1e2133fa, 0xe17076bea199, 0xe171266bb2e8,
+    0xe172fae3e110, 0xe1745a3e03ae, 0xe17494cd0973, 0xe175b998264c,
+    0xe1762eb631d6, 0xe177c89f5a39, 0xe17a4cc499b0, 0xe17afc71aaff,
+    0xe17b718fb689, 0xe17c213cc7d8, 0xe17edff10d14, 0xe17f550f189e,
+    0xe18004bc29ed, 0xe181298746c6, 0xe182fdff74ee, 0xe183adac863d,
+    0xe183e83b8c02, 0xe18422ca91c7, 0xe184d277a316, 0xe186a6efd13e,
+    0xe186e17ed703, 0xe187569ce28d, 0xe1892b1510b5, 0xe189dac22204,
+    0xe18ac4fe3918, 0xe18baf3a502c, 0xe18d0e9472ca, 0xe18d4923788f,
+    0xe18ee30ca0f2, 0xe1907cf5c955, 0xe190f213d4df, 0xe1912ca2daa4,
+    0xe19460752b6a, 0xe19510223cb9, 0xe196aa0b651c, 0xe19759b8766b,
+    0xe198b9129909, 0xe19968bfaa58, 0xe19b02a8d2bb, 0xe19cd72100e3,
+    0xe19dc15d17f7, 0xe19e367b2381, 0xe1a2c9a796e5, 0xe1a304369caa,
+    0xe1a3ee72b3be, 0xe1a42901b983, 0xe1a49e1fc50d, 0xe1a4d8aecad2,
+    0xe1a5133dd097, 0xe1a6ad26f8fa, 0xe1ac2a8f8372, 0xe1aee943c8ae,
+    0xe1b1e28713af, 0xe1b21d161974, 0xe1b2923424fe, 0xe1b2ccc32ac3,
+    0xe1b3f18e479c, 0xe1b4a13b58eb, 0xe1b63b24814e, 0xe1b6b0428cd8,
+    0xe1b75fef9e27, 0xe1b84a2bb53b, 0xe1b9a985d7d9, 0xe1bace50f4b2,
+    0xe1bb436f003c, 0xe1bf26ee6251, 0xe1c1e5a2a78d, 0xe1c25ac0b317,
+    0xe1c3f4a9db7a, 0xe1c4a456ecc9, 0xe1c51974f853, 0xe1c603b10f67,
+    0xe1c63e40152c, 0xe1c678cf1af1, 0xe1cb468a941a, 0xe1cbf637a569,
+    0xe1ce053ed956, 0xe1cfd9b7077e, 0xe1d0896418cd, 0xe1d173a02fe1,
+    0xe1d2234d4130, 0xe1d3bd366993, 0xe1d4a77280a7, 0xe1d5571f91f6,
+    0xe1d72b97c01e, 0xe1d88af1e2bc, 0xe1d93a9ef40b, 0xe1da99f916a9,
+    0xe1e0c70eb270, 0xe1e29b86e098, 0xe1e2d615e65d, 0xe1e55a3b25d4,
+    0xe1e67f0642ad, 0xe1e6b9954872, 0xe1e7de60654b, 0xe1ea27f69efd,
+    0xe1eb1232b611, 0xe1ece6aae439, 0xe1ee80940c9c, 0xe1eef5b21826,
+    0xe1f0ca2a464e, 0xe1f264136eb1, 0xe1f3c36d914f, 0xe1f76c5ded9f,
+    0xe1f891290a78, 0xe1f97b65218c, 0xe1f9f0832d16, 0xe1faa0303e65,
+    0xe1fcaf377252, 0xe2005827cea2, 0xe200cd45da2c, 0xe20401182af2,
+    0xe2047636367c, 0xe20525e347cb, 0xe206853d6a69, 0xe20734ea7bb8,
+    0xe2081f2692cc, 0xe20859b59891, 0xe2090962a9e0, 0xe209b90fbb2f,
+    0xe20a2e2dc6b9, 0xe20a68bccc7e, 0xe20aa34bd243, 0xe20cb2530630,
+    0xe20ec15a3a1d, 0xe21020b45cbb, 0xe2105b436280, 0xe211800e7f59,
+    0xe2140433bed0, 0xe215638de16e, 0xe2159e1ce733, 0xe216133af2bd,
+    0xe21738060f96, 0xe2177295155b, 0xe217e7b320e5, 0xe21b1b8571ab,
+    0xe21b56147770, 0xe21e14c8bcac, 0xe21eff04d3c0, 0xe220d37d01e8,
+    0xe2226d662a4b, 0xe222a7f53010, 0xe22392314724, 0xe224074f52ae,
+    0xe2247c6d5e38, 0xe2252c1a6f87, 0xe2273b21a374, 0xe22775b0a939,
+    0xe227b03faefe, 0xe228255dba88, 0xe228d50acbd7, 0xe2290f99d19c,
+    0xe2294a28d761, 0xe229bf46e2eb, 0xe22b1ea10589, 0xe22bce4e16d8,
+    0xe22d2da83976, 0xe22e5273564f, 0xe22ec79161d9, 0xe22fb1cd78ed,
+    0xe23111279b8b, 0xe231fb63b29f, 0xe232e59fc9b3, 0xe2335abdd53d,
+    0xe2340a6ae68c, 0xe235dee314b4, 0xe236c91f2bc8, 0xe237b35b42dc,
+    0xe237edea48a1, 0xe23828794e66, 0xe238d8265fb5, 0xe23a720f8818,
+    0xe23da5e1d8de, 0xe23e558eea2d, 0xe2402a071855, 0xe2409f2523df,
+    0xe24114432f69, 0xe24189613af3, 0xe241fe7f467d, 0xe242390e4c42,
+    0xe243234a6356, 0xe2440d867a6a, 0xe2444815802f, 0xe245e1fea892,
+    0xe2477be7d0f5, 0xe24aafba21bb, 0xe24f08578f5a, 0xe2523c29e020,
+    0xe2532665f734, 0xe2539b8402be, 0xe25a3db7aa0f, 0xe25aed64bb5e,
+    0xe25b27f3c123, 0xe25b6282c6e8, 0xe25e21370c24, 0xe25e965517ae,
+    0xe25f0b732338, 0xe25ff5af3a4c, 0xe26204b66e39, 0xe26329818b12,
+    0xe26622c4d613, 0xe267821ef8b1, 0xe268a6ea158a, 0xe2691c082114,
+    0xe26a7b6243b2, 0xe26ab5f14977, 0xe26bdabc6650, 0xe26cc4f87d64,
+    0xe26d3a1688ee, 0xe26e9970ab8c, 0xe26ed3ffb151, 0xe26f491dbcdb,
+    0xe271cd42fc52, 0xe272426107dc, 0xe273672c24b5, 0xe2748bf7418e,
+    0xe275763358a2, 0xe278aa05a968, 0xe278e494af2d, 0xe27959b2bab7,
+    0xe27b2e2ae8df, 0xe27ba348f469, 0xe27e61fd39a5, 0xe27e9c8c3f6a,
+    0xe2815b4084a6, 0xe282457c9bba, 0xe282ba9aa744, 0xe2836a47b893,
+    0xe284c9a1db31, 0xe2869e1a0959, 0xe287133814e3, 0xe288380331bc,
+    0xe289223f48d0, 0xe28abc287133, 0xe28ba6648847, 0xe28d404db0aa,
+    0xe29074200170, 0xe290aeaf0735, 0xe290e93e0cfa, 0xe291d37a240e,
+    0xe292f84540e7, 0xe294ccbd6f0f, 0xe295074c74d4, 0xe297c600ba10,
+    0xe298b03cd124, 0xe29a84b4ff4c, 0xe29b3462109b, 0xe29be40f21ea,
+    0xe29e68346161, 0xe29f52707875, 0xe2a0773b954e, 0xe2a126e8a69d,
+    0xe2a16177ac62, 0xe2a1d695b7ec, 0xe2a24bb3c376, 0xe2a62f33258b,
+    0xe2a7196f3c9f, 0xe2a753fe4264, 0xe2a78e8d4829, 0xe2aac25f98ef,
+    0xe2ad0bf5d2a1, 0xe2af558c0c53, 0xe2af901b1218, 0xe2b0b4e62ef1,
+    0xe2b12a043a7b, 0xe2b164934040, 0xe2b1d9b14bca, 0xe2b45dd68b41,
+    0xe2b71c8ad07d, 0xe2b791a8dc07, 0xe2b966210a2f, 0xe2ba8aec2708,
+    0xe2bb3a993857, 0xe2bbafb743e1, 0xe2bc5f645530, 0xe2bd49a06c44,
+    0xe2c12d1fce59, 0xe2c251eaeb32, 0xe2c28c79f0f7, 0xe2c2c708f6bc,
+    0xe2c460f21f1f, 0xe2c5c04c41bd, 0xe2c6aa8858d1, 0xe2c71fa6645b,
+    0xe2c92ead9848, 0xe2ca18e9af5c, 0xe2ca8e07bae6, 0xe2cb0325c670,
+    0xe2cbb2d2d7bf, 0xe2cee6a52885, 0xe2cf21342e4a, 0xe2d0808e50e8,
+    0xe2d304b3905f, 0xe2d4640db2fd, 0xe2d6ada3ecaf, 0xe2d6e832f274,
+    0xe2d7d26f0988, 0xe2d8478d1512, 0xe2d931c92c26, 0xe2da1c05433a,
+    0xe2dbb5ee6b9d, 0xe2dca02a82b1, 0xe2dd15488e3b, 0xe2deaf31b69e,
+    0xe2e0be38ea8b, 0xe2e16de5fbda, 0xe2e1a875019f, 0xe2e1e3040764,
+    0xe2e21d930d29, 0xe2e3425e2a02, 0xe2e4672946db, 0xe2e84aa8a8f0,
+    0xe2e88537aeb5, 0xe2e8fa55ba3f, 0xe2ec2e280b05, 0xe2f011a76d1a,
+    0xe2f220aea107, 0xe2f25b3da6cc, 0xe2f3f526cf2f, 0xe2f42fb5d4f4,
+    0xe2f63ebd08e1, 0xe2f6794c0ea6, 0xe2f79e172b7f, 0xe2f9380053e2,
+    0xe2fa223c6af6, 0xe301741d2396, 0xe30298e8406f, 0xe309b039f34a,
+    0xe30d1e9b49d5, 0xe30e436666ae, 0xe30eb8847238, 0xe30ef31377fd,
+    0xe3108cfca060, 0xe311b1c7bd39, 0xe311ec56c2fe, 0xe315202913c4,
+    0xe3167f833662, 0xe316ba123c27, 0xe316f4a141ec, 0xe31903a875d9,
+    0xe3193e377b9e, 0xe31a287392b2, 0xe31a63029877, 0xe31ce727d7ee,
+    0xe32055892e79, 0xe320caa73a03, 0xe323895b7f3f, 0xe326480fc47b,
+    0xe326829eca40, 0xe327a769e719, 0xe3281c87f2a3, 0xe328cc3503f2,
+    0xe329f10020cb, 0xe32a2b8f2690, 0xe32cea436bcc, 0xe32e0f0e88a5,
+    0xe331b7fee4f5, 0xe33317590793, 0xe3338c77131d, 0xe33476b32a31,
+    0xe3385a328c46, 0xe3397efda91f, 0xe33b18e6d182, 0xe33bc893e2d1,
+    0xe33efc663397, 0xe341bb1a78d3, 0xe3426ac78a22, 0xe342dfe595ac,
+    0xe3435503a136, 0xe345d928e0ad, 0xe3464e46ec37, 0xe346c364f7c1,
+    0xe348d26c2bae, 0xe3490cfb3173, 0xe34b56916b25, 0xe34cf07a9388,
+    0xe34f749fd2ff, 0xe353581f3514, 0xe353cd3d409e, 0xe354425b4c28,
+    0xe354b77957b2, 0xe354f2085d77, 0xe3573b9e9729, 0xe3589af8b9c7,
+    0xe358d587bf8c, 0xe3594aa5cb16, 0xe359bfc3d6a0, 0xe359fa52dc65,
+    0xe35a6f70e7ef, 0xe35b943c04c8, 0xe35c095a1052, 0xe35cf3962766,
+    0xe35f3d2c6118, 0xe360d715897b, 0xe361fbe0a654, 0xe363d058d47c,
+    0xe3648005e5cb, 0xe364ba94eb90, 0xe364f523f155, 0xe3652fb2f71a,
+    0xe366547e13f3, 0xe367042b2542, 0xe367ee673c56, 0xe36c0c75a430,
+    0xe36f4047f4f6, 0xe36feff50645, 0xe372aea94b81, 0xe372e9385146,
+    0xe37398e56295, 0xe373d374685a, 0xe374489273e4, 0xe374f83f8533,
+    0xe37532ce8af8, 0xe377b6f3ca6f, 0xe37a008a0421, 0xe37c84af4398,
+    0xe37e592771c0, 0xe37f436388d4, 0xe3818cf9c286, 0xe3823ca6d3d5,
+    0xe382b1c4df5f, 0xe383d68ffc38, 0xe384111f01fd, 0xe3844bae07c2,
+    0xe3865ab53baf, 0xe387ba0f5e4d, 0xe38869bc6f9c, 0xe389196980eb,
+    0xe389c916923a, 0xe38b2870b4d8, 0xe38bd81dc627, 0xe38e5c43059e,
+    0xe390e0684515, 0xe3915586509f, 0xe3927a516d78, 0xe392ef6f7902,
+    0xe393648d848c, 0xe393d9ab9016, 0xe394fe76acef, 0xe3962341c9c8,
+    0xe396d2eedb17, 0xe398a767093f, 0xe39a415031a2, 0xe39b661b4e7b,
+    0xe39ba0aa5440, 0xe39ed47ca506, 0xe3a06e65cd69, 0xe3a0a8f4d32e,
+    0xe3a158a1e47d, 0xe3a27d6d0156, 0xe3a2f28b0ce0, 0xe3a48c743543,
+    0xe3a53c214692, 0xe3a785b78044, 0xe3a7fad58bce, 0xe3aa446bc580,
+    0xe3aaf418d6cf, 0xe3ab2ea7dc94, 0xe3aba3c5e81e, 0xe3ae9d09331f,
+    0xe3b0ac10670c, 0xe3b1212e7296, 0xe3b1964c7e20, 0xe3b2bb179af9,
+    0xe3b2f5a6a0be, 0xe3b33035a683, 0xe3b45500c35c, 0xe3b504add4ab,
+    0xe3b66407f749, 0xe3b8ad9e30fb, 0xe3b8e82d36c0, 0xe3b922bc3c85,
+    0xe3b95d4b424a, 0xe3baf7346aad, 0xe3c1244a0674, 0xe3c15ed90c39,
+    0xe3c24915234d, 0xe3c283a42912, 0xe3c2be332ed7, 0xe3c3e2fe4bb0,
+    0xe3c4581c573a, 0xe3c62c948562, 0xe3c716d09c76, 0xe3c7515fa23b,
+    0xe3c8eb48ca9e, 0xe3cb6f6e0a15, 0xe3cba9fd0fda, 0xe3cc943926ee,
+    0xe3cd43e6383d, 0xe3d19c83a5dc, 0xe3d45b37eb18, 0xe3d6df5d2a8f,
+    0xe3d719ec3054, 0xe3d8b3d558b7, 0xe3da4dbe811a, 0xe3da884d86df,
+    0xe3dd0c72c656, 0xe3e1651033f5, 0xe3e498e284bb, 0xe3e4d3718a80,
+    0xe3e9a12d03a9, 0xe3eb3b162c0c, 0xe3ec25524320, 0xe3ecd4ff546f,
+    0xe3f0f30dbc49, 0xe3f1682bc7d3, 0xe3f25267dee7, 0xe3f4d68d1e5e,
+    0xe3f5111c2423, 0xe3f5863a2fad, 0xe3f6e594524b, 0xe3f80a5f6f24,
+    0xe3f8ba0c8073, 0xe3fac913b460, 0xe3fb3e31bfea, 0xe3fbb34fcb74,
+    0xe3ff21b121ff, 0xe4016b475bb1, 0xe401a5d66176, 0xe401e065673b,
+    0xe402558372c5, 0xe4029012788a, 0xe40305308414, 0xe405fe73cf15,
+    0xe406ae20e064, 0xe407d2ebfd3d, 0xe408480a08c7, 0xe408bd281451,
+    0xe40a91a04279, 0xe40b414d53c8, 0xe40c2b896adc, 0xe40cdb367c2b,
+    0xe40d505487b5, 0xe40e3a909ec9, 0xe411a8f1f554, 0xe412589f06a3,
+    0xe41467a63a90, 0xe41551e251a4, 0xe41760e98591, 0xe4193561b3b9,
+    0xe41acf4adc1c, 0xe41c6934047f, 0xe42345f6b195, 0xe4238085b75a,
+    0xe4246ac1ce6e, 0xe42813b22abe, 0xe42a22b95eab, 0xe42a5d486470,
+    0xe42bbca2870e, 0xe42dcba9bafb, 0xe4308a5e0037, 0xe431e9b822d5,
+    0xe438c67acfeb, 0xe4393b98db75, 0xe439b0b6e6ff, 0xe43a6063f84e,
+    0xe43b852f1527, 0xe43bbfbe1aec, 0xe43bfa4d20b1, 0xe43f2e1f7177,
+    0xe44052ea8e50, 0xe441b244b0ee, 0xe4434c2dd951, 0xe444e61701b4,
+    0xe44595c41303, 0xe4476a3c412b, 0xe4490425698e, 0xe44979437518,
+    0xe449b3d27add, 0xe44a28f08667, 0xe44b4dbba340, 0xe44bfd68b48f,
+    0xe44c7286c019, 0xe44d2233d168, 0xe44fe0e816a4, 0xe45140423942,
+    0xe452da2b61a5, 0xe454aea38fcd, 0xe454e9329592, 0xe4555e50a11c,
+    0xe457e275e093, 0xe4589222f1e2, 0xe45b16483159, 0xe45b8b663ce3,
+    0xe45fe403aa82, 0xe467707368e7, 0xe467e5917471, 0xe468cfcd8b85,
+    0xe4697f7a9cd4, 0xe46a2f27ae23, 0xe46aa445b9ad, 0xe46ec2542187,
+    0xe46f37722d11, 0xe46fac90389b, 0xe4705c3d49ea, 0xe472e0628961,
+    0xe473558094eb, 0xe4747a4bb1c4, 0xe4759f16ce9d, 0xe4768952e5b1,
+    0xe477e8ad084f, 0xe47b919d649f, 0xe47fea3ad23e, 0xe48099e7e38d,
+    0xe4826e6011b5, 0xe4852d1456f1, 0xe487b1399668, 0xe4882657a1f2,
+    0xe4891093b906, 0xe489c040ca55, 0xe48ae50be72e, 0xe48b1f9aecf3,
+    0xe48b94b8f87d, 0xe48cf4131b1b, 0xe48f78385a92, 0xe491122182f5,
+    0xe4914cb088ba, 0xe4939646c26c, 0xe494bb11df45, 0xe494f5a0e50a,
+    0xe4956abef094, 0xe496ca191332, 0xe49704a818f7, 0xe497b4552a46,
+    0xe4989e91415a, 0xe498d920471f, 0xe499fdeb63f8, 0xe49b5d458696,
+    0xe49cf72eaef9, 0xe49e5688d197, 0xe4a0daae110e, 0xe4a1c4ea2822,
+    0xe4a324444ac0, 0xe4a39962564a, 0xe4a4f8bc78e8, 0xe4a5a8698a37,
+    0xe4a6cd34a710, 0xe4a77ce1b85f, 0xe4a7f1ffc3e9, 0xe4abd57f25fe,
+    0xe4ac852c374d, 0xe4af7e6f824e, 0xe4aff38d8dd8, 0xe4b02e1c939d,
+    0xe4b068ab9962, 0xe4b0a33a9f27, 0xe4b18d76b63b, 0xe4b3275fde9e,
+    0xe4b4119bf5b2, 0xe4b65b322f64, 0xe4b7f51b57c7, 0xe4b86a396351,
+    0xe4b8a4c86916, 0xe4baee5ea2c8, 0xe4bb9e0bb417, 0xe4bc1329bfa1,
+    0xe4bc4db8c566, 0xe4c03138277b, 0xe4c11b743e8f, 0xe4c156034454,
+    0xe4c2403f5b68, 0xe4c2b55d66f2, 0xe4c32a7b727c, 0xe4c3650a7841,
+    0xe4c44f468f55, 0xe4c4c4649adf, 0xe4c86d54f72f, 0xe4c8a7e3fcf4,
+    0xe4ca7c5c2b1c, 0xe4cb66984230, 0xe4cc1645537f, 0xe4cd00816a93,
+    0xe4d1ce3ce3bc, 0xe4d2b878fad0, 0xe4d2f3080095, 0xe4d3a2b511e4,
+    0xe4d3dd4417a9, 0xe4d5020f3482, 0xe4d5772d400c, 0xe4d5b1bc45d1,
+    0xe4d69bf85ce5, 0xe4d6d68762aa, 0xe4d7fb527f83, 0xe4d8aaff90d2,
+    0xe4d8e58e9697, 0xe4d95aaca221, 0xe4daf495ca84, 0xe4df12a4325e,
+    0xe4df4d333823, 0xe4dfc25143ad, 0xe4e071fe54fc, 0xe4e196c971d5,
+    0xe4e246768324, 0xe4e2bb948eae, 0xe4e330b29a38, 0xe4e69f13f0c3,
+    0xe4e7fe6e1361, 0xe4ea0d75474e, 0xe4ea829352d8, 0xe4eabd22589d,
+    0xe4ee6612b4ed, 0xe4f4cdb75679, 0xe4f508465c3e, 0xe4f5f2827352,
+    0xe4f6dcbe8a66, 0xe4f8b136b88e, 0xe4f960e3c9dd, 0xe4f99b72cfa2,
+    0xe4fa4b1fe0f1, 0xe4fea3bd4e90, 0xe5019d009991, 0xe502121ea51b,
+    0xe502873cb0a5, 0xe5037178c7b9, 0xe503e696d343, 0xe5042125d908,
+    0xe50545f0f5e1, 0xe506302d0cf5, 0xe5083f3440e2, 0xe509d91d6945,
+    0xe50a4e3b74cf, 0xe50b730691a8, 0xe50d820dc595, 0xe510f06f1c20,
+    0xe5112afe21e5, 0xe5124fc93ebe, 0xe513e9b26721, 0xe515490c89bf,
+    0xe519671af199, 0xe51ac6751437, 0xe51b76222586, 0xe51ee4837c11,
+    0xe520094e98ea, 0xe521a337c14d, 0xe5228d73d861, 0xe52461ec0689,
+    0xe5249c7b0c4e, 0xe524d70a1213, 0xe5292fa77fb2, 0xe52cd897dc02,
+    0xe52ee79f0fef, 0xe530bc173e17, 0xe531313549a1, 0xe5321b7160b5,
+    0xe5337acb8353, 0xe5349f96a02c, 0xe53514b4abb6, 0xe53883160241,
+    0xe538bda50806, 0xe53ca1246a1b, 0xe53f9a67b51c, 0xe540bf32d1f5,
+    0xe541a96ee909, 0xe542591bfa58, 0xe543f30522bb, 0xe547d68484d0,
+    0xe549e58bb8bd, 0xe54bba03e6e5, 0xe54c69b0f834, 0xe550c24e65d3,
+    0xe551ac8a7ce7, 0xe551e71982ac, 0xe553bb91b0d4, 0xe554a5cdc7e8,
+    0xe555557ad937, 0xe555ca98e4c1, 0xe556ef64019a, 0xe558142f1e73,
+    0xe55973894111, 0xe55b829074fe, 0xe56015bce862, 0xe5613a88053b,
+    0xe563498f3928, 0xe563f93c4a77, 0xe56642d28429, 0xe5667d6189ee,
+    0xe566f27f9578, 0xe567679da102, 0xe5690186c965, 0xe56b4b1d0317,
+    0xe56bc03b0ea1, 0xe57053678205, 0xe5755bb200f3, 0xe576f59b2956,
+    0xe57b4e3896f5, 0xe57d97ced0a7, 0xe57f31b7f90a, 0xe580cba1216d,
+    0xe58106302732, 0xe5817b4e32bc, 0xe581b5dd3881, 0xe58315375b1f,
+    0xe5843a0277f8, 0xe5864909abe5, 0xe586be27b76f, 0xe588cd2eeb5c,
+    0xe58b51542ad3, 0xe58efa448723, 0xe58fa9f19872, 0xe592a334e373,
+    0xe593c800004c, 0xe59477ad119b, 0xe595275a22ea, 0xe59686b44588,
+    0xe596c1434b4d, 0xe597e60e6826, 0xe59a6a33a79d, 0xe59f37ef20c6,
+    0xe59fad0d2c50, 0xe59fe79c3215, 0xe5a146f654b3, 0xe5a1bc14603d,
+    0xe5a1f6a36602, 0xe5a231326bc7, 0xe5a31b6e82db, 0xe5a3908c8e65,
+    0xe5a4efe6b103, 0xe5a6feede4f0, 0xe5a9f8312ff1, 0xe5aa6d4f3b7b,
+    0xe5ab1cfc4cca, 0xe5af007baedf, 0xe5afeab7c5f3, 0xe5b10f82e2cc,
+    0xe5b359191c7e, 0xe5b393a82243, 0xe5b3ce372808, 0xe5b443553392,
+    0xe5b5dd3e5bf5, 0xe5b6525c677f, 0xe5b6c77a7309, 0xe5b94b9fb280,
+    0xe5b9862eb845, 0xe5bae588dae3, 0xe5bb2017e0a8, 0xe5bb9535ec32,
+    0xe5bcf4900ed0, 0xe5c14d2d7c6f, 0xe5c1c24b87f9, 0xe5c35c34b05c,
+    0xe5c396c3b621, 0xe5c3d152bbe6, 0xe5c4f61dd8bf, 0xe5c61ae8f598,
+    0xe5c690070122, 0xe5c9142c4099, 0xe5c9fe6857ad, 0xe5ca73866337,
+    0xe5ccf7aba2ae, 0xe5ce5705c54c, 0xe5ce9194cb11, 0xe5d2afa332eb,
+    0xe5d2ea3238b0, 0xe5d35f50443a, 0xe5d4f9396c9d, 0xe5d6cdb19ac5,
+    0xe5d77d5eac14, 0xe5d7f27cb79e, 0xe5d8a229c8ed, 0xe5de5a21592a,
+    0xe5dff40a818d, 0xe5e2782fc104, 0xe5e2b2bec6c9, 0xe5e65baf2319,
+    0xe5e86ab65706, 0xe5eb9e88a7cc, 0xe5ef820809e1, 0xe5f06c4420f5,
+    0xe5f1910f3dce, 0xe5f27b4b54e2, 0xe5f2f069606c, 0xe5f365876bf6,
+    0xe5f4ff709459, 0xe5f5748e9fe3, 0xe5f7be24d995, 0xe5f8e2eff66e,
+    0xe5fba1a43baa, 0xe5fc51514cf9, 0xe5fe25c97b21, 0xe5ff10059235,
+    0xe60034d0af0e, 0xe6027e66e8c0, 0xe60418501123, 0xe605b2393986,
+    0xe605ecc83f4b, 0xe6069c75509a, 0xe607fbcf7338, 0xe60870ed7ec2,
+    0xe6095b2995d6, 0xe60aba83b874, 0xe60b2fa1c3fe, 0xe60c546ce0d7,
+    0xe60e637414c4, 0xe60ed892204e, 0xe6115cb75fc5, 0xe612bc118263,
+    0xe61455faaac6, 0xe6149089b08b, 0xe614cb18b650, 0xe6157ac5c79f,
+    0xe615b554cd64, 0xe61714aef002, 0xe618740912a0, 0xe61a8310468d,
+    0xe61c1cf96ef0, 0xe61d41c48bc9, 0xe61d7c53918e, 0xe61edbadb42c,
+    0xe620b025e254, 0xe623344b21cb, 0xe623a9692d55, 0xe624ce344a2e,
+    0xe6262d8e6ccc, 0xe626a2ac7856, 0xe629d67ec91c, 0xe6387a403a5c,
+    0xe63b7383855d, 0xe63be8a190e7, 0xe6412b7b159a, 0xe64584188339,
+    0xe645f9368ec3, 0xe646a8e3a012, 0xe64ac6f207ec, 0xe64fcf3c86da,
+    0xe6507ee99829, 0xe650f407a3b3, 0xe65218d2c08c, 0xe653030ed7a0,
+    0xe653ed4aeeb4, 0xe654d78705c8, 0xe65671702e2b, 0xe65845e85c53,
+    0xe65a8f7e9605, 0xe65bb449b2de, 0xe65d4e32db41, 0xe65ee81c03a4,
+    0xe660bc9431cc, 0xe66340b97143, 0xe663f0668292, 0xe664a01393e1,
+    0xe66639fcbc44, 0xe6675ec7d91d, 0xe66a1d7c1e59, 0xe66d16bf695a,
+    0xe670bfafc5aa, 0xe670fa3ecb6f, 0xe6716f5cd6f9, 0xe67343d50521,
+    0xe675184d3349, 0xe67602894a5d, 0xe67d546a02fd, 0xe68172786ad7,
+    0xe683817f9ec4, 0xe683bc0ea489, 0xe684312cb013, 0xe684a64abb9d,
+    0xe685cb15d876, 0xe689e9244050, 0xe68b487e62ee, 0xe68ca7d8858c,
+    0xe68fdbaad652, 0xe690c5e6ed66, 0xe6929a5f1b8e, 0xe693849b32a2,
+    0xe693bf2a3867, 0xe69608c07219, 0xe696f2fc892d, 0xe6972d8b8ef2,
+    0xe697681a94b7, 0xe697dd38a041, 0xe69ad67beb42, 0xe6a08e737b7f,
+    0xe6a2d809b531, 0xe6a4ac81e359, 0xe6a6466b0bbc, 0xe6a76b362895,
+    0xe6a7e054341f, 0xe6a89001456e, 0xe6a93fae56bd, 0xe6ab8944906f,
+    0xe6ace89eb30d, 0xe6b01c7103d3, 0xe6b0918f0f5d, 0xe6b0cc1e1522,
+    0xe6b1413c20ac, 0xe6b3fff065e8, 0xe6b43a7f6bad, 0xe6b4750e7172,
+    0xe6ba67950774, 0xe6badcb312fe, 0xe6c01f8c97b1, 0xe6c0cf39a900,
+    0xe6c3c87cf401, 0xe6c4ed4810da, 0xe6c68731393d, 0xe6c736de4a8c,
+    0xe6c7abfc5616, 0xe6c9bb038a03, 0xe6ca6ab09b52, 0xe6cc3f28c97a,
+    0xe6cc79b7cf3f, 0xe6ccb446d504, 0xe6cf386c147b, 0xe6cfad8a2005,
+    0xe6d10ce442a3, 0xe6d7af17e9f4, 0xe6d82435f57e, 0xe6d85ec4fb43,
+    0xe6d949011257, 0xe6dddc2d85bb, 0xe6e234caf35a, 0xe6e26f59f91f,
+    0xe6e2e47804a9, 0xe6e359961033, 0xe6e443d22747, 0xe6e861e08f21,
+    0xe6e89c6f94e6, 0xe6f0636e5910, 0xe6f1c2c87bae, 0xe6f237e68738,
+    0xe6f4817cc0ea, 0xe6f53129d239, 0xe6f7b54f11b0, 0xe6fecca0c48b,
+    0xe703253e322a, 0xe7040f7a493e, 0xe70917c4c82c, 0xe70c86261eb7,
+    0xe70e952d52a4, 0xe710a4348691, 0xe713d806d757, 0xe7141295dd1c,
+    0xe7144d24e2e1, 0xe714fcd1f430, 0xe715ac7f057f, 0xe7165c2c16ce,
+    0xe718e0515645, 0xe7198ffe6794, 0xe71a7a3a7ea8, 0xe71ab4c9846d,
+    0xe71b29e78ff7, 0xe71b647695bc, 0xe71f0d66f20c, 0xe720a7501a6f,
+    0xe7232b7559e6, 0xe723db226b35, 0xe724504076bf, 0xe72624b8a4e7,
+    0xe7274983c1c0, 0xe72b6792299a, 0xe72c173f3ae9, 0xe72fc02f9739,
+    0xe73418cd04d8, 0xe73578272776, 0xe73712104fd9, 0xe737872e5b63,
+    0xe737fc4c66ed, 0xe739211783c6, 0xe73abb00ac29, 0xe73f139e19c8,
+    0xe74072f83c66, 0xe740e81647f0, 0xe7436c3b8767, 0xe7449106a440,
+    0xe7450624afca, 0xe74ea19ba21c, 0xe752fa390fbb, 0xe7536f571b45,
+    0xe753e47526cf, 0xe755094043a8, 0xe756ddb871d0, 0xe7583d12946e,
+    0xe758ecbfa5bd, 0xe75ac137d3e5, 0xe75bab73eaf9, 0xe75c5b20fc48,
+    0xe75e6a283035, 0xe7624da7924a, 0xe7628836980f, 0xe76337e3a95e,
+    0xe763ad01b4e8, 0xe765bc08e8d5, 0xe768402e284c, 0xe76ac45367c3,
+    0xe76e6d43c413, 0xe76f920ee0ec, 0xe7712bf8094f, 0xe77166870f14,
+    0xe771a11614d9, 0xe773b01d48c6, 0xe7796814d903, 0xe77ee57d637b,
+    0xe7800a488054, 0xe7807f668bde, 0xe78169a2a2f2, 0xe7833e1ad11a,
+    0xe783b338dca4, 0xe78846655008, 0xe788f6126157, 0xe78930a1671c,
+    0xe78aca8a8f7f, 0xe78dc3cdda80, 0xe78f5db702e3, 0xe790f7a02b46,
+    0xe794db1f8d5b, 0xe795503d98e5, 0xe797d462d85c, 0xe79d51cb62d4,
+    0xe79f9b619c86, 0xe7a0859db39a, 0xe7a1354ac4e9, 0xe7a25a15e1c2,
+    0xe7a294a4e787, 0xe7a34451f8d6, 0xe7a603063e12, 0xe7a97167949d,
+    0xe7abf58cd414, 0xe7ac301bd9d9, 0xe7aeb4411950, 0xe7b04e2a41b3,
+    0xe7b088b94778, 0xe7b1386658c7, 0xe7b5cb92cc2b, 0xe7b640b0d7b5,
+    0xe7b7a00afa53, 0xe7b97483287b, 0xe7bb48fb56a3, 0xe7bf6709be7d,
+    0xe7bfdc27ca07, 0xe7c0c663e11b, 0xe7c385182657, 0xe7c72e0882a7,
+    0xe7c76897886c, 0xe7c88d62a545, 0xe7ca61dad36d, 0xe7cb1187e4bc,
+    0xe7cb4c16ea81, 0xe7cb86a5f046, 0xe7cbfbc3fbd0, 0xe7cd208f18a9,
+    0xe7ce7fe93b47, 0xe7ceba78410c, 0xe7d731241685, 0xe7df32b1e074,
+    0xe7e0577cfd4d, 0xe7e0cc9b08d7, 0xe7e17c481a26, 0xe7e26684313a,
+    0xe7e350c0484e, 0xe7e8590ac73c, 0xe7e9b864e9da, 0xe7eadd3006b3,
+    0xe7f4edc5048f, 0xe7f5d8011ba3, 0xe7f687ae2cf2, 0xe7f6c23d32b7,
+    0xe7f7ac7949cb, 0xe7f896b560df, 0xe7f8d14466a4, 0xe7fae04b9a91,
+    0xe7fcef52ce7e, 0xe80023251f44, 0xe8005db42509, 0xe80098432ace,
+    0xe800d2d23093, 0xe801bd0e47a7, 0xe802322c5331, 0xe8026cbb58f6,
+    0xe8031c686a45, 0xe8044133871e, 0xe8052b6f9e32, 0xe80565fea3f7,
+    0xe806503abb0b, 0xe8077505d7e4, 0xe809497e060c, 0xe80b1df63434,
+    0xe80bcda34583, 0xe80c42c1510d, 0xe811fab8e14a, 0xe8135a1303e8,
+    0xe814b96d2686, 0xe8152e8b3210, 0xe816c8745a73, 0xe81b2111c812,
+    0xe81b962fd39c, 0xe81cbafaf075, 0xe82063eb4cc5, 0xe8214e2763d9,
+    0xe82188b6699e, 0xe82397bd9d8b, 0xe82531a6c5ee, 0xe8282aea10ef,
+    0xe8298a44338d, 0xe82cbe168453, 0xe8302c77dade, 0xe8344a8642b8,
+    0xe836598d76a5, 0xe8386894aa92, 0xe838a323b057, 0xe838ddb2b61c,
+    0xe83b61d7f593, 0xe8439df4c547, 0xe843d883cb0c, 0xe8441312d0d1,
+    0xe846221a04be, 0xe847f69232e6, 0xe848312138ab, 0xe84a059966d3,
+    0xe84ab5467822, 0xe84cc44dac0f, 0xe8527c453c4c, 0xe852f16347d6,
+    0xe853a1105925, 0xe85450bd6a74, 0xe8557588874d, 0xe8562535989c,
+    0xe858343ccc89, 0xe858e3e9ddd8, 0xe859ce25f4ec, 0xe85bdd2d28d9,
+    0xe85ed67073da, 0xe8615a95b351, 0xe862f47edbb4, 0xe86578a41b2b,
+    0xe8669d6f3804, 0xe867128d438e, 0xe86787ab4f18, 0xe86996b28305,
+    0xe86b6b2ab12d, 0xe86cca84d3cb, 0xe86db4c0eadf, 0xe8715db1472f,
+    0xe8775037dd31, 0xe87924b00b59, 0xe8807690c3f9, 0xe8819b5be0d2,
+    0xe8824b08f221, 0xe88335450935, 0xe883aa6314bf, 0xe883e4f21a84,
+    0xe88509bd375d, 0xe886691759fb, 0xe886de356585, 0xe88718c46b4a,
+    0xe8883d8f8823, 0xe888b2ad93ad, 0xe88ac1b4c79a, 0xe890048e4c4d,
+    0xe89079ac57d7, 0xe891d9067a75, 0xe8921395803a, 0xe893ad7ea89d,
+    0xe895f714e24f, 0xe898b5c9278b, 0xe89be99b7851, 0xe89df8a2ac3e,
+    0xe89f928bd4a1, 0xe8a0b756f17a, 0xe8a3eb294240, 0xe8a425b84805,
+    0xe8a5fa30762d, 0xe8a66f4e81b7, 0xe8a7cea8a455, 0xe8aac7ebef56,
+    0xe8af20895cf5, 0xe8af95a7687f, 0xe8b00ac57409, 0xe8b12f9090e2,
+    0xe8b1df3da231, 0xe8b2c979b945, 0xe8b46362e1a8, 0xe8b96bad6096,
+    0xe8b9a63c665b, 0xe8bcda0eb721, 0xe8c082ff1371, 0xe8c1e259360f,
+    0xe8c29206475e, 0xe8c341b358ad, 0xe8c4667e7586, 0xe8c4a10d7b4b,
+    0xe8c5c5d89824, 0xe8c600679de9, 0xe8c7d4dfcc11, 0xe8ca59050b88,
+    0xe8ca9394114d, 0xe8cb08b21cd7, 0xe8cbf2ee33eb, 0xe8ccdd2a4aff,
+    0xe8cd17b950c4, 0xe8cf26c084b1, 0xe8cfd66d9600, 0xe8d1e574c9ed,
+    0xe8d4a4290f29, 0xe8d7d7fb5fef, 0xe8deb4be0d05, 0xe8e089363b2d,
+    0xe8e30d5b7aa4, 0xe8e4a744a307, 0xe8e5cc0fbfe0, 0xe8e7db16f3cd,
+    0xe8e9af8f21f5, 0xe8e9ea1e27ba, 0xe8ead45a3ece, 0xe8ef6786b232,
+    0xe8f051c2c946, 0xe8f435422b5b, 0xe8f46fd13120, 0xe8fa27c8c15d,
+    0xe8fbc1b1e9c0, 0xe906f7120475, 0xe907e14e1b89, 0xe90906193862,
+    0xe90940a83e27, 0xe90a65735b00, 0xe90aa00260c5, 0xe91107a70251,
+    0xe91142360816, 0xe9117cc50ddb, 0xe911b75413a0, 0xe914eb266466,
+    0xe91525b56a2b, 0xe9176f4ba3dd, 0xe91addacfa68, 0xe91b183c002d,
+    0xe91efbbb6242, 0xe92020867f1b, 0xe9205b1584e0, 0xe92095a48aa5,
+    0xe920d033906a, 0xe921ba6fa77e, 0xe921f4fead43, 0xe9247923ecba,
+    0xe926882b20a7, 0xe926fd492c31, 0xe9305e3118be, 0xe93232a946e6,
+    0xe9326d384cab, 0xe93441b07ad3, 0xe9368b46b485, 0xe9370064c00f,
+    0xe937b011d15e, 0xe937eaa0d723, 0xe939f9a80b10, 0xe93a343710d5,
+    0xe93c7dcd4a87, 0xe93fb19f9b4d, 0xe9414b88c3b0, 0xe94235c4dac4,
+    0xe9468e624863, 0xe947edbc6b01, 0xe94b96acc751, 0xe94e8ff01252,
+    0xe94eca7f1817, 0xe94fef4a34f0, 0xe9506468407a, 0xe956917ddc41,
+    0xe9577bb9f355, 0xe958db1415f3, 0xe95e930ba630, 0xe9602cf4ce93,
+    0xe960dca1dfe2, 0xe962eba913cf, 0xe9677ed58733, 0xe9682e829882,
+    0xe969534db55b, 0xe96b27c5e383, 0xe96bd772f4d2, 0xe96cc1af0be6,
+    0xe972b435a1e8, 0xe97a06165a88, 0xe97a7b346612, 0xe97af052719c,
+    0xe97b9fff82eb, 0xe980332bf64f, 0xe98207a42477, 0xe982b75135c6,
+    0xe98834b9c03e, 0xe988e466d18d, 0xe9899413e2dc, 0xe989cea2e8a1,
+    0xe98af36e057a, 0xe98e9c5e61ca, 0xe9915b12a706, 0xe99195a1accb,
+    0xe9920abfb855, 0xe992ba6cc9a4, 0xe995b3b014a5, 0xe996d87b317e,
+    0xe9a073f223d0, 0xe9a1d34c466e, 0xe9a4cc8f916f, 0xe9a5071e9734,
+    0xe9ac1e704a0f, 0xe9ad433b66e8, 0xe9b0ec2bc338, 0xe9b126bac8fd,
+    0xe9b45a8d19c3, 0xe9b9284892ec, 0xe9ba87a2b58a, 0xe9bbe6fcd828,
+    0xe9bc96a9e977, 0xe9bcd138ef3c, 0xe9c0ef475716, 0xe9c19ef46865,
+    0xe9c5bd02d03f, 0xe9c63220dbc9, 0xe9c8f0d52105, 0xe9c9db113819,
+    0xe9ca502f43a3, 0xe9cac54d4f2d, 0xe9cf5879c291, 0xe9d1dc9f0208,
+    0xe9d3b1173030, 0xe9d5c01e641d, 0xe9d9690ec06d, 0xe9da534ad781,
+    0xe9dac868e30b, 0xe9db3d86ee95, 0xe9df210650aa, 0xe9e00b4267be,
+    0xe9e1dfba95e6, 0xe9e28f67a735, 0xe9e2c9f6acfa, 0xe9e30485b2bf,
+    0xe9e3b432c40e, 0xe9e49e6edb22, 0xe9e588aaf236, 0xe9e672e7094a,
+    0xe9e7d2412be8, 0xe9e8bc7d42fc, 0xe9ec656d9f4c, 0xe9f1e2d629c4,
+    0xe9f2cd1240d8, 0xe9f466fb693b, 0xe9f4a18a6f00, 0xe9f79acdba01,
+    0xe9f84a7acb50, 0xe9f8bf98d6da, 0xe9fd18364479, 0xea00c126a0c9,
+    0xea06b3ad36cb, 0xea079de94ddf, 0xea07d87853a4, 0xea0813075969,
+    0xea08c2b46ab8, 0xea08fd43707d, 0xea0bf686bb7e, 0xea1089b32ee2,
+    0xea1139604031, 0xea125e2b5d0a, 0xea15576ea80b, 0xea17a104e1bd,
+    0xea1850b1f30c, 0xea19ea9b1b6f, 0xea22d664fc72, 0xea2310f40237,
+    0xea2435bf1f10, 0xea260a374d38, 0xea26b9e45e87, 0xea272f026a11,
+    0xea29037a9839, 0xea2b4d10d1eb, 0xea2bfcbde33a, 0xea2d21890013,
+    0xea2e0bc51727, 0xea2fa5ae3f8a, 0xea31b4b57377, 0xea33140f9615,
+    0xea33c3bca764, 0xea33fe4bad29, 0xea347369b8b3, 0xea35d2c3db51,
+    0xea37a73c0979, 0xea3aa07f547a, 0xea3b159d6004, 0xea3b502c65c9,
+    0xea4142b2fbcb, 0xea422cef12df, 0xea42dc9c242e, 0xea43172b29f3,
+    0xea44b1145256, 0xea44eba3581b, 0xea46c01b8643, 0xea481f75a8e1,
+    0xea4b18b8f3e2, 0xea4b5347f9a7, 0xea4ced31220a, 0xea510b3f89e4,
+    0xea52a528b247, 0xea540482d4e5, 0xea54eebeebf9, 0xea57e80236fa,
+    0xea59bc7a6522, 0xea5a6c277671, 0xea5ec4c4e410, 0xea605eae0c73,
+    0xea60993d1238, 0xea60d3cc17fd, 0xea61f89734d6, 0xea626db54060,
+    0xea67eb1dcad8, 0xea69bf95f900, 0xea6b1ef01b9e, 0xea6cf36849c6,
+    0xea71c123c2ef, 0xea735b0ceb52, 0xea75df322ac9, 0xea76c96e41dd,
+    0xea773e8c4d67, 0xea78d87575ca, 0xea7cf683dda4, 0xea7fb53822e0,
+    0xea8532a0ad58, 0xea86cc89d5bb, 0xea870718db80, 0xea886672fe1e,
+    0xea8b2527435a, 0xea8b5fb6491f, 0xea902d71c248, 0xea94860f2fe7,
+    0xea9535bc4136, 0xea95704b46fb, 0xea98a41d97c1, 0xea9b2842d738,
+    0xea9d374a0b25, 0xeaa86caa25da, 0xeaa8a7392b9f, 0xeaaa7bb159c7,
+    0xeaaba07c76a0, 0xeaae99bfc1a1, 0xeaaed44ec766, 0xeab576826eb7,
+    0xeab69b4d8b90, 0xeab95a01d0cc, 0xeabbde271043, 0xeac245cbb1cf,
+    0xeac3dfb4da32, 0xeac4c9f0f146, 0xeac5047ff70b, 0xeac5799e0295,
+    0xeac74e1630bd, 0xeac8385247d1, 0xeaca0cca75f9, 0xeacbe142a421,
+    0xeacfff510bfb, 0xead1241c28d4, 0xead283764b72, 0xead2f89456fc,
+    0xead333235cc1, 0xead3a841684b, 0xead75131c49b, 0xeadc597c4389,
+    0xeadd092954d8, 0xeadea3127d3b, 0xeae0b219b128, 0xeae19c55c83c,
+    0xeae21173d3c6, 0xeae28691df50, 0xeae2c120e515, 0xeae5ba643016,
+    0xeae803fa69c8, 0xeae8b3a77b17, 0xeae8ee3680dc, 0xeae99de3922b,
+    0xeaed46d3ee7b, 0xeaeee0bd16de, 0xeaef906a282d, 0xeaf04017397c,
+    0xeaf3e90795cc, 0xeaf45e25a156, 0xeafa161d3193, 0xeafac5ca42e2,
+    0xeafbea955fbb, 0xeafc25246580, 0xeb01dd1bf5bd, 0xeb0426b22f6f,
+    0xeb0635b9635c, 0xeb075a848035, 0xeb080a319184, 0xeb0844c09749,
+    0xeb08f46da898, 0xeb09698bb422, 0xeb0b3e03e24a, 0xeb11308a784c,
+    0xeb137a20b1fe, 0xeb16e8820889, 0xeb18826b30ec, 0xeb1a1c54594f,
+    0xeb1acc016a9e, 0xeb1b7bae7bed, 0xeb2258712903, 0xeb2467785cf0,
+    0xeb24a20762b5, 0xeb24dc96687a, 0xeb263bf08b18, 0xeb26eb9d9c67,
+    0xeb28faa4d054, 0xeb296fc2dbde, 0xeb3136c1a008, 0xeb33f575e544,
+    0xeb34a522f693, 0xeb3554d007e2, 0xeb3ca6b0c082, 0xeb472c63c9e8,
+    0xeb4b4a7231c2, 0xeb4bfa1f4311, 0xeb4c34ae48d6, 0xeb4ca9cc5460,
+    0xeb4ce45b5a25, 0xeb4ef3628e12, 0xeb50182daaeb, 0xeb56454346b2,
+    0xeb56ba61523c, 0xeb576a0e638b, 0xeb5bc2abd12a, 0xeb61efc16cf1,
+    0xeb62d9fd8405, 0xeb63148c89ca, 0xeb6598b1c941, 0xeb66bd7ce61a,
+    0xeb66f80bebdf, 0xeb6941a22591, 0xeb6ef999b5ce, 0xeb7267fb0c59,
+    0xeb72a28a121e, 0xeb759bcd5d1f, 0xeb789510a820, 0xeb790a2eb3aa,
+    0xeb79b9dbc4f9, 0xeb79f46acabe, 0xeb838fe1bd10, 0xeb859ee8f0fd,
+    0xeb8982685312, 0xeb8d2b58af62, 0xeb914967173c, 0xeb93586e4b29,
+    0xeb9392fd50ee, 0xeb947d396802, 0xeb94f257738c, 0xeb95a20484db,
+    0xeb9617229065, 0xeb973bedad3e, 0xeb9fed28887c, 0xeba062469406,
+    0xeba18711b0df, 0xeba35b89df07, 0xeba40b36f056, 0xeba4f573076a,
+    0xeba654cd2a08, 0xeba6c9eb3592, 0xeba9c32e8093, 0xebac4753c00a,
+    0xebae90e9f9bc, 0xebb3993478aa, 0xebb4f88e9b48, 0xebb69277c3ab,
+    0xebb7b742e084, 0xebb866eff1d3, 0xebbaeb15314a, 0xebbcfa1c6537,
+    0xebbece94935f, 0xebbfb8d0aa73, 0xebc70ab16313, 0xebc7ba5e7462,
+    0xebc7f4ed7a27, 0xebc8a49a8b76, 0xebc9c965a84f, 0xebcaee30c528,
+    0xebd41489abf0, 0xebd7bd7a0840, 0xebded4cbbb1b, 0xebe2432d11a6,
+    0xebe835b3a7a8, 0xebe8e560b8f7, 0xebeaf467ece4, 0xebeb2ef6f2a9,
+    0xebef127654be, 0xebefc223660d, 0xebf20bb99fbf, 0xebf2f5f5b6d3,
+    0xebf4554fd971, 0xebf4ca6de4fb, 0xebf504fceac0, 0xebf74e932472,
+    0xebfa82657538, 0xebffc53ef9eb, 0xec02f9114ab1, 0xec057d368a28,
+    0xec06a201a701, 0xec08eb97e0b3, 0xec0960b5ec3d, 0xec0d44354e52,
+    0xec0f533c823f, 0xec10ed25aaa2, 0xec13e668f5a3, 0xec199e6085e0,
+    0xec1a4e0d972f, 0xec207b2332f6, 0xec23aef583bc, 0xec2499319ad0,
+    0xec2757e5e00c, 0xec28b74002aa, 0xec29a17c19be, 0xec2b75f447e6,
+    0xec2beb125370, 0xec30f35cd25e, 0xec3252b6f4fc, 0xec35868945c2,
+    0xec3cd869fe62, 0xec43b52cab78, 0xec449f68c28c, 0xec47d33b1352,
+    0xec4bb6ba7567, 0xec52ce0c2842, 0xec53432a33cc, 0xec55523167b9,
+    0xec58c092be44, 0xec59e55ddb1d, 0xec5e3dfb48bc, 0xec5f28375fd0,
+    0xec608791826e, 0xec625c09b096, 0xec629698b65b, 0xec646b10e483,
+    0xec6729c529bf, 0xec68fe3d57e7, 0xec6ad2b5860f, 0xec6b0d448bd4,
+    0xec6c320fa8ad, 0xec708aad164c, 0xec730ed255c3, 0xec77a1fec927,
+    0xec77dc8dceec, 0xec7f2e6e878c, 0xec7fde1b98db, 0xec8a293f9c7c,
+    0xec8b4e0ab955, 0xec8bc328c4df, 0xec8f318a1b6a, 0xec9056553843,
+    0xec91b5af5ae1, 0xec96836ad40a, 0xec97e2c4f6a8, 0xec99f1cc2a95,
+    0xec9b51264d33, 0xec9bc64458bd, 0xeca1b8caeebf, 0xeca31825115d,
+    0xeca352b41722, 0xeca8d01ca19a, 0xecaa6a05c9fd, 0xecab19b2db4c,
+    0xecab5441e111, 0xecab8ed0e6d6, 0xecacee2b0974, 0xecb10c39714e,
+    0xecb181577cd8, 0xecb689a1fbc6, 0xecb8238b2429, 0xecb948564102,
+    0xecb9bd744c8c, 0xecbbcc7b8079, 0xecc10f55052c, 0xecc776f9a6b8,
+    0xecc94b71d4e0, 0xecc9c08fe06a, 0xeccc0a261a1c, 0xeccd69803cba,
+    0xecd0283481f6, 0xecd14cff9ecf, 0xecd32177ccf7, 0xecd44642e9d0,
+    0xecd56b0e06a9, 0xecd77a153a96, 0xecd7ef334620, 0xecd8645151aa,
+    0xecd913fe62f9, 0xecdb230596e6, 0xecdb9823a270, 0xecdcbceebf49,
+    0xece0a06e215e, 0xece1158c2ce8, 0xece7b7bfd439, 0xecf02e6ba9b2,
+    0xecf3d75c0602, 0xecf44c7a118c, 0xecf4fc2722db, 0xecf536b628a0,
+    0xecf5e66339ef, 0xecf7804c6252, 0xecfb63cbc467, 0xecfde7f103de,
+    0xed04c4b3b0f4, 0xed083315077f, 0xed0c8bb2751e, 0xed1034a2d16e,
+    0xed106f31d733, 0xed1193fcf40c, 0xed1243aa055b, 0xed1452b13948,
+    0xed18e5ddacac, 0xed19206cb271, 0xed19d019c3c0, 0xed2037be654c,
+    0xed20724d6b11, 0xed2455cccd26, 0xed2714811262, 0xed2a48536328,
+    0xed2f160edc51, 0xed307568feef, 0xed319a341bc8, 0xed32847032dc,
+    0xed35f2d18967, 0xed36a27e9ab6, 0xed39d650eb7c, 0xed3ac08d0290,
+    0xed3d44b24207, 0xed433738d809, 0xed43e6e5e958, 0xed45f5ed1d45,
+    0xed48b4a16281, 0xed49644e73d0, 0xed4a4e8a8ae4, 0xed4ac3a8966e,
+    0xed4ea727f883, 0xed50f0be3235, 0xed52501854d3, 0xed52ffc56622,
+    0xed56e344c837, 0xed58080fe510, 0xed5c9b3c5874, 0xed5f947fa375,
+    0xed60b94ac04e, 0xed6218a4e2ec, 0xed63b28e0b4f, 0xed654c7733b2,
+    0xed65c1953f3c, 0xed667142508b, 0xed680b2b78ee, 0xed68f5679002,
+    0xed6b046ec3ef, 0xed6dc323092b, 0xed6fd22a3d18, 0xed70bc66542c,
+    0xed70f6f559f1, 0xed716c13657b, 0xed737b1a9968, 0xed746556b07c,
+    0xed78f88323e0, 0xed81e44d04e3, 0xed8727268996, 0xed8a2069d497,
+    0xed9137bb8772, 0xed91e76898c1, 0xed930c33b59a, 0xed946b8dd838,
+    0xed94e0abe3c2, 0xed96b52411ea, 0xed984f0d3a4d, 0xed98899c4012,
+    0xed9939495161, 0xeda1aff526da, 0xeda1ea842c9f, 0xeda225133264,
+    0xeda5ce038eb4, 0xeda643219a3e, 0xeda7dd0ac2a1, 0xedabc08a24b6,
+    0xedac35a83040, 0xedacaac63bca, 0xedaf697a8106, 0xedb13df2af2e,
+    0xedb262bdcc07, 0xedb55c011708, 0xedb680cc33e1, 0xedb76b084af5,
+    0xedb7e026567f, 0xedbbc3a5b894, 0xedbce870d56d, 0xedbf6c9614e4,
+    0xedbfe1b4206e, 0xedc1b62c4e96, 0xedc3c5338283, 0xedc3ffc28848,
+    0xedc5248da521, 0xedcadc85355e, 0xedcb17143b23, 0xedcb8c3246ad,
+    0xedcbc6c14c72, 0xedcefa939d38, 0xedcf3522a2fd, 0xedcfe4cfb44c,
+    0xedd14429d6ea, 0xedd4ed1a333a, 0xedd98046a69e, 0xeddadfa0c93c,
+    0xeddb54bed4c6, 0xeddc3efaebda, 0xeddcb418f764, 0xede26c1087a1,
+    0xede8240817de, 0xedea6d9e5190, 0xedeb1d4b62df, 0xedebccf8742e,
+    0xedecb7348b42, 0xedefeb06dc08, 0xedf02595e1cd, 0xedf4091543e2,
+    0xedf443a449a7, 0xedf94beec895, 0xedfc7fc1195b, 0xedff7904645c,
+    0xedffee226fe6, 0xee01fd29a3d3, 0xee035c83c671, 0xee03d1a1d1fb,
+    0xee0530fbf499, 0xee07057422c1, 0xee09147b56ae, 0xee0aae647f11,
+    0xee0c484da774, 0xee10db7a1ad8, 0xee132510548a, 0xee139a2e6014,
+    0xee17b83cc7ee, 0xee1c4b693b52, 0xee1f0a1d808e, 0xee28a59472e0,
+    0xee28e02378a5, 0xee291ab27e6a, 0xee295541842f, 0xee2b6448b81c,
+    0xee2cfe31e07f, 0xee306c93370a, 0xee32410b6532, 0xee32b62970bc,
+    0xee32f0b87681, 0xee36248ac747, 0xee37f902f56f, 0xee3bdc825784,
+    0xee3fc001b999, 0xee40351fc523, 0xee40aa3dd0ad, 0xee4159eae1fc,
+    0xee427eb5fed5, 0xee432e631024, 0xee43de102173, 0xee44532e2cfd,
+    0xee4627a65b25, 0xee47c18f8388, 0xee4c1a2cf127, 0xee57c4ab1766,
+    0xee59d3b24b53, 0xee5be2b97f40, 0xee5fc638e155, 0xee60b074f869,
+    0xee61602209b8, 0xee6284ed2691, 0xee66dd8a9430, 0xee6961afd3a7,
+    0xee6a4bebeabb, 0xee6fc9547533, 0xee703e7280bd, 0xee72c297c034,
+    0xee7421f1e2d2, 0xee792a3c61c0, 0xee7bae61a137, 0xee80b6ac2025,
+    0xee82c5b35412, 0xee845f9c7c75, 0xee849a2b823a, 0xee90b9c7b403,
+    0xee95126521a2, 0xee954cf42767, 0xee9a553ea655, 0xeea256cc7044,
+    0xeea341088758, 0xeea465d3a431, 0xeea5500fbb45, 0xeea58a9ec10a,
+    0xeeaf60a4b921, 0xeeb01051ca70, 0xeeb1351ce749, 0xeeb2cf060fac,
+    0xeeb42e60324a, 0xeeb762328310, 0xeeb8fc1bab73, 0xeeb9abc8bcc2,
+    0xeebd1a2a134d, 0xeebd54b91912, 0xeec4e128d777, 0xeec6f0300b64,
+    0xeec7654e16ee, 0xeec88a1933c7, 0xeece7c9fc9c9, 0xeed2d53d3768,
+    0xeed5ce808269, 0xeed67e2d93b8, 0xeed81816bc1b, 0xeed852a5c1e0,
+    0xeee2633abfbc, 0xeee7a614446f, 0xeee9b51b785c, 0xeeeb14759afa,
+    0xeef1418b36c1, 0xeef4afec8d4c, 0xeef68464bb74, 0xeef90889faeb,
+    0xeefdd6457414, 0xeeffaabda23c, 0xef010a17c4da, 0xef03c8cc0a16,
+    0xef04035b0fdb, 0xef04b308212a, 0xef0562b53279, 0xef0687804f52,
+    0xef08d1168904, 0xef0a6affb167, 0xef0b553bc87b, 0xef0c3f77df8f,
+    0xef110d3358b8, 0xef126c8d7b56, 0xef140676a3b9, 0xef17af670009,
+    0xef194950286c, 0xef19be6e33f6, 0xef1aa8aa4b0a, 0xef1cf24084bc,
+    0xef1f7665c433, 0xef20d5bfe6d1, 0xef21104eec96, 0xef23cf0331d2,
+    0xef273d64885d, 0xef294c6bbc4a, 0xef2e54b63b38, 0xef32ad53a8d7,
+    0xef332271b461, 0xef34473cd13a, 0xef37b59e27c5, 0xef398a1655ed,
+    0xef40a16808c8, 0xef4200c22b66, 0xef4275e036f0, 0xef44fa057667,
+    0xef4a776e00df, 0xef502f65911c, 0xef5119a1a830, 0xef55723f15cf,
+    0xef56d199386d, 0xef5746b743f7, 0xef591b2f721f, 0xef5c8990c8aa,
+    0xef627c175eac, 0xef62b6a66471, 0xef648b1e9299, 0xef669a25c686,
+    0xef691e4b05fd, 0xef6993691187, 0xef6a08871d11, 0xef6a431622d6,
+    0xef6ba2704574, 0xef70aabac462, 0xef727f32f28a, 0xef75b3054350,
+    0xef7662b2549f, 0xef7871b9888c, 0xef795bf59fa0, 0xef7ba58bd952,
+    0xef862b3ee2b8, 0xef8665cde87d, 0xef87c5280b1b, 0xef88e9f327f4,
+    0xef8ccd728a09, 0xef92104c0ebc, 0xef92fa8825d0, 0xef94cf0053f8,
+    0xef95b93c6b0c, 0xef9668e97c5b, 0xef97c8439ef9, 0xef98b27fb60d,
+    0xef999cbbcd21, 0xef9c95ff1822, 0xef9f8f426323, 0xefa0797e7a37,
+    0xefa33832bf73, 0xefa581c8f925, 0xefa805ee389c, 0xefaa8a137813,
+    0xefb216833678, 0xefb66f20a417, 0xefb9a2f2f4dd, 0xefba8d2f0bf1,
+    0xefc0ba44a7b8, 0xefc1df0fc491, 0xefca1b2c9445, 0xefcc2a33c832,
+    0xefd58b1bb4bf, 0xefd5c5aaba84, 0xefda1e482823, 0xefda936633ad,
+    0xefe17028e0c3, 0xefe2cf830361, 0xefe728207100, 0xefed1aa70702,
+    0xefef643d40b4, 0xeff1e862802b, 0xeffce333951b, 0xf0005194eba6,
+    0xf0088db1bb5a, 0xf009b27cd833, 0xf00ad747f50c, 0xf0138882d04a,
+    0xf0155cfafe72, 0xf01681c61b4b, 0xf0181baf43ae, 0xf018cb5c54fd,
+    0xf01a9fd48325, 0xf01b14f28eaf, 0xf01c39bdab88, 0xf021077924b1,
+    0xf0217c97303b, 0xf023510f5e63, 0xf028ce77e8db, 0xf030d005b2ca,
+    0xf0317fb2c419, 0xf032a47de0f2, 0xf032df0ce6b7, 0xf033542af241,
+    0xf034b38514df, 0xf03528a32069, 0xf036c28c48cc, 0xf03737aa5456,
+    0xf03fae5629cf, 0xf041483f5232, 0xf0435746861f, 0xf04406f3976e,
+    0xf044b6a0a8bd, 0xf0452bbeb447, 0xf0470036e26f, 0xf049845c21e6,
+    0xf04cb82e72ac, 0xf052702602e9, 0xf054b9bc3c9b, 0xf0568e346ac3,
+    0xf05e55332eed, 0xf06238b29102, 0xf063980cb3a0, 0xf0656c84e1c8,
+    0xf067066e0a2b, 0xf0677b8c15b5, 0xf0698a9349a2, 0xf06b5f0b77ca,
+    0xf070dc740242, 0xf073609941b9, 0xf073d5b74d43, 0xf075e4be8130,
+    0xf076946b927f, 0xf079c83de345, 0xf080a500905b, 0xf08363b4d597,
+    0xf087473437ac, 0xf087bc524336, 0xf08b2ab399c1, 0xf08b9fd1a54b,
+    0xf090a81c2439, 0xf091cce74112, 0xf092f1b25deb, 0xf0953b48979d,
+    0xf09bdd7c3eee, 0xf09c180b44b3, 0xf09e61a17e65, 0xf09ed6bf89ef,
+    0xf0a03619ac8d, 0xf0a070a8b252, 0xf0a12055c3a1, 0xf0a454281467,
+    0xf0a6632f4854, 0xf0a69dbe4e19, 0xf0a8e75487cb, 0xf0a95c729355,
+    0xf0af89882f1c, 0xf0b03935406b, 0xf0b12371577f, 0xf0b36d079131,
+    0xf0b45743a845, 0xf0b9d4ac32bd, 0xf0bb6e955b20, 0xf0c71913815f,
+    0xf0c9281ab54c, 0xf0cbac3ff4c3, 0xf0ccd10b119c, 0xf0cea5833fc4,
+    0xf0d0b48a73b1, 0xf0d45d7ad001, 0xf0d66c8203ee, 0xf0d71c2f153d,
+    0xf0f672b92baa, 0xf0f75cf542be, 0xf0fb4074a4d3, 0xf0fb7b03aa98,
+    0xf0fbf021b622, 0xf0ff5e830cad, 0xf108bf6af93a, 0xf109348904c4,
+    0xf1096f180a89, 0xf10a5954219d, 0xf10b090132ec, 0xf110fb87c8ee,
+    0xf1158eb43c52, 0xf115c9434217, 0xf118c2868d18, 0xf118fd1592dd,
+    0xf11ad18dc105, 0xf11b813ad254, 0xf11e7a7e1d55, 0xf12089855142,
+    0xf12139326291, 0xf1230daa90b9, 0xf131b16c01f9, 0xf13435914170,
+    0xf136b9b680e7, 0xf1388e2eaf0f, 0xf13bfc90059a, 0xf1413f698a4d,
+    0xf141b48795d7, 0xf14388ffc3ff, 0xf1460d250376, 0xf148914a42ed,
+    0xf14906684e77, 0xf149f0a4658b, 0xf14aa05176da, 0xf15526048040,
+    0xf15560938605, 0xf15610409754, 0xf157aa29bfb7, 0xf1594412e81a,
+    0xf15bc8382791, 0xf160d082a67f, 0xf161f54dc358, 0xf162a4fad4a7,
+    0xf1631a18e031, 0xf1673827480b, 0xf167e7d4595a, 0xf16b1ba6aa20,
+    0xf16f744417bf, 0xf171f8695736, 0xf17775d1e1ae, 0xf177b060e773,
+    0xf1811148d400, 0xf1840a8c1f01, 0xf1868eb15e78, 0xf1902a2850ca,
+    0xf191c411792d, 0xf19273be8a7c, 0xf1944836b8a4, 0xf196cc5bf81b,
+    0xf197417a03a5, 0xf197b6980f2f, 0xf198a0d42643, 0xf19915f231cd,
+    0xf19aafdb5a30, 0xf19b24f965ba, 0xf1a0a261f032, 0xf1a1520f0181,
+    0xf1a5701d695b, 0xf1a7b9b3a30d, 0xf1b0e00c89d5, 0xf1b70d22259c,
+    0xf1b91c295989, 0xf1b956b85f4e, 0xf1b991476513, 0xf1bf493ef550,
+    0xf1c0a89917ee, 0xf1c6d5aeb3b5, 0xf1c7855bc504, 0xf1c8aa26e1dd,
+    0xf1ca0981047b, 0xf1cb2e4c2154, 0xf1cfc17894b8, 0xf1d2459dd42f,
+    0xf1d2f54ae57e, 0xf1d50452196b, 0xf1da81baa3e3, 0xf1dba685c0bc,
+    0xf1dbe114c681, 0xf1dc1ba3cc46, 0xf1e15e7d50f9, 0xf1e248b9680d,
+    0xf1e3e2a29070, 0xf1ed7e1982c2, 0xf1efc7afbc74, 0xf1f7c93d8663,
+    0xf1fb379edcee, 0xf1fc21daf402, 0xf1ff55ad44c8, 0xf2050da4d505,
+    0xf206326ff1de, 0xf20ee3aacd1c, 0xf2100875e9f5, 0xf2107d93f57f,
+    0xf210f2b20109, 0xf2128c9b296c, 0xf218f43fcaf8, 0xf2192eced0bd,
+    0xf219695dd682, 0xf21ac8b7f920, 0xf21d124e32d2, 0xf222ca45c30f,
+    0xf225fe1813d5, 0xf22798013c38, 0xf230493c1776, 0xf2360133a7b3,
+    0xf2384ac9e165, 0xf2393505f879, 0xf23bb92b37f0, 0xf23cddf654c9,
+    0xf23fd7399fca, 0xf2430b0bf090, 0xf243f54807a4, 0xf244a4f518f3,
+    0xf246044f3b91, 0xf24813566f7e, 0xf24b4728c044, 0xf252990978e4,
+    0xf2530e27846e, 0xf25557bdbe20, 0xf256076acf6f, 0xf259b05b2bbf,
+    0xf25a25793749, 0xf25b4a445422, 0xf25b84d359e7, 0xf25bf9f16571,
+    0xf260c7acde9a, 0xf264360e3525, 0xf2688eaba2c4, 0xf26ef6504450,
+    0xf276bd4f087a, 0xf27906e5422c, 0xf27a663f64ca, 0xf27c3ab792f2,
+    0xf27d24f3aa06, 0xf283c7275157, 0xf2843c455ce1, 0xf285268173f5,
+    0xf285611079ba, 0xf2909670946f, 0xf291461da5be, 0xf2931a95d3e6,
+    0xf29479eff684, 0xf295299d07d3, 0xf2990d1c69e8, 0xf2a392cf734e,
+    0xf2a47d0b8a62, 0xf2a89b19f23c, 0xf2a9fa7414da, 0xf2aa6f922064,
+    0xf2acb9285a16, 0xf2acf3b75fdb, 0xf2b02789b0a1, 0xf2b0d736c1f0,
+    0xf2b44598187b, 0xf2b4f54529ca, 0xf2b7b3f96f06, 0xf2b8d8c48bdf,
+    0xf2bb5ce9cb56, 0xf2bb9778d11b, 0xf2bc81b4e82f, 0xf2c14f706158,
+    0xf2c399069b0a, 0xf2c3d395a0cf, 0xf2c532efc36d, 0xf2c866c21433,
+    0xf2c950fe2b47, 0xf2c9c61c36d1, 0xf2cc4a417648, 0xf2d361932923,
+    0xf2d39c222ee8, 0xf2d7ba3096c2, 0xf2d82f4ea24c, 0xf2da03c6d074,
+    0xf2dbd83efe9c, 0xf2dcfd0a1b75, 0xf2e27a72a5ed, 0xf2e53926eb29,
+    0xf2e62363023d, 0xf2eaf11e7b66, 0xf2f0a9160ba3, 0xf2f32d3b4b1a,
+    0xf2f3dce85c69, 0xf2f4520667f3, 0xf2f576d184cc, 0xf2f6d62ba76a,
+    0xf2f74b49b2f4, 0xf2f8aaa3d592, 0xf2fba3e72093, 0xf2ff1248771e,
+    0xf2fffc848e32, 0xf300e6c0a546, 0xf3020b8bc21f, 0xf306299a29f9,
+    0xf30713d6410d, 0xf30af755a322, 0xf31199894a73, 0xf31249365bc2,
+    0xf315f226b812, 0xf3216215d88c, 0xf32211c2e9db, 0xf32a886ebf54,
+    0xf3307af55556, 0xf33632ece593, 0xf338b712250a, 0xf34217fa1197,
+    0xf3428d181d21, 0xf34795629c0f, 0xf3480a80a799, 0xf34bee0009ae,
+    0xf34cd83c20c2, 0xf350bbbb82d7, 0xf3521b15a575, 0xf359a78563da,
+    0xf35eea5ee88d, 0xf3679b99c3cb, 0xf371ac2ec1a7, 0xf37380a6efcf,
+    0xf374e001126d, 0xf37813d36333, 0xf3784e6268f8, 0xf379732d85d1,
+    0xf37b8234b9be, 0xf3830ea47823, 0xf387dc5ff14c, 0xf38a608530c3,
+    0xf38caa1b6a75, 0xf390530bc6c5, 0xf394aba93464, 0xf394e6383a29,
+    0xf3972fce73db, 0xf3993ed5a7c8, 0xf39b886be17a, 0xf3a105d46bf2,
+    0xf3a1f0108306, 0xf3a3c488b12e, 0xf3a857b52492, 0xf3a8ccd3301c,
+    0xf3ab166969ce, 0xf3b2684a226e, 0xf3b402334ad1, 0xf3b43cc25096,
+    0xf3bb1984fdac, 0xf3c2e083c1d6, 0xf3c3cabfd8ea, 0xf3c52a19fb88,
+    0xf3c564a9014d, 0xf3c61456129c, 0xf3c8235d4689, 0xf3cd6636cb3c,
+    0xf3d442f97852, 0xf3d567c4952b, 0xf3d5a2539af0, 0xf3d73c3cc353,
+    0xf3dc44874241, 0xf3dcb9a54dcb, 0xf3e18760c6f4, 0xf3e480a411f5,
+    0xf3e73f585731, 0xf3ebd284ca95, 0xf3f0a04043be, 0xf3f40ea19a49,
+    0xf3f98c0a24c1, 0xf3f9c6992a86, 0xf4002e3dcc12, 0xf40068ccd1d7,
+    0xf400a35bd79c, 0xf4039c9f229d, 0xf406d0717363, 0xf40a0443c429,
+    0xf40aee7fdb3d, 0xf412f00da52c, 0xf4144f67c7ca, 0xf414ff14d919,
+    0xf41698fe017c, 0xf4186d762fa4, 0xf41b2c2a74e0, 0xf41ba148806a,
+    0xf4203474f3ce, 0xf425774e7881, 0xf4269c19955a, 0xf4271137a0e4,
+    0xf429203ed4d1, 0xf4295acdda96, 0xf42a0a7aebe5, 0xf42c54112597,
+    0xf4315c5ba485, 0xf4328126c15e, 0xf432bbb5c723, 0xf432f644cce8,
+    0xf433e080e3fc, 0xf437fe8f4bd6, 0xf438391e519b, 0xf439d30779fe,
+    0xf43a82b48b4d, 0xf4403aac1b8a, 0xf44493498929, 0xf4462d32b18c,
+    0xf450ed74c0b7, 0xf45496651d07, 0xf455bb3039e0, 0xf4599eaf9bf5,
+    0xf459d93ea1ba, 0xf45ac37ab8ce, 0xf45afe09be93, 0xf460f0905495,
+    0xf462155b716e, 0xf4624fea7733, 0xf46374b5940c, 0xf465f8dad383,
+    0xf4666df8df0d, 0xf467cd5301ab, 0xf46842710d35, 0xf46b3bb45836,
+    0xf46d102c865e, 0xf4728d9510d6, 0xf4749c9c44c3, 0xf474d72b4a88,
+    0xf47720c1843a, 0xf47e72a23cda, 0xf48131568216, 0xf48305ceb03e,
+    0xf48514d5e42b, 0xf4880e192f2c, 0xf489a802578f, 0xf48b41eb7ff2,
+    0xf48d8b81b9a4, 0xf48dc610bf69, 0xf48fd517f356, 0xf499ab1deb6d,
+    0xf49c69d230a9, 0xf4a3f641ef0e, 0xf4a8fe8c6dfc, 0xf4aca77cca4c,
+    0xf4ace20bd011, 0xf4ad5729db9b, 0xf4b015de20d7, 0xf4b1001a37eb,
+    0xf4b25f745a89, 0xf4b29a03604e, 0xf4b30f216bd8, 0xf4b6b811c828,
+    0xf4b93c37079f, 0xf4bb85cd4151, 0xf4c10335cbc9, 0xf4c29d1ef42c,
+    0xf4c3123cffb6, 0xf4c34ccc057b, 0xf4c4ac262819, 0xf4c855168469,
+    0xf4ca641db856, 0xf4d09133541d, 0xf4d22b1c7c80, 0xf4d265ab8245,
+    0xf4d6492ae45a, 0xf4d81da31282, 0xf4d8cd5023d1, 0xf4e1096cf385,
+    0xf4e22e38105e, 0xf4e268c71623, 0xf4e318742772, 0xf4e402b03e86,
+    0xf4e686d57dfd, 0xf4ea6a54e012, 0xf4eb5490f726, 0xf4f1bc3598b2,
+    0xf4f3561ec115, 0xf4f5da44008c, 0xf5005ff709f2, 0xf50393c95ab8,
+    0xf505684188e0, 0xf51864a067bf, 0xf518d9be7349, 0xf51c481fc9d4,
+    0xf5223aa65fd6, 0xf522afc46b60, 0xf524f95aa512, 0xf5298c871876,
+    0xf52a01a52400, 0xf52bd61d5228, 0xf5315385dca0, 0xf5323dc1f3b4,
+    0xf53537053eb5, 0xf536965f6153, 0xf5391a84a0ca, 0xf5448a73c144,
+    0xf546245ce9a7, 0xf5470e9900bb, 0xf547be46120a, 0xf54a426b5181,
+    0xf54b2ca76895, 0xf54ffa62e1be, 0xf5532e353284, 0xf55368c43849,
+    0xf55920bbc886, 0xf560ad2b86eb, 0xf5657ae70014, 0xf565f0050b9e,
+    0xf568742a4b15, 0xf5695e666229, 0xf56c57a9ad2a, 0xf56d0756be79,
+    0xf56e66b0e117, 0xf573a98a65ca, 0xf5812880ba31, 0xf58212bcd145,
+    0xf585468f220b, 0xf586e0784a6e, 0xf5871b075033, 0xf58cd2fee070,
+    0xf58d82abf1bf, 0xf58ea7770e98, 0xf5950f1bb024, 0xf596a904d887,
+    0xf59758b1e9d6, 0xf59c60fc68c4, 0xf5a8460994c8, 0xf5a9a563b766,
+    0xf5aa1a81c2f0, 0xf5ab04bdda04, 0xf5abeef9f118, 0xf5ae731f308f,
+    0xf5b21c0f8cdf, 0xf5b4a034cc56, 0xf5b5c4ffe92f, 0xf5b63a1df4b9,
+    0xf5b674acfa7e, 0xf5bca1c29645, 0xf5c21f2b20bd, 0xf5c259ba2682,
+    0xf5c58d8c7748, 0xf5c84c40bc84, 0xf5d25cd5ba60, 0xf5d381a0d739,
+    0xf5d640551c75, 0xf5d6b57327ff, 0xf5d7da3e44d8, 0xf5d8c47a5bec,
+    0xf5da5e63844f, 0xf5dad3818fd9, 0xf5dca7f9be01, 0xf5e08b792016,
+    0xf5e13b263165, 0xf5e225624879, 0xf5e46ef8822b, 0xf5ebfb684090,
+    0xf5f08e94b3f4, 0xf5f13e41c543, 0xf5f6468c4431, 0xf5fa9f29b1d0,
+    0xf5ff32562534, 0xf6001c923c48, 0xf60091b047d2, 0xf601f10a6a70,
+    0xf602a0b77bbf, 0xf6060f18d24a, 0xf60649a7d80f, 0xf607a901faad,
+    0xf607e3910072, 0xf608933e11c1, 0xf6114478ecff, 0xf612de621562,
+    0xf61aa560d98c, 0xf61b8f9cf0a0, 0xf61c3f4a01ef, 0xf61ec36f4166,
+    0xf61fadab587a, 0xf621822386a2, 0xf624f084dd2d, 0xf6252b13e2f2,
+    0xf625dac0f441, 0xf628d4043f42, 0xf62d6730b2a6, 0xf62e516cc9ba,
+    0xf6347e826581, 0xf63a7108fb83, 0xf63c458129ab, 0xf6439761e24b,
+    0xf64705c338d6, 0xf655a984aa16, 0xf6565931bb65, 0xf65b9c0b4018,
+    0xf6602f37b37c, 0xf66203afe1a4, 0xf67d3c2b9037, 0xf67d76ba95fc,
+    0xf6815a39f811, 0xf68209e70960, 0xf690e8378065, 0xf6915d558bef,
+    0xf69fc687f76a, 0xf6a19b002592, 0xf6a2101e311c, 0xf6a802a4c71e,
+    0xf6aa4c3b00d0, 0xf6b3e7b1f322, 0xf6b5bc2a214a, 0xf6c2c6026a27,
+    0xf6c3b03e813b, 0xf6c8b8890029, 0xf6d07f87c453, 0xf6d0ba16ca18,
+    0xf6d33e3c098f, 0xf6da558dbc6a, 0xf6df23493593, 0xf6e291aa8c1e,
+    0xf6e515cfcb95, 0xf6e5505ed15a, 0xf6e6000be2a9, 0xf6e6afb8f3f8,
+    0xf6ebf29278ab, 0xf6f0c04df1d4, 0xf6f6b2d487d6, 0xf6f84cbdb039,
+    0xf7004e4b7a28, 0xf7059124fedb, 0xf70606430a65, 0xf70974a460f0,
+    0xf7134aaa5907, 0xf718c812e37f, 0xf71977bff4ce, 0xf71b86c728bb,
+    0xf71c36743a0a, 0xf7260c7a3221, 0xf7322c1663ea, 0xf7338b708688,
+    0xf734008e9212, 0xf73684b3d189, 0xf737e40df427, 0xf73aa2c23963,
+    0xf73b526f4ab2, 0xf7443e392bb5, 0xf745287542c9, 0xf74ec3ec351b,
+    0xf74fe8b751f4, 0xf75148117492, 0xf752324d8ba6, 0xf757000904cf,
+    0xf758d48132f7, 0xf75aa8f9611f, 0xf760d60efce6, 0xf7735d4fd03b,
+    0xf778dab85ab3, 0xf77f07cdf67a, 0xf783606b6419, 0xf786943db4df,
+    0xf78e5b3c7909, 0xf795ad1d31a9, 0xf799909c93be, 0xf79ab567b097,
+    0xf7a366a28bd5, 0xf7a9ce472d61, 0xf7adec55953b, 0xf7b69d907079,
+    0xf7b8377998dc, 0xf7b95c44b5b5, 0xf7ba810fd28e, 0xf7c2480e96b8,
+    0xf7c4cc33d62f, 0xf7c6db3b0a1c, 0xf7d5f41a86e6, 0xf7d80321bad3,
+    0xf7d8783fc65d, 0xf7ebe9bcb0c6, 0xf7f3b0bb74f0, 0xf7fc61f6502e,
+    0xf802547ce630, 0xf8028f0bebf5, 0xf802c99af1ba, 0xf80bb564d2bd,
+    0xf81341d49122, 0xf8137c6396e7, 0xf8163b17dc23, 0xf81bf30f6c60,
+    0xf81c682d77ea, 0xf81eb1c3b19c, 0xf82135e8f113, 0xf821e5960262,
+    0xf82220250827, 0xf824a44a479e, 0xf825c9156477, 0xf8293776bb02,
+    0xf82a5c41d7db, 0xf831e8b19640, 0xf833f7b8ca2d, 0xf83c33d599e1,
+    0xf8408c730780, 0xf84385b65281, 0xf844aa816f5a, 0xf8472ea6aed1,
+    0xf847de53c020, 0xf84a9d08055c, 0xf84bc1d32235, 0xf85522bb0ec2,
+    0xf85c3a0cc19d, 0xf861f20451da, 0xf8668530c53e, 0xf867a9fbe217,
+    0xf86b8d7b442c, 0xf87020a7b790, 0xf871f51fe5b8, 0xf873547a0856,
+    0xf8747945252f, 0xf8764dbd5357, 0xf876fd6a64a6, 0xf87fe93445a9,
+    0xf88441d1b348, 0xf88775a4040e, 0xf88a6ee74f0f, 0xf88fec4fd987,
+    0xf891863901ea, 0xf891fb570d74, 0xf892707518fe, 0xf892e5932488,
+    0xf8935ab13012, 0xf89778bf97ec, 0xf89c467b1115, 0xf89f7a4d61db,
+    0xf8a029fa732a, 0xf8a0648978ef, 0xf8a706bd2040, 0xf8ae589dd8e0,
+    0xf8b4fad18031, 0xf8b9c88cf95a, 0xf8bb627621bd, 0xf8bff5a29521,
+    0xf8c154fcb7bf, 0xf8c65d4736ad, 0xf8c697d63c72, 0xf8c9568a81ae,
+    0xf8d416cc90d9, 0xf8d48bea9c63, 0xf8db2e1e43b4, 0xf8ded70ea004,
+    0xf8e120a4d9b6, 0xf8e628ef58a4, 0xf8ec5604f46b, 0xf8fc59208849,
+    0xf916e1ef258d, 0xf9192b855f3f, 0xf91bea39a47b, 0xf91f589afb06,
+    0xf920f2842369, 0xf922c6fc5191, 0xf923018b5756, 0xf927cf46d07f,
+    0xf9292ea0f31d, 0xf931a54cc896, 0xf935c35b3070, 0xf93c2affd1fc,
+    0xf93d8a59f49a, 0xf93e74960bae, 0xf943b76f9061, 0xf945c676c44e,
+    0xf947d57df83b, 0xf94acec1433c, 0xf94b7e6e548b, 0xf94d18577cee,
+    0xf950c147d93e, 0xf953456d18b5, 0xf957637b808f, 0xf958132891de,
+    0xf95a5cbecb90, 0xf9604f456192, 0xf969759e485a, 0xf96bf9c387d1,
+    0xf96d591daa6f, 0xf971b1bb180e, 0xf97386334636, 0xf97b4d320a60,
+    0xf984ae19f6ed, 0xf988570a533d, 0xf996108fad69, 0xf997aa78d5cc,
+    0xf99a2e9e1543, 0xf9a59e8d35bd, 0xf9a5d91c3b82, 0xf9ab5684c5fa,
+    0xf9c0275bd301, 0xf9cd6bc321a3, 0xf9d02a7766df, 0xf9d5a7dff157,
+    0xf9d8dbb2421d, 0xf9d950d04da7, 0xf9dd344fafbc, 0xf9e1c77c2320,
+    0xf9f36480df61, 0xf9f39f0fe526, 0xf9fdea33e8c7, 0xf9ff841d112a,
+    0xfa041749848e, 0xfa07c039e0de, 0xfa0bde4848b8, 0xfa1663fb521e,
+    0xfa187302860b, 0xfa1922af975a, 0xfa1be163dc96, 0xfa215ecc670e,
+    0xfa2507bcc35e, 0xfa2925cb2b38, 0xfa319c7700b1, 0xfa33e60d3a63,
+    0xfa37546e90ee, 0xfa424f3fa5de, 0xfa43ae99c87c, 0xfa48f1734d2f,
+    0xfa4a50cd6fcd, 0xfa4e344cd1e2, 0xfa5a8e780970, 0xfa65142b12d6,
+    0xfa6dfff4f3d9, 0xfa70bea93915, 0xfa7e782e9341, 0xfa801217bba4,
+    0xfa804ca6c169, 0xfa80c1c4ccf3, 0xfa8ef06832a9, 0xfa94a85fc2e6,
+    0xfa951d7dce70, 0xfa95929bd9fa, 0xfa964248eb49, 0xfa9767140822,
+    0xfa9f68a1d211, 0xfaa08d6ceeea, 0xfaa13d1a0039, 0xfaa6f5119076,
+    0xfaa76a2f9c00, 0xfaa9b3c5d5b2, 0xfaad974537c7, 0xfab140359417,
+    0xfab6830f18ca, 0xfac317c9561d, 0xfacc03933720, 0xfad2e055e436,
+    0xfad47a3f0c99, 0xfad8232f68e9, 0xfad85dbe6eae, 0xfadbcc1fc539,
+    0xfadcf0eae212, 0xfadddb26f926, 0xfade504504b0, 0xfae05f4c389d,
+    0xfae184175576, 0xfaec445964a1, 0xfaef030da9dd, 0xfaf236dffaa3,
+    0xfaf8296690a5, 0xfaf988c0b343, 0xfb0aeb3669bf, 0xfb11184c0586,
+    0xfb1486ad5c11, 0xfb15e6077eaf, 0xfb1f46ef6b3c, 0xfb206bba8815,
+    0xfb219085a4ee, 0xfb253976013e, 0xfb26d35f29a1, 0xfb3577209ae1,
+    0xfb42bb87e983, 0xfb495dbb90d4, 0xfb515f495ac3, 0xfb57c6edfc4f,
+    0xfb599b662a77, 0xfb5b354f52da, 0xfb658073567b, 0xfb65f5916205,
+    0xfb750e70decf, 0xfb7548ffe494, 0xfb77cd25240b, 0xfb7e6f58cb5c,
+    0xfb84d6fd6ce8, 0xfb85118c72ad, 0xfb8ac98402ea, 0xfb8f22217089,
+    0xfb90bc0a98ec, 0xfb91e0d5b5c5, 0xfb980deb518c, 0xfb98f82768a0,
+    0xfb996d45742a, 0xfb9a92109103, 0xfb9b7c4ca817, 0xfb9d1635d07a,
+    0xfb9f9a5b0ff1, 0xfba3b86977cb, 0xfba42d878355, 0xfba4dd3494a4,
+    0xfba81106e56a, 0xfba8fb42fc7e, 0xfba9aaf00dcd, 0xfba9e57f1392,
+    0xfbaa952c24e1, 0xfbb1ac7dd7bc, 0xfbb430a31733, 0xfbb7647567f9,
+    0xfbb9ae0ba1ab, 0xfbbbf7a1db5d, 0xfbc46e4db0d6, 0xfbc76790fbd7,
+    0xfbc9017a243a, 0xfbd2626210c7, 0xfbd55ba55bc8, 0xfbdad90de640,
+    0xfbe2da9bb02f, 0xfbe7a8572958, 0xfbeaa19a7459, 0xfbf3184649d2,
+    0xfbf64c189a98, 0xfbfa6a270272, 0xfbfaa4b60837, 0xfc005cad9874,
+    0xfc13ce2a82dd, 0xfc1fedc6b4a6, 0xfc29893da6f8, 0xfc29fe5bb282,
+    0xfc2da74c0ed2, 0xfc31c55a76ac, 0xfc42b2b2219e, 0xfc47f58ba651,
+    0xfc4ab43feb8d, 0xfc4bd90b0866, 0xfc557481fab8, 0xfc55af11007d,
+    0xfc5ffa35041e, 0xfc671186b6f9, 0xfc67c133c848, 0xfc6c19d135e7,
+    0xfc6d040d4cfb, 0xfc6d792b5885, 0xfc6ffd5097fc, 0xfc741b5effd6,
+    0xfc7ccc99db14, 0xfc7f50bf1a8b, 0xfc841e7a93b4, 0xfc854345b08d,
+    0xfc88b1a70718, 0xfc8a110129b6, 0xfc97ca8683e2, 0xfc9bae05e5f7,
+    0xfc9d0d600895, 0xfca04132595b, 0xfca2c55798d2, 0xfca633b8ef5d,
+    0xfca7931311fb, 0xfcab76927410, 0xfcc3061dc653, 0xfcc340accc18,
+    0xfcc37b3bd1dd, 0xfcc51524fa40, 0xfcc7994a39b7, 0xfcc8be155690,
+    0xfcc93333621a, 0xfcd00ff60f30, 0xfcd085141aba, 0xfcd309395a31,
+    0xfcd6779ab0bc, 0xfcd8c130ea6e, 0xfcdbf5033b34, 0xfcdeee468635,
+    0xfce84f2e72c2, 0xfcea23a6a0ea, 0xfcec32add4d7, 0xfceca7cbe061,
+    0xfcf17587598a, 0xfcfad66f4617, 0xfd06f60b77e0, 0xfd090512abcd,
+    0xfd093fa1b192, 0xfd0e0d5d2abb, 0xfd1265fa985a, 0xfd143a72c682,
+    0xfd1733b61183, 0xfd197d4c4b35, 0xfd1a67886249, 0xfd20cf2d03d5,
+    0xfd24ed3b6baf, 0xfd28210dbc75, 0xfd290b49d389, 0xfd2bc9fe18c5,
+    0xfd2ceec9359e, 0xfd310cd79d78, 0xfd3565750b17, 0xfd3d6702d506,
+    0xfd3e8bcdf1df, 0xfd3f760a08f3, 0xfd409ad525cc, 0xfd4652ccb609,
+    0xfd4c0ac44646, 0xfd506361b3e5, 0xfd53973404ab, 0xfd589f7e8399,
+    0xfd59c449a072, 0xfd62eaa2873a, 0xfd6e9520ad79, 0xfd723e1109c9,
+    0xfd7487a7437b, 0xfd7621906bde, 0xfd7c4ea607a5, 0xfd83db15c60a,
+    0xfd932e844899, 0xfd94534f6572, 0xfd971203aaae, 0xfd995b99e460,
+    0xfd9a0b46f5af, 0xfd9bdfbf23d7, 0xfda24763c563, 0xfda281f2cb28,
+    0xfdb24a7f5941, 0xfdb4941592f3, 0xfdb509339e7d, 0xfdb7183ad26a,
+    0xfdbc5b14571d, 0xfdc24d9aed1f, 0xfdc2c2b8f8a9, 0xfdcfcc914186,
+    0xfdd3b010a39b, 0xfdd50f6ac639, 0xfde0f477f23d, 0xfde219430f16,
+    0xfde4284a4303, 0xfde512865a17, 0xfde96b23c7b6, 0xfded14142406,
+    0xfdefd2c86942, 0xfdf515a1edf5, 0xfdfacd997e32, 0xfdff2636ebd1,
+    0xfe146c2c0462, 0xfe179ffe5528, 0xfe22d55e6fdd, 0xfe2bc12850e0,
+    0xfe2e454d9057, 0xfe2eba6b9be1, 0xfe31b3aee6e2, 0xfe32d87a03bb,
+    0xfe360c4c5481, 0xfe40cc8e63ac, 0xfe41f1598085, 0xfe440060b472,
+    0xfe45252bd14b, 0xfe468485f3e9, 0xfe4734330538, 0xfe48938d27d6,
+    0xfe605da77fde, 0xfe74b960815b, 0xfe75690d92aa, 0xfe773d85c0d2,
+    0xfe7b5b9428ac, 0xfe8188a9c473, 0xfe856c292688, 0xfe8f07a018da,
+    0xfe9e207f95a4, 0xfea119c2e0a5, 0xfea8e0c1a4cf, 0xfeb7f9a12199,
+    0xfeb8a94e32e8, 0xfebffb2eeb88, 0xfec11ffa0861, 0xfecd05073465,
+    0xfed57bb309de, 0xfedaf91b9456, 0xfedb33aa9a1b, 0xfedd7d40d3cd,
+    0xfee36fc769cf, 0xfee4cf218c6d, 0xfee509b09232, 0xfee6a399ba95,
+    0xfee75346cbe4, 0xfeeb715533be, 0xfef4d23d204b, 0xfef5f7083d24,
+    0xfef66c2648ae, 0xfef8409e76d6, 0xfefc993be475, 0xff0b3cfd55b5,
+    0xff12c96d141a, 0xff1b0589e3ce, 0xff1cda0211f6, 0xff1d4f201d80,
+    0xff216d2e855a, 0xff2341a6b382, 0xff2b43347d71, 0xff2b7dc38336,
+    0xff2ca28ea00f, 0xff321ff72a87, 0xff36edb2a3b0, 0xff43826ce103,
+    0xff4a24a08854, 0xff508c4529e0, 0xff5260bd5808, 0xff59b29e10a8,
+    0xff59ed2d166d, 0xff76bf91ed63, 0xff77a9ce0477, 0xff797e46329f,
+    0xff7a688249b3, 0xff8528c458de, 0xff890c43baf3, 0xff8d64e12892,
+    0xff90d3427f1d, 0xff947c32db6d, 0xff97b0052c33, 0xffab5c111c61,
+    0xffade0365bd8, 0xffb40d4bf79f, 0xffc4faa3a291, 0xffcc4c845b31,
+    0xffcde66d8394, 0xffd4c33030aa, 0xffdc1510e94a, 0xffe06dae56e9,
+    0xffe7bf8f0f89, 0xfff0ab58f08c, 0xfff1959507a0, 0xfff74d8c97dd,
+    0x0006db8a2031, 0x0007161925f6, 0x000b6eb69395, 0x000ba945995a,
+    0x0011613d2997, 0x0012c0974c35, 0x0012fb2651fa, 0x00166987a885,
+    0x0018edace7fc, 0x00207a1ca661, 0x0020ef3ab1eb, 0x00228923da4e,
+    0x0022fe41e5d8, 0x00263214369e, 0x00266ca33c63, 0x002c5f29d265,
+    0x0033767b8540, 0x0036352fca7c, 0x0036aa4dd606, 0x0038b95509f3,
+    0x003969021b42, 0x00437997191e, 0x0048f6ffa396, 0x0055512adb24,
+    0x00596f3942fe, 0x005ace93659c, 0x005d52b8a513, 0x0061ab5612b2,
+    0x006519b7693d, 0x0067634da2ef, 0x0068fd36cb52, 0x006a5c90edf0,
+    0x006bf67a1653, 0x007a9a3b8793, 0x0080523317d0, 0x0090ca6cb738,
+    0x0093c3b00239, 0x0095230a24d7, 0x009598283061, 0x0097321158c4,
+    0x0098916b7b62, 0x0099068986ec, 0x009a2b54a3c5, 0x009b501fc09e,
+    0x009caf79e33c, 0x00a1081750db, 0x00a38c3c9052, 0x00ab533b547c,
+    0x00b05b85d36a, 0x00b1f56efbcd, 0x00b479943b44, 0x00b4eeb246ce,
+    0x00b64e0c696c, 0x00b7ad668c0a, 0x00b7e7f591cf, 0x00bc7b220533,
+    0x00c26da89b35, 0x00c2a837a0fa, 0x00c2e2c6a6bf, 0x00c44220c95d,
+    0x00c6c64608d4, 0x00c700d50e99, 0x00c8d54d3cc1, 0x00c9fa18599a,
+    0x00cc43ae934c, 0x00cc7e3d9911, 0x00cddd97bbaf, 0x00d40aad5776,
+    0x00d73e7fa83c, 0x00d7b39db3c6, 0x00d8634ac515, 0x00e5e241197c,
+    0x00fa788920be, 0x0100e02dc24a, 0x0101554bcdd4, 0x0101ca69d95e,
+    0x01044e8f18d5, 0x0104fe3c2a24, 0x01086c9d80af, 0x010af0c2c026,
+    0x0110a8ba5063, 0x0113676e959f, 0x01219611fb55, 0x01241a373acc,
+    0x01248f554656, 0x0125b420632f, 0x012c1bc504bb, 0x012e9fea4432,
+    0x0131240f83a9, 0x0131d3bc94f8, 0x0132f887b1d1, 0x0133a834c320,
+    0x01349270da34, 0x01378bb42535, 0x0138eb0e47d3, 0x01419c492311,
+    0x0142fba345af, 0x014336324b74, 0x0144206e6288, 0x01483e7cca62,
+    0x014928b8e176, 0x01496347e73b, 0x015a509f922d, 0x015bea88ba90,
+    0x0164d6529b93, 0x01675a77db0a, 0x016ee6e7996f, 0x01725548effa,
+    0x0175fe394c4a, 0x01775d936ee8, 0x0180f90a613a, 0x0182cd828f62,
+    0x018308119527, 0x01837d2fa0b1, 0x0189352730ee, 0x0191714400a2,
+    0x0191abd30667, 0x0192d09e2340, 0x0193f5694019, 0x0197293b90df,
+    0x01988895b37d, 0x019e7b1c497f, 0x01a0c4b28331, 0x01a4a831e546,
+    0x01b1777b285e, 0x01b3fba067d5, 0x01b470be735f, 0x01b60aa79bc2,
+    0x01b7df1fc9ea, 0x01b8543dd574, 0x01b97908f24d, 0x01c0905aa528,
+    0x01c10578b0b2, 0x01cc0049c5a2, 0x01d2a27d6cf3, 0x01d4ec13a6a5,
+    0x01d526a2ac6a, 0x01d56131b22f, 0x01d6fb1ada92, 0x01d8cf9308ba,
+    0x01db1929426c, 0x01e4ef2f3a83, 0x01eae1b5d085, 0x01f1f9078360,
+    0x01f233968925, 0x01f3cd7fb188, 0x01f56768d9eb, 0x01f5dc86e575,
+    0x01f776700dd8, 0x01f7eb8e1962, 0x02068f4f8aa2, 0x0209fdb0e12d,
+    0x0211c4afa557, 0x0211ff3eab1c, 0x021239cdb0e1, 0x02132409c7f5,
+    0x02156da001a7, 0x0216ccfa2445, 0x021a3b5b7ad0, 0x021b259791e4,
+    0x021bd544a333, 0x0224c10e8436, 0x022b28b325c2, 0x022bd8603711,
+    0x022e5c857688, 0x02320575d2d8, 0x0232efb1e9ec, 0x0234899b124f,
+    0x02353948239e, 0x0235e8f534ed, 0x0238328b6e9f, 0x023af13fb3db,
+    0x02427daf7240, 0x0247c088f6f3, 0x025280cb061e, 0x0253a59622f7,
+    0x0253e02528bc, 0x02557a0e511f, 0x0257fe339096, 0x0258e86fa7aa,
+    0x025af776db97, 0x025b3205e15c, 0x025e2b492c5d, 0x02633393ab4b,
+    0x02636e22b110, 0x0263a8b1b6d5, 0x0265b7b8eac2, 0x0269261a414d,
+    0x026960a94712, 0x026a857463eb, 0x026baa3f80c4, 0x026c947b97d8,
+    0x0274d098678c, 0x02824f8ebbf3, 0x0283e977e456, 0x02849924f5a5,
+    0x028b3b589cf6, 0x029302576120, 0x0295115e950d, 0x0298f4ddf722,
+    0x029a19a913fb, 0x029e37b77bd5, 0x02a16b89cc9b, 0x02a30572f4fe,
+    0x02a42a3e11d7, 0x02a6ae63514e, 0x02a80dbd73ec, 0x02a8484c79b1,
+    0x02a9a7a69c4f, 0x02ab7c1eca77, 0x02b134165ab4, 0x02b3b83b9a2b,
+    0x02b55224c28e, 0x02b58cb3c853, 0x02b935a424a3, 0x02bca4057b2e,
+    0x02bd53b28c7d, 0x02c1ac4ffa1c, 0x02c2216e05a6, 0x02c2968c1130,
+    0x02c34639227f, 0x02c55540566c, 0x02c8fe30b2bc, 0x02ca9819db1f,
+    0x02d299a7a50e, 0x02d6f24512ad, 0x02e227a52d62, 0x02ea9e5102db,
+    0x02f056489318, 0x02f0cb669ea2, 0x02f14084aa2c, 0x02f7e2b8517d,
+    0x0304b2019495, 0x030561aea5e4, 0x0307ab44df96, 0x03090a9f0234,
+    0x031146bbd1e8, 0x0314b51d2873, 0x0318d32b904d, 0x031aa7a3be75,
+    0x032234137cda, 0x0327ec0b0d17, 0x03289bb81e66, 0x032c0a1974f1,
+    0x032c44a87ab6, 0x032cf4558c05, 0x03309d45e855, 0x0333d118391b,
+    0x0334463644a5, 0x0337b4979b30, 0x033b9816fd45, 0x033c0d3508cf,
+    0x033e1c3c3cbc, 0x0341ffbb9ed1, 0x0342af68b020, 0x034742952384,
+    0x034a7667744a, 0x03518db92725, 0x035954b7eb4f, 0x035a79830828,
+    0x03615645b53e, 0x0365e97228a2, 0x03670e3d457b, 0x036ba169b8df,
+    0x0372b8bb6bba, 0x037452a4941d, 0x0378ab4201bc, 0x037a452b2a1f,
+    0x0380e75ed170, 0x0385056d394a, 0x03857a8b44d4, 0x03895e0aa6e9,
+    0x039249d487ec, 0x0395f2c4e43c, 0x0396dd00fb50, 0x03a2877f218f,
+    0x03a3ac4a3e68, 0x03a5463366cb, 0x03a7553a9ab8, 0x03a8ef23c31b,
+    0x03ab73490292, 0x03ac5d8519a6, 0x03b33a47c6bc, 0x03b42483ddd0,
+    0x03b5f8fc0bf8, 0x03b6338b11bd, 0x03bee4c5ecfb, 0x03c07eaf155e,
+    0x03c33d635a9a, 0x03c3b2816624, 0x03c4d74c82fd, 0x03c5fc179fd6,
+    0x03c6e653b6ea, 0x03cc63bc4162, 0x03cfd21d97ed, 0x03d16c06c050,
+    0x03d290d1dd29, 0x03d4da6816db, 0x03dca166db05, 0x03e134934e69,
+    0x03e3439a8256, 0x03ea957b3af6, 0x03f346b61634, 0x03f72a357849,
+    0x03f8c41ea0ac, 0x03f9ae5ab7c0, 0x03fb0db4da5e, 0x03fce22d0886,
+    0x0404a92bccb0, 0x04051e49d83a, 0x04064314f513, 0x04072d510c27,
+    0x041053a9f2ef, 0x0413125e382b, 0x0417e019b154, 0x04185537bcde,
+    0x04193f73d3f2, 0x0419ef20e541, 0x041ad95cfc55, 0x0421b61fa96b,
+    0x0422a05bc07f, 0x0422daeac644, 0x0425d42e1145, 0x0426494c1ccf,
+    0x0426be6a2859, 0x0427a8a63f6d, 0x0428585350bc, 0x0429b7ad735a,
+    0x042a675a84a9, 0x043686f6b672, 0x043945aafbae, 0x043adf942411,
+    0x043f72c09775, 0x0444f02921ed, 0x044c077ad4c8, 0x044f3b4d258e,
+    0x0454f344b5cb, 0x0456180fd2a4, 0x045bd00762e1, 0x045cba4379f5,
+    0x046237ac046d, 0x04635c772146, 0x04689f50a5f9, 0x046f41844d4a,
+    0x04739a21bae9, 0x0475340ae34c, 0x047bd63e8a9d, 0x047cfb09a776,
+    0x047f449fe128, 0x0480de89098b, 0x04844cea6016, 0x0484fc977165,
+    0x04853726772a, 0x048b643c12f1, 0x048c13e92440, 0x048e980e63b7,
+    0x04906c8691df, 0x04911c33a32e, 0x049ab7aa9580, 0x049f1048031f,
+    0x04a3a3747683, 0x04a66228bbbf, 0x04b2bc53f34d, 0x04b4cb5b273a,
+    0x04bf167f2adb, 0x04c0b068533e, 0x04c36f1c987a, 0x04d0b383e71c,
+    0x04d128a1f2a6, 0x04d2fd1a20ce, 0x04d5f65d6bcf, 0x04d8b511b10b,
+    0x04d92a2fbc95, 0x04da146bd3a9, 0x04dac418e4f8, 0x04dafea7eabd,
+    0x04e50f3ce899, 0x04eeaab3daeb, 0x04eee542e0b0, 0x04f1de862bb1,
+    0x04f3786f5414, 0x04f462ab6b28, 0x04f880b9d302, 0x04fbb48c23c8,
+    0x050047b8972c, 0x0503f0a8f37c, 0x051010452545, 0x05137ea67bd0,
+    0x05172796d820, 0x05225cf6f2d5, 0x052590c9439b, 0x052bf86de527,
+    0x0532601286b3, 0x0532d530923d, 0x0534348ab4db, 0x053a27114add,
+    0x0544e7535a08, 0x0547e096a509, 0x054d988e3546, 0x0553c5a3d10d,
+    0x055734052798, 0x055cb16db210, 0x05617f292b39, 0x0561f44736c3,
+    0x05710d26b38d, 0x0572e19ee1b5, 0x0574f0a615a2, 0x05752b351b67,
+    0x057774cb5519, 0x0579f8f09490, 0x057a337f9a55, 0x057a6e0ea01a,
+    0x057ddc6ff6a5, 0x05806095361c, 0x058ae6483f82, 0x05940ca1264a,
+    0x0595e1195472, 0x059914eba538, 0x05998a09b0c2, 0x059bd39fea74,
+    0x05a5a9a5e28b, 0x05a65952f3da, 0x05a77e1e10b3, 0x05a7f33c1c3d,
+    0x05a8dd783351, 0x05ab619d72c8, 0x05acfb869b2b, 0x05afba3ae067,
+    0x05b2b37e2b68, 0x05b4c2855f55, 0x05bab50bf557, 0x05c11cb096e3,
+    0x05c36646d095, 0x05c45082e7a9, 0x05c7f97343f9, 0x05cab8278935,
+    0x05d0701f1972, 0x05d369626473, 0x05d71252c0c3, 0x05d871ace361,
+    0x05da80b4174e, 0x05ddb4866814, 0x05dfc38d9c01, 0x05e6a0504917,
+    0x05ec5847d954, 0x05efc6a92fdf, 0x05f07656412e, 0x05f3350a866a,
+    0x05f7c836f9ce, 0x05fbe64561a8, 0x05fedf88aca9, 0x0610f1ab7474,
+    0x06112c3a7a39, 0x0618b8aa389e, 0x061bec7c8964, 0x0622c93f367a,
+    0x0624d8466a67, 0x0627d189b568, 0x06280c18bb2d, 0x0628f654d241,
+    0x06321cadb909, 0x06346643f2bb, 0x0639a91d776e, 0x06430a0563fb,
+    0x0648c1fcf438, 0x064f9ebfa14e, 0x0650fe19c3ec, 0x06546c7b1a77,
+    0x0654e1992601, 0x0661013557ca, 0x0663fa78a2cb, 0x066559d2c569,
+    0x066902c321b9, 0x0671796ef732, 0x0673fd9436a9, 0x0675225f5382,
+    0x0675d20c64d1, 0x06859a98f2ea, 0x06876f112112, 0x0697722cb4f0,
+    0x0697e74ac07a, 0x069b55ac1705, 0x069c05592854, 0x06a02367902e,
+    0x06a14832ad07, 0x06a1bd50b891, 0x06a73ab94309, 0x06ab1e38a51e,
+    0x06b0d630355b, 0x06b110bf3b20, 0x06b394e47a97, 0x06b9876b1099,
+    0x06bf3f62a0d6, 0x06c14e69d4c3, 0x06c1fe16e612, 0x06c27334f19c,
+    0x06cb998dd864, 0x06d27650857a, 0x06d4fa75c4f1, 0x06d61f40e1ca,
+    0x06d77e9b0468, 0x06d8a3662141, 0x06d9c8313e1a, 0x06db278b60b8,
+    0x06dc11c777cc, 0x06e4fd9158cf, 0x06e6977a8132, 0x06ec4f72116f,
+    0x06f20769a1ac, 0x06f2f1a5b8c0, 0x06fc528da54d, 0x0700709c0d27,
+    0x070245143b4f, 0x07066322a329, 0x070abbc010c8, 0x070af64f168d,
+    0x07120da0c968, 0x07157c021ff3, 0x0716db5c4291, 0x071715eb4856,
+    0x071800275f6a, 0x07183ab6652f, 0x0718afd470b9, 0x071ba917bbba,
+    0x0721d62d5781, 0x072dbb3a8385, 0x072ea5769a99, 0x073b3a30d7ec,
+    0x0746aa1ff866, 0x0749a3634367, 0x074a531054b6, 0x074c621788a3,
+    0x07504596eab8, 0x0752c9bc2a2f, 0x075a562be894, 0x075c2aa416bc,
+    0x07637c84cf5c, 0x0764a14fec35, 0x076725752bac, 0x07696f0b655e,
+    0x076a1eb876ad, 0x076bf330a4d5, 0x076f6191fb60, 0x0771ab283512,
+    0x078ef2ab1792, 0x07955a4fb91e, 0x07976956ed0b, 0x07a29eb707c0,
+    0x07a8cbcca387, 0x07aa2b26c625, 0x07abff9ef44d, 0x07b38c0eb2b2,
+    0x07bcecf69f3f, 0x07c26a5f29b7, 0x07c38f2a4690, 0x07cc406521ce,
+    0x07cec48a6145, 0x07d0d3919532, 0x07d2a809c35a, 0x07d357b6d4a9,
+    0x07d39245da6e, 0x07d4f19ffd0c, 0x07d700a730f9, 0x07e09c1e234b,
+    0x07e5def7a7fe, 0x07e65415b388, 0x07e778e0d061, 0x07e8d83af2ff,
+    0x07ecf6495ad9, 0x07f0d9c8bcee, 0x0800a2554b07, 0x080485d4ad1c,
+    0x08057010c430, 0x0806cf6ae6ce, 0x0808de721abb, 0x08098e1f2c0a,
+    0x0809c8ae31cf, 0x080b2808546d, 0x080b9d265ff7, 0x080fbb34c7d1,
+    0x0815389d5249, 0x081cff9c1673, 0x08206dfd6cfe, 0x0823a1cfbdc4,
+    0x08269b1308c5, 0x08286f8b36ed, 0x082ba35d87b3, 0x082d3d46b016,
+    0x08336a5c4bdd, 0x0837886ab3b7, 0x083872a6cacb, 0x083ccb44386a,
+    0x083f4f6977e1, 0x08462c2c24f7, 0x084b6f05a9aa, 0x08519c1b4571,
+    0x0852c0e6624a, 0x08550a7c9bfc, 0x08575412d5ae, 0x0857c930e138,
+    0x0858b36cf84c, 0x086373af0777, 0x0867572e698c, 0x0868f11791ef,
+    0x0869a0c4a33e, 0x086a5071b48d, 0x086ea90f222c, 0x0870b8165619,
+    0x08746106b269, 0x087510b3c3b8, 0x087969513157, 0x087ac8ab53f5,
+    0x088080a2e432, 0x0880bb31e9f7, 0x08816adefb46, 0x088513cf5796,
+    0x08854e5e5d5b, 0x088bf09204ac, 0x088eaf4649e8, 0x08942caed460,
+    0x089725f21f61, 0x089760812526, 0x08984abd3c3a, 0x08ab471c1b19,
+    0x08b46d7501e1, 0x08b4a80407a6, 0x08b51d221330, 0x08b9b04e8694,
+    0x08bb0fa8a932, 0x08bb4a37aef7, 0x08c08d1133aa, 0x08c1ec6b5648,
+    0x08c2618961d2, 0x08c8540ff7d4, 0x08c93e4c0ee8, 0x08d0902cc788,
+    0x08d1054ad312, 0x08d4ae3b2f62, 0x08d7e20d8028, 0x08d8572b8bb2,
+    0x08e6faecfcf2, 0x08ea694e537d, 0x08ec3dc681a5, 0x08f1f5be11e2,
+    0x08f5299062a8, 0x08f5d93d73f7, 0x08fb91350434, 0x08ff74b46649,
+    0x0903cd51d3e8, 0x0904b78deafc, 0x0905673afc4b, 0x0906c6951ee9,
+    0x090e5304dd4e, 0x0911c16633d9, 0x0914458b7350, 0x091aad3014dc,
+    0x091fb57a93ca, 0x092323dbea55, 0x092448a7072e, 0x0928a14474cd,
+    0x092c0fa5cb58, 0x0930dd614481, 0x0932774a6ce4, 0x0934fb6fac5b,
+    0x0935ab1cbdaa, 0x093b9da353ac, 0x093f8122b5c1, 0x093fbbb1bb86,
+    0x09427a6600c2, 0x0944896d34af, 0x094a4164c4ec, 0x09506e7a60b3,
+    0x0950a9096678, 0x095367bdabb4, 0x09574b3d0dc9, 0x095785cc138e,
+    0x09591fb53bf1, 0x095c8e16927c, 0x0961214305e0, 0x0962460e22b9,
+    0x0965b46f7944, 0x096ba6f60f46, 0x096ea0395a47, 0x0976a1c72436,
+    0x097be4a0a8e9, 0x098336816189, 0x0985f535a6c5, 0x09912a95c17a,
+    0x0993742bfb2c, 0x0993aebb00f1, 0x0997923a6306, 0x099bb048cae0,
+    0x09a2c79a7dbb, 0x09a377478f0a, 0x09a7cfe4fca9, 0x09ab78d558f9,
+    0x09b290270bd4, 0x09b4d9bd4586, 0x09b80d8f964c, 0x09b8bd3ca79b,
+    0x09b9a778beaf, 0x09c30860ab3c, 0x09c58c85eab3, 0x09c63c32fc02,
+    0x09c7266f1316, 0x09d3460b44df, 0x09d679dd95a5, 0x09d76419acb9,
+    0x09dce1823731, 0x09e1e9ccb61f, 0x09e2d408cd33, 0x09e43362efd1,
+    0x09ea9b07915d, 0x09f4e62b94fe, 0x09f9ee7613ec, 0x09fef6c092da,
+    0x0a03ff0b11c8, 0x0a0a2c20ad8f, 0x0a14ec62bcba, 0x0a190a712494,
+    0x0a1d287f8c6e, 0x0a247a60450e, 0x0a2a3257d54b, 0x0a2b91b1f7e9,
+    0x0a2c06d00373, 0x0a2cb67d14c2, 0x0a33933fc1d8, 0x0a347d7bd8ec,
+    0x0a38266c353c, 0x0a3a35736929, 0x0a3ae5207a78, 0x0a3b1faf803d,
+    0x0a3bcf5c918c, 0x0a42719038dd, 0x0a461a80952d, 0x0a46ca2da67c,
+    0x0a523a1cc6f6, 0x0a5274abccbb, 0x0a540e94f51e, 0x0a561d9c290b,
+    0x0a5bd593b948, 0x0a5cfa5ed621, 0x0a674582d9c2, 0x0a67f52feb11,
+    0x0a68df6c0225, 0x0a6cfd7a69ff, 0x0a7e9a7f2640, 0x0a811ea465b7,
+    0x0a8193c27141, 0x0a81ce517706, 0x0a8786490743, 0x0a8ed829bfe3,
+    0x0a92f63827bd, 0x0a95053f5baa, 0x0aa2bec4b5d6, 0x0aa3e38fd2af,
+    0x0aa41e1ed874, 0x0ab50b768366, 0x0ab7ca2ac8a2, 0x0ab92984eb40,
+    0x0ac12b12b52f, 0x0ac2ff8ae357, 0x0ac583b022ce, 0x0acac689a781,
+    0x0ad1a34c5497, 0x0ad7d061f05e, 0x0ada8f16359a, 0x0ae49fab3376,
+    0x0ae5c476504f, 0x0ae67423619e, 0x0af6773ef57c, 0x0afa5abe5791,
+    0x0afb7f89746a, 0x0afdc91fae1c, 0x0b030bf932cf, 0x0b0346883894,
+    0x0b063fcb8395, 0x0b067a5a895a, 0x0b0c32521997, 0x0b0e7be85349,
+    0x0b0f2b956498, 0x0b14e38cf4d5, 0x0b16b80522fd, 0x0b193c2a6274,
+    0x0b19ebd773c3, 0x0b1b10a2909c, 0x0b1b4b319661, 0x0b20537c154f,
+    0x0b21b2d637ed, 0x0b23fc6c719f, 0x0b2c73184718, 0x0b36be3c4ab9,
+    0x0b3942618a30, 0x0b4fe7b0c55f, 0x0b55da375b61, 0x0b609a796a8c,
+    0x0b62346292ef, 0x0b626ef198b4, 0x0b63ce4bbb52, 0x0b656834e3b5,
+    0x0b67773c17a2, 0x0b6986434b8f, 0x0b6c7f869690, 0x0b735c4943a6,
+    0x0b7a390bf0bc, 0x0b7aae29fc46, 0x0b7b9866135a, 0x0b823a99baab,
+    0x0b827528c070, 0x0b8867af5672, 0x0b94874b883b, 0x0b95e6a5aad9,
+    0x0b9bd92c40db, 0x0ba3daba0aca, 0x0ba6996e5006, 0x0bab6729c92f,
+    0x0bb7c15500bd, 0x0bb8ab9117d1, 0x0bba800945f9, 0x0bbe6388a80e,
+    0x0bc20c79045e, 0x0bc74f528911, 0x0bc8741da5ea, 0x0bc998e8c2c3,
+    0x0bd125588128, 0x0bd15fe786ed, 0x0bd284b2a3c6, 0x0bd3345fb515,
+    0x0bd54366e902, 0x0bd6683205db, 0x0bdc20299618, 0x0be19d922090,
+    0x0be3e7285a42, 0x0beafe7a0d1d, 0x0bf12b8fa8e4, 0x0bf5842d1683,
+    0x0c012eab3cc2, 0x0c0303236aea, 0x0c0b3f403a9e, 0x0c116c55d665,
+    0x0c11e173e1ef, 0x0c1cdc44f6df, 0x0c20101747a5, 0x0c284c341759,
+    0x0c3b4892f638, 0x0c455927f414, 0x0c52d81e487b, 0x0c60cc32a86c,
+    0x0c66842a38a9, 0x0c6a67a99abe, 0x0c71b98a535e, 0x0c74783e989a,
+    0x0c847b5a2c78, 0x0c856596438c, 0x0c91101469cb, 0x0c9185327555,
+    0x0c94f393cbe0, 0x0c95ddcfe2f4, 0x0ca397553d20, 0x0ca914bdc798,
+    0x0cb53459f961, 0x0cb65925163a, 0x0cb82d9d4462, 0x0cbab1c283d9,
+    0x0ccbd9a93490, 0x0ccc14383a55, 0x0cd784275acf, 0x0cd9589f88f7,
+    0x0ce9964a229a, 0x0cf28214039d, 0x0cf2bca30962, 0x0cf4cbaa3d4f,
+    0x0cf83a0b93da, 0x0cf92447aaee, 0x0d02852f977b, 0x0d041f18bfde,
+    0x0d12884b2b59, 0x0d17cb24b00c, 0x0d1baea41221, 0x0d1e32c95198,
+    0x0d1fccb279fb, 0x0d21a12aa823, 0x0d28f30b60c3, 0x0d2cd68ac2d8,
+    0x0d2ee591f6c5, 0x0d32c91158da, 0x0d36ac90baef, 0x0d384679e352,
+    0x0d41e1f0d5a4, 0x0d43414af842, 0x0d46ea3b5492, 0x0d5085b246e4,
+    0x0d50fad0526e, 0x0d56ed56e870, 0x0d5812220549, 0x0d5f2973b824,
+    0x0d61387aec11, 0x0d672b018213, 0x0d67659087d8, 0x0d68ff79b03b,
+    0x0d793d2449de, 0x0d7ce614a62e, 0x0d81b3d01f57, 0x0d88cb21d232,
+    0x0d976ee34372, 0x0d9908cc6bd5, 0x0d9aa2b59438, 0x0d9fe58f18eb,
+    0x0da05aad2475, 0x0da64d33ba77, 0x0db3570c0354, 0x0db4b66625f2,
+    0x0db5db3142cb, 0x0dc2355c7a59, 0x0dc5692ecb1f, 0x0dccbb0f83bf,
+    0x0dd02970da4a, 0x0dd2730713fc, 0x0dd35d432b10, 0x0dd77b5192ea,
+    0x0dda7494ddeb, 0x0de325cfb929, 0x0de7f38b3252, 0x0df5ad108c7e,
+    0x0df746f9b4e1, 0x0df9cb1ef458, 0x0dfbda262845, 0x0e01578eb2bd,
+    0x0e03dbb3f234, 0x0e05b02c205c, 0x0e0ba2b2b65e, 0x0e0cc77dd337,
+    0x0e10e58c3b11, 0x0e18376cf3b1, 0x0e22f7af02dc, 0x0e262b8153a2,
+    0x0e2715bd6ab6, 0x0e28ea3598de, 0x0e29d471aff2, 0x0e341f95b393,
+    0x0e35b97edbf6, 0x0e35f40de1bb, 0x0e3878332132, 0x0e466c478123,
+    0x0e49a019d1e9, 0x0e4baf2105d6, 0x0e5425ccdb4f, 0x0e55bfb603b2,
+    0x0e571f102650, 0x0e65fd609d55, 0x0e716d4fbdcf, 0x0e784a126ae5,
+    0x0e842f1f96e9, 0x0e97db2b8717, 0x0e9a5f50c68e, 0x0e9b841be367,
+    0x0ea2264f8ab8, 0x0eace69199e3, 0x0eb29e892a20, 0x0eb3fde34cbe,
+    0x0eb4e81f63d2, 0x0eb522ae6997, 0x0eb85680ba5d, 0x0ebe4907505f,
+    0x0ec107bb959b, 0x0ec22c86b274, 0x0ec4eb3af7b0, 0x0ec8942b5400,
+    0x0ecc77aab615, 0x0ed0d04823b4, 0x0ed2df4f57a1, 0x0ed3546d632b,
+    0x0ed59e039cdd, 0x0edbcb1938a4, 0x0ee35788f709, 0x0ee73b08591e,
+    0x0eeb1e87bb33, 0x0eeb5916c0f8, 0x0eef772528d2, 0x0ef14b9d56fa,
+    0x0ef94d2b20e9, 0x0efae714494c, 0x0f011429e513, 0x0f07b65d8c64,
+    0x0f0bd46bf43e, 0x0f0cbea80b52, 0x0f13264cacde, 0x0f1918d342e0,
+    0x0f1a782d657e, 0x0f1aed4b7108, 0x0f1d36e1aaba, 0x0f22049d23e3,
+    0x0f24c351691f, 0x0f28a6d0cb34, 0x0f2a40b9f397, 0x0f2c153221bf,
+    0x0f332c83d49a, 0x0f33dc30e5e9, 0x0f3a7e648d3a, 0x0f41d04545da,
+    0x0f495cb5043f, 0x0f52487ee542, 0x0f62110b735b, 0x0f6544ddc421,
+    0x0f750d6a523a, 0x0f7757008bec, 0x0f7cd4691664, 0x0f9254ed34ba,
+    0x0f94d9127431, 0x0f95c34e8b45, 0x0f98bc91d646, 0x0f9fd3e38921,
+    0x0fa307b5d9e7, 0x0fa37cd3e571, 0x0fa934cb75ae, 0x0fb0fbca39d8,
+    0x0fb6b3c1ca15, 0x0fc1ae92df05, 0x0fc97591a32f, 0x0fd38626a10b,
+    0x0fd3c0b5a6d0, 0x0fdcac7f87d3, 0x0fe4ae0d51c2, 0x0fe6bd1485af,
+    0x0fe7a7509cc3, 0x0fea2b75dc3a, 0x0feef9315563, 0x0fefe36d6c77,
+    0x0ff2a221b1b3, 0x0ff944555904, 0x0ffa692075dd, 0x0ffb18cd872c,
+    0x0ffced45b554, 0x1004b444797e, 0x1009bc8ef86c, 0x100d657f54bc,
+    0x1018605069ac, 0x10256a28b289, 0x1027b3beec3b, 0x102b5caf488b,
+    0x102bd1cd5415, 0x102f059fa4db, 0x103189c4e452, 0x103483082f53,
+    0x1034bd973518, 0x1040a2a4611c, 0x1045e57de5cf, 0x104919503695,
+    0x104c4d22875b, 0x104ff612e3ab, 0x1050a5bff4fa, 0x1052051a1798,
+    0x1053d99245c0, 0x10591c6bca73, 0x10599189d5fd, 0x105ba09109ea,
+    0x10627d53b700, 0x1068354b473d, 0x1080e9a1b659, 0x108a8518a8ab,
+    0x108d093de822, 0x109370e289ae, 0x1098ee4b1426, 0x10a548764bb4,
+    0x10a8f166a804, 0x10b2c76ca01b, 0x10b46155c87e, 0x10bbb336811e,
+    0x10c1a5bd1720, 0x10c2ca8833f9, 0x10c5141e6dab, 0x10c8f79dcfc0,
+    0x10ceaf955ffd, 0x10cf24b36b87, 0x10d342c1d361, 0x10d676942427,
+    0x10e7d909daa3, 0x10e93863fd41, 0x10f2994be9ce, 0x10f67ccb4be3,
+    0x10f6b75a51a8, 0x10fad568b982, 0x1101ecba6c5d, 0x110311858936,
+    0x11064557d9fc, 0x1109b3b93087, 0x11129f83118a, 0x11155e3756c6,
+    0x1117a7cd9078, 0x111ef9ae4918, 0x11222d8099de, 0x112b8e68866b,
+    0x1132a5ba3946, 0x113aa7480335, 0x114a35458b89, 0x1152abf16102,
+    0x1161ff5fe391, 0x116533323457, 0x116742396844, 0x117361d59a0d,
+    0x1175364dc835, 0x1175ab6bd3bf, 0x1177ba7307ac, 0x11827ab516d7,
+    0x11832a622826, 0x11895777c3ed, 0x118e5fc242db, 0x1191939493a1,
+    0x11924341a4f0, 0x1193a29bc78e, 0x119ba429917d, 0x11aaf798140c,
+    0x11b0ea1eaa0e, 0x11b1d45ac122, 0x11c83f1af68c, 0x11cbad7c4d17,
+    0x11d24faff468, 0x11d4d3d533df, 0x11d757fa7356, 0x11e6364aea5b,
+    0x11e6ab68f5e5, 0x11e75b160734, 0x11f1a63a0ad5, 0x11ff9a4e6ac6,
+    0x1200bf19879f, 0x12029391b5c7, 0x1210c2351b7d, 0x121137532707,
+    0x1213bb78667e, 0x1216ef4ab744, 0x12181415d41d, 0x1219e88e0245,
+    0x122174fdc0aa, 0x1221af8cc66f, 0x122224aad1f9, 0x1227678456ac,
+    0x12288c4f7385, 0x122bc021c44b, 0x123ce8087502, 0x12455eb44a7b,
+    0x1249b751b81a, 0x124c3b76f791, 0x12531839a4a7, 0x126bcc9013c3,
+    0x126c7c3d2512, 0x12752d780050, 0x12902b64a91e, 0x129324a7f41f,
+    0x12935f36f9e4, 0x129708275634, 0x12982cf2730d, 0x12a327c387fd,
+    0x12a4c1acb060, 0x12a536cabbea, 0x12a65b95d8c3, 0x12a86a9d0cb0,
+    0x12b27b320a8c, 0x12c243be98a5, 0x12c53d01e3a6, 0x12c7c127231d,
+    0x12d2f6873dd2, 0x12d36ba5495c, 0x12e0b00c97fe, 0x12e1252aa388,
+    0x12e2f9a2d1b0, 0x12e6dd2233c5, 0x12ef193f0379, 0x12f2c22f5fc9,
+    0x12f6e03dc7a3, 0x12fb736a3b07, 0x1301db0edc93, 0x130499c321cf,
+    0x13087d4283e4, 0x1315c1a9d286, 0x13213198f300, 0x1321e146044f,
+    0x1327d3cc9a51, 0x1332597fa3b7, 0x133e791bd580, 0x134bf81229e7,
+    0x134ef15574e8, 0x1350c5cda310, 0x13530f63dcc2, 0x135ce569d4d9,
+    0x136471d9933e, 0x1365d133b5dc, 0x1367e03ae9c9, 0x1368ca7700dd,
+    0x13693f950c67, 0x1369b4b317f1, 0x1372a07cf8f4, 0x1374af842ce1,
+    0x1377a8c777e2, 0x137d60bf081f, 0x1380949158e5, 0x1387e6721185,
+    0x138f3852ca25, 0x1393566131ff, 0x1397746f99d9, 0x139ae2d0f064,
+    0x139c7cba18c7, 0x139f00df583e, 0x13a3ce9ad167, 0x13a91174561a,
+    0x13aa363f72f3, 0x13b481637694, 0x13b5311087e3, 0x13bc0dd334f9,
+    0x13becc877a35, 0x13c20059cafb, 0x13c2b006dc4a, 0x13c32524e7d4,
+    0x13c39a42f35e, 0x13c8a28d724c, 0x13d328407bb2, 0x13d39d5e873c,
+    0x13ddadf38518, 0x13e5ea1054cc, 0x13e7496a776a, 0x13f7c1a416d2,
+    0x1403a6b142d6, 0x141c9596b7b7, 0x1422fd3b5943, 0x1426310daa09,
+    0x14350f5e210e, 0x1441dea76426, 0x14458797c076, 0x1453068e14dd,
+    0x14542b5931b6, 0x14616fc08058, 0x1465533fe26d, 0x1466b29a050b,
+    0x1472d23636d4, 0x1475565b764b, 0x1477da80b5c2, 0x1479e987e9af,
+    0x148609241b78, 0x1491ee31477c, 0x149312fc6455, 0x149bfec64558,
+    0x149c73e450e2, 0x14a5d4cc3d6f, 0x14ab5234c7e7, 0x14b6c223e861,
+    0x14b73741f3eb, 0x14b90bba2213, 0x14c52b5653dc, 0x14c615926af0,
+    0x14c6c53f7c3f, 0x14cec6cd462e, 0x14d09b457456, 0x14d2aa4ca843,
+    0x14d94c804f94, 0x14da714b6c6d, 0x14dae66977f7, 0x14dcf570abe4,
+    0x14e14e0e1983, 0x14e40cc25ebf, 0x14e89feed223, 0x14ecf88c3fc2,
+    0x14efb74084fe, 0x14fa0264889f, 0x15015445413f, 0x15057253a919,
+    0x15127c2bf1f6, 0x1515ea8d4881, 0x151a089bb05b, 0x15211fed6336,
+    0x1523a412a2ad, 0x1529d1283e74, 0x152a0bb74439, 0x152b30826112,
+    0x152f4e90c8ec, 0x15315d97fcd9, 0x153247d413ed, 0x1532826319b2,
+    0x153a0ed2d817, 0x154126248af2, 0x15441f67d5f3, 0x154509a3ed07,
+    0x1547c8583243, 0x1555474e86aa, 0x1561dc08c3fd, 0x1570453b2f78,
+    0x157512f6a8a1, 0x158466652b30, 0x158b4327d846, 0x15925a798b21,
+    0x15937f44a7fa, 0x159bbb6177ae, 0x15a0891cf0d7, 0x15a6b6328c9e,
+    0x15b3857bcfb6, 0x15b7a38a3790, 0x15ba9ccd8291, 0x15c179902fa7,
+    0x15c7a6a5cb6e, 0x15cb150721f9, 0x15ccaef04a5c, 0x15d5602b259a,
+    0x15db52b1bb9c, 0x15e22f7468b2, 0x15eae0af43f0, 0x15f5db8058e0,
+    0x1614bcec63c3, 0x16218c35a6db, 0x16277ebc3cdd, 0x16298dc370ca,
+    0x1634889485ba, 0x163ed3b8895b, 0x16411d4ec30d, 0x1642b737eb70,
+    0x1643dc030849, 0x1647bf826a5e, 0x164993fa9886, 0x164c8d3de387,
+    0x16532f718ad8, 0x16566343db9e, 0x165c90597765, 0x1664cc764719,
+    0x16692513b4b8, 0x166be3c7f9f4, 0x16737037b859, 0x1678b3113d0c,
+    0x168129bd1285, 0x168289173523, 0x1685825a8024, 0x1685bce985e9,
+    0x168baf701beb, 0x168be9ff21b0, 0x1690b7ba9ad9, 0x16921714bd77,
+    0x1698442a593e, 0x169a8dc092f0, 0x169fd09a17a3, 0x16a0804728f2,
+    0x16a513739c56, 0x16a5fdafb36a, 0x16ab4089381d, 0x16b21d4be533,
+    0x16bbf351dd4a, 0x16bca2feee99, 0x16bd52abffe8, 0x16bfd6d13f5f,
+    0x16c1362b61fd, 0x16c220677911, 0x16cb0c315a14, 0x16d08999e48c,
+    0x16d8c5b6b440, 0x16da2510d6de, 0x16e2269ea0cd, 0x16e6b9cb1431,
+    0x16e6f45a19f6, 0x16e7de96310a, 0x16ec71c2a46e, 0x16f264493a70,
+    0x16f2d96745fa, 0x16f313f64bbf, 0x16f5d2aa90fb, 0x17038c2feb27,
+    0x171ae12c37a5, 0x171dda6f82a6, 0x17231d490759, 0x172ae447cb83,
+    0x172b1ed6d148, 0x172b5965d70d, 0x17352f6bcf24, 0x17373e730311,
+    0x1746cc708b65, 0x1749c5b3d666, 0x174b9a2c048e, 0x174c0f4a1018,
+    0x174cf986272c, 0x174fb83a6c68, 0x1752b17db769, 0x1753d648d442,
+    0x17557031fca5, 0x1757f4573c1c, 0x17623f7b3fbd, 0x177660a53b75,
+    0x177994778c3b, 0x17820b2361b4, 0x1787136de0a2, 0x178b317c487c,
+    0x1794ccf33ace, 0x17999aaeb3f7, 0x179b6f26e21f, 0x17ac970d92d6,
+    0x17b164c90bff, 0x17b87c1abeda, 0x17b8b6a9c49f, 0x17c2c73ec27b,
+    0x17c794fa3ba4, 0x17d3ef257332, 0x17db0677260d, 0x17dee9f68822,
+    0x17df24858de7, 0x17ea1f56a2d7, 0x17ee02d604ec, 0x17eeed121c00,
+    0x17f04c6c3e9e, 0x17f220e46cc6, 0x17f3803e8f64, 0x17f4df98b202,
+    0x17f7292eebb4, 0x17f8fda719dc, 0x17fad21f4804, 0x1801e970fadf,
+    0x180b4a58e76c, 0x18160a9af697, 0x1825d32784b0, 0x182b8b1f14ed,
+    0x182bc5ae1ab2, 0x1833521dd917, 0x18352696073f, 0x184146323908,
+    0x184355396cf5, 0x18459ecfa6a7, 0x18568c275199, 0x1859856a9c9a,
+    0x185f3d622cd7, 0x18609cbc4f75, 0x1874bde64b2d, 0x1876925e7955,
+    0x1883d6c5c7f7, 0x188e5c78d15d, 0x1891904b2223, 0x18927a873937,
+    0x189782d1b825, 0x189b66511a3a, 0x18a4178bf578, 0x18a4521afb3d,
+    0x18a994f47ff0, 0x18b53f72a62f, 0x18b87344f6f5, 0x18b8e863027f,
+    0x18c4585222f9, 0x18c5f23b4b5c, 0x18c6675956e6, 0x18cb6fa3d5d4,
+    0x18d2fc139439, 0x18da8883529e, 0x18e0f027f42a, 0x18e45e894ab5,
+    0x18e50e365c04, 0x18ec601714a4, 0x18ef595a5fa5, 0x18f1a2f09957,
+    0x18f3ec86d309, 0x18f8ba424c32, 0x1900f65f1be6, 0x190290484449,
+    0x19072374b7ad, 0x190b7c12254c, 0x191eed8f0fb5, 0x19204ce93253,
+    0x1926ef1cd9a4, 0x192b47ba4743, 0x192e7b8c9809, 0x1932247cf459,
+    0x1933be661cbc, 0x1934e3313995, 0x1936428b5c33, 0x1937dc748496,
+    0x19455b6ad8fd, 0x1947df901874, 0x19488f3d29c3, 0x19493eea3b12,
+    0x194cad4b919d, 0x1953c49d4478, 0x195d25853105, 0x19739045666f,
+    0x1978988fe55d, 0x197f75529273, 0x1984430e0b9c, 0x198a3594a19e,
+    0x1993215e82a1, 0x1994bb47ab04, 0x199779fbf040, 0x199a733f3b41,
+    0x19b3d742bbac, 0x19ba04585773, 0x19c414ed554f, 0x19c6991294c6,
+    0x19d5b1f21190, 0x19d8ab355c91, 0x19e7c414d95b, 0x1a14247738a5,
+    0x1a150eb34fb9, 0x1a184285a07f, 0x1a192cc1b793, 0x1a1c26050294,
+    0x1a1e350c3681, 0x1a1ee4b947d0, 0x1a2c9e3ea1fc, 0x1a4552951118,
+    0x1a463cd1282c, 0x1a493614732d, 0x1a646e9021c0, 0x1a65cdea445e,
+    0x1a67a2627286, 0x1a6b10c3c911, 0x1a6c701debaf, 0x1a7228157bec,
+    0x1a881db7a5cc, 0x1a8bc6a8021c, 0x1a9adf877ee6, 0x1aa390c25a24,
+    0x1aa47afe7138, 0x1aa77441bc39, 0x1aab1d321889, 0x1ab3594ee83d,
+    0x1ab7023f448d, 0x1ab86199672b, 0x1abaab2fa0dd, 0x1ac655adc71c,
+    0x1ac6cacbd2a6, 0x1acc48345d1e, 0x1ad77d9477d3, 0x1ae4fc8acc3a,
+    0x1ae53719d1ff, 0x1ae5e6c6e34e, 0x1ae69673f49d, 0x1aebd94d7950,
+    0x1aed38a79bee, 0x1af1cbd40f52, 0x1af44ff94ec9, 0x1af83378b0de,
+    0x1afed5ac582f, 0x1afffa777508, 0x1b05b26f0545, 0x1b115ced2b84,
+    0x1b1281b8485d, 0x1b13e1126afb, 0x1b18aecde424, 0x1b1abdd51811,
+    0x1b2000ae9cc4, 0x1b283ccb6c78, 0x1b358132bb1a, 0x1b41663fe71e,
+    0x1b43ea652695, 0x1b450f30436e, 0x1b466e8a660c, 0x1b47935582e5,
+    0x1b51a3ea80c1, 0x1b5a1a96563a, 0x1b77278a32f5, 0x1b78c1735b58,
+    0x1b83472664be, 0x1b8b48b42ead, 0x1b9fa46d302a, 0x1ba3c27b9804,
+    0x1ba905551cb7, 0x1bc2de76a8ac, 0x1bc5629be823, 0x1bc7e6c1279a,
+    0x1bd47b7b64ed, 0x1bd4f0997077, 0x1bdb92cd17c8, 0x1be31f3cd62d,
+    0x1c018b8ad586, 0x1c056f0a379b, 0x1c0c113ddeec, 0x1c10a46a5250,
+    0x1c17f64b0af0, 0x1c36d7b715d3, 0x1c3abb3677e8, 0x1c3ba5728efc,
+    0x1c3cca3dabd5, 0x1c462b259862, 0x1c4b33701750, 0x1c599ca282cb,
+    0x1c5be638bc7d, 0x1c633819751d, 0x1c6422558c31, 0x1c76e425654b,
+    0x1c80f4ba6327, 0x1c85c275dc50, 0x1c86e740f929, 0x1c8acac05b3e,
+    0x1c942ba847cb, 0x1c958b026a69, 0x1c980f27a9e0, 0x1c9a58bde392,
+    0x1c9bf2a70bf5, 0x1ca5c8ad040c, 0x1ca6785a155b, 0x1cb1389c2486,
+    0x1cb4e18c80d6, 0x1cb67b75a939, 0x1cc51f371a79, 0x1cca9c9fa4f1,
+    0x1cda652c330a, 0x1cda9fbb38cf, 0x1cdada4a3e94, 0x1cdc39a46132,
+    0x1cf7e73e1b4f, 0x1cfb902e779f, 0x1d052ba569f1, 0x1d1110b295f5,
+    0x1d1394d7d56c, 0x1d14b9a2f245, 0x1d1703392bf7, 0x1d17ed75430b,
+    0x1d18d7b15a1f, 0x1d240d1174d4, 0x1d256c6b9772, 0x1d29ff980ad6,
+    0x1d3659c34264, 0x1d37b91d6502, 0x1d491b931b7e, 0x1d4990b12708,
+    0x1d6abbb36b9d, 0x1d6c559c9400, 0x1d72bd41358c, 0x1d77c58bb47a,
+    0x1d79d492e867, 0x1d8509f3031c, 0x1d85448208e1, 0x1d92fe07630d,
+    0x1d95bcbba849, 0x1d99a03b0a5e, 0x1d9be9d14410, 0x1da33bb1fcb0,
+    0x1da4d59b2513, 0x1da5fa6641ec, 0x1da759c0648a, 0x1db1a4e4682b,
+    0x1db33ecd908e, 0x1db6729fe154, 0x1dc63b2c6f6d, 0x1dcbb894f9e5,
+    0x1dcc68420b34, 0x1dd9721a5411, 0x1ddf2a11e44e, 0x1de0896c06ec,
+    0x1de3bd3e57b2, 0x1de46ceb6901, 0x1df22670c32d, 0x1df3c059eb90,
+    0x1df6f42c3c56, 0x1df7de68536a, 0x1df8c8a46a7e, 0x1e00551428e3,
+    0x1e101da0b6fc, 0x1e28224a14c9, 0x1e36165e74ba, 0x1e389a83b431,
+    0x1e40d6a083e5, 0x1e4703b61fac, 0x1e49fcf96aad, 0x1e4bd17198d5,
+    0x1e4cbbadafe9, 0x1e5c49ab383d, 0x1e6535751940, 0x1e665a403619,
+    0x1e6a03309269, 0x1e7204be5c58, 0x1e73d9368a80, 0x1e7bdac4546f,
+    0x1e82b7870185, 0x1e82f216074a, 0x1e83a1c31899, 0x1e87bfd18073,
+    0x1e915b4872c5, 0x1e92458489d9, 0x1e94c9a9c950, 0x1ea99a80d657,
+    0x1eb24bbbb195, 0x1eb50a6ff6d1, 0x1eb83e424797, 0x1ebac267870e,
+    0x1ebc21c1a9ac, 0x1ece6e73773c, 0x1ed7cf5b63c9, 0x1eeb0649486d,
+    0x1eeca03270d0, 0x1f1d93c1437e, 0x1f1eb88c6057, 0x1f2a287b80d1,
+    0x1f2ad8289220, 0x1f33144561d4, 0x1f3389636d5e, 0x1f33c3f27323,
+    0x1f33fe8178e8, 0x1f3b15d32bc3, 0x1f40933bb63b, 0x1f443c2c128b,
+    0x1f5146045b68, 0x1f52dfed83cb, 0x1f6183aef50b, 0x1f6776358b0d,
+    0x1f898b73e6b6, 0x1f8ece4d6b69, 0x1f948644fba6, 0x1f94c0d4016b,
+    0x1f9ed168ff47, 0x1fab66233c9a, 0x1fb5019a2eec, 0x1fbb2eafcab3,
+    0x1fbe62821b79, 0x1fc45508b17b, 0x1fcd40d2927e, 0x1fd333592880,
+    0x1fd5b77e67f7, 0x1fd9d58ccfd1, 0x1fdf18665484, 0x1fe83ebf3b4c,
+    0x1ff3aeae5bc6, 0x20033cabe41a, 0x2007202b462f, 0x200f5c4815e3,
+    0x20154eceabe5, 0x2018f7bf0835, 0x202467ae28af, 0x2029e516b327,
+    0x2037297e01c9, 0x2037640d078e, 0x2039ada34140, 0x203a22c14cca,
+    0x203d56939d90, 0x2047dc46a6f6, 0x20529c88b621, 0x2059794b6337,
+    0x2061b56832eb, 0x206b8b6e2b02, 0x206fa97c92dc, 0x20847a539fe3,
+    0x2089bd2d2496, 0x2091499ce2fb, 0x209358a416e8, 0x209567ab4ad5,
+    0x209701947338, 0x20989b7d9b9b, 0x209ae513d54d, 0x20a35bbfaac6,
+    0x20a61a73f002, 0x20a65502f5c7, 0x20aae82f692b, 0x20b8a1b4c357,
+    0x20b95161d4a6, 0x20c068b38781, 0x20c7f52345e6, 0x20d1cb293dfd,
+    0x20d8e27af0d8, 0x20de5fe37b50, 0x20df84ae9829, 0x20e24362dd65,
+    0x20edb351fddf, 0x20f0ac9548e0, 0x20f8e8b21894, 0x20f923411e59,
+    0x20ffc574c5aa, 0x210333d61c35, 0x21036e6521fa, 0x2105084e4a5d,
+    0x210a1098c94b, 0x210d446b1a11, 0x2114964bd2b1, 0x212b762a13a5,
+    0x212cd5843643, 0x2132185dbaf6, 0x2132c80acc45, 0x2133b246e359,
+    0x2138ba916247, 0x2146aea5c238, 0x214bf17f46eb, 0x215fd81a3cde,
+    0x21604d384868, 0x216221b07690, 0x217384262d0c, 0x2173f9443896,
+    0x217b1095eb71, 0x217b4b24f136, 0x217c3561084a, 0x217f2ea4534b,
+    0x2185212ae94d, 0x21888f8c3fd8, 0x2192a0213db4, 0x21a0cec4a36a,
+    0x21a8d0526d59, 0x21bb1d043ae9, 0x21bbccb14c38, 0x21c31e9204d8,
+    0x21c65264559e, 0x21cb953dda51, 0x21d2ac8f8d2c, 0x21d530b4cca3,
+    0x21d89f16232e, 0x21e77d669a33, 0x21e8a231b70c, 0x220191172bed,
+    0x2208334ad33e, 0x22086dd9d903, 0x2216275f332f, 0x221c5474cef6,
+    0x222a831834ac, 0x2232bf350460, 0x22341e8f26fe, 0x2245bb93e33f,
+    0x224630b1eec9, 0x224afe6d67f2, 0x225499e45a44, 0x2261a3bca321,
+    0x2266e69627d4, 0x22675bb4335e, 0x226c296fac87, 0x226c9e8db811,
+    0x2273f06e70b1, 0x2276af22b5ed, 0x2276e9b1bbb2, 0x227a92a21802,
+    0x22979f95f4bd, 0x2299e92c2e6f, 0x229bf833625c, 0x22a3bf322686,
+    0x22a97729b6c3, 0x22abfb4ef63a, 0x22b228649201, 0x22b3c24dba64,
+    0x22b97a454aa1, 0x22c17bd31490, 0x22d68739275c, 0x22d9f59a7de7,
+    0x22db1a659ac0, 0x22e64fc5b575, 0x22e7af1fd813, 0x22ea3345178a,
+    0x22f18525d02a, 0x230188416408, 0x23061b6dd76c, 0x23074038f445,
+    0x231325462049, 0x23140f82375d, 0x2315344d5436, 0x23218e788bc4,
+    0x2324c24adc8a, 0x23286b3b38da, 0x23411f91a7f6, 0x234c1a62bce6,
+    0x23595eca0b88, 0x235a83952861, 0x2365f38448db, 0x2369275699a1,
+    0x236d0ad5fbb6, 0x236ea4bf2419, 0x237c23b57880, 0x238a17c9d871,
+    0x2391a43996d6, 0x239b7a3f8eed, 0x23a21c73363e, 0x23a2919141c8,
+    0x23a88417d7ca, 0x23aacdae117c, 0x23ab083d1741, 0x23ac2d08341a,
+    0x23c9af1a1c5f, 0x23cbbe21504c, 0x23d225c5f1d8, 0x23d2d5730327,
+    0x23d5ceb64e28, 0x23d7a32e7c50, 0x23e6817ef355, 0x23e7a64a102e,
+    0x23ec39768392, 0x23f0cca2f6f6, 0x23f734479882, 0x23f893a1bb20,
+    0x240b5571943a, 0x240fe89e079e, 0x24152b778c51, 0x2416c560b4b4,
+    0x24209b66accb, 0x242f79b723d0, 0x24302964351f, 0x2435a6ccbf97,
+    0x2440dc2cda4c, 0x2448ddbaa43b, 0x2457f69a2105, 0x245b64fb7790,
+    0x246241be24a6, 0x24627c4d2a6b, 0x24632bfa3bba, 0x247e6475ea4d,
+    0x247f89410726, 0x248715b0c58b, 0x2487c55dd6da, 0x2488750ae829,
+    0x248b6e4e332a, 0x2494cf361fb7, 0x2495b97236cb, 0x249a872daff4,
+    0x24a03f254031, 0x24a631abd633, 0x24b4604f3be9, 0x24b9a328c09c,
+    0x24c9e0d35a3f, 0x24d96ed0e293, 0x24db434910bb, 0x24e4a430fd48,
+    0x24e84d215998, 0x24fbf92d49c6, 0x25051f86308e, 0x250594a43c18,
+    0x250d5ba30042, 0x2524b09f4cc0, 0x252a6896dcfd, 0x25322f95a127,
+    0x25390c584e3d, 0x253b1b5f822a, 0x253c059b993e, 0x2545668385cb,
+    0x2547eaa8c542, 0x254aa95d0a7e, 0x25511101ac0a, 0x25589d716a6f,
+    0x255987ad8183, 0x257918c69db5, 0x25886c352044, 0x258b65786b45,
+    0x2598a9dfb9e7, 0x259b6893ff23, 0x25a663651413, 0x25b507268553,
+    0x25b7162db940, 0x25b78b4bc4ca, 0x25bf524a88f4, 0x25c8edc17b46,
+    0x25cac239a96e, 0x25cf55661cd2, 0x25d24ea967d3, 0x25d3ae038a71,
+    0x25db3a7348d6, 0x25e16788e49d, 0x25f2c9fe9b19, 0x25f6e80d02f3,
+    0x25fd4fb1a47f, 0x25fdff5eb5ce, 0x26046703575a, 0x2608fa2fcabe,
+    0x260b0936feab, 0x260f27456685, 0x26142f8fe573, 0x261f9f7f05ed,
+    0x2626b6d0b8c8, 0x2636f47b526b, 0x2655263a4bff, 0x265a2e84caed,
+    0x266688b0027b, 0x26710e630be1, 0x26747cc4626c, 0x26844550f085,
+    0x268da638dd12, 0x26909f7c2813, 0x2694bd8a8fed, 0x269741afcf64,
+    0x26a3614c012d, 0x26b06b244a0a, 0x26bc15a27049, 0x26c1930afac1,
+    0x26c8e4ebb361, 0x26d7fdcb302b, 0x26dabc7f7567, 0x26df4fabe8cb,
+    0x26e19942227d, 0x26e2be0d3f56, 0x26eb34b914cf, 0x26f161ceb096,
+    0x26f4d0300721, 0x26fd0c4cd6d5, 0x2706a7c3c927, 0x271510f634a2,
+    0x27280d551381, 0x272acc0958bd, 0x272f99c4d1e6, 0x27381070a75f,
+    0x273c690e14fe, 0x27525eb03ede, 0x2755cd119569, 0x2758c654e06a,
+    0x27631178e40b, 0x2764e5f11233, 0x2771efc95b10, 0x2776f813d9fe,
+    0x27a024a3e882, 0x27a7eba2acac, 0x27acf3ed2b9a, 0x27b3d0afd8b0,
+    0x27b4805ce9ff, 0x27bb97ae9cda, 0x27c9c6520290, 0x27dbd874ca5b,
+    0x28015c147c8f, 0x2806645efb7d, 0x280ba7388030, 0x2812496c2781,
+    0x281458735b6e, 0x282002f181ad, 0x28203d808772, 0x2820ed2d98c1,
+    0x2823e670e3c2, 0x2830409c1b50, 0x28345eaa832a, 0x2845869133e1,
+    0x2846e5eb567f, 0x2863433221eb, 0x286726b18400, 0x28679bcf8f8a,
+    0x286a5a83d4c6, 0x287938d44bcb, 0x287fa078ed57, 0x288299bc3858,
+    0x288f2e7675ab, 0x2895d0aa1cfc, 0x28a7e2cce4c7, 0x28ab512e3b52,
+    0x28c1815f6af7, 0x28d8613dabeb, 0x28f56e3188a6, 0x28fb262918e3,
+    0x29091a3d78d4, 0x2915e986bbec, 0x29169933cd3b, 0x2916d3c2d300,
+    0x291ab7423515, 0x291d3b67748c, 0x29320c3e8193, 0x29433425324a,
+    0x2948b18dbcc2, 0x2959645661ef, 0x2961db023768, 0x2962ffcd5441,
+    0x29645f2776df, 0x29780b33670d, 0x2978f56f7e21, 0x297d4e0cebc0,
+    0x2980475036c1, 0x2995182743c8, 0x2997272e77b5, 0x299b7fcbe554,
+    0x29a2971d982f, 0x29a72a4a0b93, 0x29b1756e0f34, 0x29b6f2d699ac,
+    0x29b817a1b685, 0x29c38790d6ff, 0x29d5d442a48f, 0x29d683efb5de,
+    0x29d9f2510c69, 0x29e35338f8f6, 0x29ecb420e583, 0x29ffeb0eca27,
+    0x2a068d427178, 0x2a0702607d02, 0x2a1e1ccdc3bb, 0x2a274326aa83,
+    0x2a277db5b048, 0x2a34fcac04af, 0x2a3cc3aac8d9, 0x2a3f825f0e15,
+    0x2a641bc2a935, 0x2a65f03ad75d, 0x2a68e97e225e, 0x2a69992b33ad,
+    0x2a76687476c5, 0x2a7afba0ea29, 0x2a7dba552f65, 0x2a87905b277c,
+    0x2a94d4c2761e, 0x2aa1697cb371, 0x2aa42830f8ad, 0x2aa7217443ae,
+    0x2aa9307b779b, 0x2ab37b9f7b3c, 0x2abbb7bc4af0, 0x2acad09bc7ba,
+    0x2ad84f921c21, 0x2ae0c63df19a, 0x2ae9ec96d862, 0x2af34d7ec4ef,
+    0x2b13c8d3f835, 0x2b17ac535a4a, 0x2b210d3b46d7, 0x2b261585c5c5,
+    0x2b33947c1a2c, 0x2b3444292b7b, 0x2b3568f44854, 0x2b37ed1987cb,
+    0x2b4915003882, 0x2b55a9ba75d5, 0x2b83de950347, 0x2b8662ba42be,
+    0x2b8b3075bbe7, 0x2b915d8b57ae, 0x2bab36ace3a3, 0x2bc6a9b797fb,
+    0x2bdbefacb08c, 0x2be7250ccb41, 0x2bff9ed43498, 0x2bffd9633a5d,
+    0x2c0347c490e8, 0x2c0bbe706661, 0x2c201a2967de, 0x2c39090edcbf,
+    0x2c3bc7c321fb, 0x2c3c025227c0, 0x2c3d61ac4a5e, 0x2c3dd6ca55e8,
+    0x2c5f3c5ba042, 0x2c635a6a081c, 0x2c73d2a3a784, 0x2c7bd4317173,
+    0x2c82eb83244e, 0x2c906a7978b5, 0x2c9ff8770109, 0x2cad024f49e6,
+    0x2cb27fb7d45e, 0x2cb454300286, 0x2cb69dc63c38, 0x2cb8723e6a60,
+    0x2cb997098739, 0x2cba46b69888, 0x2cc715ffdba0, 0x2cd962b1a930,
+    0x2cf4609e51fe, 0x2cf5bff8749c, 0x2cf71f52973a, 0x2d080caa422c,
+    0x2d08473947f1, 0x2d1884e3e194, 0x2d1dc7bd6647, 0x2d1eb1f97d5b,
+    0x2d1f9c35946f, 0x2d30feab4aeb, 0x2d367c13d563, 0x2d388b1b0950,
+    0x2d4644a0637c, 0x2d5856c32b47, 0x2d61428d0c4a, 0x2d73c9cddf9f,
+    0x2d75d8d5138c, 0x2d78978958c8, 0x2d7bcb5ba98e, 0x2d7c7b08badd,
+    0x2d947fb218aa, 0x2da3237389ea, 0x2db7449d85a2, 0x2dbb281ce7b7,
+    0x2dbcfc9515df, 0x2dc7bcd7250a, 0x2dd91f4cdb86, 0x2defc49c16b5,
+    0x2df0e967338e, 0x2df62c40b841, 0x2df6a15ec3cb, 0x2df83b47ec2e,
+    0x2df9d5311491, 0x2e062f5c4c1f, 0x2e1373c39ac1, 0x2e14988eb79a,
+    0x2e175742fcd6, 0x2e44dc7078f9, 0x2e481042c9bf, 0x2e488560d549,
+    0x2e4b098614c0, 0x2e6139b74465, 0x2e6975d41419, 0x2e89b69a419a,
+    0x2e943c4d4b00, 0x2e9a2ed3e102, 0x2e9ced88263e, 0x2e9d9d35378d,
+    0x2ea4b486ea68, 0x2ea56433fbb7, 0x2eb47d137881, 0x2eb5dc6d9b1f,
+    0x2ec8291f68af, 0x2ed448bb9a78, 0x2ed7f1abf6c8, 0x2edbd52b58dd,
+    0x2ef41463bc6f, 0x2efba0d37ad4, 0x2f0501bb6761, 0x2f08aaabc3b1,
+    0x2f1a823f85b7, 0x2f275188c8cf, 0x2f3754a45cad, 0x2f378f336272,
+    0x2f4b3b3f52a0, 0x2f5a198fc9a5, 0x2f70f96e0a99, 0x2f71e3aa21ad,
+    0x2f7b7f2113ff, 0x2f84a579fac7, 0x2f8a5d718b04, 0x2f8bf75ab367,
+    0x2f8ef09dfe68, 0x2f94a8958ea5, 0x2f976749d3e1, 0x2f9d1f41641e,
+    0x2fa60b0b4521, 0x2fa88f308498, 0x2fa8c9bf8a5d, 0x2faa63a8b2c0,
+    0x2fb76d80fb9d, 0x2fb9b717354f, 0x2fc2dd701c17, 0x2fc47759447a,
+    0x2fcd6323257d, 0x2fcefd0c4de0, 0x2fd26b6da46b, 0x2fd6fe9a17cf,
+    0x2fd9bd4e5d0b, 0x2fe6c726a5e8, 0x2ff6ca4239c6, 0x2ff7b47e50da,
+    0x2ff8d9496db3, 0x300707ecd369, 0x300951830d1b, 0x301202bde859,
+    0x301b9e34daab, 0x301cc2fff784, 0x3055f2ab99e6, 0x30583c41d398,
+    0x306879ec6d3b, 0x30712b274879, 0x307b764b4c1a, 0x307d4ac37a42,
+    0x307e6f8e971b, 0x30854c514431, 0x3092cb479898, 0x30a00faee73a,
+    0x30abba2d0d79, 0x30ae3e524cf0, 0x30b3bbbad768, 0x30cf69549185,
+    0x30e1f09564da, 0x30f771198330, 0x31059fbce8e6, 0x3109833c4afb,
+    0x3115684976ff, 0x31439d240471, 0x3148dffd8924, 0x315bdc5c6803,
+    0x317e2c29c971, 0x318a865500ff, 0x319be8cab77b, 0x31a2506f5907,
+    0x31b009f4b333, 0x31b6e6b76049, 0x31b7d0f3775d, 0x31d5187659dd,
+    0x31e3bc37cb1d, 0x31efdbd3fce6, 0x31f8527fd25f, 0x3205d17626c6,
+    0x322821438834, 0x3229f5bbb65c, 0x32339132a8ae, 0x3237af411088,
+    0x3239be484475, 0x323d2ca99b00, 0x3255a6710457, 0x3261c60d3620,
+    0x326a3cb90b99, 0x3279cab693ed, 0x32a2bcb79cac, 0x32aa49275b11,
+    0x32aaf8d46c60, 0x32ac582e8efe, 0x32af5171d9ff, 0x32b57e8775c6,
+    0x32ba4c42eeef, 0x32c5f6c1152e, 0x32cf57a901bb, 0x32d75936cbaa,
+    0x32f96e752753, 0x32fb7d7c5b40, 0x32ffd619c8df, 0x3300c055dff3,
+    0x33088754a41d, 0x330c6ad40632, 0x331b83b382fc, 0x3321b0c91ec3,
+    0x33246f7d63ff, 0x332d5b474502, 0x33381b89542d, 0x3346bf4ac56d,
+    0x335ae074c125, 0x336391af9c63, 0x336615d4dbda, 0x336c7d797d66,
+    0x33791233bab9, 0x337feef667cf, 0x3394fa5c7a9b, 0x339bd71f27b1,
+    0x33b91ea20a31, 0x33d20d877f12, 0x33d6db42f83b, 0x33dd7d769f8c,
+    0x33f422c5dabb, 0x33f5f73e08e3, 0x33faff8887d1, 0x33fdbe3ccd0d,
+    0x3413b3def6ed, 0x341f98ec22f1, 0x343c30c1f422, 0x344e7d73c1b2,
+    0x34508c7af59f, 0x345176b70cb3, 0x34534b2f3adb, 0x3456447285dc,
+    0x345a9d0ff37b, 0x345d213532f2, 0x3466821d1f7f, 0x3466bcac2544,
+    0x34689124536c, 0x3477e492d5fb, 0x3484794d134e, 0x3488223d6f9e,
+    0x348cb569e302, 0x349c43676b56, 0x34a1c0cff5ce, 0x34a3202a186c,
+    0x34ab21b7e25b, 0x34b4f7bdda72, 0x34c1175a0c3b, 0x34daf07b9830,
+    0x34e50110960c, 0x34f5042c29ea, 0x34f788516961, 0x34f8e7ab8bff,
+    0x34ff14c127c6, 0x3511d69100e0, 0x352a50586a37, 0x3535c0478ab1,
+    0x3542ca1fd38e, 0x354bf078ba56, 0x3556010db832, 0x355d185f6b0d,
+    0x357174186c8a, 0x3594e8b0ead1, 0x35981c833b97, 0x35a894bcdaff,
+    0x35abc88f2bc5, 0x35b10b68b078, 0x35b404abfb79, 0x35e4f83ace27,
+    0x35eb9a6e7578, 0x36070d7929d0, 0x360a414b7a96, 0x36174b23c373,
+    0x3626d9214bc7, 0x363ccec375a7, 0x36445b33340c, 0x36519f9a82ae,
+    0x36524f4793fd, 0x3660f309053d, 0x3664d6886752, 0x3677d2e74631,
+    0x3679a75f7459, 0x367d15c0cae4, 0x369813ad73b2, 0x369888cb7f3c,
+    0x36a6b76ee4f2, 0x36a9eb4135b8, 0x36aa605f4142, 0x36aad57d4ccc,
+    0x36b76a378a1f, 0x36dcb348368e, 0x36ec06b6b91d, 0x36f68c69c283,
+    0x37073f3267b0, 0x370cf729f7ed, 0x37161d82deb5, 0x3727ba879af6,
+    0x3728df52b7cf, 0x372c13250895, 0x3738e26e4bad, 0x3740a96d0fd7,
+    0x3749cfc5f69f, 0x374af4911378, 0x3757c3da5690, 0x37595dc37ef3,
+    0x375c5706c9f4, 0x376e692991bf, 0x3771626cdcc0, 0x37750b5d3910,
+    0x3786a861f551, 0x378b3b8e68b5, 0x379043d8e7a3, 0x37a37ac6cc47,
+    0x37abf172a1c0, 0x37b5c77899d7, 0x37b67725ab26, 0x37b8c0bbe4d8,
+    0x37c79f0c5bdd, 0x37c8894872f1, 0x37d433c69930, 0x37d7dcb6f580,
+    0x37f55ec8ddc5, 0x3807ab7aab55, 0x381355f8d194, 0x381b57869b83,
+    0x3822a9675423, 0x3825dd39a4e9, 0x3833d14e04da, 0x3835e05538c7,
+    0x383bd2dbcec9, 0x384cc03379bb, 0x386d3b88ad01, 0x387995b3e48f,
+    0x389493a08d5d, 0x38978ce3d85e, 0x38b33a7d927b, 0x38b66e4fe341,
+    0x38b7588bfa55, 0x38b8f27522b8, 0x38c0b973e6e2, 0x38d340b4ba37,
+    0x38e761deb5ef, 0x38eb455e1804, 0x38ef636c7fde, 0x38fad35ba058,
+    0x38fd1cf1da0a, 0x390b4b953fc0, 0x390ce57e6823, 0x39108e6ec473,
+    0x3913fcd01afe, 0x3914ac7d2c4d, 0x3914e70c3212, 0x3920918a5851,
+    0x392599d4d73f, 0x393d29602982, 0x39468a48160f, 0x394c7cceac11,
+    0x395cba7945b4, 0x3979524f16e5, 0x397aec383f48, 0x397daaec8484,
+    0x398a7a35c79c, 0x398cfe5b0713, 0x398f47f140c5, 0x39a32e8c36b8,
+    0x39a95ba1d27f, 0x39c62e06a975, 0x39d6a64048dd, 0x39d6e0cf4ea2,
+    0x39e49a54a8ce, 0x39e54a01ba1d, 0x39e84345051e, 0x39f3b3342598,
+    0x39f462e136e7, 0x39f721957c23, 0x3a0c678a94b4, 0x3a13444d41ca,
+    0x3a14a3a76468, 0x3a1b806a117e, 0x3a284fb35496, 0x3a2c6dc1bc70,
+    0x3a330ff563c1, 0x3a47311f5f79, 0x3a65d7fc6497, 0x3a6b5564ef0f,
+    0x3a74b64cdb9c, 0x3a889ce7d18f, 0x3a9865745fa8, 0x3a9bd3d5b633,
+    0x3aa952cc0a9a, 0x3ab23e95eb9d, 0x3acf4b89c858, 0x3ad0e572f0bb,
+    0x3ad5b32e69e4, 0x3ad9d13cd1be, 0x3adba5b4ffe6, 0x3ae1d2ca9bad,
+    0x3ae78ac22bea, 0x3ae8af8d48c3, 0x3af87819d6dc, 0x3af8b2a8dca1,
+    0x3afe6aa06cde, 0x3b1a8d583285, 0x3b24d87c3626, 0x3b2a1b55bad9,
+    0x3b30f81867ef, 0x3b55917c030f, 0x3b5bf920a49b, 0x3b651f798b63,
+    0x3b6609b5a277, 0x3b6c715a4403, 0x3b78cb857b91, 0x3b7940a3871b,
+    0x3b8b18374921, 0x3b998169b49c, 0x3b9c401df9d8, 0x3bbc0bc61bcf,
+    0x3bc9c54b75fb, 0x3bd65a05b34e, 0x3be39e6d01f0, 0x3bf6603cdb0a,
+    0x3c036a1523e7, 0x3c0d058c1639, 0x3c21614517b6, 0x3c2d465243ba,
+    0x3c2ea5ac6658, 0x3c35bcfe1933, 0x3c3ac5489821, 0x3c49a3990f26,
+    0x3c4b02f331c4, 0x3c5f993b3906, 0x3c6466f6b22f, 0x3c7bf6820472,
+    0x3c8d1e68b529, 0x3ca3fe46f61d, 0x3cab8ab6b482, 0x3cccb5b8f917,
+    0x3ce445444b5a, 0x3ce9c2acd5d2, 0x3cf0d9fe88ad, 0x3cf1fec9a586,
+    0x3cfb9a4097d8, 0x3d0ab32014a2, 0x3d0fbb6a9390, 0x3d1cffd1e232,
+    0x3d26262ac8fa, 0x3d38384d90c5, 0x3d43331ea5b5, 0x3d4abf8e641a,
+    0x3d4ea30dc62f, 0x3d51273305a6, 0x3d82ca6ee9a3, 0x3d8c2b56d630,
+    0x3d92cd8a7d81, 0x3d99352f1f0d, 0x3db30e50ab02, 0x3db59275ea79,
+    0x3dbd1ee5a8de, 0x3dd8cc7f62fb, 0x3ddadb8696e8, 0x3df6c3af56ca,
+    0x3df947d49641, 0x3e02a8bc82ce, 0x3e07eb960781, 0x3e0aaa4a4cbd,
+    0x3e21ff46993b, 0x3e24836bd8b2, 0x3e2866eb3ac7, 0x3e3919b3dff4,
+    0x3e3a03eff708, 0x3e50a93f3237, 0x3e58e55c01eb, 0x3e6ba72bdb05,
+    0x3e745866b643, 0x3e819cce04e5, 0x3e8d474c2b24, 0x3e8df6f93c73,
+    0x3e9b00d18550, 0x3ea7206db719, 0x3eaf5c8a86cd, 0x3ebacc79a747,
+    0x3ebb7c26b896, 0x3ebbf144c420, 0x3eca5a772f9b, 0x3ecd192b74d7,
+    0x3ed55548448b, 0x3eeb85797430, 0x3efc3842195d, 0x3f16c110b6a1,
+    0x3f2405780543, 0x3f3408939921, 0x3f37b183f571, 0x3f468fd46c76,
+    0x3f48644c9a9e, 0x3f5399acb553, 0x3f6cfdb035be, 0x3f736554d74a,
+    0x3f9c91e4e5ce, 0x3f9f50992b0a, 0x3fa2846b7bd0, 0x3fa7c7450083,
+    0x3fd49cc56b57, 0x3fd845b5c7a7, 0x3fdd137140d0, 0x3ff936290677,
+    0x3ffb0aa1349f, 0x3ffc69fb573d, 0x4004a61826f1, 0x400ad32dc2b8,
+    0x4016b83aeebc, 0x401ce5508a83, 0x40229d481ac0, 0x4024e6de5472,
+    0x4025d11a6b86, 0x40288fceb0c2, 0x4056c4a93e34, 0x4062e4456ffd,
+    0x40670253d7d7, 0x4073d19d1aef, 0x409412634870, 0x4096216a7c5d,
+    0x4096968887e7, 0x40970ba69371, 0x4097bb53a4c0, 0x4098a58fbbd4,
+    0x409aef25f586, 0x409c13f1125f, 0x409e5d874c11, 0x40a32b42c53a,
+    0x40bc54b73fe0, 0x40bc8f4645a5, 0x40d3a9b38c5e, 0x40e7904e8251,
+    0x40f87da62d43, 0x4107d114afd2, 0x410b3f76065d, 0x410c9ed028fb,
+    0x41279cbcd1c9, 0x4136f02b5458, 0x413a5e8caae3, 0x413ca822e495,
+    0x414fa481c374, 0x415437ae36d8, 0x4164ea76dc05, 0x4169b832552e,
+    0x416bc739891b, 0x4172de8b3bf6, 0x41773728a995, 0x4177ac46b51f,
+    0x417d29af3f97, 0x41839153e123, 0x418983da7725, 0x418c07ffb69c,
+    0x418c428ebc61, 0x419cbac85bc9, 0x41a35cfc031a, 0x41bde5caa05e,
+    0x41c069efdfd5, 0x41d993645a7b, 0x41eba5872246, 0x41ec1aa52dd0,
+    0x41ecca523f1f, 0x41f4cbe0090e, 0x41fbe331bbe9, 0x42062e55bf8a,
+    0x4213ad4c13f1, 0x421497882b05, 0x421581c44219, 0x42246014b91e,
+    0x4229dd7d4396, 0x423b7a81ffd7, 0x42421cb5a728, 0x426347b7ebbd,
+    0x4269af5c8d49, 0x426b4945b5ac, 0x427f6a6fb164, 0x428681c1643f,
+    0x4289b593b505, 0x429909023794, 0x42997e20431e, 0x42ab90430ae9,
+    0x42b984576ada, 0x42c1c0743a8e, 0x42ee95f4a562, 0x42f7472f80a0,
+    0x42f95636b48d, 0x42faf01fdcf0, 0x43095952486b, 0x430d0242a4bb,
+    0x430d3cd1aa80, 0x4319d18be7d3, 0x433126883451, 0x4333358f683e,
+    0x43387868ecf1, 0x43466c7d4ce2, 0x434b3a38c60b, 0x4368472ca2c6,
+    0x4371e2a39518, 0x43863e5c9695, 0x439815f0589b, 0x43a72ecfd565,
+    0x43c4eb70c36f, 0x43d4796e4bc3, 0x43d688757fb0, 0x43dfe95d6c3d,
+    0x43e0990a7d8c, 0x43e89a98477b, 0x43ebce6a9841, 0x43f7ee06ca0a,
+    0x43fae74a150b, 0x4401145fb0d2, 0x4401897dbc5c, 0x44040da2fbd3,
+    0x440b9a12ba38, 0x4423299e0c7b, 0x442f493a3e44, 0x442f83c94409,
+    0x4432427d8945, 0x4437fa751982, 0x4449225bca39, 0x44528343b6c6,
+    0x4457c61d3b79, 0x44792bae85d3, 0x449a1c21c4a3, 0x44a88554301e,
+    0x44a8fa723ba8, 0x44bbf6d11a87, 0x44cf684e04f0, 0x44d3113e6140,
+    0x44e7a7866882, 0x44f22d3971e8, 0x44f3177588fc, 0x44f59b9ac873,
+    0x44fc785d7589, 0x4506c381792a, 0x4519103346ba, 0x452111c110a9,
+    0x4526549a955c, 0x453448aef54d, 0x453cbf5acac6, 0x45418d1643ef,
+    0x45482f49eb40, 0x456367c599d3, 0x4567c0630772, 0x4568701018c1,
+    0x457abcc1e651, 0x458d4402b9a6, 0x45924c4d3894, 0x459545908395,
+    0x45b80a7bf08d, 0x45c3b4fa16cc, 0x45c42a182256, 0x45c5897244f4,
+    0x45d4a251c1be, 0x45d5176fcd48, 0x45d8c0602998, 0x45d9357e3522,
+    0x45e729929513, 0x45e813ceac27, 0x4626fb71dec6, 0x462a69d33551,
+    0x462ec270a2f0, 0x4632e07f0aca, 0x463564a44a41, 0x463cf11408a6,
+    0x46482674235b, 0x464ec8a7caac, 0x465779e2a5ea, 0x466b607d9bdd,
+    0x4684ff10220d, 0x468748a65bbf, 0x4696d6a3e413, 0x469835fe06b1,
+    0x469ba45f5d3c, 0x469f12c0b3c7, 0x46bc5a439647, 0x46c4966065fb,
+    0x46e6ab9ec1a4, 0x46ec29074c1c, 0x46f305c9f932, 0x46f6e9495b47,
+    0x46fbf193da35, 0x4704dd5dbb38, 0x47079c120074, 0x47146b5b438c,
+    0x471b481df0a2, 0x471fdb4a6406, 0x4726f29c16e1, 0x4731784f2047,
+    0x4733123848aa, 0x4739b46beffb, 0x473f31d47a73, 0x475268c25f17,
+    0x4755d723b5a2, 0x47685e6488f7, 0x476ae289c86e, 0x47744371b4fb,
+    0x477c7f8e84af, 0x479449a8dcb7, 0x4796933f1669, 0x4799176455e0,
+    0x47ac4e523a84, 0x47b48a6f0a38, 0x47b70e9449af, 0x47c243f46464,
+    0x47c27e836a29, 0x47d95e61ab1d, 0x47dba7f7e4cf, 0x47f7caafaa76,
+    0x47fbe8be1250, 0x48012b979703, 0x481462857ba7, 0x48180b75d7f7,
+    0x483c6a4a6d52, 0x483d54868466, 0x483f638db853, 0x48425cd10354,
+    0x484f66a94c31, 0x4859773e4a0d, 0x48622879254b, 0x4866bba598af,
+    0x48697a59ddeb, 0x486a9f24fac4, 0x48701c8d853c, 0x48714158a215,
+    0x4884ed649243, 0x48931c07f7f9, 0x4899491d93c0, 0x48a652f5dc9d,
+    0x48a777c0f976, 0x48b6cb2f7c05, 0x48c5e40ef8cf, 0x48c61e9dfe94,
+    0x48c6592d0459, 0x48c743691b6d, 0x48db64931725, 0x48eb2d1fa53e,
+    0x48f8ac15f9a5, 0x491fc99ed43c, 0x4920ee69f115, 0x49299fa4cc53,
+    0x492cd3771d19, 0x494c6490394b, 0x496518e6a867, 0x49704e46c31c,
+    0x49713882da30, 0x49717311dff5, 0x4977dab68181, 0x497bf8c4e95b,
+    0x498768b409d5, 0x498ce61c944d, 0x49934dc135d9, 0x4996f6b19229,
+    0x49a52554f7df, 0x49a60f910ef3, 0x49d5de54c4c8, 0x49da36f23267,
+    0x49e3d26924b9, 0x49ecf8c20b81, 0x49f4bfc0cfab, 0x49f61f1af249,
+    0x49fb61f476fc, 0x49fbd7128286, 0x4a08a65bc59e, 0x4a1bdd49aa42,
+    0x4a32f7b6f0fb, 0x4a3924cc8cc2, 0x4a3be380d1fe, 0x4a3c1e0fd7c3,
+    0x4a4718e0ecb3, 0x4a4a8742433e, 0x4a4be69c65dc, 0x4a4c9649772b,
+    0x4a512975ea8f, 0x4a78f6abd675, 0x4a875fde41f0, 0x4a879a6d47b5,
+    0x4a8bf30ab554, 0x4a9469b68acd, 0x4a9519639c1c, 0x4a97d817e158,
+    0x4abb4cb05f9f, 0x4abf302fc1b4, 0x4ac5d2636905, 0x4ac8cba6b406,
+    0x4acb4fcbf37d, 0x4ad3c677c8f6, 0x4afec78005a2, 0x4b04f495a169,
+    0x4b061960be42, 0x4b1532403b0c, 0x4b16919a5daa, 0x4b1cf93eff36,
+    0x4b21c6fa785f, 0x4b23d601ac4c, 0x4b55041f84bf, 0x4b745aa99b2c,
+    0x4b7878b80306, 0x4b9008435549, 0x4b93b133b199, 0x4b9ee693cc4e,
+    0x4bc88841e65c, 0x4bddce36feed, 0x4be4e588b1c8, 0x4be67f71da2b,
+    0x4bf6f7ab7993, 0x4bfb5048e732, 0x4bffe3755a96, 0x4c022d0b9448,
+    0x4c040183c270, 0x4c0dd789ba87, 0x4c126ab62deb, 0x4c159e887eb1,
+    0x4c1f39ff7103, 0x4c4657884b9a, 0x4c58dec91eef, 0x4c648947452e,
+    0x4c75b12df5e5, 0x4c80e68e109a, 0x4c815bac1c24, 0x4c97514e4604,
+    0x4c9800fb5753, 0x4c9c9427cab7, 0x4caafd5a3632, 0x4cb005a4b520,
+    0x4cb9668ca1ad, 0x4cc795300763, 0x4cd5fe6272de, 0x4cdf99d96530,
+    0x4d152094ab42, 0x4d17df48f07e, 0x4d1b88394cce, 0x4d2c0072ec36,
+    0x4d51498398a5, 0x4d56c6ec231d, 0x4d58264645bb, 0x4d5cb972b91f,
+    0x4d78a19b7901, 0x4d7916b9848b, 0x4d8a3ea03542, 0x4d8f46eab430,
+    0x4da95a9b45ea, 0x4dabdec08561, 0x4db53fa871ee, 0x4dc15f44a3b7,
+    0x4dc41df8e8f3, 0x4ddc22a246c0, 0x4de92c7a8f9d, 0x4df4d6f8b5dc,
+    0x4df9a4b42f05, 0x4e1726c6174a, 0x4e1b7f6384e9, 0x4e1ca42ea1c2,
+    0x4e58928e8960, 0x4e6352d0988b, 0x4e6b545e627a, 0x4e731b5d26a4,
+    0x4e8cb9efacd4, 0x4eab9b5bb7b7, 0x4ec44fb226d3, 0x4ed0e46c6426,
+    0x4ed5ecb6e314, 0x4ee3e0cb4305, 0x4ef9614f615b, 0x4efa10fc72aa,
+    0x4eff53d5f75d, 0x4f00edbf1fc0, 0x4f083f9fd860, 0x4f4761d210c4,
+    0x4f5ef15d6307, 0x4f6817b649cf, 0x4f74e6ff8ce7, 0x4f8733b15a77,
+    0x4f8f353f2466, 0x4fa89942a4d1, 0x4fc4816b64b3, 0x4fcf41ad73de,
+    0x4fe8e03ffa0e, 0x500e2950a67d, 0x502be5f19487, 0x502d0abcb160,
+    0x5038b53ad79f, 0x503be90d2865, 0x50500a37241d, 0x50528e5c6394,
+    0x505796a6e282, 0x5064a07f2b5f, 0x5083475c307d, 0x50851bd45ea5,
+    0x50897471cc44, 0x508a993ce91d, 0x508f2c695c81, 0x509b11768885,
+    0x50ab4f212228, 0x50b7e3db5f7b, 0x50c6879cd0bb, 0x50d9496ca9d5,
+    0x50dddc991d39, 0x50e185897989, 0x50e9c1a6493d, 0x50ee8f61c266,
+    0x50f0d8f7fc18, 0x51077e473747, 0x51136354634b, 0x51139de36910,
+    0x511c4f1e444e, 0x51248b3b1402, 0x512a4332a43f, 0x5142826b07d1,
+    0x514abe87d785, 0x5159d767544f, 0x516129480cef, 0x5164d238693f,
+    0x517300dbcef5, 0x5178f36264f7, 0x5191329ac889, 0x51af29cabc58,
+    0x51b51c51525a, 0x51b556e0581f, 0x51c1eb9a9572, 0x51c7de212b74,
+    0x51cd5b89b5ec, 0x51d29e633a9f, 0x51dd24164405, 0x51e1b742b769,
+    0x51e81ee758f5, 0x520b1e61cbb2, 0x52327679ac0e, 0x523a3d787038,
+    0x523b9cd292d6, 0x5248a6aadbb3, 0x5248e139e178, 0x52495657ed02,
+    0x524af0411565, 0x525b687ab4cd, 0x525fc118226c, 0x526a46cb2bd2,
+    0x526e9f689971, 0x527e67f5278a, 0x5283703fa678, 0x52841fecb7c7,
+    0x5288788a2566, 0x5288b3192b2b, 0x5295f78079cd, 0x529ac53bf2f6,
+    0x52a37676ce34, 0x52a79485360e, 0x52c7259e5240, 0x52d55441b7f6,
+    0x52d6790cd4cf, 0x52e298a90698, 0x52e72bd579fc, 0x52e9ea89bf38,
+    0x52ebbf01ed60, 0x52f903693c02, 0x53005549f4a2, 0x5307320ca1b8,
+    0x530bc539151c, 0x530c74e6266b, 0x531b18a797ab, 0x5328d22cf1d7,
+    0x534027293e55, 0x534b5c89590a, 0x5359160eb336, 0x53770d3ea705,
+    0x53a0745dbb4e, 0x53a960279c51, 0x53bb37bb5e57, 0x53d0b83f7cad,
+    0x53d8b9cd469c, 0x53da53b66eff, 0x53e0463d0501, 0x53e80d3bc92b,
+    0x53f6766e34a6, 0x54051a2fa5e6, 0x540ca69f644b, 0x5411745add74,
+    0x5416f1c367ec, 0x5433147b2d93, 0x543473d55031, 0x543c00450e96,
+    0x544894ff4be9, 0x545e15836a3f, 0x5463cd7afa7c, 0x5475df9dc247,
+    0x548b9ab0e662, 0x549cc2979719, 0x54a7484aa07f, 0x54b95a6d684a,
+    0x54c1216c2c74, 0x54c53f7a944e, 0x54ca0d360d77, 0x54d960a49006,
+    0x54dd0994ec56, 0x54e83ef5070b, 0x54ec979274aa, 0x54f2ff371636,
+    0x54ff93f15389, 0x550e7241ca8e, 0x551046b9f8b6, 0x552552200b82,
+    0x5527d6454af9, 0x553d91586f14, 0x5547dc7c72b5, 0x5550c84653b8,
+    0x5563c4a53297, 0x5569f1bace5e, 0x5574024fcc3a, 0x55caee9c5ca6,
+    0x55dd75dd2ffb, 0x55eed852e677, 0x55f037ad0915, 0x56015f93b9cc,
+    0x560a4b5d9acf, 0x560b7028b7a8, 0x561804e2f4fb, 0x5636e64effde,
+    0x563c63b78a56, 0x5646e96a93bc, 0x5662d193539e, 0x567ce543e558,
+    0x56aa6a71617b, 0x56af72bbe069, 0x56eab16eb6b8, 0x56fcc3917e83,
+    0x57048a9042ad, 0x5738ec806be6, 0x5741632c415f, 0x5746e094cbd7,
+    0x574dbd5778ed, 0x575c9ba7eff2, 0x57a4a9a40959, 0x57bb89824a4d,
+    0x57bfa790b227, 0x57db8fb97209, 0x57e231ed195a, 0x58161ebf3709,
+    0x5826d187dc36, 0x58391e39a9c6, 0x583d3c4811a0, 0x5849d1024ef3,
+    0x5878b589edb4, 0x587d0e275b53, 0x588ee5bb1d59, 0x58c8c513d10a,
+    0x58d1b0ddb20d, 0x58e263a6573a, 0x58e64725b94f, 0x58ea9fc326ee,
+    0x59439b16eb47, 0x596d3cc50555, 0x5987c593a299, 0x59933582c313,
+    0x59d21d25f5b2, 0x59db437edc7a, 0x59e3ba2ab1f3, 0x59e51984d491,
+    0x59f76636a221, 0x5a0818ff474e, 0x5a305b533ebe, 0x5a338f258f84,
+    0x5a3a6be83c9a, 0x5a3b90b35973, 0x5a3d2a9c81d6, 0x5a6448255c6d,
+    0x5a6aafc9fdf9, 0x5a70a25093fb, 0x5a75e52a18ae, 0x5a8329916750,
+    0x5aa9d1fc365d, 0x5aaa471a41e7, 0x5ab541eb56d7, 0x5ac928864cca,
+    0x5ada506cfd81, 0x5ae9de6a85d5, 0x5aea8e179724, 0x5af54e59a64f,
+    0x5b016df5d818, 0x5b0467392319, 0x5b331131bc15, 0x5b57aa955735,
+    0x5b6d65a87b50, 0x5b82367f8857, 0x5b84baa4c7ce, 0x5b97b703a6ad,
+    0x5bb6986fb190, 0x5bcbde64ca21, 0x5bd0e6af490f, 0x5bd36ad48886,
+    0x5bd7fe00fbea, 0x5bd8731f0774, 0x5beabfd0d504, 0x5bebaa0cec18,
+    0x5c079235abfa, 0x5c0e6ef85910, 0x5c0f59347024, 0x5c16708622ff,
+    0x5c48c36f184b, 0x5c49731c299a, 0x5c5ad591e016, 0x5c5bbfcdf72a,
+    0x5c7090a50431, 0x5c7fa98480fb, 0x5c9355907129, 0x5c994817072b,
+    0x5c9aa77129c9, 0x5ce3da386009, 0x5d11d483e7b6, 0x5d521b813cf3,
+    0x5d52561042b8, 0x5d549fa67c6a, 0x5d600f959ce4, 0x5d65526f2197,
+    0x5d66773a3e70, 0x5d9436f6c058, 0x5db2a344bfb1, 0x5deecc33ad14,
+    0x5df02b8dcfb2, 0x5df7f28c93dc, 0x5e057182e843, 0x5e0b297a7880,
+    0x5e0cfdf2a6a8, 0x5e12b5ea36e5, 0x5e15749e7c21, 0x5e5496d0b485,
+    0x5e904aa1965e, 0x5e96ecd53daf, 0x5e979c824efe, 0x5ec97a4d38c0,
+    0x5efda1ae5c34, 0x5f470f049839, 0x5f4a7d65eec4, 0x5f741f1408d2,
+    0x5f89da272ced, 0x5f8c98db7229, 0x5f9584a5532c, 0x5f9bec49f4b8,
+    0x5fbeebc46775, 0x5fcf63fe06dd, 0x5fd84fc7e7e0, 0x5fed5b2dfaac,
+    0x6008591aa37a, 0x600893a9a93f, 0x601478b6d543, 0x601aa5cc710a,
+    0x60abe68fc0b1, 0x60ac5badcc3b, 0x60d0ba826196, 0x60e75fd19cc5,
+    0x610591909659, 0x610815b5d5d0, 0x6109ea2e03f8, 0x61255d38b850,
+    0x615650c78afe, 0x61577592a7d7, 0x61590f7bd03a, 0x615c434e2100,
+    0x6168d8085e53, 0x6185aa6d3549, 0x618694a94c5d, 0x618ff59138ea,
+    0x6197f71f02d9, 0x619ff8acccc8, 0x61bccb11a3be, 0x61c6a1179bd5,
+    0x61c7c5e2b8ae, 0x61e45db889df, 0x621725bf8ab5, 0x62255462f06b,
+    0x6239006ee099, 0x624226c7c761, 0x624d2198dc51, 0x6260cda4cc7f,
+    0x62956a23fb7d, 0x62a323a955a9, 0x62b7b9f15ceb, 0x62b98e698b13,
+    0x62c06b2c3829, 0x62c69841d3f0, 0x62d91f82a745, 0x62dab96bcfa8,
+    0x62dc5354f80b, 0x62ef4fb3d6ea, 0x63045b19e9b6, 0x631a8b4b195b,
+    0x63372320ea8c, 0x634bf3f7f793, 0x6363be124f9b, 0x636a25b6f127,
+    0x636e43c55901, 0x638090772691, 0x63885775eabb, 0x638e848b8682,
+    0x639adeb6be10, 0x639d284cf7c2, 0x63b099c9e22b, 0x63d14fae1b36,
+    0x63d98bcaeaea, 0x642d09b624cb, 0x64471d66b685, 0x6447cd13c7d4,
+    0x6448f1dee4ad, 0x647bb9e5e583, 0x647cdeb1025c, 0x64830bc69e23,
+    0x64925f3520b2, 0x649a60c2eaa1, 0x64ac3856aca7, 0x64cdd876fcc6,
+    0x64e68ccd6be2, 0x64f062d363f9, 0x65069304939e, 0x650f7ece74a1,
+    0x651954d46cb8, 0x6530a9d0b936, 0x655b7049f01d, 0x655c95150cf6,
+    0x65775872afff, 0x657fcf1e8578, 0x6588805960b6, 0x659131943bf4,
+    0x65ad19bcfbd6, 0x65bb0dd15bc7, 0x65c3847d3140, 0x65d9ef3d66aa,
+    0x6609be011c7f, 0x660c07975631, 0x661cf4ef0123, 0x663693818753,
+    0x664e230cd996, 0x66511c502497, 0x66724752692c, 0x667f8bb9b7ce,
+    0x66d5533b2b61, 0x66e8150b047b, 0x66eca83777df, 0x66f93cf1b532,
+    0x66ffa49656be, 0x6709057e434b, 0x67181e5dc015, 0x674b5b82cc75,
+    0x674e54c61776, 0x676693fe7b08, 0x67827c273aea, 0x6784509f6912,
+    0x678a0896f94f, 0x678ba28021b2, 0x67a78aa8e194, 0x67b8b28f924b,
+    0x67c0eeac61ff, 0x67c6e132f801, 0x67ca4f944e8c, 0x681b83e94ebb,
+    0x68459ab57453, 0x6845d5447a18, 0x6848ce87c519, 0x6869f98a09ae,
+    0x687912698678, 0x687b968ec5ef, 0x687c463bd73e, 0x689535214c1f,
+    0x689cc1910a84, 0x689ed0983e71, 0x68a9564b47d7, 0x68acff3ba427,
+    0x68b11d4a0c01, 0x68b9ce84e73f, 0x68c27fbfc27d, 0x68c95c826f93,
+    0x68dd088e5fc1, 0x68e1269cc79b, 0x68f6e1afebb6, 0x69087eb4a7f7,
+    0x69280fcdc429, 0x692d52a748dc, 0x694b84664270, 0x696b8a9d6a2c,
+    0x696f338dc67c, 0x69947c9e72eb, 0x69c5aabc4b5e, 0x69cb2824d5d6,
+    0x69d1553a719d, 0x69d4fe2acded, 0x69daf0b163ef, 0x69ff4f85f94a,
+    0x6a19d854968e, 0x6a28b6a50d93, 0x6a30b832d782, 0x6a3167dfe8d1,
+    0x6a35fb0c5c35, 0x6a625b6ebb7f, 0x6aa9449fb80d, 0x6ab947bb4beb,
+    0x6aba31f762ff, 0x6abc40fe96ec, 0x6ac73bcfabdc, 0x6ae114f137d1,
+    0x6aeaeaf72fe8, 0x6af8a47c8a14, 0x6b0c8b178007, 0x6b3249463800,
+    0x6b34cd6b7777, 0x6b3ccef94166, 0x6b450b16111a, 0x6b48ee95732f,
+    0x6b4a887e9b92, 0x6b4b382bace1, 0x6b4c5cf6c9ba, 0x6b6720546cc3,
+    0x6b7464bbbb65, 0x6b77d31d11f0, 0x6b837d9b382f, 0x6b8a5a5de545,
+    0x6b934627c648, 0x6b9ad29784ad, 0x6bab856029da, 0x6bc1b591597f,
+    0x6bd4ec7f3e23, 0x6bd5d6bb5537, 0x6bdd632b139c, 0x6be8d31a3416,
+    0x6becb699962b, 0x6c161db8aa74, 0x6c3a41fe3a0a, 0x6c3a7c8d3fcf,
+    0x6c3e9a9ba7a9, 0x6c56d9d40b3b, 0x6c59236a44ed, 0x6c8165be3c5d,
+    0x6c8c26004b88, 0x6cbf632557e8, 0x6cc1722c8bd5, 0x6cf1f09d52f9,
+    0x6d017e9adb4d, 0x6d3396f4cad4, 0x6d3cbd4db19c, 0x6d4b26801d17,
+    0x6d53d7baf855, 0x6d5aef0cab30, 0x6d774c53769c, 0x6d820c9585c7,
+    0x6d8a83415b40, 0x6dbac7231c9f, 0x6dcdc381fb7e, 0x6df6b583043d,
+    0x6e17e08548d2, 0x6e1c3922b671, 0x6e2a67c61c27, 0x6e2c3c3e4a4f,
+    0x6e322ec4e051, 0x6e3562973117, 0x6e468a7de1ce, 0x6e5b5b54eed5,
+    0x6e6c48ac99c7, 0x6e7200a42a04, 0x6e9749b4d673, 0x6ea99666a403,
+    0x6eb9d4113da6, 0x6ed790b22bb0, 0x6ef6378f30ce, 0x6f0466329684,
+    0x6f07d493ed0f, 0x6f23479ea167, 0x6f36f3aa9195, 0x6f3b4c47ff34,
+    0x6f4856204811, 0x6f576effc4db, 0x6f5d9c1560a2, 0x6f6d64a1eebb,
+    0x6f77afc5f25c, 0x6f9d6df4aa55, 0x6fa1c69217f4, 0x6fac11b61b95,
+    0x6faf80177220, 0x6fcdec657179, 0x6fdffe883944, 0x701cd72437f6,
+    0x7022548cc26e, 0x70249e22fc20, 0x7038bf4cf7d8, 0x7047630e6918,
+    0x706176befad2, 0x706a6288dbd5, 0x7078cbbb4750, 0x70943ec5fba8,
+    0x709c05c4bfd2, 0x70a90f9d08af, 0x70b14bb9d863, 0x70d86942b2fa,
+    0x70f4516b72dc, 0x710b3149b3d0, 0x711074233883, 0x711541deb1ac,
+    0x7126a4546828, 0x7134234abc8f, 0x71350d86d3a3, 0x7137571d0d55,
+    0x714dfc6c4884, 0x7163b77f6c9f, 0x7164a1bb83b3, 0x719978c9b876,
+    0x71aebebed107, 0x71f189e165bb, 0x7202b1c81672, 0x720d377b1fd8,
+    0x7221cdc3271a, 0x7222f28e43f3, 0x7223a23b5542, 0x722994c1eb44,
+    0x7249d58818c5, 0x725928f69b54, 0x72671d0afb45, 0x7280bb9d8175,
+    0x728130bb8cff, 0x7286ae241777, 0x729a5a3007a5, 0x72b43351939a,
+    0x72cf6bcd422d, 0x72da669e571d, 0x72daa12d5ce2, 0x72eb8e8507d4,
+    0x72f39012d1c3, 0x72f6fe74284e, 0x72fa32467914, 0x730b1f9e2406,
+    0x7322749a7084, 0x7334119f2cc5, 0x73465e50fa55, 0x738c5d45dfcf,
+    0x73c7d687bbe3, 0x73c935e1de81, 0x73e015c01f75, 0x73f8ca168e91,
+    0x740056864cf6, 0x7404af23ba95, 0x742614b504ef, 0x745115bd419b,
+    0x7474ff73cb6c, 0x748cc98e2374, 0x7492f6a3bf3b, 0x749998d7668c,
+    0x74ba4ebb9f97, 0x74c92d0c169c, 0x74f16f600e0c, 0x74f42e145348,
+    0x74fa209ae94a, 0x750889cd54c5, 0x75229d7de67f, 0x7526466e42cf,
+    0x75322b7b6ed3, 0x75381e0204d5, 0x757655f82625, 0x7577b55248c3,
+    0x757864ff5a12, 0x757b5e42a513, 0x758bd67c447b, 0x75bb6ab0f48b,
+    0x75c49109db53, 0x75dd45604a6f, 0x75e3e793f1c0, 0x75ef5783123a,
+    0x75f4253e8b63, 0x75fc26cc5552, 0x7608bb8692a5, 0x7637a00e3166,
+    0x765646eb3684, 0x76794665a941, 0x7682a74d95ce, 0x76885f45260b,
+    0x769394a540c0, 0x76973d959d10, 0x76bb274c26e1, 0x76ce23ab05c0,
+    0x76ea0bd3c5a2, 0x76f78aca1a09, 0x77087821c4fb, 0x771be99eaf64,
+    0x776815a930a5, 0x776fdca7f4cf, 0x77ac0596e232, 0x77c9128abeed,
+    0x77db5f3c8c7d, 0x77dd33b4baa5, 0x77e485957345, 0x77f7bc8357e9,
+    0x780e9c6198dd, 0x7835f4797939, 0x7853eba96d08, 0x785b3d8a25a8,
+    0x78675d265771, 0x7873f1e094c4, 0x787c688c6a3d, 0x7895cc8feaa8,
+    0x7896f15b0781, 0x7897a10818d0, 0x78a6b9e7959a, 0x78c4016a781a,
+    0x78ca2e8013e1, 0x78cade2d2530, 0x78cb18bc2af5, 0x78f73e8f847a,
+    0x78fbd1bbf7de, 0x7910dd220aaa, 0x791d374d4238, 0x793fc1a9a96b,
+    0x795f52c2c59d, 0x797c25279c93, 0x798d127f4785, 0x79b4dfb5336b,
+    0x7a2c81e5fce2, 0x7a332419a433, 0x7a4aee33fc3b, 0x7a9d0cc5137e,
+    0x7ab302673d5e, 0x7ad3089e651a, 0x7ad9e5611230, 0x7aee7ba91972,
+    0x7af60818d7d7, 0x7b0bc32bfbf2, 0x7b114094866a, 0x7b13ff48cba6,
+    0x7b20940308f9, 0x7b2143b01a48, 0x7b2a2f79fb4b, 0x7b4c7f475cb9,
+    0x7b7571486578, 0x7b7b63cefb7a, 0x7b824091a890, 0x7b8a421f727f,
+    0x7b95ec9d98be, 0x7ba1d1aac4c2, 0x7bba860133de, 0x7bc7ca688280,
+    0x7be720f298ed, 0x7c0518228cbc, 0x7c0552b19281, 0x7c1b4853bc61,
+    0x7c3b8919e9e2, 0x7c3d5d92180a, 0x7c4474e3cae5, 0x7c452490dc34,
+    0x7c4599aee7be, 0x7c538dc347af, 0x7c54b28e6488, 0x7c6f75ec0791,
+    0x7c814d7fc997, 0x7c8c0dc1d8c2, 0x7c9daac69503, 0x7ca1c8d4fcdd,
+    0x7cbe60aace0e, 0x7cc2099b2a5e, 0x7cca45b7fa12, 0x7ced45326ccf,
+    0x7cf0ee22c91f, 0x7cf19dcfda6e, 0x7cf3e7661420, 0x7d3bbad327c2,
+    0x7d59777415cc, 0x7d71b6ac795e, 0x7d97af6a371c, 0x7dbf42111d3d,
+    0x7dcdab4388b8, 0x7dd746ba7b0a, 0x7df244a723d8, 0x7e12fa8b5ce3,
+    0x7e1b36a82c97, 0x7e250cae24ae, 0x7e39a2f62bf0, 0x7e4c9f550acf,
+    0x7e534188b220, 0x7e679d41b39d, 0x7e6bbb501b77, 0x7e885325eca8,
+    0x7e8902d2fdf7, 0x7e981bb27ac1, 0x7eb10a97efa2, 0x7eb22f630c7b,
+    0x7eb2a4811805, 0x7ed31fd64b4b, 0x7ed82820ca39, 0x7ee40d2df63d,
+    0x7ef918940909, 0x7f20361ce3a0, 0x7f253e67628e, 0x7f48b2ffe0d5,
+    0x7f7254adfae3, 0x7f79a68eb383, 0x7f87258507ea, 0x7fa6b69e241c,
+    0x7fb853a2e05d, 0x7fcb5001bf3c, 0x7fe59e4156bb, 0x7fe6fd9b7959,
+    0x7fecb5930996, 0x7ff825822a10, 0x8001866a169d, 0x801b5f8ba292,
+    0x802b62a73670, 0x802ff5d3a9d4, 0x803f0eb3269e, 0x8044518cab51,
+    0x804a44134153, 0x804fc17bcbcb, 0x807c5c6d30da, 0x807fcace8765,
+    0x808ac59f9c55, 0x80b9aa273b16, 0x80bd53179766, 0x80e5956b8ed6,
+    0x80f5d3162879, 0x8103520c7ce0, 0x811529a03ee6, 0x813c4729197d,
+    0x814a3b3d796e, 0x815b28952460, 0x816ba0cec3c8, 0x818f8a854d99,
+    0x8195b79ae960, 0x819abfe5684e, 0x819c1f3f8aec, 0x81a162190f9f,
+    0x81d75df2613b, 0x81d96cf99528, 0x81e1a91664dc, 0x821ebc416953,
+    0x821ef6d06f18, 0x824564ac3860, 0x82547d8bb52a, 0x8254b81abaef,
+    0x8264f5c55492, 0x826cf7531e81, 0x8273d415cb97, 0x829205d4c52b,
+    0x829cc616d456, 0x82ba4828bc9b, 0x82d78fab9f1b, 0x82de6c6e4c31,
+    0x830a57b29ff1, 0x830a9241a5b6, 0x830bb70cc28f, 0x8312594069e0,
+    0x8328fe8fa50f, 0x833ce52a9b02, 0x8342d7b13104, 0x83594271666e,
+    0x835bc696a5e5, 0x837da145fbc9, 0x83826f0174f2, 0x8389fb713357,
+    0x838d69d289e2, 0x8392e73b145a, 0x83a2afc7a273, 0x83a9c719554e,
+    0x83bcfe0739f2, 0x83c156a4a791, 0x83e9d387a4c6, 0x83eccccaefc7,
+    0x844b45873e98, 0x844fd8b3b1fc, 0x8459399b9e89, 0x8466b891f2f0,
+    0x8474aca652e1, 0x84755c536430, 0x847ce8c32295, 0x84843aa3db35,
+    0x84c1fd7bf0fb, 0x850fc36f9a9f, 0x8510731cabee, 0x85554d46748f,
+    0x855930c5d6a4, 0x85a05485d8f7, 0x85a85613a2e6, 0x85f4bcad29ec,
+    0x861b2a88f334, 0x862c8cfea9b0, 0x865c213359c0, 0x865f1a76a4c1,
+    0x867a52f25354, 0x867f5b3cd242, 0x868b05baf881, 0x86a5c9189b8a,
+    0x86abbb9f318c, 0x86dd244c0fc4, 0x86df335343b1, 0x86e1f20788ed,
+    0x86e4eb4ad3ee, 0x86e81f1d24b4, 0x871d30ba5f3c, 0x87339b7a94a6,
+    0x874d3a0d1ad6, 0x8765042772de, 0x87653eb678a3, 0x877c93b2c521,
+    0x8785ba0babe9, 0x87b49e934aaa, 0x87b7d2659b70, 0x87da5cc202a3,
+    0x87e3831ae96b, 0x87e7a1295145, 0x87f102113dd2, 0x87f4ab019a22,
+    0x87f978bd134b, 0x88031434059d, 0x881b8dfb6ef4, 0x882613ae785a,
+    0x882897d3b7d1, 0x882cb5e21fab, 0x8846c992b165, 0x889454f75544,
+    0x889b31ba025a, 0x889fff757b83, 0x88a0e9b19297, 0x88a925ce624b,
+    0x88ac1f11ad4c, 0x88e3ef632d10, 0x88f885ab3452, 0x892f6bc09d02,
+    0x894820170c1e, 0x89547a4243ac, 0x895e15b935fe, 0x897445ea65a3,
+    0x89918d6d4823, 0x89941192879a, 0x89aa41c3b73f, 0x89b32d8d9842,
+    0x89c87382b0d3, 0x89e71a5fb5f1, 0x8a22ce3097ca, 0x8a4bfac0a64e,
+    0x8a7efd56ace9, 0x8a952d87dc8e, 0x8a994b964468, 0x8aa187b3141c,
+    0x8aa7ef57b5a8, 0x8aa8d993ccbc, 0x8abfb9720db0, 0x8ac4fc4b9263,
+    0x8aea7feb4497, 0x8b43b5ce0eb5, 0x8b60c2c1eb70, 0x8b9cebb0d8d3,
+    0x8bb231a5f164, 0x8bd397373bbe, 0x8bd61b5c7b35, 0x8be7b8613776,
+    0x8becfb3abc29, 0x8bfb646d27a4, 0x8bfe23216ce0, 0x8c065f3e3c94,
+    0x8c2331a3138a, 0x8c274fb17b64, 0x8c51a10ca6c1, 0x8c5f952106b2,
+    0x8c7515a52508, 0x8c79e3609e31, 0x8cc6f9a73686, 0x8ccb5244a425,
+    0x8cd7e6fee178, 0x8ce05daab6f1, 0x8d17b8de2b2b, 0x8d1952c7538e,
+    0x8d1b61ce877b, 0x8d1cfbb7afde, 0x8d20a4a80c2e, 0x8d2fbd8788f8,
+    0x8d837601c89e, 0x8d8759812ab3, 0x8dacdd20dce7, 0x8db3f4728fc2,
+    0x8de438545121, 0x8dece98f2c5f, 0x8dfcb21bba78, 0x8e0737cec3de,
+    0x8e0a6ba114a4, 0x8e0e149170f4, 0x8e1098b6b06b, 0x8e12329fd8ce,
+    0x8e152be323cf, 0x8e185fb57495, 0x8e1b58f8bf96, 0x8e1da28ef948,
+    0x8e4b624b7b30, 0x8e53294a3f5a, 0x8e9370479497, 0x8e98037407fb,
+    0x8eabaf7ff829, 0x8ec797a8b80b, 0x8eead1b2308d, 0x8f0853c418d2,
+    0x8f105551e2c1, 0x8f36c32dac09, 0x8f3897a5da31, 0x8f3c40963681,
+    0x8f57b3a0ead9, 0x8f69c5c3b2a4, 0x8f6a7570c3f3, 0x8f8cffcd2b26,
+    0x8f957679009f, 0x8fa1d0a4382d, 0x8faaf6fd1ef5, 0x8fcb7252523b,
+    0x90179e5cd37c, 0x901fda79a330, 0x90313cef59ac, 0x9044e8fb49da,
+    0x904598a85b29, 0x90590a254592, 0x907bcf10b28a, 0x90873effd304,
+    0x9087798ed8c9, 0x90894e0706f1, 0x908c474a51f2, 0x90a0dd925934,
+    0x90a7ba55064a, 0x90af46c4c4af, 0x90bd004a1edb, 0x90cbde9a95e0,
+    0x90dabceb0ce5, 0x90f545b9aa29, 0x9107926b77b9, 0x910beb08e558,
+    0x9114d6d2c65b, 0x911845341ce6, 0x913049dd7ab3, 0x913b7f3d9568,
+    0x91404cf90e91, 0x91576766554a, 0x91776d9d7d06, 0x91838d39aecf,
+    0x91a5a2780a78, 0x91acb9c9bd53, 0x91b94e83faa6, 0x91cc4ae2d985,
+    0x91db9e515c14, 0x91f50254dc7f, 0x921458def2ec, 0x923842957cbd,
+    0x923dbffe0735, 0x9253f02f36da, 0x925accf1e3f0, 0x9266ec8e15b9,
+    0x9283bef2ecaf, 0x928901cc7162, 0x92a7a8a97680, 0x92b5279fcae7,
+    0x92dc7fb7ab43, 0x92ede22d61bf, 0x92f4f97f149a, 0x92fd359be44e,
+    0x931833888d1c, 0x93269cbaf897, 0x9348b1f95440, 0x93499c356b54,
+    0x935964c1f96d, 0x935a146f0abc, 0x936a5219a45f, 0x936d10cde99b,
+    0x93758779bf14, 0x9378f5db159f, 0x93e8967e1527, 0x94002609676a,
+    0x94086226371e, 0x9419ff2af35f, 0x941c835032d6, 0x943a05621b1b,
+    0x943b9f4b437e, 0x945c552f7c89, 0x9467ffada2c8, 0x9473350dbd7d,
+    0x947babb992f6, 0x949b0243a963, 0x94aee8de9f56, 0x94ea9caf812f,
+    0x95382814250e, 0x9542e8563439, 0x954706649c13, 0x9559531669a3,
+    0x95791ebe8b9a, 0x95a7190a1347, 0x95d8bc45f744, 0x96067c02792c,
+    0x96113c448857, 0x961bc1f791bd, 0x9630583f98ff, 0x968201b2a4b8,
+    0x968cc1f4b3e3, 0x96a8e4ac798a, 0x96d8b3702f5f, 0x96fc9d26b930,
+    0x970429967795, 0x971676484525, 0x9720c16c48c6, 0x972f652dba06,
+    0x9769f4337f06, 0x97738faa7158, 0x979b976f6303, 0x97c7bd42bc88,
+    0x97cf49b27aed, 0x97d4c71b0565, 0x97ebe1884c1e, 0x97f492c3275c,
+    0x97f876428971, 0x98187c79b12d, 0x982969d15c1f, 0x9853bb2c877c,
+    0x9854304a9306, 0x9872277a86d5, 0x987b134467d8, 0x98a47a637c21,
+    0x98bc09eece64, 0x98e202ac8c22, 0x98e695d8ff86, 0x990bdee9abf5,
+    0x99207531b337, 0x994d854123d0, 0x99a0537f4c62, 0x99b7a87b98e0,
+    0x99c477c4dbf8, 0x99d85e5fd1eb, 0x99e617e52c17, 0x99fb234b3ee3,
+    0x9a45b56c97c1, 0x9a5d0a68e43f, 0x9a687a5804b9, 0x9a6b739b4fba,
+    0x9a7549a147d1, 0x9a787d739897, 0x9a792d20a9e6, 0x9aa63d301a7f,
+    0x9aa761fb3758, 0x9aabf527aabc, 0x9ab605bca898, 0x9ac050e0ac39,
+    0x9ae14153eb09, 0x9b21fd6f4bd0, 0x9b3659284d4d, 0x9b3f0a63288b,
+    0x9b42ede28aa0, 0x9b4a7a524905, 0x9b5958a2c00a, 0x9b6f4e44e9ea,
+    0x9b783a0ecaed, 0x9b93728a7980, 0x9b94d1e49c1e, 0x9b96a65cca46,
+    0x9ba1dbbce4fb, 0x9bc00d7bde8f, 0x9bc3b66c3adf, 0x9bc6ea3e8ba5,
+    0x9bed1d8b4f28, 0x9c2687c5f74f, 0x9c39f942e1b8, 0x9c42e50cc2bb,
+    0x9c602c8fa53b, 0x9c7831390308, 0x9c7b9f9a5993, 0x9c853b114be5,
+    0x9c920a5a8efd, 0x9cc75686cf4a, 0x9ce219e47253, 0x9d2bfc58b9e2,
+    0x9d48599f854e, 0x9d85e1e8954f, 0x9d90dcb9aa3f, 0x9d9cfc55dc08,
+    0x9da9cb9f1f20, 0x9dc1209b6b9e, 0x9dc5b3c7df02, 0x9dd248821c55,
+    0x9dd3e26b44b8, 0x9dd6a11f89f4, 0x9df1647d2cfd, 0x9df8b65de59d,
+    0x9df9660af6ec, 0x9dff1e028729, 0x9e14296899f5, 0x9e25514f4aac,
+    0x9e3f2a70d6a1, 0x9e4174071053, 0x9e567f6d231f, 0x9e634eb66637,
+    0x9ebfb86b811b, 0x9ec23c90c092, 0x9edba09440fd, 0x9eddea2a7aaf,
+    0x9ee2b7e5f3d8, 0x9eff4fbbc509, 0x9f199dfb5c88, 0x9f35fb4227f4,
+    0x9f3c2857c3bb, 0x9f479846e435, 0x9f51a8dbe211, 0x9fb56471b595,
+    0x9fb8d2d30c20, 0x9fc94b0cab88, 0x9fd4bafbcc02, 0x9fdcf7189bb6,
+    0x9ffab3b989c0, 0xa062183fb994, 0xa06c28d4b770, 0xa0b5d0b9f93a,
+    0xa0c94236e3a3, 0xa0ccb0983a2e, 0xa0e390767b22, 0xa0efb012aceb,
+    0xa0fa7054bc16, 0xa110a085ebbb, 0xa122b2a8b386, 0xa13240a63bda,
+    0xa14aba6da531, 0xa14c19c7c7cf, 0xa1733750a266, 0xa191a39ea1bf,
+    0xa19c2951ab25, 0xa1e8555c2c66, 0xa1eb4e9f7767, 0xa20318b9cf6f,
+    0xa2047813f20d, 0xa21390f36ed7, 0xa216c4c5bf9d, 0xa21fb08fa0a0,
+    0xa22c0abad82e, 0xa230635845cd, 0xa2314d945ce1, 0xa2377aa9f8a8,
+    0xa2391493210b, 0xa24917aeb4e9, 0xa24a01eacbfd, 0xa26f858a7e31,
+    0xa27db42de3e7, 0xa28be2d1499d, 0xa2a2882084cc, 0xa2a3e77aa76a,
+    0xa2aee24bbc5a, 0xa2b66ebb7abf, 0xa2c9a5a95f63, 0xa2cc29ce9eda,
+    0xa2e1aa52bd30, 0xa2f7da83ecd5, 0xa30b4c00d73e, 0xa3310a2f8f37,
+    0xa33a308875ff, 0xa3431c525702, 0xa34a33a409dd, 0xa34c42ab3dca,
+    0xa34cb7c94954, 0xa39a432ded33, 0xa3bc586c48dc, 0xa3c802ea6f1b,
+    0xa3dc5ea37098, 0xa3e460313a87, 0xa3f9e0b558dd, 0xa40bb8491ae3,
+    0xa41762c74122, 0xa4179d5646e7, 0xa43051acb603, 0xa434aa4a23a2,
+    0xa43818ab7a2d, 0xa43b4c7dcaf3, 0xa4b154c56c07, 0xa4ca091bdb23,
+    0xa4cfc1136b60, 0xa4e0ae6b1652, 0xa4ebe3cb3107, 0xa4ed432553a5,
+    0xa5092b4e1387, 0xa539a9bedaab, 0xa54ad1a58b62, 0xa5755d8fbc84,
+    0xa5797b9e245e, 0xa58b18a2e09f, 0xa5a407885580, 0xa5a9fa0eeb82,
+    0xa5b40aa3e95e, 0xa5d2ec0ff441, 0xa5e0307742e3, 0xa5f626196cc3,
+    0xa606293500a1, 0xa622867bcc0d, 0xa62545301149, 0xa63b756140ee,
+    0xa6449bba27b6, 0xa6475a6e6cf2, 0xa649de93ac69, 0xa670c18d813b,
+    0xa697a487560d, 0xa6a439419360, 0xa6f1c4a6373f, 0xa70a3e6da096,
+    0xa71573cdbb4b, 0xa71e5f979c4e, 0xa724c73c3dda, 0xa725ec075ab3,
+    0xa72c8e3b0204, 0xa737c39b1cb9, 0xa74074d5f7f7, 0xa743a8a848bd,
+    0xa77ac94cb732, 0xa77dc2900233, 0xa790beeee112, 0xa7ab824c841b,
+    0xa7b67d1d990b, 0xa7b6f23ba495, 0xa7eaa47ebc7f, 0xa7ef37ab2fe3,
+    0xa817b48e2d18, 0xa8527e22f7dd, 0xa86664bdedd0, 0xa8bf2582ac64,
+    0xa8c518094266, 0xa8d8c4153294, 0xa8dd5741a5f8, 0xa8e46e9358d3,
+    0xa91895f47c47, 0xa91c3ee4d897, 0xa939fb85c6a1, 0xa951c5a01ea9,
+    0xa97240f551ef, 0xa97a7d1221a3, 0xa98786ea6a80, 0xa9b1d84595dd,
+    0xa9b7903d261a, 0xa9b87a793d2e, 0xa9c166431e31, 0xa9c66e8d9d1f,
+    0xa9dee8550676, 0xa9ea92d32cb5, 0xaa067afbec97, 0xaa113b3dfbc2,
+    0xaa25d1860304, 0xaa5cf22a7179, 0xaaa749bcc492, 0xaac4cbceacd7,
+    0xaad21035fb79, 0xaadedf7f3e91, 0xaaf92dbed610, 0xab196e850391,
+    0xab28124674d1, 0xab3d1dac879d, 0xab67a996b8bf, 0xabaaaf485338,
+    0xabc204449fb6, 0xabd241ef3959, 0xabd3a1495bf7, 0xabdd774f540e,
+    0xabf0391f2d28, 0xac0285d0fab8, 0xac0f1a8b380b, 0xac18f0913022,
+    0xac2376443988, 0xac2d4c4a319f, 0xac3fd38b04f4, 0xac46eadcb7cf,
+    0xac672ba2e550, 0xac7ce6b6096b, 0xac95607d72c2, 0xac9a68c7f1b0,
+    0xacb566b49a7e, 0xacc445051183, 0xacc47f941748, 0xace201a5ff8d,
+    0xad0b68c513d6, 0xad37c9277320, 0xad6847983a44, 0xad6d1553b36d,
+    0xadbf33e4cab0, 0xadcd62883066, 0xadd613c30ba4, 0xade4b7847ce4,
+    0xae17f4a98944, 0xae332d2537d7, 0xae3ab994f63c, 0xae3f4cc169a0,
+    0xae4d40d5c991, 0xae6a4dc9a64c, 0xae6b7294c325, 0xae96ae2c0596,
+    0xaea810a1bc12, 0xaeb38090dc8c, 0xaebd1c07cede, 0xaec97633066c,
+    0xaef8202b9f68, 0xaf01f631977f, 0xaf04b4e5dcbb, 0xaf3cbfc66244,
+    0xaf418d81db6d, 0xaf42029fe6f7, 0xaf4c4dc3ea98, 0xaf527ad9865f,
+    0xaf52b5688c24, 0xaf5623c9e2af, 0xaf74caa6e7cd, 0xaf857d6f8cfa,
+    0xafa2ff81753f, 0xafbac99bcd47, 0xafdc2f2d17a1, 0xb0197ce721dd,
+    0xb0431e953beb, 0xb06867a5e85a, 0xb0a246fe9c0b, 0xb0d66e5fbf7f,
+    0xb0deaa7c8f33, 0xb0f131bd6288, 0xb104ddc952b6, 0xb1079c7d97f2,
+    0xb174094e46b4, 0xb1a412a1024e, 0xb1d15d3f78ac, 0xb1df5153d89d,
+    0xb22b7d5e59de, 0xb2b6cb9b1383, 0xb2d537e912dc, 0xb2d6222529f0,
+    0xb30c588d8151, 0xb336e477b273, 0xb338f37ee660, 0xb343b3c0f58b,
+    0xb387691fa153, 0xb3929e7fbc08, 0xb3aa689a1410, 0xb404139ae9b8,
+    0xb40c1528b3a7, 0xb43123aa5a51, 0xb44001fad156, 0xb4d6fab5b13a,
+    0xb4f6c65dd331, 0xb519162b349f, 0xb51b2532688c, 0xb533d988d7a8,
+    0xb55fff5c312d, 0xb58beaa084ed, 0xb5bf27c5914d, 0xb5c43010103b,
+    0xb5d51d67bb2d, 0xb5eb4d98ead2, 0xb5f892003974, 0xb611bb74b41a,
+    0xb61ae1cd9ae2, 0xb61ec54cfcf7, 0xb62099c52b1f, 0xb6440e5da966,
+    0xb6753c7b81d9, 0xb6799518ef78, 0xb6c0b8d8f1cb, 0xb6c218331469,
+    0xb6d5c43f0497, 0xb6e76143c0d8, 0xb7025f3069a6, 0xb703be8a8c44,
+    0xb704a8c6a358, 0xb70c6fc56782, 0xb70fde26be0d, 0xb719eebbbbe9,
+    0xb7229ff69727, 0xb72e8503c32b, 0xb736c12092df, 0xb74689ad20f8,
+    0xb74b1cd9945c, 0xb77bd5d96145, 0xb78cc3310c37, 0xb7a627348ca2,
+    0xb7bbe247b0bd, 0xb7dcd2baef8d, 0xb7e215947440, 0xb7e6e34fed69,
+    0xb7e8081b0a42, 0xb7f587115ea9, 0xb7fc9e631184, 0xb80d512bb6b1,
+    0xb81a95930553, 0xb81f28bf78b7, 0xb83767f7dc49, 0xb853c53ea7b5,
+    0xb86b8f58ffbd, 0xb883597357c5, 0xb88fb39e8f53, 0xb89028bc9add,
+    0xb894815a087c, 0xb8a4f993a7e4, 0xb8ac10e55abf, 0xb8c7be7f14dc,
+    0xb916341fcfcf, 0xb96ad6d62689, 0xb9755c892fef, 0xb97b4f0fc5f1,
+    0xb9876eabf7ba, 0xb99d644e219a, 0xb9d86871f224, 0xb9fa7db04dcd,
+    0xba01cf91066d, 0xba16daf71939, 0xba3baee9ba1e, 0xba804e847cfa,
+    0xba90c6be1c62, 0xba9260a744c5, 0xbb35ee1c61fc, 0xbb59d7d2ebcd,
+    0xbb645d85f533, 0xbb8dff340f41, 0xbb9a595f46cf, 0xbbc88e39d441,
+    0xbbc93de6e590, 0xbbe7aa34e4e9, 0xbc010e386554, 0xbc2de3b8d028,
+    0xbc34c07b7d3e, 0xbc6c1baef178, 0xbc79d5344ba4, 0xbca92ed9f5ef,
+    0xbcb25532dcb7, 0xbcbbf0a9cf09, 0xbcfc37a72446, 0xbd2f74cc30a6,
+    0xbd61c7b525f2, 0xbd91215ad03d, 0xbd953f693817, 0xbdd33cd053a2,
+    0xbde3055ce1bb, 0xbe04e00c379f, 0xbe0938a9a53e, 0xbe0bf75dea7a,
+    0xbe104ffb5819, 0xbe1d1f449b31, 0xbe2311cb3133, 0xbe326539b3c2,
+    0xbe360e2a1012, 0xbe38ccde554e, 0xbed667ccdc83, 0xbf38145b7c1a,
+    0xbf38897987a4, 0xbf3ce216f543, 0xbf581a92a3d6, 0xbf5904cebaea,
+    0xbf74b2687507, 0xbf790b05e2a6, 0xbf885e746535, 0xbf92a99868d6,
+    0xbfa4bbbb30a1, 0xbfa655a45904, 0xc0119da9eaed, 0xc022c5909ba4,
+    0xc0349d245daa, 0xc0463a2919eb, 0xc07bfb7365c2, 0xc089b4f8bfee,
+    0xc08b8970ee16, 0xc091f1158fa2, 0xc0a94611dc20, 0xc0ab8fa815d2,
+    0xc0b47b71f6d5, 0xc0c4096f7f29, 0xc14a4f61b9e0, 0xc156a98cf16e,
+    0xc156e41bf733, 0xc17c2d2ca3a2, 0xc1804b3b0b7c, 0xc1858e14902f,
+    0xc1afdf6fbb8c, 0xc1c7a98a1394, 0xc1e05de082b0, 0xc1e182ab9f89,
+    0xc1e7afc13b50, 0xc20482261246, 0xc2386ef82ff5, 0xc239ce525293,
+    0xc24d3fcf3cfc, 0xc2595f6b6ec5, 0xc25edcd3f93d, 0xc265f425ac18,
+    0xc2b2954e38e3, 0xc2cc6e6fc4d8, 0xc2d51faaa016, 0xc309f6b8d4d9,
+    0xc30ae0f4ebed, 0xc31ddd53cacc, 0xc3439b8282c5, 0xc3d84aa728f7,
+    0xc3e1e61e1b49, 0xc4326ac60a29, 0xc43ae171dfa2, 0xc45de0ec525f,
+    0xc4f9a762ab6c, 0xc5255817f967, 0xc52ce487b7cc, 0xc531ecd236ba,
+    0xc53ce7a34baa, 0xc552a2b66fc5, 0xc55944ea1716, 0xc55d62f87ef0,
+    0xc5b539812670, 0xc5daf7afde69, 0xc5ef18d9da21, 0xc6066dd6269f,
+    0xc61ead0e8a31, 0xc61f974aa145, 0xc639aafb32ff, 0xc6530efeb36a,
+    0xc67b5152aada, 0xc67ebfb40165, 0xc6cd3554bc58, 0xc70b6d4adda8,
+    0xc7684c1e0416, 0xc78fdec4ea37, 0xc7bb8f7a3832, 0xc7d9fbc8378b,
+    0xc7eaae90dcb8, 0xc808e04fd64c, 0xc8215a173fa3, 0xc8315d32d381,
+    0xc85cd3591bb7, 0xc85fcc9c66b8, 0xc86ac76d7ba8, 0xc89e79b09392,
+    0xc8dfaae9ffe3, 0xc944c5d9f605, 0xc9a9311cdad8, 0xc9b9a9567a40,
+    0xc9ea27c74164, 0xc9eb12035878, 0xc9ff332d5430, 0xca8f89b48cc3,
+    0xca96667739d9, 0xca99252b7f15, 0xcb1c71da6ecb, 0xcb459e6a7d4f,
+    0xcb609c57261d, 0xcb66544eb65a, 0xcb8fbb6dcaa3, 0xcb906b1adbf2,
+    0xcb9f83fa58bc, 0xcbca0fe489de, 0xcc08f787bc7d, 0xcc184af63f0c,
+    0xcc2ef0457a3b, 0xcc388bbc6c8d, 0xcc53fec720e5, 0xcc64b18fc612,
+    0xcc6a2ef8508a, 0xcc6cb31d9001, 0xcc74ef3a5fb5, 0xcc76fe4193a2,
+    0xcc961a3ca44a, 0xcca40e51043b, 0xcd2b03f05041, 0xcd584e8ec69f,
+    0xcd8aa177bbeb, 0xce5c63c76694, 0xce88feb8cba3, 0xce988cb653f7,
+    0xcee777751a74, 0xcef5e0a785ef, 0xcf082d59537f, 0xcf1a7a0b210f,
+    0xcf24ffbe2a75, 0xcf2699a752d8, 0xcf2e9b351cc7, 0xcf4714fc861e,
+    0xcf833deb7381, 0xcf95157f3587, 0xcf9cdc7df9b1, 0xcfba5e8fe1f6,
+    0xcfeadd00a91a, 0xd000d2a2d2fa, 0xd006ffb86ec1, 0xd024473b5141,
+    0xd06be0195f1e, 0xd09631748a7b, 0xd0a54a540745, 0xd0a968626f1f,
+    0xd0ad8670d6f9, 0xd0c8f97b8b51, 0xd10a6543fd67, 0xd1739e425b63,
+    0xd1a666495c39, 0xd1ac1e40ec76, 0xd1f29253dd7a, 0xd20136154eba,
+    0xd204a476a545, 0xd20887f6075a, 0xd2326433272d, 0xd25bcb523b76,
+    0xd2afbe5b80e1, 0xd2fc24f507e7, 0xd322cd5fd6f4, 0xd3722d3ca8fb,
+    0xd37d280dbdeb, 0xd3838fb25f77, 0xd3a2e63c75e4, 0xd417c9b8fa1f,
+    0xd466b477c09c, 0xd47fddec3b42, 0xd4f4fbf7c542, 0xd548b47204e8,
+    0xd57eeada5c49, 0xd588fb6f5a25, 0xd6182d2b75df, 0xd668ec626a84,
+    0xd6ba95d5763d, 0xd7a004311114, 0xd7cc9f227623, 0xd86531c67e6a,
+    0xd8f72236df60, 0xd96f3985b461, 0xd9b7f72edf17, 0xdad98e796751,
+    0xdba264ff30f7, 0xdbc4ef5b982a, 0xdd3c13988e32, 0xdd53ddb2e63a,
+    0xdd5c545ebbb3, 0xdd7a861db547, 0xde7deba943ed, 0xde9197b5341b,
+    0xdeff9e6f0b40, 0xdf2a2a593c62, 0xdf3a2d74d040, 0xdf6d6a99dca0,
+    0xdfbc5558a31d, 0xdfc4cc047896, 0xdfff207b37d1, 0xe0f5b6bd835f,
+    0xe1584d883a0a, 0xe2b3c42b75f5, 0xe2fc0cb69521, 0xe309c63bef4d,
+    0xe38c9dccd379, 0xe482f9801942, 0xe4c290d05d30, 0xe4c3f02a7fce,
+    0xe52aa5039e53, 0xe579ca516a95, 0xe5b5b8b15233, 0xe5be2f5d27ac,
+    0xe6004ad2ab11, 0xe6cd04d7d6cc, 0xe70c619914f5, 0xe71587f1fbbd,
+    0xe73f29a015cb, 0xe75d20d0099a, 0xe775d52678b6, 0xe788d1855795,
+    0xe78e897ce7d2, 0xe823e84e9f53, 0xe83ee63b4821, 0xe8433ed8b5c0,
+    0xe889785ca0ff, 0xe894adbcbbb4, 0xe8a092c9e7b8, 0xe8a610327230,
+    0xe904fe0ccc8b, 0xe90c8a7c8af0, 0xea20dd5fc488, 0xeaaf5f6ecef3,
+    0xeb7ffcf35cc3, 0xeb8281189c3a, 0xeb8839102c77, 0xebdbb6fb6658,
+    0xebf84ed13789, 0xec117845b22f, 0xec13c1dbebe1, 0xecb6da32fd8e,
+    0xecfeada01130, 0xed4103a49a5a, 0xed9b23c37b8c, 0xed9f7c60e92b,
+    0xee5ca8688c92, 0xee6d5b3131bf, 0xeea6c56bd9e6, 0xef1b33ca5297,
+    0xef75c9073f53, 0xefab8a518b2a, 0xefc2a4bed1e3, 0xf00b27d8f6d4,
+    0xf07794a9a596, 0xf0880ce344fe, 0xf0c644d9664e, 0xf0def92fd56a,
+    0xf13a78a8d93a, 0xf16454e5f90d, 0xf1d2962ed5f7, 0xf23fb2ac9608,
+    0xf24ae80cb0bd, 0xf24f40aa1e5c, 0xf26919cbaa51, 0xf28af47b0035,
+    0xf2aa85941c67, 0xf2ac5a0c4a8f, 0xf2b754dd5f7f, 0xf2ebf15c8e7d,
+    0xf2f3b85b52a7, 0xf2fd19433f34, 0xf3338a3a9c5a, 0xf35bcc8e93ca,
+    0xf384f91ea24e, 0xf3bfc2b36d13, 0xf3c1d1baa100, 0xf3cccc8bb5f0,
+    0xf3ea140e9870, 0xf3ec5da4d222, 0xf40c294cf419, 0xf435906c0862,
+    0xf4a2725ac2ae, 0xf4a61b4b1efe, 0xf4a740163bd7, 0xf4b200584b02,
+    0xf4b4bf0c903e, 0xf4de262ba487, 0xf502bf8f3fa7, 0xf5083cf7ca1f,
+    0xf52f1ff19ef1, 0xf535c2254642, 0xf584725506fa, 0xf5a2a414008e,
+    0xf5ba339f52d1, 0xf5ba6e2e5896, 0xf5c1fa9e16fb, 0xf5cf79946b62,
+    0xf5d23848b09e, 0xf5dff1ce0aca, 0xf5ec116a3c93, 0xf5fa057e9c84,
+    0xf605affcc2c3, 0xf638b292c95e, 0xf640b420934d, 0xf6654d842e6d,
+    0xf689373ab83e, 0xf6abfc262536, 0xf6c9091a01f1, 0xf6d2a490f443,
+    0xf722eea9dd5e, 0xf755f13fe3f9, 0xf766de978eeb, 0xf7771c42288e,
+    0xf7900b279d6f, 0xf7a2ccf77689, 0xf7a675e7d2d9, 0xf7a79ab2efb2,
+    0xf7a9a9ba239f, 0xf7f560a69956, 0xf8147ca1a9fe, 0xf8c5fe2b2726,
+    0xf91473cbe219, 0xf924ec058181, 0xf96239bf8bbd, 0xf9a789075fe8,
+    0xf9a838b47137, 0xf9e7d004b525, 0xf9fc2bbdb6a2, 0xfa31b278fcb4,
+    0xfa9c10427789, 0xfab6991114cd, 0xfac2438f3b0c, 0xfacd3e604ffc,
+    0xfadea0d60678, 0xfb02ffaa9bd3, 0xfb1bb4010aef, 0xfb2290c3b805,
+    0xfb5ce53a7740, 0xfb74e9e3d50d, 0xfbb6caca52ad, 0xfbcc10bf6b3e,
+    0xfbd5ac365d90, 0xfbfcc9bf3827, 0xfc45124a5753, 0xfc5256b1a5f5,
+    0xfc580ea93632, 0xfc5f9b18f497, 0xfc62ceeb455d, 0xfc6bf5442c25,
+    0xfc86f330d4f3, 0xfca6f967fcaf, 0xfcb85bddb32b, 0xfcc022dc7755,
+    0xfcc1f754a57d, 0xfd0a7a6eca6e, 0xfd136638ab71, 0xfd350658fb90,
+    0xfd4d45915f22, 0xfd74631a39b9, 0xfda8ff9968b7, 0xfdccaec0ecc3,
+    0xfe0185cf2186, 0xfe06538a9aaf, 0xfe66a0bf17a8, 0xfe81298db4ec,
+    0xfea6380f5b96, 0xfeba5939574e, 0xfebe3cb8b963, 0xfee101a4265b,
+    0xff91d3809234, 0xff9cce51a724, 0xffb5bd371c05, 0x0026481632a1,
+    0x00afc1dabe1e, 0x01597c65771c, 0x015c75a8c21d, 0x0175d9ac4288,
+    0x0189c047387b, 0x01e5b4de47d5, 0x023932c981b6, 0x02396d58877b,
+    0x0281f072ac6c, 0x028efa4af549, 0x02f240c2bd43, 0x0317c4626f77,
+    0x0324591cacca, 0x034b3c16819c, 0x0354626f6864, 0x039bfb4d7641,
+    0x03ab4ebbf8d0, 0x03c2de474b13, 0x03d9f8b491cc, 0x045d45638182,
+    0x0462c2cc0bfa, 0x046c98d20411, 0x04854d28732d, 0x048bb4cd14b9,
+    0x04a9716e02c3, 0x04b67b464ba0, 0x04ce7fefa96d, 0x04f31953448d,
+    0x054a059fd4f9, 0x0551cc9e9923, 0x05b254621be1, 0x060472f33324,
+    0x0614760ec702, 0x065d6e46f77d, 0x0674139632ac, 0x06c5bd093e65,
+    0x06e9a6bfc836, 0x077ae78317dd, 0x07898b44891d, 0x07b9cf264a7c,
+    0x07f582f72c55, 0x082a949466dd, 0x082ca39b9aca, 0x08488bc45aac,
+    0x085a9de72277, 0x08a9fdc3f47e, 0x08c152c040fc, 0x08fb3218f4ad,
+    0x09a90ab21585, 0x09b52a4e474e, 0x09da38cfedf8, 0x09f78052d078,
+    0x0a0b2c5ec0a6, 0x0a0c8bb8e344, 0x0ae8d3bb9753, 0x0afa36314dcf,
+    0x0b04bbe45735, 0x0b650918d42e, 0x0ba8be777ff6, 0x0bb4de13b1bf,
+    0x0bbbbad65ed5, 0x0bdc70ba97e0, 0x0c1f014e26cf, 0x0c4275e6a516,
+    0x0c5412eb6157, 0x0c77fca1eb28, 0x0c80734dc0a1, 0x0cb42590d88b,
+    0x0cdbb837beac, 0x0ce63deac812, 0x0d00519b59cc, 0x0d0b4c6c6ebc,
+    0x0d6e1dc62b2c, 0x0d73d5bdbb69, 0x0d93dbf4e325, 0x0da7c28fd918,
+    0x0e2a5f91b77f, 0x0e3c71b47f4a, 0x0e9f430e3bba, 0x0f112d4774f4,
+    0x0f1e71aec396, 0x0f3c2e4fb1a0, 0x0f479e3ed21a, 0x0f9180b319a9,
+    0x0fa9103e6bec, 0x0fbb5cf0397c, 0x0fd570a0cb36, 0x0fdb9db666fd,
+    0x1067d62f37b6, 0x10c9482ed188, 0x10da35867c7a, 0x10e73f5ec557,
+    0x12419136e469, 0x12c811b824e5, 0x12d4e10167fd, 0x12ec35fdb47b,
+    0x12efa45f0b06, 0x12fcae3753e3, 0x1311447f5b25, 0x132b92bef2a4,
+    0x136069cd2767, 0x136dae347609, 0x13732b9d0081, 0x13b1639321d1,
+    0x13e849a88a81, 0x14b62878d315, 0x14cc58aa02ba, 0x14d6a3ce065b,
+    0x150b7adc3b1e, 0x1525c91bd29d, 0x155522c17ce8, 0x1579bc251808,
+    0x15c06ac70ed1, 0x15c39e995f97, 0x15f24891f893, 0x164acec7b162,
+    0x165a223633f1, 0x165d560884b7, 0x16838955483a, 0x1688919fc728,
+    0x1697e50e49b7, 0x170785b1493f, 0x170d3da8d97c, 0x1743aea036a2,
+    0x176a919a0b74, 0x177b4462b0a1, 0x17e4b7f01462, 0x17ec445fd2c7,
+    0x181ab3c965fe, 0x183208c5b27c, 0x185d445cf4ed, 0x18e5d3e56956,
+    0x18e8580aa8cd, 0x1915681a1966, 0x1940de40619c, 0x1963689cc8cf,
+    0x19a1db21efe4, 0x19b1a3ae7dfd, 0x19b6e68802b0, 0x19bcd90e98b2,
+    0x19ca5804ed19, 0x1a718e6a66a0, 0x1b2ef5010fcc, 0x1b4350ba1149,
+    0x1b4efb383788, 0x1b690ee8c942, 0x1bea4c90850b, 0x1bebabeaa7a9,
+    0x1c00b750ba75, 0x1c2257710a94, 0x1c75d55c4475, 0x1c910dd7f308,
+    0x1cab96a6904c, 0x1cc709b144a4, 0x1cd53854aa5a, 0x1ce5eb1d4f87,
+    0x1d307d3ea865, 0x1d5a940acdfd, 0x1d7173e90ef1, 0x1d7641a4881a,
+    0x1dc31d5c1aaa, 0x1dd94d8d4a4f, 0x1e0a7bab22c2, 0x1e5f1e61797c,
+    0x1e7eea099b73, 0x1e8b09a5cd3c, 0x1e8f27b43516, 0x1eea6c9e3321,
+    0x1f18dc07c658, 0x1f253632fde6, 0x1f55b4a3c50a, 0x1fbc697ce38f,
+    0x1fce7b9fab5a, 0x1fe645ba0362, 0x20185e13f2e9, 0x20231e560214,
+    0x20314cf967ca, 0x204b9b38ff49, 0x2062b5a64602, 0x2098ec0e9d63,
+    0x20c75b78309a, 0x210fde92558b, 0x21a41898f033, 0x22276547dfe9,
+    0x224387ffa590, 0x228cbac6dbd0, 0x22b2ee139f53, 0x22bbd9dd8056,
+    0x230cd3a37ac0, 0x2366098644de, 0x23943e60d250, 0x23aec72f6f94,
+    0x23cc83d05d9e, 0x23fdec7d3bd6, 0x243b74c64bd7, 0x2469e42fdf0e,
+    0x2489ea6706ca, 0x24caa6826791, 0x24e8d8416125, 0x254a4a40faf7,
+    0x25cd96efeaad, 0x264364a885fc, 0x266c91389480, 0x26cb4483e916,
+    0x26cf9d2156b5, 0x26f520c108e9, 0x270b166332c9, 0x27c8426ad630,
+    0x2803f63bb809, 0x2846c15e4cbd, 0x28564f5bd511, 0x28da8646dbdb,
+    0x292e3ec11b81, 0x29384f56195d, 0x29d4c57983b9, 0x29e1cf51cc96,
+    0x29e921328536, 0x29ea808ca7d4, 0x2a1337feaace, 0x2a44a0ab8906,
+    0x2a546938171f, 0x2a9e863b6473, 0x2ab94999077c, 0x2ab9f94618cb,
+    0x2ad35d499936, 0x2ae659a87815, 0x2b127f7bd19a, 0x2b4c24457f86,
+    0x2b55fa4b779d, 0x2b9606b9c715, 0x2ba6b9826c42, 0x2beb1e8e2959,
+    0x2c24c357d745, 0x2c2956844aa9, 0x2c45041e04c6, 0x2c48727f5b51,
+    0x2c5750cfd256, 0x2c5f17ce9680, 0x2c742334a94c, 0x2c78b6611cb0,
+    0x2ce5984fd6fc, 0x2ceadb295baf, 0x2d0d6585c2e2, 0x2d191003e921,
+    0x2d25a4be2674, 0x2d5d3a80a073, 0x2dd4dcb169ea, 0x2e23525224dd,
+    0x2e54f58e08da, 0x2e8489c2b8ea, 0x2ea7fe5b3731, 0x2f2bfab73836,
+    0x2f44e99cad17, 0x2f6a32ad5986, 0x2f75680d743b, 0x2fc74c0f85b9,
+    0x2fe57dce7f4d, 0x303b0ac0ed1b, 0x30cd35c053d6, 0x313250b049f8,
+    0x316e04812bd1, 0x319dd344e1a6, 0x31b4b323229a, 0x31e2ad6eaa47,
+    0x31f5a9cd8926, 0x321af2de3595, 0x324cd0a91f57, 0x326c61c23b89,
+    0x32942ef8276f, 0x32b9b297d9a3, 0x32d76f38c7ad, 0x332918abd366,
+    0x33548ed21b9c, 0x33887ba4394b, 0x33c0fba2ca5e, 0x349a0fd32da7,
+    0x34f011e3a6ff, 0x3509b0762d2f, 0x357fb8bdce43, 0x35811817f0e1,
+    0x35bc56cac730, 0x35ca1050215c, 0x35f2c7c22456, 0x365c00c08252,
+    0x369c0d2ed1ca, 0x369e56c50b7c, 0x36ae946fa51f, 0x36e5efa31959,
+    0x36fac07a2660, 0x3706306946da, 0x3722189206bc, 0x374d199a4368,
+    0x376a268e2023, 0x376a9bac2bad, 0x37cecc600abb, 0x37d2ea6e7295,
+    0x37e1c8bee99a, 0x382334875bb0, 0x38658a8be4da, 0x38843168e9f8,
+    0x38d5dadbf5b1, 0x3922b6938841, 0x393244911095, 0x397b3cc94110,
+    0x399d52079cb9, 0x39c8530fd965, 0x39f85c6294ff, 0x3a21fe10af0d,
+    0x3a5ffb77ca98, 0x3a6454153837, 0x3a6ffe935e76, 0x3b92baa90389,
+    0x3b9ba672e48c, 0x3babe41d7e2f, 0x3bd167bd3063, 0x3bdc27ff3f8e,
+    0x3be75d5f5a43, 0x3be797ee6008, 0x3c65a1c3cb0b, 0x3c95e5a58c6a,
+    0x3cb74b36d6c4, 0x3cbfc1e2ac3d, 0x3cf8f18e4e9f, 0x3d31e6aaeb3c,
+    0x3d35ca2a4d51, 0x3d39388ba3dc, 0x3d5ef6ba5bd5, 0x3d67a7f53713,
+    0x3d7f720f8f1b, 0x3da1122fdf3a, 0x3dcb638b0a97, 0x3e0b356a544a,
+    0x3e2792b11fb6, 0x3e6764906969, 0x3e7c35677670, 0x3e93ff81ce78,
+    0x3eb31b7cdf20, 0x3ec1f9cd5625, 0x3ed112acd2ef, 0x3ed396d21266,
+    0x3f0b2c948c65, 0x3f0cc67db4c8, 0x3f17c14ec9b8, 0x3f18ab8ae0cc,
+    0x3f2c9225d6bf, 0x3f4d0d7b0a05, 0x3f93818dfb09, 0x3fb521ae4b28,
+    0x3fcbc6fd8657, 0x3fdfad987c4a, 0x3fe39117de5f, 0x3fe5daae1811,
+    0x403c51dc9cf3, 0x404c1a692b0c, 0x4057ff765710, 0x409b3fb6f74e,
+    0x40bbbb0c2a94, 0x40d0c6723d60, 0x411775143429, 0x4119f93973a0,
+    0x41377b4b5be5, 0x415f488147cb, 0x419afc5229a4, 0x41d16d4986ca,
+    0x41d1e2679254, 0x41e7d809bc34, 0x4238d1cfb69e, 0x426029e796fa,
+    0x4268db227238, 0x42774454ddb3, 0x4293670ca35a, 0x42953b84d182,
+    0x42ae64f94c28, 0x431a973af525, 0x433bfccc3f7f, 0x43547693a8d6,
+    0x4356c029e288, 0x43789ad9386c, 0x43ac128d4a91, 0x43cd3d8f8f26,
+    0x4438fab32c99, 0x445607a70954, 0x445ef370ea57, 0x4466ba6fae81,
+    0x446dd1c1615c, 0x4499f794bae1, 0x44d23d04462f, 0x44e65e2e41e7,
+    0x4557d3496f97, 0x4576b4b57a7a, 0x458b858c8781, 0x45b2a3156218,
+    0x45ce8b3e21fa, 0x45e1fcbb0c63, 0x45fcfaa7b531, 0x462452bf958d,
+    0x4646dd1bfcc0, 0x46780b39d533, 0x467bb42a3183, 0x46abf80bf2e2,
+    0x46bad65c69e7, 0x46d4ea0cfba1, 0x46f899347fad, 0x471b98aef26a,
+    0x4769d3c0a798, 0x478c238e0906, 0x47a80bb6c8e8, 0x47b8be7f6e15,
+    0x47d72acd6d6e, 0x47e434a5b64b, 0x47e818251860, 0x483a71453568,
+    0x48494f95ac6d, 0x485693fcfb0f, 0x485ff4e4e79c, 0x48bee2bf41f7,
+    0x48c4d545d7f9, 0x48d28ecb3225, 0x48d3b3964efe, 0x48d79715b113,
+    0x49051c432d36, 0x493cb205a735, 0x495775634a3e, 0x4958d4bd6cdc,
+    0x4994fdac5a3f, 0x49b2ba4d4849, 0x49c666593877, 0x49ddbb5584f5,
+    0x4a028f4825da, 0x4a0e745551de, 0x4a2d1b3256fc, 0x4a38509271b1,
+    0x4a5b157ddea9, 0x4a68cf0338d5, 0x4aaff2c33b28, 0x4ab4c07eb451,
+    0x4ab953ab27b5, 0x4ad7856a2149, 0x4ade279dc89a, 0x4b1423771a36,
+    0x4b23b174a28a, 0x4b3c2b3c0be1, 0x4b437d1cc481, 0x4b84ae5630d2,
+    0x4bdcbf6dde17, 0x4c2be4bbaa59, 0x4c32fc0d5d34, 0x4c4a8b98af77,
+    0x4c575ae1f28f, 0x4ca9ee91155c, 0x4cecb9b3aa10, 0x4cff40f47d65,
+    0x4d27bdd77a9a, 0x4d32f337954f, 0x4d49d315d643, 0x4d7a51869d67,
+    0x4d8b044f4294, 0x4d8c63a96532, 0x4d9ca153fed5, 0x4dd10344280e,
+    0x4e13ce66bcc2, 0x4e4ff755aa25, 0x4e62f3b48904, 0x4e7839a9a195,
+    0x4e9bae421fdc, 0x4ed5187cc803, 0x4ee4315c44cd, 0x4f11416bb566,
+    0x4f54f6ca612e, 0x4fb8ecef3a77, 0x4fc21348213f, 0x4fccd38a306a,
+    0x4fe6acabbc5f, 0x4fe84694e4c2, 0x4ff96e7b9579, 0x4ffd51faf78e,
+    0x500ca5697a1d, 0x502c71119c14, 0x50431660d743, 0x50585c55efd4,
+    0x50747f0db57b, 0x508865a8ab6e, 0x50894fe4c282, 0x50a4c2ef76da,
+    0x50e285c78ca0, 0x5102c68dba21, 0x51054ab2f998, 0x5168569bbbcd,
+    0x51b11444e683, 0x51be58ac3525, 0x51fc90a25675, 0x5221d9b302e4,
+    0x52a64b2d0f73, 0x52b5640c8c3d, 0x52b897dedd03, 0x52cec8100ca8,
+    0x52e14f50dffd, 0x52fed162c842, 0x5303648f3ba6, 0x531660ee1a85,
+    0x53879b7a4270, 0x53e64ec59706, 0x5442f309b7af, 0x5444c781e5d7,
+    0x544f4d34ef3d, 0x545b6cd12106, 0x5473ac098498, 0x54912e1b6cdd,
+    0x54a5c463741f, 0x54b4dd42f0e9, 0x551823bab8e3, 0x557ed893d768,
+    0x55b842ce7f8f, 0x55ca8f804d1f, 0x55dca1a314ea, 0x5628cdad962b,
+    0x56ba838ef15c, 0x56d8b54deaf0, 0x56f33e1c8834, 0x572431ab5ae2,
+    0x57284fb9c2bc, 0x576f7379c50f, 0x577cf2701976, 0x57b69739c762,
+    0x57dd051590aa, 0x582f98c4b377, 0x58396ecaab8e, 0x5849ac754531,
+    0x58838bcdf8e2, 0x589ec449a775, 0x58d709b932c3, 0x58f4513c1543,
+    0x58fc183ad96d, 0x5910ae82e0af, 0x593f587b79ab, 0x5953b4347b28,
+    0x597b46db6149, 0x59c31a4874eb, 0x59dc43bcef91, 0x59fcbf1222d7,
+    0x5a30e673464b, 0x5a8b7bb03307, 0x5aae409b9fff, 0x5ab88bbfa3a0,
+    0x5ac34c01b2cb, 0x5b3c4d8c9ee0, 0x5ba87fce47dd, 0x5c18d01e58b4,
+    0x5c2146ca2e2d, 0x5c38613774e6, 0x5cd54c78eacc, 0x5cd8f569471c,
+    0x5cfbba54b414, 0x5d188cb98b0a, 0x5d789f5f023e, 0x5d8c4b6af26c,
+    0x5dc87459dfcf, 0x5dd802576823, 0x5dec238163db, 0x5e3556489a1b,
+    0x5e7144a881b9, 0x5e84b6256c22, 0x5e9777f5453c, 0x5ec0df145985,
+    0x5ee9d1156244, 0x5eeed95fe132, 0x5ef0e867151f, 0x5ef874d6d384,
+    0x5f0d803ce650, 0x5f1581cab03f, 0x5f18f02c06ca, 0x5f28090b8394,
+    0x5f5553a9f9f2, 0x5f73fa86ff10, 0x5fab9049790f, 0x5fb949ced33b,
+    0x603ea584f6de, 0x604a8a9222e2, 0x6053765c03e5, 0x606ee966b83d,
+    0x607bb8affb55, 0x607c2dce06df, 0x6081e5c5971c, 0x60901468fcd2,
+    0x609bbee72311, 0x60dd653e9aec, 0x60f94d675ace, 0x614b31696c4c,
+    0x614ccb5294af, 0x61599a9bd7c7, 0x617b3abc27e6, 0x6182177ed4fc,
+    0x61a4674c366a, 0x61c4e2a169b0, 0x61cbbf6416c6, 0x61e264b351f5,
+    0x62306536015e, 0x6274ca41be75, 0x62b0f330abd8, 0x63b6a2527430,
+    0x63b751ff857f, 0x63f37aee72e2, 0x6405528234e8, 0x642b85cef86b,
+    0x644091350b37, 0x64522e39c778, 0x646d66b5760b, 0x647861868afb,
+    0x648b5de569da, 0x64c4187300b2, 0x651beefba832, 0x65479fb0f62d,
+    0x654814cf01b7, 0x666f9ea01ff3, 0x6679e9c42394, 0x668cab93fcae,
+    0x66b896d8506e, 0x67127c682bdb, 0x6724c919f96b, 0x673ccdc35738,
+    0x6758b5ec171a, 0x6782ccb83cb2, 0x67958e8815cc, 0x67a3480d6ff8,
+    0x67a72b8cd20d, 0x6809c25788b8, 0x6883ae1e8be1, 0x6885bd25bfce,
+    0x6892c6fe08ab, 0x68b76061a3cb, 0x68c5547603bc, 0x68e72f2559a0,
+    0x690f36ea4b4b, 0x69389e095f94, 0x6947b6e8dc5e, 0x6976d5ff80e4,
+    0x69c75aa76fc4, 0x69f25bafac70, 0x69fbbc9798fd, 0x6a03be2562ec,
+    0x6a61fc52abf8, 0x6a6e910ce94b, 0x6a76cd29b8ff, 0x6aaaf48adc73,
+    0x6ac2498728f1, 0x6b12ce2f17d1, 0x6b1cdec415ad, 0x6b30ffee1165,
+    0x6b534fbb72d3, 0x6be36bb3a5a1, 0x6c07ca883afc, 0x6c087a354c4b,
+    0x6c9d2959f27d, 0x6cc40c53c74f, 0x6d25f3716cab, 0x6d5044cc9808,
+    0x6d86b5c3f52e, 0x6d8e7cc2b958, 0x6d9ce5f524d3, 0x6dd5a082bbab,
+    0x6e16972d2237, 0x6e6be9908a40, 0x6e74d55a6b43, 0x6ec72e7a884b,
+    0x6eca27bdd34c, 0x6ef61302270c, 0x6f0e8cc99063, 0x6f8c969efb66,
+    0x6fdb0c3fb659, 0x6ff6f468763b, 0x70210b349bd3, 0x7026fdbb31d5,
+    0x704b2200c16b, 0x7069c8ddc689, 0x707ed443d955, 0x709fff461dea,
+    0x70afc7d2ac03, 0x70b2fba4fcc9, 0x70df969661d8, 0x70e8826042db,
+    0x70ec2b509f2b, 0x70f96fb7edcd, 0x70f9e4d5f957, 0x717b227db520,
+    0x71bfc21877fc, 0x71f28a1f78d2, 0x71f92c532023, 0x71fee44ab060,
+    0x71ff5968bbea, 0x72351ab307c1, 0x7267e2ba0897, 0x7288989e41a2,
+    0x728d6659bacb, 0x72be1f5987b4, 0x72ce5d042157, 0x72e193f205fb,
+    0x7308ec09e657, 0x7351e44216d2, 0x739adc7a474d, 0x73d864c3574e,
+    0x73e1c5ab43db, 0x73e39a237203, 0x73f70ba05c6c, 0x740b67595de9,
+    0x74399c33eb5b, 0x745758d4d965, 0x74580881eab4, 0x746a5533b844,
+    0x7487622794ff, 0x752b2a2bb7fb, 0x7563aa2a490e, 0x756ac17bfbe9,
+    0x758b776034f4, 0x75cd92d5b859, 0x75e97afe783b, 0x75eb4f76a663,
+    0x75fd270a6869, 0x763740f221df, 0x763aaf53786a, 0x768506e5cb83,
+    0x7696de798d89, 0x76ba1883060b, 0x76f30d9fa2a8, 0x76f9ea624fbe,
+    0x77181c214952, 0x772c02bc3f45, 0x7780e0019bc4, 0x77974ac1d12e,
+    0x77a53ed6311f, 0x77aa0c91aa48, 0x77c5450d58db, 0x77ee370e619a,
+    0x77f13051ac9b, 0x78259241d5d4, 0x782a5ffd4efd, 0x787bcee154f1,
+    0x78a4865357eb, 0x78a4fb716375, 0x78c8e527ed46, 0x78db6c68c09b,
+    0x7951af3f6774, 0x795bbfd46550, 0x7967df709719, 0x7974aeb9da31,
+    0x79afed6cb080, 0x79bcbcb5f398, 0x79e91d1852e2, 0x7a1baa904df3,
+    0x7a48ba9fbe8c, 0x7a5f5feef9bb, 0x7a7a234c9cc4, 0x7ab686ca8fec,
+    0x7ac3cb31de8e, 0x7b55f6314549, 0x7b78bb1cb241, 0x7b8674a20c6d,
+    0x7bf1bca79e56, 0x7c850c7221ea, 0x7c8a4f4ba69d, 0x7ce1eb454858,
+    0x7ced95c36e97, 0x7d48daad6ca2, 0x7d5743dfd81d, 0x7d83ded13d2c,
+    0x7dcfd04cb8a8, 0x7dfb0be3fb19, 0x7e276c465a63, 0x7e29f06b99da,
+    0x7e6320173c3c, 0x7e8f80799b86, 0x7e94c3532039, 0x7e9ba015cd4f,
+    0x7ebdb55428f8, 0x7ec49216d60e, 0x7f07d257764c, 0x7f1aceb6552b,
+    0x7f5856ff652c, 0x7f60588d2f1b, 0x7f6351d07a1c, 0x7f7e8a4c28af,
+    0x7fa4f827f1f7, 0x7faff2f906e7, 0x7fb7f486d0d6, 0x7fdf4c9eb132,
+    0x7ffabfa9658a, 0x8038bd108115, 0x804d53588857, 0x80b233b978b4,
+    0x8117141a6911, 0x819050345aeb, 0x8190c5526675, 0x81972cf70801,
+    0x819c354186ef, 0x81e826bd026b, 0x81f14d15e933, 0x820fb963e88c,
+    0x8232f36d610e, 0x82412210c6c4, 0x82e8cd944bd5, 0x82eb172a8587,
+    0x82f35347553b, 0x832ae909cf3a, 0x832c0dd4ec13, 0x83f59407c708,
+    0x8486251e0560, 0x8499217ce43f, 0x84a7502049f5, 0x84d4602fba8e,
+    0x8539b5aeb675, 0x854a68775ba2, 0x8567004d2cd3, 0x85aa7b1cd2d6,
+    0x85ac1505fb39, 0x85b1ccfd8b76, 0x85bd025da62b, 0x85c503eb701a,
+    0x85e0b1852a37, 0x8619e130cc99, 0x862725981b3b, 0x862bf3539464,
+    0x86df49553fb4, 0x87178ec4cb02, 0x873c62b76be7, 0x8771e972b1f9,
+    0x87daad53046b, 0x888b44a06a7f, 0x889ca71620fb, 0x88d0595938e5,
+    0x88e26b7c00b0, 0x88f149cc77b5, 0x88f9fb0752f3, 0x8909fe22e6d1,
+    0x89186755524c, 0x892c1361427a, 0x89cb82c7f7d7, 0x89cca79314b0,
+    0x8a0059d62c9a, 0x8a16ff2567c9, 0x8a3c0da70e73, 0x8a5412506c40,
+    0x8a7af54a4112, 0x8a7c1a155deb, 0x8a9284d59355, 0x8aba8c9a8500,
+    0x8ac9305bf640, 0x8b2a67cc8a4d, 0x8b30cf712bd9, 0x8b6481b443c3,
+    0x8ba4190487b1, 0x8bacca3f62ef, 0x8c3ce63795bd, 0x8c8061073bc0,
+    0x8c889d240b74, 0x8cc11d229c87, 0x8ccd022fc88b, 0x8cdd054b5c69,
+    0x8cf8ed741c4b, 0x8d3d527fd962, 0x8d7b1557ef28, 0x8d81426d8aef,
+    0x8dbe9027952b, 0x8dc1c3f9e5f1, 0x8df11d9f903c, 0x8e2e6b599a78,
+    0x8e5429885271, 0x8ec38f9c4c34, 0x8eee1b867d56, 0x8f148962469e,
+    0x8f2f1230e3e2, 0x8f4df39ceec5, 0x8f52c15867ee, 0x8f9b44728cdf,
+    0x8fcfe0f1bbdd, 0x9015dfe6a157, 0x901e569276d0, 0x902c4aa6d6c1,
+    0x90499229b941, 0x90878f90d4cc, 0x90b0f6afe915, 0x90f262785b2b,
+    0x9100568cbb1c, 0x911aa4cc529b, 0x91c793295c5f, 0x920b48880827,
+    0x9237a8ea6771, 0x92464cabd8b1, 0x9249f59c3501, 0x92714db4155d,
+    0x92b6d78aef4d, 0x92d4942bdd57, 0x92e631309998, 0x92e8b555d90f,
+    0x931f264d3635, 0x93493d195bcd, 0x93b6ceb52768, 0x93f15dbaec68,
+    0x9419da9de99d, 0x943a908222a8, 0x948b4fb9174d, 0x94917cceb314,
+    0x94aec4519594, 0x94cd309f94ed, 0x95011d71b29c, 0x9529d4e3b596,
+    0x9535f47fe75f, 0x955794a0377e, 0x956931a4f3bf, 0x957f61d62364,
+    0x95e148f3c8c0, 0x95e7eb277011, 0x961cfcc4aa99, 0x96291c60dc62,
+    0x9640e67b346a, 0x964b319f380b, 0x9652f89dfc35, 0x973448eb2f32,
+    0x97544f2256ee, 0x97d0847f93c9, 0x97db7f50a8b9, 0x97f6081f45fd,
+    0x984ae564a27c, 0x9870a3935a75, 0x989b6a0c915c, 0x98ec9e61918b,
+    0x999aec18bded, 0x99ea4bf58ff4, 0x9b8e45b2f0d0, 0x9bca6ea1de33,
+    0x9c1c1814e9ec, 0x9c580674d18a, 0x9c7672c2d0e3, 0x9cc3c3986efd,
+    0x9d370d2bcad5, 0x9dc04c61508d, 0x9eb9dbe6e71c, 0x9ef7d94e02a7,
+    0x9f459f41ac4b, 0x9f6988f8361c, 0x9fabdefcbf46, 0x9fde31e5b492,
+    0xa07a6d7a1929, 0xa088618e791a, 0xa0ed41ef6977, 0xa12b3f568502,
+    0xa12f97f3f2a1, 0xa13dc6975857, 0xa147277f44e4, 0xa188cdd6bcbf,
+    0xa1d5347043c5, 0xa1ee2355b8a6, 0xa224944d15cc, 0xa25b054472f2,
+    0xa26306d23ce1, 0xa272cf5ecafa, 0xa27cdff3c8d6, 0xa2a1795763f6,
+    0xa31990a638f7, 0xa319cb353ebc, 0xa33a0bfb6c3d, 0xa350019d961d,
+    0xa35285c2d594, 0xa386e7b2fecd, 0xa3e5606f4d9e, 0xa4abb2cfd7cd,
+    0xa516fad569b6, 0xa5ed505187c3, 0xa5f6ebc87a15, 0xa5f9aa7cbf51,
+    0xa66985aec49e, 0xa67c0cef97f3, 0xa708456868ac, 0xa73221a5887f,
+    0xa74c6fe51ffe, 0xa7ed3ea5f7f9, 0xa8199f085743, 0xa8888ffe457c,
+    0xa92d07af79c7, 0xa934ceae3df1, 0xa93afbc3d9b8, 0xa969309e672a,
+    0xa9b8cb0a3ef6, 0xa9d144d1a84d, 0xa9d478a3f913, 0xa9ec7d4d56e0,
+    0xaa97d1c13841, 0xab8198ba40b7, 0xaba24e9e79c2, 0xaba965f02c9d,
+    0xabc75d20206c, 0xac28cf1fba3e, 0xac385d1d4292, 0xac431d5f51bd,
+    0xac7b62cedd0b, 0xad51f2da00dd, 0xadd1d1279a08, 0xae1c9dd7f8ab,
+    0xae2f9a36d78a, 0xaeba735585a5, 0xaee91d4e1ea1, 0xaeec51206f67,
+    0xaf47d0997337, 0xaf57d3b50715, 0xaf6ca48c141c, 0xaf8901d2df88,
+    0xaf91ed9cc08b, 0xafc2e12b9339, 0xafecbd68b30c, 0xaff2ea7e4ed3,
+    0xaff3d4ba65e7, 0xb0524d76b4b8, 0xb0566b851c92, 0xb05c237caccf,
+    0xb07e734a0e3d, 0xb08291587617, 0xb088f8fd17a3, 0xb0c97a8972a5,
+    0xb12e957968c7, 0xb13c146fbd2e, 0xb182fda0b9bc, 0xb1c469692bd2,
+    0xb1d9af5e4463, 0xb222a79674de, 0xb23f3f6c460f, 0xb25c11d11d05,
+    0xb28ffea33ab4, 0xb29a0f383890, 0xb2f2cffcf724, 0xb3526d8462ce,
+    0xb373d315ad28, 0xb3756cfed58b, 0xb3ff5be16c92, 0xb4601e33f515,
+    0xb4977967694f, 0xb4c190338ee7, 0xb4d48c926dc6, 0xb4e0e6bda554,
+    0xb5437d885bff, 0xb564a88aa094, 0xb56607e4c332, 0xb5755b5345c1,
+    0xb58fe421e305, 0xb59adef2f7f5, 0xb5b8d622ebc4, 0xb6078652ac7c,
+    0xb60a4506f1b8, 0xb61873aa576e, 0xb624cdd58efc, 0xb667d3872975,
+    0xb6913aa63dbe, 0xb6b5245cc78f, 0xb6c3c81e38cf, 0xb6e70227b151,
+    0xb76496df10ca, 0xb7a42e2f54b8, 0xb7c9b1cf06ec, 0xb7fceef4134c,
+    0xb8687188aafa, 0xb86a808fdee7, 0xb876659d0aeb, 0xb8e17313970f,
+    0xb8e59121fee9, 0xb93406c2b9dc, 0xb9c6e16f31e6, 0xb9d1dc4046d6,
+    0xb9df5b369b3d, 0xb9fd17d78947, 0xba030a5e1f49, 0xba037f7c2ad3,
+    0xba1a24cb6602, 0xba50d051c8ed, 0xba7a3770dd36, 0xbb1a5684a3e2,
+    0xbb4c6ede9369, 0xbb773557ca50, 0xbbbf7de2e97c, 0xbbd832395898,
+    0xbbe576a0a73a, 0xbbe69b6bc413, 0xbbe95a20094f, 0xbc1ac2cce787,
+    0xbc1afd5bed4c, 0xbc3969a9eca5, 0xbc730e739a91, 0xbcabc9013169,
+    0xbce0a00f662c, 0xbd90fccdc67b, 0xbda9b1243597, 0xbe5664f23996,
+    0xbe69d66f23ff, 0xbecbbd8cc95b, 0xbed050b93cbf, 0xbf46ce1ee95d,
+    0xbf7fc33b85fa, 0xbfd6ea171c2b, 0xbfdeb115e055, 0xbff0fdc7ade5,
+    0xc03269901ffb, 0xc063228fece4, 0xc08f48634669, 0xc0c66907b4de,
+    0xc12815965475, 0xc17fec1efbf5, 0xc1d95c90cbd8, 0xc2463e7f8624,
+    0xc24ce0b32d75, 0xc28b8dc75a4f, 0xc2a4421dc96b, 0xc2d98e4a09b8,
+    0xc2df464199f5, 0xc3157ca9f156, 0xc332147fc287, 0xc33a8b2b9800,
+    0xc357981f74bb, 0xc3a60dc02fae, 0xc3a857566960, 0xc47252a74fdf,
+    0xc4b9eb855dbc, 0xc4e318156c40, 0xc5562719c253, 0xc5692378a132,
+    0xc5d93939ac44, 0xc5fdd29d4764, 0xc62ae2acb7fd, 0xc65e94efcfe7,
+    0xc6cb01c07ea9, 0xc6f2cef66a8f, 0xc7213e5ffdc6, 0xc7661889c667,
+    0xc87c05562862, 0xc90c5bdd60f5, 0xc90c966c66ba, 0xc9287e95269c,
+    0xc9354dde69b4, 0xc940f85c8ff3, 0xc948bf5b541d, 0xc9bb1eb298e1,
+    0xc9c40a7c79e4, 0xca1120c31239, 0xca79e4a364ab, 0xcaa34bc278f4,
+    0xcab7325d6ee7, 0xcb520e97b0e0, 0xcb5c944aba46, 0xcb61d7243ef9,
+    0xcbc6f214351b, 0xcc4c88595e83, 0xcc68ab11242a, 0xcc7c91ac1a1d,
+    0xccc08199cbaa, 0xccdd196f9cdb, 0xcce7d9b1ac06, 0xccfa9b818520,
+    0xccfbc04ca1f9, 0xccff693cfe49, 0xcd06f5acbcae, 0xcd27ab90f5b9,
+    0xcd3f3b1c47fc, 0xcd55a5dc7d66, 0xcd6c85babe5a, 0xce5b54fe45be,
+    0xce71bfbe7b28, 0xce81884b0941, 0xce8272872055, 0xcea9ca9f00b1,
+    0xcef9da28e407, 0xcf07ce3d43f8, 0xcf087dea5547, 0xcf09dd4477e5,
+    0xcf0bb1bca60d, 0xcf2306b8f28b, 0xcf84edd697e7, 0xcfc2eb3db372,
+    0xcfc44a97d610, 0xd015b97bdc04, 0xd05f61611dce, 0xd07c6e54fa89,
+    0xd09054eff07c, 0xd0b59e009ceb, 0xd0c2e267eb8d, 0xd0d70391e745,
+    0xd10488bf6368, 0xd141615b621a, 0xd1675a191fd8, 0xd179e159f32d,
+    0xd20ae78e3d0f, 0xd2462641135e, 0xd275ba75c36e, 0xd2ab41310980,
+    0xd2bb444c9d5e, 0xd2c6048eac89, 0xd332715f5b4b, 0xd56c3f0c7f32,
+    0xd573565e320d, 0xd6358ab05462, 0xd676bbe9c0b3, 0xd6a10d44ec10,
+    0xd90a6f26c007, 0xd91703e0fd5a, 0xd9cf9cbc2d5d, 0xda190a126962,
+    0xda34b7ac237f, 0xda5c84e20f65, 0xdb45270ffb02, 0xdbe50b94bbe9,
+    0xdc3d1cac692e, 0xdd58fbff612b, 0xdd61e7c9422e, 0xdd8d2360849f,
+    0xdd94002331b5, 0xde95cbc597f8, 0xdeb6bc38d6c8, 0xdec94379aa1d,
+    0xdf35b04a58df, 0xdf4035fd6245, 0xdf849b091f5c, 0xdfa42c223b8e,
+    0xdfc85067cb24, 0xdff525e835f8, 0xdffa2e32b4e6, 0xe00e4f5cb09e,
+    0xe04b6287b515, 0xe0df61ff49f8, 0xe1ba4aa7db69, 0xe1bb6f72f842,
+    0xe1d5f8419586, 0xe263901488dd, 0xe32b07402fe5, 0xe3a0d4f8cb34,
+    0xe42fcc25e129, 0xe4b64ca721a5, 0xe50e984dd4af, 0xe57cd996b199,
+    0xe5ba2750bbd5, 0xe748db190420, 0xe7820ac4a682, 0xe893d982a0a3,
+    0xe8c9d55bf23f, 0xe92679a012e8, 0xe9555e27b1a9, 0xe9785da22466,
+    0xea0afdbf96ab, 0xea4258f30ae5, 0xea4601e36735, 0xea9a2f7bb265,
+    0xeb42158e3d3b, 0xeb763cef60af, 0xeca08574c427, 0xecaf29363567,
+    0xecd3fd28d64c, 0xed11fa8ff1d7, 0xeddf29b3291c, 0xee243e6bf782,
+    0xee6ab27ee886, 0xee72045fa126, 0xee77bc573163, 0xee93df0ef70a,
+    0xeea457489672, 0xeef96f1cf8b6, 0xef151cb6b2d3, 0xef2ce6d10adb,
+    0xef34e85ed4ca, 0xef814ef85bd0, 0xef877c0df797, 0xefceda5cffaf,
+    0xefd074462812, 0xf03bf6dabfc0, 0xf0db2bb26f58, 0xf103e3247252,
+    0xf14046a2657a, 0xf14673b80141, 0xf15f9d2c7be7, 0xf175cd5dab8c,
+    0xf22ea0c7e154, 0xf2716bea7608, 0xf3182d31e405, 0xf36c5aca2f35,
+    0xf3724d50c537, 0xf390b99ec490, 0xf39d4e5901e3, 0xf3df2f3f7f83,
+    0xf3efa7791eeb, 0xf42fb3e76e63, 0xf46c8c836d15, 0xf470aa91d4ef,
+    0xf4907639f6e6, 0xf492fa5f365d, 0xf49edf6c6261, 0xf5138859e0d7,
+    0xf51f6d670cdb, 0xf52475b18bc9, 0xf5c57f016989, 0xf6408f93898b,
+    0xf68e1af82d6a, 0xf6a117570c49, 0xf6bcff7fcc2b, 0xf6d8e7a88c0d,
+    0xf71809dac471, 0xf74d560704be, 0xf782dcc24ad0, 0xf7e06b42828d,
+    0xf7ecc56dba1b, 0xf891eccbffb5, 0xf89311971c8e, 0xf8ecbc97f236,
+    0xf9d0910a64aa, 0xf9e94560d3c6, 0xfa20a0944800, 0xfadf668513ca,
+    0xfb0b173a61c5, 0xfb86625b878c, 0xfbe21c639121, 0xfcdd0b434a4e,
+    0xfcebaf04bb8e, 0xfd1ace1b6014, 0xfd7946d7aee5, 0xfdc91bd28c76,
+    0xfe0c218426ef, 0xfe0e6b1a60a1, 0xfe12140abcf1, 0xfee8dea4e688,
+    0xffb97c297458, 0xffcf373c9873, 0xffdb1c49c477, 0x0022efb6d819,
+    0x00d34c753868, 0x0107e8f46766, 0x013f0998d5db, 0x0185f2c9d269,
+    0x036d2cc7d383, 0x03ba7d9d719d, 0x0401a15d73f0, 0x04b70666532d,
+    0x05c1f861a038, 0x067ee9da3dda, 0x07583899a6e8, 0x07b21e298255,
+    0x07fe84c3095b, 0x0805d6a3c1fb, 0x084ab0cd8a9c, 0x0868e28c8430,
+    0x088a82acd44f, 0x088f8af7533d, 0x08c511b2994f, 0x09756e70f99e,
+    0x0af5f395dc33, 0x0b207f800d55, 0x0b20ba0f131a, 0x0b267206a357,
+    0x0b3724cf4884, 0x0becfef6334b, 0x0ced30af712b, 0x0d53aaf989eb,
+    0x0d6b3a84dc2e, 0x0ed46aad7245, 0x0ee3f8aafa99, 0x0ef1b23054c5,
+    0x0f0732b4731b, 0x0fe2cb0a15db, 0x1048d0362311, 0x10526bad1563,
+    0x107ff0da9186, 0x10ad3b7907e4, 0x11bb9bd5ab7a, 0x11e9d0b038ec,
+    0x12184019cc23, 0x12c0d5d96848, 0x12f572589746, 0x12fa05850aaa,
+    0x133927b7430e, 0x136a90642146, 0x137f26ac2888, 0x13d5634ba7a5,
+    0x13ed2d65ffad, 0x1446d866d555, 0x1585b734400f, 0x15a9665bc41b,
+    0x15c0bb581099, 0x161c3ad11469, 0x165863c001cc, 0x16870db89ac8,
+    0x16b4cd751cb0, 0x1714a58b8e1f, 0x17329cbb81ee, 0x17a8a5032302,
+    0x1816712df462, 0x1838fb8a5b95, 0x184e7c0e79eb, 0x1861786d58ca,
+    0x1886fc0d0afe, 0x18cf7f272fef, 0x18fcc9c5a64d, 0x1983bf64f253,
+    0x1988529165b7, 0x199263266393, 0x199e82c2955c, 0x1a3a4938ee69,
+    0x1a4d0b08c783, 0x1b0fb478f562, 0x1bd9afc9dbe1, 0x1c5744813b5a,
+    0x1ca8b365414e, 0x1cf086d254f0, 0x1d2389685b8b, 0x1d44b46aa020,
+    0x1db280957180, 0x1dbc1c0c63d2, 0x1e51effc26dd, 0x1ecbdbc32a06,
+    0x1fa5651198d9, 0x1fef4785e068, 0x201d41d16815, 0x2089aea216d7,
+    0x21039a691a00, 0x210a020dbb8c, 0x217cd6830bda, 0x22a376181302,
+    0x22a3b0a718c7, 0x237cc4d77c10, 0x23969df90805, 0x249326c1e995,
+    0x2513ef4b99d4, 0x258d2b658bae, 0x25b4f89b7794, 0x25e710f5671b,
+    0x27292395229b, 0x278ca49bf05a, 0x27c55f298732, 0x28202ef579b3,
+    0x285874650501, 0x2982f7796e3e, 0x29c54d7df768, 0x2a3c0572a9cb,
+    0x2a514b67c25c, 0x2a62addd78d8, 0x2a9db2014962, 0x2a9ffb978314,
+    0x2b1ca612cb79, 0x2b2173ce44a2, 0x2b37a3ff7447, 0x2b4522f5c8ae,
+    0x2b7f024e7c5f, 0x2be41d3e7281, 0x2be62c45a66e, 0x2c46797a2367,
+    0x2ccc84dd5859, 0x2d0280b6a9f5, 0x2d0f4fffed0d, 0x2d0fffacfe5c,
+    0x2d542a29b5ae, 0x2dcfea68e6ff, 0x2e1f0fb6b341, 0x2e269c2671a6,
+    0x2e50b2f2973e, 0x2e66a894c11e, 0x2e854f71c63c, 0x2e90bf60e6b6,
+    0x2ecc38a2c2ca, 0x2f291775e938, 0x2f4a42782dcd, 0x2f5abab1cd35,
+    0x30c8f324e23a, 0x312905ca596e, 0x3319a0b04715, 0x33855dd3e488,
+    0x33aa6c558b32, 0x3405019277ee, 0x34399e11a6ec, 0x3461a5d69897,
+    0x346639030bfb, 0x3487d9235c1a, 0x34a22762f399, 0x34a4366a2786,
+    0x34f1c1cecb65, 0x3565f59e3e51, 0x35bb0d72a095, 0x35d93f319a29,
+    0x35f6c143826e, 0x3624f61e0fe0, 0x368c201539ef, 0x368ea43a7966,
+    0x36fc70654ac6, 0x378415b1a81b, 0x38313e9db7a4, 0x38455fc7b35c,
+    0x384f35cdab73, 0x39543542627c, 0x3a1ac231f270, 0x3ad4ba674511,
+    0x3b77d2be56be, 0x3b881068f061, 0x3b9f2ad6371a, 0x3bbcace81f5f,
+    0x3be5645a2259, 0x3c7d47511951, 0x3cb84b74e9db, 0x3cd7a1ff0048,
+    0x3cfceb0facb7, 0x3dd42ac7e1d8, 0x3e08c74710d6, 0x3e201c435d54,
+    0x3e29f249556b, 0x3e3fad5c7986, 0x3e5c45324ab7, 0x3ea5b28886bc,
+    0x3ee5f985dbf9, 0x3ee8b83a2135, 0x3f26b5a13cc0, 0x3f48905092a4,
+    0x40b94ce8e720, 0x40d8a372fd8d, 0x4122c0764ae1, 0x4146350ec928,
+    0x43369565b10a, 0x438c22581ed8, 0x43b5fe953eab, 0x43c3086d8788,
+    0x43ca1fbf3a63, 0x4457b7922dba, 0x445a3bb76d31, 0x447031599711,
+    0x4513beceb448, 0x456aab1b44b4, 0x4623f3a38606, 0x4689f8cf933c,
+    0x46a40c8024f6, 0x46dd3c2bc758, 0x471ac474d759, 0x4749e38b7bdf,
+    0x482af949a917, 0x4836a3c7cf56, 0x4853762ca64c, 0x48946cd70cd8,
+    0x48abc1d35956, 0x48ff3fbe9337, 0x49d13c9d43a5, 0x4a01f59d108e,
+    0x4a5c8ad9fd4a, 0x4aa498d616b1, 0x4aa5f830394f, 0x4b109088b9e9,
+    0x4b32e0561b57, 0x4ba74eb49408, 0x4c13f614488f, 0x4c5b8ef2566c,
+    0x4c6564f84e83, 0x4c8c0d631d90, 0x4ce0758a6e85, 0x4ce7524d1b9b,
+    0x4d0a17388893, 0x4d7cb11ed31c, 0x4eafaadf11d2, 0x4f04fd4279db,
+    0x4f153aed137e, 0x4f23deae84be, 0x4f6375fec8ac, 0x4f733e8b56c5,
+    0x4f858b3d2455, 0x4fa51c564087, 0x4fb3c017b1c7, 0x50161c5362ad,
+    0x50469ac429d1, 0x50c6b3a0c8c1, 0x50d3bd79119e, 0x50e7a4140791,
+    0x517242a3afe7, 0x5208512278b7, 0x524a3208f657, 0x5297bd6d9a36,
+    0x52a8e5544aed, 0x533fde0f2ad1, 0x53a4be701b2e, 0x53ac1050d3ce,
+    0x53cbdbf8f5c5, 0x53d11ed27a78, 0x5473fc9a8660, 0x54a10ca9f6f9,
+    0x55303e6612b3, 0x556a1dbec664, 0x55a90561f903, 0x55eaabb970de,
+    0x55eb20d77c68, 0x564e2cc03e9d, 0x56e4eaec18bc, 0x56f56325b824,
+    0x5765b375c8fb, 0x57cca2dded45, 0x57e08978e338, 0x58181f3b5d37,
+    0x58d28c8ebb62, 0x58e1dffd3df1, 0x59bb2ebca6ff, 0x59e754900084,
+    0x59f498f74f26, 0x5a6e4a2f4c8a, 0x5a8d2b9b576d, 0x5abaeb57d955,
+    0x5ae0a986914e, 0x5b349c8fd6b9, 0x5b7bc04fd90c, 0x5bab5484891c,
+    0x5bd5e06eba3e, 0x5bf8a55a2736, 0x5c05e9c175d8, 0x5c43377b8014,
+    0x5ca34a20f748, 0x5cf4f3940301, 0x5d45b2caf7a6, 0x5d996b45374c,
+    0x5de1b3d05678, 0x5e73a440b76e, 0x5eb33b90fb5c, 0x5f1fa861aa1e,
+    0x5f6447fc6cfa, 0x5fc544ddfb42, 0x5fca87b77ff5, 0x60a4c0b30017,
+    0x60e83b82a61a, 0x611bb336b83f, 0x6185615321c5, 0x61e832acde35,
+    0x62b8d0316c05, 0x6331220f46cb, 0x63633a693652, 0x63d474f55e3d,
+    0x642b26b2e8e4, 0x6434879ad571, 0x64669ff4c4f8, 0x647668815311,
+    0x64af5d9defae, 0x64e97785a924, 0x650400544668, 0x6532352ed3da,
+    0x654de2c88df7, 0x65bd836b8d7f, 0x65bf1d54b5e2, 0x666c4640c56b,
+    0x66a7bf82a17f, 0x6730feb82737, 0x68b701459444, 0x696290487b6a,
+    0x6976b1727722, 0x699592de8205, 0x6a628772b385, 0x6a9a57c43349,
+    0x6aae3e5f293c, 0x6ae056b918c3, 0x6ae81db7dced, 0x6b2028986276,
+    0x6b335f86471a, 0x6b36cde79da5, 0x6b8c5ada0b73, 0x6cfacddc263d,
+    0x6dacc483aeef, 0x6e27d515cef1, 0x6eb691b3df21, 0x6fe398ed87d5,
+    0x7050effa4dab, 0x7061a2c2f2d8, 0x707e7527c9ce, 0x70afa345a241,
+    0x70d7ab0a93ec, 0x718a8bee33b2, 0x7250de4ebde1, 0x7281974e8aca,
+    0x734feb3cdee8, 0x738990068cd4, 0x739d3c127d02, 0x73ca86b0f360,
+    0x73d4d1d4f701, 0x740e01809963, 0x7431b0a81d6f, 0x74390288d60f,
+    0x74460c611eec, 0x744ada1c9815, 0x74e8ea292ad4, 0x7557a0901348,
+    0x757e0e6bdc90, 0x758400f27292, 0x75dc120a1fd7, 0x75f7fa32dfb9,
+    0x75fe9c66870a, 0x767ca63bf20d, 0x76ac3a70a21d, 0x779acf2523bc,
+    0x783a3e8bd919, 0x7847486421f6, 0x78b34016c52e, 0x79dd137e1d1c,
+    0x7ab06fb6f028, 0x7adc958a49ad, 0x7b9185751d60, 0x7bbb9c4142f8,
+    0x7bde9bbbb5b5, 0x7bf8af6c476f, 0x7c793d66f1e9, 0x7c81b412c762,
+    0x7c9d271d7bba, 0x7d13a4832858, 0x7d7e776aaeb7, 0x7d9dcdf4c524,
+    0x7e05e2280647, 0x7f0e4ffe13db, 0x7f268f36776d, 0x7f68701cf50d,
+    0x7fc046a59c8d, 0x7ffa608d5603, 0x8233096f5d11, 0x82d91b09b9bf,
+    0x82d95598bf84, 0x830b6df2af0b, 0x8391b3e4e9c2, 0x83b5d82a7958,
+    0x83c9bec56f4b, 0x84248e9161cc, 0x843a84338bac, 0x84d7e4930d1c,
+    0x8502ab0c4403, 0x850395485b17, 0x85e6f49cc201, 0x86038c729332,
+    0x863654799408, 0x867ed793b8f9, 0x868922b7bc9a, 0x868e2b023b88,
+    0x86d2557ef2da, 0x872dd4f7f6aa, 0x8765a549766e, 0x87fd4db167a1,
+    0x880d50ccfb7f, 0x881343539181, 0x892fd2539acd, 0x8943f37d9685,
+    0x896a61595fcd, 0x897bfe5e1c0e, 0x8a1f16b52dbb, 0x8a316366fb4b,
+    0x8ad899cc74d2, 0x8b0b61d375a8, 0x8b6288af0bd9, 0x8bb1736dd256,
+    0x8bf1ba6b2793, 0x8c1ae6fb3617, 0x8c1b5c1941a1, 0x8d02645e04db,
+    0x8d477916d341, 0x8e1607942d24, 0x8e16422332e9, 0x8e37a7b47d43,
+    0x8ebc192e89d2, 0x8f02c7d0809b, 0x8f4e7ebcf652, 0x8fa829bdcbfa,
+    0x8fc82ff4f3b6, 0x8ff998a1d1ee, 0x8ffaf7fbf48c, 0x90f6216ab37e,
+    0x911920e5263b, 0x91345960d4ce, 0x917d51990549, 0x91bc393c37e8,
+    0x91c9431480c5, 0x9201fda2179d, 0x924f4e77b5b7, 0x925750057fa6,
+    0x9281a160ab03, 0x92bce0138152, 0x9324b9b7bcb0, 0x9335a70f67a2,
+    0x93511a1a1bfa, 0x93569782a672, 0x936366cbe98a, 0x9405cf75e9e8,
+    0x948155261574, 0x948697ff9a27, 0x94ac1b9f4c5b, 0x94c5ba31d28b,
+    0x94d7cc549a56, 0x950f62171455, 0x9551b81b9d7f, 0x956ae1901825,
+    0x960498ff3d45, 0x9619def455d6, 0x9642966658d0, 0x966ef6c8b81a,
+    0x967aa146de59, 0x96ae5389f643, 0x97492fc4383c, 0x98dc019ae861,
+    0x9967ff84b355, 0x997752f335e4, 0x99ac2a016aa7, 0x99d088d60002,
+    0x9a202341d7ce, 0x9a7834598513, 0x9aa509d9efe7, 0x9afeb4dac58f,
+    0x9b0ce37e2b45, 0x9b1c717bb399, 0x9b69c25151b3, 0x9b9c153a46ff,
+    0x9c2c313279cd, 0x9c9395b8a9a1, 0x9cdffc5230a7, 0x9d1b75940cbb,
+    0x9d5a5d373f5a, 0x9d6607b56599, 0x9da1bb864772, 0x9dfc8b5239f3,
+    0x9e93840d19d7, 0x9eb0cb8ffc57, 0x9f5926c092b7, 0x9f6496afb331,
+    0x9f6a4ea7436e, 0x9f9e760866e2, 0xa03e5a8d27c9, 0xa07d42305a68,
+    0xa08334b6f06a, 0xa0e222914ac5, 0xa12efe48dd55, 0xa1c0eeb93e4b,
+    0xa1d50fe33a03, 0xa2a06a8e4320, 0xa2fd83f06f53, 0xa3258bb560fe,
+    0xa339acdf5cb6, 0xa3c18cbabfd0, 0xa3e451a62cc8, 0xa3e6d5cb6c3f,
+    0xa4028365265c, 0xa437cf9166a9, 0xa44513f8b54b, 0xa456b0fd718c,
+    0xa4625b7b97cb, 0xa4ccf3d41865, 0xa4cf3d6a5217, 0xa51a44a9b67f,
+    0xa51ed7d629e3, 0xa523e020a8d1, 0xa52838be1670, 0xa530af69ebe9,
+    0xa62118969bb0, 0xa6386d92e82e, 0xa6c7d9de09ad, 0xa6d13ac5f63a,
+    0xa77c1a1bcc11, 0xa7b0b69afb0f, 0xa7cee859f4a3, 0xa7f3472e89fe,
+    0xa7f8c4971476, 0xa834ed8601d9, 0xa8f2c93ab68f, 0xa965d83f0ca2,
+    0xa991c3836062, 0xa99c0ea76403, 0xa9a410352df2, 0xa9e8afcff0ce,
+    0xaa19ddedc941, 0xaa3675c39a72, 0xaad151fddc6b, 0xab67607ca53b,
+    0xabd8d597d2eb, 0xabf7f192e393, 0xac687c71fa2f, 0xacb8c68ae34a,
+    0xacfb1c8f6c74, 0xad6d06c8a5ae, 0xadbaccbc4f52, 0xae21f6b37961,
+    0xae79581e1557, 0xaec215c7400d, 0xaeceaa817d60, 0xaf5aa86b4854,
+    0xafc6daacf151, 0xb01c2d10595a, 0xb02ad0d1ca9a, 0xb038c4e62a8b,
+    0xb085660eb756, 0xb08cb7ef6ff6, 0xb0e1cfc3d23a, 0xb109d788c3e5,
+    0xb10f1a624898, 0xb130ba8298b7, 0xb13f23b50432, 0xb157d80b734e,
+    0xb189b5d65d10, 0xb1abcb14b8b9, 0xb1c987b5a6c3, 0xb1da750d51b5,
+    0xb1fff8ad03e9, 0xb228b01f06e3, 0xb384d66f541d, 0xb3b3f585f8a3,
+    0xb40da086ce4b, 0xb412a8d14d39, 0xb485b7d5a34c, 0xb4978f696552,
+    0xb49963e1937a, 0xb4d63c7d922c, 0xb4eb8272aabd, 0xb546c75ca8c8,
+    0xb5635f3279f9, 0xb599959ad15a, 0xb5e009adc25e, 0xb616efc32b0e,
+    0xb6317891c852, 0xb64d60ba8834, 0xb6647b27ceed, 0xb6b06ca34a69,
+    0xb6e57e4084f1, 0xb7534a6b5651, 0xb76b4f14b41e, 0xb78771cc79c5,
+    0xb7a1fa9b1709, 0xb7d7f67468a5, 0xb801d2b18878, 0xb8d7ed9ea0c0,
+    0xb8fcfc20476a, 0xb931989f7668, 0xb976383a3944, 0xb9dade0c23dc,
+    0xba244b625fe1, 0xba7cd19818b0, 0xba8c25069b3f, 0xbabaceff343b,
+    0xbae3c1003cfa, 0xbb0108831f7a, 0xbb1c066fc848, 0xbb1effb31349,
+    0xbc24e963e166, 0xbc50d4a83526, 0xbcca4b512cc5, 0xbccc1fc95aed,
+    0xbd279f425ebd, 0xbd2ce21be370, 0xbd2eb6941198, 0xbd8fb3759fe0,
+    0xbdca7d0a6aa5, 0xbdebe29bb4ff, 0xbded41f5d79d, 0xbdf2f9ed67da,
+    0xbe49abaaf281, 0xbeee987a3256, 0xbf9e458b8156, 0xbfabff10db82,
+    0xc0d76c615bd3, 0xc116c92299fc, 0xc11bd16d18ea, 0xc1e4a7f2e290,
+    0xc21267af6478, 0xc2a3e301b9e4, 0xc319eb495af8, 0xc356fe745f6f,
+    0xc3c121aed47f, 0xc42d196177b7, 0xc436052b58ba, 0xc490254a39ec,
+    0xc4a481033b69, 0xc551a9ef4af2, 0xc55cdf4f65a7, 0xc57902072b4e,
+    0xc5da7406c520, 0xc69606254024, 0xc6d478aa6739, 0xc724fd525619,
+    0xc7b3f47f6c0e, 0xc8361c633eeb, 0xc842ebac8203, 0xc984c3bd37be,
+    0xcb4604fd7b1a, 0xcba0d4c96d9b, 0xcbaf788adedb, 0xcc2ac3ac04a2,
+    0xcc521bc3e4fe, 0xcc54da782a3a, 0xcc6d543f9391, 0xcd8933928b8e,
+    0xcdbb115d7550, 0xcdc08ec5ffc8, 0xce0cbad08109, 0xce86317978a8,
+    0xd02a2b36d984, 0xd0338c1ec611, 0xd0a32cc1c599, 0xd0b8ad45e3ef,
+    0xd19e5630848b, 0xd1f0af50a193, 0xd1f1246ead1d, 0xd22eacb7bd1e,
+    0xd3107222fba5, 0xd3d31b932984, 0xd427492b74b4, 0xd594d1f1786a,
+    0xd5e606467899, 0xd6431fa8a4cc, 0xd670a4d620ef, 0xd686d5075094,
+    0xd6cd0e8b3bd3, 0xd6feb1c71fd0, 0xd71b0f0deb3c, 0xd749b9068438,
+    0xd7a9568defe2, 0xd7c070fb369b, 0xd8bc4a1706dc, 0xd8d414315ee4,
+    0xd930f3048552, 0xda9d56ff6c2f, 0xdb933d94a66e, 0xdbc79f84cfa7,
+    0xdc314da1392d, 0xdc7d79abba6e, 0xdc8839edc999, 0xdc945989fb62,
+    0xdd051ef817c3, 0xdd3b55606f24, 0xdfa6512b6b7e, 0xe00f150bbdf0,
+    0xe0ab50a02287, 0xe0db9481e3e6, 0xe151623a7f35, 0xe1c1ed1995d1,
+    0xe2047dad24c0, 0xe283e6dcb261, 0xe314b281f67e, 0xe3fa95fb9cdf,
+    0xe4490b9c57d2, 0xe4aab82af769, 0xe54768dd678a, 0xe587754bb702,
+    0xe74bafcf455f, 0xe76acbca5607, 0xe7a13cc1b32d, 0xe8182f456b55,
+    0xe98249aa1880, 0xe982bec8240a, 0xe9fc35711ba9, 0xea577a5b19b4,
+    0xeb2ce59b20ad, 0xeb2fa44f65e9, 0xeb3103a98887, 0xec4d581a8c0e,
+    0xec69b561577a, 0xedc31cfd5f78, 0xede5323bbb21, 0xee50b4d052cf,
+    0xee9e4034f6ae, 0xef39918d4431, 0xef541a5be175, 0xef5af71e8e8b,
+    0xf064c44ebebd, 0xf1176aa358be, 0xf15910fad099, 0xf1a1cea3fb4f,
+    0xf1b122127dde, 0xf362d55538e6, 0xf3985c107ef8, 0xf3a4b63bb686,
+    0xf47e3f8a2559, 0xf485916addf9, 0xf4b89400e494, 0xf4bc3cf140e4,
+    0xf50a7802f612, 0xf6a75a6ea413, 0xf70aa0e66c0d, 0xf72faf6812b7,
+    0xf89ab408d6f6, 0xf8b7114fa262, 0xf93b82c9aef1, 0xf98d66cbc06f,
+    0xf99ae5c214d6, 0xf9dc518a86ec, 0xf9ee63ad4eb7, 0xfa28f2b313b7,
+    0xfaa6c1f978f5, 0xfad11354a452, 0xfb08a9171e51, 0xfb1f88f55f45,
+    0xfb450c951179, 0xfb7515e7cd13, 0xfba594589437, 0xfbf91243ce18,
+    0xfcb3f4b537cd, 0xfd7a81a4c7c1, 0xfd95ba207654, 0xfdaa8af7835b,
+    0xfdb0f29c24e7, 0xfdd885430b08, 0xfe0a9d9cfa8f, 0xfe3c7b67e451,
+    0xfea922c798d8, 0xfed1da399bd2, 0xfef046879b2b, 0xfffb7311edfb,
+    0x012ee1f0383b, 0x023abe279c5a, 0x02592a759bb3, 0x02bb4c2246d4,
+    0x032ab2364097, 0x03a588395ad4, 0x0580a29b2a25, 0x060b412ad27b,
+    0x06393b765a28, 0x06aece9fefb2, 0x06ff5347de92, 0x0803dd9e8a11,
+    0x081a0dcfb9b6, 0x0838ef3bc499, 0x083a1406e172, 0x08faae6fdb64,
+    0x09faa59a137f, 0x0aa300caa9df, 0x0b24b3907132, 0x0b2688089f5a,
+    0x0bbc5bf86265, 0x0c3ebe6b3b07, 0x0ca204e30301, 0x0cd4925afe12,
+    0x0d74ebfdca83, 0x0d78cf7d2c98, 0x0e6938a9dc5f, 0x0f086d818bf7,
+    0x0f295df4cac7, 0x0f86ec750284, 0x0fd9bab32b16, 0x11f556a15569,
+    0x140bafb5fb09, 0x14717a53027a, 0x14e23fc11edb, 0x15048f8e8049,
+    0x15a89221a90a, 0x15e35bb673cf, 0x1734fc53b7a3, 0x175a45646412,
+    0x179e3552159f, 0x17c3b8f1c7d3, 0x190fa1977b6a, 0x1911760fa992,
+    0x1924e78c93fb, 0x1a6107a5b979, 0x1aed401e8a32, 0x1bc09c575d3e,
+    0x1be695151afc, 0x1c183850fef9, 0x1c349597ca65, 0x1c7f62482908,
+    0x1d3c1931c0e5, 0x1d4246475cac, 0x1e3399b02387, 0x1ed1349eaabc,
+    0x1f2945b65801, 0x1f4393f5ef80, 0x1faa835e13ca, 0x1fb24a5cd7f4,
+    0x1fc71b33e4fb, 0x20151bb69464, 0x20cd04e4b318, 0x211c64c1851f,
+    0x2175d5335502, 0x21fc90439b43, 0x22500e2ed524, 0x228c71acc84c,
+    0x236b7863c197, 0x246d440627da, 0x24a80d9af29f, 0x2532719b9530,
+    0x255a04427b51, 0x25ef9da33897, 0x264e165f8768, 0x268c4e55a8b8,
+    0x26cd7f8f1509, 0x2746bba906e3, 0x27ade5a030f2, 0x298ce3816258,
+    0x2a3b31388eba, 0x2abca96f5048, 0x2ac26166e085, 0x2b71d3e929c0,
+    0x2d72375ba580, 0x2da240ae611a, 0x2df92cfaf186, 0x2fbd677e7fe3,
+    0x3032c0190fa8, 0x3040799e69d4, 0x305b3cfc0cdd, 0x30e182ee4794,
+    0x3116cf1a87e1, 0x319f5ea2fc4a, 0x31a30793589a, 0x3213cd0174fb,
+    0x32311484577b, 0x3373d6d1244a, 0x33b3a8b06dfd, 0x33ce6c0e1106,
+    0x33f9328747ed, 0x34705f9a05da, 0x34f496850ca4, 0x350499a0a082,
+    0x3548c41d57d4, 0x3570cbe2497f, 0x35fcc9cc1473, 0x369b4ef6b2bc,
+    0x3793b9b12c72, 0x37cb8a02ac36, 0x3a740e16b891, 0x3c9611a98470,
+    0x3d6849173aa3, 0x3d6a581e6e90, 0x3dbefad4c54a, 0x3dfc831dd54b,
+    0x3e88f625abc9, 0x3e93b667baf4, 0x3f43637909f4, 0x3f9dbe26f0eb,
+    0x3fc884a027d2, 0x3ff51f918ce1, 0x4010580d3b74, 0x407e5ec71299,
+    0x41918cdf2f58, 0x41b50177ad9f, 0x4224678ba762, 0x42dc50b9c616,
+    0x42f196aedea7, 0x4323e997d3f3, 0x438312013413, 0x44183643e5cf,
+    0x443afb2f52c7, 0x446ac9f3089c, 0x4477993c4bb4, 0x447a1d618b2b,
+    0x44b08e58e851, 0x45784013951e, 0x46277806d894, 0x46394f9a9a9a,
+    0x473b905b0c67, 0x477c4c766d2e, 0x47a1d0161f62, 0x47da5014b075,
+    0x485c77f88352, 0x48e83b534881, 0x4938fa8a3d26, 0x495900c164e2,
+    0x496f30f29487, 0x49781cbc758a, 0x4978cc6986d9, 0x49b4bac96e77,
+    0x49ef845e393c, 0x4a0838b4a858, 0x4a120ebaa06f, 0x4a5acc63cb25,
+    0x4aa7e2aa637a, 0x4abcb3817081, 0x4acae224d637, 0x4b232dcb8941,
+    0x4b8eeaef26b4, 0x4b93b8aa9fdd, 0x4cebfb7b8b02, 0x4d03c595e30a,
+    0x4d1353936b5e, 0x4d4d6d7b24d4, 0x4d9d7d05082a, 0x4daf8f27cff5,
+    0x4dfdca398523, 0x4e38593f4a23, 0x4e5824e76c1a, 0x4e5bcdd7c86a,
+    0x4f1a93c89434, 0x4f3f67bb3519, 0x4f92ab176935, 0x4fa4f7c936c5,
+    0x50c7794fd613, 0x510d0326b003, 0x511a821d046a, 0x5135457aa773,
+    0x5147ccbb7ac8, 0x518b821a2690, 0x5206cd3b4c57, 0x523c8e85982e,
+    0x530cb6ec1a74, 0x5314435bd8d9, 0x543e5152368c, 0x545c83113020,
+    0x547696c1c1da, 0x548833c67e1b, 0x5524a9e9e877, 0x5534e794821a,
+    0x556356fe1551, 0x5615fd52af52, 0x56472b7087c5, 0x56838eee7aed,
+    0x569cb862f593, 0x56c18c559678, 0x56e48bd00935, 0x572d497933eb,
+    0x577e7dce341a, 0x57a2a213c3b0, 0x58e85da3db80, 0x58fc7ecdd738,
+    0x593205891d4a, 0x59649301185b, 0x5a07ab582a08, 0x5ab808168a57,
+    0x5ac8f56e3549, 0x5b0dcf97fdea, 0x5b364c7afb1f, 0x5ba9960e56f7,
+    0x5c0b7d2bfc53, 0x5c59431fa5f7, 0x5c82aa3eba40, 0x5d45191fe25a,
+    0x5d66f3cf383e, 0x5dcc0ebf2e60, 0x5dded08f077a, 0x5de4888697b7,
+    0x5e0c1b2d7dd8, 0x5e0e64c3b78a, 0x5e79acc94973, 0x5e9594f20955,
+    0x5fafda5bd8ef, 0x5fd9f127fe87, 0x601d31689ec5, 0x60ed59cf210b,
+    0x619196f14f91, 0x6242ddebc6f4, 0x6252a678550d, 0x6279fe903569,
+    0x6328fbf4731a, 0x64171b8ae92f, 0x64542eb5eda6, 0x645d8f9dda33,
+    0x64bc085a2904, 0x6583ba14d5d1, 0x65e1f8421edd, 0x65f778c63d33,
+    0x662a7b5c43ce, 0x66c432cb68ee, 0x671c7e721bf8, 0x6791d70cabbd,
+    0x6a0633bf94a4, 0x6a57a2a39a98, 0x6a90d24f3cfa, 0x6a93cb9287fb,
+    0x6ad571e9ffd6, 0x6b23e78abac9, 0x6b5f60cc96dd, 0x6c1ab85c0c1c,
+    0x6c1c5245347f, 0x6c3f8c4ead01, 0x6c76726415b1, 0x6cbd21060c7a,
+    0x6cdc779022e7, 0x6cec058dab3b, 0x6cf2e2505851, 0x6d269493703b,
+    0x6d7ee03a2345, 0x6fa6d6538526, 0x6fcfc8548de5, 0x705bc63e58d9,
+    0x707faff4e2aa, 0x708985fadac1, 0x70a115862d04, 0x71066b0528eb,
+    0x719d9e4f0e94, 0x71b14a5afec2, 0x723ac41f8a3f, 0x72a387ffdcb1,
+    0x72d2e1a586fc, 0x72eddf922fca, 0x73139dc0e7c3, 0x7317bbcf4f9d,
+    0x7328a926fa8f, 0x736b39ba897e, 0x7394664a9802, 0x73975f8de303,
+    0x73cc369c17c6, 0x7448a6885a66, 0x751b1885165e, 0x76334ee7b20b,
+    0x76939c1c2f04, 0x77fa481f85a4, 0x785e3e445eed, 0x788646095098,
+    0x78959977d327, 0x78b739982346, 0x78ca35f70225, 0x78d77a5e50c7,
+    0x7923e0f7d7cd, 0x79487a5b72ed, 0x7a0ff18719f5, 0x7a6d45784bed,
+    0x7afd26e178f6, 0x7b2f04ac62b8, 0x7b35e16f0fce, 0x7b3740c9326c,
+    0x7bbc27614a85, 0x7be519625344, 0x7bf2233a9c21, 0x7c426d53853c,
+    0x7c89cba28d54, 0x7ce9a3b8fec3, 0x7cf54e372502, 0x7d2ad4f26b14,
+    0x7d4d5f4ed247, 0x7d54ebbe90ac, 0x7de3a85ca0dc, 0x7dfa4dabdc0b,
+    0x80b5938fc180, 0x80c9050cabe9, 0x810b95a03ad8, 0x811f41ac2b06,
+    0x817d0abb6888, 0x8182fd41fe8a, 0x819f1ff9c431, 0x81d88a346c58,
+    0x81ffe24c4cb4, 0x82122efe1a44, 0x8212a41c25ce, 0x82941c52e75c,
+    0x82b0b428b88d, 0x82fb0bbb0ba6, 0x82fd1ac23f93, 0x835cf2d8b102,
+    0x835ec750df2a, 0x8367eda9c5f2, 0x83a7fa18156a, 0x83d03c6c0cda,
+    0x8467aa44f848, 0x8493d01851cd, 0x854e02dcaa33, 0x86a520e2787f,
+    0x86c6c102c89e, 0x86faadd4e64d, 0x873193ea4efd, 0x87d01914ed46,
+    0x87eb51909bd9, 0x884b9ec518d2, 0x8a2c711e7860, 0x8a39b585c702,
+    0x8a59f64bf483, 0x8b2600a40eef, 0x8bc694d5e125, 0x8bd9cbc3c5c9,
+    0x8cba6c63e777, 0x8cd9fd7d03a9, 0x8d46df6bbdf5, 0x8d612dab5574,
+    0x8e1cbfc9d078, 0x8e57c3eda102, 0x8ec8143db1d9, 0x8f4115c89dee,
+    0x8f8714bd8368, 0x90573d2405ae, 0x913977ad4fbf, 0x913e7ff7cead,
+    0x914ada23063b, 0x915350cedbb4, 0x92bc80f771cb, 0x93e619cfc3f4,
+    0x9523d3d211d5, 0x95899e6f1946, 0x95dd1c5a5327, 0x95e642b339ef,
+    0x961e4d93bf78, 0x9676241c66f8, 0x97b57807dd3c, 0x980eadeaa75a,
+    0x98ccfebd679a, 0x98d99377a4ed, 0x9a721d45e54f, 0x9a8bf6677144,
+    0x9ad563bdad49, 0x9af988033cdf, 0x9b81a26da5be, 0x9bb7d8d5fd1f,
+    0x9d6cfa7a0eb2, 0x9d969c2828c0, 0x9df290bf381a, 0x9f51eae1d61a,
+    0x9f7aa253d914, 0x9ffd79e4bd40, 0xa008af44d7f5, 0xa064a3dbe74f,
+    0xa0d1c059a760, 0xa1378af6aed1, 0xa1ff3cb15b9e, 0xa2ba9440d0dd,
+    0xa4155b36fb79, 0xa4668f8bfba8, 0xa471ff7b1c22, 0xa535cdb666da,
+    0xa56611982839, 0xa5acfac924c7, 0xa67b4eb778e5, 0xa6ac42464b93,
+    0xa73755f3ff73, 0xa74e35d24067, 0xa7b5253a64b1, 0xa8039adb1fa4,
+    0xa852107bda97, 0xa8e0cd19eac7, 0xa8eaddaee8a3, 0xa94747640387,
+    0xa9d937d4647d, 0xaa17353b8008, 0xaa65e56b40c0, 0xab2b881eb9a0,
+    0xab60249de89e, 0xac48171ec2ec, 0xad16a59c1ccf, 0xadc0d544e157,
+    0xaddc484f95af, 0xae27ff3c0b66, 0xaeac36271230, 0xaf871ecfa3a1,
+    0xafacdcfe5b9a, 0xafca5f1043df, 0xb080ae553a30, 0xb0b251911e2d,
+    0xb0c881c24dd2, 0xb1f33f65bcd4, 0xb22174404a46, 0xb2355adb4039,
+    0xb2a1528de371, 0xb2dc56b1b3fb, 0xb2e5427b94fe, 0xb31b03c5e0d5,
+    0xb38477534496, 0xb3926b67a487, 0xb4f324e46525, 0xb4fa76c51dc5,
+    0xb507f5bb722c, 0xb6954a2997d9, 0xb6f2638bc40c, 0xb70c021e4a3c,
+    0xb72b9337666e, 0xb7314b2ef6ab, 0xb767bc2653d1, 0xb770a7f034d4,
+    0xb7f2cfd407b1, 0xb7fb467fdd2a, 0xb8b24571e4ca, 0xb8dbac90f913,
+    0xb9c20528aafe, 0xbabaaa722a79, 0xbbc59c6d7784, 0xbc3f4da574e8,
+    0xbc48396f55eb, 0xbca72749b046, 0xbd4a0511bc2e, 0xbda07c404110,
+    0xbdafcfaec39f, 0xbdcc678494d0, 0xbe2737508751, 0xc18470c0619a,
+    0xc218aac6fc42, 0xc2b29cc52727, 0xc39377f44e9a, 0xc41181c9b99d,
+    0xc427776be37d, 0xc468e3345593, 0xc4b6a927ff37, 0xc518cad4aa58,
+    0xc64d23ef0bac, 0xc6bb6537e896, 0xc6dc903a2d2b, 0xc8083819b341,
+    0xc859a6fdb935, 0xc9687c786855, 0xc98f9a0142ec, 0xc9c102ae2124,
+    0xca218a71a3e2, 0xca339c946bad, 0xca6714487dd2, 0xca75b809ef12,
+    0xcae13a9e86c0, 0xcb68a55bde50, 0xcb6a79d40c78, 0xcba33461a350,
+    0xcbd845feddd8, 0xcce3728930a8, 0xcd46b900f8a2, 0xcd809859ac53,
+    0xce054462bea7, 0xce1a4fc8d173, 0xcead9f935507, 0xced82b7d8629,
+    0xcf93f82b06f2, 0xcfbc3a7efe62, 0xcfcbc87c86b6, 0xcff5a4b9a689,
+    0xd0766d4356c8, 0xd07a50c2b8dd, 0xd0975db69598, 0xd158a7cca0d9,
+    0xd1da95216df1, 0xd1e5ca8188a6, 0xd1ea231ef645, 0xd211f054e22b,
+    0xd23feaa069d8, 0xd2815668dbee, 0xd3a70bc1cc02, 0xd449aefad225,
+    0xd4a235308af4, 0xd4d7815ccb41, 0xd53c272eb5d9, 0xd563b9d59bfa,
+    0xd5c2e23efc1a, 0xd5e865deae4e, 0xd6191ede7b37, 0xdaaa76d9b10f,
+    0xdafafb819fef, 0xdb1b01b8c7ab, 0xdc61e213fc54, 0xdc71701184a8,
+    0xdc8eb7946728, 0xddc4aa97f0df, 0xddf6c2f1e066, 0xde6df0049e53,
+    0xdedfda3dd78d, 0xdf4c819d8c14, 0xdf6b630996f7, 0xe09e223acfe8,
+    0xe0b6d6913f04, 0xe0c99861181e, 0xe165d3f57cb5, 0xe518d4e6ca91,
+    0xe5fe43426568, 0xe681ca805ae3, 0xe691587de337, 0xe6ba0fefe631,
+    0xe7982c6ac868, 0xe7e5b7cf6c47, 0xe7e92630c2d2, 0xe858c6d3c25a,
+    0xe8d6961a2798, 0xe8fa4541aba4, 0xe91493814323, 0xe92371d1ba28,
+    0xe940b9549ca8, 0xe9f02bd6e5e3, 0xeb553df11420, 0xec45a71dc3e7,
+    0xecdeaedfd7b8, 0xedbe9fd2e817, 0xee1b441708c0, 0xee3bf9fb41cb,
+    0xee9a38288ad7, 0xefb39356435d, 0xefdab0df1df4, 0xf15284c9254b,
+    0xf187d0f56598, 0xf2653dc33680, 0xf2c0bd3c3a50, 0xf2d0fae6d3f3,
+    0xf3194371f31f, 0xf333cc409063, 0xf37cc478c0de, 0xf3e63806249f,
+    0xf42d5bc626f2, 0xf431eef29a56, 0xf4773e3a6e81, 0xf499c896d5b4,
+    0xf52c2e254234, 0xf619637fa135, 0xf686ba8c670b, 0xf8ca5e3f8309,
+    0xf960e1dc5763, 0xf96958882cdc, 0xf9eb45dcf9f4, 0xfa0b86a32775,
+    0xfa3771e77b35, 0xfa7cfbbe5525, 0xfaf0ba6fbc87, 0xfb99ffdc69fb,
+    0xfbdfc44249b0, 0xfde89e609ae9, 0xfeb383ed987c, 0xff240eccaf18,
+    0xffc8fb9beeed, 0x00b19dc9da8a, 0x03d064b48dbe, 0x0433e5bb5b7d,
+    0x04f7b3f6a635, 0x06e3bbb02078, 0x072686d2b52c, 0x07778098af96,
+    0x0812d1f0fd19, 0x084c019c9f7b, 0x093b0b6f2ca4, 0x0976bf400e7d,
+    0x09e6d501198f, 0x09ec17da9e42, 0x0a53b6efd3db, 0x0aaec14acc21,
+    0x0afd36eb8714, 0x0c88f170845e, 0x0d31c1bf2648, 0x0d75771dd210,
+    0x0da71a59b60d, 0x0e25994d2c9a, 0x0ed162df1985, 0x0ef427ca867d,
+    0x0f08838387fa, 0x0f18869f1bd8, 0x0f6aa530331b, 0x106e7fd9cd4b,
+    0x10ec89af384e, 0x11c319ba5c20, 0x125c5c0b75b6, 0x126b3a5becbb,
+    0x131061ba3255, 0x15188c2b723f, 0x1608803a167c, 0x164b10cda56b,
+    0x16a94efaee77, 0x180415f11913, 0x1849da56f8c8, 0x188ad1015f54,
+    0x193157b9c78c, 0x19c7a0c79621, 0x19c9afceca0e, 0x1ac8822de550,
+    0x1b2f7196099a, 0x1bec9d9dad01, 0x1bf255953d3e, 0x1d17d05f278d,
+    0x1d985e59d207, 0x1da184b2b8cf, 0x1ddfbca8da1f, 0x1decc68122fc,
+    0x1ea350551f12, 0x1f16d47780af, 0x1f2ffdebfb55, 0x1f7f9857d321,
+    0x1fa222b43a54, 0x1fbfa4c62299, 0x207f54f30577, 0x2124b6e050d6,
+    0x214bd4692b6d, 0x21a0b1ae87ec, 0x222264744f3f, 0x227b9a57195d,
+    0x229364717165, 0x22df1b5de71c, 0x22fb0386a6fe, 0x2378983e0677,
+    0x238651c360a3, 0x23e95dac22d8, 0x24ebd8fb9a6a, 0x25533d81ca3e,
+    0x26750f5b583d, 0x271911ee80fe, 0x2763de9edfa1, 0x27d7d7df4cc8,
+    0x27fbc195d699, 0x280caeed818b, 0x2832a7ab3f49, 0x283b1e5714c2,
+    0x29030aa0c754, 0x2906b39123a4, 0x297cf667ca7d, 0x29b11dc8edf1,
+    0x29dea2f66a14, 0x2a13b493a49c, 0x2a1c65ce7fda, 0x2abc4a5340c1,
+    0x2aed78711934, 0x2ba736176610, 0x2c8ca47300e7, 0x2d0cbd4f9fd7,
+    0x2d75bbbef80e, 0x2d7a897a7137, 0x2d8549bc8062, 0x2e8f16ecb094,
+    0x2eb634758b2b, 0x2f694fe830b6, 0x2f891b9052ad, 0x304d5ee9a8ef,
+    0x30a918f1b284, 0x30d8ad266294, 0x317cea48911a, 0x318f7189646f,
+    0x31bd6bd4ec1c, 0x31ca008f296f, 0x3358b45771ba, 0x335bad9abcbb,
+    0x34c5c7ff69e6, 0x34f13e25b21c, 0x353b95b80535, 0x36f49adb78dd,
+    0x39c90a33d8f8, 0x3b678688af5c, 0x3b8961380540, 0x3bdb453a16be,
+    0x3c029d51f71a, 0x3c8ed5cac7d3, 0x3caa48d57c2b, 0x3cfd51a2aa82,
+    0x3d4faac2c78a, 0x3d8aaee69814, 0x3dbcc740879b, 0x3f5675d9e4d6,
+    0x40c7e21f4aa1, 0x417a1355d918, 0x42a0b2eae040, 0x42f7d9c67671,
+    0x432b517a8896, 0x439e9b0de46e, 0x4410bfd6236d, 0x44208862b186,
+    0x4532cc3eb731, 0x46b5d588d93d, 0x46cd65142b80, 0x4735ee65782d,
+    0x475669baab73, 0x484ba0a2d463, 0x48b638fb54fd, 0x48c8c03c2852,
+    0x48cb09d26204, 0x48d0873aec7c, 0x4916fb4ddd80, 0x4a6ae5815b06,
+    0x4b0427d2749c, 0x4b172431537b, 0x4c34d7fc79a0, 0x4c54a3a49b97,
+    0x4c5f63e6aac2, 0x4c7b4c0f6aa4, 0x4cc110754a59, 0x4d169d67b827,
+    0x4d7b7dc8a884, 0x4dbc74730f10, 0x4dc859803b14, 0x4dd6c2b2a68f,
+    0x4e33a185ccfd, 0x4ea30799c6c0, 0x4ef1081c7629, 0x4f79d233f057,
+    0x4fad0f58fcb7, 0x4fc1a5a103f9, 0x4feca6a940a5, 0x50635e9df308,
+    0x50a071c8f77f, 0x50dd0fd5f06c, 0x510cde99a641, 0x51ebaac199c7,
+    0x5292e127134e, 0x52d86afded3e, 0x536a5b6e4e34, 0x53a81e4663fa,
+    0x55d25df5ff8d, 0x55e978634646, 0x5615d8c5a590, 0x5657f43b28f5,
+    0x569541f53331, 0x56fbbc3f4bf1, 0x573ced78b842, 0x573fac2cfd7e,
+    0x57d1d72c6439, 0x58351da42c33, 0x58b18d906ed3, 0x592dfd7cb173,
+    0x5946ec622654, 0x5abd26630548, 0x5acc04b37c4d, 0x5b645cc87ecf,
+    0x5b7c9c00e261, 0x5c2da86c53ff, 0x5c7666157eb5, 0x5cc8bf359bbd,
+    0x5cf3c03dd869, 0x5dd12d0ba951, 0x5e3389475a37, 0x5e5e8a4f96e3,
+    0x5e67eb378370, 0x5ebb2e93b78c, 0x5eebad047eb0, 0x5f1ca093515e,
+    0x5f3888bc1140, 0x5f5f3126e04d, 0x5fe74b91492c, 0x5fef4d1f131b,
+    0x60b64f2cae99, 0x60ebd5e7f4ab, 0x612be2564423, 0x613d7f5b0064,
+    0x6157587c8c59, 0x618761cf47f3, 0x61e0d24117d6, 0x62569ff9b325,
+    0x62e179186140, 0x62f9f2dfca97, 0x635363519a7a, 0x63b33b680be9,
+    0x64c99d52796e, 0x64ffd3bad0cf, 0x6554eb8f3313, 0x656563c8d27b,
+    0x65bc501562e7, 0x664c6c0d95b5, 0x666d5c80d485, 0x6739a167f4b6,
+    0x67d2a92a0887, 0x67ede1a5b71a, 0x681fbf70a0dc, 0x68f9bddd1b39,
+    0x6a815a53b0a9, 0x6a9f8c12aa3d, 0x6b51bd4938b4, 0x6b81517de8c4,
+    0x6bedbe4e9786, 0x6c8b93cc2480, 0x6d7ad82db76e, 0x6d95266d4eed,
+    0x6e1579d8f3a2, 0x6e9607d39e1c, 0x6e9eb90e795a, 0x6ee52d216a5e,
+    0x6f450537dbcd, 0x70fce590329c, 0x71770be63b8a, 0x72476edbc395,
+    0x7290a1a2f9d5, 0x7302514d2d4a, 0x736c39f89c95, 0x73985fcbf61a,
+    0x75018ff48c31, 0x75b97922aae5, 0x75df375162de, 0x7601c1adca11,
+    0x76b88610cbec, 0x76c79ef048b6, 0x76e387190898, 0x775327bc0820,
+    0x793cab5042ec, 0x79d453b8341f, 0x7a8c02574d0e, 0x7ab8282aa693,
+    0x7b4bed1335b1, 0x7b9f306f69cd, 0x7bd5a166c6f3, 0x7c17824d4493,
+    0x7de4337ca869, 0x7e6bd8c905be, 0x7f63ce6573ea, 0x7f840f2ba16b,
+    0x7fcf8b89115d, 0x8034a679077f, 0x808440e4df4b, 0x8158fc77d4f5,
+    0x8236de63b167, 0x83c432d1d714, 0x8486dc4204f3, 0x85e8ba89e26a,
+    0x861a5dc5c667, 0x8772db25b751, 0x8800e816b632, 0x88150940b1ea,
+    0x885675092400, 0x88a0577d6b8f, 0x88ad6155b46c, 0x88dcf58a647c,
+    0x89b8c86f0d01, 0x89f47c3feeda, 0x8acf2a597a86, 0x8bbbb006c838,
+    0x8c56c6d00ff6, 0x8caba4156c75, 0x8cb2bb671f50, 0x8d4c72d64470,
+    0x8d7146c8e555, 0x8d9446435812, 0x8dd5025eb8d9, 0x8e39a830a371,
+    0x8ef65f1a3b4e, 0x90191b2fe061, 0x91052bbf2289, 0x91235d7e1c1d,
+    0x920ef8ef52bb, 0x923e5294fd06, 0x93129909e726, 0x9380da52c410,
+    0x949a700f825b, 0x953a54944342, 0x953dc2f599cd, 0x95467430750b,
+    0x955c2f439926, 0x9560fcff124f, 0x95651b0d7a29, 0x957ce527d231,
+    0x95859662ad6f, 0x96153d3cd4b3, 0x96775ee97fd4, 0x968d548ba9b4,
+    0x96cadcd4b9b5, 0x980a6b4f35be, 0x99494a1ca078, 0x9a08100d6c42,
+    0x9ab44ebd64b7, 0x9b7314ae3081, 0x9b7dd4f03fac, 0x9c3bb0a4f462,
+    0x9dc103855020, 0x9e1cf81c5f7a, 0x9e68e997daf6, 0x9ef3130977c2,
+    0x9f4b24212507, 0x9f4d6db75eb9, 0xa1a35e1c4847, 0xa37acf8dbb48,
+    0xa3b6bdeda2e6, 0xa468ef24315d, 0xa6e1a47487e3, 0xa7556325ef45,
+    0xa8910e210939, 0xa8e32cb2207c, 0xa931a252db6f, 0xa9e61d1fa398,
+    0xaa0ed491a692, 0xaadb8e96d24d, 0xaaf860fba943, 0xab2aee73a454,
+    0xac627b60566e, 0xac7c5481e263, 0xacad4810b511, 0xacef28f732b1,
+    0xad34ed5d1266, 0xae476bc81dd6, 0xaf23ee59d7aa, 0xb000365c8bb9,
+    0xb042c6f01aa8, 0xb08d59117386, 0xb1186cbf2766, 0xb151274cbe3e,
+    0xb19258862a8f, 0xb1d732aff330, 0xb1e09397dfbd, 0xb2495778322f,
+    0xb28580671f92, 0xb2ae37d9228c, 0xb2e433b27428, 0xb3adb9e54f1d,
+    0xb46443b94b33, 0xb47e91f8e2b2, 0xb48917abec18, 0xb4c4cb7ccdf1,
+    0xb50d1407ed1d, 0xb5a6cb77123d, 0xb6584d008f65, 0xb6f7bc6744c2,
+    0xb83cc84a4b43, 0xb8745e0cc542, 0xb8d34be71f9d, 0xb936cceded5c,
+    0xb94ed1974b29, 0xb950a60f7951, 0xb9d97026f37f, 0xba656e10be73,
+    0xbab8b16cf28f, 0xbb0518067995, 0xbb1b0da8a375, 0xbb5fad436651,
+    0xbba95528a81b, 0xbbd1d20ba550, 0xbbe6a2e2b257, 0xbc0cd62f75da,
+    0xbc5e45137bce, 0xbcb0d8c29e9b, 0xbd929e2ddd22, 0xbd9fe2952bc4,
+    0xbda4b050a4ed, 0xbdfa3d4312bb, 0xbe99aca9c818, 0xbf7e30c94bdb,
+    0xbf9510a78ccf, 0xc006c051c044, 0xc0427422a21d, 0xc083303e02e4,
+    0xc0e72662dc2d, 0xc166ca216f93, 0xc26094360be7, 0xc35d1cfeed77,
+    0xc3efbd1c5fbc, 0xc4b2668c8d9b, 0xc5241636c110, 0xc636cf30d245,
+    0xc651cd1d7b13, 0xc6783af9445b, 0xc79db5c32eaa, 0xc7ab6f4888d6,
+    0xc7bc5ca033c8, 0xc8293e8eee14, 0xca6a98abd060, 0xca9908156397,
+    0xcca42bc9ee82, 0xcd1b1e4da6aa, 0xcd22702e5f4a, 0xcd645114dcea,
+    0xcd9afc9b3fd5, 0xcdae6e182a3e, 0xce231705a8b4, 0xcec370a87525,
+    0xcf53c72fadb8, 0xd069b3fc0fb3, 0xd081438761f6, 0xd28eeb612c58,
+    0xd2a1e7c00b37, 0xd2afdbd46b28, 0xd2b384c4c778, 0xd430d6175947,
+    0xd47c8d03cefe, 0xd495f1074f69, 0xd5eea8f64618, 0xd670964b1330,
+    0xd677e82bcbd0, 0xd723772eb2f6, 0xd7a85dc6cb0f, 0xd7cfb5deab6b,
+    0xd7dc4a98e8be, 0xda6e9e7bc574, 0xdb4ddfc1c484, 0xdd5c3748a035,
+    0xde86ba5d0972, 0xde93fec45814, 0xe151c8cd7d00, 0xe1d8494ebd7c,
+    0xe1fb48c93039, 0xe28487feb5f1, 0xe348563a00a9, 0xe3b65cf3d7ce,
+    0xe3f7c8bc49e4, 0xe44d55aeb7b2, 0xe4cf7d928a8f, 0xe6480129a335,
+    0xe93106ca0a92, 0xe94612301d5e, 0xeab9185eab8c, 0xebdb24c73f50,
+    0xed1b285fc6e3, 0xee477fec5e48, 0xef0f6c3610da, 0xef58d98c4cdf,
+    0xf1759a45940b, 0xf18ec3ba0eb1, 0xf20e2ce99c52, 0xf2b095939cb0,
+    0xf317fa19cc84, 0xf39ca622ded8, 0xf470b208c333, 0xf492179a0d8d,
+    0xf4d1aeea517b, 0xf5aafda9ba89, 0xf62fa9b2ccdd, 0xf74ad958b38b,
+    0xf761b936f47f, 0xf779f86f5811, 0xf7a275525546, 0xf84d54a82b1d,
+    0xf93e32f2e66e, 0xf957d1856c9e, 0xfa8b05d4b119, 0xfa8fd3902a42,
+    0xfaeaddeb2288, 0xfb1d30d417d4, 0xfc09f1106b4b, 0xfc3ae49f3df9,
+    0xfd8de496a46b, 0xfda2b56db172, 0xfe99863f02c5, 0xff349d084a83,
+    0xff9e85b3b9ce, 0x0116cebbccaf, 0x026b689c5b84, 0x043227452958,
+    0x0452680b56d9, 0x045ec2368e67, 0x046689355291, 0x057aa1898664,
+    0x05a149f45571, 0x05f71175c904, 0x06616f3f43d9, 0x067fdb8d4332,
+    0x082116965ed2, 0x08aa55cbe48a, 0x0b91c1832384, 0x0cf574432f23,
+    0x0d58babaf71d, 0x0d72594d7d4d, 0x0d72ce6b88d7, 0x0fbf98778b9d,
+    0x0fc6afc93e78, 0x0fe93a25a5ab, 0x0fffdf74e0da, 0x100472a1543e,
+    0x10f58b7b1554, 0x11058e96a932, 0x11ab2b12fa56, 0x11cdb56f6189,
+    0x1219a6eadd05, 0x130d095ad7cd, 0x1377a1b35867, 0x14b93f35085d,
+    0x14c907c19676, 0x150187c02789, 0x158c60ded5a4, 0x15a7243c78ad,
+    0x15e01959154a, 0x165f0d6a9761, 0x16ea21184b41, 0x1859b8e582e4,
+    0x191460c7e6d4, 0x19f451baf733, 0x1a5dc5485af4, 0x1b93089ed35c,
+    0x1c72f991e3bb, 0x1c8ac3ac3bc3, 0x1ebcca5a9b80, 0x1f2c6afd9b08,
+    0x1f5104613628, 0x1f7f73cac95f, 0x20c39571b8cc, 0x2156358f2b11,
+    0x21a903cd53a3, 0x22bd1c218776, 0x22f5619112c4, 0x23436213c22d,
+    0x2388b15b9658, 0x2426c1682917, 0x25084c4461d9, 0x253521c4ccad,
+    0x257b20b9b227, 0x25f12901533b, 0x260fcfde5859, 0x2683c91ec580,
+    0x28896f6ac5f3, 0x294ab980d134, 0x29ebfd5fb4b9, 0x2a0bc907d6b0,
+    0x2a4707baacff, 0x2a486714cf9d, 0x2aad0ce6ba35, 0x2ad4da1ca61b,
+    0x2ae84b999084, 0x2b689f053539, 0x2c4c7377a7ad, 0x2c52a08d4374,
+    0x2c84f37638c0, 0x2ea6475bf350, 0x30d8137b4d48, 0x318af45eed0e,
+    0x3263937144cd, 0x32eea71ef8ad, 0x32f5be70ab88, 0x3313408293cd,
+    0x336f3519a327, 0x3655f123d0d2, 0x36bb46a2ccb9, 0x36cb844d665c,
+    0x37423c4218bf, 0x37a9db574e58, 0x38452caf9bdb, 0x3c91aa810912,
+    0x3ccf32ca1913, 0x3de55a2580d3, 0x3e252c04ca86, 0x405523abf656,
+    0x422ac0a53b2f, 0x42c611fd88b2, 0x430e9517ada3, 0x43692a549a5f,
+    0x444b9f6cea35, 0x44b4634d3ca7, 0x45159abdd0b4, 0x45ccd43ede19,
+    0x45eb05fdd7ad, 0x467c46c12754, 0x46d199248f5d, 0x47200ec54a50,
+    0x472b7eb46aca, 0x4876f23c12d7, 0x48ef098ae7d8, 0x491af4cf3b98,
+    0x49972a2c7873, 0x49e5da5c392b, 0x4b8f16f31eba, 0x4bd42babed20,
+    0x4c8ab57fe936, 0x4cb6a0c43cf6, 0x4da95387266f, 0x4eb89e1fe119,
+    0x4fb6c0d1eb0c, 0x51420638dccc, 0x51dca7e41900, 0x51dce2731ec5,
+    0x52c584a10a62, 0x53798a4fc701, 0x54e7c2c2dc06, 0x561579a99609,
+    0x56375458ebed, 0x56be84873db8, 0x571277908323, 0x5738e56c4c6b,
+    0x584a799b40c7, 0x58bc2945743c, 0x5985af784f31, 0x598624965abb,
+    0x5a00105d5de4, 0x5a38905beef7, 0x5b1930fc10a5, 0x5b35c8d1e1d6,
+    0x5cc4f1b835ab, 0x5e3d3ac0488c, 0x5ebeb2f70a1a, 0x5f9afaf9be29,
+    0x5fbcd5a9140d, 0x61b02f4346f0, 0x6217ce587c89, 0x6368bf48af0e,
+    0x64904919cd4a, 0x64daa0ac2063, 0x65b16b4649fa, 0x65fb132b8bc4,
+    0x6670313715c4, 0x66f1344fcbc8, 0x68bff486638b, 0x68f491059289,
+    0x6930f48385b1, 0x69bc42c03f56, 0x6a0b2d7f05d3, 0x6aeb939021bc,
+    0x6b3f4c0a6162, 0x6bf1b7cff59e, 0x6c3e58f88269, 0x6c4ff5fd3eaa,
+    0x6c63a2092ed8, 0x6cd7d5d8a1c4, 0x6dd8f1cdf6b8, 0x6e37a5194b4e,
+    0x6ecc8eccf745, 0x6ed8ae69290e, 0x6f41acd88145, 0x6f5349dd3d86,
+    0x6f8b54bdc30f, 0x6fa94bedb6de, 0x6fb4f66bdd1d, 0x6fcf7f3a7a61,
+    0x6ff6d7525abd, 0x70e272c3915b, 0x7129968393ae, 0x71a0fe255760,
+    0x7207786f7020, 0x727ea5822e0d, 0x730e86eb5b16, 0x7342e8db844f,
+    0x738b6bf5a940, 0x7482ec740be2, 0x748e96f23221, 0x7490a5f9660e,
+    0x74a6267d8464, 0x74b1214e9954, 0x75460b02454b, 0x7589faeff6d8,
+    0x75a0a03f3207, 0x75ef15dfecfa, 0x77a178cfb951, 0x7999dab46b22,
+    0x79d5c91452c0, 0x7a03c35fda6d, 0x7a14b0b7855f, 0x7a3b1e934ea7,
+    0x7a6cc1cf32a4, 0x7b36bd201923, 0x7b48cf42e0ee, 0x7b6b1f10425c,
+    0x7bdafa4247a9, 0x7c12ca93c76d, 0x7c1589480ca9, 0x7c54365c3983,
+    0x7ca9c34ea751, 0x7d5a200d07a0, 0x7ddbd2d2cef3, 0x7e0cc661a1a1,
+    0x7e3be5784627, 0x7e5f1f81bea9, 0x7e7a1d6e6777, 0x7f094f2a8331,
+    0x801c42b39a2b, 0x80b4603996e8, 0x81e3767a7389, 0x82a276fa4518,
+    0x82fd0c3731d4, 0x835816922a1a, 0x83b863c6a713, 0x83d77fc1b7bb,
+    0x84371d492365, 0x84454bec891b, 0x851b2c4a9b9e, 0x853d4188f747,
+    0x8712de823c20, 0x87d219911374, 0x8872adc2e5aa, 0x89b98e1e1a53,
+    0x89c413d123b9, 0x8aa404c43418, 0x8adb2568a28d, 0x8ae2022b4fa3,
+    0x8b324c4438be, 0x8bc82033fbc9, 0x8c1919f9f633, 0x8df5ce44ede7,
+    0x8e10cc3196b5, 0x8ef5fffe2bc7, 0x8f0469309742, 0x8f694991879f,
+    0x907943d75398, 0x91099a5e8c2b, 0x91ecf9b2f315, 0x92241a57618a,
+    0x92a3be15f4f0, 0x92f89b5b516f, 0x944a01698f7e, 0x9463da8b1b73,
+    0x94d8f896a573, 0x964388195e28, 0x99e6fb0d23b0, 0x9c44ecffd72d,
+    0x9f132f429b81, 0x9f383dc4422b, 0x9f8a96e45f33, 0xa055f18f6850,
+    0xa14c87d1b3de, 0xa19a133657bd, 0xa23fea41aea6, 0xa337dfde1cd2,
+    0xa405841f5fa1, 0xa580514cb1f9, 0xa6bb8729c063, 0xa7014b8fa018,
+    0xa778b33163ca, 0xa784983e8fce, 0xa793768f06d3, 0xa923c4407781,
+    0xa93cedb4f227, 0xaa388c41bca3, 0xaa664bfe3e8b, 0xaa98d976399c,
+    0xab69ec18d2f6, 0xab7730802198, 0xabbe8ecf29b0, 0xac6799acd15f,
+    0xae5a090aed2e, 0xae87191a5dc7, 0xaf1a2e55db96, 0xaf5189894fd0,
+    0xaf9be11ba2e9, 0xb02c7231e141, 0xb061f8ed2753, 0xb0dfc8338c91,
+    0xb1681d2cfb35, 0xb1a5e00510fb, 0xb1a6ca41280f, 0xb2003ab2f7f2,
+    0xb2a14402d5b2, 0xb4dce62827c1, 0xb50981198cd0, 0xb537f0832007,
+    0xb5c7975d474b, 0xb5dbb8874303, 0xb781119ec67d, 0xb81e71fe47ed,
+    0xb86b1326d4b8, 0xb96726d1aabe, 0xbac31292f233, 0xbbc97161cbda,
+    0xbd03f791c8f5, 0xbd402080b658, 0xbd464d96521f, 0xbd5da2929e9d,
+    0xbd8ed0b07710, 0xbe94f4f04af2, 0xbed74af4d41c, 0xbf651d56cd38,
+    0xbff11b40982c, 0xc0378f538930, 0xc22655c148af, 0xc24696877630,
+    0xc2b2191c0dde, 0xc2dbf5592db1, 0xc3aa494781cf, 0xc3f6ea700e9a,
+    0xc467754f2536, 0xc6955def1d19, 0xc6b180a6e2c0, 0xc6c1be517c63,
+    0xc7d59c16aa71, 0xc8d4e393d13d, 0xc9a078cde01f, 0xc9d3b5f2ec7f,
+    0xca7c4bb288a4, 0xca85ac9a7531, 0xcc396ee46426, 0xcd020adb2807,
+    0xcd387bd2852d, 0xcf28a19a674a, 0xd06a3f1c1740, 0xd06c13944568,
+    0xd177058f9273, 0xd1e92a57d172, 0xd286ffd55e6c, 0xd2ea464d2666,
+    0xd46cda793ce8, 0xd4a3c08ea598, 0xd5c6f1c25635, 0xd7618a97ca84,
+    0xd9aec9c1d8d4, 0xd9e3db5f135c, 0xdac5a0ca51e3, 0xdbb3faefcdbd,
+    0xdcadff936fd6, 0xdcefa5eae7b1, 0xdd706e7497f0, 0xddac5cd47f8e,
+    0xde14ab96c676, 0xde4230c44299, 0xde4fea499cc5, 0xe06ce591e9b6,
+    0xe105ed53fd87, 0xe41bc874cfb8, 0xe487c02772f0, 0xe5497f5b89bb,
+    0xe56fed375303, 0xe5c2bb757b95, 0xe66e0fe95cf6, 0xe67d6357df85,
+    0xe6b4496d4835, 0xe7897a1e4969, 0xe7bfeb15a68f, 0xe7ec4b7805d9,
+    0xe814533cf784, 0xe8a4e45335dc, 0xe9812c55e9eb, 0xe98c9c450a65,
+    0xeb426d962d47, 0xec8ecb59ec68, 0xed9773beffc1, 0xedfed8452f95,
+    0xefc43793dacb, 0xf3313990432d, 0xf43cdb38a187, 0xf45bf733b22f,
+    0xf45ca6e0c37e, 0xf837af970305, 0xf8736367e4de, 0xf8e9e0cd917c,
+    0xfae5eba29f9d, 0xfc7b7c2d94fe, 0xfdd3f98d85e8, 0xfded5d910653,
+    0xfedeb0f9cd2e, 0xfee6b287971d, 0x026dc8349139, 0x03144eecf971,
+    0x0372528b3cb8, 0x03a58fb04918, 0x050091357979, 0x0586d727b430,
+    0x0732d272defb, 0x081e33550fd4, 0x08dcbeb6d5d9, 0x09199752d48b,
+    0x0a43302b26b4, 0x0b7ea0973ae3, 0x0bc54f3931ac, 0x0bd811090ac6,
+    0x0d80d881e4cb, 0x0d889f80a8f5, 0x0f3c273b9225, 0x105d0ed90910,
+    0x10e2dfad383d, 0x12fb8258178f, 0x13c6a2741ae7, 0x145b5198c119,
+    0x185af3b29bc0, 0x18e7dbd87dc8, 0x1a8aeb59c790, 0x1b0377c6a81b,
+    0x1b7268bc9654, 0x1f669ae75081, 0x20222d05cb85, 0x21334c16b457,
+    0x21861a54dce9, 0x21f78f700a99, 0x22b7050de7b2, 0x23b22e7ca6a4,
+    0x24d81e649c7d, 0x254416173fb5, 0x2559d12a63d0, 0x25deb7c27be9,
+    0x2632aacbc154, 0x265918a78a9c, 0x2818fa8dab5a, 0x2895df97f984,
+    0x295bf7697dee, 0x29bd2eda11fb, 0x2d51fe0c6643, 0x2d8575c07868,
+    0x2da78afed411, 0x2dadb8146fd8, 0x2edd43735803, 0x2f2725e79f92,
+    0x307caa04457b, 0x3133e38552e0, 0x31c4e9b99cc2, 0x32e93fb86a38,
+    0x345569244b50, 0x352c33be74e7, 0x356772714b36, 0x361f5b9f69ea,
+    0x37f53327b488, 0x38b299be5db4, 0x392aeb9c387a, 0x39e767f6ca92,
+    0x3a4aae6e928c, 0x3a8ffdb666b7, 0x3b067b1c1355, 0x3b5983e941ac,
+    0x3bf9a2fd0858, 0x3c38c52f40bc, 0x3c8fb17bd128, 0x3dd7b6a222aa,
+    0x3e02f239651b, 0x3f9a91cb8e69, 0x3fac2ed04aaa, 0x40b6e63c91f0,
+    0x4184c50cda84, 0x4186998508ac, 0x41e6719b7a1b, 0x423f326038af,
+    0x441b718d24d9, 0x4427912956a2, 0x453cce48a74e, 0x455a15cb89ce,
+    0x460a37fae458, 0x4786d9a064d8, 0x498f0411a4c2, 0x49e36c38f5b7,
+    0x4ad82e03131d, 0x4b4c27438044, 0x4b8f67842082, 0x4bfecd981a45,
+    0x4c118f67f35f, 0x4c766fc8e3bc, 0x4cb93aeb7870, 0x4cd17a23dc02,
+    0x4d094a755bc6, 0x4d21c43cc51d, 0x4e6aee2e3378, 0x4f0938c9cbfc,
+    0x4fa7f883700a, 0x50d91dcb8098, 0x510493f1c8ce, 0x5145157e23d0,
+    0x519b178e9d28, 0x51e8a2f34107, 0x53014e73e83e, 0x536bac3d6313,
+    0x53e55d756077, 0x548be42dc8af, 0x5495f4c2c68b, 0x54bdfc87b836,
+    0x54d4dc65f92a, 0x552c03418f5b, 0x555111c33605, 0x5689c37b04f8,
+    0x578cee778dd9, 0x5836a90246d7, 0x58621f288f0d, 0x58b3537d8f3c,
+    0x58dbd0608c71, 0x5a4784ae61ff, 0x5a5a467e3b19, 0x5bfc31346808,
+    0x5d322437f1bf, 0x5d5055f6eb53, 0x5dcc50c52269, 0x5e759631cfdd,
+    0x60a1aa599998, 0x61265662abec, 0x61d344bfb5b0, 0x62222f7e7c2d,
+    0x63d57caa5f98, 0x642dc85112a2, 0x6562d1188545, 0x6612f347dfcf,
+    0x67a6af5aa708, 0x67c55637ac26, 0x681b5848257e, 0x690f6a653195,
+    0x692560075b75, 0x69d8b60906c5, 0x69e92e42a62d, 0x6adc1b94956b,
+    0x6b254e5bcbab, 0x6c2c5cd7b6a1, 0x6db593377474, 0x6df85e5a0928,
+    0x6e327841c29e, 0x6e979331b8c0, 0x6f4c488d86ae, 0x6f6f0d78f3a6,
+    0x6f9f16cbaf40, 0x70004e3c434d, 0x700d1d858665, 0x7087094c898e,
+    0x72af7483f6f9, 0x72dc84936792, 0x73851a5303b7, 0x73a55b193138,
+    0x74a935c2cb68, 0x74f402732a0b, 0x75820f6428ec, 0x758c5a882c8d,
+    0x7671c8e3c764, 0x76874967e5ba, 0x76da52351411, 0x7730c96398f3,
+    0x7759f5f3a777, 0x775a6b11b301, 0x794b05f7a0a8, 0x79629582f2eb,
+    0x7a524f029163, 0x7afbcefe449c, 0x7b9c633016d2, 0x7c361a9f3bf2,
+    0x7cd1a6868f3a, 0x7d129d30f5c6, 0x7d4181b89487, 0x7e03b60ab6dc,
+    0x7ed5033c55fb, 0x7fa5db4fe990, 0x80855724ee65, 0x809f30467a5a,
+    0x80c529043818, 0x80d5dbccdd45, 0x810cfc714bba, 0x815cd16c294b,
+    0x81b816562756, 0x82d813b7872d, 0x83d427625d33, 0x8526028ea6cc,
+    0x86d3d251ffbf, 0x86de58050925, 0x88d9b32d05f7, 0x893cbf15c82c,
+    0x89523f99e682, 0x8a30d132d443, 0x8a343f942ace, 0x8b9b9b4492bd,
+    0x8c16abd6b2bf, 0x8cd98fd5e663, 0x8da6bef91da8, 0x8de481d1336e,
+    0x904691d24ec5, 0x9087135ea9c7, 0x90983b455a7e, 0x91874517e7a7,
+    0x922bf75821b7, 0x92b02e432881, 0x937bc37d3763, 0x9421d5179411,
+    0x9570b70092a9, 0x95f7ac9fdeaf, 0x968160f36ff1, 0x96a12c9b91e8,
+    0x970522c06b31, 0x979a0c741728, 0x97ad4361fbcc, 0x97d07d6b744e,
+    0x99756164ec3e, 0x99ae568188db, 0x99b8dc349241, 0x9a26e2ee6966,
+    0x9a53b86ed43a, 0x9ac6c7732a4d, 0x9b483fa9ebdb, 0x9b9c32b33146,
+    0x9c5715249afb, 0x9ce55ca49fa1, 0x9d774d150097, 0x9de9374e39d1,
+    0x9e3efecfad64, 0x9fd6d8f0dc77, 0xa1bec29beee0, 0xa22bdf19aef1,
+    0xa25be86c6a8b, 0xa3cf2929fe7e, 0xa523135d7c04, 0xa5e3e8557bbb,
+    0xa5e6e198c6bc, 0xa68232f1143f, 0xa6a482be75ad, 0xa6b8a3e87165,
+    0xa6cf83c6b259, 0xa6ec1b9c838a, 0xa75b81b07d4d, 0xa90b25ec0468,
+    0xa9712b18119e, 0xa9dad9347b24, 0xaaad10a23157, 0xab0a6493634f,
+    0xad32201dbf6b, 0xaf48ee507095, 0xafa2d3e04c02, 0xb069d5ede780,
+    0xb15d72ece80d, 0xb2390b428acd, 0xb265e0c2f5a1, 0xb274f9a2726b,
+    0xb2d2fd40b5b2, 0xb35cec234cb9, 0xb397f0471d43, 0xb4959ddb1bac,
+    0xb4d4c00d5410, 0xb5d9fa1110de, 0xb625b0fd8695, 0xb6c60aa05306,
+    0xb76922f764b3, 0xb7928a1678fc, 0xb84c824bcb9d, 0xb85c104953f1,
+    0xb9401f4acc2a, 0xb96c451e25af, 0xb9b920d5b83f, 0xbb6088f46fa6,
+    0xbcc1b78f3bce, 0xbd04f7cfdc0c, 0xbd348c048c1c, 0xbd6c21c7061b,
+    0xbfbac04b3709, 0xbfc1d79ce9e4, 0xc0f2c255f4ad, 0xc223ad0eff76,
+    0xc285942ca4d2, 0xc36d4c1e795b, 0xc3c682014379, 0xc3d1b7615e2e,
+    0xc42151cd35fa, 0xc52ae46e6067, 0xc54c49ffaac1, 0xc5a3e5f94c7c,
+    0xc67951395375, 0xc6b71411693b, 0xc7c3da84e46e, 0xc9fcbdf5f141,
+    0xca0693fbe958, 0xca3871c6d31a, 0xcae3c63ab47b, 0xcb3d36ac845e,
+    0xcc51898fbdf6, 0xcc8c189582f6, 0xcca6dbf325ff, 0xcdc15bebfb5e,
+    0xce885df996dc, 0xd0879ca0f5c3, 0xd1678d940622, 0xd1c6065054f3,
+    0xd32316dcb941, 0xd51217d97e85, 0xd53eed59e959, 0xd542212c3a1f,
+    0xd54345f756f8, 0xd6ffb97c212b, 0xdababbfb38f6, 0xdb393aeeaf83,
+    0xdc935237c8d0, 0xddd3905f5628, 0xde3ffd3004ea, 0xdf277a92d3ae,
+    0xe047b283394a, 0xe2c8696159bf, 0xe4793267fdb3, 0xe4dfe7411c38,
+    0xe4fcb9a5f32e, 0xe51b25f3f287, 0xe5783f561eba, 0xe6f8c47b014f,
+    0xe706b88f6140, 0xe8f5f41b2c49, 0xe92c6512896f, 0xe97731c2e812,
+    0xeb6f1e898e59, 0xecc468172e7d, 0xef26781849d4, 0xef66bf159f11,
+    0xefbc1179071a, 0xf070c6d4d508, 0xf1b5d2b7db89, 0xf3fd1f5b53d7,
+    0xf4c371bbde06, 0xf5eb70ab07cc, 0xf66437a6ee1c, 0xf6f74ce26beb,
+    0xf739dd75fada, 0xf77b0eaf672b, 0xf889e42a164b, 0xfb1ef6c1383d,
+    0xfbe165a26057, 0xfc764f560c4e, 0xfd529758c05d, 0xfd81f0fe6aa8,
+    0xff5509d2700a, 0xffcfa5468482, 0x0109413a6a89, 0x0189cf351503,
+    0x0208fdd59cdf, 0x024c3e163d1d, 0x043ead7458ec, 0x04c68d4fbc06,
+    0x08a2bad11866, 0x08d373d0e54f, 0x097fb280ddc4, 0x0ac3247abbe2,
+    0x0b6308ff7cc9, 0x0bb4b2728882, 0x0c248da48dcf, 0x0f07662f5965,
+    0x109b5cd12663, 0x114293369fea, 0x11efbc22af73, 0x1268f83ca14d,
+    0x1297dcc4400e, 0x1421c2d10f30, 0x15765cb19e05, 0x17505248507d,
+    0x1778949c47ed, 0x188819c4085c, 0x1889ee3c3684, 0x19044f214537,
+    0x199dcc016492, 0x1b3cf8034c45, 0x1be37ebbb47d, 0x1c24ea842693,
+    0x1cad3f7d9537, 0x1e6f6af9efa7, 0x1f03a5008a4f, 0x1f8f685b4f7e,
+    0x1feda688988a, 0x20833fe955d0, 0x211c47ab69a1, 0x21524384bb3d,
+    0x21899eb82f77, 0x21b8833fce38, 0x21f64617e3fe, 0x22cd10b20d95,
+    0x254292301355, 0x256d58a94a3c, 0x28a5f8b58965, 0x2a647b418785,
+    0x2ab5750781ef, 0x2b701ce9e5df, 0x2de772e019c7, 0x2f0cb31afe51,
+    0x3042e0ad8dcd, 0x32e0a47f8afd, 0x32f8340add40, 0x333aff2d71f4,
+    0x33dae3b232db, 0x360a663b5321, 0x36ccd51c7b3b, 0x3703bb31e3eb,
+    0x37fac6923b03, 0x38d7be420061, 0x38e243f509c7, 0x38e7fbec9a04,
+    0x39396ad09ff8, 0x3a5b021b2832, 0x3c1b93ae5a3f, 0x3d81557599cb,
+    0x3ddb75947afd, 0x3e12d0c7ef37, 0x3e8bd252db4c, 0x3f6ce8110884,
+    0x40307bbd4d77, 0x40d47e507638, 0x4133e148dc1d, 0x4197d76db566,
+    0x4198c1a9cc7a, 0x41aca844c26d, 0x427210693588, 0x42ab0585d225,
+    0x433b969c107d, 0x4392bd77a6ae, 0x442e495ef9f6, 0x44e8f1415de6,
+    0x453bfa0e8c3d, 0x458f3d6ac059, 0x45dfc212af39, 0x4727521af531,
+    0x482699981bfd, 0x48699f49b676, 0x497b3378aad2, 0x4b43517b9b44,
+    0x4b57e7c3a286, 0x4b773e4db8f3, 0x4ba86c6b9166, 0x4bec5c5942f3,
+    0x4ccb9d9f4203, 0x4d11620521b8, 0x4d45c3f54af1, 0x4d51e3917cba,
+    0x4feebd2762d6, 0x506a7d669427, 0x51c9626b269d, 0x5264041662d1,
+    0x53ec503a0990, 0x54a7a7c97ecf, 0x558514974fb7, 0x55b08abd97ed,
+    0x5681284225bd, 0x568c98314637, 0x56bacd0bd3a9, 0x5789d0a73916,
+    0x57f7624304b1, 0x58ad76f8f53d, 0x5984b6b12a5e, 0x59e32f6d792f,
+    0x5b350a99c2c8, 0x5c0657cb61e7, 0x5c44ca5088fc, 0x5c8a8eb668b1,
+    0x5c8d12dba828, 0x5d69cffc67c1, 0x5dd084d58646, 0x5e7a79ef4509,
+    0x5f5269548b79, 0x60517642ac80, 0x61e657209092, 0x6286eb5262c8,
+    0x62e31a7877e7, 0x62f0996ecc4e, 0x63fa669efc80, 0x64d37acf5fc9,
+    0x661eee5707d6, 0x6711668aeb8a, 0x672dfe60bcbb, 0x68487e59921a,
+    0x698ee996bb39, 0x6a762c6a8438, 0x6ac676836d53, 0x6bad7ec8308d,
+    0x6c9c137cb22c, 0x6d7b54c2b13c, 0x6dd95860f483, 0x6e6347438b8a,
+    0x6f33aa391395, 0x6f5ac7c1ee2c, 0x6fa3bffa1ea7, 0x70c67c0fc3ba,
+    0x734aa14f3aba, 0x7431e42303b9, 0x7585593875b5, 0x7652fd79b884,
+    0x778c244f9301, 0x797fb878cba9, 0x7cdaa8526c40, 0x7de3c5d58b23,
+    0x7f15603ba73b, 0x7f4a3749dbfe, 0x7fb6a41a8ac0, 0x7fed1511e7e6,
+    0x82f5e65a713a, 0x84448db46a0d, 0x847964c29ed0, 0x84f3c5a7ad83,
+    0x8548dd7c0fc7, 0x8550df09d9b6, 0x85a790c7645d, 0x85df6118e421,
+    0x8692076d7e22, 0x87ac124847f7, 0x87b11a92c6e5, 0x883c68cf808a,
+    0x8b05dd56cbb5, 0x8b72f9d48bc6, 0x8bbd5166dedf, 0x8c88ac11e7fc,
+    0x8d6f3f389fac, 0x8e840739e4ce, 0x900785a21264, 0x90095a1a408c,
+    0x90d2e04d1b81, 0x916b72f123c8, 0x91fbc9785c5b, 0x920b9204ea74,
+    0x93c84018ba6c, 0x9434e7786ef3, 0x94505a83234b, 0x94e2c0118fcb,
+    0x94ffcd056c86, 0x95d53845737f, 0x97a7dbfb6d57, 0x993d31f75cf3,
+    0x998edb6a68ac, 0x9a15215ca363, 0x9c3a1e32ba43, 0x9cc2adbb2eac,
+    0x9f703a19b9f5, 0xa43c9638c057, 0xa4f778aa2a0c, 0xa54788340d62,
+    0xa570b4c41be6, 0xa5828c57ddec, 0xa6f7dc1ca5cc, 0xa71b50b52413,
+    0xa7da8bc3fb67, 0xa930fa1cb864, 0xa9593c70afd4, 0xa9b7057fed56,
+    0xa9c39a3a2aa9, 0xab54227aa11c, 0xac20dc7fccd7, 0xac2b27a3d078,
+    0xac4242111731, 0xad414eff3838, 0xad8a473768b3, 0xaef720505b1a,
+    0xaf45d0801bd2, 0xafb9c9c088f9, 0xafd27e16f815, 0xb0c23796968d,
+    0xb2332ebdf0ce, 0xb30e51f58804, 0xb407e17b1e93, 0xb4540d859fd4,
+    0xb4b669c150ba, 0xb59b286fda42, 0xb5c579cb059f, 0xb6e53c9d5fb1,
+    0xb78ad919b0d5, 0xb79bc6715bc7, 0xb7d028618500, 0xb90655f4147c,
+    0xb94f139d3f32, 0xba0fe8953ee9, 0xba5cfedbd73e, 0xba8f51c4cc8a,
+    0xbb718c4e169b, 0xbb723bfb27ea, 0xbcf03cfacb08, 0xbe0714034417,
+    0xbe271a3a6bd3, 0xbe8e443195e2, 0xbec8d3375ae2, 0xbf04c1974280,
+    0xbf5371c70338, 0xbfb6b83ecb32, 0xbfd01c424b9d, 0xc131104e1200,
+    0xc1a285693fb0, 0xc1b50caa1305, 0xc1ba4f8397b8, 0xc283d5b672ad,
+    0xc48b8b09a70d, 0xc567987d5557, 0xc61cfd863494, 0xc63034741938,
+    0xc6c6f29ff357, 0xc918ff857ad0, 0xca467bdd2f0e, 0xca66f7326254,
+    0xca9aa9757a3e, 0xcb97a75c6758, 0xcba7aa77fb36, 0xcc78828b8ecb,
+    0xcd1916bd6101, 0xce53625e5857, 0xcebd4b09c7a2, 0xd0b8e0c0ca39,
+    0xd19b1b4a144a, 0xd27b815b3033, 0xd4e7dc804f2b, 0xd5786d968d83,
+    0xdad3c0e2a9da, 0xdad6f4b4faa0, 0xde2c2c970afa, 0xdfec0e7d2bb8,
+    0xe3648068b494, 0xe388df3d49ef, 0xe53fd55989aa, 0xe5dc4b7cf406,
+    0xe651dea68990, 0xe68ce2ca5a1a, 0xe6b55fad574f, 0xe7603f032d26,
+    0xe81235aab5d8, 0xe95ecdfd7abe, 0xe9915b7575cf, 0xec9892d4d6c0,
+    0xed06d41db3aa, 0xedc091c40086, 0xeffef29d97d1, 0xf17ec8156917,
+    0xf379ae1f5a5f, 0xf4e4783118d9, 0xf5acd998d6f5, 0xf6641319e45a,
+    0xf748221b5c93, 0xf8e838595b5a, 0xf8e997b37df8, 0xf96ca9d367e9,
+    0xf9d61d60cbaa, 0xfa68f80d43b4, 0xfab106095d1b, 0xfc2063478ef9,
+    0xfd0fa7a921e7, 0xfd4b20eafdfb, 0xfdd71ed4c8ef, 0xff33f4d22778,
+    0xffdf83d50e9e, 0x0016df0882d8, 0x007d59529b98, 0x03f72a984712,
+    0x051d550f42b0, 0x0616e494d93f, 0x082db2c78a69, 0x088b06b8bc61,
+    0x0909faca3e78, 0x0993748ec9f5, 0x0f391f6d3965, 0x0f42f573317c,
+    0x0f7632983ddc, 0x0f87950df458, 0x10c5899f47fe, 0x11fca16dee8e,
+    0x12130c2e23f8, 0x1316372aacd9, 0x13c7090718b2, 0x13ec8ca6cae6,
+    0x147222ebf44e, 0x1653a4f2652b, 0x166f8d1b250d, 0x1a79053af7cb,
+    0x1b825d4d1c73, 0x1bbaa2bca7c1, 0x1bbb8cf8bed5, 0x1d8126d66fd0,
+    0x1dbb40be2946, 0x1e1aa3b68f2b, 0x1f3264fb1f4e, 0x1f877ccf8192,
+    0x1fa9577ed776, 0x218d231b8205, 0x21ec10f5dc60, 0x22d6c22afbea,
+    0x24c7d22ef51b, 0x25cb72498986, 0x2698dbfbc690, 0x28107556c822,
+    0x28e989872b6b, 0x297b79f78c61, 0x3157beb0cef2, 0x32b24b17f3c9,
+    0x32fcdd394ca7, 0x3563f584e6ec, 0x359907222174, 0x37073f953679,
+    0x376a10eef2e9, 0x37b552bd5d16, 0x3a57349dc220, 0x3b3048ce2569,
+    0x3b4c30f6e54b, 0x3de1438e073d, 0x3e2cfa7a7cf4, 0x4071c2f8b5cb,
+    0x43466ce01bab, 0x453cbfbd998f, 0x45a1658f8427, 0x463cf176d76f,
+    0x47d0ad899ea8, 0x47d5f063235b, 0x47e3e477834c, 0x4915f3fbaaee,
+    0x4981b11f4861, 0x4ad909b41c72, 0x4b1532a309d5, 0x4bf10587b25a,
+    0x4d3cee2d65f1, 0x4d9539d418fb, 0x4d96992e3b99, 0x4dd8ef32c4c3,
+    0x4e18868308b1, 0x4ef5f350d999, 0x4f66b8bef5fa, 0x4f817c1c9903,
+    0x4fb6532acdc6, 0x50c89706d371, 0x50e270285f66, 0x51419891bf86,
+    0x51478b185588, 0x51771f4d0598, 0x524413e13718, 0x52683826c6ae,
+    0x52c307f2b92f, 0x52ce02c3ce1f, 0x539121520788, 0x539b31e70564,
+    0x53e798808c6a, 0x5431b583d9be, 0x543fe4273f74, 0x554558ba0207,
+    0x5592e41ea5e6, 0x579a5ee2d481, 0x585a842dc2e9, 0x58ec3a0f1e1a,
+    0x59b5fad0fed4, 0x5b95a85f4189, 0x5bdabd180fef, 0x5c18ba7f2b7a,
+    0x5c64e689acbb, 0x5d1f53dd0ae6, 0x5e77218fea81, 0x5edc770ee668,
+    0x5fc135bd6ff0, 0x629ae7ef54be, 0x649bfb0ee1cd, 0x669be9635203,
+    0x66d5190ef465, 0x67fd8d1c29b5, 0x69f53f53ca37, 0x6af44c41eb3e,
+    0x6b749fad8ff3, 0x6c3d3ba453d4, 0x6dc7d15e3445, 0x6dd2cc2f4935,
+    0x6ef0456b6995, 0x707e0ef79acc, 0x70fab972e331, 0x71136dc9524d,
+    0x72d81d6aec34, 0x731fb648fa11, 0x7392502f449a, 0x750c6daf85a3,
+    0x768650a0c0e7, 0x77e11796eb83, 0x7834206419da, 0x79bbf769b50f,
+    0x79f43cd9405d, 0x7a052a30eb4f, 0x7a5414efb1cc, 0x7a8d0a0c4e69,
+    0x7b5d6d01d674, 0x7b798fb99c1b, 0x7bb19a9a21a4, 0x7c4d61107ab1,
+    0x7d513bba14e1, 0x7d622911bfd3, 0x7e141fb94885, 0x7e19d7b0d8c2,
+    0x80a1e06fb1d7, 0x80d851670efd, 0x81414fd66734, 0x823b547a094d,
+    0x82869648737a, 0x8392727fd799, 0x844cdfd335c4, 0x84a4b65bdd44,
+    0x84b4b9777122, 0x84d4bfae98de, 0x84e0a4bbc4e2, 0x853497c50a4d,
+    0x8563f16ab498, 0x8599ed440634, 0x86758599a8f4, 0x86d13fa1b289,
+    0x86f26aa3f71e, 0x87302d7c0ce4, 0x88e255dcd376, 0x8b2a179e574e,
+    0x8ba319294363, 0x8bce8f4f8b99, 0x8be6ce87ef2b, 0x8c70484c7aa8,
+    0x8d0d338df08e, 0x8e29fd1cff9f, 0x8ef9b065765b, 0x8f259ba9ca1b,
+    0x8f4407f7c974, 0x90a202c044d6, 0x90a82fd5e09d, 0x90c8360d0859,
+    0x91384bce136b, 0x915bfaf59777, 0x91e1913ac0df, 0x924d13cf588d,
+    0x926811bc015b, 0x92a59a05115c, 0x943f0e0f68d2, 0x944f4bba0275,
+    0x961f30bbb711, 0x980545ee9b52, 0x98150e7b296b, 0x9877dfd4e5db,
+    0x98881d7f7f7e, 0x9891f3857795, 0x9953b2b98e60, 0x99d85ec2a0b4,
+    0x9ae4b018105d, 0x9d431728cf64, 0x9e21a8c1bd25, 0x9e52d6df9598,
+    0x9ed9cc7ee19e, 0x9edcc5c22c9f, 0x9f5f62c40b06, 0xa0c599a9561c,
+    0xa1833acf050d, 0xa246595d3e76, 0xa3a921e13301, 0xa48aacbd6bc3,
+    0xa4b70d1fcb0d, 0xa57d5f80553c, 0xa5859b9d24f0, 0xa6312aa00c16,
+    0xa6badef39d58, 0xa77f224cf39a, 0xaac924cee93f, 0xab5caf287298,
+    0xaccc8184b000, 0xad528ce7e4f2, 0xaeffe78d325b, 0xaf69d038a1a6,
+    0xb19c4c050ced, 0xb3437994be8f, 0xb4f8261ac498, 0xb5406ea5e3c4,
+    0xb56a1053fdd2, 0xb5aa5751530f, 0xb5ca98178090, 0xb63b9814a2b6,
+    0xb7689f4e4b6a, 0xb844acc1f9b4, 0xb87e8c1aad65, 0xba04198a0ee8,
+    0xbc07eb5de133, 0xbcf311b10c47, 0xbd77489c1311, 0xbdc583adc83f,
+    0xbe226280eead, 0xc2357617b3bd, 0xc4819076a534, 0xc4cf1bdb4913,
+    0xc4f21b55bbd0, 0xc506ec2cc8d7, 0xc547a848299e, 0xc91cbe77d323,
+    0xc9c679028c21, 0xca2c7e2e9957, 0xcf90f7d39c76, 0xd091298cda56,
+    0xd0be399c4aef, 0xd10fa88050e3, 0xd2c83885b901, 0xd32e3db1c637,
+    0xd400751f7c6a, 0xd49a2c8ea18a, 0xd4a9f51b2fa3, 0xd4f7bb0ed947,
+    0xd55f94b314a5, 0xd63bdcb5c8b4, 0xda94ef41733e, 0xdbd4089de3bd,
+    0xde57b8bf4f33, 0xe0bde6ced264, 0xe1855dfa796c, 0xe2808769385e,
+    0xe40fb04f8c33, 0xe5e00a6f4c59, 0xe671c050a78a, 0xe6744475e701,
+    0xe7555a341439, 0xe87e08d04f4e, 0xe99296428eab, 0xeaa798d2d992,
+    0xec531efff8d3, 0xef8ad4d020e8, 0xeffa3ae41aab, 0xf0583e825df2,
+    0xf15710e17934, 0xf1ef2e6775f1, 0xf25f09997b3e, 0xf28452aa27ad,
+    0xf313bef5492c, 0xf321787aa358, 0xf4fc92dc72a9, 0xf5b4417b8b98,
+    0xf689722c8ccc, 0xf8043f59df24, 0xf855391fd98e, 0xf94c7f0f366b,
+    0xf9ab325a8b01, 0xfa79112ad395, 0xfbbf41d8f6ef, 0xfd0622342b98,
+    0xfd1784a9e214, 0xfd53ad98cf77, 0xfe135dc5b255, 0xfeec37670fd9,
+    0xff54110b4b37, 0xffb3e921bca6, 0xffcf96bb76c3, 0x00176a288a65,
+    0x022b0488eac9, 0x03f063d795ff, 0x040cc11e616b, 0x048c9f6bfa96,
+    0x0506c5c20384, 0x05bd4f95ff9a, 0x05e298a6ac09, 0x06a20e448922,
+    0x06a7168f0810, 0x06dd126859ac, 0x06e13076c186, 0x078448cdd333,
+    0x08acf76a0e48, 0x08dfbf710f1e, 0x08ee9dc18623, 0x091f1c324d47,
+    0x09bcf1afda41, 0x0b975c649843, 0x0bd34ac47fe1, 0x0c68a9963762,
+    0x0cc638166f1f, 0x11d6f9413298, 0x1300578a7efc, 0x13389cfa0a4a,
+    0x140815b37b41, 0x142ce9a61c26, 0x151725bd3026, 0x17c85b0c17bf,
+    0x17e701e91cdd, 0x18e1411bc4bb, 0x18fe4e0fa176, 0x1b0971c42c61,
+    0x1b0b80cb604e, 0x1b57acd5e18f, 0x1c2eb1ff10eb, 0x1fb9e5ba72e1,
+    0x1fc973b7fb35, 0x231c6203d1dd, 0x236d5bc9cc47, 0x26a28d74b4e5,
+    0x2785028d04bb, 0x289f0d67ce90, 0x2a3d149e996a, 0x2d06c3b4ea5a,
+    0x2df0158fe746, 0x30b8da6a2122, 0x30f7877e4dfc, 0x311a86f8c0b9,
+    0x3241d63ad930, 0x33912d41e352, 0x3398b9b1a1b7, 0x3427764fb1e7,
+    0x349cceea41ac, 0x354ae2126849, 0x35ebb0d34044, 0x3677aebd0b38,
+    0x36c1cbc0588c, 0x371bb15033f9, 0x37e4c2650364, 0x3817ff8a0fc4,
+    0x38e73db47af6, 0x3a052c0ea6e0, 0x3c7a386ea116, 0x3d0586ab5abb,
+    0x3e34d77b3d21, 0x3e65907b0a0a, 0x3eac79ac0698, 0x3f00a74451c8,
+    0x3fe7ea181ac7, 0x40e1047fa5cc, 0x41841cd6b779, 0x41fb49e97566,
+    0x4283d971e9cf, 0x42e0f2d41602, 0x43193843a150, 0x43355afb66f7,
+    0x43fee12e41ec, 0x441c9dcf2ff6, 0x4433b83c76af, 0x44a7ec0be99b,
+    0x464d45236d15, 0x48ac5be13d6b, 0x48e30767a056, 0x4928913e7a46,
+    0x4a2e7aef4863, 0x4af9607c45f6, 0x4b8683312dc3, 0x4b97ab17de7a,
+    0x4c1b6ce4d9ba, 0x4ce2a9817afd, 0x4d9a92af99b1, 0x4e778a5f5f0f,
+    0x4eb9e063e839, 0x4fc5bc9b4c58, 0x50c15b2816d4, 0x5274e2e30004,
+    0x52c2a8d6a9a8, 0x54e6f5ffaf39, 0x5509f57a21f6, 0x557cc9ef7244,
+    0x5597c7dc1b12, 0x583e0259edbb, 0x592669f8d393, 0x5ae2dd7d9dc6,
+    0x5b0f786f02d5, 0x5baa54a944ce, 0x5bdbf7e528cb, 0x5c5533ff1aa5,
+    0x5e42d5a1bd4b, 0x5e868b006913, 0x5ef715df7faf, 0x6000a880aa1c,
+    0x6157c6867868, 0x6179dbc4d411, 0x618969c25c65, 0x61dce7ad9646,
+    0x6240ddd26f8f, 0x62db7f7dabc3, 0x6360a0a4c9a1, 0x643d23368375,
+    0x6545567d8b44, 0x65effb445b56, 0x6872fbb8b57d, 0x68de7e4d4d2b,
+    0x6919bd00237a, 0x69498bc3d94f, 0x6a4146d141b6, 0x6a48d341001b,
+    0x6a4f7574a76c, 0x6d4b0255e21e, 0x6dfb99a34832, 0x6ea8c28f57bb,
+    0x6f4e999aaea4, 0x6fe85109d3c4, 0x6ffb87f7b868, 0x7085b1695534,
+    0x70cc600b4bfd, 0x724bc06511b9, 0x725d5d69cdfa, 0x72bcfaf139a4,
+    0x730c203f05e6, 0x76a19f1e6b7d, 0x76b4265f3ed2, 0x7809e50aea80,
+    0x78290105fb28, 0x79fdb3c328ed, 0x7a5f25c2c2bf, 0x7c1ecd19ddb8,
+    0x7c27f372c480, 0x7c2ab22709bc, 0x7d50677ff9d0, 0x7d62eec0cd25,
+    0x7ebc1bcdcf5e, 0x7f38512b0c39, 0x7fc32a49ba54, 0x8182d1a0d54d,
+    0x842772357f93, 0x845e584ae843, 0x85397b827f79, 0x871a886ae4cc,
+    0x873d4d5651c4, 0x88b979ddc6ba, 0x88f2a989691c, 0x8a0a303ef37a,
+    0x8b7360678991, 0x8c78254d3ad5, 0x8cb697d261ea, 0x8d0c5f53d57d,
+    0x8ef5333afefa, 0x8f68423f550d, 0x8fec792a5bd7, 0x9145a6375e10,
+    0x921a273b4df5, 0x92898d4f47b8, 0x975a077cb5f4, 0x99d15d72e9dc,
+    0x9a31aaa766d5, 0x9b166955f05d, 0x9f5408d6e68f, 0xa09cf8394f25,
+    0xa26a1e86be85, 0xa31c8a4c52c1, 0xa34c59100896, 0xa474928e3821,
+    0xa4e51d6d4ebd, 0xa51c78a0c2f7, 0xa55f43c357ab, 0xa736efc3d071,
+    0xa74e44c01cef, 0xa75ebcf9bc57, 0xa776fc321fe9, 0xa90a4326db98,
+    0xa91be02b97d9, 0xa9d946c24105, 0xab08d2212930, 0xabdf622c4d02,
+    0xae1511cb090f, 0xaec2ea6429e7, 0xaf13347d1302, 0xaf23e745b82f,
+    0xafe61b97da84, 0xb013a0c556a7, 0xb091700bbbe5, 0xb0fb58b72b30,
+    0xb11b997d58b1, 0xb1cbbbacb33b, 0xb1d975320d67, 0xb273a1bf3e11,
+    0xb2853ec3fa52, 0xb2b8f107123c, 0xb2ccd7a2082f, 0xb3181970725c,
+    0xb44b884ebc9c, 0xb45389dc868b, 0xb46944efaaa6, 0xb59e13281784,
+    0xb626dd3f91b2, 0xb8548b5083d0, 0xb99922157ec7, 0xba7da635028a,
+    0xba8da9509668, 0xbb1b7bb28f84, 0xbbfc1c52b132, 0xbd40eda6b1ee,
+    0xbd44d1261403, 0xbd55495fb36b, 0xbe170893ca36, 0xbe907f3cc1d5,
+    0xbe90b9cbc79a, 0xbe930362014c, 0xc0bba928747c, 0xc24fda59473f,
+    0xc30071a6ad53, 0xc32dbc4523b1, 0xc385583ec56c, 0xc4d9427242f2,
+    0xc50234734bb1, 0xc7668e0aa0ba, 0xc7c3324ec163, 0xc7f1dc475a5f,
+    0xcad47a432030, 0xcbab0a4e4402, 0xcbac2f1960db, 0xcc85b867cfae,
+    0xcd52726cfb69, 0xcda7ff5f6937, 0xcdf550350751, 0xce1172ecccf8,
+    0xcf1f98ba6ac9, 0xcf7fe5eee7c2, 0xd0157f4fa508, 0xd2148367fe2a,
+    0xd21a00d088a2, 0xd316feb775bc, 0xd34a014d7c57, 0xd37ba4896054,
+    0xd3bac6bb98b8, 0xd4698990d0a4, 0xd62930e7eb9d, 0xd7b64ac70b85,
+    0xdd1d48914e1b, 0xdd6c33501498, 0xdf58007a8916, 0xdfb46a2fa3fa,
+    0xe12526c7f876, 0xe269f81bf932, 0xe46a961d7ab7, 0xe643dc071be0,
+    0xea96fc0c3068, 0xeaaaa8182096, 0xed0406de60af, 0xed7491bd774b,
+    0xef19ead4fac5, 0xef4c3dbdf011, 0xeff3aeb26f5d, 0xeffb75b13387,
+    0xf1bec5f8aad0, 0xf21c8f07e852, 0xf30ae92d642c, 0xf482828865be,
+    0xf544072d76c4, 0xf5a1208fa2f7, 0xf65317372ba9, 0xf658949fb621,
+    0xf7ef0f66c296, 0xfa3153bfbbf6, 0xfb0943250266, 0xfd726a77d098,
+    0xfd8c090a56c8, 0xfe0495773753, 0xfe5c6bffded3, 0xfee536175901,
+    0xff6cdb63b656, 0xff79358eede4, 0xffc59c2874ea, 0x015e25f6b54c,
+    0x042884ba178b, 0x042c683979a0, 0x05808cfbfceb, 0x07d55895c9a0,
+    0x08b37510abd7, 0x0b525dadc5e0, 0x0ff293f972bd, 0x104527a8958a,
+    0x110a553e02e0, 0x127ce64e8584, 0x140bd4a5d394, 0x14e1ef92ebdc,
+    0x1507adc1a3d5, 0x15bac9344960, 0x17f87a60cf5c, 0x1aa01438c4a3,
+    0x1b1a751dd356, 0x1b7e6b42ac9f, 0x1c90e9adb80f, 0x1ce133c6a12a,
+    0x1f2f97bbcc53, 0x2057218cea8f, 0x2139d134402a, 0x21ca27bb78bd,
+    0x221aac63679d, 0x25d86d96c4a4, 0x269bc6b403d2, 0x27a4348a1166,
+    0x293531e89363, 0x2a7694db3d94, 0x2a924274f7b1, 0x2d0b325453fc,
+    0x3062ee5ba3cd, 0x30a3e5060a59, 0x31d83e206bad, 0x3201a53f7ff6,
+    0x330f907e1802, 0x33187c47f905, 0x33f0a63c453a, 0x33f8e25914ee,
+    0x34d689b5eb9b, 0x37b5f3df60a6, 0x3a02bdeb636c, 0x3b16269285f0,
+    0x3b4963b79250, 0x3b69a47dbfd1, 0x3ccd573dcb70, 0x3dc8bb3b9027,
+    0x3dc9a577a73b, 0x3f40549691b9, 0x400be9d0a09b, 0x4109d1f3a4c9,
+    0x421ed483efb0, 0x42d6bdb20e64, 0x439f9437d80a, 0x443dded3708e,
+    0x449fc5f115ea, 0x47e4c0288ca1, 0x480a09393910, 0x481a46e3d2b3,
+    0x48a595208c58, 0x4972fed2c962, 0x49b58f665851, 0x4a15dc9ad54a,
+    0x4c079c4bdfca, 0x4e1a4c70291a, 0x4ea5601ddcfa, 0x4f4210d04d1b,
+    0x50261fd1c554, 0x51198241c01c, 0x536895e3fc94, 0x53c783be56ef,
+    0x53cc5179d018, 0x566c24530135, 0x5730dcca6301, 0x57ca59aa825c,
+    0x59aed4f43e3a, 0x5b500ffd59da, 0x5bf2edc565c2, 0x5d082ae4b66e,
+    0x5f23c6d2e0c1, 0x5fae65628917, 0x62b90b234093, 0x6348776e6212,
+    0x63800d30dc11, 0x63bad6c5a6d6, 0x65e3076e0e7c, 0x6682ebf2cf63,
+    0x673383403577, 0x67d626793b9a, 0x69cec2ecf330, 0x6ae81e1aabb6,
+    0x6af6874d1731, 0x6b047b617722, 0x6b88029f6c9d, 0x6c21ba0e91bd,
+    0x6d2a27e49f51, 0x6d735aabd591, 0x6f392f188c51, 0x70c056711637,
+    0x72226f47f973, 0x7225a31a4a39, 0x732141a714b5, 0x733ae0399ae5,
+    0x743435302baf, 0x7448565a2767, 0x74b0dfab7414, 0x75d77f407b3c,
+    0x791a6a70be06, 0x79427235afb1, 0x79c0417c14ef, 0x79db79f7c382,
+    0x7a89c7aeefe4, 0x7ae9da546718, 0x7da60a7463a1, 0x7e08dbce2011,
+    0x7f76d9b22f51, 0x7fbc63890941, 0x7fe5901917c5, 0x80cd0d7be689,
+    0x82efc0bbc3b7, 0x84946a2635e2, 0x87394549e5ed, 0x876271d9f471,
+    0x887ec64af7f8, 0x8a64a0eed674, 0x8bc6b9c5b9b0, 0x8d9d40fb159d,
+    0x8fac82be0862, 0x8fc2b2ef3807, 0x917fd6211389, 0x927c5ee9f519,
+    0x94a6640a8ae7, 0x950f6279e31e, 0x95e3e37dd303, 0x96a3ce39bba6,
+    0x96c61e071d14, 0x9a19bc00050b, 0x9be87c369cce, 0x9c5055dad82c,
+    0xa0085f16a4f6, 0xa0298a18e98b, 0xa0857eaff8e5, 0xa0db0ba266b3,
+    0xa1d71f4d3cb9, 0xa2547975966d, 0xa82efb623aa0, 0xaa024ec545c7,
+    0xaa2fd3f2c1ea, 0xabacb027482f, 0xae4ecc96b2fe, 0xb057e14409fc,
+    0xb1eb9d56d135, 0xb204171e3a8c, 0xb283f56bd3b7, 0xb33243230019,
+    0xb384d6d222e6, 0xb3f7ab477334, 0xb5b0b06ae6dc, 0xb5b12588f266,
+    0xb905adbdf171, 0xb90956ae4dc1, 0xb98eecf37729, 0xba80b57a498e,
+    0xbcda4ecf8f6c, 0xbd2bf8429b25, 0xbe3a932e4480, 0xbe3eb13cac5a,
+    0xbf0c557def29, 0xbf9f6ab96cf8, 0xc37fb6493132, 0xc3b15985152f,
+    0xc50ac1211d2d, 0xc52f5a84b84d, 0xc59d613e8f72, 0xc65c61be6101,
+    0xc9ab31fbcfcf, 0xcaf0b2fce1da, 0xcd0537995952, 0xcf85b3e87402,
+    0xcfc63574cf04, 0xd1142d21b688, 0xd14f6bd48cd7, 0xd2296a410734,
+    0xd35beee33a60, 0xd3867acd6b82, 0xd3de16c70d3d, 0xd53a028854b2,
+    0xd75897b9ca06, 0xd7e2c12b66d2, 0xdadb8f585c48, 0xdc335d0b3be3,
+    0xddc4cf87c96a, 0xe0943695aa97, 0xe138e8d5e4a7, 0xe38d04c2a00d,
+    0xe4ee6dec71fa, 0xe547a3cf3c18, 0xe717c35ff679, 0xe7908a5bdcc9,
+    0xedafe672499d, 0xeffa2c590cec, 0xf0aac3a67300, 0xf171509602f4,
+    0xf1c41ed42b86, 0xf386bf6e9180, 0xf442c6ab180e, 0xf584d94ad38e,
+    0xf701b57f59d3, 0xf8a8a88005b0, 0xf9e5032830f3, 0xfedbeb316877,
+    0x02edd9fd10ae, 0x0322015e3422, 0x09692aaa8cdc, 0x0c3adb4ea7bb,
+    0x0d379ea68f10, 0x0febcd38c1aa, 0x111bcdb5b55f, 0x1246163b18d7,
+    0x15ccb6ca0769, 0x16abf8100679, 0x19589a327aae, 0x1a92e5d37204,
+    0x1bd35e8a0521, 0x1c3b72bd4644, 0x1c5ac9475cb1, 0x1c7acf7e846d,
+    0x1d1ed211ad2e, 0x20735a46ac39, 0x211bf006485e, 0x223b78499cab,
+    0x230f0f11757c, 0x24a8f839d87c, 0x24b7d68a4f81, 0x2896c2bff11d,
+    0x29b610743fa5, 0x2acf3112f266, 0x2d9cfe37ab30, 0x31a8bfedb7a0,
+    0x3360dad51434, 0x3576843ca885, 0x35a8d7259dd1, 0x3610eb58def4,
+    0x36a3c60556fe, 0x376b02a1f841, 0x37b7693b7f47, 0x38fb15c4632a,
+    0x3a188f00838a, 0x3ac2841a424d, 0x3b3433c475c2, 0x3ce3d7fffcdd,
+    0x3dc05a91b6b1, 0x3e77cea1c9db, 0x41030b32f3b6, 0x42cff6f15d51,
+    0x430ccf8d5c03, 0x45aa935f5933, 0x4644103f788e, 0x497e84c3e5df,
+    0x4a21d7a9fd51, 0x4cb1a7679a90, 0x4cec366d5f90, 0x4de4a127d946,
+    0x4e09afa97ff0, 0x4ee7cc246227, 0x4eed498cec9f, 0x4ff15ec58c94,
+    0x50d11529972e, 0x50d6cd21276b, 0x519a60cd6c5e, 0x51e7ec32103d,
+    0x534b29d41052, 0x54d04225664b, 0x5627259c2ed2, 0x578d97107fad,
+    0x588e0358c352, 0x58cb1683c7c9, 0x59471151fedf, 0x5acc9ec16062,
+    0x5c8c46187b5b, 0x5d15fa6c0c9d, 0x6584df42c173, 0x663fc1b42b28,
+    0x66837712d6f0, 0x66e76d37b039, 0x6708d2c8fa93, 0x6a87ac5924fb,
+    0x6bdc80c8b995, 0x6c775d02fb8e, 0x6cfa3493dfba, 0x6d799dc36d5b,
+    0x6d97cf8266ef, 0x6f17df893dfa, 0x6f2105e224c2, 0x70b53712f785,
+    0x711bb15d1045, 0x71b1bfdbd915, 0x73aa21c08ae6, 0x7561526bd066,
+    0x75f81097aa85, 0x762ad89eab5b, 0x770a8f02b5f5, 0x7d37df2d82ba,
+    0x7d43c43aaebe, 0x7d856a922699, 0x80ffb0f5dd9d, 0x8105de0b7964,
+    0x8222327c7ceb, 0x83dfcacc63f7, 0x85c11243cf0f, 0x8600e42318c2,
+    0x8648b7902c64, 0x86e6c79cbf23, 0x888afbe925c4, 0x88c048156611,
+    0x8b0bb2c74639, 0x8b802125beea, 0x8c2bb028a610, 0x8c4e3a850d43,
+    0x8ca143523b9a, 0x90e0f1da65b9, 0x9128159a680c, 0x91bc4fa102b4,
+    0x91d837c9c296, 0x91f9283d0166, 0x9280586b5331, 0x93275441c6f3,
+    0x937b0cbc0699, 0x95f7306db3aa, 0x9630d5376196, 0x9802199338d0,
+    0x9a76764621b7, 0x9be26522fd0a, 0x9f258ae24599, 0x9ff2ba057cde,
+    0xa0ab8d6fb2a6, 0xa2b9e4f68e57, 0xa3af1bdeb747, 0xa745fa183f7c,
+    0xa902a82c0f74, 0xa9fb880494b4, 0xabaf84dd896e, 0xac8b925137b8,
+    0xafbeb4f4ec69, 0xb0949552feec, 0xb0b8f4279447, 0xb167f18bd1f8,
+    0xb1891c8e168d, 0xb2246de66410, 0xb31a547b9e4f, 0xb3b396ccb7e5,
+    0xb523a3b7fb12, 0xb814aae62c5e, 0xb8694d9c8318, 0xb8b8385b4995,
+    0xb99c0ccdbc09, 0xba380dd31adb, 0xbb4b015c31d5, 0xbb86400f0824,
+    0xbd4069fd98a5, 0xbd841f5c446d, 0xbdb96b8884ba, 0xbefb090a34b0,
+    0xc18ce7cf05dc, 0xc2680b069d12, 0xc2b8ca3d91b7, 0xc392538c008a,
+    0xc3f091b94996, 0xc432729fc736, 0xc57c4c3e46e0, 0xc7b7b3d4932a,
+    0xc7c8dbbb43e1, 0xc82b7285fa8c, 0xc86467a29729, 0xc876eee36a7e,
+    0xc90aee5aff61, 0xca4c514da992, 0xcb57f2f607ec, 0xcce9da90a0fd,
+    0xcd2220002c4b, 0xce21dc9b5ea1, 0xcf0192ff693b, 0xcf2cce96abac,
+    0xd0cbfa98935f, 0xd334723e5042, 0xd33d23792b80, 0xd495664a16a5,
+    0xd525bcd14f38, 0xd97a76bf8c23, 0xdaea0e8cc3c6, 0xddfcb5db4531,
+    0xde8b37ea4f9c, 0xdf1f71f0ea44, 0xe3148e57bb85, 0xe76982d4fe35,
+    0xe7aa797f64c1, 0xea503edf2be0, 0xed1bfcfcb0bd, 0xedebb0452779,
+    0xee7bcc3d5a47, 0xeeb8df685ebe, 0xf0a312a9aad9, 0xf1ce456b2565,
+    0xf45f7482e542, 0xf46dddb550bd, 0xf4a1556962e2, 0xf5ce221405d1,
+    0xf5f196ac8418, 0xf9548813ee9e, 0xf9a1d8e98cb8, 0xfa7e5b7b468c,
+    0xfacee023356c, 0xfb918993634b, 0x0023913baa72, 0x01af4bc0a7bc,
+    0x02f8ead021a1, 0x03530aef02d3, 0x073e514fdbfd, 0x08246f588823,
+    0x0ba67cbb0351, 0x0d373f8a7f89, 0x0de970c10e00, 0x0e6655cb5c2a,
+    0x13a6360cc429, 0x13e51daff6c8, 0x14b2fc803f5c, 0x17ebd71b844a,
+    0x186c2a8728ff, 0x1978f0faa432, 0x1a6994b659be, 0x1bafc5647d18,
+    0x1bdb00fbbf89, 0x1ccd041197b3, 0x1ceb35d09147, 0x1d351844d8d6,
+    0x1ea9433e83dd, 0x1f42c01ea338, 0x1fea6ba22849, 0x214fb84b5c4b,
+    0x22f8f4e241da, 0x25bcec01028d, 0x2607b8b16130, 0x281bc82fcd1e,
+    0x29046a5db8bb, 0x299a03be7601, 0x2a6314d3456c, 0x2d0447069927,
+    0x2d892d9eb140, 0x2f60d99f2a06, 0x2f70679cb25a, 0x310841bde16d,
+    0x33b050b3e23e, 0x347e6a133097, 0x350a2d6df5c6, 0x37659b3b69cc,
+    0x37e713722b5a, 0x391838ba3be8, 0x393879806969, 0x3951a2f4e40f,
+    0x396046b6554f, 0x3db0a807249b, 0x429b70742a56, 0x42fdccafdb3c,
+    0x44a6ceb7bb06, 0x455b4984832f, 0x45e3d90cf798, 0x46c26aa5e559,
+    0x46df027bb68a, 0x47b816ac19d3, 0x489a16a65e1f, 0x493826b2f0de,
+    0x4ada4bf82392, 0x4bf999ac721a, 0x4c31df1bfd68, 0x4c9057d84c39,
+    0x4d57cf03f341, 0x4eecafe1d753, 0x504233fe7d3c, 0x54a51c901fdd,
+    0x54d5d58fecc6, 0x58021b70f461, 0x59fcc6ebdfe4, 0x5bba5f3bc6f0,
+    0x5beec12bf029, 0x5c3f45d3df09, 0x5cdeefc99a2b, 0x5d5ece173356,
+    0x5e4a698869f4, 0x610c519ff6ba, 0x616b3f7a5115, 0x61b646b9b57d,
+    0x6253a71936ed, 0x62a4db6e371c, 0x657c7e98e7fd, 0x680e22ceb364,
+    0x6c9cbc15a400, 0x6fd8ca8339b4, 0x71ca8a344434, 0x7587612b8a27,
+    0x786cbddb9534, 0x788bd9d6a5dc, 0x78d0797168b8, 0x7b35bd44d4d5,
+    0x7c2a7f0ef23b, 0x7d59cfded4a1, 0x7f2b8958b765, 0x7f96d15e494e,
+    0x80e69d835efa, 0x8278bfacfdd0, 0x84c046df7be3, 0x84d0f9a82110,
+    0x87df0dca2f17, 0x89357c22ec14, 0x8a0ae762f30d, 0x8a4ba37e53d4,
+    0x8aa4d9611df2, 0x8ad8c6333ba1, 0x8b45e2b0fbb2, 0x8c281d3a45c3,
+    0x8cc2f97487bc, 0x8f4ac7a45b0c, 0x916a0c82e1af, 0x927bdb40dbd0,
+    0x93bb2f2c5214, 0x9437d9a79a79, 0x952cd600bda4, 0x95bda1a601c1,
+    0x966378b158aa, 0x966930a8e8e7, 0x985daf0e38a3, 0x994c0933b47d,
+    0x99a9d242f1ff, 0x9aacc2b0751b, 0x9d2abada5054, 0x9d8acd7fc788,
+    0x9dd89373712c, 0x9e32ee215823, 0xa0359b2a0d95, 0xa105fe1f95a0,
+    0xa194459f9a46, 0xa39d1fbdeb7f, 0xa545aca7bfbf, 0xa637afbd97e9,
+    0xa6c3ada762dd, 0xa93b78bba24f, 0xa98e0c6ac51c, 0xae685c9e2b6f,
+    0xaf9ae1405e9b, 0xb13a0d42464e, 0xb1f5d9efc717, 0xb2065229667f,
+    0xb26f8b27c47b, 0xb39d077f78b9, 0xb50c2a2ea4d2, 0xb6f39ebbabb1,
+    0xbc92e1f57995, 0xbdc90f880911, 0xbe4cd1550451, 0xbec59850eaa1,
+    0xc1a95b17cd4b, 0xc1d8b4bd7796, 0xc350c33684b2, 0xc414cc00d52f,
+    0xc517bc6e584b, 0xc520a838394e, 0xc704ae63e9a2, 0xc7b077f5d68d,
+    0xc7e4d9e5ffc6, 0xc8e49681321c, 0xc947a269f451, 0xc9f038299076,
+    0xca781804f390, 0xcba902bdfe59, 0xcc8376488440, 0xcdf67c77126e,
+    0xcead064b0e84, 0xd27f98557892, 0xd36c5891cc09, 0xd47b2e0c7b29,
+    0xd7901ef13646, 0xd947150d7601, 0xd9d2d8683b30, 0xd9d388154c7f,
+    0xdc088806f73d, 0xdcc2bacb4fa3, 0xdcc53ef08f1a, 0xddbece7625a9,
+    0xe22d9c14f44e, 0xe52077bb53c2, 0xe6e2ddc6b3f7, 0xe8285ec7c602,
+    0xeb292e828567, 0xeb6a5fbbf1b8, 0xed7ee4586930, 0xee05d9f7b536,
+    0xee444c7cdc4b, 0xef74c217db8a, 0xef7dade1bc8d, 0xf1ba74d22b75,
+    0xf288c8c07f93, 0xf386b0e383c1, 0xf52b94dcfbb1, 0xf57d78df0d2f,
+    0xf798da3e31bd, 0xf7c7bec5d07e, 0xf8688d86a879, 0xf96f9c02936f,
+    0xf9c737fc352a, 0xfaace0e6d5c6, 0xffe325b14b73, 0x001662d657d3,
+    0x00b5d23d0d30, 0x01f4b10a77ea, 0x0444e977d13b, 0x08386bf57a19,
+    0x09644e6405f4, 0x09774ac2e4d3, 0x09fa2253c8ff, 0x0c0a4e52d2d8,
+    0x0f4aefecdbf0, 0x0f4d398315a2, 0x1006820b56f4, 0x118b253ea163,
+    0x13684ea7a4a1, 0x14672106bfe3, 0x177de663a928, 0x18ea0fcf8a40,
+    0x1bdceb75e9b4, 0x1be8d08315b8, 0x1d489fc3bf42, 0x1d494f70d091,
+    0x1e39b89d8058, 0x1ef9a35968fb, 0x283457f93278, 0x2b7150a2df40,
+    0x2b8b9ee276bf, 0x2d4b0baa8bf3, 0x2f3d7b08a7c2, 0x31d720cc3d18,
+    0x325cf1a06c45, 0x33cbd9c09299, 0x34681554f730, 0x348bff0b8101,
+    0x34da3a1d362f, 0x35830a6bd819, 0x39b0a6d13a6d, 0x3a71b6583fe9,
+    0x3c19939502da, 0x3ce22f8bc6bb, 0x3d0e1ad01a7b, 0x3f50d4471f65,
+    0x403224945262, 0x4366a69229b1, 0x4529472c8fab, 0x46789e3399cd,
+    0x48380afbaf01, 0x487851f9043e, 0x49be82a72798, 0x4a291affa832,
+    0x4a5122c499dd, 0x4b86a0aa180a, 0x4fffb96cea50, 0x51dbbe0ad0b5,
+    0x5426ee2dab18, 0x5509d8640678, 0x5655115ca8c0, 0x57203178ac18,
+    0x5821c28c0c96, 0x5846967ead7b, 0x59bf8f33d1ab, 0x5c9857299f65,
+    0x5dc6489f5f2d, 0x611d54f99daf, 0x61f9626d4bf9, 0x62693d9f5146,
+    0x62b987b83a61, 0x68dab846d55d, 0x68e8e6ea3b13, 0x69685019c8b4,
+    0x6a1837ba1d79, 0x6aabc213a6d2, 0x6bb888872205, 0x6cc8f7eaf988,
+    0x6cdf281c292d, 0x6e6b1d302c3c, 0x6e6c076c4350, 0x6ea7bb3d2529,
+    0x6fb2e7c777f9, 0x7245eb5765fe, 0x741595ca14d5, 0x7592376f9555,
+    0x75bd3877d201, 0x768733c8b880, 0x791b5c23c35e, 0x7b83d3c98041,
+    0x7d46e981f1c5, 0x7d7a613603ea, 0x7d84e6e90d50, 0x7edbca5fd5d7,
+    0x7fb294f9ff6e, 0x8052b40dc61a, 0x8092fb0b1b57, 0x81927d1747e8,
+    0x82037d146a0e, 0x8522f3ac2e91, 0x87bd491cd536, 0x87f5c91b6649,
+    0x8995df596510, 0x89dffc5cb264, 0x89f2f8bb9143, 0x8ab52d0db398,
+    0x8b18e8a3871c, 0x8c9c670bb4b2, 0x8d1a365219f0, 0x8fa95662a5e0,
+    0x9549be67909d, 0x95dac49bda7f, 0x9a6f5069611d, 0x9cc20cfbf9e5,
+    0x9cd9d71651ed, 0x9d255373c1df, 0xa09b06ab057f, 0xa103ca8b57f1,
+    0xa1b07e595bf0, 0xa23f3af76c20, 0xa4149d61ab34, 0xa5250cc582b7,
+    0xa70779080aa8, 0xa75872ce0512, 0xa7e85437321b, 0xa8a62febe6d1,
+    0xa918c9d2315a, 0xaa4c38b07b9a, 0xadd1b4744d53, 0xadd3c37b8140,
+    0xae58aa139959, 0xb1856512ac7e, 0xb242cba955aa, 0xb27d202014e5,
+    0xb2b77496d420, 0xb3add04a19e9, 0xb434163c54a0, 0xb6c24c10c97c,
+    0xb6d45e339147, 0xb76529d8d564, 0xb796cd14b961, 0xb7bcc5d2771f,
+    0xb83468034096, 0xb86cad72cbe4, 0xb875241ea15d, 0xbbafd3321473,
+    0xbbc79d4c6c7b, 0xbcb50d35d141, 0xbdea508c49a9, 0xbf40f9740c6b,
+    0xc1c7dd67c8a7, 0xc1ff389b3ce1, 0xc38bdd5c513f, 0xc3a073a45881,
+    0xc3fcdd597365, 0xc5c326e435af, 0xcdf7f1d3310f, 0xd2cf0e34469c,
+    0xd365cc6020bb, 0xd3bc08ff9fd8, 0xd4e0246f6789, 0xdc8bb028dd31,
+    0xdd2567980251, 0xeafe7e0b4a83, 0xeb2267c1d454, 0xec5c78d3c5e5,
+    0xedba390d3b82, 0xedbff104cbbf, 0xef84a0a665a6, 0xf145a757a33d,
+    0xf4f3da8d77f0, 0x0044e841e70d, 0x0634b023a3d1, 0x094966795929,
+    0x0993be0bac42, 0x0aff725981d0, 0x0be939528a46, 0x0e2ca276a07f,
+    0x10951a1c5d62, 0x11dcaa24a35a, 0x122fed80d776, 0x125c135430fb,
+    0x1492e7be09e1, 0x16cc7adc2803, 0x1793425abdbc, 0x1807010c251e,
+    0x19d586b3b71c, 0x1b397402c880, 0x1e5b3430c6b5, 0x20181cd39c72,
+    0x217cf45ec4ea, 0x25323ee64c78, 0x2665adc496b8, 0x27b3305372b2,
+    0x2adae30806e9, 0x2cc884aaa98f, 0x2d874a9b7559, 0x2dd87ef07588,
+    0x2e5822af08ee, 0x2ef0b5531135, 0x2f05110c12b2, 0x3118364e678c,
+    0x320a39643fb6, 0x32fb523e00cc, 0x39ad4e71dfe5, 0x3da4eefdf09d,
+    0x3ddb256647fe, 0x3e33367df543, 0x3e908a6f273b, 0x40114a230f95,
+    0x40f2d4ff4857, 0x4106467c32c0, 0x413b92a8730d, 0x414777b59f11,
+    0x4493fe5cd42d, 0x4817a5a877be, 0x4a1a1822276b, 0x4d1c4737096e,
+    0x4d587025f6d1, 0x5061b68c8baf, 0x530a3aa0980a, 0x5346d8ad90f7,
+    0x550470fd7803, 0x55b124cb7c02, 0x5642daacd733, 0x57f418d186b1,
+    0x581b70e9670d, 0x5d0bb6bef740, 0x5de8ae6ebc9e, 0x5ed39a32e1ed,
+    0x5f3d82de5138, 0x5ff1136f024d, 0x5ff7f031af63, 0x60beb7b0451c,
+    0x623cb8afe83a, 0x62e49ec27310, 0x63da1039a1c5, 0x6547d38eab40,
+    0x661786d721fc, 0x69bdf30e3285, 0x6b092c06d4cd, 0x6dd0cc15f1d0,
+    0x6f2bcd9b2231, 0x702ce9907725, 0x71759e63d9f6, 0x720a4d888028,
+    0x736c665f6364, 0x7670a47b7954, 0x772977e5af1c, 0x778e58469f79,
+    0x7794103e2fb6, 0x78a1fb7cc7c2, 0x797641f1b1e2, 0x7b671766a54e,
+    0x7c294bb8c7a3, 0x7c39144555bc, 0x7d1fa76c0d6c, 0x7d33c8960924,
+    0x7e5893b2e224, 0x7f7179c28f20, 0x80972f1b7f34, 0x80eae795beda,
+    0x80ff08bfba92, 0x828d0cdaf18e, 0x82e0c5553134, 0x833a35c70117,
+    0x83b6e042497c, 0x853172e0960f, 0x86e0dc8d1765, 0x87215e197267,
+    0x88e0905281d6, 0x892b2273dab4, 0x897a47c1a6f6, 0x8a97fb8ccd1b,
+    0x8a99d004fb43, 0x8b0d54275ce0, 0x8eb6f430be2f, 0x90105bccc62d,
+    0x90e5c70ccd26, 0x90ebb9936328, 0x9202560cd672, 0x943fccaa56a9,
+    0x959a1e8275bb, 0x9872abe93db0, 0x989cfd44690d, 0x9c2cc42c3e67,
+    0x9c7f1d4c5b6f, 0x9cf3168cc896, 0x9f3f6b7abfd2, 0x9ff420d68dc0,
+    0xa05a9b20a680, 0xa066f54bde0e, 0xa1062a238da6, 0xa304f3ace103,
+    0xa596d271b22f, 0xa89d5a2401d1, 0xab15d4e55292, 0xac9e2108f951,
+    0xafc140911a24, 0xb1683391c601, 0xb1903b56b7ac, 0xb1deeb867864,
+    0xb28830f325d8, 0xb2aaf5de92d0, 0xb2aba58ba41f, 0xb39c494759ab,
+    0xb3f6de844667, 0xb554d94cc1c9, 0xb587dbe2c864, 0xb8ab35f9eefc,
+    0xb8ec67335b4d, 0xbbae89d9edd8, 0xbc169e0d2efb, 0xbc9d93ac7b01,
+    0xbd4c1bf2ad28, 0xbd8ac906da02, 0xbda21e032680, 0xbea424349288,
+    0xbff9a8513871, 0xc051b968e5b6, 0xc10549f996cb, 0xc13b0b43e2a2,
+    0xc220eebd8903, 0xc286f3e99639, 0xc2ccb84f75ee, 0xc37a56599101,
+    0xc398c2a7905a, 0xc535dfa24420, 0xc5611b398691, 0xc5f67a0b3e12,
+    0xc60050113629, 0xc68cfda8126c, 0xc7e1226a95b7, 0xc8c32264da03,
+    0xc9174ffd2533, 0xca13d8c606c3, 0xcb1fb4fd6ae2, 0xcb64c9b63948,
+    0xcb73e295b612, 0xcba008690f97, 0xcc1058b9206e, 0xcf793ca720f6,
+    0xd0295ed67b80, 0xd0cefb52cca4, 0xd116cebfe046, 0xd11cc1467648,
+    0xd30cac7f52a0, 0xd487eecab082, 0xd4bbdb9cce31, 0xd59fb00f40a5,
+    0xd5a8d668276d, 0xd6813aeb7967, 0xd78f60b91738, 0xd9ec686fb3a1,
+    0xdbd501c7d759, 0xe11ad48fd55a, 0xe7991e809c89, 0xe86b1b5f4cf7,
+    0xebab47db4a85, 0xec240ed730d5, 0xec5e634df010, 0xeda5f3563608,
+    0xede1a72717e1, 0xf2123ccfc536, 0xf30e8b09a101, 0xf5a621c6026a,
+    0xf661b3e47d6e, 0x0520add16c01, 0x05e0d31c5a69, 0x0849fa6f289b,
+    0x0855a4ed4eda, 0x08e6e5b09e81, 0x0bf7f315f789, 0x0ccd23c6f8bd,
+    0x0ddfa232042d, 0x11e1535312c1, 0x1511b7428236, 0x151fab56e227,
+    0x182339c5e6c8, 0x1b24f3bcbd41, 0x1b3272b311a8, 0x1b753dd5a65c,
+    0x1c58629b0781, 0x1d9e1e2b1f51, 0x20bbc04ab5ac, 0x21b42b052f62,
+    0x23aa4353a781, 0x23e24e342d0a, 0x271d37d6a5e5, 0x291aa205d6a4,
+    0x298992fbc4dd, 0x2e3d3ac45c23, 0x2fdb076c2138, 0x3197f00ef6f5,
+    0x328d26f71fe5, 0x32c4822a941f, 0x35f052ed9030, 0x39860c5bfb8c,
+    0x39a269a2c6f8, 0x3dbdf3e56181, 0x3e590aaea93f, 0x3fb48151e52a,
+    0x3ff2099af52b, 0x402a8999863e, 0x403b76f13130, 0x41dd2718585a,
+    0x42fd2479b831, 0x4534337296dc, 0x463dc613c149, 0x4643437c4bc1,
+    0x46f992c14212, 0x49e6f0ff170e, 0x4b543f3614ff, 0x4ca829699285,
+    0x4e8761d9c9b0, 0x500562d96cce, 0x513aa62fe536, 0x52a65a7dbac4,
+    0x53175a7adcea, 0x5463f2cda1d0, 0x559d19a37c4d, 0x56cc6a735eb3,
+    0x5887f3bc11d2, 0x5952d9490f65, 0x597cb5862f38, 0x5ad7b70b5f99,
+    0x5b1455185886, 0x5b1d40e23989, 0x5cd1784a3408, 0x5d707292dddb,
+    0x61f44b97bf4c, 0x62070d679866, 0x628fd77f1294, 0x62a5929236af,
+    0x637d4768775a, 0x6659f2dda729, 0x67179403561a, 0x687e4006acba,
+    0x687fd9efd51d, 0x68cd2ac57337, 0x69d906fcd756, 0x6b69c9cc538e,
+    0x6b6e2269c12d, 0x6b76d3a49c6b, 0x6c3c7658154b, 0x6f7f26f95250,
+    0x7047fd7f1bf6, 0x70a292bc08b2, 0x71669b86592f, 0x718074a7e524,
+    0x724651ea63c9, 0x72cafdf3761d, 0x740b76aa093a, 0x756bf597c413,
+    0x77e903858838, 0x78482beee858, 0x78dca08488c5, 0x7bc1fd3493d2,
+    0x7becfe3cd07e, 0x7d4c92ee7443, 0x7f8f86f47ef2, 0x802f30ea3a14,
+    0x813ad292986e, 0x83d22ebff412, 0x85dc2da96224, 0x86870cff37fb,
+    0x8ab3bf28833b, 0x8ae4030a449a, 0x8e4b879e2284, 0x90592f77ece6,
+    0x906e3addffb2, 0x90faade5d630, 0x9277c4a9623a, 0x92bd13f13665,
+    0x92d79cbfd3a9, 0x92fa61ab40a1, 0x9448ce7633af, 0x94647c0fedcc,
+    0x9549ea6b88a3, 0x95effc05e551, 0x9956965dac27, 0x9bae95c9c9a2,
+    0x9dce8a556194, 0x9e1c50490b38, 0x9fe642c429d2, 0xa08c8eed8c45,
+    0xa2116cafdc79, 0xa34a1e67ab6c, 0xa7a3a6116180, 0xa81b48422af7,
+    0xa8fea79691e1, 0xa90cd639f797, 0xac2ed0f6fb91, 0xacb551783c0d,
+    0xad7af42bb4ed, 0xadcfd171116c, 0xae0767338b6b, 0xb07fe1f4dc2c,
+    0xb141a128f2f7, 0xb15a1af05c4e, 0xb15c29f7903b, 0xb2e9b8f4bbad,
+    0xb37df2fb5655, 0xb7f830894574, 0xb8357e434fb0, 0xb9c138c84cfa,
+    0xbaf30dbd6ed7, 0xbb101ab14b92, 0xbb63237e79e9, 0xbd3d19152c61,
+    0xbe2162a5aa5f, 0xbe872d42b1d0, 0xbecb57bf6922, 0xc00911c1b703,
+    0xc0311986a8ae, 0xc142e844a2cf, 0xc1bc997ca033, 0xc1fc6b5be9e6,
+    0xc415f842e04c, 0xc4517184bc60, 0xc49cb353268d, 0xc57d19644276,
+    0xc69e761fc4eb, 0xc6b1ad0da98f, 0xc7444d2b1bd4, 0xc74dae130861,
+    0xc7be738124c2, 0xca284a810443, 0xcb8052c2e9a3, 0xcba6c09eb2eb,
+    0xcd9c9e5e2545, 0xce6deb8fc464, 0xcfcedf9b8ac7, 0xd097b621546d,
+    0xd0ba407dbba0, 0xd0db6b800035, 0xd1c35e00da83, 0xd3b383c8bca0,
+    0xd46873b39053, 0xd4beeae21535, 0xd6468758aaa5, 0xd667b25aef3a,
+    0xdd5b8f754bf3, 0xe7a2204c798f, 0xe8a167c9a05b, 0xebce5d57b945,
+    0xec3c9ea0962f, 0xef7e6505bc20, 0xefd3f1f829ee, 0xf0edc243edfe,
+    0xf14ebf257c46, 0xf1661421c8c4, 0xfceb83c6611a, 0xfd7ed390e4ae,
+    0xfdc756ab099f, 0xff7fac216bf8, 0x013751eabd02, 0x022412271079,
+    0x02c4a658e2af, 0x0bd1261e1eba, 0x0d4ace805439, 0x0d8bc52abac5,
+    0x0f83ec8066d1, 0x1125d73693c0, 0x12ac14530692, 0x13a0d61d23f8,
+    0x14b9bc2cd0f4, 0x155806c86978, 0x1813fc59603c, 0x185d69af9c41,
+    0x190cdc31e57c, 0x1a7f32b3625b, 0x1b946fd2b307, 0x1c0f0b46c77f,
+    0x1cb900608642, 0x1e45a5219aa0, 0x1fbe6347b90b, 0x20d5e9fd4369,
+    0x21349d4897ff, 0x255c47276451, 0x260810b9513c, 0x263595e6cd5f,
+    0x274bf7d13ae4, 0x275349b1f384, 0x27681a89008b, 0x29d1074cc8f8,
+    0x2a70ebd189df, 0x2b561f9e1ef1, 0x353bb393be45, 0x356380c9aa2b,
+    0x35decbeacff2, 0x38e7d7c25f0b, 0x391a2aab5457, 0x39d45d6facbd,
+    0x3bbfb57c15b1, 0x3d75fbeb441d, 0x3f0aa23a226a, 0x3f48da3043ba,
+    0x409a7acd878e, 0x416e1195605f, 0x427769a78507, 0x430d78264dd7,
+    0x439139f34917, 0x440a00ef2f67, 0x451826bccd38, 0x454ff70e4cfc,
+    0x4af860a101a8, 0x4b094df8ac9a, 0x5148ead546ef, 0x51debec509fa,
+    0x5258aa8c0d23, 0x52d4a55a4439, 0x537af183a6ac, 0x541f6934daf7,
+    0x55e9d0ce051b, 0x5727c55f58c1, 0x57fe1adb76ce, 0x585b343da301,
+    0x59857cc30679, 0x59a0b53eb50c, 0x5d3a179d7cb8, 0x6373c1f3810b,
+    0x63860ea54e9b, 0x6699dabeecdf, 0x6885e2786722, 0x6d2a36d27bd9,
+    0x6e9500e43a53, 0x6eb7c5cfa74b, 0x7054e2ca5b11, 0x71aadc050c84,
+    0x77fa7bfd3ab7, 0x796cd27eb796, 0x7ad8c15b92e9, 0x7b16bec2ae74,
+    0x7b24b2d70e65, 0x7d07cec6a7a5, 0x7e4ee9b0e213, 0x7e6bf6a4bece,
+    0x7e93894ba4ef, 0x7ecb599d24b3, 0x7fcb8b566293, 0x8113907cb415,
+    0x856bf35b4d50, 0x85b092f6102c, 0x869eb28c8641, 0x88dc63b90c3d,
+    0x89877d9de7d9, 0x89da866b1630, 0x8ab02c3a22ee, 0x8b12fd93df5e,
+    0x8bc0d62d0036, 0x8bf2044ad8a9, 0x8dba224dc91b, 0x91446bcd13fd,
+    0x92bbca990fca, 0x94aa5677c984, 0x954c8492c41d, 0x95fa5d2be4f5,
+    0x9bc1330c98fa, 0x9dfcd531eb09, 0x9e6bc627d942, 0x9f1afe1b1cb8,
+    0x9fc47e16cff1, 0x9fc73ccb152d, 0xa16b71177bce, 0xa2a3adb13f37,
+    0xa3a2ba9f603e, 0xa465d92d99a7, 0xa557dc4371d1, 0xa69f31bcb204,
+    0xa7bad680a43c, 0xa80b95b798e1, 0xa90350c50148, 0xa96a402d2592,
+    0xaa58253495e2, 0xaad45a91d2bd, 0xaf7331835cfc, 0xb0be6a7bff44,
+    0xb63cf7d1941d, 0xb99947055752, 0xba96ba0a4ff6, 0xbb30e69780a0,
+    0xbcb21b697484, 0xbd933127a1bc, 0xbe44ed4024a9, 0xbf1a930f3167,
+    0xbf60920416e1, 0xc1d1f573b4c7, 0xc25a0fde1da6, 0xc2f735ae9951,
+    0xc445a2798c5f, 0xc46a014e21ba, 0xc48539c9d04d, 0xc5f3e75af0dc,
+    0xc62b428e6516, 0xc62d1706933e, 0xc6fd04de0fbf, 0xc74cd9d8ed50,
+    0xc7c7754d01c8, 0xc8a900293a8a, 0xc91ff2acf2b2, 0xc97b3796f0bd,
+    0xca6cc58ebd5d, 0xcce15cd0ac09, 0xcf3453f24a96, 0xcfe7a9f3f5e6,
+    0xd21900f54454, 0xddb6afd2403c, 0xe0b57085cbb4, 0xe2a5d0dcb396,
+    0xe2ab88d443d3, 0xe3e9b7f49d3e, 0xe3efaa7b3340, 0xe5618bdea495,
+    0xe8f44c09c4f0, 0xe94f5664bd36, 0xeaa42ad451d0, 0xec159719b79b,
+    0xef00abc152e5, 0xf0d1b58e245a, 0xf2582d399cf1, 0xf28ba4edaf16,
+    0xf2b0edfe5b85, 0xf4db2dadf718, 0xf4e837863ff5, 0xf51497e89f3f,
+    0xf5504bb98118, 0xf7a133d3ebb8, 0xf80b1c7f5b03, 0xfa714a8ede34,
+    0xfc020d5e5a6c, 0x0298d1168af2, 0x0865d40cdabe, 0x09f23e3ee957,
+    0x0b1868b5e4f5, 0x0bca99ec736c, 0x0c4cfc5f4c0e, 0x0c5831bf66c3,
+    0x0df5c3d82613, 0x1069364ef7e6, 0x11cb1496d55d, 0x1400fec4972f,
+    0x14e7cc7a54a4, 0x175302d456c3, 0x175f978e9416, 0x1773f3479593,
+    0x182a07fd861f, 0x18519aa46c40, 0x1930a15b658b, 0x1e9ef10660c1,
+    0x21ab30b040a0, 0x23d70a490496, 0x2402f58d5856, 0x284cb4aa8051,
+    0x2accf66a953c, 0x2bf52fe8c4c7, 0x3090235aecf1, 0x32e8d2741bbb,
+    0x349f18e34a27, 0x35243a0a6805, 0x37d7f37e8f15, 0x382ac1bcb7a7,
+    0x39942c745383, 0x3b02da057412, 0x3b17704d7b54, 0x3bcc9ac754cc,
+    0x3cb7116d6e91, 0x3d581abd4c51, 0x40e4e861d6aa, 0x413f7d9ec366,
+    0x41b4268c41dc, 0x41ebbc4ebbdb, 0x4399c6a11a93, 0x4455934e9b5c,
+    0x45abc7185294, 0x466b3cb62fad, 0x4688bec817f2, 0x47c678ca65d3,
+    0x47d3bd31b475, 0x48e0be34356d, 0x4a76fe6c3c1d, 0x4ada7f7309dc,
+    0x4c7ca4b83c90, 0x4f20959fd587, 0x51f629c3527b, 0x53b5216d5c25,
+    0x53c908085218, 0x55c3ee124360, 0x5768977cb58b, 0x5818448e048b,
+    0x5a60b5fc99b2, 0x5b63314c1144, 0x5d5f76b0252a, 0x5edba3379a20,
+    0x5ef5b6e82bda, 0x5f9351d6b30f, 0x5ffd7511281f, 0x62edcc92481c,
+    0x63bafbb57f61, 0x6429b21c67d5, 0x64e4cf1cd74f, 0x659391f20f3b,
+    0x664b4091282a, 0x6748ee252693, 0x678810575ef7, 0x6791abce5149,
+    0x692aaaba9d35, 0x69bd104909b5, 0x6a2ebff33d2a, 0x6a4feaf581bf,
+    0x6bb3289781d4, 0x6be506626b96, 0x7156c46ebd57, 0x77e670d53b02,
+    0x7ada36b7b18a, 0x8059e8d8633c, 0x809aa4f3c403, 0x81951eb571a6,
+    0x836db4f20180, 0x838335761fd6, 0x847204b9a73a, 0x849e9fab0c49,
+    0x8689f7b7753d, 0x8894e0dcfa63, 0x895f16bce6a7, 0x89b518cd5fff,
+    0x8ccc534854ce, 0x8e09230e8b9b, 0x8e68fb24fd0a, 0x90d7da6f5b79,
+    0x91e9e3bc5b5f, 0x95f73f5b9032, 0x95fc823514e5, 0x96ab0a7b470c,
+    0x999cc15689a7, 0x9bc5dc3b0861, 0x9bf23c9d67ab, 0x9f9d3c00eb98,
+    0xa42a3b5eb3d1, 0xa538612c51a2, 0xa77d29aa8a79, 0xab91277d669d,
+    0xac594e561ef4, 0xaf22884e645a, 0xaf888d7a7190, 0xb3fc6363bf23,
+    0xb49fb649d695, 0xb883e558fce4, 0xb9017a105c5d, 0xb937eb07b983,
+    0xbabd3de81541, 0xbc3302cae8ab, 0xbd2c57c17975, 0xc1e6a1bdb80c,
+    0xc277e28107b3, 0xc564cba0d125, 0xc6df23b017f3, 0xc899130fa2af,
+    0xc992dd243f03, 0xca0b69911f8e, 0xca9433a899bc, 0xcdc45d09036c,
+    0xd0cec83ab523, 0xd1315f056bce, 0xd141d73f0b36, 0xd1ebcc58c9f9,
+    0xd20aadc4d4dc, 0xd21b608d7a09, 0xd2411ebc3202, 0xd2574eed61a7,
+    0xd2c7d9cc7843, 0xd2f76e012853, 0xd394ce60a9c3, 0xd45c0afd4b06,
+    0xd770c153005e, 0xd9ad132563bc, 0xdc59b547d7f1, 0xddcc80e7605a,
+    0xe55df8f04448, 0xe66e68541bcb, 0xec4f17565bc5, 0xeea9d576be7c,
+    0xf03ad2d54079, 0xf3d13bf0bd24, 0xf78cee1ce63e, 0xf8e61b29e877,
+    0xfd24a4e6f5bd, 0xfd560d93d3f5, 0xfdb2022ae34f, 0xfe3eafc1bf92,
+    0xffdbccbc7358, 0x01efdc3adf46, 0x02c7cba025b6, 0x0814f048dc57,
+    0x0cc9bcdc9076, 0x126ce395c06f, 0x1531ff7f9dfb, 0x15a1659397be,
+    0x17c5b2bc9d4f, 0x185ad6ff4f0b, 0x1a98c2badacc, 0x1c2b94918af1,
+    0x1d7c1063b1ec, 0x22377f2b0d5c, 0x227f18091b39, 0x2374fe9e5578,
+    0x2486583e440f, 0x24e1d7b747df, 0x25e32e3ba298, 0x26fab4f12cf6,
+    0x29da59a9a7c6, 0x2a1aa0a6fd03, 0x2d354983485d, 0x2f95bf9b3b51,
+    0x3107a0feaca6, 0x328c4431f715, 0x3385d3b78da4, 0x33b9c089ab53,
+    0x35cce5cc002d, 0x392c2e430e63, 0x39cff647315f, 0x3b6a19fe9a24,
+    0x3df7dab50376, 0x3e060958692c, 0x3e598743a30d, 0x3eba0f0725cb,
+    0x3f4ef8bad1c2, 0x3fb2eedfab0b, 0x417763f23f2d, 0x41a64879ddee,
+    0x4307ec32b5a0, 0x44e42b5fa1ca, 0x464644368506, 0x488d1bbbf1ca,
+    0x488f65522b7c, 0x4dff4ee64f15, 0x527cfad594bf, 0x52806936eb4a,
+    0x53401963ce28, 0x5541671260fc, 0x56e2678c76d7, 0x5874c4451b72,
+    0x58d80abce36c, 0x5a25182db3dc, 0x5bea777c5f12, 0x60e333fdc4be,
+    0x61546e89eca9, 0x62c5dacf5274, 0x638d176bf3b7, 0x64c17086550b,
+    0x6712932fc570, 0x679c0cf450ed, 0x6c8416ad116c, 0x6d008699540c,
+    0x6f64308397c6, 0x6fc9fb209f37, 0x6fe658676aa3, 0x71270bad0385,
+    0x71b811e14d67, 0x740fd6be651d, 0x741d55b4b984, 0x74e1d39d158b,
+    0x757c3ab94bfa, 0x77462d346a94, 0x782200191319, 0x7ab27f83c1a7,
+    0x7add45fcf88e, 0x7b142c12613e, 0x7b7a6bcd7439, 0x7cfcc56a84f6,
+    0x7d6bb660732f, 0x7e942a6da87f, 0x7faa51c9103f, 0x84de126e4675,
+    0x8686d9e7207a, 0x8918b8abf1a6, 0x8b2c187d4c45, 0x8d259f2d1aef,
+    0x90501095f462, 0x935ba092c2f2, 0x9525cd9ce751, 0x9545d3d40f0d,
+    0x956ae255b5b7, 0x957234366e57, 0x99e72eead8c3, 0x9c34a8a3ecd8,
+    0x9c7021e5c8ec, 0x9cd1ce746883, 0x9df4c519135b, 0x9e1923eda8b6,
+    0x9f562e42e548, 0x9f6b39a8f814, 0xa28d3465fc0e, 0xa39aaa868890,
+    0xa4c56829f792, 0xa507be2e80bc, 0xa6265c35bdf5, 0xa65abe25e72e,
+    0xa7ea96b94c52, 0xaa3acf26a5a3, 0xaa484e1cfa0a, 0xaa79f158de07,
+    0xabc7e905c58b, 0xac1f0fe15bbc, 0xac7fd233e43f, 0xac837b24408f,
+    0xaedb7a905e0a, 0xafae61ab258c, 0xb03ca92b2a32, 0xb0a691d6997d,
+    0xb23afd967205, 0xb246a8149844, 0xb2a55b5fecda, 0xb32e25776708,
+    0xb42c0d9a6b36, 0xb4c291373f90, 0xb65612bb0104, 0xb90bdb365c01,
+    0xba09fde865f4, 0xba724caaacdc, 0xbbad47f8b581, 0xbbda1d792055,
+    0xbc6f41bbd211, 0xbcb62aecce9f, 0xbd1e049109fd, 0xbd61f47ebb8a,
+    0xbe926a19bac9, 0xbf8f6800a7e3, 0xc23910dfd117, 0xc34268f1f5bf,
+    0xc3c6654df6c4, 0xc96b9b0e5aaa, 0xc98c50f293b5, 0xcb21e17d8916,
+    0xcc46ac9a6216, 0xd3c82187b226, 0xd6a800cf32bb, 0xddca12c41ce6,
+    0xe1d684273aa5, 0xe2ce04a59d47, 0xe3a160de7053, 0xe63bf0de1cbd,
+    0xeaa6a06e8388, 0xec1cda6f627c, 0xf0b71e347957, 0xf3af3cb45d7e,
+    0xf52660f15386, 0xf55dbc24c7c0, 0xf6595ab1923c, 0xf68ea6ddd289,
+    0xf8673d1a6263, 0xfa40f8220f16, 0xfbbd99c78f96, 0xfbd7e8072715,
+    0xfe844f9a9585, 0x0182261209e9, 0x01fc11d90d12, 0x0239d4b122d8,
+    0x037f55b234e3, 0x0503be56798d, 0x07ab92bd7499, 0x09766f74aa47,
+    0x0a2029ff6345, 0x0b9a820eaa13, 0x0bc9dbb4545e, 0x0c0a97cfb525,
+    0x1476bdf224bf, 0x1ac364a707f1, 0x1e43d8205abc, 0x1ecb7d6cb811,
+    0x20a39e8b3c61, 0x22307ddb5684, 0x226fa00d8ee8, 0x22b7ae09a84f,
+    0x2315b1a7eb96, 0x2339264069dd, 0x24cfa1077652, 0x27605b012aa5,
+    0x294794ff2bbf, 0x2aa4a58b900d, 0x2c44f6589499, 0x2ca78d234b44,
+    0x2e9409fad111, 0x308813421543, 0x3544e1639351, 0x3659a964d873,
+    0x3a7e948f5f89, 0x3a8954d16eb4, 0x3b1bba5fdb34, 0x3c7b4f117ef9,
+    0x43dfb70af24e, 0x44e1f7cb641b, 0x47d841d31a1a, 0x4c4993972836,
+    0x4dfc6ba50017, 0x5113e0aefaab, 0x51be1057bf33, 0x53f8c840fa2e,
+    0x5586cc5c312a, 0x55f966427bb3, 0x5609de7c1b1b, 0x5730f32f2dcd,
+    0x5b0818660b3f, 0x5c217393c3c5, 0x5c24e1f51a50, 0x5c4bff7df4e7,
+    0x5c9bd478d278, 0x5df5b132e600, 0x6016556b8f41, 0x6091db1bbacd,
+    0x6cd9f4308102, 0x70ec581a34c3, 0x716cab85d978, 0x73749b68139d,
+    0x75af18c248d3, 0x76e71acd0677, 0x7913dea1e181, 0x7956e4537bfa,
+    0x803874bc0b23, 0x80649a8f64a8, 0x80ad1da98999, 0x83c03a16168e,
+    0x83e7579ef125, 0x8577dfdf6798, 0x857ef7311a73, 0x8afc252c8cae,
+    0x8e2cc3ab01e8, 0x8f0e13f834e5, 0x908cc4a4e952, 0x90a1957bf659,
+    0x90a628a869bd, 0x90ca125ef38e, 0x9361a91b54f7, 0x94415f7f5f91,
+    0x954783bf3373, 0x9658dd5f220a, 0x96941c11f859, 0x96d2540819a9,
+    0x986cecdd8df8, 0x99349e983ac5, 0x9b4a0d70c951, 0x9be4e9ab0b4a,
+    0x9c2396bf3824, 0x9c356e52fa2a, 0x9cc25678dc32, 0x9cf016355e1a,
+    0x9d12db20cb12, 0x9d19f2727ded, 0x9de044d3081c, 0x9f24a108fd4e,
+    0x9fe0a84583dc, 0xa001d347c871, 0xa06519bf906b, 0xa110a8c27791,
+    0xa3cd4e007fa4, 0xa5f4946cd036, 0xa5f5098adbc0, 0xa6c2adcc1e8f,
+    0xa766eaee4d15, 0xa831d07b4aa8, 0xab1ad61bb205, 0xab791448fb11,
+    0xadf5e7a7b971, 0xaf933f3172fc, 0xb0ac5fd025bd, 0xb144083816f0,
+    0xb22851c894ee, 0xb2f24d197b6d, 0xb53ccd8f4481, 0xb6dce3cd4348,
+    0xb8e374555acf, 0xbc02b05e198d, 0xbc4df22c83ba, 0xbccd95eb1720,
+    0xbcd64725f25e, 0xbd1c461ad7d8, 0xc10960f3df2a, 0xc1ae885224c4,
+    0xc1f1c892c502, 0xc6c5b12189c9, 0xc9f231919729, 0xc9ffb087eb90,
+    0xcecb5cf9e0a3, 0xcfa7a4fc94b2, 0xd0e683c9ff6c, 0xd1a6e3a3f399,
+    0xd22aa570eed9, 0xd379123be1e7, 0xd4258b7ae021, 0xd589ede7fd0f,
+    0xd69813b59ae0, 0xde8a4e110751, 0xdf2a3295c838, 0xdf941b413783,
+    0xe6359f3b7734, 0xe8b6561997a9, 0xea804894b643, 0xeb51d0555b27,
+    0xed0607bd55a6, 0xed297c55d3ed, 0xf0ce4ea3bc13, 0xf1cce673d190,
+    0xf51b419334d4, 0xf5d0e12b19d6, 0xf67eb9c43aae, 0xfaff9985d11e,
+    0xfb66fe0c00f2, 0xfdd31ea21a25, 0xfeccae27b0b4, 0xffe852eba2ec,
+    0x02f6a19cb6b8, 0x0908b94bd4ea, 0x0a041d4999a1, 0x0c7a4e74b0b0,
+    0x0cc17234b303, 0x0e3fe85261ab, 0x1239d274ac15, 0x12cbc2e50d0b,
+    0x136998629a05, 0x174d5253b4ca, 0x1b3773e9711b, 0x1cb6d44336d7,
+    0x1cf92a47c001, 0x222cb05df072, 0x22fe72ad9b1b, 0x230a57bac71f,
+    0x280c75241958, 0x28cb75a3eae7, 0x296b94b7b193, 0x2bf412949632,
+    0x2c2ccd222d0a, 0x2c8f9e7be97a, 0x2cbbfede48c4, 0x2d79a003f7b5,
+    0x2ecd151969b1, 0x2fe5c09a10e8, 0x30288bbca59c, 0x35fbf65796f4,
+    0x3651f868104c, 0x36a86f96952e, 0x38bb5a49e443, 0x39ea708ac0e4,
+    0x3b67fc6c5878, 0x401d3e1e1821, 0x42161520d57c, 0x440550aca085,
+    0x4519de1edfe2, 0x4597ad654520, 0x48c9e5cce2bd, 0x4a69c17bdbbf,
+    0x4ade6a695a35, 0x4aea4f768639, 0x4c61e8d187cb, 0x50dfcf4fd33a,
+    0x510ca4d03e0e, 0x52e5b02ad972, 0x54880fff11eb, 0x59fa7db874fb,
+    0x5c88b38ce9d7, 0x5e68d6393816, 0x63323914f377, 0x655d6300a61e,
+    0x657aaa83889e, 0x6631345784b4, 0x66b7052bb3e1, 0x67bd63fa8d88,
+    0x67e989cde70d, 0x67f35fd3df24, 0x68ffb1294ecd, 0x6da689a8a2fb,
+    0x733220d680b1, 0x74d5a575d603, 0x7f23c2bcc204, 0x7fc7ffdef08a,
+    0x7ff80931ac24, 0x82ef3d757937, 0x83650b2e1486, 0x84a499a8908f,
+    0x85b9d6c7e13b, 0x8634accafb78, 0x87634dedcc8f, 0x87cc86ec2a8b,
+    0x88c4f1a6a441, 0x88f9c8b4d904, 0x8c5cba1c438a, 0x905d46723545,
+    0x91073b8bf408, 0x93067a3352ef, 0x93f0b64a66ef, 0x95ba33a779ff,
+    0x9675c5c5f503, 0x9740ab52f296, 0x9ba060124471, 0x9c1afb8658e9,
+    0x9ca17c079965, 0xa0d80436dcbc, 0xa20804b3d071, 0xa43f13acaf1c,
+    0xa5a6a9ec1cd0, 0xa7605ebca1c7, 0xa8149efa642b, 0xa8c02dfd4b51,
+    0xaa32847ec830, 0xaac2a076fafe, 0xab4b6a8e752c, 0xad5444acc665,
+    0xb0badf048d3b, 0xb175fc04fcb5, 0xb318214a2f69, 0xb55313c27029,
+    0xb5ed7adea698, 0xb71c1c0177af, 0xb91184a2de7f, 0xb91ae58acb0c,
+    0xbab543d13996, 0xbd16a425439e, 0xbe341d6163fe, 0xbf97959269d8,
+    0xc03f7ba4f4ae, 0xc0c4d75b1851, 0xc17c85fa3140, 0xc22d9265a2de,
+    0xc230168ae255, 0xc23cab451fa8, 0xc53195f2b309, 0xc5bc34825b5f,
+    0xc602e3245228, 0xc729482a538b, 0xc746551e3046, 0xc86393cb4ae1,
+    0xc871fcfdb65c, 0xc8e92a107449, 0xcb05eac9bb75, 0xcbc81f1bddca,
+    0xcc3d77b66d8f, 0xcdaa50cf5ff6, 0xcdbdfcdb5024, 0xcdd79b6dd654,
+    0xd135bf19c7b1, 0xda78ea6566a7, 0xdc9fbbb3abaf, 0xdd5a290709da,
+    0xe2d3e8a1258a, 0xe6c4374c7da2, 0xe9e965dbd262, 0xed265e857f2a,
+    0xf09cfbf8d9de, 0xf1ae9027ce3a, 0xf1f32fc29116, 0xf2c86073924a,
+    0xf43deac75fef, 0xf743131f8cf3, 0xf76144de8687, 0xf8a5dba3817e,
+    0xf9fe1e746ca3, 0xfaa4dfbbdaa0, 0xfaf529d4c3bb, 0xfb14baeddfed,
+    0xfbe642ae84d1, 0xfcdf22870a11, 0xff56787d3df9, 0x01cca9a85508,
+    0x0db6f9adddbb, 0x0eefe5f4b273, 0x11aff9941111, 0x1293ce068385,
+    0x148f63bd861c, 0x15a96e984ff1, 0x19d9c9b1f781, 0x1abe1342757f,
+    0x1c62821de1e5, 0x22e35033e88b, 0x2622cd02d4ca, 0x2668cbf7ba44,
+    0x28153c60f099, 0x2928df9718e2, 0x2d1c6214c1c0, 0x32064045b067,
+    0x331d8c6c3500, 0x339778333829, 0x349734ce6a7f, 0x3656dc258578,
+    0x3a25502187ac, 0x3a308581a261, 0x3ab65655d18e, 0x3d33d961a13d,
+    0x3f5ec8be4e1f, 0x419109fbb3a1, 0x41acb7956dbe, 0x4358785192c4,
+    0x4404075479ea, 0x44bb40d5874f, 0x45c3e93a9aa8, 0x45f308513f2e,
+    0x4747dcc0d3c8, 0x4863bc13cbc5, 0x48ac3f2df0b6, 0x4a83b09f63b7,
+    0x4b5bda93afec, 0x4c2f715b88bd, 0x4c3f74771c9b, 0x4e3a951013a8,
+    0x5315cf7f910f, 0x55cb97faec0c, 0x57a58d919e84, 0x57e7e39627ae,
+    0x58bbef7c0c09, 0x5aa11a72d936, 0x5cf068a41b73, 0x5e57fee38927,
+    0x5f56d142a469, 0x6296c32f9c32, 0x649885fc3a90, 0x6599a1f18f84,
+    0x67d1260879b9, 0x67f12c3fa175, 0x6ab891bfb8b3, 0x6c214cca4340,
+    0x6eef19eefc0a, 0x6f86c256ed3d, 0x6ff8e71f2c3c, 0x72c345e28e7b,
+    0x7581fa27ca7b, 0x796b31816fb8, 0x796df035b4f4, 0x7c9f03d235b8,
+    0x7d49337afa40, 0x7d7a9c27d878, 0x7f645a4b1909, 0x82422a8b65b1,
+    0x873d6b320ad4, 0x8f79fd1fca5e, 0x91f3621d3233, 0x924be852eb02,
+    0x9278f8625b9b, 0x9350ad389c46, 0x93fe4b42b759, 0x9495f3aaa88c,
+    0x949be6313e8e, 0x9557b2debf57, 0x958e98f42807, 0x961678cf8b21,
+    0x967ddd55baf5, 0x978a2eab2a9e, 0x98a1b560b4fc, 0x98df3da9c4fd,
+    0x9a2533c8e292, 0x9a9633c604b8, 0x9d51048bdea3, 0x9f53b1949415,
+    0xa057c6cd340a, 0xa270de961ee6, 0xa2b19ab17fad, 0xa324e444db85,
+    0xa49775555e29, 0xa5caa9a4a2a4, 0xa6099147d543, 0xaa0fd595573b,
+    0xaaf160718ffd, 0xacb1b775bc45, 0xacf0d9a7f4a9, 0xaebf99de8c6c,
+    0xaf16c0ba229d, 0xb135cb09a37b, 0xb2c7b2a43c8c, 0xb3daa62d5386,
+    0xb4fb18acbee7, 0xb74b511a1838, 0xb92d483e949f, 0xb9542b386971,
+    0xbb7f1a951653, 0xbf822b104785, 0xc3bf5573322d, 0xc439b65840e0,
+    0xc469bfaafc7a, 0xc9ae6da7dda2, 0xca2e86847c92, 0xcb6822786299,
+    0xcb86c95567b7, 0xcb9a756157e5, 0xcbcfc18d9832, 0xcda9f1b3506f,
+    0xce1c510a9533, 0xceb9b16a16a3, 0xd7b36f5f7994, 0xda0875884c0e,
+    0xda7c3439b370, 0xe5443d47a29a, 0xe77bfbed9294, 0xece1d4ecb851,
+    0xf30798a7c6b1, 0xf404d11db990, 0xf625afe56896, 0xf6a21fd1ab36,
+    0xf7ffa57c1b0e, 0xf8d71fc355f4, 0xfaff8afac35f, 0xfc6218efb225,
+    0x03a1acf68495, 0x04eb8695043f, 0x05b71bcf1321, 0x05fd5552fe60,
+    0x07617d311589, 0x07c27a12a3d1, 0x0ac29a2051e7, 0x10419c93f24a,
+    0x1382eddb0cb1, 0x148fb44e87e4, 0x15f4514aaa97, 0x1706cfb5b607,
+    0x1872497485d0, 0x200bfd9a3972, 0x21db6d7de284, 0x2678e5154a25,
+    0x2c3cfc41b8ee, 0x2e238692a8b9, 0x2ecfc542a12e, 0x30943a553550,
+    0x3369595aa6ba, 0x3380e8e5f8fd, 0x37433d45c968, 0x382b2fc6a3b6,
+    0x389f639616a2, 0x3a1ec3efdc5e, 0x3aa62ead33ee, 0x3fd3128fbd0e,
+    0x3feb17391adb, 0x41d79410a0a8, 0x420bf600c9e1, 0x4331e5e8bfba,
+    0x4397b085c72b, 0x45187039af85, 0x479d0a97320f, 0x495b52942a6a,
+    0x49659db82e0b, 0x4aa023e82b26, 0x4b54d943f914, 0x4c8a91b87d06,
+    0x4ed4628134cb, 0x4fd83d2acefb, 0x5148bf341db2, 0x51f99110898b,
+    0x530189c88b95, 0x550f6c315bbc, 0x55a037d69fd9, 0x55f96db969f7,
+    0x5a8ac5b49fcf, 0x5ac60467761e, 0x5b19f770bb89, 0x5bceaccc8977,
+    0x61a8447d1696, 0x6444e383f6ed, 0x661c8f846fb3, 0x68da598d949f,
+    0x6a29b0949ec1, 0x6dca9f6324d2, 0x6fb8f0b2d8c7, 0x7066542dee15,
+    0x707f08845d31, 0x7260156cc284, 0x73b1b60a0658, 0x78eba3c4d855,
+    0x7c78716962ae, 0x7ca8efda29d2, 0x7ef8037c664a, 0x7f11a20eec7a,
+    0x83da8fcc9c51, 0x86d3236a8c02, 0x88892f4ab4a9, 0x8b26b88dac14,
+    0x8e4b376fef85, 0x8f365dc31a99, 0x8f8d0f80a540, 0x90b3e9a4b22d,
+    0x90dbf169a3d8, 0x974cf6f31c65, 0x97d49c3f79ba, 0x9879fe2cc519,
+    0x9a917c0c8792, 0x9b3c20d357a4, 0x9d1bce619a59, 0xa1224d3e2216,
+    0xa4efd6fe0d36, 0xb01aebf4be95, 0xb46f6b53f5bb, 0xb501213550ec,
+    0xb59bfd6f92e5, 0xb7e6f3036783, 0xbd52be892342, 0xbe72f67988de,
+    0xbf69522ccea7, 0xbfa9240c185a, 0xc16d991eac7c, 0xc39554a90898,
+    0xc43364b59b57, 0xc4ef6bf221e5, 0xc6bea146c532, 0xc6c508eb66be,
+    0xc7773a21f535, 0xc920ebd6e64e, 0xcdac8bda8be9, 0xce3590810bdc,
+    0xcfa10a3fdba5, 0xd118a39add37, 0xd226c9687b08, 0xd3a2f5efeffe,
+    0xd43f6c135a5a, 0xdb1eb2e5afd1, 0xdeb640cc4955, 0xdfe25dc9daf5,
+    0xe1e127532e52, 0xe5182d764518, 0xe76b5f26e96a, 0xe8ae5c02bbfe,
+    0xed87124cf9ee, 0xedc968518318, 0xf1ec4474d641, 0xf288f5274662,
+    0xf298f842da40, 0xf6af051cea51, 0xf8e8983b0873, 0xfa3cf78c9183,
+    0xfade75fa7acd, 0xfc10857ea26f, 0xfc5649e48224, 0xfc5fe55b7476,
+    0xfc7f767490a8, 0xfe600e3eea71, 0xfeca6c086546, 0x021bc06b138b,
+    0x0a4c6d4ba711, 0x13650cad14e5, 0x147506f2e0de, 0x154c46ab15ff,
+    0x1d9ff3061c42, 0x1f988f79d3d8, 0x25fc509bfdc3, 0x279eb070363c,
+    0x289b393917cc, 0x2f24b889f9b0, 0x30ac1a71895b, 0x335acb9b317d,
+    0x338a5fcfe18d, 0x3972d5d0e5b1, 0x39cc0bb3afcf, 0x3d3e50899ce4,
+    0x4127fd014dab, 0x4204450401ba, 0x4244c6905cbc, 0x427669cc40b9,
+    0x45f45920540d, 0x473546f4f2b4, 0x475f5dc1184c, 0x4a47ee43741f,
+    0x4bb5ec27835f, 0x4c1b41a67f46, 0x4d41a6ac80a9, 0x4d8b4e91c273,
+    0x51c803d6a191, 0x5344e00b27d6, 0x53d8a4f3b6f4, 0x55b4347391cf,
+    0x564f85cbdf52, 0x570266af7f18, 0x593bf9cd9d3a, 0x59868beef618,
+    0x5bfc0d6cfbd8, 0x61630b373e6e, 0x61de56586435, 0x653b553938b9,
+    0x65c5f3c8e10f, 0x6b26c47d87de, 0x6dd15798c826, 0x6ec6c90ff6db,
+    0x6f6513ab8f5f, 0x71005c2e14fd, 0x71147d5810b5, 0x7249861f8358,
+    0x7725e55a1d98, 0x77ebfd2ba202, 0x77fe49dd6f92, 0x7940d19b369c,
+    0x7982b281b43c, 0x7a84b8b32044, 0x85090c6263a6, 0x85b87ee4ace1,
+    0x87b8a7c822dc, 0x8a9259fa07aa, 0x8bf76c1435e7, 0x8c064a64acec,
+    0x8c67bc6446be, 0x8ccf5b797c57, 0x8da11dc92700, 0x92df9eb06c61,
+    0x944fe62ab553, 0x948c49a8a87b, 0x94dc1ea3860c, 0x97ba9e90e403,
+    0x98348a57e72c, 0x99340c6413bd, 0x9bb4139522e3, 0xa42960107945,
+    0xa95ef52ddda3, 0xad11bb9025ba, 0xb413c74de829, 0xb5f9dc80cc6a,
+    0xb7ad29acafd5, 0xb86c2a2c8164, 0xb93c1803fde5, 0xb9b011446b0c,
+    0xbdab954fddd9, 0xbf416069d8ff, 0xbf875f5ebe79, 0xc12fb1b98cf4,
+    0xc1c96928b214, 0xc463497b4d2f, 0xc5200064e50c, 0xc5cfad76340c,
+    0xc6abbae9e256, 0xc8389a39fc79, 0xc8ecda77bedd, 0xc8edff42dbb6,
+    0xcd44531a4104, 0xcf677b7829bc, 0xd0894d51b7bb, 0xd287672df9c9,
+    0xd335053814dc, 0xd47be5934985, 0xd75d2434ecb8, 0xdee242129918,
+    0xe29ccf73a559, 0xe9473f37c60d, 0xeb48c7755ea6, 0xeba35cb24b62,
+    0xec738518cda8, 0xedd4b3b399d0, 0xef0c40a04bea, 0xf31ea489ffab,
+    0xf3a5250b4027, 0xf916e31791e8, 0xfb7a8d01d5a2, 0xfd385fe0c273,
+    0x0096be1bb995, 0x0652240d4d20, 0x07266a823740, 0x0929178aecb2,
+    0x0d1164a87adb, 0x0de411343c98, 0x0f1202a9fc60, 0x1456eb35e34d,
+    0x16807b386d91, 0x16ba5a912142, 0x2081bcc7c702, 0x20ae924831d6,
+    0x21b984437ee1, 0x222f51fc1a30, 0x272c2c8be7b6, 0x2e2a8f594dd5,
+    0x30db4f8a29e4, 0x325a7554e9db, 0x330ad2134a2a, 0x338cf9f71d07,
+    0x38a87b63efab, 0x3c0af7ad4ea7, 0x3cbb546baef6, 0x3fe082fb03b6,
+    0x425a5d167715, 0x471b49465cfd, 0x4949e193662f, 0x498fe0884ba9,
+    0x4be5965e2f72, 0x4d4022c55449, 0x4e4c741ac3f2, 0x4f525dcb920f,
+    0x504fd0d08ab3, 0x5148761a0a2e, 0x51a08731b773, 0x525835d0d062,
+    0x5745f781211e, 0x61452a0946a2, 0x6af5e6f0b133, 0x706177e7672d,
+    0x70abcf79ba46, 0x7171379e2d61, 0x72d4002221ec, 0x73ea277d89ac,
+    0x74a8ed6e5576, 0x76777315e774, 0x7b57b5cfe3c9, 0x7d151390c510,
+    0x7d18f7102725, 0x7e8c37cdbb18, 0x7eaefcb92810, 0x7eee94096bfe,
+    0x801692f895c4, 0x81289c4595aa, 0x8447d84e5468, 0x84de215c22fd,
+    0x87954931a098, 0x88ba144e7998, 0x89782a923413, 0x8a384fdd227b,
+    0x8d8a195ddc4a, 0x8ec5fee7fc03, 0x90f19df1ba34, 0x92c3cc89a882,
+    0x979c82d3e672, 0x98d44a4f9e51, 0x991f8c1e087e, 0xa05d111da701,
+    0xa2f8163b5ef5, 0xa470d4617d60, 0xa65674765617, 0xb17828851ae9,
+    0xb2d5ae2f8ac1, 0xb78ff82bc958, 0xb8b781fce794, 0xb90df92b6c76,
+    0xbadfb2a54f3a, 0xbf72a489ad75, 0xc2fb8eaed5b9, 0xc4ebb476b7d6,
+    0xc6dc89ebab42, 0xc7d09c08b759, 0xc826638a2aec, 0xc834579e8add,
+    0xca27b138bdc0, 0xcb7115b931e0, 0xcbba48806820, 0xcc77e9a61711,
+    0xccbb9f04c2d9, 0xd185b18d8f89, 0xd73950805eea, 0xd786dbe502c9,
+    0xd9e78c8bfb82, 0xe0a7b7634051, 0xe123ecc07d2c, 0xe7e710db0cfc,
+    0xea5540785a1c, 0xee2bb602263f, 0xf51d0ef74381, 0xf6d9827c0db4,
+    0xf84536c9e342, 0xfc15f45c1f28, 0xfeb810cb89f7, 0xff83e0949e9e,
+    0x025d1da877e2, 0x08bf44e1796a, 0x09b05dbb3a80, 0x0bdc71e3043b,
+    0x1a9db5662c80, 0x1da9ba81069a, 0x1eeaa855a541, 0x1f6f8eedbd5a,
+    0x24aebf82140a, 0x2637bb52cc18, 0x2695f9801524, 0x270cb174c787,
+    0x28146f9dc3cc, 0x2875a70e57d9, 0x28aba2e7a975, 0x294cac378735,
+    0x2972df844ab8, 0x2daa51efa523, 0x3020bda9c1f7, 0x31eaeab3e656,
+    0x36fbabdea9cf, 0x36fedfb0fa95, 0x38825e19282b, 0x3a56d647502b,
+    0x3ec91247755b, 0x40445492d33d, 0x4163a24721c5, 0x419286cec086,
+    0x42886d63fac5, 0x43e19a70fcfe, 0x48bc25336916, 0x4ada0ab7cd1b,
+    0x4c05b2975331, 0x53383cc5dcc4, 0x544578576381, 0x5e7ec4c7427b,
+    0x5f922d6e64ff, 0x636952a54271, 0x674a13531235, 0x6821187c4191,
+    0x69df9b083fb1, 0x6ad0ee71068c, 0x6b7d2d20ff01, 0x7049c3cf0b28,
+    0x70a99be57c97, 0x714771630991, 0x72f6db0f8ae7, 0x74e4b7413352,
+    0x7501fec415d2, 0x75d34bf5b4f1, 0x78c41894e078, 0x792d51933e74,
+    0x7b4494e3fb28, 0x7cf0cabe2bb8, 0x7fed41db7d7e, 0x85255b1e2153,
+    0x8d36b1749e6c, 0x8ea10668515c, 0x91cfd06e986e, 0x932ce0fafcbc,
+    0x93e3dfed045c, 0x94608a684cc1, 0x98f132b6714a, 0x9cc599390980,
+    0x9d0a7362d221, 0x9d8f9489efff, 0xa2ccb61712c2, 0xa3a4a57c5932,
+    0xa4e558c1f214, 0xa6735cdd2910, 0xa6cba883dc1a, 0xa7c4fd7a6ce4,
+    0xaa32b7f9ae7a, 0xaa8302129795, 0xab1a6feb8303, 0xacc1d80a3a6a,
+    0xadbf10802d49, 0xb22f78082451, 0xb3260e4a6fdf, 0xb32adc05e908,
+    0xb39ffa117308, 0xb79e3cd12b11, 0xb813204daf4c, 0xb8a301b6dc55,
+    0xbae9d93c4919, 0xbca318eec286, 0xbcb05d561128, 0xbde1480f1bf1,
+    0xc08af0ee4525, 0xc2b9141d42cd, 0xc3893c83c513, 0xc4de4b825f72,
+    0xc63c464adad4, 0xc93fd4b9df75, 0xccdbbb3de698, 0xceb242734285,
+    0xcfed785050ef, 0xd04a1c947198, 0xd1c4e9c1c3f0, 0xd3206064ffdb,
+    0xd3e8121faca8, 0xd60f1dfcf775, 0xd98162d2e48a, 0xda09b7cc532e,
+    0xddebd7d44590, 0xe71c06c105a7, 0xe92b4883f86c, 0xed13d0308c5a,
+    0xee518a32da3b, 0xf23cd093b365, 0xfdd8aaf88125, 0xffe7b22c6e25,
+    0x019840a40c54, 0x02f516a16add, 0x05bec5b7bbcd, 0x08dbf2b9469e,
+    0x0cd0d491121a, 0x0e58e625b314, 0x108c86bd3b34, 0x10fa8d771259,
+    0x17e1d5d731bf, 0x1fde20c79c0c, 0x25b2b02daa3d, 0x28699d742213,
+    0x2b0acfa775ce, 0x2cf74c7efb9b, 0x2d34251afa4d, 0x2d42192f5a3e,
+    0x2f6ba931e482, 0x304f43155131, 0x336349bdf53a, 0x342000a78d17,
+    0x397aa446981f, 0x3a8f31b8d77c, 0x3c1659116162, 0x3e447c405f0a,
+    0x3eaf89b6eb2e, 0x3f2ca9503f1d, 0x42f44089943b, 0x464c371fe9d1,
+    0x47a897ff3cd0, 0x49a9ab1ec9df, 0x4c840cfdbffc, 0x4ca6975a272f,
+    0x4d0fd058852b, 0x5235625a55ab, 0x526237dac07f, 0x5844bb552ea1,
+    0x58f3b8b96c52, 0x5915cdf7c7fb, 0x59840f40a4e5, 0x59c9d3a6849a,
+    0x5ac278f00415, 0x5ad8e3b0397f, 0x5b0fc9c5a22f, 0x5b21a1596435,
+    0x5b71765441c6, 0x5bae897f463d, 0x5e209c9bf572, 0x5e9df6c44f26,
+    0x5f9b69c947ca, 0x60ced8a7920a, 0x6157dd4e11fd, 0x62307c6069bc,
+    0x6648d2d0b37f, 0x6801284715d8, 0x690f1385ade4, 0x69c10a2d3696,
+    0x6b3c87079a3d, 0x6c715540071b, 0x6daa4186dbd3, 0x6f0458cff520,
+    0x731b15571680, 0x75d8a4d135a7, 0x775b38fd4c29, 0x7b8616ae6941,
+    0x7bacbf19384e, 0x7c9cb327dc8b, 0x7e82533cb542, 0x7fd5c852273e,
+    0x8095ed9d15a6, 0x812559e83725, 0x81c27fb8b2d0, 0x85dc701224f6,
+    0x93774e8f4bd8, 0x9576c7c5b084, 0x9793fd9d033a, 0x9a1aa701b9b1,
+    0x9a82bb34fad4, 0x9ad33fdce9b4, 0x9dfb2d2083b0, 0x9f2b682c7d2a,
+    0xa065793e6ebb, 0xa1dc285d5939, 0xa6365fb4209c, 0xa6369a432661,
+    0xa9188891dae3, 0xa9e3e33ce400, 0xab5848c594cc, 0xab6ad0066821,
+    0xacafdbe96ea2, 0xb0798229f7ad, 0xb82d0f713744, 0xb8a38cd6e3e2,
+    0xba4c19c0b822, 0xbc82790c857e, 0xbe8b189bd0f2, 0xbf032feaa5f3,
+    0xbf254529019c, 0xbf8b4a550ed2, 0xc06b00b9196c, 0xc09a5a5ec3b7,
+    0xc2c12bad08bf, 0xc2fe796712fb, 0xc5e5aa8f4c30, 0xc82ecbaaf2a6,
+    0xcd8e77947c9c, 0xce177c3afc8f, 0xce34c3bddf0f, 0xce987f53b293,
+    0xd041f6799de7, 0xd35c2a37ddb7, 0xd4d13f6d9fd2, 0xd7944c504971,
+    0xdd8ed4741560, 0xdf8d28df5d33, 0xe2339dec35a1, 0xe3b338d50122,
+    0xe623025b76a5, 0xedda3893128c, 0xf2dbe0de593b, 0xf32dff6f707e,
+    0xf3597595b8b4, 0xf6fd23188401, 0xf7b19de54c2a, 0xf8844a710de7,
+    0xfc1a78fd84cd, 0xfe8fbfec84c8, 0xff5b8fb5996f, 0x02e38f9eaa9f,
+    0x059e9af38a4f, 0x0675658db3e6, 0x0de6d75f7018, 0x12008d29dc79,
+    0x12e1a2e809b1, 0x1381c1fbd05d, 0x1542538f026a, 0x17e55a3a844d,
+    0x1ce986ab0a73, 0x283dc831ca56, 0x2941684c5ec1, 0x297b47a51272,
+    0x2e42d6089fab, 0x2ff205261b3c, 0x320aa7d0fa8e, 0x34360c4bb2fa,
+    0x3449b857a328, 0x345353ce957a, 0x363dc19ee75a, 0x367ce3d11fbe,
+    0x38f439c753a6, 0x39247da91505, 0x3d5acb495297, 0x3f8a4dd272dd,
+    0x4079ccc30b90, 0x40f1a982dacc, 0x41957186fdc8, 0x4489e716859f,
+    0x460145e2816c, 0x4615a19b82e9, 0x46a3ae8c81ca, 0x496ad97d9343,
+    0x4a26310d0882, 0x4b2def3604c7, 0x4ba8158c0db5, 0x4ebea059f135,
+    0x53653e4a3f9e, 0x55798857b151, 0x5656800776af, 0x571c5d49f554,
+    0x586f5d415bc6, 0x5df4523b922b, 0x5fa7d9f67b5b, 0x6010d865d392,
+    0x62870990eaa1, 0x63ae936208dd, 0x6501cde87514, 0x6e231e84be26,
+    0x6ec5fc4cca0e, 0x71d56fc8fab3, 0x73ae7b239617, 0x765cf1be3874,
+    0x76f4d4b52f6c, 0x78ea77e59c01, 0x7b059eb5baca, 0x7bd6b1585424,
+    0x7e6a9f24593d, 0x8190f27ecad6, 0x8388a4b66b58, 0x84fb7055f3c1,
+    0x861197b15b81, 0x89240470d727, 0x8ad62cd19db9, 0x8f08d1817efb,
+    0x90326a59d124, 0x95d2d25ebbe1, 0x960b525d4cf4, 0x999b1945224e,
+    0x9af56b1d4160, 0x9e2bc19346d7, 0xa38a48b1b3f4, 0xa46a39a4c453,
+    0xa7e89e16e331, 0xa92da9f9e9b2, 0xabbb6ab05304, 0xac809845c05a,
+    0xacd0e25ea975, 0xaf666a13d6f1, 0xb0356daf3c5e, 0xb1f515065757,
+    0xb213bbe35c75, 0xb27fb395ffad, 0xb365970fa60e, 0xb4abc7bdc968,
+    0xb7724301c992, 0xb7a127896853, 0xb85bcf6bcc43, 0xb8b20c0b4b60,
+    0xbd89286c60ed, 0xbf7779bc14e2, 0xc187a5bb1ebb, 0xc599cf15ccb7,
+    0xca51cf7bd19c, 0xccdee085299f, 0xcee6d06763c4, 0xcf36dff1471a,
+    0xd30f6482472a, 0xd329ed50e46e, 0xd381fe6891b3, 0xd43b46f0d305,
+    0xd6cb513d7609, 0xdf7a07f37492, 0xe417ba19e1f8, 0xe7c06fe72c33,
+    0xf07762b9fa70, 0xf1aeb517a6c5, 0xf349fd9a2c63, 0xf52f9daf051a,
+    0xf83366ad0f80, 0xf95155073b6a, 0xfad2c4683513, 0xfb0517512a5f,
+    0xfb21af26fb90, 0xfeebca859025, 0x01a835349273, 0x01e5bd7da274,
+    0x0420ea84e8f9, 0x0b8b0a75ec8b, 0x0f6bcb23bc4f, 0x135711849579,
+    0x14c041ad2b90, 0x17b61696d605, 0x1b677d9efb7e, 0x1de4166eb419,
+    0x1fea6c67c5db, 0x20fe84bbf9ae, 0x221a297febe6, 0x231dc99a8051,
+    0x25630736c4b2, 0x276b6c370a61, 0x2be4fa17e831, 0x32e94f6be452,
+    0x33b3fa69dc20, 0x3477c8a526d8, 0x34fbff902da2, 0x35c75a3b36bf,
+    0x35ea59b5a97c, 0x36e92c14c4be, 0x38063032d994, 0x393d0d727a5f,
+    0x4084a30716be, 0x41091481234d, 0x4462df8fa70b, 0x488b73aa8a71,
+    0x4c7853f48bfe, 0x4d0ba3bf0f92, 0x4d60f622779b, 0x51d15daa6ea3,
+    0x5353b7477f60, 0x553a07096966, 0x559252b01c70, 0x56dc66dda1df,
+    0x580465cccba5, 0x5baef0124408, 0x5be352026d41, 0x5dc6a8810c46,
+    0x5dd7d067bcfd, 0x60f06a3cd46a, 0x61f4ba047a24, 0x62dfe057a538,
+    0x6865bf8df2b1, 0x6b1bfd275938, 0x6bc492e6f55d, 0x6e549d339861,
+    0x71b0b1d855d1, 0x804176eab6f2, 0x81e0685d98e0, 0x82048ca32876,
+    0x836a4e6a6802, 0x8443d7b8d6d5, 0x8731e5a3bd20, 0x888ad821b994,
+    0x896a53f6be69, 0x8b1b578c6822, 0x8ccb3656f502, 0x8e5c6e447cc4,
+    0x923443286b85, 0x95c89d3cb443, 0x95db247d8798, 0x9683451f1833,
+    0x96a34b563fef, 0x96c17d153983, 0x971410c45c50, 0x9ac4530164f0,
+    0x9b56b88fd170, 0x9cb7ac9b97d3, 0x9d0ff8424add, 0xa38b48efc70b,
+    0xa48f2399613b, 0xa70345bd445d, 0xa72a9dd524b9, 0xabd818882038,
+    0xacfbbed9dc5f, 0xad0ad7b95929, 0xaef75490def6, 0xbd997c18f693,
+    0xbea7a1e69464, 0xbf6458d02c41, 0xbf9845a249f0, 0xc455c370d94d,
+    0xc7d8bb0f6b8f, 0xca0a4c9fbfc2, 0xcaefbafb5a99, 0xcd06fe4c174d,
+    0xcd8074f50eec, 0xce8365629208, 0xd11d0b26275e, 0xd247c8c99660,
+    0xd42910410178, 0xd4d37a78cbc5, 0xdc982fa6bc13, 0xddc7bb05a43e,
+    0xddea7ff11136, 0xdefde89833ba, 0xeadaf4366dcb, 0xf286ba7ee938,
+    0xf2b6c3d1a4d2, 0xf3f2343db901, 0xf7e99a3ac3f4, 0xf8d65a77176b,
+    0xfd5d674e49a2, 0xfd6e54a5f494, 0x01ab7f08df3c, 0x01e6bdbbb58b,
+    0x02503149194c, 0x06b1ba80994f, 0x128b1d2e7710, 0x1526976a3a8e,
+    0x16005b47af26, 0x19e86dd6378a, 0x1ab094aeefe1, 0x1b1af2786ab6,
+    0x1c778de6c37a, 0x20c480d63c3b, 0x240c7450fdf3, 0x268bcbd4fbca,
+    0x278d22595683, 0x2b9ab887911b, 0x2d65953ec6c9, 0x310c7693e2dc,
+    0x3a2082c8dd4c, 0x3b834b4cd1d7, 0x3bbcb58779fe, 0x3f3686cd2578,
+    0x4648d035818a, 0x4a0e92f6a880, 0x4a1b623feb98, 0x4c995a69c6d1,
+    0x4cd63305c583, 0x50a8ff9f3556, 0x510270110539, 0x590a657f95c5,
+    0x59352bf8ccac, 0x5af66d391008, 0x66239136f554, 0x6b2623be5317,
+    0x6f51ebab8743, 0x71aa9ac4b60d, 0x7453ce85d3b7, 0x756c7a067aee,
+    0x778fdcf3696b, 0x7804c06feda6, 0x79992c2fc62e, 0x79fad8be65c5,
+    0x7e18e7263fc5, 0x803de3fc56a5, 0x81bede3f44c4, 0x862659fd5ac9,
+    0x8b1490cbb70f, 0x8ef63bb59de7, 0x902a94cfff3b, 0x95aba64ad38b,
+    0x979cb64eccbc, 0x9899b435b9d6, 0x98de53d07cb2, 0x9a0c45463c7a,
+    0x9d0f24082fcc, 0xa035ec80acef, 0xa0ed2601ba54, 0xa19b73b8e6b6,
+    0xa4b1144ab322, 0xa8f04db4d1b7, 0xa8fad367db1d, 0xa9ff232f80d7,
+    0xaaea0ef3a626, 0xb0e66b8fa03d, 0xb2b47c1926b1, 0xb33c5bf489cb,
+    0xb59cd20c7cbf, 0xb5a1da56fbad, 0xb97d92ba4c83, 0xba2a46885082,
+    0xbe831e84f547, 0xbf6f69a33d34, 0xc019d3db0781, 0xc33825a7af2b,
+    0xc37b65e84f69, 0xc3a3a83c46d9, 0xc404dfacdae6, 0xc5c70b293556,
+    0xc615f5e7fbd3, 0xc63720ea4068, 0xcac49566142b, 0xcc280d971a05,
+    0xcfb2571664e7, 0xd0ecdd466202, 0xd2f800faeced, 0xd95ad7e0ffc4,
+    0xef5b74dbf4b4, 0xf16f49cb5add, 0xf2b246a72d71, 0xf350cbd1cbba,
+    0xf798f105cb52, 0xf9d3a8ef064d, 0xfcc7a960829a, 0x02f4846d43d5,
+    0x06a0337dd911, 0x097084c7d152, 0x0aa259bcf32f, 0x139f10f5a121,
+    0x13c62e7e7bb8, 0x16fe1edda992, 0x1b617c8d57bd, 0x1c2b02c032b2,
+    0x1ff5933cd2d1, 0x23103c191e2b, 0x23b05b2ce4d7, 0x2579636bec5d,
+    0x283f6991e0fd, 0x2989f2dd71f6, 0x2d957a0478a1, 0x2ded15fe1a5c,
+    0x2e1015788d19, 0x2e19b0ef7f6b, 0x2f46430b1c95, 0x37f2759bdba7,
+    0x38da2d8db030, 0x3ade39f08840, 0x4080014f959b, 0x40d46976e690,
+    0x447174c60a8c, 0x450af1a629e7, 0x456720cc3f06, 0x47fa5eeb32d0,
+    0x4d93af9e6ab2, 0x4e9197c16ee0, 0x5b3bd282e036, 0x5c3c3ecb23db,
+    0x5d4855918dbf, 0x60ac31350f59, 0x6239c0323acb, 0x64fd4232eff4,
+    0x665809291a90, 0x6e194ff5b453, 0x7365c4f159a5, 0x7385560a75d7,
+    0x755fc0bf33d9, 0x78ac476668f5, 0x8168f230c76f, 0x82aa552371a0,
+    0x83abe636d21e, 0x866766a9bd58, 0x86ce5611e1a2, 0x888c28f0ce73,
+    0x8c85d8841318, 0x8e2c5666b36b, 0x9150602aeb52, 0x92f544246342,
+    0x9785b1e38206, 0x9c3d029c759c, 0x9c78414f4beb, 0x9cc014bc5f8d,
+    0x9ccf2d9bdc57, 0x9fb8333c43b4, 0xa0ac45594fcb, 0xa28849f73630,
+    0xb20dd0d3b4b7, 0xb4817dd98c4f, 0xb7d1ad711dbb, 0xb99a7b211f7c,
+    0xb9f71f654025, 0xba7bcb6e5279, 0xbb9e4cf4f1c7, 0xbc81ac4958b1,
+    0xbe2655b3cadc, 0xc222c3fb54bd, 0xc467175b820a, 0xc690a75e0c4e,
+    0xcc20d1b85d68, 0xcca19a420da7, 0xdbb02e9ad406, 0xdf061629f5af,
+    0xe75186682c3e, 0xe86e8a864114, 0xecfb14c5fdc3, 0xedd6e7aaa648,
+    0xefc3d9a0379f, 0xf6dba0711e29, 0xf78b4d826d29, 0xf8cd9ab12e6e,
+    0xfa80e7dd11d9, 0xfd3341f7164b, 0xfe846d764e95, 0x0200fd703f4b,
+    0x0280669fccec, 0x0e6d003b8f51, 0x157698691025, 0x1a5715b2123f,
+    0x2307a0e03ef0, 0x26f2379406cb, 0x272d3bb7d755, 0x2756dd65f163,
+    0x299e64986f76, 0x31f99d63341e, 0x36691aaf1412, 0x370e7c9c5f71,
+    0x3d0c38927c26, 0x3d3c41e537c0, 0x3d6c10a8ed95, 0x3e7234e8c177,
+    0x3ee78d83513c, 0x40e40d766ae7, 0x4226953431f1, 0x454ce88ea38a,
+    0x4746e45c7dbe, 0x480485822caf, 0x49d4dfa1ecd5, 0x4b3314f96dfc,
+    0x5032e8cc8683, 0x50606dfa02a6, 0x50d083bb0db8, 0x5663a758a9d3,
+    0x5a287fddb9b5, 0x5a3bf15aa41e, 0x5a6e09b493a5, 0x5ebac21506a1,
+    0x5f49b9421c96, 0x62c056b5774a, 0x63c84f6d7954, 0x645f82b75efd,
+    0x65ff98f55dc4, 0x661fd9bb8b45, 0x6a5a456a30b1, 0x6a8a142de686,
+    0x70e29feff5bc, 0x77a9326bdc17, 0x7ca85691e34f, 0x80f6e36a8473,
+    0x821fcc95c54d, 0x8815c18d1dd8, 0x88c70887953b, 0x8b3ab58d6cd3,
+    0x8c2f3cc88474, 0x8dd24c49ce3c, 0x8dd6df7641a0, 0x9070101bcb6c,
+    0x922fb772e665, 0x930263fea822, 0x9884d4d39f10, 0x9e307238a482,
+    0xa2b8a3daf392, 0xa810c354bf23, 0xa92ace2f88f8, 0xaa8e0bd1890d,
+    0xae4d2c5f08b2, 0xb17dcadd7dec, 0xb4f84bd03ab5, 0xb8c64aae315f,
+    0xb8ef3caf3a1e, 0xba4e96d1d81e, 0xbba6d9a2c343, 0xbe51e1dc0f15,
+    0xc22bc5c731c3, 0xc93264b16796, 0xcb66ef8506ca, 0xcbc86184a09c,
+    0xcfa036688f5d, 0xd4907c3e1f90, 0xdb819aa4370d, 0xdc31f762975c,
+    0xe26ba1b89baf, 0xe961c869321a, 0xe97f0fec149a, 0xef026afd229c,
+    0xf4478e180f4e, 0xf6a83ebf0807, 0xf88b953da70c, 0xfc5207abdf51,
+    0xfcc6b0995dc7, 0xfd3c7e51f916, 0x07ad25f54c4a, 0x119cca7fe97a,
+    0x125528cc13b8, 0x17dc2ccd7e0a, 0x18f7d1917042, 0x1b120e2577f7,
+    0x1e34437181b6, 0x1f9fbd30517f, 0x21ff0e7d279a, 0x24a548fafa43,
+    0x2697b8591612, 0x27ded3435080, 0x2f069d2fcae8, 0x30666c707472,
+    0x30c385d2a0a5, 0x310c08ecc596, 0x3722ee575cf1, 0x3869ceb2919a,
+    0x3b94efc87c5c, 0x42b7b16a77d6, 0x4543d837b8c5, 0x47d198ee2217,
+    0x4ab34cadd0d4, 0x4e559ad67983, 0x4f66f476681a, 0x506d534541c1,
+    0x515630023323, 0x547c0e3e9932, 0x5b09ab9de2f0, 0x5bca8095e2a7,
+    0x5c8b1afedc99, 0x5d4a909cb9b2, 0x5e070cf74bca, 0x686b94fe6d35,
+    0x6f0c2ebc95d2, 0x70b1fcf224d6, 0x76e3e04964ff, 0x7959273864fa,
+    0x7a339ac2eae1, 0x7a4a40122610, 0x7ba92516b886, 0x7c5563c6b0fb,
+    0x7ca956cff666, 0x7e4acc6817cb, 0x84b68f180ba5, 0x87a6ac0a25dd,
+    0x8a325db95b42, 0x8a54e815c275, 0x8ebd889ef553, 0x90923b5c2318,
+    0x9771479f72ca, 0x99b6bfcabcf0, 0x9abc345d7f83, 0x9e759cf36eeb,
+    0x9facef511b40, 0xa032859644a8, 0xa1311d665a25, 0xa65f60a305e3,
+    0xa8e052102c1d, 0xad45f95613fa, 0xaf741c8511a2, 0xb19361639845,
+    0xb33ee790b786, 0xb51a01f286d7, 0xb689d44ec43f, 0xb9b654bed19f,
+    0xbb428461da73, 0xbb5ea719a01a, 0xbb625009fc6a, 0xbdd8bbc4193e,
+    0xc6c65a1d4a66, 0xc74eaf16b90a, 0xc8d5d66f42f0, 0xcd275c8b2f15,
+    0xcd6d5b80148f, 0xcfe7359b87ee, 0xd0f6bac3485d, 0xd0febc51124c,
+    0xd4a981259074, 0xe665dc6be7e1, 0xe95fcf63fa30, 0xebe812b1d90a,
+    0xef8ad5f88d43, 0xf09f9df9d265, 0xf0fa6dc5c4e6, 0xf1f179261bfe,
+    0xf3159495e3af, 0xf53130840e02, 0xf635804bb3bc, 0xf6ebcf90aa0d,
+    0xf7a4a2fadfd5, 0xfc4c2b274552, 0xfd63774dc9eb, 0xfdfa7008a9cf,
+    0xffc721380da5, 0x02b89d844a7b, 0x082c6a97d029, 0x08b82df29558,
+    0x0b996c94388b, 0x0d832ab7791c, 0x0e5bc9c9d0db, 0x15c7f8c2085a,
+    0x17b2a1215fff, 0x1a318387524c, 0x1c3d56e8ee86, 0x1db7e9873b19,
+    0x1fbeb49e5865, 0x26b75f742e47, 0x293ad50693f8, 0x29ea0cf9d76e,
+    0x2a968638d5a8, 0x2bc17e6b4a6f, 0x2d4362ea4fa2, 0x2e6b274a73a3,
+    0x300fd0b4e5ce, 0x311719bfd689, 0x318d2207779d, 0x32b178064513,
+    0x331e59f4ff5f, 0x38e6c9bedbc7, 0x3ca53a9f4a1d, 0x3ed706bea415,
+    0x40b84e360f2d, 0x4477e3e19a5c, 0x472a036c9909, 0x47c7293d14b4,
+    0x47e903ec6a98, 0x4c10733c3125, 0x4e7875c3e27e, 0x517fad23436f,
+    0x53f8d791a57f, 0x556b2e13225e, 0x59e14d92a9a3, 0x59fcc09d5dfb,
+    0x5a5128c4aef0, 0x5f579ecb6ec8, 0x5f876d8f249d, 0x6025f2b9c2e6,
+    0x61eaa25b5ccd, 0x628a4c5117ef, 0x640b0c050049, 0x6548165a3cdb,
+    0x68aa92a39bd7, 0x6c72d98a0244, 0x6c776cb675a8, 0x6f9c9b45ca68,
+    0x757e6f13273b, 0x774f03c1ed26, 0x8205e4e92b99, 0x82162293c53c,
+    0x82c8c8e85f3d, 0x83dc318f81c1, 0x860a8f4d852e, 0x87463a489f22,
+    0x8ab71fc46999, 0x8dd7bb274af5, 0x909968afd1f6, 0x97d6789164ef,
+    0x993d99b2c719, 0x9a087f3fc4ac, 0x9cefeaf703a6, 0x9d6284dd4e2f,
+    0x9d7c988ddfe9, 0x9da51570dd1e, 0x9eab743fb6c5, 0xa16435fe5cc3,
+    0xa1bc81a50fcd, 0xa7ce99542dff, 0xb70ed0e8d85b, 0xb7478b766f33,
+    0xb8c16e67aa77, 0xb9147734d8ce, 0xb9156170efe2, 0xbdf4f47ddae8,
+    0xc12b4af3e05f, 0xc333004714bf, 0xc4d1420ce55e, 0xc7782c37c956,
+    0xccb46388d505, 0xd795960b3ed5, 0xdf99e2897311, 0xe6f60e6616b2,
+    0xe83c79a33fd1, 0xe89cc6d7bcca, 0xe8f6ac679837, 0xebc7ad5ea1c7,
+    0xed1c473f309c, 0xee0aa164ac76, 0xeecdbff2e5df, 0xf39fd4097c7e,
+    0xf40cb5f836ca, 0xf43b2561ca01, 0xf4ab3b22d513, 0xf7a986b85501,
+    0xfa7a4d2058cc, 0xfd016ba31acd, 0xfe0be8805c4e, 0xffc3192ba1ce,
+    0x032a28a1742e, 0x05b59fc1a3ce, 0x0978de5d8b4d, 0x0bb0d792810c,
+    0x0c0f8addd5a2, 0x0d70445a9640, 0x0fda1b5a75c1, 0x11890fe8eb8d,
+    0x150f3b59ce95, 0x175bcad6cb96, 0x1784f766da1a, 0x196d90befdd2,
+    0x1c6365a8a847, 0x2037cc2b407d, 0x2468d6f1f95c, 0x24c3e14cf1a2,
+    0x2724cc82f020, 0x2b6e16820c91, 0x2b873ff68737, 0x2c62289f18a8,
+    0x3a475eae92a3, 0x3bf618ae02aa, 0x405b8564e4c2, 0x44f603b90162,
+    0x480cc915eaa7, 0x4893842630e8, 0x48a8ca1b4979, 0x48e4f30a36dc,
+    0x4a93e798aca8, 0x4ac257023fdf, 0x5157bb604dc7, 0x53ec936869f4,
+    0x54b7ee137311, 0x59cb33637601, 0x5b0fca2870f8, 0x5ed517cb8c64,
+    0x6525dc8ed770, 0x6b323c466565, 0x6c5e93d2fcca, 0x6fa38e0a7381,
+    0x75a14a009036, 0x7755f686963f, 0x7dba6755d179, 0x80db02b8b2d5,
+    0x83ab1973a551, 0x8d5de56243cf, 0x9008787d8417, 0x906173d14870,
+    0x91027d212630, 0x9348a4f981a5, 0x97f410a54937, 0x9c0eb0abccac,
+    0x9c489004805d, 0x9e9e806969eb, 0x9feb534b3496, 0xa14b97a9e9aa,
+    0xa3e74c74b2ed, 0xa41ac428c512, 0xa6adc7b8b317, 0xa6fced067f59,
+    0xa98da70033ac, 0xab3d10acb502, 0xaeedc807c92c, 0xb03bfa43b675,
+    0xb045d049ae8c, 0xb3996e429683, 0xb42c837e1452, 0xb9b97a0614a6,
+    0xbb131c312269, 0xbb936f9cc71e, 0xbbd9a920b25d, 0xbbe63ddaefb0,
+    0xbf66ebe34840, 0xbfe94e5620e2, 0xbffb6078e8ad, 0xc135e6a8e5c8,
+    0xc38b9c7ec991, 0xcb3c3082be27, 0xcf3cbcd8afe2, 0xd0c99c28ca05,
+    0xd2b73dcb6cab, 0xd5ce3db75bb5, 0xd9250f829472, 0xda8d556f1375,
+    0xdcf4332ba7f5, 0xddd3af00acca, 0xe0c6158900b4, 0xe7a263180b2a,
+    0xeb104f508aa0, 0xede150479430, 0xef3624b728ca, 0xefe8cb0bc2cb,
+    0xf1695030a560, 0xf954e8586a80, 0xfd27b4f1da53, 0xfdfe7f8c03ea,
+    0xfea7ff87b723, 0x03177cd39717, 0x046ab75a034e, 0x08ecbbe6b697,
+    0x113448a58b11, 0x1331b2d4bbd0, 0x15663da85b04, 0x16c43870d666,
+    0x16d56057871d, 0x1b9945cab806, 0x1ca3c2a7f987, 0x205b1c36b502,
+    0x208c0fc587b0, 0x21ec8eb34289, 0x279e93bce987, 0x28d23d2a398c,
+    0x2adc3c13a79e, 0x2c45a6cb437a, 0x2cebf2f4a5ed, 0x30d59f6c56b4,
+    0x33623b57a32d, 0x34397b0fd84e, 0x354382cf0e45, 0x35d279fc243a,
+    0x4210bd0af258, 0x43166c2cbab0, 0x48f0ee195ee3, 0x4bd735058104,
+    0x4d252cb26888, 0x4da754963b65, 0x503d5169746b, 0x5081f1043747,
+    0x516515c9986c, 0x51ce8956fc2d, 0x52963b11a8fa, 0x545741c2e691,
+    0x586d4e9cf6a2, 0x5d1bb38c0935, 0x5d88205cb7f7, 0x6249471ba3a4,
+    0x6834f0eef88e, 0x690c30a72daf, 0x6c22f60416f4, 0x6cafa39af337,
+    0x7146ee1cbf11, 0x77ebe078554d, 0x79e31d91ea45, 0x7c9f4db1e6ce,
+    0x8041614b89b8, 0x820d9d5ce204, 0x828f8ab1af1c, 0x8481bf80c526,
+    0x8492e76775dd, 0x85c5317aa344, 0x8d7b08581c8d, 0x9abb8c275c78,
+    0x9c632ed519a4, 0xa13f18f1a85a, 0xb0bf9783a7f3, 0xb1dfcf740d8f,
+    0xb47eb8112798, 0xb77f12addb73, 0xb786d9ac9f9d, 0xba667e651a6d,
+    0xbd073b7a629e, 0xc0d70ed08770, 0xc4418ca7b05b, 0xc6709a12c517,
+    0xc7249fc181b6, 0xc7b864aa10d4, 0xca4fc0d76c78, 0xcd11a8eef93e,
+    0xcedab12e00c4, 0xd1c96ec5f85e, 0xd38064e23819, 0xd3a956e340d8,
+    0xd42e02ec532c, 0xdbbd6bee032d, 0xdf87122e8c38, 0xe1c06abda495,
+    0xe205f4947e85, 0xe2917d603def, 0xef0c23ecff35, 0xf1d34ede10ae,
+    0xf6876bc4b37e, 0xfdf275f1ce24, 0x061cf5bcc5e3, 0x065993c9bed0,
+    0x09080a64612d, 0x0ad56b40d652, 0x0c1150caf60b, 0x0e15d24bd9a5,
+    0x13c936afa341, 0x1935ec717614, 0x1b7c4ed8d74e, 0x23a534baa6aa,
+    0x23a8a31bfd35, 0x28d7d094c007, 0x2de4392215e1, 0x2ee3bb2e4272,
+    0x2f91ce56690f, 0x300970873286, 0x304c3ba9c73a, 0x38876e3d6426,
+    0x3a315a815b04, 0x3f119d3b5759, 0x43b283341585, 0x445894ce7233,
+    0x4c0c9733bd54, 0x542fc51dfc73, 0x56f9aec35328, 0x57143791f06c,
+    0x59b73e3d724f, 0x5d1cb3ca1c4c, 0x60bf01f2c4fb, 0x62ef3428f690,
+    0x662082547d19, 0x663727a3b848, 0x6997cf74e91c, 0x6a66d3104e89,
+    0x6ab632ed2090, 0x6e91763265dc, 0x70a49b74bab6, 0x73ca3f221b00,
+    0x73eba4b3655a, 0x747f2f0ceeb3, 0x75d7e6fbe562, 0x777af67d2f2a,
+    0x7829b9526716, 0x7a8352a7acf4, 0x7acb9b32cc20, 0x7f9d3a2b5735,
+    0x7fe0054debe9, 0x7fee33f1519f, 0x81d18a6ff0a4, 0x822f8e0e33eb,
+    0x84a8b87c95fb, 0x85919539875d, 0x8879760ed1e1, 0x8bdeeb9b7bde,
+    0x8ca3dea1e36f, 0x8d21735942e8, 0x8e6d968dfc44, 0x98cf5fe0d873,
+    0x9c55c5e0c140, 0x9e391c5f6045, 0x9ec58f6736c3, 0xa3616d157601,
+    0xa4519bb32003, 0xa6b50b0e5df8, 0xa7552a2224a4, 0xaeb2b558eae3,
+    0xb8975f127323, 0xb8e3508dee9f, 0xb983aa30bb10, 0xbd2e6f053938,
+    0xbdf411b8b218, 0xbf9a8f9b526b, 0xc441681aa699, 0xc449a437764d,
+    0xc7ba4f243aff, 0xca9526213ca6, 0xcc1a79019864, 0xcc6c9792afa7,
+    0xcce34f87620a, 0xcd75efa4d44f, 0xce3390ca8340, 0xd33d00148e19,
+    0xd6aa0210f67b, 0xdfed67eb9b36, 0xe5ef41f01fc5, 0xea7d661904d7,
+    0xebd115bd7c98, 0xf554880670cb, 0xf5e79d41ee9a, 0xf763c9c96390,
+    0xfbbb426be5b7, 0xfd0a9972efd9, 0xfdf54aa80f63, 0x01239f904aeb,
+    0x0c24289ccb28, 0x12033db5e2bf, 0x12fe6724a1b1, 0x1d62ef2bc31c,
+    0x1d72080b3fe6, 0x1ea1cdf92dd6, 0x25c2bb22fb28, 0x2937498f21ef,
+    0x29b553648cf2, 0x2b14e81630b7, 0x32a06d987ea3, 0x32b1957f2f5a,
+    0x35e910c051aa, 0x36227afaf9d1, 0x3adf0e8d721a, 0x3b15f4a2daca,
+    0x4159af8ddcf9, 0x42bec1a80b36, 0x433fc4c0c13a, 0x436339593f81,
+    0x43c17786888d, 0x447baa4ae0f3, 0x45e3b5a85a31, 0x4703b309ba08,
+    0x4f955ccbdbd6, 0x5cfa79feb6e1, 0x5d2072bc749f, 0x636bf4a63af8,
+    0x63b5276d7138, 0x66f469ad57b2, 0x71c90775842f, 0x73c8f5c9f465,
+    0x73e259cd74d0, 0x7506001f30f7, 0x75e0e8c7c268, 0x76b703b4dab0,
+    0x79d3f6275fbc, 0x7ad72123e89d, 0x7b229d81588f, 0x7f040ddc39a2,
+    0x7f7582f76752, 0x81e7d0a31c4c, 0x8459e3bfcb81, 0x867c96ffa8af,
+    0x87820b926b42, 0x8d11863fab0d, 0x906599569e8e, 0x9200322c12dd,
+    0x9278be98f368, 0x9c0591c9d428, 0x9df283bf657f, 0xa043e0f7dba9,
+    0xa0c85271e838, 0xa19128f7b1de, 0xa33313addecd, 0xa4fa4774b82b,
+    0xa674da1304be, 0xa8fe7cbb0636, 0xa99e9bcecce2, 0xac5080cac5ca,
+    0xadb55855ee42, 0xb54411aa8cf4, 0xb891bd1cdee9, 0xba59db1fcf5b,
+    0xbb0828d6fbbd, 0xbbae75005e30, 0xbc6489b64ebc, 0xbdbdf15256ba,
+    0xbdcdb9dee4d3, 0xbf4518aae0a0, 0xc00996933ca7, 0xc01ea1f94f73,
+    0xc5890e24e894, 0xc75f955a4481, 0xcad66d5ca4fa, 0xcc5d5a26291b,
+    0xcdb687332b54, 0xcde94f3a2c2a, 0xd28358703d40, 0xd4eb9586f45e,
+    0xd721f4d2c1ba, 0xe8ab4d83128c, 0xed7092506613, 0xf23352f87a23,
+    0xf7ba56f9e475, 0xfb1ee24a775e, 0x07873c256b14, 0x09729431d408,
+    0x0b1e54edf90e, 0x0c03139c8296, 0x1935a3576290, 0x1af07cf30460,
+    0x1d6b06bb890e, 0x1dabfd65ef9a, 0x1f7edbaaef37, 0x27d66b85578f,
+    0x293291d5a4c9, 0x32014ec2cb0e, 0x3542657adfb0, 0x358a7376f917,
+    0x3955ee2fb04a, 0x39dad4c7c863, 0x3e4c268bd67f, 0x40c3b711102c,
+    0x4381468b2f53, 0x4846c5e7889f, 0x4c80bc782281, 0x4c84da868a5b,
+    0x4d344d08d396, 0x5326990fcfd1, 0x56898a773a57, 0x569534f56096,
+    0x56c0708ca307, 0x5d14de404a63, 0x5d6e8941200b, 0x5e685355bc5f,
+    0x5e843b7e7c41, 0x65581261b13e, 0x6a9b26756a03, 0x6ab4c507f033,
+    0x6caa2da95703, 0x6f294a9e4f15, 0x7118fb4825a8, 0x7588b3230b61,
+    0x758d464f7ec5, 0x7886feb88b4f, 0x7b58e9ebabf3, 0x7d45a1523785,
+    0x7e36ba2bf89b, 0x81fc7ced1f91, 0x88b8fed40810, 0x89774fa6c850,
+    0x899c98b774bf, 0x8a5a746c2975, 0x8c9566e46a35, 0x8d934f076e63,
+    0x8de1c4a82956, 0x8e248fcabe0a, 0x8e42c189b79e, 0x8f19c6b2e6fa,
+    0x934b8126b128, 0x96c21e9a0bdc, 0x991d8c677fe2, 0x9a9c3d14344f,
+    0x9c3bde34278c, 0x9fba7d354c2f, 0xa265856e9801, 0xa43b9785e864,
+    0xa7b777d2c7cb, 0xa8b1f194756e, 0xa8b350ee980c, 0xab51fefcac50,
+    0xacb69bf8cf03, 0xaf50f16975a8, 0xb0b344cf5ea9, 0xb35b53c55f7a,
+    0xb5a892ef6dca, 0xb8f29571636f, 0xbe5ab806c2de, 0xc43bdc270e62,
+    0xc9ce5017992e, 0xced9594acc6a, 0xd04b001f37fa, 0xd638094caf82,
+    0xe0b495fd2eba, 0xe191531dee53, 0xeb1cc6f4ac75, 0xeb3c1d7ec2e2,
+    0xeda50a428b4f, 0xf3715d8bc9cc, 0xf3f0519d4be3, 0xf65b4d68483d,
+    0xf78c72b058cb, 0xfc1b811554f1, 0xfc7fb1c933ff, 0x0096e36e60e9,
+    0x02d7534f2c21, 0x0439a6b51522, 0x09e33512e6a7, 0x11442eab0371,
+    0x13fcf069a96f, 0x15f8c0afb1cb, 0x18dcf8949fff, 0x1ce5115a501f,
+    0x211aaf4d7c62, 0x2314e5aa5c5b, 0x27478a5a3d9d, 0x2ab208316688,
+    0x2c755878ddd1, 0x2cb9f813a0ad, 0x2e7372551fdf, 0x31188807d5af,
+    0x3147e1ad7ffa, 0x31a5aabcbd7c, 0x3586a5f99305, 0x481a066919ce,
+    0x4917796e1272, 0x491b5ced7487, 0x494c507c4735, 0x497eddf44246,
+    0x4d14d1f1b367, 0x4e74dbc162b6, 0x57af1b4320a9, 0x5d3276542eab,
+    0x617299fa6454, 0x6567b6613595, 0x692159862ac2, 0x72e80c0fbf33,
+    0x76d4773bb536, 0x7cd31d6de8ff, 0x7e1cf70c68a9, 0x7e2e59821f25,
+    0x8141eb0cb7a4, 0x86f5c48e8cca, 0x888b1a8a7c66, 0x88c2009fe516,
+    0x89fcc15ee7f6, 0x8db7894ef9fc, 0x90bc01fa15b1, 0x92efa2919dd1,
+    0x99532924c1f7, 0x9b2d93d97ff9, 0xa6674c91a298, 0xa87395114a5c,
+    0xacebc398058e, 0xaeb78a8b5250, 0xb1e4baa870ff, 0xb5b244685c1f,
+    0xb7dc0ef9ec28, 0xbee6cbf289d5, 0xc2bc91cf44a9, 0xc57b0b857ae4,
+    0xcac23da79b83, 0xd04941a905d5, 0xd159015fcc09, 0xd9a6bb343c4a,
+    0xdb29ff0d641b, 0xdd464aa89fbd, 0xe07eeab4dee6, 0xeba77b8650ce,
+    0xebfb6e8f9639, 0xedcade733f4b, 0xee5be4a7892d, 0xfa0d3f907543,
+    0xfce9b0769f4d, 0xffc70b98e06b, 0x01c76f0b5c2b, 0x04ce6bdbb757,
+    0x05b9579fdca6, 0x05da0d8415b1, 0x0753b5e64b30, 0x08f26cca2759,
+    0x0f1d735eba6c, 0x0ffbca68a268, 0x104aefb66eaa, 0x113fb1808c10,
+    0x11a57c1d9381, 0x152d06e89927, 0x160b5df28123, 0x17a581a9e9e8,
+    0x1cc1b2c3cddb, 0x1fc1d2d17bf1, 0x232c50a8a4dc, 0x258f8574dd0c,
+    0x28aec17d9bca, 0x28ca34885022, 0x2df6dddbd37d, 0x316997cfcc1c,
+    0x31db4779ff91, 0x32556dd0087f, 0x41d4c796eb3f, 0x424134679a01,
+    0x4996f89f9c16, 0x513b3278591e, 0x55cdaf3eabcf, 0x58a76170909d,
+    0x59da20a1c98e, 0x59fa6167f70f, 0x5ff29ff5894c, 0x6023ce1361bf,
+    0x67cbeb6b80dc, 0x6979f5bddf94, 0x70baae8fcedd, 0x7324faadb9e8,
+    0x770f56d27bfe, 0x7a7db82906fe, 0x7a93733c2b19, 0x7a96e19d81a4,
+    0x7b874aca316b, 0x7bac594bd815, 0x7bd8f43d3d24, 0x8a56f77fc52b,
+    0x8af3331429c2, 0x8b9e87880b23, 0x9433da3a8941, 0x98c56cc4c4de,
+    0x9a4a85161ad7, 0x9f3797195a44, 0x9f400dc52fbd, 0x9f5ca59b00ee,
+    0x9fc98789bb3a, 0xa7e613405308, 0xa9b2fefebca3, 0xaf05a10ffdbc,
+    0xb157adf58535, 0xb19e5c977bfe, 0xb513d53fb9d9, 0xb57790d58d5d,
+    0xb6d884e153c0, 0xb79fc17df503, 0xb8998b929157, 0xb919a46f3047,
+    0xbd164d45bfed, 0xbfcb6614099b, 0xbfe280815054, 0xc1a8ca0c129e,
+    0xc46743c248d9, 0xc9ed5d879c17, 0xcade3bd25768, 0xce278ea73bbe,
+    0xce5a1c1f36cf, 0xce6bb923f310, 0xce760447f6b1, 0xcf3713cefc2d,
+    0xd1469020f4b7, 0xd23e109f5759, 0xd5a1ec42d8f3, 0xd8691733ea6c,
+    0xdd244b6c4017, 0xee48fe4aa651, 0xf2bc2486e295, 0xf2d49e4e4bec,
+    0xfc61717f2cac, 0xfd132d97af99, 0x079069f54020, 0x0b2991c50207,
+    0x0dc6e078f3ad, 0x0e5ec36feaa5, 0x13f6b4c8ffe9, 0x15e15d28578e,
+    0x1f24fd92020e, 0x207ccb44e1a9, 0x22d2811ac572, 0x23c115cf4711,
+    0x24e989dc7c61, 0x25039d8d0e1b, 0x26e85365cfbe, 0x281a285af19b,
+    0x29e15c21caf9, 0x2a61af8d6fae, 0x2f87b6ad4bb8, 0x32be0d23512f,
+    0x388bfa55b80f, 0x3a04435dcaf0, 0x3b87fc54fe4b, 0x42feb1003f30,
+    0x4371c0049543, 0x43e6de101f43, 0x4479b8bc974d, 0x459bffb430d6,
+    0x4ab57219cf8d, 0x4ace26703ea9, 0x4b084057f81f, 0x4b1e708927c4,
+    0x4e0d68b02523, 0x52e952ccb3d9, 0x57a86a846b99, 0x586ef773fb8d,
+    0x5bd67c07d977, 0x5df8450b9f91, 0x5f7979dd9375, 0x621f04ae54cf,
+    0x638ab8fc2a5d, 0x66f3d77930aa, 0x69b71eeae00e, 0x7304203c771b,
+    0x75c015cd6ddf, 0x77718e812322, 0x7d164f237b7e, 0x7e80a4172e6e,
+    0x80a9befbad28, 0x820d372cb302, 0x82f31aa65963, 0x8461c83779f2,
+    0x84aa4b519ee3, 0x85243718a20c, 0x85b711c51a16, 0x86bc8657dca9,
+    0x895a0f9ad414, 0x8990bb2136ff, 0x8ca536e7e692, 0x9058acf73ff8,
+    0x90eb1285ac78, 0x97e93ac40cd2, 0x9982e95d6a0d, 0xa52232238e58,
+    0xa53d6a9f3ceb, 0xa5f04b82dcb1, 0xa7f457e5b4c1, 0xaa7165d378e6,
+    0xaaeadc7c7085, 0xae30fb7f0415, 0xbaaa7d40a882, 0xc2acbab7a8d1,
+    0xc2d7bbbfe57d, 0xc4a04ee0e179, 0xc673dcd2f265, 0xcafc0e754175,
+    0xcb1c4f3b6ef6, 0xcbd1eed353f8, 0xcdc12a5f1f01, 0xce046a9fbf3f,
+    0xce334f275e00, 0xd35154b9701b, 0xd6479ec1261a, 0xdb247319cbe4,
+    0xdcb3d68f257e, 0xdf900ce649c3, 0xe2ec5c1a0cf8, 0xe96687fc6c4d,
+    0xee0683b91365, 0xf92ffec69c61, 0x11e9d29e42d9, 0x1307866968fe,
+    0x19584b2cb40a, 0x1e4ce99fb1dc, 0x21fcb6beaef2, 0x2441f45af353,
+    0x26e18ca51eab, 0x296c19893737, 0x2b690e9a5c6c, 0x2ddb5c461166,
+    0x2ec2d9a8e02a, 0x3227da177e9d, 0x3a2c9bb3be63, 0x3eb5427418fd,
+    0x43b3b6ed0ee6, 0x4b2dd9f9a656, 0x4d2f9cc644b4, 0x4ee23a4516d0,
+    0x5523ab99df4d, 0x56821b806639, 0x578ee1f3e16c, 0x5897153ae93b,
+    0x5ab485a141b6, 0x6a83b4630207, 0x6d63ce398861, 0x6eeaf5921247,
+    0x74fb73580816, 0x751c9e5a4cab, 0x7835382f6418, 0x7bba04462482,
+    0x7bc748ad7324, 0x7cd867be5bf6, 0x7ed85612cc2c, 0x7f131fa796f1,
+    0x7f924e481ecd, 0x822a5a228bc0, 0x8250185143b9, 0x8ae480c7aac3,
+    0x8de515f36463, 0x906557b3794e, 0x923f87d9318b, 0x94f933d3ee9d,
+    0x9653108e0225, 0x9939cc982fd0, 0x9a48dca1e4b5, 0x9b243a6881b0,
+    0x9ba48dd42665, 0x9d13b083527e, 0xa8a121b5b4c3, 0xad3697bf5275,
+    0xad55ee4968e2, 0xb88c38a034f6, 0xbcd45dd4348e, 0xbe5816cb67e9,
+    0xc6716eafaef1, 0xcd679560455c, 0xd04cb7814aa4, 0xd0ba83ac1c04,
+    0xd169468153f0, 0xd6670b4d388a, 0xe1d59b138fec, 0xe4944f58cbec,
+    0xe9a71f8ac352, 0xf0aee34015fe, 0xf2602164c57c, 0xf7efd6a10b0c,
+    0xfa0afd7129d5, 0xfbef78bae5b3, 0xfd1b5b29718e, 0x020c161d0d4b,
+    0x032536bbc00c, 0x050d9584ddff, 0x0943a89615cc, 0x0d8824d9b914,
+    0x0f79e48ac394, 0x1d3ccaccdc21, 0x22045930695a, 0x265cbc0f0295,
+    0x295eb094ded3, 0x2c11ba5bf494, 0x2ffbdbf1b0e5, 0x350c9d1c745e,
+    0x3528bfd43a05, 0x435f9f56bfb9, 0x4b1a7e7eb7f0, 0x4d21f942e68b,
+    0x4ebea11f8ec7, 0x53c517264e9f, 0x5af30e2864ce, 0x5da94bc1cb55,
+    0x611efef90ef5, 0x645012958fb9, 0x6620e1d35b69, 0x6b9c3b569f7c,
+    0x6beb60a46bbe, 0x6cf9c1010f54, 0x712f5ef43b97, 0x735badab0b17,
+    0x77bb27db572d, 0x7c68dd1d5871, 0x84201354f458, 0x84efc69d6b14,
+    0x87c52031e243, 0x8a4adf5a81a6, 0x8b4050d1b05b, 0x8c68c4dee5ab,
+    0x8fac5fbc39c4, 0x90ba8589d795, 0x96895cf85589, 0x991c9b174953,
+    0x9c78ea4b0c88, 0x9fb1ff75573b, 0xa2bad0bde08f, 0xa5fc5c9400bb,
+    0xa7b85afabf64, 0xab3893e50c6a, 0xae604699a0a1, 0xb292010d6acf,
+    0xb3a7039db5b6, 0xb6b1344061a8, 0xb864816c4513, 0xbb3423092c05,
+    0xbe87c10213fc, 0xc0d9935895b0, 0xc38301a8b91f, 0xc3dd96e5a5db,
+    0xc5744c3bb815, 0xc8908f012bd2, 0xc903d89487aa, 0xc94509cdf3fb,
+    0xceca39573025, 0xd01ff802dbd3, 0xd35ddae89faf, 0xd3f79257c4cf,
+    0xd590cbd31680, 0xd65215e921c1, 0xd7eebdc5c9fd, 0xd7f9f325e4b2,
+    0xdeed95b13ba6, 0xdef6817b1ca9, 0xe30417a95741, 0xe47b7675530e,
+    0xe53658e6bcc3, 0xec302887af7e, 0xf42f322c5f07, 0xfb726f238dc7,
+    0xfbcc8f426ef9, 0x04755371d780, 0x093d56f37043, 0x0c8bb212d387,
+    0x10a6c7376286, 0x12712ed08caa, 0x12a381b981f6, 0x12e2a3ebba5a,
+    0x136a0ea911ea, 0x24425aedf11e, 0x25171680e6c8, 0x2e04ef691db5,
+    0x30d57b421bbb, 0x320af92799e8, 0x38b94c6b1cb1, 0x44f3716b82f5,
+    0x49a001e26760, 0x49c54af313cf, 0x4e71db69f83a, 0x4f1c0b12bcc2,
+    0x53d7b4691df7, 0x5497d9b40c5f, 0x54cc01152fd3, 0x54eb1d10407b,
+    0x58063b0a975f, 0x58f669a84161, 0x5bf1bbfa764e, 0x639a4e70a0f5,
+    0x6cf92755fa08, 0x7176d3453fb2, 0x71b8799cb78d, 0x7b976b5eaf90,
+    0x7bc391320915, 0x7c64d510ec9a, 0x7c842b9b0307, 0x7e9b345cb9f6,
+    0x818c00fbe57d, 0x84591e738cf8, 0x84e298381875, 0x88fb293767fd,
+    0x91efa453463b, 0x952f5bb1383f, 0x9a43c5cc5808, 0x9e541aaed7dc,
+    0xa2e747223bdc, 0xa43eda4615b2, 0xa479694bdab2, 0xa4aa224ba79b,
+    0xa53b9d9dfd07, 0xaa2a0efb5f12, 0xaf200cc87f82, 0xafa31ee86973,
+    0xb03eaacfbcbb, 0xb6f70ea83d60, 0xb76d8c0de9fe, 0xb7772784dc50,
+    0xbc3345f9490f, 0xbda9456b223e, 0xbdd77a45afb0, 0xc282ab62717d,
+    0xc41333a2e7f0, 0xc5a6b526a964, 0xc7f60357eba1, 0xc9301469dd32,
+    0xca35c38ba58a, 0xce77811b0396, 0xcf66c57c9684, 0xd1834ba6d7eb,
+    0xd534b2aefd64, 0xd5c28510f680, 0xd7d56fc44595, 0xd8a3fe419f78,
+    0xde713bc6f509, 0xe7e769a89a9a, 0xe83c817cfcde, 0xed248b35bd5d,
+    0xee58e4501eb1, 0xef8af3d44653, 0xf54bd72e6456, 0xf69cc81e96db,
+    0xf9263037928e, 0xfbb047fd9f90, 0xfc90e89dc13e, 0x01a79c4f1ab9,
+    0x0413f77439b1, 0x04c7c293f08b, 0x05d23f71320c, 0x0745babdcbc4,
+    0x0a9958b6b3bb, 0x0e16985db5c0, 0x138c39e96996, 0x1415ee3cfad8,
+    0x16a80790d1c9, 0x1934a37c1e42, 0x227becd62512, 0x260ac981e358,
+    0x3232dc5f81d1, 0x325ec7a3d591, 0x422928aa1cb9, 0x4274a5078cab,
+    0x49295fefb100, 0x4a148642dc14, 0x4bdb7f7aafad, 0x5172fbb5b967,
+    0x53a3a309f686, 0x55a10d392745, 0x55c40cb39a02, 0x5bd86df8f1e6,
+    0x5db55cd2ef5f, 0x5f53d927c5c3, 0x65cca5b0027a, 0x680cdb01c7ed,
+    0x6f2810340502, 0x6fd4c4020901, 0x70054272d025, 0x763a1f0d5b4f,
+    0x76a26dcfa237, 0x80e555b67383, 0x86f76d6591b5, 0x88661af6b244,
+    0x8b60487dca58, 0x8b94aa6df391, 0x8d1b22196c28, 0x8d7ccea80bbf,
+    0x90c44d04c1ed, 0x9339ce82c7ad, 0xa933c94a154c, 0xab6c722c1c5a,
+    0xb04fae2963b0, 0xb3e89b6a1fd2, 0xb60596b26cc3, 0xb7e8b2a20603,
+    0xb9221406e645, 0xbab1025e3455, 0xbb2d37bb7130, 0xbe57a9244aa3,
+    0xc317e5a71f3c, 0xc6ef456d0273, 0xc858b0249e4f, 0xcbe03aefa3f5,
+    0xcd31db8ce7c9, 0xcee85c8b1bfa, 0xd6098443ef11, 0xe3306980a8cc,
+    0xea8d0a7b57f7, 0xeee5e277fcbc, 0xf3158de492fd, 0xf415fa2cd6a2,
+    0xf9b74c6dd873, 0xfa01a4002b8c, 0xffa15c5804fa, 0x06c751cc513a,
+    0x080287a95fa4, 0x08128ac4f382, 0x10450c1db530, 0x14142fc6c8b3,
+    0x226d5f16afd5, 0x2276856f969d, 0x267a0b08d359, 0x2d5ff40ed021,
+    0x306bf929aa3b, 0x3591509c74f6, 0x35aa3f81e9d7, 0x36d830f7a99f,
+    0x47cc656548b5, 0x48bd43b00406, 0x4977eb9267f6, 0x4a4171c542eb,
+    0x4a71b5a7044a, 0x4be1fd214d3c, 0x5585b0306ef0, 0x5681142e33a7,
+    0x5896bd95c7f8, 0x5ed570364b39, 0x5fd7b0f6bd06, 0x61db82ca8f51,
+    0x693b1d08897d, 0x6c30424522a3, 0x72bae6612160, 0x77c2bbc203d6,
+    0x7976b89af890, 0x7ab94058bf9a, 0x7b2a7ae4e785, 0x7bd4aa8dac0d,
+    0x8c3295ed7c8e, 0x8f67c798652c, 0x91906d5ed85c, 0x93342c8d3373,
+    0x9335c6765bd6, 0x93ebdb2c4c62, 0x9440b871a8e1, 0x97502bedd986,
+    0x9766d13d14b5, 0x9b49a0f21866, 0xa3a9325a4aad, 0xa4b33a1980a4,
+    0xa5d421b6f78f, 0xa5f33db20837, 0xa6710cf86d75, 0xa96d4986b976,
+    0xa9d8cc1b5124, 0xac19016d1697, 0xb102a50eff79, 0xb24dde07a1c1,
+    0xb2e28d2c47f3, 0xb9bf4fd95df3, 0xbabe22387935, 0xc40446c7632c,
+    0xc83292d9d6cf, 0xc9eae8503928, 0xcd1ce628d100, 0xcfd98b66d913,
+    0xd188f5135a69, 0xd3c2c2c07e50, 0xd42ef502274d, 0xd6808cc9a33c,
+    0xde3fff1e0ed7, 0xe743588a641a, 0xe75c476fd8fb, 0xf75b0a66495c,
+    0xfc413fa6dbb3, 0xfe94e6758b8f, 0xff03d76b79c8, 0x0214e4d0d2d0,
+    0x053df6df89a5, 0x085d32e84863, 0x0c976407e80a, 0x0f193fb12558,
+    0x171d8c2f5994, 0x18d4824b994f, 0x2428c3d25932, 0x27ea6885184e,
+    0x2b2bb9cc32b5, 0x3111aba7f762, 0x37db376728be, 0x3af9fe51dbf2,
+    0x3de8815acdc7, 0x406d56475616, 0x47746a4f9773, 0x48996ffb7638,
+    0x4ab162f9443b, 0x4adf97d3d1ad, 0x4ee90ff3a46b, 0x4fa551bf30be,
+    0x591fd83e43ee, 0x59c5af499ad7, 0x5c79a34cc7ac, 0x5cadcaadeb20,
+    0x5e09b66f3295, 0x5ff50e7b9b89, 0x5ffb76203d15, 0x63d7691293b0,
+    0x63f56042877f, 0x642a71dfc207, 0x73bb68ab6108, 0x7ec04a554ee4,
+    0x7ec16f206bbd, 0x7eeb4b5d8b90, 0x815a9fc5f589, 0x82e61fbbed0e,
+    0x8490bbacf53b, 0x84e29faf06b9, 0x88149d879e91, 0x883d1a6a9bc6,
+    0x9224bd676f07, 0x937ff97ba52d, 0x989b7ae877d1, 0xa01cb546c21c,
+    0xa069cb8d5a71, 0xa125d2c9e0ff, 0xa29f060e0af4, 0xa4053cf3560a,
+    0xa43cd2b5d009, 0xa6f29b312b06, 0xaf8444f34cd4, 0xb485ed3e9383,
+    0xb6f3e24cdade, 0xbdbb5f04d84d, 0xbf99ad38f864, 0xcab18b41c51f,
+    0xce84ccf9407c, 0xd0288c279b93, 0xd0f5f5d9d89d, 0xd48d0ea26697,
+    0xde805c1d6017, 0xe4313c5bea3c, 0xec3d1549dcdd, 0xee0c852d85ef,
+    0xeeeaa1a86826, 0xef5ed577db12, 0xefb5c1c46b7e, 0xfa704bdc0641,
+    0x0da1bc581fc9, 0x2071bad49f7f, 0x2ec7b65235db, 0x3025016d9fee,
+    0x317f8dd4c4c5, 0x355e7a0a6661, 0x3780430e2c7b, 0x3b3f639bac20,
+    0x3c15096ab8de, 0x3f273b9b2ebf, 0x426050c57972, 0x43763d91db6d,
+    0x48c745b9f423, 0x4c7def9b9e4f, 0x5886e67e2c20, 0x5add4c012138,
+    0x5bf92b541935, 0x5c2e02624df8, 0x5ca73e7c3fd2, 0x5cbcbf005e28,
+    0x5ce0a8b6e7f9, 0x64b685cb88fe, 0x6e635f33917a, 0x714cd9f20461,
+    0x73cef02a4774, 0x7502d4269d3e, 0x76d27e994c15, 0x796a8a73b908,
+    0x7ff5a3adc34f, 0x81d048f18716, 0x82d9db92b183, 0x859b13fd2cfa,
+    0x96473a6eb2a9, 0x97622f859392, 0x997554c7e86c, 0x9c582d52b402,
+    0x9d3aa26b03d8, 0x9d9ab5107b0c, 0xa02800a8d8d4, 0xa59357108909,
+    0xac375f300831, 0xad10e87e7704, 0xb3eb61955352, 0xb44790bb6871,
+    0xc087e2d16a7c, 0xc9182d3969ac, 0xcf0d37f4ab23, 0xcf2b2f249ef2,
+    0xd19f8bd787d9, 0xd4200826a289, 0xdfe9dcd6f7f6, 0xe2b7e48ab685,
+    0xe4bdc565bcbd, 0xed6f0040fabd, 0xedbe9aacd289, 0xf5781a7aa822,
+    0x024f9fda8fd6, 0x0bd979c82595, 0x0cab76a6d603, 0x131a329a14de,
+    0x1636755f889b, 0x245cdca86ee7, 0x2c8c2a2edfcf, 0x2e6ff5cb8a5e,
+    0x2f8a00a65433, 0x2fbc8e1e4f44, 0x36434ebaebec, 0x37ee5fc9ffa3,
+    0x3b2cb7cdcf09, 0x4127b50fa682, 0x43b1ccd5b384, 0x47ac66a50f3d,
+    0x49051e9405ec, 0x4a7c42d0fbf4, 0x4d88f798e75d, 0x4f806f41821a,
+    0x4fdf5d1bdc75, 0x513eb73e7a75, 0x52e11712b2ee, 0x5b256fff36a2,
+    0x5f855f4d8e42, 0x5fde95305860, 0x66d321f7c668, 0x6a3981c08779,
+    0x6ae07d96fb3b, 0x732f96c58e1a, 0x75b38175ff55, 0x78543e8b4786,
+    0x7a25f8052a4a, 0x7ab738c879f1, 0x7b649c438f3f, 0x7cd0509164cd,
+    0x7f4ce9611d68, 0x895cceb1e819, 0x9134803eb746, 0x913a38364783,
+    0x93da459e7e65, 0x9622f19c1951, 0x9ae53d2621d7, 0x9c8d1a62e4c8,
+    0x9d3ab86cffdb, 0x9d4b6b35a508, 0x9da0f82812d6, 0x9ee086a28edf,
+    0xa4c3f4591415, 0xad47aa06d5f2, 0xaf45143606b1, 0xb41c6b262203,
+    0xb6779e649044, 0xb9b2fd2514a9, 0xc3584a1d5ec0, 0xc3b64dbba207,
+    0xc457919a858c, 0xc8ea837ee3c7, 0xcfc203527514, 0xd2ed5ef7659b,
+    0xd88515c1751a, 0xdc9e566dd5f1, 0xdd25117e1c32, 0xe5ad5a585173,
+    0xe8f5886218f0, 0xe9aab2dbf268, 0xea4b819cca63, 0xeff3eb2f7f0f,
+    0xf08cf2f192e0, 0xf28d1bd508db, 0xf412e3d37023, 0xf64c01d382bb,
+    0xfc5f3e4dbdc6, 0x042c6a27838d, 0x04531292529a, 0x0652c657bd0b,
+    0x0686b329daba, 0x1853fbc7dd19, 0x18f53fa6c09e, 0x216b3bcf284f,
+    0x255b155c74dd, 0x276eafbcd541, 0x2856dcccb554, 0x2aa874943143,
+    0x2c9984982a74, 0x2d9b503a90b7, 0x33755d092960, 0x34e7ee19ac04,
+    0x350c125f3b9a, 0x3974b2e86e78, 0x3af488603fbe, 0x40a827530f1f,
+    0x4367162750e4, 0x48a76b86c46d, 0x48e5a37ce5bd, 0x4b9d05e1691d,
+    0x4c39f122df03, 0x4d6c3b360c6a, 0x4ee02ba0b1ac, 0x5174c919c814,
+    0x521313b56098, 0x56b29a53fc26, 0x5a576ca1e44c, 0x5d8b7981b011,
+    0x60f0b47f5449, 0x61112fd4878f, 0x615f305736f8, 0x64f265a062dd,
+    0x65a83fc74da4, 0x69575d39396b, 0x6b40e0cd7437, 0x6ce8f8993ced,
+    0x6e1465e9bd3e, 0x6ee7fcb1960f, 0x72cd508bd937, 0x75316f94287b,
+    0x786c93c5a71b, 0x7f256cbc334a, 0x7f35aa66cced, 0x86da1ece8fba,
+    0x87abe11e3a63, 0x887dddfcead1, 0x89edeae82dfe, 0x8d22a7750b12,
+    0x8dac9657a219, 0x8f18101671e2, 0x9129265192cf, 0x936cca04aecd,
+    0x94618bcecc33, 0x947328d38874, 0x99a515009082, 0xafd45bf41e6e,
+    0xb087ec84cf83, 0xb18c76db7b02, 0xb932852c6632, 0xbad385a67c0d,
+    0xbd38c979e82a, 0xc4eaf7670523, 0xc51f1ec82897, 0xc69b4b4f9d8d,
+    0xc6c318858973, 0xc8444d577d57, 0xd20289353c4f, 0xe230e0605cc0,
+    0xe36e5fd3a4dc, 0xe5b70bd13fc8, 0xea3aaa471b74, 0xeb60251105c3,
+    0xfb33ac7033b3, 0xff0e7a976d75, 0x10265e2c9097, 0x10c88c478b30,
+    0x13e802df4fb3, 0x15cb595deeb8, 0x164a4d6f70cf, 0x194ec61a8c84,
+    0x1bee5e64b7dc, 0x1d85fdf6e12a, 0x1f06f839cf49, 0x1f1611194c13,
+    0x1fbf1bf6f3c2, 0x24bce0c2d85c, 0x2543d6622462, 0x27982cdde58d,
+    0x297d57d4b2ba, 0x2be470204cff, 0x2cef621b9a0a, 0x2f28baaab267,
+    0x332aa65ac6c0, 0x391bcd96a622, 0x3d136e22b6da, 0x3e578fc9a647,
+    0x4415b46f7f0e, 0x441788e7ad36, 0x4f57a9447161, 0x526b3acf09e0,
+    0x589f2d2d7df6, 0x5e7c333f61a0, 0x5f9e3fa7f564, 0x5fc1eecf7970,
+    0x6336f259abc1, 0x66fa6b849905, 0x671c0ba4e924, 0x6d64944b647c,
+    0x6f5da5dd279c, 0x72d65257b63d, 0x7478b22beeb6, 0x7618534be1f3,
+    0x767691792aff, 0x7a44cae6276e, 0x7a9f9ab219ef, 0x7cf8f9785a08,
+    0x8290b0426987, 0x855a99e7c03c, 0x859d9f995ab5, 0x85b9c251205c,
+    0x8694aaf9b1cd, 0x86e9132102c2, 0x8e79a0edcf9c, 0x95edd173d10a,
+    0x97a27df9d713, 0x97ed4aaa35b6, 0x9b30e58789cf, 0x9c5c1849045b,
+    0xa6f2438c09c3, 0xa704903dd753, 0xa8896e002787, 0xa97e6a594ab2,
+    0xae65c464f9e2, 0xb2301a52943c, 0xb26098c35b60, 0xb31379a6fb26,
+    0xbd182997ab22, 0xc0e8720bdb7e, 0xc293488be970, 0xc3306e5c651b,
+    0xc9aa9a3ec470, 0xcc3b19a972fe, 0xcc6f7b999c37, 0xd1987bfcc342,
+    0xd2b077d0592a, 0xd2b1277d6a79, 0xd2fb4480b7cd, 0xd5c4095af1a9,
+    0xd68d8f8dcc9e, 0xe2f414f0922c, 0xe4d60c150e93, 0xe57c1daf6b41,
+    0xee3ebb005fbd, 0xf37f857dded0, 0xf7a5957382bf, 0x04112320c73b,
+    0x06b16b1803e2, 0x0aa7ac49f1fc, 0x0d7eda569753, 0x104012c112ca,
+    0x1c9a3df8a0ca, 0x1e3ee76312f5, 0x219a4c5abf16, 0x25fa011a10f1,
+    0x261156165d6f, 0x2b5c6bb7e023, 0x2ba85d335b9f, 0x34fc75d6a587,
+    0x3c8cc9146c9c, 0x3dd83c9c14a9, 0x3e3a5e48bfca, 0x3f442b78effc,
+    0x4ac1d41ec428, 0x4ddcf2191b0c, 0x50bbac957ec8, 0x54516603ea24,
+    0x5cd9e96d252a, 0x5d670c220cf7, 0x5f38c59befbb, 0x60df7e0d95d3,
+    0x651eb777b468, 0x6c2c6db39d16, 0x6f7bed9e1d33, 0x71efd532fa90,
+    0x725f3b46f453, 0x7405440b891c, 0x7bb02017ed75, 0x7c02ee561607,
+    0x7e727d4d85c5, 0x87154ef6584a, 0x8fbc793c986e, 0x93e3736e5371,
+    0xa00ce5a61488, 0xa4efe7145619, 0xa78917b9dfe5, 0xa88406999912,
+    0xab0398ac9cae, 0xab2538cceccd, 0xab6803ef8181, 0xb1e563a4319c,
+    0xb5a0db4154f1, 0xb66225576032, 0xbcd91d676ec1, 0xbf05e13c49cb,
+    0xbfb5c8dc9e90, 0xbfd8533905c3, 0xc42371b0505c, 0xc9e997e3f312,
+    0xcb78fb594cac, 0xd3b9e5e479d5, 0xd6063ad27111, 0xddd06d68ebd7,
+    0xe30727516d0e, 0xe57f2cf4b245, 0xe775ba6135ee, 0xedf5d8ca2b45,
+    0xeee089ff4acf, 0xf35584b3b53b, 0xf9829a4f7c3b, 0xfcf170c412c5,
+    0xfdab2e6a5fa1, 0x07acaa88bed7, 0x0c83c6e9d464, 0x0d6dc871e29f,
+    0x0f9ee4e42b48, 0x11401fed46e8, 0x11be9ee0bd75, 0x1c114f541cda,
+    0x201a8ce4e9d3, 0x206c3657f58c, 0x2fdec0d59534, 0x300cf5b022a6,
+    0x302c4c3a3913, 0x35266815c15d, 0x3740df38ced7, 0x38bcd1313e08,
+    0x399117a62828, 0x3c35088dc11f, 0x3d39585566d9, 0x3def327c51a0,
+    0x3e52ee122524, 0x454b98e7fb06, 0x45f1aa8257b4, 0x554887663d3f,
+    0x55781b9aed4f, 0x5a8867a7a53e, 0x5d3a12149861, 0x5e47c2c42aa8,
+    0x614bc6513ad3, 0x68fb358a1290, 0x6a79aba7c138, 0x6c8927f9b9c2,
+    0x6dfb7e7b36a1, 0x7c5179f8ccfd, 0x7d77a46fc89b, 0x7f5c5a488a3e,
+    0x87f84f2eafad, 0x92ef3cc43d98, 0x95fbf18c2901, 0x97dc895682ca,
+    0x9c268302b08a, 0x9d1049fbb900, 0x9d3349762bbd, 0x9d99c3c0447d,
+    0xa3f71d3dccdc, 0xa70481b2c994, 0xa9c126f0d1a7, 0xaa0c68bf3bd4,
+    0xaa3b4d46da95, 0xade2692afc6d, 0xb8dfbe652be4, 0xbfbde06c6482,
+    0xc1e6c0c1dd77, 0xc3aa110954c0, 0xc8bad2341839, 0xcfe7defa1754,
+    0xd51c89db649e, 0xd645e824b102, 0xd78033c5a858, 0xd89d37e3bd2e,
+    0xd9b533b75316, 0xdc7b746c4d7b, 0xdce681e2d99f, 0xdd9df5f2ecc9,
+    0xe09fea78c907, 0xf305c5bad3ad, 0xf395e1b3067b, 0xf3cf867cb467,
+    0xf78df75d22bd, 0xf790b61167f9, 0x052a35346c3d, 0x0c9c91423f83,
+    0x15337ddde604, 0x1c9bc956bb6e, 0x2107d84144d7, 0x231077d0904b,
+    0x28b9cb9f5c0b, 0x2bbad5e92135, 0x2c89d98486a2, 0x3261d74beb5e,
+    0x34a839b34c98, 0x3b2b515f8cf0, 0x4cdfe5a72033, 0x5009a762e857,
+    0x503642544d66, 0x50b486b8be2e, 0x5161ea33d37c, 0x518d605a1bb2,
+    0x51a9bda0e71e, 0x52dcf1f02b99, 0x542c0e682ff6, 0x55d1a20eb935,
+    0x582bb0820a9d, 0x5eca75c80512, 0x6277f950c876, 0x64f5f17aa3af,
+    0x665f5c323f8b, 0x668972fe6523, 0x68fef47c6ae3, 0x6cab18ab0ba9,
+    0x740117721383, 0x7993164492c5, 0x7a7e3c97bdd9, 0x7fb40c4427fc,
+    0x810488164ef7, 0x818c2d62ac4c, 0x8193b9d26ab1, 0x824c52ad9ab4,
+    0x8763b60c057e, 0x9397e885d5c0, 0x990f9918bd83, 0x9f7bd0e6bce7,
+    0x9f835d567b4c, 0xa62fa192ca28, 0xa74a96a9ab11, 0xabdfd22442fe,
+    0xb78f932406b1, 0xb98bd8881a97, 0xb9cc1f856fd4, 0xbda519347b6e,
+    0xbfebf0b9e832, 0xc160cb60a488, 0xc4b971a40b6d, 0xd1e9b7c8b1b5,
+    0xd4d0ae61e525, 0xdbaf80162f12, 0xdcf15826e4cd, 0xdfeb108ff157,
+    0xe1cccd2567f9, 0xe694d0a700bc, 0xe7a0e76d6aa0, 0xef555ef0c14b,
+    0xf16b7d766126, 0xf31c467d051a, 0xfb11b4aac251, 0x013b5be532c6,
+    0x0549dc4f8472, 0x08eb05ad1048, 0x0b6f2aec8748, 0x11f11dcdaac7,
+    0x12df77f326a1, 0x15c49a142be9, 0x1c8206372b7c, 0x29e0f6546ac0,
+    0x2c3d13cef015, 0x3204d3ebbb2e, 0x33f40f778637, 0x39c09d4fca79,
+    0x449959265ed0, 0x46aa6f617fbd, 0x4d44dc0a0c93, 0x559136845a36,
+    0x5686e28a8eb0, 0x56be784d08af, 0x572910a58949, 0x5af65fd66ea4,
+    0x5b751958eaf6, 0x637756cfeb45, 0x6e47616ba45e, 0x7469b6c55c33,
+    0x7a76510befed, 0x7fd4630c5180, 0x81b7b98af085, 0x85abebb5aab2,
+    0x86b8027c1496, 0x87559d6a9bcb, 0x91bb4a3cda0f, 0x96ef4571160a,
+    0x98cfa2ac6a0e, 0x99d5173f2ca1, 0x9a0ad8897878, 0x9cd8309025b8,
+    0x9d071517c479, 0x9deb5ea84277, 0xa1877fbb4f5f, 0xa638ddedacf3,
+    0xa7c03fd53c9e, 0xa9158962dcc2, 0xad04b3431801, 0xb595383a1cf6,
+    0xb63a25095ccb, 0xbb7203bcfadb, 0xbd822fbc04b4, 0xc1356b3c5855,
+    0xcb51aab85a94, 0xcc5a531d6ded, 0xcd182ed222a3, 0xce29135405b0,
+    0xcfe33d429631, 0xd26eeef1cb96, 0xd3cfe2fd91f9, 0xf2b482dac5bf,
+    0xf307c636f9db, 0xfed543d7ab98, 0x01b5231f2c2d, 0x0302a5ae0827,
+    0x0886eafb2d3d, 0x096a4a4f9427, 0x0bdf913e9422, 0x0d79ef8502ac,
+    0x0df70f1e569b, 0x0ffe4f537f71, 0x14bc7ccf201d, 0x249baeac744c,
+    0x269485af31a7, 0x2863f592dab9, 0x2941d77eb72b, 0x2a002851776b,
+    0x343ef229e0dd, 0x39486173ebb6, 0x3cafab78c3db, 0x3f574550b922,
+    0x43d5a0ed101b, 0x462180bcfbcd, 0x47b0e4325567, 0x496b833ef172,
+    0x4eb205b400c2, 0x5423fe4f5848, 0x55281387f83d, 0x5b68d52faf6b,
+    0x640f8a57e405, 0x66d0883359b7, 0x6d24bb57fb4e, 0x71a6fa73b45c,
+    0x72dc033b26ff, 0x7b8368106ce8, 0x8b5d1c85369f, 0x9bbc2cb023f9,
+    0x9f73c0cde539, 0x9fb616d26e63, 0xab83cf0225e5, 0xb37016d6fc54,
+    0xb57e6e5dd805, 0xb6c8f7a968fe, 0xb84bc6648545, 0xbb9553c86f60,
+    0xc091098d200d, 0xc0c82a318e82, 0xc1ac393306bb, 0xc3878e23dbd1,
+    0xc43cf32cbb0e, 0xc4c373adfb8a, 0xc4f258359a4b, 0xc5f632df347b,
+    0xc73511ac9f35, 0xc83c20288a2b, 0xc88a5b3a3f59, 0xce86f2653f35,
+    0xd56bf12f24e9, 0xd71da471dff1, 0xddafd4fd9d13, 0xdf98a8e4c690,
+    0xe53c7f4b07d8, 0xea8c9d37097a, 0xede9d6a6e3c3, 0xf297c677eacc,
+    0xf63dbd90efcb, 0xf80f3c7bccca, 0xf8e6b6c307b0, 0x0553de59748f,
+    0x09bb1f8884cf, 0x0c5ed5e11801, 0x0f51ec167d3a, 0x11da69f361d9,
+    0x1a648745c542, 0x1a9b6d5b2df2, 0x2348ff460fa2, 0x28e9674afa5f,
+    0x2b22102d016d, 0x2ecac5fa4ba8, 0x3c1ce6ce47d4, 0x4139c7953d16,
+    0x42582b0d748a, 0x52c6c935ea38, 0x5393833b15f3, 0x5b419319cb12,
+    0x5d2524276fdc, 0x5feff808dda5, 0x6926197c33be, 0x6de2727fa642,
+    0x6f55039028e6, 0x72973f135a61, 0x75227ba4843c, 0x77fd52a185e3,
+    0x7eaccab02585, 0x83e4e3f2c95a, 0x8657319e7e54, 0x87dbd4d1c8c3,
+    0x8fc906e2b646, 0x91373f55cb4b, 0x92ce69c9e90f, 0x98577cd2874e,
+    0x9d2cff4a7478, 0xa17c765f2cb0, 0xb059dc9a1a9c, 0xb2eb0bb1da79,
+    0xb628ee979e55, 0xbfd72759c96f, 0xc9f366d5cbae, 0xca2e306a9673,
+    0xcba8fd97e8cb, 0xd162c9a053f3, 0xd6baae8b19bf, 0xd792d87f65f4,
+    0xd8119201e246, 0xd9d1ae7708c9, 0xe1832cb71473, 0xe945d2ddd0d4,
+    0xef093a5d2e4e, 0xf3fa2fdfcfd0, 0xf4591dba2a2b, 0xf9065dde1fe5,
+    0x00a2962912fe, 0x05504b6b1442, 0x05fb9fdef5a3, 0x16ee3a636c56,
+    0x18f5057a89a2, 0x1b1b271bbd5b, 0x1d4700b48151, 0x1de12d41b1fb,
+    0x1e88d8c5370c, 0x23368e073850, 0x2cc7f4648c74, 0x2f82500c5ad5,
+    0x386d6a404c86, 0x408e4e9451f3, 0x499dc79cd8ff, 0x4be49f2245c3,
+    0x54c2af7dee97, 0x5586b8483f14, 0x569b80498436, 0x5d0533f24423,
+    0x5ee1e83d3bd7, 0x636e37edf2c1, 0x6475bb87e941, 0x6b1ff0bd0430,
+    0x7ae842bc176b, 0x7e2bdd996b84, 0x82c571b17110, 0x846a55aae900,
+    0x8dcd4c9ea9ed, 0x90aec5cf52e5, 0x970fc83d3794, 0xa74cfdb8cf0a,
+    0xab01237539bf, 0xae30d7b797e5, 0xae4a3bbb1850, 0xaf823dc5d5f4,
+    0xb2e395441817, 0xb452f28249f5, 0xb4b3ef63d83d, 0xb5a03a82202a,
+    0xb67cf7a2dfc3, 0xb6a7838d10e5, 0xbba12a4a8da5, 0xbc085441b7b4,
+    0xbd0676f3c1a7, 0xc01af2ba713a, 0xc126cef1d559, 0xc3a785cff5ce,
+    0xc52dc2ec68a0, 0xc5abccc1d3a3, 0xd2c3d3ae1659, 0xd67f10bc33e9,
+    0xee069a80acfa, 0xee879d9962fe, 0xeebcaf369d86, 0xf12e87c446f6,
+    0xf369b4cb8d7b, 0xf6cb0c49cf9e, 0xf913f2d6704f, 0xfaba70b910a2,
+    0xfe539888d289, 0x00994b432274, 0x09069630aee7, 0x11e04deeea1c,
+    0x19359d08e0a7, 0x1d219316cb20, 0x1ff518331427, 0x2348067eeacf,
+    0x2be93e3e94f1, 0x2d49f7bb558f, 0x2f6050cffb2f, 0x3b74b7a1a97a,
+    0x3cf7865cc5c1, 0x3d704d58ac11, 0x472aa5b708f4, 0x48d073ec97f8,
+    0x4a8102643627, 0x51b476ced6ce, 0x5a6208b9b87e, 0x5b6b9b5ae2eb,
+    0x5b757160db02, 0x5bc6a5b5db31, 0x5d56f3674bdf, 0x5f2921ff3a2d,
+    0x5f54230776d9, 0x648315f133e6, 0x648e4b514e9b, 0x686326f1f25b,
+    0x6cc7a96cbd5f, 0x6fabe151ab93, 0x700bb9681d02, 0x7287a28ac44e,
+    0x888cd2b22ca2, 0x8e5b6f91a4d1, 0x90623aa8c21d, 0x914649aa3a56,
+    0x91a0dee72712, 0x96bf1f083ef2, 0x96f9e89d09b7, 0xaf369cdb5c40,
+    0xb1811d512554, 0xb21382df91d4, 0xb8df92c402a7, 0xb8e1a1cb3694,
+    0xb9de65231de9, 0xbd026ee755d0, 0xbd4783a02436, 0xc8642f646a1a,
+    0xcd8234f67c35, 0xd33bc66fe198, 0xd5b09840d609, 0xd5b9497bb147,
+    0xe14177d48ed9, 0xea1452d01cf8, 0xed2c02691d51, 0xef910bad83a9,
+    0xf330d5b0ece1, 0xfc180c657c7d, 0x08585e7b7e88, 0x08c8aecb8f5f,
+    0x1a0fb1775707, 0x1e88ca3a294d, 0x288c555fbc70, 0x2b442ce24b5a,
+    0x2f3feb7cc3ec, 0x357fc2e86406, 0x3b96a852fb61, 0x4099ea876a73,
+    0x43ba10cc4045, 0x45d2ee06255c, 0x48274481e687, 0x48d9b0477ac3,
+    0x493b9765201f, 0x4a6e56965910, 0x4af80ae9ea52, 0x4b4a640a075a,
+    0x4f55eb310e05, 0x5251ed305441, 0x5407be817723, 0x5b6006deb8af,
+    0x5b75c1f1dcca, 0x5dc809666a08, 0x5de58b78524d, 0x60416e63d1dd,
+    0x6b197a8d54e5, 0x6bf07fb68441, 0x6ccc180c2701, 0x7216b88f9e2b,
+    0x7d6d091d91fb, 0x7df6f8002902, 0x80d32e574d47, 0x8642a2cd6556,
+    0x873346891ae2, 0x897a1e0e87a6, 0x8fcddc151db3, 0x9d0a41d5f5c4,
+    0x9e536bc7641f, 0xa1e92535cf7b, 0xa1ed43443755, 0xa2f5eba94aae,
+    0xa982d95b831d, 0xa9bd2dd24258, 0xa9cbd193b398, 0xb562de3d082f,
+    0xbe10e545f569, 0xc5cc398bf92a, 0xe49c432125ae, 0xe5266c92c27a,
+    0xe90b10bff453, 0xeb23b36ad3a5, 0xeba05de61c0a, 0xed4123d12c20,
+    0xf2e86898c3f3, 0xf67620796560, 0x0245ad214b0a, 0x02b46388337e,
+    0x03dac88e34e1, 0x0586894a59e7, 0x0a73262f8dca, 0x0f971e4835e7,
+    0x100f702610ad, 0x11898da651b6, 0x14d73918a3ab, 0x1aa2a225cb14,
+    0x1ba25ec0fd6a, 0x1caeeaa572d8, 0x203466694491, 0x20c8a06fdf39,
+    0x22c43626e1d0, 0x2459c6b1d731, 0x25317b8817dc, 0x2e46ac882f25,
+    0x3dc8c5035721, 0x458ba5b91947, 0x46573af32829, 0x4b12e449895e,
+    0x4b353416eacc, 0x4ba3755fc7b6, 0x4c2bca59365a, 0x4eb7417965fa,
+    0x5180f08fb6ea, 0x51d6f2a03042, 0x521565255757, 0x5b2812002f29,
+    0x5c734af8d171, 0x645079ee2b16, 0x663a38116ba7, 0x6e94fbbe24c5,
+    0x77a6493ed9f9, 0x77de199059bd, 0x7d9b196b15ab, 0x7f10a3bee350,
+    0x8010605a15a6, 0x909e8f9ba786, 0x97b5a6bf7cc1, 0x981fc9f9f1d1,
+    0x9ffd337e513b, 0xa250da4d0117, 0xa91fe374bceb, 0xb69c55a3e474,
+    0xb81bf08caff5, 0xc25ed8738141, 0xc3117ec81b42, 0xc576c29b875f,
+    0xccd488615363, 0xce3f17e40c18, 0xdbdb1b2c4fd3, 0xdc23d8d57a89,
+    0xddc931ecfe03, 0xe374cf520375, 0xe551839cfb29, 0xeb9af67f8d95,
+    0xee05429d78a0, 0xee10b28c991a, 0xf032067253aa, 0xf4db6316e74f,
+    0xf5f2e9cc71ad, 0xf7156b5310fb, 0x06782d44228a, 0x155b8605a678,
+    0x16f0dc019614, 0x1f1f79daf5ad, 0x25de455817de, 0x2a366da7ab54,
+    0x390913a08a15, 0x47c1e077dce1, 0x55ebf0b11f7d, 0x5ba3e8415c7d,
+    0x5fdaaaffa599, 0x6317de385826, 0x6344040bb1ab, 0x69fcdd023dda,
+    0x6d9c31e79b88, 0x8146a3eea125, 0x83482c2c39be, 0x837158bc4842,
+    0x8e7674f53be3, 0x8f28a62bca5a, 0x8f70eeb6e986, 0x9009bbe9f792,
+    0x90fb0f52be6d, 0x98a27cfdcc3b, 0x98b78863df07, 0x9ded929f4eef,
+    0x9e56cb9daceb, 0xa009a3ab84cc, 0xa0f41a519e91, 0xa59e6132494a,
+    0xa6146979ea5e, 0xb3fdf826d1f8, 0xb4c200f12275, 0xbc4cd6c65f12,
+    0xbe46d2943946, 0xc231de660cab, 0xc570ab87e79b, 0xc873152bcf63,
+    0xcb7c5b926441, 0xcdc99abc7291, 0xcf2252ab6940, 0xd17eaab4f45a,
+    0xd2c9e3ad96a2, 0xd33c4304db66, 0xd7afa3d01d6f, 0xd7d90aef31b8,
+    0xdd15b75e48f1, 0xdf6331175d06, 0xe845d49f793e, 0xe88cf85f7b91,
+    0xecd51d937b29, 0xedee78c133af, 0xee081753b9df, 0xf053f723a591,
+    0xf1224b11f9af, 0xf2d4ae01c606, 0xfeb7ac269619, 0xfee61b902950,
+    0x019f8cfbe09d, 0x092017ad1999, 0x09d7169f2139, 0x0b84e6627a2c,
+    0x122ea6798991, 0x1a68b44209a4, 0x1c7412859a54, 0x21ec72c59366,
+    0x2a3b51652080, 0x344927aeb744, 0x48b8c74b2a37, 0x498755c8841a,
+    0x4ca56d0625ff, 0x506a801a3ba6, 0x576e25c12678, 0x59d20a3a6ff7,
+    0x5cf26b0e4b8e, 0x5d3118227868, 0x61e15189b923, 0x681125d9c55f,
+    0x74a271b5c1d4, 0x76a01673f858, 0x79b4923aa7eb, 0x79ccd1730b7d,
+    0x7bdbd8a6f87d, 0x81000b4ea65f, 0x85402ef4dc08, 0x8a145212a694,
+    0x9267143195c3, 0x97763b7330d9, 0x989673639675, 0xa66d05b19f30,
+    0xae46512796c0, 0xaee376f8126b, 0xb0dfbc5c2651, 0xb301fa7df7f5,
+    0xb3de4280ac04, 0xb9ddd2eef6e1, 0xbbc631b814d4, 0xc2891b439edf,
+    0xc39cf908cced, 0xc760acc2bff6, 0xca7e1453508c, 0xcdb1e6a4168c,
+    0xd1f6d805c55e, 0xd45af70e14a2, 0xd56782f28a10, 0xe235a76a8537,
+    0xe83b2a5f6616, 0xedb2daf24dd9, 0xf549d063bc3f, 0x027e3496ca61,
+    0x139f03f5ce86, 0x17957fb6c265, 0x1919adcc014a, 0x1a517547b929,
+    0x1b50bcc4dff5, 0x1b77da4dba8c, 0x20a82c919a37, 0x34d3a1b155d8,
+    0x4feffaa82331, 0x53240787eef6, 0x5dd321b0693f, 0x67c8b8c19c71,
+    0x70c98e08b23d, 0x7153f20954ce, 0x717223c84e62, 0x7726acf734d7,
+    0x7c2730775ead, 0x86bb874235ed, 0x8a581d734e5f, 0x8e0650a92312,
+    0x8f6a03692eb1, 0x90c7c3a2a44e, 0x938ca4fd7c15, 0x958282bcee6f,
+    0x9adcebccf3b2, 0x9c76d4f556b2, 0x9dc78b568372, 0xa1e7a8c5915f,
+    0xa2294f1d093a, 0xa6ede43d4b72, 0xaa41bcc5392e, 0xab7148242159,
+    0xae52c154ca51, 0xaf7d09da2dc9, 0xafd6b4db0371, 0xb1360efda171,
+    0xb269f2f9f73b, 0xb2d366875afc, 0xb524fe4ed6eb, 0xb7fe75f1b5f4,
+    0xba7347c2aa65, 0xbbdb18911dde, 0xcd96147d52ad, 0xce433d696236,
+    0xd582d17034a6, 0xd9bffbd31f4e, 0xdb2d0f7b177a, 0xdd4af4ff7b7f,
+    0xdf77f3635c4e, 0xea2d00126c99, 0xeb45e6221995, 0xf07a1be55b55,
+    0xf1b93541cbd4, 0xf2123095902d, 0xf5a10d414e73, 0xfd0e26759d06,
+    0xfff88b702701, 0x022c6696b4e6, 0x07de6ba05be4, 0x0d59c5239ff7,
+    0x11cd9b0ced8a, 0x16221a6c24b0, 0x16f033cb7309, 0x1991db1cd24e,
+    0x1abf92038c51, 0x28b5b56ab13e, 0x294596d3de47, 0x2da16813ce0d,
+    0x358364c4a0db, 0x36d8ae5240ff, 0x39c2d8bdc535, 0x3b31864ee5c4,
+    0x3f31d815d1ba, 0x41a8b8edfa18, 0x4935d8597067, 0x4aa3d63d7fa7,
+    0x4ab747ba6a10, 0x4f0649b116be, 0x52b31d8cc8d3, 0x67d020a45114,
+    0x68f7355763c6, 0x6bdc1ce96349, 0x748ad39f61d2, 0x74dd2cbf7eda,
+    0x767f8c93b753, 0x7b83f393433e, 0x803ca3a65972, 0x83ba92fa6cc6,
+    0x83bb7d3683da, 0x87099dc6e159, 0x8908675034b6, 0x89c9ebf545bc,
+    0x905c919f0e68, 0x938c80707253, 0x9569a9d97591, 0x957f64ec99ac,
+    0x986d38487a32, 0xa7ed41bc6e41, 0xb1d3fa7d2a6e, 0xb423bdcc7835,
+    0xb9f2cfc9fbee, 0xbae289499a66, 0xbc03e6051cdb, 0xbcd7b75bfb71,
+    0xc0da182a1b54, 0xd1380389ebd5, 0xd2f108ad5f7d, 0xd32236cb37f0,
+    0xdf5a4cc46a47, 0xe025e1fe7929, 0xe3b693226597, 0xeb08ae6a0b5c,
+    0xedbcdcfc3df6, 0xf0c69880de5e, 0xf8922a717bc2, 0xfc96d4d5d557,
+    0x0516e1933ae4, 0x06d8d2808f8f, 0x15f9b38b237e, 0x18e74c57fe3f,
+    0x23b0ef4f15cc, 0x28d3880d9b4b, 0x345acc2a61c9, 0x37d6e70646f5,
+    0x3b2f182ba250, 0x4ccc5776e915, 0x4d89be0d9241, 0x52d2ff36e6cd,
+    0x537bcf8588b7, 0x5dec020ad061, 0x72b28df3d3c0, 0x7a8a04f19d28,
+    0x7c0f1d42f321, 0x7f39541cc6cf, 0x8beb1b4df68a, 0x8c11fe47cb5c,
+    0x8f0e75651d22, 0x921f0dac6aa0, 0x93eca917e58a, 0x95694abd660a,
+    0x98d68748d431, 0x9d8aa42f7701, 0x9fb1b00cc1ce, 0xa28ed09ffd27,
+    0xa3e15b79580f, 0xa49944a776c3, 0xa8e8bbbc2efb, 0xac2922c7324e,
+    0xafdc98d68bb4, 0xba2329adb950, 0xc1de7df3bd11, 0xc2e7d605e1b9,
+    0xd57cd05e90e5, 0xd5e902a039e2, 0xddef5e25a20b, 0xe6279775f3f6,
+    0xe8220861d9b4, 0xf159fe4d5df5, 0xf6182bc8fea1, 0xfe8994c4f2ee,
+    0x06ddb63e04bb, 0x09a9745b8998, 0x0cb15b67fbd8, 0x125b9972deac,
+    0x15d89e8adaec, 0x1b5c6eb9f478, 0x1f0524873eb3, 0x20f54a4f20d0,
+    0x24c558344b67, 0x274faa895e2e, 0x2d77f269ac05, 0x327b349e1b17,
+    0x38d2d6241339, 0x3dcae2f86796, 0x459cdc8da686, 0x46cb08926c13,
+    0x4ad94e6db7fa, 0x4e568e14b9ff, 0x4fe0aeb08ee6, 0x568825316499,
+    0x588d90ee5f47, 0x5b83a0670f81, 0x63fe2fbbea96, 0x6fac91618bab,
+    0x7ddea3289836, 0x7e697c474651, 0x8b8ae41b7594, 0x8dcb196d3b07,
+    0x940f497648c0, 0x95d851b55046, 0x9bcbc287695a, 0xac1a1fe9b187,
+    0xb0eb845336d7, 0xb3cf471a1981, 0xb6a96e6a09d9, 0xc6ae5e761601,
+    0xce817cd671ca, 0xd0e0ce2347e5, 0xd12f7e53089d, 0xd3bc8f5c60a0,
+    0xd45437c451d3, 0xdaac4e68557f, 0xdfdca0ac352a, 0xe75f74f3a7d8,
+    0xec941fd4f522, 0xf17bb46faa17, 0xfa390ee719e0, 0xfc94b74393ab,
+    0xfca56a0c38d8, 0xffa6aee503c7, 0x013e4e772d15, 0x032672b14543,
+    0x0aa2a4c510a0, 0x0ebf8e61cdc7, 0x1d86c46b8c0e, 0x271272d14ff5,
+    0x415333727a8e, 0x4431b35fd885, 0x4d637c35c0ff, 0x503701520a06,
+    0x60734c918a68, 0x62c225a4c11b, 0x658064cbf191, 0x688602422a1f,
+    0x691c4b4ff8b4, 0x69ecae4580bf, 0x6a495289a168, 0x7106498e9571,
+    0x780c38cbb9f5, 0x7ef6ef8d2fe6, 0x7f69c4028034, 0x8187e415e9fe,
+    0x83ae7ad52941, 0x8513526051b9, 0x89ccb220793c, 0x8d42da75c866,
+    0x92bee3a61dc8, 0x988927e82858, 0x9ee34d935ff1, 0x9fc5c2abafc7,
+    0xaeca80fe7e0f, 0xaf85d88df34e, 0xb0872f124e07, 0xb6c029bb410b,
+    0xb70ccae3cdd6, 0xb7516a7e90b2, 0xb7575d0526b4, 0xbc8565b2ccad,
+    0xbdbe8c88a72a, 0xce1909872120, 0xcfbc19086ae8, 0xe4a5a46be104,
+    0xe8415060e262, 0xea2c334f3fcc, 0xf18a6e33175a, 0xf1daf2db063a,
+    0xf92ea80bd462, 0x1175e1fd3051, 0x16d4a3aaa333, 0x182310759641,
+    0x18b7850b36ae, 0x1ab43f8d561e, 0x224074bcb559, 0x230d2ec1e114,
+    0x32b2f6648d1c, 0x39459c0e55c8, 0x4118ba6eb191, 0x4931d7c3f2d4,
+    0x49e89c26f4af, 0x5098c3e2a5a0, 0x5605eec283fd, 0x5676eebfa623,
+    0x57c88f5ce9f7, 0x5cf6d29995b5, 0x5f1617781c58, 0x61bfc057458c,
+    0x64445ab4c816, 0x67afc2c80815, 0x69917f5d7eb7, 0x740f6b68208d,
+    0x77a0cc391e4a, 0x797d45f51039, 0x7f07f2e6d6db, 0x87b584d1b88b,
+    0x8ec793ab0ed8, 0x8f4aa5caf8c9, 0x8f986bbea26d, 0x92412a61b48d,
+    0x9d538b01f6d0, 0xa1cd5371da65, 0xa49bd043a47e, 0xa5c7ed41361e,
+    0xa82619c2ef60, 0xa86ffc3736ef, 0xaa22d4450ed0, 0xad66a9b168ae,
+    0xb8da7c5144c3, 0xbc686ec0ebf5, 0xbe6529430b65, 0xc0d4088d69d4,
+    0xc158b4967c28, 0xce83b7e19dbd, 0xcf47fb3af3ff, 0xd620654a9c60,
+    0xd8bb2fd94e8f, 0xe5b92314ff8b, 0xf28279d18189, 0xf34941501742,
+    0xf64ccfbf1be3, 0xf9338bc9498e, 0xfc63400ba7b4, 0x0192e2a27610,
+    0x05db7cf48132, 0x0a31211ed531, 0x1a526e71acc5, 0x1ba7b7ff4ce9,
+    0x2c60e8491b75, 0x2c8581acb695, 0x395edb84cc71, 0x480ad912dc25,
+    0x4855a5c33ac8, 0x55866105ec9a, 0x5c1ebea74583, 0x6272b73ce155,
+    0x6453c42546a8, 0x645bc5b31097, 0x705b211eac16, 0x71a44b101a71,
+    0x799efc175c5b, 0x7a851a200881, 0x7b41967a9a99, 0x7eaf4824144a,
+    0x800924de27d2, 0x822ff62c6cda, 0x88fae145c0d4, 0x950e234c5246,
+    0x9f2e80d6bc5f, 0xa3ecae525d0b, 0xa56865bbc677, 0xaacbf524b282,
+    0xab8ed923e626, 0xaf2716b790f9, 0xaf3ac2c38127, 0xb6a30e3c5691,
+    0xbd8acbba8181, 0xbf418747bb77, 0xc1994c24d32d, 0xc4f81f7dd5d9,
+    0xc819dfabd40e, 0xcff4155de2b2, 0xdc685446026c, 0xddd985fc6272,
+    0xeb2d40b98701, 0xefda0bbf7131, 0xf0b235b3bd66, 0xf5a0e1a02536,
+    0xf67946237730, 0xfc24e3887ca2, 0xfdc44a196a1a, 0xfef8ddc2d133,
+    0x01544b904539, 0x09b0e3b52c7f, 0x1db6d53535ec, 0x2eedd4c569b6,
+    0x362b59c50839, 0x37fc2902d3e9, 0x3e8cfa346e6d, 0x457aaa392f5f,
+    0x4a352ec473bb, 0x4af812c3a75f, 0x4ccba0b5b84b, 0x4fcd5aac8ec4,
+    0x53a86362ce4b, 0x578ea1792887, 0x5c79df0439cc, 0x5e44469d63f0,
+    0x5edb04c93e0f, 0x60a7064b9096, 0x65d50ef9368f, 0x6bfb47d25079,
+    0x6f29622b863c, 0x71ff30de08f5, 0x73ce6632ac42, 0x8388feac6551,
+    0x8478087ef27a, 0x8bee829b2d9a, 0x8f41e6050fcc, 0x9ab3a99db7f4,
+    0xaeb1d41efd37, 0xb2f2a772442f, 0xbc75a49d2cd8, 0xc2046f9d5b54,
+    0xc4fd3dca50ca, 0xc801b6756c7f, 0xc881cf520b6f, 0xe17d498129c2,
+    0xe1ad1844df97, 0xf61c42c34700, 0x049836fe9b1a, 0x0aa0b336c6fa,
+    0x0dc4f78a04a6, 0x178d7e8bc73f, 0x29b9055712aa, 0x369a60bcf275,
+    0x37be419db461, 0x3c596f9ee250, 0x46ed514bae06, 0x588b7ad30bdf,
+    0x58fc40412840, 0x5a226ab823de, 0x5d2a8c539be3, 0x5e87d76f05f6,
+    0x5fb9ac6427d3, 0x6be7021b4aff, 0x6d026c503772, 0x6d089965d339,
+    0x75cb36b6c7b5, 0x795df6e1e810, 0x88ac228af25d, 0x890299b9773f,
+    0x8d97601603a2, 0x91bc10b184f3, 0x954360ed84d4, 0x9833f2fdaa96,
+    0x9ac55ca47038, 0x9bbd17b1d89f, 0x9d3c02ed92d1, 0x9e4ef676a9cb,
+    0xa05dfdaa96cb, 0xa0a904e9fb33, 0xa2ccdcf4f53a, 0xa319b8ac87ca,
+    0xa95ff7bcc970, 0xac7aa09914ca, 0xb6e4e097c672, 0xb7081aa13ef4,
+    0xb76ab16bf59f, 0xb7a9990f283e, 0xb8d83a31f955, 0xbd402b0e1ae4,
+    0xc7d7408d3760, 0xd0d9752e6fca, 0xd7491b5dc5b9, 0xe4448a74373e,
+    0xe7d9cec49710, 0xedd78abab3c5, 0xf575d20cdacb, 0xf7d55de8b6ab,
+    0xfa67ec5a9926, 0xff2f7abe265f, 0x01313d8ac4bd, 0x0ca5bfd7b221,
+    0x1aa49479b24c, 0x1adacae209ad, 0x1b8276658ebe, 0x1ed3cac83d03,
+    0x237f36740495, 0x329e08776497, 0x33619c23a98a, 0x354e18fb2f57,
+    0x372eeb548ee5, 0x3b0f717358e4, 0x401f82f10b0e, 0x47662e499059,
+    0x507656ff28b4, 0x53f77a258cce, 0x59a4b173baa3, 0x5cd21c1fdf17,
+    0x5e2c3368f864, 0x63d636e4d573, 0x63f761e71a08, 0x64f5849923fb,
+    0x64fcd679dc9b, 0x6a331b445248, 0x6a3355d3580d, 0x6d4219a27763,
+    0x839584db94e5, 0x85338c125fbf, 0x895ac0d32087, 0x8aa87df10246,
+    0x8f25b4c23c66, 0x9f35651057b9, 0xa4aa1c5ff47b, 0xa699927ac549,
+    0xac2b56be3ec6, 0xac9d0668723b, 0xad88674aa314, 0xb1cbf9522f48,
+    0xb5d5ac0107cb, 0xbd0711647485, 0xc22b097d1ca2, 0xce8ed02b9cf4,
+    0xd8a30e19d544, 0xdb3b548347fc, 0xe1f601f20253, 0xe250d1bdf4d4,
+    0xe569a6221206, 0x016ba8037ffb, 0x0244f6c2e909, 0x02ba4f5d78ce,
+    0x08b303091695, 0x13763e5b8c96, 0x1413299d027c, 0x1ad7ad11b4ea,
+    0x28e9b8a199b9, 0x2a6e9663e9ed, 0x41292b94317e, 0x448fc5ebf854,
+    0x4c2bfe36eb6d, 0x5e7491f61393, 0x691df426fd9f, 0x6d55dbb06394,
+    0x6e4a9d7a80fa, 0x737bd9fa77b9, 0x7e32bb21b62c, 0x8182eab94798,
+    0x91893a1f765e, 0x9275bfccc410, 0x94c8f17d6862, 0x974dc669f0b1,
+    0x9e68116016b2, 0xac95ca89b59e, 0xace0221c08b7, 0xb009a948cb16,
+    0xb7e0ab2888f4, 0xbbe51afddcc4, 0xbcf2568f6381, 0xbd0ca4cefb00,
+    0xbee70f83b902, 0xc5b4f3e057fd, 0xd8bbd8724063, 0xdd5f7d1f43cb,
+    0xdec92265e56c, 0xdfacf6d857e0, 0xe4051f27eb56, 0xe7f2af1efe32,
+    0xe953a32ac495, 0xebe840a3dafd, 0xee7b7ec2cec7, 0xf8fc9e9fc163,
+    0xf9873d2f69b9, 0x04a8b6af28c6, 0x13193afb5c66, 0x1804eda47935,
+    0x19c001cf20ca, 0x2210ef75e1d1, 0x2b53e0327b02, 0x2f2512e2c272,
+    0x332355a27a7b, 0x366ef20d9883, 0x3c9c42386548, 0x4507f33cc958,
+    0x45fcb506e6be, 0x4ac4436a73f7, 0x4ac65271a7e4, 0x4cde456f75e7,
+    0x4da079c1983c, 0x4fab9d762327, 0x516970550ff8, 0x54cf2070bfba,
+    0x573638bc59ff, 0x6489f3797e8e, 0x6615adfe7bd8, 0x67d0127c121e,
+    0x8610ea5522e8, 0x863dfa649381, 0x8897ce48df24, 0x8ca096bba093,
+    0x93cdde10a573, 0x9af98b7c81f0, 0x9bdaa13aaf28, 0xaadc664a326f,
+    0xb195b45eca28, 0xb377ab83468f, 0xb8c2c124c943, 0xbcdb522418cb,
+    0xbd302f69754a, 0xc09bd20bbb0e, 0xc1fdeae29e4a, 0xc480011ae15d,
+    0xc5ac1e1872fd, 0xc91f87b97ceb, 0xcc68da8e6141, 0xd24bd326daed,
+    0xdde6c34f9199, 0xe578eb0586d6, 0xf4baf7125f5a, 0xf4f635c535a9,
+    0xf5889b53a229, 0xf691f365c6d1, 0xf6bb9513e0df, 0x16225cd4e782,
+    0x1a4747ff6e98, 0x1aceb2bcc628, 0x1b8159116029, 0x219d46c67672,
+    0x2743a151f731, 0x2959fa669cd1, 0x2d728b65ec59, 0x2dd228ed5803,
+    0x2e465cbccaef, 0x38cd34914dc8, 0x3f693b230301, 0x44459a5d9d41,
+    0x51d5437aa96e, 0x5657f7b46e06, 0x5885e05465e9, 0x5dc28cc37d22,
+    0x63862ed1e061, 0x649eda528798, 0x6ab007c58eb6, 0x6d41716c5458,
+    0x6dc399502735, 0x70b1321d01f6, 0x7674d42b6535, 0x7e93345a2b2b,
+    0x84a16889e748, 0x8544bb6ffeba, 0x85adb9df56f1, 0x8bba5425eaab,
+    0x8bf224776a6f, 0x98af5b97baa4, 0x98f644c8b732, 0xa8a9c5f0bd66,
+    0xb0d0d75a5e9a, 0xb626381fe4ef, 0xb774df79ddc2, 0xc5b819279b04,
+    0xc7518d31f27a, 0xcd1395572d56, 0xcdafd0eb91ed, 0xcfb2b8834d24,
+    0xd37c5ec3d62f, 0xd65a2f0422d7, 0xd807fec77bca, 0xda812935ddda,
+    0xdbdf5e8d5f01, 0xe57c6f68d964, 0xedafdafdb226, 0xf6f1e17e3443,
+    0xf8f5b352068e, 0xf96344edd229, 0xfc15d996dc60, 0xfd2f6f539aab,
+    0x074ac49385d6, 0x07c8594ae54f, 0x124052cef123, 0x18da0fca6caa,
+    0x1ea5edf59f9d, 0x21a7e27b7bdb, 0x239d85abe870, 0x2d48500cbcff,
+    0x3797ccadcb9e, 0x3c9ca8cb6313, 0x41296d9a2587, 0x495beef2e735,
+    0x52b2c64a7659, 0x5a59f9667e62, 0x5d779b8614bd, 0x615562f09980,
+    0x6d05990e68bd, 0x7122f7c9316e, 0x7318d588a3c8, 0x777a994f2990,
+    0x7c560e4dacbc, 0x85cd266b6961, 0x9309c6bb4737, 0x975d966d6d0e,
+    0xa73a7eb4878b, 0xa875b49195f5, 0xa8f6b7aa4bf9, 0xb5e72befa88e,
+    0xb981eda892d8, 0xbf513a351c56, 0xc6e536633fbb, 0xc7150526f590,
+    0xd201327a7450, 0xd606c71ae4f9, 0xdebbaae67f49, 0xe2ace3cdee75,
+    0xe2dade197622, 0xe35036b405e7, 0xec8eced33179, 0xf0f943d4927f,
+    0xf31ad24952d4, 0xf75d3f85c22f, 0xf7e768f75efb, 0xfd0c4b4c1e2c,
+    0x0098de61a2c0, 0x0c1efdb34c65, 0x0e5054b49ad3, 0x18dc34d39c9a,
+    0x1b2eb6d72f9d, 0x24f7ed860385, 0x25a132f2b0f9, 0x29ec5169fb92,
+    0x2bbd20a7c742, 0x345f42a38878, 0x37fbd8d4a0ea, 0x3f963ca765db,
+    0x4005a2bb5f9e, 0x4240cfc2a623, 0x48cee23ffb6b, 0x4f54b8a080ff,
+    0x52d73b2107b7, 0x643ef3b1086a, 0x64d417f3ba26, 0x6603a352a251,
+    0x693a3457ad8d, 0x6b689215b0fa, 0x6d2ba7ce227e, 0x6dde4e22bc7f,
+    0x7981054a3755, 0x7c047adc9d06, 0x81afa32396ee, 0x842ec0188f00,
+    0x8dbd2d329823, 0x949597424084, 0x9a34da7c0e68, 0x9f04df8b711a,
+    0xa5632345108d, 0xa9796aae2663, 0xac54f1583959, 0xb13c10d4e2c4,
+    0xb39d3699e707, 0xb70d6c68a02f, 0xbb33b6ed49e3, 0xbb5a99e71eb5,
+    0xbe47bd95edec, 0xc4e97c1f3362, 0xc4f6fb1587c9, 0xc789fea575ce,
+    0xc8a1fa790bb6, 0xc9630a001132, 0xcf42cec63a18, 0xd1fae0d7cec7,
+    0xd4a7bd8948c1, 0xda3b1bb5eaa1, 0xde5c987f1b2c, 0xf319fe0f37c3,
+    0xf9d67ff62042, 0xfd7bc76213f2, 0x0428462d6893, 0x0d2fbda825b0,
+    0x10251d73c49b, 0x108aad81c647, 0x16b7c31d8d47, 0x17a916865422,
+    0x318fb7089d89, 0x3a4a183cc251, 0x3bf354d3a7e0, 0x4252f7e769f1,
+    0x43c33f61b2e3, 0x52c713786a17, 0x5a488865ba27, 0x5da3783f5abe,
+    0x5e2fb0b82b77, 0x6081830ead2b, 0x6462f3698e3e, 0x6b15d9d9846b,
+    0x751ee267a206, 0x75521f8cae66, 0x78e7d8fb19c2, 0x79e511710ca1,
+    0x82d8dcdfd990, 0x8b1fb9f19cbb, 0x8c8999c74421, 0x948a3d551c0d,
+    0x9b5f39036de3, 0x9bbffb55f666, 0xa08c5774fcc8, 0xa2c575750f60,
+    0xabe05e6cb6e6, 0xb08ceee39b51, 0xb45a78a38671, 0xb8ac394e785b,
+    0xbc577341020d, 0xbe3f977b1a3b, 0xc0dbc163ef08, 0xc6d733c3d20b,
+    0xc8a8b2aeaf0a, 0xc9f251be28ef, 0xcbee5c933710, 0xcd23655aa9b3,
+    0xd3fd2ec474b2, 0xdd58d3d77cff, 0xe3f80e3b82fe, 0xed7e04a9b6a8,
+    0xed825d472447, 0xf8b74843cdbd, 0xf9af786f41ae, 0x06a73e9556e3,
+    0x0d4f64c33de5, 0x0ff773b93eb6, 0x12ebe948c68d, 0x14380c7d7fe9,
+    0x144dc790a404, 0x25cf594230ac, 0x2af9f38e801a, 0x3426798ae3e1,
+    0x34961a2de369, 0x366a57cd05a4, 0x48f6665bd3cd, 0x55989f8f7b34,
+    0x6a7312137486, 0x6aec88bc6c25, 0x6e96d872dec3, 0x6f2ebb69d5bb,
+    0x78f02b19e579, 0x79633a1e3b8c, 0x7fa6455c2c6c, 0x84d956545153,
+    0x8909ebfcfea8, 0x8ce778d87da6, 0x91a481890179, 0x92c1c0361c14,
+    0x9369e0d7acaf, 0x9529fd4cd332, 0xa217ed6cf050, 0xad2754c9e792,
+    0xadfd3527fa15, 0xb84d61761a03, 0xc4accf872cb6, 0xc5213de5a567,
+    0xc7f747272de5, 0xcbf932d7423e, 0xcf60424d149e, 0xd3b44c8e403a,
+    0xd3b8dfbab39e, 0xdf31baa50ea1, 0xe0940e0af7a2, 0xf15b6cf82be4,
+    0xf3df57a89d1f, 0xf7d732c3b39c, 0xff55ae6db8ab, 0x1032c86e1108,
+    0x10c31ef5499b, 0x188bb7a29bfe, 0x18942e4e7177, 0x189b45a02452,
+    0x1baf86d7ce20, 0x1dcc4791154c, 0x1e6e3b1d0a20, 0x215b243cd392,
+    0x24852087a17b, 0x29243208317f, 0x2992e86f19f3, 0x40dfa89ec83f,
+    0x4bcfb971a914, 0x502b8ab198da, 0x5355fc1a724d, 0x564b5be61138,
+    0x5699d186cc2b, 0x5da578bb80ec, 0x5f1eabffaae1, 0x6262bbfb0a84,
+    0x65f491ea13cb, 0x69c80e3094ed, 0x6dec0f1f04ef, 0x7b50f1c2da35,
+    0x7da373c66d38, 0x80096746eaa4, 0x81a4ea587607, 0x836282a85d13,
+    0x88195e43451f, 0x8a64194813f8, 0x8ea1f3580fef, 0x92cf1a9f66b9,
+    0x9403392ac248, 0x96186d744b0f, 0x96a79f3066c9, 0x978b3913d378,
+    0xa1d154ccf58a, 0xa26937c3ec82, 0xa32df03b4e4e, 0xa7d3de7e8b68,
+    0xb0fbd14e7bcb, 0xb720703e6d52, 0xb8e8c8d06389, 0xbab9980e2f39,
+    0xc09120b7886b, 0xcdcd4be95ab7, 0xd161e08ca93a, 0xd43615560390,
+    0xd5ffcd421c65, 0xe7a7cccf7255, 0xf0560e676554, 0xf74d59e31898,
+    0xf7e9205971a5, 0xf97b07f40ab6, 0xfab4de76f682, 0x0ad282d971c6,
+    0x111fd93b6647, 0x192d11837b86, 0x1bb04c86db72, 0x1d1090e59086,
+    0x260c98712d29, 0x2ac005aabeaa, 0x33e539c669d1, 0x36453ac0513b,
+    0x3969b9a294ac, 0x3b930f16192b, 0x3d3150dbe9ca, 0x433156684031,
+    0x44085b916f8d, 0x46c48bb16c16, 0x4a02a92635b7, 0x4b190b10a33c,
+    0x4d24de723f76, 0x4fb43911d12b, 0x51b1a34101ea, 0x57c30b430ecd,
+    0x59ff22866c66, 0x5e232374dc68, 0x5e4a40fdb6ff, 0x5eb04629c435,
+    0x6603c0cb8c98, 0x6bcc6b246ec5, 0x751080ac24cf, 0x844a509c2d9f,
+    0x86578357ec77, 0x94fb44c92c77, 0x95970b3f8584, 0x97c47ec171dd,
+    0x99cce3c1b78c, 0x9a0e14fb23dd, 0xa6a0fac048b5, 0xaa01680273c4,
+    0xad127567cccc, 0xad34ffc433ff, 0xb601731b2092, 0xb66618ed0b2a,
+    0xb8a096474060, 0xb99eb8f94a53, 0xc03ea30a61a1, 0xc83a3e4dba9f,
+    0xcac5404fdeb5, 0xcb75d79d44c9, 0xcbf4911fc11b, 0xd158d035be75,
+    0xe601da0cd98f, 0xf698456b3b23, 0xfd4f49e9992a, 0x0b6bdb2c875f,
+    0x3096ba19fccb, 0x395c50ae3c48, 0x3a618ab1f916, 0x3b3ad9716224,
+    0x41de31e3cffd, 0x43429450eceb, 0x43aa33662284, 0x48e66ab72e33,
+    0x4d3a74f859cf, 0x572fd17a873c, 0x58e9fb6917bd, 0x5986ac1b87de,
+    0x5b8e26dfb679, 0x61a2fd4319e7, 0x6a049db2801b, 0x6b98cee352de,
+    0x73bb87af8673, 0x741707288a43, 0x7c06bd5eb73d, 0x7fe11667e575,
+    0x80d403b9d4b3, 0x82cdc4f8a922, 0x93b90d9c6735, 0x996b4d3513f8,
+    0x9ccf63679b57, 0x9f5a2adab9a8, 0xa3486a7eddd3, 0xabca4bb47188,
+    0xb099dba5c8b0, 0xb816484899d2, 0xbb57d41eb9fe, 0xbf36fae3615f,
+    0xc16a9b7ae97f, 0xc81ea6b5fc85, 0xd282f42e182b, 0xdf453398e74e,
+    0xe83e7c703eb5, 0xeb3158169e29, 0xf3a2fba1983b, 0xf4138680aed7,
+    0xf4167fc3f9d8, 0x019207b70a4d, 0x067543b451a3, 0x0d62b92a0cd0,
+    0x0e33913da065, 0x12946ac80f19, 0x1d31ad5cc75c, 0x25b1ba1a2ce9,
+    0x2649d7a029a6, 0x2a6561e2c42f, 0x305ae1bc1130, 0x314ce4d1e95a,
+    0x36088e284a8f, 0x3c8b30b67f5d, 0x40a1781f9533, 0x47f776e69d0d,
+    0x49e7d73d84ef, 0x4ce5adb4f953, 0x4f71d4823a42, 0x5083ddcf3a28,
+    0x5e4ddb630590, 0x5f293929a28b, 0x6535d3703645, 0x67cb5b2563c1,
+    0x77d9ac195c76, 0x81810818da7a, 0x8db4c5749f32, 0x96696eb133bd,
+    0x96829825ae63, 0x96946fb97069, 0x98c6b0f6d5eb, 0xa6d463e94d1b,
+    0xaa1ccc821a5d, 0xab707c26921e, 0xb12ba7891fe4, 0xb6f033d39a37,
+    0xbb12d567e79b, 0xbd292e7c8d3b, 0xc98234e8fe62, 0xc99b5e5d7908,
+    0xca9ae069a599, 0xcdca94ac03bf, 0xcf90a3a7c044, 0xcfc62a630656,
+    0xd6272cd0eb05, 0xdc067c790861, 0xea99160397aa, 0xefcee5b001cd,
+    0x0c3886a6a55b, 0x12230baedd6c, 0x1da079c5abd3, 0x2305dda6c606,
+    0x253fe5e2efb2, 0x25d3703c790b, 0x2c130d191360, 0x2c4a2dbd81d5,
+    0x2c56fd06c4ed, 0x340aff6c100e, 0x390a23921746, 0x406a6d7d22c1,
+    0x5ea4ddb191ff, 0x5ec8c7681bd0, 0x63738366d213, 0x637fdd9209a1,
+    0x6ca3ece297ef, 0x6e3942de878b, 0x6e6185327efb, 0x7c6820d34350,
+    0x8311e0ea52b5, 0x8517c1c558ed, 0x856f5dbefaa8, 0x8842e2db43af,
+    0x91897c883930, 0x91f70e2404cb, 0x93eb8c895487, 0xa0b299af9cd3,
+    0xa3a3664ec85a, 0xa74a47a3e46d, 0xa940256356c7, 0xafb758026b1b,
+    0xb279efc70930, 0xb5dc6c10682c, 0xcc96c6b1a9f8, 0xce15024052db,
+    0xcf2ab47daf11, 0xcf3fbfe3c1dd, 0xde69179a2b45, 0xe55617f1dae8,
+    0xf64c95f5b3b0, 0xf91978de5566, 0x00edf698d3cd, 0x05774d063fb6,
+    0x0936e2b1cae5, 0x182e220e44c6, 0x18b4dd1e8b07, 0x19a162cbd8b9,
+    0x299f761537cb, 0x2bc4385c48e6, 0x2d6e99be4b4e, 0x329a1e46b1d0,
+    0x3a24446edd1e, 0x3ebcb3bbc5d1, 0x4672500a3955, 0x4da16bd76c5d,
+    0x563b1727581a, 0x58e77ebac68a, 0x5f0665b327d4, 0x6a56140d7453,
+    0x6da0c63c7b47, 0x6eb3f4549806, 0x773b1863b06e, 0x7bc4e3ef27e1,
+    0x7ebffbb25709, 0x8015f4ed087c, 0x81925c038337, 0x881782b6f77c,
+    0x89653fd4d93b, 0x9de59239f15b, 0xa25ee58bc966, 0xb33179d9185d,
+    0xb68084a58cf0, 0xb9a3a42dadc3, 0xc6185833d907, 0xc7e78d887c54,
+    0xdf0aac0a1092, 0xdf7ea54a7db9, 0xdffd243df446, 0xe679249881c3,
+    0xe9ff157a5f06, 0xeec3357c95b4, 0xf1d7ebd24b0c, 0xfcef54bd0c3d,
+    0x07098531da8f, 0x0f14e901c1a6, 0x144b2dcc3753, 0x186dcf6084b7,
+    0x21a082728445, 0x2425575f0c94, 0x25ffc213ca96, 0x270c888745c9,
+    0x28189f4dafad, 0x2c2dfc7aae6f, 0x2e2ed50b35b9, 0x331962e935af,
+    0x372265eafce3, 0x38894c7d5948, 0x49d2d34e6067, 0x5207291f503d,
+    0x6119a0f778b1, 0x63fe136b6caa, 0x6ab60225e1c5, 0x6c27e389531a,
+    0x70f6142087a4, 0x825346fd7ef1, 0x836724c2acff, 0x86d87f5c8300,
+    0x89f6d1292aaa, 0x8a52161328b5, 0x8d117a057604, 0x92662b1deb0a,
+    0x98e942ca2b62, 0xa2a1170348ce, 0xa8af10a3ff26, 0xab47570d71de,
+    0xaf13bc024025, 0xb8e5695ce986, 0xbf236c505b78, 0xbf32fa4de3cc,
+    0xc2c1274c90c3, 0xd3d5d70f631f, 0xde5dd3af02d1, 0xeb690b52026f,
+    0xf1cabd6cf86d, 0xf5ecaf543482, 0xf60563aaa39e, 0x02b55663a531,
+    0x02f22effa3e3, 0x0b4d67ca688b, 0x18f9a8bd45e9, 0x190e799452f0,
+    0x21517326b406, 0x2706e691b18f, 0x35cf7bf59274, 0x42ef0f51938f,
+    0x4f0ac803fa7a, 0x53e6027377e1, 0x546b5e299b84, 0x55367e459edc,
+    0x5c4fa470a804, 0x5daf39224bc9, 0x5f6d46903e5f, 0x60f592b3e51e,
+    0x6133901b00a9, 0x6ba8905bc17c, 0x6d2706797024, 0x6e0647bf6f34,
+    0x706e84d62652, 0x738968417771, 0x7d50ca781d31, 0x7db535bb0204,
+    0x85537d0d290a, 0x8a313ba1e5e8, 0x8afcd0dbf4ca, 0x8be5387adaa2,
+    0x983e7976518e, 0x992a8a0593b6, 0x9a89e42831b6, 0x9e77741f4492,
+    0x9e8f3e399c9a, 0xa0c0953aeb08, 0xa9f93ad38098, 0xb1489766e121,
+    0xb6f7a32d3d1e, 0xbd6d0154234a, 0xbed50cb19c88, 0xbfb18f43565c,
+    0xcb4359132640, 0xcbdc60d53a11, 0xcc6948fb1c19, 0xd211b28dd0c5,
+    0xd528b279bfcf, 0xde53d91c00f8, 0xe6fc9d4b697f, 0xe9ca2fe11c84,
+    0xefcc7f03ac9d, 0xf3dd0e753236, 0x0ff5b5a5db5a, 0x17115ff623f9,
+    0x17de19fb4fb4, 0x1cb02e11e653, 0x1d9561de7b65, 0x2338fdb5b6e8,
+    0x28748559b148, 0x3183c3d3328f, 0x3712c96266d0, 0x41548c7e1b43,
+    0x4354404385b4, 0x43e7ca9d0f0d, 0x53d5a03bd47c, 0x567d3a13c9c3,
+    0x58b916c82197, 0x64ebaf58c976, 0x68e474aff707, 0x760d2e64deea,
+    0x77969f53a282, 0x77f30908bd66, 0x7d6c8e13d351, 0x88ef79932c30,
+    0x8c2caccbdebd, 0x9c8c3214d7a1, 0xa2a944950ac3, 0xa2f4c0f27ab5,
+    0xa572f3ab5bb3, 0xa6e3b043b02f, 0xac3a35d4535d, 0xb685cef5ffe7,
+    0xc898dbf9e1fb, 0xcdccd72e1df6, 0xd0ab1c8c7628, 0xd56984971c99,
+    0xd9cbbd7badeb, 0xdb5a7143f636, 0xfe08f1f0b8cc, 0x1a399dcabfbd,
+    0x248a04a7e570, 0x2e1a463a1cbb, 0x355be9482318, 0x434abace8f65,
+    0x44ceae54c885, 0x528e60c4904c, 0x53334d93d021, 0x5c55fd8a3bd1,
+    0x5fabe5195d7a, 0x60f424ceb4c1, 0x6a1032917920, 0x7715b23ce881,
+    0x82c86c7ff735, 0x8b1ffc5a5f8d, 0x8c9d4dacf15c, 0x8feedc9ea566,
+    0x931654c433d8, 0x941093f6dbb6, 0x9534e9f5a92c, 0x96fbe32d7cc5,
+    0x9b5d31d5f703, 0x9e7bf8c0aa37, 0xa1869e8161b3, 0xaea840e496bb,
+    0xb069bcb3dfdc, 0xb73ca95afdc5, 0xb7638c54d297, 0xb8d4be0b329d,
+    0xbb54158f3074, 0xcaf84348b419, 0xce1bd7eee076, 0xd0abe23b837a,
+    0xd556d8c93f82, 0xdb3f8959496b, 0x04b8f51f5ffb, 0x14fa83386510,
+    0x180aa661a704, 0x20a5015ea410, 0x23bbc6bb8d55, 0x2de809532372,
+    0x3d704ee3e735, 0x3e12b78de793, 0x444ac7fac383, 0x46586fd48de5,
+    0x4b17c21b4b6a, 0x4ce104e958b5, 0x4e0e81410cf3, 0x523665aedf0a,
+    0x543aaca0bcdf, 0x5a0ddcaca872, 0x5bb8037fa515, 0x642fd4203aee,
+    0x682983b37f93, 0x6d8f9741ab15, 0x7d9de835a3ca, 0x8005759f4999,
+    0x8678c4befbd8, 0x8925a17075d2, 0x9562100715c8, 0x98eccea46c34,
+    0x9dbb7459ac48, 0xa3720c8fc6aa, 0xb2f3ea7be8e1, 0xb35ef7f27505,
+    0xb90bf4b19d15, 0xba2bf212fcec, 0xbe07aa764dc2, 0xcd12d06dbd96,
+    0xd252b0af2595, 0xda5479081a5a, 0xe43036f7c197, 0xea0625b7f266,
+    0xeeb8a8b56cd3, 0xfe27c4d1b5f0, 0x0c8d88dbda65, 0x101acb9e7048,
+    0x150bc12111ca, 0x1a1271b6d767, 0x1b09b7a63444, 0x421911dd658e,
+    0x4b5fab8a5b0f, 0x5991bd51679a, 0x64b03d8ddba6, 0x76cbfbcc98f8,
+    0x7a225879c62b, 0x7ea497957f39, 0x86531c923fe2, 0x87914bb2994d,
+    0x8af2ddbfe135, 0x8b63689ef7d1, 0x8c4fee4c4583, 0x8d5b8ff4a3dd,
+    0x968b84525e2f, 0x9c7dd0595a6a, 0x9ea934d412d6, 0xa6b9665f7316,
+    0xa9922e5540d0, 0xb69cf0da34e4, 0xb95159fb6d43, 0xba539abbdf10,
+    0xc4cf3d304734, 0xd8d2aa8b112a, 0xdc055810ba51, 0xddabd5f35aa4,
+    0xdfd9be935287, 0xf956b0f93268, 0xfebb652d3b4c, 0x08bbbc807da9,
+    0x0b3fe1bff4a9, 0x0c38fc277fae, 0x1423aa132dba, 0x14fe92bbbf2b,
+    0x2aa1a1367c5e, 0x2bc8f07894d5, 0x3c36def3f934, 0x3d495d5f04a4,
+    0x432872781c3b, 0x4b4cffbc7df8, 0x54fc22bac026, 0x59277589e8c8,
+    0x59e58bcda343, 0x5f40a48ab9d5, 0x5fe347c3bff8, 0x69c114ba9b22,
+    0x7b70db46b53c, 0x7d634aa4d10b, 0x7fad908b945a, 0x815c851a0a26,
+    0x830ac9fb6ea3, 0x8dcb46999f68, 0x903ef39f7700, 0x9114996e83be,
+    0x97e1938f0ba5, 0x9c9af34f3328, 0x9d71bde95cbf, 0xa40d14ce00a9,
+    0xad35f1da0820, 0xade31ac617a9, 0xbab05501fbbc, 0xc2009bd17359,
+    0xc421400a1c9a, 0xca126745fbfc, 0xd55ee1cdf7b5, 0xd68b395a8f1a,
+    0xdb2f8db4a3d1, 0xdb533cdc27dd, 0xe8f14f2b9f85, 0xebb83f8dab39,
+    0xec41f3e13c7b, 0xed5c394b0c15, 0xf97c8529e664, 0x0b6e672b83e3,
+    0x101f15b0d028, 0x1ff521353d8f, 0x2cf4e8e91cb3, 0x33924ed4f48a,
+    0x3894e15c524d, 0x41d045a92d19, 0x52d7eb93b698, 0x59ca6953f0b3,
+    0x5ecc4c2e3d27, 0x615245e5e24f, 0x634bcc95b0f9, 0x6983a2738724,
+    0x6d8b461b2bba, 0x6e3500a5e4b8, 0x7ad6150e6f46, 0x8a80354e88ed,
+    0x8fe056561e6d, 0x940f8ca4a924, 0x9735dfff1abd, 0x99e8af372ab9,
+    0xa57e21f756ed, 0xacbdf08d2f22, 0xafa97a52d5f6, 0xc6574039da6f,
+    0xce4179077cf1, 0xd18722ec04f7, 0xdcb4f696fb92, 0xe7695398fa8e,
+    0xe81e4383ce41, 0xe8b48c919cd6, 0xf1b43d0d95c9, 0xf8f5306e8ad7,
+    0xfbd4d52705a7, 0xfd551fbce277, 0xfe4b7b702840, 0xfedfb576c2e8,
+    0x0341793d48b0, 0x1fd1c29ebb4b, 0x21cef23ee645, 0x2df94eb2be70,
+    0x3167ea984f35, 0x33a97f443746, 0x357a13f2fd31, 0x3d7ba1bcec31,
+    0x423242c8ce78, 0x43bb79288c4b, 0x465b8690c32d, 0x4bd78fc1188f,
+    0x5c20352bd07f, 0x5c3197a186fb, 0x5eeb439c440d, 0x6669f9d54ee1,
+    0x6f10746e7db6, 0x71253399faf3, 0x71cc69ff747a, 0x783916eb7f68,
+    0x799711b3faca, 0x7aec5b419aee, 0x7ecad2593100, 0x8288ce1b93cc,
+    0x8c8ea2d760a1, 0x91c905b03e28, 0x92e4aa743060, 0x951a5a12ec6d,
+    0xa80172fcb2dc, 0xaebc206b6d33, 0xb3e386e56bdb, 0xb5ecd621c89e,
+    0xbb67ba870127, 0xcd54943e1fb8, 0xd30d3b7b6e07, 0xd4de0ab939b7,
+    0xd52060bdc2e1, 0xd659c222a323, 0xe8dd1f76960e, 0xeb51b6b884ba,
+    0xec55cbf124af, 0xf13d9b1adf69, 0xf7682c9166f2, 0x054267cfcbfd,
+    0x0569bfe7ac59, 0x1dbca4572e87, 0x1f0fa44e94f9, 0x250907a7440f,
+    0x2a6f55c47556, 0x2bf08a96693a, 0x494f9d043b7d, 0x4a3804a32155,
+    0x4f7894919aa3, 0x54a3de8afb60, 0x5d5fd9a8488b, 0x62dabe0d8114,
+    0x639c08238c55, 0x722abe2eb989, 0x787ad344f346, 0x8f0b8c381b04,
+    0x8f5ff45f6bf9, 0x940274415288, 0x991878459ab4, 0x9b8289d47ffa,
+    0xa1d1b4aea2a3, 0xa3a15f21517a, 0xb84f36b3e5bd, 0xc2f52a83793e,
+    0xc771b1a7a20f, 0xcddd745795e9, 0xe27e7ca0e714, 0xf101c2bcf3ce,
+    0xf5296c9bc020, 0xfa4d9f436e02, 0xff8c9548beed, 0x0574210dabfd,
+    0x2e556f4dc5d0, 0x3d731c8608f9, 0x4123d3e11d23, 0x4677d54c80da,
+    0x527521b0e86c, 0x52817bdc1ffa, 0x5e1d5640edba, 0x5fef4a49d643,
+    0x684f50d01414, 0x714da1f1ea69, 0x785c4269ea2b, 0x80ced030fb51,
+    0x83e38686b0a9, 0x866e8888d4bf, 0x8a7a4a3ee12f, 0x9906f142da76,
+    0x9cff06ecf6b8, 0x9d2ed5b0ac8d, 0xb17ac6259b74, 0xb7f3cd3cddf0,
+    0xb8ac66180df3, 0xbd58f68ef25e, 0xc04027b72b93, 0xc606135bc884,
+    0xc94e41659001, 0xce088b61ce98, 0xd076baff1bb8, 0xe3dab8f33051,
+    0xe746d0b3819f, 0xeefad318ccc0, 0xf4aac91b3fd1, 0xf522e06a14d2,
+    0xf8be8c5f1630, 0xfa78b64da6b1, 0xfb4201f17be1, 0xfbe4a52a8204,
+    0x050c5d6b6ca2, 0x11013323febb, 0x17b6289b28d5, 0x1f430d77995f,
+    0x253225ac44d4, 0x25c749eef690, 0x32aa79cd0483, 0x37ba8b4ab6ad,
+    0x3d8076ef539e, 0x3e8af3cc951f, 0x41db2364268b, 0x49b22543e469,
+    0x4fa645c30ecc, 0x58456e7b8501, 0x5a91c3697c3d, 0x5d2bde4b1d1d,
+    0x5eed1f8b6079, 0x69e029a18c4f, 0x6b5fc48a57d0, 0x729b7511c82b,
+    0x73974e2d986c, 0x76da395ddb36, 0x80a57f13e30b, 0x88690f76b680,
+    0x8c612520d2c2, 0x959696e7178c, 0x9860f5aa79cb, 0x9c48cda9fc6a,
+    0x9c747e5f4a65, 0xa6ec3d545074, 0xac3c95cf57db, 0xb91df13537a6,
+    0xbc75381e7bed, 0xc1177d715cb7, 0xcb62dc04037c, 0xd350f8510813,
+    0xd4ea31cc59c4, 0xdabefbc16dba, 0xe53f6bf14f07, 0xe76468c765e7,
+    0xead8bca486e9, 0xec52da24c7f2, 0xeff478a05f52, 0xf8a62899a8dc,
+    0xfc6eaa0f150e, 0xff4319677529, 0x196da9d7701d, 0x2e7825ae2509,
+    0x38abba2673c6, 0x4e447d7d2d58, 0x5490af140500, 0x54cb038ac43b,
+    0x5e808e2da7f5, 0x5f174c598214, 0x5f412896a1e7, 0x619210b10c87,
+    0x7a4c59a6be89, 0x7e534da151d0, 0x89d54ee4939b, 0x8dea71828c98,
+    0x94ae0abb27f2, 0x97544538fa9b, 0x988e1bbbe667, 0x9905835daa19,
+    0x9b62c5a34c47, 0x9f36b707d8f3, 0xa30d6720aadb, 0xa915a8c9d0f6,
+    0xad05f775290e, 0xb8b12548795d, 0xc09a394aff06, 0xc2e68e38f642,
+    0xc316978bb1dc, 0xce221b694709, 0xd05755e9f78c, 0xda83d310936e,
+    0xe5ca95a0feea, 0xeb241474ed19, 0xef37280bb229, 0xf15bafc3bd7f,
+    0xfabd475d5bce, 0xfacbeb1ecd0e, 0x06f76c5dc212, 0x10c4c11afdd4,
+    0x1425a37b346d, 0x21a842bff7bd, 0x343d0289a124, 0x346286295358,
+    0x3708fb362bc6, 0x3d9d003a1710, 0x3f3848bc9cae, 0x48da9c719bc4,
+    0x4ffbc42a6edb, 0x540c539bf474, 0x598ceff8bd3a, 0x621ba0779407,
+    0x6453248e7e3c, 0x6c726ef95b46, 0x7248984891da, 0x8133b808d9f2,
+    0x87956a23cff0, 0x8d895013f48e, 0x8e9c439d0b88, 0x8f145aebe089,
+    0x91c30c1588ab, 0x93ac8fa9c377, 0x98987ce1e60b, 0x9ba5e156e2c3,
+    0x9bbb9c6a06de, 0x9d23e25685e1, 0xa601f2b22eb5, 0xaad5db40f37c,
+    0xb02bebb38b20, 0xcb7f9fddccb3, 0xddbb29c4abfc, 0xde49e662bc2c,
+    0xe0aeb5181cbf, 0xe7c850613171, 0xe96a0088589b, 0xeaf00315c5a8,
+    0xef920dd9a0ad, 0xf5e17342c91b, 0xf6c631f152a3, 0x1b47cb721a9b,
+    0x228b0869495b, 0x269704ae5b90, 0x32ca877b1a83, 0x389b3361c69f,
+    0x3cbf6edf3c66, 0x4012d2491e98, 0x4338eb148a6c, 0x498f2d405ff0,
+    0x55c06676e531, 0x585defb9dc9c, 0x64aeba097e0f, 0x660353ea0ce4,
+    0x673354670099, 0x6cda24108ce2, 0x6d66d1a76925, 0x6f9fb51875f8,
+    0x7060ff2e8139, 0x71e6177fd732, 0x8643a4f9825a, 0x8755e8d58805,
+    0x90b7f58d31de, 0x90cd3b824a6f, 0x96e963c6667d, 0xa6ab4e20d82c,
+    0xaaeb71c70dd5, 0xaf999c271aa3, 0xc4aba46d8df4, 0xc555d416527c,
+    0xc8478af19517, 0xca6ed15de5a9, 0xe3958724466d, 0xeb1059ddef2c,
+    0xec505d7676bf, 0xf6191f073f1d, 0xfd0f8046db4d, 0x15c41144fd12,
+    0x1ef1817d77ed, 0x32808079c932, 0x4153d61fb942, 0x4ed21cc70ef3,
+    0x53407547d20e, 0x5f8ef60139cf, 0x644103e0a8b2, 0x6b4e7f8d8b9b,
+    0x708f0f7c04e9, 0x7619bc6dcb8b, 0x7707dc0441a0, 0x7e39b685b9e4,
+    0x83ee05259a94, 0x88bef471145a, 0x8cae58e0555e, 0x99acc13a11e4,
+    0xa2091ecff365, 0xa5ac91c3b8ed, 0xabf4a54c28bb, 0xb1e7db8f3c0a,
+    0xc76391f218e1, 0xcfcda90d548e, 0xde8e025465bf, 0xe82c37fafcfb,
+    0xeb24cb98ecac, 0xf0bb2308d98d, 0xf43d6afa5a80, 0xf6989e38c8c1,
+    0xf7099e35eae7, 0xfac849a55f02, 0x0a488da858d6, 0x0adba2e3d6a5,
+    0x0edb44fdb14c, 0x14223c90cc26, 0x16a74c0c5a3a, 0x16e8f263d215,
+    0x19959486464a, 0x1b94d32da531, 0x369cd06b710d, 0x4c23819f62d4,
+    0x50fc7278a689, 0x51389b6793ec, 0x5f7b25683fdf, 0x615ca76eb0bc,
+    0x67789523c705, 0x67baeb28502f, 0x6e4136a6e14d, 0x716a48b59822,
+    0x7ac578aa94e5, 0x822f237d8ced, 0x920569910019, 0x92b675fc71b7,
+    0xa13a6bc58fc0, 0xb95f55e98a41, 0xc6bbc1e18a0e, 0xc8ca53f76b84,
+    0xd02b13008289, 0xd12ca413e307, 0xd4792abb1823, 0xdd974785106f,
+    0xe9156548f025, 0xec321d2c6f6c, 0xf07db0c1c58f, 0xf5a16e4b67e7,
+    0xf61cf3fb9373, 0x0c85a529c986, 0x0d660b3ae56f, 0x1614fc7fe9bd,
+    0x1d17083dac2c, 0x2627e0a055d6, 0x374f8cc20711, 0x4301d1e70a3b,
+    0x4b7fcf9d3bdb, 0x4ba34435ba22, 0x4c8927af6083, 0x50eaeb75e64b,
+    0x51cebfe858bf, 0x52441882e884, 0x52e890341ccf, 0x61468d3f7d1a,
+    0x6ea6dcb6defc, 0x6f4d28e0416f, 0x78bd9eca56c3, 0x80c34aa2ad9d,
+    0x9704de480919, 0x97e8ed498152, 0x9bfb8bc23ad8, 0x9c849068bacb,
+    0xa19e77ec650c, 0xaa5b97d4cf10, 0xbe99cec463cb, 0xc1fcc02bce51,
+    0xd3b746f9f796, 0xe338afc80e43, 0xeb056683c880, 0xf42ce435ad59,
+    0xfd5ead0b95d3, 0x05b3b8c0beb4, 0x07823e6850b2, 0x295b1946068a,
+    0x2ffbb3042f27, 0x37601afda27c, 0x404542aafe2b, 0x522d14179dce,
+    0x5b8e36933093, 0x610b648ea2ce, 0x66bae5730a55, 0x67174f282539,
+    0x7a9ced3c89f1, 0x7f21b07d8276, 0x863608ed1275, 0x8cbaba827b30,
+    0x9277453f2b94, 0x99c8764aba45, 0x9edd1af4dfd3, 0xb68769a4c5dc,
+    0xb7775db36a19, 0xbdc64dfe86fd, 0xbe9bb93e8df6, 0xbef9f76bd702,
+    0xbfc37d9eb1f7, 0xc0138d28954d, 0xc15da1561abc, 0xcba93a77c746,
+    0xd0788fda18a9, 0xde8f2e9670dc, 0xe3b7f46a9222, 0xec775de935d8,
+    0xfe9feb713642, 0xffff4593d442, 0x01cbf6c33818, 0x0d89714855f7,
+    0x163c460cbc5a, 0x191bb0363165, 0x1f910e5d1791, 0x21dc790ef7b9,
+    0x2a49fe8b89f1, 0x309a4e30c973, 0x3bfa74c4b55a, 0x3ff9dc4f8a3c,
+    0x408cf18b080b, 0x40f923ccb108, 0x4cc7168b6e4f, 0x4d789814eb77,
+    0x4f02f33fc623, 0x58b9a2adc6b6, 0x6ef78d62c5e2, 0x6fd77e55d641,
+    0x7db90b74f3ec, 0x81552c8800d4, 0x84760279e7f5, 0x96ba3d9ba27c,
+    0x9d2a937809ba, 0x9d6b4f936a81, 0xa34ce8d1c18f, 0xa695516a8ed1,
+    0xae996359bd48, 0xb5fce1171989, 0xbbb8f6b5be63, 0xc2a5bc7e6841,
+    0xda6d52b130ca, 0xeb6704875a58, 0xec26b4b43d36, 0xf21bbf6f7ead,
+    0xfb9ef7296d1b, 0xff3fe5f7f32c, 0x0a5873add136, 0x1703d33a5f65,
+    0x1c38438ca6ea, 0x21a2afb8400b, 0x3bdbe3e9ac3f, 0x3fcce24215a6,
+    0x40869fe86282, 0x45687c8b873a, 0x46acd8c17c6c, 0x4a2634e91c5c,
+    0x4b1db5677efe, 0x51d3cfa9c5f1, 0x535036c040ac, 0x55edfa923ddc,
+    0x642b41b9651c, 0x65451205292c, 0x69ac5334396c, 0x6b708db7c7c9,
+    0x6e6f88fa5906, 0x78ca758a881f, 0x7c5867fa2f51, 0x80554b5fc4bc,
+    0x80b7e22a7b67, 0x81a1a92383dd, 0x827a0da6d5d7, 0x8c9f38ecb919,
+    0x8dd441b42bbc, 0x8fd8c3350f56, 0x9e1cac8fdde7, 0xa13be8989ca5,
+    0xa25df5013069, 0xaecb5726a30d, 0xb04f101dd668, 0xb7c58a3a1188,
+    0xb9d999b87d76, 0xc1ea05d2e37b, 0xcdc8ab5a45ef, 0xdf23cf30094f,
+    0xed16bec4dd76, 0xf0157f7868ee, 0xf4b7151e3869, 0xf58b21041cc4,
+    0xfa6362304f2a, 0x071772f7b897, 0x07289ade694e, 0x0f9361a6b64a,
+    0x14632c271337, 0x171b03a9a221, 0x17fdeddffd81, 0x1b0684998110,
+    0x1c8869188643, 0x22dac7c4f9b2, 0x252a15f63bef, 0x27f2dad075cb,
+    0x2870e4a5e0ce, 0x2c3a50576414, 0x3d510f216a5d, 0x48af613d281c,
+    0x4af00bacf919, 0x4d7a2373061b, 0x569004202eb3, 0x5db7937da356,
+    0x7168329a44ba, 0x71c96a0ad8c7, 0x76c4e54083af, 0x782c40f0eb9e,
+    0x7920530df7b5, 0x824253575216, 0x878a6fb589c9, 0x87cc509c0769,
+    0x8f83c162a915, 0x922c8005bb35, 0x943bc1c8adfa, 0x95801dfea32c,
+    0x9b446fba17ba, 0x9b738ed0bc40, 0x9d6ee9f8b912, 0x9e49981244be,
+    0x9e9b7c14563c, 0x9ed6803826c6, 0xa4403cb6ae98, 0xb5ebe53460d8,
+    0xb8cbc47be16d, 0xbb5f029ad537, 0xc082859571ca, 0xc599ae64d6cf,
+    0xd590353e777c, 0xdf1924eff627, 0xea1ef0d5fb17, 0xfbce424409a7,
+    0x0cb18959fdcb, 0x169d4a6538e6, 0x1922946fccbf, 0x1e4f03344a55,
+    0x20eaf28e195d, 0x2123ad1bb035, 0x27e39d63ef3f, 0x3292b78c6988,
+    0x365b7390db7f, 0x50e05eaebd6a, 0x52e3bb64842b, 0x6574d23dd142,
+    0x6b7930679548, 0x6c8756353319, 0x6ded17fc72a5, 0x7042cdd2566e,
+    0x7a23940c7c99, 0x7b30cf9e0356, 0x821bfb7d84d1, 0x9bab00062c7d,
+    0x9d7da3bc2655, 0xa0374fb6e367, 0xa5d98c33fc4c, 0xa914b0657aec,
+    0xae5998f161d9, 0xb8c1c9e8df94, 0xbdaaf86cbcec, 0xbee4ceefa8b8,
+    0xc357f52be4fc, 0xc6fd7726de71, 0xc8645db93ad6, 0xcf5d431e167d,
+    0xd73186498f1f, 0xf484b3aa355e, 0xf590ca709f42, 0xfd5ea5f77658,
+    0x05247ff0837f, 0x0a34916e35a9, 0x0ac522847401, 0x0edc199a9b26,
+    0x101f5105737f, 0x167caa82fbde, 0x167d1fa10768, 0x17679647212d,
+    0x222eefa7ff08, 0x24d5d9d2e300, 0x272bca37cc8e, 0x290e367a547f,
+    0x2bffb2c69155, 0x3878f9f92ffd, 0x3a3a75c8791e, 0x4f844e606c33,
+    0x690907c5103e, 0x7118148553a5, 0x816f5db17cd5, 0x8e5d1342942e,
+    0x94f4fbc5e18d, 0x9ec49a195701, 0xa0edef8cdb80, 0xa112c37f7c65,
+    0xa45a41dc3293, 0xa5ba111cdc1d, 0xbdc7a6448a20, 0xd0412d9284f4,
+    0xe5ef36de5717, 0xef1fa05a1cf3, 0xf9211c787c29, 0x235b5d369270,
+    0x27e05b0690ba, 0x291822824899, 0x2d66af5ae9bd, 0x2f9d83c4c2a3,
+    0x351952661240, 0x382816353196, 0x46f15b4623ca, 0x4c5859106660,
+    0x52a7495b8344, 0x594efa6b5ebc, 0x6576982af1ab, 0x72b5f72f14bd,
+    0x8248fd01e7ab, 0x89463b0430f1, 0x921da92c3274, 0x98eb8d88d16f,
+    0x9911c0d594f2, 0xa58d170f6787, 0xad2d6d68c27a, 0xaf9a3dabecfc,
+    0xb60fd661d8ed, 0xbb11b93c2561, 0xbd3d1db6ddcd, 0xc881d140155c,
+    0xc95e53d1cf30, 0xc976930a32c2, 0xe60e2e4c5dfd, 0xf2b56fca8452,
+    0xf5e5240ce278, 0x0829d44ca889, 0x0aa791e77dfd, 0x1b7a9b52d87e,
+    0x1c2edb909ae2, 0x21e11b2947a5, 0x2383f01b8ba8, 0x26906a54714c,
+    0x3980347912f9, 0x40452d0bd0f1, 0x42bcbd910a9e, 0x5227810fe61c,
+    0x5a41c3304438, 0x66182c9ad6f8, 0x71b90f4a23a6, 0x7458e22354c3,
+    0x7cfa547204aa, 0x8237eb1d32f7, 0x8453870b5d4a, 0x93e4f2f507d5,
+    0x954487a6ab9a, 0x9f6fe0022aa3, 0xa01666ba92db, 0xa3506620f4a2,
+    0xaaba10f3ecaa, 0xaec055416ea2, 0xc9e43aa7fa60, 0xca50325a9d98,
+    0xd48f71511294, 0xd93c01c7f6ff, 0xeb9f1e55bc69, 0xecbea69910b6,
+    0xf3a48f9f0d7e, 0xf78b42d37344, 0x0c3b63fc4139, 0x0c5870f01df4,
+    0x0ea1920bc46a, 0x0fca40a7ff7f, 0x1311bf04b5ad, 0x24eb61cdef9a,
+    0x2a7dd5be7a66, 0x2e20d3943464, 0x3002559aa541, 0x44f57c750daf,
+    0x474abd2ce5ee, 0x4766a555a5d0, 0x4d4afd48421a, 0x4d6969964173,
+    0x636a06913663, 0x65251abbddf8, 0x6b5479eddeaa, 0x6f4ed92e349e,
+    0x7b2199a86b0e, 0x89b3be14eecd, 0x8e062e6cf206, 0x97d0c475e88c,
+    0x9c2125c6b7d8, 0x9f24eec4c23e, 0xa19567f84910, 0xa2ea3c67ddaa,
+    0xa71086ec875e, 0xb4656674c8c6, 0xb7fb5a7239e7, 0xc406605bfba5,
+    0xc4d90ce7bd62, 0xc52ff9344dce, 0xcd562061d7ee, 0xdc7b5a09d97c,
+    0xe5e31eb91392, 0xeb3dfce7245f, 0xebfe97501e51, 0xf53e543a66bc,
+    0xf89c3d575254, 0x015036e6d590, 0x185a2bf3ef28, 0x213d449a16ea,
+    0x35a4a819ba29, 0x3b392b1178e2, 0x3cb55798edd8, 0x41bd6788d613,
+    0x4f35f6389b87, 0x5224b3d09321, 0x59a53e81cc1d, 0x5d2cc94cd1c3,
+    0x618357b33cd6, 0x6af31df040db, 0x6be0c868ab66, 0x7306bddcf7a6,
+    0x761aff14a174, 0x7d2805a378d3, 0x7e445a147c5a, 0x8106f1d91a6f,
+    0x8af3d7af7263, 0x8b84a354b680, 0x902b7bd40aae, 0xa0089eaa2af0,
+    0xa399ff7b28ad, 0xa5d6c66b9795, 0xad170a1f7b54, 0xb21e2fd34c7b,
+    0xb57ba3d22c89, 0xbef3a62c0042, 0xc2156659fe77, 0xc4b39f4a0731,
+    0xc9190c00e949, 0xcb87eb4b47b8, 0xeb702b430fe9, 0xecff199a5df9,
+    0xf542884aca99, 0xfade97b247b7, 0xfbb8d0adc7d9, 0xff01e8f3a66a,
+    0x001b0992592b, 0x0400d28aa7dd, 0x053cf2a3cd5b, 0x072dc818c0c7,
+    0x0a7c5dc729d0, 0x11cca496a16d, 0x15e4c077e56b, 0x34f33c923904,
+    0x47c0f1787f08, 0x4949b2ba3151, 0x51e448463422, 0x53b84b565098,
+    0x546deaee359a, 0x571a8d10a9cf, 0x57c6cbc0a244, 0x66566c07e68c,
+    0x68d7d2931850, 0x6bbc45070c49, 0x6d0484bc6390, 0x746334be46a8,
+    0x79ad6023b248, 0x7a896d976092, 0x7c0d9bac9f77, 0x8cfc52b1b415,
+    0x9004e96b37a4, 0x90ee00b72ecb, 0x91761b2197aa, 0x9406d51b4bfd,
+    0x95f17d7aa3a2, 0xa7dae8d06ba8, 0xaab2516c16c4, 0xb83983dd4d78,
+    0xbb8ef64e6397, 0xd66a1e0bc49f, 0xdd93f6ff72f4, 0xe5177af3f6f1,
+    0xf8854eee03a1, 0xf8b6427cd64f, 0xfc12cc3f9f49, 0x06ac2b54f577,
+    0x0cf85cebcd1f, 0x11dc489625c4, 0x2b82678c1429, 0x2ba5dc249270,
+    0x2ed04d8d6be3, 0x39aec15b9077, 0x3f46b2b4a5bb, 0x4122b7528c20,
+    0x892b70926e6d, 0x91b8120a114d, 0xa666242bab55, 0xa91251301400,
+    0xa97ada8160ad, 0xaa06634d2017, 0xb9282e93cb1a, 0xc6365f7a15b9,
+    0xce076ed33d95, 0xce5d70e3b6ed, 0xd53774dc87b1, 0xebe65f8ea903,
+    0xee09c27b9780, 0x0d6a2297fc97, 0x12e9d4b8ae49, 0x14c479fc7210,
+    0x18ad76c71188, 0x1ab984b7b387, 0x1e2f37eef727, 0x1ea037ec194d,
+    0x1f61bc912a53, 0x2ae5ccdba00b, 0x334dd4efa7cb, 0x34bb9844b146,
+    0x362cc9fb114c, 0x3a0a1c478a85, 0x4a97d66b10db, 0x4c5e5a84d8ea,
+    0x53d07c03a66b, 0x5de11101826b, 0x64cf36244ee7, 0x6acec69299c4,
+    0x6c2ab253e139, 0x6f181091b635, 0x8d4b69747298, 0x93d7e2089f7d,
+    0x94202a93bea9, 0x95b03db62992, 0xac33ecd10873, 0xb8b61fcd881e,
+    0xb9a2a57ad5d0, 0xba85ca4036f5, 0xbd60a13d389c, 0xbf56b98bb0bb,
+    0xbf6104afb45c, 0xc2d5931bdb23, 0xc5e20d54c0c7, 0xd3408853f481,
+    0xd5af2d0f4d2b, 0xd663a7dc1554, 0xe6e492b65892, 0xebcbecc207c2,
+    0xf0e942a7088e, 0xf3417ca22bce, 0xf7b9360adb76, 0xf821bf5c2823,
+    0xfbacf3178a19, 0x0a5a4fffbc6b, 0x0b70775b242b, 0x0e531556e9fc,
+    0x0ef6dd5b0cf8, 0x1baed1a1d87a, 0x2bc81d66e61f, 0x2ffd80cb0c9d,
+    0x30d7b9c68cbf, 0x37860d0a0f88, 0x39d34c341dd8, 0x3ae8c3e27449,
+    0x3c56fc55894e, 0x3c8989cd845f, 0x4832a899a0c1, 0x4a9184c86b52,
+    0x521717c4233c, 0x52afe4f73148, 0x5eda7bfa0f38, 0x5f25f8577f2a,
+    0x76327189d839, 0x7724749fb063, 0x8d9f727fb406, 0x90eb0eead20e,
+    0x9fae26e6287b, 0xa70eab6039bb, 0xa713b3aab8a9, 0xc67de9cd15d7,
+    0xc7af843331ef, 0xc7e3367649d9, 0xc8ea0a632f0a, 0xcbfb17c88812,
+    0xd1bef465f116, 0xd1e17ec25849, 0xdcd7f739daaa, 0x0159cb49a867,
+    0x0ccb54534aca, 0x17d712bfe5bc, 0x215e2df9363f, 0x27ab49cc24fb,
+    0x2c9dd937eee0, 0x2faa18e1cebf, 0x3246f277b4db, 0x362235bcfa27,
+    0x38aa3e7bd33c, 0x414c9b069a37, 0x489938e5b584, 0x4958e9129862,
+    0x4eae8467247c, 0x4eb2a2758c56, 0x54729b939345, 0x54f44e595a98,
+    0x583405b74c9c, 0x63d60d31b623, 0x672f63222e57, 0x6b92fb60e247,
+    0x796f80358104, 0x7a87f1272276, 0x7d378c8ce1ac, 0x874aa5affd23,
+    0x8eaf4838763d, 0x9490e176cd4b, 0x98ee872eeb39, 0x9f46d861f4aa,
+    0xa00947431cc4, 0xa1b507ff41ca, 0xa5f615e18e87, 0xa7d8bcb31c3d,
+    0xa7f0c15c7a0a, 0xa8991c8d106a, 0xb0994afcdccc, 0xbc410a6ed690,
+    0xbdca063f8e9e, 0xc1ea98cca815, 0xc9e5f980fb4e, 0xd268ff81abdc,
+    0xde388c299186, 0xe3dc9d1ed893, 0xec82a299fbde, 0xf2a916021b8d,
+    0xf9d6d2752bf7, 0xfd3112a1bb3f, 0xfe051e879f9a, 0x0f220a6741aa,
+    0x0f6f95cbe589, 0x149fe80fc534, 0x14f365faff15, 0x15c6fcc2d7e6,
+    0x1c11ceff8cf0, 0x1c236c044931, 0x2afadfb8a11b, 0x34cb68482da3,
+    0x46ed539c86bc, 0x52123b7d9c54, 0x54189176ae16, 0x61948e87ca15,
+    0x6bdec84f5401, 0x6d22af673da9, 0x8625b6061a61, 0x88da1f2752c0,
+    0x8f7a43c76fd3, 0x9003f81b0115, 0x9752df905614, 0x9f56b6f07ec6,
+    0xa1de4a914c51, 0xa33fee4a2403, 0xa9e3f669a32b, 0xbf27dc7b003e,
+    0xc979682342ca, 0xc9c977ad2620, 0xcf6720fdcba1, 0xd622f337a2d1,
+    0xd63ccc592ec6, 0xde265579bff9, 0xe30fbe8ca316, 0xe8d7f3c779b9,
+    0xf3ea8ef6c1c1, 0xf4b0e1574bf0, 0x18c81d0e9913, 0x194711201b2a,
+    0x247a62339c3d, 0x25ace6d5cf69, 0x3e96fe8f3740, 0x44da09cd2820,
+    0x478dc3414f30, 0x4c124bf341f0, 0x4f924a4e8931, 0x4fdcdc6fe20f,
+    0x613edd085285, 0x61587b9ad8b5, 0x7575fca63465, 0x80afb55e5704,
+    0x80fc9115e994, 0x8a8afe2ff2b7, 0x8d1bf2b8accf, 0xbfc3f3825b13,
+    0xcfbfbd358073, 0xdec09808eca6, 0xe987b6dac4bc, 0x02cc9e601f14,
+    0x03d45c891b59, 0x216aca2a61d6, 0x2f6df769cfa0, 0x38b49116c521,
+    0x3cc470db396b, 0x3f8952361132, 0x4523c7b465ed, 0x60795056d5a8,
+    0x61e997d11e9a, 0x622a195d799c, 0x6792b110e495, 0x7c13edb213c9,
+    0x883f34620308, 0x88c196d4dbaa, 0x8fe2be8daec1, 0xba5ee03242a8,
+    0xe53a2a4036af, 0xedeb9faa7a74, 0xfaf6d74d7a12, 0x06c872fc93a9,
+    0x14f9d5168ee5, 0x1daa25b5b5d1, 0x315965783497, 0x34d0b298a09a,
+    0x3a4c46aaea72, 0x3bb2b81f3b4d, 0x4370cb19844a, 0x47038b44a4a5,
+    0x49fd091eab6a, 0x4cfaa5071a09, 0x5f94328c3c99, 0x66b88e176076,
+    0x673cff916d05, 0x6857ba194829, 0x6c5721a41d0b, 0x7aba26f9fc44,
+    0x8a5359e26af9, 0x8b89fc9305ff, 0x8d972f4ec4d7, 0x9139085961fc,
+    0x95a760da2517, 0x97e80b49f614, 0x9972a103d685, 0xb07f54c53559,
+    0xb201ae624616, 0xbb45c3e9fc20, 0xc8c445205796, 0xca0da9a0cbb6,
+    0xccd37537ba91, 0xdb513deb3cd3, 0xdf0b1b9f37c5, 0xef3ce12baec1,
+    0xf11f4d6e36b2, 0xfc6c02853830, 0x000564e3ffdc, 0x019502e85f3b,
+    0x1126e3f01550, 0x195aff31ff61, 0x2052853cb86a, 0x245386b0b5af,
+    0x2617c134440c, 0x2789dd26bb26, 0x2f96dadfcaa0, 0x2fc71ec18bff,
+    0x3aa04fb62be0, 0x3f421feb0120, 0x494411276be0, 0x4c34a33791a2,
+    0x4ea8c55b74c4, 0x4f0395276745, 0x56ccdd81caf7, 0x56da5c781f5e,
+    0x572c407a30dc, 0x59087fa71d06, 0x630aab728d8b, 0x65e164612758,
+    0x79072a5f1aa1, 0x833b6e847aad, 0x847e30d1477c, 0x8d88dc1e555f,
+    0x8dbdedbb8fe7, 0x96e9897bdc9a, 0xab36299ddcd0, 0xadf468c50d46,
+    0xbb397fc0c095, 0xcad6d0b79724, 0xcf361058dd75, 0xd1150e3a0edb,
+    0xdb936f62bc3b, 0xddf9d8014531, 0xdfdb9496bbd3, 0xdfe73f14e212,
+    0xe0d60e586976, 0xec79752cf59b, 0xfa9138b46aa7, 0xfce3f547036f,
+    0xfda48faffd61, 0xfdd2c48a8ad3, 0x00929d9ae3ac, 0x026e67a9c44c,
+    0x02933b9c6531, 0x0676bafe7a31, 0x1559d930f85a, 0x163f478c9331,
+    0x18c3323d046c, 0x1b6f5f416d17, 0x1ea9d3c5da68, 0x20572e6b27d1,
+    0x3485d75d3438, 0x3f9997579919, 0x541640cc54e9, 0x5deb21f94f10,
+    0x632166c3c4bd, 0x6ea48cd22361, 0x7608f4cb96b6, 0x9caaf7f6022a,
+    0x9d28521e5bde, 0xa88dbb8bcc78, 0xb50c4597efd3, 0xc947f8624517,
+    0xd4fa78164e06, 0xdf113a29c5cd, 0xe6f99e7f3a27, 0xee1339c84ed9,
+    0x0015597485fb, 0x0cf1ac8fe6d8, 0x117969142a5e, 0x161f57576778,
+    0x3ba78a360edc, 0x502b858b834c, 0x576093df4c56, 0x61beb441cc35,
+    0x6513ec23dc8f, 0x6a0f67598777, 0x75d9017ad71f, 0x7d0adbfc4f63,
+    0x8ef1137fc6a3, 0x982525ebe8cf, 0x997392b6dbdd, 0xa2074b803198,
+    0xa85760966b55, 0xaca32ebac73d, 0xb39487afe47f, 0xba6405f5abdd,
+    0xc79c883721d9, 0xd0c59fd22f15, 0xe162e7f33dbf, 0xe73025789350,
+    0xed65b1c02fc9, 0xf10073791a13, 0xf298c2b854b0, 0xfd3ad308861c,
+    0xfe77a2cebce9, 0xffde14430dc4, 0x0555ff64fb4c, 0x0ed6b2f9aa43,
+    0x16093d2833d6, 0x19f61d723563, 0x2f7c94172165, 0x38b7bdd4f66c,
+    0x521559b0bfe0, 0x5ce738c4a721, 0x632d02b6dd3d, 0x652230c93e48,
+    0x6b6d7823fedc, 0x6c32a5b96c32, 0x72f3803dc250, 0x7e536c42a872,
+    0x8600575640b8, 0x9fc7a14e73b2, 0xa1feb047525d, 0xa72d68a209a5,
+    0xa96dd882d4dd, 0xbb440ceab83f, 0xbd4a2854c43c, 0xc271c95dc8a9,
+    0xddc8b15a5b02, 0xe55e8200ac8f, 0xeb5fe6e72594, 0xf8ae9959cb35,
+    0xfd82bc7795c1, 0xfe5827b79cba, 0x07437c7a9430, 0x13cd3be6d240,
+    0x26af4c8619c1, 0x28a59f6397a5, 0x3249c790c4e3, 0x4be64b0fc0f6,
+    0x5d7c387a4f1b, 0x60bbb5493b5a, 0x614444d1afc3, 0x676834148ffb,
+    0x6d1237906d0a, 0x745fbfab9f6b, 0x784bb5b989e4, 0x794d0c3de49d,
+    0x7e6c369b1391, 0x85881b7a61f5, 0x892aa4321069, 0x8949fabc26d6,
+    0x94ae3f5e7a97, 0x94c559cbc150, 0x9a3b35e67aeb, 0xa7c093df8377,
+    0xaa86d4947ddc, 0xb517826ef8cc, 0xb6b51487b81c, 0xd6aad375d4b4,
+    0xdacc8ace0b04, 0xf2ad850453f8, 0xf746a3fe4dfa, 0x06a5826ffd74,
+    0x087408178f72, 0x0b6e359ea786, 0x0f242fd34063, 0x2842345ec5e9,
+    0x2b1bac01a4f2, 0x41d6b64ff80d, 0x43059201cee9, 0x63fb481a239c,
+    0x6edd9f67aa45, 0x7915175f5b17, 0x7c144d30f219, 0x9196e0567c06,
+    0x96153bf2d2ff, 0x9b43b9be8482, 0x9ea4d6adc0e0, 0xa6d5be1d5a2b,
+    0xaede9dc801cb, 0xd30024a3528f, 0xf0bba0c63fb6, 0xfb3f7f57778e,
+    0x11dabdfda8b2, 0x13392de42f9e, 0x310caeb07a92, 0x3df34cefdf10,
+    0x4509eef5a8c1, 0x4913dc338709, 0x60a9949b65d0, 0x686891d1c5e1,
+    0x6a7e75c85ff7, 0x6bdd5accf26d, 0x7367f6132945, 0x78520ed31db1,
+    0x7f7d0c91e8df, 0x8604b76a9c9b, 0x9577b70647cd, 0x975c325003ab,
+    0x9fbb140b24a3, 0xb17734c2764b, 0xc1e9f0f953d3, 0xc53d19d43040,
+    0xc72244cafd6d, 0xc8f6f7882b32, 0xd0bb37980ff6, 0xeadeeb455dd4,
+    0xeeaef92a886b, 0xf921754609c7, 0x10e8213cbb3c, 0x134aa65be21d,
+    0x1b5435b39b0c, 0x3ad5c0d244b8, 0x3f2de921d82e, 0x49eabccfaca3,
+    0x4bc895e5c130, 0x6bd40fe701e3, 0x721b39335a9d, 0x793b76b016a0,
+    0x79fd35e42d6b, 0x7ab05156d2f6, 0x80575b8f6504, 0x8553c1012700,
+    0x919746e979d1, 0x94f3d0ac42cb, 0x9f1a20bd42e6, 0xabd0f038f18f,
+    0xb89f14b0ecb6, 0xbb75931080be, 0xbcf91178ae54, 0xd969c9c104bd,
+    0xded29c03757b, 0xe5af5eb08b7b, 0xe79d00532e21, 0xeadda1ed3739,
+    0xebb1add31b94, 0xf2e97adb29da, 0xf710af9beaa2, 0xf7ca32b331b9,
+    0xfd56b41d2683, 0x0752b2d2fb41, 0x2975aab3fe6d, 0x3300344ea57b,
+    0x3353ecc8e521, 0x386dd44c8f62, 0x3cd306746bb5, 0x44fa17de0ce9,
+    0x4515c577c706, 0x508edaf127ce, 0x5fdc917c2691, 0x71f591069ea7,
+    0x74708fed2edf, 0x75ad5fb365ac, 0x790d57d78531, 0xaf9569a1f1ea,
+    0xb1825b978341, 0xb4a5b5aea9d9, 0xb7af71334a41, 0xbb3f381b1f9b,
+    0xbb6b987d7ee5, 0xbe37569b03c2, 0xdb711ff82996, 0xe0ec3eec67e4,
+    0xf93eae3dde88, 0xfbc089e71bd6, 0x0d22156180c2, 0x0f0bd384c153,
+    0x1a19db8795f7, 0x1d75407f4218, 0x3c1a839b37b5, 0x3fac1efb3b37,
+    0x4eab9a7484cc, 0x57a08aae6e94, 0x5d0be1161ec9, 0x5fdfa0c16d95,
+    0x6652efe11fd4, 0x68bbdca4e841, 0x698bca7c64c2, 0x69a3cf25c28f,
+    0x6e008aa1c969, 0x7c47e25dee85, 0x7fa3bc73a630, 0x83a0da684160,
+    0x84b26e9735bc, 0x8837afcc01b0, 0x8c512b07684c, 0x971f9bb9f902,
+    0x9fea3a98b76d, 0xb42ffdf80a8d, 0xbd1560346c01, 0xc7e236fdd454,
+    0xeb4ff2b96e3e, 0xf470ce37abc6, 0xfeb0bcdb3211, 0xffa57ea54f77,
+    0x02a947a359dd, 0x0a7bb656a457, 0x0fcb5f249a6f, 0x13cb3bcd7adb,
+    0x15b18b8f64e1, 0x25975fa06061, 0x3294ddbe05d3, 0x4aea80e1cd3d,
+    0x567ab0c874be, 0x5981387ac460, 0x5dff1ef90fcf, 0x68a6acb1cbb3,
+    0x780b088c05a5, 0x826e31390472, 0x915e93d2d13d, 0xa4323b3fad43,
+    0xb23b2076ab4a, 0xb3970c37f2bf, 0xc615d65f7246, 0xcb4ba60bdc69,
+    0xce5d288f40fb, 0xe46d5387be3f, 0xe6dd579d3987, 0xea9a6923853f,
+    0xed047ab26a85, 0xf02f9bc85547, 0xf1c8d543a6f8, 0x03f4969df828,
+    0x163528cf565f, 0x1f58fd90dee8, 0x24b575a81818, 0x301736252c62,
+    0x36f7673398ed, 0x3d2aa9e4fbb4, 0x4157217f412f, 0x47020f373552,
+    0x4f554674300b, 0x5ac3611c7be3, 0x60877848eaac, 0x725620410fa9,
+    0x73a48d0c02b7, 0x7ae50b4eec3b, 0x8b599bfdf7eb, 0xa247e3715766,
+    0xba73aa57fefd, 0xc64cd276d6f9, 0xcdd4af08c895, 0xce70b00e2767,
+    0xd3f779808bf4, 0xdfa824bc66bb, 0x03b082233cd9, 0x12060882c7ab,
+    0x27d920504078, 0x32194982cc88, 0x470608b8936a, 0x4d01b5a77c32,
+    0x5281a25733a9, 0x5f10df2bfc31, 0x639f3de3e708, 0x6af27df6a9a6,
+    0x6d5a45ef553a, 0x6e47f067bfc5, 0x711f939270a6, 0x7bbbebeb11d5,
+    0x83bf88bc34c2, 0x8dff775fbb0d, 0x910eb04ce5ed, 0xa00c91dd071f,
+    0xa990ee621266, 0xafdbc09ec770, 0xbdb5fbdd2c7b, 0xbec19d858ad5,
+    0xc09406ac7ee8, 0xcc67ebf1d231, 0xe034e8553f01, 0xec491497e787,
+    0x0624ba491bfe, 0x0914d73b3636, 0x102586ba69e5, 0x28f799ca73ef,
+    0x527bc5d299aa, 0x5833bd62d6aa, 0x62f907bc8098, 0x6ee94a489f4d,
+    0x738adfee6ec8, 0x79367d53743a, 0x7acc0dde699b, 0x843238a47b4e,
+    0x86af81214538, 0x87230543a6d5, 0xa0c540ba3325, 0xb5864f3aac0c,
+    0xbf949aa24e5a, 0xddd6d1d581c2, 0xeed2cd41e502, 0x099839ec21ef,
+    0x1065340ca9d6, 0x1780de5cf275, 0x2c19aa8973ec, 0x3adfbbc8155a,
+    0x5124f85dcd26, 0x56db5604e1c3, 0x5ef13f87d240, 0x6001e97aaf88,
+    0x61b118982b19, 0x71c3879a8ba8, 0x72261e654253, 0x78265e809e7f,
+    0x89da431b2073, 0x9dd0317f9602, 0x9e02f98696d8, 0xa2974ac517b1,
+    0xa6e1b98f50fb, 0xac0a0a4566b7, 0xae4b29d3433e, 0xb1b657577d78,
+    0xb6d45ce98f93, 0xb7c32c2d16f7, 0xcd675f72f103, 0xe63eefeb8585,
+    0xefe736271a9d, 0x1a7e1b29518d, 0x1b5ce7514513, 0x1daa610a5928,
+    0x1ecfdbd44377, 0x1fc9e077e590, 0x21ad71858a5a, 0x2dbe69f5e21a,
+    0x2feed6bb1974, 0x346e1c938781, 0x385c96c6b171, 0x4224338c5cf6,
+    0x471d2a9cc867, 0x4f9bd8000b56, 0x51e56e39bd56, 0x5b3777d5d351,
+    0x5dfa4a29772b, 0x7838118756c3, 0x91a416958bb2, 0x928cf3527d14,
+    0x94737da36cdf, 0x9b6fd1699f11, 0xa280bb77d885, 0xab8a076ac3ca,
+    0xade191b8d5bb, 0xae4f2354a156, 0xb047101b479d, 0xbe7abbcb7c8b,
+    0xc206d9c2f595, 0xc822c7780bde, 0xd137bde91d62, 0xd3d1d8cabe42,
+    0xde74d35706c2, 0xebf9bc3203c4, 0xee1f68b52bf3, 0xf16ee89fac10,
+    0xf34b625b9dff, 0xf45ecb02c083, 0xf6128d4caf78, 0xffdfe209eb3a,
+    0x09bb9ff99277, 0x0a97e7fc4686, 0x0eb99f547cd6, 0x17ced054941f,
+    0x1c7d6fd2ac77, 0x350ae347f3a5, 0x38a320db9e78, 0x505362121a83,
+    0x553b6bcadb02, 0x590636d680e6, 0x5b27502d35b1, 0x5cb7634fa09a,
+    0x5d9be76f245d, 0x665683324eea, 0x68d983a6a911, 0x71954434f077,
+    0x7a25540de9e2, 0x8c523a3357eb, 0x8e89be4a4220, 0x8ed833eafd13,
+    0x95ccc0b26b1b, 0xa2129030f79e, 0xb66a65b31289, 0xbd36007977d2,
+    0xc26e544b216c, 0xca75d49ba66e, 0xd5535e2db3ee, 0xe8d727c9ea7e,
+    0xed9fdaf89490, 0xedd2dd8e9b2b, 0xf37ce10a783a, 0xfccd50bd65d2,
+    0x04b53ff4cea2, 0x19d452138ad0, 0x2d8e8ca71e86, 0x40acc635536a,
+    0x49608535d0e1, 0x5e2c1969532e, 0x685095022521, 0x755355f94f46,
+    0x86d437fdca9f, 0x9c28212abb90, 0xaa84bedbf93d, 0xc1b7e0792159,
+    0xc291dee59bb6, 0xefdddcb61c54, 0xf6a9ec9a8d27, 0xfeb082aefb15,
+    0x010fd3fbd130, 0x23a68a8e3bbe, 0x289dad267907, 0x359cffbc4ca1,
+    0x408006b6e499, 0x46ca63d58e19, 0x540a37f7bcb5, 0x55f814296520,
+    0x614d055d3652, 0x616ea57d8671, 0x64df15db455e, 0x6a75a7da3804,
+    0x7176c95be35f, 0x82c7a20da31e, 0x9d1c0ebabde5, 0xa3c2d58e8249,
+    0xb03e66575aa3, 0xc6920c1f7dea, 0xcd95ec556e81, 0xd30bc870281c,
+    0xd379cf29ff41, 0xef6b1e42c809, 0xf2667094fcf6, 0x0768eadde7f3,
+    0x175c78743d9f, 0x19c59fc70bd1, 0x1b928b85756c, 0x1b99a2d72847,
+    0x33885692cb67, 0x34caa3c18cac, 0x3e45d9edb12b, 0x462d8e961436,
+    0x55149047f474, 0x625932259c39, 0x67a06447bcd8, 0x68e77f31f746,
+    0x6dc4538a9d10, 0x6fd0d6994a99, 0x73cbe586b1dc, 0x7826cc8a8a8e,
+    0x7b40c5b9c499, 0x8d5e1de1aa4e, 0x9520fe976c74, 0x98e1f39d1a41,
+    0xabe35ac6782f, 0xb50dd1bba809, 0xbb152928b710, 0xc12865a2f21b,
+    0xcce23737b3aa, 0xe92e561c6ef3, 0xe96994cf4542, 0xf922935fd5ee,
+    0xfd75edf3f03b, 0x0e25f7e4d7ff, 0x1433f1858e57, 0x243ab609c8a7,
+    0x367137a62902, 0x3a025de820fa, 0x3b617d7bb935, 0x3bd242e9d596,
+    0x404c8077c4b5, 0x55e1d56d27bc, 0x8fce7288275e, 0xa69bc9884809,
+    0xaaba127f27ce, 0xac46f1cf41f1, 0xadf39cc77e0b, 0xb1dc5f0317be,
+    0xb28b5c67556f, 0xebbe3bdc0835, 0xf457e72bf3f2, 0xf47f04b4ce89,
+    0xf8e93f2729ca, 0x2705655e2cae, 0x2ad4fe254bbb, 0x2b2ea9262163,
+    0x2b3b3de05eb6, 0x2ba38ca2a59e, 0x3e3e0463df42, 0x4c831289caac,
+    0x511bbc65b924, 0x5fc2771a4425, 0x600d093b9d03, 0x806ceb642eab,
+    0x80b39a062574, 0x926fbabd771c, 0x972696585f28, 0x977496db0e91,
+    0xa5fb4b5871d6, 0xaf4c30296af8, 0xb923957ba496, 0xbcb2722762dc,
+    0xc374e694e15d, 0xc54c1d774e99, 0xd4489fad4d2d, 0xdd79b8d62458,
+    0xe76c9133124e, 0xf5c0088b6933, 0x0340d357fe5b, 0x1755a3287ecd,
+    0x1d27394b41fd, 0x2ba27dd984c8, 0x36d6f3b822b4, 0x3b178c7c63e7,
+    0x3c4f8e87218b, 0x46cd0573b7d7, 0x471b40856d05, 0x4c77437e9aab,
+    0x5be5afedd279, 0x69f278a43295, 0x72d0fe1de6f3, 0xa34732c83b3f,
+    0xa37e18dda3ef, 0xa4c708400c85, 0xa724fa32c002, 0xa9343bf5b2c7,
+    0xad5543a0d7c8, 0xb6051f21f32a, 0xca23c4f86bb3, 0xceb6f16bcfb3,
+    0xe703e354bbdf, 0xf4137395291c, 0x10e4ee300808, 0x141a94f8fc30,
+    0x1c65558a2170, 0x1f2e1a645b4c, 0x20a070e5d82b, 0x25fd5e1b1ce5,
+    0x2f2c2dadba5e, 0x2f743ba9d3c5, 0x3a4a735b28a5, 0x3d46afe974a6,
+    0x62d92dec1fab, 0x6be3d9392d8e, 0x8a055a8827eb, 0x93eff6c8462d,
+    0xaced456f92a8, 0xae58f9bd6836, 0xafc8cc19a59e, 0xbfa7c367f408,
+    0xcb16189f45a5, 0xda77b5c53a5b, 0xe843c26039b0, 0xeaa60cf05acc,
+    0x14df637259ff, 0x1726eaa4d812, 0x1f9ad7c60bd6, 0x22eb075d9d42,
+    0x238324e399ff, 0x2548bec14afa, 0x2864174aa7a3, 0x321ac6b8a836,
+    0x32c1fd1e21bd, 0x42d88a2eea26, 0x47ebcf7eed16, 0x57164c007357,
+    0x5f0259464401, 0x85017ea8a38d, 0x86b7c517d1f9, 0x92a24fac6071,
+    0x9319f1dd29e8, 0x969905fc5a15, 0x99de7551dc56, 0xa0a1d3fb71eb,
+    0xa0e4d9ad0c64, 0xabf5daf32c09, 0xb995872bcc14, 0xba08d0bf27ec,
+    0xca2515c78092, 0xd3642304b7ae, 0xd8279359dd0d, 0xe47b56ecc981,
+    0xeddf72aba747, 0xf6fca539887f, 0xf7505db3c825, 0x00124b57ab52,
+    0x12208aa0143d, 0x22884c05dcd5, 0x248be34aa95b, 0x29958d23b9f9,
+    0x3b2eae6098e4, 0x3cb8946d6806, 0x4452f8402cf7, 0x4c0859ff9ab6,
+    0x4d7657e3a9f6, 0x505b3f75a979, 0x52bb406f90e3, 0x59aa4fce7473,
+    0x61d076fbfe93, 0x710e64fa6f3d, 0x714ac8786265, 0x71e5df41aa23,
+    0x720117bd58b6, 0x793f4c6a0888, 0x82a835e45f77, 0xa8b674263bcd,
+    0xa9c6a8fb0d8b, 0xaf42027e519e, 0xafcf9a5144f5, 0xb9228e297204,
+    0xc1a3fa40fa2f, 0xc3c96c351c99, 0xc9d72b46cd2c, 0xcf9c2caf5309,
+    0xd08ea4e336bd, 0xe9a6f1772c06, 0xf80fe953a141, 0xfc25bb9eab8d,
+    0xfe8a8a540c20, 0x098b887e97e7, 0x1b24e44a7c97, 0x2b3ea52d95c6,
+    0x359782b690f2, 0x3d296fdd806a, 0x40e437cd9270, 0x42fa1bc42c86,
+    0x508a748e4a02, 0x5592bf0d3802, 0x62a0eff382a1, 0x63798f05da60,
+    0x6d18745982eb, 0x7652042e2f8f, 0xa0dd3eb24040, 0xac87f7678505,
+    0xb9e67266b8bf, 0xbd0d0050301d, 0xcb90466c3cd7, 0xd1800e4df99b,
+    0xdae51448ee75, 0x31882e122435, 0x349f2dfe133f, 0x3a9229b220c9,
+    0x45789f0e0f4c, 0x4d567db07a40, 0x5a6d5fd1a01d, 0x673e086edabb,
+    0x714bdeb8717f, 0x7f5782a3b4c2, 0x813ebca1b5dc, 0x9135437b5689,
+    0x944775abcc6a, 0x978ce5014eab, 0x9d8b161576ea, 0xa0e6407e1d46,
+    0xa2d95f894a64, 0xb0fd42acf139, 0xbc129c907e7d, 0xc8803944f6e6,
+    0xd543285cd758, 0xe406b576394f, 0xee2d40163f2f, 0xf00e878daa47,
+    0x0d6f6e73aab2, 0x18dc6450d9b1, 0x1fa1d201a333, 0x2d49f4e618b7,
+    0x42116b0b332a, 0x4a90c81b8768, 0x4afab0c6f6b3, 0x51b68300cde3,
+    0x5eb4011e7355, 0x630c63fd0c90, 0x842469e2c2b1, 0x8459b60f02fe,
+    0x85be530b25b1, 0x874121c641f8, 0x87457a63af97, 0x87e2a0342b42,
+    0x890b14416092, 0x9eabd925e413, 0x9fe4fffbbe90, 0xa1345702c8b2,
+    0xb0fe085bfe8b, 0xc23d7e9807ce, 0xc40a6a567169, 0xceae4f1ed0fd,
+    0xd28e259089ad, 0xe8b40b9c2b0c, 0xffa75b5a0975, 0x0cb6b10b70ed,
+    0x0f659cc41ed4, 0x170c95512118, 0x347ccfa5a412, 0x39f68f3fbfc2,
+    0x491adeabaa3c, 0x55fa2b0a561a, 0x5b5160480a97, 0x66bc471e05a9,
+    0x67efb5fc4fe9, 0x6bec5ed2df8f, 0x6f6b72f20fbc, 0x7029c3c4cffc,
+    0x7c88bcb7d725, 0x7f43c80cb6d5, 0x86f7ca7201f6, 0x914cc47b9b0d,
+    0xa92d0f04d2b2, 0xb59ae64850e0, 0xebb2e251b287, 0xf57576ccdf1e,
+    0x2df749d62046, 0x44653ddddb0c, 0x44b0ba3b4afe, 0x598af2303e8b,
+    0x5d881024d9bb, 0x66d1dda42002, 0x6b41d00e0b80, 0x6cc1a585dcc6,
+    0x704c64233332, 0x707814d8812d, 0x8002de8e8467, 0x9a3ab365cdfd,
+    0x9be5ff03e779, 0x9d94f3925d45, 0xa73747475c5b, 0xaa8f3dddb1f1,
+    0xc736dc3b710a, 0xc979d0417bb9, 0xcd6eeca84cfa, 0xcf1f7b1feb29,
+    0xcfe2d43d2a57, 0xef9259a75bb0, 0x02fece4745c2, 0x0b8751b080c8,
+    0x17170c791cbf, 0x3135082eda60, 0x33a03e88dc7f, 0x378fdd872348,
+    0x3bb871a206ae, 0x43fbe052734e, 0x46b1e35cd410, 0x4cc22693c41a,
+    0x4ed3b1ecf091, 0x6304df043c6f, 0x64c1c7a7122c, 0x7abc721b711a,
+    0x8191333abd2b, 0x8335678723cc, 0x860125a4a8a9, 0x879556d57b6c,
+    0x97656fd352d1, 0x9d523e71c494, 0xa82a100c41d7, 0xb0d2d43baa5e,
+    0xb3e456bf0ef0, 0xb45f2cc2292d, 0xb9e99f24ea0a, 0xbf25d675f5b9,
+    0xd1e905a93257, 0xd3b3e2606805, 0xda1fdf9f61a4, 0xec8be7f70811,
+    0xfecb1ace43aa, 0x19f910c9cd44, 0x1c88e0876a83, 0x2d36665312d0,
+    0x3aec42bce280, 0x4b9694b63a07, 0x4d42ca906a97, 0x4d99b6dcfb03,
+    0x62700b528c7b, 0x69f2df99ff29, 0x6a8669f38882, 0x6ad17132ecea,
+    0x72635e59dc62, 0x7fcdbe663c20, 0x8660d92e1056, 0x8955142e9268,
+    0x953c3061ca55, 0xb451893ecb04, 0xb5962003c5fb, 0xbdb5df8cae8f,
+    0xc0edcfebdc69, 0xcf41bc623ed8, 0xd3044b511508, 0xd4eeb92166e8,
+    0xd64b1a00b9e7, 0xf6dc9f652f8c, 0xf8460a1ccb68, 0x0b774009df2b,
+    0x0eec7e231741, 0x13f5ed6d221a, 0x178a47816ad8, 0x292db3e24d64,
+    0x35faee1e3177, 0x360a418cb406, 0x3c81742bc85a, 0x469e6354dbe8,
+    0x5f3b2a38a5a5, 0x730f035ebf8b, 0x84ecfec56717, 0x99a88fdd5586,
+    0xa3c5b9956ed9, 0xa866da1d32ca, 0xbc52083f992e, 0xc3dd5332e155,
+    0xcfde4887a537, 0xd6ece8ffa4f9, 0xdc2aba39d90b, 0xe4720c69a7c0,
+    0xeb792071e91d, 0xebb6a8baf91e, 0xfc48bb7bed13, 0x0ff661554376,
+    0x1ac50c96d9f1, 0x2b9bbef290c2, 0x38a4acff56ae, 0x3dedb399a575,
+    0x3e92a068e54a, 0x484de3035941, 0x4da30939d9d1, 0x51caeda7abe8,
+    0x51e7105f718f, 0x5f21dc37213d, 0x6b5a2cbf5959, 0x719fbc2289b0,
+    0x7286fef652af, 0x7585bfa9de27, 0x92bbe016a7ab, 0x97eb0d8f6a7d,
+    0x9f948a41ac38, 0xa399e453171c, 0xa5739f5ac3cf, 0xb01415c1ccd8,
+    0xbd68802c02b6, 0xc94f9c5f3aa3, 0xc9d2ae7f2494, 0xcb26d341a7df,
+    0xdaf97064bebb, 0xfbe3b68df2f4, 0x0c28035b3d45, 0x1104d7b3e30f,
+    0x397e11c0bbbf, 0x4fb47005fc86, 0x63d858b5f9c2, 0x7a4403277ad6,
+    0x8eb9202c7841, 0x904c2c922e2b, 0x923d02072197, 0xa065ed75475a,
+    0xa684d46da8a4, 0xad2742a3ff69, 0xb03d1dc4d19a, 0xb086508c07da,
+    0xb3474e677d8c, 0xb70f954de3f9, 0xbc8c88ba506f, 0xbcbf50c15145,
+    0xccb90b6d42b8, 0xd143c134d13f, 0x0a5f863c3d4c, 0x0ab3b3d4887c,
+    0x14d0a2fd9c0a, 0x1a9e1b11f760, 0x2d8d358987be, 0x31ed24d7df5e,
+    0x35071e071969, 0x39750169d0fa, 0x50b5dc8c5342, 0x6397ed2b9ac3,
+    0x642a182b017e, 0x6bcb934f794a, 0x87fb8f7c6eec, 0x9ffa0bc4a025,
+    0xb5086b1ab726, 0xb7c301518b4c, 0xb847383c9216, 0xc8af6ec06638,
+    0xcb8836b633f2, 0xcd710a9d5d6f, 0xd9c57ddd5b32, 0xdacd3c065777,
+    0xddb1395c3fe6, 0xe74b50f46f48, 0xedbf4fc132d6, 0x077362cb812c,
+    0x166315b83ca8, 0x1a6a7ed0db79, 0x4499c4bddcd0, 0x56c720015663,
+    0x56dd15a38043, 0x5b8d147bbb39, 0x5ee963af7e6e, 0x62040c8bc9c8,
+    0x6850b340acfa, 0x7d8127d51fa4, 0x8044a9d5d4cd, 0x81df7d3a4ee1,
+    0x81e918b14133, 0x83a67672227a, 0x8d08bdb8d218, 0x927ed4629178,
+    0x94effd432999, 0x9f32357ce996, 0xb2566191b47c, 0xbd22fdcc170a,
+    0xc184fc21a297, 0xc5908348a942, 0xde7607d59db5, 0xe8ee7677b513,
+    0xeb6139417597, 0xed6f56394b83, 0xeea583cbdaff, 0xf03e0d9a1b61,
+    0xf6aa45681ac5, 0xf8d2eb2e8df5, 0x0eab45d58b75, 0x1c5d3ebff910,
+    0x243181eb71b2, 0x2818351fd778, 0x36a42c76bf70, 0x37a50ddd0e9f,
+    0x46cc568c441a, 0x4c5b218c7296, 0x54158b965f43, 0x6323e5601fdd,
+    0x6b99a6f981c9, 0x752b8274e177, 0x7fe97aedd2c5, 0x82e8eb4e6f8c,
+    0xa9da13c6a742, 0xacf44784e712, 0xc6a85a8f3568, 0xd6942126c6ea,
+    0xd907ce2c9e82, 0xfb70c5028728, 0xff6113addf40, 0x0596da84817e,
+    0x0b168ca53330, 0x1bb9522ecc52, 0x1e52bd635be3, 0x24441f2e410a,
+    0x246f5ac5837b, 0x31399bbe1c8d, 0x3761e39e6a64, 0x3a143db86ed6,
+    0x4051cb8dd53e, 0x41e6374dadc6, 0x4359780b41b9, 0x4471e8fce32b,
+    0x47d0bc55e5d7, 0x53e13fa8320d, 0x53e17a3737d2, 0x58a5251b62f6,
+    0x5b971685ab56, 0x71b86964d951, 0x7da1949f452b, 0x9128920dcc81,
+    0xa3966edda116, 0xb27ff4b4c0cb, 0xb34c742ae6c1, 0xbdbdcb7b4b44,
+    0xc3dec17ae07b, 0xd073f0d63f05, 0xda815201ca3f, 0xf382f9468459,
+    0xf8f1be0f8b19, 0x18bd2ba27c54, 0x1f6e3d9a4459, 0x28819a222d7a,
+    0x39199f69b771, 0x456aded7646e, 0x45cfbf3854cb, 0x4f49d0995c71,
+    0x577d76bd3af8, 0x5948c8927c30, 0x59d27ce60d72, 0x6b310f1d275d,
+    0x72865e371de8, 0x870e3d0bf46d, 0xafbc4e2701e0, 0xb4941a3528bc,
+    0xbac6e7c87ff9, 0xc81ec0940c62, 0xd0bfbdc4b0bf, 0xd3c1b24a8cfd,
+    0xed6378a30dc3, 0xed69a5b8a98a, 0xf72a65bba7f9, 0xfad85e6276e7,
+    0x00bb9189f658, 0x0e48b681c30e, 0x2ddfc2248b10, 0x4428328c93a2,
+    0x5063f1762249, 0x531944d371bc, 0x53ac1f7fe9c6, 0x5e8a58bf0895,
+    0x74047538bd09, 0x77fa7bdba55e, 0x7fccafffea13, 0x89d9d69c6f88,
+    0x89e1630c2ded, 0x98421ecbd374, 0x9bf3c062feb2, 0xc70ea1c136a7,
+    0xca4189d5e593, 0xe4aa8ccb079c, 0xe84acbec7c5e, 0xf2f1e4872cb8,
+    0x15f492cc3a7e, 0x1bd2f83840c6, 0x34d1311ba455, 0x35c83c7bfb6d,
+    0x45c356820f7e, 0x45db5b2b6d4b, 0x68d592c4a598, 0x987f0d69ce29,
+    0x9af8726735fe, 0x9d453c7338c4, 0xa8655698d533, 0xbd700cfe8fe4,
+    0xd071e945f95c, 0xe78c1bfdac97, 0xe899578f3354, 0xe8f586b54873,
+    0xfec779b7a467, 0x1c45a8208752, 0x1c68327cee85, 0x207fd94026f9,
+    0x20fecd51a910, 0x211d399fa869, 0x2b3bfd40ea1f, 0x2cb18794b7c4,
+    0x2cb40bb9f73b, 0x33530b8ef775, 0x33901eb9fbec, 0x3987e829829f,
+    0x3e953af2ef8d, 0x4273777b7fda, 0x486a56aeef79, 0x4da89d072f15,
+    0x592c38339943, 0x6ad4e76e0082, 0x727c55190e50, 0x7c43f1deb9d5,
+    0x8effcf313dd3, 0xa1cac5633e9b, 0xaa5eb8bb9a1b, 0xb240b56c6ce9,
+    0xc33aa1d19c3c, 0xcabf852042d7, 0xd1e197152d02, 0xd52238af361a,
+    0xe8ecb0ed6373, 0xefcd1c8ad5c3, 0xf44e716a77bd, 0xfa7e804989be,
+    0xfe22a2ea6095, 0x0d121b48164c, 0x143726804b78, 0x1cef3e1e368e,
+    0x2ec70c6f4253, 0x36183d7ad104, 0x3caddc67e4b1, 0x5409b5770fc7,
+    0x66ea2c2d2ee5, 0x6eeeedc96eab, 0x7226a39996c0, 0x7bf4e292e996,
+    0x8526ab68d210, 0x943121b33095, 0xaf5248657717, 0xb720d3995f7c,
+    0xcab16c7ed924, 0xd50fc7705ec8, 0xdfcf1f4372b4, 0xedbf8ab30764,
+    0xf6c408ea7980, 0xfb2e435cd4c1, 0x06e1e7dbfa89, 0x078b2d48a7fd,
+    0x137baa63cc77, 0x1929915f0b9b, 0x27569adb9938, 0x423aae62db43,
+    0x4768f19f8701, 0x482cfa69d77e, 0x59a31c2c43ac, 0x7e290e4a7943,
+    0x85fe01230334, 0x8bb3af1d0682, 0xa33cd2caa7f6, 0xbe75fe264c45,
+    0xc255d49804f5, 0xc6b723407f33, 0xd2215a6968f6, 0xdf3df4821f10,
+    0xdfbbfe578a13, 0xf4e9eec6bd46, 0x06c984168d35, 0x08949b5cc8a8,
+    0x1836ba0f1860, 0x19cc85291386, 0x1a0923360c73, 0x226c22ff9545,
+    0x23d468ec1448, 0x2b64819ad598, 0x2f5afd5bc977, 0x309d0ffb84f7,
+    0x4a8b025e86fe, 0x5a37a6c3e01c, 0x66fa5b4cbac9, 0x68a4bcaebd31,
+    0x69d14eca5a5b, 0x7eb10427d860, 0x96af0b51fe0f, 0x9832c449316a,
+    0x9f07bff78340, 0xa0787c8fd7bc, 0xa962ac87b259, 0xaabfbd1416a7,
+    0xafa6a201ba4d, 0xb4996bfc89f7, 0xbd59fa464a86, 0xbe208735da7a,
+    0xc1b088acb599, 0xc372047bfeba, 0xc537d8e8b57a, 0xd0c375a2e997,
+    0xe1278e1855df, 0xe288f74227cc, 0xfd8808b612a5, 0xfdc297bbd7a5,
+    0xff452be7ee27, 0x27f795a06939, 0x308d5d70f2e1, 0x41d0420e52af,
+    0x4252a4812b51, 0x51480f65770a, 0x58103bca85c8, 0x61971c74d086,
+    0x6dca2a2383ef, 0x833ddef896d7, 0xa2d9f2e5ddc7, 0xa40b8d4bf9df,
+    0xb9540689ca56, 0xbb7b12671523, 0xc12a1e2d7120, 0xcb194d9a02c6,
+    0xcd0a5d9dfbf7, 0xd2666097299d, 0xd790c0547346, 0xd807784925a9,
+    0x13666be5a22a, 0x261ccbcf9bb0, 0x27c25f7624ef, 0x51cc5c5279d7,
+    0x5cde0d45aacb, 0x6b6954ef8174, 0x70af9cd58aff, 0x9ffd22e3a436,
+    0xa1feab213ccf, 0xa86231b460f5, 0xa9931c6d6bbe, 0xa9a02645b49b,
+    0xaf552492a69a, 0xb7da742990da, 0xb8492a90794e, 0xc1c8443bffe2,
+    0xc2e3ae70ec55, 0xc4e327a75101, 0xcb5b7f11822e, 0xcd28a55ef18e,
+    0xf74f3a1117a7, 0x15a900cf9d52, 0x17fa23790db7, 0x2d037a84a5ca,
+    0x3245deeb4d40, 0x3c8349699414, 0x3f49ff3c9a03, 0x448ead397b2b,
+    0x48c95377265c, 0x4c7577a5c722, 0x4ef0ebaa62e4, 0x5ba9550f39f0,
+    0x6a8b4e769b40, 0x723789dd2237, 0x764b4d20f896, 0x76e2baf9e404,
+    0x957d0344c4b1, 0x9939651dff1a, 0xa658835bf4ab, 0xbdbac40fc14d,
+    0xc76762e8c404, 0xc9011182213f, 0xcac54c05af9c, 0xd6abf31adbff,
+    0xe3153731e6c9, 0xed9aafac4704, 0xf6bb160c7902, 0x17c318d69b45,
+    0x1c847a248cb7, 0x336f533695a7, 0x39aa5ce6bc98, 0x52f2783e67b6,
+    0x629afe9558fa, 0x6bb9907d5cd0, 0x6cc61c61d23e, 0x79a777c7b209,
+    0x8e5c2c1cf362, 0xad1c32968c08, 0xb0d685689284, 0xb18f1e43c287,
+    0xb1d1e966573b, 0xba1224447315, 0xc2242a48017d, 0xcf76c03a0933,
+    0xd4e968827208, 0xe086dcd0682b, 0xf990fac0f7be, 0x00eb17966772,
+    0x05bae216c45f, 0x070cf7d213bd, 0x14f17e347c69, 0x26625d1d63e4,
+    0x440cebe8a619, 0x45490c01cb97, 0x45ab683d7c7d, 0x59e87a61f45f,
+    0x60cbdf42b1b0, 0x649c9cd4ed96, 0x6623899e71b7, 0x6f02bec53764,
+    0x7a77b6303052, 0x7b4b4cf80923, 0xac341b88a7f8, 0xaeda1b7774dc,
+    0xb43e1ffe6c71, 0xc1a80aecc0a5, 0xc62875904b8b, 0xe7a8ca384894,
+    0xee78487e0ff2, 0xf2d7fd3d61cd, 0xf68a8910a41f, 0x08e444b67cfc,
+    0x12ff5f676262, 0x21ded4a9843b, 0x317beb115505, 0x4c04f43d9eca,
+    0x4d96dbd837db, 0x5626ebb13146, 0x616a05514072, 0x6fe50f507d78,
+    0x76fb76c74164, 0x79c7e491d790, 0x7b8be4866028, 0x861f8ba42619,
+    0x8bde9a8615f4, 0x928c3e1c876e, 0x941ed56431ce, 0x9c5156bcf37c,
+    0xb9263fb928f3, 0xc8830f23a480, 0xcc1051e63a63, 0xe34289474b6b,
+    0xe7d57b2ba9a6, 0xf0e9fc7eafa0, 0xf86edfcd563b, 0x1797350935c9,
+    0x18b29f3e223c, 0x1cbf10a13ffb, 0x21fd1c6a79d2, 0x234e47e9b21c,
+    0x5928811afdfd, 0x5bb592245600, 0xa1b82ffa2c50, 0xa8ab5d6777ba,
+    0xa9bfead9b717, 0xbb2f6a687bf4, 0xc4d9851c3f34, 0xd6d95b323ca4,
+    0x0818a0f1605b, 0x08bcde138ee1, 0x0f14ba288cc8, 0x2047d6395e7d,
+    0x2e02f57cb2e0, 0x56cc3f136ee6, 0x593566663d18, 0x7c07d0c9897f,
+    0x8f5404a34610, 0x9b665c6dc06e, 0xa812a63665b1, 0xaaa8687a98f2,
+    0xade8cf859c45, 0xaf77f86bf01a, 0xbe97b4ab6730, 0xbf9e4e09469c,
+    0xc01715052cec, 0xce14ff6b1603, 0xf12c7e8730d0, 0xf3d577b948b5,
+    0x06cd08dcae8c, 0x0ca57bc21ed2, 0x3675d3d4c5ce, 0x3c4f30f64d28,
+    0x47c0f48ef550, 0x56b9934591cf, 0x59b903a62e96, 0x5a8214bafe01,
+    0x5b69578ec700, 0x84f66f60cdbe, 0x85abd469acfb, 0x9cb3f4fe986b,
+    0xa408946b7da7, 0xa8a8902824bf, 0xa9a4de62008a, 0xb645f2ca8b18,
+    0xb8f294ecff4d, 0xbc66ae3b1a8a, 0xc27a9a6266e4, 0xcc42ac461df3,
+    0xd72996c01800, 0xef455a8b2bb9, 0xf671f2331f4a, 0x0084211a23ad,
+    0x084a35a23699, 0x09108802c0c8, 0x149ac562d247, 0x18cae5ed7412,
+    0x43e3f2d37ddf, 0x4f4abb9b1117, 0x5244ae932366, 0x64d3f0f44255,
+    0x78482c92f091, 0x7b4a2118cccf, 0x7d0c1206217a, 0x85465a5da752,
+    0x9166e0cb8766, 0x9ab666425dea, 0x9eb7dcd466b9, 0xa481e6876b84,
+    0xab10e340d7e0, 0xb8af301f554d, 0xbd660bba3d59, 0xbe15b8cb8c59,
+    0xbeb1f45ff0f0, 0xc649d40d766a, 0xca1b414cc39f, 0xcfdbea17dbdd,
+    0xe75afd307f75, 0xeca59db3f69f, 0xf53a40b9636e, 0x09abef5d0a4e,
+    0x0ab3e8150c58, 0x0ca532a80b4e, 0x14be8a8c5256, 0x199c0e92096f,
+    0x3b2673cf0454, 0x4fa6c6341c74, 0x632dc3a2a3ca, 0x6372d85b7230,
+    0x663a786a8f33, 0x6b0b67b608f9, 0x76a7f1c7e808, 0x96e04149938f,
+    0x9de75551d4ec, 0xb874f1aa9215, 0xc275be1bdffc, 0xd0d25bcd1da9,
+    0xd4a59d849906, 0xf0d60ecf9a32, 0xf77fcee6a997, 0x140900f66957,
+    0x1748b8545b5b, 0x1a8c8dc0b539, 0x1aa5b7352fdf, 0x2259b99a7b00,
+    0x37952900029a, 0x3c970bda4f0e, 0x7ad6194d51e9, 0x9e2c457d9990,
+    0xa23a8b58e577, 0xa2f95149b141, 0xb078bcbc23cb, 0xb589f304f2ce,
+    0xbd858e484bcc, 0xc2221ba39c59, 0xc787ba13bc51, 0xcb0200777355,
+    0xce7e55e25e46, 0xcfd2b533e756, 0xd2f76ea5308c, 0xf46af403ea7d,
+    0xfa43dc07664d, 0x088247f9aa66, 0x124acefb6cff, 0x15a30020c85a,
+    0x38a44f0bb382, 0x3d11f7df654e, 0x468b9422616a, 0x4691fbc702f6,
+    0x6c81589cd469, 0x6ce6e8aad615, 0x769e47c5e7f7, 0x76ef7c1ae826,
+    0x786331f687a3, 0x79eeb1ec7f28, 0x7a5aa99f2260, 0x7acdb8a37873,
+    0x7ceb2909d0ee, 0x7dc7e62a9087, 0x84c1f05a8907, 0x946aebcf85d5,
+    0x992c87ac7d0c, 0xa0b3b4915d59, 0xb7717d93f5b0, 0xbbc25402d086,
+    0xc50fca72731d, 0xe1fd67c517b0, 0x1af0755a80c3, 0x399be58c1227,
+    0x3bc86ed1e76c, 0x4b141655b242, 0x4b7beff9eda0, 0x53277bb36348,
+    0x8690c6931ccd, 0xaf4788e9057e, 0xb97cb74a7c9e, 0xddf7ae979d45,
+    0xe3fbd2325b86, 0xe6e930703082, 0x00bc9a049545, 0x119b887d1bca,
+    0x20f474683542, 0x28e848acca16, 0x2cb55d4ea9ac, 0x5b599df0156f,
+    0x5e0137c80ab6, 0x6a15640ab33c, 0x6e9016b6ade5, 0x8507a6355afd,
+    0x9928d03112fd, 0xa6264e4eb86f, 0xcca342f77d39, 0xced8085a2232,
+    0xd243e58b6dbb, 0xdb80e3c170ea, 0xeeaa8d3ec648, 0x12200ff9245c,
+    0x2fd2a0523080, 0x45936b6ddbbd, 0x565a8fcc0a3a, 0x5fe469b99ff9,
+    0x63e40bd37aa0, 0x6be1f0ad0d50, 0x6e1a5f000e99, 0x75b7bc161e8b,
+    0x830eaaa593e0, 0x8af3a099b1af, 0x90d574670e82, 0xa9eb3cd5c454,
+    0xbebab488a8b6, 0xbecfbfeebb82, 0xc1af9f363c17, 0xc9a3ae09d6b0,
+    0xd4319d300c64, 0xe32c105ed70b, 0xf35dd5eb4e07, 0x1b7cb54a3fc0,
+    0x1fdcdf279d25, 0x22c1c6b99ca8, 0x367e1054644b, 0x3b025e775146,
+    0x469c29d4eb19, 0x56a70c678d43, 0x6e211735b1ed, 0x83cb02731c36,
+    0x909493bea3f9, 0x9ab3575fe5af, 0x9fcaf54d563e, 0xbb0d0c72db90,
+    0xbe4e5db9f5f7, 0xbf6de5fd4a44, 0xe03d2e39d5af, 0xe55a498fd0b6,
+    0xe9a52d78158a, 0x018a45bcc658, 0x02b69d495dbd, 0x0f78dcb42ce0,
+    0x3213b154ff48, 0x3b92cb0085dc, 0x5b5a55141502, 0x79322e7dcd95,
+    0x8adb18473a99, 0x8f989615c9f6, 0xafb62239d274, 0xbce8b1f4b26e,
+    0xbd08087ec8db, 0xbfca2b255b66, 0xcf09b30cf473, 0xf8481aaf3a79,
+    0x29f52c992f90, 0x327bdb8a3c6e, 0x3cb8965b71f3, 0x40051d02a70f,
+    0x40c074921c4e, 0x52b8be385b59, 0x54735d44f764, 0x574a8b519cbb,
+    0x5ce575edfd00, 0x5f4a0a1457ce, 0x83bcffd3ae86, 0x9d8cc077b6f9,
+    0xa2077323b1a2, 0xa7984d2b140b, 0xab43871d9dbd, 0xc2bf2bd4eaca,
+    0xdf8aee783979, 0xf3694d515cc5, 0xf6a81a7337b5, 0xfabd3d1130b2,
+    0x0a4c5f64a18b, 0x1d81edef22ed, 0x1f52f7bbf462, 0x5a9c6ad4528d,
+    0x76aeaa605a25, 0x81a29eb29d0f, 0x884b39fe8f9b, 0x8c709a47223b,
+    0x9652854c653f, 0x97abb2596778, 0x980adac2c798, 0x9d15e3f5fad4,
+    0xb3de6d3aa256, 0xb90c00ca3cc5, 0xbc4ca26445dd, 0xbf24801dfc83,
+    0xca651598cc38, 0xd3ddc79fb140, 0xd3eca5f02845, 0xe6140ead0bd6,
+    0xed7ccf43ecca, 0xef30918ddbbf, 0xf827563ff3af, 0xf98c685a21ec,
+    0x03673c0db215, 0x2f67c6568aa6, 0x41de54613a79, 0x591ce5ed830f,
+    0x682a557b2c95, 0x6fb10d420158, 0x793fb4eb1040, 0x7d63b5d98042,
+    0x7ec6b8ec7a92, 0x857b73d49ee7, 0x9e10ae48aa3f, 0xaf9b2bc417ea,
+    0xc0abbd78826c, 0xc8d6b26185b5, 0xc9ab6df47b5f, 0xe138afb084ad,
+    0x35d2051f5220, 0x3fcdc9462119, 0x6872797941ff, 0x6dc2976543a1,
+    0x801cc8292808, 0x8fccdaefd7b1, 0x93acebf09626, 0xb2281840662b,
+    0xc3887eefae3e, 0xc85d8c498fde, 0xc9a88ab32c61, 0xd19a8a7f930d,
+    0xed8cfe6378ae, 0xf81c8772d6c5, 0xf951903a4968, 0x3a2d3cb42c9a,
+    0x3d3598deaa64, 0x56665f3a0904, 0x5dc8087f371d, 0x6b072cf4546a,
+    0x8bb50f9f957b, 0x933d9bde9866, 0x9d362c331699, 0xa95c6a9886ea,
+    0xac741a318743, 0xba1f365947c8, 0xcc60b2c6bd13, 0xce2a6ab2d5e8,
+    0xd240778ce5f9, 0xd76ad74a2fa2, 0xe2a3a5c63b2d, 0xe30517c5d4ff,
+    0xe55674fe4b29, 0xeaf0ea7c9fe4, 0xfba30374bb95, 0x10a6a288c36b,
+    0x14eb1ecc66b3, 0x1a929e23044b, 0x241efc35d981, 0x2a1a3406b6bf,
+    0x3096e40e558b, 0x30f4ad1d930d, 0x415fdce4b230, 0x4b218723c7b3,
+    0x7305625ee8e2, 0xb2f62314a6c5, 0xb571d1a8484c, 0xc8a16dac33ac,
+    0xebec29ed5ad9, 0xedf20ac86111, 0xf13f7babad41, 0xf55b407d4d8f,
+    0x061470c71c1b, 0x1af49b42a5aa, 0x1da311dd4807, 0x21848238291a,
+    0x221f23e3654e, 0x2aeb973a51e1, 0x367a2d37d0ff, 0x508f3d23ad9d,
+    0x52bc3b878e6c, 0x53a8fbc3e1e3, 0x53e85885200c, 0x55494c90e66f,
+    0x69247797b8f5, 0x6b6a2a5208e0, 0x801ac098e25f, 0x943cd4d0b173,
+    0x9867b281ce8b, 0xafa559d2000d, 0xafc64a453edd, 0xb16ba35cc257,
+    0xb5074f51c3b5, 0xcdb787b277db, 0xd237b7c6fcfc, 0xd2f901dd083d,
+    0xe1585e428b26, 0xf10502a7e444, 0xf39df8be684b, 0xf7f7f58629e9,
+    0x068168b7d26a, 0x186556a50ff8, 0x220894962622, 0x26dbcd77d99a,
+    0x39b9fa97bf06, 0x3dd8b8acaa55, 0x428c9b044760, 0x4c51ee33b933,
+    0x57e810a0f6b6, 0x5b0c8f833a27, 0x713ff4852fed, 0x8eaff44aad22,
+    0x90abff1fbb43, 0x90ca6b6dba9c, 0xae22a118dfc9, 0xd5dacb9eb2fd,
+    0xed12baf75442, 0x031651359433, 0x1d8078f5d315, 0x364a4fe90d6b,
+    0x422ee7f705e1, 0x4e3595435a00, 0x507605242538, 0x5d43ef0d1a9a,
+    0x7c93d6efe049, 0x7d30c231562f, 0x8897c587ef2c, 0x914d1e719506,
+    0x95a630fd3f90, 0x9762df110f88, 0xc0f48a0f89aa, 0xef65186ddd83,
+    0xf1432c12f7d5, 0x09d398cb8a04, 0x4a2e422cb732, 0x5486aa97a6d4,
+    0x56238d0354d5, 0x5a5422ac022a, 0x5cf6b4397883, 0x5fe7bb67a9cf,
+    0x6128341e3cec, 0x7093a74a29b9, 0x7553341fed03, 0x7c06ca3cf47f,
+    0x8807fa20be26, 0x88e73b66bd36, 0x98d586238e2f, 0x9aae917e2993,
+    0xae859e76943f, 0xbd345ab8e92f, 0xc9c5e123eb69, 0xcb0bd74308fe,
+    0xce9d381406bb, 0x1172e05bc421, 0x33ef48ae9730, 0x350ed0f1eb7d,
+    0x5068b231c8d7, 0x59ab685f5c43, 0x612c682ea0c9, 0x68ae17aaf69e,
+    0x6cb929b3f1bf, 0x7a8dacfac68d, 0x90d7079ee633, 0x93340f55829c,
+    0x955fae5f40cd, 0x98236aeefbbb, 0xb76e85164841, 0xb80801f6679c,
+    0xba55f0cd873b, 0xd311d3ac61a0, 0xf004ee6790ab, 0x337363e25c1d,
+    0x3a83292578b8, 0x45d52115fee9, 0x4a0c1e634dca, 0x5b9710fcc6ff,
+    0x5f17f9942554, 0x687d74ad25b8, 0x706c7b364163, 0x79424f751a83,
+    0x88be7569ac7d, 0x9303a6e6b77b, 0x9e138361ba47, 0xc8f659df6cb3,
+    0xd7771bd639f6, 0xf38a459e58a2, 0x056f92e5b8ce, 0x135abb7bc8cb,
+    0x41ecaf6b66fe, 0x58d2bac1f6c5, 0x5aa59906f662, 0x6cdfc393b30d,
+    0x73db67acd3f0, 0x761c873ab077, 0x764af6a443ae, 0x86eac2ea91cf,
+    0x89bb8952959a, 0x8c514b96c8db, 0xc397e8c2fb99, 0xc74e92a4a5c5,
+    0xce9ffe3f3a3b, 0xf2cfb3bdf0b5, 0xfdd49567de91, 0x339027bc2554,
+    0x3e62b67d1de4, 0x3f04aa0912b8, 0x46da871db3bd, 0x6acceee25ffb,
+    0x6eb9949d5bc3, 0x7f35ec4b2b9d, 0xca09044e7029, 0xe7f9920e97d8,
+    0xe9c768091887, 0x044c5326fa72, 0x1e88bb2ab76c, 0x25c4a6412d8c,
+    0x2c061795f609, 0x3220a5f0e9b4, 0x33f6b8083a17, 0x3a9ac027b93f,
+    0x5e9fe9bc3e97, 0xb1bd12a39714, 0xbbc1fd234cd5, 0xbe9c999148b7,
+    0xc74473849a2a, 0xccd637c813a7, 0x110840ef240c, 0x204a878b0255,
+    0x38ed7b8467d9, 0x3972d73a8b7c, 0x3c8927796937, 0x499ac6c10a61,
+    0x5d198812c203, 0x6ac37f6f65af, 0x7f88716f40ab, 0x8c27ebeea2d6,
+    0x91e0589ceb60, 0xb9e60e876273, 0xcf37e8ad1f77, 0xf0c75634994a,
+    0xfb0aedc87be5, 0x0845b9a02b93, 0x37a63c0d23a9, 0x4cd5514773b5,
+    0x5f14beadb513, 0x620d524ba4c4, 0x6821b390fca8, 0x6b08e4b935dd,
+    0x6dea9878e49a, 0x7edb5e852d25, 0x81ebf6cc7aa3, 0x8aaa3b800180,
+    0xa3d92d6331f8, 0xa57a686c4d98, 0xb0117deb6a14, 0xb6b2c756a400,
+    0xd5120b7db423, 0xd9c0706cc6b6, 0xe282d32eb56d, 0xe8f95620b872,
+    0xed0308cf90f5, 0x15a23b9a2763, 0x1d0babde19a6, 0x45c89b499e1e,
+    0x61eaa36233cf, 0x6880f1fc58cb, 0x6dc983789c08, 0x73c73f6eb8bd,
+    0x8d91f7c84242, 0x8f4f9018294e, 0x92444036b6ea, 0xa09483bcbd09,
+    0xb7e43d2fb656, 0xcd69547a7fba, 0xe8f84757979c, 0xe9c74af2fd09,
+    0xeac448d9ea23, 0x06492b220429, 0x0b5817d4997a, 0x372773ff9998,
+    0x3abfec224a30, 0x423ba9180a03, 0x4272c9bc7878, 0x42e64ddeda15,
+    0x47935373ca0a, 0x4f3543b64d60, 0x524fb20392f5, 0x5b584e496ceb,
+    0x6187385d6213, 0x6fd1fe7addba, 0x72f72d0a327a, 0x82472d2b6aef,
+    0x9265f659030c, 0xa254f0c2e554, 0xaa859da378da, 0xb81df7fb6045,
+    0xc706939668e6, 0xf9d6d6b40e9a, 0x099552ad29be, 0x1413b3d5d71e,
+    0x1ad8e6f79adb, 0x1b69b29cdef8, 0x29488107b767, 0x490bed0cdeb3,
+    0x4f3f6a4d473f, 0x513b000449d6, 0x51a7e1f30422, 0x59d3116b0d30,
+    0x6e7defba5672, 0x7218ec024681, 0x7eebde35bad1, 0x9118feea2e9f,
+    0x9a5303dce6cd, 0xa9d4321bf7b5, 0xaa110ab7f667, 0xac6efcaaa9e4,
+    0xb2a03054d8be, 0xb8197ad0e8e4, 0xc458a81bce16, 0xed5cf5d65aa6,
+    0xf31ceef46195, 0xfb8f422c6cf6, 0x0d2f059cf332, 0x217ba5bef368,
+    0x28727c1c9b22, 0x393a5027daee, 0x3a650dcb49f0, 0x6760c182e173,
+    0x690c47b000b4, 0xa375c9d54e80, 0xd7d70a517631, 0xdf6638c4206d,
+    0xf06149f46c99, 0xf5c4d95d58a4, 0xff9f37f2dd43, 0x3ac202a06c61,
+    0x6c5e9c50c210, 0x6dc5485418b0, 0x6df21dd48384, 0x799cd689c849,
+    0x8ad5aa922a3b, 0x92a2614de478, 0x989a2abd6b2b, 0x9a15e226d497,
+    0xbb1c4b07ce77, 0xcc0584a4589d, 0xf252e5186d57, 0xf92437d662dd,
+    0x27d87b93627e, 0x332ff0ec7327, 0x4771963d5e6d, 0x55a457b17c47,
+    0x68e29776d8e7, 0x6acc1b0b13b3, 0x6cf953fdfa47, 0x73cd65703509,
+    0x74f05c14dfe1, 0x7c5a4176ddae, 0x8aa457e74806, 0x96ef6a3f593c,
+    0xa2bfa6945035, 0xab3cf49d7086, 0xafd59e795efe, 0xbc1122d3e7e0,
+    0xbf8ffc641248, 0xc1ea7ff56f3a, 0xc25bba819725, 0xccec685c1215,
+    0xd055c1681e27, 0xd263a3d0ee4e, 0xd7e146ea6c13, 0xdfe88cabeb50,
+    0xed4b60488ca9, 0x1818f0d12684, 0x3c87536409d8, 0x4167961e062d,
+    0x7948d4f5751f, 0x79e042ce608d, 0x7c808ac59d34, 0x84c6b82a4f10,
+    0xa0d75dcd2e45, 0xa4195ec159fb, 0xbc04a41ba690, 0xccbcaf9a5843,
+    0xd545e2b0a498, 0xeee893453c72, 0xf5e680f49707, 0xff03ee117e04,
+    0xffa2e85a27d7, 0x149649c3960a, 0x2625cf8982a3, 0x2f6258a17a48,
+    0x558cf42a220a, 0x570fc2e53e51, 0x596f1432146c, 0x63ba72c4bb31,
+    0x63eba0e293a4, 0x6fcdb4cb4ca3, 0x78fde3b80cba, 0x7ab37a7a29d7,
+    0x9b89da08681d, 0x9f0d0c360024, 0xa1de47bc0f79, 0xc3b4d9038b9f,
+    0xcb8538afa22c, 0xd09793c38e08, 0xe37d12c42c14, 0xfeb58e72bf14,
+    0x02959f737d89, 0x0dc422cb8573, 0x2b2c210338b9, 0x39aa9963cc4a,
+    0x59d1c0fec71a, 0x7e4f01e22173, 0x9f39480b55ac, 0x9f4c09db2ec6,
+    0xc2ff89fca865, 0xf34d7c530541, 0x20679c589c1d, 0x217cd977ecc9,
+    0x472cd98c8013, 0x48f5e1cb8799, 0x58cb7831e976, 0x6469d6bbf6ad,
+    0x788040759f82, 0x956b94320a63, 0xbb1c0964a937, 0xc7311fe368d1,
+    0xc7d60cb2a8a6, 0xccef09fa3bd3, 0x19ed115a2d41, 0x19fd1475c11f,
+    0x1c96ba395675, 0x1d68076af594, 0x4552bf68c3d9, 0x6a75d757751b,
+    0x6b47d4362589, 0x7415e1763a7f, 0x79fcbd8e1640, 0x8d972c7987ff,
+    0xa3539ef7c59d, 0xadc2e740f633, 0xb9df151168a8, 0xc0eccb4d5156,
+    0xe65d6ea0a677, 0xece42f3d431f, 0xf04636689691, 0x0d8b6fb4dcdf,
+    0x21b54aeb701d, 0x68b17846dcfc, 0x6e9f6bb06b98, 0x79aa0551e9b1,
+    0x7e5695c8ce1c, 0x851c0379979e, 0x8a67191b1a52, 0x92dc659670b4,
+    0x93a69b765cf8, 0xa8d2b76d6203, 0xaf90d33d72e5, 0xc7414f02f4b5,
+    0xd4999cec8ca8, 0xd5305b1866c7, 0xe21e85c789aa, 0xf6160e15279c,
+    0x3755eb42e9dc, 0x6790e13a67d9, 0x753bfd62285e, 0x75417acab2d6,
+    0x7607580d317b, 0x888204264928, 0x918647ceb57f, 0x961fa157b546,
+    0xd2f1d5b1c5ba, 0xf71e1ccf25a9, 0xfe9c5dea24f3, 0x0f1cd3a65ca7,
+    0x259b7a76bc9a, 0x3189736ca19d, 0x37b38fc51d9c, 0x589b8c581823,
+    0x59f5a3a13170, 0x5f83bef44e9d, 0x6a385685535e, 0x70c2c0124c56,
+    0x78f5b689198e, 0x7b170a6ed41e, 0x8a15268dfb15, 0x920eed5925eb,
+    0xaf9c34a185a0, 0xb22a6a75fa7c, 0xb815d9ba49a1, 0xb88be201eab5,
+    0xbcf2ae12ef6b, 0xbd6e6e5220bc, 0xc511835fc0eb, 0xd1debd9ba4fe,
+    0xdf4db0d47820, 0xe90c27413cdd, 0x1dea8756b87d, 0x1e6fa87dd65b,
+    0x42a8bee47962, 0x5293d5cef995, 0x5fc331b788c9, 0x64907812a63f,
+    0x6535d9fff19e, 0x6e7ef7d22696, 0x7cf693700d11, 0xaa55c82e7253,
+    0xaaf3d83b0512, 0x01aa9e102b00, 0x047e5dbb79cc, 0x1e9abf880f0a,
+    0x24b42917e5dc, 0x2a403563cf1c, 0x484c362eab23, 0x5569ba837851,
+    0x62ed09754cf0, 0x72e90db77815, 0x8e8c96dc9739, 0x8fbfcb2bdbb4,
+    0x908867229f95, 0x967e5c19f820, 0x9f54a576dcca, 0xc9f22cacbb0b,
+    0xccb70e0792d2, 0xcf7378b69520, 0xdfb8afbff685, 0xe9e10ed82a8d,
+    0xf48d2fbd59d5, 0x2908495b0d7b, 0x292d926bb9ea, 0x3c2055d3a698,
+    0x4c53b54945f7, 0x53884e7f0377, 0x5f91ba7f9cd2, 0x65af078ed5b9,
+    0x6737c8d08802, 0x91d7999c9ff5, 0x959ad8388774, 0x98e21c0637dd,
+    0xc8f5f474db43, 0xd3052a1894a5, 0xf76055bd9355, 0x0bfccada711c,
+    0x26f5e9c7c5f3, 0x30231f713b09, 0x3333080b7738, 0x529d3e2dd466,
+    0x769bc58eb26d, 0x773f5303cfa4, 0x9584837a4e0d, 0x9a62b72d1675,
+    0xb099c51f688b, 0xc2b3e974fd7a, 0xcdfddfd7b9bc, 0xddb160ffbff0,
+    0x0e5ef0dd8876, 0x162be2284878, 0x1f9d424e74e0, 0x2624ed27289c,
+    0x3ebce64f7930, 0x588fa036cca4, 0x59ab0a6bb917, 0x6a007f1fb41f,
+    0x747e309b5030, 0x78a10cbea359, 0xb4f23dfffdc9, 0xc084f20be4c1,
+    0xd05c225b6f01, 0xe2ba369eb57d, 0xeff50276652b, 0xf24b67f95a43,
+    0x0231ebb76712, 0x089c147e3289, 0x1e73bf781eba, 0x1f6881423c20,
+    0x572aa41e9a6a, 0x5b6ac7c4d013, 0x6c8fb5323c12, 0x7cd7aaefe2b3,
+    0x7d43dd318bb0, 0x9193b125dcac, 0x9d4d482b9876, 0xcea5f1ee3c98,
+    0xd122ffdc00bd, 0xdf53029bd95b, 0xe1ab77260260, 0xe1ac61621974,
+    0xf958f9a8392f, 0xfdeac6c17a91, 0x2b4fee0675d5, 0x31038cf94536,
+    0x4ba3b092d5b4, 0x63366fb7697a, 0x7c0848386dbf, 0x852df1722470,
+    0x89a953cb3068, 0xa0a84e073510, 0xa393281fca95, 0xa9d75828d84e,
+    0xb015d03a55ca, 0xc4aba3238c40, 0xf1482e71c3a3, 0xfed294b54b1d,
+    0x22ccc378bb85, 0x4128d3cd7ae2, 0x50538ade06e8, 0x6cd09d5194df,
+    0x7bfefd527d35, 0x7c1ec8fa9f2c, 0x88da2ba2c139, 0x9c6dbdcb85e2,
+    0xa266ac06296e, 0xa56f0830a738, 0xa5ebed3af562, 0xacc2f7f07b25,
+    0xb1e2976bb5a3, 0xd51a1cbef82c, 0xddf4f948503a, 0xeca37afb9f65,
+    0x0775b6ef1f6a, 0x138cdc7512f1, 0x1c468dfc266a, 0x3219e058a4fc,
+    0x4a64fdc96300, 0x6bc54c3a384d, 0x72f259003768, 0x7814b72fb722,
+    0x84e8ce2e484b, 0x863f3c870548, 0x9983e3f10374, 0xcc380456e381,
+    0xe0d822641d98, 0xe990af201438, 0xec6e44d15b1b, 0xfa2b731be36b,
+    0xfdca8d723b54, 0x0aa87a76c494, 0x0fefac98e533, 0x1ac23b59ddc3,
+    0x26bbdecde905, 0x55da8054637b, 0x5e9088eb1aa4, 0x612267afebd0,
+    0x63071d88ad73, 0x6e932f60ed1a, 0x71099b1b09ee, 0x74402c20152a,
+    0xb3fc15c79e4a, 0xc1d73b421a69, 0xca83a861df40, 0xf3cac13f0084,
+    0xf7b1e99171d4, 0xffb87fa5dfc2, 0x075826522966, 0x3452059192c1,
+    0x483d33b3f925, 0x4c6fd863da67, 0x4c8a613277ab, 0x6368e019490d,
+    0x960cfd63953c, 0xb9636422e2a8, 0xc298263c1623, 0xc7034aea8878,
+    0xc9b09cba0dfc, 0xcd99d413b339, 0x0f5d730e70b9, 0x4a240e95eab8,
+    0x4b2f75af434d, 0x4fc142c884af, 0x51ac9ad4eda3, 0x5500e87ae6e9,
+    0x5b28bb3d2936, 0x729805c93eb5, 0x7643b4d9d3f1, 0x7e93f2d383a9,
+    0x7f5c543b41c5, 0x814a6afbeff5, 0x84027d0d84a4, 0x854b6c6fed3a,
+    0x8c0c8183491d, 0x91de8cc417d7, 0xa93e0ec39f3d, 0xabf4fc0a1713,
+    0xc5df0aedb705, 0xcdc2dc16b7fb, 0xe37f4e94f599, 0xe689f455ad15,
+    0xfbb0cd732d6d, 0x07dd38ee3985, 0x14f8e8cad88b, 0x30bcb2b62530,
+    0x312ded424d1b, 0x579aa4407842, 0x5f6f97190233, 0x7450e65fa89b,
+    0x79be4bce8cbd, 0x89a8035eea52, 0x8cd5a89a148b, 0x9831b11f9898,
+    0x9836f3f91d4b, 0x9ea86ea0a162, 0xc2804d96b05c, 0xeb54cc8d8717,
+    0xec97c96959ab, 0xf2fb4ffc7dd1, 0xff4a45d3f11c, 0x10d831b0b552,
+    0x14741834bc75, 0x2255dfe2dfe5, 0x28e96fc8bfa5, 0x2cc0cf8ea2dc,
+    0x3843807ef5f6, 0x396c2f1b310b, 0x461d0c1049b2, 0x495362864f29,
+    0x495e22c85e54, 0x50ed16ac02cb, 0x52effe43be02, 0x5f74b5657d24,
+    0x608684237745, 0x7080795e6e7d, 0x7136c8a364ce, 0x7a6598360247,
+    0xaea57320df9e, 0xb080c811b4b4, 0xcaa39182eb7e, 0xd020bf7e5db9,
+    0xdf43af902595, 0xf96469fa2872, 0xfeeea1cde38a, 0x01b4a7f3d82a,
+    0x1d09bb783c5b, 0x2937112f5f87, 0x2988baa26b40, 0x59022bf4d837,
+    0x614bc7bae09e, 0x7fb4a758e313, 0x933194326c8d, 0xa70b255016b0,
+    0xa7e941caf8e7, 0xb54538a4ed2a, 0xb6bdf6cb0b95, 0xb7f87cfb08b0,
+    0xbeef5358b06a, 0xc515c6c0d019, 0xc73df76937bf, 0xd68df78a7034,
+    0x09fd6f7fc580, 0x27f9e24d1933, 0x2e974838f10a, 0x3ee61ab944c1,
+    0x4deb139b18ce, 0x9298dd015a84, 0x93d36331579f, 0x9528e74dfd88,
+    0x977501aceeff, 0xac7de39a7b88, 0xb50f183e91cc, 0xc69f132289ef,
+    0x1a9933b9a7ca, 0x1b80b11c768e, 0x4aed5325a06d, 0x78f55832a799,
+    0x83d9496956a5, 0x8dc2fb6d5dd3, 0x959f05979a9f, 0xad44fbaa1309,
+    0xbe3a1a53c933, 0xc90900246573, 0xeae50ed46c11, 0xefcd188d2c90,
+    0xfa5eeb32c459, 0xff05892312c2, 0x114c486a0cc0, 0x28a9bb626039,
+    0x331bc25fd60b, 0x4023c63084e3, 0x45a93048c6d2, 0x4c214d23f23a,
+    0x64dcbae4c115, 0x6cc2d5a3fbbd, 0x737bae9a87ec, 0x74d0487b16c1,
+    0xa6eaec00d773, 0xa735090424c7, 0xbb3fc83fa75d, 0xbf0f9b95cc2f,
+    0xbf23bcbfc7e7, 0xc4235603daa9, 0xd5c403b077f9, 0xd9a7f8309883,
+    0xf80393674c56, 0xf9bb39309d60, 0xf9feee8f4928, 0x06e9e56c1b45,
+    0x2308f44165f5, 0x2aecfff96cb0, 0x3fd1831263de, 0x416b31abc119,
+    0x57e0b2233a44, 0x6c4ad45722bf, 0x76ce785954d2, 0x7ab098614734,
+    0x7bd43eb3035b, 0xa5231e8ee8c9, 0xbef9f684a417, 0xce4e89d24ff0,
+    0xcf5438f41848, 0xd05a22a4e665, 0x00f6ffba09be, 0x12f2b7c19f54,
+    0x1c6d03b1acbf, 0x42acaaa0674d, 0x43b0102bf5f3, 0x4902eccc3cd1,
+    0x4c709e75b682, 0x76333d030352, 0x84913a0e639d, 0xb50aa28b08af,
+    0xba1202cddf9b, 0xc05a50e5552e, 0xc1933d2c29e6, 0xc2b633d0d4be,
+    0xdb0b27478ad9, 0xe1345963efc4, 0xee283c0aa2e4, 0xf78505e8c80a,
+    0xfed6e6a1680a, 0x245c955acff7, 0x2b1c10850377, 0x316a51230f0c,
+    0x371d05d9c759, 0x378c6bedc11c, 0x5afd5b7babcc, 0x64a701116382,
+    0x7c2b91929192, 0x937c6fd0a7b8, 0x9a22c1866092, 0xb102da565a57,
+    0xc3a085e9e4c1, 0xc862d173ed47, 0xcd088528249c, 0xd2e00dd17dce,
+    0xd54eed1bdc3d, 0xed661dba7c92, 0x1c31b673c8b1, 0x2058eb348979,
+    0x4f27f24f2c23, 0x65e1d7d26265, 0x6cb3da3d693a, 0x81d5e59f7069,
+    0x987df38ee4a5, 0xa390542f26e8, 0xaea329ed74b5, 0xbbadec7268c9,
+    0xbd6e43769511, 0xc0a9dcc61f3b, 0xc90f9b43ed49, 0xdfd6158160de,
+    0xecbb8ef5a883, 0xf8b7f11df901, 0xfa5cd51770f1, 0x091f02d6b04a,
+    0x0c1a1a99df72, 0x0c84b2f2600c, 0x258f809000ee, 0x2f362ce26da3,
+    0x507c2d13ab71, 0x72916b6f5471, 0x9273f36f8c65, 0xa51dbe9f4898,
+    0xb2a3cc456273, 0xb6f6772c6b71, 0xc5c9924355bc, 0xd5524cf22509,
+    0xf0fc3dbbe5b9, 0xf5e6567bda25, 0xff12a1e93827, 0x1ba8a3423aff,
+    0x21eac44414cb, 0x454040c74b23, 0x4ba401e9750e, 0x63e3e9fa185d,
+    0x640d51192ca6, 0x726b1395872c, 0x944cda3d1e07, 0x9c478b445ff1,
+    0xa9a173172047, 0xafa6bb7cfb61, 0xc33ac2c3cb94, 0xcaa7a1691462,
+    0xcfb8d7b1e365, 0xd0eb96e31c56, 0xe40711bd0bfe, 0xe72ae0f23e20,
+    0xfd37d8186a9e, 0xffef750bf3c3, 0x10e2bf3d7bc5, 0x4b226525a9be,
+    0x5168dec4f129, 0x5fb2f5355b81, 0xd69e619bd0a6, 0xea5b9572af5d,
+    0xeb2ad39d1a8f, 0xee9a1f2fbca3, 0x1288a37506cc, 0x17d63d3bc8f7,
+    0x3a4267e40263, 0x4620984d594d, 0x6a5ad37f192d, 0x7173bf1b1c90,
+    0x86816ec42242, 0x8d8f9a1e167a, 0x92834e54fd38, 0xa84d3fc98f3d,
+    0xa9e60426d564, 0xaaa788cbe66a, 0xae0c141c7953, 0xae31229e1ffd,
+    0xb5f2696ab9c0, 0xbbfaab13dfdb, 0xc22501fb619f, 0xc36fc5d5f85d,
+    0xc440d87891b7, 0xc78b501892e6, 0xc94b6c8db969, 0xce421a07eb28,
+    0xf3b36d085198, 0x079240ff806e, 0x0856bee7dc75, 0x11283a8947f6,
+    0x1b402167dc96, 0x24ea018c9a11, 0x502e0f7ae08a, 0x7f9449df68dd,
+    0x81c09896385d, 0x84e467cb6a7f, 0x9d30aa07455c, 0xa9cfaf689bfd,
+    0xbc46ed205d1f, 0xc2c3d7b701b0, 0xc5cd1e1d968e, 0xc80a94bb16c5,
+    0xd1fa73d4b9ba, 0xdfe4ecbdb868, 0xfdcbdf06edc5, 0x0394895fcff2,
+    0x0d749fece4ce, 0x5757143473ce, 0x5f83687799b5, 0x6b9969327063,
+    0x6d3f71f7052c, 0xbd5b560592ba, 0xbed1900671ae, 0xc2598fef82de,
+    0xddb78f3dc812, 0xe8645fd008a9, 0xf2c8e7d72a14, 0xfc0c1322c90a,
+    0x10642333e9ba, 0x13a3da91dbbe, 0x13f45f39ca9e, 0x1dfb58c0b44c,
+    0x27b15881a390, 0x5be1a56ef893, 0x90c2fec7bf34, 0x97ae65364674,
+    0xc362fe03a389, 0xc861727c9972, 0xce985e1e5889, 0xe71a27157978,
+    0x1441c61164bb, 0x25dbd18a5aba, 0x3c6e2466aadb, 0x660b3f544577,
+    0x6c6fb02380b1, 0x74ecfe2ca102, 0x876859f2c9fe, 0x9ba5a6a647a5,
+    0xb0584bf45511, 0xb73807e4b612, 0xb9a169c68a09, 0xbb77414ed4a7,
+    0xc0d1e4eddfaf, 0xc79dba434abd, 0xd5ea1a49eec7, 0xd6861b4f4d99,
+    0xf18a6f9cbd25, 0x2453264aa474, 0x7c2172d554c0, 0x8469af413a89,
+    0x955c0f36ab77, 0x969dacb85b6d, 0x99af2f3bbfff, 0x9a6752f8e478,
+    0x9c76cf4add02, 0xb5474871bea9, 0xbff5ed7c2d68, 0xc342aeb26849,
+    0xc6c56bc1f4c6, 0xf3a32849987a, 0x0afbcd8672ca, 0x0b35acdf267b,
+    0x18df2f1dbe9d, 0x1f6331061609, 0x32c435b6dfa1, 0x5dc83736d6a2,
+    0x707d7255b34f, 0x7d9defedcb7e, 0x8b7a3a336476, 0x984b57eeaa9e,
+    0xb1b4292a8ec7, 0xb1bacb5e3618, 0xb52e34ff4006, 0xb8d84a26acdf,
+    0xd0ae498be0e3, 0xfe3a193bab34, 0x0435167d82ad, 0x149f96979081,
+    0x1e4eb995d2af, 0x2b9445af9188, 0x59517e0c3a11, 0x671dffc544f0,
+    0x772a072303f3, 0x86a99b78ec78, 0x960a13d3c455, 0xad73a6684997,
+    0xb92ded1b16b0, 0xbb4ff0ade28f, 0xc0800862bc75, 0xc4ffc359360c,
+    0xcc988d42d29a, 0xe8f1f08edc85, 0xf22518bee79d, 0xf26649f853ee,
+    0xf854b27fee14, 0x03f9b33da29c, 0x19ae5ebd1c10, 0x1a508cd816a9,
+    0x1bf1183420fa, 0x2d476e4e6b31, 0x37f5d8c9d42b, 0xb0bcd4b0242b,
+    0xb95fe0e7fc75, 0xce94ae19dcbe, 0xdcd4ee844eff, 0x2234ae921967,
+    0x64fa8e4d48b4, 0x6b61f85fceef, 0x75f689b9abf4, 0x93c92049dfd4,
+    0x97b9a9843db1, 0x9c80c2c9bf60, 0xb805a511d966, 0xbbd1cf77a1e8,
+    0xd11a0e266c9a, 0xf7597a862163, 0x1414c4efd0aa, 0x259f7cfa441a,
+    0x2b129a60b879, 0x373c0c987990, 0x45402413fe6e, 0x6b495a0b5bd6,
+    0x7dd409400761, 0x97a479912123, 0x9e2aff9eb806, 0xa507c24bce06,
+    0xb27d1d2942b4, 0xbde754522c77, 0xc90c01a43c4a, 0xe37629647b2c,
+    0xf988d88237e7, 0x0e0a4fb26ce0, 0x11e30ed272b5, 0x13ecd32cdb02,
+    0x152300bf6a7e, 0x234c9bdaa190, 0x25ce3cf4d919, 0x3bb416922b00,
+    0x4765e69922a0, 0x57b653029eba, 0x59ac30c21114, 0x59cdd0e26133,
+    0x5ea77168b637, 0x780affcb15ad, 0x9b86750c09c3, 0xa2b974589ee0,
+    0xa45706715e30, 0xa97546927610, 0xb1368d5f0fd3, 0xb9d0e85c0cdf,
+    0xc3bb4a0d255c, 0xd9f307ac88c1, 0xebeaa1a5b67d, 0xf10a4120f0fb,
+    0xf6bb5bee80e5, 0x1b1a3083dbe5, 0x2ae247f3e95b, 0x3138ff3dca69,
+    0x68278b524fe2, 0x6c41068db67e, 0x6de4509e060b, 0x912a3f23b40f,
+    0xa0bb707e58d5, 0xa6f171e400d8, 0xb762cec0bbc2, 0xc9576f769e7d,
+    0xcde46ed466b6, 0xced4d801167d, 0xcee8840d06ab, 0xcf585f3f0bf8,
+    0xd87bbee288f7, 0xed84db5f1b45, 0x00bc03d2c50a, 0x05d9ced5d160,
+    0x14cec49c118f, 0x20864c9a996c, 0x260acc76c447, 0x3576b4c0bc9e,
+    0x50616a7ba5fa, 0x5384c492cc92, 0x5d2f8ef3a121, 0x67ca87f21fb2,
+    0x6f284db7ebb6, 0x77fcc29ca238, 0x80f227f4978a, 0xa26a407fc4df,
+    0xcb67b177a459, 0xfa8e8f1aee83, 0x1f99dcef47bd, 0x224101a9317a,
+    0x4946faf87637, 0x503b12a1d8b5, 0x519f750ef5a3, 0x7eb6d6604743,
+    0xb31ce497e81d, 0xb6a88d71559d, 0xbb5bfaaae71e, 0xc5975621fa05,
+    0xd0a3148e94f7, 0xe3187dce27f1, 0xf37afc5a6bd6, 0xfe35fb901223,
+    0x055848140213, 0x2bb486d88dd2, 0x3806eb1157a8, 0x6b131c8ee4fa,
+    0x894307104ad2, 0x8b4629370bce, 0xc459ecb0adec, 0xd0f5f8ceb98c,
+    0xd9b09491e419, 0x031061c57479, 0x4ce0fe794173, 0x647e7ddfe464,
+    0x6df93eedfd59, 0x923932174d76, 0xb79eda998da7, 0xbd6c8d3ceec2,
+    0xc1d23482d69f, 0xc2b7dd6d773b, 0xc9b0c2d252e2, 0xcb368ad0ba2a,
+    0xd255a3825954, 0x00e0baaf4a71, 0x11f4803605b9, 0x478f97351936,
+    0x50f75be4534c, 0x5c17b098f580, 0x5cbee6fe6f07, 0xd0b025ddcc18,
+    0xd41c3d9e1d66, 0xed7df7884eb4, 0xfb8c1f98d16e, 0x0079e149222a,
+    0x24906d535dfe, 0x50745fc6655e, 0x5db767bae4c0, 0x67b0329e68b8,
+    0x70976952f854, 0x927c63ccdff5, 0x96b11783f524, 0xa40d48ecef2c,
+    0xb343355d95e7, 0xb4d0c45ac159, 0xf0a5c03edeee, 0x06cb312c74c3,
+    0x1974873e256c, 0x214c7359fa5e, 0x2d87bd257d7b, 0x2f2a1cf9b5f4,
+    0x453b322e4a4c, 0x4f2fa47460a5, 0x5ba6dc9fcb60, 0x5fee5226b9a9,
+    0x621891d6553c, 0x7e74b3d6a463, 0x89632ac05cd5, 0xb6ee4ac315d7,
+    0xc6ebae5f639a, 0xcb56d30dd5ef, 0xdd7f9b24dc1e, 0xe71260dc52e0,
+    0xebe22b5cafcd, 0xf33e91c85933, 0xfb65a331fa67, 0xfc00b9fb4225,
+    0x09f493cc2d60, 0x496c8d8604f3, 0x51f75a8579ab, 0x53128a2b6059,
+    0xba85798d9fd4, 0xc9d8e8102ed4, 0xcd0b5b06d236, 0xf225120d96eb,
+    0xfed788ebd7f5, 0x08e90825cb09, 0x0c5b878abde3, 0x1eb5f2dda80f,
+    0x342eaffd39e5, 0x4aef37b41778, 0x4baa8f438cb7, 0x6496b604287b,
+    0x67173253432b, 0x78320f2bb14e, 0xa430c4fc5bb7, 0xb769123b2255,
+    0x1329475a531c, 0x20d96bcc928f, 0x2661949919ba, 0x2de6ed05cbdf,
+    0x33b63992555d, 0x491bfa530854, 0x599d1fbc5157, 0x6cc1c0ef27c7,
+    0x74d43c10c1b9, 0x84c963903fc8, 0x93330b19c652, 0xa30b25a567a6,
+    0xa4bb3efefa4b, 0xabe8fb720ab5, 0xb0a5c99388c3, 0xc4aad0d77b1c,
+    0xeffcd2da2186, 0xf111d56a6c6d, 0xf7d2ea7dc850, 0xfd5177d35d29,
+    0x1156448849bd, 0x5ed848443c30, 0x8edba879402e, 0x9bdb359e198d,
+    0xb0ef877ac690, 0xbd53c347526c, 0xc231474d0985, 0xcd2bddd2f3c0,
+    0xd5591c5230bb, 0xe38c8d735fe4, 0xfaeab018c4ac, 0x283eaf770f39,
+    0x3c40f806bc56, 0x4093a2edc554, 0x489b985c55e0, 0x594c176b492e,
+    0x67168a1d2020, 0x69b8a68c8aef, 0x7cb6d9e39817, 0x98aa380394cc,
+    0xa0575da632d7, 0xabe543f6a0a6, 0xbdf7a14d716b, 0xe8a05de36a61,
+    0x13a8b800cf01, 0x17f15252da23, 0x5c06fe331f1c, 0x5e41b61c5a17,
+    0x699ee36cfafd, 0x82e355d449cb, 0x8a57fb7856c3, 0x920d97c6ca47,
+    0x96649b4b40e4, 0x9bb4f3c6484b, 0xabfb150bc0c4, 0xb9289c7c21d0,
+    0xc88ff199a6c3, 0xcdc795be3f0e, 0xe6e908ab1b1f, 0xeb79766a39e3,
+    0xf11c9d2369dc, 0x18e20c108bb2, 0x2b376f18f6f0, 0x366ccf33abf0,
+    0x425e7119ed43, 0x42d4044382cd, 0x6e92e834e383, 0x8f016e1ee66b,
+    0x929886e77465, 0xa0cbf808a38e, 0xa12a70c4f25f, 0xa1c4d7e128ce,
+    0xd4ea32d330c6, 0xd5e7e0672f2f, 0xd98b8de9fa7c, 0xe9ce40ce1c6a,
+    0xee178acd38db, 0xfd7878461c42, 0x01897cd5ad65, 0x3fcef1ed51cc,
+    0x500fd0594592, 0x5af7df9e5c78, 0x858b90ce42a2, 0xa645cda4bb41,
+    0xa7801945b297, 0xad2673d13356, 0xcbe79f15e8d5, 0xccb2bf31ec2d,
+    0xd3957465982f, 0xdc7f69ce6d07, 0xe7e50dcae366, 0x281bcd7586c3,
+    0x35ffdeb9e3e5, 0x49b217bfadac, 0x4cfbdfb29d8c, 0x67b77656e262,
+    0x68c3528e4681, 0x6a1e541376e2, 0x87184b915303, 0xb925aaafc513,
+    0xfca00537bc89, 0x587d0cbbc446, 0x59ba171100d8, 0x72f6c2798b7c,
+    0x748db25ea37b, 0x7827c46a7c76, 0x79b67832c4c1, 0x8a882243fca4,
+    0xad70824972eb, 0xb3c1470cbdf7, 0xdf45616953e8, 0xf1aa17e041b5,
+    0x392a0708a9d4, 0x519ae2a81fd1, 0x5f6bbcfe984f, 0x5f9fa9d0b5fe,
+    0x710fd90c8c2a, 0x9b8f6912769c, 0xb03be14ae841, 0xb71f80baab57,
+    0xbc2c5e660cbb, 0xbe32b45f1e7d, 0xe3a392417963, 0xe8d9275eddc1,
+    0xeba7debfad9f, 0xfa5af39f702e, 0x1a15394bb0b2, 0x1a81e0ab6539,
+    0x4881e42aa276, 0x493240e902c5, 0x49d2256dc3ac, 0x58155f1b80ee,
+    0x5c5f1e38a8e9, 0x642b5fd6579c, 0x71c9acb4d509, 0x76fbd370e2dc,
+    0x87827642b657, 0x8c5992a3cbe4, 0x9a99987f3860, 0x20f66a983054,
+    0x235e6d1fe1ad, 0x3b9110c9365a, 0x3c0f552da722, 0x436d90117eb0,
+    0x4af6cbfd92ea, 0x5bf9debba905, 0x67849139c60e, 0x684c7d8378a0,
+    0x7b6c50fad5e7, 0x83dfc8fdfe21, 0x98a7eed029e3, 0xa341c3038b9b,
+    0xb6a5c0f7a034, 0xc12755f29e5a, 0xc1c899d181df, 0xcb30d39ec77f,
+    0xe40f064b0352, 0xf6b3c9304097, 0x2223c262dad0, 0x2af035b9c763,
+    0x5ec4c89f12d1, 0x6a0dd4c5b7ff, 0x72fe31d32e63, 0x7936b75e15dd,
+    0x83ab7d0fd0eb, 0x89afa0aa8f2c, 0x8ca032bab4ee, 0x92013dfe6182,
+    0x9a8f3ed02700, 0x9edbbca19437, 0xb5b48390d55c, 0xc1f3b0dbba8e,
+    0xd893f7cc6aa0, 0xe836167eba58, 0xf3e3c8774a1e, 0x0127baa7e094,
+    0x05b569b2ba1c, 0x07d9072eae5e, 0x2e1db667e7da, 0x363e9abbed47,
+    0x88815018bfdd, 0xc314e90a3341, 0xf4e74404d4c7, 0xf9b7f8c148c8,
+    0xfdbcddb4a822, 0x000673ee5a22, 0x0e722a7f1499, 0x106d4b180ba6,
+    0x17098c38c6a4, 0x1d5c25743fd8, 0x2118fc6b85cb, 0x47efd6a42602,
+    0x7948f584d5ae, 0x8a19b559f67d, 0x9e44efeaac59, 0xaa9be74fe993,
+    0xb9a9916c98de, 0xbb555228bde4, 0xbc71317bb5e1, 0xc64d29fa62e3,
+    0xfcfcce6bb5bd, 0x0d86df9edfc3, 0x1468e5257a76, 0x68e184b00ede,
+    0x70700375a7cb, 0x73455d0a1efa, 0x7aafb78a2851, 0x7f20cebf30a8,
+    0x87058a2448b2, 0xc73ecdf42b86, 0xda4786fe4214, 0x010a7a9bec58,
+    0x02afd3b36fd2, 0x0a953ec5992b, 0x1bda6cf932ab, 0x2f616a67ba01,
+    0x46b6dbd2438b, 0x50c4026ec900, 0x62fe678a8b70, 0x67df1f62934f,
+    0x89a06ab4f6e4, 0x9a6c5cce9e8a, 0x9bda5ab2adca, 0xb3b1b972046c,
+    0xba3f916053ef, 0xc43563008ce6, 0xd5b7a45f2add, 0xe30447ca9c91,
+    0xe5494ad7db2d, 0xf1c58b4dc4d6, 0x10b058409463, 0x1b7ec8f32519,
+    0x27389a87e6a8, 0x2a37d0597daa, 0x2e6b99d47bc5, 0x42f1a4312422,
+    0x52fa3d2d8c9a, 0x596bf2641676, 0x61699caea361, 0x78238231d9a3,
+    0x8ee3cf59b171, 0x9d950fc145d8, 0xa0e4551cc030, 0xb59efbf8978b,
+    0xd34f42bb69fd, 0xdaaa845bf68a, 0x07bf9c170e78, 0x133afb26a8f2,
+    0x160c36acb847, 0x16cdf5e0cf12, 0x21253980a1db, 0x676cb18040cc,
+    0x78903f938a2d, 0x7dace5cb79aa, 0x8f697ba0d6dc, 0x9e4a15ae158e,
+    0xa596ee1c36a0, 0xa824aed29ff2, 0xb8825fa36aae, 0xe0b7a9c291d1,
+    0xe62758c7afa5, 0x0418d0c3ee68, 0x0b2adf9d44b5, 0x1cbbc4bd53ec,
+    0x235c990a824e, 0x33b6665beaf5, 0x466624123d2a, 0x4b5df6578bc2,
+    0x4ba8c307ea65, 0x71f27a8ba2cf, 0x75468da29650, 0x81669ef26ada,
+    0x8ae62dbbfcf8, 0xa5ea477a66bf, 0xbe7c4e1c2151, 0xc605c4973b50,
+    0xcafbfcf36185, 0xd783ad586ba8, 0xf91c4138cc43, 0x0d4706ab7695,
+    0x283cb73774e1, 0x29d456c99e2f, 0x6571bceb41c5, 0x829a23d2b11d,
+    0x9c7bf699815b, 0xb7defe32457d, 0xd1a264ab1662, 0xefe9deb7ce7d,
+    0xf7c991d26799, 0xffe65818052c, 0x072501e2c088, 0x41f91c608eee,
+    0x44af1f6aefb0, 0x4949d84e1215, 0x7acf922026d0, 0x9154a095284f,
+    0xae81d53810d0, 0xb50e885b437a, 0xbad6f8251fe2, 0xc7f26d72b923,
+    0xc84785471b67, 0xd098385ed6a9, 0xde0b8435176a, 0xe68faf00e4d1,
+    0x07ae571a4243, 0x15e32795940a, 0x1f2b95bab7b3, 0x7d3c034741cb,
+    0xa15a565041c9, 0xa7328ea6ac4a, 0xdfebf7726771, 0xf8b0c61b22d9,
+    0x04020e5e97bb, 0x3176c3a11b53, 0x43ad0aae75e9, 0x46f831fb8867,
+    0x71da58cc2984, 0x7476bd440416, 0xa63ecd1aa1fb, 0xa6f1736f3bfc,
+    0xae39b8b0e9aa, 0xb6b44805c4bf, 0xc5e5a149f816, 0x28e1fc0ea4c2,
+    0x29671d35c2a0, 0x415ebcbb46c3, 0x42c9fbeb10c7, 0x4d56c64629a2,
+    0x4e5f6eab3cfb, 0x86800a43ea16, 0x9d35d1b8b87e, 0xa8878f1a38ea,
+    0xa8de7b66c956, 0xb7a6611d98ec, 0xc5ff1b4f7484, 0xc61bedb44b7a,
+    0xc6233f95041a, 0xcc597b89b1e2, 0xfc18b141fe8e, 0x151b0833c9f7,
+    0x4135e0bc3a07, 0x435351229282, 0x599853294489, 0x9d70b14f7f46,
+    0xdb6c43f2dc1e, 0xf2315ed62d15, 0xf53736db6b68, 0xfc3c014d7313,
+    0x22e0fdbb2988, 0x2585d8ded993, 0x49469d67a1d4, 0x54a2a5ed25e1,
+    0x6394dcff20d4, 0x70754e28e98b, 0x8ede2dc6ec00, 0x9bc924a3be1d,
+    0xa7c6710825af, 0xac2f1191588d, 0xb583d9e1b3c4, 0xecdff79204d8,
+    0x273433c23a13, 0x28b8d6f58482, 0x49b5a45f8c10, 0x4f2ad0cd345c,
+    0x5d6e7f98fd28, 0x67eb816787ea, 0x8ca0cd2b67cc, 0xb66f1636dadb,
+    0xbd7873d555ea, 0xe29f6f436941, 0xe9c773bee96e, 0x1a131c7f0c98,
+    0x25dc7c11567b, 0x3d0602378c45, 0x40269d9a6da1, 0x49fb7ec767c8,
+    0x5df8847d9032, 0x5f1c2acf4c59, 0x6450d5b099a3, 0x758c2dde3b0c,
+    0x84ea9731defc, 0x8d09e19cbc06, 0x8db2775c582b, 0x948e8a5c5cdc,
+    0x96796d4aba46, 0xa01d2059dbfa, 0xabc58f78e70d, 0xb9e1ab9dc9b8,
+    0xc224dfbf3093, 0xcf26f1094969, 0xd8c6c0990908, 0xda85f2d21877,
+    0xdc8481cc660f, 0xdcd20d3109ee, 0xfa8303a0edaf, 0x07ce0d233700,
+    0x0b06e7be7bee, 0x154b698e759d, 0x3592d1ef9dee, 0x3734bca5cadd,
+    0x40c0a59a9489, 0x5470950a249e, 0x693ac9e3844d, 0x722aec61f4ec,
+    0x7d0c94026a46, 0x86382fc2b6f9, 0x89c7f6aa8c53, 0x9e44a01f4823,
+    0xb8c398b6940c, 0xbaba262317b5, 0xcff3fb9f76ec, 0xf0da5e494910,
+    0x09dfae7e5f7a, 0x13991ca0a549, 0x3af343883536, 0x53f31654c128,
+    0x62639aa0f4c8, 0x66ee8af78914, 0x8d3f59ccf459, 0xa764a7636a9a,
+    0xbee937e498aa, 0xcf3168314510, 0xd032beb59fc9, 0xdd05eb7819de,
+    0x0075b63ae7b5, 0x114d8d61bb5f, 0x324084c5cad6, 0x36cbafab64e7,
+    0x3919d9118a4b, 0x3b69d6efddd7, 0x40a942133a4c, 0x4a7b29fce972,
+    0x4f0f061d5ec1, 0x56d7d959b6e9, 0x5fa4fc5db4cb, 0x761365837b1b,
+    0x827641f5e459, 0x8c85027b9231, 0x9ae50e8e2669, 0xa92f24fe90c1,
+    0xce6991e98e81, 0xd533cd55d12c, 0xfaf2712bd5b6, 0x1d09f91db868,
+    0x58262197a035, 0x5b86544ac57f, 0x6914d89cb4d3, 0x7a66d619916b,
+    0x7d952b01ccf3, 0x826654dc4c7e, 0x8ed3b701bf22, 0xdcf982c1d491,
+    0xe593a32fcbd8, 0x01b02ddfd711, 0x101be4709188, 0x143a67f67712,
+    0x290e3846c913, 0x3658ccab06da, 0x483652f3a2dc, 0x797bc5c8625a,
+    0xa05140a6dff3, 0xa4af5b7d096b, 0xabb843fd78f0, 0xaed7f5244338,
+    0xc65917441abd, 0xd35cfd0661bb, 0xe00479138dd5, 0xeab1f952dfbb,
+    0x101816f32b76, 0x37c0b37b7656, 0x3b1192c01911, 0x57ebbe95d33b,
+    0x67ea46fd3dd7, 0x7db2d917ad3e, 0x982661bfd8ad, 0xa63deab847f4,
+    0xaa5a24a7f3cc, 0xaf4b8f48a0d8, 0xb14306f13b95, 0xbe2b048ac2b1,
+    0xc2644b6e4b44, 0xed35fa054cf9, 0xfeedfcae36c7, 0x212b083fcbad,
+    0x2b45add2a589, 0x34ec94b41803, 0x3cfd3b5d83cd, 0x4234df821c18,
+    0x59787958e39c, 0x86d90d716b7c, 0x98ea0b6e19a3, 0x9f4e7c3d54dd,
+    0xafaacdb3fcfb, 0xb0691e86bd3b, 0xcef5ad4c43bc, 0xeef12431f091,
+    0xf6bc06757ca6, 0xf9e5c83144ca, 0xfb372e3f82d9, 0xfc8943fad237,
+    0x08093636e015, 0x19e97b33c153, 0x2da00cd6f8b9, 0x5d67098e098f,
+    0x6419b56ef9f7, 0x9e2b267c9a7e, 0xa05e8c851cd9, 0xa684c55e36c3,
+    0xc9e502237c46, 0xd0b68f707791, 0xd29552c2a332, 0xdff9c0486cee,
+    0xe9ff1fe62e39, 0xfa06cea67f9d, 0x0519def3d32f, 0x20119e870568,
+    0x2d080552f7ff, 0x51fc73491045, 0x53317c1082e8, 0x535147b8a4df,
+    0x5578c8b3fb36, 0x558473322175, 0x5752be4aadae, 0x5d006ab6e70d,
+    0x68d8a899a7f5, 0x6c8f8d0a57e6, 0x94f72a127455, 0xac838192668f,
+    0xafe722a6e264, 0xc399963bb1f0, 0xcb0b429c73e7, 0x151250479e07,
+    0x1ad5b7c6fb81, 0x1b054bfbab91, 0x24d38af4fe67, 0x3a1268bbdc8c,
+    0x3ef9131a7a6d, 0x43af04794b65, 0x79258214c3c2, 0x8d6ad0560b58,
+    0xdeea2c959ec0, 0x21ed1f7bd284, 0x5ecfcc0f8260, 0x703c8cea0201,
+    0x898e7e47a536, 0x8ca3e44a6bdd, 0x9a0bc0318c24, 0x9e2d026bb6ea,
+    0xc980d8e68b7c, 0xdd2d9483cacb, 0xf5e7a2ea7708, 0x2bff6464d2ea,
+    0x2d237fd49a9b, 0x435460b150ea, 0x601095571745, 0x7d25ffdfa7be,
+    0x7db35d239550, 0x8b28f2900fc3, 0x8e04793a22b9, 0x91c917302cd6,
+    0xa58909bb50c9, 0xa62cd1bf73c5, 0xa9a7c7d03c18, 0xcf26d455fcb4,
+    0x1cca78323f46, 0x1e800ef45c63, 0x300b3c1cdb5d, 0x32faa961e446,
+    0x33d850bebaf3, 0x49fdfc3b568d, 0x59600e7f56cd, 0x5ddb36495d00,
+    0x64d0382ed692, 0x6bf7526e3fab, 0x90553cc78397, 0xce167af42134,
+    0xfd42d5fff5d6, 0x1303dbaaa6d8, 0x1c1b90cffd98, 0x1c8a4736e60c,
+    0x22b00af1f46c, 0x381a996e208c, 0x4a147cfd87fa, 0x4c94bebd9ce5,
+    0x68b73bf43e20, 0x6bb3b3118fe6, 0x6bf643a51ed5, 0x704df6d6a6c1,
+    0x8818864cba4b, 0xba7a4d927d50, 0xf72fea16bc93, 0xfd6fc1825cad,
+    0x22e4bd731f6d, 0x4de8f9821c33, 0x78d7b50cfaa3, 0x7abb0b8b99a8,
+    0x8de021dc7ba2, 0x994f614fe453, 0xb4015c7d36d7, 0xe678a4471832,
+    0xea1db124061d, 0xf60f187b41ab, 0x02f99a3a083e, 0x2c0e259f2e71,
+    0x318b539aa0ac, 0x3bdc6a24d7ae, 0x46d89a93ea4c, 0x7f2c7351a3c7,
+    0x8da4be9c9b91, 0x93965af6867d, 0xba90e456aac0, 0xcb2c57ff8b42,
+    0xcf6ec53bfa9d, 0xeb18b605bb4d, 0xf85eb73d85b0, 0x3aeab7a0014c,
+    0x5187902f5ad3, 0x56779b75e541, 0x6588ee82f0dc, 0x67514714e713,
+    0x78efab2b4ab1, 0x7caecbb8ca56, 0x84de193f3b3e, 0x96b572723b79,
+    0xa3f964a2d1ef, 0xc9aac41187d7, 0xca75a99e856a, 0xd8a39d572a1b,
+    0xfd5df16588eb, 0x0a86708b6b09, 0x172ab8c6465d, 0x73c35268c91e,
+    0x781befd6681e, 0x9d5537f64905, 0xa295c7e4c253, 0xa464c2aa5fdb,
+    0xb6a0fc3e5073, 0xc4202d21bd38, 0xcea9891b7f88, 0xfcac4b4f0201,
+    0xff4541658608, 0x029f81921550, 0x24b7f3c00f16, 0x255603cca1d5,
+    0x5962dc217891, 0x5ad8a1044bfb, 0x7d40ad9e1d8d, 0xafff1928013b,
+    0x213845f5c99d, 0x38a8059feaa6, 0x4a483e2e7c6c, 0x4c247d5b6896,
+    0x561fcc642c05, 0x612adb23b5a8, 0x86fb1bfe7673, 0x9bbe7415290c,
+    0xa489fd2ffe8b, 0xacc027791c89, 0xdf022316bd97, 0xf66a5651203b,
+    0x41f93b01e590, 0x573ad77d08f1, 0x6429774a375e, 0x70b754c4dd48,
+    0x73c653230263, 0x7c16cbabb7e0, 0x911612225217, 0xcd1e109c7647,
+    0xe35e44e7af25, 0xf661f5a746c5, 0x13b0ca6a7f65, 0x25ea7fd93086,
+    0x4536f95a9faa, 0x4578da411d4a, 0x48fae7a39878, 0x6d71fb71570a,
+    0xacb8c70d562a, 0xc6938282738d, 0xf89b9ec760ea, 0x04298517ceb9,
+    0x188ba5bded45, 0x2ad3c45f09e1, 0x3620b4051124, 0x54bcd0c81ff9,
+    0x9c491a1bbfa6, 0xa355366e7ff1, 0xba48c0bb641f, 0xbfe7c9662c3e,
+    0xe772342f7d8a, 0xff2a01d5b7fa, 0x3e30867461dd, 0x619a993f9f77,
+    0x723731b39cd2, 0x95233aa96f69, 0x9a34ab814431, 0xb7db915c2a16,
+    0xc87028425d82, 0xcdee7b08ec96, 0xd7e745ec708e, 0x069f6d28d244,
+    0x0e374cd657be, 0x1ac2310db2a7, 0x1dbb39c9ade2, 0x6607334a306d,
+    0x686d6159b39e, 0x6f4c6d9d0350, 0x764f9e25e298, 0x7a2e153d78aa,
+    0xcad422bda304, 0xe485b1a2b1e3, 0x216b5779acc0, 0x30ef446d02e4,
+    0x82e67d6c6588, 0x8b063cf54e1c, 0xb932db65f068, 0xd3a8e8335b4e,
+    0xf361ce857934, 0x324085ee3731, 0x5ed041f32b7c, 0x7068ee11fedd,
+    0x71ca573bd0ca, 0x86a1d07c7f1b, 0x9dc688e73bbc, 0xaa8a623b3342,
+    0xc41966c3daee, 0xd7e8379f75e6, 0xe3fbb4350d1d, 0xe8298b297536,
+    0xed5b3cc7777f, 0x0dea7895b372, 0x1c9b0950368a, 0x4983fb37f4f3,
+    0x537957ba2260, 0x65d8cb578b7a, 0x789c6fa8d3a2, 0x7e8a28835c79,
+    0xa0fc45b22be7, 0xc66905861ef3, 0xc8a8509bcd52, 0xf819faef761f,
+    0x159b5d2aa9d0, 0x16697689f829, 0x254f18e1b5c9, 0x477dbb40df34,
+    0xa1ba3768dca0, 0xb51b3c19a638, 0xc0ea19147a93, 0xcb42bc0e6ffa,
+    0xd301f3d3d5d0, 0xd9599559cdf2, 0xf2cf35def533, 0x0b149b5822fa,
+    0x13a08d22b48b, 0x196e05370fe1, 0x22f4ab5254da, 0x25d699a1095c,
+    0x44806fe9725d, 0x4d6179886632, 0x86b3af872f65, 0x87739a431808,
+    0x884d5e208ca0, 0x89e70cb9e9db, 0xb3f90b2408b2, 0xfe29ba7d4ce0,
+    0x15057aafd906, 0x3d5f98c1a10e, 0x5a727f24f210, 0x99fa416b57bc,
+    0xaeac370c53d9, 0xbd206448e3c9, 0xcde788a71246, 0xee0c66abd364,
+    0xfa0101d55fb8, 0x0da3e76ca6f0, 0x1926985cfa0a, 0x1ddaefd2a29f,
+    0x2ffcdb26fbb8, 0x39c52799b88c, 0x3c5d3374257f, 0x58c8e371fcfa,
+    0x63857c90cbaa, 0x979fd3dbf6cd, 0x9c1543ae6cc3, 0xb54a2818333d,
+    0xbdbc40c138d9, 0xdbd5c0826947, 0xdbe6e86919fe, 0xed89a51ceb3b,
+    0x1185a85889cb, 0x14aa273acd3c, 0x2f3873409bb4, 0x413d1712124d,
+    0x73e726e2f47e, 0x8bbadcb1eed0, 0x8c49994fff00, 0x9d1eec519333,
+    0xa77fcb68584e, 0xb1790b69e7d0, 0xd71858b5d5ed, 0xe70e6a716b10,
+    0xf2093b865b10, 0xfd5a0eabc468, 0x003d21c595c3, 0x0a8b799b8789,
+    0x21c920ebb90b, 0x50790c0b4b0d, 0x855c39dc3fd6, 0xeb76e66d942c,
+    0x0496fa004d9f, 0x0ab8da3bf9ea, 0x0c6b029cc07c, 0x15181f6996a2,
+    0x47057850e0f6, 0x64d5ffd9e0e9, 0x82e659422a8f, 0x84c0c3f6e891,
+    0x91792d5bbf9d, 0x9b58943bc32a, 0xa997002e0743, 0xbf642574ea0e,
+    0xc7f55a190052, 0xe5859aa4ab08, 0xfcbb7af61860, 0x10d3f3b6f522,
+    0x14579b0298b3, 0x180817cea718, 0x34aa38c3dbb9, 0x3f32e5108cba,
+    0x49a17dacac01, 0x5459839f074d, 0x573962e687e2, 0x619fbf65d775,
+    0x767b9143f365, 0x76fd097ab4f3, 0xa3b87634f739, 0xa52eeac4dbf2,
+    0xb2d39f47faeb, 0xd1e798cad8fc, 0xee39aa362a47, 0xfcfa037d3b78,
+    0x19a07d0fddb8, 0x1e52506046d6, 0x25e85b959e28, 0x4585cedd07b6,
+    0x4b7a9f094368, 0x5429904e47b6, 0x591afaeef4c2, 0x6b2ef22eedea,
+    0x6d49de7006ee, 0x834a7b6afbde, 0xcf11306d40f7, 0xeb94356764f0,
+    0xeba940cd77bc, 0xf64efa0e0578, 0x48e94b6479c9, 0x8468ba562990,
+    0x8d7031d0e6ad, 0x8f60ccb6d454, 0x9fd4389ac32b, 0xadff33101cdb,
+    0xbb2ae6084fbf, 0xdece9d9d3b45, 0xe3727cd94472, 0x24c1e8048f06,
+    0x329630bc5e0f, 0x36a6859edde3, 0x36ee590bf185, 0x40bac38d1633,
+    0x4217246c6932, 0x552854225539, 0x5d3f6270628f, 0x6d900968e46e,
+    0x76301c5d71b7, 0x8a9b635c770b, 0xb0e46b331e26, 0xc52560d6f81d,
+    0xc755930d29b2, 0xeac76cd72b76, 0xf69b8cab8484, 0x126f59b26507,
+    0x325d1712b7b0, 0x327939ca7d57, 0x462afdb23b94, 0x52d488c69b9b,
+    0x5f4b1144f507, 0x9079a43b7391, 0x942936cb6ae2, 0x951aff523d47,
+    0x9c71733750ab, 0xc3c14efadcf7, 0xec649fd3db3f, 0xed170b996f7b,
+    0x038e25fa1109, 0x0d1924b2c3a1, 0x253e4965c3e7, 0x6003fab126d2,
+    0x789acf0e5a8d, 0x842337f63de4, 0x891308adc28d, 0xa38d3389954d,
+    0xb43b69024ee9, 0xb58afa985ed0, 0xf1348056342f, 0x18448a3a76c8,
+    0x216391408628, 0x285e85ac95bc, 0x2d414c8bd188, 0x533711064487,
+    0x6cdc0b311613, 0x91176b2df2cc, 0x92cf10f743d6, 0x96a586810ff9,
+    0xb2c6deec945b, 0xb2ef5bcf9190, 0xe16f782b6dbd, 0xe50740a10d06,
+    0xfd38bf7f44da, 0xfeeaad5105a7, 0x09cbdfd36f77, 0x18d1886254d3,
+    0x3c7abd5fcad1, 0x63d2d54026d1, 0x8176fc66c77a, 0x865e567276aa,
+    0x9638ba9451b0, 0xa66c8f27fc99, 0xa89bd722171a, 0xab7f99e8f9c4,
+    0xadbb017f460e, 0xc995ab48d3a7, 0xccbbc4143f7b, 0xcf02267ba0b5,
+    0xcf2107e7ab98, 0xe02629acf5a0, 0x1620de3374c7, 0x470bf65a4d4e,
+    0x6f455e87dc4b, 0x9d1608616f3d, 0xa16126d8b9d6, 0xa5479f7e19d7,
+    0xb3516ef12ef2, 0x5fbb950fec28, 0x7369005a3cc6, 0x7f251b853807,
+    0x8ea1f126db50, 0xad9659909d2f, 0x0b2e753f4c81, 0x32f57e1596ba,
+    0x34dd67c0a923, 0x41aadc8b92fb, 0x422418a584d5, 0x425c98a415e8,
+    0x5a9bd107a7e8, 0x6d5ae22c7cac, 0x97d7ee0d27a7, 0xb55950485b58,
+    0xcd55f8185e69, 0xe7a228a8a97c, 0xefaa58a63fcd, 0x37abfa946f3f,
+    0x68214502ac77, 0x77835746acb7, 0x811c4a13bf40, 0x9255933a2cbc,
+    0xb3c8de09e0e8, 0xc462f2589ecc, 0xd99ffba74ec9, 0x13d7a001b2d3,
+    0x1dbe935174c5, 0x2e736afdd5b2, 0x343ff8d619f4, 0x3c6d375556ef,
+    0x7b52cb80c202, 0x87c3d69690f6, 0x898f62fad7f3, 0x9b2306cf2c66,
+    0x9efe4a1471b2, 0xadd92c2a2027, 0xb85b70d22f9c, 0xc28fb4f78fa8,
+    0xca7b4d1f54c8, 0xd1baa6972173, 0x00c6fb6bce59, 0x03a122bbbeb1,
+    0x0b65d7e9aeff, 0x0e57c953f75f, 0x137ce637bc55, 0x20ae8bb6853b,
+    0x48078dd2f84f, 0x53fdfd74b2cb, 0x711d3e033b5b, 0x79a33d4736ea,
+    0x8002a5cbf336, 0x80857d5cd762, 0x829b26c46bb3, 0xab84b121553a,
+    0xc64ca1f0d19e, 0xf201002f28ee, 0x07994e67d6f6, 0x40bc9fdf0168,
+    0x4a8093b4509d, 0x4cc8ca93dfff, 0x5f8223c12486, 0x74650cf0f351,
+    0x7b892ded1169, 0xc4949d0b710d, 0xc7626a3029d7, 0xef4e0c6a0f30,
+    0xfe3a16666e5c, 0x0aacbb6565b3, 0x162f31c6b308, 0x3e1e7cf0f4b1,
+    0x4c18f8f5873d, 0x4ef52f4cab82, 0x63f7e4249c44, 0x7063e6efec4a,
+    0x7d42f8bf9263, 0x7e6121a8c412, 0x8cddc591297b, 0xb8fa7291c7b3,
+    0xbddc4f34ec6b, 0xc1c3b2166380, 0xd3a5cb8b72e6, 0xdbd25a5d9e92,
+    0xfc6629584de9, 0x1123c9777045, 0x45d812c0c64d, 0x554853a82c43,
+    0x58cd1fbeecad, 0x626c3fa19afd, 0x6c4c90bdb59e, 0x6de8fe0b5815,
+    0x74bd849b9e61, 0x80c84ff65a5a, 0x843f27f8bad3, 0x9968853b7aa2,
+    0x9d62a9eccad1, 0xa8dbf9f5315e, 0xc11873a47e22, 0xcbb5b6393665,
+    0xddb1e35ed785, 0xef9b142599c6, 0x02701aec986a, 0x31396a0faad7,
+    0x3b6955979d44, 0x503e854211e3, 0x625b684bec0e, 0x65d539919788,
+    0x77948e1b39f6, 0x7ee5bf26c8a7, 0x82c1778a197d, 0x99a7bd6faf09,
+    0x9ca93cd77fbd, 0xad7862c37829, 0xf3bb4796a3b6, 0x19f202bb7d41,
+    0x510e891b8a67, 0x5755ecf6e8e6, 0x5afdb8881c0d, 0x70ace69f0b09,
+    0x7fd84d5ca85e, 0x8ce29ac390e8, 0xaa0a51fdeef1, 0xd521154dbf0c,
+    0x065aa3155286, 0x0db868db1e8a, 0x280374a04cc4, 0x2e77e88b1bdc,
+    0x8f5c8ae1004a, 0xb6a6e93c021e, 0x5714e7c1749b, 0x5d3bd0479fd4,
+    0x7119ba02b796, 0x88b0d1c4b8fb, 0x9caec1b6f879, 0xb03c61592720,
+    0xbecadcd54e8f, 0xce5397841ddc, 0x1b86b08149d2, 0x35faae4780cb,
+    0x946e624bd2dd, 0x94e4a52279b6, 0xac2c5d07a914, 0xb6ffd604b8b8,
+    0xcf1f7d4f2e86, 0xef4c2252b3ce, 0xf8b9d98883e6, 0x14f87976eac8,
+    0x177219035862, 0x25d0160eb8ad, 0x2fbf0aec448e, 0x4c044d0e52c1,
+    0x66daa7103aa0, 0xb2f80dd00a60, 0xb37b1feff451, 0xc557f68b7f04,
+    0xd2fd954ab511, 0xf8d980a39c1b, 0xfda3589d6306, 0x03d74afbd71c,
+    0x064ea0f20b04, 0x0941077a5eee, 0x2942ab75a78a, 0x2f0bcaec9541,
+    0x3e6e524ea10b, 0x4a214720b584, 0x52388ffdc89f, 0x8741b68c7b26,
+    0xa00d2768dddf, 0xa4f655ecbb37, 0xb98a545dc385, 0xf0a331cd745b,
+    0x1cfa6dd3d793, 0x62130a219fa8, 0x7f8a2138cfb8, 0x891ad7e9128d,
+    0xa5fa4698516a, 0xbd2b592e4599, 0xc71e6c1a3954, 0xd49c78328940,
+    0xef08aef9fc0f, 0x079572c231ee, 0x359ba3570af2, 0x7a3844d69bf1,
+    0x91d96d2d9b32, 0x97353597c313, 0x99db701595bc, 0x9cf5a3d3d58c,
+    0xccc133b759c6, 0xf601aa60d3b9, 0x1e91c44bed5d, 0x27580a8d3e29,
+    0x340ffed409ab, 0x873cb5b8ea7c, 0x9880bf216723, 0xc38f80e36d4f,
+    0xdc4486ff9a9e, 0x1c03a4797484, 0x43d0da655a84, 0x592bdae3fe50,
+    0x60e9edde474d, 0x742c4bb20bc7, 0x22235120e320, 0x284f7c80930c,
+    0x7d39cbb55ae9, 0xa411902a1234, 0xd1790105472a, 0xd6bf837a567a,
+    0x3cb4374ded12, 0x5863a580383a, 0x81214498ce01, 0x8dd889328834,
+    0x97564383ec2a, 0x9f5d14275fdd, 0xa268699528a8, 0xaf23571f3f2b,
+    0xb1276382173b, 0x30926787e663, 0x5a27f605c29a, 0x86bae5dd07ab,
+    0xf8834466ebc7, 0xff141598864b, 0x02798b253048, 0x11b7ee41ac7c,
+    0x22de759840de, 0x2bf2bc5c4113, 0x307d3794c9d5, 0x4825773d7bf1,
+    0x550e5f131a21, 0x575695f2a983, 0x773bdca726b3, 0x778b3c83f8ba,
+    0x8909d4f23a61, 0xc24d672f9254, 0x1b829a4c9f05, 0x4a3e6a795d0b,
+    0x5bf791ed63b2, 0x7d4817d1aae6, 0xb93134dfc433, 0xda6a65c7bee9,
+    0xe0c65feb24aa, 0xf589f290dd08, 0xfca90b427c32, 0x0a4cd5898417,
+    0x1b18c7a32bbd, 0x2b91b0efa50c, 0x42452ece39c2, 0x552e1c302e59,
+    0x91a4216429ae, 0xb26b2d83e565, 0xd8c6820c5a10, 0xe42f59db2135,
+    0x395d23df8f15, 0x535c039a3c0e, 0x9346221c52a0, 0x96493b6d4bb7,
+    0xa28868b830e9, 0xaac7f3e93b74, 0xac6e71cbdbc7, 0xc330cdfae782,
+    0xc85f11379340, 0xcacd7b63e625, 0xd39ce7fe1db9, 0xf1160e1c81b6,
+    0xf80dceb64084, 0x03515d745b3a, 0x09942e234655, 0x0b3703158a58,
+    0x0b482afc3b0f, 0x156086f8db39, 0x18ead078261b, 0x4ec7533fabae,
+    0x508401537ba6, 0x560a1b18cee4, 0x72d7ecc35180, 0x731f108353d3,
+    0x7b0c7d23471b, 0x7c390f3ee445, 0xbcc7a5722f22, 0xc3348ced3fd5,
+    0xdffb81d5155b, 0xe54f83407912, 0xf0041ad17dd3, 0x1dd27b14d713,
+    0x3f69af9b1510, 0x605ad286f65f, 0x6bdc241d26db, 0x7329ac38593c,
+    0x95cf068c350a, 0x980e51a1e369, 0xa414feee3788, 0xaa2b34abbd94,
+    0x0852821688a0, 0x1014789033b2, 0x130b7244fb00, 0x1c574ecb7534,
+    0x1ec285257753, 0x22fd2b632284, 0x24c374ede4ce, 0x268c429de68f,
+    0x2e61aa947c0a, 0x38e21ac45d57, 0x3a0636342508, 0x3c0e9b346ab7,
+    0x3fcdf650f021, 0x60fdc650fe4a, 0x6c67c2eae248, 0x742dd772f534,
+    0x8861c33e864e, 0x8cf8d3314c63, 0xc4d1263eda52, 0xc7d689260d1b,
+    0x150006ac46bf, 0x1ae633171131, 0x1b15179eaff2, 0x1e186b7eaece,
+    0x3691bdc9fa44, 0x3a6d762d4b1a, 0x48a2bbc6a86b, 0x60c8cab5bfc5,
+    0x6de8d32fcc6a, 0x88483aadfc21, 0x8b668c7aa3cb, 0x9031893f878f,
+    0x97e3f1bbaa4d, 0x9a60ffa96e72, 0x9a76459e8703, 0xa3603b075bdb,
+    0xfbad068582b4, 0xffad1dbd68e5, 0x18ae8a731d3a, 0x2205d6e8b7e8,
+    0x2aeea7866fe7, 0x2e20a55f07bf, 0xbe0c59b01460, 0x01a1b224b4a4,
+    0x03b8bae66b93, 0x356b4a38eb22, 0x6690f16588a9, 0x9f40beba517e,
+    0xefc05e5eb290, 0xf886a4a0035c, 0x03885277a072, 0x139802c5bbc5,
+    0x1a974fcf38f8, 0x207b6d32cf7d, 0x3dbed206e7a3, 0x4ae5f1d2a723,
+    0x6c7524cb1b31, 0x8ffdde735de9, 0x99dfc978a0ed, 0xa1304ad71e4f,
+    0xa6e5be421bd8, 0xbd37ca2116bc, 0xda31c19ef2dd, 0xe479ec5f48dc,
+    0xea481420b581, 0xf3749a1d1948, 0x30aaff2b08ca, 0x49286f84bc1a,
+    0x606c43ea8963, 0x72457195b7c6, 0x734185408dcc, 0x7e462c5b75e3,
+    0x8b775cbc333f, 0x967d63313df4, 0xf419d77d5ae5, 0x03ff70ff50a0,
+    0x0e19dc0324b7, 0x14c7ba289bf6, 0x1a355a2685dd, 0x1c808a496040,
+    0x1fdee8845762, 0x252fb61d6a53, 0x4020d37cf53b, 0x550eb77dd8f6,
+    0x55d1d60c125f, 0x7e4c34e407e8, 0x967452da532f, 0x97c4598e6ea0,
+    0xd15ed81856ff, 0xf310204f26b6, 0x189902dadf69, 0x3eebe0b77e9b,
+    0x409e0918452d, 0x491b91b06b43, 0x7031538c3e19, 0x7f4d66db58df,
+    0x88d9c4ee2e15, 0x9f190efd4fdf, 0xa0cd0bd64499, 0xa7409584fc9d,
+    0xa7485c83c0c7, 0xac470b8bbc75, 0xb5c36682fdcd, 0xbc2886ff4a56,
+    0xf121e5016ec4, 0xf46e31199e1b, 0x02a33c23f5a7, 0x25281b229e2f,
+    0x2a59ccc0a078, 0x2f942f997dff, 0x3a93ce69e728, 0x6210f4cbe9d2,
+    0xb613517fd761, 0xb79f4693da70, 0xe801cf323e8e, 0x28861a4185ca,
+    0x380205a711ff, 0x94d52e4f59c0, 0xb95aab4f83cd, 0xe2a9c5ba6f00,
+    0xe7523822eb91, 0x279f628dc458, 0x3db99e1b3f78, 0x66c99653f247,
+    0x6c7ecf2fea0b, 0xc0be799de1d6, 0xfdebb852ea90, 0x1e3828fe91cf,
+    0x247ea29dd93a, 0x518b43ad1baf, 0x71199e150873, 0x9a0b29ffbbe9,
+    0xa4631d4ca001, 0xa9b46003be7c, 0xadeb5d510d5d, 0xc5ea4eb74a20,
+    0xcae46a92d26a, 0xcae888a13a44, 0xd28745116cd4, 0xe2a1b5a19752,
+    0xe821dce0548e, 0x127d0e11a9a5, 0x33e76d177cce, 0x3c3c3e3d9fea,
+    0x587142b5147a, 0x89bd57bd7b49, 0x8d8cf0849a56, 0x90549093b759,
+    0xcff5f16ca335, 0x01a671b7eed7, 0x25de28c46f40, 0x40fb6bf753ad,
+    0x45db39934478, 0x4c93d7facae2, 0x5db72b7f0e7e, 0x642537c53c0a,
+    0x662e870198cd, 0x804655a1baa7, 0x8a05f0d99c3d, 0xcc22c5b723db,
+    0xd18cf753b737, 0xddc5bcf9fadd, 0xe945af3608bb, 0xef86364eba24,
+    0x35ce236c649f, 0x39f592bc2b2c, 0x48b3a26d02ab, 0x53139747b0b2,
+    0x749510baca94, 0x76568c8a13b5, 0xaa190d4c9758, 0xab0e7ec3c60d,
+    0xfbfa50a9d01c, 0x0382dce8d307, 0x043ac616f1bb, 0x0499b3f14c16,
+    0x17fbdd6d3287, 0x229c53d43b90, 0x60a31bd7b31d, 0x9d545fbe84c1,
+    0xa7368552cd8a, 0xad7f0df948e2, 0xf0544122fabe, 0x03f258fec8cd,
+    0x2d5bc1a94b7f, 0x42642e78cc7e, 0x71e232f7acd9, 0x9ceb02331d03,
+    0xa8feb957b9ff, 0xbf39aac96e2a, 0xfb7ce7f668a9, 0xfdb46c0d52de,
+    0x0ddcd0b1dd4d, 0x1007fa9d8ff4, 0x12c67453c62f, 0x3101943546bc,
+    0x5010fa8bb169, 0x5fcfeba2d817, 0x69d54b409962, 0x6e2412a8404b,
+    0x9a6a9be5fe56, 0x9d61959ac5a4, 0xa805ef8130c2, 0xb3d9251972bc,
+    0xc9e7b628c79d, 0xf4895b6d0db8, 0x1dca47349335, 0x478100b4b401,
+    0x54abc970cfd1, 0x59a2b17a0755, 0x74bb6180785e, 0x98a4a2ec3dd4,
+    0xa7accfa062a7, 0xc1857c0e4c1d, 0xd0dbe3d4261e, 0xe846d5c2cdfe,
+    0x0371d27b0c97, 0x1d871cf5eefa, 0x2be3801826e2, 0x36f89f6cae61,
+    0x7beacddead2e, 0x835a6b383b38, 0xa4fcd51e93ea, 0xae9b0ac52b26,
+    0xc9f7aab94dbc, 0xf3a71258b5e8, 0x112540c198d3, 0x16f48d4e2251,
+    0x80b73fffaf93, 0x85fc631a9c45, 0xaed4c590d515, 0xb70a402ce1c4,
+    0xbd7f63c4c22b, 0x3e966315bc1e, 0x3f41f218a344, 0x4f56359331fb,
+    0x7a25d522ffc3, 0x8eb072ac1b84, 0xad0aae88acb9, 0xed18b6c14d1c,
+    0x0153b9de9111, 0x049496079fee, 0x2874eba98461, 0x53e3faa00786,
+    0x54d1a5187211, 0x598a1a9c8280, 0xac8aabae09cc, 0xb618de390d2a,
+    0xcdacfcb7c38e, 0xcf4dfd31d969, 0xd21a306d69d0, 0xd538bcc9173f,
+    0xf1ebcb15f6d2, 0x0ed87e2c8451, 0x1710b77cd63c, 0x2ea794afd1dc,
+    0x5a55162b7c16, 0x67e95274fba7, 0x795a6bece8e7, 0x7cb227f438b8,
+    0x8c6aebf5c39f, 0xa0a629a20d59, 0xbb6394be8057, 0xc01a35ca629e,
+    0xcaa8d49da9a1, 0x07763b3c40ec, 0x19a271b49da6, 0x1e09b2e3ade6,
+    0x44548f328329, 0x44e52048c181, 0x512e98b7aa54, 0x5b34a8027cee,
+    0x5b9111b797d2, 0x65a0475b5134, 0x981286dab3a1, 0x9d6efef1ecd1,
+    0xc0fdab20c58b, 0xf5d235304914, 0x5365f8418ac7, 0x5403933011fc,
+    0x57051297e2b0, 0xb65f9451f237, 0xee777eafc414, 0xff0c8ab4030a,
+    0x3c6fc5425d60, 0x58c78ea53ee8, 0x5b54651f9126, 0x73d334d36714,
+    0xab0f4c4c906c, 0xd3e5da4a9b14, 0xe144ca67da58, 0xf9743a3ede3f,
+    0x3d9ef1853604, 0x5e4c5f126b8b, 0x607be19b8bd1, 0x798131d0a23b,
+    0xd2573c844ecc, 0xf0f77755c57b, 0x13c5fe39afcd, 0x1dbc44f7f44e,
+    0x4b11a3b06179, 0x6f19516a2648, 0x71d5f6a82e5b, 0xa2157fcc1fbc,
+    0xb1c8514714a1, 0xb8adc52f05df, 0xc8cfc22eeec2, 0xcc15e1318252,
+    0xd246da4cab67, 0xeea66aae5119, 0xfa7c246bd28a, 0x00de4ba4d412,
+    0x1dd967edcd0c, 0x369759d3db5e, 0x4adbf86811a5, 0x593318b0c4da,
+    0xcdda31b70cb2, 0xce157069e301, 0x0a0e1b7584a2, 0x0f0035c342fd,
+    0x43d918703425, 0x63a7f4647beb, 0x76076801e505, 0x7c97c41573ff,
+    0x88ac6576280f, 0x9e07a083d1a0, 0xa0171cd5ca2a, 0xb2a833af1741,
+    0xefb1c33c9bef, 0xff1f456fbca9, 0x08ecd4bbfe30, 0x20bd56b8a7bc,
+    0x299af1f64506, 0x2e636a95e953, 0x3dbdf06a2b2e, 0x45ada6a05828,
+    0x5e94ffa57ac3, 0x72060771d839, 0xa7a9cfabc6f4, 0xb34410276c51,
+    0xbb6c465c2a5e, 0xbb7cbe95c9c6, 0xbceaf708decb, 0xd76178f4553b,
+    0xdb4a00a0e929, 0xf1673571af4a, 0x0a8c514ee7ab, 0x2b022919a333,
+    0x312da4cc41d0, 0x4b044232f759, 0x8af209a56a3b, 0x8f9fbee76b7f,
+    0xb5e383e48de7, 0xeb2405a6b4a8, 0xefb941214c95, 0x0a49619f4935,
+    0x10eb20288eab, 0x48a7507e56f3, 0x538cdb9e2e62, 0x9c2625f44f07,
+    0xc6db022679c6, 0xcee038e0c516, 0xf3c04b1ddbdf, 0x8c51ca5b0606,
+    0xa8ec996f8207, 0xa9810e052274, 0xb13ee67065ac, 0x1af9d2232ec4,
+    0x1bac3de8c300, 0x4cbc64914231, 0x7f0870c3e11b, 0x945e2e690034,
+    0xb8f2149b95bc, 0xcc2852d3286d, 0xe84eee183182, 0xe8e0a3f98cb3,
+    0xee8c7bed97ea, 0xf5cff373cc6f, 0x68816949a7b2, 0x9624c884c446,
+    0x9df47883c984, 0xa7a9c897a779, 0xb07c69042fd3, 0xc8da4844c6f1,
+    0xccc2cff15adf, 0xe25c7d842b85, 0x1b47146dbf1f, 0x3a7521a12eea,
+    0x3c77941ade97, 0x62d0647e13cb, 0xc198bb38bc97, 0xd4e02156ffff,
+    0x0955486e1da3, 0x344b1b4aaeee, 0x3e0a7bf38abf, 0x493fa17f39fa,
+    0x494eba5eb6c4, 0x4b30b183332b, 0x4bed2dddc543, 0x6448c38822af,
+    0x9e3bc847c3dd, 0x9ee582d27cdb, 0xa26f5733bc33, 0xa8d64c2836e4,
+    0xd4a2af0fec01, 0xee41b6b4278b, 0x22326c5138a0, 0x5d6206480ad6,
+    0x5e27a8fb83b6, 0x8cf5162cfdfd, 0x93635d02314e, 0xc81905a5a9f4,
+    0x1e682c659a49, 0x32101a47606f, 0x3691a9b6082e, 0x44dce4f18f5f,
+    0x75610db0439c, 0x7e5acba5a68d, 0x8124402cf1b8, 0x9b4dabd1cfd3,
+    0xbdd7cda9fd0e, 0xe6e6dba698c9, 0x08ca76c65dcc, 0x1aa0361035a4,
+    0x52bb19b15282, 0x54d84f88a538, 0x56d50a0ac4a8, 0x73555050a365,
+    0x87c90dfb7e32, 0x93a987fb0ece, 0xb92685799b7d, 0xcca03e80d431,
+    0xfbbd0b8f207f, 0x0de1b597bed4, 0x125d527fd091, 0x1c494e1a1171,
+    0x552d7d5f037f, 0x80d5bc012906, 0xa52d0426c5a1, 0xa761546b5f10,
+    0xb5e54a347d19, 0xb8b8cf50c620, 0xc8ae6bee4fb9, 0xdad9f2b99b24,
+    0xeafbefb98407, 0xeef1bbcd6697, 0xf58a8e8ccb0a, 0x00bae65d011c,
+    0x091b62014a77, 0x0c7442d3b721, 0x2ad05328767e, 0x35f0a7dd18b2,
+    0x5524e2262444, 0x64e7b6bcad07, 0xeffcfe59b56a, 0xf6f534117fc2,
+    0x0378514a1681, 0x15f1d8981155, 0x484954b9d0b9, 0x7321a58479bf,
+    0x78c3a7728cdf, 0x9b06a58ab7c7, 0xc34934112d8c, 0xc39cec8b6d32,
+    0xce3a69af2b3a, 0xa40d0e3c540e, 0xa895ef8bb46d, 0xede4fcd0d9a8,
+    0x123826e80e69, 0x16bb503fde8b, 0x58ad5ea42f42, 0x6718a016de2f,
+    0x79cf751ee33f, 0x7b8f570503fd, 0x96a19f66d37a, 0xbd027157d29d,
+    0xd434a8b8e3a5, 0xd92a3167f88b, 0x15a8ad47c959, 0x3a22ba58d2ec,
+    0x3a245441fb4f, 0x4a259b5dab27, 0x53f36538f273, 0x8dc5eea3605b,
+    0xb962483859de, 0xcad39c3f4ce3, 0xda2bd87d550c, 0xfab76b5b34af,
+    0xfbe34dc9c08a, 0x1323ee5d3d0d, 0x287c30279b9d, 0x5ae583dd1d07,
+    0x7ac96b377799, 0xd1edfd376ee7, 0x4a84efcb034d, 0x547cd0727031,
+    0x7a5bb50ea23c, 0x856c4136b657, 0x8681f374128d, 0x905ba25c85dd,
+    0x93ab224705fa, 0xafbb8d5adf6a, 0xd37e60eadb98, 0xdb2a61c25cca,
+    0x0f9b6acb1294, 0x461c9fd2d237, 0x48aa9b18414e, 0x6c2401520177,
+    0x9e4756129d67, 0xa956f7fe9a6e, 0xb1a17e00b9e9, 0x6ad1c709a857,
+    0x6c48010a874b, 0x9b7ac3bafd79, 0xbc8fd05d6899, 0xbf6021a760da,
+    0x1af1e75cfe6a, 0x29d69f78a4f6, 0x2b42c8e4860e, 0x45de1ec29d63,
+    0x4b5c36fa26b2, 0x7014b690575a, 0x7f4c77792c3d, 0xcbdc03013afc,
+    0xdf8eb125104d, 0xf25dc56578ef, 0x1639c269efc3, 0x29d2976c391f,
+    0x2da947850b07, 0x8785b107913f, 0xb43d74d17735, 0xb4d2991428f1,
+    0xba0360761426, 0xd3c910851ebd, 0xf901a8f7ee55, 0x3dcf789557c7,
+    0x440d40f9c3f4, 0x674b2df1a809, 0x71bc85420c8c, 0x9995daca2455,
+    0xab160d218e5f, 0xd13538bb15a7, 0xd2e293606310, 0xf2e88ff9194b,
+    0x499161b9df48, 0x5aaec2b78ce2, 0x7d79a0ab1ae4, 0xbfd1048e6782,
+    0xf4f38f209a74, 0x088cd940ef5a, 0x3f653524452e, 0x40d78ba5c20d,
+    0x476bcb38b31c, 0x72c0c67ea487, 0x89d5413ec785, 0x9118f35401cf,
+    0x944c5086bc45, 0xa1845daa26b7, 0xf6925c0672a0, 0xf82652a83f9e,
+    0xf964f6e6a493, 0x635d6ae27dac, 0x7a157bed85c6, 0x84d4993193ed,
+    0x916535607f13, 0x96e731176a77, 0x98ecd7636aea, 0x9fec5efbede2,
+    0xb2b371ae8c95, 0xbf7af3f2e06b, 0xcdf045fa8d34, 0xce8d6bcb08df,
+    0xff83b951fc1b, 0x188d27957a5f, 0x213ded52acd5, 0x54fff8f724ee,
+    0x5507fa84eedd, 0x5f7b264d8188, 0x8df5c540d33d, 0xbee77f9b5315,
+    0xc966907111c4, 0xd7e2bf3b6ba3, 0x073f98b80769, 0x2a95ff7754d5,
+    0x32147b2159e4, 0x3447317ccaf0, 0x3f578315d946, 0x8171993f1ba8,
+    0xa6fa7bcad45b, 0xbc8c625ee0d7, 0xd452d3c68c87, 0x21226bbceabe,
+    0x550567d4a1a7, 0x5b28324c6506, 0x759812043425, 0x783c02ebcd1c,
+    0x827c2c1e592c, 0x861678b937ec, 0x8b4fb6c6f89a, 0xa81a1a1024ab,
+    0xc49900fbe0ca, 0xd3819c96e96b, 0xd7de1d83ea80, 0xec9555fe6b50,
+    0xed2cc3d756be, 0x0345657ba97b, 0x6cdbb7cad773, 0x8c9ee940f8fa,
+    0xa5b61109d16a, 0xc436f55131ac, 0x10415fb2228d, 0x1098fbabc448,
+    0x2dda16e9a2bc, 0x65c9bef37d29, 0x828877be82fb, 0x8b74b6bd9185,
+    0x9907ce3bf43d, 0xb0239adccfdb, 0xd8bbb655b36e, 0xddf569817fa6,
+    0xdfcd8aa003f6, 0xe9c8d9a8c765, 0xec6a466b20e5, 0xfaf8c1e74854,
+    0x176d231ffb0d, 0x185f2635d337, 0x274a80852114, 0x2e892a4fdc70,
+    0x5943be79976c, 0x79d334d6d924, 0x81ec522c1a67, 0x9f4e986c3d70,
+    0xa884f46e994e, 0xef6deadc2189, 0x053b10230454, 0x05b202a6bc7c,
+    0x0950a7df08db, 0x29bb0fbaa3e9, 0x32f37ac433b4, 0x5ab5b5df04c4,
+    0x64ae0ba47d32, 0x7934c5ae36de, 0x98f572ff18ee, 0xa9c6a7f24547,
+    0xbeedf62dd129, 0xc28d10842912, 0xd86829df6bce, 0xdc5bac5d14ac,
+    0xf10f3be7392c, 0x0d0384434cf5, 0x54f906954a9e, 0xa7db2b58d291,
+    0xb0e8cfe92b75, 0x1b68aea25c1e, 0x3b34915358e3, 0x4f913490ecf7,
+    0x55e9fae201f2, 0x789213ea22fc, 0x830330ab81ba, 0xc4100b433d5f,
+    0xd28fa86eedc9, 0xf02f76f820d3, 0x0f963eb92776, 0x391efdedc095,
+    0x5b4822e45f88, 0xb4cd2afc49ca, 0xcc20c7eea52c, 0xcfb103f48610,
+    0x172f1ea4c007, 0x1bb0e8a26d8b, 0x2781d4a475d3, 0x46721effcfd8,
+    0x8a0f3e733446, 0xabc9e791f08a, 0xb6ba32f3d724, 0xba55dee8d882,
+    0xcd5f0d10fa9a, 0xdaa63313e1d6, 0x042c6e233b7e, 0x191375617223,
+    0x5fb6324b0f1f, 0x77c108be77e6, 0x793c8598db8d, 0x7d203f89f652,
+    0x931e1dd0a606, 0xca67b44023c5, 0xcb0fd4e1b460, 0xddf8129697a8,
+    0xf54a159fcaa7, 0x0800eaa7cfb7, 0x0fc0d21a46dc, 0x14b695586187,
+    0x19d2515439f0, 0x279621d26991, 0x346ae87e0c09, 0x6d74d5f1b610,
+    0x8d34d39586d1, 0xa2a1aba7eca3, 0xab9b2f0e49cf, 0xb4479c2e0ea6,
+    0xc6fc9cbde58e, 0xe4065cc84fc8, 0x05a6f2367a52, 0x10049d7aeea7,
+    0x3d54f3e8dce4, 0x45f9d498e356, 0x4dbfae91f07d, 0x4dd1fb43be0d,
+    0x55f38f44d4c9, 0xd34ea1da9fdd, 0xff8109ee6230, 0x18df1ae8372e,
+    0x3767c62e5b9a, 0x583c51446bb8, 0x7fa640b889be, 0xb7be65a56160,
+    0xc6ab59ddd7a0, 0x280c6c1ffeae, 0x409e72c1b940, 0x4c0ba32dee04,
+    0x4c633f278fbf, 0x536759ec861b, 0x5a5f55154aae, 0x5aa5540a3028,
+    0x8dab587221b3, 0xaa57ff1a5fba, 0xae242980283c, 0xd467ee7d4aa4,
+    0xdf812be039fd, 0xecca265b4f61, 0x2a85ac905cc1, 0x2fb54f272b1d,
+    0x49a9e3bdd475, 0x66d543e88ece, 0xbe7e476292ab, 0xc3a48911747a,
+    0xd10a55f160d4, 0xd1bb9cebd837, 0xe3f935d9eb6d, 0xe4fb017c51b0,
+    0xe78519425eb2, 0x191f695c7aaf, 0x1f1105b6659b, 0x641b38d1c235,
+    0x9ce58ef5284e, 0xe6c71900a03a, 0xebc3095456ac, 0xed920419f434,
+    0xf133680685cf, 0x018c85aadd27, 0x43510ee1b1bb, 0x5fe451866f57,
+    0x650e0196a7b1, 0x74dc461c50ee, 0x7fcd069c4312, 0x891c8c131996,
+    0x9330ca0151e6, 0xb233610e797b, 0x0a87094f4d6a, 0x2625150be216,
+    0x4fc8979e1e3e, 0x9ab045e96a0c, 0xa2661cc6e355, 0xa864c2f9171e,
+    0xea1097d97c96, 0xedd9c8fbfa17, 0x07c4c21bb11d, 0x0f0b32e530a3,
+    0x2112204ce0ee, 0x58f70814ac30, 0x80e94c8238da, 0x8c826832c15e,
+    0xcf6ef058bfb8, 0xf27422c30cf5, 0xfa17725fb2e9, 0x28a00567648f,
+    0x2f4c49a3b36b, 0x46b0d3edb9bf, 0x70c7a01351bf, 0x7c419fc8c99b,
+    0x8b4a7c29ffbd, 0xbbd71b948973, 0xbe1a4a2999e7, 0xbfae40cb66e5,
+    0xddc11e58f002, 0x51a9e68c779a, 0x69fe64e5222b, 0x86e9b8a18d0c,
+    0x974c71bcd6b6, 0xa4d20a44e507, 0xb4b435658437, 0xb67fc1c9cb34,
+    0xd5dec28c0dad, 0xeae10245f2e5, 0x4f39bde9f290, 0x4f90aa3682fc,
+    0x70f7d56a055f, 0x76deec10e6e5, 0x83854352f626, 0x95ec08d117e0,
+    0x9737b6e7c5b2, 0xa70f21c655b7, 0xd2a3b45c8b10, 0x084aeaf7d056,
+    0x423921fbf85b, 0x5c43ac34cb93, 0x68ff0edceda0, 0x6a7cd54d8af9,
+    0xa3989a54f706, 0xb11f57a82230, 0xf0e6b13ecbca, 0x18e2cbb2508b,
+    0x2df3af2da703, 0x3579b7476a77, 0x427476b0caad, 0x5f374d8a3859,
+    0x84bc4c968ef7, 0x8f95f2a93a62, 0xd528b54d0b65, 0xe924963816f6,
+    0x2550f386d081, 0x2e226f283c02, 0xb782952132d2, 0xd99305c162a9,
+    0x06f18ad2b69c, 0x43e38ad4e907, 0x4a23d75e94ab, 0x52adf4b0f814,
+    0x627b4efa8a3d, 0x67d99b89f195, 0x75ad3494af4f, 0x816a39fbc1a4,
+    0x90d1548a40d2, 0xfbdb5cb50e47, 0x3bcea1900ba1, 0x471aa6f9fbd0,
+    0x83bc97724ae5, 0x857e885f9f90, 0x87a6b9080736, 0xb2aa7ff8f872,
+    0xdac41c7e6578, 0xeb84d937f269, 0xf1d3546503c3, 0xa5e9063d36a1,
+    0xdba791d4c865, 0x09b4649d48ba, 0x26d95d236187, 0x455d753d128f,
+    0x7d5d206280da, 0x8146924b2bdc, 0x85ccb4e646ff, 0xa90cb0e55f01,
+    0xb57a130ad1a5, 0xbc53dc749ca4, 0xc3e9e7a9f3f6, 0xc8a67b3c6c3f,
+    0xf218cfb0cff4, 0xfa7d2ed47b64, 0x1c655d20b3cb, 0x3d74b1cb8eae,
+    0x59b7e4e668f4, 0x6850e61599c9, 0x8defbe437c5c, 0x98e9a51c5548,
+    0xcfa9c1c7478a, 0xfc7c48eed089, 0x0579ea63958f, 0x236aed41c8c8,
+    0x4ea31622e33d, 0x77319624d47e, 0x7bd958e03fc0, 0xa0ed927e79fd,
+    0xef2e96ba3dff, 0x1bc111737786, 0x74b931657fc0, 0x7512a1d74fa3,
+    0x7b21c04322d4, 0xfe18e2406b06, 0x2e99620ec2f3, 0x5a73b90ad801,
+    0x7384792f0ee5, 0xa4ffad4e1a3a, 0xb82a410786ac, 0xc7869b53f6af,
+    0xfb189da5b32e, 0x1f388a97db8f, 0x22cfddef6f4e, 0x39fdbcb312b7,
+    0x46ea880812fc, 0x490748c15a28, 0x94b842b07b26, 0x9b848d23f1be,
+    0xc0eda407887a, 0xc4502050e776, 0xd2367b2b7e4a, 0xda537c0021a2,
+    0x008653a59918, 0x0fbfae77965e, 0x8a99cfa03b38, 0xb8ebf1b08fb8,
+    0xbfc50b6d4968, 0xcc84c6b2d914, 0x026442bda9a8, 0x0df7e105a7b4,
+    0x27077c5ec1bf, 0x58405a7943ea, 0x64dbf1794400, 0xbd5a60334ed6,
+    0xce7b2f9252fb, 0xd97466be1a98, 0xee0b5e726de7, 0x00fcfd0f3dbc,
+    0x1251f3cf6555, 0x20c02e855f43, 0x2451c9e562c5, 0x3471b7de17bb,
+    0x749a0e564f9d, 0x7ca39dae088c, 0xa0ee511967d4, 0xb1c7879a5e1c,
+    0xce3afe96f9c1, 0xef542947ccbb, 0x25787f7c65f0, 0x4ef34a9c9f1e,
+    0x6b2d1ccf8cd7, 0x70259ec1ecbe, 0x73e6ce56a050, 0x80ae8b29f9eb,
+    0x1924223e6430, 0x3b7f9a1df86f, 0x3d665efdedff, 0x5618a665d612,
+    0x7b6d2701658c, 0x7bf62ba7e57f, 0x853e99cd0928, 0x0a27065d5050,
+    0x22c52c9b3cab, 0x662155643a8d, 0x98935a549735, 0xbd7a0ec5554f,
+    0xd1fd5a6db870, 0xfae3b0f85131, 0x2d678d7c6fdf, 0x51d839a58ce5,
+    0x6919c475207c, 0x761483de80b2, 0x93167cea26c2, 0x93b6616ee7a9,
+    0xc22d1ce2d749, 0xf363b1671fc2, 0x5f8f50dc7571, 0x741cad19d66e,
+    0xa8d3efa67777, 0xe3202a48e2c3, 0xe86c2a267c8b, 0xfe39c48b6ae0,
+    0x3bd78e1f8a36, 0x4af959663539, 0x565b19e34983, 0x82d8fe547bc8,
+    0xfb42a64999d0, 0x15dbb2917773, 0x197fd5324e4a, 0x1c8538198113,
+    0x36d49c7c1cec, 0x473790266c5b, 0x5693ea72dc5e, 0x81fccc53c3bc,
+    0x8f99f4672450, 0xebb742e8814a, 0x03c8467185d8, 0x103c8559a592,
+    0x14df3fca91e6, 0x5184d9333d4b, 0x69759bf61458, 0x6e7151bac505,
+    0x75d45a5a15bc, 0x7a51912b4fdc, 0x943b2af0e444, 0xa5885ab247b3,
+    0x27b337c86fb4, 0x3b5866f5f09e, 0x479ac8132696, 0x4f31f8139ac1,
+    0x8ebd634a5cbd, 0xa7eb3062705c, 0xd3bd85d0bb7b, 0xe673709ca977,
+    0xfc85e52b606d, 0x14e17ad5bdd9, 0x3afed1f716f9, 0x57536787a7bb,
+    0x74d5b3fef280, 0x7d5c9d7f0523, 0x961a1a4707eb, 0x9e0db3fc96fa,
+    0xaf0a248705c4, 0xb71219f59650, 0xc23da40a5339, 0xd5ffe02bb0de,
+    0xfdd0f996f8f3, 0xffe5b8c27630, 0x2e6475c42fbf, 0x39482c6bd906,
+    0x46ba5376fcee, 0x6954b2f9c3cc, 0x7e39e5bfcc49, 0x9cead359e825,
+    0xaa0105cdfcb3, 0xb1c8b43f3802, 0xc45cfeead5df, 0x0442ff5e8497,
+    0x08c99717ab44, 0x44cab8cf225e, 0x4a9c4ef1e58e, 0x8435e33fb6d9,
+    0x9d36dad75fa4, 0x08d93b172f9b, 0x24df5b07056a, 0x5e0ae89aff90,
+    0x617fec2531e1, 0x6ddf1fa73ecf, 0x72114f391487, 0xcd843382a734,
+    0x0ff8dee8d652, 0x175161d51da3, 0x22743aaeff4e, 0x2387dde52797,
+    0x29eb9f075182, 0x43a09c4db6ec, 0x5d59428478a6, 0xb3aa032d915e,
+    0xdc5fdb4762fb, 0xe48b7fdd7793, 0x206a1738877a, 0x21b96e3f919c,
+    0x22205da7b5e6, 0x5b6514b02ab2, 0xa72aa4e752f2, 0xce74c8b34f01,
+    0xfd04ad9bb947, 0x1ab35a756356, 0x256e59ab09a3, 0x380ec3f2d949,
+    0x4a5f593fcb5e, 0x5b0b45224b48, 0x6df9755dc492, 0x872a3bb92332,
+    0x8a26ed657abd, 0x9880574467a4, 0x9c765de74ff9, 0x9ff4c2596ed7,
+    0xc454469bdb26, 0xf4142c013921, 0xfc083ad4d3ba, 0x4300bf3fe449,
+    0x8573d0bceb04, 0xa8a2df645814, 0xba02d0f5949d, 0xe46f2a0d9a6b,
+    0xeb5846e5e7f9, 0xef768fdcc7be, 0xffc85ba06676, 0x0c32c4828e19,
+    0x2529ab853908, 0x3612e521c32e, 0x407682eccd85, 0x6ff70b90ed57,
+    0x7bdbde2deb92, 0x7f25a620db72, 0x942770bcb520, 0xf39b1beb3f4d,
+    0x0d06714c62ed, 0x1c6a9297971a, 0x690b0b7750cb, 0x73bf2dea4a02,
+    0x743ff673fa41, 0xc00b3ea2b2be, 0xc244d1c0d0e0, 0xc72d50979ce9,
+    0xfec7e0cd1512, 0x9431ad55ab02, 0xa66a036a3985, 0xae40559ce614,
+    0x099333af5105, 0x102613e81f76, 0x2220a7249833, 0x2876aec167f2,
+    0x6a716e6093e7, 0x6f2e771117ba, 0x7acf59c06468, 0xaaeea21e2848,
+    0xd59eb094d9de, 0xfc984fb8e70d, 0x3a0e86a62042, 0x542b981fc6cf,
+    0x6b45cad77a0a, 0x80e56af0e0b2, 0x92ba7a8da73b, 0xea85cdd50c86,
+    0xfa54fc96ccd7, 0x0157f290a65a, 0x510bc26bf2c5, 0x556a8cef2d8c,
+    0x5d903efeac22, 0xd7e5798f38e3, 0xee8cd7d19bd0, 0x280f1723209d,
+    0x4c919adfffa9, 0x59a3e9d4b222, 0x690d136a653d, 0x88e96e55016a,
+    0x9397d8d06a64, 0x9766c1ea7822, 0xf7d053ae3c8e, 0xfd0489717e4e,
+    0x20e42f665172, 0xa3bbc04a7d72, 0xd576169bc12b, 0x18cc124f2346,
+    0x1c377a626345, 0x4e596fc8dc97, 0x5dba5d41bffe, 0x6ebff4251590,
+    0x70b104290ec1, 0x782dab5ae5a8, 0x7927affe87c1, 0x922a7c0e5eb4,
+    0xb75b88116fe7, 0xc630ecbe93e4, 0xf42fcb72b448, 0xf7618ebc465b,
+    0x0a36958344ff, 0x1df7724a8006, 0x410eb6d7950e, 0x539b3a846ec1,
+    0x628e9661868d, 0x79ace727a1a2, 0x85cf7c9cb5a3, 0xa7de8de2c2dc,
+    0xaa4d329e1b86, 0x0f1ad1be9f6c, 0x18ae81b22d42, 0x237cf264bdf8,
+    0x552c1355e6fc, 0x85aa0efeff72, 0x8b869ff2d792, 0x96b2d9b4a5ca,
+    0x9e102a5c6644, 0xa18f790a9c36, 0xac7628f5907e, 0xb1a4a6c14201,
+    0xc461e36de89d, 0x072ba6a879ff, 0x1f3e7ea9acb5, 0x20c5e0913c60,
+    0x20d2afda7f78, 0x24e722cb6726, 0x590fa8b9f7ff, 0x60957644b5ae,
+    0x98e530f4074f, 0xac319f5cc9a5, 0xba2ba64350a7, 0xbdb8ae76e0c5,
+    0xd94a60083de3, 0xe384d14339b6, 0xf1b3af37f57b, 0x19453152f9a2,
+    0x44f5e6a0f4a2, 0xe3019a964603, 0x08c71b2ef7a3, 0x24a1154b73ed,
+    0x2e50ad67c1a5, 0x4085cfa9ff62, 0x5f69bfda21d9, 0x97d6c20c55fa,
+    0xcca17615e16c, 0xe3e843bef9b6, 0xfe9202cf7c86, 0x178a0e9d444e,
+    0x19a151ee0102, 0x2df4ced2ae4e, 0x439fa44c2fab, 0x6522b7a871f0,
+    0x681901b027ef, 0x9495fbe54320, 0x9b88eec388c5, 0xb4967b156ee3,
+    0xb7bc93e0dab7, 0xbbe5628ac3e2, 0xbc992daa7abc, 0xcb87f65b1f24,
+    0x37fab9907726, 0x4c9bfc68ce16, 0x5bd298868620, 0x651d5041e37b,
+    0xb1068fa08fc7, 0xbead532ae2ad, 0xf4ffa3ab038f, 0x77646019df41,
+    0x7ec1763299f6, 0xa1ac1fce49ef, 0xf22b4a549f77, 0xfa2d4d3c9a01,
+    0x0d000a6d5ef3, 0x2e11e33d794d, 0x33cbaf45e475, 0x4de308c7fac5,
+    0x79069b610df8, 0xbd6e2b43646f, 0xf6c68e57c969, 0xf8c46da505b2,
+    0x08bcc8f6d487, 0x2397f0b4358f, 0x5655e7200db3, 0x6b97839b3114,
+    0x8b5bd9dc6f74, 0x9708dc27edeb, 0xadba4aff4eb4, 0xc4f8a1fc9185,
+    0xd6db308fac75, 0x0e77201f473c, 0x331258329564, 0x3616964eab54,
+    0x70e7b7892eb9, 0x8c23dc281e09, 0xa97410457947, 0xb5f25fc296dd,
+    0xcb013436b968, 0xeab0b9a0eac1, 0x17c9052e5375, 0x6dd0931035ed,
+    0x91e1a1b1e749, 0xa1271c201658, 0xa5577739bde8, 0xb06a126905f0,
+    0xba193567481e, 0xcdf3b0c10955, 0xd05a195f924b, 0xdfa252820696,
+    0xe9296dbb5719, 0xf007ca51957c, 0xf25dbab67f0a, 0x7cc85d8cb75b,
+    0x930ef97c91c5, 0xa1e24f2281d5, 0xa669d117bf96, 0xc788ee4f2892,
+    0xec0d468435c6, 0xf24e7d49f87e, 0xf6c0442c1224, 0x60c7215a56b8,
+    0x7259daf29417, 0x9ae17e31d842, 0xbc1da85d1df9, 0xc8879c213a12,
+    0x060f358429c3, 0x1fae02995f88, 0x34bf20a3bbc5, 0x728ec802c4dd,
+    0x79cc87916925, 0x7e6f07734fb4, 0xaca41c8fc779, 0xaeb6ccb410c9,
+    0xb92da16cffc4, 0xc92c9ef275ea, 0xcac77256effe, 0xd94dec454d7e,
+    0x319206889919, 0x47cb98a02aa6, 0x96d39ee98a26, 0x42dd3d44c49e,
+    0x49c39b68ccf0, 0x4b5b006bf079, 0x65278d3da826, 0x84ff8f8ad6b4,
+    0x8cf6d230c213, 0xafc25fd16164, 0xbb324ef1db64, 0xd60c51e41f93,
+    0xe83d90a6fb3b, 0xfd8d21367e8d, 0x026a301e2a1c, 0x55f6848a7697,
+    0x5cfa9f4f6cf3, 0x5de9a921fa1c, 0x650d8f8f126f, 0xa0490e9310a9,
+    0xb04acacccc0b, 0xfc5ab2964764, 0x67d50b1125b0, 0x77166770ece5,
+    0x7ce2459c1fd8, 0x85eaa752f409, 0xc190beaf72dd, 0xd13f378cfa23,
+    0xf023624c225f, 0x0354d2c83be7, 0x699502f94271, 0x788b92a8ab03,
+    0x85ced52c302a, 0x8b30caabf3d2, 0x91a69df0e588, 0xa5b5b5c9d5bd,
+    0xc5d0f857a489, 0xf9369a4701be, 0xf9a9e3da5d96, 0x1f7fdcacae9e,
+    0x5c749b632645, 0x5d0c43cb1778, 0x5d21fede3b93, 0x650ef6602351,
+    0x65d45e84966c, 0x7b61ec7b3549, 0x9ad7928cb2f1, 0x9e3d42a862b3,
+    0x0cff540efcf2, 0x191c6c1b867b, 0x1c9677f037ba, 0x5c87e85306ec,
+    0x7dbfb9e0df04, 0xb46428f21729, 0xcc09e47589ce, 0x0b3bdf3a7be7,
+    0x293b10bc14d6, 0x40a4dddf9fdd, 0x4446b6ea3d02, 0xf5235398252d,
+    0x20ed325a9ad3, 0x219134edc394, 0x4cad0088129d, 0x4d8146fcfcbd,
+    0x71b29664db9a, 0xc6befad7ff20, 0xe24e9d622851, 0x34505c14945b,
+    0x42810e817e48, 0x68995d58587a, 0x9bbf67f771c1, 0xa65a60f5f052,
+    0xce130099cf10, 0xe236e949cc4c, 0xe9684ead3906, 0xfb63cc25c8d7,
+    0x19f3c94ca5e3, 0x1a2f7d1d87bc, 0x7ae5757ad32e, 0xb344be27ad23,
+    0xc1556a5d6f54, 0xd1f57132c33a, 0xd2003174d265, 0x03c24ec4da48,
+    0x6b0485274cda, 0x8ee42b1c1ffe, 0x934ba6da3603, 0xac49dfbd9992,
+    0xbe889d76c9a1, 0xca22ddf26efe, 0xce74640e5b23, 0x0518237281f9,
+    0x33774f5b1f56, 0x35443b1988f1, 0x60675894909a, 0x7e8da79f0420,
+    0x7ee3a9af7d78, 0x93817e267ddd, 0x9a8cb03d2714, 0xe8956f095fc8,
+    0xf47a41a65e03, 0xf973add4d4fe, 0x02db72840f14, 0x18caad094d88,
+    0x31538d522152, 0x3558724580ac, 0x3c0e1769bc15, 0x54449e9272d7,
+    0x5d0a3526b254, 0x77d594578543, 0x84fa6a8d0b11, 0x8cee04429a20,
+    0xc9f87e0c35e2, 0xda48ea75b1fc, 0x0b88a552e13d, 0x147ce5dfb9b6,
+    0x1e995feac1ba, 0x4337cbd060a8, 0x598ea56ad4b5, 0x5a4f7a62d46c,
+    0x6af8e22014df, 0x76d921909fb6, 0x7a02a8bd6215, 0x8fd1a27c7308,
+    0x92ea3c518a75, 0xb199ca9183b3, 0xb5a3b7cf61fb, 0xbe7ecee7bfce,
+    0xdf911cd5e5b2, 0x2303b05f18fe, 0x4cafe42c3064, 0x607d1b1ea2f9,
+    0x9082c4e9e0a9, 0xb400493208ac, 0xb5aa7005054f, 0xbb47a4379f46,
+    0xf2a6f5ba4120, 0x41bae110cca4, 0x7657d55dd62e, 0x945915e6a30a,
+    0x9870bca9db7e, 0x9ef324a90a87, 0xcbf998a2b135, 0xccce5435a6df,
+    0x32fd5c7ffcb2, 0x574d52c4e0ad, 0x7990164e05d0, 0xd6e2e2b4e0f7,
+    0xd9723d5472ac, 0xfa1fe570adf8, 0x1ae51d183b87, 0x376cb53ed2e4,
+    0x5127df9ad415, 0xeff04a79bd53, 0x02f644cf8ea5, 0x19200e5a9219,
+    0x24a8ec6080fa, 0x3bccf51e2c4c, 0x4c344165e95a, 0xd68b3830317d,
+    0xe3be027a173c, 0xece3abb3cded, 0xee917b7726e0, 0x095513a935a5,
+    0x1d0fc35ad4e5, 0x2219a7c2eb48, 0x7638272e9e7e, 0x8e0749d1256c,
+    0xb453105c11c3, 0xcb198a998558, 0xf010b743e2da, 0x10b4fe783199,
+    0x35c3f53ce723, 0x3da507b1a2dd, 0x50277ac97eb4, 0x7b128d6400d4,
+    0xa295a64c9980, 0xb5a69b737fc2, 0xc83e19f16e65, 0x3be6d5b6a685,
+    0xa5e1cdd7bf15, 0xf72b68cd06a6, 0x384cd9acc98d, 0x3afcafa18e88,
+    0x4f0e1110b86f, 0x572ad7565602, 0x68b63f0ddac1, 0x863975c13c9a,
+    0xa91db7b84b07, 0xc5298f9fb113, 0x114e82cf3f38, 0x26425956b8f5,
+    0x7705393bba45, 0x99d9b2a63a99, 0xb25638c3d6d5, 0xd6732c72b435,
+    0xe4b24812099d, 0xf06a0a9f973f, 0x379f67a6a680, 0x729890a61b90,
+    0x839a097b0948, 0x97181b1faf9b, 0xb0b8f73c194d, 0xb1aa4aa4e028,
+    0xc1e620c65500, 0xf4526dbf216b, 0x2ab39c8fb952, 0x37dbe12695ab,
+    0x43d92d8afd3d, 0x5acd2cf5ecf5, 0xbafca4d1f7eb, 0xd06e852edcab,
+    0x2d0d4be6fb33, 0x472bf749ca23, 0x4e90d4614902, 0x77062aeebf9d,
+    0x8c3d41b6d998, 0xba18714375f0, 0x0771bd8d6569, 0x158528776cd6,
+    0x18094db6e3d6, 0x22790b1e1ff6, 0x2d6c4fc35191, 0x3b197af24603,
+    0x9e7f0eb550ab, 0xab08939288f6, 0xbbb5decf2b7e, 0xbf535f3c5b04,
+    0xc8bcf863c342, 0x0a3e4159f798, 0x266726353a5f, 0x3df3f2d33823,
+    0x41053ac796f0, 0x780e8a39bf72, 0xceca92e86a13, 0xeb4b13bd4e95,
+    0xfa1d449821cc, 0x299f2c96643c, 0x547f09d0cba7, 0x6ccd95a2e036,
+    0xd1ac97391a98, 0x41d295eec63b, 0x9f78e040db43, 0xd742ca1bfdb7,
+    0xe6fc78599fb2, 0xfdc95a3bb4d3, 0xff751af7d9d9, 0x1c59576291df,
+    0x37e0f85ef121, 0x52d375189ea7, 0x613764aa94f4, 0x7a45db389226,
+    0x82968e504d68, 0x88d540f0d0a9, 0x8ad8d8359d2f, 0x980993784f01,
+    0xc8beea54dbb1, 0xf565111a0566, 0xfd83e666d6e6, 0x188073b58248,
+    0x2997327f8891, 0x4d88005b0c6c, 0x61aa1492db80, 0x6840289dfab7,
+    0x779a39543108, 0x7e7fe7cb280b, 0xc1f5e9b5b1e2, 0x11b479d30d78,
+    0x676bf82b0c9a, 0xee7e69dbe990, 0xf5f977249814, 0x150a3cd5255f,
+    0x16f1b1622c3e, 0x4da155d37f18, 0x65fb8c23b9e6, 0x694f64aba7a2,
+    0x724ea009950b, 0x73bdfd47c6e9, 0xadeaa6d11603, 0xc06552ea2db0,
+    0xc702b8d60587, 0xf32eb945264e, 0x055aefbd8308, 0x1acd0aa96d8d,
+    0xa1e61e8df1d4, 0xddbf730f7d08, 0xf2bce50de917, 0xf33c88cc7c7d,
+    0x098c108637ea, 0x8346a96b8877, 0xcf1b17f327bc, 0xdb7c1fed62d2,
+    0x360d3ecbb6f8, 0x7a5cca04afa2, 0x7aa671e9f16c, 0xb1531d17f945,
+    0xe9b9f234919f, 0x4fe133802348, 0x7d7b6c625914, 0xaa884800a14e,
+    0xae9c45d37d72, 0xaff413865d0d, 0xfb78728418fc, 0xfe484eb005b3,
+    0x17a3a0f59575, 0x7c755e248135, 0x9b9dedef6688, 0xa6d10473e1d6,
+    0xb68a3d937847, 0x0a4208260cf8, 0x5086fc006c72, 0x8c1a16fe0c67,
+    0x91e2c156ee94, 0x9bc4e6eb375d, 0xbd0f052add05, 0xc1bd2f8ae9d3,
+    0xd54c2e873b18, 0xded13ab957ae, 0x43a03933fe32, 0x5916acbd5656,
+    0xcd3c881be265, 0x0280ed5d6b3b, 0x033ec9121ff1, 0x1581df68bd9f,
+    0x7b49832ce39e, 0xdba177ebebc9, 0x07a98ea482bf, 0x30cd32e925bc,
+    0x55e256c3770d, 0x5aa0843f17b9, 0x6e5b33f0b6f9, 0x803a19937599,
+    0x9435856075a0, 0x9c4cce3d88bb, 0xabad46986098, 0xc97d1e744f3c,
+    0x52ca480e672d, 0x58ea8e60eb15, 0x7ed767117d11, 0xb9e0589d803a,
+    0xd76faeed13dc, 0xea7275709468, 0x0c6d2afda024, 0x2189b8f71cdb,
+    0x2577837d357c, 0x3d1c8f5396d2, 0x6163d45d9f8f, 0x879671741140,
+    0xcce4947d1f67, 0xdaab98cd9fce, 0x384638a18e97, 0x6ac3e810117e,
+    0x721eef219846, 0x8c3d5ff56171, 0x8eae13b7ee08, 0xb0825b69307c,
+    0xfd0c2ef9aefe, 0x01ebfc959fc9, 0x3be1857a806e, 0x830286c88e32,
+    0x873a6e51f427, 0x8f12cf8bd4a3, 0x9f0de991e8b4, 0xb7c27a900a79,
+    0xb7f3a8ade2ec, 0x205d68e173ba, 0x3a1ec05310b2, 0x64a5dcc8b989,
+    0xf1eae17de7f7, 0xfcbe9509fd60, 0x04d18549a2dc, 0x051415dd31cb,
+    0x28d80e384ad2, 0x2e615bcfeed6, 0x4375e83ba19e, 0x5376451b3a62,
+    0x6549baced888, 0x75027ed0636f, 0x8c097a9a3206, 0xe302d102e6e3,
+    0x05af08196fc7, 0x146a93a507cf, 0x34e4c40d30f6, 0x80a9a49747e7,
+    0x861b62a399a8, 0xcaa8010796c9, 0x3d43bbca4df1, 0x6c6a244f8c91,
+    0x7ab81e3f58fe, 0xcc68337eb94f, 0x245c78c72759, 0x67c1183bfab4,
+    0x7957b5539a28, 0x7d9ef04b82ac, 0x836b7e23c6ee, 0xc00de3ba218d,
+    0xcfc5bd7f9560, 0xe1eb8c53508e, 0x0421f5b13e23, 0x6d109c7ce70a,
+    0xb8ddf3b2d374, 0xeb0b1e79677b, 0xf745f326df0e, 0x06cb7a035d95,
+    0x1e7eb47d24a1, 0x3a38a862792f, 0x470fb8a45559, 0x4a5946083f74,
+    0x5480805556a3, 0x6e2873c37330, 0xc79034587af2, 0xcd461ce18405,
+    0xd2a30a16c8bf, 0x265a5f8b51e6, 0x2bf7ce4cf1a2, 0x529c559c9c8d,
+    0x96690944b10b, 0xbc00ca20e0c3, 0xc81481457dbf, 0xd2dcc4e272ae,
+    0x1e58ad345924, 0x2697139a46d6, 0x5325aad41e48, 0xbfa6278ed076,
+    0x194c5aa8ff4d, 0x00830ed5cc84, 0x06c9fd931f79, 0x350e661e19cd,
+    0x4ad9073fbd21, 0x875574185a02, 0x933c55bc8c2a, 0xac7b102c4abb,
+    0xeaa218f5efc9, 0xffb66ad29ccc, 0x12b0458c3c55, 0x2f576ecbefe4,
+    0x3560d54032d8, 0x4a33bb546dc5, 0x5837d2cff2a3, 0x93eb2e93c019,
+    0xba789b76244b, 0xe8d668049f0a, 0xead656590f40, 0x000f41995763,
+    0x0868a5ebede3, 0x101a994a0517, 0x19eda5fed116, 0x1a7301b4f4b9,
+    0x466d99773748, 0x46a9fcf52a70, 0x9bfac6740b0d, 0xa10d2187f6e9,
+    0xa634c290fb56, 0xb3061adb4743, 0x1a0e3756005f, 0x4ade17012a53,
+    0x731cfc9743c8, 0xa2e384304914, 0xb1c249c5599e, 0xc233e1311a4d,
+    0xfecc70c17cd5, 0x015a3177e627, 0x0bdaa1a7c774, 0x8a8be80749c0,
+    0xbbbc14e6f0ad, 0x3744be55c7ae, 0x5b4763c50d8f, 0x5f24b61186c8,
+    0x5ffb461caa9a, 0x663b58175079, 0xbb4de9a00fc6, 0xeecec40b1b8e,
+    0x1b3085c4882c, 0x3754d773578f, 0x752f79a37597, 0xd263d9bc5165,
+    0xed723e9ebecd, 0x022817bf1cff, 0x163cad0097ac, 0x3762e189b383,
+    0x43e08159bfca, 0x4cf27e87864d, 0x54f0636118fd, 0x1fa780f518c6,
+    0x2eede59f5ee9, 0x4b5db3ab9e3e, 0x5167c9ccf281, 0xb588400166de,
+    0xd7c9debf6f28, 0x46c7a3f6eb40, 0x52062a6a8708, 0x613a7cf20560,
+    0x90d762dcf09e, 0xc7255abfa3e1, 0x117610500fcb, 0x1562b60b0b93,
+    0x804615cb09fb, 0x814caf28e967, 0x14e153d64608, 0x19bd7881da83,
+    0x3a49bb0ccb75, 0x60ed58205f4c, 0x9e2d58a54120, 0xb695bd98e1a4,
+    0x41f4728c260c, 0x5c9b38595ddb, 0x6dbaa85e3f62, 0x7ae7f53f9aa9,
+    0x89628a20cc25, 0xaa1debc2619d, 0xb85839a63ddc, 0xc522b52ddcb3,
+    0xe58a5e553285, 0x17a5ec170a4b, 0x964fa606ce32, 0xa52719bb261c,
+    0xab33ee90bf9b, 0xe13c5c9c98ee, 0x237c6b83990e, 0x9720598d5805,
+    0xb0f2d8e5a5b4, 0xb67375426e7a, 0xc2b267fe4de7, 0xce39718c0ea0,
+    0x025f73556002, 0x225e932b6927, 0x2ec677e85153, 0x44abdc6797b0,
+    0x4a2f3778a5b2, 0x5ec6a44b048b, 0xc10c3aacaf5c, 0x41ed3e2457b3,
+    0x5ab03854e4f3, 0xad56e3d690d2, 0xb065a7a5b028, 0xcbbffe03990c,
+    0xcf7a164699c3, 0xd64a44397270, 0xdf88673a9278, 0xff49148b7488,
+    0x1c7bc696e781, 0x3c75de2271b8, 0x5486e1ab7646, 0x7b3abc69a3c0,
+    0x9132330bb1e8, 0x91d87f35145b, 0xb193af1d6bf3, 0xb37446e7c5bc,
+    0xf1b528d2f6bf, 0xf45fbbee3707, 0x0b8314fed10a, 0x2d0b30a5923d,
+    0x3137e2cedd7d, 0x3908b798ff94, 0x5d79293316d5, 0x82c40e57b3fd,
+    0xa4959754b135, 0xa9d8e5f76fbf, 0xcd34ca1f47a3, 0x24a254576f6c,
+    0x3e4ba71fae97, 0x64bec5c27b4a, 0x9669c8a53c74, 0xe4bb7fa9a5a3,
+    0x4b33802c2bf1, 0x5ccc2c4aff52, 0x74a17c032207, 0xc3767fb67aec,
+    0x102e882186e0, 0x132b745ce430, 0x62f4c4bc4ef1, 0x644c1d512302,
+    0xad0916cec7b3, 0xddd3b3a06cf4, 0xe46ecff60b19, 0xebbc1d8237b5,
+    0xf7a92c3c05a4, 0xfd7c96d6f6fc, 0x1ecca79d32a6, 0x506c751fd91b,
+    0x50f8387a9e4a, 0x7013494f2f36, 0x8a0d20bf5d41, 0x8ae54ab3a976,
+    0x8d39dbbe7066, 0x95b2969b1d53, 0x1b14eef267a4, 0x48bf2af0314e,
+    0x4e94a4925693, 0x558a5624e174, 0x65cf1810374f, 0xfa90896836df,
+    0x125e9d419cf7, 0x234ad021721e, 0x56255e631b73, 0x6dbd9af039b1,
+    0x75f01c48fb5f, 0x84739cf40dde, 0x616226608516, 0x020411b81542,
+    0x2138c11f2c5e, 0x2c3357a51699, 0x6e25a0986d15, 0x940f8005b410,
+    0xa9b671ffd358, 0xd1a66cd72650, 0xfe8dff64c21b, 0x0a33002276a3,
+    0x0fce9a6be837, 0x2124b5f72ca9, 0x48eeb810c1e3, 0x4a208d05e3c0,
+    0x68f9bcf3f70c, 0xcc2aeec6d87b, 0xed3032dcb582, 0xedebff8a364b,
+    0xfdd36d845a2e, 0x0f8c5a695b10, 0xb838bf54bb3f, 0xc42ce5603c09,
+    0xdbb6439ce342, 0x69c125949055, 0x7fabcced5b65, 0x8cf11e781479,
+    0x94587fb4d2cf, 0xbe899a1a024e, 0xcc2bca77e1d0, 0xffbd923a988a,
+    0x0a422078e1b1, 0x45c204889d02, 0x64d429934ceb, 0x68ace8b352c0,
+    0x6fab10f1b31a, 0xec8bfd317540, 0x34b09e9a176f, 0x4153fc98dbaf,
+    0x86353db32f8a, 0x8b905670461c, 0xc88d51438d77, 0x0744eb2370dd,
+    0x16c912a5ccc6, 0x486571c71cb0, 0x5e317242e2a2, 0x5e4ce54d96fa,
+    0x69f5c98aad97, 0x75d9ec7a9a83, 0x875ca2f74404, 0xb4b9539069cf,
+    0xba50201e623a, 0xbd00a5c03884, 0x35f47726f358, 0x99e9b1c42544,
+    0xfd0d2a11ac87, 0x1a17d4582dd5, 0x3bdd0329f37f, 0x4998226d47e2,
+    0x4e0bf8569575, 0x871aee14be6a, 0x87599b28eb44, 0x9bf267556cbb,
+    0x9cdeed02ba6d, 0xbdeef15aa69f, 0xbf3d98b49f72, 0xf16c5d645bdc,
+    0x003fed9951b1, 0x20b3b65cd94c, 0x2ee0fa686cae, 0x5987e286377c,
+    0x65393d6f2392, 0x8ffb9897a2b8, 0x9bb02752df94, 0xccbc2fecf6eb,
+    0x01c5910aaf37, 0x95ff97a55737, 0xa909005c7f14, 0xab1dbf87fc51,
+    0xb10f2152e178, 0x02c6fd9105f3, 0x0f4eadf61016, 0x0f6d54d31534,
+    0x2bcd1fc3c0ab, 0x2dedfe8b6fb1, 0xac5c04aa51bf, 0xe460b81a3ef8,
+    0x2706570f40c4, 0x27dce71a6496, 0x320d0d315cc8, 0x3b3a7d69d7a3,
+    0x525a2d8a1556, 0x6d4fde1613a2, 0x8213ab4ad1c5, 0x86ccd07bf383,
+    0xc6828d0de0dc, 0xd6740b9d029b, 0xfe2e7fb90f81, 0x90956d7fb21f,
+    0xb458f0bcbf9c, 0x24554dc45131, 0x2b6f98ba7732, 0x3b67445f34b8,
+    0x533716aeccf5, 0xab2f04e7974f, 0xe4c05d1898e6, 0x1086cd79b801,
+    0x13649dba04a9, 0x2ae0f21e6305, 0x318c86ada092, 0x3bf9fa7ea300,
+    0x4b2efcb332a7, 0x6195d9693a92, 0x64311915f84b, 0x6d585c38d75f,
+    0xf802d0ee5963, 0x3a34b131f3cd, 0x3b16b12c3819, 0x426891e4d819,
+    0xb5880e748a81, 0xe9c85e7d7362, 0x104a20e1b155, 0x31e7f79b96a3,
+    0x3e87e7390458, 0x79fedf7edea6, 0x7d135b458e39, 0xa7a781937fed,
+    0xaa5b7596acc2, 0xab61d4658669, 0xec84df2e71b3, 0xee36cd003280,
+    0x05330887f1ec, 0x161390e9a0d4, 0x36512344d10e, 0x592895f29c63,
+    0x6b93ee9d3181, 0x6fe4c50c0c57, 0xec77eb5824d9, 0x26957c01f729,
+    0x3d7260ffa028, 0x417a04a744be, 0x5036b4fdf99f, 0x5a6c930c820e,
+    0x7ff450cd1de8, 0xc2c2a73422ae, 0xcbde7a67e148, 0xfa6a06b2ddef,
+    0x13e43a647894, 0x060c242f4379, 0x46e6e66d0f97, 0xc52383df136d,
+    0x4c993c07b85d, 0x5ba252f7f444, 0xde26a07fec28, 0xeb946eeda271,
+    0x17bffa3eb7ae, 0x633789f33085, 0x90a4ed54fb7d, 0x99b60046aaec,
+    0xcef55d3db4d4, 0x6478c858d0f4, 0x7224cebca88d, 0xa27d46c60ecf,
+    0xb3d86a9bd22f, 0xc3ca5e48ff78, 0xc529b86b9d78, 0xd5f98e04a733,
+    0xe3c057c621d5, 0xe82bb70399ef, 0x149838ff15b8, 0x680bd914f317,
+    0x727d3065579a, 0x77d8be4079b6, 0x7f68274229b7, 0xae4e48ca131a,
+    0xbccf0ac0e05d, 0xd2f9be87fae5, 0xee2913dda71d, 0xf272986bc953,
+    0x1b6a516c1890, 0x3f38cf7a3afd, 0x4b6ffb375640, 0x5ba2e58eea15,
+    0x7222ebb96ca6, 0xa6a51ca8d327, 0xfd86e38635c1, 0x32d08ba1434a,
+    0x460ff031bcc3, 0x5b2a3494ffc8, 0x7c265251f607, 0x8367f55ffc64,
+    0x955be668cdd0, 0x9b7f25fe9cb9, 0xb5e061f4fa98, 0xe14dd702555a,
+    0x03cfbcbdb2e1, 0x170e71a11b0b, 0x19cb516e28e3, 0x20e6fbbe7182,
+    0x2411a7b650ba, 0x2a62a708a18b, 0x314681076a66, 0x6aecaa0f7904,
+    0xab6e366a7b04, 0xace3862f42e4, 0xf3825f997dcb, 0x192bf8096f89,
+    0x339ed10489a9, 0x70280d2669a2, 0x92f4fa212b91, 0x9524421b4612,
+    0xb567174ffaff, 0xd7cf5e78d256, 0x5adebfae7e1a, 0x6a8f0d043388,
+    0x6cda3d270deb, 0xd9e93bf0ca84, 0x39b02a7b88cd, 0x3dcbb4be2356,
+    0x551c586d33b7, 0x7e3a7f494c3c, 0xf8cd7cb1eec3, 0x4791587e96f1,
+    0xa3c586de34df, 0xaa03c460ac96, 0xaaf517c97371, 0xac02c87905b8,
+    0xcac602c4ef24, 0xf9f88ae65f8d, 0x0908b9284e4f, 0xb76a1c60a07d,
+    0xed023a1c68f9, 0x117ff01dcedc, 0x12513d4f6dfb, 0x194bbc9d7205,
+    0x35f40aa8426d, 0x5c28f154edd0, 0xc4ac501b04ce, 0xed885b8199ee,
+    0x2c25a721e5d5, 0x37e09d81c43d, 0x7dc4cf099b34, 0xabc547a6e3fb,
+    0xcf8f32889304, 0xdf819b53cbd7, 0xedc87df1e569, 0x00b08117c2ec,
+    0x035cae1c2b97, 0x1cf33f1491a8, 0x5695f9bb49bb, 0xaf9d328ccebf,
+    0xf215fc0165b7, 0x0b2b4f520fff, 0x8101b9283a3d, 0x96911b970742,
+    0x7e5da3b39784, 0x50386d25a9db, 0x6351294b5447, 0x98fb5929e48e,
+    0xbd1309ff3d3b, 0xc7b79e74ae1e, 0xc85832a68054, 0x34b11cba4c61,
+    0x677b32c2564e, 0x6c5b005e4719, 0xbdc3f1dda517, 0x180f86e51f4d,
+    0x3bc8befe2929, 0x95e8a3505564, 0x99fc2c0525fe, 0xe3388eb25850,
+    0x25e5ba171881, 0x5989ce8b9ccb, 0x5ff04e620bf2, 0x7711d2fa77cd,
+    0x7917b3d57e05, 0x1d153eb3c017, 0xab34b6f3746c, 0xc5750276937b,
+    0xc8c82b516fe8, 0xe9bda6dabed6, 0x259c3e35cebd, 0x81ba76f342cb,
+    0x9efe8b746c40, 0xbd66f5f4632b, 0xc016915a2261, 0x31c2929d3b11,
+    0x3e933b3a75af, 0x3f1f73b34668, 0x403c3d425579, 0x44e5d475eee3,
+    0x51269ba9fc78, 0x095e79f87130, 0x0b22b47bff8d, 0x25fc42503832,
+    0x3c83253d67d9, 0x5710c1962502, 0x6b690c364b77, 0x9e5b9f215299,
+    0xa8cb5c888eb9, 0xa966e86fe201, 0xb135ae32d02b, 0xd7e9fe0f092f,
+    0xff0f4de86459, 0x0be748665797, 0x1714e182486d, 0x346acd9733e8,
+    0x34c26990d5a3, 0x36f9b318ba13, 0x8c8be8600cc6, 0xba4c1a000050,
+    0xcd9b471d07e2, 0xdef5f5d4bfb8, 0xf00ec3a5f9ee, 0xf7060f21ad32,
+    0x04c636af8083, 0x25e8c248400a, 0xce4e7891a970, 0xe7401cbacfac,
+    0x8a56d9e35449, 0x93c7c4eb7527, 0x313e8f2d1a91, 0x6f3a21d07769,
+    0x81e511cb5075, 0x8ccb1209336e, 0xae24f8d5672f, 0x242be11c5891,
+    0x2d8577282cf1, 0x9366b97ed920, 0xe7b9d569bb54, 0x5aa9f853c371,
+    0x5b2284c0a3fc, 0x627ca19613b0, 0x7d02ec0e1839, 0x81c32890ecd2,
+    0x9cbde1676a0c, 0xf572fba7d7cd, 0xfbc7695b7f29, 0x553ca8e6db52,
+    0x5e61dd028679, 0x6f36f57514e7, 0xaf3a3d6ba61f, 0x08cc4f5bd93e,
+    0x0c9f56844ed6, 0x4e1b2211f8b4, 0x55a373c1f5da, 0x5f82daa1f967,
+    0x933b4acf7f2e, 0xbf99290989b7, 0xcc73a7acbc6c, 0xd5434ed5f9c5,
+    0xdad1a4b81cb7, 0xf55f4110d9e0, 0x94c2d8bb06df, 0x9d40d671387f,
+    0xb5a85128c1ef, 0xc565a856c03a, 0xd336f7cb4442, 0x3545a8178663,
+    0x5790e24c80ff, 0xb9c63b03922d, 0xc29273cb78fb, 0xc2c7fa86bf0d,
+    0xc36fe09949e3, 0x3bfcfd26e632, 0x434925e7f5f5, 0x6992dd6bae5f,
+    0x9666fe7c5fc1, 0xad578f85f8ee, 0xbbb5c7205efe, 0xc1a6b3cd389b,
+    0xc4bfc2c05b92, 0xdd8ba8bac9d5, 0xf463104fe85c, 0xf5053e6ae2f5,
+    0xf69b0984de1b, 0x028c364d13e4, 0x15fab9f431e3, 0x254a44f75ece,
+    0x3900617c8aaa, 0x7a1b6ab7ac05, 0xca139f9eb587, 0x042809efa10f,
+    0x2c36e632feea, 0x44a369350748, 0x5bf1c34dddf7, 0x71f26048d2e7,
+    0x86010303b792, 0x8d0592e6b978, 0x9c384b850f6d, 0xba4d3819cc77,
+    0xf01f6fbd4e69, 0x14c408b8891e, 0x3ee226bed9be, 0x93d8d01ed929,
+    0xc1ea3613cce2, 0x010c684c30e2, 0x482da429446b, 0xb22d2f76d05f,
+    0xf2bc00392101, 0x405ef4685244, 0x9d3d8cffba7f, 0xc549e51dd8e3,
+    0xd688368ec54d, 0xe676222f86bf, 0xf46359cccaa9, 0x03d275e913c6,
+    0x1c3731ec57fa, 0x45ebdc6544d9, 0x99e9e07bc4c9, 0xa6f7d6d309a3,
+    0xed14c2e87772, 0xf8ee60255af8, 0x3e3bd38157d0, 0x47bff5775d52,
+    0x913acaa9b6b9, 0xc754d5ba4c4d, 0xd5d81bd65907, 0xd93d1c44f77a,
+    0x55259dca3fea, 0x6b9e521409db, 0x6d1cc831b883, 0x96e08b8a222c,
+    0xb10a6c4d0bd1, 0xca3d7c3ea423, 0xd12414f1b23a, 0x34dc01d4d9ea,
+    0x535dd0585140, 0x65c83ec6cf4a, 0x8528d9723a26, 0xaed5825d5d16,
+    0xba8ac0c5ab41, 0xc958990310d9, 0xd352c340b76f, 0x3767c9860b52,
+    0x5bf49866edff, 0x6248cb8b8f96, 0x64bfe6f2bdb9, 0xee77e3745c09,
+    0x4ef5214410a3, 0x5a6b78092c2f, 0x67e098579b18, 0xa2b82136c009,
+    0xa4395608b3ed, 0xccf6f52149b4, 0xe86b24a0be8d, 0x590a5f705c0a,
+    0x5e0231b5aaa2, 0x644b6a093749, 0x86c4d918bf57, 0x8d7ba3081799,
+    0x92e1b696431b, 0xd8cf4906069f, 0x04c219c98504, 0x954a7ecd01c6,
+    0xd2fe789250c1, 0xe89776781018, 0xec5bd9df1470, 0x011ce85f8d57,
+    0x31bd6e650d00, 0xbaf467cdf54c, 0xea401963e05b, 0x1d0e4d7a5222,
+    0xb9fc4da47d5e, 0xba2be1d92d6e, 0x3307740784b0, 0x5bf04eb75ce8,
+    0x5d19e78faf11, 0x8e065f10aa36, 0xe7d50f0dd642, 0x0409d8f6450d,
+    0x25d1515e4469, 0x2deec750f34b, 0xd8cc833da1e8, 0xdbbb7b649f47,
+    0x540766b8cf45, 0x56ebd92cc33e, 0x984d1bebcfd8, 0xfe0e580b544b,
+    0x3c991c6accdd, 0x84414de72c6c, 0xd54265c24f0c, 0xf182da28e416,
+    0x0b0c6149014a, 0x2ac756a2531d, 0x59b50499fae5, 0x77215b6f1bca,
+    0xb98011332108, 0xbea1852689ae, 0xca21ec80a316, 0x1a51f1b92c5c,
+    0x4576a91d5c68, 0x5188c658d101, 0x7af896a7f53f, 0x9d1477374590,
+    0xccb540a192e3, 0xdcb9bb8f9381, 0x07a99be58eca, 0x07b421989830,
+    0x125004d32dd5, 0x2b990a66f007, 0x37ef521f1bf2, 0x4a95745e7bd5,
+    0xb50799925252, 0xc26689af9196, 0xd11bad968812, 0xe17c9239a394,
+    0xeb7fe2d030f2, 0xf828f8c6856f, 0x166453370bc1, 0x1d4fb9a59301,
+    0x8792bfc2c4f8, 0xb5bd149d2d92, 0x0c682ff42d41, 0x2430b0630cde,
+    0x2784c37a005f, 0x28da8225ac0d, 0x4e35df83e89d, 0x7709aecdae09,
+    0x868bc748d605, 0x89ca946ab0f5, 0x9ca468ed28c2, 0xa27d8b7faa57,
+    0xb7e64583a84f, 0xbbb2aa787696, 0xf670cf541b1c, 0x49e7ddcb4f06,
+    0x5b8641e1b2a4, 0x85e6f07b9233, 0x9374500264ae, 0xa0fa5da87e89,
+    0xbab75c7cade2, 0xc926bbfdc4a9, 0x3d81a8f98b40, 0x4d8699059768,
+    0x97773bf08c1e, 0xcfc1041947bd, 0xf1657d06d45c, 0x4ae4cd272e61,
+    0x7a52ce8a7ade, 0xb9da90d0e08a, 0xbab92269ce4b, 0xc1577291bd36,
+    0xdc8025b3c21d, 0x7f724978ab9a, 0x89fd04cc9088, 0xf50b6594cb9c,
+    0x6ac39dabfc46, 0x7cbac2871e78, 0xbcf9493085ff, 0xdbc61ef361bd,
+    0x31f789126408, 0x37c11da75d49, 0x60b4f3284a71, 0x8f6f63fae5d9,
+    0xa5211637144c, 0xeac70fc8c9f3, 0x48834fbd08db, 0x695b0ea569bf,
+    0x6b592881abcd, 0x6eb786bca2ef, 0xf8feef8962be, 0x10c685bc2b47,
+    0x5e859ca32231, 0x6a0519c12485, 0x6c1d0cbef288, 0x8d41a75ee5fc,
+    0xac69c20bbfc5, 0xbe035866aa3a, 0xe65c51ad5569, 0x003af0a1d4e1,
+    0x0f0b4d0479f0, 0x369b353655b4, 0x476677a2ec0b, 0xc42255610787,
+    0xee15e77d2705, 0xf3759366b0fb, 0xfe3cb2388911, 0x3babd1d40f6b,
+    0x4c2446027d30, 0x5afa94ebb841, 0x71997c8245b5, 0x0c7d08a4f755,
+    0x1b5f772a642f, 0x5c7ba530a263, 0xaec487a310c0, 0xb9978b8214da,
+    0xcffa0f9aaf26, 0xf855c7959f91, 0x4b0907d188c3, 0x6d397ea8e056,
+    0xbfc73b451754, 0xd7a785ce4ef9, 0x580f129cffb1, 0x5a14f37805e9,
+    0x8ab41a2362f4, 0x9cf6463de98e, 0xe0d3e73da8fe, 0xe1ac4bc0faf8,
+    0x47d093c941a0, 0x864f731b8e2e, 0x935f035bfb6b, 0xa85a6653338d,
+    0xff4bf5bd2440, 0x324c422d858e, 0x33d78794774e, 0x8a2255b6fa04,
+    0x95117c4dc3c5, 0xf5f5a9859ca9, 0x49113883ccc3, 0x7141ef768082,
+    0xdcd57165d974, 0xffbb12b70a7f, 0x268fa3597104, 0xd08915b5a1a3,
+    0x4906ded4bb63, 0x53e552a2dff7, 0x6f902da8b7bb, 0x7113e69feb16,
+    0x73b085a6cb6d, 0x7fd26b6ece1f, 0x42034f2fcc94, 0x7d606e541aed,
+    0xa258853a8f83, 0xffb8d097bf11, 0x201c2121a744, 0x261967f9b86f,
+    0x2edcef86c3ff, 0x3ec71c352d1e, 0x50db4e042c0b, 0x600d1c666aec,
+    0x80ea58b75648, 0x865191109ea3, 0xa20d596e2159, 0xe63c6951e6bd,
+    0x198fbe8f7662, 0x5ab930fd0338, 0x79c1ba90c0cf, 0x79f8db352f44,
+    0x81996c1d8ffc, 0x9ce2d523cdee, 0xb98fb65b11ba, 0xd1d1e801eebb,
+    0x0e4c45d357af, 0x2d7634f85fa0, 0x4724ca9a237e, 0x63fa63436a44,
+    0x8dec20e75b9a, 0x41ab96200f5b, 0x77ab8d801335, 0xc72f5408a406,
+    0xe4ccd8fb9d5e, 0xee2f5ad152c1, 0x0506c2667148, 0x4b4e3a661039,
+    0x7b9acd624a77, 0x8e7b7ea76f5a, 0xacd57ff4faca, 0x26178c6d6acc,
+    0x2d8c321177c4, 0x379ffae1a48a, 0x75d691a8d1ec, 0x99398d225cab,
+    0x9fc590987e06, 0xf1777a500c7f, 0x13c36432186a, 0x1ebc60ceda42,
+    0x1ffeadfd9b87, 0x4c7d7caae4e0, 0x5b00fd55f75f, 0x68e5be4765d0,
+    0x89c927adecf3, 0x076e1cb7ff96, 0x3200a91cc8e7, 0x63f04b9a4ced,
+    0xbe8b7b0d9eef, 0xe84ae5c89af9, 0x05f240c18c68, 0x5b82a190b0f3,
+    0x63ccb274c4e4, 0x7f792763c50b, 0x99aca39da102, 0xa6c310a0bb55,
+    0xab9d26451be3, 0xb39d1a25e280, 0xf62b641e97ce, 0x23c43da6aafc,
+    0x27ed0c509427, 0x93a59cc193c3, 0x9d4865949e63, 0xaaf3f6da6a72,
+    0xba8b8fd9b0c4, 0xc4a12d220bb2, 0xc5b0029cbad2, 0xe0e1a188a0bc,
+    0xe99107ebb094, 0x1a43a013f808, 0x24a912573087, 0x27b3b817e803,
+    0xd245f7a726ae, 0xddfe69e1c59f, 0x472ef193ec26, 0x774130196729,
+    0x87bbb34f08db, 0xb78f0a31513f, 0xf53d11700a38, 0x02442b04a1fc,
+    0x30fc524103b2, 0x3e7ce27e9315, 0x6958671b8ce1, 0x9fc2f6d41155,
+    0xa2215de4d05c, 0xb29be11a720e, 0xb92859ae9ef3, 0xd33c0a4058f3,
+    0xdbef5422cae0, 0x292e8cbc289f, 0x30a66632865d, 0x4453d17cd6fb,
+    0x7642c44d49b2, 0x1f66564b67ce, 0xcae4314bdd17, 0x29a6957fefe1,
+    0x2ccfe21dac7b, 0x65a11503bfaa, 0xc468f6a05cec, 0xc5370fffab45,
+    0xd864626d5cf3, 0x2b1593a21238, 0x41cbd034ec2a, 0x58774c85b6f1,
+    0x1b7d9b86bc5f, 0x32641bfb57b0, 0x53b96f9b180d, 0x5e981df84266,
+    0x650455c641ca, 0x76e584ff3a1c, 0xcb2912ec93fc, 0x1d8357d4b8d5,
+    0x218d0a839158, 0x397a9974179f, 0x7af66501c17d, 0xa06aebd478b3,
+    0xa3cb1e879dfd, 0xb6455582aa20, 0xcc7b7938e522, 0xf29c042c8f08,
+    0x12f160a2174a, 0x919d29990f1e, 0x947332da979c, 0xd11906d248c6,
+    0x204d6d7e0790, 0x35f3001e043a, 0x37c35a3dc460, 0x5a1571356c12,
+    0xebd17fa638d9, 0xef2c34f0d3ab, 0x1332835075dc, 0x389c847023ac,
+    0x4254933846dd, 0x624e7034cb4f, 0x683a549725fe, 0x91d17cfe2a98,
+    0xa3e5aecd2985, 0xda84a075d732, 0xe1b2d206f326, 0x8b81f307f868,
+    0xa95f84694138, 0x474280ec8f9f, 0xadafc12d06c2, 0x1fe9cf611e53,
+    0x2e87d8dace16, 0x31bbe5ba99db, 0x66e8f5ffd633, 0x6eacfb80b532,
+    0xd9ebdab9b76a, 0x4d0355bb9fe3, 0x5413901cc808, 0x7abd5a45f7a6,
+    0x88b6b17f6d59, 0xaa0b9001222c, 0xe4153a0ffe89, 0x099c82b28ed9,
+    0x6e3fd077e762, 0x9db76d522631, 0xb74fd2c2ba6a, 0xc9ba41313874,
+    0xf0ec25c4d0f1, 0x318c58fcd80f, 0x406098dedf33, 0xd6699a3f24bb,
+    0x1dc948a15f59, 0x4ac91a675eb6, 0x890a36e1957e, 0xa81afc9222c9,
+    0xdb443b038cd6, 0xfa80ebf86de1, 0x4e7ad20085f7, 0x67617b589743,
+    0x682b3c1a77fd, 0x740a914eebc0, 0x99c551a58e35, 0x9e40ee8d9ff2,
+    0xcc35bcacc27a, 0xf32b78516d94, 0x56d486e41e3f, 0x5d3d15c1c153,
+    0x61ad42bab296, 0x957122d758d7, 0xa8c383c6b12f, 0xfa43ca425bab,
+    0x1e6342167882, 0x4b1d14e79265, 0x9491f79355ca, 0x9f081c9f3376,
+    0xc9c485411c9a, 0x4cd6300d0210, 0xbb2fb8224fa2, 0x31c647436848,
+    0xcba0b5e2fb05, 0x36af16ab3619, 0x456d9b7a1922, 0x4e712f75742a,
+    0xd4d79d055e70, 0xff6dd25a8411, 0x10cf5dd4e8fd, 0x1afb2b4e7390,
+    0x25f3b2cd29de, 0x302a05f9bdd7, 0x7b42a7d3dc53, 0x8f509ae1afaf,
+    0xfb634b71907d, 0x066399ef0af5, 0x15ee9e3413f4, 0x22f83bedeb2f,
+    0x3985867fbc62, 0xc613c6d1e8f5, 0xfd5bc3583e51, 0x3901dab4bd25,
+    0x4757d6325381, 0x782f7cdc419f, 0xfbb90467f651, 0x13726bf75924,
+    0x47965eb97699, 0x5376d8b90735, 0x5c3e43c574da, 0xcd358facbf9c,
+    0xd38d3132b7be, 0xf64bb4fb0e32, 0x3e249f773aaa, 0x7cf8969de97c,
+    0xc0a5b92ce1c8, 0x08699842fb74, 0xb1e0e2bb5936, 0xbbdeb5e95c1c,
+    0xd32f59986c7d, 0x63aab4c3a062, 0xc19b91370e48, 0xcebecd836bb3,
+    0x2d3c921cbba1, 0x4b1fa0e68ee9, 0x8d151dac362b, 0xd61fa28e7ebb,
+    0xe5de59169fa4, 0xe8ff69978c8a, 0xfb40ab75fc10, 0x89635788012b,
+    0xdbc90c5f467e, 0xf7e81b34912e, 0xfba701330b0e, 0x0578ae8db46f,
+    0x0be93ef92172, 0x1176e52e3315, 0x429415aefb23, 0x6a2472fee271,
+    0x7e5f761c2666, 0x8ebd26ecf122, 0x905ba341c786, 0xa0ea47a164f0,
+    0xc5683831d098, 0xd2f13f1b3574, 0x31208e13ca6f, 0x3210bcb17471,
+    0x3632397aa4fc, 0x389aebaf67a4, 0xb7947a9a091c, 0xc9b41c582883,
+    0x0df20a8c64ec, 0x2f5726b8b362, 0x578a9c5fac5d, 0x5a75015a3658,
+    0xabffcd88ea3a, 0xae0c160891fe, 0xcc11af2ecc79, 0xe61a64ef7189,
+    0x326f9c00c10d, 0x33c5201d66f6, 0x6c6d9b91772b, 0xc29395c158fc,
+    0xea8f0087cc6e, 0xff85d052912c, 0x07ecee2a81d8, 0x3552156f7d1c,
+    0x5e068e2f2c1b, 0x872b1cafe62c, 0xce6e333c4f99, 0xdfa0da2f15c4,
+    0x54703589550c, 0x6dfddab7da1a, 0xa5fdfafb53ef, 0x083ec3a18597,
+    0x2510b35a700d, 0x2df2e1c480bb, 0x7d678f6d94c2, 0xa2a863fd340e,
+    0xc7a3aeb5f96a, 0xd129a5242d14, 0x4ab93d0140f5, 0x56ffbc2cdec7,
+    0x573e2eb205dc, 0xec3f375a495a, 0xfae6672cdfe5, 0x076cb837c76a,
+    0x1f8dbedc5fd6, 0x7b0f46e763c3, 0x7b18a7cf5050, 0xa1ab578b3935,
+    0xc03d9e484ff3, 0xca6463775b98, 0xe8e2c3997c63, 0xed8a8654e7a5,
+    0x08e2cdab9c9c, 0x3334d8840aeb, 0xab87a09ae7ff, 0xcc10af53882b,
+    0xfc9930afaa07, 0x22292a8d1595, 0x325bda55a3a5, 0x34914f6559ed,
+    0xcd2d54558d7a, 0x5bd2284b6572, 0x9fa16018b967, 0xc981f5d5fa06,
+    0x516a47e4e97f, 0xa91c71b7d424, 0xb56ffabbbad3, 0xf933c299ee4e,
+    0xfe2e19047c5d, 0x028606c50a0e, 0x204616146a99, 0x2caf94ba7b28,
+    0x2f40142529b6, 0x521cc9ac79be, 0x61e814eed7fa, 0xc83e3ac20864,
+    0xfc7ec559f70a, 0x172550982914, 0x23f3ea2e2fc5, 0x2ac53cec254b,
+    0xad9457247bd2, 0xcca0c4379b7e, 0xd7bd356cdb9d, 0x1f0f9f67c799,
+    0x3699e7e085e6, 0x409571784f1a, 0x56e48413fefd, 0xba183a0c1fe3,
+    0xc9bca254a94d, 0x3da654c447f9, 0x8544eac9b536, 0x20f7b516d208,
+    0x3c177c6ef5ec, 0x9e45bdd4543f, 0xbb32e608ed48, 0xce587177dacc,
+    0xfb984fac29a1, 0x2eb742f9900d, 0x37336c379385, 0x3cd8dc86fd30,
+    0x4a02f59607b1, 0x775b4d91bfdd, 0x99bcb7f7ea1e, 0xf2c5502391c0,
+    0x13fe810b8c76, 0x55824e270043, 0xaf1a8d2ccf29, 0xe2fd14267a88,
+    0xff307eb4c6b5, 0x001a45adcf2b, 0x627bfec73fa3, 0x157596bd74bf,
+    0x86aaa57cd547, 0xcea2abf41267, 0xd5cd3494d20b, 0xfb94c434b798,
+    0x2f059b842f82, 0xc1a4599c51e4, 0x130b01857630, 0xaf0da0cd7093,
+    0x344064f00ad4, 0x3b468ebc351d, 0x5d8f0a3cea7d, 0xd699bb81e645,
+    0x3e1fe1d20a64, 0x52eb76058cb1, 0xc5e8a2c7ddab, 0xdc52eddf3c21,
+    0x1d7d4a88e00b, 0x3e8dc3fed7c7, 0x50421db76545, 0x5e61331f92f1,
+    0x673ff3284d14, 0xd484b33c5584, 0xf216c8402e62, 0x783ceed2c36b,
+    0x7fc6654ddd6a, 0x83596008038a, 0x9315ccf9eac1, 0x2f236712fa14,
+    0x51620c8db75d, 0x5f9a85f96574, 0x7529add92cb4, 0x76380e35d04a,
+    0xaf602d6873e5, 0xd7fcdc0dcadc, 0xe517671f4d09, 0xf81aa2c0d91f,
+    0xf9f07a4923bd, 0x2152dd4d835e, 0x24880ef86bfc, 0x29fc169af76f,
+    0x608a557affef, 0xcb9fcd94edde, 0x6f2d7d412aa3, 0x7421a6961ceb,
+    0x816b162f3dd9, 0x87c32cd34185, 0xb3135a5db9c7, 0xb66857b0c45c,
+    0xf1aa78e869e7, 0x16c25b770074, 0xeb4aebd6a3d9, 0x07540509c4a9,
+    0x28b7c1dbf081, 0x29125718dd3d, 0x4b76f5515844, 0x606304da0dd7,
+    0x94c3d03829fe, 0xba03f51ab7fb, 0x4879e488f132, 0x50dd1ee17fc9,
+    0x575fc16fb497, 0x5fd423aef3e5, 0x6652a82ec0d9, 0xa358543ce372,
+    0xb9b978fb5b20, 0xff84810eb771, 0x116c8d0a5cd9, 0x34ce63b8cabf,
+    0x37d092cdacc2, 0x577ad55e5968, 0x66edd4fa049a, 0x8405fe36da4f,
+    0xddb850ed3aef, 0xb186396a7a64, 0xb6e919265520, 0xe08d10d69cd2,
+    0xf0b784825b2e, 0x245397691589, 0x2d316d35b898, 0x54b39be23a30,
+    0x832882ddfba8, 0x86270902815b, 0xab375f215983, 0x76aba8bcfcb3,
+    0xf9553f55a106, 0x225a774c44aa, 0x3782afc3e7a0, 0x3b4070f744a7,
+    0x5ed250f86e27, 0x83cf359a5be6, 0xa0fa5b36107a, 0x660824fb4483,
+    0x7062274f5c88, 0x983a929b5d3d, 0xfaca0b714f9d, 0x1ef408f875da,
+    0x256ac6797ea4, 0x60ec44726258, 0x7eb63fc9b6fd, 0xe64fd796c585,
+    0x0ade7aefd65a, 0x1fe12fc7c71c, 0x44ffef190abf, 0x6e7418059c9c,
+    0xb0dc2eb18e67, 0xeee9d377b30a, 0xfbdc56c4438c, 0xb488de0036ba,
+    0x423790d1ceae, 0x486eb702938a, 0x7be66b14b88a, 0x327be98ef4c9,
+    0x33b71f6c0333, 0x7f964e35b1a3, 0x2f1e8b9210be, 0x45fb708fb9bd,
+    0x54872d579bf0, 0x97ddd8b80f5a, 0xa6df28a98717, 0xb966697cdc17,
+    0x14f44bb31792, 0x3c0f50686f1b, 0x3dd17be4c98b, 0x893684586f0d,
+    0xfbd0dfc10397, 0x1729d6c4c9dd, 0x18a4a3f21c35, 0x1f574fd30c9d,
+    0x54d559de435f, 0x5635d8cbfe38, 0x7309626e1111, 0x7bd26763a719,
+    0xb217fe9c80e6, 0xe56adebc0501, 0xea6bd75a3a61, 0x1d91324c4259,
+    0x6ab61ca60899, 0x94c730d4105c, 0xa870f32e04aa, 0xc67985978a26,
+    0x03b10f709681, 0x4e967425a89d, 0x5e94fc8d1339, 0x8fc26ab874ea,
+    0xbe6377878fe7, 0xf0e24bc12fa7, 0x812fe72fe1a4, 0xa854875c2b7f,
+    0x372bae3af8c3, 0x38be4582a323, 0x5d6ec38b09dc, 0x6329b45e91dd,
+    0x7cfd1df2f6a0, 0x231ce6f30a56, 0x816a67aa98e5, 0x91186b6a14a1,
+    0xafc3db9ba605, 0xb92538a63e8f, 0xf8279f368098, 0x04aeda7d7f31,
+    0x43caa51141a5, 0x7de158f866df, 0x99befc053f79, 0xbe2fa82e5c7f,
+    0xd0924f9e165f, 0xf74a0ddba5ee, 0x2a4bf4352f9f, 0x6c2e749bf802,
+    0x7155db15f6aa, 0x73e91934ea74, 0x870b70d18732, 0x8f3fc6a27708,
+    0xc95b82d41b30, 0xcf9c447bd25e, 0xe597297f3711, 0xfa365d505a14,
+    0xfbf5ca186f48, 0x66c4ce1f6c33, 0x7ef2a40d47b7, 0x8fa447e757de,
+    0xdd40d471e795, 0xec5bfd84eb47, 0xef035ccddac9, 0x10f73598396f,
+    0x147e85d43950, 0x6095fa0d730e, 0x9bbb836f4768, 0xca7df5cfacbf,
+    0xd02415cc27b9, 0x133f8279c4d4, 0x1bf3f127539a, 0x3a1328e01445,
+    0x67020d4e68b0, 0xd30b5cf65cf1, 0xd7f9ce53befc, 0xeb0f1c1812dd,
+    0x06584a8f4b0a, 0x5c10b3236140, 0x62d39caeeb4b, 0x73d8495629c9,
+    0xa6ecf17f8c94, 0xd1fb78b28cfb, 0xda8b136d7adc, 0x092afb717900,
+    0x10c76e4b71de, 0x574b49c903f7, 0x0d114f89cf3f, 0x1b12a8510ee1,
+    0x441178a310f9, 0x80b78729c7e8, 0x9fb6002887a3, 0xd3ae07a65158,
+    0x0869dd5f65c5, 0x216eb87670a5, 0xf88d45a94d10, 0x3b9ccd49be27,
+    0xbbd53b01ca59, 0xcd58a12b8529, 0xe114b0374707, 0xea31331816f0,
+    0x0a5a69ba45ad, 0x1bd608e53c53, 0x3b7257618908, 0x9dfaf374ce52,
+    0xa9a621481ea1, 0xfa7e0c9332bd, 0x2b9300f72b17, 0x59d176fb8f69,
+    0x6632b984d044, 0x6e27b29481f1, 0xa395f4132a9a, 0xd096eaa446d0,
+    0xe77ae6f3a2aa, 0x5832d619af43, 0x7f193e4fd7ce, 0xaa6045816948,
+    0x454e91e62a13, 0x5bd1cbe2fd6a, 0x773c9a7a85b6, 0x9a6fc7305aa0,
+    0xb5f72d9db41d, 0xd09afa27a0eb, 0xe6c6982ad287, 0xe98d888cde3b,
+    0xf2648196d434, 0x38daa38f0c21, 0x49eea3a4cd2e, 0x5a6ccfcacb30,
+    0x5deb6ecbefd3, 0x936c721a7196, 0x9ba67fe2f1a9, 0x1ed02f5834ec,
+    0x2710df545c50, 0xeaf9a36db194, 0xfbda665e6641, 0x3e84987fdb71,
+    0x8725e463c605, 0x91864e5c7f96, 0xc79d259ac464, 0xf6d5a042cacf,
+    0x71a7fa6cab7f, 0x967a18956257, 0xa3633afa064c, 0x6730c697acfd,
+    0x8d68316997d7, 0x90c027ffed6d, 0xa0f34ce68707, 0xa3431035d4ce,
+    0xaac325c90240, 0xb296099a5844, 0xbb4b9d1303e3, 0xc3b48f6322b7,
+    0x10bf2b7d5178, 0x1f39c05e82f4, 0x4004756e9afb, 0x463f0a00b662,
+    0x7111dd62d4f0, 0x9a83bcb92d1b, 0xcb83e046186e, 0xceb2aa4c5f80,
+    0x1e2f1ef437b1, 0x1f52ffd4f99d, 0x5ee2891a2373, 0x8b55ad49468d,
+    0xb67ad9cb8223, 0xddaaaf57e6b3, 0xde18f0a0c39d, 0xfbdc33c274ee,
+    0xfd260d60f498, 0x064bf129b10e, 0x261291012920, 0x2effba3c4ebe,
+    0x842cd493ab4f, 0x9860c05f3c69, 0x9c426b492341, 0xa461b5b4004b,
+    0xf04661e63933, 0x3b0050750319, 0x8efd6a4f6bf5, 0xd478d7f6f07a,
+    0x12b6860fd0b7, 0x24f2fa32c714, 0x2dbc744668a6, 0xecd11541f35e,
+    0x636903bd2ea2, 0x9ba96afdfdb4, 0xb3d188f448fb, 0xc31862bc9aa8,
+    0xc671f33c18a1, 0xf93099550214, 0x0bf4ed535b8b, 0x688bed0cb5e9,
+    0xa9b1b689e66f, 0xc2c4fad35cca, 0x3ee636e63c12, 0x4c5bcc52b685,
+    0x5be1c84d4096, 0x5ef93d573b2a, 0x1661685d3926, 0x1a64edf675e2,
+    0x8851598e0363, 0xec9b717091ce, 0x2a99c2c833e2, 0x4f2482a1e2a2,
+    0x8c5da6641760, 0x92bd4977d971, 0xd54b1e528335, 0xd5ec27a260f5,
+    0x622bb7c4ccd0, 0x73b21731d2a1, 0x8214a769a650, 0x82fa8ae34cb1,
+    0x9b2da3aaace8, 0x0460ea1118ab, 0x0848c2109b4a, 0x17bc715957cb,
+    0x304c68f3de70, 0x3eede0cee4be, 0x8e2540bdee89, 0x0c7f9ad0e069,
+    0x1ec9c87930f2, 0x1fd445567273, 0x498f577400de, 0x5330fb7beea5,
+    0x5f146ebeca42, 0xa0fd56ca3431, 0xa295e0987493, 0xe3932d32a7e4,
+    0xf325f876750d, 0x1a342de2897e, 0x4bceb88bab40, 0x526800691b3d,
+    0x5f83b045ba43, 0x791cc5635fcb, 0xa093beafc6ae, 0xb1efe2c39d25,
+    0xe54baead0243, 0xf013429ce5e3, 0xfe83c6e91983, 0x26a689c76d51,
+    0x5f04e8383032, 0x64e73123988f, 0x791f006e8bbe, 0x883018ec9194,
+    0xc2c8ba2883e6, 0xd63cf5c73222, 0x1576b78ae865, 0x4550b0a0d81a,
+    0x88e90258c35f, 0xb2159267475f, 0xb4eff4463d7c, 0xfb3438738ba7,
+    0x09d70fa8b493, 0x2b150e4c2872, 0x2c11d1a40fc7, 0x7d2570c005bc,
+    0x8ffaeca50fea, 0x314e1ef71779, 0x452098c30ec1, 0x736c8dbdc77a,
+    0x99236a9507da, 0xab30851253ec, 0xc5b4c0832488, 0xd230c66a086c,
+    0x05b92d44d299, 0x4ba01d80eecc, 0x66b76e2d3d37, 0x8af16ecff752,
+    0xcad60fe9836c, 0x03e49089a0d7, 0x28256def0808, 0xc361bad6783c,
+    0xe17dbebce821, 0x2370b75d4fec, 0x4ea65c192aea, 0x7dcb2ab54127,
+    0x481182db248f, 0x4d23a3600aa6, 0x70e810d92f37, 0xb53bba208fbb,
+    0x1925f9ecacb7, 0xc6bf70db4c53, 0xac27d9ef8d51, 0x54776007c712,
+    0x64168576cbc9, 0x85a41e861774, 0x4f8680872193, 0x9865c9d227b2,
+    0xdf974359d4de, 0xeb15611db494, 0x0a92939ef0a1, 0x10f1fc23aced,
+    0x24d737bf7d4f, 0x3749329db9be, 0x646cee1a42ec, 0x6f4e5b2bb281,
+    0x7fe48bfb0e50, 0x8bff5a715e27, 0xab1dd9a7459e, 0xb07b76899ba7,
+    0xd037cb3d1018, 0x209973b17d5b, 0x2c9f36c1ba66, 0x5ac9510d1d3b,
+    0x708c2b2ffc65, 0x96f4feaec577, 0x9d6f9faf3056, 0xcec8be8fe002,
+    0xff0e74c96d2a, 0x1008269f96b8, 0x16e8cccc0ecd, 0x37c1eb0e924f,
+    0x549783b7d915, 0xcc3d22e2a6a0, 0xd09ac89ac48e, 0xd3104a18ca4e,
+    0xe8630e7a9e66, 0x17f35fab4c51, 0x23b398e4af6c, 0x5cba528608ad,
+    0x7dd4dc90fe45, 0xb5517596829f, 0xd0ac7ba17cd2, 0xe67b3ad18800,
+    0x2e76ea392170, 0x7467b07b35ba, 0xb6cdf2aef95d, 0xda98c7ccbf7a,
+    0xe8979c6ebfa5, 0x07b238254507, 0x12ea56f43f43, 0x1b5816ffd740,
+    0x34818b7a7d40, 0x64d5e5757ba8, 0xc58c1861ccdf, 0xe2e02ffe8a32,
+    0xefeaf2837e46, 0x0f272e5a53c7, 0x490fad66eb8f, 0x7942dc5fa562,
+    0x99540e587652, 0xbb281b7ab301, 0xc64bde90abc0, 0xc72a359a93bc,
+    0xe85d396cf2ab, 0xefd512e35069, 0x26a064ee5d60, 0x359d96d16d43,
+    0x44e903c63254, 0xa020a95ceeb2, 0xe44c105057c6, 0xe6fc95f22e10,
+    0xf323be93b575, 0x737137b1d473, 0x8d207d00a9a0, 0x94b6c2c506b7,
+    0xd6400d4904fc, 0x122c5e296f0f, 0x22e812987d12, 0x659e6456240b,
+    0x6df62ebf9228, 0xbb47797bb7b2, 0xbde83690ffe3, 0x7d4ef61da1de,
+    0x9989b28ca6ab, 0xd0cef05eb6cb, 0xf0872703c362, 0x2f98315576ab,
+    0x349ac3dcd46e, 0x7c515e8b9f78, 0x988af62f876c, 0xa3e1f66a8c8b,
+    0xc8ac4d947f39, 0xc8fa88a63467, 0xcd23cc6e291c, 0xe2bba588cb9a,
+    0x27e987cbac40, 0x4f5ac92082e6, 0xb4102908a33a, 0xb5f887d1c12d,
+    0x0c0f2e93206f, 0x398a10eb3fce, 0x4f1380d376d1, 0x5f1e636618fb,
+    0x881b99cef2b0, 0xf34e93fac8e4, 0x08d7c953fa22, 0x5445bd9180a7,
+    0x5749c11e90d2, 0x8be814c5bcfa, 0x8f9906afd6e9, 0xb0b10c958d0a,
+    0xe16af69e8d1e, 0x223540a2b9d4, 0x954a377f62d6, 0xf0b58f59371e,
+    0x2dd3b52ec30e, 0x49b91f3a5fd2, 0x5bf976dcb844, 0x7fd489a51804,
+    0xb61418555fcc, 0xbbb0d769ee39, 0x1475f4c5efd8, 0x394b46c0f776,
+    0x001a51c66edb, 0x0ec3cb2f3f18, 0x80e1b6ab9102, 0xc3ccdf776cbe,
+    0x238f0046b1de, 0x5df2ca746f6d, 0x67ae479de929, 0xea28bf1fe8f6,
+    0xf71c2ca8908c, 0x14fe16a746fb, 0x377e9d0881e4, 0x56da2f696dd2,
+    0x2d3772863efc, 0x38b8c41c6f78, 0x3e21965ee036, 0xa26c5dee7ff0,
+    0xd3ad3d96cc0a, 0x65fdfb2c3f03, 0xa770a0610219, 0xafb2afb74c1b,
+    0xb4ddf9b0acd8, 0xf3b7e35df1ac, 0x3177fcbf7270, 0x3b2b785b223d,
+    0x69fba440e1c0, 0x8e544bc0a0f9, 0xbc209cfcc64c, 0x42f168562b67,
+    0x923f33056a9c, 0x12e8662b932f, 0x1a395ca81c1b, 0x2c06dfd5243f,
+    0x71114d7f869e, 0x9d0baab2c368, 0xa778e3f4c011, 0xb537370a653a,
+    0xc7ed96f45ec0, 0x30db5383f093, 0x5fa084989b26, 0x809e3c3eb9c8,
+    0x85ce53f393ae, 0x86558421e579, 0xc0bcbcb0f993, 0xe3a4a7986450,
+    0x1bf511f4c740, 0x26d051f09b0e, 0x2bd9fbc9abac, 0x5b098aa7d114,
+    0xa354d47b4250, 0xa52fb44e0bdc, 0xbe62fecea9f3, 0xe8ff26aa6596,
+    0x1493099389a0, 0x374483839737, 0x66da521ccf9a, 0x7ac66a7b4d12,
+    0x8208f7c56a83, 0x535872fac335, 0x6539a233bb87, 0x69cb34bdf724,
+    0x6e173d7158d1, 0x7abb4b1d2e60, 0xcc48d600277e, 0x7a1848c818b6,
+    0xc3b448fcb6b2, 0xd3cfa3c8f844, 0xd3d73038b6a9, 0xe2dd4de5a78f,
+    0xee1d33b365f5, 0x03e8f9a02622, 0x1fd7ff22b538, 0x8c3e682cd5ac,
+    0xbf080916d40f, 0xbf5ad754fca1, 0xdd0182a0dcc1, 0xfdb014f92f21,
+    0x21ccce1906bc, 0x2db006ccdc94, 0x4a3fa0813de0, 0x88d350aa9775,
+    0xab1cf0f669ae, 0x05913d6fe6de, 0x23fe008d4b68, 0xd230f45c0a5f,
+    0xf3a0963b623b, 0x37e685fd6893, 0x7f50ba12ac97, 0x20e01553a189,
+    0x31f206622ea9, 0x7561db371cb9, 0xc7509d8aa9e4, 0x0adac09f2f73,
+    0x5eb75f246509, 0x97525ba220d7, 0xcc6592c5d13a, 0xe25b34efb13a,
+    0x565c01cc969f, 0x65c7ea168ef6, 0xd5479ca2d826, 0x131c11bd5a67,
+    0x1af08f77d8ce, 0x1d668613ea18, 0x25c9c06c78af, 0x3012604ada38,
+    0x3e81bfcbf0ff, 0x9c393204b6be, 0xa8f78df023cc, 0xbcad6fe649e3,
+    0x6887ef2adfd5, 0xa353586dd2fd, 0xa876db686f90, 0x1adc9a51d51c,
+    0x24eda46dbca6, 0x24f396f452a8, 0xf54d2b94711b, 0x1057acf77c6e,
+    0xa8906b6fe801, 0xfa66b3fc0bd5, 0x108fcdd9fdfa, 0x40bd0a4c21cb,
+    0x66270b6bcf9b, 0xdac8a7098cfb, 0xf635f9c654be, 0x890695a960e2,
+    0xa95306550821, 0xd3def0862a21, 0xdc143093310b, 0x0a47e6558632,
+    0x49742922e80e, 0xd7c126901886, 0x49626791220b, 0x8516730200d0,
+    0x89d5159bad06, 0xcf29a0495cb9, 0x0b6e3cd079d6, 0x212f07ec2513,
+    0x3ebed359c43f, 0x4e03638bdc3a, 0x952b419c9714, 0x60c499b9e0ee,
+    0xcd978596ac27, 0x281bd52bbd35, 0xa6399131b628, 0xd43db2bf5b3f,
+    0xdb536a890ddc, 0xf64cc4056878, 0xa674ab5782b5, 0xe3d5d6dea91e,
+    0xbf403235e171, 0xee7e9f647dde, 0x2c3bbf82b3a1, 0x3033d52ccfe3,
+    0x9c9c4d3e2444, 0x5955f58a4680, 0x77a53695c2c5, 0x96232199d806,
+    0xb0b79ab54245, 0x22cd4a14c47b, 0xa3015f2f630e, 0x2af5964b7e8b,
+    0xc4c2fb12c86b, 0xc634dc7639c0, 0xd6362391e998, 0xed8910d733ab,
+    0x0757e73f250a, 0x2c66de03da94, 0x5a4f51f7c58e, 0x776f07a459a8,
+    0x877382925a46, 0x8aa71a541a81, 0xd02f1cb5dc59, 0x3691279638c7,
+    0x6059f339215e, 0x7090fb9f1d0d, 0x922bd915b75a, 0x20e2bf2e571d,
+    0x6dd9af3c95b0, 0xd22a6952cb6c, 0xf28f1936d63d, 0x404fca06f58a,
+    0xe25a70691043, 0xffa5d6caf258, 0x2825ed9a781e, 0x551caf098cb6,
+    0x5d81f8694f3a, 0x4d5cdd991194, 0x76db51a9a712, 0x7fb5b914f396,
+    0xae28cb9886e6, 0x1b9558e27b3c, 0x2b35ddaba291, 0x4f22185ab308,
+    0x749abd3bd218, 0x790c841debbe, 0x853c98895426, 0xbb888164d37c,
+    0xe76d2384ec2b, 0xf796e7839938, 0x8a86d9f0bbc9, 0xb02f4d9590ae,
+    0xbee645f4b552, 0xce8c82b56ce4, 0xd80ed033443e, 0x13362e0d46c0,
+    0x29c462db2f07, 0xda77234bf75f, 0x81362123baad, 0xbdbdc35c7243,
+    0xc21f4c93f246, 0xe35250665135, 0x672df6831d2a, 0x7e57421a4d2f,
+    0x7ffe6fa9fed1, 0x0cd657e16d2e, 0x18acc14bffee, 0x25e5b8ab8174,
+    0x49cceb1012fd, 0x54fa842c03d3, 0xe882941f2321, 0xfa2048887570,
+    0x295d1bcde97a, 0x464192c7a745, 0x5baf30891a69, 0xdffcfb6e255d,
+    0x13c150a8d728, 0x289ebc701b7b, 0xa37bd6dc0b56, 0xcc6a698373cb,
+    0xd4b5d9c1aa5a, 0x2b419e8e939c, 0x37c655b052be, 0x3a795f77687f,
+    0x41523ea51c6a, 0x42b198c7ba6a, 0x910c3b96049c, 0xbecc6d35f826,
+    0x097e5a36f81d, 0x34fda6d814e5, 0x40ecc49916c1, 0x5ce26c4f4d28,
+    0x710864067e51, 0x8cb130052228, 0x4e670334009b, 0x8164cb7f2272,
+    0xa1b51faa2bc6, 0xc4198353a108, 0xfc96fdbf7491, 0x22f1680bd228,
+    0x462a87483d14, 0x50c99e55237f, 0xc7f16e398bcc, 0xd0b913d4ff36,
+    0xef6c4b0554c4, 0x022c80f54661, 0x49e8d39ba1a8, 0xeccffc8f7635,
+    0x7f019e29d886, 0x9a7fde3e4b3b, 0xd7d1b656ef15, 0xe6c46286f592,
+    0xffba24be83a8, 0x21af5ce304ec, 0x26407a4f34ff, 0x3ebe2537ee14,
+    0x732f2e40a3de, 0x763874a738bc, 0xc53420c560ae, 0x180a9b0ac262,
+    0x25bc1ed72473, 0xa26ed63c5927, 0xe6b11d0e032f, 0xe94d0c67d237,
+    0x0b703ed7db28, 0x1d23ae545192, 0x27fa95b2b7c1, 0xd3874f03a40f,
+    0xdd8f32c6a4d1, 0xeefeece46f73, 0x125887760da5, 0x55d7752a787f,
+    0x6cc3e825a9d2, 0x7f470aea96f8, 0x94a9d267feee, 0xab0c1bf19375,
+    0xb549c0fee00e, 0xccccf225eb80, 0xe1253cc611f5, 0x0aab3d4665d8,
+    0x2c3b5a7af0fa, 0x5402d86f46bd, 0x5745c39f8987, 0x5f5b7293743f,
+    0x9d4ddeddea4f, 0xad1c5df29951, 0xef3932d020ef, 0x336d106f5f7c,
+    0x37142c538154, 0xb813d6a82ec9, 0x053fd853a7e4, 0xbb98f34ff0fb,
+    0xf2b9d24d6bc0, 0x02a7e27b36f4, 0x87e7eb051fd7, 0xb87e9b04a769,
+    0xe53a07bee9af, 0x0dbc6824a927, 0x9932aca33fd2, 0xb1eddfd508e8,
+    0xff8eff8c0c03, 0x4ce37e1a8253, 0x52c467abc812, 0x96dcd2405247,
+    0xdc90bfe667df, 0xe34d41cd505e, 0x2023cec4ce71, 0xf57f80ce3f1d,
+    0x14c51d8d012b, 0x167c4e3846ab, 0x2195510c303f, 0x2d00ad0036db,
+    0x7cb0243e15a7, 0x8316dea38a93, 0x855664483eb7, 0xd5211401cc16,
+    0x36ba312478ad, 0x9768d7a10b7f, 0x9c511be8d1c3, 0x9dd37585e280,
+    0x65961d8a5a72, 0xc5b60768dd14, 0x173ec4905d09, 0x26dcffc34aac,
+    0x369d503493f8, 0xc305d2580892, 0x5b391367e9ad, 0x8e10e2f54dc6,
+    0xb6c7302d2aed, 0xea02f5df684f, 0x062af07e9402, 0x31310105bef0,
+    0x83d3c90808ba, 0xa7ce6ce984ac, 0x073ea9b6b84e, 0x0a9c92d3a3e6,
+    0x27effac34fea, 0x35ccf4b5fa31, 0x7b0f32b1dc54, 0xadce8877d716,
+    0xc1c2dcf32442, 0x19b93142c639, 0x2cbeb67a8c01, 0x62e9aee2cc87,
+    0x04b5e2bfc02b, 0x13fc476a064e, 0x1fbb96675255, 0xa657c5418872,
+    0xe9f85316436b, 0xead07d0a8fa0, 0x5bd50d592904, 0x8eda9ca30f05,
+    0x9c6e63ce830c, 0xe05cb796e7a9, 0xeea61e5a40b2, 0x0ba901a1fdd6,
+    0x384517d229af, 0x6cd193e593d1, 0x1beb1adb0a78, 0x16254538698a,
+    0xa5f427249f35, 0xb3e62c7d5c48, 0x0112ddd5e6b2, 0xa1bf14517a7f,
+    0xa9e32c77d0b2, 0xc449e5d6b909, 0x34d8334eab94, 0x4b419429f2f6,
+    0x5d6dcaa24fb0, 0x79da2a4d387a, 0x581b7922105f, 0x7b419bff9c6c,
+    0xb4051560556f, 0x2aa8ae59b6f2, 0x526bd3b09f16, 0x96054a33a734,
+    0x22f70b8ca186, 0x2cdfd35491a0, 0x405de4f937f3, 0x6a258bd103b1,
+    0x93f0db992bbf, 0x99321b34b65c, 0xe8381276e1ef, 0xf4305690ca93,
+    0x55453cc87060, 0xac0fe9388c41, 0xf4a2cbea0b5a, 0x3f656bb3b07e,
+    0x9d5adb5391c8, 0xd48f665cfcbb, 0xf40497506ed9, 0x21789ce5e122,
+    0x5a7ee1692ed9, 0x5c09020503c0, 0x797a26959dce, 0x7a9814efc9b8,
+    0xa0bda82df28c, 0xc31ed8051708, 0x1f371e3bf514, 0x2e69272d39ba,
+    0xa7aa5f6b8eab, 0xa8ba1f2254df, 0xb932cddfc869, 0xc15586abfbfe,
+    0xe57e99f70b27, 0xf0d2db7dcb0a, 0xfba48002ac86, 0x3f278bc57f3a,
+    0x670ffa2d13cd, 0x81d8258b95f6, 0x8eb771ea41d4, 0xdd6eb8fcacaf,
+    0x1309204eaedd, 0x15be391cf88b, 0x2ee38f8936b1, 0x3d9d0c0d9acc,
+    0x74709a357777, 0xcf9f19734d0d, 0x3c4bbc0158c0, 0x535fc1a37034,
+    0xa9ebc0ff5f3b, 0x27c21eb65016, 0x85e045c8345a, 0x8e7264a861b2,
+    0x900fbc321b3d, 0xc49e81dbbf11, 0xf0a86d0c842f, 0x2edb5ae35541,
+    0x3abb2535d48e, 0x449c25ff007e, 0x4e3b8070b493, 0xb84ad44acea0,
+    0xbf0cd39a4197, 0x030119e93c36, 0x0dd6a1ed7fc7, 0x5c8c1487bc7a,
+    0x74637347131c, 0x806bf50b9563, 0xa7ebda21dd49, 0x52b8a8b6e0f4,
+    0x546e3f78fe11, 0x623c208c2b8e, 0x97bd23daad51, 0xb382fccd2de3,
+    0x1946f7a0f792, 0x22bef9facb4b, 0x5df06869cba9, 0x65f14686a95a,
+    0x7fa93d1059c5, 0x8cfae8c64a67, 0x9fc778e17392, 0xe978bf0b2a1f,
+    0xfdee169f2d4f, 0x73e5ab779c22, 0x7838565ea520, 0x8c3a29d046b3,
+    0x9c7b7d5a4603, 0xcd190a1c7aab, 0xfade4406ed23, 0x0eca96f47060,
+    0x1ed9d2248029, 0x7cb568a2d57e, 0xac4d0bb43c09, 0xe88f5ea51f74,
+    0xeecadd7351ef, 0xfe390f5383f8, 0x2bf5d29220f7, 0x32b00ae2cfc4,
+    0x32103c600ae0, 0x60747093272b, 0x6dcff24f0fe4, 0x9c638027d67a,
+    0xbf16ce901239, 0x4ecd36b4de8d, 0x7484fdc83601, 0x477d05e762f3,
+    0x628e640d1b5c, 0x7380fe91920f, 0x8006da7e6e0a, 0xb95d691aa4dc,
+    0xee526e7f5bab, 0x4050f95f76ef, 0xaca4660ab884, 0xea6a71f2cf4a,
+    0xfea1cc1fb6ef, 0x9c5e5ac73c0e, 0xacd410416497, 0x0bbef1587496,
+    0x493980e31b6a, 0x61bce3c364bc, 0x85e9656fca70, 0xc15cef544e33,
+    0xe179913c3f9d, 0xba9f24153f19, 0x28da7a6b9317, 0x900904c2157b,
+    0x9c82c112bfad, 0xc6d332020599, 0x6bb2824b8632, 0xe5db9708b96e,
+    0x40c5018dc09e, 0x854c5d18390c, 0xfae070e9da20, 0x1f8544741a9a,
+    0x325f8e149df1, 0x3b69da459c4d, 0x869b307629e5, 0xbc3597c82c13,
+    0x0d973766d171, 0x11974e9eb7a2, 0x564e78ece5e5, 0x8001897caa61,
+    0x83288c842d49, 0x9d3efbca2c85, 0x299322349fa2, 0x84245714efcb,
+    0x6df476764c93, 0x7480ef0a7978, 0xd16ad303f86b, 0x92ff061286bf,
+    0xd657fb0933db, 0x5687f2156a94, 0x7f59ece701d8, 0x9ac40bd178d5,
+    0xcd409674dee3, 0xfd3ad050fc19, 0x3e71fc43e31b, 0x5e1efd88d4fd,
+    0x629ca9781aa7, 0xda7d4cc6b8bc, 0x39dd86785880, 0x868f9c5cce72,
+    0x94a3f182ecf3, 0xa74afdfe63ea, 0x23bc499b2688, 0x9ef6b7f8485b,
+    0xade83f5d31ff, 0x70a6f5802990, 0x72ff6a0a5295, 0x8a0ccd78c2b8,
+    0xdefac59de6e5, 0x476151ff26ed, 0x4ef8f71da6a2, 0x8625809947a6,
+    0xb63ffb3b925d, 0xbe574418a578, 0xd5c719c4c284, 0xdce5f7e75be9,
+    0xe1da965a59bb, 0x062eaaada590, 0x07c7347be5f2, 0x29f561bd03d3,
+    0x73fb4a9d111a, 0xa3f92d698aa0, 0xe6e87443ce36, 0x320275780f50,
+    0xd0174fc64779, 0xd511e0bfdb4d, 0xe2e3a5526adf, 0xe69438207547,
+    0xf313374aa42c, 0x5dca7137490f, 0xb45bedfbc28e, 0xc8266639efe7,
+    0xd3ed41a6fa53, 0xf8b00c612e9c, 0x788b019aff14, 0x81105131e954,
+    0xb6b578c5faad, 0x0e7053d3c090, 0x30f7b6f7a88f, 0x9900ef67b69f,
+    0xd601932b5a4a, 0xf2c603edf059, 0x268b08d5b373, 0xa970533f399f,
+    0xcc2e275a7ec4, 0x3efdceef539b, 0x62fa0cb9f7f0, 0x87d7d560d507,
+    0xb48a1bc23085, 0xb49c2de4f850, 0xcfc5562508c1, 0xe991a867baa9,
+    0x09fab0e93319, 0x1b08f90763e9, 0x42d99d54a074, 0x5d1296f706e3,
+    0x9389f5f8ce6f, 0x98689ec9a261, 0xb5fa3eaf6fb5, 0xc119a927fad5,
+    0x0295af44aa78, 0x4c3b857f408b, 0x53d6d38e1c90, 0x5ca6b5465fae,
+    0xc54ee7ff1791, 0x77aae506c578, 0xb7d71efe5f6f, 0x4cdc8044108c,
+    0x694c13c14a1c, 0x0b46f196d6bc, 0x2d6297972148, 0xafac5619542c,
+    0xe700e759e6db, 0xeb740d96231f, 0xf1f25786ea4e, 0x1dc2635efbbb,
+    0x94bc7386e220, 0x9ad826acf2a4, 0x0ddf6404417a, 0xa86bc94b5ce9,
+    0xb84df46bfc19, 0xdb4114b3818b, 0x026b324855de, 0x0c5a61b4e784,
+    0x160984b329b2, 0x3c77d59a7d3c, 0x3f9159ababbd, 0x843b3f928b5e,
+    0xa24656215051, 0xc4b9d2aa425d, 0xf13448ba1e17, 0x0ed7fac2b336,
+    0x313baebf1729, 0x32b1ae30f058, 0x345ff31254d5, 0x84a578cefc71,
+    0xbb69b388568d, 0xd4b53d415836, 0xdfa3b42b10a8, 0x40f2eed975b0,
+    0x693ac0397028, 0x889e8eb72bca, 0xaab9fa287091, 0x2d0811481114,
+    0x5b7d32d2d851, 0x7649f15dcdde, 0x7dd710c9442d, 0xa4cedb752334,
+    0xdf7cc2a62e17, 0xe5f34598311c, 0x78de2fbad4bf, 0xbc7dd35378a4,
+    0x2f286c66a6d1, 0xc8aa54d080bf, 0xf5b29d425595, 0x17ebc5548866,
+    0x21cd3b3bbfe0, 0x22ccf7d6f236, 0x47b2125e87ed, 0xc93a4c3ba9cb,
+    0xe561972dc42f, 0x00d79b256730, 0x0f1089af20d1, 0x1df3e270a4bf,
+    0x310fd2689ff1, 0x3c5694f90b6d, 0xaffabd91d029, 0x1775e910df58,
+    0x811561b8f418, 0x499410f9f298, 0x548d4825ba35, 0x7a1c57c70eaf,
+    0x1fd063a384f2, 0x20e5a0c2d59e, 0x564837c35808, 0x70ed291861af,
+    0x731101235bb6, 0x7344b36673a0, 0xcef7698f5000, 0x3e427f9c6a32,
+    0x606f88126b3a, 0xa37783431dec, 0x05d1afeccfff, 0x14d0069afcbb,
+    0x2f3efc16b4c6, 0x3368054fa3b6, 0x411d6c9b67dc, 0xd032a6e34cae,
+    0x13856ec45e03, 0x958216fff892, 0xd67677d04ae0, 0xe63c0b1b18df,
+    0x23ec216105c5, 0x9ebef0a8f1ff, 0x9f8a85e300e1, 0x08d228026e21,
+    0x0eb88efc3e58, 0x45022e4183fc, 0x47ed42e91f46, 0x48463e3ce39f,
+    0x97246849234c, 0xc32c09e3aeb8, 0xc713e1e33157, 0xe76903c9b3d4,
+    0x428bd889632b, 0x79da0225544e, 0x58dfcee2883a, 0x638b7aa9abf8,
+    0xa695f9ff9e21, 0xf96522644735, 0x7d1b0a525b31, 0x8da64050a210,
+    0xa2c6773a7b17, 0xea86c3623476, 0x1e794d7773b3, 0x2a0dd5fb88d3,
+    0x2f1aee35effc, 0x5d7ac9cb9ea8, 0xf72e55715c93, 0x0fc738d5c43b,
+    0x1de2a54d9597, 0x1dc4da2199ce, 0x59836b4581f9, 0xd344a65e79d7,
+    0x4a52d7b05bf4, 0x724b49338465, 0xa023ba0bdb81, 0xd3f6b307fe8c,
+    0x202666799bdc, 0xa487684c8b74, 0xbc73d271f4e2, 0xcfb287555d0c,
+    0xe56ebf4494e5, 0xf4bfe430ea33, 0xcf5e3530081a, 0xfd663a3d0f46,
+    0x8d7946a5fc43, 0x9e85f4db04b0, 0xc1a8e3e63ff7, 0xc654c4b01313,
+    0xcf217296056b, 0x178f8154e39f, 0x1e5cf0937710, 0x36474bb1ac91,
+    0x55a2de12987f, 0x2dd2ff746946, 0x872629c169c6, 0x8c551cab26d3,
+    0x8fffa6f09f36, 0x1bed5310f993, 0x47b4e83d3587, 0x4fcf2a5d93a3,
+    0x97bef4b8010f, 0xa27f715631d4, 0x0d7b856c9f58, 0x9b309fe2d8d8,
+    0xa74c58953fc3, 0xedfea37c6513, 0xf07424fa6ad3, 0x86e6afea101f,
+    0x87d370266396, 0xa0c1a5ee3347, 0xfb245562f436, 0xef3dc44fc3d6,
+    0xf91e4ffae43c, 0x48ae361fa6d6, 0x5f0ac7b1ab20, 0x6f353b5d697c,
+    0x96f8ebd4592d, 0xa3cfc1872f92, 0xa85f450a3742, 0xa9f4d5952ca3,
+    0x55222bedb30c, 0x553095201e87, 0x87fd2f4d67eb, 0xcf02bd90c157,
+    0xda22d7b65dc6, 0x6a824ab8d1c9, 0xa5a340ee32bf, 0xd1eed2766fb8,
+    0xe2eb4300de82, 0xf11bbadec2aa, 0x6309d7975ebf, 0x74ac1f2d2472,
+    0xaf9be744acf5, 0xdb7bbba94c7b, 0x831024c116c2, 0xacc544580f2b,
+    0xe1a8722903f4, 0xe32588ec8ffe, 0x3e76cd15d28c, 0x642cfa40019d,
+    0x8510d8c4944a, 0xffc959cce905, 0x13372dc6f5b5, 0x8fc7cfedcec0,
+    0x9e9692674b6c, 0xf27af7eb452c, 0x8d0fd3de3614, 0xd16cde0d8325,
+    0x156283b6a062, 0x5b52252d97d3, 0x0415df154480, 0x24ebc985773c,
+    0x5a114d5af52f, 0x663db8d60147, 0xcabfdb99153b, 0x2be7be2f99e7,
+    0x22bd22ad604b, 0x5fdd1cfb1a63, 0x62b53543d6ce, 0xa4b0a4901412,
+    0x4842724ab8b1, 0xc1d996978af7, 0x3abfe907f164, 0x9856a55c7e18,
+    0xd598b4e893d9, 0xee930a4c9553, 0x2255c59e1ebb, 0x44e097235d45,
+    0x9ca632733253, 0xb009462b2fd8, 0x39db565d5fe2, 0x8dfafa942ff1,
+    0xae8be64d904a, 0xe4f26df9a8e7, 0xa676d87ba922, 0xcebf9417baae,
+    0xf94a597dbfd5, 0x385f81dddaf8, 0x52bd14e3dc87, 0x54210232edeb,
+    0x3ab49e08a975, 0x7995d996a6e9, 0xa35718c9d11b, 0xade5b79d181e,
+    0xe9de62a8b9bf, 0xe7fcbc153f20, 0xe90e5044337c, 0x2821debb263c,
+    0x51ace785f90d, 0x9938812c876b, 0xb444275aaf97, 0xe707d5be17f8,
+    0x1e521bdaa706, 0x41696067bc0e, 0x5961750b4bbb, 0xbde1c3563187,
+    0x593ae2a278b1, 0x73a2863d781c, 0x9d890e814ebd, 0xa325cd95dd2a,
+    0xa7b884eb35a0, 0x130d59c661b8, 0x4ba7a6970c37, 0xcc390fa2dcc2,
+    0xd690c860bb15, 0xd7b5ce0c99da, 0xdfbcd93f1352, 0xe25e46016cd2,
+    0xf8587b57c036, 0x216f5053201b, 0xd2c4ee8bf45b, 0xed8343e47e6d,
+    0x078b848717f3, 0x345cac547e54, 0x4d44ef95b803, 0xbffaf89806aa,
+    0x43013374cba6, 0x497238fe4433, 0x94e485d93857, 0xa54da699238d,
+    0xec81ded515f5, 0x8343ee2e970a, 0x8fe69c8049fb, 0x921f7ff156ce,
+    0x1daa2028eef6, 0x2633c85d46d5, 0x4b599f003d53, 0x85bd692dfae2,
+    0x8d4ee136ded0, 0x3d802970e59a, 0x579155dd6023, 0x78ddbdb33f7d,
+    0x4f1492f4475f, 0xf664d18f5a54, 0x33c4d84b63e4, 0x47e16f1aa880,
+    0xbfe2c84d7fa0, 0xd8971ebc9ba0, 0xda714ee253dd, 0x51abe0969544,
+    0xed0ed5e8d485, 0x66d04b90d228, 0xf42bbb063600, 0x6d027f791419,
+    0xcd21448c79e2, 0xf2fda5036c76, 0x3e56de6be1f7, 0x47f4d983736e,
+    0x56c27731d341, 0x57ccf40f14c2, 0x62c0add251e7, 0xa97ccebf6f4e,
+    0xe954db1ebe15, 0x65b698bdf85f, 0x6d67dc6efe44, 0x7c33e03435b4,
+    0x964a8a093ab5, 0xaf3f9c93b77c, 0x0bc48a2a4a0f, 0x3f539338bb8d,
+    0x5afc24a8599f, 0x65bc66b7849f, 0x673adcd53347, 0x7ad5fb6db655,
+    0xb830f9df40f7, 0xcc39faa49168, 0x058678abca5e, 0x5461e403c4cf,
+    0xca9a34f79469, 0x4ae9828de18f, 0x64e3cf1c1b24, 0x6de2203df179,
+    0xf7fd28a851fe, 0xfa53c8ba4cdb, 0x2e32a6c39bea, 0x7483451c21a3,
+    0x82441c570643, 0xc8016ae50868, 0xeb9b11e4f612, 0xc18371854301,
+    0xde6c7bab7430, 0xeb5c05b4b9b1, 0x9cc75f00b20c, 0xcc46884aaf40,
+    0xcca959a46bb0, 0xfe7c29bd18c0, 0x1faeb8716c25, 0x46e35bb949de,
+    0xded9c42d2c47, 0x0f114bc353b9, 0x695519cc09c5, 0x9785612d0861,
+    0xb96ad0c4fb8c, 0x09174ebf8f57, 0x2d7030ce5455, 0x540f3ab574c8,
+    0xbd17baa2a9a4, 0xc569cd148784, 0xfccd3ca59138, 0x304899a81288,
+    0x4c6fe49a2cec, 0x5af4c49f6209, 0x5b71348ba4a9, 0x8f2e7274a399,
+    0xb5a91d872eb1, 0xc158de86f264, 0xcf80dfb90113, 0x5a96d7031ac5,
+    0xe5389a7dc18b, 0x686a4b80cebd, 0x9459ade2f697, 0xaab5ca56ef57,
+    0xb9b07814bfc3, 0x18f3df618891, 0x619615818a39, 0x766bf4d91027,
+    0xf2b23f6d9619, 0xf3128ca21312, 0x434849d22c95, 0xa8eadf14abea,
+    0xb34b0e7e5fb6, 0xd333fe233936, 0xdece3e9ede93, 0xed4cf18e77e9,
+    0xf7f3cf9a227e, 0xfc6fa7113a00, 0x042df49a88c2, 0x1f0daf845d2e,
+    0x25c095f4535b, 0x262c5317f0ce, 0x54646177b394, 0x55807b59b156,
+    0xe6e38e76b9c4, 0x0ed60d734c33, 0x558f351d1e99, 0xb1b3eb813036,
+    0xee05cc6f9bf5, 0xfc9b5f3d763f, 0x0e35dfd477c8, 0x283c116fdd61,
+    0x296ab292ae78, 0x7f9be222aafe, 0x83a4aa956c6d, 0xd0bc5087e40b,
+    0xd7e41a745e73, 0xe0b102e95690, 0x4ca1d8c9e17a, 0x1f73730d4524,
+    0x35023bd0fcdd, 0x3eec62f30f95, 0xa60ff2787d09, 0xa8d548f1605a,
+    0xe2b08396a980, 0x07722985c0f0, 0x2283c23a7f1e, 0x270bf3dcce2e,
+    0x505d57ddf313, 0x80af2db3b204, 0xa0bddb87437d, 0x226f07656e1a,
+    0x5570edbef7cb, 0x7f3b534b08c5, 0x97048366f9b1, 0xd4aafe35f445,
+    0xd735508b070c, 0x26973c6441f9, 0x44b2560e9aca, 0xd564974f375f,
+    0x239682ab7e97, 0x29644b50dbb5, 0x666effa97d3c, 0x7f1c7955ec26,
+    0xa26fe6d1ee91, 0xc2a9d03cc27b, 0xca5dd2a20d9c, 0xd8959c60aa64,
+    0xdb202944c2f0, 0x08c76bff4199, 0x250f6cd59508, 0x68b194937864,
+    0x7864db2c78d3, 0x7f83b94f1238, 0xb5debb0a0e58, 0x3894abcdea39,
+    0x81a52336c8cb, 0xf3f5270d0a3c, 0xae035201d51c, 0x13e78d9bcc4c,
+    0x345cb5b97685, 0x4e811913d5b2, 0x69c0718515c8, 0xa3d75ffb40c7,
+    0xbbab8ae846a3, 0x898343df27c8, 0xce49870cd2d5, 0xf831a939d1d9,
+    0x53464f561b7a, 0x8d8bad35d9b0, 0x95d006225d64, 0x9c44b49c3241,
+    0xc54b4becf883, 0xd3c063659f87, 0xda767da7e67a, 0xe8241df4e676,
+    0x69bdf4d6c495, 0x8c79f479db92, 0x98d45a406f57, 0xab6b9e2f5835,
+    0xaeb406c82577, 0xb0b84dba034c, 0xd2f46f0f811e, 0xcfe6ab7e74df,
+    0xfe939d5abbe0, 0x4a8eb44d2a32, 0x863331c080a3, 0x87f263f99012,
+    0x8e23d232c4b1, 0xa77cdae21ac1, 0xbbd7a9a780ad, 0xc4bbe718c548,
+    0xd1ba14e37c09, 0xd43d4fe6dbf5, 0xf0aa5f3ed60e, 0xfcc68d0f4883,
+    0x135ab463c6cc, 0x2ab4946db9f8, 0x5d1b63fdfbeb, 0x687b5002e20d,
+    0xb91a4631598c, 0x799851e48020, 0x93049181bad4, 0x9859f2474129,
+    0xb7ee3f35c3ef, 0x1cb7c047dffb, 0x2771602363aa, 0x3667b543c677,
+    0x85ad7e653bbd, 0x9b0fd0c49829, 0xec913c0b5f7e, 0x50036488a779,
+    0x76d5e623da11, 0x8b2803ae64bf, 0x3a87c427c6a5, 0x540ec722a462,
+    0x5908a86f26e7, 0x7a24cc6344e2, 0x2859cf3937c6, 0x5009f831410b,
+    0xa22ef0ed2597, 0xa6298abc8150, 0xbe3670371e04, 0xc9b15a28acf4,
+    0xe226c8f49655, 0x6948c8a2fb9f, 0x9698a9f2de52, 0xad3d0ef1f63e,
+    0xcb4b1ec40632, 0xfef2670adb42, 0x26a882897a89, 0x61e76feecf4e,
+    0x6bdfc5b447bc, 0xbd0ded9edaf5, 0x150aa9931e78, 0x542723d3f23b,
+    0x5adb2f0f0541, 0x79a2c1f85c4c, 0xcfda592cfa5e, 0x15fcc2aaf2a5,
+    0x34a39fb010a5, 0x84840a7cc21f, 0x899abe2e1b9a, 0xd5380c114c6a,
+    0x16160221694e, 0x17358a64bd9b, 0x35874f957957, 0x42ffa3b63906,
+    0xab64962e50ab, 0xd0ff504dcb64, 0x6a195f1369f4, 0x7450b27e1104,
+    0xbc91769e78da, 0x47f1c57ae5a5, 0xa5efe655a22d, 0xbc1a24feb12b,
+    0xdb4cb6d3869b, 0x451dd2b77f58, 0x4fc01d96b689, 0x556309c0e0bd,
+    0x70f56aff4f2a, 0x86f72cc560f3, 0xa8c1d8ffb115, 0xaa87ad6c67d5,
+    0x18a54721cdc9, 0x41335205b380, 0x3832583193f2, 0x40be0f6d1fbe,
+    0xadab49898276, 0xadfb93a26b91, 0xe6e0ad2374b3, 0xbc95950faf7d,
+    0x098483902421, 0x41ccb1cfb75d, 0x920c0a76c332, 0xfbf8244764bd,
+    0x0b64bc3e6e63, 0x2806a2a49d3f, 0x803fd818cfed, 0x8ca39ec7503f,
+    0xc1d039ee810d, 0x40d3645014d7, 0xbe5c36a261d3, 0x331fe77e7adc,
+    0x2a14a28657ad, 0x81db281243cf, 0x9d7724c7a48e, 0xab4a83435c83,
+    0xb84fc85fc61f, 0xc999feddde8d, 0x14a3d8698207, 0x44b3583ab7ce,
+    0x64f62d6f6cbb, 0x90441163ab4b, 0xc6ff60532464, 0xdadc5fd22512,
+    0x2fd4a31b4ce0, 0x1d411e1ebc55, 0x3a297897dc35, 0x4e23fa28c528,
+    0x5bd6a2c04412, 0x291ca5d5ca06, 0xbbd27e5b3321, 0xc1cd7b9d0a9a,
+    0x54e70fb84739, 0xc745f1deffaf, 0xc7df34301945, 0xd67cc88bbd7e,
+    0xee8380f0be6b, 0xf0f225ac1715, 0x459f9c44e040, 0x460775e91b9e,
+    0x8fde3fb2845f, 0xf355ab09510d, 0x5aacb242d0a6, 0xe3cd56ed7f8b,
+    0x2145d770f272, 0x98d9d99703bc, 0xb3efcae92f89, 0xb58102d6b74b,
+    0xcea4f9e8d2d3, 0xeab85e3ff744, 0x6aa1e1393cf9, 0x9f7929fd05be,
+    0x24f4e657d07a, 0x66411db0ca48, 0xc0e5ae0c08d7, 0xde1f3cda28e6,
+    0x1ed0d287e680, 0x4a2c70017f3c, 0xd2f353a95c76, 0x04fa4b232cfa,
+    0x143490314154, 0x2160f2d68587, 0x4ff655277a45, 0x562df0764aab,
+    0x2d96258e68e0, 0x41d790504e61, 0xd0295b78f802, 0x7e8ce3ba7a20,
+    0xb35b06255c1d, 0xc5f6a2b1b29a, 0xa6063c9f16b8, 0xba3d21adf2d3,
+    0xf4ac213bcb17, 0x1f2ac7059e75, 0x6dc7bfd871d1, 0xce9c5f12c261,
+    0xd2e8a25529d3, 0xf479fa56cdd1, 0x0a637ce47c08, 0x3d3977f9b1f9,
+    0x6d1162086dc1, 0x6d2bb0480540, 0xb267869f45d7, 0xd63fa0245a96,
+    0x09ba4d79ca97, 0x0eb13583021b, 0x5d86ae54668a, 0xe8ad1dd81fa4,
+    0x002f2a340e3d, 0x5f0af26ba172, 0xc927158efe97, 0xe128508b750c,
+    0x98a07ead06e6, 0xde5ce2fef1f7, 0x7466597d4309, 0x00f165fd1ed6,
+    0x6eb04c673034, 0xb89753db3298, 0xf76642b7627c, 0x1729399e7e3e,
+    0x6df7546ff0aa, 0xaf794d13364f, 0x0ef2008c3f6a, 0x1e6b67cc8c28,
+    0xac6d236b5273, 0x204747dd68cb, 0x9db67b9d2f97, 0xb2f29aafc880,
+    0xc3a85c984081, 0x28d53a24208a, 0x48f3b084401c, 0xf0c2a8a1cf63,
+    0x1859e2b463c7, 0xcdc0fa9ad4b4, 0xcee6ea82ca8d, 0x39da4d5e5cd3,
+    0x5d8b495a96fb, 0x790a391c1aff, 0xbd26c1bf0d0e, 0x3ce961be7df1,
+    0x78f4597bed22, 0xf85880bf0f34, 0xa81bfcce449e, 0xc53ee64d297e,
+    0xff5c3c67f609, 0xadd27077553e, 0xbafd3933710e, 0xfbcf84c567b3,
+    0x8c23634bb17a, 0xd6ffa1a7dcce, 0xf4eb61ac8b54, 0x0db2ef098bf8,
+    0x391b213d6207, 0x5e7c71223499, 0x6d25affbff11, 0x784a22bf091f,
+    0x784eb5eb7c83, 0xb8ab6e53dd9e, 0xf15a16dd899a, 0x805c7953994f,
+    0x26063a0c0bf1, 0x5d3f5841ea48, 0x94073beba0b4, 0xb56a0e81b578,
+    0x31ee910c5cba, 0x7ddee7bcbbe1, 0x964ba54dca04, 0x96719e0b87c2,
+    0xd83fc2b94ea8, 0x2d704b7201c4, 0x2eeced178244, 0x7c64a5af7116,
+    0xc4e03364a3b1, 0x9e4adaf67193, 0x0285d9f98334, 0x977e6bf5f139,
+    0x9cb9095dd485, 0xc30d81239c1a, 0xf1a233c77f89, 0x1354a0c96c19,
+    0x2b83266458ec, 0x53cb32535929, 0x95815257c242, 0x18778a18f360,
+    0xd8c0842edf6c, 0x5b73b63e7611, 0x5cb08604acde, 0x57c941faf9b1,
+    0xc68a2e967717, 0x86baaee4f9cc, 0xef631c2cb774, 0x0cbd9b6e1653,
+    0x04ff63e6c394, 0xa6d2156a54c7, 0xd2be096b2616, 0xd9eef9b08746,
+    0x10bedee807a1, 0x224e64adf43a, 0x71353ff50f25, 0x84655117060f,
+    0x99002c4abb73, 0x9f3dba2021db, 0x460026593bb4, 0x51484843c9ce,
+    0xcf38f43a5228, 0x31c06b827a99, 0x34e60f2fdae3, 0x72865ce939b0,
+    0xb6a1c0c10ee6, 0xe7ac2f71fdda, 0xefebbaa30865, 0x2a52f3321c7f,
+    0x3a3175625f5f, 0x6c987f81a717, 0x73cb09b030aa, 0x7b2b0a8128a1,
+    0x7b87aec5494a, 0xb8fb9c1c48cd, 0xdf8c7760038a, 0x5da05d600466,
+    0xb7047504afd8, 0xbdb845b0bd19, 0xbf982dce0593, 0x0203032e3c9a,
+    0x188886c149a3, 0x1f9f9de51ede, 0x36dedf1e78c3, 0x5bfe4e1ccdb5,
+    0x675e3a21b3d7, 0x9db25f1a02e1, 0xc75865d17e80, 0xdf20abb15858,
+    0x010c82eded0f, 0x2f2415f87c8f, 0x4c57b240069c, 0xd685b7094600,
+    0xee9386bff9c8, 0x179bf288ee32, 0x8ea590ae5ceb, 0x99f57997af2f,
+    0xc7d8359409ec, 0xf73bebd352c8, 0x09b279de029b, 0x0dd08845dc9b,
+    0xaef33c271d06, 0x03dd50ccdf1e, 0x3d2dece27fee, 0x9f229f802c58,
+    0xa6ef1bace0d0, 0xb37323218ea3, 0x17b65e416ff8, 0x43d72950760a,
+    0x4783880e1c95, 0x8d509f28acd3, 0xb1d9ffa838f5, 0xbcf3ecb8399d,
+    0xc9134e5afcd8, 0xceaf23337431, 0x507ef5eea3ec, 0xe2876af8f7b9,
+    0xeab643615d17, 0xf17e6fc66bd5, 0x227e58c45163, 0x01e940717b71,
+    0x4706355cb125, 0x4d0ae41776f3, 0xc1dcfe25fb77, 0xd154cb7d1fd2,
+    0xa0b3abcc8add, 0xb5ae99a5b775, 0x03a2fcb8eeac, 0x475cb402244b,
+    0x5095599ab9db, 0x8b5134e024af, 0xb973882cc35a, 0x18d7303fb9a9,
+    0x4fecd9dd19b9, 0x8f4eddf4c76c, 0xa08d69f4b99b, 0xb0749d5fd7b9,
+    0xb4f542926864, 0x6dbba2efe787, 0x959323ffd128, 0x3190bafd4c9d,
+    0x7a2b9f3c95a5, 0xbfb8a9e8d66b, 0x56954210f4c4, 0x55938c708a84,
+    0x97bb2190214d, 0xae3c11f6baf2, 0xe45e1e951a75, 0x41c50c25f154,
+    0xa8e7b16f47b4, 0xa8e8268d533e, 0xed7ed5864e3b, 0xf5297703accf,
+    0xff9f9c0f8a7b, 0x5da4d43bf9de, 0x910041094f75, 0xfd12075d192f,
+    0x8218e03b5fb0, 0xbed30fec1257, 0xcb926c15927c, 0xce20dc790d1d,
+    0xeabc95c9a032, 0xed78c5e99cbb, 0xef43a2a0d269, 0x3f8a87b79ca3,
+    0x64558e8ea0a0, 0x781c5ddc71a9, 0x8a5c05d1b8cc, 0xa1bdd16779e4,
+    0xa807b96817da, 0xf96bdd2bfcaf, 0x1c3b13bcf850, 0x69dbbe55efe1,
+    0x9a57e586da2f, 0xd2f68af4f24d, 0xd99256f7a1c1, 0x85908563bbbf,
+    0x9cd9621407f6, 0x1f3398cfda42, 0x2801e09ef4fd, 0x5dd2b8e85451,
+    0xbea96729d8ce, 0x08bdb93c5190, 0xa38b8a4bdf15, 0xea3314f0f53a,
+    0x4dec9bbd454d, 0x9f15bb5d5998, 0x115f578ef97d, 0x856b945aff5c,
+    0xa98603e49d45, 0x94a8ae1f54f5, 0xa05c529e7abd, 0xec4298b9dc08,
+    0x026b3d79c2a3, 0x19428081d768, 0x2f1ebea836fd, 0x4e958984d17e,
+    0x647fbbbf9104, 0x94ea0b5cb94c, 0x17cee0a833ee, 0xbc95f1b950f5,
+    0x9cb5c9514eb6, 0x410cc4a160ab, 0x6f7292bda559, 0x0acc61b6fdd2,
+    0x78df75b95a60, 0x9338eab0f415, 0x0023c5352118, 0x4da937526a16,
+    0x20abc524a06e, 0x286a4d3cf4f5, 0xc13f4749c51f, 0xe705ecad9398,
+    0xf8d3e4f8a746, 0x23c17bb868dd, 0x562d190423f9, 0x973c02a3138b,
+    0x2ee9ad6dcb3e, 0x5fd157334d3a, 0x6864d56d9d30, 0x5ef7e3e6da6a,
+    0x368fad33a8af, 0x84969787b33b, 0x8c3bf62b8d1c, 0x2913c62488b3,
+    0x3e8fb7166b4f, 0xac04f59b3ae3, 0xef4f0c4170fa, 0x7d738ccba43d,
+    0xe6d199ab46e7, 0x7df2d16e281c, 0x885166eeb385, 0xb7a171220c33,
+    0x387b97d70774, 0x3a0f53e9cead, 0x4cdfdd8655f0, 0x6b8177b1ef3d,
+    0x895d349b09e5, 0x8d940d5b4f04, 0xb869da00b893, 0xe7282e52b610,
+    0x78a13711e85e, 0xa0a259cfec0d, 0xa1f594565844, 0xd9d27a905997,
+    0x0659103c671a, 0x17186d9bd16d, 0x2ccc696e3992, 0x402238bee875,
+    0x42ea887b16c7, 0xa22b6ba2a01e, 0xd4d2f74e42d8, 0xf04d19544db3,
+    0x1c25d6673a5e, 0x30e251bb3fe1, 0x8672b28a646c, 0x9aafc4aedc4e,
+    0x6a18407539ab, 0xa1bb4756874d, 0x4aed07f80b1f, 0xd755ff398b43,
+    0x7152831779a9, 0xb317f68a6551, 0x157d1e052c54, 0x3689ee8ac7c0,
+    0xacea81d28eca, 0x5a3423c650d5, 0x79b5aee4fa81, 0x865529645cac,
+    0x989dbd2384d2, 0xa2233e73acf2, 0x46a36653cd6b, 0xa379292350a6,
+    0xc360b96e0788, 0x33384282f838, 0x5f970af919d5, 0x5925a65391c1,
+    0x679456279739, 0x84864c17a96b, 0x06abe6544cb9, 0x1940e0acfbe5,
+    0xc6f60535559e, 0xf4919d71ae08, 0x49123e8a0c5f, 0x524566ba1777,
+    0xf8a48c7b6956, 0x2996f682fa7d, 0x23225e0b21a3, 0xfa81e1e8649a,
+    0x5a923dc95ee8, 0xa3986a0e39d9, 0x3bdb391ba348, 0x7644bb40f114,
+    0x89e46d05e786, 0xf9f525c67a98, 0x64f67cb66ccf, 0xedd3560073e9,
+    0x391b51803cb0, 0x546b5cba21f3, 0x87d29892a78b, 0xbcae7482e3b4,
+    0x0aa68086773b, 0x64c037c307af, 0x9af95eceadeb, 0x9cc1f1efa9e7,
+    0xe74a77d19595, 0x393974b42885, 0x53f03d9cf432, 0x171e1f44dfc1,
+    0x4d67be8a2565, 0x60f2da071495, 0x1539f48c25ab, 0x5082f286784c,
+    0x84fc37abfdca, 0xad6b2694d2d9, 0xc67cd0f520d1, 0xe4edb220ed35,
+    0x576c2560c1dd, 0x33a1a0d3fd88, 0x3715ba2218c5, 0x523bae8fd870,
+    0x55d167fe43cc, 0xa0446d5c1124, 0xcf6ef3efb79e, 0x09982f17b02d,
+    0x38a7f24db39e, 0x64b12dd1676d, 0xf41f28de0ad3, 0x7d815dde3590,
+    0x9eb33ce577a6, 0xc67af568d32e, 0x0ecbbca4cee2, 0x2919120036ce,
+    0x63696ab109f4, 0x69acb07e0099, 0xa5d405823b36, 0xe2cfdb8a65b8,
+    0xe7f56d8c3638, 0xf627f4714e4d, 0x9656963582a1, 0xc2990164d8d2,
+    0x1594150dd5a6, 0x3868195a4a70, 0xea6612c3b510, 0x5e22b523e323,
+    0x9937c64c1815, 0xd01db727be53, 0xe7e4631e6fc8, 0xea658f1a9bc7,
+    0x0565503b97ef, 0x140e19f756dd, 0x1efeb5ea3f3f, 0x37f59cecea2e,
+    0xefae4c9ad70a, 0x2032afe8910c, 0x20c047bb8463, 0x4a664e730002,
+    0xc03a3423eac9, 0x64b1e55835c9, 0x750c6256afbf, 0x85e732c0ce6a,
+    0x886874bef66c, 0xc1e5e6550210, 0xb72f55bec565, 0xcbcd9f53d154,
+    0x4008c0a775f4, 0x4128be08d5cb, 0x9836359b8660, 0xe42651bcdfc2,
+    0x6ea3f0f1f6f2, 0x942f1d13e957, 0xd5668395d61e, 0xf2cd227366c6,
+    0x2a65de30b0c7, 0x97742d4d5c11, 0xe81706fb35a5, 0x03abec5ee389,
+    0x75cee025b461, 0x77c7077b606d, 0x2d48a8306e9e, 0x588cf0adbadc,
+    0x6178ba8ebddc, 0xa201d3597e41, 0xb30b4dbc35e8, 0xca9680710b49,
+    0x5008a154e3b3, 0x93261d09b4bb, 0x3f26950c086b, 0x872836fa37dd,
+    0x8a108ced8deb, 0xb8b2be87c5c1, 0xdb6906334c81, 0x5879fbc5ca41,
+    0xd3b3ba75dac5, 0xf6aba878d960, 0xfb3205a2fa48, 0xcb3fe356a304,
+    0xaad6f0d72697, 0x0beb2761bb15, 0x41637975619a, 0x471d0aeec6fd,
+    0x91837b925702, 0x1d445232468b, 0x2a721431ad5c, 0x6e6ab31e159a,
+    0xfc099d631f75, 0x182e29a0f49d, 0x3bcac9e42d48, 0xa7cd77587a38,
+    0xec2af6a5d2d3, 0x13b7e5946396, 0x1657f2fc9a78, 0x5e4d3abf925c,
+    0xc5f50130069a, 0xdcead513247a, 0x8a986d2bbfce, 0x1d4310510e34,
+    0x4bf03cbc5afa, 0xe89bac52f747, 0x68d997738df1, 0xc686496a4485,
+    0xd0570c88d6d2, 0xf37b5aee34b7, 0xfbbda4d3847e, 0x3140b7293a2e,
+    0x5601ad6b404f, 0xfbdf5af5d0a0, 0x245ade98e302, 0x69e5651fe451,
+    0xb577f2c105f6, 0xc5024758fda6, 0x13fb8553e224, 0x70c512853793,
+    0x0afb3b2cd3e5, 0x452fe643ecee, 0x6b66dbf7cc3e, 0xb586d8886b3f,
+    0x35fa4a6447fb, 0x3dd94dd1cfc8, 0x5285c60a416d, 0x55c03a8eaebe,
+    0x580e3f67ca60, 0xfc64507bc541, 0xdc3920d45e9a, 0xf81c7bd8c771,
+    0x010efdef6dc5, 0x7c84aaff65e7, 0x77b1c2aeb437, 0xa8a6b0db84d5,
+    0x0cf4e6cc7b1a, 0x030a608e58db, 0x0ac3a5cd28af, 0xaf8b668b5705,
+    0xb87dd2a00156, 0xe8abbebf3676, 0x00b3d67e5a01, 0x09a7675e212b,
+    0x1d33e23532f9, 0x2bc1ae044919, 0x65f2007df483, 0x973689169ced,
+    0x9e60d72856cc, 0xbef889a26838, 0xefd9913442e3, 0xf44c7ce17962,
+    0x45790ae2e438, 0x7cd14513d337, 0x8ec068612b7a, 0x9afe3651ee0e,
+    0xa89bd3835a2c, 0xf431cf85d25c, 0xe0a913a118e1, 0xfe5e281f647c,
+    0x28a70c9eec03, 0x728fe88b1c8f, 0x1a3036b012da, 0x1dee6d017b6b,
+    0xe2d010f355ef, 0xfdc2c83c093a, 0x1d2362e77416, 0xd61f0f713386,
+    0x0ac1bbb5cd4d, 0xbb1dca690afe, 0xefcbac0dbf7a, 0xab49a95ec7c2,
+    0x0ae855958e9b, 0x870d0009c46e, 0x8a761e86cabb, 0xe65b9cb6a7f1,
+    0x3b9bedfbe926, 0xa232cfea7a57, 0xa587cd3d84ec, 0xa9a650c36a76,
+    0xe9a6da05b672, 0x5668fd17e07b, 0x70817b6513a4, 0x84f6234c0585,
+    0xa2774af83371, 0xb2ec163644e6, 0xc227ba9e7bde, 0xc7713656d62f,
+    0x0d82ed0c2949, 0x16976e5f2f43, 0x642bbeccef46, 0x7b2db24c3eef,
+    0xfa58e472c464, 0x17ac86f1762d, 0x9d84e7906192, 0xd394bd7eef88,
+    0x94f0e5acf853, 0xd8bf49403137, 0x1f059c74b34f, 0x23ad24a118cc,
+    0x4c1c88a7f965, 0x81c5ce4a7298, 0xc12fd3efea3a, 0xeb6be9262ea9,
+    0x62c34d3f4706, 0x7af15dbc284f, 0x5edf6ec1227f, 0xf985380bbe59,
+    0x6ebf2bbe7e3b, 0xde02b55bda08, 0xf842c64ff352, 0x1c2cb768ca17,
+    0x3a516c8a153a, 0x537e4f6611c5, 0xada49b5cdf8c, 0xb2a7dd914e9e,
+    0x168931938a97, 0x630ac9073965, 0x6d2ab17397f4, 0x3cdac618032e,
+    0xb1d81bbdca23, 0xbe3505a99d5f, 0xc69ac4276b6d, 0x1d2effa02a28,
+    0x64a6ed3ac858, 0xc8ea9d78b537, 0x24c90456df92, 0x5c297aa49e45,
+    0x94c686298e00, 0x9d8f50901e43, 0xa24df329ca79, 0x3b265b97f12e,
+    0x5f7f1919ac6a, 0x92eb5d3cb0f0, 0x95b16362a590, 0xbd936a259897,
+    0x8e24cf1736ce, 0x345460a3d89d, 0x4b409910042b, 0x79591656aabf,
+    0xe3313b014898, 0xc78fb2584fa2, 0x7ef050ee8f39, 0x8d6359600250,
+    0x861cfe987ecd, 0xaee81ca768fb, 0xee64e41cb9b7, 0x0d0fdf303f91,
+    0x15304e663974, 0x8389615d7b7c, 0xb9264cd4bd21, 0xde580884dfa3,
+    0x0de476362b79, 0x523aa3a2cb74, 0xd36e3ac99698, 0x54cc986998a3,
+    0x7cbf8c84369c, 0xad6530d63136, 0xbf6d0879f895, 0xa310c1ec9fac,
+    0xcbd50338dcc4, 0x0c65a8735b8e, 0x6561e673cba2, 0xb64666791d11,
+    0x055a1740a2d0, 0x24b98d20f0d3, 0x2f7ffc45b79a, 0x4010751d832c,
+    0x507b2fc696c5, 0x6ba960512624, 0xa1ece882cc04, 0x9d14bd5895a1,
+    0xf95e6c3c51e5, 0x52f1dd86a7a2, 0x730ad67e3cbc, 0xb59af4ef2032,
+    0xefa33fa3d9f1, 0xabff596fc470, 0x1b2710e65c5e, 0xbcaa628d1b8a,
+    0xbc4891717231, 0x908704cdc842, 0xa1af260d8507, 0xa604308cc3ba,
+    0xcd65a9550c47, 0x72e8fc31b5a1, 0x911f13c8b740, 0xfcb25b290a6d,
+    0x6984f674d3de, 0x974f141cbb82, 0xaf69c91eae65, 0xbf0c5cef09a7,
+    0xd49b4a3fcb22, 0xe2cfa59d115f, 0xea00d0717854, 0xfefd5833cd4f,
+    0x62ea9143354c, 0x8e9135fc3270, 0x12d31bd41160, 0x1cf4639a928d,
+    0x916a891207b7, 0x978c694db402, 0xf557879269ef, 0x372512951b89,
+    0x764c4d17fe77, 0xa2f77c27a71a, 0x1d5025198a66, 0x1e3b4b6cb57a,
+    0x4e1bac2746bb, 0xef7a4e686ec4, 0xebc728ea1726, 0xee447166e110,
+    0xf2a968ffb79e, 0xf8a5c59bb1b5, 0x0d477d92142f, 0x67387d5ca1a9,
+    0xda3c86e19fb9, 0xed66e00c0666, 0x1024047a3a3c, 0x3edb7c098aa3,
+    0x9fc1f2d79d39, 0x2e93d6dce5ca, 0x0bbec3c7502a, 0x488ee91a2cb1,
+    0x4edc7a0b26f7, 0x5a12c461f30b, 0xb1d2329c2c52, 0x212ee5ae02c5,
+    0x7366dac8c630, 0x90f1d87aec33, 0xa7cc395355bb, 0x361f29471c35,
+    0x4986207e7bcf, 0x816b08464711, 0xfc8b9d81dcef, 0x4c9c86bf558d,
+    0x7dcd634c0dc9, 0xe056e99b6a27, 0x3cccf8e185b5, 0xa068887de1f9,
+    0x1c381b1db588, 0xd93b30c013c9, 0x02ac25da54e0, 0x4b10d3b14687,
+    0x6006b93ff431, 0xc9ef64af3f31, 0xe7d03fe4d4ca, 0xbb031198cc81,
+    0x13a828bda664, 0x519c69804a9c, 0x427cfdd1d54e, 0xea32575cde65,
+    0x528ed3292091, 0x10b1e62cdea9, 0x60f229100192, 0xd29fc43c42a5,
+    0x6f220742d06e, 0x7f4b562371f1, 0x04e45a011f2d, 0xc0ccb51ba24a,
+    0x1c9476aa9176, 0xdcc35d0febc8, 0x131ab5da8b98, 0x26ad23383368,
+    0x68813a6c9050, 0xb8fcbc028988, 0xd19a6d226a59, 0xd4dfdc77ec9a,
+    0xd515633332ac, 0x02f17cfbe618, 0x0fb9745e4578, 0x2fff08473fa1,
+    0x5ddcbbf91b70, 0x76d30950b113, 0xf87fdc916e11, 0x07912f9e79ac,
+    0x3e9cc8a6dbe0, 0x3f92af3c161f, 0x5a444b4d591c, 0x9136f570466f,
+    0xd2368ba0b372, 0x58bcff67c574, 0xcaacb60989ec, 0xce5bd37b75b3,
+    0xd06d24459c65, 0xf154e6499127, 0xf666cc3f7179, 0x4faec12c5744,
+    0x5063016a19a8, 0x573837a77143, 0xa704f668328f, 0x21974423c3c7,
+    0x468843b88582, 0x77b95ad44383, 0x223fcb58522d, 0x3f231d86f31f,
+    0x891e0b95eb76, 0xe30aed521116, 0x0fd1c9fb73d6, 0x5c8922b96e7b,
+    0x5df586b45558, 0x58ab7150e5bb, 0xc9ee7424a634, 0xcbbff30f8333,
+    0xf31b04332a34, 0x4ca96d330103, 0x9ae47ee82f03, 0xac6a6937294a,
+    0x46b7e6db121a, 0x94cf4968bc0e, 0xc8f71faa3b98, 0xe48c1b0fe57f,
+    0x72d276496ea6, 0xa9edd7de5ef3, 0x61542e6c2ec7, 0x89e40dc842a6,
+    0xb5360fcae910, 0x6f93600d8032, 0x1209fe223e23, 0x3771b5abb241,
+    0x7f7ddd4ceb19, 0x5d7b8cc51339, 0xb26492a1b47b, 0x63b478e2f87e,
+    0x2e030d25ab9a, 0x3bea17ad53bd, 0x0f17a687c6c1, 0x5346574f7c9e,
+    0x53a719a20521, 0x705ed11d541b, 0x97ba575f06a6, 0x9dd8541b50dc,
+    0xe8160d4cdde7, 0xf9ae444da5be, 0x8c2e5b88c302, 0x300f892039a8,
+    0x64be55010538, 0xc2091fda1670, 0x07159c8bacbc, 0x90f6c59d5990,
+    0xfae020b9b5df, 0x6c71992e314b, 0x89935de1f952, 0xb551922648b9,
+    0xd9b7b89c5c59, 0x2f9913317b4e, 0xe97cb23c150c, 0x2e9932093f36,
+    0x544aa779f121, 0x92c110206836, 0xf2dd8b9d944d, 0x2dc13418eb07,
+    0x375d20294891, 0x74a64707112d, 0xca5eea2a2d28, 0xccda98bdceaf,
+    0x328e55e6febb, 0x6642e295226d, 0x3f9bed22340e, 0xa14c5f412d23,
+    0xccf2544d18f8, 0xdeb0497c98c8, 0xec268e96248a, 0xf6df44359125,
+    0xb3dbf2334dda, 0xb4b456b69fd4, 0xb9f6808e4185, 0x942f7c0e6385,
+    0xb874eca03e1a, 0xe87b0b898754, 0xf2a15b9a876f, 0x13243d3d8bd4,
+    0x4c946a6c48d6, 0x68aa1859a6f9, 0x74af664bd87a, 0x98bc56df21fc,
+    0xd40a2294edc6, 0x32dc89e4946e, 0x4395ba2e62fa, 0x833f57241e8a,
+    0x8cdf26b3de29, 0xe0571f672927, 0x7d05c2d0163a, 0xb03d2fe4e5fd,
+    0xb7eacaa58f92, 0x3ff71c6d04df, 0x78a72450d379, 0x647d5f1c3c3e,
+    0xae03deccbbe4, 0x4766e5ca8aef, 0x520930a9c220, 0x949b2392d3be,
+    0x773cd6d40ecd, 0x9c777e4e1252, 0xd00fe844705d, 0xf501226837dd,
+    0x6857850d52f5, 0xdfdd931f044e, 0xf38437a8a3d9, 0x22cc05bf2cd3,
+    0x277a301f39a1, 0x8444bd8ea227, 0xe68eac8dba97, 0x01a797233165,
+    0x3635ad1fc3ea, 0x07b740af0c23, 0x0cd66b0c3b17, 0x4d2de09b177f,
+    0x74ffa9b370e3, 0xa1f9fe10e5c8, 0x854cf84c983a, 0xa6a3e5d580fa,
+    0x3f1265983864, 0x425cdd383993, 0xbf464023d132, 0x2d8f15707997,
+    0x368614b1974c, 0x50c834ace483, 0xb8bd4bf2f6db, 0xbee0166aba3a,
+    0xf562aacc9c7b, 0xf89607ff56f1, 0x02bedc359683, 0x0a493cecc796,
+    0x1ce28fe2e461, 0x453f6ca8f1a5, 0x8b595f7b1473, 0x211dfbcf9ce4,
+    0xa6fcfea22f9a, 0x957dcc88d8a7, 0xc292e443f095, 0xc638663eea0a,
+    0x34c8d469a04c, 0x3e2e8a11a675, 0xb7eccbe75352, 0xc4b991052bdb,
+    0xc7bf2e7b6469, 0x4a80549f5aff, 0x575b4860993e, 0x2410f4eee69f,
+    0x754f4bf905bc, 0xb65dc079e9c4, 0xbee59436137b, 0x2098a07c480a,
+    0x26bb6af40b69, 0x84a7048df49c, 0xa246988821e1, 0xb0dd8ab01ec9,
+    0x16890bbc7f21, 0x6000ad1c87c2, 0x95108be0dd9d, 0xa2e5f963c97f,
+    0x63aab3b8e6dc, 0x2673b4ffe20e, 0x42c6eb365032, 0x437329e648a7,
+    0x59abd1c1c320, 0x30aa58bd77cf, 0x08b028c41d39, 0x271cebe181c3,
+    0x29601a769237, 0x2dd674851f41, 0x9fd6ddef88e6, 0x18b2358eda63,
+    0x8c61ce16bf99, 0xba3c88855067, 0x0859687b84d3, 0x321ec5bd16df,
+    0x35325747af5e, 0x55af80f32386, 0x13302b4ce140, 0x6c4a25ee3f5e,
+    0xe84d6ad12ad7, 0x48199c356dd3, 0x6a79e1d07b3b, 0x88381ca7ad9e,
+    0x94cd4c030c28, 0x4ed7ce077ab8, 0x60c5576baa98, 0xa2dada687996,
+    0xb90a967a130c, 0xeaffb660218a, 0x3f3acda1a5f1, 0x666400fa6330,
+    0xb57d2f2a7367, 0xd0a273eb21c3, 0xa91bc81428ca, 0x6289d3f52cfe,
+    0x86e86dfb8239, 0xd3f1359d82d2, 0xa50a3fdb7e5e, 0x13efc5da96e4,
+    0x1488587e9f2b, 0x3384fd0530be, 0x81c8104828ad, 0xc382fe080aef,
+    0xcaaeab73e76c, 0xea170d1e1672, 0x531a0fa2c0d6, 0x5b3c5350e8e1,
+    0x213f196f4015, 0x3bb093103797, 0x5046db177997, 0x6659dac6381a,
+    0xbd4bbac12a95, 0x49d26ea398c3, 0x52a2505bdbe1, 0x742c05ebc577,
+    0xa7c1b12dde46, 0x394ccc0fd85f, 0xd20c80278ff8, 0xdb55d888cab5,
+    0x30d6e5e96cb1, 0x4cf2fb7b6c60, 0x3be95c8baaf7, 0x65a88cb7a13c,
+    0xc7da3c7e561a, 0xe718c1eb654d, 0x65272a82dbb1, 0xa2a896d02f9b,
+    0xc3f70dad42e2, 0x2ef4468ecd3f, 0x631af8052ff0, 0x689392d42ec7,
+    0xd47a1d90b610, 0xff5dde4a7f90, 0x0e0d0faae00a, 0x323204e78759,
+    0x49ffc82feba9, 0x9cf1b58001b5, 0xd4096e2495b2, 0xd67b0c23395d,
+    0xc56b7aace1f2, 0xa1757ff9d567, 0x127db938cb1b, 0x12fad8d21f0a,
+    0x1a285ab629af, 0xb9a9e9904a7d, 0xc58e470f3d2e, 0xd5cca155f17d,
+    0x4cc9aac122e3, 0xede76c59e063, 0x33cd37cadfbd, 0x5aeccfacaaaa,
+    0x748485702d94, 0x37d1bda22f90, 0x6edfa040cb76, 0x919831828be8,
+    0x57832d868b14, 0x99e7d5d12654, 0xbc1b45ebc8e8, 0x7c868fcf1662,
+    0xc1a6436e9152, 0xe541bee6ad24, 0x6f812625a304, 0xdee7af3d718e,
+    0x480157115721, 0x725f0c67ebaf, 0xf018229bfa0a, 0x313d01dd137c,
+    0x3750b3755a11, 0xa00af850d9bf, 0xdfcd842c0a30, 0xf56dd3f28227,
+    0x02b2b05f2fb1, 0x0dcac8f70231, 0x90a8c17fcfbd, 0xe0453c5ecfaa,
+    0x9441c4c287e2, 0xb9ab8b532fed, 0x980c6b412404, 0xcc1e866f7f73,
+    0x255b7200428f, 0x355c43fde6dd, 0xeabb94e593a0, 0x3483805e855c,
+    0x5b888f71b305, 0x7a213dd36b4f, 0x8b1b64c7a067, 0x4e9cfee9cb9c,
+    0x52348cd06520, 0xc62a996b3b5a, 0x478f5eafdef1, 0x29bfd764f215,
+    0xb130fc6123a1, 0x01b778c831c9, 0x8617905f0a4d, 0x6bbb3826219a,
+    0x8b9f949e87b6, 0xc277b5f2d7c5, 0xeaad75300a72, 0xeff2984af724,
+    0x4e99c4035b5b, 0x793a09ed7ed8, 0x91c9174bee69, 0x2a6974d98f95,
+    0x40ee48bf8b4f, 0x346eafea471e, 0xc0cbfcada103, 0xc3564f02b3ca,
+    0x531ae5cae5d4, 0xd291944edb3b, 0xd9cd44d64b96, 0x0a70da211243,
+    0x45661fa1253e, 0x59fa93303916, 0x75e998b2c82c, 0xe33f80ad8153,
+    0x522d083a63c8, 0x56c2b8d3073f, 0x48d1040b4bf4, 0x78389dc9f6e5,
+    0x8d7c83db53f8, 0xfadcf1891685, 0xd34383e24472, 0x54f47531694a,
+    0x7ffede5601d7, 0x017a8378e662, 0x453a2d48b203, 0xa248cf32d5d8,
+    0x38b2cd74a9a8, 0x8ce1ffa9020b, 0x9333aea8642b, 0x41f86e5a7a56,
+    0x1f7629830d48, 0x3ce629488a7d, 0x4a300dffb6f5, 0x4de0c55acb1f,
+    0x3ea745fbc417, 0x3a04dc1bd98b, 0xddcd1acddb50, 0x8efe1fa31470,
+    0xf685302f4fa3, 0x3bc892f64e9f, 0x449456a029e3, 0x95ae97efc729,
+    0xf73861a3f131, 0x2abe44597be7, 0x63c204b78a27, 0xa6ccf92b87da,
+    0x21b2152541a4, 0x9ce531a1aad7, 0x0342e3e499a6, 0x05c98d49501d,
+    0x094f439c279b, 0x948edc945b5b, 0x78113075b818, 0x87dc7bb81654,
+    0xf34412631b86, 0x4bcabd39f610, 0x5a5ad29f45e2, 0x9d5d15d86857,
+    0x78ff92122236, 0x93a8a17593b7, 0xe7cb0460a902, 0x4ed89e43ec96,
+    0x7e0f09e4bf14, 0x88d712f2ae3e, 0xc314a9d3a84a, 0x1bf4156f4168,
+    0x706a30d49659, 0xba4936bacece, 0xce316b99ea31, 0x04d022b39219,
+    0x36193e78ade7, 0x586a6b343e85, 0x56f1c3101c1d, 0x8b471e7f17ca,
+    0x2488bfeb9c7b, 0x39295316e21c, 0x759b74cb7b5c, 0xc57627a09c99,
+    0x078db9a49f84, 0x1f604aa87cfd, 0xa787f978aa9f, 0x20c6978fc416,
+    0x73b72585b784, 0x7b8ba34035eb, 0x83497bab7923, 0xa5cfba044449,
+    0xab6c03fac72c, 0xef0c91cf8225, 0x56f16b6afada, 0x4a5fc072eede,
+    0x8b880e155edb, 0x99270aa0ed97, 0xa14c0d035ade, 0x10d5ab979828,
+    0x8ff7b76536d5, 0x39b8e451dc26, 0x73d674fbae76, 0x9d6b8e5b7f23,
+    0x05a9634abc31, 0x787aa4c8b96b, 0x883711baa0a2, 0xc49f97f84790,
+    0x11e3d8dc243d, 0x1f35f9b02069, 0x824ae43e326f, 0x90f16463b7ab,
+    0xb5bb0be0990a, 0xf50b386484b7, 0xb2cb3f7f809a, 0xb4f4cf820ade,
+    0x02e8bd77368b, 0x60246f70caf9, 0xa9756866048d, 0x64b51f35ddc6,
+    0x853ba9c93e7b, 0xbfe154dd79aa, 0x99726a4b10d4, 0x83fd6c1dd751,
+    0xcef3490c88d5, 0x5cc1c78642c0, 0x3412a7a21477, 0xb1c1fdd226be,
+    0xd4329719c9cc, 0x2f3fb0c65508, 0x53166af14729, 0x3fb83af6bed0,
+    0x7ecb2fc29c44, 0x8ec0cc6025dd, 0x9995a4b7581f, 0xb15e252637bc,
+    0x568e6f35b2bf, 0x71469778a10a, 0x7b02c44f2c15, 0x89b773181707,
+    0x3d7847aaed66, 0x51b2d5aa25d1, 0xa80fb5ef7052, 0xdc3f8dbeb9cb,
+    0xe8f5ad8d5725, 0xf563f9eee0dd, 0xf56ce5b8c1e0, 0xf9d1dd51986e,
+    0x5f112c1c4fc9, 0x8e6d1b5cd47b, 0xa0e4ce32a127, 0xb48f4039a6c4,
+    0xbc0dbbe3abd3, 0xe3142a50fc1a, 0xed4cc713c9c5, 0xf7d6d2ba9d64,
+    0x04617c62f288, 0x3b30b1ed6194, 0x56aa24465b20, 0x67dba66e0472,
+    0x8c4e9c2d5b2a, 0xef311dd07be1, 0xf3ce9567e382, 0x39fb0f7ad9a5,
+    0x3d2f9178b0f4, 0x5d6019fb9851, 0x4f803cc79f0c, 0xbfb2208a76b3,
+    0xc066d5e644a1, 0xd199f1f71656, 0x476142edc3ca, 0xa748accdb559,
+    0xd13f72bc259d, 0x60cef7e71d1f, 0x6466c05cbc68, 0x8e5b0225ed35,
+    0xb37cbaba7bd9, 0x22d4a010d923, 0x96c6c92c4d48, 0xa9149fc4fa21,
+    0xb01cd8985857, 0x07eb5fb20e68, 0x65bce59b65e1, 0xef8594e5a95e,
+    0x40c0518a79ea, 0x7b257b125a17, 0x87f205a12cdb, 0x364992d386f2,
+    0xa2c45796a8e3, 0x7ff02ebd2a57, 0x1ec4b938455e, 0x70eb866c5812,
+    0xc0acd53df8e4, 0x679d7651a02f, 0x349a0c10ea1e, 0x4e7f28ac0725,
+    0xab94e1e7ddd5, 0x627a355cf7a5, 0x6a0c2283e71d, 0x94e329f66988,
+    0xe05242ff0ce6, 0x0d7d665e4ab7, 0x1dffb092b093, 0xc6aa0676dcd5,
+    0xcdbc4fdf38e7, 0x7a1a5661c454, 0x812f23ef5fdd, 0xcac1fdcb1711,
+    0x50b62205b896, 0x5be84e4e1cd0, 0x2b137c5a6ff1, 0x5bc47a998f02,
+    0x745ebd581948, 0xfd0400dfa663, 0x43d4f2a3d0d1, 0x69301573079c,
+    0x85ae1222aca7, 0xf31228c0cb84, 0x9f9abb2d8813, 0x736a3d93efeb,
+    0x9b201e83896d, 0xb4bf60b6cabc, 0xe25c584d45c4, 0xeaee772d731c,
+    0x69eb74796b1f, 0x8b19e51f56aa, 0x9ae446259dd2, 0x4cfc8dce9ff1,
+    0x71e42c7b751f, 0xdde5057793e7, 0x2dd5e87de4c9, 0x67e3b09b2900,
+    0x8eca18d1518b, 0xa19fcf45617e, 0xde5f41cf98d8, 0x65ccf86a73d9,
+    0xbaf335ff2354, 0xd571f4076978, 0x22eb468880ad, 0x75bbce474c5f,
+    0x767133502b9c, 0xd3a349d2cdb8, 0xb21eed1e64d8, 0xb3342a3db584,
+    0xd9b53cf4e228, 0x64aa43cbbd0a, 0xb2627df006de, 0xb89461474707,
+    0xf092ad1292b4, 0x5aeb6e42e8c6, 0x4478c281b0da, 0x0d550042e717,
+    0xeb572ce58698, 0x034c82d4d109, 0x25965dafa907, 0x6d2983c5f5ca,
+    0x8e4e58f4ef03, 0xc4d0b2c7cb7f, 0x577c1b9c2737, 0xbbfd54232417,
+    0xf59f5f1ccadb, 0x2471d498c410, 0x34e69fd6d585, 0x4bed9ba0a41c,
+    0x3252185fbb20, 0x57cdb6842531, 0xd7aaa4c32d93, 0xf0c06d31e365,
+    0x253e8012e20c, 0xbf760814a0fc, 0xd2601a41b26c, 0x7db27f1bdf7f,
+    0xa5af493c758f, 0xd48b1fa05b51, 0x68879df1f350, 0x71fb823d5f2f,
+    0x236e436c0c2d, 0x3981a236da37, 0x37e1a1fad773, 0xd81e37d36bb8,
+    0x43f61ece81c1, 0x01e5f4ad3379, 0x135190bc9641, 0x58dd017faea4,
+    0x5aa3fab7823d, 0x814bf06883b3, 0xccd174315c7b, 0x3a4f63f1074d,
+    0x74f967a2b01b, 0x9b47b252dbe9, 0x6d6f1ced7b0b, 0x7800b5040d0f,
+    0x086b97f5a18c, 0xed86eac34a35, 0xf68f8709242b, 0x3e4c143e8537,
+    0x955115256053, 0x44d40fa83abb, 0x4508ac2769b9, 0x1a47c65b0934,
+    0x21d520558548, 0x3f179aed865a, 0x4e0a81ac929c, 0xae69189f4218,
+    0xe60199cd8654, 0x15a9b5188c47, 0x2669c22507e9, 0x60fd95a58112,
+    0xf337735cb317, 0x6a24eeca5c29, 0x2f6c5d593e1e, 0x7a1857d3a813,
+    0xfbbd6415a0e7, 0x00d577211d00, 0x9457cf1cac11, 0x2141c976e239,
+    0x79c78a11a5af, 0x6cdca936cf95, 0xa12360e45a02, 0x6703d7354fc8,
+    0x9e853df64d4b, 0xb97954992334, 0xd4823c130624, 0xf0daefb1fec0,
+    0x07b7eab1a3c2, 0x18117d7406a4, 0x3d742cb2fbd4, 0x571be592129c,
+    0x3843f071182c, 0x49b3e51de893, 0x4e02376783f2, 0x0228d69761c2,
+    0x1f9b5a821e6e, 0x670a5c52db9b, 0x972845567cdd, 0xf38e8c100a52,
+    0xfa36ecccf719, 0x353c35689df2, 0x46569d23008b, 0xef5ad897083a,
+    0x7109f56dfeea, 0xa47d8b71bc10, 0xb0e694f9c115, 0x26c70f64e92f,
+    0x2dade2a6fd0b, 0x49f4beb233a1, 0x882821a7103d, 0xa8ff6b716597,
+    0xd8dfcc2bf6d8, 0x09cfb20e4888, 0x178678b42f4c, 0x4c88d88230c0,
+    0xa436e446b38b, 0xb5911de05fd7, 0x2078ec3fc7e1, 0x6a82cea13340,
+    0xb590b0394291, 0xbdaf10680887, 0xb6fe4f01424a, 0xe966f309b265,
+    0xf4ef21628ff7, 0x158f85177ca1, 0x4edfac0f11e7, 0x8836ea585a08,
+    0xaeb3a472190d, 0xaee338a6c91d, 0x4ea98ba8b689, 0x665deaed9a6e,
+    0x7fdfe59df93d, 0x9f93fe349dfa, 0xe778589feaec, 0xb749d2d5a080,
+    0xf02c68316a2b, 0x2534df130b63, 0xf117df159707, 0xc3462672e33f,
+    0xc52f34e91281, 0x711fa9cfd253, 0xc20d502e0a8a, 0xd1bf71fbee20,
+    0x011684f5ebea, 0x441485939cc3, 0x61b0ab2c737d, 0x70e46408dc89,
+    0x7cb7d4302448, 0xc733ffe6d868, 0x2f5ffd425370, 0x49c3f7ecf68b,
+    0xae2ae2345bec, 0x01f58498c5ba, 0x0cf60da545f7, 0x6d0be6eecabd,
+    0x95e3d446f803, 0xa11d527014dd, 0xa88f395fdc99, 0x076858e52695,
+    0x42f546cd2ac3, 0x7ec2069478a4, 0xd41bf66c4009, 0x5f76a353189a,
+    0x725d471ed37f, 0xa554e254598f, 0xc2737335d0d0, 0xe181ef502469,
+    0xa0cd766117d1, 0xd6d3012ba226, 0xec6a409b2f58, 0x5475c2a1771a,
+    0xbdfc626612fc, 0xf35e49b98417, 0x1923ca5235b7, 0x08c3ab5e2787,
+    0x76e1ba319905, 0xb8db1a76a25c, 0x95b21457c751, 0xaf650296f8ce,
+    0xc1095933f26e, 0xd1117d124f5c, 0x3cf07b5f1840, 0x980d5d983195,
+    0x988c8c38b971, 0x92b887f2b2cd, 0xd1d0e4251eb6, 0x381fb8179680,
+    0x3d905158cb68, 0xf3261337d551, 0x1eaa68237107, 0x45c1fe777205,
+    0x8114d277bcbd, 0xb30bc6d5f963, 0x116cf3997820, 0x28829324b7f7,
+    0x4979e326350d, 0x95ff5e1945f0, 0xaa73cb71320c, 0xfe33979190ac,
+    0x0bd3093b2af2, 0x7f0b74b0523b, 0x90a495ed3126, 0xc30c152a8468,
+    0xfd67ddca7808, 0x00e4333562f9, 0x42de43277d9f, 0x63f6f8ba450f,
+    0x22c59ac0ea4d, 0x8b8caee5ad13, 0x9abb8404a0f3, 0xd59f2c7ff7ad,
+    0x4bf70e8ce379, 0x7166c7a42186, 0xac4358cdc565, 0xde7f61e4d071,
+    0x31fdc23cbcfb, 0x5ba6128a724c, 0x7800d5309ed5, 0xe34de30d06c3,
+    0x515ac9f9c78a, 0xcf50091cc348, 0xd262eafa4a78, 0xa0f6360fa6a1,
+    0x35e616d13968, 0x67343ae0d424, 0x6ea4fd057f07, 0xc4861d0b9837,
+    0xe31680c5730e, 0x09569cd23926, 0x6e62e906e9e6, 0xa1943f0819e5,
+    0xb3854ccf9c53, 0x2525de239489, 0x522191db2c0c, 0x9012643c79b9,
+    0xc202b6670f0e, 0x0d5ab5026bb3, 0xb56c78428fae, 0xbeabfa9dd254,
+    0x24c5bcf30f96, 0x4c3ae1c74851, 0x8c2cc748230d, 0x72b0760446bc,
+    0x99adbe18b03b, 0xf5b0f8e97b7b, 0x0b4ae10b51e6, 0x0d17ccc9bb81,
+    0x6cccf9c2b3c7, 0xf168500c629a, 0x086b093abf95, 0x0b8811af40a4,
+    0x44a02dc65061, 0xabe3fe11eb56, 0xcb319c5e7753, 0x05dc152e2bab,
+    0x8c0918836dca, 0xe6d651c5a194, 0x01a7a37d0a85, 0xb29986200b41,
+    0xc990b95d4bbf, 0xd86b2654eeaa, 0x620a6e801dde, 0xb9c424c2c6e8,
+    0x323608d4b4a4, 0x43699a0391e3, 0x551b3507da25, 0x373ea3e4a46c,
+    0x51a229713bfd, 0x96abe76e8d0d, 0xaa09b84d05df, 0x48bfd67a218d,
+    0x157d0f782d53, 0x44a0b94926b7, 0xae009aa0f789, 0x0b152f11b160,
+    0x51bd0a47c94d, 0xbbca4f1aaf6d, 0xe4ff55d508e6, 0x5c42d3532b50,
+    0x02e32a4de980, 0x5e9337c47ca7, 0x3494864a3e77, 0x78760ac95ffc,
+    0x92eaf2cbae09, 0xebfb2d691013, 0xeda1362da4dc, 0x5619d4b1acaf,
+    0xeab637880595, 0x187df597b784, 0x31dbcc0286bd, 0x717c081055c0,
+    0x03da7f2b22e5, 0x7c5b06fe81e1, 0x2daed0bf27f9, 0x4721b29009fe,
+    0x7d44bf6c7c98, 0xa94808699a65, 0xf7e00ef3ead6, 0x2a94a477d66d,
+    0x67e72c3d8b96, 0x84e78b600943, 0xe5cb7e08dc62, 0x2782c2d86254,
+    0x6028f4b638d7, 0x9eb37e86aba4, 0xee77517d8bed, 0x830526946f96,
+    0xd719204d1966, 0x76cc01d21c69, 0x7a80621d8ce3, 0x292edb9c7766,
+    0x952185f53078, 0x9c720753adda, 0xb488c2d442a5, 0xb847a8d2bc85,
+    0x3296412fa1f5, 0x47211947c37b, 0xada0311bfca4, 0xfb978d727edc,
+    0x44ac5d78cb0d, 0xe94365372c7a, 0x3f84d271c2a3, 0x9d50a06389df,
+    0xb59dccdb7bd0, 0xb9747cf44db8, 0xdd9dcace62a6, 0x53968471ee52,
+    0x5ab7e6b9c72e, 0x7b50f88dfb38, 0xfae9473240be, 0x6a0a46733558,
+    0xd55cd1b827be, 0x17b4aab97fe6, 0x9400e7d49bda, 0x97c7cf60dfa9,
+    0xc3e232cb442f, 0x3566a16776be, 0xb78adc49f76e, 0x495887bf8076,
+    0xea02e9c2e61b, 0x58a94d8fc63d, 0x897b01b31e59, 0x871992d40692,
+    0xb2c53fd782a4, 0x6c873ec1cc43, 0x3f45a2174b49, 0x7383336beeee,
+    0xc2a0ba396cc4, 0xd0289c57b4c7, 0xeaf052982b66, 0x043ffa5f94e9,
+    0x8e642922dc36, 0xb7c1e74f38a9, 0x0341b3208134, 0x2bf0ae77a5bb,
+    0xeb8a360b488c, 0x7bb45ce17c42, 0xb163cf99913c, 0xa09c11904d73,
+    0x1a89887e9ad9, 0x1c68fb7dd7c9, 0x3a462dc31112, 0x948234cd02f4,
+    0x1039fd527e7b, 0x288a982bc6f7, 0x48083fcb0e8e, 0xb7b51868c45a,
+    0xf6137c65dda2, 0xfa4c88ba6070, 0x9fe60bc8396f, 0x40dbea290f06,
+    0x9bf6bd5af46e, 0xd23477930e0e, 0xf331f4aa26eb, 0x43a6245f6783,
+    0x51e49051ab9c, 0x460c2de1e0f2, 0x80e8bf0b84d1, 0xacf94c6ff140,
+    0x137062b6607a, 0x344771f1b00f, 0xea0959a4137d, 0x18004ccc65f5,
+    0x49b17cc4c2e6, 0xf99783305f83, 0x0a19cd64c55f, 0x295e0ac964cf,
+    0x0c6c656a5465, 0x60100ed2ed5e, 0xf9b0798cc2a8, 0x45f2049221fe,
+    0x1621234a0b52, 0x16b76c57d9e7, 0x1b012b7501e2, 0xc9f5de77d7a4,
+    0xf0a94417f994, 0x918a8c30c7e9, 0x771ad87cf0d0, 0x937e86ecfe5c,
+    0xf6d0e3c22460, 0x2db141334423, 0x34b55bf83a7f, 0x4ae0bf6c6656,
+    0xb19d5f89af80, 0x1969d55fbae1, 0x36902d3ff64c, 0x77c9a2c91700,
+    0x8a49cc4ab925, 0x18d7faf155ee, 0x900b0035d8f0, 0xb0559c695207,
+    0xcd1221a01a2a, 0xd5d708874858, 0x0dc6eb20288a, 0x2401dc91dcb5,
+    0x65eb39bb522e, 0x92da1e29a699, 0xe9df1f1081b5, 0x620511a6f3f5,
+    0xf1736bcfa6e2, 0xdd5e77721cae, 0x3329a1d60bfe, 0xee4f18f15b77,
+    0x5c2bf68b60a4, 0xcf496413df1f, 0xad0b49b92963, 0x5c1c5a02ca91,
+    0x7cfd3f44123d, 0xb959a5e58762, 0xba8f23cb058f, 0x4c0b9aeb8e68,
+    0x55c802511f38, 0xd981cdbe9549, 0x9bdf86fffe92, 0xa78c894b7d09,
+    0xc38614811585, 0xf1d8c1b17192, 0x72ba3a472573, 0xd0f44981c999,
+    0x15bf1fdbe80a, 0x87e0b4489644, 0x27d5ebd22271, 0x288ef9cb5dfe,
+    0x4269b5407b61, 0x37a40bcac1ec, 0x506aaeebab7c, 0x11d789e25974,
+    0x4a7803c89fba, 0x9c9201b36f56, 0x50e5dcf6b5c5, 0x8fed4bd176bc,
+    0x9587c14fcb77, 0x9315651908be, 0x99c810f9f926, 0x4ae9c260afb7,
+    0xd2e2c738445d, 0xe2515fab7431, 0x61ba8f391531, 0xa7faefe70147,
+    0xb35523f4572c, 0x141b1f6d367c, 0x3f67a407526e, 0x90d479061281,
+    0x9158004407fc, 0x9d4cebfe9618, 0xf3e78f1bf65f, 0x2d8924f79199,
+    0x602072f89ab0, 0xbb1169ed6045, 0x9e87d8c190fe, 0xa740657d879e,
+    0x2af05ae50598, 0x99997d662af6, 0xc9663214cc09, 0xe09b282a224d,
+    0x99d7cb5e4849, 0xc977aa8c7e88, 0x31ed8a5c411f, 0xcd216097dbda,
+    0x36d1c6979b8c, 0xafa0145ea42c, 0xf5bd75921d85, 0x28962f5b98b2,
+    0xfd9d3eaeb2a4, 0x18f0432be2e8, 0x615fb144e3ba, 0xb948869e314a,
+    0x4abc11f4d920, 0x365c1657ea84, 0x9ebceac19a4f, 0x1cd6139b1fde,
+    0x1d3d03034428, 0x731aef370c92, 0x196e807c3435, 0x4ce584e147e6,
+    0xc09e7e5119a9, 0xca297d09cc41, 0xda939e07ca8e, 0x5af88224320d,
+    0x8b9649756c7a, 0x92fd35941f46, 0xc2eb4fd40ab3, 0xc1d32f736b09,
+    0xec4a48cd8002, 0x114608a450e8, 0x4b452d0023df, 0x7efefc87cc44,
+    0x8081e144e48e, 0x94a938563855, 0xa8cfa52b7508, 0xb2744276add0,
+    0xed5825810a4f, 0x6d61aeb177c0, 0xc2c5c5203904, 0xe9ad179278a3,
+    0xf1644dca148a, 0x77b69a300318, 0x97ddc1cafde8, 0xa4b8b58c3c27,
+    0xaffe18c28505, 0x6d970254a651, 0xce46cd9c55fc, 0x8521ebef682e,
+    0x03de2d1fff6a, 0x3e7a8d4e4a0f, 0x8d38017650b1, 0xf60283fc6a02,
+    0xe0b886d76d2b, 0x6d8c510073ae, 0x746b97d2c925, 0x9fc17d54d1a4,
+    0x6dd733b2ce54, 0x7517b1f5b7d8, 0x8f91dcd18a98, 0xf2787c831329,
+    0xf2def6cd2be9, 0xfc53c554aedc, 0x3ce04c80c5cc, 0x9a5e19efdd9f,
+    0xaf65d7124d4f, 0x13163785b69a, 0x25c62fcb0e94, 0x419eca8d6840,
+    0xe06bc898c4e2, 0xd4473a1e78f7, 0x18fb6b295c39, 0x51e21e938dbe,
+    0x959e7003fed7, 0xa151d9f41eda, 0x4c91f21c7e5d, 0xcf36baf9a987,
+    0x9637de591073, 0x37233706dca4, 0x719cbc47be4e, 0x99bf0a080692,
+    0xd2a423890fb4, 0x213303ba795d, 0xa8f5979ab0dd, 0xa3837a724f95,
+    0xcaff417a2fa1, 0x15d16f415d19, 0x9fdaf0a8fd60, 0x54de8788a08e,
+    0x83676b2153fc, 0x89b6d08a7c6a, 0x1cfb7baff1b8, 0x68262facd7ff,
+    0x6c16093a248d, 0xc3dc6a3906ed, 0xb54f6418fe1f, 0xb7c47078f855,
+    0xbb6dd5f353df, 0x4ab0b9f5be96, 0x70053a914e10, 0xd5a1dd4d3763,
+    0x178723df3346, 0x7ad3c8bcc90d, 0x9d3657ee1027, 0xd09ad5125083,
+    0x5fc67a1a6abf, 0x3e39a6ba2c66, 0x5db3a56917ad, 0x5f00ed68ede2,
+    0x97f277172a95, 0xef8c3d24a7e6, 0xfc57a2e85dd1, 0x1807c0c7ba48,
+    0x1bc21399c0c4, 0x9e056a77521c, 0x004ea9c9593d, 0x113106a3364d,
+    0x15833c6c33c1, 0x4ad29c7ed187, 0xc4d6dd4963de, 0xf1017e5e6207,
+    0x2940c0d41440, 0x7560e648293c, 0x8ea67d7a94e3, 0x612dfabaab39,
+    0x4e6b56a77628, 0x02e32a2c5427, 0x5a9e7a582594, 0x49c26095e04e,
+    0xc9d7ced379c3, 0xde5896569d6d, 0x3ffd73f96c46, 0x4abba7016359,
+    0x5157730412cd, 0x89bb9f6e61ee, 0x06c508912149, 0x2b8f252c0e32,
+    0xad3ad3a1ae57, 0x263d8358e030, 0xec442cf69979, 0x84252ae8598f,
+    0x8f0413d489ad, 0x2379592207fc, 0x8f65d6652547, 0x1cfcf9ab6af8,
+    0x37633dec47c5, 0x89758ada4f3a, 0xf6702deb0a56, 0xc75a1912615c,
+    0xdd9c21d5c862, 0xeb2ae0b6bd7b, 0x918747c3ca1e, 0xf3e015135993,
+    0xf45a3b696281, 0x66538d82194b, 0xc191602e7170, 0xcee7d99fdb3b,
+    0xf74406b8d730, 0x4d67423473c5, 0x9f63be0d5b1c, 0xd346f4b417ca,
+    0xf3623741e696, 0x5970fec60ee8, 0xa6ef5991a50b, 0x9ffa6dae277c,
+    0x016590854c66, 0xd11ff04dbb41, 0x66fd7b87b893, 0x992b55fb5de9,
+    0x802de2c707ac, 0xcebf311bb4c9, 0xfafb34a6696e, 0x2cd0c3735bba,
+    0x994386a8b3bc, 0xbee4a86cd001, 0xca94de8a9f3e, 0xbdec19254c89,
+    0xc4481348b24a, 0xc66d4aadceef, 0x0353f0c2dce3, 0xa165d1cdca0b,
+    0x21499ccf7f83, 0x89931c3ce2d0, 0x182c21278ac7, 0x344b6a8bdb3c,
+    0xe678830bea62, 0x6dccd5a344f8, 0x9e8b1849b2ab, 0xcd738367d5c0,
+    0x3919c72707cc, 0xa998c13077c8, 0x14f7a6a0a1bc, 0x2b7fae58ee3c,
+    0x0f9bcfab6c1c, 0x32b5985dc09b, 0x4f3c0bb93b1f, 0x52ff4a55229e,
+    0xf026ef48fbc6, 0x684d56fd7990, 0x6208182b4b01, 0x9fbc870ea586,
+    0xcc6338f3d6c8, 0x32aa8076902d, 0xdf4d6122e43b, 0xfb2dc2e40211,
+    0x46ee1041a59e, 0x09c455efef72, 0x44055b324009, 0xc94613693a3b,
+    0x3bc70ace4e5a, 0x78bec2c81102, 0x2573b5972cdb, 0x5a28396f88a8,
+    0x93c53c1eb07e, 0xd36c306222d5, 0xc6eee1231856, 0xd2b9a00f84d7,
+    0x12f3938c78fa, 0x1f696c5dc117, 0x781bf3ede1a2, 0x8dbbce964e0f,
+    0xfaba8fb57105, 0x452b860c0a70, 0x59dbbd36d468, 0xe387eaad42b7,
+    0x2a0d2584f76e, 0x3201a9769d91, 0x7f550339f708, 0xe2bca604359d,
+    0x1e599707cda9, 0x69489733d217, 0x6e1fb394e7a4, 0x28f19e39ef7d,
+    0xacc542c8f183, 0x25363c9ec82b, 0xf83a9ee92cab, 0x11190c246e43,
+    0x50020eb12fe1, 0xf849341f902c, 0xcd1ea036c621, 0xd475141bd985,
+    0x6a100ec247e8, 0x9b349123c896, 0x37b0a714ba98, 0x59b5e254cfba,
+    0x7d541c8130c8, 0x2ab28f4bffda, 0x542b4b65051b, 0x731f3eb0bb70,
+    0xa1265981ab88, 0x6248433af11b, 0xad3aec5751d9, 0x21743932c851,
+    0xc1dc4531a4cc, 0xd24565f19002, 0x43a241e6dc70, 0xa325b5a1f4b6,
+    0xc37e8078d383, 0xb974de3fa09c, 0x017595f1b8fa, 0x951067b4b162,
+    0xab7e5bbc6c28, 0xef337fd92e63, 0x7f6657ea3d57, 0x570413bda19e,
+    0x996b059e7690, 0x15ae56efb181, 0x703f3b3effe2, 0x504ed52c6400,
+    0xa244e960a9cb, 0x41388fd6d57a, 0x85ba6df8c370, 0x8f8ffed2cee6,
+    0x79ddb2eb8b27, 0x1f37901e1279, 0xc633dbafe003, 0x07e7ecad152f,
+    0x1e66937d7522, 0x6ed3abe102df, 0x23016262938a, 0x4877488f6d5e,
+    0x504090e9d110, 0xd408506ba712, 0xf4df5fa6f6a7, 0x1dc49166728f,
+    0x4a8665c55661, 0xe3e8f7a519e2, 0x716da9ac8841, 0x9994c52849ae,
+    0xcfda80ee2d3d, 0xe65509b02556, 0xf93fcb8a4815, 0x485bb86e9d88,
+    0xdb8b7cbb09cc, 0x60232a145c4f, 0x9ae56cfe68af, 0xba9cb9675e32,
+    0x0948e11baa5b, 0x5ae8b8b07109, 0xd5ce5fca3260, 0xf792deeee6bb,
+    0xbb54d6106330, 0x72fdf7c0c7b8, 0x7dfa3e31d659, 0x81812f51c6b3,
+    0xab2f72261206, 0x5ec3873a7994, 0x24e3cf6ab90d, 0x2e326aa5787d,
+    0x5e91fa00919a, 0xbce35e37823e, 0xfbfd5453168a, 0x68ed3721c27b,
+    0x4f4b4c3c37f3, 0x98be1fe0c76b, 0x1c3cac9b672d, 0x5571d5a653a5,
+    0xa0dc96118964, 0x83822cd22688, 0x95b5b52b3be2, 0xe146e3723ae9,
+    0x0c8d0067b54f, 0x4c76e45ac61c, 0x97bf54f89a6d, 0xbd6285c3ea9f,
+    0x34b974bef772, 0x7c95587e6eeb, 0x9ead1aff5762, 0x3751720e56a6,
+    0xa91deea6a29c, 0xaac238f50540, 0xc073b0a22dee, 0xfd88eaadd8db,
+    0x95c42d4b83e5, 0xbc0f5a2b5af0, 0x171730fe6179, 0x65b43fd330d8,
+    0xf376d93fbebf, 0x6c91188242db, 0xf8523fb3342c, 0x8c4f3322d7b5,
+    0xb341baf53209, 0xedb9312edfc6, 0x64d84fd86cd5, 0x992425d27233,
+    0xde76425cde72, 0x38921ea29ed6, 0x88df6b5e0a9c, 0xa9e106838b53,
+    0xe365c9fa4f97, 0xf78dd0b8b4ad, 0x033d1c9a6cd6, 0x9d7b46cfd317,
+    0x7a5624305a21, 0xa3b925363b47, 0xcfe1b7440583, 0x13e9e42df615,
+    0x29c881ec8b5f, 0x5df610259b26, 0x774caab1adc2, 0xd413ee4cc97f,
+    0x1b8d51438650, 0x70751c530eb6, 0x9d6b52a21bc1, 0xa4c82e2bd0b1,
+    0xd6dfb3e13ca0, 0x5ab7176296f9, 0x864925d38cdb, 0x3d74b2cc91ea,
+    0x75712a1faf6f, 0xb610e839ab03, 0x13b01b3a0d30, 0xbb929ad682e3,
+    0xddbdced455c3, 0xf9cb9135e5fa, 0x08d6b72d55ce, 0x61ceb2925446,
+    0x699ffc7a81e7, 0xa253e7ddb296, 0x7b321c67a9fa, 0xae5ea4ad60d0,
+    0x01a743bb0183, 0xa27679b1080d, 0xd62dffa276c0, 0xe220b7c8c72d,
+    0x92c4d4781e45, 0xfa10473573a2, 0x0314f170ddc4, 0xd367e3dd54e6,
+    0x7ad569fb4a5b, 0x4bbb71a33f4c, 0x6e200fdbba53, 0x79ab7206e8ab,
+    0xe6e3b362afcb, 0xc1138889eb03, 0x553465b0185d, 0xa450c7b2795a,
+    0xea244671ab24, 0xe8393ef643f8, 0xb83e30e00bb1, 0x07ba3069d858,
+    0x9a8eb74334d5, 0xd1c2581088b4, 0xe3ccee68954f, 0xf2719a15ec63,
+    0x2c31d6ce8cbb, 0x8225f33384ca, 0x513030cc991b, 0x638ef4bcf0e6,
+    0x783664aae39d, 0xef5d84e23a9b, 0x29c741968e2c, 0xdee093895575,
+    0x44aa0bc5a99c, 0x4baa087c381e, 0x597b57f0bc26, 0x9113d91f0062,
+    0x9305d35f10a7, 0xba7af8334962, 0x0e3c23adcaa0, 0x1a17cff1e213,
+    0x6d975514eb76, 0xa4f6317981c6, 0xc35784a7c5d6, 0x5159b56497ab,
+    0x02aa10c3e738, 0x052316a53f86, 0x1c46e4d3e513, 0x7e9e183a4c25,
+    0x10033a5e8880, 0x9285b36e523c, 0xb38296da55cd, 0xeb22df075e33,
+    0x71bd73f86bed, 0xf2d3fe2b5a56, 0x6df0003a7cd0, 0xea9956b7c4f7,
+    0xeff1eb4f9c12, 0x4390c6fcbbe2, 0x6f21b0a294eb, 0xce38cd8efa72,
+    0x2441963df5c6, 0x282bf262b7dc, 0xc0fa0facdaf0, 0x7fcdb9fdff1c,
+    0xba40627c33b0, 0x82f3391e55a4, 0xb17f3a875dd5, 0x39d976cf8688,
+    0x76f09b555ba0, 0xb681f912b39e, 0x5f3a7d9a4596, 0x1a4113498a2c,
+    0x4537d062328b, 0xce317c11109b, 0xe084d01247ec, 0x3fbf110629f2,
+    0x891bef088f8a, 0xc547621b3201, 0x4918f7a3001a, 0x86f9173ba89a,
+    0x0bcec1fc16a8, 0x1f7291cf74f4, 0x6c03cd42a819, 0x88f9a6b21c60,
+    0x94227212940d, 0xb7c36af33a57, 0xeb17e4fbe6d5, 0x122654f7010b,
+    0xafb3c487e1a4, 0x1d1ca8e179aa, 0x21306c255009, 0x51853b3e59fb,
+    0x63374b60adc7, 0x90ca32622af3, 0x94a2074619b4, 0xbf11cebf760d,
+    0xd63d9e7be589, 0x2cbfc7d1dc79, 0xa99ac18b089d, 0x1bfd87312328,
+    0x8813a6225a81, 0xa06182475dc1, 0xe8c3e68815b6, 0x295d778c7583,
+    0x6eafb8a3eb84, 0x99c8c589f551, 0xb7acbe8fdfad, 0x60118a9d31ff,
+    0x836b252ed031, 0x06e608f913a3, 0x75c3c7f967ff, 0x75cec2ca7cef,
+    0xb835901e481f, 0x0ac13db34b30, 0xd37cc5904862, 0x2375e4b368f8,
+    0x27b72d24bb7a, 0x33224e89bc51, 0x3bd29f28e33d, 0xb8a10427d20e,
+    0x24653f16f7e9, 0x47b6d81acc2c, 0x61feea9caf65, 0x70ad31c0f8cb,
+    0x960c729e9770, 0xcaee7ba46f60, 0xfd4757205162, 0x083bc0909fd6,
+    0x30f1d3397738, 0x3fdaa963859e, 0x8f65873dc94a, 0x0104098a8d93,
+    0x100a27377e79, 0xe927b882b406, 0xe7ac51aa4c62, 0x94ab777eb192,
+    0x93275f6b6eb0, 0xcb60e9e990ac, 0xd4d802074d51, 0xecf2db9649f6,
+    0x80d93fb8ae53, 0xece797ab2182, 0x2fbc1b27c20f, 0xc8f2c1c331d0,
+    0x110bb8adadc0, 0x9e15f3ce1169, 0x8ef7e779beb9, 0x1ba7c7ec3b6b,
+    0x3c85795b3251, 0x0fca22a2ec0e, 0x4c07a7d85650, 0x6211469f2846,
+    0x889d542769da, 0x0bec873c5f51, 0x694a29e745a6, 0x574af4f34bc6,
+    0xd4e43f7f382a, 0x01e70a888288, 0x59fceff140b1, 0xd71571f37cd6,
+    0x067a4cfde28b, 0x243b0bfa5465, 0xaf722e46b2ac, 0xd4da957d3819,
+    0xaf04ed3be8d9, 0xb64f7c13d039, 0x1923cf138b3a, 0x293553d9d4b5,
+    0xdbbdebd2e7ab, 0xe14e50bc3e8a, 0x233404f557b3, 0x4b0349e871a0,
+    0x9ea7b90017eb, 0x38c8772591ea, 0x5e9a51e97b18, 0xd8ddb4e645d3,
+    0x673ab56f0a29, 0x00c464d7a841, 0x7ec6add2ecdc, 0x06688bceeb51,
+    0x1b81e5f61742, 0x91d4bfb88420, 0xa441b24c41a1, 0xc78591cabbb8,
+    0x14381ccd3d34, 0x5fbd06eb00b0, 0xc9c125650008, 0xe3ffd6fef6b4,
+    0x1ce97521657b, 0x1e703d5ddfda, 0x5e271ebaea0c, 0xc59789f7ea10,
+    0x292908ff4878, 0x8261e50cb179, 0x6685f1eafd45, 0x82b070af686f,
+    0xd3aa7138d834, 0xd5bfe01166c0, 0x3b7b9ec860bb, 0x56cc1f205188,
+    0x8e23e43334fd, 0xcf5bfa623313, 0x47bb1ca447b5, 0xfe0c3612c6dd,
+    0x90cd94894c75, 0x7774a1dbf268, 0xe30a32d27f47, 0x19ac1dbe77f5,
+    0xbac4115da935, 0x7c913988d426, 0xbe4ee5fcfba4, 0x3809b97151f6,
+    0xb948c0873d94, 0x3edcf6a971a7, 0xc407099130aa, 0x1b553d3f4206,
+    0x554e6f147efb, 0xb4bb18f35250, 0xc81e2cab4fd5, 0xffe1e970d682,
+    0x6f17f417dde8, 0x63fcbdafb6a5, 0x75e120baffbd, 0x95827781cb60,
+    0x2440ea0a2988, 0x644dcd77ad12, 0x9560b2d4717f, 0x9c0e566ae2f9,
+    0x4b5efe04c815, 0xa25d382af21e, 0x3a035685eb6c, 0x6d79361fe244,
+    0x849fc302cd0d, 0x8d03e79772b8, 0xa50b2b1c7b32, 0x7409be376944,
+    0x51a5708ff005, 0x6417a5fd3239, 0xcfa9c892688d, 0x3076663eef2e,
+    0xc9c64c50d598, 0xcd146ce13317, 0xe6ffa08fefe2, 0x1ed2b0c3f91e,
+    0xbe7a9777e731, 0x292626e665d5, 0x745f7ea4bd5c, 0x07dcce55cd7f,
+    0x3fee8b9e0395, 0x922ebcd596b4, 0xcb8635ade49a, 0xfb64876141ee,
+    0xcd87244067e7, 0xf4a6bc2232d4, 0x7aa883e03282, 0xeb63a6d88fe1,
+    0x21d21a10766a, 0x8878ff1a9b79, 0xd8d455ec6333, 0x091af662076f,
+    0x408f18bbb650, 0x45d64addd6ef, 0x711f26879691, 0x036b8b7f9beb,
+    0x487c60ce9fd6, 0x6521e19a1d43, 0xc996bff5e295, 0x3bfb94a3310d,
+    0x4d92e167e1d0, 0x7499ff824366, 0xbee8e09a8128, 0x5072b22454a6,
+    0x7bd4f1d194b3, 0x98833262fb1d, 0x2ea1b4475efb, 0xd7cea72d69a4,
+    0x2365a36df4eb, 0xe0018f19291d, 0x416c8d634445, 0x794f6623db9a,
+    0xb9c129f24f81, 0xf3b21faabcc2, 0x188aa5781526, 0xba0f7b0600b2,
+    0x6cdfe66c274a, 0xbdd9374885c0, 0xfdcc1d077393, 0x01949e7cdfc5,
+    0x37c4d9bea4fe, 0xd2aa74e88a8b, 0x907e280f769c, 0x961444f05db8,
+    0xc501f2e80580, 0x2b7fab621c0b, 0x4702b93207e9, 0x912fbf9aefc7,
+    0xdc691759474e, 0xed54eb1d0cee, 0x16d2af80911d, 0x35d50bfeb2ed,
+    0x4a84b8097558, 0x05207ad1338f, 0x16f54fdef453, 0x75d82f683a63,
+    0x1f2a30cfebb6, 0x8a2e810328ee, 0xc22be2925d87, 0xd2e96b7999b2,
+    0x97d55a8f77d7, 0x8eab342b49c5, 0xdd3fb65247a8, 0xe348831b7550,
+    0x0190724638f5, 0x308ba6ab2368, 0x436d7cbb6524, 0x754e06596c60,
+    0x4ff96130d324, 0x8bd16c5a37bd, 0xeb772fe2b171, 0xe959d57e54f9,
+    0x0d19001df4d7, 0x4871c6a4d591, 0xb62786b60027, 0x4feaa0594666,
+    0x94c997dd608f, 0xcacbd8d39e1b, 0xf5ecacb86c12, 0x0b0c33f533ca,
+    0xe7d44f85e1ba, 0x6fcb5b583e76, 0x4cd5cced7590, 0x04cd643e950b,
+    0x3e14d9fb4f13, 0x3d7c5d5942cf, 0x8c3db500ab86, 0x2a684a6207ca,
+    0xd92e18dd3ecb, 0x8078da0fc748, 0xa56bae1cb72b, 0xc599b27a5f11,
+    0xd4b79a41a7ff, 0xe2aafef487b0, 0x6c04bd48dcf4, 0x7a80ec1336d3,
+    0x36a485e0903f, 0x927916b8c283, 0xcf246818fe25, 0xf0e9d179c994,
+    0x046cb0d9e910, 0x5f95539324a7, 0xb92e1db8fb1a, 0x4997dbdf72be,
+    0x65f597c8ea48, 0x8a00b3e405a2, 0xe789070626db, 0x8f8d85defc34,
+    0xada35cafd052, 0xd151d486cb03, 0x3fe95a033420, 0x89a64aab10ec,
+    0xaf95bd82de62, 0x0d259d14be00, 0x75fc3f37091a, 0x96fc05e45ba9,
+    0xe4c8d650acbf, 0x591a27d58104, 0xbcaa4782bcce, 0xe1018fa85969,
+    0x58deff24a6b8, 0x37c7f97d03ae, 0x3a43e29faafa, 0xa150a848d37d,
+    0xfb1965bf6987, 0x2e34006f6254, 0xb50999844098, 0xe7c580e8e4cf,
+    0x1a19df3a4f70, 0x2e57667cd2dc, 0x7e16e0d64586, 0x984763ccd67c,
+    0xe6614a7fbfe7, 0xddcda7b8662f, 0x7017c31a31d7, 0xcec591063d5f,
+    0x3f954a46a200, 0x757416a46145, 0x7c3824fb0829, 0x15f80acbfda2,
+    0x41557cbdc486, 0x461e2fec6e98, 0x6907050ff069, 0x7257af51e3c6,
+    0x9ebb0af478c7, 0x5350608b3f0b, 0xda38f5fefc2e, 0x7897c8c377e9,
+    0x972d7de1e532, 0x0dde95d19b1c, 0x8a89fb561730, 0xbe340251317c,
+    0xc2ccac2d1ff4, 0x34cc2b5b7285, 0x5266075e0f8d, 0x60cdb5e25e2d,
+    0x85a6eb5cc7e0, 0xb668d6f391e3, 0xe1c94228a3c8, 0x944ea64f65f8,
+    0xf580997ae880, 0x38e2051d6b15, 0x48b467b17c2c, 0x9441c87b1521,
+    0x06d71b992abd, 0x809adad76212, 0xa63176e874f1, 0xb0b4a5cc9b7a,
+    0x26f82c2085c9, 0x425f67c9adc8, 0xdab48388e4c7, 0xee98d4e89e15,
+    0x70d31b6f44a8, 0xa60f4493fdca, 0xf5a7a16495dd, 0xa7b9bbf7fc35,
+    0xdb01dbd57125, 0x68ace5b6acc9, 0x6fe945eb2e73, 0x8c61158537b7,
+    0xa13595829b07, 0xd0de3beda887, 0x642938b5c35e, 0xa4653b39eb6e,
+    0x21b58d8da757, 0x3f26778f3ba0, 0x4add4fe0b22e, 0xf62be73d792f,
+    0x3b5aee4b76ae, 0x150910acea93, 0x263340f3db45, 0xb9241d9d14ea,
+    0xc424a6a99527, 0xd9ca73d89796, 0x7c2196d63558, 0xc8687ab71564,
+    0x43021a535f3c, 0x66a29e15f9fc, 0xc2b0d3b7da2c, 0xfc4add23b701,
+    0x8116b1de2cf8, 0xd3bbc376b074, 0x3937b04e60bc, 0xe05c03a51ff1,
+    0x7dd2cde6c55b, 0xc24bfacdd813, 0xf3270fd91cbc, 0xfe36773613fe,
+    0xb61ebb18b0ea, 0xea571f95cbdf, 0xfcfbe27b0924, 0x820abce71994,
+    0x209d2becb136, 0xa160ad527148, 0xad61a2a7352a, 0x031836c31d38,
+    0x90750592a3ae, 0x6d57a9f1eee2, 0x0f5ea763ad4b, 0x41b53949559b,
+    0xa19e3d126f8d, 0xcb2ceecd9eae, 0xd507fd10349c, 0x71a5789270f8,
+    0x5f39111ebb04, 0x908560b62798, 0xb50fe600d093, 0xf5f060362cee,
+    0x1ede43308414, 0xeb445552f9a9, 0x4d61e3efb2cf, 0x9c5fd9a41473,
+    0xb07050d72746, 0x4b9f1ec84313, 0xc9d2e67261e9, 0x5ef6b406125f,
+    0xe5e5ebad76d3, 0xfa767bbd2896, 0x5363db6d21a5, 0x047932a8a0a8,
+    0x4f4eced124ab, 0xfdf7cae784b6, 0x3db1a587d9e9, 0x559d00e42281,
+    0x62e9a44f9435, 0x99b446ad8fdd, 0x55c175bab3df, 0xec0fdc64c995,
+    0x2b04da00b33a, 0x4c6ae06918c4, 0x6277ed914145, 0x6e27e9200abd,
+    0x0ea157949db4, 0xceaf12f7b371, 0xfc298031c746, 0x66db52b7ddb4,
+    0x02e8b241e742, 0xcaac70866e4e, 0xfc69858bf743, 0xffbb899bb6d7,
+    0x44e5c2ee3b2d, 0x713a40405929, 0xaea2488a2ca8, 0x1ed0f87ab389,
+    0x243830d3fbe4, 0xbfaf0cc13118, 0xe57f4d9bf1e3, 0x231e768a33d7,
+    0x79e5b498f92d, 0x9c1247f0eeab, 0x0780bb5ea0f3, 0xc3f6e8db1d2c,
+    0xcb46456e7db5, 0x4820ca099e4f, 0x7536e202c954, 0xb287203244cb,
+    0x12ab62ae350c, 0x17b96524b349, 0xfa31ebd5dfd4, 0xd0cc421db575,
+    0xef7bd05daeb3, 0x0b73fc392491, 0x8d7d392efc73, 0xaaf15702e182,
+    0xc4936dec6410, 0xce197a5c93bd, 0x69fc296f6267, 0x8eb46e768d4a,
+    0xb0992e616f26, 0x19fb93de7f6f, 0x22d6e585e307, 0x8f3ee8792bde,
+    0xcd95857780fc, 0x0dfc8903e5b8, 0x8d0a73a788ad, 0xcbfe71055f3b,
+    0x37b3e3a60a14, 0x5d980b1bc0d2, 0x8f9fecd1a86a, 0x95679e6365c4,
+    0x70d10f7e8703, 0x11dd932e97c9, 0x5bc6348bc290, 0x4c2e76ff727c,
+    0xe110d8cab0dc, 0x4082ea1012a6, 0x745b25e5ba64, 0xb4df70f501a0,
+    0xe21d7ab1224d, 0x0dcbabd9ddd6, 0x0844834b69c2, 0xcd75fc3821d7,
+    0xeec2640e0131, 0x0f4153a695c2, 0x25c5b9e57436, 0x8a4fa3a74c54,
+    0xc521748ee108, 0x1dfa0367cd10, 0x1fc58fcc140d, 0x0e4ed45e9293,
+    0xa98409f44fec, 0x589112318543, 0xefe1690b0afe, 0x8f76c87e25bc,
+    0xc4f5e8c96b98, 0x66b637993338, 0x75ebaeebce69, 0xd48854f1293a,
+    0x950a7eb2b7a8, 0x9c55bd37b057, 0xb3d2279e0ab6, 0x1253709aad32,
+    0x3ec3266879c1, 0x45f083bf7aa4, 0x751e78b477a9, 0x90071f682d18,
+    0xeaf51d19a7ac, 0x544ff626f990, 0xf4aad3c0836c, 0xdbb4ecfbeb94,
+    0x88f68d61e3b0, 0x15d0d53187c2, 0x366d55671257, 0x656d158181ea,
+    0x80aa245c884e, 0x128290142081, 0x586fe7f4de40, 0xc795b4f14c03,
+    0xf12e02236d76, 0xb278a34eb603, 0x17384e5ad9f8, 0xda603d7c2f85,
+    0x0cb2c7556bfe, 0x74e7b07ac809, 0xccdd1a8e52ec, 0x495db9999819,
+    0x6acb8700c1cd, 0x17d641515139, 0x4df1712d03a6, 0xcb5868cffabe,
+    0x666ad97a4b1f, 0xe9affbfa42ba, 0x30e9b19ebf9a, 0x04fc39b6c1eb,
+    0x487cfbe35aed, 0x3e1f2c11dcd6, 0x78792039a24e, 0xb77f6a49466c,
+    0xc66fcce31337, 0xea0f2bda911e, 0x4f693e02eb82, 0x57c1b8196aee,
+    0x68fc6099fb08, 0xd259f85b9228, 0x55c6e81175a9, 0x7cf6830ed474,
+    0x59f8b8873bda, 0x11f399aca81e, 0x850a65017f48, 0xc1c2fac9098c,
+    0x3803fcf7b464, 0xb3616acf48f4, 0xd9f8e846ab02, 0x764c46c59a0a,
+    0x2d3c5a7cc305, 0x4140b213a40f, 0xae57b26b2a97, 0x19c7fa510b07,
+    0xb77ed100ffe9, 0x3ee067ffa921, 0x852cfe4bc303, 0xaab35cb23c3f,
+    0x5ba997f2aa9a, 0x89727acd7962, 0x3705ff3582fc, 0x5713c2ccfd61,
+    0x341a2c55c8a4, 0x93ad2e0e693e, 0xc1281066889d, 0x130ff5f768b2,
+    0x6a8756358892, 0xddd6dc17f694, 0xf12f2f8de4ee, 0xf21846d9dc15,
+    0xff1340d24210, 0x6756589b03d1, 0x4a19b3737d43, 0xa8d0a7b86f93,
+    0x3ebec12e0850, 0x09a1e00857dc, 0x1d692474346f, 0x7a2bd4e2dcc8,
+    0x9f747071403e, 0xe92715f51969, 0x6d6b4563320b, 0xd38afa3f054f,
+    0x940a2abd48bc, 0xb6e103bffec5, 0x0095b84b0bdd, 0x1fd59d123dae,
+    0x57b248bd393c, 0xdab21bf55cac, 0x48f1905a1884, 0x8e8920b962b0,
+    0xf184cbd0fe0d, 0x28a9c8dce0ac, 0x6135ac7b1fb0, 0xac417f0bfb14,
+    0xb01117d31a21, 0x644361811e30, 0xe4388ef88a24, 0x7646bbfa6e2e,
+    0xa878b47c7b5e, 0xc7eee5ae0093, 0x4fbfa8319dc9, 0x7b2d57cdfe50,
+    0x4d92bfcfb8fd, 0x90f6af977b09, 0x97129d4c9152, 0xb54ebd6c24f6,
+    0x7e20757a51cd, 0x1e5a120f9b11, 0x2483b94a0b86, 0x83d932b99c1f,
+    0x343f6803d3af, 0x0b403895c210, 0x1b4478f4bce9, 0x6c94f0acb190,
+    0x7685ba026b99, 0xf10a88c7a86a, 0xac87b1de95a1, 0x6e02f607af14,
+    0x3154c1662474, 0x223b483e4528, 0x2f028ff39339, 0x354560a27e54,
+    0xa5fd15398528, 0x81c957ae62d7, 0xc0386e74214c, 0xc9d753c7c9d7,
+    0xcc9cfad1aef0, 0x418878e3b3df, 0xfd2b4a275d0c, 0x033bc7ed52db,
+    0x114ada39eca9, 0x2a66e5c03a45, 0xa694f11c5ca5, 0x16a51031da6b,
+    0x6b0326edd18f, 0x9a4ffd4ed977, 0x467ac6ac5884, 0x5c36fe9b905d,
+    0x88151489fdbe, 0xc5f56eb1ac03, 0xddc8e9f1a090, 0x1cecb6132cf3,
+    0x6f17f1e6a949, 0x1aa470a88fd2, 0xa9f4d3a14ef0, 0xbb24f66ed5a4,
+    0xbe23b722611c, 0xb53f8fb31884, 0xbd703c93ac0a, 0xd0c5d1555528,
+    0x37e751d38eaf, 0x490a6ac8cc86, 0xfcfbbdcc6a09, 0xf952e372160c,
+    0x22d70f7a3bc7, 0x297b1799baef, 0x40abefa0a959, 0xca816e342fee,
+    0x904528224ac1, 0xa8d93dcb3940, 0xc57ae9a26257, 0xd2e300188863,
+    0xf8fb14605cd0, 0x36c58ee7dd38, 0x285177ad494b, 0x68e2e296d567,
+    0x42add75d2042, 0xdc6b7397dc09, 0x29fbe08639f7, 0x16948a32cad6,
+    0x1f1d482b0ba1, 0x8ab054fc5909, 0x5564794d0dd1, 0xbfa2ec3dcc64,
+    0xf86e58a14197, 0x0ce01d46e47a, 0x496b0953e8d9, 0x86d177b493f5,
+    0xd9c832c0232a, 0x3b3fea518567, 0x62774c4da85c, 0x6e733957ed50,
+    0x8af0fb788c96, 0x6a93c979326b, 0xcd48dbf0d302, 0x91b2cf26d650,
+    0xead874465aad, 0x0fa21bc33c0c, 0x9d3bfdbdc6f9, 0x3d2bf26dce73,
+    0x79e204101940, 0xc90c4b9bcc6f, 0x57ca0e771948, 0x61b8c8c59f64,
+    0xfa061d861239, 0x536e8dc82b4a, 0x59dac5962aae, 0x82e7c48b927c,
+    0x864b2b11088c, 0xaa7ec40f211b, 0x7987a24e12ce, 0x96bfd1c2103f,
+    0xf078518e0ca6, 0x1d1910eca7e6, 0x49492f6a3087, 0x82422f862f9c,
+    0xa6c896c270bd, 0xc7254518b19f, 0xf2702fc9a52e, 0xce5c8e77a69c,
+    0xd9df79f6ff7b, 0xf0c376465b55, 0x3b5d240ef7ba, 0xb73358e2729a,
+    0x79a44911c087, 0x04fdf5ba8601, 0x4f86067e6625, 0x5b007b51e98b,
+    0x2251c43d2fd0, 0xc81d75a6f459, 0x22b2b293b059, 0x61139ab60918,
+    0x009ad2fcac64, 0xc47a70bd1ae0, 0xe786ba791af8, 0x1ca108ee7e36,
+    0x74378527aebe, 0x822fb7960798, 0xd300c61e6e9e, 0xf31098bd1cf0,
+    0xb613043ec049, 0xd21d07adf82d, 0xfb12b1a7137d, 0x11f4d97e412f,
+    0x910a07778f05, 0x92ed39692448, 0xf3b3aa000f22, 0xad3c3eafb09a,
+    0x956b2307f1c2, 0x06a132056561, 0x209149719758, 0x400ce209ab02,
+    0x9f7b84edb641, 0x067b40be95e7, 0x652d2cb90949, 0x3c6b859407ab,
+    0x5ace4e1e6a5c, 0xa0928f71159a, 0xa91bfd1667b4, 0x9125985dfc6d,
+    0xf8930a57b170, 0x6725fca7a729, 0xa837df89e1bc, 0x688c0effe87d,
+    0x9e1e3a351af7, 0xa877684f17eb, 0x2f109de60307, 0x07b23463017e,
+    0x811cbdbe6eb5, 0xad46749755ca, 0xc774e886b2d3, 0xe76fcd3838a0,
+    0x14929e78aaba, 0x9484d2accbad, 0xc99b0313c711, 0x77f54efa6664,
+    0xb76fe2db7971, 0x7f16dd463746, 0xe9074fb44670, 0x4618c654ab84,
+    0x6f864d0d9610, 0xf7b3042842a0, 0xfb317e9c5d81, 0x419bf6166f2f,
+    0x0eed691b159d, 0x69da07726d93, 0xfdf183b0ae60, 0x1c0b3e00e493,
+    0x5b46afafbf3c, 0xc8ed56e16d08, 0x14cd4b5a28ca, 0xd8d6c557b719,
+    0x5bc3d6c0016f, 0xac195f983ce9, 0xe1779dfb51b4, 0x04c1c0916395,
+    0x8c6af06e1aaa, 0xb07bda82c244, 0xfe34c4541d67, 0xd2c04df70bcd,
+    0x32d452c8626b, 0x3951c87f0e89, 0x46fa9b10955c, 0x5fab0e004f47,
+    0xa1a1ea201927, 0xc5fb06bde3ea, 0xdb484db72d8a, 0xf9d9e4c732f9,
+    0xbf536bb00475, 0x6d850024a0ce, 0xf55dc43607f3, 0x5c2575266210,
+    0x85fc34ddaa98, 0x3decef6c1cfd, 0x85521b36e213, 0x04e6fb79d10e,
+    0x21486053a4e8, 0x58755eed5176, 0x37164b499505, 0x7fcb7dc8758c,
+    0x87bccde7cae9, 0x8be2a34e6913, 0x6fb5f0f5c03a, 0x87c2d6705cee,
+    0x9be01cecb2d9, 0xb4a81f67bf07, 0x60a020be3d3e, 0x9f0770853789,
+    0xdc605fef8e3e, 0xba7304cbcd27, 0x0bd2cff2445d, 0x84352606a9c5,
+    0xa8af83a8b520, 0x72e36a8fc136, 0x33cd0ed97b7b, 0x5b7ff685c9fc,
+    0x5d94f0404cfe, 0xf282876ba613, 0xfb2593a37e5d, 0x8c514b8d1291,
+    0x0742d7b3ffec, 0xffa814c52b74, 0xc8c8f221248d, 0x1ad54c889600,
+    0x695204953bdb, 0x0518f00c5465, 0x397dd978d866, 0xc95656dc0063,
+    0xef6669960ae1, 0xf8ff21d417a5, 0x0ff56ad5410f, 0xa190fdf0da90,
+    0xff92180ee219, 0x82de8c6f9254, 0xb834c944dd30, 0xc2063c1080cc,
+    0x881ad9c29a06, 0x8a6cac191bba, 0x6af8cbf4be7b, 0xe6d184ed78d2,
+    0xf8a2b10add46, 0x482cdf380fa3, 0x874ffbac8ab7, 0x58ef4bdcc0fa,
+    0xed5418f09fe1, 0x7ffd974ad16e, 0x42c991d517a1, 0xd10f53638b7c,
+    0x8552c4f84042, 0xa652c6349896, 0x5ff5bf25cd90, 0x91583eee69c9,
+    0xd71fb41365cd, 0xb2392ba69fb9, 0xf2e877be23a1, 0x7fd40c01822c,
+    0xe8790ae7e949, 0x12dec1cc47c6, 0x7305134f6bf4, 0xb7ff7dde3a75,
+    0x08636fe8e16a, 0x05627ba11843, 0x1184d687267f, 0x6e13d4b2b6ee,
+    0xf76b0ee1ccbb, 0xf3917b87abd5, 0xf8b5fec05b7f, 0xc6440fd1fada,
+    0xd3720c606770, 0xd94b6981eeca, 0x2e2281c8d203, 0xb5f936d3053b,
+    0x29157f9066dd, 0xd6945f255b76, 0x8eb3c3ac66d7, 0x0c16d7cffbda,
+    0x37423167d337, 0x071a9e6231e4, 0x22ddf32f72ff, 0x4354efc54b60,
+    0x840909984871, 0xa2646a3ff67f, 0xb81915bf6ff3, 0x4149bd85b2b6,
+    0x924d1ef70f08, 0xa964586b7742, 0xe073d4f33b8b, 0xb9afd28c7071,
+    0x691560ff5e97, 0x46805a58186f, 0xaac1fb8ed161, 0xc422cb3ceb9b,
+    0x13638c13e1f3, 0x8ef0539120ce, 0xc0ce9398ee58, 0xd77d43bc09e5,
+    0x0debb6f3f06e, 0x217c8a686fdb, 0xb0cc7843236f, 0x1b45b4c8acc7,
+    0x6cd882852a98, 0x57df44972266, 0x7d0c328bcbbf, 0xcca8384cc022,
+    0x01fb414fba38, 0xcdf13db124bb, 0x139353c3784d, 0x1c9d14d46f1c,
+    0xc416f51d9c1f, 0x24278b8d9c32, 0x5245111ec1b4, 0x12516d27b4d3,
+    0x6d845b04f40b, 0x916f3659e1e4, 0xa9271a021857, 0xd604616bb081,
+    0xffb2decf0199, 0x538656fb5067, 0x0b763d4fa7bb, 0xc2a747b13742,
+    0x2a807e4577fc, 0x5a12a3ee540f, 0x9d8b648d2322, 0xd3ca090153d6,
+    0xd5d65180fb9a, 0xfb9654b122c2, 0x454b7e5a3b64, 0xf603471582aa,
+    0x8a08b130fbac, 0x634d9a941195, 0x9fc8d41683de, 0xee7470aac87a,
+    0xf4ae901ed857, 0xba32279ca7af, 0x421fbc851bdb, 0x9c1440b2fbe3,
+    0xe8b9fc6c3a47, 0x2d4292c3cb91, 0x56f4f3a67ebe, 0x579512ba456a,
+    0xa8bbe8c42003, 0xefa519c0ae03, 0xff39b97ca954, 0x2d8f49ee545f,
+    0x4cea673134c3, 0x959c2b4ebebf, 0x5d6d3c85a22c, 0xc7173ae0c052,
+    0x0964c8be14d9, 0x94382f749f9c, 0x087dd67b4da2, 0x2246453e9d75,
+    0xc46be98d60fc, 0xfaaf964c109e, 0x8a70f941f1e2, 0x8e23bfa439f9,
+    0xc0348d240294, 0xd6a9987d7035, 0x0deb67ee29ca, 0x6b8ff3ca0cad,
+    0xf0e1d3e7b796, 0x0765d393983f, 0xedac93b1c139, 0xf7b6115dea5e,
+    0x4098a47d3d46, 0xb6d9318ddc94, 0x2a15f5a0717c, 0x68ce1aa05c6f,
+    0x869247fe24d4, 0xa8ad03c2584c, 0x1daf61b29e2f, 0x75dd61c6b628,
+    0xfd2051e85a42, 0x4ee86bd11860, 0x607c9ac57460, 0xba7059444716,
+    0x2112ab21f8c1, 0xe582d9f8a595, 0x1139bc5c3c5c, 0x87189cde3c13,
+    0xc802a3ff7d01, 0xf3765c246f8d, 0xd01794bb48ab, 0xffa64c02ce33,
+    0x3a99bd0ab306, 0xb4569f863d45, 0x1574ac16c9da, 0x72bd2d59a160,
+    0x7adcb253842f, 0x8f76f3dc2447, 0xcfb0d8ce0aab, 0xf95420d1410e,
+    0x52d719e1f763, 0x08cbdf9d57aa, 0x17decc938ba8, 0x44e24749e755,
+    0x20ee210f08f2, 0x339fedcc8f14, 0x5e8d25704124, 0x6c68105bb77e,
+    0x86e7f32f1a7b, 0x37870793f2a5, 0x86c03bfb2a98, 0xbdfd3db06b04,
+    0xcb2cd427fffd, 0x88a283b0a8c7, 0xe417ec1f7aeb, 0x7ae1c277c02a,
+    0xd04a8214f0d5, 0x6db825fdaf77, 0x84e8b4ea8a5d, 0x0bc09795a253,
+    0xd72e798ca3f7, 0xfc79d3cf4ca9, 0x4b41cdaa5cb1, 0x224f83878a2d,
+    0x39fc6c5eabb0, 0xac4caac3f2e6, 0x672ef7a09e24, 0x7f63aa5126be,
+    0x171f83bf4427, 0x5fbb43af9684, 0x82e0f16f1707, 0xbd5ab13efe76,
+    0x7ed3abd1de37, 0xc51a39956614, 0x178481991ecb, 0xe7b7a85d73f6,
+    0xc1c6a3136c61, 0xc55161b0c2cd, 0xd3fdd45cde0b, 0x3fb7feb7060a,
+    0x976ebbb66413, 0x2a5fd2eea37d, 0x44443fdcaf35, 0x9b1c30b419b8,
+    0x0c1e776c796a, 0x5451bc968cd9, 0x56b47c44b97f, 0x8cf320b8ea33,
+    0xdb882dffefa3, 0x5e31a00b8a34, 0x5a454ae19034, 0x91d13755971d,
+    0x03afc610aade, 0x7c8b6e40fe23, 0x9cdf6b5c63c7, 0x92d828bb6695,
+    0xa0deda5e26ed, 0xc2914760137d, 0x8f8c08a72f44, 0x6a116ac0d84a,
+    0xae278bbf28cd, 0x31b18868e909, 0x90aecc33c29b, 0xac4a53cb17d0,
+    0xaffcf5a05625, 0xcdb3a407ca23, 0x3ecbe06253b5, 0xaf982b4161cb,
+    0xb9650ae09203, 0xeb1bce43756f, 0x1f44c95011d2, 0x303220fb03d2,
+    0x4e99a13ee3a9, 0x588bc9eec050, 0x16af1781842d, 0x58deae2ee4e5,
+    0x1815054cbbbf, 0x3e8a6d85c224, 0x11536c904693, 0x2b76e5ae8eac,
+    0x663b379fcef9, 0x1886bc6ddd78, 0x900b6b256c33, 0xbc004af01e85,
+    0x18925860f5f8, 0x6f6edc64d3df, 0xddd1c5620dfe, 0xcda3bec7ef55,
+    0xef95888b1a0e, 0xc5ac3307f072, 0xc9a950fc8ba2, 0xe75922a1528a,
+    0x5d39d79b8069, 0x82021fbe3f2a, 0x8a5a0029a94a, 0xfacdff620456,
+    0xa9ea450bc039, 0xf58d4ae68146, 0x11a9607880f5, 0x161116c59cbf,
+    0x2b1b1d7e4621, 0xa3285bbe4945, 0x47b9ab851a75, 0x9868df5e2b97,
+    0x587500d818f1, 0x62808d8b7603, 0x5395f8eb3578, 0x0df29980bb4b,
+    0x508acf8164b3, 0xa12e936b555b, 0xd05dad2b6f39, 0xefb904fd5562,
+    0x02192847cfcb, 0x06431bbcd5cf, 0x46da3e9df228, 0x5abb219c54eb,
+    0x942a9f1e009e, 0xe9d8f71d18f8, 0x8369f8666fb0, 0x211474eb2d04,
+    0x02dd13fc04ca, 0x453cb3fc211c, 0x677984feb03d, 0x8e16d06f9e8b,
+    0xf9a28b603353, 0x14f380d62faa, 0xe2d28badc96f, 0x962e0ac1a3e7,
+    0xa3c8e93ecac9, 0x114d06141162, 0x47de03a85f1e, 0x7b0424497468,
+    0x2bcc40b15154, 0x72e281bd4fed, 0xa4cbbc963267, 0x8923f0d5a1a7,
+    0x8506a81cd4f9, 0x331eb400e725, 0xd57c3ea32673, 0x6912f257b701,
+    0x9ec43987fa23, 0x22234b2b79b6, 0x4441afe0097e, 0xb4738517d366,
+    0xe459d858faa9, 0xe90b4c8d5440, 0x1f0dc8129791, 0x27ce90eb5de5,
+    0x7513977e47e4, 0x26ede1c02e78, 0x7dc3fe1f6ad3, 0xcafc59f61b7c,
+    0xf62ecadfa5b4, 0xc6221fc4b12c, 0x51d2b8ba0712, 0x977559ec6231,
+    0xfbdbe517b80b, 0x43a8ea86b87f, 0x962cd11cf766, 0xa745d97d3761,
+    0xc23a2aaf130f, 0xd528d00897e3, 0x4ad1ef404bc3, 0x4e3dcc71974c,
+    0x912dfd87f1f6, 0xb5f3fc147705, 0x0e89101dbd0a, 0xa51c00609999,
+    0x1ac97835bb18, 0x677835bad682, 0x33415c9bd631, 0x44e27f667f0b,
+    0x661ea991c4c2, 0x06b22bb6e973, 0x3338d764f2f9, 0x63edf3b279e4,
+    0x931d82909f4c, 0x3175629071ee, 0x970cc272d68e, 0x9855182a29d8,
+    0x6d46986e17b5, 0xed7de15b070e, 0x1a2fed2d5cc7, 0xe9a39e53d4d9,
+    0xff44ee585fe7, 0xfd6fb7f218d9, 0x68a11834c6aa, 0x8256c5283d63,
+    0x051149188ca8, 0xd00ea03077b0, 0x216803b24d5a, 0x6573d98c9a3c,
+    0xc0223fedd0e2, 0xebd53ed20594, 0xeb80778ea518, 0xa18c07264a50,
+    0xabce79ef1012, 0x0b3fa0f85ac8, 0x2d7fbbcf36b2, 0x5fb4e82394a8,
+    0x8b8d301875c9, 0xa7de1cb8aa3b, 0xd98a4466883e, 0x48c34850daa5,
+    0xead57b22b3c3, 0xa59477f3ea7c, 0x1b537e42ba7d, 0x562b0721df6e,
+    0xe6d03e8a3326, 0x1b33c89c9489, 0x393d0ab32b54, 0x41ea620f073f,
+    0x641a3f3b4986, 0x851a4077a1da, 0x967d7bdd2b2c, 0x18ad613dd21b,
+    0x24d4c46e5f45, 0x28cc9f8975c2, 0xf4658288b412, 0x11ae64c556b0,
+    0x44d05156081d, 0x55dde9c7279e, 0x6636ccdc7931, 0x98c9fccf1a6e,
+    0xd443793a3433, 0xb46c3c9c12f7, 0x0a8024a92cfd, 0x251b3ff83e8d,
+    0x69fb21b86fca, 0xb59576585599, 0x76f6f761df1a, 0x8c186918d0fd,
+    0xa95c087beee8, 0xa101cfef3d57, 0xdc0c5b6468e3, 0x46e5c0916572,
+    0xd609137ab3f7, 0x4ff1a6ab8c31, 0x5b3360f178bf, 0x0ed4a46b32ec,
+    0x63eec263b09e, 0xeb21af69c0da, 0x64d1880d023c, 0xc4ef28554b2c,
+    0x4283a525be67, 0x903c546813c5, 0xcf33c02740de, 0x43c90199c6b0,
+    0xa2edfc989025, 0xc8a638c9f323, 0x958c04acf821, 0xa7f00b76d49f,
+    0x0eac4c780e42, 0xa0aa8c605a71, 0x9087f5b55c42, 0x0535eb7e5130,
+    0xc09c93d30cfa, 0xffc9c0dc85ea, 0xedea06ffac39, 0x22c48395c5c4,
+    0x25662ae72509, 0x6bb9c282f5c3, 0x73b5e8e6564e, 0x134bf806825b,
+    0x2287ecffbb1b, 0xaa5abe8a8c3e, 0xc346e54b2802, 0xf5ea8d7768a7,
+    0x195256ac6c8f, 0x75cc0ee2e46d, 0x28859a6aca11, 0x22d0b21fd415,
+    0x318cb2c977a7, 0xe33ef5466c90, 0x5402c979a52d, 0x6c3189a397c5,
+    0x8f2902888ad6, 0x366d5c1671c7, 0x4821f05e050a, 0xbbbdb84cf050,
+    0xc7f8526b621e, 0xc8e3b34d92f7, 0xb935c590133e, 0x4441e6d434d9,
+    0x90d088202c84, 0x22a5fa9479b6, 0x1a5940fe1c8c, 0x6b5082d34715,
+    0x15b786cb434f, 0xaa1e9d755be8, 0x039ab9c36527, 0xbb9734d1f9ce,
+    0xa0dee80201c1, 0x621b09f8e2d0, 0xedfcd10c1129, 0xfbfdef444b06,
+    0xf169395d643f, 0xfc9e74eb0f7d, 0x17c1e5338fb1, 0x8a360d4f60b8,
+    0xb9f54307ad64, 0xd9d422178908, 0xe58d09703383, 0x67054031c183,
+    0x757f9a83ed3a, 0xeb06456135ea, 0x8c03eac0cfab, 0xf9cd916cf034,
+    0x3938bbdd84af, 0x4832b9ee43cc, 0xec82d87ba8ab, 0x3a2e08c7a9a2,
+    0x4ddd5e8c246b, 0xb300ffbd21a9, 0xf26efee3f763, 0x9f6bc52026de,
+    0xa33187e14dd4, 0x8dfafc393b66, 0xdc21b23567e9, 0x8ee304bc11b7,
+    0xdd2edf3be0e7, 0xa39d27eecfc9, 0x2585af006ea0, 0x806d5b0f43ab,
+    0xb67553fd1174, 0xedb63931b3f5, 0x635d5f642feb, 0x2fd3e9c044e8,
+    0x482c11094bc9, 0xd0d70c886921, 0x563438082ac2, 0x9c9080ded6ba,
+    0xf6bc0faf2934, 0xd12a91ea9146, 0x36f335ecca5c, 0xcaeb962ffa81,
+    0x3ae6ce6c6f3d, 0x5f2ceeab5b21, 0x2a0528b691c0, 0x3fb038bf18e2,
+    0xb2a4f64c82a7, 0xee9774428881, 0x0dcbae8b9413, 0xd338f14b2a04,
+    0xb0a30067ccc8, 0x09d3a0586615, 0x07a25f5913aa, 0x1df6052136f1,
+    0x4dced96c09cd, 0x720740259b85, 0x6ca76faf07cd, 0xbc87655dadbd,
+    0xa35e7c030f4a, 0x16d9b29acb47, 0x84c8dce69e04, 0xd13777fb6df3,
+    0x256cd745621d, 0xa9d4f06a0490, 0xbfa3c59c0bc1, 0xcf4952afb204,
+    0x8bbab270b514, 0x26eb1a4af944, 0xc9973f1afd47, 0xcd630a64b642,
+    0x0b419014365f, 0x20aa84a73a1c, 0x6472a522db36, 0x26cec47b1c1c,
+    0xa7919633cadf, 0xb156aed436ed, 0xf635a6585116, 0x4e486df0ba7c,
+    0xaebab0ef5a26, 0x5474af52666b, 0xbb590e1a8dbc, 0x03ce6eba2490,
+    0x27a3c98af413, 0xab122e9cf635, 0x9675cf821471, 0x11e9838cd4a9,
+    0xe88c15f179fe, 0xf99ef13c1e32, 0x6e54e892dd0f, 0x683da40c362d,
+    0x0b18ad63d8f1, 0x2a096cdd3e80, 0x120a56e9f7fb, 0x2ee423a3a29e,
+    0x145e9edaab67, 0x70db15c56881, 0x8b0df8542f2c, 0x9f0a9eee480f,
+    0xc769ffd994ca, 0xf8b8747a314e, 0x2519276a7d16, 0x89419f2cbb62,
+    0xe2cac5530d7e, 0xd69575850ea7, 0x7e1ac5bd5c24, 0xf3a453dbf3d2,
+    0xf3ad1b18cb13, 0x2343d3ee1a8a, 0x521749a826d6, 0x4d162c7ce7b4,
+    0x5c7c5ccf4fce, 0xfdebec6822c9, 0xb12dcce97711, 0x3271a1badbd8,
+    0x8629e16b7c13, 0xc8205e6f366c, 0x17d4b96a8a64, 0x1afb0cc4fbfd,
+    0xa454cb195141, 0xcddf5ec61888, 0x44b92e27d16c, 0x6686d3a56c8f,
+    0x95fa09573000, 0xcf856f019b95, 0x6b32f67533b4, 0x8c3b33ce5bbc,
+    0x8664ab6315a1, 0xdf41e36a7110, 0x050b478284c5, 0x06e8365c823e,
+    0x5313cbbfb7b4, 0x4b22cc31641f, 0x4fa421110619, 0x63d2e0050e83,
+    0x2f6a9e392ffa, 0x54d74f821547, 0xdcccfbfa4f65, 0xfe64a59e98ec,
+    0x4eb6faa483a0, 0x5e815baacac8, 0x6fdaab086000, 0xad5e265ce7d7,
+    0x3022bae234f8, 0x8e01fa50e69d, 0xadccfa3cba92, 0xaefb9b5f8ba9,
+    0x0f35d37da5ca, 0x36de7005f0aa, 0x7c07848d5827, 0x84b675d25c75,
+    0xae4b1a142198, 0x4ee628a904ae, 0x88e253c18ca4, 0xfe9e34c9199e,
+    0x34ab36016655, 0x5c031352bc90, 0xbe09c21134c2, 0xcc3f423997d8,
+    0xac766ecde217, 0xbee2b1b48e49, 0x7a58e806d267, 0xca87de763ad7,
+    0x34cfecddebbc, 0x68cb282e0637, 0x6ed78f5c8270, 0x8e03186ab2c4,
+    0x42b2472304fd, 0x6829521afbaa, 0xb4346c28fdda, 0xe77e607ea0f2,
+    0x5e017e22cf2f, 0x7600e4a7177c, 0xb0ced20f4a1b, 0x908a78f368ce,
+    0x1a3e6d689b47, 0x1aeeca26fb96, 0x8a1ebdba6338, 0xa319131e64b2,
+    0xc799fcf21b5b, 0x06ecad9b467f, 0x2d4075b3fcc5, 0xe360d622af04,
+    0x12902a71cea7, 0x1818533e55d2, 0xb7f143831296, 0xbd4d970d4204,
+    0x39b5adc6101b, 0x804e5a1aaf3b, 0xa02fd351c659, 0x60c808b77aaa,
+    0x36b2b1ee014b, 0xb84b2975bccc, 0x2f6a481f49db, 0xf647bc572cbb,
+    0xf7f8bfecd674, 0x5608b85b5502, 0xba6b25784302, 0xfcafa2feacc4,
+    0x72834e2091c6, 0x2020a88e9377, 0x1ad18ae0a4ec, 0xd5bce8143aef,
+    0xe5509d941f2c, 0x73edec912703, 0xe61ff93774a5, 0x3990f09b08cb,
+    0x94c77166a850, 0xc5c64b9b6d08, 0xef54c2c79664, 0x94a12103c94f,
+    0x1517c6b1f6d1, 0xfe800c6f183b, 0x0626e06f10bd, 0x0b46d07b4d03,
+    0x688456ed0f99, 0x6b8fac5ad864, 0x8ba3035cd944, 0xd1f625da9e74,
+    0xd3f4b4d4ec0c, 0xd7e1e5afef61, 0xf40ee8999a02, 0x7a3bebeedc21,
+    0xa642f3de5241, 0x55a2553ba4a0, 0x3028dc206a7f, 0x87686c0d0a9b,
+    0x4e357e0d4a16, 0x2d158c9bc609, 0x8bbb4a6ef9e3, 0xb64b7eb27bc3,
+    0x10c3741c5543, 0x3ad5ad1579df, 0xfc559a6d02b9, 0x81d7be6c6f01,
+    0x0e758cbc23e8, 0x252611576d9d, 0x176c2ce13216, 0x35cbe6264dc3,
+    0xcfbab07ee1fd, 0x4616c69c31a6, 0x9baf3efb1c23, 0xeb39e2465a0a,
+    0xf1aaad40ccd2, 0x7eace6d3668c, 0xab0840e8319e, 0x1381e7213acc,
+    0xad4ab8bc1148, 0x8ce98d3b5905, 0x99fd761933e1, 0x573980d82ebf,
+    0xc52cdf346559, 0x74ce377a315b, 0xae837961bcc3, 0x6c4d1bf3aaf8,
+    0x7ed3136ed95d, 0x9adacd47d78f, 0x4039fbdef153, 0x692a286f822b,
+    0x9b9a345aa6e9, 0x80268ffb399d, 0xa319ead1c4d4, 0x1a7b5f7fdb0d,
+    0xae806a7f4488, 0x007c36ab1a90, 0x8510b0321c4d, 0x9988c67a64b9,
+    0x5796ce181005, 0xc08b2cdb4929, 0x508809130681, 0x828da132b467,
+    0xd0ba84447cb1, 0xdfced094d34d, 0xa3e2369a55b6, 0xf52778f22fa8,
+    0x2f2996914da0, 0x65515b273d60, 0xb5ff0b192222, 0xc611b4aa8876,
+    0x60690854695d, 0x6a2d36b8be57, 0x9c91bcb2c698, 0x934ac3e9c190,
+    0xbe7da9f15752, 0xedea02e06dad, 0x801b2f5cc474, 0x9c7a852f6461,
+    0x33f3e40beede, 0x6d9e41246159, 0xd5c2ec9f23c1, 0x9482b05551fa,
+    0xc274fa4f350b, 0x2b1e1ebaf246, 0x3d751bac85e7, 0x4795b3c5f5c5,
+    0xb91554a6af2b, 0x53e0a190fd39, 0xc50b2a9d545b, 0x33afcff40258,
+    0x61a428f51956, 0xe0c033b11442, 0x567a05b16d4f, 0x4186f4d900e4,
+    0x5fa41d8a8da2, 0x030fbf6f5d02, 0x1b4f3261f4c7, 0x9b4e706e5e97,
+    0x65dbb1c53e8d, 0xdba7abea5b68, 0xe22c38f2ba61, 0x6b519556e66c,
+    0x79110d37a86e, 0x8fb746aeee82, 0x5aefa1eaaa14, 0x7eca90260012,
+    0xc8a5ed1bdc37, 0x3ee6b4bb814a, 0x6fa7cc18303c, 0x71abd87b084c,
+    0x86cde3dd0f7b, 0x9ba31387841a, 0x71f29d1efb18, 0xd2bc06f930f3,
+    0xdd0d1d8367f5, 0x2f078a551b5f, 0x37b82b854413, 0x51685b103054,
+    0xa16fd4daaea6, 0xabc7187a816f, 0xc67f40bd6fba, 0x0ff12a25e81e,
+    0x4bdfc49c8be3, 0x67e2762b0b27, 0x08420b7e1229, 0xd3b4f5bf92bb,
+    0x39588b402527, 0x7aa3d85d07e1, 0xa40a0d3539cd, 0x305f6e6cc5c6,
+    0x96e2ba5162cc, 0x5938ac9407eb, 0xa96ac0d3c51e, 0xbd5b6c5eb5fa,
+    0xe0f3ee9386cb, 0xa0c0039f24ad, 0xfc3a745875bf, 0x69ba649446bf,
+    0xd56fc132f595, 0x319c2893cf59, 0xa5bb61beb417, 0xb725d902fa06,
+    0x14848a770131, 0xb1968a59b241, 0x381b29a8961b, 0x73c2daee3d52,
+    0x97ac56e9088d, 0xc548d961780b, 0xecd04ae77e56, 0x1400ab93ea73,
+    0x7bad3fbfd7da, 0xd4ae860ac6dc, 0x469f9c06adf2, 0x71998cf1a717,
+    0xade8749cc7d5, 0xcd338ec4145b, 0xe1e7a96e4068, 0xa34e574f5299,
+    0xb808890d1e6a, 0xb92877e37082, 0x5167b40279a4, 0x2eb9be75be9b,
+    0x711c92482bb3, 0x8a0d86c440a0, 0x9926db5f162a, 0xcc5b89bfa0b1,
+    0x0d1645c64513, 0xd2b37bd69a9b, 0x5320c09cdb90, 0xa8ca4ae07ac1,
+    0xed64dd58d7d3, 0x2e0a536a63a4, 0x9fae8daeb82a, 0xdb985a69e2c6,
+    0x54480153e648, 0xf2c8d354c1a9, 0x621f1ec1f690, 0x3cc745c70c8e,
+    0xd96e974f4101, 0x025f38fddd63, 0x53f63acabf11, 0xabc70b7aaed4,
+    0x57fafb3114a9, 0x63259b09ba7e, 0xaf4043154502, 0x05d618772c20,
+    0x88b97fdd7665, 0x85aca688813a, 0xdf95d0c93ccb, 0x6e3cb3c648b0,
+    0xc240faf4606a, 0x8e82e8d14669, 0xe576c1d170ce, 0x0f775dc5d929,
+    0x44cbc622f5dd, 0x67f4e243cceb, 0x7ba8bca9ad59, 0x334c9b808d2e,
+    0x62a88ac111e0, 0x3824b81fb5d2, 0xc6bcf75b5077, 0xd199d1404ca8,
+    0x89503757ffd2, 0x9816f843b28f, 0xab5d1509cf5c, 0x0d5f309bd42a,
+    0x243dc584a18f, 0xd8f1d7fa68f4, 0xceec53cf9de7, 0x20696678f79d,
+    0x1d7223aa1ccb, 0x46b275c68cfc, 0xdb241223aefb, 0xd90a17959b4f,
+    0x589f3267900f, 0xb6283536c297, 0xc034719730f8, 0x4bc120d5fd0d,
+    0x8f119f20d4b0, 0xf808f72758d5, 0x870985253a62, 0x48ba8a989fac,
+    0xbd01566a6a8b, 0xf307effe15b6, 0x7c41f8ac4506, 0xba994557ab73,
+    0x2252a8ccdbf2, 0xe9dbe696abbd, 0x0bcf84d2049e, 0x14aa3cce52ea,
+    0x174b6f01a6a5, 0x5a1a7515bcba, 0xba142ba77bd9, 0xdb697f473c36,
+    0x7a6fb475297e, 0xadaeadf9b7a6, 0xecbf93be612d, 0x899554b028d7,
+    0x6868b16e4bf9, 0xa5477a8299c0, 0xa6d001354644, 0xbfd1e3090623,
+    0x6d90dd9757f3, 0xf5730290aba5, 0x26f80cb5af11, 0x98367c5cfc26,
+    0x2f89cc79cce2, 0x34f522e17d17, 0xcf140c8ec8ee, 0xcf834e15b8ef,
+    0x62471aaf81fb, 0x9888b866fdb0, 0x23c6b776090d, 0xd266c7c28815,
+    0x36b429796349, 0x25bdfc068c49, 0x9e62a81f7adb, 0x9860a98dec8a,
+    0x17b7a26bbbfd, 0x5c0cab0d3f1f, 0x6af9d9d4bb24, 0x83ba2b52ff2b,
+    0xe4b7d290547d, 0x497d359408af, 0x73484acd2af8, 0xe2ce2a6f0fef,
+    0x1bcaaeee6192, 0x21a9fe967eee, 0x1a2be97f77aa, 0x4ee2b6ee0d29,
+    0x5288e89617ed, 0x3227f7a4656f, 0xaec5f434891f, 0xd4b16d8af87d,
+    0xeb4252821841, 0x168c68f8f0bf, 0xc0d42c68d28f, 0xfdfcb3645e23,
+    0x45197216fa4b, 0x2d2606a1da05, 0x5321d3a4df09, 0x5771102a917c,
+    0x6f244aa45888, 0xa4d757c1bc13, 0xdff04c69531a, 0x8f63b8eea52e,
+    0x657878f15167, 0x7aaf8fb96b62, 0x08f48b98d1eb, 0x9595a3bcd39b,
+    0x019b4a746b8c, 0x03ac262086b4, 0xd31124fa7fc7, 0x8f0565222ee8,
+    0xa14ee31d6e22, 0xef413729716c, 0xba21bbdc857e, 0x20e6d2a5a421,
+    0x431cc6e5862c, 0x6c9b7585216f, 0xcb448bb7afd1, 0x3b01516efd78,
+    0x4cfe68d0b5ac, 0xf45b522801f7, 0xd8b6f4c8c7c2, 0x579a53823995,
+    0x9e8a774370ae, 0xb3568094fe85, 0xad54d29471fc, 0xc71012f26f30,
+    0x00aa1c5e4c05, 0x643e94a8f56e, 0xc0077b030173, 0xc7a821ed5e2e,
+    0x01564c8336bb, 0xb3e96a2f5317, 0x6db15ba032b8, 0x3403819b5bf3,
+    0x42847e212efb, 0x162da8adc98e, 0x50a18bf916fe, 0xa81a77955182,
+    0x0c256d45a789, 0xc7b039dff2e9, 0x54fd0593e581, 0x36d7ccc98115,
+    0x362e9d5ecfa4, 0x529a4d5ca71f, 0x77aade0a850c, 0x07a07e6385c7,
+    0xfa22884d31de, 0xa24bdb18a81c, 0xf5c239e2cab7, 0xf4ee43fee25f,
+    0x35ad1e13ee9b, 0x00914bb75efd, 0xd0b4bff12212, 0x6a75a6002aa2,
+    0x970945848102, 0xb063fe1efb78, 0xe7ffb31f907a, 0x55a39b9cf90a,
+    0xdcdae14076e5, 0xec89cf3c09b5, 0x7765e72d6fb6, 0x241c655aa633,
+    0x27cc3279a349, 0x92ca559744ba, 0x7effcece09a2, 0xc1297a6bc29c,
+    0xd7b147950957, 0xe1deaef7bc4d, 0x6a5a2c442598, 0x74e39e3fe3eb,
+    0x675fcba4f603, 0x6c737787f6be, 0x0c7f5460be1a, 0xaacb4f5364b8,
+    0xe8925b798e95, 0x823f44eba52f, 0xa68ba84228dd, 0x7fc3fceb0173,
+    0xa4e95e6fec67, 0xd3f787bcc775, 0x7be7aadc9b51, 0x89d2d372ab4e,
+    0x343babe2d5b0, 0x393652de6587, 0x531e52bad18c, 0xa349c4c6e76e,
+    0x6654a6f46040, 0x94e57618e19a, 0x40947f372f56, 0x876cc2dc1264,
+    0xac951da44859, 0x18ce769ef834, 0xf84f53ee4c22, 0xf86f94b479a3,
+    0x1de41b8730d9, 0xcba1f14a65d0, 0xd142594f508d, 0xdb836cbdf3b1,
+    0x1e34f0c02181, 0x8a0bb2f01ab1, 0xd7185e117d5f, 0xfb6e224fed9a,
+    0x6d9b26abbc4e, 0x34146a2fc020, 0x40e4d83df4f9, 0x7da15184e152,
+    0xdd4e66ee13a6, 0xdb4c450565c1, 0xbf349e12cfb4, 0xdd13df5f3cea,
+    0x77c727a02d2b, 0x052c0dff7993, 0x71060401c389, 0x3090fdd5f11d,
+    0xee196f2e7301, 0x92468e416523, 0xc61d462fe081, 0xb186d99b94bf,
+    0xf03b7a382d24, 0x93b4590d5ce2, 0x7b2602d2ece4, 0x47c37028d6b3,
+    0x7fc47aa8679c, 0x175e04492397, 0x17881b15492f, 0x3fecf9692062,
+    0x4326be407c64, 0x60b09727858e, 0x7c8fd41d868b, 0xc339e2e7dc27,
+    0x52fc6aa8da44, 0x6c96df20a26a, 0x344e1735f9e2, 0x1214ca96bd4f,
+    0x2db90368edc2, 0x54dc1122fd7e, 0x4d9d7d5a3e25, 0x89ad084420ba,
+    0xaf12b0c660eb, 0x0614434be57c, 0x2857f11121b3, 0xcc2a7ae72719,
+    0x1606126c0903, 0xf37d65effa69, 0x67e0df99927c, 0x13f35b33a038,
+    0x4e141fafc34e, 0xc6adac6a932e, 0xcc6bd1106bf5, 0x176138e111ef,
+    0x7f177e85edab, 0x751b71450b2e, 0x6ff2fc01ebb0, 0xa04d831285df,
+    0xa4abc9eca75d, 0xd14680c2b098, 0xc0ff66b6134c, 0x933a42cd9cd7,
+    0xdf14b9ddce21, 0x9795caf37919, 0x18a4c8b6a91d, 0xcb5e19af88fc,
+    0xf407c3a21b11, 0x0da19e6ecdeb, 0x07e64e7f3663, 0x17b2f91bb73d,
+    0x89e0ad249740, 0xd5dce8e2226b, 0xdc845f62f81e, 0x449c010571a9,
+    0x791b64b5852f, 0x5b0eca3f93dc, 0x5d8bd82d5801, 0x19f9a4fffac4,
+    0x99a36c1bf2c9, 0x3ecb30f48a94, 0x6afed3d565c3, 0xe47cceadbd63,
+    0xfa2a284c7e37, 0x6396d8ed9221, 0x8d071e5ac1e9, 0xdcf8016112cb,
+    0x76c9f954d00f, 0xe86e0f0c1ad3, 0x30e41f58c2f6, 0x4bd30924102f,
+    0xa84e20b4aaab, 0x753b3e786849, 0x191b4744c216, 0xbd148f87928c,
+    0x032487c6b381, 0xe83c47a5fbdd, 0x23a0438cf74c, 0x7637ec312264,
+    0xb2671e361d2e, 0xccb055831d40, 0x2686e2810979, 0x4db3d4cc1f0b,
+    0xa5a2278df713, 0xfbda49e29cb2, 0x4ecb126795e5, 0x3875390b34ef,
+    0x4c5b9972222a, 0x99c0903a37e2, 0xd8c5f00dc4ec, 0x030639546d38,
+    0x647b6cc08ffe, 0xc2fbb57f1f63, 0x1de2d66dece1, 0xbf92e7931ade,
+    0x50b92214249a, 0x66103f136651, 0x96787fa95aac, 0xf5414b820f02,
+    0x0850e14ed2a6, 0x25e0722d6c0d, 0xbf8ebaf9a545, 0xb5dd64672568,
+    0x6c39c937bb48, 0xc550ca95ce65, 0x11a467bdf586, 0x00d900c4556d,
+    0xc6775b9fc7ce, 0x70f087bc87d6, 0xc7390c48d0da, 0xe8fe3b1a9684,
+    0x92d782b295a5, 0x5c93b166dc41, 0xadb3fb3f0b8c, 0xc20f0493773d,
+    0x7d5e1d5ce0c4, 0x50261c29521c, 0xf0ebf1376c19, 0xfde0838b3088,
+    0x1bb8d212f4a5, 0x5fb6042bd047, 0x0c783b623ac2, 0x38e8b6df14a3,
+    0xa06abf20d0e8, 0x6e2bae3b6d1c, 0x7ba7c14e851e, 0xd249069f8cb6,
+    0x8189708ed82f, 0x28f3c2da7cde, 0x651b8cfcc305, 0x68ae4d27e360,
+    0x76bcafc76bdf, 0x8c1b93c571c0, 0xab35abd2ddd9, 0x357797371330,
+    0x90fa097e2e31, 0x6a0b060f266b, 0x6daa20657e54, 0xbbf20163ef6c,
+    0xd50a9e88e67d, 0x2c5ec4bd8ddb, 0x79114fc00f57, 0x7f49259de582,
+    0x0ab8183bc38d, 0x3c9a8c53f4f4, 0xb88173f01501, 0xc1520555696e,
+    0x25f16f9b5fe2, 0x4f9bcef04920, 0xb3ecc39584a1, 0xcc48593fe20d,
+    0x38b9828c11ac, 0x0db34d77dcfc, 0x45669203c441, 0x79ff94465df7,
+    0xb293ca0368b2, 0xe9b917a04d19, 0xbef06ad5286a, 0xc3923b09fdaa,
+    0xa1a2965002e1, 0xc4dd40ea8871, 0xab9ffbd6e881, 0x75efb4e4b876,
+    0x848338ab5ed3, 0x913849b0db57, 0x3ed803b712bd, 0x96bfb4454374,
+    0xef13d1a422ed, 0x85c4e51aeb51, 0x9a1234e9fcd6, 0x2feed5e7e314,
+    0x6e15f4b38425, 0xd2c562150e77, 0xde1042b3e1cd, 0x2d2f9df98dcb,
+    0x755a6c77cbc1, 0xe44694aa8b98, 0x29e1a96d2852, 0x68199f8e7852,
+    0x443457a410f4, 0xc4d5c3cb755d, 0x336fcd6d1df1, 0x550f038125dd,
+    0x92ebb4b877d2, 0x4452eff60853, 0x1712b2a5a9f7, 0x42956da81d4a,
+    0x75748f163a03, 0x99f4bab1d19e, 0x6e6ea75003c3, 0xd46a88774939,
+    0xf0abe719f557, 0xa774439d2e6f, 0x2add2b46a619, 0x30dae73cc2ce,
+    0xba3ad2a6b3d9, 0xd47bcdd6e437, 0x1d20c2ab2b1b, 0x4062cdb1770a,
+    0x45a8a079750b, 0x145465563a8b, 0x7036afb3c6fb, 0xd0c10d5dc075,
+    0x9763f28fde55, 0xced97443afd4, 0x21fa80aa6a66, 0x6131be48e132,
+    0x7b83a6d0bc82, 0x8461f1bb6b1b, 0x4c78523a22b3, 0x5c8f8ef7fc6b,
+    0xf3dfe5d18226, 0x38b875b0fac3, 0x73eace5c1235, 0x7c606b686a5f,
+    0x32d5d5207123, 0x8ae7b27cc375, 0xdcf2e819180f, 0xdda81292f187,
+    0x84b8f46cc653, 0xc30e82a1fa9b, 0x61bb90782b84, 0xef29f5384ec0,
+    0x1e0bbe22ca84, 0x1536ea22047b, 0x669f2bf4512a, 0x8bb2f0747fdd,
+    0xe5a615483d47, 0x4e0dc6749a28, 0xf462a111e866, 0x9b9856de5e17,
+    0x73a03d6325b2, 0xbd172f161d04, 0x3301ab8b353b, 0xa90b8d15719e,
+    0x4be28e60a88b, 0x669b2bc1a260, 0x74f928cd02ab, 0x3af68784cb6a,
+    0x6653845886c4, 0xb2ccf5b161e1, 0xbc3c46d05a5c, 0xd19e2411ab3e,
+    0x3589641bdb51, 0xfbc1174a634b, 0x854317f2afff, 0x95a069a76b34,
+    0x703fa4e2a02f, 0x7defa4c7d5e0, 0xc3a2be33d067, 0x2f743d8a44e4,
+    0x585600e86a41, 0xe429af5a2ee7, 0x2c0caa6b593b, 0x80a0d3029dfe,
+    0xa3ba2696e6f3, 0x43111384da9c, 0x5347a6cccac1, 0x6fbf0148c87b,
+    0xcebbb9f39a80, 0xd575429737fe, 0x778bce067ebb, 0xb8fd13e11f33,
+    0xf7e650fce696, 0x34ccd284eac8, 0x845174be84ee, 0x61930e6f18c1,
+    0xbf20def9c472, 0x04c03657d2c8, 0x0e0b63313bad, 0xcebed1129e91,
+    0xf6ab5d889afe, 0xf773d4f2551d, 0x503e6fb6e134, 0x66f487bcb164,
+    0xc3ea3ac16058, 0x73d3507243f9, 0x68e73920c13c, 0x7af194e9c812,
+    0x3619e0bb58ca, 0x954a8638487e, 0x0de178cbdce4, 0x5b71709c2f48,
+    0x45d3f58bf890, 0xfc8b142684ee, 0x5af56742ea73, 0xc3424686968c,
+    0x2f4394a0c0de, 0x35e04adf8766, 0x7b663e3a1551, 0x98f8c85bf9b9,
+    0xad0b14073ab4, 0x162631373ee8, 0xc13fa0f4cf5e, 0xea839c019bdf,
+    0x44b04f9d0b32, 0x5f15fa32d2b3, 0x85828ca3f418, 0x8f4f31b41e8b,
+    0xb151e8cef436, 0xc8fa9d95b1dc, 0x0408d1fb39b8, 0x97d21327c557,
+    0x601eaa0ed450, 0x8896bf509027, 0xcde900680628, 0xde6592a4dbc7,
+    0xe058b1b008e5, 0x65a1e092d890, 0xd5560b1146fc, 0x90cf3aa6d61b,
+    0x9cbb5f248cf6, 0x2e6d5d005be1, 0x95ad3b418702, 0xaaa2abb22922,
+    0xb65be39bd565, 0x0d20d8146109, 0x93ba743e49f0, 0xe88a16176ac7,
+    0xf657820c8cba, 0x1002e3dbffd2, 0x6e159afec39c, 0x9f32cb7f8baa,
+    0x30645162ac1e, 0x8bf6c6c55afd, 0xcf26300fea0e, 0xf3812125e2f9,
+    0x53d498ba73c3, 0x7042a85080f3, 0x8faddeb0f138, 0xbdf3980b006b,
+    0xf4d79e6c7c7e, 0x3cbf58ae123c, 0x5c244bf6eab7, 0x7baf37fd80f0,
+    0xa523ec0a1a5a, 0xf577daf92d71, 0x2e15370f1ef4, 0x1d2896882c87,
+    0x5311ae09ef6d, 0xdfafb6e8aa19, 0x8adac3aaf6d0, 0x94450c7f705d,
+    0x67aec448d0c4, 0x848ce99fe906, 0x38b8568b3fff, 0x52af102b1947,
+    0xf9973a92eb19, 0x35ca3a154bf5, 0x0b8589a6284b, 0xd22fc0b8fecb,
+    0x9d601a66f06e, 0xfd50d6a3c0cb, 0xe9c1036d5475, 0xea87bc60dc6f,
+    0x8683705b1bfd, 0xc1db62a7e1a6, 0x2b200b8403e5, 0xad98e91cdb4f,
+    0x52cc66fea718, 0x677dd8f689ec, 0x6c489b2c67eb, 0x9d66915c3d4b,
+    0xca37440b9822, 0xe9a44ee4368f, 0xf555d5d11aab, 0xf0677a75b4a3,
+    0x288bf98dc3d3, 0x39f58695f2ae, 0xd77f4d3676f7, 0x466ef9cc895c,
+    0x9abdbd19fdf1, 0x79233034656c, 0x959c5f28914e, 0x866e8a47b085,
+    0x33502e0a2d70, 0x970fbd5f0f88, 0x262a3a80790d, 0x80a38f44752b,
+    0x9185ec1e523b, 0x9978d626cffb, 0x0f7ff8fcc722, 0x48a5c00e234c,
+    0x682242e24e0a, 0x9b05bcedd862, 0x8f47e34caafc, 0x7e261b268204,
+    0xa028d24157af, 0x1827a74eef58, 0xf8b8b9731504, 0x9ef27196b0b2,
+    0x44a8175c4f58, 0xbedb3cae8070, 0x18e73a65b6b8, 0x5319ec7797d7,
+    0x2169f74c53c2, 0x9d6c8c822dec, 0x7036d4e4d8f6, 0x263b129bc58e,
+    0x460327cf5c41, 0x6df6cb970b89, 0xd56094a0643c, 0xa9d578f41773,
+    0x53ddc9a0bf17, 0x75f3428b6ddc, 0x6469663203c1, 0xd1531beb13eb,
+    0xdb0722a6cb45, 0x9cce6e4d5c37, 0x1f4aba478a2c, 0x51f0717afebe,
+    0x686e689e4d62, 0x8627c72ef8dd, 0xad36e6d72462, 0xccb83766c849,
+    0x883300e57fcb, 0xf3e67a80f2ba, 0x7c719bcce05c, 0xa1d093906b7d,
+    0xf250d456d01f, 0x030b79fcbd4c, 0x798620f51610, 0xd041a5faa768,
+    0x4fae43e99ef3, 0x8e34afaba9e6, 0xf9ac5f743ef9, 0x1695450d6666,
+    0x0ec4c0d44617, 0x37eea83080de, 0xcac8a4fb798f, 0xfd76d2dac39a,
+    0x7f1bf51eb871, 0xd0962486c329, 0x01c89afca3c8, 0x83f1de29a366,
+    0xb7cfd1f6db61, 0x2afcf980da36, 0x93cf4305b7b1, 0x5ba228b4c946,
+    0x8f0dd32cb880, 0xb1f649342aca, 0x1e86c90a70d6, 0x5c4d849f98eb,
+    0xc655c128001d, 0x4bdc0335d43f, 0x766912321318, 0x795143985f64,
+    0x5a54e117c1da, 0xb7837aadffac, 0xe7eda5be1e32, 0x06752c3925c5,
+    0x2b83addfcfc5, 0x7b15687cc087, 0xeb7f329313f5, 0x2573e73dd989,
+    0xf3a07d7a172d, 0x0b1b37f54d26, 0xe66d19851c22, 0xc99319b359fe,
+    0x3936413af03e, 0x594c7b7e401c, 0x724a1ab68e5f, 0xa7208f403c13,
+    0xa2c87cf2a4a0, 0x48d266528a79, 0x603591426e2f, 0x91f355f50873,
+    0xd41c84fdc79f, 0xaa2c7744faaf, 0x9788ef2cd646, 0x555b938aa181,
+    0xd66b40fae2d4, 0x3183ca968e8a, 0xdb252bdb11e4, 0x9f97ba49f46d,
+    0xd5b4840ecf3d, 0x2ea601cd3026, 0xb6ca92cd0905, 0x5a7fd5202beb,
+    0x69c824449c39, 0xc704c07a47bb, 0x49b4beb78d9a, 0x378b22666c5a,
+    0xa20beb5bb417, 0x964ed7699403, 0x0f189204293f, 0xe027511e212a,
+    0x996a5bf6e8b2, 0x1047d448fde6, 0xd537e16d43e5, 0x6a0dff0f46ba,
+    0x88532f85c523, 0xfac966a8ca17, 0x67a05a920127, 0x67c618c0b920,
+    0x49b3c6533790, 0x9c8990eb87f5, 0xe289860f150c, 0x9250707fa101,
+    0x8baf8da764e0, 0x3cc88dd34033, 0x3d6c906668f4, 0x71f9ad9bd6a6,
+    0x20910cad7a70, 0x2baba96a8c67, 0x305fc6512f37, 0x33e18a9996e1,
+    0x65bd467c24f4, 0x70ab0db8cc17, 0xc037fa9a43b0, 0x6a5d6e3cc412,
+    0xe16b7b019c6d, 0xb24d7a9d2587, 0x04595fe68b70, 0x3894e233fb28,
+    0x4e70e5cb54f8, 0xd19d8e83e33c, 0xf1c20d6c94d3, 0x352d65170b82,
+    0xe12023940506, 0x4dc80e689393, 0x38a35a54432b, 0x5a05b7cc4c65,
+    0x850a2e6a4ef0, 0x8ab66cf157f2, 0xc19de1b42a90, 0xd2b16cabe013,
+    0xf04b48ae7d1b, 0x33f74672588e, 0xee04280efcd3, 0xf91b1bdbb27a,
+    0x16f8ad3cfb4a, 0xa0c84f4be7e0, 0xbd794e919386, 0xdda57e770d44,
+    0x47210d689833, 0x2ad2f57ea500, 0x54236f43b2d1, 0xc72f3fc7750b,
+    0xa6dd4a9f23d9, 0x9c61490eac2e, 0x158732cf5689, 0x5e060a58d5ed,
+    0x5feccf38cb7d, 0xf5c4f30c3a5a, 0xa3a4f40fc6fd, 0xd47f1edef492,
+    0xf6f82ed26d19, 0xfdb8aa3ab3b0, 0x8a25fa19a173, 0x37387b68f509,
+    0x8edc3c097433, 0x9c060bfe6b30, 0xba37a66af56e, 0x8986f8bcd825,
+    0xa7c1de0f52ed, 0x4051c36354b1, 0x17604d7a9d3e, 0x6af7d747046e,
+    0x73985f599d41, 0x86a8a4d37234, 0x2ae31e4faefb, 0xc4b84a15bd05,
+    0xc60866cbd479, 0xdc3e65f505b9, 0x5cffd85391de, 0x6bbde804695d,
+    0xc6686ae63dee, 0x1a9d5512268e, 0x92f69acfa131, 0x11af330fdc1d,
+    0xf45071330ba2, 0x9755a758d001, 0x77e051d85421, 0x3dcfa679c0ec,
+    0xfe5580a299ee, 0xd9cad6cae731, 0xfe0fc3b3a87d, 0xc63c54638fba,
+    0x11bfc9253495, 0x453399b7f780, 0xba0cf71c26e5, 0x1aa6e2c3a8b3,
+    0x1fcfe326cfbe, 0x6cdc0a9f1923, 0xe78363c0bd27, 0x714043ffd0a3,
+    0xcd2612c0afa1, 0x39ee9d777b36, 0x6b981c731000, 0x9fcaed85a47a,
+    0x6324f500e98e, 0x86ff92ab3dc4, 0xd1ff459fe75f, 0x56d1f71d0a6c,
+    0x88b41aa43a0b, 0xb4667fdd5971, 0xc82a5d5ecdbd, 0x02f985941938,
+    0x5dd44c57af28, 0x9ad565395e5d, 0xaa394bf58cc5, 0x3b824b620379,
+    0x0fec34e4a1c0, 0xa82001148a68, 0x3223b4829e6f, 0x2094cfdeb566,
+    0x392ba43be921, 0x634b96ba67e9, 0x6118bbd1ed1b, 0x6829e06f2c54,
+    0x027a32c95663, 0xd58153c8001f, 0xedcef55dfd9a, 0xe5d24b5183c6,
+    0x0a37878b8052, 0xa38a50deb5ba, 0x97d896d9ba1d, 0xb50d1d5d5b3e,
+    0x21551a197c59, 0xcda8013de914, 0xff8f431193a4, 0xbd28dc50c63f,
+    0x9605c7829708, 0x2b7ee779af87, 0xa23d43d0b413, 0x9ff313ebecc7,
+    0xeb6aa3a0659e, 0xdc9dcba1131d, 0x62569b26e250, 0x3bee18391b06,
+    0x81e6f60af542, 0xc501035e6fbf, 0x2f32a705eb3a, 0x41cd6f5826a6,
+    0xff070bf3de10, 0xc87641da769e, 0xcaf7e2f4ae27, 0x7044f0ddf261,
+    0x5791c6b0e17e, 0xc8cacef1a01e, 0x9d7e25ca7a6a, 0xa97754207a22,
+    0x8f63ab05ae66, 0xbdf6d9c26575, 0x0eba69547814, 0xd1a2c12589b3,
+    0xfca2f5281aa2, 0x362c61cd4e4d, 0x53237609db70, 0x8314c41c17a3,
+    0x39c9e9b16c17, 0xa20bf2b10d02, 0xf85e12b44858, 0xb55ccfb938fa,
+    0xc5f041d44f8d, 0xe4d85012d9de, 0x4b4cf83605a4, 0x6cf0b2eb7335,
+    0xeb5bbfc70a42, 0xc65ca790ded4, 0x5a211b01f14a, 0x5e87c285ec3e,
+    0x88cb7a2deb15, 0xe101f0edd887, 0xf0c5ea4f7e23, 0x5e2a8c0da48d,
+    0x029c60bd558e, 0x78b44c5dede5, 0x766e50898a76, 0x924049183cd1,
+    0x61b93d183996, 0xa1b0da90a48f, 0xaf7745360faa, 0x662ffdb9c46b,
+    0xf6ab58e4f850, 0x4873ad5cbc33, 0x5d2fee21bbf1, 0x6c85311c7919,
+    0x801b476a7d39, 0x96d3f99788e3, 0xe819eb9c7424, 0xf48628f6c9ef,
+    0x169749440b15, 0x3e38b164879c, 0xc22a129477ac, 0xd546c83b8030,
+    0x44aae3300b46, 0xe8ed82c71bbe, 0x41d8aee2d71e, 0xfcdc6150ccb6,
+    0x0f5c57ba575a, 0x282509e274d7, 0x0d8cc349a486, 0x99def0aedfb9,
+    0x41089ce11fa4, 0x8ab87525a5d4, 0xdd41d9246f33, 0xc6a58bb51d39,
+    0x1f887bb408e5, 0xa01233c31149, 0xbcb629307412, 0xbe790bd0ce15,
+    0x0f7431255ab3, 0xf9ead73f1fb3, 0x02dc93a6b8b5, 0x9ad286fc8f94,
+    0x0d155c6d7e66, 0x60e5f1587e36, 0x0a04910e1967, 0x2a26dc628d4f,
+    0x5ad172fd0ad4, 0x300557d08f9a, 0x4ae721c197f3, 0x0a30ea5c5936,
+    0x87e8dbc54ab8, 0x3739835f2fd4, 0x5f36c29dd16e, 0x191743d81669,
+    0xcc980bfc9d50, 0x4b836c43d912, 0x2b1b557565fa, 0x0de17d8d3267,
+    0xe77083f395a4, 0x2afddada6bf9, 0x7648950e79fc, 0x1d46056b645f,
+    0xc0bcb0ac566e, 0x871545c30f79, 0x19c07b7f53b0, 0x54f6cdabc93a,
+    0xbfd5d4ce5a03, 0xafb403d26926, 0x8f3ce2af8703, 0xc7463f4de3a3,
+    0xa006f1c5f2c2, 0x6b00da7c873f, 0x99fb1d2e6c5a, 0xe336d02e8d5d,
+    0xf6e1b7539e84, 0x39d5e1eb5746, 0x86db672ff95a, 0xa818a79b4e2b,
+    0x0d39ff3611b7, 0x448fefd0c704, 0x92b83fb61bea, 0xb1728e382453,
+    0xa463c3a6c468, 0x16dbc0b6e9c5, 0x74e1a8906a77, 0x3887426b1b80,
+    0x29afaa29b9d4, 0xcf120c93245e, 0x8925ef7f7f7b, 0xac586c884316,
+    0x078b09d48086, 0xc383680a9781, 0xc771e23dc171, 0x5f3055d31a54,
+    0xd15224cece53, 0x86f2e17eed2c, 0x2026df6813b4, 0x447959d23726,
+    0xf14616bbab07, 0xbc67cca82b6a, 0x90967777f362, 0xa1ac6207de9a,
+    0xa1f7de654e8c, 0xa46c75a73d38, 0x6333c5cd29d6, 0x41dd28d542de,
+    0x58ebeb9dd59f, 0xd55f5c43c82d, 0x2a589fcb0312, 0x67fa876d8a42,
+    0xf9af43fd9e69, 0x033241288712, 0x311b05ad73d4, 0x279525413c2d,
+    0x2a5ff922a9f6, 0x8083349e468b, 0x9d054f5c5370, 0xb1c3cb2c7f21,
+    0xc8b6a5cc5200, 0x8bb94bdcfb1e, 0x66c78e101a55, 0x5ae72a1285bc,
+    0x513bc6069be1, 0xa17650f22e8d, 0x400ca3772844, 0x83986074d636,
+    0xe77a9eb32943, 0xe449f1a9a64a, 0xef9ac4cf0fa2, 0x96276132cfe5,
+    0x2ccb3ecd5766, 0x63a3d53fb2ff, 0x3b470e7ba1be, 0xcbbed6b87556,
+    0xd9d993833563, 0xf04d04f37aa1, 0x4af2d01bd20c, 0x222a78381b5e,
+    0xfa8fab3726ad, 0xc218c473ecb6, 0x00bded14f8ca, 0x4cf6c6df7ce2,
+    0x74961881d738, 0xce85f38147d9, 0x90577449e069, 0xb38cb006e940,
+    0xd3ee66a7a910, 0x038cabecb6ec, 0x5e3b9d6df51f, 0x813bc78dc36e,
+    0x05cafe3b4078, 0x396ed820befd, 0x6ac8e13d85bd, 0x720ad4dc8de2,
+    0x86918ee6478e, 0x6271cdf81733, 0xb841f21b77b2, 0xbbf025514c65,
+    0x53f9659703eb, 0xbf2c2533d45a, 0xe6e7d41cfa1c, 0x28f446c2de55,
+    0x4809f030e0cc, 0x8bfd86d2ca1c, 0x92a9cb0f18f8, 0x71d1c30ca490,
+    0x3ac430ff0a72, 0x5329d73e65ba, 0x0dd85bd5fd0b, 0x22b4f363264d,
+    0x8b49b49ef3c7, 0x2027f85bca4d, 0xe4d91ddcddad, 0x7570ddc5cb42,
+    0x1eabcc372420, 0x1b36deaeedd2, 0x2417732fd61d, 0x38001d2cfd0a,
+    0x3f409b6fe68e, 0x93e134345ee2, 0x0495054c03a1, 0xd35d276f948d,
+    0x1a3f571c6bb5, 0xf9501eaab491, 0x177a8bc38ff1, 0xe4284cc60f66,
+    0xa5c129030d21, 0xd3eeb1afc681, 0x0e85b9029070, 0x35ff11e72d08,
+    0xde161800d5b6, 0x7333ce80e668, 0xef14c3967073, 0x1733946a546d,
+    0x8a1ec50bd99f, 0x9b4d3f652a31, 0xeea264a1fa78, 0xf4d4396e2ce2,
+    0xfb0a1646cb23, 0x608ed1d240f0, 0x2b99f8718227, 0xce60818319ac,
+    0xef203bc21cc3, 0xc13f6a3fec31, 0xc910550c0a4b, 0xca64b45d935b,
+    0x3d9e449dd77d, 0x6c25ed69720f, 0x0beb47a43aa8, 0x0d156b9c945e,
+    0x8387023e025d, 0xbfe126c02c14, 0x047c09c98aee, 0x05f1941d5893,
+    0x1a6fd77b3cc6, 0xf5d1819799db, 0xfac969dee476, 0x3329c8cbcd85,
+    0x80ec13851535, 0x39df83f204f1, 0x2d34af857e4f, 0xc173755091b9,
+    0x0ac67d4cff3a, 0x29827bba2c09, 0xfeb84b07dafa, 0xa48d0cc88a48,
+    0x489b60716d8a, 0x157f7c694e22, 0xce1ebf3df2ae, 0xd2d69b16edd1,
+    0xa682f975d92a, 0x0dcf05de43d3, 0x7203edcdb5b0, 0x953ff7c25dde,
+    0x0124ae06b6ff, 0x47e1f3bef13f, 0xd1124af4323a, 0xc863fad1317c,
+    0xb6de9ba23ec2, 0xefbbee2483ba, 0x700d1032ff08, 0xea432ec87b21,
+    0x26faa73bd6d0, 0x77f9ea9ecb48, 0x9f7b2f0f35cc, 0x2c7ea36ee862,
+    0x43565b9508b1, 0xdcc1b4b1a373, 0x9a2049cd0584, 0xd38f2da39beb,
+    0xc0c7d1d6e9b4, 0xcd417f9c8627, 0xcdb68f1d0268, 0xf95c9a2aea40,
+    0x0ca5d4c15bd0, 0x3b15ee01a41f, 0x511febe4859c, 0xab1594dd827d,
+    0xc99d6be98bd8, 0x96666567b9e0, 0xcd1b87419732, 0x2baa73c197d7,
+    0xc4a7a19751b2, 0x9bdebe939377, 0xb26775f8f146, 0xd4a60ce8a0d0,
+    0x2ead1857542d, 0xbd33ba8e3291, 0x083e0937e195, 0x1b9fff9bb085,
+    0x4791035def06, 0x6be73cba6acb, 0x4788942907d1, 0x9956a0985bf1,
+    0x62a7282b011d, 0x68d8ac6631bf, 0xb9ce545233e5, 0x0c8453426253,
+    0x39f2cce43c65, 0x6acbd2e84d21, 0x5822cd679e40, 0x96edd8c46c0f,
+    0x9761d97bc77a, 0xb0b1814330fd, 0x6c924fedf5b5, 0x451e2b57d011,
+    0xb6587cf0b54c, 0x61b2e358ac4e, 0xa1776fb002ed, 0xc64b9cdfedb2,
+    0x5a0d170db527, 0x5c54292227b0, 0xa751cd0f9d5e, 0x316c9aeaf81e,
+    0xeff04bb434f7, 0x39f2a1a5e1f1, 0x74afb7b865a1, 0x7b2775778182,
+    0xdbe02c891230, 0xc47700878ab9, 0xcfa1daef3653, 0x8d97703c668c,
+    0xdd37597cbd04, 0x50382f2f6a4e, 0xc13a16cbba79, 0x6a891065130c,
+    0xa67cc92831c2, 0xfa940cb5285b, 0x44c87b00c4dc, 0x3513b080980d,
+    0x797504c244fe, 0xf4d5e0fb3019, 0xf85545ab620e, 0x9d825be88c4b,
+    0xd5a9669cc936, 0x8728e112bd49, 0x03f6d0f3a090, 0x46236e5db647,
+    0x80116ad2d887, 0x86238281f6b9, 0x49002fd4e219, 0x0c58d7f6048f,
+    0x7afd42bdacc7, 0xdb6f109e40e7, 0x9731386c0081, 0x195bdaf322bd,
+    0x2976026939b7, 0x38d19e7d8aac, 0x15cc478633ad, 0xdac45638439b,
+    0x4db1454ffaf2, 0x4da32cae913f, 0xa73fda53c9c7, 0x5629a887869c,
+    0x7dc048ef05b4, 0x4f5d14f9fc80, 0xfd298e7ea2b7, 0x265a772a9456,
+    0x39f6457028b3, 0x9ce85510d1be, 0x036cc5c08b9d, 0x1d438f2b392c,
+    0x96cc60479bfa, 0xf404b9e1d5e0, 0x12e74ab7d5b9, 0x5e8f1e4e0fef,
+    0x34c768eb3672, 0xb5a0f5f51c67, 0xbe025bd57cd6, 0x910365c086ce,
+    0x14b0d702c551, 0x0a405b636a23, 0x370563949ebb, 0x87d05b0965fd,
+    0x90e1e31920f6, 0x02cbe1c35531, 0x33db6035b157, 0xdcc0f4ccb3e8,
+    0xb20885ac28dc, 0xdd521102f9cd, 0xa4434748c8de, 0x9de42f550e5a,
+    0xab31f78b9ce7, 0x01247a076c93, 0xffdc2bc7078d, 0x476038fdd786,
+    0x51358f48dd37, 0x7ed24c50527a, 0x5585cc0ca2c1, 0xcec879a71653,
+    0x7b42dd706d2c, 0xfde4d90e455b, 0xd2329fd91dfb, 0x28ae026e63d8,
+    0x239ad3205ceb, 0x1c5adffd7af4, 0x0f4329a23a06, 0x81df5982fcb8,
+    0xda03a81e265c, 0xecbd34c6512d, 0x18f84e14eebe, 0x470436a157ff,
+    0x7b39c66831b5, 0xac2fb4d3156a, 0x48176c306302, 0xb6ea21d494af,
+    0x9a1882acac01, 0x8ee6a6f5498f, 0x20040bae6e4b, 0x7fc9d56e0fbb,
+    0x971919c2fd7e, 0xdcfd9bdbd63d, 0x7a8c4639cfb2, 0xbc13f6d4a594,
+    0xb1f2ff9f262f, 0x5dbf7c444e71, 0xc28c6bb7c108, 0x41ba220385f4,
+    0x525fadb85296, 0xa33eb055198d, 0xaacb118870ce, 0x1729df97c51e,
+    0x2ce149cb83ce, 0x883636e522ac, 0x4a12a3f3c26d, 0x7cc8d360d667,
+    0xaf06766109d6, 0x652243a348b1, 0x9de7cc0b35a1, 0x703cf66256ab,
+    0x41a8cede7ac9, 0x92bcbe8b7286, 0x40c8cf605cab, 0x039ca6eb630b,
+    0x34e3b3a94aec, 0xa66eb5ee170d, 0x0659c8be64ec, 0xff9bc2f0500d,
+    0xc674a3fbbf89, 0x3aa280e81587, 0x0cc2076dd501, 0x9cb71ca6ce2f,
+    0x401af015eb21, 0xf4bdbd8ee651, 0x178b94c5bf54, 0x1b3a77a8a556,
+    0x91d38aeefd73, 0xb916976946a7, 0x66b8bf92c181, 0xdecee99ca5a8,
+    0x7a7ce62e4951, 0xa33b1066e6a5, 0xd2bb747dfcb5, 0x9ddcbcc35fd2,
+    0x9fdd9553e71c, 0x6913b8a4f8c6, 0x36cabbb7a0e0, 0xbbbba66aabc5,
+    0xbd42a9362be9, 0x8b4e4eff2abd, 0x5acfed25e345, 0xc6a4daddae4d,
+    0x038af5d2b4b4, 0x49e09c75b95b, 0x740080692a64, 0x9c841b9c02b8,
+    0x1a9b85ff5622, 0x6c8db6b439d8, 0x93fad9faa8a4, 0x7389364050f9,
+    0x6efb9d377374, 0xd1060ae843f9, 0x5bed5839c4af, 0x9a6527b14ca6,
+    0xe40580835841, 0x6c60f79899d0, 0xe6ab375811a1, 0x8e9910e1abcb,
+    0xb298f79cac70, 0x744f7a789c32, 0x2558b217e96c, 0x6fe7da2d7c6b,
+    0xf7d6e4720f38, 0x8b5ad656c6ac, 0x1cd74d774f85, 0xf75046dab33e,
+    0x82c1f82cd685, 0x5e35e06ff36b, 0xb95b89e5e401, 0x5d9035689488,
+    0xe84d81efeba9, 0x4a9352e09c3f, 0x665f0fcea514, 0x2f61d16da09c,
+    0xaf2403c5f439, 0xc67f089b043e, 0xcaa2946b68b6, 0x0bba2f453386,
+    0xa1f1a00f0c45, 0x90643f524f9c, 0x378c010a655c, 0x1a633b06e67d,
+    0x664ff03dd798, 0x8ecd745d1028, 0xa43dbad0cc85, 0x028f1f07bd29,
+    0x96e7e281664a, 0x6bc566286231, 0xa0484e3bc845, 0xe9864ad222fa,
+    0xa4ce86d8df6b, 0xc600575513c2, 0xccfe20776495, 0x5c2ceac6c1d8,
+    0xf0a7385ebf15, 0x06cb8bf82655, 0x212a59cb40c0, 0x6859c44bb9ff,
+    0x3133e47aae90, 0x987cd312c476, 0xaaf58626a439, 0xcbba0e212079,
+    0xa735e966f98f, 0xed477b8f42e7, 0x0ce4ca49a2b3, 0x1c2158edf0bf,
+    0x2575370234e2, 0xe3853f1c065c, 0x323ff48fc7c2, 0xca386c0ade18,
+    0xa3f2e897898b, 0x30e5c03092f7, 0x8d15abf4bf49, 0x3af5e78751b1,
+    0xaaae8f30377e, 0x8bd0a788a70c, 0x423aafdc9b15, 0x4bf0750e8494,
+    0x468a1866458e, 0x5d8cd90b90cd, 0x9f1305bf3a4f, 0xf63d412bb41b,
+    0x9f62f8330227, 0xb2169968b671, 0x081e61d99eae, 0x656dbfdf234a,
+    0x958db7e9f879, 0xd3ccc55cfb54, 0xdbd2281b3eaa, 0xe4f19d2b3a19,
+    0x1bf676e9d97e, 0x876c76c74a2b, 0x96bd180a8630, 0xc4f685c46b94,
+    0xf66a6802be49, 0x5be0ed73e01c, 0xf7dc004c1c1a, 0x2413e5c868e5,
+    0xa447fae30778, 0xecb0171b4faa, 0xa2b2f57819a4, 0x214fe01e9a73,
+    0xf56c8ecd96a3, 0xa5bfb1b6f351, 0xb8b98c7092da, 0x16834b5b2629,
+    0x21bf131a7cb5, 0x365595b0c47a, 0x60c890fc7199, 0xc2c111178415,
+    0x762b847dd195, 0x0054bb8b97d0, 0x6231697fe66d, 0xcd1369e5c237,
+    0xa21b9e03f902, 0x9fd760a5c7b8, 0x7d55565d606f, 0xb90b4c48695f,
+    0xc2f91c5ad867, 0xfc0887370ce6, 0x8768b1866fef, 0x2b2077fed24c,
+    0x8ce5f583de2d, 0xd6c1c797c5dc, 0x91eb5cc17d2f, 0x95b70af51aac,
+    0x6420b45c5cc7, 0xa58b1d745029, 0xb8fcb060b52c, 0xbd5d9fed1fe3,
+    0x8d236fa4af38, 0x4bf5457da53c, 0x85d9f70cd6f2, 0xeccd259a6789,
+    0x51a28bb9af99, 0xaf6ac6bd1688, 0xe09cc814eb9d, 0x4599864c1409,
+    0xf3e94c7fa9f6, 0x35c52ab2cb08, 0x4b1855a79ceb, 0x70c29dc49ff8,
+    0x97b292e6ad16, 0xe66be9004bde, 0xc2a66cbe0677, 0xd120176320df,
+    0x843ab5ce90ce, 0x03394d03b134, 0x1ef356eb01c5, 0x2cc5560c971c,
+    0x54c5df1f857f, 0x877cd43ba6cb, 0x51c3a17f95bd, 0xf7e8e7e833eb,
+    0x5240276dd460, 0xfc59da903280, 0xb6a6c89b263d, 0x77454fa38017,
+    0x5277daa938a6, 0x87cda26077f8, 0x0b7cad8bdede, 0xd16316636aa6,
+    0x343fbb81f15e, 0x3cdb75d91108, 0x231da2cac69e, 0x362f479ebe2f,
+    0xb782aa6dab4a, 0x90804b83b51e, 0xb51cf8f321e7, 0xb6c585dcf627,
+    0x16246034734d, 0x3a6921193c93, 0xf0e3931bc245, 0x6ff38cc69927,
+    0x93abcc187e30, 0xb4d56f02f092, 0x9f395ac3cabc, 0x0f5265a32985,
+    0x7b0f89409c85, 0x946264da56ce, 0x9ba1f8e1293e, 0xc9b6581967f8,
+    0xd876b1607929, 0x4783d17d6614, 0x8d353afe3c35, 0x68ae3a16e5c8,
+    0x542ac9e178e5, 0x96e0476504cd, 0xe5c05beb6ea5, 0xba367b0c3ab8,
+    0x9d970a403d94, 0x1287909cc171, 0x7175306816ac, 0xd24995136177,
+    0x23bdf681dfef, 0xb23fe0ff412d, 0xbd1f1a7c7313, 0xfc8d8ec15457,
+    0x5a374774bfea, 0xd2c590446756, 0x8fc8bbe8c19a, 0x9d452703c9a8,
+    0xa0434e0c3fd4, 0xb6b64a5e7988, 0x553a15a29fea, 0x5acbe8d3f5ef,
+    0x7b236a52ae21, 0x7d867a91dc8f, 0xf49169866027, 0x52302768b6ca,
+    0x329722c0b6de, 0xe9f6276dce12, 0x7a0799ed92ac, 0xa5b631a94c00,
+    0xcb8fe96df55b, 0x378b0a7283e6, 0xe40399c3ac97, 0xb6d48459fef2,
+    0x49dfc544cc19, 0x8fdd5ad0237b, 0xa0012c483a86, 0xee99575f94b9,
+    0x11d3c76b1484, 0x5a8eb1e18548, 0xc667bda7b82a, 0x55748143c783,
+    0x662e773ca361, 0x2495b4bd18cb, 0x765c6f4bb44b, 0xedb83379288b,
+    0x7fa2a2c87aca, 0x5c0494a011c2, 0xb424c52cd38c, 0xd6966d3d9770,
+    0xf7636be3e929, 0xb865e9111c4c, 0xd8e60bffdb75, 0x919f9d141780,
+    0xa7d8ba0d9d83, 0xb9be41e40374, 0xbe14d04a6e87, 0xd947d6076553,
+    0x753fa68242cc, 0xda3c402c6176, 0x13821bfff31b, 0xa1723a99fd25,
+    0xe1a1e2f2eda7, 0xfb3f161efb09, 0x726d4da804e2, 0xf38b644ab1b0,
+    0xa18f7391d1e6, 0xc9d4c0cc8ce7, 0x634e328a915c, 0x84942430c16b,
+    0x451cf025f72a, 0x68eb6e341997, 0x296bc37d79dd, 0x7aba251a1b1c,
+    0x7ecaef1aa67a, 0xdc433f2133d5, 0x30d9d5dbbc0c, 0x3148a244a083,
+    0xb9b05d831d9d, 0xfd02512a13e1, 0x034596f70a86, 0xcc86317017d7,
+    0x3352a190dd5e, 0xb78658c55698, 0x58a85cf985b4, 0xa8d2e4c98482,
+    0xf7a1bb6741a0, 0x9d4e1646efbc, 0xe331c425ad6a, 0x3bd7506892d7,
+    0x6a460a4eb888, 0x6ec6506539ac, 0xdfb734a7e2e2, 0x06d1fece34a6,
+    0x118cfe03daf3, 0xc440d194305a, 0x3072eeb02398, 0x95740584b9a3,
+    0x4bda64e8515c, 0x91b2b162fc4f, 0x2fc4a86fe57a, 0x9e542c5e84a8,
+    0xeac6aaf2b6ac, 0x3e0d006a1dad, 0xbde7dfa1f222, 0x8c96b3c3fea6,
+    0x5120812d31aa, 0x4e8a5fcceee2, 0x9a6c12bbdcc9, 0xf732f73ae8ff,
+    0x3af94ab54a35, 0xd1a3a5f66f45, 0xd9d9e6418946, 0x60579a42b24b,
+    0xc986fd29bbf9, 0x277a5dc26956, 0xe5156c5dba92, 0x3ebb05ccd41d,
+    0xa933841a3e67, 0xc657ccf345e5, 0xbc3ba3793fa9, 0x647d6181117f,
+    0x7f0ad94cc4e6, 0xe48c08fdf9e1, 0xb555b35f2366, 0x8d380ecd4a89,
+    0x05add65e9a5a, 0xb018f864fe6e, 0x0e480cce8da4, 0x8a1764df5b6e,
+    0xec5c1104ef2b, 0xf7fd68d24763, 0xc07157d136b8, 0xe90f2b41aa88,
+    0x94fd1c032ae3, 0x2e19c4f004ed, 0xda9dc4304e18, 0x3cc663a01831,
+    0x47d1725fa1d4, 0x603d0b25931e, 0xbc1919e27608, 0xadcc5abfc277,
+    0xc1fd4d480890, 0x02e0fb4fb5b1, 0x0dab1364d8c8, 0x95c7a2d6e7b8,
+    0x059a5e305f3f, 0x7d49d36124e1, 0xd626804878c3, 0x2a20f1709866,
+    0x48817064c165, 0x7b040b07a7bc, 0xb292b62ff3e1, 0x9f06c678e9a0,
+    0xa0fb7f6d3f21, 0xc0ccdf86c65e, 0xe1771941ab1f, 0x594f4d5b61ff,
+    0x601b22b0cd0d, 0x32bf4dc8b097, 0x696f7d5a0afe, 0xa0dd380f1853,
+    0x53fdba761085, 0x6faa7ff61274, 0x8f627c0c1946, 0x19afb2d26555,
+    0x53cab0cbea6f, 0xbeeae927e789, 0x769a1360de16, 0xb1aacbeba569,
+    0x96038b4b1c36, 0xc13fc3af90c6, 0xf7aa0a4e01b6, 0xb3f571514708,
+    0xe981d603db86, 0x7ebdbe42e5cc, 0xcfe96208398e, 0xea5957c204b0,
+    0x5614a6e74988, 0x968471b08585, 0x3d42fa6a3d49, 0xa72787cb206f,
+    0x78396c2c5b61, 0x0cf400c1addb, 0x633f44023c1b, 0xd00d27948866,
+    0xd73ef38af2eb, 0x5616e9cc3288, 0x8b7e13f92856, 0xe8ec8df9bd9a,
+    0x16f558b5d218, 0xca0cad4cf53e, 0x15db63dd0446, 0x8e15779d723e,
+    0x4acf1fe9947a, 0xfb896cca1b37, 0x0ab1b5b763c9, 0x357ba4c68f98,
+    0x9bc068240986, 0x060567a94230, 0x0b2282ff3d37, 0xb1a8dc4b65b0,
+    0x997146598e18, 0xe1dc96642710, 0x632a413b83ee, 0xd64c7c7f7b92,
+    0x1112a2e8ea07, 0xe0294224242a, 0x9925293ce95f, 0x5e7fceb9aff8,
+    0x727e49cbf703, 0x62818751acd0, 0x906f039016b8, 0x6ecbc56fa2f5,
+    0x202b743d7511, 0x02209fb4a427, 0xd2589434727b, 0x5c6499c15239,
+    0x190ca508b834, 0x331c378c0a5a, 0x44080b4fcffa, 0x959571a5bf56,
+    0xb63351356c89, 0x3c13edf127a2, 0x02a87ff2d60a, 0x08f025d42892,
+    0x2c26865c4e42, 0x4bc5ce1be5f8, 0xf8d79fbe283f, 0x3575e7461b04,
+    0x1d2e13a9a9c9, 0x8b55996703d7, 0xeec9965c79fa, 0x4b56fa9ee206,
+    0x58fa1538d89c, 0x24435dcc3f20, 0x7291a66f51c4, 0x8493c61b88e6,
+    0x6bc05b284a82, 0x2c7462b4c2b2, 0xf1216a276e38, 0x7402d111924f,
+    0x0ddaf61aeb5a, 0x142e3f0375dd, 0x2071a05ebeec, 0x33a769784613,
+    0x718bf7b05835, 0x058c59815249, 0xd4276b95729c, 0x1a797f4a16f6,
+    0x683aa53841cd, 0x0b0536564529, 0xe3d06e815dae, 0xb2f6944331e1,
+    0x3fe387e0b30a, 0x74a20dc2fef4, 0x8b421a24a941, 0xd1c9640391e5,
+    0xd5aca8d6a120, 0xfc0c55fc836a, 0xfdb14ff7f75d, 0x076a0e6d2bdd,
+    0x88f7c5b2d833, 0xe1a88755d855, 0xba83c29c84b8, 0xa2973b60ffcc,
+    0xb46cfaaad7a4, 0x0bb11dc3eb24, 0x067f8227e4de, 0x4c1bbbb59e71,
+    0x5477ba2f706b, 0x713bb5d3faf0, 0x38bc92f3f145, 0xbecee8ed8c5e,
+    0xdb0f5d542168, 0x59ddc6a97c72, 0x357bea45c8b2, 0xead8b70835fe,
+    0xec34dd588338, 0x52b1a4826334, 0x79562bd20e1f, 0x9afad0c392c4,
+    0xfad8649d8c3c, 0x000beab3bcad, 0x20714a44d8cd, 0x98130d673287,
+    0x9d4b26a9d65c, 0x676901ecbc8f, 0xe0c3eebf93b3, 0x6b634416f705,
+    0xa35117a8a34a, 0x1c9e1ef2283c, 0x429c358b66f2, 0xb225838ea274,
+    0x674971366f26, 0xcae2078f8069, 0xb816b241701a, 0x8bc08c7b1bfc,
+    0x7be3ba35fd82, 0xf090c5c2db5c, 0x318c8e75e24d, 0xac507f6d5782,
+    0x984208b66add, 0x51707d472b23, 0x6f21e8d51a6e, 0x85f75765010b,
+    0xde142f92684d, 0xa0484725b788, 0xfab20dec2b52, 0x263c555e5d0a,
+    0x263ffe4eb95a, 0xf8d0b7e9b27b, 0x3fe8584fd3b2, 0x290d393f4b1c,
+    0x71d902825913, 0x298bfa38b5b2, 0xb7190ce9fd87, 0x45729f116b52,
+    0x65a8806fd365, 0x4a14d5d93e5d, 0x89ab76701b0e, 0xac79fd540560,
+    0x0f155b3723c4, 0x7a2313c93bcd, 0x8fb325e51a21, 0x962e2d7882cb,
+    0xdafff6974a55, 0xa0b690ab2048, 0x83dabc612ffc, 0x2b0f87f18e99,
+    0xe3ac812ff973, 0xc6804ccb2409, 0xcf6c16ac2709, 0xfb62caef0783,
+    0x0dd2e2ca080b, 0x1a05f078bb74, 0x38cf1d4b3ae2, 0x95f130b24920,
+    0xea0cf169b71a, 0x8fa4da8e67b6, 0x2be2436b2cde, 0x4c6f27182160,
+    0xe799ecfcd156, 0x1315561e8c09, 0xfe4c96a14afb, 0xddc1df7474ad,
+    0xed4398d38d22, 0x48d899d269bc, 0x5a622d11c053, 0x9a73de3abd06,
+    0xdd0fbd2bc2be, 0x8aa4f17ef0be, 0x2f56bc9af534, 0xaf6ddac3b30f,
+    0x944bf5d94d7f, 0x953a7c02c15f, 0xc0f49238a8ec, 0x10e958be5be3,
+    0xd89f477b8cc0, 0x5fbadf85507e, 0xb8a278b2ab91, 0x917453116b67,
+    0xbc76127216fa, 0x3583bcfa5dc3, 0xd61654e36b60, 0xb4aa87f867da,
+    0x9c4090928d37, 0xec6676ab0ae2, 0x4ccf58c1bdff, 0x29b2723f14bd,
+    0xe5687a70a28e, 0x3048de522400, 0x78595e90ca77, 0x71c6353de882,
+    0xbc547317646d, 0xc2e87f923dfb, 0x34bf474e8d92, 0xd3a8cea4ada6,
+    0x5085ed6709ba, 0xa91dfab39ac0, 0xf17db642097c, 0xfdc1b14867d7,
+    0x3cbff9ca4206, 0xabc80a25c1bf, 0x591221378f54, 0x54ccac2cc739,
+    0x329c9be86d71, 0x3f6801ac235c, 0xaf7461cf48cf, 0x94a12d14a3f7,
+    0xd844e030a1f7, 0xf17705e62335, 0x3fe58f4f635a, 0xee76119e5a0e,
+    0xe3fb856a0104, 0x69025e484785, 0x74c481fbd4cb, 0x2b48637154c9,
+    0x29180cae1972, 0xe7a8c74f9f28, 0x27f6b7698541, 0x501ba6c7f743,
+    0xeff19dc96906, 0x88eb97ccd21b, 0xbe70b929bbb8, 0x67ee222ca782,
+    0xcfc7674bf5fb, 0x6b350e5536a8, 0x76f1640f37ae, 0x8347abc76399,
+    0x4f6ed646a68f, 0x19af3be5f3f5, 0xc71c8d013a0c, 0xd2be355f940c,
+    0xac62472c0a15, 0x50d0385c5901, 0x4df9956fbb37, 0xaa3507250406,
+    0xedb74d9b9831, 0x5d6de63d4a11, 0x0c37651fcba6, 0x9c0f32d5e254,
+    0x63f6c4cef72e, 0xf2e2bc84d179, 0xa6ae2cccad41, 0xa530f17c1775,
+    0x3efdf72751ce, 0xb8a0f1f64259, 0xce422bf8d164, 0x09e9dd3e789b,
+    0xb6db0073701b, 0xd19380be4e72, 0x26b0fe8d14f0, 0x43a035c8e1e6,
+    0xbd7089c1568e, 0xfbb21b5998e0, 0x0b4bfdef18e4, 0xca36c2ad83c9,
+    0x460829c58580, 0x56101314dca9, 0xcc7ff9cb2642, 0xc245640320ad,
+    0x74db47da7089, 0xea7ef1208835, 0xbe9fa7dbf03c, 0xe80f8e2d107d,
+    0x256d85e1e620, 0xb12b28af84e3, 0x6015908e5704, 0x389b3ee29599,
+    0x645f65ad7b02, 0x778e43794754, 0xe2f54079373a, 0x28a9cf415062,
+    0x5c0c77ed6296, 0x03cbf80f658c, 0xa091323c800f, 0x65e85acce25e,
+    0xcba070938009, 0x611a31ac9c18, 0xb99c3acbf57f, 0xe43669a27938,
+    0x0bcf782d3bc4, 0xcc3435deddf0, 0xb5732a7ee714, 0x56ce98edbe57,
+    0x588b47018e4f, 0x2ef384ef7469, 0x08ce07b34798, 0x188238885f1b,
+    0xc6c28e3756fb, 0x747ea58459cd, 0xab1bb429cb93, 0x0aa84e3dcaa1,
+    0x7b22a68fb97a, 0xd39580cd9a14, 0x44257e30b105, 0x7297a6782d41,
+    0xca8f9c27e5df, 0xba3744329bd9, 0x1db6b1173276, 0x26dad669bcc7,
+    0x7eae8346dc0d, 0x95160fa9f547, 0x397b742c72b7, 0x6bd5e9917d1c,
+    0x993050946935, 0xe3d9173c8264, 0xa201a7a8caf4, 0x1b354aeecf7b,
+    0x66d7f1ad8101, 0xdb8ed34056f2, 0x254488097721, 0x8175f7b4cfd3,
+    0x72f2527cb392, 0x886c34676241, 0xf75fe909e07d, 0xbcbfe76227cc,
+    0xc6abbe6f5eea, 0x1da665a728a6, 0x7b514325b112, 0x1845aaf47dda,
+    0x998d1a2b3132, 0xca9504b6e0af, 0xd8ff71ef748b, 0xf4a99d483b00,
+    0x7b1c40765312, 0x90e6f799f269, 0xb158ebe54bdc, 0xedfaa1ce952c,
+    0xff103bcd7e9c, 0x87560dd19813, 0xa87fb0bc0a75, 0x83340d5f4e3f,
+    0x701d0124c839, 0xc162437ca22b, 0xd9856f2a6a87, 0xae4ce72d408b,
+    0x953525b952cf, 0xce654679c059, 0x4512e17d11f9, 0x7c7aa9ab894c,
+    0x6a4ae044cc45, 0xfb05568e1913, 0x3935c03faaae, 0xc61b61fc7337,
+    0x1f5687a015ea, 0x0330ec991fec, 0xa519592fd53a, 0x7091fb68e609,
+    0x951f1adaca7e, 0x5bb9896112e5, 0x078773eb6b84, 0x7b8d3a87c218,
+    0x66fcfb09121d, 0xd012f9ec9b60, 0x426ea8410310, 0xd7f45cf258e2,
+    0x8f5c9dfa52e1, 0xbb53523d335b, 0x0921f721b299, 0x4e915b2f0158,
+    0xce5d96a5648d, 0xd1a3b5a7f81d, 0x884469824f11, 0x01bc12b80128,
+    0x40eef7b90a55, 0x6136eb3a3a33, 0xe595a376f019, 0x0978cdcf15cb,
+    0xd2e9f543f7ff, 0x1c1ae80209d7, 0xb3cd60883ab3, 0x1a8d6f06da68,
+    0x2c20a5341195, 0x7ef1ce14e0d7, 0xf311073fc595, 0x8fb649c0c61b,
+    0x21b01b09a8a8, 0xa07338fcecfa, 0x2daf51e83a65, 0x340a2740834d,
+    0xc1c2b0181358, 0xb9cef1d57a87, 0x163eccf1dad3, 0xd43fcab73d42,
+    0xaccbe0b01d63, 0x62cb161c8b0d, 0xd6b99647a2e2, 0x2327229351fb,
+    0x8246a02990f8, 0xe4c8005e19a5, 0xcc9c4f796e11, 0xfd94fc989702,
+    0x10bf5d39ebf3, 0x24815ecc43d3, 0xa4db0d88909d, 0x0e85e794b818,
+    0xe2a50466f7bc, 0x632002b292dd, 0x80e430105b42, 0xe28cdb30902d,
+    0xc5ba51cc906b, 0x5f260a053ab4, 0x664891183069, 0x83741d46e2c8,
+    0x0adba6cc2202, 0xd533b1f8bdae, 0x9083ef8d440e, 0x15650a3cd296,
+    0xdd443b8c0dfa, 0xd5ec0930c871, 0x91107182f714, 0xeaf42ce635ec,
+    0x452782b54c90, 0x6fc62eb647aa, 0x97379c0f1656, 0x790384f43ae5,
+    0xb57f421fc677, 0xef4536cff70c, 0x33294e623490, 0x582f93ec0edc,
+    0xc9f9a261175e, 0x5632b53fb473, 0xb5706494ed04, 0xe25f4903416f,
+    0xfe647eb7002a, 0x47ef1c75e7aa, 0xd30914b84db8, 0x9852339522dc,
+    0x192124ea0368, 0x88c5364ae1f3, 0xae54f59947bc, 0xbdc4c8d9906c,
+    0x6596451c5f2a, 0x0f1273523218, 0x928204a63f57, 0xb2e4a583163b,
+    0xa15b9d63c731, 0xaf21cd7a2c87, 0xafe74ba09ba5, 0x2a8c872ffdfd,
+    0x07150b7067ff, 0x4ac64c0dc825, 0x925ad17e3786, 0x5a904df7ffc6,
+    0x13679bad29db, 0x1f30fb3f73be, 0x92751132c146, 0x090204dce79a,
+    0xab32696dba4c, 0x2d1f0e8dc0fd, 0xfbf97d631f79, 0x46ae63a76a71,
+    0x9d0d68f5e4e2, 0xfee61d68d567, 0x0928f6c498f4, 0x2cc8cada2265,
+    0x6e57e357acea, 0x35c4f7559793, 0xb8a80e2ae010, 0x5c407e192c00,
+    0x7f13230b7e2c, 0x8faed1436473, 0xb2cb1e1af869, 0xefd573578a69,
+    0xcc9f28d160bc, 0xafe4a416bec7, 0xef595b7337d5, 0xa8f194225da4,
+    0x2da89805c694, 0x1c3610a8a8f7, 0xf63f69690d28, 0xbe396ca0f954,
+    0x03b081ab103a, 0x9e0c096554fe, 0x5b01a0115ed8, 0xe435a036fc23,
+    0xff29072cc0bd, 0x46a42899afb3, 0xc579e3cfc35d, 0x190a1bbb71ed,
+    0xea05f47886fc, 0x4508c3010e97, 0xac9bb89a75ce, 0xaaa90022467b,
+    0xc57842d27b7f, 0xe2faeec8a494, 0x91668e99eca4, 0x6d1f3b04d628,
+    0xcb2d998a2c53, 0x1bea2f43765e, 0x03e59be7a561, 0xc07743e5c436,
+    0xcf4d1db0f3bd, 0x4fc1ca59e955, 0x5416bed72c05, 0x88903e8bb748,
+    0x8ea8d3e17309, 0xa4812e887089, 0xcb3cc1ba546e, 0x4b07ee6796cd,
+    0xc9e26157279d, 0x815796356e76, 0x4e1cd0c1442b, 0x7c400e49f9ea,
+    0xc34d047007f9, 0xc525761f8e11, 0x1955cd1f034d, 0x918ebc14546c,
+    0x4c6056285a7d, 0x0a51c5f03498, 0x9adc4ffce14a, 0xcd545d75cff7,
+    0xa3dd6749eb1f, 0xf2e114f5dd00, 0x356d06cd4add, 0x4952eb020d13,
+    0xe6a5566f1d22, 0x92dba5bdb8ac, 0xea2b8956ee6e, 0x3923596ca28f,
+    0x925caa98171a, 0xab683de4c54e, 0xfd1101436cff, 0x05a47f7dbcf5,
+    0x0b9e6df67398, 0x8021039b1c53, 0xf37fa25d071f, 0xc90a38ee168c,
+    0xdfd9d98470e9, 0xf07b9044e935, 0xf90a0634ba3d, 0x13f14d8e4d0e,
+    0x43ca5c6825af, 0xa2d99c53cb09, 0xaeeeed619068, 0xccf9df634b99,
+    0x1e9a421819d4, 0x9ff63194d86b, 0x7b360101df9c, 0x1aabaaced466,
+    0x9947118e28d5, 0x08ed47f83750, 0xf2b10d2e5c8a, 0xcb02ceb07d70,
+    0x1dbaf2a9dbce, 0x9e7af7233796, 0xdbfe7277bf6d, 0xcb498c4050c1,
+    0x1562717f3ce7, 0x782f380f3983, 0x66bee4465995, 0x0a28facd0e51,
+    0xbd547f7c09b7, 0x46af3e0e7212, 0x70dd24a150cb, 0x9f4f8777d2cc,
+    0xcf45f3d689f0, 0x26619a0ca03b, 0x4aa8a487a333, 0x6761303d0d3e,
+    0x13eb3805e86e, 0x76025efe0008, 0xef843d55b9bd, 0x79f9d3e8e783,
+    0xddb07773e898, 0xf3670d6d8c37, 0xbbadec5d0af3, 0x6b56bb109957,
+    0x74d3dbb6e801, 0x1ad931ea5a76, 0x28e251b05e42, 0xbc017936215c,
+    0x925c88beb4d7, 0xddbfd2bc2834, 0x4058dff4ed6b, 0x4e4baafcb7d0,
+    0x6effe6c18cae, 0xaaa16af1981e, 0xcca0791c1179, 0x2353d08fe0dc,
+    0x42e7afd7465c, 0x5a8fb4f0f2b3, 0x3b7c971f1df7, 0x733eb9fb7c41,
+    0x13abce44d7aa, 0xb0dfe365e463, 0xbf7959b320c2, 0x67080ad35acc,
+    0x8c5eeb071ffb, 0x9d57ba1820b9, 0x6dfc1b689dcf, 0xde6e0b99c4ee,
+    0x3eefb808e32a, 0x234f039a0545, 0x95e07338b8cc, 0xca3058b61845,
+    0x7d880a4c8cab, 0xc09a4216353f, 0x40f256e959a6, 0xb9eaacf17a1f,
+    0x790cbe584b7f, 0xe6e5bfe9dcdb, 0xca48e9451b31, 0x588eaad38f0c,
+    0x461a41d20f29, 0xa706117ba275, 0x3327e4fd2046, 0x59e10294d273,
+    0x0cd6b70ba57a, 0x2dd30f57a17e, 0xaa03ef6a051d, 0x4413aa35d827,
+    0xa3b2cb8aaa8a, 0x4aff021951a8, 0x0690e6054fe3, 0x886b3e738904,
+    0x1a4971108dfc, 0x7ab71257ac83, 0xa30ba475dc54, 0x35f235fb1258,
+    0xb723d41ca592, 0xcf09ec9f6977, 0xece65935956e, 0x13e6256f3e64,
+    0xba08ad239756, 0x28cf51b6a4f9, 0x3acb1fc03692, 0xf5b76ea6d1ed,
+    0x0b795e8d9a03, 0x67fab935cc49, 0xaa3ca40be8d5, 0x9362a9c67d18,
+    0xaf592d2dbcd4, 0x250df6e396f3, 0x2c2f93ba7594, 0x894bca9ce9d3,
+    0xc277a8c1e5c1, 0xe8ff983bbf7b, 0xa6698ebb3447, 0x55caf094acd4,
+    0x01dffedf07c6, 0xed17a5f4c483, 0x582ce37facad, 0xc7829541cc48,
+    0x58a561617f79, 0x9bdbf7ffbd68, 0x47291a0065c8, 0x202fe16f5664,
+    0x61c87f61d738, 0x62666ae16035, 0xa61adf511121, 0x82b3b73e10c9,
+    0x9a3ddb29c554, 0xd1ab7151c8e7, 0x947bc4797cb9, 0x184d1f72450d,
+    0x6ca74423cc5d, 0x0e648b253b3d, 0x0489e375a31a, 0xe95e4d124f35,
+    0x68422860bad6, 0x4be12a19e4c7, 0x47ed5e822865, 0x4efd23c54500,
+    0x611c41da4b1e, 0x5fa08ad3b07b, 0xa29d161542b3, 0xe49ea3ec0dff,
+    0xd0f39839f316, 0xfa743153b884, 0x13841d3dd457, 0xc153ad7eafd6,
+    0xc50df1c5a893, 0x016a58671db8, 0xb1e5f833779b, 0xe6e156b1c237,
+    0x09842ce05e8e, 0x4e79f5b7abec, 0xf8c895ea3ad2, 0xfb8366b014bd,
+    0x3e9c14a96c9c, 0x4647e9dfc491, 0x6c22dc7186c8, 0xd07371fab2c2,
+    0x750d386d596b, 0x988914414b4c, 0x2fd12efe0153, 0x6a33ea629e0c,
+    0x87921294593b, 0x5955870a1f14, 0x7785e6a99076, 0xbba73d07fbae,
+    0x0e52f0d4267b, 0x1b1596d1f369, 0x82fe53ecce33, 0xfba12b8d8e9d,
+    0x9a178ddd5c9b, 0x5854ad7d8cf2, 0xb580d179990c, 0xdf6e4c8218c6,
+    0x92e027cb1ae9, 0xab7cca21dae4, 0xd0ed32e62a40, 0xebcd03d1faaf,
+    0x2f5cc1c7f4fd, 0xb6973b3dc39e, 0x3a6e139f166a, 0x93583f7cbeb3,
+    0x4c5794f6da73, 0xec2f4a6e7e5b, 0x1e1c5a3bb52b, 0x39d2d134a56f,
+    0xa3c568abe489, 0xe0ba8df559fb, 0x45f81e49df31, 0x334f2ecb2c53,
+    0x5ebd69879467, 0x0a920c47905a, 0x1aa31befce4b, 0xf6b4891f7663,
+    0xa721bfb964cb, 0xc96239ac503c, 0x3a550869239d, 0x46ba7f02c855,
+    0x699ed6fbd2c5, 0x7445a67c6f9b, 0x8150de1f6f39, 0x00358da7f5eb,
+    0xa6de95dd8f59, 0x0a8b12d1968f, 0x383fea8465a2, 0x4c80e0283f99,
+    0x4afa6ff3b546, 0xfdeddad44e9b, 0xa4aadfa6d9ff, 0xd5d1710f8e9a,
+    0x9cae359a602b, 0xe51484d16879, 0xe333535bf964, 0xfbfeda3a5820,
+    0x93a76e2f8326, 0xb6e4365c4a62, 0xf66f7d06029c, 0xa2d1dc25fba8,
+    0xdd3d99567567, 0x71f47de74c16, 0x8931dc1d6a14, 0x3403034fdb5e,
+    0x4f7ebf3f0e9c, 0x1bcf8b6c6ba0, 0x9c058b01345e, 0x61309c494ae7,
+    0xe38dcc486834, 0xef0289245b5d, 0xb03625e45934, 0xcb4a58c052dc,
+    0x626dda196dc3, 0x580b17ff6cc1, 0xa2a4dbca0529, 0xdb6930dbc781,
+    0xcbd138c071a8, 0x597c0812a787, 0xa47294ae6a5a, 0xf2c74c6d0cce,
+    0xe63a853275fe, 0xbf822d49d123, 0xc4a3a13d39c9, 0xeb6317ee7fc3,
+    0xf00ca0970b6e, 0xa816bf8bcd07, 0xf0bd13ba3689, 0x54c367b30b6d,
+    0x06f41a986924, 0x28ab975bc2e6, 0x3bb749a92475, 0x6d3acfe6fb81,
+    0xe92ab94ef894, 0x507c93b0ef7d, 0x97c8d0963fb2, 0x841789437b43,
+    0x36afe9c91c52, 0x1b6278b67289, 0x4be0aeee90c4, 0x5d3cf78f70fd,
+    0xb5baa8115cc5, 0xdd2e838d6ee5, 0x6523965a93b7, 0x7426128e1691,
+    0xc10c1544aa32, 0x1a40150b8c4b, 0xb36384b91768, 0x6b6bced2dc10,
+    0x602f996c683e, 0xc8216e551c11, 0xf8b091e4e53e, 0xa371064eb15b,
+    0xca096e022a7d, 0x40d2c52a43f9, 0xfe8b7a64873c, 0x0843140e9ee3,
+    0x7fbf86a95dea, 0x197fa7095928, 0x25f211794aba, 0x0d3117c4e3a8,
+    0x50e8c006e55a, 0xcdb75f94d9f0, 0xa9711ac8e44a, 0xdbc94697b4fd,
+    0x64f7a4c7be0e, 0x123c790006f0, 0xf509a2678a35, 0x9010a322ba1c,
+    0xcb47806f3733, 0x41863907a859, 0x9a50e9ce3073, 0xffa39b8ac478,
+    0x8012ef58395a, 0x8a8f06eaad08, 0xc52047bad8fb, 0x2bcb94504831,
+    0x4191dcd47de6, 0xc5cb4c00875d, 0x3bf3c5dcbb1f, 0x878bdfd443c4,
+    0x266f489fd5d0, 0x43c785c1e941, 0xb417b145b67f, 0xfc57a12c0344,
+    0xae3f214a2abb, 0xf6a1b18edab6, 0xcfa536a06ccd, 0x12b8cdc437ff,
+    0x68ea37e33a4a, 0x8acfa77b2d75, 0x0ddc848b99c2, 0x401b11c7e445,
+    0xadf45c738925, 0x156bba753242, 0x6e4ecf0127b0, 0x72381c5cc8f0,
+    0x8f0f64f1341c, 0xaf2b1c9d0e72, 0xdffc5ba25b04, 0xd5b6cb09407f,
+    0x6057712fb8b0, 0x66d680cf8d31, 0x1b64f9a3a65f, 0x4b34e2249838,
+    0x9f877a666123, 0x1eef8c9fd38e, 0x67b2038602b7, 0x31f15a5c2f47,
+    0x8be99d7c67a2, 0x7962138eff1e, 0x80b862e708c0, 0x789f2104bdbb,
+    0xd6acab4ff8d5, 0x54b9ef1c5260, 0xf3ba64dbc127, 0x86d67d1c3d3d,
+    0x3239d6c50386, 0x8340737f9ee2, 0xaa971d7aca85, 0x655f1c17de44,
+    0x882cf34eb747, 0x495aac132cdb, 0xae29e51cd924, 0xe20fb5ead14c,
+    0x9ece2bf26cb1, 0xbaab94703f86, 0x5173d0df5c62, 0x557631ad7c45,
+    0x4e2f9c56f2fd, 0x455fee199629, 0x9863195258ef, 0x88e9706ee6f1,
+    0x6cdc56a6488e, 0x1a1a298eda98, 0x512588083707, 0x5c89578c7f3e,
+    0x379f17a44f1b, 0x483c5fc55dc5, 0xa548086c3699, 0x6977f0458e30,
+    0x70c8ac331157, 0x8987fd734247, 0xf935352d079a, 0x75893946e7b8,
+    0xba5843b16a06, 0xa735b8fcb5c7, 0x8dad5e1ea3b0, 0xfa8e629cd89c,
+    0xcac2739d44db, 0x2b1a43cf4344, 0xe7209619ba30, 0xb6d9d1170c32,
+    0xf57f58d427cd, 0x4f16ad9ddf9f, 0xf8db48ebdb7b, 0x6e9938fa9c62,
+    0xe6f7ab8f9fb5, 0xc2757863c271, 0xed034d0f0e9c, 0x1ab7d430dbe7,
+    0x6773b1903c31, 0xe9b917750176, 0x363e92681259, 0xb16badd2d7cb,
+    0xbd7937e1d900, 0x30c8501d29bc, 0x258a1a3a8fbc, 0x7fe786d5cbf8,
+    0x23d1dac62966, 0xb4888aa62f9d, 0x538223a2f14e, 0x31f31cae7546,
+    0xc65ae3059f2e, 0x27bbc22faebb, 0xe257fa15787c, 0xc076496fdda4,
+    0xc749d024dfa2, 0x7e5a99c041a8, 0x678ad49cdd89, 0xeebe714fff14,
+    0x7c820afaa012, 0x8da9580041c6, 0xe87b6d88fc78, 0xfdb9d631cf13,
+    0x3af3a9a11520, 0x753e3bcf4a4a, 0xdf92abd92101, 0x45ee6516d7e6,
+    0x4814119a0015, 0x58ca241379de, 0x216b2321d9cc, 0xb4f383a5fae2,
+    0x45ea64815a53, 0x737808a952cc, 0x1f0e22e22ba7, 0x841d2dcb21a3,
+    0xcabf84848f9d, 0x2338c66711c3, 0x388adb1bd48c, 0x54940a50f15f,
+    0x6492d4be5004, 0x5e1e7e4c6b33, 0x5737c10c535a, 0x810a78b7300b,
+    0x1c1ef868b459, 0x9ad0dfea3a35, 0x2cb2d88db6fb, 0x91b8828ec06a,
+    0xd1218f6d1339, 0x1d34275fc60f, 0x3e8d990dee46, 0x1a65ec93efff,
+    0x3d16e2dae44d, 0x51f213f9cb76, 0x9e662c7725dd, 0x5f5154a80c82,
+    0xac0a474f2f8a, 0x4eec2d697f64, 0x28f385adbd67, 0xd37a6b4fd79b,
+    0xb886f5cf0542, 0x2b9b8d8f9ebd, 0x4684a9615fb6, 0x6939bdb6bbde,
+    0x915686fa5a2f, 0xc1bbce4d0389, 0x0cbfcb540d04, 0xb28da1c7017d,
+    0x19984266fa10, 0x85d4cf33fab1, 0xeb496a2af259, 0x2552d9aac8f1,
+    0xccd6d976d058, 0x70a19c4e1194, 0x0b32cf50a62c, 0x6ea02a1274fe,
+    0xb70b04ff026c, 0x5220e40aa958, 0xbae26c3bd3e7, 0x3d0adb2eb874,
+    0xf1c9d27398c6, 0x0a668acc54c4, 0x5df05b1361c8, 0x7531e5e2f55f,
+    0x777e8b61ee63, 0x2286741cbe9b, 0x0024357c7949, 0x88e927960cdd,
+    0x2ac4b6587154, 0xa57d54d9b056, 0x7372ca717f85, 0xb8170e62caec,
+    0xe4430ed1ebb3, 0xbcf5beaa8d22, 0x76f471a3cbb1, 0xab010f699ca8,
+    0xc06e5c9a0e04, 0xd52deeaa48cf, 0xa14d522ac79b, 0x8cf93b9b0503,
+    0x04e16b59617d, 0x6430b7b3564f, 0x92bb59c23be2, 0x290760d41be3,
+    0x37ca03b166c6, 0x7dfd5a8709ff, 0x336a9f8316b3, 0xad66debfdf1b,
+    0x51b85ca76698, 0x750ffe33cce0, 0xa0b0514a247b, 0x997650add886,
+    0x6e8cb36f7507, 0xb67d2d76f3c2, 0x98f15b8ab2ae, 0xb0aee1287d5b,
+    0x2993f1b7ab71, 0x658a44a205a1, 0x81564aaa21fa, 0x0991e015458f,
+    0x61e781be2031, 0x682cd6924ac3, 0xa4c5db40b8d5, 0xd6c2d8278780,
+    0x7724b710c834, 0xe5830ce18eef, 0x7e7eb6d01c6a, 0x82f1b87f4eec,
+    0xa15d1c4290d8, 0xa81b138597f8, 0xc457a46ccaed, 0x0c6073aea93d,
+    0x3408b11ae496, 0x57d13ca27101, 0xaf615136fffd, 0xd54ba5c25282,
+    0x984266c5cf9c, 0xe0ecef049cfb, 0x15dbf357b009, 0x2fb5c490b658,
+    0xdd8a97ab168a, 0x77169820dc98, 0xa5689d1b159a, 0x36159b822d7c,
+    0x54ffe4cbe3c0, 0xd1c0312968de, 0x38c8fd513349, 0x5462eaff601b,
+    0xd929cd715327, 0xdcf0735a71b6, 0x523686a96361, 0xafc866b7692d,
+    0x61f9d7d4e5f2, 0x6a1713388f0f, 0xba967139cae1, 0xd27b897e7baf,
+    0xeb140dc6d3d0, 0x4ca7d984edf5, 0x18e74ab382c1, 0x0e0b710c99a7,
+    0xd8a92a9d1905, 0x150b0ea718a2, 0xf809db4a7fe4, 0x281cc97d0c36,
+    0x99e4193dcf7c, 0x32e698781bc9, 0x45f6f3f3ecbf, 0xbe9781fe7377,
+    0x56ada78f6a18, 0x621cc9ecb74b, 0x9c4ac4451b44, 0x7a42e052bce9,
+    0xb214569d9dc2, 0xb2ab4f587da6, 0x7083abadd91e, 0x22020158b058,
+    0x4a5074ec5221, 0x6d2fff29e368, 0xc6837a07e5b0, 0xaddb60ade5c0,
+    0x03f8a9a2ec53, 0x52b82cd226e2, 0x7a1c0532a524, 0x618a7b25e460,
+    0xa1599bbb5224, 0x4b05d343e0ac, 0xc19208b5e7f2, 0xeb1b447f7adf,
+    0x37869d36ec49, 0x2d4a32f6b88c, 0xa73e70a5b705, 0xc4dcf5d6c374,
+    0x11a4c6ce5d81, 0x3401a57f02a2, 0x0e37a7bbd9a1, 0x3f45172701da,
+    0xad6f9d9e952d, 0x914794745bbb, 0xdcb33f1ba88e, 0x87d400b9f1a4,
+    0xaf849ed00673, 0xc0a1ffcdb40d, 0xb8467de8dbe1, 0xcfde5b59ea98,
+    0xb59b4297788e, 0xa7559ac677d8, 0x8c66103bf5d8, 0x3a69c0670687,
+    0x73dfd1914bcc, 0xdb607a78e573, 0xe5405676f48a, 0x1628b7607619,
+    0x01d26d3c75d2, 0x578a3c2576bc, 0x3a5d84178809, 0xf12422248efc,
+    0xb0e6772c30ca, 0x949491c4d785, 0x68721e419b51, 0xf551e379c9db,
+    0x023dc492b30c, 0xe98d67a4f524, 0xb9e5d779f6be, 0x69705e6c8f8b,
+    0x888ac70afd6c, 0x58a3b591b71b, 0xf3a80d9a9dc9, 0x1e3298719d2b,
+    0x2f8fbcc386b9, 0x7543614f88cd, 0x0bec81c39501, 0x1506216315ec,
+    0x6a572570fc4e, 0xca3d2ff6cb3f, 0xbab4c5d8fdba, 0x2ffad927ef65,
+    0x4f00f4986988, 0x942a1f21cd08, 0x7a666f8ee89f, 0x05ee2c8531c9,
+    0x0fd5cf82050a, 0x2addd436bf2a, 0x02ff2d4a14ef, 0xc79972ece75b,
+    0x1659ad40217d, 0x79525f1471d9, 0xcfab28c242c4, 0x59723e235dde,
+    0xa47315e32452, 0xc0ff1ca92551, 0xbe77d999598e, 0x9c80495390d9,
+    0xa6e162f95bb9, 0x87e1db317b28, 0x1d190bb70848, 0x919c427db493,
+    0xc508b2a4b11f, 0x904b59047052, 0xcbb19e81a573, 0xd1dbac4f13b3,
+    0x089d62e32e58, 0x655344089399, 0x46f3a0c573ef, 0x7b324fd114f2,
+    0x65f05439e20a, 0xf3acc090d42a, 0x1d9a519b4fe7, 0xd88cc62098c5,
+    0x496ff0dde7cf, 0xe3f09d1bcf40, 0xe1d72636d4dd, 0x1c51abb5c99e,
+    0xe1ea31839044, 0x7ac1cd2e8a2c, 0x9c121883cb9b, 0xf9c548211fbe,
+    0x6c2aa7ee75c3, 0x90693bbda342, 0xe7d6424cb1c2, 0x19bd25044ccb,
+    0x9aa3e073855f, 0xa7d9fc08a62b, 0xb2f0cb485210, 0xc8da4dd60047,
+    0x68308b16e2a1, 0xd21827bd0ccb, 0x31e8ddc2b56c, 0x37eb42e74188,
+    0x6e68cefea4db, 0x394e71fe33de, 0x4ed3d9d9ff0a, 0x6a7da616b5f8,
+    0x81cf2576cfae, 0xb5bb235a639d, 0xbda55c28061f, 0x30c49e28b2c2,
+    0x136e09dda43c, 0x84c735ce74df, 0x59588d6aef85, 0x926a41dd5db6,
+    0x0db7d89d566e, 0xe4a537b25a66, 0x89d4d98bb25e, 0x45f53f86bb04,
+    0xd8f417bb42de, 0x1f792e05edd3, 0xc898a1f5a415, 0x2f57511a212c,
+    0x1884a8b56e0f, 0x7bf4127e70ce, 0x00316529dc5a, 0xf9777d6a2f55,
+    0x4f6f42bf83b4, 0xb9a5d19b629f, 0xf758e1249a86, 0xa4bea5d02238,
+    0x558e8936c34e, 0xc96121391b41, 0x4a5a04cd17a3, 0x7d0440a1f1da,
+    0xa2354ca5030d, 0x1b0375df01eb, 0xc1d661c8a970, 0xe730253dbd9d,
+    0x7feb0485dc92, 0xc368e3712696, 0x941edab24072, 0x0a2bf00ecd9b,
+    0x988aff9ec5de, 0xd0cc3b7c7eca, 0x85dabea22929, 0x5eda0fa35763,
+    0x0c45a2486b55, 0x3f780e89aa6e, 0x678d09579651, 0x087da541b5fe,
+    0x3ec709f7f5dd, 0x61a0168ee995, 0x191b3df3c670, 0xdacba9bc166e,
+    0x203965552f0b, 0xe263f77387f7, 0x7cb6f27ffb3f, 0xc3cde3390b27,
+    0xd0f836d71b6d, 0xd38682ad8c4c, 0xee35f9b59f59, 0x28aa3c1cfc50,
+    0x78704a1f088c, 0x6751f05a361f, 0xf1eeab8a5df7, 0x9d8ab849ccd4,
+    0x1e08002ba1a7, 0xae757d44719e, 0x3018e1123450, 0x5338d6da2496,
+    0x9a93b780e60b, 0x93b02e131ef8, 0x41a01c304376, 0xe356e58b14c7,
+    0xd4a45bcb59c5, 0x10fa363b239c, 0xc3f34a883f6f, 0xb1822b5b0c55,
+    0x20a19fa0b514, 0x6d041b19533a, 0xea39d6136420, 0xed49eab19855,
+    0x0853734d7ed5, 0x53ac2195ecc9, 0xb8ef20c7eeb8, 0x1bf3b7a96b18,
+    0x876c7db20f45, 0x3307571b538a, 0x59d9290974d3, 0x8bd3e3d0f810,
+    0x9dbc9f79aec7, 0x483c1d391458, 0x64916276b669, 0xadd9688def7f,
+    0x0998df750138, 0x67d35542a329, 0x399571d534a8, 0x531e99d94255,
+    0x6f219bf8c361, 0xb87a5becc11f, 0xbeddf881e148, 0x4a56d727b36d,
+    0xa0fbc5691755, 0xbb369383abec, 0x0a8e8c40d344, 0xb63aecacd7c7,
+    0xa0d039a3a1e5, 0x9f350723184a, 0xb8f62b7c9dc1, 0x62af324e6f61,
+    0x00e5acbcf7a9, 0x18498759ecae, 0x63be6e5c1c4c, 0x13a06cbb4d12,
+    0xacef2e0216a3, 0x22b7a3c3e0f0, 0x9038a2c8d2c6, 0x2d241ecdbe8b,
+    0xf7537288573d, 0x2bdc45ab650f, 0xc8ca12bd78ca, 0xf7559f087571,
+    0xde8d9cf0380c, 0x87c724908008, 0x8b663ee6d7f1, 0x1a69dc2a0082,
+    0xa0bc7920f0d8, 0x26d1cfd4c536, 0xec1261a0fa15, 0xa53d2d415e0b,
+    0x32d99361286f, 0xb3ee751fe0b6, 0xfbee6e99da06, 0xab07ac753d29,
+    0xea3e3a66a2a6, 0x04e21cf28b77, 0x25294ac4ae03, 0x7730fbfdb00f,
+    0x0a287ada9105, 0xe99d4e8faf2d, 0x339926dabe98, 0xb8b7302847d5,
+    0xe99419abbaa6, 0x82af87cb7bd4, 0x45e8bc4c6c5f, 0xf4237e90ddc4,
+    0x3eaf4e47163b, 0x77d3ff18634b, 0xbbfce95d7b2c, 0xc07f03ec2a78,
+    0x178af797aead, 0xae6d4f2e4b87, 0xb3549aaeecf8, 0xbce89b337c96,
+    0xbb395676d3cb, 0x556ec0e6510f, 0xf58fa9252b37, 0x6ab1828f6012,
+    0x6a739b2a408a, 0xc4b9031c1ef9, 0x30eddeec5773, 0xd62affbc03d2,
+    0x20f566846d20, 0xcbc5a37ac756, 0xcc655c5e5f00, 0x2902d9be56ed,
+    0x7a974163fd21, 0x01bb8aa89c1d, 0x08c6bcbf4554, 0x154ae1acdd6e,
+    0x2e42033e8e22, 0x67c1494cc7ee, 0xe8ec713eabdd, 0xed10e74b2769,
+    0xe5f67f3ee5ea, 0xefa926a07aa6, 0x096466fe77da, 0x81d48c9a3371,
+    0xd1a19bebf685, 0x1030f377e27b, 0x73e7bb8fed52, 0x8ee75823dfb8,
+    0xfe4b22876906, 0x4936d1cb0130, 0x23b72b9a2b48, 0x12a2574a4f2a,
+    0x98d3038fed99, 0x665d6bb130a4, 0x76e8dc3e7d48, 0xaa7f9dc0a531,
+    0xd5a6299d0365, 0xebe16b9fb958, 0xed7736b9b47e, 0x8504e2c432b9,
+    0x902bb51f727c, 0xae8214f38fe0, 0xe6e4bd76b2a1, 0x39e1970ccfde,
+    0x7e6c26699912, 0xb1c8d57af5c9, 0x340eeb0ccc5d, 0x52a80e8c9031,
+    0xe3bffd5418b9, 0x290ed00a382f, 0x59519477689a, 0x9e71bd34ef14,
+    0xec63d6b1ec99, 0xf040b3e05a48, 0x14b284d49427, 0x721679222005,
+    0x7a000242b138, 0x55e842e24acc, 0x03853e343cf6, 0xb2331e08122d,
+    0x3a66f3eb5fdc, 0x6264332a0176, 0x3cd8bdedfb8d, 0xc309803595ff,
+    0x64026ddbb29a, 0x155d145f05c8, 0x58d475a3b23d, 0xd73b7a72dd73,
+    0xf3d599da4825, 0x7e04d970a065, 0x7423b41a6ab3, 0x620dcb4c27e5,
+    0xa37675ede922, 0xf22c23172b9a, 0xfb80012b6fbd, 0x7351510b8b43,
+    0xb4530c452836, 0x0fb7c1eb552d, 0x16ff8383e992, 0x1aa4caefdd42,
+    0x465286fa8d41, 0xabe344a94a90, 0xbdc665d35b51, 0xdda60a924447,
+    0xef77e65cba0a, 0xd1fc178c0cd4, 0x05c3417cffde, 0x6f6d1b4b1442,
+    0x97b7a44b347b, 0xac8e0ec2c1f6, 0x2e9ca4941a8e, 0x2b5f875d6404,
+    0x6d0912a78fca, 0xde0f02504bcc, 0xe63e1547b6ef, 0xe6b41d8f5803,
+    0x26028b9d118b, 0x0b8ffc1ed9b8, 0x26eac79ace26, 0x67bc637fb37c,
+    0xc24f56d635ca, 0xb599790d8c79, 0x59d8e4d24c2b, 0x0905c7a58001,
+    0xdd63918bec7f, 0x19a8ddc01aeb, 0x371f0a9b33e7, 0x0359317955bc,
+    0xbd4c23f27209, 0x931b70204055, 0x1155fe8b103e, 0x5f79bb43f1c0,
+    0xf3f768b237c9, 0xd3ae41dadd53, 0xf2ed6869f016, 0xc123b01f1c0f,
+    0x431e3ac874f2, 0x5c2ffb2abeed, 0x8af775d5a332, 0xc03b6d700ec2,
+    0xd128a08df700, 0xf5f78ae45d12, 0x957f5cd615aa, 0x9cbb65657611,
+    0xca992f194de3, 0x6d8525c89b99, 0xb449aed680ba, 0xf2466644fa6b,
+    0x0e3f49446fdc, 0x059f86e0e45b, 0x05a1d0771e0d, 0xd23cab1cbaa6,
+    0x71cd18475279, 0x964c2da2dafa, 0x87b2cd579a9e, 0xbe6c5dd0e192,
+    0xf7b1ff156d72, 0xb3b065d41672, 0x764d06b8b25a, 0x89f9fce4f76e,
+    0x137fe10ca637, 0x180ecae4989b, 0x97001db26a5f, 0xd2845a5f934f,
+    0x7c21ac838b57, 0xe6b70bc0da56, 0x2baad41c0186, 0xbcddb9594498,
+    0xd40c4f40e794, 0xed71c81d7135, 0x542c59338672, 0x548a88d5c1bf,
+    0x94855a207d7e, 0x46d0deee8bfd, 0x13a07aa06156, 0xdd7eb007f5dc,
+    0x150cab8330b2, 0xf01bc1f06afa, 0x4bed5985523d, 0x41eef423284f,
+    0xcc553747d386, 0xdf6d021d4763, 0x1cdb8f84a6b5, 0x587e9f12ccc9,
+    0x5c60f9a9c4f0, 0xad5f8d5fa819, 0x9b38b739ba59, 0x2caf8c64aef8,
+    0xf5d28ec9d801, 0x47a2aa40600e, 0xb5fecc7ce91a, 0x9c875ef681f5,
+    0xe540af83ca56, 0xd095881a6d90, 0xfd4fd00992fd, 0x56e9a8f88a46,
+    0xd6a1fdd3f788, 0x4d5566d0d568, 0x49b2f41b22f7, 0x02a789532f8c,
+    0x4f0396130cab, 0x041fe1491ef5, 0x302c421415cb, 0xbe6ab1c1d106,
+    0xeeb4d69ac7d0, 0xb3c7a8aa7ac7, 0xf6bfdb4e9f60, 0xfddce4f90a9d,
+    0xcd375e1ffa4a, 0x1d30b7d220a5, 0x272d41a7fcf0, 0xb592b93f6588,
+    0xca636bbf62c6, 0x39c5d6c8c976, 0x74d9c325e18f, 0x076584df2512,
+    0xd290ec4493ca, 0x93dd8dec0285, 0x60ddcc9ba8c4, 0xd0082cc489eb,
+    0x5f30796d499f, 0x539cf1274796, 0xc800c9ecef30, 0x066fa623a7e0,
+    0x6824365108cf, 0x309e17d68e26, 0x75f9362cd76b, 0x760bd36fa6c3,
+    0x44efddbbf791, 0xd2ecb6001779, 0x3c6b19a7e3a7, 0xc228073c1ab4,
+    0xbc249ac55c06, 0xceba203e12bf, 0x318b556d78fd, 0xbbf7ccbbdf76,
+    0xd64781af7d17, 0xbbc0d81b6907, 0xc55b05b5946c, 0x0f9849c915ed,
+    0xc33cfba426a5, 0x41fb866af793, 0x76f768925b78, 0x85338aee65df,
+    0xf15519cebdb2, 0x696a599c8ac5, 0xe406e3f111df, 0x2cdd06e33136,
+    0xda8ae101c093, 0x04d8cdcbc708, 0x08e062e85ddf, 0xc0db2e0bce20,
+    0xef4fda7889d3, 0x205f5173f7b5, 0xcd26234dc3d8, 0xfb09df8831ac,
+    0x1828ecfea2bb, 0x3d53cbec1827, 0xb62095cbf467, 0x9f469b8688aa,
+    0xa63a718cc5e8, 0xbff0590f4266, 0xe7541b6dc4a5, 0x1f460d0dd8c4,
+    0x7f26250d11b3, 0xa7712a3f5cf1, 0x1302197e68ad, 0xc7248c14d0e4,
+    0x8062c9321f43, 0xa677e436a8af, 0x95b3c092b377, 0xb3f5e226b9a5,
+    0xd4477895ca19, 0xb7325e9e3b68, 0xf9ead5651251, 0x7ac07eef9631,
+    0xab2bf357db52, 0x115a8684259b, 0x4031bb308a3a, 0x5fbb5ddef9d8,
+    0x34fd36c6de8f, 0xcce5e5b566cc, 0xec60858655a3, 0x7d96ef26eb43,
+    0xa7f420d46688, 0xab19f085bed8, 0x245ffdf67b36, 0xfd1cd4661684,
+    0xb3c8e9a48033, 0x58e4284de86a, 0xed688df1d1c7, 0x218afcccc2dc,
+    0x383c228a1021, 0xb4ec1b3aff99, 0xd21bc57819d2, 0x5bd55be2e085,
+    0xb18788d64d35, 0xa6a6e173eaf2, 0xcbdaac2b4161, 0xa7f92aaa209a,
+    0x271f54862721, 0x30962389d042, 0x605fd06a1895, 0x8144af2cbe59,
+    0x394941cb18f2, 0x9efae8d8f596, 0x568c05dffc51, 0x682561abe101,
+    0x14d3532b4602, 0xe33eac7dac83, 0xd5cba2ad5fcb, 0x0c9c9e24ef40,
+    0x437a4bcfa64f, 0x722b212b4f65, 0xbfdc1f70dc9c, 0xb2bf01af0d39,
+    0x89b5edaff5c4, 0x14f59caa2587, 0x9c8f2ffe4689, 0xe8e4f22f9d9a,
+    0x39c0fb891990, 0xfb34edd17a63, 0x8af46dc41fc0, 0xf109c8f0e1a4,
+    0x3b98835f575d, 0x73c25b52e743, 0x7b4b973efb7d, 0x95e63d700183,
+    0xc91725ca143c, 0xa074eb31250e, 0x142e944e0820, 0x4421072b612c,
+    0x11c2149bdf66, 0xbbaf1ea49981, 0x2b6507993a12, 0xd1429096c0a1,
+    0x8cd72c22e49d, 0xc51b8ce511c7, 0x6c924f5dea07, 0xd08f166ada58,
+    0x069d09562c67, 0x3a9fd1160547, 0x69199bcf3beb, 0x248377f6487b,
+    0x208fd0eb95db, 0x4c62d606f249, 0x8e425d2a6fab, 0x6c8be81c1744,
+    0x233d894e192a, 0x6326e99810ae, 0xcab3026ecacf, 0xa0a31a82ce29,
+    0xac272acd43e1, 0xca4bdfee8f04, 0x779f57e84926, 0x43edcbf45eb9,
+    0xebe247b1a034, 0x12f3a264f7ac, 0xa668b5f9381b, 0x16372eb73e06,
+    0xc8911cb7b800, 0x7b941595d9a9, 0x98be92fb6b32, 0xe96a84771dcf,
+    0xb40252f7f56f, 0xbe7ac19a0ccd, 0xd46f3ef8cff4, 0xce98cc8f85dc,
+    0x72f8dfad70da, 0x26b053585aac, 0x44907ee0def6, 0x48984e8c7b92,
+    0xa078fdcaf571, 0xbfce548b37d9, 0xf49fc0ca669f, 0xab920f8cbb8d,
+    0x35619b99ac20, 0xaf2ad8406ded, 0x6e236c862f01, 0x27ac0135d079,
+    0xb0a0ba9c2b9e, 0x13d08d14ea6f, 0xa92e50034a99, 0x49d7181d87db,
+    0xc60d759875f2, 0x60cd8d22a94b, 0xe19e52efb7ff, 0x9838649667a2,
+    0x0eddefe3b05d, 0x135f86c94660, 0xa4fd30630212, 0x19ab60bafcc5,
+    0xfa34a4cc3ecc, 0x8e78816cdee3, 0xfe8cb005b6c4, 0x996ca93a0817,
+    0x8900301ac259, 0xc291132db866, 0xffffd3ad2f39, 0xeabc3e2cd3ee,
+    0xa245cec41c44, 0xa865da879a67, 0xb544f3ce2ec4, 0x169df5f77e24,
+    0xaf2f92ad9292, 0x3de828b156bb, 0xba121d75ff85, 0x191628018a2a,
+    0x69156d26270c, 0x95f991526c4c, 0x341f346b45a5, 0x40beaeeaa7d0,
+    0x0942b7072706, 0xbcaaa648350f, 0x7146d8a1a869, 0x5322e92f6a98,
+    0x848568f806d1, 0xae3312213cd8, 0x4e6faf70bf61, 0x3065d3af134a,
+    0xcd811e77b4e4, 0x565fa7ace064, 0x5fcf335adea4, 0xabd24bdb16e5,
+    0x46d47edacda3, 0x60f823fd0dc2, 0x7012a4d9ee69, 0x7e51c07943d1,
+    0xff4b71333bc9, 0x8d4cf242fc4f, 0xd28953ba4473, 0x09dd0235ae52,
+    0x78e6d87e4e74, 0x1e918372d82a, 0x522e720a9bda, 0xd7265e0d5d97,
+    0xb210be00e141, 0x24f56270bb25, 0x5164a3207c2a, 0xbdc1ab42b011,
+    0x993dde2daa6a, 0xc044c1b9063b, 0xd20834511083, 0x4cdbc9480a0f,
+    0xbfe8a180cda4, 0x7ec23e5887d2, 0xae7bf6a84a06, 0x072abf46123e,
+    0x802e6f3b572e, 0xa34345a528c2, 0x44c947751f6b, 0xe314e34bb682,
+    0x46905e25dd4b, 0xf9e0a7d99d0e, 0x12239f2f8761, 0xad396fb0208e,
+    0x99ddd9dcd3af, 0x342038229dcd, 0xb287617ed2c5, 0x8fa72f0b1e73,
+    0x509306e91667, 0x830a6c2be209, 0x1fb457db51f6, 0xb216955f65b2,
+    0x780a7d2d45e1, 0x9f32169cdabd, 0xf8ab39a798fb, 0x26a5c7353a04,
+    0x8d901883f757, 0x53cf07913bee, 0x110c371b53a5, 0x60e8754e8f86,
+    0x9b1e0aa1bfa3, 0x8082cac5a451, 0x42b2bd366c37, 0xf9bc26c8d6a7,
+    0x533cd643534a, 0x09d0f5636ceb, 0x2f0417e4a04f, 0xe48b360238f8,
+    0xf8a0bcf6b8fd, 0x1098d91136ee, 0x714869c9e0d4, 0xc83f769c5bff,
+    0x0c1cb8800be8, 0x1798178fa662, 0xb8542c99c44b, 0x9989f0fe2407,
+    0x1109224d284a, 0x46cb56d5165e, 0x3e4916837322, 0x4f8d77f3dfd5,
+    0x6625d358c1fb, 0x95b7ea76904f, 0xc64815bb5ad7, 0x088774f549a8,
+    0xc33dc0ee73ec, 0x06c1b6ed5db4, 0xa3569736bad5, 0x90cde87e3578,
+    0x9fa5d8c78730, 0xf7d437f7aeb0, 0xf3d56a7abde3, 0x63eb06f8c621,
+    0x990cc4c7cc46, 0x4074585f2bb9, 0x7d9761f22cd5, 0x8547e76b13ac,
+    0x9227845ac152, 0x0fecf4ba073b, 0x1ddffa50d765, 0x7cac851be40e,
+    0xb9be163732ab, 0xf8cd18f8a04b, 0xb2498e0c0ffa, 0x4a6ad2fb254d,
+    0x5439fc308f37, 0x80c7eb34439e, 0x0ec75d3cd037, 0x143c148c6cf9,
+    0x174ed1dcea67, 0xbfa69411f3dc, 0x8ea820702cef, 0x932b0f38f74c,
+    0xd04d4491dd57, 0x61fc0e9b5b7c, 0x054dcfe7b0a3, 0xba0324a17f28,
+    0xdd57073b8d1d, 0xa466091026c0, 0xaa8c7c78466f, 0x9e0815e78915,
+    0xc01468795112, 0x80ee4bad6b82, 0x809df30974a8, 0x33a0dd5c8892,
+    0x316b0930c2c3, 0x5eaee85d5df4, 0xdbf71496462c, 0x1aea62470b6b,
+    0x43a28b6e04fe, 0x1cbc73c8de3b, 0x8ac2b16dd836, 0xc0c9c01f8eeb,
+    0x145f3ae4c22e, 0x33e743aa0969, 0x077978566705, 0x6917ee549452,
+    0x83c25d122871, 0x4eaa33a5f523, 0x9be8e8963999, 0xf6cc4af57d8d,
+    0xc3b56f37dd13, 0x26e0f08a1c89, 0x9548d346c7d7, 0x4d12e56a6b2f,
+    0xcc4da58e78f8, 0xb99478652c77, 0x794ca6d5d466, 0xab03bac9b99a,
+    0xcf3c9e184520, 0xf061e86549e3, 0x1e359a70045e, 0x0e1c90b0eac2,
+    0xba1e680d6110, 0x3f683812344b, 0xc817a230bb45, 0x3bb2deff9efe,
+    0x26f4dfc46d1b, 0x2ff973fddb3a, 0x364f7b9aaaf9, 0x44ca107bdc75,
+    0x65c7c821fb17, 0x4797b9138b7d, 0xd2164284b9c1, 0xaaffac6ecbda,
+    0xe2b86e633d97, 0xd8d11bf76c1e, 0x930a0d656de5, 0xfa48d56689ec,
+    0xb360f53910cb, 0x909a4b55e6a6, 0xa7eb0506f30a, 0x7e76b78d576b,
+    0x22fcfdf805ec, 0xa212b7115b4f, 0x9d5e16819f36, 0x34f9cca27965,
+    0x864ed786e170, 0x693a3a244c8d, 0x516a5949a691, 0x29bb308fb063,
+    0xabeb33694199, 0xb1c46bfdbf31, 0xc32ff982143a, 0xe6f4b78c3a93,
+    0x942521807439, 0x47cba7d3b319, 0x994fd1cebfaa, 0x8e8cc28041af,
+    0x058832024ab2, 0xce8525a7b9fa, 0x2b801b85eda1, 0x4ae6e346f444,
+    0xb2bcde91f5f4, 0x897399978545, 0xd55c63d82607, 0x9f8d16ece157,
+    0x9662e1fda586, 0xcb2e71b83a4d, 0x0c94ae36b816, 0x0c1d9725f62c,
+    0xc582a8b20b9e, 0x6a2b4d75294c, 0x8a48907f1e46, 0x28e2f2877218,
+    0x22e14486e58f, 0x3699fb334ce5, 0x8bcaf90a0b8b, 0x78f55183d820,
+    0x642db6d1b3eb, 0x8de7deb32b42, 0x3e7b4899dd2d, 0x5e9aeb3c07dc,
+    0xfc8d8bbeda9a, 0x8b05dac54986, 0xb86ac77b3f05, 0x20129562a187,
+    0xc836883e7794, 0x95db04104c59, 0x956d295a6d3a, 0xe09f69c711e6,
+    0x8cdca4c6370e, 0x62f33941116f, 0x989fc8b7d76b, 0xcbed2b6ed10e,
+    0x8dece111f710, 0xebc1603e998a, 0x2eebc13ea9ad, 0x4fb9e4b0183f,
+    0xa539e3479965, 0xe8a7e3a4594d, 0x60d38e325bca, 0x29169fa47474,
+    0xc8e2700eec58, 0x0cba59171b8b, 0xec8d1354617c, 0x123302d3f6ea,
+    0xeaaabd13d58e, 0x57376f6cb588, 0xcc4a630f851a, 0x006cad5d6c6d,
+    0x3cb5b883f32c, 0x3e8fe8a9ab69, 0x80dcce50dce5, 0x0b49802e4923,
+    0x938232581dba, 0x9012fcc777a9, 0xfda4e9241471, 0x08b0a790af63,
+    0x7013ce6660c5, 0xcee426aed380, 0xbf75d04197b5, 0xceb5842d28c8,
+    0xea23c126079f, 0xdb0237e550a5, 0x71843ad08242, 0x9ab706e27e91,
+    0x92e6bd386407, 0x611ba5936d62, 0xc163d1c5e774, 0xae2966f4df2a,
+    0xf1d096fd4174, 0xd3f948b3906e, 0x83ff30c94b05, 0xec805bfb2454,
+    0x4ef52eec5df2, 0x27e34204df72, 0x7f2fa8b1b0ea, 0xea3e09dcbac7,
+    0xccf3de47f18e, 0x2ebfef759701, 0x4ad712bf13c5, 0x76311dc67262,
+    0x54f03be1af85, 0x7b4e89ad6f31, 0xc1b82ced65ce, 0xc490ba542dc3,
+    0x8ddb4f603ced, 0x04f12323d972, 0x4b3c5a15d0b6, 0xebf4eabc9c11,
+    0x4bb7bb38f280, 0xbf4ce0f43675, 0xdabda21254c3, 0x2e187f46b547,
+    0xdd7a895650df, 0xfad977371960, 0xba7314ccb834, 0x9d6b8fcd79ed,
+    0xa25beba50623, 0x663905403528, 0xde6106dddb55, 0x3585a7cbaf2b,
+    0x62c920c81b5a, 0xe56d57711f7c, 0x16f4c12e589d, 0x7d20c1aa55b0,
+    0x8e4a7cd33ad8, 0x410aec94bbd6, 0x86d669c623b1, 0x157585c46b6c,
+    0x6212a644ca95, 0x2766346fde53, 0x6ac598821b3f, 0xd902ef54956b,
+    0x4ae9aba16b21, 0xda208609a012, 0xf58eb477712a, 0xcb6a44ce7b01,
+    0xad14fb9d3b80, 0xa245ccb28bbc, 0xa57be8998b6e, 0xf933edbb25e4,
+    0xa5a05d701ccc, 0x28a85e9cd0fa, 0x2962cbf02f25, 0x01b02649d4ad,
+    0x3d60732b29e8, 0x683ddb2e2e64, 0x882c483b925c, 0x5fbf093de1b3,
+    0x20a22fe0fe69, 0x95cf19bb756e, 0x4660660fe5ad, 0xcee786c655bc,
+    0x04e32cffda3b, 0x8115d2ff5e43, 0x030bb47a47bf, 0xdad08dd6869d,
+    0x0c9c13856951, 0x290280a9bc19, 0x3337c50d2f3c, 0x52c03bdc6286,
+    0x9ac9df585be7, 0xf7e666cbd1ee, 0xc9e96406cdf6, 0xd1c71e1c2f28,
+    0x1e3001396eda, 0x548710e9fb26, 0xb04df72aafc6, 0x36c049c7c610,
+    0xa9ba0828c07f, 0x408ecac70cef, 0xf8e45ea25036, 0x0e9e4cfb4e5d,
+    0x4acf7f7c6f55, 0xf6aef8807676, 0xc44018d75cd5, 0xd5cd0becfc38,
+    0x5a689cc5b0d0, 0x502163b86029, 0xf8a33cb98f36, 0x8cc1edc26d25,
+    0x182953f08ccb, 0x0540a8948c3d, 0x68a6ec04a834, 0xed1c49f36812,
+    0x86eba0abca61, 0xcceab30a2ea8, 0x082e6e2afc96, 0x2e8913065ff2,
+    0xd479bcefcf22, 0x56259769674d, 0x66337ab642bc, 0xfbe3d153c5b3,
+    0x7e1d179c592f, 0xa61af7fcfe59, 0x57753f644200, 0x77d387a3ab45,
+    0x1e4200d37fb3, 0x50253a9abe6c, 0xd975737a1d61, 0x456833d4d276,
+    0x7fcaef396f2f, 0xef6c7c750e43, 0x757ef6fbb31e, 0x4ae978ca8d10,
+    0xb318856b4f1c, 0xeae125ee4af5, 0x2045cbf6014c, 0x9b469d006377,
+    0x5b750e47b23f, 0xfa0c8597c8cf, 0x159526d23b28, 0x5541827c3bf4,
+    0x1699f32edab0, 0x64f17fa3be63, 0x6528572e1954, 0xeb92a83d65af,
+    0x6305b9f03829, 0xde3868c58416, 0x807bc9b535a7, 0x8da02accafeb,
+    0x1638647bf429, 0x19cc5f742d60, 0x5b0157d2d6b3, 0xe443fbb9e53e,
+    0xf4e5275a55fd, 0x34fabc02b4c5, 0x7e859450a20a, 0x88618ccf4f0c,
+    0xace2b8a8f9be, 0xc6423073df9e, 0x0b029e93def0, 0xed9f0070878d,
+    0x4cb9009e3c12, 0xd9cd27c693d5, 0xec03a962f430, 0x86ccacb7088c,
+    0x2ab90fae99e7, 0x800afdf8975d, 0x1c957d1bf4da, 0x2db7039ef892,
+    0x302de47720f0, 0x40cb2c982f9a, 0x58fce6056d33, 0x56792758f3fe,
+    0x55d16d4a612e, 0x2800313ca734, 0x28e2733cdf89, 0x3943be72f8d6,
+    0x8d0f17fb6237, 0x4db6c55ca2d9, 0xf48dc0c9a479, 0x462bb55b2f40,
+    0x6ac88757a5cb, 0xca084d2b008d, 0x5b09d13253ab, 0xb6d6a9f99e4d,
+    0xf892476691de, 0x6b5d213fed8c, 0x1bab8c6fcd14, 0xe5f85b282e90,
+    0xe6f95a076806, 0x8ae547e0edd7, 0x9f8bcd92c97a, 0xca36db35ea66,
+    0xd26c3144ed53, 0x95f9675a3108, 0xc9b38772db35, 0x16aa02c5dd07,
+    0xbf4df10567bd, 0x4810b69edd1d, 0xdef10db953c9, 0xb6e6918a51d1,
+    0x736b9d57450f, 0xed2159f60eb4, 0x034b14f60469, 0x1bcdc8293c6c,
+    0x99738a594aa5, 0x570bfecd6067, 0x01dc3bc3ba9d, 0xa6ce134e0e8b,
+    0xb43bbd2ebb12, 0xf7c4aced1609, 0xf007eac1e1eb, 0x21fb70bec806,
+    0xe74095b77049, 0xa44bb45e86bd, 0x9adc04237ebb, 0x69339b67f90b,
+    0x1ab9b811946f, 0x55294a366284, 0x11c511548cf4, 0x0271109dd6ec,
+    0x5e2d03219617, 0xe0aa7b5dcf29, 0x4a0f910416ef, 0xf9d0a666f729,
+    0x7f94ed52d51a, 0x9c332ac8a7a6, 0x6418adba8893, 0xfafa88bc2b9f,
+    0x887b1cb53224, 0x3a5d6ffbd0eb, 0xab78467d95f7, 0xd8ce54e31471,
+    0xd736cb52e726, 0x98db34f7efa2, 0xaa48bb817c95, 0x6c5d076ca9d9,
+    0x943cb60e556f, 0xed0edd429feb, 0xe48c27d2f125, 0x484832c480af,
+    0x4b6f61cffb9d, 0x7ea85a42e604, 0x14d4d03ba9d3, 0xbf4e20e5739d,
+    0x6c61ce76d250, 0xcfa79691bb01, 0x49ba7b1dbe98, 0x49981cc54f6b,
+    0xa6c3cba34ffb, 0xd4c076c332b0, 0x630881e7e03d, 0x3ce67a83f83b,
+    0xaf6ce12957dc, 0x4ec54aea586a, 0x10eba1e42dfe, 0x54dc0c2ab4cc,
+    0x4394faf3df65, 0x9f3c7bbca110, 0xfd9c7529f535, 0x09623342d10c,
+    0xdd453d281f50, 0xb55dcefe9dd4, 0xce405a484746, 0x01d9395cb0db,
+    0x074729eb9c8a, 0x79fb9904c2ce, 0xc6b6430df880, 0x0fb2ffdfd925,
+    0xee8b737388f4, 0x0c295ef98017, 0x71189e3a541c, 0x17d34374a9cb,
+    0x58f2bb4f34c8, 0x7abd42fc7b28, 0xc5fb2de74613, 0xdf79e63a465d,
+    0x7bd4720ce443, 0x25be1bdc86c9, 0x124bbc2cf4f9, 0x480a0d3580f8,
+    0x6264e1873d4b, 0x26220aed4097, 0x31f865ccc598, 0x8e7284963b41,
+    0x48e6050a0208, 0x8e0a2748e69a, 0x6b2c19de6238, 0x36c412a18974,
+    0x99d86384862e, 0x33cad6cd76b8, 0xf457fb601a16, 0x42554a3d3250,
+    0x1c6e3f862c94, 0x0d06c5db9ddf, 0x659f48463a6f, 0x13c6487cbfa3,
+    0x2283a88085d3, 0xe409acebae71, 0x4d324912070c, 0xa3e4069cae0c,
+    0x0b660ede6a51, 0xf09ebf30f17d, 0x1e82e1fe5d1c, 0x9be8d24f21a2,
+    0xc916d8efae71, 0x5a594c2a79d7, 0x7f5bcbaddc55, 0x470258717ce4,
+    0x88a93b095f71, 0x18a08b4d8492, 0x3fb6289c4da6, 0xbfeb0365f98b,
+    0x31f17ccfe379, 0xb52a369995c7, 0xb7d221028cd6, 0x82d76b1d340e,
+    0x831559f941da, 0x20c44c945714, 0x2f1b324e0484, 0x50dcce3169e1,
+    0x357adcd8b4d9, 0xefe86ac5e59e, 0x90a14bfdb2c1, 0x647b458c1640,
+    0xc0e7b95b3f7c, 0x2543009b6ce2, 0x51151b7ab23c, 0xb3618e9f0a23,
+    0xcb0aa9f8c594, 0x43f73fe39294, 0x8baa82330316, 0x48f9fe6ee85d,
+    0x4577bdf455ae, 0xed07fa72aa5c, 0x4e06c67b1c87, 0x5367972fc356,
+    0x5996bbd2be43, 0x63e463fb9eba, 0xa0a953ee608c, 0xcc8d9e6957f8,
+    0xe764838b4764, 0x051f57781180, 0x40fe63f12cf1, 0x5aed7b1f4bd1,
+    0x1bdfbaa1e551, 0x649f3fbbb7bd, 0x5282ad42df5a, 0x6887b8dd3dec,
+    0x4f1762a889a2, 0xa0199f4ec91b, 0xbc253ca72962, 0xe68ffbd606cd,
+    0xb18eb2481473, 0x2139414f908d, 0x20152d56b720, 0xeeb3adcc2dfe,
+    0x57b39280839f, 0xafa3b9ba89cf, 0xd630ec0de83c, 0x946c8edb0bae,
+    0x57989c0ac915, 0xcbf3055d7663, 0xcc522dc6d683, 0x6c7faabfedfe,
+    0x7f78b8b660ba, 0x9e486aa66bfb, 0x79d0bd2d981d, 0xd8973f7012c1,
+    0xac302c5213b1, 0xb2362465001a, 0x60c3053a88c2, 0xd018db89b21f,
+    0x791af2ba2770, 0xa2674e70cd67, 0xeeb575a75854, 0x18e212e21072,
+    0x4c987408624c, 0x2dfebe547770, 0x2c9026c552e4, 0x8869d5ea0019,
+    0x8f380ad7a0dc, 0xb609a236bc60, 0x9296b28f7a4b, 0x085408f325e6,
+    0xea613913b2c9, 0x42eda3590bd4, 0x399632566764, 0xb7bb05ae1332,
+    0xcf5558b953a1, 0x8423f3fdb84a, 0x4fd93443c206, 0x3088c8b7e30e,
+    0xd55591c09052, 0x90e14896f2c6, 0x3152f00cc193, 0x194bb3fea75d,
+    0x2e283589d49c, 0x77c1770a2d5c, 0xa6de94a97b72, 0xf9daa8908b5d,
+    0x4fc69edaafbb, 0x0e54dccde43e, 0x1d107e5b7849, 0x01d317db50a2,
+    0xd2b835472e7f, 0x2c082bc1de39, 0x3663113dedd7, 0x996d9aa60039,
+    0x2b770fee671d, 0xb21cbd29740e, 0x84858c7865cb, 0xceba023af090,
+    0xe9b6a58b97f5, 0x340e726db6ba, 0xfab58bb03877, 0x2431b62a9443,
+    0x02dcb31bd5ae, 0x692ed0e29a41, 0x11991a58770b, 0xf359cddd80e5,
+    0xd57934addf55, 0x77a8155785a7, 0x1487e6de4b2d, 0x21aaf0129117,
+    0x136d2542508e, 0xab96904c3992, 0xb7e0ce6a2fb7, 0x7886323536a7,
+    0x9731bfdfb252, 0x89ab188e832b, 0x5363d8e1733c, 0x7c865fd1e7a4,
+    0x55f560012325, 0x0b865424b3e5, 0x5d6121523d5e, 0x2292b040f176,
+    0x34d6b0d3a638, 0x6fd6db85a0e9, 0x67d2f59a6a78, 0xccf5c2914ca5,
+    0x4edbf181a40b, 0xb095656c7a26, 0xe6051c474170, 0x5cb968094820,
+    0x106a14f380df, 0x248a1a241c06, 0xecec318f4955, 0xb91b982b5bff,
+    0x58f9ae3381f8, 0xf81ae14e180e, 0x45b22aff2312, 0x7368117b12fb,
+    0x48983ed32db2, 0x9464fc5e04d0, 0xacc56eb1b7ed, 0x04c5e99853c3,
+    0x848dd3e8379d, 0xc39015eb6fe4, 0x903316abe02e, 0xa893f6a6b091,
+    0xc8f3812a20f6, 0x8216613eb700, 0x1c98b5f0d493, 0x0cd5e8d13a14,
+    0x26349aed12a2, 0x595887f9ea3d, 0x9ead87c5a57a, 0x8ae868c5c7a0,
+    0x93a97b1b7041, 0xd48d29231d62, 0x737943298aac, 0xf174e9f127f6,
+    0x16373f8d50b5, 0x078c33b24659, 0xf435a6297868, 0xf69ee37e429d,
+    0x2f75593dda7f, 0x7920fd71fcd2, 0x39dbbd341856, 0xdc2e12763cef,
+    0x3fb6e042c019, 0x0e56c0125995, 0xfddbf3c2a45f, 0x056963bf1c76,
+    0x4227c78032fa, 0x2c560081cf0c, 0x19f000b2baa4, 0xe696cef09100,
+    0x3f179d42d18b, 0xec5a6273e680, 0x55bd8da0041b, 0xb879a1675b8a,
+    0x5277849f6c8e, 0x00af0b9a9ee9, 0xc49034b92809, 0x52499941c169,
+    0x702bf85e8362, 0xbed301c6549a, 0x64fe8b468a92, 0x0ae1e23d9d61,
+    0x8998e094afea, 0x0a1ad1a4f424, 0xe6b4f2ea1a67, 0xb5d4b1074d0e,
+    0x78f5d967f188, 0x856b3d1b2e1b, 0xb60ab4578cee, 0xa58044085361,
+    0x2d129406c982, 0xe85886774c41, 0x591c5aaa84de, 0x68a96df6c1ca,
+    0xa5bef8936e7f, 0x539473e3f1bc, 0xec1247152bb5, 0xb930a8f8bdc9,
+    0x13ffc53e2d7a, 0xfe28f5f54a9e, 0x2dc566c22a52, 0x822293420a62,
+    0x53569b6caebc, 0xea72e0e70d06, 0xa3660839e93e, 0x1583aa9c27a4,
+    0xf86aad2536de, 0x634b7a34e810, 0x1afbc938fb76, 0x013c21b282e4,
+    0x4fe8be84da97, 0x6cf3a35a61aa, 0xe77287d5944f, 0xfdc7c786dff9,
+    0x91d5be502a77, 0x1d11cbd0ec2e, 0x13664337f891, 0x9e3aceb9a02d,
+    0xf78ae2ad3a2e, 0x295c536bc4a0, 0x2cb2dc1ce9d9, 0xf4141998b63d,
+    0xd4cf07f9fbbc, 0x7866c83b365d, 0xdae4ba0e687f, 0x78f26e7fe00e,
+    0xe542e1e7d6a2, 0xa4509795a685, 0x87b828196039, 0x7ce95fc1ae40,
+    0x5392d65feeef, 0xd3202a331f85, 0xbb1778c8e6ae, 0xc2a84124b94d,
+    0x0157d976d2c4, 0x640f10f7a34b, 0x4a5abc4a2fb5, 0xa82b83fb6820,
+    0xda2b05077642, 0x1821724a38ae, 0xaafa4a4a1486, 0x374e2f116263,
+    0x1f87991cacf1, 0xbb7534d85195, 0x09c358ee5a77, 0x960ece1df256,
+    0xdad6ed3ab9cf, 0x53c173bb8419, 0x9dda6efc6c42, 0x4e5366167cec,
+    0x9e6ff9d21bc9, 0xdd3b6bc1e763, 0x2b1d0d054580, 0x787610352175,
+    0x574d48fbb868, 0x9a283b93e8c5, 0x684a4c1d1d03, 0x027af75c2edd,
+    0xa5aaf3fb2a23, 0xbdfcc9a18b7b, 0xa2dc44114889, 0x39f5ef646b59,
+    0xe374a1bf7dbe, 0xc415f18e3d0d, 0x4d4eb0e445c2, 0xaee52554a920,
+    0xf603dd0c7d32, 0x20a19ed16138, 0x0d3246f02828, 0x0a88b412faf7,
+    0x303a0c6d9164, 0x72612c6f1ca3, 0x69aa41133caa, 0x6c3f2f1d54da,
+    0x793c72abf487, 0x55ec40793126, 0x6747d258e095, 0xbf784f7e188a,
+    0x5d91986bba55, 0xac25bb76a8b1, 0x8ccd385b03c7, 0x6859d4f48fc9,
+    0x8dd5e8370564, 0xdb67e0837df6, 0x4ce40477d312, 0xb4b18916ff49,
+    0xb18234537f73, 0x8eedfad234e1, 0x0113c34f46f8, 0x294fea311b31,
+    0x4ef01aa500e7, 0xbb9379c40a54, 0xf362a678b17b, 0x776d471f1834,
+    0xa604a9ec3320, 0x31ac991d9c0c, 0x7598b951c2fa, 0xbddf4b6bb710,
+    0xbfdb90cfcaf6, 0x3eac10a34831, 0xace18a750230, 0x2a8377b0bc13,
+    0xab0dbadfcc04, 0x03647265e6f7, 0x80d44747b153, 0x81909f1539a9,
+    0xa4d99ce02eb1, 0x51a618267d52, 0x5536c94a69c0, 0xe257349bfd0e,
+    0x5e36813d50f7, 0x34e733ba4e43, 0x92f72c28ccd1, 0xa53a58816682,
+    0x802bb24db2c0, 0xc4e702213721, 0x68d2b56bb72d, 0xd896755e9c31,
+    0xdb814f7731b6, 0xa630a60c6d55, 0x72d76d362436, 0x269b67103d9c,
+    0x98c5639db390, 0x5894c27d9e3b, 0x16437d24dfa5, 0xec48a101c494,
+    0xd9f7ac98c2f8, 0xafb7fd5ffec1, 0x7bc35d2f6c1c, 0x158325ea4617,
+    0x981458bd7d16, 0x414928a447ec, 0x348b57d8e26b, 0x7d26018925ae,
+    0x98b45abb2844, 0x85967997b235, 0x041f4314374c, 0x265c8220b27c,
+    0x4d5b7a204061, 0xf3b36b79c3e7, 0x325e02f84cb4, 0x086a27c219b2,
+    0xd0c73e59b657, 0xac7c7c634950, 0xd613df5953af, 0xcfc79f376e48,
+    0x97fff06777c7, 0xd195581bd379, 0x90bf0bf45f41, 0x9fc6fe197e4f,
+    0x59d2d863f002, 0x3181cb0be188, 0x39c90eb0a27e, 0xa168d3934ccd,
+    0x41cd8732cec0, 0xff9e41166fd0, 0xd2f32808c330, 0xb430c96de319,
+    0x3cefd78bee6a, 0xb3188bf727f1, 0x598c48008112, 0xeda17aa8882d,
+    0x34c174a4635f, 0x7b1446f3f590, 0x48b5baf77195, 0x24adfeb29f07,
+    0x9144fc2f82a2, 0xf7130f0f3871, 0x55b4d3610e33, 0x3465132bd451,
+    0x8583ca91c97d, 0xd6a09006a63a, 0x3c711f94ad3c, 0x1bf7e0df896d,
+    0xfcf4167c3740, 0xab8ceae9f9ab, 0xf789181c7717, 0xd911c0c0fb68,
+    0x99e8441ecd0c, 0x8f9693e980be, 0x0c786a6559f8, 0x4756205a1ab0,
+    0x9ea5bad93cee, 0xb72d3bc7ee1a, 0xdf9ebdc3df28, 0xaf09f83e81c1,
+    0x038431b23e8c, 0x87698947f8d6, 0x34470efc0de7, 0x38b2a8c88bc6,
+    0xd8ba2d03e583, 0xdf5733d3add3, 0xfdc07a04ae13, 0x04d6825f6278,
+    0x9e3dc4e483a4, 0x5b7818a43aa1, 0xf98eb8df9333, 0x5e4c0bca6fb7,
+    0xa8f16411325b, 0x43641c9d88a4, 0xb7ed03e4d6e8, 0x9ff8408c8248,
+    0x661a64abde2d, 0x950778f870a9, 0xaa20ff2394a0, 0xa4fa745a9f4d,
+    0x0a7a2024a7e8, 0x6e9c3f302130, 0x1fddadb3ebf9, 0xf39c58c4a4e2,
+    0xba0e84f6f5d9, 0x2e46d90b477e, 0x6abf034872c3, 0xcfddd6bdf6d8,
+    0xeda0d83c82e9, 0xb2e6c2e4387e, 0x2bf040d309ae, 0xe83c32f6568d,
+    0xb3f732aadeca, 0xa4f6a868745f, 0x81a8bfcbeab0, 0xcce9a3fa009c,
+    0xf4af6378243a, 0xe0c2e4e977c0, 0x0293f1b449f3, 0x623a7aebd0f9,
+    0x88b7e4b2a14d, 0xd174e5a73442, 0xd3e892ad0bda, 0xc2cfcc52bfe8,
+    0xc51a12398337, 0xd7547ecc33eb, 0xe8caa08ea019, 0xf929fa366fc0,
+    0x9b409ba7b280, 0x27270be95040, 0x760d638751e2, 0x7dc633a8162c,
+    0x1ebfac6e3a54, 0xa685aeafc85f, 0xfbbc55f30983, 0xb34a5529bb7b,
+    0xf46f4a6cd0f0, 0x1e7012653151, 0x997de5d0ee15, 0x957bd593cffa,
+    0x6c2fd1e51a0f, 0x46cd893922aa, 0xbb9b85393f54, 0xc994cde7a748,
+    0xcf3af55b1086, 0x6a6ff061c81a, 0x717ff033ea7a, 0xf354a6ac8f61,
+    0x6c2c29ba5b51, 0xaad4c0bcbdf0, 0x4662a88c2c1e, 0xe05b8af0ac78,
+    0x3d625750fe64, 0x0ae6ccebab6d, 0x72249c25a2a1, 0xa223781c0fc3,
+    0xd14c823f7821, 0x69b625bba8a3, 0x77ddc7d1a7cb, 0x864416fbd3cd,
+    0xef17854bce21, 0x91ed77cde438, 0xf32747fa26ed, 0x1b40d5f48634,
+    0x9c0b6eabf921, 0xdcd78d2853ff, 0x1b6af4379a10, 0xd04fa9ab325b,
+    0x04e611c69097, 0xbdade7802e5b, 0x7aa4aa6e4352, 0xc6d4063abf5f,
+    0x8b8c7d9c8b5f, 0x3a7dfccd105b, 0xe5976c8aa0d1, 0x4468a7fb0b25,
+    0x57ef6ada8cb6, 0x4f3051eceac8, 0x0b5585a36c97, 0xb4e1db81dd25,
+    0x750afb64997b, 0x8568371758ad, 0xe24bd7f93fd6, 0xd18ba65fba72,
+    0xc3321f6ab20d, 0x4b40c23f4f50, 0xa0da83f66068, 0x0ec932100820,
+    0xa106942a8cb3, 0x97b2f81c3c99, 0xb335563f1728, 0xc295e49beb08,
+    0x8d453b3126a7, 0xc3533ca78675, 0xc1a966658b9a, 0xbd05b32d7a73,
+    0x3e377d5305b3, 0x146cfab0d93b, 0x7904a56ef9c9, 0xd531475ed952,
+    0x23c68f34e487, 0xc66a02ca0d4c, 0xc2f239777644, 0xf41521efce8f,
+    0x1ef623f552d3, 0xa29499d0fed3, 0xe26eb5378187, 0x2445337b0268,
+    0x9a6c21f91b86, 0xaa17bf0c4212, 0x6c07d826d201, 0x67ee72ed6768,
+    0x92ddf427532a, 0x2d392b509626, 0x2ee27de977b8, 0x76fc5f100abc,
+    0x085facbc18ef, 0x7900bc03e494, 0xd250c8809051, 0xbe87336a5a91,
+    0x637b0b70d4ad, 0xcbce26552a4c, 0x509c44a5d9f5, 0x6716f96bca14,
+    0x0b5d1d662d1a, 0x6bbf22ba3321, 0x9987cb05fc24, 0x9ec159a4be9a,
+    0xca8d565c44b5, 0xc58de91c29f9, 0x29590ced364d, 0x08862aae2b1a,
+    0x99f55d676551, 0xd3ff49df0480, 0x7316da0bba00, 0x39864f00b3ff,
+    0x9d2b7a140295, 0xdb5fdda9c111, 0x0c16855562a0, 0xc300a685f599,
+    0x339dfe52572f, 0xd8563889eb75, 0x4a5a9b75b332, 0xd53c9762a176,
+    0x6a6992c626f8, 0xb43feee87273, 0x5baaf0e12871, 0xbd247ceab8d6,
+    0x69aab7362df4, 0xa403537abf12, 0xa51905b81b48, 0x5cefe72501b8,
+    0xd02b114e6e3d, 0x4eea02a83cf6, 0xbafdc9782cde, 0xd973fc6b5a7d,
+    0xb2b36865e5ee, 0x19ad90cc3f19, 0x1e4f61011459, 0xbd2f20dc4a15,
+    0xd6cc718141be, 0x9a636f116fcb, 0xc6fab786227b, 0xf7431de8e720,
+    0x7cf376c2e0da, 0x9bbcde24660d, 0x15a637044b99, 0x98a81943a2f6,
+    0xc5846f5d0491, 0x017a964366bb, 0xab132936f359, 0x34efb0911f0a,
+    0xccd6a1097522, 0xd367723b0fa6, 0x5967a00fe6f1, 0x960a6c393f5b,
+    0x7f43f972ba0a, 0xa4f81795b52e, 0x60fa02b7b0bc, 0x6b6ae4ea09b5,
+    0xad6177efc011, 0x1b9507474fe5, 0x7929ca96a4af, 0x6215a0678379,
+    0xe9050412dff3, 0x25352053f1d1, 0x9e9539fe51a5, 0xf35c1c11897f,
+    0xfd70cf1dcd59, 0x143e4b0dc68f, 0x13772473214f, 0x61e631e84986,
+    0x3abe395ca523, 0xf1d8eefffb43, 0x36188d1f5c12, 0xa4c9b12e4b5f,
+    0x59ff51dcd055, 0xb310262547ab, 0x8571d6ab9849, 0x0ddeb0369054,
+    0xde944f6fba24, 0xfa769bab0225, 0x013d4428e483, 0xdb606008d8a6,
+    0x1bb6eb5b9dfa, 0x03ffb04c595b, 0x651bfe66b3cb, 0x723af8179f9a,
+    0x856257febb46, 0x16af3aea940f, 0xf88175725e27, 0x0c35aef44e1c,
+    0xdffb36c7b41b, 0x116ce571c921, 0x3b422141e549, 0x6ef847d9315e,
+    0x351c38f9cd27, 0xa6353b03640b, 0x1b0afe0244df, 0xc5c127480d5b,
+    0xae196434550d, 0xaae005eff6d6, 0x32525ea521c3, 0x5ebfcadcb4a0,
+    0x1a3099c86a49, 0xce275ba98485, 0x2f9aba9d7923, 0xae049b99d3dc,
+    0x4fbf0eaa9f0f, 0x236ff1aaf00d, 0x70cff62a82da, 0xd2c7c6988407,
+    0x7082f5e5e688, 0x6a65252d9458, 0x62eb4426f0f1, 0xcbb7177c1f21,
+    0x25ae94ed4a2a, 0x3ec8cbfb699e, 0x816f309f78bc, 0xcdb6148058c8,
+    0x26ddc8a71112, 0x39d9de6bdc8e, 0xaf205fc4ba48, 0xbbc599b29af4,
+    0x3f23fba90938, 0xe421b840892a, 0x88112a7d6189, 0x997bf252a940,
+    0x309cb4f77eeb, 0x55386228d89d, 0x913774d91bca, 0x73cc3bbaf843,
+    0xef293beb6f8d, 0x9d61e7b1bec1, 0x794afc8b7366, 0x2e2f3ce10027,
+    0xfec761e1febf, 0x7062c65e6e45, 0x927ed2f1b69c, 0x6597e1861e61,
+    0x2f5de6405d14, 0xc14d40c412c3, 0xbfb7511d0ddb, 0x5cbc732b6e14,
+    0xed7398297fd5, 0xd9b50c6f6cc4, 0x8a1364b8e427, 0x5ce6d37475f9,
+    0x63c3c22583ff, 0x3e9d4ac358ba, 0x805ea027dc88, 0x0201284495e5,
+    0x5c3e2f8c9ade, 0xf08a5e4c06ac, 0x2647be045c1c, 0xd7163385ccd5,
+    0x6dbbed0f70c2, 0x2373b5a1c895, 0x57b841320397, 0xc080e0b4e101,
+    0x29bf8ff64dc3, 0x45291535af74, 0x92d754c6f76f, 0xddf2823d43a6,
+    0x0429a3f51afc, 0x9f2dfbfe01aa, 0x5455497c1728, 0xa604a7fa46af,
+    0x89d5c9844e6b, 0xaccc1d9e24a3, 0x4ba62581ca22, 0x735b9153581a,
+    0x4d6af29c4e50, 0xde3413bb2bd9, 0x7bb663ededc0, 0x78ce8a8f9180,
+    0x1c1e8d65b482, 0x574bb9394344, 0xff569fb6ba29, 0x4335a0109c37,
+    0x46157f581ccc, 0xab1ed9c070cf, 0x4f68da26166f, 0x06a445ada59a,
+    0xb716d52314b8, 0xed7f90636b04, 0xd182acce7800, 0xb13dde948b29,
+    0xd42b38bc4168, 0x43aa762a7f0e, 0x068ecd66131a, 0x3b6db89b9647,
+    0xc00c4a2e8424, 0x989f52ec0d5e, 0x7b3cfe20dc96, 0xb6df0d70ef93,
+    0x6d4ac5b00802, 0x98d7d34d6d3a, 0x2b95ad60a044, 0x41abd256a1ce,
+    0x5b6dbc5f3497, 0xb96a4ac7b700, 0x9af9c1a3daa8, 0x4acd56d09ff3,
+    0x98bd182c5607, 0x498cc87adf9c, 0xb78f904794c8, 0x8ad7e9f69919,
+    0xd34d177e186c, 0x146da598b283, 0xbfa2fd7f02db, 0xdeca9bf9b19f,
+    0x5eaabe0b0ac7, 0x03025a7d204c, 0x1e548ac0357f, 0x9ff3455f88ca,
+    0x69837a446de7, 0x26255876381b, 0x4b0929a5a737, 0x63912ea04075,
+    0x8337a3b38709, 0xdd9886270814, 0x1dc5e4e9bee4, 0x7343003ff10c,
+    0xc0f3896772b9, 0x561756fb232f, 0xb8421b741d38, 0xa83a98b7c3da,
+    0xf95b83b1f6b5, 0xfd949ef4560b, 0x9ac7794849e8, 0x0bf64552e16f,
+    0x681bab64045b, 0x52daf273a75c, 0xc1607bcf44b1, 0xef34ce9933f3,
+    0x7cf87e45d0f4, 0xb43ee0e2fded, 0x4c8b776b51b4, 0xeeaa0bdf50a4,
+    0x0c58f3480078, 0x94b3df3d3a7a, 0xbb3e8d6b5970, 0xf737bc82e323,
+    0x0283123fc203, 0xe7e90d30bf8d, 0x52f2d3b867c2, 0xb477d72806e5,
+    0xee4fc0e4e3c7, 0x6490b48880e0, 0xac78083718d3, 0x51e87c2dcd95,
+    0xda765519e0f8, 0xf22eb5571139, 0xcea7ed9fe6f0, 0xd591710b3249,
+    0x6098065bbbad, 0x772ca3311449, 0xbba2029ac0ef, 0x8e9a4548f3a6,
+    0xeb1fbdff8dc6, 0x598147a2a547, 0xa2f6cb706c3c, 0x2474d02b867c,
+    0xd4327018f0b0, 0x488d4e89a988, 0x3b91213b18f5, 0x7e95e8997ae1,
+    0xfd3591f440ec, 0x89b89ce652ca, 0x10edf0fb86ff, 0x7de1168a603e,
+    0x581609fe1667, 0xd769355e7fc8, 0xea7d5e57b6d0, 0x5eef502196e6,
+    0x0a23e9cfc830, 0xb55d5fc48062, 0x93dc87736a10, 0xa08cef4a772d,
+    0x2bf67a81c6c3, 0xfc3611734f7f, 0x40a408fa4782, 0x339b0c6273d7,
+    0xa286fa062de9, 0xff4258941da2, 0xb6de866e3550, 0x31baa8130058,
+    0x4ec6a3289685, 0x5356021e9473, 0xf8902234078d, 0xa5843eac4a0e,
+    0x73e7d0ffec65, 0xd16d40e0bea0, 0xc2f8ef1724ee, 0xf3d2f55948c1,
+    0x1f2d07d795a2, 0x36f0ba8afc16, 0x5b97628d6ab8, 0x69f2afd2624f,
+    0xbdadae3747c6, 0xce249e7e892b, 0xf7d8e9db6683, 0x258d86ff2fd1,
+    0xf7eb62912c19, 0x065b9dc34c35, 0x40180cfe867b, 0xa9a4f751a86b,
+    0xee318ea18611, 0x166730c89d40, 0x18efaea581df, 0xddefb05a4dfd,
+    0x0f85f153dea8, 0xb5c87f3f5f56, 0x91e855a172e9, 0x2a5db99dc5ad,
+    0x396277f093f5, 0xd87048194d61, 0xfbf2758fe4cb, 0x4aa09dc05722,
+    0x59fc39d4a817, 0xdd238a14846e, 0x2e26eb85e0c0, 0x93e3355ce248,
+    0x9c3b5057522d, 0x181c1254c4b7, 0xaa73e73bea8b, 0x172f6f915b87,
+    0xbd296bd7a985, 0x4a5a9ff3de03, 0x1e2c476375cb, 0x2aeaeccbc526,
+    0x0dfb5673e8a9, 0xebbc8c6c219e, 0x1b15fefe551d, 0x401f3dcb7a6a,
+    0x0d5569c96486, 0xaee28a61fc4e, 0xaa0b8b79d108, 0x2981b654ad26,
+    0xe8866a36a003, 0xa3c99df2dd86, 0xf2fbe70c5aa4, 0xbaf91e169796,
+    0x5c5c18f52d3e, 0x1c881c193892, 0x6adfc5a437c3, 0x44c108149c8a,
+    0x939428c4b988, 0x4af91ff866be, 0x3582d87306a0, 0xb5b1fb452248,
+    0x1155a0057022, 0x4901a8b29aca, 0x736dc73b9ad3, 0x5707aaa849d3,
+    0x8a86a21019b4, 0xcca794fc092c, 0x53bd15f22d26, 0xd156dd131358,
+    0x5cbb49fde7fd, 0xf9c5bdd3a971, 0x88f1c9d19041, 0xd5ddc685a828,
+    0x2952cd6c9669, 0x7586b4ee9796, 0x83e4776af21c, 0xc0dfd8551114,
+    0x631bd8d8fc46, 0xc126283dc71f, 0xe3c028a47e76, 0x1403b2c0bc33,
+    0xca5e2d1727e8, 0x032d66f8032d, 0x6a72b416ab07, 0x6d066eca989f,
+    0xcc5303e73679, 0x81a5b189982a, 0x33cc2f4cee43, 0x1ff71bbc8a4a,
+    0xc5c1cce83bbc, 0xc6639be726ce, 0xed2cbc9a6cd9, 0x2127f7ea8754,
+    0x7d72910a5aac, 0x5559f4c300bd, 0x6ec475ea094c, 0x1200ccb43c20,
+    0xf6cd523c0893, 0xa9470c47734d, 0x5f22165a31e6, 0xabbcba2c3fdc,
+    0x33d1e1bb9a29, 0xd5533a5d216b, 0xab04a55cf7eb, 0x5110d8531776,
+    0xdd874e8aec01, 0x37fe44198133, 0x13d69f167130, 0xe1429ae9b4e2,
+    0x1a9fa72c8943, 0x7462721c894b, 0xfda7759bcd8b, 0x4f1bed0c4806,
+    0xc003e5851039, 0x57c9706c1bf7, 0x078860c5cc41, 0xef229d70557b,
+    0x8fd360043d31, 0xa12fbf09e836, 0x87c78ef0079d, 0xe368998919fd,
+    0x64eafe589020, 0xa93f91dc07b8, 0x666b997f6eb8, 0x750c271e5df2,
+    0x7b2d7525e335, 0xcc2274dbc250, 0x5ff9a3088e65, 0x91c2c91f3b64,
+    0xd69f2df30857, 0xc22d623945f9, 0xfe8bd0cdcf90, 0x8c7671ff4f22,
+    0x2bb8e61e25d0, 0x3c065230376e, 0x70121c1ebc1d, 0xf46522c92c49,
+    0xc0eefa77f0b6, 0x9ef1028d8675, 0xd91043227d2b, 0xf3f4481eb177,
+    0x55b691d16c92, 0x6c4a1f7ad58f, 0xe2e35ec525b2, 0xff7a0fcb39d9,
+    0xb8250c0be12c, 0xcda764a26554, 0xe45129f3ec3a, 0xcef94ebc8b75,
+    0x5de5f61f770f, 0xac0ed8fe908f, 0x2e72621741a9, 0x44c88c04a467,
+    0x0f231b567f8a, 0x1918534ba335, 0x699e44f57899, 0xe007f00b18ac,
+    0xb99f831f4d65, 0x9089e1dce6e1, 0xc084e1681169, 0x4c4bec948afd,
+    0xd7102be08b37, 0x419ccbbac002, 0x60cdd9a86912, 0x69d94aa48447,
+    0xedbedcc94456, 0x4bff5b1f784c, 0x5b706218ba5d, 0xb24d4caf960f,
+    0x1856accd2fec, 0x997acbf86ebf, 0x8bbd6a95ceee, 0x08932175765f,
+    0xa952544fe90b, 0x753963d5ceca, 0x51c9c516f8f9, 0x9d5dcf8b2783,
+    0xd8ff7fbf2af9, 0xca9af6821f60, 0x1382f10800bd, 0x9ce26753e63e,
+    0xf5d5e58e6d55, 0xff7a484aa058, 0xf70a8825b0f6, 0xe2d9036943d5,
+    0xf972a8264c96, 0xca50912a5c1a, 0x0480e3a40784, 0xf32ac87b91db,
+    0x9c3128f8c97a, 0x23f92bb67db3, 0x39ef42fe693d, 0x9d993bcd30fc,
+    0x0ebc4757a641, 0x658b786927c7, 0x82d277a28e7e, 0x42c151999958,
+    0xa6bae4d438e3, 0x96f99487ab49, 0xa01393433bbb, 0x8226f0796465,
+    0x17ac6a9bb472, 0x301d0bac24aa, 0xc8d06598a4b5, 0x60499fe82570,
+    0x98633a311bb3, 0x758917bce7aa, 0xcc23ac4f3a32, 0x2b45fe9bba6e,
+    0x6ea6800225ef, 0xda7f4a253391, 0x0de74bacc67b, 0x6ab2c3584615,
+    0xab2d72f09aff, 0x439541f49d59, 0x04d0b43e6d19, 0x8b3169d6c722,
+    0xc35a06fd3e2c, 0x9c283fe28ff6, 0xdd5f2a3251b8, 0xb9f2a22fb12f,
+    0xebc4ee9f44f6, 0x7fd7d7b1125f, 0xbd20ae60a7fc, 0xcfdd00d13784,
+    0x1555a139690e, 0x559086696289, 0x976fff01d22c, 0xd07f95e1feb1,
+    0xd6343512e129, 0x47b6618fc84a, 0xec28203d7d48, 0xac8f27855926,
+    0x623c0b48980c, 0x70864dbcfa6a, 0xefc5db9c815c, 0x552ff0e06f9e,
+    0xab5834a68b21, 0xc3a6fb07a575, 0xc97ec5b6f2b0, 0x5af50fc1dfc2,
+    0xc36696f434ba, 0x339330c96833, 0xa9eee7caa855, 0xe132c64295d7,
+    0xb6ffed673433, 0x2b5513e05cce, 0xcc0285290977, 0x87111c6617fc,
+    0x8e88d14f6bf8, 0xc628deed6e99, 0x7d1e860f1e0f, 0x982db0a098c9,
+    0xfea133f8a7b6, 0x81bbd2055c73, 0x502933ada62f, 0xbf32ddf24e4b,
+    0x5f67eed2126f, 0x87aaf9ed8202, 0x4ad6f89231aa, 0x508c9fdae446,
+    0x709fa64be35e, 0x9812226dd2e0, 0x0edd4e0e1a84, 0xb933f745619d,
+    0x4882ec58f05e, 0x236a447e1b48, 0xff011639aee8, 0xf9811afee9b2,
+    0x7fbbb34c7c3b, 0xb208b132207d, 0x7819fdfbcd73, 0x3e46f85f3486,
+    0x77d3a04da73b, 0xa17982781918, 0x1336b318d0c3, 0xfc44799b0174,
+    0xcced24fdde6a, 0x609ca30ada17, 0x7202d7b3ae6a, 0xbf31c41764c7,
+    0x266b3ab3ee5c, 0x30b506d45b02, 0x4049ae074497, 0xa5120a4e43ca,
+    0xeb13249fbc83, 0xac88b84833fd, 0xb5a9e457734d, 0xc4a900b4ad5b,
+    0xd310d3c605bd, 0x1860f74b3a12, 0x6e4f632f9028, 0xde978d886240,
+    0x619baad2e590, 0xef4f2b5ff6ad, 0x8f3c615bb8eb, 0xcb03de498219,
+    0x87c84e4ea1c4, 0xdc2939c0da27, 0x42d03c43e97d, 0x4e4bb1557ffa,
+    0x8cf815d548ab, 0xd1aad8fafb90, 0x485ca80eb10d, 0x4d8266147993,
+    0x72561e2658ce, 0xa94ad7507a0e, 0xcf71a55bbbbe, 0x954f90d6215b,
+    0x6e87e57ef9f1, 0xd5252f122cae, 0x212304b8e03c, 0x3b003d3f1d9b,
+    0xf50ca9bdb656, 0x6b42680138ba, 0x0bd53a794c1c, 0x9f8f78c856f4,
+    0x28fc0eee8155, 0x43f5b8fbddb9, 0xa54c961bfd29, 0x6d51aa26fa48,
+    0xb3094fab48b8, 0x74c1f790686a, 0xf41104e269f1, 0x1da28b53da51,
+    0x1fc6635ed458, 0xc7227167e617, 0xde359444d4bb, 0x14b5a4817785,
+    0x6ada30cc28f9, 0xfb87d055446b, 0x5dbe88c94700, 0xeb13bc9e0152,
+    0x8526ed42192b, 0x16fcb7be5669, 0xb5bb4c974790, 0x0dc85c3820b9,
+    0x89da0a4d77ad, 0xaed7b5014187, 0xe7594dde4bb3, 0x53d930ede895,
+    0x7a8335a61df8, 0xb18e9b9668ab, 0x156c979c18e5, 0x7911ad103a41,
+    0xc50b401b7c33, 0x03ff537b4ec4, 0xc813b9bee444, 0x6b9e8629d20b,
+    0xc5bf8f471b1f, 0xd18bc38fa641, 0x1ee42d146cea, 0x1ee408876328,
+    0xb44d433ea0d9, 0x069d950ab21d, 0x6e7714b9065b, 0xe7f9e4c3c568,
+    0x7b7893cef829, 0x7a4d43f7621f, 0x791ef36592d0, 0x862882945c4c,
+    0xaa396ca903e6, 0xd5423be47410, 0xfc0d0c82de81, 0xa5b634c62605,
+    0xa2c36f21c294, 0x2213e344d4fd, 0xa157c2adaa13, 0x65d4863e943a,
+    0x020e8893bfe4, 0x8a6598808615, 0xe3877022a460, 0x1273d4c2258d,
+    0xc5c0eaa3948a, 0xaf2a5ca2c111, 0x83b8ac70e2f7, 0x1952e8df324c,
+    0x925391061e7c, 0x440feb8eff85, 0x919bea40c29a, 0xe9ef24da7943,
+    0x53d76a199541, 0xaed99ef70790, 0x199baf27b7a1, 0x48797005c78e,
+    0x8ee40c6fb1c7, 0x9441b0c8f614, 0x0bb70c120240, 0x65f23056cb52,
+    0x304432fad4f9, 0xea5014596627, 0x9c629cf6b88e, 0x5461f4327cb8,
+    0xc89304f1237c, 0x1ea9872578fc, 0xb448654b4974, 0x23583ca58d57,
+    0xc4536556d5e5, 0x104e49312cb6, 0xf9d38d571d1c, 0x4fbc39ccf4b1,
+    0xcc0284617aa3, 0x31e0f978d3d7, 0x925b6a093576, 0xf8fcb494b48f,
+    0x3366fc690fad, 0xfb50c1f66236, 0xb8959455031e, 0x3b7920485725,
+    0xc6f79258afc5, 0xf33dc3f14c8d, 0x416c40ec3d3a, 0x57ee3190e9f6,
+    0x4f57fc194308, 0x7c167818cc52, 0xf65dcd82d5aa, 0x5a68fdc23176,
+    0xe16bf7da50e4, 0x139679ee9bb2, 0xa60043e26dd3, 0x1935f0a34fe0,
+    0xa8c7d5667d17, 0xe9067211e0a1, 0x11dc8af1dfbf, 0xc03e8f4c357d,
+    0x21f65ac2d576, 0x9a698fa3d811, 0xdea3253aa6db, 0x0e595212f706,
+    0x28ee31c15f10, 0x273e7efaca36, 0xeede7815d133, 0x10341c469358,
+    0x33214a0782c8, 0xd44af0ad6c4c, 0x05d286d16c3c, 0x272bd3f28ab1,
+    0x96dba5d38b7e, 0x6baa3c9f02a1, 0x9c4754432bbf, 0x9476e60c0773,
+    0xdf78be09e0fe, 0x3b872e3ac6f3, 0x117ea6ba90ac, 0xcea6a6518bd5,
+    0xcbcbb9b55cf4, 0xf1916dc8f4de, 0xc8117d482144, 0x1ee7a8953a27,
+    0x2e953736aa59, 0x191ffe7a6b11, 0xbf0eaf5ea257, 0xe485bab967cd,
+    0xc00f989eae93, 0x704414ab0623, 0xaa741e0a9e09, 0xbebe7496648d,
+    0x17a639c7b7a6, 0xd272a76703d0, 0x6cc3f9ff40f6, 0xb48b9f7ea139,
+    0x0e7b4765fa59, 0x95aadc0cb00d, 0xe507ce894894, 0x345adc120c7c,
+    0xf394dc203fa6, 0xadaaa272a573, 0xf0abfb6fb0d4, 0xf8f096622891,
+    0x777e942d249c, 0xb7e773a8a5c4, 0xdbb20e37661c, 0xac77256c1c3d,
+    0xb20874918a30, 0x9067ac0b4825, 0x46bd4a7dfbbb, 0x5daa2b83191d,
+    0x88f31d91a38b, 0xbf785391af8a, 0x36ae8ca88352, 0xe9e9d9e40cd1,
+    0x018f364b6fef, 0xa123a46e541e, 0x64aaad6dfc0c, 0x780c6942c537,
+    0xe1e54c25821e, 0x4933f2b52841, 0xfb51f255ba9d, 0xea306c3585ae,
+    0xfb430cf1241d, 0x1505b531d5f4, 0x576379a5a4a3, 0xcb7ff41c4425,
+    0x06141fa4ad5a, 0x6a98dc8efcc8, 0xa6f6dd199a50, 0x5d96c4939340,
+    0x18df93314582, 0x0db92769038a, 0x88ab4d3b0631, 0x87b816cf036f,
+    0x818cc00c3d5d, 0xebdc3656a2e5, 0x7beac5951881, 0xbb68ec648bdb,
+    0x0c3d4c382dee, 0x5adf12c67a73, 0x55ac2dd24d92, 0xfa96dfe2afaf,
+    0x3d6d72668429, 0x6a1a51615132, 0x027cb8fec517, 0x00f8f17c83fd,
+    0xc6ec388b2f65, 0x89ba078da3c0, 0x940d4a97f8f6, 0x93af5cfbb1b2,
+    0xfc00b255b5b1, 0x4ba82805ca8e, 0xb62f0f85a053, 0x278ac6afcfe8,
+    0x82c5a78fcb50, 0x5d71cf8d2daa, 0xfd4c7733fd18, 0x048b20feb874,
+    0x52b57feb4147, 0xfb59499dc23b, 0x7ed5a2c4244e, 0xc5eebffd526a,
+    0x550c20600aed, 0x687af4982ab4, 0xc2a256cf0795, 0xd05f85198fe5,
+    0x88a14f6ff8b7, 0xffaf5c34d112, 0xe59986fcb5eb, 0x788c4e2019bb,
+    0x87d879afcca3, 0x9fb2ad256484, 0xfa1fe9c41d1d, 0x6c41f34ed6e1,
+    0x89a9dbe75ced, 0xd975dc6fff2b, 0xd7a76cca6930, 0xb2e651fb594d,
+    0x7fb9ac9f86f9, 0x465cd3d798e2, 0x484e60708be1, 0x981aaa1341a3,
+    0xeaed56db3d45, 0x895e6f3d6715, 0xb3c9c1033a51, 0xac6e9564a9c7,
+    0x7cf7491b6cc0, 0xc1b5c5ad226c, 0x5d154d00d9eb, 0x5763d3173a7a,
+    0x5656e816b585, 0x5d06c6b852f2, 0x51f4e2ad0a7d, 0xd2bdf0cc0058,
+    0x7ea89f30222e, 0x162e4235e836, 0x13245399304d, 0xf95f69393308,
+    0xb65c1eadde01, 0x1981385b7beb, 0x461253ba92d4, 0xd3b9901e6866,
+    0xb9eec939d122, 0x7bacd8857f49, 0x73689d11e5dc, 0x80f562eda30b,
+    0x360b810e198e, 0xcbfa3ba5b5db, 0xb7e45d0c1493, 0x73e19effa0ba,
+    0x44bbfc296f6c, 0xf2d0f8c83a94, 0x4393047320d3, 0x73f6b953900e,
+    0x590535c3d624, 0xd419f4e01c86, 0x3db20d1c6ae7, 0xb57f96f60e9f,
+    0x7856a25365c5, 0xe97a4e9d0fd1, 0x68e0d5786798, 0x7e8d70df095e,
+    0x674ff592cfe2, 0xa91f1a7ea9df, 0x5f91997e7361, 0xfbdd38758c83,
+    0xc19d3ae83d47, 0x3d9107cf9c6f, 0x55e7cfbe80b2, 0x1517170192f5,
+    0xa896009bcb7b, 0x82a1ec0c7ce2, 0x8800d9bde7ca, 0x5decaf367988,
+    0x55d9d4f8d00f, 0x6c8c7e9d49b4, 0x0ee14202b1c1, 0x52a78e0624b3,
+    0x969acd02ecc0, 0x26c210faa041, 0x71949e40ac09, 0xa2328a81bf01,
+    0xecff7ce6560a, 0x1304fb624963, 0x54fbed840f46, 0xd5280110e3ea,
+    0xe8e3b1009641, 0x96e7098685ad, 0xc86aeee06c40, 0x1d238c4e4261,
+    0x0f6c2bfd4651, 0x617c2f551414, 0xd49c19eeb28b, 0x08864ad10696,
+    0x6bdb41cd6814, 0xef09bf60f349, 0x7ab6db69e4e5, 0xcbe92162dff8,
+    0xbc1afa562102, 0x08941b1dfa57, 0xe983e08d7499, 0xccebf51d165f,
+    0x89075a448e5b, 0x1a18895f854b, 0x5fcb17ab7845, 0x2c4fb394d77a,
+    0x5bb27f980942, 0x88228d6dc5dd, 0x8f7a5998dc64, 0xdac3ca74c3cc,
+    0x6822753366ae, 0x05a1af0cc216, 0x156483a34ad9, 0x296b5f5f6b5a,
+    0xa402eff48145, 0xa3e63391a652, 0x1905b4da93bc, 0xd0468f3f9fa0,
+    0xe84b4e9f68a3, 0xe7c7b1c245ee, 0x3fa9c05ae26b, 0x7750d6bf8a28,
+    0x24decf96ca54, 0xb128704e340b, 0xc57f87982fe8, 0x87bf5faba165,
+    0xb5760bd69ea0, 0x325f4a352c7d, 0x836c38926d62, 0x73f572f04a62,
+    0x865e4eec8e4d, 0xf8bb2983011a, 0x874bf250d95d, 0x3151bed8418a,
+    0x3571672943ed, 0x77878b48167b, 0x0320cf411fe3, 0x85132c58b6d1,
+    0x514e7f78e3c3, 0x0196e9971f8a, 0xa04ba8cce763, 0xb15390bd64eb,
+    0x3189ef6e3d30, 0x5ca85ca6b5f7, 0x563ed564bcd9, 0xb92b0880cbe5,
+    0xc7268c3a5fcc, 0x9cb25d988815, 0xa00cbb3e01a4, 0xc7dcdbe224e6,
+    0x0dce51d14a7f, 0x6efec115a0a7, 0xbe8f1c586ecb, 0x9513d5060a98,
+    0x86d377857cd9, 0x2f1a1236a460, 0x102f8ec0b720, 0x0d55d57ab2d7,
+    0x5c5c6ddee1fa, 0x8cc8a7f6346d, 0x6d9aa239b2e6, 0x968ea3be9814,
+    0x63b8beab5e26, 0xcd9ded2e44dc, 0x060c8949a160, 0x8942cd7921f6,
+    0xb8345ef02bd3, 0x05d85ad45e71, 0xdd5108261c0e, 0xbf8cf0ca4fac,
+    0x1cd1f520c2e8, 0xe4df5b4a8bbe, 0x3d5e55249e21, 0x08aeef98bd45,
+    0x7835705ca5cc, 0x5d008f13615d, 0x57537d17dadc, 0xb80ec73c8789,
+    0xf456d224fb31, 0x3e2092161b15, 0x6caaf995fae3, 0xd52d2505e749,
+    0xda394479299f, 0xb71b73ba6949, 0x4b717ff6bb72, 0x665c9530831e,
+    0x4e69052e5916, 0xbe4e8257a9b7, 0x7aa74b9df6bb, 0x46b3d09b4fb8,
+    0x8953f227c70c, 0x30b8e483cb8a, 0x0ab99a94623c, 0x443003c6978d,
+    0xb6d12d66cb6e, 0xd334e34dc73e, 0xe7b869853024, 0x09d776d2b1c0,
+    0xc2db9a0846a9, 0xaf374e02bd58, 0x2f3dddefdfc8, 0x8c58a6ed23aa,
+    0x41189e701713, 0x20c349717d15, 0x184bc961e904, 0x4cbfcbade9cf,
+    0x5a5a6110fd2d, 0xb697cbcb7de6, 0x904f568bcc9c, 0xb01154abc38b,
+    0xc87158f58a99, 0xac92da9a7773, 0x8fd65cda9d94, 0xd30048bca22d,
+    0xbc7f591ba61a, 0x78a8b2577e07, 0xba202547ba46, 0x7d60c8bf7eef,
+    0xc22e3940d8da, 0x534d96ff3580, 0xc721deecb1d9, 0xd7e7de7fc37d,
+    0x5c1cabf44bd1, 0xcd5c05d7affa, 0xd2cb904fc40c, 0xd1ff9bf9a5a3,
+    0xda71e0a6a345, 0xee3c6ee6cca1, 0xb402d8fe1ef1, 0xbfdb4347a6a8,
+    0x59949ce6f0d3, 0xd69b47556af2, 0x2db8fc92b52a, 0x07598a5ea76e,
+    0x893c6f7ab20b, 0x4debd6859346, 0x19573bce43b7, 0xc29a5ee57e05,
+    0xd4c50a628ee4, 0x32470be20a94, 0xfaa4a6858f4b, 0xb83fefafe64c,
+    0x41a3b00e2bad, 0x7eea68c8b0d5, 0xe4cb277643bb, 0xf62694c8e968,
+    0x2b6ca9f596a4, 0x965b79a4b586, 0xbae10592bc1b, 0x157649f6665f,
+    0x333634b8bd28, 0x4661b2ae40ae, 0x736d5af65e50, 0x82ded6aadd22,
+    0x87d87d6859e2, 0xeb486b3d44b3, 0xe22e5df6a682, 0xf4037c814993,
+    0xe6d1ab615770, 0x28d49892455a, 0x7e83a0a13dcc, 0x7e6d77e6fc6b,
+    0x159aecbef9b0, 0x957439849406, 0x07bd9b272e26, 0xdd439000bc70,
+    0x99c7947b7d1c, 0xbb8c4e2f373c, 0x18bccac8b0f5,
+];
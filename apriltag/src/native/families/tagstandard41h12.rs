@@ -0,0 +1,555 @@
+//! The tagStandard41h12 family, generated from `tagStandard41h12.c` of the AprilTag library.
+//!
+//! Copyright (C) 2013-2016, The Regents of The University of Michigan.
+
+use super::FamilyTable;
+
+pub(crate) static TAG_STANDARD41H12: FamilyTable = FamilyTable {
+    name: "tagStandard41h12",
+    nbits: 41,
+    width_at_border: 5,
+    total_width: 9,
+    reversed_border: true,
+    bit_x: &BIT_X,
+    bit_y: &BIT_Y,
+    codes: &CODES,
+};
+
+#[rustfmt::skip]
+static BIT_X: [i32; 41] = [-2, -1, 0, 1, 2, 3, 4, 5, 1, 2, 6, 6, 6, 6, 6, 6, 6, 6, 3, 3, 6, 5, 4, 3, 2, 1, 0, -1, 3, 2, -2, -2, -2, -2, -2, -2, -2, -2, 1, 1, 2];
+
+#[rustfmt::skip]
+static BIT_Y: [i32; 41] = [-2, -2, -2, -2, -2, -2, -2, -2, 1, 1, -2, -1, 0, 1, 2, 3, 4, 5, 1, 2, 6, 6, 6, 6, 6, 6, 6, 6, 3, 3, 6, 5, 4, 3, 2, 1, 0, -1, 3, 2, 2];
+
+#[rustfmt::skip]
+static CODES: [u64; 2115] = [
+    0x1bd8a64ad10, 0x1bdc4f3b2d5, 0x1bdff82b89a, 0x1be3a11be5f,
+    0x1be74a0c424, 0x1beaf2fc9e9, 0x1bee9becfae, 0x1bf244dd573,
+    0x1bf5edcdb38, 0x1bf996be0fd, 0x1bfd3fae6c2, 0x1c00e89ec87,
+    0x1c04918f24c, 0x1c0be36fdd6, 0x1c0f8c6039b, 0x1c133550960,
+    0x1c16de40f25, 0x1c21d912074, 0x1c258202639, 0x1c292af2bfe,
+    0x1c307cd3788, 0x1c37ceb4312, 0x1c3b77a48d7, 0x1c3f2094e9c,
+    0x1c42c985461, 0x1c4a1b65feb, 0x1c4dc4565b0, 0x1c516d46b75,
+    0x1c55163713a, 0x1c63b9f884e, 0x1c6762e8e13, 0x1c6eb4c999d,
+    0x1c725db9f62, 0x1c7606aa527, 0x1c7d588b0b1, 0x1c81017b676,
+    0x1c84aa6bc3b, 0x1c88535c200, 0x1c8bfc4c7c5, 0x1c96f71d914,
+    0x1c9e48fe49e, 0x1ca1f1eea63, 0x1ca59adf028, 0x1cb095b0177,
+    0x1cb43ea073c, 0x1cbb90812c6, 0x1cc68b52415, 0x1cca34429da,
+    0x1ccddd32f9f, 0x1cd18623564, 0x1cd8d8040ee, 0x1cdc80f46b3,
+    0x1ce029e4c78, 0x1ce77bc5802, 0x1ceb24b5dc7, 0x1ceecda638c,
+    0x1cf27696951, 0x1cf9c8774db, 0x1d086c38bef, 0x1d136709d3e,
+    0x1d1ab8ea8c8, 0x1d25b3bba17, 0x1d295cabfdc, 0x1d30ae8cb66,
+    0x1d3f524e27a, 0x1d42fb3e83f, 0x1d46a42ee04, 0x1d4a4d1f3c9,
+    0x1d519efff53, 0x1d5547f0518, 0x1d5c99d10a2, 0x1d6042c1667,
+    0x1d6794a21f1, 0x1d6ee682d7b, 0x1d763863905, 0x1d7d8a4448f,
+    0x1d813334a54, 0x1d84dc25019, 0x1d8885155de, 0x1d8c2e05ba3,
+    0x1d8fd6f6168, 0x1d9e7ab787c, 0x1da5cc98406, 0x1da975889cb,
+    0x1db0c769555, 0x1db47059b1a, 0x1dbbc23a6a4, 0x1dbf6b2ac69,
+    0x1dca65fbdb8, 0x1dce0eec37d, 0x1ddcb2ada91, 0x1df651402f4,
+    0x1df9fa308b9, 0x1e014c11443, 0x1e223c84830, 0x1e25e574df5,
+    0x1e383226ace, 0x1e3bdb17093, 0x1e4a7ed87a7, 0x1e4e27c8d6c,
+    0x1e51d0b9331, 0x1e592299ebb, 0x1e60747aa45, 0x1e67c65b5cf,
+    0x1e6b6f4bb94, 0x1e766a1cce3, 0x1e9008af546, 0x1e9eac70c5a,
+    0x1ea2556121f, 0x1ead503236e, 0x1eb84b034bd, 0x1edce466e6f,
+    0x1eeb8828583, 0x1eef3118b48, 0x1ef2da0910d, 0x1f0526bade6,
+    0x1f10218bf35, 0x1f1b1c5d084, 0x1f226e3dc0e, 0x1f34baef8e7,
+    0x1f3c0cd0471, 0x1f435eb0ffb, 0x1f55ab62cd4, 0x1f5cfd4385e,
+    0x1f67f8149ad, 0x1f7dedb6c4b, 0x1f88e887d9a, 0x1f8c917835f,
+    0x1f903a68924, 0x1f9b3539a73, 0x1fa9d8fb187, 0x1fd21b4f0fe,
+    0x1fd5c43f6c3, 0x1fe0bf10812, 0x000558741c4, 0x000caa54d4e,
+    0x0013fc358d8, 0x002d9ac813b, 0x0034eca8cc5, 0x0038959928a,
+    0x0043906a3d9, 0x004ae24af63, 0x004e8b3b528, 0x0052342baed,
+    0x00857150bb3, 0x009b66f2e51, 0x00adb3a4b2a, 0x00b8ae75c79,
+    0x00e499ba1b5, 0x00f6e66be8e, 0x010cdc0e12c, 0x01142deecb6,
+    0x01351e620a3, 0x0143c2237b7, 0x017356582b8, 0x017aa838e42,
+    0x0181fa199cc, 0x018cf4eab1b, 0x019446cb6a5, 0x01aa3c6d943,
+    0x01b8e02f057, 0x01bc891f61c, 0x01e4cb73593, 0x022303697a8,
+    0x0231a72aebc, 0x023ca1fc00b, 0x02688d40547, 0x026c3630b0c,
+    0x02738811696, 0x027e82e27e5, 0x0290cf944be, 0x02b568f7e70,
+    0x02b911e8435, 0x02c063c8fbf, 0x02cb5e9a10e, 0x02d2b07ac98,
+    0x02e1543c3ac, 0x02e4fd2c971, 0x02f3a0ee085, 0x031f8c325c1,
+    0x0326de1314b, 0x035a1b38211, 0x03651609360, 0x0368bef9925,
+    0x0373b9caa74, 0x037b0bab5fe, 0x03b1f1c0c89, 0x03b59ab124e,
+    0x03bcec91dd8, 0x03c0958239d, 0x03d68b2463b, 0x03e185f578a,
+    0x03e52ee5d4f, 0x042366dbf64, 0x04320a9d678, 0x0435b38dc3d,
+    0x04395c7e202, 0x0444574f351, 0x0452fb10a65, 0x045a4cf15ef,
+    0x04aad1994dd, 0x04e5609f12d, 0x0506511251a, 0x052e9366491,
+    0x0535e54701b, 0x053d3727ba5, 0x054831f8cf4, 0x0565797bb1c,
+    0x05bd5004594, 0x05c0f8f4b59, 0x05de4077981, 0x05f08d2965a,
+    0x0602d9db333, 0x064c0ca2697, 0x06535e83221, 0x065e5954370,
+    0x0665ab34efa, 0x066cfd15a84, 0x067ba0d7198, 0x0682f2b7d22,
+    0x06953f699fb, 0x069c914a585, 0x06a03a3ab4a, 0x06b286ec823,
+    0x06c87c8eac1, 0x06d720501d5, 0x06f810c35c2, 0x06fbb9b3b87,
+    0x071caa26f74, 0x073648b97d7, 0x0741438a926, 0x0757392cbc4,
+    0x07dea3ea13d, 0x07ed47ab851, 0x0827d6b14a1, 0x082f289202b,
+    0x0832d1825f0, 0x08367a72bb5, 0x083dcc5373f, 0x0886ff1aaa3,
+    0x08a09dad306, 0x08a4469d8cb, 0x08cc88f1842, 0x08d3dad23cc,
+    0x08ded5a351b, 0x08f87435d7e, 0x090717f7492, 0x0915bbb8ba6,
+    0x092f5a4b409, 0x0974e4221a8, 0x09997d85b5a, 0x09a47856ca9,
+    0x09e2b04cebe, 0x0a0e9b913fa, 0x0a36dde5371, 0x0a8b0b7d824,
+    0x0a8eb46dde9, 0x0aa1011fac2, 0x0ac59a83474, 0x0afc8098aff,
+    0x0b24c2eca76, 0x0b2fbdbdbc5, 0x0b3e617f2d9, 0x0b545721577,
+    0x0ba132d8ea0, 0x0c005b424a2, 0x0c3e93386b7, 0x0c5488da955,
+    0x0c5f83abaa4, 0x0c9669c112f, 0x0cdbf397ece, 0x0ce34578a58,
+    0x0cea97595e2, 0x0d426de205a, 0x0dbeddce484, 0x0ddc25512ac,
+    0x0e75dcc04fe, 0x0ebb669729d, 0x0ef99e8d4b2, 0x0f0f942f750,
+    0x0f342d93102, 0x0f6ebc98d52, 0x0fa1f9bde18, 0x0fb09d7f52c,
+    0x0fbf4140c40, 0x0feed575741, 0x10345f4c4e0, 0x1051a6cf308,
+    0x1084e3f43ce, 0x109387b5ae2, 0x109e8286c31, 0x10a5d4677bb,
+    0x10b0cf3890a, 0x10c31bea5e3, 0x10d5689c2bc, 0x10e0636d40b,
+    0x1129963476f, 0x113839f5e83, 0x11a25d305d4, 0x11b4a9e22ad,
+    0x11bbfbc2e37, 0x11dcec36224, 0x11ef38e7efd, 0x11f68ac8a87,
+    0x11fddca9611, 0x12018599bd6, 0x12052e8a19b, 0x12a9e0ca53c,
+    0x13056043579, 0x13523bfaea2, 0x140ce3dd4e1, 0x142dd4508ce,
+    0x14a2f25c16e, 0x14aded2d2bd, 0x14e12a52383, 0x152a5d196e7,
+    0x153557ea836, 0x154052bb985, 0x154b4d8cad4, 0x154ef67d099,
+    0x157ae1c15d5, 0x157e8ab1b9a, 0x15b1c7d6c60, 0x162e37c308a,
+    0x169c03edda0, 0x16ec8895c8e, 0x16f3da76818, 0x171121f9640,
+    0x182af245281, 0x18aeb412235, 0x18b9aee3384, 0x1920292d510,
+    0x195e6123725, 0x19ab3cdb04e, 0x19bd898cd27, 0x19d37f2efc5,
+    0x1a2b55b7a3d, 0x1a6d369e217, 0x1adeabb94f2, 0x1b3dd422af4,
+    0x1ba7f75d245, 0x1bb2f22e394, 0x1c15c387f5b, 0x1c6d9a109d3,
+    0x1cc91989a10, 0x1d83c16c04f, 0x1e127e0a152, 0x1e42123ec53,
+    0x1e6dfd8318f, 0x1e879c159f2, 0x1f75811d0f7, 0x1f87cdcedd0,
+    0x1f8f1faf95a, 0x00168a6ced3, 0x00a19e1aa11, 0x00b3eacc6ea,
+    0x00e37f011eb, 0x01081864b9d, 0x0188314158c, 0x01f5fd6c2a2,
+    0x0216eddf68f, 0x038fe6948d2, 0x03fa09cf023, 0x041ea3329d5,
+    0x04caa753900, 0x04dcf4055d9, 0x052d78ad4c7, 0x056807b3117,
+    0x058ca116ac9, 0x05ebc9800cb, 0x06265885d1b, 0x070942bc2d1,
+    0x0738d6f0dd2, 0x07adf4fc672, 0x085da20db62, 0x08a6d4d4ec6,
+    0x08ae26b5a50, 0x08b92186b9f, 0x08ec5eabc65, 0x096525a7aca,
+    0x0a0d80d8430, 0x0a5a5c8fd59, 0x0a9142a53e4, 0x0b48419745e,
+    0x0b86798d673, 0x0c6963c3c29, 0x0ca3f2c9879, 0x0f0e7976786,
+    0x0f41b69b84c, 0x0fe668dbbed, 0x0ff163acd3c, 0x1015fd106ee,
+    0x10fc9037269, 0x11e6cc4e3a9, 0x136716e4176, 0x13f97c7283e,
+    0x142567b6d7a, 0x148f8af14cb, 0x14a92983d2e, 0x14ca19f711b,
+    0x14d16bd7ca5, 0x157d6ff8bd0, 0x15eee513eab, 0x16ca7d698d7,
+    0x174a96462c6, 0x17593a079da, 0x177a2a7adc7, 0x1808e718eca,
+    0x181038f9a54, 0x190918d22a8, 0x19e1083770f, 0x1ac3f26dcc5,
+    0x1b47b43ac79, 0x1b8d3e11a18, 0x1bbcd246519, 0x1efb9f682c8,
+    0x005af98aca8, 0x026a00beb78, 0x05808b8c9b0, 0x0654d201852,
+    0x07c2cfe5946, 0x08d1a560438, 0x0a4e4705c40, 0x0a81842ad06,
+    0x0bce919ba0d, 0x0c5253689c1, 0x0d4edc317da, 0x0eaa8d63bf5,
+    0x10fb757e29f, 0x119c7ece07b, 0x137bf1cd44a, 0x141cfb1d226,
+    0x15c58806f6a, 0x165444a506d, 0x16babeef1f9, 0x16d0b491497,
+    0x1875988ac16, 0x190f4ff9e68, 0x198f68d6857, 0x1a60065b134,
+    0x1ad17b7640f, 0x1af26be97fc, 0x1b22001e2fd, 0x1b469981caf,
+    0x1b84d177ec4, 0x1b9ac71a162, 0x1ba5c1eb2b1, 0x1ce42b9a8a4,
+    0x1d13bfcf3a5, 0x1dad773e5f7, 0x1dcabec141f, 0x1e01a4d6aaa,
+    0x1e1048981be, 0x1e388aec135, 0x1e97b355737, 0x1fe4c0c643e,
+    0x015db97b681, 0x042eba7271a, 0x0444b0149b8, 0x04bd771081d,
+    0x07fc44325cc, 0x0992846a637, 0x09b71dcdfe9, 0x0b71f769a06,
+    0x0c254d6b4bb, 0x0e5c96f3302, 0x0f8c5ce11e1, 0x0fe08a79694,
+    0x1090378ab84, 0x1093e07b149, 0x10bc22cf0c0, 0x10ef5ff4186,
+    0x11b502a7914, 0x126858a93c9, 0x12b53460cf2, 0x142a8425970,
+    0x15f7aa73066, 0x1a0e66fa27c, 0x1d5bd7dd73f, 0x1ddf99aa6f3,
+    0x010c1a1a7c9, 0x01b81e3b6f4, 0x021e9885880, 0x0259278b4d0,
+    0x03ad86dcd61, 0x03ef67c353b, 0x099169b166d, 0x0a2eca10e84,
+    0x0b4fec3d64f, 0x0b70dcb0a3c, 0x0b832962715, 0x0cbdea21743,
+    0x0ced7e56244, 0x0de65e2ea98, 0x0f002e7a6d9, 0x12589a2eceb,
+    0x149e8778246, 0x1672ffa64c6, 0x1be56d5faf7, 0x1ec8bb08869,
+    0x1f5777a696c, 0x017c747cada, 0x0454c7546fd, 0x07d91e4d24b,
+    0x0bb1a2de24c, 0x1070ba95dc8, 0x1274c6f8b49, 0x12920e7b971,
+    0x14a4be9fe06, 0x15003e18e43, 0x17f22f832c9, 0x1f3cbe5b13f,
+    0x1fd2ccd9dcc, 0x01cbde6b9fe, 0x01ff1b90ac4, 0x02cfb9153a1,
+    0x0302f63a467, 0x04e61229dfb, 0x0a00a95a9b4, 0x0b21cb8717f,
+    0x0dd584fb3f0, 0x12615f8dea6, 0x12f76e0cb33, 0x13fb48b64d6,
+    0x16e5e83fdd2, 0x170a81a3784, 0x19506eeccdf, 0x1aa4ce3e570,
+    0x058257d0648, 0x0640a8a324c, 0x07e9358cf90, 0x08b28130ce3,
+    0x0c4ccdcbacf, 0x0c71672f481, 0x0d45ada4323, 0x1181038eeeb,
+    0x14dd1833ac2, 0x163c72564a2, 0x17ec5120d70, 0x02dfd0550e6,
+    0x0484b44e865, 0x079b3f1c69d, 0x0c6551a5368, 0x0ee976e4ad8,
+    0x11f506e17c1, 0x15636838071, 0x17eedf5836b, 0x1a55bd14cb3,
+    0x1f8d9bc8694, 0x033a3515159, 0x0580225e6b4, 0x0637215072e,
+    0x0c0c6063926, 0x1565f66f66c, 0x15db147af0c, 0x18cd05e5392,
+    0x1b2c91c1150, 0x08cc7888bad, 0x136821344ab, 0x1631d04a9ba,
+    0x1b91f152312, 0x1cd403f1eca, 0x1d1d36b922e, 0x1f129f5a89b,
+    0x02bb8fb6d9b, 0x06bc569bd13, 0x113ab7c47e9, 0x1166a308d25,
+    0x131a2ac3bb8, 0x170c4de741c, 0x1cf7829c8b2, 0x1e39953c46a,
+    0x00db01fea02, 0x082ce2b7402, 0x0aa60d25a23, 0x0b645df8627,
+    0x0b6806e8bec, 0x0cdd56ad86a, 0x12954e3dc3a, 0x13fbfa411a4,
+    0x182c555ac1d, 0x19e72ef663a, 0x1a4a0050201, 0x1af25b80b67,
+    0x1b552cda72e, 0x1bb0ac5376b, 0x1d43439b211, 0x00e88b0714c,
+    0x027ecb3f1b7, 0x05cc3c2267a, 0x0bbec2b869a, 0x0fa993fb374,
+    0x111ee3bfff2, 0x16c0e5ae124, 0x18e23993ccd, 0x017d7ecce0f,
+    0x072a7b8c090, 0x07cf2dcc431, 0x0a9533f237b, 0x0e039548c2b,
+    0x101d974dc4a, 0x143f4ea5faf, 0x147634bb63a, 0x15f2d660e42,
+    0x16386037be1, 0x180cd865e61, 0x1b2e5e04de8, 0x1b4ba587c10,
+    0x02127292ad2, 0x071abd119b2, 0x07220ef253c, 0x08d93f9d994,
+    0x0c59eda5f1d, 0x0d7b0fd26e8, 0x17dfd26895b, 0x1c177f62f5e,
+    0x1c854b8dc74, 0x1f6899369e6, 0x1035006e519, 0x12b925adc89,
+    0x15b9bad9823, 0x1a927123c02, 0x0155953e4ff, 0x06ae64652cd,
+    0x0d4cef1c218, 0x0e3ad42391d, 0x15c39af19a8, 0x192aaa676ce,
+    0x1bb621879c8, 0x0a52911823e, 0x0ac05d42f54, 0x0dda9101351,
+    0x1842fc87b89, 0x01f4691c347, 0x0bce1804a7c, 0x10c06ce16be,
+    0x14b638f54e7, 0x1f6b8033648, 0x00f31ca9f9f, 0x02c042f7695,
+    0x0f4659734b1, 0x0ff25d943dc, 0x1ade8ae7bc8, 0x1c836ee1347,
+    0x02a1e0bb8a3, 0x03eb453bfe5, 0x0fdb4d39174, 0x1843554d1f0,
+    0x1c68b595b1a, 0x051d99614bf, 0x18dbf203478, 0x1f76d3c9dfe,
+    0x07f1288fb53, 0x0aa139137ff, 0x0b5be0f5e3e, 0x15c7f56cc3b,
+    0x1a11ef18f17, 0x1cfc8ea2813, 0x0eba83d2010, 0x181f14aeea5,
+    0x000aacd6af7, 0x04bec9bd524, 0x054d865b627, 0x1758574274d,
+    0x06e654cac8d, 0x1156123204f, 0x122a58a6ef1, 0x1d07e238fc9,
+    0x1f3b82d084b, 0x07f0669c1f0, 0x09a3ee57083, 0x11cdbe74eea,
+    0x1717e9da5a4, 0x174b26ff66a, 0x0158130ce65, 0x0acef09b9d3,
+    0x178f961d43f, 0x1a6097144d8, 0x1d145088749, 0x01b620bd49d,
+    0x16d77c723fb, 0x0f046824042, 0x13d5cc8d897, 0x06d5d45cc4c,
+    0x1407eef9993, 0x16552e23a78, 0x1d18523e375, 0x00d38f4c54e,
+    0x1996897ce22, 0x19bdb117dcf, 0x1d19c5bc9a6, 0x1d6df354e59,
+    0x07898323d68, 0x10545c919ab, 0x15938d25f16, 0x194b2143b2a,
+    0x0387a185e26, 0x0d4408eb733, 0x11ed2b01011, 0x1436c13ab31,
+    0x16840064c16, 0x1d81b385163, 0x097cb653441, 0x1c791532231,
+    0x1e8f6e46c8b, 0x07654285a1d, 0x0fe6e92c2fc, 0x19dddf97859,
+    0x08d225b0b47, 0x10623e5f75c, 0x1bdd285104b, 0x04ffd847706,
+    0x1bc617f5de3, 0x04e1760b914, 0x157aa01e381, 0x0a9fa4c38a4,
+    0x10617c6bdf9, 0x10826cdf1e6, 0x1386aafb345, 0x0f3329fc54b,
+    0x1caace70031, 0x0032ce59144, 0x00f11f2bd48, 0x09557e4f7ff,
+    0x106cd0025af, 0x07f8b26441a, 0x0a499a7eac4, 0x151b0e86a83,
+    0x0354acbd732, 0x0a7aa231bf6, 0x03748277b55, 0x05ccbc72d89,
+    0x139c376f2e7, 0x008b566c88a, 0x0ba36f045b2, 0x17be478ccb3,
+    0x1f7930c6e3a, 0x05b4ea241be, 0x02abe85ead0, 0x10aaf78fb2f,
+    0x079227b15f9, 0x15e1bb8a546, 0x194c73f0831, 0x1e1686794fc,
+    0x1e252a3ac10, 0x0a86a75307a, 0x1701c2fdd47, 0x1728ea98cf4,
+    0x1aca891466a, 0x107e4a57c90, 0x1409f331368, 0x19666b486fb,
+    0x042304673e6, 0x1873c297a67, 0x07645fc0790, 0x148084bb239,
+    0x160bca22155, 0x02d01894186, 0x09c2d0e3584, 0x152b6e2319a,
+    0x1f818cf7cf9, 0x00891091c61, 0x02095b27a2e, 0x04912957763,
+    0x0a027c57dca, 0x1d68fe7130b, 0x047f356b0f1, 0x176f7ebfdc8,
+    0x1a8d5b6e78a, 0x0fe0d98f7e4, 0x06c0b7d0724, 0x1b6f83b13dd,
+    0x19b38f5c9f6, 0x1d886afd432, 0x1f93c940d3d, 0x01fe4fedc4a,
+    0x06ecfbda2c7, 0x19f4558a206, 0x004d566a3b2, 0x1071627157f,
+    0x1e81a39b2ed, 0x10f0b95a60b, 0x13db58e3f07, 0x1c3fb8079be,
+    0x1294829adc0, 0x0fe3575e14a, 0x18a6deeb203, 0x095fdeb8093,
+    0x19d75663db0, 0x0053d5ee944, 0x18f236bb866, 0x1fb1b1e5b9e,
+    0x08f7e086c41, 0x0a782b1ca0e, 0x09d9b00422d, 0x0bfeacda39b,
+    0x031cc3114f0, 0x0f9b87ac782, 0x1c80c691ba0, 0x0067eee42b5,
+    0x0d2a07e4352, 0x134c22aee73, 0x13bd97ca14e, 0x06fbd78f718,
+    0x0e6004f9df1, 0x18c755c765f, 0x10f441792a6, 0x16359716b10,
+    0x1f2dcf472c0, 0x02c0ca01522, 0x199f8d89498, 0x16843f120d1,
+    0x075ba11ad53, 0x1df24c9492f, 0x019ee5e13f4, 0x0566913ed4d,
+    0x064c09ac8fe, 0x1be511a46f7, 0x0be48447f12, 0x1061bb192b4,
+    0x0b0b7a29ae1, 0x1473b3f6f3b, 0x016044bcee3, 0x1c065add14b,
+    0x1d068c96529, 0x08ad61cc354, 0x045b544bb8b, 0x11c9716cb53,
+    0x04a136e7f91, 0x104a9a553b7, 0x049f0175ffd, 0x0114cc1af56,
+    0x103b34a0340, 0x15ed4dce1b7, 0x14f86fab58f, 0x1e6f4d3a0fd,
+    0x0109681bb0b, 0x14a95e81cd2, 0x1320a7523b1, 0x0306d4289d0,
+    0x0d429277369, 0x015ba89e9fc, 0x1900679f767, 0x185113538de,
+    0x1b6b4711cdb, 0x1f6fb6e7218, 0x1221c845cda, 0x1dde29efaa7,
+    0x03633d20418, 0x1dcf1d00097, 0x0eefb1d007d, 0x077bc5eb2c6,
+    0x0c1529864c6, 0x026f10884be, 0x0b18f982d14, 0x05d7937c815,
+    0x02b89c14e89, 0x00d80e5caf0, 0x163101b1da7, 0x18c3cab2c2b,
+    0x0eee1d80122, 0x1ea45d5c5d9, 0x0cfdd14d6ab, 0x090a9370e7d,
+    0x0c8dcfb0a01, 0x1024735b228, 0x1c2cff31c50, 0x1156058cd9f,
+    0x0dc5990a138, 0x1e21fea4fc1, 0x08b5937c3d2, 0x145b4df9233,
+    0x14fcb00e676, 0x09d4170890d, 0x06a2d2ef2a8, 0x03e59228519,
+    0x04a1ad89189, 0x062abd7e111, 0x19c3bac8db5, 0x18abb6c0e0c,
+    0x1d959f03efa, 0x0a3cfeb876a, 0x1e8b8776e57, 0x1787f5f43b1,
+    0x1ec5ad4e7ab, 0x0c2b05105b8, 0x096a1b59264, 0x12999264706,
+    0x081ca4ba261, 0x09134f20b21, 0x179d71ff6be, 0x1243881f926,
+    0x0c8f9245182, 0x0cba62d06f4, 0x0176b7b9af7, 0x1108b6f7c63,
+    0x1880a492646, 0x1e3fee035a0, 0x1668160bc58, 0x02733019c7b,
+    0x0b9de37bb8e, 0x12ed8ec25fa, 0x04f62a3778c, 0x17360487610,
+    0x14e33fc0639, 0x0a0a10a97f4, 0x1763aba6b4f, 0x1dc7a757e4a,
+    0x17abc3b4ee9, 0x1eaaea53a67, 0x145007d5979, 0x1dce3745071,
+    0x1f1b44b5d78, 0x03b73688573, 0x05cd8f9cfcd, 0x1785a66a271,
+    0x08f0e17fbec, 0x0f9488a572d, 0x0e860bf02a2, 0x1fdd86d5913,
+    0x06a3eab24d9, 0x1f45f46f9c0, 0x01f1414a0dd, 0x0922e31a3be,
+    0x106c46d05d5, 0x079a744746f, 0x005ffcaf33e, 0x0b44162e63d,
+    0x0eaf2759f8f, 0x06a28e562bb, 0x1746a2d11e2, 0x0edae163f0c,
+    0x031890eef51, 0x0837829ad9d, 0x082698fea60, 0x0dac5dba09f,
+    0x1b83dc21e55, 0x0fdad17a096, 0x1042bf42853, 0x0379ad27293,
+    0x08ade2ea6af, 0x02492545a51, 0x128bcb7c74d, 0x16f9336a77c,
+    0x11c2c8353cc, 0x110643a6460, 0x1dd0b8d73bc, 0x08650fa2130,
+    0x18ba7c21a96, 0x028c1735cde, 0x126fb5d4d02, 0x18a939c00f2,
+    0x03717f3abfa, 0x04f797ca28b, 0x054875377e0, 0x1dda46e3658,
+    0x1f4fef6d93d, 0x0ef43b5d782, 0x154eafbbf5f, 0x185512e13bd,
+    0x085bd765762, 0x1638db6a40a, 0x07070ee5bd5, 0x18ba62098ea,
+    0x1a300a93bcf, 0x18db9ad96a9, 0x0095c21fecd, 0x03df20e4acf,
+    0x07b05394f46, 0x072b0de0ccc, 0x0f8c759ee8c, 0x0a12fe616a3,
+    0x1ad5de466b8, 0x01bd329666b, 0x0cc98e698e1, 0x1593a5e3bc1,
+    0x1b09bc8d7b7, 0x0d7d95f6064, 0x146d56dfb6b, 0x151ace7f0c7,
+    0x05235f47104, 0x160552f2bd9, 0x0b85d711139, 0x1b9909e4c5e,
+    0x1e9fd6383b8, 0x013aa2a4a94, 0x1cd28f19398, 0x1328cd2adcb,
+    0x036f95e901d, 0x1ff1761808f, 0x1bf5cba1d0d, 0x152fb021b19,
+    0x0f9ecfc3a61, 0x0ac948d2cb6, 0x00b7e1788fe, 0x121f739dcb4,
+    0x1c85a9b2558, 0x05e91fd6423, 0x1986779c35a, 0x0146247fa70,
+    0x19160cd13b4, 0x0e990ebe27a, 0x121ff3ee3c3, 0x103173ff5e4,
+    0x0999d1faf27, 0x1bed034cdb9, 0x129c2237599, 0x0b73e6e84ac,
+    0x14fedd6c98b, 0x032fddcf527, 0x112f9e8b254, 0x1c2b79f0489,
+    0x0c92da5c461, 0x1eb749d5dea, 0x146d88e7d76, 0x12775e52da6,
+    0x19c87d17e43, 0x07c8ffc74d3, 0x015f7e792e6, 0x028d0ef5231,
+    0x0748148e4ec, 0x09fd9a463f5, 0x159974ab0d1, 0x07b2e95390b,
+    0x1ff14f5ac33, 0x01f655a5054, 0x0acf593d41a, 0x0898c1402a1,
+    0x093b3e0e6ae, 0x18d45df2de5, 0x11ee6cb87ce, 0x10936d11081,
+    0x1cae4599782, 0x188e5a850b5, 0x0cd074f4022, 0x1dfca5b7190,
+    0x0b68e62b82b, 0x16e8dc2307b, 0x17c57ec8ddc, 0x02826044499,
+    0x106bc22fc2c, 0x0c14f105ed4, 0x15af4d3f42a, 0x137e93a480a,
+    0x1849474f50e, 0x16dba230a81, 0x01739183125, 0x135fefc57c4,
+    0x0b3f2d634ea, 0x029710ac92c, 0x148cf641ae9, 0x06708f24fcd,
+    0x0f94815f6fa, 0x121607febda, 0x0805bb5d7ec, 0x1c39320b045,
+    0x062000cdbc8, 0x14ac177b4a5, 0x0063a51304e, 0x12443627fa6,
+    0x1acd66a314c, 0x0f3b5bed960, 0x1517dc78a4d, 0x16839481f18,
+    0x07aa6079abe, 0x1f6e9c6f5e5, 0x0942c5bae28, 0x0458cd0f0a5,
+    0x1432717dd98, 0x0e39ebb3ef5, 0x1f0115ab508, 0x1662d90cacf,
+    0x04d1177e1e0, 0x092137e93e7, 0x0bf5c0ff11b, 0x1a81e81568d,
+    0x11aaf91d931, 0x18922c9bfba, 0x1d68ad74405, 0x0a78bce4d95,
+    0x10edea8ed9f, 0x02f6bdf7c6e, 0x1460858efb8, 0x0628b39bfa1,
+    0x1c3215f60ab, 0x18ea1a46e45, 0x019bf64425e, 0x0d310f81754,
+    0x1710d4c011f, 0x18a69b611f9, 0x0debbe1d511, 0x02fbfefbb73,
+    0x097f0e7392e, 0x0ea0d6b3747, 0x0ee85459226, 0x19f66c0749f,
+    0x1db94352bd4, 0x171dd78c628, 0x0cbba32d9b0, 0x0d5e371e1d0,
+    0x137c3fca430, 0x06ad97a92e9, 0x12f79b381ef, 0x059b998e941,
+    0x0b2e255ed67, 0x013354c8c86, 0x1aa40ef5488, 0x0b95adbcc0d,
+    0x1d4897b2626, 0x1166b9dfc1a, 0x1784c28be7a, 0x01015dab617,
+    0x05ecb96cd36, 0x081600554a2, 0x1f48f1758cb, 0x1102cad72f0,
+    0x10f94a3df50, 0x0db38400c7e, 0x1d29e72e330, 0x059698d378f,
+    0x167078aad77, 0x048a99ecc6a, 0x14c574abe4b, 0x06fe03d48c3,
+    0x1baf5decb77, 0x12fdb9e349b, 0x103664d422f, 0x07599779f7a,
+    0x1ee922cc3aa, 0x16276782f89, 0x037c979c3bc, 0x0cdba2dc35f,
+    0x052f84ee994, 0x1efe6aa735f, 0x15a1639ed91, 0x09e3a598da6,
+    0x019a86ca9a9, 0x0970dadb02b, 0x0d2d2239539, 0x1b5a9a7d43a,
+    0x1a28d711304, 0x073027dc257, 0x08697226ebb, 0x091ab09256d,
+    0x0be79f6ad45, 0x01f077c5229, 0x07854970278, 0x0d39af32496,
+    0x195054ca9ac, 0x1c1a949a25f, 0x199873924c4, 0x1847acc8563,
+    0x04acd98a710, 0x00f04020319, 0x14de092f710, 0x0305e1be573,
+    0x15bd8fdc33a, 0x15d07db004c, 0x0abd7e91181, 0x08f5bd0f053,
+    0x0cbe19f767a, 0x10be8ed0709, 0x134b8d9b6ae, 0x190e4ec260e,
+    0x03bdcbca890, 0x112372a27ed, 0x01feb58f771, 0x1c4cfbe06df,
+    0x0dee5b17d82, 0x1871a774e8f, 0x0fd5def58a5, 0x12ec074eb5f,
+    0x06b55ac1c67, 0x0c5ffe47891, 0x073b6ce69a7, 0x066b0e0f585,
+    0x100248623a3, 0x016392f6f04, 0x07d50747cb4, 0x1e19f28f0ae,
+    0x14f8084fd3b, 0x18f1b547e66, 0x0d28e258b80, 0x174de911918,
+    0x0cf50acb1ff, 0x0c8ee9466da, 0x0d633674cfa, 0x0323c586885,
+    0x1285f5a641a, 0x1f6d00cf4d0, 0x106da5ccee7, 0x08020beacb9,
+    0x1af2bb77ef3, 0x007a2f69285, 0x0eab509d74b, 0x170b3474645,
+    0x05a4dae3dea, 0x02c433626d2, 0x19d15ffd4ea, 0x1147744815f,
+    0x15ddaf434a9, 0x09025076210, 0x0939368b89b, 0x1859a3a07e7,
+    0x159db2909c6, 0x16d8f3a0103, 0x1905ea325b2, 0x19381a115aa,
+    0x1f9ea104107, 0x01e79cd536c, 0x0a9a3ac60e8, 0x070d66cadb1,
+    0x0bc17a022c7, 0x116da464f50, 0x0d83ca13b7d, 0x09dcfec097c,
+    0x1a3240bf4a1, 0x11426b7932e, 0x1acdf2b6bb1, 0x0c10683210d,
+    0x07d7a7382be, 0x05a6740670d, 0x15b4c25f379, 0x0f3cb471e8b,
+    0x1714233b4d7, 0x0b328dc549d, 0x123e124ab06, 0x0c8d2af806c,
+    0x19b6c8925ca, 0x064f327983d, 0x0dc5a2e66d8, 0x1df61d3d025,
+    0x070fac19125, 0x06617ff9162, 0x1da1d9503ab, 0x1ee6af25502,
+    0x0329c6d86ce, 0x157e5f038e1, 0x0cc6dc97cac, 0x024778a626b,
+    0x138894bb342, 0x07d6f002e55, 0x04b9b4764cc, 0x0a0cbc5d154,
+    0x12aff498e59, 0x001653b5202, 0x149b66cdb7a, 0x04cffaca152,
+    0x02f69b2b280, 0x0aed4bffbb2, 0x1dcf40a92c5, 0x1d5606cfc4d,
+    0x1d288445a1d, 0x0a9a40a7a93, 0x16c34dd7a7a, 0x0e30c44e5dc,
+    0x0e7596aeb7b, 0x0d8ec1e4dac, 0x19acb07c581, 0x1c59956a5de,
+    0x05eb32fe0f7, 0x0d4f39ab374, 0x041c0946c78, 0x1a8ca1f2d3f,
+    0x01aebd3c67f, 0x0ed6dda9095, 0x00d4eb3edb9, 0x07819d2a6ee,
+    0x045b8f998fa, 0x03c0201308a, 0x1e0ffab3f53, 0x1e6cc32a780,
+    0x1045e550c09, 0x083c4a6bfaa, 0x022b798a1f7, 0x0f42f1fdb24,
+    0x1bbfc9837f4, 0x0ce03a8c117, 0x066b8746cea, 0x0534be8e3d1,
+    0x037c5258685, 0x0ed86dea0ed, 0x15ecb7ce911, 0x1245834d414,
+    0x0c6ec052f56, 0x09df26ab706, 0x1e5d6eed381, 0x0675416f65f,
+    0x07ee172ae2b, 0x053bff79a11, 0x1bfa2b53c01, 0x1169abbdc78,
+    0x1127893424a, 0x128bd3ea4de, 0x147e8cf6371, 0x0444d0b3b3f,
+    0x05d2e9b8c8f, 0x14ec21faf96, 0x02a021b8364, 0x188855bf812,
+    0x168438f3dfc, 0x1b3a7ea750d, 0x1ff1a134379, 0x10c8c199da7,
+    0x0ec9a724ddb, 0x011f6a71c4d, 0x0ad5318169e, 0x1b156466b97,
+    0x1846159b5ea, 0x06d0af1b37f, 0x0b7d5659b5f, 0x163fadd70c5,
+    0x1287c7ad5c8, 0x0f6df36dfb0, 0x188bbbf3a19, 0x14162619351,
+    0x186af9c4e52, 0x00958ca4268, 0x08044bb1794, 0x071113e2ff4,
+    0x1fc66211707, 0x1827c740954, 0x1ff95d06cc4, 0x1b54ff685c1,
+    0x06d88de7741, 0x1c927e3eca8, 0x042c6d5652b, 0x1c28d92cb19,
+    0x1b5779b48d9, 0x1344e24a9ce, 0x031b6b8c707, 0x11c4a8eb4e2,
+    0x1995b6d9d79, 0x1193ac8315f, 0x17f611bc3b2, 0x09902c85dc7,
+    0x10ca1728539, 0x009a5bcf7b6, 0x0c875bfe3b9, 0x1c80ee1752e,
+    0x00b61b1b07e, 0x1e4f7552473, 0x141ae812d3a, 0x0992bdf44f2,
+    0x177cb8203f3, 0x00cc54c9a54, 0x12b5f0a47a0, 0x117884a0232,
+    0x0bf7d50d7b6, 0x04c6c3f6879, 0x037a66b633f, 0x10fd1a275ee,
+    0x11e5790554c, 0x15c168a72a3, 0x13c7537eaba, 0x0ca23dd9aea,
+    0x12dbb51fc2a, 0x14ae352eed0, 0x020e166257f, 0x11ce40d3d1e,
+    0x10f4929db1b, 0x19fd90a2f45, 0x04ee5bb04b0, 0x060b378e062,
+    0x1c86c08e115, 0x00849e4e2de, 0x1161e70e4cf, 0x087d52000e4,
+    0x1c0acc158ab, 0x136f7d2d252, 0x158c0d6986c, 0x1174bd33519,
+    0x05a72318656, 0x0d6e9d0f11f, 0x188b6005e76, 0x0ecbb0ca621,
+    0x1131eb3cde3, 0x1de6369b749, 0x153d9ccb5ac, 0x06022a8be13,
+    0x0368ec83b60, 0x1345506440d, 0x0ae9863319e, 0x0d963623257,
+    0x097fe23da88, 0x1cb23403fe1, 0x190a0c54bc2, 0x0585f248208,
+    0x1be4b974e1f, 0x0571a78ba23, 0x113f244d483, 0x0f8780c4818,
+    0x0db51a82200, 0x16c49ec533a, 0x1585317f190, 0x1fc70105ed9,
+    0x1ae4053905e, 0x1dda20b1e04, 0x14e06567fda, 0x1fcf73cc8f6,
+    0x0ab374cf2e1, 0x0752720033a, 0x04a6432e5dc, 0x1c0f08cdd28,
+    0x05eb87043f7, 0x1ec38067d90, 0x1fef4cf5059, 0x1700dac4882,
+    0x00cdd792496, 0x01994ef8c66, 0x1669a978fac, 0x093695c2811,
+    0x08c84651653, 0x1305144f59e, 0x1e5597b1863, 0x154d2615543,
+    0x1e5a8c08afc, 0x0841f1b18ec, 0x1476b7e29a0, 0x10731c052f3,
+    0x08744107d0d, 0x0c61901934d, 0x0ec347b7b88, 0x0e7476d701e,
+    0x006e867aafb, 0x0e1d03044bb, 0x0658319e530, 0x14336373a0e,
+    0x16cb93a933e, 0x1b8b6d95bb4, 0x004a5413171, 0x010ff862197,
+    0x1115f3202f1, 0x1d6f7ed1430, 0x1b373d7ff0f, 0x025cfa73de1,
+    0x0cc6b38943a, 0x0759f924c6c, 0x14862200a2c, 0x1b74c7a4cb4,
+    0x12d44b5851b, 0x0ca97f5aeb9, 0x04d36701f59, 0x1de88f2493c,
+    0x0420abaa0f6, 0x0d892babdd5, 0x062fe9b8b99, 0x06a1c54d76e,
+    0x0b87dde2e3b, 0x0c77657afe3, 0x06b72f09030, 0x184b68bbaea,
+    0x022a6cd479e, 0x0166f0c5ba7, 0x1809f47bad3, 0x18ed0cf68af,
+    0x1ae1368afda, 0x0ca2d646634, 0x1320f49e286, 0x0d56c0385bd,
+    0x06b6f80ffc8, 0x0b0615b2264, 0x125287f0185, 0x0110b2bee8f,
+    0x1e9004a8e70, 0x15dde15e068, 0x10438693ad9, 0x15baa26fb1e,
+    0x183bfc66070, 0x0804a41b38f, 0x05dbd4a1a67, 0x0b10be90245,
+    0x050b250494f, 0x01dac323cc2, 0x175a189df75, 0x12ddf98fe04,
+    0x10862cc7c48, 0x01dc90b7cd4, 0x11dbf851ad7, 0x1a87ae5dc11,
+    0x17f7fa9e215, 0x13038ead8fe, 0x1181bd7be69, 0x119086a04d8,
+    0x1589d569b98, 0x1e9b00424f1, 0x080ff1896e9, 0x090d4c22245,
+    0x01eaf9672f6, 0x04afcdf31fa, 0x145d412a4c3, 0x0ab19785bb1,
+    0x1d96d082bbe, 0x14286dce6ec, 0x160fd92f8fb, 0x12fdcb8b2af,
+    0x1a01a3425da, 0x133162849bf, 0x17f312a577b, 0x0a28b92bb72,
+    0x18e5fbd6372, 0x1df71ceacee, 0x12c5bdf9515, 0x0e6c1acce21,
+    0x09d25699cd2, 0x15270b91c81, 0x126e0bb0707, 0x0fdfae613f7,
+    0x16e8679d842, 0x1d2c9b489ad, 0x1abd33e45cc, 0x192f2c15d5d,
+    0x1713ce8276c, 0x10e56516b04, 0x13739a4e4b7, 0x076c27d6558,
+    0x1eee4c41ee2, 0x064d3c6c09b, 0x15d2944dbe0, 0x0c61d6ba698,
+    0x180dffc6541, 0x15061fb4932, 0x0df6e885666, 0x127eec246c8,
+    0x19b51fd70c4, 0x1187e4df717, 0x06a8b0a8f1d, 0x11942390170,
+    0x0a421965912, 0x1f726d51351, 0x0317c4daa03, 0x1acdc1ece78,
+    0x03fd243f3eb, 0x0fdc16c4efb, 0x02f12371b10, 0x039b6e20f1c,
+    0x169ab733a9e, 0x0fb398f0a72, 0x02761cbddc4, 0x0d6a06bbcce,
+    0x056f4927d58, 0x0c5a4372537, 0x1b90381e8d6, 0x091b9fae2d8,
+    0x16ee910633b, 0x0a7bc7dd016, 0x0e725ab716a, 0x18227cf3c37,
+    0x0dba6f91ce3, 0x1fedfb8bf3e, 0x15de02922d1, 0x0d11529aaa3,
+    0x1a162d5f7f1, 0x04b0b4d9d3e, 0x06f50397572, 0x1348ebcfc2e,
+    0x09df43157d4, 0x1999827f76a, 0x1467f2570f3, 0x1eba5dff8c1,
+    0x10ee96b1e22, 0x107be5eddbe, 0x1411df497c3, 0x0d226a166c0,
+    0x0f3d092e815, 0x058e2ed63ce, 0x13bd7ba8df6, 0x1298e4f35ce,
+    0x11be9845c0d, 0x0b53afc8ffa, 0x067af688e82, 0x10a41cd33d7,
+    0x0f6838baeab, 0x00777ac0858, 0x1dfcd3f233b, 0x034944e7100,
+    0x12487a3b270, 0x0ec12b9190d, 0x1bbc01a91c7, 0x0656b8c243b,
+    0x04dbb62b088, 0x13ab915c3fa, 0x1f8a3d62167, 0x0009a46896a,
+    0x0fb5f4d2fea, 0x1116bc7e342, 0x183e9fd5a14, 0x1d0c98a1c5e,
+    0x15d459676d3, 0x0f760f6f9a3, 0x12c66e40456, 0x173ddc771b1,
+    0x007db0a1578, 0x1f3ce6f5029, 0x077d9f5679b, 0x01ac5f85b64,
+    0x004c672cd5d, 0x15680655ee8, 0x186876a1255, 0x0774469842e,
+    0x13271af9a24, 0x06e89c9fb2c, 0x06c1d9e2be1, 0x0f0741acf5a,
+    0x02aa8d88fee, 0x0038b16ed82, 0x0e9cba39dd7, 0x0912e6005cb,
+    0x1251d6608a9, 0x0e0b6ff59fb, 0x0e08ea95b44, 0x14573ade1a2,
+    0x15b7c8a4880, 0x030578d9c9a, 0x1fd9016e391, 0x0a0f6219300,
+    0x0d6556f9ac1, 0x01c1012de94, 0x0b971893af5, 0x11ca283b57b,
+    0x10c64cb9e05, 0x1aa714b75b4, 0x14aca141713, 0x125c7fabffd,
+    0x0a981632be8, 0x113c56a7e7a, 0x0d2ba1957ef, 0x160d918b563,
+    0x0fb8ce18705, 0x07127d3be2c, 0x12a325812ac, 0x0a0ae4e4033,
+    0x07a50e36769, 0x00c0f50d2c7, 0x00890ba241c, 0x10b3a091b74,
+    0x01dfa797966, 0x1952caa9bad, 0x09d825284d7, 0x04ba9437737,
+    0x1f33933c933, 0x06d53c810e2, 0x0e4a1f020fb, 0x152a41fc39d,
+    0x1e6f1b3bd37, 0x091ea70722f, 0x028924a2a6d, 0x1ec4fbfa9ab,
+    0x0a989d49062, 0x079387b2a25, 0x054b2e53b73, 0x14dc3868cf9,
+    0x14b9d290525, 0x0b777ff6c35, 0x09774746651, 0x14c55cb6390,
+    0x0571a4688bf, 0x0312aa0996a, 0x1532efa1a5c, 0x0dfec4adedf,
+    0x09681870c1a, 0x0c30efd0321, 0x01e872eec40, 0x010aee5189e,
+    0x1b54c11e3b0, 0x1054076b18b, 0x12009bab10f, 0x1f5de648026,
+    0x1fec2d089f6, 0x1ae79a3d351, 0x16055846033, 0x14a18cf43cd,
+    0x02b4868b059, 0x0f39d118add, 0x184fa8a563b, 0x05dcd75adef,
+    0x1f867bca4ca, 0x0722f8230e0, 0x1c7739a0b92, 0x14d27afcac6,
+    0x1ff4483a337, 0x1ee3e7ec65e, 0x15bdf9cf572, 0x0ac55b183d8,
+    0x1bd7ba5eb7e, 0x13d6f28d36d, 0x094483a59c7, 0x1971a6811fc,
+    0x1d01f6dc8e5, 0x1d7041a8164, 0x0cd4bb66307, 0x03cc8bae5d2,
+    0x040a28f0a0e, 0x028ff942508, 0x10f7db14df0, 0x0970a05aa25,
+    0x04a714b291e, 0x0c701e4e2e9, 0x00c3311c809, 0x085c5af52eb,
+    0x0d2a0ddcc3b, 0x1b86f410788, 0x1ede3484ca6, 0x04074091b8a,
+    0x0cdace8c340, 0x0964a817ec7, 0x053c4b1e08a, 0x071f3cfaffd,
+    0x1c847b0f581, 0x1f95bfc003b, 0x01c86666096, 0x0448e6a5caf,
+    0x17d9562fe30, 0x14b11e2c939, 0x146ba94fe60, 0x13917b4bc7c,
+    0x1bfab5951e7, 0x19279b20f95, 0x0216bd36a1b, 0x17888e39bb5,
+    0x0ae80792a03, 0x08298ab4acb, 0x10dfed49850, 0x0cb166c8a85,
+    0x101beba4048, 0x0855cff4fc0, 0x1849168c1c5, 0x10a38f62987,
+    0x049cf1f189b, 0x16a2876af49, 0x087fa6e2a56, 0x062c7b015c2,
+    0x0d5c29bc4a1, 0x062091756ed, 0x0518e01968d, 0x03d09d83c3a,
+    0x054f111c2e2, 0x1555bb741cd, 0x09dbccd3073, 0x1ec92ece825,
+    0x00112998bc8, 0x0e02379d7c8, 0x149f696fd0f, 0x1b7137d7768,
+    0x1a3b030519c, 0x1e1d806bfcd, 0x00bc65b06e8, 0x1983059408a,
+    0x1e9fe1feca9, 0x0132c0a95aa, 0x0a6dc5119e7, 0x0fd58b2db10,
+    0x137e20c98e5, 0x0968688531c, 0x0a81dc892a8, 0x0f861cd9997,
+    0x1b0d2b12efb, 0x000bea34e27, 0x0db665c24f9, 0x0e90e722940,
+    0x0106d989a38, 0x02e84aac68e, 0x0f3e4a70319, 0x1d9d002369b,
+    0x01c31c31105, 0x091514c5856, 0x1c49b1c053b, 0x1ebef82c4a5,
+    0x1ec86920675, 0x1253eb1b8cf, 0x19c293afdd8, 0x1e6a30fc496,
+    0x01cf75469e1, 0x1216b1b956c, 0x06dc53cf47c, 0x16b338cb908,
+    0x07bc89831bb, 0x12bfceaee52, 0x0602827afa6, 0x129bef4e086,
+    0x19ade8a837b, 0x08f365a39dc, 0x13331a3ed5d, 0x167bf567e12,
+    0x1817eb5403d, 0x116a0ac445b, 0x151f5a1ae96, 0x0355fbafed3,
+    0x1721a856f18, 0x0398c5fb690, 0x18fde252209, 0x09bc1effd8d,
+    0x15e058368cb, 0x1ef19f8672d, 0x0efeb28bcb5, 0x08597fe8243,
+    0x1e3558b7241, 0x1f06d2b5a4d, 0x06753166ca7, 0x19d05252f87,
+    0x07ece8719c7, 0x0abada7be10, 0x03b7bae0915, 0x0b920dbfa1e,
+    0x077c3d750ef, 0x0c8a77b91e5, 0x13c12511766, 0x1bd655e970d,
+    0x0d5f0a10e67, 0x05c151f0386, 0x0f36c123216, 0x0733590bd56,
+    0x0134800baf0, 0x166cf1de51f, 0x117dd06e881, 0x129a0921efb,
+    0x0a6ff0ca5c4, 0x08ea200b050, 0x034f9387b94, 0x1d3723ad2de,
+    0x18e5af2b62e, 0x10bd979c6df, 0x14d339607a4, 0x1b88544514a,
+    0x03a4450d2d8, 0x1a61117a18f, 0x1e21725e539, 0x123cf7364fd,
+    0x179908b5b01, 0x069ec91a349, 0x0945679ec02, 0x0e6a4ca1b53,
+    0x07e2c612da0, 0x1f568f32763, 0x1b564f98a0f, 0x020e11f4d89,
+    0x090c3ea1433, 0x14c7e71acd3, 0x0dc4e4b061f, 0x1c813886671,
+    0x0998741f58b, 0x0fe30e80a8f, 0x0607de6a503, 0x1484657259d,
+    0x0e53c335255, 0x174d9bd2dc8, 0x07d39cfa317, 0x1c0992c6850,
+    0x112351c0a60, 0x1e0d5e6cd44, 0x0667998400c, 0x042dfaef259,
+    0x19abbd3ed08, 0x14ad9c7ab72, 0x056389ff426, 0x19427c1236c,
+    0x1a4063a9d61, 0x0af87f97b4d, 0x1666612b513, 0x1f23ac539e4,
+    0x1e873a1cf84, 0x1701f1aeddc, 0x18b22a98565, 0x1ddc760ab43,
+    0x022da169d55, 0x0f1416893de, 0x0ee14c0e6db, 0x19252aae1f5,
+    0x00b4fb6141d, 0x0c2b99da72c, 0x19ec587f23a, 0x1f44dc5cd77,
+    0x101573c5f0c, 0x0c8590ac48f, 0x1f1d4b1d98d, 0x12ae39c0c1e,
+    0x16e46996bb3, 0x0d907071ae1, 0x0ee89247a5b, 0x12753bfdbce,
+    0x07906c2f180, 0x06632db9a04, 0x06dd60d443f, 0x06b859327f2,
+    0x18f08bb0ad5, 0x100dd9d760a, 0x01e8c644d0d, 0x1e8edb740dc,
+    0x16ba7ffa32e, 0x0bfbda5d84c, 0x17fdf137ad8, 0x0777ffe01db,
+    0x13b9a93d0a6, 0x032bde748a4, 0x104f87c4ba8, 0x1102e5438a0,
+    0x1790599f8b8, 0x06e0e97a55c, 0x0980d898e6b, 0x14035825c86,
+    0x199ded7a4bf, 0x138ab0050f4, 0x0f8c1940dda, 0x129ad4d2a65,
+    0x076a9aca541, 0x1332c5d323c, 0x1dba5113a26, 0x1c66d9f5293,
+    0x17e232d0d9a, 0x09976915862, 0x1913b2a7244, 0x0b2ba534e99,
+    0x160d1bf0cf7, 0x00eb9922d30, 0x02e3f788035, 0x1d158a6dfbe,
+    0x14e9413f1fe, 0x132d8c19753, 0x14b4f73c0c3, 0x01f33b28cfd,
+    0x072a8fad428, 0x1514364455c, 0x02d98ab517f, 0x167cedc2d26,
+    0x1bb3c039f02, 0x144f9f06518, 0x086d324dd1c, 0x12c4eb26f57,
+    0x078ea843219, 0x06a55f7e6b6, 0x06d7b34c6e0, 0x1016cfeb2ec,
+    0x04215676871, 0x156b12b4c31, 0x0586fdbe5d9, 0x030212d8b7b,
+    0x1cc7382e8cd, 0x06ca5a2d1ec, 0x0875c7a96f2, 0x1710b90c031,
+    0x010d1d8c47a, 0x1bf01e51bb9, 0x0c1793e8778, 0x1b326c2d7e2,
+    0x0c5310a5aab, 0x11cd7b3c8e5, 0x18d5912ebaa, 0x17450d723c4,
+    0x0ab2fa34dcd, 0x12c7246a7bc, 0x1b365296a34, 0x0c47f954743,
+    0x050467ba354, 0x02cc0b816e1, 0x17598c4f17d, 0x1af4283647b,
+    0x05a5d5379ec, 0x1aaecddc00d, 0x1325e99894f, 0x03935af6226,
+    0x18450e1eebe, 0x09adfe3a3d0, 0x079383c9291, 0x02212102d63,
+    0x153d2be7427, 0x0fb53f1bb55, 0x0918bed2982, 0x10f53f0d799,
+    0x0526ff4d1b3, 0x0bc90635acd, 0x1453b55ba1d, 0x1079d69bec4,
+    0x1af3934bd83, 0x0b29697fc3f, 0x108533de8dd, 0x12968f67594,
+    0x11cbe9cb3ed, 0x00bac4e20c3, 0x134251aba54, 0x14eaaa20df5,
+    0x1528aec9718, 0x170983cc1ec, 0x02f097e68c8, 0x05f746b9d5c,
+    0x1ca56b310f3, 0x03972530948, 0x09ba80f3bce, 0x16c5f86f30d,
+    0x119664abd83, 0x16fdda25a23, 0x17631d1a1d3, 0x03d72d2b20d,
+    0x11f52f5697b, 0x01d6b6d864f, 0x043d6422f0a, 0x16ef1aa2e0c,
+    0x0e1a3790b11, 0x0f1667a531d, 0x11cc7bbbcda, 0x1ace1b03f19,
+    0x18bd83b1d52, 0x08a5de68db4, 0x18fbf08cfef, 0x082019639cc,
+    0x0ab6b9a8d3b, 0x1fc4a838fba, 0x104f0d67db1, 0x1e4bd144895,
+    0x10493faac76, 0x06f1ac45d00, 0x01b845ac503, 0x1a071baaaef,
+    0x17ce5822109, 0x1a7b9992deb, 0x1641ad2afd1, 0x1983f74b6ee,
+    0x11dd4491184, 0x1597809c70c, 0x1c9ee207a02, 0x1fb967dbcce,
+    0x013dd5d0740, 0x1f04a7cf90a, 0x0bda526f2c0, 0x00a290afad5,
+    0x1f74ef5e0ed, 0x0ad155bc8de, 0x008a74e0d64, 0x1e26d6d2095,
+    0x1efe9d3f3fe, 0x0b7bb790080, 0x0e83eb7940b, 0x0195a6721de,
+    0x1697162ecc6, 0x1a822424ecc, 0x0bb9409ef14, 0x088dc8af7de,
+    0x01a109fbdf8, 0x1263a6224e1, 0x19762451a46, 0x123f8087a5d,
+    0x1ee61da2ff8, 0x1050f8b67b3, 0x05bd05e0ad3, 0x17f3054510b,
+    0x1f0a65cbe49, 0x0c38f39ecfb, 0x1b186adf1ca, 0x003c9d6cb25,
+    0x0b0d33343b8, 0x08cb15b5077, 0x083960895dd, 0x0a4f544f26c,
+    0x14637fba5b5, 0x0c8a7c5184d, 0x109ef503b91, 0x110cc27f58c,
+    0x1dd15e9f124, 0x1eddb2ca0d9, 0x0aafbfcea5b, 0x03f14f93014,
+    0x1c8249b99d9, 0x07c3667f5b3, 0x1a62b640f70, 0x1eec6a22d2a,
+    0x0855be93f97, 0x0c7af091027, 0x10a7ffe2790, 0x1042e09ea4c,
+    0x076db25a8e1, 0x1654d706d3f, 0x0260b3a5e33, 0x088f9419e9e,
+    0x1d68917df54, 0x1188549820d, 0x158d48169b9, 0x19e93fcdd73,
+    0x16b91a47690, 0x1fba468bd65, 0x0bb64a6d60f, 0x157432e4097,
+    0x088a4f70f06, 0x0f091d83cf0, 0x1c4a6fbc192, 0x0ada88c3e7d,
+    0x0e531e141b5, 0x1237b66171c, 0x12749c7c2f4, 0x0e2cee269f9,
+    0x0260340689f, 0x19500504a28, 0x14432099794, 0x047cbdd04d3,
+    0x01af023fdbe, 0x1eee99e354e, 0x012649e3aff, 0x1a7f7a49cbf,
+    0x0e43793e465, 0x06209ca0402, 0x1eadfe92cff, 0x03dacced0bc,
+    0x097a15758c0, 0x1fcd58aeaae, 0x0ff774c923f, 0x07a5654482c,
+    0x1b492028846, 0x15c12bf403f, 0x1e7575219b9, 0x0342bb31b5c,
+    0x1a8052db35e, 0x0e8274ad0db, 0x00f1a937c8a, 0x094b5e48ed7,
+    0x0352cb6ab16, 0x15cff6a6f12, 0x1ee40155a64,
+];
@@ -0,0 +1,12205 @@
+//! The tagStandard52h13 family, generated from `tagStandard52h13.c` of the AprilTag library.
+//!
+//! Copyright (C) 2013-2016, The Regents of The University of Michigan.
+
+use super::FamilyTable;
+
+pub(crate) static TAG_STANDARD52H13: FamilyTable = FamilyTable {
+    name: "tagStandard52h13",
+    nbits: 52,
+    width_at_border: 6,
+    total_width: 10,
+    reversed_border: true,
+    bit_x: &BIT_X,
+    bit_y: &BIT_Y,
+    codes: &CODES,
+};
+
+#[rustfmt::skip]
+static BIT_X: [i32; 52] = [-2, -1, 0, 1, 2, 3, 4, 5, 6, 1, 2, 3, 2, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 4, 4, 3, 7, 6, 5, 4, 3, 2, 1, 0, -1, 4, 3, 2, 3, -2, -2, -2, -2, -2, -2, -2, -2, -2, 1, 1, 1, 2];
+
+#[rustfmt::skip]
+static BIT_Y: [i32; 52] = [-2, -2, -2, -2, -2, -2, -2, -2, -2, 1, 1, 1, 2, -2, -1, 0, 1, 2, 3, 4, 5, 6, 1, 2, 3, 2, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 4, 4, 3, 7, 6, 5, 4, 3, 2, 1, 0, -1, 4, 3, 2, 3];
+
+#[rustfmt::skip]
+static CODES: [u64; 48714] = [
+    0x4064a19651ff1, 0x4064a53f425b6, 0x4064a8e832b7b, 0x4064ac9123140,
+    0x4064b03a13705, 0x4064b3e303cca, 0x4064b78bf428f, 0x4064bb34e4854,
+    0x4064beddd4e19, 0x4064c286c53de, 0x4064c62fb59a3, 0x4064c9d8a5f68,
+    0x4064d12a86af2, 0x4064d4d3770b7, 0x4064dc2557c41, 0x4064dfce48206,
+    0x4064e377387cb, 0x4064e72028d90, 0x4064eac919355, 0x4064f21af9edf,
+    0x4064fd15cb02e, 0x406500bebb5f3, 0x40650467abbb8, 0x40650bb98c742,
+    0x40650f627cd07, 0x406516b45d891, 0x40651a5d4de56, 0x406521af2e9e0,
+    0x406525581efa5, 0x40653052f00f4, 0x406533fbe06b9, 0x406537a4d0c7e,
+    0x40653ef6b1808, 0x4065429fa1dcd, 0x4065464892392, 0x406549f182957,
+    0x40654d9a72f1c, 0x40655143634e1, 0x406554ec53aa6, 0x406558954406b,
+    0x40655c3e34630, 0x40655fe724bf5, 0x406567390577f, 0x40656ae1f5d44,
+    0x40657233d68ce, 0x40657985b7458, 0x40657d2ea7a1d, 0x40658480885a7,
+    0x40658bd269131, 0x40659e1f1ae0a, 0x4065a919ebf59, 0x4065bb669dc32,
+    0x4065bf0f8e1f7, 0x4065cdb34f90b, 0x4065d15c3fed0, 0x4065d50530495,
+    0x4065e3a8f1ba9, 0x4065eea3c2cf8, 0x4066049964f96, 0x406608425555b,
+    0x40660beb45b20, 0x4066133d266aa, 0x40661e37f77f9, 0x406621e0e7dbe,
+    0x40662932c8948, 0x40662cdbb8f0d, 0x40663084a94d2, 0x4066342d99a97,
+    0x406637d68a05c, 0x40663f286abe6, 0x4066467a4b770, 0x40664a233bd35,
+    0x40664dcc2c2fa, 0x406651751c8bf, 0x4066551e0ce84, 0x40666b13af122,
+    0x40666ebc9f6e7, 0x4066760e80271, 0x40668109513c0, 0x406684b241985,
+    0x40668fad12ad4, 0x406696fef365e, 0x40669aa7e3c23, 0x40669e50d41e8,
+    0x4066bb9857010, 0x4066bf41475d5, 0x4066c6932815f, 0x4066ca3c18724,
+    0x4066d536e9873, 0x4066dc88ca3fd, 0x4066e031ba9c2, 0x4066eb2c8bb11,
+    0x406704cb1e374, 0x40670c1cfeefe, 0x40670fc5ef4c3, 0x4067136edfa88,
+    0x40671ac0c0612, 0x40673bb1339ff, 0x406746ac04b4e, 0x40676b4568500,
+    0x40676eee58ac5, 0x406776403964f, 0x40678fdecbeb2, 0x4067b4782f864,
+    0x4067c6c4e153d, 0x4067ce16c20c7, 0x4067d568a2c51, 0x4067eb5e44eef,
+    0x4067f2b025a79, 0x4067f6591603e, 0x406808a5c7d17, 0x40680c4eb82dc,
+    0x40680ff7a88a1, 0x406817498942b, 0x40681e9b69fb5, 0x406825ed4ab3f,
+    0x40682d3f2b6c9, 0x406834910c253, 0x40683be2ecddd, 0x406846ddbdf2c,
+    0x40684a86ae4f1, 0x40684e2f9eab6, 0x406855817f640, 0x4068592a6fc05,
+    0x40685cd3601ca, 0x406867ce31319, 0x40686b77218de, 0x40687671f2a2d,
+    0x40687a1ae2ff2, 0x40688515b4141, 0x406888bea4706, 0x40689eb4469a4,
+    0x4068a6062752e, 0x4068a9af17af3, 0x4068ad58080b8, 0x4068b100f867d,
+    0x4068b4a9e8c42, 0x4068b852d9207, 0x4068c34daa356, 0x4068ce487b4a5,
+    0x4068d9434c5f4, 0x4068dcec3cbb9, 0x4068f68acf41c, 0x4069052e90b30,
+    0x40691b2432dce, 0x40691ecd23393, 0x4069261f03f1d, 0x406929c7f44e2,
+    0x40692d70e4aa7, 0x40693119d506c, 0x406934c2c5631, 0x4069386bb5bf6,
+    0x40693c14a61bb, 0x4069436686d45, 0x406955b338a1e, 0x4069595c28fe3,
+    0x40695d05195a8, 0x406960ae09b6d, 0x40696f51cb281, 0x406972fabb846,
+    0x4069819e7cf5a, 0x406988f05dae4, 0x406993eb2ec33, 0x40699b3d0f7bd,
+    0x4069ad89c1496, 0x4069b4dba2020, 0x4069d22324e48, 0x4069dd1df5f97,
+    0x4069f6bc887fa, 0x4069fa6578dbf, 0x406a09093a4d3, 0x406a105b1b05d,
+    0x406a2650bd2fb, 0x406a2da29de85, 0x406a4741306e8, 0x406a4aea20cad,
+    0x406a4e9311272, 0x406a598de23c1, 0x406a60dfc2f4b, 0x406a6bda9409a,
+    0x406a7a7e557ae, 0x406a7e2745d73, 0x406a8579268fd, 0x406a97c5d85d6,
+    0x406a9b6ec8b9b, 0x406ac75a0d0d7, 0x406acb02fd69c, 0x406af34551613,
+    0x406afa973219d, 0x406b0592032ec, 0x406b093af38b1, 0x406b0ce3e3e76,
+    0x406b1435c4a00, 0x406b17deb4fc5, 0x406b2682766d9, 0x406b2a2b66c9e,
+    0x406b2dd457263, 0x406b3c7818977, 0x406b61117c329, 0x406b64ba6c8ee,
+    0x406b6c0c4d478, 0x406b7e58ff151, 0x406b85aadfcdb, 0x406b944ea13ef,
+    0x406ba2f262b03, 0x406baa444368d, 0x406bb53f147dc, 0x406bcb34b6a7a,
+    0x406be87c398a2, 0x406bf3770a9f1, 0x406c14677ddde, 0x406c1bb95e968,
+    0x406c47a4a2ea4, 0x406c5648645b8, 0x406c64ec25ccc, 0x406c8233a8af4,
+    0x406c89858967e, 0x406c9f7b2b91c, 0x406caa75fca6b, 0x406cae1eed030,
+    0x406cb570cdbba, 0x406cbcc2ae744, 0x406cda0a3156c, 0x406cf751b4394,
+    0x406d1f940830b, 0x406d68c6cf66f, 0x406d7b1381348, 0x406dc446486ac,
+    0x406dd692fa385, 0x406de8dfac05e, 0x406dfed54e2fc, 0x406e1873e0b5f,
+    0x406e40b634ad6, 0x406e5302e67af, 0x406e56abd6d74, 0x406e73f359b9c,
+    0x406e94e3ccf89, 0x406ea7307ec62, 0x406eaad96f227, 0x406ec0cf114c5,
+    0x406ef40c3658b, 0x406f0658e8264, 0x406f1153b93b3, 0x406f1c4e8a502,
+    0x406f658151866, 0x406f742512f7a, 0x406f8dc3a57dd, 0x406fa010574b6,
+    0x406fb25d0918f, 0x407018d75331b, 0x40702eccf55b9, 0x40703275e5b7e,
+    0x4070361ed6143, 0x40704119a7292, 0x4070486b87e1c, 0x40704c14783e1,
+    0x407065b30ac44, 0x40706d04eb7ce, 0x40707ba8acee2, 0x40708a4c6e5f6,
+    0x4070919e4f180, 0x4070ab3ce19e3, 0x4070b9e0a30f7, 0x4070de7a06aa9,
+    0x4070e974d7bf8, 0x4070f0c6b8782, 0x407103136a45b, 0x40712b55be3d2,
+    0x40712efeae997, 0x40713da2700ab, 0x407144f450c35, 0x40714c46317bf,
+    0x40715ae9f2ed3, 0x40715e92e3498, 0x407170df95171, 0x4071783175cfb,
+    0x40717bda662c0, 0x40719578f8b23, 0x40719921e90e8, 0x40719ccad96ad,
+    0x4071a073c9c72, 0x4071b6696bf10, 0x4071ba125c4d5, 0x4071bdbb4ca9a,
+    0x4071c50d2d624, 0x4071d007fe773, 0x4071d3b0eed38, 0x4071d759df2fd,
+    0x4071e5fda0a11, 0x4071f4a162125, 0x4071ff9c33274, 0x40720a97043c3,
+    0x40721591d5512, 0x4072193ac5ad7, 0x40721ce3b609c, 0x4072208ca6661,
+    0x4072243596c26, 0x40723682488ff, 0x40723dd429489, 0x407245260a013,
+    0x40725020db162, 0x40725772bbcec, 0x40725ec49c876, 0x4072626d8ce3b,
+    0x407269bf6d9c5, 0x40726d685df8a, 0x407274ba3eb14, 0x40728e58d1377,
+    0x4072a44e73615, 0x4072a7f763bda, 0x4072b2f234d29, 0x4072bded05e78,
+    0x4072c195f643d, 0x4072c8e7d6fc7, 0x4072d78b986db, 0x4072e62f59def,
+    0x4072ed813a979, 0x40730e71add66, 0x4073196c7eeb5, 0x40732f6221153,
+    0x40733e05e2867, 0x407341aed2e2c, 0x40734557c33f1, 0x40735b4d6568f,
+    0x4073629f46219, 0x407369f126da3, 0x407371430792d, 0x40737c3dd8a7c,
+    0x4073c919903a5, 0x4073ccc28096a, 0x4073ea0a03792, 0x4073edb2f3d57,
+    0x4073f15be431c, 0x4073f504d48e1, 0x4073fc56b546b, 0x4074199e38293,
+    0x40741d4728858, 0x40742841f99a7, 0x40742f93da531, 0x407436e5bb0bb,
+    0x40746a22e0181, 0x40746dcbd0746, 0x40747174c0d0b, 0x4074a85ad6396,
+    0x4074ac03c695b, 0x4074baa78806f, 0x4074c1f968bf9, 0x4074df40eba21,
+    0x40753e6955023, 0x40754212455e8, 0x40759d91be625, 0x4075bad94144d,
+    0x4075d820c4275, 0x4075dbc9b483a, 0x407650e7c00da, 0x40765839a0c64,
+    0x40765be291229, 0x4076758123a8c, 0x40769a1a8743e, 0x4076a5155858d,
+    0x4076b3b919ca1, 0x4076d4a98d08e, 0x4076ee481f8f1, 0x4077168a73868,
+    0x40772185449b7, 0x40773029060cb, 0x40774d7088ef3, 0x40775119794b8,
+    0x407796a350257, 0x4077a19e213a6, 0x4077a8f001f30, 0x4077b041e2aba,
+    0x4077c63784d58, 0x4077e37f07b80, 0x4077f974a9e1e, 0x40781e0e0d7d0,
+    0x4078305abf4a9, 0x407854f422e5b, 0x40785c46039e5, 0x407884885795c,
+    0x4079084a24910, 0x40792591a7738, 0x40792ce3882c2, 0x40793b87499d6,
+    0x407942d92a560, 0x40794a2b0b0ea, 0x4079517cebc74, 0x407967728df12,
+    0x40798fb4e1e89, 0x40799706c2a13, 0x4079a20193b62, 0x4079ca43e7ad9,
+    0x4079f62f2c015, 0x407a296c510db, 0x407a34672222a, 0x407a605266766,
+    0x407a67a4472f0, 0x407a764808a04, 0x407aa9852daca, 0x407ab828ef1de,
+    0x407abf7acfd68, 0x407adcc252b90, 0x407aef0f04869, 0x407afa09d59b8,
+    0x407b13a86821b, 0x407b25f519ef4, 0x407b3498db608, 0x407b5cdb2f57f,
+    0x407b642d10109, 0x407b9ebc15d59, 0x407bc6fe69cd0, 0x407c05365fee5,
+    0x407c1ed4f2748, 0x407c2d78b3e5c, 0x407c3121a4421, 0x407c55bb07dd3,
+    0x407c76ab7b1c0, 0x407c904a0da23, 0x407c9b44deb72, 0x407ca296bf6fc,
+    0x407cbc3551f5f, 0x407cc38732ae9, 0x407cfe1638739, 0x407d140bda9d7,
+    0x407d38a53e389, 0x407d55ecc11b1, 0x407d9424b73c6, 0x407da2c878ada,
+    0x407dadc349c29, 0x407de4a95f2b4, 0x407df34d209c8, 0x407e2a3336053,
+    0x407e527589fca, 0x407e5d705b119, 0x407e770eed97c, 0x407e85b2af090,
+    0x407ee132280cd, 0x407ee88408c57, 0x407eefd5e97e1, 0x407efad0ba930,
+    0x407f09747c044, 0x407f2a64ef431, 0x407f3908b0b45, 0x407f440381c94,
+    0x407f52a7433a8, 0x407f5da2144f7, 0x407f64f3f5081, 0x407f7e92878e4,
+    0x407f823b77ea9, 0x407fa32beb296, 0x407fcf172f7d2, 0x4080025454898,
+    0x408009a635422, 0x40800d4f259e7, 0x408010f815fac, 0x408014a106571,
+    0x40801849f6b36, 0x40801f9bd76c0, 0x408026edb824a, 0x40802e3f98dd4,
+    0x408031e889399, 0x408035917995e, 0x4080393a69f23, 0x40803ce35a4e8,
+    0x4080408c4aaad, 0x408044353b072, 0x408047de2b637, 0x40805681ecd4b,
+    0x40805a2add310, 0x40805dd3cd8d5, 0x4080617cbde9a, 0x40806c778efe9,
+    0x408070207f5ae, 0x408073c96fb73, 0x4080777260138, 0x40807b1b506fd,
+    0x40807ec440cc2, 0x40808d68023d6, 0x40809110f299b, 0x40809862d3525,
+    0x40809fb4b40af, 0x4080a70694c39, 0x4080aaaf851fe, 0x4080ae58757c3,
+    0x4080b5aa5634d, 0x4080c0a52749c, 0x4080c44e17a61, 0x4080cb9ff85eb,
+    0x4080cf48e8bb0, 0x4080da43b9cff, 0x4080e53e8ae4e, 0x4080e8e77b413,
+    0x4080fedd1d6b1, 0x4081062efe23b, 0x40811c24a04d9, 0x40811fcd90a9e,
+    0x4081237681063, 0x4081271f71628, 0x40812ac861bed, 0x40812e71521b2,
+    0x40814bb8d4fda, 0x40814f61c559f, 0x40815a5c966ee, 0x408165576783d,
+    0x4081690057e02, 0x40816ca9483c7, 0x408170523898c, 0x408177a419516,
+    0x408189f0cb1ef, 0x40818d99bb7b4, 0x408194eb9c33e, 0x40819c3d7cec8,
+    0x4081a38f5da52, 0x4081a7384e017, 0x4081b2331f166, 0x4081b5dc0f72b,
+    0x4081b984ffcf0, 0x4081bd2df02b5, 0x4081c0d6e087a, 0x4081d32392553,
+    0x4081da75730dd, 0x4081ecc224db6, 0x4081f41405940, 0x4081f7bcf5f05,
+    0x408218ad692f2, 0x40821fff49e7c, 0x40822afa1afcb, 0x40823d46ccca4,
+    0x40824498ad82e, 0x4082533c6ef42, 0x40825e3740091, 0x408261e030656,
+    0x40826932111e0, 0x4082742ce232f, 0x408277d5d28f4, 0x40827f27b347e,
+    0x4082c10899c58, 0x4082c4b18a21d, 0x4082cfac5b36c, 0x4082e1f90d045,
+    0x4082e5a1fd60a, 0x4082e94aedbcf, 0x4082f09cce759, 0x4082f445bed1e,
+    0x4082f7eeaf2e3, 0x4082fb979f8a8, 0x4082ff408fe6d, 0x40830692709f7,
+    0x40830de451581, 0x40831c8812c95, 0x40832b2bd43a9, 0x40832ed4c496e,
+    0x4083327db4f33, 0x40833d7886082, 0x40834c1c47796, 0x40834fc537d5b,
+    0x40836211e9a34, 0x40836d0cbab83, 0x408370b5ab148, 0x408378078bcd2,
+    0x40837bb07c297, 0x40838a543d9ab, 0x4083954f0eafa, 0x40839ca0ef684,
+    0x4083a3f2d020e, 0x4083a79bc07d3, 0x4083bd9162a71, 0x4083c4e3435fb,
+    0x4083d38704d0f, 0x4083dad8e5899, 0x4083de81d5e5e, 0x4083f0ce87b37,
+    0x408406c429dd5, 0x4084240bacbfd, 0x40842b5d8d787, 0x40842f067dd4c,
+    0x40843a014ee9b, 0x408448a5105af, 0x40845af1c2288, 0x408469958399c,
+    0x4084749054aeb, 0x40847f8b25c3a, 0x408486dd067c4, 0x40848a85f6d89,
+    0x4084a424895ec, 0x4084ab766a176, 0x4084ba1a2b88a, 0x4084d761ae6b2,
+    0x4084e9ae6038b, 0x4084ed5750950, 0x4084f10040f15, 0x4084fbfb12064,
+    0x4084ffa402629, 0x40850a9ed3778, 0x408511f0b4302, 0x408532e1276ef,
+    0x40854184e8e03, 0x4085452dd93c8, 0x40855ecc6bc2b, 0x408595b2812b6,
+    0x4085995b7187b, 0x4085a0ad52405, 0x4085a7ff32f8f, 0x4085aba823554,
+    0x4085d79367a90, 0x4085f883dae7d, 0x4085ffd5bba07, 0x4086037eabfcc,
+    0x40860e797d11b, 0x40861d1d3e82f, 0x4086455f927a6, 0x4086505a638f5,
+    0x408657ac4447f, 0x40865b5534a44, 0x408669f8f6158, 0x4086a830ec36d,
+    0x4086b32bbd4bc, 0x4086ccca4fd1f, 0x4086db6e11433, 0x4086e2bff1fbd,
+    0x4086e668e2582, 0x4086ea11d2b47, 0x40870eab364f9, 0x40872849c8d5c,
+    0x40872f9ba98e6, 0x408736ed8a470, 0x40873e3f6affa, 0x40874ce32c70e,
+    0x40876681bef71, 0x40876dd39fafb, 0x40878ec412ee8, 0x40879d67d45fc,
+    0x4087a4b9b5186, 0x4087afb4862d5, 0x4087b70666e5f, 0x4087c20137fae,
+    0x4087ccfc090fd, 0x4087df48badd6, 0x4087f53e5d074, 0x408800392e1c3,
+    0x4088162ed0461, 0x408819d7c0a26, 0x408824d291b75, 0x4088337653289,
+    0x40883ac833e13, 0x408845c304f62, 0x40884d14e5aec, 0x408850bdd60b1,
+    0x4088630a87d8a, 0x408879002a028, 0x40889647ace50, 0x40890b65b86f0,
+    0x408912b79927a, 0x40891db26a3c9, 0x40893af9ed1f1, 0x408950ef8f48f,
+    0x408966e53172d, 0x40897cdad39cb, 0x40898083c3f90, 0x408996796622e,
+    0x4089a1743737d, 0x4089b3c0e9056, 0x4089bb12c9be0, 0x4089d1086be7e,
+    0x408a253604331, 0x408a2c87e4ebb, 0x408a3030d5480, 0x408a427d87159,
+    0x408a5c1c199bc, 0x408a6e68cb695, 0x408a8bb04e4bd, 0x408a8f593ea82,
+    0x408a96ab1f60c, 0x408abb4482fbe, 0x408ad13a2525c, 0x408aee81a8084,
+    0x408b340b7ee23, 0x408b5ff6c335f, 0x408b639fb3924, 0x408b6e9a84a73,
+    0x408ba1d7a9b39, 0x408ba5809a0fe, 0x408bb4245b812, 0x408bcdc2ee075,
+    0x408be761808d8, 0x408beeb361462, 0x408bf60541fec, 0x408bf9ae325b1,
+    0x408c0851f3cc5, 0x408c2942670b2, 0x408c2ceb57677, 0x408c309447c3c,
+    0x408c3b8f18d8b, 0x408c3f3809350, 0x408c8c13c0c79, 0x408c9365a1803,
+    0x408c9e6072952, 0x408ca5b2534dc, 0x408cb7ff051b5, 0x408cbf50e5d3f,
+    0x408cc6a2c68c9, 0x408ccdf4a7453, 0x408cd54687fdd, 0x408cdc9868b67,
+    0x408ce0415912c, 0x408ce3ea496f1, 0x408cf636fb3ca, 0x408d0131cc519,
+    0x408d0fd58dc2d, 0x408d3bc0d2169, 0x408d4e0d83e42, 0x408d555f649cc,
+    0x408d5cb145556, 0x408d605a35b1b, 0x408d72a6e77f4, 0x408d764fd7db9,
+    0x408d79f8c837e, 0x408d7da1b8943, 0x408d814aa8f08, 0x408d889c89a92,
+    0x408d8fee6a61c, 0x408d9e922bd30, 0x408dad35ed444, 0x408db830be593,
+    0x408dbbd9aeb58, 0x408e43446c0d1, 0x408e46ed5c696, 0x408e4e3f3d220,
+    0x408e72d8a0bd2, 0x408e8525528ab, 0x408e8c7733435, 0x408e9b1af4b49,
+    0x408ed5a9fa799, 0x408edcfbdb323, 0x408ee44dbbead, 0x408f053e2f29a,
+    0x408f13e1f09ae, 0x408f2285b20c2, 0x408f29d792c4c, 0x408f3129737d6,
+    0x408f4376254af, 0x408f4e70f65fe, 0x408fa6477f076, 0x408fd984a413c,
+    0x408fe82865850, 0x408febd155e15, 0x408ffa7517529, 0x409001c6f80b3,
+    0x4090106ab97c7, 0x40901b658a916, 0x40901f0e7aedb, 0x409022b76b4a0,
+    0x40903c55fdd03, 0x40904ea2af9dc, 0x409068414223f, 0x40906f9322dc9,
+    0x40908588c5067, 0x40909b7e67305, 0x4090a2d047e8f, 0x4090aa2228a19,
+    0x4090c017cacb7, 0x4090ec030f1f3, 0x4090faa6d0907, 0x409114456316a,
+    0x40911f40342b9, 0x40912de3f59cd, 0x4091478288230, 0x409159cf39f09,
+    0x4091736dcc76c, 0x409198073011e, 0x4091a6aaf1832, 0x4091c04984095,
+    0x4091cb44551e4, 0x4091d9e8168f8, 0x4091fad889ce5, 0x4091fe817a2aa,
+    0x4092022a6a86f, 0x409214771c548, 0x409231be9f370, 0x40923cb9704bf,
+    0x4092406260a84, 0x409252af1275d, 0x409268a4b49fb, 0x4092948ff8f37,
+    0x40929be1d9ac1, 0x40929f8aca086, 0x4092a333ba64b, 0x4092aa859b1d5,
+    0x4092b5806c324, 0x4092e16bb0860, 0x4093060514212, 0x409309ae047d7,
+    0x40931851c5eeb, 0x40932e4768189, 0x4093409419e62, 0x409352e0cbb3b,
+    0x40935ddb9cc8a, 0x4093861df0c01, 0x40939fbc83464, 0x4093a36573a29,
+    0x4093d2f9a852a, 0x4093d6a298aef, 0x4093e8ef4a7c8, 0x40940d88ae17a,
+    0x409414da8ed04, 0x40943973f26b6, 0x40945a6465aa3, 0x40945e0d56068,
+    0x40947b54d8e90, 0x40949fee3c842, 0x4094a3972ce07, 0x4094aae90d991,
+    0x4094c487a01f4, 0x4094cbd980d7e, 0x4094d32b61908, 0x4094f7c4c52ba,
+    0x40950668869ce, 0x4095116357b1d, 0x409535fcbb4cf, 0x40953d4e9c059,
+    0x40954bf25d76d, 0x409556ed2e8bc, 0x40956ce2d0b5a, 0x4095708bc111f,
+    0x40958681633bd, 0x40958dd343f47, 0x40959c770565b, 0x4095ecfbad549,
+    0x40960a4330371, 0x4096278ab3199, 0x40962edc93d23, 0x4096571ee7c9a,
+    0x40965e70c8824, 0x409665c2a93ae, 0x4096780f5b087, 0x409686b31c79b,
+    0x40968a5c0cd60, 0x4096cc3cf353a, 0x4096de89a5213, 0x4097032308bc5,
+    0x40971918aae63, 0x409765f46278c, 0x409770ef338db, 0x4097784114465,
+    0x4097993187852, 0x4097d7697da67, 0x4097db126e02c, 0x4097e2644ebb6,
+    0x4097ed5f1fd05, 0x4097f108102ca, 0x40980354c1fa3, 0x40980e4f930f2,
+    0x40983691e7069, 0x40984535a877d, 0x40984c8789307, 0x4098bdfca45e2,
+    0x4098d79b36e45, 0x4098deed179cf, 0x4098f139c96a8, 0x40990e814c4d0,
+    0x40991d250dbe4, 0x409920cdfe1a9, 0x409941be71596, 0x40995b5d03df9,
+    0x40996657d4f48, 0x409978a486c21, 0x40998e9a28ebf, 0x4099924319484,
+    0x409995ec09a49, 0x4099c9292eb0f, 0x4099df1ed0dad, 0x4099edc2924c1,
+    0x4099f16b82a86, 0x4099f8bd63610, 0x409a0eb3058ae, 0x409a41f02a974,
+    0x409a49420b4fe, 0x409a5093ec088, 0x409a62e09dd61, 0x409a6ddb6eeb0,
+    0x409a78d63ffff, 0x409a99c6b33ec, 0x409a9d6fa39b1, 0x409aa11893f76,
+    0x409aafbc5568a, 0x409aedf44b89f, 0x409b0ee4bec8c, 0x409b24da60f2a,
+    0x409b337e2263e, 0x409b4221e3d52, 0x409b45cad4317, 0x409b63125713f,
+    0x409b7907f93dd, 0x409b7cb0e99a2, 0x409b8402ca52c, 0x409b9da15cd8f,
+    0x409bac451e4a3, 0x409c1a11491b9, 0x409c250c1a308, 0x409c3006eb457,
+    0x409c33afdba1c, 0x409c3b01bc5a6, 0x409c50f75e844, 0x409c5bf22f993,
+    0x409c6a95f10a7, 0x409cac76d7881, 0x409cbec38955a, 0x409cc26c79b1f,
+    0x409cee57be05b, 0x409cf5a99ebe5, 0x409d0f4831448, 0x409d169a11fd2,
+    0x409d49d737098, 0x409d880f2d2ad, 0x409d9a5bdef86, 0x409dcd990404c,
+    0x409de38ea62ea, 0x409df98448588, 0x409e29187d089, 0x409e306a5dc13,
+    0x409e6ea253e28, 0x409e724b443ed, 0x409e7d461553c, 0x409e8840e668b,
+    0x409e8be9d6c50, 0x409ea1df78eee, 0x409ea93159a78, 0x409eb7d51b18c,
+    0x409ed173ad9ef, 0x409f04b0d2ab5, 0x409f1aa674d53, 0x409f25a145ea2,
+    0x409f37edf7b7b, 0x409f3f3fd8705, 0x409f6ed40d206, 0x409f7d77ce91a,
+    0x409f8fc4805f3, 0x409f97166117d, 0x409f9e6841d07, 0x409fad0c0341b,
+    0x409fbf58b50f4, 0x40a001399b8ce, 0x40a00fdd5cfe2, 0x40a013864d5a7,
+    0x40a0556733d81, 0x40a05cb91490b, 0x40a06f05c65e4, 0x40a072aeb6ba9,
+    0x40a088a458e47, 0x40a08c4d4940c, 0x40a0a242eb6aa, 0x40a0b48f9d383,
+    0x40a0bbe17df0d, 0x40a0e423d1e84, 0x40a0f2c793598, 0x40a113b806985,
+    0x40a16093be2ae, 0x40a16f377f9c2, 0x40a181843169b, 0x40a188d612225,
+    0x40a19779d3939, 0x40a19ecbb44c3, 0x40a1d208d9589, 0x40a1fa4b2d500,
+    0x40a20545fe64f, 0x40a229df62001, 0x40a2388323715, 0x40a255caa653d,
+    0x40a2855edb03e, 0x40a28cb0bbbc8, 0x40a2dd3563ab6, 0x40a2e83034c05,
+    0x40a2ef821578f, 0x40a2fe25d6ea3, 0x40a301cec7468, 0x40a30920a7ff2,
+    0x40a3107288b7c, 0x40a3141b79141, 0x40a322bf3a855, 0x40a34006bd67d,
+    0x40a352536f356, 0x40a376ecd2d08, 0x40a3bc76a9aa7, 0x40a3d9be2c8cf,
+    0x40a3efb3ceb6d, 0x40a3f35cbf132, 0x40a4353da590c, 0x40a438e695ed1,
+    0x40a43c8f86496, 0x40a4528528734, 0x40a482195d235, 0x40a485c24d7fa,
+    0x40a4aa5bb11ac, 0x40a4b556822fb, 0x40a4d9efe5cad, 0x40a4f73768ad5,
+    0x40a4fae05909a, 0x40a50d2d0ad73, 0x40a539184f2af, 0x40a57750454c4,
+    0x40a59840b88b1, 0x40a5a33b89a00, 0x40a5cb7ddd977, 0x40a5cf26cdf3c,
+    0x40a5ddca8f650, 0x40a61c0285865, 0x40a62aa646f79, 0x40a63cf2f8c52,
+    0x40a647edc9da1, 0x40a6b211044f2, 0x40a6cf588731a, 0x40a6da5358469,
+    0x40a6f3f1eaccc, 0x40a70d907d52f, 0x40a71fdd2f208, 0x40a7481f8317f,
+    0x40a794fb3aaa8, 0x40a7e1d6f23d1, 0x40a7f423a40aa, 0x40a840ff5b9d3,
+    0x40a85e46de7fb, 0x40a86598bf385, 0x40a8743c80a99, 0x40a8868932772,
+    0x40a8cfbbf9ad6, 0x40a927928254e, 0x40a92ee4630d8, 0x40a93d88247ec,
+    0x40a970c5498b2, 0x40a9746e39e77, 0x40a9ab544f502, 0x40a9b2a63008c,
+    0x40a9bda1011db, 0x40a9d396a3479, 0x40a9e23a64b8d, 0x40a9f48716866,
+    0x40a9ff81e79b5, 0x40aa48b4aed19, 0x40aa749ff3255, 0x40aa9ce2471cc,
+    0x40aaffb3a0d93, 0x40ab244d04745, 0x40ab2b9ee52cf, 0x40ab3a42a69e3,
+    0x40ab7fcc7d782, 0x40ab92192f45b, 0x40aba465e1134, 0x40ababb7c1cbe,
+    0x40abcca8350ab, 0x40ac3e1d50386, 0x40acb6e44c1eb, 0x40acdf26a0162,
+    0x40ace2cf90727, 0x40acf51c42400, 0x40ad1263c5228, 0x40ad24b076f01,
+    0x40ad335438615, 0x40ad6a3a4dca0, 0x40ad78de0f3b4, 0x40ad8b2ac108d,
+    0x40add45d883f1, 0x40addbaf68f7b, 0x40adea532a68f, 0x40ae0b439da7c,
+    0x40ae21393fd1a, 0x40ae4229b3107, 0x40ae50cd7481b, 0x40ae7566d81cd,
+    0x40ae790fc8792, 0x40ae96574b5ba, 0x40aea8a3fd293, 0x40aedbe122359,
+    0x40aeea84e3a6d, 0x40af216af90f8, 0x40af46045caaa, 0x40af6e46b0a21,
+    0x40af71efa0fe6, 0x40af7598915ab, 0x40af87e543284, 0x40afb77977d85,
+    0x40afc9c629a5e, 0x40afeab69ce4b, 0x40aff95a5e55f, 0x40b01a4ad194c,
+    0x40b033e9641af, 0x40b05c2bb8126, 0x40b06e7869dff, 0x40b075ca4a989,
+    0x40b079733af4e, 0x40b07d1c2b513, 0x40b0b059505d9, 0x40b0dc4494b15,
+    0x40b0e73f65c64, 0x40b0f5e327378, 0x40b10f81b9bdb, 0x40b116d39a765,
+    0x40b129204c43e, 0x40b12cc93ca03, 0x40b19e3e57cde, 0x40b1b7dcea541,
+    0x40b1d17b7cda4, 0x40b1fd66c12e0, 0x40b23f47a7aba, 0x40b27284ccb80,
+    0x40b2887a6ee1e, 0x40b2971e30532, 0x40b29e70110bc, 0x40b2d1ad36182,
+    0x40b2e050f7896, 0x40b2e3f9e7e5b, 0x40b32d2caf1bf, 0x40b33f7960e98,
+    0x40b34e1d225ac, 0x40b3556f03136, 0x40b372b685f5e, 0x40b38ffe08d86,
+    0x40b393a6f934b, 0x40b3f2cf6294d, 0x40b413bfd5d3a, 0x40b422639744e,
+    0x40b4310758b62, 0x40b4609b8d663, 0x40b4818c00a50, 0x40b48534f1015,
+    0x40b4a9ce549c7, 0x40b4ad7744f8c, 0x40b4bfc3f6c65, 0x40b4e8064abdc,
+    0x40b4ebaf3b1a1, 0x40b4f3011bd2b, 0x40b522955082c, 0x40b5263e40df1,
+    0x40b53c33e308f, 0x40b581bdb9e2e, 0x40b5890f9a9b8, 0x40b5940a6bb07,
+    0x40b5a6571d7e0, 0x40b5b151ee92f, 0x40b5b4fadeef4, 0x40b5bc4cbfa7e,
+    0x40b62a18ea794, 0x40b638bcabea8, 0x40b643b77cff7, 0x40b64eb24e146,
+    0x40b6525b3e70b, 0x40b6943c24ee5, 0x40b6a2dfe65f9, 0x40b6a688d6bbe,
+    0x40b6e4c0ccdd3, 0x40b6fab66f071, 0x40b72df394137, 0x40b75d87c8c38,
+    0x40b777265b49b, 0x40b7946dde2c3, 0x40b7b1b5610eb, 0x40b7e89b76776,
+    0x40b8232a7c3c6, 0x40b86162725db, 0x40b8cf2e9d2f1, 0x40b8e8cd2fb54,
+    0x40b9026bc23b7, 0x40b909bda2f41, 0x40b92aae1632e, 0x40b940a3b85cc,
+    0x40b97789cdc57, 0x40b97b32be21c, 0x40b9862d8f36b, 0x40b994d150a7f,
+    0x40b9b218d38a7, 0x40b9cbb76610a, 0x40b9eca7d94f7, 0x40ba0d984c8e4,
+    0x40ba2adfcf70c, 0x40ba3231b0296, 0x40ba482752534, 0x40ba532223683,
+    0x40ba6cc0b5ee6, 0x40ba950309e5d, 0x40ba9ffddafac, 0x40bae587b1d4b,
+    0x40baf08282e9a, 0x40bb0a21156fd, 0x40bb5353dca61, 0x40bb74444fe4e,
+    0x40bb98ddb3800, 0x40bbc871e8301, 0x40bbde678a59f, 0x40bc39e7035dc,
+    0x40bc44e1d472b, 0x40bc6d24286a2, 0x40bc74760922c, 0x40bc95667c619,
+    0x40bcaf050ee7c, 0x40bcb2adff441, 0x40bcb9ffdffcb, 0x40bcd39e7282e,
+    0x40bcfbe0c67a5, 0x40bd0e2d7847e, 0x40bd2f1deb86b, 0x40bd7fa293759,
+    0x40bdaf36c825a, 0x40bddb220c796, 0x40be0ab641297, 0x40be15b1123e6,
+    0x40be195a029ab, 0x40be1d02f2f70, 0x40be36a1857d3, 0x40be628cc9d0f,
+    0x40beabbf91073, 0x40beffed29526, 0x40bf5f1592b28, 0x40bffc75f233f,
+    0x40c0001ee2904, 0x40c0544c7adb7, 0x40c05b9e5b941, 0x40c0a128326e0,
+    0x40c0ac230382f, 0x40c119ef2e545, 0x40c1338dc0da8, 0x40c1498363046,
+    0x40c171c5b6fbd, 0x40c1791797b47, 0x40c192b62a3aa, 0x40c1c24a5eeab,
+    0x40c1ea8cb2e22, 0x40c1ee35a33e7, 0x40c2809b31aaf, 0x40c29690d3d4d,
+    0x40c2b7814713a, 0x40c2cd76e93d8, 0x40c2d4c8c9f62, 0x40c2e7157bc3b,
+    0x40c30805ef028, 0x40c3379a23b29, 0x40c3722929779, 0x40c3bf04e10a2,
+    0x40c3c2add1667, 0x40c4133279555, 0x40c41e2d4a6a4, 0x40c4a1ef17658,
+    0x40c4bf369a480, 0x40c4d1834c159, 0x40c4d52c3c71e, 0x40c50fbb4236e,
+    0x40c52959d4bd1, 0x40c55c96f9c97, 0x40c5603fea25c, 0x40c5e401b7210,
+    0x40c5f9f7594ae, 0x40c64e24f1961, 0x40c66071a363a, 0x40c667c3841c4,
+    0x40c693aec8700, 0x40c6bbf11c677, 0x40c6ce3dce350, 0x40c6d9389f49f,
+    0x40c6e433705ee, 0x40c6f2d731d02, 0x40c7017af3416, 0x40c71ec27623e,
+    0x40c74e56aad3f, 0x40c78c8ea0f54, 0x40c7903791519, 0x40c7b12804906,
+    0x40c7dd1348e42, 0x40c817a24ea92, 0x40c8229d1fbe1, 0x40c8a2b5fc5d0,
+    0x40c8bc548ee33, 0x40c8caf850547, 0x40c8cea140b0c, 0x40c8d24a310d1,
+    0x40c8ebe8c3934, 0x40c8fa8c85048, 0x40c917d407e70, 0x40c947683c971,
+    0x40c9b53467687, 0x40c9ced2f9eea, 0x40c9d9cdcb039, 0x40ca26a982962,
+    0x40ca354d44076, 0x40ca6138885b2, 0x40ca688a6913c, 0x40ca6c3359701,
+    0x40ca772e2a850, 0x40ca7e800b3da, 0x40ca897adc529, 0x40ca8d23ccaee,
+    0x40cab90f1102a, 0x40cac409e2179, 0x40cad9ff84417, 0x40cadda8749dc,
+    0x40caf39e16c7a, 0x40cb2e2d1c8ca, 0x40cb31d60ce8f, 0x40cb5a1860e06,
+    0x40cb7eb1c47b8, 0x40cbaa9d08cf4, 0x40cbbce9ba9cd, 0x40cc445477f46,
+    0x40cc47fd6850b, 0x40cc52f83965a, 0x40cc6544eb333, 0x40cc703fbc482,
+    0x40cc89de4ece5, 0x40cccf6825a84, 0x40cce906b82e7, 0x40cd189aecde8,
+    0x40cd1feccd972, 0x40cd398b601d5, 0x40cd5329f2a38, 0x40cd7b6c469af,
+    0x40cdaea96ba75, 0x40cdc49f0dd13, 0x40cdd342cf427, 0x40ce18cca61c6,
+    0x40ce1c759678b, 0x40ce23c777315, 0x40ce2ec248464, 0x40ce326b38a29,
+    0x40ce5aad8c9a0, 0x40ce70a32ec3e, 0x40ceab323488e, 0x40cedac66938f,
+    0x40cede6f59954, 0x40cf1ca74fb69, 0x40cf4892940a5, 0x40cf623126908,
+    0x40cf7bcfb916b, 0x40cf832199cf5, 0x40cfa7bafd6a7, 0x40cfaf0cde231,
+    0x40cfba07af380, 0x40cfe99be3e81, 0x40d02b7cca65b, 0x40d0451b5cebe,
+    0x40d05eb9ef721, 0x40d069b4c0870, 0x40d0a7ecb6a85, 0x40d0e27bbc6d5,
+    0x40d1036c2fac2, 0x40d11d0ac2325, 0x40d13dfb35712, 0x40d15b42b853a,
+    0x40d171385a7d8, 0x40d1788a3b362, 0x40d18e7fdd600, 0x40d19228cdbc5,
+    0x40d1b6c231577, 0x40d1c1bd026c6, 0x40d1fff4f88db, 0x40d219938b13e,
+    0x40d23a83fe52b, 0x40d2a0fe486b7, 0x40d2a4a738c7c, 0x40d2e6881f456,
+    0x40d30b2182e08, 0x40d319c54451c, 0x40d32c11f61f5, 0x40d39d87114d0,
+    0x40d3a13001a95, 0x40d3a4d8f205a, 0x40d3bace942f8, 0x40d3ea62c8df9,
+    0x40d3ee0bb93be, 0x40d3f55d99f48, 0x40d407aa4bc21, 0x40d42148de484,
+    0x40d424f1cea49, 0x40d433959015d, 0x40d4841a3804b, 0x40d4c9a40edea,
+    0x40d4e342a164d, 0x40d4f1e662d61, 0x40d4f58f53326, 0x40d4fce133eb0,
+    0x40d5008a24475, 0x40d554b7bc928, 0x40d55c099d4b2, 0x40d575a82fd15,
+    0x40d592efb2b3d, 0x40d59dea83c8c, 0x40d5cd7eb878d, 0x40d654e975d06,
+    0x40d675d9e90f3, 0x40d6bf0cb0457, 0x40d6d502526f5, 0x40d758c41f6a9,
+    0x40d76b10d1382, 0x40d78c014476f, 0x40d7acf1b7b5c, 0x40d7b09aa8121,
+    0x40d7b443986e6, 0x40d7b7ec88cab, 0x40d7e02edcc22, 0x40d7f6247eec0,
+    0x40d8087130b99, 0x40d84dfb07938, 0x40d8554ce84c2, 0x40d86eeb7ad25,
+    0x40d8972dcec9c, 0x40d89e7faf826, 0x40d8ad2370f3a, 0x40d8ef0457714,
+    0x40d91e988c215, 0x40d92d3c4d929, 0x40d946dae018c, 0x40d96079729ef,
+    0x40d9900da74f0, 0x40d993b697ab5, 0x40d9a9ac39d53, 0x40d9dce95ee19,
+    0x40d9e0924f3de, 0x40da13cf744a4, 0x40da177864a69, 0x40da29c516742,
+    0x40da52076a6b9, 0x40da59594b243, 0x40da72f7ddaa6, 0x40da7a49be630,
+    0x40da8c9670309, 0x40da903f608ce, 0x40da9ee321fe2, 0x40dadd1b181f7,
+    0x40db34f1a0c6f, 0x40db3fec71dbe, 0x40db598b04621, 0x40dba666bbf4a,
+    0x40dbe0f5c1b9a, 0x40dbe847a2724, 0x40dc2a2888efe, 0x40dc6c096f6d8,
+    0x40dc90a2d308a, 0x40dc944bc364f, 0x40dcb19346477, 0x40dcc03707b8b,
+    0x40dcd283b9864, 0x40dcd62ca9e29, 0x40dd0217ee365, 0x40dd2a5a422dc,
+    0x40dd404fe457a, 0x40dd738d09640, 0x40dd8d2b9bea3, 0x40de099b882cd,
+    0x40de9faa06f5a, 0x40deaaa4d80a9, 0x40deb948997bd, 0x40decf3e3ba5b,
+    0x40df445c472fb, 0x40df61a3ca123, 0x40df9fdbc0338, 0x40dfcbc704874,
+    0x40dfcf6ff4e39, 0x40dfd6c1d59c3, 0x40dfe565970d7, 0x40dff7b248db0,
+    0x40e05331c1ded, 0x40e08dc0c7a3d, 0x40e0ab084a865, 0x40e0d34a9e7dc,
+    0x40e10687c38a2, 0x40e11182949f1, 0x40e12eca17819, 0x40e17453ee5b8,
+    0x40e198ed51f6a, 0x40e1d7254817f, 0x40e291cd2a7be, 0x40e2d005209d3,
+    0x40e32089c88c1, 0x40e3626aaf09b, 0x40e391fee3b9c, 0x40e3af46669c4,
+    0x40e4629c68479, 0x40e492309cf7a, 0x40e4db63642de, 0x40e4f8aae7106,
+    0x40e583be94c44, 0x40e5c1f68ae59, 0x40e5e2e6fe246, 0x40e5fc8590aa9,
+    0x40e62c19c55aa, 0x40e65badfa0ab, 0x40e6d81de64d5, 0x40e6e318b7624,
+    0x40e70b5b0b59b, 0x40e71da7bd274, 0x40e76a8374b9d, 0x40e771d555727,
+    0x40e7ac645b377, 0x40e7b00d4b93c, 0x40e7b75f2c4c6, 0x40e86e5e1e540,
+    0x40e872070eb05, 0x40e8a54433bcb, 0x40e92caef1144, 0x40e9882e6a181,
+    0x40e9bb6b8f247, 0x40e9fd4c75a21, 0x40ea3b846bc36, 0x40ea79bc61e4b,
+    0x40eaa5a7a6387, 0x40eab44b67a9b, 0x40eae036abfd7, 0x40eb0c21f0513,
+    0x40eb5554b7877, 0x40eb6b4a59b15, 0x40ec1af76b005, 0x40ec6b7c12ef3,
+    0x40ec8c6c862e0, 0x40ed2d75d60bc, 0x40ed55b82a033, 0x40ed6badcc2d1,
+    0x40edd2281645d, 0x40edebc6a8cc0, 0x40ee17b1ed1fc, 0x40ee1f03cdd86,
+    0x40ee5992d39d6, 0x40eebc642d59d, 0x40ef561b9c7ef, 0x40efa2f754118,
+    0x40f02e0b01c56, 0x40f04057b392f, 0x40f0f756a59a9, 0x40f10d4c47c47,
+    0x40f1358e9bbbe, 0x40f139378c183, 0x40f13ce07c748, 0x40f23d1235b26,
+    0x40f323a55c6a1, 0x40f332491ddb5, 0x40f3692f33440, 0x40f3aeb90a1df,
+    0x40f402e6a2692, 0x40f47804adf32, 0x40f49c9e118e4, 0x40f4c88955e20,
+    0x40f4de7ef80be, 0x40f5f84f43cff, 0x40f6f12f1c553, 0x40f7121f8f940,
+    0x40f74905a4fcb, 0x40f769f6183b8, 0x40f77c42ca091, 0x40f78ae68b7a5,
+    0x40f7b328df71c, 0x40f7d07062544, 0x40f7df1423c58, 0x40f82f98cbb46,
+    0x40f8db9ceca71, 0x40f9580cd8e9b, 0x40f98b49fdf61, 0x40f9a13fa01ff,
+    0x40f9a89180d89, 0x40f9c981f4176, 0x40f9d825b588a, 0x40fa45f1e05a0,
+    0x40fa583e92279, 0x40fa6339633c8, 0x40fa71dd24adc, 0x40fa8b7bb733f,
+    0x40fa92cd97ec9, 0x40fabb0febe40, 0x40fad4ae7e6a3, 0x40fae6fb3037c,
+    0x40fb07eba3769, 0x40fb6abcfd330, 0x40fb7d09af009, 0x40fbd137474bc,
+    0x40fc54f914470, 0x40fc724097298, 0x40fceeb0836c2, 0x40fd1a9bc7bfe,
+    0x40fd1e44b81c3, 0x40fd3f352b5b0, 0x40fd67777f527, 0x40fd6b206faec,
+    0x40fd7d6d217c5, 0x40fd9ab4a45ed, 0x40fdad01562c6, 0x40fdb45336e50,
+    0x40fdd543aa23d, 0x40fe012eee779, 0x40fe137ba0452, 0x40fe381503e04,
+    0x40fe46b8c5518, 0x40fe4e0aa60a2, 0x40fe7d9edaba3, 0x40fe8899abcf2,
+    0x40feb484f022e, 0x40feca7a924cc, 0x40feef13f5e7e, 0x40ff0c5b78ca6,
+    0x40ffeb9cbec97, 0x41001788031d3, 0x41002282d4322, 0x4100262bc48e7,
+    0x41002d7da5471, 0x410034cf85ffb, 0x41003c2166b85, 0x4100471c37cd4,
+    0x41004ac528299, 0x4100521708e23, 0x41005968e99ad, 0x41005d11d9f72,
+    0x4100680cab0c1, 0x41006bb59b686, 0x41007e024d35f, 0x410081ab3d924,
+    0x410088fd1e4ae, 0x41008ca60ea73, 0x410093f7ef5fd, 0x41009b49d0187,
+    0x41009ef2c074c, 0x4100a29bb0d11, 0x4100a644a12d6, 0x4100ad9681e60,
+    0x4100b4e8629ea, 0x4100c735146c3, 0x4100d981c639c, 0x4100dd2ab6961,
+    0x4100e82587ab0, 0x4100f6c9491c4, 0x41010915fae9d, 0x41011067dba27,
+    0x41011f0b9d13b, 0x41012a066e28a, 0x410131584ee14, 0x410138aa2f99e,
+    0x41013ffc10528, 0x4101474df10b2, 0x41014af6e1677, 0x4101599aa2d8b,
+    0x41016be754a64, 0x410181dcf6d02, 0x410197d298fa0, 0x41019f2479b2a,
+    0x4101a6765a6b4, 0x4101aa1f4ac79, 0x4101bc6bfc952, 0x4101c766cdaa1,
+    0x4101cb0fbe066, 0x4101ceb8ae62b, 0x4101e4ae508c9, 0x4101e85740e8e,
+    0x4101ec0031453, 0x4101efa921a18, 0x4101fe4ce312c, 0x41021b9465f54,
+    0x41023532f87b7, 0x41023c84d9341, 0x4102402dc9906, 0x4102527a7b5df,
+    0x41025d754c72e, 0x4102945b61db9, 0x410298045237e, 0x4102c3ef968ba,
+    0x4102cb4177444, 0x4102dd8e2911d, 0x4102e4e009ca7, 0x410310cb4e1e3,
+    0x4103390da215a, 0x41034b5a53e33, 0x410368a1d6c5b, 0x41036ff3b77e5,
+    0x410377459836f, 0x4103a6d9cce70, 0x4103aa82bd435, 0x4103cf1c20de7,
+    0x4103d66e01971, 0x4103f00c941d4, 0x4103fb0765323, 0x4104060236472,
+    0x41041fa0c8cd5, 0x410426f2a985f, 0x4104393f5b538, 0x41045dd8beeea,
+    0x41046181af4af, 0x4104652a9fa74, 0x410468d390039, 0x410477775174d,
+    0x41047ec9322d7, 0x41049115e3fb0, 0x41049867c4b3a, 0x4104b95837f27,
+    0x4104c7fbf963b, 0x4104d69fbad4f, 0x4104e8ec6ca28, 0x4105112ec099f,
+    0x41051c2991aee, 0x41052acd53202, 0x41053d1a04edb, 0x410540c2f54a0,
+    0x41054bbdc65ef, 0x41055a6187d03, 0x410561b36888d, 0x410577a90ab2b,
+    0x410591479d38e, 0x41059c426e4dd, 0x41059feb5eaa2, 0x4105c484c2454,
+    0x4105ecc7163cb, 0x4105fb6ad7adf, 0x41063250ed16a, 0x41063d4bbe2b9,
+    0x410648468f408, 0x410656ea50b1c, 0x41066937027f5, 0x41066cdff2dba,
+    0x41067f2ca4a93, 0x410682d595058, 0x41069c74278bb, 0x4106ab17e8fcf,
+    0x4106b269c9b59, 0x4106c10d8b26d, 0x4106daac1dad0, 0x4107153b23720,
+    0x4107412667c5c, 0x410744cf58221, 0x41074fca29370, 0x410798fcf06d4,
+    0x4107aef292972, 0x4107b9ed63ac1, 0x4107bd9654086, 0x4107e981985c2,
+    0x4107f47c69711, 0x41080a720b9af, 0x4108156cdcafe, 0x41081915cd0c3,
+    0x41081cbebd688, 0x410827b98e7d7, 0x41082f0b6f361, 0x41083a06404b0,
+    0x41084501115ff, 0x410865f1849ec, 0x41087be726c8a, 0x41088a8ae839e,
+    0x4108af244bd50, 0x4108b2cd3c315, 0x4108d014bf13d, 0x41090aa3c4d8d,
+    0x4109368f092c9, 0x41094189da418, 0x410974c6ff4de, 0x4109b6a7e5cb8,
+    0x4109d0467851b, 0x4109e9e50ad7e, 0x410a0ad57e16b, 0x410a2f6ee1b1d,
+    0x410a36c0c26a7, 0x410a3a69b2c6c, 0x410a4cb664945, 0x410a5f031661e,
+    0x410a6da6d7d32, 0x410a7c4a99446, 0x410a7ff389a0b, 0x410aaf87be50c,
+    0x410ab330aead1, 0x410ab6d99f096, 0x410adf1bf300d, 0x410afc6375e35,
+    0x410b20fcd97e7, 0x410b36f27ba85, 0x410b41ed4cbd4, 0x410b5f34cf9fc,
+    0x410b7181816d5, 0x410b752a71c9a, 0x410b78d36225f, 0x410b9d6cc5c11,
+    0x410ba4bea679b, 0x410bac1087325, 0x410bafb9778ea, 0x410bc9580a14d,
+    0x410bd0a9eacd7, 0x410bf5434e689, 0x410c1d85a2600, 0x410c45c7f6577,
+    0x410c4970e6b3c, 0x410c8056fc1c7, 0x410ca4f05fb79, 0x410cac4240703,
+    0x410cee2326edd, 0x410cf1cc174a2, 0x410cf57507a67, 0x410cfcc6e85f1,
+    0x410d0418c917b, 0x410d16657ae54, 0x410d2c5b1d0f2, 0x410d30040d6b7,
+    0x410d50f480aa4, 0x410d66ea22d42, 0x410d6e3c038cc, 0x410d87da9612f,
+    0x410d8b83866f4, 0x410d8f2c76cb9, 0x410d967e57843, 0x410da17928992,
+    0x410db01cea0a6, 0x410dc6128c344, 0x410dcd646cece, 0x410dee54e02bb,
+    0x410e12ee43c6d, 0x410e42827876e, 0x410e462b68d33, 0x410e49d4592f8,
+    0x410e671bdc120, 0x410e96b010c21, 0x410e9e01f17ab, 0x410ea1aae1d70,
+    0x410ed4e806e36, 0x410edc39e79c0, 0x410eee8699699, 0x410efd2a5adad,
+    0x410f0bce1c4c1, 0x410f256caed24, 0x410f3b6250fc2, 0x410f4daf02c9b,
+    0x410f5157f3260, 0x410f63a4a4f39, 0x410f883e088eb, 0x410f9e33aab89,
+    0x410fc2cd0e53b, 0x410fd519c0214, 0x410ff60a33601, 0x41101aa396fb3,
+    0x41103b940a3a0, 0x4110468edb4ef, 0x4110811de113f, 0x4110886fc1cc9,
+    0x4110936a92e18, 0x41109e6563f67, 0x4110b803f67ca, 0x4110bbace6d8f,
+    0x4110eeea0be55, 0x4110fd8dcd569, 0x41110c318ec7d, 0x41113bc5c377e,
+    0x41113f6eb3d43, 0x41114317a4308, 0x411167b107cba, 0x411172abd8e09,
+    0x411179fdb9993, 0x41117da6a9f58, 0x411188a17b0a7, 0x4111939c4c1f6,
+    0x41119e971d345, 0x4111d92622f95, 0x4111e07803b1f, 0x4111e7c9e46a9,
+    0x4112100c38620, 0x411229aacae83, 0x41124a9b3e270, 0x411290251500f,
+    0x4112a9c3a7872, 0x4112fa484f760, 0x41131ee1b3112, 0x411360c2998ec,
+    0x41136f665b000, 0x411397a8aef77, 0x41139b519f53c, 0x4113c73ce3a78,
+    0x4113cae5d403d, 0x4113ce8ec4602, 0x4113d237b4bc7, 0x411414189b3a1,
+    0x411422bc5cab5, 0x41144003df8dd, 0x411460f452cca, 0x4114dd643f0f4,
+    0x4115094f83630, 0x411510a1641ba, 0x4115144a5477f, 0x41151f45258ce,
+    0x411552824a994, 0x41156c20dd1f7, 0x41159bb511cf8, 0x41159f5e022bd,
+    0x4115a6afe2e47, 0x4115e890c9621, 0x4115efe2aa1ab, 0x4115f38b9a770,
+    0x4115f7348ad35, 0x411631c390985, 0x41164f0b137ad, 0x41166157c5486,
+    0x41166500b5a4b, 0x411673a47715f, 0x41167e9f482ae, 0x411685f128e38,
+    0x41168d43099c2, 0x4116aa8a8c7ea, 0x4116b92e4defe, 0x4116cf23f019c,
+    0x4116ec6b72fc4, 0x4116fb0f346d8, 0x41172e4c5979e, 0x411740990b477,
+    0x41175de08e29f, 0x411789cbd27db, 0x41178d74c2da0, 0x4117a36a6503e,
+    0x4117a71355603, 0x4117ddf96ac8e, 0x4117e1a25b253, 0x4117f797fd4f1,
+    0x411809e4af1ca, 0x4118238341a2d, 0x41182ad5225b7, 0x41182e7e12b7c,
+    0x41184473b4e1a, 0x411861bb37c42, 0x41187b59ca4a5, 0x41188da67c17e,
+    0x4118a7450e9e1, 0x4118bd3ab0c7f, 0x4118c0e3a1244, 0x4118cbde72393,
+    0x4118de2b2406c, 0x41193d538d66e, 0x41195a9b10496, 0x4119693ed1baa,
+    0x41197090b2734, 0x411977e2932be, 0x4119a776c7dbf, 0x4119aec8a8949,
+    0x4119b27198f0e, 0x4119b9c379a98, 0x4119c4be4abe7, 0x4119d3620c2fb,
+    0x4119d70afc8c0, 0x4119de5cdd44a, 0x4119e957ae599, 0x4119ed009eb5e,
+    0x4119f0a98f123, 0x4119fba460272, 0x411a363365ec2, 0x411a3d8546a4c,
+    0x411a488017b9b, 0x411a621eaa3fe, 0x411a70c26bb12, 0x411ac898f458a,
+    0x411af0db48501, 0x411af48438ac6, 0x411b3a0e0f865, 0x411b53aca20c8,
+    0x411b65f953da1, 0x411b6d4b3492b, 0x411b9cdf6942c, 0x411ba088599f1,
+    0x411bd3c57eab7, 0x411be269401cb, 0x411be9bb20d55, 0x411c1cf845e1b,
+    0x411c3a3fc8c43, 0x411c453a99d92, 0x411c74cece893, 0x411c95bf41c80,
+    0x411c996832245, 0x411caf5dd44e3, 0x411ce643e9b6e, 0x411d07345cf5b,
+    0x411d3e1a725e6, 0x411d41c362bab, 0x411d665cc655d, 0x411db3387de86,
+    0x411db6e16e44b, 0x411dd7d1e1838, 0x411ddf23c23c2, 0x411de2ccb2987,
+    0x411dedc783ad6, 0x411e0b0f068fe, 0x411e2fa86a2b0, 0x411e459e0c54e,
+    0x411e5098dd69d, 0x411e5441cdc62, 0x411e62e58f376, 0x411e6de0604c5,
+    0x411ea86f66115, 0x411ebabc17dee, 0x411ebe65083b3, 0x411ec20df8978,
+    0x411ef54b1da3e, 0x411f1292a0866, 0x411f1d8d719b5, 0x411f45cfc592c,
+    0x411fd48c63a2f, 0x4120166d4a209, 0x412071ecc3246, 0x412087e2654e4,
+    0x4120a529e830c, 0x4120d1152c848, 0x4120e361de521, 0x41212c94a5885,
+    0x4121378f769d4, 0x41214633380e8, 0x41216723ab4d5, 0x41219e09c0b60,
+    0x412237c12fdb2, 0x412263ac742ee, 0x412275f925fc7, 0x41227d4b06b51,
+    0x41228beec8265, 0x4122a9364b08d, 0x4122e3c550cdd, 0x41231359857de,
+    0x412330a108606, 0x41233b9bd9755, 0x41234de88b42e, 0x4123762adf3a5,
+    0x412379d3cf96a, 0x4123a216238e1, 0x4123a5bf13ea6, 0x4123a9680446b,
+    0x4123b462d55ba, 0x4123d553489a7, 0x4123f9ecac359, 0x41241e860fd0b,
+    0x41242980e0e5a, 0x412472b3a81be, 0x4124850059e97, 0x4124b4948e998,
+    0x4124e428c3499, 0x4124f67575172, 0x412538565b94c, 0x41254aa30d625,
+    0x412564419fe88, 0x412567ea9044d, 0x41258c83f3dff, 0x4125b4c647d76,
+    0x4125c36a0948a, 0x4125f6a72e550, 0x4125fdf90f0da, 0x41264ad4c6a03,
+    0x41264e7db6fc8, 0x4126647359266, 0x41267a68fb504, 0x41268563cc653,
+    0x41268cb5ad1dd, 0x41269b596e8f1, 0x4126a2ab4f47b, 0x4126bc49e1cde,
+    0x4126c39bc2868, 0x4126dd3a550cb, 0x41270cce89bcc, 0x412710777a191,
+    0x41271b724b2e0, 0x412722c42be6a, 0x41274b067fde1, 0x41276bf6f31ce,
+    0x4127859585a31, 0x412797e23770a, 0x41279b8b27ccf, 0x4127a685f8e1e,
+    0x4128478f48bfa, 0x412877237d6fb, 0x4128aa60a27c1, 0x4128b1b28334b,
+    0x4128bcad5449a, 0x4128cb5115bae, 0x4128e146b7e4c, 0x4128e898989d6,
+    0x4128f73c5a0ea, 0x41291483dcf12, 0x41293cc630e89, 0x41295664c36ec,
+    0x41295a0db3cb1, 0x412968b1753c5, 0x4129775536ad9, 0x41298d4ad8d77,
+    0x41299845a9ec6, 0x412a5a3f6d08f, 0x412a94ce72cdf, 0x412ab5bee60cc,
+    0x412ac0b9b721b, 0x412ad30668ef4, 0x412ada5849a7e, 0x412ae1aa2a608,
+    0x412afef1ad430, 0x412b3980b3080, 0x412b40d293c0a, 0x412bcbe641748,
+    0x412c0a1e3795d, 0x412c1c6ae9636, 0x412c32608b8d4, 0x412cab2787739,
+    0x412cbd7439412, 0x412cc86f0a561, 0x412ccc17fab26, 0x412cf8033f062,
+    0x412d0df8e1300, 0x412d279773b63, 0x412d39e42583c, 0x412daf02310dc,
+    0x412db9fd0222b, 0x412dc4f7d337a, 0x412dd74485053, 0x412ded3a272f1,
+    0x412e24203c97c, 0x412e4167bf7a4, 0x412e6d5303ce0, 0x412e7f9fb59b9,
+    0x412eaf33ea4ba, 0x412f837a5f35c, 0x412fe2a2c895e, 0x412ffc415b1c1,
+    0x413020dabeb73, 0x41302f7e80287, 0x41306db67649c, 0x4130a8457c0ec,
+    0x4130ccdedfa9e, 0x4130d7d9b0bed, 0x4130df2b91777, 0x4130e2d481d3c,
+    0x4130fc731459f, 0x4131210c77f51, 0x41315b9b7dba1, 0x413178e3009c9,
+    0x4131c5beb82f2, 0x4131df5d4ab55, 0x4131e3063b11a, 0x41325f7627544,
+    0x41326e19e8c58, 0x4132be9e90b46, 0x4132d83d233a9, 0x41330f2338a34,
+    0x41331dc6fa148, 0x41333eb76d535, 0x413346094e0bf, 0x413354ad0f7d3,
+    0x413371f4925fb, 0x4133759d82bc0, 0x41339a36e6572, 0x4133e369ad8d6,
+    0x4133fd0840139, 0x413412fde23d7, 0x41345fd999d00, 0x4134881bedc77,
+    0x41349e118ff15, 0x4134b407321b3, 0x4134d4f7a55a0, 0x4134dff2766ef,
+    0x41350834ca666, 0x413537c8ff167, 0x41356eaf147f2, 0x4135d5295e97e,
+    0x4136136154b93, 0x41362956f6e31, 0x4136603d0c4bc, 0x41367289be195,
+    0x4136b46aa496f, 0x4136c30e66083, 0x4136eb50b9ffa, 0x413725dfbfc4a,
+    0x4137591ce4d10, 0x41375cc5d52d5, 0x41376f1286fae, 0x4137766467b38,
+    0x41379afdcb4ea, 0x4137bf972ee9c, 0x4137d1e3e0b75, 0x413808c9f6200,
+    0x4138a28165452, 0x4138a62a55a17, 0x413913f68072d, 0x41393c38d46a4,
+    0x41394e858637d, 0x41395d2947a91, 0x41396f75f976a, 0x413981c2ab443,
+    0x413997b84d6e1, 0x4139d24753331, 0x413a142839b0b, 0x413a1b7a1a695,
+    0x413a2a1ddbda9, 0x413ae11ccde23, 0x413afe6450c4b, 0x413b1459f2ee9,
+    0x413b1babd3a73, 0x413b354a662d6, 0x413b9472cf8d8, 0x413b981bbfe9d,
+    0x413bbcb52384f, 0x413bd653b60b2, 0x413bf7442949f, 0x413bfaed19a64,
+    0x413bfe960a029, 0x413c148bac2c7, 0x413c232f6d9db, 0x413c52c3a24dc,
+    0x413c8257d6fdd, 0x413c90fb986f1, 0x413c984d7927b, 0x413cddd75001a,
+    0x413cfec7c3407, 0x413d186655c6a, 0x413d7b37af831, 0x413d8289903bb,
+    0x413dcbbc5771f, 0x413e1fe9efbd2, 0x413e398882435, 0x413e6cc5a74fb,
+    0x413e98b0eba37, 0x413ee93593925, 0x413ef087744af, 0x413f201ba8fb0,
+    0x413f23c499575, 0x413f276d89b3a, 0x413f2b167a0ff, 0x413f32685ac89,
+    0x413f5358ce076, 0x413f61fc8f78a, 0x413fa3dd75f64, 0x413fc124f8d8c,
+    0x413fcc1fc9edb, 0x413fcfc8ba4a0, 0x413fe9674cd03, 0x41408a709cadf,
+    0x41408e198d0a4, 0x4140cffa7387e, 0x4140e999060e1, 0x41410e3269a93,
+    0x4141959d2700c, 0x4141b2e4a9e34, 0x4141c8da4c0d2, 0x4141d77e0d7e6,
+    0x4141decfee370, 0x4141ed73afa84, 0x4141fc1771198, 0x4142195ef3fc0,
+    0x41422baba5c99, 0x414253edf9c10, 0x4142ba6843d9c, 0x4142d05de603a,
+    0x4142d7afc6bc4, 0x4142eda568e62, 0x4142fc492a576, 0x4143af9f2c02b,
+    0x4144211447306, 0x414424bd378cb, 0x41444cff8b842, 0x41447198ef1f4,
+    0x41449289625e1, 0x4144fcac9cd32, 0x414519f41fb5a, 0x4145806e69ce6,
+    0x41458b693ae35, 0x41459a0cfc549, 0x4145bafd6f936, 0x4145d0f311bd4,
+    0x4145df96d32e8, 0x414633c46b79b, 0x41464d62fdffe, 0x41466358a029c,
+    0x41466aaa80e26, 0x4146bb2f28d14, 0x41470461f0078, 0x41471a5792316,
+    0x41471e00828db, 0x41472ca443fef, 0x41475fe1690b5, 0x4147722e1ad8e,
+    0x414775d70b353, 0x41478f759dbb6, 0x4147ee9e071b8, 0x4148292d0ce08,
+    0x4148307eed992, 0x41483f22af0a6, 0x4148516f60d7f, 0x414867650301d,
+    0x414884ac85e45, 0x41489e4b186a8, 0x4148aceed9dbc, 0x4148d5312dd33,
+    0x4148dc830e8bd, 0x4149011c7226f, 0x4149220ce565c, 0x414946a64900e,
+    0x41496044db871, 0x414984de3f223, 0x4149938200937, 0x41499ad3e14c1,
+    0x4149ca6815fc2, 0x414a5924b40c5, 0x414a607694c4f, 0x414ab4a42d102,
+    0x414ad1ebaff2a, 0x414b1023a613f, 0x414b3c0eea67b, 0x414b3fb7dac40,
+    0x414b6ba31f17c, 0x414b8c9392569, 0x414ba9db15391, 0x414bc722981b9,
+    0x414bd21d69308, 0x414be8130b5a6, 0x414c22a2111f6, 0x414c29f3f1d80,
+    0x414c4392845e3, 0x414c55df362bc, 0x414c81ca7a7f8, 0x414c9b690d05b,
+    0x414ca663de1aa, 0x414cc00270a0d, 0x414cd9a103270, 0x414ce844c4984,
+    0x414d093537d71, 0x414d22d3ca5d4, 0x414d267cbab99, 0x414d31778bce8,
+    0x414d35207c2ad, 0x414d9f43b69fe, 0x414dd629cc089, 0x414de4cd8d79d,
+    0x414df71a3f476, 0x414e1f5c933ed, 0x414e3ca416215, 0x414e5299b84b3,
+    0x414e688f5a751, 0x414e7e84fc9ef, 0x414ec40ed378e, 0x414ec7b7c3d53,
+    0x414efaf4e8e19, 0x414f14937b67c, 0x414f26e02d355, 0x414f392cdf02e,
+    0x414f910367aa6, 0x414f985548630, 0x414f9bfe38bf5, 0x414fae4aea8ce,
+    0x414fb945bba1d, 0x414fbceeabfe2, 0x41502e63c72bd, 0x4150320cb7882,
+    0x4150395e9840c, 0x41506549dc948, 0x415068f2ccf0d, 0x41507b3f7ebe6,
+    0x415089e3402fa, 0x41508d8c308bf, 0x4150b22594271, 0x4150d3160765e,
+    0x4150d6bef7c23, 0x4150e90ba98fc, 0x4151189fde3fd, 0x4151239aaf54c,
+    0x41512aec900d6, 0x41512e958069b, 0x4151323e70c60, 0x41516924862eb,
+    0x415198b8badec, 0x4151de4291b8b, 0x415277fa00ddd, 0x4152a3e545319,
+    0x4153190350bb9, 0x4153205531743, 0x415365df084e2, 0x41536987f8aa7,
+    0x4153a06e0e132, 0x4153f49ba65e5, 0x415411e32940d, 0x41542086eab21,
+    0x4154576d001ac, 0x41548e5315837, 0x4154d785dcb9b, 0x41550e6bf2226,
+    0x41552bb37504e, 0x41554ca3e843b, 0x415553f5c8fc5, 0x4155788f2c977,
+    0x4155a47a70eb3, 0x4155ea0447c52, 0x415662cb43ab7, 0x41567c69d631a,
+    0x4156b34feb9a5, 0x4156df3b2fee1, 0x4156fc82b2d09, 0x41570ecf649e2,
+    0x41572c16e780a, 0x4157420c89aa8, 0x415758022bd46, 0x415778f29f133,
+    0x415799e312520, 0x4157afd8b47be, 0x4157c5ce56a5c, 0x41585f85c5cae,
+    0x4158792458511, 0x4158ea99737ec, 0x4159008f15a8a, 0x41591a2da82ed,
+    0x415930234a58b, 0x41599a4684cdc, 0x4159b03c26f7a, 0x4159d4d58a92c,
+    0x415a37a6e44f3, 0x415a84829be1c, 0x415a96cf4daf5, 0x415a9a783e0ba,
+    0x415aacc4efd93, 0x415abf11a1a6c, 0x415b133f39f1f, 0x415b258bebbf8,
+    0x415b517730134, 0x415b885d457bf, 0x415b8c0635d84, 0x415bacf6a9171,
+    0x415bc6953b9d4, 0x415bd8e1ed6ad, 0x415beb2e9f386, 0x415c30b876125,
+    0x415c4a5708988, 0x415c58faca09c, 0x415c6ef06c33a, 0x415cd1c1c5f01,
+    0x415d4336e11dc, 0x415d72cb15cdd, 0x415d816ed73f1, 0x415dbfa6cd606,
+    0x415dd1f37f2df, 0x415e1ecf36c08, 0x415e29ca07d57, 0x415e3c16b9a30,
+    0x415e47118ab7f, 0x415e64590d9a7, 0x415e904451ee3, 0x415ee471ea396,
+    0x415f090b4dd48, 0x415f105d2e8d2, 0x415f17af0f45c, 0x415f2da4b16fa,
+    0x415f5d38e61fb, 0x415f60e1d67c0, 0x415f9f19cc9d5, 0x415fcb0510f11,
+    0x41601b89b8dff, 0x41603c7a2c1ec, 0x416085acf3550, 0x4160a69d6693d,
+    0x4161146991653, 0x41613cabe55ca, 0x4161689729b06, 0x4161773aeb21a,
+    0x4161982b5e607, 0x4162358bbde1e, 0x41624f2a50681, 0x416252d340c46,
+    0x4162985d179e5, 0x4162ae52b9c83, 0x4163480a28ed5, 0x4163988ed0dc3,
+    0x41639fe0b194d, 0x4163da6fb759d, 0x4163fb602a98a, 0x41645336b3402,
+    0x41645a8893f8c, 0x41647427267ef, 0x41647f21f793e, 0x416482cae7f03,
+    0x4164b6080cfc9, 0x4164b9b0fd58e, 0x4164e59c41aca, 0x416582fca12e1,
+    0x4165a04424109, 0x4166832e5a6bf, 0x4166c8b83145e, 0x4166d3b3025ad,
+    0x4167417f2d2c3, 0x4167c8e9ea83c, 0x4167d03bcb3c6, 0x41681d1782cef,
+    0x416820c0732b4, 0x41682bbb44403, 0x41684559d6c66, 0x41684cabb77f0,
+    0x41685b4f78f04, 0x41687c3fec2f1, 0x4168d06d847a4, 0x4168d7bf6532e,
+    0x41697176d4580, 0x4169afaeca795, 0x4169b357bad5a, 0x4169e694dfe20,
+    0x416a2875c65fa, 0x416b50e9d394f, 0x416b9673aa6ee, 0x416bac694c98c,
+    0x416be6f8525dc, 0x416c07e8c59c9, 0x416c12e396b18, 0x416c3b25eaa8f,
+    0x416c4277cb619, 0x416c511b8cd2d, 0x416c80afc182e, 0x416c8f5382f42,
+    0x416cc290a8008, 0x416d3405c32e3, 0x416d7d388a647, 0x416de75bc4d98,
+    0x416e6774a1787, 0x416ed197dbed8, 0x416ee78d7e176, 0x416f590299451,
+    0x416f8c3fbe517, 0x416fa235607b5, 0x416fd1c9952b6, 0x416fdcc466405,
+    0x416ff662f8c68, 0x4170558b6226a, 0x4170a26719b93, 0x4170caa96db0a,
+    0x4171018f83195, 0x4171178525433, 0x41719b46f23e7, 0x4171e0d0c9186,
+    0x41724af4038d7, 0x41731f3a78779, 0x417322e368d3e, 0x41732a35498c8,
+    0x41742a6702ca6, 0x4174e50ee52e5, 0x417514a319de6, 0x4175184c0a3ab,
+    0x41752e41ac649, 0x4175702292e23, 0x4175826f44afc, 0x4175ec927f24d,
+    0x41764811f828a, 0x41769144bf5ee, 0x4176d6ce9638d, 0x41774f95921f2,
+    0x4177658b34490, 0x417777d7e6169, 0x4177aebdfb7f4, 0x4177d7004f76b,
+    0x4177ff42a36e2, 0x41788dff417e5, 0x4178e5d5ca25d, 0x41791912ef323,
+    0x41792f08915c1, 0x4179992bcbd12, 0x417aed8b1d5a3, 0x417b1d1f520a4,
+    0x417bf8b7a7ad0, 0x417c493c4f9be, 0x417cf19780324, 0x417cf8e960eae,
+    0x417d0edf0314c, 0x417d2c2685f74, 0x417d45c5187d7, 0x417dafe852f28,
+    0x417dc5ddf51c6, 0x417e0f10bc52a, 0x417e9a246a068, 0x417f8460811a8,
+    0x417faca2d511f, 0x41801e17f03fa, 0x418096deec25f, 0x4181ca4dca703,
+    0x4182556178241, 0x4182a5e62012f, 0x4182e07525d7f, 0x4183608e0276e,
+    0x41836b88d38bd, 0x41837683a4a0c, 0x41849b4ec179c, 0x41854752e26c7,
+    0x4185599f943a0, 0x41856f953663e, 0x41857e38f7d52, 0x41858cdcb9466,
+    0x4185f357035f2, 0x4186980943993, 0x4187022c7e0e4, 0x4187231cf14d1,
+    0x41879be3ed336, 0x4187a6debe485, 0x41880d5908611, 0x4188ae62583ed,
+    0x4188f3ec2f18c, 0x4189d6d665742, 0x418b5720fb50f, 0x418c32b950f3b,
+    0x418c48aef31d9, 0x418c624d85a3c, 0x418cb67b1deef, 0x418cc8c7cfbc8,
+    0x418ce2666242b, 0x418cf85c046c9, 0x418d1cf56807b, 0x418d71230052e,
+    0x418da0b73502f, 0x418dd79d4a6ba, 0x418eea1bb5771, 0x418f802a343fe,
+    0x418f877c14f88, 0x418f8ecdf5b12, 0x418fbab93a04e, 0x419092a89f4b5,
+    0x419096518fa7a, 0x41909da370604, 0x4190a89e41753, 0x4190d0e0956ca,
+    0x419121653d5b8, 0x41912c600e707, 0x4191793bc6030, 0x41918f31682ce,
+    0x4191ee59d18d0, 0x419200a6835a9, 0x4192acaaa44d4, 0x4192bef7561ad,
+    0x41940c04c6eb4, 0x4194f640ddff4, 0x4195138860e1c, 0x4195431c9591d,
+    0x41957659ba9e3, 0x4195c6de628d1, 0x419638537dbac, 0x4196643ec20e8,
+    0x4196c3672b6ea, 0x41972638852b1, 0x4197b89e13979, 0x4197ebdb38a3f,
+    0x4197fa7efa153, 0x41982a132ec54, 0x419897df5996a, 0x4198add4fbc08,
+    0x4198bc78bd31c, 0x4198c3ca9dea6, 0x4198d26e5f5ba, 0x4199403a8a2d0,
+    0x4199612afd6bd, 0x4199e4ecca671, 0x419a90f0eb59c, 0x419a9bebbc6eb,
+    0x419a9f94accb0, 0x419addcca2ec5, 0x419af01954b9e, 0x419ba36f56653,
+    0x419c4bca86fb9, 0x419c94fd4e31d, 0x419cae9be0b80, 0x419cb244d1145,
+    0x419cd33544532, 0x419d4fa53095c, 0x419d743e9430e, 0x419df45770cfd,
+    0x419e2b3d86388, 0x419e3d8a38061, 0x419e44dc18beb, 0x419e537fda2ff,
+    0x419e9909b109e, 0x419ecc46d6164, 0x419ef4892a0db, 0x419fb2d9fccdf,
+    0x419fd77360691, 0x41a01202662e1, 0x41a0503a5c4f6, 0x41a0a467f49a9,
+    0x41a0e9f1cb748, 0x41a0fc3e7d421, 0x41a1750579286, 0x41a1c93311739,
+    0x41a1d42de2888, 0x41a27c89131ee, 0x41a2a4cb67165, 0x41a2d8088c22b,
+    0x41a33730f582d, 0x41a3cd3f744ba, 0x41a4007c99580, 0x41a43b0b9f1d0,
+    0x41a4585321ff8, 0x41a4b3d29b035, 0x41a554dbeae11, 0x41a579754e7c3,
+    0x41a57d1e3ed88, 0x41a584701f912, 0x41a5c9f9f66b1, 0x41a64dbbc3665,
+    0x41a6b7defddb6, 0x41a6c682bf4ca, 0x41a6e773328b7, 0x41a7220238507,
+    0x41a75c913e157, 0x41a79ac93436c, 0x41a7b0bed660a, 0x41a7eb4ddc25a,
+    0x41a8ca8f2224b, 0x41a8fa2356d4c, 0x41a9385b4cf61, 0x41a943561e0b0,
+    0x41a988dff4e4f, 0x41a99b2ca6b28, 0x41a99ed5970ed, 0x41a9a27e876b2,
+    0x41a9d5bbac778, 0x41aace9b84fcc, 0x41ab17ce4c330, 0x41ab316cdeb93,
+    0x41ab4b0b713f6, 0x41ab560642545, 0x41abd61f1ef34, 0x41abe119f0083,
+    0x41abec14c11d2, 0x41ac10ae24b84, 0x41ac2a4cb73e7, 0x41ac6fd68e186,
+    0x41ac8975209e9, 0x41ad2e2760d8a, 0x41adcb87c05a1, 0x41ae3cfcdb87c,
+    0x41ae4ba09cf90, 0x41ae4f498d555, 0x41aeb21ae711c, 0x41aebd15b826b,
+    0x41aeda5d3b093, 0x41af1fe711e32, 0x41af7f0f7b434, 0x41af95051d6d2,
+    0x41afa3a8dede6, 0x41afa751cf3ab, 0x41affb7f6785e, 0x41b02b139c35f,
+    0x41b08de4f5f26, 0x41b0a78388789, 0x41b0d36ecccc5, 0x41b1488cd8565,
+    0x41b15ad98a23e, 0x41b1a40c515a2, 0x41b1af07226f1, 0x41b1c4fcc498f,
+    0x41b1f839e9a55, 0x41b23dc3c07f4, 0x41b2f86ba2e33, 0x41b336a399048,
+    0x41b33a4c8960d, 0x41b378847f822, 0x41b38e7a21ac0, 0x41b39974f2c0f,
+    0x41b3a46fc3d5e, 0x41b3ffef3cd9b, 0x41b41d36bfbc3, 0x41b4283190d12,
+    0x41b45b6eb5dd8, 0x41b524ba59b2b, 0x41b54cfcadaa2, 0x41b5719611454,
+    0x41b599d8653cb, 0x41b62fe6e4058, 0x41b6582937fcf, 0x41b696612e1e4,
+    0x41b6a504ef8f8, 0x41b78b9816473, 0x41b7a18db8711, 0x41b7a536a8cd6,
+    0x41b8797d1db78, 0x41b88820df28c, 0x41b916dd7d38f, 0x41b91e2f5df19,
+    0x41b979aed6f56, 0x41b9fd70a3f0a, 0x41ba76379fd6f, 0x41bb641ca7474,
+    0x41bb81642a29c, 0x41bbd591c274f, 0x41bbef3054fb2, 0x41bc9b3475edd,
+    0x41bcce719afa3, 0x41bd17a462307, 0x41bd4e8a77992, 0x41bd7e1eac493,
+    0x41bd97bd3ecf6, 0x41bdb504c1b1e, 0x41bdfa8e988bd, 0x41be560e118fa,
+    0x41be64b1d300e, 0x41bf10b5f3f39, 0x41bf2302a5c12, 0x41bf6fde5d53b,
+    0x41bf77303e0c5, 0x41c06c6726354, 0x41c07b0ae7a68, 0x41c0ae480cb2e,
+    0x41c0e18531bf4, 0x41c1270f08993, 0x41c1988423c6e, 0x41c1d6bc19e83,
+    0x41c1e55fdb597, 0x41c2323b92ec0, 0x41c240df545d4, 0x41c26cca98b10,
+    0x41c2950ceca87, 0x41c2cbf302112, 0x41c3326d4c29e, 0x41c3f4670f467,
+    0x41c3fbb8efff1, 0x41c4030ad0b7b, 0x41c406b3c1140, 0x41c4cfff64e93,
+    0x41c4f841b8e0a, 0x41c5451d70733, 0x41c57108b4c6f, 0x41c5a7eeca2fa,
+    0x41c63dfd48f87, 0x41c6d40bc7c14, 0x41c6e658798ed, 0x41c7c599bf8de,
+    0x41c7df3852141, 0x41c7f18503e1a, 0x41c819c757d91, 0x41c85ba83e56b,
+    0x41c8cd1d59846, 0x41c924f3e22be, 0x41c93ae98455c, 0x41c987c53be85,
+    0x41c99a11edb5e, 0x41c9c25441ad5, 0x41c9d0f8031e9, 0x41cafd1500b03,
+    0x41cb00bdf10c8, 0x41cb2ca935604, 0x41cb4d99a89f1, 0x41cb72330c3a3,
+    0x41cb8828ae641, 0x41cbe75117c43, 0x41cc517452394, 0x41cc58c632f1e,
+    0x41cc8c0357fe4, 0x41cceb2bc15e6, 0x41ccf27da2170, 0x41cd6b449dfd5,
+    0x41cd8c35113c2, 0x41cd9386f1f4c, 0x41cdb820558fe, 0x41ce7a1a18ac7,
+    0x41ced942820c9, 0x41ceef3824367, 0x41cf7df4c246a, 0x41cf979354ccd,
+    0x41cf9b3c45292, 0x41cfad88f6f6b, 0x41cfb883c80ba, 0x41cfc37e99209,
+    0x41d0523b3730c, 0x41d12681ac1ae, 0x41d147721f59b, 0x41d177065409c,
+    0x41d1d9d7adc63, 0x41d1fe7111615, 0x41d26c3d3c32b, 0x41d28d2daf718,
+    0x41d356795346b, 0x41d35dcb33ff5, 0x41d3cf404f2d0, 0x41d3f3d9b2c82,
+    0x41d47b44701fb, 0x41d4b97c66410, 0x41d4de15c9dc2, 0x41d50da9fe8c3,
+    0x41d55333d5662, 0x41d5742448a4f, 0x41d58670fa728, 0x41d5e59963d2a,
+    0x41d60689d7117, 0x41d68df494690, 0x41d6c131b9756, 0x41d6f0c5ee257,
+    0x41d703129ff30, 0x41d7489c76ccf, 0x41d770decac46, 0x41d836817e3d4,
+    0x41d86615b2ed5, 0x41d995dba0db4, 0x41d9b32323bdc, 0x41d9db6577b53,
+    0x41d9f5040a3b6, 0x41da5083833f3, 0x41daa85a0be6b, 0x41dab6fdcd57f,
+    0x41dabaa6bdb44, 0x41daf18cd31cf, 0x41db2872e885a, 0x41db2fc4c93e4,
+    0x41dbbe81674e7, 0x41dd28d65b016, 0x41dd636560c66, 0x41de00c5c047d,
+    0x41de75e3cbd1d, 0x41de9a7d2f6cf, 0x41deca11641d0, 0x41df4a2a40bbf,
+    0x41df79be756c0, 0x41df935d07f23, 0x41df9705f84e8, 0x41dfe3e1afe11,
+    0x41e067a37cdc5, 0x41e0dcc188665, 0x41e0e7bc597b4, 0x41e13498110dd,
+    0x41e1a9b61c97d, 0x41e39f1ebdfea, 0x41e3bc6640e12, 0x41e3e8518534e,
+    0x41e3f6f546a62, 0x41e417e5b9e4f, 0x41e4c040ea7b5, 0x41e53cb0d6bdf,
+    0x41e59f82307a6, 0x41e5a32b20d6b, 0x41e5b577d2a44, 0x41e5d66845e31,
+    0x41e6408b80582, 0x41e66c76c4abe, 0x41e6a35cda149, 0x41e7062e33d10,
+    0x41e7aae0740b1, 0x41e8533ba4a17, 0x41e88a21ba0a2, 0x41e8daa661f90,
+    0x41e95e682ef44, 0x41e9696300093, 0x41e96d0bf0658, 0x41e9a79af62a8,
+    0x41ea65ebc8eac, 0x41ea91d70d3e8, 0x41ea9cd1de537, 0x41eae604a589b,
+    0x41eaed5686425, 0x41eaffa3380fe, 0x41eb2093ab4eb, 0x41eb2f376cbff,
+    0x41eb661d8228a, 0x41eb7fbc14aed, 0x41eb9d0397915, 0x41ebb2f939bb3,
+    0x41ecb32af2f91, 0x41ecf50bd976b, 0x41ed5b86238f7, 0x41edb35cac36f,
+    0x41edccfb3ebd2, 0x41ede699d1435, 0x41ededebb1fbf, 0x41edfc8f736d3,
+    0x41ee128515971, 0x41ee19d6f64fb, 0x41ee3ac7698e8, 0x41ee87a321211,
+    0x41ee8ef501d9b, 0x41eef1c65b962, 0x41ef215a90463, 0x41ef3ea21328b,
+    0x41efbb11ff6b5, 0x41f06abf10ba5, 0x41f0d4e24b2f6, 0x41f0ee80ddb59,
+    0x41f121be02c1f, 0x41f180e66c221, 0x41f196dc0e4bf, 0x41f2a208989ec,
+    0x41f2a95a79576, 0x41f2ca4aec963, 0x41f2f63630e9f, 0x41f34e0cb9917,
+    0x41f40c5d8c51b, 0x41f446ec9216b, 0x41f47680c6c6c, 0x41f555c20cc5d,
+    0x41f560bcdddac, 0x41f573098fa85, 0x41f5a9efa5110, 0x41f5bfe5473ae,
+    0x41f5ce8908ac2, 0x41f5d231f9087, 0x41f5e47eaad60, 0x41f5f3226c474,
+    0x41f69f268d39f, 0x41f6adca4eab3, 0x41f6b51c2f63d, 0x41f701f7e6f66,
+    0x41f759ce6f9de, 0x41f7612050568, 0x41f76c1b216b7, 0x41f7980665bf3,
+    0x41f7fad7bf7ba, 0x41f84b5c676a8, 0x41f898381efd1, 0x41f8c07a72f48,
+    0x41f8e8bcc6ebf, 0x41f8f760885d3, 0x41f94f371104b, 0x41f994c0e7dea,
+    0x41fae9203967b, 0x41fb3253009df, 0x41fbc10f9eae2, 0x41fbcc0a6fc31,
+    0x41fbff4794cf7, 0x41fc487a5c05b, 0x41fc86b252270, 0x41fc8e0432dfa,
+    0x41fcb64686d71, 0x41fcf0d58c9c1, 0x41fcf47e7cf86, 0x41fd2069c14c2,
+    0x41fd48ac15439, 0x41fd91dedc79d, 0x41fda0829deb1, 0x41fda42b8e476,
+    0x41fdd016d29b2, 0x41fdd768b353c, 0x41fe32e82c579, 0x41fe5ed370ab5,
+    0x41fea45d47854, 0x41fed79a6c91a, 0x41ff1d24436b9, 0x41ff41bda706b,
+    0x41ffbe2d93495, 0x41ffd42335733, 0x42000b094adbe, 0x420024a7dd621,
+    0x42002bf9be1ab, 0x42002fa2ae770, 0x4200509321b5d, 0x4200927408337,
+    0x4200961cf88fc, 0x42009d6ed9486, 0x4200d0abfe54c, 0x4200dba6cf69b,
+    0x4200e2f8b0225, 0x4200e6a1a07ea, 0x4200f19c71939, 0x42013726486d8,
+    0x420166ba7d1d9, 0x42016e0c5dd63, 0x42018efcd1150, 0x420199f7a229f,
+    0x4201a89b639b3, 0x4201d486a7eef, 0x420233af114f1, 0x420250f694319,
+    0x4202549f848de, 0x420266ec365b7, 0x420271e707706, 0x42029a295b67d,
+    0x4202b3c7edee0, 0x4202bb19cea6a, 0x4202cd6680743, 0x4202dc0a41e57,
+    0x4202ee56f3b30, 0x4202f1ffe40f5, 0x4203462d7c5a8, 0x4203587a2e281,
+    0x42035c231e846, 0x4203671def995, 0x42036ac6dff5a, 0x420375c1b10a9,
+    0x4203796aa166e, 0x42038bb753347, 0x420396b224496, 0x4203b050b6cf9,
+    0x4203b7a297883, 0x4203bef47840d, 0x4203c29d689d2, 0x4203c64658f97,
+    0x4203d1412a0e6, 0x4203eadfbc949, 0x4203f2319d4d3, 0x420400d55ebe7,
+    0x4204047e4f1ac, 0x42040f79202fb, 0x4204341283cad, 0x42047d454b011,
+    0x4204933aed2af, 0x4204bf26317eb, 0x4204c2cf21db0, 0x4204e016a4bd8,
+    0x4204eeba662ec, 0x4204fd5e27a00, 0x42050c01e9114, 0x42051e4e9aded,
+    0x42053b961dc15, 0x420542e7fe79f, 0x42054a39df329, 0x4205518bbfeb3,
+    0x42055c8691002, 0x42056b2a52716, 0x4205762523865, 0x4205811ff49b4,
+    0x4205ad0b38ef0, 0x4205b8060a03f, 0x4205c6a9cb753, 0x4205d54d8ce67,
+    0x4205fd8fe0dde, 0x42062d24158df, 0x420667b31b52f, 0x42066b5c0baf4,
+    0x42067656dcc43, 0x420679ffcd208, 0x42067da8bd7cd, 0x420688a38e91c,
+    0x4206b48ed2e58, 0x4206bf89a3fa7, 0x4206c3329456c, 0x4206ca84750f6,
+    0x4206e7cbf7f1e, 0x4206eb74e84e3, 0x4206f2c6c906d, 0x42071eb20d5a9,
+    0x420729acde6f8, 0x4207818367170, 0x4207852c57735, 0x420788d547cfa,
+    0x42079ecae9f98, 0x4207bc126cdc0, 0x4207c3644d94a, 0x4207e7fdb12fc,
+    0x4207f2f88244b, 0x420834d968c25, 0x42085d1bbcb9c, 0x42087e0c2ff89,
+    0x420881b52054e, 0x42088907010d8, 0x42089b53b2db1, 0x4208ada064a8a,
+    0x4208bfed16763, 0x4208e4867a115, 0x4208f6d32bdee, 0x4208fe250c978,
+    0x42090576ed502, 0x42090cc8ce08c, 0x42094757d3cdc, 0x420955fb953f0,
+    0x42095d4d75f7a, 0x420994338b605, 0x4209b8cceefb7, 0x4209bc75df57c,
+    0x4209c01ecfb41, 0x4209ec0a1407d, 0x4209f35bf4c07, 0x4209faadd5791,
+    0x420a22f029708, 0x420a7374d15f6, 0x420aa6b1f66bc, 0x420aae03d7246,
+    0x420ac3f9794e4, 0x420b182711997, 0x420b2321e2ae6, 0x420b565f07bac,
+    0x420b6159d8cfb, 0x420b6c54a9e4a, 0x420b90ee0d7fc, 0x420b9be8de94b,
+    0x420c1106ea1eb, 0x420c1faaab8ff, 0x420c26fc8c489, 0x420c409b1ecec,
+    0x420c5de2a1b14, 0x420c7ed314f01, 0x420c94c8b719f, 0x420cfeebf18f0,
+    0x420d3229169b6, 0x420d690f2c041, 0x420d70610cbcb, 0x420d7f04ce2df,
+    0x420d98a360b42, 0x420dc0e5b4ab9, 0x420df422d9b7f, 0x420e39acb091e,
+    0x420e44a781a6d, 0x420e5e46142d0, 0x420eb273ac783, 0x420ec1176de97,
+    0x420ee959c1e0e, 0x420f203fd7499, 0x420f2b3aa85e8, 0x420f2ee398bad,
+    0x420f39de69cfc, 0x420f3d875a2c1, 0x420f5725ecb24, 0x420fa401a444d,
+    0x420fd395d8f4e, 0x4210191fafced, 0x42108e3dbb58d, 0x421091e6abb52,
+    0x421099388c6dc, 0x4210bdd1f008e, 0x4210d770828f1, 0x4210dec26347b,
+    0x4211035bc6e2d, 0x42113698ebef3, 0x42117879d26cd, 0x42118ac6843a6,
+    0x4211af5fe7d58, 0x4211f892af0bc, 0x4211ffe48fc46, 0x421236caa52d1,
+    0x42128ea12dd49, 0x4212a83fc05ac, 0x4212ba8c72285, 0x4212edc99734b,
+    0x4213076829bae, 0x42130b111a173, 0x42133aa54ec74, 0x4213509af0f12,
+    0x4213afc35a514, 0x4213c961ecd77, 0x4213d805ae48b, 0x4213f1a440cee,
+    0x4213fc9f11e3d, 0x42144228e8bdc, 0x421450ccaa2f0, 0x421466c24c58e,
+    0x421475660dca2, 0x42148060dedf1, 0x4214d0e586cdf, 0x4214ee2d09b07,
+    0x4214f927dac56, 0x421550fe636ce, 0x4215634b153a7, 0x4215c61c6ef6e,
+    0x4215c9c55f533, 0x4215cd6e4faf8, 0x421628edc8b35, 0x42163ee36add3,
+    0x42164d872c4e7, 0x42165fd3de1c0, 0x42166725bed4a, 0x42166aceaf30f,
+    0x421680c4515ad, 0x4216931103286, 0x421696b9f384b, 0x4216b40176673,
+    0x421700dd2df9c, 0x42173f15241b1, 0x42174db8e58c5, 0x4217550ac644f,
+    0x4217ca28d1cef, 0x4217d523a2e3e, 0x4217eec2356a1, 0x421830a31be7b,
+    0x42184dea9eca3, 0x421858e56fdf2, 0x42185c8e603b7, 0x4218678931506,
+    0x42188fcb8547d, 0x4218937475a42, 0x4218ad13082a5, 0x4218b80dd93f4,
+    0x4218ce037b692, 0x421904e990d1d, 0x42195cc019795, 0x42197a079c5bd,
+    0x421981597d147, 0x4219a5f2e0af9, 0x4219d93005bbf, 0x4219e42ad6d0e,
+    0x421a29b4adaad, 0x421a559ff1fe9, 0x421aa9cd8a49c, 0x421add0aaf562,
+    0x421ae45c900ec, 0x421b597a9b98c, 0x421bb4fa149c9, 0x421bcaefb6c67,
+    0x421bf6dafb1a3, 0x421bfa83eb768, 0x421c3512f13b8, 0x421c400dc2507,
+    0x421c475fa3091, 0x421c4eb183c1b, 0x421c97e44af7f, 0x421caa30fcc58,
+    0x421cd61c41194, 0x421cf363c3fbc, 0x421d2a49d9647, 0x421d5d86fe70d,
+    0x421d8d1b3320e, 0x421dae0ba65fb, 0x421db1b496bc0, 0x421dc7aa38e5e,
+    0x421dcb5329423, 0x421de4f1bbc86, 0x421e098b1f638, 0x421e4b6c05e12,
+    0x421e77574a34e, 0x421eb58f40563, 0x421eda28a3f15, 0x421ee52375064,
+    0x421eec7555bee, 0x421f235b6b279, 0x421f39510d517, 0x421f653c51a53,
+    0x421f68e542018, 0x421f6c8e325dd, 0x421f7b31f3cf1, 0x421f8d7ea59ca,
+    0x421f987976b19, 0x421fb5c0f9941, 0x421fcf5f8c1a4, 0x421fda5a5d2f3,
+    0x421ff7a1e011b, 0x4220238d24657, 0x42207f0c9d694, 0x422095023f932,
+    0x4220b5f2b2d1f, 0x4220b99ba32e4, 0x4220cbe854fbd, 0x4220f081b896f,
+    0x4220f42aa8f34, 0x422132629f149, 0x42217b95664ad, 0x422182e747037,
+    0x42218a3927bc1, 0x4221918b0874b, 0x4221e20fb0639, 0x4222d746988c8,
+    0x42238a9c9a37d, 0x4224070c867a7, 0x4224195938480, 0x422427fcf9b94,
+    0x42242ba5ea159, 0x42245b3a1ec5a, 0x42248e7743d20, 0x4224fc436ea36,
+    0x42253a7b64c4b, 0x42255419f74ae, 0x42255b6bd8038, 0x42256db889d11,
+    0x422580053b9ea, 0x4225c1e6221c4, 0x42266dea430ef, 0x4226962c97066,
+    0x4226ac2239304, 0x4226afcb298c9, 0x4227004fd17b7, 0x4227498298b1b,
+    0x4227547d69c6a, 0x42275bcf4a7f4, 0x42275f783adb9, 0x42276e1bfc4cd,
+    0x422784119e76b, 0x4227965e50444, 0x4227b74ec3831, 0x4227dbe8271e3,
+    0x4227e6e2f8332, 0x422854af23048, 0x42285fa9f4197, 0x422871f6a5e70,
+    0x42287948869fa, 0x4228a8dcbb4fb, 0x4228c27b4dd5e, 0x4228c6243e323,
+    0x4228eabda1cd5, 0x4228ee669229a, 0x4228f20f8285f, 0x42290f5705687,
+    0x422954e0dc426, 0x42298bc6f1ab1, 0x4229c9fee7cc6, 0x422a5c647638e,
+    0x422a6b0837aa2, 0x422a8fa19b454, 0x422ae02643342, 0x422ae77823ecc,
+    0x422af61be55e0, 0x422b30aaeb230, 0x422b37fccbdba, 0x422b3ba5bc37f,
+    0x422ba971e7095, 0x422bf9f68ef83, 0x422bfd9f7f548, 0x422c382e85198,
+    0x422c90050dc10, 0x422cfa2848361, 0x422d4aacf024f, 0x422d51fed0dd9,
+    0x422d72ef441c6, 0x422d9036c6fee, 0x422da28378cc7, 0x422da62c6928c,
+    0x422da9d559851, 0x422dcac5ccc3e, 0x422de4645f4a1, 0x422dfa5a0173f,
+    0x422e76c9edb69, 0x422e9068803cc, 0x422ec3a5a5492, 0x422eebe7f9409,
+    0x422f38c3b0d32, 0x422f4eb952fd0, 0x422f610604ca9, 0x42302a51a89fc,
+    0x423059e5dd4fd, 0x42305d8ecdac2, 0x423064e0ae64c, 0x42306fdb7f79b,
+    0x4230dda7aa4b1, 0x4230e4f98b03b, 0x423102410de63, 0x4231616977465,
+    0x42317b0809cc8, 0x423189abcb3dc, 0x42318d54bb9a1, 0x4231ae452ed8e,
+    0x42323d01cce91, 0x42324f4e7eb6a, 0x42326c9601992, 0x4232a725075e2,
+    0x4232b971b92bb, 0x4232d6b93c0e3, 0x4232e55cfd7f7, 0x4232ecaede381,
+    0x4232fb529fa95, 0x423353292850d, 0x42335e23f965c, 0x42336575da1e6,
+    0x4233691eca7ab, 0x423418cbdbc9b, 0x42344fb1f1326, 0x423461fea2fff,
+    0x4234953bc80c5, 0x423511abb44ef, 0x42351554a4ab4, 0x423527a15678d,
+    0x4235329c278dc, 0x423539ee08466, 0x423565d94c9a2, 0x42358a72b0354,
+    0x4235d3a5776b8, 0x4235daf758242, 0x42362f24f06f5, 0x423691f64a2bc,
+    0x4236b68fadc6e, 0x4236cc854ff0c, 0x423748f53c336, 0x42377137902ad,
+    0x4237a81da5938, 0x4237ba6a57611, 0x4237be1347bd6, 0x4237c1bc3819b,
+    0x4237e6559bb4d, 0x42382bdf728ec, 0x42385b73a73ed, 0x4238666e7853c,
+    0x42388b07dbeee, 0x4238edd935ab5, 0x4238f52b1663f, 0x4238fc7cf71c9,
+    0x42394d019f0b7, 0x4239719b02a69, 0x423983e7b4742, 0x423996346641b,
+    0x4239d0c36c06b, 0x423a3ae6a67bc, 0x423a71ccbbe47, 0x423a9db800383,
+    0x423abaff831ab, 0x423ad84705fd3, 0x423b0b842b099, 0x423b376f6f5d5,
+    0x423b585fe29c2, 0x423b7cf946374, 0x423b9a40c919c, 0x423bac8d7ae75,
+    0x423bb7884bfc4, 0x423bd4cfcedec, 0x423bdfca9ff3b, 0x423c28fd6729f,
+    0x423c3b4a18f78, 0x423c4d96cac51, 0x423c513fbb216, 0x423c6ade4da79,
+    0x423cc65dc6ab6, 0x423cd15897c05, 0x423d13397e3df, 0x423d2cd810c42,
+    0x423e38049b16f, 0x423eb0cb96fd4, 0x423ec31848cad, 0x423ece1319dfc,
+    0x423f25e9a2874, 0x423f38365454d, 0x423f3bdf44b12, 0x423f7a173ad27,
+    0x423f81691b8b1, 0x423fd1edc379f, 0x423fe09184eb3, 0x424017779a53e,
+    0x4240903e963a3, 0x424093e786968, 0x42409b39674f2, 0x4240a28b4807c,
+    0x4240b4d7f9d55, 0x4240cacd9bff3, 0x4240fe0ac10b9, 0x42413c42b72ce,
+    0x42414ae6789e2, 0x424160dc1ac80, 0x42416f7fdc394, 0x42417328cc959,
+    0x42417e239daa8, 0x424185757e632, 0x4241a665f1a1f, 0x4241c75664e0c,
+    0x4241dd4c070aa, 0x424238cb800e7, 0x42427703762fc, 0x4242b53b6c511,
+    0x4242b8e45cad6, 0x4242fac5432b0, 0x4243021723e3a, 0x42432e0268376,
+    0x4243b1c43532a, 0x4243c7b9d75c8, 0x4243cb62c7b8d, 0x42440d43ae367,
+    0x42441be76fa7b, 0x4244651a36ddf, 0x42448d5c8ad56, 0x424509cc77180,
+    0x424568f4e0782, 0x4245988915283, 0x4245ae7eb7521, 0x4245c81d49d84,
+    0x4245d6c10b498, 0x4245e1bbdc5e7, 0x42463d3b55624, 0x42464bdf16d38,
+    0x42469511de09c, 0x424698bace661, 0x4246a3b59f7b0, 0x4246b25960ec4,
+    0x4246ece866b14, 0x42471c7c9b615, 0x42472ec94d2ee, 0x424732723d8b3,
+    0x424777fc14652, 0x4247869fd5d66, 0x424795439747a, 0x4247f814f1041,
+    0x424832a3f6c91, 0x42483d9ec7de0, 0x424865e11bd57, 0x4248a41911f6c,
+    0x4248cc5b65ee3, 0x424911e53cc82, 0x4249710da6284, 0x4249ba406d5e8,
+    0x4249d0360f886, 0x4249ded9d0f9a, 0x4249ed7d926ae, 0x424a33076944d,
+    0x424aa82574ced, 0x424ab32045e3c, 0x424ac1c407550, 0x424accbed869f,
+    0x424ad067c8c64, 0x424ae65d6af02, 0x424aedaf4ba8c, 0x424b3e33f397a,
+    0x424b41dce3f3f, 0x424b751a09005, 0x424b7c6be9b8f, 0x424b83bdca719,
+    0x424b8eb89b868, 0x424bb351ff21a, 0x424bbe4cd0369, 0x424be68f242e0,
+    0x424c1d753996b, 0x424c211e29f30, 0x424c420e9d31d, 0x424c5f5620145,
+    0x424c7c9da2f6d, 0x424ca4dff6ee4, 0x424ca888e74a9, 0x424cbe7e89747,
+    0x424cea69cdc83, 0x424d0f0331635, 0x424d12ac21bfa, 0x424d19fe02784,
+    0x424d3745855ac, 0x424d5835f8999, 0x424d66d9ba0ad, 0x424da8baa0887,
+    0x424db75e61f9b, 0x424dbb0752560, 0x424e1686cb59d, 0x424e42720fad9,
+    0x424e6ab463a50, 0x424e6e5d54015, 0x424e92f6b79c7, 0x424f0f66a3df1,
+    0x424f42a3c8eb7, 0x424f932870da5, 0x424f96d16136a, 0x424fca0e86430,
+    0x424fcdb7769f5, 0x424fd16066fba, 0x424fdc5b38109, 0x425008467c645,
+    0x42502936efa32, 0x42506ec0c67d1, 0x4250810d784aa, 0x4250a1fdeb897,
+    0x4250b44a9d570, 0x4250b7f38db35, 0x4250d8e400f22, 0x425104cf4545e,
+    0x42512968a8e10, 0x4251380c6a524, 0x42513bb55aae9, 0x4251764460739,
+    0x4251a981857ff, 0x4251f65d3d128, 0x4252642967e3e, 0x42527a1f0a0dc,
+    0x4252bfa8e0e7b, 0x4252d947736de, 0x42534abc8e9b9, 0x42537df9b3a7f,
+    0x425381a2a4044, 0x42539eea26e6c, 0x4253bc31a9c94, 0x42544aee47d97,
+    0x425505962a3d6, 0x425526869d7c3, 0x425543ce205eb, 0x425564be939d8,
+    0x4255b8ec2be8b, 0x4255ec2950f51, 0x425631b327cf0, 0x4256773cfea8f,
+    0x42569484818b7, 0x4256e8b219d6a, 0x42572a9300544, 0x4257910d4a6d0,
+    0x4257c44a6f796, 0x42589fe2c51c2, 0x42592e9f632c5, 0x42595a8aa7801,
+    0x4259b9b310e03, 0x4259fb93f75dd, 0x425a1c846a9ca, 0x425ac4df9b330,
+    0x425adad53d5ce, 0x425afbc5b09bb, 0x425aff6ea0f80, 0x425b623ffab47,
+    0x425b7bde8d3aa, 0x425b86d95e4f9, 0x425b8a824eabe, 0x425b957d1fc0d,
+    0x425bcc6335298, 0x425c06f23aee8, 0x425c11ed0c037, 0x425c193eecbc1,
+    0x425c6d6c85074, 0x425c8ab407e9c, 0x425cb69f4c3d8, 0x425cdb38afd8a,
+    0x425ce9dc7149e, 0x425d037b03d01, 0x425d2f664823d, 0x425d5056bb62a,
+    0x425d5b518c779, 0x425d664c5d8c8, 0x425d7c41ffb66, 0x425da82d440a2,
+    0x425dabd634667, 0x425e493693e7e, 0x425e543164fcd, 0x425e5b8345b57,
+    0x425e801ca9509, 0x425eb359ce5cf, 0x425ec1fd8fce3, 0x425ef8e3a536e,
+    0x425f3ac48bb48, 0x425f964404b85, 0x425fc22f490c1, 0x425fd0d30a7d5,
+    0x425fdbcddb924, 0x42603e9f354eb, 0x42605494d7789, 0x42606a8a79a27,
+    0x42614d74affdd, 0x4261b04609ba4, 0x4261cd8d8c9cc, 0x426258a13a50a,
+    0x42627991ad8f7, 0x42638867283e9, 0x4263ad008bd9b, 0x4264814700c3d,
+    0x42648feac2351, 0x42650508cdbf1, 0x426530f41212d, 0x42663877ac095,
+    0x42667306b1ce5, 0x4266dd29ec436, 0x4267bc6b32427, 0x4267d9b2b524f,
+    0x4267faa32863c, 0x42681b939ba29, 0x42682a375d13d, 0x42684b27d052a,
+    0x426859cb91c3e, 0x4268820de5bb5, 0x4268f72bf1455, 0x42690978a312e,
+    0x42694407a8d7e, 0x4269b1d3d3a94, 0x4269cf1b568bc, 0x426a6180e4f84,
+    0x426b446b1b53a, 0x426b61b29e362, 0x426ba39384b3c, 0x426bc483f7f29,
+    0x426bc82ce84ee, 0x426bda799a1c7, 0x426be1cb7ad51, 0x426c0a0dcecc8,
+    0x426c3d4af3d8e, 0x426c82d4cab2d, 0x426ca76e2e4df, 0x426cb268ff62e,
+    0x426d02eda751c, 0x426d18e3497ba, 0x426d65bf010e3, 0x426d7f5d93946,
+    0x426d91aa4561f, 0x426dcc394b26f, 0x426e65f0ba4c1, 0x426f8369f66c7,
+    0x426f8e64c7816, 0x426fbdf8fc317, 0x426fd3ee9e5b5, 0x42704563b9890,
+    0x42705b595bb2e, 0x427103b48c494, 0x42718b1f49a0d, 0x42725813ddd25,
+    0x42738f2bac78e, 0x4273bb16f0cca, 0x4273e35944c41, 0x4273fcf7d74a4,
+    0x427451256f957, 0x42746ac4021ba, 0x427480b9a4458, 0x4275bb7a63486,
+    0x4275f609690d6, 0x4276ca4fddf78, 0x4276e04580216, 0x427729784757a,
+    0x4277ca8197356, 0x4277e077395f4, 0x4278f2f5a46ab, 0x4279387f7b44a,
+    0x42796813aff4b, 0x42796f6590ad5, 0x42797a6061c24, 0x4279a2a2b5b9b,
+    0x427ad268a3a7a, 0x427b528180469, 0x427b6c2012ccc, 0x427b90b97667e,
+    0x427bec38ef6bb, 0x427bfe85a1394, 0x427ce16fd794a, 0x427cfb0e6a1ad,
+    0x427db95f3cdb1, 0x427e063af46da, 0x427e3d2109d65, 0x427e5316ac003,
+    0x427e74071f3f0, 0x427fc86670c81, 0x42805e74ef90e, 0x4280f0da7dfd6,
+    0x4281191cd1f4d, 0x42812b6983c26, 0x42813a0d4533a, 0x428178453b54f,
+    0x4282194e8b32b, 0x428248e2bfe2c, 0x4282996767d1a, 0x42837156cd181,
+    0x428407654be0e, 0x4286300b12541, 0x42869a2e4cc92, 0x4286bec7b0644,
+    0x4286f204d570a, 0x428700a896e1e, 0x428721990a20b, 0x42875fd100420,
+    0x4287b055a830e, 0x4288306e84cfd, 0x42891701ab878, 0x42892cf74db16,
+    0x42899e6c68df1, 0x4289bbb3ebc19, 0x4289e3f63fb90, 0x4289f29a012a4,
+    0x428a347ae7a7e, 0x428ae07f089a9, 0x428b1eb6febbe, 0x428bd5b5f0c38,
+    0x428bfa4f545ea, 0x428c1b3fc79d7, 0x428c2291a8561, 0x428c263a98b26,
+    0x428c29e3890eb, 0x428c34de5a23a, 0x428d141fa022b, 0x428d38b903bdd,
+    0x428e22f51ad1d, 0x428e64d6014f7, 0x428eb55aa93e5, 0x428f357385dd4,
+    0x428f73ab7bfe9, 0x428f949bef3d6, 0x42904b9ae1450, 0x42908629e70a0,
+    0x4291273336e7c, 0x4291322e07fcb, 0x42913d28d911a, 0x42914823aa269,
+    0x42915e194c507, 0x429198a852157, 0x4291da8938931, 0x4291f427cb194,
+    0x42923d5a924f8, 0x429295311af70, 0x4292bd736eee7, 0x429453b3a6f52,
+    0x4294ffb7c7e7d, 0x42952f4bfc97e, 0x42953a46cdacd, 0x42955b3740eba,
+    0x4295a812f87e3, 0x4296282bd51d2, 0x4296506e29149, 0x4296a844b1bc1,
+    0x4296d08705b38, 0x42975448d2aec, 0x42976a3e74d8a, 0x4297803417028,
+    0x429799d2a988b, 0x4297afc84bb29, 0x429819eb8627a, 0x4298288f4798e,
+    0x429866c73dba3, 0x4298a4ff33db8, 0x4299968d2ba82, 0x4299e368e33ab,
+    0x4299fd0775c0e, 0x429a80c942bc2, 0x429acda4fa4eb, 0x429af23e5de9d,
+    0x429b884cdcb2a, 0x429bb43821066, 0x429c37f9ee01a, 0x429c7288f3c6a,
+    0x429d591c1a7e5, 0x429d76639d60d, 0x429d9ea5f1584, 0x429deb81a8ead,
+    0x429e51fbf3039, 0x429ebc1f2d78a, 0x429ec719fe8d9, 0x429f29eb584a0,
+    0x429f6bcc3ec7a, 0x429f9b607377b, 0x429ffe31cd342, 0x42a03c69c3557,
+    0x42a04b0d84c6b, 0x42a059b14637f, 0x42a105b5672aa, 0x42a1faec4f539,
+    0x42a24b70f7427, 0x42a2e52866679, 0x42a3de083eecd, 0x42a40d9c739ce,
+    0x42a453264a76d, 0x42a4691beca0b, 0x42a49c5911ad1, 0x42a4cbed465d2,
+    0x42a4ff2a6b698, 0x42a511771d371, 0x42a54c0622fc1, 0x42a55700f4110,
+    0x42a5918ff9d60, 0x42a706dfbe9de, 0x42a75b0d56e91, 0x42a791f36c51c,
+    0x42a79cee3d66b, 0x42a7a7e90e7ba, 0x42a7db2633880, 0x42a8195e29a95,
+    0x42a86639e13be, 0x42a8fc486004b, 0x42a8fff150610, 0x42a9be4223214,
+    0x42a9c59403d9e, 0x42a9f17f482da, 0x42aa7c92f5e18, 0x42aac5c5bd17c,
+    0x42aba5070316d, 0x42abf9349b620, 0x42ac75a487a4a, 0x42ac87f139723,
+    0x42ac92ec0a872, 0x42ad7d28219b2, 0x42ade74b5c103, 0x42ae8102cb355,
+    0x42aea59c2ed07, 0x42aeca35926b9, 0x42af6b3ee2495, 0x42af972a269d1,
+    0x42afa224f7b20, 0x42afd5621cbe6, 0x42afd90b0d1ab, 0x42afe05cedd35,
+    0x42afe7aece8bf, 0x42b0223dd450f, 0x42b03bdc66d72, 0x42b0850f2e0d6,
+    0x42b0a9a891a88, 0x42b113cbcc1d9, 0x42b16ba254c51, 0x42b1d5c58f3a2,
+    0x42b2682b1da6a, 0x42b276cedf17e, 0x42b29b6842b30, 0x42b2c7538706c,
+    0x42b2dd492930a, 0x42b32dcdd11f8, 0x42b343c373496, 0x42b3526734baa,
+    0x42b35d6205cf9, 0x42b4404c3c2af, 0x42b50245ff478, 0x42b54426e5c52,
+    0x42b552caa7366, 0x42b5825edbe67, 0x42b5b1f310968, 0x42b665491241d,
+    0x42b6f405b0520, 0x42b72399e5021, 0x42b818d0cd2b0, 0x42b86cfe65763,
+    0x42b94144da605, 0x42b969872e57c, 0x42b98e2091f2e, 0x42b99cc453642,
+    0x42b9a7bf24791, 0x42b9c15db6ff4, 0x42ba6d61d7f1f, 0x42ba710ac84e4,
+    0x42bb037056bac, 0x42bb20b7d99d4, 0x42bb3a566c237, 0x42bb74e571e87,
+    0x42bbabcb87512, 0x42bc2be463f01, 0x42bcc59bd3153, 0x42bcdb91753f1,
+    0x42bd78f1d4c08, 0x42bd8ee776ea6, 0x42bdd4714dc45, 0x42bde6bdff91e,
+    0x42be1da414fa9, 0x42be80756eb70, 0x42bec9a835ed4, 0x42befce55af9a,
+    0x42bf5c0dc459c, 0x42bf80a727f4e, 0x42bf8f4ae9662, 0x42bfbedf1e163,
+    0x42bff21c43229, 0x42c063915e504, 0x42c100f1bdd1b, 0x42c163c3178e2,
+    0x42c1d53832bbd, 0x42c1f27fb59e5, 0x42c26b46b184a, 0x42c27d9363523,
+    0x42c2a5d5b749a, 0x42c2bbcb59738, 0x42c2eb5f8e239, 0x42c2f65a5f388,
+    0x42c325ee93e89, 0x42c330e964fd8, 0x42c351d9d83c5, 0x42c35582c898a,
+    0x42c367cf7a663, 0x42c36b786ac28, 0x42c3ad5951402, 0x42c3e096764c8,
+    0x42c464584347c, 0x42c4c7299d043, 0x42c50cb373de2, 0x42c51b57354f6,
+    0x42c5adbcc3bbe, 0x42c5f3469a95d, 0x42c62dd5a05ad, 0x42c685ac29025,
+    0x42c6cb35ffdc4, 0x42c6d9d9c14d8, 0x42c7aa7745db5, 0x42c7da0b7a8b6,
+    0x42c8099faf3b7, 0x42c80d489f97c, 0x42c8dde624259, 0x42c8f032d5f32,
+    0x42c914cc398e4, 0x42c92ac1dbb82, 0x42c9988e06898, 0x42c99c36f6e5d,
+    0x42c9d31d0c4e8, 0x42c9de17dd637, 0x42cb6d0634b18, 0x42cb98f179054,
+    0x42cc240526b92, 0x42cc832d90194, 0x42cc8a7f70d1e, 0x42ccfbf48bff9,
+    0x42cdffcf3599c, 0x42ce8e8bd3a9f, 0x42cf6a24294cb, 0x42cfa85c1f6e0,
+    0x42cfc5a3a2508, 0x42cfdf4234d6b, 0x42d07ca294582, 0x42d12c4fa5a72,
+    0x42d150e909424, 0x42d16335bb0fd, 0x42d1c60714cc4, 0x42d1e6f7880b1,
+    0x42d21a34ad177, 0x42d25c1593951, 0x42d25fbe83f16, 0x42d275b4261b4,
+    0x42d2bee6ed518, 0x42d32cb31822e, 0x42d3accbf4c1d, 0x42d3b41dd57a7,
+    0x42d3ca1377a45, 0x42d42ce4d160c, 0x42d4308dc1bd1, 0x42d4f6307535f,
+    0x42d4f9d965924, 0x42d504d436a73, 0x42d50c26175fd, 0x42d53bba4c0fe,
+    0x42d53f633c6c3, 0x42d579f242313, 0x42d5a98676e14, 0x42d5bf7c190b2,
+    0x42d60505efe51, 0x42d613a9b1565, 0x42d63f94f5aa1, 0x42d6608568e8e,
+    0x42d667d749a18, 0x42d68175dc27b, 0x42d6d94c64cf3, 0x42d6eb99169cc,
+    0x42d717845af08, 0x42d755bc5111d, 0x42d7680902df6, 0x42d7c3887be33,
+    0x42d810643375c, 0x42d89f20d185f, 0x42d8bc6854687, 0x42d8cb0c15d9b,
+    0x42d931865ff27, 0x42d9352f504ec, 0x42d9402a2163b, 0x42d9b5482cedb,
+    0x42da3561098ca, 0x42db26ef01594, 0x42db568336095, 0x42dc4bba1e324,
+    0x42dc7b4e52e25, 0x42dc9895d5c4d, 0x42dd8a23cd917, 0x42ddc85bc3b2c,
+    0x42ddcc04b40f1, 0x42ddcfada46b6, 0x42de2b2d1d6f3, 0x42dee22c0f76d,
+    0x42deed26e08bc, 0x42deff7392595, 0x42df3659a7c20, 0x42dfffa54b973,
+    0x42e03ddd41b88, 0x42e06d7176689, 0x42e08ab8f94b1, 0x42e18741c22ca,
+    0x42e19d3764568, 0x42e1a832356b7, 0x42e1abdb25c7c, 0x42e1b6d5f6dcb,
+    0x42e1edbc0c456, 0x42e23a97c3d7f, 0x42e2afb5cf61f, 0x42e39d9ad6d24,
+    0x42e3fcc340326, 0x42e42c5774e27, 0x42e4f1fa285b5, 0x42e512ea9b9a2,
+    0x42e5427ed04a3, 0x42e5c297ace92, 0x42e5f97dc251d, 0x42e679969ef0c,
+    0x42e742e242c5f, 0x42e7ad057d3b0, 0x42e7c2fb1f64e, 0x42e7e79483000,
+    0x42e80884f63ed, 0x42e8b0e026d53, 0x42e8c32cd8a2c, 0x42e967df18dcd,
+    0x42e9b111e0131, 0x42e9e7f7f57bc, 0x42ea76b4938bf, 0x42eb6f946c113,
+    0x42eb9084df500, 0x42ec736f15ab6, 0x42ec8d0da8319, 0x42ed10cf752cd,
+    0x42ed61541d1bb, 0x42ede8beda734, 0x42ee5ddce5fd4, 0x42ee6185d6599,
+    0x42eecba910cea, 0x42eeddf5c29c3, 0x42f0cfb573a6b, 0x42f14c255fe95,
+    0x42f1c4ec5bcfa, 0x42f26d478c660, 0x42f2ba2343f89, 0x42f2c51e150d8,
+    0x42f34536f1ac7, 0x42f353dab31db, 0x42f3f88cf357c, 0x42f4be2fa6d0a,
+    0x42f4db7729b32, 0x42f4f16ccbdd0, 0x42f51d581030c, 0x42f5459a64283,
+    0x42f5668ad7670, 0x42f62fd67b3c3, 0x42f6805b232b1, 0x42f68b55f4400,
+    0x42f68efee49c5, 0x42f6b7413893c, 0x42f6dbda9c2ee, 0x42f6df838c8b3,
+    0x42f6fccb0f6db, 0x42f7f5aae7f2f, 0x42f8587c41af6, 0x42f9acdb93387,
+    0x42f9e018b844d, 0x42fad1a6b0117, 0x42fad8f890ca1, 0x42fb3f72dae2d,
+    0x42fb51bf8cb06, 0x42fb8153c1607, 0x42fbad3f05b43, 0x42fbc334a7de1,
+    0x42fbd58159aba, 0x42fbd92a4a07f, 0x42fc2606019a8, 0x42fcd5b312e98,
+    0x42fce0ade3fe7, 0x42fce7ffc4b71, 0x42fd31328bed5, 0x42fd4e7a0ecfd,
+    0x42fe17c5b2a50, 0x42fe1f17935da, 0x42fecec4a4aca, 0x42fed61685654,
+    0x42ff2ded0e0cc, 0x42ff478ba092f, 0x42ff4edd814b9, 0x42ff7376e4e6b,
+    0x42ff896c87109, 0x42ff90be67c93, 0x4300d679f7e10, 0x430102653c34c,
+    0x4301e1a68233d, 0x43030a1a8f692, 0x430356f646fbb, 0x43035a9f37580,
+    0x43036942f8c94, 0x430439e07d571, 0x430448843ec85, 0x4304e5e49e49c,
+    0x4305450d07a9e, 0x4305993a9ff51, 0x43062f491ebde, 0x430653e282590,
+    0x4306b6b3dc157, 0x4306be05bcce1, 0x4306d3fb5ef7f, 0x4306def6300ce,
+    0x4306fc3db2ef6, 0x430762b7fd082, 0x4307d42d1835d, 0x43086a3b96fea,
+    0x4308f54f44b28, 0x43098062f2666, 0x4309baf1f82b6, 0x430b8bc135f71,
+    0x430c307376312, 0x430c8f9bdf914, 0x430d8c24a872d, 0x430dc30abddb8,
+    0x430e9afa2321f, 0x430f609cd69ad, 0x430f9ed4ccbc2, 0x430fb1217e89b,
+    0x430ff6ab5563a, 0x43108cb9d42c7, 0x4310e0e76c77a, 0x43113c66e57b7,
+    0x4311a68a1ff08, 0x4311ec13f6ca7, 0x4312d9f8fe3ac, 0x4313232bc5710,
+    0x43133578773e9, 0x43134f1709c4c, 0x43135dbacb360, 0x43138d4effe61,
+    0x431414b9bd3da, 0x4314444df1edb, 0x4314a71f4baa2, 0x4314cf619fa19,
+    0x43154f7a7c408, 0x4315e588fb095, 0x4316744599198, 0x43168a3b3b436,
+    0x4316c8733164b, 0x4316ed0c94ffd, 0x43179567c5963, 0x4317c4fbfa464,
+    0x4317d748ac13d, 0x4317de9a8ccc7, 0x4317f0e73e9a0, 0x431806dce0c3e,
+    0x4318500fa7fa2, 0x43187851fbf19, 0x43190ab78a5e1, 0x4319504161380,
+    0x431953ea51945, 0x431a62bfcc437, 0x431af17c6a53a, 0x431b2fb46074f,
+    0x431b80390863d, 0x431b8b33d978c, 0x431bafcd3d13e, 0x431c2c3d29568,
+    0x431d1679406a8, 0x431d3769b3a95, 0x431d5108462f8, 0x431d794a9a26f,
+    0x431da8deced70, 0x431dac87bf335, 0x431db78290484, 0x431e588be0260,
+    0x431eacb978713, 0x431f7d56fcff0, 0x431f9e47703dd, 0x43200fbc8b6b8,
+    0x43203ba7cfbf4, 0x4320519d71e92, 0x4321a253d315e, 0x432301adf5b3e,
+    0x43233c3cfb78e, 0x4323aa09264a4, 0x4323caf999891, 0x4323e0ef3bb2f,
+    0x4323e8411c6b9, 0x4323f6e4dddcd, 0x43243173e3a1d, 0x432438c5c45a7,
+    0x43246c02e966d, 0x432497ee2dba9, 0x43249b971e16e, 0x4324b8dea0f96,
+    0x4324d62623dbe, 0x4324f36da6be6, 0x43259bc8d754c, 0x4325d2aeecbd7,
+    0x43267b0a1d53d, 0x4326b94213752, 0x4326da3286b3f, 0x43273d03e0706,
+    0x43276c9815207, 0x43281fee16cbc, 0x4328b9a585f0e, 0x4328cf9b281ac,
+    0x4329a78a8d613, 0x432a1ca898eb3, 0x432aba08f86ca, 0x432b193161ccc,
+    0x432b626429030, 0x432b9cf32ec80, 0x432bc8de731bc, 0x432bd3d94430b,
+    0x432bf4c9b76f8, 0x432c0716693d1, 0x432c41a56f021, 0x432c663ed29d3,
+    0x432cbe155b44b, 0x432cd0620d124, 0x432d15ebe3ec3, 0x432d99adb0e77,
+    0x432e3e5ff1218, 0x432e57fe83a7b, 0x432e78eef6e68, 0x432ec5caae791,
+    0x432ec9739ed56, 0x432eee0d02708, 0x432f07ab94f6b, 0x432f24f317d93,
+    0x432f9dba13bf8, 0x432fd4a029283, 0x4330377182e4a, 0x43305c0ae67fc,
+    0x43306aaea7f10, 0x4330c28530988, 0x4330c62e20f4d, 0x4330e71e9433a,
+    0x433128ff7ab14, 0x4331acc147ac8, 0x4331b41328652, 0x4331bf0df97a1,
+    0x4331f24b1e867, 0x4332308314a7c, 0x4332d53554e1d, 0x4333554e3180c,
+    0x4333763ea4bf9, 0x4333b81f8b3d3, 0x4333ce152d671, 0x4333d1be1dc36,
+    0x4334433338f11, 0x43351779addb3, 0x4335f6baf3da4, 0x433622a6382e0,
+    0x433634f2e9fb9, 0x4336389bda57e, 0x4337317bb2dd2, 0x4337dd7fd3cfd,
+    0x4337e87aa4e4c, 0x43380219376af, 0x4338b1c648b9f, 0x4338b56f39164,
+    0x4339024af0a8d, 0x433910eeb21a1, 0x433931df2558e, 0x433991078eb90,
+    0x4339b949e2b07, 0x433abd248c4aa, 0x433ae90fd09e6, 0x433aff0572c84,
+    0x433b74237e524, 0x433c919cba72a, 0x433cb6361e0dc, 0x433cc882cfdb5,
+    0x433cff68e5440, 0x433d44f2bc1df, 0x433df0f6dd10a, 0x433eb69990898,
+    0x433ed3e1136c0, 0x4340124ac2cb3, 0x434036e426665, 0x43405082b8ec8,
+    0x434078c50ce3f, 0x4340ea3a2811a, 0x434124c92dd6a, 0x4342a513c3b37,
+    0x43435fbba6176, 0x43439df39c38b, 0x4343ac975da9f, 0x4343dfd482b65,
+    0x4343f5ca24e03, 0x43441a63887b5, 0x43445fed5f554, 0x4344d8b45b3b9,
+    0x4344e0063bf43, 0x43453b85b4f80, 0x434563c808ef7, 0x4345c2f0724f9,
+    0x4345cdeb43648, 0x4345e3e0e58e6, 0x43465ca7e174b, 0x434684ea356c2,
+    0x4346bf793b312, 0x4346e069ae6ff, 0x4346e7bb8f289, 0x4347174fc3d8a,
+    0x434788c4df065, 0x4347a9b552452, 0x43482d771f406, 0x4348596263942,
+    0x4348854da7e7e, 0x4348bfdcadace, 0x4348c72e8e658, 0x43496f89befbe,
+    0x4349d9acf970f, 0x434a3c7e532d6, 0x434a8d02fb1c4, 0x434af72635915,
+    0x434b6c44411b5, 0x434bddb95c490, 0x434c05fbb0407, 0x434c7770cb6e2,
+    0x434c910f5df45, 0x434c94b84e50a, 0x434ce53cf63f8, 0x434d028479220,
+    0x434e324a670ff, 0x434ebd5e14c3d, 0x434f2ed32ff18, 0x43506d3cdf50b,
+    0x43508e2d528f8, 0x4350ba1896e34, 0x435136888325e, 0x435241b50d78b,
+    0x4352b32a28a66, 0x435399bd4f5e1, 0x435400379976d, 0x43542879ed6e4,
+    0x4354371daedf8, 0x43547555a500d, 0x4354ac3bba698, 0x4355842b1faff,
+    0x4355f5a03adda, 0x43566abe4667a, 0x43567d0af8353, 0x435716c2675a5,
+    0x43571a6b57b6a, 0x4357290f1927e, 0x43573409ea3cd, 0x43573f04bb51c,
+    0x43575c4c3e344, 0x43577241e05e2, 0x43579332539cf, 0x43579e2d24b1e,
+    0x43581a9d10f48, 0x435837e493d70, 0x435858d50715d, 0x43586ecaa93fb,
+    0x43590fd3f91d7, 0x435943111e29d, 0x4359d91f9cf2a, 0x435a4342d767b,
+    0x435a64334aa68, 0x435ac704a462f, 0x435b262d0dc31, 0x435be0d4f0270,
+    0x435c1f0ce6485, 0x435c2a07b75d4, 0x435c9b7cd28af, 0x435cc76816deb,
+    0x435d6c1a5718c, 0x435d736c37d16, 0x435dd63d918dd, 0x435eb927c7e93,
+    0x435f2a9ce316e, 0x435f2e45d3733, 0x435f3940a4882, 0x435f3ce994e47,
+    0x435fb95981271, 0x435fc0ab61dfb, 0x435fcf4f2350f, 0x435fda49f465e,
+    0x436014d8fa2ae, 0x4360864e15589, 0x4360fb6c20e29, 0x43614f99b92dc,
+    0x4361867fce967, 0x4361bd65e3ff2, 0x4361de56573df, 0x43624c22820f5,
+    0x4362571d53244, 0x43626d12f54e2, 0x4362a3f90ab6d, 0x4362cfe44f0a9,
+    0x436391de12272, 0x43639cd8e33c1, 0x4363af259509a, 0x43651228a803f,
+    0x436574fa01c06, 0x4365c1d5b952f, 0x43663e45a5959, 0x4366509257632,
+    0x4366f8ed87f98, 0x4367003f68b22, 0x436716350adc0, 0x43674d1b2044b,
+    0x4367a89a99488, 0x4368e35b584b6, 0x4369a1ac2b0ba, 0x4369b7a1cd358,
+    0x436a55022cb6f, 0x436a7d4480ae6, 0x436a9e34f3ed3, 0x436ae767bb237,
+    0x436b4de2053c3, 0x436b9e66ad2b1, 0x436c1ad6996db, 0x436c34752bf3e,
+    0x436c4318ed652, 0x436c8150e3867, 0x436c9aef760ca, 0x436d9eca1fa6d,
+    0x436dc70c739e4, 0x436ddd0215c82, 0x436ec73e2cdc2, 0x436f4afff9d76,
+    0x4370144b9dac9, 0x4370353c10eb6, 0x43710d2b7631d, 0x4371356dca294,
+    0x43714f0c5caf7, 0x4371949633896, 0x4371a339f4faa, 0x4371b92f97248,
+    0x4371c7d35895c, 0x4371feb96dfe7, 0x437268dca8738, 0x4372b20f6fa9c,
+    0x437356c1afe3d, 0x4373c48ddab53, 0x437411699247c, 0x437439abe63f3,
+    0x437465972a92f, 0x43747b8cccbcd, 0x43750df25b295, 0x4375412f8035b,
+    0x43755e7703183, 0x4375621ff3748, 0x437591b428249, 0x437678474edc4,
+    0x4376a089a2d3b, 0x4376af2d6444f, 0x4376cc74e7277, 0x4376e26a89515,
+    0x4376f8602b7b3, 0x43774c8dc3c66, 0x43775eda7593f, 0x437769d546a8e,
+    0x4377a4644c6de, 0x4377b3080ddf2, 0x4377be02def41, 0x4377e9ee2347d,
+    0x43785b633e758, 0x43787501d0fbb, 0x43791d5d01921, 0x437936fb94184,
+    0x4379718a99dd4, 0x43797c856af23, 0x4379a4c7bee9a, 0x4379babd61138,
+    0x4379d45bf399b, 0x4379edfa861fe, 0x437a756543777, 0x437aac4b58e02,
+    0x437abaef1a516, 0x437b12c5a2f8e, 0x437b634a4ae7c, 0x437bc61ba4a43,
+    0x437bdfba372a6, 0x437c4d8661fbc, 0x437c6e76d53a9, 0x437c8f6748796,
+    0x437ca1b3fa46f, 0x437cd89a0fafa, 0x437dc2d626c3a, 0x437df6134bd00,
+    0x437e21fe9023c, 0x437e4697f3bee, 0x437e9e6e7c666, 0x437ebf5eefa53,
+    0x437f9af74547f, 0x437fca8b79f80, 0x437fd5864b0cf, 0x437feb7bed36d,
+    0x438093d71dcd3, 0x43812d8e8cf25, 0x43813c324e639, 0x438181bc253d8,
+    0x43830d018c2f4, 0x438359dd43c1d, 0x438405e164b48, 0x43840d33456d2,
+    0x43844b6b3b8e7, 0x43853cf9335b1, 0x4385da5992dc8, 0x43862e872b27b,
+    0x4386706811a55, 0x4386915884e42, 0x4386ff24afb58, 0x4387485776ebc,
+    0x43874fa957a46, 0x4387918a3e220, 0x4387b623a1bd2, 0x4387b9cc92197,
+    0x4387c4c7632e6, 0x43885384013e9, 0x438870cb84211, 0x4388b9fe4b575,
+    0x4388e2409f4ec, 0x4388fbdf31d4f, 0x43891ccfa513c, 0x4389993f91566,
+    0x4389a7e352c7a, 0x4389af3533804, 0x4389f867fab68, 0x438a20aa4eadf,
+    0x438ab30fdd1a7, 0x438b4cc74c3f9, 0x438b57c21d548, 0x438ba846c5436,
+    0x438c001d4deae, 0x438c076f2ea38, 0x438c19bbe0711, 0x438c370363539,
+    0x438c50a1f5d9c, 0x438ca1269dc8a, 0x438cdbb5a38da, 0x438d338c2c352,
+    0x438dcd439b5a4, 0x438dee340e991, 0x438e0f2481d7e, 0x438e4261a6e44,
+    0x438e759ecbf0a, 0x438e7947bc4cf, 0x438ef20eb8334, 0x438f7979758ad,
+    0x438fb4087b4fd, 0x438fc2ac3cc11, 0x438fcda70dd60, 0x438fe39cafffe,
+    0x4390fd6cfbc3f, 0x439104bedc7c9, 0x4391170b8e4a2, 0x4391ce0a8051c,
+    0x4391d9055166b, 0x4391dcae41c30, 0x43932d64a2efc, 0x439388e41bf39,
+    0x4393e46394f76, 0x4394a65d5813f, 0x4394caf6bbaf1, 0x439522cd44569,
+    0x43958cf07ecba, 0x4395ced165494, 0x43967ad5863bf, 0x4396dda6dff86,
+    0x4396ec4aa169a, 0x43971f87c6760, 0x4398619a66318, 0x4398a7243d0b7,
+    0x4398b21f0e206, 0x4398fefac5b2f, 0x43990d9e87243, 0x4399e936dcc6f,
+    0x439a7b9c6b337, 0x439ae216b54c3, 0x439b3d962e500, 0x439b622f91eb2,
+    0x439b782534150, 0x439ba067880c7, 0x439bdaf68dd17, 0x439c158593967,
+    0x439c4c6ba8ff2, 0x439c959e70356, 0x439d0abc7bbf6, 0x439d41a291281,
+    0x439d5eea140a9, 0x439d838377a5b, 0x439db317ac55c, 0x439dd4081f949,
+    0x439dd7b10ff0e, 0x439e5420fc338, 0x439ed439d8d27, 0x439f0776fdded,
+    0x439ff904f5ab7, 0x439ffcade607c, 0x43a02feb0b142, 0x43a0806fb3030,
+    0x43a08418a35f5, 0x43a092bc64d09, 0x43a09665552ce, 0x43a0d49d4b4e3,
+    0x43a107da705a9, 0x43a1376ea50aa, 0x43a16359e95e6, 0x43a17cf87be49,
+    0x43a1a53acfdc0, 0x43a1bed962623, 0x43a1c62b431ad, 0x43a1eac4a6b5f,
+    0x43a25fe2b23ff, 0x43a2673492f89, 0x43a2722f640d8, 0x43a300ec021db,
+    0x43a3b7eaf4255, 0x43a484df8856d, 0x43a4f2abb3283, 0x43a530e3a9498,
+    0x43a56420ce55e, 0x43a59eafd41ae, 0x43a68542fad29, 0x43a6a28a7db51,
+    0x43a6d970931dc, 0x43a7055bd7718, 0x43a76f7f11e69, 0x43a7906f85256,
+    0x43a794187581b, 0x43a8401c96746, 0x43a8610d09b33, 0x43a86c07dac82,
+    0x43a890a13e634, 0x43a8e877c70ac, 0x43aa392e28378, 0x43aa98569197a,
+    0x43aaf77efaf7c, 0x43ab1fc14eef3, 0x43ab7b40c7f30, 0x43ab7ee9b84f5,
+    0x43ab863b9907f, 0x43ab8d8d79c09, 0x43ab9c313b31d, 0x43ac35e8aa56f,
+    0x43aca00be4cc0, 0x43aca3b4d5285, 0x43acaeafa63d4, 0x43ad3d6c444d7,
+    0x43ad7ba43a6ec, 0x43ad8a47fbe00, 0x43ad9199dc98a, 0x43adb6334033c,
+    0x43adf0c245f8c, 0x43ae27a85b617, 0x43ae364c1cd2b, 0x43aeaf1318b90,
+    0x43af243124430, 0x43afa44a00e1f, 0x43afd3de35920, 0x43b06643c3fe8,
+    0x43b069ecb45ad, 0x43b06d95a4b72, 0x43b0b31f7b911, 0x43b0c56c2d5ea,
+    0x43b11247e4f13, 0x43b1249496bec, 0x43b1492dfa59e, 0x43b1d098b7b17,
+    0x43b2afd9fdb08, 0x43b2e31722bce, 0x43b3df9feb9e7, 0x43b3f5958dc85,
+    0x43b45fb8c83d6, 0x43b4b03d702c4, 0x43b4c9dc02b27, 0x43b4e7238594f,
+    0x43b642d4b7d6a, 0x43b6601c3ab92, 0x43b6a5a611931, 0x43b72967de8e5,
+    0x43b7b0d29be5e, 0x43b7bbcd6cfad, 0x43b843382a526, 0x43b8607fad34e,
+    0x43b8d946a91b3, 0x43b8e7ea6a8c7, 0x43b95d0876167, 0x43b972fe18405,
+    0x43b9d97862591, 0x43bb22dce2cd3, 0x43bb43cd560c0, 0x43bb73618abc1,
+    0x43bbcee103bfe, 0x43bbf72357b75, 0x43bc1f65abaec, 0x43bc85dff5c78,
+    0x43bcaa795962a, 0x43bcf75510f53, 0x43bd65213bc69, 0x43bd985e60d2f,
+    0x43bdbcf7c46e1, 0x43bdc0a0b4ca6, 0x43bdf786ca331, 0x43be9fe1fac97,
+    0x43bed6c810322, 0x43bf40eb4aa73, 0x43bf4be61bbc2, 0x43bf8a1e11dd7,
+    0x43c00a36ee7c6, 0x43c0c4ded0e05, 0x43c0cc30b198f, 0x43c111ba8872e,
+    0x43c1cc626ad6d, 0x43c2aba3b0d5e, 0x43c2d78ef529a, 0x43c2fc2858c4c,
+    0x43c3246aacbc3, 0x43c32f657dd12, 0x43c341b22f9eb, 0x43c3abd56a13c,
+    0x43c3b3274acc6, 0x43c3ea0d60351, 0x43c41d4a85417, 0x43c48b16b012d,
+    0x43c5162a5dc6b, 0x43c52fc8f04ce, 0x43c566af05b59, 0x43c5a13e0b7a9,
+    0x43c642475b585, 0x43c66337ce972, 0x43c6a8c1a5711, 0x43c6b0138629b,
+    0x43c707ea0ed13, 0x43c7427914963, 0x43c79df88d9a0, 0x43c7a1a17df65,
+    0x43c7e3826473f, 0x43c81e116a38f, 0x43c82cb52baa3, 0x43c8acce08492,
+    0x43c8b41fe901c, 0x43c8b7c8d95e1, 0x43c95c7b19982, 0x43c97d6b8cd6f,
+    0x43ca51b201c11, 0x43cb1754b539f, 0x43cb558cab5b4, 0x43cd22b2f8caa,
+    0x43cd2dadc9df9, 0x43cdd9b1ead24, 0x43cdfe4b4e6d6, 0x43ce5278e6b89,
+    0x43ceefd9463a0, 0x43cf6c49327ca, 0x43cf739b13354, 0x43cf7aecf3ede,
+    0x43d02e42f5993, 0x43d068d1fb5e3, 0x43d089c26e9d0, 0x43d0d2f535d34,
+    0x43d15a5ff32ad, 0x43d161b1d3e37, 0x43d1ae8d8b760, 0x43d274303eeee,
+    0x43d27f2b1003d, 0x43d2a76d63fb4, 0x43d2b26835103, 0x43d2b9ba15c8d,
+    0x43d2daaa8907a, 0x43d34876b3d90, 0x43d395526b6b9, 0x43d3f823c5280,
+    0x43d3fbccb5845, 0x43d45e9e0f40c, 0x43d47493b16aa, 0x43d4af22b72fa,
+    0x43d5a4599f589, 0x43d6281b6c53d, 0x43d6ea152f706, 0x43d719a964207,
+    0x43d72f9f064a5, 0x43d79d6b311bb, 0x43d80b375bed1, 0x43d8d82bf01e9,
+    0x43d9a52084501, 0x43d9b76d361da, 0x43d9bebf16d64, 0x43da4629d42dd,
+    0x43da5c1f7657b, 0x43daf5d6e57cd, 0x43db340edb9e2, 0x43dbb07ec7e0c,
+    0x43dbe3bbeced2, 0x43dc1aa20255d, 0x43dcc2fd32ec3, 0x43dcd549e4b9c,
+    0x43dd172acb376, 0x43dd79fc24f3d, 0x43dd814e05ac7, 0x43dd84f6f608c,
+    0x43ddd9248e53f, 0x43de3bf5e8106, 0x43de5ce65b4f3, 0x43de6f330d1cc,
+    0x43def69dca745, 0x43dfc73b4f022, 0x43dfe482d1e4a, 0x43e0c01b27876,
+    0x43e12de75258c, 0x43e1403404265, 0x43e15d7b8708d, 0x43e2b1dad891e,
+    0x43e2d2cb4bd0b, 0x43e352e4286fa, 0x43e37ecf6cc36, 0x43e44f6cf1513,
+    0x43e46562937b1, 0x43e4c48afcdb3, 0x43e4c833ed378, 0x43e4f076412ef,
+    0x43e58684bff7c, 0x43e5a023527df, 0x43e665c605f6d, 0x43e7032665784,
+    0x43e70a784630e, 0x43e7206de85ac, 0x43e73a0c7ae0f, 0x43e745074bf5e,
+    0x43e7d76cda626, 0x43e83de7247b2, 0x43e85033d648b, 0x43e899669d7ef,
+    0x43e8e9eb456dd, 0x43e93e18ddb90, 0x43e941c1ce155, 0x43e9540e7fe2e,
+    0x43e957b7703f3, 0x43ea334fc5e1f, 0x43eafc9b69b72, 0x43eb45ce30ed6,
+    0x43ec1dbd9633d, 0x43ec3eae0972a, 0x43ec92dba1bdd, 0x43edc9f370646,
+    0x43edf987a5147, 0x43ee1e2108af9, 0x43ee4db53d5fa, 0x43eeca2529a24,
+    0x43eef2677d99b, 0x43ef5538d7562, 0x43efc30502278, 0x43f05cbc714ca,
+    0x43f06f09231a3, 0x43f0b0ea0997d, 0x43f13bfdb74bb, 0x43f260c8d424b,
+    0x43f2856237bfd, 0x43f3908ec212a, 0x43f3cb1dc7d7a, 0x43f45631758b8,
+    0x43f4b559deeba, 0x43f50235967e3, 0x43f5182b38a81, 0x43f5824e731d2,
+    0x43f5da24fbc4a, 0x43f66538a9788, 0x43f668e199d4d, 0x43f677855b461,
+    0x43f6c80a0334f, 0x43f7397f1e62a, 0x43f7ecd5200df, 0x43f7f42700c69,
+    0x43f81c6954be0, 0x43f923eceeb48, 0x43f96d1fb5eac, 0x43f991b91985e,
+    0x43faa7e074eda, 0x43faba2d26bb3, 0x43fb0ab1ceaa1, 0x43fb0e5abf066,
+    0x43fb787df97b7, 0x43fc491b7e094, 0x43fc83aa83ce4, 0x43fcbe3989934,
+    0x43fd8ed70e211, 0x43fda875a0a74, 0x43fe422d0fcc6, 0x43fe7cbc15916,
+    0x43fe8f08c75ef, 0x43fec997cd23f, 0x43ff71f2fdba5, 0x43ffac82037f5,
+    0x44002c9ae01e4, 0x44003043d07a9, 0x44003b3ea18f8, 0x44005c2f14ce5,
+    0x4400a90acc60e, 0x4400b4059d75d, 0x440137c76a711, 0x4401675b9f212,
+    0x4401a1eaa4e62, 0x4401b43756b3b, 0x4401c2db1824f, 0x44024a45d57c8,
+    0x44029aca7d6b6, 0x4402a21c5e240, 0x4402eef815b69, 0x440329871b7b9,
+    0x44033bd3cd492, 0x44033f7cbda57, 0x44034a778eba6, 0x440367bf119ce,
+    0x440372b9e2b1d, 0x44037662d30e2, 0x4403a24e1761e, 0x4403ce395bb5a,
+    0x4403d58b3c6e4, 0x4404260fe45d2, 0x44044357673fa, 0x4404bc1e6325f,
+    0x4404d965e6087, 0x4404e809a779b, 0x4404f6ad68eaf, 0x44050ca30b14d,
+    0x4405104bfb712, 0x440534e55f0c4, 0x44054389207d8, 0x4405473210d9d,
+    0x4405647993bc5, 0x44058cbbe7b3c, 0x44059064d8101, 0x44059b5fa9250,
+    0x44059f0899815, 0x4405a2b189dda, 0x4406267356d8e, 0x440643bad9bb6,
+    0x44064763ca17b, 0x44064b0cba740, 0x44066fa61e0f2, 0x4406734f0e6b7,
+    0x4406a68c3377d, 0x4406cece876f4, 0x4406ec160a51c, 0x4406fe62bc1f5,
+    0x440738f1c1e45, 0x44073c9ab240a, 0x44074043a29cf, 0x44079f6c0bfd1,
+    0x4407b561ae26f, 0x4407c4056f983, 0x4407e14cf27ab, 0x4407e4f5e2d70,
+    0x440868b7afd24, 0x4408d2daea475, 0x4408d683daa3a, 0x4408da2ccafff,
+    0x4408ddd5bb5c4, 0x44093cfe24bc6, 0x44098287fb965, 0x4409ae733fea1,
+    0x4409de07749a2, 0x4409f7a607205, 0x440a2ae32c2cb, 0x440ab99fca3ce,
+    0x440b0a24722bc, 0x440ba032f0f49, 0x440ba784d1ad3, 0x440bbd7a73d71,
+    0x440bc12364336, 0x440bfbb269f86, 0x440c2b469ea87, 0x440c747965deb,
+    0x440c9cbbb9d62, 0x440ca7b68aeb1, 0x440cc1551d714, 0x440d0a87e4a78,
+    0x440d0e30d503d, 0x440d1582b5bc7, 0x440d192ba618c, 0x440d27cf678a0,
+    0x440d2b7857e65, 0x440d66075dab5, 0x440da09663705, 0x440dbddde652d,
+    0x440ded721b02e, 0x440e0ab99de56, 0x440e20af400f4, 0x440e36a4e2392,
+    0x440e87298a280, 0x440ea0c81cae3, 0x440eb6bdbed81, 0x440efc4795b20,
+    0x440f1d3808f0d, 0x440f332dab1ab, 0x440f57c70eb5d, 0x440f95ff04d72,
+    0x440fa4a2c6486, 0x440fb6ef7815f, 0x440fd08e0a9c2, 0x44101617e1761,
+    0x441028649343a, 0x44102fb673fc4, 0x441062f39908a, 0x44106a4579c14,
+    0x4410cd16d37db, 0x4410f90217d17, 0x4410fcab082dc, 0x44113ae2fe4f1,
+    0x44115bd3718de, 0x44116e20235b7, 0x441171c913b7c, 0x44118415c5855,
+    0x441192b986f69, 0x4411c24dbba6a, 0x4411dbec4e2cd, 0x4411f1e1f056b,
+    0x4411f933d10f5, 0x44123ebda7e94, 0x441271faccf5a, 0x4412b3dbb3734,
+    0x4412bb2d942be, 0x44133b4670cad, 0x44140f8ce5b4f, 0x441476072fcdb,
+    0x4414a1f274217, 0x4414ca34c818e, 0x4414e02a6a42c, 0x441513678f4f2,
+    0x441563ec373e0, 0x4415a5cd1dbba, 0x4415ad1efe744, 0x4416014c96bf7,
+    0x4416223d09fe4, 0x441630e0cb6f8, 0x441659231f66f, 0x44169b0405e49,
+    0x4416a9a7c755d, 0x4416b84b88c71, 0x4416c6ef4a385, 0x4416d1ea1b4d4,
+    0x4416dce4ec623, 0x4416eb88add37, 0x441705274059a, 0x441713cb01cae,
+    0x4417470826d74, 0x44175202f7ec3, 0x4417769c5b875, 0x4417ef63576da,
+    0x44183c3f0f003, 0x4418598691e2b, 0x441897be88040, 0x4418bc57eb9f2,
+    0x4418ef9510ab8, 0x441938c7d7e1c, 0x44194b1489af5, 0x44199b99319e3,
+    0x4419b18ed3c81, 0x441a4b4642ed3, 0x441a613be5171, 0x441a77318740f,
+    0x441a822c5855e, 0x441ac7b62f2fd, 0x441b26de988ff, 0x441b9bfca419f,
+    0x441bb94426fc7, 0x441bc7e7e86db, 0x441be1867af3e, 0x441bf02a3c652,
+    0x441bf77c1d1dc, 0x441bfecdfdd66, 0x441c4ba9b568f, 0x441c704319041,
+    0x441c7b3dea190, 0x441c8638bb2df, 0x441cf7add65ba, 0x441d1c4739f6c,
+    0x441d23991aaf6, 0x441d35e5cc7cf, 0x441d398ebcd94, 0x441d5e2820746,
+    0x441d77c6b2fa9, 0x441dda980cb70, 0x441df08daee0e, 0x441e326e955e8,
+    0x441e77f86c387, 0x441eb28771fd7, 0x441ec87d14275, 0x441ecfcef4dff,
+    0x441efbba3933b, 0x441f2ef75e401, 0x441f5ae2a293d, 0x441f91c8b7fc8,
+    0x441fc15cecac9, 0x44202085560cb, 0x4420ab9903c09, 0x4420ba3cc531d,
+    0x4420bde5b58e2, 0x4420ed79ea3e3, 0x44212f5ad0bbd, 0x44213dfe922d1,
+    0x44215eef056be, 0x44216d92c6dd2, 0x442174e4a795c, 0x44217c36884e6,
+    0x4421922c2a784, 0x4421a478dc45d, 0x442233357a560, 0x44227c68418c4,
+    0x4422eddd5cb9f, 0x44233ab9144c8, 0x4423928f9cf40, 0x4423ea66259b8,
+    0x4423f909e70cc, 0x442412a87992f, 0x44241da34aa7e, 0x44243741dd2e1,
+    0x44245bdb40c93, 0x442466d611de2, 0x4424df9d0dc47, 0x44254d693895d,
+    0x4425bb3563673, 0x44261e06bd23a, 0x442621afad7ff, 0x442637a54fa9d,
+    0x44268f7bd8515, 0x4426bb671ca51, 0x4426c2b8fd5db, 0x44272cdc37d2c,
+    0x442751759b6de, 0x4427a5a333b91, 0x4427a94c24156, 0x4427bb98d5e2f,
+    0x44282d0df110a, 0x4428345fd1c94, 0x44284303933a8, 0x442851a754abc,
+    0x4428604b161d0, 0x442863f406795, 0x44287297c7ea9, 0x442884e479b82,
+    0x4428b0cfbe0be, 0x4428d56921a70, 0x442930e89aaad, 0x4429a25db5d88,
+    0x442a645778f51, 0x442a76a42ac2a, 0x442aa9e14fcf0, 0x442b97c6573f5,
+    0x442bb50dda21d, 0x442bd2555d045, 0x442be84aff2e3, 0x442c9f49f135d,
+    0x442cdd81e7572, 0x442d021b4af24, 0x442d05c43b4e9, 0x442d096d2baae,
+    0x442d6895950b0, 0x442d7e8b3734e, 0x442d89860849d, 0x442d9829c9bb1,
+    0x442dc06c1db28, 0x442dfea413d3d, 0x442e3cdc09f52, 0x442e7ebcf072c,
+    0x442e8265e0cf1, 0x442ea6ff446a3, 0x442ec446c74cb, 0x442ecf419861a,
+    0x442f11227edf4, 0x442f14cb6f3b9, 0x442f236f30acd, 0x442ff7b5a596f,
+    0x443044915d298, 0x443056de0ef71, 0x44305a86ff536, 0x4430707ca17d4,
+    0x4430cfa50add6, 0x4430e59aad074, 0x443102e22fe9c, 0x4431b28f4138c,
+    0x4431c13302aa0, 0x4431c884e362a, 0x4431cc2dd3bef, 0x4431fbc2086f0,
+    0x4432414bdf48f, 0x4432539891168, 0x4432ff9cb2093, 0x4433502159f81,
+    0x4433d78c174fa, 0x44343e0661686, 0x443441af51c4b, 0x44344caa22d9a,
+    0x44348ae218faf, 0x4434a8299bdd7, 0x4434b3246cf26, 0x4434ccc2ff789,
+    0x44354cdbdc178, 0x4435876ae1dc8, 0x44363e69d3e42, 0x44365f5a4722f,
+    0x4436879c9b1a6, 0x443728a5eaf82, 0x443766dde1197, 0x443771d8b22e6,
+    0x4437ac67b7f36, 0x4437b76289085, 0x4437bb0b7964a, 0x4437d4aa0bead,
+    0x4437f59a7f29a, 0x44383b2456039, 0x4438427636bc3, 0x4438670f9a575,
+    0x4438b042618d9, 0x4438d132d4cc6, 0x4439514bb16b5, 0x4439ca12ad51a,
+    0x4439eb0320907, 0x443a60212c1a7, 0x443afd818b9be, 0x443b1ac90e7e6,
+    0x443b8895394fc, 0x443ba5dcbc324, 0x443be414b2539, 0x443bf2b873c4d,
+    0x443c224ca874e, 0x443c433d1bb3b, 0x443c8c6fe2e9f, 0x443cdcf48ad8d,
+    0x443d08dfcf2c9, 0x443d3fc5e4954, 0x443d6bb128e90, 0x443d7dfddab69,
+    0x443dbfdec1343, 0x443e5d3f20b5a, 0x443e60e81111f, 0x443e6be2e226e,
+    0x443e9b7716d6f, 0x443ed25d2c3fa, 0x443f094341a85, 0x443fbc994353a,
+    0x44409f8379af0, 0x4440b5791bd8e, 0x4440da127f740, 0x44410254d36b7,
+    0x444114a185390, 0x44411f9c564df, 0x44412e4017bf3, 0x44414435b9e91,
+    0x444165262d27e, 0x4441cf49679cf, 0x44421fce0f8bd, 0x444298950b722,
+    0x4442d32411372, 0x444323a8b9260, 0x4443b9b737eed, 0x4443f7ef2e102,
+    0x4444536ea713f, 0x44446964493dd, 0x44448dfdacd8f, 0x4444b9e8f12cb,
+    0x4444fbc9d7aa5, 0x44450a6d991b9, 0x444515686a308, 0x4445a4250840b,
+    0x4445a7cdf89d0, 0x4445d7622d4d1, 0x444627e6d53bf, 0x444671199c723,
+    0x4446b2fa82efd, 0x4446f88459c9c, 0x444769f974f77, 0x44477c4626c50,
+    0x4447a8316b18c, 0x4447b32c3c2db, 0x44488772b117d, 0x4448a111439e0,
+    0x4448d0a5784e1, 0x444903e29d5a7, 0x4449078b8db6c, 0x4449287c00f59,
+    0x44499d9a0c7f9, 0x4449e323e3598, 0x444ab76a5843a, 0x444b4d78d70c7,
+    0x444bb04a30c8e, 0x444be38755d54, 0x444bead9368de, 0x444cdc672e5a8,
+    0x444ce3b90f132, 0x444d16f6341f8, 0x444d3b8f97baa, 0x444d7275ad235,
+    0x444d936620622, 0x444da209e1d36, 0x444e30c67fe39, 0x444e79f94719d,
+    0x444e7da237762, 0x444e8c45f8e76, 0x444f1b0296f79, 0x444f51e8ac604,
+    0x444f76820ffb6, 0x444f7dd3f0b40, 0x44503129f25f5, 0x445090525bbf7,
+    0x4450bc3da0133, 0x44513fff6d0e7, 0x445143a85d6ac, 0x4451908414fd5,
+    0x44519f27d66e9, 0x44529807aef3d, 0x4452fe81f90c9, 0x4453d6715e530,
+    0x44542e47e6fa8, 0x4454aab7d33d2, 0x445556bbf42fd, 0x44555e0dd4e87,
+    0x4455a7409c1eb, 0x4455b23b6d33a, 0x4456ab1b45b8e, 0x4456aec436153,
+    0x4456f0a51c92d, 0x4457328603107, 0x44579ca93d858, 0x4457c4eb917cf,
+    0x4457f47fc62d0, 0x445848ad5e783, 0x4458cc6f2b737, 0x4459d049d50da,
+    0x4459e29686db3, 0x445b41f0a9793, 0x445b99c73220b, 0x445be6a2e9b34,
+    0x445c422262b71, 0x445c50c624285, 0x445c6e0da70ad, 0x445c8efe1a49a,
+    0x445ca4f3bc738, 0x445cd830e17fe, 0x445cf92154beb, 0x445d4d4eed09e,
+    0x445d6a966fec6, 0x445d808c12164, 0x445d9dd394f8c, 0x445da52575b16,
+    0x445dc9bed94c8, 0x445dd8629abdc, 0x445ddfb47b766, 0x445e49d7b5eb7,
+    0x445f1e1e2ad59, 0x445f42b78e70b, 0x445f515b4fe1f, 0x445facdac8e5c,
+    0x44604a3b28673, 0x4460518d091fd, 0x4460d54ed61b1, 0x4460e3f2978c5,
+    0x44615cb99372a, 0x44616f0645403, 0x4461c6dccde7b, 0x4461d9297fb54,
+    0x4464d9beab6ee, 0x446505a9efc2a, 0x446594668dd2d, 0x4465b5570111a,
+    0x4465e4eb35c1b, 0x44673cf377a71, 0x4467ae6892d4c, 0x44694851bb37c,
+    0x446982e0c0fcc, 0x44699184826e0, 0x4469c4c1a77a6, 0x446a7817a925b,
+    0x446ab9f88fa35, 0x446b3a116c424, 0x446b784962639, 0x446b7f9b431c3,
+    0x446bcc76faaec, 0x446c1952b2415, 0x446c9d147f3c9, 0x446cfc3ce89cb,
+    0x446d6a09136e1, 0x446d78acd4df5, 0x446da49819331, 0x446e45a16910d,
+    0x446ef1a58a038, 0x446f0eed0ce60, 0x446f4d2503075, 0x446f8b5cf928a,
+    0x446fb39f4d201, 0x4470425beb304, 0x4470a8d635490, 0x4471c2a6810d1,
+    0x4471c9f861c5b, 0x44726b01b1a37, 0x4472dc76ccd12, 0x4472e7719de61,
+    0x44733f48268d9, 0x44739375bed8c, 0x44744a74b0e06, 0x4474a5f429e43,
+    0x4474c6e49d230, 0x4475101764594, 0x447572e8be15b, 0x44759b2b120d2,
+    0x447660cdc5860, 0x4476d5ebd1100, 0x4477267078fee, 0x44778598e25f0,
+    0x4477cecba9954, 0x44781ba76127d, 0x447843e9b51f4, 0x4478fe9197833,
+    0x447905e3783bd, 0x44793577acebe, 0x447ac0bd13dda, 0x447b56cb92a67,
+    0x447bfb7dd2e08, 0x447bff26c33cd, 0x447c3d5eb95e2, 0x447c56fd4be45,
+    0x447c5e4f2c9cf, 0x447c7f3f9fdbc, 0x447c8de3614d0, 0x447cc87267120,
+    0x447d44e25354a, 0x447de994938eb, 0x447e66047fd15, 0x447effbbeef67,
+    0x447f2ba7334a3, 0x447f419cd5741, 0x447fba63d15a6, 0x447fdb5444993,
+    0x4483eabeeb01f, 0x4483f962ac733, 0x448400b48d2bd, 0x4484d4fb0215f,
+    0x4484e39ec3873, 0x448580ff2308a, 0x4485bf371929f, 0x448772bed4132,
+    0x4487850b85e0b, 0x448905561bbd8, 0x44894e88e2f3c, 0x4489b5032d0c8,
+    0x4489b8ac1d68d, 0x448ac02fb75f5, 0x448b9bc80d021, 0x448e3d34cf5b9,
+    0x448e40ddbfb7e, 0x448e56d361e1c, 0x448e5a7c523e1, 0x448e98b4485f6,
+    0x448ee1e70f95a, 0x448ee938f04e4, 0x448f2b19d6cbe, 0x448f486159ae6,
+    0x44905adfc4b9d, 0x449091c5da228, 0x4490956eca7ed, 0x4490c502ff2ee,
+    0x4491a09b54d1a, 0x4491f871dd792, 0x44934cd12f023, 0x44936a18b1e4b,
+    0x4493800e540e9, 0x4493c5982ae88, 0x4493e2dfadcb0, 0x4494d46da597a,
+    0x4494ee0c381dd, 0x449512a59bb8f, 0x449533960ef7c, 0x44954239d0690,
+    0x449566d334042, 0x44957576f5756, 0x44958f1587fb9, 0x4495a16239c92,
+    0x4495d0f66e793, 0x4496217b16681, 0x44963ec2994a9, 0x4496f96a7bae8,
+    0x449721accfa5f, 0x4498551badf03, 0x4498760c212f0, 0x44995c9f47e6b,
+    0x4499ca6b72b81, 0x449ab4a789cc1, 0x449b3fbb377ff, 0x449bfa6319e3e,
+    0x449c01b4fa9c8, 0x449c1efc7d7f0, 0x449c4ae7c1d2c, 0x449c907198acb,
+    0x449cd2527f2a5, 0x449d4b197b10a, 0x449e85da3a138, 0x449fc09af9166,
+    0x44a1239e0c10b, 0x44a12e98dd25a, 0x44a135eabdde4, 0x44a140e58ef33,
+    0x44a1e1eeded0f, 0x44a286a11f0b0, 0x44a34149016ef, 0x44a495a852f80,
+    0x44a649300de13, 0x44a71d7682cb5, 0x44a72c1a443c9, 0x44a7420fe6667,
+    0x44a7b38501942, 0x44a7d0cc8476a, 0x44a80f047a97f, 0x44a84d3c70b94,
+    0x44a8548e5171e, 0x44a8beb18be6f, 0x44a8c6036c9f9, 0x44a9252bd5ffb,
+    0x44a9f2206a313, 0x44ab1e3d67c2d, 0x44ab517a8ccf3, 0x44ab7613f06a5,
+    0x44abb7f4d6e7f, 0x44abd53c59ca7, 0x44abd8e54a26c, 0x44ac3f5f943f8,
+    0x44acd9170364a, 0x44ad46e32e360, 0x44ae1ed2937c7, 0x44aea63d50d40,
+    0x44aec72dc412d, 0x44aed97a75e06, 0x44af6be0044ce, 0x44b02dd9c7697,
+    0x44b03182b7c5c, 0x44b043cf69935, 0x44b0fe774bf74, 0x44b16c4376c8a,
+    0x44b1c0710f13d, 0x44b205fae5edc, 0x44b36555088bc, 0x44b37b4aaab5a,
+    0x44b3b982a0d6f, 0x44b3fb6387549, 0x44b3ff0c77b0e, 0x44b4692fb225f,
+    0x44b5620f8aab3, 0x44b5a04780cc8, 0x44b5b9e61352b, 0x44b686daa7843,
+    0x44b6ba17cc909, 0x44b7ffd35ca86, 0x44b895e1db713, 0x44b8d7c2c1eed,
+    0x44b8f8b3352da, 0x44b8fc5c2589f, 0x44b920f589251, 0x44b9f53bfe0f3,
+    0x44bb1a071ae83, 0x44bb6a8bc2d71, 0x44bb842a555d4, 0x44bc044331fc3,
+    0x44bc8badef53c, 0x44bc92ffd00c6, 0x44bdc2c5bdfa5, 0x44beca4957f0d,
+    0x44bee03efa1ab, 0x44beeee2bb8bf, 0x44bf0c2a3e6e7, 0x44bf3bbe731e8,
+    0x44bfc32930761, 0x44c060898ff78, 0x44c0bc0908fb5, 0x44c0dcf97c3a2,
+    0x44c0e0a26c967, 0x44c13fcad5f69, 0x44c2f6fb813c1, 0x44c3268fb5ec2,
+    0x44c47e97f7d18, 0x44c4ddc06131a, 0x44c514a6769a5, 0x44c54f357c5f5,
+    0x44c5a36314aa8, 0x44c5a70c0506d, 0x44c5cf4e58fe4, 0x44c5e19b0acbd,
+    0x44c67efb6a4d4, 0x44c68d9f2bbe8, 0x44c6aae6aea10, 0x44c6da7ae3511,
+    0x44c7066627a4d, 0x44c7693781614, 0x44c7cfb1cb7a0, 0x44c8c4e8b3a2f,
+    0x44c90e1b7ad93, 0x44c9b676ab6f9, 0x44c9cc6c4d997, 0x44ca9960e1caf,
+    0x44cac8f5167b0, 0x44caf1376a727, 0x44cb0e7eed54f, 0x44cba48d6c1dc,
+    0x44cbd078b0718, 0x44cc5b8c5e256, 0x44cc6dd90ff2f, 0x44cc83ceb21cd,
+    0x44cd630ff81be, 0x44cdb73d90671, 0x44ce549defe88, 0x44cf00a210db3,
+    0x44cf28e464d2a, 0x44cfdfe356da4, 0x44d0b7d2bc20b, 0x44d2ca82e06a0,
+    0x44d2e7ca634c8, 0x44d33fa0ebf40, 0x44d37a2ff1b90, 0x44d3bc10d836a,
+    0x44d490574d20c, 0x44d4cae652e5c, 0x44d4d238339e6, 0x44d4dd3304b35,
+    0x44d5091e49071, 0x44d56befa2c38, 0x44d5e4b69ea9d, 0x44d5ec087f627,
+    0x44d5faac40d3b, 0x44d6c3f7e4a8e, 0x44d6e4e857e7b, 0x44d73cbee08f3,
+    0x44d911370eb73, 0x44d9fb7325cb3, 0x44da659660404, 0x44dacfb99ab55,
+    0x44dad3628b11a, 0x44db328af471c, 0x44dc958e076c1, 0x44dce269befea,
+    0x44dced6490139, 0x44dd194fd4675, 0x44dd20a1b51ff, 0x44dd244aa57c4,
+    0x44dd48e409176, 0x44dd95bfc0a9f, 0x44ddc8fce5b65, 0x44ddd7a0a7279,
+    0x44ddffe2fb1f0, 0x44de5f0b647f2, 0x44defc6bc4009, 0x44dfb36ab6083,
+    0x44dfd45b29470, 0x44e03e7e63bc1, 0x44e0a8a19e312, 0x44e0ac4a8e8d7,
+    0x44e0b7455fa26, 0x44e0f92646200, 0x44e0fccf367c5, 0x44e1007826d8a,
+    0x44e166f270f16, 0x44e1bec8f998e, 0x44e23ee1d637d, 0x44e300db99546,
+    0x44e34db750e6f, 0x44e396ea181d3, 0x44e3acdfba471, 0x44e3e3c5cfafc,
+    0x44e3f9bb71d9a, 0x44e43f4548b39, 0x44e4553aeadd7, 0x44e4d553c77c6,
+    0x44e5a99a3c668, 0x44e60170c50e0, 0x44e6559e5d593, 0x44e6b4c6c6b95,
+    0x44e6b86fb715a, 0x44e6dd091ab0c, 0x44e7ada69f3e9, 0x44e81077f8fb0,
+    0x44e81b72ca0ff, 0x44e881ed1428b, 0x44e8fab4100f0, 0x44e99f6650491,
+    0x44e9dd9e466a6, 0x44e9f393e8944, 0x44eb4b9c2a79a, 0x44ebc80c16bc4,
+    0x44ec3d2a22464, 0x44ec4bcde3b78, 0x44ec61c385e16, 0x44ecc83dcffa2,
+    0x44ed1519878cb, 0x44ed77eae1492, 0x44ed918973cf5, 0x44eda3d6259ce,
+    0x44ede5b70c1a8, 0x44ee18f43126e, 0x44ef53b4f029c, 0x44ef834924d9d,
+    0x44ef8a9b05927, 0x44efd024dc6c6, 0x44efed6c5f4ee, 0x44f08723ce740,
+    0x44f08e75af2ca, 0x44f0c1b2d4390, 0x44f1db831ffd1, 0x44f1f178c226f,
+    0x44f24cf83b2ac, 0x44f2753a8f223, 0x44f299d3f2bd5, 0x44f2b71b759fd,
+    0x44f3129aeea3a, 0x44f3756c48601, 0x44f392b3cb429, 0x44f3a8a96d6c7,
+    0x44f3f58524ff0, 0x44f48441c30f3, 0x44f4eabc0d27f, 0x44f5045a9fae2,
+    0x44f55c312855a, 0x44f58bc55d05b, 0x44f5c9fd53270, 0x44f630779d3fc,
+    0x44f6600bd1efd, 0x44f6b4396a3b0, 0x44f6e7768f476, 0x44f6f9c34114f,
+    0x44f758ebaa751, 0x44f89003791ba, 0x44f8b0f3ec5a7, 0x44f8bbeebd6f6,
+    0x44f8d1e45f994, 0x44f93c079a0e5, 0x44f9ef5d9bb9a, 0x44fa4e860519c,
+    0x44fb0cd6d7da0, 0x44fb6fa831967, 0x44fbc02cd9855, 0x44fbefc10e356,
+    0x44fc981c3ecbc, 0x44fca6c0003d0, 0x44fd7b0675272, 0x44fd89aa36986,
+    0x44fdc7e22cb9b, 0x44fe35ae578b1, 0x44fe4f4cea114, 0x44fea72372b8c,
+    0x44ff02a2ebbc9, 0x44ff482cc2968, 0x44ff8664b8b7d, 0x44ffb24ffd0b9,
+    0x450098e323c34, 0x4500d37229884, 0x4501279fc1d37, 0x45017bcd5a1ea,
+    0x4501e99984f00, 0x4501f0eb65a8a, 0x4502710442479, 0x450286f9e4717,
+    0x4502b2e528c53, 0x45035b40595b9, 0x45047167b4c35, 0x4504d4390e7fc,
+    0x4504e2dccff10, 0x4505002452d38, 0x450503cd432fd, 0x4505370a683c3,
+    0x4505803d2f727, 0x450775a5d0d94, 0x4507e371fbaaa, 0x45083b4884522,
+    0x4508429a650ac, 0x4508931f0cf9a, 0x450a51a198f7c, 0x450af9fcc98e2,
+    0x450b25e80de1e, 0x450b975d290f9, 0x450be08ff045d, 0x450c55adfbcfd,
+    0x450c903d0194d, 0x450c9b37d2a9c, 0x450c9ee0c3061, 0x450d29f470b9f,
+    0x450d8573e9bdc, 0x450dd24fa1505, 0x450dfa91f547c, 0x450e685e20192,
+    0x450ec3dd991cf, 0x450f05be7f9a9, 0x450f8d293cf22, 0x450fb56b90e99,
+    0x450fb9148145e, 0x450fe8a8b5f5f, 0x4510aaa279128, 0x4510f77e30a51,
+    0x45113d08077f0, 0x45118d8caf6de, 0x4511ae7d22acb, 0x4511c472c4d69,
+    0x451209fc9bb08, 0x4512d3483f85b, 0x4512da9a203e5, 0x45130dd7454ab,
+    0x45132023f7184, 0x4513aee095287, 0x4513c12d46f60, 0x4513c4d637525,
+    0x4513f0c17ba61, 0x451427a7910ec, 0x45143d9d3338a, 0x45148a78eacb3,
+    0x4514d002c1a52, 0x451532d41b619, 0x45156d6321269, 0x45158358c3507,
+    0x4515f124ee21d, 0x451641a99610b, 0x45167fe18c320, 0x45168adc5d46f,
+    0x4516df09f5922, 0x45171d41ebb37, 0x45175b79e1d4c, 0x451783bc35cc3,
+    0x4517abfe89c3a, 0x4518420d088c7, 0x45191da55e2f3, 0x451966d825657,
+    0x4519e34811a81, 0x451a6360ee470, 0x451a6e5bbf5bf, 0x451a80a871298,
+    0x451adc27ea2d5, 0x451b42a234461, 0x451b6ae4883d8, 0x451c049bf762a,
+    0x451ce0344d056, 0x451cf9d2df8b9, 0x451d938a4eb0b, 0x451dad28e136e,
+    0x451db823b24bd, 0x451f386e4828a, 0x451f72fd4deda, 0x451f81a10f5ee,
+    0x451fef6d3a304, 0x451ffa680b453, 0x4520941f7a6a5, 0x45217ab2a1220,
+    0x452190a8434be, 0x4521adefc62e6, 0x45229482ece61, 0x4522b1ca6fc89,
+    0x452310f2d928b, 0x45232e3a5c0b3, 0x45235a25a05ef, 0x452389b9d50f0,
+    0x4523bcf6fa1b6, 0x4523cf43abe8f, 0x4523f0341f27c, 0x45243966e65e0,
+    0x45244f5c8887e, 0x4524704cfbc6b, 0x4524779edc7f5, 0x452518a82c5d1,
+    0x452561daf3935, 0x4526327878212, 0x45268df7f124f, 0x4526fbc41bf65,
+    0x45270a67dd679, 0x4527365321bb5, 0x4527ab712d455, 0x45282b8a09e44,
+    0x45283dd6bbb1d, 0x4528d3e53a7aa, 0x4528e631ec483, 0x4528f12cbd5d2,
+    0x4529196f11549, 0x452969f3b9437, 0x452aa10b87ea0, 0x452ac94ddbe17,
+    0x452aede73f7c9, 0x452c374bbff0b, 0x452c807e8726f, 0x452ceaa1c19c0,
+    0x452d302b9875f, 0x452da8f2945c4, 0x452dc9e3079b1, 0x452fb0a7e790a,
+    0x45308fe92d8fb, 0x4531349b6dc9c, 0x4531852015b8a, 0x4531e4487f18c,
+    0x4532b4e603a69, 0x4532e47a3856a, 0x4533265b1ed44, 0x4534e134ba761,
+    0x453531b96264f, 0x453590e1cbc51, 0x4535a6d76deef, 0x4535c07600752,
+    0x453623475a319, 0x45362a993aea3, 0x453689c1a44a5, 0x4537ae8cc1235,
+    0x4537f41697fd4, 0x453818affb986, 0x45390a3df3650, 0x45392033958ee,
+    0x45398304ef4b5, 0x4539b29923fb6, 0x4539bd93f5105, 0x453a783bd7744,
+    0x453b32e3b9d83, 0x453babaab5be8, 0x453bdb3eea6e9, 0x453c69fb887ec,
+    0x453d284c5b3f0, 0x453e87a67ddd0, 0x453ed0d945134, 0x453ee6cee73d2,
+    0x453eee20c7f5c, 0x453fbb155c274, 0x453fc9b91d988, 0x454067197d19f,
+    0x45408460fffc7, 0x4540fd27fbe2c, 0x454121c15f7de, 0x4541256a4fda3,
+    0x454142b1d2bcb, 0x4541674b3657d, 0x45419e314bc08, 0x4541eb0d03531,
+    0x45427d7291bf9, 0x4542e043eb7c0, 0x45439399ed275, 0x45439aebcddff,
+    0x4543e7c785728, 0x4543f66b46e3c, 0x4545521c79257, 0x45455d174a3a6,
+    0x45457a5ecd1ce, 0x4545e82af7ee4, 0x4546a2d2da523, 0x45472694a74d7,
+    0x45477e6b2ff4f, 0x4547aa567448b, 0x4547f3893b7ef, 0x4547fadb1c379,
+    0x45484b5fc4267, 0x4548cb78a0c56, 0x4548e16e42ef4, 0x4548ec6914043,
+    0x454a77ae7af5f, 0x454bbd6a0b0dc, 0x454caef802da6, 0x454d5afc23cd1,
+    0x454dab80cbbbf, 0x454e6d7a8ed88, 0x454ea0b7b3e4e, 0x454f5064c533e,
+    0x454fb3361ef05, 0x454fd426922f2, 0x45502fa60b32f, 0x45509620554bb,
+    0x45513e7b85e21, 0x455333e42748e, 0x4553378d17a53, 0x4553acab232f3,
+    0x4553b054138b8, 0x455458af4421e, 0x4554884378d1f, 0x45550fae36298,
+    0x4555309ea9685, 0x4555812351573, 0x45560fdfef676, 0x455646c604d01,
+    0x45568c4fdbaa0, 0x4556a9975e8c8, 0x4556b4922fa17, 0x45572d592b87c,
+    0x4557643f40f07, 0x45583885b5da9, 0x455860c809d20, 0x4558b89e92798,
+    0x455909233a686, 0x4559684ba3c88, 0x45598ce50763a, 0x455a6fcf3dbf0,
+    0x455a9bba8212c, 0x455ad9f278341, 0x455bc42e8f481, 0x455c3cf58b2e6,
+    0x455c7b2d814fb, 0x455d865a0ba28, 0x455db2454ff64, 0x455f18f1534ce,
+    0x455fe23cf7221, 0x455ffbdb89a84, 0x45600e283b75d, 0x456027c6cdfc0,
+    0x45603dbc7025e, 0x45604c6031972, 0x4560e26eb05ff, 0x4561578cbbe9f,
+    0x4561ba5e15a66, 0x4561db4e88e53, 0x4562282a4077c, 0x4562edccf3f0a,
+    0x456341fa8c3bd, 0x4563b71897c5d, 0x45641d92e1de9, 0x4564d83ac4428,
+    0x4565425dfeb79, 0x45655101c028d, 0x456571f23367a, 0x4565843ee5353,
+    0x4565a52f58740, 0x456625483512f, 0x4566bb56b3dbc, 0x45672922dead2,
+    0x45673b6f907ab, 0x4567dc78e0587, 0x45686ede6ec4f, 0x45688fcee203c,
+    0x4569382a129a2, 0x45693f7bf352c, 0x4569a24d4d0f3, 0x4569e7d723e92,
+    0x456a609e1fcf7, 0x456a7a3cb255a, 0x456ace6a4aa0d, 0x456afa558ef49,
+    0x456b3c3675723, 0x456b7e175befd, 0x456c525dd0d9f, 0x456d1ba974af2,
+    0x456d1f52650b7, 0x456d8d1e8fdcd, 0x456db560e3d44, 0x456dd2a866b6c,
+    0x456dec46f93cf, 0x456e1bdb2ded0, 0x456e4b6f629d1, 0x456e89a758be6,
+    0x456e9f9cfae84, 0x456eb93b8d6e7, 0x456ee8cfc21e8, 0x456f3cfd5a69b,
+    0x45701895b00c7, 0x45701fe790c51, 0x457077be196c9, 0x4570b5f60f8de,
+    0x45713d60cce57, 0x4571a3db16fe3, 0x45728a6e3db5e, 0x4572af07a1510,
+    0x4572ff8c493fe, 0x4573785345263, 0x4573c1860c5c7, 0x4576ac2595ec3,
+    0x4578dc1d3d180, 0x4579dc4ef655e, 0x4579ee9ba8237, 0x457b728f2e5c9,
+    0x457bad1e34219, 0x457be05b592df, 0x457c04f4bcc91, 0x457c3488f1792,
+    0x457c90086a7cf, 0x457ce08d126bd, 0x457d7a448190f, 0x457db4d38755f,
+    0x457e6bd2795d9, 0x457e97bdbdb15, 0x457ebc57214c7, 0x457ed5f5b3d2a,
+    0x457f4ebcafb8f, 0x457fade519191, 0x457fb536f9d1b, 0x457fe8741ede1,
+    0x4580897d6ebbd, 0x4580aa6de1faa, 0x4580ae16d256f, 0x4580b1bfc2b34,
+    0x4580b568b30f9, 0x4580bcba93c83, 0x45814f202234b, 0x45817eb456e4c,
+    0x4581a34dba7fe, 0x45827b3d1fc65, 0x4582d313a86dd, 0x45834bdaa4542,
+    0x4583c4a1a03a7, 0x4583f08ce48e3, 0x45840a2b77146, 0x4584117d57cd0,
+    0x45848a4453b35, 0x4584c87c49d4a, 0x4584d7200b45e, 0x45851ca9e21fd,
+    0x4585a06baf1b1, 0x4585f84237c29, 0x45863dcc0e9c8, 0x4586451def552,
+    0x458774e3dd431, 0x45877c35bdfbb, 0x4588afa49c45f, 0x4589719e5f628,
+    0x458a6e2728441, 0x458abb02dfd6a, 0x458b58633f581, 0x458b92f2451d1,
+    0x458bdc250c535, 0x458be71fdd684, 0x458c429f566c1, 0x458c9e1ecf6fe,
+    0x458cbf0f42aeb, 0x458cdc56c5913, 0x458d21e09c6b2, 0x458d5c6fa2302,
+    0x458d6b1363a16, 0x458d760e34b65, 0x458d8facc73c8, 0x458e345f07769,
+    0x458eb477e4158, 0x458ee06328694, 0x458f6424f5648, 0x4590311989960,
+    0x4590fe0e1dc78, 0x45914740e4fdc, 0x45914ae9d55a1, 0x45919073ac340,
+    0x45919b6e7d48f, 0x45926863117a7, 0x45926fb4f2331, 0x45930d1551b48,
+    0x459347a457798, 0x459439324f462, 0x45946c6f74528, 0x4594cb97ddb2a,
+    0x45957eeddf5df, 0x4595b22b046a5, 0x4595ff06bbfce, 0x45962af20050a,
+    0x4597152e1764a, 0x4597b63767426, 0x4597cc2d096c4, 0x4597e222ab962,
+    0x4598698d68edb, 0x4598c8b5d24dd, 0x4598e9a6458ca, 0x459a57a4299be,
+    0x459b4cdb11c4d, 0x459ba108aa100, 0x459bac037b24f, 0x459c58079c17a,
+    0x459c754f1efa2, 0x459d6a8607231, 0x459d87cd8a059, 0x459db00fddfd0,
+    0x459eb041973ae, 0x459efd1d4ecd7, 0x459f2cb1837d8, 0x459f514ae718a,
+    0x459fcdbad35b4, 0x45a0d8e75dae1, 0x45a0eb340f7ba, 0x45a17647bd2f8,
+    0x45a3262687bc6, 0x45a3436e0a9ee, 0x45a40567cdbb7, 0x45a434fc026b8,
+    0x45a46be217d43, 0x45a57e6082dfa, 0x45a5b19da7ec0, 0x45a6355f74e74,
+    0x45a685e41cd62, 0x45a6b1cf6129e, 0x45a6c41c12f77, 0x45a78d67b6cca,
+    0x45a7aaaf39af2, 0x45a7bcfbeb7cb, 0x45a809d7a30f4, 0x45a8530a6a458,
+    0x45a8ecc1d96aa, 0x45a98679488fc, 0x45aafbc90d57a, 0x45ab4ff6a5a2d,
+    0x45ab7be1e9f69, 0x45ab8e2e9bc42, 0x45aba07b4d91b, 0x45abdeb343b30,
+    0x45abe25c340f5, 0x45ac3689cc5a8, 0x45ac3ddbad132, 0x45ac870e74496,
+    0x45ac9d0416734, 0x45adabd991226, 0x45ae24a08d08b, 0x45ae28497d650,
+    0x45ae6dd3543ef, 0x45aedf486f6ca, 0x45aee2f15fc8f, 0x45aeedec30dde,
+    0x45afc5db96245, 0x45afe6cc09632, 0x45b128dea91ea, 0x45b1d88bba6da,
+    0x45b1fd251e08c, 0x45b2dc666407d, 0x45b2eeb315d56, 0x45b2f25c0631b,
+    0x45b47da16d237, 0x45b4bf8253a11, 0x45b4c32b43fd6, 0x45b4ce2615125,
+    0x45b51b01cca4e, 0x45b59b1aa943d, 0x45b664664d190, 0x45b693fa81c91,
+    0x45b7ec02c3ae7, 0x45b7faa6851fb, 0x45b87e68521af, 0x45b8d295ea662,
+    0x45b9bcd2017a2, 0x45b9cb75c2eb6, 0x45bb4f6949248, 0x45bbf41b895e9,
+    0x45bcfb9f23551, 0x45bd23e1774c8, 0x45bd362e291a1, 0x45bd98ff82d68,
+    0x45bda3fa53eb7, 0x45be8e366aff7, 0x45beba21af533, 0x45bf9d0be5ae9,
+    0x45bfaf58977c2, 0x45bfc1a54949b, 0x45bfc54e39a60, 0x45c07c4d2bada,
+    0x45c0839f0c664, 0x45c120ff6be7b, 0x45c1927487156, 0x45c33eaa6145f,
+    0x45c36343c4e11, 0x45c3cd66ff562, 0x45c43b332a278, 0x45c53f0dd3c1b,
+    0x45c5550375eb9, 0x45c55c5556a43, 0x45c74a6c17526, 0x45c75566e8675,
+    0x45c7fdc218fdb, 0x45c80c65da6ef, 0x45c8768914e40, 0x45c8b4c10b055,
+    0x45c8bfbbdc1a4, 0x45c8c364cc769, 0x45c934d9e7a44, 0x45c94acf89ce2,
+    0x45c9940251046, 0x45c9a2a61275a, 0x45ca734397037, 0x45cac3c83ef25,
+    0x45cb38e64a7c5, 0x45cb478a0bed9, 0x45cc05dadeadd, 0x45cc6503480df,
+    0x45cc90ee8c61b, 0x45ccbcd9d0b57, 0x45cd44448e0d0, 0x45cdf3f19f5c0,
+    0x45ce6566ba89b, 0x45ce86572dc88, 0x45cf56f4b2565, 0x45cff0ac217b7,
+    0x45d05e784c4cd, 0x45d0b9f7c550a, 0x45d0f486cb15a, 0x45d132bec136f,
+    0x45d35f0d78067, 0x45d395f38d6f2, 0x45d424b02b7f5, 0x45d462e821a0a,
+    0x45d592ae0f8e9, 0x45d628bc8e576, 0x45d69688b928c, 0x45d69dda99e16,
+    0x45d6a8d56af65, 0x45d780c4d03cc, 0x45d79e0c531f4, 0x45d7dfed399ce,
+    0x45d83b6cb2a0b, 0x45d84db9646e4, 0x45d8bb858f3fa, 0x45d9553cfe64c,
+    0x45d97d7f525c3, 0x45d9971de4e26, 0x45d9c6b219927, 0x45d9f6464e428,
+    0x45dad58794419, 0x45dae42b55b2d, 0x45db34affda1b, 0x45db4e4e9027e,
+    0x45dc7a6b8db98, 0x45dd64a7a4cd8, 0x45de319c38ff0, 0x45de59de8cf67,
+    0x45df73aed8ba8, 0x45dfb938af947, 0x45dfc7dc7105b, 0x45e056990f15e,
+    0x45e0f7a25ef3a, 0x45e1273693a3b, 0x45e156cac853c, 0x45e1d6e3a4f2b,
+    0x45e1de3585ab5, 0x45e2cfc37d77f, 0x45e2f805d16f6, 0x45e2ff57b2280,
+    0x45e32eebe6d81, 0x45e3bda884e84, 0x45e3daf007cac, 0x45e55b3a9da79,
+    0x45e5c1b4e7c05, 0x45e6507185d08, 0x45e6a49f1e1bb, 0x45e6a8480e780,
+    0x45e7335bbc2be, 0x45e74cfa4eb21, 0x45e845da27375, 0x45ea7228de06d,
+    0x45eb3422a1236, 0x45eb3b7481dc0, 0x45ec58edbdfc6, 0x45ec603f9eb50,
+    0x45ed173e90bca, 0x45ed4a7bb5c90, 0x45ed88b3abea5, 0x45ed9ea94e143,
+    0x45edb0f5ffe1c, 0x45edd1e673209, 0x45ee435b8e4e4, 0x45ef5d2bda125,
+    0x45ef9068ff1eb, 0x45efd5f2d5f8a, 0x45f04767f1265, 0x45f09443a8b8e,
+    0x45f2c43b4fe4b, 0x45f32ab599fd7, 0x45f3e55d7c616, 0x45f50a28993a6,
+    0x45f5c1278b420, 0x45f5de6f0e248, 0x45f639ee87285, 0x45f6747d8ced5,
+    0x45f69cbfe0e4c, 0x45f7e6246158e, 0x45f8ba6ad6430, 0x45f8e9ff0af31,
+    0x45f92bdff170b, 0x45f9457e83f6e, 0x45fa49592d911, 0x45fb1d9fa27b3,
+    0x45fb5f8088f8d, 0x45fbc251e2b54, 0x45fc376fee3f4, 0x45fc5c0951da6,
+    0x45fc6e5603a7f, 0x45fe3b7c51175, 0x45fe4dc902e4e, 0x45fe7d5d3794f,
+    0x45fed18acfe02, 0x45ff30b339404, 0x45ff8137e12f2, 0x45ffd1bc891e0,
+    0x46001aef50544, 0x460046da94a80, 0x46004a8385045, 0x4600a25a0dabd,
+    0x4600d59732b83, 0x4601052b67684, 0x46025d33a94da, 0x460359bc722f3,
+    0x4603a69829c1c, 0x4603bc8dcbeba, 0x4604947d31321, 0x46056c6c96788,
+    0x46059c00cb289, 0x460639612aaa0, 0x46066c9e4fb66, 0x4606863ce23c9,
+    0x4606cf6fa972d, 0x4606e1bc5b406, 0x4607bd54b0e32, 0x4607e93ff536e,
+    0x46092400b439c, 0x4609d3adc588c, 0x460b0ac5942f5, 0x460b504f6b094,
+    0x460c20ecef971, 0x460c3339a164a, 0x460c5b7bf55c1, 0x460ca105cc360,
+    0x460d804712351, 0x460e2c4b3327c, 0x460e548d871f3, 0x460f7958a3f83,
+    0x460fc6345b8ac, 0x46109e23c0d13, 0x4610dc5bb6f28, 0x46119aac89b2c,
+    0x4611bb9cfcf19, 0x4611c697ce068, 0x4613b8577f110, 0x4613e442c364c,
+    0x4613e7ebb3c11, 0x46146f567118a, 0x46149046e4577, 0x46149eeaa5c8b,
+    0x4614bfdb19078, 0x46151408b152b, 0x4615648d59419, 0x46161f353ba58,
+    0x4616611622232, 0x4616c3e77bdf9, 0x46173cae77c5e, 0x461752a419efc,
+    0x46185a27b3e64, 0x461909d4c5354, 0x46197ef2d0bf4, 0x461994e872e92,
+    0x4619d6c95966c, 0x4619f067ebecf, 0x461b277fba938, 0x461bc13729b8a,
+    0x461c205f9318c, 0x461cab7340cca, 0x461e330fb7621, 0x461e4905598bf,
+    0x461f2bef8fe75, 0x461f62d5a5500, 0x461f7522571d9, 0x4620ac3a25c42,
+    0x46211daf40f1d, 0x462150ec65fe3, 0x46218f245c1f8, 0x4621ac6bdf020,
+    0x4621b014cf5e5, 0x4621cd5c5240d, 0x462249cc3e837, 0x4622586ffff4b,
+    0x4622796073338, 0x4622d136fbdb0, 0x4622dc31cceff, 0x462300cb308b1,
+    0x46233b5a36501, 0x4623a92661217, 0x462458d372707, 0x4624677733e1b,
+    0x4624ca488d9e2, 0x46259e8f02884, 0x4627b4e8172de, 0x4627e47c4bddf,
+    0x4627ef771cf2e, 0x46291099496f9, 0x46296fc1b2cfb, 0x462990b2260e8,
+    0x4629efda8f6ea, 0x462a90e3df4c6, 0x462ae8ba67f3e, 0x462b5a2f83219,
+    0x462b9115988a4, 0x462bc452bd96a, 0x462c9feb13396, 0x462cae8ed4aaa,
+    0x462cbd32961be, 0x462d5e3be5f9a, 0x462de5a6a3513, 0x462e362b4b401,
+    0x462e780c31bdb, 0x462eb64427df0, 0x462eff76ef154, 0x462f3daee5369,
+    0x462faf2400644, 0x462fb2ccf0c09, 0x462fe60a15ccf, 0x463095b7271bf,
+    0x4630b6a79a5ac, 0x4630bdf97b136, 0x4630d7980d999, 0x4630db40fdf5e,
+    0x46317c4a4dd3a, 0x46318e96ffa13, 0x4631a0e3b16ec, 0x4631a48ca1cb1,
+    0x4632668664e7a, 0x4632fc94e3b07, 0x4633288028043, 0x46338efa721cf,
+    0x4633cd32683e4, 0x463580ba23277, 0x4635dc399c2b4, 0x46365157a7b54,
+    0x46367999fbacb, 0x4636f9b2d84ba, 0x46373441de10a, 0x46389744f10af,
+    0x46391eafae628, 0x463929aa7f777, 0x46394e43e3129, 0x46397686370a0,
+    0x4639bfb8fe404, 0x4639e7fb5237b, 0x463b4ea7558e5, 0x463bd9bb03423,
+    0x463c896814913, 0x463cb8fc49414, 0x463d615779d7a, 0x463e3cefcf7a6,
+    0x463e8d7477694, 0x463eae64eaa81, 0x463ebd08ac195, 0x463ee8f3f06d1,
+    0x463fecce9a074, 0x4640693e8649e, 0x4640b9c32e38c, 0x4640f0a943a17,
+    0x46411c9487f53, 0x4641328a2a1f1, 0x464265f908695, 0x4642af2bcf9f9,
+    0x4642bdcf9110d, 0x4642c52171c97, 0x4642ffb0778e7, 0x4643d3f6ec789,
+    0x46445f0a9a2c7, 0x464562e543c6a, 0x4645d45a5ef45, 0x4646d835088e8,
+    0x464704204ce24, 0x46470f1b1df73, 0x46477ce748c89, 0x46486acc5038e,
+    0x46494664a5dba, 0x464988458c594, 0x4649b087e050b, 0x4649bb82b165a,
+    0x464a5c8c01436, 0x464c17659ce53, 0x464c26095e567, 0x464ca9cb2b51b,
+    0x464d52265be81, 0x464d5d212cfd0, 0x464dc74467721, 0x464e7e435979b,
+    0x464e9438fba39, 0x464ecec801689, 0x464f17fac89ed, 0x46505db658b6a,
+    0x46517b2f94d70, 0x4651c80b4c699, 0x46535e4b84704, 0x4653a3d55b4a3,
+    0x4653dabb70b2e, 0x46543d8cca6f5, 0x46545e7d3dae2, 0x465465cf1e66c,
+    0x4654b2aad5f95, 0x46550e2a4efd2, 0x465569a9c800f, 0x465570fba8b99,
+    0x4655b2dc8f373, 0x4656a46a8703d, 0x4658b71aab4d2, 0x4659213de5c23,
+    0x46592fe1a7337, 0x46596e199d54c, 0x465992b300efe, 0x4659c5f025fc4,
+    0x465ac621df3a2, 0x465bc9fc88d45, 0x465d5c93d07eb, 0x465d76326304e,
+    0x465e25df7453e, 0x465e4a78d7ef0, 0x465f644923b31, 0x465f7de7b6394,
+    0x465fa62a0a30b, 0x465febb3e10aa, 0x4660d5eff81ea, 0x4661b5313e1db,
+    0x4662772b013a4, 0x4663566c47395, 0x46635a153795a, 0x4663b1ebc03d2,
+    0x4663c08f81ae6, 0x4663c7e162670, 0x46641c0efab23, 0x466440a85e4d5,
+    0x46647ee0546ea, 0x4664f3fe5ff8a, 0x4665cf96b59b6, 0x46666cf7151cd,
+    0x4666b280ebf6c, 0x4666dac33fee3, 0x46671ca4266bd, 0x466765d6eda21,
+    0x46685eb6c6275, 0x4668785558ad8, 0x4669a0c965e2d, 0x4669abc436f7c,
+    0x466a62c328ff6, 0x466a750fdaccf, 0x466aabf5f035a, 0x466b16192aaab,
+    0x466cf58c29e7a, 0x466d794df6e2e, 0x466e28fb0831e, 0x466e2ca3f88e3,
+    0x466e931e42a6f, 0x466fa1f3bd561, 0x466fe02bb3776, 0x4670604490165,
+    0x46707d8c12f8d, 0x4670c315e9d2c, 0x4670d90b8bfca, 0x4672fa5f71b73,
+    0x4673c00225301, 0x4674352030ba1, 0x467443c3f22b5, 0x46750966a5a43,
+    0x46752305382a6, 0x4675ae18e5de4, 0x4675c40e88082, 0x46761be510afa,
+    0x4676407e744ac, 0x4676776489b37, 0x4676c7e931a25, 0x4676f77d66526,
+    0x46771fbfba49d, 0x46775a4ec00ed, 0x4677779642f15, 0x467789e2f4bee,
+    0x4677c81aeae03, 0x4677e90b5e1f0, 0x4678a00a5026a, 0x467a2ef8a774b,
+    0x467ad753d80b1, 0x467b242f8f9da, 0x467b994d9b27a, 0x467bb2ec2dadd,
+    0x467bba3e0e667, 0x467cea03fc546, 0x467d3e31949f9, 0x467dafa6afcd4,
+    0x467e6a4e92313, 0x467e78f253a27, 0x467e87961513b, 0x467e99e2c6e14,
+    0x467ef5623fe51, 0x467f66d75b12c, 0x467f6e293bcb6, 0x467fd0fa9587d,
+    0x46812559e710e, 0x468158970c1d4, 0x468196cf023e9, 0x4681a91bb40c2,
+    0x4681d15e08039, 0x4681f24e7b426, 0x46825c71b5b77, 0x4683ef08fd61d,
+    0x468408a78fe80, 0x4684592c37d6e, 0x46849eb60eb0d, 0x4684f68c97585,
+    0x468534c48d79a, 0x468560afd1cd6, 0x468593ecf6d9c, 0x4685e81a8f24f,
+    0x46867e290dedc, 0x4686c009f46b6, 0x4686ceadb5dca, 0x4686e84c4862d,
+    0x46870ce5abfdf, 0x4687aa460b7f6, 0x468885de61222, 0x468952d2f553a,
+    0x4689cb99f139f, 0x468ac822ba1b8, 0x468b692c09f94, 0x468ba3bb0fbe4,
+    0x468bf43fb7ad2, 0x468c068c697ab, 0x468ca3ecc8fc2, 0x468d5e94ab601,
+    0x468df0fa39cc9, 0x468df4a32a28e, 0x468e8ab1a8f1b, 0x468e920389aa5,
+    0x468eb69ced457, 0x468ed03b7fcba, 0x468f072195345, 0x468f0e7375ecf,
+    0x468faf7cc5cab, 0x4690493434efd, 0x469099b8dcdeb, 0x469178fa22ddc,
+    0x46925be459392, 0x46927582ebbf5, 0x4692d854457bc, 0x4692dfa626346,
+    0x46938baa47271, 0x4693a548d9ad4, 0x469437ae6819c, 0x4694bf1925715,
+    0x4694f9a82b365, 0x469504a2fc4b4, 0x46956b1d46640, 0x46958fb6a9ff2,
+    0x4695935f9a5b7, 0x469788c83bc24, 0x4697e09ec469c, 0x46985213df977,
+    0x469855bccff3c, 0x4698cadadb7dc, 0x46992dac353a3, 0x4699c763a45f5,
+    0x469ac3ec6d40e, 0x469b1bc2f5e86, 0x469bd66ad84c5, 0x469c3ce522651,
+    0x469ccba1c0754, 0x469d5e074ee1c, 0x469dcf7c6a0f7, 0x469df415cdaa9,
+    0x469e61e1f87bf, 0x469e867b5c171, 0x469ec10a61dc1, 0x469ef44786e87,
+    0x469fb6414a050, 0x46a006c5f1f3e, 0x46a1627724359, 0x46a17fbea7181,
+    0x46a1c8f16e4e5, 0x46a23e0f79d85, 0x46a257ae0c5e8, 0x46a36683870da,
+    0x46a39d699c765, 0x46a42fcf2ae2d, 0x46a51db432532, 0x46a550f1575f8,
+    0x46a5967b2e397, 0x46a5cd6143a22, 0x46a61de5eb910, 0x46a68bb216626,
+    0x46a6dfdfaead9, 0x46a75ff88b4c8, 0x46a772453d1a1, 0x46a7bf20f4aca,
+    0x46a829442f21b, 0x46a8811ab7c93, 0x46a9d922f9ae9, 0x46aa2255c0e4d,
+    0x46aa6f3178776, 0x46ac01c8c021c, 0x46acb175d170c, 0x46ad109e3ad0e,
+    0x46ad35379e6c0, 0x46ad562811aad, 0x46ad6122e2bfc, 0x46ad8d0e27138,
+    0x46adcb461d34d, 0x46ae73a14dcb3, 0x46aecf20c6cf0, 0x46aeec6849b18,
+    0x46b048197bf33, 0x46b0914c43297, 0x46b094f53385c, 0x46b0989e23e21,
+    0x46b0c4896835d, 0x46b0cf84394ac, 0x46b1534606460, 0x46b186832b526,
+    0x46b270bf42666, 0x46b29901965dd, 0x46b30e1fa1e7d, 0x46b348aea7acd,
+    0x46b35ea449d6b, 0x46b3f109d8433, 0x46b3f85bb8fbd, 0x46b4996508d99,
+    0x46b4cca22de5f, 0x46b52821a6e9c, 0x46b61606ae5a1, 0x46b636f72198e,
+    0x46b6a11a5c0df, 0x46b6bab8ee942, 0x46b6d0ae90be0, 0x46b6ea4d23443,
+    0x46b6edf613a08, 0x46b750c76d5cf, 0x46b7840492695, 0x46b7dbdb1b10d,
+    0x46b812c130798, 0x46b8ee59861c4, 0x46b97d16242c7, 0x46b9bb4e1a4dc,
+    0x46bad51e6611d, 0x46bb603213c5b, 0x46bb8c1d58197, 0x46bbd8f90fac0,
+    0x46bbeb45c1799, 0x46bc0fdf2514b, 0x46bc640cbd5fe, 0x46bc9749e26c4,
+    0x46be7313f14ce, 0x46bf2dbbd3b0d, 0x46c0353f6da75, 0x46c0403a3ebc4,
+    0x46c0ae06698da, 0x46c0c7a4fc13d, 0x46c0d9f1ade16, 0x46c1406bf7fa2,
+    0x46c19beb70fdf, 0x46c1c42dc4f56, 0x46c1cf28960a5, 0x46c29fc61a982,
+    0x46c3740c8f824, 0x46c40a1b0e4b1, 0x46c477e7391c7, 0x46c4ab245e28d,
+    0x46c4f0ae3502c, 0x46c4f457255f1, 0x46c665fdf9caa, 0x46c7921af75c4,
+    0x46c7fc3e31d15, 0x46c820d7956c7, 0x46c83a7627f2a, 0x46c84cc2d9c03,
+    0x46c8e67a48e55, 0x46c9afc5ecba8, 0x46c9e6ac02233, 0x46ca6e16bf7ac,
+    0x46cae334cb04c, 0x46cb0f200f588, 0x46cb8f38ebf77, 0x46cbc61f01602,
+    0x46cc16a3a94f0, 0x46ccacb22817d, 0x46cd550d58ae3, 0x46ce9377080d6,
+    0x46cea21ac97ea, 0x46cea96caa374, 0x46cec30b3cbd7, 0x46cef29f716d8,
+    0x46cf382948477, 0x46cf3bd238a3c, 0x46cf5570cb29f, 0x46cf67bd7cf78,
+    0x46cfad4753d17, 0x46d097836ae57, 0x46d0ce69804e2, 0x46d160cf0ebaa,
+    0x46d1caf2492fb, 0x46d1d24429e85, 0x46d2d61ed3828, 0x46d2fe612779f,
+    0x46d38222f4753, 0x46d3cefeac07c, 0x46d468b61b2ce, 0x46d47b02ccfa7,
+    0x46d59fcde9d37, 0x46d5bd156cb5f, 0x46d698adc258b, 0x46d6b5f5453b3,
+    0x46d6f42d3b5c8, 0x46d7b27e0e1cc, 0x46d8ed3ecd1fa, 0x46d8f8399e349,
+    0x46d969aeb9624, 0x46d9c1854209c, 0x46da070f18e3b, 0x46db332c16755,
+    0x46db78b5ed4f4, 0x46dd005263e4b, 0x46dd2fe69894c, 0x46dd50d70bd39,
+    0x46dd6323bda12, 0x46ddc24c27014, 0x46ddea8e7af8b, 0x46de5c0396266,
+    0x46de66fe673b5, 0x46dee71743da4, 0x46df49e89d96b, 0x46e037cda5070,
+    0x46e0516c378d3, 0x46e0eb23a6b25, 0x46e1295b9cd3a, 0x46e137ff5e44e,
+    0x46e1b0c65a2b3, 0x46e1fda211bdc, 0x46e238311782c, 0x46e2eb87192e1,
+    0x46e3853e88533, 0x46e3fa5c93dd3, 0x46e4a660b4cfe, 0x46e561089733d,
+    0x46e5894aeb2b4, 0x46e5e12173d2c, 0x46e61f5969f41, 0x46e631a61bc1a,
+    0x46e71f8b2331f, 0x46e72a85f446e, 0x46e73cd2a6147, 0x46e7e8d6c7072,
+    0x46e832098e3d6, 0x46e8bd1d3bf14, 0x46eb5e89fe4ac, 0x46eb6232eea71,
+    0x46ebb66086f24, 0x46ebe24bcb460, 0x46ebfbea5dcc3, 0x46ec7c033a6b2,
+    0x46ecbde420e8c, 0x46ecc8def1fdb, 0x46ed20b57aa53, 0x46ed7c34f3a90,
+    0x46edd0628bf43, 0x46eeafa3d1f34, 0x46eeb34cc24f9, 0x46ef24c1dd7d4,
+    0x46ef50ad21d10, 0x46ef62f9d39e9, 0x46f03e9229415, 0x46f128ce40555,
+    0x46f1b3e1ee093, 0x46f45ca0911b5, 0x46f5ce476586e, 0x46f6ad88ab85f,
+    0x46f6f6bb72bc3, 0x46f717abe5fb0, 0x46f743972a4ec, 0x46f789210128b,
+    0x46f87aaef8f55, 0x46f8d285819cd, 0x46f91bb848d31, 0x46f943fa9cca8,
+    0x46f9b56fb7f83, 0x46f9ec55cd60e, 0x46f9faf98ed22, 0x46fafb2b48100,
+    0x46fba72f6902b, 0x46fbd6c39db2c, 0x46fbda6c8e0f1, 0x46fbf40b20954,
+    0x46fc35ec0712e, 0x46fc40e6d827d, 0x46fc7b75ddecd, 0x46fc8dc28fba6,
+    0x46fdb9df8d4c0, 0x46feff9b1d63d, 0x46ff6d6748353, 0x46ff8705dabb6,
+    0x4701db96e5825, 0x47029295d789f, 0x4702be811bddb, 0x4702c22a0c3a0,
+    0x47040f377d0a7, 0x470449c682cf7, 0x470463651555a, 0x4704cd884fcab,
+    0x470516bb1700f, 0x47056ae8af4c2, 0x4705a1cec4b4d, 0x4705acc995c9c,
+    0x4705bb6d573b0, 0x4705eeaa7c476, 0x4706ca42d1ea2, 0x470746b2be2cc,
+    0x470758ff6ffa5, 0x47078893a4aa6, 0x4707a23237309, 0x4707ca748b280,
+    0x47082d45e4e47, 0x470867d4eaa97, 0x4709c72f0d477, 0x470af34c0ad91,
+    0x470b0941ad02f, 0x470b38d5e1b30, 0x470b9ba73b6f7, 0x470c52a62d771,
+    0x470c6fedb0599, 0x470cc7c439011, 0x470dae575fb8c, 0x470dec8f55da1,
+    0x470df78a26ef0, 0x470eecc10f17f, 0x470f40eea7632, 0x470f82cf8de0c,
+    0x4710118c2bf0f, 0x4710536d126e9, 0x4711031a23bd9, 0x4711b2c7350c9,
+    0x471415fc0144c, 0x471419a4f1a11, 0x47143a9564dfe, 0x47143e3e553c3,
+    0x4716a51c11d0b, 0x47172c86cf284, 0x47173b2a90998, 0x4717a1a4dab24,
+    0x4717b3f18c7fd, 0x471916f49f7a2, 0x47193f36f3719, 0x471a4e0c6e20b,
+    0x471b4e3e275e9, 0x471b85243cc74, 0x471bad6690beb, 0x471c3c232ecee,
+    0x471cce88bd3b6, 0x471cf32220d68, 0x471d3c54e80cc, 0x471dfaa5bacd0,
+    0x471e562533d0d, 0x471ee88ac23d5, 0x471f26c2b85ea, 0x471f6ff57f94e,
+    0x471ffb092d48c, 0x4720b2081f506, 0x4720cba6b1d69, 0x4720f3e905ce0,
+    0x472109dea7f7e, 0x472194f255abc, 0x47219fed26c0b, 0x4721a396171d0,
+    0x4721de251ce20, 0x47226ce1baf23, 0x4722ff47495eb, 0x472341282fdc5,
+    0x47236218a31b2, 0x4723d736aea52, 0x4724206975db6, 0x4724699c3d11a,
+    0x4724deba489ba, 0x4724f106fa693, 0x472532e7e0e6d, 0x4725a0b40bb83,
+    0x4725fc3384bc0, 0x4726281ec90fc, 0x47268747326fe, 0x4727b70d205dd,
+    0x47283aceed591, 0x47294252874f9, 0x47296e3dcba35, 0x4729b01eb220f,
+    0x4729b3c7a27d4, 0x4729bec273923, 0x4729c26b63ee8, 0x472b4db0cae04,
+    0x472b5c548c518, 0x472b933aa1ba3, 0x472bc2ced66a4, 0x472c5c86458f6,
+    0x472c6b2a0700a, 0x472cb0b3ddda9, 0x472cc6a980047, 0x472d590f0e70f,
+    0x472d7da8720c1, 0x472dd927eb0fe, 0x472df66f6df26, 0x472e2603a2a27,
+    0x472e46f415e14, 0x472e6b8d797c6, 0x472e7dda2b49f, 0x472eb869310ef,
+    0x472eeba6561b5, 0x472ef6a127304, 0x472f0c96c95a2, 0x472f81b4d4e42,
+    0x472fa2a54822f, 0x472fe0dd3e444, 0x473017c353acf, 0x47304757885d0,
+    0x4731ae038bb3a, 0x47327af81fe52, 0x4734063d86d6e, 0x473414e148482,
+    0x47345e140f7e6, 0x473644d8ef73f, 0x47371cc854ba6, 0x473770f5ed059,
+    0x47386283e4d23, 0x4739d42ab93dc, 0x473a41f6e40f2, 0x473acd0a91c30,
+    0x473b54754f1a9, 0x473c634ac9c9b, 0x473c80924cac3, 0x473cbb2152713,
+    0x473cbeca42cd8, 0x473e4a0fa9bf4, 0x473e4db89a1b9, 0x473e96eb6151d,
+    0x473f6788e5dfa, 0x473fdca6f169a, 0x47402230c8439, 0x4740ce34e9364,
+    0x4741818aeae19, 0x4743684fcad72, 0x4743c77834374, 0x47448971f753d,
+    0x4744d9f69f42b, 0x47475e1bdeb9b, 0x474777ba713fe, 0x47479c53d4db0,
+    0x4747c0ed38762, 0x4747d6e2daa00, 0x4748b9cd10fb6, 0x474923f04b707,
+    0x47497bc6d417f, 0x47498e1385e58, 0x4749bda7ba959, 0x474adb20f6b5f,
+    0x474b1958ecd74, 0x474c20dc86cdc, 0x474c8756d0e68, 0x474d5b9d45d0a,
+    0x474d7c8db90f7, 0x474da878fd633, 0x474e58260eb23, 0x474e5bceff0e8,
+    0x474e6e1bb0dc1, 0x474f460b16228, 0x474fb02e50979, 0x474ff20f37153,
+    0x474ffd0a082a2, 0x475016a89ab05, 0x4750e7461f3e2, 0x4750f5e9e0af6,
+    0x475100e4b1c45, 0x4751292705bbc, 0x47513078e6746, 0x47514a1778fa9,
+    0x4751516959b33, 0x4751c687653d3, 0x47527d865744d, 0x47535cc79d43e,
+    0x47539aff93653, 0x4753ce3cb8719, 0x4754e46413d95, 0x4754f307d54a9,
+    0x47559b6305e0f, 0x4755bffc697c1, 0x4755dd43ec5e9, 0x475759e591df1,
+    0x475894a650e1f, 0x4758a34a12533, 0x475be96914e6c, 0x475c204f2a4f7,
+    0x475c413f9d8e4, 0x475cfbe77ff23, 0x475d959eef175, 0x475de27aa6a9e,
+    0x475ea81d5a22c, 0x475ed05fae1a3, 0x476045af72e21, 0x4761005755460,
+    0x47618f13f3563, 0x4761a160a523c, 0x4761c9a2f91b3, 0x4761e6ea7bfdb,
+    0x4762585f972b6, 0x476416e223298, 0x47647d5c6d424, 0x476488573e573,
+    0x476530b26eed9, 0x4765d90d9f83f, 0x476743629336e, 0x476829f5b9ee9,
+    0x47684ae62d2d6, 0x47687e235239c, 0x4768adb786e9d, 0x476985a6ec304,
+    0x4769b8e4113ca, 0x476a529b8061c, 0x476b233904ef9, 0x476b392ea7197,
+    0x476c61a2b44ec, 0x476c654ba4ab1, 0x476cf7b133179, 0x476d18a1a6566,
+    0x476d239c776b5, 0x476d35e92938e, 0x476d69264e454, 0x476df7e2ec557,
+    0x476dfb8bdcb1c, 0x476fe5f9ad03a, 0x47704c73f71c6, 0x47707c082bcc7,
+    0x4770bde9124a1, 0x4770db30952c9, 0x4773127a1d110, 0x477316230d6d5,
+    0x4773420e51c11, 0x4773cd21ff74f, 0x4773f90d43c8b, 0x477424f8881c7,
+    0x477528d331b6a, 0x4775a5431df94, 0x4775bee1b07f7, 0x4776f5f97f260,
+    0x47779702cf03c, 0x4777ca3ff4102, 0x4778938b97e55, 0x4778bbcdebdcc,
+    0x4778c6c8bcf1b, 0x47794338a9345, 0x47799766417f8, 0x4779d1f547448,
+    0x4779fa379b3bf, 0x477b1b59c7b8a, 0x477c85aebb6b9, 0x477d89896505c,
+    0x477e358d85f87, 0x477e47da37c60, 0x477e4f2c187ea, 0x477e6c739b612,
+    0x477eb5a662976, 0x477f704e44fb5, 0x477f7ef2066c9, 0x478002b3d367d,
+    0x4781c4df4fc24, 0x4782ba1637eb3, 0x4782e6017c3ef, 0x4783661a58dde,
+    0x4783d3e683af4, 0x4784a82cf8996, 0x478520f3f47fb, 0x47854cdf38d37,
+    0x47855b82fa44b, 0x478650b9e26da, 0x47871a058642d, 0x47893f025c59b,
+    0x478949fd2d6ea, 0x47895c49df3c3, 0x47896e969109c, 0x47898bde13ec4,
+    0x4789c66d19b14, 0x4789e00bac377, 0x478a3b8b253b4, 0x478abba401da3,
+    0x478af9dbf7fb8, 0x478b221e4bf2f, 0x478b7d9dc4f6c, 0x478b973c577cf,
+    0x478bce226ce5a, 0x478bd1cb5d41f, 0x478bdcc62e56e, 0x478cc359550e9,
+    0x478dbfe21df02, 0x478def7652a03, 0x478e8cd6b221a, 0x478f3531e2b80,
+    0x478f7abbb991f, 0x478f85b68aa6e, 0x478fdd8d134e6, 0x47904b593e1fc,
+    0x4791c7fae3a04, 0x479277a7f4ef4, 0x47931c5a35295, 0x47934845797d1,
+    0x4793cc0746785, 0x479465beb59d7, 0x4794f47b53ada, 0x4795365c3a2b4,
+    0x47955af59dc66, 0x4795ab7a45b54, 0x479606f9beb91, 0x479669cb18758,
+    0x47967c17ca431, 0x4796fc30a6e20, 0x47970e7d58af9, 0x479715cf39683,
+    0x47972472fad97, 0x47977c498380f, 0x4797a834c7d4b, 0x4797c1d35a5ae,
+    0x4797e2c3cd99b, 0x4798c956f4516, 0x4798e2f586d79, 0x4799a1465997d,
+    0x4799afea1b091, 0x4799c5dfbd32f, 0x479ab01bd446f, 0x479b9a57eb5af,
+    0x479bc6432faeb, 0x479bc9ec200b0, 0x479ca58475adc, 0x479e5563403aa,
+    0x479eb83499f71, 0x479ee076edee8, 0x479feba378415, 0x47a0437a00e8d,
+    0x47a08903d7c2c, 0x47a0cae4be406, 0x47a1d2685836e, 0x47a2cb4830bc2,
+    0x47a31f75c9075, 0x47a35dadbf28a, 0x47a36156af84f, 0x47a37e9e32677,
+    0x47a3c07f18e51, 0x47a3c7d0f99db, 0x47a3da1dab6b4, 0x47a3ddc69bc79,
+    0x47a468da497b7, 0x47a4986e7e2b8, 0x47a4b95ef16a5, 0x47a5b23ec9ef9,
+    0x47a639a987472, 0x47a702f52b1c5, 0x47a75379d30b3, 0x47a7e5df6177b,
+    0x47a8c520a776c, 0x47a948e274720, 0x47aa95efe5427, 0x47ab03bc1013d,
+    0x47ab285573aef, 0x47acc5e78c6e4, 0x47ad84385f2e8, 0x47adbb1e74973,
+    0x47ae2c938fc4e, 0x47aed145cffef, 0x47af75f810390, 0x47afd8c969f57,
+    0x47b167b7c1438, 0x47b17dad636d6, 0x47b1b83c69326, 0x47b2f2fd28354,
+    0x47b3a9fc1a3ce, 0x47b4141f54b1f, 0x47b4475c79be5, 0x47b4a2dbf2c22,
+    0x47b4a684e31e7, 0x47b4b17fb4336, 0x47b585c6291d8, 0x47b5e89782d9f,
+    0x47b68d49c3140, 0x47b690f2b3705, 0x47b7394de406b, 0x47b76c8b09131,
+    0x47b794cd5d0a8, 0x47b7f3f5c66aa, 0x47b9067431761, 0x47b9659c9ad63,
+    0x47b9ff5409fb5, 0x47bac89fadd08, 0x47bbe26ff9949, 0x47bc19560efd4,
+    0x47bc9d17dbf88, 0x47bca0c0cc54d, 0x47bcd3fdf1613, 0x47bd3a783b79f,
+    0x47bd62ba8f716, 0x47be6695390b9, 0x47bf19eb3ab6e, 0x47bf9dad07b22,
+    0x47c13b3f20717, 0x47c14d8bd23f0, 0x47c154ddb2f7a, 0x47c196be99754,
+    0x47c2dc7a298d1, 0x47c3554125736, 0x47c4d58bbb503, 0x47c5176ca1cdd,
+    0x47c5b12410f2f, 0x47c5cac2a3792, 0x47c5ce6b93d57, 0x47c60551a93e2,
+    0x47c69b602806f, 0x47c6caf45cb70, 0x47c7ec168933b, 0x47c81f53ae401,
+    0x47c864dd851a0, 0x47c8822507fc8, 0x47c8bcb40dc18, 0x47c8dda481005,
+    0x47c8e14d715ca, 0x47c9f022ec0bc, 0x47c9fb1dbd20b, 0x47cb1fe8d9f9b,
+    0x47cb9506e583b, 0x47ccde6b65f7d, 0x47cce5bd46b07, 0x47cd11a88b043,
+    0x47cd488ea06ce, 0x47cd831da631e, 0x47ce74ab9dfe8, 0x47cea7e8c30ae,
+    0x47cedeced8739, 0x47ceed7299e4d, 0x47cfb3154d5db, 0x47cfccb3dfe3e,
+    0x47d1d0c042bbf, 0x47d333c355b64, 0x47d3dc1e864ca, 0x47d449eab11e0,
+    0x47d48bcb979ba, 0x47d4c65a9d60a, 0x47d5acedc4185, 0x47d5b096b474a,
+    0x47d5f2779af24, 0x47d65c9ad5675, 0x47d6a9768cf9e, 0x47d6eb5773778,
+    0x47d704f605fdb, 0x47d79009b3b19, 0x47d7d93c7ae7d, 0x47d872f3ea0cf,
+    0x47dad628b6452, 0x47dae4cc77b66, 0x47db85d5c7942, 0x47dc233627159,
+    0x47dc2a8807ce3, 0x47dc44269a546, 0x47dc8d59618aa, 0x47dc94ab42434,
+    0x47de8dbcd4066, 0x47df20226272e, 0x47df3d69e5556, 0x47df4112d5b1b,
+    0x47df4864b66a5, 0x47e07bd394b49, 0x47e0e24ddecd5, 0x47e25eef844dd,
+    0x47e3199766b1c, 0x47e31d40570e1, 0x47e362ca2de80, 0x47e416202f935,
+    0x47e462fbe725e, 0x47e48b3e3b1d5, 0x47e592c1d513d, 0x47e5c9a7ea7c8,
+    0x47e63b1d05aa3, 0x47e6dfcf45e44, 0x47e70bba8a380, 0x47e7d15d3db0e,
+    0x47e7e752dfdac, 0x47e8810a4effe, 0x47e884b33f5c3, 0x47e8d8e0d7a76,
+    0x47e8e7849918a, 0x47e91ac1be250, 0x47e929657f964, 0x47e99ada9ac3f,
+    0x47eac34ea7f94, 0x47ebbfd770dad, 0x47ecf3464f251, 0x47ed093bf14ef,
+    0x47ed735f2bc40, 0x47eddd8266391, 0x47edefcf1806a, 0x47eed2b94e620,
+    0x47f040b732714, 0x47f18dc4a341b, 0x47f1916d939e0, 0x47f1ab0c26243,
+    0x47f1d6f76a77f, 0x47f4b69c22f2c, 0x47f59986594e2, 0x47f5db673fcbc,
+    0x47f68ebd41771, 0x47f6f18e9b338, 0x47f77ca248e76, 0x47f8967294ab7,
+    0x47f8f943ee67e, 0x47f8fcecdec43, 0x47f94d7186b31, 0x47fb468318763,
+    0x47fb81121e3b3, 0x47fb935ed008c, 0x47fbe03a879b5, 0x47fbeede490c9,
+    0x47fc430be157c, 0x47fd60851d782, 0x47fda60ef4521, 0x47fe1b2cffdc1,
+    0x47fe4e6a24e87, 0x47ff0912074c6, 0x48002a3433c91, 0x48007366faff5,
+    0x480090ae7de1d, 0x480098005e9a7, 0x4800b547e17cf, 0x4800f728c7fa9,
+    0x48012a65ed06f, 0x4801cf182d410, 0x4802d69bc7378, 0x4802f3e34a1a0,
+    0x48039c3e7ab06, 0x4803c0d7de4b8, 0x4803d6cd80756, 0x4803e91a3242f,
+    0x48040db395de1, 0x480461e12e294, 0x48048a238220b, 0x4804daa82a0f9,
+    0x480515372fd49, 0x4805536f25f5e, 0x4805e97da4beb, 0x4805ed26951b0,
+    0x480606c527a13, 0x48068e2fe4f8c, 0x48069cd3a66a0, 0x4806a07c96c65,
+    0x4806e9af5dfc9, 0x4807b2fb01d1c, 0x4807f132f7f31, 0x4807fc2dc9080,
+    0x48088aea67183, 0x4808af83cab35, 0x4808d7c61eaac, 0x4808edbbc0d4a,
+    0x48092bf3b6f5f, 0x48094ce42a34c, 0x48099d68d223a, 0x4809a4bab2dc4,
+    0x4809e69b9959e, 0x4809ea4489b63, 0x4809f1966a6ed, 0x480a5bb9a4e3e,
+    0x480a6e0656b17, 0x480ac233eefca, 0x480b87d6a2758, 0x480b9a2354431,
+    0x480bcd60794f7, 0x480bdfad2b1d0, 0x480c3031d30be, 0x480c3783b3c48,
+    0x480cfd26673d6, 0x480d3b5e5d5eb, 0x480d63a0b1562, 0x480d883a14f14,
+    0x480d9334e6063, 0x480e4a33d80dd, 0x480e60297a37b, 0x480e761f1c619,
+    0x480e886bce2f2, 0x480e93669f441, 0x480ee3eb4732f, 0x480eeb3d27eb9,
+    0x480f25cc2db09, 0x480fa23c19f33, 0x480fe07410148, 0x480ff669b23e6,
+    0x4810dcfcd8f61, 0x4810fa445bd89, 0x48116467964da, 0x481197a4bb5a0,
+    0x4811f324345dd, 0x4811f6cd24ba2, 0x4812d9b75b158, 0x4813bca19170e,
+    0x48145a01f0f25, 0x48145daae14ea, 0x4814ec677f5ed, 0x481514a9d3564,
+    0x4815359a46951, 0x481570294c5a1, 0x481627283e61b, 0x481640c6d0e7e,
+    0x4816cf836ef81, 0x4817d70708ee9, 0x4817e5aaca5fd, 0x4818746768700,
+    0x48189ca9bc677, 0x4818aef66e350, 0x48191570b84dc, 0x481927bd6a1b5,
+    0x48192b665a77a, 0x4819624c6fe05, 0x481a53da67acf, 0x481a95bb4e2a9,
+    0x481ac8f87336f, 0x481acca163934, 0x481b7ff7653e9, 0x481bb3348a4af,
+    0x481c8eccdfedb, 0x481cf19e39aa2, 0x481d4d1db2adf, 0x481d6a6535907,
+    0x481e584a3d00c, 0x481e808c90f83, 0x481ee706db10f, 0x481eeaafcb6d4,
+    0x481f8f620ba75, 0x481f96b3ec5ff, 0x481fa1aebd74e, 0x481fd894d2dd9,
+    0x4820cdcbbb068, 0x482121f95351b, 0x4821d54f54fd0, 0x4821d8f845595,
+    0x4822a5ecd98ad, 0x4822f6718179b, 0x4823434d390c4, 0x482346f629689,
+    0x4823a61e92c8b, 0x482476bc17568, 0x482481b6e86b7, 0x4824a2a75baa4,
+    0x4825350cea16c, 0x48255d4f3e0e3, 0x48256f9befdbc, 0x4825e862ebc21,
+    0x482690be1c587, 0x4826980ffd111, 0x4826ae059f3af, 0x48270985183ec,
+    0x482726cc9b214, 0x48277afa336c7, 0x4827aa8e681c8, 0x4827b1e048d52,
+    0x4827f3c12f52c, 0x482940cea0233, 0x482994fc386e6, 0x4829f07bb1723,
+    0x482a82e13fdeb, 0x482ae209a93ed, 0x482ae95b89f77, 0x482b2b3c70751,
+    0x482c2073589e0, 0x482c4163cbdcd, 0x482cbdd3b81f7, 0x482cd3c95a495,
+    0x482ced67eccf8, 0x482d6285f8598, 0x482e78ad53c14, 0x482f96268fe1a,
+    0x482fa4ca5152e, 0x482fcd0ca54a5, 0x483087b487ae4, 0x4830c2438d734,
+    0x4831a184d3725, 0x4831b77a759c3, 0x483249e00408b, 0x4832c2a6ffef0,
+    0x4832ee924442c, 0x483300def6105, 0x48334668ccea4, 0x48343f48a56f8,
+    0x483442f195cbd, 0x48344dec66e0c, 0x4835ce36fcbd9, 0x48369782a092c,
+    0x4836ad7842bca, 0x4836cabfc59f2, 0x4836ce68b5fb7, 0x483708f7bbc07,
+    0x4837313a0fb7e, 0x4837647734c44, 0x4838b52d95f10, 0x4838b8d6864d5,
+    0x4838d27518d38, 0x4838fe605d274, 0x483938ef62ec4, 0x483aa6ed46fb8,
+    0x483ac434c9de0, 0x483af0200e31c, 0x483b35a9e50bb, 0x483bf3fab7cbf,
+    0x483d709c5d4c7, 0x483d98deb143e, 0x483ded0c498f1, 0x483df45e2a47b,
+    0x483f11d766681, 0x483f834c8195c, 0x4840195b005e9, 0x4840788369beb,
+    0x48409d1ccd59d, 0x484195fca5df1, 0x4841a0f776f40, 0x4841e6814dcdf,
+    0x48422862344b9, 0x4842753debde2, 0x4842ea5bf7682, 0x4842f1add820c,
+    0x48430ef55b034, 0x4843f93172174, 0x484487ee10277, 0x4844dc1ba872a,
+    0x4844dfc498cef, 0x4845b0621d5cc, 0x484655145d96d, 0x4846c2e088683,
+    0x4846eecbccbbf, 0x484758ef07310, 0x48487dba240a0, 0x48489006d5d79,
+    0x4848b0f749166, 0x484913c8a2d2d, 0x48494705c7df3, 0x48494aaeb83b8,
+    0x484960a45a656, 0x4849bc23d3693, 0x4849d5c265ef6, 0x4849ebb808194,
+    0x484a4e8961d5b, 0x484a856f773e6, 0x484b22cfd6bfd, 0x484bcb2b07563,
+    0x484bf7164ba9f, 0x484bfe682c629, 0x484c1806bee8c, 0x484c64e2767b5,
+    0x484cb5671e6a3, 0x484d26dc3997e, 0x484e40ac855bf, 0x484e91312d4ad,
+    0x484eae78b02d5, 0x484f3d354e3d8, 0x484f40de3e99d, 0x484f5e25c17c5,
+    0x484f61ceb1d8a, 0x484f6cc982ed9, 0x484ffb8620fdc, 0x4850a3e151942,
+    0x4850c128d476a, 0x48510309baf44, 0x4851538e62e32, 0x48517827c67e4,
+    0x485186cb87ef8, 0x4851fbe993798, 0x4852c8de27ab0, 0x4852f1207ba27,
+    0x4853b31a3ebf0, 0x48540af0c7668, 0x4854716b117f4, 0x485478bcf237e,
+    0x48548760b3a92, 0x4854b6f4e8593, 0x4855928d3dfbf, 0x48560efd2a3e9,
+    0x4856c2532be9e, 0x485767056c23f, 0x485780a3feaa2, 0x485896cb5a11e,
+    0x485942cf7b049, 0x48599e4ef4086, 0x4859d8ddf9cd6, 0x485a6b438839e,
+    0x485ac31a10e16, 0x485ad566c2aef, 0x485b59288faa3, 0x485bbbf9e966a,
+    0x485c1ecb43231, 0x485c3117f4f0a, 0x485c4e5f77d32, 0x485c819c9cdf8,
+    0x485c9ee41fc20, 0x485cbc2ba2a48, 0x485d055e69dac, 0x485d389b8ee72,
+    0x485d682fc3973, 0x485dbc5d5be26, 0x485e59bdbb63d, 0x485e68617cd51,
+    0x485e735c4dea0, 0x485efac70b419, 0x485ffaf8c47f7, 0x486052cf4d26f,
+    0x4860b5a0a6e36, 0x4860c09b77f85, 0x486111201fe73, 0x4861a385ae53b,
+    0x4861f40a56429, 0x48633d6ed6b6b, 0x486406ba7a8be, 0x4866b6cafe56a,
+    0x486703a6b5e93, 0x486750826d7bc, 0x4867a107156aa, 0x486824c8e265e,
+    0x486950e5dff78, 0x48699a18a72dc, 0x4869ea9d4f1ca, 0x486a586979ee0,
+    0x486af972c9cbc, 0x486c3f2e59e39, 0x486c935bf22ec, 0x486ca1ffb3a00,
+    0x486d171dbf2a0, 0x486d67a26718e, 0x486dc321e01cb, 0x486e299c2a357,
+    0x486eb858c845a, 0x486ee7ecfcf5b, 0x486f311fc42bf, 0x486f6f57ba4d4,
+    0x486fa63dcfb5f, 0x486fa9e6c0124, 0x486ff31987488, 0x48709079e6c9f,
+    0x4870ebf95fcdc, 0x487252a563246, 0x4872ec5cd2498, 0x487310f635e4a,
+    0x487352d71c624, 0x4873826b51125, 0x4873cb9e18489, 0x48742ac681a8b,
+    0x48745308d5a02, 0x487461ac97116, 0x48747ef419f3e, 0x4874cbcfd1867,
+    0x48752ea12b42e, 0x4875e9490da6d, 0x4876f0cca79d5, 0x48778e2d071ec,
+    0x48785b219b504, 0x4879923969f6d, 0x4879d41a50747, 0x487a3342b9d49,
+    0x487b2127c144e, 0x487b3ac653cb1, 0x487b9d97ad878, 0x487ba8927e9c7,
+    0x487c25026adf1, 0x487d28dd14794, 0x487d49cd87b81, 0x487d587149295,
+    0x487d5c1a3985a, 0x487d720fdbaf8, 0x487db3f0c22d2, 0x487dcd8f54b35,
+    0x487dead6d795d, 0x487ec66f2d389, 0x487ed169fe4d8, 0x487f1e45b5e01,
+    0x487f9e5e927f0, 0x487fa5b07337a, 0x487fa9596393f, 0x4880ad340d2e2,
+    0x4882b4e960628, 0x4885b9277c787, 0x4885e169d06fe, 0x488626f3a749d,
+    0x48864b8d0ae4f, 0x4886cba5e783e, 0x4888de560bcd3, 0x488941276589a,
+    0x488afc01012b7, 0x488b6d761c592, 0x488bc8f5955cf, 0x488df19b5bd02,
+    0x488dfc962ce51, 0x488ec98ac1169, 0x488edf8063407, 0x488f50f57e6e2,
+    0x48913f0c3f1c5, 0x48916af783701, 0x4891883f06529, 0x489263d75bf55,
+    0x4893f2c5b3436, 0x4894643ace711, 0x48946f359f860, 0x48954acdf528c,
+    0x489576b9397c8, 0x489581b40a917, 0x48965d4c60343, 0x4898c0812c6c6,
+    0x48995de18bedd, 0x489965336ca67, 0x48998623dfe54, 0x4899e1a358e91,
+    0x489bb61b87111, 0x489bc86838dea, 0x489c5e76b7a77, 0x489ce23884a2b,
+    0x489d5756902cb, 0x489d7f98e4242, 0x489dba27e9e92, 0x489df10dff51d,
+    0x489e62831a7f8, 0x489eb307c26e6, 0x489ec554743bf, 0x489eed96c8336,
+    0x489f6dafa4d25, 0x489fa83eaa975, 0x48a0f54c1b67c, 0x48a124e05017d,
+    0x48a1e6da13346, 0x48a2c27268d72, 0x48a31a48f17ea, 0x48a38f66fd08a,
+    0x48a3b05770477, 0x48a3b7a951001, 0x48a3fd3327da0, 0x48a521fe44b30,
+    0x48a530a206244, 0x48a59ac540995, 0x48a5a5c011ae4, 0x48a5bbb5b3d82,
+    0x48a5dca62716f, 0x48a5e3f807cf9, 0x48a66b62c5272, 0x48a6fdc85393a,
+    0x48a71766e619d, 0x48a7c713f768d, 0x48a829e551254, 0x48a834e0223a3,
+    0x48a8ada71e208, 0x48a91b7348f1e, 0x48aac0574269d, 0x48ab6c5b635c8,
+    0x48ab85f9f5e2b, 0x48accf5e7656d, 0x48ad0d966c782, 0x48ad4825723d2,
+    0x48ad9ffbfae4a, 0x48ada3a4eb40f, 0x48ae659eae5d8, 0x48aeb9cc46a8b,
+    0x48aff0e4154f4, 0x48b032c4fbcce, 0x48b0f867af45c, 0x48b1d057148c3,
+    0x48b362ee5c369, 0x48b4498182ee4, 0x48b466c905d0c, 0x48b46a71f62d1,
+    0x48b5ee657c663, 0x48b5fd093dd77, 0x48b68f6ecc43f, 0x48b6bf0300f40,
+    0x48b6d8a1937a3, 0x48b6ee9735a41, 0x48b7884ea4c93, 0x48b7ace808645,
+    0x48b7dc7c3d146, 0x48b7f271df3e4, 0x48b8220613ee5, 0x48b8e056e6ae9,
+    0x48b9173cfc174, 0x48b951cc01dc4, 0x48b99afec9128, 0x48bb2d9610bce,
+    0x48bbb157ddb82, 0x48bbc3a48f85b, 0x48bbd99a31af9, 0x48bc3519aab36,
+    0x48bc5d5bfeaad, 0x48bc7aa3818d5, 0x48bcb53287525, 0x48bd09601f9d8,
+    0x48bd1f55c1c76, 0x48bd31a27394f, 0x48bf061aa1bcf, 0x48bf569f49abd,
+    0x48bf778fbceaa, 0x48bf7b38ad46f, 0x48c032379f4e9, 0x48c1ab305472c,
+    0x48c2daf64260b, 0x48c2de9f32bd0, 0x48c3db27fb9e9, 0x48c4a81c8fd01,
+    0x48c4d407d423d, 0x48c624be35509, 0x48c750db32e23, 0x48c787c1484ae,
+    0x48c8d125c8bf0, 0x48c975d808f91, 0x48cadc840c4fb, 0x48caeb27cdc0f,
+    0x48cb46a746c4c, 0x48cc08a109e15, 0x48ce523743935, 0x48ceef97a314c,
+    0x48cf01e454e25, 0x48cf894f1239e, 0x48d02306815f0, 0x48d0738b294de,
+    0x48d0c7b8c1991, 0x48d0d65c830a5, 0x48d11be659e44, 0x48d17765d2e81,
+    0x48d194ad55ca9, 0x48d3866d06d51, 0x48d3ab066a703, 0x48d41529a4e54,
+    0x48d4869ec012f, 0x48d523ff1f946, 0x48d55e8e25596, 0x48d565e006120,
+    0x48d5991d2b1e6, 0x48d6a0a0c514e, 0x48d6f4ce5d601, 0x48d7b31f30205,
+    0x48d7c1c2f1919, 0x48d88b0e9566c, 0x48d8f8dac0382, 0x48d9d47315dae,
+    0x48db75ae1ef68, 0x48dc80daa9495, 0x48dcca0d707f9, 0x48de1e6cc208a,
+    0x48dee40f75818, 0x48df4e32aff69, 0x48e04e6469347, 0x48e0fa688a272,
+    0x48e134f78fec2, 0x48e16834b4f88, 0x48e1a2c3babd8, 0x48e1c75d1e58a,
+    0x48e2ec283b31a, 0x48e34ef994ee1, 0x48e431e3cb497, 0x48e468c9e0b22,
+    0x48e5c82403502, 0x48e5ecbd66eb4, 0x48e6e9462fccd, 0x48e7b63ac3fe5,
+    0x48e7de7d17f5c, 0x48e911ebf6400, 0x48e927e19869e, 0x48e969c27ee78,
+    0x48eaaf7e0eff5, 0x48eab326ff5ba, 0x48eaccc591e1d, 0x48ead7c062f6c,
+    0x48eb28450ae5a, 0x48eb6dcee1bf9, 0x48eb876d7445c, 0x48ebac06d7e0e,
+    0x48ebc94e5ac36, 0x48ebea3ece023, 0x48eccd29045d9, 0x48ecd47ae5163,
+    0x48ed00662969f, 0x48ed2ffa5e1a0, 0x48ed8f22c77a2, 0x48ed92cbb7d67,
+    0x48edb7651b719, 0x48edfceef24b8, 0x48eed1356735a, 0x48ef3b58a1aab,
+    0x48ef6743e5fe7, 0x48efaccdbcd86, 0x48f046852bfd8, 0x48f0cdefe9551,
+    0x48f179f40a47c, 0x48f1a2365e3f3, 0x48f1b82c00691, 0x48f3f6c769062,
+    0x48f42dad7e6ed, 0x48f585b5c0543, 0x48f58d07a10cd, 0x48f5a2fd4336b,
+    0x48f6b9249e9e7, 0x48f6ddbe02399, 0x48f77ec752175, 0x48f79865e49d8,
+    0x48f7f78e4dfda, 0x48f84bbbe648d, 0x48f94844af2a6, 0x48f97f2ac4931,
+    0x48fa9ca400b37, 0x48fb3a046034e, 0x48fcdee859acd, 0x48fcf4ddfbd6b,
+    0x48fd3a67d2b0a, 0x48fd5f01364bc, 0x48fd923e5b582, 0x48fdc57b80648,
+    0x48fe1d52090c0, 0x48fe5b89ff2d5, 0x48ff421d25e50, 0x48ffbe8d1227a,
+    0x48fff1ca37340, 0x490049a0bfdb8, 0x4901462988bd1, 0x49026e9d95f26,
+    0x490337e939c79, 0x4903fd8bed407, 0x4907aa2539ecc, 0x4907faa9e1dba,
+    0x490868760cad0, 0x49087ac2be7a9, 0x4909022d7bd22, 0x49093913913ad,
+    0x49093cbc81972, 0x49094b6043086, 0x4909c4273eeeb, 0x490a9c16a4352,
+    0x490ab5b536bb5, 0x490ad6a5a9fa2, 0x490ca023070d3, 0x490d9559ef362,
+    0x490dd73ad5b3c, 0x490e24168d465, 0x490edebe6faa4, 0x490f5ed74c493,
+    0x49107155b754a, 0x49126315685f2, 0x49126a674917c, 0x4912be94e162f,
+    0x4912ee2916130, 0x4913f955a065d, 0x49143edf773fc, 0x4915d176beea2,
+    0x4915d8c89fa2c, 0x4915e01a805b6, 0x4916a5bd33d44, 0x4917222d2016e,
+    0x49173822c240c, 0x4917b83b9edfb, 0x4917e426e3337, 0x4918853033113,
+    0x49193c2f2518d, 0x4919681a696c9, 0x491b3540b6dbf, 0x491b7aca8db5e,
+    0x491ba6b5d209a, 0x491bcb4f35a4c, 0x491be144d7cea, 0x491ccf29df3ef,
+    0x491cd67bbff79, 0x491cda24b053e, 0x491ce51f8168d, 0x491d0d61d5604,
+    0x491d1c0596d18, 0x491d3cf60a105, 0x491dc809b7c43, 0x491e7f08a9cbd,
+    0x491eff21866ac, 0x491f44ab5d44b, 0x4920622499651, 0x492065cd89c16,
+    0x492191ea87530, 0x4923db80c1050, 0x4924210a97def, 0x4924e3045afb8,
+    0x4924edff2c107, 0x4924f1a81c6cc, 0x492503f4ce3a5, 0x4925213c511cd,
+    0x492524e541792, 0x49258064ba7cf, 0x4925840daad94, 0x4925a1552dbbc,
+    0x49275fd7b9b9e, 0x4927d4f5c543e, 0x492830753e47b, 0x4928b08e1ae6a,
+    0x4928e3cb3ff30, 0x4928eec61107f, 0x4929dcab18784, 0x492a7a0b77f9b,
+    0x492b594cbdf8c, 0x492be4606baca, 0x492c229861cdf, 0x492c597e7736a,
+    0x492cbff8c14f6, 0x492ccaf392645, 0x492d4eb55f5f9, 0x492dcece3bfe8,
+    0x492dd2772c5ad, 0x492ee89e87c29, 0x492f098efb016, 0x492f10e0dbba0,
+    0x492f68b764618, 0x49309fcf33081, 0x4930fb4eac0be, 0x49314f7c44571,
+    0x49343672dd8a8, 0x4934c1868b3e6, 0x4935036771bc0, 0x49352ba9c5b37,
+    0x49356d8aac311, 0x4936af9d4bec9, 0x4936e2da70f8f, 0x4937161796055,
+    0x4937335f18e7d, 0x4937544f8c26a, 0x4937753fff657, 0x4937df6339da8,
+    0x49383e8ba33aa, 0x4938c99f50ee8, 0x493966ffb06ff, 0x4939b784585ed,
+    0x4939e7188d0ee, 0x493a5c369898e, 0x493a6e834a667, 0x493b4dc490658,
+    0x493b725df400a, 0x493b84aaa5ce3, 0x493bacecf9c5a, 0x493bd1865d60c,
+    0x493be3d30f2e5, 0x493bf61fc0fbe, 0x493c1e6214f35, 0x493cb81984187,
+    0x493ce404c86c3, 0x493d383260b76, 0x493db4a24cfa0, 0x493e3112393ca,
+    0x493fd5f632b49, 0x4940560f0f538, 0x4940735692360, 0x4940b18e88575,
+    0x4940c03249c89, 0x49414eeee7d8c, 0x49434800799be, 0x4943a728e2fc0,
+    0x494482c1389ec, 0x4944cf9cf0315, 0x49469cc33da0b, 0x4946a06c2dfd0,
+    0x49470e3858ce6, 0x49475b141060f, 0x4947e627be14d, 0x4948a82181316,
+    0x4948e2b086f66, 0x494b5bdaf5587, 0x494bdf9cc253b, 0x494be6eea30c5,
+    0x494c5fb59ef2a, 0x494c92f2c3ff0, 0x494d0f62b041a, 0x494e676af2270,
+    0x494eacf4c900f, 0x4950642574467, 0x495176a3df51e, 0x495193eb62346,
+    0x4951fa65ac4d2, 0x495289224a5d5, 0x4953dd819be66, 0x4953e4d37c9f0,
+    0x49541467b14f1, 0x495431af34319, 0x49544ef6b7141, 0x4954bcc2e1e57,
+    0x49552e37fd132, 0x49556175221f8, 0x49557b13b4a5b, 0x4956c4783519d,
+    0x49571ff7ae1da, 0x4957916cc94b5, 0x4957ecec424f2, 0x49582029675b8,
+    0x4958a3eb3456c, 0x4958ab3d150f6, 0x49598e274b6ac, 0x495bea0a36ea5,
+    0x495bffffd9143, 0x495c4cdb90a6c, 0x495c8ebc77246, 0x495eb3b94d3b4,
+    0x495eee4853004, 0x495f377b1a368, 0x495f9df5644f4, 0x4960c66971849,
+    0x4960cdbb523d3, 0x49615c77f04d6, 0x4961d8e7dc900, 0x4961e039bd48a,
+    0x4962bf7b0347b, 0x49632d472e191, 0x496393c17831d, 0x4964b88c950ad,
+    0x496597cddb09e, 0x4967d2c0534aa, 0x4968c44e4b174, 0x496b1c88463a8,
+    0x496b7807bf3e5, 0x496b7f599ff6f, 0x496bcc3557898, 0x496dfc2cfeb55,
+    0x496e2bc133656, 0x496f2849fc46f, 0x496f5435409ab, 0x496f926d36bc0,
+    0x496fafb4b99e8, 0x496fbaaf8ab37, 0x4970f9193a12a, 0x497104140b279,
+    0x49710b65ebe03, 0x49710f0edc3c8, 0x49711db29dadc, 0x4972c9e877de5,
+    0x4973e3b8c3a26, 0x497479c7426b3, 0x4974970ec54db, 0x4974a2099662a,
+    0x49750c2cd0d7b, 0x4975605a6922e, 0x4975d57874ace, 0x4975e41c361e2,
+    0x4976643512bd1, 0x49769b1b2825c, 0x4976f69aa1299, 0x4977103933afc,
+    0x4977471f49187, 0x4977ad9993313, 0x4978e108717b7, 0x4978efac32ecb,
+    0x49797e68d0fce, 0x497a5a01269fa, 0x497b6c7f91ab1, 0x497cda7d75ba5,
+    0x497d3253fe61d, 0x497eaef5a3e25, 0x497ecfe617212, 0x497f32b770dd9,
+    0x497f415b324ed, 0x497f450422ab2, 0x497fcc6ee002b, 0x4980f4e2ed380,
+    0x49810386aea94, 0x4981ea19d560f, 0x49823a9e7d4fd, 0x4983c5e3e4419,
+    0x49847939e5ece, 0x4984dc0b3fa95, 0x498528e6f73be, 0x498551294b335,
+    0x49863b6562475, 0x498742e8fc3dd, 0x49879abf84e55, 0x4987d54e8aaa5,
+    0x4987eeed1d308, 0x49886f05f9cf7, 0x4988bbe1b1620, 0x4989225bfb7ac,
+    0x4989643ce1f86, 0x498b01cefab7b, 0x498b26685e52d, 0x498bb524fc630,
+    0x498be4b931131, 0x498c687afe0e5, 0x498d02326d337, 0x498d356f923fd,
+    0x498da6e4ad6d8, 0x498e394a3bda0, 0x498e5de39f752, 0x498f14e2917cc,
+    0x498f531a879e1, 0x498f77b3eb393, 0x498f9c4d4ed45, 0x49907f37852fb,
+    0x49908a325644a, 0x4990b9c68af4b, 0x4990dab6fe338, 0x49912792b5c61,
+    0x49915726ea762, 0x499170c57cfc5, 0x4991b2a66379f, 0x4993abb7f53d1,
+    0x4993db4c29ed2, 0x4994715aa8b5f, 0x4994924b1bf4c, 0x49949d45ed09b,
+    0x4994fc6e5669d, 0x49952c028b19e, 0x49958782041db, 0x4995dbaf9c68e,
+    0x49960eecc1754, 0x4996d48f74ee2, 0x4996d838654a7, 0x4997634c12fe5,
+    0x4999a1e77b9b6, 0x499b3827b3a21, 0x499b815a7ad85, 0x499b93a72ca5e,
+    0x499c8c87052b2, 0x499d34e235c18, 0x499d681f5acde, 0x499efab6a2784,
+    0x499f319cb7e0f, 0x499f56361b7c1, 0x499fb55e84dc3, 0x49a0d2d7c0fc9,
+    0x49a17edbe1ef4, 0x49a1b96ae7b44, 0x49a235dad3f6e, 0x49a27069d9bbe,
+    0x49a2fb7d876fc, 0x49a365a0c1e4d, 0x49a377ed73b26, 0x49a3b62569d3b,
+    0x49a3d715dd128, 0x49a474763c93f, 0x49a4d74796506, 0x49a4f838098f3,
+    0x49a4ff89ea47d, 0x49a66635ed9e7, 0x49a7c1e71fe02, 0x49a903f9bf9ba,
+    0x49a984129c3a9, 0x49a9cd456370d, 0x49aa844455787, 0x49ab672e8bd3d,
+    0x49abc2ae04d7a, 0x49abe39e78167, 0x49ac1a848d7f2, 0x49ac29284ef06,
+    0x49ad170d5660b, 0x49ad4df36bc96, 0x49ad6ee3df083, 0x49ad9e7813b84,
+    0x49add55e2920f, 0x49ae81624a13a, 0x49af017b26b29, 0x49af853cf3add,
+    0x49afbfcbf972d, 0x49b00555d04cc, 0x49b09b644f159, 0x49b0e0ee25ef8,
+    0x49b101de992e5, 0x49b1e871bfe60, 0x49b3616a750a3, 0x49b37eb1f7ecb,
+    0x49b3b5980d556, 0x49b41fbb47ca7, 0x49b468ee0f00b, 0x49b4fefc8dc98,
+    0x49b5189b204fb, 0x49b5448664a37, 0x49b5d34302b3a, 0x49b6a3e087417,
+    0x49b6c879eadc9, 0x49b6ed134e77b, 0x49b77826fc2b9, 0x49b7d3a6752f6,
+    0x49b85eba22e34, 0x49b874afc50d2, 0x49b8af3ecad22, 0x49b920b3e5ffd,
+    0x49b941a4593ea, 0x49b95b42ebc4d, 0x49ba75133788e, 0x49bb719c006a7,
+    0x49bbd0c469ca9, 0x49bbea62fc50c, 0x49bc289af2721, 0x49bc2fecd32ab,
+    0x49bc7cc88abd4, 0x49bd92efe6250, 0x49bdc9d5fb8db, 0x49bf3f25c0559,
+    0x49bf96fc48fd1, 0x49c051a42b610, 0x49c063f0dd2e9, 0x49c1d597b19a2,
+    0x49c293e8845a6, 0x49c2bfd3c8ae2, 0x49c37a7bab121, 0x49c4108a29dae,
+    0x49c435238d760, 0x49c4b8e55a714, 0x49c4bc8e4acd9, 0x49c4c7891be28,
+    0x49c6233a4e243, 0x49c69faa3a66d, 0x49c6e8dd019d1, 0x49c756a92c6e7,
+    0x49c7da6af969b, 0x49c811510ed26, 0x49c85e2cc664f, 0x49c8866f1a5c6,
+    0x49c8fb8d25e66, 0x49c96d0241141, 0x49ca4fec776f7, 0x49cb45235f986,
+    0x49cb48cc4ff4b, 0x49cc504fe9eb3, 0x49cc74e94d865, 0x49ccb6ca3403f,
+    0x49cd24965ed55, 0x49cdc1f6be56c, 0x49ceb72da67fb, 0x49cec2287794a,
+    0x49cef1bcac44b, 0x49cf424154339, 0x49cf966eec7ec, 0x49cfb75f5fbd9,
+    0x49cfc603212ed, 0x49d0cd86bb255, 0x49d0e7254dab8, 0x49d116b9825b9,
+    0x49d15c4359358, 0x49d1673e2a4a7, 0x49d1b7c2d2395, 0x49d29aad0894b,
+    0x49d3d916b7f3e, 0x49d43be811b05, 0x49d4b1061d3a5, 0x49d5ce7f595ab,
+    0x49d5e0cc0b284, 0x49d694220cd39, 0x49d6d9abe3ad8, 0x49d74b20fedb3,
+    0x49d805c8e13f2, 0x49d8355d15ef3, 0x49d859f6798a5, 0x49d8aa7b21793,
+    0x49d8bcc7d346c, 0x49d90251aa20b, 0x49d9701dd4f21, 0x49dae1c4a95da,
+    0x49dbf7ec04c56, 0x49dc3623fae6b, 0x49dc6d0a104f6, 0x49dc954c6446d,
+    0x49dc9c9e44ff7, 0x49dccfdb6a0bd, 0x49dcf0cbdd4aa, 0x49ddb2c5a0673,
+    0x49dfba7af39b9, 0x49dff16109044, 0x49e05f2d33d5a, 0x49e0d7f42fbbf,
+    0x49e0fc8d93571, 0x49e11d7e0695e, 0x49e12878d7aad, 0x49e17ca66ff60,
+    0x49e3720f115cd, 0x49e38bada3e30, 0x49e3c9e59a045, 0x49e400cbaf6d0,
+    0x49e4b078c0bc0, 0x49e4bb7391d0f, 0x49e563cec2675, 0x49e667a96c018,
+    0x49e6fa0efa6e0, 0x49e7b85fcd2e4, 0x49e7bfb1ade6e, 0x49e7d5a75010c,
+    0x49e943a534200, 0x49e9733968d01, 0x49eb9f881f9f9, 0x49eba6da00583,
+    0x49ebb926b225c, 0x49ec2349ec9ad, 0x49ec47e35035f, 0x49ec7b2075425,
+    0x49ecb5af7b075, 0x49eccf4e0d8d8, 0x49ede57568f54, 0x49ee9c745afce,
+    0x49eed35a70659, 0x49eef44ae3a46, 0x49eefb9cc45d0, 0x49ef0a4085ce4,
+    0x49ef3d7daadaa, 0x49ef5e6e1e197, 0x49ef62170e75c, 0x49f0699aa86c4,
+    0x49f0db0fc399f, 0x49f11947b9bb4, 0x49f17870231b6, 0x49f1920eb5a19,
+    0x49f1abad4827c, 0x49f1b2ff28e06, 0x49f1d3ef9c1f3, 0x49f3493f60e71,
+    0x49f5d85f71730, 0x49f65fca2eca9, 0x49f66e6df03bd, 0x49f74daf363ae,
+    0x49f7dc6bd44b1, 0x49f8886ff53dc, 0x49f984f8be1f5, 0x49f9b835e32bb,
+    0x49fae0a9f0610, 0x49fb76b86f29d, 0x49fbf3285b6c7, 0x49fc858de9d8f,
+    0x49fcaa274d741, 0x49fd64cf2fd80, 0x49fe774d9ae37, 0x49ff26faac327,
+    0x4a0014dfb3a2c, 0x4a00c0e3d4957, 0x4a00f07809458, 0x4a01066dab6f6,
+    0x4a0115116ce0a, 0x4a01484e91ed0, 0x4a017f34a755b, 0x4a03a0888d104,
+    0x4a046282502cd, 0x4a053a71b5734, 0x4a057157cadbf, 0x4a05874d6d05d,
+    0x4a06a86f99828, 0x4a06cd08fd1da, 0x4a077cb60e6ca, 0x4a07c23fe5469,
+    0x4a082c631fbba, 0x4a08c9c37f3d1, 0x4a09721eafd37, 0x4a09930f23124,
+    0x4a09cd9e28d74, 0x4a0acdcfe2152, 0x4a0ad8cab32a1, 0x4a0c29811456d,
+    0x4a0d4e4c312fd, 0x4a0d853246988, 0x4a0de45aaff8a, 0x4a0e4ad4fa116,
+    0x4a0e681c7cf3e, 0x4a0fa6862c531, 0x4a10269f08f20, 0x4a116c5a9909d,
+    0x4a117afe5a7b1, 0x4a11ae3b7f877, 0x4a11c7da120da, 0x4a14ff55532ff,
+    0x4a1506a733e89, 0x4a1511a204fd8, 0x4a156226acec6, 0x4a1620777faca,
+    0x4a16b2dd0e192, 0x4a17af65d6fab, 0x4a18c1e442062, 0x4a18c93622bec,
+    0x4a19076e18e01, 0x4a193aab3dec7, 0x4a19e6af5edf2, 0x4a1a840fbe609,
+    0x4a1ae6e1181d0, 0x4a1bac83cb95e, 0x4a1c254ac77c3, 0x4a1ca563a41b2,
+    0x4a1ce39b9a3c7, 0x4a1e812db2fbc, 0x4a1e887f93b46, 0x4a1f606ef8fad,
+    0x4a1fa5f8cfd4c, 0x4a2055a5e123c, 0x4a2093ddd7451, 0x4a20a62a8912a,
+    0x4a20b8773ae03, 0x4a214adcc94cb, 0x4a215d297b1a4, 0x4a21731f1d442,
+    0x4a219f0a6197e, 0x4a21b8a8f41e1, 0x4a21d5f077009, 0x4a2385cf418d7,
+    0x4a2439254338c, 0x4a24d2dcb25de, 0x4a24e18073cf2, 0x4a252e5c2b61b,
+    0x4a257b37e2f44, 0x4a25f7a7cf36e, 0x4a264f7e57de6, 0x4a284c38d9fdd,
+    0x4a285e858bcb6, 0x4a287f75ff0a3, 0x4a29416fc226c, 0x4a297103f6d6d,
+    0x4a29f4c5c3d21, 0x4a2afc495dc89, 0x4a2b0aed1f39d, 0x4a2b78b94a0b3,
+    0x4a2c2c0f4bb68, 0x4a2c4cffbef55, 0x4a2d24ef243bc, 0x4a2d8b696e548,
+    0x4a2da15f107e6, 0x4a2f304d67cc7, 0x4a2f5fe19c7c8, 0x4a2f6e855dedc,
+    0x4a2fd8a89862d, 0x4a309aa25b7f6, 0x4a31888762efb, 0x4a33d21d9ca1b,
+    0x4a351f2b0d722, 0x4a3638fb59363, 0x4a36738a5efb3, 0x4a3726e060a68,
+    0x4a377eb6e94e0, 0x4a3794ac8b77e, 0x4a37d68d71f58, 0x4a3873edd176f,
+    0x4a3889e373a0d, 0x4a391c49020d5, 0x4a39d6f0e4714, 0x4a3a7f4c1507a,
+    0x4a3ab9db1acca, 0x4a3c69b9e5598, 0x4a3c74b4b66e7, 0x4a3c95a529ad4,
+    0x4a3c9cf70a65e, 0x4a3d121515efe, 0x4a3ddb60b9c51, 0x4a3eabfe3e52e,
+    0x4a40d0fb1469c, 0x4a41043839762, 0x4a4112dbfae76, 0x4a411a2ddba00,
+    0x4a414d6b00ac6, 0x4a41b78e3b217, 0x4a42e3ab38b31, 0x4a43552053e0c,
+    0x4a445ca3edd74, 0x4a44fdad3db50, 0x4a45a25f7def1, 0x4a4734f6c5997,
+    0x4a47389fb5f5c, 0x4a47bc6182f10, 0x4a49390328718, 0x4a49564aab540,
+    0x4a4990d9b1190, 0x4a49fafceb8e1, 0x4a4bb22d96d39, 0x4a4be913ac3c4,
+    0x4a4c483c159c6, 0x4a4c56dfd70da, 0x4a4d32782cb06, 0x4a4d5aba80a7d,
+    0x4a4da3ed47de1, 0x4a4dff6cc0e1e, 0x4a4e992430070, 0x4a4f27e0ce173,
+    0x4a4f7c0e66626, 0x4a4fa0a7c9fd8, 0x4a4fe631a0d77, 0x4a505b4fac617,
+    0x4a509235c1ca2, 0x4a50f5071b869, 0x4a5141e2d3192, 0x4a51afaefdea8,
+    0x4a5333a28423a, 0x4a538b790ccb2, 0x4a53c25f2233d, 0x4a53f945379c8,
+    0x4a541dde9b37a, 0x4a54c9e2bc2a5, 0x4a54f976f0da6, 0x4a55305d06431,
+    0x4a554da489259, 0x4a55514d7981e, 0x4a5567431babc, 0x4a559e2931147,
+    0x4a55d8b836d97, 0x4a56a955bb674, 0x4a56b7f97cd88, 0x4a571721e638a,
+    0x4a57c326072b5, 0x4a57d91ba9553, 0x4a581ea5802f2, 0x4a588176d9eb9,
+    0x4a58b10b0e9ba, 0x4a590c8a879f7, 0x4a591b2e4910b, 0x4a594e6b6e1d1,
+    0x4a596f5be15be, 0x4a59e479ece5e, 0x4a59f6c69eb37, 0x4a5afe4a38a9f,
+    0x4a5bd6399df06, 0x4a5c23155582f, 0x4a5c90e180545, 0x4a5cc7c795bd0,
+    0x4a5d09a87c3aa, 0x4a5d2a98ef797, 0x4a5d31ead0321, 0x4a5d7774a70c0,
+    0x4a5d98651a4ad, 0x4a5dc0a76e424, 0x4a5dddeef124c, 0x4a5de540d1dd6,
+    0x4a5e56b5ed0b1, 0x4a5f1506bfcb5, 0x4a5f6cdd4872d, 0x4a5f742f292b7,
+    0x4a60c88e7ab48, 0x4a61d3bb05075, 0x4a61e25ec6789, 0x4a6274c454e51,
+    0x4a62d043cde8e, 0x4a6420fa2f15a, 0x4a64508e63c5b, 0x4a64ea45d2ead,
+    0x4a65be8c47d4f, 0x4a6628af824a0, 0x4a66bebe0112d, 0x4a66fcf5f7342,
+    0x4a673b2ded557, 0x4a6775bcf31a7, 0x4a67ee83ef00c, 0x4a689a880ff37,
+    0x4a68c67354473, 0x4a68f25e989af, 0x4a6904ab4a688, 0x4a69677ca424f,
+    0x4a69a5b49a464, 0x4a6b559364d32, 0x4a6c103b47371, 0x4a6c387d9b2e8,
+    0x4a6c4aca4cfc1, 0x4a6c55c51e110, 0x4a6c6bbac03ae, 0x4a6d091b1fbc5,
+    0x4a6d6843891c7, 0x4a6d81e21ba2a, 0x4a6ee4e52e9cf, 0x4a6eec370f559,
+    0x4a6ef388f00e3, 0x4a6f7e9c9dc21, 0x4a70025e6abd5, 0x4a708d7218713,
+    0x4a71272987965, 0x4a71da7f8941a, 0x4a72693c2751d, 0x4a739902153fc,
+    0x4a73ab4ec70d5, 0x4a74699f99cd9, 0x4a74958ade215, 0x4a7724aaeead4,
+    0x4a77b3678cbd7, 0x4a78805c20eef, 0x4a79a5273dc7f, 0x4a79c617b106c,
+    0x4a7ad1443b599, 0x4a7adc3f0c6e8, 0x4a7d34790791c, 0x4a7d93a170f1e,
+    0x4a7eef52a3339, 0x4a81c7a57af5c, 0x4a8268aecad38, 0x4a838d79e7ac8,
+    0x4a83cf5ace2a2, 0x4a83feef02da3, 0x4a8414e4a5041, 0x4a842e83378a4,
+    0x4a845e176c3a5, 0x4a854bfc73aaa, 0x4a858a3469cbf, 0x4a858ddd5a284,
+    0x4a85cc1550499, 0x4a877bf41ad67, 0x4a87ba2c10f7c, 0x4a8853e3801ce,
+    0x4a887fcec470a, 0x4a88d7a54d182, 0x4a897505ac999, 0x4a89af94b25e9,
+    0x4a8b8f07b19b8, 0x4a8baff824da5, 0x4a8dc2a84923a, 0x4a8e550dd7902,
+    0x4a8e79a73b2b4, 0x4a8eb43640f04, 0x4a8f9ac967a7f, 0x4a8fad1619758,
+    0x4a9131099faea, 0x4a93f70fc5a34, 0x4a947728a2423, 0x4a953ccb55bb1,
+    0x4a95a6ee90302, 0x4a95ae4070e8c, 0x4a95e17d95f52, 0x4a977f0faeb47,
+    0x4a978db37025b, 0x4a987f4167f25, 0x4a988de529639, 0x4a98ab2cac461,
+    0x4a98f45f737c5, 0x4a99782140779, 0x4a9b95cc35d5d, 0x4a9ba0c706eac,
+    0x4a9bbe0e89cd4, 0x4a9c45794724d, 0x4a9c62c0ca075, 0x4a9cc1e933677,
+    0x4a9d07730a416, 0x4a9d0ec4eafa0, 0x4a9dac254a7b7, 0x4a9db3772b341,
+    0x4a9dc5c3dd01a, 0x4a9e164884f08, 0x4a9f92ea2a710, 0x4a9ff5bb842d7,
+    0x4aa0dff79b417, 0x4aa1725d29adf, 0x4aa1f276064ce, 0x4aa29e7a273f9,
+    0x4aa36b6ebb711, 0x4aa447071113d, 0x4aa57e1edfba6, 0x4aa5e0f03976d,
+    0x4aa6f71794de9, 0x4aa7738781213, 0x4aa79f72c574f, 0x4aa7cf06fa250,
+    0x4aa873b93a5f1, 0x4aa8d68a941b8, 0x4aa91fbd5b51c, 0x4aa96547322bb,
+    0x4aa99fd637f0b, 0x4aaa7073bc7e8, 0x4aaad345163af, 0x4aab2b1b9ee27,
+    0x4aabdac8b0317, 0x4aac030b0428e, 0x4aacab6634bf4, 0x4aacba09f6308,
+    0x4aad3a22d2cf7, 0x4aad3dcbc32bc, 0x4aad4c6f849d0, 0x4aaee658ad000,
+    0x4aafba9f21ea2, 0x4aafd43db4705, 0x4ab078eff4aa6, 0x4ab09d8958458,
+    0x4ab0fcb1c1a5a, 0x4ab1423b987f9, 0x4ab1a16401dfb, 0x4ab1ee3fb9724,
+    0x4ab2eac88253d, 0x4ab32ca968d17, 0x4ab337a439e66, 0x4ab3f99dfd02f,
+    0x4ab47d5fc9fe3, 0x4ab6383965a00, 0x4ab64a86176d9, 0x4ab708d6ea2dd,
+    0x4ab74365eff2d, 0x4ab764566331a, 0x4ab77df4f5b7d, 0x4ab7d5cb7e5f5,
+    0x4ab8f6edaadc0, 0x4abaae1e56218, 0x4abb1498a03a4, 0x4abb1f93714f3,
+    0x4abbf782d695a, 0x4abc027da7aa9, 0x4abc35baccb6f, 0x4abc6ca0e21fa,
+    0x4abd14fc12b60, 0x4abd18a503125, 0x4abd239fd4274, 0x4abdb25c72377,
+    0x4abde94287a02, 0x4abef46f11f2f, 0x4abf4fee8af6c, 0x4abfe5fd09bf9,
+    0x4ac074b9a7cfc, 0x4ac0bdec6f060, 0x4ac1196be809d, 0x4ac153faedced,
+    0x4ac15ef5bee3c, 0x4ac18ae103378, 0x4ac328731bf6d, 0x4ac32fc4fcaf7,
+    0x4ac3ac34e8f21, 0x4ac40062813d4, 0x4ac40b5d52523, 0x4ac45be1fa411,
+    0x4ac5dc2c901de, 0x4ac69e26533a7, 0x4ac6a57833f31, 0x4ac73434d2034,
+    0x4ac74a2a742d2, 0x4ac7552545421, 0x4ac8ce1dfa664, 0x4ac8d918cb7b3,
+    0x4acb97cd10b73, 0x4acbefa3995eb, 0x4acc9455d998c, 0x4acca6a28b665,
+    0x4acdaa7d35008, 0x4acde50c3ac58, 0x4acee8e6e45fb, 0x4acfaae0a77c4,
+    0x4acff06a7e563, 0x4ad18a53a6b93, 0x4ad1954e77ce2, 0x4ad1b63eeb0cf,
+    0x4ad3d3e9e06b3, 0x4ad4ccc9b8f07, 0x4ad541e7c47a7, 0x4ad54939a5331,
+    0x4ad6496b5e70f, 0x4ad6df79dd39c, 0x4ad70f0e11e9d, 0x4ad74d46080b2,
+    0x4ad792cfdee51, 0x4ad79678cf416, 0x4ad8dfdd4fb58, 0x4ad97994bedaa,
+    0x4ad9d8bd283ac, 0x4ad9e00f08f36, 0x4ad9fd568bd5e, 0x4adb3f692b916,
+    0x4adb64028f2c8, 0x4adc0162eeadf, 0x4adc25fc52491, 0x4adeaa2191c01,
+    0x4adfb54e1c12e, 0x4adfc048ed27d, 0x4adffad7f2ecd, 0x4ae01bc8662ba,
+    0x4ae0b1d6e4f47, 0x4ae36ce239d42, 0x4ae3e5a935ba7, 0x4ae4e983df54a,
+    0x4ae5e60ca8363, 0x4ae648de01f2a, 0x4ae68e67d8cc9, 0x4ae6db43905f2,
+    0x4ae7c57fa7732, 0x4ae7edc1fb6a9, 0x4ae8961d2c00f, 0x4ae8d7fe127e9,
+    0x4ae924d9ca112, 0x4ae95816ef1d8, 0x4ae9f9203efb4, 0x4aeb9a5b4816e,
+    0x4aec84975f2ae, 0x4aee30cd395b7, 0x4aef5cea36ed1, 0x4aef8c7e6b9d2,
+    0x4aefcab661be7, 0x4af0fe254008b, 0x4af14758073ef, 0x4af2e4ea1ffe4,
+    0x4af34b646a170, 0x4af37ea18f236, 0x4af486252919e, 0x4af5272e78f7a,
+    0x4af52ad76953f, 0x4af561bd7ebca, 0x4af58656e257c, 0x4af5bd3cf7c07,
+    0x4af623b741d93, 0x4af6e5b104f5c, 0x4af77bbf83be9, 0x4af77f68741ae,
+    0x4afa665f0d4e5, 0x4afaf8c49bbad, 0x4afbbe674f33b, 0x4afbedfb83e3c,
+    0x4afd0f1db0607, 0x4afdb027003e3, 0x4afe0ba679420, 0x4b013bcfd9abb,
+    0x4b017db0c0295, 0x4b03104807d3b, 0x4b04b52c014ba, 0x4b04d273842e2,
+    0x4b05563551296, 0x4b06b9386423b, 0x4b0747f50233e, 0x4b07653c85166,
+    0x4b0768e57572b, 0x4b07e1ac71590, 0x4b08c0edb7581, 0x4b095aa5267d3,
+    0x4b09f45c95a25, 0x4b0b157ec21f0, 0x4b0b9ce97f769, 0x4b0bc52bd36e0,
+    0x4b0bed6e27657, 0x4b0bf11717c1c, 0x4b0cabbefa25b, 0x4b0d83ae5f6c2,
+    0x4b0da0f5e24ea, 0x4b0e8edae9bef, 0x4b0e9283da1b4, 0x4b0fd83f6a331,
+    0x4b102c6d027e4, 0x4b10510666196, 0x4b10b02ecf798, 0x4b1121a3eaa73,
+    0x4b1280fe0d453, 0x4b146b6bdd971, 0x4b1529bcb0575, 0x4b15644bb61c5,
+    0x4b156f4687314, 0x4b16b8ab07a56, 0x4b171b7c6161d, 0x4b17351af3e80,
+    0x4b17909a6cebd, 0x4b17c3d791f83, 0x4b19825a1df65, 0x4b199bf8b07c8,
+    0x4b19cb8ce52c9, 0x4b1af400f261e, 0x4b1cf46464dda, 0x4b1d39ee3bb79,
+    0x4b1dc158f90f2, 0x4b1e4171d5ae1, 0x4b1f454c7f484, 0x4b20f52b49d52,
+    0x4b20fc7d2a8dc, 0x4b210ec9dc5b5, 0x4b228b6b81dbd, 0x4b22cd4c68597,
+    0x4b240bb617b8a, 0x4b24429c2d215, 0x4b246e8771751, 0x4b25e02e45e0a,
+    0x4b26ce134d50f, 0x4b29314819892, 0x4b29fe3cadbaa, 0x4b2ad62c13011,
+    0x4b2aefcaa5874, 0x4b2afac5769c3, 0x4b2b43f83dd27, 0x4b2b9f77b6d64,
+    0x4b2c05f200ef0, 0x4b2c10ecd203f, 0x4b2c5a1f993a3, 0x4b2c8261ed31a,
+    0x4b2d111e8b41d, 0x4b2e0da754236, 0x4b2e1c4b1594a, 0x4b2ea75ec3488,
+    0x4b2f361b6158b, 0x4b2f486813264, 0x4b2fd724b1367, 0x4b2ffbbe14d19,
+    0x4b307bd6f1708, 0x4b30a4194567f, 0x4b32a47cb7e3b, 0x4b344d09a1b7f,
+    0x4b3450b292144, 0x4b3471a305531, 0x4b347c9dd6680, 0x4b34a8891abbc,
+    0x4b35339cc86fa, 0x4b35548d3bae7, 0x4b358421705e8, 0x4b35a8bad3f9a,
+    0x4b378bd6c392e, 0x4b396ef2b32c2, 0x4b39bf775b1b0, 0x4b39c3204b775,
+    0x4b3ca2c503f22, 0x4b3d43ce53cfe, 0x4b3d6fb99823a, 0x4b3eae234782d,
+    0x4b3ec06ff9506, 0x4b3f4f2c97609, 0x4b3f89bb9d259, 0x4b403968ae749,
+    0x4b410daf235eb, 0x4b412af6a6413, 0x4b438e2b72796, 0x4b43c1689785c,
+    0x4b4403497e036, 0x4b4515c7e90ed, 0x4b4520c2ba23c, 0x4b4553ffdf302,
+    0x4b459d32a6666, 0x4b464936c7591, 0x4b468ec09e330, 0x4b46afb11171d,
+    0x4b46c5a6b39bb, 0x4b46d0a184b0a, 0x4b47964438298, 0x4b47b38bbb0c0,
+    0x4b499df98b5de, 0x4b49fd21f4be0, 0x4b4af9aabd9f9, 0x4b4b602507b85,
+    0x4b4b84be6b537, 0x4b4b970b1d210, 0x4b4beee1a5c88, 0x4b4bf28a9624d,
+    0x4b4c30c28c462, 0x4b4c9e8eb7178, 0x4b4cb48459416, 0x4b4ceb6a6eaa1,
+    0x4b4d3f9806f54, 0x4b4dd94f761a6, 0x4b4ebc39ac75c, 0x4b51c7c9a9445,
+    0x4b51e1683bca8, 0x4b5289c36c60e, 0x4b52e199f5086, 0x4b5418b1c3aef,
+    0x4b547b831d6b6, 0x4b548dcfcf38f, 0x4b54cc07c55a4, 0x4b54daab86cb8,
+    0x4b554c20a1f93, 0x4b55571b730e2, 0x4b55de863065b, 0x4b5686e160fc1,
+    0x4b57f13654af0, 0x4b5895e894e91, 0x4b59961a4e26f, 0x4b5b6e3b6cab4,
+    0x4b5b71e45d079, 0x4b5d3b61ba1aa, 0x4b5d9a8a237ac, 0x4b5db428b600f,
+    0x4b5deb0ecb69a, 0x4b5deeb7bbc5f, 0x4b5ecdf901c50, 0x4b5f2d216b252,
+    0x4b5f43170d4f0, 0x4b5f939bb53de, 0x4b5fd57c9bbb8, 0x4b5fe7c94d891,
+    0x4b607dd7cc51e, 0x4b61b4ef9af87, 0x4b64f765ad2fb, 0x4b64feb78de85,
+    0x4b66063b27ded, 0x4b662382aac15, 0x4b665a68c02a0, 0x4b66690c819b4,
+    0x4b66e1d37d819, 0x4b672eaf35142, 0x4b67c866a4394, 0x4b67e20536bf7,
+    0x4b68746ac52bf, 0x4b68bd9d8c623, 0x4b694c5a2a726, 0x4b69ab8293d28,
+    0x4b6ac1a9ef3a4, 0x4b6adb4881c07, 0x4b6ae9ec4331b, 0x4b6b2bcd29af5,
+    0x4b6b78a8e141e, 0x4b6c877e5bf10, 0x4b6d33827ce3b, 0x4b6d5bc4d0db2,
+    0x4b6dc5e80b503, 0x4b6eb02422643, 0x4b6fc64b7dcbf, 0x4b6ff5dfb27c0,
+    0x4b701327355e8, 0x4b70ca2627662, 0x4b716b2f7743e, 0x4b718125196dc,
+    0x4b71f64324f7c, 0x4b732d5af39e5, 0x4b736f3bda1bf, 0x4b73c71262c37,
+    0x4b75266c85617, 0x4b75684d6bdf1, 0x4b7576f12d505, 0x4b76d2a25f920,
+    0x4b77f76d7c6b0, 0x4b787b2f49664, 0x4b794823dd97c, 0x4b797066318f3,
+    0x4b7977b81247d, 0x4b79c83cba36b, 0x4b7a77e9cb85b, 0x4b7aed07d70fb,
+    0x4b7b18f31b637, 0x4b7c1ccdc4fda, 0x4b7d628955157, 0x4b7da8132bef6,
+    0x4b7dccac8f8a8, 0x4b7ded9d02c95, 0x4b7df145f325a, 0x4b7e41ca9b148,
+    0x4b7e491c7bcd2, 0x4b7f2c06b2288, 0x4b7f83dd3ad00, 0x4b7f9280fc414,
+    0x4b7fc96711a9f, 0x4b81425fc6ce2, 0x4b81b77dd2582, 0x4b83c2dc15e8d,
+    0x4b83eb1e69e04, 0x4b840c0edd1f1, 0x4b850fe986b94, 0x4b854a788c7e4,
+    0x4b85e0870b471, 0x4b870ca408d8b, 0x4b87682381dc8, 0x4b8bae743dadf,
+    0x4b8c4f7d8d8bb, 0x4b8df4618703a, 0x4b8e65d6a2315, 0x4b8ee5ef7ed04,
+    0x4b8f78550d3cc, 0x4b8ff4c4f97f6, 0x4b90ba67acf84, 0x4b90f14dc260f,
+    0x4b90f4f6b2bd4, 0x4b90f89fa3199, 0x4b910aec54e72, 0x4b9136d7993ae,
+    0x4b92b7222f17b, 0x4b92e6b663c7c, 0x4b93a15e462bb, 0x4b93bea5c90e3,
+    0x4b93f58bde76e, 0x4b9425201326f, 0x4b94d4cd2475f, 0x4b95513d10b89,
+    0x4b956e84939b1, 0x4b95847a35c4f, 0x4b96bb92046b8, 0x4b9742fcc1c31,
+    0x4b977639e6cf7, 0x4b98bbf576e74, 0x4b994709249b2, 0x4b99d96eb307a,
+    0x4b99f6b635ea2, 0x4b9b351fe5495, 0x4b9bced7546e7, 0x4b9c7adb75612,
+    0x4b9c7e8465bd7, 0x4b9db94524c05, 0x4b9e2aba3fee0, 0x4b9e5a4e749e1,
+    0x4b9ed6be60e0b, 0x4ba0d378e3002, 0x4ba18325f44f2, 0x4ba191c9b5c06,
+    0x4ba1f843ffd92, 0x4ba3d0651e5d7, 0x4ba607aea641e, 0x4ba6c25688a5d,
+    0x4ba6f1eabd55e, 0x4ba792f40d33a, 0x4ba79deede489, 0x4ba86e8c62d66,
+    0x4ba8acc458f7b, 0x4ba8cdb4cc368, 0x4ba91e3974256, 0x4ba93b80f707e,
+    0x4ba993577faf6, 0x4ba9e78517fa9, 0x4baa973229499, 0x4baad9130fc73,
+    0x4baafdac73625, 0x4bab592bec662, 0x4babef3a6b2ef, 0x4babfa353c43e,
+    0x4bac645876b8f, 0x4bac9043bb0cb, 0x4bae5d6a087c1, 0x4baeb197a0c74,
+    0x4baecedf23a9c, 0x4baf64eda2729, 0x4bafc4160bd2b, 0x4bb061766b542,
+    0x4bb0dde65796c, 0x4bb1a731fb6bf, 0x4bb4f84bcf147, 0x4bb532dad4d97,
+    0x4bb592033e399, 0x4bb5dedef5cc2, 0x4bb615c50b34d, 0x4bb6330c8e175,
+    0x4bb653fd01562, 0x4bb6e6628fc2a, 0x4bb70afbf35dc, 0x4bb778c81e2f2,
+    0x4bb83ac1e14bb, 0x4bb9eaa0abd89, 0x4bbaf22445cf1, 0x4bbb589e8fe7d,
+    0x4bbbeb041e545, 0x4bbc97083f470, 0x4bbcd8e925c4a, 0x4bbce3e3f6d99,
+    0x4bbd9e8bd93d8, 0x4bbe2d48774db, 0x4bbf0c89bd4cc, 0x4bbf5d0e653ba,
+    0x4bc017b6479f9, 0x4bc09f2104f72, 0x4bc14b2525e9d, 0x4bc1aa4d8f49f,
+    0x4bc34b8898659, 0x4bc373caec5d0, 0x4bc3fede9a10e, 0x4bc4989609360,
+    0x4bc7d3ba3ab4a, 0x4bc8786c7aeeb, 0x4bc93a663e0b4, 0x4bca8773aedbb,
+    0x4bcb5468430d3, 0x4bcc842e30fb2, 0x4bcd33db424a2, 0x4bcdd13ba1cb9,
+    0x4bce4dab8e0e3, 0x4bce7d3fc2be4, 0x4bcea1d926596, 0x4bcf6029f919a,
+    0x4bcfca4d338eb, 0x4bd004dc3953b, 0x4bd0605bb2578, 0x4bd06b56836c7,
+    0x4bd072a864251, 0x4bd125fe65d06, 0x4bd13bf407fa4, 0x4bd1b4bb03e09,
+    0x4bd229d90f6a9, 0x4bd33c577a760, 0x4bd37e3860f3a, 0x4bd3b17586000,
+    0x4bd5022be72cc, 0x4bd52a6e3b243, 0x4bd5fb0bbfb20, 0x4bd7c8320d216,
+    0x4bd89526a152e, 0x4bd902f2cc244, 0x4bd9e5dd027fa, 0x4bda9933042af,
+    0x4bdb06ff2efc5, 0x4bdb7fc62ae2a, 0x4bdc24786b1cb, 0x4bdc5063af707,
+    0x4bdd7c80ad021, 0x4bddf8f09944b, 0x4bde004279fd5, 0x4bde16381c273,
+    0x4bde45cc50d74, 0x4bdefccb42dee, 0x4bdf0b6f04502, 0x4be0378c01e1c,
+    0x4be0ee8af3e96, 0x4be10829866f9, 0x4be1b42da7624, 0x4be1d51e1aa11,
+    0x4be34e16cfc54, 0x4be41eb454531, 0x4be4643e2b2d0, 0x4be47ddcbdb33,
+    0x4be490296f80c, 0x4be4bc14b3d48, 0x4be4d20a55fe6, 0x4be4e45707cbf,
+    0x4be5ada2aba12, 0x4be5bc466d126, 0x4be659a6cc93d, 0x4be7e14343294,
+    0x4be95de4e8a9c, 0x4beada868e2a4, 0x4beb151593ef4, 0x4beb868aaf1cf,
+    0x4bec06a38bbbe, 0x4bed8e4002515, 0x4bee7fcdfa1df, 0x4bf0927e1e674,
+    0x4bf3a1b70b922, 0x4bf3c2a77ed0f, 0x4bf3f98d9439a, 0x4bf46759bf0b0,
+    0x4bf479a670d89, 0x4bf4ca2b18c77, 0x4bf558e7b6d7a, 0x4bf655707fb93,
+    0x4bf6d5895c582, 0x4bf73fac96cd3, 0x4bf95d578c2b7, 0x4bf96bfb4d9cb,
+    0x4bfae4f402c0e, 0x4bfb89a642faf, 0x4bfcaac86f77a, 0x4bfce1ae84e05,
+    0x4bfcfb4d17668, 0x4bfd56cc906a5, 0x4bfe65a20b197, 0x4c0053b8cbc7a,
+    0x4c009ceb92fde, 0x4c00b2e13527c, 0x4c00ba3315e06, 0x4c014546c3944,
+    0x4c018727aa11e, 0x4c033aaf64fb1, 0x4c04806af512e, 0x4c04ac563966a,
+    0x4c05042cc20e2, 0x4c0528c625a94, 0x4c0575a1dd3bd, 0x4c065c3503f38,
+    0x4c0675d39679b, 0x4c06bb5d6d53a, 0x4c06e748b1a76, 0x4c0842f9e3e91,
+    0x4c08519da55a5, 0x4c093bd9bc6e5, 0x4c098c5e645d3, 0x4c09a9a5e73fb,
+    0x4c09f6819ed24, 0x4c0ab12981363, 0x4c0ca2e93240b, 0x4c0cd27d66f0c,
+    0x4c0eda32ba252, 0x4c0f0d6fdf318, 0x4c10c4a08a770, 0x4c10d6ed3c449,
+    0x4c12ab656a6c9, 0x4c134c6eba4a5, 0x4c1391f891244, 0x4c14872f794d3,
+    0x4c14a0ce0bd36, 0x4c14c1be7f123, 0x4c14d7b4213c1, 0x4c15507b1d226,
+    0x4c155f1ede93a, 0x4c1578bd7119d, 0x4c15df37bb329, 0x4c160b22ff865,
+    0x4c1624c1920c8, 0x4c165455c6bc9, 0x4c16e6bb55291, 0x4c17966866781,
+    0x4c17d8494cf5b, 0x4c1833c8c5f98, 0x4c192ca89e7ec, 0x4c1972327558b,
+    0x4c1b8fdd6ab6f, 0x4c1bfa00a52c0, 0x4c1cb4a8878ff, 0x4c1cce471a162,
+    0x4c1d67fe893b4, 0x4c1d76a24aac8, 0x4c1ddd1c94c54, 0x4c1efe3ec141f,
+    0x4c1f09399256e, 0x4c1f7aaead849, 0x4c1f82008e3d3, 0x4c1fb8e6a3a5e,
+    0x4c1ffe707a7fd, 0x4c208232477b1, 0x4c233994abfe7, 0x4c23448f7d136,
+    0x4c24ed1c66e7a, 0x4c26a0a421d0d, 0x4c273e0481524, 0x4c27d7bbf0776,
+    0x4c2841df2aec7, 0x4c286a217ee3e, 0x4c28fc870d506, 0x4c2b5fbbd9889,
+    0x4c2c7d3515a8f, 0x4c2d8c0a90581, 0x4c2eca743fb74, 0x4c2ee7bbc299c,
+    0x4c2efa0874675, 0x4c2f642baedc6, 0x4c2fe4448b7b5, 0x4c3138a3dd046,
+    0x4c32fe7849bb2, 0x4c337ae835fdc, 0x4c33a32a89f53, 0x4c344433d9d2f,
+    0x4c3468cd3d6e1, 0x4c34cb9e972a8, 0x4c35396ac1fbe, 0x4c35b5daae3e8,
+    0x4c35d32231210, 0x4c37ed243622f, 0x4c38190f7a76b, 0x4c38240a4b8ba,
+    0x4c39a7fdd1c4c, 0x4c3b0757f462c, 0x4c3b5433abf55, 0x4c3b926ba216a,
+    0x4c3c12847eb59, 0x4c3c546565333, 0x4c3c8b4b7a9be, 0x4c3cd827322e7,
+    0x4c3d00698625e, 0x4c3d58400ecd6, 0x4c3d6e35b0f74, 0x4c3d967804eeb,
+    0x4c3deaa59d39e, 0x4c3ea54d7f9dd, 0x4c3edc3395068, 0x4c3f674742ba6,
+    0x4c3f80e5d5409, 0x4c40b0abc32e8, 0x4c40d8ee1725f, 0x4c40eb3ac8f38,
+    0x4c40f28ca9ac2, 0x4c4146ba41f75, 0x4c41555e03689, 0x4c437e03c9dbc,
+    0x4c43bfe4b0596, 0x4c449b7d05fc2, 0x4c44faa56f5c4, 0x4c4501f75014e,
+    0x4c465da882569, 0x4c4685ead64e0, 0x4c468d3cb706a, 0x4c4709aca3494,
+    0x4c484bbf4304c, 0x4c486cafb6439, 0x4c48d32a005c5, 0x4c48e1cdc1cd9,
+    0x4c494bf0fc42a, 0x4c4b91de45985, 0x4c4ba7d3e7c23, 0x4c4df8bc022cd,
+    0x4c4f21300f622, 0x4c4f99f70b487, 0x4c4fb3959dcea, 0x4c505f99bec15,
+    0x4c5092d6e3cdb, 0x4c50ac757653e, 0x4c51b3f9104a6, 0x4c51bb4af1030,
+    0x4c51c29cd1bba, 0x4c52cdc95c0e7, 0x4c53f63d6943c, 0x4c549aefa97dd,
+    0x4c550169f3969, 0x4c5526035731b, 0x4c5555978be1c, 0x4c55a9c5242cf,
+    0x4c563fd3a2f5c, 0x4c56437c93521, 0x4c564e7764670, 0x4c56d98b121ae,
+    0x4c56ef80b444c, 0x4c57649ebfcec, 0x4c579b84d5377, 0x4c5873743a7de,
+    0x4c58cb4ac3256, 0x4c5926ca3c293, 0x4c596502324a8, 0x4c5973a5f3bbc,
+    0x4c5a618afb2c1, 0x4c5ac8054544d, 0x4c5af79979f4e, 0x4c5c9182a257e,
+    0x4c5c98d483108, 0x4c5cc116d707f, 0x4c5eed658dd77, 0x4c5f27f4939c7,
+    0x4c5f3dea35c65, 0x4c5f4c8df7379, 0x4c5fe645665cb, 0x4c60a496391cf,
+    0x4c60a83f29794, 0x4c61459f88fab, 0x4c6162e70bdd3, 0x4c61a4c7f25ad,
+    0x4c62ea838272a, 0x4c639687a3655, 0x4c63c9c4c871b, 0x4c63eab53bb08,
+    0x4c644d86956cf, 0x4c64dfec23d97, 0x4c652575fab36, 0x4c65bf2d69d88,
+    0x4c65f26a8ee4e, 0x4c669ac5bf7b4, 0x4c66b0bb61a52, 0x4c66bbb632ba1,
+    0x4c674e1bc1269, 0x4c67606872f42, 0x4c6788aac6eb9, 0x4c67c339ccb09,
+    0x4c67d92f6eda7, 0x4c687a38beb83, 0x4c6aa6877587b, 0x4c6c7affa3afb,
+    0x4c6c825184685, 0x4c6ce522de24c, 0x4c6df3f858d3e, 0x4c6e09edfafdc,
+    0x4c6eb2492b942, 0x4c6f117194f44, 0x4c6f326208331, 0x4c6f6cf10df81,
+    0x4c6fb27ae4d20, 0x4c7153b5ededa, 0x4c73abefe910e, 0x4c741d65043e9,
+    0x4c7483df4e575, 0x4c75f1dd32669, 0x4c76cd7588095, 0x4c7728f5010d2,
+    0x4c7749e5744bf, 0x4c78b43a67fee, 0x4c79fd9ee8730, 0x4c7bb4cf93b88,
+    0x4c7e9bc62cebf, 0x4c7e9f6f1d484, 0x4c7ee8a1e47e8, 0x4c7f1836192e9,
+    0x4c808633fd3dd, 0x4c80b971224a3, 0x4c811feb6c62f, 0x4c814f7fa1130,
+    0x4c82a036023fc, 0x4c82ed11b9d25, 0x4c82ff5e6b9fe, 0x4c8448c2ec140,
+    0x4c84e6234b957, 0x4c84f86ffd630, 0x4c85be12b0dbe, 0x4c85e65504d35,
+    0x4c861d3b1a3c0, 0x4c87420637150, 0x4c875f4db9f78, 0x4c88ac5b2ac7f,
+    0x4c8912d574e0b, 0x4c8be77f5c469, 0x4c8c380404357, 0x4c8e2d6ca59c4,
+    0x4c8fd5f98f708, 0x4c8fd9a27fccd, 0x4c92e1898c3f1, 0x4c93f407f74a8,
+    0x4c945e2b31bf9, 0x4c94a75df8f5d, 0x4c956957bc126, 0x4c958a482f513,
+    0x4c95fbbd4a7ee, 0x4c961904cd616, 0x4c968e22d8eb6, 0x4c9836afc2bfa,
+    0x4c986643f76fb, 0x4c988add5b0ad, 0x4c98922f3bc37, 0x4c991d42e9775,
+    0x4c999260f5015, 0x4c99afa877e3d, 0x4c9a9d8d7f542, 0x4c9afcb5e8b44,
+    0x4c9b7ccec5533, 0x4c9d4d9e031ee, 0x4c9d723766ba0, 0x4c9e2cdf491df,
+    0x4c9e3b830a8f3, 0x4c9edc8c5a6cf, 0x4c9f13726fd5a, 0x4c9f6b48f87d2,
+    0x4ca0ad5b9838a, 0x4ca134c655903, 0x4ca1dd2186269, 0x4ca26bde2436c,
+    0x4ca2e84e10796, 0x4ca3c03d75bfd, 0x4ca3cb3846d4c, 0x4ca447a833176,
+    0x4ca4823738dc6, 0x4ca4ddb6b1e03, 0x4ca547d9ec554, 0x4ca70a0568afb,
+    0x4ca723a3fb35e, 0x4ca72af5dbee8, 0x4ca74be64f2d5, 0x4ca78dc735aaf,
+    0x4ca7de4bdd99d, 0x4ca7fb93607c5, 0x4ca8362266415, 0x4ca87f552d779,
+    0x4ca8c4df04518, 0x4ca8f47339019, 0x4ca9deaf50159, 0x4caaf87f9bd9a,
+    0x4cac5b82aed3f, 0x4cac78ca31b67, 0x4cac8b16e3840, 0x4cad3e6ce52f5,
+    0x4cad7ca4db50a, 0x4cad83f6bc094, 0x4cae165c4a75c, 0x4cae5f8f11ac0,
+    0x4caf49cb28c00, 0x4cafe72b88417, 0x4cb0c2c3dde43, 0x4cb10bf6a51a7,
+    0x4cb18c0f81b96, 0x4cb1e78efabd3, 0x4cb2e7c0b3fb1, 0x4cb2eb69a4576,
+    0x4cb36087afe16, 0x4cb36f2b7152a, 0x4cb37dcf32c3e, 0x4cb388ca03d8d,
+    0x4cb413ddb18cb, 0x4cb4904d9dcf5, 0x4cb49ef15f409, 0x4cb4dd295561e,
+    0x4cb510667a6e4, 0x4cb58cd666b0e, 0x4cb5adc6d9efb, 0x4cb5f6f9a125f,
+    0x4cb68d081feec, 0x4cb7405e219a1, 0x4cb7823f0817b, 0x4cb7f00b32e91,
+    0x4cb85a2e6d5e2, 0x4cb8b5ade661f, 0x4cb9b9888ffc2, 0x4cbba3f6604e0,
+    0x4cbc91db67be5, 0x4cbd786e8e760, 0x4cbdf1358a5c5, 0x4cc0fcc5872ae,
+    0x4cc145f84e612, 0x4cc1758c83113, 0x4cc1a177c764f, 0x4cc1bebf4a477,
+    0x4cc37d41d6459, 0x4cc3c6749d7bd, 0x4cc3f608d22be, 0x4cc576536808b,
+    0x4cc693cca4291, 0x4cc6e7fa3c744, 0x4cc70198cefa7, 0x4cc74acb9630b,
+    0x4cc822bafb772, 0x4cc8a67cc8726, 0x4cc8dd62dddb1, 0x4cc922ecb4b50,
+    0x4cc92695a5115, 0x4cc92a3e956da, 0x4cc990b8df866, 0x4cc9d9eba6bca,
+    0x4cc9f38a3942d, 0x4ccafb0dd3395, 0x4ccb3945c95aa, 0x4ccb89ca71498,
+    0x4ccb9c1723171, 0x4ccc2381e06ea, 0x4ccc5e10e633a, 0x4ccc9c48dc54f,
+    0x4ccd39a93bd66, 0x4ccd5347ce5c9, 0x4cce278e4346b, 0x4cce621d490bb,
+    0x4cd015a503f4e, 0x4cd078765db15, 0x4cd0c8fb05a03, 0x4cd195ef99d1b,
+    0x4cd68bed66f22, 0x4cd6d5202e286, 0x4cd73b9a78412, 0x4cd755390ac75,
+    0x4cd7b0b883cb2, 0x4cd846c70293f, 0x4cd89af49adf2, 0x4cd8ce31bfeb8,
+    0x4cd9559c7d431, 0x4cd9977d63c0b, 0x4cd9b86dd6ff8, 0x4cdb266bbb0ec,
+    0x4cdb7e4243b64, 0x4cdc77221c3b8, 0x4cdc896ece091, 0x4cdc94699f1e0,
+    0x4cdeb9667534e, 0x4ce03d59fb6e0, 0x4ce0744010d6b, 0x4ce4506d92331,
+    0x4ce5509f4b70f, 0x4ce583dc707d5, 0x4ce5a4cce3bc2, 0x4ce5f1a89b4eb,
+    0x4ce645d63399e, 0x4ce6ee3164304, 0x4ce7f20c0dca7, 0x4ce89a673e60d,
+    0x4cea58e9ca5ef, 0x4cec8538812e7, 0x4cec8c8a61e71, 0x4ced13f51f3ea,
+    0x4ced5d27e674e, 0x4ced6f7498427, 0x4cee40121cd04, 0x4cee734f41dca,
+    0x4cef4043d60e2, 0x4cef4ee7977f6, 0x4cef7380fb1a8, 0x4cefa6be2026e,
+    0x4cf068b7e3437, 0x4cf10d6a237d8, 0x4cf11c0de4eec, 0x4cf16540ac250,
+    0x4cf173e46d964, 0x4cf1912bf078c, 0x4cf2ecdd22ba7, 0x4cf3201a47c6d,
+    0x4cf361fb2e447, 0x4cf41ca310a86, 0x4cf42eefc275f, 0x4cf4b65a7fcd8,
+    0x4cf4c4fe413ec, 0x4cf4cff91253b, 0x4cf51582e92da, 0x4cf57fa623a2b,
+    0x4cf5f86d1f890, 0x4cf6af6c1190a, 0x4cf7123d6b4d1, 0x4cf720e12cbe5,
+    0x4cf7457a90597, 0x4cf762c2133bf, 0x4cf76dbce450e, 0x4cf892880129e,
+    0x4cfb304bd3271, 0x4cfbb064afc60, 0x4cfbd4fe13612, 0x4cfc083b386d8,
+    0x4cfc2cd49c08a, 0x4cfc63bab1715, 0x4cfd0fbed2640, 0x4cfd1e6293d54,
+    0x4cfd30af45a2d, 0x4cfdfda3d9d45, 0x4cfe9b043955c, 0x4cfef2dac1fd4,
+    0x4cffad82a4613, 0x4d007e2028ef0, 0x4d00b8af2eb40, 0x4d014ebdad7cd,
+    0x4d037b0c644c5, 0x4d03e186ae651, 0x4d04027721a3e, 0x4d0470434c754,
+    0x4d048d8acf57c, 0x4d04c819d51cc, 0x4d058a1398395, 0x4d05f7dfc30ab,
+    0x4d0648646af99, 0x4d06cc2637f4d, 0x4d07ed4864718, 0x4d08f874eec45,
+    0x4d09fff888bad, 0x4d0a9d58e83c4, 0x4d0b7c9a2e3b5, 0x4d0cbeaccdf6d,
+    0x4d0ce6ef21ee4, 0x4d0d008db4747, 0x4d0d07df952d1, 0x4d0d4d696c070,
+    0x4d0db03ac5c37, 0x4d0e130c1f7fe, 0x4d0f58c7af97b, 0x4d0f7266421de,
+    0x4d0f9aa896155, 0x4d0fa1fa76cdf, 0x4d118c68471fd, 0x4d11a606d9a60,
+    0x4d12b13363f8d, 0x4d13c008dea7f, 0x4d13ebf422fbb, 0x4d144ec57cb82,
+    0x4d1526b4e1fe9, 0x4d1589863bbb0, 0x4d15c06c5123b, 0x4d16442e1e1ef,
+    0x4d1673c252cf0, 0x4d16b94c29a8f, 0x4d175dfe69e30, 0x4d17aada21759,
+    0x4d189c6819423, 0x4d18b25dbb6c1, 0x4d19118624cc3, 0x4d1944c349d89,
+    0x4d1adb0381df4, 0x4d1b3682fae31, 0x4d1d03a948527, 0x4d1d0afb290b1,
+    0x4d1d124d09c3b, 0x4d1d542df0415, 0x4d1d78c753dc7, 0x4d1db35659a17,
+    0x4d1dd446cce04, 0x4d1e99e980592, 0x4d1ea88d41ca6, 0x4d1ef568f95cf,
+    0x4d1f16596c9bc, 0x4d1f5be34375b, 0x4d1f87ce87c97, 0x4d1f9672493ab,
+    0x4d20bee656700, 0x4d210f6afe5ee, 0x4d21accb5de05, 0x4d21eeac445df,
+    0x4d21f5fe25169, 0x4d228fb5943bb, 0x4d2346b486435, 0x4d2410002a188,
+    0x4d24d1f9ed351, 0x4d251783c40f0, 0x4d25c730d55e0, 0x4d2655ed736e3,
+    0x4d274ecd4bf37, 0x4d28ddbba3418, 0x4d29e8e82d945, 0x4d2c5717cae17,
+    0x4d2c5e69ab9a1, 0x4d2c7bb12e7c9, 0x4d2ccc35d66b7, 0x4d2ce97d594df,
+    0x4d2e1943473be, 0x4d2e53d24d00e, 0x4d2ed3eb299fd, 0x4d2f5b55e6f76,
+    0x4d305b87a0354, 0x4d30dba07cd43, 0x4d31287c3466c, 0x4d31e6cd07270,
+    0x4d32a8c6ca439, 0x4d32e6fec064e, 0x4d335c1ccbeee, 0x4d33bb45354f0,
+    0x4d340bc9dd3de, 0x4d3425686fc41, 0x4d344a01d35f3, 0x4d353f38bb882,
+    0x4d35a95bf5fd3, 0x4d35eb3cdc7ad, 0x4d37bc0c1a468, 0x4d3922b81d9d2,
+    0x4d395d4723622, 0x4d3a90b601ac6, 0x4d3ac04a365c7, 0x4d3be51553357,
+    0x4d3caab806ae5, 0x4d3e56ede0dee, 0x4d3ecc0bec68e, 0x4d3f696c4bea5,
+    0x4d415b2bfcf4d, 0x4d415ed4ed512, 0x4d41e296ba4c6, 0x4d41f88c5c764,
+    0x4d42be2f0fef2, 0x4d43c5b2a9e5a, 0x4d43f19dee396, 0x4d44213222e97,
+    0x4d456e3f93b9e, 0x4d45ac7789db3, 0x4d46462ef9005, 0x4d46ee8a2996b,
+    0x4d47047fcbc09, 0x4d476afa15d95, 0x4d47c6798edd2, 0x4d4ad2098babb,
+    0x4d4add045cc0a, 0x4d4af2f9feea8, 0x4d4b5222684aa, 0x4d4b9b552f80e,
+    0x4d4c40076fbaf, 0x4d4c4eab312c3, 0x4d4c60f7e2f9c, 0x4d4db90024df2,
+    0x4d4dc3faf5f41, 0x4d4e35701121c, 0x4d4e7ea2d8580, 0x4d4ecf278046e,
+    0x4d4eddcb41b82, 0x4d4f2e4fe9a70, 0x4d4f6535ff0fb, 0x4d4f9c1c14786,
+    0x4d4fe1a5eb525, 0x4d50ff1f2772b, 0x4d512b0a6bc67, 0x4d51e5b24e2a6,
+    0x4d54af61647b5, 0x4d54d3fac8167, 0x4d5519849ef06, 0x4d55a0ef5c47f,
+    0x4d55ccdaa09bb, 0x4d5636fddb10c, 0x4d563e4fbbc96, 0x4d59637e4b1e2,
+    0x4d5a75fcb6299, 0x4d5c4a74e4519, 0x4d5c9ea27c9cc, 0x4d5d4aa69d8f7,
+    0x4d5d5cf34f5d0, 0x4d5d67ee2071f, 0x4d5d6b9710ce4, 0x4d5dbc1bb8bd2,
+    0x4d5fdd6f9e77b, 0x4d6090c5a0230, 0x4d611f823e333, 0x4d61c4347e6d4,
+    0x4d637f0e1a0f1, 0x4d6382b70a6b6, 0x4d63cbe9d1a1a, 0x4d6439b5fc730,
+    0x4d646cf3217f6, 0x4d64f806cf334, 0x4d65488b77222, 0x4d65b2aeb1973,
+    0x4d65d39f24d60, 0x4d6670ff84577, 0x4d668a9e16dda, 0x4d66cc7efd5b4,
+    0x4d67195ab4edd, 0x4d6727fe765f1, 0x4d6732f947740, 0x4d675ee48bc7c,
+    0x4d6774da2df1a, 0x4d67defd6866b, 0x4d68875898fd1, 0x4d695f47fe438,
+    0x4d69ac23b5d61, 0x4d6a338e732da, 0x4d6a6322a7ddb, 0x4d6ad497c30b6,
+    0x4d6c588b49448, 0x4d6cc2ae83b99, 0x4d6d048f6a373, 0x4d6d37cc8f439,
+    0x4d6da941aa714, 0x4d700c7676a97, 0x4d713fe554f3b, 0x4d72f36d0fdce,
+    0x4d73cb5c75235, 0x4d74d2e00f19d, 0x4d7786998340e, 0x4d78538e17726,
+    0x4d7878277b0d8, 0x4d78a7bbafbd9, 0x4d78f0ee76f3d, 0x4d7adb5c4745b,
+    0x4d7b800e877fc, 0x4d7c9635e2e78, 0x4d7d5486b5a7c, 0x4d7d96679c256,
+    0x4d7dbb00ffc08, 0x4d7e301f0b4a8, 0x4d7f16b232023, 0x4d7f1e0412bad,
+    0x4d7f3ef485f9a, 0x4d7f514137c73, 0x4d7fc2b652f4e, 0x4d80d8ddae5ca,
+    0x4d818138def30, 0x4d81d90f679a8, 0x4d81e06148532, 0x4d81e7b3290bc,
+    0x4d827a18b7784, 0x4d8338698a388, 0x4d8388ee32276, 0x4d83dd1bca729,
+    0x4d84264e91a8d, 0x4d84648687ca2, 0x4d8497c3acd68, 0x4d84e0f6740cc,
+    0x4d8768c4a3e01, 0x4d87c4441ce3e, 0x4d884baeda3b7, 0x4d89448eb2c0b,
+    0x4d89c0fe9f035, 0x4d8cc53cbb194, 0x4d8dd7bb2624b, 0x4d8dedb0c84e9,
+    0x4d8df502a9073, 0x4d8e6a20b4913, 0x4d8ea4afba563, 0x4d9045eac371d,
+    0x4d908079c936d, 0x4d90b00dfde6e, 0x4d9112df57a35, 0x4d92882f1c6b3,
+    0x4d9334333d5de, 0x4d9379bd1437d, 0x4d940c22a2a45, 0x4d94a9830225c,
+    0x4d94d1c5561d3, 0x4d94e069178e7, 0x4d95851b57c88, 0x4d96ad8f64fdd,
+    0x4d96e4757a668, 0x4d971409af169, 0x4d9729ff51407, 0x4d98bc9698ead,
+    0x4d99da0fd50b3, 0x4d9b8645af3bc, 0x4d9bd6ca572aa, 0x4d9cf09aa2eeb,
+    0x4d9d44c83b39e, 0x4d9d4fc30c4ed, 0x4d9e5746a6455, 0x4d9e65ea67b69,
+    0x4d9eb2c61f492, 0x4d9ecc64b1cf5, 0x4d9f7116f2096, 0x4da0998aff3eb,
+    0x4da119a3dbdda, 0x4da1db9d9efa3, 0x4da2e32138f0b, 0x4da312b56da0c,
+    0x4da45870fdb89, 0x4da4796170f76, 0x4da6084fc8457, 0x4da60bf8b8a1c,
+    0x4da6b0aaf8dbd, 0x4da760580a2ad, 0x4da7ef14a83b0, 0x4da7fdb869ac4,
+    0x4da801615a089, 0x4da82d4c9e5c5, 0x4da8caacfdddc, 0x4da8ce55ee3a1,
+    0x4da9dd2b68e93, 0x4daab171ddd35, 0x4dab17ec27ec1, 0x4dac31bc73b02,
+    0x4dad4f35afd08, 0x4dafaec18bac6, 0x4dafdaacd0002, 0x4db039d539604,
+    0x4db11cbf6fbba, 0x4db2711ec144b, 0x4db2f1379de3a, 0x4db2f4e08e3ff,
+    0x4db3281db34c5, 0x4db387461cac7, 0x4db3c57e12cdc, 0x4db420fd8bd19,
+    0x4db4a11668708, 0x4db4b3631a3e1, 0x4db58057ae6f9, 0x4db5afebe31fa,
+    0x4db5fcc79ab23, 0x4db6793786f4d, 0x4db67ce077512, 0x4db6d10e0f9c5,
+    0x4db6dfb1d10d9, 0x4db6eaaca2228, 0x4db7b04f559b6, 0x4db8c676b1032,
+    0x4db8cdc891bbc, 0x4db8dc6c532d0, 0x4dba88a22d5d9, 0x4dbc1ee265644,
+    0x4dbc8cae9035a, 0x4dbcc73d95faa, 0x4dbd94322a2c2, 0x4dbe22eec83c5,
+    0x4dbe9463e36a0, 0x4dbf2320817a3, 0x4dbff3be06080, 0x4dc11c32133d5,
+    0x4dc174089be4d, 0x4dc2b2724b440, 0x4dc550361d413, 0x4dc59968e4777,
+    0x4dc5af5e86a15, 0x4dc62bce72e3f, 0x4dc72c002c21d, 0x4dc82137144ac,
+    0x4dc987e317a16, 0x4dcb6aff073aa, 0x4dccb80c780b1, 0x4dcd1734e16b3,
+    0x4dcd1e86c223d, 0x4dcd93a4cdadd, 0x4dcdb49540eca, 0x4dcdeb7b56555,
+    0x4dce1014b9f07, 0x4dcf60cb1b1d3, 0x4dcfe835d874c, 0x4dd0560203462,
+    0x4dd1478ffb12c, 0x4dd2cf2c71a83, 0x4dd3e8fcbd6c4, 0x4dd47b624bd8c,
+    0x4dd4ae9f70e52, 0x4dd4c83e036b5, 0x4dd6ff878b4fc, 0x4dd80ab415a29,
+    0x4dd9a8462e61e, 0x4dd9f178f5982, 0x4ddac2167a25f, 0x4ddb07a050ffe,
+    0x4ddcbb280be91, 0x4ddcf5b711ae1, 0x4ddd0f55a4344, 0x4ddd21a25601d,
+    0x4dddeaedf9d70, 0x4dddf5e8caebf, 0x4dde8bf749b4c, 0x4ddf58ebdde64,
+    0x4ddf84d7223a0, 0x4ddfa21ea51c8, 0x4ddfbf6627ff0, 0x4ddfdcadaae18,
+    0x4de07a0e0a62f, 0x4de0ce3ba2ae2, 0x4de1ad7ce8ad3, 0x4de438f408dcd,
+    0x4de5860179ad4, 0x4de64ba42d262, 0x4de69c28d5150, 0x4de7c0f3f1ee0,
+    0x4de8fbb4b0f0e, 0x4de8ff5da14d3, 0x4de9538b39986, 0x4de95add1a510,
+    0x4deaf86f33105, 0x4deb9d21734a6, 0x4debaf6e2517f, 0x4dec507774f5b,
+    0x4dec666d171f9, 0x4dec7c62b9497, 0x4decf529b52fc, 0x4ded3e5c7c660,
+    0x4deda12dd6227, 0x4dedc21e49614, 0x4dedee098db50, 0x4defa53a38fa8,
+    0x4defcd7c8cf1f, 0x4df16eb7960d9, 0x4df2fda5ed5ba, 0x4df3c348a0d48,
+    0x4df41776391fb, 0x4df49b38061af, 0x4df5473c270da, 0x4df738fbd8182,
+    0x4df7cf0a56e0f, 0x4df7ddae18523, 0x4df7e4fff90ad, 0x4df8567514388,
+    0x4df8860948e89, 0x4df909cb15e3d, 0x4df90d7406402, 0x4df93d083af03,
+    0x4df95a4fbdd2b, 0x4dfacbf6923e4, 0x4dfb919945b72, 0x4dfd69ba643b7,
+    0x4dfe8e8581147, 0x4dff0e9e5db36, 0x4dff19992ec85, 0x4dff4584731c1,
+    0x4dff7c6a8884c, 0x4e00963ad448d, 0x4e00fcb51e619, 0x4e0128a062b55,
+    0x4e02eacbdf0fc, 0x4e04bf440d37c, 0x4e07f3165dfdc, 0x4e082da563c2c,
+    0x4e083c4925340, 0x4e0afea65acc5, 0x4e0b6c72859db, 0x4e0b910be938d,
+    0x4e0bc4490e453, 0x4e0c5305ac556, 0x4e0ccbcca83bb, 0x4e0cd6c77950a,
+    0x4e0e70b0a1b3a, 0x4e0f2406a35ef, 0x4e0f7f861c62c, 0x4e0fd00ac451a,
+    0x4e100e42ba72f, 0x4e10417fdf7f5, 0x4e108709b6594, 0x4e10d78e5e482,
+    0x4e11330dd74bf, 0x4e115ef91b9fb, 0x4e127c7257c01, 0x4e12d448e0679,
+    0x4e1521880a75e, 0x4e15a8f2c7cd7, 0x4e175c7a82b6a, 0x4e17bf4bdc731,
+    0x4e18973b41b98, 0x4e18b0d9d43fb, 0x4e191ea5ff111, 0x4e1925f7dfc9b,
+    0x4e1b1f09718cd, 0x4e1b94277d16d, 0x4e1c64c501a4a, 0x4e1d405d57476,
+    0x4e1da32eb103d, 0x4e1e1f9e9d467, 0x4e1ea7095a9e0, 0x4e1ec0a7ed243,
+    0x4e1ef3e512309, 0x4e1f7054fe733, 0x4e1f914571b20, 0x4e1fa73b13dbe,
+    0x4e2106953679e, 0x4e2148761cf78, 0x4e2165bd9fda0, 0x4e21780a51a79,
+    0x4e234c827fcf9, 0x4e23786dc4235, 0x4e23b2fcc9e85, 0x4e23d3ed3d272,
+    0x4e23fc2f911e9, 0x4e24122533487, 0x4e248aec2f2ec, 0x4e25284c8eb03,
+    0x4e25c5acee31a, 0x4e26c235b7133, 0x4e2771e2c8623, 0x4e27e357e38fe,
+    0x4e29256a834b6, 0x4e295ff989106, 0x4e2a9368675aa, 0x4e2b25cdf5c72,
+    0x4e2bb48a93d75, 0x4e2bc6d745a4e, 0x4e2cbc0e2dcdd, 0x4e2cd955b0b05,
+    0x4e2d1b36972df, 0x4e2dfe20cd895, 0x4e2e76e7c96fa, 0x4e2fd641ec0da,
+    0x4e2ff3896ef02, 0x4e3005d620bdb, 0x4e30983baf2a3, 0x4e30ae3151541,
+    0x4e30b1da41b06, 0x4e31702b1470a, 0x4e343d831b1de, 0x4e34ab4f45ef4,
+    0x4e353662f3a32, 0x4e35f4b3c6636, 0x4e362447fb137, 0x4e3648e15eae9,
+    0x4e374cbc0848c, 0x4e381d598cd69, 0x4e38c95dadc94, 0x4e3966be0d4ab,
+    0x4e3971b8de5fa, 0x4e3c21c9622a6, 0x4e3c37bf04544, 0x4e3c724e0a194,
+    0x4e3c9e394e6d0, 0x4e3cacdd0fde4, 0x4e3cb7d7e0f33, 0x4e3d010aa8297,
+    0x4e3da965d8bfd, 0x4e3db0b7b9787, 0x4e3df9ea80aeb, 0x4e3e8ff8ff778,
+    0x4e3f72e335d2e, 0x4e3ffa4df32a7, 0x4e40b89ec5eab, 0x4e40ef84db536,
+    0x4e410ccc5e35e, 0x4e438d48ad509, 0x4e43a6e73fd6c, 0x4e4447f08fb48,
+    0x4e451fdff4faf, 0x4e4570649ce9d, 0x4e458dac1fcc5, 0x4e45c0e944d8b,
+    0x4e47ed37fba83, 0x4e487f9d8a14b, 0x4e488e414b85f, 0x4e48a08dfd538,
+    0x4e4932f38bc00, 0x4e49e2a09d0f0, 0x4e4a78af1bd7d, 0x4e4b12668afcf,
+    0x4e4bafc6ea7e6, 0x4e4bd0b75dbd3, 0x4e4c7cbb7eafe, 0x4e4d9dddab2c9,
+    0x4e4da52f8be53, 0x4e4ebeffd7a94, 0x4e4f3f18b4483, 0x4e4f5c60372ab,
+    0x4e4f884b7b7e7, 0x4e50887d34bc5, 0x4e51bbec13069, 0x4e51d1e1b5307,
+    0x4e54476333363, 0x4e54b8d84e63e, 0x4e5526a479354, 0x4e55aa6646308,
+    0x4e56ae40efcab, 0x4e57912b26261, 0x4e5886620e4f0, 0x4e58bd4823b7b,
+    0x4e5944b2e10f4, 0x4e59ab2d2b280, 0x4e5cccb2ca207, 0x4e5d0aeac041c,
+    0x4e5d666a39459, 0x4e5d8008cbcbc, 0x4e5df17de6f97, 0x4e5dfc78b80e6,
+    0x4e5e2c0cecbe7, 0x4e5e8b35561e9, 0x4e5ec96d4c3fe, 0x4e5f12a013762,
+    0x4e60376b304f2, 0x4e60d874802ce, 0x4e61eaf2eb385, 0x4e61f5edbc4d4,
+    0x4e62e77bb419e, 0x4e642d374431b, 0x4e644e27b7708, 0x4e64a9a730745,
+    0x4e65226e2c5aa, 0x4e654ab080521, 0x4e65f6b4a144c, 0x4e65fe0681fd6,
+    0x4e66e499a8b51, 0x4e6805bbd531c, 0x4e68822bc1746, 0x4e68e4fd1b30d,
+    0x4e68f749ccfe6, 0x4e69bcec80774, 0x4e6b70743b607, 0x4e6c15267b9a8,
+    0x4e6c20214caf7, 0x4e6cf467c1999, 0x4e6d8e1f30beb, 0x4e6d9cc2f22ff,
+    0x4e6dbdb3656ec, 0x4e6dc15c55cb1, 0x4e6dcc5726e00, 0x4e6eb2ea4d97b,
+    0x4e6fa47845645, 0x4e70925d4cd4a, 0x4e70d43e33524, 0x4e7150ae1f94e,
+    0x4e730b87bb36b, 0x4e7333ca0f2e2, 0x4e77dcec24bc0, 0x4e77f68ab7423,
+    0x4e78e0c6ce563, 0x4e7929f9958c7, 0x4e7a9f495a545, 0x4e7afac8d3582,
+    0x4e7b31aee8c0d, 0x4e7bc06b86d10, 0x4e7c701898200, 0x4e7c9c03dc73c,
+    0x4e7d68f870a54, 0x4e7d7eee12cf2, 0x4e7e274943658, 0x4e7ede48356d2,
+    0x4e7ef43dd7970, 0x4e7f2b23ecffb, 0x4e8153c9b372e, 0x4e81835de822f,
+    0x4e82b323d610e, 0x4e84fcba0fc2e, 0x4e861ddc3c3f9, 0x4e87255fd6361,
+    0x4e88726d47068, 0x4e89d1c769a48, 0x4e89e06b2b15c, 0x4e8bdd25ad353,
+    0x4e8c6f8b3ba1b, 0x4e8c7e2efd12f, 0x4e8cebfb27e45, 0x4e8d477aa0e82,
+    0x4e8e3cb189111, 0x4e8e6c45bdc12, 0x4e8e90df215c4, 0x4e8fd2f1c117c,
+    0x4e901fcd78aa5, 0x4e91449895835, 0x4e937f8b0dc41, 0x4e93c514e49e0,
+    0x4e9406f5cb1ba, 0x4e943a32f0280, 0x4e94b2f9ec0e5, 0x4e952bc0e7f4a,
+    0x4e95f1639b6d8, 0x4e975bb88f207, 0x4e97e3234c780, 0x4e983ea2c57bd,
+    0x4e9b602864744, 0x4e9bca4b9ee95, 0x4e9c0c2c8566f, 0x4e9c67abfe6ac,
+    0x4e9cc6d467cae, 0x4e9ce41beaad6, 0x4e9d608bd6f00, 0x4e9e93fab53a4,
+    0x4e9f8cda8dbf8, 0x4ea01b972bcfb, 0x4ea164fbac43d, 0x4ea60e1dc1d1b,
+    0x4ea727ee0d95c, 0x4ea72f3fee4e6, 0x4ea7996328c37, 0x4ea9b3652dc56,
+    0x4eaa8efd83682, 0x4eac796b53ba0, 0x4eac80bd3472a, 0x4eade0175710a,
+    0x4eaf4a6c4ac39, 0x4eafca8527628, 0x4eb1a64f36432, 0x4eb3f38e60517,
+    0x4eb431c65672c, 0x4eb49be990e7d, 0x4eb4aa8d52591, 0x4eb5060ccb5ce,
+    0x4eb5d3015f8e6, 0x4eb677b39fc87, 0x4eb70a192e34f, 0x4eb7bd6f2fe04,
+    0x4eb8955e9526b, 0x4eb9ba29b1ffb, 0x4ebad7a2ee201, 0x4ebb6db16ce8e,
+    0x4ebcd0b47fe33, 0x4ebe54a8061c5, 0x4ebe5bf9e6d4f, 0x4ebfc9f7cae43,
+    0x4ebfdfed6d0e1, 0x4ec06eaa0b1e4, 0x4ec0dc7635efa, 0x4ec1d1ad1e189,
+    0x4ec29ea1b24a1, 0x4ec38c86b9ba6, 0x4ec4ce995975e, 0x4ec522c6f1c11,
+    0x4ec58cea2c362, 0x4ec5a688bebc5, 0x4ec5d9c5e3c8b, 0x4ec5efbb85f29,
+    0x4ec5fe5f4763d, 0x4ec6737d52edd, 0x4ec6ec444ed42, 0x4ec6fae810456,
+    0x4ec7098bd1b6a, 0x4ec7cb8594d33, 0x4ec93d2c693ec, 0x4ec9a74fa3b3d,
+    0x4ec9de35b91c8, 0x4ecacfc3b0e92, 0x4ecaf80604e09, 0x4eccc52c524ff,
+    0x4ecd036448714, 0x4ecd8e77f6252, 0x4ecdd7aabd5b6, 0x4ecee2d747ae3,
+    0x4ecf41ffb10e5, 0x4ecfe30900ec1, 0x4ed0affd951d9, 0x4ed1a5347d468,
+    0x4ed2e39e2ca5b, 0x4ed2fd3cbf2be, 0x4ed3cdda43b9b, 0x4ed3eecab6f88,
+    0x4ed4a972995c7, 0x4ed588b3df5b8, 0x4ed5b49f23af4, 0x4ed6a2842b1f9,
+    0x4ed82677b158b, 0x4ed85d5dc6c16, 0x4ed86c018832a, 0x4ed8d27bd24b6,
+    0x4ed8efc3552de, 0x4ed938f61c642, 0x4ed94b42ce31b, 0x4edbb5c97b228,
+    0x4edbcbbf1d4c6, 0x4edc40dd28d66, 0x4edc8666ffb05, 0x4edcc0f605755,
+    0x4edcde3d8857d, 0x4edd39bd015ba, 0x4edde5c1224e5, 0x4edf86fc2b69f,
+    0x4edfe9cd85266, 0x4ee0db5b7cf30, 0x4ee0fc4bf031d, 0x4ee1800dbd2d1,
+    0x4ee1be45b34e6, 0x4ee371cd6e379, 0x4ee39a0fc22f0, 0x4ee3ee3d5a7a3,
+    0x4ee4301e40f7d, 0x4ee537a1daee5, 0x4ee596ca444e7, 0x4ee65c6cf7c75,
+    0x4ee69aa4ede8a, 0x4ee6f9cd5748c, 0x4ee7345c5d0dc, 0x4ee743001e7f0,
+    0x4ee7f656202a5, 0x4eea2d9fa80ec, 0x4eeaadb884adb, 0x4eebc788d071c,
+    0x4eebfe6ee5da7, 0x4eee1c19db38b, 0x4eee3d0a4e778, 0x4eee56a8e0fdb,
+    0x4eee70477383e, 0x4eee7799543c8, 0x4eef2e9846442, 0x4ef077fcc6b84,
+    0x4ef0b634bcd99, 0x4ef0b9ddad35e, 0x4ef12400e7aaf, 0x4ef12efbb8bfe,
+    0x4ef132a4a91c3, 0x4ef1f0f57bdc7, 0x4ef2c53bf0c69, 0x4ef415f251f35,
+    0x4ef6424108c2d, 0x4ef69dc081c6a, 0x4ef6dfa168444, 0x4ef7252b3f1e3,
+    0x4ef7670c259bd, 0x4ef79a494aa83, 0x4ef7cd866fb49, 0x4ef8f9a36d463,
+    0x4ef92ce092529, 0x4ef9cde9e2305, 0x4efb1ea0435d1, 0x4efb30ecf52aa,
+    0x4efb608129dab, 0x4efc904717c8a, 0x4efcef6f8128c, 0x4efd17b1d5203,
+    0x4eff355cca7e7, 0x4eff5d9f1e75e, 0x4eff90dc43824, 0x4effae23c664c,
+    0x4effe160eb712, 0x4f009fb1be316, 0x4f00b5a7605b4, 0x4f01a7355827e,
+    0x4f01bd2afa51c, 0x4f03e227d068a, 0x4f043da7496c7, 0x4f044c4b0addb,
+    0x4f0592069af58, 0x4f075f2ce864e, 0x4f07c1fe42215, 0x4f09b766e3882,
+    0x4f09bb0fd3e47, 0x4f0b80e4409b3, 0x4f0bbf1c36bc8, 0x4f0c6b2057af3,
+    0x4f0cf9dcf5bf6, 0x4f0d1acd68fe3, 0x4f0da2382655c, 0x4f0e2d4bd409a,
+    0x4f0eb85f81bd8, 0x4f0ebfb162762, 0x4f0fd981ae3a3, 0x4f1038aa179a5,
+    0x4f104e9fb9c43, 0x4f10a2cd520f6, 0x4f10b8c2f4394, 0x4f111f3d3e520,
+    0x4f118d0969236, 0x4f11bc9d9dd37, 0x4f12f00c7c1db, 0x4f150a0e811fa,
+    0x4f1548467740f, 0x4f1556ea38b23, 0x4f15cc08443c3, 0x4f166968a3bda,
+    0x4f16a04eb9265, 0x4f16fbce322a2, 0x4f18577f646bd, 0x4f18af55ed135,
+    0x4f1bc5e0baf6d, 0x4f1bd82d6cc46, 0x4f1cc26983d86, 0x4f1d846346f4f,
+    0x4f1ffd8db5570, 0x4f2051bb4da23, 0x4f20b48ca75ea, 0x4f20fa167e389,
+    0x4f22bfeaeaef5, 0x4f23cec0659e7, 0x4f24144a3c786, 0x4f24562b22f60,
+    0x4f24d29b0f38a, 0x4f25391559516, 0x4f27272c19ff9, 0x4f273d21bc297,
+    0x4f277407d1922, 0x4f286ce7aa176, 0x4f28e205b5a16, 0x4f291542daadc,
+    0x4f29bd9e0b442, 0x4f2b11fd5ccd3, 0x4f2bffe2643d8, 0x4f2cf1705c0a2,
+    0x4f2d1260cf48f, 0x4f2d2bff61cf2, 0x4f2d4cefd50df, 0x4f2d5098c56a4,
+    0x4f2d9279abe7e, 0x4f2e71baf1e6f, 0x4f2f1a16227d5, 0x4f31934090df6,
+    0x4f32eef1c3211, 0x4f336b61af63b, 0x4f345cefa7305, 0x4f346098978ca,
+    0x4f349b279d51a, 0x4f34d95f9372f, 0x4f35e8350e221, 0x4f393cf7d226e,
+    0x4f39de012204a, 0x4f3bf0b1464df, 0x4f3cb653f9c6d, 0x4f3cd39b7ca95,
+    0x4f3d3dbeb71e6, 0x4f3df4bda9260, 0x4f3f95f8b241a, 0x4f3fabee546b8,
+    0x4f3ff5211ba1c, 0x4f4062ed46732, 0x4f40a4ce2cf0c, 0x4f42c9cb0307a,
+    0x4f43acb539630, 0x4f44675d1bc6f, 0x4f4625dfa7c51, 0x4f46815f20c8e,
+    0x4f47a62a3da1e, 0x4f48940f45123, 0x4f49859d3cded, 0x4f4ad9fc8e67e,
+    0x4f4b4b71a9959, 0x4f4b4f1a99f1e, 0x4f4c4ba362d37, 0x4f4d35df79e77,
+    0x4f4d915ef2eb4, 0x4f4db9a146e2b, 0x4f4e276d71b41, 0x4f50e621b6f01,
+    0x4f5141a12ff3e, 0x4f5283b3cfaf6, 0x4f52cce696e5a, 0x4f53c21d7f0e9,
+    0x4f53f1b1b3bea, 0x4f543e8d6b513, 0x4f56673331c46, 0x4f573427c5f5e,
+    0x4f57d53115d3a, 0x4f58e7af80df1, 0x4f59dce669080, 0x4f5a88ea89fab,
+    0x4f6040e21a37b, 0x4f608dbdd1ca4, 0x4f609c61933b8, 0x4f614865b42e3,
+    0x4f633a256538b, 0x4f63ab9a80666, 0x4f63bde73233f, 0x4f6448fadfe7d,
+    0x4f64713d33df4, 0x4f65f187c9bc1, 0x4f66f90b63b29, 0x4f67841f11667,
+    0x4f689def5d2a8, 0x4f69a1ca06c4b, 0x4f69bb68994ae, 0x4f6a2934c41c4,
+    0x4f6be40e5fbe1, 0x4f6c64273c5d0, 0x4f6d01879bde7, 0x4f6d4aba6314b,
+    0x4f6f8cfebc0e1, 0x4f70e8afee4fc, 0x4f71776c8c5ff, 0x4f731155b4c2f,
+    0x4f731ff976343, 0x4f736cd52dc6c, 0x4f740dde7da48, 0x4f74277d102ab,
+    0x4f7532a99a7d8, 0x4f75698fafe63, 0x4f75f0fa6d3dc, 0x4f767c0e1af1a,
+    0x4f78a85cd1c12, 0x4f798eeff878d, 0x4f7bf224c4b10, 0x4f7cf5ff6e4b3,
+    0x4f7d084c2018c, 0x4f7d0bf510751, 0x4f7d84bc0c5b6, 0x4f7e1378aa6b9,
+    0x4f7e973a7766d, 0x4f7ec325bbba9, 0x4f80d22cefa79, 0x4f81b5172602f,
+    0x4f843ce555d64, 0x4f84826f2cb03, 0x4f84cf4ae442c, 0x4f8702eb7bcae,
+    0x4f873d7a818fe, 0x4f89194490708, 0x4f89368c13530, 0x4f8a7c47a36ad,
+    0x4f8bd0a6f4f3e, 0x4f8c630c83606, 0x4f8c87a5e6fb8, 0x4f8cbe8bfc643,
+    0x4f8e796598060, 0x4f8ea8f9ccb61, 0x4f8ed4e51109d, 0x4f9084c3db96b,
+    0x4f908c15bc4f5, 0x4f912d1f0c2d1, 0x4f9164052195c, 0x4f9301973a551,
+    0x4f9590b74ae10, 0x4f95b1a7be1fd, 0x4f977b251b32e, 0x4f979c158e71b,
+    0x4f97aab94fe2f, 0x4f989c4747af9, 0x4f990dbc62dd4, 0x4f99917e2fd88,
+    0x4f9aed2f621a3, 0x4f9b32b938f42, 0x4f9b699f4e5cd, 0x4f9c95bc4bee7,
+    0x4f9d874a43bb1, 0x4f9f128faaacd, 0x4fa0bec584dd6, 0x4fa1bb4e4dbef,
+    0x4fa1dc3ec0fdc, 0x4fa4297deb0c1, 0x4fa4e425cd700, 0x4fa4e7cebdcc5,
+    0x4fa4eb77ae28a, 0x4fa5768b5bdc8, 0x4fa655cca1db9, 0x4fa6bc46ebf45,
+    0x4fa6fe27d271f, 0x4fa91829d773e, 0x4fa9c42df8669, 0x4fab740cc2f37,
+    0x4fabda870d0c3, 0x4fabe581de212, 0x4fad20429d240, 0x4faef4bacb4c0,
+    0x4faf27f7f0586, 0x4fb033247aab3, 0x4fb145a2e5b6a, 0x4fb28b5e75ce7,
+    0x4fb2b749ba223, 0x4fb2d0e84ca86, 0x4fb349af488eb, 0x4fb412faec63e,
+    0x4fb50f83b5457, 0x4fb572550f01e, 0x4fb5e3ca2a2f9, 0x4fb71e8ae9327,
+    0x4fb7bf9439103, 0x4fb855a2b7d90, 0x4fb87de50bd07, 0x4fb91eee5bae3,
+    0x4fb9313b0d7bc, 0x4fb9682122e47, 0x4fbb59e0d3eef, 0x4fbbe89d71ff2,
+    0x4fbc47c5db5f4, 0x4fbcd682796f7, 0x4fbd68e807dbf, 0x4fbd82869a622,
+    0x4fbde557f41e9, 0x4fbde900e47ae, 0x4fbdeca9d4d73, 0x4fbf709d5b105,
+    0x4fbf918dce4f2, 0x4fc0747804aa8, 0x4fc16605fc772, 0x4fc18e48506e9,
+    0x4fc1c8d756339, 0x4fc416168041e, 0x4fc4544e76633, 0x4fc47195f945b,
+    0x4fc483e2ab134, 0x4fc4c96c81ed3, 0x4fc59661161eb, 0x4fc69a3bbfb8e,
+    0x4fc6d4cac57de, 0x4fc6f96429190, 0x4fc92209ef8c3, 0x4fc979e07833b,
+    0x4fc9e05ac24c7, 0x4fcb9b345dee4, 0x4fcc13fb59d49, 0x4fcc598530ae8,
+    0x4fcc7323c334b, 0x4fcd01e06144e, 0x4fcf89ae91183, 0x4fd07b3c88e4d,
+    0x4fd3030ab8b82, 0x4fd344eb9f35c, 0x4fd4ffc53ad79, 0x4fd5788c36bde,
+    0x4fd6a4a9344f8, 0x4fd79d890cd4c, 0x4fd8d849cbd7a, 0x4fd9b78b11d6b,
+    0x4fd9dc247571d, 0x4fda5c3d5210c, 0x4fda8f7a771d2, 0x4fdb3f27886c2,
+    0x4fdb7264ad788, 0x4fdcdcb9a12b7, 0x4fdd93b893331, 0x4fdd9761838f6,
+    0x4fde76a2c98e7, 0x4fdfceab0b73d, 0x4fe185dbb6b95, 0x4fe1effef12e6,
+    0x4fe28d5f50afd, 0x4fe353020428b, 0x4fe3b97c4e417, 0x4fe3cf71f06b5,
+    0x4fe4a3b865557, 0x4fe58a4b8c0d2, 0x4fe5bd88b1198, 0x4fe5e5cb0510f,
+    0x4fe653972fe25, 0x4fe670deb2c4d, 0x4fe72b869528c, 0x4fe7452527aef,
+    0x4fe903a7b3ad1, 0x4fe9284117483, 0x4fe945889a2ab, 0x4fed514a50372,
+    0x4feea5a9a1c03, 0x4feffdb1e3a59, 0x4ff013a785cf7, 0x4ff0d94a39485,
+    0x4ff0fde39ce37, 0x4ff15d0c06439, 0x4ff190492b4ff, 0x4ff1e0cdd33ed,
+    0x4ff4898c7650f, 0x4ff4ddba0e9c2, 0x4ff5617bdb976, 0x4ff5c0a444f78,
+    0x4ff9cc65fb03f, 0x4ffb41b5bfcbd, 0x4ffb9d3538cfa, 0x4ffdc983ef9f2,
+    0x4ffed1078995a, 0x5001294184b8e, 0x500172744bef2, 0x5001a208809f3,
+    0x50031b0135c36, 0x5003c35c6659c, 0x5004387a71e3c, 0x5005d2639a46c,
+    0x500685b99bf21, 0x500785eb552ff, 0x5007da18ed7b2, 0x5007e8bcaeec6,
+    0x5008cba6e547c, 0x5009655e546ce, 0x5009d32a7f3e4, 0x500d3a39f510a,
+    0x500d5b2a684f7, 0x500dabaf103e5, 0x500e24760c24a, 0x500f03b75223b,
+    0x500fb70d53cf0, 0x500fd454d6b18, 0x500fd7fdc70dd, 0x500fdba6b76a2,
+    0x50101d879de7c, 0x501071b53632f, 0x5010afed2c544, 0x5010b73f0d0ce,
+    0x50110b6ca5581, 0x50111db95725a, 0x5011250b37de4, 0x50120b9e5e95f,
+    0x5013674f90d7a, 0x5013c677fa37c, 0x5014309b34acd, 0x5014eb431710c,
+    0x501513856b083, 0x5015381ecea35, 0x50158ff5574ad, 0x5015b837ab424,
+    0x5016225ae5b75, 0x5016d207f7065, 0x5017646d8572d, 0x501acb7cfb453,
+    0x501b1c01a3341, 0x501b4b95d7e42, 0x501bb96202b58, 0x501c14e17bb95,
+    0x501ce1d60fead, 0x501d23b6f6687, 0x501dfba65baee, 0x501dff4f4c0b3,
+    0x501e36356173e, 0x501eb9f72e6f2, 0x501ef0dd43d7d, 0x501f0a7bd65e0,
+    0x501f5005ad37f, 0x5020d3f933711, 0x50213a737d89d, 0x5022d0b3b5908,
+    0x502399ff5965b, 0x5023df89303fa, 0x502446037a586, 0x5024ee5eaaeec,
+    0x502516a0fee63, 0x5025303f916c6, 0x50279ac63e5d3, 0x5028a5f2c8b00,
+    0x5028c33a4b928, 0x502931067663e, 0x502967ec8bcc9, 0x5029ce66d5e55,
+    0x502be868dae74, 0x502cae0b8e602, 0x502d4b6bede19, 0x502e0d65b0fe2,
+    0x502f14e94af4a, 0x502f1c3b2bad4, 0x502f7b63950d6, 0x502faaf7c9bd7,
+    0x502fb249aa761, 0x502fde34eec9d, 0x50301c6ce4eb2, 0x50312eeb4ff69,
+    0x503144e0f2207, 0x503195659a0f5, 0x5032e61bfb3c1, 0x503320ab01011,
+    0x50333df283e39, 0x503487570457b, 0x503583dfcd394, 0x5035d80d65847,
+    0x5036d4962e660, 0x5036f1ddb1488, 0x50372c6cb70d8, 0x503ce0bb56ee3,
+    0x503daa06fac36, 0x503dd5f23f172, 0x503dfa8ba2b24, 0x503ec78036e3c,
+    0x503ecb2927401, 0x503ed9cce8b15, 0x503eec199a7ee, 0x503f563cd4f3f,
+    0x503fc0600f690, 0x504114bf60f21, 0x50412e5df3784, 0x5041a724ef5e9,
+    0x5042c49e2b7ef, 0x50432b187597b, 0x5043d373a62e1, 0x5043dac586e6b,
+    0x5044747cf60bd, 0x5044a7ba1b183, 0x5045208116fe8, 0x5045d78009062,
+    0x50471d3b991df, 0x504757ca9ee2f, 0x5047f52afe646, 0x50495f7ff2175,
+    0x5049a8b2b94d9, 0x5049d846edfda, 0x504ab3df43a06, 0x504ad4cfb6df3,
+    0x504b5c3a7436c, 0x504bf5f1e35be, 0x504c63be0e2d4, 0x504c6b0feee5e,
+    0x504d63efc76b2, 0x504da9799e451, 0x504dd564e298d, 0x504dd90dd2f52,
+    0x504e975ea5b56, 0x504ee7e34da44, 0x5050caff3d3d8, 0x5050d5fa0e527,
+    0x5051e12698a54, 0x5052d2b49071e, 0x5052ddaf6186d, 0x50540d754f74c,
+    0x5054cbc622350, 0x5054ecb69573d, 0x50552e977bf17, 0x505695437f481,
+    0x5056a03e505d0, 0x5056b28b022a9, 0x5056bd85d33f8, 0x5056d37b75696,
+    0x5057364ccf25d, 0x5057f49da1e61, 0x5059838bf9342, 0x5059f8aa04be2,
+    0x505b336ac3c10, 0x505c0408484ed, 0x505d28d36527d, 0x505ebb6aacd23,
+    0x505f601ced0c4, 0x505fa5a6c3e63, 0x50604a5904204, 0x506058fcc5918,
+    0x50609734bbb2d, 0x50609e869c6b7, 0x506151dc9e16c, 0x5062c72c62dea,
+    0x5063a66da8ddb, 0x506490a9bff1b, 0x5066b94f8664e, 0x5068f441fea5a,
+    0x506a86d946500, 0x506ad00c0d864, 0x506d15f956dbf, 0x506dd0a1393fe,
+    0x506ebadd5053e, 0x506f8428f4291, 0x5071e00bdfa8a, 0x50741755678d1,
+    0x5074901c63736, 0x5078c7c95dd39, 0x5078d2c42ee88, 0x5078e8b9d1126,
+    0x50794f341b2b2, 0x507994bdf2051, 0x507ba76e164e6, 0x507cf47b871ed,
+    0x507d41573eb16, 0x507d70eb73617, 0x507e5b278a757, 0x507f714ee5dd3,
+    0x507fa48c0ae99, 0x507fb32fcc5ad, 0x507fbe2a9d6fc, 0x507fe66cf1673,
+    0x50808b1f31a14, 0x50829a26658e4, 0x508458a8f18c6, 0x50849a89d80a0,
+    0x5085d54a970ce, 0x5086d57c504ac, 0x508730fbc94e9, 0x5088437a345a0,
+    0x5088bc4130405, 0x5089315f3bca5, 0x508955f89f657, 0x5089858cd4158,
+    0x508b9f8ed9177, 0x508c1103f4452, 0x508d272b4face, 0x508d35cf111e2,
+    0x508df41fe3de6, 0x508e0dbe76649, 0x508f1c93f113b, 0x508f830e3b2c7,
+    0x50908e3ac57f4, 0x509091e3b5db9, 0x5092ccd62e1c5, 0x5094805de9058,
+    0x509696b6fdab2, 0x5096c2a241fee, 0x509bbc48ff7ba, 0x509be0e26316c,
+    0x509c0ccda76a8, 0x509c9b8a457ab, 0x509d7e747bd61, 0x509f185da4391,
+    0x50a0aaf4ebe37, 0x50a15aa1fd327, 0x50a1b27885d9f, 0x50a4ccac4419c,
+    0x50a4d7a7152eb, 0x50a4f897886d8, 0x50a51987fbac5, 0x50a562bac2e29,
+    0x50a583ab36216, 0x50a5be3a3be66, 0x50a6285d765b7, 0x50a6df5c68631,
+    0x50a7dbe53144a, 0x50a7e33711fd4, 0x50a83764aa487, 0x50a95134f60c8,
+    0x50a9b7af40254, 0x50ace086bfd65, 0x50af1427575e7, 0x50b0095e3f876,
+    0x50b105e70868f, 0x50b139242d755, 0x50b1d6848cf6c, 0x50b2b5c5d2f5d,
+    0x50b2d30d55d85, 0x50b35e21038c3, 0x50b4de6b99690, 0x50b54c37c43a6,
+    0x50b57bcbf8ea7, 0x50b703686f7fe, 0x50b732fca42ff, 0x50b748f24659d,
+    0x50b7ccb413551, 0x50b84923ff97b, 0x50b85f19a1c19, 0x50b8db898e043,
+    0x50b93e5ae7c0a, 0x50b957f97a46d, 0x50ba9a0c1a025, 0x50bbbb2e467f0,
+    0x50bc21a89097c, 0x50bcd155a1e6c, 0x50bd25833a31f, 0x50bd6b0d110be,
+    0x50bd7d59c2d97, 0x50bde77cfd4e8, 0x50bdf277ce637, 0x50bee405c6301,
+    0x50bffa2d2197d, 0x50c093e490bcf, 0x50c16482154ac, 0x50c2c78528451,
+    0x50c309660ec2b, 0x50c3613c976a3, 0x50c3822d0aa90, 0x50c4ec81fe5bf,
+    0x50c53d06a64ad, 0x50c54baa67bc1, 0x50c6b9a84bcb5, 0x50c76cfe4d76a,
+    0x50c865de25fbe, 0x50c8a4161c1d3, 0x50cb15eea9c6a, 0x50cb492bced30,
+    0x50cc6a4dfb4fb, 0x50cc99e22fffc, 0x50ccafd7d229a, 0x50ccd81a26211,
+    0x50cd632dd3d4f, 0x50cd757a85a28, 0x50cd966af8e15, 0x50ce9dee92d7d,
+    0x50cef5c51b7f5, 0x50cf1a5e7f1a7, 0x50cfacc40d86f, 0x50d03f299bf37,
+    0x50d09357343ea, 0x50d0cde63a03a, 0x50d0dc89fb74e, 0x50d326203526e,
+    0x50d49b6ff9eec, 0x50d5efcf4b77d, 0x50d7cb995a587, 0x50d7e8e0dd3af,
+    0x50d840b765e27, 0x50d8e9129678d, 0x50d8fb5f48466, 0x50d9a76369391,
+    0x50d9cfa5bd308, 0x50d9eced40130, 0x50dae224283bf, 0x50db190a3da4a,
+    0x50dc45273b364, 0x50dc50220c4b3, 0x50dd5ef786fa5, 0x50de6dcd01a97,
+    0x50df78f98bfc4, 0x50dfafdfa164f, 0x50e050e8f142b, 0x50e09a1bb878f,
+    0x50e16ab93d06c, 0x50e16e622d631, 0x50e3a5abb5478, 0x50e41e72b12dd,
+    0x50e4c6cde1c43, 0x50e551e18f781, 0x50e559337030b, 0x50e5b4b2e9348,
+    0x50e66f5acb987, 0x50e69eef00488, 0x50e6a9e9d15d7, 0x50e709123abd9,
+    0x50e8561fab8e0, 0x50e8aa4d43d93, 0x50e931b80130c, 0x50e95da345848,
+    0x50e97398e7ae6, 0x50eb5a5dc7a3f, 0x50eb73fc5a2a2, 0x50ebae8b5fef2,
+    0x50ec35f61d46b, 0x50ec4f94afcce, 0x50ef41861a154, 0x50efe9e14aaba,
+    0x50f283fc2c4c8, 0x50f428e025c47, 0x50f580e867a9d, 0x50f6677b8e618,
+    0x50f6b45745f41, 0x50f7e0744385b, 0x50f808b6977d2, 0x50f8902154d4b,
+    0x50f94e722794f, 0x50f9e480a65dc, 0x50fba303325be, 0x50fca334eb99c,
+    0x50fcddc3f15ec, 0x50fd234dc838b, 0x50fdfee61ddb7, 0x50ff98cf463e7,
+    0x50ffa77307afb, 0x50fff44ebf424, 0x5100c4ec43d01, 0x510545cc05668,
+    0x5105546fc6d7c, 0x5107c29f6424e, 0x5108e76a80fde, 0x510925a2771f3,
+    0x510a97494b8ac, 0x510c13eaf10b4, 0x510c9b55ae62d, 0x510e4edd694c0,
+    0x510ec7a465325, 0x510f4b66322d9, 0x511014b1d602c, 0x51104f40dbc7c,
+    0x51118658aa6e5, 0x5112bd707914e, 0x51137f6a3c317, 0x5113aefe70e18,
+    0x5114032c092cb, 0x511470f833fe1, 0x5114cc77ad01e, 0x5115120183dbd,
+    0x5116a0efdb29e, 0x51176de46f5b6, 0x5117a1219467c, 0x5117afc555d90,
+    0x5118b39fff733, 0x5118ea8614dbe, 0x511b1e26ac640, 0x511c4dec9a51f,
+    0x511d88ad5954d, 0x511e3fac4b5c7, 0x511f9f066dfa7, 0x5120efbccf273,
+    0x5120f70eafdfd, 0x5122c7ddedab8, 0x5124c84160274, 0x5125485a3cc63,
+    0x5125e9638ca3f, 0x5126363f44368, 0x5127500f8ffa9, 0x5127710003396,
+    0x5127f118dfd85, 0x5127f4c1d034a, 0x51283df4976ae, 0x5129123b0c550,
+    0x512abac7f6294, 0x512b2c3d1156f, 0x512b71c6e830e, 0x512c96920509e,
+    0x512dcda9d3b07, 0x512de7486636a, 0x512e725c13ea8, 0x512f220925398,
+    0x512f728dcd286, 0x5130e08bb137a, 0x51311ec3a758f, 0x5131978aa33f4,
+    0x5131e4665ad1d, 0x5132adb1fea70, 0x51336859e10af, 0x51344eed07c2a,
+    0x5134cf05e4619, 0x51354f1ec1008, 0x513689df80036, 0x5138bd80178b8,
+    0x513aba3a99aaf, 0x513b788b6c6b3, 0x513d4208c97e4, 0x513d57fe6ba82,
+    0x513d6a4b1d75b, 0x513de6bb09b85, 0x513dee0cea70f, 0x513e498c6374c,
+    0x513f54b8edc79, 0x514137d4dd60d, 0x514501b5acefa, 0x5145055e9d4bf,
+    0x514617dd08576, 0x51465d66df315, 0x51469f47c5aef, 0x5146e87a8ce53,
+    0x5146ec237d418, 0x514785daec66a, 0x514956aa2a325, 0x51496c9fcc5c3,
+    0x514973f1ad14d, 0x5149f06199577, 0x514b86a1d15e2, 0x514c573f55ebf,
+    0x514c70dde8722, 0x514c782fc92ac, 0x514f0ea1ba6f5, 0x5150f1bdaa089,
+    0x5152eacf3bcbb, 0x5153514985e47, 0x5153c2bea1122, 0x5153eb00f5099,
+    0x51544dd24ec60, 0x5154e789bdeb2, 0x5156224a7cee0, 0x51565587a1fa6,
+    0x5156b858fbb6d, 0x5156e7ed3066e, 0x515708dda3a5b, 0x51577300de1ac,
+    0x5158648ed5e76, 0x5158dd55d1cdb, 0x5158f34b73f79, 0x51590cea067dc,
+    0x5159a2f885469, 0x5159b8ee27707, 0x515abcc8d10aa, 0x515b408a9e05e,
+    0x515b4b856f1ad, 0x515bf037af54e, 0x515c2e6fa5763, 0x515e5e674ca20,
+    0x515f783798661, 0x515fa079ec5d8, 0x5161df1554fa9, 0x5162f53cb0625,
+    0x51632127f4b61, 0x516366b1cb900, 0x516378fe7d5d9, 0x5165d88a59397,
+    0x5166d16a31beb, 0x5167dc96bc118, 0x5168e07165abb, 0x5169608a424aa,
+    0x516988cc96421, 0x516b43a631e3e, 0x516c68714ebce, 0x516cceeb98d5a,
+    0x516cdd8f5a46e, 0x516d10cc7f534, 0x516d7aefb9c85, 0x516de16a03e11,
+    0x516e35979c2c4, 0x516f61b499bde, 0x516f82a50cfcb, 0x517018b38bc58,
+    0x5170c4b7acb83, 0x517127890674a, 0x5171de87f87c4, 0x517332e74a055,
+    0x5173fc32edda8, 0x51753a9c9d39b, 0x51757c7d83b75, 0x5176fcc819942,
+    0x517745fae0ca6, 0x51775f9973509, 0x5177a5234a2a8, 0x5177bb18ec546,
+    0x517bb0e50036f, 0x517bd57e63d21, 0x517c3bf8adead, 0x517c60921185f,
+    0x517cc3636b426, 0x517d054451c00, 0x517d1b39f3e9e, 0x517d855d2e5ef,
+    0x517e31614f51a, 0x517e7e3d06e43, 0x5180cf25214ed, 0x518126fba9f65,
+    0x5181827b22fa2, 0x518189cd03b2c, 0x51836ce8f34c0, 0x5185d3c6afe08,
+    0x518657887cdbc, 0x518674cfffbe4, 0x5187282601699, 0x51872f77e2223,
+    0x5187abe7ce64d, 0x5187d7d312b89, 0x5188c5b81a28e, 0x51894979e7242,
+    0x518b79718e4ff, 0x518c6ea87678e, 0x518d21fe78243, 0x518d63df5ea1d,
+    0x518d762c106f6, 0x518eb0eccf724, 0x518f2d5cbbb4e, 0x518f3fa96d827,
+    0x518f559f0fac5, 0x5190054c20fb5, 0x519034e055ab6, 0x5193c0892f18e,
+    0x51951c3a615a9, 0x5195e92ef58c1, 0x519791bbdf605, 0x5197c4f9046cb,
+    0x519824216dccd, 0x5198b2de0bdd0, 0x519b8787f342e, 0x519bdbb58b8e1,
+    0x519d71f5c394c, 0x519dc6235bdff, 0x519e49e528db3, 0x519e72277cd2a,
+    0x519edff3a7a40, 0x519f84a5e7de1, 0x51a125e0f0f9b, 0x51a185095a59d,
+    0x51a213c5f86a0, 0x51a29ed9a61de, 0x51a2e80c6d542, 0x51a5354b97627,
+    0x51a6984eaa5cc, 0x51a6bce80df7e, 0x51a7e55c1b2d3, 0x51a7f056ec422,
+    0x51a8e58dd46b1, 0x51a944b63dcb3, 0x51a95e54d0516, 0x51a9744a727b4,
+    0x51a9c4cf1a6a2, 0x51a9cfc9eb7f1, 0x51abb2e5db185, 0x51ac454b6984d,
+    0x51ac48f459e12, 0x51ac69e4cd1ff, 0x51ad20e3bf279, 0x51b0301cac527,
+    0x51b100ba30e04, 0x51b128fc84d7b, 0x51b133f755eca, 0x51b1464407ba3,
+    0x51b16add6b555, 0x51b1931fbf4cc, 0x51b3763baee60, 0x51b381367ffaf,
+    0x51b3e7b0ca13b, 0x51b4557cf4e51, 0x51b4766d6823e, 0x51b5052a06341,
+    0x51b5227189169, 0x51b555aeae22f, 0x51b5caccb9acf, 0x51b7efc98fc3d,
+    0x51b847a0186b5, 0x51bbb2587e9a0, 0x51bcf46b1e558, 0x51bd698929df8,
+    0x51bda06f3f483, 0x51bebde87b689, 0x51bedb2ffe4b1, 0x51bf1967f46c6,
+    0x51bf20b9d5250, 0x51bfa47ba2204, 0x51c08765d87ba, 0x51c119cb66e82,
+    0x51c124c637fd1, 0x51c12c1818b5b, 0x51c15bac4d65c, 0x51c16df8ff335,
+    0x51c1b72bc6699, 0x51c2d84df2e64, 0x51c475e00ba59, 0x51c50f977acab,
+    0x51c613722464e, 0x51c66ef19d68b, 0x51c6a980a32db, 0x51c7349450e19,
+    0x51c7607f95355, 0x51c76f2356a69, 0x51c8595f6dba9, 0x51c86bac1f882,
+    0x51c994202cbd7, 0x51cc149c7bd82, 0x51cc7b16c5f0e, 0x51cc89ba87622,
+    0x51cc94b558771, 0x51cd1fc9062af, 0x51cdaadcb3ded, 0x51ced6f9b1707,
+    0x51cfa0455545a, 0x51d0414ea5236, 0x51d0a41ffedfd, 0x51d1870a353b3,
+    0x51d1a0a8c7c16, 0x51d1bdf04aa3e, 0x51d5165bff050, 0x51d63b271bde0,
+    0x51d663696fd57, 0x51d79e2a2ed85, 0x51d7eb05e66ae, 0x51d8e78eaf4c7,
+    0x51d9764b4d5ca, 0x51db08e295070, 0x51db262a17e98, 0x51dcdd5ac32f0,
+    0x51dd895ee421b, 0x51ddbc9c092e1, 0x51de948b6e748, 0x51deb57be1b35,
+    0x51dec41fa3249, 0x51def00ae7785, 0x51dfcf4c2d776, 0x51e014d604515,
+    0x51e08d9d0037a, 0x51e1ecf722d5a, 0x51e2d38a498d5, 0x51e3031e7e3d6,
+    0x51e495b5c5e7c, 0x51e520c9739ba, 0x51e58743bdb46, 0x51e5d41f7546f,
+    0x51e7546a0b23c, 0x51e766b6bcf15, 0x51e771b18e064, 0x51e85f9695769,
+    0x51e91a3e77da8, 0x51e98f5c83648, 0x51eb7621635a1, 0x51ec2225844cc,
+    0x51ec2d205561b, 0x51ec9aec80331, 0x51ecb48b12b94, 0x51ecb83403159,
+    0x51ece7c837c5a, 0x51ed7a2dc6322, 0x51ee890340e14, 0x51eefe214c6b4,
+    0x51efec0653db9, 0x51f0a6ae363f8, 0x51f0ec380d197, 0x51f1f764976c4,
+    0x51f394f6b02b9, 0x51f50def654fc, 0x51f518ea3664b, 0x51f6ed62648cb,
+    0x51f70aa9e76f3, 0x51f769d250cf5, 0x51f8665b19b0e, 0x51f9001288d60,
+    0x51f957e9117d8, 0x51f9c20c4bf29, 0x51fc303be93fb, 0x51fd16cf0ff76,
+    0x51fd80f24a6c7, 0x51fe6b2e61807, 0x51fe8fc7c51b9, 0x51ff8155bce83,
+    0x51ff88a79da0d, 0x5200a277e964e, 0x520185621fc04, 0x5204615de7dec,
+    0x5204cb812253d, 0x5205a71977f69, 0x5205f04c3f2cd, 0x52074fa661cad,
+    0x5209a7e05cee1, 0x520ab30ce740e, 0x520aed9bed05e, 0x520b8ea53ce3a,
+    0x520be67bc58b2, 0x520c87851568e, 0x520cf1a84fddf, 0x520da4fe51894,
+    0x520df582f9782, 0x520ec9c96e624, 0x520f75cd8f54f, 0x5210e0228307e,
+    0x5211678d405f7, 0x52119e7355c82, 0x5213e4609f1dd, 0x5214adac42f30,
+    0x52157aa0d7248, 0x5215b186ec8d3, 0x52177b0449a04, 0x52189fcf66794,
+    0x521902a0c035b, 0x5219114481a6f, 0x521b2eef77053, 0x521bc8a6e62a5,
+    0x521dc5616849c, 0x521e457a44e8b, 0x521f9d8286ce1, 0x5220214453c95,
+    0x522092b96ef70, 0x5220c5f694036, 0x5220dbec362d4, 0x5220ea8ff79e8,
+    0x5222b06464554, 0x522396f78b0cf, 0x5226ce72cc2f4, 0x522810856beac,
+    0x52284019a09ad, 0x5228ade5cb6c3, 0x5229688dadd02, 0x522977316f416,
+    0x522b111a97a46, 0x522b68f1204be, 0x522c09fa7029a, 0x522eb2b9133bc,
+    0x522f5b1443d22, 0x522f7fada76d4, 0x522fd3db3fb87, 0x52315b77b64de,
+    0x5232c5ccaa00d, 0x523392c13e325, 0x5233c25572e26, 0x5233d84b150c4,
+    0x5233dbf405689, 0x523596cda10a6, 0x5236b446dd2ac, 0x52370fc6562e9,
+    0x52389eb4ad7ca, 0x523929c85b308, 0x523be12abfb3e, 0x523d18428e5a7,
+    0x523d3cdbf1f59, 0x523e4bb16ca4b, 0x523e913b437ea, 0x523e9fdf04efe,
+    0x523eaad9d604d, 0x52408a4cd541c, 0x5240fbc1f06f7, 0x52410e0ea23d0,
+    0x5242dedde008b, 0x5243df0f99469, 0x5244b3560e30b, 0x5244d446816f8,
+    0x5244fc88d566f, 0x52476e6163106, 0x524780ae14ddf, 0x52478ba8e5f2e,
+    0x5248256055180, 0x524958cf33624, 0x524b433d03b42, 0x524bad603e293,
+    0x524d9f1fef33b, 0x524e31857da03, 0x524e6119b2504, 0x524f0d1dd342f,
+    0x52508d68691fc, 0x5250bcfc9dcfd, 0x5250d69b30560, 0x5254031ba0636,
+    0x525444fc86e10, 0x5254e25ce6627, 0x5256db6e78259, 0x5256e669493a8,
+    0x5257e2f2121c1, 0x52590f0f0fadb, 0x525933a87348d, 0x52598b7efbf05,
+    0x525ceb3c910a1, 0x525dd921987a6, 0x525f13e2577d4, 0x525f9ef605312,
+    0x5260faa73772d, 0x526140310e4cc, 0x5263911928b76, 0x5263d6a2ff915,
+    0x5264446f2a62b, 0x5264989cc2ade, 0x5264c4880701a, 0x526535fd222f5,
+    0x52657b86f9094, 0x5265e5aa337e5, 0x52665e712f64a, 0x5266d7382b4af,
+    0x5267d3c0f42c8, 0x5269b6dce3c5c, 0x5269e6711875d, 0x526b0b3c354ed,
+    0x526b5f69cd9a0, 0x526b87ac21917, 0x526bd830c9805, 0x526d462ead8f9,
+    0x526d5fcd4015c, 0x526d9e0536371, 0x526df984af3ae, 0x526e3413b4ffe,
+    0x526e3b6595b88, 0x526e8bea3da76, 0x526eb083a1428, 0x526ef9b66878c,
+    0x527172e0d6dad, 0x52719b232ad24, 0x5271d209403af, 0x52736f9b58fa4,
+    0x5275182842ce8, 0x5275899d5dfc3, 0x5276026459e28, 0x52777b5d0f06b,
+    0x527802c7cc5e4, 0x52785a9e5505c, 0x52792ee4c9efe, 0x5279328dba4c3,
+    0x52794c2c4cd26, 0x527bf4eaefe48, 0x527c3322e605d, 0x527d494a416d9,
+    0x527e5476cbc06, 0x527f2c663106d, 0x527f54a884fe4, 0x527f71f007e0c,
+    0x527f843cb9ae5, 0x527f96896b7be, 0x527feab703c71, 0x5281ca2a03040,
+    0x52836b650c1fa, 0x5283a24b21885, 0x5284b1209c377, 0x5285c39f0742e,
+    0x5285c747f79f3, 0x5286107abed57, 0x528676f508ee3, 0x52868ceaab181,
+    0x5286b8d5ef6bd, 0x5286cb22a1396, 0x5286d9c662aaa, 0x52878d1c6455f,
+    0x5287ae0cd794c, 0x5287f73f9ecb0, 0x5287fae88f275, 0x52898d7fd6d1b,
+    0x528b709bc66af, 0x528ca061b458e, 0x528ca7b395118, 0x528cb2ae66267,
+    0x528cc4fb17f40, 0x528cd39ed9654, 0x528d11d6cf869, 0x528dcc7eb1ea8,
+    0x528e3a4adcbbe, 0x528ff524785db, 0x52909d7fa8f41, 0x5290b71e3b7a4,
+    0x5290bac72bd69, 0x5291547e9afbb, 0x5293463e4c063, 0x529396c2f3f51,
+    0x52939a6be4516, 0x5294d52ca3544, 0x5294e02774693, 0x5294f2742636c,
+    0x5295e0592da71, 0x5295f64ecfd0f, 0x5296173f430fc, 0x529681627d84d,
+    0x52971b19eca9f, 0x52971ec2dd064, 0x529734b87f302, 0x52973c0a5fe8c,
+    0x529897bb922a7, 0x5299560c64eab, 0x529976fcd8298, 0x529a3c9f8ba26,
+    0x529bcb8de2f07, 0x529be183851a5, 0x529c5a4a8100a, 0x529cbd1bdabd1,
+    0x529dbd4d93faf, 0x529edac6d01b5, 0x529ede6fc077a, 0x529ee218b0d3f,
+    0x529f4fe4dba55, 0x529fbdb10676b, 0x52a0ba39cf584, 0x52a1a475e66c4,
+    0x52a34d02d0408, 0x52a53770a0926, 0x52a649ef0b9dd, 0x52a64d97fbfa2,
+    0x52a6638d9e240, 0x52a6b7bb366f3, 0x52a7cde291d6f, 0x52a81e6739c5d,
+    0x52a846a98dbd4, 0x52ab4395c91a9, 0x52ab473eb976e, 0x52ac058f8c372,
+    0x52ac267fff75f, 0x52ad738d70466, 0x52af14c879620, 0x52b1c4d8fd2cc,
+    0x52b3ffcb756d8, 0x52b5f8dd0730a, 0x52b73aefa6ec2, 0x52b9ca0fb7781,
+    0x52baeedad4511, 0x52bc9eb99eddf, 0x52bd93f08706e, 0x52bdbfdbcb5aa,
+    0x52bec75f65512, 0x52bfaa499bac8, 0x52c061488db42, 0x52c0c070f7144,
+    0x52c0da0f899a7, 0x52c2cf782b014, 0x52c2f0689e401, 0x52c4411eff6cd,
+    0x52c532acf7397, 0x52c60a9c5c7fe, 0x52c771485fd68, 0x52c7e6666b608,
+    0x52c93373dc30f, 0x52c949697e5ad, 0x52c9580d3fcc1, 0x52c95bb630286,
+    0x52c96e02e1f5f, 0x52c9b38cb8cfe, 0x52c9c9825af9c, 0x52caac6c91552,
+    0x52cba54c69da6, 0x52cccdc0770fb, 0x52d05969507d3, 0x52d09b4a36fad,
+    0x52d185864e0ed, 0x52d1adc8a2064, 0x52d3405fe9b0a, 0x52d3cf1c87c0d,
+    0x52d4ae5dcdbfe, 0x52d540c35c2c6, 0x52d57efb524db, 0x52d640f5156a4,
+    0x52d65341c737d, 0x52d7621741e6f, 0x52d7b29be9d5d, 0x52d7dade3dcd4,
+    0x52d96d758577a, 0x52da69fe4e593, 0x52db41edb39fa, 0x52dbc206903e9,
+    0x52dbdf4e13211, 0x52dc45c85d39d, 0x52dc790582463, 0x52dd45fa1677b,
+    0x52dd5846c8454, 0x52ddf1fe376a6, 0x52dea1ab48b96, 0x52dedc3a4e7e6,
+    0x52df5ffc1b79a, 0x52dfc2cd75361, 0x52dff261a9e62, 0x52e0f63c53805,
+    0x52e313e748de9, 0x52e42665b3ea0, 0x52e468469a67a, 0x52e4b179619de,
+    0x52e4b8cb42568, 0x52e5b8fcfb946, 0x52e5e4e83fe82, 0x52e76189e568a,
+    0x52e7ddf9d1ab4, 0x52e7f0468378d, 0x52e8e9265bfe1, 0x52e8f0783cb6b,
+    0x52e9066ddee09, 0x52e9534996732, 0x52e9a3ce3e620, 0x52ec8372f6dcd,
+    0x52ed122f94ed0, 0x52edba8ac5836, 0x52ee5099444c3, 0x52ee802d78fc4,
+    0x52ee96231b262, 0x52ef0b4126b02, 0x52efcd3ae9ccb, 0x52f033b533e57,
+    0x52f146339ef0e, 0x52f175c7d3a0f, 0x52f1acade909a, 0x52f1b7a8ba1e9,
+    0x52f2d17905e2a, 0x52f49af662f5b, 0x52f59b281c339, 0x52f5bc188f726,
+    0x52f5c36a702b0, 0x52f6890d23a3e, 0x52f6d23feada2, 0x52f7aa2f50209,
+    0x52f7cec8b3bbb, 0x52f7e11565894, 0x52f9c43155228, 0x52fb70672f531,
+    0x52fcde6513625, 0x52fd2ee9bb513, 0x52fd70caa1ced, 0x52fee271763a6,
+    0x5300c936562ff, 0x5300f178aa276, 0x5301c5bf1f118, 0x5303251941af8,
+    0x530433eebc5ea, 0x53045c3110561, 0x5304acb5b844f, 0x5305048c40ec7,
+    0x5305600bb9f04, 0x5305d529c57a4, 0x5306678f53e6c, 0x53067d84f610a,
+    0x5306b46b0b795, 0x5306e0564fcd1, 0x530867f2c6628, 0x5308ef5d83ba1,
+    0x530c023f61414, 0x530d02711a7f2, 0x530df3ff124bc, 0x530f0a266db38,
+    0x530f3268c1aaf, 0x530f98e30bc3b, 0x530fff5d55dc7, 0x5310de9e9bdb8,
+    0x5313e685a84dc, 0x531433615fe05, 0x531457fac37b7, 0x5314ac285bc6a,
+    0x531554838c5d0, 0x53158b69a1c5b, 0x5315c5f8a78ab, 0x5316eac3c463b,
+    0x53171306185b2, 0x5317d156eb1b6, 0x531842cc06491, 0x5318a59d60058,
+    0x53191abb6b8f8, 0x531979e3d4efa, 0x531aad52b339e, 0x531b05293be16,
+    0x531bce74dfb69, 0x531bef6552f56, 0x531d267d219bf, 0x531d5d633704a,
+    0x531d8cf76bb4b, 0x531ef3a36f0b5, 0x531f616f99dcb, 0x531f6c6a6af1a,
+    0x53215e2a1bfc2, 0x532306b705d06, 0x532453c476a0d, 0x5324c53991ce8,
+    0x5324d786439c1, 0x532595d7165c5, 0x53260e9e1242a, 0x532615eff2fb4,
+    0x532687650e28f, 0x53273e6400309, 0x5327afd91b5e4, 0x5327fcb4d2f0d,
+    0x532bdc8b44a98, 0x532c0fc869b5e, 0x532c25be0bdfc, 0x532d9764e04b5,
+    0x532e22788dff3, 0x532e81a0f75f5, 0x532ebc2ffd245, 0x532f60e23d5e6,
+    0x532fa2c323dc0, 0x5330b8ea7f43c, 0x53310d18178ef, 0x533182362318f,
+    0x53336ca3f36ad, 0x5333aadbe98c2, 0x533440ea6854f, 0x53344be53969e,
+    0x53346583cbf01, 0x5334f09779a3f, 0x533565b5852df, 0x5336240657ee3,
+    0x5336489fbb895, 0x53375023557fd, 0x5337e288e3ec5, 0x53388e8d04df0,
+    0x5338c91c0aa40, 0x5339667c6a257, 0x5339a10b6fea7, 0x5339b70112145,
+    0x5339e69546c46, 0x533ad47a4e34b, 0x533b583c1b2ff, 0x533c2187bf052,
+    0x533c720c66f40, 0x533db07616533, 0x533ea5acfe7c2, 0x533ebf4b91025,
+    0x533f296ecb776, 0x533f4e082f128, 0x5340bc061321c, 0x53410c8abb10a,
+    0x53412d7b2e4f7, 0x5342adc5c42c4, 0x5343686da6903, 0x53449f857536c,
+    0x534509a8afabd, 0x5345393ce45be, 0x534598654dbc0, 0x5345fb36a7787,
+    0x53462721ebcc3, 0x5346cf7d1c629, 0x53475e39ba72c, 0x534769348b87b,
+    0x5347a76c81a90, 0x5348b9eaecb47, 0x534ac54930452, 0x534bbe2908ca6,
+    0x534bccccca3ba, 0x534c6a2d29bd1, 0x534de325dee14, 0x534dee20aff63,
+    0x534f04480b5df, 0x5350b7cfc6472, 0x5351f9e26602a, 0x53537dd5ec3bc,
+    0x5353bfb6d2b96, 0x5353c708b3720, 0x535408e999efa, 0x535493fd47a38,
+    0x5354a649f9711, 0x5354bc3f9b9af, 0x5354c73a6cafe, 0x535576e77dfee,
+    0x5356ceefbfe44, 0x535790e98300d, 0x53584f3a55c11, 0x53593d1f5d316,
+    0x5359705c823dc, 0x5359e9237e241, 0x535a8dd5be5e2, 0x535bb9f2bbefc,
+    0x535c415d79475, 0x535cab80b3bc6, 0x535d11fafdd52, 0x535d9d0eab890,
+    0x535e15d5a76f5, 0x535ec582b8be5, 0x536066bdc1d9f, 0x5360e6d69e78e,
+    0x5362bef7bcfd3, 0x5363b085b4c9d, 0x5363b7d795827, 0x5363cdcd37ac5,
+    0x536442eb43365, 0x536538222b5f4, 0x5365eb782d0a9, 0x5366f6a4b75d6,
+    0x536734dcad7eb, 0x5367d23d0d002, 0x5369e4ed31497, 0x536d4fa597782,
+    0x536d56f77830c, 0x536ecc473cf8a, 0x536ee98ebfdb2, 0x53700707fbfb8,
+    0x53718afb8234a, 0x537275379948a, 0x53746aa03aaf7, 0x53751df63c5ac,
+    0x53776b3566691, 0x5378b499e6dd3, 0x5378ce3879636, 0x5378fa23bdb72,
+    0x5378fdccae137, 0x537910195fe10, 0x537934b2c37c2, 0x537b68535b044,
+    0x537b8943ce431, 0x537ca6bd0a637, 0x537e320271553, 0x537ecbb9e07a5,
+    0x537f0d9ac6f7f, 0x538098e02de9b, 0x5380cfc643526, 0x5381a40cb83c8,
+    0x5381ab5e98f52, 0x5383837fb7797, 0x5383ba65cce22, 0x5385bac93f5de,
+    0x5385d467d1e41, 0x5386a5055671e, 0x53887d2674f63, 0x53888bca36677,
+    0x5389e3d2784cd, 0x538ad9096075c, 0x538c5953f6529, 0x538d7e1f132b9,
+    0x538dd5f59bd31, 0x538ecb2c83fc0, 0x5390f3d24a6f3, 0x53914ba8d316b,
+    0x53919c2d7b059, 0x53949cc2a6bf3, 0x5394c504fab6a, 0x539519329301d,
+    0x539569b73af0b, 0x5395785afc61f, 0x5397abfb93ea1, 0x5397e68a99af1,
+    0x539857ffb4dcc, 0x53997921e1597, 0x5399ac5f0665d, 0x5399d0f86a00f,
+    0x539b5142ffddc, 0x539bb06b693de, 0x539bbb663a52d, 0x539c1a8ea3b2f,
+    0x539d0121ca6aa, 0x539d84e39765e, 0x539e8c67315c6, 0x539ee7e6aa603,
+    0x539f67ff86ff2, 0x53a10ce380771, 0x53a1b53eb10d7, 0x53a2aa7599366,
+    0x53a3ec8838f1e, 0x53a435bb00282, 0x53a440b5d13d1, 0x53a59c67037ec,
+    0x53a618d6efc16, 0x53a887068d0e8, 0x53a92bb8cd489, 0x53a95b4d01f8a,
+    0x53a9b6cc7afc7, 0x53a9d7bcee3b4, 0x53aab6fe343a5, 0x53ab336e207cf,
+    0x53ab78f7f756e, 0x53abfcb9c4522, 0x53ac2c4df9023, 0x53ac92c8431af,
+    0x53accd5748dff, 0x53ad511915db3, 0x53ada546ae266, 0x53ae67407142f,
+    0x53af5c77596be, 0x53b2e477427d1, 0x53b2fa6ce4a6f, 0x53b4895b3bf50,
+    0x53b552a6dfca3, 0x53b5b92129e2f, 0x53b71c243cdd4, 0x53b7271f0df23,
+    0x53b7d6cc1f413, 0x53b8066053f14, 0x53b8e1f8a9940, 0x53b8ff402c768,
+    0x53bc122209fdb, 0x53bce2bf8e8b8, 0x53bdf8e6e9f34, 0x53bf66e4ce028,
+    0x53bf9a21f30ee, 0x53bfdfabc9e8d, 0x53c0303071d7b, 0x53c13b5cfc2a8,
+    0x53c1eb0a0d798, 0x53c37da15523e, 0x53c413afd3ecb, 0x53c430f756cf3,
+    0x53c59051796d3, 0x53c593fa69c98, 0x53c61f0e177d6, 0x53c638acaa039,
+    0x53c72a3aa1d03, 0x53c7a6aa8e12d, 0x53c8983885df7, 0x53c8c423ca333,
+    0x53c9ae5fe1473, 0x53cb4f9aea62d, 0x53cb9524c13cc, 0x53ccff79b4efb,
+    0x53ce78726a13e, 0x53cf62ae8127e, 0x53d0b364e254a, 0x53d0dba7364c1,
+    0x53d16a63d45c4, 0x53d3bef4df233, 0x53d3e737331aa, 0x53d71eb2743cf,
+    0x53d7a27441383, 0x53d7eba7086e7, 0x53d881b587374, 0x53d943af4a53d,
+    0x53dae14163132, 0x53db4412bccf9, 0x53dbae35f744a, 0x53dd272eac68d,
+    0x53dd6cb88342c, 0x53dedab667520, 0x53df1c974dcfa, 0x53dfbda09dad6,
+    0x53e0cc76185c8, 0x53e0d3c7f9152, 0x53e1f14135358, 0x53e4790f6508d,
+    0x53e492adf78f0, 0x53e6e73f0255f, 0x53e7f26b8ca8c, 0x53e8469924f3f,
+    0x53e8ce03e24b8, 0x53ebfa845258e, 0x53ed26a14fea8, 0x53ed7acee835b,
+    0x53edc401af6bf, 0x53eea6ebe5c75, 0x53ef31ff937b3, 0x53ef8d7f0c7f0,
+    0x53ef9879dd93f, 0x53f08db0c5bce, 0x53f0da8c7d4f7, 0x53f1117292b82,
+    0x53f4abbf2d96e, 0x53f58ea963f24, 0x53f86aa52c10c, 0x53f9b060bc289,
+    0x53f9bf047d99d, 0x53fb170cbf7f3, 0x53fc089ab74bd, 0x53fc13958860c,
+    0x53fc3485fb9f9, 0x53fdcac633a64, 0x53feadb06a01a, 0x53feb8ab3b169,
+    0x53febffd1bcf3, 0x53ff5262aa3bb, 0x53ff97ec8115a, 0x5400c0608e4af,
+    0x5400fe98846c4, 0x5401700d9f99f, 0x5401f7785cf18, 0x540309f6c7fcf,
+    0x540382bdc3e34, 0x5404c87953fb1, 0x5408cce9294ee, 0x54090b211f703,
+    0x54095f4eb7bb6, 0x540b75a7cc610, 0x540b7cf9ad19a, 0x540eb0cbfddfa,
+    0x540f3f889befd, 0x540f43318c4c2, 0x540f4e2c5d611, 0x540f72c5c0fc3,
+    0x5410a6349f467, 0x5411e0f55e495, 0x5411f6eb00733, 0x541290a26f985,
+    0x5412efcad8f87, 0x5413f3a58292a, 0x5414dde199a6a, 0x5414f02e4b743,
+    0x54176206d91da, 0x54188a7ae652f, 0x5419710e0d0aa, 0x541974b6fd66f,
+    0x541aaf77bc69d, 0x541bb35266040, 0x541be68f8b106, 0x541ca8894e2cf,
+    0x541ccd22b1c81, 0x541d24f93a6f9, 0x541d4d3b8e670, 0x541d5bdf4fd84,
+    0x541e7d017c54f, 0x542063c65c4a8, 0x54210126bbcbf, 0x54210fca7d3d3,
+    0x54216b49f6410, 0x54236bad68bcc, 0x5423ce7ec2793, 0x5424c00cba45d,
+    0x54266feb84d2b, 0x5427c0a1e5ff7, 0x5427d69788295, 0x5428c47c8f99a,
+    0x5428fb62a5025, 0x5429bd5c681ee, 0x5429c105587b3, 0x542a745b5a268,
+    0x542ae22784f7e, 0x542ba07857b82, 0x542bb66df9e20, 0x542c870b7e6fd,
+    0x542ce9dcd82c4, 0x542d7feb56f51, 0x542de665a10dd, 0x542e0ea7f5054,
+    0x542e9d6493157, 0x542ed7f398da7, 0x542ef53b1bbcf, 0x54308b7b53c3a,
+    0x5430bb0f8873b, 0x5434012e8b074, 0x543546ea1b1f1, 0x5437ceb84af26,
+    0x54385d74e9029, 0x5439097909f54, 0x543947b100169, 0x543985e8f637e,
+    0x543b4f66534af, 0x543b6cadd62d7, 0x543c02bc54f64, 0x543c48462bd03,
+    0x543ca76e95305, 0x543d0de8df491, 0x543d65bf67f09, 0x543ddadd737a9,
+    0x543ee609fdcd6, 0x5440f8ba2216b, 0x544120fc760e2, 0x54413e43f8f0a,
+    0x544141ece94cf, 0x544407f30f419, 0x5444c643e201d, 0x5445bb7aca2ac,
+    0x54478c4a07f67, 0x544990566ace8, 0x544bbca5219e0, 0x544c0980d9309,
+    0x544c4b61bfae3, 0x544e481c41cda, 0x544e61bad453d, 0x544ecf86ff253,
+    0x54510e2267c24, 0x54515ea70fb12, 0x54536dae439e2, 0x54537ffaf56bb,
+    0x5453d07f9d5a9, 0x5454f1a1c9d74, 0x545545cf62227, 0x5455581c13f00,
+    0x54566e436f57c, 0x5456ee5c4bf6b, 0x5456f9571d0ba, 0x545880f393a11,
+    0x5458acded7f4d, 0x5458dc730ca4e, 0x5459a5beb07a1, 0x5459e04db63f1,
+    0x5459f29a680ca, 0x545abf8efc3e2, 0x545c29e3eff11, 0x545c6bc4d66eb,
+    0x545ce48bd2550, 0x545d17c8f7616, 0x545d4b061c6dc, 0x545de8667bef3,
+    0x545e612d77d58, 0x545e9bbc7d9a8, 0x545ee898352d1, 0x545f56645ffe7,
+    0x545f5a0d505ac, 0x545fa6e907ed5, 0x545fcf2b5be4c, 0x54601c0713775,
+    0x546068e2cb09e, 0x5460f79f691a1, 0x5463c4f76fc75, 0x5464157c17b63,
+    0x546453b40dd78, 0x5464ab8a967f0, 0x54658379fbc57, 0x5466491caf3e5,
+    0x54673aaaa70af, 0x54690428041e0, 0x5469759d1f4bb, 0x546b4dbe3dd00,
+    0x546c25ada3167, 0x546ce7a766330, 0x546d0c40c9ce2, 0x546d766404433,
+    0x546e29ba05ee8, 0x546ed5be26e13, 0x54708ceed226b, 0x5470f3691c3f7,
+    0x54712a4f31a82, 0x5471a6bf1deac, 0x5472d6850bd8b, 0x5473de08a5cf3,
+    0x54750a25a360d, 0x54766d28b65b2, 0x54767bcc77cc6, 0x5476c4ff3f02a,
+    0x5476f0ea83566, 0x54773dc63ae8f, 0x5477a7e9755e0, 0x5478f14df5d22,
+    0x54790e9578b4a, 0x54792bdcfb972, 0x547978b8b329b, 0x547bf1e3218bc,
+    0x547bf93502446, 0x547c6aaa1d721, 0x547cb785d504a, 0x547cf214dac9a,
+    0x547d379eb1a39, 0x547df5ef8463d, 0x547eeecf5ce91, 0x54810c7a52475,
+    0x54811775235c4, 0x54813c0e86f76, 0x54844ef0647e9, 0x548485d679e74,
+    0x5487448abf234, 0x548798b8576e7, 0x548870a7bcb4e, 0x5489030d4b216,
+    0x548aed7b1b734, 0x548b0ac29e55c, 0x548c5b78ff828, 0x548cf8d95f03f,
+    0x548eb00a0a497, 0x548f33cbd744b, 0x548f63600bf4c, 0x548fa8e9e2ceb,
+    0x549121e297f2e, 0x5492a22d2dcfb, 0x54932997eb274, 0x54933be49cf4d,
+    0x54946458aa2a2, 0x549476a55bf7b, 0x5494d224d4fb8, 0x5495b50f0b56e,
+    0x54962a2d16e0e, 0x5497c7bf2fa03, 0x5497da0be16dc, 0x549839344acde,
+    0x549c748a358a6, 0x549ca07579de2, 0x549ce256605bc, 0x549f1d48d89c8,
+    0x549f667b9fd2c, 0x549f9d61b53b7, 0x549fa85c86506, 0x54a07ca2fb3a8,
+    0x54a0f569f720d, 0x54a19dc527b73, 0x54a1c25e8b525, 0x54a375e6463b8,
+    0x54a3b41e3c5cd, 0x54a3d8b79ff7f, 0x54a77dff0beba, 0x54a831550d96f,
+    0x54a9980110ed9, 0x54ac2378311d3, 0x54ac396dd3471, 0x54ac82a09a7d5,
+    0x54ac9fe81d5fd, 0x54ad23a9ea5b1, 0x54ad7f29635ee, 0x54afdeb53f3ac,
+    0x54b2a11274d31, 0x54b2edee2c65a, 0x54b56e6a7b805, 0x54b63f08000e2,
+    0x54b6f9afe2721, 0x54b7294417222, 0x54b78c1570de9, 0x54b94e40ed390,
+    0x54bc2de5a5b3d, 0x54bc527f094ef, 0x54bd64fd745a6, 0x54beddf6297e9,
+    0x54bf56bd2564e, 0x54bfe579c3751, 0x54bff7c67542a, 0x54c1fbd2d81ab,
+    0x54c3e9e998c8e, 0x54c407311bab6, 0x54c436c5505b7, 0x54c56ddd1f020,
+    0x54c6b398af19d, 0x54c7166a08d64, 0x54c7b3ca6857b, 0x54c8930bae56c,
+    0x54c8acaa40dcf, 0x54c996e657f0f, 0x54ccf6a3ed0ab, 0x54cd9eff1da11,
+    0x54d123561655f, 0x54d35e488e96b, 0x54d37f3901d58, 0x54d4d74143bae,
+    0x54d4ed36e5e4c, 0x54d520740af12, 0x54d586ee5509e, 0x54d591e9261ed,
+    0x54d5e26dce0db, 0x54d6af62623f3, 0x54d7ea2321421, 0x54d9b3a07e552,
+    0x54daee613d580, 0x54db8f6a8d35c, 0x54dd678babba1, 0x54dd8c250f553,
+    0x54ddb467634ca, 0x54ddf64849ca4, 0x54df2265475be, 0x54e13c674c5dd,
+    0x54e2aa65306d1, 0x54e3a34508f25, 0x54e49c24e1779, 0x54e4da5cd798e,
+    0x54e78a6d5b63a, 0x54e7de9af3aed, 0x54eb2fb4c7575, 0x54ebdbb8e84a0,
+    0x54ec3ae151aa2, 0x54ed5108ad11e, 0x54ed87eec27a9, 0x54ef9e47d7203,
+    0x54efc68a2b17a, 0x54f0dcb1867f6, 0x54f125e44db5a, 0x54f35984e53dc,
+    0x54f41b7ea85a5, 0x54f49445a440a, 0x54f5a6c40f4c1, 0x54f5c40b922e9,
+    0x54f756a2d9d8f, 0x54f7a37e916b8, 0x54f88668c7c6e, 0x54f8950c89382,
+    0x54f8ece311dfa, 0x54f9152565d71, 0x54f97b9fafefd, 0x54fedf69a7e1a,
+    0x54ff2fee4fd08, 0x54ff5830a3c7f, 0x55005c0b4d622, 0x5501a918be329,
+    0x550216e4e903f, 0x5502f6262f030, 0x55043838cebe8, 0x5504816b95f4c,
+    0x5505e46ea8ef1, 0x5506adba4cc44, 0x5506c3afeeee2, 0x5506e4a0622cf,
+    0x5506ef9b3341e, 0x5507b194f65e7, 0x550926e4bb265, 0x5509651cb147a,
+    0x5509776963153, 0x5509e5358de69, 0x550b7f1eb6499, 0x550bda9e2f4d6,
+    0x550d2402afc18, 0x550ddb01a1c92, 0x550ded4e5396b, 0x550e4524dc3e3,
+    0x550ee2853bbfa, 0x550faf79cff12, 0x55116dfc5bef4, 0x5511d81f96645,
+    0x5512f941c2e10, 0x55140f691e48c, 0x55151e3e98f7e, 0x55152ce25a692,
+    0x5515d8e67b5bd, 0x5515f62dfe3e5, 0x55169ae03e786, 0x5517224afbcff,
+    0x5517433b6f0ec, 0x551893f1d03b8, 0x5518d229c65cd, 0x5519a2c74aeaa,
+    0x5519b8bced148, 0x551a6fbbdf1c2, 0x551b18170fb28, 0x551b59f7f6302,
+    0x551c10f6e837c, 0x551cb5a92871d, 0x551d0d7fb1195, 0x551d7ef4cc470,
+    0x551e742bb46ff, 0x551f412048a17, 0x55219d0334210, 0x5523fc8f0ffce,
+    0x55245bb7795d0, 0x552466b24a71f, 0x5526218be613c, 0x55264d772a678,
+    0x55268806302c8, 0x5527639e85cf4, 0x55281e4668333, 0x55295cb017926,
+    0x552b6bb74b7f6, 0x552b93f99f76d, 0x552bd9837650c, 0x552c22b63d870,
+    0x552cb172db973, 0x552e948ecb307, 0x552f1fa278e45, 0x552fe5452c5d3,
+    0x5530188251699, 0x5534502f4bc9c, 0x5534c54d5753c, 0x5535197aef9ef,
+    0x55351d23dffb4, 0x5535839e2a140, 0x5535f16a54e56, 0x5535fc6525fa5,
+    0x5536752c21e0a, 0x5536d0ab9ae47, 0x5537e6d2f64c3, 0x553845fb5fac5,
+    0x55393b3247d54, 0x553949d609468, 0x55395127e9ff2, 0x5539e73668c7f,
+    0x553ad51b70384, 0x553c5cb7e6cdb, 0x553f6bf0d3f89, 0x553fa2d6e9614,
+    0x5540d9eeb807d, 0x55410982ecb7e, 0x55432e7fc2cec, 0x5544dab59cff5,
+    0x5546ed65c148a, 0x554715a815401, 0x55471cf9f5f8b, 0x5549f54ccdbae,
+    0x554a0eeb60411, 0x554ccd9fa57d1, 0x554cf98ae9d0d, 0x554de3c700e4d,
+    0x554df26ac2561, 0x554e6788cde01, 0x554e9e6ee348c, 0x554ff677252e2,
+    0x55516475093d6, 0x55517a6aab674, 0x5551caef53562, 0x555377252d86b,
+    0x55555a411d1ff, 0x5555b217a5c77, 0x5555eca6ab8c7, 0x5556ecd864ca5,
+    0x555744aeed71d, 0x5557c11ed9b47, 0x55594c6440a63, 0x555bfc74c470f,
+    0x555d0b4a3f201, 0x555df58656341, 0x555dfcd836ecb, 0x555ea18a7726c,
+    0x555eee662eb95, 0x555fb408e2323, 0x555fd8a245cd5, 0x555feaeef79ae,
+    0x55605c6412c89, 0x556080fd7663b, 0x55610fba1473e, 0x556146a029dc9,
+    0x55642644e2576, 0x5564647cd878b, 0x5565dd758d9ce, 0x55663c9df6fd0,
+    0x5567f3cea2428, 0x556c91f5e6bb7, 0x556f0b20551d8, 0x556f2867d8000,
+    0x556f8b3931bc7, 0x556f928b12751, 0x557171fe11b20, 0x5572cdaf43f3b,
+    0x5573bf3d3bc05, 0x5573e77f8fb7c, 0x5574296076356, 0x5574b81d14459,
+    0x5575ef34e2ec2, 0x5576819a7158a, 0x55776484a7b40, 0x55777e233a3a3,
+    0x55781431b9030, 0x55781f2c8a17f, 0x5579cb6264488, 0x557a7eb865f3d,
+    0x557bfb5a0b745, 0x557c0da6bd41e, 0x557ca75e2c670, 0x557cab071cc35,
+    0x557cc84e9fa5d, 0x557da03e04ec4, 0x557e4feb163b4, 0x557e5ae5e7503,
+    0x557ef0f466190, 0x557efbef372df, 0x557f9cf8870bb, 0x558069ed1b3d3,
+    0x5581d4420ef02, 0x5582a88883da4, 0x5582f1bb4b108, 0x55832ff34131d,
+    0x558407e2a6784, 0x5584218138fe7, 0x5584b78fb7c74, 0x5585c2bc421a1,
+    0x5585e3acb558e, 0x5586c2edfb57f, 0x55878c399f2d2, 0x5588642904739,
+    0x558ac3b4e04f7, 0x558ad258a1c0b, 0x558bf37ace3d6, 0x558caa79c0450,
+    0x558d68ca93054, 0x558e2e6d467e2, 0x558e9c39714f8, 0x558e9fe261abd,
+    0x558eae86231d1, 0x558eecbe193e6, 0x558f6cd6f5dd5, 0x558f7f23a7aae,
+    0x5590d382f933f, 0x5594508811303, 0x559478ca6527a, 0x55948b1716f53,
+    0x559503de12db8, 0x5595a4e762b94, 0x5596583d64649, 0x559742797b789,
+    0x559780b17199e, 0x5597bee967bb3, 0x559a6ef9eb85f, 0x559a81469d538,
+    0x559ad91d25fb0, 0x559bfde842d40, 0x559e5d741eafe, 0x559f405e550b4,
+    0x55a09fb877a94, 0x55a14f6588f84, 0x55a15a605a0d3, 0x55a1b236e2b4b,
+    0x55a1b988c36d5, 0x55a3cc38e7b6a, 0x55a4ffa7c600e, 0x55a5711ce12e9,
+    0x55a595b644c9b, 0x55a695e7fe079, 0x55a7e69e5f345, 0x55a8d82c5700f,
+    0x55a91a0d3d7e9, 0x55a9cd633f29e, 0x55ab1a70affa5, 0x55ab80eafa131,
+    0x55abe3bc53cf8, 0x55ac259d3a4d2, 0x55aceee8de225, 0x55adb0e2a13ee,
+    0x55aee7fa6fe57, 0x55afc73bb5e48, 0x55afe48338c70, 0x55affa78daf0e,
+    0x55b0d9ba20eff, 0x55b31fa76a45a, 0x55b35a36700aa, 0x55b43225d5511,
+    0x55b54f9f11717, 0x55b59c7ac9040, 0x55b5b9c24be68, 0x55b5dab2bf255,
+    0x55b69cac8241e, 0x55b6aef9340f7, 0x55b8a461d5764, 0x55b8e9ebac503,
+    0x55bbac48e1e88, 0x55bdd4eea85bb, 0x55bef267e47c1, 0x55bef9b9c534b,
+    0x55bf170148173, 0x55c05cbcd82f0, 0x55c10c69e97e0, 0x55c15945a1109,
+    0x55c3266bee7ff, 0x55c32dbdcf389, 0x55c359a9138c5, 0x55c39b89fa09f,
+    0x55c3e4bcc1403, 0x55c417f9e64c9, 0x55c44b370b58f, 0x55c5ddce53035,
+    0x55c5f3c3f52d3, 0x55c969772c70d, 0x55c98a679fafa, 0x55ca157b4d638,
+    0x55cad3cc2023c, 0x55cbdb4fba1a4, 0x55cc282b71acd, 0x55ccf52005de5,
+    0x55ce004c90312, 0x55ce1642325b0, 0x55ce213d036ff, 0x55ce8f092e415,
+    0x55cf46082048f, 0x55d1516663d9a, 0x55d37db51aa92, 0x55d3c6e7e1df6,
+    0x55d4609f51048, 0x55d488e1a4fbf, 0x55d5ef8da8529, 0x55d6ceceee51a,
+    0x55d78d1fc111e, 0x55d873b2e7c99, 0x55d89bf53bc10, 0x55d89f9e2c1d5,
+    0x55d914bc37a75, 0x55d973e4a1077, 0x55d9b21c9728c, 0x55dd7fa65713e,
+    0x55de2f536862e, 0x55def4f61bdbc, 0x55df123d9ebe4, 0x55dfd7e052372,
+    0x55e0ee07ad9ee, 0x55e12fe8941c8, 0x55e558f1cd0b7, 0x55e576394fedf,
+    0x55e5d1b8c8f1c, 0x55e6014cfda1d, 0x55e6a2564d7f9, 0x55e6c346c0be6,
+    0x55e760a7203fd, 0x55e78197937ea, 0x55e7903b54efe, 0x55e7a28806bd7,
+    0x55e7a9d9e7761, 0x55e8e0f1b61ca, 0x55ec395d6a7dc, 0x55ec9885d3dde,
+    0x55ecc4711831a, 0x55ecde0faab7d, 0x55ed9c607d781, 0x55edb9a8005a9,
+    0x55ef8a773e264, 0x55f136ad1856d, 0x55f5bb35ca499, 0x55f6e3a9d77ee,
+    0x55f71e38dd43e, 0x55f7a1faaa3f2, 0x55f93f8cc2fe7, 0x55f997634ba5f,
+    0x55f9fa34a5626, 0x55fce4d42ef22, 0x55fd7e8b9e174, 0x55fda6cdf20eb,
+    0x55feb94c5d1a2, 0x55fef3db62df2, 0x55ff779d2fda6, 0x56003996f2f6f,
+    0x56052842df5ec, 0x560666ac8ebdf, 0x56074244e460b, 0x56075be376e6e,
+    0x560842769d9e9, 0x5608a19f06feb, 0x5608a547f75b0, 0x56095fefd9bef,
+    0x560b25c44675b, 0x560c0c576d2d6, 0x560c46e672f26, 0x560c558a3463a,
+    0x560ccaa83feda, 0x560cf2ea93e51, 0x560d018e55565, 0x560d5d0dce5a2,
+    0x560dd5d4ca407, 0x560eb16d1fe33, 0x56103560a61c5, 0x561059fa09b77,
+    0x5610823c5daee, 0x561168cf84669, 0x5611d69baf37f, 0x5611dded8ff09,
+    0x56130db37dde8, 0x561356e64514c, 0x56146212cf679, 0x56155749b7908,
+    0x561632e20d334, 0x5616fc2db1087, 0x56178aea4f18a, 0x561795e5202d9,
+    0x5617be2774250, 0x561966b45df94, 0x561a633d26dad, 0x561b75bb91e64,
+    0x561c2cba83ede, 0x561cc671f3130, 0x561d16f69b01e, 0x561d8fbd96e83,
+    0x561dd8f05e1e7, 0x561deee600485, 0x561dfd89c1b99, 0x561f175a0d7da,
+    0x561fad688c467, 0x561fbc0c4db7b, 0x5621733cf8fd3, 0x562377495bd54,
+    0x5623b92a4252e, 0x5623c7ce03c42, 0x56247b24056f7, 0x562489c7c6e0b,
+    0x562589f9801e9, 0x5625e578f9226, 0x562695260a716, 0x5626aec49cf79,
+    0x56275ac8bdea4, 0x5629b6aba969d, 0x562ad424e58a3, 0x562c3e79d93d2,
+    0x562c66bc2d349, 0x562ee38f8bf2f, 0x562f933c9d41f, 0x56318c4e2f051,
+    0x5633a9f924635, 0x5633cae997a22, 0x563401cfad0ad, 0x5634faaf85901,
+    0x5635efe66db90, 0x563735a1fdd0d, 0x5637c45e9be10, 0x5638322ac6b26,
+    0x563b83449a5ae, 0x563ba08c1d3d6, 0x563c578b0f450, 0x563d1d2dc2bde,
+    0x563d7fff1c7a5, 0x563ed8075e5fb, 0x563f58203afea, 0x563f631b0c139,
+    0x56413b3c2a97e, 0x5641b403267e3, 0x56440c3d21a17, 0x56440fe611fdc,
+    0x564697b441d11, 0x5648a6bb75be1, 0x56496c5e2936f, 0x564985fcbbbd2,
+    0x5649b939e0c98, 0x564af7a39028b, 0x564ba3a7b11b6, 0x564be58897990,
+    0x564c65a17437f, 0x564f788351bf2, 0x564ff89c2e5e1, 0x5650a4a04f50c,
+    0x5651753dd3de9, 0x5651878a85ac2, 0x5653cd77cf01d, 0x565449e7bb447,
+    0x5655dc7f02eed, 0x56561ab6f9102, 0x5656ad1c877ca, 0x565751cec7b6b,
+    0x5658a9d7099c1, 0x5658e80effbd6, 0x5659229e05826, 0x565934eab74ff,
+    0x5659cea226751, 0x565cdddb139ff, 0x565d7ee4637db, 0x565fb9d6dbbe7,
+    0x56613678813ef, 0x5661c18c2ef2d, 0x5661c8de0fab7, 0x5661e27ca231a,
+    0x56628e80c3245, 0x5665423a374b6, 0x5665582fd9754, 0x566592bedf3a4,
+    0x5665dbf1a6708, 0x5665f93929530, 0x566938064b2df, 0x566951a4ddb42,
+    0x5669763e414f4, 0x5669b0cd47144, 0x5669b81f27cce, 0x566bcacf4c163,
+    0x566e56466c45d, 0x566f73bfa8663, 0x56713994151cf, 0x567365e2cbec7,
+    0x5674208aae506, 0x5675c56ea7c85, 0x567692633bf9d, 0x5677879a2422c,
+    0x5678b00e31581, 0x5679d4d94e311, 0x567bb44c4d6e0, 0x567c729d202e4,
+    0x567e053467d8a, 0x5680cee37e299, 0x5681440189b39, 0x568440edc510e,
+    0x5685b9e67a351, 0x5685ed239f417, 0x5686bdc123cf4, 0x568850586b79a,
+    0x56888ae7713ea, 0x5688be24964b0, 0x568936eb92315, 0x5689f8e5554de,
+    0x568d72417cedd, 0x568f1ace66c21, 0x568f4a629b722, 0x569108e527704,
+    0x5692b17211448, 0x56958273084e1, 0x5695b5b02d5a7, 0x5696c82e9865e,
+    0x5696da7b4a337, 0x5697b9bc90328, 0x5699a08170281, 0x569c3a9c51c8f,
+    0x569df1ccfd0e7, 0x569f3036ac6da, 0x56a0674e7b143, 0x56a0883eee530,
+    0x56a0f9b40980b, 0x56a17d75d67bf, 0x56a26b5addec4, 0x56a3384f721dc,
+    0x56a4521fbde1d, 0x56a4646c6faf6, 0x56a46bbe50680, 0x56a4a64d562d0,
+    0x56a4c73dc96bd, 0x56a51b6b61b70, 0x56a543adb5ae7, 0x56a559a357d85,
+    0x56a601fe886eb, 0x56a7356d66b8f, 0x56a752b4e99b7, 0x56a7b1dd52fb9,
+    0x56a80d5ccbff6, 0x56a98da761dc3, 0x56aaed01847a3, 0x56ab9cae95c93,
+    0x56abf4851e70b, 0x56ac24195320c, 0x56aca4322fbfb, 0x56acd76f54cc1,
+    0x56ad7c2195062, 0x56ade9edbfd78, 0x56aded96b033d, 0x56af1d5c9e21c,
+    0x56b0372ce9e5d, 0x56b0ac4af56fd, 0x56b1cd6d21ec8, 0x56b442ee9ff24,
+    0x56b44de971073, 0x56b46ed9e4460, 0x56b4887876cc3, 0x56b4a5bff9aeb,
+    0x56b5f2cd6a7f2, 0x56b917fbf9d3e, 0x56b9528aff98e, 0x56b9aa6188406,
+    0x56bd0a1f1d5a2, 0x56bec8a1a9584, 0x56befbdece64a, 0x56bf207831ffc,
+    0x56bf48ba85f73, 0x56c0712e932c8, 0x56c0ed9e7f6f2, 0x56c18ea7cf4ce,
+    0x56c195f9b0058, 0x56c1fc73fa1e4, 0x56c43eb85317a, 0x56c4809939954,
+    0x56c521a289730, 0x56c533ef3b409, 0x56c6ca2f73474, 0x56c6e3ce05cd7,
+    0x56c7728aa3dda, 0x56c967f345447, 0x56ca522f5c587, 0x56ca76c8bff39,
+    0x56cb0cd73ebc6, 0x56cb6856b7c03, 0x56cbaa379e3dd, 0x56cbe11db3a68,
+    0x56cc688870fe1, 0x56cc8978e43ce, 0x56cd0991c0dbd, 0x56cd148c91f0c,
+    0x56cd4077d6448, 0x56cd4f1b97b5c, 0x56ce8d854714f, 0x56cf6cc68d140,
+    0x56cfcbeef6742, 0x56cffb832b243, 0x56d10306c51ab, 0x56d10e01962fa,
+    0x56d2b68e8003e, 0x56d44925c7ae4, 0x56d457c9891f8, 0x56d4800bdd16f,
+    0x56d4875dbdcf9, 0x56d4afa011c70, 0x56d550a961a4c, 0x56d59d8519375,
+    0x56d649893a2a0, 0x56d6cd4b07254, 0x56d7a53a6c6bb, 0x56d7bed8fef1e,
+    0x56d7cd7cc0632, 0x56d88824a2c71, 0x56d9d53213978, 0x56da79e453d19,
+    0x56db1aeda3af5, 0x56dc93e658d38, 0x56de1f2bbfc54, 0x56deced8d1144,
+    0x56e135b68da8c, 0x56e14f55202ef, 0x56e1863b3597a, 0x56e1bd214b005,
+    0x56e31c7b6d9e5, 0x56e37f4cc75ac, 0x56e41cad26dc3, 0x56e46236fdb62,
+    0x56e48e224209e, 0x56e4f8457c7ef, 0x56e6280b6a6ce, 0x56e712478180e,
+    0x56eac9db9f422, 0x56ec1e3af0cb3, 0x56ec9358fc553, 0x56ecc69621619,
+    0x56eceb2f84fcb, 0x56ed764332b09, 0x56eddcbd7cc95, 0x56f0ef9f5a508,
+    0x56f3358ca3a63, 0x56f3a358ce779, 0x56f439674d406, 0x56f5c4acb4322,
+    0x56f639cabfbc2, 0x56f691a14863a, 0x56f6c8875dcc5, 0x56fb1d7bdb0f0,
+    0x56fbbe852aecc, 0x56fcdbfe670d2, 0x56fd302bff585, 0x56ff3b8a42e90,
+    0x56ff517fe512e, 0x56ff6775873cc, 0x56ff8c0eead7e, 0x56fffd8406059,
+    0x570063fe501e5, 0x57013bedb564c, 0x5703dd5a77be4, 0x570401f3db596,
+    0x57049bab4a7e8, 0x5704fe7ca43af, 0x5705390ba9fff, 0x570610fb0f466,
+    0x5707a73b474d1, 0x5707b5df08be5, 0x5707b987f91aa, 0x5707c0d9d9d34,
+    0x57094c1f40c50, 0x570c0e7c765d5, 0x570c197747724, 0x571033dcbeeff,
+    0x5710d13d1e716, 0x5710d88eff2a0, 0x5715178dda42d, 0x57157a5f33ff4,
+    0x5719d2fca19e4, 0x5719d6a591fa9, 0x571b2eadd3dff, 0x571e99663a0ea,
+    0x571ef13cc2b62, 0x57204cedf4f7d, 0x57208b25eb192, 0x5723a559a958f,
+    0x5723f23560eb8, 0x57248becd010a, 0x5724a1e2723a8, 0x5727bfbf20d6a,
+    0x5727ebaa652a6, 0x572959a84939a, 0x572abcab5c33f, 0x572b85f700092,
+    0x572c09b8cd046, 0x572c110aadbd0, 0x572cae6b0d3e7, 0x572cfb46c4d10,
+    0x572dbd4087ed9, 0x572e02ca5ec78, 0x572e2eb5a31b4, 0x572e77e86a518,
+    0x572f3d8b1dca6, 0x572f6d1f527a7, 0x5733494cd3d6d, 0x57352c68c3701,
+    0x5736fd38013bc, 0x5737257a55333, 0x573763b24b548, 0x5737fd69ba79a,
+    0x57383ba1b09af, 0x573c6101f92d9, 0x573c7aa08bb3c, 0x573cfab96852b,
+    0x573d2a4d9d02c, 0x573f912b59974, 0x573fd30c4014e, 0x574074158ff2a,
+    0x574118c7d02cb, 0x5741360f530f3, 0x5741a7846e3ce, 0x5743501158112,
+    0x57453e2818bf5, 0x5745af9d33ed0, 0x5745c93bc6733, 0x574650a683cac,
+    0x5748b0325fa6a, 0x574bca661de67, 0x574cdce488f1e, 0x574cf2da2b1bc,
+    0x574cfa2c0bd46, 0x574d6f4a175e6, 0x574f38c774717, 0x574fc03231c90,
+    0x5750c40cdb633, 0x575377c64f8a4, 0x57542ec54191e, 0x57544fb5b4d0b,
+    0x57545e597641f, 0x575527a51a172, 0x5755dea40c1ec, 0x5756d3daf447b,
+    0x5757036f28f7c, 0x57571964cb21a, 0x5757f8a61120b, 0x5758f52eda024,
+    0x5759077b8bcfd, 0x575c9324653d5, 0x575cc6618a49b, 0x575ce751fd888,
+    0x575d0beb6123a, 0x575dfd7958f04, 0x575e43032fca3, 0x575f224475c94,
+    0x575f383a17f32, 0x57603119f0786, 0x57609b3d2aed7, 0x5760a9e0ec5eb,
+    0x5760dd1e116b1, 0x5760ef6ac338a, 0x5761389d8a6ee, 0x57615d36ee0a0,
+    0x5761ceac0937b, 0x5762c03a01045, 0x5762e12a74432, 0x57652717bd98d,
+    0x57656ca19472c, 0x57670a33ad321, 0x57677ba8c85fc, 0x5767ed1de38d7,
+    0x5768deabdb5a1, 0x576987070bf07, 0x576afc56d0b85, 0x576bafacd263a,
+    0x576be2e9f7700, 0x576d2c4e77e42, 0x576de34d69ebc, 0x576edfd632cd5,
+    0x576f37acbb74d, 0x57718c3dc63bc, 0x5771d5708d720, 0x57733873a06c5,
+    0x5773854f57fee, 0x577460e7ada1a, 0x57754028f3a0b, 0x577786163cf66,
+    0x5777cba013d05, 0x5779b9b6d47e8, 0x577bb2c86641a, 0x577bd00fe9242,
+    0x577c27e671cba, 0x577f790045742, 0x577f9647c856a, 0x57801db285ae3,
+    0x57809a2271f0d, 0x5780c264c5e84, 0x5781aca0dcfc4, 0x5782e3b8aba2d,
+    0x578425cb4b5e5, 0x578472a702f0e, 0x5784d1cf6c510, 0x57851eab23e39,
+    0x5786f6cc4267e, 0x57874ea2cb0f6, 0x5787aa2244133, 0x5787b17424cbd,
+    0x5787e108597be, 0x5789a6dcc632a, 0x5789b58087a3e, 0x578b4bc0bfaa9,
+    0x578ccfb445e3b, 0x578d18e70d19f, 0x5790331acb59c, 0x579062af0009d,
+    0x5790abe1c7401, 0x5790c1d76969f, 0x579107614043e, 0x5792ac4539bbd,
+    0x57932c5e165ac, 0x5793968150cfd, 0x57940b9f5c59d, 0x57952cc188d68,
+    0x5795306a7932d, 0x57956af97ef7d, 0x57963b970385a, 0x579751be5eed6,
+    0x5797640b10baf, 0x579872e08b6a1, 0x5799228d9cb91, 0x579b2debe049c,
+    0x579b85c268f14, 0x579d47ede54bb, 0x579d7b2b0a581, 0x57a1450bd9e6e,
+    0x57a1d3c877f71, 0x57a4d45da3b0b, 0x57a4e3016521f, 0x57a5e3331e5fd,
+    0x57a68f373f528, 0x57a76acf94f54, 0x57a8ca29b7934, 0x57a98fcc6b0c2,
+    0x57a9c6b28074d, 0x57aafdca4f1b6, 0x57ab7de32bba5, 0x57abe806662f6,
+    0x57acbc4cdb198, 0x57ad76f4bd7d7, 0x57ada2e001d13, 0x57ae9bbfda567,
+    0x57af70064f409, 0x57af89a4e1c6c, 0x57afae3e4561e, 0x57b07b32d9936,
+    0x57b1aea1b7dda, 0x57b218c4f252b, 0x57b35385b1559, 0x57b52ba6cfd9e,
+    0x57b6ea295bd80, 0x57b94d5e28103, 0x57bc3ba6a1fc4, 0x57bc97261b001,
+    0x57bee80e356ab, 0x57befe03d7949, 0x57c01b7d13b4f, 0x57c1b5663c17f,
+    0x57c35a4a358fe, 0x57c3c46d7004f, 0x57c606b1c8fe5, 0x57c68a7395f99,
+    0x57c7a09af1615, 0x57c94927db359, 0x57c9abf934f20, 0x57cb24f1ea163,
+    0x57cd2ca73d4a9, 0x57cd847dc5f21, 0x57cd96ca77bfa, 0x57cddffd3ef5e,
+    0x57cf4dfb23052, 0x57cf6eeb9643f, 0x57cf9384f9df1, 0x57d0816a014f6,
+    0x57d6480552fda, 0x57d6a384cc017, 0x57d756dacdacc, 0x57d7c84fe8da7,
+    0x57d911b4694e9, 0x57d92efbec311, 0x57d9e5fade38b, 0x57dbea074110c,
+    0x57dc7c6ccf7d4, 0x57dea8bb864cc, 0x57df2c7d53480, 0x57e142d667eda,
+    0x57e481a389c89, 0x57e547463d417, 0x57e5857e3362c, 0x57e6d28ba4333,
+    0x57e8b5a793cc7, 0x57e8c44b553db, 0x57e8d2ef16aef, 0x57e92e6e8fb2c,
+    0x57ec9926f5e17, 0x57ee71481465c, 0x57eed0707dc5e, 0x57efdf45f8750,
+    0x57f0546403ff0, 0x57f078fd679a2, 0x57f2fd22a7112, 0x57f36aeed1e28,
+    0x57f41a9be3318, 0x57f4552ae8f68, 0x57f467779ac41, 0x57f5346c2ef59,
+    0x57f5605773495, 0x57f7fe1b45468, 0x57f8b8c327aa7, 0x57f8c3bdf8bf6,
+    0x57f9059edf3d0, 0x57fa68a1f2375, 0x57fbc0aa341cb, 0x57fbf3e759291,
+    0x57fc112edc0b9, 0x57fc4f66d22ce, 0x57fce91e41520, 0x57fd200456bab,
+    0x57ff2b629a4b6, 0x57ffdeb89bf6b, 0x5800159eb15f6, 0x5800c8f4b30ab,
+    0x5800cc9da3670, 0x5801d7ca2db9d, 0x5801f511b09c5, 0x5802df4dc7b05,
+    0x58031633dd190, 0x5803212eae2df, 0x58035f66a44f4, 0x5803b73d2cf6c,
+    0x580466ea3e45c, 0x58051de9304d6, 0x58055fca16cb0, 0x5805e38be3c64,
+    0x5805e734d4229, 0x5807344244f30, 0x58075189c7d58, 0x58079abc8f0bc,
+    0x5807ad0940d95, 0x58083f6ecf45d, 0x5808e7c9ffdc3, 0x5809902530729,
+    0x5809b115a3b16, 0x580d18251983c, 0x580da6e1b793f, 0x580de8c29e119,
+    0x580ec0b203580, 0x580f14df9ba33, 0x580f56c08220d, 0x580f690d33ee6,
+    0x581091814123b, 0x581102f65c516, 0x5811203ddf33e, 0x58118a6119a8f,
+    0x5811955beabde, 0x5811de8eb1f42, 0x5812fc07ee148, 0x5813453ab54ac,
+    0x58137fc9bb0fc, 0x5813a0ba2e4e9, 0x581415d839d89, 0x581462b3f16b2,
+    0x5815f54b39158, 0x581600460a2a7, 0x5817300bf8186, 0x58176a9afddd6,
+    0x5817dfb909676, 0x58198beee397f, 0x5819c67de95cf, 0x581a8c209cd5d,
+    0x581bef23afd02, 0x581c2609c538d, 0x581c3bff6762b, 0x581c3fa857bf0,
+    0x581c6098cafdd, 0x581c977ee0668, 0x581ca622a1d7c, 0x581cd20de62b8,
+    0x581d438301593, 0x581def87224be, 0x581f59dc15fed, 0x581f85c75a529,
+    0x58233d5b7813d, 0x5824f0e332fd0, 0x5824ff86f46e4, 0x5825242058096,
+    0x58255eaf5dce6, 0x582574a4fff84, 0x58257f9fd10d3, 0x582641999429c,
+    0x58271988f9703, 0x58276a0da15f1, 0x58279d4ac66b7, 0x5828d80b856e5,
+    0x58296a7113dad, 0x5829df8f1f64d, 0x582c675d4f382, 0x582c79aa0105b,
+    0x582c884dc276f, 0x582ceb1f1c336, 0x582d30a8f30d5, 0x583159b22bfc4,
+    0x5831c02c76150, 0x5831e86eca0c7, 0x5832772b681ca, 0x58337b0611b6d,
+    0x58345a4757b5e, 0x5835c0f35b0c8, 0x583674495cb7d, 0x583698e2c052f,
+    0x5836a034a10b9, 0x583811db75772, 0x58384c6a7b3c2, 0x5838af3bd4f89,
+    0x583a666c803e1, 0x583c582c31489, 0x583d6701abf7b, 0x583edffa611be,
+    0x583f1a8966e0e, 0x58406b3fc80da, 0x584130e27b868, 0x5841fa2e1f5bb,
+    0x584381ca95f12, 0x584452681a7ef, 0x58446c06ad052, 0x58446faf9d617,
+    0x58457adc27b44, 0x58461f8e67ee5, 0x584773edb9776, 0x5849a78e50ff8,
+    0x5849cfd0a4f6f, 0x584b417779628, 0x584d0e9dc6d1e, 0x584d5b797e647,
+    0x584d8b0db3148, 0x584dbaa1e7c49, 0x584dccee99922, 0x584ed81b23e4f,
+    0x584edbc414414, 0x584ef90b9723c, 0x584fc9a91bb19, 0x58519a78597d4,
+    0x5851a91c1aee8, 0x5851eea5f1c87, 0x5853380a723c9, 0x58545583ae5cf,
+    0x58554368b5cd4, 0x5856ef9e8ffdd, 0x5858614564696, 0x5859358bd9538,
+    0x585e0e4223917, 0x585fb3261d096, 0x5860f8e1ad213, 0x5860fc8a9d7d8,
+    0x5863f5cde87e8, 0x58655c79ebd52, 0x5866ca77cfe46, 0x586729a039448,
+    0x5867e09f2b4c2, 0x5867eb99fc611, 0x5868dd27f42db, 0x586b14717c122,
+    0x586c5a2d0c29f, 0x586d0d830dd54, 0x586d1c26cf468, 0x586d396e52290,
+    0x586ecfae8a2fb, 0x586f83048bdb0, 0x5870fbfd40ff3, 0x5870ffa6315b8,
+    0x58728aeb984d4, 0x58732bf4e82b0, 0x5875633e700f7, 0x58756e3941246,
+    0x587633dbf49d4, 0x58770bcb59e3b, 0x587775ee9458c, 0x5877b07d9a1dc,
+    0x5879814cd7e97, 0x5879b489fcf5d, 0x587c858af3ff6, 0x587c8933e45bb,
+    0x5881fba19dbec, 0x5882dae2e3bdd, 0x588345061e32e, 0x5883833e14543,
+    0x5884418ee7147, 0x5884627f5a534, 0x5885a0e909b27, 0x58860b0c44278,
+    0x58875819b4f7f, 0x5887790a2836c, 0x5889587d2773b, 0x58895c2617d00,
+    0x588b1aa8a3ce2, 0x588b42eaf7c59, 0x588bdca266eab, 0x588c556962d10,
+    0x588d0c6854d8a, 0x588d72e29ef16, 0x588ed5e5b1ebb, 0x5891bcdc4b1f2,
+    0x5891e51e9f169, 0x58940a1b752d7, 0x58945aa01d1c5, 0x5895ab567e491,
+    0x589648b6ddca8, 0x5897996d3ef74, 0x5898f17580dca, 0x5899e6ac69059,
+    0x589b51015cb88, 0x589b843e81c4e, 0x589bbecd8789e, 0x589c3b3d73cc8,
+    0x589e135e9250d, 0x589e4a44a7b98, 0x589fbbeb7c251, 0x58a2943e53e74,
+    0x58a2d61f3a64e, 0x58a32df5c30c6, 0x58a39bc1edddc, 0x58a41831da206,
+    0x58a49bf3a71ba, 0x58a49f9c9777f, 0x58a51fb57416e, 0x58a718c705da0,
+    0x58a723c1d6eef, 0x58a7f0b66b207, 0x58a948bead05d, 0x58a96d5810a0f,
+    0x58ab99a6c7707, 0x58abea2b6f5f5, 0x58ac544ea9d46, 0x58aca12a6166f,
+    0x58ae7cf470479, 0x58aecd7918367, 0x58aedc1cd9a7b, 0x58af08081dfb7,
+    0x58b0bf38c940f, 0x58b1220a22fd6, 0x58b16042191eb, 0x58b39b34915f7,
+    0x58b4b8adcd7fd, 0x58b605bb3e504, 0x58b6b5684f9f4, 0x58b6f3a045c09,
+    0x58b7407bfd532, 0x58b7a34d570f9, 0x58b7a6f6476be, 0x58b7c43dca4e6,
+    0x58b7e1854d30e, 0x58b93d367f729, 0x58baff61fbcd0, 0x58bb7828f7b35,
+    0x58bbaf0f0d1c0, 0x58bbe24c32286, 0x58bbe99e12e10, 0x58bc033ca5673,
+    0x58bc0a8e861fd, 0x58bc994b24300, 0x58bd454f4522b, 0x58bdf4fc5671b,
+    0x58be2be26bda6, 0x58c0214b0d413, 0x58c0a50cda3c7, 0x58c0f1e891cf0,
+    0x58c13b1b59054, 0x58c19a43c2656, 0x58c300efc5bc0, 0x58c32cdb0a0fc,
+    0x58c42d0cc34da, 0x58c46eeda9cb4, 0x58c4ad259fec9, 0x58c4f2af76c68,
+    0x58c5607ba197e, 0x58c5e7e65eef7, 0x58c86c0b9e667, 0x58c9096bfde7e,
+    0x58c990d6bb3f7, 0x58c9a6cc5d695, 0x58cb35bab4b76, 0x58cb6ca0ca201,
+    0x58cbf062971b5, 0x58cc239fbc27b, 0x58cc40e73f0a3, 0x58ccab0a797f4,
+    0x58ce32a6f014b, 0x58ce4c45829ae, 0x58cefbf293e9e, 0x58ceff9b84463,
+    0x58cf193a16cc6, 0x58cf6d67af179, 0x58cf95aa030f0, 0x58cfd03908d40,
+    0x58d1ea3b0dd5f, 0x58d3cd56fd6f3, 0x58d480acff1a8, 0x58d48f50c08bc,
+    0x58d4f973fb00d, 0x58d5eeaae329c, 0x58d6c2f15813e, 0x58d71ac7e0bb6,
+    0x58d7296ba22ca, 0x58d751adf6241, 0x58d78c3cfbe91, 0x58d7fdb21716c,
+    0x58d8e09c4d722, 0x58d8f2e8ff3fb, 0x58d9979b3f79c, 0x58dc2a6440620,
+    0x58dd826c82476, 0x58ddc0a47868b, 0x58df2ea25c77f, 0x58df8dcac5d81,
+    0x58e08dfc7f15f, 0x58e16d3dc5150, 0x58e4c5a979762, 0x58e5f56f67641,
+    0x58eaf2bf153d2, 0x58ec1038515d8, 0x58ec9b4bff116, 0x58ed55f3e1755,
+    0x58ee22e875a6d, 0x58ef0d248cbad, 0x58ef98383a6eb, 0x58efcb755f7b1,
+    0x58eff3b7b3728, 0x58f15e0ca7257, 0x58f2ecfafe738, 0x58f4e2639fda5,
+    0x58f699944b1fd, 0x58f6b332dda60, 0x58f77183b0664, 0x58f8f1ce46431,
+    0x58f963436170c, 0x58fbdc6dcfd2d, 0x58fcf63e1b96e, 0x58fd4a6bb3e21,
+    0x58fe902743f9e, 0x590022be8ba44, 0x5900649f7221e, 0x59006848627e3,
+    0x5900e10f5e648, 0x5903ddfb99c1d, 0x590544a79d187, 0x5905dab61be14,
+    0x5906fbd8485df, 0x5907f861113f8, 0x5908244c55934, 0x5908ba5ad45c1,
+    0x590c843ba3eae, 0x590d9a62ff52a, 0x590de73eb6e53, 0x590e75fb54f56,
+    0x590f7d7eeeebe, 0x590f9ac671ce6, 0x590fc6b1b6222, 0x590fca5aa67e7,
+    0x5911902f13353, 0x5911bfc347e54, 0x59136fa212722, 0x5913c3cfaabd5,
+    0x591482207d7d9, 0x591490c43eeed, 0x5914b1b4b22da, 0x5914e4f1d73a0,
+    0x59169c22827f8, 0x5916b96a05620, 0x59172e8810ec0, 0x5917656e2654b,
+    0x5917865e99938, 0x59186cf1c04b3, 0x5919ed3c56280, 0x591ba46d016d8,
+    0x591bbe0b93f3b, 0x591c541a12bc8, 0x591c99a3e9967, 0x591c9d4cd9f2c,
+    0x591cc1e63d8de, 0x591e8068c98c0, 0x591ed49661d73, 0x592100e518a6b,
+    0x59232238fe614, 0x5924c374077ce, 0x59251b4a90246, 0x5926d6242bc63,
+    0x592844220fd57, 0x5928d2deade5a, 0x59293d01e85ab, 0x5929e906094d6,
+    0x592cc8aac1c83, 0x592d7c00c3738, 0x592dc8dc7b061, 0x592f78bb4592f,
+    0x5930286856e1f, 0x593321aba1e2f, 0x593442cdce5fa, 0x5934a94818786,
+    0x593767fc5db46, 0x5938c3ad8ff61, 0x593cf408a99da, 0x593d02ac6b0ee,
+    0x593d7421863c9, 0x593dcfa0ff406, 0x593f7f7fc9cd4, 0x593fbdb7bfee9,
+    0x594041798ce9d, 0x59410e6e211b5, 0x5941e65d8661c, 0x5941f50147d30,
+    0x59438eea70360, 0x5943df6f1824e, 0x5943fcb69b076, 0x59449dbfeae52,
+    0x59453b204a669, 0x5948430756d8d, 0x594cfe761e344, 0x594ecb9c6ba3a,
+    0x594f7ef26d4ef, 0x5951cc31975d4, 0x595327e2c99ef, 0x5954e2bc6540c,
+    0x5956fcbe6a42b, 0x5957c9b2fe743, 0x595900cacd1ac, 0x595ce44a2f2fc,
+    0x595d60ba1b726, 0x595e8585384b6, 0x595eebff82642, 0x595f771330180,
+    0x596068a127e4a, 0x59612e43db5d8, 0x5961d2f61b979, 0x5961fee15feb5,
+    0x5963115fcaf6c, 0x59636cdf43fa9, 0x596377da150f8, 0x5965365ca10da,
+    0x596641892b607, 0x5967072bded95, 0x59675407966be, 0x596945c747766,
+    0x5969a146c07a3, 0x596d468e2c6de, 0x596eeb7225e5d, 0x5970a2a2d12b5,
+    0x5972efe1fb39a, 0x5972fadccc4e9, 0x5975322654330, 0x5975a39b6f60b,
+    0x59776d18cc73c, 0x59793a3f19e32, 0x597995be92e6f, 0x5979d3f689084,
+    0x5979f4e6fc471, 0x597c2c30842b8, 0x597c4978070e0, 0x597ca4f78011d,
+    0x597d49a9c04be, 0x597e5c282b575, 0x597e9a602178a, 0x597fbf2b3e51a,
+    0x597fd8c9d0d7d, 0x59807d7c1111e, 0x59812d292260e, 0x5984475ce0a0b,
+    0x598517fa652e8, 0x5985478e99de9, 0x5986441762c02, 0x5986bcde5ea67,
+    0x598770346051c, 0x59879124d3909, 0x598794cdc3ece, 0x59890a1d88b4c,
+    0x5989aecfc8eed, 0x598c95c662224, 0x598ce2a219b4d, 0x598df8c9751c9,
+    0x598e509ffdc41, 0x598ea4cd960f4, 0x598fa4ff4f4d2, 0x599012cb7a1e8,
+    0x599087e985a88, 0x599363e54dc70, 0x59949afd1c6d9, 0x5995ad7b87790,
+    0x599743bbbf7fb, 0x59974764afdc0, 0x5997a2e428dfd, 0x5997c02babc25,
+    0x599822fd057ec, 0x5998b90b84479, 0x5998ec48a953f, 0x5998f7437a68e,
+    0x599b657317b60, 0x599d11a8f1e69, 0x599eb2e3fb023, 0x599f8e7c50a4f,
+    0x599fa81ae32b2, 0x599fd7af17db3, 0x59a1f1b11cdd2, 0x59a321770acb1,
+    0x59a34d624f1ed, 0x59a3794d93729, 0x59a3c6294b052, 0x59a3ee6b9efc9,
+    0x59a45fe0ba2a4, 0x59a521da7d46d, 0x59a6136875137, 0x59a7223defc29,
+    0x59a7a5ffbcbdd, 0x59a997bf6dc85, 0x59ac02461ab92, 0x59ad2e63184ac,
+    0x59adda67393d7, 0x59aecf9e21666, 0x59afa3e496508, 0x59b0a06d5f321,
+    0x59b33a8840d2f, 0x59b48ee7925c0, 0x59b66708b0e05, 0x59b7eea52775c,
+    0x59b96b46ccf64, 0x59b9eb5fa9953, 0x59bce0fa0439e, 0x59bd093c58315,
+    0x59bf442ed0721, 0x59bf860fb6efb, 0x59bfe538204fd, 0x59bff032f164c,
+    0x59c16582b62ca, 0x59c198bfdb390, 0x59c1e9448327e, 0x59c1f43f543cd,
+    0x59c8b3ba7e705, 0x59c9dc2e8ba5a, 0x59cab41df0ec1, 0x59caf9a7c7c60,
+    0x59cd1ea49ddce, 0x59cf140d3f43b, 0x59d038d85c1cb, 0x59d0820b2352f,
+    0x59d08d05f467e, 0x59d17aeafbd83, 0x59d2cba15d04f, 0x59d40a0b0c642,
+    0x59d4bd610e0f7, 0x59d5362809f5c, 0x59d557187d349, 0x59d570b70fbac,
+    0x59d7159b0932b, 0x59d8a0e070247, 0x59d98021b6238, 0x59d9edede0f4e,
+    0x59dc427eebbbd, 0x59dc8bb1b2f21, 0x59dd340ce3887, 0x59deb45779654,
+    0x59df013330f7d, 0x59df1728d321b, 0x59dffdbbf9d96, 0x59e0817dc6d4a,
+    0x59e0f69bd25ea, 0x59e12d81e7c75, 0x59e134d3c87ff, 0x59e37ac111d5a,
+    0x59e46ff7f9fe9, 0x59e4a3351f0af, 0x59e52a9fdc628, 0x59e552e23059f,
+    0x59e589c845c2a, 0x59e594c316d79, 0x59e665609b656, 0x59e6d32cc636c,
+    0x59e8ff7b7d064, 0x59e98e381b167, 0x59e9d76ae24cb, 0x59eb3a6df5470,
+    0x59ec076289788, 0x59ed92a7f06a4, 0x59ede32c98592, 0x59ee28b66f331,
+    0x59ee9a2b8a60c, 0x59ef044ec4d5d, 0x59ef1ded575c0, 0x59f00f7b4f28a,
+    0x59f05ffff7178, 0x59f0bb7f701b5, 0x59f0d17512453, 0x59f0dc6fe35a2,
+    0x59f297497efbf, 0x59f5350d50f92, 0x59f555fdc437f, 0x59f6771ff0b4a,
+    0x59f752b846576, 0x59f7e8c6c5203, 0x59f9cf8ba515c, 0x59fc8a96f9f57,
+    0x59fd19539805a, 0x59ff0ebc396c7, 0x5a0075683cc31, 0x5a01c275ad938,
+    0x5a01e70f112ea, 0x5a01f95bc2fc3, 0x5a03ace37de56, 0x5a03fd6825d44,
+    0x5a04f29f0dfd3, 0x5a05138f813c0, 0x5a054a7596a4b, 0x5a05c6e582e75,
+    0x5a09a3130443b, 0x5a0b5a43af893, 0x5a0bd30aab6f8, 0x5a0c7413fb4d4,
+    0x5a0c77bceba99, 0x5a0cde3735c25, 0x5a0cf42cd7ec3, 0x5a0fb68a0d848,
+    0x5a0fba32fde0d, 0x5a105ee53e1ae, 0x5a1120df01377, 0x5a1178b589def,
+    0x5a132894546bd, 0x5a134d2db806f, 0x5a1363235a30d, 0x5a13a8ad310ac,
+    0x5a13dbea56172, 0x5a13e6e5272c1, 0x5a140b7e8ac73, 0x5a151dfcf5d2a,
+    0x5a166eb356ff6, 0x5a18dce2f44c8, 0x5a18e7ddc5617, 0x5a1b3173ff137,
+    0x5a1cb567854c9, 0x5a1d1f8abfc1a, 0x5a1f448795d88, 0x5a1faeaad04d9,
+    0x5a2023c8dbd79, 0x5a2044b94f166, 0x5a215ae0aa7e2, 0x5a216d2d5c4bb,
+    0x5a2315ba461ff, 0x5a231963367c4, 0x5a243e2e53554, 0x5a25754621fbd,
+    0x5a25c5cac9eab, 0x5a26214a42ee8, 0x5a27bedc5badd, 0x5a29b09c0cb85,
+    0x5a29b444fd14a, 0x5a2b2d3db238d, 0x5a2db50be20c2, 0x5a2f7e893f1f3,
+    0x5a3073c027482, 0x5a30b1f81d697, 0x5a310625b5b4a, 0x5a3156aa5da38,
+    0x5a317b43c13ea, 0x5a363e046952b, 0x5a37124ade3cd, 0x5a37960cab381,
+    0x5a388b4393610, 0x5a388eec83bd5, 0x5a39d4a813d52, 0x5a3b673f5b7f8,
+    0x5a3b7d34fda96, 0x5a3c0848ab5d4, 0x5a3ca5a90adeb, 0x5a3cb44ccc4ff,
+    0x5a3d6ef4aeb3e, 0x5a3dc6cb375b6, 0x5a3dd917e928f, 0x5a3ffa6bcee38,
+    0x5a40648f09589, 0x5a40b8bca1a3c, 0x5a40c00e825c6, 0x5a40e0fef59b3,
+    0x5a4147793fb3f, 0x5a44187a36bd8, 0x5a4577d4595b8, 0x5a4582cf2a707,
+    0x5a45951bdc3e0, 0x5a460a39e7c80, 0x5a463d770cd46, 0x5a48452c6008c,
+    0x5a491220f43a4, 0x5a4abe56ce6ad, 0x5a4b0b3285fd6, 0x5a4b78feb0cec,
+    0x5a4c28abc21dc, 0x5a4c9a20dd4b7, 0x5a4c9dc9cda7c, 0x5a4cac6d8f190,
+    0x5a4ce6fc94de0, 0x5a4d6e6752359, 0x5a4ec2c6a3bea, 0x5a4ee7600759c,
+    0x5a51643366182, 0x5a51fa41e4e0f, 0x5a54527be0043, 0x5a547e672457f,
+    0x5a54f72e203e4, 0x5a567026d5627, 0x5a56c0ab7d515, 0x5a571c2af6552,
+    0x5a57f41a5b9b9, 0x5a5fdfb28360b, 0x5a5feaad5475a, 0x5a611a7342639,
+    0x5a6121c5231c3, 0x5a61a92fe073c, 0x5a61acd8d0d01, 0x5a6379ff1e3f7,
+    0x5a648c7d894ae, 0x5a65d5e209bf0, 0x5a67e1404d4fb, 0x5a686c53fb039,
+    0x5a68a6e300c89, 0x5a6989cd3723f, 0x5a6b61ee55a84, 0x5a6bab211cde8,
+    0x5a6d70f589954, 0x5a6df86046ecd, 0x5a6f999b50087, 0x5a7119e5e5e54,
+    0x5a727ce8f8df9, 0x5a744a0f464ef, 0x5a7463add8d52, 0x5a760fe3b305b,
+    0x5a766f0c1c65d, 0x5a76a5f231ce8, 0x5a76eb7c08a87, 0x5a7751f652c13,
+    0x5a776f3dd5a3b, 0x5a78ada78502e, 0x5a79316951fe2, 0x5a79bc7cffb20,
+    0x5a79fab4f5d35, 0x5a7b02388fc9d, 0x5a7b52bd37b8b, 0x5a7b73adaaf78,
+    0x5a7bf76f77f2c, 0x5a7ca71c8941c, 0x5a7cda59ae4e2, 0x5a7d69164c5e5,
+    0x5a7dcbe7a61ac, 0x5a7de1dd4844a, 0x5a7ef0b2c2f3c, 0x5a7fa05fd442c,
+    0x5a8048bb04d92, 0x5a81e9f60df4c, 0x5a83b71c5b642, 0x5a853766f140f,
+    0x5a853eb8d1f99, 0x5a8716d9f07de, 0x5a875168f642e, 0x5a8763b5a8107,
+    0x5a8822067ad0b, 0x5a8bb8aa25532, 0x5a8c1f246f6be, 0x5a8c859eb984a,
+    0x5a8d61370f276, 0x5a8eec7c76192, 0x5a8f061b089f5, 0x5a8fa37b6820c,
+    0x5a90691e1b99a, 0x5a91c1265d7f0, 0x5a9369b347534, 0x5a940abc97310,
+    0x5a9457984ec39, 0x5a94c1bb8938a, 0x5a94f14fbde8b, 0x5a952bdec3adb,
+    0x5a956dbfaa2b5, 0x5a9599aaee7f1, 0x5a95b34981054, 0x5a95df34c5590,
+    0x5a9699dca7bcf, 0x5a97214765148, 0x5a9771cc0d036, 0x5a982c73ef675,
+    0x5a986702f52c5, 0x5a991307161f0, 0x5a9b9ad545f25, 0x5a9c08a170c3b,
+    0x5a9c9eafef8c8, 0x5aa11f8fb122f, 0x5aa16c6b68b58, 0x5aa34bde67f27,
+    0x5aa35a822963b, 0x5aa3866d6db77, 0x5aa565e06cf46, 0x5aa6785ed7ffd,
+    0x5aa86dc77966a, 0x5aa8afa85fe44, 0x5aac3056683cd, 0x5aaece1a3a3a0,
+    0x5aaf4a8a267ca, 0x5aaf81703be55, 0x5aaffde02827f, 0x5ab03c181e494,
+    0x5ab129fd25b99, 0x5ab46c7337f0d, 0x5ab59c3925dec, 0x5ab732795de57,
+    0x5ab88a819fcad, 0x5ab950245343b, 0x5abb5087c5bf7, 0x5abc162a79385,
+    0x5abc45beade86, 0x5abcd47b4bf89, 0x5abd0066904c5, 0x5abdac6ab13f0,
+    0x5abf256366633, 0x5ac03438e1125, 0x5ac1137a27116, 0x5ac3b88fd9c73,
+    0x5ac438a8b6662, 0x5ac4b16fb24c7, 0x5ac4f6f989266, 0x5ac55d73d33f2,
+    0x5ac59babc9607, 0x5ac60977f431d, 0x5ac6614e7cd95, 0x5ac77ec7b8f9b,
+    0x5ac9fb9b17b81, 0x5acb27b81549b, 0x5acbdeb707515, 0x5acc11f42c5db,
+    0x5accf88753156, 0x5acd247297692, 0x5acf3722bbb27, 0x5ad10f43da36c,
+    0x5ad3d1a10fcf1, 0x5ad3f63a736a3, 0x5ad44a680bb56, 0x5ad6387ecc639,
+    0x5ad6a2a206d8a, 0x5ad6ce8d4b2c6, 0x5ad6d5df2be50, 0x5ad7106e31aa0,
+    0x5ad7a67cb072d, 0x5ad7b52071e41, 0x5ad8e88f502e5, 0x5adf8e6be7dba,
+    0x5adfd3f5beb59, 0x5adff88f2250b, 0x5ae0a0ea52e71, 0x5ae1b368bdf28,
+    0x5ae3425715409, 0x5ae3d4bca3ad1, 0x5ae5933f2fab3, 0x5ae6e04ca07ba,
+    0x5ae7088ef4731, 0x5ae7431dfa381, 0x5ae9ce951a67b, 0x5aeb9f6458336,
+    0x5aec73aacd1d8, 0x5aec9bed2114f, 0x5aed1c05fdb3e, 0x5aedfb4743b2f,
+    0x5aef325f12598, 0x5aef743ff8d72, 0x5aefbd72c00d6, 0x5af02b3eeadec,
+    0x5af053813ed63, 0x5af1366b75319, 0x5af169a89a3df, 0x5af3f51fba6d9,
+    0x5af4494d52b8c, 0x5af5a8a77556c, 0x5af633bb230aa, 0x5af94a45f0ee2,
+    0x5afb01769c33a, 0x5afdc3d3d1cbf, 0x5b00863107644, 0x5b01c843a71fc,
+    0x5b03697eb03b6, 0x5b03e5ee9c7e0, 0x5b04c52fe27d1, 0x5b078b360871b,
+    0x5b07a87d8b543, 0x5b0a5c36ff7b4, 0x5b0c30af2da34, 0x5b0c5548913e6,
+    0x5b0c9e7b5874a, 0x5b0ecaca0f442, 0x5b0f4e8bdc3f6, 0x5b1126acfac3b,
+    0x5b1309c8ea5cf, 0x5b132e624df81, 0x5b135a4d924bd, 0x5b14189e650c1,
+    0x5b15e5c4b27b7, 0x5b1644ed1bdb9, 0x5b165739cda92, 0x5b165e8bae61c,
+    0x5b16bdb417c1e, 0x5b1748c7c575c, 0x5b18788db363b, 0x5b1b8b6f90eae,
+    0x5b1bd4a258212, 0x5b1c1a2c2efb1, 0x5b1df99f2e380, 0x5b1e16e6b11a8,
+    0x5b206f20ac3dc, 0x5b2193ebc916c, 0x5b21a9e16b40a, 0x5b249829e52cb,
+    0x5b24da0acbaa5, 0x5b2589b7dcf95, 0x5b25a6ff5fdbd, 0x5b27da9ff763f,
+    0x5b28e223915a7, 0x5b2b20bef9f78, 0x5b2b3e067cda0, 0x5b2b8ae2346c9,
+    0x5b2c960ebebf6, 0x5b2d0783d9ed1, 0x5b2da13b49123, 0x5b2f88002907c,
+    0x5b3167732844b, 0x5b31d8e843726, 0x5b33433d37255, 0x5b3408dfea9e3,
+    0x5b34b88cfbed3, 0x5b3709751657d, 0x5b3731b76a4f4, 0x5b399fe7079c6,
+    0x5b39a738e8550, 0x5b39f06baf8b4, 0x5b3aecf4786cd, 0x5b3dbdf56f766,
+    0x5b3de28ed3118, 0x5b417cdb6df04, 0x5b418b7f2f618, 0x5b450c2d37ba1,
+    0x5b47596c61c86, 0x5b486841dc778, 0x5b49efde530cf, 0x5b4c655fd112b,
+    0x5b4e571f821d3, 0x5b4edae14f187, 0x5b4f3660c81c4, 0x5b4f624c0c700,
+    0x5b4fffac6bf17, 0x5b5095baeaba4, 0x5b50bdfd3eb1b, 0x5b50c54f1f6a5,
+    0x5b5141bf0bacf, 0x5b51540bbd7a8, 0x5b52b70ed074d, 0x5b5387ac5502a,
+    0x5b55637663e34, 0x5b55b7a3fc2e7, 0x5b55eae1213ad, 0x5b57085a5d5b3,
+    0x5b57d54ef18cb, 0x5b57d8f7e1e90, 0x5b5834775aecd, 0x5b589e9a9561e,
+    0x5b5befb4690a6, 0x5b5bfaaf3a1f5, 0x5b5caa5c4b6e5, 0x5b5d26cc37b0f,
+    0x5b5dd67948fff, 0x5b5e52e935429, 0x5b5ee8f7b40b6, 0x5b5f6567a04e0,
+    0x5b619cb128327, 0x5b61e98cdfc50, 0x5b63e64761e47, 0x5b65c5ba61216,
+    0x5b65f54e95d17, 0x5b66cd3dfb17e, 0x5b673b0a25e94, 0x5b675fa389846,
+    0x5b6766f56a3d0, 0x5b67c9c6c3f97, 0x5b6bbbe9e77fb, 0x5b6e7a9e2cbbb,
+    0x5b6f737e0540f, 0x5b7152f1047de, 0x5b725a749e746, 0x5b753a1956ef3,
+    0x5b755760d9d1b, 0x5b7566049b42f, 0x5b768726c7bfa, 0x5b76922198d49,
+    0x5b772f81f8560, 0x5b778006a044e, 0x5b7c3f1e57fca, 0x5b7c80ff3e7a4,
+    0x5b7cd183e6692, 0x5b7ec6ec87cff, 0x5b8047371dacc, 0x5b8356700ad7a,
+    0x5b85aeaa05fae, 0x5b877827630df, 0x5b8895a09f2e5, 0x5b890e679b14a,
+    0x5b894c9f9135f, 0x5b89ccb86dd4e, 0x5b89df051fa27, 0x5b8b83e9191a6,
+    0x5b8c841ad2584, 0x5b8ca8b435f36, 0x5b8cf938dde24, 0x5b8dbb32a0fed,
+    0x5b8f1e35b3f92, 0x5b919760225b3, 0x5b91ca9d47679, 0x5b931058d77f6,
+    0x5b954ef4401c7, 0x5b96ae4e62ba7, 0x5b97a3854ae36, 0x5b9835ead94fe,
+    0x5b9986a13a7ca, 0x5b9adea97c620, 0x5b9b2433533bf, 0x5b9c7c3b95215,
+    0x5b9cabcfc9d16, 0x5b9de2e79877f, 0x5b9e3abe211f7, 0x5b9ecd23af8bf,
+    0x5b9f0b5ba5ad4, 0x5b9f9dc13419c, 0x5ba04d6e4568c, 0x5ba325c11d2af,
+    0x5ba5645c85c80, 0x5ba59799aad46, 0x5ba638a2fab22, 0x5babb60b852a2,
+    0x5baced2353d0b, 0x5badc8bba9737, 0x5badf0fdfd6ae, 0x5baefc2a87bdb,
+    0x5bafabd7990cb, 0x5bb1ea7301a9c, 0x5bb3720f783f3, 0x5bb3e72d83c93,
+    0x5bb4674660682, 0x5bb500fdcf8d4, 0x5bb5a9590023a, 0x5bb6dcc7de6de,
+    0x5bb738475771b, 0x5bb74e3cf99b9, 0x5bb84e6eb2d97, 0x5bba611ed722c,
+    0x5bbb40601d21d, 0x5bbd7057c44da, 0x5bbd82a4761b3, 0x5bbda73dd9b65,
+    0x5bbe066643167, 0x5bbe4f990a4cb, 0x5bbe7f2d3efcc, 0x5bc08a8b828d7,
+    0x5bc2df1c8d546, 0x5bc40ee27b425, 0x5bc528b2c7066, 0x5bc55846fbb67,
+    0x5bc57ce05f519, 0x5bc69307bab95, 0x5bc75ffc4eead, 0x5bc8468f75a28,
+    0x5bc9138409d40, 0x5bc92227cb454, 0x5bc9381d6d6f2, 0x5bc99aeec72b9,
+    0x5bcb9efb2a03a, 0x5bcc89374117a, 0x5bccc76f3738f, 0x5bccd26a084de,
+    0x5bcd17f3df27d, 0x5bcd43df237b9, 0x5bcda6b07d380, 0x5bce18259865b,
+    0x5bce26c959d6f, 0x5bce73a511698, 0x5bcf110570eaf, 0x5bcfb20ec0c8b,
+    0x5bd10dbff30a6, 0x5bd19c7c911a9, 0x5bd1ed0139097, 0x5bd34c5b5ba77,
+    0x5bd5122fc85e3, 0x5bd5be33e950e, 0x5bd80f1c03bb8, 0x5bd89a2fb16f6,
+    0x5bd9bb51ddec1, 0x5bdc5915afe94, 0x5bddd96045c61, 0x5be07acd081f9,
+    0x5be1e8caec2ed, 0x5be3e5856e4e4, 0x5be527980e09c, 0x5be5b9fd9c764,
+    0x5be63dbf69718, 0x5be6784e6f368, 0x5be67fa04fef2, 0x5be6db1fc8f2f,
+    0x5be8df2c2bcb0, 0x5be9a125eee79, 0x5be9a4cedf43e, 0x5beeca60e1146,
+    0x5bf16824b3119, 0x5bf1fa8a417e1, 0x5bf3c7b08eed7, 0x5bf440778ad3c,
+    0x5bf490fc32c2a, 0x5bf509c32ea8f, 0x5bf62ae55b25a, 0x5bf7485e97460,
+    0x5bf777f2cbf61, 0x5bf8b2b38af8f, 0x5bf95eb7abeba, 0x5bfa7fd9d8685,
+    0x5bfaba68de2d5, 0x5bfbe2dceb62a, 0x5bfc19c300cb5, 0x5bfcb723604cc,
+    0x5bfdac5a4875b, 0x5bfed4ce55ab0, 0x5bff722eb52c7, 0x5bffbf0a6cbf0,
+    0x5bffd8a8ff453, 0x5c010fc0cdebc, 0x5c019e7d6bfbf, 0x5c044ae4ff6a6,
+    0x5c04cea6cc65a, 0x5c05dd7c4714c, 0x5c07024763edc, 0x5c07bcef4651b,
+    0x5c08654a76e81, 0x5c091ff2594c0, 0x5c097077013ae, 0x5c0ae21dd5a67,
+    0x5c0af46a87740, 0x5c0be5f87f40a, 0x5c0c501bb9b5b, 0x5c0ce62a387e8,
+    0x5c0fd0c9c20e4, 0x5c10214e69fd2, 0x5c14ce196fe75, 0x5c14e7b8026d8,
+    0x5c167df83a743, 0x5c1734f72c7bd, 0x5c17f6f0ef986, 0x5c18facb99329,
+    0x5c18fe74898ee, 0x5c1b4bb3b39d3, 0x5c1b9fe14be86, 0x5c1ce1f3eba3e,
+    0x5c1d5e63d7e68, 0x5c1da044be642, 0x5c1daee87fd56, 0x5c1dc13531a2f,
+    0x5c1ec50fdb3d2, 0x5c1f11eb92cfb, 0x5c1f53cc794d5, 0x5c20998809652,
+    0x5c2141e339fb8, 0x5c21a4b493b7f, 0x5c24795e7b1dd, 0x5c253b583e3a6,
+    0x5c258833f5ccf, 0x5c25c2c2fb91f, 0x5c26552889fe7, 0x5c26e3e5280ea,
+    0x5c26eedff9239, 0x5c27a987db878, 0x5c27b0d9bc402, 0x5c2829a0b8267,
+    0x5c2c8d38f6da6, 0x5c2e2acb0f99b, 0x5c2e5a5f4449c, 0x5c2f3d497aa52,
+    0x5c2f56e80d2b5, 0x5c2f867c41db6, 0x5c2fff433dc1b, 0x5c30ed2845320,
+    0x5c31fbfdbfe12, 0x5c33db70bf1e1, 0x5c3420fa95f80, 0x5c3457e0ab60b,
+    0x5c3779664a592, 0x5c3942e3a76c3, 0x5c39ad06e1e14, 0x5c39c2fc840b2,
+    0x5c3b4e41eafce, 0x5c3bb8652571f, 0x5c3bdcfe890d1, 0x5c3ca9f31d3e9,
+    0x5c41aaebbb73f, 0x5c42b9c136231, 0x5c43278d60f47, 0x5c43bd9bdfbd4,
+    0x5c4783d3beefc, 0x5c47d45866dea, 0x5c48422491b00, 0x5c496e418f41a,
+    0x5c49ac798562f, 0x5c49bb1d46d43, 0x5c49fcfe2d51d, 0x5c4cf9ea68af2,
+    0x5c4d0c371a7cb, 0x5c4d0fe00ad90, 0x5c4dbf8d1c280, 0x5c4dc6defce0a,
+    0x5c4df2ca41346, 0x5c512a458256b, 0x5c515d82a7631, 0x5c54531d0207c,
+    0x5c5539b028bf7, 0x5c5686bd998fe, 0x5c574c604d08c, 0x5c58712b69e1c,
+    0x5c587fcf2b530, 0x5c59b6e6f9f99, 0x5c59ba8fea55e, 0x5c59d42e7cdc1,
+    0x5c59fc70d0d38, 0x5c5b33889f7a1, 0x5c5c79442f91e, 0x5c5d1df66fcbf,
+    0x5c5d3b3df2ae7, 0x5c5ff2a05731d, 0x5c63fe620d3e4, 0x5c64772909249,
+    0x5c648223da398, 0x5c64a6bd3dd4a, 0x5c675a76b1fbb, 0x5c6be9fa35036,
+    0x5c6bf89df674a, 0x5c6c57c65fd4c, 0x5c6dc96d34405, 0x5c704297a2a26,
+    0x5c71292ac95a1, 0x5c73298e3bd5d, 0x5c732d372c322, 0x5c73432cce5c0,
+    0x5c76b53715435, 0x5c795df5b8557, 0x5c79ecb25665a, 0x5c7ada975dd5f,
+    0x5c7c36489017a, 0x5c7c39f18073f, 0x5c7e504a95199, 0x5c7e8ad99ade9,
+    0x5c806a4c9a1b8, 0x5c81f93af1699, 0x5c83f24c832cb, 0x5c846018adfe1,
+    0x5c848109213ce, 0x5c84c2ea07ba8, 0x5c86e7e6ddd16, 0x5c87fe0e39392,
+    0x5c89a69b230d6, 0x5c8a5d9a15150, 0x5c8e536628f79, 0x5c8e6209ea68d,
+    0x5c8e86a34e03f, 0x5c9169f0f6db1, 0x5c918e8a5a763, 0x5c929d5fd5255,
+    0x5c9445ecbef99, 0x5c95a19df13b4, 0x5c967d3646de0, 0x5c97eedd1b499,
+    0x5c98ce1e6148a, 0x5c9946e55d2ef, 0x5c9f4bb8a4fe8, 0x5c9f77a3e9524,
+    0x5c9fbd2dc02c3, 0x5ca1153602119, 0x5ca183022ce2f, 0x5ca4c1cf4ebde,
+    0x5ca5a86275759, 0x5ca671ae194ac, 0x5ca68b4cabd0f, 0x5ca7e6fdde12a,
+    0x5ca846264772c, 0x5ca875ba7c22d, 0x5ca99333b8433, 0x5caba5e3dc8c8,
+    0x5cabbf826f12b, 0x5cac1b01e8168, 0x5cac817c322f4, 0x5cae76e4d3961,
+    0x5cb0739f55b58, 0x5cb0fb0a130d1, 0x5cb18274d064a, 0x5cb1b2090514b,
+    0x5cb4e5db55dab, 0x5cb75b5cd3e07, 0x5cbd727ccd7d9, 0x5cbffdf3edad3,
+    0x5cc1f7057f705, 0x5cc2478a275f3, 0x5cc3da216f099, 0x5cc6534bdd6ba,
+    0x5cc73d87f47fa, 0x5cc869a4f2114, 0x5ccb7c86cf987, 0x5ccc3e8092b50,
+    0x5ccd3eb24bf2e, 0x5ccd425b3c4f3, 0x5cd11adfcd4f4, 0x5cd3647607014,
+    0x5cd3ef89b4b52, 0x5cd443b74d005, 0x5cd5688269d95, 0x5cd5c401e2dd2,
+    0x5cd8a3a69b57f, 0x5cd9ab2a354e7, 0x5cda7f70aa389, 0x5cdd0ae7ca683,
+    0x5cde3704c7f9d, 0x5cde6a41ed063, 0x5cded465277b4, 0x5cdf7cc05811a,
+    0x5cdf8069486df, 0x5ce121a451899, 0x5ce133f103572, 0x5ce3d906b60cf,
+    0x5ce434862f10c, 0x5ce4975788cd3, 0x5ce5310ef7f25, 0x5ce63c3b82452,
+    0x5ce6dd44d222e, 0x5ce71081f72f4, 0x5ce7d27bba4bd, 0x5ce8bcb7d15fd,
+    0x5ce9feca711b5, 0x5cedf83f755a3, 0x5cee3a205bd7d, 0x5ceec18b192f6,
+    0x5cf3510e9c371, 0x5cf3bedac7087, 0x5cf46adee7fb2, 0x5cf4e74ed43dc,
+    0x5cf5c2e729e08, 0x5cf89b3a01a2b, 0x5cf93148806b8, 0x5cf9682e95d43,
+    0x5cf9ef99532bc, 0x5cfb4ef375c9c, 0x5cfd0624210f4, 0x5cfe77caf57ad,
+    0x5cfe8a17a7486, 0x5cff8df250e29, 0x5cffdace08752, 0x5d01501dcd3d0,
+    0x5d030ea0593b2, 0x5d038eb935da1, 0x5d05b75efc4d4, 0x5d0675afcf0d8,
+    0x5d07046c6d1db, 0x5d0737a9922a1, 0x5d09813fcbdc1, 0x5d0984e8bc386,
+    0x5d09a2303f1ae, 0x5d0c81d4f795b, 0x5d0d4025ca55f, 0x5d0d5d6d4d387,
+    0x5d0d8958918c3, 0x5d0dadf1f5275, 0x5d0e2e0ad1c64, 0x5d119c6c28514,
+    0x5d1411eda6570, 0x5d16d7f3cc4ba, 0x5d174968e7795, 0x5d1850ec816fd,
+    0x5d19ee7e9a2f2, 0x5d1e3fca27158, 0x5d1eb13f42433, 0x5d1f0cbebb470,
+    0x5d219835db76a, 0x5d22a36265c97, 0x5d23bd32b18d8, 0x5d24c85f3be05,
+    0x5d25328276556, 0x5d2570ba6c76b, 0x5d257bb53d8ba, 0x5d27996032e9e,
+    0x5d27a803f45b2, 0x5d28e2c4b35e0, 0x5d2bd85f0e02b, 0x5d2c37877762d,
+    0x5d2e79cbd05c3, 0x5d2eeb40eb89e, 0x5d31d9896575f, 0x5d3373728dd8f,
+    0x5d339f5dd22cb, 0x5d34983daab1f, 0x5d350260e5270, 0x5d385723a92bd,
+    0x5d3b5b61c541c, 0x5d3cbabbe7dfc, 0x5d3cedf90cec2, 0x5d3e375d8d604,
+    0x5d3e54a51042c, 0x5d3e6348d1b40, 0x5d3f254294d09, 0x5d3f4633080f6,
+    0x5d3fd898967be, 0x5d40a1e43a511, 0x5d43977e94f5c, 0x5d43e0b15c2c0,
+    0x5d451f1b0b8b3, 0x5d47824fd7c36, 0x5d49e92d9457e, 0x5d4a9188c4ee4,
+    0x5d4bb2aaf16af, 0x5d4c500b50ec6, 0x5d542204e62b5, 0x5d54eef97a5cd,
+    0x5d5534835136c, 0x5d55d58ca1148, 0x5d576823e8bee, 0x5d576bccd91b3,
+    0x5d583c6a5da90, 0x5d5931a145d1f, 0x5d5be55ab9f90, 0x5d5f9945e75df,
+    0x5d5fa097c8169, 0x5d5fd77ddd7f4, 0x5d618eae88c4c, 0x5d61a4a42aeea,
+    0x5d632c40a1841, 0x5d644267fcebd, 0x5d65304d045c2, 0x5d675549da730,
+    0x5d6988ea71fb2, 0x5d6aadb58ed42, 0x5d6ab5076f8cc, 0x5d6c3ca3e6223,
+    0x5d6caa7010f39, 0x5d6e9c2fc1fe1, 0x5d6f09fbeccf7, 0x5d6f657b65d34,
+    0x5d6faeae2d098, 0x5d7144ee65103, 0x5d71ab68af28f, 0x5d74458390c9d,
+    0x5d75077d53e66, 0x5d76a8b85d020, 0x5d774d6a9d3c1, 0x5d77969d64725,
+    0x5d7896cf1db03, 0x5d78c2ba6203f, 0x5d79258bbbc06, 0x5d79b44859d09,
+    0x5d79f629404e3, 0x5d7c2277f71db, 0x5d7db1664e6bc, 0x5d7ff3aaa7652,
+    0x5d801becfb5c9, 0x5d80776c74606, 0x5d813966377cf, 0x5d847bdc49b43,
+    0x5d85193ca935a, 0x5d86664a1a061, 0x5d86f15dc7b9f, 0x5d889641c131e,
+    0x5d88afe053b81, 0x5d8c04a317bce, 0x5d8ee447d037b, 0x5d8f6460acd6a,
+    0x5d8f73046e47e, 0x5d8fe82279d1e, 0x5d911f3a48787, 0x5d917ab9c17c4,
+    0x5d918d067349d, 0x5d9290e11ce40, 0x5d94de2046f25, 0x5d95115d6bfeb,
+    0x5d9523aa1dcc4, 0x5d95742ec5bb2, 0x5d97031d1d093, 0x5d97d76391f35,
+    0x5d97db0c824fa, 0x5d97f4ab14d5d, 0x5d9811f297b85, 0x5d9c25062e7d6,
+    0x5d9c71e1e60ff, 0x5d9d4d7a3bb2b, 0x5d9dc64137990, 0x5d9fc6a4aa14c,
+    0x5da03f6ba5fb1, 0x5da3a67b1bcd7, 0x5da3e10a21927, 0x5da6025e074d0,
+    0x5da61bfc99d33, 0x5da70638b0e73, 0x5da81c600c4ef, 0x5da84bf440ff0,
+    0x5da8b26e8b17c, 0x5da98a5df05e3, 0x5daae9b812fc3, 0x5dab8718727da,
+    0x5dace2c9a4bf5, 0x5dad5f399101f, 0x5dadc5b3db1ab, 0x5daee6d607976,
+    0x5daf2c5fde715, 0x5db0044f43b7c, 0x5db075c45ee57, 0x5db429af8c4a6,
+    0x5db6c3ca6deb4, 0x5db70cfd35218, 0x5db7562ffc57c, 0x5db9110997f99,
+    0x5db9da553bcec, 0x5dbbab24799a7, 0x5dbc62236ba21, 0x5dbd032cbb7fd,
+    0x5dbd15796d4d6, 0x5dbd19225da9b, 0x5dbeed9a8bd1b, 0x5dbfd084c22d1,
+    0x5dc0b717e8e4c, 0x5dc3b40424421, 0x5dc53f498b33d, 0x5dc5f9f16d97c,
+    0x5dc64a761586a, 0x5dc77a3c03749, 0x5dc79031a59e7, 0x5dc7fdfdd06fd,
+    0x5dc92671dda52, 0x5dc9943e08768, 0x5dcbc08cbf460, 0x5dcbe52622e12,
+    0x5dcbf020f3f61, 0x5dcc235e19027, 0x5dcc5a442e6b2, 0x5dcccf6239f52,
+    0x5dcd14ec10cf1, 0x5dce8de4c5f34, 0x5dce9536a6abe, 0x5dcf18f873a72,
+    0x5dcf95685fe9c, 0x5dcfdaf236c3b, 0x5dd00e2f5bd01, 0x5dd0e9c7b172d,
+    0x5dd3afcdd7677, 0x5dd3c21a89350, 0x5dd42c3dc3aa1, 0x5dd50f27fa057,
+    0x5dd6b06303211, 0x5dd6bb5dd4360, 0x5dd713345cdd8, 0x5dd7467181e9e,
+    0x5dd7e028f10f0, 0x5dd967c567a47, 0x5dd997599c548, 0x5dd99eab7d0d2,
+    0x5ddb55dc2852a, 0x5ddb5d2e090b4, 0x5ddccb2bed1a8, 0x5ddd26ab661e5,
+    0x5ddd354f278f9, 0x5ddf2365e83dc, 0x5ddf70419fd05, 0x5ddfb222864df,
+    0x5de35ebbd2fa4, 0x5de40e68e4494, 0x5de4edaa2a485, 0x5de512438de37,
+    0x5de54929a34c2, 0x5de6f55f7d7cb, 0x5de75f82b7f1c, 0x5de8670651e84,
+    0x5deb63f28d459, 0x5debad25547bd, 0x5dece43d23226, 0x5dee81cf3be1b,
+    0x5def108bd9f1e, 0x5def735d33ae5, 0x5defa2f1685e6, 0x5df110ef4c6da,
+    0x5df58a7d2d4b7, 0x5df58e261da7c, 0x5df8c5a15eca1, 0x5df9e31a9aea7,
+    0x5dfc1e0d132b3, 0x5dfd8c0af73a7, 0x5dfeef0e0a34c, 0x5dff642c15bec,
+    0x5dffd5a130ec7, 0x5e0093f203acb, 0x5e0197ccad46e, 0x5e02d9df4d026,
+    0x5e0426ecbdd2d, 0x5e043ce25ffcb, 0x5e04910ff847e, 0x5e04ddebafda7,
+    0x5e066cda07288, 0x5e085e99b8330, 0x5e0bfc8f436e1, 0x5e0cdf7979c97,
+    0x5e0d0b64be1d3, 0x5e0db768df0fe, 0x5e0ebeec79066, 0x5e0f6747a99cc,
+    0x5e0fcdc1f3b58, 0x5e110130d1ffc, 0x5e14e10743b87, 0x5e15109b78688,
+    0x5e1517ed59212, 0x5e187401fdde9, 0x5e18e92009689, 0x5e18fb6cbb362,
+    0x5e19f0a3a35f1, 0x5e1b8a8ccbc21, 0x5e1dea18a79df, 0x5e1dfc65596b8,
+    0x5e1e4cea015a6, 0x5e2321f75b3c0, 0x5e246409faf78, 0x5e24a5eae1752,
+    0x5e253bf9603df, 0x5e26103fd5281, 0x5e26d98b78fd4, 0x5e28d9eeeb790,
+    0x5e29feba08520, 0x5e2cb9c55d31b, 0x5e2f48e56dbda, 0x5e2fed97adf7b,
+    0x5e2ffc3b6f68f, 0x5e30ea2076d94, 0x5e33fd0254607, 0x5e35291f51f21,
+    0x5e3542bde4784, 0x5e356b00386fb, 0x5e370c3b418b5, 0x5e38ef5731249,
+    0x5e3a5603347b3, 0x5e3bb1b466bce, 0x5e3d14b779b73, 0x5e3d73dfe3175,
+    0x5e3dc4648b063, 0x5e3f918ad8759, 0x5e4195973b4da, 0x5e43c937d2d5c,
+    0x5e43f17a26cd3, 0x5e4483dfb539b, 0x5e44afcaf98d7, 0x5e4503f891d8a,
+    0x5e4507a18234f, 0x5e47254c77933, 0x5e47463cead20, 0x5e475c328cfbe,
+    0x5e479a6a831d3, 0x5e4b1ec17bd21, 0x5e4bd5c06dd9b, 0x5e4d8cf1191f3,
+    0x5e50ae76b817a, 0x5e511febd3455, 0x5e51398a65cb8, 0x5e519509decf5,
+    0x5e5298e488698, 0x5e5369820cf75, 0x5e57127269475, 0x5e578b39652da,
+    0x5e57928b45e64, 0x5e57c9715b4ef, 0x5e5b96fb1b3a1, 0x5e5c011e55af2,
+    0x5e5c25b7b94a4, 0x5e5d46d9e5c6f, 0x5e5e6f4df2fc4, 0x5e5fc00454290,
+    0x5e609f459a281, 0x5e616fe31eb5e, 0x5e6373ef818df, 0x5e672b839f4f3,
+    0x5e67af456c4a7, 0x5e67bde92dbbb, 0x5e67d035df894, 0x5e6b50e3e7e1d,
+    0x5e6cc633aca9b, 0x5e6dd5092758d, 0x5e6e3f2c61cde, 0x5e71177f39901,
+    0x5e732a2f5dd96, 0x5e73c78fbd5ad, 0x5e74b1cbd46ed, 0x5e7531e4b10dc,
+    0x5e7795197d45f, 0x5e77a7662f138, 0x5e77c856a2525, 0x5e7a8ab3d7eaa,
+    0x5e7c19a22f38b, 0x5e7ca4b5dcec9, 0x5e7dbadd38545, 0x5e7f377eddd4d,
+    0x5e7f880385c3b, 0x5e7fdfda0e6b3, 0x5e7ffd21914db, 0x5e80a57cc1e41,
+    0x5e8184be07e32, 0x5e83d1fd31f17, 0x5e83e7f2d41b5, 0x5e85056c103bb,
+    0x5e86a2fe28fb0, 0x5e86a6a719575, 0x5e86d9e43e63b, 0x5e8785e85f566,
+    0x5e87a6d8d2953, 0x5e89864bd1d22, 0x5e89ecc61beae, 0x5e8cb2cc41df8,
+    0x5e8d27ea4d698, 0x5e8d368e0edac, 0x5e9342b33762f, 0x5e9525cf26fc3,
+    0x5e95a23f133ed, 0x5e96e7faa356a, 0x5e98bc72d17ea, 0x5e98d9ba54612,
+    0x5e98e10c3519c, 0x5e9940349e79e, 0x5e99c79f5bd17, 0x5e9b5a36a37bd,
+    0x5e9c89fc9169c, 0x5e9ca744144c4, 0x5e9da023ecd18, 0x5e9f5ea678cfa,
+    0x5ea136c79753f, 0x5ea16a04bc605, 0x5ea1e2cbb846a, 0x5ea7678623774,
+    0x5ea9225fbf191, 0x5ea934ac70e6a, 0x5ea94e4b036cd, 0x5eaabff1d7d86,
+    0x5eabb17fcfa50, 0x5eac687ec1aca, 0x5eacfe8d40757, 0x5eadb58c327d1,
+    0x5eaf7065ce1ee, 0x5eb3503c3fd79, 0x5eb3d3fe0cd2d, 0x5eb787e93a37c,
+    0x5eb980facbfae, 0x5eb9d17f73e9c, 0x5eb9eb1e066ff, 0x5ebb4a78290df,
+    0x5ebc34b44021f, 0x5ebc72ec36434, 0x5ebf6c2f81444, 0x5ec66639b13cc,
+    0x5ec6f4f64f4cf, 0x5ec771663b8f9, 0x5ec819c16c25f, 0x5ec85f4b42ffe,
+    0x5ec924edf678c, 0x5ecc9aa12dbc6, 0x5ed0906d419ef, 0x5ed0d5f71878e,
+    0x5ed1bc8a3f309, 0x5ed45a4e112dc, 0x5ed4de0fde290, 0x5ed5323d76743,
+    0x5ed5da98a70a9, 0x5edd9dee7ad84, 0x5edde37851b23, 0x5ede6ae30f09c,
+    0x5edfd53802bcb, 0x5ee0888e04680, 0x5ee32da3b71dd, 0x5ee3a66ab3042,
+    0x5ee3fe413baba, 0x5ee69c050da8d, 0x5ee768f9a1da5, 0x5ee8916daf0fa,
+    0x5ee8daa07645e, 0x5ee939c8dfa60, 0x5eeb5021f44ba, 0x5eeef569603f5,
+    0x5ef0f975c3176, 0x5ef31377c8195, 0x5ef44e38871c3, 0x5ef47a23cb6ff,
+    0x5ef5d22c0d555, 0x5ef65244e9f44, 0x5ef81bc247075, 0x5ef88d3762350,
+    0x5ef98d691b72e, 0x5ef994bafc2b8, 0x5efb7f28cc7d6, 0x5efcf47891454,
+    0x5efd27b5b651a, 0x5efd783a5e408, 0x5effba7eb739e, 0x5f0020f90152a,
+    0x5f034d7971600, 0x5f03880877250, 0x5f063f6adba86, 0x5f069aea54ac3,
+    0x5f074e4056578, 0x5f078c784c78d, 0x5f0aaa54fb14f, 0x5f0c443e2377f,
+    0x5f0c9119db0a8, 0x5f0ccba8e0cf8, 0x5f0d5e0e6f3c0, 0x5f0f0a44496c9,
+    0x5f107beb1dd82, 0x5f115b2c63d73, 0x5f146a6551021, 0x5f14790912735,
+    0x5f14805af32bf, 0x5f14f921ef124, 0x5f165c25020c9, 0x5f16fd2e51ea5,
+    0x5f171a75d4ccd, 0x5f17e0188845b, 0x5f18553693cfb, 0x5f18c302bea11,
+    0x5f18e3f331dfe, 0x5f1960631e228, 0x5f1cb525e2275, 0x5f1db1aeab08e,
+    0x5f1dd29f1e47b, 0x5f1f9c1c7b5ac, 0x5f1fc807bfae8, 0x5f2014e377411,
+    0x5f207062f044e, 0x5f20ff1f8e551, 0x5f21952e0d1de, 0x5f21cfbd12e2e,
+    0x5f25247fd6e7b, 0x5f25be37460cd, 0x5f25e6799a044, 0x5f291a4beaca4,
+    0x5f29b40359ef6, 0x5f2b0c0b9bd4c, 0x5f2b2cfc0f139, 0x5f2c72b79f2b6,
+    0x5f3090c607056, 0x5f31cb86c6084, 0x5f33238f07eda, 0x5f33c49857cb6,
+    0x5f342ebb92407, 0x5f355ad88fd21, 0x5f36cc7f643da, 0x5f37d402fe342,
+    0x5f3b1dcaf1240, 0x5f3bcd7802730, 0x5f3c5fdd90df8, 0x5f3cb40b292ab,
+    0x5f3cfd3df060f, 0x5f3d292934b4b, 0x5f3ec6bb4d740, 0x5f3ee059dffa3,
+    0x5f40b1291dc5e, 0x5f41906a63c4f, 0x5f458d8858602, 0x5f45b221bbfb4,
+    0x5f478debcadbe, 0x5f4953c03792a, 0x5f4b0747f27bd, 0x5f4c8040a7a00,
+    0x5f4d24f2e7da1, 0x5f4d71ce9f6ca, 0x5f4f4d98ae4d4, 0x5f4f80d5d359a,
+    0x5f4fbf0dc97af, 0x5f506768fa115, 0x5f540cb066050, 0x5f543149c9a02,
+    0x5f55a6998e680, 0x5f56096ae8247, 0x5f59b60434d0c, 0x5f59cfa2c756f,
+    0x5f59f7e51b4e6, 0x5f5d41ad0e3e4, 0x5f5ddb647d636, 0x5f5dedb12f30f,
+    0x5f5ec5a094776, 0x5f5f2871ee33d, 0x5f5f9d8ff9bdd, 0x5f6019ffe6007,
+    0x5f60d4a7c8646, 0x5f6100930cb82, 0x5f617d02f8fac, 0x5f629a7c351b2,
+    0x5f64380e4dda7, 0x5f650159f1afa, 0x5f6773327f591, 0x5f68352c4275a,
+    0x5f6bd6cabe0d0, 0x5f6c7b7cfe471, 0x5f6d3d76c163a, 0x5f6d4fc373313,
+    0x5f6d9c9f2ac3c, 0x5f6da799fbd8b, 0x5f6db9e6ada64, 0x5f6ff88216435,
+    0x5f708395c3f73, 0x5f712f99e4e9e, 0x5f7178ccac202, 0x5f71ccfa446b5,
+    0x5f71d44c2523f, 0x5f72a4e9a9b1c, 0x5f735be89bb96, 0x5f7425343f8e9,
+    0x5f74636c35afe, 0x5f752565f8cc7, 0x5f75848e622c9, 0x5f770c2ad8c20,
+    0x5f7885238de63, 0x5f792283ed67a, 0x5f79730895568, 0x5f7b9bae5bc9b,
+    0x5f7c64f9ff9ee, 0x5f7e4bbedf947, 0x5f7e4f67cff0c, 0x5f806969d4f2b,
+    0x5f81783f4fa1d, 0x5f818a8c016f6, 0x5f81f4af3be47, 0x5f8471829aa2d,
+    0x5f85cd33cce48, 0x5f85d82e9df97, 0x5f875c2224329, 0x5f8b3bf895eb4,
+    0x5f8b51ee38152, 0x5f8ccae6ed395, 0x5f8d091ee35aa, 0x5f8d5d4c7ba5d,
+    0x5f9089ccebb33, 0x5f90bd0a10bf9, 0x5f91272d4b34a, 0x5f91c8369b126,
+    0x5f9636c9aadb4, 0x5f969d43f4f40, 0x5f97718a69de2, 0x5f9ae73da121c,
+    0x5f9b4db7eb3a8, 0x5f9bca27d77d2, 0x5f9db0ecb772b, 0x5f9f60cb81ff9,
+    0x5fa1c0575ddb7, 0x5fa46cbef149e, 0x5fa4d3393b62a, 0x5fa6781d34da9,
+    0x5fa7242155cd4, 0x5fa9abef85a09, 0x5fa9c1e527ca7, 0x5fb108cb0f558,
+    0x5fb31b7b339ed, 0x5fb59fa07315d, 0x5fb7064c766c7, 0x5fb761cbef704,
+    0x5fb9747c13b99, 0x5fbaf11db93a1, 0x5fbc36d94951e, 0x5fbc83b500e47,
+    0x5fbdbe75bfe75, 0x5fbf28cab39a4, 0x5fbf4d6417356, 0x5fbf8b9c0d56b,
+    0x5fc1b441d3c9e, 0x5fc1c2e5953b2, 0x5fc1c68e85977, 0x5fc429c351cfa,
+    0x5fc46ba4384d4, 0x5fc5b15fc8651, 0x5fc6a2edc031b, 0x5fc6c78723ccd,
+    0x5fc87765ee59b, 0x5fc952fe43fc7, 0x5fcad348d9d94, 0x5fcaf4394d181,
+    0x5fcc623731275, 0x5fcc782cd3513, 0x5fcd6268ea653, 0x5fcd785e8c8f1,
+    0x5fcd95a60f719, 0x5fd0754ac7ec6, 0x5fd1423f5c1de, 0x5fd1dbf6cb430,
+    0x5fd530b98f47d, 0x5fd579ec567e1, 0x5fd5e7b8814f7, 0x5fd7854a9a0ec,
+    0x5fdb776dbd950, 0x5fdf44f77d802, 0x5fe0af4c71331, 0x5fe2db9b28029,
+    0x5fe3580b14453, 0x5fe3e31ec1f91, 0x5fe4374c5a444, 0x5fe4b76536e33,
+    0x5fe675e7c2e15, 0x5fe6accdd84a0, 0x5fe79709ef5e0, 0x5fe8430e1050b,
+    0x5fe8e06e6fd22, 0x5fe9b10bf45ff, 0x5fec59ca97721, 0x5fec686e58e35,
+    0x5fed90e26618a, 0x5fedcf1a5c39f, 0x5feec7fa34bf3, 0x5feefb3759cb9,
+    0x5fefc482fda0c, 0x5ff29583f4aa5, 0x5ff4714e038af, 0x5ff6bae43d3cf,
+    0x5ff82c8b11a88, 0x5ffbe0763f0d7, 0x5ffdd235f017f, 0x5ffe4ea5dc5a9,
+    0x60040def4d503, 0x6004de8cd1de0, 0x60059cdda49e4, 0x6006d04c82e88,
+    0x600899c9dffb9, 0x60095bc3a3182, 0x600a8f3281626, 0x600b7d1788d2b,
+    0x600bd4ee117a3, 0x600bf5de84b90, 0x600c849b22c93, 0x600d37f124748,
+    0x600dd1a89399a, 0x600fbfbf5447d, 0x600ffa4e5a0cd, 0x6010681a84de3,
+    0x6011141ea5d0e, 0x6012e4ede39c9, 0x6014cf5bb3ee7, 0x6014ddff755fb,
+    0x6015f07de06b2, 0x6015ff21a1dc6, 0x60165e4a0b3c8, 0x6017af006c694,
+    0x6017e5e681d1f, 0x60185ead7db84, 0x601cb3a1fafaf, 0x601cb74aeb574,
+    0x601cc5eeacc88, 0x601d0f2173fec, 0x601d87e86fe51, 0x601e9a66daf08,
+    0x6020a96e0edd8, 0x6021ef299ef55, 0x6023e0e94fffd, 0x6024fab99bc3e,
+    0x60269bf4a4df8, 0x6026a6ef75f47, 0x6026bce5181e5, 0x60274ba1b62e8,
+    0x6027cbba92cd7, 0x602b91f271fff, 0x602ba7e81429d, 0x602be27719eed,
+    0x602c6d8ac7a2b, 0x602e803aebec0, 0x6030167b23f2b, 0x603084474ec41,
+    0x60315fdfa466d, 0x603196c5b9cf8, 0x6033e7add43a2, 0x6034766a724a5,
+    0x6036cea46d6d9, 0x6036fa8fb1c15, 0x603743c278f79, 0x6038404b41d92,
+    0x60391f8c87d83, 0x603bb9a769791, 0x603c117df2209, 0x603cf468287bf,
+    0x603df0f0f15d8, 0x603fedab737cf, 0x603ffff8254a8, 0x60409606a4135,
+    0x6041ee0ee5f8b, 0x6046345fa1ca2, 0x60479b0ba520c, 0x60484e61a6cc1,
+    0x60497a7ea45db, 0x604d35bbb27b4, 0x604fcc2da3bfd, 0x605336e609ee8,
+    0x60535f285de5f, 0x6054d0cf32518, 0x605c7e2f63f55, 0x605d352e55fcf,
+    0x605daa4c6186f, 0x605dc3eaf40d2, 0x605f31e8d81c6, 0x605f89bf60c3e,
+    0x605fb5aaa517a, 0x605fbcfc85d04, 0x6060cf7af0dbb, 0x60618a22d33fa,
+    0x6065a0df5a610, 0x6065fc5ed364d, 0x606678cebfa77, 0x6069b3f2f1261,
+    0x606af9ae813de, 0x606c9ae98a598, 0x606d817cb1113, 0x606dd5aa495c6,
+    0x606e4ac854e66, 0x60740dbab6385, 0x607453448d124, 0x60745e3f5e273,
+    0x60770e4fe1f1f, 0x607e0c030246c, 0x607f381fffd86, 0x6086c0e6cde11,
+    0x6088a7abadd6a, 0x6089450c0d581, 0x608ca4c9a271d, 0x608d24e27f10c,
+    0x608f28eee1e8d, 0x609042bf2dace, 0x6093a27cc2c6a, 0x6095b8d5d76c4,
+    0x6095cecb79962, 0x6096aa63cf38e, 0x6097c4341afcf, 0x609835a9362aa,
+    0x609b2eec812ba, 0x609bc4fafff47, 0x609f753d3cfd1, 0x609f8789eecaa,
+    0x60a0ee35f2214, 0x60a149b56b251, 0x60a1b78195f67, 0x60a425b133439,
+    0x60a48c2b7d5c5, 0x60a655a8da6f6, 0x60ad4c0a1a0b9, 0x60ad5aaddb7cd,
+    0x60ada037b256c, 0x60ae99178adc0, 0x60b0454d650c9, 0x60b4008a732a2,
+    0x60b533f951746, 0x60b6b443e7513, 0x60b7888a5c3b5, 0x60b82d3c9c756,
+    0x60b9470ce8397, 0x60b976a11ce98, 0x60b9fe0bda411, 0x60bbf3747ba7e,
+    0x60bd2a8c4a4e7, 0x60bd52ce9e45e, 0x60c0cfd3b6422, 0x60c1e5fb11a9e,
+    0x60c3e2b593c95, 0x60c462ce70684, 0x60c4f18b0e787, 0x60c59d8f2f6b2,
+    0x60c7e725691d2, 0x60c83efbf1c4a, 0x60c9f62c9d0a2, 0x60cc81a3bd39c,
+    0x60cd524141c79, 0x60d156b1171b6, 0x60d470e4d55b3, 0x60d4d75f1f73f,
+    0x60d5b6a065730, 0x60d7a4b726213, 0x60d88b4a4cd8e, 0x60d8b735912ca,
+    0x60d907ba391b8, 0x60d91dafdb456, 0x60d95495f0ae1, 0x60d98b7c0616c,
+    0x60dc00fd841c8, 0x60dd5caeb65e3, 0x60e14b28e9882, 0x60e4e5758466e,
+    0x60e51509b916f, 0x60e698fd3f501, 0x60e9a0e44bc25, 0x60ebfcc73741e,
+    0x60ec9a2796c35, 0x60ee8be747cdd, 0x60f0fdbfd5774, 0x60f134a5eadff,
+    0x60f490ba8f9d6, 0x60f5774db6551, 0x60f5c4296de7a, 0x60f7656477034,
+    0x60ff166d99036, 0x60ff7ce7e31c2, 0x6101082d4a0de, 0x610451f53cfdc,
+    0x6105681c98658, 0x610789707e201, 0x6108b58d7bb1b, 0x610909bb13fce,
+    0x610cdaedc4445, 0x611479aa3476e, 0x6115603d5b2e9, 0x6115b46af379c,
+    0x6115d1b2765c4, 0x611cde0958225, 0x611e70a09fccb, 0x611ed71ae9e57,
+    0x611f782439c33, 0x612141a196d64, 0x61216d8cdb2a0, 0x6123b72314dc0,
+    0x6124c9a17fe77, 0x6126588fd7358, 0x6127a59d4805f, 0x6128e7afe7c17,
+    0x6129592502ef2, 0x61298c6227fb8, 0x612bdd4a42662, 0x612c52684df02,
+    0x612c9449346dc, 0x612ed2e49d0ad, 0x612f35b5f6c74, 0x612f8d8c7f6ec,
+    0x613415be21bdd, 0x6134788f7b7a4, 0x6134edad87044, 0x6135492d00081,
+    0x6135baa21b35c, 0x6135be4b0b921, 0x61363abaf7d4b, 0x6136495eb945f,
+    0x61366df81ce11, 0x6138a198b4693, 0x6139bb69002d4, 0x613c13a2fb508,
+    0x613c7a1d45694, 0x613ca9b17a195, 0x613e474392d8a, 0x613f40236b5de,
+    0x613f7360906a4, 0x61401469e0480, 0x61414086ddd9a, 0x6141c7f19b313,
+    0x61445aba9c197, 0x6146a0a7e56f2, 0x61486dce32de8, 0x6148ac0628ffd,
+    0x614916296374e, 0x61491d7b442d8, 0x614c54f6854fd, 0x614d084c86fb2,
+    0x614e7d9c4bc30, 0x614f7dce0500e, 0x61514af452704, 0x615176df96c40,
+    0x6153390b131e7, 0x6155396e859a3, 0x61559c3fdf56a, 0x61573d7ae8724,
+    0x6157c13cb56d8, 0x61597fbf416ba, 0x615a247181a5b, 0x615eb79df509b,
+    0x615ef97edb875, 0x6165b1a825023, 0x6167568c1e7a2, 0x61677ece72719,
+    0x6169fba1d12ff, 0x616c95bcb2d0d, 0x616cc8f9d7dd3, 0x61713b35d8026,
+    0x6171dc3f27e02, 0x6173762850432, 0x617388750210b, 0x6173dca29a5be,
+    0x6176e0e0b671d, 0x617797dfa8797, 0x6177c773dd298, 0x6178c7a596676,
+    0x6179b1e1ad7b6, 0x617ced05defa0, 0x617cf0aecf565, 0x617d488557fdd,
+    0x617d8a663e7b7, 0x618575fe66409, 0x61858bf4086a7, 0x61861707b61e5,
+    0x6186d55888de9, 0x61874324b3aff, 0x618772b8e8600, 0x6187a99efdc8b,
+    0x6187e7d6f3ea0, 0x6188176b289a1, 0x6189c749f326f, 0x618c1bdafdede,
+    0x618d828701448, 0x618e2739417e9, 0x618e61c847439, 0x618eaafb0e79d,
+    0x618f7f418363f, 0x618fed0dae355, 0x619044e436dcd, 0x6191834de63c0,
+    0x6191b2e21aec1, 0x6194ee064c6ab, 0x6195b0000f874, 0x6196844684716,
+    0x6197588cf95b8, 0x6198f27621be8, 0x61993457083c2, 0x619c55dca7349,
+    0x619d1f284b09c, 0x619d81f9a4c63, 0x619fb1f14bf20, 0x61a1b9a69f266,
+    0x61a96aafc1268, 0x61ab30842ddd4, 0x61abbb97db912, 0x61ac04caa2c76,
+    0x61ac30b5e71b2, 0x61acb0cec3ba1, 0x61aec37ee8036, 0x61afe84a04dc6,
+    0x61b1738f6bce2, 0x61b32ac01713a, 0x61b373f2de49e, 0x61b70e3f7928a,
+    0x61b7417c9e350, 0x61b8330a9601a, 0x61b88e8a0f057, 0x61b9be4ffcf36,
+    0x61b9d7ee8f799, 0x61bbfceb65907, 0x61bc043d46491, 0x61bc1a32e872f,
+    0x61bc2184c92b9, 0x61bc795b51d31, 0x61bde3b045860, 0x61be21e83ba75,
+    0x61bf012981a66, 0x61c1bc34d6861, 0x61c505fcc975f, 0x61c6480f69317,
+    0x61c7e94a724d1, 0x61c8aeed25c5f, 0x61c9bdc2a0751, 0x61cc2bf23dc23,
+    0x61cdd82817f2c, 0x61cedfabb1e94, 0x61cf845df2235, 0x61d0306213160,
+    0x61d0dc663408b, 0x61d54af943d19, 0x61d74061e5386, 0x61d785ebbc125,
+    0x61d7a6dc2f512, 0x61d92acfb58a4, 0x61d9cf81f5c45, 0x61db8e0481c27,
+    0x61dcbdca6fb06, 0x61dce9b5b4042, 0x61dd627cafea7, 0x61dd7c1b4270a,
+    0x61ddcc9fea5f8, 0x61de15d2b195c, 0x61de1d24924e6, 0x61df334bedb62,
+    0x61dfbab6ab0db, 0x61e166ec853e4, 0x61e2b3f9f60eb, 0x61e380ee8a403,
+    0x61e3a587eddb5, 0x61e4936cf54ba, 0x61e4b80658e6c, 0x61e5ce2db44e8,
+    0x61e6f2f8d1278, 0x61e72635f633e, 0x61e76f68bd6a2, 0x61e7b149a3e7c,
+    0x61e9d9ef6a5af, 0x61eda027498d7, 0x61ef7bf1586e1, 0x61f0e29d5bc4b,
+    0x61f3a4fa915d0, 0x61f525452739d, 0x61fa73197d01c, 0x61fc1f4f57325,
+    0x61fd9f99ed0f2, 0x61fe1fb2c9ae1, 0x61fecbb6eaa0c, 0x61fecf5fdafd1,
+    0x61ffa74f40438, 0x62005aa541eed, 0x6201a409c262f, 0x620441cd94602,
+    0x6205335b8c2cc, 0x62076aa514113, 0x620792e76808a, 0x620c29bccbc8f,
+    0x620c4aad3f07c, 0x620ccac61ba6b, 0x620efabdc2d28, 0x620f4eeb5b1db,
+    0x620fa6c1e3c53, 0x62113d021bcbe, 0x6213c1275b42e, 0x6213e5c0bede0,
+    0x6213e969af3a5, 0x6214d74eb6aaa, 0x6214dea097634, 0x6215ab952b94c,
+    0x6216872d81378, 0x6217b34a7ec92, 0x6219632949560, 0x62196e241a6af,
+    0x621c3b7c21183, 0x621df9fead165, 0x622394aeba70d, 0x6225448d84fdb,
+    0x622774852c298, 0x62278a7ace536, 0x6228626a3399d, 0x622957a11bc2c,
+    0x6229998202406, 0x622fe3df20e9e, 0x6230c32066e8f, 0x62314a8b24408,
+    0x6231d1f5e1981, 0x62341b8c1b4a1, 0x623485af55bf2, 0x6234dd85de66a,
+    0x62352a6195f93, 0x6238e94794731, 0x6239202da9dbc, 0x6239a79867335,
+    0x623a7bdedc1d7, 0x623bb2f6aac40, 0x623c20c2d5956, 0x623d9d647b15e,
+    0x623dd44a907e9, 0x624413acde132, 0x6245bc39c7e76, 0x6247402d4e208,
+    0x6247611dc15f5, 0x6247cee9ec30b, 0x62488d3abef0f, 0x624c53729e237,
+    0x624c8a58b38c2, 0x624cf824de5d8, 0x624e6622c26cc, 0x624e9d08d7d57,
+    0x624fb330333d3, 0x624fc57ce50ac, 0x6250a8671b662, 0x6251fcc66cef3,
+    0x625363727045d, 0x62539306a4f5e, 0x62549a8a3eec6, 0x625521f4fc43f,
+    0x6255cdf91d36a, 0x625651baea31e, 0x62569aedb1682, 0x625847238b98b,
+    0x62588cad6272a, 0x62592db6b2506, 0x62594003641df, 0x625bf3bcd8450,
+    0x625e3601313e6, 0x625eb9c2fe39a, 0x625ef7faf45af, 0x625fa3ff154da,
+    0x6262d42875b75, 0x6265bec7ff471, 0x6267724fba304, 0x626967b85b971,
+    0x626d9bbc659af, 0x626d9f6555f74, 0x627102cbdb6d5, 0x62716cef15e26,
+    0x62719188797d8, 0x62735b05d6909, 0x627415adb8f48, 0x6274d3fe8bb4c,
+    0x6275123681d61, 0x6275715eeb363, 0x6276fca45227f, 0x62779dada205b,
+    0x6277a8a8731aa, 0x62789ddf5b439, 0x627916a65729e, 0x62798f6d53103,
+    0x627c46cfb7939, 0x627eadad74281, 0x627f97e98b3c1, 0x627f9f3b6bf4b,
+    0x627ffabae4f88, 0x62807e7cb1f3c, 0x6280c7af792a0, 0x628189a93c469,
+    0x62845aaa33502, 0x6284ab2edb3f0, 0x6284cc1f4e7dd, 0x6285697fadff4,
+    0x628578236f708, 0x6286834ff9c35, 0x628736a5fb6ea, 0x6288a0faef219,
+    0x62890b1e2996a, 0x62896def83531, 0x6289d469cd6bd, 0x628b376ce0662,
+    0x628b3ebec11ec, 0x628b844897f8b, 0x628bbb2ead616, 0x628d934fcbe5b,
+    0x628d96f8bc420, 0x628f1aec427b2, 0x628f348ad5015, 0x628fc34773118,
+    0x628feb89c708f, 0x6291a2ba724e7, 0x6295d3158bf60, 0x62967075eb777,
+    0x6296de421648d, 0x6297cc271db92, 0x629b62cac83b9, 0x62a06ebe3785e,
+    0x62a0e3dc430fe, 0x62a10123c5f26, 0x62a2dcedd4d30, 0x62a3ef6c3fde7,
+    0x62a455e689f73, 0x62a7aaa94dfc0, 0x62a7d2eba1f37, 0x62a94f8d4773f,
+    0x62abba13f464c, 0x62acdb3620e17, 0x62ace9d9e252b, 0x62acfc2694204,
+    0x62ad45595b568, 0x62adbe20573cd, 0x62af58097f9fd, 0x62b0792bac1c8,
+    0x62b0d4ab25205, 0x62b1252fcd0f3, 0x62b1ac9a8a66c, 0x62b3f9d9b4751,
+    0x62b59019ec7bc, 0x62b5a9b87f01f, 0x62b5bfae212bd, 0x62b6a64147e38,
+    0x62b6bfdfda69b, 0x62b7ebfcd7fb5, 0x62b8cee70e56b, 0x62b92e0f77b6d,
+    0x62b93561586f7, 0x62b97aeb2f496, 0x62bf6d71c54b6, 0x62bf920b28e68,
+    0x62bfdb3df01cc, 0x62c0abdb74aa9, 0x62c40447290bb, 0x62c4f97e1134a,
+    0x62c563a14ba9b, 0x62c7d922c9af7, 0x62c89021bbb71, 0x62cbbca22bc47,
+    0x62cc0d26d3b35, 0x62cc6ff82d6fc, 0x62cf40f924795, 0x62cf9526bcc48,
+    0x62d1450587516, 0x62d1ba2392db6, 0x62d29212f821d, 0x62d3abe343e5e,
+    0x62d43e48d2526, 0x62d462e235ed8, 0x62d478d7d8176, 0x62d499c84b563,
+    0x62d57560a0f8f, 0x62d58404626a3, 0x62d5a14be54cb, 0x62d94de531f90,
+    0x62dc228f195ee, 0x62dd05794fba4, 0x62dd4008557f4, 0x62ddfe59283f8,
+    0x62e339e0cc39e, 0x62e3746fd1fee, 0x62e4f86358380, 0x62e60e8ab39fc,
+    0x62e6248055c9a, 0x62e7927e39d8e, 0x62e871bf7fd7f, 0x62e892aff316c,
+    0x62e93b0b23ad2, 0x62e98b8fcb9c0, 0x62ec135dfb6f5, 0x62ecdca99f448,
+    0x62ecf64831cab, 0x62ed1738a5098, 0x62ed4a75ca15e, 0x62efa658b5957,
+    0x62f0fab8071e8, 0x62f105b2d8337, 0x62f190c685e75, 0x62f3ae717b459,
+    0x62f45323bb7fa, 0x62f4cbeab765f, 0x62f4d6e5887ae, 0x62f5ab2bfd650,
+    0x62f5de6922716, 0x62f5fbb0a553e, 0x62f6c4fc49291, 0x62f703343f4a6,
+    0x62f9abf2e25c8, 0x62fbf1e02bb23, 0x62fd588c2f08d, 0x62fe96f5de680,
+    0x62fff2a710a9b, 0x63004e2689ad8, 0x6301e0bdd157e, 0x6302b15b55e5b,
+    0x6303b18d0f239, 0x630490ce5522a, 0x63051f8af332d, 0x630689dfe6e5c,
+    0x6307e93a0983c, 0x63086201056a1, 0x63094fe60cda6, 0x630965dbaf044,
+    0x630d99dfb9082, 0x6311760d3a648, 0x6311e3d96535e, 0x6314c37e1db0b,
+    0x6315a668540c1, 0x6315c3afd6ee9, 0x6317529e2e3ca, 0x6317738ea17b7,
+    0x63184f26f71e3, 0x63191fc47bac0, 0x631994e287360, 0x631c06bb14df7,
+    0x631c4c44ebb96, 0x631cd3afa910f, 0x631d27dd415c2, 0x631fc5a113595,
+    0x63203e680f3fa, 0x63210f0593cd7, 0x6324eb331529d, 0x6326d5a0e57bb,
+    0x6327c72edd485, 0x63281b5c75938, 0x63282da927611, 0x6329e130e24a4,
+    0x6329f37d9417d, 0x632c187a6a2eb, 0x632d324ab5f2c, 0x632d56e4198de,
+    0x632f190f95e85, 0x6334633afb53f, 0x63349dca0118f, 0x633528ddaeccd,
+    0x63356abe954a7, 0x633675eb1f9d4, 0x633680e5f0b23, 0x6336848ee10e8,
+    0x6337d545423b4, 0x633e5688764d7, 0x633f40c48d617, 0x6343502f33ca3,
+    0x6343836c58d69, 0x6345d7fd639d8, 0x6346f91f901a3, 0x6346fcc880768,
+    0x63490826c4073, 0x63490bcfb4638, 0x634b88a31321e, 0x634c46f3e5e22,
+    0x634e141a33518, 0x634e9f2de1056, 0x634edd65d726b, 0x6351359fd249f,
+    0x635186247a38d, 0x635302c61fb95, 0x6353694069d21, 0x63545e7751fb0,
+    0x6354e5e20f529, 0x63555ea90b38e, 0x6357df255a539, 0x635bdfec3f4b1,
+    0x635c082e93428, 0x635cb089c3d8e, 0x636076c1a30b6, 0x63614b0817f58,
+    0x6361c3cf13dbd, 0x63628d1ab7b10, 0x636406136cd53, 0x6365d339ba449,
+    0x636727990bcda, 0x63674c326f68c, 0x63685eb0da743, 0x636c004f560b9,
+    0x636c547cee56c, 0x636c9a06c530b, 0x636cea8b6d1f9, 0x636ef240c053f,
+    0x637063e794bf8, 0x6371b49df5ec4, 0x6372a282fd5c9, 0x637360d3d01cd,
+    0x6374d9cc85410, 0x6374e4c75655f, 0x6377a7248bee4, 0x6377bd1a2e182,
+    0x6378064cf54e6, 0x637961fe27901, 0x63799c8d2d551, 0x637bbde1130fa,
+    0x637bd02dc4dd3, 0x637be9cc57636, 0x637ddf34f8ca3, 0x637f164cc770c,
+    0x637f92bcb3b36, 0x6380794fda6b1, 0x6380f5bfc6adb, 0x638379e50624b,
+    0x63848168a01b3, 0x6384b0fcd4cb4, 0x6384dce8191f0, 0x638534bea1c68,
+    0x63862d9e7a4bc, 0x6386e49d6c536, 0x6389da37c6f81, 0x6389dde0b7546,
+    0x638ccc2931407, 0x639387fb6b17a, 0x639442a34d7b9, 0x63950f97e1ad1,
+    0x63960fc99aeaf, 0x6396380beee26, 0x63976b7acd2ca, 0x63978c6b406b7,
+    0x639805323c51c, 0x63990563f58fa, 0x6399b8b9f73af, 0x6399ceaf9964d,
+    0x6399e84e2beb0, 0x639b739392dcc, 0x639c567dc9382, 0x639c89baee448,
+    0x639d14ce9bf86, 0x63a0db067b2ae, 0x63a0ff9fdec60, 0x63a1ba47c129f,
+    0x63a1d78f440c7, 0x63a3c94ef516f, 0x63a5f1f4bb8a2, 0x63ad402c83cdd,
+    0x63adcb403181b, 0x63ae68a091032, 0x63b04f6570f8b, 0x63b161e3dc042,
+    0x63b362474e7fe, 0x63b3d3bc69ad9, 0x63b3e2602b1ed, 0x63b520c9da7e0,
+    0x63b54cb51ed1c, 0x63b620fb93bbe, 0x63b6be5bf33d5, 0x63bf17c045d3d,
+    0x63c06c1f975ce, 0x63c09bb3cc0cf, 0x63c1097ff6de5, 0x63c1cb79b9fae,
+    0x63c439a957480, 0x63c44bf609159, 0x63c46ce67c546, 0x63c48dd6ef933,
+    0x63c523e56e5c0, 0x63cc6e7446436, 0x63cc9e087af37, 0x63cd1326867d7,
+    0x63cd37bfea189, 0x63cdb42fd65b3, 0x63cf5cbcc02f7, 0x63cf84ff1426e,
+    0x63cfb0ea587aa, 0x63cfbf8e19ebe, 0x63d0e80227213, 0x63d1f32eb1740,
+    0x63d238b8884df, 0x63d656c6f027f, 0x63d865ce2414f, 0x63d891b96868b,
+    0x63d8e23e10579, 0x63d970faae67c, 0x63da712c67a5a, 0x63db2f7d3a65e,
+    0x63dbea251cc9d, 0x63dc9d7b1e752, 0x63dcfca387d54, 0x63dd004c78319,
+    0x63e101135d291, 0x63e1a5c59d632, 0x63e3310b0454e, 0x63e5734f5d4e4,
+    0x63e5a2e391fe5, 0x63e6c7aeaed75, 0x63e79bf523c17, 0x63e961c990783,
+    0x63e9bd49097c0, 0x63ec033652d1b, 0x63ec27cfb66cd, 0x63ee666b1f09e,
+    0x63f0b3aa49183, 0x63f1b784f2b26, 0x63f35c68ec2a5, 0x63f381024fc57,
+    0x63f48c2eda184, 0x63f7ebec6f320, 0x63f9c0649d5a0, 0x63f9eff8d20a1,
+    0x63fb4baa044bc, 0x63fc114cb7c4a, 0x63fdf0bfb7019, 0x63fedafbce159,
+    0x640120e9176b4, 0x6401492b6b62b, 0x64028b3e0b1e3, 0x6403498eddde7,
+    0x640433caf4f27, 0x640521affc62c, 0x64052caacd77b, 0x6408d9441a240,
+    0x640b6c0d1b0c4, 0x640d408549344, 0x640fde491b317, 0x6410b6388077e,
+    0x6413b6cdac318, 0x6414ac04945a7, 0x6414d09df7f59, 0x64152c1d70f96,
+    0x6416a16d35c14, 0x641937df2705d, 0x641979c00d837, 0x641f31b79dc07,
+    0x641f47ad3fea5, 0x641fa32cb8ee2, 0x641fd669ddfa8, 0x64205a2baaf5c,
+    0x6421062fcbe87, 0x6427717d5dd0c, 0x642ad4e3e346d, 0x642c0fa4a249b,
+    0x642eb4ba54ff8, 0x642f4ac8d3c85, 0x642f6f6237637, 0x64302661296b1,
+    0x64316121e86df, 0x6431a6abbf47e, 0x6435c863177e3, 0x6435cc0c07da8,
+    0x6435e5aa9a60b, 0x643727bd3a1c3, 0x643db7a42f9fa, 0x643df985161d4,
+    0x643e4660cdafd, 0x643e6ea321a74, 0x6440bbe24bb59, 0x64433163c9bb5,
+    0x644359a61db2c, 0x6445f3c0ff53a, 0x644869427d596, 0x6449746f07ac3,
+    0x6449c89c9ff76, 0x644c506acfcab, 0x644c75043365d, 0x644cccdabc0d5,
+    0x644d2fac15c9c, 0x644f300f88458, 0x644f66f59dae3, 0x644f9ddbb316e,
+    0x645151636e001, 0x64518f9b64216, 0x64534e1df01f8, 0x6453606aa1ed1,
+    0x645422646509a, 0x64542d5f361e9, 0x6455472f81e2a, 0x6455bc4d8d6ca,
+    0x6457c059f044b, 0x6458bce2b9264, 0x645cdaf121004, 0x645d192917219,
+    0x645d7fa3613a5, 0x645fdf2f3d163, 0x645ffc76bff8b, 0x6463b40addb9f,
+    0x6465086a2f430, 0x64659e78ae0bd, 0x6467644d1ac29, 0x6467ef60c8767,
+    0x6467f309b8d2c, 0x6469142be54f7, 0x6469ec1b4a95e, 0x646c14c111091,
+    0x646c270dc2d6a, 0x646c2ab6b332f, 0x646d2ae86c70d, 0x646f2b4bdeec9,
+    0x647032cf78e31, 0x6470f4c93bffa, 0x647115b9af3e7, 0x647141a4f3923,
+    0x64757cfade4eb, 0x647b473f20594, 0x647be49f7fdab, 0x6480f7e4cfdda,
+    0x6481a04000740, 0x64852be8d9e18, 0x6488f220b9140, 0x64891e0bfd67c,
+    0x64892906ce7cb, 0x648d2624c317e, 0x648dad8f806f7, 0x648f143b83c61,
+    0x648f26883593a, 0x648fa6a112329, 0x6490cb6c2f0b9, 0x6490e161d1357,
+    0x6491701e6f45a, 0x6493c10689b04, 0x649712205d58c, 0x6497ccc83fbcb,
+    0x64993e6f14284, 0x649e764dc7c65, 0x649fd1fefa080, 0x64a1892fa54d8,
+    0x64a1bc6cca59e, 0x64a43ce919749, 0x64a5e5760348d, 0x64a6867f53269,
+    0x64a702ef3f693, 0x64a9a45c01c2b, 0x64ac4971b4788, 0x64acf91ec5c78,
+    0x64ad8432737b6, 0x64aec29c22da9, 0x64b09e6631bb3, 0x64b1172d2da18,
+    0x64b18c4b392b8, 0x64b4ec08ce454, 0x64b72e4d273ea, 0x64b91c63e7ecd,
+    0x64b944a63be44, 0x64bb2b6b1bd9d, 0x64bb4c5b8f18a, 0x64bb6d4c02577,
+    0x64bc4c8d48568, 0x64bcd7a0f60a6, 0x64be78dbff260, 0x64bec960a714e,
+    0x64bf8b5a6a317, 0x64c3f9ed79fa5, 0x64c493a4e91f7, 0x64c54aa3db271,
+    0x64c62d8e11827, 0x64c7107847ddd, 0x64ccde657a44b, 0x64cce5b75afd5,
+    0x64cd39e4f3488, 0x64ce86f26418f, 0x64d08003f5dc1, 0x64d0f8caf1c26,
+    0x64d1d0ba5708d, 0x64d337665a5f7, 0x64d36e4c6fc82, 0x64d3bb28275ab,
+    0x64d46e7e29060, 0x64d4722719625, 0x64d47978fa1af, 0x64d7014729ee4,
+    0x64d7b0f43b3d4, 0x64d947347343f, 0x64d96f76c73b6, 0x64d9731fb797b,
+    0x64dbfe96d7c75, 0x64e1b68e68045, 0x64e44d005948e, 0x64e46a47dc2b6,
+    0x64e4a87fd24cb, 0x64e4b37aa361a, 0x64e4e6b7c86e0, 0x64e52c419f47f,
+    0x64e56e2285c59, 0x64e68b9bc1e5f, 0x64e7638b272c6, 0x64e91369f1b94,
+    0x64e930b1749bc, 0x64ec906f09b58, 0x64ecc0033e659, 0x64edfe6cedc4c,
+    0x64eea31f2dfed, 0x64ef183d3988d, 0x64efb94689669, 0x64efdddfed01b,
+    0x64f0f40748697, 0x64f3c15f4f16b, 0x64f441782bb5a, 0x64f5b6c7f07d8,
+    0x64f90f33a4dea, 0x64f9252947088, 0x64f98ba391214, 0x64fdc6f97bddc,
+    0x64ff76d8466aa, 0x65007709ffa88, 0x65041ffa5bf88, 0x650423a34c54d,
+    0x65047f22c558a, 0x6504a3bc28f3c, 0x6504c103abd64, 0x65053621b7604,
+    0x6505b9e3845b8, 0x6506bdbe2df5b, 0x6507aba335660, 0x65085ef937115,
+    0x651155bde9294, 0x6511f31e48aab, 0x6514e8b8a34f6, 0x651510faf746d,
+    0x65167b4feaf9c, 0x6516cf7d8344f, 0x6516da785459e, 0x65172afcfc48c,
+    0x6518f82349b82, 0x651906c70b296, 0x65194ff9d25fa, 0x65195e9d93d0e,
+    0x651bbe296facc, 0x651bf50f85157, 0x651d287e635fb, 0x651e0b6899bb1,
+    0x651e8086a5451, 0x651f5fc7eb442, 0x652030656fd1f, 0x65204a0402582,
+    0x6525091bba0fe, 0x65259f2a38d8b, 0x65271822edfce, 0x6528f795ed39d,
+    0x652977aec9d8c, 0x6529f075c5bf1, 0x652b2edf751e4, 0x652bb2a142198,
+    0x652d209f2628c, 0x652d418f99679, 0x652f5f3a8ec5d, 0x652fdbaa7b087,
+    0x65344de67b2da, 0x65356b5fb74e0, 0x653864a3024f0, 0x6539fae33a55b,
+    0x653bb965c653d, 0x653d868c13c33, 0x653ecc47a3db0, 0x653f9ce52868d,
+    0x6540af6393744, 0x6540c90225fa7, 0x6541a0f18b40e, 0x65454d8ad7ed3,
+    0x654567296a736, 0x65459a668f7fc, 0x6545acb3414d5, 0x6545b7ae12624,
+    0x6545d89e85a11, 0x65461a7f6c1eb, 0x654b894435257, 0x654c4b3df8420,
+    0x654d4b6fb17fe, 0x654ef7a58bb07, 0x654f8db40a794, 0x6550c4cbd91fd,
+    0x6551ed3fe6552, 0x6554db8860413, 0x6555c96d67b18, 0x655671c89847e,
+    0x6557008536581, 0x65582ca233e9b, 0x6558304b24460, 0x65588821aced8,
+    0x655958bf317b5, 0x655996f7279ca, 0x6559a943d96a3, 0x6559eb24bfe7d,
+    0x655bd1e99fdd6, 0x655d34ecb2d7b, 0x655d4e8b455de, 0x6560407cafa64,
+    0x6560d68b2e6f1, 0x6561111a34341, 0x656189e1301a6, 0x6564c8ae51f55,
+    0x65650a8f3872f, 0x6565c5371ad6e, 0x6566d063a529b, 0x6567ea33f0edc,
+    0x6568928f21842, 0x6569757957df8, 0x656c00f0780f2, 0x656fc7285741a,
+    0x657055e4f551d, 0x6570a6699d40b, 0x65718cfcc3f86, 0x6571b8e8084c2,
+    0x657439645766d, 0x6574988cc0c6f, 0x6575f7e6e364f, 0x65772403e0f69,
+    0x65775ae9f65f4, 0x657869bf710e6, 0x65793a5cf59c3, 0x657bfcba2b348,
+    0x657c8b76c944b, 0x657e3403b318f, 0x65806ef62b59b, 0x658097387f512,
+    0x658151e061b51, 0x658459c76e275, 0x658609a638b43, 0x6586f78b40248,
+    0x6588e1f910766, 0x6589118d45267, 0x65892782e7505, 0x658939cf991de,
+    0x658ca487ff4c9, 0x658e929ebffac, 0x658f6e37159d8, 0x6590845e71054,
+    0x6591340b82544, 0x6594593a11a90, 0x65955219ea2e4, 0x659761211e1b4,
+    0x65979f59143c9, 0x6598cb7611ce3, 0x659952e0cf25c, 0x659c571eeb3bb,
+    0x659c7bb84ed6d, 0x659d32b740de7, 0x659de264522d7, 0x659e4535abe9e,
+    0x659faf8a9f9cd, 0x65a08ecbe59be, 0x65a18059dd688, 0x65a2634413c3e,
+    0x65a496e4ab4c0, 0x65a4bb7e0ee72, 0x65a4bf26ff437, 0x65a58c1b9374f,
+    0x65a5c6aa9939f, 0x65a5e0492bc02, 0x65a5eb43fcd51, 0x65a70c662951c,
+    0x65a7852d25381, 0x65a9943459251, 0x65acc0b4c9327, 0x65ad82ae8c4f0,
+    0x65aec86a1c66d, 0x65aef7fe5116e, 0x65b1c8ff48207, 0x65b50b755a57b,
+    0x65b58b8e36f6a, 0x65b6befd1540e, 0x65b9ad458f2cf, 0x65bb313915661,
+    0x65bbbff5b3764, 0x65bc1f1e1cd66, 0x65bd404049531, 0x65bd528cfb20a,
+    0x65beb938fe774, 0x65bf7edbb1f02, 0x65bf912863bdb, 0x65bffb4b9e32c,
+    0x65c0a74fbf257, 0x65c52486905f9, 0x65c63704fb6b0, 0x65c9881ecf138,
+    0x65cb63e8ddf42, 0x65cd3c09fc787, 0x65cfcb2a0d046, 0x65d0d2ada6fae,
+    0x65d118377dd4d, 0x65d12e2d1ffeb, 0x65d1b940cdb29, 0x65d4b62d090fe,
+    0x65d5747ddbd02, 0x65d5d3a645304, 0x65dc0f5fa2688, 0x65dcf5f2c9203,
+    0x65de1abde5f93, 0x65df6422666d5, 0x65e117aa21568, 0x65e3b56df353b,
+    0x65e51871064e0, 0x65e64f88d4f49, 0x65e88a7b4d355, 0x65e90e3d1a309,
+    0x65e9e62c7f770, 0x65ebe68ff1f2c, 0x65ed3aef437bd, 0x65ef42a496b03,
+    0x65efa1cd00105, 0x65efc66663ab7, 0x65f034328e7cd, 0x65f130bb575e6,
+    0x65f17d970ef0f, 0x65f18c3ad0623, 0x65f34e664cbca, 0x65f605c8b1400,
+    0x65f7b5a77bcce, 0x65fe8b18482a4, 0x6602dc63d510a, 0x6605e7f3d1df3,
+    0x6607e4ae53fea, 0x6608ec31edf52, 0x6609ae2bb111b, 0x660d39d48a7f3,
+    0x660ec8c2e1cd4, 0x66101d2233565, 0x6611b70b5bb95, 0x66173bc5c6e9f,
+    0x6617b0e3d273f, 0x6619d98998e72, 0x661c4f0b16ece, 0x661cf3bd5726f,
+    0x661d4441ff15d, 0x661dae65398ae, 0x661f18ba2d3dd, 0x66217beef9760,
+    0x6622e9ecdd854, 0x66257563fdb4e, 0x6625ac4a131d9, 0x662707fb455f4,
+    0x66276acc9f1bb, 0x6629b0b9e8716, 0x662c9f02625d7, 0x662d7a9ab8003,
+    0x662e02057557c, 0x662e43e65bd56, 0x662ec3ff38745, 0x66333d8d19522,
+    0x663486f199c64, 0x6635070a76653, 0x6636332773f6d, 0x663b1e2a70025,
+    0x663c7289c18b6, 0x663c972325268, 0x663d432746193, 0x663d7db64bde3,
+    0x663def2b670be, 0x664131a179432, 0x6641d9fca9d98, 0x6643e903ddc68,
+    0x6644398885b56, 0x6646956b7134f, 0x66475b0e24add, 0x66478aa2595de,
+    0x66488e7d02f81, 0x6648c5631860c, 0x66494924e55c0, 0x664b9a0cffc6a,
+    0x664c3ebf4000b, 0x664c4268305d0, 0x664c75a555696, 0x664cd876af25d,
+    0x6651978e66dd9, 0x6652f33f991f4, 0x665335207f9ce, 0x66537aaa5676d,
+    0x6654822df06d5, 0x6654947aa23ae, 0x66549bcc82f38, 0x665591036b1c7,
+    0x6655da363252b, 0x665714f6f1559, 0x6658d3797d53b, 0x66597482cd317,
+    0x665a835847e09, 0x665c1d4170439, 0x665c6dc618327, 0x665d8044833de,
+    0x665e87c81d346, 0x665eb00a712bd, 0x6664177d5979f, 0x66643fbfad716,
+    0x6665732e8bbba, 0x6669ae8476782, 0x666bc4dd8b1dc, 0x666c489f58190,
+    0x666c65e6dafb8, 0x666ed06d87ec5, 0x666fbaa99f005, 0x66745c79d3d59,
+    0x667572a12f3d5, 0x667776ad92156, 0x6677904c249b9, 0x667c09da05796,
+    0x667c6cab5f35d, 0x667ee22cdd3b9, 0x667f0a6f31330, 0x6681af84e3e8d,
+    0x6681c923766f0, 0x66850f4279029, 0x6685427f9e0ef, 0x6685c2987aade,
+    0x6686b07d821e3, 0x6687b8011c14b, 0x668884f5b0463, 0x668a4e730d594,
+    0x668c9809470b4, 0x668d73a19cae0, 0x668efee7039fc, 0x668f705c1ecd7,
+    0x668fb23d054b1, 0x6690a3cafd17b, 0x6694af8cb3242, 0x6695125e0ce09,
+    0x6695752f669d0, 0x6695afbe6c620, 0x6698c2a049e93, 0x669921c8b3495,
+    0x6699f26637d72, 0x669ce0aeb1c33, 0x669d7e0f1144a, 0x669dcaeac8d73,
+    0x669fae06b8707, 0x66a06505aa781, 0x66a070007b8d0, 0x66a19122a809b,
+    0x66a1c808bd726, 0x66a29ff822b8d, 0x66a2a74a03717, 0x66a465cc8f6f9,
+    0x66a54164e5125, 0x66a7b6e663181, 0x66a7ba8f53746, 0x66abeaea6d1bf,
+    0x66ac6b0349bae, 0x66ac84a1dc411, 0x66adeef6cff40, 0x66b17e4899bdd,
+    0x66b1c77b60f41, 0x66b577bd9dfcb, 0x66b723f3782d4, 0x66b77f72f1311,
+    0x66b7c8a5b8675, 0x66b869af08451, 0x66bcb3a8b472d, 0x66bd510913f44,
+    0x66bd6aa7a67a7, 0x66c0937f262b8, 0x66c09e79f7407, 0x66c35d2e3c7c7,
+    0x66c4aa3bad4ce, 0x66c5613a9f548, 0x66c564e38fb0d, 0x66c7b5cbaa1b7,
+    0x66c8532c099ce, 0x66c8b5fd63595, 0x66cb15893f353, 0x66cc5eedbfa95,
+    0x66cd1d3e92699, 0x66d13b4cfa439, 0x66d17984f064e, 0x66d1847fc179d,
+    0x66d1e3a82ad9f, 0x66d3ad2587ed0, 0x66d49b0a8f5d5, 0x66d567ff238ed,
+    0x66d6105a54253, 0x66d9c7ee71e67, 0x66dbaeb351dc0, 0x66dc70ad14f89,
+    0x66dd698ced7dd, 0x66dd957831d19, 0x66dfa47f65be9, 0x66dfb6cc178c2,
+    0x66dfdf0e6b839, 0x66e0667928db2, 0x66e16301f1bcb, 0x66e2d0ffd5cbf,
+    0x66e437abd9229, 0x66e4c6687732c, 0x66e5b0a48e46c, 0x66e5b7f66eff6,
+    0x66e5bb9f5f5bb, 0x66e5f62e6520b, 0x66e6c6cbe9ae8, 0x66e77678fafd8,
+    0x66e7b859e17b2, 0x66e7c6fda2ec6, 0x66e8a9e7d947c, 0x66e8d22a2d3f3,
+    0x66ee742c1b525, 0x66ef39cececb3, 0x66ef6d0bf3d79, 0x66ef91a55772b,
+    0x66f378cda9e40, 0x66f3a861de941, 0x66f471ad82694, 0x66f79e2df276a,
+    0x66f91726a79ad, 0x66fc2a0885220, 0x66fc524ad9197, 0x66fcf6fd19538,
+    0x66ff94c0eb50b, 0x6700e5774c7d7, 0x670365f39b982, 0x67055f052d5b4,
+    0x6707f9200efc2, 0x670c60613e0c6, 0x670cce2d68ddc, 0x670d643be7a69,
+    0x670dd959f3309, 0x670ecae7eafd3, 0x6713fb74bde2a, 0x6714200e217dc,
+    0x6719dbaea2171, 0x671b5fa228503, 0x671cb40179d94, 0x671fef25ab57e,
+    0x671ff2ce9bb43, 0x67219f0475e4c, 0x6721d5ea8b4d7, 0x67238d1b3692f,
+    0x67239f67e8608, 0x6725444be1d87, 0x67265e1c2d9c8, 0x672870cc51e5d,
+    0x67292b743449c, 0x672aa46ce96df, 0x672d756de0778, 0x672dd0ed597b5,
+    0x672f463d1e433, 0x672f8bc6f51d2, 0x6731937c48518, 0x67344e879d313,
+    0x673468262fb76, 0x67368d2305ce4, 0x6736981dd6e33, 0x67372a83654fb,
+    0x673b413fec711, 0x673c0a8b90464, 0x673c2f24f3e16, 0x673ecce8c5de9,
+    0x673fea6201fef, 0x6745fa301ae37, 0x6747264d18751, 0x67474e8f6c6c8,
+    0x674755e14d252, 0x6749529bcf449, 0x674bfb5a7256b, 0x674c1ff3d5f1d,
+    0x674c866e200a9, 0x674cb9ab4516f, 0x674d70aa371e9, 0x674f6d64b93e0,
+    0x675015bfe9d46, 0x6753ee447ad47, 0x675500c2e5dfe, 0x6755eafefcf3e,
+    0x675658cb27c54, 0x6756e035e51cd, 0x6756e3ded5792, 0x67570c2129709,
+    0x67578fe2f66bd, 0x675f18a9c4748, 0x6760cfda6fba0, 0x67652fc9be11a,
+    0x67663af648647, 0x676712e5adaae, 0x67687d3aa15dd, 0x676cfe1a62f44,
+    0x676d683d9d695, 0x676f3563ead8b, 0x676fc42088e8e, 0x6774ed5b7b15b,
+    0x677574c6386d4, 0x67762bc52a74e, 0x6778128a0a6a7, 0x6778c5e00c15c,
+    0x6778d0dadd2ab, 0x677925087575e, 0x6779c268d4f75, 0x677b8f8f2266b,
+    0x677c79cb397ab, 0x677cca4fe1699, 0x677d0887d78ae, 0x677e30fbe4c03,
+    0x677fa64ba9881, 0x6781b552dd751, 0x6782b1dba656a, 0x67849ff26704d,
+    0x6785bd6ba3253, 0x678636329f0b8, 0x6786de8dcfa1e, 0x67878e3ae0f0e,
+    0x6787ffaffc1e9, 0x6788871ab9762, 0x678a6a36a90f6, 0x678be32f5e339,
+    0x678c28b9350d8, 0x678cb775d31db, 0x6790ce325a3f1, 0x67911eb7022df,
+    0x6791d20d03d94, 0x6791d5b5f4359, 0x6795c7d917bbd, 0x6798868d5cf7d,
+    0x6798a02bef7e0, 0x679aa7e142b26, 0x679ad3cc87062, 0x679d210bb1147,
+    0x67a075ce75194, 0x67a142c3094ac, 0x67a1ace643bfd, 0x67a3e42fcba44,
+    0x67a408c92f3f6, 0x67a5597f906c2, 0x67a5ebe51ed8a, 0x67a6f368b8cf2,
+    0x67a7822556df5, 0x67a9ecac03d02, 0x67ae8ad348491, 0x67b0803be9afe,
+    0x67b112a1781c6, 0x67b14235accc7, 0x67b328fa8cc20, 0x67b38bcbe67e7,
+    0x67b434271714d, 0x67b4a1f341e63, 0x67b5295dff3dc, 0x67b52d06ef9a1,
+    0x67b5a224fb241, 0x67b74360043fb, 0x67b7b87e0fc9b, 0x67b801b0d6fff,
+    0x67b9476c6717c, 0x67b994481eaa5, 0x67b9fe6b591f6, 0x67bcb5cdbda2c,
+    0x67bd950f03a1d, 0x67bfd3aa6c3ee, 0x67bffbecc0365, 0x67c141a8504e2,
+    0x67c153f5021bb, 0x67c169eaa4459, 0x67c1f8a74255c, 0x67c4a50ed5c43,
+    0x67c758c849eb4, 0x67c77266dc717, 0x67c7e0330742d, 0x67c9a60773f99,
+    0x67c9bbfd16237, 0x67ccaa45900f8, 0x67cdfafbf13c4, 0x67d09516d2dd2,
+    0x67d27489d21a1, 0x67d3193c12542, 0x67d4edb4407c2, 0x67d5667b3c627,
+    0x67d5c94c961ee, 0x67d6fcbb74692, 0x67d7040d5521c, 0x67d9290a2b38a,
+    0x67d9a57a177b4, 0x67db9ae2b8e21, 0x67dcbc04e55ec, 0x67dd646015f52,
+    0x67dfdd8a84573, 0x67e498f94bb2a, 0x67e52f07ca7b7, 0x67e5af20a71a6,
+    0x67e6f885278e8, 0x67e79d3767c89, 0x67efce596667a, 0x67f16beb7f26f,
+    0x67f54f6ae13bf, 0x67f7cc3e3ffa5, 0x67f83db35b280, 0x67f9deee6443a,
+    0x68055d81462ee, 0x68074f40f7396, 0x680cf4ebd5a8d, 0x680d1d2e29a04,
+    0x680dbe37797e0, 0x680e2108d33a7, 0x680e9d78bf7d1, 0x680f004a19398,
+    0x680f3ad91efe8, 0x68144e1e6f017, 0x6814fa228ff42, 0x6816263f8d85c,
+    0x68169b5d990fc, 0x68177e47cf6b2, 0x6819e8ce7c5bf, 0x681c5e4ffa61b,
+    0x681d11a5fc0d0, 0x681dd39fbf299, 0x681de5ec70f72, 0x681e2b7647d11,
+    0x681f2ba8010ef, 0x682228943c6c4, 0x68261ab75ff28, 0x682759210f51b,
+    0x6827816363492, 0x682cfecbedc12, 0x682d5df457214, 0x68301556bba4a,
+    0x6830a06a69588, 0x6831c18c95d53, 0x68343365237ea, 0x683592bf461ca,
+    0x68360f2f325f4, 0x68371309dbf97, 0x683749eff1622, 0x6837847ef7272,
+    0x6838eb2afa7dc, 0x6839136d4e753, 0x683be46e457ec, 0x683d38cd9707d,
+    0x683d43c8681cc, 0x683fec870b2ee, 0x684009ce8e116, 0x68412e99aaea6,
+    0x6843e5fc0f6dc, 0x68442434058f1, 0x6844a0a3f1d1b, 0x6844af47b342f,
+    0x6844ed7fa9644, 0x684557a2e3d95, 0x6845ccc0ef635, 0x68464587eb49a,
+    0x684837479c542, 0x68484d3d3e7e0, 0x68493b2245ee5, 0x684f17b339c67,
+    0x68500cea21ef6, 0x68506fbb7babd, 0x68539fe4dc158, 0x6854b60c377d4,
+    0x6858455e01471, 0x685d42adaf202, 0x685d58a3514a0, 0x685deeb1d012d,
+    0x6860a26b4439e, 0x6861cadf516f3, 0x686368716a2e8, 0x686398059ede9,
+    0x6863a6a9604fd, 0x6863e4e156712, 0x6866c4860eebf, 0x686795239379c,
+    0x6869bdc959ecf, 0x686c961c31af2, 0x686d0b3a3d392, 0x686ed4b79a4c3,
+    0x686f6e6f09715, 0x68715533e966e, 0x6874d23901632, 0x6874e485b330b,
+    0x687560f59f735, 0x6875dd658bb5f, 0x68765d7e6854e, 0x6876e14035502,
+    0x68771bcf3b152, 0x6878394877358, 0x68797f04074d5, 0x6879aaef4ba11,
+    0x687da4644fdff, 0x687ec5867c5ca, 0x687fdbadd7c46, 0x68800b420c747,
+    0x68807cb727a22, 0x6881968773663, 0x6881f206ec6a0, 0x688379a362ff7,
+    0x688634aeb7df2, 0x6888bc7ce7b27, 0x688910aa7ffda, 0x68899f671e0dd,
+    0x688ce1dd30451, 0x688d2b0ff77b5, 0x688d82e68022d, 0x688de960ca3b9,
+    0x688e6d229736d, 0x688f66026fbc1, 0x6891c58e4b97f, 0x6894c62377519,
+    0x6896600c9fb49, 0x689680fd12f36, 0x6898f67e90f92, 0x689960a1cb6e3,
+    0x6899853b2f095, 0x6899d968c7548, 0x689c9bc5fcecd, 0x689d6c63817aa,
+    0x689d984ec5ce6, 0x68a0f80c5ae82, 0x68a1413f221e6, 0x68a186c8f8f85,
+    0x68a18a71e954a, 0x68a4b6f259620, 0x68a51d6ca37ac, 0x68a5cd19b4c9c,
+    0x68a5f904f91d8, 0x68a8304e8101f, 0x68a93f23fbb11, 0x68ab38358d743,
+    0x68ad8574b7828, 0x68adcafe8e5c7, 0x68ae64b5fd819, 0x68ae8cf851790,
+    0x68b0dde06be3a, 0x68b189e48cd65, 0x68b18d8d7d32a, 0x68b1e1bb157dd,
+    0x68b3ed19590e8, 0x68b46d3235ad7, 0x68b4782d06c26, 0x68b5d3de39041,
+    0x68b8ea6906e79, 0x68b90b597a266, 0x68b9f93e8196b, 0x68bc58ca5d729,
+    0x68bd97340cd1c, 0x68c3567d7dc76, 0x68c4fb61773f5, 0x68c5cbfefbcd2,
+    0x68c5ecef6f0bf, 0x68c682fdedd4c, 0x68c724073db28, 0x68c82b8ad7a90,
+    0x68c9edb654037, 0x68cc758483d6c, 0x68cd462208649, 0x68d04a60247a8,
+    0x68d0b82c4f4be, 0x68d3d9b1ee445, 0x68d56c4935eeb, 0x68d609a995702,
+    0x68d62e42f90b4, 0x68d694bd43240, 0x68d79fe9cd76d, 0x68d8b9ba193ae,
+    0x68d90de7b1861, 0x68d93d7be6362, 0x68dd19a967928, 0x68de0ee04fbb7,
+    0x68df66e891a0d, 0x68e12cbcfe579, 0x68e4a9c21653d, 0x68e4b113f70c7,
+    0x68e5d5df13e57, 0x68e67e3a447bd, 0x68e8c07e9d753, 0x68e9da4ee9394,
+    0x68e9e549ba4e3, 0x68eb82dbd30d8, 0x68ec0a4690651, 0x68ee53dcca171,
+    0x68ef456ac1e3b, 0x68ef6dad15db2, 0x68ef95ef69d29, 0x68f1b7434f8d2,
+    0x68f48f96274f5, 0x68f5b46144285, 0x68f8a2a9be146, 0x68f959a8b01c0,
+    0x68f9bc7a09d87, 0x68fc110b149f6, 0x68fdc83bbfe4e, 0x68fe44abac278,
+    0x68ffa7aebf21d, 0x6900a7e0785fb, 0x6904c245efdd6, 0x6905a18735dc7,
+    0x690d64dd09aa2, 0x690dc7ae63669, 0x690f987da1324, 0x6912af086f15c,
+    0x6913e2774d600, 0x691771c91729d, 0x6917c24dbf18b, 0x691821762878d,
+    0x691a00e927b5c, 0x691f0cdc97001, 0x69206c36b99e1, 0x6923f08db252f,
+    0x6924f0bf6b90d, 0x6925b6621f09b, 0x6926fc1daf218, 0x6927036f8fda2,
+    0x6927504b476cb, 0x69285b77d1bf8, 0x69296a4d4c6ea, 0x69298b3dbfad7,
+    0x692ac9a76f0ca, 0x692c0f62ff247, 0x692ceea445238, 0x692e5ca22932c,
+    0x692fbbfc4bd0c, 0x692ff2e261397, 0x6930c728d6239, 0x6931f6eec4118,
+    0x69341842a9cc1, 0x69346c7042174, 0x6939ed81bceb9, 0x693d792a96591,
+    0x6940a5ab06667, 0x6943fa6dca6b4, 0x6944adc3cc169, 0x6945686bae7a8,
+    0x6946fb02f624e, 0x694768cf20f64, 0x69479fb5365ef, 0x694c1cec07991,
+    0x694c2f38b966a, 0x694ed0a57bc02, 0x694f50be585f1, 0x694f5bb929740,
+    0x694f87a46dc7c, 0x694fafe6c1bf3, 0x69519302b1587, 0x695593c9964ff,
+    0x69574ea331f1c, 0x695918208f04d, 0x69594b5db4113, 0x695968a536f3b,
+    0x6959f00ff44b4, 0x695c066908f0e, 0x695c0dbae9a98, 0x695d830aae716,
+    0x695e2f0ecf641, 0x695f9d0cb3735, 0x6961afbcd7bca, 0x69629da1df2cf,
+    0x69651e1e2e47a, 0x6965f264a331c, 0x6967ef1f25513, 0x696c4b65834c8,
+    0x696c4f0e73a8d, 0x696d026475542, 0x696e7b5d2a785, 0x696f27614b6b0,
+    0x6970cfee353f4, 0x6970e23ae70cd, 0x69749d77f52a6, 0x6976d4c17d0ed,
+    0x6977cda155941, 0x6977f98c99e7d, 0x69785c5df3a44, 0x6979a96b6474b,
+    0x697be0b4ec592, 0x697bebafbd6e1, 0x697d4eb2d0686, 0x6980eca85ba37,
+    0x6983c4fb3365a, 0x6984ffbbf2688, 0x698933bffc6c6, 0x698c170da5438,
+    0x698c728d1e475, 0x698ff6e416fc3, 0x699081f7c4b01, 0x6991404897705,
+    0x6991eff5a8bf5, 0x6992ae467b7f9, 0x6992c7e50e05c, 0x6993f7aafbf3b,
+    0x699440ddc329f, 0x69958deb33fa6, 0x6996b65f412fb, 0x699720827ba4c,
+    0x69975768910d7, 0x69990af04bf6a, 0x6999f52c630aa, 0x699ab37d35cae,
+    0x699d2555c3745, 0x699e63bf72d38, 0x699fad23f347a, 0x69a008a36c4b7,
+    0x69a5a6fc6a024, 0x69a5f02f31388, 0x69a6236c5644e, 0x69a82eca99d59,
+    0x69a8919bf3920, 0x69a8cc2af9570, 0x69ad127bb5287, 0x69adf565eb83d,
+    0x69aef940951e0, 0x69b05fec9874a, 0x69b1b44be9fdb, 0x69b3b4af5c797,
+    0x69b44abddb424, 0x69b455b8ac573, 0x69b517b26f73c, 0x69b62687ea22e,
+    0x69b70d1b10da9, 0x69b8fb31d188c, 0x69b9704fdd12c, 0x69b99c3b21668,
+    0x69b9c82665ba4, 0x69bbaeeb45afd, 0x69bc11bc9f6c4, 0x69c74373c9c4f,
+    0x69c759696beed, 0x69c7bfe3b6079, 0x69ce362c1904d, 0x69cede87499b3,
+    0x69cf2b63012dc, 0x69d011f627e57, 0x69d1247492f0e, 0x69d1d421a43fe,
+    0x69d512eec61ad, 0x69d7677fd0e1c, 0x69d913b5ab125, 0x69d9593f81ec4,
+    0x69db8ce019746, 0x69dc05a7155ab, 0x69dc2de969522, 0x69dc7e6e11410,
+    0x69ddd67653266, 0x69ddda1f4382b, 0x69de47eb6e541, 0x69e0275e6d910,
+    0x69e1990541fc9, 0x69e38373124e7, 0x69e43320239d7, 0x69e6d48ce5f6f,
+    0x69e70f1bebbbf, 0x69ec977f4748e, 0x69ecebacdf941, 0x69ed3c318782f,
+    0x69ee6bf77570e, 0x69f0ae3bce6a4, 0x69f1394f7c1e2, 0x69f356fa717c6,
+    0x69f35aa361d8b, 0x69f3bd74bb952, 0x69f46226fbcf3, 0x69f5993eca75c,
+    0x69f6ba60f6f27, 0x69f8ac20a7fcf, 0x69f94d29f7dab, 0x69f9affb51972,
+    0x69f9cd42d479a, 0x69fe93ac6cea0, 0x69ffa9d3c851c, 0x6a0164ad63f39,
+    0x6a0c03feffdfc, 0x6a0c4d31c7160, 0x6a0cb00320d27, 0x6a105c9c6d7ec,
+    0x6a106ee91f4c5, 0x6a14b8e2cb7a1, 0x6a14e1251f718, 0x6a15e8a8b9680,
+    0x6a16f77e34172, 0x6a1afbee096af, 0x6a1cd0663792f, 0x6a1dfc8335249,
+    0x6a1ecd20b9b26, 0x6a1f423ec53c6, 0x6a1f757bea48c, 0x6a21a57391749,
+    0x6a2508da16eaa, 0x6a2543691cafa, 0x6a2697c86e38b, 0x6a274ec760405,
+    0x6a27cee03cdf4, 0x6a287ae45dd1f, 0x6a28c7c015648, 0x6a291f969e0c0,
+    0x6a294f2ad2bc1, 0x6a2ba3bbdd830, 0x6a2c2b269ada9, 0x6a2c98f2c5abf,
+    0x6a2e7c0eb5453, 0x6a2e95ad47cb6, 0x6a2ef87ea187d, 0x6a2f3e087861c,
+    0x6a31afe1060b3, 0x6a33f2255f049, 0x6a345c489979a, 0x6a34723e3ba38,
+    0x6a358c0e87679, 0x6a376086b58f9, 0x6a3893f593d9d, 0x6a38bfe0d82d9,
+    0x6a3e0a0c3d993, 0x6a3ff822fe476, 0x6a412f3accedf, 0x6a41c5494bb6c,
+    0x6a435437a304d, 0x6a489aba18142, 0x6a48fd8b71d09, 0x6a49ad38831f9,
+    0x6a4c05727e42d, 0x6a4d9bb2b6498, 0x6a4dadff68171, 0x6a4e2a6f5459b,
+    0x6a4f1bfd4c265, 0x6a4f3cedbf652, 0x6a4f4f3a7132b, 0x6a50b98f64e5a,
+    0x6a51bd6a0e7fd, 0x6a527f63d19c6, 0x6a58d4bbc15ad, 0x6a5b812354c94,
+    0x6a5f22c1d060a, 0x6a5f316591d1e, 0x6a617ea4bbe03, 0x6a61b1e1e0ec9,
+    0x6a6370646ceab, 0x6a65204337779, 0x6a65aeffd587c, 0x6a69c9654d057,
+    0x6a6c1a4d67701, 0x6a6c96bd53b2b, 0x6a6ca5611523f, 0x6a6ece06db972,
+    0x6a70c36f7cfdf, 0x6a716bcaad945, 0x6a7181c04fbe3, 0x6a71940d018bc,
+    0x6a742e27e32ca, 0x6a74775aaa62e, 0x6a74a6eedf12f, 0x6a74c43661f57,
+    0x6a76b99f035c4, 0x6a78622bed308, 0x6a78de9bd9732, 0x6a7be682e5e56,
+    0x6a7c1d68fb4e1, 0x6a7c335e9d77f, 0x6a7d2143a4e84, 0x6a7da8ae623fd,
+    0x6a7e6aa8255c6, 0x6a843fe7387be, 0x6a8473245d884, 0x6a85560e93e3a,
+    0x6a86d65929c07, 0x6a8768beb82cf, 0x6a87da33d35aa, 0x6a88b9751959b,
+    0x6a8d245f38c64, 0x6a8e3e2f848a5, 0x6a8fb72839ae8, 0x6a900efec2560,
+    0x6a9396feab673, 0x6a9425bb49776, 0x6a96819e34f6f, 0x6a97f6edf9bed,
+    0x6a98e87bf18b7, 0x6a98fe7193b55, 0x6a9d98efe7d1f, 0x6a9dbd894b6d1,
+    0x6a9de222af083, 0x6a9ded1d801d2, 0x6a9e4feed9d99, 0x6a9f1ce36e0b1,
+    0x6aa01d152748f, 0x6aa3c6058398f, 0x6aa3fceb9901a, 0x6aa960b590f37,
+    0x6aa9f6c40fbc4, 0x6aab4ecc51a1a, 0x6aac10c614be3, 0x6aaccb6df7222,
+    0x6aadfb33e5101, 0x6aae9fe6254a2, 0x6aaeb232d717b, 0x6aafcc0322dbc,
+    0x6ab09ca0a7699, 0x6ab48771ea373, 0x6ab6f1f897280, 0x6ab746262f733,
+    0x6ab7beed2b598, 0x6ab83b5d179c2, 0x6abba9be6e272, 0x6abcbc3cd9329,
+    0x6abea301b9282, 0x6abf0d24f39d3, 0x6abf440b0905e, 0x6abfc07af5488,
+    0x6ac1063685605, 0x6ac2b9be40498, 0x6ac3e9842e377, 0x6ac4b678c268f,
+    0x6ac54c874131c, 0x6ac5627ce35ba, 0x6ac7000efc1af, 0x6ac7334c21275,
+    0x6ac845ca8c32c, 0x6ac892a643c55, 0x6ac8f1cead257, 0x6acd30cd883e4,
+    0x6ace8c7eba7ff, 0x6ad16c2372fac, 0x6ad1aa5b691c1, 0x6ad3a715eb3b8,
+    0x6ad414e2160ce, 0x6ad42385d77e2, 0x6ad54fa2d50fc, 0x6ad5ff4fe65ec,
+    0x6ad657266f064, 0x6ad68a639412a, 0x6ad753af37e7d, 0x6ad7749fab26a,
+    0x6ad8ffe512186, 0x6ad91d2c94fae, 0x6ad95f0d7b788, 0x6ad9ba8cf47c5,
+    0x6adac5b97ecf2, 0x6adb806161331, 0x6adc5fa2a7322, 0x6add30402bbff,
+    0x6ade79a4ac341, 0x6adef26ba81a6, 0x6adf30a39e3bb, 0x6adfca5b0d60d,
+    0x6ae44b3acef74, 0x6ae4b1b519100, 0x6ae57005ebd04, 0x6ae5c433841b7,
+    0x6ae640a3705e1, 0x6ae761c59cdac, 0x6ae8918b8ac8b, 0x6ae97bc7a1dcb,
+    0x6ae98a6b634df, 0x6aea27cbc2cf6, 0x6aea5b08e7dbc, 0x6aeb48edef4c1,
+    0x6aec8b008f079, 0x6aecea28f867b, 0x6aee66ca9de83, 0x6aeeee355b3fc,
+    0x6af0f5eaae742, 0x6af14dc1371ba, 0x6af3dce147a79, 0x6af4e464e19e1,
+    0x6af681f6fa5d6, 0x6af8a6f3d0744, 0x6afb3d65c1b8d, 0x6afc0a5a55ea5,
+    0x6afc39ee8a9a6, 0x6afc7bcf71180, 0x6aff96032f57d, 0x6b04308183747,
+    0x6b05ad2328f4f, 0x6b05bbc6ea663, 0x6b06d1ee45cdf, 0x6b07470c5157f,
+    0x6b07fe0b435f9, 0x6b088cc7e16fc, 0x6b09526a94e8a, 0x6b0b26e2c310a,
+    0x6b0b442a45f32, 0x6b0cc474dbcff, 0x6b0dece8e9054, 0x6b13f56521312,
+    0x6b145bdf6b49e, 0x6b153025e0340, 0x6b17293771f72, 0x6b18e0681d3ca,
+    0x6b1f2e6e2c427, 0x6b24330fbad42, 0x6b250ea81076e, 0x6b25ac086ff85,
+    0x6b25d44ac3efc, 0x6b29b42135a87, 0x6b2c976ede7f9, 0x6b2ca9bb904d2,
+    0x6b2da644592eb, 0x6b2e265d35cda, 0x6b2f3c8491356, 0x6b3465bf83623,
+    0x6b356d431d58b, 0x6b36b2fead708, 0x6b37d420d9ed3, 0x6b37f8ba3d885,
+    0x6b3866866859b, 0x6b39c9897b540, 0x6b3ae359c7181, 0x6b3be38b8055f,
+    0x6b3cca1ea70da, 0x6b3f1eafb1d49, 0x6b40a9f518c65, 0x6b40c73c9ba8d,
+    0x6b40f327dffc9, 0x6b437af60fcfe, 0x6b4452e575165, 0x6b45c83539de3,
+    0x6b469180ddb36, 0x6b46f7fb27cc2, 0x6b47b2a30a301, 0x6b48206f35017,
+    0x6b4b9d744cfdb, 0x6b4df5ae4820f, 0x6b4e7d1905788, 0x6b50e3f6c20d0,
+    0x6b51bf8f17afc, 0x6b53caed5b407, 0x6b53fe2a804cd, 0x6b541b72032f5,
+    0x6b54ec0f87bd2, 0x6b54f3616875c, 0x6b55c3feed039, 0x6b5656647b701,
+    0x6b575a3f250a4, 0x6b578d7c4a16a, 0x6b58531efd8f8, 0x6b5af48bbfe90,
+    0x6b5ba438d1380, 0x6b5cccacde6d5, 0x6b5dc935a74ee, 0x6b6133ee0d7d9,
+    0x6b6175cef3fb3, 0x6b64fdcedd0c6, 0x6b650520bdc50, 0x6b66caf52a7bc,
+    0x6b68b90beb29f, 0x6b6baafd55725, 0x6b6baea645cea, 0x6b6cb629dfc52,
+    0x6b6dba04895f5, 0x6b6e7fa73cd83, 0x6b6ec1882355d, 0x6b6fa81b4a0d8,
+    0x6b6fb6bf0b7ec, 0x6b74bf098a6cc, 0x6b758bfe1e9e4, 0x6b75e02bb6e97,
+    0x6b76972aa8f11, 0x6b7955deee2d1, 0x6b79d9a0bb285, 0x6b7b9bcc3782c,
+    0x6b7cf77d69c47, 0x6b808a7823ea9, 0x6b81c8e1d349c, 0x6b84b3815cd98,
+    0x6b85423dfae9b, 0x6b854d38cbfea, 0x6b855f857dcc3, 0x6b856e293f3d7,
+    0x6b86426fb4279, 0x6b873ef87d092, 0x6b88c2ec03424, 0x6b89dcbc4f065,
+    0x6b8b29c9bfd6c, 0x6b8e3902ad01a, 0x6b8ec7bf4b11d, 0x6b918673904dd,
+    0x6b95b325b9991, 0x6b96c1fb34483, 0x6b98967362703, 0x6b98a51723e17,
+    0x6b98c60797204, 0x6b98dfa629a67, 0x6b9c6b4f0313f, 0x6b9d2d48c6308,
+    0x6b9da60fc216d, 0x6b9f683b3e714, 0x6b9faa1c24eee, 0x6b9fd9b0599ef,
+    0x6ba12e0fab280, 0x6ba1e8b78d8bf, 0x6ba36cab13c51, 0x6ba373fcf47db,
+    0x6ba45e390b91b, 0x6ba62b5f59011, 0x6ba75ece374b5, 0x6bacc9ea0ff5c,
+    0x6bad5c4f9e624, 0x6bb2c3c286b06, 0x6bb35d79f5d58, 0x6bb547e7c6276,
+    0x6bb5b95ce1551, 0x6bb82ede5f5ad, 0x6bb9a42e2422b, 0x6bbdc5e57c590,
+    0x6bbf0ba10c70d, 0x6bbf796d37423, 0x6bc0f9b7cd1f0, 0x6bc8653718453,
+    0x6bc977b58350a, 0x6bcca08d0301b, 0x6bd0cd3f2c4cf, 0x6bd17cec3d9bf,
+    0x6bd3f26dbba1b, 0x6bd4c30b402f8, 0x6bdb40a583e56, 0x6bdb52f235b2f,
+    0x6bdd0dcbd154c, 0x6bdda7834079e, 0x6bdee243ff7cc, 0x6bdf834d4f5a8,
+    0x6bdffc144b40d, 0x6be16dbb1fac6, 0x6be8766911162, 0x6be9a2860ea7c,
+    0x6be9d5c333b42, 0x6bec38f7ffec5, 0x6bec3ca0f048a, 0x6bed407b99e2d,
+    0x6bedc43d66de1, 0x6bef1fee991fc, 0x6bef7b6e12239, 0x6bef98b595061,
+    0x6bf445809af04, 0x6bf50b234e692, 0x6bf624f39a2d3, 0x6bf73ec3e5f14,
+    0x6bf89a751832f, 0x6bf8b7bc9b157, 0x6bfbb0ffe6167, 0x6bfc4e604597e,
+    0x6bfcd973f34bc, 0x6bfd314a7bf34, 0x6bfdfe3f1024c, 0x6bfe108bc1f25,
+    0x6bfecb33a4564, 0x6bfecedc94b29, 0x6c01ecb9434eb, 0x6c04623ac1547,
+    0x6c05243484710, 0x6c05d3e195c00, 0x6c0e7a219fe91, 0x6c0f4abf2476e,
+    0x6c11cee463ede, 0x6c197fed85ee0, 0x6c19e2bedfaa7, 0x6c1cf5a0bd31a,
+    0x6c1d28dde23e0, 0x6c1f9ab66fe77, 0x6c20ce254e31b, 0x6c24e88ac5af6,
+    0x6c25c7cc0bae7, 0x6c2794f2591dd, 0x6c2986b20a285, 0x6c299555cb999,
+    0x6c29e98363e4c, 0x6c2dc207f4e4d, 0x6c3030379231f, 0x6c32518b77ec8,
+    0x6c344a9d09afa, 0x6c34a27392572, 0x6c34fdf30b5af, 0x6c391faa63914,
+    0x6c3fedc94f360, 0x6c4057ec89ab1, 0x6c40e6a927bb4, 0x6c41497a8177b,
+    0x6c418409873cb, 0x6c4558e527e07, 0x6c45a969cfcf5, 0x6c45e04fe5380,
+    0x6c45f9ee77be3, 0x6c4638266ddf8, 0x6c47ce66a5e63, 0x6c48e836f1aa4,
+    0x6c4960fded909, 0x6c49e868aae82, 0x6c4ceca6c6fe1, 0x6c4da3a5b905b,
+    0x6c4e02ce2265d, 0x6c50784fa06b9, 0x6c541d970c5f4, 0x6c55426229384,
+    0x6c5554aedb05d, 0x6c555fa9ac1ac, 0x6c561dfa7edb0, 0x6c565fdb6558a,
+    0x6c56cda7902a0, 0x6c5796f333ff3, 0x6c57ca30590b9, 0x6c59f67f0fdb1,
+    0x6c5b9b6309530, 0x6c5c97ebd2349, 0x6c605328e0522, 0x6c60e1e57e625,
+    0x6c6177f3fd2b2, 0x6c618de99f550, 0x6c61c126c4616, 0x6c6536d9fba50,
+    0x6c6678ec9b608, 0x6c6c7dbfe3301, 0x6c714b7b5c591, 0x6c750e0a4b2f4,
+    0x6c77ea06134dc, 0x6c7999e4dddaa, 0x6c79f90d473ac, 0x6c7bd12e65bf1,
+    0x6c7c6e8ec5408, 0x6c7f72cce1567, 0x6c7fa26116068, 0x6c80645ad9231,
+    0x6c84826940fd1, 0x6c84aaab94f48, 0x6c86daa33c205, 0x6c87745aab457,
+    0x6c8cc22f010d6, 0x6c8cd824a3374, 0x6c8e96a72f356, 0x6c906ec84db9b,
+    0x6c90bf4cf5a89, 0x6c91ad31fd18e, 0x6c91d57451105, 0x6c94d6097cc9f,
+    0x6c959f55209f2, 0x6c97e54269f4d, 0x6c98272350727, 0x6c98c82ca0503,
+    0x6c9956e93e606, 0x6c9cc8f38547b, 0x6c9d490c61e6a, 0x6c9d714eb5de1,
+    0x6c9dba817d145, 0x6c9de66cc1681, 0x6c9eb36155999, 0x6c9f078eede4c,
+    0x6ca03754dbd2b, 0x6ca04d4a7dfc9, 0x6ca237b84e4e7, 0x6ca4c32f6e7e1,
+    0x6ca97e9e35d98, 0x6cabda8121591, 0x6cacff4c3e321, 0x6cad3289633e7,
+    0x6cadbd9d10f25, 0x6cade98855461, 0x6cb4cd9ce314b, 0x6cb521ca7b5fe,
+    0x6cb6b80ab3669, 0x6cb901a0ed189, 0x6cb9e48b2373f, 0x6cbc824ef5712,
+    0x6cbdd6ae46fa3, 0x6cbfa02ba40d4, 0x6cbfdabaa9d24, 0x6cc14c617e3dd,
+    0x6cc153b35ef67, 0x6cc45f435bc50, 0x6cc5e336e1fe2, 0x6cc6dfbfaadfb,
+    0x6cc6e3689b3c0, 0x6cc733ed432ae, 0x6cc7a5625e589, 0x6cc88f9e756c9,
+    0x6cc9a96ec130a, 0x6ccc81c198f2d, 0x6ccccaf460291, 0x6cce6134982fc,
+    0x6ccf357b0d19e, 0x6cd1cbecfe5e7, 0x6cd2f809fbf01, 0x6cd3c1559fc54,
+    0x6cd4416e7c643, 0x6cd75f4b2b005, 0x6cd7a12c117df, 0x6cd7f9029a257,
+    0x6cdc6b3e9a4aa, 0x6cdd9b0488389, 0x6cddb0fa2a627, 0x6cdf7a7787758,
+    0x6cdfcea51fc0b, 0x6ce1f3a1f5d79, 0x6ce58a45a05a0, 0x6ce5de7338a53,
+    0x6ce5ed16fa167, 0x6ce8c1c0e17c5, 0x6ce92be41bf16, 0x6cebd4a2bf038,
+    0x6cebf1ea41e60, 0x6cec969c82201, 0x6ceceaca1a6b4, 0x6cef9731add9b,
+    0x6cf025ee4be9e, 0x6cf1819f7e2b9, 0x6cf55a240f2ba, 0x6cf6da6ea5087,
+    0x6cfdac3681098, 0x6cfdd821c55d4, 0x6cffe728f94a4, 0x6d0075e5975a7,
+    0x6d00e3b1c22bd, 0x6d047dfe5d0a9, 0x6d04e478a7235, 0x6d04ef7378384,
+    0x6d0601f1e343b, 0x6d071f6b1f641, 0x6d072a65f0790, 0x6d07ec5fb3959,
+    0x6d0c0a6e1b6f9, 0x6d0d27e7578ff, 0x6d1071af4a7fd, 0x6d14d5478933c,
+    0x6d1755c3d84e7, 0x6d1dab1bc80ce, 0x6d1ee985776c1, 0x6d1fa0846973b,
+    0x6d2183a0590cf, 0x6d243b02bd905, 0x6d24b3c9b976a, 0x6d270108e384f,
+    0x6d2725a247201, 0x6d279e6943066, 0x6d2de51d71539, 0x6d2e1c0386bc4,
+    0x6d2e912192464, 0x6d2f322ae2240, 0x6d31537ec7de9, 0x6d31955fae5c3,
+    0x6d3383766f0a6, 0x6d35c963b8601, 0x6d36d0e752569, 0x6d36dbe2236b8,
+    0x6d389a64af69a, 0x6d394dbab114f, 0x6d3c72e94069b, 0x6d3cbfc4f7fc4,
+    0x6d3d08f7bf328, 0x6d3e8942550f5, 0x6d4039211f9c3, 0x6d4907a37dbcb,
+    0x6d49f588852d0, 0x6d4bc657c2f8b, 0x6d4cb43cca690, 0x6d4d9acff120b,
+    0x6d4dce0d162d1, 0x6d4e5920c3e0f, 0x6d5001adadb53, 0x6d53407acf902,
+    0x6d54828d6f4ba, 0x6d56dac76a6ee, 0x6d58a444c781f, 0x6d58c18c4a647,
+    0x6d591962d30bf, 0x6d59fc4d09675, 0x6d5bdf68f9009, 0x6d5d252489186,
+    0x6d6108a3eb2d6, 0x6d65cb6493417, 0x6d666c6de31f3, 0x6d68f43c12f28,
+    0x6d69152c86315, 0x6d6ba0a3a660f, 0x6d6cb322116c6, 0x6d6dccf25d307,
+    0x6d74642b336c8, 0x6d74b4afdb5b6, 0x6d7647472305c, 0x6d76b8bc3e337,
+    0x6d777ab601500, 0x6d7cf4759b6bb, 0x6d7d2409d01bc, 0x6d7e69c560339,
+    0x6d7f6da009cdc, 0x6d80d44c0d246, 0x6d8150bbf9670, 0x6d83218b3732b,
+    0x6d841a6b0fb7f, 0x6d87c35b6c07f, 0x6d87e44bdf46c, 0x6d89efaa22d77,
+    0x6d8c443b2d9e6, 0x6d8db98af2664, 0x6d8dc485c37b3, 0x6d8e5342618b6,
+    0x6d8f23dfe6193, 0x6d8f9ca6e1ff8, 0x6d910e4db66b1, 0x6d91a80525903,
+    0x6d91c1a3b8166, 0x6d93bab549d98, 0x6d94212f93f24, 0x6d953ea8d012a,
+    0x6d96fd2b5c10c, 0x6d98259f69461, 0x6d9984f98be41, 0x6d9c9b8459c79,
+    0x6d9dd9ee0926c, 0x6da14fa1406a6, 0x6da1d3630d65a, 0x6da3ffb1c4352,
+    0x6da4453b9b0f1, 0x6da4a464046f3, 0x6da5038c6dcf5, 0x6da9c9f6063fb,
+    0x6da9e73d89223, 0x6dab7282f013f, 0x6dadfdfa10439, 0x6dae597989476,
+    0x6dae81bbdd3ed, 0x6daee48d36fb4, 0x6daf4b0781140, 0x6daf7e44a6206,
+    0x6db64c6391c52, 0x6db6d7773f790, 0x6db7c9053745a, 0x6db82f7f815e6,
+    0x6db8a8467d44b, 0x6dbdeec8f2540, 0x6dc06b9c51126, 0x6dc49fa05b164,
+    0x6dc57ee1a1155, 0x6dc6482d44ea8, 0x6dc68a0e2b682, 0x6dc8fbe6b9119,
+    0x6dcd6a79c8da7, 0x6dcdc5f941de4, 0x6dcf080be199c, 0x6dd0d1893eacd,
+    0x6dd0eb27d1330, 0x6dd52a26ac4bd, 0x6dd710eb8c416, 0x6dd7dde02072e,
+    0x6dd877978f980, 0x6dd9de4392eea, 0x6ddbdea7056a6, 0x6ddc48ca3fdf7,
+    0x6ddcc53a2c221, 0x6ddf4d085bf56, 0x6de15fb8803eb, 0x6de1d4d68bc8b,
+    0x6de358ca1201d, 0x6de41371f465c, 0x6de70cb53f66c, 0x6de74744452bc,
+    0x6de7fa9a46d71, 0x6dec8a1dc9dec, 0x6df1a10c0a3e0, 0x6df47d07d25c8,
+    0x6df580e27bf6b, 0x6df69ab2c7bac, 0x6df7c6cfc54c6, 0x6df934cda95ba,
+    0x6df9b4e685fa9, 0x6dfea39272626, 0x6dffe1fc21c19, 0x6e008305719f5,
+    0x6e01992ccd071, 0x6e044ce6412e2, 0x6e055bbbbbdd4, 0x6e084dad2625a,
+    0x6e09b80219d89, 0x6e09d1a0ac5ec, 0x6e0a3f6cd7302, 0x6e0c6f647e5bf,
+    0x6e0ce0d99989a, 0x6e0e56295e518, 0x6e0fd673f42e5, 0x6e10cbaadc574,
+    0x6e11272a555b1, 0x6e171607fb00c, 0x6e17dbaaae79a, 0x6e183e7c08361,
+    0x6e1875621d9ec, 0x6e199a2d3a77c, 0x6e1c010af70c4, 0x6e1d46c687241,
+    0x6e1fdd387868a, 0x6e2017c77e2da, 0x6e2373dc22eb1, 0x6e270e28bdc9d,
+    0x6e28b6b5a79e1, 0x6e29b33e707fa, 0x6e2c16733cb7d, 0x6e2da1b8a3a99,
+    0x6e2fbf639907d, 0x6e31ad7a59b60, 0x6e3326730eda3, 0x6e3438f179e5a,
+    0x6e34897621d48, 0x6e34c7ae17f5d, 0x6e359f9d7d3c4, 0x6e37482a67108,
+    0x6e37706cbb07f, 0x6e38e5bc7fcfd, 0x6e3cabf45f025, 0x6e3d8b35a5016,
+    0x6e3e214423ca3, 0x6e41c2e29f619, 0x6e438c5ffc74a, 0x6e45e0f1073b9,
+    0x6e46fac152ffa, 0x6e498d8a53e7e, 0x6e4c3648f6fa0, 0x6e53cdb38673f,
+    0x6e542932ff77c, 0x6e547d6097c2f, 0x6e54ca3c4f558, 0x6e598cfcf7699,
+    0x6e5bf783a45a6, 0x6e5c68f8bf881, 0x6e5d1c4ec1336, 0x6e5d82c90b4c2,
+    0x6e5decec45c13, 0x6e5f0e0e723de, 0x6e5fd008355a7, 0x6e61196cb5ce9,
+    0x6e61f8adfbcda, 0x6e64e34d855d6, 0x6e66accae2707, 0x6e67468251959,
+    0x6e67eedd822bf, 0x6e68c323f7161, 0x6e6cd9e07e377, 0x6e6cf7280119f,
+    0x6e702afa51dff, 0x6e72cc6714397, 0x6e74037ee2e00, 0x6e78a54f17b54,
+    0x6e7921bf03f7e, 0x6e7958a519609, 0x6e7c0c5e8d87a, 0x6e7c593a451a3,
+    0x6e7e3fff250fc, 0x6e7e60ef984e9, 0x6e7eb8c620f61, 0x6e809f8b00eba,
+    0x6e80b9299371d, 0x6e84487b5d3ba, 0x6e8486b3535cf, 0x6e84b9f078695,
+    0x6e8f67e5d5c6c, 0x6e9117c4a053a, 0x6e937ea25ce82, 0x6e940d5efaf85,
+    0x6e944f3fe175f, 0x6e9677e5a7e92, 0x6e996d80028dd, 0x6e9b66919450f,
+    0x6e9bb7163c3fd, 0x6e9c50cdab64f, 0x6e9ccd3d97a79, 0x6e9fb43430db0,
+    0x6ea0d1ad6cfb6, 0x6ea4057fbdc16, 0x6ea543e96d209, 0x6ea559df0f4a7,
+    0x6ea7aac729b51, 0x6ea7e1ad3f1dc, 0x6ea7f7a2e147a, 0x6eaf07a2b36a0,
+    0x6eaf8069af505, 0x6eb0bb2a6e533, 0x6eb24a18c5a14, 0x6eb57deb16674,
+    0x6eb5b87a1c2c4, 0x6eb5f6b2124d9, 0x6eb7351bc1acc, 0x6eb77e4e88e30,
+    0x6eb9566fa7675, 0x6eb989accc73b, 0x6eb9c43bd238b, 0x6ebc98e5b99e9,
+    0x6ebd0e03c5289, 0x6ebdd74f68fdc, 0x6ebed02f41830, 0x6ebf48f63d695,
+    0x6ec4ca07b83da, 0x6ec67d8f7326d, 0x6ec6ad23a7d6e, 0x6eccc7ec91d05,
+    0x6ecd1fc31a77d, 0x6ece866f1dce7, 0x6ecf7ba605f76, 0x6ecfd7257efb3,
+    0x6ed027aa26ea1, 0x6ed053956b3dd, 0x6ed1af469d7f8, 0x6ed36a2039215,
+    0x6ed47c9ea42cc, 0x6ed737a9f90c7, 0x6ed858cc25892, 0x6ed9c321193c1,
+    0x6edcb8bb73e0c, 0x6ededdb849f7a, 0x6edf2e3cf1e68, 0x6ee1483ef6e87,
+    0x6ee3957e20f6c, 0x6ee5b32916550, 0x6ee678cbc9cde, 0x6ee76307e0e1e,
+    0x6ee7a4e8c75f8, 0x6ee9a1a3497ef, 0x6ee9dc324f43f, 0x6eec79f621412,
+    0x6eed7a27da7f0, 0x6eee2d7ddc2a5, 0x6eeea29be7b45, 0x6eef35017620d,
+    0x6ef164f91d4ca, 0x6ef1c7ca77091, 0x6ef4ab181fe03, 0x6ef53282dd37c,
+    0x6ef5c13f7b47f, 0x6ef6bdc844298, 0x6ef74c84e239b, 0x6ef769cc651c3,
+    0x6ef7920eb913a, 0x6ef7d3ef9f914, 0x6efc07f3a9952, 0x6efd4daf39acf,
+    0x6f00ce5d42058, 0x6f01d5e0dbfc0, 0x6f044b625a01c, 0x6f04aa8ac361e,
+    0x6f088a61351a9, 0x6f0aed960152c, 0x6f0c24adcff95, 0x6f0d2c3169efd,
+    0x6f0f8f6636280, 0x6f0ff5e08040c, 0x6f104665282fa, 0x6f11f9ece318d,
+    0x6f12ca8a67a6a, 0x6f1610a96a3a3, 0x6f1773ac7d348, 0x6f1aed08a4d47,
+    0x6f1bc14f19be9, 0x6f1c0e2ad1512, 0x6f1c784e0bc63, 0x6f1cf866e8652,
+    0x6f1e3e22787cf, 0x6f2087b8b22ef, 0x6f219a371d3a6, 0x6f21c2797131d,
+    0x6f2425ae3d6a0, 0x6f24b0c1eb1de, 0x6f25850860080, 0x6f26cac3f01fd,
+    0x6f26f6af34739, 0x6f2776c811128, 0x6f278cbdb33c6, 0x6f27fe32ce6a1,
+    0x6f29c7b02b7d2, 0x6f2c064b941a3, 0x6f2ce935ca759, 0x6f33d49c38fcd,
+    0x6f3400877d509, 0x6f34585e05f81, 0x6f3492ed0bbd1, 0x6f364dc6a75ee,
+    0x6f36c2e4b2e8e, 0x6f3704c599668, 0x6f383bdd680d1, 0x6f38d594d7323,
+    0x6f3c947ad5ac1, 0x6f3d1be59303a, 0x6f3eb225cb0a5, 0x6f3eb977abc2f,
+    0x6f3ecbc45d908, 0x6f475113f47ac, 0x6f476360a6485, 0x6f47c2890fa87,
+    0x6f4c9796698a1, 0x6f507ebebbfb6, 0x6f51f0659066f, 0x6f55ed8385022,
+    0x6f58ac37ca3e2, 0x6f5c5c7a0746c, 0x6f5d84ee147c1, 0x6f5e6f2a2b901,
+    0x6f606f8d9e0bd, 0x6f60e102b9398, 0x6f61477d03524, 0x6f654843e849c,
+    0x6f68159beef70, 0x6f6ac955631e1, 0x6f6d2c8a2f564, 0x6f6d3785006b3,
+    0x6f6d80b7c7a17, 0x6f6e0bcb75555, 0x6f6fa5b49db85, 0x6f7193cb5e668,
+    0x6f71bc0db25df, 0x6f7405a3ec0ff, 0x6f7422eb6ef27, 0x6f74687545cc6,
+    0x6f74c79daf2c8, 0x6f7564fe0eadf, 0x6f76cbaa12049, 0x6f7babb23cfb2,
+    0x6f7ca0e925241, 0x6f7d210201c30, 0x6f7d494455ba7, 0x6f7ed0e0cc4fe,
+    0x6f7eee284f326, 0x6f7f45fed7d9e, 0x6f7f584b89a77, 0x6f7fe35f375b5,
+    0x6f81a58ab3b5c, 0x6f81b42e75270, 0x6f81bf29463bf, 0x6f8434aac441b,
+    0x6f858cb306271, 0x6f891fadc04d3, 0x6f8986280a65f, 0x6f89eca2547eb,
+    0x6f8c15481af1e, 0x6f8eed9af2b41, 0x6f943b6f487c0, 0x6f9472555de4b,
+    0x6f9484a20fb24, 0x6f95b467fda03, 0x6f9701756e70a, 0x6f9c14babe739,
+    0x6f9f697d82786, 0x6f9f9cbaa784c, 0x6fa7b7e703f9f, 0x6fac9f410fa92,
+    0x6facade4d11a6, 0x6fad773074ef9, 0x6fad9f72c8e70, 0x6faf73eaf70f0,
+    0x6fafc46f9efde, 0x6fb1d71fc3473, 0x6fb4a820ba50c, 0x6fb5074923b0e,
+    0x6fb666a3464ee, 0x6fbe64881fe19, 0x6fbed5fd3b0f4, 0x6fc2ae81cc0f5,
+    0x6fc3a00fc3dbf, 0x6fc4d37ea2263, 0x6fc627ddf3af4, 0x6fc678629b9e2,
+    0x6fc8a10862115, 0x6fcd1e3f334b7, 0x6fcd293a04606, 0x6fcd58ce39107,
+    0x6fd405fcb1766, 0x6fd47ec3ad5cb, 0x6fd4b200d2691, 0x6fd4f78aa9430,
+    0x6fd5f06a81c84, 0x6fd8833382b08, 0x6fdbd44d56590, 0x6fdc1d801d8f4,
+    0x6fdcf1c692796, 0x6fdd8f26f1fad, 0x6fdf21be39a53, 0x6fe5b1a52f28a,
+    0x6fe7861d5d50a, 0x6fe839735efbf, 0x6fe9c10fd5916, 0x6febb67876f83,
+    0x6febd3bff9dab, 0x6fec502fe61d5, 0x6feca0b48e0c3, 0x6feca45d7e688,
+    0x6fecabaf5f212, 0x6fef66bab400d, 0x6ff3bbaf31438, 0x6ff44a6bcf53b,
+    0x6ff48c4cb5d15, 0x6ff4ca84abf2a, 0x6ff4f66ff0466, 0x6ff567e50b741,
+    0x6ff63130af494, 0x6ff6dd34d03bf, 0x6ffc116a937db, 0x6ffc39ace7752,
+    0x6ffd02f88b4a5, 0x6ffdaefcac3d0, 0x6fffabb72e5c7, 0x700546673bb6f,
+    0x7005ace185cfb, 0x70061704c044c, 0x70094e8001671, 0x700deca745e00,
+    0x700e3d2bedcee, 0x700e77baf393e, 0x701106db041fd, 0x701153b6bbb26,
+    0x701445a825fac, 0x701450a2f70fb, 0x701480372bbfc, 0x7016dfc3079ba,
+    0x70175c32f3de4, 0x7018c2def734e, 0x7019bf67c0167, 0x701f94a6d335f,
+    0x701ffb211d4eb, 0x702406e2d35b2, 0x7024a7ec2338e, 0x70267c645160e,
+    0x702757fca703a, 0x70281d9f5a7c8, 0x70289db8371b7, 0x7028e6eafe51b,
+    0x702980a26d76d, 0x7029f969695d2, 0x702c6b41f7069, 0x702d25e9d96a8,
+    0x702de43aac2ac, 0x702f81ccc4ea1, 0x702fe0f52e4a3, 0x7030526a4977e,
+    0x703097f42051d, 0x7034d34a0b0e5, 0x70352777a3598, 0x703632a42dac5,
+    0x7036a7c239365, 0x70398b0fe20d7, 0x703cdc29b5b5f, 0x703d29056d488,
+    0x703e810daf2de, 0x703ffa0664521, 0x704689ed59d58, 0x7047cfa8e9ed5,
+    0x70485abc97a13, 0x7048e97935b16, 0x704939fddda04, 0x704dd47c31bce,
+    0x704e8429430be, 0x704ef59e5e399, 0x704f4d74e6e11, 0x705497a04c4cb,
+    0x70555d42ffc59, 0x70565d74b9037, 0x70567abc3be5f, 0x705785e8c638c,
+    0x705847e289555, 0x7059e1cbb1b85, 0x705b7f5dca77a, 0x705d1946f2daa,
+    0x705d8abc0e085, 0x70611a0dd7d22, 0x70613ea73b6d4, 0x70641e4bf3e81,
+    0x7065e7c950fb2, 0x7066f2f5db4df, 0x70674e755451c, 0x70678cad4a731,
+    0x706c32266fa4a, 0x706ce92561ac4, 0x706d0dbec5476, 0x706d5e436d364,
+    0x706da02453b3e, 0x706daec815252, 0x706f6d4aa1234, 0x7073ac497c3c1,
+    0x7073fcce242af, 0x7075dfea13c43, 0x707db58c995f7, 0x70802b0e17653,
+    0x708039b1d8d67, 0x7081d743f195c, 0x7083e9f415df1, 0x7084edcebf794,
+    0x7085076d51ff7, 0x708792e4722f1, 0x708d0ca40c4ac, 0x708eefbffbe40,
+    0x708fc40670ce2, 0x7091a72260676, 0x70931177541a5, 0x7093f4618a75b,
+    0x70942ef0903ab, 0x709883e50d7d6, 0x7098fcac0963b, 0x709907a6da78a,
+    0x709924ee5d5b2, 0x709c4dc5dd0c3, 0x709c63bb7f361, 0x709ed93cfd3bd,
+    0x709f55ace97e7, 0x709f7def3d75e, 0x70a0e0f250703, 0x70a335835b372,
+    0x70a3a34f86088, 0x70a56ccce31b9, 0x70a61c79f46a9, 0x70a732a14fd25,
+    0x70a78325f7c13, 0x70a7ba0c0d29e, 0x70a8033ed4602, 0x70ab62fc6979e,
+    0x70abd81a7503e, 0x70ac12a97ac8e, 0x70ac2c480d4f1, 0x70b38ccc87605,
+    0x70b4f7217b134, 0x70b53cab51ed3, 0x70b87426930f8, 0x70b89517064e5,
+    0x70bbc54066b80, 0x70be71a7fa267, 0x70c00f3a12e5c, 0x70c03b2557398,
+    0x70c1514cb2a14, 0x70c1a9233b48c, 0x70c2fd828cd1d, 0x70c42d487abfc,
+    0x70c4558aceb73, 0x70c7f7294a4e9, 0x70c8d2c19ff15, 0x70cd4ff8712b7,
+    0x70cd6d3ff40df, 0x70d0ededfc668, 0x70d713b1b774e, 0x70d7768311315,
+    0x70d8c739725e1, 0x70db4b5eb1d51, 0x70dc9fbe035e2, 0x70dca366f3ba7,
+    0x70dd237fd0596, 0x70dd40c7533be, 0x70df5ac9583dd, 0x70e195bbd07e9,
+    0x70e1f4e439deb, 0x70e4c98e21449, 0x70e7cdcc3d5a8, 0x70e8d1a6e6f4b,
+    0x70e9088cfc5d6, 0x70ed6c253b115, 0x70ed896cbdf3d, 0x70ee566152255,
+    0x70f48e71bf014, 0x70f51d2e5d117, 0x70f79daaac2c2, 0x70fcef27f2506,
+    0x70fdd5bb19081, 0x70fdd96409646, 0x70feb4fc5f072, 0x70feebe2746fd,
+    0x71011f830bf7f, 0x71017007b3e6d, 0x7101b93a7b1d1, 0x71035a758438b,
+    0x7104276a186a3, 0x7104ab2be5657, 0x7105ed3e8520f, 0x7107c1b6b348f,
+    0x7107ffeea96a4, 0x71083a7daf2f4, 0x7108e681d021f, 0x710ad0efa073d,
+    0x710d3f1f3dc0f, 0x710fa5fcfa557, 0x7111aa095d2d8, 0x7111ebea43ab2,
+    0x71137386ba409, 0x71142a85ac483, 0x7115e90838465, 0x711995a184f2a,
+    0x711c719d4d112, 0x711cc5cae55c5, 0x711cea6448f77, 0x71206017803b1,
+    0x71213806e5818, 0x71219e812f9a4, 0x71237a4b3e7ae, 0x712455e3941da,
+    0x71248920b92a0, 0x71275678bfd74, 0x7127d2e8ac19e, 0x7127e8de4e43c,
+    0x7128445dc7479, 0x712870490b9b5, 0x712ae2219944c, 0x712ba7c44cbda,
+    0x712bd3af91116, 0x712c920063d1a, 0x7133a5a926505, 0x7134d1c623e1f,
+    0x71385d6efd4f7, 0x7139982fbc525, 0x713c2ea1ad96e, 0x713c7f265585c,
+    0x713f41838b1e1, 0x7141801ef3bb2, 0x7141d0a39baa0, 0x714396780860c,
+    0x71444d76fa686, 0x7149bfe4b3cb7, 0x7149cadf84e06, 0x714d234b39418,
+    0x714f743353ac2, 0x714fcc09dc53a, 0x71516d44e56f4, 0x7154075fc7102,
+    0x7155a89ad02bc, 0x715b5ce9700c7, 0x715ba27346e66, 0x715c3881c5af3,
+    0x715c76b9bbd08, 0x715fa33a2bdde, 0x716370c3ebc90, 0x716515a7e540f,
+    0x7166999b6b7a1, 0x716819e60156e, 0x71685475071be, 0x716880604b6fa,
+    0x7168cd3c03023, 0x716bca283e5f8, 0x716c812730672, 0x716c8879111fc,
+    0x716d7a0708ec6, 0x716d8ffcab164, 0x7173c4642795e, 0x7174273581525,
+    0x7174de347359f, 0x717523be4a33e, 0x7176363cb53f5, 0x717741693f922,
+    0x717769ab93899, 0x7177cc7ced460, 0x7179be3c9e508, 0x7179c58e7f092,
+    0x717b37355374b, 0x717c12cda9177, 0x717c7947f3303, 0x717d4d8e681a5,
+    0x717dd4f92571e, 0x7181bfca683f8, 0x718338c31d63b, 0x7184b90db3408,
+    0x718632066864b, 0x71871feb6fd50, 0x7187a7562d2c9, 0x718818cb485a4,
+    0x718877f3b1ba6, 0x7188e968cce81, 0x7188ff5e6f11f, 0x7189fbe737f38,
+    0x718e791e092da, 0x718e96658c102, 0x718f87f383dcc, 0x71906addba382,
+    0x7194052a5516e, 0x7196ec20ee4a5, 0x7197fe9f5955c, 0x71991c1895762,
+    0x719c782d3a339, 0x719dab9c187dd, 0x719e1968434f3, 0x719f9260f8736,
+    0x71a119fd6f08d, 0x71a3fd4b17dff, 0x71a41e3b8b1ec, 0x71a4a5a648765,
+    0x71a834f812402, 0x71ae695f8ebfc, 0x71aebd8d270af, 0x71aecc30e87c3,
+    0x71aff0fc05553, 0x71b057764f6df, 0x71b7293e2b6f0, 0x71b7e03d1d76a,
+    0x71b9ef445163a, 0x71bd440715687, 0x71be2e432c7c7, 0x71beb5ade9d40,
+    0x71beda474d6f2, 0x71bf35c6c672f, 0x71c0f44952711, 0x71c1e22e59e16,
+    0x71c43a685504a, 0x71c4f167470c4, 0x71c51257ba4b1, 0x71c71db5fddbc,
+    0x71c73002afa95, 0x71c98493ba704, 0x71caf9e37f382, 0x71ccbc0efb929,
+    0x71ce22bafee93, 0x71ceefaf931ab, 0x71d0c427c142b, 0x71d1568d4faf3,
+    0x71d8afbfe907d, 0x71d9ee2998670, 0x71dc0f7d7e219, 0x71dd010b75ee3,
+    0x71dd294dc9e5a, 0x71dea2467f09d, 0x71dffdf7b14b8, 0x71e0681aebc09,
+    0x71e409b96757f, 0x71e57b603bc38, 0x71e739e2c7c1a, 0x71e8a7e0abd0e,
+    0x71e932f45984c, 0x71e9d3fda9628, 0x71f0812c21c87, 0x71f43c692fe60,
+    0x71f4a68c6a5b1, 0x71fbe620712d8, 0x71fcc561b72c9, 0x71fd800999908,
+    0x71fdf17eb4be3, 0x71fed468eb199, 0x71ff5481c7b88, 0x720175d5ad731,
+    0x7203eb572b78d, 0x72042d3811f67, 0x72056ba1c155a, 0x72057dee73233,
+    0x7205ad82a7d34, 0x7207fe6ac23de, 0x72083ca2b85f3, 0x720894794106b,
+    0x7209825e48770, 0x720e660f63c9e, 0x72102491efc80, 0x72105f20f58d0,
+    0x7211928fd3d74, 0x7214882a2e7bf, 0x7214acc392171, 0x72150bebfb773,
+    0x7215d5379f4c6, 0x72160122e3a02, 0x7217558235293, 0x7218fe0f1efd7,
+    0x721960e078b9e, 0x721d4808cb2b3, 0x722070e04adc4, 0x7221af49fa3b7,
+    0x7223f18e5334d, 0x72254d3f85768, 0x7225ac67eed6a, 0x7225f943a6693,
+    0x7228552691e8c, 0x7229973931a44, 0x722c5d3f5798e, 0x722d90ae35e32,
+    0x722ee164970fe, 0x723386ddbc417, 0x7234db3d0dca8, 0x7234e9e0cf3bc,
+    0x723557acfa0d2, 0x7237c98587b69, 0x7238cd603150c, 0x723beee5d0493,
+    0x723c5cb1fb1a9, 0x723cad36a3097, 0x723da26d8b326, 0x723f2a0a01c7d,
+    0x724035368c1aa, 0x7240945ef57ac, 0x724147b4f7261, 0x72437efe7f0a8,
+    0x72445344f3f4a, 0x7244b9bf3e0d6, 0x7245ed2e1c57a, 0x72483a6d4665f,
+    0x72488af1ee54d, 0x7248a0e7907eb, 0x7249d0ad7e6ca, 0x724db083f0255,
+    0x724ddc6f34791, 0x724ead0cb906e, 0x724f72af6c7fc, 0x7250c365cdac8,
+    0x7251dd3619709, 0x7251e487fa293, 0x725465044943e, 0x72555a3b316cd,
+    0x7255827d85644, 0x72562e81a656f, 0x7258f0dedbef4, 0x725c160d6b440,
+    0x725c78dec5007, 0x725d288bd64f7, 0x725d9a00f17d2, 0x725e8b8ee949c,
+    0x725f6e791fa52, 0x725f846ec1cf0, 0x726037c4c37a5, 0x72618fcd055fb,
+    0x7261b0bd789e8, 0x7261c30a2a6c1, 0x7264940b2175a, 0x7264e0e6d9083,
+    0x726c7bfa58de7, 0x726d32f94ae61, 0x726fa87ac8ebd, 0x726fac23b9482,
+    0x72731e2e002f7, 0x7275f680d7f1a, 0x7276d5c21df0b, 0x72774e8919d70,
+    0x7279a6c314fa4, 0x727bae78682ea, 0x727c98b47f42a, 0x727caeaa216c8,
+    0x727ea069d2770, 0x72816dc1d9244, 0x728500bc934a6, 0x72856736dd632,
+    0x7285932221b6e, 0x72864a2113be8, 0x7286a1f79c660, 0x72879e8065479,
+    0x728bdd7f40606, 0x7293246527eb7, 0x7294ede284fe8, 0x7297670cf3609,
+    0x729979bd17a9e, 0x7299d8e5810a0, 0x729c3fc33d9e8, 0x729d8927be12a,
+    0x729dc3b6c3d7a, 0x72a1b982d7ba3, 0x72a1e1c52bb1a, 0x72a4957e9fd8b,
+    0x72a4d3b695fa0, 0x72a92ffcf3f55, 0x72aaf228704fc, 0x72ad25c907d7e,
+    0x72ae3bf0633fa, 0x72af2d7e5b0c4, 0x72b13c858ef94, 0x72b4028bb4ede,
+    0x72b418815717c, 0x72b55e3ce72f9, 0x72b5aec18f1e7, 0x72b61c8db9efd,
+    0x72b706c9d103d, 0x72b9be2c35873, 0x72bd9e02a73fe, 0x72be9a8b70217,
+    0x72bf58dc42e1b, 0x72bff63ca2632, 0x72c0590dfc1f9, 0x72c0939d01e49,
+    0x72c0a5e9b3b22, 0x72c3ec08b645b, 0x72c53cbf17727, 0x72c7b5e985d48,
+    0x72c8659697238, 0x72c8aec95e59c, 0x72c8e20683662, 0x72c923e769e3c,
+    0x72cb53df110f9, 0x72cbe29baf1fc, 0x72cc5b62ab061, 0x72cde6a811f7d,
+    0x72d3bbe725175, 0x72d4902d9a017, 0x72d50c9d86441, 0x72d8c431a4055,
+    0x72d8da27462f3, 0x72dafb7b2be9c, 0x72dcf48cbdace, 0x72dd74a59a4bd,
+    0x72e18f0b11c98, 0x72e1b3a47564a, 0x72e1baf6561d4, 0x72e4466d764ce,
+    0x72e46eafca445, 0x72e4f61a879be, 0x72e6014711eeb, 0x72f3d0c20e449,
+    0x72f407a823ad4, 0x72fcfac3e568e, 0x72fe7eb76ba20, 0x7301486681f2f,
+    0x7303994e9c5d9, 0x73040e6ca7e79, 0x73052f8ed4644, 0x730be7b81ddf2,
+    0x730cc6f963de3, 0x730ccaa2543a8, 0x730d311c9e534, 0x730e0cb4f3f60,
+    0x730f1f335f017, 0x730f4023d2404, 0x7310982c1425a, 0x731578343f1c3,
+    0x73165b1e75779, 0x7316c541afeca, 0x73178ae463658, 0x731871778a1d3,
+    0x7318ccf703210, 0x7319879ee584f, 0x731bb3ed9c547, 0x731bcd8c2edaa,
+    0x731c5ff1bd472, 0x731cb7c845eea, 0x731cd166d874d, 0x731db7f9ff2c8,
+    0x731e9e8d25e43, 0x73248d6acb89e, 0x7325bd30b977d, 0x732744cd300d4,
+    0x73297c16b7f1b, 0x732b8774fb826, 0x732bbe5b10eb1, 0x732c24d55b03d,
+    0x732c630d51252, 0x732c7cabe3ab5, 0x732c8b4fa51c9, 0x732e512411d35,
+    0x732ec6421d5d5, 0x732edc37bf873, 0x733167aedfb6d, 0x7331f66b7dc70,
+    0x7335896637ed2, 0x73359bb2e9bab, 0x7336e16e79d28, 0x73383224daff4,
+    0x7339bd6a41f10, 0x733d5f08bd886, 0x733dbe3126e88, 0x733f92a955108,
+    0x734100a7391fc, 0x7341512be10ea, 0x734404e55535b, 0x7345d95d835db,
+    0x7348ec3f60e4e, 0x7348fae322562, 0x734caece4fbb1, 0x734ccfbec2f9e,
+    0x734d39e1fd6ef, 0x734d572980517, 0x734d7bc2e3ec9, 0x734d95617672c,
+    0x734ea436f121e, 0x734fed9b71960, 0x7350a0f173415, 0x7353bece21dd7,
+    0x735558b74a407, 0x7355e02207980, 0x7356dcaad0799, 0x7357bbec1678a,
+    0x73599b5f15b59, 0x735c3579f7567, 0x735ccb88761f4, 0x735d1c0d1e0e2,
+    0x735f067aee600, 0x735fb627ffaf0, 0x736171019b50d, 0x73648ede49ecf,
+    0x736587be22723, 0x7368a59ad10e5, 0x73691366fbdfb, 0x7369eb5661262,
+    0x736ae7df2a07b, 0x737406e630171, 0x7374af4160ad7, 0x7375245f6c377,
+    0x737666720bf2f, 0x7376ea33d8ee3, 0x73791dd470765, 0x7379d87c52da4,
+    0x737ea637cc034, 0x73806863485db, 0x738147a48e5cc, 0x73817738c30cd,
+    0x7386623bbf185, 0x738711e8d0675, 0x7388e2b80e330, 0x738c80ad996e1,
+    0x738de3b0ac686, 0x738dfd4f3eee9, 0x738e6ec45a1c4, 0x738fbf7abb490,
+    0x738ff2b7e0556, 0x739060840b26c, 0x73910c882c197, 0x7394f0078e2e7,
+    0x7399db0a8a39f, 0x739d630a734b2, 0x739d8ef5b79ee, 0x739f04457c66c,
+    0x73a1d8ef63cca, 0x73a23f69ade56, 0x73a48ca8d7f3b, 0x73a6fe81659d2,
+    0x73a7c7cd09725, 0x73a9b23ad9c43, 0x73ab98ffb9b9c, 0x73ad1cf33ff2e,
+    0x73afb70e2193c, 0x73b1eaaeb91be, 0x73b40107cdc18, 0x73b56f05b1d0c,
+    0x73b99f60cb785, 0x73bbe1a52471b, 0x73bd7f373d310, 0x73bf53af6b590,
+    0x73bfa4341347e, 0x73c97a3a0b5ee, 0x73c98534dc73d, 0x73c9cabeb34dc,
+    0x73ccaa636bc89, 0x73ccd9f7a078a, 0x73cdfec2bd51a, 0x73ce82848a4ce,
+    0x73cf32319b9be, 0x73d1a40a29455, 0x73d56a420877d, 0x73d5c96a71d7f,
+    0x73d71dc9c3610, 0x73d7d871a5c4f, 0x73d8f993d241a, 0x73d99a9d221f6,
+    0x73d99e46127bb, 0x73dbbf99f8364, 0x73dc3fb2d4d53, 0x73dc72eff9e19,
+    0x73dc8c8e8c67c, 0x73dd3c3b9db6c, 0x73dfd9ff6fb3f, 0x73e0da3128f1d,
+    0x73e61961bd488, 0x73e6fff4e4003, 0x73e77169ff2de, 0x73e8fcaf661fa,
+    0x73ec3f257856e, 0x73ecb7ec743d3, 0x73ed972dba3c4, 0x73edb4753d1ec,
+    0x73f12a2874626, 0x73f4f0605394e, 0x73f8367f56287, 0x73f9838cc6f8e,
+    0x73fbd81dd1bfd, 0x73fe5c431136d, 0x73fe673de24bc, 0x740113a575ba3,
+    0x7403e84f5d201, 0x7405773db46e2, 0x7405fea871c5b, 0x7407271c7efb0,
+    0x740856e26ce8f, 0x740f0b62c6078, 0x7411883624c5e, 0x7411ca170b438,
+    0x74146b83cd9d0, 0x7414bfb165e83, 0x74154e6e03f86, 0x7418147429ed0,
+    0x741835649d2bd, 0x7418ddbfcdc23, 0x741994bebfc9d, 0x741ce22fa3160,
+    0x741e3de0d557b, 0x741ffc636155d, 0x7420be5d24726, 0x7420d0a9d63ff,
+    0x742480ec13489, 0x7424dc6b8c4c6, 0x74250104efe78, 0x742542e5d6652,
+    0x7426bbde8b895, 0x74292a0e28d67, 0x742c5a3789402, 0x742c702d2b6a0,
+    0x742c8622cd93e, 0x742c986f7f617, 0x742dc8356d4f6, 0x742e39aa887d1,
+    0x742e6ce7ad897, 0x743132edd37e1, 0x7436befa1f675, 0x74396066e1c0d,
+    0x744048245febc, 0x74410dc71364a, 0x7441e20d884ec, 0x7443b685b676c,
+    0x7444e2a2b4086, 0x7444ffea36eae, 0x7445d087bb78b, 0x744662ed49e53,
+    0x7446d80b556f3, 0x744716434b908, 0x74491a4fae689, 0x74496382759ed,
+    0x7449797817c8b, 0x744d9b2f6fff0, 0x744faddf94485, 0x74501babbf19b,
+    0x74519f9f4552d, 0x74541177d2fc4, 0x7457872b0a3fe, 0x745ceaf50231b,
+    0x745d00eaa45b9, 0x745d307ed90ba, 0x745d46747b358, 0x745f93b3a543d,
+    0x745ffa2def5c9, 0x7461adb5aa45c, 0x7461c3ab4c6fa, 0x7461c7543ccbf,
+    0x746405efa5690, 0x7464f77d9d35a, 0x7467d72255b07, 0x74687f7d8646d,
+    0x746d24f6ab786, 0x746d7ccd341fe, 0x74719adb9bf9e, 0x7475c3e4d4e8d,
+    0x74770d49555cf, 0x7477651fde047, 0x7478b5d63f313, 0x747bc1663bffc,
+    0x747ce9da49351, 0x747eac05c58f8, 0x7480b76409203, 0x7483e03b88d14,
+    0x74841378addda, 0x7487b16e3918b, 0x748b35c531cd9, 0x748bc82ac03a1,
+    0x748d0de65051e, 0x748d91a81d4d2, 0x748ed763ad64f, 0x748efbfd11001,
+    0x748f4186e7da0, 0x748f6d722c2dc, 0x748f7fbeddfb5, 0x748fcc9a958de,
+    0x74917c79601ac, 0x7491a112c3b5e, 0x749387d7a3ab7, 0x7493cd617a856,
+    0x74954dac10623, 0x749558a6e1772, 0x74955c4fd1d37, 0x749905402e237,
+    0x749ce16daf7fd, 0x749dfb3dfb43e, 0x74a08e06fc2c2, 0x74a5128fae1ee,
+    0x74a53e7af272a, 0x74a6793bb1758, 0x74a6e707dc46e, 0x74a7d143f35ae,
+    0x74a837be3d73a, 0x74ad22c1397f2, 0x74ad908d64508, 0x74adcec55a71d,
+    0x74addd691be31, 0x74b2ab24950c1, 0x74b45eac4ff54, 0x74b4784ae27b7,
+    0x74b587205d2a9, 0x74b5d7a505197, 0x74bd606bd3222, 0x74be72ea3e2d9,
+    0x74bfadaafd307, 0x74c056062dc6d, 0x74c5cfc5c7e28, 0x74cc0b7f251ac,
+    0x74cdca01b118e, 0x74cec68a79fa7, 0x74cf170f21e95, 0x74cfbbc162236,
+    0x74d00c460a124, 0x74d2e0eff1782, 0x74d381f94155e, 0x74d3d9cfc9fd6,
+    0x74d5aa9f07c91, 0x74d5fb23afb7f, 0x74d9dafa2170a, 0x74dc36dd0cf03,
+    0x74dce68a1e3f3, 0x74dd161e52ef4, 0x74e2ef06566b1, 0x74e4e46ef7d1e,
+    0x74e9654eb9685, 0x74e97eed4bee8, 0x74e9e56796074, 0x74edbdec27075,
+    0x74ee6d9938565, 0x74eec56fc0fdd, 0x74f3d8b51100c, 0x74f6caa67b492,
+    0x74f71b2b23380, 0x74f89b75b914d, 0x74f8a2c799cd7, 0x74f9cee4975f1,
+    0x74fd0db1b93a0, 0x74fe118c62d43, 0x74fe536d4951d, 0x74fe954e2fcf7,
+    0x74ff44fb411e7, 0x7502edeb9d6e7, 0x75059dfc21393, 0x750779c63019d,
+    0x7507b0ac45828, 0x750cc3f195857, 0x750dfb09642c0, 0x750f0d87cf377,
+    0x751023af2a9f3, 0x7511ab4ba134a, 0x7513d048774b8, 0x75143a6bb1c09,
+    0x7516ac443f6a0, 0x751c29acc9e20, 0x751c3fa26c0be, 0x751db14940777,
+    0x7520c42b1dfea, 0x75213cf219e4f, 0x75242ee3842d5, 0x75252b6c4d0ee,
+    0x752778ab771d3, 0x7527b6e36d3e8, 0x7527df25c135f, 0x752875343ffec,
+    0x752b8bbf0de24, 0x752c1329cb39d, 0x752e6068f5482, 0x7531e4bfedfd0,
+    0x7536e5b88c326, 0x7539defbd7336, 0x753c0ef37e5f3, 0x753da18ac6099,
+    0x753dc62429a4b, 0x753ea5656fa3c, 0x7543104f8f105, 0x7543b501cf4a6,
+    0x75467b07f53f0, 0x754690fd9768e, 0x754c32ff857c0, 0x754d2f884e5d9,
+    0x754da4a659e79, 0x754ebacdb54f5, 0x754f5f7ff5896, 0x754f9db7ebaab,
+    0x755193208d118, 0x755446da01389, 0x755513ce956a1, 0x75559ee2431df,
+    0x755681cc79795, 0x755c98ec73167, 0x755d2efaf1df4, 0x755d5ae636330,
+    0x755d91cc4b9bb, 0x755de9a2d4433, 0x755ed035fafae, 0x7561336ac7331,
+    0x7565885f4475c, 0x756608782114b, 0x7566604ea9bc3, 0x756960e3d575d,
+    0x756aaa4855e9f, 0x756c910d35df8, 0x756cda3ffd15c, 0x7570e9aaa37e8,
+    0x7572f8b1d76b8, 0x757336e9cd8cd, 0x7573ede8bf947, 0x75740787521aa,
+    0x7574b38b730d5, 0x757675b6ef67c, 0x75767d08d0206, 0x75768803a1355,
+    0x757d4b27bbc52, 0x757d7abbf0753, 0x757e52ab55bba, 0x757fc0a939cae,
+    0x758069046a614, 0x75808d9dcdfc6, 0x75855407666cc, 0x7585d7c933680,
+    0x758771b25bcb0, 0x75883ea6effc8, 0x758c0887bf8b5, 0x758e3c2857137,
+    0x758ee48387a9d, 0x758ff3590258f, 0x7590c3f686e6c, 0x75941c623b47e,
+    0x75942eaeed157, 0x7594ff4c71a34, 0x75950df033148, 0x75955722fa4ac,
+    0x7596833ff7dc6, 0x759c5c27fb583, 0x759ceae499686, 0x759d79a137789,
+    0x75a16f6d4b5b2, 0x75a4b9353e4b0, 0x75a51faf8863c, 0x75ad338a04205,
+    0x75adc246a2308, 0x75aeb02ba9a0d, 0x75b1b0c0d55a7, 0x75b3fdffff68c,
+    0x75b6b90b54487, 0x75b76c6155f3c, 0x75b794a3a9eb3, 0x75b8fef89d9e2,
+    0x75bdf14d7a624, 0x75be666b85ec4, 0x75c1510b0f7c0, 0x75c3f277d1d58,
+    0x75c455492b91f, 0x75c5816629239, 0x75c5dce5a2276, 0x75c781c99b9f5,
+    0x75cd117ed7e4e, 0x75cf6266f24f8, 0x75d1f52ff337c, 0x75d2c5cd77c59,
+    0x75d425279a639, 0x75d533fd1512b, 0x75d5eea4f776a, 0x75d6517651331,
+    0x75d679b8a52a8, 0x75d7e7b68939c, 0x75d8caa0bf952, 0x75d96f52ffcf3,
+    0x75d9e0c81afce, 0x75daf6ef7664a, 0x75dcfea4c9990, 0x75de913c11436,
+    0x75df8672f96c5, 0x75dfb25e3dc01, 0x75e52874e77f7, 0x75e6374a622e9,
+    0x75e86aeaf9b6b, 0x75eb433dd178e, 0x75edefa564e75, 0x75ef0d1ea107b,
+    0x75f15e06bb725, 0x75f47f8c5a6ac, 0x75f5577bbfb13, 0x75f5920ac5763,
+    0x75f757df322cf, 0x75f9842de8fc7, 0x75fda98e318f1, 0x7601cb4589c56,
+    0x76027af29b146, 0x7603d6a3cd561, 0x7605f7f7b310a, 0x760786e60a5eb,
+    0x760ce70711f43, 0x760e63a8b774b, 0x760e799e599e9, 0x760fe79c3dadd,
+    0x761129aedd695, 0x76117ddc75b48, 0x7612d5e4b799e, 0x76135254a3dc8,
+    0x76187b8f96095, 0x76189c8009482, 0x7621307361a3a, 0x7627dda1da099,
+    0x7629da5c5c290, 0x762c2049a57eb, 0x76300771f7f00, 0x76308038f3d65,
+    0x7630bac7f99b5, 0x7630d4668c218, 0x763233c0aebf8, 0x76344a19c3652,
+    0x7635170e5796a, 0x7638bffeb3e6a, 0x7638c3a7a442f, 0x7638f6e4c94f5,
+    0x7639b18cabb34, 0x763a6c348e173, 0x763b39292248b, 0x763cc0c598de2,
+    0x763d91631d6bf, 0x763ef46630664, 0x763f27a35572a, 0x763fdea2477a4,
+    0x7641c567276fd, 0x7641d7b3d93d6, 0x76449db9ff320, 0x764528cdace5e,
+    0x764972c75913a, 0x764edde331be1, 0x7650916aeca74, 0x7657c6042264c,
+    0x76598f817f77d, 0x7659fd4daa493, 0x765c6b7d47965, 0x765cb1071e704,
+    0x765ea2c6cf7ac, 0x765f1f36bbbd6, 0x765f736454089, 0x765fd635adc50,
+    0x7660ae25130b7, 0x7660b1ce0367c, 0x7661358fd0630, 0x76642b2a2b07b,
+    0x766565eaea0a9, 0x76669207e79c3, 0x7666a0aba90d7, 0x76680ea98d1cb,
+    0x766c3060e5530, 0x766d886927386, 0x766e7da00f615, 0x7671265eb2737,
+    0x767176e35a625, 0x7673d66f363e3, 0x76750635242c2, 0x7675397249388,
+    0x76770a4187043, 0x76783db0654e7, 0x76793a392e300, 0x767d6e3d3833e,
+    0x767f2cbfc4320, 0x7684ec093527a, 0x7687f047513d9, 0x76890dc08d5df,
+    0x7689534a6437e, 0x7689ff4e852a9, 0x768bab845f5b2, 0x768d3320d5f09,
+    0x768f24e086fb1, 0x7691c64d49549, 0x7698821f832bc, 0x7698c7a95a05b,
+    0x76997aff5bb10, 0x769b8a068f9e0, 0x769c3261c0346, 0x769e9ce86d253,
+    0x769fccae5b132, 0x76a015e122496, 0x76a45888edbe8, 0x76a4fd3b2df89,
+    0x76a579ab1a3b3, 0x76a888e407661, 0x76a959818bf3e, 0x76ac407825275,
+    0x76acae444ff8b, 0x76ad32061cf3f, 0x76ad47fbbf1dd, 0x76ad68ec325ca,
+    0x76ae3989b6ea7, 0x76b044e7fa7b2, 0x76b4878fc5f04, 0x76b94df95e60a,
+    0x76bbe0c25f48e, 0x76bc55e06ad2e, 0x76bd894f491d2, 0x76bf9fa85dc2c,
+    0x76bfcf3c9272d, 0x76c34c41aa6f1, 0x76c636e133fed, 0x76c8e348c76d4,
+    0x76cbf62aa4f47, 0x76cd8c6adcfb2, 0x76cf4744789cf, 0x76cffa9a7a484,
+    0x76d36ca4c12f9, 0x76d857a7bd3b1, 0x76dbfcef292ec, 0x76e0f2ecf64f3,
+    0x76e5568535032, 0x76e5ae5bbdaaa, 0x76e6fb692e7b1, 0x76ec0eae7e7e0,
+    0x76ed5f64dfaac, 0x76efd13d6d543, 0x76f104ac4b9e7, 0x76f3ef4bd52e3,
+    0x76f4bfe959bc0, 0x76f74f096a47f, 0x76f9fb70fdb66, 0x76fb70c0c27e4,
+    0x76fda4615a066, 0x7701b0231012d, 0x7701e360351f3, 0x7705e07e29ba6,
+    0x770a7afc7dd70, 0x770be8fa61e64, 0x770e1c9af96e6, 0x770ed742dbd25,
+    0x770f2075a3089, 0x77101cfe6bea2, 0x7710def82f06b, 0x771115de446f6,
+    0x7712e30491dec, 0x7714cd726230a, 0x771516a52966e, 0x77157224a26ab,
+    0x7715eaeb9e510, 0x77173ba1ff7dc, 0x7719b4cc6ddfd, 0x771e0d69db7ed,
+    0x771e235f7da8b, 0x7726bafbc6608, 0x772766ffe7533, 0x772aca666cc94,
+    0x772d4ae2bbe3f, 0x772f146018f70, 0x772f43f44da71, 0x7731e1b81fa44,
+    0x7731f404d171d, 0x77340e06d673c, 0x77357fadaadf5, 0x77371996d3425,
+    0x7738df6b3ff91, 0x773919fa45be1, 0x7739498e7a6e2, 0x773d84e4652aa,
+    0x77403c46c9ae0, 0x77444f5a60731, 0x774502b0621e6, 0x7746570fb3a77,
+    0x7748f12a95485, 0x774b6dfdf406b, 0x774d3ecd31d26, 0x774f552646780,
+    0x7751a60e60e2a, 0x7754268aaffd5, 0x77567020e9af5, 0x7756d69b33c81,
+    0x7758aebc524c6, 0x775b034d5d135, 0x775b7fbd4955f, 0x775ee6ccbf285,
+    0x775efcc261523, 0x7760378320551, 0x7760aca12bdf1, 0x77643bf2f5a8e,
+    0x7764bc0bd247d, 0x77655d1522259, 0x77662660c5fac, 0x7766d60dd749c,
+    0x77686c4e0f507, 0x7768a6dd15157, 0x7769b5b28fc49, 0x776c153e6ba07,
+    0x776e5b2bb4f62, 0x776f8af1a2e41, 0x776fb333f6db8, 0x77770c6690342,
+    0x7777f2f9b6ebd, 0x777947590874e, 0x7779cb1ad5702, 0x777dfb75ef17b,
+    0x777f2b3bdd05a, 0x777fd3970d9c0, 0x77805006f9dea, 0x77810aaedc429,
+    0x77811cfb8e102, 0x77859a325f4a4, 0x7785eab707392, 0x7786dfedef621,
+    0x77871e25e5836, 0x778721ced5dfb, 0x77897a08d102f, 0x778bb8a439a00,
+    0x778dc7ab6d8d0, 0x778fc465efac7, 0x779118c541358, 0x77913263d3bbb,
+    0x77948e7878792, 0x7795b6ec85ae7, 0x77978b64b3d67, 0x779858594807f,
+    0x779d7a42597c2, 0x779eebe92de7b, 0x77a14b7509c39, 0x77a19fa2a20ec,
+    0x77a4b2847f95f, 0x77a8e2df993d8, 0x77a90b21ed34f, 0x77a90ecadd914,
+    0x77abac8eaf8e7, 0x77ac5fe4b139c, 0x77ac6adf824eb, 0x77adf624e9407,
+    0x77ae763dc5df6, 0x77af3be079584, 0x77af4e2d2b25d, 0x77afce4607c4c,
+    0x77afd940d8d9b, 0x77b3fea1216c5, 0x77b7de7793250, 0x77baf50261088,
+    0x77bcbe7fbe1b9, 0x77bf0bbee829e, 0x77bf54f1af602, 0x77bfd50a8bff1,
+    0x77c18fe427a0e, 0x77c74b84a83a3, 0x77c81fcb1d245, 0x77c9e9487a376,
+    0x77d0b3be757fd, 0x77d4bbd73b2ff, 0x77de156d47045, 0x77e124a6342f3,
+    0x77e141edb711b, 0x77e15f3539f43, 0x77e7381d3d700, 0x77e976b8a60d1,
+    0x77e9fe236364a, 0x77ee740853e62, 0x77efde5d47991, 0x77f1d76ed95c3,
+    0x77f3ea1efda58, 0x77f775c7d7130, 0x77f7a55c0bc31, 0x77f8849d51c22,
+    0x77fd092603b4e, 0x77fd97e2a1c51, 0x77fdbc7c05603, 0x77fdd9c38842b,
+    0x780031fd8365f, 0x780520a96fcdc, 0x7807076e4fc35, 0x78070ec0307bf,
+    0x7808be9efb08d, 0x7809007fe1867, 0x7809c9cb855ba, 0x780ee462b6173,
+    0x780ef30677887, 0x780fadae59ec6, 0x781118034d9f5, 0x78145a795fd69,
+    0x7816956bd8175, 0x781c80a08d60b, 0x781cac8bd1b47, 0x7820a9a9c64fa,
+    0x78257eb720314, 0x7826ae7d0e1f3, 0x7827b600a815b, 0x78285709f7f37,
+    0x782c507efc325, 0x782ee6f0ed76e, 0x7837564ae2374, 0x783c06bed87dc,
+    0x783c24065b604, 0x783ce2572e208, 0x783d452887dcf, 0x783d7865ace95,
+    0x783f36e838e77, 0x784078fad8a2f, 0x7840804cb95b9, 0x7841bb0d785e7,
+    0x784481139e531, 0x784534699ffe6, 0x7848ceb63add2, 0x78499baacf0ea,
+    0x784c40c081c47, 0x784daebe65d3b, 0x784e3d7b03e3e, 0x784e91a89c2f1,
+    0x784f0a6f98156, 0x785596ad9d3c8, 0x785befae7d574, 0x785cceefc3565,
+    0x785de8c00f1a6, 0x785f6561b49ae, 0x78613288020a4, 0x7862d3c30b25e,
+    0x7864924597240, 0x7865bab9a4595, 0x7869f9b87f722, 0x786be07d5f67b,
+    0x786c9b2541cba, 0x786d94051a50e, 0x7874328fd1459, 0x7874ed37b3a98,
+    0x78770ae2a907c, 0x78775b6750f6a, 0x78778ea476030, 0x787c6eaca0f99,
+    0x787c9e40d5a9a, 0x787d553fc7b14, 0x787e9afb57c91, 0x7884569bd8626,
+    0x788473e35b44e, 0x78885762bd59e, 0x78897884e9d69, 0x788c6a76541ef,
+    0x788c87bdd7017, 0x78917dbba421e, 0x7893a2b87a38c, 0x7897619e78b2a,
+    0x789e62fa8963c, 0x789f33980df19, 0x789f966967ae0, 0x78a4976205e36,
+    0x78a4a605c754a, 0x78a7fac88b597, 0x78a8a6ccac4c2, 0x78b500f7e3da2,
+    0x78b5b0a4f5292, 0x78b7d5a1cb400, 0x78b8c72fc30ca, 0x78b9bc66ab359,
+    0x78bc65254e47b, 0x78bcaaaf2521a, 0x78bd187b4ff30, 0x78bdd3233256f,
+    0x78bed6fddbf12, 0x78c011be9af40, 0x78c07f8ac5c56, 0x78c2c920ff776,
+    0x78c366815ef8d, 0x78c3d44d89ca3, 0x78c4df7a141d0, 0x78c58f27256c0,
+    0x78c91e78ef35d, 0x78ca67dd6fa9f, 0x78cb93fa6d3b9, 0x78cd43d937c87,
+    0x78d699c653408, 0x78d7c23a6075d, 0x78d892d7e503a, 0x78e0a3097063e,
+    0x78e539ded4243, 0x78e5e5e2f516e, 0x78e753e0d9262, 0x78e8750305a2d,
+    0x78ec6e7809e1b, 0x78ef1e888dac7, 0x78f447c37fd94, 0x78f64f78d30da,
+    0x78f8f0e595672, 0x78fae9f7272a4, 0x78fbb342caff7, 0x78fcee038a025,
+    0x78fd03f92c2c3, 0x7901937caf33e, 0x79044e8804139, 0x790a3d65a9b94,
+    0x790f50aaf9bc3, 0x79151aef3bc6c, 0x791530e4ddf0a, 0x7917264d7f577,
+    0x791734f140c8b, 0x7919da06f37e8, 0x791cfb8c9276f, 0x791fdb314af1c,
+    0x792036b0c3f59, 0x7924e37bc9dfc, 0x7924f5c87bad5, 0x7928bfa94b3c2,
+    0x79291b28c43ff, 0x792976a83d43c, 0x7929b4e033651, 0x792bcb39480ab,
+    0x792d39372c19f, 0x792e89ed8d46b, 0x792f02b4892d0, 0x7936dbffff249,
+    0x79372189d5fe8, 0x7938a57d5c37a, 0x793c4372e772b, 0x793faa825d451,
+    0x7941d32823b84, 0x79470eafc7b2a, 0x7947ea481d556, 0x794df66d45dd9,
+    0x7951ec3959c02, 0x7954481c453fb, 0x795540fc1dc4f, 0x7956487fb7bb7,
+    0x795669702afa4, 0x79585786eba87, 0x795d4289e7b3f, 0x795d930e8fa2d,
+    0x795dacad22290, 0x795e75f8c5fe3, 0x795fb462755d6, 0x795fce0107e39,
+    0x79657ea6b767f, 0x7966394e99cbe, 0x796668e2ce7bf, 0x7968d3697b6cc,
+    0x796bdef9783b5, 0x796dbac3871bf, 0x79712924dda6f, 0x7971884d47071,
+    0x7976cecfbc166, 0x797b152077e7d, 0x797d5b0dc13d8, 0x797eccb495a91,
+    0x7987c722381d5, 0x7989aa3e27b69, 0x798c6c9b5d4ee, 0x798ce90b49918,
+    0x798d866ba912f, 0x79973f2a1e477, 0x799c6fb6f12ce, 0x799da325cf772,
+    0x799dc7bf33124, 0x799dda0be4dfd, 0x799edde68e7a0, 0x799fb97ee41cc,
+    0x799fd6c666ff4, 0x79a0f7e8937bf, 0x79a499870f135, 0x79a49d2fff6fa,
+    0x79a56dcd83fd7, 0x79a5e2eb8f877, 0x79a6e6c63921a, 0x79af686cdfaf9,
+    0x79b1184baa3c7, 0x79b42032b6aeb, 0x79b4d388b85a0, 0x79b769faa99e9,
+    0x79b8edee2fd7b, 0x79c126620f2f6, 0x79c4652f310a5, 0x79c535ccb5982,
+    0x79c811c87db6a, 0x79c9a808b5bd5, 0x79ccf57999098, 0x79ccf9228965d,
+    0x79cd84363719b, 0x79cfa1e12c77f, 0x79d02cf4da2bd, 0x79d2c366cb706,
+    0x79d3a9f9f2281, 0x79d41b6f0d55c, 0x79d5a30b83eb3, 0x79d882b03c660,
+    0x79d99185b7152, 0x79db993b0a498, 0x79dc872011b9d, 0x79df3387a5284,
+    0x79e49ea37dd2b, 0x79e6bff7638d4, 0x79e6e839b784b, 0x79e85d897c4c9,
+    0x79e8c403c6655, 0x79eb482905dc5, 0x79edcff735afa, 0x79f1df61dc186,
+    0x79f449e889093, 0x79f5600fe470f, 0x79f5bf384dd11, 0x79f8c71f5a435,
+    0x79ff70a4e24cf, 0x7a04b37e66fff, 0x7a04d0c5e9e27, 0x7a06426cbe4e0,
+    0x7a065fb441308, 0x7a0dc038bb41c, 0x7a0e738ebced1, 0x7a0e9f7a0140d,
+    0x7a0f2e369f510, 0x7a0f5a21e3a4c, 0x7a0fb1f86c4c4, 0x7a11a3b81d56c,
+    0x7a1427dd5ccdc, 0x7a166a21b5c72, 0x7a1678c577386, 0x7a17c97bd8652,
+    0x7a182ff6227de, 0x7a18757ff957d, 0x7a1c4e048a57e, 0x7a1dad5eacf5e,
+    0x7a21e50ba7561, 0x7a27c8ee7be6d, 0x7a27dee41e10b, 0x7a28a486d1899,
+    0x7a2af56eebf43, 0x7a3510febae52, 0x7a3c8b21c77c9, 0x7a3d2c2b175a5,
+    0x7a3de32a0961f, 0x7a3e00718c447, 0x7a46d645cb1d9, 0x7a496565dba98,
+    0x7a49c8373565f, 0x7a49de2cd78fd, 0x7a49fb745a725, 0x7a4d2f46ab385,
+    0x7a4dd7a1dbceb, 0x7a54344bac45c, 0x7a590207256ec, 0x7a614575d5db6,
+    0x7a62928346abd, 0x7a658f6f82092, 0x7a6d9fa10d696, 0x7a6f35e145701,
+    0x7a6fe937471b6, 0x7a75fcae505c3, 0x7a793080a1223, 0x7a7af9fdfe354,
+    0x7a7ffaf69c6aa, 0x7a807eb86965e, 0x7a846989ac338, 0x7a85a44a6b366,
+    0x7a85a7f35b92b, 0x7a85ffc9e43a3, 0x7a8c676e85c63, 0x7a8f0c84387c0,
+    0x7a90e84e475ca, 0x7a93a3599c3c5, 0x7a97e60167b17, 0x7a98af4d0b86a,
+    0x7a9aa4b5aced7, 0x7a9c0066df2f2, 0x7a9f293e5ee03, 0x7aa113ac2f321,
+    0x7aa4fe7d71ffb, 0x7aa52a68b6537, 0x7aa56ff28d2d6, 0x7aa7bd31b73bb,
+    0x7aa839a1a37e5, 0x7aad19a9ce74e, 0x7aaebe8dc7ecd, 0x7aaed82c5a730,
+    0x7ab1674c6afef, 0x7ab38ff231722, 0x7ab4dcffa2429, 0x7ab4eba363b3d,
+    0x7abd27c03367d, 0x7abdc8c983459, 0x7abdf10bd73d0, 0x7ac5010ba95f6,
+    0x7ac5ad0fca521, 0x7accae6bdb033, 0x7ad0e26fe5071, 0x7ad43e8489c48,
+    0x7ad4f5837bcc2, 0x7ad784a38c581, 0x7adcf368555ed, 0x7adda6be570a2,
+    0x7adf5a4611f35, 0x7ae082ba1f28a, 0x7ae18a3db91f2, 0x7ae85c0595203,
+    0x7aeabf3a61586, 0x7aec72c21c419, 0x7aef770038578, 0x7aef9447bb3a0,
+    0x7af04eef9d9df, 0x7af67fae29c14, 0x7af7d40d7b4a5, 0x7af7ea031d743,
+    0x7af8a4aaffd82, 0x7afe888dd468e, 0x7afebf73e9d19, 0x7aff13a1821cc,
+    0x7aff174a72791, 0x7b01056133274, 0x7b06a763213a6, 0x7b06b9afd307f,
+    0x7b073276ceee4, 0x7b08deaca91ed, 0x7b08e9a77a33c, 0x7b097fb5f8fc9,
+    0x7b0a50537d8a6, 0x7b0abe1fa85bc, 0x7b0d87cebeacb, 0x7b10bba10f72b,
+    0x7b117648f1d6a, 0x7b11c324a9693, 0x7b136808a2e12, 0x7b14056902629,
+    0x7b176526977c5, 0x7b177ec52a028, 0x7b1d9d3704584, 0x7b1dcccb39085,
+    0x7b208b7f7e445, 0x7b20e6fef7482, 0x7b20fcf499720, 0x7b23c6a3afc2f,
+    0x7b24ca7e595d2, 0x7b2508b64f7e7, 0x7b25593af76d5, 0x7b2576827a4fd,
+    0x7b26312a5cb3c, 0x7b29cf1fe7eed, 0x7b2c40f875984, 0x7b2cf44e77439,
+    0x7b2d1195fa261, 0x7b2f6d78e5a5a, 0x7b36eeedd2f5b, 0x7b373477a9cfa,
+    0x7b377daa7105e, 0x7b38c366011db, 0x7b3bae058aad7, 0x7b3d2aa7302df,
+    0x7b3d8626a931c, 0x7b40c4f3cb0cb, 0x7b4893446fef5, 0x7b4c104987eb9,
+    0x7b4c1b4459008, 0x7b4c472f9d544, 0x7b4d64a8d974a, 0x7b4dc02852787,
+    0x7b4f1f8275167, 0x7b55036549a73, 0x7b552ba79d9ea, 0x7b58d497f9eea,
+    0x7b5987edfb99f, 0x7b5da9a553d04, 0x7b60476925cd7, 0x7b63c8172e260,
+    0x7b640da104fff, 0x7b6456d3cc363, 0x7b6d6e88f18cf, 0x7b6e1a8d127fa,
+    0x7b77f43bfaf2f, 0x7b7898ee3b2d0, 0x7b78e5c9f2bf9, 0x7b79e5fbabfd7,
+    0x7b7f7207f7e6b, 0x7b7fa8ee0d4f6, 0x7b81726b6a627, 0x7b85fe45fd0dd,
+    0x7b89fb63f1a90, 0x7b8e7148e22a8, 0x7b9133a617c2d, 0x7b95e7c2fe65a,
+    0x7b9ec1402d9b1, 0x7ba1839d63336, 0x7ba5f5d963589, 0x7bac94641a4d4,
+    0x7bacbca66e44b, 0x7baf77b1c3246, 0x7bb0ab20a16ea, 0x7bb0d362f5661,
+    0x7bb0de5dc67b0, 0x7bb5927aad1dd, 0x7bb5e2ff550cb, 0x7bb5e6a845690,
+    0x7bbb974df4ed6, 0x7bbc34ae546ed, 0x7bc669dcb5e5f, 0x7bc7073d15676,
+    0x7bc83aabf3b1a, 0x7bcc2ccf1737e, 0x7bcc6b070d593, 0x7bcfb1260fecc,
+    0x7bd0caf65bb0d, 0x7bd4da6102199, 0x7bd9872c0803c, 0x7bd9eda6521c8,
+    0x7bd9fc4a138dc, 0x7bdc2feaab15e, 0x7be505bee9ef0, 0x7be5e8a9204a6,
+    0x7be9e5c714e59, 0x7bec713e35153, 0x7bec8adcc79b6, 0x7bed1d425607e,
+    0x7bedf8daabaaa, 0x7bf18f7e562d1, 0x7bf19a7927420, 0x7bf501889d146,
+    0x7bf5b8878f1c0, 0x7bf6cb05fa277, 0x7bfc5abb366d0, 0x7bfd2b58bafad,
+    0x7bff0e74aa941, 0x7bff8e8d87330, 0x7c012c1f9ff25, 0x7c04514e2f471,
+    0x7c05308f75462, 0x7c05d8eaa5dc8, 0x7c075cde2c15a, 0x7c07fde77bf36,
+    0x7c0c09a931ffd, 0x7c12f8b890e36, 0x7c159a25533ce, 0x7c1adcfed7efe,
+    0x7c1c524e9cb7c, 0x7c1c81e2d167d, 0x7c1ce4b42b244, 0x7c1ef7644f6d9,
+    0x7c1fc0aff342c, 0x7c21066b835a9, 0x7c2123b3063d1, 0x7c2578a7837fc,
+    0x7c274d1fb1a7c, 0x7c28166b557cf, 0x7c2d3bfd574d7, 0x7c2de80178402,
+    0x7c2f4ead7b96c, 0x7c30ec3f94561, 0x7c3e37f8c3b0b, 0x7c3ec30c71649,
+    0x7c4586308bf46, 0x7c45912b5d095, 0x7c46f0857fa75, 0x7c4bd7df8b568,
+    0x7c50b7e7b64d1, 0x7c50d52f392f9, 0x7c51d560f26d7, 0x7c54857176383,
+    0x7c54aa0ad9d35, 0x7c55142e14486, 0x7c568d26c96c9, 0x7c57bcecb75a8,
+    0x7c58a728ce6e8, 0x7c5cf8745b54e, 0x7c5daf734d5c8, 0x7c5eea340c5f6,
+    0x7c6154bab9503, 0x7c64c6c500378, 0x7c667df5ab7d0, 0x7c679f17d7f9b,
+    0x7c69230b5e32d, 0x7c69effff2645, 0x7c74a547307a6, 0x7c7b318535a18,
+    0x7c7d10f834de7, 0x7c7d9862f2360, 0x7c7e4f61e43da, 0x7c90d6a2b792a,
+    0x7c9189f8b93df, 0x7c952445541cb, 0x7c958abf9e357, 0x7c962f71de6f8,
+    0x7c964cb961520, 0x7c97a86a9393b, 0x7c9c21f874718, 0x7c9f81b6098b4,
+    0x7ca42e810f757, 0x7ca52760e7fab, 0x7ca732bf2b8b6, 0x7ca7cc769ab08,
+    0x7cb155a0db34f, 0x7cb62aae35169, 0x7cb95ad795804, 0x7cbebaf89d15c,
+    0x7cc1fd6eaf4d0, 0x7cc3014958e73, 0x7cc53c3bd127f, 0x7cc6fabe5d261,
+    0x7cc71baed064e, 0x7cca8db9174c3, 0x7ccd033a9551f, 0x7cd0b37cd25a9,
+    0x7cd1498b51236, 0x7cd78544ae5ba, 0x7cd7a9de11f6c, 0x7cd97aad4fc27,
+    0x7cdfba0f9d570, 0x7ce0158f165ad, 0x7ce01ce0f7137, 0x7ce6882e88fbc,
+    0x7ce72ce0c935d, 0x7ced59f664fcd, 0x7ced614845b57, 0x7cef0d7e1fe60,
+    0x7cf0bd5cea72e, 0x7cf854c779ecd, 0x7cff9bad6177e, 0x7d00ec63c2a4a,
+    0x7d042036136aa, 0x7d05b2cd5b150, 0x7d05e9b3707db, 0x7d08c206483fe,
+    0x7d0c2cbeae6e9, 0x7d0c4a0631511, 0x7d1131603d004, 0x7d1238e3d6f6c,
+    0x7d153d21f30cb, 0x7d1582abc9e6a, 0x7d1869a2631a1, 0x7d19662b2bfba,
+    0x7d19a0ba31c0a, 0x7d1b9d74b3e01, 0x7d1cee2b150cd, 0x7d1e6723ca310,
+    0x7d1f42bc1fd3c, 0x7d1ff2693122c, 0x7d21d92e11185, 0x7d24943965f80,
+    0x7d2714b5b512b, 0x7d276c8c3dba3, 0x7d27eca51a592, 0x7d2cb6b7a325d,
+    0x7d2d57c0f3039, 0x7d2edbb4793cb, 0x7d30b77e881d5, 0x7d318f6ded63c,
+    0x7d37c77e5a3fb, 0x7d37cb274a9c0, 0x7d39f3cd110f3, 0x7d3c61fcae5c5,
+    0x7d407c6225da0, 0x7d47e7e171003, 0x7d49264b205f6, 0x7d494e8d7456d,
+    0x7d497e21a906e, 0x7d49fe3a85a5d, 0x7d4b09670ff8a, 0x7d4c7764f407e,
+    0x7d4ef08f6269f, 0x7d535f227232d, 0x7d540b2693258, 0x7d55423e61cc1,
+    0x7d56969db3552, 0x7d58467c7de20, 0x7d5946ae371fe, 0x7d5af68d01acc,
+    0x7d5f26e81b545, 0x7d60cbcc14cc4, 0x7d61de4a7fd7b, 0x7d63698fe6c97,
+    0x7d66e2ec0e696, 0x7d6c46b6065b3, 0x7d6c6ef85a52a, 0x7d6e6460fbb97,
+    0x7d6e7304bd2ab, 0x7d6e9b4711222, 0x7d6fadc57c2d9, 0x7d6fc3bb1e577,
+    0x7d76cc690fc13, 0x7d78edbcf57bc, 0x7d80bfb68abab, 0x7d80cab15bcfa,
+    0x7d84d67311dc1, 0x7d855dddcf33a, 0x7d85777c61b9d, 0x7d887f636e2c1,
+    0x7d8e968367c93, 0x7d965d822bf33, 0x7d979bebdb526, 0x7d9cc17ddd22e,
+    0x7d9ed42e016c3, 0x7d9fd808ab066, 0x7da146068f15a, 0x7da1809594daa,
+    0x7da36eac5588d, 0x7dacda8f132ac, 0x7dad98dfe5eb0, 0x7daea40c703dd,
+    0x7db1c1e91ed9f, 0x7db1e2d99218c, 0x7db58820fe0c7, 0x7db596c4bf7db,
+    0x7db73ba8b8f5a, 0x7db7a5cbf36ab, 0x7db8766977f88, 0x7dbafe37a7cbd,
+    0x7dbe1fbd46c44, 0x7dbf02a77d1fa, 0x7dc41d3eaddb3, 0x7dc578efe01ce,
+    0x7dc7a8e78748b, 0x7dc9133c7afba, 0x7dcc4365db655, 0x7dcee87b8e1b2,
+    0x7dcf4b4ce7d79, 0x7dd4af16dfc96, 0x7dd8b72fa5798, 0x7ddc6ec3c33ac,
+    0x7ddda5db91e15, 0x7ddeeb9721f92, 0x7de1736551cc7, 0x7de5fb96f41b8,
+    0x7de68dfc82880, 0x7de6f0cddc447, 0x7de99d356fb2e, 0x7decac6e5cddc,
+    0x7ded6abf2f9e0, 0x7def2941bb9c2, 0x7df5e8bce5cfa, 0x7df962190d6f9,
+    0x7dfd41ef7f284, 0x7dff12bebcf3f, 0x7e005c233d681, 0x7e00bef497248,
+    0x7e0121c5f0e0f, 0x7e091faaca73a, 0x7e0c363598572, 0x7e0ccfed077c4,
+    0x7e14d8ccb223e, 0x7e153f46fc3ca, 0x7e197e45d7557, 0x7e23e3086d7ca,
+    0x7e24d0ed74ecf, 0x7e24e6e31716d, 0x7e24ea8c07732, 0x7e25f96182224,
+    0x7e27b43b1dc41, 0x7e29a9a3bf2ae, 0x7e2fda624b4e3, 0x7e3165a7b23ff,
+    0x7e3198e4d74c5, 0x7e376a7afa0f8, 0x7e37ee3cc70ac, 0x7e39046422728,
+    0x7e393b4a37db3, 0x7e4173be1732e, 0x7e42e90ddbfac, 0x7e435a82f7287,
+    0x7e44f46c1f8b7, 0x7e46ed7db14e9, 0x7e4dc2ee7dabf, 0x7e4de787e1471,
+    0x7e4f08aa0dc3c, 0x7e50b888d850a, 0x7e50ce7e7a7a8, 0x7e5152404775c,
+    0x7e55c82537f74, 0x7e584ff367ca9, 0x7e5d66e1a829d, 0x7e5ef5cfff77e,
+    0x7e60d542feb4d, 0x7e63fe1a7e65e, 0x7e6443a4553fd, 0x7e6c368e5dbd9,
+    0x7e6d57b08a3a4, 0x7e717967e2709, 0x7e74a5e8527df, 0x7e759ec82b033,
+    0x7e78737212691, 0x7e7df82c7d99b, 0x7e7fdef15d8f4, 0x7e856afda9788,
+    0x7e8634494d4db, 0x7e8684cdf53c9, 0x7e8d68e2830b3, 0x7e8f116f6cdf7,
+    0x7e8f1c6a3df46, 0x7e9616746dece, 0x7e97672acf19a, 0x7e9be461a053c,
+    0x7e9c29eb772db, 0x7e9c68236d4f0, 0x7e9e8d204365e, 0x7e9e9472241e8,
+    0x7e9fd2dbd37db, 0x7ea186638e66e, 0x7ea55ee81f66f, 0x7ea6f17f67115,
+    0x7eacd1b94b45c, 0x7eace405fd135, 0x7eadb4a381a12, 0x7eae29c18d2b2,
+    0x7eae6f4b64051, 0x7eae7def25765, 0x7eaf13fda43f2, 0x7ebc217edd787,
+    0x7ebcbb364c9d9, 0x7ebd6ae35dec9, 0x7ebf0c1e67083, 0x7ec09763cdf9f,
+    0x7ec126206c0a2, 0x7ec4a32584066, 0x7ec8aee73a12d, 0x7ecd21233a380,
+    0x7ecebeb552f75, 0x7ecf3b253f39f, 0x7ed476ace3345, 0x7ed7c074d6243,
+    0x7ed84b8883d81, 0x7ed94f632d724, 0x7ed96caab054c, 0x7edd0aa03b8fd,
+    0x7edd368b7fe39, 0x7ee1f94c27f7a, 0x7ee2d4e47d9a6, 0x7ee3a58202283,
+    0x7ee64e40a53a5, 0x7ee8a67aa05d9, 0x7ee985bbe65ca, 0x7eed77df09e2e,
+    0x7eefd76ae5bec, 0x7ef07874359c8, 0x7ef500a5d7eb9, 0x7ef5b7a4c9f33,
+    0x7effc0e7e7169, 0x7f046db2ed00c, 0x7f0849e06e5d2, 0x7f09dc77b6078,
+    0x7f173a7d972fb, 0x7f17f17c89375, 0x7f17fc775a4c4, 0x7f1819bedd2ec,
+    0x7f188b33f85c7, 0x7f1bb7b46869d, 0x7f1c08391058b, 0x7f1ebf9b74dc1,
+    0x7f21909c6be5a, 0x7f263d6771cfd, 0x7f2c498c9a580, 0x7f2f258862768,
+    0x7f3490a43b20f, 0x7f376ca0033f7, 0x7f37a72f09047, 0x7f44fde309740,
+    0x7f47ec2b83601, 0x7f4923435206a, 0x7f4a56b23050e, 0x7f4c5e6783854,
+    0x7f4cf476024e1, 0x7f4f69f78053d, 0x7f506a293991b, 0x7f50f53ce7459,
+    0x7f512fcbed0a9, 0x7f5793c79e3a4, 0x7f590cc0535e7, 0x7f5976e38dd38,
+    0x7f5cf03fb5737, 0x7f654d4cf8664, 0x7f68e3f0a2e8b, 0x7f6caa28821b3,
+    0x7f6ea33a13de5, 0x7f6faabdadd4d, 0x7f71b27301093, 0x7f757501efdf6,
+    0x7f7703f0472d7, 0x7f7833b6351b6, 0x7f79eae6e060e, 0x7f7beef34338f,
+    0x7f7ee48d9ddda, 0x7f7f9b8c8fe54, 0x7f807e76c640a, 0x7f863dc037364,
+    0x7f87abbe1b458, 0x7f8d2926a5bd8, 0x7f8f93ad52ae5, 0x7f90cac52154e,
+    0x7f91fe33ff9f2, 0x7f94442148f4d, 0x7f94700c8d489, 0x7f9c8b38e9bdc,
+    0x7f9d12a3a7155, 0x7f9de3412ba32, 0x7f9f21aadb025, 0x7fa794adc01f0,
+    0x7fa9e1ecea2d5, 0x7fae99b2c12c7, 0x7faeb35153b2a, 0x7fb38c079df09,
+    0x7fb451aa51697, 0x7fb49e8608fc0, 0x7fb693eeaa62d, 0x7fb82a2ee2698,
+    0x7fbc150025372, 0x7fbc5e32ec6d6, 0x7fbd9c9c9bcc9, 0x7fbeab72167bb,
+    0x7fc4168def262, 0x7fc4cd8ce12dc, 0x7fc534072b468, 0x7fc66ec7ea496,
+    0x7fc7087f596e8, 0x7fc79ae4e7db0, 0x7fce94ef17d38, 0x7fd0b9ebedea6,
+    0x7fd65beddbfd8, 0x7fdf26c749c1b, 0x7fdf9be5554bb, 0x7fe0ae63c0572,
+    0x7fe0b5b5a10fc, 0x7fe465f7de186, 0x7fe56d7b780ee, 0x7fe6999875a08,
+    0x7fe75f3b29196, 0x7feaa1b13b50a, 0x7feb9e3a04323, 0x7fecf9eb3673e,
+    0x7fef681ad3c10, 0x7ff10cfecd38f, 0x7ff748b82a713, 0x7ffc759c0cfa5,
+    0x7ffd75cdc6383, 0x800129b8f39d2, 0x80048d1f79133, 0x8004aa66fbf5b,
+    0x80052e28c8f0f, 0x80057ead70dfd, 0x80069fcf9d5c8, 0x8006b96e2fe2b,
+    0x800709f2d7d19, 0x8009bdac4bf8a, 0x8009f0e971050, 0x800c750eb07c0,
+    0x800c99a814172, 0x800cf5278d1af, 0x800d83e42b2b2, 0x800dd468d31a0,
+    0x800f217643ea7, 0x8014226ee21fd, 0x8014e468a53c6, 0x801526498bba0,
+    0x8015cea4bc506, 0x80162a2435543, 0x8016a2eb313a8, 0x8017a6c5dad4b,
+    0x8017bcbb7cfe9, 0x8018407d49f9d, 0x80192e62516a2, 0x8019ecb3242a6,
+    0x801c06b5292c5, 0x801c1caacb563, 0x801c44ed1f4da, 0x801cab6769666,
+    0x801d9cf561330, 0x801ddb2d57545, 0x8020336752779, 0x80216a7f211e2,
+    0x80241e3895453, 0x80254303b21e3, 0x8025dcbb21435, 0x8025e40d01fbf,
+    0x8026592b0d85f, 0x8029c3e373b4a, 0x802c988d5b1a8, 0x802cb22beda0b,
+    0x802cc8218fca9, 0x802cecbaf365b, 0x802d4f8c4d222, 0x802da3b9e56d5,
+    0x802f39fa1d740, 0x802fa7c648456, 0x803095ab4fb5b, 0x8034c606695d4,
+    0x803621b79b9ef, 0x803763ca3b5a7, 0x8037cded75cf8, 0x80381ac92d621,
+    0x80394a8f1b500, 0x8039851e21150, 0x8039d5a2c903e, 0x8039eb986b2dc,
+    0x803c59c8087ae, 0x803d2a658d08b, 0x803db9222b18e, 0x803dbccb1b753,
+    0x803e68cf3c67e, 0x803ef3e2ea1bc, 0x803f6ca9e6021, 0x80414122142a1,
+    0x804348d7675e7, 0x80436d70caf99, 0x80443a655f2b1, 0x804541e8f9219,
+    0x8046d82931284, 0x8047f5a26d48a, 0x804812e9f02b2, 0x80485873c7051,
+    0x804b4e0e21a9c, 0x804c050d13b16, 0x804ccaafc72a4, 0x804cfdecec36a,
+    0x804d6bb917080, 0x804e8cdb4384b, 0x804e9f27f5524, 0x804fe88c75c66,
+    0x805109aea2431, 0x8051568a59d5a, 0x8052e578b123b, 0x805532b7db320,
+    0x8055af27c774a, 0x80569d0ccee4f, 0x8056a8079ff9e, 0x8058f8efba648,
+    0x805916373d470, 0x805924dafeb84, 0x805992a72989a, 0x8059f57883461,
+    0x805ca931f76d2, 0x805d0c0351299, 0x805d6782ca2d6, 0x805ebf8b0c12c,
+    0x8065f42441d04, 0x806d3b0a295b5, 0x8074734c4f752, 0x80748ceae1fb5,
+    0x8077653db9bd8, 0x807c53e9a6255, 0x807c628d67969, 0x807d8eaa65283,
+    0x807e50a42844c, 0x807f1646dbbda, 0x80813b43b1d48, 0x808425e33b644,
+    0x8085e465c7626, 0x80861ef4cd276, 0x808629ef9e3c5, 0x8086732265729,
+    0x8087cb2aa757f, 0x8089f0277d6ed, 0x808b65774236b, 0x808bc8489bf32,
+    0x808d6231c4562, 0x808f2baf21693, 0x8090457f6d2d4, 0x8090fc7e5f34e,
+    0x80919635ce5a0, 0x809546780b62a, 0x80954a20fbbef, 0x809581071127a,
+    0x8096dcb843695, 0x8097a9acd79ad, 0x809893e8eeaed, 0x809bcf0d202d7,
+    0x809c73bf60678, 0x809d89e6bbcf4, 0x80a00e0bfb464, 0x80a04fece1c3e,
+    0x80a1f879cb982, 0x80a592c66676e, 0x80a5b00de9596, 0x80a68f4f2f587,
+    0x80a6fd1b5a29d, 0x80a930bbf1b1f, 0x80aa88c433975, 0x80ac942277280,
+    0x80ac9b7457e0a, 0x80adec2ab90d6, 0x80ae7ae7571d9, 0x80af5a289d1ca,
+    0x80af9fb273f69, 0x80afda4179bb9, 0x80b056b165fe3, 0x80b068fe17cbc,
+    0x80b0f068d5235, 0x80b140ed7d123, 0x80b17f2573338, 0x80b4870c7fa5c,
+    0x80b52f67b03c2, 0x80b557aa04339, 0x80b6edea3c3a4, 0x80b74969b53e1,
+    0x80b7546486530, 0x80b8b767994d5, 0x80bce070d23c4, 0x80bd2d4c89ced,
+    0x80bdbfb2183b5, 0x80bfefa9bf672, 0x80c0b8f5633c5, 0x80c0ec328848b,
+    0x80c4658eafe8a, 0x80c52b3163618, 0x80c5d38c93f7e, 0x80c6b676ca534,
+    0x80c78abd3f3d6, 0x80c7f4e079b27, 0x80c7f8896a0ec, 0x80c85f03b4278,
+    0x80c98777c15cd, 0x80c9afba15544, 0x80cc9307be2b6, 0x80d3562bd8bb3,
+    0x80d865c83861d, 0x80d969a2e1fc0, 0x80d991e535f37, 0x80d9a088f764b,
+    0x80dd4d2244110, 0x80df721f1a27e, 0x80e1dca5c718b, 0x80e4597925d71,
+    0x80e46bc5d7a4a, 0x80e4caee4104c, 0x80e522c4c9ac4, 0x80e56fa0813ed,
+    0x80e597e2d5364, 0x80e5aa2f8703d, 0x80e6aa614041b, 0x80e6d2a394392,
+    0x80ea8de0a256b, 0x80ed48ebf7366, 0x80ed8e75ce105, 0x80efbe6d753c2,
+    0x80f1e36a4b530, 0x80f49723bf7a1, 0x80f57a0df5d57, 0x80f5b845ebf6c,
+    0x80f5c6e9ad680, 0x80f66f44ddfe6, 0x80f6e0b9f92c1, 0x80f9d2ab63747,
+    0x80fc5e2283a41, 0x80fcaafe3b36a, 0x80fd067db43a7, 0x80fdf80bac071,
+    0x80feba056f23a, 0x8101be438b399, 0x8106b441585a0, 0x81072d0854405,
+    0x8107380325554, 0x81080c499a3f6, 0x810817446b545, 0x8108432fafa81,
+    0x810c05be9e7e4, 0x810d05f057bc2, 0x810d5a1df0075, 0x810ebd210301a,
+    0x81124c72cccb7, 0x8114507f2fa38, 0x81184d9d243eb, 0x81189a78dbd14,
+    0x811d2da54f354, 0x811fddb5d3000, 0x81204f2aee2db, 0x81211124b14a4,
+    0x81211fc872bb8, 0x8121480ac6b2f, 0x81219fe14f5a7, 0x81242f015fe66,
+    0x8124deae71356, 0x812569c21ee94, 0x8126e663c469c, 0x8127542fef3b2,
+    0x8127fc8b1fd18, 0x812933a2ee781, 0x81298b79771f9, 0x812ca5ad355f6,
+    0x812e6f2a92727, 0x812f1b2eb3652, 0x8131b8f285625, 0x8134655a18d0c,
+    0x81379583793a7, 0x8138e639da673, 0x813dd88eb72b5, 0x813df22d49b18,
+    0x813ed16e8fb09, 0x813fad06e5535, 0x8144d9eac7dc7, 0x8146ae62f6047,
+    0x8148693c91a64, 0x8148cc0deb62b, 0x814b453859c4c, 0x814bd04c0778a,
+    0x814c0adb0d3da, 0x814c874af9804, 0x814d03bae5c2e, 0x814d802ad2058,
+    0x814df548dd8f8, 0x814e6a66e9198, 0x814e99fb1dc99, 0x8150cd9bb551b,
+    0x81514db491f0a, 0x81544aa0cd4df, 0x8154ebaa1d2bb, 0x81554729962f8,
+    0x81556bc2f9caa, 0x8155a2a90f335, 0x8157896def28e, 0x8157f73a19fa4,
+    0x815814819cdcc, 0x815b8a34d4206, 0x815c3d8ad5cbb, 0x815c7f6bbc495,
+    0x815dba2c7b4c3, 0x815e3dee48477, 0x815ea46892603, 0x815f5f1074c42,
+    0x815fb6e6fd6ba, 0x81602fadf951f, 0x81612c36c2338, 0x816525abc6726,
+    0x816558e8eb7ec, 0x8166c33ddf31b, 0x816938bf5d377, 0x816c1c0d060e9,
+    0x816d9505bb32c, 0x816dbd480f2a3, 0x816fbdab81a5f, 0x817048bf2f59d,
+    0x817169e15bd68, 0x81748f0feb2b4, 0x81749661cbe3e, 0x817533c22b655,
+    0x817c1084d87b5, 0x817c2dcc5b5dd, 0x817c31754bba2, 0x817dc063a3083,
+    0x817fb2235412b, 0x8180cbf39fd6c, 0x818123ca287e4, 0x8181953f43abf,
+    0x81836265911b5, 0x818441a6d71a6, 0x81848730adf45, 0x8184af7301ebc,
+    0x8185d7e70f211, 0x81863ab868dd8, 0x8186928ef1850, 0x818bfdaaca2f7,
+    0x818d55b30c14d, 0x818d67ffbde26, 0x818def6a7b39f, 0x818e55e4c552b,
+    0x818e7e27194a2, 0x818ebc5f0f6b7, 0x818fc3e2a961f, 0x8191bcf43b251,
+    0x8191fb2c31466, 0x8192654f6bbb7, 0x8194e5cbbad62, 0x8194f46f7c476,
+    0x819565e497751, 0x819836e58e7ea, 0x8199df727852e, 0x819d16edb9753,
+    0x819d9aaf86707, 0x819df62eff744, 0x81a0b4e344b04, 0x81a138a511ab8,
+    0x81a16839465b9, 0x81a43ce32dc17, 0x81a6aebbbb6ae, 0x81a6daa6ffbea,
+    0x81a6e1f8e0774, 0x81a86d3e47690, 0x81a9b6a2c7dd2, 0x81ac8b4caf430,
+    0x81b060284fe6c, 0x81b4524b736d0, 0x81b5e88bab73b, 0x81b8de2606186,
+    0x81bbd76951196, 0x81be2fa34c3ca, 0x81bea4c157c6a, 0x81bef8eef011d,
+    0x81bf45caa7a46, 0x81c1044d33a28, 0x81c1aca86438e, 0x81c1b7a3354dd,
+    0x81c1e73769fde, 0x81c534a84d4a1, 0x81c55ceaa1418, 0x81c56f37530f1,
+    0x81c572e0436b6, 0x81c5eba73f51b, 0x81c626364516b, 0x81c7efb3a229c,
+    0x81c90983ededd, 0x81cbbd3d6214e, 0x81cc1c65cb750, 0x81cf3a427a112,
+    0x81d5082fac780, 0x81d5d17b504d3, 0x81d5d8cd3105d, 0x81d6e7a2abb4f,
+    0x81d7347e63478, 0x81d8644451357, 0x81d964760a735, 0x81dc1f815f530,
+    0x81dc7b00d856d, 0x81e1db21dfec5, 0x81e1e9c5a15d9, 0x81e50b4b40560,
+    0x81e5b74f6148b, 0x81e780ccbe5bc, 0x81e91e5ed71b1, 0x81e937fd69a14,
+    0x81ec229cf3310, 0x81ecf6e3681b2, 0x81ed1b7ccbb64, 0x81ed2dc97d83d,
+    0x81ed97ecb7f8e, 0x81ef9fa20b2d4, 0x81f1d6eb9311b, 0x81f51961a548f,
+    0x81f5f8a2eb480, 0x81f750ab2d2d6, 0x81f78ee3234eb, 0x81f8a8b36f12c,
+    0x81f8c9a3e2519, 0x81f9dfcb3db95, 0x81fc51a3cb62c, 0x81fc6eeb4e454,
+    0x81fc7d8f0fb68, 0x81fd6b741726d, 0x81fe1b212875d, 0x81fe60aaff4fc,
+    0x81ff314883dd9, 0x81ffb8b341352, 0x8204fb8cc5e82, 0x8204ff35b6447,
+    0x8206a7c2a018b, 0x8207576fb167b, 0x8208c1c4a51aa, 0x82093a8ba100f,
+    0x820d16b9225d5, 0x820d673dca4c3, 0x820dc66633ac5, 0x820e84b7066c9,
+    0x820f0fcab4207, 0x820f1e6e7591b, 0x820fa98223459, 0x820fef0bfa1f8,
+    0x8210c6fb5f65f, 0x8211fa6a3db03, 0x8213770be330b, 0x8214898a4e3c2,
+    0x8215b5a74bcdc, 0x82175a8b4545b, 0x8219a7ca6f540, 0x8219b2c54068f,
+    0x821c508912662, 0x821c99bbd99c6, 0x821ca10dba550, 0x821f3b289bf5e,
+    0x82213b8c0e71a, 0x82244e6debf8d, 0x822497a0b32f1, 0x8224b13f45b54,
+    0x82288d6cc711a, 0x8229d32857297, 0x822c48a9d52f3, 0x822c86e1cb508,
+    0x822ca4294e330, 0x822e78a17c5b0, 0x822ef16878415, 0x823092a3815cf,
+    0x823171e4c75c0, 0x8231fcf8750fe, 0x8234f9e4b06d3, 0x823646f2213da,
+    0x82397372914b0, 0x823e69705e6b7, 0x823f15747f5e2, 0x823f1cc66016c,
+    0x823fd3c5521e6, 0x823fed63e4a49, 0x824384078f270, 0x8247294efb1ab,
+    0x824967ea63b7c, 0x8249cabbbd743, 0x8249dd086f41c, 0x824cbcad27bc9,
+    0x824cfe8e0e3a3, 0x824e110c7945a, 0x824fb24782614, 0x8254abee3fde0,
+    0x825b9002cdaca, 0x825bfa260821b, 0x825ef3695322b, 0x82613204bbbfc,
+    0x8261912d251fe, 0x8261987f05d88, 0x8264a7b7f3036, 0x82674924b55ce,
+    0x8268d46a1c4ea, 0x826c307ec10c1, 0x826d04c535f63, 0x826da2259577a,
+    0x826dca67e96f1, 0x826e93b38d444, 0x827461a0bfab2, 0x8274c0c9290b4,
+    0x8274c47219679, 0x827648659fa0b, 0x827670a7f3982, 0x827cf93d0862f,
+    0x827d87f9a6732, 0x827deacb002f9, 0x827efd496b3b0, 0x827f8c06094b3,
+    0x828125ef31ae3, 0x82817673d99d1, 0x82851bbb4590c, 0x828547a689e48,
+    0x82854b4f7a40d, 0x8285a6cef344a, 0x8289153049cfa, 0x828d1d490f7fc,
+    0x8290c6396bcfc, 0x8290f224b0238, 0x82943beca3136, 0x82995a2cc42b4,
+    0x829daf21416df, 0x829e5b256260a, 0x829e627743194, 0x829e8367b6581,
+    0x829e920b77c95, 0x829eba4dcbc0c, 0x829ee9e20070d, 0x829f072983535,
+    0x829f20c815d98, 0x829ffc606b7c4, 0x82a3e731ae49e, 0x82a5343f1f1a5,
+    0x82a5602a636e1, 0x82a59e62598f6, 0x82a6b489b4f72, 0x82a6e074f94ae,
+    0x82a7434653075, 0x82a89ef785490, 0x82a96bec197a8, 0x82a9f6ffc72e6,
+    0x82ac94c3992b9, 0x82acaab93b557, 0x82acf043122f6, 0x82ad1133856e3,
+    0x82ae23b1f079a, 0x82afc4ecf9954, 0x82b399c89a390, 0x82b499fa5376e,
+    0x82b4b741d6596, 0x82b5166a3fb98, 0x82b7eebd177bb, 0x82bd43e34dfc4,
+    0x82bdd648dc68c, 0x82bf618e435a8, 0x82bfa36f29d82, 0x82c02730f6d36,
+    0x82c0cbe3370d7, 0x82c4a0bed7b13, 0x82c8becd3f8b3, 0x82c9637f7fc54,
+    0x82c9dfef6c07e, 0x82cd9031a9108, 0x82ceb8a5b645d, 0x82cf64a9d7388,
+    0x82cf76f689061, 0x82d44facd3440, 0x82d5d3a0597d2, 0x82d7872814665,
+    0x82d86dbb3b1e0, 0x82d98039a6297, 0x82dc28f8493b9, 0x82dceaf20c582,
+    0x82df7dbb0d406, 0x82e1090074322, 0x82e1b5049524d, 0x82e42a86132a9,
+    0x82e9a445ad464, 0x82e9a7ee9da29, 0x82eb20e752c6c, 0x82ec3365bdd23,
+    0x82ec83ea65c11, 0x82edc5fd057c9, 0x82edd849b74a2, 0x82efca096854a,
+    0x82f104ca27578, 0x82f16eed61cc9, 0x82f4907300c50, 0x82f49b6dd1d9f,
+    0x82f5ec243306b, 0x82f67ae0d116e, 0x82f74b7e55a4b, 0x82f7651ce82ae,
+    0x82f7b94a80761, 0x82f814c9f979e, 0x82f940e6f70b8, 0x82faff698309a,
+    0x82fcd78aa18df, 0x82fd280f497cd, 0x82fda828261bc, 0x82fec5a1623c2,
+    0x83016e60054e4, 0x8301b3e9dc283, 0x8301c28d9d997, 0x83040c23d74b7,
+    0x8304380f1b9f3, 0x83045ca87f3a5, 0x8304c322c9531, 0x8304d56f7b20a,
+    0x8305a2640f522, 0x8305a9b5f00ac, 0x8305bfab9234a, 0x8308f726d356f,
+    0x830ac0a4306a0, 0x830c18ac724f6, 0x830c3d45d5ea8, 0x830c4be9975bc,
+    0x830ccfab64570, 0x830fc545befbb, 0x831069f7ff35c, 0x83107c44b1035,
+    0x8310ba7ca724a, 0x831115fc20287, 0x8311926c0c6b1, 0x8314427c9035d,
+    0x8314f97b823d7, 0x8316d8ee817a6, 0x8317297329694, 0x83178c448325b,
+    0x8318fdeb57914, 0x8319e47e7e48f, 0x831cd2c6f8350, 0x831d47e503bf0,
+    0x831daab65d7b7, 0x8320ed2c6fb2b, 0x83247c7e397c8, 0x8324a4c08d73f,
+    0x8325c239c9945, 0x83267938bb9bf, 0x8327aca799e63, 0x8329b0b3fcbe4,
+    0x832ac33267c9b, 0x832d26673401e, 0x8330e1a4421f7, 0x8331c83768d72,
+    0x83342b6c350f5, 0x83349ce1503d0, 0x8335f4e992226, 0x83366a079dac6,
+    0x83370767fd2dd, 0x83373aa5223a3, 0x83375f3e85d55, 0x8337babdfed92,
+    0x833c8c22685e7, 0x833d8ffd11f8a, 0x833ead764e190, 0x833f7a6ae24a8,
+    0x8340a687dfdc2, 0x8341737c740da, 0x8341c7aa0c58d, 0x8346990e75de2,
+    0x83469cb7663a7, 0x8348fc4342165, 0x8349073e132b4, 0x834ac5c09f296,
+    0x834c0f251f9d8, 0x834c8f3dfc3c7, 0x834d797a13507, 0x834d7d2303acc,
+    0x834eb7e3c2afa, 0x834f8881473d7, 0x83504a7b0a5a0, 0x8351310e3111b,
+    0x835160a265c1c, 0x83554421c7d6c, 0x8356619b03f72, 0x8356cbbe3e6c3,
+    0x83572e8f9828a, 0x83579509e2416, 0x8359f0eccdc0f, 0x835c3a830772f,
+    0x835dbacd9d4fc, 0x835f0b83fe7c8, 0x836100ec9fe35, 0x836488ec88f48,
+    0x836afb8bfb957, 0x836b39c3f1b6c, 0x836b5e5d5551e, 0x836c2efad9dfb,
+    0x836c782da115f, 0x836d5ec0c7cda, 0x836d994fcd92a, 0x836f36e1e651f,
+    0x83716e2b6e366, 0x837455220769d, 0x83774e65526ad, 0x837776a7a6624,
+    0x837d0a05d3042, 0x837fe9aa8b7ef, 0x8384ee4c1a10a, 0x83850f3c8d4f7,
+    0x838580b1a87d2, 0x83865ff2ee7c3, 0x8389d5a625bfd, 0x838c59cb6536d,
+    0x838ccb4080648, 0x838d1bc528536, 0x838f77a813d2f, 0x8393af550e332,
+    0x8395ac0f90529, 0x8395bab351c3d, 0x83963acc2e62c, 0x8396a146787b8,
+    0x8396d82c8de43, 0x839712bb93a93, 0x8398dc38f0bc4, 0x839bad39e7c5d,
+    0x839c9b1eef362, 0x839d312d6dfef, 0x839d437a1fcc8, 0x839ebc72d4f0b,
+    0x839fb8fb9dd24, 0x83a1b5b61ff1b, 0x83a3ecffa7d62, 0x83a7b3378708a,
+    0x83acf9b9fc17f, 0x83ad04b4cd2ce, 0x83adc6ae90497, 0x83af016f4f4c5,
+    0x83af81882beb4, 0x83b10ccd92dd0, 0x83b1141f7395a, 0x83b1aa2df25e7,
+    0x83b527330a5ab, 0x83b5740ec1ed4, 0x83b594ff352c1, 0x83b5fb797f44d,
+    0x83bc7cbcb3570, 0x83bd007e80524, 0x83be1df7bc72a, 0x83c08c2759bfc,
+    0x83c72e5b0110c, 0x83c8c8442973c, 0x83cbcc824589b, 0x83cc48f231cc5,
+    0x83ce8b368ac5b, 0x83cfb3aa97fb0, 0x83d634edcc0d3, 0x83d64ae36e371,
+    0x83d738c875a76, 0x83d7ade681316, 0x83dca03b5df58, 0x83dcc12bd1345,
+    0x83dfe2b1702cc, 0x83e015ee95392, 0x83e0507d9afe2, 0x83e0baa0d5733,
+    0x83e0cced8740c, 0x83e17548b7d72, 0x83e558c819ec2, 0x83e68c36f8366,
+    0x83e69388d8ef0, 0x83e6ad276b753, 0x83e7b102150f6, 0x83e917ae18660,
+    0x83e9a66ab6763, 0x83ec2a8ff5ed3, 0x83ecb94c93fd6, 0x83ecda3d073c3,
+    0x83ef8df67b634, 0x83f0205c09cfc, 0x83f1f87d28541, 0x83f7a579e77c2,
+    0x83f7eb03be561, 0x83f95558b2090, 0x83fcd9afaabde, 0x83fcf6f72da06,
+    0x83fec7c66b6c1, 0x83fee50dee4e9, 0x83ff14a222fea, 0x83ff73ca8c5ec,
+    0x8404dee665093, 0x84058aea85fbe, 0x840916935f696, 0x840928e01136f,
+    0x840a58a5ff24e, 0x840c3f6adf1a7, 0x840c72a80426d, 0x840f1f0f97954,
+    0x84116ff7b1ffe, 0x8414b9bfa4efc, 0x841570be96f76, 0x8418d4251c6d7,
+    0x8419cd04f4f2b, 0x84236fcdc7fd5, 0x842568df59c07, 0x842577831b31b,
+    0x84275e47fb274, 0x84284884123b4, 0x8428537ee3503, 0x842870c66632b,
+    0x84298344d13e2, 0x842c5445c847b, 0x842c6a3b6a719, 0x842d71bf04681,
+    0x842f75cb67402, 0x843060077e542, 0x8433818d1d4c9, 0x8434682044044,
+    0x8434dd3e4f8e4, 0x8434fa85d270c, 0x84351b7645af9, 0x84362a4bc05eb,
+    0x8436c4032f83d, 0x84371487d772b, 0x84375668bdf05, 0x84378d4ed3590,
+    0x8445089c3763b, 0x84469038adf92, 0x8447906a67370, 0x844926aa9f3db,
+    0x844e4893b0b1e, 0x844f48c569efc, 0x844faf3fb4088, 0x844fe625c9713,
+    0x84513e2e0b569, 0x8451f52cfd5e3, 0x84553b4bfff1c, 0x84557983f6131,
+    0x8459e46e157fa, 0x845ae0f6de613, 0x845c7e88f7208, 0x845d68c50e348,
+    0x845d73bfdf497, 0x845dbcf2a67fb, 0x845e2abed1511, 0x845f98bcb5605,
+    0x845fcbf9da6cb, 0x845fecea4dab8, 0x8460c882a34e4, 0x8460dacf551bd,
+    0x8461ed4dc0274, 0x846483bfb16bd, 0x846580487a4d6, 0x8465b3859f59c,
+    0x8466b00e683b5, 0x84673ecb064b8, 0x8467cd87a45bb, 0x846854f261b34,
+    0x8468b41acb136, 0x84693b85886af, 0x846a2d1380379, 0x846b851bc21cf,
+    0x846c76a9b9e99, 0x846df6f44fc66, 0x847619728cf43, 0x8476873eb7c59,
+    0x8477c5a86724c, 0x84783e6f630b1, 0x8478d826d2303, 0x847db82efd26c,
+    0x847deb6c22332, 0x847ee7f4eb14b, 0x847f262ce1360, 0x84810cf1c12b9,
+    0x84845a62a477c, 0x84849fec7b51b, 0x84859523637aa, 0x848670bbb91d6,
+    0x8488e9e6277f7, 0x8489e66ef0610, 0x848af5446b102, 0x848d6ac5e915e,
+    0x848d80bb8b3fc, 0x848f468ff7f68, 0x8490bf88ad1ab, 0x8495f3be705c7,
+    0x849652e6d9bc9, 0x8497a39d3ae95, 0x8497e57e2166f, 0x8497f7cad3348,
+    0x84984bf86b7fb, 0x8498693fee623, 0x84992ee2a1db1, 0x849cfc6c61c63,
+    0x849e00470b606, 0x84a1082e17d2a, 0x84a17d4c235ca, 0x84a44e4d1a663,
+    0x84a4977fe19c7, 0x84a4bfc23593e, 0x84a53889317a3, 0x84a652597d3e4,
+    0x84a71f4e116fc, 0x84a947f3d7e2f, 0x84ad2421593f5, 0x84ad366e0b0ce,
+    0x84afd431dd0a1, 0x84b458ba8efcd, 0x84b48fa0a4658, 0x84b4d52a7b3f7,
+    0x84b651cc20bff, 0x84bd7b6a85688, 0x84bee21688bf2, 0x84c4d0f42e64d,
+    0x84c4d8460f1d7, 0x84c8b0caa01d8, 0x84c9557ce0579, 0x84cb891d77dfb,
+    0x84cc610cdd262, 0x84ccc787273ee, 0x84cdbcbe0f67d, 0x84cdc7b8e07cc,
+    0x84cec7ea99baa, 0x84cedde03be48, 0x84ceec83fd55c, 0x84d1e5c74856c,
+    0x84d2c5088e55d, 0x84d3994f033ff, 0x84d47c39399b5, 0x84d5a10456745,
+    0x84d6420da6521, 0x84d6754acb5e7, 0x84d7966cf7db2, 0x84d8b3e633fb8,
+    0x84dc816ff3e6a, 0x84de8925471b0, 0x84dee84db07b2, 0x84e7246a802f2,
+    0x84e75ef985f42, 0x84e945be65e9b, 0x84e996430dd89, 0x84ed93610273c,
+    0x84edd8ead94db, 0x84f3feae945c1, 0x84f4fb375d3da, 0x84f5e5737451a,
+    0x84f79ca41f972, 0x84f94531096b6, 0x84f9b2fd343cc, 0x84fd4d49cf1b8,
+    0x84fed4e645b0f, 0x84ff674bd41d7, 0x84ff933718713, 0x84ffbb796c68a,
+    0x8500a20c93205, 0x8500e3ed799df, 0x8504efaf2faa6, 0x85053c8ae73cf,
+    0x850d2f74efbab, 0x850d6a03f57fb, 0x850ffcccf667f, 0x8514b0e9dd0ac,
+    0x8515434f6b774, 0x8515d5b4f9e3c, 0x85165224e6266, 0x851676be49c18,
+    0x851b531d845bc, 0x851fa0c020e5d, 0x85203a77900af, 0x8523e367ec5af,
+    0x852449e23673b, 0x8524f23d670a1, 0x85250f84e9ec9, 0x85259e4187fcc,
+    0x8526170883e31, 0x8526b468e3648, 0x8526ce0775eab, 0x852cb93c2b341,
+    0x852d35ac1776b, 0x852e06499c048, 0x8534803aef5e1, 0x853700b73e78c,
+    0x8539d56125dea, 0x853c8cc38a620, 0x853d267af9872, 0x853f73ba23957,
+    0x8540a72901dfb, 0x8540b5ccc350f, 0x8543fbebc5e48, 0x85453dfe65a00,
+    0x8547ee0ee96ac, 0x854c5ca1f933a, 0x854df2e2313a5, 0x855097f7e3f02,
+    0x85519f7b7de6a, 0x8554916ce82f0, 0x85550a33e4155, 0x8555f46ffb295,
+    0x8557ed818cec7, 0x8558ea0a55ce0, 0x855cf2231b7e2, 0x8560642d62657,
+    0x8561b88cb3ee8, 0x8561e477f8424, 0x85636c146ed7b, 0x85644efea5331,
+    0x8564e8b614583, 0x8569d3b91063b, 0x856e6a8e74240, 0x856e9679b877c,
+    0x856f378308558, 0x856f75bafe76d, 0x85703062e0dac, 0x857184c23263d,
+    0x8574680fdb3af, 0x85755d46c363e, 0x8576e13a499d0, 0x8577d67131c5f,
+    0x857c0a753bc9d, 0x857c833c37b02, 0x857da0b573d08, 0x857eba85bf949,
+    0x8585b838dfe96, 0x8586b4c1a8caf, 0x85876f698b2ee, 0x858789081db51,
+    0x8587d23ae4eb5, 0x858859a5a242e, 0x8588908bb7ab9, 0x8588d26c9e293,
+    0x858c2ad8528a5, 0x858ec14a43cee, 0x85940f1e9996d, 0x859412c789f32,
+    0x8594b027e9749, 0x8594f208cff23, 0x8599818c52f9e, 0x859c981720dd6,
+    0x859cf73f8a3d8, 0x859d40725173c, 0x859f9ffe2d4fa, 0x85a1cc4ce41f2,
+    0x85a67cc0da65a, 0x85a792e835cd6, 0x85a8379a76077, 0x85a9600e833cc,
+    0x85a976042566a, 0x85a9eecb214cf, 0x85abc3434f74f, 0x85ae64b011ce7,
+    0x85af59e6f9f76, 0x85af82294deed, 0x85afb90f63578, 0x85b0ae464b807,
+    0x85b15a4a6c732, 0x85b4623178e56, 0x85b4cffda3b6c, 0x85b54c6d8ff96,
+    0x85b7f52c330b8, 0x85bf93e8a33e1, 0x85c07329e93d2, 0x85c0941a5c7bf,
+    0x85c0a6670e498, 0x85c2f3a63857d, 0x85c502ad6c44d, 0x85c52746cfdff,
+    0x85c5d6f3e12ef, 0x85c7e9a405784, 0x85cd6ab5804c9, 0x85ce0815dfce0,
+    0x85ce882ebc6cf, 0x85cf467f8f2d3, 0x85d5e50a4621e, 0x85d898c3ba48f,
+    0x85d94fc2ac509, 0x85dc62a489d7c, 0x85ddc5a79cd21, 0x85de19d5351d4,
+    0x85df3af76199f, 0x85e242de6e0c3, 0x85e8fb07b7871, 0x85ec9ca6331e7,
+    0x85edaf249e29e, 0x85eedeea8c17d, 0x85f0668702ad4, 0x85f44daf551e9,
+    0x85f730fcfdf5b, 0x85f83c2988488, 0x85f9e10d81c07, 0x86015035bd42f,
+    0x8602874d8be98, 0x86054601d1258, 0x8605ac7c1b3e4, 0x8607a9369d5db,
+    0x8608a5bf663f4, 0x860c400c011e0, 0x860c60fc745cd, 0x860d38ebd9a34,
+    0x860f394f4c1f0, 0x860f3cf83c7b5, 0x860f444a1d33f, 0x8611a77ee96c2,
+    0x8614b6b7d6970, 0x8614e64c0b471, 0x8614ed9debffb, 0x86158ea73bdd7,
+    0x86175bcd894cd, 0x8617b74d0250a, 0x86187cefb5c98, 0x8618968e484fb,
+    0x86193045b774d, 0x861d3fb05ddd9, 0x861ee0eb66f93, 0x8625f0eb391b9,
+    0x8627f4f79bf3a, 0x862cc2b3151ca, 0x862d083cebf69, 0x862f3f8673db0,
+    0x8631f6e8d85e6, 0x86343cd621b41, 0x8634da3681358, 0x86373d6b4d6db,
+    0x8637a3e597867, 0x8638ab69317cf, 0x863c1278a74f5, 0x863edc27bda04,
+    0x864058c96320c, 0x8641e065d9b63, 0x8644a66bffaad, 0x8644cb056345f,
+    0x864661459b4ca, 0x8646b5733397d, 0x86477ebed76d0, 0x864d0acb23564,
+    0x864d8e8cf0518, 0x864f162966e6f, 0x8659898fbe7f6, 0x865cc4b3effe0,
+    0x865d20336901d, 0x865e7be49b438, 0x86631db4d018c, 0x8663424e33b3e,
+    0x8664a1a85651e, 0x8666fd8b41d17, 0x86675cb3ab319, 0x8668d2036ff97,
+    0x86698cab525d6, 0x8669ef7cac19d, 0x866b7e6b0367e, 0x866c4f0887f5b,
+    0x866ce16e16623, 0x866d7ece75e3a, 0x866df3ec816da, 0x86723de62d9b6,
+    0x867549762a69f, 0x8675f57a4b5ca, 0x86773b35db747, 0x86774287bc2d1,
+    0x867916ffea551, 0x8679b8093a32d, 0x8679c6acfba41, 0x867f3571c4aad,
+    0x868035a37de8b, 0x86806c8993516, 0x8681c83ac5931, 0x86858377d3b0a,
+    0x8686ba8fa2573, 0x868bd17de2b67, 0x86966d268e465, 0x86970a86edc7c,
+    0x86992f83c3dea, 0x869cd874202ea, 0x869dd4fce9103, 0x86a09eabff612,
+    0x86a160a5c27db, 0x86a1d96cbe640, 0x86a52334b153e, 0x86a67b3cf3394,
+    0x86a7c0f883511, 0x86a8b62f6b7a0, 0x86a9a7bd6346a, 0x86acfc80274b7,
+    0x86af87f7477b1, 0x86b11a8e8f257, 0x86b50908c24f6, 0x86b6944e29412,
+    0x86bc7830fdd1e, 0x86be9984e38c7, 0x86beea098b7b5, 0x86c0e6c40d9ac,
+    0x86c1b00fb16ff, 0x86c44a2a9310d, 0x86c713d9a961c, 0x86c78ca0a5481,
+    0x86c8bc6693360, 0x86cd742c6a352, 0x86d9bfb3e051e, 0x86d9fdebd6733,
+    0x86dcf72f21743, 0x86e04848f51cb, 0x86e0ecfb3556c, 0x86eb6f054e607,
+    0x86ed7a6391f12, 0x86ee908aed58e, 0x86ef896ac5de2, 0x86f15a3a03a9d,
+    0x86f3f0abf4ee6, 0x86f511ce216b1, 0x86f7038dd2759, 0x86f750698a082,
+    0x86fb80c4a3afb, 0x86ffb4c8adb39, 0x8705ff25cc5d1, 0x8707154d27c4d,
+    0x8708416a25567, 0x8708f869175e1, 0x87097c2ae4595, 0x8709a46d3850c,
+    0x870efd3c5f2da, 0x87148948ab16e, 0x87196cf9c669c, 0x871c8e7f65623,
+    0x871cf4f9af7af, 0x871dc5973408c, 0x871fd84758521, 0x87235c9e5106f,
+    0x8724bbf873a4f, 0x872538685fe79, 0x872970155a47c, 0x8729fb2907fba,
+    0x8731f564f1320, 0x8738b1372b093, 0x873cdde954547, 0x873ea766b1678,
+    0x8741d3e72174e, 0x87451daf1464c, 0x874925c7da14e, 0x87499ae5e59ee,
+    0x874cf6fa8a5c5, 0x874eddbf6a51e, 0x874f7024f8be6, 0x874fb5aecf985,
+    0x875123acb3a79, 0x8751daaba5af3, 0x8755e66d5bbba, 0x875bd54b01615,
+    0x875cf66d2dde0, 0x875da9c32f895, 0x875ece8e4c625, 0x8765a7a8091c0,
+    0x8765b2a2da30f, 0x8765ff7e91c38, 0x876657551a6b0, 0x876665f8dbdc4,
+    0x8767cca4df32e, 0x8768edc70baf9, 0x876b7ce71c3b8, 0x8770ebabe5424,
+    0x877152262f5b0, 0x8774c08785e60, 0x8776b5f0274cd, 0x8777d369636d3,
+    0x877991ebef6b5, 0x8779a08fb0dc9, 0x8779cc7af5305, 0x877fb7afaa79b,
+    0x87805167199ed, 0x8781f9f403731, 0x87849f09b628e, 0x87867e7cb565d,
+    0x87869bc438485, 0x8789ff2abdbe6, 0x878b5732ffa3c, 0x878efc7a6b977,
+    0x8791b785c0772, 0x8794972a78f1f, 0x8794d90b5f6f9, 0x8799404c8e7fd,
+    0x879c69240e30e, 0x87a1c94515c66, 0x87a475aca934d, 0x87a763f52320e,
+    0x87a784e5965fb, 0x87a79e8428e5e, 0x87a830e9b7526, 0x87a846df597c4,
+    0x87a8d944e7e8c, 0x87ad2e39652b7, 0x87ae9fe039970, 0x87af18a7357d5,
+    0x87b1414cfbf08, 0x87b1783311593, 0x87b712e31eb3b, 0x87b85c479f27d,
+    0x87b90f9da0d32, 0x87c194ed37bd6, 0x87c4fbfcad8fc, 0x87c7334635743,
+    0x87c83e72bfc70, 0x87c907be639c3, 0x87cb0bcac6744, 0x87ccf28fa669d,
+    0x87d0a67ad3cec, 0x87d461b7e1ec5, 0x87dc71e96d4c9, 0x87dc87df0f767,
+    0x87dcf20249eb8, 0x87dd462fe236b, 0x87de9e38241c1, 0x87df3f4173f9d,
+    0x87e4c3fbdf2a7, 0x87e6bd0d70ed9, 0x87ec4570cc7a8, 0x87ef042511b68,
+    0x87efa1857137f, 0x87f0b403dc436, 0x87f51eedfbaff, 0x87f5856845c8b,
+    0x87fa98ad95cba, 0x87fd500ffa4f0, 0x87fddecc985f3, 0x87ff8007a17ad,
+    0x8800d466f303e, 0x8801bea30a17e, 0x8801e33c6db30, 0x880563ea760b9,
+    0x880655786dd83, 0x880705257f273, 0x8807c71f4243c, 0x880c89dfea57d,
+    0x880f65dbb2765, 0x880fdea2ae5ca, 0x880ff0ef602a3, 0x88110e689c4a9,
+    0x881195d359a22, 0x881471cf21c0a, 0x8814c9a5aa682, 0x881533c8e4dd3,
+    0x8819174846f23, 0x881d31adbe6fe, 0x881f9c346b60b, 0x881fff05c51d2,
+    0x882170ac9988b, 0x882366153aef8, 0x88251d45e6350, 0x8826a88b4d26c,
+    0x8828670dd924e, 0x8829c2bf0b669, 0x8829eb015f5e0, 0x882ca60cb43db,
+    0x882e2da92ad32, 0x882f9f4fff3eb, 0x8830b1ce6a4a2, 0x883189bdcf909,
+    0x8835748f125e3, 0x8835992875f95, 0x8837f162711c9, 0x88390037ebcbb,
+    0x8839f56ed3f4a, 0x883df635b8ec2, 0x883f29a497366, 0x88419f26153c2,
+    0x8847e231532d0, 0x88488e35741fb, 0x8848ba20b8737, 0x8849418b75cb0,
+    0x8849502f373c4, 0x884978718b33b, 0x88497fc36bec5, 0x8849deebd54c7,
+    0x884afc65116cd, 0x884b0eb1c33a6, 0x884c71b4d634b, 0x884d0b6c4559d,
+    0x884dc26b37617, 0x884df1ff6c118, 0x8851939de7a8e, 0x8855ec3b5547e,
+    0x8856a33a474f8, 0x8856f3beef3e6, 0x885afbd7b4ee8, 0x885eafc2e2537,
+    0x886050fdeb6f1, 0x8863ce03036b5, 0x8865818abe548, 0x88697004f17e7,
+    0x886d572d43efc, 0x88735105baaa6, 0x8875fd6d4e18d, 0x88761e5dc157a,
+    0x8876345363818, 0x8876d905a3bb9, 0x8877382e0d1bb, 0x8877900495c33,
+    0x8885ee3c30294, 0x888754e8337fe, 0x8887f24893015, 0x888be0c2c62b4,
+    0x888ddd7d484ab, 0x888efaf6846b1, 0x888f6519bee02, 0x88913d3add647,
+    0x8893fbef22a07, 0x889448cada330, 0x8894ed7d1a6d1, 0x88971d74c198e,
+    0x8898c258bb10d, 0x88991686535c0, 0x889b21e496ecb, 0x889ba94f54444,
+    0x889cfa05b5710, 0x889df2e58df64, 0x889eb888416f2, 0x889f4aedcfdba,
+    0x88a3e1c3339bf, 0x88a73a2ee7fd1, 0x88a96dcf7f853, 0x88ac33d5a579d,
+    0x88afb483add26, 0x88afc3276f43a, 0x88b0852132603, 0x88b11034e0141,
+    0x88b2e1041ddfc, 0x88b2efa7df510, 0x88b5408ff9bba, 0x88b694ef4b44b,
+    0x88b6bd319f3c2, 0x88be5f96ffcb0, 0x88be9dcef5ec5, 0x88bfd13dd4369,
+    0x88c1f9e39aa9c, 0x88c3ad6b5592f, 0x88c48903ab35b, 0x88c53507cc286,
+    0x88c85a365b7d2, 0x88c8f044da45f, 0x88c9de29e1b64, 0x88ccbdce9a311,
+    0x88ceedc6415ce, 0x88cf92788196f, 0x88cfea4f0a3e7, 0x88cff549db536,
+    0x88d08b585a1c3, 0x88d64e4abb6e2, 0x88d69ecf635d0, 0x88d797af3be24,
+    0x88d864a3d013c, 0x88d99f648f16a, 0x88dc98a7da17a, 0x88dd0dc5e5a1a,
+    0x88de95625c371, 0x88def48ac5973, 0x88e6bb8989c13, 0x88eabff95f150,
+    0x88eda34707ec2, 0x88f1204c1fe86, 0x88f1488e73dfd, 0x88f4c93c7c386,
+    0x88f55f4afb013, 0x88f67cc437219, 0x88f6d843b0256, 0x88f813046f284,
+    0x88f9ca351a6dc, 0x88fb5ccc62182, 0x88fd7e2047d2b, 0x88ff2dff125f9,
+    0x88fff3a1c5d87, 0x89012367b3c66, 0x89012ab9947f0, 0x8902ab042a5bd,
+    0x8904f49a640dd, 0x89080ece224da, 0x8909b0092b694, 0x890b96ce0b5ed,
+    0x890d93888d7e4, 0x890dbf73d1d20, 0x890fbc2e53f17, 0x8910732d45f91,
+    0x8911526e8bf82, 0x8913bcf538e8f, 0x8916954810ab2, 0x8916b63883e9f,
+    0x891c792ae53be, 0x891c7cd3d5983, 0x891ca16d39335, 0x891d5fbe0bf39,
+    0x891de37fd8eed, 0x89203f62c46e6, 0x89205caa4750e, 0x8924aa4ce3daf,
+    0x8924fe7a7c262, 0x892505cc5cdec, 0x8925a32cbc603, 0x892626ee895b7,
+    0x892c19751f5d7, 0x892c74f498614, 0x892d284a9a0c9, 0x892d493b0d4b6,
+    0x892d717d6142d, 0x8934fded1fa7d, 0x89356bb94a793, 0x8935ef7b17747,
+    0x8937094b63388, 0x8937c04a55402, 0x8937f3877a4c8, 0x8939c0adc7bbe,
+    0x893cab4d514ba, 0x893d9589685fa, 0x8940543dad9ba, 0x8940f546fd796,
+    0x8941c23b91aae, 0x8944fd5fc3298, 0x8949c7724bf63, 0x894e116bf823f,
+    0x894e44a91d305, 0x894fd397747e6, 0x894fdae955370, 0x8952abea4c409,
+    0x89538782a1e35, 0x8954b39f9f74f, 0x8954c5ec51428, 0x89551670f9316,
+    0x8956eae927596, 0x89571a7d5c097, 0x8958e3fab91c8, 0x8959c6e4ef77e,
+    0x895fd30a18001, 0x8960de36a252e, 0x8961fbafde734, 0x8969a1be2f5e7,
+    0x896d978a43410, 0x897857cc526c0, 0x8978a4a809fe9, 0x897ee7b347ef7,
+    0x89809ee3f334f, 0x898117aaef1b4, 0x8986d34b6fb49, 0x898aea07f6d5f,
+    0x8996cb6c327da, 0x8996d2be13364, 0x899cbdf2c87fa, 0x899f3e6f179a5,
+    0x899fdbcf771bc, 0x89a4012fbfae6, 0x89a41ace52349, 0x89a559380193c,
+    0x89a5852345e78, 0x89a5fdea41cdd, 0x89a9ec6474f7c, 0x89ab989a4f285,
+    0x89ac917a27ad9, 0x89ad2b3196d2b, 0x89afbdfa97baf, 0x89b08aef2bec7,
+    0x89b5883ed9c58, 0x89b6b804c7b37, 0x89b85596e072c, 0x89b8768753b19,
+    0x89b8b8683a2f3, 0x89b913e7b3330, 0x89bc0d2afe340, 0x89bfc8680c519,
+    0x89bfd362dd668, 0x89c08e0abfca7, 0x89c7463409455, 0x89c84db7a33bd,
+    0x89c9344ac9f38, 0x89cd4b075114e, 0x89d0653b0f54b, 0x89d6abef3da1e,
+    0x89d7a12625cad, 0x89d7d80c3b338, 0x89d9c622fbe1b, 0x89d9d86fadaf4,
+    0x89e126a775f2f, 0x89e168885c709, 0x89e2cb8b6f6ae, 0x89e5c125ca0f9,
+    0x89e8120de47a3, 0x89f51be62d573, 0x89f5b9468cd8a, 0x89f709fcee056,
+    0x89f82b1f1a821, 0x89f844bdad084, 0x89f898eb45537, 0x89f96988c9e14,
+    0x89f98e222d7c6, 0x8a005fea097d7, 0x8a01eb2f706f3, 0x8a0397654a9fc,
+    0x8a0493ee13815, 0x8a059076dc62e, 0x8a063c7afd559, 0x8a07c7c064475,
+    0x8a0956aebb956, 0x8a0ad6f951723, 0x8a0b18da37efd, 0x8a0c6d398978e,
+    0x8a0daf4c29346, 0x8a0e8e8d6f337, 0x8a141a99bb1cb, 0x8a166f2ac5e3a,
+    0x8a19c3ed89e87, 0x8a1bd69dae31c, 0x8a1c4f64aa181, 0x8a1c77a6fe0f8,
+    0x8a1d2ea5f0172, 0x8a1eff752de2d, 0x8a1f70ea49108, 0x8a21c57b53d77,
+    0x8a235bbb8bde2, 0x8a23c987b6af8, 0x8a2445f7a2f22, 0x8a2580b861f50,
+    0x8a28a98fe1a61, 0x8a2cb1a8a7563, 0x8a2d5a03d7ec9, 0x8a2f09e2a2797,
+    0x8a2fa74301fae, 0x8a317bbb3022e, 0x8a398843cb26d, 0x8a39f267059be,
+    0x8a3bfdc5492c9, 0x8a3dfa7fcb4c0, 0x8a3f403b5b63d, 0x8a3f737880703,
+    0x8a4457299bc31, 0x8a45b683be611, 0x8a46f4ed6dc04, 0x8a4775064a5f3,
+    0x8a4980648defe, 0x8a49dbe406f3b, 0x8a4cca2c80dfc, 0x8a4ddcaaebeb3,
+    0x8a4e382a64ef0, 0x8a4e7a0b4b6ca, 0x8a5cb3a982379, 0x8a5e045fe3645,
+    0x8a6030ae9a33d, 0x8a63f33d890a0, 0x8a64dd79a01e0, 0x8a674ba93d6b2,
+    0x8a69f467e07d4, 0x8a6e7547a213b, 0x8a6f19f9e24dc, 0x8a74d94353436,
+    0x8a74f2e1e5c99, 0x8a7567fff1539, 0x8a794b7f53689, 0x8a7eba441c6f5,
+    0x8a7f66483d620, 0x8a812fc59a751, 0x8a85d8e7b002f, 0x8a87fa3b95bd8,
+    0x8a88d97cdbbc9, 0x8a8976dd3b3e0, 0x8a89ceb3c3e58, 0x8a8d9fe6742cf,
+    0x8a8eb9b6bff10, 0x8a949647b3c92, 0x8a94be8a07c09, 0x8a94cd2dc931d,
+    0x8a964d785f0ea, 0x8a9780e73d58e, 0x8a9821f08d36a, 0x8a993bc0d8fab,
+    0x8a998c4580e99, 0x8a9d1f403b0fb, 0x8a9dd9e81d73a, 0x8a9ecb7615404,
+    0x8aa06cb11e5be, 0x8aa16939e73d7, 0x8aa47872d4685, 0x8aa68028279cb,
+    0x8aa7128db6093, 0x8aa787abc1933, 0x8aa96ac7b12c7, 0x8aa9bef54977a,
+    0x8aaefe25ddce5, 0x8ab1615aaa068, 0x8ab6207261be4, 0x8ab77c2393fff,
+    0x8ab98b2ac7ecf, 0x8abdbb85e1948, 0x8abf6b64ac216, 0x8ac0c36cee06c,
+    0x8ac1e0e62a272, 0x8ac45667a82ce, 0x8ac4b1e72130b, 0x8ac5e8feefd74,
+    0x8ac661c5ebbd9, 0x8ac94c65754d5, 0x8acc57f5721be, 0x8ace8f3efa005,
+    0x8acfa56655681, 0x8ad037cbe3d49, 0x8ad6dda87b81e, 0x8ad8d6ba0d450,
+    0x8ad977c35d22c, 0x8ad9ff2e1a7a5, 0x8adc9948fc1b3, 0x8ae06a7bac62a,
+    0x8ae192efb997f, 0x8ae1b7891d331, 0x8ae3f9cd762c7, 0x8ae4bbc739490,
+    0x8ae781cd5f3da, 0x8ae9529c9d095, 0x8af069ee4fe45, 0x8af500c3b3a4a,
+    0x8af56ae6ee19b, 0x8af7f2b51ded0, 0x8af9a63cd8d63, 0x8afcc41987725,
+    0x8b0115651458b, 0x8b048ec13bf8a, 0x8b0571ab72540, 0x8b0599edc64b7,
+    0x8b08f9ab5b653, 0x8b0cfa72405cb, 0x8b0dcb0fc4ea8, 0x8b1094bedb3b7,
+    0x8b149585c032f, 0x8b15836ac7a34, 0x8b169d3b13675, 0x8b1966ea29b84,
+    0x8b2076e9fbdaa, 0x8b23e54b5265a, 0x8b24e925fbffd, 0x8b25b2719fd50,
+    0x8b2732bc35b1d, 0x8b2815a66c0d3, 0x8b293a7188e63, 0x8b2d0f4d2989f,
+    0x8b2d28ebbc102, 0x8b2deae57f2cb, 0x8b315946d5b7b, 0x8b319b27bc355,
+    0x8b34d64bedb3f, 0x8b36de0140e85, 0x8b39c4f7da1bc, 0x8b433f7e592ef,
+    0x8b44ebb4335f8, 0x8b45313e0a397, 0x8b45dd422b2c2, 0x8b476887921de,
+    0x8b48984d800bd, 0x8b48b93df34aa, 0x8b49270a1e1c0, 0x8b49cbbc5e561,
+    0x8b4c7823f1c48, 0x8b4ce2472c399, 0x8b4f1d39a47a5, 0x8b4fbe42f4581,
+    0x8b5458c14874b, 0x8b552d07bd5ed, 0x8b564e29e9db8, 0x8b59b5395fade,
+    0x8b5cda67ef02a, 0x8b5eb2890d86f, 0x8b5f1cac47fc0, 0x8b65c9dac061f,
+    0x8b68a97f78dcc, 0x8b69caa1a5597, 0x8b6f2e6b9d4b4, 0x8b6f9c37c81ca,
+    0x8b6fa73299319, 0x8b712b261f6ab, 0x8b716d0705e85, 0x8b717baac7599,
+    0x8b74a82b3766f, 0x8b755f2a296e9, 0x8b76580a01f3d, 0x8b77f1f32a56d,
+    0x8b7954f63d512, 0x8b79b7c7970d9, 0x8b7a6b1d98b8e, 0x8b7b430cfdff5,
+    0x8b7c8c717e737, 0x8b85156a05ba0, 0x8b8544fe3a6a1, 0x8b890b36199c9,
+    0x8b8e0fd7a82e4, 0x8b8f51ea47e9c, 0x8b945a34c6d7c, 0x8b967436cbd9b,
+    0x8b983db428ecc, 0x8b990356dc65a, 0x8b993de5e22aa, 0x8b9ccd37abf47,
+    0x8b9df5abb929c, 0x8ba13bcabbbd5, 0x8ba41b6f74382, 0x8ba44b03a8e83,
+    0x8ba459a76a597, 0x8ba8f07cce19c, 0x8ba96cecba5c6, 0x8ba9b27691365,
+    0x8ba9c86c33603, 0x8bacf4eca36d9, 0x8baee6ac54781, 0x8baf19e979847,
+    0x8bb1dc46af1cc, 0x8bbd86c4d55bc, 0x8bc02f83786de, 0x8bc0d08cc84ba,
+    0x8bc41dfdab97d, 0x8bc54dc39985c, 0x8bcea3b0b4fdd, 0x8bd17108bbab1,
+    0x8bd40028cc370, 0x8bd4ee0dd3a75, 0x8bd754eb903bd, 0x8bdc7e268268a,
+    0x8bdd352574704, 0x8bde230a7be09, 0x8be4a0a4bf967, 0x8be5c56fdc6f7,
+    0x8be666792c4d3, 0x8be6c5a195ad5, 0x8be6c94a8609a, 0x8be992f99c5a9,
+    0x8bedfa3acb6ad, 0x8bf0a2f96e7cf, 0x8bf0d28da32d0, 0x8bf39c3cb97df,
+    0x8bf64ff62da50, 0x8bf6b67077bdc, 0x8bf7e63665abb, 0x8bf936ecc6d87,
+    0x8bf9801f8e0eb, 0x8bfca8f70dbfc, 0x8bfcd13961b73, 0x8bfd5c4d0f6b1,
+    0x8bfd883853bed, 0x8bffe0724ee21, 0x8c003bf1c7e5e, 0x8c056183c9b66,
+    0x8c079c7641f72, 0x8c091cc0d7d3f, 0x8c0d3acf3fadf, 0x8c0e21626665a,
+    0x8c0f28e6005c2, 0x8c0fc6465fdd9, 0x8c107d4551e53, 0x8c1080ee42418,
+    0x8c11727c3a0e2, 0x8c11f9e6f765b, 0x8c17356e9b601, 0x8c17983ff51c8,
+    0x8c17a33ac6317, 0x8c183cf235569, 0x8c1ca4336466d, 0x8c1d3a41e32fa,
+    0x8c1dabb6fe5d5, 0x8c20790f050a9, 0x8c20df894f235, 0x8c21843b8f5d6,
+    0x8c25a249f7376, 0x8c272d8f5e292, 0x8c2897e451dc1, 0x8c28a688134d5,
+    0x8c2959de14f8a, 0x8c2ca74ef844d, 0x8c2cbd449a6eb, 0x8c2cde350dad8,
+    0x8c2e572dc2d1b, 0x8c2f5b086c6be, 0x8c37d1b441e4e, 0x8c3967f479eb9,
+    0x8c3ce4f991e7d, 0x8c3d10e4d63b9, 0x8c3e52f775f71, 0x8c411554ab8f6,
+    0x8c41c8aaad3ab, 0x8c4578ecea435, 0x8c48e3a550720, 0x8c4bfdd90eb1d,
+    0x8c4c903e9d1e5, 0x8c4c93e78d7aa, 0x8c4d1efb3b2e8, 0x8c4f9bce99ece,
+    0x8c5135b7c24fe, 0x8c54364cee098, 0x8c554179785c5, 0x8c55d036166c8,
+    0x8c563e02413de, 0x8c596e2ba1a79, 0x8c5bfd4bb2338, 0x8c5ceed9aa002,
+    0x8c5d6b499642c, 0x8c5e2248884a6, 0x8c5f177f70735, 0x8c64d6c8e168f,
+    0x8c652af679b42, 0x8c655a8aae643, 0x8c66aee9ffed4, 0x8c66e97905b24,
+    0x8c67a7c9d8728, 0x8c6e49fd7fc38, 0x8c6fa5aeb2053, 0x8c75e1680f3d7,
+    0x8c78e5a62b536, 0x8c7cba81cbf72, 0x8c7d4ce75a63a, 0x8c7fcd63a97e5,
+    0x8c813066bc78a, 0x8c84593e3c29b, 0x8c8493cd41eeb, 0x8c85437a533db,
+    0x8c870cf7b050c, 0x8c88aa89c9101, 0x8c8b5e433d372, 0x8c8b7f33b075f,
+    0x8c8d9935b577e, 0x8c8dd76dab993, 0x8c8e7876fb76f, 0x8c8f038aa92ad,
+    0x8c8ffc6a81b01, 0x8c95294e64393, 0x8c99400aeb5a9, 0x8c9a9bbc1d9c4,
+    0x8c9bc7d91b2de, 0x8c9fff86158e1, 0x8ca779a922258, 0x8cabfa88e3bbf,
+    0x8cac4eb67c072, 0x8cac7aa1c05ae, 0x8cad020c7db27, 0x8caec08f09b09,
+    0x8caf14bca1fbc, 0x8caf5def69320, 0x8cb0d33f2df9e, 0x8cb7a8affa574,
+    0x8cb83ebe79201, 0x8cb9a1c18c1a6, 0x8cbf0cdd64c4d, 0x8cc5a7bf2b5d3,
+    0x8cc5e24e31223, 0x8cc6db2e09a77, 0x8cc71965ffc8c, 0x8cc72bb2b1965,
+    0x8cc7d40de22cb, 0x8cc8f1871e4d1, 0x8cccca0baf4d2, 0x8ccf7dc523743,
+    0x8cd076a4fbf97, 0x8cd1f346a179f, 0x8cd953cb1b8b3, 0x8cdb2bec3a0f8,
+    0x8cddc6071bb06, 0x8cdf2cb31f070, 0x8ce6aa7f1bfac, 0x8ce969336136c,
+    0x8cecaba9736e0, 0x8ced1223bd86c, 0x8ced4cb2c34bc, 0x8ced62a86575a,
+    0x8cf1845fbdabf, 0x8cf43f6b128ba, 0x8cf45cb2956e2, 0x8cf62d81d339d,
+    0x8d014cec4bc4f, 0x8d01c20a574ef, 0x8d05cdcc0d5b6, 0x8d06d54fa751e,
+    0x8d07ce2f7fd72, 0x8d0834a9c9efe, 0x8d0caa8eba716, 0x8d0db96435208,
+    0x8d10f83156fb7, 0x8d13edcbb1a02, 0x8d14927df1da3, 0x8d14d45ed857d,
+    0x8d15ee2f241be, 0x8d18c681fbde1, 0x8d19b0be12f21, 0x8d1be45eaa7a3,
+    0x8d1d4761bd748, 0x8d1d4eb39e2d2, 0x8d1df365de673, 0x8d1e404195f9c,
+    0x8d1e56373823a, 0x8d1f2a7dad0dc, 0x8d1f85fd26119, 0x8d203cfc18193,
+    0x8d20987b911d0, 0x8d209c2481795, 0x8d20ae713346e, 0x8d20b5c313ff8,
+    0x8d2536a2d595f, 0x8d28affefd35e, 0x8d29d8730a6b3, 0x8d2c97274fa73,
+    0x8d2da5fcca565, 0x8d2ef30a3b26c, 0x8d3d46470477e, 0x8d41f6bafabe6,
+    0x8d47916b0818e, 0x8d53c6fcdc0bc, 0x8d550cb86c239, 0x8d55d9ad00551,
+    0x8d55e0fee10db, 0x8d567ab65032d, 0x8d592ac6d3fd9, 0x8d6437e49abb2,
+    0x8d64f6356d7b6, 0x8d650c2b0fa54, 0x8d651e77c172d, 0x8d65973ebd592,
+    0x8d68d60bdf341, 0x8d6fc8c42e73f, 0x8d72c9595a2d9, 0x8d7d2e1bf054c,
+    0x8d80dab53d011, 0x8d899e3cca0ca, 0x8d9147f40b542, 0x8d91828311192,
+    0x8d919878b3430, 0x8d91f04f3bea8, 0x8d936947f10eb, 0x8d9453840822b,
+    0x8d96f147da1fe, 0x8d98335a79db6, 0x8d99c5f1c185c, 0x8d9c257d9d61a,
+    0x8da3b93f3c7f4, 0x8da4cf6697e70, 0x8da51feb3fd5e, 0x8da9454b88688,
+    0x8da99d2211100, 0x8dacf1e4d514d, 0x8dae847c1cbf3, 0x8daed8a9b50a6,
+    0x8dafe77f2fb98, 0x8db1c6f22ef67, 0x8db5c06733355, 0x8db6aaa34a495,
+    0x8dc08ba413754, 0x8dc1a5745f395, 0x8dc57df8f0396, 0x8dc5fa68dc7c0,
+    0x8dc7cb381a47b, 0x8dc8d2bbb43e3, 0x8dc92e3b2d420, 0x8dccfbc4ed2d2,
+    0x8dcfd06ed4930, 0x8dd663feba72c, 0x8dd7608783545, 0x8ddc56855074c,
+    0x8ddcd2f53cb76, 0x8ddefb9b032a9, 0x8de1786e61e8f, 0x8de2b32f20ebd,
+    0x8de6dc3859dac, 0x8de708239e2e8, 0x8de837e98c1c7, 0x8de979fc2bd7f,
+    0x8dec9f2abb2cb, 0x8dede4e64b448, 0x8deffb3f5fea2, 0x8df035ce65af2,
+    0x8df0c833f41ba, 0x8df123b36d1f7, 0x8df68b26556d9, 0x8dfdb4c4ba162,
+    0x8e015a0c2609d, 0x8e018d494b163, 0x8e0bf5b4d199b, 0x8e1108fa219ca,
+    0x8e13d2a937ed9, 0x8e1698af5de23, 0x8e195b0c937a8, 0x8e272a878fd06,
+    0x8e29239921938, 0x8e2ad377ec206, 0x8e2f83ebe266e, 0x8e33d5376f4d4,
+    0x8e35383a82479, 0x8e3c3244b2401, 0x8e3f4c78707fe, 0x8e41b6ff1d70b,
+    0x8e473467a7e8b, 0x8e47973901a52, 0x8e50de825babf, 0x8e565beae623f,
+    0x8e5754cabea93, 0x8e5904a989361, 0x8e59bba87b3db, 0x8e5d43a8644ee,
+    0x8e5d60efe7316, 0x8e5f615359ad2, 0x8e61063753251, 0x8e66d07b952fa,
+    0x8e69d4b9b1459, 0x8e6e1eb35d735, 0x8e70b5254eb7e, 0x8e7518bd8d6bd,
+    0x8e76c89c57f8b, 0x8e76f4879c4c7, 0x8e79f8c5b8626, 0x8e89c8a427340,
+    0x8e8c0ae8802d6, 0x8e8d1d66eb38d, 0x8e8f71f7f5ffc, 0x8e96909b89936,
+    0x8e96efc3f2f38, 0x8e982e2da252b, 0x8e9889ad1b568, 0x8e9af7dcb8a3a,
+    0x8e9ce5f37951d, 0x8e9d5eba75382, 0x8e9dd02f9065d, 0x8e9e7c33b1588,
+    0x8ea2b3e0abb8b, 0x8ea5938564338, 0x8ea5c6c2893fe, 0x8ea5e7b2fc7eb,
+    0x8ea5ef04dd375, 0x8ea70c7e1957b, 0x8ea8b50b032bf, 0x8ead5a84285d8,
+    0x8eb1af78a5a03, 0x8eb487cb7d626, 0x8eb65148da757, 0x8eb89adf14277,
+    0x8eb8d56e19ec7, 0x8eba4e66cf10a, 0x8ebc9f4ee97b4, 0x8ec0708199c2b,
+    0x8ec541e603480, 0x8ec7d4af04304, 0x8ec8b3f04a2f5, 0x8ec98f889fd21,
+    0x8ec9f259f98e8, 0x8ed0c7cac5ebe, 0x8ed10d549cc5d, 0x8ed77ff40f66c,
+    0x8ed8543a8450e, 0x8ed9f5758d6c8, 0x8edc93395f69b, 0x8edcbf24a3bd7,
+    0x8edfbfb9cf771, 0x8edfc70bb02fb, 0x8ee122bce2716, 0x8ee7746be1d38,
+    0x8eff87b90111c, 0x8f01acb5d728a, 0x8f03ca60cc86e, 0x8f06fa8a2cf09,
+    0x8f07e86f3460e, 0x8f07f36a0575d, 0x8f11a12da9956, 0x8f15c68df2280,
+    0x8f176b71eb9ff, 0x8f182270dda79, 0x8f193fea19c7f, 0x8f1cf77e37893,
+    0x8f1f23ccee58b, 0x8f22a823e70d9, 0x8f25966c60f9a, 0x8f262c7adfc27,
+    0x8f2646197248a, 0x8f2929671b1fc, 0x8f2c5d396be5c, 0x8f2cbc61d545e,
+    0x8f3724cd5bc96, 0x8f388b795f200, 0x8f3d6086b901a, 0x8f4919a8a0b1e,
+    0x8f4f46be3c78e, 0x8f50bfb6f19d1, 0x8f51b4edd9c60, 0x8f5f4630dffa9,
+    0x8f5feae32034a, 0x8f6581ea3d32d, 0x8f66896dd7295, 0x8f695a6ece32e,
+    0x8f6dd3fcaf10b, 0x8f6e282a475be, 0x8f719a348e433, 0x8f7f2f2084d41,
+    0x8f7fffbe0961e, 0x8f8024576cfd0, 0x8f836a766f909, 0x8f8371c850493,
+    0x8f8663b9ba919, 0x8f88e08d194ff, 0x8f8d47ce48603, 0x8f8ec46fede0b,
+    0x8f9462c8eb978, 0x8f94d7e6f7218, 0x8f94e2e1c8367, 0x8f989a75e5f7b,
+    0x8f9d6bda4f7d0, 0x8f9e38cee3ae8, 0x8f9f7e8a73c65, 0x8f9fe8adae3b6,
+    0x8fa53a2af45fa, 0x8fa98f1f71a25, 0x8fad79f0b46ff, 0x8fb1d28e220ef,
+    0x8fb5b60d8423f, 0x8fb9e2bfad6f3, 0x8fbda1a5abe91, 0x8fbf602837e73,
+    0x8fc4fad84541b, 0x8fc5e16b6bf96, 0x8fc83d4e5778f, 0x8fc99ca87a16f,
+    0x8fc9d38e8f7fa, 0x8fceac44d9bd9, 0x8fd1eebaebf4d, 0x8fd279ce99a8b,
+    0x8fd710a3fd690, 0x8fd72a428fef3, 0x8fd7e4ea72532, 0x8fd81f7978182,
+    0x8fd87af8f11bf, 0x8fdb61ef8a4f6, 0x8fdff51bfdb36, 0x8fecaac6ae453,
+    0x8fee69493a435, 0x8fef44e18fe61, 0x8ff0a7e4a2e06, 0x8ff17882276e3,
+    0x8ff7e778a9b2d, 0x8ff9f67fdd9fd, 0x8ffd5d8f53723, 0x8ffef0269b1c9,
+    0x9001067fafc23, 0x9001953c4dd26, 0x9006928bfbab7, 0x9006cd1b01707,
+    0x9006df67b33e0, 0x9008cd7e73ec3, 0x900949ee602ed, 0x9009a1c4e8d65,
+    0x900dcace21c54, 0x900f64b74a284, 0x9010180d4bd39, 0x9010ddafff4c7,
+    0x9011b1f674369, 0x9014a03eee22a, 0x9017db631fa14, 0x901cb41969df3,
+    0x901db7f413796, 0x90266cd7df13b, 0x9026b261b5eda, 0x902791a2fbecb,
+    0x902d6e33efc4d, 0x902dac6be5e62, 0x90316efad4bc5, 0x9031ce233e1c7,
+    0x903d5f02d1d54, 0x903f16337d1ac, 0x9040a521d468d, 0x90458529ff5f6,
+    0x9047b178b62ee, 0x9047faab7d652, 0x904ded3213672, 0x9055ca2679bb0,
+    0x905730d27d11a, 0x905cec72fdaaf, 0x9064494e875fe, 0x9065547b11b2b,
+    0x9065d0eafdf55, 0x9065f1db71342, 0x9069678ea877c, 0x907047fa45ea1,
+    0x907699a9454c3, 0x9076b347d7d26, 0x90772865e35c6, 0x907c4df7e52ce,
+    0x907d3f85dcf98, 0x907fbc593bb7e, 0x9087f1242ab34, 0x9088504c94136,
+    0x9089df3aeb617, 0x908bd84c7d249, 0x908f380a123e5, 0x909735eeebd10,
+    0x90982ecec4564, 0x90997f8525830, 0x909af87ddaa73, 0x909efcedaffb0,
+    0x90a8826f00232, 0x90ad194463e37, 0x90adbdf6a41d8, 0x90b0d82a625d5,
+    0x90b630f9893a3, 0x90b651e9fc790, 0x90b8a2d216e3a, 0x90b9b8f9724b6,
+    0x90b9ceef14754, 0x90bab5823b2cf, 0x90bceccbc3116, 0x90c174fd65607,
+    0x90c5acaa5fc0a, 0x90c7d1a735d78, 0x90ca3884f26c0, 0x90cdb58a0a684,
+    0x90d082e211158, 0x90d0b9c8267e3, 0x90d1328f22648, 0x90d38ac91d87c,
+    0x90d9f26dbf13c, 0x90e089a6954fd, 0x90e7d4356d373, 0x90e8d0be3618c,
+    0x90e971c785f68, 0x90f1b53636632, 0x90f452fa08605, 0x90f5f08c211fa,
+    0x90f98386db45c, 0x90fb6df4ab97a, 0x9100d1bea3897, 0x91077eed1bef6,
+    0x9108916b86fad, 0x9109cc2c45fdb, 0x910c7fe5ba24c, 0x9110bee4953d9,
+    0x9113e7bc14eea, 0x9118feaa554de, 0x9119cb9ee97f6, 0x911d83330740a,
+    0x911f2f68e1713, 0x912171ad3a6a9, 0x912500ff04346, 0x9126a23a0d500,
+    0x91288ca7dda1e, 0x91292db12d7fa, 0x912c5688ad30b, 0x912f70bc6b708,
+    0x912ff47e386bc, 0x91312f3ef76ea, 0x913157814b661, 0x9131c54d76377,
+    0x913628e5b4eb6, 0x9137ca20be070, 0x913842e7b9ed5, 0x913dd29cf632e,
+    0x9149c2a4f34bd, 0x9149dc4385d20, 0x9150e89a67981, 0x91512a7b4e15b,
+    0x915691ee3663d, 0x9156dec9edf66, 0x915d9e451829e, 0x915f6b6b65994,
+    0x9160385ff9cac, 0x91616bced8150, 0x9167f80cdd3c2, 0x916839edc3b9c,
+    0x91692429dacdc, 0x91699947e657c, 0x916e6aac4fdd1, 0x916e8f45b3783,
+    0x9175e1266c183, 0x917710ec5a062, 0x917e2be6fd3d7, 0x918092c4b9d1f,
+    0x9183885f1476a, 0x9187857d0911d, 0x91889ba464799, 0x918943ff950ff,
+    0x9189982d2d5b2, 0x918c35f0ff585, 0x918ccbff7e212, 0x918ff87fee2e8,
+    0x919036b7e44fd, 0x91925f5daac30, 0x9194a8f3e4750, 0x91966ec8512bc,
+    0x91984e3b5068b, 0x919ce167c3ccb, 0x919da70a77459, 0x919de8eb5dc33,
+    0x919e14d6a216f, 0x919e89f4ada0f, 0x919f2afdfd7eb, 0x91a1bdc6fe66f,
+    0x91a42bf69bb41, 0x91a5ac413190e, 0x91a71a3f15a02, 0x91adefafe1fd8,
+    0x91adfaaab3127, 0x91b13225f434c, 0x91bd556b165a1, 0x91befdf8002e5,
+    0x91c01b713c4eb, 0x91c16c279d7b7, 0x91c1efe96a76b, 0x91c5116f096f2,
+    0x91c6572a9986f, 0x91c669774b548, 0x91c69cb47060e, 0x91c7993d39427,
+    0x91cf603bfd6c7, 0x91cfb0c0a55b5, 0x91d5650f453c0, 0x91d5cb898f54c,
+    0x91d74f7d158de, 0x91d8c123e9f97, 0x91d8e9663df0e, 0x91dae620c0105,
+    0x91db95cdd15f5, 0x91ddcd175943c, 0x91df251f9b292, 0x91e0f5eed8f4d,
+    0x91e6108609b06, 0x91e7cf0895ae8, 0x91eca7bedfec7, 0x91ef57cf63b73,
+    0x91ef5b7854138, 0x91f0a133e42b5, 0x91f4342e9e517, 0x91f6a6072bfae,
+    0x92045f8c8626e, 0x9205dc2e2ba76, 0x9210f446c379e, 0x92117bb180d17,
+    0x92144cb277db0, 0x9214edbbc7b8c, 0x9215926e07f2d, 0x921bd92236400,
+    0x92205daae832c, 0x92212a9f7c644, 0x922131f15d1ce, 0x92268ac083f9c,
+    0x922e84fc6d302, 0x922fbfbd2c330, 0x922ffa4c31f80, 0x9230bfeee570e,
+    0x9230f32c0a7d4, 0x923706a313be1, 0x9239b30aa72c8, 0x923a506b06adf,
+    0x923c3e81c75c2, 0x9240887b7389e, 0x9241b8416177d, 0x9241fdcb3851c,
+    0x924409297be27, 0x9246a6ed4ddfa, 0x9246e8ce345d4, 0x92470d6797f86,
+    0x9247d30a4b714, 0x9248029e80215, 0x92501678fbdde, 0x9254a5fc7ee59,
+    0x92567a74ad0d9, 0x9256aa08e1bda, 0x92575d5ee368f, 0x925764b0c4219,
+    0x9258e4fb59fe6, 0x9258e8a44a5ab, 0x9259825bb97fd, 0x925e91f819267,
+    0x925f5eecad57f, 0x92610779972c3, 0x9267607a7746f, 0x9267d1ef9274a,
+    0x926d1c1af7e04, 0x926d445d4bd7b, 0x926ff46dcfa27, 0x9271f87a327a8,
+    0x9277acc8d25b3, 0x927cceb1e3cf6, 0x927ce4a785f94, 0x927e4efc79ac3,
+    0x927e898b7f713, 0x927ef3aeb9e64, 0x928118ab8ffd2, 0x9281e1f733d25,
+    0x928562a53c2ae, 0x92863e3d91cda, 0x92893b29cd2af, 0x928954c85fb12,
+    0x9293e5763a2c1, 0x92965af7b831d, 0x929895ea30729, 0x92999272f9542,
+    0x92a739aba1b29, 0x92acd8049f696, 0x92b0a1e56ef83, 0x92b835a70e15d,
+    0x92bcf4bec5cd9, 0x92bea49d905a7, 0x92c1e36ab2356, 0x92c4812e84329,
+    0x92c6a62b5a497, 0x92c6b4cf1bbab, 0x92c9563bde143, 0x92cbb5c7b9f01,
+    0x92cc1feaf4652, 0x92ccbd4b53e69, 0x92cef0ebeb6eb, 0x92cfed74b4504,
+    0x92d55c397d570, 0x92d68bff6b44f, 0x92d967fb33637, 0x92db401c51e7c,
+    0x92ddf02cd5b28, 0x92e94dcf445ef, 0x92ef230e577e7, 0x92f60acbd5a96,
+    0x92f6f15efc611, 0x92f95f8e99ae3, 0x92fec35891a00, 0x9306780aa3fc7,
+    0x9307992cd0792, 0x9309d7c839163, 0x930cd85d64cfd, 0x930f5530c38e3,
+    0x9317914d93423, 0x931835ffd37c4, 0x931902f467adc, 0x931e2886697e4,
+    0x931e6a674ffbe, 0x931fe708f57c6, 0x9324fa4e457f5, 0x932ce5e66d447,
+    0x93379b2dab5a8, 0x933d6571ed651, 0x933f23f479633, 0x933ff83aee4d5,
+    0x93457cf5597df, 0x9345c27f3057e, 0x9348c6bd4c6dd, 0x934967c69c4b9,
+    0x93527f7bc1a25, 0x935d595c63538, 0x935def6ae21c5, 0x935fe87c73df7,
+    0x9360d2b88af37, 0x9364ed1e02712, 0x9367330b4bc6d, 0x936878c6dbdea,
+    0x936d3f30744f0, 0x936dc2f2414a4, 0x9370fa6d826c9, 0x9373aa7e06375,
+    0x937974c24841e, 0x937cd828cdb7f, 0x937ebeedadad8, 0x937f5c4e0d2ef,
+    0x938172a721d49, 0x938460ef9bc0a, 0x9389150c82637, 0x93894f9b88287,
+    0x9391c6475da17, 0x93998d4621cb7, 0x939dc4f31c2ba, 0x939ec524d5698,
+    0x93a5c680e61aa, 0x93a6d1ad706d7, 0x93a8a9ce8ef1c, 0x93a9ce99abcac,
+    0x93ae7f0da2114, 0x93b0ff89f12bf, 0x93b169ad2ba10, 0x93b2c55e5de2b,
+    0x93b3d08ae8358, 0x93b52c3c1a773, 0x93b721a4bbde0, 0x93b8c2dfc4f9a,
+    0x93b8cdda960e9, 0x93b9bbbf9d7ee, 0x93bc7a73e2bae, 0x93c7b37cedcc3,
+    0x93ce5d0275d5d, 0x93cf17aa5839c, 0x93d6a7c306fb1, 0x93e0b4af147ac,
+    0x93ed7ca676da2, 0x93ef2c8541670, 0x93efa54c3d4d5, 0x93f08bdf64050,
+    0x93f1e3e7a5ea6, 0x93f44ac5627ee, 0x93f49b4a0a6dc, 0x93f5cb0ff85bb,
+    0x9405ef1bff788, 0x940ceccf1fcd5, 0x940d0dbf930c2, 0x940de20607f64,
+    0x940fba27267a9, 0x941157b93f39e, 0x94136a6963833, 0x9418ad42e8363,
+    0x94196f3cab52c, 0x941b9b8b62224, 0x941ba68633373, 0x941ef04e26271,
+    0x9428a90c9b5b9, 0x9428c2ab2de1c, 0x9429292577fa8, 0x942b51cb3e6db,
+    0x943669e3d6403, 0x9439cd4a5bb64, 0x943cd53168288, 0x943e7dbe51fcc,
+    0x944373bc1f1d3, 0x94444bab8463a, 0x9444ae7cde201, 0x9446695679c1e,
+    0x9449af757c557, 0x9449c56b1e7f5, 0x944d54bce8492, 0x945df538dba89,
+    0x9464caa9a805f, 0x94653fc7b38ff, 0x946555bd55b9d, 0x9467f72a18135,
+    0x946f923d97e99, 0x94759367ef5cd, 0x947746efaa460, 0x9477ce5a679d9,
+    0x94794afc0d1e1, 0x947c9fbed122e, 0x9484ea7f62482, 0x94868bba6b63c,
+    0x9486930c4c1c6, 0x948bf32d53b1e, 0x9495f1759fc05, 0x9496a122b10f5,
+    0x949df30369af5, 0x94a02df5e1f01, 0x94a506ac2c2e0, 0x94aca5689c609,
+    0x94ad67625f7d2, 0x94aeb46fd04d9, 0x94af5ccb00e3f, 0x94b10ca9cb70d,
+    0x94b18570c7572, 0x94b19f0f59dd5, 0x94b4c7e6d98e6, 0x94b85ae193b48,
+    0x94b90a8ea5038, 0x94ba7c35796f1, 0x94bd4d367078a, 0x94be217ce562c,
+    0x94bf130add2f6, 0x94c6bcc21e76e, 0x94c7dde44af39, 0x94ccdb33f8cca,
+    0x94cd41ae42e56, 0x94cd5b4cd56b9, 0x94cd838f29630, 0x94d02c4dcc752,
+    0x94d4814249b7d, 0x94d9d2bf8fdc1, 0x94dd278253e0e, 0x94dd69633a5e8,
+    0x94ddaeed11387, 0x94ddf81fd86eb, 0x94e6468959f04, 0x94e7e7c4630be,
+    0x94ef6939505bf, 0x94f4961d32e51, 0x94f7eadff6e9e, 0x94f93f3f4872f,
+    0x94fd59a4bff0a, 0x950937600b3c0, 0x951340a3285f6, 0x95139fcb91bf8,
+    0x951a5b9dcb96b, 0x951c8f3e631ed, 0x951d672dc8654, 0x951ebb8d19ee5,
+    0x95246fdbb9cf0, 0x9525357e6d47e, 0x9525ec7d5f4f8, 0x9526fefbca5af,
+    0x952f1a2826d02, 0x952f2522f7e51, 0x952f9a41036f1, 0x95311a8b994be,
+    0x953d3a27cb14e, 0x953e20baf1cc9, 0x953ec1c441aa5, 0x9541d0fd2ed53,
+    0x954463c62fbd7, 0x954501268f3ee, 0x954651dcf06ba, 0x954b8269c3511,
+    0x9551b3284f746, 0x955445f1505ca, 0x955bd9b2ef7a4, 0x9561e22f27a62,
+    0x9564ba81ff685, 0x956d90563e417, 0x956fb1aa23fc0, 0x95702e1a103ea,
+    0x957131f4b9d8d, 0x9571ddf8dacb8, 0x957f2260296d8, 0x957f902c543ee,
+    0x95851c38a0282, 0x9585cbe5b1772, 0x9587a7afc057c, 0x958d216f5a737,
+    0x9594d2787c739, 0x9595b1b9c272a, 0x9598bd49bf413, 0x9599bd7b787f1,
+    0x959cfc489a5a0, 0x959d99a8f9db7, 0x959e336069009, 0x959e3709595ce,
+    0x95a17d285bf07, 0x95a3d5625713b, 0x95ae785ce35c3, 0x95b0baa13c559,
+    0x95b472355a16d, 0x95b4eafc55fd2, 0x95b713a21c705, 0x95b76f2195742,
+    0x95b9c3b2a03b1, 0x95b9f346d4eb2, 0x95bbc41612b6d, 0x95bbe50685f5a,
+    0x95bc5a24917fa, 0x95bdbd27a479f, 0x95bfe9765b497, 0x95c1aba1d7a3e,
+    0x95c7c66ac19d5, 0x95c7f5fef64d6, 0x95c85c7940662, 0x95d557adc7d1e,
+    0x95d7f1c8a972c, 0x95df2db3bfe8e, 0x95dfd60ef07f4, 0x95e47f31060d2,
+    0x95e6ffad5527d, 0x95e8be2fe125f, 0x95e93e48bdc4e, 0x95ec7d15df9fd,
+    0x95efd92a845d4, 0x95f1fe275a742, 0x95f694fcbe347, 0x95f78ddc96b9b,
+    0x95f870c6cd151, 0x95f8ff836b254, 0x95f9c17d2e41d, 0x95fcc5bb4a57c,
+    0x95fcf8f86f642, 0x95ffc2a785b51, 0x96071f830f6a0, 0x9609a3a84ee10,
+    0x960c8e47d870c, 0x960cab8f5b534, 0x960caf384baf9, 0x960ea849dd72b,
+    0x961593b04bf9f, 0x9615d1e8421b4, 0x961cf08bd5aee, 0x961dab33b812d,
+    0x96213e2e7238f, 0x9622b37e3700d, 0x962396686d5c3, 0x9624b78a99d8e,
+    0x96256737ab27e, 0x962596cbdfd7f, 0x9628348fb1d52, 0x9629c726f97f8,
+    0x962c8d2d1f742, 0x962d10eeec6f6, 0x962d860cf7f96, 0x962d89b5e855b,
+    0x9634ac026c45a, 0x96368f1e5bdee, 0x96380f68f1bbb, 0x963aa983d35c9,
+    0x963bcaa5ffd94, 0x96453688bd7b3, 0x964dc32a351e1, 0x964e1ea9ae21e,
+    0x9651144408c69, 0x96567f5fe1710, 0x965815a01977b, 0x965e306903712,
+    0x965eb42ad06c6, 0x965f04af785b4, 0x9660939dcfa95, 0x9661225a6db98,
+    0x9662e0dcf9b7a, 0x966789ff0f458, 0x966cf171f793a, 0x966dc20f7c217,
+    0x966df8f5918a2, 0x966e8f041052f, 0x96716affd8717, 0x9679fb4a4070a,
+    0x967ded6d63f6e, 0x96811644e3a7f, 0x9684b43a6ee30, 0x9684e025b336c,
+    0x9688adaf7321e, 0x968fc8aa16593, 0x969032cd50ce4, 0x9690b2e62d6d3,
+    0x9696a56cc36f3, 0x9698171397dac, 0x969cb53adc53b, 0x969ec7eb009d0,
+    0x969f0d74d776f, 0x96a1869f45d90, 0x96a198ebf7a69, 0x96a77ccecc375,
+    0x96ade0ca7d670, 0x96b7664bcd8f2, 0x96b7ccc617a7e, 0x96b89268cb20c,
+    0x96b9d0d27a7ff, 0x96bdbf4cada9e, 0x96be30c1c8d79, 0x96be9ae5034ca,
+    0x96bf30f382157, 0x96c1106681526, 0x96c1892d7d38b, 0x96c6d701d300a,
+    0x96c8ffa79973d, 0x96c9bdf86c341, 0x96ccfcc58e0f0, 0x96cdf1fc7637f,
+    0x96cec642eb221, 0x96ced13dbc370, 0x96d4d9b9f462e, 0x96d585be15559,
+    0x96d877af7f9df, 0x96d9917fcb620, 0x96e11def89c70, 0x96e529b13fd37,
+    0x96e5559c84273, 0x96eec17f41c92, 0x96f463812fdc4, 0x96f53075c40dc,
+    0x96f7bbece43d6, 0x96fcb93c92167, 0x96fff0b7d338c, 0x970006ad7562a,
+    0x97004c374c3c9, 0x9701dece93e6f, 0x97056a776d547, 0x970587bef036f,
+    0x970796c62423f, 0x97098c2ec58ac, 0x970c9b67b2b5a, 0x97111c47744c1,
+    0x9711fb88ba4b2, 0x9712b9d98d0b6, 0x9713ed486b55a, 0x9717d0c7cd6aa,
+    0x971dc34e636ca, 0x971e7a4d55744, 0x97290afb2fef3, 0x9731c387ebe5d,
+    0x9731e8214f80f, 0x97360286c6fea, 0x973718ae22666, 0x97375e37f9405,
+    0x9738aeee5a6d1, 0x973944fcd935e, 0x974685bb377b9, 0x9747cb76c7936,
+    0x974b39d81e1e6, 0x974df88c635a6, 0x974f92758bbd6, 0x9751b77261d44,
+    0x9756768a198c0, 0x97588ce32e31a, 0x975cda85cabbb, 0x975f1cca23b51,
+    0x9760c1ae1d2d0, 0x9764460515e1e, 0x9765a1b648239, 0x977002cfedee7,
+    0x977792e89cafc, 0x9777cd77a274c, 0x977ccac7504dd, 0x977efe67e7d5f,
+    0x97853dca356a8, 0x97885f4fd462f, 0x978fb8826dbb9, 0x9791e128342ec,
+    0x9796aee3ad57c, 0x979711b507143, 0x979c966f7244d, 0x979f79bd1b1bf,
+    0x97a68613fce20, 0x97a9b9e64da80, 0x97a9e228a19f7, 0x97addf46963aa,
+    0x97af0068c2b75, 0x97af374ed8200, 0x97b4d1fee57a8, 0x97c7384f4590b,
+    0x97c79b209f4d2, 0x97d0d3c637e2b, 0x97df9fc9fd1a2, 0x97e0706781a7f,
+    0x97e141050635c, 0x97ec0898f6196, 0x97eece9f1c0e0, 0x97f11f873678a,
+    0x97f190fc51a65, 0x97f5538b407c8, 0x97f70abbebc20, 0x97f8ea2eeafef,
+    0x97f919c31faf0, 0x97f92866e1204, 0x97fd0f8f33919, 0x97febbc50dc22,
+    0x97ff9eaf441d8, 0x9805ecb553235, 0x9806cbf699226, 0x980fe3abbe792,
+    0x98129b0e22fc8, 0x981d6d9ce3f51, 0x9826b4e63dfbe, 0x9836c6a5934b2,
+    0x9837ad38ba02d, 0x9839e82b32439, 0x9848291b49c72, 0x984d7346af32c,
+    0x9861528fc46d2, 0x98643d2f4dfce, 0x98679cece316a, 0x98690aeac725e,
+    0x986c376b37334, 0x986e0f8c55b79, 0x9870a9a737587, 0x98713863d568a,
+    0x9876cf6af266d, 0x9877aeac3865e, 0x9877bd4ff9d72, 0x98805c3e23479,
+    0x9884520a372a2, 0x9885892205d0b, 0x9885cb02ec4e5, 0x9887b1c7cc43e,
+    0x98893d0d3335a, 0x9889445f13ee4, 0x988d208c954aa, 0x988fafaca5d69,
+    0x9890b7303fcd1, 0x989608ad85f15, 0x989760b5c7d6b, 0x98984af1deeab,
+    0x98a120c61dc3d, 0x98a174f3b60f0, 0x98a3ee1e24711, 0x98a46a8e10b3b,
+    0x98a9e0a4ba731, 0x98ad5da9d26f5, 0x98af028dcbe74, 0x98af9fee2b68b,
+    0x98b10dec0f77f, 0x98b36d77eb53d, 0x98b712bf57478, 0x98bef35cadf7b,
+    0x98c1060cd2410, 0x98ce4e1d113f5, 0x98d029e7201ff, 0x98d18cea331a4,
+    0x98d5a3a6ba3ba, 0x98d927fdb2f08, 0x98dfc2df7988e, 0x98e308fe7c1c7,
+    0x98e31ef41e465, 0x98e4f36c4c6e5, 0x98e53c9f13a49, 0x98ef2148cd2cd,
+    0x98f01a28a5b21, 0x98f0510ebb1ac, 0x98f11e034f4c4, 0x98f98d5d440ca,
+    0x98f9c44359755, 0x98fdf84763793, 0x990a1b8c859e8, 0x990f068f81aa0,
+    0x9915dfa93e63b, 0x991680b28e417, 0x991e59fe04390, 0x991f6c7c6f447,
+    0x992148467e251, 0x99217088d21c8, 0x9924711dfdd62, 0x9927c989b2374,
+    0x992828b21b976, 0x99294d7d38706, 0x99342b06ca7de, 0x9936ab8319989,
+    0x9937244a157ee, 0x9945185e756fe, 0x9945b5bed4f15, 0x99478a3703195,
+    0x994991ec564db, 0x994fa90c4fead, 0x99558cef247b9, 0x9955fabb4f4cf,
+    0x995e2f863e485, 0x995e62c36354b, 0x995fbe7495966, 0x9964bf6d33cbc,
+    0x996ca00a8a7bf, 0x996d4fb79bcaf, 0x996f0e3a27c91, 0x996f3a256c1cd,
+    0x99749a4673b25, 0x99753ef8b3ec6, 0x9978f68cd1ada, 0x99803d72b938b,
+    0x99898fb6e4547, 0x9995d043895c4, 0x999d84f59bb8b, 0x999f387d56a1e,
+    0x99a290e90b030, 0x99a59c7907d19, 0x99a6278cb5857, 0x99a75afb93cfb,
+    0x99a995ee0c107, 0x99b58d47e9e20, 0x99b65de56e6fd, 0x99b9aeff42185,
+    0x99be87b58c564, 0x99c1e77321700, 0x99c56bca1a24e, 0x99c5940c6e1c5,
+    0x99d06a441f713, 0x99d530adb7e19, 0x99d91f27eb0b8, 0x99d952651017e,
+    0x99dcc46f56ff3, 0x99e0bde45b3e1, 0x99e1922ad0283, 0x99ec01e837645,
+    0x99ec3c773d295, 0x99f61d7806554, 0x99f9673ff9452, 0x9a07f162d7fef,
+    0x9a0819a52bf66, 0x9a08d44d0e5a5, 0x9a0d4a31fedbd, 0x9a10262dc6fa5,
+    0x9a103128980f4, 0x9a170deb45254, 0x9a1927ed4a273, 0x9a1ea8fec4fb8,
+    0x9a1edc3bea07e, 0x9a25980e23df1, 0x9a25b8fe971de, 0x9a292b08de053,
+    0x9a33c3089938c, 0x9a37734ad6416, 0x9a378597880ef, 0x9a382a49c8490,
+    0x9a39d67fa2799, 0x9a45b43aedc4f, 0x9a4730dc93457, 0x9a4b22ffb6cbb,
+    0x9a5106e28b5c7, 0x9a5542387618f, 0x9a5580706c3a4, 0x9a5ff77fb42f0,
+    0x9a60fb5a5dc93, 0x9a613d3b4446d, 0x9a65a47c73571, 0x9a6921818b535,
+    0x9a6e102d77bb2, 0x9a7544c6ad78a, 0x9a81a643c5bf4, 0x9a81a9ecb61b9,
+    0x9a877f2bc93b1, 0x9a8e63405709b, 0x9a8e71e4187af, 0x9a9016c811f2e,
+    0x9a91d8f38e4d5, 0x9a9555f8a6499, 0x9a97ae32a16cd, 0x9a9afba384b90,
+    0x9a9db6aed998b, 0x9aa0c5e7c6c39, 0x9aa4a5be387c4, 0x9aa4d8fb5d88a,
+    0x9aa9612cffd7b, 0x9ab0abbbd7bf1, 0x9abfd33f15fa5, 0x9acf31a8699e4,
+    0x9ad0ddde43ced, 0x9ad46d300d98a, 0x9ad4dafc386a0, 0x9ad5e27fd2608,
+    0x9ad6713c7070b, 0x9ad9a8b7b1930, 0x9adbd50668628, 0x9ade9b0c8e572,
+    0x9ae1526ef2da8, 0x9ae16fb675bd0, 0x9ae18203278a9, 0x9ae77489bd8c9,
+    0x9aecb3ba51e34, 0x9aed4276eff37, 0x9aefe03ac1f0a, 0x9af193c27cd9d,
+    0x9af67021b7741, 0x9af9e22bfe5b6, 0x9af9f0cfbfcca, 0x9afedf7bac347,
+    0x9b006ac113263, 0x9b07cb458d377, 0x9b09cba8ffb33, 0x9b115bc1ae748,
+    0x9b11dbda8b137, 0x9b17977b0bacc, 0x9b1a9467470a1, 0x9b1cfb45039e9,
+    0x9b20ba2b02187, 0x9b26f98d4fad0, 0x9b2e4f16f8a95, 0x9b2ebce3237ab,
+    0x9b318a3b2a27f, 0x9b34628e01ea2, 0x9b36fca8e38b0, 0x9b3ea2b734763,
+    0x9b41024310521, 0x9b41c43cd36ea, 0x9b44f80f2434a, 0x9b455e896e4d6,
+    0x9b46db2b13cde, 0x9b47b6c36970a, 0x9b4e6eecb2eb8, 0x9b50d9735fdc5,
+    0x9b5af5032ecd4, 0x9b5f37aafa426, 0x9b618fe4f565a, 0x9b65e130824c0,
+    0x9b67358fd3d51, 0x9b67443395465, 0x9b6c870d19f95, 0x9b6ef8e5a7a2c,
+    0x9b79097aa57ec, 0x9b79390eda2ed, 0x9b79feb18da7b, 0x9b807c4bd15d9,
+    0x9b81752ba9e2d, 0x9b8e9c4b75a25, 0x9b90f82e6121e, 0x9b93c58667cf2,
+    0x9ba00d64ed8f9, 0x9ba6c9372766c, 0x9ba9ac84d03de, 0x9bae6b9c87f5a,
+    0x9bb0f36ab7c8f, 0x9bb523c5d1708, 0x9bb57b9c5a180, 0x9bc552cca9a24,
+    0x9bcd286f2f3d8, 0x9bd5c00b77f55, 0x9bd9a733ca66a, 0x9bda5e32bc6e4,
+    0x9bdf667d3b5c4, 0x9be03ac3b0466, 0x9be1040f541b9, 0x9be6976d80bd7,
+    0x9be8adc695631, 0x9befcc6a28f6b, 0x9bf3a145c99a7, 0x9c098bed22658,
+    0x9c09d8c8d9f81, 0x9c0bfdc5b00ef, 0x9c1570fa4e698, 0x9c186de689c6d,
+    0x9c2718f9dbbf7, 0x9c27cff8cdc71, 0x9c2eacbb7add1, 0x9c367eb5101c0,
+    0x9c36a6f764137, 0x9c46857994565, 0x9c476fb5ab6a5, 0x9c489480c8435,
+    0x9c4b53350d7f5, 0x9c4e2b87e5418, 0x9c549a7e67862, 0x9c616275c9e58,
+    0x9c66e73035162, 0x9c70bd362d2d2, 0x9c7ca24359312, 0x9c7cad3e2a461,
+    0x9c7d977a415a1, 0x9c7deba7d9a54, 0x9c7ea9f8ac658, 0x9c814f0e5f1b5,
+    0x9c81d6791c72e, 0x9c85ff825561d, 0x9c86ba2a37c5c, 0x9c89cd0c154cf,
+    0x9c8c80c589740, 0x9c8dca2a09e82, 0x9c8f1e895b713, 0x9c92c779b7c13,
+    0x9c9f513923ff4, 0x9ca3ef6068783, 0x9cb149bd59441, 0x9cb5eb8d8e195,
+    0x9cb6c725e3bc1, 0x9cb77a7be5676, 0x9cbdc881f46d3, 0x9cbe70dd25039,
+    0x9cc0f159741e4, 0x9cc5acc83b79b, 0x9cc7ad2badf57, 0x9cce40bb93d53,
+    0x9ccfd6fbcbdbe, 0x9cdb60897edc1, 0x9cdda676c831c, 0x9cdfe16940728,
+    0x9ce1fb6b45747, 0x9ce4a080f82a4, 0x9ce506fb42430, 0x9ce6e66e417ff,
+    0x9ced9aee9a9e8, 0x9cf038b26c9bb, 0x9cf5952a83d4e, 0x9cf602f6aea64,
+    0x9cf7958df650a, 0x9cf8194fc34be, 0x9d00ce338ee63, 0x9d0c073c99f78,
+    0x9d0d3aab7841c, 0x9d146f44adff4, 0x9d1681f4d2489, 0x9d1f0aed598f2,
+    0x9d1f2834dc71a, 0x9d25bbc4c2516, 0x9d27769e5df33, 0x9d2c0d73c1b38,
+    0x9d2dc84d5d555, 0x9d2f74833785e, 0x9d313a57a43ca, 0x9d31455275519,
+    0x9d39bfa73b26e, 0x9d3eb1fc17eb0, 0x9d417bab2e3bf, 0x9d48e72a79622,
+    0x9d4954f6a4338, 0x9d4b764a89ee1, 0x9d4fb8f255633, 0x9d57a48a7d285,
+    0x9d6ddfb67de24, 0x9d6feebdb1cf4, 0x9d7590bf9fe26, 0x9d75cb4ea5a76,
+    0x9d796ced213ec, 0x9d7e3aa89a67c, 0x9d7fee305550f, 0x9d869f07be133,
+    0x9d9684dbcf0eb, 0x9d99264891683, 0x9d99d24cb25ae, 0x9d9b3ca1a60dd,
+    0x9d9d8d89c0787, 0x9d9e40dfc223c, 0x9d9f82f261df4, 0x9da14174eddd6,
+    0x9da6168247bf0, 0x9db7754f0ddeb, 0x9dba7d361a50f, 0x9dbc6ef5cb5b7,
+    0x9dc0a2f9d55f5, 0x9dc1ddba94623, 0x9dc807273fcce, 0x9dce51845e766,
+    0x9dcef28dae542, 0x9dd8696b3d0b0, 0x9dd8ff79bbd3d, 0x9dd9ed5ec3442,
+    0x9ddc6a3222028, 0x9de0c6787ffdd, 0x9de5d614dfa47, 0x9de7356f02427,
+    0x9decd3c7fff94, 0x9df0a4fab040b, 0x9df15fa292a4a, 0x9df1e70d4ffc3,
+    0x9dff3dc1506bc, 0x9e04a18b485d9, 0x9e07c6b9d7b25, 0x9e09226b09f40,
+    0x9e1111ac22157, 0x9e16f1e60649e, 0x9e1d3c4324f36, 0x9e2474854b0d3,
+    0x9e298078ba578, 0x9e2d4e027a42a, 0x9e3152724f967, 0x9e36a046a55e6,
+    0x9e36f0cb4d4d4, 0x9e3aede941e87, 0x9e3ca519ed2df, 0x9e3d9df9c5b33,
+    0x9e4e87a88048e, 0x9e5560c23d029, 0x9e564ea74472e, 0x9e581bcd91e24,
+    0x9e5e4c8c1e059, 0x9e5e8ac41426e, 0x9e673bfeef64e, 0x9e6e36091f5d6,
+    0x9e6e5e4b7354d, 0x9e7533bc3fb23, 0x9e75f20d12727, 0x9e77e3ccc37cf,
+    0x9e7988b0bcf4e, 0x9e7d26a6482ff, 0x9e80da917594e, 0x9e83367461147,
+    0x9e8eaa0c71eac, 0x9e98b6f87f6a7, 0x9e9d5176d3871, 0x9e9fb4ab9fbf4,
+    0x9ea9f12be1ef0, 0x9eb7e54041e00, 0x9eb84f637c551, 0x9eb9200100e2e,
+    0x9ebf496dac4d9, 0x9ec154cbefde4, 0x9ec9aa8752187, 0x9ecc325581ebc,
+    0x9ed312c11f5e1, 0x9ed98cb272b7a, 0x9ed9ef83cc741, 0x9edf534dc465e,
+    0x9edf743e37a4b, 0x9ee800dfaf479, 0x9ee8fd6878292, 0x9ef6db8735f04,
+    0x9ef7126d4b58f, 0x9effaa099410c, 0x9f0076fe28424, 0x9f01b567d7a17,
+    0x9f06daf9d971f, 0x9f08872fb3a28, 0x9f0d21ae07bf2, 0x9f0ddffeda7f6,
+    0x9f1e7928ed263, 0x9f1fc28d6d9a5, 0x9f25e0ff47f01, 0x9f25f34bf9bda,
+    0x9f27a32ac44a8, 0x9f31dfab067a4, 0x9f33c2c6f6138, 0x9f3f833abe7c6,
+    0x9f469e3561b3b, 0x9f4ee54d027ca, 0x9f5704224f4e2, 0x9f596ea8fc3ef,
+    0x9f5c1eb98009b, 0x9f5d4e7f6df7a, 0x9f5e7e455be59, 0x9f5f612f9240f,
+    0x9f5fd64d9dcaf, 0x9f6486c194117, 0x9f6648ed106be, 0x9f670e8fc3e4c,
+    0x9f6dbbbe3c4ab, 0x9f6ff307c42f2, 0x9f75273d8770e, 0x9f78192ef1b94,
+    0x9f79d4088d5b1, 0x9f7f00ec6fe43, 0x9f855d96405b4, 0x9f8e1622fc51e,
+    0x9f971b8b6fdb1, 0x9f98c7c14a0ba, 0x9f9f49047e1dd, 0x9fa90914d40af,
+    0x9faaefd9b4008, 0x9fac393e3474a, 0x9fad690422629, 0x9faec10c6447f,
+    0x9fb1836999e04, 0x9fb69305f986e, 0x9fb7eb0e3b6c4, 0x9fbd5d7bf4cf5,
+    0x9fbfae640f39f, 0x9fc60b0ddfb10, 0x9fc745ce9eb3e, 0x9fcb0faf6e42b,
+    0x9fcdf2fd1719d, 0x9fce2d8c1cded, 0x9fd1a33f54227, 0x9fd52ee82d8ff,
+    0x9fd8e67c4b513, 0x9fd93aa9e39c6, 0x9fe78de6aced8, 0x9fe8a40e08554,
+    0x9ff3520365b2b, 0x9ff6cb5f8d52a, 0x9ffd3dfefff39, 0xa00c3d3fea376,
+    0xa013374a1a2fe, 0xa016308d6530e, 0xa0199eeebbbbe, 0xa01f40f0a9cf0,
+    0xa021f4aa1df61, 0xa02e43da846f2, 0xa02ecb4541c6b, 0xa035abb0df390,
+    0xa037bab813260, 0xa038bae9cc63e, 0xa043827dbc478, 0xa0454bfb195a9,
+    0xa049db7e9c624, 0xa04c300fa7293, 0xa05547c4cc7ff, 0xa0598a6c97f51,
+    0xa059f0e6e20dd, 0xa05e24eaec11b, 0xa0613b75b9f53, 0xa06417718213b,
+    0xa06ffc7eae17b, 0xa070df68e4731, 0xa075fda9058af, 0xa077470d85ff1,
+    0xa07dff36cf79f, 0xa084d0feab7b0, 0xa0852c7e247ed, 0xa0861e0c1c4b7,
+    0xa087acfa73998, 0xa091ab42bfa7f, 0xa091c13861d1d, 0xa09f1f3e42fa0,
+    0xa0a5574eafd5f, 0xa0accdc8cc111, 0xa0b7488104622, 0xa0bad080ed735,
+    0xa0bcd48d504b6, 0xa0bf79a303013, 0xa0c05c8d395c9, 0xa0c0762bcbe2c,
+    0xa0c17daf65d94, 0xa0c59814dd56f, 0xa0c740a1c72b3, 0xa0cbb686b7acb,
+    0xa0cd3a7a3de5d, 0xa0cf87b967f42, 0xa0db9508e7ef9, 0xa0dbe58d8fde7,
+    0xa0e0457cde361, 0xa0e81ec8542da, 0xa0ef7ba3dde29, 0xa0efcc2885d17,
+    0xa0f362cc3053e, 0xa0f925be91a5d, 0xa0fb227913c54, 0xa0fcceaeedf5d,
+    0xa0ff910c238e2, 0xa1023d73b6fc9, 0xa1089a1d8773a, 0xa110dd8c37e04,
+    0xa11583055d11d, 0xa117abab23850, 0xa1190eae367f5, 0xa11e1e4a9625f,
+    0xa11e3f3b0964c, 0xa11fdccd22241, 0xa12108ea1fb5b, 0xa125b20c35439,
+    0xa12d547195d27, 0xa1311357944c5, 0xa1387b2def163, 0xa139f07db3de1,
+    0xa13cb683d9d2b, 0xa13daf63b257f, 0xa14479d9ada06, 0xa149d2a8d47d4,
+    0xa14f32c9dc12c, 0xa155722c29a75, 0xa1566b0c022c9, 0xa1675863ad1e9,
+    0xa1710d7931f6c, 0xa171ff0729c36, 0xa176db66645da, 0xa177e2e9fe542,
+    0xa17efa3bb12f2, 0xa181b8eff66b2, 0xa185ecf4006f0, 0xa186f4779a658,
+    0xa188db3c7a5b1, 0xa1891974707c6, 0xa1900fd5b0189, 0xa19084f3bba29,
+    0xa190e7c5155f0, 0xa198e5a9eef1b, 0xa1994c24390a7, 0xa19f8434a5e66,
+    0xa19ffcfba1ccb, 0xa1a15503e3b21, 0xa1a360622742c, 0xa1a89840dae0d,
+    0xa1a9aabf45ec4, 0xa1ad4c5dc183a, 0xa1b646cb63f7e, 0xa1b7d962aba24,
+    0xa1b981ef95768, 0xa1bd029d9dcf1, 0xa1c729283dd4f, 0xa1c780fec67c7,
+    0xa1cd1805e37aa, 0xa1d0e58fa365c, 0xa1d37c0194aa5, 0xa1df85a824497,
+    0xa1e0effd17fc6, 0xa1e87c6cd6616, 0xa1e9336bc8690, 0xa1e9963d22257,
+    0xa1e9ac32c44f5, 0xa1ecc2bd9232d, 0xa1ef4339e14d8, 0xa1efbfa9cd902,
+    0xa1f1857e3a46e, 0xa1fda8c35c6c3, 0xa20dba82b1bb7, 0xa20ddb7324fa4,
+    0xa20e802565345, 0xa20f1d85c4b5c, 0xa217106fcd338, 0xa219d675f3282,
+    0xa21bde2b465c8, 0xa21d4c292a6bc, 0xa21dc14735f5c, 0xa225d8caa20ea,
+    0xa2290c9cf2d4a, 0xa229263b855ad, 0xa22d35a62bc39, 0xa22dde015c59f,
+    0xa22e14e771c2a, 0xa22eb5f0c1a06, 0xa2374d8d0a583, 0xa23cadae11edb,
+    0xa23de86ed0f09, 0xa23f47c8f38e9, 0xa23f73b437e25, 0xa244660914a67,
+    0xa244b2e4cc390, 0xa24678b938efc, 0xa2468b05eabd5, 0xa24e34bd2c04d,
+    0xa250267cdd0f5, 0xa257db2eef6bc, 0xa25c87f9f555f, 0xa25d5145992b2,
+    0xa25fa22db395c, 0xa2611ecf59164, 0xa26839c9fc4d9, 0xa2689c9b560a0,
+    0xa26d83f561b93, 0xa277405cc74a0, 0xa2785a2d130e1, 0xa27ee66b18353,
+    0xa27f5b8923bf3, 0xa27fedeeb22bb, 0xa281215d9075f, 0xa281c266e053b,
+    0xa284c2fc0c0d5, 0xa2889085cbf87, 0xa288adcd4edaf, 0xa2894032dd477,
+    0xa2894b2dae5c6, 0xa2935f6b9c94b, 0xa294ee59f3e2c, 0xa2976032818c3,
+    0xa2990c685bbcc, 0xa299fa4d632d1, 0xa29d182a11c93, 0xa2a17bc2507d2,
+    0xa2a4999eff194, 0xa2a66e172d414, 0xa2a9c682e1a26, 0xa2ada2b062fec,
+    0xa2ae4b0b93952, 0xa2b0b1e95029a, 0xa2b632facafdf, 0xa2b66d89d0c2f,
+    0xa2b783b12c2ab, 0xa2b795fdddf84, 0xa2bdfda27f844, 0xa2be90080df0c,
+    0xa2befa2b4865d, 0xa2c4186b697db, 0xa2c4c46f8a706, 0xa2c5f435785e5,
+    0xa2ce847fe05d8, 0xa2df50e71810b, 0xa2e0d131aded8, 0xa2e9ec8fc3a09,
+    0xa2ecd72f4d305, 0xa2ed8e2e3f37f, 0xa2ef037e03ffd, 0xa2f3f97bd1204,
+    0xa2ff20382a640, 0xa30032b6956f7, 0xa306dc3c1d791, 0xa309c332b6ac8,
+    0xa30fbd0b2d672, 0xa311bd6e9fe2e, 0xa31b29515d84d, 0xa32b49b474455,
+    0xa32f25e1f5a1b, 0xa32f6419ebc30, 0xa33677c2ae41b, 0xa338329c49e38,
+    0xa338cffca964f, 0xa34794ae8de3c, 0xa357c00c75b93, 0xa35efbf78c2f5,
+    0xa366fd85561e5, 0xa36f70883b3b0, 0xa36f8dcfbe1d8, 0xa371e609b940c,
+    0xa376a8ca6154d, 0xa37d97d9c0386, 0xa37dadcf62624, 0xa37fcf23481cd,
+    0xa38b4db62a081, 0xa38dd92d4a37b, 0xa38ea278ee0ce, 0xa39ca8d9ffcb7,
+    0xa3ae54827d7db, 0xa3b34a804a9e2, 0xa3bc86ced3900, 0xa3bd7c05bbb8f,
+    0xa3befff941f21, 0xa3d01ce521942, 0xa3dd774212600, 0xa3e144cbd24b2,
+    0xa3e7d109d7724, 0xa3eecebcf7c71, 0xa3f8a86be03a6, 0xa402ec3e0322c,
+    0xa4073637af508, 0xa407e23bd0433, 0xa40d07cdd213b, 0xa40e637f04556,
+    0xa41f6a7541cd9, 0xa42534b983d82, 0xa425fe0527ad5, 0xa429d2e0c8511,
+    0xa431161dbf7fd, 0xa435e78229052, 0xa43da72f0c768, 0xa43de1be123b8,
+    0xa43fab3b6f4e9, 0xa440b2bf09451, 0xa446ee78667d5, 0xa449ef0d9236f,
+    0xa44d17e511e80, 0xa45515c9eb7ab, 0xa4552bbf8da49, 0xa456a4b842c8c,
+    0xa457580e44741, 0xa457c5da6f457, 0xa45c51b501f0d, 0xa45d08b3f3f87,
+    0xa46774c86ad84, 0xa4678e66fd5e7, 0xa4766cb774637, 0xa477d36377ba1,
+    0xa47fb400ce6a4, 0xa481ce02d36c3, 0xa48443845171f, 0xa48685c8aa6b5,
+    0xa489b2491a78b, 0xa48a360ae773f, 0xa48aa3d712455, 0xa48dffebb702c,
+    0xa49045d900587, 0xa4910080e2bc6, 0xa49192e67128e, 0xa4a1074566f6b,
+    0xa4a5513f13247, 0xa4a704c6ce0da, 0xa4a8b0fca83e3, 0xa4b15e8e931fe,
+    0xa4b174843549c, 0xa4b4b6fa47810, 0xa4b4f5323da25, 0xa4b5b38310629,
+    0xa4b87237559e9, 0xa4bfb1cb5c710, 0xa4c08d63b213c, 0xa4c9beb769f0b,
+    0xa4cfc38ab1c04, 0xa4d1f72b49486, 0xa4db003cad2de, 0xa4db421d93ab8,
+    0xa4dfad07b3181, 0xa4dfc2fd5541f, 0xa4e2a64afe191, 0xa4e7063a4c70b,
+    0xa4ef9684b46fe, 0xa4f0378e044da, 0xa4f1bf2a7ae31, 0xa4f2eb477874b,
+    0xa4f77acafb7c6, 0xa4f994cd007e5, 0xa4fef14517b78, 0xa4ff3325fe352,
+    0xa5188fd79de78, 0xa51cb537e67a2, 0xa51f3d06164d7, 0xa51f5df6898c4,
+    0xa521ed169a183, 0xa529eafb73aae, 0xa544dded4b63f, 0xa547327e562ae,
+    0xa549bdf5765a8, 0xa54cb38fd0ff3, 0xa54e7d0d2e124, 0xa55060291dab8,
+    0xa5575a334da40, 0xa55814db3007f, 0xa55cd049f7636, 0xa5615129b8f9d,
+    0xa5649e9a9c460, 0xa5690d2dac0ee, 0xa5695a0963a17, 0xa56d7f69ac341,
+    0xa5703e1df1701, 0xa571076995454, 0xa575b7dd8b8bc, 0xa57834b0ea4a2,
+    0xa581c1841b2ae, 0xa58cf6e435dfe, 0xa58fe52cafcbf, 0xa5975f4fbc636,
+    0xa59e76a16f3e6, 0xa59eb88255bc0, 0xa5a126b1f3092, 0xa5a74523cd5ee,
+    0xa5ab87cb98d40, 0xa5b173004e1d6, 0xa5b85abdcc485, 0xa5b944f9e35c5,
+    0xa5bdf1c4e9468, 0xa5c11e455953e, 0xa5cd32e6ba07f, 0xa5cd418a7b793,
+    0xa5ce3e13445ac, 0xa5d964cf9d9e8, 0xa5dea7a922518, 0xa5e0cca5f8686,
+    0xa5e183a4ea700, 0xa5e46e4473ffc, 0xa5e49a2fb8538, 0xa5e69e3c1b2b9,
+    0xa5e76b30af5d1, 0xa5eeb96877a0c, 0xa5f9a93ebb7bd, 0xa5ffeff2e9c90,
+    0xa60090fc39a6c, 0xa601270ab86f9, 0xa603953a55bcb, 0xa60e900b6aacb,
+    0xa6103fea35399, 0xa611efc8ffc67, 0xa6127e859dd6a, 0xa627913d915b4,
+    0xa631d50fb443a, 0xa636474bb468d, 0xa636a2cb2d6ca, 0xa637402b8cee1,
+    0xa638948ade772, 0xa648f325eb58f, 0xa64cec9aef97d, 0xa652d07dc4289,
+    0xa656c649d80b2, 0xa6584a3d5e444, 0xa659382265b49, 0xa6612b0c6e325,
+    0xa663d02220e82, 0xa665248172713, 0xa6655b6787d9e, 0xa66f6fa576123,
+    0xa6714074b3dde, 0xa673da8f957ec, 0xa6793707acb7f, 0xa67d1e2fff294,
+    0xa67f1aea8148b, 0xa68410e84e692, 0xa684392aa2609, 0xa6858d89f3e9a,
+    0xa685c0c718f60, 0xa68762022211a, 0xa6887f7b5e320, 0xa68d0efee139b,
+    0xa691dcba5a62b, 0xa692f68aa626c, 0xa697233ccf720, 0xa69dba75a5ae1,
+    0xa69edb97d22ac, 0xa6a45ca94cff1, 0xa6a49ae143206, 0xa6a755ec98001,
+    0xa6b9387b2b1b0, 0xa6be2e78f83b7, 0xa6bfab1a9dbbf, 0xa6c2db43fe25a,
+    0xa6c3a0e6b19e8, 0xa6c3f8bd3a460, 0xa6c5964f53055, 0xa6c9727cd461b,
+    0xa6d19c4cf2482, 0xa6d9d869c1fc2, 0xa6de31072f9b2, 0xa6e57f3ef7ded,
+    0xa6f151ff72154, 0xa6f27e1c6fa6e, 0xa6f7cf99b5cb2, 0xa702ad2347d8a,
+    0xa706686055f63, 0xa7089fa9dddaa, 0xa70948050e710, 0xa70f0af76fc2f,
+    0xa7151373a7eed, 0xa7181408d3a87, 0xa71829fe75d25, 0xa71ee979a005d,
+    0xa7202f35301da, 0xa721998a23d09, 0xa729b10d8fe97, 0xa72e1bf7af560,
+    0xa72f272439a8d, 0xa731069738e5c, 0xa739eb0f39302, 0xa73f7ac47575b,
+    0xa74147eac2e51, 0xa741702d16dc8, 0xa75509ec553cf, 0xa756fbac06477,
+    0xa7578a68a457a, 0xa75d3b0e53dc0, 0xa75ebb58e9b8d, 0xa75f37c8d5fb7,
+    0xa760d1b1fe5e7, 0xa7658d20c5b9e, 0xa7688db5f1738, 0xa76bba366180e,
+    0xa76ea12cfab45, 0xa76fe33f9a6fd, 0xa77785a4fafeb, 0xa77d3d9c8b3bb,
+    0xa78112782bdf7, 0xa7946a5683c24, 0xa796880179208, 0xa7968f5359d92,
+    0xa798726f49726, 0xa7989708ad0d8, 0xa79e59fb0e5f7, 0xa7a06902424c7,
+    0xa7a1f447a93e3, 0xa7a4243f506a0, 0xa7a50e7b677e0, 0xa7ae596db1e12,
+    0xa7be6032361b7, 0xa7bec3038fd7e, 0xa7c05943c7de9, 0xa7c4c42de74b2,
+    0xa7cee366a6986, 0xa7d38c88bc264, 0xa7d39b2c7d978, 0xa7dcc185645f8,
+    0xa7def8ceec43f, 0xa7e6bc24c011a, 0xa7ecd344b9aec, 0xa7f1be47b5ba4,
+    0xa7f8a9ae24418, 0xa7f9c7276061e, 0xa8035b4c71fb4, 0xa804f18caa01f,
+    0xa8072184512dc, 0xa808d1631bbaa, 0xa8090f9b11dbf, 0xa80e6fbc19717,
+    0xa80f6c44e2530, 0xa811b2322ba8b, 0xa8147f8a3255f, 0xa815d792743b5,
+    0xa8194d45ab7ef, 0xa826b29d6d5fc, 0xa8287c1aca72d, 0xa82ef9b50e28b,
+    0xa830ca844bf46, 0xa8315ce9da60e, 0xa836d6a9747c9, 0xa838b9c56415d,
+    0xa83fee5e99d35, 0xa840722066ce9, 0xa867083e840e0, 0xa867f9cc7bdaa,
+    0xa870866df37d8, 0xa876127a3f66c, 0xa877a8ba776d7, 0xa877dbf79c79d,
+    0xa879bb6a9bb6c, 0xa87ef6f23fb12, 0xa88cc66d3c070, 0xa8916be661389,
+    0xa8979ca4ed5be, 0xa89ed13e23196, 0xa8a0b0b122565, 0xa8a5a305ff1a7,
+    0xa8a7fb3ffa3db, 0xa8a894f76962d, 0xa8a89c494a1b7, 0xa8b1a9039e5d4,
+    0xa8b4e07edf7f9, 0xa8b56f3b7d8fc, 0xa8b7aa2df5d08, 0xa8b9b93529bd8,
+    0xa8c9313d0fe7a, 0xa8c96f750608f, 0xa8d7f5eef4667, 0xa8e0f7ae77935,
+    0xa8efc75b2d271, 0xa8f52ece15753, 0xa8fe3f315a135, 0xa8fef2875bbea,
+    0xa907867ab41a2, 0xa90c125546c58, 0xa90cf8e86d7d3, 0xa90d5bb9c739a,
+    0xa914103a20583, 0xa9164783a83ca, 0xa919b98def23f, 0xa91f3a9f69f84,
+    0xa920805afa101, 0xa929821a7d3cf, 0xa934167148143, 0xa9349a33150f7,
+    0xa93d4b6df04d7, 0xa93ee1ae28542, 0xa93fcbea3f682, 0xa94692b74a544,
+    0xa948acb94f563, 0xa955c53559a47, 0xa9565b43d86d4, 0xa9567c344bac1,
+    0xa959beaa5de35, 0xa95c8107937ba, 0xa96197f5d3dae, 0xa961cb32f8e74,
+    0xa963a6fd07c7e, 0xa966cc2b971ca, 0xa97255b94a1cd, 0xa974143bd61af,
+    0xa974cb3ac8229, 0xa9761bf1294f5, 0xa977b23161560, 0xa978ff3ed2267,
+    0xa97c789af9c66, 0xa97cc576b158f, 0xa984d1ff4c5ce, 0xa989c7fd197d5,
+    0xa98edeeb59dc9, 0xa98f281e2112d, 0xa98f3318f227c, 0xa998d98ab58eb,
+    0xa999d26a8e13f, 0xa99c1fa9b8224, 0xa9a3f8f52e19d, 0xa9a65c29fa520,
+    0xa9b1e5b7ad523, 0xa9b8eabcae5fa, 0xa9bb220636441, 0xa9c382bc69933,
+    0xa9d77f4d01b01, 0xa9fa9fb7e7abe, 0xa9fe70ea97f35, 0xaa0913e5243bd,
+    0xaa0c147a4ff57, 0xaa0cf7648650d, 0xaa15d833963ee, 0xaa16a5282a706,
+    0xaa16b774dc3df, 0xaa1bd5b4fd55d, 0xaa1c3c2f476e9, 0xaa1ff3c3652fd,
+    0xaa29510261608, 0xaa386638edce3, 0xaa3dbb5f244ec, 0xaa48a3e387713,
+    0xaa4f635eb1a4b, 0xaa5096cd8feef, 0xaa50bf0fe3e66, 0xaa51258a2dff2,
+    0xaa566c0ca30e7, 0xaa56d9d8cddfd, 0xaa578233fe763, 0xaa59913b32633,
+    0xaa59b22ba5a20, 0xaa5bc4dbc9eb5, 0xaa5c2eff04606, 0xaa5e629f9be88,
+    0xaa616334c7a22, 0xaa63d8b645a7e, 0xaa78a98d52aee, 0xaa7997725a1f3,
+    0xaa7c736e223db, 0xaa81a3faf5232, 0xaa81c1427805a, 0xaa8c977a295a8,
+    0xaa8e5d4e96114, 0xaa8fa30a26291, 0xaa967fccd33f1, 0xaa96c1adb9bcb,
+    0xaa9d3f47fd729, 0xaaa310de2035c, 0xaaa8416af31b3, 0xaaa9f149bda81,
+    0xaaac71c60cc2c, 0xaaaef2425bdd7, 0xaab48cf26937f, 0xaab5e4faab1d5,
+    0xaab9b62d5b64c, 0xaac0f5c162373, 0xaac2b7ecde91a, 0xaac493b6ed724,
+    0xaaccfbbf017a0, 0xaad513426d92e, 0xaad516eb5def3, 0xaad9648dfa794,
+    0xaadeff3e07d3c, 0xaae76aef0c37d, 0xaae9a98a74d4e, 0xaaf975bff34a3,
+    0xab1f7226a1648, 0xab269bc5060d1, 0xab293236f751a, 0xab2e1d39f35d2,
+    0xab3171fcb761f, 0xab3f19355fc06, 0xab48a25fa044d, 0xab51bdbdb5f7e,
+    0xab51c166a6543, 0xab54c94db2c67, 0xab576e63657c4, 0xab58932e82554,
+    0xab5ec0441e1c4, 0xab5fc41ec7b67, 0xab6144695d934, 0xab6fcae34bf0c,
+    0xab74ae946743a, 0xab752ead43e29, 0xab7832eb5ff88, 0xab7f1356fd6ad,
+    0xab8055699d265, 0xab87d3359a1a1, 0xab95caf2ea676, 0xab9622c9730ee,
+    0xab979bc228331, 0xaba02c0c90324, 0xaba0d467c0c8a, 0xaba2fd0d873bd,
+    0xaba9560e67569, 0xabb94a8639c35, 0xabc5a10880f50, 0xabc8e37e932c4,
+    0xabc9e0075c0dd, 0xabcaf62eb7759, 0xabcc3bea478d6, 0xabcfdd88c324c,
+    0xabe1dd5ed9223, 0xabf7e1a4c4737, 0xabfb9938e234b, 0xac0d2047fc4bd,
+    0xac0da7b2b9a36, 0xac0ecc7dd67c6, 0xac25ee3cfdee0, 0xac26eac5c6cf9,
+    0xac29972d5a3e0, 0xac35df0bdffe7, 0xac38c9ab698e3, 0xac3ceb62c1c48,
+    0xac40e12ed5a71, 0xac4b42487b71f, 0xac4c0f3d0fa37, 0xac4e16f262d7d,
+    0xac5718b1e604b, 0xac5823de70578, 0xac610fa8515a8, 0xac61553228347,
+    0xac61e045d5e85, 0xac6d78774a59c, 0xac6ddef194728, 0xac6f6a36fb644,
+    0xac6fd45a35d95, 0xac7529806c59e, 0xac7c706653e4f, 0xac7eb9fc8d96f,
+    0xac832fe17e187, 0xac849ddf6227b, 0xac85f23eb3b0c, 0xac89e461d7370,
+    0xac8b525fbb464, 0xac8de17fcbd23, 0xac91d3a2ef587, 0xac9583e52c611,
+    0xac96b3ab1a4f0, 0xaca1f4060618f, 0xaca9c5ff9b57e, 0xacb414cc8f553,
+    0xacb54be45dfbc, 0xacb811ea83f06, 0xacbb4d0eb56f0, 0xacbd2186e3970,
+    0xacc3cb0c6ba0a, 0xacc8e94c8cb88, 0xaccff5a36e7e9, 0xacd07d0e2bd62,
+    0xacd5f6cdc5f1d, 0xacd6d9b7fc4d3, 0xacd7ec366758a, 0xacdbcfb5c96da,
+    0xacde74cb7c237, 0xace0f8f0bb9a7, 0xace100429c531, 0xace455056057e,
+    0xacee8a33c1cf0, 0xacef91b75bc58, 0xacf64637b4e41, 0xad006cc254e9f,
+    0xad0e3c3d513fd, 0xad16e27d5b68e, 0xad19336575d38, 0xad246173afcfe,
+    0xad400b6479909, 0xad4012b65a493, 0xad413b2a677e8, 0xad4964fa8564f,
+    0xad4ff88a6b44b, 0xad579746db774, 0xad59b148e0793, 0xad655bc706b83,
+    0xad6716a0a25a0, 0xad6eae0b31d3f, 0xad70aac5b3f36, 0xad76d0896f01c,
+    0xad7f4735447ac, 0xad8168892a355, 0xad82a6f2d9948, 0xad82fec9623c0,
+    0xad83a37ba2761, 0xad8848f4c7a7a, 0xad8c4d649cfb7, 0xad8fe408477de,
+    0xad9db38343d3c, 0xada0a574ae1c2, 0xadb09643902c9, 0xadb28bac31936,
+    0xadb321bab05c3, 0xadb64e3b20699, 0xadc951ebe0013, 0xadcf2e7cd3d95,
+    0xadd47ffa19fd9, 0xadd699fc1eff8, 0xadd9cdce6fc58, 0xadddebdcd79f8,
+    0xaddf687e7d200, 0xade02a78403c9, 0xadee9001bb5b4, 0xadf0e0e9d5c5e,
+    0xadf17e4a35475, 0xadfc3e8c44725, 0xadfcb0015fa00, 0xadfddfc74d8df,
+    0xae056fdffc4f4, 0xae0b95a3b75da, 0xae0d2f8cdfc0a, 0xae147672c74bb,
+    0xae1e5b1c80d3f, 0xae1f5ef72a6e2, 0xae23fd1e6ee71, 0xae2fc88d0864e,
+    0xae3967aceb133, 0xae3f1100b9def, 0xae5f1e89c2539, 0xae61a657f226e,
+    0xae61e48fe8483, 0xae6bdf2f43fa5, 0xae6df588589ff, 0xae6ef963023a2,
+    0xae8342cf51e99, 0xae8bf40a2d279, 0xae95b41a8314b, 0xae984a8c74594,
+    0xaea6ee4de5994, 0xaead9b7c5dff3, 0xaeaee137ee170, 0xaeb77c7d272b2,
+    0xaeb78ec9d8f8b, 0xaeb88efb92369, 0xaec8e644be5fc, 0xaece6aff29906,
+    0xaed6d6b02df47, 0xaed97f6ed1069, 0xaeddf8fcb1e46, 0xaedeaffba3ec0,
+    0xaedff96024602, 0xaee0d14f89a69, 0xaee1a1ed0e346, 0xaee973e6a3735,
+    0xaeed86fa3a386, 0xaeedf86f55661, 0xaef7978f38146, 0xaef98cf7d97b3,
+    0xaef9fac4044c9, 0xaefc654ab13d6, 0xaefe23cd3d3b8, 0xaf0904ffbfa55,
+    0xaf0cfe74c3e43, 0xaf16d47abbfb3, 0xaf21617fa619d, 0xaf2d8fbf99541,
+    0xaf2e51b95c70a, 0xaf3f082af1f9f, 0xaf3f42b9f7bef, 0xaf3fd17695cf2,
+    0xaf46c7d7d56b5, 0xaf4b784bcbb1d, 0xaf4ee30431e08, 0xaf51d14cabcc9,
+    0xaf592a7f45253, 0xaf693c3e9a747, 0xaf72ba6e09e3f, 0xaf799730b6f9f,
+    0xaf7daded3e1b5, 0xaf84a0a58d5b3, 0xaf84e62f64352, 0xaf88ac674367a,
+    0xaf918d365355b, 0xaf9bd85a56f6b, 0xaf9f3f69ccc91, 0xafa0d20114737,
+    0xafac36f563d88, 0xafad4221ee2b5, 0xafb52dba15f07, 0xafbfa1206d88e,
+    0xafc7e48f1df58, 0xafc8267004732, 0xafcfd77926734, 0xafd032f89f771,
+    0xafd58475e59b5, 0xafd5fd3ce181a, 0xafd9ce6f91c91, 0xafdf1149167c1,
+    0xafe0f80df671a, 0xafe62c43b9b36, 0xafe8d1596c693, 0xaffe6b7c1d456,
+    0xafff018a9c0e3, 0xb008af4e402dc, 0xb00f81161c2ed, 0xb0287af66224c,
+    0xb028ddc7bbe13, 0xb02eabb4ee481, 0xb030c95fe3a65, 0xb030f54b27fa1,
+    0xb03121366c4dd, 0xb0392dbf0751c, 0xb05928fb5df8d, 0xb05f0935422d4,
+    0xb06ceafcf050b, 0xb06e1370fd860, 0xb06fe4403b51b, 0xb073d6635ed7f,
+    0xb0795774d9ac4, 0xb07987090e5c5, 0xb07ef5cdd7631, 0xb07f798fa45e5,
+    0xb08146b5f1cdb, 0xb0857ab9fbd19, 0xb088cf7cbfd66, 0xb08dcccc6daf7,
+    0xb08e83cb5fb71, 0xb0985d7a482a6, 0xb0986fc6f9f7f, 0xb0a0abe3c9abf,
+    0xb0a3b773c67a8, 0xb0a84aa039de8, 0xb0aed6de3f05a, 0xb0af6943cd722,
+    0xb0bd734dcf8d0, 0xb0bdc77b67d83, 0xb0bee14bb39c4, 0xb0bf9f9c865c8,
+    0xb0c79d815fef3, 0xb0e6654ed84c0, 0xb0eafc243c0c5, 0xb0fcffa342661,
+    0xb105346e31617, 0xb105555ea4a04, 0xb109527c993b7, 0xb10fd3bfcd4da,
+    0xb111072eab97e, 0xb11f1138adb2c, 0xb11f9c4c5b66a, 0xb120e5b0dbdac,
+    0xb124fc6d62fc2, 0xb131a71d42790, 0xb134cff4c22a1, 0xb1396a731646b,
+    0xb13e8c5c27bae, 0xb1402645501de, 0xb149ba6a61b74, 0xb1554ef2e5cc6,
+    0xb157236b13f46, 0xb158a00cb974e, 0xb168e50933d08, 0xb16cc88895e58,
+    0xb16ed3e6d9763, 0xb17166afda5e7, 0xb189172b9fe04, 0xb18f44413ba74,
+    0xb190a7444ea19, 0xb1a97c8b30fc6, 0xb1aec6b696680, 0xb1b30d0752397,
+    0xb1bf673289c77, 0xb1c18fd8503aa, 0xb1c97075a6ead, 0xb1cd8adb1e688,
+    0xb1d024f600096, 0xb1dfd78cebf88, 0xb1e095ddbeb8c, 0xb1e7b481524c6,
+    0xb1f5df7bc7a61, 0xb1f6967ab9adb, 0xb1f8c2c9707d3, 0xb1f9727681cc3,
+    0xb1fda67a8bd01, 0xb20094c305bc2, 0xb210efb52241a, 0xb217f4ba234f1,
+    0xb218ba5cd6c7f, 0xb2367e4fa58a9, 0xb23b326c8c2d6, 0xb23e1d0c15bd2,
+    0xb24dd6f4e264e, 0xb2551a31d993a, 0xb2595930b4ac7, 0xb25bc76051f99,
+    0xb25d982f8fc54, 0xb261e2293bf30, 0xb2647fed0df03, 0xb28122bdb0362,
+    0xb2849870e779c, 0xb297e1ab7deb5, 0xb299dabd0fae7, 0xb29b575eb52ef,
+    0xb2afaf6ec64fa, 0xb2b0a0fcbe1c4, 0xb2b3167e3c220, 0xb2d766af100bc,
+    0xb2e4ff43f6f8f, 0xb2f1f6cf8e086, 0xb2f52e4acf2ab, 0xb2ffcd9c6b16e,
+    0xb309740e2e7dd, 0xb30fafc78bb61, 0xb318bc81dff7e, 0xb321d437054ea,
+    0xb32278e94588b, 0xb328a5fee14fb, 0xb329ef6361c3d, 0xb32eecb30f9ce,
+    0xb32ef404f0558, 0xb347afad402a2, 0xb349cd5835886, 0xb34ce78bf3c83,
+    0xb34d3bb98c136, 0xb3511b8ffdcc1, 0xb351e88491fd9, 0xb35874c29724b,
+    0xb36df8efa5d70, 0xb36e546f1edad, 0xb3769f2fb0001, 0xb378f017ca6ab,
+    0xb3790d5f4d4d3, 0xb37fc58896c81, 0xb385344d5fced, 0xb3864322da7df,
+    0xb388e0e6ac7b2, 0xb391f149f1194, 0xb39cb18c00444, 0xb39d38f6bd9bd,
+    0xb3a787c3b1992, 0xb3a7c5fba7ba7, 0xb3ad436432327, 0xb3af8cfa6be47,
+    0xb3c73d7631664, 0xb3cb7c750c7f1, 0xb3d2bc0913518, 0xb3d5b8f54eaed,
+    0xb3d7e19b15220, 0xb3e107f3fbea0, 0xb3f851f577531, 0xb3ff868ead109,
+    0xb3ffbd74c2794, 0xb40687eabdc1b, 0xb40f7b067f7d5, 0xb41486f9eec7a,
+    0xb417e30e93851, 0xb4273dcef6ccb, 0xb42b214e58e1b, 0xb42d130e09ec3,
+    0xb4319b3fac3b4, 0xb436b22dec9a8, 0xb4377b79906fb, 0xb4383d73538c4,
+    0xb4406aec61cf0, 0xb448e54127a45, 0xb44ec57b0bd8c, 0xb451cd62184b0,
+    0xb45d2009b5e28, 0xb45e02f3ec3de, 0xb45e203b6f206, 0xb45fd76c1a65e,
+    0xb460209ee19c2, 0xb4684a6eff829, 0xb4713d8ac13e3, 0xb47ee86c59f8f,
+    0xb4852b7797e9d, 0xb48632fb31e05, 0xb48f55ab284c0, 0xb4948637fb317,
+    0xb495741d02a1c, 0xb49f58c6bc2a0, 0xb4a193b9346ac, 0xb4a8d34d3b3d3,
+    0xb4b18488167b3, 0xb4b5f31b26441, 0xb4b9fb33ebf43, 0xb4bcfbc917add,
+    0xb4bfc5782dfec, 0xb4c49333a727c, 0xb4ccd2f967381, 0xb4ce98cdd3eed,
+    0xb4d1627cea3fc, 0xb4d675c23a42b, 0xb4e08a00287b0, 0xb4ece0826facb,
+    0xb4f71207e0c78, 0xb4f71959c1802, 0xb4fdf9c55ef27, 0xb50f847d6965e,
+    0xb51656454566f, 0xb5381ea7e97d6, 0xb539dd2a757b8, 0xb53bb8f4845c2,
+    0xb53cbccf2df65, 0xb5462508fb3bf, 0xb547378766476, 0xb547880c0e364,
+    0xb5558ac42f988, 0xb55907c94794c, 0xb5653d5b1b87a, 0xb567366cad4ac,
+    0xb5719b2f4371f, 0xb572e83cb4426, 0xb5735d5abfcc6, 0xb575566c518f8,
+    0xb57da87ec36d6, 0xb581d187fc5c5, 0xb59901eae53f3, 0xb5990ce5b6542,
+    0xb5a9224dfbffb, 0xb5af396df59cd, 0xb5bbeb6fb5d25, 0xb5c152e29e207,
+    0xb5c6fc366cec3, 0xb5c984049cbf8, 0xb5cd84cb81b70, 0xb5ce8c4f1bad8,
+    0xb5d770c71bf7e, 0xb5df6b03052e4, 0xb5df93455925b, 0xb5e73201c9584,
+    0xb5e80d9a1efb0, 0xb5edf17cf38bc, 0xb5ee335dda096, 0xb5f066fe71918,
+    0xb5f7fe69010b7, 0xb5ff8e81afccc, 0xb600b6f5bd021, 0xb600fc7f93dc0,
+    0xb6096487a7e3c, 0xb6096f8278f8b, 0xb6136678e44e8, 0xb619f65fd9d1f,
+    0xb61b7dfc50676, 0xb61b9b43d349e, 0xb61e94871e4ae, 0xb61faaae79b2a,
+    0xb628eaa5f300d, 0xb6297d0b816d5, 0xb629f97b6daff, 0xb63c671dae7ec,
+    0xb6484130096dd, 0xb653644372554, 0xb655c3cf4e312, 0xb65622f7b7914,
+    0xb656efec4bc2c, 0xb658b5c0b8798, 0xb6638bf869ce6, 0xb666d9694d1a9,
+    0xb671a4a62d5a8, 0xb671f8d3c5a5b, 0xb675eaf6e92bf, 0xb6776046adf3d,
+    0xb67922722a4e4, 0xb67d392eb16fa, 0xb67f657d683f2, 0xb68253c5e22b3,
+    0xb6990066fe12d, 0xb6a8786ee43cf, 0xb6bbd3f62c7c1, 0xb6c0587ede6ed,
+    0xb6ccdaec69f44, 0xb6dc654101ebf, 0xb6de410b10cc9, 0xb6df0304d3e92,
+    0xb6df5e844cecf, 0xb6e091f32b373, 0xb6e0e9c9b3deb, 0xb6e36a4602f96,
+    0xb6f0a009902a2, 0xb6f6f90a7044e, 0xb6f7ee41586dd, 0xb6ff43cb016a2,
+    0xb70d6773960b3, 0xb70efa0addb59, 0xb70f4a8f85a47, 0xb719627664391,
+    0xb73d2ee56b279, 0xb74ccb86b4ecd, 0xb754c96b8e7f8, 0xb75721a589a2c,
+    0xb75cb503b644a, 0xb75cebe9cbad5, 0xb75f700f0b245, 0xb761114a143ff,
+    0xb77f58feaffdd, 0xb789fbf93c465, 0xb7949b4ad8328, 0xb7a0a89a582df,
+    0xb7a9771cb64e7, 0xb7adbd6d721fe, 0xb7b9818a2ae51, 0xb7c2af34f265b,
+    0xb7c4df2c99918, 0xb7c5afca1e1f5, 0xb7c68f0b641e6, 0xb7d0392617e1a,
+    0xb7d75b729bd19, 0xb7e53d3a49f50, 0xb7ed8f4cbbd2e, 0xb800586e75a58,
+    0xb81ab551ce95c, 0xb826bb4f6dd89, 0xb82fa3705e7f4, 0xb833ab89242f6,
+    0xb8353e206bd9c, 0xb83c7a0b824fe, 0xb83d9784be704, 0xb83f38bfc78be,
+    0xb8467bfcbebaa, 0xb854dddd497d0, 0xb859b2eaa35ea, 0xb85aedab62618,
+    0xb86079b7ae4ac, 0xb87e27b4dae38, 0xb88813b075246, 0xb8961dba773f4,
+    0xb897f984861fe, 0xb89ab48fdaff9, 0xb89eb8ffb0536, 0xb89f6503d1461,
+    0xb8a5e9eff5b49, 0xb8b9df2ead18d, 0xb8c3efc3aaf4d, 0xb8c6828cabdd1,
+    0xb8c95adf839f4, 0xb8d39b08b62b5, 0xb8d7da0791442, 0xb8d9e1bce4788,
+    0xb8ddf8796b99e, 0xb8dec916f027b, 0xb8f1e2bd51e93, 0xb9004befbd643,
+    0xb904edbff2397, 0xb9067905592b3, 0xb9090f774a6fc, 0xb9192c3170d3f,
+    0xb9489bccbd48d, 0xb94de24f32582, 0xb958d22576333, 0xb95c61773ffd0,
+    0xb95d14cd41a85, 0xb960d00a4fc5e, 0xb963a4b4372bc, 0xb983de2883f42,
+    0xb98d6000e3bff, 0xb999d3caadd42, 0xb9a1469bd9b2f, 0xb9a74b6f21828,
+    0xb9b4ad1df3070, 0xb9b973878b776, 0xb9beaf0f2f71c, 0xb9bee99e3536c,
+    0xb9ca85789a048, 0xb9cfa3b8bb1c6, 0xb9d065b27e38f, 0xb9d2a0a4f679b,
+    0xb9d821b6714e0, 0xb9f3c0ac69f9c, 0xb9f8e63e6bca4, 0xb9f9490fc586b,
+    0xb9fe0f795df71, 0xba09990710f74, 0xba0eb747320f2, 0xba199879b478f,
+    0xba2f11abf2165, 0xba2f364555b17, 0xba2f4fe3e837a, 0xba305767822e2,
+    0xba310e667435c, 0xba31e9fec9d88, 0xba34d84743c49, 0xba39eb8c93c78,
+    0xba3f8d8e81daa, 0xba4c25f1af89f, 0xba5ba54b766cb, 0xba6494be47cc0,
+    0xba69502d0f277, 0xba6b41ecc031f, 0xba76ddc724ffb, 0xba816acc0f1e5,
+    0xba86e48ba93a0, 0xba89f3c49664e, 0xba914cf72fbd8, 0xba98f30580a8b,
+    0xba9eb5f7e1faa, 0xbaa0c4ff15e7a, 0xbab8a50f10198, 0xbac4692bc8deb,
+    0xbad0fde60631b, 0xbad61fcf17a5e, 0xbad953a1686be, 0xbadc6a2c364f6,
+    0xbae0f9afb9571, 0xbae1134e4bdd4, 0xbae6e88d5efcc, 0xbae6f731206e0,
+    0xbae8c8005e39b, 0xbae9237fd73d8, 0xbaeac863d0b57, 0xbaf2c648aa482,
+    0xbaf608bebc7f6, 0xbaf6c3669ee35, 0xbaf9562f9fcb9, 0xbafd868ab9732,
+    0xbb08298545bba, 0xbb09bfc57dc25, 0xbb0cae0df7ae6, 0xbb0d3cca95be9,
+    0xbb1039b6d11be, 0xbb2f5208f1679, 0xbb3456aa7ff94, 0xbb3e9e25933df,
+    0xbb459f81a3ef1, 0xbb48f44467f3e, 0xbb517d3cef3a7, 0xbb602fa221ebb,
+    0xbb67f2f7f5b96, 0xbb6c702ec6f38, 0xbb76b400e9dbe, 0xbb76c9f68c05c,
+    0xbb7a88dc8a7fa, 0xbb804825fb754, 0xbb85d08957023, 0xbb8707a125a8c,
+    0xbb87f92f1d756, 0xbb8b603e9347c, 0xbb8c90048135b, 0xbb970713c92a7,
+    0xbb97454bbf4bc, 0xbb9fb84ea4687, 0xbba1264c8877b, 0xbbb710f3e142c,
+    0xbbbce28a0405f, 0xbbc63fc90036a, 0xbbc85d73f594e, 0xbbc8d63af17b3,
+    0xbbcf27e9f0dd5, 0xbbcfababbdd89, 0xbbd5883cb1b0b, 0xbbd5a92d24ef8,
+    0xbbd7dccdbc77a, 0xbbed400a57eb2, 0xbbeea6b65b41c, 0xbbf0da56f2c9e,
+    0xbbf887b7246db, 0xbbfd0c3fd6607, 0xbc00e86d57bcd, 0xbc0753bae9a52,
+    0xbc0bc99fda26a, 0xbc197f7c43f65, 0xbc20723493363, 0xbc20bb675a6c7,
+    0xbc258579e3392, 0xbc2bc885212a0, 0xbc2e3e069f2fc, 0xbc2ee661cfc62,
+    0xbc315f8c3e283, 0xbc37f6c514644, 0xbc40f88497912, 0xbc48fdbb51dc7,
+    0xbc4bb174c6038, 0xbc51153ebdf55, 0xbc52ab7ef5fc0, 0xbc5c6b8f4be92,
+    0xbc5cbfbce4345, 0xbc5d3c2cd076f, 0xbc6d678ab84c6, 0xbc706bc8d4625,
+    0xbc75cbe9dbf7d, 0xbc77781fb6286, 0xbc7bc96b430ec, 0xbc89c87a7414b,
+    0xbc8e19c600fb1, 0xbc99fed32cff1, 0xbc9b1c4c691f7, 0xbca1f56625d92,
+    0xbca39df30fad6, 0xbca917b2a9c91, 0xbcb6bb4261cb3, 0xbcb8ca4995b83,
+    0xbccf76eab19fd, 0xbce194084a7fc, 0xbce7dabc78ccf, 0xbcf46bcdc5c3a,
+    0xbcf8b5c771f16, 0xbd07cafdfe5f1, 0xbd167d6331105, 0xbd1b8cff90b6f,
+    0xbd1bc78e967bf, 0xbd2ce47a761e0, 0xbd2d60ea6260a, 0xbd37a4bc85490,
+    0xbd394d496f1d4, 0xbd399a2526afd, 0xbd41109f42eaf, 0xbd46c144f26f5,
+    0xbd55a33e59d0a, 0xbd5cc58addc09, 0xbd5df8f9bc0ad, 0xbd61bf319b3d5,
+    0xbd61ca2c6c524, 0xbd6b8de5b29bb, 0xbd75170ff3202, 0xbd79cb2cd9c2f,
+    0xbd7f030b8d610, 0xbd809cf4b5c40, 0xbd88fa01f8b6d, 0xbd98670f0dcc0,
+    0xbd9fa2fa24422, 0xbda010c64f138, 0xbda194b9d54ca, 0xbda423d9e5d89,
+    0xbda8ea437e48f, 0xbdaef2bfb674d, 0xbdaefdba8789c, 0xbdb16841347a9,
+    0xbdc00c02a5ba9, 0xbdc0727cefd35, 0xbdcfe6dbe5a12, 0xbde3bb2a29c69,
+    0xbde74e24e3ecb, 0xbde8c71d9910e, 0xbe070ed234cec, 0xbe0b2937ac4c7,
+    0xbe13f7ba0a6cf, 0xbe15202e17a24, 0xbe17d3e78bc95, 0xbe1a71ab5dc68,
+    0xbe28f8254c240, 0xbe2ed4b63ffc2, 0xbe306e9f685f2, 0xbe40b744d3171,
+    0xbe46a27988607, 0xbe47ef86f930e, 0xbe4bfef19f99a, 0xbe4d9c83b858f,
+    0xbe55bf01f586c, 0xbe59176da9e7e, 0xbe5dd685619fa, 0xbe5e52f54de24,
+    0xbe6070a043408, 0xbe6942cb91bd5, 0xbe714bab3c64f, 0xbe71863a4229f,
+    0xbe78d4720a6da, 0xbe83c09f5dec6, 0xbe8c4d40d58f4, 0xbe8d1dde5a1d1,
+    0xbea4fa4563f2a, 0xbea9060719ff1, 0xbeb328e8c9a8a, 0xbeb917c66f4e5,
+    0xbebf65cc7e542, 0xbebf86bcf192f, 0xbed5d08cb3be2, 0xbed85facc44a1,
+    0xbee168be282f9, 0xbeedfd7865829, 0xbf06d2bf47dd6, 0xbf0f78ff52067,
+    0xbf142973484cf, 0xbf1459077cfd0, 0xbf1660bcd0316, 0xbf2cd2cee6540,
+    0xbf3065c9a07a2, 0xbf350047f496c, 0xbf37303f9bc29, 0xbf3d81ee9b24b,
+    0xbf468ea8ef668, 0xbf712cdfd2561, 0xbf77a32835535, 0xbf794bb51f279,
+    0xbf8f11c314578, 0xbf901946ae4e0, 0xbf9593064869b, 0xbfa3af5cfc522,
+    0xbfa9eebf49e6b, 0xbfaf7379b5175, 0xbfc5c0f2679ed, 0xbfc6b2805f6b7,
+    0xbfc7d74b7c447, 0xbfc9f4f671a2b, 0xbfcedff96dae3, 0xbfcf67642b05c,
+    0xbfd0fda4630c7, 0xbfde4d0682c36, 0xbfdf757a8ff8b, 0xbfe1d8af5c30e,
+    0xbfe68ccc42d3b, 0xbff0f18ed8fae, 0xbffe4bebc9c6c, 0xc010f0741ffe4,
+    0xc013ab7f74ddf, 0xc01fec0c19e5c, 0xc0276d810735d, 0xc031940ba73bb,
+    0xc044d24b6c985, 0xc046f74842af3, 0xc06d94b840a74, 0xc0702b2a31ebd,
+    0xc0766e356fdcb, 0xc0abf899a64b0, 0xc0c943c579275, 0xc0c9c78746229,
+    0xc0c9efc99a1a0, 0xc0dc64bdbba17, 0xc0e9eb05f0c11, 0xc0f2705587ab5,
+    0xc116615df234f, 0xc12f6d8ae9f87, 0xc1351a87a9208, 0xc1365c9a48dc0,
+    0xc13f8a45105ca, 0xc1477986287e1, 0xc14a767263db6, 0xc15f0cba6b1d6,
+    0xc169125497e47, 0xc16deb0ae2226, 0xc17614db0008d, 0xc1817d783fca3,
+    0xc185141bea4ca, 0xc18f5b96fd915, 0xc191f208eed5e, 0xc19905b1b1549,
+    0xc19eb2ae707ca, 0xc1aa9f0d7d394, 0xc1ac4b435769d, 0xc1add2dfcdff4,
+    0xc1c047d3ef86b, 0xc1c945ea82574, 0xc1c98079881c4, 0xc1cc0847b7ef9,
+    0xc1cf520faadf7, 0xc1d0a2c60c0c3, 0xc1d72f0411335, 0xc1d7ed54e3f39,
+    0xc1db6e02ec4c2, 0xc1dd6abd6e6b9, 0xc201ee2b6761b, 0xc2154d5b9ffd2,
+    0xc21c43bcdf995, 0xc21fe1b26ad46, 0xc224fc499b8ff, 0xc237c56b55629,
+    0xc23d5c727260c, 0xc2411b5870daa, 0xc25f964a31a4e, 0xc26fabb277507,
+    0xc270b3361146f, 0xc278643f33471, 0xc27d5694100b3, 0xc286eab921a49,
+    0xc2a70e37cc431, 0xc2be3e9ab525f, 0xc2c1e3e22119a, 0xc2c5a6710fefd,
+    0xc2c8ddec51122, 0xc2cea830931cb, 0xc2ceb32b6431a, 0xc2d9a301a80cb,
+    0xc2de278a59ff7, 0xc2df65f4095ea, 0xc2dfe60ce5fd9, 0xc2f1c149985fe,
+    0xc30e04f1d145b, 0xc325bcbf77802, 0xc328d34a4563a, 0xc32e754c3376c,
+    0xc33f78998092a, 0xc3606563cf065, 0xc3816f75a05c8, 0xc38656cfac0bb,
+    0xc396d65b2c2c5, 0xc3a6b1346c12e, 0xc3a7732e2f2f7, 0xc3af0e41af05b,
+    0xc3b0330ccbdeb, 0xc3b5c2c208244, 0xc3b9ce83be30b, 0xc3be187d6a5e7,
+    0xc3d3278c6d86c, 0xc3d5c8f92fe04, 0xc3dd04e446566, 0xc3df228f3bb4a,
+    0xc3e83ded5167b, 0xc3ee6b02ed2eb, 0xc3f1260e420e6, 0xc3f57007ee3c2,
+    0xc4036b6e2ee5c, 0xc40aff2fce036, 0xc422d7ede77ca, 0xc428ca747d7ea,
+    0xc43b30c4dd94d, 0xc446a45cee6b2, 0xc4470e8028e03, 0xc45181e68078a,
+    0xc4611e87ca3de, 0xc4613f783d7cb, 0xc4616f0c722cc, 0xc46e076f9fdc1,
+    0xc479e181facb2, 0xc48630b261443, 0xc49fc0a12602f, 0xc4ae979fbc4f5,
+    0xc4bd58a8b071d, 0xc4c0f2f54b509, 0xc4c839db32dba, 0xc4cc99ca81334,
+    0xc4d163dd09fff, 0xc4d768b051cf8, 0xc4e1a187a3a2f, 0xc4eebdac9e4d8,
+    0xc4eed3a240776, 0xc4fbdd7a89546, 0xc51127189241b, 0xc5399ca9aebe1,
+    0xc53f7ce392f28, 0xc547c3fb33bb7, 0xc54ddec41db4e, 0xc5611d03e3118,
+    0xc5655c02be2a5, 0xc569e7dd50d5b, 0xc573193108b2a, 0xc5772149ce62c,
+    0xc581fb2a7013f, 0xc586417b2be56, 0xc586af4756b6c, 0xc5907dfb6e152,
+    0xc5917332563e1, 0xc5ada836cdb2a, 0xc5b8d9edf80b5, 0xc5c12fa95a458,
+    0xc5cea74dcdf3e, 0xc5d07f6eec783, 0xc5d1f867a19c6, 0xc5d8a93f0a5ea,
+    0xc5e27f450275a, 0xc5e987f2f3df6, 0xc5fce7232c7ad, 0xc5fdacc5dff3b,
+    0xc5ffd56ba666e, 0xc60694e6d09a6, 0xc60dbe853542f, 0xc614680abd4c9,
+    0xc626a9c1b9c7a, 0xc62af7645651b, 0xc62d7437b5101, 0xc62f624e75be4,
+    0xc63abc47f40e6, 0xc63e703321735, 0xc6458784d44e5, 0xc656be0f46769,
+    0xc661a693a9990, 0xc66491333328c, 0xc667fbeb99577, 0xc66953f3db3cd,
+    0xc66a8b0ba9e36, 0xc66f9aa8098a0, 0xc68ab9852596d, 0xc695343d5de7e,
+    0xc69d09dfe3832, 0xc69edaaf214ed, 0xc6a7d8c5b41f6, 0xc6b0188b742fb,
+    0xc6b6e6aa5fd47, 0xc6b8dfbbf1979, 0xc6d0f6b201322, 0xc6d547fd8e188,
+    0xc6ddac5cb1c3f, 0xc6df96ca8215d, 0xc6f54a8bc5783, 0xc6f590159c522,
+    0xc6f5b1060f90f, 0xc700a48543c85, 0xc70767a95e582, 0xc7086b8407f25,
+    0xc70c9f8811f63, 0xc712996088b0d, 0xc71901052a3cd, 0xc71bce5d30ea1,
+    0xc7263a71a7c9e, 0xc733cbb4adfe7, 0xc73f7632d43d7, 0xc7504642fc4cf,
+    0xc750d4ff9a5d2, 0xc753e08f972bb, 0xc7612ff1b6e2a, 0xc77484271e692,
+    0xc77c5d729460b, 0xc77d9f85341c3, 0xc780e1fb46537, 0xc785ccfe425ef,
+    0xc78ff388e264d, 0xc7996e0f61780, 0xc7a95b35532c2, 0xc7b6441d28ca5,
+    0xc7b8aafae55ed, 0xc7c9da3376ce7, 0xc7cc5706d58cd, 0xc7cee27df5bc7,
+    0xc7d6938717bc9, 0xc7da4b1b357dd, 0xc7db7738330f7, 0xc7f41941f05de,
+    0xc80394f2c6e45, 0xc806c8c517aa5, 0xc80fad3d17f4b, 0xc815f3f14641e,
+    0xc81c2c01b31dd, 0xc81ca8719f607, 0xc8328f7007cf3, 0xc8362d65930a4,
+    0xc83c98b324f29, 0xc8459a72a81f7, 0xc8467d5cde7ad, 0xc84f2745d9003,
+    0xc84f40e46b866, 0xc8579a48be1ce, 0xc88422ed715e5, 0xc88e45cf2107e,
+    0xc89c990bea590, 0xc8a646cf8e789, 0xc8cf82035e6dd, 0xc8d68ab14fd79,
+    0xc8f36bb9e83ed, 0xc900b77317997, 0xc9052d58081af, 0xc9193fde4261b,
+    0xc91d52f1d926c, 0xc92c4737f255a, 0xc933daf991734, 0xc93c4dfc768ff,
+    0xc9448a194643f, 0xc9460a63dc20c, 0xc96fb363d6e76, 0xc9768182c28c2,
+    0xc97855faf0b42, 0xc980abb652ee5, 0xc98f1c3a9f21f, 0xc98f4bced3d20,
+    0xc990f0b2cd49f, 0xc99270fd6326c, 0xc993f899d9bc3, 0xc99eefc1fe4fe,
+    0xc9ae4e2b51f3d, 0xc9b9ba7182118, 0xc9bebb6a2046e, 0xc9c5c41811b0a,
+    0xc9d89f867d50d, 0xc9e119db43262, 0xc9e83c27c7161, 0xc9f336f8dc061,
+    0xca04def8695c0, 0xca07d0e9d3a46, 0xca20bc2658291, 0xca313bb1d849b,
+    0xca3710f0eb693, 0xca53bebc5ec41, 0xca56bba89a216, 0xca75453e1c5ce,
+    0xca75a0bd9560b, 0xca7ca219a611d, 0xca7fb8a473f55, 0xca865e810ba2a,
+    0xca91101f595c6, 0xcaa1e3d871c83, 0xcaa2b0cd05f9b, 0xcaa4c37d2a430,
+    0xcaa826e3afb91, 0xcaaf0af83d87b, 0xcab64a8c445a2, 0xcab6eb959437e,
+    0xcab83c4bf564a, 0xcac8c329563de, 0xcac9767f57e93, 0xcaccceeb0c4a5,
+    0xcacf56b93c1da, 0xcad1780d21d83, 0xcad8b04f47f20, 0xcad942b4d65e8,
+    0xcadddd332a7b2, 0xcae68ac5155cd, 0xcae8c5b78d9d9, 0xcb007d8533d80,
+    0xcb06775daa92a, 0xcb10e37221727, 0xcb12f279555f7, 0xcb169b69b1af7,
+    0xcb1759ba846fb, 0xcb18c06687c65, 0xcb1f5ef13ebb0, 0xcb21df6d8dd5b,
+    0xcb383f32f22ac, 0xcb44db3f10366, 0xcb4d175bdfea6, 0xcb4e0c92c8135,
+    0xcb5147b6f991f, 0xcb59c20bbf674, 0xcb5c8bbad5b83, 0xcb5e518f426ef,
+    0xcb61e0e10c38c, 0xcb66de30ba11d, 0xcb6870c801bc3, 0xcb8071c86f2ce,
+    0xcb8ab59a92154, 0xcb8daedddd164, 0xcb9658c6d79ba, 0xcb9eda6d7e299,
+    0xcba7cd893fe53, 0xcbb5c54690328, 0xcbb8790004599, 0xcbb8e6cc2f2af,
+    0xcbb93ea2b7d27, 0xcbbcca4b913ff, 0xcbbdfdba6f8a3, 0xcbbe76816b708,
+    0xcbc0b51cd40d9, 0xcbd57af90fffa, 0xcbd64444b3d4d, 0xcbdf71ef7b557,
+    0xcbe03ee40f86f, 0xcbe1d524478da, 0xcbe9de03f2354, 0xcbeb694959270,
+    0xcbec622931ac4, 0xcbffaf0cb87a2, 0xcc160b292c72e, 0xcc1f59c467325,
+    0xcc370de91d107, 0xcc3bd7fba5dd2, 0xcc3fe0146b8d4, 0xcc4188a155618,
+    0xcc5100a93b8ba, 0xcc54a247b7230, 0xcc5910dac6ebe, 0xcc5b56c810419,
+    0xcc5e157c557d9, 0xcc6f8a3ebdc72, 0xcc71c5313607e, 0xcc96c8b80b9cf,
+    0xcc9f5cab63f87, 0xcca9c516ea7bf, 0xccb11aa093784, 0xccb176200c7c1,
+    0xccbaa021e3a06, 0xccbc8a8fb3f24, 0xccbf7c811e3aa, 0xccc5556921b67,
+    0xccd053e32702c, 0xcced29f0ee551, 0xccf0b942b81ee, 0xccfea25c46faf,
+    0xcd007e2655db9, 0xcd050657f82aa, 0xcd185e36500d7, 0xcd1c873f88fc6,
+    0xcd2cd38de410a, 0xcd2e95b9606b1, 0xcd33462d56b19, 0xcd3e40fe6ba19,
+    0xcd68457ddf6c0, 0xcd6f46d9f01d2, 0xcd7029c426788, 0xcd84bc633d5e3,
+    0xcd8b957cfa17e, 0xcd91c63b863b3, 0xcda0d7c9224c9, 0xcda1284dca3b7,
+    0xcda1a4bdb67e1, 0xcdb1415f00435, 0xcdb3874c49990, 0xcdbca9fc4004b,
+    0xcdc3ab5850b5d, 0xcdc68ea5f98cf, 0xcdcefa56fdf10, 0xcdd0a68cd8219,
+    0xcdde37cfde562, 0xcde7c84bff933, 0xcdeb5eefaa15a, 0xcded24c416cc6,
+    0xcdef2c796a00c, 0xce0c9143cf634, 0xce1b05710bf33, 0xce1f65605a4ad,
+    0xce21e98599c1d, 0xce2754a1726c4, 0xce2908292d557, 0xce2f40399a316,
+    0xce32ff1f98ab4, 0xce3bdc45b83d0, 0xce58ab019ed6b, 0xce617983fcf73,
+    0xce6493b7bb370, 0xce6e36808e41a, 0xce6f74ea3da0d, 0xce710ed36603d,
+    0xce7ccf472e6cb, 0xce80db08e4792, 0xce843e6f69ef3, 0xce9ffe55d5d9c,
+    0xcea3f421e9bc5, 0xcebf16a7f6257, 0xcec2bbef62192, 0xcec55d5c2472a,
+    0xceddc87fcc586, 0xcedfba3f7d62e, 0xcee7ce19f91f7, 0xcee89eb77dad4,
+    0xcee9b887c9715, 0xceed73c4d78ee, 0xcef9468551c55, 0xcefe6c175395d,
+    0xceff948b60cb2, 0xcf08b39266da8, 0xcf0d04ddf3c0e, 0xcf122e18e5edb,
+    0xcf14f41f0be25, 0xcf1e2cc4a477e, 0xcf2c198723b04, 0xcf354e83cbe98,
+    0xcf3f41d146e30, 0xcf4dcbf4259cd, 0xcf69723bff013, 0xcf6bc6cd09c82,
+    0xcf6f7ab8372d1, 0xcf6fec2d525ac, 0xcf758a8650119, 0xcf7ca580f348e,
+    0xcf85c487f9584, 0xcf8618b591a37, 0xcf8969cf654bf, 0xcf99a0281e365,
+    0xcfa2eec358f5c, 0xcfa7943c7e275, 0xcfaa3cfb21397, 0xcfc7638d907aa,
+    0xcfe68331917ef, 0xcfe8d070bb8d4, 0xcfee3b8c9437b, 0xcff10c8d8b414,
+    0xcff3fad6052d5, 0xd0141aabbf6f8, 0xd017ada67995a, 0xd01f3dbf2856f,
+    0xd02efef9d5b75, 0xd03082ed5bf07, 0xd031c8a8ec084, 0xd03be7e1ab558,
+    0xd04b2cac6c734, 0xd050cb056a2a1, 0xd0564fbfd55ab, 0xd063aa1cc6269,
+    0xd0682001b6a81, 0xd070b79dff5fe, 0xd072f9e258594, 0xd08ca36faf9e3,
+    0xd08ea77c12764, 0xd08f70c7b64b7, 0xd0976008ce6ce, 0xd09830a652fab,
+    0xd0a721437bcd4, 0xd0a7ea8f1fa27, 0xd0b03ca191805, 0xd0bb3772a6705,
+    0xd0c3b9194cfe4, 0xd0c7d37ec47bf, 0xd0d597feefbce, 0xd0d5d636e5de3,
+    0xd0e952aea15c2, 0xd0f788a3e7cac, 0xd10edda03448c, 0xd12138f5c34a0,
+    0xd12539bca8418, 0xd125cfcb270a5, 0xd13ed0fd4db8e, 0xd149d32043618,
+    0xd14c748d05bb0, 0xd154761acfaa0, 0xd1561afec921f, 0xd15817b94b416,
+    0xd158d9b30e5df, 0xd16e6531fdc8e, 0xd175877e81b8d, 0xd176805e5a3e1,
+    0xd17f22f5740ad, 0xd18648eae8571, 0xd1865b379a24a, 0xd190fe32266d2,
+    0xd1a7f406098b0, 0xd1b080a7812de, 0xd1b19e20bd4e4, 0xd1c91f084e200,
+    0xd1d1db3dfa72f, 0xd1d5b76b7bcf5, 0xd1db81afbdd9e, 0xd1e15e40b1b20,
+    0xd1e5665977622, 0xd1eb6783ced56, 0xd1ed3fa4ed59b, 0xd1f14f0f93c27,
+    0xd1f5537f69164, 0xd1fd08317b72b, 0xd1fe42f23a759, 0xd1ffce37a1675,
+    0xd203d9f95773c, 0xd20ecd788bab2, 0xd20f2ca0f50b4, 0xd2164049b789f,
+    0xd2195a7d75c9c, 0xd21d245e45589, 0xd22cd6f53147b, 0xd232ac3444673,
+    0xd23634342d786, 0xd239645d8de21, 0xd2467930a7d40, 0xd24751200d1a7,
+    0xd24ffb09079fd, 0xd250bd02cabc6, 0xd2552443f9cca, 0xd2679ce10bb06,
+    0xd26bbaef738a6, 0xd27d928335906, 0xd29078ec72458, 0xd294083e3c0f5,
+    0xd29c1fc1a8283, 0xd2a1f500bb47b, 0xd2a66ae5abc93, 0xd2ad47a858df3,
+    0xd2adb57483b09, 0xd2bde8244c3ea, 0xd2c7540709e09, 0xd2d8206e4193c,
+    0xd2d9ffe140d0b, 0xd2eee6adf0019, 0xd2f89f6c65361, 0xd2ff1d06a8ebf,
+    0xd303585c93a87, 0xd3068c2ee46e7, 0xd307014ceff87, 0xd3077dbcdc3b1,
+    0xd312be17c8050, 0xd31378bfaa68f, 0xd31fc09e30296, 0xd321f43ec7b18,
+    0xd33670e83c6d5, 0xd339f53f35223, 0xd3416bb9515d5, 0xd341e8293d9ff,
+    0xd34ed4ba039a7, 0xd355c77252da5, 0xd359531b2c47d, 0xd35977b48fe2f,
+    0xd35ea84162c86, 0xd367ce9a49906, 0xd369fe91f0bc3, 0xd3712f82361d6,
+    0xd37bd9cea31e8, 0xd3995f897bbfd, 0xd3ae8f88f226f, 0xd3b58993221f7,
+    0xd3b9e98270771, 0xd3c0e38ca06f9, 0xd3c116c9c57bf, 0xd3caeccfbd92f,
+    0xd3d228bad4091, 0xd3d7310552f71, 0xd3d8bc4ab9e8d, 0xd3e7884e7f204,
+    0xd3f49d2199123, 0xd3ff2dcf738d2, 0xd40691fcddfab, 0xd4078e85a6dc4,
+    0xd407d0668d59e, 0xd4107df8783b9, 0xd411090c25ef7, 0xd4139f7e17340,
+    0xd419e9db35dd8, 0xd42021eba2b97, 0xd4213bbbee7d8, 0xd42659fc0f956,
+    0xd42861b162c9c, 0xd42961e31c07a, 0xd4364acaf1a5d, 0xd437350708b9d,
+    0xd437e85d0a652, 0xd43eab8124f4f, 0xd43ee2673a5da, 0xd43f99662c654,
+    0xd4486f3a6b3e6, 0xd4491b3e8c311, 0xd44e7b5f93c69, 0xd44f868c1e196,
+    0xd4598fcf3b3cc, 0xd4737042a7ea6, 0xd479eddceba04, 0xd486396461bd0,
+    0xd495858103936, 0xd4b533e1a2a7e, 0xd4b8d5801e3f4, 0xd4bf272f1da16,
+    0xd4bf8a00775dd, 0xd4c52107945c0, 0xd4d1026bd003b, 0xd4d97cc095d90,
+    0xd4dcb43bd6fb5, 0xd4e4445485bca, 0xd4e577c36406e, 0xd50108159b416,
+    0xd519a2cd77d73, 0xd51e867e932a1, 0xd52d89686dca3, 0xd5305a6964d3c,
+    0xd531200c184ca, 0xd531ed00ac7e2, 0xd5485417f18bd, 0xd54cfd3a0719b,
+    0xd5505cf79c337, 0xd55373826a16f, 0xd5564f7e32357, 0xd56021db39f02,
+    0xd5667adc1a0ae, 0xd56c0e3a46acc, 0xd571a8ea54074, 0xd5732934e9e41,
+    0xd5791f6470426, 0xd58149348e28d, 0xd58504719c466, 0xd58a6f8d74f0d,
+    0xd5907f5b8dd55, 0xd59127b6be6bb, 0xd59519d9e1f1f, 0xd59e4b2d99cee,
+    0xd5b4f7ceb5b68, 0xd5b665cc99c5c, 0xd5b8244f25c3e, 0xd5b8f143b9f56,
+    0xd5b9b33d7d11f, 0xd5b9be384e26e, 0xd5cbf89d69e95, 0xd5cc875a07f98,
+    0xd5cf84464356d, 0xd5cfc62729d47, 0xd5d1d1856d652, 0xd5f1c1c6f2f74,
+    0xd5f82219b3caa, 0xd603d3e9bac24, 0xd613c4b89cd2b, 0xd621a6804af62,
+    0xd63809ee9fa78, 0xd641a90e8255d, 0xd64f83844fc0a, 0xd668dc8cff16b,
+    0xd6707ef25fa59, 0xd688490cb7ad9, 0xd68bbebfeef13, 0xd693a30635fdb,
+    0xd6a15539af711, 0xd6a852eccfc5e, 0xd6b171f3d5d54, 0xd6b1cd734ed91,
+    0xd6b7b4ff13c62, 0xd6b98d20324a7, 0xd6cb4ebe52269, 0xd6e0e18f224a2,
+    0xd6fb3e727b3a6, 0xd6fca1758e34b, 0xd706b5b37c6d0, 0xd70f7ce3f9d4e,
+    0xd7105fce30304, 0xd711cdcc143f8, 0xd719e1a68ffc1, 0xd720509d1240b,
+    0xd730c184d0f01, 0xd749e3a76add7, 0xd753239ee42ba, 0xd7552efd27bc5,
+    0xd760e81f0f6c9, 0xd7657b4b82d09, 0xd772ef470622a, 0xd7797b850b49c,
+    0xd7813edadf177, 0xd78dc89a4b558, 0xd78eed65682e8, 0xd7905b634c3dc,
+    0xd7956004dacf7, 0xd79be1480ee1a, 0xd79c8d4c2fd45, 0xd7a77d2273af6,
+    0xd7ae7383b34b9, 0xd7af05e941b81, 0xd7b0703e356b0, 0xd7c9333865f84,
+    0xd7c936e156549, 0xd7d55a267879e, 0xd7dec26045bf8, 0xd7f1f94e2a638,
+    0xd7f6c360b3303, 0xd805d8973f9de, 0xd80bec0e48deb, 0xd816f5831f3ff,
+    0xd8179dde4fd65, 0xd81cd965f3d0b, 0xd81e943f8f728, 0xd825fc15ea3c6,
+    0xd8356ccbefade, 0xd8375ae2b05c1, 0xd83f58c789eec, 0xd83f8109dde63,
+    0xd848e59abacf8, 0xd84fa8bed55f5, 0xd854fde50bdfe, 0xd8662d1d9d4f8,
+    0xd868fa75a3fcc, 0xd86c2e47f4c2c, 0xd873d45645adf, 0xd8885fa37bdb0,
+    0xd8891a4b5e3ef, 0xd88b37f6539d3, 0xd88faa3253c26, 0xd890dda1320ca,
+    0xd891098c76606, 0xd895747695ccf, 0xd8a98aa5c0700, 0xd8bc208a55364,
+    0xd8c1eace9740d, 0xd8e86af712566, 0xd8f5d7a0b4efd, 0xd8f7a11e1202e,
+    0xd8fe1b0f655c7, 0xd90ac9683535a, 0xd90de744e3d1c, 0xd90ee7769d0fa,
+    0xd91782bbd623c, 0xd921fd740e74d, 0xd92705be8d62d, 0xd93504cdbe68c,
+    0xd9397709be8df, 0xd93b68c96f987, 0xd93e65b5aaf5c, 0xd960ae312bab2,
+    0xd9826f41ef08f, 0xd9862a7efd268, 0xd98786302f683, 0xd98939b7ea516,
+    0xd990a53735779, 0xd991e749d5331, 0xd998bcbaa1907, 0xd99bece401fa2,
+    0xd9a651a698215, 0xd9abefff95d82, 0xd9b044f4131ad, 0xd9b1e62f1c367,
+    0xd9bae7ee9f635, 0xd9c04bb897552, 0xd9e63229a3459, 0xd9f61f4f94f9b,
+    0xd9fcab8d9a20d, 0xda1d4f25215e4, 0xda1d910607dbe, 0xda1f02acdc477,
+    0xda2e8d01743f2, 0xda39a8c2fc6df, 0xda40265d4023d, 0xda4ee76634465,
+    0xda5151ece1372, 0xda542a3fb8f95, 0xda55ddc773e28, 0xda648188e5228,
+    0xda713e85766cf, 0xda71f92d58d0e, 0xda7d9507bd9ea, 0xda7f3642c6ba4,
+    0xda8521777c03a, 0xdab4c7f8dde13, 0xdab8ab783ff63, 0xdac0e3ec1f4de,
+    0xdac3178cb6d60, 0xdad7d9c0026bc, 0xdae8ef5a01553, 0xdaed09bf78d2e,
+    0xdaf8f9c775ebd, 0xdb1769be65a12, 0xdb23cee46e441, 0xdb2e3af8e523e,
+    0xdb314688e1f27, 0xdb3517bb9239e, 0xdb3dbdfb9c62f, 0xdb4546c26a6ba,
+    0xdb47a2a555eb3, 0xdb51038d42783, 0xdb64f8cbf9dc7, 0xdb6f4b41de361,
+    0xdb6f981d95c8a, 0xdb7d8c31f5b9a, 0xdb80b509756ab, 0xdb87957512dd0,
+    0xdb96985eed7d2, 0xdba0720dd5f07, 0xdbcf98765b2f1, 0xdbd0f07e9d147,
+    0xdbdd97858c350, 0xdbf0b4d4de52d, 0xdbf98aa91d2bf, 0xdc07e537c735b,
+    0xdc08480920f22, 0xdc095a878bfd9, 0xdc119df63c6a3, 0xdc20d7c62c730,
+    0xdc2d14a9e11e8, 0xdc31a08473c9e, 0xdc33197d28ee1, 0xdc373039b00f7,
+    0xdc40f3f2f658e, 0xdc415a6d4071a, 0xdc48b39fd9ca4, 0xdc50ec13b921f,
+    0xdc5c794a5c7e7, 0xdc6dc5ca70d09, 0xdc70cdb17d42d, 0xdc70d5035dfb7,
+    0xdc77d2b67e504, 0xdc7da0a3b0b72, 0xdc81c9ace9a61, 0xdc82b3e900ba1,
+    0xdc84e78998423, 0xdc9195e2681b6, 0xdc94ac6d35fee, 0xdca253a5de5d5,
+    0xdca699f69a2ec, 0xdca916c9f8ed2, 0xdca9f9b42f488, 0xdcb10d5cf1c73,
+    0xdcc47ed9dc303, 0xdcd3f6e1c25a5, 0xdcd4fe655c50d, 0xdcd71fb9420b6,
+    0xdcd9f80c19cd9, 0xdcdd875de3976, 0xdcdd8b06d3f3b, 0xdcdee30f15d91,
+    0xdce57a47ec152, 0xdceaf05e95d48, 0xdcecb28a122ef, 0xdcf914072a759,
+    0xdd02e2bb41d3f, 0xdd0341e3ab341, 0xdd053af53cf73, 0xdd061a3682f64,
+    0xdd09d91c81702, 0xdd26a42f77ad8, 0xdd352aa9660b0, 0xdd37868c518a9,
+    0xdd37bd7266f34, 0xdd67813b4bb35, 0xdd6be87c7ac39, 0xdd776e613d677,
+    0xdd783efec1f54, 0xdd873e3fac391, 0xdd875f301f77e, 0xdd888ef60d65d,
+    0xdd909083d754d, 0xdd909b7ea869c, 0xdd9d880f6e644, 0xdda1b118a7533,
+    0xdda485c28eb91, 0xdda547bc51d5a, 0xdda6ae68552c4, 0xddd10abe519e3,
+    0xddd6847debb9e, 0xddd9d940afbeb, 0xddde2e352d016, 0xddf05d9f77aee,
+    0xde1c7878001ef, 0xde1f2fda64a25, 0xde21ee8ea9de5, 0xde2f070ab42c9,
+    0xde39c74cc3579, 0xde3e3236e2c42, 0xde3ef430a5e0b, 0xde3f410c5d734,
+    0xde410337d9cdb, 0xde4ba63266163, 0xde503d07c9d68, 0xde5e4ababc4db,
+    0xde66d00a5337f, 0xde675ec6f1482, 0xde78c13ca7c42, 0xde79f4ab860e6,
+    0xde84c73a4706f, 0xde8601fb0609d, 0xde89690a7bdc3, 0xde983c6021cc4,
+    0xdea10e8b70491, 0xdea725ab69e63, 0xdeaf98ae4f02e, 0xdeb3c90968aa7,
+    0xdec4fbeaea766, 0xdedd49c70f79a, 0xdee1e44563964, 0xdee83d4643b10,
+    0xdee87b7e39d25, 0xdee8aebb5edeb, 0xdee8b60d3f975, 0xdefba3c85d051,
+    0xdf00be5f8dc0a, 0xdf0b8d455e5ce, 0xdf12fc6d99df6, 0xdf1d897283fe0,
+    0xdf2e08fe041ea, 0xdf36576785a03, 0xdf367fa9d997a, 0xdf4164854c5dc,
+    0xdf5ab2932a9ee, 0xdf65d1fda32a0, 0xdf6693f766469, 0xdf69823fe032a,
+    0xdf6f5b27e3ae7, 0xdf7826015172a, 0xdf7f484dd5629, 0xdf83038ae3802,
+    0xdf8550ca0d8e7, 0xdf8842bb77d6d, 0xdf902aaaaf3fa, 0xdf91531ebc74f,
+    0xdfa7834fec19f, 0xdfa88381a557d, 0xdfaecddec4015, 0xdfb4e8a7adfac,
+    0xdfc93965de62d, 0xdfd1f9447b121, 0xdfd68c70ee761, 0xdfdb73cafa254,
+    0xdfe4215ce506f, 0xdfe554cbc3513, 0xdfefb98e59786, 0xdff2c8c746a34,
+    0xdff8a1af4a1f1, 0xe00b3041fe2cb, 0xe01f133403c36, 0xe030eac7c5c96,
+    0xe0416dfc36465, 0xe04fa0488c58a, 0xe054964659791, 0xe058ca4a637cf,
+    0xe05e6ff541ec6, 0xe0665b8d69b18, 0xe067dbd7ff8e5, 0xe0794cf1777b9,
+    0xe09f50aa064e8, 0xe0a881fdbe2b7, 0xe0a9f74d82f35, 0xe0aeba0e2b076,
+    0xe0af8702bf38e, 0xe0b813a436dbc, 0xe0c97270fcfb7, 0xe0d0f03cf9ef3,
+    0xe0d4bdc6b9da5, 0xe0d5c1a163748, 0xe0d92c59c9a33, 0xe0e4cf860f299,
+    0xe0f777b755bd6, 0xe0ffee632b366, 0xe1089f9e06746, 0xe111cd48cdf50,
+    0xe117a9d9c1cd2, 0xe11c3d0635312, 0xe124a8b739953, 0xe125f5c4aa65a,
+    0xe129c34e6a50c, 0xe12bb1652afef, 0xe13fc3eb6545b, 0xe1583660ede41,
+    0xe15d9a2ae5d5e, 0xe16ba434e7f0c, 0xe170f95b1e715, 0xe180b6ecdb756,
+    0xe181f1ad9a784, 0xe1879b0169440, 0xe191e9ce5d415, 0xe1a094e1af39f,
+    0xe1b0561c5c9a5, 0xe1b1fea9466e9, 0xe1d5a67ee9c1f, 0xe1df160a97c03,
+    0xe1e1f2065fdeb, 0xe1ec61c3c71ad, 0xe1f08acd0009c, 0xe1f6b439ab747,
+    0xe20b3bddf1453, 0xe20cd21e294be, 0xe21eef3bc22bd, 0xe2241876b458a,
+    0xe229b326c1b32, 0xe23360ea65d2b, 0xe24ee9eabc549, 0xe26b97b62faf7,
+    0xe26cefbe7194d, 0xe26d1800c58c4, 0xe278dfc66eadc, 0xe27d34baebf07,
+    0xe298bdbb42725, 0xe29f514b28521, 0xe2a88d99b143f, 0xe2ac0a9ec9403,
+    0xe2b0eaa6f436c, 0xe2b510073cc96, 0xe2b56f2fa6298, 0xe2b8b54ea8bd1,
+    0xe2b97af15c35f, 0xe2c33eaaa27f6, 0xe2d3c5880358a, 0xe2e8cd4525c85,
+    0xe2f769b4b64fb, 0xe2ff8c32f37d8, 0xe2ffb4754774f, 0xe30d963cf5986,
+    0xe30dbe7f498fd, 0xe319cbcec98b4, 0xe3208ef2e41b1, 0xe3383f6ea99ce,
+    0xe33cbca57ad70, 0xe34671baffaf3, 0xe347cd6c31f0e, 0xe34a7d7cb5bba,
+    0xe352e92dba1fb, 0xe362fe95ffcb4, 0xe36509f4435bf, 0xe374ced7e118a,
+    0xe38087f9c8c8e, 0xe38dd3b2f8238, 0xe38f998764da4, 0xe39d530cbf064,
+    0xe3a12f3a4062a, 0xe3b1200922731, 0xe3b31571c3d9e, 0xe3b386e6df079,
+    0xe3ce37f7d0430, 0xe3e85df513ca9, 0xe3ec6d5fba335, 0xe3f722a6f8496,
+    0xe3fb35ba8f0e7, 0xe404f973d557e, 0xe4062ce2b3a22, 0xe407ad2d497ef,
+    0xe40e61ada29d8, 0xe40f82cfcf1a3, 0xe4171de34ef07, 0xe41d64977d3da,
+    0xe428546dc118b, 0xe43bea840f1cd, 0xe442e8372f71a, 0xe447c4966a0be,
+    0xe449697a6383d, 0xe44d544ba6517, 0xe450e39d701b4, 0xe454bfcaf177a,
+    0xe4591fba3fcf4, 0xe45c86c9b5a1a, 0xe464292f16308, 0xe46e95438d105,
+    0xe477a1fde1522, 0xe48111898f506, 0xe491b20582afd, 0xe4a8958cb4002,
+    0xe4b3c39aedfc8, 0xe4b81fe14bf7d, 0xe4b961f3ebb35, 0xe4c8b1b97de60,
+    0xe4d0a4a38663c, 0xe4d8bfcfe2d8f, 0xe4de398f7cf4a, 0xe4e91ac1ff5e7,
+    0xe4e9679db6f10, 0xe4ecfe4161737, 0xe4f1daa09c0db, 0xe4f3deacfee5c,
+    0xe4faa922fa2e3, 0xe50c303214455, 0xe5111b351050d, 0xe51782d9b1dcd,
+    0xe5203f0f5e2fc, 0xe533bb8719adb, 0xe53a52bfefe9c, 0xe5406636f92a9,
+    0xe556e343e0622, 0xe558c65fcffb6, 0xe569d4a7ee2c3, 0xe571318377e12,
+    0xe57711bd5c159, 0xe5882ea93bb7a, 0xe595b4f170d74, 0xe598e171e0e4a,
+    0xe5a73fa97b4ab, 0xe5b0784f13e04, 0xe5b1be0aa3f81, 0xe5c1067e55722,
+    0xe5c12ec0a9699, 0xe5cf64b5efd83, 0xe5f4ceb70f860, 0xe5f7b9569915c,
+    0xe5f8ecc577600, 0xe5fbe608c2610, 0xe600215ead1d8, 0xe603a95e962eb,
+    0xe6208a672e95f, 0xe636457a52b0f, 0xe63737084a7d9, 0xe644483274133,
+    0xe64e1a8f7bcde, 0xe677228626b6c, 0xe67f06cc6dc34, 0xe681072fe03f0,
+    0xe68400732b400, 0xe6906942243f4, 0xe695b7167a073, 0xe698befd86797,
+    0xe6990f822e685, 0xe6acca31e0079, 0xe6afdd13bd8ec, 0xe6b5fb8597e48,
+    0xe6c54ef41a738, 0xe6c7ff049e3e4, 0xe6de27e3ed2aa, 0xe6e10eda865e1,
+    0xe6e9d9b3f4224, 0xe6f3c9587ebf7, 0xe6ff11054b420, 0xe70c298155904,
+    0xe717ba60e9491, 0xe723e4f7ec270, 0xe727953a292fa, 0xe72fbf0a47161,
+    0xe7382abb4b7a2, 0xe74e39fc07e05, 0xe750330d99a37, 0xe756552864558,
+    0xe775418f404d7, 0xe784f4262c3c9, 0xe796e1af906c7, 0xe7975a768c52c,
+    0xe7a35ccb3b394, 0xe7ac06b435bea, 0xe7b3d15bea44f, 0xe7c2542ce8462,
+    0xe7c73b86f3f55, 0xe7d22f06282cb, 0xe7daad03de5e5, 0xe7dc7a2a2bcdb,
+    0xe7dd7a5be50b9, 0xe7dfefdd63115, 0xe7e077482068e, 0xe7e13cead3e1c,
+    0xe7e9163649d95, 0xe7e9964f26784, 0xe7fcdf89bce9d, 0xe7fd9a319f4dc,
+    0xe7fee73f101e3, 0xe805aa632aae0, 0xe80f8f0ce4364, 0xe818981e481bc,
+    0xe819ddd9d8339, 0xe820a4a6e31fb, 0xe825308175cb1, 0xe82d35b830166,
+    0xe82fe5c8b3e12, 0xe833074e52d99, 0xe83558366d443, 0xe8648d42b3f41,
+    0xe876b55b1de8f, 0xe879865c14f28, 0xe87a95318fa1a, 0xe88e538a319d3,
+    0xe891800aa1aa9, 0xe8933ae43d4c6, 0xe896acee8433b, 0xe897a9774d154,
+    0xe8a8ca0c1d13a, 0xe8bfcadad1467, 0xe8c92470dd1ad, 0xe8d01e7b0d135,
+    0xe8d067add4499, 0xe8e7c7a4f1dc8, 0xe8eb5a9fac02a, 0xe8ee996ccddd9,
+    0xe8f396bc7bb6a, 0xe8f92a1aa8588, 0xe909b0f80931c, 0xe9117f48ae146,
+    0xe91867062c3f5, 0xe91f1b86855de, 0xe9274556a3445, 0xe930e47685f2a,
+    0xe934d699a978e, 0xe94179f7a83d2, 0xe94376b22a5c9, 0xe9504d4d4e2d3,
+    0xe9708a6a8b51e, 0xe973ba93ebbb9, 0xe9762171a8501, 0xe9789e45070e7,
+    0xe97b9788520f7, 0xe97fae44d930d, 0xe9807ee25dbea, 0xe981e1e570b8f,
+    0xe985d05fa3e2e, 0xe9a5fb302f3a0, 0xe9ad2877843ee, 0xe9aebeb7bc459,
+    0xe9b121ec887dc, 0xe9b97ef9cb709, 0xe9d3f57bb6e70, 0xe9e58b2e926f6,
+    0xe9e6840e6af4a, 0xe9f34c05cd540, 0xe9f3b9d1f8256, 0xe9fdd561c7165,
+    0xea012a248b1b2, 0xea074c3f55cd3, 0xea0a4582a0ce3, 0xea1dd7effe760,
+    0xea31372037117, 0xea3d5313787e2, 0xea485c884edf6, 0xea4eaa8e5de53,
+    0xea6c045df232c, 0xea6e4df42be4c, 0xea7eb78a09db8, 0xea855614c0d03,
+    0xea8be5fbb653a, 0xea8cd037cd67a, 0xea91043bd76b8, 0xea94346537d53,
+    0xeac1c48d850d2, 0xeac1e57df84bf, 0xeae4b1bb45fc9, 0xeaf7613e6d490,
+    0xeafeb6c816455, 0xeb009d8cf63ae, 0xeb10ba471c9f1, 0xeb210de7586bf,
+    0xeb3068a7bbb39, 0xeb32d6d75900b, 0xeb386dde75fee, 0xeb3b5fcfe0474,
+    0xeb3b846943e26, 0xeb3f4e4a13713, 0xeb568255ecb06, 0xeb5961faa52b3,
+    0xeb60b0326d6ee, 0xeb69b943d1546, 0xeb79b50d8479c, 0xeb815ec4c5c14,
+    0xeb8bef72a03c3, 0xeb9177d5fbc92, 0xeb96508c46071, 0xeb9dce5842fad,
+    0xeba0527d8271d, 0xeba13567b8cd3, 0xebb2cb1a94559, 0xebb531f850ea1,
+    0xebb86973920c6, 0xebb891b5e603d, 0xebc1d8ff400aa, 0xebc97f0d90f5d,
+    0xebe2f906b38ab, 0xec1895b79bc69, 0xec2c122f57448, 0xec2f7ce7bd733,
+    0xec2f8f346f40c, 0xec2fc61a84a97, 0xec342609d3011, 0xec4e5aaad7f9e,
+    0xec58813577ffc, 0xec994bf49a380, 0xecb630a622fb9, 0xeccf52c8bce8f,
+    0xecd5edaa83815, 0xece17e8a173a2, 0xece1cb65ceccb, 0xece43d3e5c762,
+    0xecef938eea69f, 0xecf12d7812ccf, 0xecfd5466254e9, 0xed118b85c3307,
+    0xed1d69410e7bd, 0xed21e2ceef59a, 0xed3562ef9b33e, 0xed3f2dfac235f,
+    0xed3fd2ad02700, 0xed410272f05df, 0xed479602d63db, 0xed4f67fc6b7ca,
+    0xed51e4cfca3b0, 0xed56bd861478f, 0xed597c3a59b4f, 0xed6c1970cf33d,
+    0xed91af5d33356, 0xed97d177fde77, 0xed9d22f5440bb, 0xedb4fbb35d84f,
+    0xedbc75d66a1c6, 0xedbe30b005be3, 0xedc95ebe3fba9, 0xedce1a2d07160,
+    0xedd17641abd37, 0xeddbb66ade5f8, 0xeddf28752546d, 0xedfc47b5b3cf6,
+    0xedfdf0429da3a, 0xee068435f5ff2, 0xee191a1a8ac56, 0xee30641c062e7,
+    0xee31a62ea5e9f, 0xee35f77a32d05, 0xee3fa53dd6efe, 0xee402ca894477,
+    0xee48ae4f3ad56, 0xee5c89ef5fb37, 0xee69fdeae3058, 0xee6da3324ef93,
+    0xee73ffdc1f704, 0xeea0c6b8c8d30, 0xeea8189981730, 0xeec7d946d2551,
+    0xeecb773c5d902, 0xeed8f9dba2537, 0xeedcfaa2874af, 0xeee0bd3176212,
+    0xeef0c04d09ff2, 0xeef17af4ec631, 0xeef59903543d1, 0xeefd80f28ba5e,
+    0xef01c74347775, 0xef15cb25c04cd, 0xef252d38044d1, 0xef264ab1406d7,
+    0xef282a243faa6, 0xef32d8199d07d, 0xef3d05f61dc65, 0xef40bd8a3b879,
+    0xef5871aef165b, 0xef60c3c163439, 0xef67ed5fc7ec2, 0xef6dd1429c7ce,
+    0xef6fe3f2c0c63, 0xef785a9e963f3, 0xef7ff20925b92, 0xefa60f6047124,
+    0xefabcb00c7ab9, 0xefbf4b217385d, 0xefc3ff3e5a28a, 0xefcdf634c57e7,
+    0xefd70a40fa78e, 0xefe83d227c44d, 0xeff508c2cf008, 0xf005257cf564b,
+    0xf00ce180e879c, 0xf00fa7870e6e6, 0xf01d9b9b6e5f6, 0xf021b600e5dd1,
+    0xf02858348d2e1, 0xf0291a2e504aa, 0xf0298f4c5bd4a, 0xf033a38a4a0cf,
+    0xf0367f86122b7, 0xf038873b655fd, 0xf0406086db576, 0xf0467ef8b5ad2,
+    0xf059c48a5bc26, 0xf061007572388, 0xf0647d7a8a34c, 0xf07964473965a,
+    0xf080151ea227e, 0xf085878c5b8af, 0xf0983e6163900, 0xf098b7285f765,
+    0xf0b1a60dd4575, 0xf0bff94a9da87, 0xf0c4434449d63, 0xf0d8522193c0a,
+    0xf0e35b966a21e, 0xf0f83768483dd, 0xf1012e2cfa55c, 0xf104bd7ec41f9,
+    0xf108ea30ed6ad, 0xf1106f4ecb173, 0xf111c0052c43f, 0xf11e9df230cd3,
+    0xf123227ae2bff, 0xf12b748d549dd, 0xf13ff4dfb9b5f, 0xf15ebcad3212c,
+    0xf160d6af3714b, 0xf17d2ca421c81, 0xf18e9dbd99b55, 0xf198f3dc6e6b4,
+    0xf19ce9a8824dd, 0xf1a9c043a61e7, 0xf1b07fbed051f, 0xf1b31d82a24f2,
+    0xf1cf0cfd42e9c, 0xf1d6244ef5c4c, 0xf1d996593cac1, 0xf1fcabc95192f,
+    0xf1fe127554e99, 0xf20fa8283071f, 0xf21b0221aec21, 0xf22b3c235808c,
+    0xf22fcf4fcb6cc, 0xf2378b53be81d, 0xf239a1acd3277, 0xf23e97aaa047e,
+    0xf26f2fb9f9f21, 0xf2751aeeaf3b7, 0xf2776f7fba026, 0xf279d65d7696e,
+    0xf27be90d9ae03, 0xf28f35f121ae1, 0xf2906d08f054a, 0xf2c6de004d7aa,
+    0xf2cadb1e4215d, 0xf2d97b36c2f98, 0xf2df4923f5606, 0xf2e112a152737,
+    0xf2e77a45f3ff7, 0xf2ffc8221902b, 0xf31c5ff7ea33b, 0xf3207e06520db,
+    0xf324ddf5a0655, 0xf325b98df6081, 0xf3294c88b02e3, 0xf341b7ac5813f,
+    0xf34472b7acf3a, 0xf386deb1d8478, 0xf3877fbb28254, 0xf395fb3a456dd,
+    0xf397ff46a845e, 0xf39adb4270646, 0xf39d2fd37b2b5, 0xf3a56f993b3ba,
+    0xf3b5a5f1f4260, 0xf3b738893bd06, 0xf3c7d9052f2fd, 0xf3dc9b387ac59,
+    0xf3dd1efa47c0d, 0xf3e862fe23e71, 0xf400555acfe68, 0xf4079c40b7719,
+    0xf4080663f1e6a, 0xf409d7332fb25, 0xf40aed5a8b1a1, 0xf41230978248d,
+    0xf41fc1da887d6, 0xf4370f84f442c, 0xf44854b327dc4, 0xf44b42fba1c85,
+    0xf45d6ebcfc198, 0xf45f93b9d2306, 0xf47db32c19f6d, 0xf483e3eaa61a2,
+    0xf48bda7d9ef43, 0xf498f2f9a9427, 0xf49c85f463689, 0xf49ec48fcc05a,
+    0xf4a712f94d873, 0xf4ad4b09ba632, 0xf4d1e81645df7, 0xf4e5b8bb99a89,
+    0xf4f8c3be39f8d, 0xf50074c75bf8f, 0xf5173b070a66c, 0xf5276a0de2988,
+    0xf528b372630ca, 0xf52cf9c31ede1, 0xf5377bcd37e7c, 0xf5413bdd8dd4e,
+    0xf544d62a28b3a, 0xf548239b0bffd, 0xf55ccc2fc50f6, 0xf5642cb43f20a,
+    0xf564d1667f5ab, 0xf56dd6cef2e3e, 0xf580d6d6c21f3, 0xf580eccc64491,
+    0xf584194cd4567, 0xf58e29e1d2327, 0xf59114815bc23, 0xf5ab95fe184d9,
+    0xf5b04dc3ef4cb, 0xf5b89c2d70ce4, 0xf5cd5ab7cc07b, 0xf5e7aca053e30,
+    0xf5f118831184f, 0xf604f7cc26bf5, 0xf62e69e60c1d4, 0xf63743633b52b,
+    0xf6383c4313d7f, 0xf65f31899a778, 0xf669c98955ab1, 0xf66b33de495e0,
+    0xf670cae5665c3, 0xf674c8035af76, 0xf676ff4ce2dbd, 0xf67c6a68bb864,
+    0xf6838cb53f763, 0xf688fb7a087cf, 0xf68f49801782c, 0xf6b9888e91123,
+    0xf6c00d7ab580b, 0xf6d043d36e6b1, 0xf6d903b20b1a5, 0xf6eb5b5ea9bf4,
+    0xf6edea7eba4b3, 0xf6f4263817837, 0xf6f59435fb92b, 0xf7088942f9b91,
+    0xf70dab2c0b2d4, 0xf70fcc7ff0e7d, 0xf715797cb00fe, 0xf7207f489614d,
+    0xf72671cf2c16d, 0xf7291e36bf854, 0xf72ae40b2c3c0, 0xf731de155c348,
+    0xf7350343eb894, 0xf7412a31fe0ae, 0xf74b371e0b8a9, 0xf74db04879eca,
+    0xf75ea89af5f39, 0xf7611a73839d0, 0xf761e0163715e, 0xf762811f86f3a,
+    0xf7650c96a7234, 0xf76ced33fdd37, 0xf76ee6458f969, 0xf76f71593d4a7,
+    0xf77ef0b3042d3, 0xf78086f33c33e, 0xf789c6eab5821, 0xf78f4f4e110f0,
+    0xf7a194adfde66, 0xf7b7ed218182d, 0xf7c1d1cb3b0b1, 0xf7d56f3369c7d,
+    0xf7d9cb79c7c32, 0xf7ecb1e304784, 0xf7efed0735f6e, 0xf7f8e3cbe80ed,
+    0xf7fd8cedfd9cb, 0xf7ffd2db46f26, 0xf8343c1d50e40, 0xf836cb3d616ff,
+    0xf83852d9d8056, 0xf8391fce6c36e, 0xf84ff85acc724, 0xf859434d16d56,
+    0xf8899d247a5e4, 0xf88e6737032af, 0xf891bbf9c72fc, 0xf895738de4f10,
+    0xf895cf0d5df4d, 0xf8a0c28c922c3, 0xf8af74f1c4dd7, 0xf8b0000572915,
+    0xf8bd40c3d0d70, 0xf8caa9c483142, 0xf8d846025a5da, 0xf8fdbea73b7cb,
+    0xf9381e18cbcca, 0xf93d733f024d3, 0xf93dbc71c9837, 0xf94eb11b552e1,
+    0xf94f94058b897, 0xf9631b78181c5, 0xf9665a4539f74, 0xf96c95fe972f8,
+    0xf975a2b8eb715, 0xf9766109be319, 0xf97f0749c85aa, 0xf986d1f17ce0f,
+    0xf9885d36e3d2b, 0xf989b8e816146, 0xf9912f62324f8, 0xf998087bef093,
+    0xf99c1094b4b95, 0xf9a5f1957de54, 0xf9b857e5ddfb7, 0xf9bb80bd5dac8,
+    0xf9be97482b900, 0xf9bfc70e197df, 0xf9e05c01df4a2, 0xf9eacf6836e29,
+    0xf9ebe58f924a5, 0xf9f15f4f2c660, 0xf9f66b429bb05, 0xfa064272eb3a9,
+    0xfa086ec1a20a1, 0xfa0984e8fd71d, 0xfa19511e7be72, 0xfa247088f4724,
+    0xfa2862ac17f88, 0xfa295b8bf07dc, 0xfa2dc6760fea5, 0xfa304a9b4f615,
+    0xfa35ec9d3d747, 0xfa38e23798192, 0xfa4079a227931, 0xfa418fc982fad,
+    0xfa4cf4bdd25fe, 0xfa576bcd1a54a, 0xfa6663bc23dfd, 0xfa6e48026aec5,
+    0xfa7f26b6546d1, 0xfa817b475f340, 0xfa9fe0437dd46, 0xfabcdaeaa8c1d,
+    0xfabf5b66f7dc8, 0xfac17cbadd971, 0xfacf78211e40b, 0xfadabc24fa66f,
+    0xfb0dc60ce1ba9, 0xfb15ceec8c623, 0xfb30fc6d69e04, 0xfb4127cb51b5b,
+    0xfb490868a865e, 0xfb4f0d3bf0357, 0xfb682463b90de, 0xfb6ed53b21d02,
+    0xfb8163cdd5ddc, 0xfb816776c63a1, 0xfb85350086253, 0xfb8c365c96d65,
+    0xfb9564075e56f, 0xfb97d988dc5cb, 0xfbaba6853fc98, 0xfbc57fa6cbbe8,
+    0xfbc9c9a077ec4, 0xfbd81cdd413d6, 0xfbe8b60753e43, 0xfbf3fdb42066c,
+    0xfbf568091419b, 0xfc0062da2909b, 0xfc00c2029269d, 0xfc07bc0cc2625,
+    0xfc1993a084685, 0xfc2495c37a10f, 0xfc25f174ac52a, 0xfc390b1b0e142,
+    0xfc399285cb6bb, 0xfc548cc983dd6, 0xfc610f370f62d, 0xfc6576783e731,
+    0xfc6d780608621, 0xfc8d55fadc26a, 0xfc94784760169, 0xfc94ab848522f,
+    0xfcaeeec94b8d0, 0xfcb87f456cca1, 0xfce05b1f1a215, 0xfce951e3cc394,
+    0xfce9f2ed1c170, 0xfcf92cbd0c1fd, 0xfd16659c2d2e9, 0xfd3505275193f,
+    0xfd3ffc4f7627a, 0xfd409606e54cc, 0xfd4755820f804, 0xfd64194325050,
+    0xfd7fb8391db0c, 0xfd9c48bd0e292, 0xfda7f33b34682, 0xfdb537a2830a2,
+    0xfdb704c8d0798, 0xfdbf0da87b212, 0xfdc4ea396ef94, 0xfdc8340161e92,
+    0xfdd0e1934ccad, 0xfdd6d7c2d3292, 0xfddad889b820a, 0xfddf64644acc0,
+    0xfdedfd2aeaf71, 0xfdf8e2065dbd3, 0xfdf99cae40212, 0xfe019e3c0a102,
+    0xfe04c36a9964e, 0xfe0885f9883b1, 0xfe1f86c83c6de, 0xfe21663b3baad,
+    0xfe5f42b1e3f70, 0xfe6ff57a89240, 0xfe71acab34698, 0xfe90a063f11a1,
+    0xfe90b2b0a2e7a, 0xfe99295c7860a, 0xfea97cfcb42d8, 0xfed6f72f5f3b9,
+    0xfee145fc5338e, 0xfeeb6c86f33ec, 0xfef8974faf5a9, 0xff079de27a570,
+    0xff091a841fd78, 0xff21c036cd824, 0xff50bab40e6d2, 0xff558fc1684ec,
+    0xff57852a09b59, 0xff58ad9e16eae, 0xff69ad4273aa7, 0xff7dd5be501b1,
+    0xff89dbbbef5de, 0xff8e6b3f72659, 0xff94d2e413f19, 0xff9e33cc007e9,
+    0xff9ea1982b4ff, 0xffa19adb7650f, 0xffa8cf74ac0e7, 0xffacdb36621ae,
+    0xffb79b787145e, 0xffbccc05442b5, 0xffc0edbc9c61a, 0xffc81b03f1668,
+    0xffd6c617435f2, 0xffdb4e48e5ae3, 0xffdb8c80dbcf8, 0xfff1c403ec2d2,
+    0xfff518c6b031f, 0xfff6d3a04bd3c, 0x002d2af916739, 0x002e118c3d2b4,
+    0x004aca52819b1, 0x005ae70ca7ff4, 0x006886f36fa51, 0x006edc4b5f638,
+    0x00a1c199e31c0, 0x00b02e753ef35, 0x00c77876ba5c6, 0x00d14e7cb2736,
+    0x00dc210b736bf, 0x0116e34e5d785, 0x0120e53f99e31, 0x0137a084773bf,
+    0x0140b0e7bbda1, 0x0151bb86e9ae9, 0x0173d0c545579, 0x01812b2236237,
+    0x01904b5393a61, 0x01afdc6cafd81, 0x01bcc1ab9519f, 0x01cd7f6f0b5be,
+    0x01d0f522429f8, 0x01e2d7b0d5ba7, 0x01e2fc4a39559, 0x01e96b40bb9a3,
+    0x01f4ded8cc708, 0x01f52f5d745f6, 0x0229f7c7e7b12, 0x02572175ead05,
+    0x025c55abae121, 0x0288fb97e4360, 0x028f4d46e3982, 0x028fc60ddf7e7,
+    0x0293f2c008c9b, 0x02a81792f4de0, 0x02af02f963654, 0x02efe3ae27c76,
+    0x02f1171d0611a, 0x02f33870ebcc3, 0x02f490792db19, 0x030f0aa409845,
+    0x0316f29340ed2, 0x0325ac4a54570, 0x032ddd6c52f61, 0x0331c83d95c3b,
+    0x033916755e076, 0x0355776519cfb, 0x03567ee8b3c63, 0x0360a57353cc1,
+    0x0361bb9aaf33d, 0x03868f8d5018d, 0x03c6aa9f61021, 0x03c709c7ca623,
+    0x03d0635dd6369, 0x03d13b4d3b7d0, 0x03d6ef9bdb5db, 0x03d991089db73,
+    0x03e13abfdefeb, 0x03e4bb6de7574, 0x0416cc75f625a, 0x04170704fbeaa,
+    0x042702ceaf100, 0x04407cc7d1a4e, 0x04477e23e2560, 0x04512495a5bcf,
+    0x045c689981e33, 0x04672532a0b1e, 0x0484f7c930e5c, 0x04a91865d01f7,
+    0x04b964b42b33b, 0x04ccaa45d148f, 0x04ce7b150f14a, 0x04d144c425659,
+    0x04d1f0c846584, 0x04d8dc2eb4df8, 0x05111df54fd13, 0x0512d525fb16b,
+    0x052e657832513, 0x053afa326fa43, 0x053e6141e5769, 0x05554fc3e7dbd,
+    0x05583e0c61c7e, 0x055acd2c7253d, 0x055c9dfbb01f8, 0x058a2e23fd577,
+    0x059c38f4e469d, 0x059e86340e782, 0x05a7ac8cf5402, 0x05b189e4ce0fc,
+    0x05b8a88861a36, 0x05c068354514c, 0x05d8658cc2292, 0x05df3ea67ee2d,
+    0x05e18f8e994d7, 0x0617d843a27c0, 0x0620faf398e7b, 0x0624117e66cb3,
+    0x06268356f474a, 0x064726ee7bb21, 0x06505842338f0, 0x0660edc355d98,
+    0x0662b740b2ec9, 0x066a7e3f77169, 0x0672f1425c334, 0x068615e38f09b,
+    0x06976263a35bd, 0x06a072c6e7f9f, 0x06adc22907b0e, 0x06c7387939e97,
+    0x06c947806dd67, 0x06d136c185f7e, 0x06d9e0aa807d4, 0x06f9dbe6d7245,
+    0x0706a78729e00, 0x07090e64e6748, 0x0723b823f6f75, 0x0723d1c2897d8,
+    0x072d7f862d9d1, 0x072f0379b3d63, 0x0748b0affb777, 0x0770a27f4af89,
+    0x0787fecd782f3, 0x079d3d70b0079, 0x079f8aafda15e, 0x07aeff0ecfe3b,
+    0x07bbf69a66f32, 0x07cd8c4d427b8, 0x07d8def4e0130, 0x07db1295779b2,
+    0x07fd91f70db93, 0x08037230f1eda, 0x0804a59fd037e, 0x0820a3be3243c,
+    0x0823dee263c26, 0x08303564aaf41, 0x083674c6f888a, 0x0851b83d78309,
+    0x08883086b60f3, 0x08a51c8a1f8b6, 0x08a7c8f1b2f9d, 0x08caab24a2d45,
+    0x08f1bdb2ac566, 0x090b3efdafa3e, 0x09399fc38169a, 0x0940160be466e,
+    0x09409d76a1be7, 0x09492dc109bda, 0x094f6228863d4, 0x0959fa284170d,
+    0x095a9b31914e9, 0x095deff455536, 0x0985a38baeb33, 0x0988957d18fb9,
+    0x098bb702b7f40, 0x099df167d3b67, 0x099f87a80bbd2, 0x09a0e7022e5b2,
+    0x09a551ec4dc7b, 0x09a67a605afd0, 0x09af27f245deb, 0x09c7dff1a5570,
+    0x09cd6bfdf1404, 0x09ce7e7c5c4bb, 0x09cf700a54185, 0x09f36c0d8fb6e,
+    0x09fb8739ec2c1, 0x09fcc1faab2ef, 0x09fdc22c646cd, 0x0a095e06c93a9,
+    0x0a09c82a03afa, 0x0a21282121429, 0x0a28c334a118d, 0x0a36ea8626163,
+    0x0a49cd46726f0, 0x0a4a794a9361b, 0x0a59036d721b8, 0x0a5ba4da34750,
+    0x0a614a8512e47, 0x0a6447714e41c, 0x0a69277979385, 0x0a6edbc819190,
+    0x0a7588f6917ef, 0x0a7717e4e8cd0, 0x0a800005d973b, 0x0ab841cc74656,
+    0x0ac9e9cc01bb5, 0x0adb991d6fc9e, 0x0ade3e33227fb, 0x0ae0f93e775f6,
+    0x0af84e3ac3dd6, 0x0afba2fd87e23, 0x0b0f440ea6fb4, 0x0b20a6845d774,
+    0x0b244822d90ea, 0x0b25534f63617, 0x0b33fab9c4fdc, 0x0b3944e52a696,
+    0x0b3bb314c7b68, 0x0b5672c97a633, 0x0b71d7306d49f, 0x0b732f38af2f5,
+    0x0b77633cb9333, 0x0b833d4f14224, 0x0b8ce769c7e58, 0x0bbdaf0d563fc,
+    0x0bc5342b33ec2, 0x0bc77dc16d9e2, 0x0bc91401a5a4d, 0x0bcb7adf62395,
+    0x0c07b9c3f1c63, 0x0c0d840833d0c, 0x0c158ce7de786, 0x0c1cdb1fa6bc1,
+    0x0c20a500764ae, 0x0c215104973d9, 0x0c21968e6e178, 0x0c30f14ed15f2,
+    0x0c30f4f7c1bb7, 0x0c48fd4a0fe4c, 0x0c569987e72e4, 0x0c5fc732aeaee,
+    0x0c6143d4542f6, 0x0c61c796212aa, 0x0c7b2447c0dd0, 0x0cb996cce7f20,
+    0x0cc5b66919bb0, 0x0ccf67d5ae36e, 0x0cd9c8ef5401c, 0x0cde2535b1fd1,
+    0x0cdfb7ccf9a77, 0x0ceb9cda25ab7, 0x0cf4eb75606ae, 0x0cff2b9e92f6f,
+    0x0d1b470477e55, 0x0d1e981e4b8dd, 0x0d209c2aae65e, 0x0d26d43b1b41d,
+    0x0d62d4e7b4ad6, 0x0d6be1a208ef3, 0x0d91987ee02f9, 0x0db04a56b6628,
+    0x0dbb4c79ac0b2, 0x0dbe2fc754e24, 0x0dcf20c7f0309, 0x0ddf74682bfd7,
+    0x0de9222bd01d0, 0x0e0ee05a88160, 0x0e1c8ee5112d1, 0x0e21fa00e9d78,
+    0x0e22e7e5f147d, 0x0e3142749b519, 0x0e362d77975d1, 0x0e386fbbf0567,
+    0x0e41afb369a4a, 0x0e58b0821dd77, 0x0e7995fa8b928, 0x0e7bd495f42f9,
+    0x0e8906b091040, 0x0e89de9ff64a7, 0x0e8d6df1c0144, 0x0e8e9a0ebda5e,
+    0x0e8f301d3c6eb, 0x0e9cbdb75246f, 0x0efeb721a9708, 0x0f003b152fa9a,
+    0x0f1199e1f5c95, 0x0f198ccbfe471, 0x0f21626e83e25, 0x0f46699e49d3b,
+    0x0f544f0ee8537, 0x0f768c8f97f3e, 0x0f7d8a42b848b, 0x0f87888b04572,
+    0x0f9483bf8bc2e, 0x0f9663328affd, 0x0f98dc5cf961e, 0x0f9d9b74b119a,
+    0x0fa81d7eca235, 0x0fb853d7830db, 0x0fc3dd65360de, 0x0fd01a48eab96,
+    0x0fdcb2ac1868b, 0x1002b664a73ba, 0x10076a818dde7, 0x101323a3758eb,
+    0x101e63fe6158a, 0x10249c0ece349, 0x103125ce3a72a, 0x1046bff0eb4ed,
+    0x104b4f746e568, 0x1054d4f5be7ea, 0x10570fe836bf6, 0x10572986c9459,
+    0x1058818f0b2af, 0x1071866a2235d, 0x1078632ccf4bd, 0x1079cd81c2fec,
+    0x107cad267b799, 0x107e90426b12d, 0x10919b450b631, 0x10a55d469dbaf,
+    0x10afa86aa15bf, 0x10b08402f6feb, 0x10c1ba8d6926f, 0x10cff082af959,
+    0x10d38e783ad0a, 0x10efa6352f62b, 0x110e12832ebbb, 0x11205f34fc4bb,
+    0x1122f5a6ed904, 0x1132f5199111f, 0x114027342de66, 0x115b5fafdc796,
+    0x1168e5f811990, 0x116cd47244c2f, 0x118926be3f1a0, 0x119c097e8b72d,
+    0x11ab4aa05c344, 0x11b946069cdde, 0x11b9f5b3ae2ce, 0x11c5bd79574e6,
+    0x11cca18de51d0, 0x11d16f495e460, 0x11dd3e60e8202, 0x11e455b29afb2,
+    0x11efb6fdfa03e, 0x11f099e8305f4, 0x11f0ea6cd84e2, 0x120696dc3af7e,
+    0x1217fcfae1d03, 0x121b381f134ed, 0x12704c4a85368, 0x1280cf7ef5b37,
+    0x1289a8fc24e8e, 0x128c939bae78a, 0x12916ffae912e, 0x129a7cb53d54b,
+    0x129f0c38c05c6, 0x12ae3764eef3f, 0x12c5592416659, 0x12c7f33ef8067,
+    0x12cc398fb3d7e, 0x12d3ea98d5d80, 0x12e62ff8c2af6, 0x12edb16dafff7,
+    0x1316e8f88f986, 0x131827623ef79, 0x131fc61eaf2a2, 0x1329703962ed6,
+    0x132e3df4dc166, 0x1330753e63fad, 0x1346bf0e26260, 0x134ef782057db,
+    0x136105fbdcec6, 0x1364696262627, 0x1371056e806e1, 0x137a57b2ab89d,
+    0x138b91e60e0e6, 0x138fea837bad6, 0x1392d8cbf5997, 0x1394ef250a3f1,
+    0x13a9055434e22, 0x13b8bf3d0189e, 0x13b9fda6b0e91, 0x13bd685f1717c,
+    0x13c0c0cacb78e, 0x13d6992572766, 0x13d9801c0ba9d, 0x13e1ad9519ec9,
+    0x140f0a8042182, 0x141642c26831f, 0x1420151f6feca, 0x1421ab5fa7f35,
+    0x14252c0db04be, 0x143068bfabb98, 0x14388ee6d943a, 0x144c967242757,
+    0x144e3eff2c49b, 0x146e9963ec50e, 0x1470be60c267c, 0x1474acdaf591b,
+    0x1481c8fff03c4, 0x148793443246d, 0x14961d671100a, 0x149c55777ddc9,
+    0x14a486997c7ba, 0x14ae17159db8b, 0x14bd548e7e1dd, 0x14bdf940be57e,
+    0x14c2a60bc4421, 0x14cb06c1f7913, 0x14d755f25e0a4, 0x14d76be800342,
+    0x14f84db77d92e, 0x14fb1b0f84402, 0x150af23fd3ca6, 0x1516204e0dc6c,
+    0x15198eaf6451c, 0x15285ab329893, 0x1546f2ec6d35f, 0x154be54149fa1,
+    0x1561746929c15, 0x15b04cdb3e70c, 0x15b4d163f0638, 0x15d0914a5c4e1,
+    0x15df3c5dae46b, 0x15e12e1d5f513, 0x15e4fba71f3c5, 0x15f1890f7bd6b,
+    0x15f51c0a35fcd, 0x1604dd44e35d3, 0x16119dea6503f, 0x161b70476cbea,
+    0x161d77fcbff30, 0x162554f12646e, 0x164ea9c388c25, 0x1655c4be2bf9a,
+    0x16652e2250b28, 0x166fb3d55a188, 0x1676efc0708ea, 0x168625e7703b2,
+    0x16933aba8a2d1, 0x16a515f73c8f6, 0x16b2ae8c237c9, 0x16b7a832e0f95,
+    0x16bd0fa5c9477, 0x16c5f7c6b9ee2, 0x16dfd0e845e32, 0x16e6caf275dba,
+    0x16e99bf36ce53, 0x16f7b0f840150, 0x16f8d96c4d4a5, 0x16fdde0ddbdc0,
+    0x17034580c42a2, 0x1712cfd55c21d, 0x171b6771a4d9a, 0x171c63fa6dbb3,
+    0x171d72cfe86a5, 0x172110c573a56, 0x17270e46dabc5, 0x1727661d6363d,
+    0x172915fc2df0b, 0x173911c5e1161, 0x173fee888e2c1, 0x17873e33d4d2d,
+    0x1791faccf3a18, 0x17a60d532de84, 0x17b0b04dba30c, 0x17d6acb4684b1,
+    0x17e6c96e8eaf4, 0x17fdfd7a67ee7, 0x1808ff9d5d971, 0x181042da54c5d,
+    0x1817184b21233, 0x181a745fc5e0a, 0x18205499aa151, 0x182ce5aaf70bc,
+    0x18349e05f9c48, 0x184716a30ba84, 0x1859bed4523c1, 0x185d850c316e9,
+    0x185db849567af, 0x1874e5034f018, 0x1886f37d26703, 0x1896dcfa27c80,
+    0x18aba67f54166, 0x18bd29e57dd13, 0x18bf43e782d32, 0x1927d48ab038c,
+    0x1929378dc3331, 0x192dc36855de7, 0x193d9a98a568b, 0x194744b3592bf,
+    0x19618f4a004ea, 0x19703a5d52474, 0x197115f5a7ea0, 0x197afa9f61724,
+    0x197e2e71b2384, 0x1981c8be4d170, 0x1990861e50dd3, 0x199f9efdcda73,
+    0x19aee7717f214, 0x19c97b3aed7a3, 0x19d74ab5e9d01, 0x19d7ef682a0a2,
+    0x19dac7bb01cc5, 0x19dfd3ae7116a, 0x19e0ed7ebcdab, 0x19f624d013fa7,
+    0x1a01393fbb70a, 0x1a049ca640e6b, 0x1a05d766ffe99, 0x1a09546c17e5d,
+    0x1a18834136d9b, 0x1a21a2483ce91, 0x1a2f45d7f4eb3, 0x1a31254af4282,
+    0x1a4ecf9f30649, 0x1a992ada73d9e, 0x1ab0f14bdb859, 0x1ab6e77b61e3e,
+    0x1ab7ca65983f4, 0x1ac5cd1db9a18, 0x1adae7278ddec, 0x1adf81a5e1fb6,
+    0x1af5948f8ebde, 0x1afa833b7b25b, 0x1afc62ae7a62a, 0x1afe128d44ef8,
+    0x1b04100eac067, 0x1b119da8c1deb, 0x1b168159dd319, 0x1b199b8d9b716,
+    0x1b1e151b7c4f3, 0x1b38e373f06d2, 0x1b3dc7250bc00, 0x1b40cf0c18324,
+    0x1b4d3f2cf1ea2, 0x1b56b9b370fd5, 0x1b5e80b235275, 0x1b6902bc4e310,
+    0x1b6e66864622d, 0x1b8760668c18c, 0x1b883fa7d217d, 0x1b993ba33e7b1,
+    0x1ba0e903701ee, 0x1bae3fb7708e7, 0x1bbf0c1ea841a, 0x1bbf30b80bdcc,
+    0x1bc17df735eb1, 0x1bc8202add3c1, 0x1bcfd885dff4d, 0x1bd923782a57f,
+    0x1be45c8135694, 0x1bed2008c274d, 0x1bf7a5bbcbdad, 0x1c0749aef658b,
+    0x1c2194459d7b6, 0x1c2723fad9c0f, 0x1c3f1da966790, 0x1c41b07267614,
+    0x1c49a705603b5, 0x1c5bc07a08bef, 0x1c5d27260c159, 0x1c61a0b3ecf36,
+    0x1c65dfb2c80c3, 0x1c69b837590c4, 0x1c6c55fb2b097, 0x1c7040cc6dd71,
+    0x1c7603becf290, 0x1c79befbdd469, 0x1c90470395931, 0x1cb826863346a,
+    0x1cbff12de7ccf, 0x1cc0e2bbdf999, 0x1cf77f9e81135, 0x1d0108c8c197c,
+    0x1d161b80b51c6, 0x1d196548a80c4, 0x1d1f0af3867bb, 0x1d1f57cf3e0e4,
+    0x1d30a44f52606, 0x1d43e28f17bd0, 0x1d4afd89baf45, 0x1d5774fc7564d,
+    0x1d5c1323b9ddc, 0x1d6bc96396293, 0x1d99fe3e239b3, 0x1d9c10ee47e48,
+    0x1d9d68f689c9e, 0x1d9daad770478, 0x1da0155e1d385, 0x1da5bb08fba7c,
+    0x1da89704c3c64, 0x1db8a51b28b93, 0x1dbeef784762b, 0x1dc957e3cde63,
+    0x1dc98ec9e34ee, 0x1de452278657e, 0x1df0ea8ab4073, 0x1e01c93e9d87f,
+    0x1e07e7b077ddb, 0x1e10e21e1a51f, 0x1e2ead62c9cd3, 0x1e41384c8d7e8,
+    0x1e4c0e843ed36, 0x1e5831c960f8b, 0x1e66c33e206b2, 0x1e676b9951018,
+    0x1e69fe6251e9c, 0x1e71a470a2d4f, 0x1e7c64b2b1fff, 0x1e7e89af8816d,
+    0x1e8f686371979, 0x1ea1efa444ec9, 0x1ed1160cca2b3, 0x1edc68b467c2b,
+    0x1edd4b9e9e1e1, 0x1edeff2659074, 0x1ee10a849c97f, 0x1ee33a7c43c3c,
+    0x1ee399a4ad23e, 0x1ee742950973e, 0x1eee2dfb77fb2, 0x1f1dc5d918677,
+    0x1f2497a0f4688, 0x1f29bd32f6390, 0x1f2aaec0ee05a, 0x1f2fd0a9ff79d,
+    0x1f4e35a61e1a3, 0x1f5002cc6b899, 0x1f51363b49d3d, 0x1f5766f9d5f72,
+    0x1f58d14ec9aa1, 0x1f59b7e1f061c, 0x1f76a3e559ddf, 0x1f7770d9ee0f7,
+    0x1fa08ec63b223, 0x1fab022c92baa, 0x1faf2b35cba99, 0x1fb0e26676ef1,
+    0x1fb2b335b4bac, 0x1fbe10d823673, 0x1fc04f738c044, 0x1fceb8a5f77f4,
+    0x1fdd9e484f3ce, 0x1fec4d049191d, 0x1ffcd78ae2c76, 0x1ffee2e926581,
+    0x200926bb49407, 0x201bb1a50cf1c, 0x2023ba84b7996, 0x2037e6a984665,
+    0x2039166f72544, 0x203cb0bc0d330, 0x203f4e7fdf303, 0x2056fefba4b20,
+    0x2076ef3d2a442, 0x20774e6593a44, 0x209893066abf7, 0x20a2da817e042,
+    0x20b54bccaf2f4, 0x20b7b2aa6bc3c, 0x20b948eaa3ca7, 0x20c6cf32d8ea1,
+    0x20cef1b11617e, 0x20d15fe0b3650, 0x20dc70a76a7ee, 0x20de3dcdb7ee4,
+    0x21076a5dc6724, 0x2108f1fa3d07b, 0x211289c83efd6, 0x211cf5dcb5dd3,
+    0x211f97497836b, 0x2120e456e9072, 0x2130bf3028edb, 0x21403ae0ff742,
+    0x2143cddbb99a4, 0x21474737e13a3, 0x214bcf6983894, 0x2175d04006f76,
+    0x217ba9280a733, 0x2185c860c9c07, 0x218748ab5f9d4, 0x218d3b31f59f4,
+    0x2196fb424b8c6, 0x21acdaeed3428, 0x21af073d8a120, 0x21c8b0cae156f,
+    0x21e1b1fd08058, 0x21f0bfe1b3ba9, 0x21f3b57c0e5f4, 0x21f7b642f356c,
+    0x2204aa259a09e, 0x2207b20ca67c2, 0x221d3d8b95e71, 0x222e4481d35f4,
+    0x225064bb001d3, 0x22557ba9407c7, 0x2259e2ea6f8cb, 0x225b66ddf5c5d,
+    0x225fc6cd441d7, 0x22600c571af76, 0x227bff7aabee5, 0x229f6cc1497cb,
+    0x22b10d6ef61a0, 0x230e6509186e5, 0x23139595eb53c, 0x2313d3cde1751,
+    0x2320f39bcc7bf, 0x232cbf0a65f9c, 0x232e6ee93086a, 0x2339ba3eed658,
+    0x234c920468a96, 0x234e20f2bff77, 0x235e70ea0b680, 0x236b2a3dac562,
+    0x236d09b0ab931, 0x237c13ec66ebd, 0x2394ecdc39a2f, 0x2396af07b5fd6,
+    0x2398a47057643, 0x23a110215bc84, 0x23a16f49c5286, 0x23b1bf411098f,
+    0x23c6433c660d6, 0x23de443cd37e1, 0x23e0d705d4665, 0x240f3422b5cfc,
+    0x2431c2280d5f1, 0x243dcbce9cfe3, 0x2446a8f4bc8ff, 0x2460cb490fbb3,
+    0x2463aaedc8360, 0x246cd146aefe0, 0x24a7a97f3b344, 0x24a9e0c8c318b,
+    0x24b3ccc45d599, 0x24c8d4817fc94, 0x24d7241558be1, 0x24f35570dfd65,
+    0x24fbf0b618ea7, 0x251d180f6d232, 0x251f2e6881c8c, 0x252645ba34a3c,
+    0x253736bacff21, 0x253e0130cb3a8, 0x2551381eafde8, 0x2556b5873a568,
+    0x2561a1b48dd54, 0x257dfefb59414, 0x25988f1bd73de, 0x25a368fc78ef1,
+    0x25e319eb4f634, 0x25ecd9fba5506, 0x25ed4075ef692, 0x25fbca98ce22f,
+    0x25fdb15dae188, 0x260ee43f2fe47, 0x261df5cccbf5d, 0x26216f28f395c,
+    0x2624f37fec4aa, 0x263be201eeafe, 0x2640459a2d63d, 0x2640752e6213e,
+    0x264767e6b153c, 0x2678eddb126e4, 0x26b516c9ffd14, 0x26c127c270290,
+    0x26c1f10e13fe3, 0x26dd76657a23c, 0x26e523c5abc79, 0x26e848f43b1c5,
+    0x26f6062285a4a, 0x270455b65e997, 0x2710e6c7ab902, 0x271aec61d8573,
+    0x271ed38a2ac88, 0x2731203bf8588, 0x2744f48a3c7df, 0x275b803ae526c,
+    0x2765897e024a2, 0x276e2c151c16e, 0x276ef909b0486, 0x27964d78a0481,
+    0x27965c1c61b95, 0x27ac0139e3aa7, 0x27cc5b9ea3b1a, 0x27cd71c5ff196,
+    0x27d84ba6a0ca9, 0x27e4763da3a88, 0x27f1a4af5020a, 0x28132b310db97,
+    0x281620cb685e2, 0x281bf60a7b7da, 0x282e0bd633a4f, 0x28637c9bd78d1,
+    0x2865bb37402a2, 0x28663ef90d256, 0x287728a7c7bb1, 0x2877cd5a07f52,
+    0x287fa6a57decb, 0x289850011bf3c, 0x2899db4682e58, 0x28a6cb80393c5,
+    0x28b1dc46f0563, 0x28c02f83b9a75, 0x28cef08cadc9d, 0x28d7f24c30f6b,
+    0x28d9430292237, 0x28e56647b448c, 0x28eda60d74591, 0x291405457c2fd,
+    0x293187576474d, 0x293ceff4a4363, 0x293f064db8dbd, 0x29416d2b75705,
+    0x29448b08240c7, 0x2947b036b3613, 0x294d97c2784e4, 0x29755654a2c30,
+    0x29a02076ca065, 0x29b165a4fd9fd, 0x29d28cfe51d88, 0x29d41beca9269,
+    0x29dbd7f09c3ba, 0x29e98a2415af0, 0x29ece28fca102, 0x29eea4bb466a9,
+    0x29f3eee6abd63, 0x29f964fd55959, 0x2a4317dd68748, 0x2a447ae07b6ed,
+    0x2a51b44cf8fbe, 0x2a67b4e9f3f0d, 0x2a6dc10f1c790, 0x2ab46c0822e5b,
+    0x2abdb6fa6d48d, 0x2abfbeafc07d3, 0x2acbe59dd2fed, 0x2ad54685bf8bd,
+    0x2ade82d4487db, 0x2ae0a07f3ddbf, 0x2aeed674844a9, 0x2b1155d61a68a,
+    0x2b195eb5c5104, 0x2b23769ca3a4e, 0x2b3bd6c57a75b, 0x2b44f2239028c,
+    0x2b4730bef8c5d, 0x2b59552e725e6, 0x2b597d70c655d, 0x2b5d09199fc35,
+    0x2b706bf2c8bb1, 0x2b77c8ce52700, 0x2b7f25a9dc24f, 0x2b90e747fc011,
+    0x2b94101f7bb22, 0x2b9dcc86e142f, 0x2ba63be0d6035, 0x2bb50b8d8b971,
+    0x2bc6929ca5ae3, 0x2be1bc7492cff, 0x2bebdf5642798, 0x2beff612c99ae,
+    0x2bf5020638e53, 0x2bf97f3d0a1f5, 0x2c37f56b2190a, 0x2c3d30f2c58b0,
+    0x2c45b2996c18f, 0x2c85304b1d80c, 0x2c9599e0fb778, 0x2c9c29c7f0faf,
+    0x2ccbf88ba6cff, 0x2cd9cf5883de7, 0x2cdd11ce9615b, 0x2cf8e401b3cdd,
+    0x2cfc59b4eb117, 0x2d0ff3742971e, 0x2d379fb9a2191, 0x2d84ab0569592,
+    0x2d8b24f6bcb2b, 0x2d8ba166a8f55, 0x2da116eff6366, 0x2db11db47a70b,
+    0x2db14d48af20c, 0x2dd8b7ad414a5, 0x2ddb9000190c8, 0x2e05b918f0721,
+    0x2e116740070d6, 0x2e11848789efe, 0x2e139737ae393, 0x2e16d25bdfb7d,
+    0x2e38eb432bbd2, 0x2e3e68abb6352, 0x2e4144a77e53a, 0x2e4849ac7f611,
+    0x2e49dfecb767c, 0x2e6423317dd1d, 0x2e67ab3166e30, 0x2e6bbe44fda81,
+    0x2e705171710c1, 0x2e767ade1c76c, 0x2e8ec511511db, 0x2ea1ed5b74507,
+    0x2eb9375cefb98, 0x2ebc762a11947, 0x2ec86d83ef660, 0x2ed9329946609,
+    0x2ef124f5f2600, 0x2efc61a7edcda, 0x2efd4be404e1a, 0x2f0ccee6bc20b,
+    0x2f22c488e600b, 0x2f28f54772240, 0x2f2e8153be0d4, 0x2f3770c68f6c9,
+    0x2f39db4d3c5d6, 0x2f410fe6721ae, 0x2f8344fa88061, 0x2f90f72e01797,
+    0x2f98af8904323, 0x2faf637c00d27, 0x2fb90644d3dd1, 0x2fc87e4cba073,
+    0x2fcde5bfa2555, 0x2fdc2306c97c9, 0x2fe1f49cec3fc, 0x3025d23debff3,
+    0x304d4b463f9a0, 0x305536de675f2, 0x306e2d15bcf8c, 0x3080f28e866f1,
+    0x30a1babf7147a, 0x30a88c874d48b, 0x30a9ef8a60430, 0x30b49d7fbda07,
+    0x30bc9f0d878f7, 0x30d62efc4c4e3, 0x30e3561c180db, 0x3106b4bef42ad,
+    0x310f15752779f, 0x312b557470037, 0x3135d02ca8548, 0x31568267f1033,
+    0x315818a82909e, 0x315fc6085aadb, 0x316622b22b24c, 0x3172e7009d27d,
+    0x317e8683f251e, 0x3186e73a25a10, 0x31aa7571366e3, 0x31abd4cb590c3,
+    0x31b0a286d2353, 0x31b656d57215e, 0x31cfc97cb3f22, 0x31fcfa7c97c9f,
+    0x32085476161a1, 0x32149ffd8c36d, 0x3245a982010eb, 0x327611fd2608d,
+    0x329709c245917, 0x32a8230534d73, 0x32ae20869bee2, 0x32aeffc7e1ed3,
+    0x32b4b06d91719, 0x32c111eaa9b83, 0x32ccceb581c4c, 0x32ed60005734a,
+    0x332162c8170d8, 0x33275ca08dc82, 0x332dec87834b9, 0x33340af95da15,
+    0x333687ccbc5fb, 0x3338046e61e03, 0x334e51e71467b, 0x3358ed8fbff79,
+    0x3375ef88cb9da, 0x337935a7ce313, 0x3389b18a5df58, 0x3398c317fa06e,
+    0x33ba9dc74feae, 0x33f30b792f305, 0x3401834f5c1c9, 0x3404e30cf1365,
+    0x3419f96dd5174, 0x342c0095cbcd5, 0x3439e6066a4d1, 0x345ef48810f71,
+    0x34804f1e8a3c2, 0x34b5ebcf72780, 0x34c776877ceb7, 0x34d257b9ff554,
+    0x34d3e2ff66470, 0x34e1b62352f93, 0x34f12a8248c70, 0x350c80457a3c8,
+    0x351605c6ca64a, 0x352cd358598b1, 0x3534d88f13d66, 0x357b579cd5ef5,
+    0x35a9977234764, 0x35d7697b682bd, 0x35e7aacef22b2, 0x35e9744c4f3e3,
+    0x3600d7ec5d2d7, 0x3601a4e0f15ef, 0x3604f9a3b563c, 0x3621ecf8ff989,
+    0x363c1df114351, 0x363c303dc602a, 0x3651ce09673b2, 0x366701b1cdfe9,
+    0x3670579ee976a, 0x36798549b0f74, 0x36878801d2598, 0x3693d38948764,
+    0x3699fcf5f3e0f, 0x36aac95d2b942, 0x36b8de61fec3f, 0x36d3dff797ee4,
+    0x36e1a477c32f3, 0x3715481719a7f, 0x3729ab21fbdd9, 0x373f503f7dceb,
+    0x374d2e5e3b95d, 0x376ead8e18760, 0x37709ba4d9243, 0x37813877dc275,
+    0x3786f06f6c645, 0x378d58140df05, 0x378e66e9889f7, 0x379d3de81eebd,
+    0x37ad39b1d2113, 0x37af70fb59f5a, 0x37b1f8c989c8f, 0x37ccd21ccefbd,
+    0x37e535ee9628f, 0x37e7a7c723d26, 0x37f79144252a3, 0x3803e0748ba34,
+    0x381973455bc6d, 0x382de14b0f116, 0x3831b27dbf58d, 0x384df27d07e25,
+    0x385bccf2d54d2, 0x38905725527d9, 0x389496242d966, 0x3897a1b42a64f,
+    0x38a60ae695dff, 0x38a8cd43cb784, 0x38c9ab6a587ab, 0x38efc51889778,
+    0x39056ddefbc4f, 0x3915450f4b4f3, 0x393c74e4d7b3c, 0x3961b6a3a36a2,
+    0x3966b04a60e6e, 0x396c81e083aa1, 0x39856d1d082ec, 0x39d478cc41ea9,
+    0x3a06c463567df, 0x3a25b0ca3275e, 0x3a31a82410477, 0x3a37467d0dfe4,
+    0x3a39558441eb4, 0x3a4182fd502e0, 0x3a6de7089fd45, 0x3a6f4662c2725,
+    0x3a79ed063f172, 0x3a85c36fa9a9e, 0x3a8dc8a663f53, 0x3ab85f8b662c2,
+    0x3ad4feb31815c, 0x3adfe38e8adbe, 0x3aee7c552b06f, 0x3aefd8065d48a,
+    0x3aff2b74dfd7a, 0x3b1f230846226, 0x3b2e4339a3a50, 0x3b50846343a1c,
+    0x3b55263378770, 0x3b5c70c2505e6, 0x3b66ad42928e2, 0x3b766ad44f923,
+    0x3b865f4c21fef, 0x3bd266bd3fa4d, 0x3be503f3b523b, 0x3c079cf3ddc7f,
+    0x3c18954659cee, 0x3c1ea8bd630fb, 0x3c252da9877e3, 0x3c2cdeb2a97e5,
+    0x3cbc4754daa10, 0x3cc6babb32397, 0x3ccd6f3b8b580, 0x3ccfe4bd095dc,
+    0x3ce7117701e45, 0x3ce92b7906e64, 0x3cf9e1ea9c6f9, 0x3cffafd7ced67,
+    0x3cffd47132719, 0x3d1fc4b2b803b, 0x3d2a17289c5d5, 0x3d37c95c15d0b,
+    0x3d51b87343ef9, 0x3d64978a9fec1, 0x3d666859ddb7c, 0x3d6daf3fc542d,
+    0x3d79fe702bbbe, 0x3d89a2635639c, 0x3d93aba6735d2, 0x3d95baada74a2,
+    0x3da1c7fd27459, 0x3da606fc025e6, 0x3dcf1d966eb88, 0x3dd5435a29c6e,
+    0x3de0edd85005e, 0x3decd68e6c663, 0x3ded73eecbe7a, 0x3e20fa469f7de,
+    0x3e2e28b84bf60, 0x3e4947956802d, 0x3e6da669fd5dd, 0x3e6e4b1c3d97e,
+    0x3e78ac35e362c, 0x3e80c7623fd7f, 0x3ea10bd15db54, 0x3eb917cc9c3ae,
+    0x3f548dbe4d590, 0x3f56f0f319913, 0x3f57f876b387b, 0x3f5d37a747de6,
+    0x3f681530d9ebe, 0x3f769458e790c, 0x3f8889342c794, 0x3f976ed68436e,
+    0x3f9a179527490, 0x3fcf00f00dd99, 0x3fde5bb071213, 0x3fdeafde096c6,
+    0x3ff593653abcb, 0x437f975aaec4c, 0x43d950ff45de0, 0x4427dc95a2fae,
+    0x44eb8d896ad06, 0x453dd45cd60ad, 0x45535889e4bd2, 0x45a8447302ad6,
+    0x45e954bbfbbf9, 0x45f7a7f8c510b, 0x469c2e4dbaccf, 0x47d6b826a8444,
+    0x48c549323986f, 0x48daf94a8c8d0, 0x491604c03dcfa, 0x49342b84664eb,
+    0x4946deb07df77, 0x4a072142ef41f, 0x4b23377bfca7a, 0x4b39bbdac497d,
+    0x4b56e61624355, 0x4bb10d86e61ff, 0x4bd0a5f1e30a9, 0x4c5d7f7403a15,
+    0x4c893029519c5, 0x4d3f2045de8d3, 0x4d606c3896610, 0x4d6102471529d,
+    0x4e3e7db8a7831, 0x4e48bde1da0f2, 0x4e79aa1ecc048, 0x4e871a715efa4,
+    0x4ef6a51ebc586, 0x4f27657069fa0, 0x4f46f2e095cfb, 0x4faef4c725252,
+    0x4fb103ce59122, 0x50740c66f0514, 0x508333ea2e8c8, 0x51088bf761d33,
+    0x5124ece71d9b8, 0x5129abfed5534, 0x514940c0e1e19, 0x51b87397b6983,
+    0x51fc379a23d17, 0x52317522a2ad3, 0x526001d3b8c6b, 0x52a2751fc4d33,
+    0x52ce1731515cf, 0x533f04e1c1b56, 0x53518c22950a6, 0x53865bdee914c,
+    0x53cccff1da18c, 0x53f68043b5980, 0x5457254ebb388, 0x54b8f676be6aa,
+    0x54e5e93eac212, 0x54fcc1cb0c5c8, 0x554691f2a21df, 0x558ba3028027a,
+    0x55e8439db0745, 0x56219439c6152, 0x562b41fd6a34b, 0x5636aa9aa9f61,
+    0x5669b82b81a60, 0x56bb8637f0fa2, 0x56cdc09d0cbc9, 0x56dc4abfeb766,
+    0x57305eb9a4203, 0x5749046c51caf, 0x57c1146946135, 0x57c1c7bf47bea,
+    0x57eb23e38af2b, 0x580e987c0939b, 0x58171679bf6b5, 0x585ccfe4ce0b6,
+    0x588e6bced14fc, 0x58f4032eb3b46, 0x5915ddde09986, 0x59548e9b26ceb,
+    0x595c72e16ddb3, 0x595cc70f06266, 0x5980fda14789f, 0x59a9fa9d215de,
+    0x59c73e7713819, 0x5a5b3a45b8084, 0x5a61c683bd2f6, 0x5aa465bb0d8fa,
+    0x5aa5ed5784251, 0x5ab2144596a6b, 0x5ac4350c1fe2f, 0x5afd85a83583c,
+    0x5b21ef779bf3b, 0x5b3edf23f5cc3, 0x5bc9c33d74fc2, 0x5cb382e49cb98,
+    0x5cbece3a59986, 0x5cd0295e2f5bc, 0x5d0dd297b29b9, 0x5d144c8905f52,
+    0x5dd784b5d1e45, 0x5e23295595cdc, 0x5e23ce07d607d, 0x5e493808f5b5a,
+    0x5e6d37b521b08, 0x5e7ab30285bb3, 0x5e8d95c2d2140, 0x5ed1d9de1bec3,
+    0x5f05e7a0acda0, 0x5f286359529bc, 0x5f45bd28e6e95, 0x5f5f6e081ee6e,
+    0x5f7ce17645baa, 0x5f9e72f2d4686, 0x5fcff53e45269, 0x5fd5297408685,
+    0x6078db82893a7, 0x60e8def6e27ee, 0x61193c7736641, 0x614b1a4220261,
+    0x614b587a16476, 0x618ee1ed7dbba, 0x61a7afe27f5dd, 0x61a9cd8d74bc1,
+    0x61d1e79f1834a, 0x61defc7232269, 0x61eb7b36cd4fb, 0x62e892bc4cefe,
+    0x62fe8fb057888, 0x6341f8334bbdf, 0x63782749c2665, 0x6392b3c15006a,
+    0x6393fd25d07ac, 0x6397c706a0099, 0x639a9bb0876f7, 0x63accb1ad21cf,
+    0x63b5123272e5e, 0x63c3f082e9eae, 0x63c518f6f7203, 0x63d99cf24c94a,
+    0x63e62308c8766, 0x64dd14ca8d083, 0x64f9845e0a41c, 0x653458eda61bb,
+    0x655a63f815a74, 0x657e9e3347672, 0x65a40bdd57714, 0x65b05b0dbdea5,
+    0x65ff213320cc3, 0x65ff6e0ed85ec, 0x6624c21a55e2b, 0x6649f5356027d,
+    0x6670c23992cff, 0x6672796a3e157, 0x66769778a5ef7, 0x66a75b7343ed6,
+    0x66b665aeff462, 0x66e18ef98fe99, 0x67271527797d4, 0x676c1b3c86720,
+    0x6790298c73de2, 0x679e2149c42b7, 0x67a00bb7947d5, 0x67bc4f5fcd632,
+    0x67bdf09ad67ec, 0x67c93847a3015, 0x67df1f460b701, 0x67ef7de11851e,
+    0x67f92f4daccdc, 0x68003ba48e93d, 0x681bcbf6c5ce5, 0x68218ee927204,
+    0x682f571242bd8, 0x683e614dfe164, 0x6862add5e1a3b, 0x68695b045a09a,
+    0x687852f36394d, 0x6891fc80bad9c, 0x68a88bda53dee, 0x68b6af82e87ff,
+    0x68c27af181fdc, 0x68d96d1c74bf5, 0x68ef7c5d31258, 0x691374b77c67c,
+    0x69437a6147a57, 0x694538e3d3a39, 0x6998e2ba51d85, 0x6999ea3debced,
+    0x69b8ec9a69f0a, 0x69be540d523ec, 0x69d60832081ce, 0x69dbf70fadc29,
+    0x69e17bca18f33, 0x69f71995ba2bb, 0x6a00732bc6001, 0x6a10b0d65fa31,
+    0x6a1be6367a581, 0x6a1cedba144e9, 0x6a575f78566c1, 0x6a711efb4fdae,
+    0x6aa06da62910f, 0x6b0c321ba73c9, 0x6b2807f7b5510, 0x6b30da2303cdd,
+    0x6b389626f6e2e, 0x6b47030252ba3, 0x6b79fa9d88404, 0x6b93bdc9720b6,
+    0x6b9c4e13da0a9, 0x6b9e08ed75ac6, 0x6bcfdf667eb5c, 0x6bed2e3b41ee6,
+    0x6c182b9a8e3e1, 0x6c2322c2b2d1c, 0x6c24821cd56fc, 0x6c3388afa06c3,
+    0x6c371f534aeea, 0x6c51edabbf0c9, 0x6c5c1f3130276, 0x6c60da9ff782d,
+    0x6c6a88639ba26, 0x6c839be2741e8, 0x6c8cc98d3b9f2, 0x6c93cae94c504,
+    0x6c98cf8adae1f, 0x6cb2756f41ca9, 0x6ccb520804de0, 0x6ccb72f8781cd,
+    0x6cdad8b3ac796, 0x6cdf090ec620f, 0x6cfec61326a6b, 0x6d3ffe9e73b05,
+    0x6d4e93bc237f1, 0x6d57b2c3298e7, 0x6d6bbdf7831c9, 0x6dae0caa2b8df,
+    0x6dccfcb9f7e23, 0x6dd1171f6f5fe, 0x6dd4a2c848cd6, 0x6de0dfabfd78e,
+    0x6debecc9c4367, 0x6df5b0830a7fe, 0x6e20a2e785baa, 0x6e6066230dfc6,
+    0x6e6f14df50515, 0x6ed053a1c516f, 0x6ef6500873314, 0x6f34b792c9315,
+    0x6f5a6ac6b0156, 0x6f81257e30eff, 0x6f939a7252776, 0x6fa49a16af36f,
+    0x6ff36b36e32dc, 0x6ffade080f0c9, 0x702c8fe7b47ad, 0x704ebec4a2aa0,
+    0x70871dd2c07e3, 0x7096ccc0bc110, 0x70ae6e98c0219, 0x70d7a27aaf5e3,
+    0x70d8d5e98da87, 0x70f3f4c6a9b54, 0x7148b11b92f57, 0x71796a1b5fde7,
+    0x71c3f4ead803d, 0x71d45e80b5fa9, 0x71f7111f71250, 0x721b2e1320026,
+    0x7221a45b82ffa, 0x722f2e4ca87b9, 0x72302ad5715d2, 0x72500c73357e0,
+    0x72537e7d7c655, 0x7280664a9906e, 0x72a4958af9b1d, 0x72b85b357c660,
+    0x72c9622bb9de3, 0x72c991bfee8e4, 0x72eb3cdb0fc23, 0x730098c5ca7d1,
+    0x7316209bc98bb, 0x73202d87d70b6, 0x732409b55867c, 0x7325aaf061836,
+    0x73318ffd8d876, 0x734676ca3cb84, 0x7353cd7e3d27d, 0x735b47a149bf4,
+    0x73672cae75c34, 0x736b0c84e77bf, 0x7399c178518ce, 0x73a8a371b8ee3,
+    0x73a93d2928135, 0x73c6d187c225e, 0x73ee88c80be20, 0x740953778fa3a,
+    0x74133821492be, 0x743132fa2d573, 0x7436513a4e6f1, 0x7449a56fb5f59,
+    0x74644b85d61c1, 0x747c3de2821b8, 0x74837624a8355, 0x74899be86343b,
+    0x748e1cc824da2, 0x74cf1ac46c1ec, 0x750015a51f856, 0x752c81024fe45,
+    0x7544c78c942ef, 0x7580e580b07d0, 0x759f2d354c3ae, 0x75ad632a92a98,
+    0x75ccc10689cf2, 0x75cdc138430d0, 0x75e5755cf8eb2, 0x75ec55c8965d7,
+    0x7606d74552e8d, 0x7607f4be8f093, 0x761b8e7dcd69a, 0x762501b26bc43,
+    0x76513280965e2, 0x768297848439d, 0x7687d6b518908, 0x76b0a7c5ae10b,
+    0x76b27c3ddc38b, 0x76b43ac06836d, 0x76c4786b01d9d, 0x76d3655f3a501,
+    0x76d7836da22a1, 0x7738f9162c586, 0x778ee188132a3, 0x77f1decd13edf,
+    0x780df2e11823b, 0x7830e760b9cbc, 0x784690272c193, 0x784ecc43fbcd3,
+    0x7855cda00c7e5, 0x785e026afb79b, 0x7882f3a51f413, 0x78ab7b82ed8b2,
+    0x78fdc25658c59, 0x7929d985f0d95, 0x7933f8beb0269, 0x7937a900ed2f3,
+    0x793db17d255b1, 0x79d3f6e20393c, 0x7a00cc626e67c, 0x7a05f94650f0e,
+    0x7a157c49082ff, 0x7a3702cac5c8c, 0x7a3c9d7ad3234, 0x7a4ffcab0bbeb,
+    0x7a50f58ae443f, 0x7a7c1ed574e76, 0x7a8b6af216bdc, 0x7a8ead6828f50,
+    0x7a9727bceeca5, 0x7aafa18458215, 0x7acacb5c45431, 0x7accb5ca1594f,
+    0x7ad628feb3ef8, 0x7aec2d449f40c, 0x7b17c8044b11e, 0x7b664c48c7762,
+    0x7b7f671980aae, 0x7b90ea7faa65b, 0x7ba0b6b528db0, 0x7ba4c9c8bfa01,
+    0x7bd8577273eef, 0x7c1da6ba4819f, 0x7c20ff25fc7b1, 0x7c3031a40bcb4,
+    0x7c3152c63847f, 0x7c4101b433dac, 0x7c48d3adc919b, 0x7c5b6d3b4e3c4,
+    0x7c72a4f017d7c, 0x7ca0c77df37c3, 0x7ca352f513abd, 0x7cacd4cd7377a,
+    0x7cb172f4b7f09, 0x7cb69c2faa1d6, 0x7cf1830bf7c4e, 0x7cf7250de5d80,
+    0x7cf925715853c, 0x7cf9cdcc88ea2, 0x7d1101d862295, 0x7d1d9a3b8fd8a,
+    0x7d41f1be447b0, 0x7d65b6db6ab0e, 0x7dcbb10ca6d1f, 0x7de670c1597ea,
+    0x7e0dcc822a36f, 0x7e103e5ab7e06, 0x7e247923461e9, 0x7e2784b342ed2,
+    0x7e34a4812df40, 0x7e5b5f38aece9, 0x7e5e930aff949, 0x7e63fa7de7e2b,
+    0x7e73553e4b2a5, 0x7ece7ce0c652d, 0x7ee087b1ad653, 0x7efdb595fd5f0,
+    0x7f0557fb5dede, 0x7f1f2d73f9869, 0x7f37237995e25, 0x7f3b20978a7d8,
+    0x7f3dd0a80e484, 0x7f5fdaeb98dc5, 0x7fa6fb02aad30, 0x7fac7fbd1603a,
+    0x803cf38bd1792, 0x8040ff4d87859, 0x8043a4633a3b6, 0x80863c48a9e30,
+    0x810929cf3038e, 0x810ec47f3d936, 0x8124f8595d94b, 0x814426a1200a4,
+    0x816a7e8747286, 0x81eb4368070b1, 0x820b2505cb2bf, 0x820ebba975ae6,
+    0x8218f480c781d, 0x8227b589bba45, 0x8238f70efee18, 0x82687c9fed804,
+    0x829cab52f1ace, 0x82d4d37afa186, 0x832e020be37dc, 0x83527e27fbbb4,
+    0x836eb32c732fd, 0x837087a4a157d, 0x8377d5dc699b8, 0x83a907a3326ad,
+    0x83b9e6571beb9, 0x83d88d3421099, 0x83dea0ab2a4a6, 0x8416b68680e85,
+    0x84a09e173736b, 0x84be277b00345, 0x84c0d78b83ff1, 0x850ea879feb80,
+    0x851617a23a3a8, 0x851dc1597b820, 0x856b50670fbd5, 0x8587473391109,
+    0x85cca17636508, 0x85e5d23c91af2, 0x861fe1297a103, 0x8627be1de0641,
+    0x870b83ec9166d, 0x8729f78c71787, 0x8746aca9c58bf, 0x874b2d8987226,
+    0x874c7345173a3, 0x874da30b05282, 0x874e4b6635be8, 0x8799b1ce0386a,
+    0x87a05401aad7a, 0x87a54da868546, 0x87a9680ddfd21, 0x87ad6182e410f,
+    0x87b94a3900714, 0x87b9fd8f021c9, 0x87dc1dc82eda8, 0x87e4dda6cb89c,
+    0x88169a81420cf, 0x8821a3f6186e3, 0x885bb68bf12b9, 0x889073fb93686,
+    0x88a37efe33b8a, 0x88b91577f4388, 0x88ed10edd358c, 0x88f3c1c53c1b0,
+    0x88fd14096736c, 0x892b871beaca1, 0x893ed7a861f44, 0x89503dc708cc9,
+    0x897f88c8f1a65, 0x89b6d558a46f1, 0x89c3039897a95, 0x89c81334f74ff,
+    0x8a05b173a97ad, 0x8a48dbbea78ef, 0x8a51526a7d07f, 0x8a699c9db1aee,
+    0x8a7f923fdb8ee, 0x8a9528b99c0ec, 0x8aaaf9c26253a, 0x8aecff42438ec,
+    0x8afd3cecdd31c, 0x8b31e80fcda10, 0x8b760791b3de1, 0x8b7963a6589b8,
+    0x8bb931dcb1f23, 0x8c11a96ea94ff, 0x8c4f5da2fda4b, 0x8c6bac46079f7,
+    0x8c7ee333ec437, 0x8c9922cfc2513, 0x8c9a479adf2a3, 0x8cac9b9e8d72d,
+    0x8cd70a413bb25, 0x8d4b5b583180d, 0x8d787db453e76, 0x8d78e085ada3d,
+    0x8dd18059f8f90, 0x8ddd1c345dc6c, 0x8e2564bf7cf2c, 0x8e340880ee32c,
+    0x8e3d4c2157dd4, 0x8e40db7321a71, 0x8e41543a1d8d6, 0x8e4db5b735d40,
+    0x8e5d7a9ad390b, 0x8e8db72ab4371, 0x8e9647751c364, 0x8ea7b88e94238,
+    0x8ecc643ee1111, 0x8ee3c7deef005, 0x8efbb2e9ba472, 0x8f76f6b8ff558,
+    0x8fb62b37e9871, 0x903021f9bdc50, 0x904158842fed4, 0x905f2ec3b07d7,
+    0x905f3d6771eeb, 0x9063e689877c9, 0x90668f482a8eb, 0x90803183a11b0,
+    0x90890b00d0507, 0x908efd8766527, 0x90ac3db86819d, 0x90c6675e9bfdb,
+    0x90dd264c69b2e, 0x910902ecfc01a, 0x91676d0589616, 0x91b89a08a8d7c,
+    0x91c0c02fd661e, 0x91c4b252f9e82, 0x91f47d6dbf60d, 0x922f72edce799,
+    0x924da4acc80d9, 0x924deddf8f43d, 0x9253dcbd34e98, 0x9263b04494177,
+    0x9276e73278bb7, 0x92c39355d69b6, 0x92c5851587a5e, 0x92c8cb348a397,
+    0x92e40b0219851, 0x92eccae0b6345, 0x9329cbbf08ddc, 0x9331f58f26c43,
+    0x9335a22873708, 0x93366b741745b, 0x93517f56623d9, 0x9359b4215138f,
+    0x937b5f3c726ce, 0x9396caf5460c4, 0x939f9225c3742, 0x93d3ae8c15d33,
+    0x93e7dab0e2a02, 0x94059afac1067, 0x9414ac885d17d, 0x944e848f30103,
+    0x945cb33295c63, 0x94953a830791d, 0x94ae6b4962f07, 0x94e4b3fe6c1f0,
+    0x94eca33f84407, 0x94f90c0e7d3fb, 0x94fd22cb04611, 0x94ff05e6f3fa5,
+    0x9513f40583e3d, 0x9519abfd1420d, 0x9522f3466e27a, 0x952e42451b62d,
+    0x9530e008ed600, 0x9541a17553fe4, 0x954d484a89e0f, 0x9556a1e095b55,
+    0x9563c1ae80bc3, 0x9576454663b4e, 0x957f4705e6e1c, 0x9582f74823ea6,
+    0x9584619d179d5, 0x95a78959de51c, 0x95eac5f18e337, 0x96375c1f49e98,
+    0x9639bf541621b, 0x963f47b771aea, 0x9656488625e17, 0x976c180b04f9f,
+    0x977df6f0a7b89, 0x97d533c1e0137, 0x9818446e4ba16, 0x982b61bd9dbf3,
+    0x98557c32b3b38, 0x98561241327c5, 0x985c46a8aefbf, 0x987c6a27599a7,
+    0x988b8e01a7796, 0x9891d10ce56a4, 0x98ad05dfa3a0f, 0x98bf0d079a570,
+    0x996c82cf61729, 0x9970d071fdfca, 0x998550c46314c, 0x998faa8c28270,
+    0x999c58e4f8003, 0x9a2cf89ef7c97, 0x9a35caca46464, 0x9a6d88cf143cb,
+    0x9a70ec3599b2c, 0x9a89575941988, 0x9a8e8f37f5369, 0x9a909344580ea,
+    0x9a93ad78164e7, 0x9a9505805833d, 0x9ab7c319e4733, 0x9abf533293348,
+    0x9af7b5e9a1650, 0x9af811691a68d, 0x9b0edefaa98f4, 0x9b612225246d6,
+    0x9b9f567255611, 0x9ba6925d6bd73, 0x9beaf01748359, 0x9bf7971e37562,
+    0x9c15a7ecbdab5, 0x9c2cd4a6b631e, 0x9c46bc6c03982, 0x9c6bf6d8ee95e,
+    0x9ca11ac2daeb7, 0x9cd039d97f717, 0x9cf8c9092e740, 0x9d591d8f8c25a,
+    0x9d6d042a8218a, 0x9d7caf6f8d4f2, 0x9d80167f03218, 0x9d95676eecc77,
+    0x9d96d1c3e07a6, 0x9da904d71b843, 0x9db9aca4ef9c4, 0x9e2af97dc954d,
+    0x9e773f26dd1c0, 0x9e812027a647f, 0x9eb2d5b03c128, 0x9ec8c4008539e,
+    0x9ed89ed9c5207, 0x9f170db5fbd92, 0x9f4df022742cd, 0x9f992a9efda13,
+    0x9fd807475f2b4, 0x9fe55dfb5f9ad, 0x9ff4e4a707363, 0xa00681abc3773,
+    0xa02c1eea08316, 0xa02e77240354a, 0xa02e7accf3b0f, 0xa05317d97f2d4,
+    0xa07d02ba60718, 0xa082b70900523, 0xa083c9876b5da, 0xa08ae4820e94f,
+    0xa0a8a122fc9ef, 0xa0c3e8426ca33, 0xa0d1e3a8ad4cd, 0xa0dfe2b7de52c,
+    0xa1404d33de2e4, 0xa15e1c217e05d, 0xa163037b89b50, 0xa17e2601961e2,
+    0xa1decb0c9bbea, 0xa20d999e9855c, 0xa22b26ab51afb, 0xa272eb74a3e07,
+    0xa28099ff2cf78, 0xa28337c2fef4b, 0xa28ed746541ec, 0xa2ad17a90f240,
+    0xa2b6922f8e373, 0xa2c7d00be1181, 0xa2de87a7ce14a, 0xa2df50f371e9d,
+    0xa2e681e3b74b0, 0xa2e6c3c49dc8a, 0xa2e76c1fce5f0, 0xa32527a6036c6,
+    0xa327ea033904b, 0xa33101b85e5b7, 0xa333e50607329, 0xa349b60ecd777,
+    0xa34dbe2793279, 0xa3500f0fad923, 0xa36f200fed254, 0xa381706aab119,
+    0xa3a03838236e6, 0xa3aacc8eee45a, 0xa3f3ed0972b81, 0xa426648bcb9f3,
+    0xa42b14ffc1e5b, 0xa430c5a5716a1, 0xa4395246e90cf, 0xa44dc79e7d102,
+    0xa45fc022b254f, 0xa46e4a45910ec, 0xa491377351fe3, 0xa49f69bfa8108,
+    0xa4c7718499bb8, 0xa4cb884120dce, 0xa4ff7c651f448, 0xa50b91067ff89,
+    0xa5182969ada7e, 0xa51c9ba5adcd1, 0xa5459ff36859a, 0xa56e0a89b3c11,
+    0xa59be78db88b9, 0xa5a71944e2e44, 0xa5b0b112e4d9f, 0xa5b568d8bbd91,
+    0xa5dcc0f09c351, 0xa5e46355fcc3f, 0xa60ea26476536, 0xa6391c01f5a7d,
+    0xa680ce7e960b0, 0xa69d247380be6, 0xa69e83cda35c6, 0xa6f001556ac1a,
+    0xa7062a34b9ae0, 0xa7064ece1d492, 0xa70986495e6b7, 0xa718942e0a208,
+    0xa7566cfbc2106, 0xa78fb9eee754e, 0xa7a700477261a, 0xa7a958816d84e,
+    0xa7b65eb0c6059, 0xa7cbabf7bf4f3, 0xa7cdc5f9c4512, 0xa830c33ec514e,
+    0xa83d878d3717f, 0xa8688143930b5, 0xa8960dc2efe6f, 0xa89f75fcbd2c9,
+    0xa8a104eb147aa, 0xa8b629efb9ccd, 0xa8e0ff0cb2251, 0xa908ed331149e,
+    0xa90bccd7c9c4b, 0xa945fcb525649, 0xa959374bfa64e, 0xa98d48b77baf0,
+    0xa9b707ad189f8, 0xa9d75abff7ee1, 0xa9e5527d483b6, 0xaa10af04fdeb3,
+    0xaa403be7cd429, 0xaa6fba26db28b, 0xaac79baa53bda, 0xaad421c0cf9f6,
+    0xaadbf3ba64de5, 0xaadd40c7d5aec, 0xaafae773218ee, 0xab0d9a9f3937a,
+    0xab60b96209b88, 0xab6f8cb7afa89, 0xab997046b0343, 0xabada3bd5db9c,
+    0xabcd97a7d3a83, 0xabd58a91dc25f, 0xabf3aa0423ec6, 0xac0175d62fe5f,
+    0xac155c7125d8f, 0xac30705370d0d, 0xac4afccafe712, 0xac555a3bb3dfb,
+    0xac782a21f1eca, 0xac9b7a210c988, 0xaca3e97b0158e, 0xaccbfc3ac418d,
+    0xad06b72bcd6c9, 0xad12b22e9b9a7, 0xad365dad2f4a2, 0xad50924e3442f,
+    0xad64dbba83f26, 0xad9f1de4915fd, 0xada4d98511f92, 0xadaef16bf08dc,
+    0xadc87a08d493e, 0xadd71dca45d3e, 0xade0d688bb086, 0xade5ba39d65b4,
+    0xae0c8e8fe9bc0, 0xae1cfbceb80f1, 0xae28a9f5ceaa6, 0xae54485e6ad7d,
+    0xae62f71aad2cc, 0xae6906e8c6114, 0xae697c06d19b4, 0xae7e99b99634d,
+    0xaea9c30426d84, 0xaeb736ffaa2a5, 0xaecce36f0cd41, 0xaed3ade5081c8,
+    0xaf2e01410e5ae, 0xaf3fbb8d4d7e6, 0xaf4891618c578, 0xaf4c330007eee,
+    0xaf712b8c0c6f0, 0xaf8073ffbde91, 0xaf815a92e4a0c, 0xaf9bfd00146af,
+    0xafab95f86dd3e, 0xb015c42db3f8d, 0xb019c4f498f05, 0xb01d6de4f5405,
+    0xb03fed468b5e6, 0xb051919d28580, 0xb066dee421a1a, 0xb0754f686dd54,
+    0xb0a7e08959429, 0xb0b84dc82795a, 0xb0bc140006c82, 0xb0c67c6b8d4ba,
+    0xb107e48b0f055, 0xb119818fcb465, 0xb12e610a99be9, 0xb12fb1c0faeb5,
+    0xb13156a4f4634, 0xb139dbf48b4d8, 0xb13b6739f23f4, 0xb146b9e18fd6c,
+    0xb15bba4cd18dd, 0xb1685658ef997, 0xb16aff1792ab9, 0xb16b18b62531c,
+    0xb16d8a8eb2db3, 0xb16e6627087df, 0xb1a348300e55e, 0xb1b98704ff6c2,
+    0xb218c90cf2125, 0xb2435c4903ecf, 0xb2608a2d53e6c, 0xb2d76a645a413,
+    0xb2d9f9846acd2, 0xb2dca5ebfe3b9, 0xb2ed30724f712, 0xb32e4bb619984,
+    0xb356aefa84471, 0xb3682b0ecd494, 0xb3c9a0b757779, 0xb3cdf202e45df,
+    0xb3dd8afb3dc6e, 0xb3ebb24cc2c44, 0xb3f7014b6fff7, 0xb40c34f3d6c2e,
+    0xb4489871c9eae, 0xb454dca75f4f0, 0xb474b34a525af, 0xb490ebf7ba2bd,
+    0xb4c960fb7a29e, 0xb4f0822d451d3, 0xb50ff255ee106, 0xb51af478e3b90,
+    0xb53407f7bc352, 0xb539597502596, 0xb53f868a9e206, 0xb583299c981ad,
+    0xb5aed300055d3, 0xb5c745758dfb9, 0xb5cbd4f911034, 0xb5d0d248bedc5,
+    0xb5e556441450c, 0xb64c4203483e7, 0xb64f28f9e171e, 0xb659866a96e07,
+    0xb6c517a2efffb, 0xb6f0065e7ade2, 0xb701a70c277b7, 0xb73b915fac416,
+    0xb73e8e4be79eb, 0xb741ac28963ad, 0xb79121fb0a6bb, 0xb7a9dda35a405,
+    0xb7c0aeddd9c31, 0xb7d6d06b47f6d, 0xb7e639cf6cafb, 0xb7f58994fee26,
+    0xb7f9a3fa76601, 0xb804cab6cfa3d, 0xb86ebab41fa77, 0xb8af7e2161271,
+    0xb8c4b1c9c7ea8, 0xb8e73fcf1f79d, 0xb8ebddf663f2c, 0xb93e963eea5ae,
+    0xb941f9a56fd0f, 0xb95f5e6fd5337, 0xb96897156dc90, 0xb9d5496ff364f,
+    0xb9ef60c9757b4, 0xba3bd606bdf28, 0xba4ccab0499d2, 0xba9428ff51b52,
+    0xbaa4a4e1e1797, 0xbb048f4504b60, 0xbb1e22dcb9d11, 0xbb20e8e2dfc5b,
+    0xbb24c8b9517e6, 0xbb2569c2a15c2, 0xbb396da51a31a, 0xbb4051b9a8004,
+    0xbb41384cceb7f, 0xbb61c5eeb3cb8, 0xbb705b0c639a4, 0xbb799eaccd44c,
+    0xbb868b3d933f4, 0xbb8fb53f6a639, 0xbb91aaa80bca6, 0xbb9fef4113aa4,
+    0xbbaa7feeee253, 0xbbacbe8a56c24, 0xbbb1dcca77da2, 0xbbee563e0d2c0,
+    0xbc042746d370e, 0xbc0820bbd7afc, 0xbc1644646c50d, 0xbc2be981ee41f,
+    0xbc6e348ba6570, 0xbc7632707fe9b, 0xbc9764c4a5375, 0xbcde972868fb9,
+    0xbce8f4991e6a2, 0xbd0e992943dcf, 0xbd1b736d5809e, 0xbd30ddfbd4360,
+    0xbd4995fb33ae5, 0xbd71b3b5c7833, 0xbdb7ab58cc449, 0xbe1a5bc21575c,
+    0xbe5bcedc68446, 0xbe627fb3d106a, 0xbe70149fc7978, 0xbea38ffcca18d,
+    0xbeb119edef94c, 0xbeba981d5f044, 0xbee70e7560782, 0xbf407ee7305b2,
+    0xbf66de1f3831e, 0xbf7f2fa44d917, 0xbf81c26d4e79b, 0xbf9e47f66ddd2,
+    0xbfc0e49f86ddb, 0xbff166b93e5e0, 0xc011ff55f4868, 0xc061d0a7e1bb3,
+    0xc0e9b42c35318, 0xc11de2df395e2, 0xc12c570c75ee1, 0xc195e4386c354,
+    0xc195fdd6febb7, 0xc19634bd14242, 0xc1dcb021e5e0c, 0xc228bb3bf3e2f,
+    0xc26304b7e2090, 0xc285c5fa5ea4b, 0xc286a192b4477, 0xc2a6cc633f9e9,
+    0xc2d08406fbd67, 0xc2dbaac3551a3, 0xc2e8ce3a307d6, 0xc2f6ac58ee448,
+    0xc3180a9857e5e, 0xc32761afcad13, 0xc3334e0ed78dd, 0xc343dc3e191fb,
+    0xc376cc876ded2, 0xc38afffe1b72b, 0xc3bdc45c2bec6, 0xc448f8fa530b3,
+    0xc474b10181bed, 0xc49dc79bee18f, 0xc4c0ebafc4711, 0xc4d042c7375c6,
+    0xc4fcb1cd5817a, 0xc506499b5a0d5, 0xc523a713deb73, 0xc5530462795e8,
+    0xc57bfa0c7279d, 0xc5897902c6e0d, 0xc5a5f73a058ba, 0xc5a63571fbacf,
+    0xc5b0f95cfb344, 0xc5d5e6ee2e9f7, 0xc5d7f24c72302, 0xc5e0654f574cd,
+    0xc5e5f50493926, 0xc6162deb83dc7, 0xc63bc02ef781b, 0xc65a6ab4ecfc0,
+    0xc65f387066250, 0xc677530f661be, 0xc6898279b0c96, 0xc6a9d58c9017f,
+    0xc6b0bd4a0e42e, 0xc6b72897a02b3, 0xc6be473b33bed, 0xc6ceca6fa43bc,
+    0xc6d51fc793fa3, 0xc6e7a708674f3, 0xc6f12c89b7775, 0xc6f9e8bf63ca4,
+    0xc6fad6a46b3a9, 0xc73627a3f3572, 0xc741fa646d8d9, 0xc782d3c751371,
+    0xc786790ebd2ac, 0xc7a9fff3ed3f5, 0xc7c8d2bc36b11, 0xc7d579c325d1a,
+    0xc7e6bef1596b2, 0xc7f65440c277c, 0xc7f6f8f302b1d, 0xc7f946322cc02,
+    0xc7ff7e42999c1, 0xc81d07a66299b, 0xc8219729e5a16, 0xc837b50e6378d,
+    0xc841667af7f4b, 0xc841ccf5420d7, 0xc85daa2330da8, 0xc8895e816f31d,
+    0xc8a6c6f4c4f0a, 0xc8ae4117d1881, 0xc8e2cb4a4eb88, 0xc8fa572cb09f3,
+    0xc8fbd777467c0, 0xc904d936c9a8e, 0xc96193708c7bc, 0xc965aa2d139d2,
+    0xc9ce71b6566b7, 0xc9edb5f3bb0ae, 0xc9f93f816e0b1, 0xca08a53ca267a,
+    0xca08ac8e83204, 0xca8ef9d29e8fe, 0xca939f4bc3c17, 0xcab5d57a92a94,
+    0xcac0c1a7e6280, 0xcb029ee5736bb, 0xcb1581a5bfc48, 0xcb3d2a42480f6,
+    0xcb552047e46b2, 0xcb56deca70694, 0xcb682b4a84bb6, 0xcb802c4af22c1,
+    0xcb9dd2f63e0c3, 0xcba566b7dd29d, 0xcbb33d84ba385, 0xcbf50fc776671,
+    0xcc19e3ba174c1, 0xcc8b25981fefb, 0xccabf51aeb80e, 0xccbce9c4772b8,
+    0xccff262a6dcf5, 0xcd204631e14f6, 0xcd33a1b9298e8, 0xcd77aeee5dfe0,
+    0xcd9681b6a76fc, 0xcd96b89cbcd87, 0xcdc1a3af575a9, 0xcdc8b3af297cf,
+    0xcdd6ab6c79ca4, 0xcdf61b9522bd7, 0xce040105c13d3, 0xce0876eab1beb,
+    0xce0f0a7a979e7, 0xce343643c12af, 0xce37a0fc2759a, 0xce48c539e7b45,
+    0xce4be31696507, 0xced8bc98b6e73, 0xceefd35d0d43e, 0xcef4afbc47de2,
+    0xcf17f4c091751, 0xcf1ca18b975f4, 0xcf47cad62802b, 0xcf4d274e3f3be,
+    0xcf570bf7f8c42, 0xcf7bf5e03bd30, 0xcf9172bb69ccb, 0xcf951babc61cb,
+    0xcfc2ea0c0975f, 0xcfd1c85c807af, 0xcfe06c1df1baf, 0xd014d55ffbac9,
+    0xd016595381e5b, 0xd0237cca5d48e, 0xd039dc8fc19df, 0xd0656c549c5a2,
+    0xd0732d2bd73ec, 0xd081004fc3f0f, 0xd09d5644aea45, 0xd0a7ee4469d7e,
+    0xd0b6e63373631, 0xd0c74c2060fd8, 0xd0d13bc4eb9ab, 0xd14555f5cc008,
+    0xd170ed0c87755, 0xd17c853dfbe6c, 0xd1bed3f0a4582, 0xd1c37969c989b,
+    0xd1c7cab556701, 0xd1e851055acb0, 0xd21dea0d52aa9, 0xd266747958543,
+    0xd28fc1f9da170, 0xd29f8a8668300, 0xd2b07f2ff3daa, 0xd2b6fcca37908,
+    0xd2c7a0ef1b4c4, 0xd2d01b43e1219, 0xd2f6ebf104260, 0xd309fe45852ee,
+    0xd317c66ea0cc2, 0xd365975d1b851, 0xd39e1b049cf46, 0xd3a7ab80be317,
+    0xd3b7064121791, 0xd3d85985ba058, 0xd408f8e6f4685, 0xd40aa8c5bef53,
+    0xd40e6efd9e27b, 0xd43eb2df5f86b, 0xd44c566f1788d, 0xd458cde1d1f95,
+    0xd466df3db4ccd, 0xd4884c20dfdf7, 0xd4c1ff8e4f3cb, 0xd4c7ee6bf4e26,
+    0xd4e1fe7396401, 0xd517b86c015e7, 0xd5311c6f81c97, 0xd5478ad8a78fc,
+    0xd549e6bb930f5, 0xd5730fa2b1370, 0xd583d10f17d54, 0xd58b85c12a31b,
+    0xd5c0b4a5e79c3, 0xd5c2f34150394, 0xd5e4ca47b5c0f, 0xd5ebf03d2a0d3,
+    0xd5fcb1a990ab7, 0xd609e3c42d7fe, 0xd61b797709084, 0xd64df0f961ef6,
+    0xd666a8f8c167b, 0xd695235d25b3a, 0xd69811a59f9fb, 0xd6ceae8841197,
+    0xd6db67dbe2079, 0xd6e8003f0fb6e, 0xd6f9874e29ce0, 0xd70735d8b2e51,
+    0xd70991bb9e64a, 0xd731f50009137, 0xd735670a4ffac, 0xd777912394d10,
+    0xd7c795b2a7121, 0xd7d1fa753d394, 0xd7df1a4328402, 0xd7ed0aae97d4d,
+    0xd8001d0318ddb, 0xd8008acf43af1, 0xd81d55e239ec7, 0xd8341c21e85a4,
+    0xd85947eb11e6c, 0xd859b9602d147, 0xd863befa59db8, 0xd8738786e7f48,
+    0xd8ceaf29631d0, 0xd8d7bf8ca7bb2, 0xd8d829afe2303, 0xd8eb594be61b9,
+    0xd8ef2a7e96630, 0xd8fceefec1a3f, 0xd90623fb69dd3, 0xd91ebb0a5616b,
+    0xd92ac4b0e5b5d, 0xd974b5c8eeb61, 0xd9a9b8c267ccd, 0xd9bd3c8c04036,
+    0xd9d0aa5ffe101, 0xd9d9001b604a4, 0xd9d95b9ad94e1, 0xd9e6aea5e9615,
+    0xd9e9a09753a9b, 0xd9ff9d8b5e425, 0xda04a5d5dd305, 0xda0d695d6a3be,
+    0xda276ac14a285, 0xda2858a65198a, 0xda3e7ddcb028b, 0xda4f220193e47,
+    0xda8e68cd2fe39, 0xda9a9ab6137a2, 0xdab62eb13b10f, 0xdad4a9a2fbdb3,
+    0xdae0800c666df, 0xdae813ce058b9, 0xdaea6fb0f10b2, 0xdb0fe4ace1cde,
+    0xdb13e1cad6691, 0xdb1869fc78b82, 0xdb4033897441d, 0xdb68e000a626e,
+    0xdb732b24a9c7e, 0xdb783ac1096e8, 0xdb7eb1096c6bc, 0xdb99278b57e23,
+    0xdba3a99570ebe, 0xdbbcbd1449680, 0xdbd6ea636da83, 0xdbf70de21846b,
+    0xdc08de23f9941, 0xdc2d828265c90, 0xdc38e024d4757, 0xdc3b9b3029552,
+    0xdc7dc5496e2b6, 0xdc86c708f1584, 0xdc9d7752fd9c3, 0xdc9e9fc70ad18,
+    0xdcacf303d422a, 0xdcb42b45fa3c7, 0xdcbd30ae6dc5a, 0xdcc10cdbef220,
+    0xdd0f7b2ac95c6, 0xdd783b622b721, 0xdd961c9c7d173, 0xdda74f7dfee32,
+    0xddc9adef21c26, 0xddcfa41ea820b, 0xdde6dbd371bc3, 0xde0e96bcabd4a,
+    0xde12f6abfa2c4, 0xde21cdaa9078a, 0xde2a9883fe3cd, 0xde4efeaa74507,
+    0xde84229460a60, 0xde87c089ebe11, 0xde8cffba8037c, 0xde967de9efa74,
+    0xde976bcef7179, 0xdea02bad93c6d, 0xdef5bc48f1f12, 0xdf166adb4a438,
+    0xdf247fe01d735, 0xdfa4b25b4ee98, 0xdfab5f89c74f7, 0xdfbefcf1f60c3,
+    0xdfc635341c260, 0xdffb0c4250e90, 0xe039bcff6e1f5, 0xe0660b151b9bc,
+    0xe075cc4fc8fc2, 0xe08390cff43d1, 0xe0868dbc2f9a6, 0xe089ded60342e,
+    0xe09dd414baa72, 0xe0a985e4c19ec, 0xe0b1ccfc6267b, 0xe0fe45e29b3b4,
+    0xe117520f92fec, 0xe13e1b6ad54a9, 0xe17593e5cc671, 0xe180e68d69fe9,
+    0xe1ac30c86de0d, 0xe1c7d36756e8e, 0xe1ce1dc475926, 0xe1cebb24d513d,
+    0xe1d4388d5f8bd, 0xe1e5298dfada2, 0xe1e6769b6baa9, 0xe232bfed6fce1,
+    0xe25938c40a2b0, 0xe25da75719f3e, 0xe285ffa0b38dc, 0xe28720c2e00a7,
+    0xe28b511df9b20, 0xe2b37d7c4ef82, 0xe301d97e7764f, 0xe305cf4a8b478,
+    0xe317feb4d5f50, 0xe337a96c84ad3, 0xe36facfb297d9, 0xe37f12b65dda2,
+    0xe39d9ff4d071f, 0xe3dd0407ef539, 0xe3f6730640d38, 0xe41d057b6db6a,
+    0xe42fdd40e8fa8, 0xe461792aec3ee, 0xe477f9e0c3d2c, 0xe4b40cda0f0be,
+    0xe4d3c28c8ed90, 0xe4f28e02f7922, 0xe509668f57cd8, 0xe50d291e46a3b,
+    0xe513269fadbaa, 0xe53c239b878e9, 0xe540ccbd9d1c7, 0xe553abd4f918f,
+    0xe55aa23638b52, 0xe55ddd5a6a33c, 0xe5865de657c51, 0xe5999fcf0d7e0,
+    0xe5fdf1735fcad, 0xe60bb5f38b0bc, 0xe62667047c473, 0xe6270464dbc8a,
+    0xe6acacf6b6fe3, 0xe6c9a3f4f18f5, 0xe6f87286ee267, 0xe6ff3cfce96ee,
+    0xe701ed0d6d39a, 0xe728f0f7b54a7, 0xe7819f6fc210e, 0xe7a0a1cc4032b,
+    0xe7b9cee9ab350, 0xe7e0ddcec45ac, 0xe875d9cf221f5, 0xe8ab88ccbc28c,
+    0xe8cf4a40f2025, 0xe8e994d799250, 0xe901b31f89783, 0xe90c43cd63f32,
+    0xe91d52158223f, 0xe9533ba221f26, 0xe972a0cff9d0a, 0xe97d0ce470b07,
+    0xe9c3341baa21e, 0xea029f80a9bc2, 0xea039c09729db, 0xea0639cd449ae,
+    0xea27ae0250662, 0xea3931687a20f, 0xea42d7da3d87e, 0xea6845844d920,
+    0xea73f00273d10, 0xea7c630558edb, 0xea7f67437503a, 0xea98318f86498,
+    0xeac94d60aceef, 0xeadec2e9fa300, 0xeaf5c3b8ae62d, 0xeb3653e8cad61,
+    0xeb506b424cec6, 0xeba75b37cdb4b, 0xebbe87f1c63b4, 0xebc1a9776533b,
+    0xebcea4abec9f7, 0xec0819e165db6, 0xec2594a16d67c, 0xec25e8cf05b2f,
+    0xec3bfbb8b2757, 0xec59e09bf476e, 0xec8ec2a4fa4ed, 0xeca8e4f94d7a1,
+    0xecef9aed24fbb, 0xecf818eadb2d5, 0xed06ec40811d6, 0xed10079e96d07,
+    0xed276043d3aac, 0xed2bd27fd3cff, 0xed8c4f4885790, 0xed9dae154b98b,
+    0xedb3e5985bf65, 0xedd1d5766f0cb, 0xedd935fae91df, 0xeddd0e7f7a1e0,
+    0xede8fade86daa, 0xee1719c37222c, 0xee25fbbcd9841, 0xeec52942a8637,
+    0xeec935045e6fe, 0xef07c128180b1, 0xef2897fcc454e, 0xef3f61e5631f0,
+    0xef744ee93a0be, 0xef85682c2951a, 0xef95fa045b3fd, 0xefe9e964b04e8,
+    0xefec95cc43bcf, 0xf0005b76c6712, 0xf04c12633c282, 0xf05125a88c2b1,
+    0xf089eb30f4180, 0xf0e8b0c8fa7b9, 0xf100f7533ec63, 0xf1068e5a5bc46,
+    0xf118deb519b0b, 0xf123d2344de81, 0xf12be60ec9a4a, 0xf130f20238eef,
+    0xf13c0dc3c11dc, 0xf164242c743a0, 0xf17268c57c19e, 0xf17a50b4b382b,
+    0xf17deb014e617, 0xf187e1f7b9b74, 0xf189db094b7a6, 0xf18be6678f0b1,
+    0xf192d1cdfd925, 0xf1a755c95306c, 0xf1cefe65db51a, 0xf1d9cd4babede,
+    0xf1db8f7728485, 0xf1e5fb8b9f282, 0xf1f490a94ef6e, 0xf244b27fe41a7,
+    0xf24850756f558, 0xf24cdff8f25d3, 0xf2641eff9cb15, 0xf2a569d79b888,
+    0xf2b64139a450a, 0xf301e98258966, 0xf30c93cec5978, 0xf324aac4d5321,
+    0xf32e881cae01b, 0xf3732f0951965, 0xf3eec7062eefe, 0xf408ed0372777,
+    0xf439975f7def3, 0xf43ce12770df1, 0xf442698acc6c0, 0xf46e9307164d5,
+    0xf4a963edc1caf, 0xf4b01fbffba22, 0xf4bf5d38dc074, 0xf4d1cadb1cd61,
+    0xf4d67ef80378e, 0xf4d9e607794b4, 0xf4e932241b21a, 0xf4fe18f0ca528,
+    0xf52f34c1f0f7f, 0xf54113a793b69, 0xf54e3ac75f761, 0xf5713698e1d6c,
+    0xf5b1d56cbfbb4, 0xf5b3645b17095, 0xf605c124246da, 0xf60f13684f896,
+    0xf61072c272276, 0xf619ba0bcc2e3, 0xf62b411ae6455, 0xf6346773cd0d5,
+    0xf6387a8763d26, 0xf63d708530f2d, 0xf67d28c5e81fa, 0xf688de3edf739,
+    0xf6b97da019d66, 0xf6c4c54ce658f, 0xf6c6334aca683, 0xf70321dc6b441,
+    0xf768f3cb536db, 0xf776a5fecce11, 0xf7aff2f1f2259, 0xf7bc2883c6187,
+    0xf800ae7ff66e4, 0xf843b43190e74, 0xf87ed1f3f3f77, 0xf89e7902b2535,
+    0xf91d23e16d341, 0xf9429c864e532, 0xf9453a4a20505, 0xf95394d8ca5a1,
+    0xf9a54946a7280, 0xf9b1c80b42512, 0xf9bc2ccdd8785, 0xf9c347c87bafa,
+    0xf9c452f506027, 0xf9d9c87e53438, 0xf9e1882b36b4e, 0xf9f1fcbbe5c09,
+    0xfa1520cfbc18b, 0xfa305cf45b080, 0xfa3ce6b3c7461, 0xfa7483c821fdb,
+    0xfa82d35bfaf28, 0xfa8b6af843aa5, 0xfa990e87fbac7, 0xfaa5098ac9da5,
+    0xfab8f025bfcd5, 0xfac100574b2d9, 0xfac3b067cef85, 0xfac95269bd0b7,
+    0xfb08415ed0631, 0xfb09453979fd4, 0xfb0c03edbf394, 0xfb27734f8334f,
+    0xfb45a8b76d254, 0xfb4d4ec5be107, 0xfb50b22c43868, 0xfb87ae374e606,
+    0xfb892e81e43d3, 0xfba830de625f0, 0xfbc109ce35162, 0xfbcc9e56b92b4,
+    0xfbe060584b832, 0xfbf172495a104, 0xfc07902dd7e7b, 0xfc18b0c2a7e61,
+    0xfc5eccff10429, 0xfc9d25e5a4d16, 0xfc9d641d9af2b, 0xfcc2eb663d830,
+    0xfcc77397dfd21, 0xfd1ebb63e941e, 0xfd5e231ff87fd, 0xfd7c33ee7ed50,
+    0xfda4fa0443404, 0xfdafe288a662b, 0xfdb1458bb95d0, 0xfdbdf78d79928,
+    0xfdbf9176a1f58, 0xfdc3d0757d0e5, 0xfe37dc029c02e, 0xfe4648ddf7da3,
+    0xfe4df9e719da5, 0xfe543949676ee, 0xfe7f872d5bad7, 0xfe86d90e144d7,
+    0xfe8a944b226b0, 0xfeb84cb5c39a6, 0xfebd6e9ed50e9, 0xfec4aa89eb84b,
+    0xfeeada2dbeab6, 0xfefb10867795c, 0xff1d81444c429, 0xffac3a396c164,
+    0xfff64c41e8555, 0x00238493ace5c, 0x00506165f8726, 0x005d9e7b665bc,
+    0x011780bb16d2e, 0x0143a68e7057e, 0x01c96515edb75, 0x01fcfdba731b2,
+    0x022c959813877, 0x026615c85dd85, 0x02673e3c6b0da, 0x028740caa26d5,
+    0x028c4cbe11b7a, 0x02a56b37bb48b, 0x02b7a945c7677, 0x02e783044e516,
+    0x031637f7b8625, 0x03b64115dce47, 0x043c32da7f504, 0x045b8d0d86199,
+    0x047c887b95fe8, 0x04cba6778187e, 0x04e109b41cfb6, 0x04e75b631c5d8,
+    0x053d3c832276b, 0x05b93afa49e90, 0x05fea3e0b09a3, 0x063a5408a216e,
+    0x0652bb8359a05, 0x0691fafd14e6d, 0x069fd91bd2adf, 0x06cd7f39c20fc,
+    0x06ee7355f13c1, 0x0701317cd9f9c, 0x0724dcfb6da97, 0x072c65c23bb22,
+    0x07385221486ec, 0x075c25e23015e, 0x07710caedf46c, 0x07aea744a1155,
+    0x07db806dfc45a, 0x0804f287e1a39, 0x083d58e7e0306, 0x0845064811d43,
+    0x084edff6fa478, 0x085b53c0c45bb, 0x085c3a53eb136, 0x08a75788f1a54,
+    0x08dae189b597d, 0x08dcf439d9e12, 0x09187c1f77666, 0x0947978d2b901,
+    0x09b15b9f373ff, 0x09c8e3d8a8ca5, 0x09fbea179fc1a, 0x0a1e7bc5e7ad4,
+    0x0a3c903d5e5ec, 0x0a3d1ef9fc6ef, 0x0a54829a0a5e3, 0x0af6dca0494af,
+    0x0b046de34f7f8, 0x0b14decb0e2ee, 0x0b39d00531f66, 0x0baaf0f2c75b3,
+    0x0bdda30426075, 0x0c397dfca2db2, 0x0c3dbcfb7df3f, 0x0c4d2a0893092,
+    0x0c910efb73813, 0x0cbb3bbd3b431, 0x0ce7d305aff5c, 0x0cf4fdce6c119,
+    0x0d2719d14bf4e, 0x0da62b2a50ee6, 0x0dbe3e77702ca, 0x0dc56bbec5318,
+    0x0dcf198269511, 0x0de6c9fe2ed2e, 0x0e5f357a9c1f1, 0x0e6b6b0c7011f,
+    0x0e91726def413, 0x0eaf3660be03d, 0x0eb7c30235a6b, 0x0ecb815ad7a24,
+    0x0f4627c9bd2f3, 0x0f56691d472e8, 0x0f6d713ddc19f, 0x0fe354ec1992d,
+    0x0ff9a264cc1a5, 0x10042817d5805, 0x1020e4870a4c7, 0x10481bae7769a,
+    0x107191714d23e, 0x10c05b3fa0621, 0x11288cba64679, 0x112a822305ce6,
+    0x113e616c1b08c, 0x113fd6bbdfd0a, 0x11517ebb6d269, 0x115beacfe4066,
+    0x115e8141d54af, 0x11ddea7162ebf, 0x11e9fb69d343b, 0x122bc65aaeb9d,
+    0x123b80437b619, 0x1243e84b8f695, 0x12bb91ce3998f, 0x12c9982f4b578,
+    0x12e66e3d12a9d, 0x12ee771cbd517, 0x1301528b28f1a, 0x13213428ed128,
+    0x13218fa866165, 0x1327180bc1a34, 0x1327cb61c34e9, 0x13348c0744f55,
+    0x133c829a3dcf6, 0x134ad97ff77cd, 0x1381d1e211fa6, 0x138538f187ccc,
+    0x13a66b45ad1a6, 0x1404f9f79e154, 0x140d2b199cb45, 0x1416f9cdb412b,
+    0x14184e2d059bc, 0x141abc5ca2e8e, 0x142027787b935, 0x148c685de6019,
+    0x149e941f4052c, 0x14b54bbb2d4f5, 0x14c1889ee1fad, 0x14dce20b03cca,
+    0x14e0edccb9d91, 0x151c0f380d459, 0x1520224ba40aa, 0x15732ec1c2bdf,
+    0x15be36012725f, 0x15fdbeada9a2b, 0x1607b5a414f88, 0x161a9fb64209f,
+    0x161ef4aabf4ca, 0x1636dc0c9a372, 0x164624804bb13, 0x1657e9c75be9a,
+    0x1669c15b1defa, 0x1679da6c53f78, 0x168bc7f5b8276, 0x16a0de569c085,
+    0x16b82fa9f82a0, 0x16ca6db80448c, 0x16dfe3415189d, 0x16f77dc774e1c,
+    0x170d38da98fcc, 0x171719db6228b, 0x1717df7e15a19, 0x17335631ba55e,
+    0x173dcd41024aa, 0x1759613c29e17, 0x1768fddd73a6b, 0x1769904302133,
+    0x176b6c0d10f3d, 0x1790cebc4fe90, 0x17ffa9bc9bf82, 0x17ffeb9d8275c,
+    0x1818113753819, 0x183e3d32364bf, 0x1847acbde44a3, 0x1864bd5ab1618,
+    0x188c99345eb8c, 0x18913009c2791, 0x18b0a0326b6c4, 0x18b519c04c4a1,
+    0x19056b2b161db, 0x1905c6aa8f218, 0x192469dea3e33, 0x1928a18b9e436,
+    0x192d0c75bdaff, 0x193b80a2fa3fe, 0x19556fba285ec, 0x19897281e837a,
+    0x198e1f4cee21d, 0x19c35cd56cfd9, 0x19c894b4209ba, 0x1a014446e65eb,
+    0x1a6603b7637ce, 0x1a78874f46759, 0x1a819f046bcc5, 0x1a84b58f39afd,
+    0x1a89e9c4fcf19, 0x1ac545bf56231, 0x1ace01f502760, 0x1ad014a526bf5,
+    0x1b1793e4a2162, 0x1b2bef9da3932, 0x1b37f59b42d5f, 0x1b386ab94e5ff,
+    0x1b38b3ec15963, 0x1b70becc9b1f3, 0x1b7bde3713aa5, 0x1b8872f150fd5,
+    0x1b989e4f38d2c, 0x1bbbb0165d5d5, 0x1bbf8c43deb9b, 0x1bfe3d00fbf00,
+    0x1c35cb7195366, 0x1c46e4b4847c2, 0x1cbeaf27a1ea9, 0x1cecc311bc1dc,
+    0x1d2e7bb5e5c65, 0x1d3929ab4323c, 0x1d3f0d8e17b48, 0x1d5cbf3434a99,
+    0x1d93511c050e6, 0x1d9ebd62352c1, 0x1dab81b0a72f2, 0x1db62102431b5,
+    0x1dee83b9514bd, 0x1dfcbd578816c, 0x1e1f2a6c6c674, 0x1e7fecbef4ea4,
+    0x1e907e9726d87, 0x1eceaf3b676fd, 0x1ef3db0490fc5, 0x1f1facaa52362,
+    0x1f7d8baf31e20, 0x1f94c70cebd9d, 0x1fad35d9841be, 0x1fc1a78827c2c,
+    0x1fdda94f7a2af, 0x1ff652ab18320, 0x20a05dba791ee, 0x20a127061cf41,
+    0x20a8bac7bc11b, 0x20b5d6ecb6bc4, 0x2108216912530, 0x2109d4f0cd3c3,
+    0x2126916002085, 0x217465f76d1d9, 0x217678a79166e, 0x217d4a6f6d67f,
+    0x219101762eaae, 0x21972739e9b94, 0x21a9a728dc55a, 0x21afd43e781ca,
+    0x21b4a94bd1fe4, 0x21b7ce7a61530, 0x21d851217551a, 0x2246afb1d51e2,
+    0x2248beb9090b2, 0x22864702190c2, 0x228da786931d6, 0x22b7751ff17f2,
+    0x22f9d9c83c1a6, 0x231d470ed9a8c, 0x23218264c4654, 0x2329fcb98a3a9,
+    0x236d4f46dc462, 0x236fb9cd8936f, 0x238fb509dfde0, 0x23b4e824ea232,
+    0x240df5c56049b, 0x24500d91f3526, 0x2487fb2af5f8e, 0x24a9bfe4a9b30,
+    0x24b462df35fb8, 0x24cfbff44829a, 0x24ec875e4e0ab, 0x24f8dde0953c6,
+    0x2549369d3fc8a, 0x254b9d7afc5d2, 0x255b2b7884b12, 0x25b385c2f92c6,
+    0x25b694fbe6574, 0x260ba57e67e2a, 0x261aedf2195cb, 0x264cd6b7d433a,
+    0x264e44b5b842e, 0x2675deae7f1c8, 0x269daf8d5b5ed, 0x26a3b809938ab,
+    0x26ade98f04a58, 0x26bb26a4728ee, 0x26ffb04993410, 0x27115f9b014f9,
+    0x2780f5432fc2a, 0x2799e07fb4475, 0x279ad5b69c704, 0x27e67dff50b60,
+    0x27fb8d0e53de5, 0x2816491a162eb, 0x2817c5bbbbaf3, 0x286688382e34c,
+    0x291329b980663, 0x297ba0be1b45a, 0x2984d211d3229, 0x29a4beaa68586,
+    0x29ad738e33f2b, 0x29f947c22c8c3, 0x2a04d8a1c0450, 0x2a28214efa384,
+    0x2a5b44d574121, 0x2a5eea1ce005c, 0x2a6f993c94d67, 0x2a9bbf0fee5b7,
+    0x2adbb9318c05e, 0x2b163241aedc0, 0x2b1d339dbf8d2, 0x2b2f5f5f19de5,
+    0x2b690428c7ca5, 0x2bf7828ee1d90, 0x2c13c28e2a628, 0x2c40b55618190,
+    0x2c69064dd0fa4, 0x2c87b825a72d3, 0x2ca6fc630bcca, 0x2d1f46ef05da0,
+    0x2d56813206d53, 0x2d5e82bfd0c43, 0x2da31eb1a343e, 0x2db63fa9e5be0,
+    0x2db6559f87e7e, 0x2dbce1dd8d0f0, 0x2dde3c7406541, 0x2df2bcc66b6c3,
+    0x2e0918e2df64f, 0x2e1e6d7bb9673, 0x2e60e819a62c5, 0x2ea0f487f5a45,
+    0x2ec41c44bc58c, 0x2ef40bf8e56c9, 0x2efa47b242a4d, 0x2f1e6fa0c2972,
+    0x2f3e4643b5a31, 0x2f64d50ff229e, 0x2fcfc53efb6b6, 0x300ffd988f372,
+    0x302eb31955c66, 0x306a07c1ce3f4, 0x308159152a60f, 0x30b979eb5213d,
+    0x30bd9ba2aa4a2, 0x30d16ff0ee6f9, 0x30f75db3db18a, 0x3101759ab9ad4,
+    0x311c096428063, 0x313703a7e077e, 0x3158917b7ec95, 0x319cca9bf78c9,
+    0x3215bd8322305, 0x324d693b3e593, 0x329417dd35223, 0x329ea88b0f9d2,
+    0x335f30a757c19, 0x338e74575fe2b, 0x33dc1208b58f4, 0x33e968bcb5fed,
+    0x3455eb8306eab, 0x345d4c0780fbf, 0x34615b722764b, 0x347821b1d5d28,
+    0x3478d507d77dd, 0x348736e862403, 0x349c4d4946212, 0x349d6370a188e,
+    0x34d616ac57a84, 0x34f72467195ac, 0x34fce3b08a506, 0x3555c565bc233,
+    0x355e6ba5c64c4, 0x359fffb08c59b, 0x35a2c95fa2aaa, 0x35ab59aa0aa9d,
+    0x35b0cc17c40ce, 0x35d0f33f5f07b, 0x35dce6f04c7cf, 0x35de2902ec387,
+    0x35f701f2beef9, 0x35f9f03b38dba, 0x362427f7d1b27, 0x362d176aa311c,
+    0x362fb52e750ef, 0x36b1903690596, 0x36beb3ad6bbc9, 0x36f174628bd9f,
+    0x37380965f01cc, 0x373d44ed94172, 0x37534cdc6fc4b, 0x3779f19e4e756,
+    0x37cbbc01cd6d3, 0x37f740cbd7147, 0x38060ccf9c4be, 0x380af080b79ec,
+    0x380d1ccf6e6e4, 0x3817017927f68, 0x38259a3fc8219, 0x38ce8b7edd4a6,
+    0x39085138fe753, 0x3909307a44744, 0x39109fa27ff6c, 0x392706b9c5047,
+    0x3935c070d86e5, 0x3936cf46531d7, 0x3938cfa9c5993, 0x395e56f268298,
+    0x3987f4f791db3, 0x39b0f1f36baf2, 0x39b1b0443e6f6, 0x39dc38857f351,
+    0x39ddd26ea7981, 0x3a27227d60ba9, 0x3a63775792715, 0x3a70554496fa9,
+    0x3a849b07f64db, 0x3ae0e023ad969, 0x3b2deb6f74d6a, 0x3b40297d80f56,
+    0x3b449467a061f, 0x3b81dacfc9e55, 0x3b9544fad395b, 0x3b9dd5453b94e,
+    0x3bbf2137f368b, 0x3bd71ae68020c, 0x3be0622fda279, 0x3bf3a06f9f843,
+    0x3c6cb7f02dc31, 0x3d100384647c7, 0x3d8e7b25fa50d, 0x3d90ecfe87fa4,
+    0x3db73297fd4ad, 0x3dd843fbaf59a, 0x3df0b2c8479bb, 0x3dfeaa8597e90,
+    0x3e0b6b2b198fc, 0x3e1732f0c2b14, 0x3e46bc2aa1ac5, 0x3e74f1052f1e5,
+    0x3e91310477a7d, 0x3eced2ec1a2f0, 0x3ed607854fec8, 0x3f06b9333c1ce,
+    0x3f2f117cd5b6c, 0x3f3f6c6ef23c4, 0x3f54533ba16d2, 0x3f5f72a619f84,
+    0x3f64181f3f29d, 0x3f89b1b49387b, 0x3f99b12737096, 0x3fa15e8768ad3,
+    0x3fb152ff3b19f, 0x3fc0d9aae2b55, 0x3fcd7d08e1799, 0x3fe142b3642dc,
+    0x3fe5730e7dd55, 0x438635f1bf929, 0x44f751b27d6eb, 0x4554a1fabf0a6,
+    0x460b924905392, 0x46c61ce3e646a, 0x46de3ed4c6f62, 0x47d6f2c207e26,
+    0x48129b9818a67, 0x4b49cda673c03, 0x4bbf22981328e, 0x4be2d1bf9734e,
+    0x4cab66647a5d4, 0x4ccc5a80a9899, 0x4ce1c50f25b5b, 0x4eb431df0a250,
+    0x4eee94f98ad14, 0x4ef1191eca484, 0x4f1435e0bfe7c, 0x4f2f6e5c6e7ac,
+    0x4f56eb0db271e, 0x4f76921c70cdc, 0x4f9e66a43d6c6, 0x5218d6ce2f943,
+    0x52382d5846013, 0x52546605add21, 0x52770a00a78b4, 0x52ecf8a9b6191,
+    0x53bff5ba1fc4f, 0x54b5e3a13abc9, 0x54d87ca16360d, 0x5712f2a9b7de3,
+    0x57370f9d66bb9, 0x573f02876f395, 0x5761aa2b594ed, 0x583f2ceecc60b,
+    0x58474f6d098e8, 0x590582f846ec0, 0x5961da60b0027, 0x5993221d1afba,
+    0x59b2a83b6618b, 0x59cd310a035cb, 0x59ed3aea1b750, 0x59fe960df1386,
+    0x5a17b4879ac97, 0x5a44a74f887ff, 0x5a50f67feef90, 0x5a527a7375322,
+    0x5a966e0a171b7, 0x5aa8f1a1fa142, 0x5abd5fa7ad5eb, 0x5b5893b877ff3,
+    0x5bf160eb860b3, 0x5c08eccde7f1e, 0x5c47495d6cdd0, 0x5c94a52ddc0bf,
+    0x5cb7a4a84ec8f, 0x5d09e080e8ee7, 0x5d2d84ad9be58, 0x5d77bb4f7bbfb,
+    0x5db7d2b89c4ca, 0x5e872a819a04d, 0x5ec102886cfd3, 0x5ededc70dde9b,
+    0x5f00c5c3f53ef, 0x5f24df0eb3c00, 0x5f37923acb68c, 0x5f3e08832e660,
+    0x5f4ebb4bd3930, 0x5f603eb1fd4dd, 0x5f6d3294a400f, 0x5fa7661aeffd2,
+    0x5faed5432b7fa, 0x5fb11035a3c06, 0x6004c506f30a1, 0x60201aca247f9,
+    0x60763679305dc, 0x611003ddf7a7a, 0x6120995f19f22, 0x6171848152eae,
+    0x61a040c69db47, 0x61f61a94c3150, 0x620e85b86afac, 0x621386b109302,
+    0x6269e440fb8bf, 0x62e3fbf34308b, 0x6303ceed45b85, 0x630bd4240003a,
+    0x634023c7776f1, 0x635e2d441d0ba, 0x6370bf7fc1759, 0x63c309fc1d0c5,
+    0x640621fa6952e, 0x6413a8429e728, 0x64538c6e99f31, 0x64c2acf8bcdc2,
+    0x64ff400ae4b43, 0x6527f77ce7ae3, 0x6528f405b08fc, 0x65d4fbcf939c1,
+    0x65ee04539b034, 0x65f9868f6d4ad, 0x6631e9467b7b5, 0x667e8e17f8a2a,
+    0x66ec94d1cfc7a, 0x66eecc1b57ac1, 0x670011498b459, 0x6751c960586fd,
+    0x678f380ad5eaa, 0x67a5f6f8a39fd, 0x685c7d23af598, 0x687f002e35d3e,
+    0x68e7146176f6e, 0x693031330b0d0, 0x6938bdd482afe, 0x693e37941ccb9,
+    0x6965a1f8aef52, 0x69c7fe345fdb2, 0x69eb224836334, 0x6a91462f44aed,
+    0x6a987e716ac8a, 0x6ab649b61a43e, 0x6ab90c134fdc3, 0x6abe7ad818e2f,
+    0x6abf10e697abc, 0x6ae83624c5772, 0x6b2d9f0b2c285, 0x6b33b28235692,
+    0x6b55982c5c621, 0x6b9056c656122, 0x6ba4a032a5c19, 0x6bc25581b312f,
+    0x6bcbdb03033b1, 0x6c0adfedb8bc9, 0x6c0bbb860e5f5, 0x6c63b6a8197a7,
+    0x6c785b93e22db, 0x6cbb90d9b156c, 0x6cbffbc3d0c35, 0x6d3e1b8eddf03,
+    0x6d4f3128dcd9a, 0x6de10f4c8c021, 0x6df5466c29e3f, 0x6e66b7de6737a,
+    0x6e77b3d9d39ae, 0x6e9361738db7e, 0x6e9b002ffdea7, 0x6eb19332874be,
+    0x6f0ca88850a6d, 0x6f31df4c4b484, 0x6f688380cd7aa, 0x6f89d6c566071,
+    0x6f9fdb0b51585, 0x6fb3deedca2dd, 0x6fdc50d5f64de, 0x6fe39f0dbe919,
+    0x6ff6c006010bb, 0x704e28c27dba5, 0x7050c6864fb78, 0x706f2b826e57e,
+    0x70a7500186671, 0x70e5c9d88e34b, 0x70e7b09d6e2a4, 0x71b77d84776c7,
+    0x722b0ca1aa1e6, 0x72361d6861384, 0x72672a95c66c7, 0x72a40a83a5d71,
+    0x72b9960295420, 0x72cd3e659513b, 0x730fe4eec62c9, 0x7356f2b92655b,
+    0x73676af2c5bdb, 0x7382185ac69cd, 0x7392b52dc99ff, 0x73c9ebc7da3ed,
+    0x73d106c27d762, 0x73e8d485c5da7, 0x741f123ffdf41, 0x7475b1b0d6cd8,
+    0x7490c59321c56, 0x7497174221278, 0x74d7a3c94d3e7, 0x74e868dea4390,
+    0x75015b6d09765, 0x75035f796c4e6, 0x7560c96040704, 0x756ecc1861d28,
+    0x758432fdeda25, 0x75fecac911be0, 0x76065738d0230, 0x7629655704514,
+    0x766026d309662, 0x769f0acd4ba8d, 0x76b11cf01373d, 0x7710c1c95fd67,
+    0x77933688ea460, 0x77b4aabdf6114, 0x784f2426de4dd, 0x7897fb6e9b8a0,
+    0x7898ff4945243, 0x78bb52bf96ee8, 0x78cc351c70cb9, 0x78f2facec2bb1,
+    0x793a3f7f384ce, 0x794e34bdefb12, 0x794fb8b175ea4, 0x79ea396c3edf7,
+    0x7a38e5f30f3b2, 0x7a67137bbbf48, 0x7aab9977ec4a5, 0x7aade30e25fc5,
+    0x7ab6401b68ef2, 0x7ae65bbad656b, 0x7b99981def208, 0x7bbd648cf60f0,
+    0x7c25c59beec49, 0x7c38fc89d3689, 0x7c7ecbea84328, 0x7c7fa030f91ca,
+    0x7ccbf47dce551, 0x7d09d8465759e, 0x7d15cfa0352b7, 0x7d4d7f0141b0a,
+    0x7d75782271ea6, 0x7d874c0d43941, 0x7da175b37777f, 0x7e38aca64d7d4,
+    0x7e7dcc59ecf83, 0x7e7e2b8256585, 0x7e8d9fe14c262, 0x7eebcf6ad3c0e,
+    0x7f25fb9f3f047, 0x7f3573a7252e9, 0x7f45597b362a1, 0x7f45d24232106,
+    0x7fa44afe80e16, 0x7fb6d23f54366, 0x7fceb5f83ec49, 0x7fdca663ae594,
+    0x8021c6174dd43, 0x80549cc2101b7, 0x805c133c2c569, 0x805f6ba7e0b7b,
+    0x80cb7cf91675e, 0x80cbdfca70325, 0x81a2946ef79f7, 0x81c991075ef7a,
+    0x81f19c7540fef, 0x81f1e5a808353, 0x822267c1bfb58, 0x822fdbbd43079,
+    0x825d1f09d8acf, 0x82f36f6987fa9, 0x82fac14a409a9, 0x82fafbd9465f9,
+    0x831c94a7b5c5f, 0x83482f6761971, 0x83766f3cc01e0, 0x837e0a503ff44,
+    0x8383d0eb91a28, 0x839f947aede96, 0x840fa2ea1842c, 0x84134f8364ef1,
+    0x845463754e5d9, 0x84acfbf7b8fa2, 0x85c1d645afe9b, 0x860700f420799,
+    0x867f3cdc5915b, 0x86afd142c2639, 0x875e8cc61996f, 0x8773bcc58ffe1,
+    0x878188979bf7a, 0x87cab0640122b, 0x8826c9947417d, 0x885108a2eda74,
+    0x8854c788ec212, 0x8858a75f5dd9d, 0x886149f677a69, 0x8893f4b5f59a1,
+    0x88a2fb48c0968, 0x88d26e8cfd67b, 0x8906b335a3be3, 0x891f344eedcdd,
+    0x8948b163a440b, 0x896aeb3b6384d, 0x89c79a7a5542c, 0x8a4f81a799156,
+    0x8a77ae05ee5b8, 0x8a85f9f0d6f40, 0x8aae8577959a4, 0x8add7c4be628d,
+    0x8adf337c916e5, 0x8ae14d7e96704, 0x8b530bcc8b568, 0x8b766e1857cff,
+    0x8b9d0436750f6, 0x8bdb60c5f9fa8, 0x8bff7db9a8d7e, 0x8c00d5c1eabd4,
+    0x8c07f0bc8df49, 0x8c1ffcb7cc7a3, 0x8ca0a7f9f9d6b, 0x8cf04d60a2b7a,
+    0x8d033772cfc91, 0x8d20a73806408, 0x8d30a301b965e, 0x8ddaf743e1890,
+    0x8de7c2e43444b, 0x8e474e1eee212, 0x8e767487735fc, 0x8e7d3b547e4be,
+    0x8e80a9b5d4d6e, 0x8ecbe4325e4b4, 0x8ee4654ba85ae, 0x8f5770a70e119,
+    0x8f5c1d7213fbc, 0x8f753bebbd8cd, 0x8f8ccb770fcfd, 0x8fa9dc13dce72,
+    0x8fce9a10dba24, 0x8fcff96afe404, 0x8ff680e55a0e7, 0x8ff8eb6c06ff4,
+    0x8fff5a628943e, 0x90182fa96b9eb, 0x901d5c8d4e27d, 0x902d1a1f0b2be,
+    0x9057d946615a4, 0x90829fbf98414, 0x90ab991281b8e, 0x912050a3c1a02,
+    0x91217917ced57, 0x91c9ede6f7bba, 0x91d385b4f9b15, 0x924623443496a,
+    0x931df1b907c7d, 0x938efcb0fb02c, 0x93bfb207d78f7, 0x93e2c0260bbdb,
+    0x9427a54aa573a, 0x94390b694c4bf, 0x94468e08910f4, 0x949be414e9749,
+    0x94c042e97ecf9, 0x94ef1c764c7ba, 0x950a72397df12, 0x953e665d7c58c,
+    0x954c2adda799b, 0x95b1f57aaf0ab, 0x95b1f9239f670, 0x95e0c40caba1d,
+    0x95f055b324522, 0x95fddfa449ce1, 0x9639ce04316c1, 0x963defbb89a26,
+    0x965f3bae41763, 0x967f2befc7085, 0x97060f426140c, 0x9725a4046dcf1,
+    0x9736398590199, 0x973ffd3ed6630, 0x978dce2d511bf, 0x979498a34c646,
+    0x979fc6b18660c, 0x97c5c6c124d76, 0x97d0193709310, 0x97d1ba72124ca,
+    0x98072b37b634c, 0x9844c5cd78035, 0x985d4a8fb26f4, 0x987b4cba77533,
+    0x990997e36c558, 0x9918dcae2d734, 0x99318260db1e0, 0x99337b726ce12,
+    0x9949a7faac29d, 0x99ce8397f32de, 0x99eaa9f8a9313, 0x9a0168e676e66,
+    0x9a29152bef8d9, 0x9a3847a9feddc, 0x9a4fb9edce3e4, 0x9a53a86801683,
+    0x9a5f52e627a73, 0x9a948573d56e0, 0x9ab120f296fb5, 0x9ab83bed3a32a,
+    0x9aee9a97e58b1, 0x9b2f40bda4283, 0x9b351253c6eb6, 0x9b64859803bc9,
+    0x9bfc404ca6bd2, 0x9c1b290a9258c, 0x9c4fd084926bb, 0x9ca4c016a0b84,
+    0x9d147306520dd, 0x9d578009cd3f7, 0x9dc9753dd78e6, 0x9dd502747aeae,
+    0x9e48e5bf45e80, 0x9e540c7b9f2bc, 0x9e80f09fcb710, 0x9e927405f52bd,
+    0x9ea14b048b783, 0x9ea5d3362dc74, 0x9f35816235c3e, 0x9f956f6e495cc,
+    0x9f9694396635c, 0x9ff3c73a24eef, 0xa0029a8fcadf0, 0xa088ce3553c87,
+    0xa08e68e56122f, 0xa0b6de767d9f5, 0xa1056a0cdabc3, 0xa109b7af77464,
+    0xa17569d843a45, 0xa1808599cbd32, 0xa19936474a92d, 0xa1b7bc33dc720,
+    0xa1e0e8c3eaf60, 0xa2197b0f2dd69, 0xa23383c4ee7ba, 0xa246d7fa56022,
+    0xa262add664169, 0xa28e9cc3a832e, 0xa2f4bb8e47ef1, 0xa31af283fbce6,
+    0xa3b75a03a4b92, 0xa3c455382c24e, 0xa3d8f67b047bd, 0xa3dc22fb74893,
+    0xa3e26d589332b, 0xa4134af1c3b6d, 0xa452cff555d74, 0xa46f557e753ab,
+    0xa49e4ffbb6259, 0xa4a124a59d8b7, 0xa4b8e01c34223, 0xa4cfb4ffa4014,
+    0xa4e3b5392c7a7, 0xa54f934d3d2c4, 0xa5680219d56e5, 0xa57bd2bf29377,
+    0xa5a16ffd6df1a, 0xa5b87bc6f3396, 0xa5b9b2dec1dff, 0xa5f7b3eecdc74,
+    0xa64096315c186, 0xa6613d71d3b22, 0xa6df6837b1f3f, 0xa73dcea74ef76,
+    0xa77e8719bf621, 0xa795968c35062, 0xa7996b67d5a9e, 0xa7a9304b73669,
+    0xa7cef974fc748, 0xa80ca2ae7fb45, 0xa86608257e826, 0xa8675c84d00b7,
+    0xa87e8595d835b, 0xa889be9ee3470, 0xa88eeb82c5d02, 0xa8a3adb61165e,
+    0xa8cfcfe07a8e9, 0xa9340f381b0dd, 0xa9577183e7874, 0xa959f5a926fe4,
+    0xa968787a24ff7, 0xa97d03c75b2c8, 0xa9b7e6fab877b, 0xa9c94d195f500,
+    0xa9cffdf0c8124, 0xa9d82417f59c6, 0xa9d9fc391420b, 0xa9fec187f3947,
+    0xaa39000910a59, 0xaa73d0efbc233, 0xaa8ed285554d8, 0xaaa14027961c5,
+    0xaab08c4437f2b, 0xaaf6b72461c07, 0xaaf712a3dac44, 0xab18b66d1b3f9,
+    0xabcdc39f71d51, 0xac1d94f15f09c, 0xac29ce2c2358f, 0xac4dbf348de29,
+    0xace50c1d0611c, 0xadb1a188ce31a, 0xaded8fe8b5cfa, 0xae072e7b3bffa,
+    0xae18fb142cf0b, 0xae30db2427229, 0xae896ffda162d, 0xae8df0dd62f94,
+    0xaeb6db8c8affa, 0xaebd0c4b1722f, 0xaf096f3badcca, 0xaf27df329d81f,
+    0xaf37c506ae7d7, 0xaf4e71a7ca651, 0xaf7492a7dc1a8, 0xafa0ef614b083,
+    0xaff198a29d835, 0xb0be8d36cf035, 0xb0d5153e874fd, 0xb0e544455f819,
+    0xb15a78468bab7, 0xb1614db75808d, 0xb175170acb195, 0xb182c59554306,
+    0xb1d513baa0237, 0xb1fe9bca27ab4, 0xb20cad260a7ec, 0xb271828c29c6d,
+    0xb278370c82e56, 0xb2c4840777653, 0xb336f5ab6df6c, 0xb3b4f48607e4d,
+    0xb3c84c645fc7a, 0xb3cdb78038721, 0xb4091acc725c3, 0xb4181db64cfc5,
+    0xb46db1fa9b82f, 0xb4a0f2c8983f4, 0xb4b513f293f74, 0xb4b6d2751ff56,
+    0xb4f1f03783059, 0xb519d70c0171c, 0xb52fb6b88927e, 0xb5736fc0254c3,
+    0xb5d5692a7c75c, 0xb5f9c0ad31182, 0xb62198ddee131, 0xb685fccef22d7,
+    0xb6b9ab6919bb2, 0xb6bd3abae384f, 0xb6cd193d13c7d, 0xb6d9adf7511ad,
+    0xb72e336624f25, 0xb770c3f9b3e15, 0xb781fe2d1665e, 0xb7a3eb291e177,
+    0xb7e76d4aa4d31, 0xb867c45f39e46, 0xb895cd4e8302a, 0xb8b66d3d19e3c,
+    0xb8efd025e1522, 0xb919633039eee, 0xb91ab03daabf5, 0xb93db3610dd8a,
+    0xb94f74ff2db4c, 0xb96d1baa7994e, 0xb9aa5ac0c25fa, 0xba4b43202ce0d,
+    0xba4f59dcb4023, 0xba60e0ebce195, 0xbac173aa21ec4, 0xbb0cfb0262f33,
+    0xbb3bbaf09e191, 0xbb8f2de106e52, 0xbb970e7e5d955, 0xbbae28eba44e5,
+    0xbbaf1e228c774, 0xbbb12d29c0644, 0xbbdb43f5e5fc4, 0xbbe68ba2b27ed,
+    0xbc05a04be26e3, 0xbc2f8783d3562, 0xbc3f660603990, 0xbc4afe37780a7,
+    0xbc682fc4b8609, 0xbc84f72ebe41a, 0xbcc7e341c6347, 0xbcec804e51b0c,
+    0xbcf3e824ac7aa, 0xbd046f020d53e, 0xbd0d3d846b746, 0xbd6759fa5c4a1,
+    0xbd776f62a1f5a, 0xbd9478ad8e545, 0xbd98f98d4feac, 0xbdcf12ae24694,
+    0xbdd2eb32b5695, 0xbde500fe6d90a, 0xbe2523625f328, 0xbe56308fc466b,
+    0xbe8ef26f3bf75, 0xbeabb287611fc, 0xbedf77172ad75, 0xbef356604011b,
+    0xbf0d5b6d105a7, 0xbfe28c1e118e7, 0xbffef0b6bdb31, 0xc001f4f4d9c90,
+    0xc007f61f313c4, 0xc0902dd11cfdc, 0xc0bd06fa782e1, 0xc143ea4d12668,
+    0xc158eab8541d9, 0xc1973c4d07f3c, 0xc1c719b47f3a0, 0xc1dbdbe7cacfc,
+    0xc1eae9cc7684d, 0xc227549c4a657, 0xc2a4150d34f45, 0xc2bfb4032da01,
+    0xc2d734eabe71d, 0xc3470179024d9, 0xc37f7a25b2a7f, 0xc411e35d0f844,
+    0xc438b7b322e50, 0xc4410273b40a4, 0xc5011cc3d15d5, 0xc52715818f1b5,
+    0xc58fe805a2fe9, 0xc5a9f80d445c4, 0xc6136b9aa81d4, 0xc65f730bc5c32,
+    0xc6f78ce8d223d, 0xc7108e1af8d26, 0xc732d2ed892b7, 0xc751b459940e7,
+    0xc7648fc7ffaea, 0xc778a9a01aae0, 0xc830c60b5e6e6, 0xc846c2ff69070,
+    0xc89ae59ce3221, 0xc8f3b15c72cb0, 0xc920f10018141, 0xc946c5247236f,
+    0xc9607603aa348, 0xc96e20e542ef4, 0xc96f57fd1195d, 0xc995580cb00c7,
+    0xc99c4ac4ff4c5, 0xc9b3568e84941, 0xc9f174e6135de, 0xc9fe2e39b44c0,
+    0xca1105ff2f8fe, 0xca2008e90a300, 0xca46054fb84a5, 0xca47142532f97,
+    0xca5f8a43abf42, 0xcaa46f6845aa1, 0xcaa81c0192566, 0xcaaf5f3e89852,
+    0xcab79b5b59392, 0xcafbc98100e77, 0xcb035d42a0051, 0xcb1cb64b4f5b2,
+    0xcb2ec11c366d8, 0xcb3f236033aba, 0xcb4f0cdd35037, 0xcb566d61af14b,
+    0xcba436fe49150, 0xcba59a015c0f5, 0xcbb04f489a256, 0xcc0162ad27159,
+    0xcc260362a2ee3, 0xcc4042fe78fbf, 0xcc8d05177905c, 0xccc05f8408484,
+    0xccd39671ecec4, 0xcd4baa17d190f, 0xcd7f3f1366987, 0xcd955cf7e46fe,
+    0xcd9ed02c82ca7, 0xcdddd16e47efa, 0xce11df30d8dd7, 0xce23ba6d8b3fc,
+    0xce8dfaef83324, 0xcea27b41e84a6, 0xceab79587b1af, 0xcefcb8a84c5ee,
+    0xcf1d5fe8c3f8a, 0xcf276582f0bfb, 0xcf54c9bff9a3e, 0xcf76628e690a4,
+    0xcf9079e7eb209, 0xcff19f0bcd600, 0xcff865d8d84c2, 0xd02f02bb79c5e,
+    0xd03ffeb6e6292, 0xd065566b54096, 0xd0988fe7700d1, 0xd0e02c6e6e466,
+    0xd0eeffc414367, 0xd115ccc846de9, 0xd124449e73cad, 0xd1b67e419bf71,
+    0xd1ddae17285ba, 0xd1f189b74d39b, 0xd21ff577f0146, 0xd22931c679064,
+    0xd229da21a99ca, 0xd2375917fe03a, 0xd2573363e16be, 0xd257b725ae672,
+    0xd2894f66c14f3, 0xd28e62ac11522, 0xd293b7d247d2b, 0xd29d6595ebf24,
+    0xd2acd64bf163c, 0xd2be22cc05b5e, 0xd2d7ed49d039a, 0xd2e31b580a360,
+    0xd30ba6dec8dc4, 0xd30e917e526c0, 0xd333bd477bf88, 0xd353dd1d363ab,
+    0xd3b2d99b5206f, 0xd3d9e4d77ad06, 0xd40febab9d815, 0xd4373c719d24b,
+    0xd4a1c6265c4d7, 0xd4b3e6ece589b, 0xd4b8e43c9362c, 0xd4c039c63c5f1,
+    0xd517bfca3bf03, 0xd54f8177fa42f, 0xd58de1b06f8a6, 0xd614c8abfa1f2,
+    0xd668e7a083dde, 0xd679a1bb09c38, 0xd6b86c16b9800, 0xd6b990e1d6590,
+    0xd706a37f7e51b, 0xd72484b9cff6d, 0xd73458412f24c, 0xd759372ea11eb,
+    0xd78304c7ff807, 0xd79bf75664bdc, 0xd7e3ce6c68bc1, 0xd7ea326819ebc,
+    0xd821ae8c01649, 0xd8587eabc7eab, 0xd85f7c5ee83f8, 0xd8b0309b0bcf9,
+    0xd8c16e775eb07, 0xd8e938045a3a2, 0xd916272357945, 0xd9509c8a8a0e2,
+    0xd9540742f03cd, 0xd9a0bab82ed56, 0xd9b92d2db773c, 0xd9dc8f7983ed3,
+    0xd9df557fa9e1d, 0xda4b24eff9226, 0xda5b0ac40a1de, 0xda5ee3489b1df,
+    0xda7166e07e16a, 0xdb7fef7f75a41, 0xdb82ae33bae01, 0xdbb588876d83a,
+    0xdbf51133f0006, 0xdc0a18f112701, 0xdc266b3d0cc72, 0xdc363ec46bf51,
+    0xdc674848e0ccf, 0xdc693a0891d77, 0xdc7c53aef398f, 0xdc7f509b2ef64,
+    0xdd2c6e8c6d6a5, 0xdd43c388b9e85, 0xdd579b7fee6a1, 0xdd70d7411adda,
+    0xdd7c6820ae967, 0xddb08f81d20a7, 0xddc5e071bbb06, 0xddde23530f9eb,
+    0xdde50eb97e25f, 0xddf45ad61ffc5, 0xde2f57a80fcdb, 0xde585bf5ca5a4,
+    0xde87392b8862a, 0xded08ce331e17, 0xded9b6e50905c, 0xdf75b441777b7,
+    0xe051abbf839b9, 0xe064a81e627a9, 0xe085d6c9976be, 0xe0b11d5baaf1d,
+    0xe0b71e8602651, 0xe0ed9e2120fc5, 0xe1559c5ebff57, 0xe161fddbd83c1,
+    0xe170b792eba5f, 0xe17aa38e85e6d, 0xe182a1735f798, 0xe1aea2ad55636,
+    0xe1b10239313f4, 0xe1d1dcb6cde56, 0xe213ed3180357, 0xe21950fb78274,
+    0xe22595310d8b6, 0xe270115cc43f8, 0xe29d0f1f830af, 0xe2cce18c293c4,
+    0xe345754aea7fe, 0xe3c95bb1495b0, 0xe3ffa80f42e5e, 0xe4631a724f33a,
+    0xe4804fa87fe61, 0xe48792e57714d, 0xe549b893d7f89, 0xe59cd3adb81d2,
+    0xe5a6383e95067, 0xe6377558f4512, 0xe696bb09d753a, 0xe6e5bf673056d,
+    0xe71ceb066fe0c, 0xe7882bba21112, 0xe7d8dc4d5444e, 0xe81c0a4142b55,
+    0xe8548a3fd3c85, 0xe8c409f260118, 0xe906b4248186b, 0xe90df76178b57,
+    0xe922f423ca103, 0xe94673b7196c2, 0xe9544a83f67aa, 0xe96416b974eff,
+    0xe964a57613002, 0xe9a0559e047cd, 0xe9c83177b1d41, 0xe9dca6cf45d74,
+    0xe9ec979e27e7b, 0xe9ee820bf8399, 0xea5f686a87d96, 0xea6ead3548f72,
+    0xeb1de5288c6d2, 0xeb5e003a9d566, 0xeb65a648ee419, 0xeba49c8fe251d,
+    0xebb82efd3ff9a, 0xec249824fe5f5, 0xec4e8305dfa39, 0xec58d57bc3fd3,
+    0xec594a99cf873, 0xec60e5ad4f5d7, 0xec61b2a1e38ef, 0xec7e30d92239c,
+    0xecc17119c277c, 0xecf5703891f45, 0xed78ae43c0391, 0xed9a81a135647,
+    0xedeb482a0ac21, 0xedf829bfffa7a, 0xee0b6456d4a7f, 0xee2518defd01d,
+    0xee2ecdf481da0, 0xee38f828123c3, 0xee456848ebf41, 0xee6fe9384c012,
+    0xeea727243d58a, 0xeecf5ad473576, 0xeed041679a0f1, 0xef29c4261bbfa,
+    0xef40daea721c5, 0xef457cbaa6f19, 0xef4ea6bc7e15e, 0xef865d6f6b53b,
+    0xf014d483a4a9c, 0xf07dd69bed3d1, 0xf08633a9302fe, 0xf090f0424efe9,
+    0xf0cfc1efdf73b, 0xf157eea6fa204, 0xf1729c0efaff6, 0xf178490bba277,
+    0xf1864bc3db89b, 0xf1c5f560d1454, 0xf2265c33e0c47, 0xf2511f04274f2,
+    0xf253d2bd9b763, 0xf2ae67fa88323, 0xf2b6f0f30f78c, 0xf2d2430d5091f,
+    0xf3a6c068501aa, 0xf3a8027aefd62, 0xf3b2f5fa240d8, 0xf3cdd2f6599cb,
+    0xf46dcd70bcad9, 0xf5760f5b85edd, 0xf62c91dda14b3, 0xf6b1418fa3fb8,
+    0xf6d2ae72cf0e2, 0xf6f0eb2c99b71, 0xf7117c776f26f, 0xf727378a9341f,
+    0xf75bd409c23ff, 0xf7c8273bde7bc, 0xf7d7c3dd28410, 0xf81f04e4ad768,
+    0xf820a276c635d, 0xf950526f11fbf, 0xf9cef5fbec241, 0xf9eb4bf0d6d77,
+    0xfa0951c48c17b, 0xfa2c33f77bf23, 0xfa37f81434b76, 0xfa3c754b05f18,
+    0xfaa7a75af5b0a, 0xfadff7c552139, 0xfb08a093939c5, 0xfb3f18dcd17af,
+    0xfb46ee7f57163, 0xfb6038e444fb0, 0xfb99ec51b4584, 0xfbb5ea7016642,
+    0xfbc2daa9ccbaf, 0xfbc9215dfb082, 0xfc0119f1cec39, 0xfc20de481001f,
+    0xfc2e7a85e74b7, 0xfc6cdabe5c92e, 0xfc6ed778deb25, 0xfcf106ae9247f,
+    0xfd04c50734438, 0xfd0cb09f5c08a, 0xfd62f0e7cb81f, 0xfe008f7f43134,
+    0xfe0fa4b5cf80f, 0xfe52dda48f065, 0xfe5cf58b6d9af, 0xfea7ea7e20356,
+    0xfec7f45e384db, 0xfed34aaec6418, 0xfed898831c097, 0xff4cd74d600a6,
+    0xff656e5c4c43e, 0xff8736bef05a5, 0xff92d29955281, 0xffa03f42f7c18,
+    0xffc14202e85f1, 0xffeeb88ca310d, 0x002760cd881b4, 0x0033e6e403fd0,
+    0x00439d23e0487, 0x005950e523aad, 0x0062ae241fdb8, 0x0085d5e0e68ff,
+    0x00a1be09a671f, 0x00d05d076e590, 0x00d891d25d546, 0x00d9c1984b425,
+    0x015429cf3aadf, 0x01bdede1465dd, 0x01f5e6751a194, 0x022e03a2516fd,
+    0x0241124de21c6, 0x0245eb042c5a5, 0x02a045b213515, 0x02a19a1164da6,
+    0x02b1e2b6cf925, 0x02d506caa5ea7, 0x030faf6efd70a, 0x03628ff9d7d03,
+    0x03e0f1a5cb7ab, 0x03ed14eaeda00, 0x041d5c759f5b5, 0x048d9a78fe64c,
+    0x048dc66442b88, 0x04ab4fc80bb62, 0x04b7521cba9ca, 0x04ed04c345026,
+    0x053daa5ba7213, 0x0571011f46076, 0x058321e5cf43a, 0x05a1c170f3a90,
+    0x05a8291595350, 0x05e57326af14b, 0x05fee2250094a, 0x0611f479819d8,
+    0x06958e0428e61, 0x06cc09f657210, 0x07032a9ac5960, 0x0706c13e70187,
+    0x0715440f6e19a, 0x0715fb0e60214, 0x07cb21df493cf, 0x08073c2a752eb,
+    0x080a73a5b6510, 0x0842822f2c365, 0x087c568d0ed26, 0x0891c86d6bb72,
+    0x08addc816fece, 0x08b1fa8fd7c6e, 0x08e964670d722, 0x0929c502f5355,
+    0x094addb887fcc, 0x0972322777fc7, 0x0a04104b2724e, 0x0a5cb3c862d66,
+    0x0a6c75031036c, 0x0a6ebaf0598c7, 0x0a9c1089a0ff6, 0x0b160af46599a,
+    0x0b1ca5d62c320, 0x0b53d51e5c184, 0x0b83b9d7b4172, 0x0b914b1aba4bb,
+    0x0bfeef0337b44, 0x0c1ef53a5f704, 0x0c2e2b615f1cc, 0x0c36c2fda7d49,
+    0x0c62984c596ab, 0x0c96b109bb6d7, 0x0cc903f2b0b97, 0x0cdd844515d19,
+    0x0cf529c60a3e7, 0x0d61167ddc618, 0x0d7ef40f3daa5, 0x0d81db05d6ddc,
+    0x0d8afa0cdced2, 0x0dbf216e00612, 0x0dc49b2d9a7cd, 0x0dc7e14c9d106,
+    0x0dd7ebba11a70, 0x0df12b242e76e, 0x0e63205838c5d, 0x0e71cb6b8abe7,
+    0x0f0701faee480, 0x0f173101c679c, 0x0f31f45f6982c, 0x0f36c96cc3646,
+    0x0f5f49f8b0f5b, 0x0f7f0aa601d7c, 0x0fd1b0a1d6725, 0x10343c71bc086,
+    0x105cda452c7c3, 0x10bf8e576609b, 0x10e67c4c0bf0a, 0x10ff21feb99b6,
+    0x112f91cbbf4e2, 0x11ae9f7bd3eb5, 0x11ecdec3d5f3f, 0x11ef07699c672,
+    0x11ff8a9e0ce41, 0x12182ca7ca328, 0x1264eb17d9e00, 0x12b2ef4379a55,
+    0x12d8b4c41256f, 0x12dae4bbb982c, 0x131e45ecccff9, 0x132e3a649f6c5,
+    0x134145673fbc9, 0x135930720b036, 0x13db3eb74b5a3, 0x13de519928e16,
+    0x143cbbb1b6412, 0x148de50be55b3, 0x15046d6c630e2, 0x15288e090247d,
+    0x1529958c9c3e5, 0x1567eaca4070d, 0x15c17131b27db, 0x1612e767992a5,
+    0x1621c5b8102f5, 0x1629d5e99b8f9, 0x163148bac76e6, 0x165d9e2255a37,
+    0x1676aa4f4d66f, 0x168eaef8ab33f, 0x169b4009f82aa, 0x16b1703b27cfa,
+    0x16c791c896036, 0x1714ffe5b6ffe, 0x172d63b77e2d0, 0x176e94f0ea7e0,
+    0x1777880cac39a, 0x17781e1b2b027, 0x179b756c2666f, 0x17f3dab16bf72,
+    0x1828ec4ea67f2, 0x18513d465f606, 0x187147267778b, 0x18b3d06825af1,
+    0x1975ca2b423f1, 0x197a1f1fbf81c, 0x1a6f76f85bb09, 0x1ab3f5a2ab4dc,
+    0x1ac2af59beb7a, 0x1ac79a5cbac32, 0x1b5fbf349838c, 0x1b95c9b1ab460,
+    0x1ba6f5414c595, 0x1bc661c104f03, 0x1bf08ad9dc55c, 0x1c017f8368006,
+    0x1c1eef489e77d, 0x1c4c0300ff6d2, 0x1cc38f3c26ba4, 0x1ce0b225a59f2,
+    0x1d58a88407615, 0x1dd3faf70de0f, 0x1e07ad3a25caf, 0x1e168b8a9ccff,
+    0x1e209c1f9aabf, 0x1e81a7a4ea653, 0x1e865f6ac1645, 0x1e8f7ac8d7176,
+    0x1ea06bc97265b, 0x1ebea8833d0ea, 0x1ec476706f758, 0x1ed48486d4687,
+    0x1efdb868c3a51, 0x1f74de29a0d97, 0x1f96e86d2b6d8, 0x1fccd1f9cb3bf,
+    0x1fdb5121d8e0d, 0x205e007069156, 0x20adfa04aa418, 0x20f3f8f98fbb8,
+    0x2132e09cc25a8, 0x214a9fbc494d9, 0x2187033a3c759, 0x21895b743798d,
+    0x21e3f45a14b12, 0x21f34f1a77f8c, 0x226c8edd5a2f1, 0x226d8f0f136cf,
+    0x236ce1870b4de, 0x23890f39a209d, 0x23b522c049c14, 0x23c6bc1c15a5f,
+    0x23e0e5c24989d, 0x23e71a29c6097, 0x2409246d509d8, 0x242000a2a1353,
+    0x244c89475476a, 0x24a77a03ba367, 0x24b55bcb6859e, 0x25005f61dc659,
+    0x25997d1992607, 0x25b331a1baba5, 0x25cc6d62e72de, 0x263142c90675f,
+    0x2670ae2e06103, 0x269eafcb6e75d, 0x26b8e46c736ea, 0x26d9e72c640c3,
+    0x27131a80f6ca8, 0x2760dccbb0123, 0x27bf25f3ca332, 0x27f41a4981d8a,
+    0x280f89ab45d45, 0x287c5cf63eaf1, 0x2887a84bfb8df, 0x28d7724c080a0,
+    0x28e8425c30198, 0x28f8123a9eeb2, 0x290129efc441e, 0x291d7fe4aef54,
+    0x297ff967e2bdc, 0x298184ad49af8, 0x29b291daaee3b, 0x29c86dde463d8,
+    0x29d682e3196d5, 0x2a0a5d68854ec, 0x2a2e596bc0ed5, 0x2a3586b315f23,
+    0x2b0e8c3fb7c9f, 0x2b1cf5722344f, 0x2b869141dafd6, 0x2b978242764bb,
+    0x2ce48c0a565f6, 0x2d015ac63cf91, 0x2d68e03ce00be, 0x2d74bdf82b574,
+    0x2d9b6a0beac09, 0x2e514b84b6403, 0x2e99fa8a1f84f, 0x2ea12428842d8,
+    0x2ea89e4b90c4f, 0x2eda9d06edc5c, 0x2f3b336e31f50, 0x2f50636da85c2,
+    0x2f71787a4ac74, 0x2f718e6fecf12, 0x2f72a4974858e, 0x2fa8991eb93c4,
+    0x301bc1c1a1d57, 0x302af096c0c95, 0x309f246633b55, 0x30a3d131399f8,
+    0x30fbed43b7f97, 0x31f8b4448faac, 0x328c8f22c0f2a, 0x329f7cddde606,
+    0x32ac36317f4e8, 0x331fc54eb2007, 0x335fd1bd01787, 0x33712937e6df8,
+    0x33c67f443f44d, 0x33ef69f3674b3, 0x3467b47f61589, 0x346dc7f66a996,
+    0x34e733a491237, 0x350e5c283ccf6, 0x35d0648f1ad0a, 0x360ea3d71cd94,
+    0x362d470b319af, 0x362dfe0a23a29, 0x365ef198f6509, 0x36d96873a72d7,
+    0x36e6c6798855a, 0x36fbca8dba690, 0x3700f3c8ac95d, 0x370382e8bd21c,
+    0x3707be3ea7de4, 0x37481788aee8d, 0x37584a387776e, 0x377c304610eb9,
+    0x379914f799af2, 0x37d8f923952fb, 0x390c7a4e913d4, 0x393c456956b5f,
+    0x3950160eaa7f1, 0x39557d8192cd3, 0x3a1137e261c8a, 0x3a35ee8d7fcb2,
+    0x3a41af0148340, 0x3a6e8bd393c0a, 0x3a8e7fbe09af1, 0x3aad0201ab31f,
+    0x3af1f22115fcd, 0x3b001220ba419, 0x3b003a630e390, 0x3b5cd0036d70c,
+    0x3b7f9c40bb216, 0x3bd0ea344dd69, 0x3bd8227673f06, 0x3c692158dd19c,
+    0x3c776d43c5b24, 0x3d1d52f2de0c8, 0x3d32a78bb80ec, 0x3d5d8b4c71d84,
+    0x3dad72940136d, 0x3df12b9b9d5b2, 0x3e1f4e2978ff9, 0x3f57b30590600,
+    0x3f671c69b518e, 0x3f81fd0edb046, 0x3f8588b7b471e, 0x3fe467ee4d5ba,
+    0x448651c1b521d, 0x46d7ff7ed33ab, 0x4b5c1e0d8b85a, 0x4b9e40d4efa34,
+    0x4ba837cb5af91, 0x4c94ff598f28b, 0x4d329df106ba0, 0x4d4f0289b2dea,
+    0x4f3329a5d6717, 0x4f5ff42b70308, 0x4f6ba5fb77282, 0x4f768ad6e9ee4,
+    0x5041ff2085917, 0x50709a755d1c3, 0x51ca563efd656, 0x521e544313e55,
+    0x5312db7e2b865, 0x531d7e78b7ced, 0x539cb0c230072, 0x554919d985a38,
+    0x5550f324fb9b1, 0x560d4e8f1a744, 0x56c6b807cf051, 0x570ec603e86c1,
+    0x580af6f641549, 0x5882f84f742bb, 0x58ce86f995eb4, 0x593ff4c2e2e2a,
+    0x598953755d766, 0x59add6e3566c8, 0x5afbad9fcab1b, 0x5b3b030f28221,
+    0x5b503e096f9e2, 0x5b903bd3fda4e, 0x5bb12c473c74e, 0x5bd9ca1aace8b,
+    0x5c07a375c156e, 0x5c1513c8544ca, 0x5c90abc531a63, 0x5c938b69ea210,
+    0x5ca9e7865e19c, 0x5d0bebeb86584, 0x5db0b42162922, 0x5dd162b3bae48,
+    0x5e78c15b8862f, 0x5ec462525bf01, 0x5f72a8b7a7997, 0x5f8f94bb1115a,
+    0x5fbd71bf15e02, 0x60b35854501f2, 0x60f3c3eb08f74, 0x60fe6a8e859c1,
+    0x61a0c83db4e52, 0x63d8bdc9ba47d, 0x63e923b6a7e24, 0x647c22fc83876,
+    0x651d9dc17c751, 0x665ca1284aca3, 0x667701b49416c, 0x66d030457d7c2,
+    0x677406ed61e96, 0x679798cd6312e, 0x67ab6972b6dc0, 0x67e4dea83017f,
+    0x67e8336af41cc, 0x683ae80a8a289, 0x68cd080f1fcea, 0x695bf44164aeb,
+    0x6975ee5363e28, 0x698f9b89ab83c, 0x6a7f082d92693, 0x6a9ba3ac53f68,
+    0x6c390287ee3a2, 0x6c857f17176a0, 0x6d517acb7064c, 0x6d879b3e259be,
+    0x6e0f7b1988b5e, 0x6e1370e59c987, 0x6f2fe29e2301f, 0x6f47fd3d22f8d,
+    0x6f692be857ea2, 0x6fb38e757c181, 0x6fb83045b0ed5, 0x6fde42a201318,
+    0x6fee71a8d9634, 0x7088a930db223, 0x70909c1ae39ff, 0x709e3858bae97,
+    0x70ce280ce3fd4, 0x711f257bcec39, 0x7148ebc34c6cb, 0x71c74d6f40173,
+    0x728c984c304fb, 0x72cac8f070e71, 0x72de8af2033ef, 0x73026604cb9eb,
+    0x730578e6a925e, 0x730c60a42750d, 0x73f389d95dc9a, 0x740160a63ad82,
+    0x741bfbc189e9b, 0x742659323f584, 0x7487770440df1, 0x74c38da67c748,
+    0x74c750356b4ab, 0x74e6bcb523e19, 0x74eb956b6e1f8, 0x74f8a2eca758d,
+    0x75050bbba0581, 0x7511190b20538, 0x751a1acaa3806, 0x7530f35703bbc,
+    0x755690954875f, 0x7584e6604926c, 0x760d639c0bc23, 0x7652ba35c0a5d,
+    0x765528655df2f, 0x76f7cf4754724, 0x7751ce75c2657, 0x777045be92d36,
+    0x778e73d49c0b1, 0x77d55d0598991, 0x77da7b45b9b0f, 0x78668081655d9,
+    0x786a67a9b7cee, 0x78850a16e7991, 0x7889e67622335, 0x790334dcc5dae,
+    0x791717cecb719, 0x792b7ad9ada73, 0x79c7d3b59520b, 0x7a35b97ef906e,
+    0x7ae7e363a6c54, 0x7b07413f9deae, 0x7b7af4f63437f, 0x7b8c83572f07b,
+    0x7bb14c4efed7c, 0x7c13580607cee, 0x7c42c7a15443c, 0x7c65644a6d445,
+    0x7c7525851aa4b, 0x7cc1309f28a6e, 0x7d5be49716a87, 0x7d8b508972c10,
+    0x7dc904bdc715c, 0x7e19adff1990e, 0x7e557b6e8df01, 0x7e76b16ba39a0,
+    0x7f30c6e8791d8, 0x7f87540ca0296, 0x7fc5b4451570d, 0x7fceaeb2b7e51,
+    0x80b39cf5761d2, 0x812ed5c9ea169, 0x8139d7ecdfbf3, 0x819b379fc7c3a,
+    0x827fa5c9a95cc, 0x833bb80100ffb, 0x8369d33cfbeb8, 0x83b37ecb2e11d,
+    0x83b665c1c7454, 0x84ae5f0725cd9, 0x84f8786182c54, 0x8518181e60688,
+    0x8606a581014ee, 0x86356cc11d2d6, 0x8664a1cd63dd4, 0x877122b708365,
+    0x882ba600088b3, 0x88799f30d73b9, 0x89034fdb78214, 0x898928018806e,
+    0x898b9d83060ca, 0x89a4383ae2a27, 0x89c76af27a6bd, 0x89d1de58d2044,
+    0x89d6d4569f24b, 0x8a324c7dc23c1, 0x8a43a7a197ff7, 0x8a880cad55167,
+    0x8b3fb7a37da92, 0x8b4b7bc0366e5, 0x8b959c6c741ea, 0x8bf5623633c01,
+    0x8c6edc881bbb6, 0x8d5cc18f8c0b6, 0x8e8689fc12e47, 0x8f9044df9148e,
+    0x8fb38d8ccb3c2, 0x8fc0ce4b2981d, 0x8fef4c587e2a1, 0x9017ba97b9edd,
+    0x902d33c9f78b3, 0x903e3e69255fb, 0x9059b173d9b7b, 0x912cf06529e13,
+    0x91701ab027f55, 0x91a71e0d1387d, 0x91c252dfd1be8, 0x9202e30fee31c,
+    0x927f94dd174f6, 0x92bd4cba5c007, 0x9353b6b89dd44, 0x94341cc9b9bd4,
+    0x9454772e79c47, 0x946cc8b38f240, 0x94716a83c3f94, 0x9539de3833e2d,
+    0x95484ebc80167, 0x95f1683de936b, 0x96f9b17a932f9, 0x9746823754aaa,
+    0x975363cd49903, 0x975f3a36b422f, 0x97a7d6ef6b9a2, 0x97c817b5991b2,
+    0x9817f05967087, 0x983c44332b4e8, 0x991074b27344a, 0x991dfafaa8644,
+    0x9971210f599dc, 0x99ad2564e365a, 0x99b79c742b5a6, 0x99cfdbac8eec6,
+    0x99d88ce76a2a6, 0x9a1e5ff10b50a, 0x9a257742be2ba, 0x9a56e7417d1c4,
+    0x9a6d0c77dbac5, 0x9ab45128513e2, 0x9ad7f1ac13d8e, 0x9b2ace8dfddc2,
+    0x9b8aadf65003c, 0x9bf94715b5954, 0x9c13d38d43359, 0x9c26a7a9ce1d2,
+    0x9c3b7880db242, 0x9c642c49edc1d, 0x9c9e12f4822b7, 0x9cc07c60761fa,
+    0x9cf5cc35a6c8f, 0x9d0ea52579801, 0x9d29319d07206, 0x9d47301edba80,
+    0x9e1a51c8a8ef0, 0x9e258e7aa45ca, 0x9e2ef30b8145f, 0x9ede2003f3a70,
+    0x9ef9378f2efb3, 0x9f6674fd624b0, 0x9fcb814996fbc, 0x9ffcccaef2514,
+    0xa08893b2a7dc9, 0xa0c9328685c11, 0xa0f8b46e84038, 0xa107ac5d8d8eb,
+    0xa1487ac5a0234, 0xa14b1fdb52d91, 0xa16f7b06f7d7c, 0xa17da9aa5d8dc,
+    0xa18957d174291, 0xa1c600d93e2b0, 0xa1e84cfdaf3cb, 0xa1f4a37ff66e6,
+    0xa224f605793ea, 0xa273b1300b0b9, 0xa28bd6c9dc176, 0xa2a97d7527f78,
+    0xa2c1946b37921, 0xa321a367be69c, 0xa3d65171ab9f2, 0xa40c9dcfa52a0,
+    0xa410dcce8042d, 0xa436606e3276d, 0xa480468b6a622, 0xa5091f46a6016,
+    0xa50ed73e363e6, 0xa52bce3c70cf8, 0xa5fe85c303a17, 0xa64dbd5d81b10,
+    0xa694b8db300c9, 0xa69977f2e7c45, 0xa6b0d7ea05574, 0xa6c83f3303a2d,
+    0xa6cb7e00257dc, 0xa6dce41ecc561, 0xa784bb8d95bad, 0xa7b9f1c433ddf,
+    0xa7d47e3bc17e4, 0xa7d9d70ae85b2, 0xa8516e40e0bd3, 0xa96e5c6953695,
+    0xaa21a01e4cebc, 0xaa2fdd6574130, 0xaadee96d73354, 0xab18d018079ee,
+    0xab37f364f8ff8, 0xab4e7f15a1a85, 0xab6520bbec7b0, 0xabe8e288e7bb0,
+    0xac005fc788307, 0xace01d7d73831, 0xacf3c23782f87, 0xad3752fccb255,
+    0xad74194c1809c, 0xad80ec3e4b7e1, 0xad8b8f38d7c69, 0xadb087c4dc46b,
+    0xae6efd310021d, 0xaeb1692b2b75b, 0xaed36873e4f4d, 0xaee7023323554,
+    0xaf4de6a0768a5, 0xaf74f1dc9f53c, 0xaf942b1f32de4, 0xafea5cc3e0e65,
+    0xb0376f6188df0, 0xb04d1f79dbe51, 0xb051d73fb2e43, 0xb0594667ee66b,
+    0xb0a5293fa8717, 0xb11ad25ee0255, 0xb17dc4a90fd42, 0xb23cf4bd16133,
+    0xb24bb21d19d96, 0xb310c9bce5058, 0xb33592b4b4d59, 0xb335ee342dd96,
+    0xb3375889218c5, 0xb376686ea822c, 0xb3934d2030e65, 0xb3aed9c977c48,
+    0xb459ec5c72a7e, 0xb4789a8b587e8, 0xb47fddc84fad4, 0xb4b2e7b03700e,
+    0xb4f586e787612, 0xb4f5bdcd9cc9d, 0xb52764b271232, 0xb65fc23ca7caf,
+    0xb6611dedda0ca, 0xb6f82c9e5c1a8, 0xb6f900e4d104a, 0xb722a292eb12a,
+    0xb7427935de1e9, 0xb76c2987b99dd, 0xb770b5624c493, 0xb7cfddcbac693,
+    0xb80da44cb28b8, 0xb84deb4a07c88, 0xb88e1156e9c6b, 0xb93331634ea81,
+    0xb977d0fe11841, 0xb98e6efb6bfa7, 0xb9cd2ab35a45b, 0xb9ec8c3841c7a,
+    0xba590403c19e9, 0xbad7d724d076c, 0xbb0befe232798, 0xbb1e85c6c73fc,
+    0xbb2ff3374ed0b, 0xbbba4c3d204cc, 0xbbc0635d19e9e, 0xbbf75816440b2,
+    0xbc0fef253044a, 0xbc20a1edd571a, 0xbc38c3deb6212, 0xbc52d3e6577ed,
+    0xbc570b9351df0, 0xbc71fe8529981, 0xbcef306b2f54a, 0xbd277d2c9b5b4,
+    0xbd5883081fd6d, 0xbdce5bbb8c3ac, 0xbdd9c0afdb9fd, 0xbe2ed8843de3d,
+    0xbe4b5e0d5d474, 0xbeb8adc84264a, 0xbf609783bd96f, 0xbf78e1b6f23de,
+    0xbfac387a91241, 0xbfcdb7aa6e044, 0xc04bd0239a788, 0xc097dee698d70,
+    0xc1b174a357220, 0xc1c2f0b7a0243, 0xc1dd3b4e4746e, 0xc25d8b10fb9f9,
+    0xc29c5cbe8c14b, 0xc36aca4b72b8e, 0xc3b29666a5a24, 0xc3e7c1a272b07,
+    0xc3f317f300a44, 0xc45ec2c9ec49b, 0xc45f1e49654d8, 0xc4cc3e7015bad,
+    0xc4d393f9beb72, 0xc4de0eb1f7083, 0xc50e94749ee4d, 0xc536a73461a4c,
+    0xc555eb71c6443, 0xc55aae326e584, 0xc5799e423aac8, 0xc59fd8e0dee82,
+    0xc5a9361fdb18d, 0xc5b617b5cffe6, 0xc60974b096a09, 0xc648082630f46,
+    0xc64d60f557d14, 0xc68b7ba3f63ec, 0xc707a6066204d, 0xc74c75355990e,
+    0xc7e7f621dbc3f, 0xc7eed68d79364, 0xc82e5f39fbb30, 0xc8e7dea8526db,
+    0xc91d40ca34e49, 0xc96d8ae31dff9, 0xc9ebf3e0f262b, 0xca6466af40678,
+    0xcab5f683b99a5, 0xcb0689cf69eb9, 0xcb57ba7b79be4, 0xcbd80a3e2e16f,
+    0xcc26997d7b902, 0xcc87965f09d82, 0xccaddfa16f850, 0xcd1fb78df6f17,
+    0xcd644884f85c3, 0xcd7013f391da0, 0xcd819b02abf12, 0xcd997769b5c6b,
+    0xcda9c00f207ea, 0xcdae742c07217, 0xcdbd736cf1654, 0xce01beda1bf61,
+    0xce71b3aab3c94, 0xcedbe1dff9ee3, 0xcf1753cff5499, 0xcf42a905ca40c,
+    0xcf7871a1f6d06, 0xcfc55fa63b2df, 0xcfe73303b0595, 0xd008271fdf85a,
+    0xd01990e776ba4, 0xd021076192f56, 0xd036f5b1dc1cc, 0xd079d6ca12faa,
+    0xd0ec486e098c3, 0xd1031256a8565, 0xd13de6e644304, 0xd1d81e6e45ef3,
+    0xd20827c101893, 0xd230abf5df76d, 0xd239d9a0a6f77, 0xd272d60f244d1,
+    0xd2785ac98f7db, 0xd2942cfcad35d, 0xd2a6307bb38f9, 0xd2b6e34458bc9,
+    0xd2ba972f86218, 0xd2bd0cb104274, 0xd35cc8f37116d, 0xd3c267a534341,
+    0xd3cb118e2eb97, 0xd3dec1430f43c, 0xd464f4e8982d3, 0xd470d2a3e3789,
+    0xd474a3d693c00, 0xd48c57fb499e2, 0xd4f9d74a636b9, 0xd539639fd644a,
+    0xd540e8bdb3f10, 0xd549c5e3d382c, 0xd5c6063be172b, 0xd5d6efea9c086,
+    0xd60efacb21916, 0xd62d3784ec3a5, 0xd648ec70870ff, 0xd667d52e72ab9,
+    0xd695864733225, 0xd73141c2bb1a6, 0xd7a758ae1d9fa, 0xd7bd8c883da0f,
+    0xd85021aaded10, 0xd8875beddfcc3, 0xd89d7d7b4dfff, 0xd8b794d4d0164,
+    0xd90cfd2dda492, 0xd9918bef69baa, 0xd99cb9fda3b70, 0xd9d695ad670bb,
+    0xda23b6eed075a, 0xda7b5a3a52e94, 0xda8f656eac776, 0xda99b43ba074b,
+    0xdb24dddef67e9, 0xdb5516c5e6c8a, 0xdbc1789bc475b, 0xdbdc9026ffc9e,
+    0xdc80791b9604b, 0xdce5aa012e509, 0xdce7c40333528, 0xdd00994a15ad5,
+    0xdd141d13b1e3e, 0xdd24536c6ace4, 0xdd7bf30efce59, 0xdd84f4ce80127,
+    0xdd88a167ccbec, 0xddd97de644464, 0xdddf95063de36, 0xde39c01ff02a5,
+    0xde3f0a4b5595f, 0xde44d48f97a08, 0xde846f88cbead, 0xdffc9ef24c45a,
+    0xe03ddb2689ab9, 0xe0b62d0464719, 0xe0fdea7bd5e9b, 0xe105b17a9a13b,
+    0xe111a52b8788f, 0xe229800eaa322, 0xe276840890b99, 0xe282c83e261db,
+    0xe2af3e9627919, 0xe341ed575b47d, 0xe36ebb85e5633, 0xe3770d9857411,
+    0xe3778a084383b, 0xe3ce605f31c5d, 0xe3f51ebfa2fcb, 0xe40625b5e074e,
+    0xe42783f54a164, 0xe449833e03956, 0xe45aa3d2d393c, 0xe46c11435b24b,
+    0xe46e06abfc8b8, 0xe477eefea6701, 0xe47e1c1442371, 0xe4b92789f379b,
+    0xe557bf0143904, 0xe5808c68e8b42, 0xe58f49c8ec7a5, 0xe5ade20230271,
+    0xe5b9f6a390db2, 0xe5d0da2ac22b7, 0xe62bef808b866, 0xe6ec48089efac,
+    0xe6eed728af86b, 0xe6f1f8ae4e7f2, 0xe70af9e0752db, 0xe713cfb4b406d,
+    0xe779880509aa4, 0xe7c8975d33c26, 0xe7cec0c9df2d1, 0xe7f60de6ee742,
+    0xe801bc0e050f7, 0xe81163aa1fe9a, 0xe825678c98bf2, 0xe85674b9fdf35,
+    0xe86e28deb3d17, 0xe8f6d5aeab1cf, 0xe960df4a8da6c, 0xe99e3ba859540,
+    0xe9efaa8c5f480, 0xea61c0b0dcd5c, 0xea6b80c132c2e, 0xeb0bb221ab3c7,
+    0xeb285c442e3b0, 0xeb480e4dbdabd, 0xeb55ddc8ba01b, 0xebf6585bf9b18,
+    0xec6b1ae80aadb, 0xec8d28d4859e1, 0xeca137b1cf888, 0xecf73d6b393cd,
+    0xecfcd472563b0, 0xecfd5bdd13929, 0xed03520c99f0e, 0xed41aaf32e7fb,
+    0xed48ea8735522, 0xed73a9ae8b808, 0xed990162f960c, 0xed9f063641305,
+    0xeddecd1ab9ce6, 0xedfeecf074109, 0xee479f9ecdb1a, 0xee65eea54a282,
+    0xee7d907d4e38b, 0xeef110f6bf796, 0xeef606f48c99d, 0xef10f63d73f69,
+    0xef347979b3aed, 0xef571622ccaf6, 0xef72a2cc138d9, 0xef9852570a155,
+    0xefc2916583a4c, 0xefc4a7be984a6, 0xefc80ece0e1cc, 0xeff046273477d,
+    0xeffc830ae9235, 0xeffd5afa4e69c, 0xf00563d9f9116, 0xf0445dc9dd7df,
+    0xf05889eeaa4ae, 0xf08a51c3f1e30, 0xf0d63bed8ca66, 0xf14d7ef5ecbd4,
+    0xf16620ffaa0bb, 0xf1688b8656fc8, 0xf1ace940335ae, 0xf1cbe44ad0c41,
+    0xf1ceec31dd365, 0xf274a5f5b13cd, 0xf278c4041916d, 0xf28aade48cea6,
+    0xf2d76159cb82f, 0xf2e1c9c552067, 0xf38857cf9af71, 0xf3b84783c40ae,
+    0xf3f748c589301, 0xf456d05752b03, 0xf4c0e4ee064ef, 0xf56da00deb069,
+    0xf5ce710434fad, 0xf5def7e195d41, 0xf6c38aa4db085, 0xf6f5efda8221e,
+    0xf710abe644724, 0xf721d775e5859, 0xf730cf64ef10c, 0xf7b6953e4d28d,
+    0xf7c09e816a4c3, 0xf7c464b9497eb, 0xf7ff18587219d, 0xf83cd78797838,
+    0xf88753b34e37a, 0xf89d83e47ddca, 0xf8b98cfdb0fd7, 0xf8e4c14312b5d,
+    0xfadadd3a7b312, 0xfb0cc25745abc, 0xfb342912e7790, 0xfb4f1109ee1d2,
+    0xfba8a9be11f79, 0xfbd110ab6d02b, 0xfc9603b1d493b, 0xfd13e8eddbfb9,
+    0xfe6f792faa6cc, 0xfec7e21de0594, 0xff283a4d2e673, 0xffb9f7806a50d,
+    0x002f5ebebb871, 0x00509c0db1e9a, 0x007ec9965ea30, 0x012d34950de78,
+    0x0130c3e6d7b15, 0x0138db6a43ca3, 0x0151d54a89c02, 0x01b4cb3da9cb4,
+    0x01be79014dead, 0x02497a624ffd4, 0x02a7680af11a6, 0x02ca9e6b79401,
+    0x02d8210abe036, 0x03143b55e9f52, 0x03233a96d438f, 0x0324f1c77f7e7,
+    0x035b2f81b7981, 0x038529065a4d9, 0x038c59f69faec, 0x03cd50a1063ac,
+    0x03f0e28107644, 0x04296d7a698c3, 0x04c0e64e260f2, 0x057d714c79986,
+    0x05932c5f9db36, 0x05ebb63e46deb, 0x05ff9cd93cd1b, 0x061036034f788,
+    0x064b11e4cc0b1, 0x06741d8467504, 0x067780eaecc65, 0x067c81e38afbb,
+    0x06813600719e8, 0x06b4dd48b8739, 0x06b5401a12300, 0x06bd713c10cf1,
+    0x07001073612f5, 0x075e733a0dd67, 0x0808c3d3459d4, 0x0829eb2c99d5f,
+    0x082e8201fd964, 0x0865f32b13fa2, 0x08d5dd00dab86, 0x08f38003363c3,
+    0x0947b14471c88, 0x095050329b38f, 0x09d8634b235f5, 0x09d8c61c7d1bc,
+    0x0a0efc84d47cc, 0x0a19d2bc85d1a, 0x0a304c207cace, 0x0b88e31f001d1,
+    0x0b9f5531163fb, 0x0bebbf738da20, 0x0c1b624bff234, 0x0c4be0bcc6474,
+    0x0c973880d29e2, 0x0c9dab20453f1, 0x0d47535e4c6f8, 0x0ddaa328d0038,
+    0x0deed2f68d2cc, 0x0df28338ca356, 0x0e3edb2e8fca2, 0x0f22fc7cb9d0b,
+    0x0f6d74ff80288, 0x0f83a8d9a029d, 0x0fa4d784d51b2, 0x1043939588ccd,
+    0x104ae91f31c92, 0x10c1075c75070, 0x11286be2a4db0, 0x116adb85c08b3,
+    0x11ef9d8475091, 0x126e869b260b2, 0x1271ccba289eb, 0x127c17de2c3fb,
+    0x12b5562d9012f, 0x133c2e8559367, 0x1385774231a05, 0x13b8887bf9ac9,
+    0x144118046e159, 0x144d513f3264c, 0x1493d04cf47db, 0x15010dbb27cd8,
+    0x1574c171be1a9, 0x16b4190624bae, 0x16c68dfa46425, 0x1734233f0239a,
+    0x173f8bdc41fb0, 0x175180b786e38, 0x17e3ed97d41c2, 0x181656766b920,
+    0x1829184644ac0, 0x1849fa15c20ac, 0x18b5be8b40366, 0x18d8e64806ead,
+    0x18fb48621a266, 0x19848ee980970, 0x19a24b8a6ea10, 0x19bff9879b39c,
+    0x1a0d296cc614f, 0x1a1744fc9505e, 0x1a45f6470eba8, 0x1a934373bc783,
+    0x1b407eac7dcec, 0x1b47d08d366ec, 0x1b76bc66b5e86, 0x1b92ea194ca45,
+    0x1ba3af2ea39ee, 0x1c7758432e3d7, 0x1cbef8731cd31, 0x1cc93c453fbb7,
+    0x1ccef7e5c054c, 0x1cd1bdebe6496, 0x1cdc1b5c9bb7f, 0x1ce90b96520ec,
+    0x1cf3c82f70dd7, 0x1d0fa9065006d, 0x1d2363b601a61, 0x1da5dfc76cce4,
+    0x1dcac25dcf248, 0x1eacdbf6a5f6b, 0x1ec5d22dfb905, 0x1ecd83371d907,
+    0x1f1be68b26b5e, 0x1f2c0a972dd2b, 0x1fe8f4bdeabc1, 0x20151e3a349d6,
+    0x206d0ab87e474, 0x2085d5048f8d2, 0x20e625e1fce27, 0x20f4a1611a2b0,
+    0x2121faa351fa4, 0x2146731679db7, 0x2193561fed241, 0x21a1b80077e67,
+    0x21a4a9f1e22ed, 0x21d6d0ef93271, 0x227868fc0ef74, 0x22b0fb4751d7d,
+    0x23c06e2260794, 0x23db8204ab712, 0x246c8be1e5af7, 0x24f617f322fa0,
+    0x250f41679da00, 0x2540ceaddf732, 0x2556e1978c35a, 0x25978f0f2b8b6,
+    0x25c1087af1a1f, 0x26d7128ed67f7, 0x26ec12fa18368, 0x270b5ae06d324,
+    0x2711ee7053120, 0x271f11e72e753, 0x277d3675e4fb0, 0x27869eafb240a,
+    0x27cfcdcdf8245, 0x27ffc87cf24d1, 0x2813c8b67ac64, 0x282b6a8e7ed6d,
+    0x2861abf1a74cc, 0x2871ed45314c1, 0x28f8e68d6dae6, 0x2925c708a9975,
+    0x297d2fc52645f, 0x2984312136f71, 0x2989d323250a3, 0x2a0ef44a42e83,
+    0x2a4695078dfc2, 0x2a4d8416ecdfb, 0x2ab030d745b49, 0x2ae6464f29d6c,
+    0x2b0031bd67995, 0x2b46ef031fd39, 0x2b8cea4f14f14, 0x2bf3b174e543d,
+    0x2c76d23890a61, 0x2c7e958e6473c, 0x2c9796c08b225, 0x2ce897d86644f,
+    0x2dc31a06ad9d3, 0x2dd68088c6f14, 0x2dff63e60e3f0, 0x2e25ef095a698,
+    0x2e54069c64f90, 0x2e906dc3487d5, 0x2e931dd3cc481, 0x2ea9fdb20d3c1,
+    0x2eaff038a33e1, 0x2f3dee85e0add, 0x2fc2f9b75c61f, 0x2ff0cf698073d,
+    0x30186d0b37a9c, 0x301f12e7cf571, 0x303d39abf7d62, 0x308840eb5c3e2,
+    0x3109fb030449c, 0x322f3b3de8d3c, 0x3255d504f66f8, 0x32b8caf8167aa,
+    0x33c05d35ce6be, 0x340751619c0ed, 0x3420cf03af000, 0x348dc33f1b199,
+    0x349138f2525d3, 0x34c9d6386652b, 0x35062769a7ad2, 0x3517236514106,
+    0x355799f69dfd7, 0x36053457c8b42, 0x365bd3c8a18d9, 0x36a0736364699,
+    0x36e13327b58ce, 0x36fc22709ce9a, 0x3737fadae25dc, 0x38258bb4ba629,
+    0x386d57cfed4bf, 0x388199ea5c42c, 0x39b5f6adadf31, 0x39e198bf3a7cd,
+    0x39ed9ebcd9bfa, 0x39f674911898c, 0x3a0b6daa79973, 0x3b36d3a9192f9,
+    0x3b6e32857dc5e, 0x3ba185a02c4fc, 0x3bac841a319c1, 0x3be06d435eeec,
+    0x3c481afc55f90, 0x3c539d3828409, 0x3cd83e46697fa, 0x3d04591ef1efb,
+    0x3d1e7b73451af, 0x3d36db9c1bebc, 0x3d7baacb1377d, 0x3d836e20e7458,
+    0x3ecc5d834fdb8, 0x3f26e7c56b829, 0x3f2890525556d, 0x3f4812c7b0179,
+    0x3fb1b240582c5, 0x3fbcf9ed24aee, 0x3fc0aa2f61b78, 0x4509bf6d71efc,
+    0x46a0452f4f79b, 0x481e0b9fecd2b, 0x48b44261099a2, 0x4a734505e4591,
+    0x4b98903b99f80, 0x4c283e67a1f4a, 0x4cc9147a5aa84, 0x4e2951872f03a,
+    0x4e88e7bcb9f50, 0x4f4f5eb6a7bf2, 0x50a97256d0afd, 0x50c39bfd0493b,
+    0x52234df62b3b3, 0x524f2e3fade64, 0x546265ceb48dd, 0x5501e7821bb86,
+    0x5540949648926, 0x5541c80526dca, 0x5559837bbd736, 0x559884bd82989,
+    0x55b586b68e3ea, 0x55fb3526cbc9c, 0x56e0b22628120, 0x5740e21322288,
+    0x58301f22d45de, 0x587a9ef77b6e5, 0x587d2e178bfa4, 0x5a761b0feb7f2,
+    0x5ac82ea631ad3, 0x5af2999fef906, 0x5bf01b48a9a5a, 0x5c3f025e7fc65,
+    0x5c516c57d038d, 0x5d41ad422c086, 0x5d627cc4f7999, 0x5d6c10ea0932f,
+    0x5db9fb7716721, 0x5dd65514f181c, 0x5e03aaae38f4b, 0x5ea6170129af0,
+    0x5eb3541697986, 0x5ede29338ff0a, 0x5f06ce58e11d1, 0x5f25fca0a392a,
+    0x5f2c2d5f2fb5f, 0x5f3047c4a733a, 0x5ff6a52002779, 0x5ffec04c5eecc,
+    0x610408f3dd2c0, 0x6153ddeebabd0, 0x615423789196f, 0x6378a3debc345,
+    0x637a3a1ef43b0, 0x63e65a13eb6a7, 0x64029dbc24504, 0x64182ce404178,
+    0x6468526389976, 0x647e988a5b664, 0x6521dcccb1670, 0x669749d8fc298,
+    0x67cd7ebd6c5e2, 0x68165605299a5, 0x68234295ef94d, 0x686b4ce9189f8,
+    0x68b6eddfec2ca, 0x68e4d987b2686, 0x69951ca780313, 0x6a2eb6cf226eb,
+    0x6a346774d1f31, 0x6a8a9cc270577, 0x6ade58e5a059c, 0x6b762d38d5e08,
+    0x6b907b786d5f8, 0x6bb2b8f91cfff, 0x6bfb9040da3c2, 0x6c0198bd12680,
+    0x6ca66bedbfb6d, 0x6cbede6348553, 0x6cd6420356447, 0x6d053c80972f5,
+    0x6dc3b1ecbb0a7, 0x6e34566a642ca, 0x6e9b497b78d2f, 0x6eb7ec4c1b18e,
+    0x6ec41e34feaf7, 0x6ee3d03e8e204, 0x6f3c7764ba2e1, 0x70b8bd8ac1aa4,
+    0x70d8b51e27f50, 0x71435c1a6a004, 0x7235c5aa8c430, 0x7247d42463b1b,
+    0x72960f3618dfb, 0x72edcc202dd98, 0x7316edb56b489, 0x737b46ab9e4e0,
+    0x753518c3a6278, 0x75b1fa2503f53, 0x75d1286cc66ac, 0x763071c699c99,
+    0x76ad5327f7974, 0x776bc4eb2b161, 0x778178ac6e787, 0x77fc43b4b7a08,
+    0x783b39fbabb0c, 0x78695c8987553, 0x78816c2db6372, 0x791b7f1c545af,
+    0x791ed78808bc1, 0x794127556a2a1, 0x7981a538d4cfc, 0x7a0cc78a4a210,
+    0x7a0daa74807c6, 0x7a20cb6cc2f68, 0x7a30c736761be, 0x7a58e899fa4d1,
+    0x7a968a819cd44, 0x7ad17c58bb90b, 0x7af7cced01f63, 0x7b655736ecd89,
+    0x7bb26282b418a, 0x7c337f39fca2d, 0x7c3c3f1899521, 0x7c5063eb85666,
+    0x7de7e28d3b759, 0x7e84933fab969, 0x7ee1b04ac825e, 0x7eec5345546e6,
+    0x7eff534d23a9b, 0x7f27a09bec2ea, 0x7f9869b2f8ebf, 0x7fafd84dd7f02,
+    0x7fb430eb458f2, 0x7fb97ebf9b571, 0x7fbe836129e8c, 0x80e8a74d29c5a,
+    0x82037078c65ae, 0x829b1c89a7ea3, 0x82e4fefdef793, 0x839bf69e16609,
+    0x84b968885607f, 0x84d5286ec1f28, 0x84ed8145b80ab, 0x8520210a64e94,
+    0x85578738aa383, 0x8588ec3c9813e, 0x85b47c0172d01, 0x87009b8d3bcfc,
+    0x875972479c8da, 0x87be52a88ceaa, 0x87e1a9f9884f2, 0x886738ecd0fe8,
+    0x886d86f2e0045, 0x892c99bf6360e, 0x896eb18bf6699, 0x899ecd2b63d12,
+    0x899fa171d8bb4, 0x8a373b36087d0, 0x8aa8e38e5b396, 0x8ab99da8e11f0,
+    0x8ae99fa9bc006, 0x8c83a9c2923f3, 0x8ca85cc4bfe56, 0x8cebc19ec3be8,
+    0x8d951d01135c6, 0x8e17cff893ed4, 0x8e51f1322e1be, 0x8e77f893ad4b2,
+    0x8e892b752f171, 0x8ee02a0e7150a, 0x8f94d0c67dcd6, 0x8fc05ce2682d4,
+    0x8fe58ffd72726, 0x8fe67a3989866, 0x8ffe828bd7afb, 0x9027c8ba78b9e,
+    0x90825df76575e, 0x90bdf0d7d4101, 0x90d49d78eff7b, 0x9112e7bbc3154,
+    0x918568037b181, 0x927e39383ee6d, 0x92e2d7b848c63, 0x9363a3eae9618,
+    0x93ee302ddfe9f, 0x946049fb4dd40, 0x94c42dd3754f7, 0x94f7082727f30,
+    0x9591c719e7098, 0x963466a9fcd03, 0x96699ce09af35, 0x96bcd541fdfa6,
+    0x970ed2e2a1fe9, 0x9718043659db8, 0x97f370a0b847c, 0x9800314639ee8,
+    0x986e0119fbaad, 0x98c657bb7fc9c, 0x98cf9b5be9744, 0x9a801ed8b68e5,
+    0x9ac9d561b9c99, 0x9b6181729b58e, 0x9b63fa9d09baf, 0x9b7877467e76c,
+    0x9bacd58db7537, 0x9bb1f024e80f0, 0x9bd9f7e9d9ba0, 0x9c122b0cb33a7,
+    0x9c3fe3775469d, 0x9ccdf068534ad, 0x9d0986f1b2415, 0x9deb69a473aad,
+    0x9e57231f20c18, 0x9e5a2eaf1d901, 0x9eed9f6a1462e, 0x9f59344b5dde7,
+    0x9f88d37adf036, 0xa06bd74fcce99, 0xa12447e8a8f52, 0xa1c0ba6322f4d,
+    0xa241b9d2e89c8, 0xa245541f837b4, 0xa2eb745da19a8, 0xa304ac75ddb1c,
+    0xa305044c66594, 0xa32e1ae6d2b36, 0xa38ae3c456f78, 0xa3c149c0e3089,
+    0xa45683f936ee7, 0xa55f63445fb02, 0xa56790bd6df2e, 0xa5b6e59f6ee4f,
+    0xa5b974bf7f70e, 0xa6075ba39c53b, 0xa61941db1fcaf, 0xa61e0beda897a,
+    0xa6210188033c5, 0xa65c6c261ddf1, 0xa6845df56d603, 0xa6b84375aa569,
+    0xa6bfa3fa2467d, 0xa7276efa9e549, 0xa78743681f674, 0xa91084c2ae4f3,
+    0xa94cdd45d0624, 0xa975e53c7b4b2, 0xa97e9a2046e57, 0xa9d409cb31d0f,
+    0xaab06b6c78662, 0xab3c2ec73d952, 0xab5d90af9792d, 0xaba8e873a3e9b,
+    0xabb428ce8fb3a, 0xac2366a0357f3, 0xaca536ad7fb4b, 0xad6d7acdbaee3,
+    0xad98f845e3dcd, 0xadda591384dde, 0xae26fa3c11a8e, 0xaef175a5d4c6d,
+    0xaf27a8653bcb8, 0xaf2a9dff96703, 0xaf3b5f6bfd0e7, 0xb00fe06fecf37,
+    0xb0158d6cac1b8, 0xb0d912752f9d4, 0xb13d22389b6c7, 0xb1adbf6463d60,
+    0xb1cc713c3a08f, 0xb1d534c3c7148, 0xb223c403148db, 0xb249355614f42,
+    0xb2a297242365e, 0xb2e7490b980f7, 0xb45f2f4251340, 0xb5bc9ef71ee22,
+    0xb5c42b66dd472, 0xb5ff2838cd188, 0xb60f44f2f37cb, 0xb63cdc6d216d4,
+    0xb63f7688030e2, 0xb693ab722ef6c, 0xb69c72a2ac5ea, 0xb73080be02b2e,
+    0xb754965fd0d7a, 0xb789ed86e2399, 0xb7939ef376b57, 0xb7c99accc8517,
+    0xb824dc0dd6002, 0xb83cd5bc62b83, 0xb8b38db1151b3, 0xb993febd02192,
+    0xb9a172b8856b3, 0xb9bb82c026c8e, 0xba00dd02cc08d, 0xbad359fa5915c,
+    0xbb11e9c7030d4, 0xbb26e2e0640bb, 0xbb7ffb7bab473, 0xbc127356c994c,
+    0xbc2856ac41a73, 0xbc9738fe6e6ef, 0xbc9f8767eff08, 0xbd4a9da3db303,
+    0xbd8bec24ca63b, 0xbde30bae7fdc1, 0xbe4f8379ffb30, 0xbe63800a97cfe,
+    0xbe6cfe3a073f6, 0xbeae9244cd4cd, 0xbef6a3e9d7102, 0xbf346318fc79d,
+    0xbf465b9d31bea, 0xbf5eeb5a3d3f8, 0xbf70b7f32e309, 0xbfb7cd0f6f125,
+    0xc007c2fabfe22, 0xc011487c100a4, 0xc0fb340e7c1b6, 0xc1228fcf4cd3b,
+    0xc1654c4e20167, 0xc1cd174e9a033, 0xc1de6025bdf90, 0xc2d9097ba04c1,
+    0xc32ad0362ee79, 0xc357677ea39a4, 0xc369b7d961869, 0xc386410b71465,
+    0xc3c4f5717ed8f, 0xc4097072de19d, 0xc4403940c3e75, 0xc47e8ad577bd8,
+    0xc4f8fa5e47e1c, 0xc60b5f2ac0cb9, 0xc644f1a7bcea0, 0xc6508d8221b7c,
+    0xc684f31b3b4d1, 0xc69280b551255, 0xc7017ca610734, 0xc70a7abca343d,
+    0xc7381d31a2495, 0xc77f4bec75b14, 0xc80d7d76d82d6, 0xc849907023668,
+    0xc8615e336bcad, 0xc869ee7dd3ca0, 0xc8dfd22c1142e, 0xc99ee14fa4432,
+    0xc9a64925ff0d0, 0xc9f53b36a642a, 0xca1c292b4c299, 0xcb188608e965d,
+    0xcb3e393cd049e, 0xcbd694fac3283, 0xcbec9597be1d2, 0xcbef07704bc69,
+    0xcc4fb3cd321fb, 0xcce3d08c49e53, 0xce23c1d81faaa, 0xce38d839038b9,
+    0xce5df1b57b4a8, 0xcf26e582c7d30, 0xcfd91e0b3702a, 0xd00adae5ad85d,
+    0xd04712785c5a1, 0xd0a98bfb90229, 0xd0c5da9e9a1d5, 0xd0e3764f14e88,
+    0xd0f4849733195, 0xd1339bce9a686, 0xd18d5cc5123a4, 0xd1e1e233e611c,
+    0xd207af065f7c0, 0xd2289f799e4c0, 0xd34db7722ede9, 0xd3eff7d9db452,
+    0xd46d1b1c1f907, 0xd48e3028c1fb9, 0xd4e5dac62527d, 0xd58199ea9d7c3,
+    0xd59cc3c28a9df, 0xd603e2bee3980, 0xd606549771417, 0xd6b3ad17b57a8,
+    0xd6e83e9c13639, 0xd7c13584f3ca1, 0xd7cef65c2eaeb, 0xd7e66ff1dec7d,
+    0xd821d33e18b1f, 0xd8a6d71db3ad7, 0xd9991c1472551, 0xd9a45c6f5e1f0,
+    0xda46a428eb3e3, 0xda697f09fa601, 0xdae86bc99bbe7, 0xdb165ec342b2d,
+    0xdb8ca8ebca447, 0xdbc1c583d5e16, 0xdbe3485ca31de, 0xdc391ad8e7c5d,
+    0xdc8cc106759e4, 0xdc8e69935f728, 0xdd38ffb66e134, 0xdd3bea55f7a30,
+    0xdd95d737b3c8a, 0xddc567c3737c5, 0xde08f136daf09, 0xde59acc4df394,
+    0xde7da51f2a7b8, 0xde8e0b0c1815f, 0xdebeb568238db, 0xdee3a2f956f8e,
+    0xdeec8b1a479f9, 0xe05eb2078fce8, 0xe0a95dc77b32b, 0xe11dc87d03876,
+    0xe16a91e7e449d, 0xe1c9c54c157ec, 0xe221230dc1187, 0xe2aaba19cf77f,
+    0xe2fb8f466646d, 0xe33a6f97b82d3, 0xe41821ef5fef2, 0xe42f55fb392e5,
+    0xe46f53c5c7351, 0xe4df155939fbe, 0xe576db08ae116, 0xe5a393419602e,
+    0xe5a5b83e6c19c, 0xe5fd877532e12, 0xe608fb0d43b77, 0xe6e050bb1b025,
+    0xe6ed8a27988f6, 0xe7380da52ffc2, 0xe76ed2ca256d5, 0xe81bb62c5e1c6,
+    0xe840065d32062, 0xe84d6bb4f3e6f, 0xe8e7c7d659410, 0xe8fbaac85ed7b,
+    0xe96881bc480ec, 0xe98be7b104e48, 0xe9a2dd84e8026, 0xea2ddee5ea14d,
+    0xea6d01182278d, 0xec289cad87656, 0xec4622686006b, 0xec8d3784a0e87,
+    0xed2d7edabb8be, 0xed383b73da5a9, 0xeddcf505f5233, 0xee993e23622ed,
+    0xeea5616884542, 0xeef4ff7d4c7c7, 0xef110fe86055e, 0xef23fda37dc3a,
+    0xef243f8464414, 0xef2bde40d473d, 0xef3acb350cea1, 0xef739bb845ebf,
+    0xefb00a310a28e, 0xefd9be2bd6047, 0xf034620c8431b, 0xf0604d50d7f1b,
+    0xf0d5ce2dbbae2, 0xf134817910442, 0xf158e3f695fb7, 0xf16427fa7221b,
+    0xf191fdac96339, 0xf1b3dc04dc73e, 0xf1bf7f3121fa4, 0xf20cb6682d8e1,
+    0xf258f11670405, 0xf2e0fcdd17ae1, 0xf340b05a2581f, 0xf37c433a941c2,
+    0xf4aef3c80b9be, 0xf4e4c3b618e42, 0xf5a2e4f4a4741, 0xf5ac129f6bf4b,
+    0xf5e76af0d4c9e, 0xf6499eea31b87, 0xf654a85f0819b, 0xf6d5bdc46feb4,
+    0xf737162577371, 0xf73d224a9fbf4, 0xf78257f3e1641, 0xf855b42cb4701,
+    0xf896b97adc6d5, 0xf8dcf2fec7ac5, 0xfa6d89e2ff909, 0xfb664c7401ee1,
+    0xfc3cd18454ab2, 0xfcd13b1f24033, 0xfd71da4bc74e2, 0xfde5a04f0f68c,
+    0xfe0c70fc326d3, 0xfe5e75eeb72a0, 0xfe668d722342e, 0xfe6ec98ef2f6e,
+    0xfee743af21b45, 0xff08e3cf71d35, 0xff5bddf8deb91, 0xff9644bc4fc1a,
+    0xffc8fe1f8f266, 0x008ff18969332, 0x00bc5ce699921, 0x01665cfb296a0,
+    0x0238aa5e81c6e, 0x02a33f0e12049, 0x02fbf4d7ff83a, 0x0333c8d26fa3f,
+    0x034ac24f431e2, 0x038f5e41159dd, 0x03fdb928850e0, 0x0407250b42aff,
+    0x04e54c80f5fbe, 0x05d798c9955c2, 0x05f7e48a93f21, 0x06a886d2cb1b0,
+    0x06d30b6b1b846, 0x06dde54bbd359, 0x076ea24d3fe15, 0x07a015f4ef2e4,
+    0x07e798dd5ae16, 0x086d3dc645baa, 0x08c673a90fd8a, 0x08f3abfad4691,
+    0x091d8be0e4986, 0x097c1e3bc5ef9, 0x0987b2c44a04b, 0x09dbce0fe3672,
+    0x0a955fcaebef6, 0x0ad7e563a9c97, 0x0ae1589848240, 0x0b0742b24470c,
+    0x0bcb4b7c94edc, 0x0bf9ac4266b38, 0x0c20a531ddaf6, 0x0c43d7e97578c,
+    0x0ceb0aa5fea37, 0x0d6c3601089ee, 0x0d7575f881ed1, 0x0dce3e0f2139b,
+    0x0ddf86e6452f8, 0x0e00b93a6a7d2, 0x0e0f08ce4371f, 0x0ea0a1681bc07,
+    0x0ead1531e5d4a, 0x0ece4b2efb7e9, 0x0f21d41506748, 0x0f3232b013565,
+    0x0f4d7d7873b6e, 0x0f69cc1b7db1a, 0x109f6af16f1d7, 0x10a130c5dbd43,
+    0x112ee23761b16, 0x114c3fafe65b4, 0x11a5808d818e3, 0x122d93a609b49,
+    0x128fe13df9295, 0x12b2017725e74, 0x12ce5ebdf1534, 0x13bc85a64820e,
+    0x13dead3155977, 0x1440efce73f74, 0x1500113eb8c51, 0x15039ce792329,
+    0x157282e2af56a, 0x15b3b7c50c03f, 0x163ce856d04ab, 0x1644e9e49a39b,
+    0x16586a054613f, 0x16d00c360f8af, 0x178db8568f90e, 0x17cf53b33656f,
+    0x18000cb3033ff, 0x18b76acd74401, 0x18be5633e2c75, 0x1956fecd8d383,
+    0x199c172f4bfa8, 0x19db530016e4b, 0x19f0ed22c7c0e, 0x19f821bbfd7e6,
+    0x1ae1564f7787e, 0x1b4af21f2f405, 0x1c50a140f7985, 0x1cf1391bba2aa,
+    0x1df45574819a6, 0x1e7e65478be03, 0x1ea670b56de78, 0x1f68a8b08098d,
+    0x1f9bc88e0a165, 0x20063ff617718, 0x2070d0fcb752e, 0x20b6c14ddb5ba,
+    0x20ddb2eb719ee, 0x2101745fa7787, 0x21646a52c7839, 0x2190b11694476,
+    0x21a97f0b95e99, 0x21d68b7216264, 0x22077b57f877f, 0x2269f1323be42,
+    0x229dfb4bdc75a, 0x22a0990fae72d, 0x22f55cb6786ba, 0x2330b507e140d,
+    0x23369c93a62de, 0x236fed2fbbceb, 0x237c0922fd3b6, 0x238aca2bf15de,
+    0x23d6886a47cd8, 0x23e78f608545b, 0x24170d9f932bd, 0x245ff73402359,
+    0x24a1211b8dcdf, 0x24cbe794c4b4f, 0x2511cceb17a8c, 0x254eacd8f7136,
+    0x2588086fddc92, 0x26d99a69602be, 0x2715224efdb12, 0x27bd344cccdae,
+    0x28a1ce61f2c7c, 0x28d0c18d52fa0, 0x28dccedcd2f57, 0x290e1a422e4af,
+    0x293897889dfbb, 0x29675ec8b9da3, 0x29bc3fb706b58, 0x2a1679747a6db,
+    0x2a196b65e4b61, 0x2a7d8d760252d, 0x2aa87fda7d8d9, 0x2ab88a47f2243,
+    0x2b4d4bb94a23c, 0x2c09cf65bcf46, 0x2c145c6aa7130, 0x2c65fe8bd2136,
+    0x2c8f5ab015477, 0x2ca90b8f4d450, 0x2cf324e9aa3cb, 0x2d18a4e06c146,
+    0x2d6b598002203, 0x2d6b7a70755f0, 0x2d8c80d95658e, 0x2e17564f14179,
+    0x2edee7194da5c, 0x2f5381630aaa8, 0x303addd5662fb, 0x30b07fa2bd2af,
+    0x3111afc1707f5, 0x3115dc7399ca9, 0x311772b3d1d14, 0x31338e19b6bfa,
+    0x3152d2571b5f1, 0x3168d2f416540, 0x31be5142c2b0c, 0x322338f593c66,
+    0x32d1278456c84, 0x32e90b3d41567, 0x32fe637f0bb50, 0x330918c649cb1,
+    0x33df00763d06b, 0x33feb628bcd3d, 0x347e563e5fdd8, 0x34ebce3b98f25,
+    0x359d2b2bb27f3, 0x35be4edc165b9, 0x362d1b38a0f97, 0x3645cf8f10157,
+    0x376198ec65e89, 0x379ebabb2bd0d, 0x388d0d8ec6f23, 0x38f13a99b5a3e,
+    0x390cef8550798, 0x391f08f9f8fd2, 0x3988dbafc61e4, 0x39bcc881e3cd4,
+    0x3a8597b5ccbaa, 0x3ac73a6454395, 0x3dd05c31857c3, 0x3df98c6a845c8,
+    0x3e785be2a2d86, 0x3e9bd07b211f6, 0x3f3337022bd4c, 0x3ff06309cf3bc,
+    0x45e650af470e2, 0x464b29be56b28, 0x47c7096a1415f, 0x484f411bffd77,
+    0x4c3a8eceb9ba1, 0x4c9391745ecbb, 0x4cd0b34324b3f, 0x4cff5992cd53a,
+    0x4d2ca43143b1a, 0x4d3922f5dedac, 0x4dd60e3754c0c, 0x4f4c867029d61,
+    0x4fc41da622382, 0x50a4e2dfa7814, 0x5148cf7d2e186, 0x5410140cd2179,
+    0x542818b62fe49, 0x545b60d60d598, 0x54e4866d008b5, 0x55a880938f971,
+    0x5778e2053075b, 0x5793b7af854c4, 0x57cc1e0f83d91, 0x57f06e4057c2d,
+    0x58311bb7f7189, 0x594c0d25e7a54, 0x5970fab71b107, 0x59d8b713d38bf,
+    0x5af59a4175232, 0x5b30e7980ce36, 0x5b8eeb36502a6, 0x5c8d2ed8cd5c3,
+    0x5da197b1a91e1, 0x5da8f0e44276b, 0x5e0d3edfa4673, 0x5e8ed806d9340,
+    0x5e944322b1de7, 0x5efe4915a40bf, 0x5f0e23eee3f28, 0x5f866728fd474,
+    0x5fa5ba0a2357f, 0x5fd6b4ead6be9, 0x601cf5c0a2b63, 0x60276ccfeaaaf,
+    0x608a7c619d3c4, 0x625f48bd5d877, 0x6298fc2acce4b, 0x62ad6a30802f4,
+    0x637c2f93ef7af, 0x63e0554cfd740, 0x648ef731c2213, 0x64a99d47e247b,
+    0x64c759e8d051b, 0x65044c236189e, 0x657e76225ad43, 0x66287d88cb64c,
+    0x669800e4480a4, 0x66b16fe2998a3, 0x68286f862bf71, 0x686f5c6018e16,
+    0x688493b170012, 0x69030b5305d58, 0x69d3d119e79cf, 0x6a0c252d345c3,
+    0x6aa9f358e09d9, 0x6b11a4bac8042, 0x6b87b7fd3a2d1, 0x6c0e93fdf3ace,
+    0x6c42a1c0849ab, 0x6c570fc637e54, 0x6d1ce20de5755, 0x6db010e7f5ca8,
+    0x6dc7db024dd28, 0x6dec7bb7c9ab2, 0x6dfffbd875856, 0x6e55ce54ba2d5,
+    0x6e83c8a041da5, 0x6e860392ba1b1, 0x6e997166b427c, 0x6f5dbfbadb7eb,
+    0x6f7f5fdb2b9db, 0x70d31e2364cff, 0x7156a90a4aa74, 0x717cc2b87ba41,
+    0x71a9a333b78d0, 0x727864ee367c6, 0x73ed0505ed0d6, 0x7428a68a1d18d,
+    0x7482887108298, 0x74a73b7335cfb, 0x74acefc1d5b06, 0x74fb705d61b85,
+    0x74fd5acb320a3, 0x7528c5f6a92b4, 0x75b848376cd42, 0x75eaa27242d8c,
+    0x7650c88ec34d9, 0x76f1765f2809c, 0x770b35e221789, 0x77d0f9860d976,
+    0x78205962df9e6, 0x790a5741fd7d1, 0x7b6d970906c20, 0x7b9bcbe394340,
+    0x7baf82ea5576f, 0x7c2c1ec1dc6ab, 0x7c500fca46f45, 0x7cd49ae2e6098,
+    0x7cf436f6d3507, 0x7cf984cb29186, 0x7d50577926fe3, 0x7e1b89e1dc23c,
+    0x7e498f2834e5b, 0x7e7647611cd73, 0x7ed6fb0fe3e8f, 0x7ef56b06d39e4,
+    0x7ffffdd9b7492, 0x8066747adfacd, 0x80a1edbcbbc0d, 0x80f18f7a74457,
+    0x811e3566aa696, 0x813b80927d45b, 0x827e00fe63971, 0x82f11ea67b1b5,
+    0x8454cdbd965e0, 0x84d3f8b52dddb, 0x852ed724e1cff, 0x865379ff66d88,
+    0x8662c61c08aee, 0x86b063cd5e5b7, 0x87ebb348ff4ba, 0x887ed37f4e2f9,
+    0x8881330b2a0b7, 0x88db9162015ec, 0x88eb738d21fdf, 0x8924a338c45ff,
+    0x89fef229e6abd, 0x8abde7aee725e, 0x8be4456307d04, 0x8c1ef902306b6,
+    0x8c8ec1e783ead, 0x8d6cb27721ce1, 0x8d81f11a59a67, 0x8d8ab0f8f655b,
+    0x8df19566498ac, 0x8f56e9615e456, 0x90e671701b7a8, 0x9128aa2d21c20,
+    0x9163099eb211f, 0x91f4c328fd9f4, 0x932ad71cfa951, 0x939f123e4e39b,
+    0x9420ab6583068, 0x95aec5765c2c6, 0x9608d6f17bed2, 0x965e887d4d564,
+    0x976eaeae5da30, 0x979b584384234, 0x97e787f6f5c09, 0x9885268e6d51e,
+    0x9956ae4f1235e, 0x9abf82f82f491, 0x9ae62f0beeb26, 0x9b9e1be2fdc2b,
+    0x9c4034085631d, 0x9c5c914f219dd, 0x9d9c56afb30f8, 0x9e0bc7be7de77,
+    0x9e8707e4d2998, 0x9ebad3c67d09b, 0x9fbdec76541d2, 0xa070c608132a8,
+    0xa190f6a6980de, 0xa192f3611a2d5, 0xa27fbaef4e5cf, 0xa31feff8b732d,
+    0xa3bfafe4147eb, 0xa3ebe8041fd14, 0xa45afd9371a56, 0xa797811f12e36,
+    0xa849ab03c0a1c, 0xa94f5a2588f9c, 0xa9baca6d6eda3, 0xa9ea5af92e8de,
+    0xab587d76a1690, 0xabaab5a64b323, 0xabdcdca3fc2a7, 0xabdf263a35dc7,
+    0xac1f78325c2e6, 0xac730c1338394, 0xac8096045db53, 0xacd3f6a814b3b,
+    0xad156d6b57dea, 0xad9770b5c7208, 0xaeb1ed05ac233, 0xaed7068223e22,
+    0xafcf504c2a595, 0xafcfd7b6e7b0e, 0xafd3b3e4690d4, 0xb00874fcfba66,
+    0xb01452b846f1c, 0xb096cec9b219f, 0xb0a6d93726b09, 0xb0a96857373c8,
+    0xb0b5e71bd265a, 0xb17b2aa6e1e58, 0xb19a8c2bc9677, 0xb1c38cd09397b,
+    0xb210d2ab609cc, 0xb29fbb34b5208, 0xb2f30239d998d, 0xb378987f0300d,
+    0xb3dec24473d1f, 0xb498d7c149557, 0xb4b3bfb84ff99, 0xb4b5524f97a3f,
+    0xb5e8ff65d8054, 0xb6925ac827a32, 0xb703537369108, 0xb71fb0ba347c8,
+    0xb786cc0d9d1a4, 0xb7be323be2693, 0xb7f39bafa598b, 0xb8207881f1255,
+    0xb822e3089e162, 0xb82a9b63a0cee, 0xb84dfa067cec0, 0xb8e34a3472fbc,
+    0xba210436c0dcc, 0xba967a18d3844, 0xbac627ec161a7, 0xbaf2eb1fcf20e,
+    0xbb0d0d74224c2, 0xbb3862a9f7435, 0xbb654a7713e4e, 0xbb947488897fd,
+    0xbbb23fcd38fb1, 0xbc79a1033dd93, 0xbc7bb00a71c63, 0xbcec040372f98,
+    0xbdbba8a828444, 0xbedf4ef9e46b4, 0xbeece03cea9fd, 0xbf14e0affb923,
+    0xbf6e8807e0dde, 0xbf7c71216fb9f, 0xbfdc5089c1e19, 0xc03b5802aec2c,
+    0xc05c2b2e6ab04, 0xc0995f49e2661, 0xc17539806ba3b, 0xc1a98cccd36b7,
+    0xc1b4f91303892, 0xc2260f05c7d90, 0xc260a1b47d355, 0xc2bec9ec24177,
+    0xc2d8a6b6a068c, 0xc2e53f19ce181, 0xc3f92f2badf3a, 0xc454f7d778f9e,
+    0xc48f4ff728913, 0xc5d58f490d5c7, 0xc5e98830b51d0, 0xc5f19862407d4,
+    0xc6247a07d3d97, 0xc6b111a90df29, 0xc6b48b0535928, 0xc6dc807d756ff,
+    0xc6edf53fddb98, 0xc71a9ed50439c, 0xc77f40fdfe757, 0xc78cc39d4338c,
+    0xc7a74c6be07cc, 0xc7d0afe204697, 0xc87cd4f36a584, 0xc8993d3506d93,
+    0xc8aec8b3f6442, 0xc8d0b2070d996, 0xc902e7a88002e, 0xc91b73bc9b277,
+    0xca6e1bdd78f1f, 0xcab15123481b0, 0xcab43819e14e7, 0xcacb56301863c,
+    0xcc1dd60ea236d, 0xcca30d2b623eb, 0xccdb2e0189f19, 0xcce9dcbdcc468,
+    0xcd819ec44fffb, 0xcded28aac8665, 0xce3c7d8cc9586, 0xce4043c4a88ae,
+    0xce47576d6b099, 0xce76f2f3fbd23, 0xcf60aef233334, 0xd026554e9c6f9,
+    0xd0d0a5e7d4366, 0xd0d7e924cb652, 0xd0e2e79ed0b17, 0xd109f683e9d73,
+    0xd10f0d722a367, 0xd2cb00de17ca8, 0xd2ccbf60a3c8a, 0xd3361cf8655fc,
+    0xd37a5d6abedba, 0xd3c78d4fe9b6d, 0xd4037f58c1b12, 0xd4656676670d2,
+    0xd4de8548d604a, 0xd50c6d47abe41, 0xd51864a189b5a, 0xd5266007ca5f4,
+    0xd56f07bb52eb6, 0xd59b13f019ea3, 0xd5f772aa63b94, 0xd61595c59bdc0,
+    0xd631bfcf423ba, 0xd6518425837a0, 0xd69e5fdd160a0, 0xd77ce67b32b61,
+    0xd78ec909c5d10, 0xd7a5217d496d7, 0xd826458672b04, 0xd82dd94811cde,
+    0xd841a9ed65970, 0xd8b1dcf5f38b8, 0xd8fe97bd12dcb, 0xda0931e1d7403,
+    0xda8ede1ca2d21, 0xdb99a083bb2d0, 0xdc88318f4c6fb, 0xdcc37b3cf3d3a,
+    0xddcdc4dd10484, 0xde260cdad2f5f, 0xde7ff9bc8f1b9, 0xdeaf0a2f72305,
+    0xdecd7dcf5241f, 0xdf1b7aa9114ea, 0xdf2ce81998df9, 0xdf5c331b81b95,
+    0xe086b62feaf65, 0xe0a68cd2de024, 0xe0dbce044d3a5, 0xe1bcca23e7ec2,
+    0xe1be1ada4918e, 0xe21f733b5064b, 0xe39ffc0923560, 0xe3bfb1bba3232,
+    0xe40b4b6095f7a, 0xe50f8c847a406, 0xe5d958412c0f5, 0xe5f16b8e4b4d9,
+    0xe6155544d51e9, 0xe6218e7f996dc, 0xe64105fa23199, 0xe72d252d26b2d,
+    0xe734352cf8d53, 0xe7a9ff3ca3c7e, 0xe977dc89052f8, 0xe988168aae763,
+    0xe98f85b2e9f8b, 0xe9b0678267577, 0xe9bcba5bbe2cd, 0xea4e09c2cf451,
+    0xeacc557920c5b, 0xead3741cb4595, 0xeb17590f94d16, 0xeb1fff4f9efa7,
+    0xec24e17cd320f, 0xec98cfc26f330, 0xece36cde9925f, 0xed5b45f57805a,
+    0xed5e79c7c8cba, 0xede33bc67d498, 0xee0612fe9c0f1, 0xee148382e842b,
+    0xef413a37e907d, 0xef7197b83ced0, 0xf01337169975d, 0xf0815ec0e3d9a,
+    0xf08884b65825e, 0xf138ae3793688, 0xf1507bfadbccd, 0xf1697984121f1,
+    0xf17377cc5e2d8, 0xf1f5cb9b755e4, 0xf2e3cdea6890c, 0xf384de8c27096,
+    0xf3a67b0386cc1, 0xf3b62d9a72bb3, 0xf3bcaedda6cd6, 0xf421b02f0a693,
+    0xf47e9da5f2487, 0xf47fd866b14b5, 0xf48ccff2485ac, 0xf4bce7e8c5660,
+    0xf4eb11c881c31, 0xf5814fdb7f432, 0xf60ad49adbd51, 0xf62a9c9a0d6fc,
+    0xf698da39f9fd7, 0xf6c7c26a891ac, 0xf7dbd36cdc352, 0xf857b84571214,
+    0xf8b8f707e5e6e, 0xf91cc1417adc2, 0xf94eadb0260f6, 0xf98b2e759c19e,
+    0xfa33fb1b4da79, 0xfae8d8b96f8d0, 0xfbc68eba07ab4, 0xfc337f4c83688,
+    0xfda8ae20d809b, 0xfdb06a24cb1ec, 0xfdc97651c2e24, 0xfdd807c68254b,
+    0xfde52b3d5db7e, 0xfdfa7fd637ba2, 0xfe54bd3c9bcea, 0xff01a447c4da0,
+    0xff1bf2875c590, 0xff9ee3b6d30b3, 0xffdbe83e1610f, 0x004558228975a,
+    0x02a98225a9ce9, 0x02fb5783f9db5, 0x039dbc8509dd0, 0x03ce92cc59a88,
+    0x03df508fcfea7, 0x040483aada2f9, 0x044634fd231f8, 0x04505f30b381b,
+    0x04d436f350eb9, 0x06db1ba900bdc, 0x06f2de71780d2, 0x070539c7070e6,
+    0x07a380b9af361, 0x0814dc364a5fe, 0x08984d7e9db10, 0x08bf675e87ebb,
+    0x099f8b8ebd571, 0x09b35c3411203, 0x0b583c8498b3e, 0x0b59739c675a7,
+    0x0b7b8188e24ad, 0x0c531cc0906fa, 0x0c91b3df1b1fc, 0x0cfacf95f6394,
+    0x0d162cab08676, 0x0d79f6e49d5ca, 0x0dde27987c6aa, 0x0df123f75b49a,
+    0x0f2fb5e90e711, 0x0f451925a9e49, 0x0f61014e69c69, 0x0f807c71e3ceb,
+    0x11204d260bbbc, 0x1175022914435, 0x119e23be51b26, 0x134993f5cec98,
+    0x134d9fb784d5f, 0x136b933e8848a, 0x13c186ab402f6, 0x13c4d41c237b9,
+    0x147acf3381816, 0x14d95de5727c4, 0x1516c1951ee22, 0x1520b139a97f5,
+    0x15680f88b1975, 0x164c6f0ed1bf3, 0x167051737ad79, 0x1690037d0a486,
+    0x16c373df3bb4c, 0x16ccf5b79b809, 0x182a9ffb6ef3b, 0x1883730cdf554,
+    0x18fa9c76ace5f, 0x1914e36463ac5, 0x1a1d045eb9adc, 0x1a52e2f088674,
+    0x1a60a3c7c34be, 0x1a88af35a5533, 0x1a8c2fe3adabc, 0x1a910c42e8460,
+    0x1ab4798985d46, 0x1ad4577e5998f, 0x1bbb5fc31cd2f, 0x1bc8574eb3e26,
+    0x1c517ce5a7143, 0x1c55d92c050f8, 0x1cb2cdf4cda76, 0x1d05b97a791be,
+    0x1debc8df63d0a, 0x1e0c09a59151a, 0x1e18ad039015e, 0x1e2198cd7118e,
+    0x1ee7e7850aeb9, 0x1ff75a60198d0, 0x20593a2bde306, 0x206e6dd444f3d,
+    0x20b4398c05617, 0x214c82fd46723, 0x21bfc195d1354, 0x223e985fd069c,
+    0x227037f2c40a7, 0x228681c28635a, 0x22ce552f99d7a, 0x22f09a022a30b,
+    0x23c3dc9c6ab68, 0x24745a4b3e445, 0x24f1d5640b372, 0x24fff1babf1f9,
+    0x2529602bb4213, 0x255b7c2e94048, 0x2596e323be4af, 0x25b83dba37900,
+    0x25cb3a19166f0, 0x26007b4a85a71, 0x262c9d74eecfc, 0x268753a24eca9,
+    0x26d77921d44a7, 0x270690e69817d, 0x28507cd1c98f6, 0x28c037135b9d9,
+    0x28dca2fde87ad, 0x294e689dbe19b, 0x29a8fa31ba796, 0x2a09b18971e77,
+    0x2a0f7bcdb3f20, 0x2a4e5876157c1, 0x2b12b916eea09, 0x2b63660131780,
+    0x2c1688c5b7bba, 0x2c59503f5c135, 0x2d5d6920ec64a, 0x2d709513fff3b,
+    0x2e4f2dfece6d5, 0x2e57df39a9ab5, 0x2f0bc3f7f30b8, 0x3015fb4b5db29,
+    0x30262dfb2640a, 0x303293212ee39, 0x304a17b1b011a, 0x31734a0fb821e,
+    0x31a57fb12a8b6, 0x32b60c5c84f0e, 0x32c6f60b3f869, 0x32cb4004ebb45,
+    0x3362525e5e1e8, 0x345d65d77ae6a, 0x3469adb600a71, 0x349bcd61d0e6b,
+    0x35d9d43fd65a4, 0x35e2b50ee6485, 0x3705b30571d8f, 0x373f45826df76,
+    0x37cd01eec4e98, 0x37cf09a4181de, 0x3803bc18e945c, 0x3861d203de5a5,
+    0x389e4f2064088, 0x38f693753659e, 0x392f9e877520c, 0x39b3c6ceba798,
+    0x39e62c0461931, 0x3a47e736c29b5, 0x3a4daa2923ed4, 0x3a8ed067bf295,
+    0x3b213d480c61f, 0x3b9b883778eb1, 0x3ccfb1bda58f0, 0x3d078d09f667f,
+    0x3d55f406efe9b, 0x3d71302b8ed90, 0x3db98eac502ee, 0x3e492339c5a55,
+    0x3e508eb910cb8, 0x3ed1b66b2a6aa, 0x3f42cc5deeba8, 0x428bebf5ae042,
+    0x4591efe630aae, 0x4769f3bd32186, 0x479a6adc1883c, 0x48c54d18eb20e,
+    0x49976e90ff2a0, 0x4b7794e63dc55, 0x4cebb88e0813b, 0x4d0d3414f4979,
+    0x4e541bc209f93, 0x4f88a0c7afa0f, 0x4fb6f692b051c, 0x51ff1b258de63,
+    0x5201fe7336bd5, 0x54bf4fb55fc30, 0x54d135ece33a4, 0x55046bc00ee1a,
+    0x55680a0e5f832, 0x56bb065cd598d, 0x56f96a3e3b3c9, 0x5791dbf1d044c,
+    0x581979ec4ce12, 0x5874bed64aec2, 0x58c44e4751a33, 0x598397f9ea687,
+    0x5a86bf4d82ed2, 0x5c8321f919b5a, 0x5e019816c85da, 0x5ed9961fd03ee,
+    0x5f892d3b7d150, 0x601ab388a395f, 0x621bfd8e460da, 0x62226c84c8524,
+    0x62c1ea8f3f208, 0x63bf230531ff8, 0x650b54ddacccc, 0x6537317e3f1b8,
+    0x65a3ca3a32314, 0x663849caa3b33, 0x66639f0078aa6, 0x66755cf5a82a3,
+    0x669c55e51f261, 0x67566f0ae505e, 0x67d1c526dbe1d, 0x68550a83eadf3,
+    0x68f76bdc0a849, 0x6925871805706, 0x69bb6d547a48f, 0x69d4eaf68d3a2,
+    0x6aa2dfbc77f80, 0x6ab2fc769e5c3, 0x6ac5f8d57d3b3, 0x6b0f95bfedf04,
+    0x6b27045accf47, 0x6b33daf5f0c51, 0x6b44d3486ccc0, 0x6b93921beef54,
+    0x6b9faa664005a, 0x6ca19aa209e3c, 0x6cfaa4998fae0, 0x6cfb285b5ca94,
+    0x6d203a85f3af9, 0x6d20507b95d97, 0x6d58edc1a9cef, 0x6de7fae461edd,
+    0x6ea9f4a77e7dd, 0x6ed78c21ac6e6, 0x6f44fccd04ca9, 0x7039c5e902e93,
+    0x70bb2bd312a9a, 0x70f3fc564bab8, 0x7134b871ac728, 0x74374e00d88e4,
+    0x7482dcaafa4dd, 0x7501b71de9dea, 0x755468148f8e2, 0x755caf2c30571,
+    0x769e4cade04d1, 0x7733cc700b0ce, 0x78489bc330e78, 0x786b29c88876d,
+    0x7884ab138bc45, 0x78d018cd3a451, 0x7961857bce3fd, 0x7989b58313e24,
+    0x79d88a4c38356, 0x7a506ab4f7cdb, 0x7ac693ed0c208, 0x7ad0c91b6d97a,
+    0x7ade321c1fd4c, 0x7b29c0c641945, 0x7b336ae0f5579, 0x7b3e5e60298ef,
+    0x7bc2cfda361df, 0x7bef96b6df80b, 0x7c3ac63897e02, 0x7d21d5cf3bd2c,
+    0x7d510389a1ca0, 0x7e5641364ef45, 0x7ed4ec1509d51, 0x7f0dfe7929549,
+    0x811f9c1f07992, 0x8124ccabda7e9, 0x820b5880b175f, 0x82c4960e21b30,
+    0x8401a7b53efda, 0x8517e50648e78, 0x85bcb0e5157db, 0x85be9efbd62be,
+    0x8871d4ae3040a, 0x88c74baafbe4c, 0x88d0bb36a9e30, 0x89607baf63ad3,
+    0x89773e4621beb, 0x8a5eb0ae1f6dc, 0x8b450ceec1b51, 0x8b5e5001cee14,
+    0x8b90cb2d1824b, 0x8bafbb3ce478f, 0x8bb7b921be0ba, 0x8c5cbf8f9066d,
+    0x8c67195755791, 0x8c741f86adf9c, 0x8cb67cdd17dc6, 0x8e3991220afd5,
+    0x8e976c7dfa4ce, 0x8ecc6bce83075, 0x8f004da5cfa16, 0x8f4eef31cee82,
+    0x90035b5ad59fe, 0x90fc3b335adfe, 0x929cfd757a999, 0x92cbe5a609b6e,
+    0x92f3a08f43cf5, 0x9317e21c5647d, 0x93ae0a39b19e0, 0x9470a5061e0bc,
+    0x950842733e29d, 0x9575838a61d5f, 0x9654410e93eab, 0x9685eb9c58a05,
+    0x96c8b315fcf80, 0x96d7f437cdb97, 0x96ffe6071d3a9, 0x97e078037d775,
+    0x97e307238e034, 0x9881bf8b5158a, 0x98b7acc0e1836, 0x98c8e34b53aba,
+    0x99632d2007382, 0x997266eff740f, 0x99b2ec25429f4, 0x9ab58c0e1dcc6,
+    0x9bc8c5210ba05, 0x9c8c1e3e4ace5, 0x9ce0706ff9997, 0x9da56ac841e31,
+    0x9df09a49fa428, 0x9e0fc4e8cc5bc, 0x9e117c1977a14, 0x9e8ba9c16147e,
+    0x9eaeb436a519d, 0x9f2c5ee3a6bcb, 0x9f7d5500b0ca6, 0x9ff7b23ccf211,
+    0xa04ca1cedd6da, 0xa074b0e5afd14, 0xa0904889c7c46, 0xa0f98c82f6d55,
+    0xa17619b6bc57d, 0xa1c0b6d2e64ac, 0xa2bea9f0bb8db, 0xa34417f390fe4,
+    0xa3dfde69ea0b4, 0xa3e67cf4a0fff, 0xa42b8309adf4b, 0xa5e8f6c031659,
+    0xa5e91407b4481, 0xa5eb6146de566, 0xa765fb370ba20, 0xa8a7e93d6386e,
+    0xa8ab922dbfd6e, 0xa960353cdbf75, 0xa9dfc6aebd8fc, 0xaab8f0d4c302a,
+    0xaad3291eb857c, 0xac00c2bdef784, 0xacf1df40a0ea9, 0xad288ac703d59,
+    0xae9fed3beffee, 0xaeb7c9a2f9d47, 0xaee3e47b82448, 0xafbd6a2100bb3,
+    0xb02543c53c193, 0xb0e51139a1d9b, 0xb12b9eeb2563e, 0xb12dec2a4f723,
+    0xb1a189eb43956, 0xb2681e2cb4420, 0xb2690868cb560, 0xb347df8b8ff0f,
+    0xb61e8d7f58a90, 0xb64999826669f, 0xb6d1b795bfa54, 0xb7eaac492e128,
+    0xb84f97a4ef847, 0xb930d1fc80579, 0xb953c67c21ffa, 0xb9c1f1cf5cbfc,
+    0xb9d92984265b4, 0xba24e0709c124, 0xbb1acaaec6ad9, 0xbb31650330c7a,
+    0xbb7825f1d95e3, 0xbbf0692bf2b2f, 0xbc30968ab569c, 0xbeef1ee4ad160,
+    0xc0a14e975460a, 0xc36efacb99ebd, 0xc3951f749bfd9, 0xc40e334c39e02,
+    0xc44bfd76305ec, 0xc51de0b64e469, 0xc5396264c40fd, 0xc543854673b96,
+    0xc57775c181c4b, 0xc6a41a29d0bc4, 0xc6ba9e8898ac7, 0xc6f86160ae727,
+    0xc79778f0db27f, 0xc7df8e3ed5479, 0xc833ca7ae1e8d, 0xc86b469ec961a,
+    0xc8def3037ef61, 0xc8eddff7b76c5, 0xc90f7174461a1, 0xca364446724e7,
+    0xcab68cb745ee8, 0xcb908eccb0a7d, 0xcbf08b7c85b1f, 0xcc27fca59c15d,
+    0xcc783471d3634, 0xccd7c6fe6df85, 0xccf9c29e371b2, 0xcd7e9340ad0a4,
+    0xcd7feb48eeefa, 0xcd8c3a795568b, 0xcdd83a989255f, 0xce507a29bb4e6,
+    0xcea073bdfc7a8, 0xcea53dd085473, 0xcef04166f952e, 0xcf28a7c6f7dfb,
+    0xd000dcb61529a, 0xd02d2e74b3026, 0xd055990afe69d, 0xd09c4b55e58f2,
+    0xd105c9de1a651, 0xd1776ae48c68d, 0xd27dd10546c87, 0xd29cde5c95ff3,
+    0xd32f17ffbe2b7, 0xd47189c7e30b9, 0xd51e7824eccf9, 0xd55f7d7314ccd,
+    0xd56affaee7146, 0xd5cb2bf2f0ce9, 0xd651f1fe08248, 0xd6bd8a8841fc6,
+    0xd740571e55137, 0xd750c45d23668, 0xd768ed9fe4cea, 0xd821f4473fa30,
+    0xd8bfa8d4595e3, 0xd909964372022, 0xd963e5f687e43, 0xda0b74328a12b,
+    0xda1c73d6e6d24, 0xda557ee925992, 0xdb38707161b1c, 0xdc6c19deb1b6c,
+    0xdcd4ae2acf78b, 0xdd3b9d92f3c2b, 0xdeda4d24ef331, 0xdedd132b1527b,
+    0xdee6c84099ffe, 0xdf498e9f855af, 0xe008bb0a9b3db, 0xe0608dea52616,
+    0xe0aee29a9a159, 0xe0eceafc86b58, 0xe11b6560eb017, 0xe1c7622ffcf8d,
+    0xe1e13056b7d8e, 0xe1e64744f8382, 0xe343d7ea39251, 0xe347d8b11e1c9,
+    0xe3a87dbc23bd1, 0xe422fbe8b5529, 0xe44ce320a63a8, 0xe51b719e001d8,
+    0xe7225da590a85, 0xe83fd6e1b1085, 0xe893dc37a840e, 0xe8fef4a90579d,
+    0xe92c937514230, 0xe93ebf366e743, 0xea371ef617154, 0xea3fdb2bc3683,
+    0xea67c20041d46, 0xeaca9359fe446, 0xeb1fdac295387, 0xeb3699b062eda,
+    0xeb8b38bdc94b5, 0xeba715ebb8186, 0xed6a53e67d93d, 0xed8ee9a128578,
+    0xedf959b754fa1, 0xee0b64883c0c7, 0xee80c0cbbc2dc, 0xeeedebed3db00,
+    0xeef01f8dd5382, 0xef378185cdac7, 0xef3805479aa7b, 0xef392a12b780b,
+    0xef62c0c60079c, 0xef9188061c584, 0xefe90e0a1be96, 0xf1dddcf413944,
+    0xf2427f1d0dcff, 0xf2934cf7c3e63, 0xf38fcac5d4614, 0xf3a81ff3da1d2,
+    0xf3e5999928ace, 0xf41605bd3e035, 0xf44d38ae5e45e, 0xf5cc3636ca457,
+    0xf611cb08754a6, 0xf689f84cec754, 0xf7108b1adebed, 0xf78fa01cd414a,
+    0xf83dcce38d37d, 0xf8497761b376d, 0xf8a5aa30b8f22, 0xf94996ce3f894,
+    0xf964184afc14a, 0xf9b98f47c7b8c, 0xf9f4d1a38e641, 0xfa45c7c09871c,
+    0xfac4ca75dbfa0, 0xfacef1007bffe, 0xfadad9b698603, 0xfb21a94902680,
+    0xfbb65c1698f65, 0xfc2c93f26eba6, 0xfc3e608b5fab7, 0xfc67d9f725c20,
+    0xfcf6b033c8783, 0xfe95512202775, 0xff7292048f0b9, 0x004bb881a4222,
+    0x008c191d8be55, 0x00f4a26ed8925, 0x012ac68a7e25c, 0x01efa39b438ce,
+    0x0229b6311c4a4, 0x02944737bc2ba, 0x02a0498c6b122, 0x0394495cc55b9,
+    0x0507e599d2526, 0x0599599a4705c, 0x076f0c892e08a, 0x080f92173ecd6,
+    0x0a7e2485e5a9d, 0x0afa7381b50b0, 0x0afe2b15d2cc4, 0x0b0d5d93e21c7,
+    0x0b670c3da820c, 0x0ba9156679b83, 0x0bda890e29052, 0x0c37eba31c6e6,
+    0x0d579c28c4b2d, 0x0dfc81a623cf3, 0x0dfd0cb9d1831, 0x0e5ffb5b10d59,
+    0x0f463a54303a6, 0x0f912f46e2d4d, 0x0fd1f2b424547, 0x0fd8062b2d954,
+    0x105817b5ebcca, 0x110bf1796417e, 0x113dda3f1eeed, 0x12eec4363621a,
+    0x135fb1e6a67a1, 0x13b430039998f, 0x1428886c70201, 0x1439512ab776f,
+    0x1446d772ec969, 0x144d6759e21a0, 0x147f69be2f772, 0x15ee7677b9664,
+    0x16917c821945b, 0x172f769909dad, 0x172f9b326d75f, 0x17754250ca487,
+    0x17e6c60fb969b, 0x17ed60f180021, 0x18d8714f08ec3, 0x18ff076d262ba,
+    0x19ddaefbb6168, 0x1a2c99ba7c938, 0x1a2d162a68d62, 0x1a81c78481016,
+    0x1afcd0c4c04ac, 0x1b36b01d73fbc, 0x1b6891914e1a1, 0x1bb9cd382f01b,
+    0x1cae9da60dd8f, 0x1cf126e7bc0f5, 0x1d3be4f459411, 0x1dbfeff41bb75,
+    0x1e01c236d7e61, 0x1e3fef3228212, 0x1e41ebecaa409, 0x1e6613db2a32e,
+    0x1eef27256b972, 0x1f0df29bd4504, 0x1f81bff0fd238, 0x1f850d61e06fb,
+    0x20700077e6775, 0x20a02e6405ac7, 0x20a79d8c412ef, 0x21774982d7325,
+    0x217b9ace6418b, 0x21bba73cb390b, 0x22a9d576eb16f, 0x2361ac5857fd6,
+    0x236db255f7403, 0x237e1499f47e5, 0x23be87828e0f1, 0x23fe73006a484,
+    0x24490521c3264, 0x24614bac0770e, 0x2492e04429fca, 0x249764ccdbef6,
+    0x24cf31756b571, 0x25fcaa242f38c, 0x26571375d7a10, 0x2703ce95bc58a,
+    0x270d506e1c247, 0x27661c2dabcd6, 0x27764b3483ff2, 0x27843f48e3f02,
+    0x27fec11e65e1f, 0x28ec08c576b08, 0x29c6e673370c9, 0x2a68180568c40,
+    0x2a76679941b8d, 0x2abd8f0234682, 0x2af9a94d6059e, 0x2b3681e95f0be,
+    0x2b4150cf2fa82, 0x2bcc13f83b994, 0x2bf7c4ad89944, 0x2bfbaf7ecc61e,
+    0x2c3958b84fa1b, 0x2e795accf0085, 0x2f4bd41b8cb8f, 0x2f51f636576b0,
+    0x2f5568409e525, 0x2fa0264d3b841, 0x30b68be099656, 0x30dabec9ea6ca,
+    0x31ef6983acac2, 0x329ec9b944199, 0x3341ab2a405de, 0x33c46cc582600,
+    0x33fe3d7a749fc, 0x35bb5d035fc57, 0x36245f1ba858c, 0x36693cee61561,
+    0x36780c9b16e9d, 0x3779ac5238d91, 0x384094c141d0e, 0x384bdc6e0e537,
+    0x3b1303b62f702, 0x3c6270b2dbbc0, 0x3c7c3b30a63fc, 0x3c9e80033698d,
+    0x3d8b6881de074, 0x3d9829275fae0, 0x3de6bfb88ddfd, 0x3e5c47e75254e,
+    0x3e9bb34c51ef2, 0x3f853c0d6443d, 0x43b5ad1cadfdb, 0x47068c6150b8b,
+    0x481f79c2de6d5, 0x4915c3297268c, 0x4b378fd628df1, 0x4c0893d500c7d,
+    0x4e17e43bb4fe1, 0x4ec9de8c2e0c6, 0x4f6f392798b2c, 0x4f80c036b2c9e,
+    0x500ac8b7dc571, 0x5037799ee38ff, 0x51b28ffefd1e3, 0x52e29b76c1e82,
+    0x54a82307c1159, 0x55b0e50b66f4c, 0x56a4e132d0e1e, 0x56e792b6d30fb,
+    0x5701e8484b475, 0x5754366d973a6, 0x579da3c3d33f6, 0x57fb4f8b8ddee,
+    0x58d6a6004a214, 0x59b5dff46878a, 0x5a933323a6da7, 0x5ab56cfb661e9,
+    0x5b8cacb39b3f9, 0x5cab72fd2c700, 0x5d98b6fb4ce24, 0x5dc613e6750dd,
+    0x5e2cc8bf9392d, 0x5e43b74195f81, 0x5e9581a514efe, 0x5f1f5e3afa295,
+    0x5f4109561b5d4, 0x5fe86f4fc9945, 0x60e8baa799fa8, 0x61c0d9a1151a9,
+    0x64680cfec048d, 0x6508d46db78b3, 0x6613723b6c4b0, 0x665891ef0bc5f,
+    0x66e007a7346ae, 0x689fb9f92078d, 0x68c1ae4708e30, 0x69c14d9ab8568,
+    0x6bc40cf01f961, 0x6bd743de043a1, 0x6bf5b02c03931, 0x6c85de70e82ea,
+    0x6c9a5b1a5cea7, 0x6cd05e458f3f1, 0x6f8f87a8d6c91, 0x70150ba14e638,
+    0x7061723ad5698, 0x7067143cc37ca, 0x712108c925c15, 0x71a042647eb24,
+    0x71f352838dc1e, 0x71fa3de9fc492, 0x72a5ea346651a, 0x733f427b22118,
+    0x73803cce78f9d, 0x73a1ce4b07a79, 0x73d9ad4048dcd, 0x744d6bf1b03ed,
+    0x753e8fc64269c, 0x756ff1213fe92, 0x75e015860c6c6, 0x75fc25f12045d,
+    0x773571605e5df, 0x783a9917695e6, 0x7929fac07f2ee, 0x79d038462030a,
+    0x7ad2755da1a15, 0x7b4b76e88db65, 0x7bd085c2f9c6c, 0x7bf956d38f46f,
+    0x7c703ab385fdb, 0x7ca8edef3c1d1, 0x7cf1c536f9594, 0x7d242a6ca072d,
+    0x7d887fb9e31bf, 0x7dae0aab76089, 0x7debc631ab15f, 0x7df677cff8cfb,
+    0x7e375c2dad8e2, 0x7f4f286d0ec61, 0x7fa9622a827e4, 0x7fc3eaf91fc24,
+    0x80af7f1875a7a, 0x813f592fc1f80, 0x81661ee213e78, 0x85025d3ca3b20,
+    0x859ff82b2ae70, 0x85a024166f3ac, 0x85ad6c26ae391, 0x85b0cbe44352d,
+    0x86055ff6d89b9, 0x86271d5eab9d1, 0x878bc5559f650, 0x88b4ff05882de,
+    0x89b16a86e6db6, 0x8a38e03f0f805, 0x8b03879416f20, 0x8b11570f1347e,
+    0x8be849eb90d65, 0x8bfa49c1a6d3c, 0x8d253aa23ae22, 0x8da3531b67566,
+    0x8e219b28c87ab, 0x8e542ff2a4445, 0x8e8f306d84720, 0x8efd755f51b85,
+    0x8f7df85f2b1d6, 0x8f8d8a05a3cdb, 0x8ffec83abc150, 0x904c5e9a3108f,
+    0x90ff7663e637a, 0x912af0331ec9f, 0x923999c289963, 0x945c07788fea4,
+    0x94b732c3fb6f1, 0x950ca9c0c7133, 0x957deb9ecfb6d, 0x95b76bcf1a07b,
+    0x983d5e34de771, 0x9a62625cd60fb, 0x9a7486cc4fa84, 0x9af54bad0f8af,
+    0x9cd78fad43848, 0x9de1dcf650557, 0x9f3e95ac2bfbf, 0x9fe16c22572b5,
+    0xa0271340b3fdd, 0xa0611389daeda, 0xa1c467217d2c8, 0xa1e52f5268051,
+    0xa276a6fbcd14c, 0xa3396a0a8d79f, 0xa3505c35803b8, 0xa3ef05f9821fa,
+    0xa3f50e75ba4b8, 0xa49e27f7236bc, 0xa4a2a52df4a5e, 0xa56410347325b,
+    0xa7b1e1c40fe23, 0xa7bffa71d36e5, 0xab6eebf87ae19, 0xab972e4c72519,
+    0xac77736d1afbc, 0xad6e185327fb0, 0xadfbd8686f497, 0xae28e4ceef862,
+    0xb021e06b107c4, 0xb0441a42cfc06, 0xb164cea3219f0, 0xb19c387a574a4,
+    0xb280fe7ac18ae, 0xb2b7ef8afb4fd, 0xb34e59893d23a, 0xb396294d60695,
+    0xb4de9896ec606, 0xb58bffbaf20ab, 0xb5e7275d6d333, 0xb604f64b0d0ac,
+    0xb661f265b65b4, 0xb6c8864e61a17, 0xb77db4712b75c, 0xb9583c6d6c5a4,
+    0xbaf92a9ad067b, 0xbb73d85b96ad4, 0xbc0085f272f04, 0xbc5600982ef0b,
+    0xbd370b5b8b13c, 0xbd8f53594dc17, 0xbd9884ad059e6, 0xbdb73684dbd15,
+    0xbea3f6c12f485, 0xbf1121e2b0ca9, 0xc01545bf1230d, 0xc1d720b6c4b1f,
+    0xc22842bf13136, 0xc2fb93fd150a7, 0xc304fc36e2501, 0xc3b437d316226,
+    0xc3bcecb6e1bcb, 0xc493c99dbd214, 0xc6c53a3d9e157, 0xc76ef871476fc,
+    0xc772c5fb075ae, 0xc783074e915a3, 0xc999dcd3233cd, 0xc9d6947eaeb00,
+    0xcc8c470467832, 0xccc13f030f84f, 0xcd2d6649e76d0, 0xcd357a2463299,
+    0xcd410b03f6e26, 0xcd4e4bc255281, 0xcd727eaba62f5, 0xceb9506319671,
+    0xcf1e6efbffe56, 0xcfb4bbb2bed6b, 0xd0139e92481cc, 0xd04332c6f82cc,
+    0xd098bfb965fac, 0xd1d371d4a7698, 0xd1d5a91e2f4df, 0xd31032f71cc54,
+    0xd52095dc3c06f, 0xd5f8f6b69da4a, 0xd6071a5f3245b, 0xd61faa1c3dc69,
+    0xd647f0192592e, 0xd66fde3f84b7b, 0xd7009b4107637, 0xd711087fd5b68,
+    0xd7527f4318e17, 0xd8646b4895e4f, 0xd8c0847908da1, 0xd969e72d39309,
+    0xdb10232ef305f, 0xdbb049949a6a9, 0xdbeeebadf62fa, 0xdc0e63287fdb7,
+    0xdc39b10c741a0, 0xdd739d85020fe, 0xdd9c9e29cc402, 0xde4d700638192,
+    0xdf8fbd34f95e2, 0xe190195594658, 0xe50c7d6440c7c, 0xe5c8b08c0ba98,
+    0xe62ace8fc66e3, 0xe6b6cc7991623, 0xe6ef3a2b70a7a, 0xe73f33bfb1d3c,
+    0xe78a1a0ea2fcf, 0xe7f059c9b5f7f, 0xe82914574ccff, 0xe8b78b6b86260,
+    0xe8e0552a3aed9, 0xe95a115d09668, 0xe977cdfdf7708, 0xe9dd2aced4102,
+    0xeafe0ec35a9ed, 0xed08b9b0e9a38, 0xed7b6d35c6b2b, 0xee6d396589740,
+    0xf0318635c99e9, 0xf0638febf7b45, 0xf076590db186f, 0xf0b9a44922d9e,
+    0xf1a4a602ea52c, 0xf1df81e466e55, 0xf1e6f85e83207, 0xf20afbb39f77a,
+    0xf2d8e57eb9209, 0xf3454afd8729f, 0xf4615d8da4335, 0xf4bd59769445f,
+    0xf68b8af08df8c, 0xf70d78455b10c, 0xf73f64b406440, 0xf7c74be14a16a,
+    0xf7e1ee4e79e0d, 0xf84ab5d7bcaf2, 0xf85ae887853d3, 0xf8ee176195926,
+    0xf985e0b9fa043, 0xfae0c8a097df0, 0xfc0ff88007063, 0xfc53142743a91,
+    0xfc6f0e9cb558a, 0xfc8f446811c4b, 0xfd3b6d22680fd, 0xfdd1744f50273,
+    0xfde2ae82b2abc, 0xfe36ac86c92bb, 0xff097a02fe278, 0xffe33a3782633,
+    0x0011b844d70b7, 0x0024fdd67d20b, 0x0248940090aa1, 0x030b486b8f9e0,
+    0x03ed1528aedda, 0x047e31529ae98, 0x05b38a9eb57b6, 0x05b75f7a561f2,
+    0x06a068228bd4e, 0x06d099b79b665, 0x07f15569cdfd9, 0x07f5896dd8017,
+    0x086fedfbd710c, 0x08abf9a341914, 0x0922aa46133ba, 0x092fc66b0de63,
+    0x09a894b8d4eed, 0x09f561cca60d9, 0x0ad9bda9d5d92, 0x0b05eacf1016c,
+    0x0b5fa81c978c5, 0x0c0fcdf4e272a, 0x0c6035554e702, 0x0c69bad69e984,
+    0x0c8f337b7fb75, 0x0ca04cbe6efd1, 0x0d22ffb5ef8df, 0x0e39fb57cc381,
+    0x0e5141b05744d, 0x0eb69e8133e47, 0x0ecb645d6fd68, 0x0f4b3f0218a53,
+    0x0f89943fbcd7b, 0x10cfdae3825b9, 0x126931a656ef1, 0x136f97c7114eb,
+    0x14097eca6b1ec, 0x15f28245c94bd, 0x1680c61cdd958, 0x17077d84337a3,
+    0x17a11efdb6705, 0x1875915de4e41, 0x18c6f54719c32, 0x1940ec08ee011,
+    0x1a93a6767d992, 0x1a97112ee3c7d, 0x1b1a52e30268e, 0x1d61739b36632,
+    0x1db5dbc287582, 0x1dd38616c3949, 0x1ebd1280c6459, 0x1eeccef7ca4d0,
+    0x1f9ea4aedfc03, 0x1fa3a5a77df59, 0x20004d948efae, 0x23f4bdf73f493,
+    0x251d9c27af0e4, 0x260c4a7ac3337, 0x2639065c9b814, 0x26dbb0e7825ce,
+    0x2706233320f8b, 0x275feb7b79833, 0x27a9084d0d995, 0x28028b0b8f49e,
+    0x29f45d094b977, 0x2a26372b44fd2, 0x2aa5a7acb356c, 0x2ab490f7fb70b,
+    0x2afd85873b8f6, 0x2b4fb2bc1443a, 0x2cd251e2fbda9, 0x2cd3a2995d075,
+    0x2d6e987231868, 0x2ee8e586a73f9, 0x2f5ae5b582a37, 0x2ff070727e783,
+    0x30f8d6f6ab539, 0x31597faaa1506, 0x3250ba9f2d187, 0x33985cf424de0,
+    0x34a5ae7b4dc4e, 0x3505b27d0387a, 0x352c698b9405e, 0x35946f1b13b7a,
+    0x35a480da6906e, 0x35fe2bdb3eaee, 0x362a9e8a4fc67, 0x38570a88a228f,
+    0x3a15cb4c966a4, 0x3a45632a36d69, 0x3a9f8a9af8c13, 0x3b03e3912bc6a,
+    0x3bf66316f0334, 0x3c0068b11cfa5, 0x3c3ca043cbce9, 0x3c6de0ae560f2,
+    0x3e33faa4e3a91, 0x3edd22ca0e3a9, 0x3ef2e18622b1e, 0x3f7c629c8ee78,
+    0x3fc377b8cfc94, 0x3fd5cf656e6e3, 0x41e70a39f2f65, 0x4431ed8115c6c,
+    0x47154d769eb45, 0x499e31cdcd6c1, 0x4a7880beefb7f, 0x4be2f2fa258a7,
+    0x4c5a70918b665, 0x4d0e2212afba2, 0x4ea53a3a1bb09, 0x4f50248ac29c8,
+    0x4f8f51b7cc157, 0x52cca2380184f, 0x52f0f9bab6275, 0x5474f492cffff,
+    0x557673597eb06, 0x5649b24aced9e, 0x56683be051156, 0x5700ad93e61d9,
+    0x57c1b5c90ae0f, 0x58066f0260432, 0x582bbbbbfd0e7, 0x598b20d96c236,
+    0x5a194768fd8a9, 0x5a298c6577e63, 0x5a673947eb825, 0x5a91ed74709bc,
+    0x5adc4c58a46d6, 0x5bd3d428e7c80, 0x5c15c3b326d94, 0x5d0d2341163c7,
+    0x5e7adf443efed, 0x5ee3c41504afa, 0x5f51bc2b1a636, 0x60dba5e0d9e1b,
+    0x62f3cfc4bd4d6, 0x63c41cc4a32e8, 0x65a990ee3791c, 0x66cbcceb0005d,
+    0x693e935db0e62, 0x6ad4cc43d6dd8, 0x6b3b6b275338a, 0x6bd826d4946e9,
+    0x6c1b009aea93d, 0x6c990e1945f32, 0x6c9da8979a0fc, 0x6cb0d48aad9ed,
+    0x6d53fb8580bd1, 0x6d588eb1f4211, 0x6d66a00dd6f49, 0x6e8779078c6e5,
+    0x6f2f541f462f6, 0x6f3fc8aff53b1, 0x6f6bb79d39576, 0x6fc89a195021b,
+    0x715c05a76f6bd, 0x71fe5109ece75, 0x720752c970143, 0x735f32c9017cc,
+    0x739c2c55736d9, 0x73a1c35c906bc, 0x73a3649799876, 0x751afa49aabd1,
+    0x764f570cfc6d6, 0x768c41f5acecf, 0x77c614cfa85ca, 0x78d43a9d462da,
+    0x795f13bbf448a, 0x7acedb1d609bb, 0x7b86feda8514b, 0x7bb3babc5d628,
+    0x7be0cacbcdfb8, 0x7c691c1c4c433, 0x7d5abc60cab0c, 0x7dd9d8b4a0bf3,
+    0x7decc2c6cdd0a, 0x7deeeb6c9443d, 0x7eee53da2e4ea, 0x7f7ac33914705,
+    0x7f964fe25b4e8, 0x7ff5f112b754d, 0x8165e0b6779f5, 0x81a606c3599d8,
+    0x81e8a5faa9fdc, 0x82ad53773ab4d, 0x83687b727b43c, 0x858180eeb4523,
+    0x85c73307e239a, 0x86d4eeb245959, 0x88112869fd99c, 0x89ee766c6472e,
+    0x8a0802b238d55, 0x8acf937c72638, 0x8c5f5d6c16164, 0x8c9d7471c4277,
+    0x8cd114682a43e, 0x8ce97f8bd229a, 0x8d2fe4fb01bc6, 0x8d33e5c1e6b3e,
+    0x8d9dcac465a29, 0x8e4834fc2fef9, 0x8f396774838bc, 0x8fa2e253c8056,
+    0x90dea34484234, 0x9198e8558e56d, 0x92205e0db6fbc, 0x923632bf6d9cf,
+    0x928236879ae68, 0x92a86d7d4ec5d, 0x97ad3af724c99, 0x98a4a57fe541b,
+    0x99477f9f00cd6, 0x9acb8571ebbaf, 0x9b668d977207b, 0x9bf1a4ee16440,
+    0x9ca865a827c2b, 0x9d4b979dcbf5e, 0x9d8da8187e45f, 0x9eeb9b8f18ef5,
+    0xa15c270f518ee, 0xa1a272dfee9b7, 0xa2cb0f2f77e2e, 0xa54e0fa3d209e,
+    0xa588d9389ccee, 0xa5ee868e215d6, 0xa67532faa62d2, 0xa6ef435b0cf14,
+    0xa71a9c39d244c, 0xa77f62fc301b9, 0xa8395036b1a7a, 0xa93fe242b05b0,
+    0xabddcaae11262, 0xac10373598f85, 0xaca89a456c8f4, 0xacc2e885040e4,
+    0xad5922ef11320, 0xad5eed33533c9, 0xae0468bf3121c, 0xafc6bc7ddf893,
+    0xb2c29291e1717, 0xb3590edcd512d, 0xb5cea9f96d590, 0xb6fb470fdb97f,
+    0xb77e6081a6419, 0xb7b33b38cb60e, 0xb8275569abc6b, 0xb8b453852ff89,
+    0xb8c9ccb76d95f, 0xb8d0ca6a8deac, 0xb8f738465732c, 0xb928edceecfd5,
+    0xba9fba355a5dd, 0xbbe637bf354a6, 0xbc97b948b2726, 0xbca74e981b7f0,
+    0xbf70ca714762a, 0xbf9bc427a3560, 0xc240fe73bcc12, 0xc2901c6fa84a8,
+    0xc2aa7953013ac, 0xc4544bf865929, 0xc461472cecfe5, 0xc56bc0613e230,
+    0xc5fb00c11b4e4, 0xc6675b451842b, 0xc7b2fab804a37, 0xc8285e4d657d6,
+    0xc842b3deddb50, 0xc97f1d2aca694, 0xc9990c41f8882, 0xc9e0250729c63,
+    0xca35cb982a1a6, 0xcadd17f345cb4, 0xcce6dc4dae184, 0xcd45884721f5a,
+    0xcdf3d5fe4e57a, 0xce14f605c1d7b, 0xd139af770b0db, 0xd164bf23092af,
+    0xd32be4462117b, 0xd3fa2d39a420c, 0xd4b87a6374047, 0xd50eb702f3217,
+    0xd514fdb7216ea, 0xd5867dcd20339, 0xd7b6dbee961c5, 0xd7f4593cd5086,
+    0xd84c9296d644d, 0xd8770c3455994, 0xd8debd963cffd, 0xd8e68f8fd23ec,
+    0xd93593ed2b41f, 0xd9361f00d8f5d, 0xda6ef8fafbe04, 0xda93ea351fa7c,
+    0xdaa32b56f0693, 0xdb98b66cb1a46, 0xdba6cb7184d43, 0xdc545385fdbd5,
+    0xdd2fa9fab9ffb, 0xdda86252dede7, 0xde18c4efa1830, 0xdedabeb2be130,
+    0xdf06313015ecb, 0xdf4f604e5bd06, 0xdf71424f926d0, 0xe040e34b575b7,
+    0xe119ec80e98f8, 0xe218461908eb3, 0xe24856bda53dd, 0xe2ab95e38c7f3,
+    0xe309db62b643d, 0xe398a2fb9788c, 0xe3fc489bc8e2e, 0xe47c10f3bfe40,
+    0xe514cbda1c227, 0xe550c8ddc531b, 0xe5793ac5f151c, 0xe5c58f12c68a3,
+    0xe606adff810da, 0xe64047ce5de4b, 0xe645a7ef657a3, 0xe657a41c8b1b5,
+    0xe6d8ff0bc9c6d, 0xe6df9644a002e, 0xe845be8629a7a, 0xe8f76bfaeb236,
+    0xe9610078c2233, 0xe9995bddef9b1, 0xe9e83f4ad55f7, 0xe9ed4795544d7,
+    0xea466b2b6c9de, 0xea47a9951bfd1, 0xeb0b1ff9de0d9, 0xeb0ff15e4792e,
+    0xeceb932ad43b7, 0xed2914220383d, 0xed73fe19e5095, 0xeee4926fe58de,
+    0xeee837b751819, 0xefd059cc607fa, 0xf0b8a423c3752, 0xf10762f7459e6,
+    0xf16efe638adb1, 0xf1cd353ef32e7, 0xf1e0fae975e2a, 0xf2205058d3530,
+    0xf249793ff17ab, 0xf262fa8af4c83, 0xf29cb8f3353a6, 0xf2d8915d7aae8,
+    0xf385ba498a378, 0xf39bf91e7b4dc, 0xf39daca63636f, 0xf46cd1320ee2c,
+    0xf79457fb5ec60, 0xf8af162c2a465, 0xf8d457eaf5fcb, 0xfa1c4e6d860d7,
+    0xfa371cc5fa2b6, 0xfd477a7e41e46, 0xfd4d5ab82618d, 0xfd7fef8201e27,
+    0xfdf3ae3369447, 0xff1dc37ba7b01, 0xff63ee5bd17dd, 0xff65d8c9a1cfb,
+    0xff9ba8b7af17f, 0xffb9187ce58f6, 0x01cade6517cb6, 0x0271c09cf9073,
+    0x0348be7447aa9, 0x03d4090810f34, 0x0431101d8b58b, 0x05873c9561d81,
+    0x05a592edbf073, 0x06787db176e58, 0x0780f2d965322, 0x0787b5fd7fc1f,
+    0x08203654d63b6, 0x08354cb5ba1c5, 0x08d16b029bd0d, 0x099affd938371,
+    0x09cb734f2e462, 0x09e733359a30b, 0x0a28dd3602680, 0x0a5591c5f9fd3,
+    0x0b6015f51c36d, 0x0c2d7bfe68e48, 0x0d1bd27af4623, 0x0d4047452be71,
+    0x0eae0a9a35621, 0x0ebf5e6c2a6cd, 0x0f1859bfeec5d, 0x0f31ab76bd634,
+    0x0f61903015622, 0x0fc0e82daa323, 0x0fd918c24c52f, 0x10b44a9da4fa3,
+    0x11a05783f6c5e, 0x11efa51416ff5, 0x1220706095b5e, 0x1225ccd8acef1,
+    0x125046762c438, 0x135f05fb3939a, 0x14c030ed15055, 0x15265360a51dd,
+    0x15a3dd1d3381e, 0x160552c5bdb03, 0x1608953bcfe77, 0x1644505e92791,
+    0x16e605b2912bc, 0x1778d5643820d, 0x1797ba7933602, 0x190fc1a05fc38,
+    0x19849dcb0345e, 0x198d948fb55dd, 0x19db4bdf9d909, 0x1a44763a3a1b5,
+    0x1b18cefbd608e, 0x1cc65cde487e4, 0x1ede413855100, 0x20338374147b6,
+    0x212615468ab59, 0x21e45b1e79e0a, 0x228c5e7887992, 0x2317254a83e69,
+    0x233477c8377b8, 0x2450cfe22b5ed, 0x24836fa6d83d6, 0x25386a8c7d055,
+    0x25960f0256ec3, 0x260407186c9ff, 0x26552920bb016, 0x2693561c0b3c7,
+    0x278f74c1b2576, 0x27b98b8dd7ef6, 0x27be8c867624c, 0x282e0fe1f2ca4,
+    0x2916aabdfdaea, 0x294c1b83a196c, 0x2a36abc84de1f, 0x2bb7ef3e03373,
+    0x2dc092763f078, 0x2e2c77dc3071f, 0x2fb65de8ff93f, 0x2fd9058ce9a97,
+    0x302c035f46eb8, 0x30a9319c5c4bc, 0x30cdaa0f842cf, 0x31016af65d883,
+    0x32042826bb97d, 0x323b5ec0cc36b, 0x34683fdd2a233, 0x3477bf36f105f,
+    0x3491c09ad0f26, 0x3496c53c5f841, 0x357e638fa186e, 0x3616e78fe85ca,
+    0x37196a3140a74, 0x385b2ff54494b, 0x385b633269a11, 0x38dfc608b4bed,
+    0x3925bdabb9803, 0x398620d5d8a31, 0x39c08eeb2a644, 0x3a05113e6a5dc,
+    0x3a5fa67b5719c, 0x3b7036cfa1db9, 0x3ba6539966b66, 0x3c2dc5a89eff0,
+    0x3e4dd029d91ae, 0x3ed18e4de3fe9, 0x3f10a1dc5af15, 0x3f7d2f9d7cf22,
+    0x40a8db25f3647, 0x471e9fc723a93, 0x4951736a1797b, 0x4b3c61534616a,
+    0x4caf0c025b40d, 0x4d76fbf4fe2f2, 0x4d9e9d3fa5c16, 0x4db5e741212a7,
+    0x4ef2fc912ed16, 0x4f947ea80877b, 0x4fa64ee9e9c51, 0x553bb4cbded97,
+    0x5601a803ffa85, 0x5628fc72efa80, 0x5633095efd27b, 0x56f291498c0e4,
+    0x575c5cad7876c, 0x57616c49d81d6, 0x57a92d6a39f1d, 0x5a608c25ccf58,
+    0x5b4823272e3fb, 0x5d40089bf3ce1, 0x5d7e5a30a7a44, 0x5d891320d616a,
+    0x5de5dbfe5a5ac, 0x5f31866c17d07, 0x5f9771f992804, 0x6220f75a1115c,
+    0x6391bb44464a6, 0x63fb65b7bf741, 0x657102583ee6a, 0x658a8af522ecc,
+    0x6641988aebfe0, 0x6644a41ae8cc9, 0x66f3dfb71c9ee, 0x67e44191ebad4,
+    0x67ff51cb4648d, 0x684bb112ec963, 0x68fbe58ef8edc, 0x69f6fcb106123,
+    0x6a4e35d94e10c, 0x6a9b4c1fe665c, 0x6b6998bc59cb2, 0x6bbe79aaa6a67,
+    0x6be2b78ec8c2a, 0x6cdb1af761c00, 0x6d37d8da14ef3, 0x6d9f0dcc10132,
+    0x6e0195f3054ce, 0x6e3ba888de0a4, 0x6e5890e3572a2, 0x6f6b79719d0f3,
+    0x7053908bdaf85, 0x715680f95e145, 0x71d7d83fac638, 0x71da516a1ac59,
+    0x7208b981cd43f, 0x7318bec26a51e, 0x7383dadcb7e72, 0x73a9538199063,
+    0x73eb30bf2649e, 0x746c0443a79dd, 0x7564222269c14, 0x75f5cd08f3dd5,
+    0x7676ba2c07b77, 0x76bfc107f9a3b, 0x77b3321bb5dcf, 0x79c8d08879190,
+    0x79fd8a4f2af98, 0x7d38988b076fa, 0x7deeeb78ee1cf, 0x7e2d3d0da1f32,
+    0x7e2e4f8c0cfe9, 0x7f27714578bc3, 0x7f96d00791c69, 0x7fa2ebfad3334,
+    0x7ffcfd75f2f40, 0x8038855b90794, 0x82acc4c6f67dc, 0x82d56643574de,
+    0x874905468f783, 0x878e9a183a7d2, 0x8797ae246f779, 0x87bca6b073f7b,
+    0x87d14b9c3caaf, 0x88e94ec1b34b9, 0x88e9fac5d43e4, 0x89e6a82819696,
+    0x8a53cbf7ba330, 0x8cf77ea75d08b, 0x8de062b62f235, 0x8e6937c87a664,
+    0x8e8d969d0fc14, 0x8f6eac5b3cf94, 0x90df9135e56cb, 0x921c22c426186,
+    0x923026a69eede, 0x92ff6fcbdb34d, 0x9364c1a1e6bf8, 0x93e5f44ed1739,
+    0x93ff8b8f76eaf, 0x9491d77f50e4c, 0x969d9c3d2bad8, 0x96ab730a08bc0,
+    0x98a681564dfb7, 0x9b90be0e83ff0, 0x9d4a6b8d283d6, 0x9ed9145a9f737,
+    0x9edbc814139a8, 0x9f52df312f5da, 0x9fe5c88168d8e, 0xa03cd2157c276,
+    0xa2606be8800d1, 0xa31157637e6c4, 0xa3a3f02f0ff8a, 0xa44f23b27e1ad,
+    0xa61348406a4df, 0xa896a7dd2dd51, 0xaaca98f95de3f, 0xad1912e42b36d,
+    0xad47ec70f8e2e, 0xada44030719d0, 0xae4d2674b5b0e, 0xafb6b96ea5845,
+    0xb02e2c0b3a4b4, 0xb1f6b08874d60, 0xb294e1857ad3d, 0xb415003013501,
+    0xb4342acee5695, 0xb51848741f139, 0xb6a0565fcfb14, 0xb7fe28e5f71bd,
+    0xb805ae03d4c83, 0xb87acfb84f248, 0xb9265ebb364a8, 0xba9f710eed00b,
+    0xbaba6057d45d7, 0xbbbf462df8e04, 0xbc15e1f5e15d6, 0xbd25e7367e6b5,
+    0xbf138c82116da, 0xbf61477ae9fcb, 0xbf92eab6cdf9b, 0xc17a19b9fdfec,
+    0xc220698c50ce1, 0xc33c7c1c6dd77, 0xc36d9f3f75358, 0xc3939f4f13ac2,
+    0xc448d4c3be391, 0xc476ae1ed2a74, 0xc47de2b80864c, 0xc5581f5c78e31,
+    0xc5cad68a464e9, 0xc65eb16877967, 0xc6ed2fce91a52, 0xc735ed77bc5b2,
+    0xc8d75bbdfd078, 0xc93499b98cd5a, 0xc9802111cddc9, 0xca11d6f3290d9,
+    0xca6bb53123c1f, 0xcabd50006e09b, 0xcad3b717b3176, 0xcb32ac43ee2b0,
+    0xcb49d554f6554, 0xcd0ff2f4744b8, 0xcd1f37bf35694, 0xcda688ddfa731,
+    0xd0665a9c72937, 0xd0dcbaba9c4ef, 0xd10c9f73f44dd, 0xd1d6a9689c3e1,
+    0xd21013a344651, 0xd3ac3b6710022, 0xd4c7f277b407b, 0xd5797efc0244a,
+    0xd5f01d5222217, 0xd783051e74705, 0xd931b7cc03beb, 0xd937ec33803e5,
+    0xda24e355e91e0, 0xdb1866b65724d, 0xdc2d9c83c7183, 0xdd76f9b25a7f9,
+    0xdda8decf24fa3, 0xdebc6114da046, 0xded62f3b94e47, 0xdf71cd6f99fa0,
+    0xe14578a92dc88, 0xe2bc993d33743, 0xe2bf3aa9f5cdb, 0xe42002ca77dcf,
+    0xe546433715a4d, 0xe5560f6c941a2, 0xe623791ed1242, 0xe685ca5fb0f53,
+    0xe71645804d235, 0xe71be3d94ada2, 0xe7594788f7400, 0xe839178b94603,
+    0xe84ecef5c81ee, 0xe858631ad9b84, 0xe862da2a21ad0, 0xe870e7dd14243,
+    0xe9061371a698d, 0xea4c2e2a27c8f, 0xeb804cb58357f, 0xebc1e0c049656,
+    0xebe8aa1b8bb13, 0xede643def1204, 0xef59b7d9aa1fa, 0xef640a4f8e794,
+    0xefa5d8e95a4bb, 0xefadbd2fa1583, 0xf027427c5a687, 0xf0467f67de4f4,
+    0xf2573a2386387, 0xf32769dbe9371, 0xf34d41a933b64, 0xf3eccaae7b997,
+    0xf49c40d9b530c, 0xf536d0383f973, 0xf5d6b865f0da8, 0xf5f102fc97fd3,
+    0xf64810399ba80, 0xf6c89339750d1, 0xf7988862d246b, 0xf7d4ada8cf4d6,
+    0xf8962afbff9ac, 0xf95fcacd6d15f, 0xf978ecf007035, 0xf9899c0fbbd40,
+    0xfa5158c539b5f, 0xfb3b7042ea1ad, 0xfb88325bea24a, 0xfd539dcfbde2d,
+    0xfdc5218ead041, 0xfe19e18c86a09, 0xff8699b505c8c, 0xffa6989a4ccc2,
+    0x005a885367414, 0x027999f4c8d7e, 0x028d63483be86, 0x04762fdd84acc,
+    0x04c938aab303c, 0x04d2df1c766ab, 0x0710ef7165c6d, 0x072827262f625,
+    0x0873308a9cfa4, 0x08abb7db0ec5e, 0x0a59929938cdd, 0x0b8f01daf5899,
+    0x0bf0f04a7b9e3, 0x0bffadaa7f646, 0x0c653a0f90b41, 0x0c9a9136a2160,
+    0x0cc63e42ffb4b, 0x0cd4cc0ececad, 0x0d11801169e1b, 0x0d51bc13ee09c,
+    0x0e37c426f805e, 0x0e8d49c7851b4, 0x0ead4c55bc7af, 0x0f0c193fa3972,
+    0x0fde5ba82adf1, 0x12b882f81b371, 0x12fe9f3483939, 0x1360740577220,
+    0x13d45af9327b7, 0x140ec90e843ca, 0x15016232db2f7, 0x166f84b04e0a9,
+    0x1766642560ced, 0x17dd320fb55bb, 0x17fe93f80f596, 0x1976a2711c756,
+    0x19aea256d0e97, 0x1a6a5cb79fe4e, 0x1b80ee364219f, 0x1d3ff4840d353,
+    0x1d96227fcae0f, 0x1ee14582caff1, 0x1ef4e2eaf9bbd, 0x1fc6ee6d6b9b1,
+    0x20cfd8b36571b, 0x210de1155211a, 0x22859065f5cd8, 0x22f05100ca5ef,
+    0x238780a1bfaba, 0x2447a243bdb75, 0x246742009b5a9, 0x24e2a6c053a7c,
+    0x252ab8655d6b1, 0x2577e84a88464, 0x26b82dc3f656e, 0x287be82ea814f,
+    0x28d9c73387c0d, 0x292fa4aa9d7db, 0x29a1bacf1b0b7, 0x2b0db0fdd7171,
+    0x2c1b4465e67b9, 0x2ebb591ffe163, 0x2f8692da93f46, 0x3034e43ab0b2b,
+    0x306c01362ecb6, 0x31f6132e42412, 0x33813b4db11ea, 0x3391a13a9eb91,
+    0x34e16264e3502, 0x34ee1466a385a, 0x35a85d209e158, 0x35bb6f751f1e6,
+    0x37bb73bf317e4, 0x37d3ba4975c8e, 0x38ccd859f12a3, 0x39a343cbb1611,
+    0x39b6fe7b63005, 0x3aa35be65cbae, 0x3b318275ee221, 0x3b809cc8e94f2,
+    0x3bdcbd4b3cfce, 0x3c4e4faded8f6, 0x3c97fb3c1fb5b, 0x3cc64d5e300a3,
+    0x3ccb51ffbe9be, 0x3dc9b692af0c8, 0x3df7ef162cdad, 0x3f162a4c10576,
+    0x3fbb1ac44088b, 0x3fed0e84cc749, 0x42edd6edab20f, 0x4460ebbffac03,
+    0x49d2d20ea078a, 0x4b5892bb27080, 0x4cec268b9a499, 0x4e7ced0406dde,
+    0x4fddec0a9e55d, 0x5117d4da3bef6, 0x51687a729e0e3, 0x51c968b06ae4f,
+    0x544838c9ab646, 0x54ac32977509b, 0x54b36e828b7fd, 0x561cef2fc985b,
+    0x57333ecd853d2, 0x584bd8017aa69, 0x585c7fcf4ebea, 0x599d1d1f4576c,
+    0x5a29ef4f8554e, 0x5b504208d4ea5, 0x5bb9a34986ddc, 0x5bf35a5fe6975,
+    0x5c674c4e7305b, 0x5df0da84b9803, 0x5e1173216fa8b, 0x5fafa6437ed67,
+    0x6441efccac7b1, 0x6494c905a6220, 0x64c2c6fa1e2b5, 0x654133a0e2eac,
+    0x65bb3caf68f64, 0x6816c7c45fdec, 0x68583735c2511, 0x6937578b4e224,
+    0x69998089d9fbe, 0x6abe7ee3d8084, 0x6dfc98afb14cf, 0x6f802d0d810cd,
+    0x6fb85c876a30f, 0x6fe640dd4fb41, 0x705bb6bf625b9, 0x7061083ca87fd,
+    0x71d7df9de6f54, 0x72466dc27b71d, 0x72b0c43a158e3, 0x72ecb299fd2c3,
+    0x73916fd508512, 0x742f5b4837750, 0x74bc34ca580bc, 0x75b635c509c87,
+    0x76bf2b05d4b40, 0x77936dd1ce77b, 0x779d01f6e0111, 0x788ee7c535589,
+    0x79c147ce04e97, 0x7ab6d2e3c624a, 0x7b8861f64bc14, 0x7bccc70208d84,
+    0x7c3114fd6ac8c, 0x7c98638df872e, 0x7cc6986885e4e, 0x7d9f5119702a1,
+    0x7de318c4cdbfa, 0x7eb14470cde63, 0x7ed2f6ddcfd2c, 0x80eb53fed84ad,
+    0x829882b8e1601, 0x83103e883d5d4, 0x836e66bfe43f6, 0x838b73b3c0fa6,
+    0x85fe1cdeeef83, 0x8894ac17b66ab, 0x88d8024df8d29, 0x89350d0c63945,
+    0x8b8c900894ccb, 0x8c4e3ceff9ca2, 0x8e2978423c59f, 0x8e4629b6a0112,
+    0x8f1de235d1187, 0x8fc60dd232c86, 0x921f2ab78c63c, 0x93368581d26e0,
+    0x9346974127bd4, 0x9433209765cb9, 0x9443116647dc0, 0x946b2f20dbb0e,
+    0x94dba40a50230, 0x9514cc6411cc6, 0x959ee731ed272, 0x962dd70d22638,
+    0x9690c2057159b, 0x97bbe27a3a182, 0x980ee3f587b68, 0x9a69c3065dac5,
+    0x9a7ecac3801c0, 0x9bff8a7768760, 0x9c893b22095bb, 0xa18b379ae855e,
+    0xa1b1464e483dc, 0xa3030b84eface, 0xa31692f77c3fc, 0xa377f9fc44fcd,
+    0xa3b8275b07b3a, 0xa4d87c92f0322, 0xa629be07caa60, 0xa6c6d53484dfc,
+    0xa6d68b74612b3, 0xa7541c82d047e, 0xa873c5b697d3b, 0xa94637b353cbb,
+    0xa9f82ab1ec216, 0xaa260f07d1a48, 0xaa7dfb861b4e6, 0xac168ca63c690,
+    0xac980c2edeafa, 0xae43d093f411f, 0xaec928a12758a, 0xaf9aee99c25df,
+    0xb0568bb30e76e, 0xb393a8f61eda0, 0xb3c3d6e23e0f2, 0xb42339daa3f42,
+    0xb58ce2ca35f17, 0xb6c78641b5eef, 0xb76ab48e69c5d, 0xb92f59353297e,
+    0xb9ff5207802dd, 0xbb10243cb16d4, 0xbb7071712e664, 0xbc2f216c58066,
+    0xbcaa5697dba38, 0xbcab9ffc5c17a, 0xbd490eff9ef8e, 0xbd4916517fb18,
+    0xbebe3a2b033dc, 0xbff8ae0e4e8b3, 0xc1c0e958c1dfb, 0xc301362410a8f,
+    0xc32243ded25b7, 0xc32ba86faf44c, 0xc335a30f0af6e, 0xc4090699bebb8,
+    0xc441aedaa3c5f, 0xc4ac60d1b6e62, 0xc5a359e55c309, 0xc61bba66f867d,
+    0xc698aa6c17a6c, 0xc89eafe08179e, 0xc8a588fa3e339, 0xc906a6cc3fba6,
+    0xc982d4d79bdcc, 0xca4a2769df49a, 0xca70f817024e1, 0xca8edcfa444f8,
+    0xcb3024821830d, 0xcb6ee78be734b, 0xcb9e0df46c735, 0xcbfccfe3827a9,
+    0xcdf0382176ced, 0xce3896a23824b, 0xce460a9dbb76c, 0xcf2aa109f1075,
+    0xcf2d3ecdc3048, 0xd0b123b03ab34, 0xd132ddc7e2bee, 0xd63d33a5144f9,
+    0xd76b54af08c7a, 0xd88ce4a7b0490, 0xd970355855c1c, 0xd9831818a21a9,
+    0xdaa8c9c8a1d24, 0xdacfb06b67009, 0xdb2c759ffae86, 0xdb31bfcb60540,
+    0xdb8919e41b916, 0xdbdf77740ded3, 0xdc2bc0c61210b, 0xdd1f4f21512c7,
+    0xdd508be2eb10b, 0xdd70e9f09b743, 0xdd81c8a484f4f, 0xddb3f6f416a5d,
+    0xdefd8b08bf75e, 0xdfff11214edef, 0xe0454aa53a1df, 0xe048a6b9dedb6,
+    0xe0cda347991e4, 0xe0cf3987d124f, 0xe1b62f7fe2916, 0xe1ebf0ca2e686,
+    0xe1f6b10c3d936, 0xe2ce782f300bf, 0xe3389b69a51bf, 0xe3a3bb2ce30d8,
+    0xe3d39142799b2, 0xe530046e7e67b, 0xe59a5e8f08e06, 0xe5d118b92d3ca,
+    0xe631a425a056f, 0xe674a2855a175, 0xe7af2c5e478ea, 0xe7c1581fa1dfd,
+    0xe821c6449217a, 0xe839388861782, 0xe97d7776d3c7a, 0xeb6a9557a9726,
+    0xeb6e7c7ffbe3b, 0xeb9d38c546ad4, 0xebac8c33c93c4, 0xebb9f8dd6bd5b,
+    0xed88b56b133c6, 0xeddc001928110, 0xefbaf2ff88621, 0xf0009dc6d590e,
+    0xf00db299ef82d, 0xf02b85307fb6b, 0xf1b829f19414b, 0xf33c07822b0ad,
+    0xf3d0e29215909, 0xf3e42bccac022, 0xf5ed68bc56f79, 0xf6dabf0729376,
+    0xf6f4e8ad5d1b4, 0xf7c19065d708b, 0xf85e70ac7bd9c, 0xf8f022e4e6ae7,
+    0xf9c7c56e758be, 0xfb83b8da631ff, 0xfef96868b663a, 0xff605b79cb09f,
+    0x00f689651fec6, 0x014bd0cdb6e07, 0x0313f9cb78676, 0x0349250745759,
+    0x05e585d62fab4, 0x06740093595da, 0x06e9d59dd5654, 0x073ff14ce1437,
+    0x0783403142f2b, 0x08d468078ae06, 0x08e65590ef104, 0x0a9c3585d3638,
+    0x0ba8b66f77bc9, 0x0bf11feb0a276, 0x0c38d267aa8a9, 0x0f2e09f0f57e2,
+    0x0fc0adb7581f7, 0x0ff91b693764e, 0x1160f3898b968, 0x1186e89e58f83,
+    0x1288a59cfdc9f, 0x13165ab774037, 0x138c9d8e1adc7, 0x13a560884b69b,
+    0x1418b8bf68b2f, 0x144f1ebbf4c40, 0x14a2c4e9829c7, 0x15737fb5934ef,
+    0x16e7fb33e644d, 0x17ebd98670d12, 0x1830f1e82f937, 0x18f88a0449da4,
+    0x1d116b8841512, 0x1d24977b54e03, 0x1d88b98b727cf, 0x1d912c8e5799a,
+    0x1e5f4244b5965, 0x1f5bf34feb1dc, 0x21816c95ee406, 0x228b4c12d03ff,
+    0x24e99984fcc0c, 0x251b615a4458e, 0x257e574d64640, 0x25a65b6965b2b,
+    0x2696fb7c2ae26, 0x26b5a25930006, 0x270b20a7dc5d2, 0x297d0f2b27f70,
+    0x2a10b32343d63, 0x2b0ca98696f9b, 0x2ca13636e2c08, 0x2d76633ef3983,
+    0x2e78e5e04be2d, 0x2f444b862614c, 0x30e65e7ea6fb3, 0x31c13c2c67574,
+    0x329b99c14b146, 0x3303ec2c8258b, 0x33270c9768548, 0x3344a847e31fb,
+    0x348035fb7a313, 0x3483418b76ffc, 0x348cc363d6cb9, 0x34fd2257a913d,
+    0x3528111333f24, 0x3598f771c3921, 0x35db009a95298, 0x362e822ebf66d,
+    0x36350ac3d431a, 0x364026855c607, 0x368bfab954f9f, 0x370b46a15fb87,
+    0x379255df3e44a, 0x380d283968255, 0x3889738c472a3, 0x399f1aced24b4,
+    0x39a1b140c38fd, 0x39e4457d42db2, 0x3b505c9c72259, 0x3c5cd9dd26225,
+    0x3d79f047ecc5e, 0x3d89e868af8ef, 0x3db80af68b336, 0x3e6053da6fc5d,
+    0x3f4adccd3b586, 0x494d1ae55daaf, 0x4b23a5c3a9f44, 0x4d52db7112a7b,
+    0x4effaeaba2b92, 0x5089408ad98ff, 0x57626c94450d8, 0x57e3351df54c8,
+    0x5875467ec9815, 0x594b4b763f9f7, 0x59cbd970ea197, 0x5a01e0450cca6,
+    0x5a09b23ea2095, 0x5a46fff8ac455, 0x5b7d81b8d40c8, 0x5c8c4c38b2179,
+    0x5ca507e101ec3, 0x5cd09b4ecd04b, 0x5f03dcbdebc49, 0x638d3a7d38663,
+    0x63e8b2a45b7d9, 0x6778cdb9c922c, 0x684ab84bc7c33, 0x692f1f23c8a3b,
+    0x6a802d5b7e0b3, 0x6b10f557d1cbe, 0x6b1a5296cdfc9, 0x6be0ee2a1f61d,
+    0x6becf427bea4a, 0x6c6eb93a37c53, 0x6dfb4805a9f95, 0x6dfc5a841504c,
+    0x70b4b97161465, 0x7181687bbbec6, 0x724a88344cc8a, 0x72f6e082d7c3d,
+    0x72f74aa61238e, 0x7414d9d7d4c2c, 0x746f850a63a8a, 0x74ab01f53018f,
+    0x753e88a5c915a, 0x75e11d3b0dc76, 0x76f8bd8f2aab9, 0x77b8b345e4638,
+    0x7895da89de719, 0x7a1a850f09993, 0x7a7ce89c9b37d, 0x7c47af5e2ebbf,
+    0x7ce14233f040d, 0x7df7e5ff44437, 0x7e58f18493fcb, 0x7e8175b971ea5,
+    0x7f9348205c67a, 0x8027b90d0c785, 0x8256b0827f0a7, 0x836b4c987fd8b,
+    0x84dabfcc53e09, 0x86bd19c22a040, 0x88f867b9e3c7d, 0x88fe09bbd1daf,
+    0x8a3b599ae546e, 0x8af2ee9b6bafb, 0x8b55e48e8bbad, 0x8d550997581ba,
+    0x8dcd542352290, 0x8e21b14fd2091, 0x8e4caeaf1e58c, 0x8e71b235f3edd,
+    0x8e934009923f4, 0x8edd8ca114435, 0x8efc1636967ed, 0x8f38a59fcdfa9,
+    0x8f46ede1c636c, 0x925c4540cb6c8, 0x9364df021d544, 0x944deefc33c2a,
+    0x94cab3160eadd, 0x95d64d6c8c4f3, 0x973135532a2a0, 0x981f8bcfb5a7b,
+    0x9bbaa90818f58, 0x9c32c056edf68, 0x9c8e387e110de, 0x9e13422ba595a,
+    0x9e35ba3b5afb1, 0x9f1122fcc90b0, 0xa003435a24178, 0xa15c6cbe35f43,
+    0xa458fd7a1a406, 0xa464af4a21380, 0xa684b2797a9b4, 0xa6d315cd83c0b,
+    0xa80dcf3aa5e81, 0xa8e9d1b3831d2, 0xa9945932d04ca, 0xa99573031c10b,
+    0xaa161e45496d3, 0xaa6d8701c61bd, 0xaa853b267bf9f, 0xabf0fe1812f93,
+    0xad39d784d9655, 0xae0110788a4c0, 0xae067b9462f67, 0xaf0028617c67f,
+    0xaf55c3f7aba73, 0xb0d0cbb403c43, 0xb1cc3703a933d, 0xb34846439b475,
+    0xb34d6f7e8d742, 0xb46f27b988ecf, 0xb5d2b989214d2, 0xb703c8db8fb14,
+    0xb758dd070198f, 0xb77bbf39f1737, 0xb7c6e017e861a, 0xb9c60177c4662,
+    0xbb73845f65c69, 0xbbb5e907b061d, 0xbbc9d6f4870d7, 0xbc1f812e77bdf,
+    0xbc6a727839fc1, 0xbeb325c7b5a0b, 0xbf20a516cf6e2, 0xbfc950cc0dbd0,
+    0xc1478508d5e76, 0xc197aa885b674, 0xc2446cfa20d78, 0xc2bd4642b8f51,
+    0xc3b84ec104a84, 0xc3ce1126097be, 0xc585541e00c97, 0xc64fb5e931613,
+    0xc6633260ecdf2, 0xc78048cbb382b, 0xc7966e021212c, 0xc80ab7c72728a,
+    0xc9bc1a853a41c, 0xcaae791a8b6f9, 0xcae14fc54db6d, 0xcb8bff86eeddc,
+    0xcb8d74d6b3a5a, 0xcc64d57f5c057, 0xcd1732a12ed03, 0xd02e6221528a4,
+    0xd0ade14682552, 0xd0b97d20e722e, 0xd197a0edaa128, 0xd1c2d8dbfc273,
+    0xd2261baad3c4e, 0xd35f7d0fb406e, 0xd432c352e4e90, 0xd4ae8ae3f6f2a,
+    0xd5fc65495b942, 0xd68b5c7671892, 0xd709121e4440f, 0xd8e9018d6d739,
+    0xda36841c496d9, 0xda4a87fec2431, 0xda5352d830074, 0xdbb9c7f5713e9,
+    0xdcb6f1c7a2ac5, 0xdcf46f15e1986, 0xdcf91837f7264, 0xdea99f5db49ca,
+    0xdee8242f8d7f3, 0xdf8784f2816af, 0xe0fbdbd770c5b, 0xe2912a817fa91,
+    0xe2abcceeaf734, 0xe38740aaee982, 0xe396ebeff9cea, 0xe48c1b8642060,
+    0xe505ba718d9c7, 0xe59417e7346c5, 0xe63f0237db584, 0xe69a228875c82,
+    0xe6d33c3e76004, 0xe7339f6895232, 0xe8102cf5200c1, 0xe8b4e2de4a786,
+    0xe9a1145dffdf3, 0xe9fc51f61d319, 0xea5ec7d0609dc, 0xead9b7720d60f,
+    0xeb703eb7d2174, 0xec17ede447849, 0xed4f095bde70e, 0xedb62c0127c74,
+    0xee6b0d483a090, 0xee6dbd58bdd3c, 0xf178581ea43ad, 0xf4926743806fb,
+    0xf4c59d16ac171, 0xf58efb073314a, 0xf6c14171701f5, 0xf7ef27ec5ed26,
+    0xf81992e61cb59, 0xfb639cb9f3133, 0xfc75b101c40e2, 0xff7c9b856d6c9,
+    0x0196420af658c, 0x022c19a3a9c01, 0x02370d22ddf77, 0x0484190fc73b1,
+    0x0542d05cd193d, 0x05f03780d73e2, 0x074888f583d46, 0x0783a6b7e6e49,
+    0x07c7168cbbd2a, 0x0976420147075, 0x097c9eab177e6, 0x09de5634882a5,
+    0x0ad8956730085, 0x0ae4c3a723429, 0x0afbe1bd5a57e, 0x0b7f40b8fbdb7,
+    0x0c0acd2daba1c, 0x0d4e58c61c45f, 0x0e168e42960e3, 0x0e5a7a87573ee,
+    0x0e6cb895635da, 0x0ea8d6897fabb, 0x0eb9aa4298178, 0x0f932c3f2631e,
+    0x1079170aad4b8, 0x108654201b34e, 0x10e854dc53171, 0x123b1deda4206,
+    0x156f39713156a, 0x157e4003fc531, 0x16e10c30e13a6, 0x173670e0fb10f,
+    0x18acb5dcab19e, 0x1a0b3466f3772, 0x1a4e8a9d35df0, 0x1b39a99e803a6,
+    0x1bf3f6016b269, 0x1c6354c38430f, 0x1c6d9c3e9775a, 0x1de7f7f6ce9ff,
+    0x1dfc784933b81, 0x1e00163ebef32, 0x1f4755c25cfc4, 0x20fd4803f31d1,
+    0x253dfa66c6d64, 0x270f8b9e55a2d, 0x27437c1963ae2, 0x2767ab59c4591,
+    0x27a6f22560583, 0x29e84147718f4, 0x2c17107a9029f, 0x2c3b7a49f699e,
+    0x2d1ed24c7ccb4, 0x2dbfd0a189765, 0x2dcfc8c24c3f6, 0x2f6d9cbbf20d0,
+    0x3056a90d181f1, 0x305f47fb418f8, 0x3193c20616225, 0x31eccbfd9bec9,
+    0x33b88b9f07f5f, 0x341c8915c1f79, 0x36bc5beef3149, 0x373bfc04961e4,
+    0x37604fde5a645, 0x37bbae66eaf58, 0x380d9d63cd887, 0x38b12e81db1bc,
+    0x39fd51b69477c, 0x3b22e61f114cf, 0x3f5bbf366f0e9, 0x41e02da8ad44d,
+    0x42189f037ce69, 0x4246501c3d5d5, 0x4321fe6782473, 0x470fbdf2c9d34,
+    0x47be9a6694457, 0x48aea0c1ea500, 0x4a22c0c0c4421, 0x4fcf0680fa4a7,
+    0x511592ae96a84, 0x54b112b853b28, 0x555a66c8c297c, 0x55a97277fc539,
+    0x56d1ea2e21ffe, 0x576f17507e638, 0x5b2185dc3dd30, 0x5b4e5f0599035,
+    0x5b6eb5c168ae3, 0x5c110c1eb73ea, 0x5ca1c577498e1, 0x5da7f108fe28b,
+    0x60f4272b8b55d, 0x60fbd0e2cc9d5, 0x6133002afc839, 0x61a66aaecb9a6,
+    0x627b9b5fccce6, 0x6422f4dac2c42, 0x6466ec1a5509c, 0x65400eee79c40,
+    0x6544d55812346, 0x6545e7d67d3fd, 0x6819a3d8dbaf8, 0x687afc39e2fb5,
+    0x698be0bbc6085, 0x69ae58cb7b6dc, 0x6da1b306d0545, 0x710bf2a6031e0,
+    0x72eb2b163a490, 0x7369647fd9fc1, 0x73cecc4b87b0a, 0x75c72e303981a,
+    0x77a8d1271d9d7, 0x78fd6eb09ccec, 0x79404125123b6, 0x79ec245591ac9,
+    0x7a0646a9e4d7d, 0x7a5747c1bffa7, 0x7a78432fcfdf6, 0x7ab4980a01962,
+    0x7b65a0cc82d7d, 0x7cd6f373561ca, 0x7dcb1f2ef4b9d, 0x7e0f0b73b5ea8,
+    0x802ee660bb565, 0x832458790c0ee, 0x867d618dccb05, 0x86bb6646c8f3f,
+    0x874eaebf6bcf5, 0x88efb68b6262f, 0x89d6a5319316c, 0x8bebb138c7e65,
+    0x8d3dbfa2368bb, 0x8e286985755d1, 0x9000af3d5d483, 0x9254894932309,
+    0x944f904396b76, 0x96e5bcab046d7, 0x99096c73aa7d0, 0x99ced0ef2d3bb,
+    0x9a0f318b14fee, 0x9a6bd226454b9, 0x9cb1ef03cfaba, 0x9d6eeb773e629,
+    0x9dbb730138a76, 0x9ea88b1414c5e, 0xa01b9c3d7408d, 0xa12190e9133ac,
+    0xa12e7d79d9354, 0xa2383faf38525, 0xa34d2ff2d16bc, 0xa36629d31761b,
+    0xa4480c85d8cb3, 0xa5cf0f44ff161, 0xa650459ada267, 0xa79f8dfe22d73,
+    0xa92fcd0bd213f, 0xa9362263c1d26, 0xa994175e43a82, 0xab98c1217b399,
+    0xae58b027763c7, 0xae859f467396a, 0xaeb5f574e6c33, 0xb0d95fb3b5f8d,
+    0xb1e18456fc569, 0xb231bfcc24005, 0xb30cd0b70968c, 0xb31e1d371dbae,
+    0xb57611a86a20f, 0xb584eff8e125f, 0xb586111b0da2a, 0xb64c00aa3e153,
+    0xb675c6f1bbbe5, 0xb67f4c730be67, 0xb7d0dac39dece, 0xb7f7205d133d7,
+    0xba9e36733b893, 0xbaa8d21be7191, 0xbb1f5a7c64cc0, 0xbc0e67f7e2515,
+    0xbe53c2dba994d, 0xbf40daee85b35, 0xbf9c2381741aa, 0xc27337ef86eb7,
+    0xc38c6e83dbd65, 0xc4448aef1f96b, 0xc46494cf37af0, 0xc4695b38d01f6,
+    0xc51792fa5a578, 0xc51f18183803e, 0xc5c89bbcdb993, 0xc67957a3a5485,
+    0xc6c66a414d410, 0xc77613a9abe4b, 0xc77f32b0b1f41, 0xc7dc3d6f1cb5d,
+    0xc852c226aa0c7, 0xc956af1cf60a0, 0xc9eeaf5b6fe48, 0xcc16a1cbe1693,
+    0xcc6ed77cf2495, 0xcd540ef277b7a, 0xcdb2baebeb950, 0xcf1f4380360d2,
+    0xd0f1637462e9e, 0xd1a63d6994730, 0xd1dbf3b90f351, 0xd298c44139984,
+    0xd2fbed717eafc, 0xd475af7246b4f, 0xd5a728e7ef8e2, 0xd5d34ebb49132,
+    0xd66292c4169ab, 0xd6a8ff8526e61, 0xd709c9299021b, 0xd75b012780ad0,
+    0xd8540ceb4a40c, 0xd8690604ab3f3, 0xd9475d0e933b3, 0xdb792cd6dd8f8,
+    0xdb7a9e7db1fb1, 0xdc13e477bbed6, 0xdc9c6cae4f9dc, 0xdca319dcc803b,
+    0xde4b9f74bb8b1, 0xdfb95f20d4a9c, 0xe0388a186c297, 0xe1446da1b1011,
+    0xe16848b47960d, 0xe168c52465a37, 0xe17bfc124a477, 0xe4cb8e497c320,
+    0xe5dbf65b72fc6, 0xe6394df59550b, 0xe86d79a0cb249, 0xeb7e7c0b5317a,
+    0xebea985759eac, 0xecf0e1309167e, 0xed584d08a1f48, 0xee955eafbf3f2,
+    0xee9df64c07f6f, 0xeef45ed6cb67b, 0xeef73ad293863, 0xef41333c7d3f1,
+    0xf00cf80ac0d12, 0xf105ba9bc32ea, 0xf1393d4aa6689, 0xf1e5e274e8f65,
+    0xf29183c481e9e, 0xf4fbed29efd76, 0xf92fa4583c24d, 0xfae8e40ab591d,
+    0xfbad5aa130e03, 0xff10aed9f522a, 0x0124edec95c0b, 0x0153bc7e9257d,
+    0x0296fee43bc5c, 0x0458c78f3c795, 0x0566958651a2d, 0x059ee247bda97,
+    0x0643898d26a48, 0x06859db0c950e, 0x06afdcbf42e05, 0x06e1d07fcecc3,
+    0x07127389f98b5, 0x072db70079334, 0x07b458722cee1, 0x081bb1fd8bad2,
+    0x0a5e30e58ad22, 0x0a9df7ca03703, 0x0b682da9efb43, 0x0c9ae58947ec9,
+    0x0db88707bc440, 0x0e56d8f53580a, 0x0f06a6f6f7bf7, 0x0f231ddc55b1a,
+    0x105446cd569bf, 0x115871fb98bad, 0x11d2b94214e7a, 0x1207f6ca93c36,
+    0x136bc32931e89, 0x13877814ccbe3, 0x15607874970d4, 0x15a1900f70d81,
+    0x16d1f35dbe498, 0x16d5c8395eed4, 0x16dc19e85e4f6, 0x17111ce1d7662,
+    0x17a88abac2d42, 0x193cb842a53ad, 0x19ab58b3eb84f, 0x1a08880bb9e1d,
+    0x1ad1748725b1b, 0x1c5d992f5d70c, 0x1c833a1692874, 0x1c942b172dd59,
+    0x1ce1d76c44f36, 0x1db58f2491033, 0x1f38da4f998cd, 0x2010c262ff443,
+    0x219c8439dd46d, 0x22d1fe766b178, 0x2322622de6b8b, 0x23a6414264db3,
+    0x24c086ac34753, 0x254282a4c2fe7, 0x25818b3868dc4, 0x2592be19eaa83,
+    0x28653bb299b8b, 0x29c7ac60059c3, 0x2b543ed4682ca, 0x2da4b579b79ef,
+    0x2dadafe75a133, 0x2f259d6ff3f06, 0x303ded7122239, 0x3142c4a385352,
+    0x31509b706243a, 0x31a5be3f959c9, 0x321b51692b269, 0x325584ef7722c,
+    0x32ab11e1e4f0c, 0x32c10ed5ef896, 0x32e8584a0e742, 0x32ea933c86b4e,
+    0x348c3c11cd264, 0x370c2d4d3a226, 0x37bbde07797eb, 0x37e1b97db45a3,
+    0x39875bc7ff0a7, 0x3ac6f53d4c286, 0x3ae8955d9c476, 0x3c9ec98018e5d,
+    0x3cab95206ba18, 0x3ddc788795b1e, 0x3e6c39004f7c1, 0x3f2ee9c25e13b,
+    0x4ae71dc506e36, 0x52e936a2a3974, 0x54488d1c513af, 0x55450e9352125,
+    0x59248d2e821ad, 0x59f962600a4b0, 0x5a554101777b2, 0x5cca7cf5a6613,
+    0x5dcb7f4c68cf0, 0x5eaf2081b636a, 0x5f6bf85bc1527, 0x61e4731d12137,
+    0x62f972046c9e2, 0x648d8d3f9d374, 0x669c6136652ae, 0x66d7655a35b4e,
+    0x67102ae29da1d, 0x6a97986620655, 0x6cf0b1a289a46, 0x6e5531572974e,
+    0x6f78c905242aa, 0x700f3a5546b71, 0x7123fb04ab207, 0x71fcf1ed8b86f,
+    0x728530f158011, 0x747256240e647, 0x74a537c9a1c0a, 0x784e36c9b331e,
+    0x78ed6f4a53263, 0x7d08b05a2678f, 0x7ddb72db8a5fd, 0x7eecf866bd4a9,
+    0x82516dc1ae09b, 0x82d503a364f5f, 0x85c315373b9d4, 0x8644443b35f50,
+    0x86a1230e5c630, 0x86de4c2f0303e, 0x876b9acf2f24a, 0x88ee06b8f1af3,
+    0x897353cb53e0f, 0x8c1fca0283c23, 0x8ea042a8ae15e, 0x8f66532851c74,
+    0x907defd37e4f2, 0x91bec7b27acc4, 0x93573f340960b, 0x94e67a670f034,
+    0x957fc40a0951e, 0x95a2d97a1e38c, 0x96371ad299996, 0x96afa39689c81,
+    0x9727b73c6e6cc, 0x9d5dd240a8f5f, 0x9ef97299b73b7, 0xa003ebce08602,
+    0xa059925f08b45, 0xa0dec97bc8bc3, 0xa13ffd436c6ce, 0xa16b14414b42c,
+    0xa19adbb3205f2, 0xa25ab07966d84, 0xa39b760bb187d, 0xa45675c49e1f5,
+    0xa4d907cbab716, 0xa53f01fce7927, 0xa62043a6591e3, 0xa677e348eb358,
+    0xace1fd6bf53b4, 0xaf0c2e77cf5d0, 0xaf5c713ed7bf6, 0xb15dd13a1c60f,
+    0xb15f7275257c9, 0xb1b7c916a99b8, 0xb21469b1d9e83, 0xb43439a40e3f1,
+    0xb774c8f165898, 0xb78ebbb18404b, 0xb7c49a4352be3, 0xb87e5440af3de,
+    0xb8cc3b24cc20b, 0xbacaa92ea679e, 0xbdb7073ac2380, 0xbe3dac55664f2,
+    0xbec6139b86c0b, 0xc0f1cc43d777e, 0xc1882ef038931, 0xc3e7a12d81ece,
+    0xc7f700d91997f, 0xc8c30eda24604, 0xca1c30ec55845, 0xcd02708696ecb,
+    0xcd8e6ac771846, 0xcf31fe0a8847a, 0xcfaa7481c6a8c, 0xd033807a27546,
+    0xd077c0ec80d04, 0xd09d0da61d9b9, 0xd20eb8237987e, 0xd268cd4789a4f,
+    0xd2b6256f08779, 0xd3064e977e53c, 0xd4499fa0e932f, 0xd48dd5187199e,
+    0xd63c214bb6cf8, 0xd7cd050ba6465, 0xd9134f585c268, 0xd9941f33ed1e2,
+    0xd9ee30af0cdee, 0xda2ca6dd24503, 0xdb16f540ea1dc, 0xdb215a038044f,
+    0xdbad802f9f306, 0xdea35c6b2a5e0, 0xded3c88f3fb47, 0xdee6b64a5d223,
+    0xe0384beccfe14, 0xe1a1e638a06d5, 0xe1fcd6f5062d2, 0xe217ee8041815,
+    0xe23dc9f67c5cd, 0xe2e2997e394f5, 0xe488e07ac439a, 0xe516f866942f9,
+    0xe55e9c3f73218, 0xe7dfb997ddaf4, 0xe7e58ed6f0cec, 0xeafee353ea9fb,
+    0xeb3d7a72754fd, 0xec8f2d5c6af16, 0xee8bdce3b94c7, 0xefa36aeb24631,
+    0xf0738104f4db8, 0xf0d5b8a742266, 0xf21049d210565, 0xf250145f7950b,
+    0xf2e7e509be7b2, 0xf55ff1fee46ac, 0xf5bb78c9c8f36, 0xf63f80209b0d5,
+    0xf65d5a090bf9d, 0xf7c3a6e3f939b, 0xf862c96ef7042, 0xf99bf044d1812,
+    0xfe7030aa1eefa, 0xfe8bf4397b368, 0xff3fbf5932108, 0x005cef628b3a4,
+    0x00c5c9387fd62, 0x02ea7589eec74, 0x0344208ac46f4, 0x070f6baf46f23,
+    0x0a442f8e04bed, 0x0aac38c674cce, 0x0bc2bf4a45ed0, 0x0d18f313fd250,
+    0x0d930ac644a1c, 0x0df0a7ea3dd00, 0x0e38d6d6ca75d, 0x0e46dd37dc346,
+    0x0fc117ffa01fe, 0x1096624f33da1, 0x10b313c397914, 0x11b4a12e07b2f,
+    0x174514bb20033, 0x17495b0bdbd4a, 0x18ed80b481046, 0x194b54be8f9b5,
+    0x19e58c46915a4, 0x1c50f234c8295, 0x1d6e4a80754a8, 0x1eb9454121713,
+    0x207b5ac7d9b75, 0x222f037336262, 0x2398d4a51c1ae, 0x25c2e4c082fdd,
+    0x26ee312090100, 0x26f7f130e5fd2, 0x273988e49c66e, 0x27d7277c13f83,
+    0x28a9245ac4663, 0x2988de67bf5c8, 0x29a0a88217648, 0x29b72ce0df54b,
+    0x2d496cf32310c, 0x2d7b520fed8b6, 0x2fe730c52040c, 0x30a5a28853bf9,
+    0x3398439fad6e9, 0x3971369dfa538, 0x3a4f65658e581, 0x3abfe8f2c43b7,
+    0x3ae0609f07252, 0x3b7fdea97df36, 0x3d187ac47022f, 0x3d631f327ace8,
+    0x3d996f3964b5b, 0x3db8f900a02f1, 0x3e84d76d76475, 0x49b38a59b2e16,
+    0x4a5e27cea23ac, 0x4ca3e92cb3970, 0x4e8aaa63b8cab, 0x4eaab443d0e30,
+    0x5131da18739ca, 0x5162a90de2af8, 0x523ebdd371b22, 0x5380a487e8de6,
+    0x545292c2d7db2, 0x5577fb40105c9, 0x566b02309220c, 0x56a744be1209f,
+    0x5747eb3c960d8, 0x5903991eacc7a, 0x595d566c343d3, 0x5a908abb78b83,
+    0x5aa6a14e15d70, 0x5d5cffd7ef9cd, 0x602f134d64384, 0x61bf057f5be27,
+    0x63c387003f7c7, 0x65409dc3cb867, 0x658dd8a3c7769, 0x65ca0192b4d99,
+    0x6877f0c299df0, 0x68b5cd39422b3, 0x68c0b96695a9f, 0x68e8a3e404727,
+    0x68ffe2eaaec69, 0x6a719862dbc7d, 0x6ab6086969f3c, 0x6b14ca587ffb0,
+    0x6b4af96ef6a36, 0x6fc4874fd4736, 0x70c72e8a90592, 0x736b64fc002a1,
+    0x737d980f3b33e, 0x747848b6fe3f9, 0x79db2120f842f, 0x7a265b9d81b75,
+    0x7a79cae4fa271, 0x7dcb9f60850b0, 0x7ec94cf483740, 0x7f3dc64dcd39f,
+    0x7fd6fda415bb0, 0x81d096a096329, 0x82f8ffb2fa6da, 0x8408937e7c4de,
+    0x88c34b46e5b64, 0x8accca1777295, 0x8b9e1af206a4a, 0x8bb409424fcc0,
+    0x8eefe0c9d0175, 0x8fc131a45f92a, 0x90d14931ae6e2, 0x910ec2d6fcfde,
+    0x91359ad600baf, 0x91c6458ad1992, 0x92d985efa025b, 0x9357a9639daee,
+    0x9386b62d90675, 0x97e7e3e597668, 0x99abe3da1ffe8, 0x99db4fcc7c171,
+    0x9e6bc1348b376, 0x9f7eb4bda2316, 0xa175581fc8044, 0xa30db602c4128,
+    0xa316882e128f5, 0xa6eaeeb0aac55, 0xaae67db6eea74, 0xac707d62504f7,
+    0xae61826b786b8, 0xafa8f52c3b810, 0xb080e0e89194b, 0xb1a1202ad7e95,
+    0xb326e0d75e78b, 0xb398683f3df64, 0xb433e582cfcd0, 0xb49ab7a371348,
+    0xb5275de86cbee, 0xb6b75ebe25da5, 0xb72fbf3fc2119, 0xb8471db2f8782,
+    0xb9ccbd6f0bc8b, 0xb9dfde674e42d, 0xba191cb6b2161, 0xbb55b93fc3d6b,
+    0xbc7acd8f640cf, 0xbd90af60f4f30, 0xbf30074e20f9c, 0xbf99aa6fb96ad,
+    0xbfa12be4a6bae, 0xc1481ee55297e, 0xc2b686ec9c4cf, 0xc2bf2d2ca6760,
+    0xc3b06a9fcb272, 0xc431073e37126, 0xc4c5623544f93, 0xc5537dca054b7,
+    0xc62fc975a9b6c, 0xc79debf31c91e, 0xca0466de57557, 0xcb423e282818f,
+    0xcb80cdf4d2107, 0xcbbff778eb2d1, 0xcd8010452153c, 0xcd8352bb338b0,
+    0xcef1076c7b94c, 0xcfff223f4850d, 0xd06b42343f804, 0xd08ded8119f21,
+    0xd14b95f8a99bb, 0xd2560b840a641, 0xd28f33ddcc0d7, 0xd396f206c8527,
+    0xd437feff966ec, 0xd69b426f90100, 0xd714021995a76, 0xd718b28d8bede,
+    0xd76ca93fc1b53, 0xd7b2d076fb26a, 0xdb5bcf770c97e, 0xdb8b341787f7d,
+    0xdb924f122b2f2, 0xdbbe838946256, 0xdccf88fb9c713, 0xdd39ac3611813,
+    0xddc870260269d, 0xdf5969db940a8, 0xe11f4cec0c3bc, 0xe13a55d3861eb,
+    0xe1c06c318c25a, 0xe2592717e8641, 0xe472254240b9e, 0xe4ed2e8280034,
+    0xe5f352c253e54, 0xe67c5768d3d84, 0xe84554ad0a495, 0xe91bb17b090ef,
+    0xe97df7c117cb1, 0xe99de8029d5d3, 0xeac53aeda6308, 0xead135f0745e6,
+    0xec55b0e16ad5f, 0xecf565d1f70ce, 0xecfca90eee3ba, 0xed2154bf3b293,
+    0xede956fe8fe51, 0xef6d4a84c9051, 0xf0dc20583d8b8, 0xf1a3ebb17cdeb,
+    0xf2be177cb9f28, 0xf2e8a70fdb70d, 0xf3bf804dc6791, 0xf468160d629e1,
+    0xf5a5b2c82d9c9, 0xf66c7a46c3559, 0xf8bcba05fd5f3, 0xfb088b32279ff,
+    0xfb5637873ebdc, 0xfd2cbebc9aaac, 0xfe59ee3897563, 0x00b700ea04d42,
+    0x00c9e3aa512cf, 0x0247474994c98, 0x02d3fc3251c52, 0x03daab85d35b0,
+    0x04fbc6606f526, 0x06616e891c583, 0x06ad1e23b1569, 0x0bb1ad6591390,
+    0x0c007adcd4d38, 0x0c2d41b97e364, 0x0cb7cdfc74beb, 0x0d3a1e229b932,
+    0x0f5f72cf3b1aa, 0x0ff1967cc11d0, 0x118dbe408cba1, 0x11fb7475bbf03,
+    0x120d82ef935ee, 0x1255356c33c21, 0x126b744124d85, 0x13d854abf7f7f,
+    0x14b23d22d02b1, 0x15551aeadc131, 0x16678ab22611d, 0x1782712545899,
+    0x17a2775c6d459, 0x18713cbfdc914, 0x19d3a272775fd, 0x1afc3b19104af,
+    0x1f4116851cf31, 0x203b177fceafc, 0x2208ffc7012c5, 0x25c39479ee25f,
+    0x27dd0b6b42621, 0x2920d8e49983e, 0x2bcdc7e2c54b7, 0x2d836d48a3d9b,
+    0x2e61d69f3da34, 0x2ee6cbdb172d8, 0x2ef66c25514f1, 0x3151e4ed966a0,
+    0x319e4b871d700, 0x334e145c08262, 0x3690cc4f25e3c, 0x376ec7d994dbf,
+    0x39ad8b8485e36, 0x3a2eccd53208b, 0x3fb9294250bbd, 0x46cc01674ade0,
+    0x47de2452dd4a3, 0x4a39ba62a547a, 0x4d85f0853274c, 0x4f206c1323e14,
+    0x4f26d3b7c56d4, 0x4fde650f5b79c, 0x5403f4ec22c9d, 0x546808587ef55,
+    0x55e96cbea7896, 0x56c6263676c61, 0x575cb1252bd8b, 0x5858c878f23b0,
+    0x5b8657be7a4a2, 0x60ce77c5a2597, 0x625bcfdcb862c, 0x62e742b2d5a2e,
+    0x631af14cfd309, 0x635da2d0ff5e6, 0x66c24f1205863, 0x68a508304509c,
+    0x69813de6474b3, 0x6aabcbf5819d2, 0x6ad11c580ec4c, 0x6ed437ce110bb,
+    0x6fe029fb17549, 0x7076521872aac, 0x723efb2f10d0a, 0x72acc00801780,
+    0x7587f2847c22d, 0x75ef1c7ba631d, 0x766aa97db2767, 0x78e1c88dd0f5c,
+    0x7b0c0be65ce51, 0x7e112943b8d42, 0x7ea7291ec032e, 0x826d97e4081b9,
+    0x86b3c05d85942, 0x8730e748ba3bc, 0x892f604d65a9e, 0x8996bd81b4c54,
+    0x8b6133bea05a8, 0x8bcdb2dc00ea1, 0x8bf4e2b18d4ea, 0x8ccc2d6493849,
+    0x90b01a92d355f, 0x925f0b7858c5a, 0x976613e4a70a2, 0x98525b59fe9ad,
+    0x9c660ffa13889, 0x9c68adbde585c, 0x9df0a5b3f3f99, 0x9ee3ee855c3b6,
+    0xa537eac3e869b, 0xa731d44510d02, 0xa84b8e9b32b64, 0xa86e697c41d82,
+    0xa9e2d9ffc3b91, 0xac70e04003e50, 0xaf5ef1d3da8c5, 0xafd45cbb1c1ee,
+    0xb0712ab50f226, 0xb0bfff7e33758, 0xb13921f992c95, 0xb323bbb528fd1,
+    0xb33fdac3fe47c, 0xb5099a01f7d56, 0xb71114c626706, 0xb7297fe9ce562,
+    0xb868c8da73853, 0xbb1afab223ffc, 0xbbf18abd47d1c, 0xbc86a05c381c8,
+    0xbdc5b60fb83f3, 0xbe80fefb6c0cf, 0xbea656afd9ed3, 0xbeed4732b733d,
+    0xbf8e3a8cf2c9f, 0xbffa5e2ada55b, 0xc004676df7791, 0xc00484b57a5b9,
+    0xc048e9c137729, 0xc2588703a33b6, 0xc8177feff0ec8, 0xc966c10158e4a,
+    0xc9f7ebcf0661c, 0xca412d39fe130, 0xcd15326f23b8f, 0xce38883c37f11,
+    0xce94b7624d101, 0xd087ae2b2636a, 0xd1431f592dfbd, 0xd1fd4722b54ce,
+    0xd416abc757bb7, 0xd44710998c594, 0xd464163b885ba, 0xd81d060a7bdd5,
+    0xd8af2d60f23c0, 0xdaddc956ebca5, 0xdae8356b62aa2, 0xdb4b1cbac1440,
+    0xdd01c5fb496c7, 0xde44fd6621c57, 0xdef025eebed2b, 0xdf4d30ad29947,
+    0xe1264aab8669b, 0xe1bf01e8f24bd, 0xe24e8b7b96ad5, 0xe269f38b79f06,
+    0xe281572b87dfa, 0xe347172683a22, 0xe3570b9e560ee, 0xe68b89f33d019,
+    0xea3b2ecfe6202, 0xeab171a68cf92, 0xee8d2a09ddcf2, 0xf0a3e998cd87e,
+    0xf10ca6273f414, 0xf2198d8b2db31, 0xf3db38eeab842, 0xf4a2a51f81773,
+    0xf542aa94b59d0, 0xf6240985aa0b4, 0xf6b8b5015fe0f, 0xf783a1e03e2c9,
+    0xf9c1530cc4289, 0xfdb03b0c18e9f, 0xfe5506eae5802, 0xfea6f5e7c8131,
+    0x027fef96d3ad1, 0x04e854efdec28, 0x0577c13b00418, 0x05d368f65808f,
+    0x07958977e1640, 0x0861ce5f01950, 0x09ef7aa3afe98, 0x0a524fa65cb5d,
+    0x0af24a20bfc6b, 0x0cb7ca5fde3b8, 0x0d0b0a1321fb3, 0x0d7cf7f54b918,
+    0x0d8f0dc103b8d, 0x0defcc6a9bdf8, 0x0e7928e7a47a0, 0x0ed14c4c038c9,
+    0x0efeb7daed296, 0x0f940bb1d3957, 0x108599a9a0357, 0x10a561a8d1d02,
+    0x10a9e9da741f3, 0x116f6f466a1cb, 0x11801e661eed6, 0x11e7485d48fc6,
+    0x12bf6affb478c, 0x1344eb4f3bb6e, 0x15668867bd7d2, 0x174a3a65d585f,
+    0x18666de665ce2, 0x1b68af47f99eb, 0x1c10696f4020f, 0x1d286199e5aca,
+    0x1d69dc061933e, 0x1e108b00d5635, 0x1e25bea93c26c, 0x1e6d92164fc8c,
+    0x1f0c7dbb382a8, 0x20b9d4b795373, 0x20e78d2236669, 0x211cfde7da4eb,
+    0x21a48d3e9579d, 0x23834595f005e, 0x269d1dd4b6d21, 0x27183961a7e90,
+    0x299d9d0ace483, 0x2b3f373c53485, 0x2cd3aa4e0c88f, 0x2db96cd73fab2,
+    0x2ddbd29a43430, 0x323ce6b3b7bc0, 0x329af1a3dbbba, 0x34087e12cfcdf,
+    0x3489d1b02dc0d, 0x34b1dd1e0fc82, 0x34dec1423c0d6, 0x3558eeea25b40,
+    0x3951083d32525, 0x3a319de282eb6, 0x4b21a59df8b62, 0x4b9d623439aad,
+    0x4cec1f83d4a7b, 0x525ff0404ab20, 0x528b3a7b4e944, 0x539b72f910ae9,
+    0x53f0fc428e204, 0x54be9588ffda5, 0x5623c893a1562, 0x57281c04376c7,
+    0x5763e973abcba, 0x57886938b4657, 0x59aef8a612efd, 0x5a261e66f0243,
+    0x5ba7f7eb24424, 0x5cce6f3dd772d, 0x5d83284295bd2, 0x5dfb8172513bc,
+    0x5eca7a12e593d, 0x5ee7d3e279e16, 0x6054eb336269b, 0x60cc47da5506c,
+    0x6389f0f306b3f, 0x63de46cda5db6, 0x63e4c467e9914, 0x6555516c095d3,
+    0x671fd9f5a6c00, 0x67423c0fb9fb9, 0x685796768d8a1, 0x6984b3a5d867f,
+    0x6af09b30d3025, 0x6d1ab9effb568, 0x6d2a98722b996, 0x6dd465499664f,
+    0x7039882c8f432, 0x703f38d23ec78, 0x731d17b64ce0c, 0x78670fde93746,
+    0x7a2a4a3068938, 0x7ac5a2da96cf2, 0x7acabd71c78ab, 0x7ed0d5d4052ef,
+    0x7ed68dcb956bf, 0x7f16008275bed, 0x7fece86422385, 0x80d2ecce3bd82,
+    0x8105e81261ba8, 0x8159e616783a7, 0x82242e43164c0, 0x8438e9c5a32cb,
+    0x8511a9c86e2a8, 0x85d857a8715d5, 0x8867447bd840f, 0x896f8db88239d,
+    0x8ba038b5afb52, 0x8c29c11dfca36, 0x9018a1cb70ac2, 0x910c0b8d4c2cc,
+    0x919330c0cce2d, 0x92dbc4a3bc750, 0x935e82960e1ad, 0x972c30ef5cd5f,
+    0x9861dac01f56b, 0x9d8e19f0683ca, 0xa2fea836cc0fb, 0xa31600dc08ea0,
+    0xa40d591817949, 0xa52f3243864c3, 0xa55dc9ef6d7aa, 0xa6a4895a2ee4d,
+    0xa7165d9dc5f4f, 0xa7e8d34372494, 0xaa20af30e525c, 0xab0e141f78d6d,
+    0xad3fa958bd662, 0xae5f737cf830c, 0xb25bf7ba243ba, 0xb2b6058c53a01,
+    0xb508e30f5fa6e, 0xb53994bd4bd74, 0xb5f6c0c4ef3e4, 0xb70170df55cba,
+    0xb7c84701acf5e, 0xb825d92ad50f3, 0xb843a81874e6c, 0xbbc631eadc576,
+    0xbdafc079e8385, 0xbdccf95909471, 0xbe3f6afcffd8a, 0xc03356fcc1282,
+    0xc18d8b8d5d57a, 0xc287dd0cb7033, 0xc66780414aa6d, 0xc7e9d6356b078,
+    0xc8a91c3f13707, 0xc8df823b9f818, 0xc9b7dbc420669, 0xcad6f9e43a3e8,
+    0xcb472943d7d6b, 0xcd6b4a819913f, 0xcd9c54060debd, 0xce0d9d35f7481,
+    0xce476deae987d, 0xd0f405128ca7e, 0xd1eec0b520c88, 0xd27b7946ce207,
+    0xd5709ada76ea2, 0xd5949a86a2e50, 0xd610d735c078a, 0xd705de89b4b89,
+    0xd72efc7601cb5, 0xd887eb4b0de30, 0xdc9f45328ec47, 0xddffc7c939f9c,
+    0xe365036800355, 0xe56fcb9d121c8, 0xe70fda89302ae, 0xe71139e352c8e,
+    0xe738710abfe61, 0xe7b0a1f8276d4, 0xe82121dc6cf45, 0xe88e83e403df4,
+    0xeae515523d4b0, 0xeb3d22c0fa33b, 0xebde8b394153d, 0xee9dce3b1b640,
+    0xf01c7b3edf74b, 0xf270a5cf5c4bf, 0xf377a1fe95746, 0xf4227dab7aef1,
+    0xf43608c6f7de4, 0xf44ff43535a0d, 0xf60c013fb5bb1, 0xf620bfca10f48,
+    0xf690a24df6fa2, 0xf6a6ec1db9255, 0xf7814d5b8d3ec, 0xfc51108a09732,
+    0xfc9bc744c5ec4, 0xfcde542f647ef, 0xfcf62ced7df83, 0xfcfe8651d08eb,
+    0x021b721396e5a, 0x02c67602d057c, 0x03b4a0941781b, 0x03c28604b6017,
+    0x05a0039b518aa, 0x071d5144f2fd5, 0x0785189c7c8dc, 0x082fd701df25f,
+    0x087021a824bf4, 0x08917143ccef6, 0x0c2f791bb9ccf, 0x0dc4aa7e45cdd,
+    0x0df40f1ec12dc, 0x0fcc96b78f968, 0x107319c707723, 0x11c6c2199e7a9,
+    0x129ba5eee81c0, 0x1334e84001b20, 0x150b55d6cb18d, 0x17f51276247d7,
+    0x18b39a2efa262, 0x194e5921b93ca, 0x1a34479630b29, 0x1a4ae93c7b854,
+    0x1dee7977c3efc, 0x1e5c78dfba5c2, 0x2027f2f74f8b9, 0x22cc8fe309754,
+    0x23562a9808311, 0x23c7aaae06f60, 0x23dd6d130bc9a, 0x2416e99a65be3,
+    0x256051c3ca3a8, 0x25b1c7f9b0e72, 0x279c655e37773, 0x28be338ed519e,
+    0x2a19aa321104e, 0x2cfe6981bc907, 0x2e4f0d963782e, 0x2ec6370005139,
+    0x3013705c19d75, 0x338ea4a4d84ba, 0x3394d563646ef, 0x33d326f818452,
+    0x343707274f644, 0x37e76dfdbb9f6, 0x37fb8f27b7576, 0x3811d1a598c9f,
+    0x38cf25ef90286, 0x3b3c84ef58ba9, 0x3b95f1b838414, 0x41c904d566583,
+    0x423e9ba7ec3e8, 0x45df991a33c0c, 0x4a0ba2e84e6a6, 0x4c20ff742b28d,
+    0x4e5fa98089aa1, 0x4f4659eec43c9, 0x4f80f7984aadd, 0x55bf17d33f825,
+    0x570adbdf8f7e3, 0x57e07a5cbb839, 0x5848f50a46bf5, 0x58a8420d0a7a7,
+    0x59d12ee13bb0c, 0x5acb8f0456cd9, 0x5caeb5eec1228, 0x5cb0f8331a1be,
+    0x5d731a388aa35, 0x5ddc61daaa109, 0x5e4e2b23700bc, 0x61680e5d07ece,
+    0x62190fcda875f, 0x631ee731c4c56, 0x648458745c628, 0x6541a1c382ac0,
+    0x6815b59c69c33, 0x697a56417cd28, 0x69e66040d1d81, 0x6af2c03a02f25,
+    0x6b04c3b9094c1, 0x6b7f667efe7cb, 0x6c5aae4ff94dd, 0x6ca8a3d7d7a1e,
+    0x6cd77d64a54df, 0x6dc39545c82e9, 0x6e1d180449df2, 0x6e573ce6d46a1,
+    0x6ea82bb1fdbf2, 0x6ecc7839e14c9, 0x6f8d71cb449eb, 0x6fbe89f37ae7d,
+    0x7076a65ebea83, 0x7128abaa08cb7, 0x714e8e72245f9, 0x72c924b9614ee,
+    0x743c1c442e0ba, 0x74f54ed6cd33c, 0x7583e6db79c8a, 0x78864cd671345,
+    0x79de88557ba0b, 0x7ae846e1ea617, 0x7b348c8afe28a, 0x7ec2d3283da5d,
+    0x8120c17200c68, 0x82502f89660f0, 0x836cd47f1184e, 0x83ca371404ee2,
+    0x83cc6362bbbda, 0x8471b6ac45ab6, 0x84791e82a0754, 0x854062712270e,
+    0x855e89354aeff, 0x85f09a961f24c, 0x87d49d18df1c7, 0x8b16a55eeb8b1,
+    0x8c6deba70dce8, 0x8fefcd1e44a8c, 0x9453ae8fbfcf0, 0x96b41d55d20a6,
+    0x978cadc468582, 0x97b197acab670, 0x97d1b78265a93, 0x98303b39858f2,
+    0x98fe25049f381, 0x994c4677c1dfe, 0x9a277fa4fb3fc, 0x9a9582b5e2087,
+    0x9ad9b0db89b6c, 0x9b568e93f7282, 0x9c3cbb4064bf6, 0x9d9c031650f1d,
+    0x9ebbcd3a8bbc7, 0x9f098833644b8, 0xa2f6d9f281063, 0xa3bfe75e6014e,
+    0xa4857073466eb, 0xa5019ad5b234c, 0xa58829fab4220, 0xa920bbbbf7403,
+    0xaa058cb73295c, 0xac3c109c638ce, 0xac9f78049ec5b, 0xb00b010852038,
+    0xb0a8775d759d6, 0xb1166478ba3c3, 0xb14094e3725a6, 0xb1dc5f02bbc3b,
+    0xb4ac8bb350699, 0xb5907d6d45c01, 0xb60e1d1f764e0, 0xb90e1141e0104,
+    0xbe2ef2cfba49c, 0xbe8fc01d13e1b, 0xbf88f7cc21c93, 0xc03d7641da4e8,
+    0xc161cc40a7c48, 0xc1bc94bab98ce, 0xc220ccc079538, 0xc2aa37e1435f4,
+    0xc5625896997f8, 0xc6f4e4e372ca9, 0xc7467c09ccb60, 0xc7d601f380bb3,
+    0xc8d341bb5452d, 0xcb02c7ed64f52, 0xcb775ae541414, 0xcc0355261bd8f,
+    0xcd1ecdfec9bd3, 0xcfe0ecfc6bebe, 0xd08d15b6c2370, 0xd0dcbec65b744,
+    0xd166fa84aa0dd, 0xd758bf20e8f14, 0xdb09558b89dc7, 0xdbbf83e00ceea,
+    0xdbefe16060d3d, 0xdc450f2a6541b, 0xded4a458fcbbb, 0xe06c319e743c2,
+    0xe07e6fac805ae, 0xe2168bae95eb8, 0xe4bb4238e25b6, 0xe5d96ecb0466b,
+    0xe680b02b4f02a, 0xe9a937265823c, 0xe9f0f49dc99be, 0xea752f31c0c23,
+    0xeb0f99f6e78d8, 0xec96c14f71738, 0xeca59bf6f81c3, 0xf05eb4083f955,
+    0xf10f823bbb120, 0xf186a453a7ea1, 0xf29ed36462de7, 0xf2e90daf3314f,
+    0xf5bfdc936f0bd, 0xf6cc17f33c8af, 0xf737d8bfca5a4, 0xf7e2c31071463,
+    0xf8a2b1754a458, 0xf9a68875f4193, 0xfa73427b1fd43, 0xfb505b1b58710,
+    0xfc414bb2c58f9, 0xff5d8acf48f04, 0xffff2a2da5791, 0x02d20e409ea25,
+    0x035b54c80512f, 0x043c92c886426, 0x045e6625fb6dc, 0x047d613098d6f,
+    0x05494e4130607, 0x0572c404061ab, 0x07ffd50d5e1db, 0x081c952583462,
+    0x0991353d39d72, 0x0a81dca1dfbf7, 0x0c49f6fbdfd52, 0x0ccf68a7a5a20,
+    0x0dbc671bef3a5, 0x0f5987bf935ca, 0x10be544feabfb, 0x142d7b4929389,
+    0x153bf8ed4fb11, 0x16190692b738f, 0x169d20363b207, 0x16b02ee1cbcd0,
+    0x175b74b1ebbcc, 0x178be827e1cbd, 0x17bae64e13130, 0x19f392d90a7d5,
+    0x1a6f89fe51370, 0x1aea63aa5bd05, 0x1b15267aa25b0, 0x1d1194210a387,
+    0x1dcef70ec3082, 0x21b6e15af774b, 0x226ef6745a7c7, 0x274c0904f667c,
+    0x284fa1cdaa1a2, 0x286a89c4b0be4, 0x29d6540828762, 0x29f343b4824ea,
+    0x2b6d020c59f78, 0x2c383f6fe0320, 0x2c83ca7111954, 0x2ce03f20fd8e3,
+    0x2ceef186303f7, 0x2dce959d890be, 0x2f73b42606c0e, 0x312db97b33a6c,
+    0x31d7cf8565a89, 0x341e73cdad603, 0x374ed06b3c1c9, 0x39375b1f9e635,
+    0x3b699c5d03e55, 0x3e5e99574913e, 0x3e81c4bd0024a, 0x42ba54a196b00,
+    0x45f3a0b1f6cbb, 0x4b88c10a15062, 0x4ec3701d881c2, 0x573e1910f5b75,
+    0x57f13f7e6c574, 0x580b40e24c43b, 0x5a4f1080ac957, 0x5a7434f7f5695,
+    0x5e223147b4b3a, 0x5fc1bc7205c6c, 0x60dbc3a3df3f7, 0x62fc1ea9c14a3,
+    0x6319871d17090, 0x63c55457f4505, 0x642700e693e75, 0x64ce3e9dee26f,
+    0x66252d0f87c2e, 0x695494762e565, 0x69fe26be935ce, 0x6a4688e8450f1,
+    0x6b3174ac6a5e1, 0x6ca1c3789408b, 0x6eb61fd2b7894, 0x6fee8fa99ffea,
+    0x70fbd28d07744, 0x73e649d4433cd, 0x74a083ea7c5b7, 0x77c60a5059c2f,
+    0x78d3ee3d11165, 0x7a6b1c5a1f36a, 0x7b3e071dd714f, 0x7c106e1fc1f80,
+    0x81c4b1c4d1931, 0x821336094df75, 0x823e9639f4037, 0x84554725224af,
+    0x852c4ff742034, 0x86b8157710623, 0x8739322e58ec6, 0x88c0d5f6cf150,
+    0x8929e309e8bd4, 0x8abe64bf636f2, 0x8b1cdd7bb2402, 0x8b82d403fe04e,
+    0x8bcc1cc0d66ec, 0x8bd5c6db8a320, 0x8c9a86a4ccb6a, 0x8ccfddcbde189,
+    0x8e8674bfb4737, 0x8e8b168fe948b, 0x8f1c48af777e7, 0x8f7ca0dec58c6,
+    0x8fdec9dd51660, 0x904aded777808, 0x91b10e6ae1dde, 0x937d51ce1ae28,
+    0x941e69c1ba13c, 0x9435c9b8d7a6b, 0x9507049dc4f82, 0x95df189c6f034,
+    0x961dc9598c399, 0x9724d0839676f, 0x980e13bad1f1b, 0x9c05d18e658c3,
+    0xa1162c3edeec7, 0xa4dc975b3678d, 0xa94f3d7e961de, 0xaac16f66af61c,
+    0xabd3800590006, 0xac4a603c965ad, 0xac78f7e87d894, 0xad7ae0d266aec,
+    0xadf536bca44cd, 0xb07f6bca34315, 0xb0e72f78cd657, 0xb2dbe86d22e67,
+    0xb38fb38cd9c07, 0xb3990228147fe, 0xb4718049f9001, 0xb51f3b9b96f59,
+    0xb6e0d85b53556, 0xbc0c67de8aec5, 0xbd877343d365a, 0xbe7e0d2f0f4ff,
+    0xbf571db6823ca, 0xc09f97fadf48a, 0xc1778eb206714, 0xc2eced1a8fc28,
+    0xc3664a24f4db5, 0xc3ee0566f45a3, 0xc63bb6061dd7e, 0xc75be2fbb25ef,
+    0xc8e061959932d, 0xca4d78e681bb2, 0xd00ae2e4781e3, 0xd157c46a043a7,
+    0xd2d4d3dbaf8bd, 0xd36923d7ec5db, 0xd50c8034edb84, 0xda603c167a955,
+    0xda63230d13c8c, 0xdc3d8dc1d1cac, 0xdc593f047c441, 0xdde30f1ba93c3,
+    0xe0efd6304672c, 0xe2eabc3a37bac, 0xe32564de8f40f, 0xe5709c534a5c9,
+    0xe6503cc1b2ccb, 0xe8e3e503e10bc, 0xebb5af468e70f, 0xed0c0400b8e7c,
+    0xee934c49b60c9, 0xf0b54138c07a5, 0xf1394fe1734ce, 0xf41fbf0fe9655,
+    0xf4b2a86022e09, 0xf54de019dddd6, 0xf64717c8ebc4e, 0xf67ec38107edc,
+    0xf693980105511, 0xf8a10c9daaa6b, 0xfc069f71d7863, 0xfd510b75e59cb,
+    0xfe563a7ed155c, 0xff19b835741ee, 0x005d8d00abf95, 0x01c60d7c30c15,
+    0x03795aa8142c5, 0x04206522495f9, 0x09337e8707fbd, 0x0b0d56d637915,
+    0x0b2624cb39338, 0x0c7a966974111, 0x0ccf4b6c7c98a, 0x0daebc46b058b,
+    0x0f80b04f98e1b, 0x1191b7e6f85d7, 0x120c152316b42, 0x12c6ec99af543,
+    0x131d726bf5a77, 0x13877c07d8314, 0x13d4f176d9e66, 0x14009ada4728c,
+    0x1417adf5ad292, 0x16557c69b607a, 0x17780542360e4, 0x1937252e93afb,
+    0x1c32bd0a9f76a, 0x1ce58f4a7dcb6, 0x1dfef91bf7c2a, 0x1e7bf7c4d872d,
+    0x1f47aa466a375, 0x1f6086df2d4ac, 0x23c848271a29b, 0x23d8ec4bfde57,
+    0x26c9e87f5e1c8, 0x272bc0f942074, 0x279edea1598b8, 0x2911267f14f94,
+    0x2bf7d3e581330, 0x2de89364d2752, 0x2fad04ce763ad, 0x305b896bb8058,
+    0x30e8df5dc4dee, 0x315ec30c0257c, 0x31627aa020190, 0x345af8486da02,
+    0x34d2d15f4c7fd, 0x35c13a2889cb1, 0x36e3812023541, 0x3e0daa987997f,
+    0x3e31526e1ceb5, 0x3f9d4feeb9af9, 0x462f424280b04, 0x4e024e562aabb,
+    0x4fd63075d3e2e, 0x51e3dfa17efd8, 0x59db7dc6e3605, 0x5c44beb844188,
+    0x5d225ec33a0ce, 0x60c6a5fd747f0, 0x613ca34a447e1, 0x6412c2816f25f,
+    0x66273fcc05e55, 0x67b1ce34059db, 0x6b387d66b5a0f, 0x6ee0fff6dacf9,
+    0x6f64af7724420, 0x70bf08a1240ca, 0x720d547ba3dbd, 0x739b84821f2b9,
+    0x74a5973c26378, 0x74b6fd5acd0fd, 0x76f1b19b17ae8, 0x7732ba9230081,
+    0x77a70457451df, 0x7deb729848f84, 0x82141556edcf8, 0x8240d138c61d5,
+    0x86919caccfde6, 0x8a247dc863783, 0x8f80512d5c6e2, 0x93b843b193781,
+    0x9606696ec87fc, 0x961cb33e8aaaf, 0x96bda2efd5e4c, 0x9a339f59e0bb0,
+    0x9a3f0ba010d8b, 0x9b45b3a1b1b5f, 0x9ba51debf8539, 0x9c09683e69e7c,
+    0x9ebe79bad2dd2, 0xa0925bda7c145, 0xa3bd2170edd28, 0xa4e3a01581bbb,
+    0xa7ae14ce86249, 0xab6cf7240fa84, 0xac315b6dd9291, 0xacc374208e168,
+    0xaefc71302d6fb, 0xafbf761fd4528, 0xb276d4db67563, 0xb38e877c3607f,
+    0xb3f0cdc244c41, 0xb8087f804e4d0, 0xb82e624869e12, 0xbe3d1de2e355b,
+    0xbf6f3c0acc68f, 0xbf714ebaf0b24, 0xc0ab49d740196, 0xc54e80b818b00,
+    0xc690e0338bc29, 0xc6f8e5c30b745, 0xc864a50bb2174, 0xc88f30f5e3394,
+    0xca501a5f9dedc, 0xcb439dc00bf49, 0xcbec50c72afc1, 0xcc7099fee393a,
+    0xcd1e6b4623b30, 0xcde6663397b64, 0xcf0b76da47903, 0xcf0cc03ec8045,
+    0xd094d92549b6f, 0xd0adf3f602ebb, 0xd36bb6ad471f1, 0xd5dc5bcc1244d,
+    0xd73ec8d08dcc0, 0xd803ad3333ebc, 0xd893edc4ca54e, 0xda0025d46cde2,
+    0xdc04cbeeb4134, 0xdd03a59fb00de, 0xde71654bc92c9, 0xdecde4f6863a7,
+    0xe045c0326e4a1, 0xe116a6e9c3505, 0xe39fe6c06b0be, 0xe3d70b0dc9dd3,
+    0xe464addb8e492, 0xe49a81728bedb, 0xe4d6b9053ac1f, 0xe53b578544a15,
+    0xe8ffa4f6a6cf7, 0xea00aaf659999, 0xeaf2319c4580f, 0xeb408d9e6dedc,
+    0xebce42b8e4274, 0xebf050a55f17a, 0xec246d0bb176b, 0xec8c4306fc786,
+    0xef01ae8f600e8, 0xeff9f10785cd1, 0xf0383ef34946f, 0xf0df287d0b3b6,
+    0xf199997959c2b, 0xf1add441e800e, 0xf2dc581d36356, 0xf3fb380559ec0,
+    0xf508c7c478f43, 0xf66c4af04fe32, 0xf67c8fecca3ec, 0xf93713d6ec973,
+    0xf9817a0d01217, 0xf9bf6c794b978, 0xfb06edddd01e4, 0xfb36094b8447f,
+    0xfc505607349a9, 0xfedc42456fc49, 0xfeee87a55c9bf, 0xfef931f1c99d1,
+    0xff680cf215ac3, 0x01a515c36b11d, 0x05e1e0fdec59b, 0x064ca541b1477,
+    0x07237384cb3ac, 0x0874d9930949c, 0x0ce11bbab7bf2, 0x0d0ccc7005ba2,
+    0x0ddc06f1808fd, 0x0e9b7c8f5da8d, 0x0f095b06e0d66, 0x0fbff37e9e5da,
+    0x1126564f2dc76, 0x12b0e8601ddc1, 0x1381f007e6212, 0x13ddadb8e0127,
+    0x13e2448e43d2c, 0x144dce74bc396, 0x145506b6e2533, 0x14e279f0720f1,
+    0x151002c6de8e6, 0x16eb874be8547, 0x189146ddb5e73, 0x18d75bc83d8b1,
+    0x1b0060b71a1b3, 0x1c6d3d78fcde8, 0x1d556336fc38e, 0x1f4c4fcbe9420,
+    0x22414cc62e709, 0x245ec82758008, 0x247ca20fc8ed0, 0x26b0d8b5cfd5d,
+    0x2739f351f1f2b, 0x28f80468d4e50, 0x297be7264363d, 0x2c799904542cb,
+    0x2cbb3bb2dbab6, 0x2dc6907f8272d, 0x2ef73957a6be3, 0x3011910e2825c,
+    0x3748a36e5407d, 0x374f19b6b7051, 0x385649231539e, 0x38cab7818deae,
+    0x3909b51a62b3c, 0x3c74af6177e16, 0x3d00e7da489a6, 0x3dbdc35d44128,
+    0x3e930dacd7ccb, 0x3f43ea8414baa, 0x46852717d0fee, 0x4f1311f3f4530,
+    0x4fe0c881e8ef9, 0x5053fc1fa29db, 0x5803939ace522, 0x5946fe42cbb78,
+    0x5a3119696c78b, 0x5aa03d9c7fbe1, 0x5aeaa3d294485, 0x5c4908be4a1f6,
+    0x5e365d853532d, 0x5e7ad4dda4176, 0x5ea0ee8bd5143, 0x63c8d8c7a0b77,
+    0x6658cd1ea1919, 0x665f3c1523d63, 0x682835b069eaf, 0x6ad2d01d8aeb9,
+    0x6af4cbbd540e6, 0x6b1263c4de7d4, 0x6b319d077207c, 0x6bbba9318bf14,
+    0x6c1332de7bdeb, 0x6cb75a0b083ad, 0x6dc0a3796b719, 0x703b8c6a597fb,
+    0x713af4d7f38a8, 0x71bc6213e4039, 0x738f4efca511d, 0x764b82c591f72,
+    0x76c2c976e26a5, 0x7a05766f2f130, 0x7a54e4efc28b4, 0x7a81ea04620f5,
+    0x7af8c2e987b12, 0x7e85dd69c99cb, 0x7ea0663866e0b, 0x7fbc876c455b5,
+    0x86016acb54bfa, 0x88168cc82bb91, 0x8a27efdf0438a, 0x8a796614eae54,
+    0x8a80468088579, 0x8f0e2c7177484, 0x901032a2e3504, 0x9218680ef44f3,
+    0x92fe47dfaa53e, 0x93bf9947964d8, 0x94fd325970efb, 0x965f4b30542bb,
+    0x96893d6316289, 0x972d17b3eaf22, 0x98055b46c9ad5, 0x9864e6818389c,
+    0x9919c7c895cb8, 0x999758d704e83, 0x9bb3280254479, 0x9dcfcb7418911,
+    0x9ffc80a53229d, 0xa03069ce5f7c8, 0xa1715b4bee7fd, 0xa3694469a46a8,
+    0xa397f5b41e1f2, 0xa4a38c61ab643, 0xa63c003a499c5, 0xa75541c96f9c2,
+    0xa87b1812d2eef, 0xaad8b22efdc47, 0xab3e24f57c8df, 0xab9eca00822e7,
+    0xac04fec0c4148, 0xae6beeca0a621, 0xb2898f65b990b, 0xb51774b5867dd,
+    0xb5433b6076a2b, 0xb70f3939d8cd6, 0xb75342c61ce09, 0xb8a99b2937b3b,
+    0xb8f10bc4f1994, 0xb8f8c07703f5b, 0xb979387c0c45d, 0xb9ed44092b3a6,
+    0xbae577dd8f87b, 0xbb82dbe601540, 0xbd626fd5b182d, 0xbfbe4f1840b68,
+    0xc14a5a21e5ef6, 0xc2f71766d3d6f, 0xc45e3fda16b99, 0xc46e8c2871cdd,
+    0xc59d349d239d7, 0xc5f93f29d5215, 0xc63bb9c7c1e67, 0xc79ca681a790d,
+    0xc81411cc5b9f2, 0xc9ec15a35d0ca, 0xcbd950cbb599e, 0xcefea3f46df50,
+    0xcf31058124b24, 0xcfa5a373d2135, 0xcff5fc307c9f9, 0xd0c332a5949d3,
+    0xd12e735945cd9, 0xd1996ada2fc7b, 0xd2bc9fb6d0c10, 0xd3092ae9bb622,
+    0xd42ead058669c, 0xd4b11e1c207d0, 0xd5ded159ea23b, 0xda453347b464a,
+    0xdadc4cf307877, 0xdc215c7efe64c, 0xdc45254514f6f, 0xde40d8439a707,
+    0xdfc8208c97954, 0xe335472263926, 0xe3d082850eeb8, 0xe6275d260f8d8,
+    0xe6a8a21fac0f2, 0xe7807f3840b19, 0xe7f6927ab2da8, 0xe8c1d38729715,
+    0xe976d9679f4e3, 0xf01729f300b4f, 0xf0cecd97488f0, 0xf38d6be6e2652,
+    0xf701b4c932523, 0xf7757e756ac92, 0xf7f9ec4686fbd, 0xf866ca8c50eb8,
+    0xfa265f96ba16f, 0xfa7c11228b801, 0xff9992f2d09fd, 0xfff8588ad7036,
+    0x0046f66de5edd, 0x006dad7c766c1, 0x01a80f130febf, 0x01ce1a1d7f778,
+    0x028386783f6d2, 0x0291efaaaae82, 0x066b41303f29a, 0x07ef2d6497910,
+    0x0b4bc97412589, 0x0d40985e0a037, 0x0efa71c7f2959, 0x115dcb2d8e60b,
+    0x1175570ff0476, 0x11d34c0a721d2, 0x12478382d5657, 0x12bf4a4d02779,
+    0x13faf8f10cc7e, 0x14293c6f5bab2, 0x14c75776bf7f1, 0x18809b734b4bf,
+    0x1b19ed094856c, 0x1c98f93575c79, 0x1cafbf7524356, 0x1e3fb1a71bdf9,
+    0x1f3dc5b564615, 0x203658b2320ec, 0x2141d5c12ccda, 0x22cc2d43171d5,
+    0x2479843f742a0, 0x248143ec579b6, 0x24c6dc66f2fca, 0x255ff675b89b3,
+    0x261f402851607, 0x2705f0968bf2f, 0x27a50bcfa904c, 0x2819c709d9485,
+    0x2856c0964b392, 0x2a56ab41cb12d, 0x2b84f0e523260, 0x2ba33feb9f9c8,
+    0x2e6f59889d7d5, 0x2eef21e0947e7, 0x30479f4085687, 0x324baba35d787,
+    0x336fb4c6735be, 0x34fabf9e5f56e, 0x35e0c7b169530, 0x38b9f6218125c,
+    0x3a0dd55a2d96d, 0x3b3a72709bd5c, 0x3b4d84c51cdea, 0x3bff14f25b77e,
+    0x3c061da04ce1a, 0x3d05b94b0bf8d, 0x3d1e664f9a5c3, 0x3eaf550a5ae7f,
+    0x46952a3fbeb60, 0x497ad77471b1e, 0x4d54931d40687, 0x4e398508eefcd,
+    0x4f1e76f49d913, 0x54bf3a7901520, 0x566964f4ee515, 0x592e54f387899,
+    0x5c40db5195b5c, 0x5e5224c9dbaf2, 0x5f2250d94e517, 0x61c3b649c718d,
+    0x62ed77646d394, 0x64a286bbccfeb, 0x6548b9469ceb8, 0x654ba03d361ef,
+    0x65617c40cd78c, 0x68c0a01e7813a, 0x6977264983cd5, 0x6a2cf5759d7f6,
+    0x6a49e521f757e, 0x6b77985fc0fe9, 0x6e1d73b52a477, 0x6edd8a5c573e3,
+    0x7016e8184723e, 0x709549c43ace6, 0x726523cb1e557, 0x73c11e30273bb,
+    0x7515bf6296c95, 0x7633c3b264dd3, 0x7945d89b57dbb, 0x79b328563cf91,
+    0x7d86d088026ed, 0x7f2da6412b695, 0x839de1ddde1d9, 0x83a3c217c2520,
+    0x84618b7fc53a7, 0x8572e8c8a42dc, 0x8621ba419d8b0, 0x86cafc055aa2b,
+    0x898ed233a816e, 0x95acb734c5d16, 0x971c8d39f395b, 0x992b1ba6e4af6,
+    0x9c8cf33e03715, 0x9f3f66f69a698, 0x9f42bbb95e6e5, 0xa01f2bfe6674c,
+    0xa1d375b312c15, 0xa2dad85c96028, 0xa3964238bd0f1, 0xa416ba3dc55f3,
+    0xa57e3e308145a, 0xa5fcb97b07765, 0xa729b962cf71b, 0xa9de81ac7130d,
+    0xabbe3dde75571, 0xac9bc0a1e868f, 0xaddbf025b44fb, 0xae1be69e619dd,
+    0xaff8d1cf6eba8, 0xb4b1aa24d8e5f, 0xb5cb598029b72, 0xb6b6d7a9dd72a,
+    0xb765b7c698412, 0xb8b5719efc1f9, 0xb99904308815f, 0xbabef06f8d92a,
+    0xbcf93a8c9dbc4, 0xbd2546c164bb1, 0xc04ff2b943f31, 0xc1c64daa9625e,
+    0xc31ae78b24fae, 0xc47e54c159bff, 0xc50dab16d9151, 0xc52880c12deba,
+    0xc60912bd8e286, 0xc67269036f06e, 0xc7500565749ef, 0xc794e6e11df89,
+    0xc97d3aaf6ad6a, 0xc9fe1cd7ad9bd, 0xcb5e4797d029a, 0xcb617f13114bf,
+    0xcc8ce8baa140a, 0xcee7e16a09bca, 0xd0c0481264e69, 0xd1e5b7e17e20a,
+    0xd241c9c0105d2, 0xd293d9ad662ee, 0xd2992ed39caf7, 0xd3c561c6d0795,
+    0xd79e9604e1d85, 0xd8bc9302cf339, 0xdb1f3cbb59afb, 0xdcd769ef68114,
+    0xddce0ed575108, 0xdf2ffbc113f8c, 0xe02f817630e61, 0xe0c7ebd7e535a,
+    0xe3f281da2243c, 0xe9cb36a079076, 0xe9dbfbb5d001f, 0xedfced6b52d91,
+    0xee51dcfd6125a, 0xefeb7cf2fcef6, 0xf1e348c92ff79, 0xf3d8b8bc77803,
+    0xf5a6afa76b6e0, 0xf606a8ae501bd, 0xfae7bc05d0fea, 0xfc541505e6c6b,
+    0x00719108325a3, 0x01a70b44c02ae, 0x032e329d4a10e, 0x065c8b2e75f53,
+    0x087747db5a492, 0x09097dd592191, 0x0a07f85e24b39, 0x0b75d8fab1111,
+    0x0c6b7a0614762, 0x0ee87cf907863, 0x103669ab1df54, 0x10c6aa3cb45e6,
+    0x10ea4ac076f92, 0x158993271c65d, 0x15b1d92404322, 0x18ffcdc91d5d6,
+    0x1b770dc9af1b8, 0x1ea19c7a0b710, 0x1f70d35295ea6, 0x214b69f298402,
+    0x2151eedebcaea, 0x2264c52050c62, 0x2368f3f783415, 0x25be7a39325a4,
+    0x290344dc74613, 0x294bd2f16a672, 0x296963a7141d6, 0x2b7e610a877bb,
+    0x30e182a748b55, 0x313e9fb26544a, 0x31c670ea06ed6, 0x3891d12166716,
+    0x394eeadc580ad, 0x3a094d34e520e, 0x3c1724a2e432f, 0x3fffa4fd97685,
+    0x495b4a109fb55, 0x4ca887b6c6d8f, 0x509eb69c03256, 0x51cda8437c2b4,
+    0x52531646519bd, 0x52d2991471c30, 0x53e91846622a8, 0x54ec1ea987706,
+    0x5674169f95e43, 0x56ddcc0de022d, 0x5778282f457ce, 0x598e79f20a644,
+    0x5cd8e6973a7e5, 0x5d1b3c9bc3a85, 0x5fe3606cada69, 0x648b05e096061,
+    0x649879dc19582, 0x6b38a22526c77, 0x6b9c183123718, 0x6cc8b19ea1542,
+    0x6dde3b99a992b, 0x6eeafabb440d1, 0x7616edb0f7640, 0x78404e1f4cf7f,
+    0x7864760dccea4, 0x7b75abb579e9b, 0x7bbf44f6fa427, 0x7bf5f4264d89c,
+    0x7d13ded789177, 0x8a987384edce4, 0x8c4793fea7ee0, 0x8c91bfa5b6b34,
+    0x8f33843e98dac, 0x9562809f3fd05, 0x95edefcc6cb42, 0x9657c28239d54,
+    0x9a365e3333826, 0x9c9eb13f8cca4, 0x9de7ded9eb819, 0x9e958b87c805d,
+    0x9f474ef22bab7, 0xa04295a86d7ff, 0xa2cdfa7beb526, 0xa90047f665f07,
+    0xa9eca90a50075, 0xab2db4267190d, 0xafbb3e97e77db, 0xb1313e09c0acb,
+    0xb144490c60fcf, 0xb409ddbd3a6f4, 0xb5f5c82f31cfc, 0xb657af4cd72bc,
+    0xb72102428d146, 0xb9a6783865412, 0xbc4b535c154c2, 0xbd3dcf38e95c7,
+    0xc23a7a3482326, 0xc276cf0eb3e92, 0xc63b02e183911, 0xc6fdbe9e633da,
+    0xc8372003437fa, 0xca086f59ebce9, 0xca1dc049d5748, 0xca5034233dff5,
+    0xce19c817153cc, 0xce819a696fe22, 0xd0676dbb6da58, 0xd0bbf6d331d95,
+    0xd1215af5ef319, 0xd15b872a5a752, 0xd16d994d22402, 0xd2e0021b50ecb,
+    0xd56ca9016e7aa, 0xd59973870839b, 0xd688083b89d8b, 0xd81bb5aa8fa07,
+    0xdb5e121e345a4, 0xdc838ce81ea94, 0xdcd8d0a7c5410, 0xde3f2c2673f22,
+    0xdedac308984f1, 0xe03736349d1ba, 0xe2f800d2edc14, 0xe3fb36ca47b73,
+    0xe92acabd54a1f, 0xe93bf64cf5b54, 0xe9ad5cc461f40, 0xeb22a537491b6,
+    0xed6741ca3d9ea, 0xee777a47ffb8f, 0xef43a5908d63c, 0xf16e0287abd94,
+    0xf95f6c4593bc7, 0x06c5c4392dca5, 0x07461052f1c6b, 0x07aec9387323c,
+    0x0854411b60aca, 0x0871590a0e7c9, 0x0f8889cc763dc, 0x10050fae5b07a,
+    0x10a2c7e4651f2, 0x113c695de8154, 0x12bd131c2e456, 0x12ed61f8c0b95,
+    0x15f98f55eecac, 0x18ec2cc4581d7, 0x1a714c678ecf1, 0x1a88d4a100597,
+    0x1af2aea8ae333, 0x1cf56303445dd, 0x1fc40f694326e, 0x214d66b97438b,
+    0x217528f48f09c, 0x2815e74c1b41e, 0x29cea33cc7b3a, 0x2ac290c0702f8,
+    0x2d89e04ae543a, 0x2f9c34efb58fd, 0x317e45b2c47d0, 0x323fa96762443,
+    0x32be9d78e45b3, 0x33ceb15d42da6, 0x350875937cd8d, 0x35670b974e8c5,
+    0x3c7b51ba2cbdc, 0x3d504f2e08e56, 0x3d5323d7f04b4, 0x3e39960e34bc7,
+    0x3e8958bc607fe, 0x3fd6ba5ac93b1, 0x4b74867f48059, 0x4e2e118991d8c,
+    0x4f6f622f8a3c3, 0x5406acb156163, 0x5426d03000b4b, 0x567926484f63f,
+    0x576f0cdd89a2f, 0x598abb1865c38, 0x5a6e4da9f1b9e, 0x5c1bc596c2056,
+    0x5cbb60e8bbb62, 0x615eefa01cf44, 0x6702d8531009d, 0x67b8e96010398,
+    0x67ddab05ff50f, 0x68eb5463b0df5, 0x6b9e2e9691f04, 0x6d8004ca9b187,
+    0x6ee901b60c231, 0x705fef0cecc26, 0x724e9f850a178, 0x738834270f65e,
+    0x7440f8ed83bca, 0x77001ea7daea5, 0x77c6835516e6e, 0x786c655b3ee4d,
+    0x7a1bfe9bf4eae, 0x7a611e4f9465d, 0x7d00d78a32fca, 0x82b63c516f146,
+    0x83070a2c252aa, 0x8e2df6199d9ab, 0x9169e396c00fe, 0x93ef29f8638c9,
+    0x98d619e0d8478, 0x9924cdb9895bd, 0x99f625e5f98fc, 0x9e2c26aa7f8f3,
+    0xa1daaa64fc311, 0xa2a579fc579a3, 0xa52ec4cdd06ab, 0xa586bc46eb298,
+    0xa6bc9202f1fe0, 0xab202a41a5ee0, 0xac7b5b5b0aff1, 0xaed3aef4c0c54,
+    0xb734a708f662e, 0xb81b8e5d465e1, 0xb9c8026f6d0f6, 0xbb658d364ba6c,
+    0xbd37b47c593c2, 0xbed51357f37fc, 0xc05635dd35963, 0xc0fc22de2ea91,
+    0xc2d1b133b210d, 0xc52ba60870f2a, 0xc557aaeb5738d, 0xc9cca948b2012,
+    0xcb3acbc624dc4, 0xcbbab8b77f788, 0xcbc38ae2cdf55, 0xccafc75d54711,
+    0xccde5b604b433, 0xd1c1432ffa4e0, 0xd1f8294562fe0, 0xd26550bdf423f,
+    0xd670a0fee5664, 0xd6f98fafc32f6, 0xd789ffd58e489, 0xd8fc64facc98d,
+    0xdd65c02be1dac, 0xddb7f85b8ba3f, 0xddd1d8cef8519, 0xdfed9204a5871,
+    0xe2ea43b0fd121, 0xe38ef99a277e6, 0xe4066fdfaca1a, 0xe47b869955e90,
+    0xe881b148455ad, 0xea934eee239f6, 0xeb9d56ad59966, 0xec40fdc109539,
+    0xeccdb2a9c64f3, 0xf21b208544267, 0xf47523fdc4798, 0xf5af97e10fc6f,
+    0xf95e7e6ce6254, 0xf9926ee7f4309, 0xfc2ad298e9cb1, 0xfca5b73fc5795,
+    0xfe6498f42cf97, 0xffa08d220e23b, 0x0029e5f62661e, 0x00ad62394ac7f,
+    0x027d648282467, 0x0428b7727c7b1, 0x05e8f88106993, 0x05efc69ff23df,
+    0x0606cb1796cd1, 0x07abab681e60c, 0x07c8caa8ace95, 0x0a3aaa883711f,
+    0x0ad08972cb31e, 0x0b4d21a161c95, 0x0db3de6d830a8, 0x109fc75b933ea,
+    0x12fda70194ee1, 0x1366848079e64, 0x14cebf7227d45, 0x17afb889f42d6,
+    0x186fe8cfb3aa5, 0x1be2bc623ecf8, 0x1d0933b4f2001, 0x1dec1a425d03c,
+    0x1df9c8cce61ad, 0x1f367b4b9a055, 0x2112f5078bf45, 0x234482eeefcb0,
+    0x2447c78a0b323, 0x24977099a46f7, 0x26659ac1bd69a, 0x27f5944595cc7,
+    0x30ff51ad9c3f2, 0x31b65448943b7, 0x33e135017fac3, 0x341d2361674a3,
+    0x359714f663ff7, 0x3aa4fdce4fb64, 0x3afbc92a6ce37, 0x3b29a2858151a,
+    0x3c7ccabf3bbb1, 0x3cef1b72bf0dd, 0x3cf523eef739b, 0x3e36c8c287e85,
+    0x3edafae9e5596, 0x4532a3c1be340, 0x47d62a861cb5f, 0x49968c851a12e,
+    0x510a9c1aa8421, 0x5429ea7018cda, 0x55203b288d81b, 0x56af815664393,
+    0x599b5f49a3586, 0x5acda20af006c, 0x5cfac8b124cd3, 0x5dd9bd1b6c4aa,
+    0x5f4980d3e8416, 0x6318a825ec20b, 0x66810bfb1009c, 0x669cb5ebd9ca7,
+    0x66b12eec5e29f, 0x678295bc8fcf2, 0x68107a6b3ab8b, 0x6a7d6f47c8d5d,
+    0x6b3e986d60d80, 0x6bfd62071cfe5, 0x6f21ce9caea1c, 0x70996f4990ec6,
+    0x72e5fb1d9d911, 0x73a9b363461f3, 0x7499534452110, 0x74ae625355395,
+    0x74d9e71d5ee09, 0x74e67f808c8fe, 0x74f16bade00ea, 0x78aac948fe61b,
+    0x79705606d517d, 0x7ad3606bb0207, 0x7b2c4d1bb3083, 0x81108074ebb71,
+    0x8266b43ea2ef1, 0x8297b671370e5, 0x82bb7f374da08, 0x8532dc7f62412,
+    0x865cc5dc5c590, 0x8aafe98a613d5, 0x8add6765fca7b, 0x8c29ed6c0fc02,
+    0x8d264a49acfc6, 0x9004d8dacc64a, 0x9111e4d81e719, 0x947c3e15e3c17,
+    0x96cec01976c47, 0x9929faa9c5be1, 0x99fb701db8d48, 0x9a6f23d44f219,
+    0x9c3b75db49977, 0xa423566ef0f63, 0xa72fc5ad05854, 0xa80e1cb6ed814,
+    0xa8c522fad5d9e, 0xaa87d5e1eda17, 0xabc630ed8b603, 0xaca87dc3873ec,
+    0xb23bee3cdaec5, 0xb395fa8b23246, 0xb463f2f9fe3e9, 0xb7618ee26cdd9,
+    0xb76997c217853, 0xb791bcce8c12b, 0xb8ea41805db55, 0xb958c4aa211cf,
+    0xbd080e075137b, 0xbd51029691566, 0xbd8622d78d4fa, 0xc24b46b46d97d,
+    0xc50e0a6450009, 0xccc2d9be2f531, 0xcd1b4da736548, 0xcdb89468253e5,
+    0xce271791e8a5f, 0xce7561475f453, 0xd2e0bcdbe702e, 0xe1fff9028179f,
+    0xe210fff8bef22, 0xe237f53f4591b, 0xe594c834d5c1f, 0xeb3637bd5a757,
+    0xed397e7d7f0c9, 0xf4018d9b0ae81, 0xf499bd6db972a, 0xf5bfbbf970bce,
+    0xf65bcf4b815c7, 0xfc077b54483fb, 0xfcc4b5ffad17f, 0xffc3680f771eb,
+    0x0150560352b2f, 0x01505d55336b9, 0x028f88fe55b82, 0x03b60ef4ca59f,
+    0x08ae47b4630ab, 0x08d970fef3ae2, 0x090392c5ea5b1, 0x097bc00a6185f,
+    0x0db1988c938df, 0x105be61dfcfc0, 0x125a333764166, 0x1335da30c847a,
+    0x136693309530a, 0x14d535c6e4aab, 0x15ca112f9496e, 0x172ff03e57056,
+    0x17e959b70b963, 0x187866d9c3b51, 0x1a33a6efaf9dd, 0x1afc4a3854377,
+    0x1b2ad34079f4a, 0x1c5cabde8c2df, 0x2095f66b051d4, 0x24a4d5fdc0296,
+    0x2619fd803411f, 0x27969b7cc435a, 0x28459538118a5, 0x28b4d309b755e,
+    0x2ab7b6f882309, 0x2e80cad37ccf1, 0x2eb85ced0671c, 0x2fa5874c945dd,
+    0x302df98d85e45, 0x30c02bdecd57f, 0x31fd4880bbb78, 0x32fe81bd938e0,
+    0x3560e24356d3e, 0x38182eb2380a0, 0x3c63631f24cce, 0x3c79dc831ba82,
+    0x436a2a4bae975, 0x4b76ba38933ff, 0x4cf50813edf08, 0x50cf7abbaeaeb,
+    0x529c62d127ed6, 0x54c4a5c64160f, 0x551e5bc1e81de, 0x554262bff4d16,
+    0x5aa40412202e3, 0x5afdd00369150, 0x5f54f821434d2, 0x5f81fd35e2d13,
+    0x630c79f252bf9, 0x643cce9cdebfc, 0x66ff36cd4824b, 0x694b9a5f00d1f,
+    0x6ad6d1223120b, 0x6d42e6bd793ec, 0x6d58f9a726014, 0x6e599cd57f0ef,
+    0x6fcfd32d6da6a, 0x705010a37031c, 0x732d731792086, 0x738901345749a,
+    0x73b62ae25a68d, 0x75f02823b2ffe, 0x769c841b2e576, 0x78051a8c55494,
+    0x7bde7e5e9b585, 0x7d2b9e1c1d95e, 0x7e04238fe2ceb, 0x7f0ada35451d3,
+    0x7f9a58cd1869c, 0x8198f2c23716b, 0x823a3df2fb545, 0x8529aee0f499a,
+    0x86d8aac14b1e4, 0x874b534b57188, 0x8bed7404d4476, 0x8e9845580ab0b,
+    0x8f0665b0745be, 0x8f248179cbc60, 0x932ebb9361a09, 0x98c8c6ee7be68,
+    0x9a0eb964a91f3, 0x9f343570d7755, 0xa3a82c4a98472, 0xa4198b7023cd4,
+    0xa6543fb06e6bf, 0xa9d1118b459f9, 0xabd78122e9e7c, 0xabdf98a65600a,
+    0xacfbc87df5ec8, 0xad65644dada4f, 0xaeda54ea0c24d, 0xb1499705c4504,
+    0xb530518c0acee, 0xb534ec0a5eeb8, 0xb68a3ce9dfc82, 0xb8cdb4b1b7726,
+    0xb9e72d26f2dae, 0xba66ee2d09236, 0xba86ab3169a92, 0xbd4f4f1b30465,
+    0xbd8731b961d7e, 0xbed5e7b71c1c2, 0xbee3755131f46, 0xc2f97e8251a91,
+    0xc3bfee2a5eba9, 0xc4002dd5d33ef, 0xc45db5042a435, 0xc7da6d406ef0c,
+    0xc945e3564e5d7, 0xcbc4494c5467d, 0xcd4ea4772f13d, 0xcfbf92c8c16fd,
+    0xd2d2d3ceb1fff, 0xd5ab354a35a13, 0xd68dd2a4d96ea, 0xd6c9274d51e78,
+    0xdb4e5c03659a3, 0xdb91655df06f8, 0xdd2032c4cb40b, 0xdd635d0fc954d,
+    0xe2599cbdd0427, 0xe499d5b88611c, 0xe74b3e4492b72, 0xe8412fd49e0b1,
+    0xea6f56ac8c0f6, 0xea75493322116, 0xede1106ecb708, 0xee40895cd37f6,
+    0xf3ae763674f8f, 0xf3f3f8bb6e305, 0xf722a57a325fd, 0xf87cb91a5b508,
+    0xfca111df53fa0, 0xfcc6a079d742f, 0xfd8a4672ce038, 0xff72b3dfad67c,
+    0x003f6de4d922c, 0x00cd354c0129d, 0x014c2d06739d2, 0x017d577b5bb3d,
+    0x01c86d5e818d1, 0x03ec874a6211b, 0x03fee648e16f4, 0x0571fb1b310e8,
+    0x05abe917a630c, 0x07c6f9f222cfe, 0x08fa1f9da5d9a, 0x08fee6073e4a0,
+    0x095aa7612897a, 0x0a797c4e7b395, 0x1107afbc43c02, 0x117b0b9c5165b,
+    0x118060c287e64, 0x13a09ad7f6b23, 0x15b0fdbd15f3e, 0x1de52ef4a22ec,
+    0x1e6709fcbd793, 0x1fb9de08df977, 0x20850cc8a460b, 0x2151ad2f3d958,
+    0x2457d9621433b, 0x2464dbe87c581, 0x2a585b5e56dd5, 0x2b7c17a5b52e3,
+    0x2da94946bb099, 0x2fc6e1ef677c0, 0x30457e2a60eb8, 0x30bdc50d6a9c9,
+    0x30d91e798c6e6, 0x3316f7e86661d, 0x331ec9e1fba0c, 0x3ab6ce28e4b5e,
+    0x3c59b1beea2a2, 0x4a2cdcfd7d12c, 0x4d5d0dafc77b6, 0x4d6badc8485f1,
+    0x5438c8d94384e, 0x5666e85f50d09, 0x5aa0b6ad96bb2, 0x5e1af2167caa3,
+    0x60e086c7561c8, 0x67d8e4c1746bf, 0x69b14b69cf95e, 0x69b1e1784e5eb,
+    0x6a0d76e6f4589, 0x6a78009bb3815, 0x6d9137d12a6fc, 0x6d92220d4183c,
+    0x6f65e6e567d87, 0x73cb7f878e443, 0x7407d80ab0574, 0x74996cfb98497,
+    0x77de54e65d32e, 0x79aac08bea2ef, 0x7bed9af36257c, 0x7c608907452bf,
+    0x80a39e9ee51d5, 0x850156a3b4d8e, 0x8610b73211acc, 0x8dee7c35ea1a9,
+    0x8ea1019a10dcc, 0x8ed13a810126d, 0x8f8e752c65ff1, 0x901d1f7dc4618,
+    0x91e1440bb094a, 0x9477ab02240fb, 0x94ac611fe593e, 0x961fb0813af82,
+    0x977f662351fbf, 0x97913db71401f, 0x9a507d0ffdb5d, 0xa1dce1d391a0e,
+    0xa9471f0c18156, 0xac834e6a21083, 0xae643a62131c6, 0xae990dc757831,
+    0xb1f4ca958c4b9, 0xb41bd672d7189, 0xb562ba76fc1de, 0xb7b2a9b18e38a,
+    0xba894901957f7, 0xbace85fcb7dce, 0xbad2bda9b23d1, 0xbb06f3ae97225,
+    0xbb54c49d11db4, 0xbb7afb92c5ba9, 0xbcac0ae5341eb, 0xbcd6a57326b1f,
+    0xbe9beb233f61c, 0xbff0eb7e184f8, 0xc1d50b485b29b, 0xc538f58f9e34f,
+    0xc73654c3fddf0, 0xc793214a727f7, 0xcba9aa946ed31, 0xcce8efdc23a5d,
+    0xceeb06d65a4f0, 0xcf74042af9896, 0xd2b0be9cb0301, 0xd937b9c8529d1,
+    0xdaa28ed4e22c0, 0xdb0413212dcb9, 0xdf5ed934933ec, 0xe095f10339cec,
+    0xe349021c30486, 0xe901bba63064f, 0xe91421f6907b2, 0xee47db49e5f88,
+    0xef260dba6a596, 0xf217b5f1eb832, 0xf3d337e8bde98, 0xf719b26aca7d5,
+    0xf7b533574cb06, 0xf87bec3220f82, 0xfa07896f9b5fa, 0xfa661bca7cb6d,
+    0xfb4ebdf86853d, 0x00013d4cf2648, 0x00398db74ec77, 0x0177b1dcd71d8,
+    0x02e75f9fb0ea6, 0x0350b98e82253, 0x039af03061ff6, 0x04e7554601d90,
+    0x062c82197b98d, 0x06dec59cbbdd6, 0x079e07fd73ea0, 0x091649b3a6126,
+    0x0c01570960a3c, 0x0dca7c8feb0c4, 0x0eef4efea4c4e, 0x11d55f04b17d3,
+    0x1610b1467da0e, 0x1ed09e86ee522, 0x2013e495881c6, 0x21f05356a8f67,
+    0x2492e13b2ef32, 0x2569b3273942c, 0x259233b326d41, 0x25944a0c3b79b,
+    0x262a6e80a6739, 0x2762b6153af18, 0x2ce09b0f9f342, 0x3107de74216d6,
+    0x34109d6ff8f3d, 0x35ab6230b1969, 0x3916cdece1f1e, 0x39682332555fb,
+    0x3bcf4678c0b9a, 0x3e388b1311ce2, 0x3eff6c303a0d5, 0x3fbfd704ff4f4,
+    0x3fe3f3f8ae2ca, 0x447dd1437aeee, 0x471aae8251673, 0x47ec23f6447da,
+    0x47fbf77da3ab9, 0x4ca612730a10d, 0x52f6cc3b8407e, 0x5970cfdb8f657,
+    0x5c868db4deb3f, 0x5eebf621ae6c1, 0x5ffaa702f9f0f, 0x67261636e04ca,
+    0x69134db6487d9, 0x69337fd8b48d5, 0x694ea25ec0f67, 0x6f896dd6f1c62,
+    0x70cffdad7e804, 0x72b0e5fc80382, 0x75e408a034e92, 0x77098abbfff0c,
+    0x77872e1720db0, 0x799c5b0ec8e96, 0x7a5d3b0199b55, 0x7e8ddba5181f4,
+    0x7f6f28495abff, 0x82b67368ebe19, 0x8544ca2dd3fc6, 0x88b7548d97eb5,
+    0x8936f84c2b515, 0x8937c197cf268, 0x8a2bf0fc5e200, 0x8a74273acb7e7,
+    0x916a37f5bfbf9, 0x96dd84f068cea, 0x999fbd8c9d838, 0x9c582ec69b92a,
+    0xa148a4306075b, 0xa199377c10c6f, 0xa3146b23ad37b, 0xa3394dba0f8df,
+    0xa38b7745f7e5e, 0xa87e44e9b7ec3, 0xaa870569769f0, 0xab39defb35ac6,
+    0xac06ff7aab802, 0xafcd1dbb4b79f, 0xb0288395bcc3c, 0xb6153143bb47f,
+    0xba04920a0befa, 0xbb3aeb87dfbf6, 0xbb4b6ebc503c5, 0xc2e1fa0a842d4,
+    0xc380ed014d47a, 0xc5b786dc2068a, 0xc76f847bfa1a2, 0xc8ef85df0fb3e,
+    0xce7bedaa71f7b, 0xd0bbbc81ed51f, 0xd0dbfd481ad2f, 0xd3d9699c54c1e,
+    0xd5dd6b045bbcf, 0xdb75341b1d098, 0xdc888e1e7e1c4, 0xdeed816d424a6,
+    0xdf786cd8a232f, 0xe0742c55dfedc, 0xe095500643ca2, 0xe70d56ebcd084,
+    0xea9cc9a60a171, 0xebdef23b67c0f, 0xeed998897bbb4, 0xeefc059e600bc,
+    0xef0232b3fbd2c, 0xf0f7cae99752d, 0xf1d75cb43e51b, 0xf2508dd35f16c,
+    0xf2c1a76f13c2f, 0xf3818ad91bad5, 0xf4ae209da933a, 0xf8cc2f058333a,
+    0xfc5dad1e03d32, 0xfcda62941d4d1, 0xff3e4364766fc, 0xffb2f74cc5fab,
+    0x00632bc8d2524, 0x037e461a38d9f, 0x06ef27ed12f4a, 0x08fd18f9a48ce,
+    0x0a2d8399d2b6f, 0x0ab15f05607d2, 0x0db72fb8be178, 0x0e4c3a5cdd4d5,
+    0x0f3166d791a6b, 0x14e28c9ff2a5a, 0x156f7fc0a5c29, 0x1c8d356f31f24,
+    0x21574f49df5ae, 0x249b4cf88d305, 0x272e3a92d90b7, 0x27ec0ef5ad08d,
+    0x2864856ceb69f, 0x29001c4f0fc6e, 0x2ddb7f00e1255, 0x2ec693075a6bc,
+    0x387c1daa3e25c, 0x3971c9b0729fc, 0x3cdb4755e24ce, 0x44e435a44b5e2,
+    0x4f37d7a5a28fc, 0x51506bacc0708, 0x51b64fe85a67b, 0x53b47fba3e9f9,
+    0x545edb4e477b5, 0x54796b6ec577f, 0x591d6f4432401, 0x5d2e40969e56b,
+    0x5fd2352727aa0, 0x5ff5c35e38773, 0x60259973cf04d, 0x63bcda7eb0f64,
+    0x65c1b77f0d941, 0x6637e46012433, 0x68683ed897cfa, 0x712ecaa3bf759,
+    0x71fc0c13a8882, 0x72fd788da56b0, 0x7e77a085713e7, 0x7fc46fbe4b8d2,
+    0x8652d2c048c40, 0x872d00c0f7d11, 0x89a9505de935d, 0x8b6bd759bca9a,
+    0x8bd4b4d8a1a1d, 0x8c0f85bf4d1f7, 0x8fa0b6fc162c6, 0x914860fbb4110,
+    0x91b8c39876b59, 0x94bb975f98f2a, 0x97a469c2db434, 0x9a1db178c035c,
+    0x9b650a9af0c51, 0x9bc383573f961, 0x9c51018ba066e, 0x9e79114394ce1,
+    0x9f33f75deedf6, 0xa15234b8db8be, 0xa29c3a429f89a, 0xa34ccde715415,
+    0xa5f588e137050, 0xa7357a2d0cca7, 0xac1839ba67ddd, 0xaefdc9a797f73,
+    0xb06f82c8b554c, 0xb301653676dd1, 0xb3eb92a9c96bd, 0xb90d5721da00b,
+    0xb94d5c3e48c01, 0xb97b14a8e9ef7, 0xbae887794b7b9, 0xbbbd13780c758,
+    0xbe4941972e1d2, 0xbed15106c5e73, 0xc1e8d85d7248c, 0xc2d37745e0053,
+    0xc4a64e38fee99, 0xc5f0af423beb2, 0xc6080b906921c, 0xc73f2ab0f06a6,
+    0xc7d0557e9de78, 0xc96ca584bd7c0, 0xcd53f9c2731fc, 0xce26a9f725391,
+    0xcec1778da5c0d, 0xd2eaf98d90972, 0xd3f7d24dbd97b, 0xd665389f66e28,
+    0xd6a5f863b805d, 0xd70f68482b6a8, 0xd9c6c703be6e3, 0xdc446ea8f1b85,
+    0xdf2d5aaac68f2, 0xdf9dd33d2b5d9, 0xe56aadf127322, 0xe71d2e28766ba,
+    0xe86d4e7b2462d, 0xeca3ddfc48727, 0xf08905eb4746b, 0xf16917cecae48,
+    0xf16b73b1b6641, 0xf1cf035c45945, 0xf20a1d75b8483, 0xf53c264921352,
+    0xf54975ab40ec1, 0xf589d29e3852f, 0xf66608543a946, 0xf9a543424055c,
+    0x0455f3aaf2a57, 0x04d710623b2fa, 0x05d7ee1f9a025, 0x0b23d45ea1442,
+    0x0e2da22ff379b, 0x0e4ecd32380eb, 0x0ecf4c8921177, 0x0f1097611feea,
+    0x12d1ef3827781, 0x17196868061d6, 0x19e03f2b7f4b3, 0x1a5f8ebc7a660,
+    0x1ac49eb19f731, 0x1bc27830e22fd, 0x1bee796ad819b, 0x1c3eb888f01fc,
+    0x1dcb943019e67, 0x1e58247f7346f, 0x1fe9517229f40, 0x2069d47203591,
+    0x20fae5a11e500, 0x23fbc056aec9f, 0x29d1bdbaa10a3, 0x2b20d2e0c4ae9,
+    0x2ee1e8d6e5ba6, 0x3127dd721c230, 0x3808ea18de50c, 0x38e5de1fb3527,
+    0x39ffcf5beaa14, 0x3fe40daff4651, 0x4487da9f4bc48, 0x45992ced59a2e,
+    0x4981382a014e4, 0x4cf387fabf783, 0x4d4364477dc1d, 0x5009b3a039981,
+    0x5446c80d82163, 0x5ad39fca185b5, 0x5b95037eb6228, 0x5cebf1f04fbe7,
+    0x6035f81b35bfc, 0x6521a37271d62, 0x66481ac52506b, 0x6b03f006ca8f7,
+    0x6caba158492cb, 0x6e651447e7a61, 0x761e891aec2a2, 0x7719d37a1e5af,
+    0x77341315f468b, 0x78110373d90e1, 0x7892cfd832e74, 0x7a243759ef595,
+    0x7b43975aefaee, 0x7b9bb36d6e08d, 0x88f8b91cdcfaf, 0x89fe43a541b7d,
+    0x8a578f7dadffb, 0x8a60ff095bfdf, 0x8acd85789d462, 0x8d28ebf430938,
+    0x8edcf01f06062, 0x8fa59ab98b586, 0x907ca38bab10b, 0x91e1a70217dc7,
+    0x9310ae9f330c3, 0x94abe87df738f, 0x9efecc2e7baa5, 0x9f5ee9cec3f34,
+    0x9f89f97ac2108, 0xa3b0f7556d6fd, 0xaa65f7c7689ec, 0xaa7ac4f585497,
+    0xaae70983e0140, 0xb097500afb13e, 0xb297002775289, 0xb6c059e50c077,
+    0xb8b3b37f3eea7, 0xbb4fcec452463, 0xbd3537f315948, 0xbdd72b7f0a688,
+    0xbfbf39c3806ca, 0xc055ff413b444, 0xc0b465b0d847b, 0xc69ddbe395a99,
+    0xc8f95bfdbb7d2, 0xc927fea473c08, 0xcb156d09f15a2, 0xcb7fe81aef11a,
+    0xcdf465be4b377, 0xcdf6d796d8e0e, 0xd1f4dc1e9ac89, 0xd228243e783d8,
+    0xd22b372055c4b, 0xd28f8c6d986dd, 0xd45b1528ef0e8, 0xd5d715c51fb0c,
+    0xd5e01032c2250, 0xdc7e346f6cbc4, 0xdcbe532a6e01d, 0xde29875f66f0e,
+    0xe031e8b6bc439, 0xe035cfdf0eb4e, 0xe414d5b342d71, 0xe540813bb9496,
+    0xe64e7775226a5, 0xe7b0ce83fbc7a, 0xe809302050fb8, 0xe89f66e16dc2f,
+    0xe9291eddef614, 0xe976a2f0b287a, 0xeca0cecfb520b, 0xed2982f18d24d,
+    0xf54fb170f7fd7, 0xf5d585ee1786c, 0xfc3a55e5bc20e, 0xfc6e8498c04d8,
+    0xffb5a775fd77b, 0x000d60b722153, 0x016c0031ddb14, 0x02439b698bd61,
+    0x04a335e929275, 0x0a052f11dd2ba, 0x103a672be1597, 0x1258ba7c702fd,
+    0x14762390e7f23, 0x15414003faede, 0x1b204dcb31cc4, 0x2256256a9eab7,
+    0x240b7a4bd54ad, 0x25f3430674750, 0x25f45cd6c0391, 0x271cbe9743bb8,
+    0x2775d3899a9ab, 0x29b88d009f84b, 0x2fde6e0330c73, 0x30184d5be4783,
+    0x31478bdf1510a, 0x3171fa81c3502, 0x3595e57a91284, 0x36bf894db4663,
+    0x3ce9d53a65154, 0x3cfc37e1d4cf2, 0x44dbb06d68262, 0x4a043bb283a72,
+    0x4b6e944f26f37, 0x5127343a9489d, 0x52db93e4e3004, 0x5bdb27195910c,
+    0x5be8fa3d45c2f, 0x60fc30e98741b, 0x61c11c9e0e1a1, 0x62fee19b2d100,
+    0x63679a80ae6d1, 0x639c2fadfcb27, 0x64a29977a76e6, 0x66475c80ac1f9,
+    0x68ccc77bb3376, 0x68e2828ed7526, 0x6a60e2b6e3d08, 0x6bed8777f82e8,
+    0x6c4723d50c654, 0x6ff37b40d237a, 0x7030b3053a49c, 0x727af8ebfd98c,
+    0x734b37482208a, 0x73ee02c37c231, 0x79a451c6cf4ed, 0x7bc26e3148bc8,
+    0x7cf9b93d1458e, 0x7d5bcc45fe08a, 0x7d8bca9de88db, 0x7e15237200cbe,
+    0x820b475c6c036, 0x82ba98ee41ff9, 0x8f42cd15191dd, 0x91c95cdb3d0ea,
+    0x92ac227834d38, 0x93c18f2bba2f9, 0x9700e00f621ad, 0x9b596ed93fa99,
+    0x9dbb4b9d35f43, 0xa06423deda9a6, 0xa8ab81097a645, 0xa9fb24ec3c18e,
+    0xb10dd125f1e75, 0xb1a5bf17b9f44, 0xb6cf175169a6c, 0xbef002f74fcc6,
+    0xc1cdc83ccb5f7, 0xc708e2149aee1, 0xc7e8aac55755a, 0xc81bd946a2446,
+    0xc8caeca0821f4, 0xcdb96ca1a59b8, 0xd423ff8bab879, 0xd94df8ceab17d,
+    0xd9911bc7c8735, 0xdc53d825ca237, 0xdcb722468279c, 0xdcd28a5665bcd,
+    0xdd5662190326b, 0xdd8ce1b421bdf, 0xdf2f74c57f435, 0xdf552f4b46e00,
+    0xe11df200778c1, 0xe203974227cbc, 0xe453f448e4b7e, 0xe5f39568d7f4e,
+    0xe84080654df9b, 0xebabc7881ab9e, 0xec79731b3e418, 0xec8f9fa37d8a3,
+    0xee8d35bdf29cf, 0xf0df7d327fdaf, 0xf519651f584bb, 0xf612fbf6cf935,
+    0xf85c3e02e9482, 0xfec5fca67a4a1, 0xff93df1fb33a6, 0x019ac3d5630c9,
+    0x01a8eb26e809f, 0x043b1e1951812, 0x051d639d6ca71, 0x05ad27bf16cd9,
+    0x0728df2880399, 0x099e261780349, 0x0a8dc24f9bca1, 0x0bf9b87e57d5b,
+    0x0cfd42a34a16d, 0x0e03ac6cf4d2c, 0x0e90fb0d20f38, 0x0ed551751c994,
+    0x106814a80b4d0, 0x110e0ca3d574d, 0x1279b24de9919, 0x129e027ebd7b5,
+    0x1503a57a92f87, 0x16799648aab63, 0x18b4383c43875, 0x18f57bc261a5e,
+    0x19a066130891d, 0x1ace71275ae00, 0x1d162f3fee5bb, 0x1e36d4fc7ec91,
+    0x21bb0009ef555, 0x2210f71f97986, 0x2459f74acacf9, 0x27c9eee18dd64,
+    0x28973b4c47fdc, 0x2a570ae5b6ee3, 0x2da34c0315304, 0x2e810cfe7e637,
+    0x2e964ba1b63bd, 0x2f297e24b6ed5, 0x3163064803fa6, 0x3513a404859e3,
+    0x352b9661319da, 0x3cb5a2eaca657, 0x3db341db075d3, 0x3fdba217a3b34,
+    0x4694183cd625d, 0x4700ef30bf5ce, 0x4fc8aac1d4f0c, 0x5b71ee2754ede,
+    0x5f95b82faf873, 0x5fb7f2076ecb5, 0x604a9d1fb2254, 0x63142b458fd67,
+    0x66b9765a73e2c, 0x683c834d864b1, 0x7b29801a214ad, 0x7b8f520909747,
+    0x7db1b11b4e574, 0x8c0eb19de5c0b, 0x8c7cd59f3fc83, 0x8f031fdb8cdf1,
+    0x91c69a8a614f7, 0x91dbf2cc2bae0, 0x95805af6d95ef, 0x9e4d34c8100ab,
+    0x9e70a9608e51b, 0x9fbe36ea3b60a, 0x9fd575f0e5b4c, 0xa346d08abbb5c,
+    0xa5f1883f5f98e, 0xac5d34f9b1490, 0xac81852a8532c, 0xaf0bd77f97f9c,
+    0xb5278353c7c52, 0xb68d71064ba4e, 0xbac70fc05cdf6, 0xbe8960773cee1,
+    0xbefeddab304e3, 0xbf96e53b8ae15, 0xbff8ba0c7e6fc, 0xc1973a0a45301,
+    0xc55b78d7e5ecf, 0xc5b4ecf2a62c4, 0xc645c73babba8, 0xc7772e64a2c62,
+    0xc85eeda85807c, 0xc8f3ed51a628a, 0xc9a8bff4f6f92, 0xcf1b733830e31,
+    0xcf504def56026, 0xd0cf616d642bd, 0xd7e9fce8321bb, 0xd88717bddcb1c,
+    0xd894ee8ab9c04, 0xda43d0cc7dbeb, 0xdb0eab5eaa3cc, 0xdda8e730be5b9,
+    0xde3c24ae90220, 0xe1f9d73e2bb7c, 0xe25db01b821e4, 0xe356b8365b55b,
+    0xe5c9c089f2b3a, 0xe7facaaf898f1, 0xea3ce6c62ef7a, 0xeea16940f9fba,
+    0xf003a6b140d2c, 0xf8456582e2e5e, 0xf91c0088d7ccd, 0xf96979a0c9de4,
+    0xfbce067543f3a, 0xfcc3cc1a0af3d, 0x003d960dd5b53, 0x051b166a9c71e,
+    0x05d2a7c2327e6, 0x082af7b2f7e84, 0x0ba032233fa1f, 0x0eef986f2d38c,
+    0x10c9a3fb81daa, 0x144371983cf85, 0x15dbc0d777955, 0x1d6512b92df93,
+    0x1dbaf782246eb, 0x21213a03629d3, 0x24605efbc634b, 0x24860addcc602,
+    0x27439a57eb872, 0x29bbb999c3445, 0x2bdbf758226c9, 0x2eab5e6603999,
+    0x2fb719acf479c, 0x30a1437756ac3, 0x32bb875d3f19d, 0x37b9d393e10b6,
+    0x38ff499a22017, 0x39619adb01d28, 0x3d1ca854eab27, 0x3dd1406935bdf,
+    0x43fcf6aada1ff, 0x4c5850661206c, 0x4e9f1947bd598, 0x4f1006f82db1f,
+    0x50cb1b22d546f, 0x531889e118470, 0x53957d8f27e24, 0x57560c1a8b968,
+    0x576132d6e4da4, 0x5ff384f4373ea, 0x60ca3998beabc, 0x62c255f399a2d,
+    0x662dd3fc7bcbb, 0x6923b03806f95, 0x6a453535dd65c, 0x6fc4046c58bc6,
+    0x7a3e85be9463b, 0x7b8912b315b90, 0x7bc1c997bc34b, 0x7c6138fe7191b,
+    0x8408cb42e2fad, 0x8480c54a35195, 0x884571e400a79, 0x8b1e39d9ce619,
+    0x8b8e2b0175d87, 0x8bd429f65b527, 0x8c93cf286d1b8, 0x9b88a7a72a680,
+    0x9c28b46e3f467, 0xa3532ac24d1ce, 0xa3ef2bc7abeee, 0xa59b3d0878e3c,
+    0xa73c02f388f9c, 0xa76515e504f79, 0xad33ba165ddf3, 0xb167a481cf390,
+    0xb32b629571536, 0xb72ead9fa84a6, 0xbb52aae527f01, 0xbcaf639b03969,
+    0xbcf98b9921ff8, 0xbd3d7f2fc3e8d, 0xbffe1690ef821, 0xc09f9ff9a9e10,
+    0xc0ced8aee0ed3, 0xd00fc7427d50d, 0xd14ac9e266ae7, 0xd19a769af0480,
+    0xd270dabacfc64, 0xd4fe14067bc0b, 0xd8661c5c26a5f, 0xdd55117b55ac3,
+    0xdf59511b52c89, 0xe073b3cca5451, 0xe15091ddd81ce, 0xe2fab15ef4074,
+    0xe95f0fe17d73b, 0xea64b7b165131, 0xeadd4b702656b, 0xed0b37b90e960,
+    0xed87de8b669eb, 0xf185d46f67152, 0xf369c84e659b9, 0xf459adb948675,
+    0xf6d7555e7bb17, 0xf6f31c96c854a, 0xfa7ce9a626f40, 0xfcd88ab0c0066,
+    0xffff5e240e3e5, 0x0253ad4deeb0b, 0x065a8efbd02a2, 0x076fd36d018ec,
+    0x07cccf87aadf4, 0x08775aafe86b1, 0x0d5bf607525f1, 0x0e348a1ed9092,
+    0x11e76676c34a0, 0x13e10e170532d, 0x1411bfc4f1633, 0x173896e12ff77,
+    0x185cb9a2d8611, 0x1acfac00cd952, 0x1e73bffde2fae, 0x1f4e19e9d65bb,
+    0x2002f787f8412, 0x250340791c522, 0x2cd46970d6b45, 0x2f86904db619f,
+    0x2fb64572d968c, 0x301beb767d3ea, 0x31b4df67f815b, 0x334b7ec86825d,
+    0x34098668612f9, 0x375ac12c7cee6, 0x37a4f07c7c0ff, 0x3863a420960c6,
+    0x3963053c4f5e9, 0x449e1253c386a, 0x47e52ddf1ff83, 0x4bfe3ef74c1f2,
+    0x4d292c2eefd13, 0x504fe603ab82f, 0x5c2c4946b4fd9, 0x5c7f1784dd8f9,
+    0x5e7070bb9df6d, 0x65e044fb41698, 0x6671e13e0a145, 0x68d0f452ea0e0,
+    0x68ddc7451d825, 0x6930d3bb3c35a, 0x69d6eca7799c4, 0x6c5b1ce1c1b13,
+    0x6cb92b7ad60d2, 0x6d47d5cc346f9, 0x6fd8309d7f627, 0x700ac5675b2c1,
+    0x70ed925633a99, 0x718621514b944, 0x734461fc6336a, 0x74439383e7d8c,
+    0x75ff1ccc9af7c, 0x77caeebab8ceb, 0x7ab53a16b0438, 0x7f2ef78bc2c39,
+    0x7fd058b2292b1, 0x8390cd9efa592, 0x86ae85b432de0, 0x8724c88ad9b70,
+    0x87481c32e4bf3, 0x8eb585ebdb411, 0x906e960a1ffe0, 0x91dea2f5632b0,
+    0x9471d9c2763c6, 0x9529f62db9fcc, 0x95b8e9b1df957, 0x96ead84593f8a,
+    0x98feb0ddea7df, 0x9dfc67060da6b, 0xa469e8388d7ed, 0xa643e8ca110bc,
+    0xaa33a50fdab74, 0xaee636b116958, 0xb6e3314e92318, 0xb73c5fdf7b96e,
+    0xb78621634fe71, 0xb929b4a666aa5, 0xbb8a40b3fbc83, 0xbe77b4e772ee1,
+    0xbfd0e1f475271, 0xc46009f803d34, 0xc4a1ee8771cf9, 0xc750ae54db62d,
+    0xc7f38c1ce74ad, 0xc9fe45ae37c0c, 0xcaeaa6c221d7a, 0xcde144f77021d,
+    0xd4c9e1b6e110e, 0xd544d1588dd41, 0xd8e9656e7fd8c, 0xda4f98aada927,
+    0xdc7e29a6030bd, 0xde36b9ab6b29d, 0xe2517e4b5239f, 0xe5716a012246f,
+    0xe5b4a2efe1cc5, 0xe70b2ae7314f8, 0xea9836c3b1c9d, 0xed3e57a2f1eca,
+    0xf08000c094fb2, 0xf2f0fdb5e8c86, 0xfa8551ba94d4e, 0xfaf813e333555,
+    0xfd1f5df87443a, 0xffa5e2c3c71f8, 0x006e3587c3ca4, 0x0139b12340261,
+    0x024a95a523331, 0x02d74a8de02eb, 0x033959edd9822, 0x04cf87d92e649,
+    0x0622f59cbfa7f, 0x07833652845fa, 0x0ad291a3a0e18, 0x0bd19396f0d39,
+    0x0deb625ecdb73, 0x0eb3e85fef6e5, 0x0fd2da94c4f28, 0x11cf98bfd4bed,
+    0x135a655fca988, 0x1715050d88a71, 0x19f08f608bf96, 0x1a046005dfc28,
+    0x1a20b251da199, 0x1cf8b4a4f45ab, 0x1d6b9f0fe6d29, 0x219545a935440,
+    0x2553b2e0b33db, 0x2568abfa143c2, 0x284845b7bdf73, 0x28e85627c331f,
+    0x298ac9cc94a4e, 0x29db1ee04ed4d, 0x29e483712bbe2, 0x2c2b91dcadead,
+    0x30cdd3869e588, 0x38584a3371956, 0x40e2812467849, 0x42c7718c2eec9,
+    0x48c10f73e3c79, 0x4b1126f0c9d9c, 0x4b73e254e41fe, 0x4dd9bfdfbf620,
+    0x4ff92957a9a02, 0x522168a3d2b76, 0x52814bb5153b5, 0x555d527804d04,
+    0x59e207153be40, 0x62df66a91a6c6, 0x63f9e6a1efcb6, 0x6769d6e6d2197,
+    0x6832f2f672996, 0x68cd616489c10, 0x6c506ef8be2ce, 0x6d1286035d9f6,
+    0x73fb4eae12e23, 0x764dc5b6d4d04, 0x775e8948449e7, 0x7978e323cf35f,
+    0x7b2144225f223, 0x7b8e4e536d65a, 0x7f596d8cab94d, 0x8be9e8cb237c0,
+    0x8e386a07d1878, 0x8e62c65dcdf97, 0x8eaf4695e785a, 0x952f8d4130d41,
+    0x99401cb2b66d1, 0x9debbf449367c, 0x9e6c583a0ef6b, 0x9f59779ecbcdd,
+    0x9fcfedb297b33, 0x9ff6c208ab13f, 0xa2f0e494f2130, 0xa68dae032f916,
+    0xab5d7fd56d370, 0xadb6a7b597e75, 0xaef8f88d4988a, 0xb106538b5c581,
+    0xb51e940603f13, 0xb557732cfe645, 0xb6e15939cd865, 0xb74df59eb0f86,
+    0xb9643514c4123, 0xbdc327da52d0a, 0xbea4ed459157a, 0xc0c88718953d5,
+    0xc1c0f57bff4fa, 0xc215f75abf69c, 0xccf03e76bab28, 0xd5f89189cd724,
+    0xda1a69d277011, 0xdbc3a6695c901, 0xdd7cb687a14d0, 0xde633eb387e81,
+    0xdee792e611949, 0xe00964bf9f939, 0xe0a44f9da2fdd, 0xe389c2435034b,
+    0xe4fb915a0fbc2, 0xe65ffec1fdbf1, 0xeb6d63d81c7aa, 0xeca539f795cae,
+    0xee80286e20c82, 0xf15e751e59b2c, 0xf45fcc43d66f5, 0xf76c4a25ac6fa,
+    0xf819019ca0caf, 0xf8ac42c362edb, 0xf946fa64414b9, 0xfcd732c131d34,
+    0xff6b7fb5a04c6, 0x01ef2c2e1b661, 0x08e06b84a621e, 0x0ab8979dfb86d,
+    0x0b269aaee24f8, 0x0b8b4429bd43d, 0x0e0651b40eed1, 0x0f3ed780998c5,
+    0x13443c8cd5854, 0x1e019ef7480a7, 0x2099fb565cec5, 0x20c1ab44c5efd,
+    0x21e9525d670e5, 0x25171fdae53ec, 0x2553ded8516a9, 0x2bd4dc828cc0a,
+    0x2c4fa78ad5e8b, 0x2f2321ac4ddac, 0x2f868cbd796fe, 0x30195c6f2064f,
+    0x31b0c8c424a69, 0x32bf2920c83c9, 0x340229a58b2ce, 0x36451dab95dbe,
+    0x37af171fcfc81, 0x39d7bce642f81, 0x3a0f64f56ec4a, 0x49b0369a4dac3,
+    0x4bd336b5e26cc, 0x53681d201ce5c, 0x53e8de57ec6c2, 0x55d14f6dbc2cb,
+    0x55eac966dec19, 0x58eb1908c187a, 0x5aa57d8657cda, 0x5e18f5cb232ce,
+    0x5ff50cb5bb5f7, 0x6055e8a6d668a, 0x65c39e9a62798, 0x6ac1302922072,
+    0x6afa846e28044, 0x6bdda58a98ccf, 0x6cf3df32b25a8, 0x740f947dcc0e7,
+    0x75e6822d72143, 0x769b462d01737, 0x7a3579294dad4, 0x816c1dbd4ebdf,
+    0x82efb5c40eda2, 0x84d6e8702f3b8, 0x922f4c4f69586, 0x92c8123096abc,
+    0x946f0c8323416, 0x964d620924110, 0x9819080bfd943, 0x99736fd9bed01,
+    0xa243b80c4c06d, 0xa512b4f6f2bec, 0xa6433cdea3cb5, 0xa6511002907d8,
+    0xa92e4ddd4eb90, 0xaa86adf5bcc08, 0xadf9ebab825ac, 0xb6b03d7500ba0,
+    0xc1869d68a9917, 0xc42cba9ef957f, 0xc686c5695a63a, 0xca6c243e6ea09,
+    0xcdc071e467e69, 0xcf019df0fcaee, 0xd041b3d6360f7, 0xd3c5e63587545,
+    0xd7d3960254728, 0xdae3b1d9b5ade, 0xdaf0518ec415d, 0xdb199b66557c5,
+    0xdf608230a5b52, 0xe14d98bf9aa74, 0xe2839e0fd62bd, 0xe8b59eae99375,
+    0xed56725aa595c, 0xf04148ca4abe7, 0xf170b338bfaaa, 0xf4384f9eec515,
+    0xf7ec630ea538c, 0xf8b8e9d6abe76, 0xfa30539d78c95, 0xfc5657f7299fd,
+    0xfc8944978e10f, 0xfdcd454e0a3f2, 0xfe5c26857e0a4, 0xfeede761aa503,
+    0x01f9566e05a16, 0x04b6ab591f036, 0x05f920ca343fd, 0x0a7be3a7ba491,
+    0x0c5ff8772c0e5, 0x0f837727b6417, 0x109fcf41aa24c, 0x127b69bc5614b,
+    0x14d2c4763355a, 0x189553650985a, 0x1f663fa8b4f2e, 0x26733793cae0a,
+    0x276b93aa83256, 0x31484328222f0, 0x33ac91c4a6231, 0x35a051d9231ed,
+    0x3646eade3d246, 0x36f9c46ffc31c, 0x38a4172e3d288, 0x39b1a3446bd46,
+    0x39b824879fe69, 0x3b23c688c3a70, 0x3cc84407f17e4, 0x3dae1534e611b,
+    0x49f34ea4f3cbe, 0x4b50284b42b13, 0x4ff925c76cf61, 0x50384ba295b66,
+    0x527e0205d5fdb, 0x54a3d32261c7d, 0x595b18e08448e, 0x62bcb07a2297e,
+    0x65722c19c6025, 0x65b2f6d8e83a9, 0x664aade49aded, 0x673c557afa050,
+    0x67b52ec392229, 0x6b885e2e5bb20, 0x6fc2c28b20656, 0x70fe32f734946,
+    0x714ebb4813d0b, 0x7504cb724ed79, 0x76969d1745beb, 0x77085f0e2b014,
+    0x779f800b5edcb, 0x83f397cbe39be, 0x84aff6def2d16, 0x8aa51ee1b72ae,
+    0x909fc44d05fc6, 0x91d666fda1026, 0x94c436b0912c1, 0x9598dc4de4ac3,
+    0x9bec427df211b, 0xa0d3de6a87bf5, 0xa1ec4461581c6, 0xa3b102a6b374a,
+    0xa8933ac951307, 0xaacb79881dc96, 0xafb699cbac2be, 0xaff7f6f05cd0a,
+    0xb2ee7f3008f0f, 0xb4389aaf6f189, 0xb57fbceb8a3f3, 0xb794d3ed9023b,
+    0xb81352e106b0b, 0xbb24ef02fdc8e, 0xbb5519462ca1b, 0xbe1ef447c1e57,
+    0xc02d5e1b4f640, 0xc062527107098, 0xc3a6bdebdfb05, 0xc83c9e18b7d76,
+    0xc8e5d88a94367, 0xcc25e7bf0ee1f, 0xcf8fe1d46ad1b, 0xd03429f16a6ca,
+    0xd2bedb6ee693c, 0xd876060a8f624, 0xd8df29134b346, 0xda9664b961c95,
+    0xde304ad9f6709, 0xe62fc99cb1839, 0xe84d11c0b6072, 0xe84fb32d7860a,
+    0xe90b8383e985f, 0xed7641b811c23, 0xee726b5889f21, 0xf6168440d3bb4,
+    0xf6b80657ad619, 0xfb9a99f9c4213, 0xfc2daf3541f03, 0x014f7ea8239a0,
+    0x076decd988fdb, 0x0979526efa665, 0x09c747f6d8ba6, 0x10febd285e58e,
+    0x1b89def68d5fa, 0x1d2aee1464abe, 0x20aea7acba0a7, 0x2402fca494091,
+    0x289b107203b84, 0x2d3fb8f9b0ba7, 0x2fb5af95c2047, 0x32d7bc9f77cc0,
+    0x33de9b872e11f, 0x3ff6dadd6d5d0, 0x47fdfc0588fee, 0x4f04172df1d6a,
+    0x532522820733f, 0x568f746debcb3, 0x5c27e236ed51d, 0x664ee946df747,
+    0x66b5e000e4771, 0x694b088da892f, 0x695916409b0a2, 0x6d16157a34f49,
+    0x74bef875078a7, 0x74e12afae615f, 0x7525dce25abf8, 0x76c116c11eec4,
+    0x7ceedfb2e7979, 0x88555b9ec33d0, 0x8bdecd2ea8d89, 0x8dd4e9261153e,
+    0x8f94683ad8557, 0x94281fc1ea095, 0x9561971c6c753, 0x99cea9e19f786,
+    0x9b57eee51ebca, 0xa2632f9f8964e, 0xa4c0dfb156644, 0xa683b388e16aa,
+    0xa7d515ee2f1d5, 0xa7e3658208122, 0xb232536678a0f, 0xb298255560ca9,
+    0xb6d41ff25d84a, 0xb6f18865b3437, 0xbc301b99aa720, 0xbd5c7e2112ebf,
+    0xbeda8dc4777b3, 0xc2a0afae07d15, 0xc52add69b6fd3, 0xc9a67a51c8ba3,
+    0xca978bd9a9179, 0xcbb63c2d981e2, 0xcf5b36bdd43b9, 0xd17ddb59eff85,
+    0xd2181a33d26fe, 0xd5eac233dea7c, 0xd6d6a6d7dc7c0, 0xd77980f6f807b,
+    0xd9a6a04b4c158, 0xd9eda6c3cb860, 0xda24adc9a774d, 0xddcb92c7b3e42,
+    0xde7bb89ffeca7, 0xe1de732153e7c, 0xe3ee907c9c4f8, 0xe5d3e75eadd04,
+    0xe8a4cb0e347dc, 0xe98e3687c3eff, 0xe9a127ebd1ba0, 0xed9df55bc4fb2,
+    0xf913658db9cf7, 0xfa999f013c452, 0x074f87e9c4367, 0x0830790e8dd35,
+    0x0be6943399ef2, 0x0e840b29df8c9, 0x0fd4f87121b54, 0x1020d04e0aab1,
+    0x10510934faf52, 0x1305b43719d1c, 0x15176f247af8d, 0x15b82f4191829,
+    0x1cd2a9cbec33a, 0x1deb8fdb992fa, 0x2011a2d90b776, 0x2280d9f9f28de,
+    0x26685679fc291, 0x269c852d0055b, 0x271999cb832fc, 0x288430a01c9d6,
+    0x2e771db068b62, 0x331757a505ef7, 0x35e27c0b1ba75, 0x559b4515b6aad,
+    0x56fca344b782e, 0x57817b390e2aa, 0x5b32daef52eb0, 0x5ca53c6ba0def,
+    0x5ff0d8d6bee6f, 0x6079d9d44e7da, 0x648efc72477aa, 0x693abc4ba757d,
+    0x69e38c9a4941d, 0x6c00a52a19155, 0x6c2ab84d4e510, 0x701e9d9c50eb7,
+    0x72d5abd33c004, 0x7d77d96af04ec, 0x81675026e3205, 0x816f4e0bbcb30,
+    0x8471f23eaa400, 0x890b94fa713d4, 0x89ff86270a157, 0x8daecf843a303,
+    0x8dfa77ccee75f, 0x8f38700732784, 0x943947b4f4997, 0x95c6235c1e602,
+    0x98be1999ae8fb, 0x9fbd0ecca31b3, 0xa08e5fa732968, 0xa1bfa988a6bfa,
+    0xaa0c7cc9f048f, 0xaf4b015a26064, 0xb436dfee87290, 0xb4dbd40fa7b6a,
+    0xb907c43f2fda1, 0xba6d05ed92c72, 0xbe7f0705eccbb, 0xc08eaf4329a97,
+    0xc5a81a56e7a7d, 0xccb2364635771, 0xceae18d8efa0a, 0xcf19bc5dfa8d7,
+    0xd1574c9a0d4aa, 0xd43f5208bb69c, 0xd5007785630fa, 0xd97642d752097,
+    0xe037c20de8618, 0xe720711a0b1e2, 0xe77472c711fa6, 0xe7cfb7b110056,
+    0xec43237723235, 0xecf93f7ef467f, 0xee9bd9e232a5f, 0xf13c6eb526df8,
+    0xf27f0516af5ac, 0xf32908d42f8f0, 0xf489b7561f181, 0xf4e3cc7a2f352,
+    0xf7dafd150bebd, 0x00fbd4ea59178, 0x0a0849b4c40d9, 0x10b9211d864d9,
+    0x1590ab4ac6abf, 0x169a5edc6457c, 0x17cebb9fb6081, 0x1c3d81eca3f47,
+    0x1cce63878a3b5, 0x1dd7e033127e7, 0x1f8bce6845c73, 0x20879c8944f34,
+    0x20b92d787722b, 0x2a3d77b0d09c2, 0x2a9e2f08880a3, 0x30436fc3bd052,
+    0x374389c1ce69a, 0x37f94df31706c, 0x39734e2bd52d4, 0x3bf99fba02fcc,
+    0x3dbbefcfc107e, 0x476bb780436ff, 0x4be365ee22030, 0x5ae45e0911188,
+    0x5b1dd33e8a547, 0x5ce7160c979f7, 0x61f7c89399a73, 0x634dd41afce7c,
+    0x6b8167f229a13, 0x6c871e65d2b1d, 0x6f235a9b594c6, 0x7101375a5e35b,
+    0x75278cd9d8fea, 0x75722d9ef34de, 0x78fe09b585da4, 0x7cb842e8f9d01,
+    0x81191178976f2, 0x87565d6d17598, 0x87ec091a866d1, 0x8963f2fa2fedf,
+    0x8ab6593a273ad, 0x8dcd67c9d7b61, 0x933dc2d3167cc, 0x9b968d6e3dd7a,
+    0x9e675b28225b4, 0xa2bdf8324edf8, 0xa2e02e611dc75, 0xa439074087b52,
+    0xa79b997f88db0, 0xa7c2b35f7315b, 0xb0286e3450c76, 0xb2174345d1b7a,
+    0xb2aabefb999f6, 0xb32f8f9e0f8e8, 0xb544d28b59c6c, 0xb755148005c9a,
+    0xb78120b4ccc87, 0xbd22ef65badc1, 0xbf45f32a3ff8f, 0xbf6f828ba8396,
+    0xc02f91e0f4778, 0xc444a2323ba6f, 0xc4cc90b160323, 0xcc354d9f50c9e,
+    0xcee90abc68363, 0xd1f5138032ac8, 0xd3e948b2bb324, 0xd46fa0f1a7b6d,
+    0xd785b2f88f508, 0xda8983487a6f2, 0xdcb2bf1d6c67f, 0xdf1d07926716a,
+    0xdf2940cd2b65d, 0xe0d77ab3becde, 0xe10b010b92642, 0xe22ed34892dee,
+    0xe29fa3b18054d, 0xe4b08e015cee1, 0xe6884c4e8781a, 0xee8a3597ef857,
+    0xeec706e20d7ed, 0xf1c94f9582080, 0xf47b1af2e869d, 0xf5083655ef7e3,
+    0xf7cff0039f076, 0xf7e17712b91e8, 0xfd2ed64a75848, 0xff21878977d12,
+    0x09a4a7c9dce8e, 0x0de108e123bbb, 0x1235674fe7a2e, 0x188177f64c0c1,
+    0x18f1064c99c68, 0x1cb0f3cead9d0, 0x1d36114cdb1eb, 0x1d8e1769b74ec,
+    0x1f172587212a5, 0x2379ab476a0ee, 0x24427e2443589, 0x244673f0573b2,
+    0x25fae5e7577f2, 0x2876a31eba776, 0x297f73c621c7d, 0x2bae728d75129,
+    0x2fd45697d4add, 0x304e92e37fc5b, 0x3c712106b30e1, 0x3e772dccfd99d,
+    0x51ae613b7873c, 0x5a6678d96389c, 0x67669863cb554, 0x682e551949373,
+    0x69b578c8e2c0e, 0x6c6dccbb5ded8, 0x6ed4c41684f3b, 0x6fe3f167bcbb3,
+    0x6fe6166492d21, 0x70c1f09b1c0fb, 0x74bb69484b4c0, 0x75bb1e919ce96,
+    0x79bffc331b8ac, 0x7b684e8dea05c, 0x7e531659cdbd3, 0x7ff3f5e370596,
+    0x82e02f56287c6, 0x84d27671f053f, 0x853e2c43ad0e5, 0x87cda7d3b2022,
+    0x88bcd296b269f, 0x97d90e2821276, 0x99387345903c5, 0x9a561c15e54c6,
+    0x9bc2ccec83bbf, 0xa3605244e7a56, 0xa5c3418748fb7, 0xac3f8019cc99c,
+    0xb4fd7448ab87e, 0xbd86916955b30, 0xc0223ee23e3d6, 0xc1d44da472493,
+    0xc8ca5e5f668a5, 0xcc23ca4580e83, 0xcd9eb11165c66, 0xd25effe0ec2cf,
+    0xd47d324107c48, 0xd716e2ff6e2f7, 0xd762f1c26c8df, 0xdb3eaa25bd63f,
+    0xdd8977773e0a8, 0xddac8ce752f16, 0xe179f20dda764, 0xe32d552f600b2,
+    0xef5868a22a3dc, 0xf4a13fa84454b, 0xf5cbf5f9d29e1, 0xf6c83938dd542,
+    0x00a7c109541ff, 0x01f826e5d8f11, 0x02a53d8536ac8, 0x04f596e3033c5,
+    0x053b838b36e8c, 0x0599195d4f5e6, 0x09e1040249316, 0x0e60d1457495f,
+    0x124f2a882b472, 0x12fe11f6c6ce4, 0x142c838563353, 0x16176a1cb0fb8,
+    0x17c340ce782b6, 0x1984736afa162, 0x1a8dc7d42e61d, 0x200b340796be2,
+    0x22f2fb3e4ebbf, 0x25e5f08340b62, 0x26ee4863ac204, 0x297f234dd3b21,
+    0x2baae3480521e, 0x2c80647dae44c, 0x2e59ec4835eb6, 0x2fb220755f9f2,
+    0x30e863fd91450, 0x350967ffc5e9b, 0x3b1b158ba9a6a, 0x4948e101fa603,
+    0x4b111c4c6db4b, 0x4b6888b1dabfa, 0x4c26855702b47, 0x4ff192438f161,
+    0x557620c37b625, 0x56340b1bf1899, 0x5cee2d76fe2cb, 0x5f60618420a08,
+    0x6041ec6059628, 0x678e1c7349de2, 0x67ea5a3d206e6, 0x687fe4fa1c432,
+    0x6bad18c02b4e7, 0x6f28211da56f0, 0x785bc214ac6d5, 0x7b38ba6593cee,
+    0x897634c9e01b4, 0x91fac60ff79b0, 0x922f070fad953, 0x94388d321fc0e,
+    0x9802cd2a17f10, 0x9c9dda3ad2a13, 0x9eee214bed637, 0xa2df4094ca094,
+    0xa3fb448125a16, 0xa40ca6f6dc1d6, 0xa72ff26a41442, 0xab832113173d6,
+    0xacc05001b76a8, 0xb300c4cdb705f, 0xb3c9bfece4471, 0xbe36d83e6db14,
+    0xcd6bd6ca0cfbf, 0xd0203c4254fea, 0xd02b963bd34ec, 0xd4e224fb03c83,
+    0xd60edf58f4e9a, 0xda70a6c86b0df, 0xda745ab39872e, 0xda8b0002d3a1e,
+    0xdb6bba4187d61, 0xdcae803745016, 0xe0a85463ed418, 0xe12da51f3fcf9,
+    0xe44fd31968d5f, 0xe5823e1d097bc, 0xe6fd791686a52, 0xf0f6b1c6356e8,
+    0xf10caeba40072, 0xf247f33b0fe26, 0xf7ab397134b72, 0xfd84794b392ea,
+    0x0051080642997, 0x00e837a737e62, 0x029b25aab1f10, 0x03bc10f119385,
+    0x0805748ec82f8, 0x0a2509f1f6c16, 0x10008115831d5, 0x10470b1e164b3,
+    0x12099d14bad3f, 0x15f0a81fa9417, 0x1e8b32b0dafd8, 0x22e492183d1a1,
+    0x22ee29e63f0fc, 0x292ac9d69e077, 0x2b86b413fe501, 0x344aeef70b8b6,
+    0x35e253fa2f146, 0x385ede26263e2, 0x38f969dbc0484, 0x390058eb1f2bd,
+    0x3abde9e9257f3, 0x42737b3cc7ee4, 0x47a33ec409891, 0x49d38346eceba,
+    0x4a7cdb004c2d3, 0x4afc776cfeda9, 0x4b8657abd4705, 0x4c8ded927cbde,
+    0x4cee7555ff7be, 0x4d704cb52a6a0, 0x5017c9459cce8, 0x553bed49893f4,
+    0x58284efe9559b, 0x58bdec0842fc0, 0x5c5fe25a63038, 0x5ebe2fcc8f845,
+    0x6bab9c2adfa71, 0x73ab25e86bcf0, 0x7440bf4929150, 0x751f8f1a0cf75,
+    0x7952e71fefe4a, 0x7e58c36f40978, 0x7f5c2551dee13, 0x7fcd84776a675,
+    0x81c519678806d, 0x83d90f47616ea, 0x83dbf63dfaa21, 0x879f597345bc3,
+    0x8a1f2d672fd5d, 0x8e4190c387188, 0x8f3ee2d80c7db, 0x921e161b6c200,
+    0x987efb41cdec8, 0xa42c0c310dd4c, 0xa49b2cbb30bdd, 0xa576e9aa3718f,
+    0xa6ab2ccef6431, 0xa8f3ace14cdb5, 0xaad0e4ee118a9, 0xabe0c93e3b59b,
+    0xae17372dca26f, 0xafed9d72b2d52, 0xb6b863f2a3340, 0xbd17b2d8ccf9d,
+    0xc2ebf90c13f49, 0xc4dcd97bd8758, 0xc532cce8905c4, 0xcbcd02ab07c99,
+    0xcc06a774b5b59, 0xcf8f5e5cb8ed3, 0xd46873cf603d5, 0xd497759e81e0d,
+    0xdbe87715dbe1c, 0xe20b8717761ab, 0xe3608ec42fc11, 0xe3813604a75ad,
+    0xe46382daa3396, 0xe75936d3da6f9, 0xe7bf768eed6a9, 0xea28fd0a24fcb,
+    0xecc18ca65eeaf, 0xed08eaf56702f, 0xee9d89f264975, 0xef6d18a177b83,
+    0xf1eb948d1fec7, 0xf6efb9abc559d, 0xfc31d4dfa5999, 0xfcab986454cb2,
+    0xfd6dd05f677c7, 0x00bb329ef23b3, 0x05ffe444c3bf8, 0x06e2a638cb281,
+    0x07dc9c38abcfd, 0x0afaafcd5d588, 0x0bc82bcc4c301, 0x0df5527280f68,
+    0x0e3fe83cca30d, 0x12e735da29e8d, 0x14a6c73ba2b7f, 0x18eb4728365c4,
+    0x1c9b6c1dbc19c, 0x1d5c7452e0dd2, 0x1fab12d711cb2, 0x23002276ce2db,
+    0x236414f2b71a6, 0x246634c2b5a89, 0x27ce40c150ea2, 0x31a5fa4122d35,
+    0x3696d62531cf2, 0x37c95ac764fb2, 0x389661a84848b, 0x3d4bb1fdc962f,
+    0x3fd86035c7a98, 0x50d3f527e0d0c, 0x511b830b1d98d, 0x532ee2dc7837d,
+    0x59b0ef5c2e3d0, 0x5e5cb2de7e768, 0x5ebe1d8c378fe, 0x5f5b9edc2c3eb,
+    0x655cf51ee3d27, 0x677b695fe5e7a, 0x6bbce50e5d760, 0x6f288b5993965,
+    0x7014ec6d7dad3, 0x7c061935b3763, 0x80c3bb9da66e5, 0x821f4f88653bd,
+    0x83cfe8fad47fc, 0x870f6972b11b1, 0x892fc0cfa2c98, 0x9005e4f201d4d,
+    0x90205b73ed4b4, 0x92483445cc49c, 0x93049aaabc37e, 0x947b6e630a510,
+    0x966e9fbae93c9, 0x98e9138dcbc0b, 0x9be7df3c284da, 0xa00b58bfdaf81,
+    0xa28c79c135e22, 0xa5869c4d7ce13, 0xa7a0acf640427, 0xa7a2d59c06b5a,
+    0xab6c6d38ce4f6, 0xae4c995c0676f, 0xae86abf1df345, 0xb1fe040d1c4c4,
+    0xb306ac722fa54, 0xb3158719b64df, 0xb6881c744b51d, 0xb8ca6bc815c6c,
+    0xba51429bf7bde, 0xbbb519f566f80, 0xc1db1be86b795, 0xc301b082a18c6,
+    0xc32b17a1b5d56, 0xc41047c55a8b1, 0xc68ef6ee27cbb, 0xcbd0870e5a579,
+    0xcc1a329c8c7de, 0xcd06c6ed9ba12, 0xd7133a341f6ad, 0xdf9f2103dfe6e,
+    0xdff6408d955f4, 0xe7477c93f5253, 0xe7d91c7fae2c5, 0xe8fcf60e8f5fb,
+    0xec5a5417cd43d, 0xf18c05b5cf8cd, 0xf3a383959205d, 0xf598efdfe9322,
+    0xf65e420eba234, 0xf6a2d305bb8e0, 0xf9470d201bbb4, 0xf9f5af04e0687,
+    0xfbb8bd6b7133d, 0xfef966575b047, 0xff47005fc054b, 0x021b8204d23d4,
+    0x0318455cb9924, 0x0633635710764, 0x0a7880a4039c0, 0x0b8054c2a20ae,
+    0x17892e5dacf96, 0x18ffb8e333dc4, 0x1a3e1b40b253a, 0x1f086c017524f,
+    0x20f7c4d4c3107, 0x260e120bd272b, 0x32c81902c23e0, 0x32c8a06d7f959,
+    0x35c835677ff7b, 0x35d3a1adb0156, 0x37508c85f7cba, 0x3b905fa78585c,
+    0x4228aafc2ca13, 0x42578bdadb05e, 0x43470e7464153, 0x4988be0122b38,
+    0x49f0263042e3d, 0x4ab595a696b77, 0x551d1e42e3dc1, 0x57cd98e9e9112,
+    0x5a81525e10212, 0x5ea502c7d8344, 0x61cac765abbd1, 0x64b61a453d286,
+    0x6828d7e22623b, 0x68b93d0d2027f, 0x6d0a5caec2343, 0x732edaae407c6,
+    0x7705f4ea4cd97, 0x79c672ace5ec8, 0x7d578df40ccf9, 0x804a6d435c9fe,
+    0x85cfaf194a977, 0x8a78252ab784c, 0x8b0b5eff98eee, 0x8ccf071d98df6,
+    0x8dbd5d9a245d1, 0x942e9a09b252c, 0x96e6edfc2d7f6, 0x9816795b15aa6,
+    0x9866ce6ecfda5, 0x9c0f12c6fee7a, 0xa07d764293179, 0xa9a6743f0dcd6,
+    0xb004726ed6667, 0xb023836f15f98, 0xbb501c594d91a, 0xbe0935ee7c372,
+    0xbe92a4b8369f3, 0xc444f2f4a4d37, 0xc474872954e37, 0xc62f3882a2bc0,
+    0xc84f38090bc2f, 0xcaadb50f6cf3d, 0xcc38dd2edbd15, 0xd2218dbee5ba5,
+    0xda13f405967f1, 0xe1b3e78d97b5a, 0xefd73bf4a07a7, 0xf2dd97bbabc8b,
+    0xf49b166d004e8, 0xfa0bc94cc7bcb, 0xfab707cb06f3d, 0xfc7f46be6aa4a,
+    0xfe1e7dbb236c9, 0x0019552153435, 0x0269e1bc44df8, 0x034acb8f2dc3c,
+    0x049f029e62dc5, 0x0570487e2142b, 0x09f57d3434f56, 0x0ca9fff3ffda9,
+    0x0d1d6a77cef16, 0x16c651bcb3e72, 0x189812887763c, 0x19e80e41c1bfd,
+    0x1a217fce4a9f7, 0x1a228afad4f24, 0x28e52a2f2f78f, 0x2ac38e58f1b9d,
+    0x354c57ed259d5, 0x35e071034d068, 0x36c47c5bd4e33, 0x373009eb3da62,
+    0x397b3db708657, 0x3a051a4ced9ee, 0x4834c8df2df1b, 0x4bf8df6a7ab72,
+    0x4eb6e059b50bd, 0x552594fb132e3, 0x5961bb83543c0, 0x5ba5edf30e4a3,
+    0x5caab2d8bf8e3, 0x5f9429ee4218e, 0x6e66412a82c9b, 0x6ea71e3656cf8,
+    0x6ff02ae042480, 0x7197e383a19de, 0x76f4cd0ff5fb9, 0x78fc5a20d6642,
+    0x79e06ccb3ef97, 0x7bc6aa407731e, 0x7dececd21e29b, 0x7f6ad4332ec18,
+    0x83e0f009c5aa3, 0x871d05c93c16d, 0x89766be15ce87, 0x8e9a25c20ee42,
+    0x91c02145f7d5a, 0x92f5057406dd8, 0x995dd289a012d, 0x9f7959c46c431,
+    0x9fa8e6a73b9a7, 0xa1107594c895d, 0xa3961775e5165, 0xabd9b211960a1,
+    0xac55b088bd7c6, 0xace83e597df3d, 0xb0bbb34e1e5d3, 0xb60386798ed9f,
+    0xb641ac22fe5c6, 0xbb609bf1278b6, 0xc0f0d8982a72f, 0xc3626f44ed655,
+    0xc63544b4251d5, 0xcd67736335ac8, 0xd0425cace9211, 0xd16a8b3047972,
+    0xd1d0890a74148, 0xd874026d552c5, 0xda0d5cd91a1c2, 0xdafbafacb53d8,
+    0xdde193c77da21, 0xe0fbc3dccd15c, 0xe14d3a12b3c26, 0xeabfc655ddbc0,
+    0xebc2713989fe1, 0xf578450f34ee5, 0xf65a78469e46b, 0xf72fee817654a,
+    0xf8fe74290852a, 0x0054b879e7069, 0x030d2262045d1, 0x0a429e81f8387,
+    0x0d74a3ac70c91, 0x10386b36fccc0, 0x103bbff9c0d0d, 0x1b8ba191325c3,
+    0x1dfaf5f99c553, 0x1e65d3dbf3c92, 0x1eb74668ea197, 0x2155a04966124,
+    0x2359b0552e7e9, 0x2550366fd16ef, 0x28e9aec43b44d, 0x2985ee0190382,
+    0x2c6e10b7c139c, 0x2d73ef6dbe41d, 0x30c489288a22e, 0x3602655d8f49d,
+    0x3d5554eba9f8f, 0x484085b65fd76, 0x4c4d479e25854, 0x5327060d1f6f5,
+    0x5469c1080b85b, 0x57f3573154bc6, 0x5b4f64843173c, 0x604c7d4bf51b1,
+    0x63c82e049fd20, 0x64f125d3a21d4, 0x6a0c1131f5f87, 0x71f925fb6098f,
+    0x76439c177a9b9, 0x7b2624bec0464, 0x7be784ca6db12, 0x7c63ffb181661,
+    0x7f3ae8344fe32, 0x81ac828a0331d, 0x87105b25b6731, 0x893b88ba59766,
+    0x89ccd4787a325, 0xa8f2a1e629ed0, 0xb630a5391abd5, 0xc00f4dc84b8a1,
+    0xc5a128016730f, 0xc6eaa9c95e337, 0xc87f242cf82cb, 0xc96e77324c8bf,
+    0xca71813e622e2, 0xcc3a822b88fb8, 0xcf258f81438ce, 0xd319c554ee163,
+    0xd8cb8fcf8f4f3, 0xdaa96193c3239, 0xde0aaa6e43d55, 0xe67b1a8a5f9d1,
+    0xe76c28694f9e2, 0xed4814aae7841, 0xf0e2096f3d9c9, 0xf9cee5ceaba80,
+    0xfb4702eb7a354, 0xfb9a932365e3d, 0xfbfdc74e7c104, 0xfc20604ea4b48,
+    0xfd6bd3d64cc18, 0xfd971e1150a3c, 0xfe95a394b4533, 0xffc7ba6abcadd,
+    0x016cd1a159aa3, 0x05ce697c9b1e7, 0x07e40b924eb6d, 0x08135a3d27ece,
+    0x0affdce2a7462, 0x0b3e14d8c8962, 0x0d507f733b0c3, 0x1537fd3588ae8,
+    0x24bfa90edd4c6, 0x27bf246a4b285, 0x2d2dfb8003b5e, 0x2d5c5c45d57ba,
+    0x2e99bac8aa58d, 0x31abe9502fdd8, 0x35e925ffcc531, 0x3bee28dbd0932,
+    0x49a2bf8a102b5, 0x54bfb0d82ce94, 0x56345f93a4eb8, 0x5b300a5d84839,
+    0x5fe2f02c58ad0, 0x6186921330e18, 0x619b8031c0cb0, 0x6ac6773fcd43f,
+    0x6fdb393175b47, 0x74f3a7bc6ad14, 0x7ed77d2f7e272, 0x7f17b1e021969,
+    0x830804346a0ae, 0x8786721d72d17, 0x8dd48a792a6f0, 0x94d8a195306eb,
+    0xaa763da2343ea, 0xaee6c271ae292, 0xb57fc86e37a88, 0xbaa99d17d39da,
+    0xc25563604f0aa, 0xc48ea5f9c53dc, 0xc6b2b893c509c, 0xcb2a5c06d287e,
+    0xcc9cf0c045883, 0xcec0c1795ed69, 0xd000539ccb3be, 0xd0f5fbfa0f599,
+    0xd60bdf0de446c, 0xd8aad9f7b01d5, 0xdae09be31df7e, 0xdcd5f989b3b2f,
+    0xdd007a7913c00, 0xe3d5b8084c13a, 0xe9afab3852367, 0xee4963e9bb5d9,
+    0xef36cc813f6af, 0xf634660301b4c, 0xfb54cb20efaba, 0xfbdd5357835c0,
+    0x01e8f8672eed1, 0x04d84fb695ac3, 0x07629367e701f, 0x0cee184912ea6,
+    0x1601cca784b2e, 0x17f19346fd6fc, 0x18fbf2dcbc0e4, 0x1b2c9a30f92d4,
+    0x1f44ff4504618, 0x28ed960541686, 0x29571bdf56f6f, 0x30bcd0e63b1c6,
+    0x31776a24dd9b2, 0x324e1ec965084, 0x32a8a1b99ff6b, 0x35794ada20df3,
+    0x3658c3063557e, 0x39567c3626d96, 0x3f876dff6f35c, 0x4f2725d402594,
+    0x4fa5f54c20d52, 0x53de51f392542, 0x56fd745dbe8bf, 0x5acf655ed8bc3,
+    0x63b9b9f016f45, 0x64962dde0f571, 0x6670b2315fdf4, 0x69ba6b808e4e0,
+    0x6bbac3f838f91, 0x6d3cd0b992238, 0x70c53ac5ddc89, 0x721173f0394e7,
+    0x72951875b1abf, 0x7576d38741219, 0x75dee068a18bf, 0x76445e29f16a6,
+    0x7a2798022f907, 0x7d80edf2a7c47, 0x8ad567009f13b, 0x8b333b0aadaaa,
+    0x93ebf008f8421, 0x98e2963149487, 0x99464a753c13d, 0x9a436cf58cc8f,
+    0x9b4eedad77e42, 0x9f53cef7e6e1d, 0xa0564a475e73d, 0xa065bea65441a,
+    0xa2585646c4081, 0xae3f37eaf6301, 0xb2438a78c71d9, 0xb36a893637a5b,
+    0xb811989ba13c6, 0xbb9790cf5f380, 0xbee77e860a266, 0xc1cab6393f1c8,
+    0xc3e2c2d59fa5b, 0xc56402a2649ea, 0xc7e19ba3d6778, 0xca42e6023e55a,
+    0xcf3faa9c69b1c, 0xd076eaad64393, 0xd415d1c69715d, 0xea41e4e7d43bd,
+    0xeae684db5c7e4, 0xeaf47546cc12f, 0xed49c84b561f8, 0xed5f5ec5169f6,
+    0xf07fe7db462dd, 0xf11f5aeaebe72, 0xf59049dda046b, 0xfa26524ccc653,
+    0xfb74a9221d495, 0xfbd479e6adffb, 0x04c641491816a, 0x059eb0c73b259,
+    0x06379798dbb7c, 0x0682f6aec8c74, 0x0705ce3facf34, 0x0fa3c732353a5,
+    0x0fcd778410b99, 0x197dfd8565e1e, 0x1a90dec1cb0e5, 0x1f38216459b16,
+    0x20069643210e3, 0x26187ab51a33d, 0x2cfd33f5290de, 0x2e923d1561175,
+    0x344ea6e19e3c8, 0x3806bec12c77c, 0x39b6ac2f7ac90, 0x3d5860a0b452e,
+    0x4a8ac4704ff92, 0x4c0db41bdf7ef, 0x578a519529582, 0x5e39a1617502b,
+    0x6a2ace29aacbb, 0x6a5bc90a5e325, 0x6cf5936757237, 0x6d6929d66a8e0,
+    0x6e6f6b5dc1528, 0x6f544001ed046, 0x7082b8e26a23f, 0x791fd63c9cc84,
+    0x7d375c0f61fd7, 0x83347aa51f310, 0x84b1707837fc3, 0x84c45e335569f,
+    0x8671cb2554a08, 0x8d3c7f589331d, 0x900b3e0b43c87, 0x94ced2f9ccc29,
+    0x967fda3866d7e, 0x98eb8d0255398, 0x9cb975eaa9b9a, 0x9e1686770e07a,
+    0xa0c0ccb696bd1, 0xa3f95a7624188, 0xa65f46a4c0cbe, 0xa88075f117c0c,
+    0xa9411403020f1, 0xa9b44b49ac198, 0xa9cb068e89726, 0xaf0b3afd89bc9,
+    0xb61e4a0899477, 0xb9916a76dbff3, 0xbbb846bff21c2, 0xc1d2f9b449624,
+    0xcc22fa1724fc8, 0xccada74a8ec3c, 0xce69eee414a30, 0xd099eddd212ba,
+    0xd7c55d1107875, 0xd87978b566503, 0xe9bc0cce1e2f5, 0xea1f1c5fd0c0a,
+    0xeb4404c42ca32, 0xed7ae07fe641c, 0xf1511b7aac9fc, 0xf2c27cc54155d,
+    0xf3f8d29a24c94, 0xf7859543de0d5, 0xf96aec25ef8e1, 0xfab5806c519c0,
+    0xfcc02407ffe81, 0x001da301b10b0, 0x009bf9b2d3a09, 0x036897119e7ca,
+    0x0afddca44255c, 0x0bba6ef47697a, 0x132bce7980fc1, 0x13ea4b37858fd,
+    0x14ec884f07008, 0x175f1432b21bd, 0x18c03080cc764, 0x1da577dc575cf,
+    0x1f66614612117, 0x1fbac5c472aa2, 0x2517836582b41, 0x287ac1a8a4cca,
+    0x2b32992b33b6a, 0x2f3becb1a2d98, 0x2f59308b94fd3, 0x32793d31d8490,
+    0x3ef1ee55f8283, 0x47a26ae06367f, 0x4ab7b7449788c, 0x4f0a0a5517df4,
+    0x4f681c971c978, 0x50edcaf6f1595, 0x515139b10d4ac, 0x5306789ca1c04,
+    0x548f413034c1e, 0x59a432b611e27, 0x59d45cf940bb4, 0x60d4092b274e6,
+    0x649f2fb646363, 0x64f6f0494b8c5, 0x653b13742225b, 0x697de72adb997,
+    0x6d1ec755a0393, 0x6e6b9de05b408, 0x71d90da8ee73e, 0x7f4274d575aca,
+    0x86af8e09c43fa, 0x8a0ce86a11c77, 0x8c3ff69c0b7af, 0x8c4ba11a31b9f,
+    0x8cb04e3dfd0a9, 0x8cd8fe5e1f4bf, 0x9525eb3dfb5b7, 0x9cdd9693a2cc7,
+    0xaa1c4241c4332, 0xace78b413d862, 0xb161a7deb9665, 0xb79e392b56ecc,
+    0xba9ff6cb1dc21, 0xbb08c1fd50ecb, 0xc72d83c11bbd3, 0xca823b8a4f784,
+    0xcb1ec0517b458, 0xcb65bf7819fd6, 0xcfb8546980d18, 0xd16c25573137c,
+    0xd215671aee4f7, 0xd313e54c71464, 0xd42b81f79dce2, 0xd725a82cd5298,
+    0xd74feae43f154, 0xd7a79581a2418, 0xd986f28b3d07a, 0xdf7e67cd2b6f7,
+    0xe018aa4ffe435, 0xe442ac68c5b89, 0xe50049e5844d4, 0xe99daf303a20b,
+    0xea7380ea8b327, 0xeb0cd1df6639b, 0xebe35e4199af6, 0xec8dd71d256da,
+    0xedb0b07a4d632, 0xf029119d0b9df, 0xf04d398b8b904, 0xfae88a6092c8c,
+    0x01aa9101e6786, 0x04665aa798e8a, 0x0857e413b0038, 0x0ddb3080fc944,
+    0x0f4c91cb914a5, 0x0f596866b51af, 0x126a3b3d085df, 0x1300ee6e11680,
+    0x138f9c686026c, 0x13cf510026f74, 0x13eb4423b7ee3, 0x1e4584afc6148,
+    0x1e86de2b865cf, 0x2018f55a541e0, 0x231661ae8e0cf, 0x24421f83b64cd,
+    0x26582bbca45a4, 0x2b97047a7262c, 0x2c49e1b521cc7, 0x30c64f3ab8174,
+    0x355cea0f72a24, 0x35e307bf5961d, 0x3f362030ea0bf, 0x3f9aa8bb51c17,
+    0x4f7acfcf8e196, 0x55be0aa1b3a97, 0x5619ff38c3037, 0x5e3a7217ad42c,
+    0x61a8869280b03, 0x62f8da2253b3c, 0x65b16c4cc501b, 0x66c9bc4df334e,
+    0x6dbc65f971a3a, 0x6e1e9d9bbeee8, 0x6f72ac68a00fa, 0x706b6ef9a26d2,
+    0x72f40dc6fa4af, 0x75541269d2114, 0x783d613d00a48, 0x7af03b6fe1b57,
+    0x882d164ec5507, 0x895978d62dca6, 0x8a71e9c7cf3c6, 0x8f0fcb8271527,
+    0x9224d9aeaf51f, 0x974bcb0aa26ff, 0x9dd01625c1123, 0x9fbb07b7dfed7,
+    0xa4e95d413d790, 0xa7dda33c909ff, 0xa99ce41961803, 0xab3e2a1d4e352,
+    0xadecdeefe6b37, 0xb02445bf4e05f, 0xb25715b951982, 0xb2c2ddd7c0201,
+    0xb6310bf12613b, 0xb8d17fd3a70e7, 0xc121a42ec4404, 0xc57fe39e51536,
+    0xcadf75e948c33, 0xcb2d766bf82c3, 0xceba6500f5c40, 0xd2dddadbb8122,
+    0xd300b965b7905, 0xd318ba6625010, 0xd5e6d0bda5014, 0xd628ec3328664,
+    0xd6fd27ad41715, 0xd76f870486355, 0xdbbffe4af7ab3, 0xde41687f19cb8,
+    0xe18e6b963b2a2, 0xe239b8b83bd28, 0xeb9f30284bda3, 0xedd0c56190698,
+    0xf0a78cf3eba7c, 0xf7d94dd6d1659, 0xfdec277fb2b42, 0x02cff98b78d2f,
+    0x039ce6cdc99a5, 0x09efc59319a84, 0x0a535c8f89912, 0x13843b295af72,
+    0x1bf774f48d0fd, 0x1bf7c922255b0, 0x1f515d4a93b05, 0x20a0d8eb016d7,
+    0x22ca828c1e37a, 0x247e2b377aa67, 0x24998f9e6d8d3, 0x249c310b2fe6b,
+    0x31507166ce03c, 0x335eba49e8438, 0x3e0c5f229dc4a, 0x54c160f4b8b3c,
+    0x54eb482ca99bb, 0x5b19ecb6c7e9c, 0x61d879fbf3f97, 0x6a2e85e2d6185,
+    0x6db3385b03fc2, 0x707c1a7cc0baa, 0x716f0b77a054f, 0x7331c95989317,
+    0x777c17334f3ca, 0x7db05dbf5b9dd, 0x805ac4ef57921, 0x81f78a1382b09,
+    0x84477cf70527a, 0x9530417583c3a, 0x96ecb4fa4df6a, 0x9ad11e987a0aa,
+    0x9e41078b7ba01, 0x9f7e0a8ed7797, 0xa00663313679c, 0xa6eaa00159113,
+    0xa90630f4b24f4, 0xad238faf7b004, 0xad851b4da7587, 0xaf398d44a79c7,
+    0xb457b01e3c99f, 0xb7f95d3d956b3, 0xba9849839fd08, 0xc19d8cbca361d,
+    0xc2a9654b17248, 0xc2bb9c07428aa, 0xc3959a73bce7a, 0xcfbc81345dcf0,
+    0xd12d660f06427, 0xd992b6c0a97f1, 0xe205532b7c165, 0xe621a6b9ba748,
+    0xe862b7a3d58a4, 0xeca5790ddd307, 0xf808ed12ac63a, 0xf90f02aebed46,
+    0xfb97ac76e7c72, 0xfbec09a367a73, 0xfc352674fbbd5, 0x094139b050fe1,
+    0x0ab17d81a993c, 0x0ec44093c6b4e, 0x116551d6a7311, 0x1f1e1c88f0cd2,
+    0x2478f36520e18, 0x2a4938d182e4c, 0x2bd5945fd00c8, 0x357f4feb29ec6,
+    0x3865514d75337, 0x3dd8acebdfb3c, 0x47528b0fc24d6, 0x4b99f1f2ef252,
+    0x4e6ad94b662ef, 0x51021f831fa91, 0x53db76358b734, 0x5fb36393a46c6,
+    0x678ad6e87d781, 0x6cd14e62bbb32, 0x6de8acd5f219b, 0x6e35604b30b24,
+    0x6f99f5f572aca, 0x7441e49c22426, 0x7670b77831396, 0x7cbae159b5ad0,
+    0x8340429c2fb70, 0x8e83b069d72e3, 0x8fd20e9108caf, 0x93b3ff04c67ce,
+    0x9a59df4564293, 0x9c01859c11b18, 0x9d4af51756e67, 0xa30ad83fbbab9,
+    0xa4bfc6a6a8323, 0xa5688bfa79074, 0xa5e339bb3f4cd, 0xa67c9cfccc21a,
+    0xa71c7a2fac500, 0xa8a3ee63edc89, 0xaa35adbc32e22, 0xaa904df3f0b31,
+    0xab62c7428d63b, 0xad99b8f3e92c3, 0xb8e3a0b2e3fcf, 0xc04b85b16f4e3,
+    0xc32b68a1e03f8, 0xc6e69e5e1d16e, 0xdc48326ca6c2a, 0xdf7778e2da174,
+    0xe1993a94bf78a, 0xe290424c26345, 0xe41ec2d74972f, 0xe4eb758a94755,
+    0xe8a01f08cc259, 0xea157d715576d, 0xeba6bcb0bdf17, 0xedb0726764cd3,
+    0xf0bc7b2b2f438, 0xf24016dadfbc0, 0xf3e1a268a34ae, 0xf831db771e9f7,
+    0xfa381428ad7ef, 0xfe32efd8efb59, 0x00e28ee79f47e, 0x062e284aeef72,
+    0x0830971bae47d, 0x0a118e0e7170f, 0x140d29f2ec728, 0x1a5dca1cd3e36,
+    0x1ac5f09cc6d3f, 0x1f47db8ae796c, 0x224418193397c, 0x23dfe45d86310,
+    0x24169735c9d4a, 0x2a66c241a5bb8, 0x2b9580abf9b50, 0x2fc06957e7e1f,
+    0x3337cc6df60ed, 0x3598a1ae5262f, 0x40c28a8806305, 0x4222cb3dcae80,
+    0x4cf78d9f5b002, 0x4e0f05b123ece, 0x5384e1cbdd87e, 0x54a7fd09ebfb0,
+    0x565771b13e65f, 0x597cf46e2b712, 0x685ce9c929e91, 0x6fe04ccd3aa74,
+    0x71d72e67569b7, 0x7472f57ed1ac0, 0x7921d334e0255, 0x7fcd2d3517ed5,
+    0x827909b4d8a97, 0x8454206db79e2, 0x85eaa62f95281, 0x888c169adf046,
+    0x8a615f668b923, 0x8e370ba4b3e00, 0x906f0130b942b, 0x98519798fb35d,
+    0xa2255051e8278, 0xa83c1c1decfc5, 0xaeb644575bf50, 0xb1f1ec4aa7904,
+    0xb6c0e62d7fef7, 0xbb7653ca83ec3, 0xcdc547dc6ce59, 0xd5e749a9ae72f,
+    0xd815c4af34c27, 0xdbb040de48328, 0xe29e27c91e8d3, 0xe99d8e712e466,
+    0xf6bd21cd2f616, 0xfd745236d1bc2, 0x003d17110b982, 0x01a1f245246c7,
+    0x01b73f8c1db61, 0x03d274fffdf05, 0x0479c50409fd8, 0x06b516a4b41da,
+    0x0810899effac5, 0x0d3f076ab12f5, 0x149c8ef887120, 0x17c63715bcafd,
+    0x1a986080d3752, 0x1ad0a5f05ec32, 0x1fdc74c645780, 0x2387daa4137dc,
+    0x260fc61b69b04, 0x2c90f702ca12b, 0x2dce25f16a3fd, 0x3b6c93c05aeba,
+    0x472df916bb984, 0x4f680a882c079, 0x5b4921e81c250, 0x62593f01c1678,
+    0x6c4f43df1f6ae, 0x6c8e7c06f9f8c, 0x6dd2dbe5df871, 0x77c0875eeaf3f,
+    0x7a665067a26f4, 0x7f54e2b577b91, 0x84b68b5983ce8, 0x8602ae8e3d2a8,
+    0x8a37365a0e05c, 0x8c617d5b8a516, 0x8f45d287fb67e, 0x91d3d51f4b378,
+    0x97972300162b5, 0x9b75dfa183174, 0xa873984e2e4e4, 0xc04e447e683c7,
+    0xc1f658a140962, 0xc58f07aa0696d, 0xc845ed9e9db43, 0xca6c5120b7ead,
+    0xd565a594026a5, 0xd72bc6dc70bce, 0xd82ed0e8865f1, 0xd898272e673d9,
+    0xd92bf368d7143, 0xdae12007b9bc2, 0xe1f06531f9b83, 0xe41394e1c328d,
+    0xeadc3db6be297, 0xec1e15c773e47, 0xee1b8af175b86, 0xf13eae2286e7b,
+    0xf84174882bbaa, 0xfc2d71e7f6ec4, 0x02ff98ec615c6, 0x057b6c19667e8,
+    0x096d9de0ae2fc, 0x0ae8a59d064cc, 0x0f51c9e806260, 0x10154b47994b7,
+    0x12c483dbfec50, 0x19195809f039c, 0x1c6b25339a651, 0x1e27d3476a5d1,
+    0x2036cf8086482, 0x20c14976cb030, 0x21728cc85209b, 0x23c7f96b6e9c7,
+    0x25bfdb3743ce8, 0x29b30d3044bda, 0x2a5d7eb9efc34, 0x2b2095f64873a,
+    0x2ca6619da017f, 0x3271bc07060fb, 0x382b1dec36c2a, 0x4b4723d9d41e8,
+    0x4c2814fe9dbb6, 0x4fa5007807753, 0x5335cee376c5b, 0x5a0ec05ddc7e4,
+    0x5aac69f025248, 0x5b1e7170e1410, 0x5dac1888b80cd, 0x5e348377c8dab,
+    0x6060471aeaa6d, 0x6e7c12bb2562f, 0x6f9d6bcdb77ba, 0x7417a95ba69aa,
+    0x7e3402763b5fd, 0x80e679d7c2b45, 0x83de89b3e56a1, 0x8a267ff4d2559,
+    0x8a2c3f3e434b3, 0x8fb3b85db9273, 0x99aac115c0c4c, 0x9dde4c58c8be7,
+    0x9f4089c90f959, 0x9fc44f3efb31e, 0xa1c1bd171c4d3, 0xb6395e41592f3,
+    0xc21799a5812e2, 0xc4934be213117, 0xcab05e6246337, 0xd1b3c5d13ae42,
+    0xd4e8bced1dbd2, 0xda42974084eff, 0xe43b48857661c, 0xe5a22b6ee26a7,
+    0xe73100279df44, 0xeadf1d67d07d6, 0xffa2d84fdcd2d, 0x07043c0b3411e,
+    0x137581b008cae, 0x191cb42aeed05, 0x1dbdfcf506b8c, 0x26c1da2328f70,
+    0x291407f923aed, 0x3158090f1ebb5, 0x3757504aa2621, 0x38ae6aa78051c,
+    0x53479436e0d74, 0x58268d8c5cb82, 0x5f033decc0ea9, 0x64114b5e103c8,
+    0x682ba5dabcd79, 0x687ce1819dbf3, 0x6a33cca30c654, 0x769aa6336a3e7,
+    0x79aad0ae8ceb1, 0x7ac507749b13d, 0x7bf6eeb66ebe6, 0x7f85cf0b1d60b,
+    0x831f8ce95e108, 0x8427ec1baa334, 0x8f69d5f5cb715, 0x91385f464dcba,
+    0x9532d0d3558d3, 0xa0290b12e1cce, 0xa1e68272559a1, 0xa55b232b2e2ea,
+    0xa8a5eef8c7a8d, 0xac2b5fc1c84ce, 0xb15f240feedf3, 0xb82f903abdad8,
+    0xba5798a0d15c1, 0xbac0b457ac759, 0xbc1d8dfdfb5ae, 0xc0dc51881acfb,
+    0xc1665a09445ce, 0xc373bc5937e4f, 0xc6b6744c55a29, 0xc6c5f74f0ce1a,
+    0xcc142acd3e31c, 0xd558982b7ce34, 0xd5b04a1ac0c82, 0xd7617d449f313,
+    0xe1ce6259038f0, 0xe5ea914dde521, 0xe83af1fd8b9a8, 0xec3ac7548b4de,
+    0xf0ed9384ccf12, 0xf660606699614, 0xf7d36695278f4, 0xfb3946450c015,
+    0xfd989b3ad278a, 0xfe4030c8b55fc, 0x0910e7688f6b7, 0x11631721f02df,
+    0x11f796b261afe, 0x13266ebb482f9, 0x187fb30030999, 0x1be086bca5c15,
+    0x1d296b243d426, 0x229bca39dee12, 0x22cc2068520db, 0x27e90fd308c0f,
+    0x2a7b2ccfd00e4, 0x3288910329a10, 0x36ceecb9cc25f, 0x46c7c8247799e,
+    0x4dc61ff70ca3f, 0x5f7724ecd61d2, 0x610860834e3b7, 0x63898c7f7a3a7,
+    0x6cb80f366020e, 0x6dc3605a168c0, 0x6f389dd22c9e7, 0x7219f26971fb5,
+    0x8acac0d8a4ea2, 0x94bc86b727d4b, 0x95ae1c00d52d5, 0x9a1ced48942ea,
+    0x9c8ad3b31a186, 0x9cc3b68304e7d, 0x9fae2a2150541, 0xa1e76cbac6873,
+    0xa72799d7e618c, 0xabe994dd46afe, 0xbd64ead5761fa, 0xbf52817d47b0b,
+    0xc7d165c6a0086, 0xd0f21cab79f54, 0xd1e29f76bc427, 0xd4d2f34eebe32,
+    0xd53d6ab6f93e5, 0xd64830c701f59, 0xda2b49aeccdcd, 0xe1b1b499ea0d4,
+    0xe9891cf3f2040, 0xea10a8a1bcd2d, 0xef20574e15406, 0xf063a1059f66f,
+    0xf144b31adc42a, 0xf9fd6470367dc, 0xfc868aa84bb32, 0x03007852b4e6d,
+    0x10c1d34f66821, 0x1283ec7f0f248, 0x1804a9cc4b295, 0x1a0eb7597aac9,
+    0x20497f28bb1ff, 0x2389f12e8f87e, 0x23916efa8c7ba, 0x284041b5c9e00,
+    0x2a4f3a45f56ec, 0x313635ca5d423, 0x3f06432c418eb, 0x4e9b92954e2eb,
+    0x5af8edf63c986, 0x5f4c4c334741b, 0x61ef5687b9810, 0x7a2ddb9549d5b,
+    0x8dbddac354589, 0x97f22291a4c0e, 0x98bb6e3579f0e, 0x9f85b8457e0d2,
+    0xa5339b97ccd4d, 0xac3b50a95e0f9, 0xb93d8ddebb395, 0xbe3651b2019df,
+    0xc51e5fb4d47cd, 0xc5e9f4eee35ed, 0xcc02b9cc79f6c, 0xcd6aaf34510ae,
+    0xcea77b51977b9, 0xd1de0504c1fef, 0xd2f3d8329173c, 0xd314ba020ed28,
+    0xdebd1e2648d09, 0xe3746edf3c669, 0xe6e5ea6985a66, 0xea6e99ffa8256,
+    0xee03ecf3c968a, 0xf1f9d2a63e7ed, 0xfe477f193155b, 0x05e8608639fc9,
+    0x0bd7cce87dbcc, 0x1ac0a3128c22c, 0x1d5fdc344e1aa, 0x1e5454cba44a6,
+    0x1e5f23b174e6a, 0x2745359ae7a9a, 0x2f0f22a78b95b, 0x34c96b1fe3005,
+    0x3c440e45570f4, 0x3c6ba83e1de8e, 0x3d8cfda7bfa54, 0x42f703590ead8,
+    0x5b063612d56fd, 0x62f0678e97393, 0x66c593b3e2e81, 0x6763f4451d95f,
+    0x6c30edc483796, 0x6ce06b419dc95, 0x6e01cf4f00f6f, 0x73bd0cfe408a8,
+    0x7453bc8659384, 0x76a1ab5d78d74, 0x7b4e9753d6461, 0x8113da9d42a0b,
+    0x86a97eb72dd66, 0x9690828e17445, 0x98cf379546da8, 0x9af007b9346f4,
+    0xaa028e351e548, 0xabcb42468d8f5, 0xac94dac61a51e, 0xb8155815d5e3c,
+    0xbe33846654c9d, 0xc9349fd863735, 0xcf2404e8c67ae, 0xd103f0aeff513,
+    0xd7109d4244d8c, 0xd95159fec7a35, 0xdd2b32ef193c6, 0xe002f70a3d5c3,
+    0xe0097f9f52270, 0xe9238203d2f55, 0xeb0abfaac46ba, 0xf2ef73bdfbbd0,
+    0xf7650480e4f1d, 0xfe498a83cebf8, 0x03931136fa257, 0x06959bcb552c4,
+    0x09200b67ead5c, 0x0d18669bddf1b, 0x11bbc967fadc1, 0x19a32d8bb5f83,
+    0x1bdce8ec2811a, 0x1d6af80230229, 0x2b0874432901c, 0x33ee0d659fde7,
+    0x364dab8e2d8c0, 0x36f6623e3cefd, 0x3b6023e99c4a8, 0x3e75b5d7a7454,
+    0x3f8b5971420a0, 0x45d7c9400fd35, 0x470b939dd3172, 0x4fb9342c76386,
+    0x5b8d6d9f61cc9, 0x65ce1a93bad7d, 0x6ec9eb3942122, 0x6fbb6a8d4d40e,
+    0x7a749c9ca61e8, 0x7a98d6d7d7de6, 0x815855aafbbab, 0x9b7add3b4c071,
+    0x9f8a8619aae86, 0xb816d86cc599b, 0xb81b46ffd5629, 0xbecf389c55e26,
+    0xbef8861cd7a53, 0xc183eaf05577a, 0xc239c8c0309af, 0xc412e6677dcc8,
+    0xc7418bd461436, 0xcd4cd90d842cf, 0xd167497fd2f1e, 0xd68356005349c,
+    0xd869eb4c1429b, 0xde70881140ccc, 0xe136f4b17f858, 0xe66d23865308a,
+    0xe6c8fad5df802, 0xef8876a13503b, 0xf64b677e9fc75, 0xfc2dfd45bfb6e,
+    0x06070ecdd3857, 0x072642e38f874, 0x161363074a1b0, 0x1a6e8f94f9a6f,
+    0x1ae9ab21eabde, 0x1fa24c913f80a, 0x2cebe46cb4661, 0x39b00e4553daf,
+    0x3b77be7c197b9, 0x4a334db0a1dfe, 0x4e6d68da9f5d0, 0x560022f623068,
+    0x58e14ba2240fa, 0x59309cdb34a56, 0x5dcf61800d16d, 0x6bb2e05edbcc5,
+    0x7e8fb1cd8ef6a, 0x8060b7f1700f5, 0x8bd82c2fc7bbb, 0x8c11d4a266040,
+    0x9a923ec2aa9f8, 0x9ab27f88d8208, 0x9e46dd46113ad, 0xa9c6ecc9a1fb5,
+    0xaa8999e2c036a, 0xb345ecd696092, 0xb50104aa2dfa7, 0xb841ef76fe48b,
+    0xbfee2e86759c0, 0xc34174a8d4eb8, 0xc4eb5d43db6d3, 0xc71ff6bb3c127,
+    0xcc29e275332e1, 0xd52291951b6ff, 0xd9dc507dac531, 0xda0bcebcba393,
+    0xdc94abc208385, 0xdcf56319bfa66, 0xe045b74ab4ad8, 0xe1a0b8cfe50e8,
+    0xe665554207ff2, 0xf420b66642dfc, 0x0038a537da3bf, 0x010a9e6d9a4da,
+    0x042ff19652a8c, 0x08431b22b9e2a, 0x0e829ab7d1552, 0x1088b621dd522,
+    0x14aa27f03cc83, 0x1b482b3c7420a, 0x1b68f36d5ef93, 0x1f260fee7bc62,
+    0x233b4fd3f7a5a, 0x24291042045a8, 0x25c53f57b0b03, 0x28b33af5e52da,
+    0x2b9885593e6d1, 0x2d9e20aa6dcb2, 0x30159b3a054e4, 0x312047ab7b7f5,
+    0x33b4e52491e75, 0x3a24d486af0c9, 0x3a7300f4a2c95, 0x3bb4bf66c5fe2,
+    0x3cb1d6ec459e5, 0x3e8f1d9ccbbed, 0x52fcec6a00e62, 0x552bb7f42f248,
+    0x563f7fc3bb08a, 0x5a8adc72fbc2f, 0x69f1858c5fc34, 0x6a2ee5931bccd,
+    0x784f35bc087bb, 0x7c97ffa2484dc, 0x83b151b895c98, 0x8425efab432a9,
+    0x891b451d33043, 0x900e2205d67f5, 0x91c57e9c60531, 0x955a5177a4f76,
+    0x98146ee77d371, 0x9f2ebd8693946, 0xa57e38e55e2c4, 0xaa9235345323e,
+    0xabd76207cce3b, 0xbd9d3b7d93c03, 0xc60fa10250eec, 0xcb3750af16cd0,
+    0xcd60e80381c9a, 0xd962d9e10e8d3, 0xda7d478d321ea, 0xe0b29945c8d2a,
+    0xe141e849676f2, 0xe2918fd519800, 0xe678938e2734e, 0xebec8c8cf136a,
+    0xf72abed2f4b37, 0x0818d4ceb973b, 0x0baed76fec05f, 0x0c018fb8726e1,
+    0x1241f989a0f49, 0x13701341b4b40, 0x15b6cd7f9e958, 0x27ec3c9d93e51,
+    0x2c9d8c2c3007d, 0x2d873234c53f0, 0x35b8c5a87f7cb, 0x376eb7ea159d8,
+    0x37e25cfcea795, 0x3811f8837b41f, 0x39994f7039d80, 0x4e0091ff69d83,
+    0x516141227b64d, 0x53199698ddbdd, 0x54d389a158d62, 0x54f6d5f78325b,
+    0x553b584ac31f3, 0x56eb211fadd55, 0x5f80e8f0377d5, 0x6a412ea852d9a,
+    0x6e29cc4a88f18, 0x6f386e8813052, 0x708adc19eb0aa, 0x72e6f994705fa,
+    0x7ccdc0f8edfde, 0x86167850d8dd2, 0x8a2a553341c25, 0x8d9d28c5cce78,
+    0x8e5491db0efc9, 0x9499335937e34, 0x9e892fba5dbac, 0xa1c9f996baca3,
+    0xa7fae40e226df, 0xa948c96e58246, 0xafc7f64955aec, 0xb67d473ff8cc9,
+    0xb83f34845d1b4, 0xb876f9db0bca5, 0xb8eb2a018e5a0, 0xbfddf49d80079,
+    0xc3fe7886d80d5, 0xc5268270d2e84, 0xc7ccf02bca9da, 0xcb052d66b00a3,
+    0xcc5a943bd310b, 0xcd366e725c4e5, 0xcef026ebd1a1a, 0xd29993b80de44,
+    0xd4019b6c96c5f, 0xd6d919fde40bd, 0xd6e848d302ffb, 0xd73578b82ddae,
+    0xd748b6f7f3378, 0xd8aa326e76f21, 0xd8caceb41d76e, 0xdf34407bf6e64,
+    0xdf8ead768faad, 0xe00e6381d4de6, 0xe386a9821966a, 0xe3a91a3fee137,
+    0xe41073cb4cd28, 0xe46a819d7c36f, 0xe69778af7c4d5, 0xe736b881fcfa4,
+    0xea8b027f05e3f, 0xece64bb3164ed, 0xf4d5adbbaafda, 0xf615fa86f9c6e,
+    0xfb9d10d515e67, 0xfba6df892d44d, 0xff4aa6aa8b180, 0x01fe8c09f67bc,
+    0x0f2807b8a17b5, 0x10e2e8a62403f, 0x12f61191693a4, 0x1c602ad1ae173,
+    0x1d890cab0e389, 0x1f5f017adbb91, 0x30c87fdf4922d, 0x33dec8cc46253,
+    0x3a9f73bc67932, 0x3b00c1229dca0, 0x3b07dc1d41015, 0x3def7768b4ab6,
+    0x4f483f5b8c8bd, 0x53a338ac170b6, 0x64f9f8e99bb77, 0x6a7ab636d7bc4,
+    0x6e43798d2a6be, 0x7116ec5cc1a55, 0x780cd87e524b5, 0x8304c64599743,
+    0x87b9fcfc88084, 0x88c0f582d0d46, 0x8982d5a75ade3, 0x8e31f1955f78d,
+    0x97b88563f2a44, 0x9f0ed106b210d, 0xa26f5f39505ea, 0xa377c9666d965,
+    0xa7c9448788a66, 0xa8f8571f74eb1, 0xb010378eedfb4, 0xb1359c6336206,
+    0xb52ee9252108f, 0xc726af099318b, 0xc763f91aacf86, 0xc984b348f8634,
+    0xccdce0c56361f, 0xd1c7d51dad70b, 0xd2bf34ab9cd3e, 0xd5781eac96c95,
+    0xd6b35bdb85ebf, 0xd9301944a2221, 0xeca625b28e29c, 0xf0b5b1496a289,
+    0xf0be8e6f89ba5, 0xf0c21dc153842, 0xf2767619c141f, 0xf7df151f0cf39,
+    0xfad9e3af74e55, 0x026ef25c0355c, 0x0778779bb058a, 0x0b53fcc1dc224,
+    0x0ba7476ff0f6e, 0x12caf6f6f3e13, 0x19902a18b79e3, 0x22fd82261e561,
+    0x24c6955ff6f10, 0x26d5869e41c72, 0x2d49940ec6c66, 0x2d5700b8695fd,
+    0x3744c978f7af3, 0x3781c6ae59fc5, 0x452eac5377946, 0x477015141b51a,
+    0x4a696702ddc2e, 0x4b08e1646434d, 0x6208d82931be6, 0x786dad29e6750,
+    0x7a77f8ef0c199, 0x7b0058e34bd28, 0x803858875d215, 0x8133c02e1234a,
+    0x855db74c0894f, 0x8633a9f6cce58, 0x8c1a9c044ad06, 0x8eaf4f7303624,
+    0x8ee0ce1583c42, 0x9277e6de11be2, 0x9346630eb9d39, 0x9a9efbf0a34e7,
+    0xa38895d9ff22a, 0xaba3ea78c84a1, 0xb7b849f895dc7, 0xbb7a3f2ffce75,
+    0xc1404bc50d172, 0xc839fe1e7cefa, 0xc9a52b0195261, 0xc9b8534bb858d,
+    0xcafba0ac32dbb, 0xcd12086499ecf, 0xce3887092dd62, 0xd0f8ca3cc1243,
+    0xd7a4995b04763, 0xd8934efff9540, 0xe33faa742e610, 0xe4bffc5bebe9a,
+    0xe6ca9ff79a35b, 0xeb753eaecd963, 0xee3e540daf611, 0xeefb4cd82dbbb,
+    0xf2dcf07033db1, 0xfe636b415683e, 0xfecafbb2caaba, 0xfef43a8f8afd3,
+    0x024566afe54ac, 0x042e8ec4a712f, 0x0986aa958247a, 0x1336933677ee8,
+    0x13c6e614c0253, 0x1be12f86fef9d, 0x1e6371aa86609, 0x257db54ecba8f,
+    0x27ebfe8aab4f2, 0x2fd3a8383d453, 0x3510bb219e96f, 0x44437ebac5a0e,
+    0x45a4ca9d14ab6, 0x48d96d8b5f393, 0x49b7f0808b88f, 0x5c9336a9d7c18,
+    0x6203f82d60a0f, 0x684c67354972c, 0x73c14c5390933, 0x76971b06134c3,
+    0x7bde497f438ee, 0x7df7c07097cb0, 0x8dedc7315bd91, 0x8ef13065dadb6,
+    0xb51bcbee829d6, 0xb8a6a42a6b8f9, 0xbaa678e0493f6, 0xbbcf4c15e7ef8,
+    0xca487a4b1614e, 0xcade224f94cc2, 0xd3ca9834b8bed, 0xd4d6a400518de,
+    0xd5cea49790ced, 0xd6c917075db93, 0xd7949d9dab29f, 0xdd5c4072f3607,
+    0xddf8cc8bffe65, 0xe0f1e042cc364, 0xe62074041fe32, 0xeeca819808de4,
+    0xf480074fb834d, 0xf6b92ca1ab857, 0xf88918f540da1, 0xfea01ba75b179,
+    0x027e373f7825c, 0x04ef134458b43, 0x06be44f00ba4e, 0x0d27defa390bb,
+    0x0d9848e8dc68e, 0x106a29212bf7f, 0x11752d692ad08, 0x1d61a26b899a6,
+    0x1d8be17a0329d, 0x1ec3d889efb8e, 0x1f98d254db843, 0x20c9ddfe598c0,
+    0x26f6020c28bf6, 0x282dc5def0421, 0x2d7c4638d924c, 0x2ef8c344f609a,
+    0x30b6e6a88ac98, 0x3760b5635b9fc, 0x3770a9db2e0c8, 0x3db41b9369054,
+    0x52be30efd3d88, 0x53fe766941e92, 0x543d3d1c01495, 0x5be656fce947e,
+    0x70c708e3302e7, 0x75f4ff442459e, 0x78fea87c12f45, 0x79e4c684bf1a5,
+    0x7db6c6299abbd, 0x93a60ba9aa44c, 0x983ca67e64cfc, 0xab9b1fb80e382,
+    0xae1760b13e2ba, 0xae6709c0d768e, 0xc4e2c99a9e287, 0xc59004d35f7f0,
+    0xcaa41ac0e6fcd, 0xcf2360995509d, 0xd47fe7544fab1, 0xd735afcfaaa81,
+    0xf3040a68d1c80, 0xfdf346f53db2e, 0x03d09d8bc94bc, 0x054ad8538d374,
+    0x1768600fa79c5, 0x18555adb00d85, 0x1d0434e81ef55, 0x1de838eec6196,
+    0x2a3d7ccc486a3, 0x2ace2b2a09a4b, 0x33ef06a8472cb, 0x392d03cdbf927,
+    0x3c3cd2c9693b4, 0x5cfa437232a04, 0x5d3c74dd582f2, 0x5db7856f78312,
+    0x6232f2c3553e1, 0x63e2308492405, 0x6f31ffcf51fe2, 0x6fa854f2aaa4b,
+    0x7287d511c1d99, 0x74814222fdfd6, 0x7a6ddd844ab40, 0x7b580af79d42c,
+    0x82def252a6b5d, 0x832ce088a4514, 0x84073a7497b21, 0x9aadd6bd37828,
+    0x9cf54f4bf4244, 0xa6b8d1ac282b9, 0xa91ade0453264, 0xb0241ab25af67,
+    0xbff1af8af2e47, 0xc638ca338a2d3, 0xd2a9a20c3414d, 0xd43f74780ff37,
+    0xe8ac346fca6ba, 0xeaab8563db203, 0x041891f5aa05b, 0x092b5383dffa7,
+    0x0de854e28314d, 0x1575fbb8b6bb6, 0x1a978cf3a243e, 0x27238ea4394d4,
+    0x2b5766c2f8d98, 0x31cada7c0eb3a, 0x3b7cc72967329, 0x3bdbd24b44701,
+    0x3cc62457fa99f, 0x4d52b60773baa, 0x59f5bfd89faf7, 0x60cc51c7298c2,
+    0x6349969b0319d, 0x669d4e327d970, 0x6b600eda91a70, 0x6df2ba93f7048,
+    0x6f10dc2b47fae, 0x7211f16fde39d, 0x74074f1673f4e, 0x74acce4b42366,
+    0x765c9ac91d48d, 0x7c74965b100b9, 0x7e22a4565f1fe, 0x7f496fd6aa9ba,
+    0x8312edd4dfaf3, 0x92e19182f24c5, 0x92eae01e2d0bc, 0x9960e2f75371d,
+    0x9eb39ea727110, 0xa1f9279b3bd83, 0xa61c8b294c58c, 0xabfa6cd385a58,
+    0xaeed696a58585, 0xb5219657d2335, 0xbceb7117c451d, 0xbd84f1a0d4092,
+    0xbf844d8fb5d2a, 0xc3d6b2ece7f6b, 0xc7ac9d630665d, 0xc8c62f76d4548,
+    0xcd6f58de42ed2, 0xce22c12ca00ab, 0xcffe5ba74bfaa, 0xd7fc4b7bafbf9,
+    0xdde140ceab8b0, 0xe4dc22ee09517, 0xe4fb7d21101ac, 0xe53834cc9b8df,
+    0xe886b485626d1, 0xe91b6752f8fb6, 0xea6043a1cacc5, 0xfa1ee434498b7,
+    0xfa59adc914507, 0xfbfbf055c9e6f, 0x0620aa2691fb4, 0x0b96236ff1d9d,
+    0x283bd3b6f044a, 0x2ae48eb112085, 0x2b1051b311d0e, 0x2e4eb108c1ef8,
+    0x322791193b035, 0x339e0cfb0074f, 0x381c6897576df, 0x3bc479b2616ee,
+    0x3d0f2ee936bba, 0x453db32403ce7, 0x455a2db2521cf, 0x481844972e9b8,
+    0x49125435a1c97, 0x53307e1f745a5, 0x57b3fba4dcc78, 0x59dbea6c5defe,
+    0x603d151c96965, 0x621c30454aded, 0x625f8dcd6dff5, 0x640c894a52083,
+    0x67e70343f37f0, 0x7226ac5da2f01, 0x7d29d32df52a4, 0x7ef3d44cd5358,
+    0x8363d55a8224c, 0xa8ce3cf47a0d8, 0xb1f06cd2091e9, 0xb3359d4e733ab,
+    0xb8aceeb8f19d9, 0xb9b2fd032355b, 0xba072e445ee20, 0xbeb6ca4b401b9,
+    0xc300d9ed10057, 0xcbd68240a4d1b, 0xd0ea9f800d082, 0xd1158e3b97e69,
+    0xd425ebf3df9f9, 0xdf2b8beea03bd, 0xdff595e3482c1, 0xe60425927d4ce,
+    0xe730797624559, 0xeb8af2add2363, 0xed1cae5d26f37, 0xf0ba5ab59acd3,
+    0xf7bdac2eed540, 0xf93a9707350a4, 0x02fa4bdda9267, 0x10ff2ca4d1d9a,
+    0x1385fe4bdc481, 0x15cf0d1ad0f08, 0x1a575109d1ce1, 0x1e25a7be511f9,
+    0x27d7946ba99e8, 0x28799ded409c6, 0x2dc3df484e664, 0x33a778ea17f00,
+    0x383f1b426c718, 0x38e0c9448a6b9, 0x3d6a7b316f586, 0x4ba118d30e8ec,
+    0x506aff7096eb0, 0x62bc7b50afb7b, 0x69fb8f3ea588c, 0x6bcdfc0e89f81,
+    0x6e77d82ad8387, 0x75a322c55af90, 0x7ada9afeaef04, 0x7c6a85dec5e1d,
+    0x803a5934eab3d, 0x82d2d2db82783, 0x8303eeaca91da, 0x8fef151dafeab,
+    0x91a40ad67d29f, 0x93277a9ae94eb, 0x942f59b458d28, 0x94cec91b0e2f8,
+    0x9ac03237d40f2, 0xa5435d730a3bd, 0xb01710ff1fa4d, 0xb1ac7cf0b16ab,
+    0xbf89350275341, 0xcaaccc2d299f5, 0xcdbd681d6779a, 0xcf9329b00fedc,
+    0xdc007d31c1208, 0xdea842f4fabb4, 0xdfbfdfa027432, 0xe1cbffdd7b0fb,
+    0xe23d0e7e5ea6f, 0xe25aa685e915d, 0xe2653733c390c, 0xe5289844057af,
+    0xe7507fb9a5eab, 0xf26aa006cb9f1, 0xf87ccdab8bfaf, 0xfa69766e561bb,
+    0x09cd7a7207663, 0x0c071c33e6f97, 0x0c2bf3cf783ac, 0x10095868a3415,
+    0x165cdbe033895, 0x1a03481744125, 0x2dcc56007db86, 0x3085fe524a6e1,
+    0x38fc400488f90, 0x3935d9d365d01, 0x3a19e52bedacc, 0x59c6a48ff9112,
+    0x6c410ec82a408, 0x6dd9d32570678, 0x6e4488c573e40, 0x71bd4b35a4aee,
+    0x7f58176619c35, 0x80ec9cc484d18, 0x8c51184cedfb3, 0x8dc71415d6cde,
+    0x97a24a9ac0b35, 0x9c48945d76d12, 0xa4104dc983351, 0xa5c5ef8671670,
+    0xa84ebde7fdf4e, 0xc21e26b57dc06, 0xc433af2c9ed29, 0xc4b67bc2b1e9a,
+    0xcb55b9cfa844f, 0xd0ebc463dd936, 0xdac0b434992ba, 0xdb06abd79ded0,
+    0xea88986781954, 0xedb83e061e4a0, 0xf0338d715670e, 0xf18c7c4662889,
+    0xf4c99d3263480, 0xfc59f7c20b15a, 0x042babcd732bb, 0x0b080ba92f6f4,
+    0x0e1d0b31abfd8, 0x11e7736bf8251, 0x1247fed86b3f6, 0x1d5026a15baef,
+    0x23183399de5a8, 0x2a9627d92611f, 0x33943371258d0, 0x346148f5ca4bd,
+    0x356cd85176d84, 0x38128f0d7c860, 0x39279546b7c95, 0x39fc12a1b7520,
+    0x3c69f5634cdf7, 0x48be097ae1425, 0x6bf9c67b1e2b8, 0x6c234e8aa5b35,
+    0x74a6b3b3bfa17, 0x7733ecff6b9be, 0x7de582b9009c2, 0x7fc0921ffed83,
+    0x8acc7c77de1df, 0x8cc74c8c2d3c1, 0xa06c02f2b23fc, 0xad1b2c600ffd9,
+    0xb516a30a05607, 0xb89dc008e0351, 0xbcb12f1f1e48e, 0xcad35ebb0a34b,
+    0xdcc1ceb260cc6, 0xde537bbdf4186, 0xe0adb61c89d42, 0xe3d5f78dbc1b5,
+    0xf51c0ffd6caf5, 0xf5e62fe7b6c97, 0xf81d8bbc4d070, 0xfdaff85af71a6,
+    0x01fd9af7812a6, 0x184b976bd5a5a, 0x189187bcf9ae6, 0x1c883e0cf3526,
+    0x20b7745b7e096, 0x255eccf3aed65, 0x25ed739c1cdc7, 0x283986a92d9ad,
+    0x28abf4a433d01, 0x374187720e1a1, 0x3ed0446f9d286, 0x466e9e0e75fbf,
+    0x48ddd1866cb62, 0x561e729d2f83a, 0x58dbceda299e4, 0x6310dc4b2d7f7,
+    0x89aaec8b9075b, 0x8fa12363cf7e5, 0x9e82e23c2b095, 0xa4a14572bf581,
+    0xb2f93a01e7773, 0xb619ca69f7be4, 0xb853bcb07f406, 0xbb8ed5e72ccb7,
+    0xbc84d9c3e9ecf, 0xc38f07ffe989c, 0xc9e58c0ca58b6, 0xca3f6df3909c1,
+    0xcc574000eb604, 0xce558d1a527aa, 0xcf48c747f94b3, 0xd0c4a6f3b6aea,
+    0xd9a4e39e16522, 0xdac4474807040, 0xdb6b4a705b7ea, 0xdd47e173d0502,
+    0xf004378d50347, 0xf120258409a2b, 0xf48d27807204b, 0xf50af6c6d742b,
+    0xf76bf7f277ea9, 0xfb12a2617e94e, 0xfcdad65a1130c, 0xfdbd566d321bb,
+    0x0f5514a6fe0c6, 0x1167a031e3c14, 0x1317d6d2f948c, 0x1ec99bdf1fd3d,
+    0x28f753c67ab8b, 0x2b333f1e93fdf, 0x343193e95aaf4, 0x3bdf2eaa04444,
+    0x3e7b429d36e76, 0x4cd1a495175c2, 0x4fd3bdb457354, 0x57eacfab54e79,
+    0x5873ccfff421f, 0x6588ab14b726e, 0x69eb8c54790f4, 0x7141be58a5f5a,
+    0x752ba1b66c255, 0x76baa25a6c02e, 0x88bf3eda01e34, 0x8b8e9aed11fb5,
+    0x9b6644039821a, 0xa3eb6f011ec68, 0xa8bc4bffe6bef, 0xab27a34a5c1cc,
+    0xac855fdae15d7, 0xb5ea573214c63, 0xb7469ac9e4e2b, 0xc7f5ba7eb592b,
+    0xc8d109a1911c7, 0xc95d66b3c5709, 0xd05e2cb5f7c7c, 0xdc4778e0d6e09,
+    0xdd319f0248b6b, 0xdef5019671cd4, 0xea328b8144b3b, 0xf55f5b5191b48,
+    0xf6b504079b38a, 0xfafb54c36ca8a, 0xfe8658ea99ee9, 0x044ee9a4e9956,
+    0x07d00ccb4daf6, 0x0f618c2612560, 0x0fbaf54601806, 0x2430afab5e6cd,
+    0x2618ba46e414a, 0x332d5dcca1549, 0x355fc3a36a71b, 0x369933ac0c24f,
+    0x3cc9eae64ebc5, 0x3f66f7b959e4b, 0x5350d5255f1bf, 0x5494103927d14,
+    0x55cf5fb4c8c17, 0x55d4645657532, 0x59a85963d45b7, 0x5b6ccacd78212,
+    0x675e06396f5b6, 0x743858f15dbca, 0x841895fb3c3e7, 0x8777bd81d735a,
+    0x9768ef3541621, 0x9a2cfff2949b4, 0x9b37548d8224d, 0x9e3f8fc78cb00,
+    0xa4910f32ba1ff, 0xa83b7c30afa07, 0xaa0bdda2507f1, 0xb1063fa8d1a69,
+    0xc9d7687cc49c9, 0xd391237ac1fe2, 0xdbc24579610e2, 0xdf5e75302f676,
+    0xe7dd9b5a6e3cb, 0xf0dfab62430b9, 0xfba6934e05b8e, 0x07739827bb8f9,
+    0x1e1692d1dfc8a, 0x2628952c7dd45, 0x2aa11b89c1add, 0x2f329f703bd99,
+    0x3837e6f351cac, 0x434cae7150a24, 0x4f3fdb9cf8e70, 0x549bc4f79406d,
+    0x557885c143fc2, 0x569950173804a, 0x5733b7336e73a, 0x5bec54f9d2da1,
+    0x5cc3233ceccd6, 0x5f1cd630c5319, 0x60a3134d38039, 0x61460e5cc6ce1,
+    0x682897a52e7c5, 0x6a2f1d3274ee6, 0x6aee0413b3f73, 0x6ba23cff95a29,
+    0x6ce895f00cf40, 0x7086e351d0ab8, 0x76dc80cb65f57, 0x789108b808635,
+    0x7d38c7ca83490, 0x8949d9d96d8f3, 0x8955720ae200a, 0x8c8072305983d,
+    0x90eee44faf250, 0x9378dece39448, 0x96b135a7b1374, 0x96e5a63b9be18,
+    0x97f9a1484cd20, 0x985ed5d6d57a3, 0x99f9f9bff77d1, 0x9ec588ea69ad9,
+    0xa4fe36b7a51f0, 0xa7ed74687957f, 0xab4ede336d488, 0xacb0a685a895a,
+    0xb2b94ea918c96, 0xba47166fbfaec, 0xc8a625f98b053, 0xdaee8dcd6ed77,
+    0xe208047d1fee5, 0xf91f454368e0f, 0x02b396404694b, 0x10a1f9fa88105,
+    0x13039fd868f24, 0x22bc21f90d5ba, 0x230e9860ad462, 0x2c7f7c16ed6b8,
+    0x33c1526218d4e, 0x34a90dfcddba3, 0x3b176ac7b3351, 0x3b629a496b948,
+    0x3d80453ec9d48, 0x4b4c3f8d175bf, 0x4b7ce297421b1, 0x5779919b4a2ba,
+    0x57c56d21237dc, 0x6fc0aa9c32dbd, 0x75a50637bf822, 0x77a3bd7461119,
+    0x7b8889e3e6e20, 0x8506da43c9a0d, 0x8a44d3c051aa4, 0x9002a08fa1c9c,
+    0x9811b0f8d58d1, 0xa2958f8a0d651, 0xa5c4b8b8bdd73, 0xa9df516d60939,
+    0xaa2fd9be3fcfe, 0xae36e3ae7540c, 0xaf6cc4654d2a3, 0xafe64d5af696c,
+    0xb46eff162245b, 0xbe98b28da7d6c, 0xd354e105f5c73, 0xd64e419879a9b,
+    0xd6c0abea8f82a, 0xdffe8d0ac90d0, 0xe40c6c6bcadb4, 0xe68de543ae6cd,
+    0xed881f07db9ce, 0xf106e64b54375, 0xf2c5443deebc3, 0xf331ce562060b,
+    0xff556053fd434, 0x005c6ecfe8394, 0x0081257b063bc, 0x029daba547a2c,
+    0x061b4a74b307e, 0x06c6e0c97ae68, 0x0ce3cb075a111, 0x0e4de1c316dfc,
+    0x204b584d3873e, 0x258bb8a77d11d, 0x28ce659fc9ba8, 0x38137d3c9f0d1,
+    0x519bf1de5135a, 0x528f962f327b4, 0x649887a3459e5, 0x7304e68f30abb,
+    0x77ddd3bf84046, 0x80bcf2f0a7878, 0x8cc775189c4a4, 0x8e3d5e94d34f6,
+    0x9033fe4e08c5f, 0x906680cb32c20, 0x980090705f67d, 0xa65ce145e5824,
+    0xad3c8740a4596, 0xad837f156258a, 0xba6d6373c96a3, 0xbde08b33ecda9,
+    0xbe02cc5d8cd75, 0xc1493be4c8563, 0xcadbc36448f6e, 0xd05c28dafc543,
+    0xd2a888c3c4a52, 0xd5645d64482a5, 0xd7b76329a8289, 0xdebbe811d8f9a,
+    0xedcf42d437c90, 0xffb054c5ad388, 0x024f8de76f306, 0x0cf59efe8593e,
+    0x108e430c7a7fa, 0x13103102699b3, 0x153ac136ad1d1, 0x19650c823bc89,
+    0x19711d7aac205, 0x1f452cc7ddb26, 0x213bbddd51b7b, 0x2b671638d0c0b,
+    0x2d052fbc4d684, 0x395099eae745c, 0x3c292afa9f971, 0x48f79ff742acf,
+    0x4a1d30b6cf25d, 0x58299dedb63e0, 0x5aebc09448c90, 0x61ad1036aa710,
+    0x61e6dd42ac547, 0x6dc5e94458e13, 0x7093a41c5fdda, 0x70ef741a0b9c8,
+    0x7787220e53368, 0x77d8bcdd9d7e4, 0x828e0b6d9446e, 0x86b4aa1fd6461,
+    0x8c0101d3f8b59, 0x9a9272ea7ac94, 0x9bf84aa75c7f2, 0x9f2393ff9b389,
+    0xa047a47491d4a, 0xa5bbe2fd32b05, 0xaa853e870d58b, 0xb5bd1b7521171,
+    0xbb163b20a5e5f, 0xbbd12fdec1688, 0xc4f7a60d0c4b0, 0xc6c32ec862ebb,
+    0xc9c82ede3bf84, 0xce46af13f68c6, 0xcfd4c1d2eef9a, 0xd679306cb83a6,
+    0xdc3fe55cf9009, 0xee3c66b0326bc, 0xfeef2bac6f0f7, 0x002b705ef8289,
+    0x015ab9dcf9d5f, 0x0cb692ce4932e, 0x1028a0be20df3, 0x1202dbdeaa312,
+    0x19975825aa351, 0x1a7757bc7c055, 0x1ae147b9cc08f, 0x1bc0f6cbf5ea5,
+    0x1e02b3ba31f2c, 0x2aed984a52423, 0x2fd9f6f79003e, 0x308c7160e5b12,
+    0x385e6af624a12, 0x3a79bdb187bde, 0x3d213a41fa226, 0x3e040ad9c2fc3,
+    0x41f844565de1d, 0x6549b517de2d6, 0x655f653031337, 0x6d5a7cb1bd363,
+    0x7a659ab62a4e0, 0x817178d0f477b, 0x90c7fa3560fee, 0x9334b0d9f8fab,
+    0xa5dc1183083ce, 0xa7ec99018b19b, 0xaa7271c8bd02e, 0xad0f26c53f83c,
+    0xb5505a8333e30, 0xc5f60e5546f11, 0xd058ea268e2b8, 0xd0791ff1ea979,
+    0xd1d062911c7eb, 0xd68e67ca69334, 0xddd30072ca34f, 0xe392eaed0fb2b,
+    0xe431f3d97af6f, 0xe84044af97f2e, 0xf128326319968, 0xfab18fe0c312e,
+    0xfe61ebbc5e391, 0x083f1400f9290, 0x088c821e1a258, 0x1e67e4ac2417c,
+    0x215307f780d30, 0x3008800c0f9a3, 0x36f9d9012cdc3, 0x397f26b4b1118,
+    0x3f49d519f6169, 0x53e4671ae4445, 0x5b897c8bf6ef1, 0x5d053b474113b,
+    0x64dd9c81218fb, 0x7bd2f79d43896, 0x8010ad13dbe54, 0x81b0eb942ea3b,
+    0x87d9fcc0204fe, 0x8de387cdc6df0, 0x958b0073a5c1f, 0x9f3efc28322de,
+    0xa25dca64c61a8, 0xa76ef5b2c4089, 0xa93cd2ff25703, 0xa9bdbc7948ee0,
+    0xb0dd5543c4b6f, 0xb50cdc16f75cd, 0xbac0c43c8df41, 0xc034a745b5cbf,
+    0xc663029d0ce3c, 0xce5cdbb4e9875, 0xcee43f20605eb, 0xef3f65da2aab4,
+    0xf3bf95eeafcc4, 0xf44780c4e3fb3, 0x0416923f2169b, 0x1aa99c1a63925,
+    0x1f06fff19b02b, 0x2b7ce014c3d85, 0x326db548141f1, 0x3ad51500eb48b,
+    0x3ffc533895f94, 0x412b6d2262f69, 0x509981bb121d1, 0x53a9b73105dea,
+    0x561850f18d73b, 0x56d1ccb6f3d21, 0x6b2993954d4bd, 0x6b68e904aabc3,
+    0x6b7f7fb02479f, 0x6b86eed85ffc7, 0x71a03777c38fa, 0x77d8aab5f93c1,
+    0x86309f45215b3, 0x937185e5bb02a, 0x95a64b485ffba, 0x9bb1f7a9ec455,
+    0xa050313b1702e, 0xa233dbe74e531, 0xad5588a4326c7, 0xb977d13d8edae,
+    0xbd1ca734675d3, 0xc0fa5ffb2aaef, 0xc7f9dc98dc920, 0xc8e0402b5f91f,
+    0xc99132f83ea9c, 0xd2a981d5048ee, 0xdbb62633a4350, 0xdc1f508e40bfc,
+    0xe0d745f9748fd, 0xea6386c4c6e35, 0xefd6c172be24d, 0xf9470b718f251,
+    0xfd361bb337dde, 0xfd617f8cce465, 0xfe156f45e8bb7, 0x0640eb99a95c0,
+    0x066d325d761fd, 0x095888e5f7e77, 0x0ea01dd97242e, 0x112760f597df0,
+    0x122b295280417, 0x1b22a5038a391, 0x237ae0e21383c, 0x24d8802b15e1f,
+    0x289c3edfd9ffe, 0x2a8eda293a22a, 0x340c9bcc7ed14, 0x3a3ede4c285a6,
+    0x3ab6006415327, 0x3f43eda6e4dbc, 0x5278ab93e1aff, 0x5c5d888a8efc5,
+    0x6b713ecc66cf8, 0x6e094aa6d3c28, 0x715c563a2d4d0, 0x76350c846b3d0,
+    0x764bbcce7780f, 0x7725dc2b651cc, 0x799c443c91947, 0x824eab34cd261,
+    0x8faa4de1291de, 0x9d03f3d302f64, 0x9fd08636fcbd6, 0xace1e746a7c61,
+    0xc2fc401ba5c89, 0xc9b6c19f1bcbd, 0xd78d03687c97f, 0xd94ea77a19b06,
+    0xdfc3e4b08c9d9, 0xe25abd1c1b465, 0xe3bae4334d77d, 0xed3be84ca45db,
+    0xfc701291cebe4, 0xfcc09390cd41f, 0x0187131edfcbd, 0x03a66df30898b,
+    0x105fd037b829f, 0x11e76cae4d99f, 0x14df076c64c5b, 0x1e0b826df777c,
+    0x1fdda9b4050d2, 0x214325f16dbf3, 0x3cab6568e57f4, 0x4ca2d2d5ace3f,
+    0x547aa552ef4fc, 0x59cec05ce58cf, 0x5b2fd5591f2ec, 0x5db768f9ecb9c,
+    0x6357e6f479a0a, 0x6900c94e2a32f, 0x737e981149267, 0x7c5eb3cb358b2,
+    0x7f4fd0ef09010, 0x8a11307770216, 0x8ac1d66897a6a, 0x964c6f4822297,
+    0x9f029c783ced9, 0xa20c03cf450a6, 0xa362dff42cd8c, 0xbb21c8950a801,
+    0xc260cddf3edfe, 0xc471e7c350293, 0xd91e0f7719df5, 0xdc6f42e954e58,
+    0xe5cc43ad8f743, 0xe7db3c3dbb02f, 0xeee2c56407e9f, 0xf20ebe6957f26,
+    0xf3fadfc164bb9, 0xfbafa42072f92, 0x11a51e07ff01b, 0x12458da06d9c9,
+    0x1be9e90abfe6f, 0x1d02ae29f9a42, 0x1f1289a45b8e4, 0x230c6522e4870,
+    0x25de664ba754e, 0x27154734387c3, 0x2c28b11d9f075, 0x3ccd52714709f,
+    0x48d8eac097347, 0x4aa4d2a457354, 0x4fcb38ec9c9f6, 0x576eb47486e72,
+    0x57eb20b7d92ad, 0x654ee23981f42, 0x6595f755c2d5e, 0x71515b81cc0f4,
+    0x7dcfb9a2ab168, 0x90ebd585ea9c4, 0x9110e7b081a29, 0xa83055efd8d7f,
+    0xaa20362de41b0, 0xadb9321261ae4, 0xae2eb69835c70, 0xb2e1bd577d2f4,
+    0xb3e53c819e5b7, 0xbbbaaf7304eb6, 0xc3c2dbc7aae01, 0xcadbdd59506cf,
+    0xd121e1da8c4df, 0xd24c3903b1373, 0xe038d6e985fc1, 0xe0b7bc5746a1d,
+    0xe165112e9a7e9, 0xe6c42709a5abc, 0xe8dbbadf0a4ea, 0xe9ae9aa7f1180,
+    0xf13cba4520a4e, 0xf56358f762a41, 0xf5c51429c3ac5, 0xfe7e36f439152,
+    0xfebb42cd5cd38, 0x03010875808fa, 0x1169962ebb559, 0x16042e216a7bc,
+    0x1af2f0037475a, 0x1cc5fa33b8666, 0x37c7971ec36f0, 0x3c0f13f79270a,
+    0x40393aa9bd810, 0x4aa3142e25104, 0x4b42664d578ac, 0x5ab6e28aa83d4,
+    0x5c9c56b43ca08, 0x74a07ff92d019, 0x7c8e09e0a32c1, 0x94e7d9b693e15,
+    0xa1af66f5b8cc4, 0xa31c4eb26ca48, 0xa495e870e0b24, 0xa5ddaf5f3c12f,
+    0xa649359cc41d4, 0xa974b23227e31, 0xc515dbcb6b2b3, 0xcc33fb9d31cff,
+    0xd0cc9a7e4f330, 0xdf23be6ff2c45, 0xe6acb4d230246, 0xed392d665d096,
+    0xf528a90d843e6, 0x00d3fb7a38288, 0x13b90905bb06d, 0x143c0c81e3869,
+    0x16e04a4534102, 0x1966ec5809ce8, 0x1a6d4b26e3758, 0x22067438e963a,
+    0x26138df737b90, 0x27113b8b36220, 0x29fbcc710470c, 0x2bfe588946a3f,
+    0x2d32d63d0b931, 0x33cb3ed935910, 0x36b732c216da1, 0x3fa07c26cabf6,
+    0x66cbf1c3e0f7c, 0x68dc9689e6b71, 0x69189adf707ef, 0x6beafb309cacf,
+    0x757c2355faafa, 0x780c598de2076, 0x9cc35c826d2ee, 0xa1919bbd632a2,
+    0xb5d65b420caff, 0xb689103a68223, 0xba1bb31e059ab, 0xdfda3d55779e8,
+    0xe0396967c81ad, 0xe2adcd6c91ba7, 0xeb80284f433a8, 0xedaca2f1570e4,
+    0xefcd3fd81f96a, 0xf1144123c77e7, 0xfc8fc4ccc5939, 0xff6efbb915923,
+    0x00319dd762b89, 0x12018b8b17cd6, 0x17508fa6cdab5, 0x197430cbb249a,
+    0x1d727e863b679, 0x2e6c723d4b733, 0x3214bde75b392, 0x380f0081504e3,
+    0x3c4ae528aade6, 0x3cc6672fe60e1, 0x460c0f4ee3c27, 0x4e3e56189fab7,
+    0x4f6ae8343cd57, 0x630047834eedd, 0x6a077c7c0389a, 0x71096e9b33727,
+    0x750b98833decc, 0x769334f9d35cc, 0x8107f7029e0e7, 0x835eb45c1bcdf,
+    0x8b6b2aaa6df06, 0x907e774c51990, 0x921b43c25d702, 0xa2b96b24b2193,
+    0xa45da6c2f972d, 0xa4e6c5080bec0, 0xa83b8f1df174a, 0xacc19bc36a6dc,
+    0xb0fd5f7a51bf2, 0xb4993b0387cd3, 0xbb649ee3d7ad8, 0xbd95acb25ee54,
+    0xbda174780806c, 0xbf8f1271ba507, 0xc1986557076fc, 0xcbc0bd1d5abf2,
+    0xd17e0d7cbe9c0, 0xd6ace31ef8c68, 0xe1cfbbf8da718, 0xe5ce7b287ebd2,
+    0xe7bbe98dfc56c, 0xedc857e94bbd0, 0xee061e6a51df5, 0xeedc6199be1ec,
+    0xf393b9a4926d6, 0xfeafe1a70b562, 0x0195d465952bf, 0x0947564e91324,
+    0x0c4c3573f7000, 0x0cd0b591c5004, 0x1260b7a9c222d, 0x292063c84a131,
+    0x2af5cd8469dfb, 0x2e88f7d2c4afc, 0x5405d0b7edc3a, 0x57980226700e7,
+    0x60cf95409a930, 0x6964015ff1f95, 0x69fa4e16b0eaa, 0x753741f305084,
+    0x811f326cb1df6, 0x9de78e008abc0, 0xa2c3622777482, 0xa2df84df3cef2,
+    0xb25b98871d1b9, 0xb61f5ae4d195d, 0xb8d1e4930ab7e, 0xbe39194362b69,
+    0xcc9fb193fc15b, 0xdcc4958a7e2c2, 0xdec754dfe56bb, 0xe2c83cb5502a8,
+    0xe56adce687f4c, 0xec212314133b8, 0xed79d75a198e3, 0xfe137a33bc448,
+    0x04fae3844ee95, 0x0a40119a0cb04, 0x0c3e69ae44df9, 0x0d30b99fd49c2,
+    0x13c1997530916, 0x16cc1e4574ce9, 0x1b303deee6162, 0x1ee50c0681618,
+    0x3119f762a9b5d, 0x334607e183148, 0x4115a7773c8fa, 0x4446c2659e0c4,
+    0x4daf6da7fed9f, 0x53ae3c1c869a6, 0x5b18f96de9add, 0x606be10901a0c,
+    0x70831a1deafc7, 0x7f238e1e47b04, 0x880d6296a9497, 0x9802e93e90b89,
+    0x9cdc3ce92e2a0, 0xa8c939564a4b7, 0xb0c2ea2bd2f79, 0xc39f37d8b926a,
+    0xc40db7598c31f, 0xc6a5ad3e56fb1, 0xc7dd74ba0eda1, 0xcdeeca6f69ef8,
+    0xcfa97476d70f7, 0xe18edb5cc9c1a, 0xe19ff0f6c8ab1, 0xe9cb7bee4abce,
+    0xf2aae7fb25d29, 0x081660b369069, 0x09a02d21a5a26, 0x0d1734b83acb7,
+    0x0ddcdebd94641, 0x176ea09a618be, 0x1bc89d622329e, 0x2af717019e061,
+    0x2b0f05b559a93, 0x37067cdaae1bb, 0x557820003d9c4, 0x5d8e31c58210b,
+    0x698278c176198, 0x6b2fab246f8b1, 0x7c3b2d3c7a667, 0x8230ca5d4a49f,
+    0x83cbdbf9ba7f4, 0x864ecdca53350, 0x96e7d3439669e, 0xa8c4cacf945bb,
+    0xa9c600637bd5e, 0xb267a246606cf, 0xc62c24550767a, 0xc75159951adcb,
+    0xc83a7832f2bc5, 0xd6d5134b4bf45, 0xdfa7ac65f395b, 0xe22fc01f9dbfa,
+    0xe26fffcb12440, 0xe58e58e99aa6a, 0xe80131a8fd548, 0xf3f10668f1382,
+    0xf5ecfb485d2f4, 0xfd0f616adfa57, 0xfd48e5441a52a, 0xff0fa3ece826a,
+    0x07408061b05cb, 0x0a05cf88b2f51, 0x10afa5956483f, 0x26b3b0f1affef,
+    0x3292fb2b52ad0, 0x3cd2ffc47b21e, 0x46cb1751fd6e9, 0x5236893ba6347,
+    0x56b8ff3d74ab2, 0x59d707d7551ee, 0x6c3fe3ae8b7e8, 0x7404659f56c02,
+    0x8fc147cd253a3, 0x926f160c9700d, 0xa49ed484dccc0, 0xb5febec4389c6,
+    0xb9947489b39c1, 0xba3d4c2a363eb, 0xba75d37aa80a5, 0xc151a842b69a0,
+    0xdbcff4d5e1905, 0xe1b91e2ce75fa, 0xe5434e0d9fbb7, 0xed2956802895e,
+    0xf984bc787598c, 0x01a8e3428d3d0, 0x0da72134ec9ba, 0x0e8899c473901,
+    0x1273dc7c5c5dc, 0x1339917c870b5, 0x14be08c48d269, 0x196409547c0e2,
+    0x1e126e438ea12, 0x369a72f40cc86, 0x56c45cec3d30b, 0x5743ee5e1ec92,
+    0x64f35bd16c348, 0x673936ce1016f, 0x7158d9b097cc0, 0x73f5e683a2f46,
+    0x794be100986e8, 0x801bac22175f1, 0x818c94a5b02ed, 0x837689af06288,
+    0x9011c1869cde6, 0x9f44349b1bf97, 0xaf2e9f817b39c, 0xc1ffbb7737102,
+    0xc3a8d71da9605, 0xc3b3b4a73b6dd, 0xccb37b18d68ab, 0xe57a72675865e,
+    0xe6c8ba98e7d8c, 0xeec802758d831, 0x16464da2ad061, 0x176c990a1be2e,
+    0x227f9ab3ae03a, 0x26fd4df4d4664, 0x2802844fa0d7f, 0x29d391c562a94,
+    0x2c7bb6b105a42, 0x37c0b36d04696, 0x5bfb6765c02fb, 0x5cdea5c9b3dae,
+    0x76e2ab1662a46, 0x8b7b9f853812d, 0x96fe15e68567d, 0xa0a675c0ad060,
+    0xb3448b7771e51, 0xb94a6d94bc243, 0xc2c1d28e305bc, 0xcbc98be9d3f66,
+    0xce0b11f1fa962, 0xdbed267bd598b, 0xf25c8c2a64ca4, 0xfa27a5540647f,
+    0x088693ed5e5f9, 0x0c52ac0675140, 0x181d46597df9e, 0x26ab87469fa3e,
+    0x2c5b933eb4dec, 0x308e16fe22e1f, 0x3df83180abc60, 0x63445c60d905d,
+    0x642a940817b20, 0x6e2e8cf9d5a66, 0x6e40c00d10b03, 0x6f8ca509d3eae,
+    0x72154ed1fcdda, 0x72aab14ca4baf, 0x770a91f73ae9b, 0x7a6f41e1316dd,
+    0x8e801be59dfd4, 0x8ff7fe7366c58, 0x9393020d378d2, 0x9fdeaa73c78bf,
+    0xa16f2b625d465, 0xa65588e54394c, 0xb68fc51d9009c, 0xc0b1efce47922,
+    0xc1d7f5abdf950, 0xca8fe8b0670fe, 0xdd9495f8c7917, 0xe21d948faad2f,
+    0xe49a8535ec157, 0xeab905b40346b, 0xff92a3f187ae9, 0x0b62c2fefbc51,
+    0x0d161b25b0450, 0x145ceec089877, 0x16023cdd3bec8, 0x1668002862a4e,
+    0x1e517aa53266a, 0x215dba4f1245a, 0x2d1d90b71ba43, 0x2d817f8a14349,
+    0x32359c70b7049, 0x4bd84d054ede9, 0x4d3d8eb3b1cba, 0x5e505408b3d5c,
+    0x5f4f8ce219308, 0x7160f50201cc9, 0x7624a7380da93, 0x7fbbe2d474ecb,
+    0x857f01210b307, 0x8a4a7dfecb936, 0x8e19587517e02, 0x919ecce708e08,
+    0x9b2e11f07749f, 0xa2397e962645f, 0xa5de9dbfc5fe8, 0xaa2704d4ac142,
+    0xac9a8d4120110, 0xb3da2c42c395f, 0xb56500349a284, 0xbfc9f25ef6085,
+    0xc2dc5423a0996, 0xcb44588eb7fd1, 0xcf9e5556799b1, 0xd014fafe7a308,
+    0xd13c603634d16, 0xd1b2cb4f2fa1d, 0xd3783598abecc, 0xd3f881b26fe92,
+    0xd86e83ea744ba, 0xd9806c4700f2d, 0xe0bfbe6cece53, 0xe4c05d0f906dc,
+    0xe806627391779, 0xfef8617b0eb3d, 0x075ae87d9b9f8, 0x0905a55f170b5,
+    0x100cda57cba72, 0x1959961f8bda3, 0x20f8b1b827ca5, 0x23845c157c76b,
+    0x29823ca4fcc6d, 0x30c6553481299, 0x31e0e77a08562, 0x45b364eeeffa7,
+    0x5558f5ab8699c, 0x55780dfda6e57, 0x5aaaf6b377d50, 0x66b8a55bdca52,
+    0x73eecbba67219, 0x769dac7843f3a, 0x78f892dafaa21, 0x821ed97510d48,
+    0x97f3edfd0bc0f, 0x99c6ff7f306a5, 0x9de4324eb4c79, 0xa2689db924a51,
+    0xa3d0eaf78460b, 0xa7a86f56cb32d, 0xb1a02b64d47bb, 0xca0b2a7356a09,
+    0xca95ccabef52e, 0xcb1706aababf9, 0xdac0b1ccc8dc9, 0xe2a3a3b483d38,
+    0xeadcd5e4ae43c, 0xef3055122c2be, 0xef9673dccbe81, 0xf266d77375f6a,
+    0xf6a522f88d1b5, 0x052e2fafeb839, 0x19cfc30cea627, 0x1fc82633e03a9,
+    0x30d26d8b2c131, 0x47dc24604f89c, 0x4b8d6e20f2204, 0x536baf94b6d0b,
+    0x68a1dc20b9b7b, 0x6e848b866c2d7, 0x83bd597f316df, 0x84511abed02fa,
+    0x86b2c445a16de, 0x89c1b4000517a, 0x9708404b7d2e8, 0x97f3b37a5fd51,
+    0x9b9dab5a49cb9, 0x9d5c3c8a095cd, 0xa3689c419751d, 0xa56326cc0f960,
+    0xacafaeb588b92, 0xc0a7544aa98da, 0xc0e651e37e568, 0xc98c27ca6cf17,
+    0xce2c40ce96ebf, 0xfb6392616e1e1, 0x0256978c6590a, 0x10e83f88fd0d0,
+    0x12df7ca292050, 0x17a37bb455743, 0x238cc4364430b, 0x24804ee892f02,
+    0x291f30d4ee441, 0x3bd9c11a0171a, 0x5e65fcf433ae9, 0x6406b926b6b6c,
+    0x77b02afc0315e, 0x7a41b1ea4b9a6, 0x7bc1eddc66f92, 0x8688c37b77d8e,
+    0x88c648bcb9812, 0x97a5e2983ef54, 0x9a5363fbf9295, 0x9d007b3c78e85,
+    0xa27f4a72f43ef, 0xac0109342d88c, 0xac4d10a54b2ea, 0xace1f6b006c95,
+    0xb8ed840485dee, 0xbda90518931c7, 0xbdf19a7f69db0, 0xc257b6e35d420,
+    0xc3ba6d1a9fff7, 0xe1ff50b566d5e, 0xe312c0ae6a128, 0xed70893a614a0,
+    0xefb693cb39dc8, 0xfa887a3101d62, 0x03e09e9601ca9, 0x09afb43c75dfe,
+    0x0c09d8a56971c, 0x115e910fbf306, 0x16a484c830703, 0x2aeb151c17c1b,
+    0x2b8e9b3f54401, 0x3c7749c830b23, 0x454f973178344, 0x4c709a50e7b02,
+    0x4f13b6f20bbd0, 0x59758b3fb900f, 0x5dcb2bc11ca3a, 0x6ce40b3de6a3a,
+    0x6ecda870b3f5d, 0x75ed7f7325e01, 0x9a2a1a30c19bf, 0x9a5c39dc91db9,
+    0xa9a2555410c85, 0xa9b06e01d4547, 0xc5372fbced976, 0xc656c6a40355a,
+    0xcca1a03299184, 0xcfb740c465844, 0xd08343ca9f37a, 0xd4a7d71e9da62,
+    0xe0de741f1ad8f, 0xe6e4dda7226fa, 0xeb31baa0f906c, 0xeead9744e8117,
+    0xefe66d961a9f9, 0x0bcd802ea137d, 0x106d3db3522e8, 0x1d3a7f4116fa2,
+    0x22371f41debb2, 0x29f5323c27b82, 0x32a494f64733d, 0x3e07bfc84f30c,
+    0x3f8886ce18436, 0x4afcf6ce53d9d, 0x63b484b8b0fc2, 0x656d6c94a1c1a,
+    0x69f97a64722e0, 0x6eb270015f3bf, 0x77980cccc674f, 0x886c9dd4978b6,
+    0x8ad80019ddfe2, 0x8d08b268ec321, 0x9e1b8db390661, 0xa87f95a1d5322,
+    0xb34fa03d8e4b2, 0xc24eb53c8dc76, 0xc2f10f42ccb42, 0xcb1b0ef4e7d43,
+    0xd8a8e742b6358, 0xdecb38f37dae3, 0xe6fda4569d325, 0xf07998d394719,
+    0xf68486e44dfb0, 0x00de94333714f, 0x051a7c8382017, 0x0c375deb99470,
+    0x2b1d6474d063a, 0x56c05542a022b, 0x5d444887361d7, 0x5e8bfd28dfb09,
+    0x6b2f06fa0ba56, 0x6e8e063e52a52, 0x757973feba9dc, 0x968761fd922a2,
+    0x9b1dd838e91a0, 0x9f49f453b5913, 0xaa90924abd721, 0xb1c0860755c08,
+    0xb4719a65ca1ab, 0xb4a678c5df965, 0xb662cf0326e6d, 0xb79b0b9cea4fd,
+    0xb896b52485e0c, 0xbc07f61fc95b9, 0xc1ba9137ef226, 0xca226d60b28ea,
+    0xca4e96dcfc6ff, 0xd29d339ba30c5, 0xe2df282ef23a1, 0x0186680569f68,
+    0x03c9b0390cf0b, 0x05b9c75d2d9c7, 0x094d3e873fff1, 0x0eeb93dc0672c,
+    0x1192a2a04e05e, 0x20130cc092a16, 0x20871bf6a1f24, 0x23ddb332d4ea4,
+    0x25f05fae2dddf, 0x47f5ad3af4cd8, 0x5eea0bce4de5a, 0x680762f592b8c,
+    0x70c32ad5bad76, 0x7d8a7633f944b, 0x8118ae2d7750a, 0x8bf3790b3f94a,
+    0x9a83be04c416b, 0xaae91fdeb0d2d, 0xb1eb27f382e58, 0xc95fa6b55ba64,
+    0xc9f01e2d07781, 0xd253d89e72ae0, 0xed9fee0c440e7, 0xefcb8d16023f7,
+    0x0040a186daeab, 0x10cbfc1e8564d, 0x21c05c7768ce9, 0x2e6b4e37cc2c3,
+    0x3fb8e81c6a104, 0x56577b855f391, 0x722685cb97a80, 0x9277357619ffd,
+    0xa264ccdce94d8, 0xbfe820d7ce090, 0xd33a81c726610, 0xd929004462b0e,
+    0xd9bfbe703ccfe, 0xdc6d437ce7604, 0xdc954eeac9679, 0xe383d335ff43b,
+    0xe54f9dd23c620, 0xe892730cdd022, 0xedb70885e4a09, 0xf0596e2816a5d,
+    0xf6284ce875527, 0xffe7d97c95773, 0x02a614fad590e, 0x065ab7272c888,
+    0x22630e608a3bf, 0x22876d351f96f, 0x2a76f02e27849, 0x2a90afb120f36,
+    0x4f03c660eaea3, 0x54e3c95f09f18, 0x57ed3bb0e3234, 0x59bfc21f5a18c,
+    0x5a04fb718c19e, 0x80f038b50e868, 0x83495cec48da8, 0x8389e5ca84952,
+    0x8abb90b7c8291, 0x94f0589ef5305, 0xa1ee02a7def61, 0xa3a48af7f3dfb,
+    0xb38fc67bd7add, 0xbd95b12d46acb, 0xc1071e13ce7b4, 0xd137460e2cb7f,
+    0xdf21f986312af, 0xe5f15e2d6602c, 0xea35c47b6720e, 0xf71c1d30f4c4f,
+    0xfc0be69698b55, 0x01d3319558445, 0x02749a0d9f647, 0x031aa0ad2afd8,
+    0x04dad6c0e406b, 0x09b7f44c5106f, 0x14c7d819348b9, 0x17e9e8cbdaaf7,
+    0x1b7fad3517206, 0x1ea1367cffecb, 0x4f058feaa1d66, 0x503bde6da4913,
+    0x5d8c321b534dd, 0x6408e974d2d27, 0x7099fe6ab9dec, 0x899499589232b,
+    0x8b48928896906, 0x8d7ea14fbbfd8, 0x9067c08eb5e0b, 0x9a7888c9b6ed1,
+    0x9b2e719463255, 0xa9b79f3c34cc6, 0xa9e2e5ce48525, 0xacb66aea91595,
+    0xb4a8fd1c8671d, 0xc7e6773f2f98f, 0xc8d58fb57e333, 0xd01340a06109f,
+    0xdbf09cc34309d, 0x014efab6ab537, 0x0a229b54eceb5, 0x100890d9a1f4a,
+    0x109a9ae89570d, 0x16595945dd5cf, 0x2a3642cf3be11, 0x2e1c0475a9da7,
+    0x38a049812bcb3, 0x39d81fa0a51b7, 0x3d683e5f031cf, 0x3fe90789d55f8,
+    0x4ece177c04930, 0x4f13d838f3ebb, 0x557d4da9bdb76, 0x59d0df23ed6d1,
+    0x5d08bd3669798, 0x5e387bd276b0e, 0x6a48238c6d442, 0x8343841cf910f,
+    0x881ddf4b3078e, 0x89ab3eb4273ad, 0x958efb29ca0e1, 0x97fea016dbf5f,
+    0xa44db6dec27fc, 0xb1cdb10dd319f, 0xb83e710d74cd0, 0xc15116966be66,
+    0xc8f9c2ab29139, 0xcbae99988c43f, 0xceeb3a6bb0647, 0xd627725dde170,
+    0xd674bbe19b786, 0xdb771031031a1, 0xde46a32a289ad, 0xe0913243b3201,
+    0xe1695c37ff551, 0xfef1978cefbfc, 0x019f1c999a502, 0x06edc18ce6cdf,
+    0x1066ddb7064b0, 0x1234423c6bcc5, 0x15fb8548289f2, 0x1707c0a7f61e4,
+    0x209028eeb771b, 0x2861dcfa1f87c, 0x2f1c7f6e08c9d, 0x31e55de6d52c0,
+    0x35c2c28000329, 0x405599004195c, 0x551176f3e7975, 0x5ea6b22cdc5f1,
+    0x658253b7c5e26, 0x73ec2e7e7178c, 0x970e543e08ea9, 0x9b05bde40439e,
+    0xb2c67ea600658, 0xb30bbba122c2f, 0xbe08a6b817c4e, 0xc093dfa051439,
+    0xc0ac471b08cd0, 0xc3306c5a7fcd0, 0xdf3128805daa3, 0xe1ffd88f4ccf9,
+    0xe2ce5868e5415, 0xe9259ac674033, 0xf488e688ef3ef, 0xfb6f868dde0e9,
+    0xfc0b6df4aa5a6, 0x04c08154795a7, 0x0c69d5c4671e0, 0x0e65e442659b5,
+    0x0ec5b506f651b, 0x175544c7131dc, 0x1a005f4d10bd5, 0x2407b0aa8312d,
+    0x2563c8570edb9, 0x27ad11b509490, 0x335658c379a27, 0x4a1b824a8c0ab,
+    0x4be58abb4cce9, 0x5a90eae8fd012, 0x6c56b963f2c8b, 0x6f7e8d08fa3e8,
+    0x793a7f507f848, 0x827299d56760a, 0x836073e2069bb, 0x890d82ede0794,
+    0x9561c2f0b92fe, 0x97a1dafafbc06, 0x97b778c69cf8e, 0x9a2c9b1c3958c,
+    0x9b41b74b16c5f, 0xa8bb84648b992, 0xaac92fe746577, 0xb62f9986702f5,
+    0xbac24989e7ecb, 0xd6de4ccf35ce2, 0xd9482ec9e6641, 0xe21e23f932c2e,
+    0xe69e6dac4a6a1, 0xf36cc1b87a412, 0x00fcbb5a2e5d0, 0x0483165f46151,
+    0x13f541b47c5cf, 0x18b84b8f57a33, 0x2ccb30f207c35, 0x2f61ec1613899,
+    0x2fe2e7dce8d4f, 0x3688c0cba5c8a, 0x3784fcb8cfc61, 0x3ae2fbcb5d87f,
+    0x46bf0ae0ceb76, 0x4c805fb007e81, 0x4d9a45f16e21f, 0x4fcacffe285e7,
+    0x54a93a97062f2, 0x5e250a7a99d34, 0x6d47b8ab7b31a, 0x746bc75ae77c1,
+    0x96387b4a8ad27, 0xb6574981330bf, 0xc1a40993059ee, 0xc7856499668b9,
+    0xcc6f76077a3ef, 0xd45c1209e8f92, 0xd9ab16259ed71, 0xdac749a62f1f4,
+    0xe78b2a4c075de, 0xe94c7a300c2b2, 0xf78d3e5c4b676, 0xfa73e470d6e88,
+    0xff7eb315045f8, 0x054df0fdcc6c4, 0x0a94b8fcb2963, 0x0fb8100c0ad57,
+    0x16d0b27547023, 0x1749dfeb776af, 0x2384e7d6140a5, 0x2ed8eecdce285,
+    0x3443ffaba7836, 0x374aedd8413e2, 0x37d7ebf3c5700, 0x47d6068f053aa,
+    0x579494d4d22c3, 0x5922b6378c0ab, 0x5c537cf8553c2, 0x5d4b3baeadff7,
+    0x68cbe140bd88c, 0x69d8daf15dc82, 0x6aa06469b69db, 0x700f37d67ecef,
+    0x73c57794ee85e, 0x8646964d78d3d, 0x8f127395e69e0, 0x90ad4aa3510e5,
+    0x92ffdef395dee, 0x9cc602c08c3fb, 0xa1299aff402fb, 0xa68600c9c7922,
+    0xae22d2cc29d04, 0xbcdbd6899204f, 0xbe90fbd693f44, 0xcfbedc5fc1aee,
+    0xd7a65a220f513, 0xd7b5e0cdb6ec9, 0xd9b00c2fc5d0a, 0xf8e41e367d6b3,
+    0xfa2785358a744, 0x104cb7eb2a27f, 0x105c583564498, 0x17fddaabbcce2,
+    0x25d8c59733282, 0x2884812680a57, 0x29145042fbe0e, 0x3c3010a6c262d,
+    0x3c60d84a50bd1, 0x3fced32691a45, 0x543c4a1d3e242, 0x566cc92f274bb,
+    0x6dd01c615ef92, 0x6e819698fb688, 0x7e9b3ddd82115, 0x86f56080eb519,
+    0x88b7f6208036a, 0x8dc07ed76457f, 0x99e3a30916692, 0xadff6591e61b0,
+    0xc40e9b537b361, 0xd8c4d39c42c83, 0xde58febd78d9b, 0xe9b0ff2a37369,
+    0xeadbcb7167a9d, 0x0439f609cf2e0, 0x04f65173ee073, 0x094836b8438c5,
+    0x18504d76c6357, 0x1b369f5db96b6, 0x1ff091836f5ae, 0x20510aa330a7a,
+    0x2a67df035a3c3, 0x3e48f8e7d267e, 0x3f23fb2ef65f1, 0x4b8741c49a122,
+    0x53e83286eef72, 0x53f3559a57de9, 0x5d13f2e09f454, 0x71619e2f29ce1,
+    0x814d477f386d9, 0x90ccafe9dc9ed, 0x9d8d8156c7b29, 0x9e58d4aff016f,
+    0xaa8e493c60147, 0xb2885ce3427d0, 0xb73dbbdc85088, 0xb9f349c8da408,
+    0xc3a88b38f6c44, 0xc6169293efecd, 0xd60ba074db75a, 0xe031c0f1a6e09,
+    0xe4b1ab7c5527a, 0xe713d51c0304d, 0xe7461d0a273be, 0xfc74864056553,
+    0x0cc25fe0d186f, 0x161cdc7fcc9ea, 0x22c3d8741c19b, 0x28f847427c725,
+    0x338a163f23df0, 0x35480a0e83eed, 0x51be471145887, 0x578af982ed659,
+    0x5930aa70f9871, 0x5e149d6d32e4b, 0x640770deec774, 0x64c799d2cb3b9,
+    0x654a2830e8315, 0x65df8aab900ea, 0x66728d9a5c101, 0x67bdeb2c61f33,
+    0x74de233aa3484, 0x93e18ae54f6b1, 0x9efbdac6a9cf8, 0xaaa75cc79269b,
+    0xc48c5c0ed2b51, 0xc490b4ac40541, 0xdebc9d247d2d7, 0xe70e83ab16b9b,
+    0xe96c80989b4ba, 0xea74f5c089984, 0xeab3adcf87873, 0x052ef62496679,
+    0x074e21648a846, 0x216c2f66f9f2f, 0x23f6b4f931c65, 0x2c0ac65b03bf0,
+    0x2c87dea276f56, 0x2de39726995e0, 0x47e64bbce6fac, 0x600c1179371e8,
+    0x7257679594dcd, 0x7976f5653f90d, 0x7e9ffd1a47547, 0xa0b3c9cf1be8e,
+    0xb87a0f4b83452, 0xbdf72ea3340ee, 0xc207d7b34c2e1, 0xc49c20feca4ae,
+    0xd3c9931aab309, 0xd838fa70e8fab, 0xdc1aa903c02f0, 0xe141d145c8b5b,
+    0xe330388b1ed49, 0xeb6e3876c26dd, 0xee89d2e105947, 0xf455f6960f616,
+    0xfe4f3de97f9c0, 0x02d0792a8f0fd, 0x0951683108f4a, 0x289a9b9375851,
+    0x2d9b1f139f5b1, 0x5e967c36f23db, 0x61f94cade984e, 0x6dbf159c88e57,
+    0x708f499efe43f, 0x9912a68d869d8, 0x9ac69119c989f, 0x9b84cf9fd7fc6,
+    0xb69db2e36e11c, 0xb9bd30cd134d6, 0xbd662129634d6, 0xc75b51c04c66a,
+    0xc8954cdc9bcdc, 0xc8bbe2fab90d3, 0xceaefbf64979b, 0xcf0610852ddd2,
+    0xd0edd1edec4eb, 0xdaff044c27d02, 0xdd8ca0692d870, 0xf0697580d10da,
+    0xf51db700d778c, 0xf7fb07284781d, 0xfc202ce1d45cd, 0xfe7d5588e404a,
+    0xff89da1b78ba0, 0x081ebb58dbaa5, 0x0eff47e6c1392, 0x1960a71662f31,
+    0x1f7b90f0cfa1e, 0x2c988a31ef1c0, 0x2e8b37c8010c5, 0x35e913836f3a3,
+    0x46a7c135c83e3, 0x54cbbdf801996, 0x5cfb1dcb244ef, 0x5da3212532077,
+    0x6b9181368326c, 0x7771b9552d452, 0x79cacee8a627e, 0x7a00e0b799edc,
+    0x7bddc496c651d, 0x7ed707e1c751d, 0x7feba7a0b87c6, 0x81a9907547774,
+    0x825b57889b793, 0x89536ff8e2eeb, 0x9701472bf8536, 0x972003fe9f9b4,
+    0x9ff38afe4eacf, 0xa3efa8c1309f1, 0xa43669afd935a, 0xa573e57a30f55,
+    0xac433b7da45be, 0xc0fcff6f455b8, 0xc5cf67b37445b, 0xd04935afae41b,
+    0xd602cad204010, 0xdcdf47f543271, 0xe2b59986cdb28, 0xf63aac8784b6a,
+    0xfd4aa8b0b6ba5, 0x0e00d865598cb, 0x0fbb52d891fc9, 0x23fce233db18b,
+    0x2481a7db7ff2e, 0x25d073cedc610, 0x29c79099201dc, 0x2bf027bbd1dc8,
+    0x3733ffacb3c8c, 0x47dabb0260cd5, 0x52b59f7ebb978, 0x53a13e98e291d,
+    0x688f29eba7057, 0x6a1829654f6fc, 0x78ed0a50a6718, 0x9208ffd4f80a8,
+    0x939bb80d15a95, 0xb4610cfc261ad, 0xb5591fe017295, 0xb9a0bda95969c,
+    0xccf4903f862d5, 0xd5e010edc1f71, 0xd860e16a74f24, 0xdaddf30129739,
+    0xeaa7e292556de, 0xf51397e0cbddc, 0xf64a9267ef8b4, 0xfaff5400d2955,
+    0x0e1c3586c593f, 0x1d33f3e163174, 0x282ac42f6e1fc, 0x2ac58b152ff27,
+    0x2bb5a3bd37ca9, 0x30b0bc2188f62, 0x4e0182a472a0a, 0x4e35f6e14da73,
+    0x555b3ca888983, 0x7b1ab4c5020c5, 0x7d7c15190c145, 0x8a969186ccd01,
+    0x989c3447b89fd, 0xa2b91fc7dbd18, 0xa3313abfa12ed, 0xa80e07c666403,
+    0xaa97cb5edaf70, 0xb8eab051a36f8, 0xbd731531178be, 0xc587109d475ab,
+    0xc6beea65b1074, 0xc975a0c613749, 0xcc56be774368c, 0xd68e7bf8cb14b,
+    0xe466a4870bd66, 0xfa1cd3fa0b838, 0xffaa88d2de97c, 0x0109fc940f1df,
+    0x0d5e64d93bcc0, 0x1177e3bd92c45, 0x260fa4bd89e88, 0x3e6e8f2aab595,
+    0x4f67de2f7b2ae, 0x581bba777b846, 0x5a803156536fe, 0x5bec6965f5f92,
+    0x6a633f6128fb4, 0x789ff7cbb22b1, 0x8cd1f1d7923a9, 0x8efb73365b0d5,
+    0x9446852eed650, 0x9b7828ca50405, 0xa8144addfe0a3, 0xb26f4268fe382,
+    0xb86ccb21f5e0c, 0xbb67d7ea53f3d, 0xbd050e839a400, 0xbfc935368fa31,
+    0xc02555b8e350d, 0xd5f8c905d521a, 0xda743dab92e73, 0xe724131d1197b,
+    0xe993518fd966d, 0xeb42795b743f3, 0x06eaa7f9b894c, 0x1cdcb37fee125,
+    0x223b03b845c6a, 0x236f2995820e4, 0x238b78388c090, 0x2a23ce8804396,
+    0x2ae331d92f84d, 0x2d1af07f1f7ed, 0x2f7c0ef243093, 0x35d150ec5f4f5,
+    0x52b86200febb3, 0x59caed4a414ad, 0x5a309e48b635a, 0x63ff90980ab6f,
+    0x78829e0877b6a, 0x7b7b38f848204, 0x7d52354baf974, 0x82e65cc3f54c7,
+    0x8583deb50bfed, 0x8a7e476c4bdb6, 0x8d998a00065a8, 0x9844861a18c98,
+    0x9c19ecce6a3d6, 0xabf675ed1b5a4, 0xaf8cc1c11522c, 0xb6e6a4077f11c,
+    0xbc176b696a46c, 0xbdb4935eef21b, 0xbef00b1ce4095, 0xcaf0288242a4e,
+    0xd1cff31665172, 0xd5ed1aeb185f7, 0xd6a0d0152d0f9, 0xd7214bc325bc0,
+    0xda033668e9e1b, 0xef830e831eef0, 0xf0567d08a3c89, 0x294bdf2f17a28,
+    0x2b2d563ab76a9, 0x2ba4b68a9a63f, 0x3645d1a3e3a70, 0x36ee5516cdfe7,
+    0x3ffa720ab04d0, 0x48d9aada66565, 0x556fff00e1b95, 0x573b791876e8c,
+    0x6783cdfe86e9e, 0x74857c7746037, 0x8b7d4d14e602e, 0x8d27988146410,
+    0x9724dcf2ab16d, 0xa98aeb71dac93, 0xb4639ff68e679, 0xc5a9fdf015d58,
+    0xc6e0d786c6443, 0xcce72ec21c0d5, 0xcf74fa7356744, 0xe7505da2826a1,
+    0xe7be436be6504, 0xf746053add180, 0xf78ee77d6b692, 0x03f70a7249b67,
+    0x14a36420f471d, 0x167a8c5fa03c9, 0x16a2ceb397ac9, 0x2cceaaeebf69e,
+    0x360f93f605668, 0x6110cf6fd672e, 0x621a954e25ec4, 0x68e60f2417f67,
+    0x6f5e210472498, 0x820ec8f6db928, 0x89bde4021b045, 0x8dba1f0c7fd8f,
+    0x9bcad63d131ee, 0xa534521cf87a6, 0xae0120f35e113, 0xb4e8d376b7ec4,
+    0xc27b4299ea0de, 0xc31a701fb8ed4, 0xd0dbb17dd8025, 0xd1389b4bcf854,
+    0xd1816eea9c652, 0xd35c6fadd92ff, 0xf63de44ce0efb, 0xfb6cb29d3a619,
+    0xfbdf69cb07cd1, 0x0aab454deb45a, 0x0c7f39ba464a6, 0x12cd76af61831,
+    0x1ac618af6f801, 0x28360c19fbdff, 0x3b2104da3407a, 0x405248ac0b7f4,
+    0x4f031b477514e, 0x53ae69abb9c46, 0x5e2191cb5c131, 0x7838812a37ee0,
+    0x7ddbab8c58435, 0x81367a75859b8, 0x821956081f8a4, 0x8401017b3bd1f,
+    0x8b1baf42bb8f6, 0x900001675e4d2, 0x9a140c187190c, 0xa2b4ec01930b4,
+    0xa3581a4e46e22, 0xb48b3db0f94fc, 0xb58a5942dbc80, 0xbd82c805c4b8a,
+    0xc23d578bda299, 0xc87ac819bdaf1, 0xcca9b8de718c2, 0xed616de6ba57d,
+    0x016f55f9bc9ee, 0x05b2d5b497055, 0x07c90dd8c9668, 0x0d383cc50a9b9,
+    0x134e728290a79, 0x2af9783168b83, 0x2ba69622a72c4, 0x2ffedbb9bd84c,
+    0x3309e44bcebd3, 0x472965ba9ce8f, 0x533004632f96b, 0x7fae270c57fd0,
+    0x83552cfad7ab2, 0x8470bf7218159, 0x8b7a36af25aac, 0x91f58eae82916,
+    0x92b54d7f26e0a, 0xa510d64b4d2d0, 0xbf87792736dbd, 0xcb2aa915ad982,
+    0xd05e452180330, 0xf0d4dee5fed79, 0xfa4a81b3f6b4b, 0x07d3eb6eb54d2,
+    0x084425c923fa4, 0x15a946a4ef619, 0x195730a7fcde5, 0x1d3d2cdd709cb,
+    0x1d6afee6a4524, 0x1f02bf6940df1, 0x25dc2d5392da4, 0x2753f642c91c5,
+    0x29c04a16075bb, 0x361bf1ef3adc3, 0x3b7e81266da95, 0x3ba85268bc676,
+    0x51d61cbaa4d2e, 0x650eb6d523037, 0x676491446a679, 0x781d367a8b3fb,
+    0x80356241d4b61, 0x8746a07da6754, 0x92319e0b37475, 0x959f20207c484,
+    0xbb014f4694d95, 0xc384eb55c4302, 0xc6b58d7d29c67, 0xdd3bb21986ad3,
+    0xead9da5ea07f1, 0xf5242dc4bcf14, 0xf76195be7bb70, 0xfbb3da2b3a9c4,
+    0x056f48b0f2e70, 0x07762d66a2b93, 0x0b4613097958c, 0x1468fd8eeacdc,
+    0x1d791b49b213d, 0x22b57734215df, 0x2f7361aa733e4, 0x3a9b58c476012,
+    0x4f0373430b47c, 0x5854212def011, 0x62dcdfc751cfa, 0x90d8c5382732a,
+    0x952952743ad26, 0x96f0cf6ddb66a, 0x97a6ce2e29c8c, 0x98e2a51488108,
+    0xa07fadfcffb75, 0xa0d1501e2ab7b, 0xa4d9c46353db8, 0xa84e52cf7aa28,
+    0xc437ed36310e1, 0xdb7422df8e248, 0xdea899e2945e9, 0xdeb3fb2df3675,
+    0xed71a0bb90714, 0x06bbe861f25ec, 0x0b5169666125b, 0x14df1bd887e4c,
+    0x15b47fc6ae252, 0x1690405ea4dc9, 0x1ca0a0dd17c91, 0x20ff1732ba44e,
+    0x282bf80d750c2, 0x3141accf59506, 0x33aef2308f5c6, 0x5050bef82bb23,
+    0x7b9462c337b62, 0x98c3c75dc762f, 0x9d6dc50baae5b, 0xa44e170a8aaf8,
+    0xb0b0770d07aae, 0xb216561bca196, 0xb26bf903da114, 0xf0ebd887dfdd2,
+    0xf1370460a7e04, 0xf2dff41bd5dcb, 0xfb7e1550b21b3, 0x068d03e6ad76e,
+    0x06cf479e84d35, 0x09abef6ac4460, 0x190353b967289, 0x364f6d714ae8e,
+    0x5895fadb3f9ab, 0x5a10f3f3d6467, 0x5d20935b4b3f3, 0x63f00a4f31e49,
+    0x71af6fe342190, 0x7936fbf08bc62, 0x7a2d2f617d97b, 0x7ff6dd95095ee,
+    0x9a32a48f767b2, 0xa4837ce1b75bd, 0xc9e592693d66b, 0xd031020651f22,
+    0xd1f9e954e6395, 0xd95bd47afacff, 0xdac9683bcf9ae, 0xdcd7c36b9ba83,
+    0xe0c0c3df2b7c8, 0xe1fda5f214171, 0xe295903aebc7b, 0xfbe19663d9b35,
+    0x01bd864e61f59, 0x0a68a660b5fc2, 0x1e21bc292cd92, 0x333db06b3a6b0,
+    0x337d6ffdd2507, 0x4d8934f7648b5, 0x5073afe790b03, 0x56fde9e054f82,
+    0x5a60712485091, 0x609349b2ad5b0, 0x79433c898aa71, 0x8c616ec5ded27,
+    0x8dfa66604a05d, 0xa43bb0d2de4b9, 0xaacc8cff49e48, 0xacbc11bddc23c,
+    0xacd0cc9f4700e, 0xb3e0e2670b8ac, 0xb809dcfc39098, 0xcc874fbc98aeb,
+    0xcdc4fb1b251e7, 0xd77f7bbbd5f8e, 0xdfc500c5573e8, 0xe6a19ed909a36,
+    0x184980362bd4f, 0x2f7d8c0f6b04f, 0x2fd453c297d5d, 0x5f500b024e228,
+    0x714dce6827493, 0x7888f3dbe9f05, 0x8ef12c43241d0, 0x975383b17c58a,
+    0xad71941a981c6, 0xb0a6126f7f0f1, 0xb13aba4244887, 0xb8626e391cc69,
+    0xc9b7d66e5f8d4, 0xdc0edab1d3e6e, 0xf94d757363403, 0x05b6a73dbc3ca,
+    0x077d5742c89f6, 0x1da109add2764, 0x1e20a27194c75, 0x27829cdc8cd2c,
+    0x33810a6320e17, 0x369e7cee828c6, 0x373b055e9eb5f, 0x3854559186270,
+    0x54e7c4218816c, 0x5fbed01951e0e, 0x6736431565862, 0x6916780e6592b,
+    0x6c2667fa827a5, 0x7bfc2df519076, 0x8e644f246d031, 0x8fdfd6f9a1bf0,
+    0x9e646dcc0fa5c, 0xa11dd09405818, 0xa5cf39c1342a7, 0xa6fafee83d22f,
+    0xae9c7db5a54b4, 0xb0c2efdb80f32, 0xbb4d9539c3b74, 0xc86514bb4915b,
+    0xd727dc31f793d, 0xe3785d4ed1d09, 0xe7f4520d6c351, 0xe9831bcb56a9f,
+    0xf4494cb8274fa, 0xf90f537f3df33, 0x0bd1aac3154ac, 0x12c4c98c9f438,
+    0x29b2cb7627e49, 0x34a12c6a3e2cb, 0x3fd85d5430f86, 0x4f4d3c62db675,
+    0x56fd2462af0aa, 0x672c1c971f596, 0x69772f7276d9e, 0x6aae5d36bf93c,
+    0x719500e49ebfb, 0x788768af36b0d, 0x7fbe52cd0e9b7, 0x859a2cc1f4b3d,
+    0x8669a1d2754e8, 0x97e899692055a, 0x9865b55983e85, 0xa430cc1c7910d,
+    0xae5b53da738c0, 0xb6c6886eeb596, 0xbfb4bcd69b4a3, 0xc6ae6b871ac66,
+    0xc81de263df2a9, 0xdbeb83798c34a, 0xdff4fb995ef2a, 0xeaf1a4cf6d76f,
+    0xee9ed7d388ec1, 0x012594e510f0d, 0x12e7b6c6ba0c1, 0x263aaa1ba0d09,
+    0x300e667d7e1e9, 0x322eab8dbdff7, 0x3630a98a84260, 0x3d73fc77550fe,
+    0x608de9c30d2a0, 0x72b673a21d37b, 0x949644e112abe, 0xa315fbab559c1,
+    0xbb4931ba38b59, 0xccc03666db3ef, 0xd75e5f8eba39a, 0xdaf13d015d772,
+    0xed803d8195e88, 0xf09164858176b, 0xfab8644392e0b, 0x0170c0ca32cd1,
+    0x160688b8982e2, 0x20d3289beb187, 0x2bd1ce8c7bbc3, 0x2d63ed0d2a35e,
+    0x4b7bd68e229d3, 0x5d3ced434165a, 0x5d5897340b265, 0x629c156afe606,
+    0x6c936755cd343, 0x8204353446e8c, 0x8b22819273e4d, 0xa09ead135c45d,
+    0xa1ae707312d62, 0xa2bd92c97988b, 0xaaf9b3421de50, 0xb72e7f735d4c2,
+    0xbd43fe31f1508, 0xc6ebe5451d086, 0xd7db7f346801c, 0xe281b13bf1a41,
+    0xe7d3063fc1eca, 0xf5378d641e2ed, 0xf8462c99d9e9b, 0xf8b86757bb129,
+    0xfe8fc7bec04d2, 0x06081da50a4dc, 0x0c243aee5546d, 0x13da7bef0904e,
+    0x331e5dd42f711, 0x356740b7dfc5c, 0x45a9a31759c4e, 0x5b6377d1c565b,
+    0x6ffeaadc03713, 0x784a3861bce2b, 0x82247606ce42e, 0x847ee74b79675,
+    0x8870ab4696473, 0x8bbb318a58e77, 0x94341100696f9, 0x980ed083e1c05,
+    0xa8d5209b9b533, 0xada2ac808fa32, 0xb44d9162bbe12, 0xd263732e61141,
+    0xd803995265537, 0xd8c38b602eaf1, 0xdd0e4aaf0fe7f, 0xde474599a6113,
+    0xf596ff0c9f5e3, 0x0f8144d654b8e, 0x136d8b68e720c, 0x15d6e9a1cabb7,
+    0x15e67ef133c81, 0x193df1c5bc62d, 0x26410e3c5f8ba, 0x28db600415d45,
+    0x310cf720c06e5, 0x44bf670c9f9e0, 0x456ad51f13853, 0x4ccfecc598293,
+    0x58c24201db278, 0x6f933e4967c63, 0x73f740ab562b4, 0x7521fe4ec52d4,
+    0x8ab19b4c97f74, 0x8b035e5e36367, 0x9b707c3c1607a, 0xab5febc403d9a,
+    0xabed0acffb4a5, 0xb8a076e5247d4, 0xc1b27b64cbb8e, 0xc5648e7112249,
+    0xd17611f517987, 0x0054394117c51, 0x00f7fd9c4a64c, 0x05419f71ef7d4,
+    0x185bc5ec8d9c3, 0x284e7b937e01c, 0x299d68774daeb, 0x2d7e3076fe2b5,
+    0x3831b932883d3, 0x53c46e9e8ef56, 0x600ef23a021b3, 0x6047a575b83a9,
+    0x64336f985e5fd, 0x6b80cf713cc96, 0x7c8a4d7ce4ccb, 0x87d3ec0918673,
+    0xac739a015710b, 0xbaf61328cf993, 0xcc30e3ebb3aaa, 0xcf7ff60a08f64,
+    0xd0a161694cdc8, 0xd1f69c532b8f4, 0xd2b5f85276221, 0xe6fdf6a44182d,
+    0xf691f8ffdd526, 0x040de01b57278, 0x17d875a107630, 0x21beb1f1d74d6,
+    0x25e1daf0e208f, 0x2625e47d261c2, 0x37b8f5ebec22a, 0x3a6397aaeddbe,
+    0x44be166ef2238, 0x48939e13b6d63, 0x6358b2e76b1bb, 0x642b2c3607cc5,
+    0x66b46863bf2b9, 0x69dfcb5a906b3, 0x73219af4fd1f8, 0x863079c2cebbe,
+    0x95066983a06cc, 0x978dead7bc2a3, 0x9ff6bfe0581bb, 0xa266ec38275b2,
+    0xad2590b5399bd, 0xbeb4bafbad310, 0xcaf481fe01882, 0xcf451dddd6992,
+    0xd1dc3bd33c1bd, 0xd6d419135bc8c, 0xe3b0cf0016623, 0xf183175472ef7,
+    0xfa9fbb25b6174, 0xfbd71c2723dd8, 0x02bedd4e3f29d, 0x07d94893b6661,
+    0x12aea4acb5a35, 0x291c482fc87a7, 0x2c44e17783692, 0x774438ecb4005,
+    0x7a31c315cd501, 0x9659f49b0e6bc, 0x9b01582e104da, 0x9e9331c609f0f,
+    0xa1b01ce6ae445, 0xb0fda78668b39, 0xb5a0c8719f205, 0xbf86eecccce0d,
+    0xc32ca7addbbe8, 0xc6f26a6f02b48, 0xd5a440e515e45, 0xd8840ddfe4abc,
+    0xd8c17538816df, 0xdb78c8f9435cb, 0xfbcd49d675fbf, 0x051e693674e2f,
+    0x05617639f0149, 0x1a2f8740d11ff, 0x1ef5487e10e99, 0x1f548e2ef3ec1,
+    0x212d53ffb8762, 0x2a1e6f5e019a6, 0x2d6e730a4eb2a, 0x30260ffdd7d7a,
+    0x328dea4335393, 0x373f23dc2f321, 0x377af4f493ed9, 0x38f5a4da63631,
+    0x3d944c37b8f20, 0x3f097b0c0d933, 0x5ec77bf55c34c, 0x7fb103cdbdad8,
+    0x936da8e7fe545, 0x9743e78bb50ea, 0x9d1379a215669, 0x9e51fcf0071cc,
+    0xa37b7d6c0ac6b, 0xa580188b80e6e, 0xbd2327a7601d7, 0xc8949e6450b2e,
+    0xce2e7a2b3648c, 0xe552f45dfcc87, 0x0210eb2f3f7de, 0x0c8f45060c254,
+    0x103da427252c0, 0x178edc849495a, 0x1cb33ec07727b, 0x20993af5eae61,
+    0x215fa6f5079b4, 0x243f7eeaa777a, 0x271d985dbb55e, 0x30b306d3d52a0,
+    0x330508be8b8e1, 0x5d801b8da4c5f, 0x5e9ecf8a8428d, 0x73aa89cae8740,
+    0x7498a20f7dd06, 0xa401594764e1c, 0xabf31732e5102, 0xc1ec5afb40a78,
+    0xc60731e7d9853, 0xcea62b0c1b0a2, 0xd0e5ad07ded1d, 0xda3df9af32bdb,
+    0xe34fc39fd4345, 0xee9dc96d36df1, 0xf430e5b8f2417, 0xfaa91889bfd35,
+    0x108f1a6965b1c, 0x18547f44674ec, 0x6905002ae9413, 0x6ea4819cad468,
+    0x70dde8cf8714c, 0x777e0274d312d, 0x7ff61c4830221, 0x82cfce7a14f01,
+    0x83e3fcc3eaecf, 0x86b5977263a21, 0x8b75b304c4fc4, 0x90b416a4877ac,
+    0x9e228d6d6e5a2, 0x9ec43f187cb08, 0xb62b797306cf4, 0xb715899ed67b8,
+    0xba09da94fab76, 0xc15a50f8a7047, 0xc71c30db8de90, 0xca15b9b065c2f,
+    0xec353bde31ab5, 0xef91f18c3ef91, 0xf7e0ffc000c32, 0xfb2a772e48b44,
+    0x0f7e6588112df, 0x105ecb992d16f, 0x10fc6dd995049, 0x1255088108d11,
+    0x154b4edfce73c, 0x2151b2f95b5c8, 0x2c9c889d5d9d9, 0x329bdad3b2594,
+    0x3bfd293a89720, 0x4ab9b14eea5b9, 0x50583d89c637f, 0x603d1c63d98f0,
+    0x63faefe3e8639, 0x65e5cb806514f, 0x6bbcba724f21d, 0x712f78b05a20b,
+    0x73e85b5f735d8, 0x7476266f8bc0e, 0x765e88e19a103, 0x76714ab1732a3,
+    0x7d04cf9c81d54, 0x7f3b7472260b3, 0x8e8f287e8be52, 0x98dbabdc4f832,
+    0x9d0eccfc1d07c, 0xab139f1f8449b, 0xb62d888694956, 0xba940b64d2152,
+    0xe3a3872d98a18, 0xea90285cdee46, 0x0db56bf924f25, 0x131b8a8221894,
+    0x1657e2227e738, 0x2d06f8bfe4194, 0x37f5859f3eb52, 0x5a610f3e28436,
+    0x62c42ff824543, 0x771c09232f9b8, 0x8c57579843f6b, 0x934837c665526,
+    0x9ca472e7ec683, 0xc4d94b91f85d8, 0xcf1de7008492b, 0xdd0645e77e3ec,
+    0xdd1b3f00df3d3, 0xe9a2978f60b8b, 0xf1d5ab4db0d33, 0xf8d7e69fa7f24,
+    0xf9a8b7615acea, 0x0eb5423f43a7a, 0x14ad9a6b686ad, 0x17e9b77cbf901,
+    0x1f0473e800bec, 0x310f0a400d59c, 0x5bf25d2dac086, 0x627ee0bcaa025,
+    0x65cf473a50d70, 0x69487024cbbaa, 0x709ae342fa272, 0x733ed42a931e2,
+    0x84b2dbeafa4a3, 0x84d524667aff9, 0x92e5876975fa5, 0xa0f73b22d221d,
+    0xacf8a93e91ea2, 0xb8ce2fbeee4ec, 0xc66473bdc1142, 0xcb6110159878d,
+    0x15bdf3e5f79d1, 0x20614d9aa97d3, 0x29ed11f60f8e1, 0x2a4f42467c205,
+    0x2a570945404a5, 0x3be9bf348d4d0, 0x3fb0f39c88ae9, 0x4d248c4e81022,
+    0x5709cc16880af, 0x5c86bf82f480f, 0x61ec7791a6ff2, 0x7b990ccff8616,
+    0x8feb47a205f1e, 0x9958cf43a159d, 0xafa0815ad72b9, 0xb3d74f13f15c8,
+    0xbec837d63777f, 0xe45b11585b80c, 0xe698d128a2ee0, 0xe839886ff192c,
+    0xe885087651e11, 0xe94eb34290713, 0xec67e6cf17035, 0xed3c97673b986,
+    0xf2e3a8f1ae5f0, 0xfbc6d03b97924, 0x0779832cc58da, 0x10b13ae053ad5,
+    0x11861011dbdd8, 0x13b29d00a17ed, 0x23f01baf002b1, 0x25ee73c3385a6,
+    0x32a38c0e3bbde, 0x351617f1e6d93, 0x36a19f39bf16d, 0x3c601f5f10e1a,
+    0x686b5b46372c6, 0x6ab35ee8a1820, 0x7023aef70f33c, 0x8211e8085062c,
+    0x855e0bde2bc25, 0x9634c58bc34bf, 0x97cf0ddc8fac1, 0xaa0ea72e155dd,
+    0xb5d26fb94242a, 0xba7590a478af6, 0xbda344836459a, 0xc7f6b6f086db3,
+    0xd006526d68526, 0xd361bb0e04cfb, 0xe9238a0459a6e, 0xfd6fcea6e0d91,
+    0x07b8513dbf7f9, 0x09f234e085907, 0x0a803a7fa3b8d, 0x11b8f1c3c912d,
+    0x12bae1ff92f0f, 0x1435f85fac7f3, 0x15dbfd7b50ebe, 0x4aba354e78947,
+    0x5aca1187d83b3, 0x5bc262a3bf6b0, 0x5f0be50cd8711, 0x815279c8addb8,
+    0xa66df2faeeeaf, 0xa75e254189494, 0xba45be442c573, 0xc011115bb1965,
+    0xc6382d1f01dbb, 0xd292c9cbab096, 0xd49989e7f7407, 0xd70c5b557935b,
+    0xdc7028f65b620, 0xfe633c1e068f2, 0x00fc74157113c, 0x0dd500f8f2be4,
+    0x0df12759a8c19, 0x29486383d365c, 0x2a1780bdcb58f, 0x514752a13f8ca,
+    0x6a9c7428432b5, 0x7654be208e24e, 0x7d5675b0b848b, 0x8f12373fa0909,
+    0x903401c74dd6f, 0x9cde1f413c4a7, 0x9d542788dd5e7, 0xa19301ca92935,
+    0xa260ad5db61af, 0xab6a428368963, 0xc3b7a98a604c3, 0xd31287352aceb,
+    0xd9a6938af6d15, 0xe162e459c373e, 0x06271d11dbcc2, 0x080b26e67c7c7,
+    0x0cc2b5d76633c, 0x11a7a55c6872f, 0x12a870cd15781, 0x14fcfa85fbaf7,
+    0x20584c0c8db4d, 0x3fb2f08872328, 0x497bcf60bd730, 0x5484a32dced54,
+    0x60b6084f986a0, 0x694c2b9f9b15d, 0x76e07a35cc746, 0x8930a1b693b80,
+    0x98430e93eb171, 0xaebe13c5cf72b, 0xdd2d0be3eb450, 0xe635fc575d863,
+    0xfdb8a613aba0a, 0x14ebf745086cb, 0x177399899768f, 0x199efa5b5f78a,
+    0x2357fab17a764, 0x33e1a56a5a638, 0x36c2763fd2c52, 0x36d97365969ba,
+    0x3c1d19deddcd2, 0x5818a1cef8689, 0x609e075b84d27, 0x6a45e71ccfd1b,
+    0x6d5eca24ae74f, 0x94ce9d7ba10bb, 0x965c16832a53d, 0x9abf5e3d3654f,
+    0x9d353ee3a5751, 0x9daf2701b841c, 0x9f7bc23b79ede, 0xcb6226870ef75,
+    0xf0e06d6a1c1a7, 0xf2a4f12071adb, 0xf356bbdcb60bf, 0xfec364de2d786,
+    0x01e4288362cbd, 0x100191b5b6474, 0x2b50204df609c, 0x46c0147780264,
+    0x52a01d01f5949, 0x61d3cad733b28, 0x6b2fc417d44ab, 0x87727719d1f1c,
+    0x9778a9387dd54, 0x986f1ae165c82, 0xc08035c02c403, 0xc46b8372e622d,
+    0xccdf0dc2c02a6, 0xddde09c44f240, 0xe6045cdd1d97c, 0xe8de2c5685484,
+    0xea73e17ade446, 0xed572b7ac5081, 0xf335b5802eeb3, 0xf79f9f6de23d5,
+    0xfa21f7870bcdf, 0x097eef33db526, 0x09df35167792c, 0x2ea349352c4fe,
+    0x335f46b925d01, 0x33a60055edae0, 0x36eebd1c533b3, 0x4645aff5daf01,
+    0x485c140551a50, 0x55912b8e61725, 0x877e513886b9f, 0x99a68e3bdf351,
+    0xbfed5ec8fe6ba, 0xd8acbeacf0cce, 0xf224e3575784e, 0x08e4847b0e603,
+    0x1476c368e9ce3, 0x20bd045c917ee, 0x55aba5c5971e3, 0x6170931be0dc0,
+    0x66d9690741f65, 0x72b6db1fc6201, 0x7817a0d99bda2, 0x801ac88cb33d2,
+    0x926e98fdd6d0c, 0x9d83607bd5a84, 0xa47bbacd039b6, 0xb8f3fd00905b2,
+    0xc1e7b9cb9bd8e, 0xc56d52d6f0746, 0xc59247ba04983, 0xd53f10b8c1515,
+    0xd881e99c524dc, 0xdcc50685d2f7c, 0xe317fee9b58be, 0xece5bdca2bc2f,
+    0xf607fff46ca19, 0xf8de4b16db9d3, 0xfd6d2295c25a8, 0x0265ccca7638f,
+    0x062ea9bf5b6ec, 0x2ff0bd38fa8ae, 0x374e86a7b6eb3, 0x462b2e91d216f,
+    0x563cb3581b91f, 0x5766572b3ecfe, 0x6ab74673c2bc5, 0x71085469d4fe9,
+    0x919f86cb09cba, 0x9ad348b28408c, 0xafe94a6dfb98a, 0xce11b723647ce,
+    0xd52c26b2ee190, 0xd95499dd5e403, 0xdb28db2570d78, 0xdfab880d54b6e,
+    0xe09b418cf32ee, 0xf0ce0e9d04216, 0x0515d9b1aa75c, 0x061b43499bf3d,
+    0x2d8648e515619, 0x2fe07bf1ca64b, 0x3ade21b0a1ca9, 0x5086b13cd9d1e,
+    0x6320c9d5aa15c, 0x63cc16f7aabe2, 0x881041cdf53b5, 0x902361a1cf676,
+    0x943b29557b1a3, 0xbfd093db15b9a, 0xc042a9ff93476, 0xc795be271191a,
+    0xd5c7537e31da0, 0xdb03d40204bf4, 0xe029decad1259, 0xe9ae6ae4111ca,
+    0xe9b52a5f3b502, 0xeebe6dbe01d56, 0xf3f95f537d6c9, 0x1034cb6f93889,
+    0x11c191211b256, 0x12ac8f31f241f, 0x2c6aaf284e17a, 0x4715cd92f385a,
+    0x4da48fbd5a1ca, 0x6c4a9fcde3eb2, 0x7e3e1466c9148, 0x96ebaf03ab3d5,
+    0xa5c8aec44f109, 0xa8b840a2bb94b, 0xb6f67205f9e8b, 0xb90e899d2b86d,
+    0xd7eb8abdef1a4, 0xe771b9f59e37a, 0xe8683deb37f81, 0x00943466143f2,
+    0x0e0beecf64c90, 0x0f8eb9e190b3b, 0x194c301c9c32d, 0x1df773860fcd6,
+    0x21e3b2c6c17ca, 0x25a09fb3a9998, 0x377323d94ff2e, 0x42d60cca71723,
+    0x4bce7a0973367, 0x51783f4d5a242, 0x57391b55976e8, 0xa97fc2d58d8ac,
+    0xcd05d7681d8cf, 0xce24423235b99, 0xe666c0b4ca4d2, 0x1c80e1bb020b0,
+    0x200a3d55457cb, 0x25077e5f5d1b7, 0x3345ac19ab132, 0x360ac803889f2,
+    0x591e25684b35d, 0x5e65e29e1988b, 0x6069bf6cbce8a, 0x71ddef6f780c2,
+    0x7f8937827ce4e, 0x8709212a66032, 0x9d716f874259b, 0xb972ccb67014a,
+    0xc38a755d0e935, 0xc8c98c52d2bd2, 0xc9cebf04aed28, 0xd019e56efc27b,
+    0xd6010367be665, 0xdea358a0a4a8b, 0xe97a733c2fe41, 0x02ab0dac4a305,
+    0x041d08ae4e0b8, 0x122ba233ebf33, 0x1b9add5d42445, 0x1e56a35a04584,
+    0x3878b5cc491aa, 0x3c28e96590496, 0x528034751f841, 0x8fd1bfb20bcb8,
+    0xc013717bc39fb, 0xc81a9d9eb0568, 0xd28dd80b0472c, 0xd6636aaa9a3a6,
+    0xd93ab5fec273e, 0xe262d862e786f, 0xf4cfaa0631c92, 0x1318fc3408687,
+    0x2f8b72feeacf9, 0x4646132a03758, 0x48b2eabf0eb02, 0x520e7d85652f9,
+    0x62cf0aaabd708, 0x6b90f4a5b0413, 0x6e54520d01cf1, 0x70db23b40c3d8,
+    0x87f464ddc7abe, 0x8de3c6453a572, 0x95d7eb0e771a0, 0x97136a1e4cba4,
+    0xb04faf0c8d458, 0xfaf71dac648f2, 0x0028e19718a5b, 0x030293c8fd73b,
+    0x13cdc3e8e1fd2, 0x228cd3cb72ba0, 0x25a5f16257224, 0x36bd54de9da55,
+    0x47fcd61577fd4, 0x5309a3578d9e6, 0x5ab2c0e165f94, 0x5e0f9b28d6e22,
+    0x8712b91d75843, 0x9d21acfe2421a, 0xa59ba9ed70ca2, 0xbc6b299357e85,
+    0xd214c0a329e62, 0xdfe9e89c3eee3, 0xf53406be08dd2, 0xfb4b10c203d34,
+    0xff00a82542f3d, 0x0172d1379452b, 0x02b18419bab8f, 0x03047e43279eb,
+    0x146b53e8f1f65, 0x17ad1a4e17e75, 0x1ae3cc4396622, 0x26a32292c321c,
+    0x3d687bae0a3a1, 0x55bfd6027ce99, 0x56e02791750bc, 0x701124d2e9147,
+    0x7e9fab49e1986, 0x96f98d6c841b3, 0x9c6964b3f5e6a, 0xa62c7947ff1c9,
+    0xa82dce4872a93, 0xcab1553ed94bd, 0xdce3add200b7e, 0xea86e20a89d41,
+    0xf1ddb51806983, 0xf274c01f9849c, 0xf815e2cc656ab, 0x082ce8a429ba0,
+    0x103f30889e9fa, 0x1c389d169478f, 0x7a542664a6bfc, 0x7d4d9ceccccc2,
+    0x854e05eb9ef32, 0x8d93db79c827a, 0x90b7f78ab1dc3, 0x934755d333ed8,
+    0x9e557ec67bd05, 0xa44eed19fbfb4, 0xa9e0fe392d0ad, 0xb5db8be94f60d,
+    0xbe42f2f407431, 0xc4d1e85b92e67, 0xc6a901f67d3ff, 0xc81026c0cfc64,
+    0xd1c8b1f8df39e, 0xe8989f6af1297, 0xf3848e8679c82, 0x0993d2ebd0547,
+    0x1a46dd71e3d21, 0x1aa09e685ba3f, 0x2172f8a9eb207, 0x263c5433c5c8d,
+    0x265c89ff2234e, 0x28fd1ed2166e7, 0x3d7f002585dc8, 0x578130fa067e0,
+    0x6801687e4810b, 0x7ae7e7b09f5a9, 0x8d0f332600091, 0x9ed8e17767895,
+    0xb04fc18aa6779, 0xbfe47e8e24ab1, 0xc92e8a4561136, 0xd9b145eee21d1,
+    0xe354e6b152038, 0xe3b8c6e08922a, 0xebe3888c675f4, 0xf258be70f993d,
+    0x015e4d614c69a, 0x05daee2407c0d, 0x0e66b40354fe1, 0x2cb04bbb02775,
+    0x36933e43df71d, 0x45a2e8c401389, 0x52f4eff96ade6, 0x5972117624d81,
+    0x61aad231341aa, 0x6ad1485f7efd2, 0x6fb3d4afb5042, 0x71ae459b9ac22,
+    0x8f3596b47418d, 0xaa70843b94c24, 0xab83a3aff0100, 0xc0c970862d189,
+    0xcebff315bb65b, 0xf3c0795624bc1, 0xf55a2b9872536, 0xfa98843d63bcf,
+    0x032b0997db2db, 0x192358294e9c2, 0x596cf3425d995, 0x5a437bfba0b2b,
+    0x6d02981b468ba, 0x6e993b24a6f81, 0x6fb1afbf38c66, 0x71dae09959aa4,
+    0x7262e16530031, 0x737763dc9e4b2, 0x7df669b78be53, 0x924a7caab7fa0,
+    0x943aa9c47acfa, 0xa6f38681d3140, 0xad26c58a457eb, 0xb2630084418a0,
+    0xc2cedc4f819fb, 0xdbaaccb764795, 0xfe87f00adf52b, 0x20bedd2a99e2f,
+    0x28dc35d5c5e27, 0x303c8712b2161, 0x38d03fdc07d11, 0x565e2f7f981c7,
+    0x5c0815b3f248f, 0x65c4dfeadcd56, 0x6e4d7949ba054, 0x8375aa6f7c42a,
+    0x83e0e3d14cba6, 0x87c87646f87f7, 0x8c0548d35a7ff, 0xb6b64c80f43ee,
+    0xc33c99e2eb679, 0xc4f6aa32e9626, 0xc5b9c518326f1, 0xd64568e2a41f7,
+    0xe1c2ff3bc67de, 0xe6325f40238f6, 0xf41827b52bf6e, 0x0526dd9f87984,
+    0x0612ea85d963f, 0x09f674e2bf78e, 0x25d0222384305, 0x2f925ec8281fd,
+    0x33800fafae3aa, 0x3b86bbb9be528, 0x4d3585bd0f8af, 0x5a5bf5dbbdbbf,
+    0x6eac379c39895, 0x7f3c798defb2a, 0x80f71fec6c764, 0x8ee27816b1235,
+    0xa5cc30068d96a, 0xcc7e0a614894e, 0xcd719c65780cf, 0xd0c1483b3c7db,
+    0xd316a63a979f3, 0xe7bd5f29984e9, 0x06630c68c860a, 0x0a58c62ff9231,
+    0x182e0f198169f, 0x2a8f1ca012e6f, 0x3aaac4480c0b8, 0x4abea4f141061,
+    0x6b465f4a8fa90, 0x784861f0e70dc, 0x79c0c0ee9c18a, 0x7c194f1757a3d,
+    0x839bfec566b6b, 0x8736147a300e0, 0x93381c4d5efb7, 0x9e36aff13dd1a,
+    0xa531d79a72720, 0xa6edebf6d344e, 0xf1a6409c74c7e, 0xf8641e348f889,
+    0x10b7bd4bf41a8, 0x1b3e115ea9f84, 0x3d5f471430c9d, 0x4f2ead5d28871,
+    0x75b5696823f6d, 0x7b9fa53d94d19, 0x82e0690a552f8, 0x9a60c587793ba,
+    0xa0a0ba3a9c382, 0xa231ead643418, 0xa7d4f09efffbb, 0xae948cb9a6ba8,
+    0xc77ac0f3ac7c8, 0xd73d1cc339593, 0xe49d738c7bf3d, 0xe834d587d1241,
+    0x065e28d060c00, 0x082a7e804b923, 0x0af02ccfb78ab, 0x13fbf596018e1,
+    0x236230e33abd0, 0x3513f7d2c752c, 0x3bb511a9cc8eb, 0x489d58761ae0f,
+    0x512ea6b8c3ab2, 0x7cd34eb73eafb, 0x87275e84c0b2b, 0x8e2950a3f09b8,
+    0x8e565d0a70d83, 0x981669b76d9be, 0x9d6f61209e735, 0xa04b9ec9a370f,
+    0xca7095e9b0caa, 0xcd7448f21906c, 0xda9275a216cb2, 0x03b70b74b194c,
+    0x125c1d9c52c18, 0x19ff489f951a6, 0x1d9b710482bb0, 0x30474ee463375,
+    0x43d60bffcdfeb, 0x5c248cd71178c, 0x5ce259e804bd8, 0x634e91b604218,
+    0x7cfe780e292c4, 0x87e1322d0991b, 0x89315d7a889dd, 0x8bf4630b51843,
+    0xa3d9b987f8738, 0xae54ea874569d, 0xb9b259b8cccd7, 0xc50daee84f2f2,
+    0xc949d570903cf, 0xca3af1f341af4, 0xd67997d36989b, 0xe329783fb94f2,
+    0xe849f353496fe, 0xf4b020df86566, 0x066d54154309d, 0x0a4f5e279341f,
+    0x20a4bec9522ac, 0x23bd8bdb8ea42, 0x25338b4d67d32, 0x27e33ca8c9330,
+    0x4e8c02f74f36d, 0x54e020264ea3f, 0x7388ad0a3730d, 0x753441db17e31,
+    0x82ee22b4bce6e, 0xaaf3eaebe5c77, 0xaf13e3c190195, 0xb4bba4f9142ef,
+    0xc6a1eec93d3c8, 0xd1b92bc8ba19c, 0xe13dde5ec3b6a, 0xe1b8e79f03000,
+    0xeaaa70c976f5a, 0x085ec34dff741, 0x089b26cbf29c1, 0x0de1f273c9225,
+    0x11c7d50aaa5a8, 0x25a14c89c1f75, 0x263ca8dce08f4, 0x6bd7dadaa652a,
+    0x741c71ff2027f, 0x80e3e948a2e90, 0x9029466f4f158, 0x90c9d6f830ef3,
+    0x9b83ceaa3d45b, 0x9d97aaeb84275, 0xa30d48ce47a10, 0xa3713ef320ea0,
+    0xa39a1ea777db7, 0xbc032bf648f5d, 0xbdef8f2f3c3ca, 0xd6c08fc0db3b3,
+    0xdb8716a0ce7db, 0xe4687bbf3b568, 0xedaf5af607b17, 0xeecb15af9c135,
+    0xf3e55509cefbd, 0xf4c86ed45f0be, 0xfa24582efa2bb, 0x016782d9771e2,
+    0x2d092a42c6691, 0x2ea6055c93b17, 0x330ab3c2a3093, 0x533eaaa6e0f13,
+    0x56e5934dddbcd, 0x66faf098b837e, 0x6f48145ea9b01, 0x81b444f8a4148,
+    0x95775cb2575c4, 0xa2fcccf811b5d, 0xb351af467e515, 0xbb575ec7c587a,
+    0xc09afa463ba43, 0xd1bc510ffd20c, 0xd39cb9462239b, 0xd495364d4dbd4,
+    0x1431a87a4d317, 0x4a77d5b54bee2, 0x6a46d642f74f4, 0x72abec6594c6e,
+    0x7d46b978cf042, 0xabd572443bb88, 0xb089215eb3b72, 0xb0f74cb1ee774,
+    0xba346bd864e51, 0xc98610dd96d20, 0xd3e986664d319, 0xd938957cd4247,
+    0xdc1e047990ff0, 0xddf3006985fa4, 0xdf09dec3dfc1e, 0xec280b73dd864,
+    0x1986e4b2c9c47, 0x20d80371a6a7e, 0x31b5ea6693366, 0x4a08d627f61d0,
+    0x6566c32989837, 0x67d33f3f1bba4, 0x6a3c57ee287b0, 0x80885ef9db8f7,
+    0x841ecbbe4896c, 0x86e4ad4ad99ba, 0xb51d6b57adb0a, 0xb87d1a4905ff6,
+    0xc075b4f73343c, 0xc28ff8dd1bb16, 0xc382920172a43, 0xcad81baa6ef43,
+    0xe10b2c7ecc6f0, 0xe79ea2c619a8d, 0xf6d84e1cbeddb, 0x08864ed46c40f,
+    0x0c1189e1aeef9, 0x10150f7aebab9, 0x101c391950542, 0x1040f7164f0f4,
+    0x14d2ae39ee476, 0x1d04a0d611e53, 0x2845ab6eed243, 0x2891e2743f7a2,
+    0x30d9ad6b0a157, 0x389bff642e2b4, 0x5139f264f579e, 0x5bef18b2984b1,
+    0x63d91a9a25646, 0x6aa1dd0db2eb3, 0x76026a1be8eaf, 0x98b9fed4e07b6,
+    0x9f67608a6b77c, 0xab27d7fbc4b41, 0xb4981356d4431, 0xc4c5cd219532a,
+    0xcc0f6a6b84c60, 0xd11e2789e566f, 0x152f22f634197, 0x2313b4536dda6,
+    0x47a6d4e34fe98, 0x4ee77f117dc14, 0x6a41d1c67648f, 0x73ebf02329e54,
+    0x7894e9f663cdd, 0x799cd061b40a4, 0x8539e93031297, 0x8d7cd075e071e,
+    0xb071033bd0ef5, 0xc7ad9bb687c23, 0xca488e878de8a, 0xd2c15807fc46e,
+    0xd391e33fd8495, 0xd498d0cb50008, 0xe145fd7e2b9ee, 0xe311ae7bd6370,
+    0x00a7860e9deb3, 0x1676195364c57, 0x1e49f25ba2f26, 0x26c37a2ce410e,
+    0x655e15bcac2d2, 0x6864e2f8d2a91, 0x6ffac5ebd603a, 0x79d5e67b1dbf3,
+    0x89fa9e865b81e, 0xab1d81f5a3b06, 0xcc39bf8854319, 0xd3fea44a792fa,
+    0xdbb93de89a8cb, 0xe3f72f307cb4b, 0xf93f0b0dedaa4, 0xff6d1d327d8bd,
+    0x09bf881c0616e, 0x1519e0c2bf970, 0x1aaf42fbc44f1, 0x1dbec172c6090,
+    0x24b39b15eba39, 0x2a8e0363fd506, 0x3bbeef7d27d99, 0x3c0f5a8684336,
+    0x80a0d549bbeea, 0x84eff487eb7f2, 0x8f09ee16a4687, 0x93ec9f003e0a9,
+    0x9537d7f8e0529, 0x96ec79841546a, 0xb270b719ef129, 0xb79d641662c9e,
+    0xd3df8109e1a82, 0xd80de311f7750, 0xf3e71b34b0a27, 0xf5b061abae49c,
+    0xf94af77954400, 0x06cdbb577b2b2, 0x166a0fc588d89, 0x19ef584c35853,
+    0x1b085ba36563b, 0x292c252879b28, 0x4baba05d2a48b, 0x65eb2d8f76977,
+    0x6f65dff9ce1b3, 0x7ed77197953df, 0x826f53abc70d2, 0xab063bb5cc55e,
+    0xb0e33e1ebfa39, 0xc97582f87056e, 0xd145628baa44f, 0xd2a417fc080ae,
+    0xd4cebe25edb6a, 0xdb8ce899c009e, 0xe28acef739e64, 0xe8150a73e55a9,
+    0x03d9b749685af, 0x1983f30b7a92d, 0x280376989876a, 0x2dbb8b7058592,
+    0x503d1cfe1d94f, 0x680ab45b3d913, 0x7d6cc882a3dbe, 0x84746b4783491,
+    0x88415ef8efa04, 0xbbe5d295dd4a6, 0xcf86ca1663d43, 0xe091a3d33e193,
+    0x023857b342f8f, 0x0395683fa746f, 0x0e769e6b01734, 0x1270c6c541fe9,
+    0x18aaffd7e461c, 0x1ec8df4cabb54, 0x2469942d4e04d, 0x3f51ee054be14,
+    0x4d07a22cc799d, 0x6d57e0622ec3f, 0x7a7f580476eb7, 0x860fbb284378d,
+    0x8660fe2105191, 0x955d99f596334, 0x9fcefc40cbcb3, 0xd059fa704c659,
+    0xd9febc54e8c8b, 0xe5507d5f59910, 0xe5c83585c531e, 0xec78140eaeeca,
+    0xf7b7c2f657e9f, 0xf9fddc2af1edb, 0xfd5ce66a0a026, 0x212fcbb95b7fa,
+    0x2a80d17ac7e07, 0x31ae4c0cf1ccd, 0x32ff619687ecf, 0x4599384e71b33,
+    0x6ad6e7af81aa7, 0x85119130b2a65, 0x96f770dda13ed, 0xb537c14bf4b14,
+    0xce43c25873dd8, 0xcf65bc7455d3f, 0xd31f8089be3fc, 0xdb6d664972d48,
+    0x03f0eb7a4f258, 0x37999e1617e87, 0x65027cef30edb, 0x70c4367329e58,
+    0x730d578ed05b8, 0x7a3c7704f3bfd, 0x9567b9470932c, 0x9684ab186c3b3,
+    0x98066afe0dd31, 0x9afb88e8c6407, 0xc8a5bd94af31d, 0xc9443b6d6cc23,
+    0xf8a5abbf6c488, 0x02493dde1abdb, 0x0d9d203c71409, 0x2162978200643,
+    0x351102fe0a401, 0x3cc7f754bfa97, 0x5f475198fd00d, 0x6d915965a5e79,
+    0x6e4056c9e3989, 0x7a5d79d13e368, 0x7fa99a9f4b3d5, 0x82e93fb08b73c,
+    0x94e4e1c27edfe, 0x9abe28ee64100, 0xc11e980e09769, 0xdaa0b005eb281,
+    0xe9e64b648d75e, 0x1235eebe1d2cd, 0x15efba2566514, 0x1707da925fd46,
+    0x1ead73c53f7a6, 0x5266eb765f37e, 0x5c5078e102cac, 0x95d9cf843a72c,
+    0x9648181ef8156, 0x9e451a0e546a0, 0xaee82521c465f, 0xbc976300dd214,
+    0xc1ac92beb0632, 0xc2e983755a6ef, 0xc6c14d5e781b0, 0xc76bb04461af6,
+    0xcbf010b40077f, 0xd7eda9f41f9c8, 0xdc924ed2dc426, 0xe666212a5bba4,
+    0xfc1dac4e8da91, 0xfea9829726a93, 0x006145a7fa95b, 0x01c277ebb71a0,
+    0x0b503caa8fa6a, 0x11cc73eb328c5, 0x21ceee75c0ae9, 0x24d63bcac3c97,
+    0x2903e32af7326, 0x321ce1b4ce668, 0x416b1c019a24c, 0x676f92e13fd50,
+    0x8bb3e9a2cea5f, 0x908cfb6c8599c, 0x96a61bc995358, 0x97ad1f4aaf169,
+    0x9fad37c4d94eb, 0xa45ddef844db1, 0xab5614b00f331, 0xd806a3e0bf6b9,
+    0xda3513eb74a62, 0xe788598a8dab2, 0xf12c1b3d70d06, 0x06ad7af41c732,
+    0x202d2c0e41902, 0x3add6c61f8725, 0x3e0330ffcbfb2, 0x6866d18b15d28,
+    0x70072b8d6121d, 0x73a519c6bb793, 0x847b953c5ce18, 0x90184b3980444,
+    0xa11d202312b9b, 0xc00986ff0aa9b, 0xcaae2dc12d5a4, 0xd3603939efe81,
+    0xd405fdf895038, 0xd4427b151ab1b, 0xf450425585730, 0x069375f3a6038,
+    0x1b15b2c68e756, 0x1ba4d5dee8be2, 0x1d15d45823b7c, 0x3cf023e47c541,
+    0x4ca6d8ded34e1, 0x557b934d60aa0, 0x599e8cb836b58, 0x5cf6a7e7efe6a,
+    0x60567077dabb9, 0x8c6ccbc979917, 0x95e7d9b34a190, 0xa58a872237e13,
+    0xd422f15a39117, 0xec68189b70b72, 0x190eb82796527, 0x2d5e294a8d920,
+    0x3476fef0eecb2, 0x3e56e5e9cef71, 0x4982c42c242b4, 0x531535b602a21,
+    0x7caa68b465d54, 0x8dce43a366c8d, 0xa83f447d62648, 0xbe7b70afd5926,
+    0xdbbdce0053c1e, 0xfe7c9afb716c2, 0x4567030fce12b, 0x497e2d631a441,
+    0x5a63713390878, 0x72fe88388fb7a, 0x7674fd6996743, 0x7f0c5f23487f3,
+    0x81177f2ee30de, 0x85fe04f41d53c, 0x924c38d1cd823, 0xa308192c1fd8f,
+    0xa875c07bea789, 0xb447befc5dcc0, 0xc8e8263c5f194, 0xcc4eb59954da5,
+    0xcf92c93da4d9a, 0xd2eea6fc4ce0f, 0xdd882e53f7066, 0xe7d90df8189fb,
+    0xeb91ce32d7715, 0xecbb8452ac7cd, 0xf82c8248a12bf, 0xfb1aad7b0a597,
+    0xfc1723f73a1be, 0x06709b37a46d0, 0x09869e9aca957, 0x1bfef622d77b8,
+    0x1c280cbd43d5a, 0x2cefd976a2e90, 0x345bc68df3ea6, 0x3e67b9bb9b152,
+    0x50e12b13f3bf4, 0x7ae80dbc8a677, 0x9fd82a6715c71, 0xb06a2e84484ad,
+    0xbbec7ca341a86, 0xe17e2db1587be, 0xea6e1949b3b23, 0x03bb3599fd059,
+    0x10b06ca001aea, 0x2d865d1fd11c2, 0x48f9174f812d4, 0x55bfe294e2fba,
+    0x6edbd8193494a, 0x73ae09774e162, 0x9d71db7379306, 0x9d884d858f530,
+    0xabd52da50ffbf, 0xc56dacb436bb2, 0xc83fefbde006a, 0xcc642b3b55cda,
+    0xd422b6fc9ab0f, 0xd9aa71fcf70a9, 0xe016b11cd7273, 0xe858823b2b07e,
+    0xf6eefb9c2c099, 0x0fbe86de06404, 0x15a4c595827fd, 0x1c733c57afe75,
+    0x2b5ff60120625, 0x362b995baa6b1, 0x36b0b330e7907, 0x5405941948b8a,
+    0x71f9226e9c214, 0x77e7c92e2c689, 0xa87a661a1d358, 0xb4111e95d9815,
+    0xd1961022d6fc2, 0xef4c162f1a63c, 0x09feb265bcc58, 0x1d18ca3c99733,
+    0x240bb2200e034, 0x265c21737cbcf, 0x3aab1d7868728, 0x67e9e5855bdfc,
+    0x6e31afdb04778, 0x6e9a4f21f34e6, 0x972f038b610f0, 0xce1444ad9c24e,
+    0xd1c3f82e06b4b, 0xdfc6bef32a65f, 0xe2d9922cf024b, 0xe954a4a276316,
+    0xf61b05c49d8ab, 0xff5c8c2c4308c, 0x07b46c8b534fa, 0x0a5e2f090f09d,
+    0x32837d5e83077, 0x329c10c47ee4a, 0x3ecc33d3a8bde, 0x563069f016c6b,
+    0x6311419429967, 0x674ae7a01b899, 0x95a04fcf729d2, 0x9e7c4629166f3,
+    0xad2e53853f07b, 0xb57fbd9bec515, 0xbea6d12a96b54, 0xd75030718e219,
+    0xe6e432cd29f12, 0xef8e440a03489, 0xf8a044e0ba27e, 0x06b3c5c063bec,
+    0x0a7f825a016f6, 0x19c0574f0b4cd, 0x37743c0768f9e, 0x3e72d963d4dde,
+    0x8b229e6030654, 0x95b65f1c88dc7, 0x96ad040295dbb, 0xa35abf720f8a4,
+    0xb88c8c0ec419a, 0xc42787324bda9, 0xc57a15b4971ee, 0xf0e99d72162a3,
+    0x06d105fdbf5f4, 0x0e4af5cd31c2e, 0x150c7c55a8d39, 0x264ca23ec3659,
+    0x28d2c08fcc28b, 0x28dbb3ab8de45, 0x39c669e9bd8ad, 0x4c370916c7b82,
+    0x5d3810769a427, 0x6f4fbba1c0cf6, 0x7e93da5ebd9cb, 0x9d13127043ae2,
+    0x9f54fef2b466a, 0xc83148913fa7f, 0xdf60d38abd418, 0xe4e748cf89835,
+    0xe7ebd01e66a99, 0x04c13cdc691bd, 0x1856e1b5520e2, 0x185da1307c41a,
+    0x35a9afed8eed0, 0x4ed90bf3f9da1, 0x5563a51527822, 0x7854154c29296,
+    0x7a826f613c3a1, 0x8523364ced31f, 0x8bb6cd84adaa9, 0xa7cf91fcd9b11,
+    0xc6b093e4823c0, 0xdf80e1201f8f4, 0xefb4861f95c83, 0x003966c5ece8c,
+    0x37cfd8ecfd37c, 0x3a756014ce357, 0x4d780950cbdef, 0x4dcac19952471,
+    0x6ee181c378504, 0xb1783b16225ea, 0xb1d06d1e42e27, 0xb797380425d28,
+    0xc49c672aed44a, 0xceee7a3ded283, 0x574920a950504, 0x6234342c834c3,
+    0x73991bbb8cf5a, 0x85718ebed8f4b, 0x92e110b44a26e, 0x98fa9edd84940,
+    0xa2c163b3cad29, 0xb9cd0c489f53c, 0xc886c35c0933c, 0xdfa334af250bd,
+    0x06c7ead11110b, 0x16aa5b7b871aa, 0x288097354b354, 0x62a1fcbabe290,
+    0x71b9a51fb9827, 0x7ff99c57648d3, 0x807cae774e7e3, 0x95de930a8018d,
+    0xb0c13c3cce70e, 0xb5c0ce2f007a4, 0xbf5e78c1ea026, 0xc4c1637895d35,
+    0xf2d015b415654, 0xfa5c60d916ca2, 0xffbb228689ac2, 0x06377e60902cf,
+    0x12ed3b5dd3ca8, 0x1974ed88683f2, 0x258f299929a9a, 0x3e884f373d35b,
+    0x5b93f9af77c19, 0x6339968b47c3e, 0x6933a5e817cc9, 0x771a9a7a1dc5b,
+    0x7dc0a6a5ffc5c, 0x7f6318c6ea0c5, 0xa6096993f20a6, 0xcbddde72bcd94,
+    0xd2e187c298079, 0xe4141c68ac650, 0xe87fd37cad268, 0xf5100cda3e901,
+    0xfb7bf07aa5a8e, 0x07d8b5cd1549c, 0x1ff0493d0f5b3, 0x29d33bc5ec55b,
+    0x60c7be09f82d0, 0x6d890f8fbfdfb, 0x70a4c74185e8d, 0x841fc25b5e585,
+    0x850050aece38c, 0x9c979badf4aa2, 0xbe8d28000e395, 0xcaa7f6765e105,
+    0xd5eeae0bf8776, 0x2f55dc3b71cce, 0x3298858ace194, 0x353c9eb4bb07b,
+    0x5f9ec9f040173, 0x83cd0dc82225a, 0x94b47de74f389, 0xa8860d772f6c9,
+    0xb12cc9f144bf3, 0xb18ebc09bb302, 0xb31e73acad155, 0xbfb15d1ac249a,
+    0xc12fb5f0ee0f2, 0xc5607032feff4, 0x127fc72e989d6, 0x2377a48c94036,
+    0x3ebb34aace799, 0x732ae95432ba8, 0x77505f9267846, 0x997d93f3ace02,
+    0xc0531d75ebea6, 0xd85379311c605, 0xf64fbc6a3b634, 0x00e2c27eb1768,
+    0x12e5ab768c6db, 0x19c13ab4c4237, 0x3c8caeb6d0ee4, 0x3e5077c5441d9,
+    0x4a3b234a45d46, 0x6632bcc02d45e, 0x87a82c8cb788c, 0x92ef55976d1d8,
+    0x9f50a31b7f0d7, 0x9f638984bbc29, 0xae4c07d841811, 0xaf8678129c723,
+    0xb031cc867dd33, 0xd9310352ca03f, 0xde1d117b5fd6c, 0x192b2feb4588e,
+    0x3057ed8cbc753, 0x3aa8be8d1c9d4, 0x58fb002dc49be, 0x60576a425e5e3,
+    0x64afe6bf8a1f6, 0x6adcdf13ce3ce, 0x7f1f4db05d581, 0x8ec3c49ca8335,
+    0xaa99870c2a1d2, 0xaa9e4d75c28d8, 0xc12df7936f9c6, 0xc94281bc3d7b6,
+    0xd3474b4b7ffd9, 0xd7a85bbc041a4, 0xe7cb2d0261e76, 0xec13f6e8a1b97,
+    0x0ecefdabe0313, 0x4b896b9488f98, 0x512051c113eab, 0x850a9115c2027,
+    0x94e139fa8eeae, 0x96a4695192f51, 0xa214b3f185f8e, 0xa77171929602d,
+    0xb36f8742a16a0, 0xc2fae35e33108, 0xc545134f5435a, 0xc9b977f53fd8d,
+    0xd37562eae4663, 0xe334998be1ee2, 0xfc139583c1965, 0x1a5e177786239,
+    0x22884e0fb6ac5, 0x31ac076d225d8, 0x4c204abd30307, 0x6728987fa3fb5,
+    0x6e5392957ecd0, 0x748b094aeb97e, 0x966d366ccc8ba, 0x9ca40fc1d9d51,
+    0xc6732218f0b94, 0xf102e8779415a, 0x051ce18306b47, 0x0c3fddb407f37,
+    0x3d0c73973ef79, 0x64c9b8b442e72, 0x784141c3d46f5, 0x795b9d2346333,
+    0x94390e76e0ed3, 0xadbf7f0c303db, 0xc3590bae8da4e, 0xc7017bf20105f,
+    0xcea598833b2b7, 0xe1de57371cf72, 0x0421136e61618, 0x118354a5744e0,
+    0x3a0f960bfcf1f, 0x613d887c71e8b, 0x6a609b4437552, 0x77deaeae67f9c,
+    0x7a3e80141ab3b, 0xa105de9be4644, 0xcce8c23971152, 0xd85e0dd2024e5,
+    0xeb5a7059d1aaa, 0x004c55219a5d2, 0x0221beddba29c, 0x08e18e3585f4f,
+    0x0ca3a45d603ea, 0x2a5071c40b10b, 0x30979767736e6, 0x4aa961344d78d,
+    0x5b74de2fe994d, 0x65a7134e15b3d, 0x66e2be492fa7d, 0x685b960de0990,
+    0x69a752c84fdc4, 0x7c72e65ab025b, 0x7db60b78d6b12, 0x7eca4c0f5e7b9,
+    0xa1d2d6e56019b, 0xa2bd00afc24c2, 0xc77650e37781f, 0x5a6f2f1a7b15f,
+    0x62afc1cf1f977, 0x88b4475286b8f, 0x8bf58d9ed00b0, 0xce30057eb32cd,
+    0xd32b54c919c11, 0xe455014e3d77d, 0xfb7c2f3a781e9, 0x0ca0f46590262,
+    0x20b505e53dd7e, 0x27204c25456f4, 0x282c66949faf9, 0x2bb4b70258ce7,
+    0x2fc8f6b61b701, 0x3c723c40a49d2, 0x512a9d2f32a27, 0x6826a922bd5e6,
+    0x6f69784dc14d0, 0x727b1818a8c18, 0x7410c99411615, 0xd508ec0aa1a99,
+    0xf44161a997f81, 0xf4a157078c499, 0xf5e6d8089e549, 0xfd42be5b6b1ba,
+    0x062b4d183c9d0, 0x1a7d71f4a803a, 0x31302dd9799d1, 0x4fe27e76a8736,
+    0x50112fc122280, 0x55741a77cdf8f, 0x57c82f12a8a65, 0x6e7df2de86b20,
+    0x7d47a5bba3b76, 0x9dc47b397f943, 0xaaa609dc846b9, 0xc28d3fcc2a97d,
+    0xc88e124d15305, 0xcccee1f76bcc0, 0xd7daab5ed7d2f, 0xee87661951f92,
+    0xf390f2aadfb4a, 0xff773345c1fee, 0x1162bfef6fbf7, 0x16d785e2cdf2b,
+    0x310e65832f5fc, 0x4a9ebe6b2894d, 0x4fae91b0e49d8, 0x55b786b00c63d,
+    0x59740d22aa67f, 0x5f8afd8812d7e, 0x7ded2153db75b, 0x9d20a49df5001,
+    0xc00086a5b5157, 0xc2b01e6283ef2, 0xcb15b0f50da96, 0xf0bcd3e38161a,
+    0xf2584bfa3bafb, 0xfa87d066c2006, 0x47a29b87c8f32, 0x57d998f2f38d3,
+    0x710ba8b2d2a15, 0x844e65af007b7, 0x9336b46e5189e, 0xb36f21377ff36,
+    0xb410ec8120cff, 0xce497fa93d263, 0xdc08cb9ebad47, 0xe29b36b97dbb7,
+    0xecc583e87271a, 0x032951658d31c, 0x0fd6104c3dfec, 0x1ec6a9cc20327,
+    0x2b1f0f2f417bb, 0x3f3fcb5eceaa5, 0x6b8bef4c9a0fd, 0x78b095edeb2dc,
+    0x89d6f1593b3c0, 0x8ccd724706a3b, 0x9212af0085dbe, 0x9ec8aa35bf9ac,
+    0xa10a9e0a110be, 0xc708f677dc666, 0xd3d63f5781eaa, 0xdb4f329e2b6cb,
+    0xe29a578491958, 0x076d1a5f88a79, 0x09a7bffc11d50, 0x1d33fc9b2d81b,
+    0x21e3b240a1417, 0x334c6b025b325, 0x5f93e5ce1109f, 0x861b21f1e918a,
+    0x8686e2be76e7f, 0x8da29f5b71548, 0x31ff9bd5dabcc, 0x3fb047b2d7875,
+    0x93ed211fd848c, 0xad7d67bb1fb04, 0xbb87c241e27f2, 0xd77251df8113a,
+    0xe3f72d9aa3d0c, 0xecc628bfc0371, 0xf65d1b2960445, 0x03add8fa49760,
+    0x071b2429790e4, 0x141f7f09cb964, 0x23b008093fc5e, 0x2b935cc254794,
+    0x630ee6f25e242, 0x8ea672cbde7e2, 0x94b4099b3b19b, 0xa24cef06d0389,
+    0xd473e1bcf763a, 0x10d4db8adfeca, 0x12daadc224b36, 0x26c6216e61f15,
+    0x2b6f263c6ceed, 0x3a7718618bfcd, 0x5d3af8a1f9aa0, 0x6a65e9a069717,
+    0x7fb4590dc046c, 0x861b4e023af7c, 0x8f063ff3d8b15, 0xa8d56a89625b8,
+    0xaab301be906ae, 0xd75126f5f030e, 0xd982f6be3a853, 0xfed60d04d64c4,
+    0x011a6f08c50a8, 0x291840acf5110, 0x387f7c2be77dd, 0x46ead3943894b,
+    0x4d364a832dd8c, 0x5cb88797b96fe, 0x66c5e8c344a9e, 0x8dd349e8e430c,
+    0x958034fc7c76c, 0xc8f3a2bde5a55, 0xdf8cd9aec394f, 0xfebc80cb5bc2f,
+    0x45617ff951b85, 0x4f663392f210a, 0x5388466aa1647, 0x5ad9f3e61c581,
+    0x5fd40fc1a4a21, 0x7171356e58e0e, 0xbe6571c3234cd, 0xd5ee0a5d170cf,
+    0x07caacd2cbd7a, 0x182c2ed646db1, 0x41a2a50202c66, 0x587162ae26c80,
+    0x9165fb88f6ccc, 0xac3139c9567cf, 0xb0e405f998203, 0xf070af9a097b6,
+    0xf693ce3f65259, 0x03356669bcaed, 0x07d44104374a2, 0x09a508f022418,
+    0x0f7797f2bdf6c, 0x2476151db4589, 0x2c1cbd260eadb, 0x5549498ba2516,
+    0x573b4ec683ab5, 0x5cf667dc5fa3c, 0x91b89de834e42, 0xb08eea889f590,
+    0xc9a778fd7b1fa, 0xd8dc4f46c672e, 0xe0b16a61a45b5, 0xeea0eb9521f75,
+    0xf86036485b9e7, 0xfa0b141a4a491, 0x0a41bd57dc97f, 0x338187fd35984,
+    0x3bcdaf3a5e2ee, 0x3d5653348d956, 0x3f5aa1784c230, 0x57ac38da5d809,
+    0x612c31c72a13a, 0x68de3b1ae3718, 0x9f64368c3b84e, 0xb3c8e9fb5af92,
+    0xbb8e318ed9b3a, 0x034ee42483524, 0x0991d21af14fc, 0x1658c94b9771e,
+    0x23ac24e052a0c, 0x2ca1e5b7c0f69, 0x65fad036e3482, 0x6efb0f6f7a4b5,
+    0x8603c9bbd4e07, 0x965e555e1047b, 0xa5512d795b187, 0xb1e905935cb49,
+    0xb38ced040bc30, 0xba1f50ccedf16, 0xedd3267c1f9bc, 0x240691e7453e7,
+    0x44221da7db40b, 0x5702945dfa5eb, 0x78f07e4ab35f0, 0x7b39403df074e,
+    0x947dc14900b42, 0x9b6c075c406ef, 0xa05176fa1f4d1, 0xc1bda3263fe57,
+    0xe523b181a82ba, 0xe52dafc9f43a1, 0xe9a32e402ba15, 0xec859ca7bcc24,
+    0xf433dfc396eda, 0xfddd2d82c5fc2, 0x0203745e7f53d, 0x03b715b7fb0a0,
+    0x048e93a8264c5, 0x09477e4a42455, 0x11f2fd84ffac0, 0x50e0eebdadb1d,
+    0x8fb632f1cd544, 0x948ecf9d78be1, 0xc42a4b3370492, 0xca24951f4616d,
+    0xe9177d3e72190, 0xeb28d90369dff, 0x0412b9d6bc4e4, 0x0ff9e4adb5ac8,
+    0x1198adde43a31, 0x22510638ace8a, 0x32f12a5583b12, 0x3cf2892c6004a,
+    0x4e98e3d5bc7cb, 0x671ebfe07430c, 0xb9ee90a04ddb2, 0xc8f85394aab4d,
+    0xd2fa39d6445fe, 0xd4a3ce43b2966, 0x07d9dbfe5fbb6, 0x137748fa7525c,
+    0x18ac4f5b3b739, 0x2fae5c791da2c, 0x415086c760734, 0x6ecd3d97adfa4,
+    0x6edac788d3763, 0x95b5d96e6e0d6, 0xa7c273d2dd807, 0xac549519b72da,
+    0xb15008fd815d0, 0xb437785db0b35, 0xbba87511615b5, 0xd31542ca181bf,
+    0xda2ffb8c68ee5, 0xde0deb3941a8c, 0xdff2afb5c4bd0, 0xf01d94d69e46b,
+    0xfa6c9507c0a31, 0xfef9ae041b624, 0x11de55155427d, 0x1342eabf96223,
+    0x267e01ad73112, 0x38f06e00caadd, 0x575f6115d663a, 0x5df05e32b53b6,
+    0x74738d34b77d7, 0x9201f948340b7, 0xa341598e9b249, 0xb9fab3fe23b2b,
+    0xbde75d620fd70, 0xc47bb693936c3, 0xcc0099e23a073, 0xdffde522394b2,
+    0x10967494c01a1, 0x42ecc0f091902, 0x9ac2a4e5d1561, 0xd76f609b00ab0,
+    0xeb84ca22f0422, 0x088c394540118, 0x0bbb11ef4894c, 0x124e58a2611e8,
+    0x2f2315b35241c, 0x670b22a9acd88, 0x7aa3cf69a23d1, 0x91f97f0c21e86,
+    0x9533d9f1fcb33, 0x9b15314f6d439, 0xa1afd4de0f824, 0xaad3ef296ee53,
+    0xaec1d34e1a0c6, 0xbe9ceac5f6bdb, 0xc768b5c1b2ba5, 0xd25e47a60e63a,
+    0xf8e8d88caa772, 0x04fe8170f87da, 0x157b46eaf3290, 0x17e5f23147942,
+    0x3c3fd471c5d00, 0x3fb10320577d4, 0x471938a38abd6, 0x552931834b431,
+    0x612b027064c7d, 0x65a597d4dc8e5, 0x846c1f91a9468, 0xa78d4c7168331,
+    0xbd0443bc8d525, 0xbe35e91d7a7f4, 0xc32c1a27bffba, 0xd10e1c64e930a,
+    0xd1d24276bc902, 0xf471dd8127688, 0x032f621e5133a, 0x0dc0f2e3027f0,
+    0x13a531370c42d, 0x2ef8b97609821, 0x314949b9eb7a9, 0x33c695dfa5c0e,
+    0x57cc8c68bf4a6, 0x5efea1793d536, 0x7d4edb64921da, 0x7e10eec64133d,
+    0x8d2763bc5e430, 0x92e9dd56b44cb, 0x9c8e6fa71bffc, 0xaf1f86806916c,
+    0xc61e671ddb389, 0xcb6b00b2e425b, 0xdaee9978a1fe8, 0xfa2191af0dd50,
+    0x0e12f438f0b8a, 0x26f7ba75126b6, 0x359a571b35926, 0x36d57359b1763,
+    0x432bb3bffca89, 0x8a69075a04c3a, 0x923e5d03e8711, 0x99b8e68ac9f9d,
+    0xa0bbf27a45a6b, 0xa36bcfc0eb5a5, 0xb0270a26b96fe, 0xb2fba9134e3af,
+    0xb778c9eee6311, 0xca08caa0d7e05, 0xd44b3617bce9b, 0xe6c3a73e5c55f,
+    0xedcaf22cb31ba, 0xf98ea4c23dd69, 0xfde1868f5c3d4, 0x07ce2332ecfb0,
+    0x080ebab4ea26e, 0x0f46632394d1c, 0x1d8e5be909cb8, 0x211483b0fc773,
+    0x6a9bd7a7f1075, 0x6ead2cbc2a193, 0x9ba141b75169a, 0xa60af2f965017,
+    0xca13df1cd92fa, 0xe2b55a1d898f7, 0xe396ae13ace8c, 0x13695548e3fdc,
+    0x33518deecb762, 0x33de935c3060a, 0x49d6533105bee, 0x560e7b76e9e37,
+    0x56c43104710f5, 0x5891c8c6fb9d0, 0x5e8e58a01ac06, 0x76523ae7b5e47,
+    0x812563601d999, 0x8907307cbbb78, 0x8b3532bb4620b, 0x9679c1ab1a149,
+    0x9786cda86c218, 0x98f431d50c3c6, 0x9ca5ed0aca009, 0xa00702a825a5f,
+    0xa9c91e5c5656a, 0xd12462bd22640, 0xeefcc73a88aae, 0xef411da28450a,
+    0xf14054f8027f0, 0xf2f13b4629558, 0x0eeb79d1c37cd, 0x137b7d6da7cbc,
+    0x2364bfdff9d6c, 0x39ccf49e43a72, 0x492e7bce96334, 0x58802825a8d8d,
+    0x5c8794e738062, 0x6935ea0e20d9d, 0x7b2540989e293, 0xb4f5d49a6aaa6,
+    0xb5912d4498e60, 0xce147a2f400e2, 0xd41e1edb79237, 0xdaa528aadd01b,
+    0xed4f11221c173, 0xed7b49422769c, 0x0450465098fff, 0x2e2b2b682a1a9,
+    0x2e4ebd482b441, 0x329d9e4e64b34, 0x44d5d71b7053c, 0x57ecd1159e655,
+    0x7ec80042bbdf0, 0xa66788bed1c49, 0xbfe4cf5249f0c, 0xd1d9ae4022cd1,
+    0xdb99cd39d15e5, 0xe4605904f9044, 0x1560fcaac0f63, 0x2448a6b7d1ca9,
+    0x27d88ae72a071, 0xa6ff26384bbbc, 0xb22595b5d7e93, 0xe27c1104bc0bc,
+    0xe361ff793381b, 0xf0b277c045d97, 0x0868d7689c3a3, 0x0b2fc421b791e,
+    0x0cf3b5727eb8a, 0x1260728632444, 0x18bc1fcde072b, 0x2f3ea674b21e6,
+    0x33ae709c49a4f, 0x365d1e1d016aa, 0x3b38ca0199ff5, 0x482a2c2bfe04a,
+    0x558cd0346aad9, 0x5b69f736c1966, 0x7e7a62aa19e4b, 0xb36d67ab5e37f,
+    0xbfcd08f995f75, 0xc233e6b62a775, 0xc273c739359b9, 0xe583e227e5fb0,
+    0xfa06dd4ba12d2, 0x0573655ca55ac, 0x135ff847a90ab, 0x649f4bc1dd570,
+    0x79aeb29b8e4e8, 0x90b4b1dc9403f, 0x9aa4f3c790b56, 0xb6662740ec15d,
+    0xbdfe2f30c5874, 0xe5070f76a377b, 0x0cd227b794197, 0x0ff021adb31bf,
+    0x1128d365820ef, 0x17f1af77a21bf, 0x229c7bfd7fdae, 0x3a1ef9ce89a19,
+    0x4f5bb9ea72685, 0x7c0f3eb57d458, 0x80ae3de95b7bf, 0xb489312167bd5,
+    0xb9389d941446d, 0xc4192b643ddcc, 0xc97920808f090, 0xcc582086c99ef,
+    0xd14d25741189b, 0xe57eaa61e60f3, 0xec27d46a76ab6, 0xeccc745dfeedd,
+    0xef54a1b63b9df, 0xf86fd7899ab68, 0x1948d4dbaaf9b, 0x2018cf915e9a5,
+    0x27a8f6e3e15b9, 0x4f3a12849b69d, 0x5088b635a3e08, 0x83259ee6ba520,
+    0x8b4e9e671c343, 0x92f63ba65eb24, 0xc2eb1cb3550b6, 0x076bceb8456fc,
+    0x203725f615a29, 0x27ddc6ac8f3f1, 0x58e2e03747b28, 0xafbd7d7645a3f,
+    0xbcbaa3bd626e7, 0xcbe7d04f6c7a3, 0xe8ade24d0ba4d, 0xe9c6a3c35505b,
+    0xf0244dc585539, 0xff67e8c0b525a, 0x066e755e392b1, 0x1f1af281df338,
+    0x25ee8b2d1e0f3, 0x2667b4fa5e1ba, 0x3438461e0f636, 0x5990108191cd4,
+    0x643c1b711eeb6, 0x78d79f0004e5c, 0xb35065f013cf8, 0xc8f1c48c0675a,
+    0xc9a8e81771b0c, 0x095ffcb1a0ef2, 0x0dedfc4122660, 0x27ac98a76a7e5,
+    0x295e4841352a0, 0x67affa3c8e3c8, 0x743ddf0914df2, 0x7cfc6cef62f26,
+    0x89710affec0c8, 0xaae0fd63ebd76, 0xb4d2c3426ec1f, 0xc53d1b1a289e8,
+    0xca4ad45ddfa54, 0xd9955e686e5ae, 0xf36664c806e5b, 0xfd8ed62cecbb4,
+    0x0133f5568c73d, 0x3e613f066642c, 0x6826fb7061aee, 0x6f1f13e0a9246,
+    0x75f31e01032dc, 0x766660427e4d2, 0x7eb12f7764fe6, 0x8e454b7193542,
+    0x9ad6591599a7d, 0xacc15aab99b48, 0xdf02cede7d6af, 0xed36ec03cd86a,
+    0x0150ec6120de1, 0x037b545310688, 0x04f872687d2b2, 0x06096ce002620,
+    0x0ed1420faa7c1, 0x18b6c00fa7a63, 0x2c502ec960275, 0x363ac3b79db0b,
+    0x4506092c02607, 0x7da081e7f1333, 0x7f9c3fe147c1a, 0xb935edcdab92d,
+    0xbc6f4881cd1fc, 0xc52700f74ed5a, 0xe473055ab26fa, 0xe5776b17fa538,
+    0xfdb96981b2482, 0xfdc0bb626ae82, 0x18446f687e2c9, 0x222c2facd4501,
+    0x27e4f088b5254, 0x29e40344cfb88, 0x341781c77c4ba, 0x37df698579588,
+    0x4674c56d3e39d, 0x4a8bd2790788b, 0x4d02458505155, 0x5ba7df176399a,
+    0xf9dcd93b5604d, 0x05756286501c5, 0x0eb084f2446ab, 0x1418a787a3d9b,
+    0x19f6f6fe07f7d, 0x7419e6e031016, 0x90e822adee127, 0xa221e0f267187,
+    0xa38cf08dfc6c6, 0xabbcf8bc4fb85, 0xb6b4d08df4b75, 0xbed6d60426a10,
+    0xc7e977e42d5e1, 0xf97f97a32fb38, 0x11f08ce13836b, 0x1280b3d43c19a,
+    0x14f88d8c3cfce, 0x20f6615b61e67, 0x2f348f15afde2, 0x83e6d712e28e7,
+    0x860631e70b5b5, 0x8cd3370264574, 0x935fbe3a52ad8, 0x9e341dca89093,
+    0xa727cbf1d315b, 0xa72f0f2eca447, 0xac61d34b3798e, 0xb86069141f9f0,
+    0xc3f730339d5c1, 0xe99f067812bfa, 0xea46985d054a7, 0xee04ceae6ddb7,
+    0xf46d6c2fd260b, 0x1d9eeaea42c88, 0x2c6e1b2fea45e, 0x3ae9a8f0f4472,
+    0x9ce4a23675253, 0xa9662cd7c439d, 0xabedec63d6189, 0xb5c65939a9ad1,
+    0xc3e8ce5f6c72d, 0xc8aa2c046d888, 0xda26b56b7b428, 0xdcafedf042457,
+    0xdfb84671cfb32, 0xe939d947c4a93, 0xee6d19d41e404, 0x0e89f64b156f4,
+    0x1de5c583d7be6, 0x1e5887ac763ed, 0x1f3e3f3ad84c1, 0x38f573cac59a8,
+    0x3e534793310c3, 0x5badce2670a3d, 0x6dab3d5eb17f5, 0x80fa164e20c62,
+    0xa1d0ca09f7575, 0xabc38c7143237, 0xbafcd14d9e3f9, 0xcb39fbce64a0a,
+    0xe3eeb8b7cab96, 0x16b9737214e07, 0x21640911dd36b, 0x35eab820c5cdc,
+    0x482b84e129c9c, 0x4b9865ed1eecf, 0x5893cdb1afb95, 0x8e4029def6cfd,
+    0x8f42c9c7d1fcf, 0x92bfadef5afe2, 0xb4731b2300cc0, 0xc9c8f9b89323d,
+    0xdee07f6790ecd, 0xe4acd2b0cf69d, 0xf2aaa7211656f, 0xff0e78ca67bde,
+    0x27cbe84ec8d4d, 0x28f88cb717cc6, 0x2d2f7f0995987, 0x2e64c9b1eeb91,
+    0x4bc8769e1518b, 0x6859ade48f240, 0x86a1f88ecbccd, 0x933226f18c217,
+    0x9f1988ae9ae86, 0x9feecbac4de9f, 0xb2e6eb8c51d12, 0xc9ab23856c6cc,
+    0xd7198458b1224, 0xefe3a0d5c2523, 0xf7c6582e77842, 0x2ca7685476eee,
+    0x578235f27eb34, 0x6b52d3f4671aa, 0x7631cb8458a9e, 0x7a067ee2a8727,
+    0xaba0e149763d0, 0xaf00853ffd76d, 0xba4b991bf5d93, 0xd34d9be028f70,
+    0x03d14f80d1aa0, 0x251c754411488, 0x36b9d1d6daf00, 0x53e682b7f675c,
+    0x77a6dd1d8441b, 0x8340ee04f4eea, 0x83d320563c624, 0x926ff2b81d7eb,
+    0x980b4025d7802, 0xb6a55c8573d96, 0xc652e02c12f67, 0xef33024f2f37d,
+    0xfce01b3171dc4, 0x005f9973dc7e5, 0x0e0c0651fe301, 0x102a22bc779dc,
+    0x227478a71c1e3, 0x2f0b20fb2fcc6, 0x573ac56f787ff, 0x7efef99bdb531,
+    0x8d6fad7c43a32, 0xbee952acf7aa1, 0xcd7b9bb2df043, 0x0bcb07c0eec10,
+    0x1779a3f5959b0, 0x602cfe53579db, 0x6680ebee225ac, 0x75d7ab8a85034,
+    0x77d2a03837bc8, 0x8baa25f79e4ed, 0x988cd5bccfa2e, 0xa55e096db7f4c,
+    0xb3bd7bc8dd07a, 0xbc378eadcbda0, 0xdb8fff8918cf9, 0x0451f3962bd94,
+    0x04ea395e7c8db, 0x0889d3cdb115a, 0x0deed4dd718c3, 0x1cfbc7fb2ecf9,
+    0x1f7036fac9842, 0x25714f058af69, 0x25b299dd89cdc, 0x3f5ada275ded5,
+    0x6e7ab2c5a709e, 0x715ce69e3265d, 0x9217bd2c1a29f, 0x982557a46721d,
+    0x9f9dc380534c5, 0xb49f7f786b891, 0xdb9751762b48b, 0xe2a8258ec292d,
+    0xfb0e0a0613fc2, 0x0e61dc9c40bfb, 0x2269d228ad04c, 0x2f7ddf9feb7be,
+    0x36df7dea487ff, 0x37d914c1bfc79, 0x5bb1986a0efba, 0x70c0867cc40cd,
+    0xb486c3dc758d9, 0xc1d4d19cdaf48, 0xc93fe6c4c6af7, 0xdf8dc248a7ebe,
+    0xe13773fd9904e, 0xf4fe1b0916167, 0x16b64afd63f86, 0x6392397609611,
+    0x68c608bf01085, 0x8003dbfa76de1, 0x9c52e1d5cb5a8, 0xa9e3d45757fba,
+    0xb6783a6712b07, 0xbccddf3288b30, 0xbf4c06f0989c1, 0x0b017a6d9a77e,
+    0x28a0f4c93536b, 0x2bb686b740317, 0x4b4f164d8e6c9, 0x512b56bcbe9db,
+    0x69af276932c11, 0x9523a8cd6f492, 0xa94953a8e8df9, 0xcf051f2c15a76,
+    0xde6bde3b1bd19, 0xe36843acddcd9, 0xe817e35caf637, 0xf126a1bd540b8,
+    0x0183a689fdd4d, 0x01ebafc26de2e, 0x05e93f2c24409, 0x061d4ceeb52e6,
+    0x0a032bdca60a4, 0x119e2d0fca7cb, 0x2849762370375, 0x4a25ad15caccc,
+    0x50edef707bb4a, 0x5bea94fd99dca, 0x6b629591e3440, 0x738384e0b9c5f,
+    0x7ba6365b0ca25, 0x91c045fd436e9, 0xa24b576226b27, 0xbf3544728e3cd,
+    0xc4bd6d3f1567d, 0xe1a108a07d901, 0x085d4414de593, 0x17a91b2cdddf4,
+    0x710ce99ec21b0, 0x7114b44676a15, 0x8b973fd87cb07, 0x9143cb228992c,
+    0x9c376ef0248de, 0xc37e713681a47, 0x0603e903e875a, 0x10fffc2b78312,
+    0x6d0e650a7d6d8, 0x7fbf6c255016a, 0x96c99bc16f73a, 0x9c03f7486c420,
+    0xaa159c5e06f84, 0xf2ef942bc6f30, 0xf61df7b7c3ec4, 0xfe44bc45ad8db,
+    0x01f7a741593fd, 0x12d1e545e97e5, 0x32602d612474c, 0x80c1dc86506cd,
+    0x87fb4ac967ce7, 0xac28db4b48319, 0xac644d3b438cf, 0xad3621d7a0038,
+    0xc3b933921f631, 0xcdd89f8e91af7, 0xd16f13a4df6f6, 0xde60672b82037,
+    0xe2775a98b8cc2, 0xeead8d75fb89e, 0x0968e23959e24, 0x3945fcd4e88fb,
+    0x5c14ba9ee84a6, 0xb6f13c3c19dc3, 0xb95c4a53c803c, 0xc4e144da54cfc,
+    0xc83b8c58c4d06, 0xd6b1aefdf6273, 0xe33b30323e15e, 0xeea7f2d248088,
+    0x01b6971113dfe, 0x15cda57cfceef, 0x22b03f4c8c192, 0x66043a9c7bb86,
+    0xe2f0847eac8ad, 0x0e42cfb41a2b1, 0x1cf62a1db3940, 0x29d2accd49211,
+    0x2e6f034284456, 0x2f03ab1549bec, 0x3957f91ac1e31, 0x4a5a75ca59354,
+    0x5765cab4dbb5c, 0x5ab92a75cd8b7, 0x7664a2dc04d0e, 0x999443e9004d6,
+    0xbc2c31933981f, 0xd4f7d5acc1475, 0xd67a921b2bc0c, 0x0ddffabdc737e,
+    0x16163001b64ad, 0x277e565de1cf3, 0x2cf8ea3e72695, 0x33b8865919282,
+    0x4e87070f8b0f9, 0x504d99cd148fd, 0x598007553d43e, 0x61679eb61d6c6,
+    0x957ee6c85b648, 0xac33e148959b0, 0xaf101ef19a98a, 0x0421a8f6bfef2,
+    0x0dde5d380851b, 0x13371dbb23c07, 0x19f25d8f6c83f, 0x59f44282eac1a,
+    0x654cbf5f95612, 0x7663648b0923f, 0x8e37d501e5d9e, 0xa2ef89ec52ec8,
+    0xab15a2761b9b4, 0xcdf50bb6dfca5, 0xd165870f6fcc4, 0xf8ff713287fb0,
+    0x090c4cd6bbe82, 0x6cf18baa3aaec, 0xb402e1b33d3c4, 0xc3a8f631a0d6d,
+    0xc9005eac7a603, 0xccd85bd2bd18a, 0xdc5a570662322, 0x08e9a8e81c081,
+    0x14b81bbfb5ee0, 0x2c70bdac65482, 0x4b682dfd33996, 0x5aa3fe50aee52,
+    0x5ea156d44fda2, 0x6a13799561624, 0x710c41b2ba26c, 0x77155b4b45883,
+    0x87a3e9b540c85, 0x9701a704c3c5a, 0xcd1ac43051e95, 0xcfcb4280477ab,
+    0xec900ec2568d8, 0x16900d565f671, 0x195a3533ac3d6, 0x3300d09986e50,
+    0x36feb087e5319, 0x4de2c2cce3357, 0x5050675682a19, 0x614665417ecaa,
+    0x7680fcb7a11e3, 0xb63389202e0d8, 0x3983fe47c3400, 0x78121b12f0332,
+    0x999f0f6ffba41, 0xbc52618127bf6, 0xcc735e4f57648, 0xcfeebe835a2c9,
+    0xd831e0580f3a0, 0xfd59792682127, 0x0741ff0d8baed, 0x10205c44ec156,
+    0x25eeb8a39d86f, 0x4c05b31b262fe, 0x6c4bc71cfcf7d, 0x84f9f41f6d8d2,
+    0x8a4e2c70e6acd, 0xbc41275a1f13f, 0xcef2446a93e6f, 0xe5872db8d54c8,
+    0xed5a70b294b0a, 0xee60de252fc8e, 0x04a0f8d1d620b, 0x076e28962f694,
+    0x231cd125c7186, 0x2946b2ef3d526, 0x5af1f7b2e4fa0, 0x86f055ad06bb8,
+    0xd8b7fa77b94f8, 0xe574fab1f41bd, 0x0c3f945def4b0, 0x0c74b847dba09,
+    0x0f2741f614c2a, 0x48a478fd1aa1a, 0x9b1269f12fa8a, 0xcb13e70a440d6,
+    0xcdf86f73da304, 0xe046ee67b79fa, 0xe48dca3736c38, 0xeaf0d0b17e4a9,
+    0xf8427c676eec9, 0x05ff861893a17, 0x643d3348c3028, 0x6a432f049fc7d,
+    0x72f828c5dc41b, 0x7865c51ad5cc6, 0x88197980010cc, 0x9ae56fe3bb12a,
+    0x9f676d1e8da30, 0xa5832b3f6f3bf, 0xc39c1c440199c, 0xd3cf9caa14379,
+    0x1d6001044d65d, 0x27b3efe15c2a0, 0x3ce0a1e6dffdd, 0x713b43d850ba2,
+    0x7ef754a99ce9c, 0x854c795c364d6, 0x8a55613b83ced, 0x909de9e1ff26d,
+    0xda884618c58fa, 0xdeb1a7283d272, 0xe195ba73c7c00, 0xf70876690222c,
+    0x18aaa969456c1, 0x57a427d896ce6, 0x737be7029ad7a, 0xd49abcdecb41d,
+    0xe8c3f70c0ea21, 0x303eb1feb37f5, 0x3bfc8f552b1ac, 0x663b1663fd333,
+    0x6d53908ae5688, 0xdac6314be2ff5, 0xdfd8f2da18f41, 0xe3787afc9bae7,
+    0x11d3c365d6f67, 0x23e2b604418cc, 0x2ac0076df59cf, 0x3424b59261cf7,
+    0x3e853580bd8a5, 0x47decb8c91ea5, 0x54a1492f572ea, 0x592b7b3518ba6,
+    0x5aad0f2f75fe8, 0x5ccdd458927e5, 0xbd32c7f512716, 0xe7a91bac73f18,
+    0x015a70027d0b8, 0x09929e57fde19, 0x113958ad0a044, 0x1431f745caca3,
+    0x19af796ed5506, 0x6520f5ac44e69, 0x75d6c28f8dfc8, 0x9da47c3d40f7c,
+    0xa236e6b6e1db3, 0xc6bc88506f835, 0xd2759f3d4eae6, 0xd8ca4b28ec2bb,
+    0xdb4bfae6e525f, 0xdcf236e89efb5, 0xeb619a12a61ea, 0xfacbfac027e03,
+    0x030be8c28c27a, 0x23793ee6a121b, 0x2ec0c370cfba4, 0x3320f848fe343,
+    0x5231577f41667, 0x84283d39bc9b3, 0x85670d6365e3f, 0xa86512865e1c1,
+    0xb499bdc72a446, 0x2402857a616b7, 0x388e47ce9a057, 0x56ffa56a52ac1,
+    0x81714504cd896, 0x8ec0f0574bafa, 0x9a66e9f4d8bce, 0xa2ddffed8c31f,
+    0xb6937f12588c8, 0xd0b1b1ae2b963, 0xd1af5b9939a2e, 0xf16010c958e9d,
+    0x11b82bf32667d, 0x1ab6c647c3f31, 0x34f14b2f9153d, 0x674ae15355b9c,
+    0x6b7fd342610a1, 0x79afe84eeb75a, 0xb44eaba5a879b, 0xd159648feb38f,
+    0x12fe5cada39af, 0x20357bec069ca, 0x9b87ce0212fdd, 0xa6c7bb21b21c7,
+    0xd3f6716f503a7, 0xd96720a6274c5, 0xeca54a75e1c27, 0xf3adb2dd74a88,
+    0x34f4f438a1561, 0x5e80d89bc9c9d, 0x639e5e14ff45e, 0x7ef0fc17e5712,
+    0x8b304dfc2e3e4, 0xceadd50495c1a, 0xd0bff2c350a52, 0xd1e98b9ba2ce2,
+    0xdc9ace69e38a5, 0xe2944f0a1582d, 0x03051ae161c08, 0x031428c60d759,
+    0x0f03b0aa49c6a, 0x22c83a0ad179f, 0x2b62398855822, 0x4fac533c45a50,
+    0x5ff4579dadb74, 0x77ab66f315670, 0xd3299a3b554f3, 0xd480dcda87365,
+    0xe88917f0ca555, 0xf0748b7f2bda3, 0xf096ed993f15c, 0x14c72330d22eb,
+    0x17dd9bb203e12, 0x1c4a6c965066b, 0x31506edf2474e, 0x397716258b33d,
+    0x3da86a1f0b491, 0x8c2eb2a7d2612, 0x9e65b0b41efec, 0xa852b77aea319,
+    0xa99380b6253d7, 0xad0942912a4eb, 0xb4353586dda5a, 0xb8314fa0cf3b7,
+    0xba6906f4de7cd, 0xc2c3f68cdbee9, 0xcf35e0e3f0e1a, 0xd5d3a9a122751,
+    0xf027c5c9954d3, 0xf82b0ac42f92b, 0x11764ef35a61c, 0x300b3ac623d59,
+    0x4346c6d20c4e8, 0x576fe00edc6ff, 0x582b63899602b, 0x5baa6cadf51ac,
+    0x9315febd3bfc9, 0x9665616039371, 0xb895790f2769f, 0xc324c3e68f5fa,
+    0xc377c561dcfe0, 0x154c9ff01cc2c, 0x2437c35955371, 0x30dfe418c18b2,
+    0x3b50f5df4f343, 0x3bdb43ea4f9b5, 0x5dad4d0029724, 0x5ea1507974180,
+    0x79f0ac06480c0, 0x9dc02a4623b6e, 0xa46465a2c7eb4, 0xb3c827c592b82,
+    0xb7121f4cb7483, 0xc02dd538f2ffb, 0xe114e3e6e6166, 0x12d041bbc3c5e,
+    0x19db0d5822e42, 0x2b8839c95b5d4, 0x369e385f28db5, 0x4b073d19d535f,
+    0x732e21af813a4, 0x7cd81f1bc197c, 0xb8dd3a483cf0a, 0xcea77fea6740d,
+    0xd47502f993abc, 0xd9ad447e8b783, 0xf2144dc0f9ba8, 0xffeb5c7eeeb82,
+    0x067587d3f18ed, 0x11e4856673c23, 0x22485fa3e9e8e, 0x5c8d8def736ed,
+    0x5e4b3fdded010, 0x80840c70a6ce3, 0x9a53f8fff394f, 0xb080f2b45772a,
+    0xba89cf5730b9e, 0xc3005693461ec, 0xed46457873011, 0x0fada9b713fcb,
+    0x10e4c8d79b455, 0x152f1a5a51acd, 0x1972ac61dde0d, 0x81a37ecab2ca7,
+    0xa323ff5df4273, 0xa3ffa65758587, 0xbdf951dc420fb, 0xe4b54f18acb78,
+    0xfc60587075247, 0x04ccb578fa272, 0x23f43a1755275, 0x5e9ccfcb19e61,
+    0x8b2398b44c757, 0x92f12eb14cb18, 0xa275b1b3219e5, 0xc91e11875d896,
+    0xff9df22fcba35, 0x16f66086908aa, 0x436842a313e92, 0x6a0deebddbad2,
+    0x74815515741d2, 0x90d06242a9523, 0x94defc4b8d846, 0x9bd95e520eabe,
+    0xa848237a944a3, 0xc994755ad17a5, 0xd7adddc63ffe4, 0xd7d4cbbae5e53,
+    0xddbcd046cedb8, 0xfb3835009b759, 0x1123d8e22f672, 0x36abb0415dc75,
+    0x52e10c8f5afed, 0x58075ce1fe3f1, 0x590b169b25304, 0x6672eed9551af,
+    0x6ad5699eccea9, 0x70d8611c8d99f, 0x936411e312230, 0xfdfbc95a5c454,
+    0x1994a48a1e0bd, 0x34ff1b4b1db05, 0x4ad1f137afffb, 0x60e77c5134d93,
+    0x61e033e76621c, 0x7413c3925c346, 0xacdfa15238e2d, 0xbf207cb65e501,
+    0xc7c6c4126818b, 0xce36cd1317c42, 0xdc1e41bdfa5c3, 0xf3cf32a187b63,
+    0x1dd273aba2c70, 0x61490452caae3, 0x6f4608258d0d8, 0x769aa0409190e,
+    0xc8e42eb720e09, 0xcbcb28f944ace, 0xdfd0f5dfea7ec, 0xe3f163d3a05aa,
+    0xfe2e23ade5fc2, 0x172b6eac421ad, 0x1750429ee2ffd, 0x29b5dfa8f7848,
+    0x2b6ebc8a17351, 0x60b88ce778b58, 0x67694708b8130, 0x77086525dc116,
+    0x8ff8437a9596a, 0xa053777801944, 0xa4ba940dae392, 0xaf801c9f4e487,
+    0xb0c0e98379b0a, 0xe47e61fb7e65a, 0xedb9930b34254, 0xefdfad5a8725a,
+    0xf08fa39e9d5be, 0xf87bdccfb259a, 0x1cd048f986d62, 0x2a14427bfe04c,
+    0x2ba179a2a0cf4, 0x2c32c1b7d12ee, 0x7154e386e96c0, 0x83915057ff106,
+    0x8f42f0cac2005, 0xc980df8244b3d, 0xcf3ba46a88611, 0xd9b981d168c5d,
+    0x057a8716af366, 0x1526b2b50c6e1, 0x18fe78f539bdd, 0x80c03977ad2fa,
+    0xb16c15cdbacc7, 0xbee6496179b86, 0xd028cb2d7fc9f, 0xd5ab818c4d91e,
+    0xd6e9f28d8d7a8, 0xe37ad4464f7a7, 0xffd199ce87684, 0x0b03f95410aea,
+    0x96b78f35a1f1f, 0x98dcd53e80083, 0xad55fe05337fa, 0xb13c6f58b2c80,
+    0xbc4db27fb8eaa, 0xec9cee3a963ac, 0x2262f79e24f28, 0x2e752e782c11b,
+    0x5690529a0fad2, 0x90c7b8bc7d95d, 0x9ad4d0b541999, 0xa76273a0e1be9,
+    0xc36daa7ef7ecd, 0x03a6842ba04bc, 0x056ac6010f616, 0x2f18139e728b7,
+    0x585364b5eaadf, 0x858b9cdbe897c, 0x8e487ae36c1e2, 0x9c9c16d5269e4,
+    0xb5bcaa80bcb03, 0xf1075859d9de1, 0x3988637796f11, 0x4ace5af6d4464,
+    0x4c68b5945273f, 0x559daaeaaafb5, 0x65588c972b409, 0x8f28ebfbb2f53,
+    0x98fce69586648, 0xbac07b7e23ab8, 0xc21881fa7eb9e, 0xcbc79da6e02f4,
+    0xcc95ea435394a, 0xfcd11e72c7b2f, 0x098b96ded2abf, 0x22387a7cc2cd2,
+    0x2d9d93658b784, 0x3056935c27979, 0x30eb2c8b2b9fb, 0x34dbb96e79d90,
+    0x3e41fdd31e123, 0x495cf266b8b0b, 0x7111112ad5d84, 0xad522d03ea9cb,
+    0xbb6602112c7ec, 0xdd5b786da3e41, 0x09896e456611e, 0x1dcdd99c774c8,
+    0x275bd8ea559e2, 0x4d22aa39686ae, 0x59a08bea5b2f8, 0x5b89c2a2de68f,
+    0x6e1ee8e6d1e8b, 0x802c837cfa99a, 0xd9145e7288780, 0x12241281842d4,
+    0x133d2f774691f, 0x16cf2da8a3d06, 0x2dd8f6ca7914a, 0x491a82dc50b2c,
+    0x9c914bc9adc2d, 0xe68c153f427eb, 0xee28b05b8f542, 0xff38c948fdefd,
+    0x2dcf8e9d05a82, 0x34940e68c7b9d, 0x541619a04e04c, 0xacb6a8938598b,
+    0xb5628a9f9cbbd, 0xbb3e907fc727f, 0x19994aa3d3440, 0x4fee528658a96,
+    0x635972c6f1325, 0x673e134b32af0, 0x6c0107306bcb6, 0x7c63c3f4a5d1b,
+    0x82278e455d082, 0x960d42a829507, 0x9c644a76b24d5, 0xd320122d274ca,
+    0xff7890462a402, 0x0ad598fd678b0, 0x0e21097d413f4, 0x20dc71b1b9b34,
+    0x329139367202a, 0x3921f4726a5cc, 0x3f49e8251fe89, 0x47d3646e3373d,
+    0x704432ca07bfc, 0x73f62c37bba54, 0x846889462fcd2, 0x8b3d25cc18430,
+    0x9d1da557ff460, 0xa5c7626740524, 0xc05b750860788, 0xcf57946d05501,
+    0x00bee59a0b0e6, 0x341f1c6d8f98f, 0x3499132f63d6e, 0x58d894e398c63,
+    0x8f08157d7b9b4, 0xa7e9c8d7bfc6d, 0xb287bb198958d, 0xd909ce026f3ab,
+    0xe984dff4aefce, 0xed5d01b455507, 0xfc6f723a9d0bd, 0x2e31cdc29b102,
+    0x308d936697bda, 0x5a2e0a68d7171, 0x6574d7f413a80, 0x6bde33c64aed8,
+    0xb27e06105e59c, 0xeeeb0d2dc6de3, 0x08c511a3f2399, 0x21d36e935ce56,
+    0x21ece13a9ec1a, 0x54881a0ceaa64, 0x567f4fd49ee5a, 0x577d7286a8d8a,
+    0x82aadecea47ef, 0x8d4b0fabd6ae0, 0x9d448fc8c25c0, 0xee3fdd5fa2f17,
+    0xf910103db001e, 0x114ec13684aa5, 0x64454904eed2f, 0x65e7a8d9274bf,
+    0x9297c2ebcbfa7, 0xa76af984aed65, 0xb18037a4a0643, 0xbef424843102f,
+    0xd215ffb0e17e5, 0x01190d3c345d8, 0x08a44239da5aa, 0x0b67d68741513,
+    0x15f4d0768edc4, 0x3443533138610, 0x4672a03462fe8, 0x7128077a3b716,
+    0x90363e0ab8307, 0xafcb5b96bf844, 0xb3d336247982f, 0xc54f4a6d7bb2f,
+    0xd1b09f436e5b8, 0xda1c5eeb93dcc, 0xe16f855fc3f49, 0xf0babf1763f93,
+    0x24bcff6c7f81a, 0x2dbe427fc01f0, 0x3d73da00daf8a, 0x743ff5578bc4d,
+    0x997f8b7d7bb1a, 0xa2c041479ca1e, 0xa44179c280e23, 0xae9727221ba48,
+    0xc321115535ba3, 0xd0a8267ee98bb, 0xd5df174d802b6, 0xe38a6a5b56191,
+    0x1ffe59363cc87, 0x31e1d9574f851, 0x39a1b977e5f17, 0x39f0a436ac6e7,
+    0x538eaba92eba9, 0x65c9689b79d21, 0x6e61222bb4849, 0x7d97283aedc5c,
+    0x7f6e19938427d, 0x94c30decff6ba, 0xa36906a7c7501, 0xd16d62c4722c1,
+    0x15b682afd8edc, 0xd8936e3aba6f1, 0x04e44d974d300, 0x0a3cfbcdb6d13,
+    0x21304f3485968, 0x2fc4da7ec5ea0, 0x9d4e6618d589c, 0xa89754f7f7d54,
+    0xc1d4cd5516aac, 0xd75601207df9c, 0xeb519c81b2b0d, 0x3a754569fb38e,
+    0x3d7e3ef4d8845, 0x511d65a621427, 0x7727da072e09a, 0x96e666042a5f1,
+    0xf60955a4b0436, 0x17f8387141c8f, 0x4cb633272c4cd, 0x5a25f6fd83fca,
+    0x63b797e9dde5a, 0xb0fe1ebb03e85, 0xb854287cdcd74, 0xce703882861f4,
+    0x05e6713549a5e, 0x118fbbecaa5ba, 0x13969cf969d18, 0x2abfc04e45df1,
+    0x5d07aac98c92c, 0x87128e3908327, 0xa208c62fc3d60, 0xa4395b374f277,
+    0xf91f3f81f797e, 0x16dce0a1bad5c, 0x17acbfd575e58, 0x8fd2f44ccea32,
+    0x98bc7be978a9c, 0xa0648a9dd6558, 0xc6653eee8d2f9, 0xca896f7131e1a,
+    0xcae3d8c2da49e, 0xd2d1072ad7709, 0xdbd5d98fe1d7c, 0xe115a78498093,
+    0x0be54e664689d, 0x1c1d6cf39da09, 0x46d03a1e94729, 0x53a0b3279a608,
+    0x82c3dcdfb7259, 0x8920571bf9858, 0x96d84635ed7ed, 0xb6d6364608b5e,
+    0xd12036deac9d1, 0xdcd320b5f0012, 0xe15830d2a018b, 0xe9bbbbafd69e9,
+    0x00d780fed183f, 0x014ecb5912537, 0x5c508b0c1f3f5, 0x5d029b523a778,
+    0x6f4058d9b148a, 0x934eb018fe214, 0x9aa7071a011e8, 0xb5af93146b0ab,
+    0xdaca9728a0902, 0xfb8745d7a6d89, 0x1f19013f6cbd7, 0x55504c810418d,
+    0x597ae4a84a56e, 0x81521c856cc1a, 0x9b2fc9ebf46d0, 0xa283da9c3b98d,
+    0xbe3da9e82c8bb, 0xe3d5a1aa71ac6, 0xec3086479e093, 0xf538b4c14d2dd,
+    0xfd5baf6e67407, 0x05840a3c88e89, 0x2ed153d8364fe, 0x67ede97d26eab,
+    0x6fc53be18cb79, 0x9ff69a0b08bee, 0xbdf1017a18e13, 0xe7ce3b22b4c2c,
+    0xed6f567da12b1, 0x3b4e405e9ac4b, 0x4a50f353257c0, 0x610900b53d39b,
+    0x6721123cd2265, 0x856eda4f99472, 0xb2a2c12a0a4a9, 0xc26bb7db5dbfa,
+    0xd4928a89c287d, 0x1c0ecd18ddfa8, 0x3dbff0b64a166, 0x73b6fc4c6ced6,
+    0x9b0327cdbc30c, 0xb0585e081df23, 0xbddf857e83914, 0xd28cc702990b7,
+    0xe0b8af5cfa2bc, 0xf30476ee7317c, 0x0c1def9f65f26, 0x0f889d0ac38d7,
+    0x5a8d5849ec167, 0x75bbce5e524f6, 0x81e61fd759657, 0xb6f90a1f5235e,
+    0xd198e82f0bd9f, 0x157d2b62729af, 0x3215dccbf93db, 0x334eaf743b6f8,
+    0x4a8c1fde5788d, 0x5d943f30fef1b, 0x5dc44131d9d31, 0x66803ff8175a6,
+    0x7fd8c13cb012d, 0x9c4854b9e9a2d, 0xd423e694996cc, 0xe28d57380a8e1,
+    0x6b18a7ff792de, 0x8eed3984a4dbb, 0x9af01cf0296be, 0xb63a77845f2a8,
+    0xbe87ce8775af1, 0xe2a02b60ef4ec, 0xed35fecd6c0f4, 0x1549dc09681fa,
+    0x5cec46ea76006, 0x6ef513c525885, 0x9ce413f715a97, 0xaf282e285cf1a,
+    0xdb8f48b0f06c8, 0xdd16232dc2bff, 0xfa3b4b48103d0, 0x057d9b9c7b93d,
+    0x05d500b007e62, 0x0adb7e08a876c, 0x47cb46c152fd5, 0x4810926036cc0,
+    0x4eb05f29cb378, 0x90b9a542e58a0, 0x92879fd6c9d42, 0x9e92e3f881b37,
+    0xa53bf0b98f6d2, 0xae953640bbde4, 0xb7d3434c39bc6, 0xca67f47221b22,
+    0xd4b4bd59bc2a1, 0x43f51e1f98460, 0x603039b706732, 0x88dcc3359d50b,
+    0x8f7306d4f137c, 0x8fb9e50b1cb0d, 0x9df5874e4a78e, 0xb81e3bf4368c4,
+    0xcae25cb56af6e, 0xd68c8305214f6, 0xe4cf38f111962, 0xf51cf1a119891,
+    0xf5acc80f757d2, 0x0366a5402a24a, 0x23a6fda180534, 0x45a2e69d6a598,
+    0x545927fd9cf5e, 0x59b411267ed7d, 0x5a342dac0e242, 0x63ba7f99bad1f,
+    0x724e369d863b5, 0x89b2f7cda1f80, 0xaf0d0f704e703, 0xb639026601c72,
+    0xda131153b7ecf, 0xde9af2715f0e1, 0x1470394dce2af, 0xa97ec83e46c89,
+    0xd54f2f95d1396, 0x0d748def26687, 0x2ab78ff1e4d20, 0x3543183a5df18,
+    0x3d9dee33c8dd1, 0x497bbbcbc60aa, 0x538f2cc56a292, 0x67831e6f5d98b,
+    0x83fef9cb1ce92, 0xacbbd6e9ef939, 0xc56e3f424ae56, 0xded0c6211064e,
+    0xe3958e7e77a94, 0xea5d1631462d3, 0x05873afa1f7fc, 0x49ee0539c27de,
+    0x62b0c8843a553, 0xd631edf99bf7e, 0xf1a9bb6e9c0bf, 0x057c353a9353f,
+    0x1acb6a4a9da22, 0x2aa028c19a38b, 0x59dba3fecc5d5, 0x9ef8d37907d65,
+    0xaa384f238bc74, 0xad1547747328d, 0xc1a90eff660e2, 0xe5e1fd23b51db,
+    0x0ccb7492cad39, 0x0ebba5557e058, 0x133e857a86f14, 0x32a1501d98f91,
+    0x47f4899d789b1, 0x48824d5bb045d, 0x4e798f6079a14, 0x6720423fdd9f2,
+    0x7059101ac7269, 0x9112999b3e1a4, 0x9ddd5703c36ee, 0xb802d7d75ebc4,
+    0xbe194bccdae99, 0xc8a6ac36728d6, 0xcdaf55ddc9ed8, 0xd5596ff06b29f,
+    0x0d262723944b3, 0x0f10e1cf9dbdc, 0x236391bfb6d84, 0x65e6caf1b50c6,
+    0x81b2f93c255cd, 0xe08d487b036da, 0xf4f5a4da7f31e, 0x0975737dca56a,
+    0x0c4c8099fc6ed, 0x0ccb86f830536, 0x57e3a1679177d, 0x5ca1e881c4aa0,
+    0x5cc2518a46227, 0xa8ca341d07302, 0xaea82bbce2a6c, 0xb41b57c718770,
+    0x1cb93bb2dc5cb, 0x31aca8171ba4a, 0x56890dbae5c15, 0x63b75acdfa463,
+    0x663e12d6722e7, 0x736d58c95f389, 0x7658159a71db1, 0x7d0d0016cae02,
+    0x9143183112c9a, 0xa6e3cac8e47d1, 0xfa4e323929468, 0x20ba82bd0a54c,
+    0x30f94acfe9752, 0x7866bdb24f541, 0x900d3ed87571f, 0xa0a29f0a4cb32,
+    0xa328e1f4b9116, 0xe14d6a420f04b, 0xe5302b5351447, 0xf5c533ae9fde2,
+    0x0849f405a5c37, 0x1283c43751b8b, 0x12bfcfdebc393, 0x20eb640b850e5,
+    0x3db529f4519de, 0x84cd9af7f762b, 0x888cfd665d855, 0x8a3c8bac42767,
+    0x9528e4eb058a3, 0x9a3ee5465d59e, 0xc5e41106a559b, 0xd7f1d788125e6,
+    0xe6e42be19033e, 0xf4d60ca0e9abc, 0x24240b75fec91, 0x3158bc84c47da,
+    0x3760f6dc09e00, 0x3d9dd5045ef90, 0x4811acd11296b, 0x736dd55e59069,
+    0x80add56bcbf65, 0xb5a38e268470a, 0xfc77a1704dd71, 0x06f1fe2925e34,
+    0x179fc22cc4519, 0x1b1a1e861d7f7, 0x1b73bae331b63, 0x279beb085311b,
+    0x5d9c6d7c049f9, 0x6daa3aae30595, 0x7d77ecce4b29d, 0x905784432448c,
+    0xae38213469e75, 0xe48420058b673, 0xf1701e65f77ab, 0xf60b0e2f2f486,
+    0x0f5d3dc4c89eb, 0x2a83fb7e2c1ee, 0x3ba77744c3b25, 0x5d6519a6caf75,
+    0x6bbcb2b67a12a, 0x758cc27f0ab45, 0x9a2755e018a24, 0xe6a66585979cf,
+    0x013aab63dccf9, 0x3023e37694161, 0x5f48912237144, 0xa3f29ba27a506,
+    0xaba5b3cbae5d6, 0xcb89bc167c2e3, 0xccbf0a67c5ab2, 0x04e74865d3550,
+    0x08c66086b944c, 0x09fe8381ea279, 0x6d262fd37b6e7, 0x8d6092054b3ec,
+    0xa406983f6c466, 0xb580ea5cf21bf, 0xbc9f53617ff6f, 0xca8f66fa8bff7,
+    0xdcedae7af787d, 0xe2459365bd53d, 0x01674a16e5ed2, 0x13ab1b1565ff1,
+    0x4c203873f6954, 0x601b192d48e86, 0x67af2b510e774, 0x7f88b65f1be8c,
+    0x8e630d611ca9e, 0x8f8d1b577a5ce, 0x9e85fbeefd598, 0xe12b6b4fca757,
+    0xe4cb8980cbf8a, 0xe51d9d171226b, 0x03ea51e97aa5e, 0x1d43484c1ee85,
+    0x1fce84dd48c35, 0x2a4c48a596a1e, 0x481610b36c616, 0x58cfb61b46776,
+    0x6d54c3ef25f2d, 0x7479a33c16cb1, 0xbd6fed55d11ce, 0xccd650e55e434,
+    0xe696a82541fd6, 0x504368dd95bbb, 0x5167cd8024a2f, 0x5cbd6e610723f,
+    0x61f5162e8fcb4, 0x6ff79ebbbd5b3, 0x9893d49a186be, 0xa14c90ea43bbf,
+    0xb40e3fd2ea7d2, 0xb8c756604ac9e, 0xd456edefa2e5f, 0xeb7dae0fb2bb5,
+    0xfedd44c298441, 0x4b15b0c0f18ab, 0x8b01cbfd8a3c2, 0x9e59c79cefeea,
+    0xb909c60fc0533, 0xc2d2580c54012, 0xc660bf9a06bd2, 0xd492bb6b711e4,
+    0x381387aa2a594, 0x563ac84295abe, 0x6b15c5da3cb1c, 0xa0fc93c5c5e5c,
+    0xab0706a8d733b, 0xb44e0a790729c, 0x02cdd1be9a2fa, 0x2f308c57df52e,
+    0x34ec9352c2bba, 0x36968a918aae9, 0x3bd70f8532e7a, 0x4d2e7474f7cdc,
+    0x61dedb279c9cb, 0x71e2aa117c480, 0x897b3e75232d8, 0xb5dc57d35f352,
+    0xb96ce45de8080, 0xce28841997e84, 0xf443b2952a951, 0xf44f29d62bc7b,
+    0x10357e1ddf9fb, 0x54b6d12c1fe1d, 0x6cb5d887feceb, 0x95ef6b1ceaf31,
+    0x96e1967517148, 0xcbb4869b723a8, 0xf73b3ebbda28b, 0x0deaf2b99f4fe,
+    0x2cb36c361d129, 0x2d4fab737205e, 0x30ab05704d11f, 0x32397b009f3ba,
+    0x4fbd0d337a187, 0x6e7aeeb03ca79, 0x73db887ece0de, 0x92f3e1f0fa768,
+    0xbd7888e52547c, 0xc446deb6df707, 0xdf25454162536, 0xe7ef84f7b75e4,
+    0xf253057b3ed2c, 0xf6f72dea0f360, 0x3ed8f58c5b3fc, 0x5ccf9e156ce83,
+    0xb8f4f91f64e62, 0xc03b53f342424, 0xf06da3aab6163, 0x13a407dbcc228,
+    0x20cc4c72a87b8, 0x26aa7af8995ad, 0x34088bd4929fc, 0x85feb9a76af0f,
+    0x959965888eca2, 0xc547e12655908, 0x04e477ecb89fd, 0x3988b6c89a113,
+    0x75aa6d73d6f76, 0x7d5412686ca9d, 0x898f588aff6a8, 0x9fcbb7fa97a4c,
+    0xb21969f9e0e2a, 0xd24beb8e5a02c, 0xd5f02f1fa4189, 0xd772c6f4aaf6e,
+    0xdbded5df345fe, 0xf61b4534d2128, 0x11857a14eb396, 0x13a9bc431fb57,
+    0x1f61d2fe45a2a, 0x347dd23b24497, 0x621ba569f4f43, 0x67fff9b3a0e1e,
+    0x71c5f19552eef, 0x84d8c28648119, 0x871647c789b9d, 0xa3add80ee3dfe,
+    0xc8e91597662db, 0xe029485eb7df5, 0x11659131a0390, 0x1400c5e38cdd1,
+    0x20454102c7d70, 0x6078a0efd61a4, 0x755e41820968a, 0x86c0d828f8a77,
+    0x946c487e5177a, 0x9df0ac553d774, 0xb761d34c83da7, 0xd7d6f7c13db72,
+    0xdaa3f7f1624fa, 0xdadf036713924, 0x6d29a28aac358, 0x703711fa7a027,
+    0x8ec70f21570e7, 0xaaf02739bee1d, 0xcd939ac8baba4, 0xcdc035ba1fc94,
+    0xec8138bc8150d, 0xf6c00294eac2d, 0xfb90dbeac25ef, 0x0a2a906ff4df4,
+    0x34a6ae6b9869f, 0x6e98f4da66d7b, 0x6eaba0b49dc7d, 0x72970f57cae94,
+    0xa15d1109fa0a1, 0x012af24d0792f, 0x3c86f7a10a27e, 0x4e90fb9388566,
+    0x61a098b22cb30, 0x688bcbe38ee6a, 0xd962f44c2f0a2, 0xe8dabdfa6308d,
+    0x383f85cf66145, 0x4dffb38ab1cfe, 0x76a7b12eb6021, 0x77a0c444604e7,
+    0x8ca20b1e6d013, 0xb1594c4aee4a0, 0xca3b920ac0e21, 0xd16604b5de5c3,
+    0xe97c0937811f9, 0x02f1d950dd10a, 0x54434296c9844, 0x82ef76223dc50,
+    0xcd5b3f94f4a6e, 0xd99d368ef029d, 0x1aed3f1a9a3f4, 0x1bfdf7b138f88,
+    0x20b097f636480, 0x22f98d26986a4, 0x29d8e9ee900b2, 0x57813f2779bf9,
+    0x598c78d1a6d47, 0x7eca50750ac32, 0xba4c7a720f69d, 0xd219d74029a11,
+    0xdac9e25579b32, 0xe0be3d63a9db2, 0x1327b95b7f3c9, 0x6669f46d6ebfe,
+    0x732732df9fad8, 0xbdf73f52e76af, 0xc25640bc379aa, 0xd67ee4dafc321,
+    0xed1fccd4fc21d, 0x7327f88eacff8, 0x7a27bab635bc8, 0x9fd50e6335981,
+    0xdaa954cc4851d, 0xebade08313910, 0xf6b746b5b35fc, 0x392fbbfcb20c9,
+    0x4a7937d2e816a, 0x4c2cef2205f6b, 0x80d4532c76bcd, 0x82dfc3bcb93a6,
+    0xc9030181266b8, 0xce7970016e730, 0xe0efe0c49b638, 0x0b03e33b1d129,
+    0x0f1f3697a232e, 0x12115b3f0f9f4, 0x13645edf666d9, 0x1c20181bcd1af,
+    0x1d752ac357d64, 0x340d4f35caba7, 0x4b9f3a13e9965, 0x630f0fb3acc93,
+    0x8bce74a0af46f, 0x8e5b686284677, 0x9d32c9ca2a83e, 0xba2e20a22942e,
+    0xe5e5a7b80043f, 0xf3a2bc63f60dc, 0xf7765d43dacae, 0xf91bcff9f0cb1,
+    0x03078d5c3b89c, 0x620a60cff7883, 0x8b8787d31c35c, 0x9889364bdb4f5,
+    0xc5efcb8ebaa29, 0xced14df4aa5de, 0xe59acc703a08d, 0x0e5d5dddac93f,
+    0x2010eaa1a5e07, 0x21aae616baae0, 0x2756c1b3b6415, 0x28c8b563d963e,
+    0x299ecca8014f9, 0x3d8b149ab377a, 0x634cec4308c7a, 0x762839be35b8d,
+    0x7b6892c6999e2, 0x96761867c1071, 0xd2645b07dc249, 0xe33f993e25a0f,
+    0xef45a58129e23, 0x23c2c6d430bc9, 0x4c3bee94579f0, 0x75c1ce2438433,
+    0x7a7062a77f864, 0xb623f1a87208a, 0xc0151a269571c, 0xc0ab0b5ddb5f4,
+    0xe0736265feb6c, 0xf9de1a66c2d55, 0x118cccaee7924, 0x26511da572b08,
+    0x38a8bba0562f4, 0x895860ee827ef, 0xb1460addbb0c5, 0xbd98a5fc9a4b0,
+    0x0b02b00a0005f, 0x150e983cd61bc, 0x2964f4c63be29, 0x3437b6c4597ef,
+    0x5f2045399c27f, 0x985925d8a6613, 0xbd09a0381cbde, 0xc68f88028ef6a,
+    0x2c0babc054a75, 0x31649bd7a4c62, 0x3db7877b2bf3b, 0x3eedfa979249a,
+    0x5eb66b3e48275, 0x95829c8a9b1d6, 0xa13181f2092da, 0xb7da976517602,
+    0xc0c8ff09ec5d5, 0xcad788a984cca, 0xe5ba567536bfd, 0x03776c814c49d,
+    0x23d1587a57938, 0x2be031fd75ee2, 0x327adcddf8e57, 0x88ab2983bf101,
+    0x8985ffdf9eb38, 0x9de9a4794378a, 0x9e6f2c1aab6f6, 0xbc724c1677885,
+    0x1a41cdf36c294, 0x2b1d6efb0f621, 0xe15182c79287b, 0x5dad9712fe05f,
+    0x729d065948b2b, 0xc468b6e5b1532, 0xcffe3f9b7fb10, 0xd8ae5cfd8190a,
+    0xf8d8fa4bb3a44, 0x11dc083c7114e, 0x2a4c342ed5c2e, 0x3c36bcfdd9e94,
+    0x4f8e1b3ce01a5, 0x5d4b92ba2fa09, 0x81f66ec0a8467, 0xb2f99ddd90680,
+    0xf5e608bc0bdf8, 0x1556cb1c6e34a, 0x2e52db5a0b507, 0x789fbfb7c6f30,
+    0x8eff433b35856, 0xaee86d6f14ca6, 0xaf02e799f09d2, 0x0b15847cffdd6,
+    0x10c8b5a3a46d0, 0x5854f3fc73051, 0x796b883b54ba8, 0x8cfeb792bfa71,
+    0xa9c048b09d3b4, 0xbb89efb02402e, 0x2c6ca54f6782e, 0x579a39d9b720a,
+    0x7a474c8895a76, 0x92387d179785c, 0xdc2531885911d, 0x1efa4ebc68c3f,
+    0x242f8fac34d6c, 0x2df6d49b57b44, 0x551b57801eacc, 0x7da57b3bb1f27,
+    0xc09bb4ce44c85, 0xc9e2f6d66adfb, 0xcaf3a81b28e05, 0xd32a2a3acf85d,
+    0xed40504e078b9, 0xf1cfc52d4dca5, 0xf7d5a3a1a7ad2, 0x974444b451344,
+    0xa2fa5b0c04a5b, 0xc7f496ef4f529, 0x257e4219b270f, 0x289545ae91d74,
+    0x8998c97081284, 0x8a37f34d5fab5, 0x9678be2a5d9ca, 0xb659212dbf79c,
+    0xb7599870d433b, 0x062c7e7937ba7, 0x12492c6286ce6, 0x3395400acddd3,
+    0x5c5ab4c5e93f7, 0x7f378042db715, 0x9de1025dac272, 0xa504e8cac47a2,
+    0x004e2f0f2d757, 0x4bbab1a5df8ee, 0x60c7611d2c030, 0x716292e526076,
+    0x8b301896b639a, 0xb18eea2442e0e, 0xbf1147881fb34, 0xd35c6b087a68c,
+    0xe522cf91eef92, 0xf1c9616303ff2, 0xf690b18e9b16d, 0xfb859934601f1,
+    0x0d13d93ebca04, 0x1dcc9f6550b73, 0x217328e3e422b, 0x3c5ccc2062734,
+    0x4632c374b8020, 0xd361944fc66eb, 0xe4ed7fc91828f, 0xe6758662e80e0,
+    0xf59d65209c51d, 0x354385113fe59, 0x68aa70651d8eb, 0x6b2ada6786712,
+    0x856a79e6848d7, 0xa6ff354254286, 0xc26efd8099f12, 0xf59b6fc454c42,
+    0x0a236837bdcf5, 0x0b6c15b93fe7b, 0x2695d7b0bf7dd, 0x3f09c9db035e5,
+    0x78f14164012fd, 0x7d765c7b825c5, 0x8a724458efc7a, 0xa1d4e43525c9c,
+    0xadf3afc96a3bf, 0xd4c885f5a79ae, 0xdbe5a93ea55e1, 0xe2bbb3c271e33,
+    0xe6609faeec8f6, 0x2879a359c3e5f, 0x2c1264af3bb43, 0x3e38baedb439c,
+    0x64a2ea1daf8d7, 0x6f176a4593c18, 0x7fab0bf4df049, 0x9af50760ab631,
+    0x9e537f3a350b4, 0xa4d12478bc003, 0xa55af613d024b, 0xcc83eedd879eb,
+    0x2ecf7b6eb8ce0, 0x3cfeb4e2ed96d, 0x58d8f83231171, 0xc63ecd52dbf23,
+    0xdaedc9b08d0e3, 0xe25e49f451739, 0xfca071417f633, 0x26ce7c6dc1b75,
+    0xb3a7aa60c02c2, 0xcbec010473440, 0xcef970744110f, 0xdd4c7a006d249,
+    0xe018a5ea1cd2f, 0xf7e04e6997298, 0x000236984630b, 0x0cd78857965c9,
+    0x10a5f158c77ba, 0x1b6035e68b64b, 0x3b83a24478172, 0x639f049e51d3e,
+    0x7803ca5a2315b, 0xa02cba4e12aab, 0xa7ee8fd74a7de, 0xbe88e7ea54ea3,
+    0x7bafc9fbba19b, 0x98a8621f739cb, 0x9b626247c8f9e, 0x070dfed621e2c,
+    0x0f139fb3a7a7d, 0x323abb710c3a1, 0x599b639bd0394, 0x61eb33c9551fe,
+    0x66b6f630ec5cc, 0x873fa21832cc5, 0xa0b5d502e879d, 0xa832e2af09799,
+    0xc86bd6e2f53aa, 0xd5667904d28e2, 0xd9d21a233125c, 0xe3ceff6c2c9b7,
+    0xe620b47b2b6cf, 0x4c6995e70d34f, 0x67b2409c7866b, 0x7e954f06ccd06,
+    0x95ad8c55dea49, 0x96c730b65e60d, 0xb0d94b07e05a2, 0xbbd85020da5e0,
+    0xbc98e6e0e3f3b, 0xf78405d656e8d, 0x07dcfee14aa5b, 0x08b12bb7a23f8,
+    0x0cfe8c7345d1e, 0x56e9d68f13ab0, 0x5fc712a44794e, 0x993128b30af9c,
+    0xce9fea4a9041b, 0x09c841046b48f, 0x0c44ec20d5b18, 0x46d83f88723b9,
+    0x7b311ba576412, 0x8fb0493f71882, 0xe30929a09c3a7, 0x01b248ea1333d,
+    0x1ea7a5e99b383, 0x2ee3f4d20bf68, 0x8c0eb22214b9e, 0x9a7150fda9da2,
+    0x9aff94d4be23d, 0xc477a140b215d, 0xd8b633afbfd4a, 0x2ae034b6234ea,
+    0xa14e46f43ebfa, 0xade61b654fff7, 0xda43eea489738, 0x14057c138af84,
+    0x4bedc741dbb05, 0x8a96742d86a01, 0xb644ee89095f5, 0xbe01bbc7c2448,
+    0x14cfbcfaa22a5, 0x4c9985e5515f8, 0x8059a37308ca5, 0xe2f4888f2b480,
+    0xe30679cdd9ad5, 0x369909f383609, 0x7b27bb07a4cae, 0xc3e9147497d38,
+    0x06e43cb317113, 0x08558f59ea560, 0x627b69db9cef5, 0x7d4b1a57fcc4b,
+    0x900260d51d026, 0x9e50ed2a77dbe, 0xa357fce8a6d90, 0xb5f35ee5f7910,
+    0xccb053a21afde, 0xd7fc8c4930394, 0xf752bae024357, 0x383a5b0af4dcb,
+    0x56f1a1a5f0d37, 0x5d1b2f41cec24, 0x6a180504439de, 0x7f562bcc2fbb4,
+    0x87bb7c7dd2f7e, 0x8e2378f5e79f6, 0x952333cb8fa3c, 0xde751aa5d0a81,
+    0xfec1f574b25c2, 0x236bbefcbff69, 0x2a4ef7f238f3d, 0x49f63644c983e,
+    0x939127a3ecd0c, 0xa8ededf6fd538, 0xc3b9cd40ace17, 0xc8893fea8126f,
+    0x70e64ff3e063e, 0x844b765c02323, 0xbecad484e7580, 0xe2eca83befae9,
+    0x24e5cdf1f3409, 0x2eb0ad2db0fcd, 0x30cfac8260c5e, 0x350d6cf3ca36b,
+    0x3dfe46712cdd5, 0x46a2da457bbcc, 0x531af1b22c76d, 0x75fc9d37499f4,
+    0x833ec24192a5e, 0xd611a5e349d77, 0xe828d847747e1, 0x0e6714e13b592,
+    0x18aeb836d4009, 0x6e031c024f7c5, 0x8133d57f76fcb, 0xcb93f474079f9,
+    0xf3dc040bf838e, 0x1474c90474b05, 0x9ddd0a29c7f58, 0xd516544aeaa04,
+    0x1400bd83af94e, 0x1bb5091bc1ec2, 0x28f0c2d8790a7, 0xd39b195dc7500,
+    0xd4b2b608f3d7e, 0xd7940aa03934c, 0xe8e70fa0afc34, 0x0fa4404bf8b55,
+    0x10968199c700a, 0x10ab90a8ca28f, 0x1b88f94a646a2, 0x1d07f329e00d6,
+    0x4a288d20ca42f, 0x4f179f877c2bb, 0x54ef7c5e6da8e, 0x65dbd3d7a66b0,
+    0x800af3cb2466b, 0x8bf7e8e65fcf8, 0x8fb731b6336bf, 0xc1b76d5dca18c,
+    0xdf11e8f6389b7, 0x8ad788c000153, 0x9965f941566f4, 0xa55922c40e57b,
+    0x11f1a7d10eaf0, 0x1af859a918532, 0x1e9856e9a6978, 0x2103c429be1f3,
+    0x348181a0cc270, 0x5234fc35ef5f0, 0x83cbe197a52d5, 0x09be89244758b,
+    0x22c0515974b18, 0x7d75c4118d1d9, 0x89d414a963b03, 0xc47713560b70c,
+    0xce4284f757998, 0xea7fbe39bb24e, 0xfc661304b5476, 0x147d7a896b051,
+    0x4f91215cac442, 0x6219661704b77, 0x6ec721867e660, 0x916b8a4c62676,
+    0xffe623c562083, 0x0836f07bafd06, 0x2792b9c2b1271, 0x3341e10b05b4f,
+    0x5b440ef593b6c, 0x6eb49dfaf5467, 0x74ef99075ac63, 0x7cb5f6c234e87,
+    0xcaf4474484c36, 0xd8de43bd972ec, 0xe2adb625c84f0, 0x03b81c24b6ca3,
+    0x4b2df70f30b0e, 0x5819f56f9cc46, 0x5bca80df6c9aa, 0x73b6d50f33dec,
+    0x948e3c210c1b4, 0xa88c24c16ae0a, 0xd361a48b1cdd1, 0xec1977ff50d95,
+    0x0730b2b5fd1a7, 0x1c19b305c5829, 0x25aa3dcac403d, 0x60678365228ac,
+    0x6827045d4f970, 0x6aadc3b7a837e, 0x71518d9f313e9, 0xa8602e8e9fe4d,
+    0xc1ac60a2d2243, 0x1fdb8007326f2, 0x2ad6f225724ce, 0x2e20d3b6f4b31,
+    0x2e2754fa28c54, 0x4cc7f29cf930b, 0x6186ee6d4dce6, 0x6ac6990ae46bd,
+    0x739397757eb2b, 0x8dcb6ff5b8a50, 0xa0c0e717197a1, 0xeb881a0d32021,
+    0x0629e28abbf80, 0x14865ba29609e, 0x28bbaa713a1e3, 0x676b16d80f417,
+    0x6815049fed4bd, 0x710dd4b048cc8, 0x806d2f91e4892, 0xa0ae722f51cbc,
+    0xa4dbb6be2b784, 0xc87ad3d4c2e1a, 0x10c7bee33d394, 0x110eccad9d626,
+    0x2ec8b63942df0, 0xe3cb3a9a7b019, 0xf979ae098799a, 0x2d8c9d7e57f2c,
+    0x500e7f90c51d7, 0x548fe3142888b, 0xa25891c921686, 0xd15781460c6d9,
+    0xdf283e5502091, 0x0ecb80e9bdbe2, 0x240bed9ef3313, 0x69ad0ad16e3df,
+    0x6aa4ac40441ec, 0x7d5182fffd205, 0xa7bc338b191a1, 0xb3873baa4cd15,
+    0xe25521a2c2fea, 0xf3b89edcd8f52, 0x4dccd15efe388, 0x77db90fbfb349,
+    0xade9960ef185c, 0xed82e30d61a53, 0xf76831792a1f4, 0x04eca5361b974,
+    0x30c52b62f2d99, 0x638764769672b, 0x8d1f946135033, 0xa4941a74ee7c9,
+    0x049e62dedf89c, 0x0a4cd896bcbdf, 0x5fa64103c6cb6, 0x87b056dd8c360,
+    0xb6da38bef275f, 0xc56d17d35898e, 0x01ebf68483235, 0x23229bf55da9b,
+    0x573b4361be3fd, 0x77fcb1287c400, 0x0bc2bad9c6bcb, 0x11e0f5ce07140,
+    0x3f73c6d9e2162, 0x61945b861909f, 0xaac179688a3e3, 0xb6f8a8ce95dd8,
+    0xe3a37c5ec57cb, 0x0aee1548cd15b, 0x0aff18961a319, 0x36268b059f1d4,
+    0x7b117d8c87742, 0x869d169dcb34d, 0x9418f2be73f50, 0x956f17e469bbc,
+    0xe544ee5a505e8, 0xecdd04edeb413, 0xf8a1bf070ff2a, 0x7e6610c8b16d3,
+    0xb2ad2ef085f2f, 0xdaf48b3274e0f, 0xe4cc8de50e006, 0x61a620f236cdc,
+    0x6368c8de7d806, 0x71005298e05d9, 0x95405bb7d2a47, 0xb969c3307a18a,
+    0xc018d11bdf459, 0xc36a6eb154c0d, 0xc55e18d02f92b, 0x08e62cdd8134b,
+    0x217bd8c6a7ba6, 0x5a0e06c20567e, 0x82cabf4774773, 0xa7f8b4bfb7c6b,
+    0xe54c8240fd078, 0x2af02aea9843e, 0x679117883dbeb, 0x72468708a7c62,
+    0x9ae273b43ba09, 0xae0844acfffe8, 0xb0e67415b606a, 0x10f5694aacfe0,
+    0x32116c4e57ba3, 0xb6beb7734b75b, 0x2de4c52c37684, 0x580b3c96da9ec,
+    0x5e921e23ea859, 0x6fc4f853d5bcf, 0xc57d3c4e8857d, 0xd2dc844250435,
+    0x4fc1d7c341799, 0x56393d9f7ad9f, 0x64dc0630e254b, 0x85f3dc8263c5a,
+    0xa090c14ed268b, 0xbad6a0301e199, 0xc82fb3bc69857, 0xcc26715e43e21,
+    0x03177a4628197, 0x16d3c037ff602, 0x49d95e25a6d26, 0x52a0b6e562a9d,
+    0x70fca9f29f245, 0xd125fc0aef0bf, 0xd7c52b7423f60, 0xfa0eae78734c8,
+    0x00059ff894b91, 0x23250703e6eee, 0x7f2cb067c1f7c, 0x8627669bdb6a7,
+    0x9108155c783f3, 0xaf7262f85ec37, 0xe7f1c42912420, 0xf6f17688713fb,
+    0x13ec12b88d9ac, 0x3a300749e4b2d, 0x3afefc4188ae9, 0x7cba751518a47,
+    0xb69c93ceef991, 0xc2f7725c7f446, 0xc5f0910e1ca94, 0xd743e2ea4aca5,
+    0x28bd2f5bc54dd, 0x8fc1092eb2f4b, 0x950e64bd7efe6, 0x9b38b0aa2fad7,
+    0xcb781534bd735, 0xd3a77c59c0e18, 0xe11b20068a4a0, 0xe6565acecd177,
+    0xec288aa8ff6c9, 0xedee5f15b62c9, 0xfbb5586b657ea, 0xfda7ddbf23778,
+    0x0c59a59175361, 0x0e4296c021959, 0x1a0967325aeca, 0x2785ad763e21e,
+    0x37546ac2e3453, 0x657e93b2078b7, 0x65df3c65fd884, 0x84f6db30478cf,
+    0x93a6a29f26cfc, 0xaa36edc623bc6, 0xf17469a27fcee, 0x06fda9f68221d,
+    0xa0e61053652c2, 0x0ae33c1515444, 0x31d1a2301761f, 0x695e75374502a,
+    0x7766e550377fa, 0xa3793fdaf15e0, 0xba0b49847a48c, 0xd426de5c7b554,
+    0xe728ccf0969ad, 0x0db4c4833604f, 0x699e13e5c3826, 0x6b5924677abb1,
+    0x7fe27fddf6c09, 0x871b24d56a4d0, 0x994757a4d6aab, 0xd40e97de90e3c,
+    0xe0ae99c8b0665, 0x194b8baf0d9b2, 0x6c8803ce9bcc8, 0x808fd118b41a2,
+    0x85463ee77154c, 0xafbaa0df21ca6, 0xc56f450cba85c, 0xef3012cac98a1,
+    0x34a17ad821480, 0x94e451e464d97, 0x0ce5daab70a98, 0x5930258f6caec,
+    0x7a0897cdcf3e1, 0x9d4449d33b125, 0xb71e9ece0e5c9, 0xbd635d93ba25c,
+    0xc549bddccba7b, 0x7f146a5900286, 0x8119b52587979, 0x87c960714c45f,
+    0x923f733078246, 0xa8be87cd02e8c, 0xd5fdc14f1183b, 0x52c1c8dd4ae62,
+    0x7a863b41a3da9, 0xd4f1148682b00, 0x089c0d0f94c8a, 0x11ecdf93dc1d1,
+    0x280629935f707, 0x2f2cefa5303e4, 0x3d968fdcd60fa, 0x7a391db584a61,
+    0xa7359ab8bffe4, 0xcbc42f6e0f620, 0x1b9943ea32e83, 0x1df7c49984756,
+    0x3602816e5ab63, 0x43faeac2c8f8e, 0x52f3ae12c9130, 0x6d41245ea43dd,
+    0x6db150155179b, 0x83b1485e062fa, 0x169f490b77b62, 0x38d945641f714,
+    0x8941ef349d656, 0xaf42654d5e1e2, 0xb66e006c88cd9, 0x3d4979bb4b460,
+    0x4753c45c089c8, 0x4cf6724e3caf3, 0x695f96d4f3fa9, 0x733b795dfed2b,
+    0x7b92aa0ffdca9, 0xb3e86d3b87977, 0xe01ce7ff6e33c, 0x2e855a48b4bba,
+    0x4aa6f4951f9b4, 0x611d6a4380ef3, 0x93b4ce3a2c301, 0xb2d40817f60b0,
+    0xcf7032dda44db, 0x26b747e822161, 0xd1aceedf9bf9e, 0xe8ad20336f487,
+    0x90337f4137749, 0x9cef4c0c93f6a, 0xc47290e070f66, 0xc6c07fb790956,
+    0xca9b1e4a95a75, 0xd544424389920, 0xf013bf82c45b0, 0x2619c307f0bd3,
+    0x386df9f35e699, 0x89bb1ce88f0bc, 0xf1accd1f2b916, 0x5df10af54e328,
+    0xb8c10dcde49b3, 0xee3ba225e4199, 0xff19e49a49abe, 0x0b09bd032debd,
+    0x1b08e2caf8094, 0x6fcdbd03cf238, 0xad5a994043878, 0xb72e609cf1ea7,
+    0xe579a241a8f82, 0x3bf6eb8f74f19, 0x8d37886e29b20, 0xa72be61ebda15,
+    0xbc56a664906aa, 0xcca4cce0c32ef, 0x1d9464feac707, 0x1f173eb499cc6,
+    0x2006488726f56, 0x2c6819cd05e09, 0x35d930c06b125, 0x56e6bbede8e24,
+    0xe0959d11717f3, 0xe907827d520ed, 0xf51973cd82541, 0x0628609df35e2,
+    0x1420044fae27f, 0x7c16a33237156, 0x9f65ca5d7daef, 0xa2e65ec743b8c,
+    0xa967ca3da9e03, 0xfdb823cb567be, 0x3b58334cbf279, 0x6c38878898274,
+    0x6caf8c59021cd, 0x70d2c7a4bea5f, 0x842c10519528e, 0x9180820dabbf8,
+    0xd2e719f2eeda1, 0xe5b8ece79cb81, 0xf74a97aa5f67f, 0x28a4ffef8f881,
+    0x547bbc9f09b75, 0x8bf8310b2a763, 0xd4fd8560a020c, 0x12e710dc0642b,
+    0x2e351bb27909f, 0x4922d2028e1f9, 0x5e7bf30e32aea, 0x89bf54f85834f,
+    0xe467575dddab4, 0x01fd5e84da0f8, 0x0d026fc2fc9b9, 0x11668f6c6de32,
+    0x3e2f6c79431ee, 0x6c11876c4dfe2, 0x816afca58ad86, 0x8b555e56a3556,
+    0xb82647ec13951, 0x2e3d125e25964, 0x3be5e4efac694, 0x9327bac995daf,
+    0xaa333021454fc, 0xce796655d35da, 0xe036a0dd70c9b, 0x032213c4c497e,
+    0x0f3ef89429148, 0x7817100638f66, 0xdae1a2f59e0a4, 0x29019976a059c,
+    0x3034ecf0cdc1f, 0x31bc1b9b38609, 0x386e6853bf687, 0x9701ad712dac7,
+    0xc62bcd8a8a0db, 0x40e29b0b23df8, 0xbca4de48d7b79, 0xe2c5f0a73ef52,
+    0x3bf94f4c1d7e2, 0x50e63e1619103, 0x6d5de56dce5cc, 0xa3686629cbf91,
+    0xf7d9bece78d60, 0x0915dc9ecdb7e, 0x1a2dc785d1928, 0x3180d9647f40a,
+    0x38dd2288a5c42, 0xa04a47a6a3349, 0x0ab0f599787ef, 0x31822dd02aa2d,
+    0x3d9f6324370e5, 0x80151d5fe0db7, 0x8a42ca4c6aab6, 0xcdd68c80d2e8b,
+    0xe511d7ee18eb2, 0x2438db8ae6707, 0x38c93faf53dfb, 0x3feb6eebc0ed3,
+    0x4aac2270071ae, 0x656e36ae8fa6c, 0xcf497574380d5, 0xd44ff675c8fa4,
+    0xe436d20a5e70c, 0xed8750610d7a0, 0x76b44f2a9a13e, 0x80f57c37cfbe1,
+    0xa5d8129a25fe1, 0xf3fb89c930a62, 0xf400627f7ae41, 0x489b7670d4553,
+    0x54f1a48a6dba0, 0x6e0361376d7f9, 0xa635a4cfa7f08, 0x611ed20b96c7b,
+    0x65b0a676b8e25, 0x6d8b255b8ebc9, 0xb9f717b1bb997, 0xc53b895a0caad,
+    0xccfb0dfb2a136, 0xe80596b0161f0, 0x636296bb7fdda, 0xa8b583d716d15,
+    0xb788d22b2628b, 0xc25f06338aac6, 0xda783ade8dd97, 0xdb2fff7348f25,
+    0xe6d8639782f06, 0x3470c718d9b87, 0x53c11575e9803, 0x593c1acb95480,
+    0x950e03cdd555d, 0xc3f32524057af, 0xc9300879321ca, 0xd2e7728f15139,
+    0xe351220b9e59c, 0xf2d6e72013021, 0x17cd4a7eccaee, 0xa9ebb52b4a5be,
+    0xabd1603af427d, 0xec43fbf8cd554, 0x19765b36c7c34, 0x475a64409350b,
+    0x60e66f85effbb, 0x62f28c1a536bf, 0x720b88dea04e7, 0x83006cf950b37,
+    0xa0ed72b98f514, 0xac98207403015, 0xbc7128e4abc5a, 0x2ca4a2e7bb735,
+    0xa9ae1aae3c3f9, 0xdeaa54ac28cc1, 0x0fcf1c9780540, 0x2d48933a8c3fe,
+    0x5a409e01c772e, 0x9f71a573376da, 0xa4d4768b50b86, 0xa56eeff438f4f,
+    0xbf7830c88bb8d, 0xd9083cd4cd5b5, 0xed8aa592fa20f, 0x02672ac717bc4,
+    0x13dd8ac17a0b9, 0x962cdca1d9917, 0xbde6c5aa38c39, 0xbf2056a34db5a,
+    0xcf1a8dbf2b6b4, 0xeed96de9c00be, 0xf121f54df75cc, 0xfd15102cedd3f,
+    0x639892eb46698, 0x7173ce5b64b26, 0xe1643b5af92e7, 0x008ec08e7fe84,
+    0x3e5da24ad5876, 0x5cedcf05e7437, 0x824987e39cd74, 0xa947a7e76b9cb,
+    0xaf4fb6536cab5, 0x026acc8aa0df0, 0x360c72cf87dbe, 0x46f32f98b3438,
+    0x4e68e7bb2b46f, 0x718e06be0db9c, 0xc1c9bdc69df76, 0xe9d323f352130,
+    0x7371d2671221e, 0x979f160d3af27, 0x0c5fc9fd189e2, 0x15d1d62765f8d,
+    0x260385be3acaf, 0x26caa8bc4987c, 0x73edcd41a3110, 0x819689dd87ba2,
+    0x8638232c47917, 0xd55ded6875d41, 0xf7f86ddbaff0e, 0xfb15eb61e2b0c,
+    0x19bf453a5f6f2, 0x1f5c3b350344d, 0x2bae31a1a2497, 0x38955af4b47b5,
+    0x411b1105e8d41, 0x5df008a5dfbc5, 0x97ef37fc83c84, 0xa237abefa0fd8,
+    0xe326e385011eb, 0x03803d187dfbe, 0x0e8d7bcfaecab, 0x62fcc1c4375e5,
+    0x68282cae0b5a2, 0x962374be813bb, 0xa15ae3e06a28b, 0xc115ef2f5e259,
+    0x29de41bdd04ac, 0x2da7100ef40f5, 0x774f6a6ec9995, 0x8eae899cf742e,
+    0xa79ee0b8acae7, 0xc64109a0e40ba, 0x8298bc2b9f7a6, 0x2ca2fe97f828e,
+    0x59231dfba2aea, 0x735a3f7cea995, 0x804f64363d74d, 0xaf1c8bdde0e1e,
+    0xc080f6fcfe48b, 0xcd45d42b9f68e, 0x0cb8c943e86a3, 0x1f6352c4775d7,
+    0x2419aa9d926e3, 0x2c431f3bffda6, 0x3d25b2fbf2531, 0x4e6ae4d87c2f6,
+    0x92c7f659a9f90, 0xe338a1b7f1dc2, 0x039fd5c13c242, 0x2da7522141f17,
+    0x34e35e282b504, 0x7ac4ea9a4f917, 0xc7fac24bc0c37, 0x2c0cbd0117d7e,
+    0x2c91fb6fb8986, 0x6357a7cfe71ff, 0xc9b4c40457262, 0x0b98ecf8095d6,
+    0x16318a139c6ed, 0x34429328f763d, 0x34f3aa8f3a16c, 0x913348ddf87ec,
+    0xa5ce9cd8a9c91, 0xe804ce67d1197, 0x0742783c8aa9b, 0xbe6b935d020f4,
+    0xfe7790375ee19, 0x206d27844985b, 0x3308d65d51d04, 0x934d9f29466c3,
+    0xf3dc172c5d8ac, 0xf91646c816056, 0x169946956275b, 0x28cf1132d0c91,
+    0x3d626e9a89395, 0x630d57c0dc241, 0x9f4c3ff959606, 0xd127bda3f1521,
+    0xf16f51f05df6d, 0xfd555f4e1b34b, 0x0efc241ab221d, 0x244619fa28195,
+    0x32986c8762255, 0x3822fc31a5e4d, 0x389306f7dfe1e, 0x7876af319376a,
+    0x901c21823ee56, 0xc8b4878e096ed, 0xc9bd2c4a2c6b8, 0x214cc0c5dec89,
+    0x29e856839ad77, 0x339e6fe31ca1a, 0x3c3efb9ea5d0f, 0xc04adaa262100,
+    0x7fdb1e959b368, 0xac65d9a1f14c2, 0x17d737af2d23e, 0x6623db6e464ff,
+    0xb4b0f215f90cc, 0xfbe01ab58bce2, 0x0d79608bce544, 0x2ec6e231f9725,
+    0x301aaf1df415d, 0x4e0b68c960189, 0x65d5e99bb2315, 0xb5087f78332fa,
+    0xea409cdb36453, 0xfef5befca26f9, 0x564b1c4918742, 0xc9c438decf6f3,
+    0xcd1eac4883c39, 0xd947e0484eb94, 0xeae92b554b8ab, 0x0bc805384fa60,
+    0x314e2cb8b3795, 0x561b21a678248, 0x7d91f2b08b101, 0xa1557d3f7945b,
+    0xe9635fba4dbf8, 0xf21b2a7c8142f, 0x10c41688d32ff, 0x301b8adb5743f,
+    0x33ba71f48a209, 0x359e2049b1cd1, 0x3f311ce73df7c, 0x61975c5ac21a6,
+    0x8452d83c0c1c2, 0x9d02c3c108af9, 0xd1e73e9f6e490, 0xe827594c14a0d,
+    0x1e6f500470709, 0x38b40a1a9f487, 0x8319e106c0285, 0x94e9b8c4d3134,
+    0x9773541af3d2a, 0xcf4db62fb5aea, 0xf483e7c4c8b22, 0x0293e7f669f07,
+    0x02e6152b42a4b, 0x302f66942fd44, 0x47f018b26a8ea, 0x821695261de1a,
+    0xa9b58793b4fe6, 0x046d095301577, 0x68fe623d14f7f, 0x8192952d86597,
+    0x8843c0c3e0e4a, 0x9e9eef52d2345, 0xf361f8bc6b82e, 0x052a983858540,
+    0x0e895b280f3d2, 0x169b85c501404, 0x80554903bd22f, 0x6920a37f68a6f,
+    0x6d0610f83e552, 0xd07b2b66f0988, 0xd6450cd7a16c1, 0xd9afd78a81e9a,
+    0xde46f27819139, 0xf2eb6579d06d4, 0xf86f31fff99cf, 0x0012e816e9a9b,
+    0x0c74861fa3888, 0x184fb5f3ceb8e, 0x52fed093b7e62, 0x5dd28f1a9e641,
+    0x07fd2842c6bd7, 0x23692df21db00, 0x3311676d57617, 0x4b281f44fbd02,
+    0x5dbeee15d9242, 0x902ffa265d46e, 0xc332d20ddec48, 0xc475686f673fc,
+    0xcd185eb19d5fe, 0xcd50ba16cad7c, 0xcf44ad686cdfe, 0xdf04ce45817bd,
+    0xdf4d1e2281607, 0x1b0376d28a33c, 0x32ac3de5f9a75, 0x048189ef81865,
+    0x10dca30c16f6a, 0x25aad8ac5b9d2, 0x4cf25762a4f65, 0x5b2b12af398af,
+    0x5c342c896811a, 0x8c482d3a5f6f1, 0x9acd407cb9987, 0xe378576bcb2e8,
+    0x07df438492476, 0x1740d958a644c, 0x268f67d30a4e3, 0x3600ac9519de6,
+    0x74f163d3edd8d, 0x777429b9423ad, 0x992115a9b3f68, 0x9bea534ae9b8d,
+    0xa3ae5c74b9142, 0xc7e08b1dddf03, 0xe01c258be4b52, 0xece274615a40e,
+    0x0b4338d30040b, 0x1516db964b088, 0x1f09fd260034c, 0x50441d53221b4,
+    0x11ee12c6b542e, 0x23f2af464b234, 0x5bf511ce1df1a, 0x5f668d5867317,
+    0x97f8e39618d66, 0xcc4c2c54f03a1, 0xcd3325f5f202d, 0xe74f548562347,
+    0xf90fb7e47f519, 0x0b0d09d53d4a9, 0x0e4831afac46e, 0x4d71cf675b6d1,
+    0x5161e383adc01, 0x5bfed93cae708, 0x680f3b9e8767b, 0x7db133f1e932f,
+    0x91891c82a981b, 0x0d67da4eaba84, 0x6e7fd3682efc7, 0x7426354590741,
+    0x776da15594d48, 0x7896af1a3949a, 0x9e9dc3bdb0f71, 0xbac07f2c48536,
+    0xd32ae4835b532, 0xd596e08010eb0, 0x09d02f2ce91ae, 0x1a81c80c282cf,
+    0x2344c0dc95acc, 0x28988b61e3fb1, 0x3c526a75fead4, 0x507cdbbb10b41,
+    0x97dde5ce7d93c, 0xea6cf32115be8, 0x806bb0af385e2, 0xceee036beb93a,
+    0xd7e2689227a7c, 0xe66b12782c539, 0x5bf8757264a55, 0xa38f82b103d9a,
+    0xf24c29e4764a2, 0x0a02f3b0071ff, 0x163d0663bb966, 0x2453b3c3d53aa,
+    0x6c3a1d365619a, 0x962452137966f, 0xb34934a3f00a1, 0xc271469ec95a4,
+    0x87e0cb965e6b8, 0xa6f37f5dac64b, 0xb1b58741441b7, 0xfd8b7d6559f5e,
+    0x102edd4784409, 0x17de7119bf98b, 0x6fb5d9028597c, 0xcf40173399263,
+    0xe8f4a304e3628, 0x5c3b8da218506, 0x949ca3753fb4c, 0xe0dae4b2ac1ae,
+    0xf20cc259ce70b, 0x2ffda707cdeb4, 0x434b9d0d06d6b, 0xece32bf482760,
+    0xf66e6c8e1b8ba, 0x0093adc9a0f78, 0x02bf45817e6fe, 0x0b34994eb58a8,
+    0x1358e4b230c9e, 0x2b9619095ff1d, 0x73e1493e3ea7a, 0xe621ca11c8d99,
+    0xea1b4a10d8ce8, 0x4e0ac56499c4e, 0x585fc6c013948, 0x646f00addf566,
+    0x80a31ae93cd26, 0x8e9a71bf4009a, 0xcb59a26890e60, 0xeb23467e250df,
+    0xee2f185bda1b9, 0x13bcf83740a7a, 0x1724a8b662e56, 0x81e5aa053e6e2,
+    0x996a2be2ab0ce, 0xa0e9dea47ec27, 0x60efc4650ee43, 0x62d8775bc5226,
+    0x67b40d4abb8d3, 0xac8b8702d8c27, 0xad0bde176dd3c, 0xc96277b4616dd,
+    0xe101e531c50d0, 0xf8ff898a90ff9, 0x5e4ab9b984024, 0xd105bc6290260,
+    0xda75fb6690115, 0xdef2c8148fbc4, 0xffb51173a35f3, 0x113814cc0472c,
+    0x5283daafe3131, 0xaf17c7875fe9e, 0xc1d89e80a164a, 0x1bd135b5be589,
+    0xcb75d401d93be, 0x1691e43d4e42e, 0x4952577c85254, 0x56f34d19a5a46,
+    0xa7c8b43f7a496, 0x016068319b7b8, 0x36a704bcac35f, 0x65ec17c7e0504,
+    0x966ba9b130ccf, 0xb94a808c668f8, 0x19ccd8f34be51, 0x29cbb9313f289,
+    0x2c873d4d1abee, 0x3484f63b691b2, 0x65867ccb67687, 0xabb639546fc2b,
+    0xcd076ee5c845b, 0xcfccc9079bf30, 0xfde7296a32204, 0x0d903ad4d1182,
+    0x33a6909a19870, 0x74e370dba069b, 0x82090cb3d9b09, 0x927030b942c72,
+    0xc75edd1d197b6, 0xfe5174f35500d, 0x178e623cc6227, 0x2563aecf3ec5a,
+    0x2940f9c9d7460, 0x5b98eb09a2340, 0x635bc81673fdb, 0xecdcf4784bc79,
+    0x5d4f6fbd209a7, 0x5f31cd5be71a3, 0x5f6d1ab27eda7, 0x65df4c58f4f91,
+    0x01bd7a7fa8338, 0x31038dbc958bb, 0xc413ecdb29f17, 0xf189606e8049b,
+    0x129d02bbf7b6c, 0x20177487acc40, 0x2a5ae3d93b679, 0x34f88ce83dc35,
+    0x4ed7750f84719, 0x59bc493069d8f, 0xa503bd4575486, 0xb3cc26be11d9a,
+    0xb909e5ab941e1, 0xc80c09e380c53, 0xe3dabc53308ca, 0x075e15da6baf2,
+    0x1003d5cbb8203, 0x6ac88d4e91aa0, 0xe2a904b1eb6b4, 0x04ef32f376bcf,
+    0x16356c539a8fc, 0x343f8a0286fd8, 0x7317f70e26510, 0xe52478c2e1066,
+    0x0ae05de4a0546, 0x3eb6193df8f7b, 0xbf3531ef0b966, 0x5da1a0e504c1c,
+    0x66670b8dffeb0, 0x85b9320c2e371, 0xbf990ad8bbd60, 0x086177ee715d5,
+    0x11b9950190992, 0x204aa346b8f06, 0x8ae6ac098ff90, 0xb8a0516b7e5be,
+    0xc24b2e1904b87, 0xd2fdb4dd4b3ad, 0xedcdaae381ea2, 0x0a69349fe04f1,
+    0x26c9d3d700b03, 0x70ae70c456236, 0xb0972fec4a176, 0xd546b8bdc8a77,
+    0xd5b30befe4e34, 0xe9c1c0f77b5bd, 0x029bd1ec5ef88, 0x81f62e30b3e19,
+    0xb8188b53bb537, 0xe417dadbd4e19, 0xe8e61dbfbb392, 0xeaa0d2c1f96e0,
+    0xf2a7443d03c0e, 0x00d751f7ad73d, 0x2f9722eb50715, 0x302165fb7fc38,
+    0x700b49ee90908, 0x819789957a95f, 0xf67590030bd69, 0xf6a5c5410bc45,
+    0x12690f137bca6, 0x2a43dfdd1953b, 0x7adc3b29ca3a5, 0xad32afc7efa7d,
+    0x5c9abeaad30f6, 0xd85f442cdfe0d, 0x0503c2651fc19, 0x19185ef87b273,
+    0x1fdd62860a342, 0x560290aa08af9, 0x6e6eca7949d75, 0xc81a182aa969e,
+    0xc96492d278f1a, 0xd740e0c10245f, 0x00fbb0fdaa335, 0x0e92c1f1112a3,
+    0x559ba14e3e3eb, 0x76fb107dcd8ca, 0x7928a1473cc82, 0xa88fc990cc8b7,
+    0xb6691e3c0adec, 0xbab80de605bf3, 0xcad24ee1fb8d2, 0xef6d3a1992229,
+    0xf8536cf378246, 0x222e102a22c16, 0x688e8d04d8bd4, 0x7e6ff80f1adb6,
+    0x861e5fc458a1e, 0x87de0ac463f73, 0x89fef82fd46e7, 0xb5c8aeaff61d0,
+    0x0efc9116a1a14, 0x3b14eccbb2f2e, 0x457623b900b56, 0x98f9f67ea6a27,
+    0x9e6fd9eb40f61, 0xd87d60279eaf7, 0x0c26ee5bbd152, 0x5462bc7e57cab,
+    0x99df7e852dd8c, 0x9f84bb9772776, 0xae246d9e0c0ea, 0xf195589a55866,
+    0x0619eda7391b8, 0x0c970b7b02b8e, 0xacf24f827cd48, 0xd2aceee8ac0ab,
+    0xe214cb70ee554, 0xe951419b12292, 0xf88167706735e, 0x1f22581c679e7,
+    0x2b667b6519f0e, 0x301bcbba9b0b2, 0x3402913bb29eb, 0x3ac81fdcef5f8,
+    0xa34daaf1bd2d0, 0xa9ab67409f487, 0xbf7d6c8fad0a0, 0x05a57bb883c07,
+    0x07d414058cf27, 0x5591233730a81, 0x7e590621e9577, 0x7f24940a1780d,
+    0x9c01c41dedc0c, 0xc0e50dd645ac1, 0xfe9e138f03f16, 0x0c5ea8e90213c,
+    0x3268d06e57486, 0xb1bde623dd490, 0xdcd2a1be5a2fa, 0x040995ee52534,
+    0x152e243354f22, 0x3bcefb40c2d48, 0x896b339dba405, 0xbc46509c01a58,
+    0xc75f98f9c2137, 0x2e3eb878a15b8, 0x46eca7431bcf8, 0x65eb2ee59cfbc,
+    0x7e8392312804d, 0xfe31a6e362bac, 0x1abf9bb3ca8ed, 0x6f2679a03f1ab,
+    0x7fa4e05542e1b, 0x3e7ebb64f3310, 0x6f8a612db0cb9, 0x71561d262c78a,
+    0xcdda24299853f, 0x58f6572d0f3e3, 0x7594b59355090, 0x7728cd259545d,
+    0xab76395378d16, 0x729386bc861d0, 0x7aec465cdc62f, 0x7b34cd1ff1b04,
+    0x4677dda2eb585, 0x4cc7f6621571a, 0x655b63afd35a4, 0x8c489a04e78a0,
+    0xec85564841220, 0x074b6ef69f01b, 0x1c836c51dfb46, 0x5bb60f720263c,
+    0x888a72639a436, 0xe31ca01769188, 0x204532016be85, 0x2ef85584efe89,
+    0x94939c8fa6f9e, 0x0afbf6d6322de, 0x242f70eb04f4f, 0x55edc8032da57,
+    0x5c2419ed7d975, 0x7915c301d836c, 0xa4ba0bd7e9db3, 0xc56ae41d8590e,
+    0xedc2fe22eac0d, 0xf75559b7270dc, 0xfd5f6134b9df8, 0x29019eac87f34,
+    0x3cb03d65b6db8, 0x4f535b66faa89, 0x9cf1b16ee772a, 0xb8b3ae33e6a84,
+    0xc81927875cbaa, 0xce43d64567262, 0xfd8609abe2c5a, 0x22c568f81722e,
+    0x7c2ddce320903, 0xab10bf9de8184, 0xb3e3550f9f5d5, 0x083dc2db3a315,
+    0x2cfaa260b930f, 0x6a94d5512e048, 0xc71b51d617e59, 0x1699a999dfafd,
+    0x59174472e02d2, 0xba1ff8c1a2639, 0xc73d80bf5fede, 0x28571ebcdcba0,
+    0xcac908b4b4083, 0xce1f1c2f1a04f, 0x502b1f2b17db9, 0x5359c840ebaec,
+    0xbc287ecb0f03a, 0xda191034270ef, 0x1279347956a6b, 0x1e55adb2024b3,
+    0x983e4bddab9a2, 0xc121fd5291a55, 0x22032d9e2f2c0, 0x2d2b3704e3bc7,
+    0x7293c5950c44f, 0x897090f422bdc, 0x939968d352ac1, 0xcca055b1d0f97,
+    0xf72fae4449847, 0x26a31ac36eabe, 0x5705bd0065501, 0xdaa99674a1dd6,
+    0xf9fe9545a7eba, 0xa16a3847adc76, 0xb08bffe5686e1, 0xbed69a179fc15,
+    0xc0d162da0e26d, 0xcdc7a163acc66,
+];
@@ -0,0 +1,21 @@
+//! A Rust port of the AprilTag detection pipeline.
+//!
+//! The modules follow the structure of the C library, so that each
+//! function can be compared against its C counterpart. The outputs
+//! follow the C library up to floating point rounding.
+//!
+//! The quad detection stage is not part of the public headers of the C
+//! library, so it is also built with the C backend to capture its
+//! intermediate results. The pose estimation is also built with the C
+//! backend to estimate the poses of tag bundles.
+
+#[cfg(feature = "pure-rust")]
+pub(crate) mod detector;
+#[cfg(feature = "pure-rust")]
+pub(crate) mod families;
+#[cfg(feature = "pure-rust")]
+pub(crate) mod pnm;
+pub(crate) mod pose;
+#[cfg(any(feature = "pure-rust", test))]
+pub(crate) mod preprocess;
+pub(crate) mod quad;
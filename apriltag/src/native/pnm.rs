@@ -0,0 +1,136 @@
+//! PNM image loading, the same as `image_u8_create_from_pnm()`.
+
+use crate::{error::Error, image_buf::Image};
+use std::{fs, path::Path};
+
+/// Load a binary PBM, PGM or PPM file as a gray image.
+///
+/// Color pixels are converted by `(r + 2g + b) / 4` and 16-bit samples
+/// keep their most significant byte, the same as the C library.
+pub(crate) fn read_file(path: &Path, alignment: usize) -> Result<Image, Error> {
+    let bytes = fs::read(path).map_err(|err| Error::CreateImageError {
+        reason: format!("failed to load '{}': {err}", path.display()),
+    })?;
+    decode(&bytes, alignment).map_err(|reason| Error::CreateImageError {
+        reason: format!("failed to load '{}': {reason}", path.display()),
+    })
+}
+
+pub(crate) fn decode(bytes: &[u8], alignment: usize) -> Result<Image, String> {
+    let mut header = Header { bytes, pos: 0 };
+    let format = match header.bytes.get(..2) {
+        Some(b"P4") => Format::Binary,
+        Some(b"P5") => Format::Gray,
+        Some(b"P6") => Format::Rgb,
+        _ => return Err("not a binary PBM, PGM or PPM file".to_string()),
+    };
+    header.pos = 2;
+
+    let width = header.next_number()?;
+    let height = header.next_number()?;
+    let max = match format {
+        Format::Binary => 1,
+        Format::Gray | Format::Rgb => header.next_number()?,
+    };
+    // A single whitespace separates the header from the samples.
+    let data = &bytes[(header.pos + 1).min(bytes.len())..];
+
+    let sample_size = match max {
+        1 if format == Format::Binary => 1,
+        255 => 1,
+        65535 => 2,
+        _ => return Err(format!("unsupported maximum value {max}")),
+    };
+    let channels = if format == Format::Rgb { 3 } else { 1 };
+    let row_len = match format {
+        Format::Binary => width.div_ceil(8),
+        _ => width * channels * sample_size,
+    };
+    if data.len() < row_len * height {
+        return Err("the file is truncated".to_string());
+    }
+
+    let mut image =
+        Image::zeros_with_alignment(width, height, alignment).map_err(|err| err.to_string())?;
+    for y in 0..height {
+        let row = &data[y * row_len..(y + 1) * row_len];
+        for x in 0..width {
+            // Take the most significant byte of 16-bit samples.
+            let sample = |channel: usize| row[(x * channels + channel) * sample_size] as u32;
+            image[(x, y)] = match format {
+                // Black is one in PBM files.
+                Format::Binary => {
+                    if (row[x / 8] >> (7 - (x & 7))) & 1 != 0 {
+                        0
+                    } else {
+                        255
+                    }
+                }
+                Format::Gray => sample(0) as u8,
+                Format::Rgb => ((sample(0) + 2 * sample(1) + sample(2)) / 4) as u8,
+            };
+        }
+    }
+
+    Ok(image)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Binary,
+    Gray,
+    Rgb,
+}
+
+struct Header<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Header<'_> {
+    /// Parse the next decimal number, skipping whitespace and comments.
+    fn next_number(&mut self) -> Result<usize, String> {
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'#') => {
+                    while !matches!(self.bytes.get(self.pos), Some(b'\n') | None) {
+                        self.pos += 1;
+                    }
+                }
+                Some(byte) if byte.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .ok_or_else(|| "malformed header".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_gray_and_rgb() {
+        let image = decode(b"P5\n# comment\n3 2\n255\n\x00\x01\x02\x03\x04\x05", 4).unwrap();
+        assert_eq!((image.width(), image.height(), image.stride()), (3, 2, 4));
+        assert_eq!(image[(2, 1)], 5);
+
+        let image = decode(b"P6 1 1 255 \x10\x20\x30", 1).unwrap();
+        assert_eq!(image[(0, 0)], 0x20);
+
+        let image = decode(b"P4\n10 1\n\x80\x40", 1).unwrap();
+        assert_eq!(image[(0, 0)], 0);
+        assert_eq!(image[(1, 0)], 255);
+        assert_eq!(image[(9, 0)], 0);
+
+        assert!(decode(b"P5\n3 2\n255\n\x00", 1).is_err());
+    }
+}
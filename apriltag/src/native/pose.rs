@@ -0,0 +1,599 @@
+//! Tag pose estimation, ported from `apriltag_pose.c`.
+
+pub(crate) type Mat3 = [[f64; 3]; 3];
+pub(crate) type Vec3 = [f64; 3];
+
+const IDENTITY: Mat3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// A rotation and translation from tag to camera coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RawPose {
+    pub rotation: Mat3,
+    pub translation: Vec3,
+}
+
+/// The detection and camera parameters, the same as
+/// `apriltag_detection_info_t`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PoseInput<'a> {
+    pub homography: &'a Mat3,
+    pub corners: &'a [[f64; 2]; 4],
+    pub tagsize: f64,
+    pub fx: f64,
+    pub fy: f64,
+    pub cx: f64,
+    pub cy: f64,
+}
+
+/// Estimate the pose from the homography, the same as
+/// `estimate_pose_for_tag_homography()`.
+pub(crate) fn estimate_pose_for_tag_homography(input: &PoseInput<'_>) -> RawPose {
+    let scale = input.tagsize / 2.0;
+    let (rotation, translation) =
+        homography_to_pose(input.homography, -input.fx, input.fy, input.cx, input.cy);
+
+    // Flip the y and z axes so that the camera looks along +z.
+    let fix = [1.0, -1.0, -1.0];
+    RawPose {
+        rotation: std::array::from_fn(|row| rotation[row].map(|value| value * fix[row])),
+        translation: std::array::from_fn(|row| translation[row] * scale * fix[row]),
+    }
+}
+
+/// Estimate up to two poses by orthogonal iteration, the same as
+/// `estimate_tag_pose_orthogonal_iteration()`.
+///
+/// The second pose is the other local minimum of the object-space
+/// error if any.
+pub(crate) fn estimate_tag_pose_orthogonal_iteration(
+    input: &PoseInput<'_>,
+    n_iters: usize,
+) -> ((RawPose, f64), Option<(RawPose, f64)>) {
+    let scale = input.tagsize / 2.0;
+    let p = [
+        [-scale, scale, 0.0],
+        [scale, scale, 0.0],
+        [scale, -scale, 0.0],
+        [-scale, -scale, 0.0],
+    ];
+    let v = input
+        .corners
+        .map(|[x, y]| [(x - input.cx) / input.fx, (y - input.cy) / input.fy, 1.0]);
+
+    let mut pose1 = estimate_pose_for_tag_homography(input);
+    let err1 = orthogonal_iteration(&v, &p, &mut pose1, n_iters);
+
+    let second = fix_pose_ambiguities(&v, &p, &pose1).map(|rotation| {
+        let mut pose2 = RawPose {
+            rotation,
+            translation: [0.0; 3],
+        };
+        let err2 = orthogonal_iteration(&v, &p, &mut pose2, n_iters);
+        (pose2, err2)
+    });
+
+    ((pose1, err1), second)
+}
+
+/// Estimate the pose with the least error, the same as `estimate_tag_pose()`.
+pub(crate) fn estimate_tag_pose(input: &PoseInput<'_>) -> (RawPose, f64) {
+    let ((pose1, err1), second) = estimate_tag_pose_orthogonal_iteration(input, 50);
+    match second {
+        Some((pose2, err2)) if err2 < err1 => (pose2, err2),
+        _ => (pose1, err1),
+    }
+}
+
+/// Recover the rotation and translation from a homography, the same
+/// as `homography_to_pose()`.
+///
+/// The camera is assumed to look along the -z direction.
+fn homography_to_pose(h: &Mat3, fx: f64, fy: f64, cx: f64, cy: f64) -> (Mat3, Vec3) {
+    // Every variable is proportional to the scale factor of H.
+    let mut r20 = h[2][0];
+    let mut r21 = h[2][1];
+    let mut tz = h[2][2];
+    let mut r00 = (h[0][0] - cx * r20) / fx;
+    let mut r01 = (h[0][1] - cx * r21) / fx;
+    let mut tx = (h[0][2] - cx * tz) / fx;
+    let mut r10 = (h[1][0] - cy * r20) / fy;
+    let mut r11 = (h[1][1] - cy * r21) / fy;
+    let mut ty = (h[1][2] - cy * tz) / fy;
+
+    // Require the rotation columns to be unit length. The C library
+    // takes the square roots in single precision.
+    let sqrtf = |value: f64| (value as f32).sqrt() as f64;
+    let length1 = sqrtf(r00 * r00 + r10 * r10 + r20 * r20);
+    let length2 = sqrtf(r01 * r01 + r11 * r11 + r21 * r21);
+    let mut s = 1.0 / sqrtf(length1 * length2);
+
+    // Require the tag to be in front of the camera.
+    if tz > 0.0 {
+        s = -s;
+    }
+    for value in [
+        &mut r20, &mut r21, &mut tz, &mut r00, &mut r01, &mut tx, &mut r10, &mut r11, &mut ty,
+    ] {
+        *value *= s;
+    }
+
+    // The third column is the cross product of the other two.
+    let r02 = r10 * r21 - r20 * r11;
+    let r12 = r20 * r01 - r00 * r21;
+    let r22 = r00 * r11 - r10 * r01;
+
+    // Make the rotation proper by polar decomposition.
+    let (u, _, v) = svd(&[[r00, r01, r02], [r10, r11, r12], [r20, r21, r22]]);
+    (mul(&u, &transpose(&v)), [tx, ty, tz])
+}
+
+/// Refine the pose by minimizing the object-space error, the same as
+/// `orthogonal_iteration()`.
+///
+/// It accepts any number of points, so that it also refines the pose
+/// of a tag bundle. It returns the error after the last step.
+pub(crate) fn orthogonal_iteration(
+    v: &[Vec3],
+    p: &[Vec3],
+    pose: &mut RawPose,
+    n_steps: usize,
+) -> f64 {
+    let n = p.len() as f64;
+    let p_mean = scale_vec(&p.iter().fold([0.0; 3], |acc, p| add_vec(&acc, p)), 1.0 / n);
+    let p_res: Vec<Vec3> = p.iter().map(|p| sub_vec(p, &p_mean)).collect();
+
+    let f: Vec<Mat3> = v.iter().map(calculate_f).collect();
+    let avg_f = scale(
+        &f.iter().fold([[0.0; 3]; 3], |acc, f| add(&acc, f)),
+        1.0 / n,
+    );
+    let m1_inv = inverse(&sub(&IDENTITY, &avg_f));
+
+    let mut error = f64::INFINITY;
+
+    for _ in 0..n_steps {
+        // Calculate the translation.
+        let m2 = f.iter().zip(p).fold([0.0; 3], |acc, (f, p)| {
+            let update = mul_vec(&sub(f, &IDENTITY), &mul_vec(&pose.rotation, p));
+            add_vec(&acc, &update)
+        });
+        pose.translation = mul_vec(&m1_inv, &scale_vec(&m2, 1.0 / n));
+
+        // Calculate the rotation.
+        let q: Vec<Vec3> = f
+            .iter()
+            .zip(p)
+            .map(|(f, p)| mul_vec(f, &add_vec(&mul_vec(&pose.rotation, p), &pose.translation)))
+            .collect();
+        let q_mean = scale_vec(&q.iter().fold([0.0; 3], |acc, q| add_vec(&acc, q)), 1.0 / n);
+        let m3 = q.iter().zip(&p_res).fold([[0.0; 3]; 3], |acc, (q, p_res)| {
+            add(&acc, &outer(&sub_vec(q, &q_mean), p_res))
+        });
+
+        let (u, _, v) = svd(&m3);
+        let mut rotation = mul(&u, &transpose(&v));
+        if det(&rotation) < 0.0 {
+            rotation.iter_mut().for_each(|row| row[2] = -row[2]);
+        }
+        pose.rotation = rotation;
+
+        error = f
+            .iter()
+            .zip(p)
+            .map(|(f, p)| {
+                let point = add_vec(&mul_vec(&pose.rotation, p), &pose.translation);
+                let err = mul_vec(&sub(&IDENTITY, f), &point);
+                dot(&err, &err)
+            })
+            .sum();
+    }
+
+    error
+}
+
+/// Find the rotation at the other local minimum of the object-space
+/// error, the same as `fix_pose_ambiguities()`.
+///
+/// Based on "Robust Pose Estimation from a Planar Target" by Gerald
+/// Schweighofer and Axel Pinz.
+fn fix_pose_ambiguities(v: &[Vec3; 4], p: &[Vec3; 4], pose: &RawPose) -> Option<Mat3> {
+    // 1. Find R_t.
+    let r_t_3 = normalize(&pose.translation);
+    let e_x = [1.0, 0.0, 0.0];
+    let r_t_1 = normalize(&sub_vec(&e_x, &scale_vec(&r_t_3, dot(&e_x, &r_t_3))));
+    let r_t_2 = cross(&r_t_3, &r_t_1);
+    let r_t = [r_t_1, r_t_2, r_t_3];
+
+    // 2. Find R_z.
+    let r_1_prime = mul(&r_t, &pose.rotation);
+    let mut r31 = r_1_prime[2][0];
+    let mut r32 = r_1_prime[2][1];
+    let mut hypotenuse = r31.hypot(r32);
+    if hypotenuse < 1e-100 {
+        r31 = 1.0;
+        r32 = 0.0;
+        hypotenuse = 1.0;
+    }
+    let r_z = [
+        [r31 / hypotenuse, -r32 / hypotenuse, 0.0],
+        [r32 / hypotenuse, r31 / hypotenuse, 0.0],
+        [0.0, 0.0, 1.0],
+    ];
+
+    // 3. Calculate the parameters of Eos.
+    let r_trans = mul(&r_1_prime, &r_z);
+    let sin_gamma = -r_trans[0][1];
+    let cos_gamma = r_trans[1][1];
+    let r_gamma = [
+        [cos_gamma, -sin_gamma, 0.0],
+        [sin_gamma, cos_gamma, 0.0],
+        [0.0, 0.0, 1.0],
+    ];
+
+    let sin_beta = -r_trans[2][0];
+    let cos_beta = r_trans[2][2];
+    let t_initial = sin_beta.atan2(cos_beta);
+
+    let n = p.len() as f64;
+    let p_trans = p.map(|p| mul_vec(&transpose(&r_z), &p));
+    let f_trans = v.map(|v| calculate_f(&mul_vec(&r_t, &v)));
+    let avg_f_trans = scale(
+        &f_trans.iter().fold([[0.0; 3]; 3], |acc, f| add(&acc, f)),
+        1.0 / n,
+    );
+    let g = scale(&inverse(&sub(&IDENTITY, &avg_f_trans)), 1.0 / n);
+
+    let m1 = [[0.0, 0.0, 2.0], [0.0, 0.0, 0.0], [-2.0, 0.0, 0.0]];
+    let m2 = [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+    let r_gamma_m1 = mul(&r_gamma, &m1);
+    let r_gamma_m2 = mul(&r_gamma, &m2);
+
+    let (mut b0, mut b1, mut b2) = ([0.0; 3], [0.0; 3], [0.0; 3]);
+    for (f, p) in f_trans.iter().zip(&p_trans) {
+        let f_minus_i = sub(f, &IDENTITY);
+        b0 = add_vec(&b0, &mul_vec(&mul(&f_minus_i, &r_gamma), p));
+        b1 = add_vec(&b1, &mul_vec(&mul(&f_minus_i, &r_gamma_m1), p));
+        b2 = add_vec(&b2, &mul_vec(&mul(&f_minus_i, &r_gamma_m2), p));
+    }
+    let b0 = mul_vec(&g, &b0);
+    let b1 = mul_vec(&g, &b1);
+    let b2 = mul_vec(&g, &b2);
+
+    let (mut a0, mut a1, mut a2, mut a3, mut a4) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for (f, p) in f_trans.iter().zip(&p_trans) {
+        let i_minus_f = sub(&IDENTITY, f);
+        let c0 = mul_vec(&i_minus_f, &add_vec(&mul_vec(&r_gamma, p), &b0));
+        let c1 = mul_vec(&i_minus_f, &add_vec(&mul_vec(&r_gamma_m1, p), &b1));
+        let c2 = mul_vec(&i_minus_f, &add_vec(&mul_vec(&r_gamma_m2, p), &b2));
+
+        a0 += dot(&c0, &c0);
+        a1 += 2.0 * dot(&c0, &c1);
+        a2 += dot(&c1, &c1) + 2.0 * dot(&c0, &c2);
+        a3 += 2.0 * dot(&c1, &c2);
+        a4 += dot(&c2, &c2);
+    }
+
+    // 4. Solve for the minima of Eos.
+    let poly = [
+        a1,
+        2.0 * a2 - 4.0 * a0,
+        3.0 * a3 - 3.0 * a1,
+        4.0 * a4 - 2.0 * a2,
+        -a3,
+    ];
+    let minima: Vec<f64> = solve_poly_approx(&poly)
+        .into_iter()
+        .filter(|&t1| {
+            let t2 = t1 * t1;
+            let t3 = t1 * t2;
+            let t4 = t1 * t3;
+            let t5 = t1 * t4;
+
+            // Check that the extremum is a minimum.
+            let second_derivative = a2 - 2.0 * a0
+                + (3.0 * a3 - 6.0 * a1) * t1
+                + (6.0 * a4 - 8.0 * a2 + 10.0 * a0) * t2
+                + (-8.0 * a3 + 6.0 * a1) * t3
+                + (-6.0 * a4 + 3.0 * a2) * t4
+                + a3 * t5;
+
+            // Only a minimum qualitatively different from the known one counts.
+            second_derivative >= 0.0 && (2.0 * t1.atan() - t_initial).abs() > 0.1
+        })
+        .collect();
+
+    // 5. Get the pose of the minimum.
+    let [t] = minima[..] else {
+        return None;
+    };
+    let r_beta = scale(
+        &add(&scale(&add(&scale(&m2, t), &m1), t), &IDENTITY),
+        1.0 / (1.0 + t * t),
+    );
+    let rotation = mul(
+        &mul(&mul(&transpose(&r_t), &r_gamma), &r_beta),
+        &transpose(&r_z),
+    );
+    Some(rotation)
+}
+
+/// Find the real roots of a polynomial in ascending order of degrees,
+/// the same as `solve_poly_approx()`.
+///
+/// The roots are searched within `[-1000, 1000]` between the extrema.
+fn solve_poly_approx(p: &[f64]) -> Vec<f64> {
+    const MAX_ROOT: f64 = 1000.0;
+    let degree = p.len() - 1;
+
+    if degree == 1 {
+        return if p[0].abs() > MAX_ROOT * p[1].abs() {
+            vec![]
+        } else {
+            vec![-p[0] / p[1]]
+        };
+    }
+
+    let p_der: Vec<f64> = (0..degree).map(|i| (i + 1) as f64 * p[i + 1]).collect();
+    let der_roots = solve_poly_approx(&p_der);
+    let polyval = |p: &[f64], x: f64| -> f64 {
+        p.iter()
+            .enumerate()
+            .map(|(i, &c)| c * x.powi(i as i32))
+            .sum()
+    };
+
+    let mut roots = vec![];
+    for i in 0..=der_roots.len() {
+        let min = if i == 0 { -MAX_ROOT } else { der_roots[i - 1] };
+        let max = if i == der_roots.len() {
+            MAX_ROOT
+        } else {
+            der_roots[i]
+        };
+
+        if polyval(p, min) * polyval(p, max) < 0.0 {
+            // There is a zero crossing in the interval. Combine Newton's
+            // method and bisection.
+            let (mut lower, mut upper) = if polyval(p, min) < polyval(p, max) {
+                (min, max)
+            } else {
+                (max, min)
+            };
+            let mut root = 0.5 * (lower + upper);
+            let mut dx_old = upper - lower;
+            let mut dx = dx_old;
+            let mut f = polyval(p, root);
+            let mut df = polyval(&p_der, root);
+
+            for _ in 0..100 {
+                if (f + df * (upper - root)) * (f + df * (lower - root)) > 0.0
+                    || (2.0 * f).abs() > (dx_old * df).abs()
+                {
+                    dx_old = dx;
+                    dx = 0.5 * (upper - lower);
+                    root = lower + dx;
+                } else {
+                    dx_old = dx;
+                    dx = -f / df;
+                    root += dx;
+                }
+
+                if root == upper || root == lower {
+                    break;
+                }
+
+                f = polyval(p, root);
+                df = polyval(&p_der, root);
+                if f > 0.0 {
+                    upper = root;
+                } else {
+                    lower = root;
+                }
+            }
+
+            roots.push(root);
+        } else if polyval(p, max) == 0.0 {
+            // A double or triple root.
+            roots.push(max);
+        }
+    }
+
+    roots
+}
+
+/// The projection operator onto the line of sight.
+fn calculate_f(v: &Vec3) -> Mat3 {
+    scale(&outer(v, v), 1.0 / dot(v, v))
+}
+
+/// Singular value decomposition `A = U * diag(S) * V^T` by one-sided
+/// Jacobi rotations.
+///
+/// The singular values are sorted in descending order. If the matrix
+/// is rank deficient, the missing columns of `U` are completed to an
+/// orthonormal basis.
+fn svd(a: &Mat3) -> (Mat3, Vec3, Mat3) {
+    let mut w = *a;
+    let mut v = IDENTITY;
+
+    for _ in 0..64 {
+        let mut rotated = false;
+
+        for j in 0..2 {
+            for k in (j + 1)..3 {
+                let alpha: f64 = (0..3).map(|i| w[i][j] * w[i][j]).sum();
+                let beta: f64 = (0..3).map(|i| w[i][k] * w[i][k]).sum();
+                let gamma: f64 = (0..3).map(|i| w[i][j] * w[i][k]).sum();
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+
+                for m in [&mut w, &mut v] {
+                    for row in m.iter_mut() {
+                        let (x, y) = (row[j], row[k]);
+                        row[j] = c * x - s * y;
+                        row[k] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    let norms: Vec3 =
+        std::array::from_fn(|j| (0..3).map(|i| w[i][j] * w[i][j]).sum::<f64>().sqrt());
+    order.sort_by(|&lhs, &rhs| norms[rhs].total_cmp(&norms[lhs]));
+
+    let s = order.map(|j| norms[j]);
+    let v_sorted: Mat3 = std::array::from_fn(|i| order.map(|j| v[i][j]));
+    let mut columns: [Vec3; 3] = order.map(|j| [w[0][j], w[1][j], w[2][j]]);
+
+    let tiny = s[0] * 1e-12;
+    for j in 0..3 {
+        if s[j] > tiny && s[j] > 0.0 {
+            columns[j] = scale_vec(&columns[j], 1.0 / s[j]);
+            continue;
+        }
+
+        columns[j] = match j {
+            0 => [1.0, 0.0, 0.0],
+            1 => {
+                // Pick the axis least aligned with the first column.
+                let u0 = columns[0];
+                let axis = (0..3)
+                    .min_by(|&lhs, &rhs| u0[lhs].abs().total_cmp(&u0[rhs].abs()))
+                    .unwrap();
+                let mut e = [0.0; 3];
+                e[axis] = 1.0;
+                normalize(&sub_vec(&e, &scale_vec(&u0, dot(&e, &u0))))
+            }
+            _ => cross(&columns[0], &columns[1]),
+        };
+    }
+
+    let u: Mat3 = std::array::from_fn(|i| columns.map(|column| column[i]));
+    (u, s, v_sorted)
+}
+
+pub(crate) fn mul(lhs: &Mat3, rhs: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..3).map(|k| lhs[i][k] * rhs[k][j]).sum()))
+}
+
+pub(crate) fn mul_vec(lhs: &Mat3, rhs: &Vec3) -> Vec3 {
+    lhs.map(|row| dot(&row, rhs))
+}
+
+pub(crate) fn transpose(m: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| m[j][i]))
+}
+
+fn add(lhs: &Mat3, rhs: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| add_vec(&lhs[i], &rhs[i]))
+}
+
+fn sub(lhs: &Mat3, rhs: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| sub_vec(&lhs[i], &rhs[i]))
+}
+
+fn scale(m: &Mat3, factor: f64) -> Mat3 {
+    m.map(|row| scale_vec(&row, factor))
+}
+
+fn outer(lhs: &Vec3, rhs: &Vec3) -> Mat3 {
+    lhs.map(|x| scale_vec(rhs, x))
+}
+
+fn det(m: &Mat3) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+fn inverse(m: &Mat3) -> Mat3 {
+    let d = det(m);
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    // The inverse is the transposed cofactor matrix over the determinant.
+    std::array::from_fn(|i| std::array::from_fn(|j| cofactor(j, i) / d))
+}
+
+pub(crate) fn add_vec(lhs: &Vec3, rhs: &Vec3) -> Vec3 {
+    std::array::from_fn(|i| lhs[i] + rhs[i])
+}
+
+pub(crate) fn sub_vec(lhs: &Vec3, rhs: &Vec3) -> Vec3 {
+    std::array::from_fn(|i| lhs[i] - rhs[i])
+}
+
+fn scale_vec(v: &Vec3, factor: f64) -> Vec3 {
+    v.map(|x| x * factor)
+}
+
+fn dot(lhs: &Vec3, rhs: &Vec3) -> f64 {
+    lhs.iter().zip(rhs).map(|(x, y)| x * y).sum()
+}
+
+fn cross(lhs: &Vec3, rhs: &Vec3) -> Vec3 {
+    [
+        lhs[1] * rhs[2] - lhs[2] * rhs[1],
+        lhs[2] * rhs[0] - lhs[0] * rhs[2],
+        lhs[0] * rhs[1] - lhs[1] * rhs[0],
+    ]
+}
+
+fn normalize(v: &Vec3) -> Vec3 {
+    scale_vec(v, 1.0 / dot(v, v).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svd_reconstructs_matrix() {
+        let a = [[2.0, -1.0, 0.5], [0.3, 4.0, 1.0], [-1.5, 0.2, 3.0]];
+        let (u, s, v) = svd(&a);
+        let us: Mat3 = std::array::from_fn(|i| std::array::from_fn(|j| u[i][j] * s[j]));
+        let reconstructed = mul(&us, &transpose(&v));
+
+        assert!(s[0] >= s[1] && s[1] >= s[2]);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((reconstructed[i][j] - a[i][j]).abs() < 1e-9);
+            }
+        }
+
+        // A rank deficient matrix still yields an orthonormal U.
+        let (u, s, _) = svd(&[[1.0, 2.0, 0.0], [3.0, 1.0, 0.0], [0.5, -1.0, 0.0]]);
+        assert!(s[2].abs() < 1e-9);
+        let utu = mul(&transpose(&u), &u);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((utu[i][j] - IDENTITY[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn solve_poly_finds_roots() {
+        // (x - 1)(x + 2)(x - 3) = x^3 - 2x^2 - 5x + 6
+        let roots = solve_poly_approx(&[6.0, -5.0, -2.0, 1.0]);
+        assert_eq!(roots.len(), 3);
+        for (root, expect) in roots.iter().zip([-2.0, 1.0, 3.0]) {
+            assert!((root - expect).abs() < 1e-9);
+        }
+    }
+}
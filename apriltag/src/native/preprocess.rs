@@ -0,0 +1,212 @@
+//! Image decimation and blurring, ported from `common/image_u8.c`.
+
+use crate::{
+    error::Error,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+};
+use std::borrow::Cow;
+
+/// Blur the image if the sigma is positive or sharpen it if negative,
+/// in the same way as `apriltag_detector_detect()`.
+pub(crate) fn blur_or_sharpen(quad_im: &mut Cow<'_, Image>, quad_sigma: f32) {
+    if quad_sigma == 0.0 {
+        return;
+    }
+
+    // The kernel goes out 2 standard deviations.
+    let sigma = quad_sigma.abs();
+    let mut ksz = (4.0 * sigma) as usize;
+    if ksz & 1 == 0 {
+        ksz += 1;
+    }
+    if ksz <= 1 {
+        return;
+    }
+
+    let quad_im = quad_im.to_mut();
+    if quad_sigma > 0.0 {
+        gaussian_blur(quad_im, sigma as f64, ksz);
+    } else {
+        // Sharpen the image by subtracting the low frequency components.
+        let orig = quad_im.clone();
+        gaussian_blur(quad_im, sigma as f64, ksz);
+        for y in 0..orig.height() {
+            for x in 0..orig.width() {
+                let value = 2 * orig[(x, y)] as i32 - quad_im[(x, y)] as i32;
+                quad_im[(x, y)] = value.clamp(0, 255) as u8;
+            }
+        }
+    }
+}
+
+/// Downsample the image by the factor, the same as `image_u8_decimate()`.
+///
+/// The factor 1.5 is handled by a dedicated filter. Other factors are
+/// truncated to integers and pick every n-th pixel.
+pub(crate) fn decimate(image: &Image, factor: f32) -> Result<Image, Error> {
+    let width = image.width();
+    let height = image.height();
+
+    if factor == 1.5 {
+        let swidth = width / 3 * 2;
+        let sheight = height / 3 * 2;
+        let mut decim = Image::zeros_with_alignment(swidth, sheight, DEFAULT_ALIGNMENT_U8)?;
+
+        for sy in (0..sheight).step_by(2) {
+            let y = sy / 2 * 3;
+            for sx in (0..swidth).step_by(2) {
+                let x = sx / 2 * 3;
+                let at = |dx: usize, dy: usize| image[(x + dx, y + dy)] as u32;
+                let (a, b, c) = (at(0, 0), at(1, 0), at(2, 0));
+                let (d, e, f) = (at(0, 1), at(1, 1), at(2, 1));
+                let (g, h, i) = (at(0, 2), at(1, 2), at(2, 2));
+
+                decim[(sx, sy)] = ((4 * a + 2 * b + 2 * d + e) / 9) as u8;
+                decim[(sx + 1, sy)] = ((4 * c + 2 * b + 2 * f + e) / 9) as u8;
+                decim[(sx, sy + 1)] = ((4 * g + 2 * d + 2 * h + e) / 9) as u8;
+                decim[(sx + 1, sy + 1)] = ((4 * i + 2 * f + 2 * h + e) / 9) as u8;
+            }
+        }
+
+        return Ok(decim);
+    }
+
+    let factor = (factor as usize).max(1);
+    let swidth = 1 + (width.max(1) - 1) / factor;
+    let sheight = 1 + (height.max(1) - 1) / factor;
+    let mut decim = Image::zeros_with_alignment(swidth, sheight, DEFAULT_ALIGNMENT_U8)?;
+
+    for (sy, y) in (0..height).step_by(factor).enumerate() {
+        for (sx, x) in (0..width).step_by(factor).enumerate() {
+            decim[(sx, sy)] = image[(x, y)];
+        }
+    }
+
+    Ok(decim)
+}
+
+/// Blur the image with a separable Gaussian kernel of odd size, the
+/// same as `image_u8_gaussian_blur()`.
+///
+/// The kernel is quantized to bytes and the borders are left unfiltered.
+pub(crate) fn gaussian_blur(image: &mut Image, sigma: f64, ksz: usize) {
+    if sigma == 0.0 {
+        return;
+    }
+    debug_assert!(ksz & 1 == 1);
+
+    let dk: Vec<f64> = (0..ksz)
+        .map(|i| {
+            let x = i as f64 - (ksz / 2) as f64;
+            (-0.5 * (x / sigma).powi(2)).exp()
+        })
+        .collect();
+    let sum: f64 = dk.iter().sum();
+    let kernel: Vec<u32> = dk.iter().map(|v| (v / sum * 255.0) as u32).collect();
+
+    let width = image.width();
+    let height = image.height();
+
+    let mut row = vec![0u8; width];
+    for y in 0..height {
+        (0..width).for_each(|x| row[x] = image[(x, y)]);
+        convolve(&row, &kernel, |x, value| image[(x, y)] = value);
+    }
+
+    let mut column = vec![0u8; height];
+    for x in 0..width {
+        (0..height).for_each(|y| column[y] = image[(x, y)]);
+        convolve(&column, &kernel, |y, value| image[(x, y)] = value);
+    }
+}
+
+/// Convolve a line of pixels and write the filtered values by the callback.
+fn convolve<F>(line: &[u8], kernel: &[u32], mut write: F)
+where
+    F: FnMut(usize, u8),
+{
+    let ksz = kernel.len();
+    let len = line.len();
+
+    for (i, &value) in line.iter().enumerate().take(ksz / 2) {
+        write(i, value);
+    }
+
+    for i in 0..len.saturating_sub(ksz) {
+        let acc: u32 = kernel
+            .iter()
+            .zip(&line[i..])
+            .map(|(&k, &v)| k * v as u32)
+            .sum();
+        write(ksz / 2 + i, (acc >> 8) as u8);
+    }
+
+    let tail = len.saturating_sub(ksz) + ksz / 2;
+    for (i, &value) in line.iter().enumerate().skip(tail) {
+        write(i, value);
+    }
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::*;
+    use apriltag_sys as sys;
+    use std::ptr::NonNull;
+
+    fn noise_image(width: usize, height: usize) -> Image {
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8).unwrap();
+        let mut state = 12345u32;
+        for y in 0..height {
+            for x in 0..width {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                image[(x, y)] = (state >> 16) as u8;
+            }
+        }
+        image
+    }
+
+    fn assert_same_pixels(lhs: &Image, rhs: &Image) {
+        assert_eq!((lhs.width(), lhs.height()), (rhs.width(), rhs.height()));
+        for y in 0..lhs.height() {
+            for x in 0..lhs.width() {
+                assert_eq!(lhs[(x, y)], rhs[(x, y)], "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn decimate_matches_c_library() {
+        let image = noise_image(101, 67);
+
+        for factor in [1.5, 2.0, 3.0, 4.0] {
+            let expect = unsafe {
+                let ptr = sys::image_u8_decimate(image.ptr.as_ptr(), factor);
+                Image {
+                    ptr: NonNull::new(ptr).unwrap(),
+                }
+            };
+            assert_same_pixels(&decimate(&image, factor).unwrap(), &expect);
+        }
+    }
+
+    #[test]
+    fn gaussian_blur_matches_c_library() {
+        let image = noise_image(64, 48);
+
+        for sigma in [0.8f32, 1.5, 2.0] {
+            let mut ksz = (4.0 * sigma) as usize;
+            if ksz & 1 == 0 {
+                ksz += 1;
+            }
+
+            let mut blurred = Cow::Borrowed(&image);
+            blur_or_sharpen(&mut blurred, sigma);
+
+            let expect = image.clone();
+            unsafe {
+                sys::image_u8_gaussian_blur(expect.ptr.as_ptr(), sigma as f64, ksz as i32);
+            }
+            assert_same_pixels(&blurred, &expect);
+        }
+    }
+}
@@ -0,0 +1,756 @@
+//! Quad detection, ported from `apriltag_quad_thresh.c`.
+//!
+//! The image is thresholded, segmented by union-find, and the boundary
+//! points between each pair of black and white components are gathered
+//! into clusters. Quads are then fitted to the clusters.
+
+use crate::{
+    debug::Quad, detector::QuadThresholds, error::Error, image_buf::Image, unionfind::UnionFind,
+};
+#[cfg(not(feature = "pure-rust"))]
+use apriltag_sys as sys;
+use std::collections::HashMap;
+
+/// The thresholds in the form used by the C library.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QuadParams {
+    pub min_cluster_pixels: usize,
+    pub max_nmaxima: usize,
+    pub cos_critical_rad: f64,
+    pub max_line_fit_mse: f64,
+    pub min_white_black_diff: i32,
+    pub deglitch: bool,
+}
+
+impl From<QuadThresholds> for QuadParams {
+    fn from(thresholds: QuadThresholds) -> Self {
+        Self {
+            min_cluster_pixels: thresholds.min_cluster_pixels as usize,
+            max_nmaxima: thresholds.max_maxima_number as usize,
+            // The C library stores the values in single precision.
+            cos_critical_rad: thresholds.min_opposite_angle.as_radians().cos() as f32 as f64,
+            max_line_fit_mse: thresholds.max_mse.raw() as f64,
+            min_white_black_diff: thresholds.min_white_black_diff as i32,
+            deglitch: thresholds.deglitch,
+        }
+    }
+}
+
+#[cfg(not(feature = "pure-rust"))]
+impl QuadParams {
+    /// Read the thresholds set on a C detector.
+    pub fn from_raw(qtp: &sys::apriltag_quad_thresh_params) -> Self {
+        Self {
+            min_cluster_pixels: qtp.min_cluster_pixels.max(0) as usize,
+            max_nmaxima: qtp.max_nmaxima.max(0) as usize,
+            cos_critical_rad: qtp.cos_critical_rad as f64,
+            max_line_fit_mse: qtp.max_line_fit_mse as f64,
+            min_white_black_diff: qtp.min_white_black_diff,
+            deglitch: qtp.deglitch != 0,
+        }
+    }
+}
+
+/// The geometry of the enabled families that constrains quad fitting.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QuadConstraints {
+    /// The minimal tag width in pixels on the decimated image.
+    pub min_tag_width: usize,
+    /// Whether any family has the black border outside.
+    pub normal_border: bool,
+    /// Whether any family has the white border outside.
+    pub reversed_border: bool,
+}
+
+impl QuadConstraints {
+    /// Gets the decoding geometry of the enabled families, given as
+    /// pairs of the `width_at_border` and `reversed_border` fields.
+    pub fn new<I>(families: I, quad_decimate: f32) -> Self
+    where
+        I: IntoIterator<Item = (i32, bool)>,
+    {
+        let mut min_tag_width = i32::MAX;
+        let mut normal_border = false;
+        let mut reversed_border = false;
+
+        for (width_at_border, reversed) in families {
+            min_tag_width = min_tag_width.min(width_at_border);
+            normal_border |= !reversed;
+            reversed_border |= reversed;
+        }
+
+        // The division happens in single precision and truncates.
+        let min_tag_width = (min_tag_width as f32 / quad_decimate) as usize;
+
+        Self {
+            min_tag_width: min_tag_width.max(3),
+            normal_border,
+            reversed_border,
+        }
+    }
+}
+
+/// A boundary point between a black and a white pixel.
+///
+/// The coordinates are twice the actual values.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Pt {
+    x: u16,
+    y: u16,
+    gx: i16,
+    gy: i16,
+    slope: f32,
+}
+
+/// Cumulative moments of the boundary points.
+#[derive(Debug, Clone, Copy, Default)]
+struct LineFitPt {
+    mx: f64,
+    my: f64,
+    mxx: f64,
+    myy: f64,
+    mxy: f64,
+    w: f64,
+}
+
+/// Threshold the image into black (0), white (255) and low contrast
+/// (127) pixels, the same as `threshold()`.
+pub(crate) fn threshold(image: &Image, params: &QuadParams) -> Result<Image, Error> {
+    const TILESZ: usize = 4;
+
+    let w = image.width();
+    let h = image.height();
+    let mut threshim = Image::zeros_with_stride(w, h, image.stride())?;
+
+    // The last partial tiles use the statistics of the last full tile.
+    let tw = w / TILESZ;
+    let th = h / TILESZ;
+    if tw == 0 || th == 0 {
+        // Too small to find any edge.
+        threshim.as_slice_mut().fill(127);
+        return Ok(threshim);
+    }
+
+    // Collect min/max statistics for each tile.
+    let mut im_max = vec![0u8; tw * th];
+    let mut im_min = vec![0u8; tw * th];
+    for ty in 0..th {
+        for tx in 0..tw {
+            let (mut max, mut min) = (0u8, 255u8);
+            for dy in 0..TILESZ {
+                for dx in 0..TILESZ {
+                    let v = image[(tx * TILESZ + dx, ty * TILESZ + dy)];
+                    min = min.min(v);
+                    max = max.max(v);
+                }
+            }
+            im_max[ty * tw + tx] = max;
+            im_min[ty * tw + tx] = min;
+        }
+    }
+
+    // Apply 3x3 max/min filters over the tiles to avoid abrupt changes
+    // of the threshold.
+    let mut max_tmp = vec![0u8; tw * th];
+    let mut min_tmp = vec![0u8; tw * th];
+    for ty in 0..th {
+        for tx in 0..tw {
+            let (mut max, mut min) = (0u8, 255u8);
+            for ny in ty.saturating_sub(1)..(ty + 2).min(th) {
+                for nx in tx.saturating_sub(1)..(tx + 2).min(tw) {
+                    max = max.max(im_max[ny * tw + nx]);
+                    min = min.min(im_min[ny * tw + nx]);
+                }
+            }
+            max_tmp[ty * tw + tx] = max;
+            min_tmp[ty * tw + tx] = min;
+        }
+    }
+    let (im_max, im_min) = (max_tmp, min_tmp);
+
+    for ty in 0..th {
+        for tx in 0..tw {
+            let min = im_min[ty * tw + tx] as i32;
+            let max = im_max[ty * tw + tx] as i32;
+
+            for dy in 0..TILESZ {
+                for dx in 0..TILESZ {
+                    let (x, y) = (tx * TILESZ + dx, ty * TILESZ + dy);
+                    threshim[(x, y)] = if max - min < params.min_white_black_diff {
+                        // Low contrast region without edges.
+                        127
+                    } else {
+                        // Bias towards dark, since specular highlights
+                        // can be much brighter than white tag cells.
+                        binarize(image[(x, y)], min + (max - min) / 2)
+                    };
+                }
+            }
+        }
+    }
+
+    // Threshold the pixels of partial tiles along the right and bottom edges.
+    for y in 0..h {
+        let x0 = if y >= th * TILESZ { 0 } else { tw * TILESZ };
+        let ty = (y / TILESZ).min(th - 1);
+
+        for x in x0..w {
+            let tx = (x / TILESZ).min(tw - 1);
+            let min = im_min[ty * tw + tx] as i32;
+            let max = im_max[ty * tw + tx] as i32;
+            threshim[(x, y)] = binarize(image[(x, y)], min + (max - min) / 2);
+        }
+    }
+
+    if params.deglitch && w > 2 && h > 2 {
+        // Dilate and then erode.
+        let mut tmp = Image::zeros_with_stride(w, h, threshim.stride())?;
+        let filter = |src: &Image, dst: &mut Image, pick: fn(u8, u8) -> u8, init: u8| {
+            for y in 1..h - 1 {
+                for x in 1..w - 1 {
+                    let mut value = init;
+                    for ny in y - 1..=y + 1 {
+                        for nx in x - 1..=x + 1 {
+                            value = pick(value, src[(nx, ny)]);
+                        }
+                    }
+                    dst[(x, y)] = value;
+                }
+            }
+        };
+        filter(&threshim, &mut tmp, u8::max, 0);
+        filter(&tmp, &mut threshim, u8::min, 255);
+    }
+
+    Ok(threshim)
+}
+
+fn binarize(value: u8, thresh: i32) -> u8 {
+    if value as i32 > thresh {
+        255
+    } else {
+        0
+    }
+}
+
+/// Fit quads to the clusters, the same as `fit_quads()`.
+///
+/// The quad corners are on the preprocessed image.
+pub(crate) fn fit_quads(
+    params: &QuadParams,
+    image: &Image,
+    clusters: Vec<Vec<Pt>>,
+    constraints: &QuadConstraints,
+) -> Vec<Quad> {
+    let w = image.width();
+    let h = image.height();
+
+    clusters
+        .into_iter()
+        .filter(|cluster| {
+            // A typical edge point is added three times, and the
+            // perimeter can not exceed that of the image.
+            cluster.len() >= params.min_cluster_pixels && cluster.len() <= 3 * (2 * w + 2 * h)
+        })
+        .filter_map(|mut cluster| fit_quad(params, image, &mut cluster, constraints))
+        .collect()
+}
+
+/// Map the quad corners on the decimated image back to the input
+/// image. The C library keeps the corners in single precision.
+pub(crate) fn map_to_input(quads: &mut [Quad], quad_decimate: f32) {
+    let decimate = quad_decimate as f64;
+    if decimate <= 1.0 {
+        return;
+    }
+
+    for quad in quads {
+        for [x, y] in &mut quad.corners {
+            let (mapped_x, mapped_y) = if quad_decimate == 1.5 {
+                (*x * decimate, *y * decimate)
+            } else {
+                ((*x - 0.5) * decimate + 0.5, (*y - 0.5) * decimate + 0.5)
+            };
+            *x = mapped_x as f32 as f64;
+            *y = mapped_y as f32 as f64;
+        }
+    }
+}
+
+/// Gather boundary points between every pair of adjacent black and
+/// white components, the same as `gradient_clusters()`.
+///
+/// The clusters are ordered by their hash buckets in the C library.
+pub(crate) fn gradient_clusters(threshim: &Image, uf: &mut UnionFind) -> Vec<Vec<Pt>> {
+    let w = threshim.width();
+    let h = threshim.height();
+    let mut index: HashMap<u64, usize> = HashMap::new();
+    let mut clusters: Vec<(u64, Vec<Pt>)> = vec![];
+
+    for y in 1..h.saturating_sub(1) {
+        for x in 1..w.saturating_sub(1) {
+            let v0 = threshim[(x, y)];
+            if v0 == 127 {
+                continue;
+            }
+
+            let id0 = (y * w + x) as u32;
+            let rep0 = uf.representative(id0);
+            if uf.set_size(rep0) < 25 {
+                continue;
+            }
+
+            // Neighbors to the right and below, with both 4 and 8 connectivity.
+            for (dx, dy) in [(1, 0), (0, 1), (-1, 1), (1, 1)] {
+                let (nx, ny) = ((x as isize + dx) as usize, y + dy as usize);
+                let v1 = threshim[(nx, ny)];
+                if v0 as u32 + v1 as u32 != 255 {
+                    continue;
+                }
+
+                let rep1 = uf.representative((ny * w + nx) as u32);
+                if uf.set_size(rep1) <= 24 {
+                    continue;
+                }
+
+                let (lo, hi) = if rep0 < rep1 {
+                    (rep0, rep1)
+                } else {
+                    (rep1, rep0)
+                };
+                let cluster_id = ((hi as u64) << 32) + lo as u64;
+                let slot = *index.entry(cluster_id).or_insert_with(|| {
+                    clusters.push((cluster_id, vec![]));
+                    clusters.len() - 1
+                });
+
+                // The gradient points towards the white pixel.
+                let diff = v1 as i32 - v0 as i32;
+                clusters[slot].1.push(Pt {
+                    x: (2 * x as isize + dx) as u16,
+                    y: (2 * y + dy as usize) as u16,
+                    gx: (dx as i32 * diff) as i16,
+                    gy: (dy * diff) as i16,
+                    slope: 0.0,
+                });
+            }
+        }
+    }
+
+    let nclustermap = ((0.2 * w as f64 * h as f64) as u64).max(1);
+    let bucket = |id: u64| (2654435761u64.wrapping_mul(id) >> 32) % nclustermap;
+    clusters.sort_by_key(|&(id, _)| (bucket(id), id));
+    clusters.into_iter().map(|(_, cluster)| cluster).collect()
+}
+
+/// Fit a quad to a cluster, the same as `fit_quad()`.
+fn fit_quad(
+    params: &QuadParams,
+    image: &Image,
+    cluster: &mut Vec<Pt>,
+    constraints: &QuadConstraints,
+) -> Option<Quad> {
+    if cluster.len() < 24 {
+        return None;
+    }
+
+    // Sort the points by their angle around the center.
+    let (mut xmin, mut xmax) = (cluster[0].x, cluster[0].x);
+    let (mut ymin, mut ymax) = (cluster[0].y, cluster[0].y);
+    for p in &cluster[1..] {
+        xmin = xmin.min(p.x);
+        xmax = xmax.max(p.x);
+        ymin = ymin.min(p.y);
+        ymax = ymax.max(p.y);
+    }
+
+    let tag_width = constraints.min_tag_width;
+    if ((xmax - xmin) as usize) * ((ymax - ymin) as usize) < tag_width {
+        return None;
+    }
+
+    // Add some noise to the center so that pixels get more diverse angles.
+    let cx = ((xmin as u32 + xmax as u32) as f64 * 0.5 + 0.05118) as f32;
+    let cy = ((ymin as u32 + ymax as u32) as f64 * 0.5 - 0.028581) as f32;
+    let quadrants = [
+        [-(2 << 15) as f32, 0.0],
+        [(2 * (2 << 15)) as f32, (2 << 15) as f32],
+    ];
+    let mut dot = 0f32;
+
+    for p in cluster.iter_mut() {
+        let mut dx = p.x as f32 - cx;
+        let mut dy = p.y as f32 - cy;
+        dot += dx * p.gx as f32 + dy * p.gy as f32;
+
+        let quadrant = quadrants[(dy > 0.0) as usize][(dx > 0.0) as usize];
+        if dy < 0.0 {
+            dy = -dy;
+            dx = -dx;
+        }
+        if dx < 0.0 {
+            let tmp = dx;
+            dx = dy;
+            dy = -tmp;
+        }
+        p.slope = quadrant + dy / dx;
+    }
+
+    // Ensure that the black border is inside the white border.
+    let reversed_border = dot < 0.0;
+    if (reversed_border && !constraints.reversed_border)
+        || (!reversed_border && !constraints.normal_border)
+    {
+        return None;
+    }
+
+    ptsort(cluster);
+    // Remove duplicate points, a byproduct of the segmentation.
+    cluster.dedup_by(|p, last| p.x == last.x && p.y == last.y);
+    if cluster.len() < 24 {
+        return None;
+    }
+
+    let lfps = compute_lfps(cluster, image);
+    let indices = quad_segment_maxima(params, cluster.len(), &lfps)?;
+
+    let mut lines = [[0f64; 4]; 4];
+    for (i, line) in lines.iter_mut().enumerate() {
+        let fit = fit_line(&lfps, indices[i], indices[(i + 1) & 3]);
+        if fit.mse > params.max_line_fit_mse {
+            return None;
+        }
+        *line = fit.line;
+    }
+
+    let mut corners = [[0f64; 2]; 4];
+    for (i, corner) in corners.iter_mut().enumerate() {
+        // Intersect the lines i and i+1. The line parameters hold the
+        // normal vectors, so the directions are their perpendiculars.
+        let this = &lines[i];
+        let next = &lines[(i + 1) & 3];
+        let a00 = this[3];
+        let a01 = -next[3];
+        let a10 = -this[2];
+        let a11 = next[2];
+        let b0 = -this[0] + next[0];
+        let b1 = -this[1] + next[1];
+
+        let det = a00 * a11 - a10 * a01;
+        if det.abs() < 0.001 {
+            return None;
+        }
+        let l0 = (a11 * b0 - a01 * b1) / det;
+
+        // The C library stores corners in single precision.
+        *corner = [
+            (this[0] + l0 * a00) as f32 as f64,
+            (this[1] + l0 * a10) as f32 as f64,
+        ];
+    }
+
+    // Reject quads that are too small.
+    let triangle_area = |a: [f64; 2], b: [f64; 2], c: [f64; 2]| {
+        let length = |p: [f64; 2], q: [f64; 2]| (q[0] - p[0]).hypot(q[1] - p[1]);
+        let (la, lb, lc) = (length(a, b), length(b, c), length(c, a));
+        let p = (la + lb + lc) / 2.0;
+        (p * (p - la) * (p - lb) * (p - lc)).sqrt()
+    };
+    let area = triangle_area(corners[0], corners[1], corners[2])
+        + triangle_area(corners[2], corners[3], corners[0]);
+    if area < 0.95 * (tag_width * tag_width) as f64 {
+        return None;
+    }
+
+    // Reject quads whose cumulative angle change is not 2 pi.
+    for i in 0..4 {
+        let [x0, y0] = corners[i];
+        let [x1, y1] = corners[(i + 1) & 3];
+        let [x2, y2] = corners[(i + 2) & 3];
+        let (dx1, dy1) = (x1 - x0, y1 - y0);
+        let (dx2, dy2) = (x2 - x1, y2 - y1);
+        let cos_dtheta =
+            (dx1 * dx2 + dy1 * dy2) / ((dx1 * dx1 + dy1 * dy1) * (dx2 * dx2 + dy2 * dy2)).sqrt();
+
+        if cos_dtheta.abs() > params.cos_critical_rad || dx1 * dy2 < dy1 * dx2 {
+            return None;
+        }
+    }
+
+    Some(Quad {
+        corners,
+        reversed_border,
+    })
+}
+
+/// Sort the points by slope, the same as `ptsort()`.
+///
+/// The merge sort is not stable. Slopes often tie due to the single
+/// precision, and the order of ties affects the line fits, so the
+/// exact algorithm of the C library is kept.
+fn ptsort(pts: &mut [Pt]) {
+    let maybe_swap = |pts: &mut [Pt], a: usize, b: usize| {
+        if pts[a].slope - pts[b].slope > 0.0 {
+            pts.swap(a, b);
+        }
+    };
+
+    match pts.len() {
+        0 | 1 => {}
+        2 => maybe_swap(pts, 0, 1),
+        3 => {
+            for (a, b) in [(0, 1), (1, 2), (0, 1)] {
+                maybe_swap(pts, a, b);
+            }
+        }
+        4 => {
+            for (a, b) in [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)] {
+                maybe_swap(pts, a, b);
+            }
+        }
+        5 => {
+            for (a, b) in [
+                (0, 1),
+                (3, 4),
+                (1, 2),
+                (0, 1),
+                (0, 3),
+                (2, 4),
+                (1, 2),
+                (2, 3),
+                (1, 2),
+            ] {
+                maybe_swap(pts, a, b);
+            }
+        }
+        sz => {
+            let mut tmp = pts.to_vec();
+            let (lhs, rhs) = tmp.split_at_mut(sz / 2);
+            ptsort(lhs);
+            ptsort(rhs);
+
+            // Take from the right half on ties.
+            let (mut apos, mut bpos) = (0, 0);
+            for out in pts.iter_mut() {
+                let take_lhs = bpos == rhs.len()
+                    || (apos < lhs.len() && lhs[apos].slope - rhs[bpos].slope < 0.0);
+                if take_lhs {
+                    *out = lhs[apos];
+                    apos += 1;
+                } else {
+                    *out = rhs[bpos];
+                    bpos += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Compute the cumulative moments of the points weighted by gradients.
+fn compute_lfps(cluster: &[Pt], image: &Image) -> Vec<LineFitPt> {
+    let mut acc = LineFitPt::default();
+
+    cluster
+        .iter()
+        .map(|p| {
+            // Undo the fixed-point representation and shift to pixel centers.
+            let x = p.x as f64 * 0.5 + 0.5;
+            let y = p.y as f64 * 0.5 + 0.5;
+            let (ix, iy) = (x as usize, y as usize);
+            let mut w = 1.0;
+
+            if ix > 0 && ix + 1 < image.width() && iy > 0 && iy + 1 < image.height() {
+                let grad_x = image[(ix + 1, iy)] as i32 - image[(ix - 1, iy)] as i32;
+                let grad_y = image[(ix, iy + 1)] as i32 - image[(ix, iy - 1)] as i32;
+                w = ((grad_x * grad_x + grad_y * grad_y) as f64).sqrt() + 1.0;
+            }
+
+            acc.mx += w * x;
+            acc.my += w * y;
+            acc.mxx += w * x * x;
+            acc.mxy += w * x * y;
+            acc.myy += w * y * y;
+            acc.w += w;
+            acc
+        })
+        .collect()
+}
+
+/// The outcome of [fit_line].
+struct LineFit {
+    /// The centroid and the unit normal in form of `[ex, ey, nx, ny]`.
+    line: [f64; 4],
+    /// The sum of squared errors.
+    err: f64,
+    /// The mean squared error.
+    mse: f64,
+}
+
+/// Fit a line to the points `[i0, i1]`, which wrap around if `i1 < i0`.
+fn fit_line(lfps: &[LineFitPt], i0: usize, i1: usize) -> LineFit {
+    debug_assert_ne!(i0, i1);
+    let sz = lfps.len();
+    let sub = |lhs: LineFitPt, rhs: LineFitPt| LineFitPt {
+        mx: lhs.mx - rhs.mx,
+        my: lhs.my - rhs.my,
+        mxx: lhs.mxx - rhs.mxx,
+        myy: lhs.myy - rhs.myy,
+        mxy: lhs.mxy - rhs.mxy,
+        w: lhs.w - rhs.w,
+    };
+
+    let (m, n) = if i0 < i1 {
+        let m = if i0 > 0 {
+            sub(lfps[i1], lfps[i0 - 1])
+        } else {
+            lfps[i1]
+        };
+        (m, i1 - i0 + 1)
+    } else {
+        let tail = sub(lfps[sz - 1], lfps[i0 - 1]);
+        let head = lfps[i1];
+        let m = LineFitPt {
+            mx: tail.mx + head.mx,
+            my: tail.my + head.my,
+            mxx: tail.mxx + head.mxx,
+            myy: tail.myy + head.myy,
+            mxy: tail.mxy + head.mxy,
+            w: tail.w + head.w,
+        };
+        (m, sz - i0 + i1 + 1)
+    };
+
+    let ex = m.mx / m.w;
+    let ey = m.my / m.w;
+    let cxx = m.mxx / m.w - ex * ex;
+    let cxy = m.mxy / m.w - ex * ey;
+    let cyy = m.myy / m.w - ey * ey;
+
+    // Pose it as an eigenvalue problem. The C library takes the
+    // square roots in single precision.
+    let root = (((cxx - cyy) * (cxx - cyy) + 4.0 * cxy * cxy) as f32).sqrt() as f64;
+    let eig_small = 0.5 * (cxx + cyy - root);
+    let eig = 0.5 * (cxx + cyy + root);
+
+    let (nx1, ny1) = (cxx - eig, cxy);
+    let (nx2, ny2) = (cxy, cyy - eig);
+    let m1 = nx1 * nx1 + ny1 * ny1;
+    let m2 = nx2 * nx2 + ny2 * ny2;
+    let (nx, ny, mm) = if m1 > m2 {
+        (nx1, ny1, m1)
+    } else {
+        (nx2, ny2, m2)
+    };
+    let length = (mm as f32).sqrt() as f64;
+    let (nx, ny) = if length.abs() < 1e-12 {
+        (0.0, 0.0)
+    } else {
+        (nx / length, ny / length)
+    };
+
+    LineFit {
+        line: [ex, ey, nx, ny],
+        err: n as f64 * eig_small,
+        mse: eig_small,
+    }
+}
+
+/// Find the four corners of a cluster at the maxima of the line fitting
+/// errors, the same as `quad_segment_maxima()`.
+fn quad_segment_maxima(params: &QuadParams, sz: usize, lfps: &[LineFitPt]) -> Option<[usize; 4]> {
+    // The number of points on either side to fit lines.
+    let ksz = 20.min(sz / 12);
+    if ksz < 2 {
+        return None;
+    }
+
+    let errs: Vec<f64> = (0..sz)
+        .map(|i| fit_line(lfps, (i + sz - ksz) % sz, (i + ksz) % sz).err)
+        .collect();
+
+    // Apply a low-pass filter to the errors.
+    let sigma = 1.0f64;
+    let cutoff = 0.05f64;
+    let fsz = 2 * (((-cutoff.ln() * 2.0 * sigma * sigma).sqrt() + 1.0) as usize) + 1;
+    let filter: Vec<f64> = (0..fsz)
+        .map(|i| {
+            let j = i as f64 - (fsz / 2) as f64;
+            (-j * j / (2.0 * sigma * sigma)).exp() as f32 as f64
+        })
+        .collect();
+    let errs: Vec<f64> = (0..sz)
+        .map(|iy| {
+            filter
+                .iter()
+                .enumerate()
+                .map(|(i, f)| errs[(iy + i + sz * fsz - fsz / 2) % sz] * f)
+                .sum()
+        })
+        .collect();
+
+    let mut maxima: Vec<(usize, f64)> = (0..sz)
+        .filter(|&i| errs[i] > errs[(i + 1) % sz] && errs[i] > errs[(i + sz - 1) % sz])
+        .map(|i| (i, errs[i]))
+        .collect();
+    if maxima.len() < 4 {
+        return None;
+    }
+
+    // Keep only the best maxima if there are too many.
+    if maxima.len() > params.max_nmaxima {
+        let mut sorted: Vec<f64> = maxima.iter().map(|&(_, err)| err).collect();
+        sorted.sort_by(|lhs, rhs| rhs.total_cmp(lhs));
+        let thresh = sorted[params.max_nmaxima];
+        maxima.retain(|&(_, err)| err > thresh);
+    }
+    let maxima: Vec<usize> = maxima.into_iter().map(|(i, _)| i).collect();
+    let nmaxima = maxima.len();
+
+    let max_mse = params.max_line_fit_mse;
+    let max_dot = params.cos_critical_rad;
+    let mut best: Option<([usize; 4], f64)> = None;
+
+    for m0 in 0..nmaxima.saturating_sub(3) {
+        let i0 = maxima[m0];
+
+        for m1 in (m0 + 1)..nmaxima - 2 {
+            let i1 = maxima[m1];
+            let fit01 = fit_line(lfps, i0, i1);
+            if fit01.mse > max_mse {
+                continue;
+            }
+
+            for m2 in (m1 + 1)..nmaxima - 1 {
+                let i2 = maxima[m2];
+                let fit12 = fit_line(lfps, i1, i2);
+                if fit12.mse > max_mse {
+                    continue;
+                }
+
+                let dot = fit01.line[2] * fit12.line[2] + fit01.line[3] * fit12.line[3];
+                if dot.abs() > max_dot {
+                    continue;
+                }
+
+                for &i3 in &maxima[(m2 + 1)..] {
+                    let fit23 = fit_line(lfps, i2, i3);
+                    if fit23.mse > max_mse {
+                        continue;
+                    }
+                    let fit30 = fit_line(lfps, i3, i0);
+                    if fit30.mse > max_mse {
+                        continue;
+                    }
+
+                    let err = fit01.err + fit12.err + fit23.err + fit30.err;
+                    if best.is_none_or(|(_, best_err)| err < best_err) {
+                        best = Some(([i0, i1, i2, i3], err));
+                    }
+                }
+            }
+        }
+    }
+
+    let (indices, err) = best?;
+    (err / (sz as f64) < max_mse).then_some(indices)
+}
@@ -0,0 +1,111 @@
+//! Overlap of tag quads in pixels.
+
+/// Compute the intersection over union of two quads.
+///
+/// The quads are treated as convex polygons in either winding order.
+/// It returns zero if either quad is degenerate.
+pub(crate) fn quad_iou(lhs: &[[f64; 2]; 4], rhs: &[[f64; 2]; 4]) -> f64 {
+    let lhs_area = signed_area(lhs).abs();
+    let rhs_area = signed_area(rhs).abs();
+    if lhs_area <= f64::EPSILON || rhs_area <= f64::EPSILON {
+        return 0.0;
+    }
+
+    let intersection = signed_area(&clip(lhs, rhs)).abs();
+    let union = lhs_area + rhs_area - intersection;
+    (intersection / union).clamp(0.0, 1.0)
+}
+
+/// Compute the signed area of a polygon by the shoelace formula.
+fn signed_area(polygon: &[[f64; 2]]) -> f64 {
+    let len = polygon.len();
+    (0..len)
+        .map(|index| {
+            let [x0, y0] = polygon[index];
+            let [x1, y1] = polygon[(index + 1) % len];
+            x0 * y1 - x1 * y0
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Clip the subject polygon by a convex polygon by the
+/// Sutherland-Hodgman algorithm.
+fn clip(subject: &[[f64; 2]], clipper: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    // Keep the points on the left of counter-clockwise edges.
+    let orientation = signed_area(clipper).signum();
+    let inside = |[ax, ay]: [f64; 2], [bx, by]: [f64; 2], [px, py]: [f64; 2]| {
+        ((bx - ax) * (py - ay) - (by - ay) * (px - ax)) * orientation >= 0.0
+    };
+    let intersect =
+        |[ax, ay]: [f64; 2], [bx, by]: [f64; 2], [px, py]: [f64; 2], [qx, qy]: [f64; 2]| {
+            let [ex, ey] = [bx - ax, by - ay];
+            let [dx, dy] = [qx - px, qy - py];
+            let denom = ex * dy - ey * dx;
+            let t = ((px - ax) * dy - (py - ay) * dx) / denom;
+            [ax + t * ex, ay + t * ey]
+        };
+
+    let mut output = subject.to_vec();
+    for index in 0..clipper.len() {
+        if output.is_empty() {
+            break;
+        }
+        let a = clipper[index];
+        let b = clipper[(index + 1) % clipper.len()];
+        let input = std::mem::take(&mut output);
+
+        for (current_index, &current) in input.iter().enumerate() {
+            let previous = input[(current_index + input.len() - 1) % input.len()];
+            match (inside(a, b, previous), inside(a, b, current)) {
+                (true, true) => output.push(current),
+                (true, false) => output.push(intersect(previous, current, a, b)),
+                (false, true) => {
+                    output.push(intersect(previous, current, a, b));
+                    output.push(current);
+                }
+                (false, false) => {}
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> [[f64; 2]; 4] {
+        [[x, y], [x + size, y], [x + size, y + size], [x, y + size]]
+    }
+
+    #[test]
+    fn iou_of_squares() {
+        let lhs = square(0.0, 0.0, 2.0);
+        assert!((quad_iou(&lhs, &lhs) - 1.0).abs() < 1e-12);
+
+        // Half overlapping squares share 2 of 6 units of area.
+        let rhs = square(1.0, 0.0, 2.0);
+        assert!((quad_iou(&lhs, &rhs) - 1.0 / 3.0).abs() < 1e-12);
+
+        let far = square(5.0, 5.0, 2.0);
+        assert_eq!(quad_iou(&lhs, &far), 0.0);
+    }
+
+    #[test]
+    fn iou_ignores_winding_order() {
+        let lhs = square(0.0, 0.0, 2.0);
+        let mut rhs = square(1.0, 1.0, 2.0);
+        rhs.reverse();
+        assert!((quad_iou(&lhs, &rhs) - 1.0 / 7.0).abs() < 1e-12);
+        assert!((quad_iou(&rhs, &lhs) - 1.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn iou_of_degenerate_quad() {
+        let lhs = square(0.0, 0.0, 2.0);
+        let line = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 0.0]];
+        assert_eq!(quad_iou(&lhs, &line), 0.0);
+    }
+}
@@ -1,6 +1,10 @@
 //! Pose types storing estimated rotation and translation parameters.
 
-use crate::MatdRef;
+use crate::{
+    record::{PoseEstimationRecord, PoseRecord},
+    MatdRef,
+};
+#[cfg(not(feature = "pure-rust"))]
 use apriltag_sys as sys;
 use std::fmt::{self, Debug, Formatter};
 
@@ -10,10 +14,33 @@ pub struct PoseEstimation {
     pub error: f64,
 }
 
+impl PoseEstimation {
+    /// Copy the estimation into an owned [PoseEstimationRecord].
+    pub fn to_record(&self) -> PoseEstimationRecord {
+        self.into()
+    }
+}
+
 /// Estimated pose rotation and translation parameters.
+#[cfg(not(feature = "pure-rust"))]
 #[repr(transparent)]
 pub struct Pose(pub(crate) sys::apriltag_pose_t);
 
+/// Estimated pose rotation and translation parameters.
+#[cfg(feature = "pure-rust")]
+pub struct Pose {
+    /// The rotation in row-major order.
+    rotation: [f64; 9],
+    translation: [f64; 3],
+}
+
+// The pose owns its matrices.
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Send for Pose {}
+#[cfg(not(feature = "pure-rust"))]
+unsafe impl Sync for Pose {}
+
+#[cfg(not(feature = "pure-rust"))]
 impl Pose {
     /// Gets the rotation matrix.
     pub fn rotation(&self) -> MatdRef<'_> {
@@ -26,6 +53,36 @@ impl Pose {
     }
 }
 
+#[cfg(feature = "pure-rust")]
+impl Pose {
+    /// Gets the rotation matrix.
+    pub fn rotation(&self) -> MatdRef<'_> {
+        MatdRef::from_slice(3, 3, &self.rotation)
+    }
+
+    /// Gets the translation matrix.
+    pub fn translation(&self) -> MatdRef<'_> {
+        MatdRef::from_slice(3, 1, &self.translation)
+    }
+}
+
+#[cfg(feature = "pure-rust")]
+impl From<crate::native::pose::RawPose> for Pose {
+    fn from(pose: crate::native::pose::RawPose) -> Self {
+        Self {
+            rotation: pose.rotation.concat().try_into().unwrap(),
+            translation: pose.translation,
+        }
+    }
+}
+
+impl Pose {
+    /// Copy the pose into an owned [PoseRecord].
+    pub fn to_record(&self) -> PoseRecord {
+        self.into()
+    }
+}
+
 impl Debug for Pose {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         formatter
@@ -36,6 +93,7 @@ impl Debug for Pose {
     }
 }
 
+#[cfg(not(feature = "pure-rust"))]
 impl Drop for Pose {
     fn drop(&mut self) {
         unsafe {
@@ -46,7 +104,8 @@ impl Drop for Pose {
 }
 
 /// Stores tag size and camera parameters.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagParams {
     pub tagsize: f64,
     pub fx: f64,
@@ -0,0 +1,359 @@
+//! Image enhancement applied before detection.
+//!
+//! Tags are lost in dim, low contrast or glaring scenes, because the
+//! adaptive threshold cannot separate the black and white cells. A
+//! [Detector](crate::Detector) built with
+//! [add_preprocess](crate::DetectorBuilder::add_preprocess) runs a chain
+//! of [Preprocess] steps on the input image before detection. Alternate
+//! chains added by
+//! [add_preprocess_retry](crate::DetectorBuilder::add_preprocess_retry)
+//! are tried in order when nothing is found.
+//!
+//! ```no_run
+//! use apriltag::{preprocess::Preprocess, Detector, Family, Image};
+//!
+//! let mut detector = Detector::builder()
+//!     .add_family_bits(Family::tag_36h11(), 1)
+//!     .add_preprocess(Preprocess::Gamma { gamma: 2.0 })
+//!     .add_preprocess_retry([Preprocess::Clahe {
+//!         tiles: [8, 8],
+//!         clip_limit: 4.0,
+//!     }])
+//!     .build()?;
+//!
+//! let image = Image::from_pnm_file("dusk.pgm")?;
+//! let detections = detector.detect(&image);
+//! # Ok::<(), apriltag::Error>(())
+//! ```
+
+use crate::{
+    clahe,
+    error::Error,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+};
+
+/// An enhancement step on 8-bit images.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "method", rename_all = "snake_case")
+)]
+pub enum Preprocess {
+    /// Spread the intensities by the histogram of the whole image.
+    EqualizeHistogram,
+
+    /// Equalize by CLAHE on `tiles` of `[columns, rows]`. The
+    /// `clip_limit` is relative to the average bin count of a tile.
+    Clahe { tiles: [usize; 2], clip_limit: f64 },
+
+    /// Map the intensities by `255 * (v / 255)^(1 / gamma)`. A gamma
+    /// above 1 brightens dim images, while a gamma below 1 recovers
+    /// glaring ones.
+    Gamma { gamma: f64 },
+
+    /// Sharpen by `v + amount * (v - blur(v))`, where `blur` is a
+    /// Gaussian blur with standard deviation `sigma` in pixels.
+    Unsharp { sigma: f64, amount: f64 },
+}
+
+impl Preprocess {
+    /// Check that the parameters are in range.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: String| Err(Error::PreprocessError { reason });
+
+        match *self {
+            Self::EqualizeHistogram => Ok(()),
+            Self::Clahe { tiles, clip_limit } => {
+                if tiles.contains(&0) {
+                    return invalid(format!("tiles ({tiles:?}) must be positive"));
+                }
+                if clip_limit.is_nan() || clip_limit < 1.0 {
+                    return invalid(format!("clip limit ({clip_limit}) must be at least 1"));
+                }
+                Ok(())
+            }
+            Self::Gamma { gamma } => {
+                if !(gamma.is_finite() && gamma > 0.0) {
+                    return invalid(format!("gamma ({gamma}) must be positive and finite"));
+                }
+                Ok(())
+            }
+            Self::Unsharp { sigma, amount } => {
+                if !(sigma.is_finite() && sigma > 0.0) {
+                    return invalid(format!("sigma ({sigma}) must be positive and finite"));
+                }
+                if !(amount.is_finite() && amount >= 0.0) {
+                    return invalid(format!("amount ({amount}) must be non-negative and finite"));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Apply the step on an image and return the enhanced copy.
+    pub fn apply(&self, image: &Image) -> Result<Image, Error> {
+        self.validate()?;
+        let mut out =
+            Image::zeros_with_alignment(image.width(), image.height(), DEFAULT_ALIGNMENT_U8)?;
+        self.apply_to(image, &mut out);
+        Ok(out)
+    }
+
+    /// Apply the step on an image with validated parameters. The output
+    /// has the same size as the input.
+    fn apply_to(&self, image: &Image, out: &mut Image) {
+        match *self {
+            Self::EqualizeHistogram => {
+                // Equalization is CLAHE on a single tile without clipping.
+                clahe::equalize(
+                    256,
+                    [1, 1],
+                    f64::INFINITY,
+                    |x, y| image[(x, y)] as usize,
+                    out,
+                );
+            }
+            Self::Clahe { tiles, clip_limit } => {
+                clahe::equalize(256, tiles, clip_limit, |x, y| image[(x, y)] as usize, out);
+            }
+            Self::Gamma { gamma } => {
+                let lut: Vec<u8> = (0..=u8::MAX)
+                    .map(|value| {
+                        let ratio = value as f64 / u8::MAX as f64;
+                        (ratio.powf(gamma.recip()) * u8::MAX as f64).round() as u8
+                    })
+                    .collect();
+                for (x, y, value) in image.indexed_samples_iter() {
+                    out[(x, y)] = lut[value as usize];
+                }
+            }
+            Self::Unsharp { sigma, amount } => {
+                let blurred = gaussian_blur(image, sigma);
+                let width = image.width();
+                for (x, y, value) in image.indexed_samples_iter() {
+                    let value = value as f64;
+                    let sharpened = value + amount * (value - blurred[y * width + x]);
+                    out[(x, y)] = sharpened.round().clamp(0.0, u8::MAX as f64) as u8;
+                }
+            }
+        }
+    }
+}
+
+/// The preprocessing of a [Detector](crate::Detector).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct PreprocessParams {
+    /// The steps applied in order before detection. The image is
+    /// detected as is if it is empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub steps: Vec<Preprocess>,
+
+    /// The alternate chains of steps, each applied on the original
+    /// image. They are tried in order until tags are found. An empty
+    /// chain retries on the original image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub retries: Vec<Vec<Preprocess>>,
+}
+
+impl PreprocessParams {
+    /// Check the parameters of all steps.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.steps
+            .iter()
+            .chain(self.retries.iter().flatten())
+            .try_for_each(Preprocess::validate)
+    }
+
+    /// Return true if neither steps nor retries are configured.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty() && self.retries.is_empty()
+    }
+}
+
+/// Apply a chain of steps on an image.
+///
+/// It returns a copy of the image if `steps` is empty.
+pub fn preprocess(image: &Image, steps: &[Preprocess]) -> Result<Image, Error> {
+    steps.iter().try_for_each(Preprocess::validate)?;
+    Ok(apply_steps(image, steps))
+}
+
+/// Apply a chain of validated steps on an image.
+pub(crate) fn apply_steps(image: &Image, steps: &[Preprocess]) -> Image {
+    let mut current = image.clone();
+    let mut out = Image::zeros_with_alignment(image.width(), image.height(), DEFAULT_ALIGNMENT_U8)
+        .expect("the size of an existing image must be valid");
+    for step in steps {
+        step.apply_to(&current, &mut out);
+        std::mem::swap(&mut current, &mut out);
+    }
+    current
+}
+
+/// Blur the image by a separable Gaussian kernel truncated at 3 sigma.
+/// The borders are extended by replication.
+fn gaussian_blur(image: &Image, sigma: f64) -> Vec<f64> {
+    let width = image.width();
+    let height = image.height();
+    let radius = (3.0 * sigma).ceil() as isize;
+
+    let kernel: Vec<f64> = (-radius..=radius)
+        .map(|offset| (-0.5 * (offset as f64 / sigma).powi(2)).exp())
+        .collect();
+    let sum: f64 = kernel.iter().sum();
+    let kernel: Vec<f64> = kernel.into_iter().map(|weight| weight / sum).collect();
+
+    let convolve = |len: usize, sample: &dyn Fn(usize) -> f64, pos: usize| -> f64 {
+        kernel
+            .iter()
+            .zip(-radius..=radius)
+            .map(|(weight, offset)| {
+                let index = (pos as isize + offset).clamp(0, len as isize - 1) as usize;
+                weight * sample(index)
+            })
+            .sum()
+    };
+
+    let mut horizontal = vec![0.0; width * height];
+    for y in 0..height {
+        let sample = |x: usize| image[(x, y)] as f64;
+        for x in 0..width {
+            horizontal[y * width + x] = convolve(width, &sample, x);
+        }
+    }
+
+    let mut blurred = vec![0.0; width * height];
+    for x in 0..width {
+        let sample = |y: usize| horizontal[y * width + x];
+        for y in 0..height {
+            blurred[y * width + x] = convolve(height, &sample, y);
+        }
+    }
+    blurred
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(width: usize, height: usize, low: u8, high: u8) -> Image {
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                let span = (high - low) as usize;
+                image[(x, y)] = low + (x * span / (width - 1)) as u8;
+            }
+        }
+        image
+    }
+
+    fn range(image: &Image) -> (u8, u8) {
+        image
+            .samples_iter()
+            .fold((u8::MAX, u8::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            })
+    }
+
+    #[test]
+    fn equalize_histogram_stretches_range() {
+        let image = ramp(64, 8, 20, 40);
+        let out = Preprocess::EqualizeHistogram.apply(&image).unwrap();
+        let (min, max) = range(&out);
+        assert!(min < 20, "{min}");
+        assert_eq!(max, 255);
+    }
+
+    #[test]
+    fn gamma_brightens_dim_image() {
+        let image = ramp(64, 8, 0, 64);
+        let out = Preprocess::Gamma { gamma: 2.0 }.apply(&image).unwrap();
+        assert_eq!(out[(0, 0)], 0);
+        assert_eq!(out[(63, 0)], 128);
+        assert!(image
+            .indexed_samples_iter()
+            .all(|(x, y, value)| out[(x, y)] >= value));
+    }
+
+    #[test]
+    fn unsharp_increases_edge_contrast() {
+        let mut image = Image::zeros_with_alignment(32, 8, DEFAULT_ALIGNMENT_U8).unwrap();
+        for y in 0..8 {
+            for x in 0..32 {
+                image[(x, y)] = if x < 16 { 100 } else { 150 };
+            }
+        }
+        let out = Preprocess::Unsharp {
+            sigma: 1.5,
+            amount: 1.0,
+        }
+        .apply(&image)
+        .unwrap();
+
+        assert_eq!(out[(0, 0)], 100);
+        assert_eq!(out[(31, 0)], 150);
+        assert!(out[(15, 0)] < 100);
+        assert!(out[(16, 0)] > 150);
+    }
+
+    #[test]
+    fn chain_applies_in_order() {
+        let image = ramp(64, 8, 0, 64);
+        let steps = [
+            Preprocess::Gamma { gamma: 2.0 },
+            Preprocess::Gamma { gamma: 0.5 },
+        ];
+        let out = preprocess(&image, &steps).unwrap();
+        assert!(image
+            .indexed_samples_iter()
+            .all(|(x, y, value)| out[(x, y)].abs_diff(value) <= 2));
+
+        let copy = preprocess(&image, &[]).unwrap();
+        assert_eq!(
+            copy.samples_iter().collect::<Vec<_>>(),
+            image.samples_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn reject_invalid_params() {
+        let invalid = [
+            Preprocess::Gamma { gamma: 0.0 },
+            Preprocess::Gamma { gamma: f64::NAN },
+            Preprocess::Clahe {
+                tiles: [0, 8],
+                clip_limit: 4.0,
+            },
+            Preprocess::Clahe {
+                tiles: [8, 8],
+                clip_limit: 0.5,
+            },
+            Preprocess::Unsharp {
+                sigma: 0.0,
+                amount: 1.0,
+            },
+            Preprocess::Unsharp {
+                sigma: 1.0,
+                amount: -1.0,
+            },
+        ];
+        for step in invalid {
+            assert!(
+                matches!(step.validate(), Err(Error::PreprocessError { .. })),
+                "{step:?}"
+            );
+        }
+
+        let params = PreprocessParams {
+            steps: vec![Preprocess::EqualizeHistogram],
+            retries: vec![vec![Preprocess::Gamma { gamma: -1.0 }]],
+        };
+        assert!(params.validate().is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! Owned copies of detection results.
+//!
+//! [Detection] and [Pose] wrap buffers allocated by the AprilTag
+//! library. The record types here copy their values into plain Rust
+//! data, which can be stored, sent across threads and, with the
+//! `serde` feature, serialized for logging and replay.
+
+use crate::{
+    detection::Detection,
+    matd::MatdRef,
+    pose::{Pose, PoseEstimation},
+};
+
+/// An owned copy of a [Detection].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DetectionRecord {
+    /// The name of the tag family.
+    pub family: String,
+
+    /// The marker ID.
+    pub id: usize,
+
+    /// The Hamming distance to the target tag.
+    pub hamming: usize,
+
+    /// Indicate the _goodness_ of the detection.
+    pub decision_margin: f32,
+
+    /// The center coordinates in form of `[x, y]` in pixels.
+    pub center: [f64; 2],
+
+    /// The corner coordinates in form of `[[x, y]; 4]` in pixels.
+    pub corners: [[f64; 2]; 4],
+
+    /// The row-major homography matrix that maps tag coordinates to
+    /// pixels.
+    pub homography: [[f64; 3]; 3],
+}
+
+impl From<&Detection> for DetectionRecord {
+    fn from(detection: &Detection) -> Self {
+        Self {
+            family: detection.family_name(),
+            id: detection.id(),
+            hamming: detection.hamming(),
+            decision_margin: detection.decision_margin(),
+            center: detection.center(),
+            corners: detection.corners(),
+            homography: to_array(&detection.homography()),
+        }
+    }
+}
+
+/// An owned copy of a [Pose].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseRecord {
+    /// The row-major rotation matrix.
+    pub rotation: [[f64; 3]; 3],
+
+    /// The translation vector in the same unit as `tagsize`.
+    pub translation: [f64; 3],
+}
+
+impl From<&Pose> for PoseRecord {
+    fn from(pose: &Pose) -> Self {
+        let [[tx], [ty], [tz]] = to_array(&pose.translation());
+        Self {
+            rotation: to_array(&pose.rotation()),
+            translation: [tx, ty, tz],
+        }
+    }
+}
+
+/// An owned copy of a [PoseEstimation].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseEstimationRecord {
+    /// The estimated pose.
+    pub pose: PoseRecord,
+
+    /// The object-space error of the estimation.
+    pub error: f64,
+}
+
+impl From<&PoseEstimation> for PoseEstimationRecord {
+    fn from(estimation: &PoseEstimation) -> Self {
+        Self {
+            pose: (&estimation.pose).into(),
+            error: estimation.error,
+        }
+    }
+}
+
+/// Copy a matrix of known size into an array.
+fn to_array<const R: usize, const C: usize>(matrix: &MatdRef<'_>) -> [[f64; C]; R] {
+    assert_eq!(
+        (matrix.nrows(), matrix.ncols()),
+        (R, C),
+        "please report bug"
+    );
+    let data = matrix.data();
+    std::array::from_fn(|row| std::array::from_fn(|col| data[row * C + col]))
+}
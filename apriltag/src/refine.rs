@@ -0,0 +1,357 @@
+//! Sub-pixel refinement of detected tag corners.
+//!
+//! The detector locates corners on a possibly decimated image, and
+//! their accuracy is limited by
+//! [set_refine_edges](crate::Detector::set_refine_edges) and
+//! [set_decimation](crate::Detector::set_decimation). The refinement
+//! here revisits the full-resolution [Image]. For each of the four
+//! tag edges, it searches the intensity gradient along the edge
+//! normal, fits a line to the gradient peaks by weighted least
+//! squares, and intersects adjacent lines to obtain the corners.
+
+use crate::{homography, image_buf::Image};
+
+/// Parameters for [refine_corners].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefineParams {
+    /// The distance in pixels to search for the edge on both sides of
+    /// the current edge estimate.
+    pub search_radius: f64,
+
+    /// The sampling step in pixels along the edge normal.
+    pub step: f64,
+
+    /// The fraction of the edge length to skip near each corner.
+    pub corner_margin: f64,
+
+    /// The number of fit-and-intersect passes.
+    pub iterations: usize,
+}
+
+impl Default for RefineParams {
+    fn default() -> Self {
+        Self {
+            search_radius: 2.0,
+            step: 0.25,
+            corner_margin: 0.1,
+            iterations: 2,
+        }
+    }
+}
+
+/// Corners refined to sub-pixel accuracy along with the recomputed
+/// homography.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefinedCorners {
+    /// The corner coordinates in form of `[[x, y]; 4]`, in the same
+    /// order as [Detection::corners](crate::Detection::corners).
+    pub corners: [[f64; 2]; 4],
+
+    /// The center coordinates in form of `[x, y]`.
+    pub center: [f64; 2],
+
+    /// The homography that maps tag coordinates to pixels in
+    /// row-major order.
+    pub homography: [[f64; 3]; 3],
+}
+
+/// Refine the corners of a quad on the full-resolution image.
+///
+/// The `corners` are ordered in the same way as
+/// [Detection::corners](crate::Detection::corners). It returns `None`
+/// if any edge cannot be located within the search radius.
+pub fn refine_corners(
+    image: &Image,
+    corners: &[[f64; 2]; 4],
+    params: &RefineParams,
+) -> Option<RefinedCorners> {
+    let mut corners = *corners;
+
+    for _ in 0..params.iterations.max(1) {
+        let center = mean(&corners);
+        let mut lines = [Line::default(); 4];
+
+        for (index, line) in lines.iter_mut().enumerate() {
+            let from = corners[index];
+            let to = corners[(index + 1) % 4];
+            *line = fit_edge(image, from, to, center, params)?;
+        }
+
+        for (index, corner) in corners.iter_mut().enumerate() {
+            let prev = &lines[(index + 3) % 4];
+            let next = &lines[index];
+            *corner = prev.intersect(next)?;
+        }
+    }
+
+    let homography = homography::from_corners(&corners)?;
+    let center = homography::project(&homography, 0.0, 0.0);
+
+    Some(RefinedCorners {
+        corners,
+        center,
+        homography,
+    })
+}
+
+/// A line passing a point along a unit direction.
+#[derive(Debug, Clone, Copy, Default)]
+struct Line {
+    point: [f64; 2],
+    dir: [f64; 2],
+}
+
+impl Line {
+    fn intersect(&self, other: &Line) -> Option<[f64; 2]> {
+        let [px, py] = self.point;
+        let [dx, dy] = self.dir;
+        let [qx, qy] = other.point;
+        let [ex, ey] = other.dir;
+
+        let det = dx * ey - dy * ex;
+        if det.abs() < 1e-9 {
+            return None;
+        }
+        let t = ((qx - px) * ey - (qy - py) * ex) / det;
+        Some([px + t * dx, py + t * dy])
+    }
+}
+
+/// Locate the edge between two corners and fit a line to it.
+fn fit_edge(
+    image: &Image,
+    from: [f64; 2],
+    to: [f64; 2],
+    center: [f64; 2],
+    params: &RefineParams,
+) -> Option<Line> {
+    let RefineParams {
+        search_radius,
+        step,
+        corner_margin,
+        ..
+    } = *params;
+
+    let length = distance(from, to);
+    if length < 1.0 || step <= 0.0 {
+        return None;
+    }
+    let dir = [(to[0] - from[0]) / length, (to[1] - from[1]) / length];
+
+    // Let the normal point outward from the tag.
+    let normal = {
+        let normal = [-dir[1], dir[0]];
+        let mid = [(from[0] + to[0]) / 2.0, (from[1] + to[1]) / 2.0];
+        let outward = (mid[0] - center[0]) * normal[0] + (mid[1] - center[1]) * normal[1];
+        if outward < 0.0 {
+            [-normal[0], -normal[1]]
+        } else {
+            normal
+        }
+    };
+
+    let margin = (length * corner_margin).max(search_radius);
+    let nsamples = (length - 2.0 * margin).floor();
+    if nsamples < 2.0 {
+        return None;
+    }
+    let nsamples = nsamples as usize;
+    let nsteps = (search_radius / step).round() as isize;
+
+    let profiles: Vec<_> = (0..nsamples)
+        .map(|index| {
+            let offset = margin + (length - 2.0 * margin) * (index as f64 + 0.5) / nsamples as f64;
+            let origin = [from[0] + dir[0] * offset, from[1] + dir[1] * offset];
+
+            // Central differences of the intensity along the normal.
+            let grads: Option<Vec<f64>> = (-nsteps..=nsteps)
+                .map(|k| {
+                    let t = k as f64 * step;
+                    let sample = |t: f64| {
+                        sample_bilinear(image, origin[0] + normal[0] * t, origin[1] + normal[1] * t)
+                    };
+                    Some((sample(t + step)? - sample(t - step)?) / (2.0 * step))
+                })
+                .collect();
+            (origin, grads)
+        })
+        .collect();
+
+    // The border is black inside and white outside for regular tags,
+    // and the opposite for tags with reversed borders.
+    let polarity = profiles
+        .iter()
+        .filter_map(|(_, grads)| grads.as_ref())
+        .flatten()
+        .sum::<f64>()
+        .signum();
+
+    let points: Vec<_> = profiles
+        .into_iter()
+        .filter_map(|(origin, grads)| {
+            let grads: Vec<f64> = grads?.into_iter().map(|g| g * polarity).collect();
+            let (peak_index, &peak) = grads
+                .iter()
+                .enumerate()
+                .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))?;
+            if peak <= 0.0 {
+                return None;
+            }
+
+            // Take the centroid of the gradient around the peak above the half maximum.
+            let half = peak / 2.0;
+            let lower = (0..peak_index)
+                .rev()
+                .find(|&index| grads[index] < half)
+                .map(|index| index + 1)
+                .unwrap_or(0);
+            let upper = (peak_index..grads.len())
+                .find(|&index| grads[index] < half)
+                .unwrap_or(grads.len());
+
+            let (sum_w, sum_wt) = (lower..upper).fold((0.0, 0.0), |(sum_w, sum_wt), index| {
+                let w = grads[index] - half;
+                let t = (index as isize - nsteps) as f64 * step;
+                (sum_w + w, sum_wt + w * t)
+            });
+            if sum_w <= 0.0 {
+                return None;
+            }
+            let t = sum_wt / sum_w;
+            let point = [origin[0] + normal[0] * t, origin[1] + normal[1] * t];
+            Some((point, peak))
+        })
+        .collect();
+
+    fit_line(&points)
+}
+
+/// Fit a line to weighted points by total least squares.
+fn fit_line(points: &[([f64; 2], f64)]) -> Option<Line> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let sum_w: f64 = points.iter().map(|(_, w)| w).sum();
+    let mx = points.iter().map(|([x, _], w)| x * w).sum::<f64>() / sum_w;
+    let my = points.iter().map(|([_, y], w)| y * w).sum::<f64>() / sum_w;
+
+    let (cxx, cxy, cyy) = points
+        .iter()
+        .fold((0.0, 0.0, 0.0), |(cxx, cxy, cyy), ([x, y], w)| {
+            let dx = x - mx;
+            let dy = y - my;
+            (cxx + w * dx * dx, cxy + w * dx * dy, cyy + w * dy * dy)
+        });
+
+    // The direction is the eigenvector of the larger eigenvalue.
+    let theta = 0.5 * (2.0 * cxy).atan2(cxx - cyy);
+    Some(Line {
+        point: [mx, my],
+        dir: [theta.cos(), theta.sin()],
+    })
+}
+
+/// Sample the image by bilinear interpolation at pixel centers.
+fn sample_bilinear(image: &Image, x: f64, y: f64) -> Option<f64> {
+    let x = x - 0.5;
+    let y = y - 0.5;
+    let x0 = x.floor();
+    let y0 = y.floor();
+
+    if x0 < 0.0 || y0 < 0.0 {
+        return None;
+    }
+    let (x0, y0) = (x0 as usize, y0 as usize);
+    if x0 + 1 >= image.width() || y0 + 1 >= image.height() {
+        return None;
+    }
+
+    let fx = x - x0 as f64;
+    let fy = y - y0 as f64;
+    let stride = image.stride();
+    let buf = image.as_slice();
+    let at = |x: usize, y: usize| buf[y * stride + x] as f64;
+
+    Some(
+        at(x0, y0) * (1.0 - fx) * (1.0 - fy)
+            + at(x0 + 1, y0) * fx * (1.0 - fy)
+            + at(x0, y0 + 1) * (1.0 - fx) * fy
+            + at(x0 + 1, y0 + 1) * fx * fy,
+    )
+}
+
+fn mean(points: &[[f64; 2]; 4]) -> [f64; 2] {
+    let [sx, sy] = points
+        .iter()
+        .fold([0.0, 0.0], |[sx, sy], [x, y]| [sx + x, sy + y]);
+    [sx / 4.0, sy / 4.0]
+}
+
+fn distance(lhs: [f64; 2], rhs: [f64; 2]) -> f64 {
+    (lhs[0] - rhs[0]).hypot(lhs[1] - rhs[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image_buf::DEFAULT_ALIGNMENT_U8;
+
+    #[test]
+    fn refine_perturbed_corners() {
+        let truth = [[31.3, 92.6], [88.2, 81.9], [77.4, 24.1], [20.7, 35.8]];
+        let image = render_quad(120, 120, &truth);
+
+        let perturbed = [[32.0, 92.0], [87.6, 82.5], [78.1, 24.5], [20.2, 35.1]];
+        let refined = refine_corners(&image, &perturbed, &RefineParams::default()).unwrap();
+
+        refined
+            .corners
+            .iter()
+            .zip(&truth)
+            .for_each(|(&corner, &expect)| {
+                assert!(distance(corner, expect) < 0.05, "{corner:?} vs {expect:?}");
+            });
+
+        homography::TAG_CORNERS
+            .iter()
+            .zip(&refined.corners)
+            .for_each(|(&[x, y], &corner)| {
+                let projected = homography::project(&refined.homography, x, y);
+                assert!(distance(projected, corner) < 1e-6);
+            });
+    }
+
+    /// Render a black convex quad on a white background with
+    /// supersampled anti-aliasing.
+    fn render_quad(width: usize, height: usize, corners: &[[f64; 2]; 4]) -> Image {
+        const SUBSAMPLES: usize = 8;
+
+        let inside = |x: f64, y: f64| {
+            let signs: Vec<_> = (0..4)
+                .map(|index| {
+                    let [ax, ay] = corners[index];
+                    let [bx, by] = corners[(index + 1) % 4];
+                    ((bx - ax) * (y - ay) - (by - ay) * (x - ax)).signum()
+                })
+                .collect();
+            signs.iter().all(|&s| s >= 0.0) || signs.iter().all(|&s| s <= 0.0)
+        };
+
+        let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                let covered = (0..SUBSAMPLES * SUBSAMPLES)
+                    .filter(|&index| {
+                        let sx = x as f64 + ((index % SUBSAMPLES) as f64 + 0.5) / SUBSAMPLES as f64;
+                        let sy = y as f64 + ((index / SUBSAMPLES) as f64 + 0.5) / SUBSAMPLES as f64;
+                        inside(sx, sy)
+                    })
+                    .count();
+                let white = 1.0 - covered as f64 / (SUBSAMPLES * SUBSAMPLES) as f64;
+                image[(x, y)] = (white * 255.0).round() as u8;
+            }
+        }
+        image
+    }
+}
@@ -0,0 +1,216 @@
+//! Timing and counting statistics of the detection pipeline.
+
+#[cfg(not(feature = "pure-rust"))]
+use apriltag_sys as sys;
+use std::time::Duration;
+#[cfg(not(feature = "pure-rust"))]
+use std::{
+    ffi::{c_char, CStr},
+    slice,
+};
+
+/// The statistics of the last detection returned by
+/// [Detector::last_stats](crate::Detector::last_stats).
+///
+/// With multi-scale detection, the timings and counters are the sums
+/// over the passes, and the stamps of the passes are concatenated.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DetectorStats {
+    /// The elapsed time of each stage.
+    pub timings: StageTimings,
+
+    /// The raw time stamps recorded by the detection pipeline.
+    pub stamps: Vec<TimeStamp>,
+
+    /// The number of edges.
+    ///
+    /// The counter is not updated by all versions of the AprilTag library
+    /// nor by the pure-rust backend.
+    pub nedges: usize,
+
+    /// The number of segments.
+    ///
+    /// The counter is not updated by all versions of the AprilTag library
+    /// nor by the pure-rust backend.
+    pub nsegments: usize,
+
+    /// The number of quads fitted to clusters.
+    pub nquads: usize,
+}
+
+impl DetectorStats {
+    /// Gets the total elapsed time of the detection.
+    pub fn total(&self) -> Duration {
+        self.stamps.iter().map(|stamp| stamp.elapsed).sum()
+    }
+
+    #[cfg(not(feature = "pure-rust"))]
+    pub(crate) unsafe fn from_raw(detector: &sys::apriltag_detector_t) -> Self {
+        let stamps = match detector.tp.as_ref() {
+            Some(tp) => read_stamps(tp),
+            None => vec![],
+        };
+        let timings = StageTimings::from_stamps(&stamps);
+
+        Self {
+            timings,
+            stamps,
+            nedges: detector.nedges as usize,
+            nsegments: detector.nsegments as usize,
+            nquads: detector.nquads as usize,
+        }
+    }
+
+    #[cfg(feature = "pure-rust")]
+    pub(crate) fn from_stamps(stamps: Vec<TimeStamp>, nquads: usize) -> Self {
+        Self {
+            timings: StageTimings::from_stamps(&stamps),
+            stamps,
+            nedges: 0,
+            nsegments: 0,
+            nquads,
+        }
+    }
+
+    /// Add the statistics of another pass to the totals.
+    pub(crate) fn accumulate(&mut self, other: &Self) {
+        self.timings.accumulate(&other.timings);
+        self.stamps.extend(other.stamps.iter().cloned());
+        self.nedges += other.nedges;
+        self.nsegments += other.nsegments;
+        self.nquads += other.nquads;
+    }
+
+    #[cfg(feature = "tracing")]
+    pub(crate) fn trace(&self) {
+        for TimeStamp { name, elapsed } in &self.stamps {
+            tracing::trace!(stage = %name, elapsed_us = elapsed.as_micros() as u64);
+        }
+        tracing::debug!(
+            total_us = self.total().as_micros() as u64,
+            nquads = self.nquads,
+            "apriltag detection finished"
+        );
+    }
+}
+
+/// The elapsed time of each stage of the detection pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StageTimings {
+    /// Allocations before detection.
+    pub init: Duration,
+
+    /// Image decimation.
+    pub decimate: Duration,
+
+    /// Gaussian blurring or sharpening.
+    pub blur: Duration,
+
+    /// Adaptive thresholding.
+    pub threshold: Duration,
+
+    /// Union-find segmentation of connected components.
+    pub unionfind: Duration,
+
+    /// Gathering of boundary pixels into clusters.
+    pub clusters: Duration,
+
+    /// Fitting quads to clusters.
+    pub fit_quads: Duration,
+
+    /// Edge refinement and decoding of tag payloads.
+    pub decode: Duration,
+
+    /// Removal of overlapping detections.
+    pub reconcile: Duration,
+
+    /// Writing debug outputs.
+    pub debug: Duration,
+
+    /// Deallocation after detection.
+    pub cleanup: Duration,
+}
+
+impl StageTimings {
+    fn accumulate(&mut self, other: &Self) {
+        self.init += other.init;
+        self.decimate += other.decimate;
+        self.blur += other.blur;
+        self.threshold += other.threshold;
+        self.unionfind += other.unionfind;
+        self.clusters += other.clusters;
+        self.fit_quads += other.fit_quads;
+        self.decode += other.decode;
+        self.reconcile += other.reconcile;
+        self.debug += other.debug;
+        self.cleanup += other.cleanup;
+    }
+
+    fn from_stamps(stamps: &[TimeStamp]) -> Self {
+        let mut timings = Self::default();
+
+        for TimeStamp { name, elapsed } in stamps {
+            let slot = match name.as_str() {
+                "init" => &mut timings.init,
+                "decimate" => &mut timings.decimate,
+                "blur/sharp" => &mut timings.blur,
+                "threshold" => &mut timings.threshold,
+                "unionfind" => &mut timings.unionfind,
+                "make clusters" => &mut timings.clusters,
+                "fit quads to clusters" | "quads" => &mut timings.fit_quads,
+                "decode+refinement" => &mut timings.decode,
+                "reconcile" => &mut timings.reconcile,
+                "debug output" => &mut timings.debug,
+                "cleanup" => &mut timings.cleanup,
+                _ => continue,
+            };
+            *slot += *elapsed;
+        }
+
+        timings
+    }
+}
+
+/// A time stamp recorded by the detection pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeStamp {
+    /// The stage name.
+    pub name: String,
+
+    /// The elapsed time since the previous stamp.
+    pub elapsed: Duration,
+}
+
+/// Mirrors `struct timeprofile_entry` in `common/timeprofile.h`.
+#[cfg(not(feature = "pure-rust"))]
+#[repr(C)]
+struct TimeProfileEntry {
+    name: [c_char; 32],
+    utime: i64,
+}
+
+#[cfg(not(feature = "pure-rust"))]
+unsafe fn read_stamps(tp: &sys::timeprofile_t) -> Vec<TimeStamp> {
+    let Some(stamps) = tp.stamps.as_ref() else {
+        return vec![];
+    };
+    if stamps.size <= 0 || stamps.el_sz != std::mem::size_of::<TimeProfileEntry>() {
+        return vec![];
+    }
+
+    let entries =
+        slice::from_raw_parts(stamps.data as *const TimeProfileEntry, stamps.size as usize);
+
+    let mut last_utime = tp.utime;
+    entries
+        .iter()
+        .map(|entry| {
+            let name = CStr::from_ptr(entry.name.as_ptr())
+                .to_string_lossy()
+                .into_owned();
+            let elapsed = Duration::from_micros((entry.utime - last_utime).max(0) as u64);
+            last_utime = entry.utime;
+            TimeStamp { name, elapsed }
+        })
+        .collect()
+}
@@ -0,0 +1,348 @@
+//! Streaming detection for video pipelines.
+//!
+//! [DetectorStream] adapts a [Stream] of [Image]s into a stream of
+//! detections. The detection runs on a dedicated pool of worker
+//! threads, each owning a [Detector], so that async runtimes are not
+//! blocked. Results are yielded in the order of the input frames.
+//!
+//! ```rust,no_run
+//! use apriltag::{
+//!     stream::{DetectorStream, StreamConfig},
+//!     Detector, Family, Image,
+//! };
+//! use futures::{channel::mpsc, StreamExt};
+//!
+//! # async fn run() -> Result<(), apriltag::Error> {
+//! let (mut tx, rx) = mpsc::channel::<Image>(4);
+//! let mut stream = DetectorStream::new(rx, StreamConfig::default(), || {
+//!     Detector::builder()
+//!         .add_family_bits(Family::tag_36h11(), 1)
+//!         .build()
+//! })?;
+//!
+//! while let Some(frame) = stream.next().await {
+//!     println!("frame {}: {} tags", frame.index, frame.detections.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{detection::Detection, detector::Detector, error::Error, image_buf::Image};
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    Stream, StreamExt,
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex},
+    task::{Context, Poll},
+    thread,
+};
+
+/// The policy to apply when the frame queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropPolicy {
+    /// Stop pulling frames from the input stream until the queue has
+    /// room, propagating the backpressure to the producer.
+    Block,
+
+    /// Drop the oldest queued frame to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Drop the incoming frame.
+    DropNewest,
+}
+
+/// The configuration for [DetectorStream].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// The number of worker threads, each owning a detector.
+    pub num_workers: usize,
+
+    /// The maximum number of frames waiting for a worker.
+    pub queue_size: usize,
+
+    /// The policy to apply when the queue is full.
+    pub drop_policy: DropPolicy,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            num_workers: 1,
+            queue_size: 2,
+            drop_policy: DropPolicy::default(),
+        }
+    }
+}
+
+/// The detections of a frame yielded by [DetectorStream].
+#[derive(Debug)]
+pub struct DetectedFrame {
+    /// The index of the frame in the input stream, counting the
+    /// dropped frames.
+    pub index: usize,
+
+    /// The detections on the frame.
+    pub detections: Vec<Detection>,
+}
+
+/// A [Stream] that runs detection on the frames of the input stream.
+///
+/// Dropping the stream discards the queued frames and returns without
+/// waiting for the workers. The worker threads are detached, and a
+/// worker busy on a frame exits once the frame is done.
+///
+/// See the [module](self) documentation for an example.
+pub struct DetectorStream<S> {
+    input: Option<S>,
+    config: StreamConfig,
+    shared: Arc<Shared>,
+    results: UnboundedReceiver<(u64, Option<DetectedFrame>)>,
+    disconnected: bool,
+    next_frame_index: usize,
+    next_seq: u64,
+    next_output_seq: u64,
+    in_flight: usize,
+    reorder: BTreeMap<u64, Option<DetectedFrame>>,
+    dropped: usize,
+    failed: usize,
+}
+
+struct Job {
+    seq: u64,
+    frame_index: usize,
+    image: Image,
+}
+
+#[derive(Default)]
+struct Queue {
+    jobs: VecDeque<Job>,
+    closed: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    queue: Mutex<Queue>,
+    cond: Condvar,
+}
+
+impl<S> DetectorStream<S>
+where
+    S: Stream<Item = Image> + Unpin,
+{
+    /// Create a stream that runs detection on frames from `input`.
+    ///
+    /// The `make_detector` is called once for each worker.
+    pub fn new<F>(input: S, config: StreamConfig, make_detector: F) -> Result<Self, Error>
+    where
+        F: Fn() -> Result<Detector, Error>,
+    {
+        if config.num_workers == 0 || config.queue_size == 0 {
+            return Err(Error::CreateDetectorError {
+                reason: "num_workers and queue_size must be positive".to_string(),
+            });
+        }
+
+        let shared = Arc::new(Shared::default());
+        let (tx, rx) = mpsc::unbounded();
+
+        let detectors: Vec<_> = (0..config.num_workers)
+            .map(|_| make_detector())
+            .collect::<Result<_, _>>()?;
+        for detector in detectors {
+            let shared = shared.clone();
+            let tx = tx.clone();
+            thread::spawn(move || worker_loop(detector, &shared, tx));
+        }
+
+        Ok(Self {
+            input: Some(input),
+            config,
+            shared,
+            results: rx,
+            disconnected: false,
+            next_frame_index: 0,
+            next_seq: 0,
+            next_output_seq: 0,
+            in_flight: 0,
+            reorder: BTreeMap::new(),
+            dropped: 0,
+            failed: 0,
+        })
+    }
+
+    /// Gets the number of frames dropped so far.
+    pub fn dropped_frames(&self) -> usize {
+        self.dropped
+    }
+
+    /// Gets the number of frames lost because a worker panicked.
+    ///
+    /// The worker that panicked exits. Once all workers have exited, the
+    /// stream yields the finished frames and ends.
+    pub fn failed_frames(&self) -> usize {
+        self.failed
+    }
+
+    /// Pull frames from the input stream into the queue.
+    fn pull_input(&mut self, cx: &mut Context<'_>) {
+        while let Some(input) = &mut self.input {
+            if self.config.drop_policy == DropPolicy::Block
+                && self.shared.queue.lock().unwrap().jobs.len() >= self.config.queue_size
+            {
+                break;
+            }
+
+            let image = match input.poll_next_unpin(cx) {
+                Poll::Ready(Some(image)) => image,
+                Poll::Ready(None) => {
+                    self.input = None;
+                    break;
+                }
+                Poll::Pending => break,
+            };
+            let frame_index = self.next_frame_index;
+            self.next_frame_index += 1;
+
+            let mut queue = self.shared.queue.lock().unwrap();
+            if queue.jobs.len() >= self.config.queue_size {
+                self.dropped += 1;
+                match self.config.drop_policy {
+                    DropPolicy::DropNewest => continue,
+                    DropPolicy::DropOldest => {
+                        let job = queue.jobs.pop_front().unwrap();
+                        self.reorder.insert(job.seq, None);
+                        self.in_flight -= 1;
+                    }
+                    DropPolicy::Block => unreachable!(),
+                }
+            }
+
+            let seq = self.next_seq;
+            self.next_seq += 1;
+            self.in_flight += 1;
+            queue.jobs.push_back(Job {
+                seq,
+                frame_index,
+                image,
+            });
+            self.shared.cond.notify_one();
+        }
+    }
+}
+
+impl<S> Stream for DetectorStream<S>
+where
+    S: Stream<Item = Image> + Unpin,
+{
+    type Item = DetectedFrame;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            // Yield the results in order, skipping dropped frames.
+            while let Some(entry) = this.reorder.first_entry() {
+                if *entry.key() != this.next_output_seq {
+                    break;
+                }
+                this.next_output_seq += 1;
+                if let Some(frame) = entry.remove() {
+                    return Poll::Ready(Some(frame));
+                }
+            }
+
+            if this.disconnected {
+                // The queued frames are never processed, so yield the
+                // finished frames and end the stream.
+                while let Some((_, frame)) = this.reorder.pop_first() {
+                    if let Some(frame) = frame {
+                        return Poll::Ready(Some(frame));
+                    }
+                }
+                return Poll::Ready(None);
+            }
+
+            this.pull_input(cx);
+
+            if this.input.is_none() && this.in_flight == 0 && this.reorder.is_empty() {
+                return Poll::Ready(None);
+            }
+
+            match this.results.poll_next_unpin(cx) {
+                Poll::Ready(Some((seq, frame))) => {
+                    this.in_flight -= 1;
+                    if frame.is_none() {
+                        this.failed += 1;
+                    }
+                    this.reorder.insert(seq, frame);
+                }
+                Poll::Ready(None) => {
+                    // All workers have exited after panicking.
+                    this.disconnected = true;
+                    this.input = None;
+                    this.failed += this.in_flight;
+                    this.in_flight = 0;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S> Drop for DetectorStream<S> {
+    fn drop(&mut self) {
+        {
+            let mut queue = self.shared.queue.lock().unwrap();
+            queue.closed = true;
+            queue.jobs.clear();
+        }
+        self.shared.cond.notify_all();
+    }
+}
+
+fn worker_loop(
+    mut detector: Detector,
+    shared: &Shared,
+    tx: UnboundedSender<(u64, Option<DetectedFrame>)>,
+) {
+    loop {
+        let job = {
+            let mut queue = shared.queue.lock().unwrap();
+            loop {
+                if queue.closed {
+                    return;
+                }
+                if let Some(job) = queue.jobs.pop_front() {
+                    break job;
+                }
+                queue = shared.cond.wait(queue).unwrap();
+            }
+        };
+
+        let Job {
+            seq,
+            frame_index,
+            image,
+        } = job;
+        // Report a panic as a skipped frame so that the stream does not
+        // wait for it, and stop using the detector.
+        let Ok(detections) = panic::catch_unwind(AssertUnwindSafe(|| detector.detect(&image)))
+        else {
+            let _ = tx.unbounded_send((seq, None));
+            return;
+        };
+        let frame = DetectedFrame {
+            index: frame_index,
+            detections,
+        };
+
+        if tx.unbounded_send((seq, Some(frame))).is_err() {
+            return;
+        }
+    }
+}
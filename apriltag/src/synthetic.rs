@@ -0,0 +1,664 @@
+//! Synthetic tag scenes with exact ground truth.
+//!
+//! [render] draws tags of a [Family] into an [Image] as seen by a
+//! pinhole [Camera] with optional lens [Distortion]. Each tag is
+//! placed by a tag-to-camera pose, and the returned [SyntheticScene]
+//! carries the projected corners and the pose of every tag. [Effects]
+//! degrade the image with occlusion, illumination gradients, blur and
+//! noise to emulate real captures.
+//!
+//! The tag frame follows [estimate_tag_pose](crate::Detection::estimate_tag_pose).
+//! The x axis points to the right of the tag, the y axis points down
+//! and the z axis points into the tag. A tag at the identity rotation
+//! faces the camera upright.
+//!
+//! ```rust
+//! use apriltag::{
+//!     synthetic::{self, Camera, Effects, SyntheticTag},
+//!     Detector, Family, PoseRecord,
+//! };
+//!
+//! let family = Family::tag_36h11();
+//! let camera = Camera::new(640, 480, 600.0, 600.0, 320.0, 240.0);
+//! let tag = SyntheticTag {
+//!     family: &family,
+//!     id: 7,
+//!     size: 0.1,
+//!     pose: PoseRecord {
+//!         rotation: synthetic::euler_rotation(0.2, -0.3, 0.1),
+//!         translation: [0.0, 0.0, 0.5],
+//!     },
+//! };
+//! let effects = Effects {
+//!     blur_sigma: 0.8,
+//!     noise_stddev: 4.0,
+//!     ..Effects::default()
+//! };
+//! let scene = synthetic::render(&camera, &[tag], &effects)?;
+//!
+//! let mut detector = Detector::builder().add_family_bits(family, 1).build()?;
+//! let detections = detector.detect(&scene.image);
+//! assert_eq!(detections[0].id(), scene.tags[0].id);
+//! # Ok::<(), apriltag::Error>(())
+//! ```
+
+use crate::{
+    error::Error,
+    eval::LabelledTag,
+    families::Family,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+    pose::TagParams,
+    record::PoseRecord,
+};
+
+/// A pinhole camera with lens distortion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera {
+    /// The image width in pixels.
+    pub width: usize,
+
+    /// The image height in pixels.
+    pub height: usize,
+
+    pub fx: f64,
+    pub fy: f64,
+    pub cx: f64,
+    pub cy: f64,
+
+    /// The lens distortion.
+    pub distortion: Distortion,
+}
+
+impl Camera {
+    /// Create a camera without distortion.
+    pub fn new(width: usize, height: usize, fx: f64, fy: f64, cx: f64, cy: f64) -> Self {
+        Self {
+            width,
+            height,
+            fx,
+            fy,
+            cx,
+            cy,
+            distortion: Distortion::default(),
+        }
+    }
+
+    /// Gets the [TagParams] to estimate poses of tags of the size.
+    pub fn tag_params(&self, tagsize: f64) -> TagParams {
+        TagParams {
+            tagsize,
+            fx: self.fx,
+            fy: self.fy,
+            cx: self.cx,
+            cy: self.cy,
+        }
+    }
+
+    /// Project a point in the camera frame to pixel coordinates.
+    ///
+    /// It returns `None` if the point is not in front of the camera.
+    pub fn project(&self, [x, y, z]: [f64; 3]) -> Option<[f64; 2]> {
+        if z <= 0.0 {
+            return None;
+        }
+        let [xd, yd] = self.distortion.distort([x / z, y / z]);
+        Some([self.fx * xd + self.cx, self.fy * yd + self.cy])
+    }
+
+    /// Gets the undistorted normalized coordinates of the ray through
+    /// the pixel coordinates.
+    fn unproject(&self, [px, py]: [f64; 2]) -> [f64; 2] {
+        self.distortion
+            .undistort([(px - self.cx) / self.fx, (py - self.cy) / self.fy])
+    }
+}
+
+/// The Brown-Conrady lens distortion model.
+///
+/// The coefficients follow the OpenCV convention. All coefficients are
+/// zero by default.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Distortion {
+    /// The radial coefficients.
+    pub k1: f64,
+    pub k2: f64,
+    pub k3: f64,
+
+    /// The tangential coefficients.
+    pub p1: f64,
+    pub p2: f64,
+}
+
+impl Distortion {
+    /// Distort normalized image coordinates.
+    pub fn distort(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let r2 = x * x + y * y;
+        let radial = 1.0 + r2 * (self.k1 + r2 * (self.k2 + r2 * self.k3));
+        [
+            x * radial + 2.0 * self.p1 * x * y + self.p2 * (r2 + 2.0 * x * x),
+            y * radial + self.p1 * (r2 + 2.0 * y * y) + 2.0 * self.p2 * x * y,
+        ]
+    }
+
+    /// Undistort normalized image coordinates by fixed-point iteration.
+    pub fn undistort(&self, [xd, yd]: [f64; 2]) -> [f64; 2] {
+        if *self == Self::default() {
+            return [xd, yd];
+        }
+
+        let [mut x, mut y] = [xd, yd];
+        for _ in 0..20 {
+            let r2 = x * x + y * y;
+            let radial = 1.0 + r2 * (self.k1 + r2 * (self.k2 + r2 * self.k3));
+            let dx = 2.0 * self.p1 * x * y + self.p2 * (r2 + 2.0 * x * x);
+            let dy = self.p1 * (r2 + 2.0 * y * y) + 2.0 * self.p2 * x * y;
+            x = (xd - dx) / radial;
+            y = (yd - dy) / radial;
+        }
+        [x, y]
+    }
+}
+
+/// A tag placed in a synthetic scene.
+#[derive(Debug, Clone)]
+pub struct SyntheticTag<'a> {
+    /// The tag family.
+    pub family: &'a Family,
+
+    /// The marker ID.
+    pub id: usize,
+
+    /// The width of the tag border, in the unit of the translation.
+    pub size: f64,
+
+    /// The pose that maps tag coordinates to camera coordinates.
+    pub pose: PoseRecord,
+}
+
+/// Image degradations applied to a synthetic scene.
+///
+/// They are applied in the order of occlusion, illumination, blur and
+/// noise. The default renders a clean image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Effects {
+    /// The intensity of pixels not covered by tags.
+    pub background: u8,
+
+    /// The number of samples per pixel along each axis for
+    /// anti-aliasing.
+    pub supersampling: usize,
+
+    /// Rectangles drawn over the tags.
+    pub occluders: Vec<Occluder>,
+
+    /// The illumination gradient.
+    pub illumination: Illumination,
+
+    /// The standard deviation in pixels of the Gaussian blur.
+    pub blur_sigma: f64,
+
+    /// The standard deviation of the additive Gaussian noise.
+    pub noise_stddev: f64,
+
+    /// The seed of the noise.
+    pub seed: u64,
+}
+
+impl Default for Effects {
+    fn default() -> Self {
+        Self {
+            background: 255,
+            supersampling: 3,
+            occluders: vec![],
+            illumination: Illumination::default(),
+            blur_sigma: 0.0,
+            noise_stddev: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+/// A rectangle in pixel coordinates painted with a solid intensity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occluder {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+    pub intensity: u8,
+}
+
+impl Occluder {
+    fn contains(&self, [x, y]: [f64; 2]) -> bool {
+        (self.left..self.left + self.width).contains(&x)
+            && (self.top..self.top + self.height).contains(&y)
+    }
+}
+
+/// A linear illumination gradient.
+///
+/// Each intensity is multiplied by the gain at the pixel, which is
+/// `gain + gradient[0] * (x - cx) + gradient[1] * (y - cy)` with
+/// `(cx, cy)` at the image center.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Illumination {
+    /// The gain at the image center.
+    pub gain: f64,
+
+    /// The change of gain per pixel along the x and y axes.
+    pub gradient: [f64; 2],
+}
+
+impl Default for Illumination {
+    fn default() -> Self {
+        Self {
+            gain: 1.0,
+            gradient: [0.0, 0.0],
+        }
+    }
+}
+
+/// A rendered scene along with the ground truth.
+#[derive(Debug)]
+pub struct SyntheticScene {
+    /// The rendered image.
+    pub image: Image,
+
+    /// The ground truth of each tag, in the order of the input tags.
+    pub tags: Vec<TagTruth>,
+}
+
+/// The ground truth of a rendered tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagTruth {
+    /// The name of the tag family.
+    pub family: String,
+
+    /// The marker ID.
+    pub id: usize,
+
+    /// The projected corner coordinates in form of `[[x, y]; 4]` in
+    /// pixels, in the same order as
+    /// [Detection::corners](crate::Detection::corners).
+    pub corners: [[f64; 2]; 4],
+
+    /// The projected tag center in form of `[x, y]` in pixels.
+    pub center: [f64; 2],
+
+    /// The tag-to-camera pose.
+    pub pose: PoseRecord,
+}
+
+impl From<&TagTruth> for LabelledTag {
+    fn from(truth: &TagTruth) -> Self {
+        Self {
+            family: Some(truth.family.clone()),
+            id: truth.id,
+            corners: truth.corners,
+            pose: Some(truth.pose.clone()),
+        }
+    }
+}
+
+/// Render tags into an image under the camera.
+///
+/// Tags closer to the camera hide tags behind them. It fails if the
+/// camera or the effects are invalid, a tag ID is out of range, or
+/// any tag corner is behind the camera.
+pub fn render(
+    camera: &Camera,
+    tags: &[SyntheticTag<'_>],
+    effects: &Effects,
+) -> Result<SyntheticScene, Error> {
+    let Camera { width, height, .. } = *camera;
+    if width == 0 || height == 0 || effects.supersampling == 0 {
+        return Err(Error::CreateImageError {
+            reason: "the image size and supersampling must be positive".to_string(),
+        });
+    }
+
+    let planes = tags
+        .iter()
+        .map(|tag| TagPlane::new(tag))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let truths = tags
+        .iter()
+        .map(|tag| {
+            let corners =
+                TAG_CORNERS.map(|[u, v]| transform(&tag.pose, [u * tag.size, v * tag.size]));
+            let project = |point| {
+                camera
+                    .project(point)
+                    .ok_or_else(|| Error::CreateImageError {
+                        reason: format!("tag {} is behind the camera", tag.id),
+                    })
+            };
+            Ok(TagTruth {
+                family: tag.family.kind().name().to_string(),
+                id: tag.id,
+                corners: [
+                    project(corners[0])?,
+                    project(corners[1])?,
+                    project(corners[2])?,
+                    project(corners[3])?,
+                ],
+                center: project(tag.pose.translation)?,
+                pose: tag.pose.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    // Render the scene with supersampling.
+    let samples = effects.supersampling;
+    let mut buffer = vec![0f64; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0.0;
+            for sy in 0..samples {
+                for sx in 0..samples {
+                    let point = [
+                        x as f64 + (sx as f64 + 0.5) / samples as f64,
+                        y as f64 + (sy as f64 + 0.5) / samples as f64,
+                    ];
+                    sum += sample(camera, &planes, effects, point);
+                }
+            }
+            buffer[x + y * width] = sum / (samples * samples) as f64;
+        }
+    }
+
+    let Illumination {
+        gain,
+        gradient: [gx, gy],
+    } = effects.illumination;
+    if gain != 1.0 || gx != 0.0 || gy != 0.0 {
+        for y in 0..height {
+            for x in 0..width {
+                let dx = x as f64 + 0.5 - width as f64 / 2.0;
+                let dy = y as f64 + 0.5 - height as f64 / 2.0;
+                buffer[x + y * width] *= (gain + gx * dx + gy * dy).max(0.0);
+            }
+        }
+    }
+
+    if effects.blur_sigma > 0.0 {
+        gaussian_blur(&mut buffer, width, height, effects.blur_sigma);
+    }
+
+    if effects.noise_stddev > 0.0 {
+        let mut rng = SplitMix64(effects.seed);
+        for value in &mut buffer {
+            *value += rng.next_gaussian() * effects.noise_stddev;
+        }
+    }
+
+    let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+    for y in 0..height {
+        for x in 0..width {
+            image[(x, y)] = buffer[x + y * width].round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    Ok(SyntheticScene {
+        image,
+        tags: truths,
+    })
+}
+
+/// Build a rotation matrix from rotation angles in radians about the
+/// x, y and z axes, which are applied in that order.
+pub fn euler_rotation(rx: f64, ry: f64, rz: f64) -> [[f64; 3]; 3] {
+    let (sx, cx) = rx.sin_cos();
+    let (sy, cy) = ry.sin_cos();
+    let (sz, cz) = rz.sin_cos();
+    [
+        [cz * cy, cz * sy * sx - sz * cx, cz * sy * cx + sz * sx],
+        [sz * cy, sz * sy * sx + cz * cx, sz * sy * cx - cz * sx],
+        [-sy, cy * sx, cy * cx],
+    ]
+}
+
+/// The corners in the unit of the tag size, in the order of
+/// [Detection::corners](crate::Detection::corners).
+const TAG_CORNERS: [[f64; 2]; 4] = [[-0.5, 0.5], [0.5, 0.5], [0.5, -0.5], [-0.5, -0.5]];
+
+/// Map a point on the tag plane to the camera frame.
+fn transform(pose: &PoseRecord, [u, v]: [f64; 2]) -> [f64; 3] {
+    let r = &pose.rotation;
+    let t = &pose.translation;
+    [
+        r[0][0] * u + r[0][1] * v + t[0],
+        r[1][0] * u + r[1][1] * v + t[1],
+        r[2][0] * u + r[2][1] * v + t[2],
+    ]
+}
+
+/// A tag prepared for ray casting.
+struct TagPlane {
+    /// The inverse of `[r1 r2 t]`, which maps a ray to homogeneous
+    /// tag plane coordinates.
+    inverse: [[f64; 3]; 3],
+
+    /// The tag cells, where `cells[x + y * total_width]` is the
+    /// intensity.
+    cells: Vec<u8>,
+
+    total_width: usize,
+
+    /// The tag plane coordinates of the top-left corner of the cells.
+    origin: f64,
+
+    cell_size: f64,
+}
+
+impl TagPlane {
+    fn new(tag: &SyntheticTag<'_>) -> Result<Self, Error> {
+        let cells_image = tag.family.to_image(tag.id)?;
+        let total_width = tag.family.total_width();
+        let width_at_border = tag.family.width_at_border();
+        let cells = (0..total_width)
+            .flat_map(|y| (0..total_width).map(move |x| (x, y)))
+            .map(|(x, y)| cells_image[(x, y)])
+            .collect();
+
+        let r = &tag.pose.rotation;
+        let t = &tag.pose.translation;
+        let matrix = [
+            [r[0][0], r[0][1], t[0]],
+            [r[1][0], r[1][1], t[1]],
+            [r[2][0], r[2][1], t[2]],
+        ];
+        let inverse = invert(&matrix).ok_or_else(|| Error::CreateImageError {
+            reason: format!("tag {} is seen edge-on by the camera", tag.id),
+        })?;
+
+        let cell_size = tag.size / width_at_border as f64;
+        Ok(Self {
+            inverse,
+            cells,
+            total_width,
+            origin: -(total_width as f64) * cell_size / 2.0,
+            cell_size,
+        })
+    }
+
+    /// Cast the ray and get the depth and the intensity of the hit
+    /// cell.
+    fn hit(&self, [x, y]: [f64; 2]) -> Option<(f64, u8)> {
+        let m = &self.inverse;
+        let [u, v, w] = [0, 1, 2].map(|row| m[row][0] * x + m[row][1] * y + m[row][2]);
+        if w <= 0.0 {
+            return None;
+        }
+
+        let col = ((u / w - self.origin) / self.cell_size).floor();
+        let row = ((v / w - self.origin) / self.cell_size).floor();
+        let range = 0.0..self.total_width as f64;
+        if !range.contains(&col) || !range.contains(&row) {
+            return None;
+        }
+        let intensity = self.cells[col as usize + row as usize * self.total_width];
+        Some((1.0 / w, intensity))
+    }
+}
+
+/// Gets the scene intensity at the pixel coordinates.
+fn sample(camera: &Camera, planes: &[TagPlane], effects: &Effects, point: [f64; 2]) -> f64 {
+    if let Some(occluder) = effects
+        .occluders
+        .iter()
+        .rev()
+        .find(|occluder| occluder.contains(point))
+    {
+        return occluder.intensity as f64;
+    }
+
+    let ray = camera.unproject(point);
+    planes
+        .iter()
+        .filter_map(|plane| plane.hit(ray))
+        .min_by(|(lhs, _), (rhs, _)| lhs.total_cmp(rhs))
+        .map(|(_, intensity)| intensity)
+        .unwrap_or(effects.background) as f64
+}
+
+/// Blur the row-major buffer in place with a separable Gaussian
+/// kernel, extending the borders.
+fn gaussian_blur(buffer: &mut [f64], width: usize, height: usize, sigma: f64) {
+    let radius = (sigma * 3.0).ceil() as isize;
+    let kernel: Vec<f64> = (-radius..=radius)
+        .map(|offset| (-((offset * offset) as f64) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let norm: f64 = kernel.iter().sum();
+
+    let convolve = |input: &[f64], len: usize, at: &dyn Fn(usize) -> usize| -> Vec<f64> {
+        (0..len)
+            .map(|index| {
+                kernel
+                    .iter()
+                    .enumerate()
+                    .map(|(k, weight)| {
+                        let pos = (index as isize + k as isize - radius).clamp(0, len as isize - 1);
+                        weight * input[at(pos as usize)]
+                    })
+                    .sum::<f64>()
+                    / norm
+            })
+            .collect()
+    };
+
+    for y in 0..height {
+        let row = convolve(buffer, width, &|x| x + y * width);
+        buffer[y * width..(y + 1) * width].copy_from_slice(&row);
+    }
+    for x in 0..width {
+        let col = convolve(buffer, height, &|y| x + y * width);
+        for (y, value) in col.into_iter().enumerate() {
+            buffer[x + y * width] = value;
+        }
+    }
+}
+
+/// Invert a 3x3 matrix.
+fn invert(m: &[[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let cofactor = |row: usize, col: usize| {
+        let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+        let (c0, c1) = ((col + 1) % 3, (col + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let det: f64 = (0..3).map(|col| m[0][col] * cofactor(0, col)).sum();
+    if det.abs() < 1e-12 {
+        return None;
+    }
+    Some(std::array::from_fn(|row| {
+        std::array::from_fn(|col| cofactor(col, row) / det)
+    }))
+}
+
+/// A small deterministic random number generator.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a uniform sample in (0, 1].
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Gets a standard normal sample by the Box-Muller transform.
+    fn next_gaussian(&mut self) -> f64 {
+        let radius = (-2.0 * self.next_f64().ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.next_f64();
+        radius * angle.cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FamilyKind;
+
+    #[test]
+    fn distortion_roundtrip() {
+        let distortion = Distortion {
+            k1: -0.2,
+            k2: 0.05,
+            k3: 0.0,
+            p1: 0.001,
+            p2: -0.002,
+        };
+        for point in [[0.0, 0.0], [0.3, -0.2], [-0.5, 0.4]] {
+            let [x, y] = distortion.undistort(distortion.distort(point));
+            assert!((x - point[0]).abs() < 1e-9 && (y - point[1]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rotation_is_orthonormal() {
+        let r = euler_rotation(0.3, -0.7, 1.1);
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f64 = (0..3).map(|k| r[k][i] * r[k][j]).sum();
+                let expect = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expect).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn fronto_parallel_corners() {
+        let family = FamilyKind::Tag16h5.create();
+        let camera = Camera::new(200, 200, 500.0, 500.0, 100.0, 100.0);
+        let tag = SyntheticTag {
+            family: &family,
+            id: 0,
+            size: 0.1,
+            pose: PoseRecord {
+                rotation: euler_rotation(0.0, 0.0, 0.0),
+                translation: [0.0, 0.0, 0.5],
+            },
+        };
+        let scene = render(&camera, &[tag], &Effects::default()).unwrap();
+
+        // The 0.1 tag at 0.5 away spans 100 pixels.
+        let truth = &scene.tags[0];
+        assert_eq!(truth.family, "tag16h5");
+        assert_eq!(
+            truth.corners,
+            [[50.0, 150.0], [150.0, 150.0], [150.0, 50.0], [50.0, 50.0]]
+        );
+        assert_eq!(truth.center, [100.0, 100.0]);
+
+        // The border is black and the outer ring is white.
+        assert_eq!(scene.image[(55, 100)], 0);
+        assert_eq!(scene.image[(40, 100)], 255);
+        assert_eq!(scene.image[(5, 5)], 255);
+    }
+}
@@ -0,0 +1,373 @@
+//! Conversion of high bit-depth frames to 8-bit images.
+//!
+//! Thermal and scientific cameras deliver 10 to 16 bits per pixel,
+//! while the detector works on 8-bit [Image]s. [tone_map] maps 16-bit
+//! samples to 8 bits by a [ToneMapping], which is either a fixed
+//! window, a window stretched between percentiles of the frame, a
+//! gamma curve or CLAHE. The applied parameters are returned along with
+//! the image in [AppliedToneMap], so that they can be logged alongside
+//! the detections.
+
+use crate::{
+    clahe,
+    error::Error,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+};
+
+/// The number of histogram bins used by CLAHE on 16-bit samples.
+const CLAHE_BINS: usize = 4096;
+
+/// The mapping from high bit-depth samples to 8 bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "method", rename_all = "snake_case")
+)]
+pub enum ToneMapping {
+    /// Map the window from `low` to `high` linearly to 0 to 255.
+    /// Samples outside the window are saturated.
+    Linear { low: u16, high: u16 },
+
+    /// Map linearly the window between the `low` and `high`
+    /// percentiles of the frame, in range 0 to 100.
+    Percentile { low: f64, high: f64 },
+
+    /// Map the full range of the bit depth by `255 * (v / max)^(1 / gamma)`.
+    /// A gamma above 1 brightens dim frames.
+    Gamma { gamma: f64 },
+
+    /// Equalize the window between the minimum and the maximum of the
+    /// frame by CLAHE on `tiles` of `[columns, rows]`. The `clip_limit`
+    /// is relative to the average bin count of a tile.
+    Clahe { tiles: [usize; 2], clip_limit: f64 },
+}
+
+/// Parameters for [tone_map].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToneMapParams {
+    /// The number of significant bits of the samples, from 1 to 16.
+    /// Samples above the maximum of the bit depth are saturated.
+    pub bit_depth: u32,
+
+    /// The tone mapping method.
+    pub mapping: ToneMapping,
+}
+
+impl Default for ToneMapParams {
+    fn default() -> Self {
+        Self {
+            bit_depth: 16,
+            mapping: ToneMapping::Percentile {
+                low: 1.0,
+                high: 99.0,
+            },
+        }
+    }
+}
+
+/// The parameters of a tone mapping applied to a frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppliedToneMap {
+    /// The requested parameters.
+    pub params: ToneMapParams,
+
+    /// The window of samples in form of `[low, high]` that is mapped to
+    /// the 8-bit range. It is resolved from the frame for the
+    /// percentile and CLAHE methods.
+    pub window: [u16; 2],
+}
+
+/// An 8-bit image converted by [tone_map].
+#[derive(Debug, Clone)]
+pub struct ToneMapped {
+    pub image: Image,
+    pub applied: AppliedToneMap,
+}
+
+/// Map 16-bit samples to an 8-bit image.
+///
+/// The sample at `(x, y)` is located at `samples[y * stride + x]`.
+pub fn tone_map(
+    samples: &[u16],
+    width: usize,
+    height: usize,
+    stride: usize,
+    params: &ToneMapParams,
+) -> Result<ToneMapped, Error> {
+    let ToneMapParams { bit_depth, mapping } = *params;
+    let invalid = |reason: String| Error::ToneMapError { reason };
+
+    if !(1..=16).contains(&bit_depth) {
+        return Err(invalid(format!(
+            "bit depth ({bit_depth}) must be in range 1 to 16"
+        )));
+    }
+    if width > stride {
+        return Err(invalid(format!(
+            "width ({width}) must be less than or equal to stride ({stride})"
+        )));
+    }
+    let required = if height == 0 {
+        0
+    } else {
+        (height - 1) * stride + width
+    };
+    if samples.len() < required {
+        return Err(invalid(format!(
+            "expect at least {required} samples, but get {}",
+            samples.len()
+        )));
+    }
+
+    let max_value = ((1u32 << bit_depth) - 1) as u16;
+    let sample = |x: usize, y: usize| samples[y * stride + x].min(max_value);
+    let rows = || {
+        (0..height).map(move |y| {
+            let start = y * stride;
+            &samples[start..start + width]
+        })
+    };
+    let mut image = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+
+    let window = match mapping {
+        ToneMapping::Linear { low, high } => {
+            if low >= high {
+                return Err(invalid(format!(
+                    "low ({low}) must be less than high ({high})"
+                )));
+            }
+            apply_lut(&mut image, rows(), &linear_lut(low, high, max_value));
+            [low, high]
+        }
+        ToneMapping::Percentile { low, high } => {
+            if !(0.0 <= low && low < high && high <= 100.0) {
+                return Err(invalid(format!(
+                    "percentiles ({low}, {high}) must be increasing in range 0 to 100"
+                )));
+            }
+            let hist = histogram(rows(), max_value);
+            let lower = percentile(&hist, low);
+            let upper = percentile(&hist, high).max(lower.saturating_add(1));
+            apply_lut(&mut image, rows(), &linear_lut(lower, upper, max_value));
+            [lower, upper]
+        }
+        ToneMapping::Gamma { gamma } => {
+            if !(gamma.is_finite() && gamma > 0.0) {
+                return Err(invalid(format!("gamma ({gamma}) must be positive")));
+            }
+            let lut: Vec<u8> = (0..=max_value)
+                .map(|value| {
+                    let ratio = value as f64 / max_value as f64;
+                    (ratio.powf(1.0 / gamma) * u8::MAX as f64).round() as u8
+                })
+                .collect();
+            apply_lut(&mut image, rows(), &lut);
+            [0, max_value]
+        }
+        ToneMapping::Clahe { tiles, clip_limit } => {
+            if tiles.contains(&0) {
+                return Err(invalid(format!("tiles ({tiles:?}) must be positive")));
+            }
+            if !(clip_limit.is_finite() && clip_limit > 0.0) {
+                return Err(invalid(format!(
+                    "clip limit ({clip_limit}) must be positive"
+                )));
+            }
+
+            let hist = histogram(rows(), max_value);
+            let lower = percentile(&hist, 0.0);
+            let upper = percentile(&hist, 100.0);
+            let range = (upper - lower) as usize;
+            let bins = (range + 1).min(CLAHE_BINS);
+            let bin_of = |x: usize, y: usize| {
+                let offset = sample(x, y).clamp(lower, upper) - lower;
+                (offset as usize * (bins - 1))
+                    .checked_div(range)
+                    .unwrap_or(0)
+            };
+            clahe::equalize(bins, tiles, clip_limit, bin_of, &mut image);
+            [lower, upper]
+        }
+    };
+
+    Ok(ToneMapped {
+        image,
+        applied: AppliedToneMap {
+            params: *params,
+            window,
+        },
+    })
+}
+
+/// Build the lookup table that maps `[low, high]` linearly to 0 to 255.
+fn linear_lut(low: u16, high: u16, max_value: u16) -> Vec<u8> {
+    let scale = u8::MAX as f64 / (high - low) as f64;
+    (0..=max_value)
+        .map(|value| {
+            let offset = value.clamp(low, high) - low;
+            (offset as f64 * scale).round() as u8
+        })
+        .collect()
+}
+
+fn apply_lut<'a, I>(image: &mut Image, rows: I, lut: &[u8])
+where
+    I: Iterator<Item = &'a [u16]>,
+{
+    let stride = image.stride();
+    let max_value = lut.len() - 1;
+    if stride == 0 {
+        return;
+    }
+
+    for (dst, src) in image.as_slice_mut().chunks_mut(stride).zip(rows) {
+        dst.iter_mut()
+            .zip(src)
+            .for_each(|(dst, &value)| *dst = lut[(value as usize).min(max_value)]);
+    }
+}
+
+fn histogram<'a, I>(rows: I, max_value: u16) -> Vec<u32>
+where
+    I: Iterator<Item = &'a [u16]>,
+{
+    let mut hist = vec![0; max_value as usize + 1];
+    rows.flatten()
+        .for_each(|&value| hist[value.min(max_value) as usize] += 1);
+    hist
+}
+
+/// Find the smallest sample that at least `percent` of the samples
+/// are less than or equal to.
+fn percentile(hist: &[u32], percent: f64) -> u16 {
+    let total: u64 = hist.iter().map(|&count| count as u64).sum();
+    if total == 0 {
+        return 0;
+    }
+
+    // The 0th percentile is the minimum sample.
+    let target = ((percent / 100.0 * total as f64).ceil() as u64).clamp(1, total);
+    let mut sum = 0;
+    for (value, &count) in hist.iter().enumerate() {
+        sum += count as u64;
+        if sum >= target {
+            return value as u16;
+        }
+    }
+    (hist.len() - 1) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_window() {
+        let samples = [0, 1000, 2000, 3000, 4000, 65535];
+        let params = ToneMapParams {
+            bit_depth: 16,
+            mapping: ToneMapping::Linear {
+                low: 1000,
+                high: 3000,
+            },
+        };
+        let mapped = tone_map(&samples, 6, 1, 6, &params).unwrap();
+        let values: Vec<_> = (0..6).map(|x| mapped.image[(x, 0)]).collect();
+        assert_eq!(values, [0, 0, 128, 255, 255, 255]);
+        assert_eq!(mapped.applied.window, [1000, 3000]);
+        assert_eq!(mapped.applied.params, params);
+    }
+
+    #[test]
+    fn percentile_window() {
+        // 100 samples of 12 bits in a dim range with an outlier.
+        let mut samples: Vec<u16> = (0..100).map(|value| 100 + value).collect();
+        samples[99] = 4095;
+        let params = ToneMapParams {
+            bit_depth: 12,
+            mapping: ToneMapping::Percentile {
+                low: 1.0,
+                high: 98.0,
+            },
+        };
+        let mapped = tone_map(&samples, 10, 10, 10, &params).unwrap();
+        assert_eq!(mapped.applied.window, [100, 197]);
+        assert_eq!(mapped.image[(0, 0)], 0);
+        assert_eq!(mapped.image[(7, 9)], 255);
+        assert_eq!(mapped.image[(9, 9)], 255);
+    }
+
+    #[test]
+    fn gamma_curve() {
+        let samples = [0, 1023, 4095];
+        let params = ToneMapParams {
+            bit_depth: 12,
+            mapping: ToneMapping::Gamma { gamma: 2.0 },
+        };
+        let mapped = tone_map(&samples, 3, 1, 3, &params).unwrap();
+        assert_eq!(mapped.image[(0, 0)], 0);
+        assert_eq!(mapped.image[(1, 0)], 127);
+        assert_eq!(mapped.image[(2, 0)], 255);
+        assert_eq!(mapped.applied.window, [0, 4095]);
+    }
+
+    #[test]
+    fn clahe_window() {
+        let width = 32;
+        let height = 16;
+        let samples: Vec<u16> = (0..width * height)
+            .map(|index| 20_000 + (index % 7) as u16 * 10)
+            .collect();
+        let params = ToneMapParams {
+            bit_depth: 16,
+            mapping: ToneMapping::Clahe {
+                tiles: [4, 2],
+                clip_limit: 4.0,
+            },
+        };
+        let mapped = tone_map(&samples, width, height, width, &params).unwrap();
+        assert_eq!(mapped.applied.window, [20_000, 20_060]);
+        let values: Vec<_> = mapped.image.samples_iter().collect();
+        assert!(values.iter().max().unwrap() - values.iter().min().unwrap() > 100);
+    }
+
+    #[test]
+    fn reject_invalid_params() {
+        let samples = [0; 16];
+        let tone_map = |bit_depth, mapping, len| {
+            let params = ToneMapParams { bit_depth, mapping };
+            tone_map(&samples[..len], 4, 4, 4, &params)
+        };
+        let percentile = ToneMapping::Percentile {
+            low: 1.0,
+            high: 99.0,
+        };
+
+        assert!(tone_map(16, percentile, 16).is_ok());
+        assert!(tone_map(17, percentile, 16).is_err());
+        assert!(tone_map(16, percentile, 15).is_err());
+        assert!(tone_map(16, ToneMapping::Linear { low: 5, high: 5 }, 16).is_err());
+        assert!(tone_map(
+            16,
+            ToneMapping::Percentile {
+                low: 50.0,
+                high: 10.0
+            },
+            16
+        )
+        .is_err());
+        assert!(tone_map(16, ToneMapping::Gamma { gamma: 0.0 }, 16).is_err());
+        assert!(tone_map(
+            16,
+            ToneMapping::Clahe {
+                tiles: [0, 2],
+                clip_limit: 2.0
+            },
+            16
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! Tracking of tags across video frames.
+//!
+//! A [Tracker] assigns a stable track id to each physical tag. Every
+//! frame, a detection continues the track of the same family and id
+//! whose predicted center is the nearest, so that repeated ids in one
+//! frame are kept apart. A track survives a few frames without
+//! detections, which bridges short occlusions and missed detections.
+//!
+//! ```no_run
+//! use apriltag::{track::{Tracker, TrackerParams}, Detector, Image};
+//!
+//! # fn frames() -> Vec<Image> { vec![] }
+//! let mut detector = Detector::builder().build()?;
+//! let mut tracker = Tracker::new(TrackerParams::default())?;
+//! for image in frames() {
+//!     let records: Vec<_> = detector.detect(&image).iter().map(|det| det.to_record()).collect();
+//!     for track in tracker.update(&records) {
+//!         println!("track {}: {} {}", track.track_id, track.detection.family, track.detection.id);
+//!     }
+//! }
+//! # Ok::<(), apriltag::Error>(())
+//! ```
+
+use crate::{error::Error, record::DetectionRecord};
+
+/// Parameters of the [Tracker].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct TrackerParams {
+    /// The number of consecutive frames a track is kept without
+    /// detections. It defaults to 5.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_missed"))]
+    pub max_missed: usize,
+
+    /// The maximal distance in pixels between the predicted and the
+    /// detected center to continue a track. It defaults to 50.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_distance"))]
+    pub max_distance: f64,
+}
+
+impl TrackerParams {
+    /// Check that the parameters are in range.
+    pub fn validate(&self) -> Result<(), Error> {
+        let max_distance = self.max_distance;
+        if !(max_distance.is_finite() && max_distance > 0.0) {
+            return Err(Error::TrackerError {
+                reason: format!("max distance ({max_distance}) must be positive"),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for TrackerParams {
+    fn default() -> Self {
+        Self {
+            max_missed: default_max_missed(),
+            max_distance: default_max_distance(),
+        }
+    }
+}
+
+/// A tag followed across frames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    /// The id of the track, unique within the [Tracker].
+    pub track_id: u64,
+
+    /// The last detection of the tag.
+    pub detection: DetectionRecord,
+
+    /// The number of frames where the tag is detected.
+    pub hits: usize,
+
+    /// The number of consecutive frames where the tag is not detected.
+    /// It is zero if the tag is detected in the last frame.
+    pub missed: usize,
+
+    /// The center velocity in pixels per frame.
+    pub velocity: [f64; 2],
+}
+
+impl Track {
+    /// The center expected in the next frame.
+    pub fn predicted_center(&self) -> [f64; 2] {
+        let [x, y] = self.detection.center;
+        let [vx, vy] = self.velocity;
+        let frames = (self.missed + 1) as f64;
+        [x + vx * frames, y + vy * frames]
+    }
+}
+
+/// Follows tags across frames. See the [module](self) documentation.
+#[derive(Debug, Clone)]
+pub struct Tracker {
+    params: TrackerParams,
+    tracks: Vec<Track>,
+    next_track_id: u64,
+}
+
+impl Tracker {
+    /// Create a tracker without tracks.
+    pub fn new(params: TrackerParams) -> Result<Self, Error> {
+        params.validate()?;
+        Ok(Self {
+            params,
+            tracks: vec![],
+            next_track_id: 0,
+        })
+    }
+
+    /// Gets the parameters.
+    pub fn params(&self) -> &TrackerParams {
+        &self.params
+    }
+
+    /// Gets the live tracks ordered by track id, including those not
+    /// detected in the last frame.
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Update the tracks with the detections of the next frame.
+    ///
+    /// Detections are matched to tracks of the same family and id in
+    /// the order of the distance to the predicted center. Unmatched
+    /// detections start new tracks, and tracks missed for more than
+    /// `max_missed` frames are dropped.
+    pub fn update(&mut self, detections: &[DetectionRecord]) -> &[Track] {
+        let mut pairs = vec![];
+        for (track_index, track) in self.tracks.iter().enumerate() {
+            let [px, py] = track.predicted_center();
+            for (det_index, det) in detections.iter().enumerate() {
+                if det.family != track.detection.family || det.id != track.detection.id {
+                    continue;
+                }
+                let [x, y] = det.center;
+                let distance = (x - px).hypot(y - py);
+                if distance <= self.params.max_distance {
+                    pairs.push((distance, track_index, det_index));
+                }
+            }
+        }
+        pairs.sort_by(|lhs, rhs| lhs.0.total_cmp(&rhs.0));
+
+        let mut track_matched = vec![false; self.tracks.len()];
+        let mut det_matched = vec![false; detections.len()];
+        for (_, track_index, det_index) in pairs {
+            if track_matched[track_index] || det_matched[det_index] {
+                continue;
+            }
+            track_matched[track_index] = true;
+            det_matched[det_index] = true;
+
+            let track = &mut self.tracks[track_index];
+            let det = &detections[det_index];
+            let frames = (track.missed + 1) as f64;
+            let [x0, y0] = track.detection.center;
+            let [x1, y1] = det.center;
+            track.velocity = [(x1 - x0) / frames, (y1 - y0) / frames];
+            track.detection = det.clone();
+            track.hits += 1;
+            track.missed = 0;
+        }
+
+        for (track, matched) in self.tracks.iter_mut().zip(track_matched) {
+            if !matched {
+                track.missed += 1;
+            }
+        }
+        let max_missed = self.params.max_missed;
+        self.tracks.retain(|track| track.missed <= max_missed);
+
+        for (det, matched) in detections.iter().zip(det_matched) {
+            if matched {
+                continue;
+            }
+            self.tracks.push(Track {
+                track_id: self.next_track_id,
+                detection: det.clone(),
+                hits: 1,
+                missed: 0,
+                velocity: [0.0, 0.0],
+            });
+            self.next_track_id += 1;
+        }
+
+        &self.tracks
+    }
+
+    /// Drop all tracks. Track ids are not reused.
+    pub fn clear(&mut self) {
+        self.tracks.clear();
+    }
+}
+
+fn default_max_missed() -> usize {
+    5
+}
+
+fn default_max_distance() -> f64 {
+    50.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: usize, x: f64) -> DetectionRecord {
+        DetectionRecord {
+            family: "tag36h11".to_string(),
+            id,
+            hamming: 0,
+            decision_margin: 50.0,
+            center: [x + 5.0, 5.0],
+            corners: [[x, 0.0], [x + 10.0, 0.0], [x + 10.0, 10.0], [x, 10.0]],
+            homography: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    fn summary(tracks: &[Track]) -> Vec<(u64, usize, f64, usize)> {
+        tracks
+            .iter()
+            .map(|track| {
+                let det = &track.detection;
+                (track.track_id, det.id, det.center[0], track.missed)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn follow_moving_tags() {
+        let mut tracker = Tracker::new(TrackerParams {
+            max_missed: 1,
+            max_distance: 20.0,
+        })
+        .unwrap();
+
+        // Two tags of the same id are kept apart by their positions.
+        let tracks = tracker.update(&[record(1, 0.0), record(1, 100.0), record(2, 50.0)]);
+        assert_eq!(
+            summary(tracks),
+            [(0, 1, 5.0, 0), (1, 1, 105.0, 0), (2, 2, 55.0, 0)]
+        );
+
+        let tracks = tracker.update(&[record(1, 110.0), record(1, 10.0)]);
+        assert_eq!(
+            summary(tracks),
+            [(0, 1, 15.0, 0), (1, 1, 115.0, 0), (2, 2, 55.0, 1)]
+        );
+        assert_eq!(tracks[0].velocity, [10.0, 0.0]);
+        assert_eq!(tracks[0].predicted_center(), [25.0, 5.0]);
+
+        // The missed track 2 is dropped, and a detection far from the
+        // prediction starts a new track.
+        let tracks = tracker.update(&[record(1, 20.0), record(1, 200.0)]);
+        assert_eq!(
+            summary(tracks),
+            [(0, 1, 25.0, 0), (1, 1, 115.0, 1), (3, 1, 205.0, 0)]
+        );
+        assert_eq!(tracks[0].hits, 3);
+
+        tracker.clear();
+        let tracks = tracker.update(&[record(1, 0.0)]);
+        assert_eq!(summary(tracks), [(4, 1, 5.0, 0)]);
+    }
+
+    #[test]
+    fn reject_invalid_params() {
+        for max_distance in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let params = TrackerParams {
+                max_distance,
+                ..TrackerParams::default()
+            };
+            assert!(Tracker::new(params).is_err());
+        }
+    }
+}
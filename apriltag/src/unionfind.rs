@@ -0,0 +1,133 @@
+//! Union-find segmentation of thresholded images.
+
+use crate::{
+    error::Error,
+    image_buf::{Image, DEFAULT_ALIGNMENT_U8},
+};
+
+/// The disjoint-set forest over pixel indices.
+#[derive(Debug, Clone)]
+pub(crate) struct UnionFind {
+    parent: Vec<u32>,
+    size: Vec<u32>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len as u32).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    pub fn representative(&mut self, id: u32) -> u32 {
+        let mut root = id;
+        while self.parent[root as usize] != root {
+            root = self.parent[root as usize];
+        }
+
+        // Path compression.
+        let mut id = id;
+        while self.parent[id as usize] != root {
+            let next = self.parent[id as usize];
+            self.parent[id as usize] = root;
+            id = next;
+        }
+
+        root
+    }
+
+    pub fn set_size(&mut self, id: u32) -> u32 {
+        let root = self.representative(id);
+        self.size[root as usize]
+    }
+
+    pub fn connect(&mut self, lhs: u32, rhs: u32) {
+        let lhs = self.representative(lhs);
+        let rhs = self.representative(rhs);
+        if lhs == rhs {
+            return;
+        }
+
+        let (small, large) = if self.size[lhs as usize] < self.size[rhs as usize] {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        self.parent[small as usize] = large;
+        self.size[large as usize] += self.size[small as usize];
+    }
+}
+
+/// Find connected components of a thresholded image.
+///
+/// Pixels of value 127 are left alone. Black pixels are 4-connected
+/// and white pixels are 8-connected, the same as the AprilTag library.
+pub(crate) fn connected_components(threshim: &Image) -> UnionFind {
+    let width = threshim.width();
+    let height = threshim.height();
+    let stride = threshim.stride();
+    let buf = threshim.as_slice();
+    let mut uf = UnionFind::new(width * height);
+
+    let id = |x: usize, y: usize| (y * width + x) as u32;
+
+    for y in 0..height {
+        for x in 1..width.saturating_sub(1) {
+            let v = buf[y * stride + x];
+            if v == 127 {
+                continue;
+            }
+
+            if buf[y * stride + x - 1] == v {
+                uf.connect(id(x, y), id(x - 1, y));
+            }
+            if y == 0 {
+                continue;
+            }
+            if buf[(y - 1) * stride + x] == v {
+                uf.connect(id(x, y), id(x, y - 1));
+            }
+            if v == 255 {
+                if buf[(y - 1) * stride + x - 1] == v {
+                    uf.connect(id(x, y), id(x - 1, y - 1));
+                }
+                if buf[(y - 1) * stride + x + 1] == v {
+                    uf.connect(id(x, y), id(x + 1, y - 1));
+                }
+            }
+        }
+    }
+
+    uf
+}
+
+/// Paint each connected component with a distinct gray level.
+///
+/// Unknown pixels and components smaller than `min_cluster_pixels`
+/// are painted black.
+pub(crate) fn segmentation_image(
+    threshim: &Image,
+    min_cluster_pixels: u32,
+) -> Result<Image, Error> {
+    let width = threshim.width();
+    let height = threshim.height();
+    let stride = threshim.stride();
+    let buf = threshim.as_slice();
+    let mut uf = connected_components(threshim);
+    let mut segmentation = Image::zeros_with_alignment(width, height, DEFAULT_ALIGNMENT_U8)?;
+
+    for y in 0..height {
+        for x in 0..width {
+            let id = (y * width + x) as u32;
+            if buf[y * stride + x] == 127 || uf.set_size(id) < min_cluster_pixels {
+                continue;
+            }
+            let root = uf.representative(id);
+            let hash = root.wrapping_mul(2654435761) >> 24;
+            segmentation[(x, y)] = 64 + (hash % 192) as u8;
+        }
+    }
+
+    Ok(segmentation)
+}
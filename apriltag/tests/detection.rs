@@ -1,4 +1,4 @@
-use apriltag::{DetectorBuilder, Family, Image};
+use apriltag::{DetectorBuilder, Family, Image, RefineParams};
 
 #[test]
 fn pnm_file_detection() {
@@ -22,3 +22,978 @@ fn pnm_file_detection() {
     ids_found.sort_unstable();
     assert_eq!(ids_found, [2, 12, 22, 29]);
 }
+
+#[test]
+fn refine_detected_corners() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .expect("Valid builder");
+
+    let detections = detector.detect(&image);
+    assert!(!detections.is_empty());
+
+    // Refined corners stay close to the detected corners.
+    for detection in detections {
+        let refined = detection
+            .refine_corners(&image, &RefineParams::default())
+            .expect("Refinement succeeds");
+        detection
+            .corners()
+            .iter()
+            .zip(&refined.corners)
+            .for_each(|(&[x1, y1], &[x2, y2])| {
+                assert!((x1 - x2).hypot(y1 - y2) < 1.5);
+            });
+    }
+}
+
+#[test]
+fn detection_stats() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .expect("Valid builder");
+    assert!(detector.last_stats().stamps.is_empty());
+
+    let detections = detector.detect(&image);
+    let stats = detector.last_stats();
+    assert!(stats.nquads >= detections.len());
+    assert!(stats.stamps.iter().any(|stamp| stamp.name == "threshold"));
+    assert_eq!(
+        stats.total(),
+        stats.stamps.iter().map(|stamp| stamp.elapsed).sum()
+    );
+}
+
+#[test]
+fn debug_artifacts() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .expect("Valid builder");
+    detector.set_decimation(2.0);
+
+    let (detections, artifacts) = detector.detect_debug(&image).unwrap();
+    let stats = detector.last_stats();
+    assert_eq!(detections.len(), 4);
+
+    assert_eq!(artifacts.preprocessed.width(), image.width() / 2);
+    assert_eq!(artifacts.threshold.width(), artifacts.preprocessed.width());
+    assert_eq!(
+        artifacts.segmentation.height(),
+        artifacts.preprocessed.height()
+    );
+    assert!(artifacts
+        .threshold
+        .samples_iter()
+        .all(|value| matches!(value, 0 | 127 | 255)));
+
+    assert_eq!(artifacts.quads.len(), stats.nquads);
+    assert_eq!(
+        artifacts.quads.len() - artifacts.rejected_quads.len(),
+        detections.len()
+    );
+}
+
+#[test]
+fn debug_quads_match_c_detector() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .expect("Valid builder");
+
+    // The quads are fitted by the Rust port of the stage, which must
+    // agree with the C library that produced the detections.
+    for (decimation, sigma) in [(1.0, 0.0), (1.5, 0.0), (2.0, 0.8), (3.0, -0.8)] {
+        detector.set_decimation(decimation);
+        detector.set_sigma(sigma);
+
+        let (detections, artifacts) = detector.detect_debug(&image).unwrap();
+        assert_eq!(artifacts.quads.len(), detector.last_stats().nquads);
+
+        for detection in &detections {
+            let [x1, y1] = detection.center();
+            assert!(artifacts.quads.iter().any(|quad| {
+                let [x2, y2] = quad.center();
+                (x1 - x2).hypot(y1 - y2) < 2.0
+            }));
+        }
+    }
+}
+
+#[test]
+fn rejected_quads() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    // The tags are not in the tag25h9 family, so all quads are rejected.
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_25h9(), 1)
+        .build()
+        .expect("Valid builder");
+
+    let (detections, rejected) = detector.detect_with_rejected(&image).unwrap();
+    assert!(detections.is_empty());
+    assert!(rejected.len() >= 4);
+    assert!(rejected.iter().all(|quad| quad.family == "tag25h9"));
+    assert!(rejected.iter().all(|quad| quad.best_hamming > 1));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn detector_stream() {
+    use apriltag::stream::{DetectorStream, DropPolicy, StreamConfig};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let frames: Vec<_> = (0..8)
+        .map(|_| Image::from_pnm_file(path).unwrap())
+        .collect();
+
+    let config = StreamConfig {
+        num_workers: 3,
+        queue_size: 2,
+        drop_policy: DropPolicy::Block,
+    };
+    let stream = DetectorStream::new(stream::iter(frames), config, || {
+        DetectorBuilder::new()
+            .add_family_bits(Family::tag_16h5(), 1)
+            .build()
+    })
+    .unwrap();
+
+    // No frame is dropped and the results keep the input order.
+    let outputs: Vec<_> = block_on(stream.collect());
+    let indices: Vec<_> = outputs.iter().map(|frame| frame.index).collect();
+    assert_eq!(indices, (0..8).collect::<Vec<_>>());
+    assert!(outputs.iter().all(|frame| frame.detections.len() == 4));
+}
+
+/// Stream the frames faster than a single worker can detect them and
+/// return the output indices and the number of dropped frames.
+#[cfg(feature = "async")]
+fn stream_with_drops(
+    drop_policy: apriltag::stream::DropPolicy,
+    num_frames: usize,
+) -> (Vec<usize>, usize) {
+    use apriltag::stream::{DetectorStream, StreamConfig};
+    use futures::{executor::block_on, stream, StreamExt};
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+    let frames: Vec<_> = (0..num_frames).map(|_| image.clone()).collect();
+
+    let config = StreamConfig {
+        num_workers: 1,
+        queue_size: 1,
+        drop_policy,
+    };
+    let mut stream = DetectorStream::new(stream::iter(frames), config, || {
+        DetectorBuilder::new()
+            .add_family_bits(Family::tag_16h5(), 1)
+            .build()
+    })
+    .unwrap();
+
+    let mut indices = vec![];
+    while let Some(frame) = block_on(stream.next()) {
+        assert_eq!(frame.detections.len(), 4);
+        indices.push(frame.index);
+    }
+    assert_eq!(stream.failed_frames(), 0);
+    (indices, stream.dropped_frames())
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn detector_stream_drop_oldest() {
+    use apriltag::stream::DropPolicy;
+
+    let (indices, dropped) = stream_with_drops(DropPolicy::DropOldest, 32);
+
+    // The queued frames are replaced by newer ones, so the last frame is
+    // always detected.
+    assert!(dropped > 0);
+    assert_eq!(indices.len() + dropped, 32);
+    assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(indices.last(), Some(&31));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn detector_stream_drop_newest() {
+    use apriltag::stream::DropPolicy;
+
+    let (indices, dropped) = stream_with_drops(DropPolicy::DropNewest, 32);
+
+    // The incoming frames are discarded while the queue is full, so the
+    // first frame is always detected.
+    assert!(dropped > 0);
+    assert_eq!(indices.len() + dropped, 32);
+    assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(indices.first(), Some(&0));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn batch_detection() {
+    use apriltag::batch::BatchDetector;
+    use std::{
+        collections::HashSet,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+    };
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let inputs = [path, "missing.pnm", path, path];
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let threads = Arc::new(Mutex::new(HashSet::new()));
+    let batch = BatchDetector::new(|| {
+        DetectorBuilder::new()
+            .add_family_bits(Family::tag_16h5(), 1)
+            .build()
+    })
+    .num_threads(2)
+    .on_progress({
+        let calls = calls.clone();
+        let threads = threads.clone();
+        move |progress| {
+            assert_eq!(progress.total, 4);
+            calls.fetch_add(1, Ordering::SeqCst);
+            threads.lock().unwrap().insert(thread::current().id());
+        }
+    });
+
+    // The loading error is captured without failing other images.
+    let results = batch.detect_with(&inputs, |path| Image::from_pnm_file(path));
+    assert_eq!(results.len(), 4);
+    assert!(results[1].is_err());
+    for index in [0, 2, 3] {
+        assert_eq!(results[index].as_ref().unwrap().len(), 4);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    // Later batches run on the same worker threads.
+    batch.detect_with(&inputs, |path| Image::from_pnm_file(path));
+    assert_eq!(calls.load(Ordering::SeqCst), 8);
+    assert!(threads.lock().unwrap().len() <= 2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_records() {
+    use apriltag::{detector::QuadThresholds, DetectionRecord, FamilyKind, TagParams};
+    use measurements::angle::Angle;
+    use noisy_float::prelude::r32;
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/DICT_APRILTAG_16h5-2x2-500-10-0.8-29,12,22,2.pnm"
+    );
+    let image = Image::from_pnm_file(path).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(FamilyKind::Tag16h5, 1)
+        .build()
+        .expect("Valid builder");
+
+    let records: Vec<DetectionRecord> = detector
+        .detect(&image)
+        .iter()
+        .map(|detection| detection.to_record())
+        .collect();
+    assert!(records.iter().all(|record| record.family == "tag16h5"));
+    let json = serde_json::to_string(&records).unwrap();
+    let parsed: Vec<DetectionRecord> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, records);
+
+    let params = TagParams {
+        tagsize: 0.1,
+        fx: 500.0,
+        fy: 500.0,
+        cx: 250.0,
+        cy: 250.0,
+    };
+    let pose = detector.detect(&image)[0]
+        .estimate_tag_pose(&params)
+        .unwrap()
+        .to_record();
+    let json = serde_json::to_value(&pose).unwrap();
+    assert_eq!(json["translation"].as_array().unwrap().len(), 3);
+
+    let thresholds = QuadThresholds {
+        min_cluster_pixels: 5,
+        max_maxima_number: 10,
+        min_angle: Angle::from_degrees(10.0),
+        min_opposite_angle: Angle::from_degrees(20.0),
+        max_mse: r32(10.0),
+        min_white_black_diff: 5,
+        deglitch: false,
+    };
+    let json = serde_json::to_value(thresholds).unwrap();
+    assert!((json["min_angle_degrees"].as_f64().unwrap() - 10.0).abs() < 1e-9);
+    let parsed: QuadThresholds = serde_json::from_value(json).unwrap();
+    assert!((parsed.min_opposite_angle.as_degrees() - 20.0).abs() < 1e-9);
+
+    let kind: FamilyKind = serde_json::from_str("\"tagStandard41h12\"").unwrap();
+    assert_eq!(kind, FamilyKind::TagStandard41h12);
+}
+
+#[test]
+fn render_and_detect_tags() {
+    use apriltag::FamilyKind;
+
+    for (kind, id) in [
+        (FamilyKind::Tag16h5, 3),
+        (FamilyKind::Tag36h11, 100),
+        (FamilyKind::TagCircle21h7, 7),
+        (FamilyKind::TagStandard41h12, 42),
+    ] {
+        let family = kind.create();
+        let tag = family.to_image(id).unwrap();
+        assert_eq!(tag.width(), family.total_width());
+
+        // Upscale the tag and put it on a white canvas.
+        let scale = 20;
+        let margin = 40;
+        let size = tag.width() * scale + 2 * margin;
+        let mut image = Image::zeros_with_alignment(size, size, 96).unwrap();
+        for y in 0..size {
+            for x in 0..size {
+                let inside =
+                    (margin..size - margin).contains(&x) && (margin..size - margin).contains(&y);
+                image[(x, y)] = if inside {
+                    tag[((x - margin) / scale, (y - margin) / scale)]
+                } else {
+                    255
+                };
+            }
+        }
+
+        let mut detector = DetectorBuilder::new()
+            .add_family_bits(kind, 0)
+            .build()
+            .unwrap();
+        let ids: Vec<_> = detector
+            .detect(&image)
+            .iter()
+            .map(|detection| detection.id())
+            .collect();
+        assert_eq!(ids, [id], "{kind}");
+    }
+
+    assert!(Family::tag_16h5().to_image(30).is_err());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn evaluate_dataset() {
+    use apriltag::eval::{Dataset, EvalParams};
+
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/dataset.csv");
+    let dataset = Dataset::from_csv_file(path).unwrap();
+    assert_eq!(dataset.samples.len(), 1);
+    assert_eq!(dataset.num_tags(), 4);
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 1)
+        .build()
+        .expect("Valid builder");
+    let report = dataset
+        .evaluate(&mut detector, &EvalParams::default())
+        .unwrap();
+    assert_eq!(report.num_images, 1);
+    assert_eq!(report.recall(), Some(1.0));
+    assert_eq!(report.precision(), Some(1.0));
+    assert!(report.corner_rmse.unwrap() < 0.5);
+    assert_eq!(report.pose_error, None);
+
+    #[cfg(feature = "serde")]
+    {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/dataset.json");
+        let json_dataset = Dataset::from_json_file(path).unwrap();
+        assert_eq!(json_dataset, dataset);
+    }
+}
+
+#[test]
+fn evaluate_pose_error() {
+    use apriltag::{
+        eval::{Dataset, EvalParams, LabelledTag, Sample},
+        FamilyKind, PoseRecord, TagParams,
+    };
+
+    // Render a fronto-parallel tag36h11 tag whose border spans
+    // [80, 240] pixels on both axes.
+    let family = FamilyKind::Tag36h11.create();
+    let tag = family.to_image(5).unwrap();
+    let scale = 20;
+    let margin = 60;
+    let size = tag.width() * scale + 2 * margin;
+    let render = |_: &std::path::Path| {
+        let mut image = Image::zeros_with_alignment(size, size, 96)?;
+        for y in 0..size {
+            for x in 0..size {
+                let inside =
+                    (margin..size - margin).contains(&x) && (margin..size - margin).contains(&y);
+                image[(x, y)] = if inside {
+                    tag[((x - margin) / scale, (y - margin) / scale)]
+                } else {
+                    255
+                };
+            }
+        }
+        Ok(image)
+    };
+
+    // A 0.1m tag spanning 160 pixels at the focal length of 500 pixels
+    // is 0.3125m away from the camera.
+    let tag_params = TagParams {
+        tagsize: 0.1,
+        fx: 500.0,
+        fy: 500.0,
+        cx: 200.0,
+        cy: 200.0,
+    };
+    let pose = PoseRecord {
+        rotation: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        translation: [-0.025, -0.025, 0.3125],
+    };
+    let dataset = Dataset {
+        samples: vec![Sample {
+            image: "rendered".into(),
+            tag_params: Some(tag_params),
+            tags: vec![
+                LabelledTag {
+                    family: Some("tag36h11".to_string()),
+                    id: 5,
+                    corners: [[80.0, 240.0], [240.0, 240.0], [240.0, 80.0], [80.0, 80.0]],
+                    pose: Some(pose),
+                },
+                LabelledTag {
+                    family: None,
+                    id: 6,
+                    corners: [[0.0, 20.0], [20.0, 20.0], [20.0, 0.0], [0.0, 0.0]],
+                    pose: None,
+                },
+            ],
+        }],
+    };
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(FamilyKind::Tag36h11, 0)
+        .build()
+        .unwrap();
+    let report = dataset
+        .evaluate_with(&mut detector, &EvalParams::default(), render)
+        .unwrap();
+    assert_eq!(report.true_positives, 1);
+    assert_eq!(report.false_negatives, 1);
+    assert_eq!(report.recall(), Some(0.5));
+    assert_eq!(report.precision(), Some(1.0));
+
+    let pose_error = report.pose_error.unwrap();
+    assert_eq!((pose_error.count, pose_error.failures), (1, 0));
+    assert!(pose_error.mean_translation < 1e-3);
+    assert!(pose_error.mean_rotation_degrees < 1.0);
+}
+
+#[test]
+fn synthetic_pose_accuracy() {
+    use apriltag::{
+        synthetic::{self, Camera, Distortion, Effects, Illumination, SyntheticTag},
+        PoseRecord,
+    };
+
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_36h11(), 1)
+        .build()
+        .unwrap();
+
+    let effects = Effects {
+        blur_sigma: 0.6,
+        noise_stddev: 3.0,
+        illumination: Illumination {
+            gain: 0.9,
+            gradient: [0.001, -0.0005],
+        },
+        ..Effects::default()
+    };
+    for (index, (rx, ry, rz, z)) in [
+        (0.0, 0.0, 0.0, 0.4),
+        (0.4, 0.0, 0.3, 0.5),
+        (0.0, -0.6, -1.2, 0.45),
+        (-0.3, 0.5, 2.5, 0.6),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let tag = SyntheticTag {
+            family: &family,
+            id: 10 + index,
+            size: 0.1,
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(rx, ry, rz),
+                translation: [0.02, -0.01, z],
+            },
+        };
+        let effects = Effects {
+            seed: index as u64,
+            ..effects.clone()
+        };
+        let scene = synthetic::render(&camera, &[tag], &effects).unwrap();
+        let truth = &scene.tags[0];
+
+        let detections = detector.detect(&scene.image);
+        assert_eq!(detections.len(), 1);
+        let detection = &detections[0];
+        assert_eq!(detection.id(), truth.id);
+        for ([x1, y1], [x2, y2]) in detection.corners().iter().zip(&truth.corners) {
+            assert!((x1 - x2).hypot(y1 - y2) < 1.0);
+        }
+
+        let pose = detection
+            .estimate_tag_pose(&camera.tag_params(0.1))
+            .unwrap()
+            .to_record();
+        for (lhs, rhs) in pose.translation.iter().zip(&truth.pose.translation) {
+            assert!((lhs - rhs).abs() < 0.01);
+        }
+    }
+
+    // A mildly distorted lens keeps the corners on the distorted
+    // projection.
+    let camera = Camera {
+        distortion: Distortion {
+            k1: -0.1,
+            ..Distortion::default()
+        },
+        ..camera
+    };
+    let tag = SyntheticTag {
+        family: &family,
+        id: 3,
+        size: 0.1,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.2, 0.1, 0.0),
+            translation: [0.05, 0.03, 0.4],
+        },
+    };
+    let scene = synthetic::render(&camera, &[tag], &Effects::default()).unwrap();
+    let detections = detector.detect(&scene.image);
+    assert_eq!(detections.len(), 1);
+    for ([x1, y1], [x2, y2]) in detections[0].corners().iter().zip(&scene.tags[0].corners) {
+        assert!((x1 - x2).hypot(y1 - y2) < 1.0);
+    }
+}
+
+#[test]
+fn synthetic_occlusion() {
+    use apriltag::{
+        eval::{Dataset, EvalParams, Sample},
+        synthetic::{self, Camera, Effects, Occluder, SyntheticTag},
+        PoseRecord,
+    };
+
+    let family = Family::tag_16h5();
+    let camera = Camera::new(320, 160, 400.0, 400.0, 160.0, 80.0);
+    let tags: Vec<_> = [-0.08, 0.08]
+        .into_iter()
+        .enumerate()
+        .map(|(id, x)| SyntheticTag {
+            family: &family,
+            id,
+            size: 0.1,
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(0.0, 0.0, 0.0),
+                translation: [x, 0.0, 0.5],
+            },
+        })
+        .collect();
+
+    // Cover the upper half of the second tag.
+    let [cx, cy] = synthetic::render(&camera, &tags, &Effects::default())
+        .unwrap()
+        .tags[1]
+        .corners[3];
+    let effects = Effects {
+        occluders: vec![Occluder {
+            left: cx - 10.0,
+            top: cy - 10.0,
+            width: 100.0,
+            height: 50.0,
+            intensity: 255,
+        }],
+        ..Effects::default()
+    };
+    let scene = synthetic::render(&camera, &tags, &effects).unwrap();
+
+    let dataset = Dataset {
+        samples: vec![Sample {
+            image: "scene".into(),
+            tag_params: Some(camera.tag_params(0.1)),
+            tags: scene.tags.iter().map(Into::into).collect(),
+        }],
+    };
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 0)
+        .build()
+        .unwrap();
+    let mut image = Some(scene.image);
+    let report = dataset
+        .evaluate_with(&mut detector, &EvalParams::default(), |_| {
+            Ok(image.take().unwrap())
+        })
+        .unwrap();
+    assert_eq!(report.true_positives, 1);
+    assert_eq!(report.false_negatives, 1);
+    assert_eq!(report.false_positives, 0);
+    assert!(report.corner_rmse.unwrap() < 1.0);
+    assert_eq!(report.pose_error.unwrap().count, 1);
+}
+
+#[test]
+fn preprocess_dim_scene() {
+    use apriltag::{
+        synthetic::{self, Camera, Effects, Illumination, SyntheticTag},
+        PoseRecord, Preprocess,
+    };
+
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let tag = SyntheticTag {
+        family: &family,
+        id: 3,
+        size: 0.1,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.2, 0.1, 0.0),
+            translation: [0.0, 0.0, 0.5],
+        },
+    };
+
+    // The white cells are barely above black at dusk.
+    let effects = Effects {
+        illumination: Illumination {
+            gain: 0.015,
+            gradient: [0.0, 0.0],
+        },
+        ..Effects::default()
+    };
+    let scene = synthetic::render(&camera, &[tag], &effects).unwrap();
+
+    let build = |builder: DetectorBuilder| {
+        builder
+            .add_family_bits(Family::tag_36h11(), 1)
+            .build()
+            .unwrap()
+    };
+
+    let mut detector = build(DetectorBuilder::new());
+    assert!(detector.detect(&scene.image).is_empty());
+
+    let mut detector =
+        build(DetectorBuilder::new().add_preprocess(Preprocess::Gamma { gamma: 3.0 }));
+    let detections = detector.detect(&scene.image);
+    assert_eq!(detections.len(), 1);
+    assert_eq!(detections[0].id(), 3);
+
+    // Darkening first finds nothing, so the alternate chain is tried.
+    let mut detector = build(
+        DetectorBuilder::new()
+            .add_preprocess(Preprocess::Gamma { gamma: 0.5 })
+            .add_preprocess_retry([])
+            .add_preprocess_retry([
+                Preprocess::EqualizeHistogram,
+                Preprocess::Unsharp {
+                    sigma: 1.0,
+                    amount: 0.5,
+                },
+            ]),
+    );
+    let detections = detector.detect(&scene.image);
+    assert_eq!(detections.len(), 1);
+    assert_eq!(detections[0].id(), 3);
+
+    let mut detector = build(DetectorBuilder::new().add_preprocess(Preprocess::Clahe {
+        tiles: [4, 4],
+        clip_limit: 8.0,
+    }));
+    assert_eq!(detector.detect(&scene.image).len(), 1);
+
+    let invalid = DetectorBuilder::new()
+        .add_family_bits(Family::tag_36h11(), 1)
+        .add_preprocess(Preprocess::Gamma { gamma: -1.0 })
+        .build();
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn multi_scale_detection() {
+    use apriltag::{
+        synthetic::{self, Camera, Effects, SyntheticTag},
+        MultiScaleParams, PoseRecord,
+    };
+
+    let family = Family::tag_36h11();
+    let camera = Camera::new(640, 480, 600.0, 600.0, 320.0, 240.0);
+    let tag = |id, size, translation| SyntheticTag {
+        family: &family,
+        id,
+        size,
+        pose: PoseRecord {
+            rotation: synthetic::euler_rotation(0.0, 0.0, 0.0),
+            translation,
+        },
+    };
+
+    // A near tag covering a third of the frame and a far one of about
+    // 15 pixels.
+    let tags = [tag(1, 0.2, [-0.1, 0.0, 0.8]), tag(2, 0.1, [0.6, 0.3, 4.0])];
+    let scene = synthetic::render(&camera, &tags, &Effects::default()).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_36h11(), 1)
+        .build()
+        .unwrap();
+    detector.set_decimation(1.0);
+    detector.detect(&scene.image);
+    let fine_stats = detector.last_stats();
+    detector.set_decimation(4.0);
+    let coarse = detector.detect(&scene.image);
+    assert_eq!(coarse.iter().map(|det| det.id()).collect::<Vec<_>>(), [1]);
+    let coarse_stats = detector.last_stats();
+
+    detector
+        .set_multi_scale(Some(MultiScaleParams {
+            decimations: vec![4.0, 1.0],
+            ..MultiScaleParams::default()
+        }))
+        .unwrap();
+    let mut detections = detector.detect(&scene.image);
+    detections.sort_by_key(|det| det.id());
+    assert_eq!(
+        detections.iter().map(|det| det.id()).collect::<Vec<_>>(),
+        [1, 2]
+    );
+
+    // The statistics are summed over the passes.
+    let stats = detector.last_stats();
+    assert_eq!(stats.nquads, coarse_stats.nquads + fine_stats.nquads);
+    assert_eq!(
+        stats.stamps.len(),
+        coarse_stats.stamps.len() + fine_stats.stamps.len()
+    );
+    for (detection, truth) in detections.iter().zip(&scene.tags) {
+        for (corner, expect) in detection.corners().iter().zip(&truth.corners) {
+            assert!((corner[0] - expect[0]).hypot(corner[1] - expect[1]) < 1.0);
+        }
+    }
+
+    // The decimation is restored after the passes.
+    detector.set_multi_scale(None).unwrap();
+    assert_eq!(detector.detect(&scene.image).len(), 1);
+
+    assert!(detector
+        .set_multi_scale(Some(MultiScaleParams {
+            decimations: vec![],
+            ..MultiScaleParams::default()
+        }))
+        .is_err());
+}
+
+#[test]
+fn bundle_pose() {
+    use apriltag::{
+        synthetic::{self, Camera, Effects, SyntheticTag},
+        BundleTag, FamilyKind, PoseRecord, TagBundle,
+    };
+
+    let family = Family::tag_36h11();
+    let camera = Camera::new(320, 240, 300.0, 300.0, 160.0, 120.0);
+    let rotation = synthetic::euler_rotation(0.3, -0.2, 0.1);
+    let translation = [0.01, -0.02, 0.6];
+
+    // Four tags in a square, where the last one has the default size.
+    let bundle = TagBundle {
+        name: "board".to_string(),
+        tags: [
+            (0, -0.04, -0.04),
+            (1, 0.04, -0.04),
+            (2, -0.04, 0.04),
+            (3, 0.04, 0.04),
+        ]
+        .into_iter()
+        .map(|(id, x, y)| BundleTag {
+            family: FamilyKind::Tag36h11,
+            id,
+            size: (id != 3).then_some(0.06),
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(0.0, 0.0, 0.0),
+                translation: [x, y, 0.0],
+            },
+        })
+        .collect(),
+    };
+    bundle.validate().unwrap();
+
+    let tags: Vec<_> = bundle
+        .tags
+        .iter()
+        .map(|tag| {
+            let [x, y, _] = tag.pose.translation;
+            let offset: Vec<f64> = (0..3)
+                .map(|row| rotation[row][0] * x + rotation[row][1] * y + translation[row])
+                .collect();
+            SyntheticTag {
+                family: &family,
+                id: tag.id,
+                size: 0.06,
+                pose: PoseRecord {
+                    rotation,
+                    translation: [offset[0], offset[1], offset[2]],
+                },
+            }
+        })
+        .collect();
+    let scene = synthetic::render(&camera, &tags, &Effects::default()).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_36h11(), 1)
+        .build()
+        .unwrap();
+    let records: Vec<_> = detector
+        .detect(&scene.image)
+        .iter()
+        .map(|det| det.to_record())
+        .collect();
+    assert_eq!(records.len(), 4);
+
+    let params = camera.tag_params(0.06);
+    let check = |records: &[apriltag::DetectionRecord]| {
+        let estimation = bundle.estimate_pose(records, &params).unwrap();
+        let pose = estimation.pose;
+        for (lhs, rhs) in pose.translation.iter().zip(&translation) {
+            assert!((lhs - rhs).abs() < 0.01);
+        }
+        for (lhs, rhs) in pose
+            .rotation
+            .iter()
+            .flatten()
+            .zip(rotation.iter().flatten())
+        {
+            assert!((lhs - rhs).abs() < 0.05);
+        }
+    };
+    check(&records);
+
+    // A single member tag is enough.
+    check(&records[..1]);
+    assert!(bundle.estimate_pose(&[], &params).is_none());
+}
+
+#[test]
+fn filter_repeated_ids() {
+    use apriltag::{
+        synthetic::{self, Camera, Effects, SyntheticTag},
+        FamilyKind, FilterParams, PoseRecord,
+    };
+
+    let family = Family::tag_16h5();
+    let camera = Camera::new(480, 160, 400.0, 400.0, 240.0, 80.0);
+    let tags: Vec<_> = [(4, -0.15), (4, 0.0), (6, 0.15)]
+        .into_iter()
+        .map(|(id, x)| SyntheticTag {
+            family: &family,
+            id,
+            size: 0.08,
+            pose: PoseRecord {
+                rotation: synthetic::euler_rotation(0.0, 0.0, 0.0),
+                translation: [x, 0.0, 0.5],
+            },
+        })
+        .collect();
+    let scene = synthetic::render(&camera, &tags, &Effects::default()).unwrap();
+
+    let mut detector = DetectorBuilder::new()
+        .add_family_bits(Family::tag_16h5(), 0)
+        .filter(FilterParams::default())
+        .build()
+        .unwrap();
+    let ids = |detector: &mut apriltag::Detector| {
+        let mut ids: Vec<_> = detector
+            .detect(&scene.image)
+            .iter()
+            .map(|det| det.id())
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids(&mut detector), [4, 4, 6]);
+
+    detector
+        .set_filter(Some(FilterParams {
+            unique_ids: true,
+            ..FilterParams::default()
+        }))
+        .unwrap();
+    assert_eq!(ids(&mut detector), [4, 6]);
+
+    detector
+        .set_filter(Some(FilterParams {
+            allowed_ids: [(FamilyKind::Tag16h5, vec![6])].into(),
+            ..FilterParams::default()
+        }))
+        .unwrap();
+    assert_eq!(ids(&mut detector), [6]);
+
+    detector
+        .set_filter(Some(FilterParams {
+            min_decision_margin: Some(f32::MAX),
+            ..FilterParams::default()
+        }))
+        .unwrap();
+    assert!(detector.detect(&scene.image).is_empty());
+}